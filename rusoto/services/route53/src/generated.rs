@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use std::io::Write;
 use std::str::FromStr;
 use xml;
@@ -36,41 +37,52 @@ use xml::EventReader;
 use xml::EventWriter;
 
 /// <p>A complex type that contains the type of limit that you specified in the request and the current value for that limit.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AccountLimit {
     /// <p><p>The limit that you requested. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>HEALTH</em>CHECKS<em>BY</em>OWNER</b>: The maximum number of health checks that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>HOSTED</em>ZONES<em>BY</em>OWNER</b>: The maximum number of hosted zones that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>REUSABLE</em>DELEGATION<em>SETS</em>BY<em>OWNER</b>: The maximum number of reusable delegation sets that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICIES</em>BY<em>OWNER</b>: The maximum number of traffic policies that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICY</em>INSTANCES<em>BY</em>OWNER</b>: The maximum number of traffic policy instances that you can create using the current account. (Traffic policy instances are referred to as traffic flow policy records in the Amazon Route 53 console.)</p> </li> </ul></p>
-    pub type_: String,
+    pub type_: AccountLimitType,
     /// <p>The current value for the limit that is specified by <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_AccountLimit.html#Route53-Type-AccountLimit-Type">Type</a>.</p>
     pub value: i64,
 }
 
-struct AccountLimitDeserializer;
+pub(crate) struct AccountLimitDeserializer;
 impl AccountLimitDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccountLimit, XmlParseError> {
-        deserialize_elements::<_, AccountLimit, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Type" => {
-                    obj.type_ = AccountLimitTypeDeserializer::deserialize("Type", stack)?;
-                }
-                "Value" => {
-                    obj.value = LimitValueDeserializer::deserialize("Value", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            AccountLimit {
+                type_: AccountLimitType::Unknown(String::new()),
+                value: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Type" => {
+                        obj.type_ = AccountLimitTypeDeserializer::deserialize("Type", stack)?;
+                    }
+                    "Value" => {
+                        obj.value = LimitValueDeserializer::deserialize("Value", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct AccountLimitTypeDeserializer;
+pub(crate) struct AccountLimitTypeDeserializer;
 impl AccountLimitTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AccountLimitType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -83,7 +95,7 @@ impl AccountLimitTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &AccountLimitType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -97,34 +109,121 @@ impl AccountLimitTypeSerializer {
     }
 }
 
+/// <p><p>The limit that you requested. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>HEALTH</em>CHECKS<em>BY</em>OWNER</b>: The maximum number of health checks that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>HOSTED</em>ZONES<em>BY</em>OWNER</b>: The maximum number of hosted zones that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>REUSABLE</em>DELEGATION<em>SETS</em>BY<em>OWNER</b>: The maximum number of reusable delegation sets that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICIES</em>BY<em>OWNER</b>: The maximum number of traffic policies that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICY</em>INSTANCES<em>BY</em>OWNER</b>: The maximum number of traffic policy instances that you can create using the current account. (Traffic policy instances are referred to as traffic flow policy records in the Amazon Route 53 console.)</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccountLimitType {
+    /// <p><code>MAX_HEALTH_CHECKS_BY_OWNER</code></p>
+    MaxHealthChecksByOwner,
+    /// <p><code>MAX_HOSTED_ZONES_BY_OWNER</code></p>
+    MaxHostedZonesByOwner,
+    /// <p><code>MAX_TRAFFIC_POLICY_INSTANCES_BY_OWNER</code></p>
+    MaxTrafficPolicyInstancesByOwner,
+    /// <p><code>MAX_REUSABLE_DELEGATION_SETS_BY_OWNER</code></p>
+    MaxReusableDelegationSetsByOwner,
+    /// <p><code>MAX_TRAFFIC_POLICIES_BY_OWNER</code></p>
+    MaxTrafficPoliciesByOwner,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccountLimitType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountLimitType::MaxHealthChecksByOwner => "MAX_HEALTH_CHECKS_BY_OWNER",
+            AccountLimitType::MaxHostedZonesByOwner => "MAX_HOSTED_ZONES_BY_OWNER",
+            AccountLimitType::MaxTrafficPolicyInstancesByOwner => {
+                "MAX_TRAFFIC_POLICY_INSTANCES_BY_OWNER"
+            }
+            AccountLimitType::MaxReusableDelegationSetsByOwner => {
+                "MAX_REUSABLE_DELEGATION_SETS_BY_OWNER"
+            }
+            AccountLimitType::MaxTrafficPoliciesByOwner => "MAX_TRAFFIC_POLICIES_BY_OWNER",
+            AccountLimitType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccountLimitType {
+    fn from(value: &'a str) -> AccountLimitType {
+        match value {
+            "MAX_HEALTH_CHECKS_BY_OWNER" => AccountLimitType::MaxHealthChecksByOwner,
+            "MAX_HOSTED_ZONES_BY_OWNER" => AccountLimitType::MaxHostedZonesByOwner,
+            "MAX_TRAFFIC_POLICY_INSTANCES_BY_OWNER" => {
+                AccountLimitType::MaxTrafficPolicyInstancesByOwner
+            }
+            "MAX_REUSABLE_DELEGATION_SETS_BY_OWNER" => {
+                AccountLimitType::MaxReusableDelegationSetsByOwner
+            }
+            "MAX_TRAFFIC_POLICIES_BY_OWNER" => AccountLimitType::MaxTrafficPoliciesByOwner,
+            _ => AccountLimitType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountLimitType {
+    fn from(value: String) -> AccountLimitType {
+        AccountLimitType::from(value.as_str())
+    }
+}
+
+impl From<AccountLimitType> for String {
+    fn from(value: AccountLimitType) -> String {
+        match value {
+            AccountLimitType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccountLimitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccountLimitType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A complex type that identifies the CloudWatch alarm that you want Amazon Route 53 health checkers to use to determine whether the specified health check is healthy.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AlarmIdentifier {
     /// <p><p>The name of the CloudWatch alarm that you want Amazon Route 53 health checkers to use to determine whether this health check is healthy.</p> <note> <p>Route 53 supports CloudWatch alarms with the following features:</p> <ul> <li> <p>Standard-resolution metrics. High-resolution metrics aren&#39;t supported. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudWatch/latest/DeveloperGuide/publishingMetrics.html#high-resolution-metrics">High-Resolution Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p> </li> <li> <p>Statistics: Average, Minimum, Maximum, Sum, and SampleCount. Extended statistics aren&#39;t supported.</p> </li> </ul> </note></p>
     pub name: String,
     /// <p>For the CloudWatch alarm that you want Route 53 health checkers to use to determine whether this health check is healthy, the region that the alarm was created in.</p> <p>For the current list of CloudWatch regions, see <a href="http://docs.aws.amazon.com/general/latest/gr/rande.html#cw_region">Amazon CloudWatch</a> in the <i>AWS Regions and Endpoints</i> chapter of the <i>Amazon Web Services General Reference</i>.</p>
-    pub region: String,
+    pub region: CloudWatchRegion,
 }
 
-struct AlarmIdentifierDeserializer;
+pub(crate) struct AlarmIdentifierDeserializer;
 impl AlarmIdentifierDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AlarmIdentifier, XmlParseError> {
-        deserialize_elements::<_, AlarmIdentifier, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Name" => {
-                    obj.name = AlarmNameDeserializer::deserialize("Name", stack)?;
-                }
-                "Region" => {
-                    obj.region = CloudWatchRegionDeserializer::deserialize("Region", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            AlarmIdentifier {
+                name: Default::default(),
+                region: CloudWatchRegion::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Name" => {
+                        obj.name = AlarmNameDeserializer::deserialize("Name", stack)?;
+                    }
+                    "Region" => {
+                        obj.region = CloudWatchRegionDeserializer::deserialize("Region", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -156,10 +255,13 @@ impl AlarmIdentifierSerializer {
     }
 }
 
-struct AlarmNameDeserializer;
+pub(crate) struct AlarmNameDeserializer;
 impl AlarmNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -188,10 +290,13 @@ impl AlarmNameSerializer {
     }
 }
 
-struct AliasHealthEnabledDeserializer;
+pub(crate) struct AliasHealthEnabledDeserializer;
 impl AliasHealthEnabledDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -231,10 +336,10 @@ pub struct AliasTarget {
     pub hosted_zone_id: String,
 }
 
-struct AliasTargetDeserializer;
+pub(crate) struct AliasTargetDeserializer;
 impl AliasTargetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AliasTarget, XmlParseError> {
@@ -323,6 +428,54 @@ pub struct AssociateVPCWithHostedZoneRequest {
     pub vpc: VPC,
 }
 
+impl AssociateVPCWithHostedZoneRequest {
+    /// Returns a builder for `AssociateVPCWithHostedZoneRequest`, checking its required members when built.
+    pub fn builder() -> AssociateVPCWithHostedZoneRequestBuilder {
+        AssociateVPCWithHostedZoneRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateVPCWithHostedZoneRequest`](struct.AssociateVPCWithHostedZoneRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateVPCWithHostedZoneRequestBuilder {
+    comment: Option<String>,
+    hosted_zone_id: Option<String>,
+    vpc: Option<VPC>,
+}
+
+impl AssociateVPCWithHostedZoneRequestBuilder {
+    /// Sets the [`comment`](struct.AssociateVPCWithHostedZoneRequest.html#structfield.comment) member.
+    pub fn comment<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hosted_zone_id`](struct.AssociateVPCWithHostedZoneRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc`](struct.AssociateVPCWithHostedZoneRequest.html#structfield.vpc) member.
+    pub fn vpc<T: Into<VPC>>(mut self, value: T) -> Self {
+        self.vpc = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateVPCWithHostedZoneRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateVPCWithHostedZoneRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateVPCWithHostedZoneRequest {
+            comment: self.comment,
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+            vpc: self.vpc.ok_or(::rusoto_core::BuildError::missing("vpc"))?,
+        })
+    }
+}
+
 pub struct AssociateVPCWithHostedZoneRequestSerializer;
 impl AssociateVPCWithHostedZoneRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -344,22 +497,30 @@ impl AssociateVPCWithHostedZoneRequestSerializer {
     }
 }
 /// <p>A complex type that contains the response information for the <code>AssociateVPCWithHostedZone</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AssociateVPCWithHostedZoneResponse {
     /// <p>A complex type that describes the changes made to your hosted zone.</p>
     pub change_info: ChangeInfo,
 }
 
-struct AssociateVPCWithHostedZoneResponseDeserializer;
+pub(crate) struct AssociateVPCWithHostedZoneResponseDeserializer;
 impl AssociateVPCWithHostedZoneResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AssociateVPCWithHostedZoneResponse, XmlParseError> {
-        deserialize_elements::<_, AssociateVPCWithHostedZoneResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            AssociateVPCWithHostedZoneResponse {
+                change_info: ChangeInfo {
+                    comment: Default::default(),
+                    id: Default::default(),
+                    status: ChangeStatus::Unknown(String::new()),
+                    submitted_at: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "ChangeInfo" => {
@@ -373,10 +534,10 @@ impl AssociateVPCWithHostedZoneResponseDeserializer {
     }
 }
 /// <p>The information for each resource record set that you want to change.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Change {
     /// <p><p>The action to perform:</p> <ul> <li> <p> <code>CREATE</code>: Creates a resource record set that has the specified values.</p> </li> <li> <p> <code>DELETE</code>: Deletes a existing resource record set.</p> <important> <p>To delete the resource record set that is associated with a traffic policy instance, use <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_DeleteTrafficPolicyInstance.html">DeleteTrafficPolicyInstance</a>. Amazon Route 53 will delete the resource record set automatically. If you delete the resource record set by using <code>ChangeResourceRecordSets</code>, Route 53 doesn&#39;t automatically delete the traffic policy instance, and you&#39;ll continue to be charged for it even though it&#39;s no longer in use. </p> </important> </li> <li> <p> <code>UPSERT</code>: If a resource record set doesn&#39;t already exist, Route 53 creates it. If a resource record set does exist, Route 53 updates it with the values in the request.</p> </li> </ul></p>
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Information about the resource record set to create, delete, or update.</p>
     pub resource_record_set: ResourceRecordSet,
 }
@@ -414,7 +575,7 @@ impl ChangeActionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ChangeAction,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -428,6 +589,69 @@ impl ChangeActionSerializer {
     }
 }
 
+/// <p><p>The action to perform:</p> <ul> <li> <p> <code>CREATE</code>: Creates a resource record set that has the specified values.</p> </li> <li> <p> <code>DELETE</code>: Deletes a existing resource record set.</p> <important> <p>To delete the resource record set that is associated with a traffic policy instance, use <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_DeleteTrafficPolicyInstance.html">DeleteTrafficPolicyInstance</a>. Amazon Route 53 will delete the resource record set automatically. If you delete the resource record set by using <code>ChangeResourceRecordSets</code>, Route 53 doesn&#39;t automatically delete the traffic policy instance, and you&#39;ll continue to be charged for it even though it&#39;s no longer in use. </p> </important> </li> <li> <p> <code>UPSERT</code>: If a resource record set doesn&#39;t already exist, Route 53 creates it. If a resource record set does exist, Route 53 updates it with the values in the request.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeAction {
+    /// <p><code>CREATE</code> - Creates a resource record set that has the specified values.</p>
+    Create,
+    /// <p><code>DELETE</code> - Deletes a existing resource record set.</p>
+    Delete,
+    /// <p><code>UPSERT</code> - If a resource record set doesn&#39;t already exist, Route 53 creates it. If a resource record set does exist, Route 53 updates it with the values in the request.</p>
+    Upsert,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeAction::Create => "CREATE",
+            ChangeAction::Delete => "DELETE",
+            ChangeAction::Upsert => "UPSERT",
+            ChangeAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeAction {
+    fn from(value: &'a str) -> ChangeAction {
+        match value {
+            "CREATE" => ChangeAction::Create,
+            "DELETE" => ChangeAction::Delete,
+            "UPSERT" => ChangeAction::Upsert,
+            _ => ChangeAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeAction {
+    fn from(value: String) -> ChangeAction {
+        ChangeAction::from(value.as_str())
+    }
+}
+
+impl From<ChangeAction> for String {
+    fn from(value: ChangeAction) -> String {
+        match value {
+            ChangeAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>The information for a change request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ChangeBatch {
@@ -463,45 +687,56 @@ impl ChangeBatchSerializer {
 }
 
 /// <p>A complex type that describes change information about changes made to your hosted zone.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ChangeInfo {
     /// <p>A complex type that describes change information about changes made to your hosted zone.</p> <p>This element contains an ID that you use when performing a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetChange.html">GetChange</a> action to get detailed information about the change.</p>
     pub comment: Option<String>,
     /// <p>The ID of the request.</p>
     pub id: String,
     /// <p>The current state of the request. <code>PENDING</code> indicates that this request has not yet been applied to all Amazon Route 53 DNS servers.</p>
-    pub status: String,
+    pub status: ChangeStatus,
     /// <p>The date and time that the change request was submitted in <a href="https://en.wikipedia.org/wiki/ISO_8601">ISO 8601 format</a> and Coordinated Universal Time (UTC). For example, the value <code>2017-03-27T17:48:16.751Z</code> represents March 27, 2017 at 17:48:16.751 UTC.</p>
-    pub submitted_at: String,
+    pub submitted_at: IsoTimestamp,
 }
 
-struct ChangeInfoDeserializer;
+pub(crate) struct ChangeInfoDeserializer;
 impl ChangeInfoDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ChangeInfo, XmlParseError> {
-        deserialize_elements::<_, ChangeInfo, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Comment" => {
-                    obj.comment = Some(ResourceDescriptionDeserializer::deserialize(
-                        "Comment", stack,
-                    )?);
-                }
-                "Id" => {
-                    obj.id = ResourceIdDeserializer::deserialize("Id", stack)?;
-                }
-                "Status" => {
-                    obj.status = ChangeStatusDeserializer::deserialize("Status", stack)?;
-                }
-                "SubmittedAt" => {
-                    obj.submitted_at = TimeStampDeserializer::deserialize("SubmittedAt", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            ChangeInfo {
+                comment: Default::default(),
+                id: Default::default(),
+                status: ChangeStatus::Unknown(String::new()),
+                submitted_at: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Comment" => {
+                        obj.comment = Some(ResourceDescriptionDeserializer::deserialize(
+                            "Comment", stack,
+                        )?);
+                    }
+                    "Id" => {
+                        obj.id = ResourceIdDeserializer::deserialize("Id", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = ChangeStatusDeserializer::deserialize("Status", stack)?;
+                    }
+                    "SubmittedAt" => {
+                        obj.submitted_at =
+                            TimeStampDeserializer::deserialize("SubmittedAt", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 /// <p>A complex type that contains change information for the resource record set.</p>
@@ -513,6 +748,48 @@ pub struct ChangeResourceRecordSetsRequest {
     pub hosted_zone_id: String,
 }
 
+impl ChangeResourceRecordSetsRequest {
+    /// Returns a builder for `ChangeResourceRecordSetsRequest`, checking its required members when built.
+    pub fn builder() -> ChangeResourceRecordSetsRequestBuilder {
+        ChangeResourceRecordSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ChangeResourceRecordSetsRequest`](struct.ChangeResourceRecordSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ChangeResourceRecordSetsRequestBuilder {
+    change_batch: Option<ChangeBatch>,
+    hosted_zone_id: Option<String>,
+}
+
+impl ChangeResourceRecordSetsRequestBuilder {
+    /// Sets the [`change_batch`](struct.ChangeResourceRecordSetsRequest.html#structfield.change_batch) member.
+    pub fn change_batch<T: Into<ChangeBatch>>(mut self, value: T) -> Self {
+        self.change_batch = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hosted_zone_id`](struct.ChangeResourceRecordSetsRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ChangeResourceRecordSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ChangeResourceRecordSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ChangeResourceRecordSetsRequest {
+            change_batch: self
+                .change_batch
+                .ok_or(::rusoto_core::BuildError::missing("change_batch"))?,
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+        })
+    }
+}
+
 pub struct ChangeResourceRecordSetsRequestSerializer;
 impl ChangeResourceRecordSetsRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -531,22 +808,30 @@ impl ChangeResourceRecordSetsRequestSerializer {
     }
 }
 /// <p>A complex type containing the response for the request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ChangeResourceRecordSetsResponse {
     /// <p>A complex type that contains information about changes made to your hosted zone.</p> <p>This element contains an ID that you use when performing a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetChange.html">GetChange</a> action to get detailed information about the change.</p>
     pub change_info: ChangeInfo,
 }
 
-struct ChangeResourceRecordSetsResponseDeserializer;
+pub(crate) struct ChangeResourceRecordSetsResponseDeserializer;
 impl ChangeResourceRecordSetsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ChangeResourceRecordSetsResponse, XmlParseError> {
-        deserialize_elements::<_, ChangeResourceRecordSetsResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            ChangeResourceRecordSetsResponse {
+                change_info: ChangeInfo {
+                    comment: Default::default(),
+                    id: Default::default(),
+                    status: ChangeStatus::Unknown(String::new()),
+                    submitted_at: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "ChangeInfo" => {
@@ -559,19 +844,81 @@ impl ChangeResourceRecordSetsResponseDeserializer {
         )
     }
 }
-struct ChangeStatusDeserializer;
+pub(crate) struct ChangeStatusDeserializer;
 impl ChangeStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The current state of the request. <code>PENDING</code> indicates that this request has not yet been applied to all Amazon Route 53 DNS servers.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeStatus {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>INSYNC</code></p>
+    Insync,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeStatus::Pending => "PENDING",
+            ChangeStatus::Insync => "INSYNC",
+            ChangeStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeStatus {
+    fn from(value: &'a str) -> ChangeStatus {
+        match value {
+            "PENDING" => ChangeStatus::Pending,
+            "INSYNC" => ChangeStatus::Insync,
+            _ => ChangeStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeStatus {
+    fn from(value: String) -> ChangeStatus {
+        ChangeStatus::from(value.as_str())
+    }
+}
+
+impl From<ChangeStatus> for String {
+    fn from(value: ChangeStatus) -> String {
+        match value {
+            ChangeStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A complex type that contains information about the tags that you want to add, edit, or delete.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ChangeTagsForResourceRequest {
     /// <p>A complex type that contains a list of the tags that you want to add to the specified health check or hosted zone and/or the tags that you want to edit <code>Value</code> for.</p> <p>You can add a maximum of 10 tags to a health check or a hosted zone.</p>
     pub add_tags: Option<Vec<Tag>>,
@@ -580,7 +927,65 @@ pub struct ChangeTagsForResourceRequest {
     /// <p>The ID of the resource for which you want to add, change, or delete tags.</p>
     pub resource_id: String,
     /// <p><p>The type of the resource.</p> <ul> <li> <p>The resource type for health checks is <code>healthcheck</code>.</p> </li> <li> <p>The resource type for hosted zones is <code>hostedzone</code>.</p> </li> </ul></p>
-    pub resource_type: String,
+    pub resource_type: TagResourceType,
+}
+
+impl ChangeTagsForResourceRequest {
+    /// Returns a builder for `ChangeTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ChangeTagsForResourceRequestBuilder {
+        ChangeTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ChangeTagsForResourceRequest`](struct.ChangeTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ChangeTagsForResourceRequestBuilder {
+    add_tags: Option<Vec<Tag>>,
+    remove_tag_keys: Option<Vec<String>>,
+    resource_id: Option<String>,
+    resource_type: Option<TagResourceType>,
+}
+
+impl ChangeTagsForResourceRequestBuilder {
+    /// Sets the [`add_tags`](struct.ChangeTagsForResourceRequest.html#structfield.add_tags) member.
+    pub fn add_tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.add_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remove_tag_keys`](struct.ChangeTagsForResourceRequest.html#structfield.remove_tag_keys) member.
+    pub fn remove_tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.remove_tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.ChangeTagsForResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.ChangeTagsForResourceRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<TagResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ChangeTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ChangeTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ChangeTagsForResourceRequest {
+            add_tags: self.add_tags,
+            remove_tag_keys: self.remove_tag_keys,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+        })
+    }
 }
 
 pub struct ChangeTagsForResourceRequestSerializer;
@@ -609,10 +1014,10 @@ impl ChangeTagsForResourceRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ChangeTagsForResourceResponse {}
 
-struct ChangeTagsForResourceResponseDeserializer;
+pub(crate) struct ChangeTagsForResourceResponseDeserializer;
 impl ChangeTagsForResourceResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ChangeTagsForResourceResponse, XmlParseError> {
@@ -646,10 +1051,10 @@ impl ChangesSerializer {
     }
 }
 
-struct CheckerIpRangesDeserializer;
+pub(crate) struct CheckerIpRangesDeserializer;
 impl CheckerIpRangesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -663,10 +1068,10 @@ impl CheckerIpRangesDeserializer {
         })
     }
 }
-struct ChildHealthCheckListDeserializer;
+pub(crate) struct ChildHealthCheckListDeserializer;
 impl ChildHealthCheckListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -705,10 +1110,10 @@ impl ChildHealthCheckListSerializer {
 }
 
 /// <p>A complex type that contains information about the CloudWatch alarm that Amazon Route 53 is monitoring for this health check.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CloudWatchAlarmConfiguration {
     /// <p>For the metric that the CloudWatch alarm is associated with, the arithmetic operation that is used for the comparison.</p>
-    pub comparison_operator: String,
+    pub comparison_operator: ComparisonOperator,
     /// <p>For the metric that the CloudWatch alarm is associated with, a complex type that contains information about the dimensions for the metric. For information, see <a href="http://docs.aws.amazon.com/AmazonCloudWatch/latest/DeveloperGuide/CW_Support_For_AWS.html">Amazon CloudWatch Namespaces, Dimensions, and Metrics Reference</a> in the <i>Amazon CloudWatch User Guide</i>.</p>
     pub dimensions: Option<Vec<Dimension>>,
     /// <p>For the metric that the CloudWatch alarm is associated with, the number of periods that the metric is compared to the threshold.</p>
@@ -720,21 +1125,31 @@ pub struct CloudWatchAlarmConfiguration {
     /// <p>For the metric that the CloudWatch alarm is associated with, the duration of one evaluation period in seconds.</p>
     pub period: i64,
     /// <p>For the metric that the CloudWatch alarm is associated with, the statistic that is applied to the metric.</p>
-    pub statistic: String,
+    pub statistic: Statistic,
     /// <p>For the metric that the CloudWatch alarm is associated with, the value the metric is compared with.</p>
     pub threshold: f64,
 }
 
-struct CloudWatchAlarmConfigurationDeserializer;
+pub(crate) struct CloudWatchAlarmConfigurationDeserializer;
 impl CloudWatchAlarmConfigurationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CloudWatchAlarmConfiguration, XmlParseError> {
-        deserialize_elements::<_, CloudWatchAlarmConfiguration, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            CloudWatchAlarmConfiguration {
+                comparison_operator: ComparisonOperator::Unknown(String::new()),
+                dimensions: Default::default(),
+                evaluation_periods: Default::default(),
+                metric_name: Default::default(),
+                namespace: Default::default(),
+                period: Default::default(),
+                statistic: Statistic::Unknown(String::new()),
+                threshold: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "ComparisonOperator" => {
@@ -774,10 +1189,13 @@ impl CloudWatchAlarmConfigurationDeserializer {
         )
     }
 }
-struct CloudWatchLogsLogGroupArnDeserializer;
+pub(crate) struct CloudWatchLogsLogGroupArnDeserializer;
 impl CloudWatchLogsLogGroupArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -806,12 +1224,15 @@ impl CloudWatchLogsLogGroupArnSerializer {
     }
 }
 
-struct CloudWatchRegionDeserializer;
+pub(crate) struct CloudWatchRegionDeserializer;
 impl CloudWatchRegionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<CloudWatchRegion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -824,7 +1245,7 @@ impl CloudWatchRegionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &CloudWatchRegion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -838,25 +1259,332 @@ impl CloudWatchRegionSerializer {
     }
 }
 
-struct ComparisonOperatorDeserializer;
+/// <p>For the CloudWatch alarm that you want Route 53 health checkers to use to determine whether this health check is healthy, the region that the alarm was created in.</p> <p>For the current list of CloudWatch regions, see <a href="http://docs.aws.amazon.com/general/latest/gr/rande.html#cw_region">Amazon CloudWatch</a> in the <i>AWS Regions and Endpoints</i> chapter of the <i>Amazon Web Services General Reference</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CloudWatchRegion {
+    /// <p><code>us-east-1</code></p>
+    UsEast1,
+    /// <p><code>us-east-2</code></p>
+    UsEast2,
+    /// <p><code>us-west-1</code></p>
+    UsWest1,
+    /// <p><code>us-west-2</code></p>
+    UsWest2,
+    /// <p><code>ca-central-1</code></p>
+    CaCentral1,
+    /// <p><code>eu-central-1</code></p>
+    EuCentral1,
+    /// <p><code>eu-central-2</code></p>
+    EuCentral2,
+    /// <p><code>eu-west-1</code></p>
+    EuWest1,
+    /// <p><code>eu-west-2</code></p>
+    EuWest2,
+    /// <p><code>eu-west-3</code></p>
+    EuWest3,
+    /// <p><code>ap-east-1</code></p>
+    ApEast1,
+    /// <p><code>me-south-1</code></p>
+    MeSouth1,
+    /// <p><code>me-central-1</code></p>
+    MeCentral1,
+    /// <p><code>ap-south-1</code></p>
+    ApSouth1,
+    /// <p><code>ap-south-2</code></p>
+    ApSouth2,
+    /// <p><code>ap-southeast-1</code></p>
+    ApSoutheast1,
+    /// <p><code>ap-southeast-2</code></p>
+    ApSoutheast2,
+    /// <p><code>ap-southeast-3</code></p>
+    ApSoutheast3,
+    /// <p><code>ap-northeast-1</code></p>
+    ApNortheast1,
+    /// <p><code>ap-northeast-2</code></p>
+    ApNortheast2,
+    /// <p><code>ap-northeast-3</code></p>
+    ApNortheast3,
+    /// <p><code>eu-north-1</code></p>
+    EuNorth1,
+    /// <p><code>sa-east-1</code></p>
+    SaEast1,
+    /// <p><code>cn-northwest-1</code></p>
+    CnNorthwest1,
+    /// <p><code>cn-north-1</code></p>
+    CnNorth1,
+    /// <p><code>af-south-1</code></p>
+    AfSouth1,
+    /// <p><code>eu-south-1</code></p>
+    EuSouth1,
+    /// <p><code>eu-south-2</code></p>
+    EuSouth2,
+    /// <p><code>us-gov-west-1</code></p>
+    UsGovWest1,
+    /// <p><code>us-gov-east-1</code></p>
+    UsGovEast1,
+    /// <p><code>us-iso-east-1</code></p>
+    UsIsoEast1,
+    /// <p><code>us-iso-west-1</code></p>
+    UsIsoWest1,
+    /// <p><code>us-isob-east-1</code></p>
+    UsIsobEast1,
+    /// <p><code>ap-southeast-4</code></p>
+    ApSoutheast4,
+    /// <p><code>il-central-1</code></p>
+    IlCentral1,
+    /// <p><code>ca-west-1</code></p>
+    CaWest1,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CloudWatchRegion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudWatchRegion::UsEast1 => "us-east-1",
+            CloudWatchRegion::UsEast2 => "us-east-2",
+            CloudWatchRegion::UsWest1 => "us-west-1",
+            CloudWatchRegion::UsWest2 => "us-west-2",
+            CloudWatchRegion::CaCentral1 => "ca-central-1",
+            CloudWatchRegion::EuCentral1 => "eu-central-1",
+            CloudWatchRegion::EuCentral2 => "eu-central-2",
+            CloudWatchRegion::EuWest1 => "eu-west-1",
+            CloudWatchRegion::EuWest2 => "eu-west-2",
+            CloudWatchRegion::EuWest3 => "eu-west-3",
+            CloudWatchRegion::ApEast1 => "ap-east-1",
+            CloudWatchRegion::MeSouth1 => "me-south-1",
+            CloudWatchRegion::MeCentral1 => "me-central-1",
+            CloudWatchRegion::ApSouth1 => "ap-south-1",
+            CloudWatchRegion::ApSouth2 => "ap-south-2",
+            CloudWatchRegion::ApSoutheast1 => "ap-southeast-1",
+            CloudWatchRegion::ApSoutheast2 => "ap-southeast-2",
+            CloudWatchRegion::ApSoutheast3 => "ap-southeast-3",
+            CloudWatchRegion::ApNortheast1 => "ap-northeast-1",
+            CloudWatchRegion::ApNortheast2 => "ap-northeast-2",
+            CloudWatchRegion::ApNortheast3 => "ap-northeast-3",
+            CloudWatchRegion::EuNorth1 => "eu-north-1",
+            CloudWatchRegion::SaEast1 => "sa-east-1",
+            CloudWatchRegion::CnNorthwest1 => "cn-northwest-1",
+            CloudWatchRegion::CnNorth1 => "cn-north-1",
+            CloudWatchRegion::AfSouth1 => "af-south-1",
+            CloudWatchRegion::EuSouth1 => "eu-south-1",
+            CloudWatchRegion::EuSouth2 => "eu-south-2",
+            CloudWatchRegion::UsGovWest1 => "us-gov-west-1",
+            CloudWatchRegion::UsGovEast1 => "us-gov-east-1",
+            CloudWatchRegion::UsIsoEast1 => "us-iso-east-1",
+            CloudWatchRegion::UsIsoWest1 => "us-iso-west-1",
+            CloudWatchRegion::UsIsobEast1 => "us-isob-east-1",
+            CloudWatchRegion::ApSoutheast4 => "ap-southeast-4",
+            CloudWatchRegion::IlCentral1 => "il-central-1",
+            CloudWatchRegion::CaWest1 => "ca-west-1",
+            CloudWatchRegion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CloudWatchRegion {
+    fn from(value: &'a str) -> CloudWatchRegion {
+        match value {
+            "us-east-1" => CloudWatchRegion::UsEast1,
+            "us-east-2" => CloudWatchRegion::UsEast2,
+            "us-west-1" => CloudWatchRegion::UsWest1,
+            "us-west-2" => CloudWatchRegion::UsWest2,
+            "ca-central-1" => CloudWatchRegion::CaCentral1,
+            "eu-central-1" => CloudWatchRegion::EuCentral1,
+            "eu-central-2" => CloudWatchRegion::EuCentral2,
+            "eu-west-1" => CloudWatchRegion::EuWest1,
+            "eu-west-2" => CloudWatchRegion::EuWest2,
+            "eu-west-3" => CloudWatchRegion::EuWest3,
+            "ap-east-1" => CloudWatchRegion::ApEast1,
+            "me-south-1" => CloudWatchRegion::MeSouth1,
+            "me-central-1" => CloudWatchRegion::MeCentral1,
+            "ap-south-1" => CloudWatchRegion::ApSouth1,
+            "ap-south-2" => CloudWatchRegion::ApSouth2,
+            "ap-southeast-1" => CloudWatchRegion::ApSoutheast1,
+            "ap-southeast-2" => CloudWatchRegion::ApSoutheast2,
+            "ap-southeast-3" => CloudWatchRegion::ApSoutheast3,
+            "ap-northeast-1" => CloudWatchRegion::ApNortheast1,
+            "ap-northeast-2" => CloudWatchRegion::ApNortheast2,
+            "ap-northeast-3" => CloudWatchRegion::ApNortheast3,
+            "eu-north-1" => CloudWatchRegion::EuNorth1,
+            "sa-east-1" => CloudWatchRegion::SaEast1,
+            "cn-northwest-1" => CloudWatchRegion::CnNorthwest1,
+            "cn-north-1" => CloudWatchRegion::CnNorth1,
+            "af-south-1" => CloudWatchRegion::AfSouth1,
+            "eu-south-1" => CloudWatchRegion::EuSouth1,
+            "eu-south-2" => CloudWatchRegion::EuSouth2,
+            "us-gov-west-1" => CloudWatchRegion::UsGovWest1,
+            "us-gov-east-1" => CloudWatchRegion::UsGovEast1,
+            "us-iso-east-1" => CloudWatchRegion::UsIsoEast1,
+            "us-iso-west-1" => CloudWatchRegion::UsIsoWest1,
+            "us-isob-east-1" => CloudWatchRegion::UsIsobEast1,
+            "ap-southeast-4" => CloudWatchRegion::ApSoutheast4,
+            "il-central-1" => CloudWatchRegion::IlCentral1,
+            "ca-west-1" => CloudWatchRegion::CaWest1,
+            _ => CloudWatchRegion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudWatchRegion {
+    fn from(value: String) -> CloudWatchRegion {
+        CloudWatchRegion::from(value.as_str())
+    }
+}
+
+impl From<CloudWatchRegion> for String {
+    fn from(value: CloudWatchRegion) -> String {
+        match value {
+            CloudWatchRegion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CloudWatchRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CloudWatchRegion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ComparisonOperatorDeserializer;
 impl ComparisonOperatorDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ComparisonOperator, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-/// <p>A complex type that contains the health check request information.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct CreateHealthCheckRequest {
-    /// <p><p>A unique string that identifies the request and that allows you to retry a failed <code>CreateHealthCheck</code> request without the risk of creating two identical health checks:</p> <ul> <li> <p>If you send a <code>CreateHealthCheck</code> request with the same <code>CallerReference</code> and settings as a previous request, and if the health check doesn&#39;t exist, Amazon Route 53 creates the health check. If the health check does exist, Route 53 returns the settings for the existing health check.</p> </li> <li> <p>If you send a <code>CreateHealthCheck</code> request with the same <code>CallerReference</code> as a deleted health check, regardless of the settings, Route 53 returns a <code>HealthCheckAlreadyExists</code> error.</p> </li> <li> <p>If you send a <code>CreateHealthCheck</code> request with the same <code>CallerReference</code> as an existing health check but with different settings, Route 53 returns a <code>HealthCheckAlreadyExists</code> error.</p> </li> <li> <p>If you send a <code>CreateHealthCheck</code> request with a unique <code>CallerReference</code> but settings identical to an existing health check, Route 53 creates the health check.</p> </li> </ul></p>
-    pub caller_reference: String,
-    /// <p>A complex type that contains settings for a new health check.</p>
-    pub health_check_config: HealthCheckConfig,
-}
+/// <p>For the metric that the CloudWatch alarm is associated with, the arithmetic operation that is used for the comparison.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonOperator {
+    /// <p><code>GreaterThanOrEqualToThreshold</code></p>
+    GreaterThanOrEqualToThreshold,
+    /// <p><code>GreaterThanThreshold</code></p>
+    GreaterThanThreshold,
+    /// <p><code>LessThanThreshold</code></p>
+    LessThanThreshold,
+    /// <p><code>LessThanOrEqualToThreshold</code></p>
+    LessThanOrEqualToThreshold,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::GreaterThanOrEqualToThreshold => "GreaterThanOrEqualToThreshold",
+            ComparisonOperator::GreaterThanThreshold => "GreaterThanThreshold",
+            ComparisonOperator::LessThanThreshold => "LessThanThreshold",
+            ComparisonOperator::LessThanOrEqualToThreshold => "LessThanOrEqualToThreshold",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "GreaterThanOrEqualToThreshold" => ComparisonOperator::GreaterThanOrEqualToThreshold,
+            "GreaterThanThreshold" => ComparisonOperator::GreaterThanThreshold,
+            "LessThanThreshold" => ComparisonOperator::LessThanThreshold,
+            "LessThanOrEqualToThreshold" => ComparisonOperator::LessThanOrEqualToThreshold,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        ComparisonOperator::from(value.as_str())
+    }
+}
+
+impl From<ComparisonOperator> for String {
+    fn from(value: ComparisonOperator) -> String {
+        match value {
+            ComparisonOperator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p>A complex type that contains the health check request information.</p>
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateHealthCheckRequest {
+    /// <p><p>A unique string that identifies the request and that allows you to retry a failed <code>CreateHealthCheck</code> request without the risk of creating two identical health checks:</p> <ul> <li> <p>If you send a <code>CreateHealthCheck</code> request with the same <code>CallerReference</code> and settings as a previous request, and if the health check doesn&#39;t exist, Amazon Route 53 creates the health check. If the health check does exist, Route 53 returns the settings for the existing health check.</p> </li> <li> <p>If you send a <code>CreateHealthCheck</code> request with the same <code>CallerReference</code> as a deleted health check, regardless of the settings, Route 53 returns a <code>HealthCheckAlreadyExists</code> error.</p> </li> <li> <p>If you send a <code>CreateHealthCheck</code> request with the same <code>CallerReference</code> as an existing health check but with different settings, Route 53 returns a <code>HealthCheckAlreadyExists</code> error.</p> </li> <li> <p>If you send a <code>CreateHealthCheck</code> request with a unique <code>CallerReference</code> but settings identical to an existing health check, Route 53 creates the health check.</p> </li> </ul></p>
+    pub caller_reference: String,
+    /// <p>A complex type that contains settings for a new health check.</p>
+    pub health_check_config: HealthCheckConfig,
+}
+
+impl CreateHealthCheckRequest {
+    /// Returns a builder for `CreateHealthCheckRequest`, checking its required members when built.
+    pub fn builder() -> CreateHealthCheckRequestBuilder {
+        CreateHealthCheckRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateHealthCheckRequest`](struct.CreateHealthCheckRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateHealthCheckRequestBuilder {
+    caller_reference: Option<String>,
+    health_check_config: Option<HealthCheckConfig>,
+}
+
+impl CreateHealthCheckRequestBuilder {
+    /// Sets the [`caller_reference`](struct.CreateHealthCheckRequest.html#structfield.caller_reference) member.
+    pub fn caller_reference<T: Into<String>>(mut self, value: T) -> Self {
+        self.caller_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_config`](struct.CreateHealthCheckRequest.html#structfield.health_check_config) member.
+    pub fn health_check_config<T: Into<HealthCheckConfig>>(mut self, value: T) -> Self {
+        self.health_check_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateHealthCheckRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateHealthCheckRequest, ::rusoto_core::BuildError> {
+        Ok(CreateHealthCheckRequest {
+            caller_reference: self
+                .caller_reference
+                .ok_or(::rusoto_core::BuildError::missing("caller_reference"))?,
+            health_check_config: self
+                .health_check_config
+                .ok_or(::rusoto_core::BuildError::missing("health_check_config"))?,
+        })
+    }
+}
 
 pub struct CreateHealthCheckRequestSerializer;
 impl CreateHealthCheckRequestSerializer {
@@ -885,7 +1613,7 @@ impl CreateHealthCheckRequestSerializer {
     }
 }
 /// <p>A complex type containing the response information for the new health check.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateHealthCheckResponse {
     /// <p>A complex type that contains identifying information about the health check.</p>
     pub health_check: HealthCheck,
@@ -893,16 +1621,45 @@ pub struct CreateHealthCheckResponse {
     pub location: String,
 }
 
-struct CreateHealthCheckResponseDeserializer;
+pub(crate) struct CreateHealthCheckResponseDeserializer;
 impl CreateHealthCheckResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateHealthCheckResponse, XmlParseError> {
-        deserialize_elements::<_, CreateHealthCheckResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            CreateHealthCheckResponse {
+                health_check: HealthCheck {
+                    caller_reference: Default::default(),
+                    cloud_watch_alarm_configuration: Default::default(),
+                    health_check_config: HealthCheckConfig {
+                        alarm_identifier: Default::default(),
+                        child_health_checks: Default::default(),
+                        disabled: Default::default(),
+                        enable_sni: Default::default(),
+                        failure_threshold: Default::default(),
+                        fully_qualified_domain_name: Default::default(),
+                        health_threshold: Default::default(),
+                        ip_address: Default::default(),
+                        insufficient_data_health_status: Default::default(),
+                        inverted: Default::default(),
+                        measure_latency: Default::default(),
+                        port: Default::default(),
+                        regions: Default::default(),
+                        request_interval: Default::default(),
+                        resource_path: Default::default(),
+                        search_string: Default::default(),
+                        type_: HealthCheckType::Unknown(String::new()),
+                    },
+                    health_check_version: Default::default(),
+                    id: Default::default(),
+                    linked_service: Default::default(),
+                },
+                location: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "HealthCheck" => {
@@ -931,6 +1688,72 @@ pub struct CreateHostedZoneRequest {
     pub vpc: Option<VPC>,
 }
 
+impl CreateHostedZoneRequest {
+    /// Returns a builder for `CreateHostedZoneRequest`, checking its required members when built.
+    pub fn builder() -> CreateHostedZoneRequestBuilder {
+        CreateHostedZoneRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateHostedZoneRequest`](struct.CreateHostedZoneRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateHostedZoneRequestBuilder {
+    caller_reference: Option<String>,
+    delegation_set_id: Option<String>,
+    hosted_zone_config: Option<HostedZoneConfig>,
+    name: Option<String>,
+    vpc: Option<VPC>,
+}
+
+impl CreateHostedZoneRequestBuilder {
+    /// Sets the [`caller_reference`](struct.CreateHostedZoneRequest.html#structfield.caller_reference) member.
+    pub fn caller_reference<T: Into<String>>(mut self, value: T) -> Self {
+        self.caller_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delegation_set_id`](struct.CreateHostedZoneRequest.html#structfield.delegation_set_id) member.
+    pub fn delegation_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.delegation_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hosted_zone_config`](struct.CreateHostedZoneRequest.html#structfield.hosted_zone_config) member.
+    pub fn hosted_zone_config<T: Into<HostedZoneConfig>>(mut self, value: T) -> Self {
+        self.hosted_zone_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateHostedZoneRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc`](struct.CreateHostedZoneRequest.html#structfield.vpc) member.
+    pub fn vpc<T: Into<VPC>>(mut self, value: T) -> Self {
+        self.vpc = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateHostedZoneRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateHostedZoneRequest, ::rusoto_core::BuildError> {
+        Ok(CreateHostedZoneRequest {
+            caller_reference: self
+                .caller_reference
+                .ok_or(::rusoto_core::BuildError::missing("caller_reference"))?,
+            delegation_set_id: self.delegation_set_id,
+            hosted_zone_config: self.hosted_zone_config,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            vpc: self.vpc,
+        })
+    }
+}
+
 pub struct CreateHostedZoneRequestSerializer;
 impl CreateHostedZoneRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -959,7 +1782,7 @@ impl CreateHostedZoneRequestSerializer {
     }
 }
 /// <p>A complex type containing the response information for the hosted zone.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateHostedZoneResponse {
     /// <p>A complex type that contains information about the <code>CreateHostedZone</code> request.</p>
     pub change_info: ChangeInfo,
@@ -973,16 +1796,28 @@ pub struct CreateHostedZoneResponse {
     pub vpc: Option<VPC>,
 }
 
-struct CreateHostedZoneResponseDeserializer;
+pub(crate) struct CreateHostedZoneResponseDeserializer;
 impl CreateHostedZoneResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateHostedZoneResponse, XmlParseError> {
-        deserialize_elements::<_, CreateHostedZoneResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            CreateHostedZoneResponse {
+                change_info: ChangeInfo {
+                    comment: Default::default(),
+                    id: Default::default(),
+                    status: ChangeStatus::Unknown(String::new()),
+                    submitted_at: Default::default(),
+                },
+                delegation_set: Default::default(),
+                hosted_zone: Default::default(),
+                location: Default::default(),
+                vpc: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "ChangeInfo" => {
@@ -1013,6 +1848,48 @@ pub struct CreateQueryLoggingConfigRequest {
     pub hosted_zone_id: String,
 }
 
+impl CreateQueryLoggingConfigRequest {
+    /// Returns a builder for `CreateQueryLoggingConfigRequest`, checking its required members when built.
+    pub fn builder() -> CreateQueryLoggingConfigRequestBuilder {
+        CreateQueryLoggingConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateQueryLoggingConfigRequest`](struct.CreateQueryLoggingConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateQueryLoggingConfigRequestBuilder {
+    cloud_watch_logs_log_group_arn: Option<String>,
+    hosted_zone_id: Option<String>,
+}
+
+impl CreateQueryLoggingConfigRequestBuilder {
+    /// Sets the [`cloud_watch_logs_log_group_arn`](struct.CreateQueryLoggingConfigRequest.html#structfield.cloud_watch_logs_log_group_arn) member.
+    pub fn cloud_watch_logs_log_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cloud_watch_logs_log_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hosted_zone_id`](struct.CreateQueryLoggingConfigRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateQueryLoggingConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateQueryLoggingConfigRequest, ::rusoto_core::BuildError> {
+        Ok(CreateQueryLoggingConfigRequest {
+            cloud_watch_logs_log_group_arn: self.cloud_watch_logs_log_group_arn.ok_or(
+                ::rusoto_core::BuildError::missing("cloud_watch_logs_log_group_arn"),
+            )?,
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+        })
+    }
+}
+
 pub struct CreateQueryLoggingConfigRequestSerializer;
 impl CreateQueryLoggingConfigRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1043,10 +1920,10 @@ pub struct CreateQueryLoggingConfigResponse {
     pub query_logging_config: QueryLoggingConfig,
 }
 
-struct CreateQueryLoggingConfigResponseDeserializer;
+pub(crate) struct CreateQueryLoggingConfigResponseDeserializer;
 impl CreateQueryLoggingConfigResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateQueryLoggingConfigResponse, XmlParseError> {
@@ -1076,6 +1953,46 @@ pub struct CreateReusableDelegationSetRequest {
     pub hosted_zone_id: Option<String>,
 }
 
+impl CreateReusableDelegationSetRequest {
+    /// Returns a builder for `CreateReusableDelegationSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateReusableDelegationSetRequestBuilder {
+        CreateReusableDelegationSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateReusableDelegationSetRequest`](struct.CreateReusableDelegationSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateReusableDelegationSetRequestBuilder {
+    caller_reference: Option<String>,
+    hosted_zone_id: Option<String>,
+}
+
+impl CreateReusableDelegationSetRequestBuilder {
+    /// Sets the [`caller_reference`](struct.CreateReusableDelegationSetRequest.html#structfield.caller_reference) member.
+    pub fn caller_reference<T: Into<String>>(mut self, value: T) -> Self {
+        self.caller_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hosted_zone_id`](struct.CreateReusableDelegationSetRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateReusableDelegationSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateReusableDelegationSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateReusableDelegationSetRequest {
+            caller_reference: self
+                .caller_reference
+                .ok_or(::rusoto_core::BuildError::missing("caller_reference"))?,
+            hosted_zone_id: self.hosted_zone_id,
+        })
+    }
+}
+
 pub struct CreateReusableDelegationSetRequestSerializer;
 impl CreateReusableDelegationSetRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1104,10 +2021,10 @@ pub struct CreateReusableDelegationSetResponse {
     pub location: String,
 }
 
-struct CreateReusableDelegationSetResponseDeserializer;
+pub(crate) struct CreateReusableDelegationSetResponseDeserializer;
 impl CreateReusableDelegationSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateReusableDelegationSetResponse, XmlParseError> {
@@ -1142,6 +2059,76 @@ pub struct CreateTrafficPolicyInstanceRequest {
     pub traffic_policy_version: i64,
 }
 
+impl CreateTrafficPolicyInstanceRequest {
+    /// Returns a builder for `CreateTrafficPolicyInstanceRequest`, checking its required members when built.
+    pub fn builder() -> CreateTrafficPolicyInstanceRequestBuilder {
+        CreateTrafficPolicyInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTrafficPolicyInstanceRequest`](struct.CreateTrafficPolicyInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTrafficPolicyInstanceRequestBuilder {
+    hosted_zone_id: Option<String>,
+    name: Option<String>,
+    ttl: Option<i64>,
+    traffic_policy_id: Option<String>,
+    traffic_policy_version: Option<i64>,
+}
+
+impl CreateTrafficPolicyInstanceRequestBuilder {
+    /// Sets the [`hosted_zone_id`](struct.CreateTrafficPolicyInstanceRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateTrafficPolicyInstanceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ttl`](struct.CreateTrafficPolicyInstanceRequest.html#structfield.ttl) member.
+    pub fn ttl<T: Into<i64>>(mut self, value: T) -> Self {
+        self.ttl = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_id`](struct.CreateTrafficPolicyInstanceRequest.html#structfield.traffic_policy_id) member.
+    pub fn traffic_policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.traffic_policy_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_version`](struct.CreateTrafficPolicyInstanceRequest.html#structfield.traffic_policy_version) member.
+    pub fn traffic_policy_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.traffic_policy_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTrafficPolicyInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateTrafficPolicyInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTrafficPolicyInstanceRequest {
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            ttl: self.ttl.ok_or(::rusoto_core::BuildError::missing("ttl"))?,
+            traffic_policy_id: self
+                .traffic_policy_id
+                .ok_or(::rusoto_core::BuildError::missing("traffic_policy_id"))?,
+            traffic_policy_version: self
+                .traffic_policy_version
+                .ok_or(::rusoto_core::BuildError::missing("traffic_policy_version"))?,
+        })
+    }
+}
+
 pub struct CreateTrafficPolicyInstanceRequestSerializer;
 impl CreateTrafficPolicyInstanceRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1172,7 +2159,7 @@ impl CreateTrafficPolicyInstanceRequestSerializer {
     }
 }
 /// <p>A complex type that contains the response information for the <code>CreateTrafficPolicyInstance</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateTrafficPolicyInstanceResponse {
     /// <p>A unique URL that represents a new traffic policy instance.</p>
     pub location: String,
@@ -1180,16 +2167,30 @@ pub struct CreateTrafficPolicyInstanceResponse {
     pub traffic_policy_instance: TrafficPolicyInstance,
 }
 
-struct CreateTrafficPolicyInstanceResponseDeserializer;
+pub(crate) struct CreateTrafficPolicyInstanceResponseDeserializer;
 impl CreateTrafficPolicyInstanceResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateTrafficPolicyInstanceResponse, XmlParseError> {
-        deserialize_elements::<_, CreateTrafficPolicyInstanceResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            CreateTrafficPolicyInstanceResponse {
+                location: Default::default(),
+                traffic_policy_instance: TrafficPolicyInstance {
+                    hosted_zone_id: Default::default(),
+                    id: Default::default(),
+                    message: Default::default(),
+                    name: Default::default(),
+                    state: Default::default(),
+                    ttl: Default::default(),
+                    traffic_policy_id: Default::default(),
+                    traffic_policy_type: RRType::Unknown(String::new()),
+                    traffic_policy_version: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "TrafficPolicyInstance" => {
@@ -1217,6 +2218,56 @@ pub struct CreateTrafficPolicyRequest {
     pub name: String,
 }
 
+impl CreateTrafficPolicyRequest {
+    /// Returns a builder for `CreateTrafficPolicyRequest`, checking its required members when built.
+    pub fn builder() -> CreateTrafficPolicyRequestBuilder {
+        CreateTrafficPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTrafficPolicyRequest`](struct.CreateTrafficPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTrafficPolicyRequestBuilder {
+    comment: Option<String>,
+    document: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateTrafficPolicyRequestBuilder {
+    /// Sets the [`comment`](struct.CreateTrafficPolicyRequest.html#structfield.comment) member.
+    pub fn comment<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document`](struct.CreateTrafficPolicyRequest.html#structfield.document) member.
+    pub fn document<T: Into<String>>(mut self, value: T) -> Self {
+        self.document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateTrafficPolicyRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTrafficPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateTrafficPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTrafficPolicyRequest {
+            comment: self.comment,
+            document: self
+                .document
+                .ok_or(::rusoto_core::BuildError::missing("document"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 pub struct CreateTrafficPolicyRequestSerializer;
 impl CreateTrafficPolicyRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1239,7 +2290,7 @@ impl CreateTrafficPolicyRequestSerializer {
     }
 }
 /// <p>A complex type that contains the response information for the <code>CreateTrafficPolicy</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateTrafficPolicyResponse {
     /// <p>A unique URL that represents a new traffic policy.</p>
     pub location: String,
@@ -1247,16 +2298,27 @@ pub struct CreateTrafficPolicyResponse {
     pub traffic_policy: TrafficPolicy,
 }
 
-struct CreateTrafficPolicyResponseDeserializer;
+pub(crate) struct CreateTrafficPolicyResponseDeserializer;
 impl CreateTrafficPolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateTrafficPolicyResponse, XmlParseError> {
-        deserialize_elements::<_, CreateTrafficPolicyResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            CreateTrafficPolicyResponse {
+                location: Default::default(),
+                traffic_policy: TrafficPolicy {
+                    comment: Default::default(),
+                    document: Default::default(),
+                    id: Default::default(),
+                    name: Default::default(),
+                    type_: RRType::Unknown(String::new()),
+                    version: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "TrafficPolicy" => {
@@ -1281,6 +2343,54 @@ pub struct CreateTrafficPolicyVersionRequest {
     pub id: String,
 }
 
+impl CreateTrafficPolicyVersionRequest {
+    /// Returns a builder for `CreateTrafficPolicyVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateTrafficPolicyVersionRequestBuilder {
+        CreateTrafficPolicyVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTrafficPolicyVersionRequest`](struct.CreateTrafficPolicyVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTrafficPolicyVersionRequestBuilder {
+    comment: Option<String>,
+    document: Option<String>,
+    id: Option<String>,
+}
+
+impl CreateTrafficPolicyVersionRequestBuilder {
+    /// Sets the [`comment`](struct.CreateTrafficPolicyVersionRequest.html#structfield.comment) member.
+    pub fn comment<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document`](struct.CreateTrafficPolicyVersionRequest.html#structfield.document) member.
+    pub fn document<T: Into<String>>(mut self, value: T) -> Self {
+        self.document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.CreateTrafficPolicyVersionRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTrafficPolicyVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateTrafficPolicyVersionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTrafficPolicyVersionRequest {
+            comment: self.comment,
+            document: self
+                .document
+                .ok_or(::rusoto_core::BuildError::missing("document"))?,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 pub struct CreateTrafficPolicyVersionRequestSerializer;
 impl CreateTrafficPolicyVersionRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1302,7 +2412,7 @@ impl CreateTrafficPolicyVersionRequestSerializer {
     }
 }
 /// <p>A complex type that contains the response information for the <code>CreateTrafficPolicyVersion</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateTrafficPolicyVersionResponse {
     /// <p>A unique URL that represents a new traffic policy version.</p>
     pub location: String,
@@ -1310,16 +2420,27 @@ pub struct CreateTrafficPolicyVersionResponse {
     pub traffic_policy: TrafficPolicy,
 }
 
-struct CreateTrafficPolicyVersionResponseDeserializer;
+pub(crate) struct CreateTrafficPolicyVersionResponseDeserializer;
 impl CreateTrafficPolicyVersionResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateTrafficPolicyVersionResponse, XmlParseError> {
-        deserialize_elements::<_, CreateTrafficPolicyVersionResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            CreateTrafficPolicyVersionResponse {
+                location: Default::default(),
+                traffic_policy: TrafficPolicy {
+                    comment: Default::default(),
+                    document: Default::default(),
+                    id: Default::default(),
+                    name: Default::default(),
+                    type_: RRType::Unknown(String::new()),
+                    version: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "TrafficPolicy" => {
@@ -1342,6 +2463,47 @@ pub struct CreateVPCAssociationAuthorizationRequest {
     pub vpc: VPC,
 }
 
+impl CreateVPCAssociationAuthorizationRequest {
+    /// Returns a builder for `CreateVPCAssociationAuthorizationRequest`, checking its required members when built.
+    pub fn builder() -> CreateVPCAssociationAuthorizationRequestBuilder {
+        CreateVPCAssociationAuthorizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateVPCAssociationAuthorizationRequest`](struct.CreateVPCAssociationAuthorizationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateVPCAssociationAuthorizationRequestBuilder {
+    hosted_zone_id: Option<String>,
+    vpc: Option<VPC>,
+}
+
+impl CreateVPCAssociationAuthorizationRequestBuilder {
+    /// Sets the [`hosted_zone_id`](struct.CreateVPCAssociationAuthorizationRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc`](struct.CreateVPCAssociationAuthorizationRequest.html#structfield.vpc) member.
+    pub fn vpc<T: Into<VPC>>(mut self, value: T) -> Self {
+        self.vpc = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateVPCAssociationAuthorizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateVPCAssociationAuthorizationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateVPCAssociationAuthorizationRequest {
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+            vpc: self.vpc.ok_or(::rusoto_core::BuildError::missing("vpc"))?,
+        })
+    }
+}
+
 pub struct CreateVPCAssociationAuthorizationRequestSerializer;
 impl CreateVPCAssociationAuthorizationRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1368,10 +2530,10 @@ pub struct CreateVPCAssociationAuthorizationResponse {
     pub vpc: VPC,
 }
 
-struct CreateVPCAssociationAuthorizationResponseDeserializer;
+pub(crate) struct CreateVPCAssociationAuthorizationResponseDeserializer;
 impl CreateVPCAssociationAuthorizationResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateVPCAssociationAuthorizationResponse, XmlParseError> {
@@ -1394,10 +2556,13 @@ impl CreateVPCAssociationAuthorizationResponseDeserializer {
         )
     }
 }
-struct DNSNameDeserializer;
+pub(crate) struct DNSNameDeserializer;
 impl DNSNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1426,10 +2591,13 @@ impl DNSNameSerializer {
     }
 }
 
-struct DNSRCodeDeserializer;
+pub(crate) struct DNSRCodeDeserializer;
 impl DNSRCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1448,10 +2616,10 @@ pub struct DelegationSet {
     pub name_servers: Vec<String>,
 }
 
-struct DelegationSetDeserializer;
+pub(crate) struct DelegationSetDeserializer;
 impl DelegationSetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DelegationSet, XmlParseError> {
@@ -1477,10 +2645,10 @@ impl DelegationSetDeserializer {
         })
     }
 }
-struct DelegationSetNameServersDeserializer;
+pub(crate) struct DelegationSetNameServersDeserializer;
 impl DelegationSetNameServersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -1494,10 +2662,10 @@ impl DelegationSetNameServersDeserializer {
         })
     }
 }
-struct DelegationSetsDeserializer;
+pub(crate) struct DelegationSetsDeserializer;
 impl DelegationSetsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DelegationSet>, XmlParseError> {
@@ -1521,14 +2689,46 @@ pub struct DeleteHealthCheckRequest {
     pub health_check_id: String,
 }
 
+impl DeleteHealthCheckRequest {
+    /// Returns a builder for `DeleteHealthCheckRequest`, checking its required members when built.
+    pub fn builder() -> DeleteHealthCheckRequestBuilder {
+        DeleteHealthCheckRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteHealthCheckRequest`](struct.DeleteHealthCheckRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteHealthCheckRequestBuilder {
+    health_check_id: Option<String>,
+}
+
+impl DeleteHealthCheckRequestBuilder {
+    /// Sets the [`health_check_id`](struct.DeleteHealthCheckRequest.html#structfield.health_check_id) member.
+    pub fn health_check_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteHealthCheckRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteHealthCheckRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteHealthCheckRequest {
+            health_check_id: self
+                .health_check_id
+                .ok_or(::rusoto_core::BuildError::missing("health_check_id"))?,
+        })
+    }
+}
+
 /// <p>An empty element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteHealthCheckResponse {}
 
-struct DeleteHealthCheckResponseDeserializer;
+pub(crate) struct DeleteHealthCheckResponseDeserializer;
 impl DeleteHealthCheckResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteHealthCheckResponse, XmlParseError> {
@@ -1548,23 +2748,61 @@ pub struct DeleteHostedZoneRequest {
     pub id: String,
 }
 
+impl DeleteHostedZoneRequest {
+    /// Returns a builder for `DeleteHostedZoneRequest`, checking its required members when built.
+    pub fn builder() -> DeleteHostedZoneRequestBuilder {
+        DeleteHostedZoneRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteHostedZoneRequest`](struct.DeleteHostedZoneRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteHostedZoneRequestBuilder {
+    id: Option<String>,
+}
+
+impl DeleteHostedZoneRequestBuilder {
+    /// Sets the [`id`](struct.DeleteHostedZoneRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteHostedZoneRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteHostedZoneRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteHostedZoneRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>DeleteHostedZone</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DeleteHostedZoneResponse {
     /// <p>A complex type that contains the ID, the status, and the date and time of a request to delete a hosted zone.</p>
     pub change_info: ChangeInfo,
 }
 
-struct DeleteHostedZoneResponseDeserializer;
+pub(crate) struct DeleteHostedZoneResponseDeserializer;
 impl DeleteHostedZoneResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteHostedZoneResponse, XmlParseError> {
-        deserialize_elements::<_, DeleteHostedZoneResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DeleteHostedZoneResponse {
+                change_info: ChangeInfo {
+                    comment: Default::default(),
+                    id: Default::default(),
+                    status: ChangeStatus::Unknown(String::new()),
+                    submitted_at: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "ChangeInfo" => {
@@ -1583,13 +2821,43 @@ pub struct DeleteQueryLoggingConfigRequest {
     pub id: String,
 }
 
+impl DeleteQueryLoggingConfigRequest {
+    /// Returns a builder for `DeleteQueryLoggingConfigRequest`, checking its required members when built.
+    pub fn builder() -> DeleteQueryLoggingConfigRequestBuilder {
+        DeleteQueryLoggingConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteQueryLoggingConfigRequest`](struct.DeleteQueryLoggingConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteQueryLoggingConfigRequestBuilder {
+    id: Option<String>,
+}
+
+impl DeleteQueryLoggingConfigRequestBuilder {
+    /// Sets the [`id`](struct.DeleteQueryLoggingConfigRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteQueryLoggingConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteQueryLoggingConfigRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteQueryLoggingConfigRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteQueryLoggingConfigResponse {}
 
-struct DeleteQueryLoggingConfigResponseDeserializer;
+pub(crate) struct DeleteQueryLoggingConfigResponseDeserializer;
 impl DeleteQueryLoggingConfigResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteQueryLoggingConfigResponse, XmlParseError> {
@@ -1609,14 +2877,44 @@ pub struct DeleteReusableDelegationSetRequest {
     pub id: String,
 }
 
+impl DeleteReusableDelegationSetRequest {
+    /// Returns a builder for `DeleteReusableDelegationSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteReusableDelegationSetRequestBuilder {
+        DeleteReusableDelegationSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteReusableDelegationSetRequest`](struct.DeleteReusableDelegationSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteReusableDelegationSetRequestBuilder {
+    id: Option<String>,
+}
+
+impl DeleteReusableDelegationSetRequestBuilder {
+    /// Sets the [`id`](struct.DeleteReusableDelegationSetRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteReusableDelegationSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteReusableDelegationSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteReusableDelegationSetRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>An empty element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteReusableDelegationSetResponse {}
 
-struct DeleteReusableDelegationSetResponseDeserializer;
+pub(crate) struct DeleteReusableDelegationSetResponseDeserializer;
 impl DeleteReusableDelegationSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteReusableDelegationSetResponse, XmlParseError> {
@@ -1636,14 +2934,44 @@ pub struct DeleteTrafficPolicyInstanceRequest {
     pub id: String,
 }
 
+impl DeleteTrafficPolicyInstanceRequest {
+    /// Returns a builder for `DeleteTrafficPolicyInstanceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTrafficPolicyInstanceRequestBuilder {
+        DeleteTrafficPolicyInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTrafficPolicyInstanceRequest`](struct.DeleteTrafficPolicyInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTrafficPolicyInstanceRequestBuilder {
+    id: Option<String>,
+}
+
+impl DeleteTrafficPolicyInstanceRequestBuilder {
+    /// Sets the [`id`](struct.DeleteTrafficPolicyInstanceRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTrafficPolicyInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteTrafficPolicyInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTrafficPolicyInstanceRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>An empty element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteTrafficPolicyInstanceResponse {}
 
-struct DeleteTrafficPolicyInstanceResponseDeserializer;
+pub(crate) struct DeleteTrafficPolicyInstanceResponseDeserializer;
 impl DeleteTrafficPolicyInstanceResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteTrafficPolicyInstanceResponse, XmlParseError> {
@@ -1665,14 +2993,54 @@ pub struct DeleteTrafficPolicyRequest {
     pub version: i64,
 }
 
+impl DeleteTrafficPolicyRequest {
+    /// Returns a builder for `DeleteTrafficPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTrafficPolicyRequestBuilder {
+        DeleteTrafficPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTrafficPolicyRequest`](struct.DeleteTrafficPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTrafficPolicyRequestBuilder {
+    id: Option<String>,
+    version: Option<i64>,
+}
+
+impl DeleteTrafficPolicyRequestBuilder {
+    /// Sets the [`id`](struct.DeleteTrafficPolicyRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.DeleteTrafficPolicyRequest.html#structfield.version) member.
+    pub fn version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTrafficPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteTrafficPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTrafficPolicyRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 /// <p>An empty element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteTrafficPolicyResponse {}
 
-struct DeleteTrafficPolicyResponseDeserializer;
+pub(crate) struct DeleteTrafficPolicyResponseDeserializer;
 impl DeleteTrafficPolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteTrafficPolicyResponse, XmlParseError> {
@@ -1694,6 +3062,47 @@ pub struct DeleteVPCAssociationAuthorizationRequest {
     pub vpc: VPC,
 }
 
+impl DeleteVPCAssociationAuthorizationRequest {
+    /// Returns a builder for `DeleteVPCAssociationAuthorizationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteVPCAssociationAuthorizationRequestBuilder {
+        DeleteVPCAssociationAuthorizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVPCAssociationAuthorizationRequest`](struct.DeleteVPCAssociationAuthorizationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteVPCAssociationAuthorizationRequestBuilder {
+    hosted_zone_id: Option<String>,
+    vpc: Option<VPC>,
+}
+
+impl DeleteVPCAssociationAuthorizationRequestBuilder {
+    /// Sets the [`hosted_zone_id`](struct.DeleteVPCAssociationAuthorizationRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc`](struct.DeleteVPCAssociationAuthorizationRequest.html#structfield.vpc) member.
+    pub fn vpc<T: Into<VPC>>(mut self, value: T) -> Self {
+        self.vpc = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVPCAssociationAuthorizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVPCAssociationAuthorizationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteVPCAssociationAuthorizationRequest {
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+            vpc: self.vpc.ok_or(::rusoto_core::BuildError::missing("vpc"))?,
+        })
+    }
+}
+
 pub struct DeleteVPCAssociationAuthorizationRequestSerializer;
 impl DeleteVPCAssociationAuthorizationRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1715,10 +3124,10 @@ impl DeleteVPCAssociationAuthorizationRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteVPCAssociationAuthorizationResponse {}
 
-struct DeleteVPCAssociationAuthorizationResponseDeserializer;
+pub(crate) struct DeleteVPCAssociationAuthorizationResponseDeserializer;
 impl DeleteVPCAssociationAuthorizationResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteVPCAssociationAuthorizationResponse, XmlParseError> {
@@ -1740,10 +3149,10 @@ pub struct Dimension {
     pub value: String,
 }
 
-struct DimensionDeserializer;
+pub(crate) struct DimensionDeserializer;
 impl DimensionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Dimension, XmlParseError> {
@@ -1761,10 +3170,13 @@ impl DimensionDeserializer {
         })
     }
 }
-struct DimensionFieldDeserializer;
+pub(crate) struct DimensionFieldDeserializer;
 impl DimensionFieldDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1772,10 +3184,10 @@ impl DimensionFieldDeserializer {
         Ok(obj)
     }
 }
-struct DimensionListDeserializer;
+pub(crate) struct DimensionListDeserializer;
 impl DimensionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Dimension>, XmlParseError> {
@@ -1789,10 +3201,13 @@ impl DimensionListDeserializer {
         })
     }
 }
-struct DisabledDeserializer;
+pub(crate) struct DisabledDeserializer;
 impl DisabledDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1852,9 +3267,58 @@ pub struct DisassociateVPCFromHostedZoneRequest {
     pub vpc: VPC,
 }
 
-pub struct DisassociateVPCFromHostedZoneRequestSerializer;
-impl DisassociateVPCFromHostedZoneRequestSerializer {
-    #[allow(unused_variables, warnings)]
+impl DisassociateVPCFromHostedZoneRequest {
+    /// Returns a builder for `DisassociateVPCFromHostedZoneRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateVPCFromHostedZoneRequestBuilder {
+        DisassociateVPCFromHostedZoneRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateVPCFromHostedZoneRequest`](struct.DisassociateVPCFromHostedZoneRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateVPCFromHostedZoneRequestBuilder {
+    comment: Option<String>,
+    hosted_zone_id: Option<String>,
+    vpc: Option<VPC>,
+}
+
+impl DisassociateVPCFromHostedZoneRequestBuilder {
+    /// Sets the [`comment`](struct.DisassociateVPCFromHostedZoneRequest.html#structfield.comment) member.
+    pub fn comment<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hosted_zone_id`](struct.DisassociateVPCFromHostedZoneRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc`](struct.DisassociateVPCFromHostedZoneRequest.html#structfield.vpc) member.
+    pub fn vpc<T: Into<VPC>>(mut self, value: T) -> Self {
+        self.vpc = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateVPCFromHostedZoneRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateVPCFromHostedZoneRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateVPCFromHostedZoneRequest {
+            comment: self.comment,
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+            vpc: self.vpc.ok_or(::rusoto_core::BuildError::missing("vpc"))?,
+        })
+    }
+}
+
+pub struct DisassociateVPCFromHostedZoneRequestSerializer;
+impl DisassociateVPCFromHostedZoneRequestSerializer {
+    #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
@@ -1873,22 +3337,30 @@ impl DisassociateVPCFromHostedZoneRequestSerializer {
     }
 }
 /// <p>A complex type that contains the response information for the disassociate request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DisassociateVPCFromHostedZoneResponse {
     /// <p>A complex type that describes the changes made to the specified private hosted zone.</p>
     pub change_info: ChangeInfo,
 }
 
-struct DisassociateVPCFromHostedZoneResponseDeserializer;
+pub(crate) struct DisassociateVPCFromHostedZoneResponseDeserializer;
 impl DisassociateVPCFromHostedZoneResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DisassociateVPCFromHostedZoneResponse, XmlParseError> {
-        deserialize_elements::<_, DisassociateVPCFromHostedZoneResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DisassociateVPCFromHostedZoneResponse {
+                change_info: ChangeInfo {
+                    comment: Default::default(),
+                    id: Default::default(),
+                    status: ChangeStatus::Unknown(String::new()),
+                    submitted_at: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "ChangeInfo" => {
@@ -1901,10 +3373,13 @@ impl DisassociateVPCFromHostedZoneResponseDeserializer {
         )
     }
 }
-struct EnableSNIDeserializer;
+pub(crate) struct EnableSNIDeserializer;
 impl EnableSNIDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1933,10 +3408,13 @@ impl EnableSNISerializer {
     }
 }
 
-struct EvaluationPeriodsDeserializer;
+pub(crate) struct EvaluationPeriodsDeserializer;
 impl EvaluationPeriodsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1944,10 +3422,13 @@ impl EvaluationPeriodsDeserializer {
         Ok(obj)
     }
 }
-struct FailureThresholdDeserializer;
+pub(crate) struct FailureThresholdDeserializer;
 impl FailureThresholdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1976,10 +3457,13 @@ impl FailureThresholdSerializer {
     }
 }
 
-struct FullyQualifiedDomainNameDeserializer;
+pub(crate) struct FullyQualifiedDomainNameDeserializer;
 impl FullyQualifiedDomainNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2019,10 +3503,10 @@ pub struct GeoLocation {
     pub subdivision_code: Option<String>,
 }
 
-struct GeoLocationDeserializer;
+pub(crate) struct GeoLocationDeserializer;
 impl GeoLocationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GeoLocation, XmlParseError> {
@@ -2094,10 +3578,13 @@ impl GeoLocationSerializer {
     }
 }
 
-struct GeoLocationContinentCodeDeserializer;
+pub(crate) struct GeoLocationContinentCodeDeserializer;
 impl GeoLocationContinentCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2126,10 +3613,13 @@ impl GeoLocationContinentCodeSerializer {
     }
 }
 
-struct GeoLocationContinentNameDeserializer;
+pub(crate) struct GeoLocationContinentNameDeserializer;
 impl GeoLocationContinentNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2137,10 +3627,13 @@ impl GeoLocationContinentNameDeserializer {
         Ok(obj)
     }
 }
-struct GeoLocationCountryCodeDeserializer;
+pub(crate) struct GeoLocationCountryCodeDeserializer;
 impl GeoLocationCountryCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2169,10 +3662,13 @@ impl GeoLocationCountryCodeSerializer {
     }
 }
 
-struct GeoLocationCountryNameDeserializer;
+pub(crate) struct GeoLocationCountryNameDeserializer;
 impl GeoLocationCountryNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2197,10 +3693,10 @@ pub struct GeoLocationDetails {
     pub subdivision_name: Option<String>,
 }
 
-struct GeoLocationDetailsDeserializer;
+pub(crate) struct GeoLocationDetailsDeserializer;
 impl GeoLocationDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GeoLocationDetails, XmlParseError> {
@@ -2250,10 +3746,10 @@ impl GeoLocationDetailsDeserializer {
         })
     }
 }
-struct GeoLocationDetailsListDeserializer;
+pub(crate) struct GeoLocationDetailsListDeserializer;
 impl GeoLocationDetailsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<GeoLocationDetails>, XmlParseError> {
@@ -2270,10 +3766,13 @@ impl GeoLocationDetailsListDeserializer {
         })
     }
 }
-struct GeoLocationSubdivisionCodeDeserializer;
+pub(crate) struct GeoLocationSubdivisionCodeDeserializer;
 impl GeoLocationSubdivisionCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2302,10 +3801,13 @@ impl GeoLocationSubdivisionCodeSerializer {
     }
 }
 
-struct GeoLocationSubdivisionNameDeserializer;
+pub(crate) struct GeoLocationSubdivisionNameDeserializer;
 impl GeoLocationSubdivisionNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2314,14 +3816,44 @@ impl GeoLocationSubdivisionNameDeserializer {
     }
 }
 /// <p>A complex type that contains information about the request to create a hosted zone.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetAccountLimitRequest {
     /// <p><p>The limit that you want to get. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>HEALTH</em>CHECKS<em>BY</em>OWNER</b>: The maximum number of health checks that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>HOSTED</em>ZONES<em>BY</em>OWNER</b>: The maximum number of hosted zones that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>REUSABLE</em>DELEGATION<em>SETS</em>BY<em>OWNER</b>: The maximum number of reusable delegation sets that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICIES</em>BY<em>OWNER</b>: The maximum number of traffic policies that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICY</em>INSTANCES<em>BY</em>OWNER</b>: The maximum number of traffic policy instances that you can create using the current account. (Traffic policy instances are referred to as traffic flow policy records in the Amazon Route 53 console.)</p> </li> </ul></p>
-    pub type_: String,
+    pub type_: AccountLimitType,
+}
+
+impl GetAccountLimitRequest {
+    /// Returns a builder for `GetAccountLimitRequest`, checking its required members when built.
+    pub fn builder() -> GetAccountLimitRequestBuilder {
+        GetAccountLimitRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAccountLimitRequest`](struct.GetAccountLimitRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAccountLimitRequestBuilder {
+    type_: Option<AccountLimitType>,
+}
+
+impl GetAccountLimitRequestBuilder {
+    /// Sets the [`type_`](struct.GetAccountLimitRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<AccountLimitType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAccountLimitRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAccountLimitRequest, ::rusoto_core::BuildError> {
+        Ok(GetAccountLimitRequest {
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 /// <p>A complex type that contains the requested limit. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetAccountLimitResponse {
     /// <p>The current number of entities that you have created of the specified type. For example, if you specified <code>MAX_HEALTH_CHECKS_BY_OWNER</code> for the value of <code>Type</code> in the request, the value of <code>Count</code> is the current number of health checks that you have created using the current account.</p>
     pub count: i64,
@@ -2329,16 +3861,23 @@ pub struct GetAccountLimitResponse {
     pub limit: AccountLimit,
 }
 
-struct GetAccountLimitResponseDeserializer;
+pub(crate) struct GetAccountLimitResponseDeserializer;
 impl GetAccountLimitResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetAccountLimitResponse, XmlParseError> {
-        deserialize_elements::<_, GetAccountLimitResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            GetAccountLimitResponse {
+                count: Default::default(),
+                limit: AccountLimit {
+                    type_: AccountLimitType::Unknown(String::new()),
+                    value: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "Count" => {
@@ -2361,35 +3900,95 @@ pub struct GetChangeRequest {
     pub id: String,
 }
 
+impl GetChangeRequest {
+    /// Returns a builder for `GetChangeRequest`, checking its required members when built.
+    pub fn builder() -> GetChangeRequestBuilder {
+        GetChangeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetChangeRequest`](struct.GetChangeRequest.html).
+#[derive(Default, Debug)]
+pub struct GetChangeRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetChangeRequestBuilder {
+    /// Sets the [`id`](struct.GetChangeRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetChangeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetChangeRequest, ::rusoto_core::BuildError> {
+        Ok(GetChangeRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>A complex type that contains the <code>ChangeInfo</code> element.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetChangeResponse {
     /// <p>A complex type that contains information about the specified change batch.</p>
     pub change_info: ChangeInfo,
 }
 
-struct GetChangeResponseDeserializer;
+pub(crate) struct GetChangeResponseDeserializer;
 impl GetChangeResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetChangeResponse, XmlParseError> {
-        deserialize_elements::<_, GetChangeResponse, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "ChangeInfo" => {
-                    obj.change_info = ChangeInfoDeserializer::deserialize("ChangeInfo", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            GetChangeResponse {
+                change_info: ChangeInfo {
+                    comment: Default::default(),
+                    id: Default::default(),
+                    status: ChangeStatus::Unknown(String::new()),
+                    submitted_at: Default::default(),
+                },
+            },
+            |name, stack, obj| {
+                match name {
+                    "ChangeInfo" => {
+                        obj.change_info = ChangeInfoDeserializer::deserialize("ChangeInfo", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 /// <p>Empty request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetCheckerIpRangesRequest {}
 
+impl GetCheckerIpRangesRequest {
+    /// Returns a builder for `GetCheckerIpRangesRequest`, checking its required members when built.
+    pub fn builder() -> GetCheckerIpRangesRequestBuilder {
+        GetCheckerIpRangesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCheckerIpRangesRequest`](struct.GetCheckerIpRangesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCheckerIpRangesRequestBuilder {}
+
+impl GetCheckerIpRangesRequestBuilder {
+    /// Builds the `GetCheckerIpRangesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCheckerIpRangesRequest, ::rusoto_core::BuildError> {
+        Ok(GetCheckerIpRangesRequest {})
+    }
+}
+
 /// <p>A complex type that contains the <code>CheckerIpRanges</code> element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetCheckerIpRangesResponse {
@@ -2397,10 +3996,10 @@ pub struct GetCheckerIpRangesResponse {
     pub checker_ip_ranges: Vec<String>,
 }
 
-struct GetCheckerIpRangesResponseDeserializer;
+pub(crate) struct GetCheckerIpRangesResponseDeserializer;
 impl GetCheckerIpRangesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetCheckerIpRangesResponse, XmlParseError> {
@@ -2434,6 +4033,50 @@ pub struct GetGeoLocationRequest {
     pub subdivision_code: Option<String>,
 }
 
+impl GetGeoLocationRequest {
+    /// Returns a builder for `GetGeoLocationRequest`, checking its required members when built.
+    pub fn builder() -> GetGeoLocationRequestBuilder {
+        GetGeoLocationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGeoLocationRequest`](struct.GetGeoLocationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGeoLocationRequestBuilder {
+    continent_code: Option<String>,
+    country_code: Option<String>,
+    subdivision_code: Option<String>,
+}
+
+impl GetGeoLocationRequestBuilder {
+    /// Sets the [`continent_code`](struct.GetGeoLocationRequest.html#structfield.continent_code) member.
+    pub fn continent_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.continent_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`country_code`](struct.GetGeoLocationRequest.html#structfield.country_code) member.
+    pub fn country_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.country_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subdivision_code`](struct.GetGeoLocationRequest.html#structfield.subdivision_code) member.
+    pub fn subdivision_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.subdivision_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGeoLocationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGeoLocationRequest, ::rusoto_core::BuildError> {
+        Ok(GetGeoLocationRequest {
+            continent_code: self.continent_code,
+            country_code: self.country_code,
+            subdivision_code: self.subdivision_code,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response information for the specified geolocation code.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetGeoLocationResponse {
@@ -2441,10 +4084,10 @@ pub struct GetGeoLocationResponse {
     pub geo_location_details: GeoLocationDetails,
 }
 
-struct GetGeoLocationResponseDeserializer;
+pub(crate) struct GetGeoLocationResponseDeserializer;
 impl GetGeoLocationResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetGeoLocationResponse, XmlParseError> {
@@ -2464,6 +4107,26 @@ impl GetGeoLocationResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetHealthCheckCountRequest {}
 
+impl GetHealthCheckCountRequest {
+    /// Returns a builder for `GetHealthCheckCountRequest`, checking its required members when built.
+    pub fn builder() -> GetHealthCheckCountRequestBuilder {
+        GetHealthCheckCountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetHealthCheckCountRequest`](struct.GetHealthCheckCountRequest.html).
+#[derive(Default, Debug)]
+pub struct GetHealthCheckCountRequestBuilder {}
+
+impl GetHealthCheckCountRequestBuilder {
+    /// Builds the `GetHealthCheckCountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetHealthCheckCountRequest, ::rusoto_core::BuildError> {
+        Ok(GetHealthCheckCountRequest {})
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>GetHealthCheckCount</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetHealthCheckCountResponse {
@@ -2471,10 +4134,10 @@ pub struct GetHealthCheckCountResponse {
     pub health_check_count: i64,
 }
 
-struct GetHealthCheckCountResponseDeserializer;
+pub(crate) struct GetHealthCheckCountResponseDeserializer;
 impl GetHealthCheckCountResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetHealthCheckCountResponse, XmlParseError> {
@@ -2501,6 +4164,39 @@ pub struct GetHealthCheckLastFailureReasonRequest {
     pub health_check_id: String,
 }
 
+impl GetHealthCheckLastFailureReasonRequest {
+    /// Returns a builder for `GetHealthCheckLastFailureReasonRequest`, checking its required members when built.
+    pub fn builder() -> GetHealthCheckLastFailureReasonRequestBuilder {
+        GetHealthCheckLastFailureReasonRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetHealthCheckLastFailureReasonRequest`](struct.GetHealthCheckLastFailureReasonRequest.html).
+#[derive(Default, Debug)]
+pub struct GetHealthCheckLastFailureReasonRequestBuilder {
+    health_check_id: Option<String>,
+}
+
+impl GetHealthCheckLastFailureReasonRequestBuilder {
+    /// Sets the [`health_check_id`](struct.GetHealthCheckLastFailureReasonRequest.html#structfield.health_check_id) member.
+    pub fn health_check_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetHealthCheckLastFailureReasonRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetHealthCheckLastFailureReasonRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetHealthCheckLastFailureReasonRequest {
+            health_check_id: self
+                .health_check_id
+                .ok_or(::rusoto_core::BuildError::missing("health_check_id"))?,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>GetHealthCheckLastFailureReason</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetHealthCheckLastFailureReasonResponse {
@@ -2508,10 +4204,10 @@ pub struct GetHealthCheckLastFailureReasonResponse {
     pub health_check_observations: Vec<HealthCheckObservation>,
 }
 
-struct GetHealthCheckLastFailureReasonResponseDeserializer;
+pub(crate) struct GetHealthCheckLastFailureReasonResponseDeserializer;
 impl GetHealthCheckLastFailureReasonResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetHealthCheckLastFailureReasonResponse, XmlParseError> {
@@ -2542,29 +4238,92 @@ pub struct GetHealthCheckRequest {
     pub health_check_id: String,
 }
 
+impl GetHealthCheckRequest {
+    /// Returns a builder for `GetHealthCheckRequest`, checking its required members when built.
+    pub fn builder() -> GetHealthCheckRequestBuilder {
+        GetHealthCheckRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetHealthCheckRequest`](struct.GetHealthCheckRequest.html).
+#[derive(Default, Debug)]
+pub struct GetHealthCheckRequestBuilder {
+    health_check_id: Option<String>,
+}
+
+impl GetHealthCheckRequestBuilder {
+    /// Sets the [`health_check_id`](struct.GetHealthCheckRequest.html#structfield.health_check_id) member.
+    pub fn health_check_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetHealthCheckRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetHealthCheckRequest, ::rusoto_core::BuildError> {
+        Ok(GetHealthCheckRequest {
+            health_check_id: self
+                .health_check_id
+                .ok_or(::rusoto_core::BuildError::missing("health_check_id"))?,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>GetHealthCheck</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetHealthCheckResponse {
     /// <p>A complex type that contains information about one health check that is associated with the current AWS account.</p>
     pub health_check: HealthCheck,
 }
 
-struct GetHealthCheckResponseDeserializer;
+pub(crate) struct GetHealthCheckResponseDeserializer;
 impl GetHealthCheckResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetHealthCheckResponse, XmlParseError> {
-        deserialize_elements::<_, GetHealthCheckResponse, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "HealthCheck" => {
-                    obj.health_check = HealthCheckDeserializer::deserialize("HealthCheck", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            GetHealthCheckResponse {
+                health_check: HealthCheck {
+                    caller_reference: Default::default(),
+                    cloud_watch_alarm_configuration: Default::default(),
+                    health_check_config: HealthCheckConfig {
+                        alarm_identifier: Default::default(),
+                        child_health_checks: Default::default(),
+                        disabled: Default::default(),
+                        enable_sni: Default::default(),
+                        failure_threshold: Default::default(),
+                        fully_qualified_domain_name: Default::default(),
+                        health_threshold: Default::default(),
+                        ip_address: Default::default(),
+                        insufficient_data_health_status: Default::default(),
+                        inverted: Default::default(),
+                        measure_latency: Default::default(),
+                        port: Default::default(),
+                        regions: Default::default(),
+                        request_interval: Default::default(),
+                        resource_path: Default::default(),
+                        search_string: Default::default(),
+                        type_: HealthCheckType::Unknown(String::new()),
+                    },
+                    health_check_version: Default::default(),
+                    id: Default::default(),
+                    linked_service: Default::default(),
+                },
+            },
+            |name, stack, obj| {
+                match name {
+                    "HealthCheck" => {
+                        obj.health_check =
+                            HealthCheckDeserializer::deserialize("HealthCheck", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 /// <p>A request to get the status for a health check.</p>
@@ -2574,6 +4333,38 @@ pub struct GetHealthCheckStatusRequest {
     pub health_check_id: String,
 }
 
+impl GetHealthCheckStatusRequest {
+    /// Returns a builder for `GetHealthCheckStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetHealthCheckStatusRequestBuilder {
+        GetHealthCheckStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetHealthCheckStatusRequest`](struct.GetHealthCheckStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetHealthCheckStatusRequestBuilder {
+    health_check_id: Option<String>,
+}
+
+impl GetHealthCheckStatusRequestBuilder {
+    /// Sets the [`health_check_id`](struct.GetHealthCheckStatusRequest.html#structfield.health_check_id) member.
+    pub fn health_check_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetHealthCheckStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetHealthCheckStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetHealthCheckStatusRequest {
+            health_check_id: self
+                .health_check_id
+                .ok_or(::rusoto_core::BuildError::missing("health_check_id"))?,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>GetHealthCheck</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetHealthCheckStatusResponse {
@@ -2581,10 +4372,10 @@ pub struct GetHealthCheckStatusResponse {
     pub health_check_observations: Vec<HealthCheckObservation>,
 }
 
-struct GetHealthCheckStatusResponseDeserializer;
+pub(crate) struct GetHealthCheckStatusResponseDeserializer;
 impl GetHealthCheckStatusResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetHealthCheckStatusResponse, XmlParseError> {
@@ -2612,6 +4403,26 @@ impl GetHealthCheckStatusResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetHostedZoneCountRequest {}
 
+impl GetHostedZoneCountRequest {
+    /// Returns a builder for `GetHostedZoneCountRequest`, checking its required members when built.
+    pub fn builder() -> GetHostedZoneCountRequestBuilder {
+        GetHostedZoneCountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetHostedZoneCountRequest`](struct.GetHostedZoneCountRequest.html).
+#[derive(Default, Debug)]
+pub struct GetHostedZoneCountRequestBuilder {}
+
+impl GetHostedZoneCountRequestBuilder {
+    /// Builds the `GetHostedZoneCountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetHostedZoneCountRequest, ::rusoto_core::BuildError> {
+        Ok(GetHostedZoneCountRequest {})
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>GetHostedZoneCount</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetHostedZoneCountResponse {
@@ -2619,10 +4430,10 @@ pub struct GetHostedZoneCountResponse {
     pub hosted_zone_count: i64,
 }
 
-struct GetHostedZoneCountResponseDeserializer;
+pub(crate) struct GetHostedZoneCountResponseDeserializer;
 impl GetHostedZoneCountResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetHostedZoneCountResponse, XmlParseError> {
@@ -2643,16 +4454,58 @@ impl GetHostedZoneCountResponseDeserializer {
     }
 }
 /// <p>A complex type that contains information about the request to create a hosted zone.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetHostedZoneLimitRequest {
     /// <p>The ID of the hosted zone that you want to get a limit for.</p>
     pub hosted_zone_id: String,
     /// <p><p>The limit that you want to get. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>RRSETS</em>BY<em>ZONE</b>: The maximum number of records that you can create in the specified hosted zone.</p> </li> <li> <p> <b>MAX</em>VPCS<em>ASSOCIATED</em>BY_ZONE</b>: The maximum number of Amazon VPCs that you can associate with the specified private hosted zone.</p> </li> </ul></p>
-    pub type_: String,
+    pub type_: HostedZoneLimitType,
+}
+
+impl GetHostedZoneLimitRequest {
+    /// Returns a builder for `GetHostedZoneLimitRequest`, checking its required members when built.
+    pub fn builder() -> GetHostedZoneLimitRequestBuilder {
+        GetHostedZoneLimitRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetHostedZoneLimitRequest`](struct.GetHostedZoneLimitRequest.html).
+#[derive(Default, Debug)]
+pub struct GetHostedZoneLimitRequestBuilder {
+    hosted_zone_id: Option<String>,
+    type_: Option<HostedZoneLimitType>,
+}
+
+impl GetHostedZoneLimitRequestBuilder {
+    /// Sets the [`hosted_zone_id`](struct.GetHostedZoneLimitRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.GetHostedZoneLimitRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<HostedZoneLimitType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetHostedZoneLimitRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetHostedZoneLimitRequest, ::rusoto_core::BuildError> {
+        Ok(GetHostedZoneLimitRequest {
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 /// <p>A complex type that contains the requested limit. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetHostedZoneLimitResponse {
     /// <p>The current number of entities that you have created of the specified type. For example, if you specified <code>MAX_RRSETS_BY_ZONE</code> for the value of <code>Type</code> in the request, the value of <code>Count</code> is the current number of records that you have created in the specified hosted zone.</p>
     pub count: i64,
@@ -2660,16 +4513,23 @@ pub struct GetHostedZoneLimitResponse {
     pub limit: HostedZoneLimit,
 }
 
-struct GetHostedZoneLimitResponseDeserializer;
+pub(crate) struct GetHostedZoneLimitResponseDeserializer;
 impl GetHostedZoneLimitResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetHostedZoneLimitResponse, XmlParseError> {
-        deserialize_elements::<_, GetHostedZoneLimitResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            GetHostedZoneLimitResponse {
+                count: Default::default(),
+                limit: HostedZoneLimit {
+                    type_: HostedZoneLimitType::Unknown(String::new()),
+                    value: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "Count" => {
@@ -2692,6 +4552,34 @@ pub struct GetHostedZoneRequest {
     pub id: String,
 }
 
+impl GetHostedZoneRequest {
+    /// Returns a builder for `GetHostedZoneRequest`, checking its required members when built.
+    pub fn builder() -> GetHostedZoneRequestBuilder {
+        GetHostedZoneRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetHostedZoneRequest`](struct.GetHostedZoneRequest.html).
+#[derive(Default, Debug)]
+pub struct GetHostedZoneRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetHostedZoneRequestBuilder {
+    /// Sets the [`id`](struct.GetHostedZoneRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetHostedZoneRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetHostedZoneRequest, ::rusoto_core::BuildError> {
+        Ok(GetHostedZoneRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>A complex type that contain the response to a <code>GetHostedZone</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetHostedZoneResponse {
@@ -2703,10 +4591,10 @@ pub struct GetHostedZoneResponse {
     pub vp_cs: Option<Vec<VPC>>,
 }
 
-struct GetHostedZoneResponseDeserializer;
+pub(crate) struct GetHostedZoneResponseDeserializer;
 impl GetHostedZoneResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetHostedZoneResponse, XmlParseError> {
@@ -2738,16 +4626,46 @@ pub struct GetQueryLoggingConfigRequest {
     pub id: String,
 }
 
+impl GetQueryLoggingConfigRequest {
+    /// Returns a builder for `GetQueryLoggingConfigRequest`, checking its required members when built.
+    pub fn builder() -> GetQueryLoggingConfigRequestBuilder {
+        GetQueryLoggingConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetQueryLoggingConfigRequest`](struct.GetQueryLoggingConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct GetQueryLoggingConfigRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetQueryLoggingConfigRequestBuilder {
+    /// Sets the [`id`](struct.GetQueryLoggingConfigRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetQueryLoggingConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetQueryLoggingConfigRequest, ::rusoto_core::BuildError> {
+        Ok(GetQueryLoggingConfigRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetQueryLoggingConfigResponse {
     /// <p>A complex type that contains information about the query logging configuration that you specified in a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetQueryLoggingConfig.html">GetQueryLoggingConfig</a> request.</p>
     pub query_logging_config: QueryLoggingConfig,
 }
 
-struct GetQueryLoggingConfigResponseDeserializer;
+pub(crate) struct GetQueryLoggingConfigResponseDeserializer;
 impl GetQueryLoggingConfigResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetQueryLoggingConfigResponse, XmlParseError> {
@@ -2770,16 +4688,59 @@ impl GetQueryLoggingConfigResponseDeserializer {
     }
 }
 /// <p>A complex type that contains information about the request to create a hosted zone.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetReusableDelegationSetLimitRequest {
     /// <p>The ID of the delegation set that you want to get the limit for.</p>
     pub delegation_set_id: String,
     /// <p>Specify <code>MAX_ZONES_BY_REUSABLE_DELEGATION_SET</code> to get the maximum number of hosted zones that you can associate with the specified reusable delegation set.</p>
-    pub type_: String,
+    pub type_: ReusableDelegationSetLimitType,
+}
+
+impl GetReusableDelegationSetLimitRequest {
+    /// Returns a builder for `GetReusableDelegationSetLimitRequest`, checking its required members when built.
+    pub fn builder() -> GetReusableDelegationSetLimitRequestBuilder {
+        GetReusableDelegationSetLimitRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetReusableDelegationSetLimitRequest`](struct.GetReusableDelegationSetLimitRequest.html).
+#[derive(Default, Debug)]
+pub struct GetReusableDelegationSetLimitRequestBuilder {
+    delegation_set_id: Option<String>,
+    type_: Option<ReusableDelegationSetLimitType>,
+}
+
+impl GetReusableDelegationSetLimitRequestBuilder {
+    /// Sets the [`delegation_set_id`](struct.GetReusableDelegationSetLimitRequest.html#structfield.delegation_set_id) member.
+    pub fn delegation_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.delegation_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.GetReusableDelegationSetLimitRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<ReusableDelegationSetLimitType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetReusableDelegationSetLimitRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetReusableDelegationSetLimitRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetReusableDelegationSetLimitRequest {
+            delegation_set_id: self
+                .delegation_set_id
+                .ok_or(::rusoto_core::BuildError::missing("delegation_set_id"))?,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 /// <p>A complex type that contains the requested limit. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetReusableDelegationSetLimitResponse {
     /// <p>The current number of hosted zones that you can associate with the specified reusable delegation set.</p>
     pub count: i64,
@@ -2787,16 +4748,23 @@ pub struct GetReusableDelegationSetLimitResponse {
     pub limit: ReusableDelegationSetLimit,
 }
 
-struct GetReusableDelegationSetLimitResponseDeserializer;
+pub(crate) struct GetReusableDelegationSetLimitResponseDeserializer;
 impl GetReusableDelegationSetLimitResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetReusableDelegationSetLimitResponse, XmlParseError> {
-        deserialize_elements::<_, GetReusableDelegationSetLimitResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            GetReusableDelegationSetLimitResponse {
+                count: Default::default(),
+                limit: ReusableDelegationSetLimit {
+                    type_: ReusableDelegationSetLimitType::Unknown(String::new()),
+                    value: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "Count" => {
@@ -2820,6 +4788,36 @@ pub struct GetReusableDelegationSetRequest {
     pub id: String,
 }
 
+impl GetReusableDelegationSetRequest {
+    /// Returns a builder for `GetReusableDelegationSetRequest`, checking its required members when built.
+    pub fn builder() -> GetReusableDelegationSetRequestBuilder {
+        GetReusableDelegationSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetReusableDelegationSetRequest`](struct.GetReusableDelegationSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetReusableDelegationSetRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetReusableDelegationSetRequestBuilder {
+    /// Sets the [`id`](struct.GetReusableDelegationSetRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetReusableDelegationSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetReusableDelegationSetRequest, ::rusoto_core::BuildError> {
+        Ok(GetReusableDelegationSetRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response to the <code>GetReusableDelegationSet</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetReusableDelegationSetResponse {
@@ -2827,10 +4825,10 @@ pub struct GetReusableDelegationSetResponse {
     pub delegation_set: DelegationSet,
 }
 
-struct GetReusableDelegationSetResponseDeserializer;
+pub(crate) struct GetReusableDelegationSetResponseDeserializer;
 impl GetReusableDelegationSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetReusableDelegationSetResponse, XmlParseError> {
@@ -2854,6 +4852,27 @@ impl GetReusableDelegationSetResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetTrafficPolicyInstanceCountRequest {}
 
+impl GetTrafficPolicyInstanceCountRequest {
+    /// Returns a builder for `GetTrafficPolicyInstanceCountRequest`, checking its required members when built.
+    pub fn builder() -> GetTrafficPolicyInstanceCountRequestBuilder {
+        GetTrafficPolicyInstanceCountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTrafficPolicyInstanceCountRequest`](struct.GetTrafficPolicyInstanceCountRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTrafficPolicyInstanceCountRequestBuilder {}
+
+impl GetTrafficPolicyInstanceCountRequestBuilder {
+    /// Builds the `GetTrafficPolicyInstanceCountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetTrafficPolicyInstanceCountRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetTrafficPolicyInstanceCountRequest {})
+    }
+}
+
 /// <p>A complex type that contains information about the resource record sets that Amazon Route 53 created based on a specified traffic policy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetTrafficPolicyInstanceCountResponse {
@@ -2861,10 +4880,10 @@ pub struct GetTrafficPolicyInstanceCountResponse {
     pub traffic_policy_instance_count: i64,
 }
 
-struct GetTrafficPolicyInstanceCountResponseDeserializer;
+pub(crate) struct GetTrafficPolicyInstanceCountResponseDeserializer;
 impl GetTrafficPolicyInstanceCountResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetTrafficPolicyInstanceCountResponse, XmlParseError> {
@@ -2894,23 +4913,66 @@ pub struct GetTrafficPolicyInstanceRequest {
     pub id: String,
 }
 
-/// <p>A complex type that contains information about the resource record sets that Amazon Route 53 created based on a specified traffic policy.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct GetTrafficPolicyInstanceResponse {
-    /// <p>A complex type that contains settings for the traffic policy instance.</p>
-    pub traffic_policy_instance: TrafficPolicyInstance,
+impl GetTrafficPolicyInstanceRequest {
+    /// Returns a builder for `GetTrafficPolicyInstanceRequest`, checking its required members when built.
+    pub fn builder() -> GetTrafficPolicyInstanceRequestBuilder {
+        GetTrafficPolicyInstanceRequestBuilder::default()
+    }
 }
 
-struct GetTrafficPolicyInstanceResponseDeserializer;
-impl GetTrafficPolicyInstanceResponseDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
+/// Builds a [`GetTrafficPolicyInstanceRequest`](struct.GetTrafficPolicyInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTrafficPolicyInstanceRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetTrafficPolicyInstanceRequestBuilder {
+    /// Sets the [`id`](struct.GetTrafficPolicyInstanceRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTrafficPolicyInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetTrafficPolicyInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(GetTrafficPolicyInstanceRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
+/// <p>A complex type that contains information about the resource record sets that Amazon Route 53 created based on a specified traffic policy.</p>
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetTrafficPolicyInstanceResponse {
+    /// <p>A complex type that contains settings for the traffic policy instance.</p>
+    pub traffic_policy_instance: TrafficPolicyInstance,
+}
+
+pub(crate) struct GetTrafficPolicyInstanceResponseDeserializer;
+impl GetTrafficPolicyInstanceResponseDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
         stack: &mut T,
     ) -> Result<GetTrafficPolicyInstanceResponse, XmlParseError> {
-        deserialize_elements::<_, GetTrafficPolicyInstanceResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            GetTrafficPolicyInstanceResponse {
+                traffic_policy_instance: TrafficPolicyInstance {
+                    hosted_zone_id: Default::default(),
+                    id: Default::default(),
+                    message: Default::default(),
+                    name: Default::default(),
+                    state: Default::default(),
+                    ttl: Default::default(),
+                    traffic_policy_id: Default::default(),
+                    traffic_policy_type: RRType::Unknown(String::new()),
+                    traffic_policy_version: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "TrafficPolicyInstance" => {
@@ -2936,23 +4998,73 @@ pub struct GetTrafficPolicyRequest {
     pub version: i64,
 }
 
+impl GetTrafficPolicyRequest {
+    /// Returns a builder for `GetTrafficPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetTrafficPolicyRequestBuilder {
+        GetTrafficPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTrafficPolicyRequest`](struct.GetTrafficPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTrafficPolicyRequestBuilder {
+    id: Option<String>,
+    version: Option<i64>,
+}
+
+impl GetTrafficPolicyRequestBuilder {
+    /// Sets the [`id`](struct.GetTrafficPolicyRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.GetTrafficPolicyRequest.html#structfield.version) member.
+    pub fn version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTrafficPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetTrafficPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetTrafficPolicyRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetTrafficPolicyResponse {
     /// <p>A complex type that contains settings for the specified traffic policy.</p>
     pub traffic_policy: TrafficPolicy,
 }
 
-struct GetTrafficPolicyResponseDeserializer;
+pub(crate) struct GetTrafficPolicyResponseDeserializer;
 impl GetTrafficPolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetTrafficPolicyResponse, XmlParseError> {
-        deserialize_elements::<_, GetTrafficPolicyResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            GetTrafficPolicyResponse {
+                traffic_policy: TrafficPolicy {
+                    comment: Default::default(),
+                    document: Default::default(),
+                    id: Default::default(),
+                    name: Default::default(),
+                    type_: RRType::Unknown(String::new()),
+                    version: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "TrafficPolicy" => {
@@ -2967,7 +5079,7 @@ impl GetTrafficPolicyResponseDeserializer {
     }
 }
 /// <p>A complex type that contains information about one health check that is associated with the current AWS account.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct HealthCheck {
     /// <p>A unique string that you specified when you created the health check.</p>
     pub caller_reference: String,
@@ -2983,51 +5095,83 @@ pub struct HealthCheck {
     pub linked_service: Option<LinkedService>,
 }
 
-struct HealthCheckDeserializer;
+pub(crate) struct HealthCheckDeserializer;
 impl HealthCheckDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HealthCheck, XmlParseError> {
-        deserialize_elements::<_, HealthCheck, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "CallerReference" => {
-                    obj.caller_reference =
-                        HealthCheckNonceDeserializer::deserialize("CallerReference", stack)?;
-                }
-                "CloudWatchAlarmConfiguration" => {
-                    obj.cloud_watch_alarm_configuration =
-                        Some(CloudWatchAlarmConfigurationDeserializer::deserialize(
-                            "CloudWatchAlarmConfiguration",
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            HealthCheck {
+                caller_reference: Default::default(),
+                cloud_watch_alarm_configuration: Default::default(),
+                health_check_config: HealthCheckConfig {
+                    alarm_identifier: Default::default(),
+                    child_health_checks: Default::default(),
+                    disabled: Default::default(),
+                    enable_sni: Default::default(),
+                    failure_threshold: Default::default(),
+                    fully_qualified_domain_name: Default::default(),
+                    health_threshold: Default::default(),
+                    ip_address: Default::default(),
+                    insufficient_data_health_status: Default::default(),
+                    inverted: Default::default(),
+                    measure_latency: Default::default(),
+                    port: Default::default(),
+                    regions: Default::default(),
+                    request_interval: Default::default(),
+                    resource_path: Default::default(),
+                    search_string: Default::default(),
+                    type_: HealthCheckType::Unknown(String::new()),
+                },
+                health_check_version: Default::default(),
+                id: Default::default(),
+                linked_service: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "CallerReference" => {
+                        obj.caller_reference =
+                            HealthCheckNonceDeserializer::deserialize("CallerReference", stack)?;
+                    }
+                    "CloudWatchAlarmConfiguration" => {
+                        obj.cloud_watch_alarm_configuration =
+                            Some(CloudWatchAlarmConfigurationDeserializer::deserialize(
+                                "CloudWatchAlarmConfiguration",
+                                stack,
+                            )?);
+                    }
+                    "HealthCheckConfig" => {
+                        obj.health_check_config =
+                            HealthCheckConfigDeserializer::deserialize("HealthCheckConfig", stack)?;
+                    }
+                    "HealthCheckVersion" => {
+                        obj.health_check_version = HealthCheckVersionDeserializer::deserialize(
+                            "HealthCheckVersion",
+                            stack,
+                        )?;
+                    }
+                    "Id" => {
+                        obj.id = HealthCheckIdDeserializer::deserialize("Id", stack)?;
+                    }
+                    "LinkedService" => {
+                        obj.linked_service = Some(LinkedServiceDeserializer::deserialize(
+                            "LinkedService",
                             stack,
                         )?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                "HealthCheckConfig" => {
-                    obj.health_check_config =
-                        HealthCheckConfigDeserializer::deserialize("HealthCheckConfig", stack)?;
-                }
-                "HealthCheckVersion" => {
-                    obj.health_check_version =
-                        HealthCheckVersionDeserializer::deserialize("HealthCheckVersion", stack)?;
-                }
-                "Id" => {
-                    obj.id = HealthCheckIdDeserializer::deserialize("Id", stack)?;
-                }
-                "LinkedService" => {
-                    obj.linked_service = Some(LinkedServiceDeserializer::deserialize(
-                        "LinkedService",
-                        stack,
-                    )?);
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 /// <p>A complex type that contains information about the health check.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct HealthCheckConfig {
     /// <p>A complex type that identifies the CloudWatch alarm that you want Amazon Route 53 health checkers to use to determine whether the specified health check is healthy.</p>
     pub alarm_identifier: Option<AlarmIdentifier>,
@@ -3046,7 +5190,7 @@ pub struct HealthCheckConfig {
     /// <p>The IPv4 or IPv6 IP address of the endpoint that you want Amazon Route 53 to perform health checks on. If you don't specify a value for <code>IPAddress</code>, Route 53 sends a DNS request to resolve the domain name that you specify in <code>FullyQualifiedDomainName</code> at the interval that you specify in <code>RequestInterval</code>. Using an IP address returned by DNS, Route 53 then checks the health of the endpoint.</p> <p>Use one of the following formats for the value of <code>IPAddress</code>: </p> <ul> <li> <p> <b>IPv4 address</b>: four values between 0 and 255, separated by periods (.), for example, <code>192.0.2.44</code>.</p> </li> <li> <p> <b>IPv6 address</b>: eight groups of four hexadecimal values, separated by colons (:), for example, <code>2001:0db8:85a3:0000:0000:abcd:0001:2345</code>. You can also shorten IPv6 addresses as described in RFC 5952, for example, <code>2001:db8:85a3::abcd:1:2345</code>.</p> </li> </ul> <p>If the endpoint is an EC2 instance, we recommend that you create an Elastic IP address, associate it with your EC2 instance, and specify the Elastic IP address for <code>IPAddress</code>. This ensures that the IP address of your instance will never change.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_UpdateHealthCheck.html#Route53-UpdateHealthCheck-request-FullyQualifiedDomainName">FullyQualifiedDomainName</a>. </p> <p>Constraints: Route 53 can't check the health of endpoints for which the IP address is in local, private, non-routable, or multicast ranges. For more information about IP addresses for which you can't create health checks, see the following documents:</p> <ul> <li> <p> <a href="https://tools.ietf.org/html/rfc5735">RFC 5735, Special Use IPv4 Addresses</a> </p> </li> <li> <p> <a href="https://tools.ietf.org/html/rfc6598">RFC 6598, IANA-Reserved IPv4 Prefix for Shared Address Space</a> </p> </li> <li> <p> <a href="https://tools.ietf.org/html/rfc5156">RFC 5156, Special-Use IPv6 Addresses</a> </p> </li> </ul> <p>When the value of <code>Type</code> is <code>CALCULATED</code> or <code>CLOUDWATCH_METRIC</code>, omit <code>IPAddress</code>.</p>
     pub ip_address: Option<String>,
     /// <p><p>When CloudWatch has insufficient data about the metric to determine the alarm state, the status that you want Amazon Route 53 to assign to the health check:</p> <ul> <li> <p> <code>Healthy</code>: Route 53 considers the health check to be healthy.</p> </li> <li> <p> <code>Unhealthy</code>: Route 53 considers the health check to be unhealthy.</p> </li> <li> <p> <code>LastKnownStatus</code>: Route 53 uses the status of the health check from the last time that CloudWatch had sufficient data to determine the alarm state. For new health checks that have no last known status, the default status for the health check is healthy.</p> </li> </ul></p>
-    pub insufficient_data_health_status: Option<String>,
+    pub insufficient_data_health_status: Option<InsufficientDataHealthStatus>,
     /// <p>Specify whether you want Amazon Route 53 to invert the status of a health check, for example, to consider a health check unhealthy when it otherwise would be considered healthy.</p>
     pub inverted: Option<bool>,
     /// <p><p>Specify whether you want Amazon Route 53 to measure the latency between health checkers in multiple AWS regions and your endpoint, and to display CloudWatch latency graphs on the <b>Health Checks</b> page in the Route 53 console.</p> <important> <p>You can&#39;t change the value of <code>MeasureLatency</code> after you create a health check.</p> </important></p>
@@ -3054,7 +5198,7 @@ pub struct HealthCheckConfig {
     /// <p>The port on the endpoint on which you want Amazon Route 53 to perform health checks. Specify a value for <code>Port</code> only when you specify a value for <code>IPAddress</code>.</p>
     pub port: Option<i64>,
     /// <p>A complex type that contains one <code>Region</code> element for each region from which you want Amazon Route 53 health checkers to check the specified endpoint.</p> <p>If you don't specify any regions, Route 53 health checkers automatically performs checks from all of the regions that are listed under <b>Valid Values</b>.</p> <p>If you update a health check to remove a region that has been performing health checks, Route 53 will briefly continue to perform checks from that region to ensure that some health checkers are always checking the endpoint (for example, if you replace three regions with four different regions). </p>
-    pub regions: Option<Vec<String>>,
+    pub regions: Option<Vec<HealthCheckRegion>>,
     /// <p>The number of seconds between the time that Amazon Route 53 gets a response from your endpoint and the time that it sends the next health check request. Each Route 53 health checker makes requests at this interval.</p> <important> <p>You can't change the value of <code>RequestInterval</code> after you create a health check.</p> </important> <p>If you don't specify a value for <code>RequestInterval</code>, the default value is <code>30</code> seconds.</p>
     pub request_interval: Option<i64>,
     /// <p>The path, if any, that you want Amazon Route 53 to request when performing health checks. The path can be any value for which your endpoint will return an HTTP status code of 2xx or 3xx when the endpoint is healthy, for example, the file /docs/route53-health-check.html. You can also include query string parameters, for example, <code>/welcome.html?language=jp&amp;login=y</code>. </p>
@@ -3062,106 +5206,134 @@ pub struct HealthCheckConfig {
     /// <p>If the value of Type is <code>HTTP_STR_MATCH</code> or <code>HTTP_STR_MATCH</code>, the string that you want Amazon Route 53 to search for in the response body from the specified resource. If the string appears in the response body, Route 53 considers the resource healthy.</p> <p>Route 53 considers case when searching for <code>SearchString</code> in the response body. </p>
     pub search_string: Option<String>,
     /// <p>The type of health check that you want to create, which indicates how Amazon Route 53 determines whether an endpoint is healthy.</p> <important> <p>You can't change the value of <code>Type</code> after you create a health check.</p> </important> <p>You can create the following types of health checks:</p> <ul> <li> <p> <b>HTTP</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTP request and waits for an HTTP status code of 200 or greater and less than 400.</p> </li> <li> <p> <b>HTTPS</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTPS request and waits for an HTTP status code of 200 or greater and less than 400.</p> <important> <p>If you specify <code>HTTPS</code> for the value of <code>Type</code>, the endpoint must support TLS v1.0 or later.</p> </important> </li> <li> <p> <b>HTTP_STR_MATCH</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTP request and searches the first 5,120 bytes of the response body for the string that you specify in <code>SearchString</code>.</p> </li> <li> <p> <b>HTTPS_STR_MATCH</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an <code>HTTPS</code> request and searches the first 5,120 bytes of the response body for the string that you specify in <code>SearchString</code>.</p> </li> <li> <p> <b>TCP</b>: Route 53 tries to establish a TCP connection.</p> </li> <li> <p> <b>CLOUDWATCH_METRIC</b>: The health check is associated with a CloudWatch alarm. If the state of the alarm is <code>OK</code>, the health check is considered healthy. If the state is <code>ALARM</code>, the health check is considered unhealthy. If CloudWatch doesn't have sufficient data to determine whether the state is <code>OK</code> or <code>ALARM</code>, the health check status depends on the setting for <code>InsufficientDataHealthStatus</code>: <code>Healthy</code>, <code>Unhealthy</code>, or <code>LastKnownStatus</code>. </p> </li> <li> <p> <b>CALCULATED</b>: For health checks that monitor the status of other health checks, Route 53 adds up the number of health checks that Route 53 health checkers consider to be healthy and compares that number with the value of <code>HealthThreshold</code>. </p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-determining-health-of-endpoints.html">How Route 53 Determines Whether an Endpoint Is Healthy</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
-    pub type_: String,
+    pub type_: HealthCheckType,
 }
 
-struct HealthCheckConfigDeserializer;
+pub(crate) struct HealthCheckConfigDeserializer;
 impl HealthCheckConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HealthCheckConfig, XmlParseError> {
-        deserialize_elements::<_, HealthCheckConfig, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "AlarmIdentifier" => {
-                    obj.alarm_identifier = Some(AlarmIdentifierDeserializer::deserialize(
-                        "AlarmIdentifier",
-                        stack,
-                    )?);
-                }
-                "ChildHealthChecks" => {
-                    obj.child_health_checks.get_or_insert(vec![]).extend(
-                        ChildHealthCheckListDeserializer::deserialize("ChildHealthChecks", stack)?,
-                    );
-                }
-                "Disabled" => {
-                    obj.disabled = Some(DisabledDeserializer::deserialize("Disabled", stack)?);
-                }
-                "EnableSNI" => {
-                    obj.enable_sni = Some(EnableSNIDeserializer::deserialize("EnableSNI", stack)?);
-                }
-                "FailureThreshold" => {
-                    obj.failure_threshold = Some(FailureThresholdDeserializer::deserialize(
-                        "FailureThreshold",
-                        stack,
-                    )?);
-                }
-                "FullyQualifiedDomainName" => {
-                    obj.fully_qualified_domain_name =
-                        Some(FullyQualifiedDomainNameDeserializer::deserialize(
-                            "FullyQualifiedDomainName",
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            HealthCheckConfig {
+                alarm_identifier: Default::default(),
+                child_health_checks: Default::default(),
+                disabled: Default::default(),
+                enable_sni: Default::default(),
+                failure_threshold: Default::default(),
+                fully_qualified_domain_name: Default::default(),
+                health_threshold: Default::default(),
+                ip_address: Default::default(),
+                insufficient_data_health_status: Default::default(),
+                inverted: Default::default(),
+                measure_latency: Default::default(),
+                port: Default::default(),
+                regions: Default::default(),
+                request_interval: Default::default(),
+                resource_path: Default::default(),
+                search_string: Default::default(),
+                type_: HealthCheckType::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "AlarmIdentifier" => {
+                        obj.alarm_identifier = Some(AlarmIdentifierDeserializer::deserialize(
+                            "AlarmIdentifier",
                             stack,
                         )?);
-                }
-                "HealthThreshold" => {
-                    obj.health_threshold = Some(HealthThresholdDeserializer::deserialize(
-                        "HealthThreshold",
-                        stack,
-                    )?);
-                }
-                "IPAddress" => {
-                    obj.ip_address = Some(IPAddressDeserializer::deserialize("IPAddress", stack)?);
-                }
-                "InsufficientDataHealthStatus" => {
-                    obj.insufficient_data_health_status =
-                        Some(InsufficientDataHealthStatusDeserializer::deserialize(
-                            "InsufficientDataHealthStatus",
+                    }
+                    "ChildHealthChecks" => {
+                        obj.child_health_checks.get_or_insert(vec![]).extend(
+                            ChildHealthCheckListDeserializer::deserialize(
+                                "ChildHealthChecks",
+                                stack,
+                            )?,
+                        );
+                    }
+                    "Disabled" => {
+                        obj.disabled = Some(DisabledDeserializer::deserialize("Disabled", stack)?);
+                    }
+                    "EnableSNI" => {
+                        obj.enable_sni =
+                            Some(EnableSNIDeserializer::deserialize("EnableSNI", stack)?);
+                    }
+                    "FailureThreshold" => {
+                        obj.failure_threshold = Some(FailureThresholdDeserializer::deserialize(
+                            "FailureThreshold",
                             stack,
                         )?);
+                    }
+                    "FullyQualifiedDomainName" => {
+                        obj.fully_qualified_domain_name =
+                            Some(FullyQualifiedDomainNameDeserializer::deserialize(
+                                "FullyQualifiedDomainName",
+                                stack,
+                            )?);
+                    }
+                    "HealthThreshold" => {
+                        obj.health_threshold = Some(HealthThresholdDeserializer::deserialize(
+                            "HealthThreshold",
+                            stack,
+                        )?);
+                    }
+                    "IPAddress" => {
+                        obj.ip_address =
+                            Some(IPAddressDeserializer::deserialize("IPAddress", stack)?);
+                    }
+                    "InsufficientDataHealthStatus" => {
+                        obj.insufficient_data_health_status =
+                            Some(InsufficientDataHealthStatusDeserializer::deserialize(
+                                "InsufficientDataHealthStatus",
+                                stack,
+                            )?);
+                    }
+                    "Inverted" => {
+                        obj.inverted = Some(InvertedDeserializer::deserialize("Inverted", stack)?);
+                    }
+                    "MeasureLatency" => {
+                        obj.measure_latency = Some(MeasureLatencyDeserializer::deserialize(
+                            "MeasureLatency",
+                            stack,
+                        )?);
+                    }
+                    "Port" => {
+                        obj.port = Some(PortDeserializer::deserialize("Port", stack)?);
+                    }
+                    "Regions" => {
+                        obj.regions.get_or_insert(vec![]).extend(
+                            HealthCheckRegionListDeserializer::deserialize("Regions", stack)?,
+                        );
+                    }
+                    "RequestInterval" => {
+                        obj.request_interval = Some(RequestIntervalDeserializer::deserialize(
+                            "RequestInterval",
+                            stack,
+                        )?);
+                    }
+                    "ResourcePath" => {
+                        obj.resource_path = Some(ResourcePathDeserializer::deserialize(
+                            "ResourcePath",
+                            stack,
+                        )?);
+                    }
+                    "SearchString" => {
+                        obj.search_string = Some(SearchStringDeserializer::deserialize(
+                            "SearchString",
+                            stack,
+                        )?);
+                    }
+                    "Type" => {
+                        obj.type_ = HealthCheckTypeDeserializer::deserialize("Type", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                "Inverted" => {
-                    obj.inverted = Some(InvertedDeserializer::deserialize("Inverted", stack)?);
-                }
-                "MeasureLatency" => {
-                    obj.measure_latency = Some(MeasureLatencyDeserializer::deserialize(
-                        "MeasureLatency",
-                        stack,
-                    )?);
-                }
-                "Port" => {
-                    obj.port = Some(PortDeserializer::deserialize("Port", stack)?);
-                }
-                "Regions" => {
-                    obj.regions.get_or_insert(vec![]).extend(
-                        HealthCheckRegionListDeserializer::deserialize("Regions", stack)?,
-                    );
-                }
-                "RequestInterval" => {
-                    obj.request_interval = Some(RequestIntervalDeserializer::deserialize(
-                        "RequestInterval",
-                        stack,
-                    )?);
-                }
-                "ResourcePath" => {
-                    obj.resource_path = Some(ResourcePathDeserializer::deserialize(
-                        "ResourcePath",
-                        stack,
-                    )?);
-                }
-                "SearchString" => {
-                    obj.search_string = Some(SearchStringDeserializer::deserialize(
-                        "SearchString",
-                        stack,
-                    )?);
-                }
-                "Type" => {
-                    obj.type_ = HealthCheckTypeDeserializer::deserialize("Type", stack)?;
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -3304,10 +5476,13 @@ impl HealthCheckConfigSerializer {
     }
 }
 
-struct HealthCheckCountDeserializer;
+pub(crate) struct HealthCheckCountDeserializer;
 impl HealthCheckCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3315,10 +5490,13 @@ impl HealthCheckCountDeserializer {
         Ok(obj)
     }
 }
-struct HealthCheckIdDeserializer;
+pub(crate) struct HealthCheckIdDeserializer;
 impl HealthCheckIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3347,10 +5525,13 @@ impl HealthCheckIdSerializer {
     }
 }
 
-struct HealthCheckNonceDeserializer;
+pub(crate) struct HealthCheckNonceDeserializer;
 impl HealthCheckNonceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3385,15 +5566,15 @@ pub struct HealthCheckObservation {
     /// <p>The IP address of the Amazon Route 53 health checker that provided the failure reason in <code>StatusReport</code>.</p>
     pub ip_address: Option<String>,
     /// <p>The region of the Amazon Route 53 health checker that provided the status in <code>StatusReport</code>.</p>
-    pub region: Option<String>,
+    pub region: Option<HealthCheckRegion>,
     /// <p>A complex type that contains the last failure reason as reported by one Amazon Route 53 health checker and the time of the failed health check.</p>
     pub status_report: Option<StatusReport>,
 }
 
-struct HealthCheckObservationDeserializer;
+pub(crate) struct HealthCheckObservationDeserializer;
 impl HealthCheckObservationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HealthCheckObservation, XmlParseError> {
@@ -3417,10 +5598,10 @@ impl HealthCheckObservationDeserializer {
         })
     }
 }
-struct HealthCheckObservationsDeserializer;
+pub(crate) struct HealthCheckObservationsDeserializer;
 impl HealthCheckObservationsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<HealthCheckObservation>, XmlParseError> {
@@ -3437,12 +5618,15 @@ impl HealthCheckObservationsDeserializer {
         })
     }
 }
-struct HealthCheckRegionDeserializer;
+pub(crate) struct HealthCheckRegionDeserializer;
 impl HealthCheckRegionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<HealthCheckRegion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3455,7 +5639,7 @@ impl HealthCheckRegionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &HealthCheckRegion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3469,13 +5653,96 @@ impl HealthCheckRegionSerializer {
     }
 }
 
-struct HealthCheckRegionListDeserializer;
+/// <p>A complex type that contains one <code>Region</code> element for each region from which you want Amazon Route 53 health checkers to check the specified endpoint.</p> <p>If you don't specify any regions, Route 53 health checkers automatically performs checks from all of the regions that are listed under <b>Valid Values</b>.</p> <p>If you update a health check to remove a region that has been performing health checks, Route 53 will briefly continue to perform checks from that region to ensure that some health checkers are always checking the endpoint (for example, if you replace three regions with four different regions). </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HealthCheckRegion {
+    /// <p><code>us-east-1</code></p>
+    UsEast1,
+    /// <p><code>us-west-1</code></p>
+    UsWest1,
+    /// <p><code>us-west-2</code></p>
+    UsWest2,
+    /// <p><code>eu-west-1</code></p>
+    EuWest1,
+    /// <p><code>ap-southeast-1</code></p>
+    ApSoutheast1,
+    /// <p><code>ap-southeast-2</code></p>
+    ApSoutheast2,
+    /// <p><code>ap-northeast-1</code></p>
+    ApNortheast1,
+    /// <p><code>sa-east-1</code></p>
+    SaEast1,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HealthCheckRegion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthCheckRegion::UsEast1 => "us-east-1",
+            HealthCheckRegion::UsWest1 => "us-west-1",
+            HealthCheckRegion::UsWest2 => "us-west-2",
+            HealthCheckRegion::EuWest1 => "eu-west-1",
+            HealthCheckRegion::ApSoutheast1 => "ap-southeast-1",
+            HealthCheckRegion::ApSoutheast2 => "ap-southeast-2",
+            HealthCheckRegion::ApNortheast1 => "ap-northeast-1",
+            HealthCheckRegion::SaEast1 => "sa-east-1",
+            HealthCheckRegion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HealthCheckRegion {
+    fn from(value: &'a str) -> HealthCheckRegion {
+        match value {
+            "us-east-1" => HealthCheckRegion::UsEast1,
+            "us-west-1" => HealthCheckRegion::UsWest1,
+            "us-west-2" => HealthCheckRegion::UsWest2,
+            "eu-west-1" => HealthCheckRegion::EuWest1,
+            "ap-southeast-1" => HealthCheckRegion::ApSoutheast1,
+            "ap-southeast-2" => HealthCheckRegion::ApSoutheast2,
+            "ap-northeast-1" => HealthCheckRegion::ApNortheast1,
+            "sa-east-1" => HealthCheckRegion::SaEast1,
+            _ => HealthCheckRegion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthCheckRegion {
+    fn from(value: String) -> HealthCheckRegion {
+        HealthCheckRegion::from(value.as_str())
+    }
+}
+
+impl From<HealthCheckRegion> for String {
+    fn from(value: HealthCheckRegion) -> String {
+        match value {
+            HealthCheckRegion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HealthCheckRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HealthCheckRegion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct HealthCheckRegionListDeserializer;
 impl HealthCheckRegionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<HealthCheckRegion>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "Region" {
                 obj.push(HealthCheckRegionDeserializer::deserialize("Region", stack)?);
@@ -3493,7 +5760,7 @@ impl HealthCheckRegionListSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &Vec<String>,
+        obj: &Vec<HealthCheckRegion>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3507,12 +5774,15 @@ impl HealthCheckRegionListSerializer {
     }
 }
 
-struct HealthCheckTypeDeserializer;
+pub(crate) struct HealthCheckTypeDeserializer;
 impl HealthCheckTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<HealthCheckType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3525,7 +5795,7 @@ impl HealthCheckTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &HealthCheckType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3539,10 +5809,96 @@ impl HealthCheckTypeSerializer {
     }
 }
 
-struct HealthCheckVersionDeserializer;
+/// <p>The type of health check that you want to create, which indicates how Amazon Route 53 determines whether an endpoint is healthy.</p> <important> <p>You can't change the value of <code>Type</code> after you create a health check.</p> </important> <p>You can create the following types of health checks:</p> <ul> <li> <p> <b>HTTP</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTP request and waits for an HTTP status code of 200 or greater and less than 400.</p> </li> <li> <p> <b>HTTPS</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTPS request and waits for an HTTP status code of 200 or greater and less than 400.</p> <important> <p>If you specify <code>HTTPS</code> for the value of <code>Type</code>, the endpoint must support TLS v1.0 or later.</p> </important> </li> <li> <p> <b>HTTP_STR_MATCH</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTP request and searches the first 5,120 bytes of the response body for the string that you specify in <code>SearchString</code>.</p> </li> <li> <p> <b>HTTPS_STR_MATCH</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an <code>HTTPS</code> request and searches the first 5,120 bytes of the response body for the string that you specify in <code>SearchString</code>.</p> </li> <li> <p> <b>TCP</b>: Route 53 tries to establish a TCP connection.</p> </li> <li> <p> <b>CLOUDWATCH_METRIC</b>: The health check is associated with a CloudWatch alarm. If the state of the alarm is <code>OK</code>, the health check is considered healthy. If the state is <code>ALARM</code>, the health check is considered unhealthy. If CloudWatch doesn't have sufficient data to determine whether the state is <code>OK</code> or <code>ALARM</code>, the health check status depends on the setting for <code>InsufficientDataHealthStatus</code>: <code>Healthy</code>, <code>Unhealthy</code>, or <code>LastKnownStatus</code>. </p> </li> <li> <p> <b>CALCULATED</b>: For health checks that monitor the status of other health checks, Route 53 adds up the number of health checks that Route 53 health checkers consider to be healthy and compares that number with the value of <code>HealthThreshold</code>. </p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-determining-health-of-endpoints.html">How Route 53 Determines Whether an Endpoint Is Healthy</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HealthCheckType {
+    /// <p><code>HTTP</code></p>
+    Http,
+    /// <p><code>HTTPS</code></p>
+    Https,
+    /// <p><code>HTTP_STR_MATCH</code></p>
+    HttpStrMatch,
+    /// <p><code>HTTPS_STR_MATCH</code></p>
+    HttpsStrMatch,
+    /// <p><code>TCP</code></p>
+    Tcp,
+    /// <p><code>CALCULATED</code></p>
+    Calculated,
+    /// <p><code>CLOUDWATCH_METRIC</code></p>
+    CloudwatchMetric,
+    /// <p><code>RECOVERY_CONTROL</code></p>
+    RecoveryControl,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HealthCheckType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthCheckType::Http => "HTTP",
+            HealthCheckType::Https => "HTTPS",
+            HealthCheckType::HttpStrMatch => "HTTP_STR_MATCH",
+            HealthCheckType::HttpsStrMatch => "HTTPS_STR_MATCH",
+            HealthCheckType::Tcp => "TCP",
+            HealthCheckType::Calculated => "CALCULATED",
+            HealthCheckType::CloudwatchMetric => "CLOUDWATCH_METRIC",
+            HealthCheckType::RecoveryControl => "RECOVERY_CONTROL",
+            HealthCheckType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HealthCheckType {
+    fn from(value: &'a str) -> HealthCheckType {
+        match value {
+            "HTTP" => HealthCheckType::Http,
+            "HTTPS" => HealthCheckType::Https,
+            "HTTP_STR_MATCH" => HealthCheckType::HttpStrMatch,
+            "HTTPS_STR_MATCH" => HealthCheckType::HttpsStrMatch,
+            "TCP" => HealthCheckType::Tcp,
+            "CALCULATED" => HealthCheckType::Calculated,
+            "CLOUDWATCH_METRIC" => HealthCheckType::CloudwatchMetric,
+            "RECOVERY_CONTROL" => HealthCheckType::RecoveryControl,
+            _ => HealthCheckType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthCheckType {
+    fn from(value: String) -> HealthCheckType {
+        HealthCheckType::from(value.as_str())
+    }
+}
+
+impl From<HealthCheckType> for String {
+    fn from(value: HealthCheckType) -> String {
+        match value {
+            HealthCheckType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HealthCheckType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HealthCheckType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct HealthCheckVersionDeserializer;
 impl HealthCheckVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3571,10 +5927,10 @@ impl HealthCheckVersionSerializer {
     }
 }
 
-struct HealthChecksDeserializer;
+pub(crate) struct HealthChecksDeserializer;
 impl HealthChecksDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<HealthCheck>, XmlParseError> {
@@ -3588,10 +5944,13 @@ impl HealthChecksDeserializer {
         })
     }
 }
-struct HealthThresholdDeserializer;
+pub(crate) struct HealthThresholdDeserializer;
 impl HealthThresholdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3637,10 +5996,10 @@ pub struct HostedZone {
     pub resource_record_set_count: Option<i64>,
 }
 
-struct HostedZoneDeserializer;
+pub(crate) struct HostedZoneDeserializer;
 impl HostedZoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HostedZone, XmlParseError> {
@@ -3687,10 +6046,10 @@ pub struct HostedZoneConfig {
     pub private_zone: Option<bool>,
 }
 
-struct HostedZoneConfigDeserializer;
+pub(crate) struct HostedZoneConfigDeserializer;
 impl HostedZoneConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HostedZoneConfig, XmlParseError> {
@@ -3746,10 +6105,13 @@ impl HostedZoneConfigSerializer {
     }
 }
 
-struct HostedZoneCountDeserializer;
+pub(crate) struct HostedZoneCountDeserializer;
 impl HostedZoneCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3758,41 +6120,52 @@ impl HostedZoneCountDeserializer {
     }
 }
 /// <p>A complex type that contains the type of limit that you specified in the request and the current value for that limit.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct HostedZoneLimit {
     /// <p><p>The limit that you requested. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>RRSETS</em>BY<em>ZONE</b>: The maximum number of records that you can create in the specified hosted zone.</p> </li> <li> <p> <b>MAX</em>VPCS<em>ASSOCIATED</em>BY_ZONE</b>: The maximum number of Amazon VPCs that you can associate with the specified private hosted zone.</p> </li> </ul></p>
-    pub type_: String,
+    pub type_: HostedZoneLimitType,
     /// <p>The current value for the limit that is specified by <code>Type</code>.</p>
     pub value: i64,
 }
 
-struct HostedZoneLimitDeserializer;
+pub(crate) struct HostedZoneLimitDeserializer;
 impl HostedZoneLimitDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HostedZoneLimit, XmlParseError> {
-        deserialize_elements::<_, HostedZoneLimit, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Type" => {
-                    obj.type_ = HostedZoneLimitTypeDeserializer::deserialize("Type", stack)?;
-                }
-                "Value" => {
-                    obj.value = LimitValueDeserializer::deserialize("Value", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            HostedZoneLimit {
+                type_: HostedZoneLimitType::Unknown(String::new()),
+                value: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Type" => {
+                        obj.type_ = HostedZoneLimitTypeDeserializer::deserialize("Type", stack)?;
+                    }
+                    "Value" => {
+                        obj.value = LimitValueDeserializer::deserialize("Value", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct HostedZoneLimitTypeDeserializer;
+pub(crate) struct HostedZoneLimitTypeDeserializer;
 impl HostedZoneLimitTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<HostedZoneLimitType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3805,7 +6178,7 @@ impl HostedZoneLimitTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &HostedZoneLimitType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3819,21 +6192,83 @@ impl HostedZoneLimitTypeSerializer {
     }
 }
 
-struct HostedZoneRRSetCountDeserializer;
-impl HostedZoneRRSetCountDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
-        end_element(tag_name, stack)?;
+/// <p><p>The limit that you want to get. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>RRSETS</em>BY<em>ZONE</b>: The maximum number of records that you can create in the specified hosted zone.</p> </li> <li> <p> <b>MAX</em>VPCS<em>ASSOCIATED</em>BY_ZONE</b>: The maximum number of Amazon VPCs that you can associate with the specified private hosted zone.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HostedZoneLimitType {
+    /// <p><code>MAX_RRSETS_BY_ZONE</code></p>
+    MaxRrsetsByZone,
+    /// <p><code>MAX_VPCS_ASSOCIATED_BY_ZONE</code></p>
+    MaxVpcsAssociatedByZone,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
 
-        Ok(obj)
+impl HostedZoneLimitType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HostedZoneLimitType::MaxRrsetsByZone => "MAX_RRSETS_BY_ZONE",
+            HostedZoneLimitType::MaxVpcsAssociatedByZone => "MAX_VPCS_ASSOCIATED_BY_ZONE",
+            HostedZoneLimitType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HostedZoneLimitType {
+    fn from(value: &'a str) -> HostedZoneLimitType {
+        match value {
+            "MAX_RRSETS_BY_ZONE" => HostedZoneLimitType::MaxRrsetsByZone,
+            "MAX_VPCS_ASSOCIATED_BY_ZONE" => HostedZoneLimitType::MaxVpcsAssociatedByZone,
+            _ => HostedZoneLimitType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HostedZoneLimitType {
+    fn from(value: String) -> HostedZoneLimitType {
+        HostedZoneLimitType::from(value.as_str())
+    }
+}
+
+impl From<HostedZoneLimitType> for String {
+    fn from(value: HostedZoneLimitType) -> String {
+        match value {
+            HostedZoneLimitType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HostedZoneLimitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HostedZoneLimitType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct HostedZoneRRSetCountDeserializer;
+impl HostedZoneRRSetCountDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
     }
 }
-struct HostedZonesDeserializer;
+pub(crate) struct HostedZonesDeserializer;
 impl HostedZonesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<HostedZone>, XmlParseError> {
@@ -3847,10 +6282,13 @@ impl HostedZonesDeserializer {
         })
     }
 }
-struct IPAddressDeserializer;
+pub(crate) struct IPAddressDeserializer;
 impl IPAddressDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3879,10 +6317,13 @@ impl IPAddressSerializer {
     }
 }
 
-struct IPAddressCidrDeserializer;
+pub(crate) struct IPAddressCidrDeserializer;
 impl IPAddressCidrDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3890,12 +6331,15 @@ impl IPAddressCidrDeserializer {
         Ok(obj)
     }
 }
-struct InsufficientDataHealthStatusDeserializer;
+pub(crate) struct InsufficientDataHealthStatusDeserializer;
 impl InsufficientDataHealthStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<InsufficientDataHealthStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3908,7 +6352,7 @@ impl InsufficientDataHealthStatusSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &InsufficientDataHealthStatus,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3922,10 +6366,76 @@ impl InsufficientDataHealthStatusSerializer {
     }
 }
 
-struct InvertedDeserializer;
+/// <p><p>When CloudWatch has insufficient data about the metric to determine the alarm state, the status that you want Amazon Route 53 to assign to the health check:</p> <ul> <li> <p> <code>Healthy</code>: Route 53 considers the health check to be healthy.</p> </li> <li> <p> <code>Unhealthy</code>: Route 53 considers the health check to be unhealthy.</p> </li> <li> <p> <code>LastKnownStatus</code>: Route 53 uses the status of the health check from the last time that CloudWatch had sufficient data to determine the alarm state. For new health checks that have no last known status, the default status for the health check is healthy.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InsufficientDataHealthStatus {
+    /// <p><code>Healthy</code> - Route 53 considers the health check to be healthy.</p>
+    Healthy,
+    /// <p><code>Unhealthy</code> - Route 53 considers the health check to be unhealthy.</p>
+    Unhealthy,
+    /// <p><code>LastKnownStatus</code> - Route 53 uses the status of the health check from the last time that CloudWatch had sufficient data to determine the alarm state. For new health checks that have no last known status, the default status for the health check is healthy.</p>
+    LastKnownStatus,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InsufficientDataHealthStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InsufficientDataHealthStatus::Healthy => "Healthy",
+            InsufficientDataHealthStatus::Unhealthy => "Unhealthy",
+            InsufficientDataHealthStatus::LastKnownStatus => "LastKnownStatus",
+            InsufficientDataHealthStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InsufficientDataHealthStatus {
+    fn from(value: &'a str) -> InsufficientDataHealthStatus {
+        match value {
+            "Healthy" => InsufficientDataHealthStatus::Healthy,
+            "Unhealthy" => InsufficientDataHealthStatus::Unhealthy,
+            "LastKnownStatus" => InsufficientDataHealthStatus::LastKnownStatus,
+            _ => InsufficientDataHealthStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InsufficientDataHealthStatus {
+    fn from(value: String) -> InsufficientDataHealthStatus {
+        InsufficientDataHealthStatus::from(value.as_str())
+    }
+}
+
+impl From<InsufficientDataHealthStatus> for String {
+    fn from(value: InsufficientDataHealthStatus) -> String {
+        match value {
+            InsufficientDataHealthStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InsufficientDataHealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InsufficientDataHealthStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct InvertedDeserializer;
 impl InvertedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3954,10 +6464,13 @@ impl InvertedSerializer {
     }
 }
 
-struct IsPrivateZoneDeserializer;
+pub(crate) struct IsPrivateZoneDeserializer;
 impl IsPrivateZoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3986,10 +6499,13 @@ impl IsPrivateZoneSerializer {
     }
 }
 
-struct LimitValueDeserializer;
+pub(crate) struct LimitValueDeserializer;
 impl LimitValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4006,10 +6522,10 @@ pub struct LinkedService {
     pub service_principal: Option<String>,
 }
 
-struct LinkedServiceDeserializer;
+pub(crate) struct LinkedServiceDeserializer;
 impl LinkedServiceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LinkedService, XmlParseError> {
@@ -4046,6 +6562,60 @@ pub struct ListGeoLocationsRequest {
     pub start_subdivision_code: Option<String>,
 }
 
+impl ListGeoLocationsRequest {
+    /// Returns a builder for `ListGeoLocationsRequest`, checking its required members when built.
+    pub fn builder() -> ListGeoLocationsRequestBuilder {
+        ListGeoLocationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGeoLocationsRequest`](struct.ListGeoLocationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGeoLocationsRequestBuilder {
+    max_items: Option<String>,
+    start_continent_code: Option<String>,
+    start_country_code: Option<String>,
+    start_subdivision_code: Option<String>,
+}
+
+impl ListGeoLocationsRequestBuilder {
+    /// Sets the [`max_items`](struct.ListGeoLocationsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_continent_code`](struct.ListGeoLocationsRequest.html#structfield.start_continent_code) member.
+    pub fn start_continent_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.start_continent_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_country_code`](struct.ListGeoLocationsRequest.html#structfield.start_country_code) member.
+    pub fn start_country_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.start_country_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_subdivision_code`](struct.ListGeoLocationsRequest.html#structfield.start_subdivision_code) member.
+    pub fn start_subdivision_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.start_subdivision_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGeoLocationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListGeoLocationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListGeoLocationsRequest {
+            max_items: self.max_items,
+            start_continent_code: self.start_continent_code,
+            start_country_code: self.start_country_code,
+            start_subdivision_code: self.start_subdivision_code,
+        })
+    }
+}
+
 /// <p>A complex type containing the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListGeoLocationsResponse {
@@ -4063,10 +6633,10 @@ pub struct ListGeoLocationsResponse {
     pub next_subdivision_code: Option<String>,
 }
 
-struct ListGeoLocationsResponseDeserializer;
+pub(crate) struct ListGeoLocationsResponseDeserializer;
 impl ListGeoLocationsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListGeoLocationsResponse, XmlParseError> {
@@ -4127,6 +6697,44 @@ pub struct ListHealthChecksRequest {
     pub max_items: Option<String>,
 }
 
+impl ListHealthChecksRequest {
+    /// Returns a builder for `ListHealthChecksRequest`, checking its required members when built.
+    pub fn builder() -> ListHealthChecksRequestBuilder {
+        ListHealthChecksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListHealthChecksRequest`](struct.ListHealthChecksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListHealthChecksRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<String>,
+}
+
+impl ListHealthChecksRequestBuilder {
+    /// Sets the [`marker`](struct.ListHealthChecksRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListHealthChecksRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListHealthChecksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListHealthChecksRequest, ::rusoto_core::BuildError> {
+        Ok(ListHealthChecksRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>ListHealthChecks</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListHealthChecksResponse {
@@ -4142,10 +6750,10 @@ pub struct ListHealthChecksResponse {
     pub next_marker: Option<String>,
 }
 
-struct ListHealthChecksResponseDeserializer;
+pub(crate) struct ListHealthChecksResponseDeserializer;
 impl ListHealthChecksResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListHealthChecksResponse, XmlParseError> {
@@ -4193,6 +6801,52 @@ pub struct ListHostedZonesByNameRequest {
     pub max_items: Option<String>,
 }
 
+impl ListHostedZonesByNameRequest {
+    /// Returns a builder for `ListHostedZonesByNameRequest`, checking its required members when built.
+    pub fn builder() -> ListHostedZonesByNameRequestBuilder {
+        ListHostedZonesByNameRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListHostedZonesByNameRequest`](struct.ListHostedZonesByNameRequest.html).
+#[derive(Default, Debug)]
+pub struct ListHostedZonesByNameRequestBuilder {
+    dns_name: Option<String>,
+    hosted_zone_id: Option<String>,
+    max_items: Option<String>,
+}
+
+impl ListHostedZonesByNameRequestBuilder {
+    /// Sets the [`dns_name`](struct.ListHostedZonesByNameRequest.html#structfield.dns_name) member.
+    pub fn dns_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dns_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hosted_zone_id`](struct.ListHostedZonesByNameRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListHostedZonesByNameRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListHostedZonesByNameRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListHostedZonesByNameRequest, ::rusoto_core::BuildError> {
+        Ok(ListHostedZonesByNameRequest {
+            dns_name: self.dns_name,
+            hosted_zone_id: self.hosted_zone_id,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListHostedZonesByNameResponse {
@@ -4212,10 +6866,10 @@ pub struct ListHostedZonesByNameResponse {
     pub next_hosted_zone_id: Option<String>,
 }
 
-struct ListHostedZonesByNameResponseDeserializer;
+pub(crate) struct ListHostedZonesByNameResponseDeserializer;
 impl ListHostedZonesByNameResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListHostedZonesByNameResponse, XmlParseError> {
@@ -4270,6 +6924,50 @@ pub struct ListHostedZonesRequest {
     pub max_items: Option<String>,
 }
 
+impl ListHostedZonesRequest {
+    /// Returns a builder for `ListHostedZonesRequest`, checking its required members when built.
+    pub fn builder() -> ListHostedZonesRequestBuilder {
+        ListHostedZonesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListHostedZonesRequest`](struct.ListHostedZonesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListHostedZonesRequestBuilder {
+    delegation_set_id: Option<String>,
+    marker: Option<String>,
+    max_items: Option<String>,
+}
+
+impl ListHostedZonesRequestBuilder {
+    /// Sets the [`delegation_set_id`](struct.ListHostedZonesRequest.html#structfield.delegation_set_id) member.
+    pub fn delegation_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.delegation_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListHostedZonesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListHostedZonesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListHostedZonesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListHostedZonesRequest, ::rusoto_core::BuildError> {
+        Ok(ListHostedZonesRequest {
+            delegation_set_id: self.delegation_set_id,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListHostedZonesResponse {
     /// <p>A complex type that contains general information about the hosted zone.</p>
@@ -4284,10 +6982,10 @@ pub struct ListHostedZonesResponse {
     pub next_marker: Option<String>,
 }
 
-struct ListHostedZonesResponseDeserializer;
+pub(crate) struct ListHostedZonesResponseDeserializer;
 impl ListHostedZonesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListHostedZonesResponse, XmlParseError> {
@@ -4331,6 +7029,52 @@ pub struct ListQueryLoggingConfigsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListQueryLoggingConfigsRequest {
+    /// Returns a builder for `ListQueryLoggingConfigsRequest`, checking its required members when built.
+    pub fn builder() -> ListQueryLoggingConfigsRequestBuilder {
+        ListQueryLoggingConfigsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListQueryLoggingConfigsRequest`](struct.ListQueryLoggingConfigsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListQueryLoggingConfigsRequestBuilder {
+    hosted_zone_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListQueryLoggingConfigsRequestBuilder {
+    /// Sets the [`hosted_zone_id`](struct.ListQueryLoggingConfigsRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListQueryLoggingConfigsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListQueryLoggingConfigsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListQueryLoggingConfigsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListQueryLoggingConfigsRequest, ::rusoto_core::BuildError> {
+        Ok(ListQueryLoggingConfigsRequest {
+            hosted_zone_id: self.hosted_zone_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListQueryLoggingConfigsResponse {
     /// <p>If a response includes the last of the query logging configurations that are associated with the current AWS account, <code>NextToken</code> doesn't appear in the response.</p> <p>If a response doesn't include the last of the configurations, you can get more configurations by submitting another <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ListQueryLoggingConfigs.html">ListQueryLoggingConfigs</a> request. Get the value of <code>NextToken</code> that Amazon Route 53 returned in the previous response and include it in <code>NextToken</code> in the next request.</p>
@@ -4339,10 +7083,10 @@ pub struct ListQueryLoggingConfigsResponse {
     pub query_logging_configs: Vec<QueryLoggingConfig>,
 }
 
-struct ListQueryLoggingConfigsResponseDeserializer;
+pub(crate) struct ListQueryLoggingConfigsResponseDeserializer;
 impl ListQueryLoggingConfigsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListQueryLoggingConfigsResponse, XmlParseError> {
@@ -4384,7 +7128,71 @@ pub struct ListResourceRecordSetsRequest {
     /// <p>The first name in the lexicographic ordering of resource record sets that you want to list.</p>
     pub start_record_name: Option<String>,
     /// <p>The type of resource record set to begin the record listing from.</p> <p>Valid values for basic resource record sets: <code>A</code> | <code>AAAA</code> | <code>CAA</code> | <code>CNAME</code> | <code>MX</code> | <code>NAPTR</code> | <code>NS</code> | <code>PTR</code> | <code>SOA</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code> </p> <p>Values for weighted, latency, geolocation, and failover resource record sets: <code>A</code> | <code>AAAA</code> | <code>CAA</code> | <code>CNAME</code> | <code>MX</code> | <code>NAPTR</code> | <code>PTR</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code> </p> <p>Values for alias resource record sets: </p> <ul> <li> <p> <b>API Gateway custom regional API or edge-optimized API</b>: A</p> </li> <li> <p> <b>CloudFront distribution</b>: A or AAAA</p> </li> <li> <p> <b>Elastic Beanstalk environment that has a regionalized subdomain</b>: A</p> </li> <li> <p> <b>Elastic Load Balancing load balancer</b>: A | AAAA</p> </li> <li> <p> <b>Amazon S3 bucket</b>: A</p> </li> <li> <p> <b>Amazon VPC interface VPC endpoint</b>: A</p> </li> <li> <p> <b>Another resource record set in this hosted zone:</b> The type of the resource record set that the alias references.</p> </li> </ul> <p>Constraint: Specifying <code>type</code> without specifying <code>name</code> returns an <code>InvalidInput</code> error.</p>
-    pub start_record_type: Option<String>,
+    pub start_record_type: Option<RRType>,
+}
+
+impl ListResourceRecordSetsRequest {
+    /// Returns a builder for `ListResourceRecordSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListResourceRecordSetsRequestBuilder {
+        ListResourceRecordSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourceRecordSetsRequest`](struct.ListResourceRecordSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResourceRecordSetsRequestBuilder {
+    hosted_zone_id: Option<String>,
+    max_items: Option<String>,
+    start_record_identifier: Option<String>,
+    start_record_name: Option<String>,
+    start_record_type: Option<RRType>,
+}
+
+impl ListResourceRecordSetsRequestBuilder {
+    /// Sets the [`hosted_zone_id`](struct.ListResourceRecordSetsRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListResourceRecordSetsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_record_identifier`](struct.ListResourceRecordSetsRequest.html#structfield.start_record_identifier) member.
+    pub fn start_record_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.start_record_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_record_name`](struct.ListResourceRecordSetsRequest.html#structfield.start_record_name) member.
+    pub fn start_record_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.start_record_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_record_type`](struct.ListResourceRecordSetsRequest.html#structfield.start_record_type) member.
+    pub fn start_record_type<T: Into<RRType>>(mut self, value: T) -> Self {
+        self.start_record_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourceRecordSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListResourceRecordSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListResourceRecordSetsRequest {
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+            max_items: self.max_items,
+            start_record_identifier: self.start_record_identifier,
+            start_record_name: self.start_record_name,
+            start_record_type: self.start_record_type,
+        })
+    }
 }
 
 /// <p>A complex type that contains list information for the resource record set.</p>
@@ -4399,15 +7207,15 @@ pub struct ListResourceRecordSetsResponse {
     /// <p>If the results were truncated, the name of the next record in the list.</p> <p>This element is present only if <code>IsTruncated</code> is true. </p>
     pub next_record_name: Option<String>,
     /// <p>If the results were truncated, the type of the next record in the list.</p> <p>This element is present only if <code>IsTruncated</code> is true. </p>
-    pub next_record_type: Option<String>,
+    pub next_record_type: Option<RRType>,
     /// <p>Information about multiple resource record sets.</p>
     pub resource_record_sets: Vec<ResourceRecordSet>,
 }
 
-struct ListResourceRecordSetsResponseDeserializer;
+pub(crate) struct ListResourceRecordSetsResponseDeserializer;
 impl ListResourceRecordSetsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListResourceRecordSetsResponse, XmlParseError> {
@@ -4462,6 +7270,44 @@ pub struct ListReusableDelegationSetsRequest {
     pub max_items: Option<String>,
 }
 
+impl ListReusableDelegationSetsRequest {
+    /// Returns a builder for `ListReusableDelegationSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListReusableDelegationSetsRequestBuilder {
+        ListReusableDelegationSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListReusableDelegationSetsRequest`](struct.ListReusableDelegationSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListReusableDelegationSetsRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<String>,
+}
+
+impl ListReusableDelegationSetsRequestBuilder {
+    /// Sets the [`marker`](struct.ListReusableDelegationSetsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListReusableDelegationSetsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListReusableDelegationSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListReusableDelegationSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListReusableDelegationSetsRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// <p>A complex type that contains information about the reusable delegation sets that are associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListReusableDelegationSetsResponse {
@@ -4477,10 +7323,10 @@ pub struct ListReusableDelegationSetsResponse {
     pub next_marker: Option<String>,
 }
 
-struct ListReusableDelegationSetsResponseDeserializer;
+pub(crate) struct ListReusableDelegationSetsResponseDeserializer;
 impl ListReusableDelegationSetsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListReusableDelegationSetsResponse, XmlParseError> {
@@ -4518,12 +7364,54 @@ impl ListReusableDelegationSetsResponseDeserializer {
     }
 }
 /// <p>A complex type containing information about a request for a list of the tags that are associated with an individual resource.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ListTagsForResourceRequest {
     /// <p>The ID of the resource for which you want to retrieve tags.</p>
     pub resource_id: String,
     /// <p><p>The type of the resource.</p> <ul> <li> <p>The resource type for health checks is <code>healthcheck</code>.</p> </li> <li> <p>The resource type for hosted zones is <code>hostedzone</code>.</p> </li> </ul></p>
-    pub resource_type: String,
+    pub resource_type: TagResourceType,
+}
+
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_id: Option<String>,
+    resource_type: Option<TagResourceType>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_id`](struct.ListTagsForResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.ListTagsForResourceRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<TagResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+        })
+    }
 }
 
 /// <p>A complex type that contains information about the health checks or hosted zones for which you want to list tags.</p>
@@ -4533,10 +7421,10 @@ pub struct ListTagsForResourceResponse {
     pub resource_tag_set: ResourceTagSet,
 }
 
-struct ListTagsForResourceResponseDeserializer;
+pub(crate) struct ListTagsForResourceResponseDeserializer;
 impl ListTagsForResourceResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListTagsForResourceResponse, XmlParseError> {
@@ -4557,12 +7445,54 @@ impl ListTagsForResourceResponseDeserializer {
     }
 }
 /// <p>A complex type that contains information about the health checks or hosted zones for which you want to list tags.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ListTagsForResourcesRequest {
     /// <p>A complex type that contains the ResourceId element for each resource for which you want to get a list of tags.</p>
     pub resource_ids: Vec<String>,
     /// <p><p>The type of the resources.</p> <ul> <li> <p>The resource type for health checks is <code>healthcheck</code>.</p> </li> <li> <p>The resource type for hosted zones is <code>hostedzone</code>.</p> </li> </ul></p>
-    pub resource_type: String,
+    pub resource_type: TagResourceType,
+}
+
+impl ListTagsForResourcesRequest {
+    /// Returns a builder for `ListTagsForResourcesRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourcesRequestBuilder {
+        ListTagsForResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourcesRequest`](struct.ListTagsForResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourcesRequestBuilder {
+    resource_ids: Option<Vec<String>>,
+    resource_type: Option<TagResourceType>,
+}
+
+impl ListTagsForResourcesRequestBuilder {
+    /// Sets the [`resource_ids`](struct.ListTagsForResourcesRequest.html#structfield.resource_ids) member.
+    pub fn resource_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.ListTagsForResourcesRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<TagResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourcesRequest {
+            resource_ids: self
+                .resource_ids
+                .ok_or(::rusoto_core::BuildError::missing("resource_ids"))?,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+        })
+    }
 }
 
 pub struct ListTagsForResourcesRequestSerializer;
@@ -4589,10 +7519,10 @@ pub struct ListTagsForResourcesResponse {
     pub resource_tag_sets: Vec<ResourceTagSet>,
 }
 
-struct ListTagsForResourcesResponseDeserializer;
+pub(crate) struct ListTagsForResourcesResponseDeserializer;
 impl ListTagsForResourcesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListTagsForResourcesResponse, XmlParseError> {
@@ -4624,6 +7554,44 @@ pub struct ListTrafficPoliciesRequest {
     pub traffic_policy_id_marker: Option<String>,
 }
 
+impl ListTrafficPoliciesRequest {
+    /// Returns a builder for `ListTrafficPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListTrafficPoliciesRequestBuilder {
+        ListTrafficPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTrafficPoliciesRequest`](struct.ListTrafficPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTrafficPoliciesRequestBuilder {
+    max_items: Option<String>,
+    traffic_policy_id_marker: Option<String>,
+}
+
+impl ListTrafficPoliciesRequestBuilder {
+    /// Sets the [`max_items`](struct.ListTrafficPoliciesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_id_marker`](struct.ListTrafficPoliciesRequest.html#structfield.traffic_policy_id_marker) member.
+    pub fn traffic_policy_id_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.traffic_policy_id_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTrafficPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTrafficPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListTrafficPoliciesRequest {
+            max_items: self.max_items,
+            traffic_policy_id_marker: self.traffic_policy_id_marker,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListTrafficPoliciesResponse {
@@ -4637,10 +7605,10 @@ pub struct ListTrafficPoliciesResponse {
     pub traffic_policy_summaries: Vec<TrafficPolicySummary>,
 }
 
-struct ListTrafficPoliciesResponseDeserializer;
+pub(crate) struct ListTrafficPoliciesResponseDeserializer;
 impl ListTrafficPoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListTrafficPoliciesResponse, XmlParseError> {
@@ -4687,7 +7655,66 @@ pub struct ListTrafficPolicyInstancesByHostedZoneRequest {
     /// <p>If the value of <code>IsTruncated</code> in the previous response is true, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstances</code> request. For the value of <code>trafficpolicyinstancename</code>, specify the value of <code>TrafficPolicyInstanceNameMarker</code> from the previous response, which is the name of the first traffic policy instance in the next group of traffic policy instances.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
     pub traffic_policy_instance_name_marker: Option<String>,
     /// <p>If the value of <code>IsTruncated</code> in the previous response is true, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstances</code> request. For the value of <code>trafficpolicyinstancetype</code>, specify the value of <code>TrafficPolicyInstanceTypeMarker</code> from the previous response, which is the type of the first traffic policy instance in the next group of traffic policy instances.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
-    pub traffic_policy_instance_type_marker: Option<String>,
+    pub traffic_policy_instance_type_marker: Option<RRType>,
+}
+
+impl ListTrafficPolicyInstancesByHostedZoneRequest {
+    /// Returns a builder for `ListTrafficPolicyInstancesByHostedZoneRequest`, checking its required members when built.
+    pub fn builder() -> ListTrafficPolicyInstancesByHostedZoneRequestBuilder {
+        ListTrafficPolicyInstancesByHostedZoneRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTrafficPolicyInstancesByHostedZoneRequest`](struct.ListTrafficPolicyInstancesByHostedZoneRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTrafficPolicyInstancesByHostedZoneRequestBuilder {
+    hosted_zone_id: Option<String>,
+    max_items: Option<String>,
+    traffic_policy_instance_name_marker: Option<String>,
+    traffic_policy_instance_type_marker: Option<RRType>,
+}
+
+impl ListTrafficPolicyInstancesByHostedZoneRequestBuilder {
+    /// Sets the [`hosted_zone_id`](struct.ListTrafficPolicyInstancesByHostedZoneRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListTrafficPolicyInstancesByHostedZoneRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_instance_name_marker`](struct.ListTrafficPolicyInstancesByHostedZoneRequest.html#structfield.traffic_policy_instance_name_marker) member.
+    pub fn traffic_policy_instance_name_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.traffic_policy_instance_name_marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_instance_type_marker`](struct.ListTrafficPolicyInstancesByHostedZoneRequest.html#structfield.traffic_policy_instance_type_marker) member.
+    pub fn traffic_policy_instance_type_marker<T: Into<RRType>>(mut self, value: T) -> Self {
+        self.traffic_policy_instance_type_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTrafficPolicyInstancesByHostedZoneRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ListTrafficPolicyInstancesByHostedZoneRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ListTrafficPolicyInstancesByHostedZoneRequest {
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+            max_items: self.max_items,
+            traffic_policy_instance_name_marker: self.traffic_policy_instance_name_marker,
+            traffic_policy_instance_type_marker: self.traffic_policy_instance_type_marker,
+        })
+    }
 }
 
 /// <p>A complex type that contains the response information for the request.</p>
@@ -4700,15 +7727,15 @@ pub struct ListTrafficPolicyInstancesByHostedZoneResponse {
     /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceNameMarker</code> is the name of the first traffic policy instance in the next group of traffic policy instances.</p>
     pub traffic_policy_instance_name_marker: Option<String>,
     /// <p>If <code>IsTruncated</code> is true, <code>TrafficPolicyInstanceTypeMarker</code> is the DNS type of the resource record sets that are associated with the first traffic policy instance in the next group of traffic policy instances.</p>
-    pub traffic_policy_instance_type_marker: Option<String>,
+    pub traffic_policy_instance_type_marker: Option<RRType>,
     /// <p>A list that contains one <code>TrafficPolicyInstance</code> element for each traffic policy instance that matches the elements in the request. </p>
     pub traffic_policy_instances: Vec<TrafficPolicyInstance>,
 }
 
-struct ListTrafficPolicyInstancesByHostedZoneResponseDeserializer;
+pub(crate) struct ListTrafficPolicyInstancesByHostedZoneResponseDeserializer;
 impl ListTrafficPolicyInstancesByHostedZoneResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListTrafficPolicyInstancesByHostedZoneResponse, XmlParseError> {
@@ -4765,34 +7792,109 @@ pub struct ListTrafficPolicyInstancesByPolicyRequest {
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstancesByPolicy</code> request.</p> <p>For the value of <code>trafficpolicyinstancename</code>, specify the value of <code>TrafficPolicyInstanceNameMarker</code> from the previous response, which is the name of the first traffic policy instance that Amazon Route 53 will return if you submit another request.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
     pub traffic_policy_instance_name_marker: Option<String>,
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstancesByPolicy</code> request.</p> <p>For the value of <code>trafficpolicyinstancetype</code>, specify the value of <code>TrafficPolicyInstanceTypeMarker</code> from the previous response, which is the name of the first traffic policy instance that Amazon Route 53 will return if you submit another request.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
-    pub traffic_policy_instance_type_marker: Option<String>,
+    pub traffic_policy_instance_type_marker: Option<RRType>,
     /// <p>The version of the traffic policy for which you want to list traffic policy instances. The version must be associated with the traffic policy that is specified by <code>TrafficPolicyId</code>.</p>
     pub traffic_policy_version: i64,
 }
 
-/// <p>A complex type that contains the response information for the request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct ListTrafficPolicyInstancesByPolicyResponse {
-    /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>HostedZoneIdMarker</code> is the ID of the hosted zone of the first traffic policy instance in the next group of traffic policy instances.</p>
-    pub hosted_zone_id_marker: Option<String>,
-    /// <p>A flag that indicates whether there are more traffic policy instances to be listed. If the response was truncated, you can get the next group of traffic policy instances by calling <code>ListTrafficPolicyInstancesByPolicy</code> again and specifying the values of the <code>HostedZoneIdMarker</code>, <code>TrafficPolicyInstanceNameMarker</code>, and <code>TrafficPolicyInstanceTypeMarker</code> elements in the corresponding request parameters.</p>
-    pub is_truncated: bool,
-    /// <p>The value that you specified for the <code>MaxItems</code> parameter in the call to <code>ListTrafficPolicyInstancesByPolicy</code> that produced the current response.</p>
-    pub max_items: String,
-    /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceNameMarker</code> is the name of the first traffic policy instance in the next group of <code>MaxItems</code> traffic policy instances.</p>
-    pub traffic_policy_instance_name_marker: Option<String>,
-    /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceTypeMarker</code> is the DNS type of the resource record sets that are associated with the first traffic policy instance in the next group of <code>MaxItems</code> traffic policy instances.</p>
-    pub traffic_policy_instance_type_marker: Option<String>,
-    /// <p>A list that contains one <code>TrafficPolicyInstance</code> element for each traffic policy instance that matches the elements in the request.</p>
-    pub traffic_policy_instances: Vec<TrafficPolicyInstance>,
+impl ListTrafficPolicyInstancesByPolicyRequest {
+    /// Returns a builder for `ListTrafficPolicyInstancesByPolicyRequest`, checking its required members when built.
+    pub fn builder() -> ListTrafficPolicyInstancesByPolicyRequestBuilder {
+        ListTrafficPolicyInstancesByPolicyRequestBuilder::default()
+    }
 }
 
-struct ListTrafficPolicyInstancesByPolicyResponseDeserializer;
-impl ListTrafficPolicyInstancesByPolicyResponseDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
+/// Builds a [`ListTrafficPolicyInstancesByPolicyRequest`](struct.ListTrafficPolicyInstancesByPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTrafficPolicyInstancesByPolicyRequestBuilder {
+    hosted_zone_id_marker: Option<String>,
+    max_items: Option<String>,
+    traffic_policy_id: Option<String>,
+    traffic_policy_instance_name_marker: Option<String>,
+    traffic_policy_instance_type_marker: Option<RRType>,
+    traffic_policy_version: Option<i64>,
+}
+
+impl ListTrafficPolicyInstancesByPolicyRequestBuilder {
+    /// Sets the [`hosted_zone_id_marker`](struct.ListTrafficPolicyInstancesByPolicyRequest.html#structfield.hosted_zone_id_marker) member.
+    pub fn hosted_zone_id_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id_marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListTrafficPolicyInstancesByPolicyRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_id`](struct.ListTrafficPolicyInstancesByPolicyRequest.html#structfield.traffic_policy_id) member.
+    pub fn traffic_policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.traffic_policy_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_instance_name_marker`](struct.ListTrafficPolicyInstancesByPolicyRequest.html#structfield.traffic_policy_instance_name_marker) member.
+    pub fn traffic_policy_instance_name_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.traffic_policy_instance_name_marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_instance_type_marker`](struct.ListTrafficPolicyInstancesByPolicyRequest.html#structfield.traffic_policy_instance_type_marker) member.
+    pub fn traffic_policy_instance_type_marker<T: Into<RRType>>(mut self, value: T) -> Self {
+        self.traffic_policy_instance_type_marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_version`](struct.ListTrafficPolicyInstancesByPolicyRequest.html#structfield.traffic_policy_version) member.
+    pub fn traffic_policy_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.traffic_policy_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTrafficPolicyInstancesByPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTrafficPolicyInstancesByPolicyRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListTrafficPolicyInstancesByPolicyRequest {
+            hosted_zone_id_marker: self.hosted_zone_id_marker,
+            max_items: self.max_items,
+            traffic_policy_id: self
+                .traffic_policy_id
+                .ok_or(::rusoto_core::BuildError::missing("traffic_policy_id"))?,
+            traffic_policy_instance_name_marker: self.traffic_policy_instance_name_marker,
+            traffic_policy_instance_type_marker: self.traffic_policy_instance_type_marker,
+            traffic_policy_version: self
+                .traffic_policy_version
+                .ok_or(::rusoto_core::BuildError::missing("traffic_policy_version"))?,
+        })
+    }
+}
+
+/// <p>A complex type that contains the response information for the request.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ListTrafficPolicyInstancesByPolicyResponse {
+    /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>HostedZoneIdMarker</code> is the ID of the hosted zone of the first traffic policy instance in the next group of traffic policy instances.</p>
+    pub hosted_zone_id_marker: Option<String>,
+    /// <p>A flag that indicates whether there are more traffic policy instances to be listed. If the response was truncated, you can get the next group of traffic policy instances by calling <code>ListTrafficPolicyInstancesByPolicy</code> again and specifying the values of the <code>HostedZoneIdMarker</code>, <code>TrafficPolicyInstanceNameMarker</code>, and <code>TrafficPolicyInstanceTypeMarker</code> elements in the corresponding request parameters.</p>
+    pub is_truncated: bool,
+    /// <p>The value that you specified for the <code>MaxItems</code> parameter in the call to <code>ListTrafficPolicyInstancesByPolicy</code> that produced the current response.</p>
+    pub max_items: String,
+    /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceNameMarker</code> is the name of the first traffic policy instance in the next group of <code>MaxItems</code> traffic policy instances.</p>
+    pub traffic_policy_instance_name_marker: Option<String>,
+    /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceTypeMarker</code> is the DNS type of the resource record sets that are associated with the first traffic policy instance in the next group of <code>MaxItems</code> traffic policy instances.</p>
+    pub traffic_policy_instance_type_marker: Option<RRType>,
+    /// <p>A list that contains one <code>TrafficPolicyInstance</code> element for each traffic policy instance that matches the elements in the request.</p>
+    pub traffic_policy_instances: Vec<TrafficPolicyInstance>,
+}
+
+pub(crate) struct ListTrafficPolicyInstancesByPolicyResponseDeserializer;
+impl ListTrafficPolicyInstancesByPolicyResponseDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
     ) -> Result<ListTrafficPolicyInstancesByPolicyResponse, XmlParseError> {
         deserialize_elements::<_, ListTrafficPolicyInstancesByPolicyResponse, _>(
             tag_name,
@@ -4851,7 +7953,61 @@ pub struct ListTrafficPolicyInstancesRequest {
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstances</code> request. For the value of <code>trafficpolicyinstancename</code>, specify the value of <code>TrafficPolicyInstanceNameMarker</code> from the previous response, which is the name of the first traffic policy instance in the next group of traffic policy instances.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
     pub traffic_policy_instance_name_marker: Option<String>,
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstances</code> request. For the value of <code>trafficpolicyinstancetype</code>, specify the value of <code>TrafficPolicyInstanceTypeMarker</code> from the previous response, which is the type of the first traffic policy instance in the next group of traffic policy instances.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
-    pub traffic_policy_instance_type_marker: Option<String>,
+    pub traffic_policy_instance_type_marker: Option<RRType>,
+}
+
+impl ListTrafficPolicyInstancesRequest {
+    /// Returns a builder for `ListTrafficPolicyInstancesRequest`, checking its required members when built.
+    pub fn builder() -> ListTrafficPolicyInstancesRequestBuilder {
+        ListTrafficPolicyInstancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTrafficPolicyInstancesRequest`](struct.ListTrafficPolicyInstancesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTrafficPolicyInstancesRequestBuilder {
+    hosted_zone_id_marker: Option<String>,
+    max_items: Option<String>,
+    traffic_policy_instance_name_marker: Option<String>,
+    traffic_policy_instance_type_marker: Option<RRType>,
+}
+
+impl ListTrafficPolicyInstancesRequestBuilder {
+    /// Sets the [`hosted_zone_id_marker`](struct.ListTrafficPolicyInstancesRequest.html#structfield.hosted_zone_id_marker) member.
+    pub fn hosted_zone_id_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id_marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListTrafficPolicyInstancesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_instance_name_marker`](struct.ListTrafficPolicyInstancesRequest.html#structfield.traffic_policy_instance_name_marker) member.
+    pub fn traffic_policy_instance_name_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.traffic_policy_instance_name_marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_instance_type_marker`](struct.ListTrafficPolicyInstancesRequest.html#structfield.traffic_policy_instance_type_marker) member.
+    pub fn traffic_policy_instance_type_marker<T: Into<RRType>>(mut self, value: T) -> Self {
+        self.traffic_policy_instance_type_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTrafficPolicyInstancesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTrafficPolicyInstancesRequest, ::rusoto_core::BuildError> {
+        Ok(ListTrafficPolicyInstancesRequest {
+            hosted_zone_id_marker: self.hosted_zone_id_marker,
+            max_items: self.max_items,
+            traffic_policy_instance_name_marker: self.traffic_policy_instance_name_marker,
+            traffic_policy_instance_type_marker: self.traffic_policy_instance_type_marker,
+        })
+    }
 }
 
 /// <p>A complex type that contains the response information for the request.</p>
@@ -4866,15 +8022,15 @@ pub struct ListTrafficPolicyInstancesResponse {
     /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceNameMarker</code> is the name of the first traffic policy instance that Route 53 will return if you submit another <code>ListTrafficPolicyInstances</code> request. </p>
     pub traffic_policy_instance_name_marker: Option<String>,
     /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>TrafficPolicyInstanceTypeMarker</code> is the DNS type of the resource record sets that are associated with the first traffic policy instance that Amazon Route 53 will return if you submit another <code>ListTrafficPolicyInstances</code> request. </p>
-    pub traffic_policy_instance_type_marker: Option<String>,
+    pub traffic_policy_instance_type_marker: Option<RRType>,
     /// <p>A list that contains one <code>TrafficPolicyInstance</code> element for each traffic policy instance that matches the elements in the request.</p>
     pub traffic_policy_instances: Vec<TrafficPolicyInstance>,
 }
 
-struct ListTrafficPolicyInstancesResponseDeserializer;
+pub(crate) struct ListTrafficPolicyInstancesResponseDeserializer;
 impl ListTrafficPolicyInstancesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListTrafficPolicyInstancesResponse, XmlParseError> {
@@ -4936,6 +8092,52 @@ pub struct ListTrafficPolicyVersionsRequest {
     pub traffic_policy_version_marker: Option<String>,
 }
 
+impl ListTrafficPolicyVersionsRequest {
+    /// Returns a builder for `ListTrafficPolicyVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListTrafficPolicyVersionsRequestBuilder {
+        ListTrafficPolicyVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTrafficPolicyVersionsRequest`](struct.ListTrafficPolicyVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTrafficPolicyVersionsRequestBuilder {
+    id: Option<String>,
+    max_items: Option<String>,
+    traffic_policy_version_marker: Option<String>,
+}
+
+impl ListTrafficPolicyVersionsRequestBuilder {
+    /// Sets the [`id`](struct.ListTrafficPolicyVersionsRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListTrafficPolicyVersionsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_version_marker`](struct.ListTrafficPolicyVersionsRequest.html#structfield.traffic_policy_version_marker) member.
+    pub fn traffic_policy_version_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.traffic_policy_version_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTrafficPolicyVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTrafficPolicyVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTrafficPolicyVersionsRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            max_items: self.max_items,
+            traffic_policy_version_marker: self.traffic_policy_version_marker,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListTrafficPolicyVersionsResponse {
@@ -4949,10 +8151,10 @@ pub struct ListTrafficPolicyVersionsResponse {
     pub traffic_policy_version_marker: String,
 }
 
-struct ListTrafficPolicyVersionsResponseDeserializer;
+pub(crate) struct ListTrafficPolicyVersionsResponseDeserializer;
 impl ListTrafficPolicyVersionsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListTrafficPolicyVersionsResponse, XmlParseError> {
@@ -5000,6 +8202,55 @@ pub struct ListVPCAssociationAuthorizationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListVPCAssociationAuthorizationsRequest {
+    /// Returns a builder for `ListVPCAssociationAuthorizationsRequest`, checking its required members when built.
+    pub fn builder() -> ListVPCAssociationAuthorizationsRequestBuilder {
+        ListVPCAssociationAuthorizationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListVPCAssociationAuthorizationsRequest`](struct.ListVPCAssociationAuthorizationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListVPCAssociationAuthorizationsRequestBuilder {
+    hosted_zone_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListVPCAssociationAuthorizationsRequestBuilder {
+    /// Sets the [`hosted_zone_id`](struct.ListVPCAssociationAuthorizationsRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListVPCAssociationAuthorizationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListVPCAssociationAuthorizationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVPCAssociationAuthorizationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListVPCAssociationAuthorizationsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListVPCAssociationAuthorizationsRequest {
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListVPCAssociationAuthorizationsResponse {
@@ -5011,10 +8262,10 @@ pub struct ListVPCAssociationAuthorizationsResponse {
     pub vp_cs: Vec<VPC>,
 }
 
-struct ListVPCAssociationAuthorizationsResponseDeserializer;
+pub(crate) struct ListVPCAssociationAuthorizationsResponseDeserializer;
 impl ListVPCAssociationAuthorizationsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListVPCAssociationAuthorizationsResponse, XmlParseError> {
@@ -5065,10 +8316,13 @@ impl MaxResultsSerializer {
     }
 }
 
-struct MeasureLatencyDeserializer;
+pub(crate) struct MeasureLatencyDeserializer;
 impl MeasureLatencyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5097,10 +8351,13 @@ impl MeasureLatencySerializer {
     }
 }
 
-struct MessageDeserializer;
+pub(crate) struct MessageDeserializer;
 impl MessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5108,10 +8365,13 @@ impl MessageDeserializer {
         Ok(obj)
     }
 }
-struct MetricNameDeserializer;
+pub(crate) struct MetricNameDeserializer;
 impl MetricNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5119,10 +8379,13 @@ impl MetricNameDeserializer {
         Ok(obj)
     }
 }
-struct NameserverDeserializer;
+pub(crate) struct NameserverDeserializer;
 impl NameserverDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5130,10 +8393,13 @@ impl NameserverDeserializer {
         Ok(obj)
     }
 }
-struct NamespaceDeserializer;
+pub(crate) struct NamespaceDeserializer;
 impl NamespaceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5141,10 +8407,13 @@ impl NamespaceDeserializer {
         Ok(obj)
     }
 }
-struct NonceDeserializer;
+pub(crate) struct NonceDeserializer;
 impl NonceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5173,10 +8442,13 @@ impl NonceSerializer {
     }
 }
 
-struct PageMarkerDeserializer;
+pub(crate) struct PageMarkerDeserializer;
 impl PageMarkerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5205,10 +8477,13 @@ impl PageMarkerSerializer {
     }
 }
 
-struct PageMaxItemsDeserializer;
+pub(crate) struct PageMaxItemsDeserializer;
 impl PageMaxItemsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5237,10 +8512,13 @@ impl PageMaxItemsSerializer {
     }
 }
 
-struct PageTruncatedDeserializer;
+pub(crate) struct PageTruncatedDeserializer;
 impl PageTruncatedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5248,10 +8526,13 @@ impl PageTruncatedDeserializer {
         Ok(obj)
     }
 }
-struct PaginationTokenDeserializer;
+pub(crate) struct PaginationTokenDeserializer;
 impl PaginationTokenDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5280,10 +8561,13 @@ impl PaginationTokenSerializer {
     }
 }
 
-struct PeriodDeserializer;
+pub(crate) struct PeriodDeserializer;
 impl PeriodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5291,10 +8575,13 @@ impl PeriodDeserializer {
         Ok(obj)
     }
 }
-struct PortDeserializer;
+pub(crate) struct PortDeserializer;
 impl PortDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5334,10 +8621,10 @@ pub struct QueryLoggingConfig {
     pub id: String,
 }
 
-struct QueryLoggingConfigDeserializer;
+pub(crate) struct QueryLoggingConfigDeserializer;
 impl QueryLoggingConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<QueryLoggingConfig, XmlParseError> {
@@ -5363,10 +8650,13 @@ impl QueryLoggingConfigDeserializer {
         })
     }
 }
-struct QueryLoggingConfigIdDeserializer;
+pub(crate) struct QueryLoggingConfigIdDeserializer;
 impl QueryLoggingConfigIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5395,10 +8685,10 @@ impl QueryLoggingConfigIdSerializer {
     }
 }
 
-struct QueryLoggingConfigsDeserializer;
+pub(crate) struct QueryLoggingConfigsDeserializer;
 impl QueryLoggingConfigsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<QueryLoggingConfig>, XmlParseError> {
@@ -5415,10 +8705,13 @@ impl QueryLoggingConfigsDeserializer {
         })
     }
 }
-struct RDataDeserializer;
+pub(crate) struct RDataDeserializer;
 impl RDataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5447,12 +8740,15 @@ impl RDataSerializer {
     }
 }
 
-struct RRTypeDeserializer;
+pub(crate) struct RRTypeDeserializer;
 impl RRTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<RRType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5465,7 +8761,7 @@ impl RRTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &RRType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5479,10 +8775,113 @@ impl RRTypeSerializer {
     }
 }
 
-struct RecordDataDeserializer;
+/// <p>The type of resource record set to begin the record listing from.</p> <p>Valid values for basic resource record sets: <code>A</code> | <code>AAAA</code> | <code>CAA</code> | <code>CNAME</code> | <code>MX</code> | <code>NAPTR</code> | <code>NS</code> | <code>PTR</code> | <code>SOA</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code> </p> <p>Values for weighted, latency, geolocation, and failover resource record sets: <code>A</code> | <code>AAAA</code> | <code>CAA</code> | <code>CNAME</code> | <code>MX</code> | <code>NAPTR</code> | <code>PTR</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code> </p> <p>Values for alias resource record sets: </p> <ul> <li> <p> <b>API Gateway custom regional API or edge-optimized API</b>: A</p> </li> <li> <p> <b>CloudFront distribution</b>: A or AAAA</p> </li> <li> <p> <b>Elastic Beanstalk environment that has a regionalized subdomain</b>: A</p> </li> <li> <p> <b>Elastic Load Balancing load balancer</b>: A | AAAA</p> </li> <li> <p> <b>Amazon S3 bucket</b>: A</p> </li> <li> <p> <b>Amazon VPC interface VPC endpoint</b>: A</p> </li> <li> <p> <b>Another resource record set in this hosted zone:</b> The type of the resource record set that the alias references.</p> </li> </ul> <p>Constraint: Specifying <code>type</code> without specifying <code>name</code> returns an <code>InvalidInput</code> error.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RRType {
+    /// <p><code>SOA</code></p>
+    Soa,
+    /// <p><code>A</code></p>
+    A,
+    /// <p><code>TXT</code></p>
+    Txt,
+    /// <p><code>NS</code></p>
+    Ns,
+    /// <p><code>CNAME</code></p>
+    Cname,
+    /// <p><code>MX</code></p>
+    Mx,
+    /// <p><code>NAPTR</code></p>
+    Naptr,
+    /// <p><code>PTR</code></p>
+    Ptr,
+    /// <p><code>SRV</code></p>
+    Srv,
+    /// <p><code>SPF</code></p>
+    Spf,
+    /// <p><code>AAAA</code></p>
+    Aaaa,
+    /// <p><code>CAA</code></p>
+    Caa,
+    /// <p><code>DS</code></p>
+    Ds,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RRType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RRType::Soa => "SOA",
+            RRType::A => "A",
+            RRType::Txt => "TXT",
+            RRType::Ns => "NS",
+            RRType::Cname => "CNAME",
+            RRType::Mx => "MX",
+            RRType::Naptr => "NAPTR",
+            RRType::Ptr => "PTR",
+            RRType::Srv => "SRV",
+            RRType::Spf => "SPF",
+            RRType::Aaaa => "AAAA",
+            RRType::Caa => "CAA",
+            RRType::Ds => "DS",
+            RRType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RRType {
+    fn from(value: &'a str) -> RRType {
+        match value {
+            "SOA" => RRType::Soa,
+            "A" => RRType::A,
+            "TXT" => RRType::Txt,
+            "NS" => RRType::Ns,
+            "CNAME" => RRType::Cname,
+            "MX" => RRType::Mx,
+            "NAPTR" => RRType::Naptr,
+            "PTR" => RRType::Ptr,
+            "SRV" => RRType::Srv,
+            "SPF" => RRType::Spf,
+            "AAAA" => RRType::Aaaa,
+            "CAA" => RRType::Caa,
+            "DS" => RRType::Ds,
+            _ => RRType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RRType {
+    fn from(value: String) -> RRType {
+        RRType::from(value.as_str())
+    }
+}
+
+impl From<RRType> for String {
+    fn from(value: RRType) -> String {
+        match value {
+            RRType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RRType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RRType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct RecordDataDeserializer;
 impl RecordDataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -5499,10 +8898,13 @@ impl RecordDataDeserializer {
         })
     }
 }
-struct RecordDataEntryDeserializer;
+pub(crate) struct RecordDataEntryDeserializer;
 impl RecordDataEntryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5510,10 +8912,13 @@ impl RecordDataEntryDeserializer {
         Ok(obj)
     }
 }
-struct RequestIntervalDeserializer;
+pub(crate) struct RequestIntervalDeserializer;
 impl RequestIntervalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5548,7 +8953,7 @@ impl ResettableElementNameSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ResettableElementName,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5562,13 +8967,80 @@ impl ResettableElementNameSerializer {
     }
 }
 
+/// <p><p>A complex type that contains one <code>ResettableElementName</code> element for each element that you want to reset to the default value. Valid values for <code>ResettableElementName</code> include the following:</p> <ul> <li> <p> <code>ChildHealthChecks</code>: Amazon Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-ChildHealthChecks">ChildHealthChecks</a> to null.</p> </li> <li> <p> <code>FullyQualifiedDomainName</code>: Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_UpdateHealthCheck.html#Route53-UpdateHealthCheck-request-FullyQualifiedDomainName">FullyQualifiedDomainName</a>. to null.</p> </li> <li> <p> <code>Regions</code>: Route 53 resets the <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-Regions">Regions</a> list to the default set of regions. </p> </li> <li> <p> <code>ResourcePath</code>: Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-ResourcePath">ResourcePath</a> to null.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResettableElementName {
+    /// <p><code>FullyQualifiedDomainName</code> - Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_UpdateHealthCheck.html#Route53-UpdateHealthCheck-request-FullyQualifiedDomainName">FullyQualifiedDomainName</a>. to null.</p>
+    FullyQualifiedDomainName,
+    /// <p><code>Regions</code> - Route 53 resets the <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-Regions">Regions</a> list to the default set of regions.</p>
+    Regions,
+    /// <p><code>ResourcePath</code> - Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-ResourcePath">ResourcePath</a> to null.</p>
+    ResourcePath,
+    /// <p><code>ChildHealthChecks</code> - Amazon Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-ChildHealthChecks">ChildHealthChecks</a> to null.</p>
+    ChildHealthChecks,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResettableElementName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResettableElementName::FullyQualifiedDomainName => "FullyQualifiedDomainName",
+            ResettableElementName::Regions => "Regions",
+            ResettableElementName::ResourcePath => "ResourcePath",
+            ResettableElementName::ChildHealthChecks => "ChildHealthChecks",
+            ResettableElementName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResettableElementName {
+    fn from(value: &'a str) -> ResettableElementName {
+        match value {
+            "FullyQualifiedDomainName" => ResettableElementName::FullyQualifiedDomainName,
+            "Regions" => ResettableElementName::Regions,
+            "ResourcePath" => ResettableElementName::ResourcePath,
+            "ChildHealthChecks" => ResettableElementName::ChildHealthChecks,
+            _ => ResettableElementName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResettableElementName {
+    fn from(value: String) -> ResettableElementName {
+        ResettableElementName::from(value.as_str())
+    }
+}
+
+impl From<ResettableElementName> for String {
+    fn from(value: ResettableElementName) -> String {
+        match value {
+            ResettableElementName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResettableElementName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResettableElementName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 pub struct ResettableElementNameListSerializer;
 impl ResettableElementNameListSerializer {
     #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &Vec<String>,
+        obj: &Vec<ResettableElementName>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5582,10 +9054,13 @@ impl ResettableElementNameListSerializer {
     }
 }
 
-struct ResourceDescriptionDeserializer;
+pub(crate) struct ResourceDescriptionDeserializer;
 impl ResourceDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5614,10 +9089,13 @@ impl ResourceDescriptionSerializer {
     }
 }
 
-struct ResourceIdDeserializer;
+pub(crate) struct ResourceIdDeserializer;
 impl ResourceIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5646,10 +9124,13 @@ impl ResourceIdSerializer {
     }
 }
 
-struct ResourcePathDeserializer;
+pub(crate) struct ResourcePathDeserializer;
 impl ResourcePathDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5685,10 +9166,10 @@ pub struct ResourceRecord {
     pub value: String,
 }
 
-struct ResourceRecordDeserializer;
+pub(crate) struct ResourceRecordDeserializer;
 impl ResourceRecordDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourceRecord, XmlParseError> {
@@ -5727,12 +9208,12 @@ impl ResourceRecordSerializer {
 }
 
 /// <p>Information about the resource record set to create or delete.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ResourceRecordSet {
     /// <p><p> <i>Alias resource record sets only:</i> Information about the AWS resource, such as a CloudFront distribution or an Amazon S3 bucket, that you want to route traffic to. </p> <p>If you&#39;re creating resource records sets for a private hosted zone, note the following:</p> <ul> <li> <p>You can&#39;t create an alias resource record set in a private hosted zone to route traffic to a CloudFront distribution.</p> </li> <li> <p>Creating geolocation alias resource record sets or latency alias resource record sets in a private hosted zone is unsupported.</p> </li> <li> <p>For information about creating failover resource record sets in a private hosted zone, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-private-hosted-zones.html">Configuring Failover in a Private Hosted Zone</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </li> </ul></p>
     pub alias_target: Option<AliasTarget>,
     /// <p><p> <i>Failover resource record sets only:</i> To configure failover, you add the <code>Failover</code> element to two resource record sets. For one resource record set, you specify <code>PRIMARY</code> as the value for <code>Failover</code>; for the other resource record set, you specify <code>SECONDARY</code>. In addition, you include the <code>HealthCheckId</code> element and specify the health check that you want Amazon Route 53 to perform for each resource record set.</p> <p>Except where noted, the following failover behaviors assume that you have included the <code>HealthCheckId</code> element in both resource record sets:</p> <ul> <li> <p>When the primary resource record set is healthy, Route 53 responds to DNS queries with the applicable value from the primary resource record set regardless of the health of the secondary resource record set.</p> </li> <li> <p>When the primary resource record set is unhealthy and the secondary resource record set is healthy, Route 53 responds to DNS queries with the applicable value from the secondary resource record set.</p> </li> <li> <p>When the secondary resource record set is unhealthy, Route 53 responds to DNS queries with the applicable value from the primary resource record set regardless of the health of the primary resource record set.</p> </li> <li> <p>If you omit the <code>HealthCheckId</code> element for the secondary resource record set, and if the primary resource record set is unhealthy, Route 53 always responds to DNS queries with the applicable value from the secondary resource record set. This is true regardless of the health of the associated endpoint.</p> </li> </ul> <p>You can&#39;t create non-failover resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements as failover resource record sets.</p> <p>For failover alias resource record sets, you must also include the <code>EvaluateTargetHealth</code> element and set the value to true.</p> <p>For more information about configuring failover for Route 53, see the following topics in the <i>Amazon Route 53 Developer Guide</i>: </p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover.html">Route 53 Health Checks and DNS Failover</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-private-hosted-zones.html">Configuring Failover in a Private Hosted Zone</a> </p> </li> </ul></p>
-    pub failover: Option<String>,
+    pub failover: Option<ResourceRecordSetFailover>,
     /// <p> <i>Geolocation resource record sets only:</i> A complex type that lets you control how Amazon Route 53 responds to DNS queries based on the geographic origin of the query. For example, if you want all queries from Africa to be routed to a web server with an IP address of <code>192.0.2.111</code>, create a resource record set with a <code>Type</code> of <code>A</code> and a <code>ContinentCode</code> of <code>AF</code>.</p> <note> <p>Creating geolocation and geolocation alias resource record sets in private hosted zones is not supported.</p> </note> <p>If you create separate resource record sets for overlapping geographic regions (for example, one resource record set for a continent and one for a country on the same continent), priority goes to the smallest geographic region. This allows you to route most queries for a continent to one resource and to route queries for a country on that continent to a different resource.</p> <p>You can't create two geolocation resource record sets that specify the same geographic location.</p> <p>The value <code>*</code> in the <code>CountryCode</code> element matches all geographic locations that aren't specified in other geolocation resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements.</p> <important> <p>Geolocation works by mapping IP addresses to locations. However, some IP addresses aren't mapped to geographic locations, so even if you create geolocation resource record sets that cover all seven continents, Route 53 will receive some DNS queries from locations that it can't identify. We recommend that you create a resource record set for which the value of <code>CountryCode</code> is <code>*</code>, which handles both queries that come from locations for which you haven't created geolocation resource record sets and queries from IP addresses that aren't mapped to a location. If you don't create a <code>*</code> resource record set, Route 53 returns a "no answer" response for queries from those locations.</p> </important> <p>You can't create non-geolocation resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements as geolocation resource record sets.</p>
     pub geo_location: Option<GeoLocation>,
     /// <p><p>If you want Amazon Route 53 to return this resource record set in response to a DNS query only when the status of a health check is healthy, include the <code>HealthCheckId</code> element and specify the ID of the applicable health check.</p> <p>Route 53 determines whether a resource record set is healthy based on one of the following:</p> <ul> <li> <p>By periodically sending a request to the endpoint that is specified in the health check</p> </li> <li> <p>By aggregating the status of a specified group of health checks (calculated health checks)</p> </li> <li> <p>By determining the current state of a CloudWatch alarm (CloudWatch metric health checks)</p> </li> </ul> <important> <p>Route 53 doesn&#39;t check the health of the endpoint that is specified in the resource record set, for example, the endpoint specified by the IP address in the <code>Value</code> element. When you add a <code>HealthCheckId</code> element to a resource record set, Route 53 checks the health of the endpoint that you specified in the health check. </p> </important> <p>For more information, see the following topics in the <i>Amazon Route 53 Developer Guide</i>:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-determining-health-of-endpoints.html">How Amazon Route 53 Determines Whether an Endpoint Is Healthy</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover.html">Route 53 Health Checks and DNS Failover</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-private-hosted-zones.html">Configuring Failover in a Private Hosted Zone</a> </p> </li> </ul> <p> <b>When to Specify HealthCheckId</b> </p> <p>Specifying a value for <code>HealthCheckId</code> is useful only when Route 53 is choosing between two or more resource record sets to respond to a DNS query, and you want Route 53 to base the choice in part on the status of a health check. Configuring health checks makes sense only in the following configurations:</p> <ul> <li> <p> <b>Non-alias resource record sets</b>: You&#39;re checking the health of a group of non-alias resource record sets that have the same routing policy, name, and type (such as multiple weighted records named www.example.com with a type of A) and you specify health check IDs for all the resource record sets. </p> <p>If the health check status for a resource record set is healthy, Route 53 includes the record among the records that it responds to DNS queries with.</p> <p>If the health check status for a resource record set is unhealthy, Route 53 stops responding to DNS queries using the value for that resource record set.</p> <p>If the health check status for all resource record sets in the group is unhealthy, Route 53 considers all resource record sets in the group healthy and responds to DNS queries accordingly. </p> </li> <li> <p> <b>Alias resource record sets</b>: You specify the following settings:</p> <ul> <li> <p>You set <code>EvaluateTargetHealth</code> to true for an alias resource record set in a group of resource record sets that have the same routing policy, name, and type (such as multiple weighted records named www.example.com with a type of A). </p> </li> <li> <p>You configure the alias resource record set to route traffic to a non-alias resource record set in the same hosted zone.</p> </li> <li> <p>You specify a health check ID for the non-alias resource record set. </p> </li> </ul> <p>If the health check status is healthy, Route 53 considers the alias resource record set to be healthy and includes the alias record among the records that it responds to DNS queries with.</p> <p>If the health check status is unhealthy, Route 53 stops responding to DNS queries using the alias resource record set.</p> <note> <p>The alias resource record set can also route traffic to a <i>group</i> of non-alias resource record sets that have the same routing policy, name, and type. In that configuration, associate health checks with all of the resource record sets in the group of non-alias resource record sets.</p> </note> </li> </ul> <p> <b>Geolocation Routing</b> </p> <p>For geolocation resource record sets, if an endpoint is unhealthy, Route 53 looks for a resource record set for the larger, associated geographic region. For example, suppose you have resource record sets for a state in the United States, for the entire United States, for North America, and a resource record set that has <code><em></code> for <code>CountryCode</code> is <code></em></code>, which applies to all locations. If the endpoint for the state resource record set is unhealthy, Route 53 checks for healthy resource record sets in the following order until it finds a resource record set for which the endpoint is healthy:</p> <ul> <li> <p>The United States</p> </li> <li> <p>North America</p> </li> <li> <p>The default resource record set</p> </li> </ul> <p> <b>Specifying the Health Check Endpoint by Domain Name</b> </p> <p>If your health checks specify the endpoint only by domain name, we recommend that you create a separate health check for each endpoint. For example, create a health check for each <code>HTTP</code> server that is serving content for <code>www.example.com</code>. For the value of <code>FullyQualifiedDomainName</code>, specify the domain name of the server (such as <code>us-east-2-www.example.com</code>), not the name of the resource record sets (<code>www.example.com</code>).</p> <important> <p>Health check results will be unpredictable if you do the following:</p> <ul> <li> <p>Create a health check that has the same value for <code>FullyQualifiedDomainName</code> as the name of a resource record set.</p> </li> <li> <p>Associate that health check with the resource record set.</p> </li> </ul> </important></p>
@@ -5742,7 +9223,7 @@ pub struct ResourceRecordSet {
     /// <p>For <code>ChangeResourceRecordSets</code> requests, the name of the record that you want to create, update, or delete. For <code>ListResourceRecordSets</code> responses, the name of a record in the specified hosted zone.</p> <p> <b>ChangeResourceRecordSets Only</b> </p> <p>Enter a fully qualified domain name, for example, <code>www.example.com</code>. You can optionally include a trailing dot. If you omit the trailing dot, Amazon Route 53 assumes that the domain name that you specify is fully qualified. This means that Route 53 treats <code>www.example.com</code> (without a trailing dot) and <code>www.example.com.</code> (with a trailing dot) as identical.</p> <p>For information about how to specify characters other than <code>a-z</code>, <code>0-9</code>, and <code>-</code> (hyphen) and how to specify internationalized domain names, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DomainNameFormat.html">DNS Domain Name Format</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>You can use the asterisk (*) wildcard to replace the leftmost label in a domain name, for example, <code>*.example.com</code>. Note the following:</p> <ul> <li> <p>The * must replace the entire label. For example, you can't specify <code>*prod.example.com</code> or <code>prod*.example.com</code>.</p> </li> <li> <p>The * can't replace any of the middle labels, for example, marketing.*.example.com.</p> </li> <li> <p>If you include * in any position other than the leftmost label in a domain name, DNS treats it as an * character (ASCII 42), not as a wildcard.</p> <important> <p>You can't use the * wildcard for resource records sets that have a type of NS.</p> </important> </li> </ul> <p>You can use the * wildcard as the leftmost label in a domain name, for example, <code>*.example.com</code>. You can't use an * for one of the middle labels, for example, <code>marketing.*.example.com</code>. In addition, the * must replace the entire label; for example, you can't specify <code>prod*.example.com</code>.</p>
     pub name: String,
     /// <p><p> <i>Latency-based resource record sets only:</i> The Amazon EC2 Region where you created the resource that this resource record set refers to. The resource typically is an AWS resource, such as an EC2 instance or an ELB load balancer, and is referred to by an IP address or a DNS domain name, depending on the record type.</p> <note> <p>Creating latency and latency alias resource record sets in private hosted zones is not supported.</p> </note> <p>When Amazon Route 53 receives a DNS query for a domain name and type for which you have created latency resource record sets, Route 53 selects the latency resource record set that has the lowest latency between the end user and the associated Amazon EC2 Region. Route 53 then returns the value that is associated with the selected resource record set.</p> <p>Note the following:</p> <ul> <li> <p>You can only specify one <code>ResourceRecord</code> per latency resource record set.</p> </li> <li> <p>You can only create one latency resource record set for each Amazon EC2 Region.</p> </li> <li> <p>You aren&#39;t required to create latency resource record sets for all Amazon EC2 Regions. Route 53 will choose the region with the best latency from among the regions that you create latency resource record sets for.</p> </li> <li> <p>You can&#39;t create non-latency resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements as latency resource record sets.</p> </li> </ul></p>
-    pub region: Option<String>,
+    pub region: Option<ResourceRecordSetRegion>,
     /// <p><p>Information about the resource records to act upon.</p> <note> <p>If you&#39;re creating an alias resource record set, omit <code>ResourceRecords</code>.</p> </note></p>
     pub resource_records: Option<Vec<ResourceRecord>>,
     /// <p> <i>Resource record sets that have a routing policy other than simple:</i> An identifier that differentiates among multiple resource record sets that have the same combination of name and type, such as multiple weighted resource record sets named acme.example.com that have a type of A. In a group of resource record sets that have the same name and type, the value of <code>SetIdentifier</code> must be unique for each resource record set. </p> <p>For information about routing policies, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/routing-policy.html">Choosing a Routing Policy</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
@@ -5752,88 +9233,107 @@ pub struct ResourceRecordSet {
     /// <p><p>When you create a traffic policy instance, Amazon Route 53 automatically creates a resource record set. <code>TrafficPolicyInstanceId</code> is the ID of the traffic policy instance that Route 53 created this resource record set for.</p> <important> <p>To delete the resource record set that is associated with a traffic policy instance, use <code>DeleteTrafficPolicyInstance</code>. Route 53 will delete the resource record set automatically. If you delete the resource record set by using <code>ChangeResourceRecordSets</code>, Route 53 doesn&#39;t automatically delete the traffic policy instance, and you&#39;ll continue to be charged for it even though it&#39;s no longer in use. </p> </important></p>
     pub traffic_policy_instance_id: Option<String>,
     /// <p><p>The DNS record type. For information about different record types and how data is encoded for them, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/ResourceRecordTypes.html">Supported DNS Resource Record Types</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>Valid values for basic resource record sets: <code>A</code> | <code>AAAA</code> | <code>CAA</code> | <code>CNAME</code> | <code>MX</code> | <code>NAPTR</code> | <code>NS</code> | <code>PTR</code> | <code>SOA</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code> </p> <p>Values for weighted, latency, geolocation, and failover resource record sets: <code>A</code> | <code>AAAA</code> | <code>CAA</code> | <code>CNAME</code> | <code>MX</code> | <code>NAPTR</code> | <code>PTR</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code>. When creating a group of weighted, latency, geolocation, or failover resource record sets, specify the same value for all of the resource record sets in the group.</p> <p>Valid values for multivalue answer resource record sets: <code>A</code> | <code>AAAA</code> | <code>MX</code> | <code>NAPTR</code> | <code>PTR</code> | <code>SPF</code> | <code>SRV</code> | <code>TXT</code> </p> <note> <p>SPF records were formerly used to verify the identity of the sender of email messages. However, we no longer recommend that you create resource record sets for which the value of <code>Type</code> is <code>SPF</code>. RFC 7208, <i>Sender Policy Framework (SPF) for Authorizing Use of Domains in Email, Version 1</i>, has been updated to say, &quot;...[I]ts existence and mechanism defined in [RFC4408] have led to some interoperability issues. Accordingly, its use is no longer appropriate for SPF version 1; implementations are not to use it.&quot; In RFC 7208, see section 14.1, <a href="http://tools.ietf.org/html/rfc7208#section-14.1">The SPF DNS Record Type</a>.</p> </note> <p>Values for alias resource record sets:</p> <ul> <li> <p> <b>Amazon API Gateway custom regional APIs and edge-optimized APIs:</b> <code>A</code> </p> </li> <li> <p> <b>CloudFront distributions:</b> <code>A</code> </p> <p>If IPv6 is enabled for the distribution, create two resource record sets to route traffic to your distribution, one with a value of <code>A</code> and one with a value of <code>AAAA</code>. </p> </li> <li> <p> <b>AWS Elastic Beanstalk environment that has a regionalized subdomain</b>: <code>A</code> </p> </li> <li> <p> <b>ELB load balancers:</b> <code>A</code> | <code>AAAA</code> </p> </li> <li> <p> <b>Amazon S3 buckets:</b> <code>A</code> </p> </li> <li> <p> <b>Amazon Virtual Private Cloud interface VPC endpoints</b> <code>A</code> </p> </li> <li> <p> <b>Another resource record set in this hosted zone:</b> Specify the type of the resource record set that you&#39;re creating the alias for. All values are supported except <code>NS</code> and <code>SOA</code>.</p> <note> <p>If you&#39;re creating an alias record that has the same name as the hosted zone (known as the zone apex), you can&#39;t route traffic to a record for which the value of <code>Type</code> is <code>CNAME</code>. This is because the alias record must have the same type as the record you&#39;re routing traffic to, and creating a CNAME record for the zone apex isn&#39;t supported even for an alias record.</p> </note> </li> </ul></p>
-    pub type_: String,
+    pub type_: RRType,
     /// <p><p> <i>Weighted resource record sets only:</i> Among resource record sets that have the same combination of DNS name and type, a value that determines the proportion of DNS queries that Amazon Route 53 responds to using the current resource record set. Route 53 calculates the sum of the weights for the resource record sets that have the same combination of DNS name and type. Route 53 then responds to queries based on the ratio of a resource&#39;s weight to the total. Note the following:</p> <ul> <li> <p>You must specify a value for the <code>Weight</code> element for every weighted resource record set.</p> </li> <li> <p>You can only specify one <code>ResourceRecord</code> per weighted resource record set.</p> </li> <li> <p>You can&#39;t create latency, failover, or geolocation resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements as weighted resource record sets.</p> </li> <li> <p>You can create a maximum of 100 weighted resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements.</p> </li> <li> <p>For weighted (but not weighted alias) resource record sets, if you set <code>Weight</code> to <code>0</code> for a resource record set, Route 53 never responds to queries with the applicable value for that resource record set. However, if you set <code>Weight</code> to <code>0</code> for all resource record sets that have the same combination of DNS name and type, traffic is routed to all resources with equal probability.</p> <p>The effect of setting <code>Weight</code> to <code>0</code> is different when you associate health checks with weighted resource record sets. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-configuring-options.html">Options for Configuring Route 53 Active-Active and Active-Passive Failover</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </li> </ul></p>
     pub weight: Option<i64>,
 }
 
-struct ResourceRecordSetDeserializer;
+pub(crate) struct ResourceRecordSetDeserializer;
 impl ResourceRecordSetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourceRecordSet, XmlParseError> {
-        deserialize_elements::<_, ResourceRecordSet, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "AliasTarget" => {
-                    obj.alias_target =
-                        Some(AliasTargetDeserializer::deserialize("AliasTarget", stack)?);
-                }
-                "Failover" => {
-                    obj.failover = Some(ResourceRecordSetFailoverDeserializer::deserialize(
-                        "Failover", stack,
-                    )?);
-                }
-                "GeoLocation" => {
-                    obj.geo_location =
-                        Some(GeoLocationDeserializer::deserialize("GeoLocation", stack)?);
-                }
-                "HealthCheckId" => {
-                    obj.health_check_id = Some(HealthCheckIdDeserializer::deserialize(
-                        "HealthCheckId",
-                        stack,
-                    )?);
-                }
-                "MultiValueAnswer" => {
-                    obj.multi_value_answer =
-                        Some(ResourceRecordSetMultiValueAnswerDeserializer::deserialize(
-                            "MultiValueAnswer",
-                            stack,
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            ResourceRecordSet {
+                alias_target: Default::default(),
+                failover: Default::default(),
+                geo_location: Default::default(),
+                health_check_id: Default::default(),
+                multi_value_answer: Default::default(),
+                name: Default::default(),
+                region: Default::default(),
+                resource_records: Default::default(),
+                set_identifier: Default::default(),
+                ttl: Default::default(),
+                traffic_policy_instance_id: Default::default(),
+                type_: RRType::Unknown(String::new()),
+                weight: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "AliasTarget" => {
+                        obj.alias_target =
+                            Some(AliasTargetDeserializer::deserialize("AliasTarget", stack)?);
+                    }
+                    "Failover" => {
+                        obj.failover = Some(ResourceRecordSetFailoverDeserializer::deserialize(
+                            "Failover", stack,
                         )?);
-                }
-                "Name" => {
-                    obj.name = DNSNameDeserializer::deserialize("Name", stack)?;
-                }
-                "Region" => {
-                    obj.region = Some(ResourceRecordSetRegionDeserializer::deserialize(
-                        "Region", stack,
-                    )?);
-                }
-                "ResourceRecords" => {
-                    obj.resource_records.get_or_insert(vec![]).extend(
-                        ResourceRecordsDeserializer::deserialize("ResourceRecords", stack)?,
-                    );
-                }
-                "SetIdentifier" => {
-                    obj.set_identifier =
-                        Some(ResourceRecordSetIdentifierDeserializer::deserialize(
-                            "SetIdentifier",
+                    }
+                    "GeoLocation" => {
+                        obj.geo_location =
+                            Some(GeoLocationDeserializer::deserialize("GeoLocation", stack)?);
+                    }
+                    "HealthCheckId" => {
+                        obj.health_check_id = Some(HealthCheckIdDeserializer::deserialize(
+                            "HealthCheckId",
                             stack,
                         )?);
-                }
-                "TTL" => {
-                    obj.ttl = Some(TTLDeserializer::deserialize("TTL", stack)?);
-                }
-                "TrafficPolicyInstanceId" => {
-                    obj.traffic_policy_instance_id =
-                        Some(TrafficPolicyInstanceIdDeserializer::deserialize(
-                            "TrafficPolicyInstanceId",
-                            stack,
+                    }
+                    "MultiValueAnswer" => {
+                        obj.multi_value_answer =
+                            Some(ResourceRecordSetMultiValueAnswerDeserializer::deserialize(
+                                "MultiValueAnswer",
+                                stack,
+                            )?);
+                    }
+                    "Name" => {
+                        obj.name = DNSNameDeserializer::deserialize("Name", stack)?;
+                    }
+                    "Region" => {
+                        obj.region = Some(ResourceRecordSetRegionDeserializer::deserialize(
+                            "Region", stack,
                         )?);
+                    }
+                    "ResourceRecords" => {
+                        obj.resource_records.get_or_insert(vec![]).extend(
+                            ResourceRecordsDeserializer::deserialize("ResourceRecords", stack)?,
+                        );
+                    }
+                    "SetIdentifier" => {
+                        obj.set_identifier =
+                            Some(ResourceRecordSetIdentifierDeserializer::deserialize(
+                                "SetIdentifier",
+                                stack,
+                            )?);
+                    }
+                    "TTL" => {
+                        obj.ttl = Some(TTLDeserializer::deserialize("TTL", stack)?);
+                    }
+                    "TrafficPolicyInstanceId" => {
+                        obj.traffic_policy_instance_id =
+                            Some(TrafficPolicyInstanceIdDeserializer::deserialize(
+                                "TrafficPolicyInstanceId",
+                                stack,
+                            )?);
+                    }
+                    "Type" => {
+                        obj.type_ = RRTypeDeserializer::deserialize("Type", stack)?;
+                    }
+                    "Weight" => {
+                        obj.weight = Some(ResourceRecordSetWeightDeserializer::deserialize(
+                            "Weight", stack,
+                        )?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                "Type" => {
-                    obj.type_ = RRTypeDeserializer::deserialize("Type", stack)?;
-                }
-                "Weight" => {
-                    obj.weight = Some(ResourceRecordSetWeightDeserializer::deserialize(
-                        "Weight", stack,
-                    )?);
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -5940,12 +9440,15 @@ impl ResourceRecordSetSerializer {
     }
 }
 
-struct ResourceRecordSetFailoverDeserializer;
+pub(crate) struct ResourceRecordSetFailoverDeserializer;
 impl ResourceRecordSetFailoverDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ResourceRecordSetFailover, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5958,7 +9461,7 @@ impl ResourceRecordSetFailoverSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ResourceRecordSetFailover,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5972,21 +9475,83 @@ impl ResourceRecordSetFailoverSerializer {
     }
 }
 
-struct ResourceRecordSetIdentifierDeserializer;
-impl ResourceRecordSetIdentifierDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+/// <p><p> <i>Failover resource record sets only:</i> To configure failover, you add the <code>Failover</code> element to two resource record sets. For one resource record set, you specify <code>PRIMARY</code> as the value for <code>Failover</code>; for the other resource record set, you specify <code>SECONDARY</code>. In addition, you include the <code>HealthCheckId</code> element and specify the health check that you want Amazon Route 53 to perform for each resource record set.</p> <p>Except where noted, the following failover behaviors assume that you have included the <code>HealthCheckId</code> element in both resource record sets:</p> <ul> <li> <p>When the primary resource record set is healthy, Route 53 responds to DNS queries with the applicable value from the primary resource record set regardless of the health of the secondary resource record set.</p> </li> <li> <p>When the primary resource record set is unhealthy and the secondary resource record set is healthy, Route 53 responds to DNS queries with the applicable value from the secondary resource record set.</p> </li> <li> <p>When the secondary resource record set is unhealthy, Route 53 responds to DNS queries with the applicable value from the primary resource record set regardless of the health of the primary resource record set.</p> </li> <li> <p>If you omit the <code>HealthCheckId</code> element for the secondary resource record set, and if the primary resource record set is unhealthy, Route 53 always responds to DNS queries with the applicable value from the secondary resource record set. This is true regardless of the health of the associated endpoint.</p> </li> </ul> <p>You can&#39;t create non-failover resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements as failover resource record sets.</p> <p>For failover alias resource record sets, you must also include the <code>EvaluateTargetHealth</code> element and set the value to true.</p> <p>For more information about configuring failover for Route 53, see the following topics in the <i>Amazon Route 53 Developer Guide</i>: </p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover.html">Route 53 Health Checks and DNS Failover</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-private-hosted-zones.html">Configuring Failover in a Private Hosted Zone</a> </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceRecordSetFailover {
+    /// <p><code>PRIMARY</code></p>
+    Primary,
+    /// <p><code>SECONDARY</code></p>
+    Secondary,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
 
-        Ok(obj)
+impl ResourceRecordSetFailover {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceRecordSetFailover::Primary => "PRIMARY",
+            ResourceRecordSetFailover::Secondary => "SECONDARY",
+            ResourceRecordSetFailover::Unknown(ref value) => value,
+        }
     }
 }
 
-pub struct ResourceRecordSetIdentifierSerializer;
-impl ResourceRecordSetIdentifierSerializer {
-    #[allow(unused_variables, warnings)]
+impl<'a> From<&'a str> for ResourceRecordSetFailover {
+    fn from(value: &'a str) -> ResourceRecordSetFailover {
+        match value {
+            "PRIMARY" => ResourceRecordSetFailover::Primary,
+            "SECONDARY" => ResourceRecordSetFailover::Secondary,
+            _ => ResourceRecordSetFailover::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceRecordSetFailover {
+    fn from(value: String) -> ResourceRecordSetFailover {
+        ResourceRecordSetFailover::from(value.as_str())
+    }
+}
+
+impl From<ResourceRecordSetFailover> for String {
+    fn from(value: ResourceRecordSetFailover) -> String {
+        match value {
+            ResourceRecordSetFailover::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceRecordSetFailover {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceRecordSetFailover {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ResourceRecordSetIdentifierDeserializer;
+impl ResourceRecordSetIdentifierDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+
+pub struct ResourceRecordSetIdentifierSerializer;
+impl ResourceRecordSetIdentifierSerializer {
+    #[allow(unused_variables, warnings)]
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
@@ -6004,10 +9569,13 @@ impl ResourceRecordSetIdentifierSerializer {
     }
 }
 
-struct ResourceRecordSetMultiValueAnswerDeserializer;
+pub(crate) struct ResourceRecordSetMultiValueAnswerDeserializer;
 impl ResourceRecordSetMultiValueAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6036,12 +9604,15 @@ impl ResourceRecordSetMultiValueAnswerSerializer {
     }
 }
 
-struct ResourceRecordSetRegionDeserializer;
+pub(crate) struct ResourceRecordSetRegionDeserializer;
 impl ResourceRecordSetRegionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ResourceRecordSetRegion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6054,7 +9625,7 @@ impl ResourceRecordSetRegionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ResourceRecordSetRegion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6068,10 +9639,188 @@ impl ResourceRecordSetRegionSerializer {
     }
 }
 
-struct ResourceRecordSetWeightDeserializer;
+/// <p><p> <i>Latency-based resource record sets only:</i> The Amazon EC2 Region where you created the resource that this resource record set refers to. The resource typically is an AWS resource, such as an EC2 instance or an ELB load balancer, and is referred to by an IP address or a DNS domain name, depending on the record type.</p> <note> <p>Creating latency and latency alias resource record sets in private hosted zones is not supported.</p> </note> <p>When Amazon Route 53 receives a DNS query for a domain name and type for which you have created latency resource record sets, Route 53 selects the latency resource record set that has the lowest latency between the end user and the associated Amazon EC2 Region. Route 53 then returns the value that is associated with the selected resource record set.</p> <p>Note the following:</p> <ul> <li> <p>You can only specify one <code>ResourceRecord</code> per latency resource record set.</p> </li> <li> <p>You can only create one latency resource record set for each Amazon EC2 Region.</p> </li> <li> <p>You aren&#39;t required to create latency resource record sets for all Amazon EC2 Regions. Route 53 will choose the region with the best latency from among the regions that you create latency resource record sets for.</p> </li> <li> <p>You can&#39;t create non-latency resource record sets that have the same values for the <code>Name</code> and <code>Type</code> elements as latency resource record sets.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceRecordSetRegion {
+    /// <p><code>us-east-1</code></p>
+    UsEast1,
+    /// <p><code>us-east-2</code></p>
+    UsEast2,
+    /// <p><code>us-west-1</code></p>
+    UsWest1,
+    /// <p><code>us-west-2</code></p>
+    UsWest2,
+    /// <p><code>ca-central-1</code></p>
+    CaCentral1,
+    /// <p><code>eu-west-1</code></p>
+    EuWest1,
+    /// <p><code>eu-west-2</code></p>
+    EuWest2,
+    /// <p><code>eu-west-3</code></p>
+    EuWest3,
+    /// <p><code>eu-central-1</code></p>
+    EuCentral1,
+    /// <p><code>eu-central-2</code></p>
+    EuCentral2,
+    /// <p><code>ap-southeast-1</code></p>
+    ApSoutheast1,
+    /// <p><code>ap-southeast-2</code></p>
+    ApSoutheast2,
+    /// <p><code>ap-southeast-3</code></p>
+    ApSoutheast3,
+    /// <p><code>ap-northeast-1</code></p>
+    ApNortheast1,
+    /// <p><code>ap-northeast-2</code></p>
+    ApNortheast2,
+    /// <p><code>ap-northeast-3</code></p>
+    ApNortheast3,
+    /// <p><code>eu-north-1</code></p>
+    EuNorth1,
+    /// <p><code>sa-east-1</code></p>
+    SaEast1,
+    /// <p><code>cn-north-1</code></p>
+    CnNorth1,
+    /// <p><code>cn-northwest-1</code></p>
+    CnNorthwest1,
+    /// <p><code>ap-east-1</code></p>
+    ApEast1,
+    /// <p><code>me-south-1</code></p>
+    MeSouth1,
+    /// <p><code>me-central-1</code></p>
+    MeCentral1,
+    /// <p><code>ap-south-1</code></p>
+    ApSouth1,
+    /// <p><code>ap-south-2</code></p>
+    ApSouth2,
+    /// <p><code>af-south-1</code></p>
+    AfSouth1,
+    /// <p><code>eu-south-1</code></p>
+    EuSouth1,
+    /// <p><code>eu-south-2</code></p>
+    EuSouth2,
+    /// <p><code>ap-southeast-4</code></p>
+    ApSoutheast4,
+    /// <p><code>il-central-1</code></p>
+    IlCentral1,
+    /// <p><code>ca-west-1</code></p>
+    CaWest1,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceRecordSetRegion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceRecordSetRegion::UsEast1 => "us-east-1",
+            ResourceRecordSetRegion::UsEast2 => "us-east-2",
+            ResourceRecordSetRegion::UsWest1 => "us-west-1",
+            ResourceRecordSetRegion::UsWest2 => "us-west-2",
+            ResourceRecordSetRegion::CaCentral1 => "ca-central-1",
+            ResourceRecordSetRegion::EuWest1 => "eu-west-1",
+            ResourceRecordSetRegion::EuWest2 => "eu-west-2",
+            ResourceRecordSetRegion::EuWest3 => "eu-west-3",
+            ResourceRecordSetRegion::EuCentral1 => "eu-central-1",
+            ResourceRecordSetRegion::EuCentral2 => "eu-central-2",
+            ResourceRecordSetRegion::ApSoutheast1 => "ap-southeast-1",
+            ResourceRecordSetRegion::ApSoutheast2 => "ap-southeast-2",
+            ResourceRecordSetRegion::ApSoutheast3 => "ap-southeast-3",
+            ResourceRecordSetRegion::ApNortheast1 => "ap-northeast-1",
+            ResourceRecordSetRegion::ApNortheast2 => "ap-northeast-2",
+            ResourceRecordSetRegion::ApNortheast3 => "ap-northeast-3",
+            ResourceRecordSetRegion::EuNorth1 => "eu-north-1",
+            ResourceRecordSetRegion::SaEast1 => "sa-east-1",
+            ResourceRecordSetRegion::CnNorth1 => "cn-north-1",
+            ResourceRecordSetRegion::CnNorthwest1 => "cn-northwest-1",
+            ResourceRecordSetRegion::ApEast1 => "ap-east-1",
+            ResourceRecordSetRegion::MeSouth1 => "me-south-1",
+            ResourceRecordSetRegion::MeCentral1 => "me-central-1",
+            ResourceRecordSetRegion::ApSouth1 => "ap-south-1",
+            ResourceRecordSetRegion::ApSouth2 => "ap-south-2",
+            ResourceRecordSetRegion::AfSouth1 => "af-south-1",
+            ResourceRecordSetRegion::EuSouth1 => "eu-south-1",
+            ResourceRecordSetRegion::EuSouth2 => "eu-south-2",
+            ResourceRecordSetRegion::ApSoutheast4 => "ap-southeast-4",
+            ResourceRecordSetRegion::IlCentral1 => "il-central-1",
+            ResourceRecordSetRegion::CaWest1 => "ca-west-1",
+            ResourceRecordSetRegion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceRecordSetRegion {
+    fn from(value: &'a str) -> ResourceRecordSetRegion {
+        match value {
+            "us-east-1" => ResourceRecordSetRegion::UsEast1,
+            "us-east-2" => ResourceRecordSetRegion::UsEast2,
+            "us-west-1" => ResourceRecordSetRegion::UsWest1,
+            "us-west-2" => ResourceRecordSetRegion::UsWest2,
+            "ca-central-1" => ResourceRecordSetRegion::CaCentral1,
+            "eu-west-1" => ResourceRecordSetRegion::EuWest1,
+            "eu-west-2" => ResourceRecordSetRegion::EuWest2,
+            "eu-west-3" => ResourceRecordSetRegion::EuWest3,
+            "eu-central-1" => ResourceRecordSetRegion::EuCentral1,
+            "eu-central-2" => ResourceRecordSetRegion::EuCentral2,
+            "ap-southeast-1" => ResourceRecordSetRegion::ApSoutheast1,
+            "ap-southeast-2" => ResourceRecordSetRegion::ApSoutheast2,
+            "ap-southeast-3" => ResourceRecordSetRegion::ApSoutheast3,
+            "ap-northeast-1" => ResourceRecordSetRegion::ApNortheast1,
+            "ap-northeast-2" => ResourceRecordSetRegion::ApNortheast2,
+            "ap-northeast-3" => ResourceRecordSetRegion::ApNortheast3,
+            "eu-north-1" => ResourceRecordSetRegion::EuNorth1,
+            "sa-east-1" => ResourceRecordSetRegion::SaEast1,
+            "cn-north-1" => ResourceRecordSetRegion::CnNorth1,
+            "cn-northwest-1" => ResourceRecordSetRegion::CnNorthwest1,
+            "ap-east-1" => ResourceRecordSetRegion::ApEast1,
+            "me-south-1" => ResourceRecordSetRegion::MeSouth1,
+            "me-central-1" => ResourceRecordSetRegion::MeCentral1,
+            "ap-south-1" => ResourceRecordSetRegion::ApSouth1,
+            "ap-south-2" => ResourceRecordSetRegion::ApSouth2,
+            "af-south-1" => ResourceRecordSetRegion::AfSouth1,
+            "eu-south-1" => ResourceRecordSetRegion::EuSouth1,
+            "eu-south-2" => ResourceRecordSetRegion::EuSouth2,
+            "ap-southeast-4" => ResourceRecordSetRegion::ApSoutheast4,
+            "il-central-1" => ResourceRecordSetRegion::IlCentral1,
+            "ca-west-1" => ResourceRecordSetRegion::CaWest1,
+            _ => ResourceRecordSetRegion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceRecordSetRegion {
+    fn from(value: String) -> ResourceRecordSetRegion {
+        ResourceRecordSetRegion::from(value.as_str())
+    }
+}
+
+impl From<ResourceRecordSetRegion> for String {
+    fn from(value: ResourceRecordSetRegion) -> String {
+        match value {
+            ResourceRecordSetRegion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceRecordSetRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceRecordSetRegion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ResourceRecordSetWeightDeserializer;
 impl ResourceRecordSetWeightDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6100,10 +9849,10 @@ impl ResourceRecordSetWeightSerializer {
     }
 }
 
-struct ResourceRecordSetsDeserializer;
+pub(crate) struct ResourceRecordSetsDeserializer;
 impl ResourceRecordSetsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ResourceRecordSet>, XmlParseError> {
@@ -6120,10 +9869,10 @@ impl ResourceRecordSetsDeserializer {
         })
     }
 }
-struct ResourceRecordsDeserializer;
+pub(crate) struct ResourceRecordsDeserializer;
 impl ResourceRecordsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ResourceRecord>, XmlParseError> {
@@ -6167,15 +9916,15 @@ pub struct ResourceTagSet {
     /// <p>The ID for the specified resource.</p>
     pub resource_id: Option<String>,
     /// <p><p>The type of the resource.</p> <ul> <li> <p>The resource type for health checks is <code>healthcheck</code>.</p> </li> <li> <p>The resource type for hosted zones is <code>hostedzone</code>.</p> </li> </ul></p>
-    pub resource_type: Option<String>,
+    pub resource_type: Option<TagResourceType>,
     /// <p>The tags associated with the specified resource.</p>
     pub tags: Option<Vec<Tag>>,
 }
 
-struct ResourceTagSetDeserializer;
+pub(crate) struct ResourceTagSetDeserializer;
 impl ResourceTagSetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourceTagSet, XmlParseError> {
@@ -6202,10 +9951,10 @@ impl ResourceTagSetDeserializer {
         })
     }
 }
-struct ResourceTagSetListDeserializer;
+pub(crate) struct ResourceTagSetListDeserializer;
 impl ResourceTagSetListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ResourceTagSet>, XmlParseError> {
@@ -6223,24 +9972,28 @@ impl ResourceTagSetListDeserializer {
     }
 }
 /// <p>A complex type that contains the type of limit that you specified in the request and the current value for that limit.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ReusableDelegationSetLimit {
     /// <p>The limit that you requested: <code>MAX_ZONES_BY_REUSABLE_DELEGATION_SET</code>, the maximum number of hosted zones that you can associate with the specified reusable delegation set.</p>
-    pub type_: String,
+    pub type_: ReusableDelegationSetLimitType,
     /// <p>The current value for the <code>MAX_ZONES_BY_REUSABLE_DELEGATION_SET</code> limit.</p>
     pub value: i64,
 }
 
-struct ReusableDelegationSetLimitDeserializer;
+pub(crate) struct ReusableDelegationSetLimitDeserializer;
 impl ReusableDelegationSetLimitDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReusableDelegationSetLimit, XmlParseError> {
-        deserialize_elements::<_, ReusableDelegationSetLimit, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            ReusableDelegationSetLimit {
+                type_: ReusableDelegationSetLimitType::Unknown(String::new()),
+                value: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "Type" => {
@@ -6257,12 +10010,15 @@ impl ReusableDelegationSetLimitDeserializer {
         )
     }
 }
-struct ReusableDelegationSetLimitTypeDeserializer;
+pub(crate) struct ReusableDelegationSetLimitTypeDeserializer;
 impl ReusableDelegationSetLimitTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReusableDelegationSetLimitType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6275,7 +10031,7 @@ impl ReusableDelegationSetLimitTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ReusableDelegationSetLimitType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6289,10 +10045,72 @@ impl ReusableDelegationSetLimitTypeSerializer {
     }
 }
 
-struct SearchStringDeserializer;
+/// <p>Specify <code>MAX_ZONES_BY_REUSABLE_DELEGATION_SET</code> to get the maximum number of hosted zones that you can associate with the specified reusable delegation set.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReusableDelegationSetLimitType {
+    /// <p><code>MAX_ZONES_BY_REUSABLE_DELEGATION_SET</code></p>
+    MaxZonesByReusableDelegationSet,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReusableDelegationSetLimitType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReusableDelegationSetLimitType::MaxZonesByReusableDelegationSet => {
+                "MAX_ZONES_BY_REUSABLE_DELEGATION_SET"
+            }
+            ReusableDelegationSetLimitType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReusableDelegationSetLimitType {
+    fn from(value: &'a str) -> ReusableDelegationSetLimitType {
+        match value {
+            "MAX_ZONES_BY_REUSABLE_DELEGATION_SET" => {
+                ReusableDelegationSetLimitType::MaxZonesByReusableDelegationSet
+            }
+            _ => ReusableDelegationSetLimitType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReusableDelegationSetLimitType {
+    fn from(value: String) -> ReusableDelegationSetLimitType {
+        ReusableDelegationSetLimitType::from(value.as_str())
+    }
+}
+
+impl From<ReusableDelegationSetLimitType> for String {
+    fn from(value: ReusableDelegationSetLimitType) -> String {
+        match value {
+            ReusableDelegationSetLimitType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReusableDelegationSetLimitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReusableDelegationSetLimitType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct SearchStringDeserializer;
 impl SearchStringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6321,10 +10139,13 @@ impl SearchStringSerializer {
     }
 }
 
-struct ServicePrincipalDeserializer;
+pub(crate) struct ServicePrincipalDeserializer;
 impl ServicePrincipalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6332,21 +10153,98 @@ impl ServicePrincipalDeserializer {
         Ok(obj)
     }
 }
-struct StatisticDeserializer;
+pub(crate) struct StatisticDeserializer;
 impl StatisticDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Statistic, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StatusDeserializer;
+/// <p>For the metric that the CloudWatch alarm is associated with, the statistic that is applied to the metric.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Statistic {
+    /// <p><code>Average</code></p>
+    Average,
+    /// <p><code>Sum</code></p>
+    Sum,
+    /// <p><code>SampleCount</code></p>
+    SampleCount,
+    /// <p><code>Maximum</code></p>
+    Maximum,
+    /// <p><code>Minimum</code></p>
+    Minimum,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Statistic {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Statistic::Average => "Average",
+            Statistic::Sum => "Sum",
+            Statistic::SampleCount => "SampleCount",
+            Statistic::Maximum => "Maximum",
+            Statistic::Minimum => "Minimum",
+            Statistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Statistic {
+    fn from(value: &'a str) -> Statistic {
+        match value {
+            "Average" => Statistic::Average,
+            "Sum" => Statistic::Sum,
+            "SampleCount" => Statistic::SampleCount,
+            "Maximum" => Statistic::Maximum,
+            "Minimum" => Statistic::Minimum,
+            _ => Statistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Statistic {
+    fn from(value: String) -> Statistic {
+        Statistic::from(value.as_str())
+    }
+}
+
+impl From<Statistic> for String {
+    fn from(value: Statistic) -> String {
+        match value {
+            Statistic::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Statistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Statistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct StatusDeserializer;
 impl StatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6358,15 +10256,15 @@ impl StatusDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StatusReport {
     /// <p>The date and time that the health checker performed the health check in <a href="https://en.wikipedia.org/wiki/ISO_8601">ISO 8601 format</a> and Coordinated Universal Time (UTC). For example, the value <code>2017-03-27T17:48:16.751Z</code> represents March 27, 2017 at 17:48:16.751 UTC.</p>
-    pub checked_time: Option<String>,
+    pub checked_time: Option<IsoTimestamp>,
     /// <p>A description of the status of the health check endpoint as reported by one of the Amazon Route 53 health checkers.</p>
     pub status: Option<String>,
 }
 
-struct StatusReportDeserializer;
+pub(crate) struct StatusReportDeserializer;
 impl StatusReportDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StatusReport, XmlParseError> {
@@ -6406,10 +10304,13 @@ impl SubnetMaskSerializer {
     }
 }
 
-struct TTLDeserializer;
+pub(crate) struct TTLDeserializer;
 impl TTLDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6447,10 +10348,13 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -6498,10 +10402,13 @@ impl TagSerializer {
     }
 }
 
-struct TagKeyDeserializer;
+pub(crate) struct TagKeyDeserializer;
 impl TagKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6550,10 +10457,10 @@ impl TagKeyListSerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -6588,10 +10495,13 @@ impl TagListSerializer {
     }
 }
 
-struct TagResourceIdDeserializer;
+pub(crate) struct TagResourceIdDeserializer;
 impl TagResourceIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6640,12 +10550,15 @@ impl TagResourceIdListSerializer {
     }
 }
 
-struct TagResourceTypeDeserializer;
+pub(crate) struct TagResourceTypeDeserializer;
 impl TagResourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TagResourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6658,7 +10571,7 @@ impl TagResourceTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &TagResourceType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6672,10 +10585,72 @@ impl TagResourceTypeSerializer {
     }
 }
 
-struct TagValueDeserializer;
+/// <p><p>The type of the resource.</p> <ul> <li> <p>The resource type for health checks is <code>healthcheck</code>.</p> </li> <li> <p>The resource type for hosted zones is <code>hostedzone</code>.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TagResourceType {
+    /// <p><code>healthcheck</code></p>
+    Healthcheck,
+    /// <p><code>hostedzone</code></p>
+    Hostedzone,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TagResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TagResourceType::Healthcheck => "healthcheck",
+            TagResourceType::Hostedzone => "hostedzone",
+            TagResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TagResourceType {
+    fn from(value: &'a str) -> TagResourceType {
+        match value {
+            "healthcheck" => TagResourceType::Healthcheck,
+            "hostedzone" => TagResourceType::Hostedzone,
+            _ => TagResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TagResourceType {
+    fn from(value: String) -> TagResourceType {
+        TagResourceType::from(value.as_str())
+    }
+}
+
+impl From<TagResourceType> for String {
+    fn from(value: TagResourceType) -> String {
+        match value {
+            TagResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TagResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TagResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct TagValueDeserializer;
 impl TagValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6705,7 +10680,7 @@ impl TagValueSerializer {
 }
 
 /// <p>Gets the value that Amazon Route 53 returns in response to a DNS request for a specified record name and type. You can optionally specify the IP address of a DNS resolver, an EDNS0 client subnet IP address, and a subnet mask. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TestDNSAnswerRequest {
     /// <p>If the resolver that you specified for resolverip supports EDNS0, specify the IPv4 or IPv6 address of a client in the applicable location, for example, <code>192.0.2.44</code> or <code>2001:db8:85a3::8a2e:370:7334</code>.</p>
     pub edns0_client_subnet_ip: Option<String>,
@@ -6716,13 +10691,87 @@ pub struct TestDNSAnswerRequest {
     /// <p>The name of the resource record set that you want Amazon Route 53 to simulate a query for.</p>
     pub record_name: String,
     /// <p>The type of the resource record set.</p>
-    pub record_type: String,
+    pub record_type: RRType,
     /// <p>If you want to simulate a request from a specific DNS resolver, specify the IP address for that resolver. If you omit this value, <code>TestDnsAnswer</code> uses the IP address of a DNS resolver in the AWS US East (N. Virginia) Region (<code>us-east-1</code>).</p>
     pub resolver_ip: Option<String>,
 }
 
+impl TestDNSAnswerRequest {
+    /// Returns a builder for `TestDNSAnswerRequest`, checking its required members when built.
+    pub fn builder() -> TestDNSAnswerRequestBuilder {
+        TestDNSAnswerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TestDNSAnswerRequest`](struct.TestDNSAnswerRequest.html).
+#[derive(Default, Debug)]
+pub struct TestDNSAnswerRequestBuilder {
+    edns0_client_subnet_ip: Option<String>,
+    edns0_client_subnet_mask: Option<String>,
+    hosted_zone_id: Option<String>,
+    record_name: Option<String>,
+    record_type: Option<RRType>,
+    resolver_ip: Option<String>,
+}
+
+impl TestDNSAnswerRequestBuilder {
+    /// Sets the [`edns0_client_subnet_ip`](struct.TestDNSAnswerRequest.html#structfield.edns0_client_subnet_ip) member.
+    pub fn edns0_client_subnet_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.edns0_client_subnet_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`edns0_client_subnet_mask`](struct.TestDNSAnswerRequest.html#structfield.edns0_client_subnet_mask) member.
+    pub fn edns0_client_subnet_mask<T: Into<String>>(mut self, value: T) -> Self {
+        self.edns0_client_subnet_mask = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hosted_zone_id`](struct.TestDNSAnswerRequest.html#structfield.hosted_zone_id) member.
+    pub fn hosted_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hosted_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`record_name`](struct.TestDNSAnswerRequest.html#structfield.record_name) member.
+    pub fn record_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.record_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`record_type`](struct.TestDNSAnswerRequest.html#structfield.record_type) member.
+    pub fn record_type<T: Into<RRType>>(mut self, value: T) -> Self {
+        self.record_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resolver_ip`](struct.TestDNSAnswerRequest.html#structfield.resolver_ip) member.
+    pub fn resolver_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.resolver_ip = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestDNSAnswerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TestDNSAnswerRequest, ::rusoto_core::BuildError> {
+        Ok(TestDNSAnswerRequest {
+            edns0_client_subnet_ip: self.edns0_client_subnet_ip,
+            edns0_client_subnet_mask: self.edns0_client_subnet_mask,
+            hosted_zone_id: self
+                .hosted_zone_id
+                .ok_or(::rusoto_core::BuildError::missing("hosted_zone_id"))?,
+            record_name: self
+                .record_name
+                .ok_or(::rusoto_core::BuildError::missing("record_name"))?,
+            record_type: self
+                .record_type
+                .ok_or(::rusoto_core::BuildError::missing("record_type"))?,
+            resolver_ip: self.resolver_ip,
+        })
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>TestDNSAnswer</code> request. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TestDNSAnswerResponse {
     /// <p>The Amazon Route 53 name server used to respond to the request.</p>
     pub nameserver: String,
@@ -6733,49 +10782,66 @@ pub struct TestDNSAnswerResponse {
     /// <p>The name of the resource record set that you submitted a request for.</p>
     pub record_name: String,
     /// <p>The type of the resource record set that you submitted a request for.</p>
-    pub record_type: String,
+    pub record_type: RRType,
     /// <p>A code that indicates whether the request is valid or not. The most common response code is <code>NOERROR</code>, meaning that the request is valid. If the response is not valid, Amazon Route 53 returns a response code that describes the error. For a list of possible response codes, see <a href="http://www.iana.org/assignments/dns-parameters/dns-parameters.xhtml#dns-parameters-6">DNS RCODES</a> on the IANA website. </p>
     pub response_code: String,
 }
 
-struct TestDNSAnswerResponseDeserializer;
+pub(crate) struct TestDNSAnswerResponseDeserializer;
 impl TestDNSAnswerResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TestDNSAnswerResponse, XmlParseError> {
-        deserialize_elements::<_, TestDNSAnswerResponse, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Nameserver" => {
-                    obj.nameserver = NameserverDeserializer::deserialize("Nameserver", stack)?;
-                }
-                "Protocol" => {
-                    obj.protocol = TransportProtocolDeserializer::deserialize("Protocol", stack)?;
-                }
-                "RecordData" => {
-                    obj.record_data
-                        .extend(RecordDataDeserializer::deserialize("RecordData", stack)?);
-                }
-                "RecordName" => {
-                    obj.record_name = DNSNameDeserializer::deserialize("RecordName", stack)?;
-                }
-                "RecordType" => {
-                    obj.record_type = RRTypeDeserializer::deserialize("RecordType", stack)?;
-                }
-                "ResponseCode" => {
-                    obj.response_code = DNSRCodeDeserializer::deserialize("ResponseCode", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            TestDNSAnswerResponse {
+                nameserver: Default::default(),
+                protocol: Default::default(),
+                record_data: Default::default(),
+                record_name: Default::default(),
+                record_type: RRType::Unknown(String::new()),
+                response_code: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Nameserver" => {
+                        obj.nameserver = NameserverDeserializer::deserialize("Nameserver", stack)?;
+                    }
+                    "Protocol" => {
+                        obj.protocol =
+                            TransportProtocolDeserializer::deserialize("Protocol", stack)?;
+                    }
+                    "RecordData" => {
+                        obj.record_data
+                            .extend(RecordDataDeserializer::deserialize("RecordData", stack)?);
+                    }
+                    "RecordName" => {
+                        obj.record_name = DNSNameDeserializer::deserialize("RecordName", stack)?;
+                    }
+                    "RecordType" => {
+                        obj.record_type = RRTypeDeserializer::deserialize("RecordType", stack)?;
+                    }
+                    "ResponseCode" => {
+                        obj.response_code =
+                            DNSRCodeDeserializer::deserialize("ResponseCode", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct ThresholdDeserializer;
+pub(crate) struct ThresholdDeserializer;
 impl ThresholdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6783,21 +10849,24 @@ impl ThresholdDeserializer {
         Ok(obj)
     }
 }
-struct TimeStampDeserializer;
+pub(crate) struct TimeStampDeserializer;
 impl TimeStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct TrafficPoliciesDeserializer;
+pub(crate) struct TrafficPoliciesDeserializer;
 impl TrafficPoliciesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TrafficPolicy>, XmlParseError> {
@@ -6815,7 +10884,7 @@ impl TrafficPoliciesDeserializer {
     }
 }
 /// <p>A complex type that contains settings for a traffic policy.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TrafficPolicy {
     /// <p>The comment that you specify in the <code>CreateTrafficPolicy</code> request, if any.</p>
     pub comment: Option<String>,
@@ -6826,51 +10895,67 @@ pub struct TrafficPolicy {
     /// <p>The name that you specified when you created the traffic policy.</p>
     pub name: String,
     /// <p>The DNS type of the resource record sets that Amazon Route 53 creates when you use a traffic policy to create a traffic policy instance.</p>
-    pub type_: String,
+    pub type_: RRType,
     /// <p>The version number that Amazon Route 53 assigns to a traffic policy. For a new traffic policy, the value of <code>Version</code> is always 1.</p>
     pub version: i64,
 }
 
-struct TrafficPolicyDeserializer;
+pub(crate) struct TrafficPolicyDeserializer;
 impl TrafficPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TrafficPolicy, XmlParseError> {
-        deserialize_elements::<_, TrafficPolicy, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Comment" => {
-                    obj.comment = Some(TrafficPolicyCommentDeserializer::deserialize(
-                        "Comment", stack,
-                    )?);
-                }
-                "Document" => {
-                    obj.document =
-                        TrafficPolicyDocumentDeserializer::deserialize("Document", stack)?;
-                }
-                "Id" => {
-                    obj.id = TrafficPolicyIdDeserializer::deserialize("Id", stack)?;
-                }
-                "Name" => {
-                    obj.name = TrafficPolicyNameDeserializer::deserialize("Name", stack)?;
-                }
-                "Type" => {
-                    obj.type_ = RRTypeDeserializer::deserialize("Type", stack)?;
-                }
-                "Version" => {
-                    obj.version = TrafficPolicyVersionDeserializer::deserialize("Version", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            TrafficPolicy {
+                comment: Default::default(),
+                document: Default::default(),
+                id: Default::default(),
+                name: Default::default(),
+                type_: RRType::Unknown(String::new()),
+                version: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Comment" => {
+                        obj.comment = Some(TrafficPolicyCommentDeserializer::deserialize(
+                            "Comment", stack,
+                        )?);
+                    }
+                    "Document" => {
+                        obj.document =
+                            TrafficPolicyDocumentDeserializer::deserialize("Document", stack)?;
+                    }
+                    "Id" => {
+                        obj.id = TrafficPolicyIdDeserializer::deserialize("Id", stack)?;
+                    }
+                    "Name" => {
+                        obj.name = TrafficPolicyNameDeserializer::deserialize("Name", stack)?;
+                    }
+                    "Type" => {
+                        obj.type_ = RRTypeDeserializer::deserialize("Type", stack)?;
+                    }
+                    "Version" => {
+                        obj.version =
+                            TrafficPolicyVersionDeserializer::deserialize("Version", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct TrafficPolicyCommentDeserializer;
+pub(crate) struct TrafficPolicyCommentDeserializer;
 impl TrafficPolicyCommentDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6899,10 +10984,13 @@ impl TrafficPolicyCommentSerializer {
     }
 }
 
-struct TrafficPolicyDocumentDeserializer;
+pub(crate) struct TrafficPolicyDocumentDeserializer;
 impl TrafficPolicyDocumentDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6931,10 +11019,13 @@ impl TrafficPolicyDocumentSerializer {
     }
 }
 
-struct TrafficPolicyIdDeserializer;
+pub(crate) struct TrafficPolicyIdDeserializer;
 impl TrafficPolicyIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6964,7 +11055,7 @@ impl TrafficPolicyIdSerializer {
 }
 
 /// <p>A complex type that contains settings for the new traffic policy instance.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TrafficPolicyInstance {
     /// <p>The ID of the hosted zone that Amazon Route 53 created resource record sets in.</p>
     pub hosted_zone_id: String,
@@ -6981,64 +11072,82 @@ pub struct TrafficPolicyInstance {
     /// <p>The ID of the traffic policy that Amazon Route 53 used to create resource record sets in the specified hosted zone.</p>
     pub traffic_policy_id: String,
     /// <p>The DNS type that Amazon Route 53 assigned to all of the resource record sets that it created for this traffic policy instance. </p>
-    pub traffic_policy_type: String,
+    pub traffic_policy_type: RRType,
     /// <p>The version of the traffic policy that Amazon Route 53 used to create resource record sets in the specified hosted zone.</p>
     pub traffic_policy_version: i64,
 }
 
-struct TrafficPolicyInstanceDeserializer;
+pub(crate) struct TrafficPolicyInstanceDeserializer;
 impl TrafficPolicyInstanceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TrafficPolicyInstance, XmlParseError> {
-        deserialize_elements::<_, TrafficPolicyInstance, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "HostedZoneId" => {
-                    obj.hosted_zone_id =
-                        ResourceIdDeserializer::deserialize("HostedZoneId", stack)?;
-                }
-                "Id" => {
-                    obj.id = TrafficPolicyInstanceIdDeserializer::deserialize("Id", stack)?;
-                }
-                "Message" => {
-                    obj.message = MessageDeserializer::deserialize("Message", stack)?;
-                }
-                "Name" => {
-                    obj.name = DNSNameDeserializer::deserialize("Name", stack)?;
-                }
-                "State" => {
-                    obj.state =
-                        TrafficPolicyInstanceStateDeserializer::deserialize("State", stack)?;
-                }
-                "TTL" => {
-                    obj.ttl = TTLDeserializer::deserialize("TTL", stack)?;
-                }
-                "TrafficPolicyId" => {
-                    obj.traffic_policy_id =
-                        TrafficPolicyIdDeserializer::deserialize("TrafficPolicyId", stack)?;
-                }
-                "TrafficPolicyType" => {
-                    obj.traffic_policy_type =
-                        RRTypeDeserializer::deserialize("TrafficPolicyType", stack)?;
-                }
-                "TrafficPolicyVersion" => {
-                    obj.traffic_policy_version = TrafficPolicyVersionDeserializer::deserialize(
-                        "TrafficPolicyVersion",
-                        stack,
-                    )?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            TrafficPolicyInstance {
+                hosted_zone_id: Default::default(),
+                id: Default::default(),
+                message: Default::default(),
+                name: Default::default(),
+                state: Default::default(),
+                ttl: Default::default(),
+                traffic_policy_id: Default::default(),
+                traffic_policy_type: RRType::Unknown(String::new()),
+                traffic_policy_version: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "HostedZoneId" => {
+                        obj.hosted_zone_id =
+                            ResourceIdDeserializer::deserialize("HostedZoneId", stack)?;
+                    }
+                    "Id" => {
+                        obj.id = TrafficPolicyInstanceIdDeserializer::deserialize("Id", stack)?;
+                    }
+                    "Message" => {
+                        obj.message = MessageDeserializer::deserialize("Message", stack)?;
+                    }
+                    "Name" => {
+                        obj.name = DNSNameDeserializer::deserialize("Name", stack)?;
+                    }
+                    "State" => {
+                        obj.state =
+                            TrafficPolicyInstanceStateDeserializer::deserialize("State", stack)?;
+                    }
+                    "TTL" => {
+                        obj.ttl = TTLDeserializer::deserialize("TTL", stack)?;
+                    }
+                    "TrafficPolicyId" => {
+                        obj.traffic_policy_id =
+                            TrafficPolicyIdDeserializer::deserialize("TrafficPolicyId", stack)?;
+                    }
+                    "TrafficPolicyType" => {
+                        obj.traffic_policy_type =
+                            RRTypeDeserializer::deserialize("TrafficPolicyType", stack)?;
+                    }
+                    "TrafficPolicyVersion" => {
+                        obj.traffic_policy_version = TrafficPolicyVersionDeserializer::deserialize(
+                            "TrafficPolicyVersion",
+                            stack,
+                        )?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct TrafficPolicyInstanceCountDeserializer;
+pub(crate) struct TrafficPolicyInstanceCountDeserializer;
 impl TrafficPolicyInstanceCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -7046,10 +11155,13 @@ impl TrafficPolicyInstanceCountDeserializer {
         Ok(obj)
     }
 }
-struct TrafficPolicyInstanceIdDeserializer;
+pub(crate) struct TrafficPolicyInstanceIdDeserializer;
 impl TrafficPolicyInstanceIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7078,10 +11190,13 @@ impl TrafficPolicyInstanceIdSerializer {
     }
 }
 
-struct TrafficPolicyInstanceStateDeserializer;
+pub(crate) struct TrafficPolicyInstanceStateDeserializer;
 impl TrafficPolicyInstanceStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7089,10 +11204,10 @@ impl TrafficPolicyInstanceStateDeserializer {
         Ok(obj)
     }
 }
-struct TrafficPolicyInstancesDeserializer;
+pub(crate) struct TrafficPolicyInstancesDeserializer;
 impl TrafficPolicyInstancesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TrafficPolicyInstance>, XmlParseError> {
@@ -7109,10 +11224,13 @@ impl TrafficPolicyInstancesDeserializer {
         })
     }
 }
-struct TrafficPolicyNameDeserializer;
+pub(crate) struct TrafficPolicyNameDeserializer;
 impl TrafficPolicyNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7141,10 +11259,10 @@ impl TrafficPolicyNameSerializer {
     }
 }
 
-struct TrafficPolicySummariesDeserializer;
+pub(crate) struct TrafficPolicySummariesDeserializer;
 impl TrafficPolicySummariesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TrafficPolicySummary>, XmlParseError> {
@@ -7162,7 +11280,7 @@ impl TrafficPolicySummariesDeserializer {
     }
 }
 /// <p>A complex type that contains information about the latest version of one traffic policy that is associated with the current AWS account.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TrafficPolicySummary {
     /// <p>The ID that Amazon Route 53 assigned to the traffic policy when you created it.</p>
     pub id: String,
@@ -7173,45 +11291,61 @@ pub struct TrafficPolicySummary {
     /// <p>The number of traffic policies that are associated with the current AWS account.</p>
     pub traffic_policy_count: i64,
     /// <p>The DNS type of the resource record sets that Amazon Route 53 creates when you use a traffic policy to create a traffic policy instance.</p>
-    pub type_: String,
+    pub type_: RRType,
 }
 
-struct TrafficPolicySummaryDeserializer;
+pub(crate) struct TrafficPolicySummaryDeserializer;
 impl TrafficPolicySummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TrafficPolicySummary, XmlParseError> {
-        deserialize_elements::<_, TrafficPolicySummary, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Id" => {
-                    obj.id = TrafficPolicyIdDeserializer::deserialize("Id", stack)?;
-                }
-                "LatestVersion" => {
-                    obj.latest_version =
-                        TrafficPolicyVersionDeserializer::deserialize("LatestVersion", stack)?;
-                }
-                "Name" => {
-                    obj.name = TrafficPolicyNameDeserializer::deserialize("Name", stack)?;
-                }
-                "TrafficPolicyCount" => {
-                    obj.traffic_policy_count =
-                        TrafficPolicyVersionDeserializer::deserialize("TrafficPolicyCount", stack)?;
-                }
-                "Type" => {
-                    obj.type_ = RRTypeDeserializer::deserialize("Type", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            TrafficPolicySummary {
+                id: Default::default(),
+                latest_version: Default::default(),
+                name: Default::default(),
+                traffic_policy_count: Default::default(),
+                type_: RRType::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Id" => {
+                        obj.id = TrafficPolicyIdDeserializer::deserialize("Id", stack)?;
+                    }
+                    "LatestVersion" => {
+                        obj.latest_version =
+                            TrafficPolicyVersionDeserializer::deserialize("LatestVersion", stack)?;
+                    }
+                    "Name" => {
+                        obj.name = TrafficPolicyNameDeserializer::deserialize("Name", stack)?;
+                    }
+                    "TrafficPolicyCount" => {
+                        obj.traffic_policy_count = TrafficPolicyVersionDeserializer::deserialize(
+                            "TrafficPolicyCount",
+                            stack,
+                        )?;
+                    }
+                    "Type" => {
+                        obj.type_ = RRTypeDeserializer::deserialize("Type", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct TrafficPolicyVersionDeserializer;
+pub(crate) struct TrafficPolicyVersionDeserializer;
 impl TrafficPolicyVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -7240,10 +11374,13 @@ impl TrafficPolicyVersionSerializer {
     }
 }
 
-struct TrafficPolicyVersionMarkerDeserializer;
+pub(crate) struct TrafficPolicyVersionMarkerDeserializer;
 impl TrafficPolicyVersionMarkerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7272,10 +11409,13 @@ impl TrafficPolicyVersionMarkerSerializer {
     }
 }
 
-struct TransportProtocolDeserializer;
+pub(crate) struct TransportProtocolDeserializer;
 impl TransportProtocolDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7307,21 +11447,184 @@ pub struct UpdateHealthCheckRequest {
     /// <p><p>The IPv4 or IPv6 IP address for the endpoint that you want Amazon Route 53 to perform health checks on. If you don&#39;t specify a value for <code>IPAddress</code>, Route 53 sends a DNS request to resolve the domain name that you specify in <code>FullyQualifiedDomainName</code> at the interval that you specify in <code>RequestInterval</code>. Using an IP address that is returned by DNS, Route 53 then checks the health of the endpoint.</p> <p>Use one of the following formats for the value of <code>IPAddress</code>: </p> <ul> <li> <p> <b>IPv4 address</b>: four values between 0 and 255, separated by periods (.), for example, <code>192.0.2.44</code>.</p> </li> <li> <p> <b>IPv6 address</b>: eight groups of four hexadecimal values, separated by colons (:), for example, <code>2001:0db8:85a3:0000:0000:abcd:0001:2345</code>. You can also shorten IPv6 addresses as described in RFC 5952, for example, <code>2001:db8:85a3::abcd:1:2345</code>.</p> </li> </ul> <p>If the endpoint is an EC2 instance, we recommend that you create an Elastic IP address, associate it with your EC2 instance, and specify the Elastic IP address for <code>IPAddress</code>. This ensures that the IP address of your instance never changes. For more information, see the applicable documentation:</p> <ul> <li> <p>Linux: <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP Addresses (EIP)</a> in the <i>Amazon EC2 User Guide for Linux Instances</i> </p> </li> <li> <p>Windows: <a href="https://docs.aws.amazon.com/AWSEC2/latest/WindowsGuide/elastic-ip-addresses-eip.html">Elastic IP Addresses (EIP)</a> in the <i>Amazon EC2 User Guide for Windows Instances</i> </p> </li> </ul> <note> <p>If a health check already has a value for <code>IPAddress</code>, you can change the value. However, you can&#39;t update an existing health check to add or remove the value of <code>IPAddress</code>. </p> </note> <p>For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_UpdateHealthCheck.html#Route53-UpdateHealthCheck-request-FullyQualifiedDomainName">FullyQualifiedDomainName</a>. </p> <p>Constraints: Route 53 can&#39;t check the health of endpoints for which the IP address is in local, private, non-routable, or multicast ranges. For more information about IP addresses for which you can&#39;t create health checks, see the following documents:</p> <ul> <li> <p> <a href="https://tools.ietf.org/html/rfc5735">RFC 5735, Special Use IPv4 Addresses</a> </p> </li> <li> <p> <a href="https://tools.ietf.org/html/rfc6598">RFC 6598, IANA-Reserved IPv4 Prefix for Shared Address Space</a> </p> </li> <li> <p> <a href="https://tools.ietf.org/html/rfc5156">RFC 5156, Special-Use IPv6 Addresses</a> </p> </li> </ul></p>
     pub ip_address: Option<String>,
     /// <p><p>When CloudWatch has insufficient data about the metric to determine the alarm state, the status that you want Amazon Route 53 to assign to the health check:</p> <ul> <li> <p> <code>Healthy</code>: Route 53 considers the health check to be healthy.</p> </li> <li> <p> <code>Unhealthy</code>: Route 53 considers the health check to be unhealthy.</p> </li> <li> <p> <code>LastKnownStatus</code>: Route 53 uses the status of the health check from the last time CloudWatch had sufficient data to determine the alarm state. For new health checks that have no last known status, the default status for the health check is healthy.</p> </li> </ul></p>
-    pub insufficient_data_health_status: Option<String>,
+    pub insufficient_data_health_status: Option<InsufficientDataHealthStatus>,
     /// <p>Specify whether you want Amazon Route 53 to invert the status of a health check, for example, to consider a health check unhealthy when it otherwise would be considered healthy.</p>
     pub inverted: Option<bool>,
     /// <p>The port on the endpoint on which you want Amazon Route 53 to perform health checks.</p>
     pub port: Option<i64>,
     /// <p>A complex type that contains one <code>Region</code> element for each region that you want Amazon Route 53 health checkers to check the specified endpoint from.</p>
-    pub regions: Option<Vec<String>>,
+    pub regions: Option<Vec<HealthCheckRegion>>,
     /// <p><p>A complex type that contains one <code>ResettableElementName</code> element for each element that you want to reset to the default value. Valid values for <code>ResettableElementName</code> include the following:</p> <ul> <li> <p> <code>ChildHealthChecks</code>: Amazon Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-ChildHealthChecks">ChildHealthChecks</a> to null.</p> </li> <li> <p> <code>FullyQualifiedDomainName</code>: Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_UpdateHealthCheck.html#Route53-UpdateHealthCheck-request-FullyQualifiedDomainName">FullyQualifiedDomainName</a>. to null.</p> </li> <li> <p> <code>Regions</code>: Route 53 resets the <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-Regions">Regions</a> list to the default set of regions. </p> </li> <li> <p> <code>ResourcePath</code>: Route 53 resets <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-ResourcePath">ResourcePath</a> to null.</p> </li> </ul></p>
-    pub reset_elements: Option<Vec<String>>,
+    pub reset_elements: Option<Vec<ResettableElementName>>,
     /// <p>The path that you want Amazon Route 53 to request when performing health checks. The path can be any value for which your endpoint will return an HTTP status code of 2xx or 3xx when the endpoint is healthy, for example the file /docs/route53-health-check.html. You can also include query string parameters, for example, <code>/welcome.html?language=jp&amp;login=y</code>. </p> <p>Specify this value only if you want to change it.</p>
     pub resource_path: Option<String>,
     /// <p>If the value of <code>Type</code> is <code>HTTP_STR_MATCH</code> or <code>HTTP_STR_MATCH</code>, the string that you want Amazon Route 53 to search for in the response body from the specified resource. If the string appears in the response body, Route 53 considers the resource healthy. (You can't change the value of <code>Type</code> when you update a health check.)</p>
     pub search_string: Option<String>,
 }
 
+impl UpdateHealthCheckRequest {
+    /// Returns a builder for `UpdateHealthCheckRequest`, checking its required members when built.
+    pub fn builder() -> UpdateHealthCheckRequestBuilder {
+        UpdateHealthCheckRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateHealthCheckRequest`](struct.UpdateHealthCheckRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateHealthCheckRequestBuilder {
+    alarm_identifier: Option<AlarmIdentifier>,
+    child_health_checks: Option<Vec<String>>,
+    disabled: Option<bool>,
+    enable_sni: Option<bool>,
+    failure_threshold: Option<i64>,
+    fully_qualified_domain_name: Option<String>,
+    health_check_id: Option<String>,
+    health_check_version: Option<i64>,
+    health_threshold: Option<i64>,
+    ip_address: Option<String>,
+    insufficient_data_health_status: Option<InsufficientDataHealthStatus>,
+    inverted: Option<bool>,
+    port: Option<i64>,
+    regions: Option<Vec<HealthCheckRegion>>,
+    reset_elements: Option<Vec<ResettableElementName>>,
+    resource_path: Option<String>,
+    search_string: Option<String>,
+}
+
+impl UpdateHealthCheckRequestBuilder {
+    /// Sets the [`alarm_identifier`](struct.UpdateHealthCheckRequest.html#structfield.alarm_identifier) member.
+    pub fn alarm_identifier<T: Into<AlarmIdentifier>>(mut self, value: T) -> Self {
+        self.alarm_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`child_health_checks`](struct.UpdateHealthCheckRequest.html#structfield.child_health_checks) member.
+    pub fn child_health_checks<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.child_health_checks = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disabled`](struct.UpdateHealthCheckRequest.html#structfield.disabled) member.
+    pub fn disabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.disabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_sni`](struct.UpdateHealthCheckRequest.html#structfield.enable_sni) member.
+    pub fn enable_sni<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_sni = Some(value.into());
+        self
+    }
+
+    /// Sets the [`failure_threshold`](struct.UpdateHealthCheckRequest.html#structfield.failure_threshold) member.
+    pub fn failure_threshold<T: Into<i64>>(mut self, value: T) -> Self {
+        self.failure_threshold = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fully_qualified_domain_name`](struct.UpdateHealthCheckRequest.html#structfield.fully_qualified_domain_name) member.
+    pub fn fully_qualified_domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.fully_qualified_domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_id`](struct.UpdateHealthCheckRequest.html#structfield.health_check_id) member.
+    pub fn health_check_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_version`](struct.UpdateHealthCheckRequest.html#structfield.health_check_version) member.
+    pub fn health_check_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.health_check_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_threshold`](struct.UpdateHealthCheckRequest.html#structfield.health_threshold) member.
+    pub fn health_threshold<T: Into<i64>>(mut self, value: T) -> Self {
+        self.health_threshold = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ip_address`](struct.UpdateHealthCheckRequest.html#structfield.ip_address) member.
+    pub fn ip_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.ip_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`insufficient_data_health_status`](struct.UpdateHealthCheckRequest.html#structfield.insufficient_data_health_status) member.
+    pub fn insufficient_data_health_status<T: Into<InsufficientDataHealthStatus>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.insufficient_data_health_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`inverted`](struct.UpdateHealthCheckRequest.html#structfield.inverted) member.
+    pub fn inverted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.inverted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.UpdateHealthCheckRequest.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`regions`](struct.UpdateHealthCheckRequest.html#structfield.regions) member.
+    pub fn regions<T: Into<Vec<HealthCheckRegion>>>(mut self, value: T) -> Self {
+        self.regions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reset_elements`](struct.UpdateHealthCheckRequest.html#structfield.reset_elements) member.
+    pub fn reset_elements<T: Into<Vec<ResettableElementName>>>(mut self, value: T) -> Self {
+        self.reset_elements = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_path`](struct.UpdateHealthCheckRequest.html#structfield.resource_path) member.
+    pub fn resource_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`search_string`](struct.UpdateHealthCheckRequest.html#structfield.search_string) member.
+    pub fn search_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.search_string = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateHealthCheckRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateHealthCheckRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateHealthCheckRequest {
+            alarm_identifier: self.alarm_identifier,
+            child_health_checks: self.child_health_checks,
+            disabled: self.disabled,
+            enable_sni: self.enable_sni,
+            failure_threshold: self.failure_threshold,
+            fully_qualified_domain_name: self.fully_qualified_domain_name,
+            health_check_id: self
+                .health_check_id
+                .ok_or(::rusoto_core::BuildError::missing("health_check_id"))?,
+            health_check_version: self.health_check_version,
+            health_threshold: self.health_threshold,
+            ip_address: self.ip_address,
+            insufficient_data_health_status: self.insufficient_data_health_status,
+            inverted: self.inverted,
+            port: self.port,
+            regions: self.regions,
+            reset_elements: self.reset_elements,
+            resource_path: self.resource_path,
+            search_string: self.search_string,
+        })
+    }
+}
+
 pub struct UpdateHealthCheckRequestSerializer;
 impl UpdateHealthCheckRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -7395,22 +11698,50 @@ impl UpdateHealthCheckRequestSerializer {
     }
 }
 /// <p>A complex type that contains the response to the <code>UpdateHealthCheck</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdateHealthCheckResponse {
     /// <p>A complex type that contains the response to an <code>UpdateHealthCheck</code> request.</p>
     pub health_check: HealthCheck,
 }
 
-struct UpdateHealthCheckResponseDeserializer;
+pub(crate) struct UpdateHealthCheckResponseDeserializer;
 impl UpdateHealthCheckResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateHealthCheckResponse, XmlParseError> {
-        deserialize_elements::<_, UpdateHealthCheckResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            UpdateHealthCheckResponse {
+                health_check: HealthCheck {
+                    caller_reference: Default::default(),
+                    cloud_watch_alarm_configuration: Default::default(),
+                    health_check_config: HealthCheckConfig {
+                        alarm_identifier: Default::default(),
+                        child_health_checks: Default::default(),
+                        disabled: Default::default(),
+                        enable_sni: Default::default(),
+                        failure_threshold: Default::default(),
+                        fully_qualified_domain_name: Default::default(),
+                        health_threshold: Default::default(),
+                        ip_address: Default::default(),
+                        insufficient_data_health_status: Default::default(),
+                        inverted: Default::default(),
+                        measure_latency: Default::default(),
+                        port: Default::default(),
+                        regions: Default::default(),
+                        request_interval: Default::default(),
+                        resource_path: Default::default(),
+                        search_string: Default::default(),
+                        type_: HealthCheckType::Unknown(String::new()),
+                    },
+                    health_check_version: Default::default(),
+                    id: Default::default(),
+                    linked_service: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "HealthCheck" => {
@@ -7433,6 +11764,44 @@ pub struct UpdateHostedZoneCommentRequest {
     pub id: String,
 }
 
+impl UpdateHostedZoneCommentRequest {
+    /// Returns a builder for `UpdateHostedZoneCommentRequest`, checking its required members when built.
+    pub fn builder() -> UpdateHostedZoneCommentRequestBuilder {
+        UpdateHostedZoneCommentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateHostedZoneCommentRequest`](struct.UpdateHostedZoneCommentRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateHostedZoneCommentRequestBuilder {
+    comment: Option<String>,
+    id: Option<String>,
+}
+
+impl UpdateHostedZoneCommentRequestBuilder {
+    /// Sets the [`comment`](struct.UpdateHostedZoneCommentRequest.html#structfield.comment) member.
+    pub fn comment<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateHostedZoneCommentRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateHostedZoneCommentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateHostedZoneCommentRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateHostedZoneCommentRequest {
+            comment: self.comment,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 pub struct UpdateHostedZoneCommentRequestSerializer;
 impl UpdateHostedZoneCommentRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -7459,10 +11828,10 @@ pub struct UpdateHostedZoneCommentResponse {
     pub hosted_zone: HostedZone,
 }
 
-struct UpdateHostedZoneCommentResponseDeserializer;
+pub(crate) struct UpdateHostedZoneCommentResponseDeserializer;
 impl UpdateHostedZoneCommentResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateHostedZoneCommentResponse, XmlParseError> {
@@ -7492,6 +11861,56 @@ pub struct UpdateTrafficPolicyCommentRequest {
     pub version: i64,
 }
 
+impl UpdateTrafficPolicyCommentRequest {
+    /// Returns a builder for `UpdateTrafficPolicyCommentRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTrafficPolicyCommentRequestBuilder {
+        UpdateTrafficPolicyCommentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTrafficPolicyCommentRequest`](struct.UpdateTrafficPolicyCommentRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTrafficPolicyCommentRequestBuilder {
+    comment: Option<String>,
+    id: Option<String>,
+    version: Option<i64>,
+}
+
+impl UpdateTrafficPolicyCommentRequestBuilder {
+    /// Sets the [`comment`](struct.UpdateTrafficPolicyCommentRequest.html#structfield.comment) member.
+    pub fn comment<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateTrafficPolicyCommentRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.UpdateTrafficPolicyCommentRequest.html#structfield.version) member.
+    pub fn version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTrafficPolicyCommentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateTrafficPolicyCommentRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTrafficPolicyCommentRequest {
+            comment: self
+                .comment
+                .ok_or(::rusoto_core::BuildError::missing("comment"))?,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 pub struct UpdateTrafficPolicyCommentRequestSerializer;
 impl UpdateTrafficPolicyCommentRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -7510,22 +11929,32 @@ impl UpdateTrafficPolicyCommentRequestSerializer {
     }
 }
 /// <p>A complex type that contains the response information for the traffic policy.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdateTrafficPolicyCommentResponse {
     /// <p>A complex type that contains settings for the specified traffic policy.</p>
     pub traffic_policy: TrafficPolicy,
 }
 
-struct UpdateTrafficPolicyCommentResponseDeserializer;
+pub(crate) struct UpdateTrafficPolicyCommentResponseDeserializer;
 impl UpdateTrafficPolicyCommentResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateTrafficPolicyCommentResponse, XmlParseError> {
-        deserialize_elements::<_, UpdateTrafficPolicyCommentResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            UpdateTrafficPolicyCommentResponse {
+                traffic_policy: TrafficPolicy {
+                    comment: Default::default(),
+                    document: Default::default(),
+                    id: Default::default(),
+                    name: Default::default(),
+                    type_: RRType::Unknown(String::new()),
+                    version: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "TrafficPolicy" => {
@@ -7552,6 +11981,64 @@ pub struct UpdateTrafficPolicyInstanceRequest {
     pub traffic_policy_version: i64,
 }
 
+impl UpdateTrafficPolicyInstanceRequest {
+    /// Returns a builder for `UpdateTrafficPolicyInstanceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTrafficPolicyInstanceRequestBuilder {
+        UpdateTrafficPolicyInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTrafficPolicyInstanceRequest`](struct.UpdateTrafficPolicyInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTrafficPolicyInstanceRequestBuilder {
+    id: Option<String>,
+    ttl: Option<i64>,
+    traffic_policy_id: Option<String>,
+    traffic_policy_version: Option<i64>,
+}
+
+impl UpdateTrafficPolicyInstanceRequestBuilder {
+    /// Sets the [`id`](struct.UpdateTrafficPolicyInstanceRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ttl`](struct.UpdateTrafficPolicyInstanceRequest.html#structfield.ttl) member.
+    pub fn ttl<T: Into<i64>>(mut self, value: T) -> Self {
+        self.ttl = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_id`](struct.UpdateTrafficPolicyInstanceRequest.html#structfield.traffic_policy_id) member.
+    pub fn traffic_policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.traffic_policy_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_policy_version`](struct.UpdateTrafficPolicyInstanceRequest.html#structfield.traffic_policy_version) member.
+    pub fn traffic_policy_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.traffic_policy_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTrafficPolicyInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateTrafficPolicyInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTrafficPolicyInstanceRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            ttl: self.ttl.ok_or(::rusoto_core::BuildError::missing("ttl"))?,
+            traffic_policy_id: self
+                .traffic_policy_id
+                .ok_or(::rusoto_core::BuildError::missing("traffic_policy_id"))?,
+            traffic_policy_version: self
+                .traffic_policy_version
+                .ok_or(::rusoto_core::BuildError::missing("traffic_policy_version"))?,
+        })
+    }
+}
+
 pub struct UpdateTrafficPolicyInstanceRequestSerializer;
 impl UpdateTrafficPolicyInstanceRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -7580,22 +12067,35 @@ impl UpdateTrafficPolicyInstanceRequestSerializer {
     }
 }
 /// <p>A complex type that contains information about the resource record sets that Amazon Route 53 created based on a specified traffic policy.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdateTrafficPolicyInstanceResponse {
     /// <p>A complex type that contains settings for the updated traffic policy instance.</p>
     pub traffic_policy_instance: TrafficPolicyInstance,
 }
 
-struct UpdateTrafficPolicyInstanceResponseDeserializer;
+pub(crate) struct UpdateTrafficPolicyInstanceResponseDeserializer;
 impl UpdateTrafficPolicyInstanceResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateTrafficPolicyInstanceResponse, XmlParseError> {
-        deserialize_elements::<_, UpdateTrafficPolicyInstanceResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            UpdateTrafficPolicyInstanceResponse {
+                traffic_policy_instance: TrafficPolicyInstance {
+                    hosted_zone_id: Default::default(),
+                    id: Default::default(),
+                    message: Default::default(),
+                    name: Default::default(),
+                    state: Default::default(),
+                    ttl: Default::default(),
+                    traffic_policy_id: Default::default(),
+                    traffic_policy_type: RRType::Unknown(String::new()),
+                    traffic_policy_version: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "TrafficPolicyInstance" => {
@@ -7612,10 +12112,13 @@ impl UpdateTrafficPolicyInstanceResponseDeserializer {
         )
     }
 }
-struct UsageCountDeserializer;
+pub(crate) struct UsageCountDeserializer;
 impl UsageCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -7628,13 +12131,16 @@ impl UsageCountDeserializer {
 pub struct VPC {
     pub vpc_id: Option<String>,
     /// <p>(Private hosted zones only) The region that an Amazon VPC was created in.</p>
-    pub vpc_region: Option<String>,
+    pub vpc_region: Option<VPCRegion>,
 }
 
-struct VPCDeserializer;
+pub(crate) struct VPCDeserializer;
 impl VPCDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<VPC, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<VPC, XmlParseError> {
         deserialize_elements::<_, VPC, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "VPCId" => {
@@ -7682,10 +12188,13 @@ impl VPCSerializer {
     }
 }
 
-struct VPCIdDeserializer;
+pub(crate) struct VPCIdDeserializer;
 impl VPCIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7714,12 +12223,15 @@ impl VPCIdSerializer {
     }
 }
 
-struct VPCRegionDeserializer;
+pub(crate) struct VPCRegionDeserializer;
 impl VPCRegionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<VPCRegion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7732,7 +12244,7 @@ impl VPCRegionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &VPCRegion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -7746,10 +12258,201 @@ impl VPCRegionSerializer {
     }
 }
 
-struct VPCsDeserializer;
+/// <p>(Private hosted zones only) The region that an Amazon VPC was created in.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VPCRegion {
+    /// <p><code>us-east-1</code></p>
+    UsEast1,
+    /// <p><code>us-east-2</code></p>
+    UsEast2,
+    /// <p><code>us-west-1</code></p>
+    UsWest1,
+    /// <p><code>us-west-2</code></p>
+    UsWest2,
+    /// <p><code>eu-west-1</code></p>
+    EuWest1,
+    /// <p><code>eu-west-2</code></p>
+    EuWest2,
+    /// <p><code>eu-west-3</code></p>
+    EuWest3,
+    /// <p><code>eu-central-1</code></p>
+    EuCentral1,
+    /// <p><code>eu-central-2</code></p>
+    EuCentral2,
+    /// <p><code>ap-east-1</code></p>
+    ApEast1,
+    /// <p><code>me-south-1</code></p>
+    MeSouth1,
+    /// <p><code>us-gov-west-1</code></p>
+    UsGovWest1,
+    /// <p><code>us-gov-east-1</code></p>
+    UsGovEast1,
+    /// <p><code>us-iso-east-1</code></p>
+    UsIsoEast1,
+    /// <p><code>us-iso-west-1</code></p>
+    UsIsoWest1,
+    /// <p><code>us-isob-east-1</code></p>
+    UsIsobEast1,
+    /// <p><code>me-central-1</code></p>
+    MeCentral1,
+    /// <p><code>ap-southeast-1</code></p>
+    ApSoutheast1,
+    /// <p><code>ap-southeast-2</code></p>
+    ApSoutheast2,
+    /// <p><code>ap-southeast-3</code></p>
+    ApSoutheast3,
+    /// <p><code>ap-south-1</code></p>
+    ApSouth1,
+    /// <p><code>ap-south-2</code></p>
+    ApSouth2,
+    /// <p><code>ap-northeast-1</code></p>
+    ApNortheast1,
+    /// <p><code>ap-northeast-2</code></p>
+    ApNortheast2,
+    /// <p><code>ap-northeast-3</code></p>
+    ApNortheast3,
+    /// <p><code>eu-north-1</code></p>
+    EuNorth1,
+    /// <p><code>sa-east-1</code></p>
+    SaEast1,
+    /// <p><code>ca-central-1</code></p>
+    CaCentral1,
+    /// <p><code>cn-north-1</code></p>
+    CnNorth1,
+    /// <p><code>af-south-1</code></p>
+    AfSouth1,
+    /// <p><code>eu-south-1</code></p>
+    EuSouth1,
+    /// <p><code>eu-south-2</code></p>
+    EuSouth2,
+    /// <p><code>ap-southeast-4</code></p>
+    ApSoutheast4,
+    /// <p><code>il-central-1</code></p>
+    IlCentral1,
+    /// <p><code>ca-west-1</code></p>
+    CaWest1,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VPCRegion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VPCRegion::UsEast1 => "us-east-1",
+            VPCRegion::UsEast2 => "us-east-2",
+            VPCRegion::UsWest1 => "us-west-1",
+            VPCRegion::UsWest2 => "us-west-2",
+            VPCRegion::EuWest1 => "eu-west-1",
+            VPCRegion::EuWest2 => "eu-west-2",
+            VPCRegion::EuWest3 => "eu-west-3",
+            VPCRegion::EuCentral1 => "eu-central-1",
+            VPCRegion::EuCentral2 => "eu-central-2",
+            VPCRegion::ApEast1 => "ap-east-1",
+            VPCRegion::MeSouth1 => "me-south-1",
+            VPCRegion::UsGovWest1 => "us-gov-west-1",
+            VPCRegion::UsGovEast1 => "us-gov-east-1",
+            VPCRegion::UsIsoEast1 => "us-iso-east-1",
+            VPCRegion::UsIsoWest1 => "us-iso-west-1",
+            VPCRegion::UsIsobEast1 => "us-isob-east-1",
+            VPCRegion::MeCentral1 => "me-central-1",
+            VPCRegion::ApSoutheast1 => "ap-southeast-1",
+            VPCRegion::ApSoutheast2 => "ap-southeast-2",
+            VPCRegion::ApSoutheast3 => "ap-southeast-3",
+            VPCRegion::ApSouth1 => "ap-south-1",
+            VPCRegion::ApSouth2 => "ap-south-2",
+            VPCRegion::ApNortheast1 => "ap-northeast-1",
+            VPCRegion::ApNortheast2 => "ap-northeast-2",
+            VPCRegion::ApNortheast3 => "ap-northeast-3",
+            VPCRegion::EuNorth1 => "eu-north-1",
+            VPCRegion::SaEast1 => "sa-east-1",
+            VPCRegion::CaCentral1 => "ca-central-1",
+            VPCRegion::CnNorth1 => "cn-north-1",
+            VPCRegion::AfSouth1 => "af-south-1",
+            VPCRegion::EuSouth1 => "eu-south-1",
+            VPCRegion::EuSouth2 => "eu-south-2",
+            VPCRegion::ApSoutheast4 => "ap-southeast-4",
+            VPCRegion::IlCentral1 => "il-central-1",
+            VPCRegion::CaWest1 => "ca-west-1",
+            VPCRegion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VPCRegion {
+    fn from(value: &'a str) -> VPCRegion {
+        match value {
+            "us-east-1" => VPCRegion::UsEast1,
+            "us-east-2" => VPCRegion::UsEast2,
+            "us-west-1" => VPCRegion::UsWest1,
+            "us-west-2" => VPCRegion::UsWest2,
+            "eu-west-1" => VPCRegion::EuWest1,
+            "eu-west-2" => VPCRegion::EuWest2,
+            "eu-west-3" => VPCRegion::EuWest3,
+            "eu-central-1" => VPCRegion::EuCentral1,
+            "eu-central-2" => VPCRegion::EuCentral2,
+            "ap-east-1" => VPCRegion::ApEast1,
+            "me-south-1" => VPCRegion::MeSouth1,
+            "us-gov-west-1" => VPCRegion::UsGovWest1,
+            "us-gov-east-1" => VPCRegion::UsGovEast1,
+            "us-iso-east-1" => VPCRegion::UsIsoEast1,
+            "us-iso-west-1" => VPCRegion::UsIsoWest1,
+            "us-isob-east-1" => VPCRegion::UsIsobEast1,
+            "me-central-1" => VPCRegion::MeCentral1,
+            "ap-southeast-1" => VPCRegion::ApSoutheast1,
+            "ap-southeast-2" => VPCRegion::ApSoutheast2,
+            "ap-southeast-3" => VPCRegion::ApSoutheast3,
+            "ap-south-1" => VPCRegion::ApSouth1,
+            "ap-south-2" => VPCRegion::ApSouth2,
+            "ap-northeast-1" => VPCRegion::ApNortheast1,
+            "ap-northeast-2" => VPCRegion::ApNortheast2,
+            "ap-northeast-3" => VPCRegion::ApNortheast3,
+            "eu-north-1" => VPCRegion::EuNorth1,
+            "sa-east-1" => VPCRegion::SaEast1,
+            "ca-central-1" => VPCRegion::CaCentral1,
+            "cn-north-1" => VPCRegion::CnNorth1,
+            "af-south-1" => VPCRegion::AfSouth1,
+            "eu-south-1" => VPCRegion::EuSouth1,
+            "eu-south-2" => VPCRegion::EuSouth2,
+            "ap-southeast-4" => VPCRegion::ApSoutheast4,
+            "il-central-1" => VPCRegion::IlCentral1,
+            "ca-west-1" => VPCRegion::CaWest1,
+            _ => VPCRegion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VPCRegion {
+    fn from(value: String) -> VPCRegion {
+        VPCRegion::from(value.as_str())
+    }
+}
+
+impl From<VPCRegion> for String {
+    fn from(value: VPCRegion) -> String {
+        match value {
+            VPCRegion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VPCRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VPCRegion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct VPCsDeserializer;
 impl VPCsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<VPC>, XmlParseError> {
@@ -11843,40 +16546,465 @@ pub trait Route53 {
         input: UpdateTrafficPolicyInstanceRequest,
     ) -> RusotoFuture<UpdateTrafficPolicyInstanceResponse, UpdateTrafficPolicyInstanceError>;
 }
-/// A client for the Route 53 API.
-#[derive(Clone)]
-pub struct Route53Client {
-    client: Client,
-    region: region::Region,
-}
-
-impl Route53Client {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> Route53Client {
-        Self::new_with_client(Client::shared(), region)
+impl<T: ?Sized + Route53> Route53 for ::std::sync::Arc<T> {
+    fn associate_vpc_with_hosted_zone(
+        &self,
+        input: AssociateVPCWithHostedZoneRequest,
+    ) -> RusotoFuture<AssociateVPCWithHostedZoneResponse, AssociateVPCWithHostedZoneError> {
+        (**self).associate_vpc_with_hosted_zone(input)
     }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> Route53Client
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
+    fn change_resource_record_sets(
+        &self,
+        input: ChangeResourceRecordSetsRequest,
+    ) -> RusotoFuture<ChangeResourceRecordSetsResponse, ChangeResourceRecordSetsError> {
+        (**self).change_resource_record_sets(input)
     }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> Route53Client {
-        Route53Client { client, region }
+    fn change_tags_for_resource(
+        &self,
+        input: ChangeTagsForResourceRequest,
+    ) -> RusotoFuture<ChangeTagsForResourceResponse, ChangeTagsForResourceError> {
+        (**self).change_tags_for_resource(input)
+    }
+    fn create_health_check(
+        &self,
+        input: CreateHealthCheckRequest,
+    ) -> RusotoFuture<CreateHealthCheckResponse, CreateHealthCheckError> {
+        (**self).create_health_check(input)
+    }
+    fn create_hosted_zone(
+        &self,
+        input: CreateHostedZoneRequest,
+    ) -> RusotoFuture<CreateHostedZoneResponse, CreateHostedZoneError> {
+        (**self).create_hosted_zone(input)
+    }
+    fn create_query_logging_config(
+        &self,
+        input: CreateQueryLoggingConfigRequest,
+    ) -> RusotoFuture<CreateQueryLoggingConfigResponse, CreateQueryLoggingConfigError> {
+        (**self).create_query_logging_config(input)
+    }
+    fn create_reusable_delegation_set(
+        &self,
+        input: CreateReusableDelegationSetRequest,
+    ) -> RusotoFuture<CreateReusableDelegationSetResponse, CreateReusableDelegationSetError> {
+        (**self).create_reusable_delegation_set(input)
+    }
+    fn create_traffic_policy(
+        &self,
+        input: CreateTrafficPolicyRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyResponse, CreateTrafficPolicyError> {
+        (**self).create_traffic_policy(input)
+    }
+    fn create_traffic_policy_instance(
+        &self,
+        input: CreateTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyInstanceResponse, CreateTrafficPolicyInstanceError> {
+        (**self).create_traffic_policy_instance(input)
+    }
+    fn create_traffic_policy_version(
+        &self,
+        input: CreateTrafficPolicyVersionRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyVersionResponse, CreateTrafficPolicyVersionError> {
+        (**self).create_traffic_policy_version(input)
+    }
+    fn create_vpc_association_authorization(
+        &self,
+        input: CreateVPCAssociationAuthorizationRequest,
+    ) -> RusotoFuture<
+        CreateVPCAssociationAuthorizationResponse,
+        CreateVPCAssociationAuthorizationError,
+    > {
+        (**self).create_vpc_association_authorization(input)
+    }
+    fn delete_health_check(
+        &self,
+        input: DeleteHealthCheckRequest,
+    ) -> RusotoFuture<DeleteHealthCheckResponse, DeleteHealthCheckError> {
+        (**self).delete_health_check(input)
+    }
+    fn delete_hosted_zone(
+        &self,
+        input: DeleteHostedZoneRequest,
+    ) -> RusotoFuture<DeleteHostedZoneResponse, DeleteHostedZoneError> {
+        (**self).delete_hosted_zone(input)
+    }
+    fn delete_query_logging_config(
+        &self,
+        input: DeleteQueryLoggingConfigRequest,
+    ) -> RusotoFuture<DeleteQueryLoggingConfigResponse, DeleteQueryLoggingConfigError> {
+        (**self).delete_query_logging_config(input)
+    }
+    fn delete_reusable_delegation_set(
+        &self,
+        input: DeleteReusableDelegationSetRequest,
+    ) -> RusotoFuture<DeleteReusableDelegationSetResponse, DeleteReusableDelegationSetError> {
+        (**self).delete_reusable_delegation_set(input)
+    }
+    fn delete_traffic_policy(
+        &self,
+        input: DeleteTrafficPolicyRequest,
+    ) -> RusotoFuture<DeleteTrafficPolicyResponse, DeleteTrafficPolicyError> {
+        (**self).delete_traffic_policy(input)
+    }
+    fn delete_traffic_policy_instance(
+        &self,
+        input: DeleteTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<DeleteTrafficPolicyInstanceResponse, DeleteTrafficPolicyInstanceError> {
+        (**self).delete_traffic_policy_instance(input)
+    }
+    fn delete_vpc_association_authorization(
+        &self,
+        input: DeleteVPCAssociationAuthorizationRequest,
+    ) -> RusotoFuture<
+        DeleteVPCAssociationAuthorizationResponse,
+        DeleteVPCAssociationAuthorizationError,
+    > {
+        (**self).delete_vpc_association_authorization(input)
+    }
+    fn disassociate_vpc_from_hosted_zone(
+        &self,
+        input: DisassociateVPCFromHostedZoneRequest,
+    ) -> RusotoFuture<DisassociateVPCFromHostedZoneResponse, DisassociateVPCFromHostedZoneError>
+    {
+        (**self).disassociate_vpc_from_hosted_zone(input)
+    }
+    fn get_account_limit(
+        &self,
+        input: GetAccountLimitRequest,
+    ) -> RusotoFuture<GetAccountLimitResponse, GetAccountLimitError> {
+        (**self).get_account_limit(input)
+    }
+    fn get_change(
+        &self,
+        input: GetChangeRequest,
+    ) -> RusotoFuture<GetChangeResponse, GetChangeError> {
+        (**self).get_change(input)
+    }
+    fn get_checker_ip_ranges(
+        &self,
+        input: GetCheckerIpRangesRequest,
+    ) -> RusotoFuture<GetCheckerIpRangesResponse, GetCheckerIpRangesError> {
+        (**self).get_checker_ip_ranges(input)
+    }
+    fn get_geo_location(
+        &self,
+        input: GetGeoLocationRequest,
+    ) -> RusotoFuture<GetGeoLocationResponse, GetGeoLocationError> {
+        (**self).get_geo_location(input)
+    }
+    fn get_health_check(
+        &self,
+        input: GetHealthCheckRequest,
+    ) -> RusotoFuture<GetHealthCheckResponse, GetHealthCheckError> {
+        (**self).get_health_check(input)
+    }
+    fn get_health_check_count(
+        &self,
+        input: GetHealthCheckCountRequest,
+    ) -> RusotoFuture<GetHealthCheckCountResponse, GetHealthCheckCountError> {
+        (**self).get_health_check_count(input)
+    }
+    fn get_health_check_last_failure_reason(
+        &self,
+        input: GetHealthCheckLastFailureReasonRequest,
+    ) -> RusotoFuture<GetHealthCheckLastFailureReasonResponse, GetHealthCheckLastFailureReasonError>
+    {
+        (**self).get_health_check_last_failure_reason(input)
+    }
+    fn get_health_check_status(
+        &self,
+        input: GetHealthCheckStatusRequest,
+    ) -> RusotoFuture<GetHealthCheckStatusResponse, GetHealthCheckStatusError> {
+        (**self).get_health_check_status(input)
+    }
+    fn get_hosted_zone(
+        &self,
+        input: GetHostedZoneRequest,
+    ) -> RusotoFuture<GetHostedZoneResponse, GetHostedZoneError> {
+        (**self).get_hosted_zone(input)
+    }
+    fn get_hosted_zone_count(
+        &self,
+        input: GetHostedZoneCountRequest,
+    ) -> RusotoFuture<GetHostedZoneCountResponse, GetHostedZoneCountError> {
+        (**self).get_hosted_zone_count(input)
+    }
+    fn get_hosted_zone_limit(
+        &self,
+        input: GetHostedZoneLimitRequest,
+    ) -> RusotoFuture<GetHostedZoneLimitResponse, GetHostedZoneLimitError> {
+        (**self).get_hosted_zone_limit(input)
+    }
+    fn get_query_logging_config(
+        &self,
+        input: GetQueryLoggingConfigRequest,
+    ) -> RusotoFuture<GetQueryLoggingConfigResponse, GetQueryLoggingConfigError> {
+        (**self).get_query_logging_config(input)
+    }
+    fn get_reusable_delegation_set(
+        &self,
+        input: GetReusableDelegationSetRequest,
+    ) -> RusotoFuture<GetReusableDelegationSetResponse, GetReusableDelegationSetError> {
+        (**self).get_reusable_delegation_set(input)
+    }
+    fn get_reusable_delegation_set_limit(
+        &self,
+        input: GetReusableDelegationSetLimitRequest,
+    ) -> RusotoFuture<GetReusableDelegationSetLimitResponse, GetReusableDelegationSetLimitError>
+    {
+        (**self).get_reusable_delegation_set_limit(input)
+    }
+    fn get_traffic_policy(
+        &self,
+        input: GetTrafficPolicyRequest,
+    ) -> RusotoFuture<GetTrafficPolicyResponse, GetTrafficPolicyError> {
+        (**self).get_traffic_policy(input)
+    }
+    fn get_traffic_policy_instance(
+        &self,
+        input: GetTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<GetTrafficPolicyInstanceResponse, GetTrafficPolicyInstanceError> {
+        (**self).get_traffic_policy_instance(input)
+    }
+    fn get_traffic_policy_instance_count(
+        &self,
+        input: GetTrafficPolicyInstanceCountRequest,
+    ) -> RusotoFuture<GetTrafficPolicyInstanceCountResponse, GetTrafficPolicyInstanceCountError>
+    {
+        (**self).get_traffic_policy_instance_count(input)
+    }
+    fn list_geo_locations(
+        &self,
+        input: ListGeoLocationsRequest,
+    ) -> RusotoFuture<ListGeoLocationsResponse, ListGeoLocationsError> {
+        (**self).list_geo_locations(input)
+    }
+    fn list_health_checks(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> RusotoFuture<ListHealthChecksResponse, ListHealthChecksError> {
+        (**self).list_health_checks(input)
+    }
+    fn list_hosted_zones(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> RusotoFuture<ListHostedZonesResponse, ListHostedZonesError> {
+        (**self).list_hosted_zones(input)
+    }
+    fn list_hosted_zones_by_name(
+        &self,
+        input: ListHostedZonesByNameRequest,
+    ) -> RusotoFuture<ListHostedZonesByNameResponse, ListHostedZonesByNameError> {
+        (**self).list_hosted_zones_by_name(input)
+    }
+    fn list_query_logging_configs(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> RusotoFuture<ListQueryLoggingConfigsResponse, ListQueryLoggingConfigsError> {
+        (**self).list_query_logging_configs(input)
+    }
+    fn list_resource_record_sets(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> RusotoFuture<ListResourceRecordSetsResponse, ListResourceRecordSetsError> {
+        (**self).list_resource_record_sets(input)
+    }
+    fn list_reusable_delegation_sets(
+        &self,
+        input: ListReusableDelegationSetsRequest,
+    ) -> RusotoFuture<ListReusableDelegationSetsResponse, ListReusableDelegationSetsError> {
+        (**self).list_reusable_delegation_sets(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_tags_for_resources(
+        &self,
+        input: ListTagsForResourcesRequest,
+    ) -> RusotoFuture<ListTagsForResourcesResponse, ListTagsForResourcesError> {
+        (**self).list_tags_for_resources(input)
+    }
+    fn list_traffic_policies(
+        &self,
+        input: ListTrafficPoliciesRequest,
+    ) -> RusotoFuture<ListTrafficPoliciesResponse, ListTrafficPoliciesError> {
+        (**self).list_traffic_policies(input)
+    }
+    fn list_traffic_policy_instances(
+        &self,
+        input: ListTrafficPolicyInstancesRequest,
+    ) -> RusotoFuture<ListTrafficPolicyInstancesResponse, ListTrafficPolicyInstancesError> {
+        (**self).list_traffic_policy_instances(input)
+    }
+    fn list_traffic_policy_instances_by_hosted_zone(
+        &self,
+        input: ListTrafficPolicyInstancesByHostedZoneRequest,
+    ) -> RusotoFuture<
+        ListTrafficPolicyInstancesByHostedZoneResponse,
+        ListTrafficPolicyInstancesByHostedZoneError,
+    > {
+        (**self).list_traffic_policy_instances_by_hosted_zone(input)
+    }
+    fn list_traffic_policy_instances_by_policy(
+        &self,
+        input: ListTrafficPolicyInstancesByPolicyRequest,
+    ) -> RusotoFuture<
+        ListTrafficPolicyInstancesByPolicyResponse,
+        ListTrafficPolicyInstancesByPolicyError,
+    > {
+        (**self).list_traffic_policy_instances_by_policy(input)
+    }
+    fn list_traffic_policy_versions(
+        &self,
+        input: ListTrafficPolicyVersionsRequest,
+    ) -> RusotoFuture<ListTrafficPolicyVersionsResponse, ListTrafficPolicyVersionsError> {
+        (**self).list_traffic_policy_versions(input)
+    }
+    fn list_vpc_association_authorizations(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> RusotoFuture<ListVPCAssociationAuthorizationsResponse, ListVPCAssociationAuthorizationsError>
+    {
+        (**self).list_vpc_association_authorizations(input)
+    }
+    fn test_dns_answer(
+        &self,
+        input: TestDNSAnswerRequest,
+    ) -> RusotoFuture<TestDNSAnswerResponse, TestDNSAnswerError> {
+        (**self).test_dns_answer(input)
+    }
+    fn update_health_check(
+        &self,
+        input: UpdateHealthCheckRequest,
+    ) -> RusotoFuture<UpdateHealthCheckResponse, UpdateHealthCheckError> {
+        (**self).update_health_check(input)
+    }
+    fn update_hosted_zone_comment(
+        &self,
+        input: UpdateHostedZoneCommentRequest,
+    ) -> RusotoFuture<UpdateHostedZoneCommentResponse, UpdateHostedZoneCommentError> {
+        (**self).update_hosted_zone_comment(input)
+    }
+    fn update_traffic_policy_comment(
+        &self,
+        input: UpdateTrafficPolicyCommentRequest,
+    ) -> RusotoFuture<UpdateTrafficPolicyCommentResponse, UpdateTrafficPolicyCommentError> {
+        (**self).update_traffic_policy_comment(input)
+    }
+    fn update_traffic_policy_instance(
+        &self,
+        input: UpdateTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<UpdateTrafficPolicyInstanceResponse, UpdateTrafficPolicyInstanceError> {
+        (**self).update_traffic_policy_instance(input)
+    }
+}
+/// The error codes Route 53 retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["Throttling"],
+        transient: &[],
+    };
+
+/// A client for the Route 53 API.
+#[derive(Clone)]
+pub struct Route53Client {
+    client: Client,
+    region: region::Region,
+}
+
+impl Route53Client {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Route53Client {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ROUTE53`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> Route53Client {
+        Self::new(region::Region::from_env_for_service("ROUTE53"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `route53`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> Route53Client {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("route53", "ROUTE53"),
+        )
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Route53Client
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> Route53Client {
+        Route53Client {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "route53")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "route53", "route53", "ROUTE53")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Route53` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Route53 + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for Route53Client {
+    fn service_name(&self) -> &'static str {
+        "Route 53"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "route53"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> Route53Client {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -11916,7 +17044,14 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = AssociateVPCWithHostedZoneResponse::default();
+                    result = AssociateVPCWithHostedZoneResponse {
+                        change_info: ChangeInfo {
+                            comment: Default::default(),
+                            id: Default::default(),
+                            status: ChangeStatus::Unknown(String::new()),
+                            submitted_at: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -11972,7 +17107,14 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = ChangeResourceRecordSetsResponse::default();
+                    result = ChangeResourceRecordSetsResponse {
+                        change_info: ChangeInfo {
+                            comment: Default::default(),
+                            id: Default::default(),
+                            status: ChangeStatus::Unknown(String::new()),
+                            submitted_at: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -12081,7 +17223,35 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = CreateHealthCheckResponse::default();
+                    result = CreateHealthCheckResponse {
+                        health_check: HealthCheck {
+                            caller_reference: Default::default(),
+                            cloud_watch_alarm_configuration: Default::default(),
+                            health_check_config: HealthCheckConfig {
+                                alarm_identifier: Default::default(),
+                                child_health_checks: Default::default(),
+                                disabled: Default::default(),
+                                enable_sni: Default::default(),
+                                failure_threshold: Default::default(),
+                                fully_qualified_domain_name: Default::default(),
+                                health_threshold: Default::default(),
+                                ip_address: Default::default(),
+                                insufficient_data_health_status: Default::default(),
+                                inverted: Default::default(),
+                                measure_latency: Default::default(),
+                                port: Default::default(),
+                                regions: Default::default(),
+                                request_interval: Default::default(),
+                                resource_path: Default::default(),
+                                search_string: Default::default(),
+                                type_: HealthCheckType::Unknown(String::new()),
+                            },
+                            health_check_version: Default::default(),
+                            id: Default::default(),
+                            linked_service: Default::default(),
+                        },
+                        location: Default::default(),
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -12135,7 +17305,18 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = CreateHostedZoneResponse::default();
+                    result = CreateHostedZoneResponse {
+                        change_info: ChangeInfo {
+                            comment: Default::default(),
+                            id: Default::default(),
+                            status: ChangeStatus::Unknown(String::new()),
+                            submitted_at: Default::default(),
+                        },
+                        delegation_set: Default::default(),
+                        hosted_zone: Default::default(),
+                        location: Default::default(),
+                        vpc: Default::default(),
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -12290,7 +17471,17 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = CreateTrafficPolicyResponse::default();
+                    result = CreateTrafficPolicyResponse {
+                        location: Default::default(),
+                        traffic_policy: TrafficPolicy {
+                            comment: Default::default(),
+                            document: Default::default(),
+                            id: Default::default(),
+                            name: Default::default(),
+                            type_: RRType::Unknown(String::new()),
+                            version: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -12341,7 +17532,20 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = CreateTrafficPolicyInstanceResponse::default();
+                    result = CreateTrafficPolicyInstanceResponse {
+                        location: Default::default(),
+                        traffic_policy_instance: TrafficPolicyInstance {
+                            hosted_zone_id: Default::default(),
+                            id: Default::default(),
+                            message: Default::default(),
+                            name: Default::default(),
+                            state: Default::default(),
+                            ttl: Default::default(),
+                            traffic_policy_id: Default::default(),
+                            traffic_policy_type: RRType::Unknown(String::new()),
+                            traffic_policy_version: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -12394,7 +17598,17 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = CreateTrafficPolicyVersionResponse::default();
+                    result = CreateTrafficPolicyVersionResponse {
+                        location: Default::default(),
+                        traffic_policy: TrafficPolicy {
+                            comment: Default::default(),
+                            document: Default::default(),
+                            id: Default::default(),
+                            name: Default::default(),
+                            type_: RRType::Unknown(String::new()),
+                            version: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -12550,7 +17764,14 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = DeleteHostedZoneResponse::default();
+                    result = DeleteHostedZoneResponse {
+                        change_info: ChangeInfo {
+                            comment: Default::default(),
+                            id: Default::default(),
+                            status: ChangeStatus::Unknown(String::new()),
+                            submitted_at: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -12844,7 +18065,14 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = DisassociateVPCFromHostedZoneResponse::default();
+                    result = DisassociateVPCFromHostedZoneResponse {
+                        change_info: ChangeInfo {
+                            comment: Default::default(),
+                            id: Default::default(),
+                            status: ChangeStatus::Unknown(String::new()),
+                            submitted_at: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -12890,7 +18118,13 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetAccountLimitResponse::default();
+                    result = GetAccountLimitResponse {
+                        count: Default::default(),
+                        limit: AccountLimit {
+                            type_: AccountLimitType::Unknown(String::new()),
+                            value: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -12936,7 +18170,14 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetChangeResponse::default();
+                    result = GetChangeResponse {
+                        change_info: ChangeInfo {
+                            comment: Default::default(),
+                            id: Default::default(),
+                            status: ChangeStatus::Unknown(String::new()),
+                            submitted_at: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -13083,7 +18324,34 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetHealthCheckResponse::default();
+                    result = GetHealthCheckResponse {
+                        health_check: HealthCheck {
+                            caller_reference: Default::default(),
+                            cloud_watch_alarm_configuration: Default::default(),
+                            health_check_config: HealthCheckConfig {
+                                alarm_identifier: Default::default(),
+                                child_health_checks: Default::default(),
+                                disabled: Default::default(),
+                                enable_sni: Default::default(),
+                                failure_threshold: Default::default(),
+                                fully_qualified_domain_name: Default::default(),
+                                health_threshold: Default::default(),
+                                ip_address: Default::default(),
+                                insufficient_data_health_status: Default::default(),
+                                inverted: Default::default(),
+                                measure_latency: Default::default(),
+                                port: Default::default(),
+                                regions: Default::default(),
+                                request_interval: Default::default(),
+                                resource_path: Default::default(),
+                                search_string: Default::default(),
+                                type_: HealthCheckType::Unknown(String::new()),
+                            },
+                            health_check_version: Default::default(),
+                            id: Default::default(),
+                            linked_service: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -13357,7 +18625,13 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetHostedZoneLimitResponse::default();
+                    result = GetHostedZoneLimitResponse {
+                        count: Default::default(),
+                        limit: HostedZoneLimit {
+                            type_: HostedZoneLimitType::Unknown(String::new()),
+                            value: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -13485,7 +18759,13 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetReusableDelegationSetLimitResponse::default();
+                    result = GetReusableDelegationSetLimitResponse {
+                        count: Default::default(),
+                        limit: ReusableDelegationSetLimit {
+                            type_: ReusableDelegationSetLimitType::Unknown(String::new()),
+                            value: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -13535,7 +18815,16 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetTrafficPolicyResponse::default();
+                    result = GetTrafficPolicyResponse {
+                        traffic_policy: TrafficPolicy {
+                            comment: Default::default(),
+                            document: Default::default(),
+                            id: Default::default(),
+                            name: Default::default(),
+                            type_: RRType::Unknown(String::new()),
+                            version: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -13578,7 +18867,19 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetTrafficPolicyInstanceResponse::default();
+                    result = GetTrafficPolicyInstanceResponse {
+                        traffic_policy_instance: TrafficPolicyInstance {
+                            hosted_zone_id: Default::default(),
+                            id: Default::default(),
+                            message: Default::default(),
+                            name: Default::default(),
+                            state: Default::default(),
+                            ttl: Default::default(),
+                            traffic_policy_id: Default::default(),
+                            traffic_policy_type: RRType::Unknown(String::new()),
+                            traffic_policy_version: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -14511,7 +19812,14 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = TestDNSAnswerResponse::default();
+                    result = TestDNSAnswerResponse {
+                        nameserver: Default::default(),
+                        protocol: Default::default(),
+                        record_data: Default::default(),
+                        record_name: Default::default(),
+                        record_type: RRType::Unknown(String::new()),
+                        response_code: Default::default(),
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -14569,7 +19877,34 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = UpdateHealthCheckResponse::default();
+                    result = UpdateHealthCheckResponse {
+                        health_check: HealthCheck {
+                            caller_reference: Default::default(),
+                            cloud_watch_alarm_configuration: Default::default(),
+                            health_check_config: HealthCheckConfig {
+                                alarm_identifier: Default::default(),
+                                child_health_checks: Default::default(),
+                                disabled: Default::default(),
+                                enable_sni: Default::default(),
+                                failure_threshold: Default::default(),
+                                fully_qualified_domain_name: Default::default(),
+                                health_threshold: Default::default(),
+                                ip_address: Default::default(),
+                                insufficient_data_health_status: Default::default(),
+                                inverted: Default::default(),
+                                measure_latency: Default::default(),
+                                port: Default::default(),
+                                regions: Default::default(),
+                                request_interval: Default::default(),
+                                resource_path: Default::default(),
+                                search_string: Default::default(),
+                                type_: HealthCheckType::Unknown(String::new()),
+                            },
+                            health_check_version: Default::default(),
+                            id: Default::default(),
+                            linked_service: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -14677,7 +20012,16 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = UpdateTrafficPolicyCommentResponse::default();
+                    result = UpdateTrafficPolicyCommentResponse {
+                        traffic_policy: TrafficPolicy {
+                            comment: Default::default(),
+                            document: Default::default(),
+                            id: Default::default(),
+                            name: Default::default(),
+                            type_: RRType::Unknown(String::new()),
+                            version: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -14729,7 +20073,19 @@ impl Route53 for Route53Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = UpdateTrafficPolicyInstanceResponse::default();
+                    result = UpdateTrafficPolicyInstanceResponse {
+                        traffic_policy_instance: TrafficPolicyInstance {
+                            hosted_zone_id: Default::default(),
+                            id: Default::default(),
+                            message: Default::default(),
+                            name: Default::default(),
+                            state: Default::default(),
+                            ttl: Default::default(),
+                            traffic_policy_id: Default::default(),
+                            traffic_policy_type: RRType::Unknown(String::new()),
+                            traffic_policy_version: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
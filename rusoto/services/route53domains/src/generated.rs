@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Information for one billing record.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -29,8 +30,9 @@ use serde_json;
 pub struct BillingRecord {
     /// <p>The date that the operation was billed, in Unix format.</p>
     #[serde(rename = "BillDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bill_date: Option<f64>,
+    pub bill_date: Option<EpochTimestamp>,
     /// <p>The name of the domain that the billing record applies to. If the domain name contains characters other than a-z, 0-9, and - (hyphen), such as an internationalized domain name, then this value is in Punycode. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DomainNameFormat.html">DNS Domain Name Format</a> in the <i>Amazon Route 53 Developer Guidezzz</i>.</p>
     #[serde(rename = "DomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -42,7 +44,7 @@ pub struct BillingRecord {
     /// <p>The operation that you were charged for.</p>
     #[serde(rename = "Operation")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operation: Option<String>,
+    pub operation: Option<OperationType>,
     /// <p>The price that you were charged for the operation, in US dollars.</p> <p>Example value: 12.0</p>
     #[serde(rename = "Price")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,13 +63,53 @@ pub struct CheckDomainAvailabilityRequest {
     pub idn_lang_code: Option<String>,
 }
 
+impl CheckDomainAvailabilityRequest {
+    /// Returns a builder for `CheckDomainAvailabilityRequest`, checking its required members when built.
+    pub fn builder() -> CheckDomainAvailabilityRequestBuilder {
+        CheckDomainAvailabilityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CheckDomainAvailabilityRequest`](struct.CheckDomainAvailabilityRequest.html).
+#[derive(Default, Debug)]
+pub struct CheckDomainAvailabilityRequestBuilder {
+    domain_name: Option<String>,
+    idn_lang_code: Option<String>,
+}
+
+impl CheckDomainAvailabilityRequestBuilder {
+    /// Sets the [`domain_name`](struct.CheckDomainAvailabilityRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idn_lang_code`](struct.CheckDomainAvailabilityRequest.html#structfield.idn_lang_code) member.
+    pub fn idn_lang_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.idn_lang_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `CheckDomainAvailabilityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CheckDomainAvailabilityRequest, ::rusoto_core::BuildError> {
+        Ok(CheckDomainAvailabilityRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            idn_lang_code: self.idn_lang_code,
+        })
+    }
+}
+
 /// <p>The CheckDomainAvailability response includes the following elements.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CheckDomainAvailabilityResponse {
     /// <p><p>Whether the domain name is available for registering.</p> <note> <p>You can register only domains designated as <code>AVAILABLE</code>.</p> </note> <p>Valid values:</p> <dl> <dt>AVAILABLE</dt> <dd> <p>The domain name is available.</p> </dd> <dt>AVAILABLE<em>RESERVED</dt> <dd> <p>The domain name is reserved under specific conditions.</p> </dd> <dt>AVAILABLE</em>PREORDER</dt> <dd> <p>The domain name is available and can be preordered.</p> </dd> <dt>DONT<em>KNOW</dt> <dd> <p>The TLD registry didn&#39;t reply with a definitive answer about whether the domain name is available. Amazon Route 53 can return this response for a variety of reasons, for example, the registry is performing maintenance. Try again later.</p> </dd> <dt>PENDING</dt> <dd> <p>The TLD registry didn&#39;t return a response in the expected amount of time. When the response is delayed, it usually takes just a few extra seconds. You can resubmit the request immediately.</p> </dd> <dt>RESERVED</dt> <dd> <p>The domain name has been reserved for another person or organization.</p> </dd> <dt>UNAVAILABLE</dt> <dd> <p>The domain name is not available.</p> </dd> <dt>UNAVAILABLE</em>PREMIUM</dt> <dd> <p>The domain name is not available.</p> </dd> <dt>UNAVAILABLE_RESTRICTED</dt> <dd> <p>The domain name is forbidden.</p> </dd> </dl></p>
     #[serde(rename = "Availability")]
-    pub availability: String,
+    pub availability: DomainAvailability,
 }
 
 /// <p>The CheckDomainTransferability request contains the following elements.</p>
@@ -82,6 +124,46 @@ pub struct CheckDomainTransferabilityRequest {
     pub domain_name: String,
 }
 
+impl CheckDomainTransferabilityRequest {
+    /// Returns a builder for `CheckDomainTransferabilityRequest`, checking its required members when built.
+    pub fn builder() -> CheckDomainTransferabilityRequestBuilder {
+        CheckDomainTransferabilityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CheckDomainTransferabilityRequest`](struct.CheckDomainTransferabilityRequest.html).
+#[derive(Default, Debug)]
+pub struct CheckDomainTransferabilityRequestBuilder {
+    auth_code: Option<String>,
+    domain_name: Option<String>,
+}
+
+impl CheckDomainTransferabilityRequestBuilder {
+    /// Sets the [`auth_code`](struct.CheckDomainTransferabilityRequest.html#structfield.auth_code) member.
+    pub fn auth_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.auth_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.CheckDomainTransferabilityRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CheckDomainTransferabilityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CheckDomainTransferabilityRequest, ::rusoto_core::BuildError> {
+        Ok(CheckDomainTransferabilityRequest {
+            auth_code: self.auth_code,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// <p>The CheckDomainTransferability response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -109,11 +191,11 @@ pub struct ContactDetail {
     /// <p>Indicates whether the contact is a person, company, association, or public organization. If you choose an option other than <code>PERSON</code>, you must enter an organization name, and you can't enable privacy protection for the contact.</p>
     #[serde(rename = "ContactType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact_type: Option<String>,
+    pub contact_type: Option<ContactType>,
     /// <p>Code for the country of the contact's address.</p>
     #[serde(rename = "CountryCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    pub country_code: Option<CountryCode>,
     /// <p>Email address of the contact.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -152,6 +234,1158 @@ pub struct ContactDetail {
     pub zip_code: Option<String>,
 }
 
+/// <p>Indicates whether the contact is a person, company, association, or public organization. If you choose an option other than <code>PERSON</code>, you must enter an organization name, and you can't enable privacy protection for the contact.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContactType {
+    /// <p><code>PERSON</code></p>
+    Person,
+    /// <p><code>COMPANY</code></p>
+    Company,
+    /// <p><code>ASSOCIATION</code></p>
+    Association,
+    /// <p><code>PUBLIC_BODY</code></p>
+    PublicBody,
+    /// <p><code>RESELLER</code></p>
+    Reseller,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContactType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContactType::Person => "PERSON",
+            ContactType::Company => "COMPANY",
+            ContactType::Association => "ASSOCIATION",
+            ContactType::PublicBody => "PUBLIC_BODY",
+            ContactType::Reseller => "RESELLER",
+            ContactType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContactType {
+    fn from(value: &'a str) -> ContactType {
+        match value {
+            "PERSON" => ContactType::Person,
+            "COMPANY" => ContactType::Company,
+            "ASSOCIATION" => ContactType::Association,
+            "PUBLIC_BODY" => ContactType::PublicBody,
+            "RESELLER" => ContactType::Reseller,
+            _ => ContactType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContactType {
+    fn from(value: String) -> ContactType {
+        ContactType::from(value.as_str())
+    }
+}
+
+impl From<ContactType> for String {
+    fn from(value: ContactType) -> String {
+        match value {
+            ContactType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContactType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ContactType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContactType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContactType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContactType::from(value))
+    }
+}
+
+/// <p>Code for the country of the contact's address.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CountryCode {
+    /// <p><code>AC</code></p>
+    Ac,
+    /// <p><code>AD</code></p>
+    Ad,
+    /// <p><code>AE</code></p>
+    Ae,
+    /// <p><code>AF</code></p>
+    Af,
+    /// <p><code>AG</code></p>
+    Ag,
+    /// <p><code>AI</code></p>
+    Ai,
+    /// <p><code>AL</code></p>
+    Al,
+    /// <p><code>AM</code></p>
+    Am,
+    /// <p><code>AN</code></p>
+    An,
+    /// <p><code>AO</code></p>
+    Ao,
+    /// <p><code>AQ</code></p>
+    Aq,
+    /// <p><code>AR</code></p>
+    Ar,
+    /// <p><code>AS</code></p>
+    As,
+    /// <p><code>AT</code></p>
+    At,
+    /// <p><code>AU</code></p>
+    Au,
+    /// <p><code>AW</code></p>
+    Aw,
+    /// <p><code>AX</code></p>
+    Ax,
+    /// <p><code>AZ</code></p>
+    Az,
+    /// <p><code>BA</code></p>
+    Ba,
+    /// <p><code>BB</code></p>
+    Bb,
+    /// <p><code>BD</code></p>
+    Bd,
+    /// <p><code>BE</code></p>
+    Be,
+    /// <p><code>BF</code></p>
+    Bf,
+    /// <p><code>BG</code></p>
+    Bg,
+    /// <p><code>BH</code></p>
+    Bh,
+    /// <p><code>BI</code></p>
+    Bi,
+    /// <p><code>BJ</code></p>
+    Bj,
+    /// <p><code>BL</code></p>
+    Bl,
+    /// <p><code>BM</code></p>
+    Bm,
+    /// <p><code>BN</code></p>
+    Bn,
+    /// <p><code>BO</code></p>
+    Bo,
+    /// <p><code>BQ</code></p>
+    Bq,
+    /// <p><code>BR</code></p>
+    Br,
+    /// <p><code>BS</code></p>
+    Bs,
+    /// <p><code>BT</code></p>
+    Bt,
+    /// <p><code>BV</code></p>
+    Bv,
+    /// <p><code>BW</code></p>
+    Bw,
+    /// <p><code>BY</code></p>
+    By,
+    /// <p><code>BZ</code></p>
+    Bz,
+    /// <p><code>CA</code></p>
+    Ca,
+    /// <p><code>CC</code></p>
+    Cc,
+    /// <p><code>CD</code></p>
+    Cd,
+    /// <p><code>CF</code></p>
+    Cf,
+    /// <p><code>CG</code></p>
+    Cg,
+    /// <p><code>CH</code></p>
+    Ch,
+    /// <p><code>CI</code></p>
+    Ci,
+    /// <p><code>CK</code></p>
+    Ck,
+    /// <p><code>CL</code></p>
+    Cl,
+    /// <p><code>CM</code></p>
+    Cm,
+    /// <p><code>CN</code></p>
+    Cn,
+    /// <p><code>CO</code></p>
+    Co,
+    /// <p><code>CR</code></p>
+    Cr,
+    /// <p><code>CU</code></p>
+    Cu,
+    /// <p><code>CV</code></p>
+    Cv,
+    /// <p><code>CW</code></p>
+    Cw,
+    /// <p><code>CX</code></p>
+    Cx,
+    /// <p><code>CY</code></p>
+    Cy,
+    /// <p><code>CZ</code></p>
+    Cz,
+    /// <p><code>DE</code></p>
+    De,
+    /// <p><code>DJ</code></p>
+    Dj,
+    /// <p><code>DK</code></p>
+    Dk,
+    /// <p><code>DM</code></p>
+    Dm,
+    /// <p><code>DO</code></p>
+    Do,
+    /// <p><code>DZ</code></p>
+    Dz,
+    /// <p><code>EC</code></p>
+    Ec,
+    /// <p><code>EE</code></p>
+    Ee,
+    /// <p><code>EG</code></p>
+    Eg,
+    /// <p><code>EH</code></p>
+    Eh,
+    /// <p><code>ER</code></p>
+    Er,
+    /// <p><code>ES</code></p>
+    Es,
+    /// <p><code>ET</code></p>
+    Et,
+    /// <p><code>FI</code></p>
+    Fi,
+    /// <p><code>FJ</code></p>
+    Fj,
+    /// <p><code>FK</code></p>
+    Fk,
+    /// <p><code>FM</code></p>
+    Fm,
+    /// <p><code>FO</code></p>
+    Fo,
+    /// <p><code>FR</code></p>
+    Fr,
+    /// <p><code>GA</code></p>
+    Ga,
+    /// <p><code>GB</code></p>
+    Gb,
+    /// <p><code>GD</code></p>
+    Gd,
+    /// <p><code>GE</code></p>
+    Ge,
+    /// <p><code>GF</code></p>
+    Gf,
+    /// <p><code>GG</code></p>
+    Gg,
+    /// <p><code>GH</code></p>
+    Gh,
+    /// <p><code>GI</code></p>
+    Gi,
+    /// <p><code>GL</code></p>
+    Gl,
+    /// <p><code>GM</code></p>
+    Gm,
+    /// <p><code>GN</code></p>
+    Gn,
+    /// <p><code>GP</code></p>
+    Gp,
+    /// <p><code>GQ</code></p>
+    Gq,
+    /// <p><code>GR</code></p>
+    Gr,
+    /// <p><code>GS</code></p>
+    Gs,
+    /// <p><code>GT</code></p>
+    Gt,
+    /// <p><code>GU</code></p>
+    Gu,
+    /// <p><code>GW</code></p>
+    Gw,
+    /// <p><code>GY</code></p>
+    Gy,
+    /// <p><code>HK</code></p>
+    Hk,
+    /// <p><code>HM</code></p>
+    Hm,
+    /// <p><code>HN</code></p>
+    Hn,
+    /// <p><code>HR</code></p>
+    Hr,
+    /// <p><code>HT</code></p>
+    Ht,
+    /// <p><code>HU</code></p>
+    Hu,
+    /// <p><code>ID</code></p>
+    Id,
+    /// <p><code>IE</code></p>
+    Ie,
+    /// <p><code>IL</code></p>
+    Il,
+    /// <p><code>IM</code></p>
+    Im,
+    /// <p><code>IN</code></p>
+    In,
+    /// <p><code>IO</code></p>
+    Io,
+    /// <p><code>IQ</code></p>
+    Iq,
+    /// <p><code>IR</code></p>
+    Ir,
+    /// <p><code>IS</code></p>
+    Is,
+    /// <p><code>IT</code></p>
+    It,
+    /// <p><code>JE</code></p>
+    Je,
+    /// <p><code>JM</code></p>
+    Jm,
+    /// <p><code>JO</code></p>
+    Jo,
+    /// <p><code>JP</code></p>
+    Jp,
+    /// <p><code>KE</code></p>
+    Ke,
+    /// <p><code>KG</code></p>
+    Kg,
+    /// <p><code>KH</code></p>
+    Kh,
+    /// <p><code>KI</code></p>
+    Ki,
+    /// <p><code>KM</code></p>
+    Km,
+    /// <p><code>KN</code></p>
+    Kn,
+    /// <p><code>KP</code></p>
+    Kp,
+    /// <p><code>KR</code></p>
+    Kr,
+    /// <p><code>KW</code></p>
+    Kw,
+    /// <p><code>KY</code></p>
+    Ky,
+    /// <p><code>KZ</code></p>
+    Kz,
+    /// <p><code>LA</code></p>
+    La,
+    /// <p><code>LB</code></p>
+    Lb,
+    /// <p><code>LC</code></p>
+    Lc,
+    /// <p><code>LI</code></p>
+    Li,
+    /// <p><code>LK</code></p>
+    Lk,
+    /// <p><code>LR</code></p>
+    Lr,
+    /// <p><code>LS</code></p>
+    Ls,
+    /// <p><code>LT</code></p>
+    Lt,
+    /// <p><code>LU</code></p>
+    Lu,
+    /// <p><code>LV</code></p>
+    Lv,
+    /// <p><code>LY</code></p>
+    Ly,
+    /// <p><code>MA</code></p>
+    Ma,
+    /// <p><code>MC</code></p>
+    Mc,
+    /// <p><code>MD</code></p>
+    Md,
+    /// <p><code>ME</code></p>
+    Me,
+    /// <p><code>MF</code></p>
+    Mf,
+    /// <p><code>MG</code></p>
+    Mg,
+    /// <p><code>MH</code></p>
+    Mh,
+    /// <p><code>MK</code></p>
+    Mk,
+    /// <p><code>ML</code></p>
+    Ml,
+    /// <p><code>MM</code></p>
+    Mm,
+    /// <p><code>MN</code></p>
+    Mn,
+    /// <p><code>MO</code></p>
+    Mo,
+    /// <p><code>MP</code></p>
+    Mp,
+    /// <p><code>MQ</code></p>
+    Mq,
+    /// <p><code>MR</code></p>
+    Mr,
+    /// <p><code>MS</code></p>
+    Ms,
+    /// <p><code>MT</code></p>
+    Mt,
+    /// <p><code>MU</code></p>
+    Mu,
+    /// <p><code>MV</code></p>
+    Mv,
+    /// <p><code>MW</code></p>
+    Mw,
+    /// <p><code>MX</code></p>
+    Mx,
+    /// <p><code>MY</code></p>
+    My,
+    /// <p><code>MZ</code></p>
+    Mz,
+    /// <p><code>NA</code></p>
+    Na,
+    /// <p><code>NC</code></p>
+    Nc,
+    /// <p><code>NE</code></p>
+    Ne,
+    /// <p><code>NF</code></p>
+    Nf,
+    /// <p><code>NG</code></p>
+    Ng,
+    /// <p><code>NI</code></p>
+    Ni,
+    /// <p><code>NL</code></p>
+    Nl,
+    /// <p><code>NO</code></p>
+    No,
+    /// <p><code>NP</code></p>
+    Np,
+    /// <p><code>NR</code></p>
+    Nr,
+    /// <p><code>NU</code></p>
+    Nu,
+    /// <p><code>NZ</code></p>
+    Nz,
+    /// <p><code>OM</code></p>
+    Om,
+    /// <p><code>PA</code></p>
+    Pa,
+    /// <p><code>PE</code></p>
+    Pe,
+    /// <p><code>PF</code></p>
+    Pf,
+    /// <p><code>PG</code></p>
+    Pg,
+    /// <p><code>PH</code></p>
+    Ph,
+    /// <p><code>PK</code></p>
+    Pk,
+    /// <p><code>PL</code></p>
+    Pl,
+    /// <p><code>PM</code></p>
+    Pm,
+    /// <p><code>PN</code></p>
+    Pn,
+    /// <p><code>PR</code></p>
+    Pr,
+    /// <p><code>PS</code></p>
+    Ps,
+    /// <p><code>PT</code></p>
+    Pt,
+    /// <p><code>PW</code></p>
+    Pw,
+    /// <p><code>PY</code></p>
+    Py,
+    /// <p><code>QA</code></p>
+    Qa,
+    /// <p><code>RE</code></p>
+    Re,
+    /// <p><code>RO</code></p>
+    Ro,
+    /// <p><code>RS</code></p>
+    Rs,
+    /// <p><code>RU</code></p>
+    Ru,
+    /// <p><code>RW</code></p>
+    Rw,
+    /// <p><code>SA</code></p>
+    Sa,
+    /// <p><code>SB</code></p>
+    Sb,
+    /// <p><code>SC</code></p>
+    Sc,
+    /// <p><code>SD</code></p>
+    Sd,
+    /// <p><code>SE</code></p>
+    Se,
+    /// <p><code>SG</code></p>
+    Sg,
+    /// <p><code>SH</code></p>
+    Sh,
+    /// <p><code>SI</code></p>
+    Si,
+    /// <p><code>SJ</code></p>
+    Sj,
+    /// <p><code>SK</code></p>
+    Sk,
+    /// <p><code>SL</code></p>
+    Sl,
+    /// <p><code>SM</code></p>
+    Sm,
+    /// <p><code>SN</code></p>
+    Sn,
+    /// <p><code>SO</code></p>
+    So,
+    /// <p><code>SR</code></p>
+    Sr,
+    /// <p><code>SS</code></p>
+    Ss,
+    /// <p><code>ST</code></p>
+    St,
+    /// <p><code>SV</code></p>
+    Sv,
+    /// <p><code>SX</code></p>
+    Sx,
+    /// <p><code>SY</code></p>
+    Sy,
+    /// <p><code>SZ</code></p>
+    Sz,
+    /// <p><code>TC</code></p>
+    Tc,
+    /// <p><code>TD</code></p>
+    Td,
+    /// <p><code>TF</code></p>
+    Tf,
+    /// <p><code>TG</code></p>
+    Tg,
+    /// <p><code>TH</code></p>
+    Th,
+    /// <p><code>TJ</code></p>
+    Tj,
+    /// <p><code>TK</code></p>
+    Tk,
+    /// <p><code>TL</code></p>
+    Tl,
+    /// <p><code>TM</code></p>
+    Tm,
+    /// <p><code>TN</code></p>
+    Tn,
+    /// <p><code>TO</code></p>
+    To,
+    /// <p><code>TP</code></p>
+    Tp,
+    /// <p><code>TR</code></p>
+    Tr,
+    /// <p><code>TT</code></p>
+    Tt,
+    /// <p><code>TV</code></p>
+    Tv,
+    /// <p><code>TW</code></p>
+    Tw,
+    /// <p><code>TZ</code></p>
+    Tz,
+    /// <p><code>UA</code></p>
+    Ua,
+    /// <p><code>UG</code></p>
+    Ug,
+    /// <p><code>US</code></p>
+    Us,
+    /// <p><code>UY</code></p>
+    Uy,
+    /// <p><code>UZ</code></p>
+    Uz,
+    /// <p><code>VA</code></p>
+    Va,
+    /// <p><code>VC</code></p>
+    Vc,
+    /// <p><code>VE</code></p>
+    Ve,
+    /// <p><code>VG</code></p>
+    Vg,
+    /// <p><code>VI</code></p>
+    Vi,
+    /// <p><code>VN</code></p>
+    Vn,
+    /// <p><code>VU</code></p>
+    Vu,
+    /// <p><code>WF</code></p>
+    Wf,
+    /// <p><code>WS</code></p>
+    Ws,
+    /// <p><code>YE</code></p>
+    Ye,
+    /// <p><code>YT</code></p>
+    Yt,
+    /// <p><code>ZA</code></p>
+    Za,
+    /// <p><code>ZM</code></p>
+    Zm,
+    /// <p><code>ZW</code></p>
+    Zw,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CountryCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CountryCode::Ac => "AC",
+            CountryCode::Ad => "AD",
+            CountryCode::Ae => "AE",
+            CountryCode::Af => "AF",
+            CountryCode::Ag => "AG",
+            CountryCode::Ai => "AI",
+            CountryCode::Al => "AL",
+            CountryCode::Am => "AM",
+            CountryCode::An => "AN",
+            CountryCode::Ao => "AO",
+            CountryCode::Aq => "AQ",
+            CountryCode::Ar => "AR",
+            CountryCode::As => "AS",
+            CountryCode::At => "AT",
+            CountryCode::Au => "AU",
+            CountryCode::Aw => "AW",
+            CountryCode::Ax => "AX",
+            CountryCode::Az => "AZ",
+            CountryCode::Ba => "BA",
+            CountryCode::Bb => "BB",
+            CountryCode::Bd => "BD",
+            CountryCode::Be => "BE",
+            CountryCode::Bf => "BF",
+            CountryCode::Bg => "BG",
+            CountryCode::Bh => "BH",
+            CountryCode::Bi => "BI",
+            CountryCode::Bj => "BJ",
+            CountryCode::Bl => "BL",
+            CountryCode::Bm => "BM",
+            CountryCode::Bn => "BN",
+            CountryCode::Bo => "BO",
+            CountryCode::Bq => "BQ",
+            CountryCode::Br => "BR",
+            CountryCode::Bs => "BS",
+            CountryCode::Bt => "BT",
+            CountryCode::Bv => "BV",
+            CountryCode::Bw => "BW",
+            CountryCode::By => "BY",
+            CountryCode::Bz => "BZ",
+            CountryCode::Ca => "CA",
+            CountryCode::Cc => "CC",
+            CountryCode::Cd => "CD",
+            CountryCode::Cf => "CF",
+            CountryCode::Cg => "CG",
+            CountryCode::Ch => "CH",
+            CountryCode::Ci => "CI",
+            CountryCode::Ck => "CK",
+            CountryCode::Cl => "CL",
+            CountryCode::Cm => "CM",
+            CountryCode::Cn => "CN",
+            CountryCode::Co => "CO",
+            CountryCode::Cr => "CR",
+            CountryCode::Cu => "CU",
+            CountryCode::Cv => "CV",
+            CountryCode::Cw => "CW",
+            CountryCode::Cx => "CX",
+            CountryCode::Cy => "CY",
+            CountryCode::Cz => "CZ",
+            CountryCode::De => "DE",
+            CountryCode::Dj => "DJ",
+            CountryCode::Dk => "DK",
+            CountryCode::Dm => "DM",
+            CountryCode::Do => "DO",
+            CountryCode::Dz => "DZ",
+            CountryCode::Ec => "EC",
+            CountryCode::Ee => "EE",
+            CountryCode::Eg => "EG",
+            CountryCode::Eh => "EH",
+            CountryCode::Er => "ER",
+            CountryCode::Es => "ES",
+            CountryCode::Et => "ET",
+            CountryCode::Fi => "FI",
+            CountryCode::Fj => "FJ",
+            CountryCode::Fk => "FK",
+            CountryCode::Fm => "FM",
+            CountryCode::Fo => "FO",
+            CountryCode::Fr => "FR",
+            CountryCode::Ga => "GA",
+            CountryCode::Gb => "GB",
+            CountryCode::Gd => "GD",
+            CountryCode::Ge => "GE",
+            CountryCode::Gf => "GF",
+            CountryCode::Gg => "GG",
+            CountryCode::Gh => "GH",
+            CountryCode::Gi => "GI",
+            CountryCode::Gl => "GL",
+            CountryCode::Gm => "GM",
+            CountryCode::Gn => "GN",
+            CountryCode::Gp => "GP",
+            CountryCode::Gq => "GQ",
+            CountryCode::Gr => "GR",
+            CountryCode::Gs => "GS",
+            CountryCode::Gt => "GT",
+            CountryCode::Gu => "GU",
+            CountryCode::Gw => "GW",
+            CountryCode::Gy => "GY",
+            CountryCode::Hk => "HK",
+            CountryCode::Hm => "HM",
+            CountryCode::Hn => "HN",
+            CountryCode::Hr => "HR",
+            CountryCode::Ht => "HT",
+            CountryCode::Hu => "HU",
+            CountryCode::Id => "ID",
+            CountryCode::Ie => "IE",
+            CountryCode::Il => "IL",
+            CountryCode::Im => "IM",
+            CountryCode::In => "IN",
+            CountryCode::Io => "IO",
+            CountryCode::Iq => "IQ",
+            CountryCode::Ir => "IR",
+            CountryCode::Is => "IS",
+            CountryCode::It => "IT",
+            CountryCode::Je => "JE",
+            CountryCode::Jm => "JM",
+            CountryCode::Jo => "JO",
+            CountryCode::Jp => "JP",
+            CountryCode::Ke => "KE",
+            CountryCode::Kg => "KG",
+            CountryCode::Kh => "KH",
+            CountryCode::Ki => "KI",
+            CountryCode::Km => "KM",
+            CountryCode::Kn => "KN",
+            CountryCode::Kp => "KP",
+            CountryCode::Kr => "KR",
+            CountryCode::Kw => "KW",
+            CountryCode::Ky => "KY",
+            CountryCode::Kz => "KZ",
+            CountryCode::La => "LA",
+            CountryCode::Lb => "LB",
+            CountryCode::Lc => "LC",
+            CountryCode::Li => "LI",
+            CountryCode::Lk => "LK",
+            CountryCode::Lr => "LR",
+            CountryCode::Ls => "LS",
+            CountryCode::Lt => "LT",
+            CountryCode::Lu => "LU",
+            CountryCode::Lv => "LV",
+            CountryCode::Ly => "LY",
+            CountryCode::Ma => "MA",
+            CountryCode::Mc => "MC",
+            CountryCode::Md => "MD",
+            CountryCode::Me => "ME",
+            CountryCode::Mf => "MF",
+            CountryCode::Mg => "MG",
+            CountryCode::Mh => "MH",
+            CountryCode::Mk => "MK",
+            CountryCode::Ml => "ML",
+            CountryCode::Mm => "MM",
+            CountryCode::Mn => "MN",
+            CountryCode::Mo => "MO",
+            CountryCode::Mp => "MP",
+            CountryCode::Mq => "MQ",
+            CountryCode::Mr => "MR",
+            CountryCode::Ms => "MS",
+            CountryCode::Mt => "MT",
+            CountryCode::Mu => "MU",
+            CountryCode::Mv => "MV",
+            CountryCode::Mw => "MW",
+            CountryCode::Mx => "MX",
+            CountryCode::My => "MY",
+            CountryCode::Mz => "MZ",
+            CountryCode::Na => "NA",
+            CountryCode::Nc => "NC",
+            CountryCode::Ne => "NE",
+            CountryCode::Nf => "NF",
+            CountryCode::Ng => "NG",
+            CountryCode::Ni => "NI",
+            CountryCode::Nl => "NL",
+            CountryCode::No => "NO",
+            CountryCode::Np => "NP",
+            CountryCode::Nr => "NR",
+            CountryCode::Nu => "NU",
+            CountryCode::Nz => "NZ",
+            CountryCode::Om => "OM",
+            CountryCode::Pa => "PA",
+            CountryCode::Pe => "PE",
+            CountryCode::Pf => "PF",
+            CountryCode::Pg => "PG",
+            CountryCode::Ph => "PH",
+            CountryCode::Pk => "PK",
+            CountryCode::Pl => "PL",
+            CountryCode::Pm => "PM",
+            CountryCode::Pn => "PN",
+            CountryCode::Pr => "PR",
+            CountryCode::Ps => "PS",
+            CountryCode::Pt => "PT",
+            CountryCode::Pw => "PW",
+            CountryCode::Py => "PY",
+            CountryCode::Qa => "QA",
+            CountryCode::Re => "RE",
+            CountryCode::Ro => "RO",
+            CountryCode::Rs => "RS",
+            CountryCode::Ru => "RU",
+            CountryCode::Rw => "RW",
+            CountryCode::Sa => "SA",
+            CountryCode::Sb => "SB",
+            CountryCode::Sc => "SC",
+            CountryCode::Sd => "SD",
+            CountryCode::Se => "SE",
+            CountryCode::Sg => "SG",
+            CountryCode::Sh => "SH",
+            CountryCode::Si => "SI",
+            CountryCode::Sj => "SJ",
+            CountryCode::Sk => "SK",
+            CountryCode::Sl => "SL",
+            CountryCode::Sm => "SM",
+            CountryCode::Sn => "SN",
+            CountryCode::So => "SO",
+            CountryCode::Sr => "SR",
+            CountryCode::Ss => "SS",
+            CountryCode::St => "ST",
+            CountryCode::Sv => "SV",
+            CountryCode::Sx => "SX",
+            CountryCode::Sy => "SY",
+            CountryCode::Sz => "SZ",
+            CountryCode::Tc => "TC",
+            CountryCode::Td => "TD",
+            CountryCode::Tf => "TF",
+            CountryCode::Tg => "TG",
+            CountryCode::Th => "TH",
+            CountryCode::Tj => "TJ",
+            CountryCode::Tk => "TK",
+            CountryCode::Tl => "TL",
+            CountryCode::Tm => "TM",
+            CountryCode::Tn => "TN",
+            CountryCode::To => "TO",
+            CountryCode::Tp => "TP",
+            CountryCode::Tr => "TR",
+            CountryCode::Tt => "TT",
+            CountryCode::Tv => "TV",
+            CountryCode::Tw => "TW",
+            CountryCode::Tz => "TZ",
+            CountryCode::Ua => "UA",
+            CountryCode::Ug => "UG",
+            CountryCode::Us => "US",
+            CountryCode::Uy => "UY",
+            CountryCode::Uz => "UZ",
+            CountryCode::Va => "VA",
+            CountryCode::Vc => "VC",
+            CountryCode::Ve => "VE",
+            CountryCode::Vg => "VG",
+            CountryCode::Vi => "VI",
+            CountryCode::Vn => "VN",
+            CountryCode::Vu => "VU",
+            CountryCode::Wf => "WF",
+            CountryCode::Ws => "WS",
+            CountryCode::Ye => "YE",
+            CountryCode::Yt => "YT",
+            CountryCode::Za => "ZA",
+            CountryCode::Zm => "ZM",
+            CountryCode::Zw => "ZW",
+            CountryCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CountryCode {
+    fn from(value: &'a str) -> CountryCode {
+        match value {
+            "AC" => CountryCode::Ac,
+            "AD" => CountryCode::Ad,
+            "AE" => CountryCode::Ae,
+            "AF" => CountryCode::Af,
+            "AG" => CountryCode::Ag,
+            "AI" => CountryCode::Ai,
+            "AL" => CountryCode::Al,
+            "AM" => CountryCode::Am,
+            "AN" => CountryCode::An,
+            "AO" => CountryCode::Ao,
+            "AQ" => CountryCode::Aq,
+            "AR" => CountryCode::Ar,
+            "AS" => CountryCode::As,
+            "AT" => CountryCode::At,
+            "AU" => CountryCode::Au,
+            "AW" => CountryCode::Aw,
+            "AX" => CountryCode::Ax,
+            "AZ" => CountryCode::Az,
+            "BA" => CountryCode::Ba,
+            "BB" => CountryCode::Bb,
+            "BD" => CountryCode::Bd,
+            "BE" => CountryCode::Be,
+            "BF" => CountryCode::Bf,
+            "BG" => CountryCode::Bg,
+            "BH" => CountryCode::Bh,
+            "BI" => CountryCode::Bi,
+            "BJ" => CountryCode::Bj,
+            "BL" => CountryCode::Bl,
+            "BM" => CountryCode::Bm,
+            "BN" => CountryCode::Bn,
+            "BO" => CountryCode::Bo,
+            "BQ" => CountryCode::Bq,
+            "BR" => CountryCode::Br,
+            "BS" => CountryCode::Bs,
+            "BT" => CountryCode::Bt,
+            "BV" => CountryCode::Bv,
+            "BW" => CountryCode::Bw,
+            "BY" => CountryCode::By,
+            "BZ" => CountryCode::Bz,
+            "CA" => CountryCode::Ca,
+            "CC" => CountryCode::Cc,
+            "CD" => CountryCode::Cd,
+            "CF" => CountryCode::Cf,
+            "CG" => CountryCode::Cg,
+            "CH" => CountryCode::Ch,
+            "CI" => CountryCode::Ci,
+            "CK" => CountryCode::Ck,
+            "CL" => CountryCode::Cl,
+            "CM" => CountryCode::Cm,
+            "CN" => CountryCode::Cn,
+            "CO" => CountryCode::Co,
+            "CR" => CountryCode::Cr,
+            "CU" => CountryCode::Cu,
+            "CV" => CountryCode::Cv,
+            "CW" => CountryCode::Cw,
+            "CX" => CountryCode::Cx,
+            "CY" => CountryCode::Cy,
+            "CZ" => CountryCode::Cz,
+            "DE" => CountryCode::De,
+            "DJ" => CountryCode::Dj,
+            "DK" => CountryCode::Dk,
+            "DM" => CountryCode::Dm,
+            "DO" => CountryCode::Do,
+            "DZ" => CountryCode::Dz,
+            "EC" => CountryCode::Ec,
+            "EE" => CountryCode::Ee,
+            "EG" => CountryCode::Eg,
+            "EH" => CountryCode::Eh,
+            "ER" => CountryCode::Er,
+            "ES" => CountryCode::Es,
+            "ET" => CountryCode::Et,
+            "FI" => CountryCode::Fi,
+            "FJ" => CountryCode::Fj,
+            "FK" => CountryCode::Fk,
+            "FM" => CountryCode::Fm,
+            "FO" => CountryCode::Fo,
+            "FR" => CountryCode::Fr,
+            "GA" => CountryCode::Ga,
+            "GB" => CountryCode::Gb,
+            "GD" => CountryCode::Gd,
+            "GE" => CountryCode::Ge,
+            "GF" => CountryCode::Gf,
+            "GG" => CountryCode::Gg,
+            "GH" => CountryCode::Gh,
+            "GI" => CountryCode::Gi,
+            "GL" => CountryCode::Gl,
+            "GM" => CountryCode::Gm,
+            "GN" => CountryCode::Gn,
+            "GP" => CountryCode::Gp,
+            "GQ" => CountryCode::Gq,
+            "GR" => CountryCode::Gr,
+            "GS" => CountryCode::Gs,
+            "GT" => CountryCode::Gt,
+            "GU" => CountryCode::Gu,
+            "GW" => CountryCode::Gw,
+            "GY" => CountryCode::Gy,
+            "HK" => CountryCode::Hk,
+            "HM" => CountryCode::Hm,
+            "HN" => CountryCode::Hn,
+            "HR" => CountryCode::Hr,
+            "HT" => CountryCode::Ht,
+            "HU" => CountryCode::Hu,
+            "ID" => CountryCode::Id,
+            "IE" => CountryCode::Ie,
+            "IL" => CountryCode::Il,
+            "IM" => CountryCode::Im,
+            "IN" => CountryCode::In,
+            "IO" => CountryCode::Io,
+            "IQ" => CountryCode::Iq,
+            "IR" => CountryCode::Ir,
+            "IS" => CountryCode::Is,
+            "IT" => CountryCode::It,
+            "JE" => CountryCode::Je,
+            "JM" => CountryCode::Jm,
+            "JO" => CountryCode::Jo,
+            "JP" => CountryCode::Jp,
+            "KE" => CountryCode::Ke,
+            "KG" => CountryCode::Kg,
+            "KH" => CountryCode::Kh,
+            "KI" => CountryCode::Ki,
+            "KM" => CountryCode::Km,
+            "KN" => CountryCode::Kn,
+            "KP" => CountryCode::Kp,
+            "KR" => CountryCode::Kr,
+            "KW" => CountryCode::Kw,
+            "KY" => CountryCode::Ky,
+            "KZ" => CountryCode::Kz,
+            "LA" => CountryCode::La,
+            "LB" => CountryCode::Lb,
+            "LC" => CountryCode::Lc,
+            "LI" => CountryCode::Li,
+            "LK" => CountryCode::Lk,
+            "LR" => CountryCode::Lr,
+            "LS" => CountryCode::Ls,
+            "LT" => CountryCode::Lt,
+            "LU" => CountryCode::Lu,
+            "LV" => CountryCode::Lv,
+            "LY" => CountryCode::Ly,
+            "MA" => CountryCode::Ma,
+            "MC" => CountryCode::Mc,
+            "MD" => CountryCode::Md,
+            "ME" => CountryCode::Me,
+            "MF" => CountryCode::Mf,
+            "MG" => CountryCode::Mg,
+            "MH" => CountryCode::Mh,
+            "MK" => CountryCode::Mk,
+            "ML" => CountryCode::Ml,
+            "MM" => CountryCode::Mm,
+            "MN" => CountryCode::Mn,
+            "MO" => CountryCode::Mo,
+            "MP" => CountryCode::Mp,
+            "MQ" => CountryCode::Mq,
+            "MR" => CountryCode::Mr,
+            "MS" => CountryCode::Ms,
+            "MT" => CountryCode::Mt,
+            "MU" => CountryCode::Mu,
+            "MV" => CountryCode::Mv,
+            "MW" => CountryCode::Mw,
+            "MX" => CountryCode::Mx,
+            "MY" => CountryCode::My,
+            "MZ" => CountryCode::Mz,
+            "NA" => CountryCode::Na,
+            "NC" => CountryCode::Nc,
+            "NE" => CountryCode::Ne,
+            "NF" => CountryCode::Nf,
+            "NG" => CountryCode::Ng,
+            "NI" => CountryCode::Ni,
+            "NL" => CountryCode::Nl,
+            "NO" => CountryCode::No,
+            "NP" => CountryCode::Np,
+            "NR" => CountryCode::Nr,
+            "NU" => CountryCode::Nu,
+            "NZ" => CountryCode::Nz,
+            "OM" => CountryCode::Om,
+            "PA" => CountryCode::Pa,
+            "PE" => CountryCode::Pe,
+            "PF" => CountryCode::Pf,
+            "PG" => CountryCode::Pg,
+            "PH" => CountryCode::Ph,
+            "PK" => CountryCode::Pk,
+            "PL" => CountryCode::Pl,
+            "PM" => CountryCode::Pm,
+            "PN" => CountryCode::Pn,
+            "PR" => CountryCode::Pr,
+            "PS" => CountryCode::Ps,
+            "PT" => CountryCode::Pt,
+            "PW" => CountryCode::Pw,
+            "PY" => CountryCode::Py,
+            "QA" => CountryCode::Qa,
+            "RE" => CountryCode::Re,
+            "RO" => CountryCode::Ro,
+            "RS" => CountryCode::Rs,
+            "RU" => CountryCode::Ru,
+            "RW" => CountryCode::Rw,
+            "SA" => CountryCode::Sa,
+            "SB" => CountryCode::Sb,
+            "SC" => CountryCode::Sc,
+            "SD" => CountryCode::Sd,
+            "SE" => CountryCode::Se,
+            "SG" => CountryCode::Sg,
+            "SH" => CountryCode::Sh,
+            "SI" => CountryCode::Si,
+            "SJ" => CountryCode::Sj,
+            "SK" => CountryCode::Sk,
+            "SL" => CountryCode::Sl,
+            "SM" => CountryCode::Sm,
+            "SN" => CountryCode::Sn,
+            "SO" => CountryCode::So,
+            "SR" => CountryCode::Sr,
+            "SS" => CountryCode::Ss,
+            "ST" => CountryCode::St,
+            "SV" => CountryCode::Sv,
+            "SX" => CountryCode::Sx,
+            "SY" => CountryCode::Sy,
+            "SZ" => CountryCode::Sz,
+            "TC" => CountryCode::Tc,
+            "TD" => CountryCode::Td,
+            "TF" => CountryCode::Tf,
+            "TG" => CountryCode::Tg,
+            "TH" => CountryCode::Th,
+            "TJ" => CountryCode::Tj,
+            "TK" => CountryCode::Tk,
+            "TL" => CountryCode::Tl,
+            "TM" => CountryCode::Tm,
+            "TN" => CountryCode::Tn,
+            "TO" => CountryCode::To,
+            "TP" => CountryCode::Tp,
+            "TR" => CountryCode::Tr,
+            "TT" => CountryCode::Tt,
+            "TV" => CountryCode::Tv,
+            "TW" => CountryCode::Tw,
+            "TZ" => CountryCode::Tz,
+            "UA" => CountryCode::Ua,
+            "UG" => CountryCode::Ug,
+            "US" => CountryCode::Us,
+            "UY" => CountryCode::Uy,
+            "UZ" => CountryCode::Uz,
+            "VA" => CountryCode::Va,
+            "VC" => CountryCode::Vc,
+            "VE" => CountryCode::Ve,
+            "VG" => CountryCode::Vg,
+            "VI" => CountryCode::Vi,
+            "VN" => CountryCode::Vn,
+            "VU" => CountryCode::Vu,
+            "WF" => CountryCode::Wf,
+            "WS" => CountryCode::Ws,
+            "YE" => CountryCode::Ye,
+            "YT" => CountryCode::Yt,
+            "ZA" => CountryCode::Za,
+            "ZM" => CountryCode::Zm,
+            "ZW" => CountryCode::Zw,
+            _ => CountryCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CountryCode {
+    fn from(value: String) -> CountryCode {
+        CountryCode::from(value.as_str())
+    }
+}
+
+impl From<CountryCode> for String {
+    fn from(value: CountryCode) -> String {
+        match value {
+            CountryCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CountryCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CountryCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CountryCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CountryCode::from(value))
+    }
+}
+
 /// <p>The DeleteTagsForDomainRequest includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteTagsForDomainRequest {
@@ -163,6 +1397,48 @@ pub struct DeleteTagsForDomainRequest {
     pub tags_to_delete: Vec<String>,
 }
 
+impl DeleteTagsForDomainRequest {
+    /// Returns a builder for `DeleteTagsForDomainRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTagsForDomainRequestBuilder {
+        DeleteTagsForDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTagsForDomainRequest`](struct.DeleteTagsForDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTagsForDomainRequestBuilder {
+    domain_name: Option<String>,
+    tags_to_delete: Option<Vec<String>>,
+}
+
+impl DeleteTagsForDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.DeleteTagsForDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags_to_delete`](struct.DeleteTagsForDomainRequest.html#structfield.tags_to_delete) member.
+    pub fn tags_to_delete<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tags_to_delete = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTagsForDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteTagsForDomainRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTagsForDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            tags_to_delete: self
+                .tags_to_delete
+                .ok_or(::rusoto_core::BuildError::missing("tags_to_delete"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteTagsForDomainResponse {}
@@ -174,6 +1450,38 @@ pub struct DisableDomainAutoRenewRequest {
     pub domain_name: String,
 }
 
+impl DisableDomainAutoRenewRequest {
+    /// Returns a builder for `DisableDomainAutoRenewRequest`, checking its required members when built.
+    pub fn builder() -> DisableDomainAutoRenewRequestBuilder {
+        DisableDomainAutoRenewRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableDomainAutoRenewRequest`](struct.DisableDomainAutoRenewRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableDomainAutoRenewRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl DisableDomainAutoRenewRequestBuilder {
+    /// Sets the [`domain_name`](struct.DisableDomainAutoRenewRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableDomainAutoRenewRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableDomainAutoRenewRequest, ::rusoto_core::BuildError> {
+        Ok(DisableDomainAutoRenewRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisableDomainAutoRenewResponse {}
@@ -186,6 +1494,38 @@ pub struct DisableDomainTransferLockRequest {
     pub domain_name: String,
 }
 
+impl DisableDomainTransferLockRequest {
+    /// Returns a builder for `DisableDomainTransferLockRequest`, checking its required members when built.
+    pub fn builder() -> DisableDomainTransferLockRequestBuilder {
+        DisableDomainTransferLockRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableDomainTransferLockRequest`](struct.DisableDomainTransferLockRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableDomainTransferLockRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl DisableDomainTransferLockRequestBuilder {
+    /// Sets the [`domain_name`](struct.DisableDomainTransferLockRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableDomainTransferLockRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableDomainTransferLockRequest, ::rusoto_core::BuildError> {
+        Ok(DisableDomainTransferLockRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// <p>The DisableDomainTransferLock response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -195,6 +1535,102 @@ pub struct DisableDomainTransferLockResponse {
     pub operation_id: String,
 }
 
+/// <p><p>Whether the domain name is available for registering.</p> <note> <p>You can register only domains designated as <code>AVAILABLE</code>.</p> </note> <p>Valid values:</p> <dl> <dt>AVAILABLE</dt> <dd> <p>The domain name is available.</p> </dd> <dt>AVAILABLE<em>RESERVED</dt> <dd> <p>The domain name is reserved under specific conditions.</p> </dd> <dt>AVAILABLE</em>PREORDER</dt> <dd> <p>The domain name is available and can be preordered.</p> </dd> <dt>DONT<em>KNOW</dt> <dd> <p>The TLD registry didn&#39;t reply with a definitive answer about whether the domain name is available. Amazon Route 53 can return this response for a variety of reasons, for example, the registry is performing maintenance. Try again later.</p> </dd> <dt>PENDING</dt> <dd> <p>The TLD registry didn&#39;t return a response in the expected amount of time. When the response is delayed, it usually takes just a few extra seconds. You can resubmit the request immediately.</p> </dd> <dt>RESERVED</dt> <dd> <p>The domain name has been reserved for another person or organization.</p> </dd> <dt>UNAVAILABLE</dt> <dd> <p>The domain name is not available.</p> </dd> <dt>UNAVAILABLE</em>PREMIUM</dt> <dd> <p>The domain name is not available.</p> </dd> <dt>UNAVAILABLE_RESTRICTED</dt> <dd> <p>The domain name is forbidden.</p> </dd> </dl></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomainAvailability {
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>AVAILABLE_RESERVED</code></p>
+    AvailableReserved,
+    /// <p><code>AVAILABLE_PREORDER</code></p>
+    AvailablePreorder,
+    /// <p><code>UNAVAILABLE</code></p>
+    Unavailable,
+    /// <p><code>UNAVAILABLE_PREMIUM</code></p>
+    UnavailablePremium,
+    /// <p><code>UNAVAILABLE_RESTRICTED</code></p>
+    UnavailableRestricted,
+    /// <p><code>RESERVED</code></p>
+    Reserved,
+    /// <p><code>DONT_KNOW</code></p>
+    DontKnow,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DomainAvailability {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainAvailability::Available => "AVAILABLE",
+            DomainAvailability::AvailableReserved => "AVAILABLE_RESERVED",
+            DomainAvailability::AvailablePreorder => "AVAILABLE_PREORDER",
+            DomainAvailability::Unavailable => "UNAVAILABLE",
+            DomainAvailability::UnavailablePremium => "UNAVAILABLE_PREMIUM",
+            DomainAvailability::UnavailableRestricted => "UNAVAILABLE_RESTRICTED",
+            DomainAvailability::Reserved => "RESERVED",
+            DomainAvailability::DontKnow => "DONT_KNOW",
+            DomainAvailability::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DomainAvailability {
+    fn from(value: &'a str) -> DomainAvailability {
+        match value {
+            "AVAILABLE" => DomainAvailability::Available,
+            "AVAILABLE_RESERVED" => DomainAvailability::AvailableReserved,
+            "AVAILABLE_PREORDER" => DomainAvailability::AvailablePreorder,
+            "UNAVAILABLE" => DomainAvailability::Unavailable,
+            "UNAVAILABLE_PREMIUM" => DomainAvailability::UnavailablePremium,
+            "UNAVAILABLE_RESTRICTED" => DomainAvailability::UnavailableRestricted,
+            "RESERVED" => DomainAvailability::Reserved,
+            "DONT_KNOW" => DomainAvailability::DontKnow,
+            _ => DomainAvailability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainAvailability {
+    fn from(value: String) -> DomainAvailability {
+        DomainAvailability::from(value.as_str())
+    }
+}
+
+impl From<DomainAvailability> for String {
+    fn from(value: DomainAvailability) -> String {
+        match value {
+            DomainAvailability::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DomainAvailability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DomainAvailability {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainAvailability {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DomainAvailability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DomainAvailability::from(value))
+    }
+}
+
 /// <p>Information about one suggested domain name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -222,8 +1658,9 @@ pub struct DomainSummary {
     pub domain_name: String,
     /// <p>Expiration date of the domain in Coordinated Universal Time (UTC).</p>
     #[serde(rename = "Expiry")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiry: Option<f64>,
+    pub expiry: Option<EpochTimestamp>,
     /// <p>Indicates whether a domain is locked from unauthorized transfer to another party.</p>
     #[serde(rename = "TransferLock")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -236,7 +1673,7 @@ pub struct DomainSummary {
 pub struct DomainTransferability {
     #[serde(rename = "Transferable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transferable: Option<String>,
+    pub transferable: Option<Transferable>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -246,6 +1683,38 @@ pub struct EnableDomainAutoRenewRequest {
     pub domain_name: String,
 }
 
+impl EnableDomainAutoRenewRequest {
+    /// Returns a builder for `EnableDomainAutoRenewRequest`, checking its required members when built.
+    pub fn builder() -> EnableDomainAutoRenewRequestBuilder {
+        EnableDomainAutoRenewRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableDomainAutoRenewRequest`](struct.EnableDomainAutoRenewRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableDomainAutoRenewRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl EnableDomainAutoRenewRequestBuilder {
+    /// Sets the [`domain_name`](struct.EnableDomainAutoRenewRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableDomainAutoRenewRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnableDomainAutoRenewRequest, ::rusoto_core::BuildError> {
+        Ok(EnableDomainAutoRenewRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct EnableDomainAutoRenewResponse {}
@@ -258,6 +1727,38 @@ pub struct EnableDomainTransferLockRequest {
     pub domain_name: String,
 }
 
+impl EnableDomainTransferLockRequest {
+    /// Returns a builder for `EnableDomainTransferLockRequest`, checking its required members when built.
+    pub fn builder() -> EnableDomainTransferLockRequestBuilder {
+        EnableDomainTransferLockRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableDomainTransferLockRequest`](struct.EnableDomainTransferLockRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableDomainTransferLockRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl EnableDomainTransferLockRequestBuilder {
+    /// Sets the [`domain_name`](struct.EnableDomainTransferLockRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableDomainTransferLockRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnableDomainTransferLockRequest, ::rusoto_core::BuildError> {
+        Ok(EnableDomainTransferLockRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// <p>The EnableDomainTransferLock response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -268,16 +1769,204 @@ pub struct EnableDomainTransferLockResponse {
 }
 
 /// <p>ExtraParam includes the following elements.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExtraParam {
     /// <p>Name of the additional parameter required by the top-level domain. Here are the top-level domains that require additional parameters and which parameters they require:</p> <ul> <li> <p> <b>.com.au and .net.au:</b> <code>AU_ID_NUMBER</code> and <code>AU_ID_TYPE</code> </p> </li> <li> <p> <b>.ca:</b> <code>BRAND_NUMBER</code>, <code>CA_LEGAL_TYPE</code>, and <code>CA_BUSINESS_ENTITY_TYPE</code> </p> </li> <li> <p> <b>.es:</b> <code>ES_IDENTIFICATION</code>, <code>ES_IDENTIFICATION_TYPE</code>, and <code>ES_LEGAL_FORM</code> </p> </li> <li> <p> <b>.fi:</b> <code>BIRTH_DATE_IN_YYYY_MM_DD</code>, <code>FI_BUSINESS_NUMBER</code>, <code>FI_ID_NUMBER</code>, <code>FI_NATIONALITY</code>, and <code>FI_ORGANIZATION_TYPE</code> </p> </li> <li> <p> <b>.fr:</b> <code>BRAND_NUMBER</code>, <code>BIRTH_DEPARTMENT</code>, <code>BIRTH_DATE_IN_YYYY_MM_DD</code>, <code>BIRTH_COUNTRY</code>, and <code>BIRTH_CITY</code> </p> </li> <li> <p> <b>.it:</b> <code>BIRTH_COUNTRY</code>, <code>IT_PIN</code>, and <code>IT_REGISTRANT_ENTITY_TYPE</code> </p> </li> <li> <p> <b>.ru:</b> <code>BIRTH_DATE_IN_YYYY_MM_DD</code> and <code>RU_PASSPORT_DATA</code> </p> </li> <li> <p> <b>.se:</b> <code>BIRTH_COUNTRY</code> and <code>SE_ID_NUMBER</code> </p> </li> <li> <p> <b>.sg:</b> <code>SG_ID_NUMBER</code> </p> </li> <li> <p> <b>.co.uk, .me.uk, and .org.uk:</b> <code>UK_CONTACT_TYPE</code> and <code>UK_COMPANY_NUMBER</code> </p> </li> </ul> <p>In addition, many TLDs require <code>VAT_NUMBER</code>.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: ExtraParamName,
     /// <p>Values corresponding to the additional parameter names required by some top-level domains.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+/// <p>Name of the additional parameter required by the top-level domain. Here are the top-level domains that require additional parameters and which parameters they require:</p> <ul> <li> <p> <b>.com.au and .net.au:</b> <code>AU_ID_NUMBER</code> and <code>AU_ID_TYPE</code> </p> </li> <li> <p> <b>.ca:</b> <code>BRAND_NUMBER</code>, <code>CA_LEGAL_TYPE</code>, and <code>CA_BUSINESS_ENTITY_TYPE</code> </p> </li> <li> <p> <b>.es:</b> <code>ES_IDENTIFICATION</code>, <code>ES_IDENTIFICATION_TYPE</code>, and <code>ES_LEGAL_FORM</code> </p> </li> <li> <p> <b>.fi:</b> <code>BIRTH_DATE_IN_YYYY_MM_DD</code>, <code>FI_BUSINESS_NUMBER</code>, <code>FI_ID_NUMBER</code>, <code>FI_NATIONALITY</code>, and <code>FI_ORGANIZATION_TYPE</code> </p> </li> <li> <p> <b>.fr:</b> <code>BRAND_NUMBER</code>, <code>BIRTH_DEPARTMENT</code>, <code>BIRTH_DATE_IN_YYYY_MM_DD</code>, <code>BIRTH_COUNTRY</code>, and <code>BIRTH_CITY</code> </p> </li> <li> <p> <b>.it:</b> <code>BIRTH_COUNTRY</code>, <code>IT_PIN</code>, and <code>IT_REGISTRANT_ENTITY_TYPE</code> </p> </li> <li> <p> <b>.ru:</b> <code>BIRTH_DATE_IN_YYYY_MM_DD</code> and <code>RU_PASSPORT_DATA</code> </p> </li> <li> <p> <b>.se:</b> <code>BIRTH_COUNTRY</code> and <code>SE_ID_NUMBER</code> </p> </li> <li> <p> <b>.sg:</b> <code>SG_ID_NUMBER</code> </p> </li> <li> <p> <b>.co.uk, .me.uk, and .org.uk:</b> <code>UK_CONTACT_TYPE</code> and <code>UK_COMPANY_NUMBER</code> </p> </li> </ul> <p>In addition, many TLDs require <code>VAT_NUMBER</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExtraParamName {
+    /// <p><code>DUNS_NUMBER</code></p>
+    DunsNumber,
+    /// <p><code>BRAND_NUMBER</code></p>
+    BrandNumber,
+    /// <p><code>BIRTH_DEPARTMENT</code></p>
+    BirthDepartment,
+    /// <p><code>BIRTH_DATE_IN_YYYY_MM_DD</code></p>
+    BirthDateInYyyyMmDd,
+    /// <p><code>BIRTH_COUNTRY</code></p>
+    BirthCountry,
+    /// <p><code>BIRTH_CITY</code></p>
+    BirthCity,
+    /// <p><code>DOCUMENT_NUMBER</code></p>
+    DocumentNumber,
+    /// <p><code>AU_ID_NUMBER</code></p>
+    AuIdNumber,
+    /// <p><code>AU_ID_TYPE</code></p>
+    AuIdType,
+    /// <p><code>CA_LEGAL_TYPE</code></p>
+    CaLegalType,
+    /// <p><code>CA_BUSINESS_ENTITY_TYPE</code></p>
+    CaBusinessEntityType,
+    /// <p><code>CA_LEGAL_REPRESENTATIVE</code></p>
+    CaLegalRepresentative,
+    /// <p><code>CA_LEGAL_REPRESENTATIVE_CAPACITY</code></p>
+    CaLegalRepresentativeCapacity,
+    /// <p><code>ES_IDENTIFICATION</code></p>
+    EsIdentification,
+    /// <p><code>ES_IDENTIFICATION_TYPE</code></p>
+    EsIdentificationType,
+    /// <p><code>ES_LEGAL_FORM</code></p>
+    EsLegalForm,
+    /// <p><code>FI_BUSINESS_NUMBER</code></p>
+    FiBusinessNumber,
+    /// <p><code>FI_ID_NUMBER</code></p>
+    FiIdNumber,
+    /// <p><code>FI_NATIONALITY</code></p>
+    FiNationality,
+    /// <p><code>FI_ORGANIZATION_TYPE</code></p>
+    FiOrganizationType,
+    /// <p><code>IT_NATIONALITY</code></p>
+    ItNationality,
+    /// <p><code>IT_PIN</code></p>
+    ItPin,
+    /// <p><code>IT_REGISTRANT_ENTITY_TYPE</code></p>
+    ItRegistrantEntityType,
+    /// <p><code>RU_PASSPORT_DATA</code></p>
+    RuPassportData,
+    /// <p><code>SE_ID_NUMBER</code></p>
+    SeIdNumber,
+    /// <p><code>SG_ID_NUMBER</code></p>
+    SgIdNumber,
+    /// <p><code>VAT_NUMBER</code></p>
+    VatNumber,
+    /// <p><code>UK_CONTACT_TYPE</code></p>
+    UkContactType,
+    /// <p><code>UK_COMPANY_NUMBER</code></p>
+    UkCompanyNumber,
+    /// <p><code>EU_COUNTRY_OF_CITIZENSHIP</code></p>
+    EuCountryOfCitizenship,
+    /// <p><code>AU_PRIORITY_TOKEN</code></p>
+    AuPriorityToken,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExtraParamName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExtraParamName::DunsNumber => "DUNS_NUMBER",
+            ExtraParamName::BrandNumber => "BRAND_NUMBER",
+            ExtraParamName::BirthDepartment => "BIRTH_DEPARTMENT",
+            ExtraParamName::BirthDateInYyyyMmDd => "BIRTH_DATE_IN_YYYY_MM_DD",
+            ExtraParamName::BirthCountry => "BIRTH_COUNTRY",
+            ExtraParamName::BirthCity => "BIRTH_CITY",
+            ExtraParamName::DocumentNumber => "DOCUMENT_NUMBER",
+            ExtraParamName::AuIdNumber => "AU_ID_NUMBER",
+            ExtraParamName::AuIdType => "AU_ID_TYPE",
+            ExtraParamName::CaLegalType => "CA_LEGAL_TYPE",
+            ExtraParamName::CaBusinessEntityType => "CA_BUSINESS_ENTITY_TYPE",
+            ExtraParamName::CaLegalRepresentative => "CA_LEGAL_REPRESENTATIVE",
+            ExtraParamName::CaLegalRepresentativeCapacity => "CA_LEGAL_REPRESENTATIVE_CAPACITY",
+            ExtraParamName::EsIdentification => "ES_IDENTIFICATION",
+            ExtraParamName::EsIdentificationType => "ES_IDENTIFICATION_TYPE",
+            ExtraParamName::EsLegalForm => "ES_LEGAL_FORM",
+            ExtraParamName::FiBusinessNumber => "FI_BUSINESS_NUMBER",
+            ExtraParamName::FiIdNumber => "FI_ID_NUMBER",
+            ExtraParamName::FiNationality => "FI_NATIONALITY",
+            ExtraParamName::FiOrganizationType => "FI_ORGANIZATION_TYPE",
+            ExtraParamName::ItNationality => "IT_NATIONALITY",
+            ExtraParamName::ItPin => "IT_PIN",
+            ExtraParamName::ItRegistrantEntityType => "IT_REGISTRANT_ENTITY_TYPE",
+            ExtraParamName::RuPassportData => "RU_PASSPORT_DATA",
+            ExtraParamName::SeIdNumber => "SE_ID_NUMBER",
+            ExtraParamName::SgIdNumber => "SG_ID_NUMBER",
+            ExtraParamName::VatNumber => "VAT_NUMBER",
+            ExtraParamName::UkContactType => "UK_CONTACT_TYPE",
+            ExtraParamName::UkCompanyNumber => "UK_COMPANY_NUMBER",
+            ExtraParamName::EuCountryOfCitizenship => "EU_COUNTRY_OF_CITIZENSHIP",
+            ExtraParamName::AuPriorityToken => "AU_PRIORITY_TOKEN",
+            ExtraParamName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExtraParamName {
+    fn from(value: &'a str) -> ExtraParamName {
+        match value {
+            "DUNS_NUMBER" => ExtraParamName::DunsNumber,
+            "BRAND_NUMBER" => ExtraParamName::BrandNumber,
+            "BIRTH_DEPARTMENT" => ExtraParamName::BirthDepartment,
+            "BIRTH_DATE_IN_YYYY_MM_DD" => ExtraParamName::BirthDateInYyyyMmDd,
+            "BIRTH_COUNTRY" => ExtraParamName::BirthCountry,
+            "BIRTH_CITY" => ExtraParamName::BirthCity,
+            "DOCUMENT_NUMBER" => ExtraParamName::DocumentNumber,
+            "AU_ID_NUMBER" => ExtraParamName::AuIdNumber,
+            "AU_ID_TYPE" => ExtraParamName::AuIdType,
+            "CA_LEGAL_TYPE" => ExtraParamName::CaLegalType,
+            "CA_BUSINESS_ENTITY_TYPE" => ExtraParamName::CaBusinessEntityType,
+            "CA_LEGAL_REPRESENTATIVE" => ExtraParamName::CaLegalRepresentative,
+            "CA_LEGAL_REPRESENTATIVE_CAPACITY" => ExtraParamName::CaLegalRepresentativeCapacity,
+            "ES_IDENTIFICATION" => ExtraParamName::EsIdentification,
+            "ES_IDENTIFICATION_TYPE" => ExtraParamName::EsIdentificationType,
+            "ES_LEGAL_FORM" => ExtraParamName::EsLegalForm,
+            "FI_BUSINESS_NUMBER" => ExtraParamName::FiBusinessNumber,
+            "FI_ID_NUMBER" => ExtraParamName::FiIdNumber,
+            "FI_NATIONALITY" => ExtraParamName::FiNationality,
+            "FI_ORGANIZATION_TYPE" => ExtraParamName::FiOrganizationType,
+            "IT_NATIONALITY" => ExtraParamName::ItNationality,
+            "IT_PIN" => ExtraParamName::ItPin,
+            "IT_REGISTRANT_ENTITY_TYPE" => ExtraParamName::ItRegistrantEntityType,
+            "RU_PASSPORT_DATA" => ExtraParamName::RuPassportData,
+            "SE_ID_NUMBER" => ExtraParamName::SeIdNumber,
+            "SG_ID_NUMBER" => ExtraParamName::SgIdNumber,
+            "VAT_NUMBER" => ExtraParamName::VatNumber,
+            "UK_CONTACT_TYPE" => ExtraParamName::UkContactType,
+            "UK_COMPANY_NUMBER" => ExtraParamName::UkCompanyNumber,
+            "EU_COUNTRY_OF_CITIZENSHIP" => ExtraParamName::EuCountryOfCitizenship,
+            "AU_PRIORITY_TOKEN" => ExtraParamName::AuPriorityToken,
+            _ => ExtraParamName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExtraParamName {
+    fn from(value: String) -> ExtraParamName {
+        ExtraParamName::from(value.as_str())
+    }
+}
+
+impl From<ExtraParamName> for String {
+    fn from(value: ExtraParamName) -> String {
+        match value {
+            ExtraParamName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExtraParamName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExtraParamName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExtraParamName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExtraParamName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExtraParamName::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetContactReachabilityStatusRequest {
     /// <p>The name of the domain for which you want to know whether the registrant contact has confirmed that the email address is valid.</p>
@@ -286,6 +1975,36 @@ pub struct GetContactReachabilityStatusRequest {
     pub domain_name: Option<String>,
 }
 
+impl GetContactReachabilityStatusRequest {
+    /// Returns a builder for `GetContactReachabilityStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetContactReachabilityStatusRequestBuilder {
+        GetContactReachabilityStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetContactReachabilityStatusRequest`](struct.GetContactReachabilityStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetContactReachabilityStatusRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl GetContactReachabilityStatusRequestBuilder {
+    /// Sets the [`domain_name`](struct.GetContactReachabilityStatusRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetContactReachabilityStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetContactReachabilityStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetContactReachabilityStatusRequest {
+            domain_name: self.domain_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetContactReachabilityStatusResponse {
@@ -296,7 +2015,7 @@ pub struct GetContactReachabilityStatusResponse {
     /// <p><p>Whether the registrant contact has responded. Values include the following:</p> <dl> <dt>PENDING</dt> <dd> <p>We sent the confirmation email and haven&#39;t received a response yet.</p> </dd> <dt>DONE</dt> <dd> <p>We sent the email and got confirmation from the registrant contact.</p> </dd> <dt>EXPIRED</dt> <dd> <p>The time limit expired before the registrant contact responded.</p> </dd> </dl></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ReachabilityStatus>,
 }
 
 /// <p>The GetDomainDetail request includes the following element.</p>
@@ -307,6 +2026,36 @@ pub struct GetDomainDetailRequest {
     pub domain_name: String,
 }
 
+impl GetDomainDetailRequest {
+    /// Returns a builder for `GetDomainDetailRequest`, checking its required members when built.
+    pub fn builder() -> GetDomainDetailRequestBuilder {
+        GetDomainDetailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDomainDetailRequest`](struct.GetDomainDetailRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDomainDetailRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl GetDomainDetailRequestBuilder {
+    /// Sets the [`domain_name`](struct.GetDomainDetailRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDomainDetailRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDomainDetailRequest, ::rusoto_core::BuildError> {
+        Ok(GetDomainDetailRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// <p>The GetDomainDetail response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -332,8 +2081,9 @@ pub struct GetDomainDetailResponse {
     pub auto_renew: Option<bool>,
     /// <p>The date when the domain was created as found in the response to a WHOIS query. The date and time is in Coordinated Universal time (UTC).</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>Reserved for future use.</p>
     #[serde(rename = "DnsSec")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -343,8 +2093,9 @@ pub struct GetDomainDetailResponse {
     pub domain_name: String,
     /// <p>The date when the registration for the domain is set to expire. The date and time is in Coordinated Universal time (UTC).</p>
     #[serde(rename = "ExpirationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_date: Option<f64>,
+    pub expiration_date: Option<EpochTimestamp>,
     /// <p>The name of the domain.</p>
     #[serde(rename = "Nameservers")]
     pub nameservers: Vec<Nameserver>,
@@ -384,8 +2135,9 @@ pub struct GetDomainDetailResponse {
     pub tech_privacy: Option<bool>,
     /// <p>The last updated date of the domain as found in the response to a WHOIS query. The date and time is in Coordinated Universal time (UTC).</p>
     #[serde(rename = "UpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_date: Option<f64>,
+    pub updated_date: Option<EpochTimestamp>,
     /// <p>The fully qualified name of the WHOIS server that can answer the WHOIS query for the domain.</p>
     #[serde(rename = "WhoIsServer")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -405,6 +2157,58 @@ pub struct GetDomainSuggestionsRequest {
     pub suggestion_count: i64,
 }
 
+impl GetDomainSuggestionsRequest {
+    /// Returns a builder for `GetDomainSuggestionsRequest`, checking its required members when built.
+    pub fn builder() -> GetDomainSuggestionsRequestBuilder {
+        GetDomainSuggestionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDomainSuggestionsRequest`](struct.GetDomainSuggestionsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDomainSuggestionsRequestBuilder {
+    domain_name: Option<String>,
+    only_available: Option<bool>,
+    suggestion_count: Option<i64>,
+}
+
+impl GetDomainSuggestionsRequestBuilder {
+    /// Sets the [`domain_name`](struct.GetDomainSuggestionsRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`only_available`](struct.GetDomainSuggestionsRequest.html#structfield.only_available) member.
+    pub fn only_available<T: Into<bool>>(mut self, value: T) -> Self {
+        self.only_available = Some(value.into());
+        self
+    }
+
+    /// Sets the [`suggestion_count`](struct.GetDomainSuggestionsRequest.html#structfield.suggestion_count) member.
+    pub fn suggestion_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.suggestion_count = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDomainSuggestionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDomainSuggestionsRequest, ::rusoto_core::BuildError> {
+        Ok(GetDomainSuggestionsRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            only_available: self
+                .only_available
+                .ok_or(::rusoto_core::BuildError::missing("only_available"))?,
+            suggestion_count: self
+                .suggestion_count
+                .ok_or(::rusoto_core::BuildError::missing("suggestion_count"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDomainSuggestionsResponse {
@@ -422,6 +2226,38 @@ pub struct GetOperationDetailRequest {
     pub operation_id: String,
 }
 
+impl GetOperationDetailRequest {
+    /// Returns a builder for `GetOperationDetailRequest`, checking its required members when built.
+    pub fn builder() -> GetOperationDetailRequestBuilder {
+        GetOperationDetailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetOperationDetailRequest`](struct.GetOperationDetailRequest.html).
+#[derive(Default, Debug)]
+pub struct GetOperationDetailRequestBuilder {
+    operation_id: Option<String>,
+}
+
+impl GetOperationDetailRequestBuilder {
+    /// Sets the [`operation_id`](struct.GetOperationDetailRequest.html#structfield.operation_id) member.
+    pub fn operation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOperationDetailRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetOperationDetailRequest, ::rusoto_core::BuildError> {
+        Ok(GetOperationDetailRequest {
+            operation_id: self
+                .operation_id
+                .ok_or(::rusoto_core::BuildError::missing("operation_id"))?,
+        })
+    }
+}
+
 /// <p>The GetOperationDetail response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -441,15 +2277,16 @@ pub struct GetOperationDetailResponse {
     /// <p>The current status of the requested operation in the system.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OperationStatus>,
     /// <p>The date when the request was submitted.</p>
     #[serde(rename = "SubmittedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submitted_date: Option<f64>,
+    pub submitted_date: Option<EpochTimestamp>,
     /// <p>The type of operation that was requested.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OperationType>,
 }
 
 /// <p>The ListDomains request includes the following elements.</p>
@@ -465,6 +2302,42 @@ pub struct ListDomainsRequest {
     pub max_items: Option<i64>,
 }
 
+impl ListDomainsRequest {
+    /// Returns a builder for `ListDomainsRequest`, checking its required members when built.
+    pub fn builder() -> ListDomainsRequestBuilder {
+        ListDomainsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDomainsRequest`](struct.ListDomainsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDomainsRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl ListDomainsRequestBuilder {
+    /// Sets the [`marker`](struct.ListDomainsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListDomainsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDomainsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDomainsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDomainsRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// <p>The ListDomains response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -491,8 +2364,53 @@ pub struct ListOperationsRequest {
     pub max_items: Option<i64>,
     /// <p>An optional parameter that lets you get information about all the operations that you submitted after a specified date and time. Specify the date and time in Coordinated Universal time (UTC).</p>
     #[serde(rename = "SubmittedSince")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submitted_since: Option<f64>,
+    pub submitted_since: Option<EpochTimestamp>,
+}
+
+impl ListOperationsRequest {
+    /// Returns a builder for `ListOperationsRequest`, checking its required members when built.
+    pub fn builder() -> ListOperationsRequestBuilder {
+        ListOperationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOperationsRequest`](struct.ListOperationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOperationsRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    submitted_since: Option<EpochTimestamp>,
+}
+
+impl ListOperationsRequestBuilder {
+    /// Sets the [`marker`](struct.ListOperationsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListOperationsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`submitted_since`](struct.ListOperationsRequest.html#structfield.submitted_since) member.
+    pub fn submitted_since<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.submitted_since = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOperationsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListOperationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListOperationsRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            submitted_since: self.submitted_since,
+        })
+    }
 }
 
 /// <p>The ListOperations response includes the following elements.</p>
@@ -516,6 +2434,38 @@ pub struct ListTagsForDomainRequest {
     pub domain_name: String,
 }
 
+impl ListTagsForDomainRequest {
+    /// Returns a builder for `ListTagsForDomainRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForDomainRequestBuilder {
+        ListTagsForDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForDomainRequest`](struct.ListTagsForDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForDomainRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl ListTagsForDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.ListTagsForDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForDomainRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// <p>The ListTagsForDomain response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -537,8 +2487,92 @@ pub struct Nameserver {
     pub name: String,
 }
 
+/// <p>The current status of the requested operation in the system.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperationStatus {
+    /// <p><code>SUBMITTED</code></p>
+    Submitted,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>SUCCESSFUL</code></p>
+    Successful,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OperationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationStatus::Submitted => "SUBMITTED",
+            OperationStatus::InProgress => "IN_PROGRESS",
+            OperationStatus::Error => "ERROR",
+            OperationStatus::Successful => "SUCCESSFUL",
+            OperationStatus::Failed => "FAILED",
+            OperationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperationStatus {
+    fn from(value: &'a str) -> OperationStatus {
+        match value {
+            "SUBMITTED" => OperationStatus::Submitted,
+            "IN_PROGRESS" => OperationStatus::InProgress,
+            "ERROR" => OperationStatus::Error,
+            "SUCCESSFUL" => OperationStatus::Successful,
+            "FAILED" => OperationStatus::Failed,
+            _ => OperationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationStatus {
+    fn from(value: String) -> OperationStatus {
+        OperationStatus::from(value.as_str())
+    }
+}
+
+impl From<OperationStatus> for String {
+    fn from(value: OperationStatus) -> String {
+        match value {
+            OperationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OperationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OperationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OperationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OperationStatus::from(value))
+    }
+}
+
 /// <p>OperationSummary includes the following elements.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct OperationSummary {
     /// <p>Identifier returned to track the requested action.</p>
@@ -546,13 +2580,226 @@ pub struct OperationSummary {
     pub operation_id: String,
     /// <p>The current status of the requested operation in the system.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: OperationStatus,
     /// <p>The date when the request was submitted.</p>
     #[serde(rename = "SubmittedDate")]
-    pub submitted_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub submitted_date: EpochTimestamp,
     /// <p>Type of the action requested.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: OperationType,
+}
+
+/// <p>The operation that you were charged for.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperationType {
+    /// <p><code>REGISTER_DOMAIN</code></p>
+    RegisterDomain,
+    /// <p><code>DELETE_DOMAIN</code></p>
+    DeleteDomain,
+    /// <p><code>TRANSFER_IN_DOMAIN</code></p>
+    TransferInDomain,
+    /// <p><code>UPDATE_DOMAIN_CONTACT</code></p>
+    UpdateDomainContact,
+    /// <p><code>UPDATE_NAMESERVER</code></p>
+    UpdateNameserver,
+    /// <p><code>CHANGE_PRIVACY_PROTECTION</code></p>
+    ChangePrivacyProtection,
+    /// <p><code>DOMAIN_LOCK</code></p>
+    DomainLock,
+    /// <p><code>ENABLE_AUTORENEW</code></p>
+    EnableAutorenew,
+    /// <p><code>DISABLE_AUTORENEW</code></p>
+    DisableAutorenew,
+    /// <p><code>ADD_DNSSEC</code></p>
+    AddDnssec,
+    /// <p><code>REMOVE_DNSSEC</code></p>
+    RemoveDnssec,
+    /// <p><code>EXPIRE_DOMAIN</code></p>
+    ExpireDomain,
+    /// <p><code>TRANSFER_OUT_DOMAIN</code></p>
+    TransferOutDomain,
+    /// <p><code>CHANGE_DOMAIN_OWNER</code></p>
+    ChangeDomainOwner,
+    /// <p><code>RENEW_DOMAIN</code></p>
+    RenewDomain,
+    /// <p><code>PUSH_DOMAIN</code></p>
+    PushDomain,
+    /// <p><code>INTERNAL_TRANSFER_OUT_DOMAIN</code></p>
+    InternalTransferOutDomain,
+    /// <p><code>INTERNAL_TRANSFER_IN_DOMAIN</code></p>
+    InternalTransferInDomain,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OperationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationType::RegisterDomain => "REGISTER_DOMAIN",
+            OperationType::DeleteDomain => "DELETE_DOMAIN",
+            OperationType::TransferInDomain => "TRANSFER_IN_DOMAIN",
+            OperationType::UpdateDomainContact => "UPDATE_DOMAIN_CONTACT",
+            OperationType::UpdateNameserver => "UPDATE_NAMESERVER",
+            OperationType::ChangePrivacyProtection => "CHANGE_PRIVACY_PROTECTION",
+            OperationType::DomainLock => "DOMAIN_LOCK",
+            OperationType::EnableAutorenew => "ENABLE_AUTORENEW",
+            OperationType::DisableAutorenew => "DISABLE_AUTORENEW",
+            OperationType::AddDnssec => "ADD_DNSSEC",
+            OperationType::RemoveDnssec => "REMOVE_DNSSEC",
+            OperationType::ExpireDomain => "EXPIRE_DOMAIN",
+            OperationType::TransferOutDomain => "TRANSFER_OUT_DOMAIN",
+            OperationType::ChangeDomainOwner => "CHANGE_DOMAIN_OWNER",
+            OperationType::RenewDomain => "RENEW_DOMAIN",
+            OperationType::PushDomain => "PUSH_DOMAIN",
+            OperationType::InternalTransferOutDomain => "INTERNAL_TRANSFER_OUT_DOMAIN",
+            OperationType::InternalTransferInDomain => "INTERNAL_TRANSFER_IN_DOMAIN",
+            OperationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperationType {
+    fn from(value: &'a str) -> OperationType {
+        match value {
+            "REGISTER_DOMAIN" => OperationType::RegisterDomain,
+            "DELETE_DOMAIN" => OperationType::DeleteDomain,
+            "TRANSFER_IN_DOMAIN" => OperationType::TransferInDomain,
+            "UPDATE_DOMAIN_CONTACT" => OperationType::UpdateDomainContact,
+            "UPDATE_NAMESERVER" => OperationType::UpdateNameserver,
+            "CHANGE_PRIVACY_PROTECTION" => OperationType::ChangePrivacyProtection,
+            "DOMAIN_LOCK" => OperationType::DomainLock,
+            "ENABLE_AUTORENEW" => OperationType::EnableAutorenew,
+            "DISABLE_AUTORENEW" => OperationType::DisableAutorenew,
+            "ADD_DNSSEC" => OperationType::AddDnssec,
+            "REMOVE_DNSSEC" => OperationType::RemoveDnssec,
+            "EXPIRE_DOMAIN" => OperationType::ExpireDomain,
+            "TRANSFER_OUT_DOMAIN" => OperationType::TransferOutDomain,
+            "CHANGE_DOMAIN_OWNER" => OperationType::ChangeDomainOwner,
+            "RENEW_DOMAIN" => OperationType::RenewDomain,
+            "PUSH_DOMAIN" => OperationType::PushDomain,
+            "INTERNAL_TRANSFER_OUT_DOMAIN" => OperationType::InternalTransferOutDomain,
+            "INTERNAL_TRANSFER_IN_DOMAIN" => OperationType::InternalTransferInDomain,
+            _ => OperationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationType {
+    fn from(value: String) -> OperationType {
+        OperationType::from(value.as_str())
+    }
+}
+
+impl From<OperationType> for String {
+    fn from(value: OperationType) -> String {
+        match value {
+            OperationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OperationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OperationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OperationType::from(value))
+    }
+}
+
+/// <p><p>Whether the registrant contact has responded. Values include the following:</p> <dl> <dt>PENDING</dt> <dd> <p>We sent the confirmation email and haven&#39;t received a response yet.</p> </dd> <dt>DONE</dt> <dd> <p>We sent the email and got confirmation from the registrant contact.</p> </dd> <dt>EXPIRED</dt> <dd> <p>The time limit expired before the registrant contact responded.</p> </dd> </dl></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReachabilityStatus {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>DONE</code></p>
+    Done,
+    /// <p><code>EXPIRED</code></p>
+    Expired,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReachabilityStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReachabilityStatus::Pending => "PENDING",
+            ReachabilityStatus::Done => "DONE",
+            ReachabilityStatus::Expired => "EXPIRED",
+            ReachabilityStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReachabilityStatus {
+    fn from(value: &'a str) -> ReachabilityStatus {
+        match value {
+            "PENDING" => ReachabilityStatus::Pending,
+            "DONE" => ReachabilityStatus::Done,
+            "EXPIRED" => ReachabilityStatus::Expired,
+            _ => ReachabilityStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReachabilityStatus {
+    fn from(value: String) -> ReachabilityStatus {
+        ReachabilityStatus::from(value.as_str())
+    }
+}
+
+impl From<ReachabilityStatus> for String {
+    fn from(value: ReachabilityStatus) -> String {
+        match value {
+            ReachabilityStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReachabilityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReachabilityStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReachabilityStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReachabilityStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReachabilityStatus::from(value))
+    }
 }
 
 /// <p>The RegisterDomain request includes the following elements.</p>
@@ -595,6 +2842,116 @@ pub struct RegisterDomainRequest {
     pub tech_contact: ContactDetail,
 }
 
+impl RegisterDomainRequest {
+    /// Returns a builder for `RegisterDomainRequest`, checking its required members when built.
+    pub fn builder() -> RegisterDomainRequestBuilder {
+        RegisterDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterDomainRequest`](struct.RegisterDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterDomainRequestBuilder {
+    admin_contact: Option<ContactDetail>,
+    auto_renew: Option<bool>,
+    domain_name: Option<String>,
+    duration_in_years: Option<i64>,
+    idn_lang_code: Option<String>,
+    privacy_protect_admin_contact: Option<bool>,
+    privacy_protect_registrant_contact: Option<bool>,
+    privacy_protect_tech_contact: Option<bool>,
+    registrant_contact: Option<ContactDetail>,
+    tech_contact: Option<ContactDetail>,
+}
+
+impl RegisterDomainRequestBuilder {
+    /// Sets the [`admin_contact`](struct.RegisterDomainRequest.html#structfield.admin_contact) member.
+    pub fn admin_contact<T: Into<ContactDetail>>(mut self, value: T) -> Self {
+        self.admin_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_renew`](struct.RegisterDomainRequest.html#structfield.auto_renew) member.
+    pub fn auto_renew<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_renew = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.RegisterDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`duration_in_years`](struct.RegisterDomainRequest.html#structfield.duration_in_years) member.
+    pub fn duration_in_years<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration_in_years = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idn_lang_code`](struct.RegisterDomainRequest.html#structfield.idn_lang_code) member.
+    pub fn idn_lang_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.idn_lang_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`privacy_protect_admin_contact`](struct.RegisterDomainRequest.html#structfield.privacy_protect_admin_contact) member.
+    pub fn privacy_protect_admin_contact<T: Into<bool>>(mut self, value: T) -> Self {
+        self.privacy_protect_admin_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`privacy_protect_registrant_contact`](struct.RegisterDomainRequest.html#structfield.privacy_protect_registrant_contact) member.
+    pub fn privacy_protect_registrant_contact<T: Into<bool>>(mut self, value: T) -> Self {
+        self.privacy_protect_registrant_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`privacy_protect_tech_contact`](struct.RegisterDomainRequest.html#structfield.privacy_protect_tech_contact) member.
+    pub fn privacy_protect_tech_contact<T: Into<bool>>(mut self, value: T) -> Self {
+        self.privacy_protect_tech_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registrant_contact`](struct.RegisterDomainRequest.html#structfield.registrant_contact) member.
+    pub fn registrant_contact<T: Into<ContactDetail>>(mut self, value: T) -> Self {
+        self.registrant_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tech_contact`](struct.RegisterDomainRequest.html#structfield.tech_contact) member.
+    pub fn tech_contact<T: Into<ContactDetail>>(mut self, value: T) -> Self {
+        self.tech_contact = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RegisterDomainRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterDomainRequest {
+            admin_contact: self
+                .admin_contact
+                .ok_or(::rusoto_core::BuildError::missing("admin_contact"))?,
+            auto_renew: self.auto_renew,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            duration_in_years: self
+                .duration_in_years
+                .ok_or(::rusoto_core::BuildError::missing("duration_in_years"))?,
+            idn_lang_code: self.idn_lang_code,
+            privacy_protect_admin_contact: self.privacy_protect_admin_contact,
+            privacy_protect_registrant_contact: self.privacy_protect_registrant_contact,
+            privacy_protect_tech_contact: self.privacy_protect_tech_contact,
+            registrant_contact: self
+                .registrant_contact
+                .ok_or(::rusoto_core::BuildError::missing("registrant_contact"))?,
+            tech_contact: self
+                .tech_contact
+                .ok_or(::rusoto_core::BuildError::missing("tech_contact"))?,
+        })
+    }
+}
+
 /// <p>The RegisterDomain response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -619,6 +2976,54 @@ pub struct RenewDomainRequest {
     pub duration_in_years: Option<i64>,
 }
 
+impl RenewDomainRequest {
+    /// Returns a builder for `RenewDomainRequest`, checking its required members when built.
+    pub fn builder() -> RenewDomainRequestBuilder {
+        RenewDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RenewDomainRequest`](struct.RenewDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct RenewDomainRequestBuilder {
+    current_expiry_year: Option<i64>,
+    domain_name: Option<String>,
+    duration_in_years: Option<i64>,
+}
+
+impl RenewDomainRequestBuilder {
+    /// Sets the [`current_expiry_year`](struct.RenewDomainRequest.html#structfield.current_expiry_year) member.
+    pub fn current_expiry_year<T: Into<i64>>(mut self, value: T) -> Self {
+        self.current_expiry_year = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.RenewDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`duration_in_years`](struct.RenewDomainRequest.html#structfield.duration_in_years) member.
+    pub fn duration_in_years<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration_in_years = Some(value.into());
+        self
+    }
+
+    /// Builds the `RenewDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RenewDomainRequest, ::rusoto_core::BuildError> {
+        Ok(RenewDomainRequest {
+            current_expiry_year: self
+                .current_expiry_year
+                .ok_or(::rusoto_core::BuildError::missing("current_expiry_year"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            duration_in_years: self.duration_in_years,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RenewDomainResponse {
@@ -635,6 +3040,37 @@ pub struct ResendContactReachabilityEmailRequest {
     pub domain_name: Option<String>,
 }
 
+impl ResendContactReachabilityEmailRequest {
+    /// Returns a builder for `ResendContactReachabilityEmailRequest`, checking its required members when built.
+    pub fn builder() -> ResendContactReachabilityEmailRequestBuilder {
+        ResendContactReachabilityEmailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResendContactReachabilityEmailRequest`](struct.ResendContactReachabilityEmailRequest.html).
+#[derive(Default, Debug)]
+pub struct ResendContactReachabilityEmailRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl ResendContactReachabilityEmailRequestBuilder {
+    /// Sets the [`domain_name`](struct.ResendContactReachabilityEmailRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResendContactReachabilityEmailRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResendContactReachabilityEmailRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ResendContactReachabilityEmailRequest {
+            domain_name: self.domain_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ResendContactReachabilityEmailResponse {
@@ -660,6 +3096,38 @@ pub struct RetrieveDomainAuthCodeRequest {
     pub domain_name: String,
 }
 
+impl RetrieveDomainAuthCodeRequest {
+    /// Returns a builder for `RetrieveDomainAuthCodeRequest`, checking its required members when built.
+    pub fn builder() -> RetrieveDomainAuthCodeRequestBuilder {
+        RetrieveDomainAuthCodeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RetrieveDomainAuthCodeRequest`](struct.RetrieveDomainAuthCodeRequest.html).
+#[derive(Default, Debug)]
+pub struct RetrieveDomainAuthCodeRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl RetrieveDomainAuthCodeRequestBuilder {
+    /// Sets the [`domain_name`](struct.RetrieveDomainAuthCodeRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RetrieveDomainAuthCodeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RetrieveDomainAuthCodeRequest, ::rusoto_core::BuildError> {
+        Ok(RetrieveDomainAuthCodeRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// <p>The RetrieveDomainAuthCode response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -730,13 +3198,227 @@ pub struct TransferDomainRequest {
     pub tech_contact: ContactDetail,
 }
 
-/// <p>The TranserDomain response includes the following element.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct TransferDomainResponse {
-    /// <p>Identifier for tracking the progress of the request. To use this ID to query the operation status, use <a>GetOperationDetail</a>.</p>
-    #[serde(rename = "OperationId")]
-    pub operation_id: String,
+impl TransferDomainRequest {
+    /// Returns a builder for `TransferDomainRequest`, checking its required members when built.
+    pub fn builder() -> TransferDomainRequestBuilder {
+        TransferDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TransferDomainRequest`](struct.TransferDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct TransferDomainRequestBuilder {
+    admin_contact: Option<ContactDetail>,
+    auth_code: Option<String>,
+    auto_renew: Option<bool>,
+    domain_name: Option<String>,
+    duration_in_years: Option<i64>,
+    idn_lang_code: Option<String>,
+    nameservers: Option<Vec<Nameserver>>,
+    privacy_protect_admin_contact: Option<bool>,
+    privacy_protect_registrant_contact: Option<bool>,
+    privacy_protect_tech_contact: Option<bool>,
+    registrant_contact: Option<ContactDetail>,
+    tech_contact: Option<ContactDetail>,
+}
+
+impl TransferDomainRequestBuilder {
+    /// Sets the [`admin_contact`](struct.TransferDomainRequest.html#structfield.admin_contact) member.
+    pub fn admin_contact<T: Into<ContactDetail>>(mut self, value: T) -> Self {
+        self.admin_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auth_code`](struct.TransferDomainRequest.html#structfield.auth_code) member.
+    pub fn auth_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.auth_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_renew`](struct.TransferDomainRequest.html#structfield.auto_renew) member.
+    pub fn auto_renew<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_renew = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.TransferDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`duration_in_years`](struct.TransferDomainRequest.html#structfield.duration_in_years) member.
+    pub fn duration_in_years<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration_in_years = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idn_lang_code`](struct.TransferDomainRequest.html#structfield.idn_lang_code) member.
+    pub fn idn_lang_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.idn_lang_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`nameservers`](struct.TransferDomainRequest.html#structfield.nameservers) member.
+    pub fn nameservers<T: Into<Vec<Nameserver>>>(mut self, value: T) -> Self {
+        self.nameservers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`privacy_protect_admin_contact`](struct.TransferDomainRequest.html#structfield.privacy_protect_admin_contact) member.
+    pub fn privacy_protect_admin_contact<T: Into<bool>>(mut self, value: T) -> Self {
+        self.privacy_protect_admin_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`privacy_protect_registrant_contact`](struct.TransferDomainRequest.html#structfield.privacy_protect_registrant_contact) member.
+    pub fn privacy_protect_registrant_contact<T: Into<bool>>(mut self, value: T) -> Self {
+        self.privacy_protect_registrant_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`privacy_protect_tech_contact`](struct.TransferDomainRequest.html#structfield.privacy_protect_tech_contact) member.
+    pub fn privacy_protect_tech_contact<T: Into<bool>>(mut self, value: T) -> Self {
+        self.privacy_protect_tech_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registrant_contact`](struct.TransferDomainRequest.html#structfield.registrant_contact) member.
+    pub fn registrant_contact<T: Into<ContactDetail>>(mut self, value: T) -> Self {
+        self.registrant_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tech_contact`](struct.TransferDomainRequest.html#structfield.tech_contact) member.
+    pub fn tech_contact<T: Into<ContactDetail>>(mut self, value: T) -> Self {
+        self.tech_contact = Some(value.into());
+        self
+    }
+
+    /// Builds the `TransferDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TransferDomainRequest, ::rusoto_core::BuildError> {
+        Ok(TransferDomainRequest {
+            admin_contact: self
+                .admin_contact
+                .ok_or(::rusoto_core::BuildError::missing("admin_contact"))?,
+            auth_code: self.auth_code,
+            auto_renew: self.auto_renew,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            duration_in_years: self
+                .duration_in_years
+                .ok_or(::rusoto_core::BuildError::missing("duration_in_years"))?,
+            idn_lang_code: self.idn_lang_code,
+            nameservers: self.nameservers,
+            privacy_protect_admin_contact: self.privacy_protect_admin_contact,
+            privacy_protect_registrant_contact: self.privacy_protect_registrant_contact,
+            privacy_protect_tech_contact: self.privacy_protect_tech_contact,
+            registrant_contact: self
+                .registrant_contact
+                .ok_or(::rusoto_core::BuildError::missing("registrant_contact"))?,
+            tech_contact: self
+                .tech_contact
+                .ok_or(::rusoto_core::BuildError::missing("tech_contact"))?,
+        })
+    }
+}
+
+/// <p>The TranserDomain response includes the following element.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct TransferDomainResponse {
+    /// <p>Identifier for tracking the progress of the request. To use this ID to query the operation status, use <a>GetOperationDetail</a>.</p>
+    #[serde(rename = "OperationId")]
+    pub operation_id: String,
+}
+
+/// <p>Whether the domain name can be transferred to Route 53.</p> <note> <p>You can transfer only domains that have a value of <code>TRANSFERABLE</code> or <code>Transferable</code>.</p> </note> <p>Valid values:</p> <dl> <dt>TRANSFERABLE</dt> <dd> <p>The domain name can be transferred to Route 53.</p> </dd> <dt>UNTRANSFERRABLE</dt> <dd> <p>The domain name can't be transferred to Route 53.</p> </dd> <dt>DONT_KNOW</dt> <dd> <p>Reserved for future use.</p> </dd> <dt>DOMAIN_IN_OWN_ACCOUNT</dt> <dd> <p>The domain already exists in the current Amazon Web Services account.</p> </dd> <dt>DOMAIN_IN_ANOTHER_ACCOUNT</dt> <dd> <p> the domain exists in another Amazon Web Services account.</p> </dd> <dt>PREMIUM_DOMAIN</dt> <dd> <p>Premium domain transfer is not supported.</p> </dd> </dl>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Transferable {
+    /// <p><code>TRANSFERABLE</code></p>
+    Transferable,
+    /// <p><code>UNTRANSFERABLE</code></p>
+    Untransferable,
+    /// <p><code>DONT_KNOW</code></p>
+    DontKnow,
+    /// <p><code>DOMAIN_IN_OWN_ACCOUNT</code></p>
+    DomainInOwnAccount,
+    /// <p><code>DOMAIN_IN_ANOTHER_ACCOUNT</code></p>
+    DomainInAnotherAccount,
+    /// <p><code>PREMIUM_DOMAIN</code></p>
+    PremiumDomain,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Transferable {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Transferable::Transferable => "TRANSFERABLE",
+            Transferable::Untransferable => "UNTRANSFERABLE",
+            Transferable::DontKnow => "DONT_KNOW",
+            Transferable::DomainInOwnAccount => "DOMAIN_IN_OWN_ACCOUNT",
+            Transferable::DomainInAnotherAccount => "DOMAIN_IN_ANOTHER_ACCOUNT",
+            Transferable::PremiumDomain => "PREMIUM_DOMAIN",
+            Transferable::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Transferable {
+    fn from(value: &'a str) -> Transferable {
+        match value {
+            "TRANSFERABLE" => Transferable::Transferable,
+            "UNTRANSFERABLE" => Transferable::Untransferable,
+            "DONT_KNOW" => Transferable::DontKnow,
+            "DOMAIN_IN_OWN_ACCOUNT" => Transferable::DomainInOwnAccount,
+            "DOMAIN_IN_ANOTHER_ACCOUNT" => Transferable::DomainInAnotherAccount,
+            "PREMIUM_DOMAIN" => Transferable::PremiumDomain,
+            _ => Transferable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Transferable {
+    fn from(value: String) -> Transferable {
+        Transferable::from(value.as_str())
+    }
+}
+
+impl From<Transferable> for String {
+    fn from(value: Transferable) -> String {
+        match value {
+            Transferable::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Transferable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Transferable {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Transferable {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Transferable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Transferable::from(value))
+    }
 }
 
 /// <p>The UpdateDomainContactPrivacy request includes the following elements.</p>
@@ -759,6 +3441,62 @@ pub struct UpdateDomainContactPrivacyRequest {
     pub tech_privacy: Option<bool>,
 }
 
+impl UpdateDomainContactPrivacyRequest {
+    /// Returns a builder for `UpdateDomainContactPrivacyRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDomainContactPrivacyRequestBuilder {
+        UpdateDomainContactPrivacyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDomainContactPrivacyRequest`](struct.UpdateDomainContactPrivacyRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDomainContactPrivacyRequestBuilder {
+    admin_privacy: Option<bool>,
+    domain_name: Option<String>,
+    registrant_privacy: Option<bool>,
+    tech_privacy: Option<bool>,
+}
+
+impl UpdateDomainContactPrivacyRequestBuilder {
+    /// Sets the [`admin_privacy`](struct.UpdateDomainContactPrivacyRequest.html#structfield.admin_privacy) member.
+    pub fn admin_privacy<T: Into<bool>>(mut self, value: T) -> Self {
+        self.admin_privacy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.UpdateDomainContactPrivacyRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registrant_privacy`](struct.UpdateDomainContactPrivacyRequest.html#structfield.registrant_privacy) member.
+    pub fn registrant_privacy<T: Into<bool>>(mut self, value: T) -> Self {
+        self.registrant_privacy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tech_privacy`](struct.UpdateDomainContactPrivacyRequest.html#structfield.tech_privacy) member.
+    pub fn tech_privacy<T: Into<bool>>(mut self, value: T) -> Self {
+        self.tech_privacy = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDomainContactPrivacyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDomainContactPrivacyRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDomainContactPrivacyRequest {
+            admin_privacy: self.admin_privacy,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            registrant_privacy: self.registrant_privacy,
+            tech_privacy: self.tech_privacy,
+        })
+    }
+}
+
 /// <p>The UpdateDomainContactPrivacy response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -788,6 +3526,62 @@ pub struct UpdateDomainContactRequest {
     pub tech_contact: Option<ContactDetail>,
 }
 
+impl UpdateDomainContactRequest {
+    /// Returns a builder for `UpdateDomainContactRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDomainContactRequestBuilder {
+        UpdateDomainContactRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDomainContactRequest`](struct.UpdateDomainContactRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDomainContactRequestBuilder {
+    admin_contact: Option<ContactDetail>,
+    domain_name: Option<String>,
+    registrant_contact: Option<ContactDetail>,
+    tech_contact: Option<ContactDetail>,
+}
+
+impl UpdateDomainContactRequestBuilder {
+    /// Sets the [`admin_contact`](struct.UpdateDomainContactRequest.html#structfield.admin_contact) member.
+    pub fn admin_contact<T: Into<ContactDetail>>(mut self, value: T) -> Self {
+        self.admin_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.UpdateDomainContactRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registrant_contact`](struct.UpdateDomainContactRequest.html#structfield.registrant_contact) member.
+    pub fn registrant_contact<T: Into<ContactDetail>>(mut self, value: T) -> Self {
+        self.registrant_contact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tech_contact`](struct.UpdateDomainContactRequest.html#structfield.tech_contact) member.
+    pub fn tech_contact<T: Into<ContactDetail>>(mut self, value: T) -> Self {
+        self.tech_contact = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDomainContactRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDomainContactRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDomainContactRequest {
+            admin_contact: self.admin_contact,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            registrant_contact: self.registrant_contact,
+            tech_contact: self.tech_contact,
+        })
+    }
+}
+
 /// <p>The UpdateDomainContact response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -808,6 +3602,48 @@ pub struct UpdateDomainNameserversRequest {
     pub nameservers: Vec<Nameserver>,
 }
 
+impl UpdateDomainNameserversRequest {
+    /// Returns a builder for `UpdateDomainNameserversRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDomainNameserversRequestBuilder {
+        UpdateDomainNameserversRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDomainNameserversRequest`](struct.UpdateDomainNameserversRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDomainNameserversRequestBuilder {
+    domain_name: Option<String>,
+    nameservers: Option<Vec<Nameserver>>,
+}
+
+impl UpdateDomainNameserversRequestBuilder {
+    /// Sets the [`domain_name`](struct.UpdateDomainNameserversRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`nameservers`](struct.UpdateDomainNameserversRequest.html#structfield.nameservers) member.
+    pub fn nameservers<T: Into<Vec<Nameserver>>>(mut self, value: T) -> Self {
+        self.nameservers = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDomainNameserversRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDomainNameserversRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDomainNameserversRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            nameservers: self
+                .nameservers
+                .ok_or(::rusoto_core::BuildError::missing("nameservers"))?,
+        })
+    }
+}
+
 /// <p>The UpdateDomainNameservers response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -829,6 +3665,46 @@ pub struct UpdateTagsForDomainRequest {
     pub tags_to_update: Option<Vec<Tag>>,
 }
 
+impl UpdateTagsForDomainRequest {
+    /// Returns a builder for `UpdateTagsForDomainRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTagsForDomainRequestBuilder {
+        UpdateTagsForDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTagsForDomainRequest`](struct.UpdateTagsForDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTagsForDomainRequestBuilder {
+    domain_name: Option<String>,
+    tags_to_update: Option<Vec<Tag>>,
+}
+
+impl UpdateTagsForDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.UpdateTagsForDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags_to_update`](struct.UpdateTagsForDomainRequest.html#structfield.tags_to_update) member.
+    pub fn tags_to_update<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags_to_update = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTagsForDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateTagsForDomainRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTagsForDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            tags_to_update: self.tags_to_update,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateTagsForDomainResponse {}
@@ -838,8 +3714,9 @@ pub struct UpdateTagsForDomainResponse {}
 pub struct ViewBillingRequest {
     /// <p>The end date and time for the time period for which you want a list of billing records. Specify the date and time in Coordinated Universal time (UTC).</p>
     #[serde(rename = "End")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end: Option<f64>,
+    pub end: Option<EpochTimestamp>,
     /// <p>For an initial request for a list of billing records, omit this element. If the number of billing records that are associated with the current AWS account during the specified period is greater than the value that you specified for <code>MaxItems</code>, you can use <code>Marker</code> to return additional billing records. Get the value of <code>NextPageMarker</code> from the previous response, and submit another request that includes the value of <code>NextPageMarker</code> in the <code>Marker</code> element. </p> <p>Constraints: The marker must match the value of <code>NextPageMarker</code> that was returned in the previous response.</p>
     #[serde(rename = "Marker")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -850,8 +3727,61 @@ pub struct ViewBillingRequest {
     pub max_items: Option<i64>,
     /// <p>The beginning date and time for the time period for which you want a list of billing records. Specify the date and time in Coordinated Universal time (UTC).</p>
     #[serde(rename = "Start")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start: Option<f64>,
+    pub start: Option<EpochTimestamp>,
+}
+
+impl ViewBillingRequest {
+    /// Returns a builder for `ViewBillingRequest`, checking its required members when built.
+    pub fn builder() -> ViewBillingRequestBuilder {
+        ViewBillingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ViewBillingRequest`](struct.ViewBillingRequest.html).
+#[derive(Default, Debug)]
+pub struct ViewBillingRequestBuilder {
+    end: Option<EpochTimestamp>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+    start: Option<EpochTimestamp>,
+}
+
+impl ViewBillingRequestBuilder {
+    /// Sets the [`end`](struct.ViewBillingRequest.html#structfield.end) member.
+    pub fn end<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ViewBillingRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ViewBillingRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start`](struct.ViewBillingRequest.html#structfield.start) member.
+    pub fn start<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start = Some(value.into());
+        self
+    }
+
+    /// Builds the `ViewBillingRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ViewBillingRequest, ::rusoto_core::BuildError> {
+        Ok(ViewBillingRequest {
+            end: self.end,
+            marker: self.marker,
+            max_items: self.max_items,
+            start: self.start,
+        })
+    }
 }
 
 /// <p>The ViewBilling response includes the following elements.</p>
@@ -2210,6 +5140,160 @@ pub trait Route53Domains {
         input: ViewBillingRequest,
     ) -> RusotoFuture<ViewBillingResponse, ViewBillingError>;
 }
+impl<T: ?Sized + Route53Domains> Route53Domains for ::std::sync::Arc<T> {
+    fn check_domain_availability(
+        &self,
+        input: CheckDomainAvailabilityRequest,
+    ) -> RusotoFuture<CheckDomainAvailabilityResponse, CheckDomainAvailabilityError> {
+        (**self).check_domain_availability(input)
+    }
+    fn check_domain_transferability(
+        &self,
+        input: CheckDomainTransferabilityRequest,
+    ) -> RusotoFuture<CheckDomainTransferabilityResponse, CheckDomainTransferabilityError> {
+        (**self).check_domain_transferability(input)
+    }
+    fn delete_tags_for_domain(
+        &self,
+        input: DeleteTagsForDomainRequest,
+    ) -> RusotoFuture<DeleteTagsForDomainResponse, DeleteTagsForDomainError> {
+        (**self).delete_tags_for_domain(input)
+    }
+    fn disable_domain_auto_renew(
+        &self,
+        input: DisableDomainAutoRenewRequest,
+    ) -> RusotoFuture<DisableDomainAutoRenewResponse, DisableDomainAutoRenewError> {
+        (**self).disable_domain_auto_renew(input)
+    }
+    fn disable_domain_transfer_lock(
+        &self,
+        input: DisableDomainTransferLockRequest,
+    ) -> RusotoFuture<DisableDomainTransferLockResponse, DisableDomainTransferLockError> {
+        (**self).disable_domain_transfer_lock(input)
+    }
+    fn enable_domain_auto_renew(
+        &self,
+        input: EnableDomainAutoRenewRequest,
+    ) -> RusotoFuture<EnableDomainAutoRenewResponse, EnableDomainAutoRenewError> {
+        (**self).enable_domain_auto_renew(input)
+    }
+    fn enable_domain_transfer_lock(
+        &self,
+        input: EnableDomainTransferLockRequest,
+    ) -> RusotoFuture<EnableDomainTransferLockResponse, EnableDomainTransferLockError> {
+        (**self).enable_domain_transfer_lock(input)
+    }
+    fn get_contact_reachability_status(
+        &self,
+        input: GetContactReachabilityStatusRequest,
+    ) -> RusotoFuture<GetContactReachabilityStatusResponse, GetContactReachabilityStatusError> {
+        (**self).get_contact_reachability_status(input)
+    }
+    fn get_domain_detail(
+        &self,
+        input: GetDomainDetailRequest,
+    ) -> RusotoFuture<GetDomainDetailResponse, GetDomainDetailError> {
+        (**self).get_domain_detail(input)
+    }
+    fn get_domain_suggestions(
+        &self,
+        input: GetDomainSuggestionsRequest,
+    ) -> RusotoFuture<GetDomainSuggestionsResponse, GetDomainSuggestionsError> {
+        (**self).get_domain_suggestions(input)
+    }
+    fn get_operation_detail(
+        &self,
+        input: GetOperationDetailRequest,
+    ) -> RusotoFuture<GetOperationDetailResponse, GetOperationDetailError> {
+        (**self).get_operation_detail(input)
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        (**self).list_operations(input)
+    }
+    fn list_tags_for_domain(
+        &self,
+        input: ListTagsForDomainRequest,
+    ) -> RusotoFuture<ListTagsForDomainResponse, ListTagsForDomainError> {
+        (**self).list_tags_for_domain(input)
+    }
+    fn register_domain(
+        &self,
+        input: RegisterDomainRequest,
+    ) -> RusotoFuture<RegisterDomainResponse, RegisterDomainError> {
+        (**self).register_domain(input)
+    }
+    fn renew_domain(
+        &self,
+        input: RenewDomainRequest,
+    ) -> RusotoFuture<RenewDomainResponse, RenewDomainError> {
+        (**self).renew_domain(input)
+    }
+    fn resend_contact_reachability_email(
+        &self,
+        input: ResendContactReachabilityEmailRequest,
+    ) -> RusotoFuture<ResendContactReachabilityEmailResponse, ResendContactReachabilityEmailError>
+    {
+        (**self).resend_contact_reachability_email(input)
+    }
+    fn retrieve_domain_auth_code(
+        &self,
+        input: RetrieveDomainAuthCodeRequest,
+    ) -> RusotoFuture<RetrieveDomainAuthCodeResponse, RetrieveDomainAuthCodeError> {
+        (**self).retrieve_domain_auth_code(input)
+    }
+    fn transfer_domain(
+        &self,
+        input: TransferDomainRequest,
+    ) -> RusotoFuture<TransferDomainResponse, TransferDomainError> {
+        (**self).transfer_domain(input)
+    }
+    fn update_domain_contact(
+        &self,
+        input: UpdateDomainContactRequest,
+    ) -> RusotoFuture<UpdateDomainContactResponse, UpdateDomainContactError> {
+        (**self).update_domain_contact(input)
+    }
+    fn update_domain_contact_privacy(
+        &self,
+        input: UpdateDomainContactPrivacyRequest,
+    ) -> RusotoFuture<UpdateDomainContactPrivacyResponse, UpdateDomainContactPrivacyError> {
+        (**self).update_domain_contact_privacy(input)
+    }
+    fn update_domain_nameservers(
+        &self,
+        input: UpdateDomainNameserversRequest,
+    ) -> RusotoFuture<UpdateDomainNameserversResponse, UpdateDomainNameserversError> {
+        (**self).update_domain_nameservers(input)
+    }
+    fn update_tags_for_domain(
+        &self,
+        input: UpdateTagsForDomainRequest,
+    ) -> RusotoFuture<UpdateTagsForDomainResponse, UpdateTagsForDomainError> {
+        (**self).update_tags_for_domain(input)
+    }
+    fn view_billing(
+        &self,
+        input: ViewBillingRequest,
+    ) -> RusotoFuture<ViewBillingResponse, ViewBillingError> {
+        (**self).view_billing(input)
+    }
+}
+/// The error codes Amazon Route 53 Domains retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Route 53 Domains API.
 #[derive(Clone)]
 pub struct Route53DomainsClient {
@@ -2225,6 +5309,27 @@ impl Route53DomainsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ROUTE53DOMAINS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> Route53DomainsClient {
+        Self::new(region::Region::from_env_for_service("ROUTE53DOMAINS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `route53domains`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> Route53DomainsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("route53domains", "ROUTE53DOMAINS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2243,7 +5348,54 @@ impl Route53DomainsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Route53DomainsClient {
-        Route53DomainsClient { client, region }
+        Route53DomainsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "route53domains")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "route53domains",
+            "route53domains",
+            "ROUTE53DOMAINS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Route53Domains` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Route53Domains + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for Route53DomainsClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Route 53 Domains"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "route53domains"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> Route53DomainsClient {
+        Self::new_with_client(client, region)
     }
 }
 
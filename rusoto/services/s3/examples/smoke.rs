@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Simple Storage Service clients.
+//!
+//! Makes a single read-only `ListBuckets` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_s3::{S3Client, S3};
+
+fn main() {
+    let client = S3Client::from_env();
+    match client.list_buckets().sync() {
+        Ok(_) => println!("ListBuckets succeeded"),
+        Err(error) => {
+            eprintln!("ListBuckets failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
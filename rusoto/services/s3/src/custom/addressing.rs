@@ -0,0 +1,432 @@
+//! Bucket addressing options.
+//!
+//! S3 requests can name their bucket in the path (`https://s3.amazonaws.com/bucket/key`) or in
+//! the host (`https://bucket.s3.amazonaws.com/key`). Clients created with
+//! `S3Client::new_with_addressing` use virtual-hosted addressing by default, and can also send
+//! requests to the transfer acceleration and dualstack (IPv6) endpoints. Clients created with
+//! the other constructors keep using path-style addressing.
+//!
+//! Requests for a custom region use path-style addressing on its endpoint, which is what S3
+//! compatible servers such as MinIO expect, except when the endpoint of the custom region is
+//! an `s3-accelerate` endpoint: such requests use transfer acceleration. Acceleration isn't
+//! available in the China and GovCloud regions, which keep using their regional endpoints.
+
+use std::error::Error;
+use std::fmt;
+
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{Client, Region};
+
+use crate::generated::S3Client;
+
+/// How an `S3Client` addresses buckets.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct S3Addressing {
+    /// Always name the bucket in the path instead of the host
+    pub path_style: bool,
+    /// Send requests to the `s3-accelerate` endpoint; requires virtual-hosted addressing
+    pub accelerate: bool,
+    /// Send requests to the dualstack endpoints, reachable over IPv6
+    pub dualstack: bool,
+}
+
+/// Why a bucket can't be addressed as configured.
+#[derive(Debug, PartialEq)]
+pub enum AddressingError {
+    /// The bucket name is not a valid DNS label, so it can't be part of a host name
+    NotVirtualHostable(String),
+    /// Transfer acceleration needs virtual-hosted addressing and a bucket name without dots
+    AccelerateUnavailable(String),
+}
+
+impl fmt::Display for AddressingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AddressingError::NotVirtualHostable(ref bucket)
+            | AddressingError::AccelerateUnavailable(ref bucket) => {
+                write!(f, "{}: {}", self.description(), bucket)
+            }
+        }
+    }
+}
+
+impl Error for AddressingError {
+    fn description(&self) -> &str {
+        match *self {
+            AddressingError::NotVirtualHostable(_) => {
+                "Bucket name can't be used with virtual-hosted addressing"
+            }
+            AddressingError::AccelerateUnavailable(_) => {
+                "Bucket can't be used with transfer acceleration"
+            }
+        }
+    }
+}
+
+impl S3Addressing {
+    /// Checks that requests for `bucket` can be addressed as configured.
+    ///
+    /// Requests which can't are sent with path-style addressing to the regular or dualstack
+    /// endpoint instead, so this only needs to be called to fail early.
+    pub fn validate_bucket(&self, bucket: &str) -> Result<(), AddressingError> {
+        if self.accelerate && (self.path_style || bucket.contains('.')) {
+            return Err(AddressingError::AccelerateUnavailable(bucket.to_owned()));
+        }
+        if !self.path_style && !is_virtual_hostable(bucket) {
+            return Err(AddressingError::NotVirtualHostable(bucket.to_owned()));
+        }
+        Ok(())
+    }
+
+    /// Rewrites the host and path of an unsigned S3 request.
+    ///
+    /// Clients created with `S3Client::new_with_addressing` call this for every request. Call it
+    /// directly on requests built by hand, e.g. before generating a presigned URL.
+    pub fn apply(&self, request: &mut SignedRequest) {
+        if let Region::Custom {
+            ref name,
+            ref endpoint,
+        } = request.region
+        {
+            // an accelerate endpoint given as a custom region enables acceleration
+            let dualstack = match accelerate_endpoint_dualstack(endpoint) {
+                Some(dualstack) => dualstack,
+                None => return,
+            };
+            let region = match name.parse::<Region>() {
+                Ok(region) => region,
+                Err(_) => return,
+            };
+            request.region = region;
+            let addressing = S3Addressing {
+                path_style: false,
+                accelerate: true,
+                dualstack,
+            };
+            addressing.apply(request);
+            return;
+        }
+        let suffix = match request.region {
+            Region::Custom { .. } => return,
+            ref region => region.partition().dns_suffix(),
+        };
+        let (bucket, key) = split_path(&request.path);
+        let bucket = match bucket {
+            Some(bucket) => bucket,
+            None => {
+                if self.dualstack {
+                    let host = format!("s3.dualstack.{}.{}", request.region.name(), suffix);
+                    request.set_hostname(Some(host));
+                }
+                return;
+            }
+        };
+
+        // dotted bucket names don't match the wildcard certificate of the S3 endpoints
+        let virtual_hosted = !self.path_style
+            && is_virtual_hostable(&bucket)
+            && !(bucket.contains('.') && request.scheme() == "https");
+        // bucket creation and deletion aren't accelerated
+        let bucket_operation = key == "/"
+            && request.params.is_empty()
+            && (request.method == "PUT" || request.method == "DELETE");
+        let accelerate = self.accelerate
+            && supports_accelerate(&request.region)
+            && virtual_hosted
+            && !bucket.contains('.')
+            && !bucket_operation;
+
+        let endpoint = if accelerate && self.dualstack {
+            format!("s3-accelerate.dualstack.{}", suffix)
+        } else if accelerate {
+            format!("s3-accelerate.{}", suffix)
+        } else if self.dualstack {
+            format!("s3.dualstack.{}.{}", request.region.name(), suffix)
+        } else {
+            request.hostname()
+        };
+        if virtual_hosted {
+            request.set_hostname(Some(format!("{}.{}", bucket, endpoint)));
+            request.path = key;
+        } else {
+            request.set_hostname(Some(endpoint));
+        }
+    }
+}
+
+impl S3Client {
+    /// Creates a client addressing buckets as configured by `addressing`.
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::{Client, Region};
+    /// use rusoto_s3::addressing::S3Addressing;
+    /// use rusoto_s3::S3Client;
+    ///
+    /// let addressing = S3Addressing {
+    ///     accelerate: true,
+    ///     ..Default::default()
+    /// };
+    /// let client = S3Client::new_with_addressing(Client::shared(), Region::UsWest2, addressing);
+    /// ```
+    pub fn new_with_addressing(
+        client: Client,
+        region: Region,
+        addressing: S3Addressing,
+    ) -> S3Client {
+        let client =
+            client.with_unsigned_request_interceptor(move |request| addressing.apply(request));
+        S3Client::new_with_client(client, region)
+    }
+}
+
+/// Whether `bucket` can be part of a host name: 3 to 63 lower case letters, digits, dots and
+/// hyphens, in labels starting and ending with a letter or digit, and not an IP address.
+pub fn is_virtual_hostable(bucket: &str) -> bool {
+    if bucket.len() < 3 || bucket.len() > 63 {
+        return false;
+    }
+    let valid_labels = bucket.split('.').all(|label| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    });
+    let ip_address = bucket.split('.').count() == 4
+        && bucket
+            .split('.')
+            .all(|label| label.chars().all(|c| c.is_ascii_digit()));
+    valid_labels && !ip_address
+}
+
+/// Whether transfer acceleration is available in `region`. Only the standard partition has
+/// accelerate endpoints.
+fn supports_accelerate(region: &Region) -> bool {
+    region.partition().id() == "aws"
+}
+
+/// Returns whether `endpoint` is the dualstack variant if it is an `s3-accelerate` endpoint,
+/// such as `https://s3-accelerate.dualstack.amazonaws.com`.
+fn accelerate_endpoint_dualstack(endpoint: &str) -> Option<bool> {
+    let host = endpoint
+        .splitn(2, "://")
+        .last()
+        .unwrap_or(endpoint)
+        .split(|c| c == '/' || c == ':')
+        .next()
+        .unwrap_or("");
+    let labels: Vec<&str> = host.split('.').collect();
+    if labels.len() < 3
+        || labels[0] != "s3-accelerate"
+        || labels[labels.len() - 2..] != ["amazonaws", "com"]
+    {
+        return None;
+    }
+    let features = &labels[1..labels.len() - 2];
+    if features.iter().all(|label| *label == "dualstack") && features.len() <= 1 {
+        Some(features.len() == 1)
+    } else {
+        None
+    }
+}
+
+/// Splits a request path into the bucket and the path within the bucket.
+pub(crate) fn split_path(path: &str) -> (Option<String>, String) {
+    let path = path.trim_start_matches('/');
+    let (bucket, key) = match path.find('/') {
+        Some(index) => (&path[..index], &path[index..]),
+        None => (path, "/"),
+    };
+    if bucket.is_empty() {
+        (None, "/".to_owned())
+    } else {
+        (Some(bucket.to_owned()), key.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addressed(
+        addressing: S3Addressing,
+        method: &str,
+        region: Region,
+        path: &str,
+    ) -> (String, String) {
+        let mut request = SignedRequest::new(method, "s3", &region, path);
+        addressing.apply(&mut request);
+        (request.hostname(), request.path().to_owned())
+    }
+
+    #[test]
+    fn uses_virtual_hosted_addressing_by_default() {
+        assert_eq!(
+            addressed(
+                S3Addressing::default(),
+                "GET",
+                Region::UsEast1,
+                "/bucket/a/b.txt"
+            ),
+            ("bucket.s3.amazonaws.com".to_owned(), "/a/b.txt".to_owned())
+        );
+        assert_eq!(
+            addressed(S3Addressing::default(), "GET", Region::UsEast1, "/"),
+            ("s3.amazonaws.com".to_owned(), "/".to_owned())
+        );
+    }
+
+    #[test]
+    fn falls_back_to_path_style_for_incompatible_buckets() {
+        assert_eq!(
+            addressed(
+                S3Addressing::default(),
+                "GET",
+                Region::UsEast1,
+                "/my.bucket/key"
+            ),
+            ("s3.amazonaws.com".to_owned(), "/my.bucket/key".to_owned())
+        );
+        assert_eq!(
+            addressed(
+                S3Addressing::default(),
+                "GET",
+                Region::UsEast1,
+                "/My_Bucket/key"
+            ),
+            ("s3.amazonaws.com".to_owned(), "/My_Bucket/key".to_owned())
+        );
+    }
+
+    #[test]
+    fn forces_path_style() {
+        let addressing = S3Addressing {
+            path_style: true,
+            dualstack: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            addressed(addressing, "GET", Region::EuWest1, "/bucket/key"),
+            (
+                "s3.dualstack.eu-west-1.amazonaws.com".to_owned(),
+                "/bucket/key".to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn uses_accelerate_endpoints() {
+        let addressing = S3Addressing {
+            accelerate: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            addressed(addressing.clone(), "PUT", Region::UsWest2, "/bucket/key"),
+            (
+                "bucket.s3-accelerate.amazonaws.com".to_owned(),
+                "/key".to_owned()
+            )
+        );
+        // bucket creation goes to the regional endpoint
+        assert_eq!(
+            addressed(addressing, "PUT", Region::UsWest2, "/bucket"),
+            (
+                "bucket.s3-us-west-2.amazonaws.com".to_owned(),
+                "/".to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn uses_regional_endpoints_without_accelerate() {
+        let addressing = S3Addressing {
+            accelerate: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            addressed(addressing.clone(), "GET", Region::CnNorth1, "/bucket/key"),
+            (
+                "bucket.s3.cn-north-1.amazonaws.com.cn".to_owned(),
+                "/key".to_owned()
+            )
+        );
+        assert_eq!(
+            addressed(addressing, "GET", Region::UsGovWest1, "/bucket/key"),
+            (
+                "bucket.s3-us-gov-west-1.amazonaws.com".to_owned(),
+                "/key".to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn accelerates_custom_accelerate_endpoints() {
+        let region = Region::Custom {
+            name: "eu-west-1".to_owned(),
+            endpoint: "https://s3-accelerate.dualstack.amazonaws.com".to_owned(),
+        };
+        assert_eq!(
+            addressed(
+                S3Addressing::default(),
+                "GET",
+                region.clone(),
+                "/bucket/key"
+            ),
+            (
+                "bucket.s3-accelerate.dualstack.amazonaws.com".to_owned(),
+                "/key".to_owned()
+            )
+        );
+        // bucket deletion goes to the regional endpoint
+        assert_eq!(
+            addressed(S3Addressing::default(), "DELETE", region, "/bucket"),
+            (
+                "bucket.s3.dualstack.eu-west-1.amazonaws.com".to_owned(),
+                "/".to_owned()
+            )
+        );
+        assert_eq!(
+            accelerate_endpoint_dualstack("s3-accelerate.amazonaws.com"),
+            Some(false)
+        );
+        assert_eq!(
+            accelerate_endpoint_dualstack("https://s3-accelerate.foo.amazonaws.com"),
+            None
+        );
+        assert_eq!(accelerate_endpoint_dualstack("http://localhost:9000"), None);
+    }
+
+    #[test]
+    fn leaves_custom_endpoints_alone() {
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: "http://localhost:9000".to_owned(),
+        };
+        assert_eq!(
+            addressed(S3Addressing::default(), "GET", region, "/bucket/key"),
+            ("localhost:9000".to_owned(), "/bucket/key".to_owned())
+        );
+    }
+
+    #[test]
+    fn validates_bucket_names() {
+        assert!(is_virtual_hostable("my-bucket.logs"));
+        assert!(!is_virtual_hostable("ab"));
+        assert!(!is_virtual_hostable("-bucket"));
+        assert!(!is_virtual_hostable("bucket..logs"));
+        assert!(!is_virtual_hostable("192.168.1.1"));
+
+        let accelerate = S3Addressing {
+            accelerate: true,
+            ..Default::default()
+        };
+        assert_eq!(accelerate.validate_bucket("bucket"), Ok(()));
+        assert_eq!(
+            accelerate.validate_bucket("my.bucket"),
+            Err(AddressingError::AccelerateUnavailable(
+                "my.bucket".to_owned()
+            ))
+        );
+    }
+}
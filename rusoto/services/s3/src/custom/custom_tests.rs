@@ -1,12 +1,13 @@
 extern crate rusoto_mock;
 
+use crate::custom::listing::ObjectLister;
 use crate::generated::*;
 
 use self::rusoto_mock::*;
 use bytes::Bytes;
 use futures::{Future, Stream};
 use rusoto_core::signature::SignedRequest;
-use rusoto_core::{Region, RusotoError};
+use rusoto_core::{Client, Region, RusotoError, RusotoFuture};
 
 #[test]
 fn test_multipart_upload_copy_response() {
@@ -175,7 +176,7 @@ fn list_multipart_upload_happy_path() {
                 test_owner.display_name
             );
 
-            assert_eq!(an_upload.storage_class, sstr("STANDARD"));
+            assert_eq!(an_upload.storage_class, Some(StorageClass::Standard));
         }
     }
 }
@@ -254,7 +255,7 @@ fn list_multipart_upload_parts_happy_path() {
         test_owner.display_name
     );
 
-    assert_eq!(result.storage_class, sstr("STANDARD"));
+    assert_eq!(result.storage_class, Some(StorageClass::Standard));
 
     assert!(result.parts.is_some());
 
@@ -296,12 +297,10 @@ fn list_multipart_uploads_no_uploads() {
 
 #[cfg(nightly)]
 #[bench]
-fn bench_parse_list_buckets_response(b: &mut Bencher) {
-    use test::Bencher;
+fn bench_parse_list_buckets_response(b: &mut test::Bencher) {
     let mock = MockRequestDispatcher::with_status(200)
         .with_body(
-            r#"
-        <?xml version="1.0" encoding="UTF-8"?>
+            r#"<?xml version="1.0" encoding="UTF-8"?>
         <ListAllMyBucketsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01">
             <Owner>
             <ID>bcaf1ffd86f461ca5fb16fd081034f</ID>
@@ -331,6 +330,45 @@ fn bench_parse_list_buckets_response(b: &mut Bencher) {
     b.iter(|| client.list_buckets().sync().unwrap());
 }
 
+#[cfg(nightly)]
+#[bench]
+fn bench_parse_large_list_objects_v2_response(b: &mut test::Bencher) {
+    let contents: String = (0..1000)
+        .map(|i| {
+            format!(
+                "<Contents>
+                    <Key>photos/2006/February/sample-{}.jpg</Key>
+                    <LastModified>2011-02-26T01:56:20.000Z</LastModified>
+                    <ETag>&quot;bf1d737a4d46a19f3bced6905cc8b902&quot;</ETag>
+                    <Size>142863</Size>
+                    <StorageClass>STANDARD</StorageClass>
+                </Contents>",
+                i
+            )
+        })
+        .collect();
+    let body = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Name>bucket</Name>
+            <Prefix/>
+            <KeyCount>1000</KeyCount>
+            <MaxKeys>1000</MaxKeys>
+            <IsTruncated>false</IsTruncated>
+            {}
+        </ListBucketResult>"#,
+        contents
+    );
+    let mock = MockRequestDispatcher::with_status(200).with_body(&body);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = ListObjectsV2Request {
+        bucket: "bucket".to_owned(),
+        ..Default::default()
+    };
+
+    b.iter(|| client.list_objects_v2(request.clone()).sync().unwrap());
+}
+
 #[test]
 // sample response from the S3 documentation
 // tests the model generation and deserialization end-to-end
@@ -407,7 +445,7 @@ fn should_serialize_complicated_request() {
         key: "key".to_string(),
         part_number: Some(1),
         range: sstr("range"),
-        request_payer: sstr("request_payer"),
+        request_payer: Some(RequestPayer::Requester),
         response_cache_control: sstr("response_cache_control"),
         response_content_disposition: sstr("response_content_disposition"),
         response_content_encoding: sstr("response_content_encoding"),
@@ -459,7 +497,10 @@ fn should_parse_location_constraint() {
     match result {
         Err(_) => panic!("Couldn't parse get_bucket_location"),
         Ok(result) => {
-            assert_eq!(sstr("EU"), result.location_constraint);
+            assert_eq!(
+                Some(BucketLocationConstraint::Eu),
+                result.location_constraint
+            );
         }
     }
 }
@@ -484,6 +525,45 @@ fn structs_should_impl_clone() {
     assert_clone::<RestoreObjectRequest>();
 }
 
+#[test]
+fn shared_clients_can_be_injected() {
+    use std::sync::Arc;
+
+    struct BucketCounter {
+        s3: Arc<dyn S3 + Send + Sync>,
+    }
+
+    impl BucketCounter {
+        fn count(&self) -> usize {
+            self.s3
+                .list_buckets()
+                .sync()
+                .unwrap()
+                .buckets
+                .map_or(0, |buckets| buckets.len())
+        }
+    }
+
+    fn assert_extensible<T: crate::custom::versioning::VersioningExt>(_: &T) {}
+
+    let mock = MockRequestDispatcher::with_status(200).with_body(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ListAllMyBucketsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Owner><ID>owner</ID></Owner>
+                <Buckets>
+                    <Bucket><Name>a</Name></Bucket>
+                    <Bucket><Name>b</Name></Bucket>
+                </Buckets>
+            </ListAllMyBucketsResult>"#,
+    );
+    let counter = BucketCounter {
+        s3: S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1).into_shared(),
+    };
+
+    assert_eq!(counter.count(), 2);
+    assert_extensible(&counter.s3);
+}
+
 /// returns Some(String)
 fn sstr(value: &'static str) -> Option<String> {
     Some(value.to_string())
@@ -516,3 +596,686 @@ fn test_parse_no_such_bucket_error() {
         err
     );
 }
+
+#[test]
+fn abort_incomplete_uploads_only_aborts_old_uploads() {
+    use crate::multipart::MultipartCleanupExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let aborts = Arc::new(AtomicUsize::new(0));
+    let counter = aborts.clone();
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ListMultipartUploadsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Bucket>bucket</Bucket>
+                <IsTruncated>false</IsTruncated>
+                <Upload>
+                    <Key>old</Key>
+                    <UploadId>old-upload</UploadId>
+                    <Initiated>2010-11-10T20:48:33.000Z</Initiated>
+                </Upload>
+                <Upload>
+                    <Key>recent</Key>
+                    <UploadId>recent-upload</UploadId>
+                    <Initiated>2099-11-10T20:48:33.000Z</Initiated>
+                </Upload>
+            </ListMultipartUploadsResult>"#,
+        )
+        .with_request_checker(move |request: &SignedRequest| {
+            if request.method == "DELETE" {
+                assert_eq!(request.path, "/bucket/old");
+                assert_eq!(
+                    request.params.get("uploadId"),
+                    Some(&Some("old-upload".to_owned()))
+                );
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let report = client
+        .abort_incomplete_uploads("bucket", Duration::from_secs(24 * 60 * 60))
+        .sync()
+        .unwrap();
+
+    assert_eq!(aborts.load(Ordering::SeqCst), 1);
+    assert_eq!(report.kept, 1);
+    assert_eq!(report.aborted.len() + report.failed.len(), 1);
+}
+
+#[test]
+fn undelete_removes_the_latest_delete_marker() {
+    use crate::versioning::VersioningExt;
+    use std::sync::{Arc, Mutex};
+
+    let deleted = Arc::new(Mutex::new(Vec::new()));
+    let recorded = deleted.clone();
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        MockRequestDispatcher::with_status(200).with_body(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ListVersionsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Name>bucket</Name>
+                <Prefix>report.csv</Prefix>
+                <IsTruncated>false</IsTruncated>
+                <DeleteMarker>
+                    <Key>report.csv</Key>
+                    <VersionId>marker</VersionId>
+                    <IsLatest>true</IsLatest>
+                    <LastModified>2019-11-10T20:48:33.000Z</LastModified>
+                </DeleteMarker>
+                <Version>
+                    <Key>report.csv</Key>
+                    <VersionId>original</VersionId>
+                    <IsLatest>false</IsLatest>
+                    <LastModified>2019-10-10T20:48:33.000Z</LastModified>
+                    <Size>42</Size>
+                </Version>
+            </ListVersionsResult>"#,
+        ),
+        MockRequestDispatcher::with_status(204).with_request_checker(
+            move |request: &SignedRequest| {
+                assert_eq!(request.method, "DELETE");
+                assert_eq!(request.path, "/bucket/report.csv");
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(request.params.get("versionId").cloned());
+            },
+        ),
+    ]);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let removed = client.undelete("bucket", "report.csv").sync().unwrap();
+
+    assert_eq!(removed, Some("marker".to_owned()));
+    assert_eq!(
+        *deleted.lock().unwrap(),
+        vec![Some(Some("marker".to_owned()))]
+    );
+}
+
+#[test]
+fn should_address_buckets_as_configured() {
+    use crate::addressing::S3Addressing;
+    use rusoto_core::Client;
+
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body("")
+        .with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                request.hostname(),
+                "bucket.s3.dualstack.eu-west-1.amazonaws.com"
+            );
+            assert_eq!(request.path, "/key");
+        });
+    let addressing = S3Addressing {
+        dualstack: true,
+        ..Default::default()
+    };
+    let client = S3Client::new_with_addressing(
+        Client::new_with(MockCredentialsProvider, mock),
+        Region::EuWest1,
+        addressing,
+    );
+    let request = DeleteObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    client.delete_object(request).sync().unwrap();
+}
+
+#[test]
+fn should_checksum_payloads_with_the_algorithm_of_the_request() {
+    use rusoto_core::signature::ChecksumAlgorithm;
+
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body("")
+        .with_request_checker(|request: &SignedRequest| {
+            assert!(request.headers.get("content-md5").is_none());
+            assert!(request.headers.get("x-amz-checksum-crc32c").is_some());
+            assert_eq!(
+                request.headers.get("x-amz-sdk-checksum-algorithm"),
+                Some(&vec![Vec::from("CRC32C")])
+            );
+        });
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = PutBucketTaggingRequest {
+        bucket: "bucket".to_owned(),
+        checksum_algorithm: Some(ChecksumAlgorithm::Crc32c),
+        ..Default::default()
+    };
+    client.put_bucket_tagging(request).sync().unwrap();
+}
+
+#[test]
+fn should_follow_redirects_to_the_region_of_the_bucket() {
+    use crate::redirects::BucketRegionCache;
+    use rusoto_core::Client;
+    use std::sync::{Arc, Mutex};
+
+    let regions = Arc::new(Mutex::new(Vec::new()));
+    let seen = regions.clone();
+    let mock = MockRequestDispatcher::with_status(301)
+        .with_body("")
+        .with_header("x-amz-bucket-region", "eu-west-1")
+        .with_request_checker(move |request: &SignedRequest| {
+            seen.lock()
+                .unwrap()
+                .push((request.region.clone(), request.hostname()));
+        });
+    let cache = BucketRegionCache::new();
+    let client = S3Client::new_with_client(
+        cache.follow_redirects(Client::new_with(MockCredentialsProvider, mock)),
+        Region::UsEast1,
+    );
+    let request = HeadBucketRequest {
+        bucket: "bucket".to_owned(),
+    };
+
+    // the mock keeps redirecting, so the redirected request fails
+    assert!(client.head_bucket(request.clone()).sync().is_err());
+    assert_eq!(cache.get("bucket"), Some(Region::EuWest1));
+    assert!(client.head_bucket(request).sync().is_err());
+    assert_eq!(
+        *regions.lock().unwrap(),
+        vec![
+            (Region::UsEast1, "s3.amazonaws.com".to_owned()),
+            (Region::EuWest1, "s3-eu-west-1.amazonaws.com".to_owned()),
+            (Region::EuWest1, "s3-eu-west-1.amazonaws.com".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn request_builders_check_required_members() {
+    let request = GetObjectRequest::builder()
+        .bucket("bucket")
+        .key("key")
+        .range("bytes=0-9")
+        .build()
+        .unwrap();
+    assert_eq!(
+        request,
+        GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            range: Some("bytes=0-9".to_owned()),
+            ..Default::default()
+        }
+    );
+
+    let missing = GetObjectRequest::builder().bucket("bucket").build();
+    assert_eq!(missing, Err(rusoto_core::BuildError::missing("key")));
+}
+
+#[test]
+fn enums_convert_from_and_to_strings() {
+    assert_eq!(StorageClass::from("STANDARD_IA"), StorageClass::StandardIa);
+    assert_eq!(
+        StorageClass::from("SOMETHING_NEW"),
+        StorageClass::Unknown("SOMETHING_NEW".to_owned())
+    );
+    assert_eq!(String::from(StorageClass::Glacier), "GLACIER");
+    assert_eq!(StorageClass::Unknown("X".to_owned()).to_string(), "X");
+
+    let request = PutObjectRequest::builder()
+        .bucket("bucket")
+        .key("key")
+        .storage_class("DEEP_ARCHIVE")
+        .build()
+        .unwrap();
+    assert_eq!(request.storage_class, Some(StorageClass::DeepArchive));
+}
+
+#[test]
+fn download_parts_requests_ranges_of_the_same_object() {
+    use crate::download::{DownloadConfig, DownloadExt};
+
+    let e_tag = "\"5d41402abc4b2a76b9719d911017c592\"";
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        MockRequestDispatcher::with_status(200)
+            .with_header("Content-Length", "5")
+            .with_header("ETag", e_tag)
+            .with_request_checker(|request: &SignedRequest| assert_eq!(request.method, "HEAD")),
+        MockRequestDispatcher::with_status(200)
+            .with_body("hello")
+            .with_header("Content-Length", "5")
+            .with_header("ETag", e_tag)
+            .with_request_checker(move |request: &SignedRequest| {
+                assert_eq!(request.method, "GET");
+                assert_eq!(
+                    request.headers.get("range"),
+                    Some(&vec![Vec::from("bytes=0-4")])
+                );
+                assert_eq!(
+                    request.headers.get("if-match"),
+                    Some(&vec![Vec::from(e_tag)])
+                );
+            }),
+    ]);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "greeting.txt".to_owned(),
+        ..Default::default()
+    };
+
+    let data = RusotoFuture::from_future(
+        client
+            .download_parts(request, DownloadConfig::default())
+            .concat2(),
+    )
+    .sync()
+    .unwrap();
+
+    assert_eq!(data, Bytes::from("hello"));
+}
+
+#[test]
+fn download_parts_retries_truncated_parts() {
+    use crate::download::{DownloadConfig, DownloadError, DownloadExt};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let gets = Arc::new(AtomicUsize::new(0));
+    let truncated_get = || {
+        let counter = gets.clone();
+        MockRequestDispatcher::with_status(200)
+            .with_body("hello")
+            .with_header("Content-Length", "10")
+            .with_request_checker(move |request: &SignedRequest| {
+                assert_eq!(request.method, "GET");
+                counter.fetch_add(1, Ordering::SeqCst);
+            })
+    };
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        MockRequestDispatcher::with_status(200).with_header("Content-Length", "10"),
+        truncated_get(),
+        truncated_get(),
+    ]);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "greeting.txt".to_owned(),
+        ..Default::default()
+    };
+    let config = DownloadConfig {
+        max_attempts: 2,
+        ..Default::default()
+    };
+
+    // retries back off on the timer of the runtime used by `sync`
+    let err = RusotoFuture::from_future(client.download_parts(request, config).concat2())
+        .sync()
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        RusotoError::Service(DownloadError::LengthMismatch {
+            expected: 10,
+            actual: 5
+        })
+    );
+    assert_eq!(gets.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn download_parts_with_progress_discards_bytes_of_failed_attempts() {
+    use crate::download::{DownloadConfig, DownloadExt};
+    use rusoto_core::TransferProgress;
+    use std::sync::{Arc, Mutex};
+
+    let truncated_get = || {
+        MockRequestDispatcher::with_status(200)
+            .with_body("hello")
+            .with_header("Content-Length", "10")
+    };
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        MockRequestDispatcher::with_status(200).with_header("Content-Length", "10"),
+        truncated_get(),
+        truncated_get(),
+    ]);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "greeting.txt".to_owned(),
+        ..Default::default()
+    };
+    let config = DownloadConfig {
+        max_attempts: 2,
+        ..Default::default()
+    };
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let sink = reported.clone();
+
+    let parts = client.download_parts_with_progress(request, config, move |progress| {
+        sink.lock().unwrap().push(progress)
+    });
+    RusotoFuture::from_future(parts.concat2())
+        .sync()
+        .unwrap_err();
+
+    let transferred: Vec<u64> = reported
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|progress: &TransferProgress| {
+            assert_eq!(progress.total, Some(10));
+            progress.transferred
+        })
+        .collect();
+    assert_eq!(transferred, vec![5, 0, 5, 0]);
+}
+
+#[test]
+fn list_objects_v2_stream_yields_every_object() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Name>bucket</Name>
+                <Prefix>photos/</Prefix>
+                <KeyCount>2</KeyCount>
+                <MaxKeys>1000</MaxKeys>
+                <IsTruncated>false</IsTruncated>
+                <Contents>
+                    <Key>photos/a.jpg</Key>
+                    <Size>10</Size>
+                </Contents>
+                <Contents>
+                    <Key>photos/b.jpg</Key>
+                    <Size>20</Size>
+                </Contents>
+            </ListBucketResult>"#,
+        )
+        .with_request_checker(|request: &SignedRequest| {
+            assert_eq!(request.path, "/bucket");
+            assert_eq!(request.params.get("list-type"), Some(&Some("2".to_owned())));
+            assert_eq!(
+                request.params.get("prefix"),
+                Some(&Some("photos/".to_owned()))
+            );
+        });
+    let client = Client::new_with(MockCredentialsProvider, mock);
+    let lister = ObjectLister::new_with_client(client, Region::UsEast1);
+
+    let objects = lister
+        .list_objects_v2_stream(ListObjectsV2Request {
+            bucket: "bucket".to_owned(),
+            prefix: Some("photos/".to_owned()),
+            ..Default::default()
+        })
+        .collect()
+        .wait()
+        .unwrap();
+
+    let sizes: Vec<_> = objects.iter().map(|object| object.size).collect();
+    assert_eq!(sizes, vec![Some(10), Some(20)]);
+}
+
+#[cfg(feature = "fake")]
+#[test]
+fn fake_s3_stores_and_lists_objects() {
+    use crate::fake::FakeS3;
+
+    let s3 = FakeS3::new();
+    s3.create_bucket(CreateBucketRequest {
+        bucket: "bucket".to_owned(),
+        ..Default::default()
+    })
+    .sync()
+    .unwrap();
+    for key in &["a/1", "a/2", "b"] {
+        s3.put_object(PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: key.to_string(),
+            body: Some(key.as_bytes().to_vec().into()),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap();
+    }
+
+    let object = s3
+        .get_object(GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "a/2".to_owned(),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap();
+    let body = object.body.unwrap().concat2().wait().unwrap();
+    assert_eq!(body.as_ref(), b"a/2");
+
+    let listing = s3
+        .list_objects_v2(ListObjectsV2Request {
+            bucket: "bucket".to_owned(),
+            delimiter: Some("/".to_owned()),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap();
+    assert_eq!(
+        listing.common_prefixes.unwrap()[0].prefix,
+        Some("a/".to_owned())
+    );
+    assert_eq!(listing.contents.unwrap()[0].key, Some("b".to_owned()));
+
+    match s3
+        .get_object(GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "missing".to_owned(),
+            ..Default::default()
+        })
+        .sync()
+    {
+        Err(RusotoError::Service(GetObjectError::NoSuchKey(_))) => {}
+        other => panic!("expected NoSuchKey, got {:?}", other),
+    }
+    match s3
+        .get_bucket_acl(GetBucketAclRequest {
+            bucket: "bucket".to_owned(),
+        })
+        .sync()
+    {
+        Err(RusotoError::Validation(_)) => {}
+        other => panic!("expected an unsupported operation, got {:?}", other),
+    }
+}
+
+#[test]
+fn should_presign_batches_of_urls_with_one_signing_key() {
+    use crate::util::{BatchPresigner, PreSignedRequestOption};
+    use rusoto_core::credential::AwsCredentials;
+
+    let credentials = AwsCredentials::new("access_key", "secret_key", None, None);
+    let presigner = BatchPresigner::new(
+        &Region::EuWest1,
+        &credentials,
+        PreSignedRequestOption::default(),
+    );
+    let urls: Vec<String> = presigner
+        .get_object_urls("bucket", vec!["a.jpg", "b c.jpg"])
+        .collect();
+
+    assert!(urls[0].starts_with("https://s3-eu-west-1.amazonaws.com/bucket/a.jpg?"));
+    assert!(urls[1].starts_with("https://s3-eu-west-1.amazonaws.com/bucket/b%20c.jpg?"));
+    assert!(urls.iter().all(|url| url.contains("X-Amz-Expires=3600")));
+
+    // every URL is dated when the signing key was derived
+    let date = |url: &str| {
+        url.split('&')
+            .find(|param| param.starts_with("X-Amz-Date="))
+            .map(str::to_owned)
+    };
+    assert!(date(&urls[0]).is_some());
+    assert_eq!(date(&urls[0]), date(&urls[1]));
+}
+
+#[cfg(feature = "fake")]
+#[test]
+fn bucket_walker_lists_prefixes_depth_first() {
+    use crate::fake::FakeS3;
+    use crate::walker::{BucketWalker, WalkConfig, WalkEntry};
+
+    let s3 = FakeS3::new();
+    s3.create_bucket(CreateBucketRequest {
+        bucket: "bucket".to_owned(),
+        ..Default::default()
+    })
+    .sync()
+    .unwrap();
+    for key in &["a/1", "a/b/2", "a/b/3", "a/c/4", "d", "e/5"] {
+        s3.put_object(PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: key.to_string(),
+            body: Some(key.as_bytes().to_vec().into()),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap();
+    }
+    // pages of two keys, to follow continuation tokens in the middle of levels
+    let config = WalkConfig {
+        concurrency: 2,
+        max_keys: Some(2),
+        ..Default::default()
+    };
+    let walker = BucketWalker::with_config(s3.clone(), "bucket", config.clone());
+
+    let entries = walker.walk("").collect().wait().unwrap();
+    let walked: Vec<_> = entries
+        .iter()
+        .map(|entry| (entry.key(), entry.depth()))
+        .collect();
+    assert_eq!(
+        walked,
+        vec![
+            ("a/", 0),
+            ("a/1", 1),
+            ("a/b/", 1),
+            ("a/b/2", 2),
+            ("a/b/3", 2),
+            ("a/c/", 1),
+            ("a/c/4", 2),
+            ("d", 0),
+            ("e/", 0),
+            ("e/5", 1),
+        ]
+    );
+    match entries[0] {
+        WalkEntry::Prefix { .. } => {}
+        ref other => panic!("expected a prefix, got {:?}", other),
+    }
+
+    let tree = walker.tree("a/").sync().unwrap();
+    assert_eq!(tree.prefix, "a/");
+    assert_eq!(tree.object_count(), 4);
+    assert_eq!(tree.children[0].prefix, "a/b/");
+    assert_eq!(tree.children[0].objects.len(), 2);
+
+    let shallow = BucketWalker::with_config(
+        s3,
+        "bucket",
+        WalkConfig {
+            max_depth: Some(0),
+            ..config
+        },
+    );
+    let keys: Vec<_> = shallow
+        .walk("")
+        .map(|entry| entry.key().to_owned())
+        .collect()
+        .wait()
+        .unwrap();
+    assert_eq!(keys, vec!["a/", "d", "e/"]);
+}
+
+#[cfg(feature = "fake")]
+#[test]
+fn sync_uploads_and_downloads_only_differences() {
+    use crate::fake::FakeS3;
+    use crate::sync::{SyncConfig, SyncExt};
+    use std::fs;
+    use std::sync::{Arc, Mutex};
+
+    let root = std::env::temp_dir().join(format!("rusoto-s3-sync-{}", std::process::id()));
+    let source = root.join("source");
+    let destination = root.join("destination");
+    fs::create_dir_all(source.join("css")).unwrap();
+    fs::write(source.join("index.html"), "<html></html>").unwrap();
+    fs::write(source.join("css").join("site.css"), "body {}").unwrap();
+
+    let s3 = FakeS3::new();
+    s3.create_bucket(CreateBucketRequest {
+        bucket: "bucket".to_owned(),
+        ..Default::default()
+    })
+    .sync()
+    .unwrap();
+    s3.put_object(PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "www/stale.html".to_owned(),
+        body: Some(b"stale".to_vec().into()),
+        ..Default::default()
+    })
+    .sync()
+    .unwrap();
+
+    let finished = Arc::new(Mutex::new(Vec::new()));
+    let recorded = finished.clone();
+    let config = SyncConfig {
+        delete: true,
+        ..Default::default()
+    };
+    let report = s3
+        .sync_up(&source, "bucket", "www/", config.clone(), move |progress| {
+            recorded.lock().unwrap().push((
+                progress.key.clone(),
+                progress.completed,
+                progress.total,
+            ));
+        })
+        .sync()
+        .unwrap();
+
+    assert!(report.is_complete());
+    let mut uploaded = report.uploaded.clone();
+    uploaded.sort();
+    assert_eq!(uploaded, vec!["www/css/site.css", "www/index.html"]);
+    assert_eq!(report.deleted, vec!["www/stale.html"]);
+    assert_eq!(finished.lock().unwrap().len(), 3);
+    assert!(finished
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|&(_, _, total)| total == 3));
+    assert_eq!(
+        s3.backend().keys("bucket"),
+        vec!["www/css/site.css", "www/index.html"]
+    );
+
+    // nothing changed since
+    let report = s3
+        .sync_up(&source, "bucket", "www/", config.clone(), |_| {})
+        .sync()
+        .unwrap();
+    assert!(report.uploaded.is_empty());
+    assert_eq!(report.unchanged, 2);
+
+    let report = s3
+        .sync_down("bucket", "www/", &destination, config, |_| {})
+        .sync()
+        .unwrap();
+    assert_eq!(report.downloaded.len(), 2);
+    assert_eq!(
+        fs::read_to_string(destination.join("css").join("site.css")).unwrap(),
+        "body {}"
+    );
+
+    fs::remove_dir_all(&root).unwrap();
+}
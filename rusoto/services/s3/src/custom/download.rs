@@ -0,0 +1,483 @@
+//! Downloading large objects as concurrent ranged requests.
+//!
+//! `DownloadExt` looks up the size and ETag of an object with `HeadObject`, then fetches it in
+//! parts of `DownloadConfig::part_size` bytes, with up to `DownloadConfig::concurrency` ranged
+//! `GetObject` requests in flight. Parts whose request or body transfer fails are retried on
+//! their own. Every ranged request carries `If-Match` with the ETag seen by `HeadObject`, so an
+//! object overwritten during the download fails the download instead of mixing both versions.
+//!
+//! The parts are returned in order, as a stream, a `ByteStream` implementing `AsyncRead`, or
+//! written to a file. The `_with_progress` variants report the number of bytes received as the
+//! parts are transferred.
+
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::future::{self, Either, Loop};
+use futures::{stream, Future, Stream};
+use rusoto_core::request::HttpDispatchError;
+use rusoto_core::waiter::sleep;
+use rusoto_core::{ByteStream, RusotoError, RusotoFuture, TransferProgress};
+
+use crate::generated::{
+    GetObjectError, GetObjectOutput, GetObjectRequest, HeadObjectError, HeadObjectRequest, S3,
+};
+
+/// The size of the parts requested by default, 8 MiB.
+pub const DEFAULT_PART_SIZE: u64 = 8 * 1024 * 1024;
+
+/// The number of parts downloaded concurrently by default.
+pub const DEFAULT_DOWNLOAD_CONCURRENCY: usize = 4;
+
+/// The number of times a part is requested by default before the download fails.
+pub const DEFAULT_PART_ATTEMPTS: u32 = 3;
+
+/// How objects are split up and downloaded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DownloadConfig {
+    /// Size of the parts in bytes, the last part being smaller
+    pub part_size: u64,
+    /// Number of parts downloaded concurrently, which bounds memory use to about
+    /// `part_size * concurrency`
+    pub concurrency: usize,
+    /// Number of times a part is requested, including the first attempt
+    pub max_attempts: u32,
+}
+
+impl Default for DownloadConfig {
+    fn default() -> DownloadConfig {
+        DownloadConfig {
+            part_size: DEFAULT_PART_SIZE,
+            concurrency: DEFAULT_DOWNLOAD_CONCURRENCY,
+            max_attempts: DEFAULT_PART_ATTEMPTS,
+        }
+    }
+}
+
+/// The object downloaded by `DownloadExt::download_file`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DownloadedObject {
+    /// Size of the object in bytes
+    pub size: u64,
+    /// ETag of the object
+    pub e_tag: Option<String>,
+    /// Number of ranged requests the object was downloaded with
+    pub parts: usize,
+}
+
+/// Errors returned by the methods of `DownloadExt`.
+#[derive(Debug, PartialEq)]
+pub enum DownloadError {
+    /// Looking up the size of the object failed.
+    HeadObject(HeadObjectError),
+    /// Downloading a part failed, after retries for transient errors.
+    GetObject(GetObjectError),
+    /// The object was replaced while it was being downloaded.
+    ObjectChanged,
+    /// The data received doesn't have the expected length.
+    LengthMismatch {
+        /// The length S3 reported
+        expected: u64,
+        /// The length of the data received
+        actual: u64,
+    },
+    /// Writing the downloaded data failed.
+    Write(String),
+}
+
+impl fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DownloadError::LengthMismatch { expected, actual } => write!(
+                f,
+                "{}: expected {} bytes, got {}",
+                self.description(),
+                expected,
+                actual
+            ),
+            DownloadError::Write(ref cause) => write!(f, "{}: {}", self.description(), cause),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for DownloadError {
+    fn description(&self) -> &str {
+        match *self {
+            DownloadError::HeadObject(ref cause) => cause.description(),
+            DownloadError::GetObject(ref cause) => cause.description(),
+            DownloadError::ObjectChanged => "Object was replaced during the download",
+            DownloadError::LengthMismatch { .. } => "Length of downloaded data doesn't match",
+            DownloadError::Write(_) => "Writing downloaded data failed",
+        }
+    }
+}
+
+/// A stream of the data of an object, in order, as returned by `DownloadExt::download_parts`.
+pub type DownloadStream = Box<dyn Stream<Item = Bytes, Error = RusotoError<DownloadError>> + Send>;
+
+/// The bytes received by the concurrent part requests of a download.
+struct DownloadProgress {
+    transferred: AtomicU64,
+    total: u64,
+    callback: Option<Box<dyn Fn(TransferProgress) + Send + Sync>>,
+}
+
+impl DownloadProgress {
+    fn new(total: u64, callback: Option<Box<dyn Fn(TransferProgress) + Send + Sync>>) -> Self {
+        DownloadProgress {
+            transferred: AtomicU64::new(0),
+            total,
+            callback,
+        }
+    }
+
+    /// Adds `received` bytes to the bytes transferred.
+    fn advance(&self, received: u64) {
+        let transferred = self.transferred.fetch_add(received, Ordering::SeqCst) + received;
+        self.report(transferred);
+    }
+
+    /// Removes the `discarded` bytes of a failed part attempt, which will be received again.
+    fn rewind(&self, discarded: u64) {
+        let transferred = self.transferred.fetch_sub(discarded, Ordering::SeqCst) - discarded;
+        self.report(transferred);
+    }
+
+    fn report(&self, transferred: u64) {
+        if let Some(ref callback) = self.callback {
+            callback(TransferProgress {
+                transferred,
+                total: Some(self.total),
+            });
+        }
+    }
+}
+
+/// The inclusive byte ranges of the parts of an object of `size` bytes.
+fn part_ranges(size: u64, part_size: u64) -> Vec<(u64, u64)> {
+    let part_size = part_size.max(1);
+    (0..size)
+        .step_by(part_size as usize)
+        .map(|start| (start, (start + part_size).min(size) - 1))
+        .collect()
+}
+
+/// Whether a failed part request is worth retrying: connection errors, server errors and
+/// truncated bodies.
+fn is_transient(error: &RusotoError<DownloadError>) -> bool {
+    match *error {
+        RusotoError::HttpDispatch(_) => true,
+        RusotoError::Service(DownloadError::LengthMismatch { .. }) => true,
+        RusotoError::Unknown(ref response) => response.status.is_server_error(),
+        _ => false,
+    }
+}
+
+fn get_error(error: RusotoError<GetObjectError>) -> RusotoError<DownloadError> {
+    match error {
+        RusotoError::Unknown(ref response) if response.status.as_u16() == 412 => {
+            RusotoError::Service(DownloadError::ObjectChanged)
+        }
+        error => error.map_service(DownloadError::GetObject),
+    }
+}
+
+/// Reads the body of a ranged response, checking it is the expected part of the object.
+fn read_part(
+    output: GetObjectOutput,
+    e_tag: Option<String>,
+    expected: u64,
+    progress: Arc<DownloadProgress>,
+) -> impl Future<Item = Bytes, Error = RusotoError<DownloadError>> {
+    if e_tag.is_some() && output.e_tag.is_some() && output.e_tag != e_tag {
+        return Either::A(future::err(RusotoError::Service(
+            DownloadError::ObjectChanged,
+        )));
+    }
+    let received = Arc::new(AtomicU64::new(0));
+    let body = match output.body {
+        Some(body) => {
+            let (received, progress) = (received.clone(), progress.clone());
+            Either::A(
+                body.with_progress(move |part| {
+                    let previous = received.swap(part.transferred, Ordering::SeqCst);
+                    progress.advance(part.transferred - previous);
+                })
+                .concat2(),
+            )
+        }
+        None => Either::B(future::ok(Bytes::new())),
+    };
+    Either::B(
+        // an interrupted transfer is retried like a failed request
+        body.map_err(|e| RusotoError::from(HttpDispatchError::new(e.to_string())))
+            .and_then(move |data| {
+                if data.len() as u64 == expected {
+                    Ok(data)
+                } else {
+                    Err(RusotoError::Service(DownloadError::LengthMismatch {
+                        expected,
+                        actual: data.len() as u64,
+                    }))
+                }
+            })
+            .map_err(move |e| {
+                // the bytes of this attempt are received again by the next one
+                progress.rewind(received.load(Ordering::SeqCst));
+                e
+            }),
+    )
+}
+
+/// Looks up the size and ETag of the object of `request`, returning them with the stream of
+/// its parts.
+pub(crate) fn start_download<C>(
+    client: &C,
+    request: GetObjectRequest,
+    config: DownloadConfig,
+    progress: Option<Box<dyn Fn(TransferProgress) + Send + Sync>>,
+) -> impl Future<Item = (u64, Option<String>, DownloadStream), Error = RusotoError<DownloadError>>
+where
+    C: S3 + Clone + Send + Sync + 'static,
+{
+    let client = client.clone();
+    let head = HeadObjectRequest {
+        bucket: request.bucket.clone(),
+        key: request.key.clone(),
+        version_id: request.version_id.clone(),
+        request_payer: request.request_payer.clone(),
+        sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+        sse_customer_key: request.sse_customer_key.clone(),
+        sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+        ..Default::default()
+    };
+
+    client
+        .head_object(head)
+        .map_err(|e| e.map_service(DownloadError::HeadObject))
+        .map(move |output| {
+            let size = output.content_length.unwrap_or(0).max(0) as u64;
+            let e_tag = output.e_tag;
+            let request = GetObjectRequest {
+                if_match: e_tag.clone(),
+                // pin the version seen by HEAD, in case the object is overwritten
+                version_id: request.version_id.or(output.version_id),
+                ..request
+            };
+            let max_attempts = config.max_attempts.max(1);
+            let progress = Arc::new(DownloadProgress::new(size, progress));
+            let part_e_tag = e_tag.clone();
+            let parts = stream::iter_ok(part_ranges(size, config.part_size))
+                .map(move |(start, end)| {
+                    let client = client.clone();
+                    let request = GetObjectRequest {
+                        range: Some(format!("bytes={}-{}", start, end)),
+                        ..request.clone()
+                    };
+                    let e_tag = part_e_tag.clone();
+                    let progress = progress.clone();
+                    future::loop_fn(1, move |attempt| {
+                        let (e_tag, progress) = (e_tag.clone(), progress.clone());
+                        client
+                            .get_object(request.clone())
+                            .map_err(get_error)
+                            .and_then(move |output| {
+                                read_part(output, e_tag, end - start + 1, progress)
+                            })
+                            .then(move |result| match result {
+                                Err(ref e) if attempt < max_attempts && is_transient(e) => {
+                                    let backoff = Duration::from_millis(100u64 << attempt.min(6));
+                                    Either::A(
+                                        sleep(backoff).map(move |_| Loop::Continue(attempt + 1)),
+                                    )
+                                }
+                                result => Either::B(future::result(result.map(Loop::Break))),
+                            })
+                    })
+                })
+                .buffered(config.concurrency.max(1));
+            (size, e_tag, Box::new(parts) as DownloadStream)
+        })
+}
+
+/// Extension methods for downloading objects in concurrent parts.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::download::{DownloadConfig, DownloadExt};
+/// use rusoto_s3::{GetObjectRequest, S3Client};
+///
+/// let client = S3Client::new(Region::UsEast1);
+/// let request = GetObjectRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "backups/database.tar.gz".to_owned(),
+///     ..Default::default()
+/// };
+/// let object = client
+///     .download_file(request, "database.tar.gz", DownloadConfig::default())
+///     .sync()
+///     .unwrap();
+/// println!("downloaded {} bytes in {} parts", object.size, object.parts);
+/// ```
+pub trait DownloadExt: S3 + Clone + Send + Sync + Sized + 'static {
+    /// Downloads the object of `request` in parts, returning its data in order.
+    ///
+    /// The bucket, key, version and the request payer and customer key settings of `request`
+    /// are used for every part; its `range` and `if_match` members are replaced. The stream
+    /// fails if the object changes during the download, or if a part can't be downloaded in
+    /// `config.max_attempts` attempts.
+    fn download_parts(&self, request: GetObjectRequest, config: DownloadConfig) -> DownloadStream {
+        Box::new(
+            start_download(self, request, config, None)
+                .map(|(_, _, parts)| parts)
+                .flatten_stream(),
+        )
+    }
+
+    /// Downloads the object of `request` in parts like `download_parts`, calling `progress`
+    /// with the number of bytes received so far and the size of the object.
+    ///
+    /// `progress` is called on the event loop as the data of the parts is received, so it
+    /// should only record the progress or hand it off rather than block. Bytes received by a
+    /// part attempt which fails are subtracted again before the part is retried.
+    fn download_parts_with_progress<F>(
+        &self,
+        request: GetObjectRequest,
+        config: DownloadConfig,
+        progress: F,
+    ) -> DownloadStream
+    where
+        F: Fn(TransferProgress) + Send + Sync + 'static,
+    {
+        Box::new(
+            start_download(self, request, config, Some(Box::new(progress)))
+                .map(|(_, _, parts)| parts)
+                .flatten_stream(),
+        )
+    }
+
+    /// Downloads the object of `request` in parts, as a `ByteStream`.
+    ///
+    /// Use `ByteStream::into_async_read` or `ByteStream::into_blocking_read` to read it. Errors
+    /// are reported as `io::Error`s of kind `Other`.
+    fn download_byte_stream(
+        &self,
+        request: GetObjectRequest,
+        config: DownloadConfig,
+    ) -> ByteStream {
+        ByteStream::new(
+            self.download_parts(request, config)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string())),
+        )
+    }
+
+    /// Downloads the object of `request` in parts to a new file at `path`, checking the size
+    /// of the file matches the size of the object at the end.
+    ///
+    /// An existing file is overwritten. The file is removed if the download fails.
+    fn download_file<P: AsRef<Path>>(
+        &self,
+        request: GetObjectRequest,
+        path: P,
+        config: DownloadConfig,
+    ) -> RusotoFuture<DownloadedObject, DownloadError> {
+        download_to_file(self, request, path.as_ref(), config, None)
+    }
+
+    /// Downloads the object of `request` in parts to a new file at `path` like `download_file`,
+    /// calling `progress` with the number of bytes received so far and the size of the object.
+    ///
+    /// See `download_parts_with_progress` for when `progress` is called.
+    fn download_file_with_progress<P, F>(
+        &self,
+        request: GetObjectRequest,
+        path: P,
+        config: DownloadConfig,
+        progress: F,
+    ) -> RusotoFuture<DownloadedObject, DownloadError>
+    where
+        P: AsRef<Path>,
+        F: Fn(TransferProgress) + Send + Sync + 'static,
+    {
+        download_to_file(
+            self,
+            request,
+            path.as_ref(),
+            config,
+            Some(Box::new(progress)),
+        )
+    }
+}
+
+impl<T: S3 + Clone + Send + Sync + 'static> DownloadExt for T {}
+
+fn download_to_file<C>(
+    client: &C,
+    request: GetObjectRequest,
+    path: &Path,
+    config: DownloadConfig,
+    progress: Option<Box<dyn Fn(TransferProgress) + Send + Sync>>,
+) -> RusotoFuture<DownloadedObject, DownloadError>
+where
+    C: S3 + Clone + Send + Sync + 'static,
+{
+    let path = path.to_owned();
+    let file = match File::create(&path) {
+        Ok(file) => file,
+        Err(e) => {
+            return RusotoFuture::from_future(future::err(RusotoError::Service(
+                DownloadError::Write(e.to_string()),
+            )))
+        }
+    };
+    let write_error = |e: io::Error| RusotoError::Service(DownloadError::Write(e.to_string()));
+
+    let future = start_download(client, request, config, progress)
+        .and_then(move |(expected, e_tag, parts)| {
+            parts
+                .fold((file, 0, 0), move |(mut file, size, parts), data| {
+                    file.write_all(&data)
+                        .map(|_| (file, size + data.len() as u64, parts + 1))
+                        .map_err(write_error)
+                })
+                .and_then(move |(mut file, size, parts)| {
+                    file.flush().map_err(write_error)?;
+                    if size != expected {
+                        return Err(RusotoError::Service(DownloadError::LengthMismatch {
+                            expected,
+                            actual: size,
+                        }));
+                    }
+                    Ok(DownloadedObject { size, e_tag, parts })
+                })
+        })
+        .map_err(move |e| {
+            let _ = fs::remove_file(&path);
+            e
+        });
+
+    RusotoFuture::from_future(future)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_objects_into_inclusive_ranges() {
+        assert_eq!(part_ranges(0, 4), vec![]);
+        assert_eq!(part_ranges(4, 4), vec![(0, 3)]);
+        assert_eq!(part_ranges(10, 4), vec![(0, 3), (4, 7), (8, 9)]);
+        assert_eq!(part_ranges(3, 0), vec![(0, 0), (1, 1), (2, 2)]);
+    }
+}
@@ -0,0 +1,454 @@
+//! Client side (envelope) encryption for S3 objects.
+//!
+//! Objects are encrypted with AES-256-GCM under a fresh data key obtained from KMS
+//! `GenerateDataKey`. The wrapped data key and the parameters needed to decrypt the object are
+//! stored in the object's user metadata, using the same layout as the AWS SDK encryption
+//! clients (`x-amz-key-v2`, `x-amz-iv`, `x-amz-cek-alg`, ...), so objects can be exchanged
+//! with other SDKs.
+//!
+//! This module is only available with the `encryption` feature enabled.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use bytes::Bytes;
+use futures::{future, Future, Stream};
+use rand::RngCore;
+use rusoto_core::{Region, RusotoError, RusotoFuture};
+use rusoto_kms::{
+    DataKeySpec, DecryptError, DecryptRequest, GenerateDataKeyError, GenerateDataKeyRequest, Kms,
+    KmsClient,
+};
+
+use crate::generated::{
+    GetObjectError, GetObjectOutput, GetObjectRequest, PutObjectError, PutObjectOutput,
+    PutObjectRequest, S3Client, StreamingBody, S3,
+};
+
+const KEY_V2_HEADER: &str = "x-amz-key-v2";
+const IV_HEADER: &str = "x-amz-iv";
+const CEK_ALG_HEADER: &str = "x-amz-cek-alg";
+const WRAP_ALG_HEADER: &str = "x-amz-wrap-alg";
+const MATDESC_HEADER: &str = "x-amz-matdesc";
+const TAG_LEN_HEADER: &str = "x-amz-tag-len";
+const UNENCRYPTED_CONTENT_LENGTH_HEADER: &str = "x-amz-unencrypted-content-length";
+
+const AES_GCM_ALGORITHM: &str = "AES/GCM/NoPadding";
+const KMS_WRAP_ALGORITHM: &str = "kms";
+const KMS_CONTEXT_WRAP_ALGORITHM: &str = "kms+context";
+const CEK_ALG_CONTEXT_KEY: &str = "aws:x-amz-cek-alg";
+const GCM_TAG_LENGTH_BITS: &str = "128";
+const GCM_IV_LENGTH: usize = 12;
+const DATA_KEY_LENGTH: usize = 32;
+
+/// Errors returned by the `EncryptedS3Client`.
+#[derive(Debug, PartialEq)]
+pub enum S3EncryptionError {
+    /// KMS failed to generate a data key.
+    GenerateDataKey(GenerateDataKeyError),
+    /// KMS failed to decrypt the wrapped data key.
+    Decrypt(DecryptError),
+    /// S3 rejected the upload of the encrypted object.
+    PutObject(PutObjectError),
+    /// S3 failed to return the encrypted object.
+    GetObject(GetObjectError),
+    /// The object is missing the envelope metadata needed to decrypt it.
+    MissingMetadata(String),
+    /// The object was encrypted with an algorithm this client does not support.
+    UnsupportedAlgorithm(String),
+    /// Encrypting or decrypting the object contents failed.
+    Crypto(String),
+}
+
+impl fmt::Display for S3EncryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for S3EncryptionError {
+    fn description(&self) -> &str {
+        match *self {
+            S3EncryptionError::GenerateDataKey(ref cause) => cause.description(),
+            S3EncryptionError::Decrypt(ref cause) => cause.description(),
+            S3EncryptionError::PutObject(ref cause) => cause.description(),
+            S3EncryptionError::GetObject(ref cause) => cause.description(),
+            S3EncryptionError::MissingMetadata(ref cause) => cause,
+            S3EncryptionError::UnsupportedAlgorithm(ref cause) => cause,
+            S3EncryptionError::Crypto(ref cause) => cause,
+        }
+    }
+}
+
+fn crypto_error<T: fmt::Display>(message: &str, cause: T) -> RusotoError<S3EncryptionError> {
+    RusotoError::Service(S3EncryptionError::Crypto(format!("{}: {}", message, cause)))
+}
+
+fn missing_metadata(key: &str) -> RusotoError<S3EncryptionError> {
+    RusotoError::Service(S3EncryptionError::MissingMetadata(format!(
+        "object is missing encryption metadata x-amz-meta-{}",
+        key
+    )))
+}
+
+fn read_body(
+    body: Option<StreamingBody>,
+) -> Box<dyn Future<Item = Bytes, Error = RusotoError<S3EncryptionError>> + Send> {
+    match body {
+        Some(body) => Box::new(body.concat2().map_err(RusotoError::from)),
+        None => Box::new(future::ok(Bytes::new())),
+    }
+}
+
+/// Encrypts `plaintext` with AES-256-GCM, returning the ciphertext with the authentication
+/// tag appended, as expected by the other AWS SDKs.
+fn encrypt_content(
+    data_key: &[u8],
+    iv: &[u8],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, RusotoError<S3EncryptionError>> {
+    if data_key.len() != DATA_KEY_LENGTH {
+        return Err(crypto_error("invalid data key", "expected a 256 bit key"));
+    }
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(data_key));
+    cipher
+        .encrypt(Nonce::from_slice(iv), plaintext)
+        .map_err(|_| crypto_error("failed to encrypt object", "AES-GCM error"))
+}
+
+/// Decrypts and authenticates AES-256-GCM `ciphertext` produced by `encrypt_content`.
+fn decrypt_content(
+    data_key: &[u8],
+    iv: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, RusotoError<S3EncryptionError>> {
+    if data_key.len() != DATA_KEY_LENGTH {
+        return Err(crypto_error("invalid data key", "expected a 256 bit key"));
+    }
+    if iv.len() != GCM_IV_LENGTH {
+        return Err(crypto_error("invalid iv", "expected a 96 bit iv"));
+    }
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(data_key));
+    cipher
+        .decrypt(Nonce::from_slice(iv), ciphertext)
+        .map_err(|_| crypto_error("failed to decrypt object", "authentication failed"))
+}
+
+/// The parts of the envelope needed to decrypt an object, read from its metadata.
+#[derive(Debug, PartialEq)]
+struct Envelope {
+    wrapped_key: Vec<u8>,
+    iv: Vec<u8>,
+    encryption_context: HashMap<String, String>,
+}
+
+impl Envelope {
+    fn from_metadata(
+        metadata: &HashMap<String, String>,
+    ) -> Result<Envelope, RusotoError<S3EncryptionError>> {
+        let get = |key: &str| metadata.get(key).ok_or_else(|| missing_metadata(key));
+
+        let cek_alg = get(CEK_ALG_HEADER)?;
+        if cek_alg != AES_GCM_ALGORITHM {
+            return Err(RusotoError::Service(
+                S3EncryptionError::UnsupportedAlgorithm(format!(
+                    "unsupported content encryption algorithm {}",
+                    cek_alg
+                )),
+            ));
+        }
+        let wrap_alg = get(WRAP_ALG_HEADER)?;
+        if wrap_alg != KMS_WRAP_ALGORITHM && wrap_alg != KMS_CONTEXT_WRAP_ALGORITHM {
+            return Err(RusotoError::Service(
+                S3EncryptionError::UnsupportedAlgorithm(format!(
+                    "unsupported key wrapping algorithm {}",
+                    wrap_alg
+                )),
+            ));
+        }
+
+        let wrapped_key = base64::decode(get(KEY_V2_HEADER)?)
+            .map_err(|e| crypto_error("invalid wrapped data key", e))?;
+        let iv = base64::decode(get(IV_HEADER)?).map_err(|e| crypto_error("invalid iv", e))?;
+        let encryption_context = match metadata.get(MATDESC_HEADER) {
+            Some(matdesc) => serde_json::from_str(matdesc)
+                .map_err(|e| crypto_error("invalid material description", e))?,
+            None => HashMap::new(),
+        };
+
+        Ok(Envelope {
+            wrapped_key,
+            iv,
+            encryption_context,
+        })
+    }
+
+    fn write_metadata(&self, plaintext_length: usize, metadata: &mut HashMap<String, String>) {
+        metadata.insert(KEY_V2_HEADER.to_owned(), base64::encode(&self.wrapped_key));
+        metadata.insert(IV_HEADER.to_owned(), base64::encode(&self.iv));
+        metadata.insert(CEK_ALG_HEADER.to_owned(), AES_GCM_ALGORITHM.to_owned());
+        metadata.insert(
+            WRAP_ALG_HEADER.to_owned(),
+            KMS_CONTEXT_WRAP_ALGORITHM.to_owned(),
+        );
+        metadata.insert(
+            MATDESC_HEADER.to_owned(),
+            serde_json::to_string(&self.encryption_context)
+                .expect("failed to serialize encryption context"),
+        );
+        metadata.insert(TAG_LEN_HEADER.to_owned(), GCM_TAG_LENGTH_BITS.to_owned());
+        metadata.insert(
+            UNENCRYPTED_CONTENT_LENGTH_HEADER.to_owned(),
+            plaintext_length.to_string(),
+        );
+    }
+}
+
+/// An S3 client which transparently encrypts objects on `put_object` and decrypts them on
+/// `get_object`, using KMS managed data keys.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::encryption::EncryptedS3Client;
+/// use rusoto_s3::{GetObjectRequest, PutObjectRequest};
+///
+/// let client = EncryptedS3Client::new(Region::UsEast1, "alias/my-key");
+/// client.put_object(PutObjectRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "secret.txt".to_owned(),
+///     body: Some(b"attack at dawn".to_vec().into()),
+///     ..Default::default()
+/// }).sync().unwrap();
+/// ```
+pub struct EncryptedS3Client<S = S3Client, K = KmsClient> {
+    s3: Arc<S>,
+    kms: Arc<K>,
+    key_id: String,
+    encryption_context: HashMap<String, String>,
+}
+
+impl EncryptedS3Client {
+    /// Creates a client for the given region, wrapping data keys with the KMS key `key_id`
+    /// (a key id, key ARN, alias name or alias ARN).
+    pub fn new<T: Into<String>>(region: Region, key_id: T) -> EncryptedS3Client {
+        EncryptedS3Client::new_with(
+            S3Client::new(region.clone()),
+            KmsClient::new(region),
+            key_id,
+        )
+    }
+}
+
+impl<S, K> EncryptedS3Client<S, K>
+where
+    S: S3 + Send + Sync + 'static,
+    K: Kms + Send + Sync + 'static,
+{
+    /// Creates a client from existing S3 and KMS clients.
+    pub fn new_with<T: Into<String>>(s3: S, kms: K, key_id: T) -> EncryptedS3Client<S, K> {
+        EncryptedS3Client {
+            s3: Arc::new(s3),
+            kms: Arc::new(kms),
+            key_id: key_id.into(),
+            encryption_context: HashMap::new(),
+        }
+    }
+
+    /// Adds an entry to the KMS encryption context used to wrap data keys.
+    ///
+    /// The context is stored in the `x-amz-matdesc` metadata entry and is required by KMS when
+    /// unwrapping the key again.
+    pub fn with_encryption_context<T: Into<String>, U: Into<String>>(
+        mut self,
+        key: T,
+        value: U,
+    ) -> Self {
+        self.encryption_context.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the underlying, non-encrypting S3 client.
+    pub fn inner(&self) -> &S {
+        &self.s3
+    }
+
+    /// Encrypts the request body and uploads it along with the envelope metadata.
+    ///
+    /// The whole body is buffered in memory, since AES-GCM authenticates the object as a whole.
+    /// Any `content_md5` on the request is dropped, as it would not match the ciphertext.
+    pub fn put_object(
+        &self,
+        mut input: PutObjectRequest,
+    ) -> RusotoFuture<PutObjectOutput, S3EncryptionError> {
+        let s3 = self.s3.clone();
+        let mut encryption_context = self.encryption_context.clone();
+        encryption_context.insert(CEK_ALG_CONTEXT_KEY.to_owned(), AES_GCM_ALGORITHM.to_owned());
+
+        let data_key = self
+            .kms
+            .generate_data_key(GenerateDataKeyRequest {
+                key_id: self.key_id.clone(),
+                key_spec: Some(DataKeySpec::Aes256),
+                encryption_context: Some(encryption_context.clone()),
+                ..Default::default()
+            })
+            .map_err(|e| e.map_service(S3EncryptionError::GenerateDataKey));
+
+        let future = data_key
+            .join(read_body(input.body.take()))
+            .and_then(move |(data_key, plaintext)| {
+                let (plaintext_key, wrapped_key) =
+                    match (data_key.plaintext, data_key.ciphertext_blob) {
+                        (Some(plaintext_key), Some(wrapped_key)) => (plaintext_key, wrapped_key),
+                        _ => {
+                            return Err(crypto_error(
+                                "invalid GenerateDataKey response",
+                                "missing key material",
+                            ))
+                        }
+                    };
+
+                let mut iv = [0u8; GCM_IV_LENGTH];
+                rand::thread_rng().fill_bytes(&mut iv);
+                let ciphertext = encrypt_content(&plaintext_key, &iv, &plaintext)?;
+
+                let envelope = Envelope {
+                    wrapped_key: wrapped_key.to_vec(),
+                    iv: iv.to_vec(),
+                    encryption_context,
+                };
+                let mut metadata = input.metadata.take().unwrap_or_default();
+                envelope.write_metadata(plaintext.len(), &mut metadata);
+
+                input.metadata = Some(metadata);
+                input.content_md5 = None;
+                input.content_length = Some(ciphertext.len() as i64);
+                input.body = Some(ciphertext.into());
+                Ok(input)
+            })
+            .and_then(move |input| {
+                s3.put_object(input)
+                    .map_err(|e| e.map_service(S3EncryptionError::PutObject))
+            });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Downloads an object, unwraps its data key with KMS and returns the decrypted body.
+    ///
+    /// Ranged requests are rejected, since a partial object cannot be authenticated.
+    pub fn get_object(
+        &self,
+        input: GetObjectRequest,
+    ) -> RusotoFuture<GetObjectOutput, S3EncryptionError> {
+        if input.range.is_some() {
+            return RusotoFuture::from(Err(RusotoError::Validation(
+                "ranged gets are not supported for encrypted objects".to_owned(),
+            )));
+        }
+
+        let kms = self.kms.clone();
+        let future = self
+            .s3
+            .get_object(input)
+            .map_err(|e| e.map_service(S3EncryptionError::GetObject))
+            .and_then(move |mut output| {
+                let envelope = match output.metadata {
+                    Some(ref metadata) => Envelope::from_metadata(metadata),
+                    None => Err(missing_metadata(KEY_V2_HEADER)),
+                };
+                let envelope = match envelope {
+                    Ok(envelope) => envelope,
+                    Err(e) => return future::Either::A(future::err(e)),
+                };
+
+                let data_key = kms
+                    .decrypt(DecryptRequest {
+                        ciphertext_blob: envelope.wrapped_key.clone().into(),
+                        encryption_context: Some(envelope.encryption_context.clone()),
+                        ..Default::default()
+                    })
+                    .map_err(|e| e.map_service(S3EncryptionError::Decrypt));
+
+                future::Either::B(data_key.join(read_body(output.body.take())).and_then(
+                    move |(data_key, ciphertext)| {
+                        let plaintext_key = data_key.plaintext.ok_or_else(|| {
+                            crypto_error("invalid Decrypt response", "missing plaintext key")
+                        })?;
+                        let plaintext = decrypt_content(&plaintext_key, &envelope.iv, &ciphertext)?;
+
+                        output.content_length = Some(plaintext.len() as i64);
+                        output.body = Some(plaintext.into());
+                        Ok(output)
+                    },
+                ))
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_round_trips() {
+        let key = [7u8; DATA_KEY_LENGTH];
+        let iv = [3u8; GCM_IV_LENGTH];
+        let ciphertext = encrypt_content(&key, &iv, b"attack at dawn").unwrap();
+
+        // ciphertext followed by a 16 byte tag
+        assert_eq!(ciphertext.len(), b"attack at dawn".len() + 16);
+        assert_eq!(
+            decrypt_content(&key, &iv, &ciphertext).unwrap(),
+            b"attack at dawn".to_vec()
+        );
+    }
+
+    #[test]
+    fn tampered_content_is_rejected() {
+        let key = [7u8; DATA_KEY_LENGTH];
+        let iv = [3u8; GCM_IV_LENGTH];
+        let mut ciphertext = encrypt_content(&key, &iv, b"attack at dawn").unwrap();
+        ciphertext[0] ^= 1;
+
+        assert!(decrypt_content(&key, &iv, &ciphertext).is_err());
+    }
+
+    #[test]
+    fn envelope_round_trips_through_metadata() {
+        let mut encryption_context = HashMap::new();
+        encryption_context.insert(CEK_ALG_CONTEXT_KEY.to_owned(), AES_GCM_ALGORITHM.to_owned());
+        let envelope = Envelope {
+            wrapped_key: vec![1, 2, 3],
+            iv: vec![4; GCM_IV_LENGTH],
+            encryption_context,
+        };
+
+        let mut metadata = HashMap::new();
+        envelope.write_metadata(42, &mut metadata);
+
+        assert_eq!(metadata[UNENCRYPTED_CONTENT_LENGTH_HEADER], "42");
+        assert_eq!(metadata[WRAP_ALG_HEADER], "kms+context");
+        assert_eq!(Envelope::from_metadata(&metadata).unwrap(), envelope);
+    }
+
+    #[test]
+    fn envelope_requires_wrapped_key() {
+        let mut metadata = HashMap::new();
+        metadata.insert(CEK_ALG_HEADER.to_owned(), AES_GCM_ALGORITHM.to_owned());
+        metadata.insert(WRAP_ALG_HEADER.to_owned(), KMS_WRAP_ALGORITHM.to_owned());
+
+        match Envelope::from_metadata(&metadata) {
+            Err(RusotoError::Service(S3EncryptionError::MissingMetadata(_))) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
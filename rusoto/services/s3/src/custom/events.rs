@@ -0,0 +1,318 @@
+//! S3 event notifications.
+//!
+//! S3 notifies SQS queues, SNS topics and Lambda functions of events in a bucket, such as objects
+//! being created or removed, with a JSON document listing them as `Records`. `S3EventNotification`
+//! is that document, parsed with `S3EventNotification::from_json`, or with
+//! `S3EventNotification::from_sqs_message_body` from the body of an SQS message, which may have
+//! been delivered through an SNS topic.
+//!
+//! Object keys are URL encoded in notifications. They are decoded while parsing, so they can be
+//! used in requests as they are.
+//!
+//! This module is only available with the `events` feature enabled.
+//!
+//! # Examples
+//!
+//! ```
+//! use rusoto_s3::events::S3EventNotification;
+//!
+//! let body = r#"{"Records": [{
+//!     "eventVersion": "2.1",
+//!     "eventSource": "aws:s3",
+//!     "awsRegion": "us-east-1",
+//!     "eventTime": "2019-10-21T12:00:00.000Z",
+//!     "eventName": "ObjectCreated:Put",
+//!     "s3": {
+//!         "s3SchemaVersion": "1.0",
+//!         "bucket": {"name": "my-bucket", "arn": "arn:aws:s3:::my-bucket"},
+//!         "object": {"key": "photos/summer+2019.jpg", "size": 1024}
+//!     }
+//! }]}"#;
+//!
+//! let notification = S3EventNotification::from_sqs_message_body(body).unwrap();
+//! let record = &notification.records[0];
+//! assert!(record.is_object_created());
+//! assert_eq!(record.s3.bucket.name, "my-bucket");
+//! assert_eq!(record.s3.object.key, "photos/summer 2019.jpg");
+//! ```
+
+use std::collections::HashMap;
+
+use rusoto_core::signature;
+use serde::{Deserialize, Deserializer};
+use serde_derive::Deserialize;
+
+/// A notification of events in a bucket.
+///
+/// The test event S3 sends when notifications are configured has no records.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct S3EventNotification {
+    /// The events.
+    #[serde(rename = "Records", default)]
+    pub records: Vec<S3EventRecord>,
+}
+
+impl S3EventNotification {
+    /// Parses a notification.
+    pub fn from_json(json: &str) -> Result<S3EventNotification, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Parses the body of an SQS message notifying of events, sent either by S3 itself or by an
+    /// SNS topic S3 notified.
+    pub fn from_sqs_message_body(body: &str) -> Result<S3EventNotification, serde_json::Error> {
+        #[derive(Deserialize)]
+        struct SnsNotification {
+            #[serde(rename = "Type")]
+            notification_type: String,
+            #[serde(rename = "Message")]
+            message: String,
+        }
+
+        match serde_json::from_str::<SnsNotification>(body) {
+            Ok(ref sns) if sns.notification_type == "Notification" => {
+                S3EventNotification::from_json(&sns.message)
+            }
+            _ => S3EventNotification::from_json(body),
+        }
+    }
+}
+
+/// An event in a bucket.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct S3EventRecord {
+    /// The version of the event structure, e.g. `2.1`.
+    pub event_version: String,
+    /// `aws:s3`.
+    pub event_source: String,
+    /// The region of the bucket.
+    pub aws_region: String,
+    /// When the event happened, in ISO 8601 format.
+    pub event_time: String,
+    /// The type of the event, such as `ObjectCreated:Put` or `ObjectRemoved:Delete`.
+    pub event_name: String,
+    /// Who caused the event.
+    #[serde(default)]
+    pub user_identity: Option<S3UserIdentity>,
+    /// The request causing the event.
+    #[serde(default)]
+    pub request_parameters: Option<S3RequestParameters>,
+    /// The response to the request causing the event, such as its `x-amz-request-id`.
+    #[serde(default)]
+    pub response_elements: HashMap<String, String>,
+    /// The bucket and object of the event.
+    pub s3: S3Entity,
+    /// The restored object, for `ObjectRestore:Completed` events.
+    #[serde(default)]
+    pub glacier_event_data: Option<S3GlacierEventData>,
+}
+
+impl S3EventRecord {
+    /// Whether an object was created, e.g. by `PutObject`, `CopyObject` or a multipart upload.
+    pub fn is_object_created(&self) -> bool {
+        self.event_name.starts_with("ObjectCreated:")
+    }
+
+    /// Whether an object was removed, or a delete marker was created for it.
+    pub fn is_object_removed(&self) -> bool {
+        self.event_name.starts_with("ObjectRemoved:")
+    }
+}
+
+/// The principal causing an event.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct S3UserIdentity {
+    /// The ID of the principal.
+    pub principal_id: String,
+}
+
+/// The request causing an event.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct S3RequestParameters {
+    /// The IP address the request was sent from.
+    #[serde(rename = "sourceIPAddress")]
+    pub source_ip_address: String,
+}
+
+/// The bucket and object of an event.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct S3Entity {
+    /// The version of the `s3` structure, e.g. `1.0`.
+    pub s3_schema_version: String,
+    /// The ID of the notification configuration the event matched.
+    #[serde(default)]
+    pub configuration_id: Option<String>,
+    /// The bucket.
+    pub bucket: S3Bucket,
+    /// The object.
+    pub object: S3Object,
+}
+
+/// The bucket of an event.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct S3Bucket {
+    /// The name of the bucket.
+    pub name: String,
+    /// The owner of the bucket.
+    #[serde(default)]
+    pub owner_identity: Option<S3UserIdentity>,
+    /// The ARN of the bucket.
+    pub arn: String,
+}
+
+/// The object of an event.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct S3Object {
+    /// The key of the object, decoded.
+    #[serde(deserialize_with = "deserialize_key")]
+    pub key: String,
+    /// The size of the object in bytes. Not given for removed objects.
+    #[serde(default)]
+    pub size: Option<i64>,
+    /// The ETag of the object. Not given for removed objects.
+    #[serde(default)]
+    pub e_tag: Option<String>,
+    /// The version of the object, if the bucket is versioned.
+    #[serde(default)]
+    pub version_id: Option<String>,
+    /// A hexadecimal value ordering the events of the same object key.
+    #[serde(default)]
+    pub sequencer: Option<String>,
+}
+
+/// The object restored from Glacier in an `ObjectRestore:Completed` event.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct S3GlacierEventData {
+    /// The restored copy.
+    pub restore_event_data: S3RestoreEventData,
+}
+
+/// The restored copy of an object.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct S3RestoreEventData {
+    /// When the restored copy expires, in ISO 8601 format.
+    pub lifecycle_restoration_expiry_time: String,
+    /// The storage class of the object.
+    pub lifecycle_restore_storage_class: String,
+}
+
+/// Decodes an object key, which is form encoded: spaces are sent as `+`.
+fn decode_key(key: &str) -> String {
+    signature::decode_uri(&key.replace('+', " "))
+}
+
+fn deserialize_key<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer).map(|key| decode_key(&key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a notification from the S3 documentation
+    const NOTIFICATION: &str = r#"{
+        "Records": [
+            {
+                "eventVersion": "2.1",
+                "eventSource": "aws:s3",
+                "awsRegion": "us-west-2",
+                "eventTime": "1970-01-01T00:00:00.000Z",
+                "eventName": "ObjectRemoved:Delete",
+                "userIdentity": {"principalId": "AWS:AIDAJDPLRKLG7UEXAMPLE"},
+                "requestParameters": {"sourceIPAddress": "127.0.0.1"},
+                "responseElements": {
+                    "x-amz-request-id": "C3D13FE58DE4C810",
+                    "x-amz-id-2": "FMyUVURIY8/IgAtTv8xRjskZQpcIZ9KG4V5Wp6S7S/JRWeUWerMUE5JgHvANOjpD"
+                },
+                "s3": {
+                    "s3SchemaVersion": "1.0",
+                    "configurationId": "testConfigRule",
+                    "bucket": {
+                        "name": "mybucket",
+                        "ownerIdentity": {"principalId": "A3NL1KOZZKExample"},
+                        "arn": "arn:aws:s3:::mybucket"
+                    },
+                    "object": {
+                        "key": "HappyFace%C3%A9+%282%29.jpg",
+                        "versionId": "096fKKXTRTtl3on89fVO.nfljtsv6qko",
+                        "sequencer": "0055AED6DCD90281E5"
+                    }
+                }
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn parses_notifications() {
+        let notification = S3EventNotification::from_json(NOTIFICATION).unwrap();
+        assert_eq!(notification.records.len(), 1);
+
+        let record = &notification.records[0];
+        assert!(record.is_object_removed());
+        assert!(!record.is_object_created());
+        assert_eq!(record.aws_region, "us-west-2");
+        assert_eq!(
+            record.request_parameters,
+            Some(S3RequestParameters {
+                source_ip_address: "127.0.0.1".to_owned()
+            })
+        );
+        assert_eq!(
+            record.response_elements.get("x-amz-request-id"),
+            Some(&"C3D13FE58DE4C810".to_owned())
+        );
+        assert_eq!(
+            record.s3.configuration_id,
+            Some("testConfigRule".to_owned())
+        );
+        assert_eq!(record.s3.bucket.name, "mybucket");
+        assert_eq!(record.s3.object.key, "HappyFaceé (2).jpg");
+        assert_eq!(record.s3.object.size, None);
+        assert_eq!(
+            record.s3.object.version_id,
+            Some("096fKKXTRTtl3on89fVO.nfljtsv6qko".to_owned())
+        );
+    }
+
+    #[test]
+    fn parses_notifications_delivered_through_sns() {
+        let body = serde_json::json!({
+            "Type": "Notification",
+            "MessageId": "22b80b92-fdea-4c2c-8f9d-bdfb0c7bf324",
+            "TopicArn": "arn:aws:sns:us-west-2:123456789012:MyTopic",
+            "Subject": "Amazon S3 Notification",
+            "Message": NOTIFICATION,
+        })
+        .to_string();
+
+        assert_eq!(
+            S3EventNotification::from_sqs_message_body(&body).unwrap(),
+            S3EventNotification::from_json(NOTIFICATION).unwrap()
+        );
+    }
+
+    #[test]
+    fn parses_test_events() {
+        let notification = S3EventNotification::from_sqs_message_body(
+            r#"{"Service":"Amazon S3","Event":"s3:TestEvent","Time":"2019-10-21T12:00:00.000Z","Bucket":"mybucket","RequestId":"5582815E1AEA5ADF","HostId":"8cLeGAmw098X5cv4Zkwcmo8vvZa3eH3eKxsPzbB9wrR+YstdA6Knx4Ip8EXAMPLE"}"#,
+        )
+        .unwrap();
+        assert!(notification.records.is_empty());
+    }
+
+    #[test]
+    fn decodes_keys() {
+        assert_eq!(decode_key("a+b%2Bc%2Fd"), "a b+c/d");
+        assert_eq!(decode_key("plain"), "plain");
+    }
+}
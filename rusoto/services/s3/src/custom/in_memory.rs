@@ -0,0 +1,323 @@
+//! An S3 backend keeping buckets and objects in memory.
+//!
+//! `FakeS3::new()` creates a fake S3 backed by an empty `InMemoryS3`, which can stand in for an
+//! `S3Client` in unit tests. Buckets can be created, listed and deleted, and objects put, read,
+//! listed with `ListObjectsV2` and deleted. The other operations fail with
+//! `RusotoError::Validation`.
+//!
+//! Errors the API has a typed variant for, such as `GetObjectError::NoSuchKey`, are returned as
+//! that variant. The others, such as a missing bucket in `PutObject`, are returned as
+//! `RusotoError::Validation` starting with the S3 error code.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+use futures::{Future, Stream};
+use rusoto_core::signature::ChecksumAlgorithm;
+use rusoto_core::{ByteStream, RusotoError};
+
+use crate::fake::{FakeS3, S3Backend};
+use crate::generated::{
+    Bucket, CommonPrefix, CreateBucketError, CreateBucketOutput, CreateBucketRequest,
+    DeleteBucketError, DeleteBucketRequest, DeleteObjectError, DeleteObjectOutput,
+    DeleteObjectRequest, GetObjectError, GetObjectOutput, GetObjectRequest, HeadBucketError,
+    HeadBucketRequest, HeadObjectError, HeadObjectOutput, HeadObjectRequest, ListBucketsError,
+    ListBucketsOutput, ListObjectsV2Error, ListObjectsV2Output, ListObjectsV2Request, Object,
+    ObjectStorageClass, PutObjectError, PutObjectOutput, PutObjectRequest,
+};
+
+/// `ListObjectsV2` returns up to 1,000 keys by default.
+const DEFAULT_MAX_KEYS: usize = 1000;
+
+#[derive(Clone, Debug)]
+struct StoredObject {
+    data: Vec<u8>,
+    content_type: Option<String>,
+    metadata: Option<HashMap<String, String>>,
+    e_tag: String,
+}
+
+/// Buckets and their objects, kept in memory.
+#[derive(Debug, Default)]
+pub struct InMemoryS3 {
+    buckets: Mutex<BTreeMap<String, BTreeMap<String, StoredObject>>>,
+}
+
+impl InMemoryS3 {
+    /// The data of an object, if it exists.
+    pub fn object_data(&self, bucket: &str, key: &str) -> Option<Vec<u8>> {
+        self.buckets()
+            .get(bucket)
+            .and_then(|objects| objects.get(key))
+            .map(|object| object.data.clone())
+    }
+
+    /// The keys of the objects in a bucket, in lexicographical order.
+    pub fn keys(&self, bucket: &str) -> Vec<String> {
+        self.buckets()
+            .get(bucket)
+            .map(|objects| objects.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn buckets(&self) -> MutexGuard<'_, BTreeMap<String, BTreeMap<String, StoredObject>>> {
+        self.buckets.lock().expect("InMemoryS3 lock poisoned")
+    }
+}
+
+impl FakeS3<InMemoryS3> {
+    /// Creates a fake S3 without any buckets.
+    pub fn new() -> FakeS3<InMemoryS3> {
+        FakeS3::with_backend(InMemoryS3::default())
+    }
+}
+
+impl Default for FakeS3<InMemoryS3> {
+    fn default() -> FakeS3<InMemoryS3> {
+        FakeS3::new()
+    }
+}
+
+fn no_such_bucket<E>(bucket: &str) -> RusotoError<E> {
+    RusotoError::Validation(format!(
+        "NoSuchBucket: The specified bucket does not exist: {}",
+        bucket
+    ))
+}
+
+/// Reads a request body, which is expected to be in memory already.
+fn read_body<E>(body: Option<ByteStream>) -> Result<Vec<u8>, RusotoError<E>> {
+    match body {
+        Some(ref stream) if stream.buffered().is_some() => Ok(stream
+            .buffered()
+            .map(|bytes| bytes.to_vec())
+            .unwrap_or_default()),
+        Some(stream) => Ok(stream.concat2().wait()?.to_vec()),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn hex(digest: &[u8]) -> String {
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+impl S3Backend for InMemoryS3 {
+    fn create_bucket(
+        &self,
+        input: CreateBucketRequest,
+    ) -> Result<CreateBucketOutput, RusotoError<CreateBucketError>> {
+        let mut buckets = self.buckets();
+        if buckets.contains_key(&input.bucket) {
+            return Err(RusotoError::Service(
+                CreateBucketError::BucketAlreadyOwnedByYou(input.bucket),
+            ));
+        }
+        let location = format!("/{}", input.bucket);
+        buckets.insert(input.bucket, BTreeMap::new());
+        Ok(CreateBucketOutput {
+            location: Some(location),
+        })
+    }
+
+    fn delete_bucket(
+        &self,
+        input: DeleteBucketRequest,
+    ) -> Result<(), RusotoError<DeleteBucketError>> {
+        let mut buckets = self.buckets();
+        match buckets.get(&input.bucket) {
+            None => return Err(no_such_bucket(&input.bucket)),
+            Some(objects) if !objects.is_empty() => {
+                return Err(RusotoError::Validation(format!(
+                    "BucketNotEmpty: The bucket you tried to delete is not empty: {}",
+                    input.bucket
+                )))
+            }
+            Some(_) => {}
+        }
+        buckets.remove(&input.bucket);
+        Ok(())
+    }
+
+    fn head_bucket(&self, input: HeadBucketRequest) -> Result<(), RusotoError<HeadBucketError>> {
+        if self.buckets().contains_key(&input.bucket) {
+            Ok(())
+        } else {
+            Err(RusotoError::Service(HeadBucketError::NoSuchBucket(
+                input.bucket,
+            )))
+        }
+    }
+
+    fn list_buckets(&self) -> Result<ListBucketsOutput, RusotoError<ListBucketsError>> {
+        let buckets = self
+            .buckets()
+            .keys()
+            .map(|name| Bucket {
+                name: Some(name.clone()),
+                ..Default::default()
+            })
+            .collect();
+        Ok(ListBucketsOutput {
+            buckets: Some(buckets),
+            ..Default::default()
+        })
+    }
+
+    fn put_object(
+        &self,
+        input: PutObjectRequest,
+    ) -> Result<PutObjectOutput, RusotoError<PutObjectError>> {
+        let data = read_body(input.body)?;
+        let e_tag = format!("\"{}\"", hex(&ChecksumAlgorithm::Md5.digest(&data)));
+        let bucket = &input.bucket;
+        let mut buckets = self.buckets();
+        let objects = buckets
+            .get_mut(bucket)
+            .ok_or_else(|| no_such_bucket(bucket))?;
+        objects.insert(
+            input.key,
+            StoredObject {
+                data,
+                content_type: input.content_type,
+                metadata: input.metadata,
+                e_tag: e_tag.clone(),
+            },
+        );
+        Ok(PutObjectOutput {
+            e_tag: Some(e_tag),
+            ..Default::default()
+        })
+    }
+
+    fn get_object(
+        &self,
+        input: GetObjectRequest,
+    ) -> Result<GetObjectOutput, RusotoError<GetObjectError>> {
+        let buckets = self.buckets();
+        let object = buckets
+            .get(&input.bucket)
+            .ok_or_else(|| no_such_bucket(&input.bucket))?
+            .get(&input.key)
+            .ok_or_else(|| RusotoError::Service(GetObjectError::NoSuchKey(input.key.clone())))?;
+        Ok(GetObjectOutput {
+            body: Some(ByteStream::from(object.data.clone())),
+            content_length: Some(object.data.len() as i64),
+            content_type: object.content_type.clone(),
+            e_tag: Some(object.e_tag.clone()),
+            metadata: object.metadata.clone(),
+            ..Default::default()
+        })
+    }
+
+    fn head_object(
+        &self,
+        input: HeadObjectRequest,
+    ) -> Result<HeadObjectOutput, RusotoError<HeadObjectError>> {
+        let buckets = self.buckets();
+        let object = buckets
+            .get(&input.bucket)
+            .ok_or_else(|| no_such_bucket(&input.bucket))?
+            .get(&input.key)
+            .ok_or_else(|| RusotoError::Service(HeadObjectError::NoSuchKey(input.key.clone())))?;
+        Ok(HeadObjectOutput {
+            content_length: Some(object.data.len() as i64),
+            content_type: object.content_type.clone(),
+            e_tag: Some(object.e_tag.clone()),
+            metadata: object.metadata.clone(),
+            ..Default::default()
+        })
+    }
+
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> Result<DeleteObjectOutput, RusotoError<DeleteObjectError>> {
+        self.buckets()
+            .get_mut(&input.bucket)
+            .ok_or_else(|| no_such_bucket(&input.bucket))?
+            .remove(&input.key);
+        Ok(DeleteObjectOutput::default())
+    }
+
+    fn list_objects_v2(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> Result<ListObjectsV2Output, RusotoError<ListObjectsV2Error>> {
+        let buckets = self.buckets();
+        let objects = buckets.get(&input.bucket).ok_or_else(|| {
+            RusotoError::Service(ListObjectsV2Error::NoSuchBucket(input.bucket.clone()))
+        })?;
+        let prefix = input.prefix.clone().unwrap_or_default();
+        let max_keys = input
+            .max_keys
+            .map_or(DEFAULT_MAX_KEYS, |max_keys| max_keys.max(0) as usize);
+        // continuation tokens are the last key or common prefix of the previous page
+        let after = input
+            .continuation_token
+            .clone()
+            .or_else(|| input.start_after.clone())
+            .unwrap_or_default();
+
+        let mut contents = Vec::new();
+        let mut common_prefixes: Vec<String> = Vec::new();
+        let mut last = None;
+        let mut is_truncated = false;
+        for (key, object) in objects.range(after.clone()..) {
+            if key <= &after || !key.starts_with(&prefix) {
+                continue;
+            }
+            let common_prefix = input.delimiter.as_ref().and_then(|delimiter| {
+                key[prefix.len()..]
+                    .find(delimiter.as_str())
+                    .map(|end| key[..prefix.len() + end + delimiter.len()].to_owned())
+            });
+            if let Some(ref common_prefix) = common_prefix {
+                if common_prefixes.last() == Some(common_prefix) || common_prefix <= &after {
+                    continue;
+                }
+            }
+            if contents.len() + common_prefixes.len() == max_keys {
+                is_truncated = true;
+                break;
+            }
+            match common_prefix {
+                Some(common_prefix) => {
+                    last = Some(common_prefix.clone());
+                    common_prefixes.push(common_prefix);
+                }
+                None => {
+                    last = Some(key.clone());
+                    contents.push(Object {
+                        key: Some(key.clone()),
+                        e_tag: Some(object.e_tag.clone()),
+                        size: Some(object.data.len() as i64),
+                        storage_class: Some(ObjectStorageClass::Standard),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+
+        Ok(ListObjectsV2Output {
+            key_count: Some((contents.len() + common_prefixes.len()) as i64),
+            contents: Some(contents),
+            common_prefixes: Some(
+                common_prefixes
+                    .into_iter()
+                    .map(|prefix| CommonPrefix {
+                        prefix: Some(prefix),
+                    })
+                    .collect(),
+            ),
+            continuation_token: input.continuation_token,
+            delimiter: input.delimiter,
+            is_truncated: Some(is_truncated),
+            max_keys: Some(max_keys as i64),
+            name: Some(input.bucket),
+            next_continuation_token: if is_truncated { last } else { None },
+            prefix: input.prefix,
+            start_after: input.start_after,
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,305 @@
+//! End to end integrity checks of object data.
+//!
+//! S3 rejects an upload whose `Content-MD5` or `x-amz-checksum-*` header doesn't match the data
+//! it received. Clients created with `S3Client::new_with_checksums` attach these headers to every
+//! `PutObject` and `UploadPart` request whose body is held in memory, e.g. created with
+//! `StreamingBody::from(Vec<u8>)`. The `put_object_checked` and `upload_part_checked` methods of
+//! `IntegrityExt` read streaming bodies into memory first, so their checksums can be computed.
+//!
+//! Downloads are checked by `get_object_checked`, which compares the MD5 digest of the object
+//! with its ETag. The ETag of objects uploaded in multiple parts or encrypted with KMS or a
+//! customer key isn't a digest of their data, so these objects can't be checked this way.
+
+use std::error::Error;
+use std::fmt;
+
+use futures::future::{self, Either};
+use futures::{Future, Stream};
+use rusoto_core::signature::{ChecksumAlgorithm, SignedRequest, SignedRequestPayload};
+use rusoto_core::{ByteStream, Client, Region, RusotoError, RusotoFuture};
+
+use crate::generated::{
+    GetObjectError, GetObjectOutput, GetObjectRequest, PutObjectError, PutObjectOutput,
+    PutObjectRequest, S3Client, ServerSideEncryption, UploadPartError, UploadPartOutput,
+    UploadPartRequest, S3,
+};
+
+/// Errors returned by the methods of `IntegrityExt`.
+#[derive(Debug, PartialEq)]
+pub enum IntegrityError {
+    /// Uploading the object failed, e.g. because its checksum didn't match.
+    PutObject(PutObjectError),
+    /// Uploading the part failed, e.g. because its checksum didn't match.
+    UploadPart(UploadPartError),
+    /// Downloading the object failed.
+    GetObject(GetObjectError),
+    /// The downloaded data doesn't match the checksum of the object.
+    ChecksumMismatch {
+        /// The checksum S3 reported
+        expected: String,
+        /// The checksum of the data received
+        actual: String,
+    },
+}
+
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IntegrityError::ChecksumMismatch {
+                ref expected,
+                ref actual,
+            } => write!(
+                f,
+                "{}: expected {}, got {}",
+                self.description(),
+                expected,
+                actual
+            ),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for IntegrityError {
+    fn description(&self) -> &str {
+        match *self {
+            IntegrityError::PutObject(ref cause) => cause.description(),
+            IntegrityError::UploadPart(ref cause) => cause.description(),
+            IntegrityError::GetObject(ref cause) => cause.description(),
+            IntegrityError::ChecksumMismatch { .. } => "Checksum of downloaded data doesn't match",
+        }
+    }
+}
+
+/// Adds the `Content-MD5` header, and the header of `algorithm` unless it is MD5, to an
+/// object upload whose body is held in memory.
+///
+/// Requests which already have the header, and requests without an in-memory body, are left
+/// alone.
+pub fn add_checksum_headers(request: &mut SignedRequest, algorithm: ChecksumAlgorithm) {
+    if request.method != "PUT" || request.headers.contains_key("x-amz-copy-source") {
+        return;
+    }
+    let body = match request.payload {
+        Some(SignedRequestPayload::Stream(ref stream)) => match stream.buffered() {
+            Some(body) => body.clone(),
+            None => return,
+        },
+        _ => return,
+    };
+    for algorithm in &[ChecksumAlgorithm::Md5, algorithm] {
+        let header = algorithm.header_name();
+        if !request.headers.contains_key(&header.to_ascii_lowercase()) {
+            request.add_header(header, &algorithm.compute(&body));
+        }
+    }
+    if algorithm != ChecksumAlgorithm::Md5 {
+        request.add_header("x-amz-sdk-checksum-algorithm", algorithm.as_str());
+    }
+}
+
+impl S3Client {
+    /// Creates a client attaching checksums to object uploads, see `add_checksum_headers`.
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::signature::ChecksumAlgorithm;
+    /// use rusoto_core::{Client, Region};
+    /// use rusoto_s3::S3Client;
+    ///
+    /// let client =
+    ///     S3Client::new_with_checksums(Client::shared(), Region::UsEast1, ChecksumAlgorithm::Sha256);
+    /// ```
+    pub fn new_with_checksums(
+        client: Client,
+        region: Region,
+        algorithm: ChecksumAlgorithm,
+    ) -> S3Client {
+        let client = client.with_unsigned_request_interceptor(move |request| {
+            add_checksum_headers(request, algorithm)
+        });
+        S3Client::new_with_client(client, region)
+    }
+}
+
+/// Reads a body into memory so that its checksum can be computed.
+fn buffer_body<E>(
+    body: Option<ByteStream>,
+) -> impl Future<Item = Option<ByteStream>, Error = RusotoError<E>> + Send
+where
+    E: Send + 'static,
+{
+    match body {
+        Some(ref stream) if stream.buffered().is_some() => Either::A(future::ok(body)),
+        Some(stream) => Either::B(
+            stream
+                .concat2()
+                .map(|bytes| Some(ByteStream::from(bytes.to_vec())))
+                .map_err(RusotoError::from),
+        ),
+        None => Either::A(future::ok(None)),
+    }
+}
+
+/// Whether the ETag of an object is the MD5 digest of its data.
+fn etag_is_md5(object: &GetObjectOutput) -> bool {
+    let etag = object
+        .e_tag
+        .as_ref()
+        .map_or("", |etag| etag.trim_matches('"'));
+    etag.len() == 32
+        && etag.chars().all(|c| c.is_ascii_hexdigit())
+        && object.server_side_encryption != Some(ServerSideEncryption::AwsKms)
+        && object.sse_customer_algorithm.is_none()
+}
+
+pub(crate) fn hex(digest: &[u8]) -> String {
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Extension methods for uploading and downloading objects with integrity checks.
+///
+/// These methods are meant for clients created with `S3Client::new_with_checksums`; with
+/// other clients, uploads are buffered but sent without checksums.
+pub trait IntegrityExt: S3 + Clone + Send + Sized + 'static {
+    /// Uploads an object, reading a streaming body into memory first so that its checksum is
+    /// sent along.
+    fn put_object_checked(
+        &self,
+        mut request: PutObjectRequest,
+    ) -> RusotoFuture<PutObjectOutput, IntegrityError> {
+        let client = self.clone();
+
+        let future = buffer_body(request.body.take()).and_then(move |body| {
+            request.body = body;
+            client
+                .put_object(request)
+                .map_err(|e| e.map_service(IntegrityError::PutObject))
+        });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Uploads a part of a multipart upload, reading a streaming body into memory first so
+    /// that its checksum is sent along.
+    fn upload_part_checked(
+        &self,
+        mut request: UploadPartRequest,
+    ) -> RusotoFuture<UploadPartOutput, IntegrityError> {
+        let client = self.clone();
+
+        let future = buffer_body(request.body.take()).and_then(move |body| {
+            request.body = body;
+            client
+                .upload_part(request)
+                .map_err(|e| e.map_service(IntegrityError::UploadPart))
+        });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Downloads an object into memory and checks its data against its ETag.
+    ///
+    /// Fails with `IntegrityError::ChecksumMismatch` if the data is corrupted. Objects whose
+    /// ETag isn't an MD5 digest are returned without being checked.
+    fn get_object_checked(
+        &self,
+        request: GetObjectRequest,
+    ) -> RusotoFuture<GetObjectOutput, IntegrityError> {
+        let future = self
+            .get_object(request)
+            .map_err(|e| e.map_service(IntegrityError::GetObject))
+            .and_then(|mut object| {
+                buffer_body(object.body.take()).and_then(move |body| {
+                    object.body = body;
+                    if !etag_is_md5(&object) {
+                        return Ok(object);
+                    }
+                    let data = object
+                        .body
+                        .as_ref()
+                        .and_then(ByteStream::buffered)
+                        .map_or(&[][..], |body| &body[..]);
+                    let actual = hex(&ChecksumAlgorithm::Md5.digest(data));
+                    let expected = object
+                        .e_tag
+                        .as_ref()
+                        .map_or("", |etag| etag.trim_matches('"'))
+                        .to_ascii_lowercase();
+                    if actual == expected {
+                        Ok(object)
+                    } else {
+                        Err(RusotoError::Service(IntegrityError::ChecksumMismatch {
+                            expected,
+                            actual,
+                        }))
+                    }
+                })
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: S3 + Clone + Send + 'static> IntegrityExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upload(body: Vec<u8>) -> SignedRequest {
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload_stream(ByteStream::from(body));
+        request
+    }
+
+    #[test]
+    fn adds_checksums_to_buffered_uploads() {
+        let mut request = upload(b"hello world".to_vec());
+        add_checksum_headers(&mut request, ChecksumAlgorithm::Crc32c);
+        assert_eq!(
+            request.headers.get("content-md5"),
+            Some(&vec![b"XrY7u+Ae7tCTyyK7j1rNww==".to_vec()])
+        );
+        assert_eq!(
+            request.headers.get("x-amz-checksum-crc32c"),
+            Some(&vec![b"yZRlqg==".to_vec()])
+        );
+        assert_eq!(
+            request.headers.get("x-amz-sdk-checksum-algorithm"),
+            Some(&vec![b"CRC32C".to_vec()])
+        );
+    }
+
+    #[test]
+    fn leaves_other_requests_alone() {
+        let mut copy = upload(Vec::new());
+        copy.add_header("x-amz-copy-source", "bucket/other");
+        add_checksum_headers(&mut copy, ChecksumAlgorithm::Md5);
+        assert!(!copy.headers.contains_key("content-md5"));
+
+        let mut get = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        add_checksum_headers(&mut get, ChecksumAlgorithm::Md5);
+        assert!(!get.headers.contains_key("content-md5"));
+    }
+
+    #[test]
+    fn only_plain_etags_are_digests() {
+        let object = GetObjectOutput {
+            e_tag: Some("\"5eb63bbbe01eeed093cb22bb8f5acdc3\"".to_owned()),
+            ..Default::default()
+        };
+        assert!(etag_is_md5(&object));
+
+        let multipart = GetObjectOutput {
+            e_tag: Some("\"5eb63bbbe01eeed093cb22bb8f5acdc3-2\"".to_owned()),
+            ..Default::default()
+        };
+        assert!(!etag_is_md5(&multipart));
+
+        let encrypted = GetObjectOutput {
+            server_side_encryption: Some(ServerSideEncryption::AwsKms),
+            ..object
+        };
+        assert!(!etag_is_md5(&encrypted));
+    }
+}
@@ -0,0 +1,314 @@
+//! Listing the objects of large buckets without buffering whole pages.
+//!
+//! `ListObjectsV2` returns up to 1000 objects per page, and the generated client buffers each
+//! response body before parsing the complete document. `ObjectLister::list_objects_v2_stream`
+//! instead parses the body as it arrives: every `<Contents>` element is deserialized as soon as
+//! it has been received and yielded right away, so memory use doesn't grow with the page size.
+//! The next page is requested once the previous one has been read to its end.
+
+use std::collections::VecDeque;
+use std::mem;
+
+use bytes::{Bytes, BytesMut};
+use futures::{Async, Future, Poll, Stream};
+use rusoto_core::param::{Params, ServiceParams};
+use rusoto_core::proto::xml::util::{peek_at_name, Next, XmlParseError, XmlResponse};
+use rusoto_core::request::HttpResponse;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{ByteStream, Client, Region, RusotoError, RusotoFuture};
+use xml::reader::ParserConfig;
+use xml::EventReader;
+
+use crate::generated::{
+    ListObjectsV2Error, ListObjectsV2OutputDeserializer, ListObjectsV2Request, Object,
+    ObjectDeserializer,
+};
+
+const CONTENTS_START: &[u8] = b"<Contents>";
+const CONTENTS_END: &[u8] = b"</Contents>";
+
+/// Lists objects with incremental parsing of the responses.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::{Future, Stream};
+/// use rusoto_core::Region;
+/// use rusoto_s3::listing::ObjectLister;
+/// use rusoto_s3::ListObjectsV2Request;
+///
+/// let lister = ObjectLister::new(Region::UsEast1);
+/// lister
+///     .list_objects_v2_stream(ListObjectsV2Request {
+///         bucket: "my-bucket".to_owned(),
+///         ..Default::default()
+///     })
+///     .for_each(|object| {
+///         println!("{}", object.key.unwrap_or_default());
+///         Ok(())
+///     })
+///     .wait()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct ObjectLister {
+    client: Client,
+    region: Region,
+}
+
+impl ObjectLister {
+    /// Creates a lister sending requests with the shared default client.
+    pub fn new(region: Region) -> ObjectLister {
+        ObjectLister::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a lister sending requests with `client`, e.g. the one an `S3Client` was created
+    /// with.
+    pub fn new_with_client(client: Client, region: Region) -> ObjectLister {
+        ObjectLister { client, region }
+    }
+
+    /// Streams every object matched by `input`, following continuation tokens from the
+    /// request's `continuation_token` onwards.
+    ///
+    /// Common prefixes are not returned; use `S3::list_objects_v2` to list them.
+    pub fn list_objects_v2_stream(&self, input: ListObjectsV2Request) -> ObjectStream {
+        ObjectStream {
+            lister: self.clone(),
+            input,
+            state: State::Idle,
+            objects: VecDeque::new(),
+        }
+    }
+
+    fn request_page(
+        &self,
+        input: &ListObjectsV2Request,
+    ) -> RusotoFuture<HttpResponse, ListObjectsV2Error> {
+        let request_uri = format!("/{}", input.bucket);
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", request_payer.as_str());
+        }
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.continuation_token {
+            params.put("continuation-token", x);
+        }
+        if let Some(ref x) = input.delimiter {
+            params.put("delimiter", x);
+        }
+        if let Some(ref x) = input.encoding_type {
+            params.put("encoding-type", x);
+        }
+        if let Some(ref x) = input.fetch_owner {
+            params.put("fetch-owner", x);
+        }
+        if let Some(ref x) = input.max_keys {
+            params.put("max-keys", x);
+        }
+        if let Some(ref x) = input.prefix {
+            params.put("prefix", x);
+        }
+        if let Some(ref x) = input.start_after {
+            params.put("start-after", x);
+        }
+        params.put("list-type", "2");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(futures::future::ok(response))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListObjectsV2Error::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A stream of the objects in a bucket, as returned by `ObjectLister::list_objects_v2_stream`.
+pub struct ObjectStream {
+    lister: ObjectLister,
+    input: ListObjectsV2Request,
+    state: State,
+    objects: VecDeque<Object>,
+}
+
+enum State {
+    Idle,
+    Requesting(RusotoFuture<HttpResponse, ListObjectsV2Error>),
+    Reading(ByteStream, PageParser),
+    Done,
+}
+
+impl Stream for ObjectStream {
+    type Item = Object;
+    type Error = RusotoError<ListObjectsV2Error>;
+
+    fn poll(&mut self) -> Poll<Option<Object>, RusotoError<ListObjectsV2Error>> {
+        loop {
+            if let Some(object) = self.objects.pop_front() {
+                return Ok(Async::Ready(Some(object)));
+            }
+
+            self.state = match mem::replace(&mut self.state, State::Done) {
+                State::Idle => State::Requesting(self.lister.request_page(&self.input)),
+                State::Requesting(mut future) => match future.poll()? {
+                    Async::Ready(response) => State::Reading(response.body, PageParser::default()),
+                    Async::NotReady => {
+                        self.state = State::Requesting(future);
+                        return Ok(Async::NotReady);
+                    }
+                },
+                State::Reading(mut body, mut parser) => match body.poll()? {
+                    Async::Ready(Some(chunk)) => {
+                        parser.push(&chunk, &mut self.objects)?;
+                        State::Reading(body, parser)
+                    }
+                    Async::Ready(None) => match parser.finish()? {
+                        Some(token) => {
+                            self.input.continuation_token = Some(token);
+                            State::Idle
+                        }
+                        None => State::Done,
+                    },
+                    Async::NotReady => {
+                        self.state = State::Reading(body, parser);
+                        return Ok(Async::NotReady);
+                    }
+                },
+                State::Done => return Ok(Async::Ready(None)),
+            };
+        }
+    }
+}
+
+/// Splits a `ListObjectsV2` response into its `<Contents>` elements, which are parsed as they
+/// are completed, and the rest of the document, which is parsed at the end.
+///
+/// Text content is escaped in XML, so `<Contents>` can only occur as a tag.
+#[derive(Default)]
+struct PageParser {
+    pending: BytesMut,
+    rest: Vec<u8>,
+}
+
+impl PageParser {
+    fn push(&mut self, chunk: &Bytes, objects: &mut VecDeque<Object>) -> Result<(), XmlParseError> {
+        self.pending.extend_from_slice(chunk);
+        loop {
+            let start = match find(&self.pending, CONTENTS_START) {
+                Some(start) => start,
+                None => {
+                    // the end of the chunk may be the beginning of a start tag
+                    let keep = (CONTENTS_START.len() - 1).min(self.pending.len());
+                    let complete = self.pending.len() - keep;
+                    self.rest
+                        .extend_from_slice(&self.pending.split_to(complete));
+                    return Ok(());
+                }
+            };
+            self.rest.extend_from_slice(&self.pending.split_to(start));
+
+            let end = match find(&self.pending, CONTENTS_END) {
+                Some(end) => end + CONTENTS_END.len(),
+                None => return Ok(()),
+            };
+            let element = self.pending.split_to(end);
+            objects.push_back(parse_object(&element)?);
+        }
+    }
+
+    /// Parses the rest of the document, returning the token of the next page if the listing is
+    /// truncated.
+    fn finish(mut self) -> Result<Option<String>, XmlParseError> {
+        if find(&self.pending, CONTENTS_START).is_some() {
+            return Err(XmlParseError::new("Incomplete Contents element"));
+        }
+        self.rest.extend_from_slice(&self.pending);
+        if self.rest.iter().all(u8::is_ascii_whitespace) {
+            return Ok(None);
+        }
+
+        let reader = EventReader::new_with_config(
+            self.rest.as_slice(),
+            ParserConfig::new().trim_whitespace(true),
+        );
+        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let _start_document = stack.next();
+        let actual_tag_name = peek_at_name(&mut stack)?;
+        let output = ListObjectsV2OutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+        if output.is_truncated == Some(true) {
+            Ok(output.next_continuation_token)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+fn parse_object(element: &[u8]) -> Result<Object, XmlParseError> {
+    let reader = EventReader::new_with_config(element, ParserConfig::new().trim_whitespace(true));
+    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+    let _start_document = stack.next();
+    ObjectDeserializer::deserialize("Contents", &mut stack)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Name>bucket</Name>
+            <NextContinuationToken>next-page</NextContinuationToken>
+            <KeyCount>2</KeyCount>
+            <MaxKeys>2</MaxKeys>
+            <IsTruncated>true</IsTruncated>
+            <Contents>
+                <Key>a&lt;Contents&gt;.txt</Key>
+                <Size>1</Size>
+            </Contents>
+            <Contents>
+                <Key>b.txt</Key>
+                <Size>2</Size>
+            </Contents>
+        </ListBucketResult>"#;
+
+    #[test]
+    fn parses_objects_split_across_chunks() {
+        let mut parser = PageParser::default();
+        let mut objects = VecDeque::new();
+        for chunk in PAGE.as_bytes().chunks(3) {
+            parser.push(&Bytes::from(chunk), &mut objects).unwrap();
+        }
+
+        let keys: Vec<_> = objects.iter().map(|o| o.key.clone().unwrap()).collect();
+        assert_eq!(keys, vec!["a<Contents>.txt", "b.txt"]);
+        assert_eq!(objects[1].size, Some(2));
+        // only the tail which may start a split `<Contents>` tag is held back
+        assert!(parser.pending.len() < CONTENTS_START.len());
+        assert!(find(&parser.pending, CONTENTS_START).is_none());
+        assert_eq!(parser.finish().unwrap(), Some("next-page".to_owned()));
+    }
+
+    #[test]
+    fn rejects_truncated_bodies() {
+        let mut parser = PageParser::default();
+        let truncated = &PAGE[..PAGE.find("<Size>2").unwrap()];
+        parser
+            .push(&Bytes::from(truncated), &mut VecDeque::new())
+            .unwrap();
+        assert!(parser.finish().is_err());
+    }
+}
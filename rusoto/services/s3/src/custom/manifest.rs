@@ -0,0 +1,677 @@
+//! Integrity manifests of the objects under a prefix.
+//!
+//! `ManifestExt::write_manifest` lists the objects under a prefix and computes the checksum of
+//! each one, downloading it in concurrent ranged requests like `DownloadExt` and hashing the
+//! parts as they arrive. The checksums are written to a manifest file one object at a time, in
+//! the order of the listing, so an interrupted run picks up after the last object written when
+//! it is started again with the same file.
+//!
+//! `ManifestExt::verify_manifest` computes the checksums again and reports the objects whose
+//! data changed, the objects which disappeared and the objects which were added since.
+//!
+//! This version of the S3 API has no `GetObjectAttributes` operation, and the ETag is only a
+//! digest of the data for some objects (see `integrity`), so every object is read in full.
+//! Objects in the Glacier storage classes must be restored before they can be read.
+//!
+//! # Format
+//!
+//! A manifest is a text file. Its first line is a header naming the bucket, prefix and
+//! algorithm; every other line describes an object, as tab separated fields:
+//!
+//! ```text
+//! rusoto-s3-manifest/1	SHA256	my-bucket	audits/2019/
+//! audits/2019/q1.csv	1024	"9a9d1bbe80188883302bff764b4cb321"	2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae
+//! ```
+//!
+//! The fields are the key, the size in bytes, the ETag (`-` if S3 returned none) and the hex
+//! encoded checksum. Tabs, line breaks and backslashes in keys and prefixes are escaped with a
+//! backslash.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use futures::future;
+use futures::{stream, Future, Stream};
+use rusoto_core::signature::ChecksumAlgorithm;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::custom::download::{start_download, DownloadConfig, DownloadError};
+use crate::custom::integrity::hex;
+use crate::generated::{GetObjectRequest, ListObjectsV2Error, ListObjectsV2Request, Object, S3};
+
+/// The first field of the header of a manifest.
+const MANIFEST_VERSION: &str = "rusoto-s3-manifest/1";
+
+/// The number of objects hashed concurrently by default.
+pub const DEFAULT_MANIFEST_CONCURRENCY: usize = 4;
+
+/// How the checksums of objects are computed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ManifestConfig {
+    /// Algorithm the checksums are computed with. `verify_manifest` uses the algorithm of the
+    /// manifest instead.
+    pub algorithm: ChecksumAlgorithm,
+    /// Number of objects hashed concurrently
+    pub concurrency: usize,
+    /// How each object is downloaded, which bounds memory use to about
+    /// `concurrency * download.part_size * download.concurrency`
+    pub download: DownloadConfig,
+}
+
+impl Default for ManifestConfig {
+    fn default() -> ManifestConfig {
+        ManifestConfig {
+            algorithm: ChecksumAlgorithm::Sha256,
+            concurrency: DEFAULT_MANIFEST_CONCURRENCY,
+            download: DownloadConfig::default(),
+        }
+    }
+}
+
+/// The checksum of an object.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ManifestEntry {
+    /// Key of the object
+    pub key: String,
+    /// Size of the object in bytes
+    pub size: u64,
+    /// ETag of the object
+    pub e_tag: Option<String>,
+    /// Hex encoded checksum of the data of the object
+    pub checksum: String,
+}
+
+impl ManifestEntry {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\n",
+            escape(&self.key),
+            self.size,
+            self.e_tag.as_ref().map_or("-", String::as_str),
+            self.checksum
+        )
+    }
+
+    fn parse(line: &str) -> Result<ManifestEntry, String> {
+        let fields = line.split('\t').collect::<Vec<_>>();
+        if fields.len() != 4 {
+            return Err(format!("expected 4 fields, found {}", fields.len()));
+        }
+        Ok(ManifestEntry {
+            key: unescape(fields[0])?,
+            size: fields[1]
+                .parse()
+                .map_err(|_| format!("invalid size {:?}", fields[1]))?,
+            e_tag: match fields[2] {
+                "-" => None,
+                e_tag => Some(e_tag.to_owned()),
+            },
+            checksum: fields[3].to_owned(),
+        })
+    }
+}
+
+/// The checksums of the objects under a prefix, as written by `ManifestExt::write_manifest`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Manifest {
+    /// Bucket of the objects
+    pub bucket: String,
+    /// Prefix of the keys of the objects
+    pub prefix: String,
+    /// Algorithm the checksums were computed with
+    pub algorithm: ChecksumAlgorithm,
+    /// Checksums of the objects, in the order of their keys
+    pub entries: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+    /// Creates an empty manifest.
+    pub fn new(bucket: &str, prefix: &str, algorithm: ChecksumAlgorithm) -> Manifest {
+        Manifest {
+            bucket: bucket.to_owned(),
+            prefix: prefix.to_owned(),
+            algorithm,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Reads the manifest file at `path`.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Manifest, ManifestError> {
+        Manifest::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Parses the contents of a manifest file.
+    pub fn parse(text: &str) -> Result<Manifest, ManifestError> {
+        let malformed = |line: usize, message: String| ManifestError::Malformed { line, message };
+        let mut lines = text.lines();
+        let header = lines.next().unwrap_or("");
+        let fields = header.split('\t').collect::<Vec<_>>();
+        if fields.len() != 4 || fields[0] != MANIFEST_VERSION {
+            return Err(malformed(1, "missing manifest header".to_owned()));
+        }
+        let mut manifest = Manifest {
+            bucket: fields[2].to_owned(),
+            prefix: unescape(fields[3]).map_err(|e| malformed(1, e))?,
+            algorithm: fields[1]
+                .parse()
+                .map_err(|e| malformed(1, format!("{}", e)))?,
+            entries: Vec::new(),
+        };
+        for (index, line) in lines.enumerate() {
+            let entry = ManifestEntry::parse(line).map_err(|e| malformed(index + 2, e))?;
+            manifest.entries.push(entry);
+        }
+        Ok(manifest)
+    }
+
+    /// Writes the manifest, header first.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(self.header().as_bytes())?;
+        for entry in &self.entries {
+            writer.write_all(entry.to_line().as_bytes())?;
+        }
+        writer.flush()
+    }
+
+    fn header(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\n",
+            MANIFEST_VERSION,
+            self.algorithm,
+            self.bucket,
+            escape(&self.prefix)
+        )
+    }
+}
+
+/// An object whose data no longer matches its manifest entry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChangedObject {
+    /// The entry of the object in the manifest
+    pub expected: ManifestEntry,
+    /// The entry computed from the current data of the object
+    pub actual: ManifestEntry,
+}
+
+/// The result of `ManifestExt::verify_manifest`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VerificationReport {
+    /// Number of objects whose size and checksum match the manifest
+    pub matched: usize,
+    /// Objects whose size or checksum changed
+    pub changed: Vec<ChangedObject>,
+    /// Keys of objects in the manifest which no longer exist
+    pub missing: Vec<String>,
+    /// Keys of objects which aren't in the manifest
+    pub unexpected: Vec<String>,
+}
+
+impl VerificationReport {
+    /// Whether the objects under the prefix are exactly the ones of the manifest, unchanged.
+    pub fn is_verified(&self) -> bool {
+        self.changed.is_empty() && self.missing.is_empty() && self.unexpected.is_empty()
+    }
+
+    fn new(manifest: Manifest, actual: Vec<ManifestEntry>) -> VerificationReport {
+        let mut expected = manifest
+            .entries
+            .into_iter()
+            .map(|entry| (entry.key.clone(), entry))
+            .collect::<BTreeMap<_, _>>();
+        let mut report = VerificationReport::default();
+        for entry in actual {
+            match expected.remove(&entry.key) {
+                None => report.unexpected.push(entry.key),
+                Some(ref expected)
+                    if expected.size == entry.size
+                        && expected.checksum.eq_ignore_ascii_case(&entry.checksum) =>
+                {
+                    report.matched += 1
+                }
+                Some(expected) => report.changed.push(ChangedObject {
+                    expected,
+                    actual: entry,
+                }),
+            }
+        }
+        report.missing = expected.into_iter().map(|(key, _)| key).collect();
+        report
+    }
+}
+
+/// Errors returned by the methods of `ManifestExt`.
+#[derive(Debug, PartialEq)]
+pub enum ManifestError {
+    /// Listing the objects failed.
+    ListObjects(ListObjectsV2Error),
+    /// Downloading an object failed.
+    Download(DownloadError),
+    /// Reading or writing the manifest file failed.
+    Io(String),
+    /// The manifest file can't be parsed.
+    Malformed {
+        /// The number of the offending line, starting at 1
+        line: usize,
+        /// What is wrong with it
+        message: String,
+    },
+    /// The manifest file to resume is of another bucket, prefix or algorithm.
+    Conflict(String),
+}
+
+impl From<io::Error> for ManifestError {
+    fn from(error: io::Error) -> ManifestError {
+        ManifestError::Io(error.to_string())
+    }
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ManifestError::Io(ref cause) | ManifestError::Conflict(ref cause) => {
+                write!(f, "{}: {}", self.description(), cause)
+            }
+            ManifestError::Malformed { line, ref message } => {
+                write!(f, "{} on line {}: {}", self.description(), line, message)
+            }
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for ManifestError {
+    fn description(&self) -> &str {
+        match *self {
+            ManifestError::ListObjects(ref cause) => cause.description(),
+            ManifestError::Download(ref cause) => cause.description(),
+            ManifestError::Io(_) => "Reading or writing the manifest failed",
+            ManifestError::Malformed { .. } => "Malformed manifest",
+            ManifestError::Conflict(_) => "Manifest is of other objects",
+        }
+    }
+}
+
+/// A stream of the checksums of objects, as returned by `ManifestExt::object_checksums`.
+pub type ManifestStream =
+    Box<dyn Stream<Item = ManifestEntry, Error = RusotoError<ManifestError>> + Send>;
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape(value: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => unescaped.push('\\'),
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            other => {
+                return Err(format!(
+                    "invalid escape sequence \\{}",
+                    other.unwrap_or(' ')
+                ))
+            }
+        }
+    }
+    Ok(unescaped)
+}
+
+/// Opens the manifest file at `path` to append the entries after its last one, creating it if
+/// it doesn't exist. Returns the key of the last entry, to resume the listing after.
+fn open_manifest(
+    path: &Path,
+    manifest: &Manifest,
+) -> Result<(File, Option<String>), ManifestError> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    // a run interrupted while writing leaves an incomplete last line behind
+    let complete = &text[..text.rfind('\n').map_or(0, |end| end + 1)];
+    if complete.is_empty() {
+        let mut file = File::create(path)?;
+        file.write_all(manifest.header().as_bytes())?;
+        return Ok((file, None));
+    }
+
+    let existing = Manifest::parse(complete)?;
+    if existing.bucket != manifest.bucket
+        || existing.prefix != manifest.prefix
+        || existing.algorithm != manifest.algorithm
+    {
+        return Err(ManifestError::Conflict(format!(
+            "{} lists {} checksums of s3://{}/{}",
+            path.display(),
+            existing.algorithm,
+            existing.bucket,
+            existing.prefix
+        )));
+    }
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    file.set_len(complete.len() as u64)?;
+    file.seek(SeekFrom::End(0))?;
+    Ok((file, existing.entries.last().map(|entry| entry.key.clone())))
+}
+
+/// Lists the objects of `bucket` under `prefix`, after the key `start_after`.
+fn list_objects<C>(
+    client: &C,
+    bucket: String,
+    prefix: String,
+    start_after: Option<String>,
+) -> impl Stream<Item = Object, Error = RusotoError<ManifestError>> + Send
+where
+    C: S3 + Clone + Send + Sync + 'static,
+{
+    let client = client.clone();
+    let pages = stream::unfold(Some((None, start_after)), move |markers| {
+        let (continuation_token, start_after) = markers?;
+        let request = ListObjectsV2Request {
+            bucket: bucket.clone(),
+            prefix: Some(prefix.clone()),
+            continuation_token,
+            start_after,
+            ..Default::default()
+        };
+        Some(
+            client
+                .list_objects_v2(request)
+                .map_err(|e| e.map_service(ManifestError::ListObjects))
+                .map(|output| {
+                    let next = match output.next_continuation_token {
+                        Some(token) if output.is_truncated == Some(true) => {
+                            Some((Some(token), None))
+                        }
+                        _ => None,
+                    };
+                    (output.contents.unwrap_or_default(), next)
+                }),
+        )
+    });
+    pages.map(stream::iter_ok).flatten()
+}
+
+/// Downloads the object `key` in parts, computing its checksum as they arrive.
+fn hash_object<C>(
+    client: &C,
+    bucket: String,
+    key: String,
+    config: &ManifestConfig,
+) -> impl Future<Item = ManifestEntry, Error = RusotoError<ManifestError>> + Send
+where
+    C: S3 + Clone + Send + Sync + 'static,
+{
+    let algorithm = config.algorithm;
+    let request = GetObjectRequest {
+        bucket,
+        key: key.clone(),
+        ..Default::default()
+    };
+    start_download(client, request, config.download.clone(), None)
+        .map_err(|e| e.map_service(ManifestError::Download))
+        .and_then(move |(size, e_tag, parts)| {
+            parts
+                .map_err(|e| e.map_service(ManifestError::Download))
+                .fold((algorithm.checksum(), 0), |(mut checksum, read), data| {
+                    checksum.update(&data);
+                    Ok::<_, RusotoError<ManifestError>>((checksum, read + data.len() as u64))
+                })
+                .and_then(move |(checksum, read)| {
+                    if read != size {
+                        return Err(RusotoError::Service(ManifestError::Download(
+                            DownloadError::LengthMismatch {
+                                expected: size,
+                                actual: read,
+                            },
+                        )));
+                    }
+                    Ok(ManifestEntry {
+                        key,
+                        size,
+                        e_tag,
+                        checksum: hex(&checksum.finish()),
+                    })
+                })
+        })
+}
+
+/// Extension methods for writing and verifying integrity manifests of the objects under a
+/// prefix.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::manifest::{ManifestConfig, ManifestExt};
+/// use rusoto_s3::S3Client;
+///
+/// let client = S3Client::new(Region::UsEast1);
+/// client
+///     .write_manifest("my-bucket", "audits/2019/", "audit.manifest", ManifestConfig::default())
+///     .sync()
+///     .unwrap();
+///
+/// // later on
+/// let report = client
+///     .verify_manifest("audit.manifest", ManifestConfig::default())
+///     .sync()
+///     .unwrap();
+/// for object in &report.changed {
+///     println!("{} changed", object.expected.key);
+/// }
+/// ```
+pub trait ManifestExt: S3 + Clone + Send + Sync + Sized + 'static {
+    /// Computes the checksums of the objects of `bucket` whose key starts with `prefix`, in the
+    /// order of their keys, starting after the key `start_after`.
+    ///
+    /// Up to `config.concurrency` objects are downloaded at once. The stream fails if an object
+    /// can't be read, e.g. because it is archived in Glacier.
+    fn object_checksums(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        start_after: Option<&str>,
+        config: ManifestConfig,
+    ) -> ManifestStream {
+        let client = self.clone();
+        let bucket = bucket.to_owned();
+        let concurrency = config.concurrency.max(1);
+        let objects = list_objects(
+            self,
+            bucket.clone(),
+            prefix.to_owned(),
+            start_after.map(str::to_owned),
+        );
+        Box::new(
+            objects
+                .filter_map(|object| object.key)
+                .map(move |key| hash_object(&client, bucket.clone(), key, &config))
+                .buffered(concurrency),
+        )
+    }
+
+    /// Writes the checksums of the objects of `bucket` whose key starts with `prefix` to the
+    /// manifest file at `path`, returning the number of objects written.
+    ///
+    /// Each checksum is written as soon as it and the ones before it are computed. If `path`
+    /// already holds a manifest of the same bucket, prefix and algorithm, e.g. from an
+    /// interrupted run, the objects it lists are skipped and the others are appended to it.
+    fn write_manifest<P: AsRef<Path>>(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        path: P,
+        config: ManifestConfig,
+    ) -> RusotoFuture<usize, ManifestError> {
+        let manifest = Manifest::new(bucket, prefix, config.algorithm);
+        let (file, start_after) = match open_manifest(path.as_ref(), &manifest) {
+            Ok(opened) => opened,
+            Err(e) => return RusotoFuture::from_future(future::err(RusotoError::Service(e))),
+        };
+        let io_error = |e: io::Error| RusotoError::Service(ManifestError::from(e));
+
+        let future = self
+            .object_checksums(
+                bucket,
+                prefix,
+                start_after.as_ref().map(String::as_str),
+                config,
+            )
+            .fold((file, 0), move |(mut file, written), entry| {
+                file.write_all(entry.to_line().as_bytes())
+                    .and_then(|_| file.flush())
+                    .map(|_| (file, written + 1))
+                    .map_err(io_error)
+            })
+            .map(|(_, written)| written);
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Computes the checksums of the objects listed by the manifest file at `path` again, and
+    /// compares them with the manifest.
+    ///
+    /// Every object under the prefix of the manifest is read, so that objects added since it
+    /// was written are reported too. The checksums are computed with the algorithm of the
+    /// manifest, whatever `config.algorithm` is.
+    fn verify_manifest<P: AsRef<Path>>(
+        &self,
+        path: P,
+        config: ManifestConfig,
+    ) -> RusotoFuture<VerificationReport, ManifestError> {
+        let manifest = match Manifest::read(path) {
+            Ok(manifest) => manifest,
+            Err(e) => return RusotoFuture::from_future(future::err(RusotoError::Service(e))),
+        };
+        let config = ManifestConfig {
+            algorithm: manifest.algorithm,
+            ..config
+        };
+
+        let future = self
+            .object_checksums(&manifest.bucket, &manifest.prefix, None, config)
+            .collect()
+            .map(move |actual| VerificationReport::new(manifest, actual));
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: S3 + Clone + Send + Sync + 'static> ManifestExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn entry(key: &str, size: u64, checksum: &str) -> ManifestEntry {
+        ManifestEntry {
+            key: key.to_owned(),
+            size,
+            e_tag: Some("\"5d41402abc4b2a76b9719d911017c592\"".to_owned()),
+            checksum: checksum.to_owned(),
+        }
+    }
+
+    #[test]
+    fn manifests_round_trip() {
+        let mut manifest = Manifest::new("bucket", "odd\tprefix/", ChecksumAlgorithm::Crc32c);
+        manifest
+            .entries
+            .push(entry("odd\tprefix/a\\b\nc", 5, "c9946aaa"));
+        manifest.entries.push(ManifestEntry {
+            e_tag: None,
+            ..entry("odd\tprefix/empty", 0, "00000000")
+        });
+
+        let mut text = Vec::new();
+        manifest.write_to(&mut text).unwrap();
+        let text = String::from_utf8(text).unwrap();
+        assert_eq!(text.lines().count(), 3);
+        assert!(text.ends_with("odd\\tprefix/empty\t0\t-\t00000000\n"));
+        assert_eq!(Manifest::parse(&text).unwrap(), manifest);
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        assert_eq!(
+            Manifest::parse("rusoto-s3-manifest/1\tSHA256\tbucket\t\nkey\t12\n"),
+            Err(ManifestError::Malformed {
+                line: 2,
+                message: "expected 4 fields, found 2".to_owned()
+            })
+        );
+        assert!(Manifest::parse("key\t1\t-\t00\n").is_err());
+    }
+
+    #[test]
+    fn resumes_after_the_last_complete_entry() {
+        let path = env::temp_dir().join("rusoto_s3_manifest_resume");
+        let manifest = Manifest::new("bucket", "logs/", ChecksumAlgorithm::Sha256);
+        let mut text = manifest.header();
+        text.push_str(&entry("logs/a", 1, "aa").to_line());
+        text.push_str("logs/b\t2\t-\tb");
+        fs::write(&path, &text).unwrap();
+
+        let (file, start_after) = open_manifest(&path, &manifest).unwrap();
+        drop(file);
+        let resumed = fs::read_to_string(&path).unwrap();
+        let conflict = open_manifest(
+            &path,
+            &Manifest::new("bucket", "logs/", ChecksumAlgorithm::Md5),
+        );
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(start_after, Some("logs/a".to_owned()));
+        assert!(resumed.ends_with("aa\n"));
+        assert!(match conflict {
+            Err(ManifestError::Conflict(_)) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn verification_reports_differences() {
+        let mut manifest = Manifest::new("bucket", "", ChecksumAlgorithm::Sha256);
+        manifest.entries = vec![
+            entry("a", 1, "aa"),
+            entry("b", 1, "bb"),
+            entry("c", 1, "cc"),
+        ];
+        let actual = vec![
+            entry("a", 1, "AA"),
+            entry("b", 1, "b0"),
+            entry("d", 1, "dd"),
+        ];
+
+        let report = VerificationReport::new(manifest, actual);
+        assert_eq!(report.matched, 1);
+        assert_eq!(report.changed.len(), 1);
+        assert_eq!(report.changed[0].actual.checksum, "b0");
+        assert_eq!(report.missing, vec!["c"]);
+        assert_eq!(report.unexpected, vec!["d"]);
+        assert!(!report.is_verified());
+    }
+}
@@ -1,5 +1,47 @@
 /// Utility helpers for working with S3
 pub mod util;
 
+/// Virtual-hosted, path-style, accelerate and dualstack bucket addressing
+pub mod addressing;
+
+/// Checksums of uploaded and downloaded object data
+pub mod integrity;
+
+/// Downloading large objects as concurrent ranged requests
+pub mod download;
+
+/// Following buckets to their region when S3 redirects requests
+pub mod redirects;
+
+/// Cleaning up incomplete multipart uploads
+pub mod multipart;
+
+/// Writing and verifying checksum manifests of the objects under a prefix
+pub mod manifest;
+
+/// Listing objects while their responses are still being received
+pub mod listing;
+
+/// Walking the prefixes of a bucket like directories, depth first or as a tree
+pub mod walker;
+
+/// Synchronizing a local directory with a prefix of a bucket
+pub mod sync;
+
+/// Listing, restoring and permanently deleting object versions
+pub mod versioning;
+
+/// Client side encryption of S3 objects with KMS managed keys
+#[cfg(feature = "encryption")]
+pub mod encryption;
+
+/// Parsing S3 event notifications
+#[cfg(feature = "events")]
+pub mod events;
+
+/// An in-memory backend for `FakeS3`
+#[cfg(feature = "fake")]
+pub mod in_memory;
+
 #[cfg(test)]
 mod custom_tests;
@@ -0,0 +1,238 @@
+//! Cleaning up incomplete multipart uploads.
+//!
+//! The parts of a multipart upload which is never completed or aborted are stored, and billed,
+//! indefinitely. `abort_incomplete_uploads` finds the uploads of a bucket which were initiated
+//! longer ago than a threshold and aborts them.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::future::{self, Either};
+use futures::{stream, Future, Stream};
+use rusoto_core::timestamp;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    AbortMultipartUploadRequest, ListMultipartUploadsError, ListMultipartUploadsRequest,
+    MultipartUpload, S3,
+};
+
+/// The number of uploads aborted concurrently by `abort_incomplete_uploads`.
+pub const DEFAULT_ABORT_CONCURRENCY: usize = 8;
+
+/// An incomplete multipart upload.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IncompleteUpload {
+    /// Key of the object being uploaded
+    pub key: String,
+    /// ID of the upload
+    pub upload_id: String,
+    /// When the upload was initiated, in ISO 8601 format
+    pub initiated: String,
+}
+
+/// An upload which could not be aborted.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FailedAbort {
+    /// The upload
+    pub upload: IncompleteUpload,
+    /// Why aborting it failed
+    pub error: String,
+}
+
+/// The result of `abort_incomplete_uploads`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AbortIncompleteUploadsReport {
+    /// Uploads which were aborted, freeing their parts
+    pub aborted: Vec<IncompleteUpload>,
+    /// Uploads older than the threshold which could not be aborted
+    pub failed: Vec<FailedAbort>,
+    /// Number of uploads left alone because they are more recent than the threshold
+    pub kept: usize,
+}
+
+enum UploadOutcome {
+    Aborted(IncompleteUpload),
+    Failed(FailedAbort),
+    Kept,
+}
+
+/// Days between 1970-01-01 and the given date of the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Parses an S3 timestamp such as `2019-10-12T17:50:30.000Z`, ignoring fractional seconds.
+pub(crate) fn parse_timestamp(timestamp: &str) -> Option<SystemTime> {
+    let field = |range: std::ops::Range<usize>| -> Option<i64> {
+        timestamp.get(range).and_then(|s| s.parse().ok())
+    };
+    let days = days_from_civil(field(0..4)?, field(5..7)?, field(8..10)?);
+    let seconds = days * 86_400 + field(11..13)? * 3_600 + field(14..16)? * 60 + field(17..19)?;
+    if seconds < 0 {
+        return None;
+    }
+    Some(UNIX_EPOCH + Duration::from_secs(seconds as u64))
+}
+
+/// Extension methods for cleaning up incomplete multipart uploads.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use rusoto_core::Region;
+/// use rusoto_s3::multipart::MultipartCleanupExt;
+/// use rusoto_s3::S3Client;
+///
+/// let client = S3Client::new(Region::UsEast1);
+/// let week = Duration::from_secs(7 * 24 * 60 * 60);
+/// let report = client
+///     .abort_incomplete_uploads("my-bucket", week)
+///     .sync()
+///     .unwrap();
+/// println!("aborted {} uploads", report.aborted.len());
+/// ```
+pub trait MultipartCleanupExt: S3 + Clone + Send + Sync + Sized + 'static {
+    /// Aborts every multipart upload of `bucket` initiated more than `older_than` ago.
+    ///
+    /// Uploads are listed page by page and aborted `DEFAULT_ABORT_CONCURRENCY` at a time.
+    fn abort_incomplete_uploads(
+        &self,
+        bucket: &str,
+        older_than: Duration,
+    ) -> RusotoFuture<AbortIncompleteUploadsReport, ListMultipartUploadsError> {
+        self.abort_incomplete_uploads_with_concurrency(
+            bucket,
+            older_than,
+            DEFAULT_ABORT_CONCURRENCY,
+        )
+    }
+
+    /// Aborts every multipart upload of `bucket` initiated more than `older_than` ago, with up
+    /// to `concurrency` abort requests in flight.
+    ///
+    /// The returned future only fails if the uploads can't be listed. Uploads which can't be
+    /// aborted, for instance because they were completed in the meantime, are reported in
+    /// `AbortIncompleteUploadsReport::failed`. Uploads with a missing or unparseable initiation
+    /// date are kept.
+    fn abort_incomplete_uploads_with_concurrency(
+        &self,
+        bucket: &str,
+        older_than: Duration,
+        concurrency: usize,
+    ) -> RusotoFuture<AbortIncompleteUploadsReport, ListMultipartUploadsError> {
+        let cutoff = SystemTime::now()
+            .checked_sub(older_than)
+            .unwrap_or(UNIX_EPOCH);
+        let list_client = self.clone();
+        let abort_client = self.clone();
+        let list_bucket = bucket.to_owned();
+        let abort_bucket = bucket.to_owned();
+
+        let pages = stream::unfold(Some((None, None)), move |markers| {
+            let (key_marker, upload_id_marker) = markers?;
+            let request = ListMultipartUploadsRequest {
+                bucket: list_bucket.clone(),
+                key_marker,
+                upload_id_marker,
+                ..Default::default()
+            };
+            Some(list_client.list_multipart_uploads(request).map(|output| {
+                let next = match output.next_key_marker {
+                    Some(key_marker) if output.is_truncated == Some(true) => {
+                        Some((Some(key_marker), output.next_upload_id_marker))
+                    }
+                    _ => None,
+                };
+                (output.uploads.unwrap_or_default(), next)
+            }))
+        });
+
+        let future = pages
+            .map(stream::iter_ok::<_, RusotoError<ListMultipartUploadsError>>)
+            .flatten()
+            .map(move |upload: MultipartUpload| {
+                let initiated = upload
+                    .initiated
+                    .as_ref()
+                    .map(timestamp::to_iso8601)
+                    .unwrap_or_default();
+                let expired = parse_timestamp(&initiated).map_or(false, |time| time < cutoff);
+                if !expired {
+                    return Either::A(future::ok(UploadOutcome::Kept));
+                }
+                let upload = match (upload.key, upload.upload_id) {
+                    (Some(key), Some(upload_id)) => IncompleteUpload {
+                        key,
+                        upload_id,
+                        initiated,
+                    },
+                    _ => return Either::A(future::ok(UploadOutcome::Kept)),
+                };
+
+                let request = AbortMultipartUploadRequest {
+                    bucket: abort_bucket.clone(),
+                    key: upload.key.clone(),
+                    upload_id: upload.upload_id.clone(),
+                    ..Default::default()
+                };
+                Either::B(
+                    abort_client
+                        .abort_multipart_upload(request)
+                        .then(move |result| {
+                            Ok::<_, RusotoError<ListMultipartUploadsError>>(match result {
+                                Ok(_) => UploadOutcome::Aborted(upload),
+                                Err(error) => UploadOutcome::Failed(FailedAbort {
+                                    upload,
+                                    error: error.to_string(),
+                                }),
+                            })
+                        }),
+                )
+            })
+            .buffer_unordered(concurrency.max(1))
+            .fold(
+                AbortIncompleteUploadsReport::default(),
+                |mut report, outcome| {
+                    match outcome {
+                        UploadOutcome::Aborted(upload) => report.aborted.push(upload),
+                        UploadOutcome::Failed(failure) => report.failed.push(failure),
+                        UploadOutcome::Kept => report.kept += 1,
+                    }
+                    Ok::<_, RusotoError<ListMultipartUploadsError>>(report)
+                },
+            );
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: S3 + Clone + Send + Sync + 'static> MultipartCleanupExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_s3_timestamps() {
+        assert_eq!(
+            parse_timestamp("1970-01-01T00:00:00.000Z"),
+            Some(UNIX_EPOCH)
+        );
+        assert_eq!(
+            parse_timestamp("2019-10-12T17:50:30.000Z"),
+            Some(UNIX_EPOCH + Duration::from_secs(1_570_902_630))
+        );
+        assert_eq!(
+            parse_timestamp("2000-02-29T12:00:00Z"),
+            Some(UNIX_EPOCH + Duration::from_secs(951_825_600))
+        );
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+}
@@ -0,0 +1,240 @@
+//! Following buckets to their region.
+//!
+//! S3 answers requests sent to another region than their bucket's with an error, such as
+//! `301 Moved Permanently`, naming the region of the bucket in the `x-amz-bucket-region` header.
+//! Clients created with `S3Client::new_with_region_redirects` send such requests again to that
+//! region, signed for it, and remember the region of the bucket in a `BucketRegionCache`, so
+//! later requests for the bucket are sent to its region directly.
+//!
+//! Requests with a streaming body, such as `PutObject`, can't be sent again. Sending one to the
+//! wrong region still fails, but sending it again once the region of its bucket is known doesn't.
+//! Requests for a custom region, or sent to a transfer acceleration endpoint, aren't redirected.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use rusoto_core::request::HttpResponse;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{Client, Region};
+
+use crate::custom::addressing::split_path;
+use crate::generated::S3Client;
+
+/// The header S3 names the region of a bucket in.
+const BUCKET_REGION_HEADER: &str = "x-amz-bucket-region";
+
+/// The regions of buckets, learnt from redirects.
+///
+/// Clones share the same cache, so a cache can be shared by several clients.
+#[derive(Clone, Debug, Default)]
+pub struct BucketRegionCache {
+    regions: Arc<Mutex<HashMap<String, Region>>>,
+}
+
+impl BucketRegionCache {
+    /// Creates an empty cache.
+    pub fn new() -> BucketRegionCache {
+        BucketRegionCache::default()
+    }
+
+    /// The region of `bucket`, if it is known.
+    pub fn get(&self, bucket: &str) -> Option<Region> {
+        self.regions().get(bucket).cloned()
+    }
+
+    /// Records the region of `bucket`, e.g. to send its first request to the right region.
+    pub fn insert<B>(&self, bucket: B, region: Region)
+    where
+        B: Into<String>,
+    {
+        self.regions().insert(bucket.into(), region);
+    }
+
+    /// Forgets the region of `bucket`, e.g. after deleting it.
+    pub fn remove(&self, bucket: &str) -> Option<Region> {
+        self.regions().remove(bucket)
+    }
+
+    /// Adds the interceptors following redirects and recording them in this cache to `client`.
+    ///
+    /// Use this to combine redirects with other options, such as `S3Addressing`. Addressing
+    /// should be added first, so requests are addressed before they are routed.
+    pub fn follow_redirects(&self, client: Client) -> Client {
+        let routing = self.clone();
+        let redirects = self.clone();
+        client
+            .with_unsigned_request_interceptor(move |request| routing.route(request))
+            .with_redirect_handler(move |response, request| redirects.redirect(response, request))
+    }
+
+    /// Sends a request for a bucket with a known region to that region.
+    pub fn route(&self, request: &mut SignedRequest) {
+        let bucket = match bucket_of(request) {
+            Some(bucket) => bucket,
+            None => return,
+        };
+        if let Some(region) = self.get(&bucket) {
+            if region != request.region {
+                send_to_region(request, &bucket, region);
+            }
+        }
+    }
+
+    /// Sends a request to the region named in an error response, and records the region of
+    /// its bucket. Returns whether the request was changed.
+    pub fn redirect(&self, response: &HttpResponse, request: &mut SignedRequest) -> bool {
+        match response.headers.get(BUCKET_REGION_HEADER) {
+            Some(region) => self.redirect_to(region, request),
+            None => false,
+        }
+    }
+
+    fn redirect_to(&self, region: &str, request: &mut SignedRequest) -> bool {
+        let region = match region.parse::<Region>() {
+            Ok(region) => region,
+            Err(_) => return false,
+        };
+        let bucket = match bucket_of(request) {
+            Some(bucket) => bucket,
+            None => return false,
+        };
+        if region == request.region || !can_redirect(request) {
+            return false;
+        }
+        self.insert(bucket.clone(), region.clone());
+        send_to_region(request, &bucket, region)
+    }
+
+    fn regions(&self) -> MutexGuard<'_, HashMap<String, Region>> {
+        self.regions
+            .lock()
+            .expect("BucketRegionCache lock poisoned")
+    }
+}
+
+impl S3Client {
+    /// Creates a client sending requests again to the region of their bucket when S3 names
+    /// it in a redirect.
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::{Client, Region};
+    /// use rusoto_s3::S3Client;
+    ///
+    /// let client = S3Client::new_with_region_redirects(Client::shared(), Region::UsEast1);
+    /// ```
+    pub fn new_with_region_redirects(client: Client, region: Region) -> S3Client {
+        let client = BucketRegionCache::new().follow_redirects(client);
+        S3Client::new_with_client(client, region)
+    }
+}
+
+/// The bucket a request is for, named in its host or path.
+fn bucket_of(request: &SignedRequest) -> Option<String> {
+    if let Some(ref host) = request.hostname {
+        if let Some(end) = host.rfind(".s3.").or_else(|| host.rfind(".s3-")) {
+            return Some(host[..end].to_owned());
+        }
+    }
+    split_path(&request.path).0
+}
+
+/// Changes the region of a request for `bucket`, and its host unless it is derived from the
+/// region. Returns whether the request was changed.
+fn send_to_region(request: &mut SignedRequest, bucket: &str, region: Region) -> bool {
+    if !can_redirect(request) {
+        return false;
+    }
+    if let Some(host) = request.hostname.take() {
+        let suffix = region.partition().dns_suffix();
+        let endpoint = if host.contains(".dualstack.") {
+            format!("s3.dualstack.{}.{}", region.name(), suffix)
+        } else {
+            SignedRequest::new(&request.method, "s3", &region, "/").hostname()
+        };
+        if host.starts_with(&format!("{}.", bucket)) {
+            request.set_hostname(Some(format!("{}.{}", bucket, endpoint)));
+        } else {
+            request.set_hostname(Some(endpoint));
+        }
+    }
+    request.region = region;
+    true
+}
+
+/// Whether a request is sent to a regional AWS endpoint.
+fn can_redirect(request: &SignedRequest) -> bool {
+    let custom = match request.region {
+        Region::Custom { .. } => true,
+        _ => false,
+    };
+    let accelerated = request
+        .hostname
+        .as_ref()
+        .map_or(false, |host| host.contains("s3-accelerate"));
+    !custom && !accelerated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redirects_path_style_requests() {
+        let cache = BucketRegionCache::new();
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+
+        assert!(cache.redirect_to("eu-west-1", &mut request));
+        assert_eq!(request.region, Region::EuWest1);
+        assert_eq!(request.hostname(), "s3-eu-west-1.amazonaws.com");
+        assert_eq!(request.path(), "/bucket/key");
+        assert_eq!(cache.get("bucket"), Some(Region::EuWest1));
+
+        // the request is already in the bucket's region
+        assert!(!cache.redirect_to("eu-west-1", &mut request));
+    }
+
+    #[test]
+    fn redirects_virtual_hosted_requests() {
+        let cache = BucketRegionCache::new();
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/key");
+        request.set_hostname(Some(
+            "bucket.s3.dualstack.us-east-1.amazonaws.com".to_owned(),
+        ));
+
+        assert!(cache.redirect_to("ap-southeast-2", &mut request));
+        assert_eq!(
+            request.hostname(),
+            "bucket.s3.dualstack.ap-southeast-2.amazonaws.com"
+        );
+        assert_eq!(cache.get("bucket"), Some(Region::ApSoutheast2));
+    }
+
+    #[test]
+    fn routes_requests_for_known_buckets() {
+        let cache = BucketRegionCache::new();
+        cache.insert("bucket", Region::UsWest2);
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        cache.route(&mut request);
+        assert_eq!(request.region, Region::UsWest2);
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/other/key");
+        cache.route(&mut request);
+        assert_eq!(request.region, Region::UsEast1);
+    }
+
+    #[test]
+    fn ignores_custom_regions_and_unknown_regions() {
+        let cache = BucketRegionCache::new();
+        let region = Region::Custom {
+            name: "minio".to_owned(),
+            endpoint: "http://localhost:9000".to_owned(),
+        };
+        let mut request = SignedRequest::new("GET", "s3", &region, "/bucket/key");
+        assert!(!cache.redirect_to("eu-west-1", &mut request));
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        assert!(!cache.redirect_to("not-a-region", &mut request));
+        assert_eq!(cache.get("bucket"), None);
+    }
+}
@@ -0,0 +1,831 @@
+//! Synchronizing a local directory with a prefix of a bucket, like `aws s3 sync`.
+//!
+//! `SyncExt::sync_up` uploads the files of a directory which are missing from the bucket or
+//! differ from their object, and `SyncExt::sync_down` downloads the objects which are missing
+//! from the directory or differ from their file. Files are matched with the objects whose key
+//! is the prefix followed by their path relative to the directory, with `/` separators.
+//!
+//! Whether a file and its object differ is decided by `SyncConfig::comparison`. The default,
+//! like the AWS CLI, compares their sizes and modification times: a file is uploaded if it was
+//! modified after its object, and an object is downloaded if it was modified after its file.
+//! Downloaded files can't be given the modification time of their object, so the time they
+//! were written at is compared on the next run.
+//!
+//! Transfers run concurrently, up to `SyncConfig::concurrency` at a time. A transfer which
+//! fails is reported in `SyncReport::failed` without stopping the others, and the progress
+//! callback is called as each one finishes.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use bytes::Bytes;
+use futures::future;
+use futures::{stream, Async, Future, Stream};
+use rusoto_core::signature::ChecksumAlgorithm;
+use rusoto_core::{timestamp, ByteStream, RusotoError, RusotoFuture};
+
+use crate::custom::download::{DownloadConfig, DownloadExt};
+use crate::custom::integrity::hex;
+use crate::custom::multipart::parse_timestamp;
+use crate::generated::{
+    DeleteObjectRequest, GetObjectRequest, ListObjectsV2Error, ListObjectsV2Request,
+    PutObjectRequest, S3,
+};
+
+/// The number of files transferred concurrently by default.
+pub const DEFAULT_SYNC_CONCURRENCY: usize = 4;
+
+/// The size of the chunks files are read in when they are uploaded.
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// How files and objects are compared to decide whether to transfer them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SyncComparison {
+    /// Transfer when the sizes differ.
+    Size,
+    /// Transfer when the sizes differ or the source was modified after the destination.
+    SizeAndModified,
+    /// Transfer when the sizes differ or the MD5 digest of the file doesn't match the ETag of
+    /// the object. Objects whose ETag isn't a digest of their data, e.g. those uploaded in
+    /// multiple parts, are compared by size and modification time instead.
+    Checksum,
+}
+
+/// How a directory and a prefix are synchronized.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyncConfig {
+    /// How files and objects are compared
+    pub comparison: SyncComparison,
+    /// Whether to delete the objects (when uploading) or files (when downloading) which have no
+    /// counterpart in the source
+    pub delete: bool,
+    /// Number of files transferred or deleted concurrently
+    pub concurrency: usize,
+    /// How each object is downloaded by `sync_down`
+    pub download: DownloadConfig,
+}
+
+impl Default for SyncConfig {
+    fn default() -> SyncConfig {
+        SyncConfig {
+            comparison: SyncComparison::SizeAndModified,
+            delete: false,
+            concurrency: DEFAULT_SYNC_CONCURRENCY,
+            download: DownloadConfig::default(),
+        }
+    }
+}
+
+/// What was done to synchronize a file and its object.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SyncAction {
+    /// The file was uploaded.
+    Upload,
+    /// The object was downloaded.
+    Download,
+    /// The object was deleted from the bucket.
+    DeleteObject,
+    /// The file was deleted from the directory.
+    DeleteFile,
+}
+
+/// A transfer or deletion which finished, passed to the progress callback.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyncProgress {
+    /// What was done
+    pub action: SyncAction,
+    /// Key of the object
+    pub key: String,
+    /// Path of the file
+    pub path: PathBuf,
+    /// Number of bytes transferred, 0 for deletions
+    pub bytes: u64,
+    /// Why it failed, if it did
+    pub error: Option<String>,
+    /// Number of transfers and deletions finished so far, including this one
+    pub completed: usize,
+    /// Number of transfers and deletions to do in total
+    pub total: usize,
+}
+
+/// A transfer or deletion which failed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyncFailure {
+    /// What was attempted
+    pub action: SyncAction,
+    /// Key of the object
+    pub key: String,
+    /// Why it failed
+    pub error: String,
+}
+
+/// The result of `SyncExt::sync_up` and `SyncExt::sync_down`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SyncReport {
+    /// Keys of the objects uploaded
+    pub uploaded: Vec<String>,
+    /// Keys of the objects downloaded
+    pub downloaded: Vec<String>,
+    /// Keys of the objects, or of the files, deleted
+    pub deleted: Vec<String>,
+    /// Transfers and deletions which failed
+    pub failed: Vec<SyncFailure>,
+    /// Number of files left alone because they match their object
+    pub unchanged: usize,
+    /// Number of bytes uploaded and downloaded
+    pub bytes: u64,
+}
+
+impl SyncReport {
+    /// Whether every transfer and deletion succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Errors which stop `SyncExt::sync_up` and `SyncExt::sync_down` before anything is
+/// transferred.
+#[derive(Debug, PartialEq)]
+pub enum SyncError {
+    /// Listing the objects under the prefix failed.
+    ListObjects(ListObjectsV2Error),
+    /// Reading the local directory failed.
+    Directory(String),
+}
+
+impl fmt::Display for SyncError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SyncError::Directory(ref cause) => write!(f, "{}: {}", self.description(), cause),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for SyncError {
+    fn description(&self) -> &str {
+        match *self {
+            SyncError::ListObjects(ref cause) => cause.description(),
+            SyncError::Directory(_) => "Reading the local directory failed",
+        }
+    }
+}
+
+/// A file of the local directory.
+#[derive(Clone, Debug, PartialEq)]
+struct LocalFile {
+    path: PathBuf,
+    size: u64,
+    modified: Option<SystemTime>,
+}
+
+/// An object under the prefix.
+#[derive(Clone, Debug, PartialEq)]
+struct RemoteObject {
+    size: u64,
+    e_tag: Option<String>,
+    modified: Option<SystemTime>,
+}
+
+/// Which way files are synchronized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Direction {
+    Up,
+    Down,
+}
+
+/// A transfer or deletion to do.
+#[derive(Clone, Debug, PartialEq)]
+struct Task {
+    action: SyncAction,
+    key: String,
+    path: PathBuf,
+    size: u64,
+}
+
+/// Lists the files under `dir` recursively, by the key of their object.
+fn local_files(
+    dir: &Path,
+    prefix: &str,
+    files: &mut BTreeMap<String, LocalFile>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let metadata = entry.metadata()?;
+        let name = entry.file_name().into_string().map_err(|name| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{:?} is not valid UTF-8", name),
+            )
+        })?;
+        let key = format!("{}{}", prefix, name);
+        if metadata.is_dir() {
+            local_files(&path, &format!("{}/", key), files)?;
+        } else if metadata.is_file() {
+            files.insert(
+                key,
+                LocalFile {
+                    path,
+                    size: metadata.len(),
+                    modified: metadata.modified().ok(),
+                },
+            );
+        }
+    }
+    Ok(())
+}
+
+/// The path of the file of `key` in `dir`, unless the key can't be mapped to a path inside
+/// `dir`, e.g. because it contains `..`.
+fn local_path(dir: &Path, prefix: &str, key: &str) -> Option<PathBuf> {
+    let relative = key.get(prefix.len()..)?;
+    let mut path = dir.to_owned();
+    for component in relative.split('/') {
+        if component.is_empty() || component == "." || component == ".." {
+            return None;
+        }
+        path.push(component);
+    }
+    Some(path)
+}
+
+/// Lists the objects of `bucket` under `prefix`, by key.
+fn remote_objects<C>(
+    client: &C,
+    bucket: String,
+    prefix: String,
+) -> impl Future<Item = BTreeMap<String, RemoteObject>, Error = RusotoError<SyncError>> + Send
+where
+    C: S3 + Clone + Send + Sync + 'static,
+{
+    let client = client.clone();
+    let pages = stream::unfold(Some(None), move |continuation_token| {
+        let request = ListObjectsV2Request {
+            bucket: bucket.clone(),
+            prefix: Some(prefix.clone()),
+            continuation_token: continuation_token?,
+            ..Default::default()
+        };
+        Some(
+            client
+                .list_objects_v2(request)
+                .map_err(|e| e.map_service(SyncError::ListObjects))
+                .map(|output| {
+                    let next = match output.next_continuation_token {
+                        Some(token) if output.is_truncated == Some(true) => Some(Some(token)),
+                        _ => None,
+                    };
+                    (output.contents.unwrap_or_default(), next)
+                }),
+        )
+    });
+    pages
+        .map(stream::iter_ok)
+        .flatten()
+        .fold(BTreeMap::new(), |mut objects, object| {
+            if let Some(key) = object.key {
+                let modified = object
+                    .last_modified
+                    .as_ref()
+                    .and_then(|modified| parse_timestamp(&timestamp::to_iso8601(modified)));
+                objects.insert(
+                    key,
+                    RemoteObject {
+                        size: object.size.unwrap_or(0).max(0) as u64,
+                        e_tag: object.e_tag,
+                        modified,
+                    },
+                );
+            }
+            Ok::<_, RusotoError<SyncError>>(objects)
+        })
+}
+
+/// The MD5 digest of the file at `path`, hex encoded.
+fn file_md5(path: &Path) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut checksum = ChecksumAlgorithm::Md5.checksum();
+    let mut buffer = vec![0; READ_CHUNK_SIZE];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            return Ok(hex(&checksum.finish()));
+        }
+        checksum.update(&buffer[..read]);
+    }
+}
+
+/// Whether `file` and `object` differ, and the source should be transferred.
+fn differs(
+    file: &LocalFile,
+    object: &RemoteObject,
+    direction: Direction,
+    comparison: SyncComparison,
+) -> bool {
+    if file.size != object.size {
+        return true;
+    }
+    let modified_differs = || match (file.modified, object.modified) {
+        (Some(file), Some(object)) => match direction {
+            Direction::Up => file > object,
+            Direction::Down => object > file,
+        },
+        _ => false,
+    };
+    match comparison {
+        SyncComparison::Size => false,
+        SyncComparison::SizeAndModified => modified_differs(),
+        SyncComparison::Checksum => {
+            let e_tag = object
+                .e_tag
+                .as_ref()
+                .map_or("", |e_tag| e_tag.trim_matches('"'));
+            if e_tag.len() != 32 || !e_tag.chars().all(|c| c.is_ascii_hexdigit()) {
+                return modified_differs();
+            }
+            // a file which can't be read fails when it is transferred
+            file_md5(&file.path).map_or(true, |md5| !md5.eq_ignore_ascii_case(e_tag))
+        }
+    }
+}
+
+/// The transfers and deletions synchronizing `files` and `objects`, and the number of files
+/// which are already synchronized.
+fn plan(
+    dir: &Path,
+    prefix: &str,
+    files: &BTreeMap<String, LocalFile>,
+    objects: &BTreeMap<String, RemoteObject>,
+    direction: Direction,
+    config: &SyncConfig,
+) -> (Vec<Task>, usize) {
+    let mut tasks = Vec::new();
+    let mut unchanged = 0;
+    match direction {
+        Direction::Up => {
+            for (key, file) in files {
+                match objects.get(key) {
+                    Some(object) if !differs(file, object, direction, config.comparison) => {
+                        unchanged += 1
+                    }
+                    _ => tasks.push(Task {
+                        action: SyncAction::Upload,
+                        key: key.clone(),
+                        path: file.path.clone(),
+                        size: file.size,
+                    }),
+                }
+            }
+            if config.delete {
+                for key in objects.keys().filter(|key| !files.contains_key(*key)) {
+                    tasks.push(Task {
+                        action: SyncAction::DeleteObject,
+                        key: key.clone(),
+                        path: local_path(dir, prefix, key).unwrap_or_default(),
+                        size: 0,
+                    });
+                }
+            }
+        }
+        Direction::Down => {
+            for (key, object) in objects {
+                // keys such as the folder markers of the S3 console have no file
+                let path = match local_path(dir, prefix, key) {
+                    Some(path) => path,
+                    None => continue,
+                };
+                match files.get(key) {
+                    Some(file) if !differs(file, object, direction, config.comparison) => {
+                        unchanged += 1
+                    }
+                    _ => tasks.push(Task {
+                        action: SyncAction::Download,
+                        key: key.clone(),
+                        path,
+                        size: object.size,
+                    }),
+                }
+            }
+            if config.delete {
+                for (key, file) in files.iter().filter(|&(key, _)| !objects.contains_key(key)) {
+                    tasks.push(Task {
+                        action: SyncAction::DeleteFile,
+                        key: key.clone(),
+                        path: file.path.clone(),
+                        size: 0,
+                    });
+                }
+            }
+        }
+    }
+    (tasks, unchanged)
+}
+
+/// The data of the file at `path`, read in chunks as it is sent.
+fn file_body(path: &Path) -> io::Result<ByteStream> {
+    let mut file = File::open(path)?;
+    let chunks = stream::poll_fn(move || {
+        let mut buffer = vec![0; READ_CHUNK_SIZE];
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            return Ok(Async::Ready(None));
+        }
+        buffer.truncate(read);
+        Ok(Async::Ready(Some(Bytes::from(buffer))))
+    });
+    Ok(ByteStream::new(chunks))
+}
+
+/// Does `task`, returning the number of bytes transferred.
+fn run_task<C>(
+    client: &C,
+    bucket: &str,
+    task: &Task,
+    download: &DownloadConfig,
+) -> Box<dyn Future<Item = u64, Error = String> + Send>
+where
+    C: S3 + Clone + Send + Sync + 'static,
+{
+    let size = task.size;
+    match task.action {
+        SyncAction::Upload => {
+            let body = match file_body(&task.path) {
+                Ok(body) => body,
+                Err(e) => return Box::new(future::err(e.to_string())),
+            };
+            let request = PutObjectRequest {
+                bucket: bucket.to_owned(),
+                key: task.key.clone(),
+                body: Some(body),
+                content_length: Some(size as i64),
+                ..Default::default()
+            };
+            Box::new(
+                client
+                    .put_object(request)
+                    .map(move |_| size)
+                    .map_err(|e| e.to_string()),
+            )
+        }
+        SyncAction::Download => {
+            let path = task.path.clone();
+            // download next to the file, so that a failed download leaves it as it was
+            let partial = path.with_file_name(format!(
+                ".{}.rusoto-sync",
+                path.file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default()
+            ));
+            if let Some(parent) = path.parent() {
+                if let Err(e) = fs::create_dir_all(parent) {
+                    return Box::new(future::err(e.to_string()));
+                }
+            }
+            let request = GetObjectRequest {
+                bucket: bucket.to_owned(),
+                key: task.key.clone(),
+                ..Default::default()
+            };
+            Box::new(
+                client
+                    .download_file(request, partial.clone(), download.clone())
+                    .map_err(|e| e.to_string())
+                    .and_then(move |object| {
+                        fs::rename(&partial, &path)
+                            .map(|_| object.size)
+                            .map_err(|e| e.to_string())
+                    }),
+            )
+        }
+        SyncAction::DeleteObject => {
+            let request = DeleteObjectRequest {
+                bucket: bucket.to_owned(),
+                key: task.key.clone(),
+                ..Default::default()
+            };
+            Box::new(
+                client
+                    .delete_object(request)
+                    .map(|_| 0)
+                    .map_err(|e| e.to_string()),
+            )
+        }
+        SyncAction::DeleteFile => Box::new(future::result(
+            fs::remove_file(&task.path)
+                .map(|_| 0)
+                .map_err(|e| e.to_string()),
+        )),
+    }
+}
+
+/// Lists both sides, then does the transfers and deletions synchronizing them.
+fn synchronize<C, F>(
+    client: &C,
+    dir: &Path,
+    bucket: &str,
+    prefix: &str,
+    direction: Direction,
+    config: SyncConfig,
+    progress: F,
+) -> RusotoFuture<SyncReport, SyncError>
+where
+    C: S3 + Clone + Send + Sync + 'static,
+    F: Fn(&SyncProgress) + Send + Sync + 'static,
+{
+    let mut files = BTreeMap::new();
+    match local_files(dir, prefix, &mut files) {
+        Ok(()) => {}
+        // a missing directory is created by the first download
+        Err(ref e) if direction == Direction::Down && e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => {
+            return RusotoFuture::from_future(future::err(RusotoError::Service(
+                SyncError::Directory(e.to_string()),
+            )))
+        }
+    }
+
+    let client = client.clone();
+    let dir = dir.to_owned();
+    let bucket = bucket.to_owned();
+    let prefix = prefix.to_owned();
+    let future = remote_objects(&client, bucket.clone(), prefix.clone()).and_then(move |objects| {
+        let (tasks, unchanged) = plan(&dir, &prefix, &files, &objects, direction, &config);
+        let total = tasks.len();
+        let concurrency = config.concurrency.max(1);
+        let download = config.download;
+        stream::iter_ok(tasks)
+            .map(move |task| {
+                run_task(&client, &bucket, &task, &download)
+                    .then(move |result| Ok::<_, RusotoError<SyncError>>((task, result)))
+            })
+            .buffer_unordered(concurrency)
+            .fold(
+                SyncReport {
+                    unchanged,
+                    ..Default::default()
+                },
+                move |mut report, (task, result): (Task, Result<u64, String>)| {
+                    let completed = report.uploaded.len()
+                        + report.downloaded.len()
+                        + report.deleted.len()
+                        + report.failed.len()
+                        + 1;
+                    let (bytes, error) = match result {
+                        Ok(bytes) => (bytes, None),
+                        Err(error) => (0, Some(error)),
+                    };
+                    progress(&SyncProgress {
+                        action: task.action,
+                        key: task.key.clone(),
+                        path: task.path,
+                        bytes,
+                        error: error.clone(),
+                        completed,
+                        total,
+                    });
+                    match error {
+                        Some(error) => report.failed.push(SyncFailure {
+                            action: task.action,
+                            key: task.key,
+                            error,
+                        }),
+                        None => {
+                            report.bytes += bytes;
+                            match task.action {
+                                SyncAction::Upload => report.uploaded.push(task.key),
+                                SyncAction::Download => report.downloaded.push(task.key),
+                                SyncAction::DeleteObject | SyncAction::DeleteFile => {
+                                    report.deleted.push(task.key)
+                                }
+                            }
+                        }
+                    }
+                    Ok::<_, RusotoError<SyncError>>(report)
+                },
+            )
+    });
+    RusotoFuture::from_future(future)
+}
+
+/// Extension methods for synchronizing a local directory with a prefix of a bucket.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::sync::{SyncConfig, SyncExt};
+/// use rusoto_s3::S3Client;
+///
+/// let client = S3Client::new(Region::UsEast1);
+/// let config = SyncConfig {
+///     delete: true,
+///     ..Default::default()
+/// };
+/// let report = client
+///     .sync_up("site/", "my-bucket", "www/", config, |progress| {
+///         println!("{}/{} {}", progress.completed, progress.total, progress.key)
+///     })
+///     .sync()
+///     .unwrap();
+/// println!("uploaded {} files, {} unchanged", report.uploaded.len(), report.unchanged);
+/// ```
+pub trait SyncExt: S3 + Clone + Send + Sync + Sized + 'static {
+    /// Uploads the files under `dir` which are missing from `bucket` or differ from their
+    /// object, as the objects whose key is `prefix` followed by their path relative to `dir`.
+    ///
+    /// With `config.delete`, the objects under `prefix` which have no file are deleted.
+    /// `prefix` should be empty or end with `/`. Each file is uploaded with a single `PutObject`
+    /// request, so files larger than 5 GB can't be uploaded.
+    fn sync_up<P, F>(
+        &self,
+        dir: P,
+        bucket: &str,
+        prefix: &str,
+        config: SyncConfig,
+        progress: F,
+    ) -> RusotoFuture<SyncReport, SyncError>
+    where
+        P: AsRef<Path>,
+        F: Fn(&SyncProgress) + Send + Sync + 'static,
+    {
+        synchronize(
+            self,
+            dir.as_ref(),
+            bucket,
+            prefix,
+            Direction::Up,
+            config,
+            progress,
+        )
+    }
+
+    /// Downloads the objects of `bucket` under `prefix` which are missing from `dir` or differ
+    /// from their file, to the path of their key after `prefix`, creating directories as
+    /// needed.
+    ///
+    /// With `config.delete`, the files under `dir` which have no object are deleted. Objects
+    /// whose key can't be a path under `dir`, because it contains empty, `.` or `..`
+    /// components, are skipped.
+    fn sync_down<P, F>(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        dir: P,
+        config: SyncConfig,
+        progress: F,
+    ) -> RusotoFuture<SyncReport, SyncError>
+    where
+        P: AsRef<Path>,
+        F: Fn(&SyncProgress) + Send + Sync + 'static,
+    {
+        synchronize(
+            self,
+            dir.as_ref(),
+            bucket,
+            prefix,
+            Direction::Down,
+            config,
+            progress,
+        )
+    }
+}
+
+impl<T: S3 + Clone + Send + Sync + 'static> SyncExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn file(size: u64, modified: u64) -> LocalFile {
+        LocalFile {
+            path: PathBuf::from("unused"),
+            size,
+            modified: Some(UNIX_EPOCH + Duration::from_secs(modified)),
+        }
+    }
+
+    fn object(size: u64, modified: u64) -> RemoteObject {
+        RemoteObject {
+            size,
+            e_tag: Some("\"multipart-2\"".to_owned()),
+            modified: Some(UNIX_EPOCH + Duration::from_secs(modified)),
+        }
+    }
+
+    #[test]
+    fn compares_sizes_and_modification_times() {
+        let comparison = SyncComparison::SizeAndModified;
+        assert!(differs(
+            &file(1, 10),
+            &object(2, 10),
+            Direction::Up,
+            comparison
+        ));
+        assert!(differs(
+            &file(1, 20),
+            &object(1, 10),
+            Direction::Up,
+            comparison
+        ));
+        assert!(!differs(
+            &file(1, 10),
+            &object(1, 20),
+            Direction::Up,
+            comparison
+        ));
+        assert!(differs(
+            &file(1, 10),
+            &object(1, 20),
+            Direction::Down,
+            comparison
+        ));
+        assert!(!differs(
+            &file(1, 20),
+            &object(1, 10),
+            Direction::Down,
+            comparison
+        ));
+        assert!(!differs(
+            &file(1, 20),
+            &object(1, 10),
+            Direction::Up,
+            SyncComparison::Size
+        ));
+        // ETags of multipart uploads aren't digests, so times are compared instead
+        assert!(!differs(
+            &file(1, 10),
+            &object(1, 20),
+            Direction::Up,
+            SyncComparison::Checksum
+        ));
+    }
+
+    #[test]
+    fn maps_keys_to_paths_inside_the_directory() {
+        let dir = Path::new("backup");
+        assert_eq!(
+            local_path(dir, "www/", "www/css/site.css"),
+            Some(dir.join("css").join("site.css"))
+        );
+        assert_eq!(local_path(dir, "www/", "www/css/"), None);
+        assert_eq!(local_path(dir, "www/", "www/../etc/passwd"), None);
+        assert_eq!(local_path(dir, "www/", "www//a"), None);
+    }
+
+    #[test]
+    fn plans_transfers_and_deletions() {
+        let mut files = BTreeMap::new();
+        files.insert("a".to_owned(), file(1, 10));
+        files.insert("b".to_owned(), file(1, 10));
+        let mut objects = BTreeMap::new();
+        objects.insert("b".to_owned(), object(1, 10));
+        objects.insert("c".to_owned(), object(3, 10));
+        let config = SyncConfig {
+            delete: true,
+            ..Default::default()
+        };
+
+        let (tasks, unchanged) = plan(
+            Path::new("dir"),
+            "",
+            &files,
+            &objects,
+            Direction::Up,
+            &config,
+        );
+        let actions: Vec<_> = tasks
+            .iter()
+            .map(|task| (task.action, task.key.as_str()))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![(SyncAction::Upload, "a"), (SyncAction::DeleteObject, "c")]
+        );
+        assert_eq!(unchanged, 1);
+
+        let (tasks, _) = plan(
+            Path::new("dir"),
+            "",
+            &files,
+            &objects,
+            Direction::Down,
+            &config,
+        );
+        let actions: Vec<_> = tasks
+            .iter()
+            .map(|task| (task.action, task.key.as_str()))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![(SyncAction::Download, "c"), (SyncAction::DeleteFile, "a")]
+        );
+        assert_eq!(tasks[0].path, Path::new("dir").join("c"));
+    }
+}
@@ -5,7 +5,7 @@ use rusoto_core::credential::AwsCredentials;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::region::Region;
 use rusoto_core::signature;
-use rusoto_core::signature::SignedRequest;
+use rusoto_core::signature::{ChecksumAlgorithm, SignedRequest, SigningKey};
 use std::time::Duration;
 /// URL encodes an S3 object key. This is necessary for `copy_object` and `upload_part_copy`,
 /// which require the `copy_source` field to be URL encoded.
@@ -26,6 +26,26 @@ pub fn encode_key<T: AsRef<str>>(key: T) -> String {
     signature::encode_uri_path(key.as_ref())
 }
 
+/// Computes the checksum of an object body with the given algorithm, returning the name of
+/// the header it belongs in and its base64 encoded value.
+///
+/// CRC32 and CRC32C use hardware acceleration where the CPU supports it, which makes them
+/// considerably cheaper than MD5 for large uploads.
+///
+/// # Examples
+///
+/// ```
+/// use rusoto_s3::util::checksum_header;
+/// use rusoto_core::signature::ChecksumAlgorithm;
+///
+/// let (header, value) = checksum_header(ChecksumAlgorithm::Crc32c, b"hello world");
+/// assert_eq!(header, "x-amz-checksum-crc32c");
+/// assert_eq!(value, "yZRlqg==");
+/// ```
+pub fn checksum_header(algorithm: ChecksumAlgorithm, body: &[u8]) -> (&'static str, String) {
+    (algorithm.header_name(), algorithm.compute(body))
+}
+
 macro_rules! add_headers {
     (
         $input:ident , $req:ident ; $p:ident , $e:expr ; $( $t:tt )*
@@ -60,6 +80,7 @@ macro_rules! add_params {
     });
 }
 
+#[derive(Clone, Debug)]
 pub struct PreSignedRequestOption {
     pub expires_in: Duration,
 }
@@ -80,16 +101,152 @@ pub trait PreSignedRequest {
         credentials: &AwsCredentials,
         option: &PreSignedRequestOption,
     ) -> String;
-}
 
-impl PreSignedRequest for GetObjectRequest {
-    /// https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectGET.html
-    fn get_presigned_url(
+    /// Presigns the request with a signing key derived ahead of time for S3 in its region,
+    /// such as the key of a `BatchPresigner`.
+    ///
+    /// The default implementation ignores the key, deriving another one for its region.
+    fn get_presigned_url_with_key(
         &self,
-        region: &Region,
+        key: &SigningKey,
         credentials: &AwsCredentials,
         option: &PreSignedRequestOption,
     ) -> String {
+        self.get_presigned_url(key.region(), credentials, option)
+    }
+}
+
+/// Builds the request presigned URLs are generated from.
+trait UnsignedRequest {
+    fn unsigned_request(&self, region: &Region) -> SignedRequest;
+}
+
+macro_rules! impl_presigned_request {
+    ( $( $request:ty ),* ) => {
+        $(
+            impl PreSignedRequest for $request {
+                fn get_presigned_url(
+                    &self,
+                    region: &Region,
+                    credentials: &AwsCredentials,
+                    option: &PreSignedRequestOption,
+                ) -> String {
+                    self.unsigned_request(region)
+                        .generate_presigned_url(credentials, &option.expires_in, false)
+                }
+
+                fn get_presigned_url_with_key(
+                    &self,
+                    key: &SigningKey,
+                    credentials: &AwsCredentials,
+                    option: &PreSignedRequestOption,
+                ) -> String {
+                    self.unsigned_request(key.region()).generate_presigned_url_with_key(
+                        credentials,
+                        key,
+                        &option.expires_in,
+                        false,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_presigned_request!(
+    GetObjectRequest,
+    PutObjectRequest,
+    DeleteObjectRequest,
+    UploadPartRequest
+);
+
+/// Presigns many requests to S3 in a region, such as the objects of a gallery.
+///
+/// The signing key is derived once, when the presigner is created, instead of for each URL. The
+/// URLs are all dated then, and expire `expires_in` after it: create another presigner for the
+/// next batch rather than keeping one around.
+///
+/// # Examples
+///
+/// ```
+/// use rusoto_core::credential::AwsCredentials;
+/// use rusoto_core::Region;
+/// use rusoto_s3::util::{BatchPresigner, PreSignedRequestOption};
+///
+/// let credentials = AwsCredentials::new("access_key", "secret_key", None, None);
+/// let presigner = BatchPresigner::new(
+///     &Region::EuWest1,
+///     &credentials,
+///     PreSignedRequestOption::default(),
+/// );
+///
+/// let keys = vec!["photos/1.jpg", "photos/2.jpg"];
+/// let urls: Vec<String> = presigner.get_object_urls("my-bucket", keys).collect();
+/// assert_eq!(urls.len(), 2);
+/// ```
+#[derive(Debug)]
+pub struct BatchPresigner {
+    key: SigningKey,
+    credentials: AwsCredentials,
+    option: PreSignedRequestOption,
+}
+
+impl BatchPresigner {
+    /// Creates a presigner for requests to S3 in `region`, deriving its signing key.
+    pub fn new(
+        region: &Region,
+        credentials: &AwsCredentials,
+        option: PreSignedRequestOption,
+    ) -> BatchPresigner {
+        BatchPresigner {
+            key: SigningKey::new(credentials, region, "s3"),
+            credentials: credentials.clone(),
+            option,
+        }
+    }
+
+    /// Presigns a request.
+    pub fn presign<R>(&self, request: &R) -> String
+    where
+        R: PreSignedRequest + ?Sized,
+    {
+        request.get_presigned_url_with_key(&self.key, &self.credentials, &self.option)
+    }
+
+    /// Presigns requests as they are iterated over.
+    pub fn presign_all<'a, I>(&'a self, requests: I) -> impl Iterator<Item = String> + 'a
+    where
+        I: IntoIterator,
+        I::IntoIter: 'a,
+        I::Item: PreSignedRequest,
+    {
+        requests
+            .into_iter()
+            .map(move |request| self.presign(&request))
+    }
+
+    /// Presigns `GetObject` requests for `keys` in `bucket` as they are iterated over.
+    pub fn get_object_urls<'a, I>(
+        &'a self,
+        bucket: &'a str,
+        keys: I,
+    ) -> impl Iterator<Item = String> + 'a
+    where
+        I: IntoIterator,
+        I::IntoIter: 'a,
+        I::Item: Into<String>,
+    {
+        self.presign_all(keys.into_iter().map(move |key| GetObjectRequest {
+            bucket: bucket.to_owned(),
+            key: key.into(),
+            ..Default::default()
+        }))
+    }
+}
+
+impl UnsignedRequest for GetObjectRequest {
+    /// https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectGET.html
+    fn unsigned_request(&self, region: &Region) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = self.bucket, key = self.key);
         let mut request = SignedRequest::new("GET", "s3", &region, &request_uri);
         let mut params = Params::new();
@@ -119,18 +276,13 @@ impl PreSignedRequest for GetObjectRequest {
         );
 
         request.set_params(params);
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        request
     }
 }
 
-impl PreSignedRequest for PutObjectRequest {
+impl UnsignedRequest for PutObjectRequest {
     /// https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectPUT.html
-    fn get_presigned_url(
-        &self,
-        region: &Region,
-        credentials: &AwsCredentials,
-        option: &PreSignedRequestOption,
-    ) -> String {
+    fn unsigned_request(&self, region: &Region) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = self.bucket, key = self.key);
         let mut request = SignedRequest::new("PUT", "s3", &region, &request_uri);
 
@@ -171,18 +323,13 @@ impl PreSignedRequest for PutObjectRequest {
             }
         }
 
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        request
     }
 }
 
-impl PreSignedRequest for DeleteObjectRequest {
+impl UnsignedRequest for DeleteObjectRequest {
     /// https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectDELETE.html
-    fn get_presigned_url(
-        &self,
-        region: &Region,
-        credentials: &AwsCredentials,
-        option: &PreSignedRequestOption,
-    ) -> String {
+    fn unsigned_request(&self, region: &Region) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = self.bucket, key = self.key);
         let mut request = SignedRequest::new("DELETE", "s3", &region, &request_uri);
         let mut params = Params::new();
@@ -198,18 +345,13 @@ impl PreSignedRequest for DeleteObjectRequest {
         );
 
         request.set_params(params);
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        request
     }
 }
 
-impl PreSignedRequest for UploadPartRequest {
+impl UnsignedRequest for UploadPartRequest {
     /// https://docs.aws.amazon.com/AmazonS3/latest/API/mpUploadUploadPart.html
-    fn get_presigned_url(
-        &self,
-        region: &Region,
-        credentials: &AwsCredentials,
-        option: &PreSignedRequestOption,
-    ) -> String {
+    fn unsigned_request(&self, region: &Region) -> SignedRequest {
         let request_uri = format!("/{bucket}/{key}", bucket = self.bucket, key = self.key);
         let mut request = SignedRequest::new("PUT", "s3", &region, &request_uri);
 
@@ -226,6 +368,6 @@ impl PreSignedRequest for UploadPartRequest {
             request_payer, "x-amz-request-payer";
         );
 
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        request
     }
 }
@@ -0,0 +1,335 @@
+//! Working with the versions of objects in versioned buckets.
+//!
+//! In a bucket with versioning enabled, overwriting an object adds a new version and deleting
+//! it adds a delete marker, which becomes the latest version of the key. `VersioningExt` lists
+//! every version of every key as a single stream, removes delete markers to bring objects back,
+//! restores older versions and deletes versions for good.
+
+use std::error::Error;
+use std::fmt;
+
+use futures::future::{self, Either};
+use futures::{stream, Future, Stream};
+use rusoto_core::timestamp;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    CopyObjectError, CopyObjectOutput, CopyObjectRequest, DeleteMarkerEntry, DeleteObjectError,
+    DeleteObjectOutput, DeleteObjectRequest, ListObjectVersionsError, ListObjectVersionsOutput,
+    ListObjectVersionsRequest, ObjectVersion, S3,
+};
+use crate::util::encode_key;
+
+/// A version of an object, or a delete marker.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VersionEntry {
+    /// Key of the object
+    pub key: String,
+    /// ID of the version, `null` for objects stored before versioning was enabled
+    pub version_id: String,
+    /// Whether this is the current version of the object
+    pub is_latest: bool,
+    /// Whether this version is a delete marker rather than object data
+    pub is_delete_marker: bool,
+    /// When the version was created, in ISO 8601 format
+    pub last_modified: Option<String>,
+    /// ETag of the version, unset for delete markers
+    pub e_tag: Option<String>,
+    /// Size of the version in bytes, unset for delete markers
+    pub size: Option<i64>,
+}
+
+/// A stream of the versions of the objects in a bucket, as returned by
+/// `VersioningExt::list_versions`.
+pub type VersionStream =
+    Box<dyn Stream<Item = VersionEntry, Error = RusotoError<ListObjectVersionsError>> + Send>;
+
+/// An MFA device and the code it currently displays, required to permanently delete versions
+/// from a bucket with MFA delete enabled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MfaToken {
+    /// Serial number or ARN of the MFA device
+    pub serial_number: String,
+    /// The code displayed by the device
+    pub code: String,
+}
+
+impl fmt::Display for MfaToken {
+    /// Formats the token as expected in the `x-amz-mfa` header.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.serial_number, self.code)
+    }
+}
+
+/// Errors returned by `VersioningExt::undelete`.
+#[derive(Debug, PartialEq)]
+pub enum UndeleteError {
+    /// Listing the versions of the object failed.
+    ListObjectVersions(ListObjectVersionsError),
+    /// Removing the delete marker failed.
+    DeleteObject(DeleteObjectError),
+}
+
+impl fmt::Display for UndeleteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for UndeleteError {
+    fn description(&self) -> &str {
+        match *self {
+            UndeleteError::ListObjectVersions(ref cause) => cause.description(),
+            UndeleteError::DeleteObject(ref cause) => cause.description(),
+        }
+    }
+}
+
+fn version_entry(version: ObjectVersion) -> Option<VersionEntry> {
+    Some(VersionEntry {
+        key: version.key?,
+        version_id: version.version_id?,
+        is_latest: version.is_latest.unwrap_or(false),
+        is_delete_marker: false,
+        last_modified: version.last_modified.as_ref().map(timestamp::to_iso8601),
+        e_tag: version.e_tag,
+        size: version.size,
+    })
+}
+
+fn delete_marker_entry(marker: DeleteMarkerEntry) -> Option<VersionEntry> {
+    Some(VersionEntry {
+        key: marker.key?,
+        version_id: marker.version_id?,
+        is_latest: marker.is_latest.unwrap_or(false),
+        is_delete_marker: true,
+        last_modified: marker.last_modified.as_ref().map(timestamp::to_iso8601),
+        e_tag: None,
+        size: None,
+    })
+}
+
+/// Merges the versions and delete markers of a page, which S3 returns as separate lists, back
+/// into the order of the listing: by key, then from the latest to the oldest version.
+fn page_entries(output: ListObjectVersionsOutput) -> Vec<VersionEntry> {
+    let mut entries = output
+        .versions
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(version_entry)
+        .chain(
+            output
+                .delete_markers
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(delete_marker_entry),
+        )
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| {
+        a.key
+            .cmp(&b.key)
+            .then(b.is_latest.cmp(&a.is_latest))
+            .then(b.last_modified.cmp(&a.last_modified))
+    });
+    entries
+}
+
+/// Extension methods for working with object versions.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::{Future, Stream};
+/// use rusoto_core::Region;
+/// use rusoto_s3::versioning::VersioningExt;
+/// use rusoto_s3::S3Client;
+///
+/// let client = S3Client::new(Region::UsEast1);
+/// let deleted = client
+///     .list_versions("my-bucket", None)
+///     .filter(|version| version.is_latest && version.is_delete_marker)
+///     .collect()
+///     .wait()
+///     .unwrap();
+/// for version in deleted {
+///     client.undelete("my-bucket", &version.key).sync().unwrap();
+/// }
+/// ```
+pub trait VersioningExt: S3 + Clone + Send + Sync + Sized + 'static {
+    /// Lists every version and delete marker of the objects of `bucket` whose key starts with
+    /// `prefix`, fetching further pages as the stream is consumed.
+    fn list_versions(&self, bucket: &str, prefix: Option<&str>) -> VersionStream {
+        let client = self.clone();
+        let bucket = bucket.to_owned();
+        let prefix = prefix.map(str::to_owned);
+
+        let pages = stream::unfold(Some((None, None)), move |markers| {
+            let (key_marker, version_id_marker) = markers?;
+            let request = ListObjectVersionsRequest {
+                bucket: bucket.clone(),
+                prefix: prefix.clone(),
+                key_marker,
+                version_id_marker,
+                ..Default::default()
+            };
+            Some(client.list_object_versions(request).map(|output| {
+                let next = match output.next_key_marker {
+                    Some(ref key_marker) if output.is_truncated == Some(true) => Some((
+                        Some(key_marker.clone()),
+                        output.next_version_id_marker.clone(),
+                    )),
+                    _ => None,
+                };
+                (page_entries(output), next)
+            }))
+        });
+
+        Box::new(
+            pages
+                .map(stream::iter_ok::<_, RusotoError<ListObjectVersionsError>>)
+                .flatten(),
+        )
+    }
+
+    /// Brings back a deleted object by removing the delete marker which is its latest version,
+    /// making the version before it current again.
+    ///
+    /// Returns the version ID of the removed delete marker, or `None` if the latest version of
+    /// the object is not a delete marker, in which case nothing is changed.
+    fn undelete(&self, bucket: &str, key: &str) -> RusotoFuture<Option<String>, UndeleteError> {
+        let delete_client = self.clone();
+        let bucket = bucket.to_owned();
+        let key = key.to_owned();
+
+        // the key itself sorts before any other key it is a prefix of, so its latest version
+        // is always on the first page
+        let future = self
+            .list_object_versions(ListObjectVersionsRequest {
+                bucket: bucket.clone(),
+                prefix: Some(key.clone()),
+                ..Default::default()
+            })
+            .map_err(|e| e.map_service(UndeleteError::ListObjectVersions))
+            .and_then(move |output| {
+                let marker = page_entries(output)
+                    .into_iter()
+                    .find(|entry| entry.key == key && entry.is_latest)
+                    .filter(|entry| entry.is_delete_marker);
+                let marker = match marker {
+                    Some(marker) => marker,
+                    None => return Either::A(future::ok(None)),
+                };
+                let request = DeleteObjectRequest {
+                    bucket,
+                    key,
+                    version_id: Some(marker.version_id.clone()),
+                    ..Default::default()
+                };
+                Either::B(
+                    delete_client
+                        .delete_object(request)
+                        .map(move |_| Some(marker.version_id))
+                        .map_err(|e| e.map_service(UndeleteError::DeleteObject)),
+                )
+            });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Makes an older version of an object current again by copying it over the object.
+    ///
+    /// The restored copy gets a new version ID, and every existing version is kept.
+    fn restore_version(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: &str,
+    ) -> RusotoFuture<CopyObjectOutput, CopyObjectError> {
+        self.copy_object(CopyObjectRequest {
+            bucket: bucket.to_owned(),
+            key: key.to_owned(),
+            copy_source: format!(
+                "{}?versionId={}",
+                encode_key(format!("{}/{}", bucket, key)),
+                encode_key(version_id)
+            ),
+            ..Default::default()
+        })
+    }
+
+    /// Permanently deletes a version of an object, or a delete marker.
+    ///
+    /// Unlike a plain delete, which only adds a delete marker, the version can't be recovered
+    /// afterwards. Buckets with MFA delete enabled require an `mfa` token.
+    fn permanently_delete_version(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: &str,
+        mfa: Option<&MfaToken>,
+    ) -> RusotoFuture<DeleteObjectOutput, DeleteObjectError> {
+        self.delete_object(DeleteObjectRequest {
+            bucket: bucket.to_owned(),
+            key: key.to_owned(),
+            version_id: Some(version_id.to_owned()),
+            mfa: mfa.map(MfaToken::to_string),
+            ..Default::default()
+        })
+    }
+}
+
+impl<T: S3 + Clone + Send + Sync + 'static> VersioningExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(key: &str, version_id: &str, last_modified: &str, latest: bool) -> ObjectVersion {
+        ObjectVersion {
+            key: Some(key.to_owned()),
+            version_id: Some(version_id.to_owned()),
+            last_modified: timestamp::from_iso8601(last_modified.to_owned()).ok(),
+            is_latest: Some(latest),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn merges_versions_and_delete_markers_in_listing_order() {
+        let output = ListObjectVersionsOutput {
+            versions: Some(vec![
+                version("a", "a1", "2019-01-01T00:00:00.000Z", false),
+                version("b", "b1", "2019-01-01T00:00:00.000Z", true),
+                version("a", "a2", "2019-02-01T00:00:00.000Z", false),
+            ]),
+            delete_markers: Some(vec![DeleteMarkerEntry {
+                key: Some("a".to_owned()),
+                version_id: Some("a3".to_owned()),
+                last_modified: timestamp::from_iso8601("2019-03-01T00:00:00.000Z".to_owned()).ok(),
+                is_latest: Some(true),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        let entries = page_entries(output);
+        let ids = entries
+            .iter()
+            .map(|entry| entry.version_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["a3", "a2", "a1", "b1"]);
+        assert!(entries[0].is_delete_marker && entries[0].is_latest);
+    }
+
+    #[test]
+    fn formats_mfa_header() {
+        let token = MfaToken {
+            serial_number: "arn:aws:iam::123456789012:mfa/root-account-mfa-device".to_owned(),
+            code: "123456".to_owned(),
+        };
+        assert_eq!(
+            token.to_string(),
+            "arn:aws:iam::123456789012:mfa/root-account-mfa-device 123456"
+        );
+    }
+}
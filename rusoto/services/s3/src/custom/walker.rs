@@ -0,0 +1,467 @@
+//! Walking the prefixes of a bucket like the directories of a file system.
+//!
+//! S3 has no directories, but listing objects with a delimiter groups the keys containing it
+//! after the prefix into common prefixes, one level at a time. `BucketWalker` lists every level
+//! under a prefix and returns the prefixes and objects it finds depth first, in the order of
+//! their keys, either as a stream or as a tree.
+//!
+//! The pages of the levels following the current position are listed ahead of time, up to
+//! `WalkConfig::concurrency` pages at once, so that walking prefixes with many small levels
+//! doesn't wait for each listing in turn. Memory use is bounded by that many pages.
+
+use std::collections::VecDeque;
+use std::mem;
+
+use futures::{Async, Future, Poll, Stream};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{ListObjectsV2Error, ListObjectsV2Output, ListObjectsV2Request, Object, S3};
+
+/// The number of pages listed concurrently by default.
+pub const DEFAULT_WALK_CONCURRENCY: usize = 8;
+
+/// How a bucket is walked.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WalkConfig {
+    /// Separator of the levels of keys
+    pub delimiter: String,
+    /// Number of pages listed concurrently
+    pub concurrency: usize,
+    /// Depth of the deepest entries returned, unlimited if unset. Entries directly under the
+    /// walked prefix have a depth of 0, so a depth of 0 lists a single level.
+    pub max_depth: Option<usize>,
+    /// Number of keys requested per page, 1000 if unset
+    pub max_keys: Option<i64>,
+}
+
+impl Default for WalkConfig {
+    fn default() -> WalkConfig {
+        WalkConfig {
+            delimiter: "/".to_owned(),
+            concurrency: DEFAULT_WALK_CONCURRENCY,
+            max_depth: None,
+            max_keys: None,
+        }
+    }
+}
+
+/// A prefix or an object found while walking a bucket.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WalkEntry {
+    /// A common prefix, ending with the delimiter. Its entries follow it.
+    Prefix {
+        /// The prefix
+        prefix: String,
+        /// Number of levels between the walked prefix and this one
+        depth: usize,
+    },
+    /// An object.
+    Object {
+        /// The object, as listed by `ListObjectsV2`
+        object: Object,
+        /// Number of levels between the walked prefix and the object
+        depth: usize,
+    },
+}
+
+impl WalkEntry {
+    /// The prefix, or the key of the object.
+    pub fn key(&self) -> &str {
+        match *self {
+            WalkEntry::Prefix { ref prefix, .. } => prefix,
+            WalkEntry::Object { ref object, .. } => object.key.as_ref().map_or("", String::as_str),
+        }
+    }
+
+    /// Number of levels between the walked prefix and the entry.
+    pub fn depth(&self) -> usize {
+        match *self {
+            WalkEntry::Prefix { depth, .. } | WalkEntry::Object { depth, .. } => depth,
+        }
+    }
+}
+
+/// The objects and prefixes under a prefix, as returned by `BucketWalker::tree`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PrefixTree {
+    /// The prefix
+    pub prefix: String,
+    /// Objects directly under the prefix, in the order of their keys
+    pub objects: Vec<Object>,
+    /// Prefixes directly under the prefix, in order
+    pub children: Vec<PrefixTree>,
+}
+
+impl PrefixTree {
+    /// Number of objects under the prefix, at any depth.
+    pub fn object_count(&self) -> usize {
+        self.objects.len()
+            + self
+                .children
+                .iter()
+                .map(PrefixTree::object_count)
+                .sum::<usize>()
+    }
+}
+
+/// Walks the prefixes of a bucket.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::{Future, Stream};
+/// use rusoto_core::Region;
+/// use rusoto_s3::walker::{BucketWalker, WalkEntry};
+/// use rusoto_s3::S3Client;
+///
+/// let walker = BucketWalker::new(S3Client::new(Region::UsEast1), "my-bucket");
+/// walker
+///     .walk("backups/")
+///     .for_each(|entry| {
+///         let indent = "  ".repeat(entry.depth());
+///         match entry {
+///             WalkEntry::Prefix { prefix, .. } => println!("{}{}", indent, prefix),
+///             WalkEntry::Object { object, .. } => println!(
+///                 "{}{} ({} bytes)",
+///                 indent,
+///                 object.key.unwrap_or_default(),
+///                 object.size.unwrap_or(0)
+///             ),
+///         }
+///         Ok(())
+///     })
+///     .wait()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct BucketWalker<C> {
+    client: C,
+    bucket: String,
+    config: WalkConfig,
+}
+
+impl<C> BucketWalker<C>
+where
+    C: S3 + Clone + Send + 'static,
+{
+    /// Creates a walker of `bucket` with the default configuration.
+    pub fn new(client: C, bucket: &str) -> BucketWalker<C> {
+        BucketWalker::with_config(client, bucket, WalkConfig::default())
+    }
+
+    /// Creates a walker of `bucket` configured with `config`.
+    pub fn with_config(client: C, bucket: &str, config: WalkConfig) -> BucketWalker<C> {
+        BucketWalker {
+            client,
+            bucket: bucket.to_owned(),
+            config,
+        }
+    }
+
+    /// Streams the prefixes and objects under `prefix` depth first: each prefix is followed by
+    /// its own entries, before the next entry of its level.
+    ///
+    /// `prefix` should be empty or end with the delimiter. Objects whose key is a prefix
+    /// followed by the delimiter, such as the markers created for folders by the S3 console,
+    /// are returned as objects of that prefix.
+    pub fn walk(&self, prefix: &str) -> WalkStream<C> {
+        let mut nodes = VecDeque::new();
+        nodes.push_back(Node::Listing(Listing::new(prefix.to_owned(), 0, None)));
+        WalkStream {
+            walker: self.clone(),
+            nodes,
+        }
+    }
+
+    /// Lists the prefixes and objects under `prefix` into a tree.
+    pub fn tree(&self, prefix: &str) -> RusotoFuture<PrefixTree, ListObjectsV2Error> {
+        let root = PrefixTree {
+            prefix: prefix.to_owned(),
+            ..Default::default()
+        };
+        let future = self
+            .walk(prefix)
+            .fold(vec![root], |mut path, entry| {
+                add_to_tree(&mut path, entry);
+                Ok::<_, RusotoError<ListObjectsV2Error>>(path)
+            })
+            .map(|mut path| {
+                close_tree(&mut path, 1);
+                path.pop().unwrap_or_default()
+            });
+        RusotoFuture::from_future(future)
+    }
+
+    fn list_page(
+        &self,
+        listing: &Listing,
+    ) -> RusotoFuture<ListObjectsV2Output, ListObjectsV2Error> {
+        self.client.list_objects_v2(ListObjectsV2Request {
+            bucket: self.bucket.clone(),
+            prefix: Some(listing.prefix.clone()),
+            delimiter: Some(self.config.delimiter.clone()),
+            continuation_token: listing.continuation_token.clone(),
+            max_keys: self.config.max_keys,
+            ..Default::default()
+        })
+    }
+
+    fn descends_into(&self, depth: usize) -> bool {
+        self.config
+            .max_depth
+            .map_or(true, |max_depth| depth < max_depth)
+    }
+}
+
+/// Adds the next entry of a depth first walk to the path of trees leading to it.
+fn add_to_tree(path: &mut Vec<PrefixTree>, entry: WalkEntry) {
+    // the path holds the root, then the prefix of each level above the entry
+    close_tree(path, entry.depth() + 1);
+    match entry {
+        WalkEntry::Prefix { prefix, .. } => path.push(PrefixTree {
+            prefix,
+            ..Default::default()
+        }),
+        WalkEntry::Object { object, .. } => {
+            if let Some(parent) = path.last_mut() {
+                parent.objects.push(object);
+            }
+        }
+    }
+}
+
+/// Moves the trees deeper than `len` into their parents.
+fn close_tree(path: &mut Vec<PrefixTree>, len: usize) {
+    while path.len() > len.max(1) {
+        let tree = path.pop().expect("path longer than its length");
+        if let Some(parent) = path.last_mut() {
+            parent.children.push(tree);
+        }
+    }
+}
+
+/// A page of the entries of a prefix, yet to be listed.
+struct Listing {
+    prefix: String,
+    depth: usize,
+    continuation_token: Option<String>,
+    state: ListingState,
+}
+
+impl Listing {
+    fn new(prefix: String, depth: usize, continuation_token: Option<String>) -> Listing {
+        Listing {
+            prefix,
+            depth,
+            continuation_token,
+            state: ListingState::Idle,
+        }
+    }
+
+    /// Whether the page is being listed or has been listed.
+    fn is_active(&self) -> bool {
+        match self.state {
+            ListingState::Idle => false,
+            ListingState::Requesting(_) | ListingState::Listed(_) => true,
+        }
+    }
+}
+
+enum ListingState {
+    Idle,
+    Requesting(RusotoFuture<ListObjectsV2Output, ListObjectsV2Error>),
+    Listed(ListObjectsV2Output),
+}
+
+/// The entries left to return, in order, and the pages of the levels they lead to.
+enum Node {
+    Entry(WalkEntry),
+    Listing(Listing),
+}
+
+/// A depth first stream of the prefixes and objects of a bucket, as returned by
+/// `BucketWalker::walk`.
+pub struct WalkStream<C> {
+    walker: BucketWalker<C>,
+    nodes: VecDeque<Node>,
+}
+
+impl<C> WalkStream<C>
+where
+    C: S3 + Clone + Send + 'static,
+{
+    /// Starts listing the next pages, and polls the ones being listed.
+    fn poll_listings(&mut self) -> Result<(), RusotoError<ListObjectsV2Error>> {
+        let concurrency = self.walker.config.concurrency.max(1);
+        // pages listed ahead count against the concurrency too, so that they don't pile up
+        // while an earlier page is slow
+        let mut active = self
+            .nodes
+            .iter()
+            .filter(|node| match **node {
+                Node::Listing(ref listing) => listing.is_active(),
+                Node::Entry(_) => false,
+            })
+            .count();
+        for node in self.nodes.iter_mut() {
+            let listing = match *node {
+                Node::Listing(ref mut listing) => listing,
+                Node::Entry(_) => continue,
+            };
+            if !listing.is_active() {
+                if active == concurrency {
+                    continue;
+                }
+                listing.state = ListingState::Requesting(self.walker.list_page(listing));
+                active += 1;
+            }
+            if let ListingState::Requesting(ref mut future) = listing.state {
+                if let Async::Ready(output) = future.poll()? {
+                    listing.state = ListingState::Listed(output);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the listing at the front with its entries, followed by its next page.
+    fn expand(&mut self, listing: Listing, output: ListObjectsV2Output) {
+        let depth = listing.depth;
+        let mut objects = output.contents.unwrap_or_default().into_iter().peekable();
+        let mut prefixes = output
+            .common_prefixes
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|common_prefix| common_prefix.prefix)
+            .peekable();
+
+        // S3 returns objects and common prefixes as separate lists, each in order
+        let mut nodes = Vec::new();
+        loop {
+            let next_is_object = match (objects.peek(), prefixes.peek()) {
+                (Some(object), Some(prefix)) => {
+                    object.key.as_ref().map_or(true, |key| key < prefix)
+                }
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            if next_is_object {
+                let object = objects.next().expect("peeked object");
+                nodes.push(Node::Entry(WalkEntry::Object { object, depth }));
+                continue;
+            }
+            let prefix = prefixes.next().expect("peeked prefix");
+            nodes.push(Node::Entry(WalkEntry::Prefix {
+                prefix: prefix.clone(),
+                depth,
+            }));
+            if self.walker.descends_into(depth) {
+                nodes.push(Node::Listing(Listing::new(prefix, depth + 1, None)));
+            }
+        }
+        match output.next_continuation_token {
+            Some(token) if output.is_truncated == Some(true) => {
+                nodes.push(Node::Listing(Listing::new(
+                    listing.prefix,
+                    depth,
+                    Some(token),
+                )));
+            }
+            _ => {}
+        }
+
+        for node in nodes.into_iter().rev() {
+            self.nodes.push_front(node);
+        }
+    }
+}
+
+impl<C> Stream for WalkStream<C>
+where
+    C: S3 + Clone + Send + 'static,
+{
+    type Item = WalkEntry;
+    type Error = RusotoError<ListObjectsV2Error>;
+
+    fn poll(&mut self) -> Poll<Option<WalkEntry>, RusotoError<ListObjectsV2Error>> {
+        loop {
+            self.poll_listings()?;
+            match self.nodes.pop_front() {
+                None => return Ok(Async::Ready(None)),
+                Some(Node::Entry(entry)) => return Ok(Async::Ready(Some(entry))),
+                Some(Node::Listing(mut listing)) => {
+                    match mem::replace(&mut listing.state, ListingState::Idle) {
+                        ListingState::Listed(output) => self.expand(listing, output),
+                        state => {
+                            listing.state = state;
+                            self.nodes.push_front(Node::Listing(listing));
+                            return Ok(Async::NotReady);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(key: &str) -> Object {
+        Object {
+            key: Some(key.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn builds_trees_from_depth_first_walks() {
+        let entries = vec![
+            WalkEntry::Object {
+                object: object("a.txt"),
+                depth: 0,
+            },
+            WalkEntry::Prefix {
+                prefix: "b/".to_owned(),
+                depth: 0,
+            },
+            WalkEntry::Prefix {
+                prefix: "b/c/".to_owned(),
+                depth: 1,
+            },
+            WalkEntry::Object {
+                object: object("b/c/d.txt"),
+                depth: 2,
+            },
+            WalkEntry::Object {
+                object: object("b/e.txt"),
+                depth: 1,
+            },
+            WalkEntry::Prefix {
+                prefix: "f/".to_owned(),
+                depth: 0,
+            },
+        ];
+
+        let mut path = vec![PrefixTree::default()];
+        for entry in entries {
+            add_to_tree(&mut path, entry);
+        }
+        close_tree(&mut path, 1);
+
+        assert_eq!(path.len(), 1);
+        let root = &path[0];
+        assert_eq!(root.objects, vec![object("a.txt")]);
+        let children: Vec<_> = root.children.iter().map(|c| c.prefix.as_str()).collect();
+        assert_eq!(children, vec!["b/", "f/"]);
+        assert_eq!(root.children[0].objects, vec![object("b/e.txt")]);
+        assert_eq!(root.children[0].children[0].prefix, "b/c/");
+        assert_eq!(
+            root.children[0].children[0].objects,
+            vec![object("b/c/d.txt")]
+        );
+        assert_eq!(root.object_count(), 3);
+    }
+}
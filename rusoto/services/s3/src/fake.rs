@@ -0,0 +1,1266 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! An in-process fake of the Amazon S3 API for unit tests.
+//!
+//! `FakeS3` implements `S3` by calling a `S3Backend`, which holds the
+//! state of the fake. Backend methods fail with `RusotoError::Validation` unless they are
+//! implemented, so a backend only needs to implement the operations a test calls.
+
+use std::sync::Arc;
+
+use futures::future;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::*;
+
+/// The error returned by operations a backend doesn't implement.
+pub fn unsupported_operation<E>(operation: &str) -> RusotoError<E> {
+    RusotoError::Validation(format!("{} is not supported by this fake", operation))
+}
+
+/// The state and behaviour of a `FakeS3`.
+pub trait S3Backend: Send + Sync {
+    fn abort_multipart_upload(
+        &self,
+        _input: AbortMultipartUploadRequest,
+    ) -> Result<AbortMultipartUploadOutput, RusotoError<AbortMultipartUploadError>> {
+        Err(unsupported_operation("AbortMultipartUpload"))
+    }
+    fn complete_multipart_upload(
+        &self,
+        _input: CompleteMultipartUploadRequest,
+    ) -> Result<CompleteMultipartUploadOutput, RusotoError<CompleteMultipartUploadError>> {
+        Err(unsupported_operation("CompleteMultipartUpload"))
+    }
+    fn copy_object(
+        &self,
+        _input: CopyObjectRequest,
+    ) -> Result<CopyObjectOutput, RusotoError<CopyObjectError>> {
+        Err(unsupported_operation("CopyObject"))
+    }
+    fn create_bucket(
+        &self,
+        _input: CreateBucketRequest,
+    ) -> Result<CreateBucketOutput, RusotoError<CreateBucketError>> {
+        Err(unsupported_operation("CreateBucket"))
+    }
+    fn create_multipart_upload(
+        &self,
+        _input: CreateMultipartUploadRequest,
+    ) -> Result<CreateMultipartUploadOutput, RusotoError<CreateMultipartUploadError>> {
+        Err(unsupported_operation("CreateMultipartUpload"))
+    }
+    fn delete_bucket(
+        &self,
+        _input: DeleteBucketRequest,
+    ) -> Result<(), RusotoError<DeleteBucketError>> {
+        Err(unsupported_operation("DeleteBucket"))
+    }
+    fn delete_bucket_analytics_configuration(
+        &self,
+        _input: DeleteBucketAnalyticsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketAnalyticsConfigurationError>> {
+        Err(unsupported_operation("DeleteBucketAnalyticsConfiguration"))
+    }
+    fn delete_bucket_cors(
+        &self,
+        _input: DeleteBucketCorsRequest,
+    ) -> Result<(), RusotoError<DeleteBucketCorsError>> {
+        Err(unsupported_operation("DeleteBucketCors"))
+    }
+    fn delete_bucket_encryption(
+        &self,
+        _input: DeleteBucketEncryptionRequest,
+    ) -> Result<(), RusotoError<DeleteBucketEncryptionError>> {
+        Err(unsupported_operation("DeleteBucketEncryption"))
+    }
+    fn delete_bucket_inventory_configuration(
+        &self,
+        _input: DeleteBucketInventoryConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketInventoryConfigurationError>> {
+        Err(unsupported_operation("DeleteBucketInventoryConfiguration"))
+    }
+    fn delete_bucket_lifecycle(
+        &self,
+        _input: DeleteBucketLifecycleRequest,
+    ) -> Result<(), RusotoError<DeleteBucketLifecycleError>> {
+        Err(unsupported_operation("DeleteBucketLifecycle"))
+    }
+    fn delete_bucket_metrics_configuration(
+        &self,
+        _input: DeleteBucketMetricsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketMetricsConfigurationError>> {
+        Err(unsupported_operation("DeleteBucketMetricsConfiguration"))
+    }
+    fn delete_bucket_policy(
+        &self,
+        _input: DeleteBucketPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteBucketPolicyError>> {
+        Err(unsupported_operation("DeleteBucketPolicy"))
+    }
+    fn delete_bucket_replication(
+        &self,
+        _input: DeleteBucketReplicationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketReplicationError>> {
+        Err(unsupported_operation("DeleteBucketReplication"))
+    }
+    fn delete_bucket_tagging(
+        &self,
+        _input: DeleteBucketTaggingRequest,
+    ) -> Result<(), RusotoError<DeleteBucketTaggingError>> {
+        Err(unsupported_operation("DeleteBucketTagging"))
+    }
+    fn delete_bucket_website(
+        &self,
+        _input: DeleteBucketWebsiteRequest,
+    ) -> Result<(), RusotoError<DeleteBucketWebsiteError>> {
+        Err(unsupported_operation("DeleteBucketWebsite"))
+    }
+    fn delete_object(
+        &self,
+        _input: DeleteObjectRequest,
+    ) -> Result<DeleteObjectOutput, RusotoError<DeleteObjectError>> {
+        Err(unsupported_operation("DeleteObject"))
+    }
+    fn delete_object_tagging(
+        &self,
+        _input: DeleteObjectTaggingRequest,
+    ) -> Result<DeleteObjectTaggingOutput, RusotoError<DeleteObjectTaggingError>> {
+        Err(unsupported_operation("DeleteObjectTagging"))
+    }
+    fn delete_objects(
+        &self,
+        _input: DeleteObjectsRequest,
+    ) -> Result<DeleteObjectsOutput, RusotoError<DeleteObjectsError>> {
+        Err(unsupported_operation("DeleteObjects"))
+    }
+    fn delete_public_access_block(
+        &self,
+        _input: DeletePublicAccessBlockRequest,
+    ) -> Result<(), RusotoError<DeletePublicAccessBlockError>> {
+        Err(unsupported_operation("DeletePublicAccessBlock"))
+    }
+    fn get_bucket_accelerate_configuration(
+        &self,
+        _input: GetBucketAccelerateConfigurationRequest,
+    ) -> Result<
+        GetBucketAccelerateConfigurationOutput,
+        RusotoError<GetBucketAccelerateConfigurationError>,
+    > {
+        Err(unsupported_operation("GetBucketAccelerateConfiguration"))
+    }
+    fn get_bucket_acl(
+        &self,
+        _input: GetBucketAclRequest,
+    ) -> Result<GetBucketAclOutput, RusotoError<GetBucketAclError>> {
+        Err(unsupported_operation("GetBucketAcl"))
+    }
+    fn get_bucket_analytics_configuration(
+        &self,
+        _input: GetBucketAnalyticsConfigurationRequest,
+    ) -> Result<
+        GetBucketAnalyticsConfigurationOutput,
+        RusotoError<GetBucketAnalyticsConfigurationError>,
+    > {
+        Err(unsupported_operation("GetBucketAnalyticsConfiguration"))
+    }
+    fn get_bucket_cors(
+        &self,
+        _input: GetBucketCorsRequest,
+    ) -> Result<GetBucketCorsOutput, RusotoError<GetBucketCorsError>> {
+        Err(unsupported_operation("GetBucketCors"))
+    }
+    fn get_bucket_encryption(
+        &self,
+        _input: GetBucketEncryptionRequest,
+    ) -> Result<GetBucketEncryptionOutput, RusotoError<GetBucketEncryptionError>> {
+        Err(unsupported_operation("GetBucketEncryption"))
+    }
+    fn get_bucket_inventory_configuration(
+        &self,
+        _input: GetBucketInventoryConfigurationRequest,
+    ) -> Result<
+        GetBucketInventoryConfigurationOutput,
+        RusotoError<GetBucketInventoryConfigurationError>,
+    > {
+        Err(unsupported_operation("GetBucketInventoryConfiguration"))
+    }
+    fn get_bucket_lifecycle(
+        &self,
+        _input: GetBucketLifecycleRequest,
+    ) -> Result<GetBucketLifecycleOutput, RusotoError<GetBucketLifecycleError>> {
+        Err(unsupported_operation("GetBucketLifecycle"))
+    }
+    fn get_bucket_lifecycle_configuration(
+        &self,
+        _input: GetBucketLifecycleConfigurationRequest,
+    ) -> Result<
+        GetBucketLifecycleConfigurationOutput,
+        RusotoError<GetBucketLifecycleConfigurationError>,
+    > {
+        Err(unsupported_operation("GetBucketLifecycleConfiguration"))
+    }
+    fn get_bucket_location(
+        &self,
+        _input: GetBucketLocationRequest,
+    ) -> Result<GetBucketLocationOutput, RusotoError<GetBucketLocationError>> {
+        Err(unsupported_operation("GetBucketLocation"))
+    }
+    fn get_bucket_logging(
+        &self,
+        _input: GetBucketLoggingRequest,
+    ) -> Result<GetBucketLoggingOutput, RusotoError<GetBucketLoggingError>> {
+        Err(unsupported_operation("GetBucketLogging"))
+    }
+    fn get_bucket_metrics_configuration(
+        &self,
+        _input: GetBucketMetricsConfigurationRequest,
+    ) -> Result<GetBucketMetricsConfigurationOutput, RusotoError<GetBucketMetricsConfigurationError>>
+    {
+        Err(unsupported_operation("GetBucketMetricsConfiguration"))
+    }
+    fn get_bucket_notification(
+        &self,
+        _input: GetBucketNotificationConfigurationRequest,
+    ) -> Result<NotificationConfigurationDeprecated, RusotoError<GetBucketNotificationError>> {
+        Err(unsupported_operation("GetBucketNotification"))
+    }
+    fn get_bucket_notification_configuration(
+        &self,
+        _input: GetBucketNotificationConfigurationRequest,
+    ) -> Result<NotificationConfiguration, RusotoError<GetBucketNotificationConfigurationError>>
+    {
+        Err(unsupported_operation("GetBucketNotificationConfiguration"))
+    }
+    fn get_bucket_policy(
+        &self,
+        _input: GetBucketPolicyRequest,
+    ) -> Result<GetBucketPolicyOutput, RusotoError<GetBucketPolicyError>> {
+        Err(unsupported_operation("GetBucketPolicy"))
+    }
+    fn get_bucket_policy_status(
+        &self,
+        _input: GetBucketPolicyStatusRequest,
+    ) -> Result<GetBucketPolicyStatusOutput, RusotoError<GetBucketPolicyStatusError>> {
+        Err(unsupported_operation("GetBucketPolicyStatus"))
+    }
+    fn get_bucket_replication(
+        &self,
+        _input: GetBucketReplicationRequest,
+    ) -> Result<GetBucketReplicationOutput, RusotoError<GetBucketReplicationError>> {
+        Err(unsupported_operation("GetBucketReplication"))
+    }
+    fn get_bucket_request_payment(
+        &self,
+        _input: GetBucketRequestPaymentRequest,
+    ) -> Result<GetBucketRequestPaymentOutput, RusotoError<GetBucketRequestPaymentError>> {
+        Err(unsupported_operation("GetBucketRequestPayment"))
+    }
+    fn get_bucket_tagging(
+        &self,
+        _input: GetBucketTaggingRequest,
+    ) -> Result<GetBucketTaggingOutput, RusotoError<GetBucketTaggingError>> {
+        Err(unsupported_operation("GetBucketTagging"))
+    }
+    fn get_bucket_versioning(
+        &self,
+        _input: GetBucketVersioningRequest,
+    ) -> Result<GetBucketVersioningOutput, RusotoError<GetBucketVersioningError>> {
+        Err(unsupported_operation("GetBucketVersioning"))
+    }
+    fn get_bucket_website(
+        &self,
+        _input: GetBucketWebsiteRequest,
+    ) -> Result<GetBucketWebsiteOutput, RusotoError<GetBucketWebsiteError>> {
+        Err(unsupported_operation("GetBucketWebsite"))
+    }
+    fn get_object(
+        &self,
+        _input: GetObjectRequest,
+    ) -> Result<GetObjectOutput, RusotoError<GetObjectError>> {
+        Err(unsupported_operation("GetObject"))
+    }
+    fn get_object_acl(
+        &self,
+        _input: GetObjectAclRequest,
+    ) -> Result<GetObjectAclOutput, RusotoError<GetObjectAclError>> {
+        Err(unsupported_operation("GetObjectAcl"))
+    }
+    fn get_object_legal_hold(
+        &self,
+        _input: GetObjectLegalHoldRequest,
+    ) -> Result<GetObjectLegalHoldOutput, RusotoError<GetObjectLegalHoldError>> {
+        Err(unsupported_operation("GetObjectLegalHold"))
+    }
+    fn get_object_lock_configuration(
+        &self,
+        _input: GetObjectLockConfigurationRequest,
+    ) -> Result<GetObjectLockConfigurationOutput, RusotoError<GetObjectLockConfigurationError>>
+    {
+        Err(unsupported_operation("GetObjectLockConfiguration"))
+    }
+    fn get_object_retention(
+        &self,
+        _input: GetObjectRetentionRequest,
+    ) -> Result<GetObjectRetentionOutput, RusotoError<GetObjectRetentionError>> {
+        Err(unsupported_operation("GetObjectRetention"))
+    }
+    fn get_object_tagging(
+        &self,
+        _input: GetObjectTaggingRequest,
+    ) -> Result<GetObjectTaggingOutput, RusotoError<GetObjectTaggingError>> {
+        Err(unsupported_operation("GetObjectTagging"))
+    }
+    fn get_object_torrent(
+        &self,
+        _input: GetObjectTorrentRequest,
+    ) -> Result<GetObjectTorrentOutput, RusotoError<GetObjectTorrentError>> {
+        Err(unsupported_operation("GetObjectTorrent"))
+    }
+    fn get_public_access_block(
+        &self,
+        _input: GetPublicAccessBlockRequest,
+    ) -> Result<GetPublicAccessBlockOutput, RusotoError<GetPublicAccessBlockError>> {
+        Err(unsupported_operation("GetPublicAccessBlock"))
+    }
+    fn head_bucket(&self, _input: HeadBucketRequest) -> Result<(), RusotoError<HeadBucketError>> {
+        Err(unsupported_operation("HeadBucket"))
+    }
+    fn head_object(
+        &self,
+        _input: HeadObjectRequest,
+    ) -> Result<HeadObjectOutput, RusotoError<HeadObjectError>> {
+        Err(unsupported_operation("HeadObject"))
+    }
+    fn list_bucket_analytics_configurations(
+        &self,
+        _input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> Result<
+        ListBucketAnalyticsConfigurationsOutput,
+        RusotoError<ListBucketAnalyticsConfigurationsError>,
+    > {
+        Err(unsupported_operation("ListBucketAnalyticsConfigurations"))
+    }
+    fn list_bucket_inventory_configurations(
+        &self,
+        _input: ListBucketInventoryConfigurationsRequest,
+    ) -> Result<
+        ListBucketInventoryConfigurationsOutput,
+        RusotoError<ListBucketInventoryConfigurationsError>,
+    > {
+        Err(unsupported_operation("ListBucketInventoryConfigurations"))
+    }
+    fn list_bucket_metrics_configurations(
+        &self,
+        _input: ListBucketMetricsConfigurationsRequest,
+    ) -> Result<
+        ListBucketMetricsConfigurationsOutput,
+        RusotoError<ListBucketMetricsConfigurationsError>,
+    > {
+        Err(unsupported_operation("ListBucketMetricsConfigurations"))
+    }
+    fn list_buckets(&self) -> Result<ListBucketsOutput, RusotoError<ListBucketsError>> {
+        Err(unsupported_operation("ListBuckets"))
+    }
+    fn list_multipart_uploads(
+        &self,
+        _input: ListMultipartUploadsRequest,
+    ) -> Result<ListMultipartUploadsOutput, RusotoError<ListMultipartUploadsError>> {
+        Err(unsupported_operation("ListMultipartUploads"))
+    }
+    fn list_object_versions(
+        &self,
+        _input: ListObjectVersionsRequest,
+    ) -> Result<ListObjectVersionsOutput, RusotoError<ListObjectVersionsError>> {
+        Err(unsupported_operation("ListObjectVersions"))
+    }
+    fn list_objects(
+        &self,
+        _input: ListObjectsRequest,
+    ) -> Result<ListObjectsOutput, RusotoError<ListObjectsError>> {
+        Err(unsupported_operation("ListObjects"))
+    }
+    fn list_objects_v2(
+        &self,
+        _input: ListObjectsV2Request,
+    ) -> Result<ListObjectsV2Output, RusotoError<ListObjectsV2Error>> {
+        Err(unsupported_operation("ListObjectsV2"))
+    }
+    fn list_parts(
+        &self,
+        _input: ListPartsRequest,
+    ) -> Result<ListPartsOutput, RusotoError<ListPartsError>> {
+        Err(unsupported_operation("ListParts"))
+    }
+    fn put_bucket_accelerate_configuration(
+        &self,
+        _input: PutBucketAccelerateConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketAccelerateConfigurationError>> {
+        Err(unsupported_operation("PutBucketAccelerateConfiguration"))
+    }
+    fn put_bucket_acl(
+        &self,
+        _input: PutBucketAclRequest,
+    ) -> Result<(), RusotoError<PutBucketAclError>> {
+        Err(unsupported_operation("PutBucketAcl"))
+    }
+    fn put_bucket_analytics_configuration(
+        &self,
+        _input: PutBucketAnalyticsConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketAnalyticsConfigurationError>> {
+        Err(unsupported_operation("PutBucketAnalyticsConfiguration"))
+    }
+    fn put_bucket_cors(
+        &self,
+        _input: PutBucketCorsRequest,
+    ) -> Result<(), RusotoError<PutBucketCorsError>> {
+        Err(unsupported_operation("PutBucketCors"))
+    }
+    fn put_bucket_encryption(
+        &self,
+        _input: PutBucketEncryptionRequest,
+    ) -> Result<(), RusotoError<PutBucketEncryptionError>> {
+        Err(unsupported_operation("PutBucketEncryption"))
+    }
+    fn put_bucket_inventory_configuration(
+        &self,
+        _input: PutBucketInventoryConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketInventoryConfigurationError>> {
+        Err(unsupported_operation("PutBucketInventoryConfiguration"))
+    }
+    fn put_bucket_lifecycle(
+        &self,
+        _input: PutBucketLifecycleRequest,
+    ) -> Result<(), RusotoError<PutBucketLifecycleError>> {
+        Err(unsupported_operation("PutBucketLifecycle"))
+    }
+    fn put_bucket_lifecycle_configuration(
+        &self,
+        _input: PutBucketLifecycleConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketLifecycleConfigurationError>> {
+        Err(unsupported_operation("PutBucketLifecycleConfiguration"))
+    }
+    fn put_bucket_logging(
+        &self,
+        _input: PutBucketLoggingRequest,
+    ) -> Result<(), RusotoError<PutBucketLoggingError>> {
+        Err(unsupported_operation("PutBucketLogging"))
+    }
+    fn put_bucket_metrics_configuration(
+        &self,
+        _input: PutBucketMetricsConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketMetricsConfigurationError>> {
+        Err(unsupported_operation("PutBucketMetricsConfiguration"))
+    }
+    fn put_bucket_notification(
+        &self,
+        _input: PutBucketNotificationRequest,
+    ) -> Result<(), RusotoError<PutBucketNotificationError>> {
+        Err(unsupported_operation("PutBucketNotification"))
+    }
+    fn put_bucket_notification_configuration(
+        &self,
+        _input: PutBucketNotificationConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketNotificationConfigurationError>> {
+        Err(unsupported_operation("PutBucketNotificationConfiguration"))
+    }
+    fn put_bucket_policy(
+        &self,
+        _input: PutBucketPolicyRequest,
+    ) -> Result<(), RusotoError<PutBucketPolicyError>> {
+        Err(unsupported_operation("PutBucketPolicy"))
+    }
+    fn put_bucket_replication(
+        &self,
+        _input: PutBucketReplicationRequest,
+    ) -> Result<(), RusotoError<PutBucketReplicationError>> {
+        Err(unsupported_operation("PutBucketReplication"))
+    }
+    fn put_bucket_request_payment(
+        &self,
+        _input: PutBucketRequestPaymentRequest,
+    ) -> Result<(), RusotoError<PutBucketRequestPaymentError>> {
+        Err(unsupported_operation("PutBucketRequestPayment"))
+    }
+    fn put_bucket_tagging(
+        &self,
+        _input: PutBucketTaggingRequest,
+    ) -> Result<(), RusotoError<PutBucketTaggingError>> {
+        Err(unsupported_operation("PutBucketTagging"))
+    }
+    fn put_bucket_versioning(
+        &self,
+        _input: PutBucketVersioningRequest,
+    ) -> Result<(), RusotoError<PutBucketVersioningError>> {
+        Err(unsupported_operation("PutBucketVersioning"))
+    }
+    fn put_bucket_website(
+        &self,
+        _input: PutBucketWebsiteRequest,
+    ) -> Result<(), RusotoError<PutBucketWebsiteError>> {
+        Err(unsupported_operation("PutBucketWebsite"))
+    }
+    fn put_object(
+        &self,
+        _input: PutObjectRequest,
+    ) -> Result<PutObjectOutput, RusotoError<PutObjectError>> {
+        Err(unsupported_operation("PutObject"))
+    }
+    fn put_object_acl(
+        &self,
+        _input: PutObjectAclRequest,
+    ) -> Result<PutObjectAclOutput, RusotoError<PutObjectAclError>> {
+        Err(unsupported_operation("PutObjectAcl"))
+    }
+    fn put_object_legal_hold(
+        &self,
+        _input: PutObjectLegalHoldRequest,
+    ) -> Result<PutObjectLegalHoldOutput, RusotoError<PutObjectLegalHoldError>> {
+        Err(unsupported_operation("PutObjectLegalHold"))
+    }
+    fn put_object_lock_configuration(
+        &self,
+        _input: PutObjectLockConfigurationRequest,
+    ) -> Result<PutObjectLockConfigurationOutput, RusotoError<PutObjectLockConfigurationError>>
+    {
+        Err(unsupported_operation("PutObjectLockConfiguration"))
+    }
+    fn put_object_retention(
+        &self,
+        _input: PutObjectRetentionRequest,
+    ) -> Result<PutObjectRetentionOutput, RusotoError<PutObjectRetentionError>> {
+        Err(unsupported_operation("PutObjectRetention"))
+    }
+    fn put_object_tagging(
+        &self,
+        _input: PutObjectTaggingRequest,
+    ) -> Result<PutObjectTaggingOutput, RusotoError<PutObjectTaggingError>> {
+        Err(unsupported_operation("PutObjectTagging"))
+    }
+    fn put_public_access_block(
+        &self,
+        _input: PutPublicAccessBlockRequest,
+    ) -> Result<(), RusotoError<PutPublicAccessBlockError>> {
+        Err(unsupported_operation("PutPublicAccessBlock"))
+    }
+    fn restore_object(
+        &self,
+        _input: RestoreObjectRequest,
+    ) -> Result<RestoreObjectOutput, RusotoError<RestoreObjectError>> {
+        Err(unsupported_operation("RestoreObject"))
+    }
+    fn select_object_content(
+        &self,
+        _input: SelectObjectContentRequest,
+    ) -> Result<SelectObjectContentOutput, RusotoError<SelectObjectContentError>> {
+        Err(unsupported_operation("SelectObjectContent"))
+    }
+    fn upload_part(
+        &self,
+        _input: UploadPartRequest,
+    ) -> Result<UploadPartOutput, RusotoError<UploadPartError>> {
+        Err(unsupported_operation("UploadPart"))
+    }
+    fn upload_part_copy(
+        &self,
+        _input: UploadPartCopyRequest,
+    ) -> Result<UploadPartCopyOutput, RusotoError<UploadPartCopyError>> {
+        Err(unsupported_operation("UploadPartCopy"))
+    }
+}
+
+/// An implementation of `S3` calling a `S3Backend` instead of AWS.
+///
+/// Operations are run by the backend when the returned futures are polled. Clones of the fake
+/// share the same backend.
+pub struct FakeS3<B> {
+    backend: Arc<B>,
+}
+
+impl<B> Clone for FakeS3<B> {
+    fn clone(&self) -> Self {
+        FakeS3 {
+            backend: self.backend.clone(),
+        }
+    }
+}
+
+impl<B: S3Backend> FakeS3<B> {
+    /// Creates a fake calling `backend`.
+    pub fn with_backend(backend: B) -> FakeS3<B> {
+        FakeS3 {
+            backend: Arc::new(backend),
+        }
+    }
+
+    /// The backend of the fake, to set up or inspect its state.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+}
+
+impl<B: S3Backend + 'static> S3 for FakeS3<B> {
+    fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadRequest,
+    ) -> RusotoFuture<AbortMultipartUploadOutput, AbortMultipartUploadError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.abort_multipart_upload(input)))
+    }
+    fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadRequest,
+    ) -> RusotoFuture<CompleteMultipartUploadOutput, CompleteMultipartUploadError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.complete_multipart_upload(input)
+        }))
+    }
+    fn copy_object(
+        &self,
+        input: CopyObjectRequest,
+    ) -> RusotoFuture<CopyObjectOutput, CopyObjectError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.copy_object(input)))
+    }
+    fn create_bucket(
+        &self,
+        input: CreateBucketRequest,
+    ) -> RusotoFuture<CreateBucketOutput, CreateBucketError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.create_bucket(input)))
+    }
+    fn create_multipart_upload(
+        &self,
+        input: CreateMultipartUploadRequest,
+    ) -> RusotoFuture<CreateMultipartUploadOutput, CreateMultipartUploadError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.create_multipart_upload(input)))
+    }
+    fn delete_bucket(&self, input: DeleteBucketRequest) -> RusotoFuture<(), DeleteBucketError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_bucket(input)))
+    }
+    fn delete_bucket_analytics_configuration(
+        &self,
+        input: DeleteBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketAnalyticsConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.delete_bucket_analytics_configuration(input)
+        }))
+    }
+    fn delete_bucket_cors(
+        &self,
+        input: DeleteBucketCorsRequest,
+    ) -> RusotoFuture<(), DeleteBucketCorsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_bucket_cors(input)))
+    }
+    fn delete_bucket_encryption(
+        &self,
+        input: DeleteBucketEncryptionRequest,
+    ) -> RusotoFuture<(), DeleteBucketEncryptionError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.delete_bucket_encryption(input)
+        }))
+    }
+    fn delete_bucket_inventory_configuration(
+        &self,
+        input: DeleteBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketInventoryConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.delete_bucket_inventory_configuration(input)
+        }))
+    }
+    fn delete_bucket_lifecycle(
+        &self,
+        input: DeleteBucketLifecycleRequest,
+    ) -> RusotoFuture<(), DeleteBucketLifecycleError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_bucket_lifecycle(input)))
+    }
+    fn delete_bucket_metrics_configuration(
+        &self,
+        input: DeleteBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketMetricsConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.delete_bucket_metrics_configuration(input)
+        }))
+    }
+    fn delete_bucket_policy(
+        &self,
+        input: DeleteBucketPolicyRequest,
+    ) -> RusotoFuture<(), DeleteBucketPolicyError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_bucket_policy(input)))
+    }
+    fn delete_bucket_replication(
+        &self,
+        input: DeleteBucketReplicationRequest,
+    ) -> RusotoFuture<(), DeleteBucketReplicationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.delete_bucket_replication(input)
+        }))
+    }
+    fn delete_bucket_tagging(
+        &self,
+        input: DeleteBucketTaggingRequest,
+    ) -> RusotoFuture<(), DeleteBucketTaggingError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_bucket_tagging(input)))
+    }
+    fn delete_bucket_website(
+        &self,
+        input: DeleteBucketWebsiteRequest,
+    ) -> RusotoFuture<(), DeleteBucketWebsiteError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_bucket_website(input)))
+    }
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> RusotoFuture<DeleteObjectOutput, DeleteObjectError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_object(input)))
+    }
+    fn delete_object_tagging(
+        &self,
+        input: DeleteObjectTaggingRequest,
+    ) -> RusotoFuture<DeleteObjectTaggingOutput, DeleteObjectTaggingError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_object_tagging(input)))
+    }
+    fn delete_objects(
+        &self,
+        input: DeleteObjectsRequest,
+    ) -> RusotoFuture<DeleteObjectsOutput, DeleteObjectsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_objects(input)))
+    }
+    fn delete_public_access_block(
+        &self,
+        input: DeletePublicAccessBlockRequest,
+    ) -> RusotoFuture<(), DeletePublicAccessBlockError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.delete_public_access_block(input)
+        }))
+    }
+    fn get_bucket_accelerate_configuration(
+        &self,
+        input: GetBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAccelerateConfigurationOutput, GetBucketAccelerateConfigurationError>
+    {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.get_bucket_accelerate_configuration(input)
+        }))
+    }
+    fn get_bucket_acl(
+        &self,
+        input: GetBucketAclRequest,
+    ) -> RusotoFuture<GetBucketAclOutput, GetBucketAclError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_acl(input)))
+    }
+    fn get_bucket_analytics_configuration(
+        &self,
+        input: GetBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAnalyticsConfigurationOutput, GetBucketAnalyticsConfigurationError>
+    {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.get_bucket_analytics_configuration(input)
+        }))
+    }
+    fn get_bucket_cors(
+        &self,
+        input: GetBucketCorsRequest,
+    ) -> RusotoFuture<GetBucketCorsOutput, GetBucketCorsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_cors(input)))
+    }
+    fn get_bucket_encryption(
+        &self,
+        input: GetBucketEncryptionRequest,
+    ) -> RusotoFuture<GetBucketEncryptionOutput, GetBucketEncryptionError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_encryption(input)))
+    }
+    fn get_bucket_inventory_configuration(
+        &self,
+        input: GetBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<GetBucketInventoryConfigurationOutput, GetBucketInventoryConfigurationError>
+    {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.get_bucket_inventory_configuration(input)
+        }))
+    }
+    fn get_bucket_lifecycle(
+        &self,
+        input: GetBucketLifecycleRequest,
+    ) -> RusotoFuture<GetBucketLifecycleOutput, GetBucketLifecycleError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_lifecycle(input)))
+    }
+    fn get_bucket_lifecycle_configuration(
+        &self,
+        input: GetBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<GetBucketLifecycleConfigurationOutput, GetBucketLifecycleConfigurationError>
+    {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.get_bucket_lifecycle_configuration(input)
+        }))
+    }
+    fn get_bucket_location(
+        &self,
+        input: GetBucketLocationRequest,
+    ) -> RusotoFuture<GetBucketLocationOutput, GetBucketLocationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_location(input)))
+    }
+    fn get_bucket_logging(
+        &self,
+        input: GetBucketLoggingRequest,
+    ) -> RusotoFuture<GetBucketLoggingOutput, GetBucketLoggingError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_logging(input)))
+    }
+    fn get_bucket_metrics_configuration(
+        &self,
+        input: GetBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketMetricsConfigurationOutput, GetBucketMetricsConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.get_bucket_metrics_configuration(input)
+        }))
+    }
+    fn get_bucket_notification(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfigurationDeprecated, GetBucketNotificationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_notification(input)))
+    }
+    fn get_bucket_notification_configuration(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfiguration, GetBucketNotificationConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.get_bucket_notification_configuration(input)
+        }))
+    }
+    fn get_bucket_policy(
+        &self,
+        input: GetBucketPolicyRequest,
+    ) -> RusotoFuture<GetBucketPolicyOutput, GetBucketPolicyError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_policy(input)))
+    }
+    fn get_bucket_policy_status(
+        &self,
+        input: GetBucketPolicyStatusRequest,
+    ) -> RusotoFuture<GetBucketPolicyStatusOutput, GetBucketPolicyStatusError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.get_bucket_policy_status(input)
+        }))
+    }
+    fn get_bucket_replication(
+        &self,
+        input: GetBucketReplicationRequest,
+    ) -> RusotoFuture<GetBucketReplicationOutput, GetBucketReplicationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_replication(input)))
+    }
+    fn get_bucket_request_payment(
+        &self,
+        input: GetBucketRequestPaymentRequest,
+    ) -> RusotoFuture<GetBucketRequestPaymentOutput, GetBucketRequestPaymentError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.get_bucket_request_payment(input)
+        }))
+    }
+    fn get_bucket_tagging(
+        &self,
+        input: GetBucketTaggingRequest,
+    ) -> RusotoFuture<GetBucketTaggingOutput, GetBucketTaggingError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_tagging(input)))
+    }
+    fn get_bucket_versioning(
+        &self,
+        input: GetBucketVersioningRequest,
+    ) -> RusotoFuture<GetBucketVersioningOutput, GetBucketVersioningError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_versioning(input)))
+    }
+    fn get_bucket_website(
+        &self,
+        input: GetBucketWebsiteRequest,
+    ) -> RusotoFuture<GetBucketWebsiteOutput, GetBucketWebsiteError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_bucket_website(input)))
+    }
+    fn get_object(&self, input: GetObjectRequest) -> RusotoFuture<GetObjectOutput, GetObjectError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_object(input)))
+    }
+    fn get_object_acl(
+        &self,
+        input: GetObjectAclRequest,
+    ) -> RusotoFuture<GetObjectAclOutput, GetObjectAclError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_object_acl(input)))
+    }
+    fn get_object_legal_hold(
+        &self,
+        input: GetObjectLegalHoldRequest,
+    ) -> RusotoFuture<GetObjectLegalHoldOutput, GetObjectLegalHoldError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_object_legal_hold(input)))
+    }
+    fn get_object_lock_configuration(
+        &self,
+        input: GetObjectLockConfigurationRequest,
+    ) -> RusotoFuture<GetObjectLockConfigurationOutput, GetObjectLockConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.get_object_lock_configuration(input)
+        }))
+    }
+    fn get_object_retention(
+        &self,
+        input: GetObjectRetentionRequest,
+    ) -> RusotoFuture<GetObjectRetentionOutput, GetObjectRetentionError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_object_retention(input)))
+    }
+    fn get_object_tagging(
+        &self,
+        input: GetObjectTaggingRequest,
+    ) -> RusotoFuture<GetObjectTaggingOutput, GetObjectTaggingError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_object_tagging(input)))
+    }
+    fn get_object_torrent(
+        &self,
+        input: GetObjectTorrentRequest,
+    ) -> RusotoFuture<GetObjectTorrentOutput, GetObjectTorrentError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_object_torrent(input)))
+    }
+    fn get_public_access_block(
+        &self,
+        input: GetPublicAccessBlockRequest,
+    ) -> RusotoFuture<GetPublicAccessBlockOutput, GetPublicAccessBlockError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_public_access_block(input)))
+    }
+    fn head_bucket(&self, input: HeadBucketRequest) -> RusotoFuture<(), HeadBucketError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.head_bucket(input)))
+    }
+    fn head_object(
+        &self,
+        input: HeadObjectRequest,
+    ) -> RusotoFuture<HeadObjectOutput, HeadObjectError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.head_object(input)))
+    }
+    fn list_bucket_analytics_configurations(
+        &self,
+        input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketAnalyticsConfigurationsOutput, ListBucketAnalyticsConfigurationsError>
+    {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.list_bucket_analytics_configurations(input)
+        }))
+    }
+    fn list_bucket_inventory_configurations(
+        &self,
+        input: ListBucketInventoryConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketInventoryConfigurationsOutput, ListBucketInventoryConfigurationsError>
+    {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.list_bucket_inventory_configurations(input)
+        }))
+    }
+    fn list_bucket_metrics_configurations(
+        &self,
+        input: ListBucketMetricsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketMetricsConfigurationsOutput, ListBucketMetricsConfigurationsError>
+    {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.list_bucket_metrics_configurations(input)
+        }))
+    }
+    fn list_buckets(&self) -> RusotoFuture<ListBucketsOutput, ListBucketsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_buckets()))
+    }
+    fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsRequest,
+    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_multipart_uploads(input)))
+    }
+    fn list_object_versions(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> RusotoFuture<ListObjectVersionsOutput, ListObjectVersionsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_object_versions(input)))
+    }
+    fn list_objects(
+        &self,
+        input: ListObjectsRequest,
+    ) -> RusotoFuture<ListObjectsOutput, ListObjectsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_objects(input)))
+    }
+    fn list_objects_v2(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> RusotoFuture<ListObjectsV2Output, ListObjectsV2Error> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_objects_v2(input)))
+    }
+    fn list_parts(&self, input: ListPartsRequest) -> RusotoFuture<ListPartsOutput, ListPartsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_parts(input)))
+    }
+    fn put_bucket_accelerate_configuration(
+        &self,
+        input: PutBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAccelerateConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.put_bucket_accelerate_configuration(input)
+        }))
+    }
+    fn put_bucket_acl(&self, input: PutBucketAclRequest) -> RusotoFuture<(), PutBucketAclError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_bucket_acl(input)))
+    }
+    fn put_bucket_analytics_configuration(
+        &self,
+        input: PutBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAnalyticsConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.put_bucket_analytics_configuration(input)
+        }))
+    }
+    fn put_bucket_cors(&self, input: PutBucketCorsRequest) -> RusotoFuture<(), PutBucketCorsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_bucket_cors(input)))
+    }
+    fn put_bucket_encryption(
+        &self,
+        input: PutBucketEncryptionRequest,
+    ) -> RusotoFuture<(), PutBucketEncryptionError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_bucket_encryption(input)))
+    }
+    fn put_bucket_inventory_configuration(
+        &self,
+        input: PutBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketInventoryConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.put_bucket_inventory_configuration(input)
+        }))
+    }
+    fn put_bucket_lifecycle(
+        &self,
+        input: PutBucketLifecycleRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_bucket_lifecycle(input)))
+    }
+    fn put_bucket_lifecycle_configuration(
+        &self,
+        input: PutBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.put_bucket_lifecycle_configuration(input)
+        }))
+    }
+    fn put_bucket_logging(
+        &self,
+        input: PutBucketLoggingRequest,
+    ) -> RusotoFuture<(), PutBucketLoggingError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_bucket_logging(input)))
+    }
+    fn put_bucket_metrics_configuration(
+        &self,
+        input: PutBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketMetricsConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.put_bucket_metrics_configuration(input)
+        }))
+    }
+    fn put_bucket_notification(
+        &self,
+        input: PutBucketNotificationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_bucket_notification(input)))
+    }
+    fn put_bucket_notification_configuration(
+        &self,
+        input: PutBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.put_bucket_notification_configuration(input)
+        }))
+    }
+    fn put_bucket_policy(
+        &self,
+        input: PutBucketPolicyRequest,
+    ) -> RusotoFuture<(), PutBucketPolicyError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_bucket_policy(input)))
+    }
+    fn put_bucket_replication(
+        &self,
+        input: PutBucketReplicationRequest,
+    ) -> RusotoFuture<(), PutBucketReplicationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_bucket_replication(input)))
+    }
+    fn put_bucket_request_payment(
+        &self,
+        input: PutBucketRequestPaymentRequest,
+    ) -> RusotoFuture<(), PutBucketRequestPaymentError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.put_bucket_request_payment(input)
+        }))
+    }
+    fn put_bucket_tagging(
+        &self,
+        input: PutBucketTaggingRequest,
+    ) -> RusotoFuture<(), PutBucketTaggingError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_bucket_tagging(input)))
+    }
+    fn put_bucket_versioning(
+        &self,
+        input: PutBucketVersioningRequest,
+    ) -> RusotoFuture<(), PutBucketVersioningError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_bucket_versioning(input)))
+    }
+    fn put_bucket_website(
+        &self,
+        input: PutBucketWebsiteRequest,
+    ) -> RusotoFuture<(), PutBucketWebsiteError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_bucket_website(input)))
+    }
+    fn put_object(&self, input: PutObjectRequest) -> RusotoFuture<PutObjectOutput, PutObjectError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_object(input)))
+    }
+    fn put_object_acl(
+        &self,
+        input: PutObjectAclRequest,
+    ) -> RusotoFuture<PutObjectAclOutput, PutObjectAclError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_object_acl(input)))
+    }
+    fn put_object_legal_hold(
+        &self,
+        input: PutObjectLegalHoldRequest,
+    ) -> RusotoFuture<PutObjectLegalHoldOutput, PutObjectLegalHoldError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_object_legal_hold(input)))
+    }
+    fn put_object_lock_configuration(
+        &self,
+        input: PutObjectLockConfigurationRequest,
+    ) -> RusotoFuture<PutObjectLockConfigurationOutput, PutObjectLockConfigurationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.put_object_lock_configuration(input)
+        }))
+    }
+    fn put_object_retention(
+        &self,
+        input: PutObjectRetentionRequest,
+    ) -> RusotoFuture<PutObjectRetentionOutput, PutObjectRetentionError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_object_retention(input)))
+    }
+    fn put_object_tagging(
+        &self,
+        input: PutObjectTaggingRequest,
+    ) -> RusotoFuture<PutObjectTaggingOutput, PutObjectTaggingError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_object_tagging(input)))
+    }
+    fn put_public_access_block(
+        &self,
+        input: PutPublicAccessBlockRequest,
+    ) -> RusotoFuture<(), PutPublicAccessBlockError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_public_access_block(input)))
+    }
+    fn restore_object(
+        &self,
+        input: RestoreObjectRequest,
+    ) -> RusotoFuture<RestoreObjectOutput, RestoreObjectError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.restore_object(input)))
+    }
+    fn select_object_content(
+        &self,
+        input: SelectObjectContentRequest,
+    ) -> RusotoFuture<SelectObjectContentOutput, SelectObjectContentError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.select_object_content(input)))
+    }
+    fn upload_part(
+        &self,
+        input: UploadPartRequest,
+    ) -> RusotoFuture<UploadPartOutput, UploadPartError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.upload_part(input)))
+    }
+    fn upload_part_copy(
+        &self,
+        input: UploadPartCopyRequest,
+    ) -> RusotoFuture<UploadPartCopyOutput, UploadPartCopyError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.upload_part_copy(input)))
+    }
+}
@@ -12,6 +12,7 @@
 // =================================================================
 
 #![doc(html_logo_url = "https://raw.githubusercontent.com/rusoto/rusoto/master/assets/logo-square.png")]
+#![cfg_attr(nightly, feature(test))]
 //! <p><p/></p>
 //!
 //! If you're using the service, you're probably looking for [S3Client](struct.S3Client.html) and [S3](trait.S3.html).
@@ -25,6 +26,8 @@ extern crate test;
 
 mod generated;
 mod custom;
+#[cfg(feature = "fake")]
+pub mod fake;
 
 pub use crate::generated::*;
 pub use crate::custom::*;
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct InvokeEndpointInput {
     /// <p>The desired MIME type of the inference in the response.</p>
@@ -49,6 +50,70 @@ pub struct InvokeEndpointInput {
     pub endpoint_name: String,
 }
 
+impl InvokeEndpointInput {
+    /// Returns a builder for `InvokeEndpointInput`, checking its required members when built.
+    pub fn builder() -> InvokeEndpointInputBuilder {
+        InvokeEndpointInputBuilder::default()
+    }
+}
+
+/// Builds a [`InvokeEndpointInput`](struct.InvokeEndpointInput.html).
+#[derive(Default, Debug)]
+pub struct InvokeEndpointInputBuilder {
+    accept: Option<String>,
+    body: Option<bytes::Bytes>,
+    content_type: Option<String>,
+    custom_attributes: Option<String>,
+    endpoint_name: Option<String>,
+}
+
+impl InvokeEndpointInputBuilder {
+    /// Sets the [`accept`](struct.InvokeEndpointInput.html#structfield.accept) member.
+    pub fn accept<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept = Some(value.into());
+        self
+    }
+
+    /// Sets the [`body`](struct.InvokeEndpointInput.html#structfield.body) member.
+    pub fn body<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_type`](struct.InvokeEndpointInput.html#structfield.content_type) member.
+    pub fn content_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_attributes`](struct.InvokeEndpointInput.html#structfield.custom_attributes) member.
+    pub fn custom_attributes<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_name`](struct.InvokeEndpointInput.html#structfield.endpoint_name) member.
+    pub fn endpoint_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `InvokeEndpointInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<InvokeEndpointInput, ::rusoto_core::BuildError> {
+        Ok(InvokeEndpointInput {
+            accept: self.accept,
+            body: self
+                .body
+                .ok_or(::rusoto_core::BuildError::missing("body"))?,
+            content_type: self.content_type,
+            custom_attributes: self.custom_attributes,
+            endpoint_name: self
+                .endpoint_name
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct InvokeEndpointOutput {
     /// <p>Includes the inference provided by the model.</p> <p>For information about the format of the response body, see <a href="http://docs.aws.amazon.com/sagemaker/latest/dg/cdf-inference.html">Common Data Formats—Inference</a>.</p>
@@ -120,6 +185,21 @@ pub trait SageMakerRuntime {
         input: InvokeEndpointInput,
     ) -> RusotoFuture<InvokeEndpointOutput, InvokeEndpointError>;
 }
+impl<T: ?Sized + SageMakerRuntime> SageMakerRuntime for ::std::sync::Arc<T> {
+    fn invoke_endpoint(
+        &self,
+        input: InvokeEndpointInput,
+    ) -> RusotoFuture<InvokeEndpointOutput, InvokeEndpointError> {
+        (**self).invoke_endpoint(input)
+    }
+}
+/// The error codes Amazon SageMaker Runtime retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon SageMaker Runtime API.
 #[derive(Clone)]
 pub struct SageMakerRuntimeClient {
@@ -135,6 +215,27 @@ impl SageMakerRuntimeClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SAGEMAKER_RUNTIME`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> SageMakerRuntimeClient {
+        Self::new(region::Region::from_env_for_service("SAGEMAKER_RUNTIME"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `runtime.sagemaker`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> SageMakerRuntimeClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("runtime.sagemaker", "SAGEMAKER_RUNTIME"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -153,7 +254,54 @@ impl SageMakerRuntimeClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SageMakerRuntimeClient {
-        SageMakerRuntimeClient { client, region }
+        SageMakerRuntimeClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "runtime.sagemaker")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "runtime.sagemaker",
+            "sagemaker",
+            "SAGEMAKER_RUNTIME",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `SageMakerRuntime` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn SageMakerRuntime + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for SageMakerRuntimeClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon SageMaker Runtime"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "runtime.sagemaker"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> SageMakerRuntimeClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon SageMaker Service clients.
+//!
+//! Makes a single read-only `ListAlgorithms` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_sagemaker::{ListAlgorithmsInput, SageMaker, SageMakerClient};
+
+fn main() {
+    let client = SageMakerClient::from_env();
+    match client
+        .list_algorithms(ListAlgorithmsInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListAlgorithms succeeded"),
+        Err(error) => {
+            eprintln!("ListAlgorithms failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+extern crate rusoto_mock;
+
+use std::sync::{Arc, Mutex};
+
+use crate::custom::transform::{TransformJobError, TransformJobExt};
+use crate::generated::{
+    CreateTransformJobRequest, S3DataType, SageMakerClient, TransformDataSource, TransformInput,
+    TransformInstanceType, TransformJobStatus, TransformOutput, TransformResources,
+    TransformS3DataSource,
+};
+
+use self::rusoto_mock::*;
+use rusoto_core::waiter::WaiterConfig;
+use rusoto_core::{Region, RusotoError};
+use std::time::Duration;
+
+#[test]
+fn dry_run_rejects_invalid_requests_without_calling_sagemaker() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_request_checker(|_| panic!("an invalid request must not be sent"));
+    let client = SageMakerClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let request = CreateTransformJobRequest {
+        batch_strategy: None,
+        data_processing: None,
+        environment: None,
+        max_concurrent_transforms: None,
+        max_payload_in_mb: None,
+        model_name: String::new(),
+        tags: None,
+        transform_input: TransformInput {
+            compression_type: None,
+            content_type: None,
+            data_source: TransformDataSource {
+                s3_data_source: TransformS3DataSource {
+                    s3_data_type: S3DataType::S3Prefix,
+                    s3_uri: String::new(),
+                },
+            },
+            split_type: None,
+        },
+        transform_job_name: String::new(),
+        transform_output: TransformOutput::default(),
+        transform_resources: TransformResources {
+            instance_count: 0,
+            instance_type: TransformInstanceType::MlM5Large,
+            volume_kms_key_id: None,
+        },
+    };
+
+    let result = client.dry_run_transform_job(&request).sync();
+    match result {
+        Err(RusotoError::Service(TransformJobError::InvalidRequest(_))) => {}
+        other => panic!("expected an invalid request, got {:?}", other),
+    }
+}
+
+#[test]
+fn wait_for_transform_job_reports_progress_until_completed() {
+    let body = r#"{
+        "CreationTime": 1570000000,
+        "ModelName": "my-model",
+        "TransformInput": {
+            "DataSource": {"S3DataSource": {"S3DataType": "S3Prefix", "S3Uri": "s3://bucket/in"}}
+        },
+        "TransformJobArn": "arn:aws:sagemaker:us-east-1:123456789012:transform-job/nightly",
+        "TransformJobName": "nightly",
+        "TransformJobStatus": "Completed",
+        "TransformResources": {"InstanceCount": 1, "InstanceType": "ml.m5.large"}
+    }"#;
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(body)
+        .with_request_checker(|request| {
+            assert_eq!(
+                request.headers.get("x-amz-target").unwrap()[0],
+                b"SageMaker.DescribeTransformJob".to_vec()
+            );
+        });
+    let client = SageMakerClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let statuses = Arc::new(Mutex::new(Vec::new()));
+    let seen = statuses.clone();
+
+    let job = client
+        .wait_for_transform_job(
+            "nightly",
+            WaiterConfig::new(Duration::from_millis(1), 3),
+            move |job| seen.lock().unwrap().push(job.transform_job_status.clone()),
+        )
+        .sync()
+        .unwrap();
+
+    assert_eq!(job.transform_job_name, "nightly");
+    assert_eq!(
+        *statuses.lock().unwrap(),
+        vec![TransformJobStatus::Completed]
+    );
+}
@@ -1 +1,5 @@
+/// Validating, running and waiting for batch transform jobs
+pub mod transform;
 
+#[cfg(test)]
+mod custom_tests;
@@ -0,0 +1,474 @@
+//! Helpers for running batch transform jobs.
+//!
+//! A batch transform job runs a model over a dataset in S3 and writes its predictions back to
+//! S3, using the model's execution role. Mistakes in the request, such as a malformed S3 URI or
+//! a missing model, often only surface once the job has been scheduled and failed. The methods
+//! of `TransformJobExt` check the request up front, optionally without creating anything, and
+//! wait for the job to finish while reporting its progress.
+
+use std::error::Error;
+use std::fmt;
+
+use futures::future::{self, Either, Loop};
+use futures::Future;
+use rusoto_core::waiter::{sleep, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    AssemblyType, BatchStrategy, CompressionType, CreateTransformJobError,
+    CreateTransformJobRequest, CreateTransformJobResponse, DescribeModelError, DescribeModelInput,
+    DescribeTransformJobError, DescribeTransformJobRequest, DescribeTransformJobResponse,
+    S3DataType, SageMaker, SplitType, TransformJobStatus,
+};
+
+/// Errors returned while creating a transform job or waiting for it to finish.
+#[derive(Debug, PartialEq)]
+pub enum TransformJobError {
+    /// The request is invalid, see `validate_transform_job`.
+    InvalidRequest(String),
+    /// Describing the job's model failed, usually because it doesn't exist.
+    DescribeModel(DescribeModelError),
+    /// Creating the job failed.
+    CreateTransformJob(CreateTransformJobError),
+    /// Describing the job while waiting failed.
+    DescribeTransformJob(DescribeTransformJobError),
+    /// The job failed, with the reason reported by SageMaker.
+    JobFailed(String),
+    /// The job was stopped before completing.
+    JobStopped(String),
+    /// The job did not finish within the configured number of attempts.
+    TimedOut(String),
+}
+
+impl fmt::Display for TransformJobError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for TransformJobError {
+    fn description(&self) -> &str {
+        match *self {
+            TransformJobError::InvalidRequest(ref cause) => cause,
+            TransformJobError::DescribeModel(ref cause) => cause.description(),
+            TransformJobError::CreateTransformJob(ref cause) => cause.description(),
+            TransformJobError::DescribeTransformJob(ref cause) => cause.description(),
+            TransformJobError::JobFailed(ref cause) => cause,
+            TransformJobError::JobStopped(ref cause) => cause,
+            TransformJobError::TimedOut(ref cause) => cause,
+        }
+    }
+}
+
+/// What a transform job would run with, as checked by `dry_run_transform_job`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransformJobPlan {
+    /// ARN of the model the job runs
+    pub model_arn: String,
+    /// ARN of the role the job reads its input and writes its output with
+    pub execution_role_arn: String,
+}
+
+/// Splits an `s3://bucket/key` URI into its bucket and key.
+fn parse_s3_uri(uri: &str) -> Option<(&str, &str)> {
+    let uri = uri.trim();
+    if !uri.starts_with("s3://") {
+        return None;
+    }
+    let path = &uri["s3://".len()..];
+    let (bucket, key) = match path.find('/') {
+        Some(index) => (&path[..index], &path[index + 1..]),
+        None => (path, ""),
+    };
+    if bucket.len() < 3 || bucket.len() > 63 {
+        return None;
+    }
+    let valid_bucket = bucket
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.');
+    if valid_bucket {
+        Some((bucket, key))
+    } else {
+        None
+    }
+}
+
+fn is_valid_job_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 63
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+fn is_valid_role_arn(arn: &str) -> bool {
+    let parts: Vec<&str> = arn.splitn(6, ':').collect();
+    parts.len() == 6
+        && parts[0] == "arn"
+        && parts[1].starts_with("aws")
+        && parts[2] == "iam"
+        && parts[4].len() == 12
+        && parts[5].starts_with("role/")
+}
+
+fn check_one_of(problems: &mut Vec<String>, field: &str, value: Option<&str>, allowed: &[&str]) {
+    if let Some(value) = value {
+        if !allowed.contains(&value) {
+            problems.push(format!(
+                "{} must be one of {}, not {}",
+                field,
+                allowed.join(", "),
+                value
+            ));
+        }
+    }
+}
+
+/// Checks a transform job request without sending it, returning the problems found.
+///
+/// This catches malformed job names and S3 URIs, unknown enumeration values, out of range
+/// limits, and an output path inside the input prefix, which would make the job's output part of
+/// the input of the next run.
+pub fn validate_transform_job(request: &CreateTransformJobRequest) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    if !is_valid_job_name(&request.transform_job_name) {
+        problems.push(format!(
+            "Job name {:?} must be 1 to 63 alphanumeric characters or hyphens, not starting or \
+             ending with a hyphen",
+            request.transform_job_name
+        ));
+    }
+    if request.model_name.is_empty() {
+        problems.push("Model name must not be empty".to_owned());
+    }
+
+    let data_source = &request.transform_input.data_source.s3_data_source;
+    let input = parse_s3_uri(&data_source.s3_uri);
+    if input.is_none() {
+        problems.push(format!(
+            "Input {:?} is not a valid s3://bucket/prefix URI",
+            data_source.s3_uri
+        ));
+    }
+    if let S3DataType::Unknown(_) = data_source.s3_data_type {
+        problems.push(format!(
+            "Input data type must be S3Prefix, ManifestFile or AugmentedManifestFile, not {}",
+            data_source.s3_data_type
+        ));
+    }
+    let output = parse_s3_uri(&request.transform_output.s3_output_path);
+    match (input, output) {
+        (_, None) => problems.push(format!(
+            "Output {:?} is not a valid s3://bucket/prefix URI",
+            request.transform_output.s3_output_path
+        )),
+        (Some((input_bucket, input_key)), Some((output_bucket, output_key)))
+            if data_source.s3_data_type == S3DataType::S3Prefix
+                && input_bucket == output_bucket
+                && output_key.starts_with(input_key) =>
+        {
+            problems.push(format!(
+                "Output {} is inside the input prefix {}",
+                request.transform_output.s3_output_path, data_source.s3_uri
+            ))
+        }
+        _ => {}
+    }
+
+    check_one_of(
+        &mut problems,
+        "Split type",
+        request
+            .transform_input
+            .split_type
+            .as_ref()
+            .map(SplitType::as_str),
+        &["None", "Line", "RecordIO", "TFRecord"],
+    );
+    check_one_of(
+        &mut problems,
+        "Compression type",
+        request
+            .transform_input
+            .compression_type
+            .as_ref()
+            .map(CompressionType::as_str),
+        &["None", "Gzip"],
+    );
+    check_one_of(
+        &mut problems,
+        "Batch strategy",
+        request.batch_strategy.as_ref().map(BatchStrategy::as_str),
+        &["MultiRecord", "SingleRecord"],
+    );
+    check_one_of(
+        &mut problems,
+        "Assemble with",
+        request
+            .transform_output
+            .assemble_with
+            .as_ref()
+            .map(AssemblyType::as_str),
+        &["None", "Line"],
+    );
+
+    if request.transform_resources.instance_count < 1 {
+        problems.push("Instance count must be at least 1".to_owned());
+    }
+    if request
+        .transform_resources
+        .instance_type
+        .as_str()
+        .is_empty()
+    {
+        problems.push("Instance type must not be empty".to_owned());
+    }
+    if let Some(max_payload) = request.max_payload_in_mb {
+        if max_payload < 0 || max_payload > 100 {
+            problems.push(format!(
+                "Max payload must be between 0 and 100 MB, not {}",
+                max_payload
+            ));
+        }
+    }
+    if request
+        .max_concurrent_transforms
+        .map_or(false, |max| max < 0)
+    {
+        problems.push("Max concurrent transforms must not be negative".to_owned());
+    }
+
+    problems
+}
+
+/// Extension methods for creating transform jobs and waiting for them.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::waiter::WaiterConfig;
+/// use rusoto_core::Region;
+/// use rusoto_sagemaker::transform::TransformJobExt;
+/// use rusoto_sagemaker::{CreateTransformJobRequest, SageMakerClient};
+///
+/// # fn request() -> CreateTransformJobRequest { unimplemented!() }
+/// # let request = request();
+/// let client = SageMakerClient::new(Region::UsEast1);
+/// let job = client
+///     .run_transform_job(request, WaiterConfig::default(), |job| {
+///         println!("{}: {}", job.transform_job_name, job.transform_job_status)
+///     })
+///     .sync()
+///     .unwrap();
+/// ```
+pub trait TransformJobExt: SageMaker + Clone + Send + Sized + 'static {
+    /// Checks a transform job request without creating the job.
+    ///
+    /// The request is validated with `validate_transform_job`, then the model is described to
+    /// make sure it exists and has a well formed execution role, which is what the job reads
+    /// and writes S3 with.
+    fn dry_run_transform_job(
+        &self,
+        request: &CreateTransformJobRequest,
+    ) -> RusotoFuture<TransformJobPlan, TransformJobError> {
+        let problems = validate_transform_job(request);
+        if !problems.is_empty() {
+            return RusotoFuture::from_future(future::err(RusotoError::Service(
+                TransformJobError::InvalidRequest(problems.join("; ")),
+            )));
+        }
+
+        let future = self
+            .describe_model(DescribeModelInput {
+                model_name: request.model_name.clone(),
+            })
+            .map_err(|e| e.map_service(TransformJobError::DescribeModel))
+            .and_then(|model| {
+                if is_valid_role_arn(&model.execution_role_arn) {
+                    Ok(TransformJobPlan {
+                        model_arn: model.model_arn,
+                        execution_role_arn: model.execution_role_arn,
+                    })
+                } else {
+                    Err(RusotoError::Service(TransformJobError::InvalidRequest(
+                        format!(
+                            "Model {} has an invalid execution role {:?}",
+                            model.model_name, model.execution_role_arn
+                        ),
+                    )))
+                }
+            });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Checks a transform job request as `dry_run_transform_job` does, then creates the job.
+    fn create_transform_job_checked(
+        &self,
+        request: CreateTransformJobRequest,
+    ) -> RusotoFuture<CreateTransformJobResponse, TransformJobError> {
+        let client = self.clone();
+
+        let future = self.dry_run_transform_job(&request).and_then(move |_| {
+            client
+                .create_transform_job(request)
+                .map_err(|e| e.map_service(TransformJobError::CreateTransformJob))
+        });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Polls `DescribeTransformJob` until the job completes, calling `on_progress` with every
+    /// description of the job.
+    ///
+    /// Fails if the job fails or is stopped.
+    fn wait_for_transform_job<F>(
+        &self,
+        transform_job_name: &str,
+        config: WaiterConfig,
+        on_progress: F,
+    ) -> RusotoFuture<DescribeTransformJobResponse, TransformJobError>
+    where
+        F: FnMut(&DescribeTransformJobResponse) + Send + 'static,
+    {
+        let state = (self.clone(), on_progress, 1);
+        let name = transform_job_name.to_owned();
+
+        let future = future::loop_fn(state, move |(client, mut on_progress, attempts)| {
+            let config = config.clone();
+            client
+                .describe_transform_job(DescribeTransformJobRequest {
+                    transform_job_name: name.clone(),
+                })
+                .map_err(|e| e.map_service(TransformJobError::DescribeTransformJob))
+                .and_then(move |job| {
+                    on_progress(&job);
+                    let error =
+                        match job.transform_job_status {
+                            TransformJobStatus::Completed => {
+                                return Either::A(future::ok(Loop::Break(job)))
+                            }
+                            TransformJobStatus::Failed => TransformJobError::JobFailed(format!(
+                                "Transform job {} failed: {}",
+                                job.transform_job_name,
+                                job.failure_reason.unwrap_or_default()
+                            )),
+                            TransformJobStatus::Stopped => TransformJobError::JobStopped(format!(
+                                "Transform job {} was stopped",
+                                job.transform_job_name
+                            )),
+                            _ if attempts >= config.max_attempts => TransformJobError::TimedOut(
+                                format!("Transform job {} did not finish", job.transform_job_name),
+                            ),
+                            _ => {
+                                return Either::B(sleep(config.delay).map(move |_| {
+                                    Loop::Continue((client, on_progress, attempts + 1))
+                                }))
+                            }
+                        };
+                    Either::A(future::err(RusotoError::Service(error)))
+                })
+        });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Checks and creates a transform job, then waits for it to complete.
+    fn run_transform_job<F>(
+        &self,
+        request: CreateTransformJobRequest,
+        config: WaiterConfig,
+        on_progress: F,
+    ) -> RusotoFuture<DescribeTransformJobResponse, TransformJobError>
+    where
+        F: FnMut(&DescribeTransformJobResponse) + Send + 'static,
+    {
+        let client = self.clone();
+        let name = request.transform_job_name.clone();
+
+        let future = self
+            .create_transform_job_checked(request)
+            .and_then(move |_| client.wait_for_transform_job(&name, config, on_progress));
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: SageMaker + Clone + Send + 'static> TransformJobExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::{
+        TransformDataSource, TransformInput, TransformInstanceType, TransformOutput,
+        TransformResources, TransformS3DataSource,
+    };
+
+    fn request(input: &str, output: &str) -> CreateTransformJobRequest {
+        CreateTransformJobRequest {
+            model_name: "my-model".to_owned(),
+            transform_job_name: "nightly-scoring-1".to_owned(),
+            transform_input: TransformInput {
+                compression_type: None,
+                content_type: None,
+                data_source: TransformDataSource {
+                    s3_data_source: TransformS3DataSource {
+                        s3_data_type: S3DataType::S3Prefix,
+                        s3_uri: input.to_owned(),
+                    },
+                },
+                split_type: None,
+            },
+            transform_output: TransformOutput {
+                s3_output_path: output.to_owned(),
+                ..Default::default()
+            },
+            transform_resources: TransformResources {
+                instance_count: 1,
+                instance_type: TransformInstanceType::MlM5Large,
+                volume_kms_key_id: None,
+            },
+            batch_strategy: None,
+            data_processing: None,
+            environment: None,
+            max_concurrent_transforms: None,
+            max_payload_in_mb: None,
+            tags: None,
+        }
+    }
+
+    #[test]
+    fn accepts_valid_requests() {
+        let request = request("s3://my-bucket/input/", "s3://my-bucket/output/");
+        assert_eq!(validate_transform_job(&request), Vec::<String>::new());
+    }
+
+    #[test]
+    fn reports_every_problem() {
+        let mut request = request("https://my-bucket/input", "s3://my-bucket/output/");
+        request.transform_job_name = "-nightly".to_owned();
+        request.transform_input.split_type = Some(SplitType::Unknown("Lines".to_owned()));
+        request.transform_resources.instance_count = 0;
+
+        let problems = validate_transform_job(&request);
+        assert_eq!(problems.len(), 4, "{:?}", problems);
+    }
+
+    #[test]
+    fn rejects_output_inside_input_prefix() {
+        let request = request("s3://my-bucket/data", "s3://my-bucket/data/predictions");
+        let problems = validate_transform_job(&request);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("inside the input prefix"));
+    }
+
+    #[test]
+    fn validates_role_arns() {
+        assert!(is_valid_role_arn(
+            "arn:aws:iam::123456789012:role/service-role/SageMakerRole"
+        ));
+        assert!(!is_valid_role_arn("arn:aws:iam::123456789012:user/alice"));
+        assert!(!is_valid_role_arn("SageMakerRole"));
+    }
+}
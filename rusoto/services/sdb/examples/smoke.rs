@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon SimpleDB clients.
+//!
+//! Makes a single read-only `ListDomains` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_sdb::{ListDomainsRequest, SimpleDb, SimpleDbClient};
+
+fn main() {
+    let client = SimpleDbClient::from_env();
+    match client.list_domains(ListDomainsRequest::default()).sync() {
+        Ok(_) => println!("ListDomains succeeded"),
+        Err(error) => {
+            eprintln!("ListDomains failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
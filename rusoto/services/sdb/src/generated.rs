@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -46,10 +47,10 @@ pub struct Attribute {
     pub value: String,
 }
 
-struct AttributeDeserializer;
+pub(crate) struct AttributeDeserializer;
 impl AttributeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Attribute, XmlParseError> {
@@ -106,10 +107,10 @@ impl AttributeSerializer {
     }
 }
 
-struct AttributeListDeserializer;
+pub(crate) struct AttributeListDeserializer;
 impl AttributeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Attribute>, XmlParseError> {
@@ -164,6 +165,48 @@ pub struct BatchDeleteAttributesRequest {
     pub items: Vec<DeletableItem>,
 }
 
+impl BatchDeleteAttributesRequest {
+    /// Returns a builder for `BatchDeleteAttributesRequest`, checking its required members when built.
+    pub fn builder() -> BatchDeleteAttributesRequestBuilder {
+        BatchDeleteAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDeleteAttributesRequest`](struct.BatchDeleteAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDeleteAttributesRequestBuilder {
+    domain_name: Option<String>,
+    items: Option<Vec<DeletableItem>>,
+}
+
+impl BatchDeleteAttributesRequestBuilder {
+    /// Sets the [`domain_name`](struct.BatchDeleteAttributesRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`items`](struct.BatchDeleteAttributesRequest.html#structfield.items) member.
+    pub fn items<T: Into<Vec<DeletableItem>>>(mut self, value: T) -> Self {
+        self.items = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDeleteAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDeleteAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDeleteAttributesRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            items: self
+                .items
+                .ok_or(::rusoto_core::BuildError::missing("items"))?,
+        })
+    }
+}
+
 /// Serialize `BatchDeleteAttributesRequest` contents to a `SignedRequest`.
 struct BatchDeleteAttributesRequestSerializer;
 impl BatchDeleteAttributesRequestSerializer {
@@ -190,6 +233,48 @@ pub struct BatchPutAttributesRequest {
     pub items: Vec<ReplaceableItem>,
 }
 
+impl BatchPutAttributesRequest {
+    /// Returns a builder for `BatchPutAttributesRequest`, checking its required members when built.
+    pub fn builder() -> BatchPutAttributesRequestBuilder {
+        BatchPutAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchPutAttributesRequest`](struct.BatchPutAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchPutAttributesRequestBuilder {
+    domain_name: Option<String>,
+    items: Option<Vec<ReplaceableItem>>,
+}
+
+impl BatchPutAttributesRequestBuilder {
+    /// Sets the [`domain_name`](struct.BatchPutAttributesRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`items`](struct.BatchPutAttributesRequest.html#structfield.items) member.
+    pub fn items<T: Into<Vec<ReplaceableItem>>>(mut self, value: T) -> Self {
+        self.items = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchPutAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchPutAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(BatchPutAttributesRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            items: self
+                .items
+                .ok_or(::rusoto_core::BuildError::missing("items"))?,
+        })
+    }
+}
+
 /// Serialize `BatchPutAttributesRequest` contents to a `SignedRequest`.
 struct BatchPutAttributesRequestSerializer;
 impl BatchPutAttributesRequestSerializer {
@@ -214,6 +299,36 @@ pub struct CreateDomainRequest {
     pub domain_name: String,
 }
 
+impl CreateDomainRequest {
+    /// Returns a builder for `CreateDomainRequest`, checking its required members when built.
+    pub fn builder() -> CreateDomainRequestBuilder {
+        CreateDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDomainRequest`](struct.CreateDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDomainRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl CreateDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.CreateDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDomainRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateDomainRequest` contents to a `SignedRequest`.
 struct CreateDomainRequestSerializer;
 impl CreateDomainRequestSerializer {
@@ -276,6 +391,64 @@ pub struct DeleteAttributesRequest {
     pub item_name: String,
 }
 
+impl DeleteAttributesRequest {
+    /// Returns a builder for `DeleteAttributesRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAttributesRequestBuilder {
+        DeleteAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAttributesRequest`](struct.DeleteAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAttributesRequestBuilder {
+    attributes: Option<Vec<Attribute>>,
+    domain_name: Option<String>,
+    expected: Option<UpdateCondition>,
+    item_name: Option<String>,
+}
+
+impl DeleteAttributesRequestBuilder {
+    /// Sets the [`attributes`](struct.DeleteAttributesRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<Attribute>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DeleteAttributesRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expected`](struct.DeleteAttributesRequest.html#structfield.expected) member.
+    pub fn expected<T: Into<UpdateCondition>>(mut self, value: T) -> Self {
+        self.expected = Some(value.into());
+        self
+    }
+
+    /// Sets the [`item_name`](struct.DeleteAttributesRequest.html#structfield.item_name) member.
+    pub fn item_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.item_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAttributesRequest {
+            attributes: self.attributes,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            expected: self.expected,
+            item_name: self
+                .item_name
+                .ok_or(::rusoto_core::BuildError::missing("item_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteAttributesRequest` contents to a `SignedRequest`.
 struct DeleteAttributesRequestSerializer;
 impl DeleteAttributesRequestSerializer {
@@ -310,6 +483,36 @@ pub struct DeleteDomainRequest {
     pub domain_name: String,
 }
 
+impl DeleteDomainRequest {
+    /// Returns a builder for `DeleteDomainRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDomainRequestBuilder {
+        DeleteDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDomainRequest`](struct.DeleteDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDomainRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl DeleteDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.DeleteDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDomainRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteDomainRequest` contents to a `SignedRequest`.
 struct DeleteDomainRequestSerializer;
 impl DeleteDomainRequestSerializer {
@@ -329,6 +532,36 @@ pub struct DomainMetadataRequest {
     pub domain_name: String,
 }
 
+impl DomainMetadataRequest {
+    /// Returns a builder for `DomainMetadataRequest`, checking its required members when built.
+    pub fn builder() -> DomainMetadataRequestBuilder {
+        DomainMetadataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DomainMetadataRequest`](struct.DomainMetadataRequest.html).
+#[derive(Default, Debug)]
+pub struct DomainMetadataRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl DomainMetadataRequestBuilder {
+    /// Sets the [`domain_name`](struct.DomainMetadataRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DomainMetadataRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DomainMetadataRequest, ::rusoto_core::BuildError> {
+        Ok(DomainMetadataRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `DomainMetadataRequest` contents to a `SignedRequest`.
 struct DomainMetadataRequestSerializer;
 impl DomainMetadataRequestSerializer {
@@ -360,10 +593,10 @@ pub struct DomainMetadataResult {
     pub timestamp: Option<i64>,
 }
 
-struct DomainMetadataResultDeserializer;
+pub(crate) struct DomainMetadataResultDeserializer;
 impl DomainMetadataResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DomainMetadataResult, XmlParseError> {
@@ -409,10 +642,10 @@ impl DomainMetadataResultDeserializer {
         })
     }
 }
-struct DomainNameListDeserializer;
+pub(crate) struct DomainNameListDeserializer;
 impl DomainNameListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -448,6 +681,62 @@ pub struct GetAttributesRequest {
     pub item_name: String,
 }
 
+impl GetAttributesRequest {
+    /// Returns a builder for `GetAttributesRequest`, checking its required members when built.
+    pub fn builder() -> GetAttributesRequestBuilder {
+        GetAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAttributesRequest`](struct.GetAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAttributesRequestBuilder {
+    attribute_names: Option<Vec<String>>,
+    consistent_read: Option<bool>,
+    domain_name: Option<String>,
+    item_name: Option<String>,
+}
+
+impl GetAttributesRequestBuilder {
+    /// Sets the [`attribute_names`](struct.GetAttributesRequest.html#structfield.attribute_names) member.
+    pub fn attribute_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`consistent_read`](struct.GetAttributesRequest.html#structfield.consistent_read) member.
+    pub fn consistent_read<T: Into<bool>>(mut self, value: T) -> Self {
+        self.consistent_read = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.GetAttributesRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`item_name`](struct.GetAttributesRequest.html#structfield.item_name) member.
+    pub fn item_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.item_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(GetAttributesRequest {
+            attribute_names: self.attribute_names,
+            consistent_read: self.consistent_read,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            item_name: self
+                .item_name
+                .ok_or(::rusoto_core::BuildError::missing("item_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetAttributesRequest` contents to a `SignedRequest`.
 struct GetAttributesRequestSerializer;
 impl GetAttributesRequestSerializer {
@@ -478,10 +767,10 @@ pub struct GetAttributesResult {
     pub attributes: Option<Vec<Attribute>>,
 }
 
-struct GetAttributesResultDeserializer;
+pub(crate) struct GetAttributesResultDeserializer;
 impl GetAttributesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetAttributesResult, XmlParseError> {
@@ -498,10 +787,13 @@ impl GetAttributesResultDeserializer {
         })
     }
 }
-struct IntegerDeserializer;
+pub(crate) struct IntegerDeserializer;
 impl IntegerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -520,10 +812,13 @@ pub struct Item {
     pub name: String,
 }
 
-struct ItemDeserializer;
+pub(crate) struct ItemDeserializer;
 impl ItemDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Item, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Item, XmlParseError> {
         deserialize_elements::<_, Item, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "AlternateNameEncoding" => {
@@ -545,10 +840,10 @@ impl ItemDeserializer {
         })
     }
 }
-struct ItemListDeserializer;
+pub(crate) struct ItemListDeserializer;
 impl ItemListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Item>, XmlParseError> {
@@ -580,6 +875,42 @@ pub struct ListDomainsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDomainsRequest {
+    /// Returns a builder for `ListDomainsRequest`, checking its required members when built.
+    pub fn builder() -> ListDomainsRequestBuilder {
+        ListDomainsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDomainsRequest`](struct.ListDomainsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDomainsRequestBuilder {
+    max_number_of_domains: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDomainsRequestBuilder {
+    /// Sets the [`max_number_of_domains`](struct.ListDomainsRequest.html#structfield.max_number_of_domains) member.
+    pub fn max_number_of_domains<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_number_of_domains = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDomainsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDomainsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDomainsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDomainsRequest {
+            max_number_of_domains: self.max_number_of_domains,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListDomainsRequest` contents to a `SignedRequest`.
 struct ListDomainsRequestSerializer;
 impl ListDomainsRequestSerializer {
@@ -606,10 +937,10 @@ pub struct ListDomainsResult {
     pub next_token: Option<String>,
 }
 
-struct ListDomainsResultDeserializer;
+pub(crate) struct ListDomainsResultDeserializer;
 impl ListDomainsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListDomainsResult, XmlParseError> {
@@ -629,10 +960,13 @@ impl ListDomainsResultDeserializer {
         })
     }
 }
-struct LongDeserializer;
+pub(crate) struct LongDeserializer;
 impl LongDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -652,6 +986,64 @@ pub struct PutAttributesRequest {
     pub item_name: String,
 }
 
+impl PutAttributesRequest {
+    /// Returns a builder for `PutAttributesRequest`, checking its required members when built.
+    pub fn builder() -> PutAttributesRequestBuilder {
+        PutAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutAttributesRequest`](struct.PutAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct PutAttributesRequestBuilder {
+    attributes: Option<Vec<ReplaceableAttribute>>,
+    domain_name: Option<String>,
+    expected: Option<UpdateCondition>,
+    item_name: Option<String>,
+}
+
+impl PutAttributesRequestBuilder {
+    /// Sets the [`attributes`](struct.PutAttributesRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<ReplaceableAttribute>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.PutAttributesRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expected`](struct.PutAttributesRequest.html#structfield.expected) member.
+    pub fn expected<T: Into<UpdateCondition>>(mut self, value: T) -> Self {
+        self.expected = Some(value.into());
+        self
+    }
+
+    /// Sets the [`item_name`](struct.PutAttributesRequest.html#structfield.item_name) member.
+    pub fn item_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.item_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(PutAttributesRequest {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            expected: self.expected,
+            item_name: self
+                .item_name
+                .ok_or(::rusoto_core::BuildError::missing("item_name"))?,
+        })
+    }
+}
+
 /// Serialize `PutAttributesRequest` contents to a `SignedRequest`.
 struct PutAttributesRequestSerializer;
 impl PutAttributesRequestSerializer {
@@ -765,6 +1157,52 @@ pub struct SelectRequest {
     pub select_expression: String,
 }
 
+impl SelectRequest {
+    /// Returns a builder for `SelectRequest`, checking its required members when built.
+    pub fn builder() -> SelectRequestBuilder {
+        SelectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SelectRequest`](struct.SelectRequest.html).
+#[derive(Default, Debug)]
+pub struct SelectRequestBuilder {
+    consistent_read: Option<bool>,
+    next_token: Option<String>,
+    select_expression: Option<String>,
+}
+
+impl SelectRequestBuilder {
+    /// Sets the [`consistent_read`](struct.SelectRequest.html#structfield.consistent_read) member.
+    pub fn consistent_read<T: Into<bool>>(mut self, value: T) -> Self {
+        self.consistent_read = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SelectRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`select_expression`](struct.SelectRequest.html#structfield.select_expression) member.
+    pub fn select_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.select_expression = Some(value.into());
+        self
+    }
+
+    /// Builds the `SelectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SelectRequest, ::rusoto_core::BuildError> {
+        Ok(SelectRequest {
+            consistent_read: self.consistent_read,
+            next_token: self.next_token,
+            select_expression: self
+                .select_expression
+                .ok_or(::rusoto_core::BuildError::missing("select_expression"))?,
+        })
+    }
+}
+
 /// Serialize `SelectRequest` contents to a `SignedRequest`.
 struct SelectRequestSerializer;
 impl SelectRequestSerializer {
@@ -795,10 +1233,10 @@ pub struct SelectResult {
     pub next_token: Option<String>,
 }
 
-struct SelectResultDeserializer;
+pub(crate) struct SelectResultDeserializer;
 impl SelectResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SelectResult, XmlParseError> {
@@ -818,10 +1256,13 @@ impl SelectResultDeserializer {
         })
     }
 }
-struct StringDeserializer;
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1631,6 +2072,63 @@ pub trait SimpleDb {
     /// <p> The <code>Select</code> operation returns a set of attributes for <code>ItemNames</code> that match the select expression. <code>Select</code> is similar to the standard SQL SELECT statement. </p> <p> The total size of the response cannot exceed 1 MB in total size. Amazon SimpleDB automatically adjusts the number of items returned per page to enforce this limit. For example, if the client asks to retrieve 2500 items, but each individual item is 10 kB in size, the system returns 100 items and an appropriate <code>NextToken</code> so the client can access the next page of results. </p> <p> For information on how to construct select expressions, see Using Select to Create Amazon SimpleDB Queries in the Developer Guide. </p>
     fn select(&self, input: SelectRequest) -> RusotoFuture<SelectResult, SelectError>;
 }
+impl<T: ?Sized + SimpleDb> SimpleDb for ::std::sync::Arc<T> {
+    fn batch_delete_attributes(
+        &self,
+        input: BatchDeleteAttributesRequest,
+    ) -> RusotoFuture<(), BatchDeleteAttributesError> {
+        (**self).batch_delete_attributes(input)
+    }
+    fn batch_put_attributes(
+        &self,
+        input: BatchPutAttributesRequest,
+    ) -> RusotoFuture<(), BatchPutAttributesError> {
+        (**self).batch_put_attributes(input)
+    }
+    fn create_domain(&self, input: CreateDomainRequest) -> RusotoFuture<(), CreateDomainError> {
+        (**self).create_domain(input)
+    }
+    fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> RusotoFuture<(), DeleteAttributesError> {
+        (**self).delete_attributes(input)
+    }
+    fn delete_domain(&self, input: DeleteDomainRequest) -> RusotoFuture<(), DeleteDomainError> {
+        (**self).delete_domain(input)
+    }
+    fn domain_metadata(
+        &self,
+        input: DomainMetadataRequest,
+    ) -> RusotoFuture<DomainMetadataResult, DomainMetadataError> {
+        (**self).domain_metadata(input)
+    }
+    fn get_attributes(
+        &self,
+        input: GetAttributesRequest,
+    ) -> RusotoFuture<GetAttributesResult, GetAttributesError> {
+        (**self).get_attributes(input)
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResult, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn put_attributes(&self, input: PutAttributesRequest) -> RusotoFuture<(), PutAttributesError> {
+        (**self).put_attributes(input)
+    }
+    fn select(&self, input: SelectRequest) -> RusotoFuture<SelectResult, SelectError> {
+        (**self).select(input)
+    }
+}
+/// The error codes Amazon SimpleDB retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon SimpleDB API.
 #[derive(Clone)]
 pub struct SimpleDbClient {
@@ -1646,6 +2144,24 @@ impl SimpleDbClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SDB`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> SimpleDbClient {
+        Self::new(region::Region::from_env_for_service("SDB"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `sdb`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> SimpleDbClient {
+        Self::new_with_client(config.client().clone(), config.region_for("sdb", "SDB"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1664,7 +2180,49 @@ impl SimpleDbClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SimpleDbClient {
-        SimpleDbClient { client, region }
+        SimpleDbClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "sdb")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "sdb", "sdb", "SDB")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `SimpleDb` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn SimpleDb + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for SimpleDbClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon SimpleDB"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "sdb"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> SimpleDbClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Secrets Manager clients.
+//!
+//! Makes a single read-only `ListSecrets` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_secretsmanager::{ListSecretsRequest, SecretsManager, SecretsManagerClient};
+
+fn main() {
+    let client = SecretsManagerClient::from_env();
+    match client.list_secrets(ListSecretsRequest::default()).sync() {
+        Ok(_) => println!("ListSecrets succeeded"),
+        Err(error) => {
+            eprintln!("ListSecrets failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
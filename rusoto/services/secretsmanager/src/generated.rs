@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CancelRotateSecretRequest {
@@ -30,6 +31,38 @@ pub struct CancelRotateSecretRequest {
     pub secret_id: String,
 }
 
+impl CancelRotateSecretRequest {
+    /// Returns a builder for `CancelRotateSecretRequest`, checking its required members when built.
+    pub fn builder() -> CancelRotateSecretRequestBuilder {
+        CancelRotateSecretRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelRotateSecretRequest`](struct.CancelRotateSecretRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelRotateSecretRequestBuilder {
+    secret_id: Option<String>,
+}
+
+impl CancelRotateSecretRequestBuilder {
+    /// Sets the [`secret_id`](struct.CancelRotateSecretRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelRotateSecretRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CancelRotateSecretRequest, ::rusoto_core::BuildError> {
+        Ok(CancelRotateSecretRequest {
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelRotateSecretResponse {
@@ -83,6 +116,84 @@ pub struct CreateSecretRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateSecretRequest {
+    /// Returns a builder for `CreateSecretRequest`, checking its required members when built.
+    pub fn builder() -> CreateSecretRequestBuilder {
+        CreateSecretRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSecretRequest`](struct.CreateSecretRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSecretRequestBuilder {
+    client_request_token: Option<String>,
+    description: Option<String>,
+    kms_key_id: Option<String>,
+    name: Option<String>,
+    secret_binary: Option<bytes::Bytes>,
+    secret_string: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateSecretRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateSecretRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateSecretRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateSecretRequest.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateSecretRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_binary`](struct.CreateSecretRequest.html#structfield.secret_binary) member.
+    pub fn secret_binary<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.secret_binary = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_string`](struct.CreateSecretRequest.html#structfield.secret_string) member.
+    pub fn secret_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateSecretRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSecretRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateSecretRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSecretRequest {
+            client_request_token: self.client_request_token,
+            description: self.description,
+            kms_key_id: self.kms_key_id,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            secret_binary: self.secret_binary,
+            secret_string: self.secret_string,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSecretResponse {
@@ -107,6 +218,38 @@ pub struct DeleteResourcePolicyRequest {
     pub secret_id: String,
 }
 
+impl DeleteResourcePolicyRequest {
+    /// Returns a builder for `DeleteResourcePolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteResourcePolicyRequestBuilder {
+        DeleteResourcePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteResourcePolicyRequest`](struct.DeleteResourcePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteResourcePolicyRequestBuilder {
+    secret_id: Option<String>,
+}
+
+impl DeleteResourcePolicyRequestBuilder {
+    /// Sets the [`secret_id`](struct.DeleteResourcePolicyRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteResourcePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteResourcePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteResourcePolicyRequest {
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteResourcePolicyResponse {
@@ -135,6 +278,52 @@ pub struct DeleteSecretRequest {
     pub secret_id: String,
 }
 
+impl DeleteSecretRequest {
+    /// Returns a builder for `DeleteSecretRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSecretRequestBuilder {
+        DeleteSecretRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSecretRequest`](struct.DeleteSecretRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSecretRequestBuilder {
+    force_delete_without_recovery: Option<bool>,
+    recovery_window_in_days: Option<i64>,
+    secret_id: Option<String>,
+}
+
+impl DeleteSecretRequestBuilder {
+    /// Sets the [`force_delete_without_recovery`](struct.DeleteSecretRequest.html#structfield.force_delete_without_recovery) member.
+    pub fn force_delete_without_recovery<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_delete_without_recovery = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recovery_window_in_days`](struct.DeleteSecretRequest.html#structfield.recovery_window_in_days) member.
+    pub fn recovery_window_in_days<T: Into<i64>>(mut self, value: T) -> Self {
+        self.recovery_window_in_days = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_id`](struct.DeleteSecretRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSecretRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteSecretRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSecretRequest {
+            force_delete_without_recovery: self.force_delete_without_recovery,
+            recovery_window_in_days: self.recovery_window_in_days,
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSecretResponse {
@@ -144,8 +333,9 @@ pub struct DeleteSecretResponse {
     pub arn: Option<String>,
     /// <p>The date and time after which this secret can be deleted by Secrets Manager and can no longer be restored. This value is the date and time of the delete request plus the number of days specified in <code>RecoveryWindowInDays</code>.</p>
     #[serde(rename = "DeletionDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_date: Option<f64>,
+    pub deletion_date: Option<EpochTimestamp>,
     /// <p>The friendly name of the secret that is now scheduled for deletion.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -159,6 +349,36 @@ pub struct DescribeSecretRequest {
     pub secret_id: String,
 }
 
+impl DescribeSecretRequest {
+    /// Returns a builder for `DescribeSecretRequest`, checking its required members when built.
+    pub fn builder() -> DescribeSecretRequestBuilder {
+        DescribeSecretRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSecretRequest`](struct.DescribeSecretRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeSecretRequestBuilder {
+    secret_id: Option<String>,
+}
+
+impl DescribeSecretRequestBuilder {
+    /// Sets the [`secret_id`](struct.DescribeSecretRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSecretRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeSecretRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeSecretRequest {
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeSecretResponse {
@@ -168,8 +388,9 @@ pub struct DescribeSecretResponse {
     pub arn: Option<String>,
     /// <p>This value exists if the secret is scheduled for deletion. Some time after the specified date and time, Secrets Manager deletes the secret and all of its versions.</p> <p>If a secret is scheduled for deletion, then its details, including the encrypted secret information, is not accessible. To cancel a scheduled deletion and restore access, use <a>RestoreSecret</a>.</p>
     #[serde(rename = "DeletedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deleted_date: Option<f64>,
+    pub deleted_date: Option<EpochTimestamp>,
     /// <p>The user-provided description of the secret.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -180,16 +401,19 @@ pub struct DescribeSecretResponse {
     pub kms_key_id: Option<String>,
     /// <p>The last date that this secret was accessed. This value is truncated to midnight of the date and therefore shows only the date, not the time.</p>
     #[serde(rename = "LastAccessedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_accessed_date: Option<f64>,
+    pub last_accessed_date: Option<EpochTimestamp>,
     /// <p>The last date and time that this secret was modified in any way.</p>
     #[serde(rename = "LastChangedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_changed_date: Option<f64>,
+    pub last_changed_date: Option<EpochTimestamp>,
     /// <p>The most recent date and time that the Secrets Manager rotation process was successfully completed. This value is null if the secret has never rotated.</p>
     #[serde(rename = "LastRotatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_rotated_date: Option<f64>,
+    pub last_rotated_date: Option<EpochTimestamp>,
     /// <p>The user-provided friendly name of the secret.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -255,6 +479,92 @@ pub struct GetRandomPasswordRequest {
     pub require_each_included_type: Option<bool>,
 }
 
+impl GetRandomPasswordRequest {
+    /// Returns a builder for `GetRandomPasswordRequest`, checking its required members when built.
+    pub fn builder() -> GetRandomPasswordRequestBuilder {
+        GetRandomPasswordRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRandomPasswordRequest`](struct.GetRandomPasswordRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRandomPasswordRequestBuilder {
+    exclude_characters: Option<String>,
+    exclude_lowercase: Option<bool>,
+    exclude_numbers: Option<bool>,
+    exclude_punctuation: Option<bool>,
+    exclude_uppercase: Option<bool>,
+    include_space: Option<bool>,
+    password_length: Option<i64>,
+    require_each_included_type: Option<bool>,
+}
+
+impl GetRandomPasswordRequestBuilder {
+    /// Sets the [`exclude_characters`](struct.GetRandomPasswordRequest.html#structfield.exclude_characters) member.
+    pub fn exclude_characters<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclude_characters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclude_lowercase`](struct.GetRandomPasswordRequest.html#structfield.exclude_lowercase) member.
+    pub fn exclude_lowercase<T: Into<bool>>(mut self, value: T) -> Self {
+        self.exclude_lowercase = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclude_numbers`](struct.GetRandomPasswordRequest.html#structfield.exclude_numbers) member.
+    pub fn exclude_numbers<T: Into<bool>>(mut self, value: T) -> Self {
+        self.exclude_numbers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclude_punctuation`](struct.GetRandomPasswordRequest.html#structfield.exclude_punctuation) member.
+    pub fn exclude_punctuation<T: Into<bool>>(mut self, value: T) -> Self {
+        self.exclude_punctuation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclude_uppercase`](struct.GetRandomPasswordRequest.html#structfield.exclude_uppercase) member.
+    pub fn exclude_uppercase<T: Into<bool>>(mut self, value: T) -> Self {
+        self.exclude_uppercase = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_space`](struct.GetRandomPasswordRequest.html#structfield.include_space) member.
+    pub fn include_space<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_space = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password_length`](struct.GetRandomPasswordRequest.html#structfield.password_length) member.
+    pub fn password_length<T: Into<i64>>(mut self, value: T) -> Self {
+        self.password_length = Some(value.into());
+        self
+    }
+
+    /// Sets the [`require_each_included_type`](struct.GetRandomPasswordRequest.html#structfield.require_each_included_type) member.
+    pub fn require_each_included_type<T: Into<bool>>(mut self, value: T) -> Self {
+        self.require_each_included_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRandomPasswordRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRandomPasswordRequest, ::rusoto_core::BuildError> {
+        Ok(GetRandomPasswordRequest {
+            exclude_characters: self.exclude_characters,
+            exclude_lowercase: self.exclude_lowercase,
+            exclude_numbers: self.exclude_numbers,
+            exclude_punctuation: self.exclude_punctuation,
+            exclude_uppercase: self.exclude_uppercase,
+            include_space: self.include_space,
+            password_length: self.password_length,
+            require_each_included_type: self.require_each_included_type,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRandomPasswordResponse {
@@ -271,6 +581,38 @@ pub struct GetResourcePolicyRequest {
     pub secret_id: String,
 }
 
+impl GetResourcePolicyRequest {
+    /// Returns a builder for `GetResourcePolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetResourcePolicyRequestBuilder {
+        GetResourcePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourcePolicyRequest`](struct.GetResourcePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourcePolicyRequestBuilder {
+    secret_id: Option<String>,
+}
+
+impl GetResourcePolicyRequestBuilder {
+    /// Sets the [`secret_id`](struct.GetResourcePolicyRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourcePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetResourcePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourcePolicyRequest {
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetResourcePolicyResponse {
@@ -303,6 +645,52 @@ pub struct GetSecretValueRequest {
     pub version_stage: Option<String>,
 }
 
+impl GetSecretValueRequest {
+    /// Returns a builder for `GetSecretValueRequest`, checking its required members when built.
+    pub fn builder() -> GetSecretValueRequestBuilder {
+        GetSecretValueRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSecretValueRequest`](struct.GetSecretValueRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSecretValueRequestBuilder {
+    secret_id: Option<String>,
+    version_id: Option<String>,
+    version_stage: Option<String>,
+}
+
+impl GetSecretValueRequestBuilder {
+    /// Sets the [`secret_id`](struct.GetSecretValueRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.GetSecretValueRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_stage`](struct.GetSecretValueRequest.html#structfield.version_stage) member.
+    pub fn version_stage<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_stage = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSecretValueRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSecretValueRequest, ::rusoto_core::BuildError> {
+        Ok(GetSecretValueRequest {
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+            version_id: self.version_id,
+            version_stage: self.version_stage,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSecretValueResponse {
@@ -312,8 +700,9 @@ pub struct GetSecretValueResponse {
     pub arn: Option<String>,
     /// <p>The date and time that this version of the secret was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The friendly name of the secret.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -360,6 +749,62 @@ pub struct ListSecretVersionIdsRequest {
     pub secret_id: String,
 }
 
+impl ListSecretVersionIdsRequest {
+    /// Returns a builder for `ListSecretVersionIdsRequest`, checking its required members when built.
+    pub fn builder() -> ListSecretVersionIdsRequestBuilder {
+        ListSecretVersionIdsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSecretVersionIdsRequest`](struct.ListSecretVersionIdsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSecretVersionIdsRequestBuilder {
+    include_deprecated: Option<bool>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    secret_id: Option<String>,
+}
+
+impl ListSecretVersionIdsRequestBuilder {
+    /// Sets the [`include_deprecated`](struct.ListSecretVersionIdsRequest.html#structfield.include_deprecated) member.
+    pub fn include_deprecated<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_deprecated = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListSecretVersionIdsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSecretVersionIdsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_id`](struct.ListSecretVersionIdsRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSecretVersionIdsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSecretVersionIdsRequest, ::rusoto_core::BuildError> {
+        Ok(ListSecretVersionIdsRequest {
+            include_deprecated: self.include_deprecated,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSecretVersionIdsResponse {
@@ -393,6 +838,42 @@ pub struct ListSecretsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListSecretsRequest {
+    /// Returns a builder for `ListSecretsRequest`, checking its required members when built.
+    pub fn builder() -> ListSecretsRequestBuilder {
+        ListSecretsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSecretsRequest`](struct.ListSecretsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSecretsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListSecretsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListSecretsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSecretsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSecretsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListSecretsRequest, ::rusoto_core::BuildError> {
+        Ok(ListSecretsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSecretsResponse {
@@ -416,6 +897,48 @@ pub struct PutResourcePolicyRequest {
     pub secret_id: String,
 }
 
+impl PutResourcePolicyRequest {
+    /// Returns a builder for `PutResourcePolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutResourcePolicyRequestBuilder {
+        PutResourcePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutResourcePolicyRequest`](struct.PutResourcePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutResourcePolicyRequestBuilder {
+    resource_policy: Option<String>,
+    secret_id: Option<String>,
+}
+
+impl PutResourcePolicyRequestBuilder {
+    /// Sets the [`resource_policy`](struct.PutResourcePolicyRequest.html#structfield.resource_policy) member.
+    pub fn resource_policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_id`](struct.PutResourcePolicyRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutResourcePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutResourcePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutResourcePolicyRequest {
+            resource_policy: self
+                .resource_policy
+                .ok_or(::rusoto_core::BuildError::missing("resource_policy"))?,
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutResourcePolicyResponse {
@@ -457,6 +980,68 @@ pub struct PutSecretValueRequest {
     pub version_stages: Option<Vec<String>>,
 }
 
+impl PutSecretValueRequest {
+    /// Returns a builder for `PutSecretValueRequest`, checking its required members when built.
+    pub fn builder() -> PutSecretValueRequestBuilder {
+        PutSecretValueRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutSecretValueRequest`](struct.PutSecretValueRequest.html).
+#[derive(Default, Debug)]
+pub struct PutSecretValueRequestBuilder {
+    client_request_token: Option<String>,
+    secret_binary: Option<bytes::Bytes>,
+    secret_id: Option<String>,
+    secret_string: Option<String>,
+    version_stages: Option<Vec<String>>,
+}
+
+impl PutSecretValueRequestBuilder {
+    /// Sets the [`client_request_token`](struct.PutSecretValueRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_binary`](struct.PutSecretValueRequest.html#structfield.secret_binary) member.
+    pub fn secret_binary<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.secret_binary = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_id`](struct.PutSecretValueRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_string`](struct.PutSecretValueRequest.html#structfield.secret_string) member.
+    pub fn secret_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_stages`](struct.PutSecretValueRequest.html#structfield.version_stages) member.
+    pub fn version_stages<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.version_stages = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutSecretValueRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutSecretValueRequest, ::rusoto_core::BuildError> {
+        Ok(PutSecretValueRequest {
+            client_request_token: self.client_request_token,
+            secret_binary: self.secret_binary,
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+            secret_string: self.secret_string,
+            version_stages: self.version_stages,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutSecretValueResponse {
@@ -485,6 +1070,36 @@ pub struct RestoreSecretRequest {
     pub secret_id: String,
 }
 
+impl RestoreSecretRequest {
+    /// Returns a builder for `RestoreSecretRequest`, checking its required members when built.
+    pub fn builder() -> RestoreSecretRequestBuilder {
+        RestoreSecretRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreSecretRequest`](struct.RestoreSecretRequest.html).
+#[derive(Default, Debug)]
+pub struct RestoreSecretRequestBuilder {
+    secret_id: Option<String>,
+}
+
+impl RestoreSecretRequestBuilder {
+    /// Sets the [`secret_id`](struct.RestoreSecretRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreSecretRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RestoreSecretRequest, ::rusoto_core::BuildError> {
+        Ok(RestoreSecretRequest {
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RestoreSecretResponse {
@@ -517,6 +1132,60 @@ pub struct RotateSecretRequest {
     pub secret_id: String,
 }
 
+impl RotateSecretRequest {
+    /// Returns a builder for `RotateSecretRequest`, checking its required members when built.
+    pub fn builder() -> RotateSecretRequestBuilder {
+        RotateSecretRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RotateSecretRequest`](struct.RotateSecretRequest.html).
+#[derive(Default, Debug)]
+pub struct RotateSecretRequestBuilder {
+    client_request_token: Option<String>,
+    rotation_lambda_arn: Option<String>,
+    rotation_rules: Option<RotationRulesType>,
+    secret_id: Option<String>,
+}
+
+impl RotateSecretRequestBuilder {
+    /// Sets the [`client_request_token`](struct.RotateSecretRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rotation_lambda_arn`](struct.RotateSecretRequest.html#structfield.rotation_lambda_arn) member.
+    pub fn rotation_lambda_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.rotation_lambda_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rotation_rules`](struct.RotateSecretRequest.html#structfield.rotation_rules) member.
+    pub fn rotation_rules<T: Into<RotationRulesType>>(mut self, value: T) -> Self {
+        self.rotation_rules = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_id`](struct.RotateSecretRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RotateSecretRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RotateSecretRequest, ::rusoto_core::BuildError> {
+        Ok(RotateSecretRequest {
+            client_request_token: self.client_request_token,
+            rotation_lambda_arn: self.rotation_lambda_arn,
+            rotation_rules: self.rotation_rules,
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RotateSecretResponse {
@@ -553,8 +1222,9 @@ pub struct SecretListEntry {
     pub arn: Option<String>,
     /// <p>The date and time on which this secret was deleted. Not present on active secrets. The secret can be recovered until the number of days in the recovery window has passed, as specified in the <code>RecoveryWindowInDays</code> parameter of the <a>DeleteSecret</a> operation.</p>
     #[serde(rename = "DeletedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deleted_date: Option<f64>,
+    pub deleted_date: Option<EpochTimestamp>,
     /// <p>The user-provided description of the secret.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -565,16 +1235,19 @@ pub struct SecretListEntry {
     pub kms_key_id: Option<String>,
     /// <p>The last date that this secret was accessed. This value is truncated to midnight of the date and therefore shows only the date, not the time.</p>
     #[serde(rename = "LastAccessedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_accessed_date: Option<f64>,
+    pub last_accessed_date: Option<EpochTimestamp>,
     /// <p>The last date and time that this secret was modified in any way.</p>
     #[serde(rename = "LastChangedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_changed_date: Option<f64>,
+    pub last_changed_date: Option<EpochTimestamp>,
     /// <p>The last date and time that the rotation process for this secret was invoked.</p>
     #[serde(rename = "LastRotatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_rotated_date: Option<f64>,
+    pub last_rotated_date: Option<EpochTimestamp>,
     /// <p>The friendly name of the secret. You can use forward slashes in the name to represent a path hierarchy. For example, <code>/prod/databases/dbserver1</code> could represent the secret for a server named <code>dbserver1</code> in the folder <code>databases</code> in the folder <code>prod</code>. </p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -610,12 +1283,14 @@ pub struct SecretListEntry {
 pub struct SecretVersionsListEntry {
     /// <p>The date and time this version of the secret was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The date that this version of the secret was last accessed. Note that the resolution of this field is at the date level and does not include the time.</p>
     #[serde(rename = "LastAccessedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_accessed_date: Option<f64>,
+    pub last_accessed_date: Option<EpochTimestamp>,
     /// <p>The unique version identifier of this version of the secret.</p>
     #[serde(rename = "VersionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -649,6 +1324,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    secret_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`secret_id`](struct.TagResourceRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     /// <p><p>The identifier for the secret that you want to remove tags from. You can specify either the Amazon Resource Name (ARN) or the friendly name of the secret.</p> <note> <p>If you specify an ARN, we generally recommend that you specify a complete ARN. You can specify a partial ARN too—for example, if you don’t include the final hyphen and six random characters that Secrets Manager adds at the end of the ARN when you created the secret. A partial ARN match can work as long as it uniquely matches only one secret. However, if your secret has a name that ends in a hyphen followed by six characters (before Secrets Manager adds the hyphen and six characters to the ARN) and you try to use that as a partial ARN, then those characters cause Secrets Manager to assume that you’re specifying a complete ARN. This confusion can cause unexpected results. To avoid this situation, we recommend that you don’t create secret names that end with a hyphen followed by six characters.</p> </note></p>
@@ -659,6 +1374,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    secret_id: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`secret_id`](struct.UntagResourceRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateSecretRequest {
     /// <p>(Optional) If you want to add a new version to the secret, this parameter specifies a unique identifier for the new version that helps ensure idempotency. </p> <p>If you use the AWS CLI or one of the AWS SDK to call this operation, then you can leave this parameter empty. The CLI or SDK generates a random UUID for you and includes that in the request. If you don't use the SDK and instead generate a raw HTTP request to the Secrets Manager service endpoint, then you must generate a <code>ClientRequestToken</code> yourself for new versions and include that value in the request.</p> <p>You typically only need to interact with this value if you implement your own retry logic and want to ensure that a given secret is not created twice. We recommend that you generate a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID-type</a> value to ensure uniqueness within the specified secret. </p> <p>Secrets Manager uses this value to prevent the accidental creation of duplicate versions if there are failures and retries during the Lambda rotation function's processing.</p> <ul> <li> <p>If the <code>ClientRequestToken</code> value isn't already associated with a version of the secret then a new version of the secret is created. </p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> and <code>SecretBinary</code> values are the same as those in the request then the request is ignored (the operation is idempotent). </p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> and <code>SecretBinary</code> values are different from the request then an error occurs because you cannot modify an existing secret value.</p> </li> </ul> <p>This value becomes the <code>VersionId</code> of the new version.</p>
@@ -691,6 +1446,76 @@ pub struct UpdateSecretRequest {
     pub secret_string: Option<String>,
 }
 
+impl UpdateSecretRequest {
+    /// Returns a builder for `UpdateSecretRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSecretRequestBuilder {
+        UpdateSecretRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSecretRequest`](struct.UpdateSecretRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSecretRequestBuilder {
+    client_request_token: Option<String>,
+    description: Option<String>,
+    kms_key_id: Option<String>,
+    secret_binary: Option<bytes::Bytes>,
+    secret_id: Option<String>,
+    secret_string: Option<String>,
+}
+
+impl UpdateSecretRequestBuilder {
+    /// Sets the [`client_request_token`](struct.UpdateSecretRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateSecretRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.UpdateSecretRequest.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_binary`](struct.UpdateSecretRequest.html#structfield.secret_binary) member.
+    pub fn secret_binary<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.secret_binary = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_id`](struct.UpdateSecretRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_string`](struct.UpdateSecretRequest.html#structfield.secret_string) member.
+    pub fn secret_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_string = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSecretRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateSecretRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSecretRequest {
+            client_request_token: self.client_request_token,
+            description: self.description,
+            kms_key_id: self.kms_key_id,
+            secret_binary: self.secret_binary,
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+            secret_string: self.secret_string,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateSecretResponse {
@@ -726,6 +1551,64 @@ pub struct UpdateSecretVersionStageRequest {
     pub version_stage: String,
 }
 
+impl UpdateSecretVersionStageRequest {
+    /// Returns a builder for `UpdateSecretVersionStageRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSecretVersionStageRequestBuilder {
+        UpdateSecretVersionStageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSecretVersionStageRequest`](struct.UpdateSecretVersionStageRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSecretVersionStageRequestBuilder {
+    move_to_version_id: Option<String>,
+    remove_from_version_id: Option<String>,
+    secret_id: Option<String>,
+    version_stage: Option<String>,
+}
+
+impl UpdateSecretVersionStageRequestBuilder {
+    /// Sets the [`move_to_version_id`](struct.UpdateSecretVersionStageRequest.html#structfield.move_to_version_id) member.
+    pub fn move_to_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.move_to_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remove_from_version_id`](struct.UpdateSecretVersionStageRequest.html#structfield.remove_from_version_id) member.
+    pub fn remove_from_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.remove_from_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_id`](struct.UpdateSecretVersionStageRequest.html#structfield.secret_id) member.
+    pub fn secret_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_stage`](struct.UpdateSecretVersionStageRequest.html#structfield.version_stage) member.
+    pub fn version_stage<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_stage = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSecretVersionStageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSecretVersionStageRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSecretVersionStageRequest {
+            move_to_version_id: self.move_to_version_id,
+            remove_from_version_id: self.remove_from_version_id,
+            secret_id: self
+                .secret_id
+                .ok_or(::rusoto_core::BuildError::missing("secret_id"))?,
+            version_stage: self
+                .version_stage
+                .ok_or(::rusoto_core::BuildError::missing("version_stage"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateSecretVersionStageResponse {
@@ -1849,6 +2732,117 @@ pub trait SecretsManager {
         input: UpdateSecretVersionStageRequest,
     ) -> RusotoFuture<UpdateSecretVersionStageResponse, UpdateSecretVersionStageError>;
 }
+impl<T: ?Sized + SecretsManager> SecretsManager for ::std::sync::Arc<T> {
+    fn cancel_rotate_secret(
+        &self,
+        input: CancelRotateSecretRequest,
+    ) -> RusotoFuture<CancelRotateSecretResponse, CancelRotateSecretError> {
+        (**self).cancel_rotate_secret(input)
+    }
+    fn create_secret(
+        &self,
+        input: CreateSecretRequest,
+    ) -> RusotoFuture<CreateSecretResponse, CreateSecretError> {
+        (**self).create_secret(input)
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
+        (**self).delete_resource_policy(input)
+    }
+    fn delete_secret(
+        &self,
+        input: DeleteSecretRequest,
+    ) -> RusotoFuture<DeleteSecretResponse, DeleteSecretError> {
+        (**self).delete_secret(input)
+    }
+    fn describe_secret(
+        &self,
+        input: DescribeSecretRequest,
+    ) -> RusotoFuture<DescribeSecretResponse, DescribeSecretError> {
+        (**self).describe_secret(input)
+    }
+    fn get_random_password(
+        &self,
+        input: GetRandomPasswordRequest,
+    ) -> RusotoFuture<GetRandomPasswordResponse, GetRandomPasswordError> {
+        (**self).get_random_password(input)
+    }
+    fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
+        (**self).get_resource_policy(input)
+    }
+    fn get_secret_value(
+        &self,
+        input: GetSecretValueRequest,
+    ) -> RusotoFuture<GetSecretValueResponse, GetSecretValueError> {
+        (**self).get_secret_value(input)
+    }
+    fn list_secret_version_ids(
+        &self,
+        input: ListSecretVersionIdsRequest,
+    ) -> RusotoFuture<ListSecretVersionIdsResponse, ListSecretVersionIdsError> {
+        (**self).list_secret_version_ids(input)
+    }
+    fn list_secrets(
+        &self,
+        input: ListSecretsRequest,
+    ) -> RusotoFuture<ListSecretsResponse, ListSecretsError> {
+        (**self).list_secrets(input)
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        (**self).put_resource_policy(input)
+    }
+    fn put_secret_value(
+        &self,
+        input: PutSecretValueRequest,
+    ) -> RusotoFuture<PutSecretValueResponse, PutSecretValueError> {
+        (**self).put_secret_value(input)
+    }
+    fn restore_secret(
+        &self,
+        input: RestoreSecretRequest,
+    ) -> RusotoFuture<RestoreSecretResponse, RestoreSecretError> {
+        (**self).restore_secret(input)
+    }
+    fn rotate_secret(
+        &self,
+        input: RotateSecretRequest,
+    ) -> RusotoFuture<RotateSecretResponse, RotateSecretError> {
+        (**self).rotate_secret(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_secret(
+        &self,
+        input: UpdateSecretRequest,
+    ) -> RusotoFuture<UpdateSecretResponse, UpdateSecretError> {
+        (**self).update_secret(input)
+    }
+    fn update_secret_version_stage(
+        &self,
+        input: UpdateSecretVersionStageRequest,
+    ) -> RusotoFuture<UpdateSecretVersionStageResponse, UpdateSecretVersionStageError> {
+        (**self).update_secret_version_stage(input)
+    }
+}
+/// The error codes AWS Secrets Manager retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Secrets Manager API.
 #[derive(Clone)]
 pub struct SecretsManagerClient {
@@ -1864,6 +2858,27 @@ impl SecretsManagerClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SECRETSMANAGER`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> SecretsManagerClient {
+        Self::new(region::Region::from_env_for_service("SECRETSMANAGER"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `secretsmanager`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> SecretsManagerClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("secretsmanager", "SECRETSMANAGER"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1882,7 +2897,54 @@ impl SecretsManagerClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SecretsManagerClient {
-        SecretsManagerClient { client, region }
+        SecretsManagerClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "secretsmanager")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "secretsmanager",
+            "secretsmanager",
+            "SECRETSMANAGER",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `SecretsManager` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn SecretsManager + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for SecretsManagerClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Secrets Manager"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "secretsmanager"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> SecretsManagerClient {
+        Self::new_with_client(client, region)
     }
 }
 
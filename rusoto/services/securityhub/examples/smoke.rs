@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS SecurityHub clients.
+//!
+//! Makes a single read-only `ListEnabledProductsForImport` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_securityhub::{ListEnabledProductsForImportRequest, SecurityHub, SecurityHubClient};
+
+fn main() {
+    let client = SecurityHubClient::from_env();
+    match client
+        .list_enabled_products_for_import(ListEnabledProductsForImportRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListEnabledProductsForImport succeeded"),
+        Err(error) => {
+            eprintln!("ListEnabledProductsForImport failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
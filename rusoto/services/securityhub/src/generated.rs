@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AcceptInvitationRequest {
@@ -34,6 +35,48 @@ pub struct AcceptInvitationRequest {
     pub master_id: String,
 }
 
+impl AcceptInvitationRequest {
+    /// Returns a builder for `AcceptInvitationRequest`, checking its required members when built.
+    pub fn builder() -> AcceptInvitationRequestBuilder {
+        AcceptInvitationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AcceptInvitationRequest`](struct.AcceptInvitationRequest.html).
+#[derive(Default, Debug)]
+pub struct AcceptInvitationRequestBuilder {
+    invitation_id: Option<String>,
+    master_id: Option<String>,
+}
+
+impl AcceptInvitationRequestBuilder {
+    /// Sets the [`invitation_id`](struct.AcceptInvitationRequest.html#structfield.invitation_id) member.
+    pub fn invitation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.invitation_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_id`](struct.AcceptInvitationRequest.html#structfield.master_id) member.
+    pub fn master_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcceptInvitationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AcceptInvitationRequest, ::rusoto_core::BuildError> {
+        Ok(AcceptInvitationRequest {
+            invitation_id: self
+                .invitation_id
+                .ok_or(::rusoto_core::BuildError::missing("invitation_id"))?,
+            master_id: self
+                .master_id
+                .ok_or(::rusoto_core::BuildError::missing("master_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AcceptInvitationResponse {}
@@ -117,13 +160,91 @@ pub struct AwsIamAccessKeyDetails {
     /// <p>The status of the IAM access key related to a finding.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AwsIamAccessKeyStatus>,
     /// <p>The user associated with the IAM access key related to a finding.</p>
     #[serde(rename = "UserName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_name: Option<String>,
 }
 
+/// <p>The status of the IAM access key related to a finding.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AwsIamAccessKeyStatus {
+    /// <p><code>Active</code></p>
+    Active,
+    /// <p><code>Inactive</code></p>
+    Inactive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AwsIamAccessKeyStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AwsIamAccessKeyStatus::Active => "Active",
+            AwsIamAccessKeyStatus::Inactive => "Inactive",
+            AwsIamAccessKeyStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AwsIamAccessKeyStatus {
+    fn from(value: &'a str) -> AwsIamAccessKeyStatus {
+        match value {
+            "Active" => AwsIamAccessKeyStatus::Active,
+            "Inactive" => AwsIamAccessKeyStatus::Inactive,
+            _ => AwsIamAccessKeyStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AwsIamAccessKeyStatus {
+    fn from(value: String) -> AwsIamAccessKeyStatus {
+        AwsIamAccessKeyStatus::from(value.as_str())
+    }
+}
+
+impl From<AwsIamAccessKeyStatus> for String {
+    fn from(value: AwsIamAccessKeyStatus) -> String {
+        match value {
+            AwsIamAccessKeyStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AwsIamAccessKeyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AwsIamAccessKeyStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AwsIamAccessKeyStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AwsIamAccessKeyStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AwsIamAccessKeyStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AwsIamAccessKeyStatus::from(value))
+    }
+}
+
 /// <p>The details of an Amazon S3 bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsS3BucketDetails {
@@ -201,7 +322,7 @@ pub struct AwsSecurityFinding {
     /// <p>The record state of a finding.</p>
     #[serde(rename = "RecordState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub record_state: Option<String>,
+    pub record_state: Option<RecordState>,
     /// <p>A list of related findings.</p>
     #[serde(rename = "RelatedFindings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -243,11 +364,11 @@ pub struct AwsSecurityFinding {
     /// <p>Indicates the veracity of a finding. </p>
     #[serde(rename = "VerificationState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub verification_state: Option<String>,
+    pub verification_state: Option<VerificationState>,
     /// <p>The workflow state of a finding. </p>
     #[serde(rename = "WorkflowState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub workflow_state: Option<String>,
+    pub workflow_state: Option<WorkflowState>,
 }
 
 /// <p>A collection of attributes that are applied to all active Security Hub-aggregated findings and that result in a subset of findings that are included in this insight. </p>
@@ -594,6 +715,38 @@ pub struct BatchDisableStandardsRequest {
     pub standards_subscription_arns: Vec<String>,
 }
 
+impl BatchDisableStandardsRequest {
+    /// Returns a builder for `BatchDisableStandardsRequest`, checking its required members when built.
+    pub fn builder() -> BatchDisableStandardsRequestBuilder {
+        BatchDisableStandardsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDisableStandardsRequest`](struct.BatchDisableStandardsRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDisableStandardsRequestBuilder {
+    standards_subscription_arns: Option<Vec<String>>,
+}
+
+impl BatchDisableStandardsRequestBuilder {
+    /// Sets the [`standards_subscription_arns`](struct.BatchDisableStandardsRequest.html#structfield.standards_subscription_arns) member.
+    pub fn standards_subscription_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.standards_subscription_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDisableStandardsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDisableStandardsRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDisableStandardsRequest {
+            standards_subscription_arns: self.standards_subscription_arns.ok_or(
+                ::rusoto_core::BuildError::missing("standards_subscription_arns"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDisableStandardsResponse {
@@ -610,6 +763,41 @@ pub struct BatchEnableStandardsRequest {
     pub standards_subscription_requests: Vec<StandardsSubscriptionRequest>,
 }
 
+impl BatchEnableStandardsRequest {
+    /// Returns a builder for `BatchEnableStandardsRequest`, checking its required members when built.
+    pub fn builder() -> BatchEnableStandardsRequestBuilder {
+        BatchEnableStandardsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchEnableStandardsRequest`](struct.BatchEnableStandardsRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchEnableStandardsRequestBuilder {
+    standards_subscription_requests: Option<Vec<StandardsSubscriptionRequest>>,
+}
+
+impl BatchEnableStandardsRequestBuilder {
+    /// Sets the [`standards_subscription_requests`](struct.BatchEnableStandardsRequest.html#structfield.standards_subscription_requests) member.
+    pub fn standards_subscription_requests<T: Into<Vec<StandardsSubscriptionRequest>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.standards_subscription_requests = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchEnableStandardsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchEnableStandardsRequest, ::rusoto_core::BuildError> {
+        Ok(BatchEnableStandardsRequest {
+            standards_subscription_requests: self.standards_subscription_requests.ok_or(
+                ::rusoto_core::BuildError::missing("standards_subscription_requests"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchEnableStandardsResponse {
@@ -626,6 +814,38 @@ pub struct BatchImportFindingsRequest {
     pub findings: Vec<AwsSecurityFinding>,
 }
 
+impl BatchImportFindingsRequest {
+    /// Returns a builder for `BatchImportFindingsRequest`, checking its required members when built.
+    pub fn builder() -> BatchImportFindingsRequestBuilder {
+        BatchImportFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchImportFindingsRequest`](struct.BatchImportFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchImportFindingsRequestBuilder {
+    findings: Option<Vec<AwsSecurityFinding>>,
+}
+
+impl BatchImportFindingsRequestBuilder {
+    /// Sets the [`findings`](struct.BatchImportFindingsRequest.html#structfield.findings) member.
+    pub fn findings<T: Into<Vec<AwsSecurityFinding>>>(mut self, value: T) -> Self {
+        self.findings = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchImportFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchImportFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(BatchImportFindingsRequest {
+            findings: self
+                .findings
+                .ok_or(::rusoto_core::BuildError::missing("findings"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchImportFindingsResponse {
@@ -647,7 +867,93 @@ pub struct Compliance {
     /// <p>The result of a compliance check.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ComplianceStatus>,
+}
+
+/// <p>The result of a compliance check.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComplianceStatus {
+    /// <p><code>PASSED</code></p>
+    Passed,
+    /// <p><code>WARNING</code></p>
+    Warning,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>NOT_AVAILABLE</code></p>
+    NotAvailable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComplianceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComplianceStatus::Passed => "PASSED",
+            ComplianceStatus::Warning => "WARNING",
+            ComplianceStatus::Failed => "FAILED",
+            ComplianceStatus::NotAvailable => "NOT_AVAILABLE",
+            ComplianceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComplianceStatus {
+    fn from(value: &'a str) -> ComplianceStatus {
+        match value {
+            "PASSED" => ComplianceStatus::Passed,
+            "WARNING" => ComplianceStatus::Warning,
+            "FAILED" => ComplianceStatus::Failed,
+            "NOT_AVAILABLE" => ComplianceStatus::NotAvailable,
+            _ => ComplianceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComplianceStatus {
+    fn from(value: String) -> ComplianceStatus {
+        ComplianceStatus::from(value.as_str())
+    }
+}
+
+impl From<ComplianceStatus> for String {
+    fn from(value: ComplianceStatus) -> String {
+        match value {
+            ComplianceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComplianceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComplianceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComplianceStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComplianceStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ComplianceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ComplianceStatus::from(value))
+    }
 }
 
 /// <p>Container details related to a finding.</p>
@@ -684,6 +990,56 @@ pub struct CreateActionTargetRequest {
     pub name: String,
 }
 
+impl CreateActionTargetRequest {
+    /// Returns a builder for `CreateActionTargetRequest`, checking its required members when built.
+    pub fn builder() -> CreateActionTargetRequestBuilder {
+        CreateActionTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateActionTargetRequest`](struct.CreateActionTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateActionTargetRequestBuilder {
+    description: Option<String>,
+    id: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateActionTargetRequestBuilder {
+    /// Sets the [`description`](struct.CreateActionTargetRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.CreateActionTargetRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateActionTargetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateActionTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateActionTargetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateActionTargetRequest {
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateActionTargetResponse {
@@ -705,6 +1061,56 @@ pub struct CreateInsightRequest {
     pub name: String,
 }
 
+impl CreateInsightRequest {
+    /// Returns a builder for `CreateInsightRequest`, checking its required members when built.
+    pub fn builder() -> CreateInsightRequestBuilder {
+        CreateInsightRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateInsightRequest`](struct.CreateInsightRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateInsightRequestBuilder {
+    filters: Option<AwsSecurityFindingFilters>,
+    group_by_attribute: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateInsightRequestBuilder {
+    /// Sets the [`filters`](struct.CreateInsightRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<AwsSecurityFindingFilters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_by_attribute`](struct.CreateInsightRequest.html#structfield.group_by_attribute) member.
+    pub fn group_by_attribute<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_by_attribute = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateInsightRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateInsightRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateInsightRequest, ::rusoto_core::BuildError> {
+        Ok(CreateInsightRequest {
+            filters: self
+                .filters
+                .ok_or(::rusoto_core::BuildError::missing("filters"))?,
+            group_by_attribute: self
+                .group_by_attribute
+                .ok_or(::rusoto_core::BuildError::missing("group_by_attribute"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateInsightResponse {
@@ -721,6 +1127,34 @@ pub struct CreateMembersRequest {
     pub account_details: Option<Vec<AccountDetails>>,
 }
 
+impl CreateMembersRequest {
+    /// Returns a builder for `CreateMembersRequest`, checking its required members when built.
+    pub fn builder() -> CreateMembersRequestBuilder {
+        CreateMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateMembersRequest`](struct.CreateMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateMembersRequestBuilder {
+    account_details: Option<Vec<AccountDetails>>,
+}
+
+impl CreateMembersRequestBuilder {
+    /// Sets the [`account_details`](struct.CreateMembersRequest.html#structfield.account_details) member.
+    pub fn account_details<T: Into<Vec<AccountDetails>>>(mut self, value: T) -> Self {
+        self.account_details = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateMembersRequest, ::rusoto_core::BuildError> {
+        Ok(CreateMembersRequest {
+            account_details: self.account_details,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateMembersResponse {
@@ -753,13 +1187,87 @@ pub struct DateRange {
     /// <p>A date range unit for the date filter.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<DateRangeUnit>,
     /// <p>A date range value for the date filter.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<i64>,
 }
 
+/// <p>A date range unit for the date filter.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DateRangeUnit {
+    /// <p><code>DAYS</code></p>
+    Days,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DateRangeUnit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DateRangeUnit::Days => "DAYS",
+            DateRangeUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DateRangeUnit {
+    fn from(value: &'a str) -> DateRangeUnit {
+        match value {
+            "DAYS" => DateRangeUnit::Days,
+            _ => DateRangeUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DateRangeUnit {
+    fn from(value: String) -> DateRangeUnit {
+        DateRangeUnit::from(value.as_str())
+    }
+}
+
+impl From<DateRangeUnit> for String {
+    fn from(value: DateRangeUnit) -> String {
+        match value {
+            DateRangeUnit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DateRangeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DateRangeUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DateRangeUnit {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DateRangeUnit {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DateRangeUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DateRangeUnit::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeclineInvitationsRequest {
     /// <p>A list of account IDs that specify the accounts that invitations to Security Hub are declined from.</p>
@@ -767,6 +1275,38 @@ pub struct DeclineInvitationsRequest {
     pub account_ids: Vec<String>,
 }
 
+impl DeclineInvitationsRequest {
+    /// Returns a builder for `DeclineInvitationsRequest`, checking its required members when built.
+    pub fn builder() -> DeclineInvitationsRequestBuilder {
+        DeclineInvitationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeclineInvitationsRequest`](struct.DeclineInvitationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeclineInvitationsRequestBuilder {
+    account_ids: Option<Vec<String>>,
+}
+
+impl DeclineInvitationsRequestBuilder {
+    /// Sets the [`account_ids`](struct.DeclineInvitationsRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeclineInvitationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeclineInvitationsRequest, ::rusoto_core::BuildError> {
+        Ok(DeclineInvitationsRequest {
+            account_ids: self
+                .account_ids
+                .ok_or(::rusoto_core::BuildError::missing("account_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeclineInvitationsResponse {
@@ -783,6 +1323,38 @@ pub struct DeleteActionTargetRequest {
     pub action_target_arn: String,
 }
 
+impl DeleteActionTargetRequest {
+    /// Returns a builder for `DeleteActionTargetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteActionTargetRequestBuilder {
+        DeleteActionTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteActionTargetRequest`](struct.DeleteActionTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteActionTargetRequestBuilder {
+    action_target_arn: Option<String>,
+}
+
+impl DeleteActionTargetRequestBuilder {
+    /// Sets the [`action_target_arn`](struct.DeleteActionTargetRequest.html#structfield.action_target_arn) member.
+    pub fn action_target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.action_target_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteActionTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteActionTargetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteActionTargetRequest {
+            action_target_arn: self
+                .action_target_arn
+                .ok_or(::rusoto_core::BuildError::missing("action_target_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteActionTargetResponse {
@@ -798,6 +1370,36 @@ pub struct DeleteInsightRequest {
     pub insight_arn: String,
 }
 
+impl DeleteInsightRequest {
+    /// Returns a builder for `DeleteInsightRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInsightRequestBuilder {
+        DeleteInsightRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInsightRequest`](struct.DeleteInsightRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInsightRequestBuilder {
+    insight_arn: Option<String>,
+}
+
+impl DeleteInsightRequestBuilder {
+    /// Sets the [`insight_arn`](struct.DeleteInsightRequest.html#structfield.insight_arn) member.
+    pub fn insight_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.insight_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInsightRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteInsightRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteInsightRequest {
+            insight_arn: self
+                .insight_arn
+                .ok_or(::rusoto_core::BuildError::missing("insight_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteInsightResponse {
@@ -813,6 +1415,38 @@ pub struct DeleteInvitationsRequest {
     pub account_ids: Vec<String>,
 }
 
+impl DeleteInvitationsRequest {
+    /// Returns a builder for `DeleteInvitationsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInvitationsRequestBuilder {
+        DeleteInvitationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInvitationsRequest`](struct.DeleteInvitationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInvitationsRequestBuilder {
+    account_ids: Option<Vec<String>>,
+}
+
+impl DeleteInvitationsRequestBuilder {
+    /// Sets the [`account_ids`](struct.DeleteInvitationsRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInvitationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteInvitationsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteInvitationsRequest {
+            account_ids: self
+                .account_ids
+                .ok_or(::rusoto_core::BuildError::missing("account_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteInvitationsResponse {
@@ -830,6 +1464,34 @@ pub struct DeleteMembersRequest {
     pub account_ids: Option<Vec<String>>,
 }
 
+impl DeleteMembersRequest {
+    /// Returns a builder for `DeleteMembersRequest`, checking its required members when built.
+    pub fn builder() -> DeleteMembersRequestBuilder {
+        DeleteMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMembersRequest`](struct.DeleteMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteMembersRequestBuilder {
+    account_ids: Option<Vec<String>>,
+}
+
+impl DeleteMembersRequestBuilder {
+    /// Sets the [`account_ids`](struct.DeleteMembersRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteMembersRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteMembersRequest {
+            account_ids: self.account_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteMembersResponse {
@@ -855,15 +1517,61 @@ pub struct DescribeActionTargetsRequest {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DescribeActionTargetsResponse {
-    /// <p>A list of <code>ActionTarget</code> objects. Each object includes the <code>ActionTargetArn</code>, <code>Description</code>, and <code>Name</code> of a custom action target available in Security Hub.</p>
-    #[serde(rename = "ActionTargets")]
-    pub action_targets: Vec<ActionTarget>,
-    /// <p>The token that is required for pagination.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl DescribeActionTargetsRequest {
+    /// Returns a builder for `DescribeActionTargetsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeActionTargetsRequestBuilder {
+        DescribeActionTargetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeActionTargetsRequest`](struct.DescribeActionTargetsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeActionTargetsRequestBuilder {
+    action_target_arns: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeActionTargetsRequestBuilder {
+    /// Sets the [`action_target_arns`](struct.DescribeActionTargetsRequest.html#structfield.action_target_arns) member.
+    pub fn action_target_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.action_target_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeActionTargetsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeActionTargetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeActionTargetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeActionTargetsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeActionTargetsRequest {
+            action_target_arns: self.action_target_arns,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeActionTargetsResponse {
+    /// <p>A list of <code>ActionTarget</code> objects. Each object includes the <code>ActionTargetArn</code>, <code>Description</code>, and <code>Name</code> of a custom action target available in Security Hub.</p>
+    #[serde(rename = "ActionTargets")]
+    pub action_targets: Vec<ActionTarget>,
+    /// <p>The token that is required for pagination.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_token: Option<String>,
 }
 
@@ -875,6 +1583,34 @@ pub struct DescribeHubRequest {
     pub hub_arn: Option<String>,
 }
 
+impl DescribeHubRequest {
+    /// Returns a builder for `DescribeHubRequest`, checking its required members when built.
+    pub fn builder() -> DescribeHubRequestBuilder {
+        DescribeHubRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeHubRequest`](struct.DescribeHubRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeHubRequestBuilder {
+    hub_arn: Option<String>,
+}
+
+impl DescribeHubRequestBuilder {
+    /// Sets the [`hub_arn`](struct.DescribeHubRequest.html#structfield.hub_arn) member.
+    pub fn hub_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.hub_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeHubRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeHubRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeHubRequest {
+            hub_arn: self.hub_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeHubResponse {
@@ -900,6 +1636,44 @@ pub struct DescribeProductsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeProductsRequest {
+    /// Returns a builder for `DescribeProductsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeProductsRequestBuilder {
+        DescribeProductsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProductsRequest`](struct.DescribeProductsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeProductsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeProductsRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeProductsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeProductsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProductsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeProductsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeProductsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeProductsResponse {
@@ -919,6 +1693,39 @@ pub struct DisableImportFindingsForProductRequest {
     pub product_subscription_arn: String,
 }
 
+impl DisableImportFindingsForProductRequest {
+    /// Returns a builder for `DisableImportFindingsForProductRequest`, checking its required members when built.
+    pub fn builder() -> DisableImportFindingsForProductRequestBuilder {
+        DisableImportFindingsForProductRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableImportFindingsForProductRequest`](struct.DisableImportFindingsForProductRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableImportFindingsForProductRequestBuilder {
+    product_subscription_arn: Option<String>,
+}
+
+impl DisableImportFindingsForProductRequestBuilder {
+    /// Sets the [`product_subscription_arn`](struct.DisableImportFindingsForProductRequest.html#structfield.product_subscription_arn) member.
+    pub fn product_subscription_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_subscription_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableImportFindingsForProductRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableImportFindingsForProductRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisableImportFindingsForProductRequest {
+            product_subscription_arn: self.product_subscription_arn.ok_or(
+                ::rusoto_core::BuildError::missing("product_subscription_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisableImportFindingsForProductResponse {}
@@ -945,6 +1752,36 @@ pub struct DisassociateMembersRequest {
     pub account_ids: Option<Vec<String>>,
 }
 
+impl DisassociateMembersRequest {
+    /// Returns a builder for `DisassociateMembersRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateMembersRequestBuilder {
+        DisassociateMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateMembersRequest`](struct.DisassociateMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateMembersRequestBuilder {
+    account_ids: Option<Vec<String>>,
+}
+
+impl DisassociateMembersRequestBuilder {
+    /// Sets the [`account_ids`](struct.DisassociateMembersRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateMembersRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateMembersRequest {
+            account_ids: self.account_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateMembersResponse {}
@@ -956,6 +1793,39 @@ pub struct EnableImportFindingsForProductRequest {
     pub product_arn: String,
 }
 
+impl EnableImportFindingsForProductRequest {
+    /// Returns a builder for `EnableImportFindingsForProductRequest`, checking its required members when built.
+    pub fn builder() -> EnableImportFindingsForProductRequestBuilder {
+        EnableImportFindingsForProductRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableImportFindingsForProductRequest`](struct.EnableImportFindingsForProductRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableImportFindingsForProductRequestBuilder {
+    product_arn: Option<String>,
+}
+
+impl EnableImportFindingsForProductRequestBuilder {
+    /// Sets the [`product_arn`](struct.EnableImportFindingsForProductRequest.html#structfield.product_arn) member.
+    pub fn product_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableImportFindingsForProductRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnableImportFindingsForProductRequest, ::rusoto_core::BuildError>
+    {
+        Ok(EnableImportFindingsForProductRequest {
+            product_arn: self
+                .product_arn
+                .ok_or(::rusoto_core::BuildError::missing("product_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct EnableImportFindingsForProductResponse {
@@ -973,6 +1843,34 @@ pub struct EnableSecurityHubRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl EnableSecurityHubRequest {
+    /// Returns a builder for `EnableSecurityHubRequest`, checking its required members when built.
+    pub fn builder() -> EnableSecurityHubRequestBuilder {
+        EnableSecurityHubRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableSecurityHubRequest`](struct.EnableSecurityHubRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableSecurityHubRequestBuilder {
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl EnableSecurityHubRequestBuilder {
+    /// Sets the [`tags`](struct.EnableSecurityHubRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableSecurityHubRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnableSecurityHubRequest, ::rusoto_core::BuildError> {
+        Ok(EnableSecurityHubRequest { tags: self.tags })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct EnableSecurityHubResponse {}
@@ -993,6 +1891,52 @@ pub struct GetEnabledStandardsRequest {
     pub standards_subscription_arns: Option<Vec<String>>,
 }
 
+impl GetEnabledStandardsRequest {
+    /// Returns a builder for `GetEnabledStandardsRequest`, checking its required members when built.
+    pub fn builder() -> GetEnabledStandardsRequestBuilder {
+        GetEnabledStandardsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetEnabledStandardsRequest`](struct.GetEnabledStandardsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetEnabledStandardsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    standards_subscription_arns: Option<Vec<String>>,
+}
+
+impl GetEnabledStandardsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetEnabledStandardsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetEnabledStandardsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`standards_subscription_arns`](struct.GetEnabledStandardsRequest.html#structfield.standards_subscription_arns) member.
+    pub fn standards_subscription_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.standards_subscription_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetEnabledStandardsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetEnabledStandardsRequest, ::rusoto_core::BuildError> {
+        Ok(GetEnabledStandardsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            standards_subscription_arns: self.standards_subscription_arns,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetEnabledStandardsResponse {
@@ -1026,6 +1970,58 @@ pub struct GetFindingsRequest {
     pub sort_criteria: Option<Vec<SortCriterion>>,
 }
 
+impl GetFindingsRequest {
+    /// Returns a builder for `GetFindingsRequest`, checking its required members when built.
+    pub fn builder() -> GetFindingsRequestBuilder {
+        GetFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFindingsRequest`](struct.GetFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFindingsRequestBuilder {
+    filters: Option<AwsSecurityFindingFilters>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_criteria: Option<Vec<SortCriterion>>,
+}
+
+impl GetFindingsRequestBuilder {
+    /// Sets the [`filters`](struct.GetFindingsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<AwsSecurityFindingFilters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetFindingsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetFindingsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.GetFindingsRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<Vec<SortCriterion>>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(GetFindingsRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFindingsResponse {
@@ -1045,6 +2041,38 @@ pub struct GetInsightResultsRequest {
     pub insight_arn: String,
 }
 
+impl GetInsightResultsRequest {
+    /// Returns a builder for `GetInsightResultsRequest`, checking its required members when built.
+    pub fn builder() -> GetInsightResultsRequestBuilder {
+        GetInsightResultsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInsightResultsRequest`](struct.GetInsightResultsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInsightResultsRequestBuilder {
+    insight_arn: Option<String>,
+}
+
+impl GetInsightResultsRequestBuilder {
+    /// Sets the [`insight_arn`](struct.GetInsightResultsRequest.html#structfield.insight_arn) member.
+    pub fn insight_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.insight_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInsightResultsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetInsightResultsRequest, ::rusoto_core::BuildError> {
+        Ok(GetInsightResultsRequest {
+            insight_arn: self
+                .insight_arn
+                .ok_or(::rusoto_core::BuildError::missing("insight_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetInsightResultsResponse {
@@ -1069,6 +2097,50 @@ pub struct GetInsightsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetInsightsRequest {
+    /// Returns a builder for `GetInsightsRequest`, checking its required members when built.
+    pub fn builder() -> GetInsightsRequestBuilder {
+        GetInsightsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInsightsRequest`](struct.GetInsightsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInsightsRequestBuilder {
+    insight_arns: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetInsightsRequestBuilder {
+    /// Sets the [`insight_arns`](struct.GetInsightsRequest.html#structfield.insight_arns) member.
+    pub fn insight_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.insight_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetInsightsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetInsightsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInsightsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetInsightsRequest, ::rusoto_core::BuildError> {
+        Ok(GetInsightsRequest {
+            insight_arns: self.insight_arns,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetInsightsResponse {
@@ -1112,6 +2184,36 @@ pub struct GetMembersRequest {
     pub account_ids: Vec<String>,
 }
 
+impl GetMembersRequest {
+    /// Returns a builder for `GetMembersRequest`, checking its required members when built.
+    pub fn builder() -> GetMembersRequestBuilder {
+        GetMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMembersRequest`](struct.GetMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMembersRequestBuilder {
+    account_ids: Option<Vec<String>>,
+}
+
+impl GetMembersRequestBuilder {
+    /// Sets the [`account_ids`](struct.GetMembersRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMembersRequest, ::rusoto_core::BuildError> {
+        Ok(GetMembersRequest {
+            account_ids: self
+                .account_ids
+                .ok_or(::rusoto_core::BuildError::missing("account_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetMembersResponse {
@@ -1199,8 +2301,9 @@ pub struct Invitation {
     pub invitation_id: Option<String>,
     /// <p>The timestamp of when the invitation was sent.</p>
     #[serde(rename = "InvitedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invited_at: Option<f64>,
+    pub invited_at: Option<EpochTimestamp>,
     /// <p>The current status of the association between member and master accounts.</p>
     #[serde(rename = "MemberStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1215,6 +2318,34 @@ pub struct InviteMembersRequest {
     pub account_ids: Option<Vec<String>>,
 }
 
+impl InviteMembersRequest {
+    /// Returns a builder for `InviteMembersRequest`, checking its required members when built.
+    pub fn builder() -> InviteMembersRequestBuilder {
+        InviteMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InviteMembersRequest`](struct.InviteMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct InviteMembersRequestBuilder {
+    account_ids: Option<Vec<String>>,
+}
+
+impl InviteMembersRequestBuilder {
+    /// Sets the [`account_ids`](struct.InviteMembersRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `InviteMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<InviteMembersRequest, ::rusoto_core::BuildError> {
+        Ok(InviteMembersRequest {
+            account_ids: self.account_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InviteMembersResponse {
@@ -1254,6 +2385,44 @@ pub struct ListEnabledProductsForImportRequest {
     pub next_token: Option<String>,
 }
 
+impl ListEnabledProductsForImportRequest {
+    /// Returns a builder for `ListEnabledProductsForImportRequest`, checking its required members when built.
+    pub fn builder() -> ListEnabledProductsForImportRequestBuilder {
+        ListEnabledProductsForImportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListEnabledProductsForImportRequest`](struct.ListEnabledProductsForImportRequest.html).
+#[derive(Default, Debug)]
+pub struct ListEnabledProductsForImportRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListEnabledProductsForImportRequestBuilder {
+    /// Sets the [`max_results`](struct.ListEnabledProductsForImportRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListEnabledProductsForImportRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListEnabledProductsForImportRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListEnabledProductsForImportRequest, ::rusoto_core::BuildError> {
+        Ok(ListEnabledProductsForImportRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListEnabledProductsForImportResponse {
@@ -1279,6 +2448,42 @@ pub struct ListInvitationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListInvitationsRequest {
+    /// Returns a builder for `ListInvitationsRequest`, checking its required members when built.
+    pub fn builder() -> ListInvitationsRequestBuilder {
+        ListInvitationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListInvitationsRequest`](struct.ListInvitationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListInvitationsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListInvitationsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListInvitationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListInvitationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListInvitationsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListInvitationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListInvitationsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListInvitationsResponse {
@@ -1308,6 +2513,50 @@ pub struct ListMembersRequest {
     pub only_associated: Option<bool>,
 }
 
+impl ListMembersRequest {
+    /// Returns a builder for `ListMembersRequest`, checking its required members when built.
+    pub fn builder() -> ListMembersRequestBuilder {
+        ListMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListMembersRequest`](struct.ListMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListMembersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    only_associated: Option<bool>,
+}
+
+impl ListMembersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListMembersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListMembersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`only_associated`](struct.ListMembersRequest.html#structfield.only_associated) member.
+    pub fn only_associated<T: Into<bool>>(mut self, value: T) -> Self {
+        self.only_associated = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListMembersRequest, ::rusoto_core::BuildError> {
+        Ok(ListMembersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            only_associated: self.only_associated,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListMembersResponse {
@@ -1328,6 +2577,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -1350,47 +2631,346 @@ pub struct Malware {
     /// <p>The state of the malware that was observed.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<MalwareState>,
     /// <p>The type of the malware that was observed.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<MalwareType>,
 }
 
-/// <p>The map filter for querying findings.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct MapFilter {
-    /// <p>The condition to apply to a key value when querying for findings with a map filter.</p>
-    #[serde(rename = "Comparison")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison: Option<String>,
-    /// <p>The key of the map filter.</p>
-    #[serde(rename = "Key")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
-    /// <p>The value for the key in the map filter.</p>
-    #[serde(rename = "Value")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+/// <p>The state of the malware that was observed.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MalwareState {
+    /// <p><code>OBSERVED</code></p>
+    Observed,
+    /// <p><code>REMOVAL_FAILED</code></p>
+    RemovalFailed,
+    /// <p><code>REMOVED</code></p>
+    Removed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>The details about a member account.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Member {
-    /// <p>The AWS account ID of the member account.</p>
-    #[serde(rename = "AccountId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_id: Option<String>,
-    /// <p>The email address of the member account.</p>
-    #[serde(rename = "Email")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<String>,
-    /// <p>A timestamp for the date and time when the invitation was sent to the member account.</p>
-    #[serde(rename = "InvitedAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub invited_at: Option<f64>,
-    /// <p>The AWS account ID of the Security Hub master account associated with this member account.</p>
+impl MalwareState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MalwareState::Observed => "OBSERVED",
+            MalwareState::RemovalFailed => "REMOVAL_FAILED",
+            MalwareState::Removed => "REMOVED",
+            MalwareState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MalwareState {
+    fn from(value: &'a str) -> MalwareState {
+        match value {
+            "OBSERVED" => MalwareState::Observed,
+            "REMOVAL_FAILED" => MalwareState::RemovalFailed,
+            "REMOVED" => MalwareState::Removed,
+            _ => MalwareState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MalwareState {
+    fn from(value: String) -> MalwareState {
+        MalwareState::from(value.as_str())
+    }
+}
+
+impl From<MalwareState> for String {
+    fn from(value: MalwareState) -> String {
+        match value {
+            MalwareState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MalwareState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MalwareState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MalwareState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MalwareState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MalwareState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MalwareState::from(value))
+    }
+}
+
+/// <p>The type of the malware that was observed.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MalwareType {
+    /// <p><code>ADWARE</code></p>
+    Adware,
+    /// <p><code>BLENDED_THREAT</code></p>
+    BlendedThreat,
+    /// <p><code>BOTNET_AGENT</code></p>
+    BotnetAgent,
+    /// <p><code>COIN_MINER</code></p>
+    CoinMiner,
+    /// <p><code>EXPLOIT_KIT</code></p>
+    ExploitKit,
+    /// <p><code>KEYLOGGER</code></p>
+    Keylogger,
+    /// <p><code>MACRO</code></p>
+    Macro,
+    /// <p><code>POTENTIALLY_UNWANTED</code></p>
+    PotentiallyUnwanted,
+    /// <p><code>SPYWARE</code></p>
+    Spyware,
+    /// <p><code>RANSOMWARE</code></p>
+    Ransomware,
+    /// <p><code>REMOTE_ACCESS</code></p>
+    RemoteAccess,
+    /// <p><code>ROOTKIT</code></p>
+    Rootkit,
+    /// <p><code>TROJAN</code></p>
+    Trojan,
+    /// <p><code>VIRUS</code></p>
+    Virus,
+    /// <p><code>WORM</code></p>
+    Worm,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MalwareType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MalwareType::Adware => "ADWARE",
+            MalwareType::BlendedThreat => "BLENDED_THREAT",
+            MalwareType::BotnetAgent => "BOTNET_AGENT",
+            MalwareType::CoinMiner => "COIN_MINER",
+            MalwareType::ExploitKit => "EXPLOIT_KIT",
+            MalwareType::Keylogger => "KEYLOGGER",
+            MalwareType::Macro => "MACRO",
+            MalwareType::PotentiallyUnwanted => "POTENTIALLY_UNWANTED",
+            MalwareType::Spyware => "SPYWARE",
+            MalwareType::Ransomware => "RANSOMWARE",
+            MalwareType::RemoteAccess => "REMOTE_ACCESS",
+            MalwareType::Rootkit => "ROOTKIT",
+            MalwareType::Trojan => "TROJAN",
+            MalwareType::Virus => "VIRUS",
+            MalwareType::Worm => "WORM",
+            MalwareType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MalwareType {
+    fn from(value: &'a str) -> MalwareType {
+        match value {
+            "ADWARE" => MalwareType::Adware,
+            "BLENDED_THREAT" => MalwareType::BlendedThreat,
+            "BOTNET_AGENT" => MalwareType::BotnetAgent,
+            "COIN_MINER" => MalwareType::CoinMiner,
+            "EXPLOIT_KIT" => MalwareType::ExploitKit,
+            "KEYLOGGER" => MalwareType::Keylogger,
+            "MACRO" => MalwareType::Macro,
+            "POTENTIALLY_UNWANTED" => MalwareType::PotentiallyUnwanted,
+            "SPYWARE" => MalwareType::Spyware,
+            "RANSOMWARE" => MalwareType::Ransomware,
+            "REMOTE_ACCESS" => MalwareType::RemoteAccess,
+            "ROOTKIT" => MalwareType::Rootkit,
+            "TROJAN" => MalwareType::Trojan,
+            "VIRUS" => MalwareType::Virus,
+            "WORM" => MalwareType::Worm,
+            _ => MalwareType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MalwareType {
+    fn from(value: String) -> MalwareType {
+        MalwareType::from(value.as_str())
+    }
+}
+
+impl From<MalwareType> for String {
+    fn from(value: MalwareType) -> String {
+        match value {
+            MalwareType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MalwareType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MalwareType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MalwareType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MalwareType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MalwareType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MalwareType::from(value))
+    }
+}
+
+/// <p>The map filter for querying findings.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapFilter {
+    /// <p>The condition to apply to a key value when querying for findings with a map filter.</p>
+    #[serde(rename = "Comparison")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comparison: Option<MapFilterComparison>,
+    /// <p>The key of the map filter.</p>
+    #[serde(rename = "Key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// <p>The value for the key in the map filter.</p>
+    #[serde(rename = "Value")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// <p>The condition to apply to a key value when querying for findings with a map filter.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MapFilterComparison {
+    /// <p><code>EQUALS</code></p>
+    Equals,
+    /// <p><code>NOT_EQUALS</code></p>
+    NotEquals,
+    /// <p><code>CONTAINS</code></p>
+    Contains,
+    /// <p><code>NOT_CONTAINS</code></p>
+    NotContains,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MapFilterComparison {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MapFilterComparison::Equals => "EQUALS",
+            MapFilterComparison::NotEquals => "NOT_EQUALS",
+            MapFilterComparison::Contains => "CONTAINS",
+            MapFilterComparison::NotContains => "NOT_CONTAINS",
+            MapFilterComparison::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MapFilterComparison {
+    fn from(value: &'a str) -> MapFilterComparison {
+        match value {
+            "EQUALS" => MapFilterComparison::Equals,
+            "NOT_EQUALS" => MapFilterComparison::NotEquals,
+            "CONTAINS" => MapFilterComparison::Contains,
+            "NOT_CONTAINS" => MapFilterComparison::NotContains,
+            _ => MapFilterComparison::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MapFilterComparison {
+    fn from(value: String) -> MapFilterComparison {
+        MapFilterComparison::from(value.as_str())
+    }
+}
+
+impl From<MapFilterComparison> for String {
+    fn from(value: MapFilterComparison) -> String {
+        match value {
+            MapFilterComparison::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MapFilterComparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MapFilterComparison {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MapFilterComparison {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MapFilterComparison {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MapFilterComparison, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MapFilterComparison::from(value))
+    }
+}
+
+/// <p>The details about a member account.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Member {
+    /// <p>The AWS account ID of the member account.</p>
+    #[serde(rename = "AccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// <p>The email address of the member account.</p>
+    #[serde(rename = "Email")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// <p>A timestamp for the date and time when the invitation was sent to the member account.</p>
+    #[serde(rename = "InvitedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invited_at: Option<EpochTimestamp>,
+    /// <p>The AWS account ID of the Security Hub master account associated with this member account.</p>
     #[serde(rename = "MasterId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub master_id: Option<String>,
@@ -1400,8 +2980,9 @@ pub struct Member {
     pub member_status: Option<String>,
     /// <p>The timestamp for the date and time when the member account was updated.</p>
     #[serde(rename = "UpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<EpochTimestamp>,
 }
 
 /// <p>The details of network-related information about a finding.</p>
@@ -1426,7 +3007,7 @@ pub struct Network {
     /// <p>The direction of network traffic associated with a finding.</p>
     #[serde(rename = "Direction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub direction: Option<String>,
+    pub direction: Option<NetworkDirection>,
     /// <p>The protocol of network-related information about a finding.</p>
     #[serde(rename = "Protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1453,6 +3034,84 @@ pub struct Network {
     pub source_port: Option<i64>,
 }
 
+/// <p>The direction of network traffic associated with a finding.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NetworkDirection {
+    /// <p><code>IN</code></p>
+    In,
+    /// <p><code>OUT</code></p>
+    Out,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NetworkDirection {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkDirection::In => "IN",
+            NetworkDirection::Out => "OUT",
+            NetworkDirection::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NetworkDirection {
+    fn from(value: &'a str) -> NetworkDirection {
+        match value {
+            "IN" => NetworkDirection::In,
+            "OUT" => NetworkDirection::Out,
+            _ => NetworkDirection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkDirection {
+    fn from(value: String) -> NetworkDirection {
+        NetworkDirection::from(value.as_str())
+    }
+}
+
+impl From<NetworkDirection> for String {
+    fn from(value: NetworkDirection) -> String {
+        match value {
+            NetworkDirection::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NetworkDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NetworkDirection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NetworkDirection {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkDirection {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NetworkDirection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NetworkDirection::from(value))
+    }
+}
+
 /// <p>A user-defined note added to a finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Note {
@@ -1495,6 +3154,88 @@ pub struct NumberFilter {
     pub lte: Option<f64>,
 }
 
+/// <p>The canonical AWS partition name that the Region is assigned to.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Partition {
+    /// <p><code>aws</code></p>
+    Aws,
+    /// <p><code>aws-cn</code></p>
+    AwsCn,
+    /// <p><code>aws-us-gov</code></p>
+    AwsUsGov,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Partition {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Partition::Aws => "aws",
+            Partition::AwsCn => "aws-cn",
+            Partition::AwsUsGov => "aws-us-gov",
+            Partition::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Partition {
+    fn from(value: &'a str) -> Partition {
+        match value {
+            "aws" => Partition::Aws,
+            "aws-cn" => Partition::AwsCn,
+            "aws-us-gov" => Partition::AwsUsGov,
+            _ => Partition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Partition {
+    fn from(value: String) -> Partition {
+        Partition::from(value.as_str())
+    }
+}
+
+impl From<Partition> for String {
+    fn from(value: Partition) -> String {
+        match value {
+            Partition::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Partition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Partition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Partition {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Partition {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Partition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Partition::from(value))
+    }
+}
+
 /// <p>The details of process-related information about a finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProcessDetails {
@@ -1574,6 +3315,84 @@ pub struct Recommendation {
     pub url: Option<String>,
 }
 
+/// <p>The record state of a finding.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordState {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>ARCHIVED</code></p>
+    Archived,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RecordState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordState::Active => "ACTIVE",
+            RecordState::Archived => "ARCHIVED",
+            RecordState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RecordState {
+    fn from(value: &'a str) -> RecordState {
+        match value {
+            "ACTIVE" => RecordState::Active,
+            "ARCHIVED" => RecordState::Archived,
+            _ => RecordState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordState {
+    fn from(value: String) -> RecordState {
+        RecordState::from(value.as_str())
+    }
+}
+
+impl From<RecordState> for String {
+    fn from(value: RecordState) -> String {
+        match value {
+            RecordState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RecordState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RecordState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RecordState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RecordState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RecordState::from(value))
+    }
+}
+
 /// <p>Details about a related finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RelatedFinding {
@@ -1607,7 +3426,7 @@ pub struct Resource {
     /// <p>The canonical AWS partition name that the Region is assigned to.</p>
     #[serde(rename = "Partition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub partition: Option<String>,
+    pub partition: Option<Partition>,
     /// <p>The canonical AWS external Region name where this resource is located.</p>
     #[serde(rename = "Region")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1682,35 +3501,203 @@ pub struct SortCriterion {
     /// <p>The order used to sort findings.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
-/// <p>A resource that represents your subscription to a supported standard.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct StandardsSubscription {
-    /// <p>The ARN of a standard.</p> <p>In this release, Security Hub supports only the CIS AWS Foundations standard, which uses the following ARN: <code>arn:aws:securityhub:::ruleset/cis-aws-foundations-benchmark/v/1.2.0.</code> </p>
-    #[serde(rename = "StandardsArn")]
-    pub standards_arn: String,
-    /// <p>A key-value pair of input for the standard.</p>
-    #[serde(rename = "StandardsInput")]
-    pub standards_input: ::std::collections::HashMap<String, String>,
-    /// <p>The status of the standards subscription.</p>
-    #[serde(rename = "StandardsStatus")]
-    pub standards_status: String,
-    /// <p>The ARN of a resource that represents your subscription to a supported standard.</p>
-    #[serde(rename = "StandardsSubscriptionArn")]
-    pub standards_subscription_arn: String,
+/// <p>The order used to sort findings.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// <p><code>asc</code></p>
+    Asc,
+    /// <p><code>desc</code></p>
+    Desc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>The standard that you want to enable.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct StandardsSubscriptionRequest {
-    /// <p><p>The ARN of the standard that you want to enable.</p> <important> <p>In this release, Security Hub only supports the CIS AWS Foundations standard. </p> <p>Its ARN is <code>arn:aws:securityhub:::ruleset/cis-aws-foundations-benchmark/v/1.2.0</code>.</p> </important></p>
-    #[serde(rename = "StandardsArn")]
-    pub standards_arn: String,
-    /// <p>A key-value pair of input for the standard.</p>
-    #[serde(rename = "StandardsInput")]
+impl SortOrder {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "asc" => SortOrder::Asc,
+            "desc" => SortOrder::Desc,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        SortOrder::from(value.as_str())
+    }
+}
+
+impl From<SortOrder> for String {
+    fn from(value: SortOrder) -> String {
+        match value {
+            SortOrder::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SortOrder::from(value))
+    }
+}
+
+/// <p>The status of the standards subscription.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StandardsStatus {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>READY</code></p>
+    Ready,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>INCOMPLETE</code></p>
+    Incomplete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StandardsStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StandardsStatus::Pending => "PENDING",
+            StandardsStatus::Ready => "READY",
+            StandardsStatus::Failed => "FAILED",
+            StandardsStatus::Deleting => "DELETING",
+            StandardsStatus::Incomplete => "INCOMPLETE",
+            StandardsStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StandardsStatus {
+    fn from(value: &'a str) -> StandardsStatus {
+        match value {
+            "PENDING" => StandardsStatus::Pending,
+            "READY" => StandardsStatus::Ready,
+            "FAILED" => StandardsStatus::Failed,
+            "DELETING" => StandardsStatus::Deleting,
+            "INCOMPLETE" => StandardsStatus::Incomplete,
+            _ => StandardsStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StandardsStatus {
+    fn from(value: String) -> StandardsStatus {
+        StandardsStatus::from(value.as_str())
+    }
+}
+
+impl From<StandardsStatus> for String {
+    fn from(value: StandardsStatus) -> String {
+        match value {
+            StandardsStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StandardsStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StandardsStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StandardsStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StandardsStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StandardsStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StandardsStatus::from(value))
+    }
+}
+
+/// <p>A resource that represents your subscription to a supported standard.</p>
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct StandardsSubscription {
+    /// <p>The ARN of a standard.</p> <p>In this release, Security Hub supports only the CIS AWS Foundations standard, which uses the following ARN: <code>arn:aws:securityhub:::ruleset/cis-aws-foundations-benchmark/v/1.2.0.</code> </p>
+    #[serde(rename = "StandardsArn")]
+    pub standards_arn: String,
+    /// <p>A key-value pair of input for the standard.</p>
+    #[serde(rename = "StandardsInput")]
+    pub standards_input: ::std::collections::HashMap<String, String>,
+    /// <p>The status of the standards subscription.</p>
+    #[serde(rename = "StandardsStatus")]
+    pub standards_status: StandardsStatus,
+    /// <p>The ARN of a resource that represents your subscription to a supported standard.</p>
+    #[serde(rename = "StandardsSubscriptionArn")]
+    pub standards_subscription_arn: String,
+}
+
+/// <p>The standard that you want to enable.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct StandardsSubscriptionRequest {
+    /// <p><p>The ARN of the standard that you want to enable.</p> <important> <p>In this release, Security Hub only supports the CIS AWS Foundations standard. </p> <p>Its ARN is <code>arn:aws:securityhub:::ruleset/cis-aws-foundations-benchmark/v/1.2.0</code>.</p> </important></p>
+    #[serde(rename = "StandardsArn")]
+    pub standards_arn: String,
+    /// <p>A key-value pair of input for the standard.</p>
+    #[serde(rename = "StandardsInput")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub standards_input: Option<::std::collections::HashMap<String, String>>,
 }
@@ -1721,13 +3708,107 @@ pub struct StringFilter {
     /// <p>The condition to be applied to a string value when querying for findings. </p>
     #[serde(rename = "Comparison")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison: Option<String>,
+    pub comparison: Option<StringFilterComparison>,
     /// <p>The string filter value.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p>The condition to be applied to a string value when querying for findings. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StringFilterComparison {
+    /// <p><code>EQUALS</code></p>
+    Equals,
+    /// <p><code>PREFIX</code></p>
+    Prefix,
+    /// <p><code>NOT_EQUALS</code></p>
+    NotEquals,
+    /// <p><code>PREFIX_NOT_EQUALS</code></p>
+    PrefixNotEquals,
+    /// <p><code>CONTAINS</code></p>
+    Contains,
+    /// <p><code>NOT_CONTAINS</code></p>
+    NotContains,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StringFilterComparison {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StringFilterComparison::Equals => "EQUALS",
+            StringFilterComparison::Prefix => "PREFIX",
+            StringFilterComparison::NotEquals => "NOT_EQUALS",
+            StringFilterComparison::PrefixNotEquals => "PREFIX_NOT_EQUALS",
+            StringFilterComparison::Contains => "CONTAINS",
+            StringFilterComparison::NotContains => "NOT_CONTAINS",
+            StringFilterComparison::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StringFilterComparison {
+    fn from(value: &'a str) -> StringFilterComparison {
+        match value {
+            "EQUALS" => StringFilterComparison::Equals,
+            "PREFIX" => StringFilterComparison::Prefix,
+            "NOT_EQUALS" => StringFilterComparison::NotEquals,
+            "PREFIX_NOT_EQUALS" => StringFilterComparison::PrefixNotEquals,
+            "CONTAINS" => StringFilterComparison::Contains,
+            "NOT_CONTAINS" => StringFilterComparison::NotContains,
+            _ => StringFilterComparison::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StringFilterComparison {
+    fn from(value: String) -> StringFilterComparison {
+        StringFilterComparison::from(value.as_str())
+    }
+}
+
+impl From<StringFilterComparison> for String {
+    fn from(value: StringFilterComparison) -> String {
+        match value {
+            StringFilterComparison::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StringFilterComparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StringFilterComparison {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StringFilterComparison {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StringFilterComparison {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StringFilterComparison, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StringFilterComparison::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TagResourceRequest {
     /// <p>The ARN of the resource to apply the tags to.</p>
@@ -1738,6 +3819,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -1748,7 +3869,7 @@ pub struct ThreatIntelIndicator {
     /// <p>The category of a threat intel indicator.</p>
     #[serde(rename = "Category")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
+    pub category: Option<ThreatIntelIndicatorCategory>,
     /// <p>The date and time when the most recent instance of a threat intel indicator was observed.</p>
     #[serde(rename = "LastObservedAt")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1764,13 +3885,223 @@ pub struct ThreatIntelIndicator {
     /// <p>The type of a threat intel indicator.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ThreatIntelIndicatorType>,
     /// <p>The value of a threat intel indicator.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p>The category of a threat intel indicator.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ThreatIntelIndicatorCategory {
+    /// <p><code>BACKDOOR</code></p>
+    Backdoor,
+    /// <p><code>CARD_STEALER</code></p>
+    CardStealer,
+    /// <p><code>COMMAND_AND_CONTROL</code></p>
+    CommandAndControl,
+    /// <p><code>DROP_SITE</code></p>
+    DropSite,
+    /// <p><code>EXPLOIT_SITE</code></p>
+    ExploitSite,
+    /// <p><code>KEYLOGGER</code></p>
+    Keylogger,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ThreatIntelIndicatorCategory {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThreatIntelIndicatorCategory::Backdoor => "BACKDOOR",
+            ThreatIntelIndicatorCategory::CardStealer => "CARD_STEALER",
+            ThreatIntelIndicatorCategory::CommandAndControl => "COMMAND_AND_CONTROL",
+            ThreatIntelIndicatorCategory::DropSite => "DROP_SITE",
+            ThreatIntelIndicatorCategory::ExploitSite => "EXPLOIT_SITE",
+            ThreatIntelIndicatorCategory::Keylogger => "KEYLOGGER",
+            ThreatIntelIndicatorCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ThreatIntelIndicatorCategory {
+    fn from(value: &'a str) -> ThreatIntelIndicatorCategory {
+        match value {
+            "BACKDOOR" => ThreatIntelIndicatorCategory::Backdoor,
+            "CARD_STEALER" => ThreatIntelIndicatorCategory::CardStealer,
+            "COMMAND_AND_CONTROL" => ThreatIntelIndicatorCategory::CommandAndControl,
+            "DROP_SITE" => ThreatIntelIndicatorCategory::DropSite,
+            "EXPLOIT_SITE" => ThreatIntelIndicatorCategory::ExploitSite,
+            "KEYLOGGER" => ThreatIntelIndicatorCategory::Keylogger,
+            _ => ThreatIntelIndicatorCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThreatIntelIndicatorCategory {
+    fn from(value: String) -> ThreatIntelIndicatorCategory {
+        ThreatIntelIndicatorCategory::from(value.as_str())
+    }
+}
+
+impl From<ThreatIntelIndicatorCategory> for String {
+    fn from(value: ThreatIntelIndicatorCategory) -> String {
+        match value {
+            ThreatIntelIndicatorCategory::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ThreatIntelIndicatorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThreatIntelIndicatorCategory {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThreatIntelIndicatorCategory {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThreatIntelIndicatorCategory {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ThreatIntelIndicatorCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ThreatIntelIndicatorCategory::from(value))
+    }
+}
+
+/// <p>The type of a threat intel indicator.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ThreatIntelIndicatorType {
+    /// <p><code>DOMAIN</code></p>
+    Domain,
+    /// <p><code>EMAIL_ADDRESS</code></p>
+    EmailAddress,
+    /// <p><code>HASH_MD5</code></p>
+    HashMd5,
+    /// <p><code>HASH_SHA1</code></p>
+    HashSha1,
+    /// <p><code>HASH_SHA256</code></p>
+    HashSha256,
+    /// <p><code>HASH_SHA512</code></p>
+    HashSha512,
+    /// <p><code>IPV4_ADDRESS</code></p>
+    Ipv4Address,
+    /// <p><code>IPV6_ADDRESS</code></p>
+    Ipv6Address,
+    /// <p><code>MUTEX</code></p>
+    Mutex,
+    /// <p><code>PROCESS</code></p>
+    Process,
+    /// <p><code>URL</code></p>
+    Url,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ThreatIntelIndicatorType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThreatIntelIndicatorType::Domain => "DOMAIN",
+            ThreatIntelIndicatorType::EmailAddress => "EMAIL_ADDRESS",
+            ThreatIntelIndicatorType::HashMd5 => "HASH_MD5",
+            ThreatIntelIndicatorType::HashSha1 => "HASH_SHA1",
+            ThreatIntelIndicatorType::HashSha256 => "HASH_SHA256",
+            ThreatIntelIndicatorType::HashSha512 => "HASH_SHA512",
+            ThreatIntelIndicatorType::Ipv4Address => "IPV4_ADDRESS",
+            ThreatIntelIndicatorType::Ipv6Address => "IPV6_ADDRESS",
+            ThreatIntelIndicatorType::Mutex => "MUTEX",
+            ThreatIntelIndicatorType::Process => "PROCESS",
+            ThreatIntelIndicatorType::Url => "URL",
+            ThreatIntelIndicatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ThreatIntelIndicatorType {
+    fn from(value: &'a str) -> ThreatIntelIndicatorType {
+        match value {
+            "DOMAIN" => ThreatIntelIndicatorType::Domain,
+            "EMAIL_ADDRESS" => ThreatIntelIndicatorType::EmailAddress,
+            "HASH_MD5" => ThreatIntelIndicatorType::HashMd5,
+            "HASH_SHA1" => ThreatIntelIndicatorType::HashSha1,
+            "HASH_SHA256" => ThreatIntelIndicatorType::HashSha256,
+            "HASH_SHA512" => ThreatIntelIndicatorType::HashSha512,
+            "IPV4_ADDRESS" => ThreatIntelIndicatorType::Ipv4Address,
+            "IPV6_ADDRESS" => ThreatIntelIndicatorType::Ipv6Address,
+            "MUTEX" => ThreatIntelIndicatorType::Mutex,
+            "PROCESS" => ThreatIntelIndicatorType::Process,
+            "URL" => ThreatIntelIndicatorType::Url,
+            _ => ThreatIntelIndicatorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThreatIntelIndicatorType {
+    fn from(value: String) -> ThreatIntelIndicatorType {
+        ThreatIntelIndicatorType::from(value.as_str())
+    }
+}
+
+impl From<ThreatIntelIndicatorType> for String {
+    fn from(value: ThreatIntelIndicatorType) -> String {
+        match value {
+            ThreatIntelIndicatorType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ThreatIntelIndicatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThreatIntelIndicatorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThreatIntelIndicatorType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThreatIntelIndicatorType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ThreatIntelIndicatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ThreatIntelIndicatorType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     /// <p>The ARN of the resource to remove the tags from.</p>
@@ -1781,6 +4112,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -1800,6 +4171,54 @@ pub struct UpdateActionTargetRequest {
     pub name: Option<String>,
 }
 
+impl UpdateActionTargetRequest {
+    /// Returns a builder for `UpdateActionTargetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateActionTargetRequestBuilder {
+        UpdateActionTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateActionTargetRequest`](struct.UpdateActionTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateActionTargetRequestBuilder {
+    action_target_arn: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateActionTargetRequestBuilder {
+    /// Sets the [`action_target_arn`](struct.UpdateActionTargetRequest.html#structfield.action_target_arn) member.
+    pub fn action_target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.action_target_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateActionTargetRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateActionTargetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateActionTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateActionTargetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateActionTargetRequest {
+            action_target_arn: self
+                .action_target_arn
+                .ok_or(::rusoto_core::BuildError::missing("action_target_arn"))?,
+            description: self.description,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateActionTargetResponse {}
@@ -1816,35 +4235,311 @@ pub struct UpdateFindingsRequest {
     /// <p>The updated record state for the finding.</p>
     #[serde(rename = "RecordState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub record_state: Option<String>,
+    pub record_state: Option<RecordState>,
+}
+
+impl UpdateFindingsRequest {
+    /// Returns a builder for `UpdateFindingsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFindingsRequestBuilder {
+        UpdateFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFindingsRequest`](struct.UpdateFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFindingsRequestBuilder {
+    filters: Option<AwsSecurityFindingFilters>,
+    note: Option<NoteUpdate>,
+    record_state: Option<RecordState>,
+}
+
+impl UpdateFindingsRequestBuilder {
+    /// Sets the [`filters`](struct.UpdateFindingsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<AwsSecurityFindingFilters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`note`](struct.UpdateFindingsRequest.html#structfield.note) member.
+    pub fn note<T: Into<NoteUpdate>>(mut self, value: T) -> Self {
+        self.note = Some(value.into());
+        self
+    }
+
+    /// Sets the [`record_state`](struct.UpdateFindingsRequest.html#structfield.record_state) member.
+    pub fn record_state<T: Into<RecordState>>(mut self, value: T) -> Self {
+        self.record_state = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFindingsRequest {
+            filters: self
+                .filters
+                .ok_or(::rusoto_core::BuildError::missing("filters"))?,
+            note: self.note,
+            record_state: self.record_state,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateFindingsResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateInsightRequest {
+    /// <p>The updated filters that define this insight.</p>
+    #[serde(rename = "Filters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<AwsSecurityFindingFilters>,
+    /// <p>The updated <code>GroupBy</code> attribute that defines this insight.</p>
+    #[serde(rename = "GroupByAttribute")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by_attribute: Option<String>,
+    /// <p>The ARN of the insight that you want to update.</p>
+    #[serde(rename = "InsightArn")]
+    pub insight_arn: String,
+    /// <p>The updated name for the insight.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl UpdateInsightRequest {
+    /// Returns a builder for `UpdateInsightRequest`, checking its required members when built.
+    pub fn builder() -> UpdateInsightRequestBuilder {
+        UpdateInsightRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateInsightRequest`](struct.UpdateInsightRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateInsightRequestBuilder {
+    filters: Option<AwsSecurityFindingFilters>,
+    group_by_attribute: Option<String>,
+    insight_arn: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateInsightRequestBuilder {
+    /// Sets the [`filters`](struct.UpdateInsightRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<AwsSecurityFindingFilters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_by_attribute`](struct.UpdateInsightRequest.html#structfield.group_by_attribute) member.
+    pub fn group_by_attribute<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_by_attribute = Some(value.into());
+        self
+    }
+
+    /// Sets the [`insight_arn`](struct.UpdateInsightRequest.html#structfield.insight_arn) member.
+    pub fn insight_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.insight_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateInsightRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateInsightRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateInsightRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateInsightRequest {
+            filters: self.filters,
+            group_by_attribute: self.group_by_attribute,
+            insight_arn: self
+                .insight_arn
+                .ok_or(::rusoto_core::BuildError::missing("insight_arn"))?,
+            name: self.name,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateInsightResponse {}
+
+/// <p>Indicates the veracity of a finding. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VerificationState {
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// <p><code>TRUE_POSITIVE</code></p>
+    TruePositive,
+    /// <p><code>FALSE_POSITIVE</code></p>
+    FalsePositive,
+    /// <p><code>BENIGN_POSITIVE</code></p>
+    BenignPositive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VerificationState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VerificationState::UnknownValue => "UNKNOWN",
+            VerificationState::TruePositive => "TRUE_POSITIVE",
+            VerificationState::FalsePositive => "FALSE_POSITIVE",
+            VerificationState::BenignPositive => "BENIGN_POSITIVE",
+            VerificationState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VerificationState {
+    fn from(value: &'a str) -> VerificationState {
+        match value {
+            "UNKNOWN" => VerificationState::UnknownValue,
+            "TRUE_POSITIVE" => VerificationState::TruePositive,
+            "FALSE_POSITIVE" => VerificationState::FalsePositive,
+            "BENIGN_POSITIVE" => VerificationState::BenignPositive,
+            _ => VerificationState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VerificationState {
+    fn from(value: String) -> VerificationState {
+        VerificationState::from(value.as_str())
+    }
+}
+
+impl From<VerificationState> for String {
+    fn from(value: VerificationState) -> String {
+        match value {
+            VerificationState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VerificationState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VerificationState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VerificationState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VerificationState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VerificationState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VerificationState::from(value))
+    }
+}
+
+/// <p>The workflow state of a finding. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkflowState {
+    /// <p><code>NEW</code></p>
+    New,
+    /// <p><code>ASSIGNED</code></p>
+    Assigned,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>DEFERRED</code></p>
+    Deferred,
+    /// <p><code>RESOLVED</code></p>
+    Resolved,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkflowState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkflowState::New => "NEW",
+            WorkflowState::Assigned => "ASSIGNED",
+            WorkflowState::InProgress => "IN_PROGRESS",
+            WorkflowState::Deferred => "DEFERRED",
+            WorkflowState::Resolved => "RESOLVED",
+            WorkflowState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowState {
+    fn from(value: &'a str) -> WorkflowState {
+        match value {
+            "NEW" => WorkflowState::New,
+            "ASSIGNED" => WorkflowState::Assigned,
+            "IN_PROGRESS" => WorkflowState::InProgress,
+            "DEFERRED" => WorkflowState::Deferred,
+            "RESOLVED" => WorkflowState::Resolved,
+            _ => WorkflowState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkflowState {
+    fn from(value: String) -> WorkflowState {
+        WorkflowState::from(value.as_str())
+    }
+}
+
+impl From<WorkflowState> for String {
+    fn from(value: WorkflowState) -> String {
+        match value {
+            WorkflowState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateFindingsResponse {}
+impl fmt::Display for WorkflowState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateInsightRequest {
-    /// <p>The updated filters that define this insight.</p>
-    #[serde(rename = "Filters")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filters: Option<AwsSecurityFindingFilters>,
-    /// <p>The updated <code>GroupBy</code> attribute that defines this insight.</p>
-    #[serde(rename = "GroupByAttribute")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub group_by_attribute: Option<String>,
-    /// <p>The ARN of the insight that you want to update.</p>
-    #[serde(rename = "InsightArn")]
-    pub insight_arn: String,
-    /// <p>The updated name for the insight.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+impl ::rusoto_core::param::ToParam for WorkflowState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateInsightResponse {}
+impl ::serde::Serialize for WorkflowState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkflowState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WorkflowState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkflowState::from(value))
+    }
+}
 
 /// Errors returned by AcceptInvitation
 #[derive(Debug, PartialEq)]
@@ -4154,6 +6849,240 @@ pub trait SecurityHub {
         input: UpdateInsightRequest,
     ) -> RusotoFuture<UpdateInsightResponse, UpdateInsightError>;
 }
+impl<T: ?Sized + SecurityHub> SecurityHub for ::std::sync::Arc<T> {
+    fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
+        (**self).accept_invitation(input)
+    }
+    fn batch_disable_standards(
+        &self,
+        input: BatchDisableStandardsRequest,
+    ) -> RusotoFuture<BatchDisableStandardsResponse, BatchDisableStandardsError> {
+        (**self).batch_disable_standards(input)
+    }
+    fn batch_enable_standards(
+        &self,
+        input: BatchEnableStandardsRequest,
+    ) -> RusotoFuture<BatchEnableStandardsResponse, BatchEnableStandardsError> {
+        (**self).batch_enable_standards(input)
+    }
+    fn batch_import_findings(
+        &self,
+        input: BatchImportFindingsRequest,
+    ) -> RusotoFuture<BatchImportFindingsResponse, BatchImportFindingsError> {
+        (**self).batch_import_findings(input)
+    }
+    fn create_action_target(
+        &self,
+        input: CreateActionTargetRequest,
+    ) -> RusotoFuture<CreateActionTargetResponse, CreateActionTargetError> {
+        (**self).create_action_target(input)
+    }
+    fn create_insight(
+        &self,
+        input: CreateInsightRequest,
+    ) -> RusotoFuture<CreateInsightResponse, CreateInsightError> {
+        (**self).create_insight(input)
+    }
+    fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
+        (**self).create_members(input)
+    }
+    fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
+        (**self).decline_invitations(input)
+    }
+    fn delete_action_target(
+        &self,
+        input: DeleteActionTargetRequest,
+    ) -> RusotoFuture<DeleteActionTargetResponse, DeleteActionTargetError> {
+        (**self).delete_action_target(input)
+    }
+    fn delete_insight(
+        &self,
+        input: DeleteInsightRequest,
+    ) -> RusotoFuture<DeleteInsightResponse, DeleteInsightError> {
+        (**self).delete_insight(input)
+    }
+    fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
+        (**self).delete_invitations(input)
+    }
+    fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
+        (**self).delete_members(input)
+    }
+    fn describe_action_targets(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> RusotoFuture<DescribeActionTargetsResponse, DescribeActionTargetsError> {
+        (**self).describe_action_targets(input)
+    }
+    fn describe_hub(
+        &self,
+        input: DescribeHubRequest,
+    ) -> RusotoFuture<DescribeHubResponse, DescribeHubError> {
+        (**self).describe_hub(input)
+    }
+    fn describe_products(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> RusotoFuture<DescribeProductsResponse, DescribeProductsError> {
+        (**self).describe_products(input)
+    }
+    fn disable_import_findings_for_product(
+        &self,
+        input: DisableImportFindingsForProductRequest,
+    ) -> RusotoFuture<DisableImportFindingsForProductResponse, DisableImportFindingsForProductError>
+    {
+        (**self).disable_import_findings_for_product(input)
+    }
+    fn disable_security_hub(
+        &self,
+    ) -> RusotoFuture<DisableSecurityHubResponse, DisableSecurityHubError> {
+        (**self).disable_security_hub()
+    }
+    fn disassociate_from_master_account(
+        &self,
+    ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
+    {
+        (**self).disassociate_from_master_account()
+    }
+    fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
+        (**self).disassociate_members(input)
+    }
+    fn enable_import_findings_for_product(
+        &self,
+        input: EnableImportFindingsForProductRequest,
+    ) -> RusotoFuture<EnableImportFindingsForProductResponse, EnableImportFindingsForProductError>
+    {
+        (**self).enable_import_findings_for_product(input)
+    }
+    fn enable_security_hub(
+        &self,
+        input: EnableSecurityHubRequest,
+    ) -> RusotoFuture<EnableSecurityHubResponse, EnableSecurityHubError> {
+        (**self).enable_security_hub(input)
+    }
+    fn get_enabled_standards(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> RusotoFuture<GetEnabledStandardsResponse, GetEnabledStandardsError> {
+        (**self).get_enabled_standards(input)
+    }
+    fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
+        (**self).get_findings(input)
+    }
+    fn get_insight_results(
+        &self,
+        input: GetInsightResultsRequest,
+    ) -> RusotoFuture<GetInsightResultsResponse, GetInsightResultsError> {
+        (**self).get_insight_results(input)
+    }
+    fn get_insights(
+        &self,
+        input: GetInsightsRequest,
+    ) -> RusotoFuture<GetInsightsResponse, GetInsightsError> {
+        (**self).get_insights(input)
+    }
+    fn get_invitations_count(
+        &self,
+    ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
+        (**self).get_invitations_count()
+    }
+    fn get_master_account(&self) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
+        (**self).get_master_account()
+    }
+    fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
+        (**self).get_members(input)
+    }
+    fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
+        (**self).invite_members(input)
+    }
+    fn list_enabled_products_for_import(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> RusotoFuture<ListEnabledProductsForImportResponse, ListEnabledProductsForImportError> {
+        (**self).list_enabled_products_for_import(input)
+    }
+    fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
+        (**self).list_invitations(input)
+    }
+    fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
+        (**self).list_members(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_action_target(
+        &self,
+        input: UpdateActionTargetRequest,
+    ) -> RusotoFuture<UpdateActionTargetResponse, UpdateActionTargetError> {
+        (**self).update_action_target(input)
+    }
+    fn update_findings(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> RusotoFuture<UpdateFindingsResponse, UpdateFindingsError> {
+        (**self).update_findings(input)
+    }
+    fn update_insight(
+        &self,
+        input: UpdateInsightRequest,
+    ) -> RusotoFuture<UpdateInsightResponse, UpdateInsightError> {
+        (**self).update_insight(input)
+    }
+}
+/// The error codes AWS SecurityHub retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS SecurityHub API.
 #[derive(Clone)]
 pub struct SecurityHubClient {
@@ -4169,6 +7098,27 @@ impl SecurityHubClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SECURITYHUB`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> SecurityHubClient {
+        Self::new(region::Region::from_env_for_service("SECURITYHUB"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `securityhub`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> SecurityHubClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("securityhub", "SECURITYHUB"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4187,7 +7137,54 @@ impl SecurityHubClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SecurityHubClient {
-        SecurityHubClient { client, region }
+        SecurityHubClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "securityhub")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "securityhub",
+            "securityhub",
+            "SECURITYHUB",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `SecurityHub` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn SecurityHub + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for SecurityHubClient {
+    fn service_name(&self) -> &'static str {
+        "AWS SecurityHub"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "securityhub"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> SecurityHubClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>A nested application summary.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -86,6 +87,92 @@ pub struct ApplicationSummary {
     pub spdx_license_id: Option<String>,
 }
 
+/// <p>Values that must be specified in order to deploy some applications.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// <p><code>CAPABILITY_IAM</code></p>
+    CapabilityIam,
+    /// <p><code>CAPABILITY_NAMED_IAM</code></p>
+    CapabilityNamedIam,
+    /// <p><code>CAPABILITY_AUTO_EXPAND</code></p>
+    CapabilityAutoExpand,
+    /// <p><code>CAPABILITY_RESOURCE_POLICY</code></p>
+    CapabilityResourcePolicy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Capability {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Capability::CapabilityIam => "CAPABILITY_IAM",
+            Capability::CapabilityNamedIam => "CAPABILITY_NAMED_IAM",
+            Capability::CapabilityAutoExpand => "CAPABILITY_AUTO_EXPAND",
+            Capability::CapabilityResourcePolicy => "CAPABILITY_RESOURCE_POLICY",
+            Capability::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Capability {
+    fn from(value: &'a str) -> Capability {
+        match value {
+            "CAPABILITY_IAM" => Capability::CapabilityIam,
+            "CAPABILITY_NAMED_IAM" => Capability::CapabilityNamedIam,
+            "CAPABILITY_AUTO_EXPAND" => Capability::CapabilityAutoExpand,
+            "CAPABILITY_RESOURCE_POLICY" => Capability::CapabilityResourcePolicy,
+            _ => Capability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Capability {
+    fn from(value: String) -> Capability {
+        Capability::from(value.as_str())
+    }
+}
+
+impl From<Capability> for String {
+    fn from(value: Capability) -> String {
+        match value {
+            Capability::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Capability {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Capability {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Capability {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Capability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Capability::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateApplicationRequest {
     /// <p>The name of the author publishing the app.</p><p>Minimum length=1. Maximum length=127.</p><p>Pattern "^[a-z0-9](([a-z0-9]|-(?!-))*[a-z0-9])?$";</p>
@@ -152,6 +239,154 @@ pub struct CreateApplicationRequest {
     pub template_url: Option<String>,
 }
 
+impl CreateApplicationRequest {
+    /// Returns a builder for `CreateApplicationRequest`, checking its required members when built.
+    pub fn builder() -> CreateApplicationRequestBuilder {
+        CreateApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApplicationRequest`](struct.CreateApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateApplicationRequestBuilder {
+    author: Option<String>,
+    description: Option<String>,
+    home_page_url: Option<String>,
+    labels: Option<Vec<String>>,
+    license_body: Option<String>,
+    license_url: Option<String>,
+    name: Option<String>,
+    readme_body: Option<String>,
+    readme_url: Option<String>,
+    semantic_version: Option<String>,
+    source_code_archive_url: Option<String>,
+    source_code_url: Option<String>,
+    spdx_license_id: Option<String>,
+    template_body: Option<String>,
+    template_url: Option<String>,
+}
+
+impl CreateApplicationRequestBuilder {
+    /// Sets the [`author`](struct.CreateApplicationRequest.html#structfield.author) member.
+    pub fn author<T: Into<String>>(mut self, value: T) -> Self {
+        self.author = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateApplicationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`home_page_url`](struct.CreateApplicationRequest.html#structfield.home_page_url) member.
+    pub fn home_page_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.home_page_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`labels`](struct.CreateApplicationRequest.html#structfield.labels) member.
+    pub fn labels<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.labels = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_body`](struct.CreateApplicationRequest.html#structfield.license_body) member.
+    pub fn license_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_url`](struct.CreateApplicationRequest.html#structfield.license_url) member.
+    pub fn license_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateApplicationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`readme_body`](struct.CreateApplicationRequest.html#structfield.readme_body) member.
+    pub fn readme_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.readme_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`readme_url`](struct.CreateApplicationRequest.html#structfield.readme_url) member.
+    pub fn readme_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.readme_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`semantic_version`](struct.CreateApplicationRequest.html#structfield.semantic_version) member.
+    pub fn semantic_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.semantic_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_code_archive_url`](struct.CreateApplicationRequest.html#structfield.source_code_archive_url) member.
+    pub fn source_code_archive_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_code_archive_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_code_url`](struct.CreateApplicationRequest.html#structfield.source_code_url) member.
+    pub fn source_code_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_code_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spdx_license_id`](struct.CreateApplicationRequest.html#structfield.spdx_license_id) member.
+    pub fn spdx_license_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.spdx_license_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_body`](struct.CreateApplicationRequest.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_url`](struct.CreateApplicationRequest.html#structfield.template_url) member.
+    pub fn template_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateApplicationRequest {
+            author: self
+                .author
+                .ok_or(::rusoto_core::BuildError::missing("author"))?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            home_page_url: self.home_page_url,
+            labels: self.labels,
+            license_body: self.license_body,
+            license_url: self.license_url,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            readme_body: self.readme_body,
+            readme_url: self.readme_url,
+            semantic_version: self.semantic_version,
+            source_code_archive_url: self.source_code_archive_url,
+            source_code_url: self.source_code_url,
+            spdx_license_id: self.spdx_license_id,
+            template_body: self.template_body,
+            template_url: self.template_url,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateApplicationResponse {
@@ -227,6 +462,80 @@ pub struct CreateApplicationVersionRequest {
     pub template_url: Option<String>,
 }
 
+impl CreateApplicationVersionRequest {
+    /// Returns a builder for `CreateApplicationVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateApplicationVersionRequestBuilder {
+        CreateApplicationVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApplicationVersionRequest`](struct.CreateApplicationVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateApplicationVersionRequestBuilder {
+    application_id: Option<String>,
+    semantic_version: Option<String>,
+    source_code_archive_url: Option<String>,
+    source_code_url: Option<String>,
+    template_body: Option<String>,
+    template_url: Option<String>,
+}
+
+impl CreateApplicationVersionRequestBuilder {
+    /// Sets the [`application_id`](struct.CreateApplicationVersionRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`semantic_version`](struct.CreateApplicationVersionRequest.html#structfield.semantic_version) member.
+    pub fn semantic_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.semantic_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_code_archive_url`](struct.CreateApplicationVersionRequest.html#structfield.source_code_archive_url) member.
+    pub fn source_code_archive_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_code_archive_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_code_url`](struct.CreateApplicationVersionRequest.html#structfield.source_code_url) member.
+    pub fn source_code_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_code_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_body`](struct.CreateApplicationVersionRequest.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_url`](struct.CreateApplicationVersionRequest.html#structfield.template_url) member.
+    pub fn template_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApplicationVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateApplicationVersionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateApplicationVersionRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+            semantic_version: self
+                .semantic_version
+                .ok_or(::rusoto_core::BuildError::missing("semantic_version"))?,
+            source_code_archive_url: self.source_code_archive_url,
+            source_code_url: self.source_code_url,
+            template_body: self.template_body,
+            template_url: self.template_url,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateApplicationVersionResponse {
@@ -267,7 +576,7 @@ pub struct CreateApplicationVersionResponse {
     /// this parameter for an application that requires capabilities, the call will fail.</p>
     #[serde(rename = "RequiredCapabilities")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub required_capabilities: Option<Vec<String>>,
+    pub required_capabilities: Option<Vec<Capability>>,
     /// <p>Whether all of the AWS resources contained in this application are supported in the region
     /// in which it is being retrieved.</p>
     #[serde(rename = "ResourcesSupported")]
@@ -379,6 +688,137 @@ pub struct CreateCloudFormationChangeSetRequest {
     pub template_id: Option<String>,
 }
 
+impl CreateCloudFormationChangeSetRequest {
+    /// Returns a builder for `CreateCloudFormationChangeSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateCloudFormationChangeSetRequestBuilder {
+        CreateCloudFormationChangeSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCloudFormationChangeSetRequest`](struct.CreateCloudFormationChangeSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCloudFormationChangeSetRequestBuilder {
+    application_id: Option<String>,
+    capabilities: Option<Vec<String>>,
+    change_set_name: Option<String>,
+    client_token: Option<String>,
+    description: Option<String>,
+    notification_arns: Option<Vec<String>>,
+    parameter_overrides: Option<Vec<ParameterValue>>,
+    resource_types: Option<Vec<String>>,
+    rollback_configuration: Option<RollbackConfiguration>,
+    semantic_version: Option<String>,
+    stack_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    template_id: Option<String>,
+}
+
+impl CreateCloudFormationChangeSetRequestBuilder {
+    /// Sets the [`application_id`](struct.CreateCloudFormationChangeSetRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`capabilities`](struct.CreateCloudFormationChangeSetRequest.html#structfield.capabilities) member.
+    pub fn capabilities<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.capabilities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`change_set_name`](struct.CreateCloudFormationChangeSetRequest.html#structfield.change_set_name) member.
+    pub fn change_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_token`](struct.CreateCloudFormationChangeSetRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateCloudFormationChangeSetRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_arns`](struct.CreateCloudFormationChangeSetRequest.html#structfield.notification_arns) member.
+    pub fn notification_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.notification_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_overrides`](struct.CreateCloudFormationChangeSetRequest.html#structfield.parameter_overrides) member.
+    pub fn parameter_overrides<T: Into<Vec<ParameterValue>>>(mut self, value: T) -> Self {
+        self.parameter_overrides = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_types`](struct.CreateCloudFormationChangeSetRequest.html#structfield.resource_types) member.
+    pub fn resource_types<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rollback_configuration`](struct.CreateCloudFormationChangeSetRequest.html#structfield.rollback_configuration) member.
+    pub fn rollback_configuration<T: Into<RollbackConfiguration>>(mut self, value: T) -> Self {
+        self.rollback_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`semantic_version`](struct.CreateCloudFormationChangeSetRequest.html#structfield.semantic_version) member.
+    pub fn semantic_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.semantic_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.CreateCloudFormationChangeSetRequest.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateCloudFormationChangeSetRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_id`](struct.CreateCloudFormationChangeSetRequest.html#structfield.template_id) member.
+    pub fn template_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCloudFormationChangeSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCloudFormationChangeSetRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateCloudFormationChangeSetRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+            capabilities: self.capabilities,
+            change_set_name: self.change_set_name,
+            client_token: self.client_token,
+            description: self.description,
+            notification_arns: self.notification_arns,
+            parameter_overrides: self.parameter_overrides,
+            resource_types: self.resource_types,
+            rollback_configuration: self.rollback_configuration,
+            semantic_version: self.semantic_version,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+            tags: self.tags,
+            template_id: self.template_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCloudFormationChangeSetResponse {
@@ -415,6 +855,46 @@ pub struct CreateCloudFormationTemplateRequest {
     pub semantic_version: Option<String>,
 }
 
+impl CreateCloudFormationTemplateRequest {
+    /// Returns a builder for `CreateCloudFormationTemplateRequest`, checking its required members when built.
+    pub fn builder() -> CreateCloudFormationTemplateRequestBuilder {
+        CreateCloudFormationTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCloudFormationTemplateRequest`](struct.CreateCloudFormationTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCloudFormationTemplateRequestBuilder {
+    application_id: Option<String>,
+    semantic_version: Option<String>,
+}
+
+impl CreateCloudFormationTemplateRequestBuilder {
+    /// Sets the [`application_id`](struct.CreateCloudFormationTemplateRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`semantic_version`](struct.CreateCloudFormationTemplateRequest.html#structfield.semantic_version) member.
+    pub fn semantic_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.semantic_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCloudFormationTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCloudFormationTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCloudFormationTemplateRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+            semantic_version: self.semantic_version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCloudFormationTemplateResponse {
@@ -441,7 +921,7 @@ pub struct CreateCloudFormationTemplateResponse {
     /// </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The UUID returned by CreateCloudFormationTemplate.</p><p>Pattern: [0-9a-fA-F]{8}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{12}</p>
     #[serde(rename = "TemplateId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -460,6 +940,38 @@ pub struct DeleteApplicationRequest {
     pub application_id: String,
 }
 
+impl DeleteApplicationRequest {
+    /// Returns a builder for `DeleteApplicationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationRequestBuilder {
+        DeleteApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationRequest`](struct.DeleteApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationRequestBuilder {
+    application_id: Option<String>,
+}
+
+impl DeleteApplicationRequestBuilder {
+    /// Sets the [`application_id`](struct.DeleteApplicationRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApplicationRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetApplicationPolicyRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
@@ -467,6 +979,38 @@ pub struct GetApplicationPolicyRequest {
     pub application_id: String,
 }
 
+impl GetApplicationPolicyRequest {
+    /// Returns a builder for `GetApplicationPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetApplicationPolicyRequestBuilder {
+        GetApplicationPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApplicationPolicyRequest`](struct.GetApplicationPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApplicationPolicyRequestBuilder {
+    application_id: Option<String>,
+}
+
+impl GetApplicationPolicyRequestBuilder {
+    /// Sets the [`application_id`](struct.GetApplicationPolicyRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApplicationPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetApplicationPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetApplicationPolicyRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetApplicationPolicyResponse {
@@ -487,6 +1031,44 @@ pub struct GetApplicationRequest {
     pub semantic_version: Option<String>,
 }
 
+impl GetApplicationRequest {
+    /// Returns a builder for `GetApplicationRequest`, checking its required members when built.
+    pub fn builder() -> GetApplicationRequestBuilder {
+        GetApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApplicationRequest`](struct.GetApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApplicationRequestBuilder {
+    application_id: Option<String>,
+    semantic_version: Option<String>,
+}
+
+impl GetApplicationRequestBuilder {
+    /// Sets the [`application_id`](struct.GetApplicationRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`semantic_version`](struct.GetApplicationRequest.html#structfield.semantic_version) member.
+    pub fn semantic_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.semantic_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(GetApplicationRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+            semantic_version: self.semantic_version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetApplicationResponse {
@@ -546,6 +1128,48 @@ pub struct GetCloudFormationTemplateRequest {
     pub template_id: String,
 }
 
+impl GetCloudFormationTemplateRequest {
+    /// Returns a builder for `GetCloudFormationTemplateRequest`, checking its required members when built.
+    pub fn builder() -> GetCloudFormationTemplateRequestBuilder {
+        GetCloudFormationTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCloudFormationTemplateRequest`](struct.GetCloudFormationTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCloudFormationTemplateRequestBuilder {
+    application_id: Option<String>,
+    template_id: Option<String>,
+}
+
+impl GetCloudFormationTemplateRequestBuilder {
+    /// Sets the [`application_id`](struct.GetCloudFormationTemplateRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_id`](struct.GetCloudFormationTemplateRequest.html#structfield.template_id) member.
+    pub fn template_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCloudFormationTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCloudFormationTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(GetCloudFormationTemplateRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+            template_id: self
+                .template_id
+                .ok_or(::rusoto_core::BuildError::missing("template_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCloudFormationTemplateResponse {
@@ -572,7 +1196,7 @@ pub struct GetCloudFormationTemplateResponse {
     /// </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The UUID returned by CreateCloudFormationTemplate.</p><p>Pattern: [0-9a-fA-F]{8}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{4}\-[0-9a-fA-F]{12}</p>
     #[serde(rename = "TemplateId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -603,6 +1227,62 @@ pub struct ListApplicationDependenciesRequest {
     pub semantic_version: Option<String>,
 }
 
+impl ListApplicationDependenciesRequest {
+    /// Returns a builder for `ListApplicationDependenciesRequest`, checking its required members when built.
+    pub fn builder() -> ListApplicationDependenciesRequestBuilder {
+        ListApplicationDependenciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationDependenciesRequest`](struct.ListApplicationDependenciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationDependenciesRequestBuilder {
+    application_id: Option<String>,
+    max_items: Option<i64>,
+    next_token: Option<String>,
+    semantic_version: Option<String>,
+}
+
+impl ListApplicationDependenciesRequestBuilder {
+    /// Sets the [`application_id`](struct.ListApplicationDependenciesRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListApplicationDependenciesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApplicationDependenciesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`semantic_version`](struct.ListApplicationDependenciesRequest.html#structfield.semantic_version) member.
+    pub fn semantic_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.semantic_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationDependenciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListApplicationDependenciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListApplicationDependenciesRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+            max_items: self.max_items,
+            next_token: self.next_token,
+            semantic_version: self.semantic_version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListApplicationDependenciesResponse {
@@ -631,6 +1311,54 @@ pub struct ListApplicationVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListApplicationVersionsRequest {
+    /// Returns a builder for `ListApplicationVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListApplicationVersionsRequestBuilder {
+        ListApplicationVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationVersionsRequest`](struct.ListApplicationVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationVersionsRequestBuilder {
+    application_id: Option<String>,
+    max_items: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListApplicationVersionsRequestBuilder {
+    /// Sets the [`application_id`](struct.ListApplicationVersionsRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListApplicationVersionsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApplicationVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListApplicationVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListApplicationVersionsRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+            max_items: self.max_items,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListApplicationVersionsResponse {
@@ -656,6 +1384,44 @@ pub struct ListApplicationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListApplicationsRequest {
+    /// Returns a builder for `ListApplicationsRequest`, checking its required members when built.
+    pub fn builder() -> ListApplicationsRequestBuilder {
+        ListApplicationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationsRequest`](struct.ListApplicationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationsRequestBuilder {
+    max_items: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListApplicationsRequestBuilder {
+    /// Sets the [`max_items`](struct.ListApplicationsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApplicationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListApplicationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListApplicationsRequest {
+            max_items: self.max_items,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListApplicationsResponse {
@@ -764,6 +1530,48 @@ pub struct PutApplicationPolicyRequest {
     pub statements: Vec<ApplicationPolicyStatement>,
 }
 
+impl PutApplicationPolicyRequest {
+    /// Returns a builder for `PutApplicationPolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutApplicationPolicyRequestBuilder {
+        PutApplicationPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutApplicationPolicyRequest`](struct.PutApplicationPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutApplicationPolicyRequestBuilder {
+    application_id: Option<String>,
+    statements: Option<Vec<ApplicationPolicyStatement>>,
+}
+
+impl PutApplicationPolicyRequestBuilder {
+    /// Sets the [`application_id`](struct.PutApplicationPolicyRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statements`](struct.PutApplicationPolicyRequest.html#structfield.statements) member.
+    pub fn statements<T: Into<Vec<ApplicationPolicyStatement>>>(mut self, value: T) -> Self {
+        self.statements = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutApplicationPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutApplicationPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutApplicationPolicyRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+            statements: self
+                .statements
+                .ok_or(::rusoto_core::BuildError::missing("statements"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutApplicationPolicyResponse {
@@ -803,6 +1611,89 @@ pub struct RollbackTrigger {
     pub type_: String,
 }
 
+/// <p>Status of the template creation workflow.</p><p>Possible values: PREPARING | ACTIVE | EXPIRED
+/// </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// <p><code>PREPARING</code></p>
+    Preparing,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>EXPIRED</code></p>
+    Expired,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Status {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::Preparing => "PREPARING",
+            Status::Active => "ACTIVE",
+            Status::Expired => "EXPIRED",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "PREPARING" => Status::Preparing,
+            "ACTIVE" => Status::Active,
+            "EXPIRED" => Status::Expired,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        Status::from(value.as_str())
+    }
+}
+
+impl From<Status> for String {
+    fn from(value: Status) -> String {
+        match value {
+            Status::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Status::from(value))
+    }
+}
+
 /// <p>This property corresponds to the <i>AWS CloudFormation <a href="https://docs.aws.amazon.com/goto/WebAPI/cloudformation-2010-05-15/Tag">Tag</a>
 /// </i> Data Type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -850,6 +1741,86 @@ pub struct UpdateApplicationRequest {
     pub readme_url: Option<String>,
 }
 
+impl UpdateApplicationRequest {
+    /// Returns a builder for `UpdateApplicationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateApplicationRequestBuilder {
+        UpdateApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApplicationRequest`](struct.UpdateApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateApplicationRequestBuilder {
+    application_id: Option<String>,
+    author: Option<String>,
+    description: Option<String>,
+    home_page_url: Option<String>,
+    labels: Option<Vec<String>>,
+    readme_body: Option<String>,
+    readme_url: Option<String>,
+}
+
+impl UpdateApplicationRequestBuilder {
+    /// Sets the [`application_id`](struct.UpdateApplicationRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`author`](struct.UpdateApplicationRequest.html#structfield.author) member.
+    pub fn author<T: Into<String>>(mut self, value: T) -> Self {
+        self.author = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateApplicationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`home_page_url`](struct.UpdateApplicationRequest.html#structfield.home_page_url) member.
+    pub fn home_page_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.home_page_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`labels`](struct.UpdateApplicationRequest.html#structfield.labels) member.
+    pub fn labels<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.labels = Some(value.into());
+        self
+    }
+
+    /// Sets the [`readme_body`](struct.UpdateApplicationRequest.html#structfield.readme_body) member.
+    pub fn readme_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.readme_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`readme_url`](struct.UpdateApplicationRequest.html#structfield.readme_url) member.
+    pub fn readme_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.readme_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateApplicationRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+            author: self.author,
+            description: self.description,
+            home_page_url: self.home_page_url,
+            labels: self.labels,
+            readme_body: self.readme_body,
+            readme_url: self.readme_url,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateApplicationResponse {
@@ -936,7 +1907,7 @@ pub struct Version {
     /// all permissions associated with the application before deploying. If you don't specify
     /// this parameter for an application that requires capabilities, the call will fail.</p>
     #[serde(rename = "RequiredCapabilities")]
-    pub required_capabilities: Vec<String>,
+    pub required_capabilities: Vec<Capability>,
     /// <p>Whether all of the AWS resources contained in this application are supported in the region
     /// in which it is being retrieved.</p>
     #[serde(rename = "ResourcesSupported")]
@@ -1868,6 +2839,94 @@ pub trait ServerlessRepo {
         input: UpdateApplicationRequest,
     ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError>;
 }
+impl<T: ?Sized + ServerlessRepo> ServerlessRepo for ::std::sync::Arc<T> {
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_application_version(
+        &self,
+        input: CreateApplicationVersionRequest,
+    ) -> RusotoFuture<CreateApplicationVersionResponse, CreateApplicationVersionError> {
+        (**self).create_application_version(input)
+    }
+    fn create_cloud_formation_change_set(
+        &self,
+        input: CreateCloudFormationChangeSetRequest,
+    ) -> RusotoFuture<CreateCloudFormationChangeSetResponse, CreateCloudFormationChangeSetError>
+    {
+        (**self).create_cloud_formation_change_set(input)
+    }
+    fn create_cloud_formation_template(
+        &self,
+        input: CreateCloudFormationTemplateRequest,
+    ) -> RusotoFuture<CreateCloudFormationTemplateResponse, CreateCloudFormationTemplateError> {
+        (**self).create_cloud_formation_template(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn get_application(
+        &self,
+        input: GetApplicationRequest,
+    ) -> RusotoFuture<GetApplicationResponse, GetApplicationError> {
+        (**self).get_application(input)
+    }
+    fn get_application_policy(
+        &self,
+        input: GetApplicationPolicyRequest,
+    ) -> RusotoFuture<GetApplicationPolicyResponse, GetApplicationPolicyError> {
+        (**self).get_application_policy(input)
+    }
+    fn get_cloud_formation_template(
+        &self,
+        input: GetCloudFormationTemplateRequest,
+    ) -> RusotoFuture<GetCloudFormationTemplateResponse, GetCloudFormationTemplateError> {
+        (**self).get_cloud_formation_template(input)
+    }
+    fn list_application_dependencies(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> RusotoFuture<ListApplicationDependenciesResponse, ListApplicationDependenciesError> {
+        (**self).list_application_dependencies(input)
+    }
+    fn list_application_versions(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> RusotoFuture<ListApplicationVersionsResponse, ListApplicationVersionsError> {
+        (**self).list_application_versions(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn put_application_policy(
+        &self,
+        input: PutApplicationPolicyRequest,
+    ) -> RusotoFuture<PutApplicationPolicyResponse, PutApplicationPolicyError> {
+        (**self).put_application_policy(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
+/// The error codes AWSServerlessApplicationRepository retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the AWSServerlessApplicationRepository API.
 #[derive(Clone)]
 pub struct ServerlessRepoClient {
@@ -1883,6 +2942,27 @@ impl ServerlessRepoClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SERVERLESSREPO`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ServerlessRepoClient {
+        Self::new(region::Region::from_env_for_service("SERVERLESSREPO"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `serverlessrepo`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ServerlessRepoClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("serverlessrepo", "SERVERLESSREPO"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1901,7 +2981,54 @@ impl ServerlessRepoClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ServerlessRepoClient {
-        ServerlessRepoClient { client, region }
+        ServerlessRepoClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "serverlessrepo")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "serverlessrepo",
+            "serverlessrepo",
+            "SERVERLESSREPO",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ServerlessRepo` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ServerlessRepo + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ServerlessRepoClient {
+    fn service_name(&self) -> &'static str {
+        "AWSServerlessApplicationRepository"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "serverlessrepo"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ServerlessRepoClient {
+        Self::new_with_client(client, region)
     }
 }
 
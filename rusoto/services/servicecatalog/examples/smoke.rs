@@ -0,0 +1,40 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Service Catalog clients.
+//!
+//! Makes a single read-only `ListAcceptedPortfolioShares` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_servicecatalog::{
+    ListAcceptedPortfolioSharesInput, ServiceCatalog, ServiceCatalogClient,
+};
+
+fn main() {
+    let client = ServiceCatalogClient::from_env();
+    match client
+        .list_accepted_portfolio_shares(ListAcceptedPortfolioSharesInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListAcceptedPortfolioShares succeeded"),
+        Err(error) => {
+            eprintln!("ListAcceptedPortfolioShares failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
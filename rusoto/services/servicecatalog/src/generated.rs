@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AcceptPortfolioShareInput {
@@ -35,7 +36,55 @@ pub struct AcceptPortfolioShareInput {
     /// <p>The type of shared portfolios to accept. The default is to accept imported portfolios.</p> <ul> <li> <p> <code>AWS_ORGANIZATIONS</code> - Accept portfolios shared by the master account of your organization.</p> </li> <li> <p> <code>IMPORTED</code> - Accept imported portfolios.</p> </li> <li> <p> <code>AWS_SERVICECATALOG</code> - Not supported. (Throws ResourceNotFoundException.)</p> </li> </ul> <p>For example, <code>aws servicecatalog accept-portfolio-share --portfolio-id "port-2qwzkwxt3y5fk" --portfolio-share-type AWS_ORGANIZATIONS</code> </p>
     #[serde(rename = "PortfolioShareType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub portfolio_share_type: Option<String>,
+    pub portfolio_share_type: Option<PortfolioShareType>,
+}
+
+impl AcceptPortfolioShareInput {
+    /// Returns a builder for `AcceptPortfolioShareInput`, checking its required members when built.
+    pub fn builder() -> AcceptPortfolioShareInputBuilder {
+        AcceptPortfolioShareInputBuilder::default()
+    }
+}
+
+/// Builds a [`AcceptPortfolioShareInput`](struct.AcceptPortfolioShareInput.html).
+#[derive(Default, Debug)]
+pub struct AcceptPortfolioShareInputBuilder {
+    accept_language: Option<String>,
+    portfolio_id: Option<String>,
+    portfolio_share_type: Option<PortfolioShareType>,
+}
+
+impl AcceptPortfolioShareInputBuilder {
+    /// Sets the [`accept_language`](struct.AcceptPortfolioShareInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.AcceptPortfolioShareInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_share_type`](struct.AcceptPortfolioShareInput.html#structfield.portfolio_share_type) member.
+    pub fn portfolio_share_type<T: Into<PortfolioShareType>>(mut self, value: T) -> Self {
+        self.portfolio_share_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcceptPortfolioShareInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AcceptPortfolioShareInput, ::rusoto_core::BuildError> {
+        Ok(AcceptPortfolioShareInput {
+            accept_language: self.accept_language,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+            portfolio_share_type: self.portfolio_share_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -48,13 +97,165 @@ pub struct AccessLevelFilter {
     /// <p><p>The access level.</p> <ul> <li> <p> <code>Account</code> - Filter results based on the account.</p> </li> <li> <p> <code>Role</code> - Filter results based on the federated role of the specified user.</p> </li> <li> <p> <code>User</code> - Filter results based on the specified user.</p> </li> </ul></p>
     #[serde(rename = "Key")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
+    pub key: Option<AccessLevelFilterKey>,
     /// <p>The user to which the access level applies. The only supported value is <code>Self</code>.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p><p>The access level.</p> <ul> <li> <p> <code>Account</code> - Filter results based on the account.</p> </li> <li> <p> <code>Role</code> - Filter results based on the federated role of the specified user.</p> </li> <li> <p> <code>User</code> - Filter results based on the specified user.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccessLevelFilterKey {
+    /// <p><code>Account</code> - Filter results based on the account.</p>
+    Account,
+    /// <p><code>Role</code> - Filter results based on the federated role of the specified user.</p>
+    Role,
+    /// <p><code>User</code> - Filter results based on the specified user.</p>
+    User,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccessLevelFilterKey {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccessLevelFilterKey::Account => "Account",
+            AccessLevelFilterKey::Role => "Role",
+            AccessLevelFilterKey::User => "User",
+            AccessLevelFilterKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccessLevelFilterKey {
+    fn from(value: &'a str) -> AccessLevelFilterKey {
+        match value {
+            "Account" => AccessLevelFilterKey::Account,
+            "Role" => AccessLevelFilterKey::Role,
+            "User" => AccessLevelFilterKey::User,
+            _ => AccessLevelFilterKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccessLevelFilterKey {
+    fn from(value: String) -> AccessLevelFilterKey {
+        AccessLevelFilterKey::from(value.as_str())
+    }
+}
+
+impl From<AccessLevelFilterKey> for String {
+    fn from(value: AccessLevelFilterKey) -> String {
+        match value {
+            AccessLevelFilterKey::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccessLevelFilterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AccessLevelFilterKey {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccessLevelFilterKey {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AccessLevelFilterKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AccessLevelFilterKey::from(value))
+    }
+}
+
+/// <p>The status of the portfolio share feature.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccessStatus {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>UNDER_CHANGE</code></p>
+    UnderChange,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccessStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccessStatus::Enabled => "ENABLED",
+            AccessStatus::UnderChange => "UNDER_CHANGE",
+            AccessStatus::Disabled => "DISABLED",
+            AccessStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccessStatus {
+    fn from(value: &'a str) -> AccessStatus {
+        match value {
+            "ENABLED" => AccessStatus::Enabled,
+            "UNDER_CHANGE" => AccessStatus::UnderChange,
+            "DISABLED" => AccessStatus::Disabled,
+            _ => AccessStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccessStatus {
+    fn from(value: String) -> AccessStatus {
+        AccessStatus::from(value.as_str())
+    }
+}
+
+impl From<AccessStatus> for String {
+    fn from(value: AccessStatus) -> String {
+        match value {
+            AccessStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccessStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AccessStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccessStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AccessStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AccessStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateBudgetWithResourceInput {
     /// <p>The name of the budget you want to associate.</p>
@@ -65,11 +266,53 @@ pub struct AssociateBudgetWithResourceInput {
     pub resource_id: String,
 }
 
+impl AssociateBudgetWithResourceInput {
+    /// Returns a builder for `AssociateBudgetWithResourceInput`, checking its required members when built.
+    pub fn builder() -> AssociateBudgetWithResourceInputBuilder {
+        AssociateBudgetWithResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateBudgetWithResourceInput`](struct.AssociateBudgetWithResourceInput.html).
+#[derive(Default, Debug)]
+pub struct AssociateBudgetWithResourceInputBuilder {
+    budget_name: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl AssociateBudgetWithResourceInputBuilder {
+    /// Sets the [`budget_name`](struct.AssociateBudgetWithResourceInput.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.AssociateBudgetWithResourceInput.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateBudgetWithResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateBudgetWithResourceInput, ::rusoto_core::BuildError> {
+        Ok(AssociateBudgetWithResourceInput {
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateBudgetWithResourceOutput {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AssociatePrincipalWithPortfolioInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -83,7 +326,68 @@ pub struct AssociatePrincipalWithPortfolioInput {
     pub principal_arn: String,
     /// <p>The principal type. The supported value is <code>IAM</code>.</p>
     #[serde(rename = "PrincipalType")]
-    pub principal_type: String,
+    pub principal_type: PrincipalType,
+}
+
+impl AssociatePrincipalWithPortfolioInput {
+    /// Returns a builder for `AssociatePrincipalWithPortfolioInput`, checking its required members when built.
+    pub fn builder() -> AssociatePrincipalWithPortfolioInputBuilder {
+        AssociatePrincipalWithPortfolioInputBuilder::default()
+    }
+}
+
+/// Builds a [`AssociatePrincipalWithPortfolioInput`](struct.AssociatePrincipalWithPortfolioInput.html).
+#[derive(Default, Debug)]
+pub struct AssociatePrincipalWithPortfolioInputBuilder {
+    accept_language: Option<String>,
+    portfolio_id: Option<String>,
+    principal_arn: Option<String>,
+    principal_type: Option<PrincipalType>,
+}
+
+impl AssociatePrincipalWithPortfolioInputBuilder {
+    /// Sets the [`accept_language`](struct.AssociatePrincipalWithPortfolioInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.AssociatePrincipalWithPortfolioInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_arn`](struct.AssociatePrincipalWithPortfolioInput.html#structfield.principal_arn) member.
+    pub fn principal_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_type`](struct.AssociatePrincipalWithPortfolioInput.html#structfield.principal_type) member.
+    pub fn principal_type<T: Into<PrincipalType>>(mut self, value: T) -> Self {
+        self.principal_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociatePrincipalWithPortfolioInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociatePrincipalWithPortfolioInput, ::rusoto_core::BuildError>
+    {
+        Ok(AssociatePrincipalWithPortfolioInput {
+            accept_language: self.accept_language,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+            principal_arn: self
+                .principal_arn
+                .ok_or(::rusoto_core::BuildError::missing("principal_arn"))?,
+            principal_type: self
+                .principal_type
+                .ok_or(::rusoto_core::BuildError::missing("principal_type"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -108,6 +412,64 @@ pub struct AssociateProductWithPortfolioInput {
     pub source_portfolio_id: Option<String>,
 }
 
+impl AssociateProductWithPortfolioInput {
+    /// Returns a builder for `AssociateProductWithPortfolioInput`, checking its required members when built.
+    pub fn builder() -> AssociateProductWithPortfolioInputBuilder {
+        AssociateProductWithPortfolioInputBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateProductWithPortfolioInput`](struct.AssociateProductWithPortfolioInput.html).
+#[derive(Default, Debug)]
+pub struct AssociateProductWithPortfolioInputBuilder {
+    accept_language: Option<String>,
+    portfolio_id: Option<String>,
+    product_id: Option<String>,
+    source_portfolio_id: Option<String>,
+}
+
+impl AssociateProductWithPortfolioInputBuilder {
+    /// Sets the [`accept_language`](struct.AssociateProductWithPortfolioInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.AssociateProductWithPortfolioInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.AssociateProductWithPortfolioInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_portfolio_id`](struct.AssociateProductWithPortfolioInput.html#structfield.source_portfolio_id) member.
+    pub fn source_portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateProductWithPortfolioInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateProductWithPortfolioInput, ::rusoto_core::BuildError> {
+        Ok(AssociateProductWithPortfolioInput {
+            accept_language: self.accept_language,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            source_portfolio_id: self.source_portfolio_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateProductWithPortfolioOutput {}
@@ -129,6 +491,69 @@ pub struct AssociateServiceActionWithProvisioningArtifactInput {
     pub service_action_id: String,
 }
 
+impl AssociateServiceActionWithProvisioningArtifactInput {
+    /// Returns a builder for `AssociateServiceActionWithProvisioningArtifactInput`, checking its required members when built.
+    pub fn builder() -> AssociateServiceActionWithProvisioningArtifactInputBuilder {
+        AssociateServiceActionWithProvisioningArtifactInputBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateServiceActionWithProvisioningArtifactInput`](struct.AssociateServiceActionWithProvisioningArtifactInput.html).
+#[derive(Default, Debug)]
+pub struct AssociateServiceActionWithProvisioningArtifactInputBuilder {
+    accept_language: Option<String>,
+    product_id: Option<String>,
+    provisioning_artifact_id: Option<String>,
+    service_action_id: Option<String>,
+}
+
+impl AssociateServiceActionWithProvisioningArtifactInputBuilder {
+    /// Sets the [`accept_language`](struct.AssociateServiceActionWithProvisioningArtifactInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.AssociateServiceActionWithProvisioningArtifactInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_artifact_id`](struct.AssociateServiceActionWithProvisioningArtifactInput.html#structfield.provisioning_artifact_id) member.
+    pub fn provisioning_artifact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioning_artifact_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_action_id`](struct.AssociateServiceActionWithProvisioningArtifactInput.html#structfield.service_action_id) member.
+    pub fn service_action_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_action_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateServiceActionWithProvisioningArtifactInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        AssociateServiceActionWithProvisioningArtifactInput,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(AssociateServiceActionWithProvisioningArtifactInput {
+            accept_language: self.accept_language,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            provisioning_artifact_id: self.provisioning_artifact_id.ok_or(
+                ::rusoto_core::BuildError::missing("provisioning_artifact_id"),
+            )?,
+            service_action_id: self
+                .service_action_id
+                .ok_or(::rusoto_core::BuildError::missing("service_action_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateServiceActionWithProvisioningArtifactOutput {}
@@ -143,6 +568,48 @@ pub struct AssociateTagOptionWithResourceInput {
     pub tag_option_id: String,
 }
 
+impl AssociateTagOptionWithResourceInput {
+    /// Returns a builder for `AssociateTagOptionWithResourceInput`, checking its required members when built.
+    pub fn builder() -> AssociateTagOptionWithResourceInputBuilder {
+        AssociateTagOptionWithResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateTagOptionWithResourceInput`](struct.AssociateTagOptionWithResourceInput.html).
+#[derive(Default, Debug)]
+pub struct AssociateTagOptionWithResourceInputBuilder {
+    resource_id: Option<String>,
+    tag_option_id: Option<String>,
+}
+
+impl AssociateTagOptionWithResourceInputBuilder {
+    /// Sets the [`resource_id`](struct.AssociateTagOptionWithResourceInput.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_option_id`](struct.AssociateTagOptionWithResourceInput.html#structfield.tag_option_id) member.
+    pub fn tag_option_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.tag_option_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateTagOptionWithResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateTagOptionWithResourceInput, ::rusoto_core::BuildError> {
+        Ok(AssociateTagOptionWithResourceInput {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tag_option_id: self
+                .tag_option_id
+                .ok_or(::rusoto_core::BuildError::missing("tag_option_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateTagOptionWithResourceOutput {}
@@ -158,6 +625,52 @@ pub struct BatchAssociateServiceActionWithProvisioningArtifactInput {
     pub service_action_associations: Vec<ServiceActionAssociation>,
 }
 
+impl BatchAssociateServiceActionWithProvisioningArtifactInput {
+    /// Returns a builder for `BatchAssociateServiceActionWithProvisioningArtifactInput`, checking its required members when built.
+    pub fn builder() -> BatchAssociateServiceActionWithProvisioningArtifactInputBuilder {
+        BatchAssociateServiceActionWithProvisioningArtifactInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchAssociateServiceActionWithProvisioningArtifactInput`](struct.BatchAssociateServiceActionWithProvisioningArtifactInput.html).
+#[derive(Default, Debug)]
+pub struct BatchAssociateServiceActionWithProvisioningArtifactInputBuilder {
+    accept_language: Option<String>,
+    service_action_associations: Option<Vec<ServiceActionAssociation>>,
+}
+
+impl BatchAssociateServiceActionWithProvisioningArtifactInputBuilder {
+    /// Sets the [`accept_language`](struct.BatchAssociateServiceActionWithProvisioningArtifactInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_action_associations`](struct.BatchAssociateServiceActionWithProvisioningArtifactInput.html#structfield.service_action_associations) member.
+    pub fn service_action_associations<T: Into<Vec<ServiceActionAssociation>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.service_action_associations = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchAssociateServiceActionWithProvisioningArtifactInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        BatchAssociateServiceActionWithProvisioningArtifactInput,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(BatchAssociateServiceActionWithProvisioningArtifactInput {
+            accept_language: self.accept_language,
+            service_action_associations: self.service_action_associations.ok_or(
+                ::rusoto_core::BuildError::missing("service_action_associations"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchAssociateServiceActionWithProvisioningArtifactOutput {
@@ -178,6 +691,54 @@ pub struct BatchDisassociateServiceActionFromProvisioningArtifactInput {
     pub service_action_associations: Vec<ServiceActionAssociation>,
 }
 
+impl BatchDisassociateServiceActionFromProvisioningArtifactInput {
+    /// Returns a builder for `BatchDisassociateServiceActionFromProvisioningArtifactInput`, checking its required members when built.
+    pub fn builder() -> BatchDisassociateServiceActionFromProvisioningArtifactInputBuilder {
+        BatchDisassociateServiceActionFromProvisioningArtifactInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDisassociateServiceActionFromProvisioningArtifactInput`](struct.BatchDisassociateServiceActionFromProvisioningArtifactInput.html).
+#[derive(Default, Debug)]
+pub struct BatchDisassociateServiceActionFromProvisioningArtifactInputBuilder {
+    accept_language: Option<String>,
+    service_action_associations: Option<Vec<ServiceActionAssociation>>,
+}
+
+impl BatchDisassociateServiceActionFromProvisioningArtifactInputBuilder {
+    /// Sets the [`accept_language`](struct.BatchDisassociateServiceActionFromProvisioningArtifactInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_action_associations`](struct.BatchDisassociateServiceActionFromProvisioningArtifactInput.html#structfield.service_action_associations) member.
+    pub fn service_action_associations<T: Into<Vec<ServiceActionAssociation>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.service_action_associations = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDisassociateServiceActionFromProvisioningArtifactInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        BatchDisassociateServiceActionFromProvisioningArtifactInput,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(
+            BatchDisassociateServiceActionFromProvisioningArtifactInput {
+                accept_language: self.accept_language,
+                service_action_associations: self.service_action_associations.ok_or(
+                    ::rusoto_core::BuildError::missing("service_action_associations"),
+                )?,
+            },
+        )
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDisassociateServiceActionFromProvisioningArtifactOutput {
@@ -197,6 +758,82 @@ pub struct BudgetDetail {
     pub budget_name: Option<String>,
 }
 
+/// <p>The change action.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeAction {
+    /// <p><code>ADD</code></p>
+    Add,
+    /// <p><code>MODIFY</code></p>
+    Modify,
+    /// <p><code>REMOVE</code></p>
+    Remove,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeAction::Add => "ADD",
+            ChangeAction::Modify => "MODIFY",
+            ChangeAction::Remove => "REMOVE",
+            ChangeAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeAction {
+    fn from(value: &'a str) -> ChangeAction {
+        match value {
+            "ADD" => ChangeAction::Add,
+            "MODIFY" => ChangeAction::Modify,
+            "REMOVE" => ChangeAction::Remove,
+            _ => ChangeAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeAction {
+    fn from(value: String) -> ChangeAction {
+        ChangeAction::from(value.as_str())
+    }
+}
+
+impl From<ChangeAction> for String {
+    fn from(value: ChangeAction) -> String {
+        match value {
+            ChangeAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ChangeAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChangeAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChangeAction::from(value))
+    }
+}
+
 /// <p>Information about a CloudWatch dashboard.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -243,6 +880,74 @@ pub struct ConstraintSummary {
     pub type_: Option<String>,
 }
 
+/// <p>The copy options. If the value is <code>CopyTags</code>, the tags from the source product are copied to the target product.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CopyOption {
+    /// <p><code>CopyTags</code></p>
+    CopyTags,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CopyOption {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CopyOption::CopyTags => "CopyTags",
+            CopyOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CopyOption {
+    fn from(value: &'a str) -> CopyOption {
+        match value {
+            "CopyTags" => CopyOption::CopyTags,
+            _ => CopyOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CopyOption {
+    fn from(value: String) -> CopyOption {
+        CopyOption::from(value.as_str())
+    }
+}
+
+impl From<CopyOption> for String {
+    fn from(value: CopyOption) -> String {
+        match value {
+            CopyOption::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CopyOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CopyOption {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CopyOption {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CopyOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CopyOption::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CopyProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
@@ -252,7 +957,7 @@ pub struct CopyProductInput {
     /// <p>The copy options. If the value is <code>CopyTags</code>, the tags from the source product are copied to the target product.</p>
     #[serde(rename = "CopyOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub copy_options: Option<Vec<String>>,
+    pub copy_options: Option<Vec<CopyOption>>,
     /// <p> A unique identifier that you provide to ensure idempotency. If multiple requests differ only by the idempotency token, the same response is returned for each repeated request. </p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
@@ -263,7 +968,7 @@ pub struct CopyProductInput {
     #[serde(rename = "SourceProvisioningArtifactIdentifiers")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_provisioning_artifact_identifiers:
-        Option<Vec<::std::collections::HashMap<String, String>>>,
+        Option<Vec<::std::collections::HashMap<ProvisioningArtifactPropertyName, String>>>,
     /// <p>The identifier of the target product. By default, a new product is created.</p>
     #[serde(rename = "TargetProductId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -274,15 +979,177 @@ pub struct CopyProductInput {
     pub target_product_name: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CopyProductOutput {
-    /// <p>The token to use to track the progress of the operation.</p>
+impl CopyProductInput {
+    /// Returns a builder for `CopyProductInput`, checking its required members when built.
+    pub fn builder() -> CopyProductInputBuilder {
+        CopyProductInputBuilder::default()
+    }
+}
+
+/// Builds a [`CopyProductInput`](struct.CopyProductInput.html).
+#[derive(Default, Debug)]
+pub struct CopyProductInputBuilder {
+    accept_language: Option<String>,
+    copy_options: Option<Vec<CopyOption>>,
+    idempotency_token: Option<String>,
+    source_product_arn: Option<String>,
+    source_provisioning_artifact_identifiers:
+        Option<Vec<::std::collections::HashMap<ProvisioningArtifactPropertyName, String>>>,
+    target_product_id: Option<String>,
+    target_product_name: Option<String>,
+}
+
+impl CopyProductInputBuilder {
+    /// Sets the [`accept_language`](struct.CopyProductInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_options`](struct.CopyProductInput.html#structfield.copy_options) member.
+    pub fn copy_options<T: Into<Vec<CopyOption>>>(mut self, value: T) -> Self {
+        self.copy_options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.CopyProductInput.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_product_arn`](struct.CopyProductInput.html#structfield.source_product_arn) member.
+    pub fn source_product_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_product_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_provisioning_artifact_identifiers`](struct.CopyProductInput.html#structfield.source_provisioning_artifact_identifiers) member.
+    pub fn source_provisioning_artifact_identifiers<
+        T: Into<Vec<::std::collections::HashMap<ProvisioningArtifactPropertyName, String>>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.source_provisioning_artifact_identifiers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_product_id`](struct.CopyProductInput.html#structfield.target_product_id) member.
+    pub fn target_product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_product_name`](struct.CopyProductInput.html#structfield.target_product_name) member.
+    pub fn target_product_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_product_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyProductInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CopyProductInput, ::rusoto_core::BuildError> {
+        Ok(CopyProductInput {
+            accept_language: self.accept_language,
+            copy_options: self.copy_options,
+            idempotency_token: self
+                .idempotency_token
+                .ok_or(::rusoto_core::BuildError::missing("idempotency_token"))?,
+            source_product_arn: self
+                .source_product_arn
+                .ok_or(::rusoto_core::BuildError::missing("source_product_arn"))?,
+            source_provisioning_artifact_identifiers: self.source_provisioning_artifact_identifiers,
+            target_product_id: self.target_product_id,
+            target_product_name: self.target_product_name,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CopyProductOutput {
+    /// <p>The token to use to track the progress of the operation.</p>
     #[serde(rename = "CopyProductToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub copy_product_token: Option<String>,
 }
 
+/// <p>The status of the copy product operation.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CopyProductStatus {
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CopyProductStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CopyProductStatus::Succeeded => "SUCCEEDED",
+            CopyProductStatus::InProgress => "IN_PROGRESS",
+            CopyProductStatus::Failed => "FAILED",
+            CopyProductStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CopyProductStatus {
+    fn from(value: &'a str) -> CopyProductStatus {
+        match value {
+            "SUCCEEDED" => CopyProductStatus::Succeeded,
+            "IN_PROGRESS" => CopyProductStatus::InProgress,
+            "FAILED" => CopyProductStatus::Failed,
+            _ => CopyProductStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CopyProductStatus {
+    fn from(value: String) -> CopyProductStatus {
+        CopyProductStatus::from(value.as_str())
+    }
+}
+
+impl From<CopyProductStatus> for String {
+    fn from(value: CopyProductStatus) -> String {
+        match value {
+            CopyProductStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CopyProductStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CopyProductStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CopyProductStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CopyProductStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CopyProductStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateConstraintInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
@@ -310,6 +1177,92 @@ pub struct CreateConstraintInput {
     pub type_: String,
 }
 
+impl CreateConstraintInput {
+    /// Returns a builder for `CreateConstraintInput`, checking its required members when built.
+    pub fn builder() -> CreateConstraintInputBuilder {
+        CreateConstraintInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConstraintInput`](struct.CreateConstraintInput.html).
+#[derive(Default, Debug)]
+pub struct CreateConstraintInputBuilder {
+    accept_language: Option<String>,
+    description: Option<String>,
+    idempotency_token: Option<String>,
+    parameters: Option<String>,
+    portfolio_id: Option<String>,
+    product_id: Option<String>,
+    type_: Option<String>,
+}
+
+impl CreateConstraintInputBuilder {
+    /// Sets the [`accept_language`](struct.CreateConstraintInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateConstraintInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.CreateConstraintInput.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.CreateConstraintInput.html#structfield.parameters) member.
+    pub fn parameters<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.CreateConstraintInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.CreateConstraintInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreateConstraintInput.html#structfield.type_) member.
+    pub fn type_<T: Into<String>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConstraintInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateConstraintInput, ::rusoto_core::BuildError> {
+        Ok(CreateConstraintInput {
+            accept_language: self.accept_language,
+            description: self.description,
+            idempotency_token: self
+                .idempotency_token
+                .ok_or(::rusoto_core::BuildError::missing("idempotency_token"))?,
+            parameters: self
+                .parameters
+                .ok_or(::rusoto_core::BuildError::missing("parameters"))?,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateConstraintOutput {
@@ -324,7 +1277,7 @@ pub struct CreateConstraintOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -352,6 +1305,80 @@ pub struct CreatePortfolioInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreatePortfolioInput {
+    /// Returns a builder for `CreatePortfolioInput`, checking its required members when built.
+    pub fn builder() -> CreatePortfolioInputBuilder {
+        CreatePortfolioInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePortfolioInput`](struct.CreatePortfolioInput.html).
+#[derive(Default, Debug)]
+pub struct CreatePortfolioInputBuilder {
+    accept_language: Option<String>,
+    description: Option<String>,
+    display_name: Option<String>,
+    idempotency_token: Option<String>,
+    provider_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreatePortfolioInputBuilder {
+    /// Sets the [`accept_language`](struct.CreatePortfolioInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreatePortfolioInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.CreatePortfolioInput.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.CreatePortfolioInput.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_name`](struct.CreatePortfolioInput.html#structfield.provider_name) member.
+    pub fn provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreatePortfolioInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePortfolioInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePortfolioInput, ::rusoto_core::BuildError> {
+        Ok(CreatePortfolioInput {
+            accept_language: self.accept_language,
+            description: self.description,
+            display_name: self
+                .display_name
+                .ok_or(::rusoto_core::BuildError::missing("display_name"))?,
+            idempotency_token: self
+                .idempotency_token
+                .ok_or(::rusoto_core::BuildError::missing("idempotency_token"))?,
+            provider_name: self
+                .provider_name
+                .ok_or(::rusoto_core::BuildError::missing("provider_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreatePortfolioOutput {
@@ -384,6 +1411,62 @@ pub struct CreatePortfolioShareInput {
     pub portfolio_id: String,
 }
 
+impl CreatePortfolioShareInput {
+    /// Returns a builder for `CreatePortfolioShareInput`, checking its required members when built.
+    pub fn builder() -> CreatePortfolioShareInputBuilder {
+        CreatePortfolioShareInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePortfolioShareInput`](struct.CreatePortfolioShareInput.html).
+#[derive(Default, Debug)]
+pub struct CreatePortfolioShareInputBuilder {
+    accept_language: Option<String>,
+    account_id: Option<String>,
+    organization_node: Option<OrganizationNode>,
+    portfolio_id: Option<String>,
+}
+
+impl CreatePortfolioShareInputBuilder {
+    /// Sets the [`accept_language`](struct.CreatePortfolioShareInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`account_id`](struct.CreatePortfolioShareInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_node`](struct.CreatePortfolioShareInput.html#structfield.organization_node) member.
+    pub fn organization_node<T: Into<OrganizationNode>>(mut self, value: T) -> Self {
+        self.organization_node = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.CreatePortfolioShareInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePortfolioShareInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePortfolioShareInput, ::rusoto_core::BuildError> {
+        Ok(CreatePortfolioShareInput {
+            accept_language: self.accept_language,
+            account_id: self.account_id,
+            organization_node: self.organization_node,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreatePortfolioShareOutput {
@@ -393,7 +1476,7 @@ pub struct CreatePortfolioShareOutput {
     pub portfolio_share_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -418,7 +1501,7 @@ pub struct CreateProductInput {
     pub owner: String,
     /// <p>The type of product.</p>
     #[serde(rename = "ProductType")]
-    pub product_type: String,
+    pub product_type: ProductType,
     /// <p>The configuration of the provisioning artifact.</p>
     #[serde(rename = "ProvisioningArtifactParameters")]
     pub provisioning_artifact_parameters: ProvisioningArtifactProperties,
@@ -440,6 +1523,135 @@ pub struct CreateProductInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateProductInput {
+    /// Returns a builder for `CreateProductInput`, checking its required members when built.
+    pub fn builder() -> CreateProductInputBuilder {
+        CreateProductInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateProductInput`](struct.CreateProductInput.html).
+#[derive(Default, Debug)]
+pub struct CreateProductInputBuilder {
+    accept_language: Option<String>,
+    description: Option<String>,
+    distributor: Option<String>,
+    idempotency_token: Option<String>,
+    name: Option<String>,
+    owner: Option<String>,
+    product_type: Option<ProductType>,
+    provisioning_artifact_parameters: Option<ProvisioningArtifactProperties>,
+    support_description: Option<String>,
+    support_email: Option<String>,
+    support_url: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateProductInputBuilder {
+    /// Sets the [`accept_language`](struct.CreateProductInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateProductInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`distributor`](struct.CreateProductInput.html#structfield.distributor) member.
+    pub fn distributor<T: Into<String>>(mut self, value: T) -> Self {
+        self.distributor = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.CreateProductInput.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateProductInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner`](struct.CreateProductInput.html#structfield.owner) member.
+    pub fn owner<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_type`](struct.CreateProductInput.html#structfield.product_type) member.
+    pub fn product_type<T: Into<ProductType>>(mut self, value: T) -> Self {
+        self.product_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_artifact_parameters`](struct.CreateProductInput.html#structfield.provisioning_artifact_parameters) member.
+    pub fn provisioning_artifact_parameters<T: Into<ProvisioningArtifactProperties>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.provisioning_artifact_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`support_description`](struct.CreateProductInput.html#structfield.support_description) member.
+    pub fn support_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.support_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`support_email`](struct.CreateProductInput.html#structfield.support_email) member.
+    pub fn support_email<T: Into<String>>(mut self, value: T) -> Self {
+        self.support_email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`support_url`](struct.CreateProductInput.html#structfield.support_url) member.
+    pub fn support_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.support_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateProductInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateProductInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateProductInput, ::rusoto_core::BuildError> {
+        Ok(CreateProductInput {
+            accept_language: self.accept_language,
+            description: self.description,
+            distributor: self.distributor,
+            idempotency_token: self
+                .idempotency_token
+                .ok_or(::rusoto_core::BuildError::missing("idempotency_token"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            owner: self
+                .owner
+                .ok_or(::rusoto_core::BuildError::missing("owner"))?,
+            product_type: self
+                .product_type
+                .ok_or(::rusoto_core::BuildError::missing("product_type"))?,
+            provisioning_artifact_parameters: self.provisioning_artifact_parameters.ok_or(
+                ::rusoto_core::BuildError::missing("provisioning_artifact_parameters"),
+            )?,
+            support_description: self.support_description,
+            support_email: self.support_email,
+            support_url: self.support_url,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateProductOutput {
@@ -457,7 +1669,7 @@ pub struct CreateProductOutput {
     pub tags: Option<Vec<Tag>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateProvisionedProductPlanInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -479,7 +1691,7 @@ pub struct CreateProvisionedProductPlanInput {
     pub plan_name: String,
     /// <p>The plan type.</p>
     #[serde(rename = "PlanType")]
-    pub plan_type: String,
+    pub plan_type: ProvisionedProductPlanType,
     /// <p>The product identifier.</p>
     #[serde(rename = "ProductId")]
     pub product_id: String,
@@ -499,6 +1711,131 @@ pub struct CreateProvisionedProductPlanInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateProvisionedProductPlanInput {
+    /// Returns a builder for `CreateProvisionedProductPlanInput`, checking its required members when built.
+    pub fn builder() -> CreateProvisionedProductPlanInputBuilder {
+        CreateProvisionedProductPlanInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateProvisionedProductPlanInput`](struct.CreateProvisionedProductPlanInput.html).
+#[derive(Default, Debug)]
+pub struct CreateProvisionedProductPlanInputBuilder {
+    accept_language: Option<String>,
+    idempotency_token: Option<String>,
+    notification_arns: Option<Vec<String>>,
+    path_id: Option<String>,
+    plan_name: Option<String>,
+    plan_type: Option<ProvisionedProductPlanType>,
+    product_id: Option<String>,
+    provisioned_product_name: Option<String>,
+    provisioning_artifact_id: Option<String>,
+    provisioning_parameters: Option<Vec<UpdateProvisioningParameter>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateProvisionedProductPlanInputBuilder {
+    /// Sets the [`accept_language`](struct.CreateProvisionedProductPlanInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.CreateProvisionedProductPlanInput.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_arns`](struct.CreateProvisionedProductPlanInput.html#structfield.notification_arns) member.
+    pub fn notification_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.notification_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_id`](struct.CreateProvisionedProductPlanInput.html#structfield.path_id) member.
+    pub fn path_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`plan_name`](struct.CreateProvisionedProductPlanInput.html#structfield.plan_name) member.
+    pub fn plan_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.plan_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`plan_type`](struct.CreateProvisionedProductPlanInput.html#structfield.plan_type) member.
+    pub fn plan_type<T: Into<ProvisionedProductPlanType>>(mut self, value: T) -> Self {
+        self.plan_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.CreateProvisionedProductPlanInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_product_name`](struct.CreateProvisionedProductPlanInput.html#structfield.provisioned_product_name) member.
+    pub fn provisioned_product_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioned_product_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_artifact_id`](struct.CreateProvisionedProductPlanInput.html#structfield.provisioning_artifact_id) member.
+    pub fn provisioning_artifact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioning_artifact_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_parameters`](struct.CreateProvisionedProductPlanInput.html#structfield.provisioning_parameters) member.
+    pub fn provisioning_parameters<T: Into<Vec<UpdateProvisioningParameter>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.provisioning_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateProvisionedProductPlanInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateProvisionedProductPlanInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateProvisionedProductPlanInput, ::rusoto_core::BuildError> {
+        Ok(CreateProvisionedProductPlanInput {
+            accept_language: self.accept_language,
+            idempotency_token: self
+                .idempotency_token
+                .ok_or(::rusoto_core::BuildError::missing("idempotency_token"))?,
+            notification_arns: self.notification_arns,
+            path_id: self.path_id,
+            plan_name: self
+                .plan_name
+                .ok_or(::rusoto_core::BuildError::missing("plan_name"))?,
+            plan_type: self
+                .plan_type
+                .ok_or(::rusoto_core::BuildError::missing("plan_type"))?,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            provisioned_product_name: self.provisioned_product_name.ok_or(
+                ::rusoto_core::BuildError::missing("provisioned_product_name"),
+            )?,
+            provisioning_artifact_id: self.provisioning_artifact_id.ok_or(
+                ::rusoto_core::BuildError::missing("provisioning_artifact_id"),
+            )?,
+            provisioning_parameters: self.provisioning_parameters,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateProvisionedProductPlanOutput {
@@ -541,6 +1878,66 @@ pub struct CreateProvisioningArtifactInput {
     pub product_id: String,
 }
 
+impl CreateProvisioningArtifactInput {
+    /// Returns a builder for `CreateProvisioningArtifactInput`, checking its required members when built.
+    pub fn builder() -> CreateProvisioningArtifactInputBuilder {
+        CreateProvisioningArtifactInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateProvisioningArtifactInput`](struct.CreateProvisioningArtifactInput.html).
+#[derive(Default, Debug)]
+pub struct CreateProvisioningArtifactInputBuilder {
+    accept_language: Option<String>,
+    idempotency_token: Option<String>,
+    parameters: Option<ProvisioningArtifactProperties>,
+    product_id: Option<String>,
+}
+
+impl CreateProvisioningArtifactInputBuilder {
+    /// Sets the [`accept_language`](struct.CreateProvisioningArtifactInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.CreateProvisioningArtifactInput.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.CreateProvisioningArtifactInput.html#structfield.parameters) member.
+    pub fn parameters<T: Into<ProvisioningArtifactProperties>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.CreateProvisioningArtifactInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateProvisioningArtifactInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateProvisioningArtifactInput, ::rusoto_core::BuildError> {
+        Ok(CreateProvisioningArtifactInput {
+            accept_language: self.accept_language,
+            idempotency_token: self
+                .idempotency_token
+                .ok_or(::rusoto_core::BuildError::missing("idempotency_token"))?,
+            parameters: self
+                .parameters
+                .ok_or(::rusoto_core::BuildError::missing("parameters"))?,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateProvisioningArtifactOutput {
@@ -555,10 +1952,10 @@ pub struct CreateProvisioningArtifactOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateServiceActionInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -566,10 +1963,10 @@ pub struct CreateServiceActionInput {
     pub accept_language: Option<String>,
     /// <p><p>The self-service action definition. Can be one of the following:</p> <dl> <dt>Name</dt> <dd> <p>The name of the AWS Systems Manager Document. For example, <code>AWS-RestartEC2Instance</code>.</p> </dd> <dt>Version</dt> <dd> <p>The AWS Systems Manager automation document version. For example, <code>&quot;Version&quot;: &quot;1&quot;</code> </p> </dd> <dt>AssumeRole</dt> <dd> <p>The Amazon Resource Name (ARN) of the role that performs the self-service actions on your behalf. For example, <code>&quot;AssumeRole&quot;: &quot;arn:aws:iam::12345678910:role/ActionRole&quot;</code>.</p> <p>To reuse the provisioned product launch role, set to <code>&quot;AssumeRole&quot;: &quot;LAUNCH_ROLE&quot;</code>.</p> </dd> <dt>Parameters</dt> <dd> <p>The list of parameters in JSON format.</p> <p>For example: <code>[{&quot;Name&quot;:&quot;InstanceId&quot;,&quot;Type&quot;:&quot;TARGET&quot;}]</code>.</p> </dd> </dl></p>
     #[serde(rename = "Definition")]
-    pub definition: ::std::collections::HashMap<String, String>,
+    pub definition: ::std::collections::HashMap<ServiceActionDefinitionKey, String>,
     /// <p>The service action definition type. For example, <code>SSM_AUTOMATION</code>.</p>
     #[serde(rename = "DefinitionType")]
-    pub definition_type: String,
+    pub definition_type: ServiceActionDefinitionType,
     /// <p>The self-service action description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -582,6 +1979,87 @@ pub struct CreateServiceActionInput {
     pub name: String,
 }
 
+impl CreateServiceActionInput {
+    /// Returns a builder for `CreateServiceActionInput`, checking its required members when built.
+    pub fn builder() -> CreateServiceActionInputBuilder {
+        CreateServiceActionInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateServiceActionInput`](struct.CreateServiceActionInput.html).
+#[derive(Default, Debug)]
+pub struct CreateServiceActionInputBuilder {
+    accept_language: Option<String>,
+    definition: Option<::std::collections::HashMap<ServiceActionDefinitionKey, String>>,
+    definition_type: Option<ServiceActionDefinitionType>,
+    description: Option<String>,
+    idempotency_token: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateServiceActionInputBuilder {
+    /// Sets the [`accept_language`](struct.CreateServiceActionInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`definition`](struct.CreateServiceActionInput.html#structfield.definition) member.
+    pub fn definition<T: Into<::std::collections::HashMap<ServiceActionDefinitionKey, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.definition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`definition_type`](struct.CreateServiceActionInput.html#structfield.definition_type) member.
+    pub fn definition_type<T: Into<ServiceActionDefinitionType>>(mut self, value: T) -> Self {
+        self.definition_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateServiceActionInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.CreateServiceActionInput.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateServiceActionInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateServiceActionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateServiceActionInput, ::rusoto_core::BuildError> {
+        Ok(CreateServiceActionInput {
+            accept_language: self.accept_language,
+            definition: self
+                .definition
+                .ok_or(::rusoto_core::BuildError::missing("definition"))?,
+            definition_type: self
+                .definition_type
+                .ok_or(::rusoto_core::BuildError::missing("definition_type"))?,
+            description: self.description,
+            idempotency_token: self
+                .idempotency_token
+                .ok_or(::rusoto_core::BuildError::missing("idempotency_token"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateServiceActionOutput {
@@ -601,6 +2079,44 @@ pub struct CreateTagOptionInput {
     pub value: String,
 }
 
+impl CreateTagOptionInput {
+    /// Returns a builder for `CreateTagOptionInput`, checking its required members when built.
+    pub fn builder() -> CreateTagOptionInputBuilder {
+        CreateTagOptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTagOptionInput`](struct.CreateTagOptionInput.html).
+#[derive(Default, Debug)]
+pub struct CreateTagOptionInputBuilder {
+    key: Option<String>,
+    value: Option<String>,
+}
+
+impl CreateTagOptionInputBuilder {
+    /// Sets the [`key`](struct.CreateTagOptionInput.html#structfield.key) member.
+    pub fn key<T: Into<String>>(mut self, value: T) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`value`](struct.CreateTagOptionInput.html#structfield.value) member.
+    pub fn value<T: Into<String>>(mut self, value: T) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTagOptionInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTagOptionInput, ::rusoto_core::BuildError> {
+        Ok(CreateTagOptionInput {
+            key: self.key.ok_or(::rusoto_core::BuildError::missing("key"))?,
+            value: self
+                .value
+                .ok_or(::rusoto_core::BuildError::missing("value"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateTagOptionOutput {
@@ -621,6 +2137,42 @@ pub struct DeleteConstraintInput {
     pub id: String,
 }
 
+impl DeleteConstraintInput {
+    /// Returns a builder for `DeleteConstraintInput`, checking its required members when built.
+    pub fn builder() -> DeleteConstraintInputBuilder {
+        DeleteConstraintInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConstraintInput`](struct.DeleteConstraintInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteConstraintInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+}
+
+impl DeleteConstraintInputBuilder {
+    /// Sets the [`accept_language`](struct.DeleteConstraintInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DeleteConstraintInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConstraintInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteConstraintInput, ::rusoto_core::BuildError> {
+        Ok(DeleteConstraintInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteConstraintOutput {}
@@ -636,6 +2188,42 @@ pub struct DeletePortfolioInput {
     pub id: String,
 }
 
+impl DeletePortfolioInput {
+    /// Returns a builder for `DeletePortfolioInput`, checking its required members when built.
+    pub fn builder() -> DeletePortfolioInputBuilder {
+        DeletePortfolioInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePortfolioInput`](struct.DeletePortfolioInput.html).
+#[derive(Default, Debug)]
+pub struct DeletePortfolioInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+}
+
+impl DeletePortfolioInputBuilder {
+    /// Sets the [`accept_language`](struct.DeletePortfolioInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DeletePortfolioInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePortfolioInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePortfolioInput, ::rusoto_core::BuildError> {
+        Ok(DeletePortfolioInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeletePortfolioOutput {}
@@ -659,6 +2247,62 @@ pub struct DeletePortfolioShareInput {
     pub portfolio_id: String,
 }
 
+impl DeletePortfolioShareInput {
+    /// Returns a builder for `DeletePortfolioShareInput`, checking its required members when built.
+    pub fn builder() -> DeletePortfolioShareInputBuilder {
+        DeletePortfolioShareInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePortfolioShareInput`](struct.DeletePortfolioShareInput.html).
+#[derive(Default, Debug)]
+pub struct DeletePortfolioShareInputBuilder {
+    accept_language: Option<String>,
+    account_id: Option<String>,
+    organization_node: Option<OrganizationNode>,
+    portfolio_id: Option<String>,
+}
+
+impl DeletePortfolioShareInputBuilder {
+    /// Sets the [`accept_language`](struct.DeletePortfolioShareInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`account_id`](struct.DeletePortfolioShareInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_node`](struct.DeletePortfolioShareInput.html#structfield.organization_node) member.
+    pub fn organization_node<T: Into<OrganizationNode>>(mut self, value: T) -> Self {
+        self.organization_node = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.DeletePortfolioShareInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePortfolioShareInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePortfolioShareInput, ::rusoto_core::BuildError> {
+        Ok(DeletePortfolioShareInput {
+            accept_language: self.accept_language,
+            account_id: self.account_id,
+            organization_node: self.organization_node,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeletePortfolioShareOutput {
@@ -679,6 +2323,42 @@ pub struct DeleteProductInput {
     pub id: String,
 }
 
+impl DeleteProductInput {
+    /// Returns a builder for `DeleteProductInput`, checking its required members when built.
+    pub fn builder() -> DeleteProductInputBuilder {
+        DeleteProductInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteProductInput`](struct.DeleteProductInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteProductInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+}
+
+impl DeleteProductInputBuilder {
+    /// Sets the [`accept_language`](struct.DeleteProductInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DeleteProductInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteProductInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteProductInput, ::rusoto_core::BuildError> {
+        Ok(DeleteProductInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteProductOutput {}
@@ -698,6 +2378,54 @@ pub struct DeleteProvisionedProductPlanInput {
     pub plan_id: String,
 }
 
+impl DeleteProvisionedProductPlanInput {
+    /// Returns a builder for `DeleteProvisionedProductPlanInput`, checking its required members when built.
+    pub fn builder() -> DeleteProvisionedProductPlanInputBuilder {
+        DeleteProvisionedProductPlanInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteProvisionedProductPlanInput`](struct.DeleteProvisionedProductPlanInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteProvisionedProductPlanInputBuilder {
+    accept_language: Option<String>,
+    ignore_errors: Option<bool>,
+    plan_id: Option<String>,
+}
+
+impl DeleteProvisionedProductPlanInputBuilder {
+    /// Sets the [`accept_language`](struct.DeleteProvisionedProductPlanInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ignore_errors`](struct.DeleteProvisionedProductPlanInput.html#structfield.ignore_errors) member.
+    pub fn ignore_errors<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ignore_errors = Some(value.into());
+        self
+    }
+
+    /// Sets the [`plan_id`](struct.DeleteProvisionedProductPlanInput.html#structfield.plan_id) member.
+    pub fn plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteProvisionedProductPlanInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteProvisionedProductPlanInput, ::rusoto_core::BuildError> {
+        Ok(DeleteProvisionedProductPlanInput {
+            accept_language: self.accept_language,
+            ignore_errors: self.ignore_errors,
+            plan_id: self
+                .plan_id
+                .ok_or(::rusoto_core::BuildError::missing("plan_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteProvisionedProductPlanOutput {}
@@ -716,6 +2444,56 @@ pub struct DeleteProvisioningArtifactInput {
     pub provisioning_artifact_id: String,
 }
 
+impl DeleteProvisioningArtifactInput {
+    /// Returns a builder for `DeleteProvisioningArtifactInput`, checking its required members when built.
+    pub fn builder() -> DeleteProvisioningArtifactInputBuilder {
+        DeleteProvisioningArtifactInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteProvisioningArtifactInput`](struct.DeleteProvisioningArtifactInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteProvisioningArtifactInputBuilder {
+    accept_language: Option<String>,
+    product_id: Option<String>,
+    provisioning_artifact_id: Option<String>,
+}
+
+impl DeleteProvisioningArtifactInputBuilder {
+    /// Sets the [`accept_language`](struct.DeleteProvisioningArtifactInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.DeleteProvisioningArtifactInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_artifact_id`](struct.DeleteProvisioningArtifactInput.html#structfield.provisioning_artifact_id) member.
+    pub fn provisioning_artifact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioning_artifact_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteProvisioningArtifactInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteProvisioningArtifactInput, ::rusoto_core::BuildError> {
+        Ok(DeleteProvisioningArtifactInput {
+            accept_language: self.accept_language,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            provisioning_artifact_id: self.provisioning_artifact_id.ok_or(
+                ::rusoto_core::BuildError::missing("provisioning_artifact_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteProvisioningArtifactOutput {}
@@ -731,6 +2509,44 @@ pub struct DeleteServiceActionInput {
     pub id: String,
 }
 
+impl DeleteServiceActionInput {
+    /// Returns a builder for `DeleteServiceActionInput`, checking its required members when built.
+    pub fn builder() -> DeleteServiceActionInputBuilder {
+        DeleteServiceActionInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteServiceActionInput`](struct.DeleteServiceActionInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteServiceActionInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+}
+
+impl DeleteServiceActionInputBuilder {
+    /// Sets the [`accept_language`](struct.DeleteServiceActionInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DeleteServiceActionInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteServiceActionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteServiceActionInput, ::rusoto_core::BuildError> {
+        Ok(DeleteServiceActionInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteServiceActionOutput {}
@@ -742,6 +2558,34 @@ pub struct DeleteTagOptionInput {
     pub id: String,
 }
 
+impl DeleteTagOptionInput {
+    /// Returns a builder for `DeleteTagOptionInput`, checking its required members when built.
+    pub fn builder() -> DeleteTagOptionInputBuilder {
+        DeleteTagOptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTagOptionInput`](struct.DeleteTagOptionInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteTagOptionInputBuilder {
+    id: Option<String>,
+}
+
+impl DeleteTagOptionInputBuilder {
+    /// Sets the [`id`](struct.DeleteTagOptionInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTagOptionInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTagOptionInput, ::rusoto_core::BuildError> {
+        Ok(DeleteTagOptionInput {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteTagOptionOutput {}
@@ -757,6 +2601,44 @@ pub struct DescribeConstraintInput {
     pub id: String,
 }
 
+impl DescribeConstraintInput {
+    /// Returns a builder for `DescribeConstraintInput`, checking its required members when built.
+    pub fn builder() -> DescribeConstraintInputBuilder {
+        DescribeConstraintInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConstraintInput`](struct.DescribeConstraintInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeConstraintInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+}
+
+impl DescribeConstraintInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeConstraintInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DescribeConstraintInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConstraintInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConstraintInput, ::rusoto_core::BuildError> {
+        Ok(DescribeConstraintInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeConstraintOutput {
@@ -771,7 +2653,7 @@ pub struct DescribeConstraintOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -785,13 +2667,53 @@ pub struct DescribeCopyProductStatusInput {
     pub copy_product_token: String,
 }
 
+impl DescribeCopyProductStatusInput {
+    /// Returns a builder for `DescribeCopyProductStatusInput`, checking its required members when built.
+    pub fn builder() -> DescribeCopyProductStatusInputBuilder {
+        DescribeCopyProductStatusInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCopyProductStatusInput`](struct.DescribeCopyProductStatusInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeCopyProductStatusInputBuilder {
+    accept_language: Option<String>,
+    copy_product_token: Option<String>,
+}
+
+impl DescribeCopyProductStatusInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeCopyProductStatusInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_product_token`](struct.DescribeCopyProductStatusInput.html#structfield.copy_product_token) member.
+    pub fn copy_product_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.copy_product_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCopyProductStatusInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCopyProductStatusInput, ::rusoto_core::BuildError> {
+        Ok(DescribeCopyProductStatusInput {
+            accept_language: self.accept_language,
+            copy_product_token: self
+                .copy_product_token
+                .ok_or(::rusoto_core::BuildError::missing("copy_product_token"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeCopyProductStatusOutput {
     /// <p>The status of the copy product operation.</p>
     #[serde(rename = "CopyProductStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub copy_product_status: Option<String>,
+    pub copy_product_status: Option<CopyProductStatus>,
     /// <p>The status message.</p>
     #[serde(rename = "StatusDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -813,6 +2735,42 @@ pub struct DescribePortfolioInput {
     pub id: String,
 }
 
+impl DescribePortfolioInput {
+    /// Returns a builder for `DescribePortfolioInput`, checking its required members when built.
+    pub fn builder() -> DescribePortfolioInputBuilder {
+        DescribePortfolioInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePortfolioInput`](struct.DescribePortfolioInput.html).
+#[derive(Default, Debug)]
+pub struct DescribePortfolioInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+}
+
+impl DescribePortfolioInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribePortfolioInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DescribePortfolioInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePortfolioInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribePortfolioInput, ::rusoto_core::BuildError> {
+        Ok(DescribePortfolioInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribePortfolioOutput {
@@ -841,6 +2799,38 @@ pub struct DescribePortfolioShareStatusInput {
     pub portfolio_share_token: String,
 }
 
+impl DescribePortfolioShareStatusInput {
+    /// Returns a builder for `DescribePortfolioShareStatusInput`, checking its required members when built.
+    pub fn builder() -> DescribePortfolioShareStatusInputBuilder {
+        DescribePortfolioShareStatusInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePortfolioShareStatusInput`](struct.DescribePortfolioShareStatusInput.html).
+#[derive(Default, Debug)]
+pub struct DescribePortfolioShareStatusInputBuilder {
+    portfolio_share_token: Option<String>,
+}
+
+impl DescribePortfolioShareStatusInputBuilder {
+    /// Sets the [`portfolio_share_token`](struct.DescribePortfolioShareStatusInput.html#structfield.portfolio_share_token) member.
+    pub fn portfolio_share_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_share_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePortfolioShareStatusInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePortfolioShareStatusInput, ::rusoto_core::BuildError> {
+        Ok(DescribePortfolioShareStatusInput {
+            portfolio_share_token: self
+                .portfolio_share_token
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_share_token"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribePortfolioShareStatusOutput {
@@ -863,7 +2853,7 @@ pub struct DescribePortfolioShareStatusOutput {
     /// <p>Status of the portfolio share operation.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ShareStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -877,6 +2867,44 @@ pub struct DescribeProductAsAdminInput {
     pub id: String,
 }
 
+impl DescribeProductAsAdminInput {
+    /// Returns a builder for `DescribeProductAsAdminInput`, checking its required members when built.
+    pub fn builder() -> DescribeProductAsAdminInputBuilder {
+        DescribeProductAsAdminInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProductAsAdminInput`](struct.DescribeProductAsAdminInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeProductAsAdminInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+}
+
+impl DescribeProductAsAdminInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeProductAsAdminInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DescribeProductAsAdminInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProductAsAdminInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeProductAsAdminInput, ::rusoto_core::BuildError> {
+        Ok(DescribeProductAsAdminInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeProductAsAdminOutput {
@@ -913,6 +2941,42 @@ pub struct DescribeProductInput {
     pub id: String,
 }
 
+impl DescribeProductInput {
+    /// Returns a builder for `DescribeProductInput`, checking its required members when built.
+    pub fn builder() -> DescribeProductInputBuilder {
+        DescribeProductInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProductInput`](struct.DescribeProductInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeProductInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+}
+
+impl DescribeProductInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeProductInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DescribeProductInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProductInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeProductInput, ::rusoto_core::BuildError> {
+        Ok(DescribeProductInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeProductOutput {
@@ -941,6 +3005,44 @@ pub struct DescribeProductViewInput {
     pub id: String,
 }
 
+impl DescribeProductViewInput {
+    /// Returns a builder for `DescribeProductViewInput`, checking its required members when built.
+    pub fn builder() -> DescribeProductViewInputBuilder {
+        DescribeProductViewInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProductViewInput`](struct.DescribeProductViewInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeProductViewInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+}
+
+impl DescribeProductViewInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeProductViewInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DescribeProductViewInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProductViewInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeProductViewInput, ::rusoto_core::BuildError> {
+        Ok(DescribeProductViewInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeProductViewOutput {
@@ -965,6 +3067,44 @@ pub struct DescribeProvisionedProductInput {
     pub id: String,
 }
 
+impl DescribeProvisionedProductInput {
+    /// Returns a builder for `DescribeProvisionedProductInput`, checking its required members when built.
+    pub fn builder() -> DescribeProvisionedProductInputBuilder {
+        DescribeProvisionedProductInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProvisionedProductInput`](struct.DescribeProvisionedProductInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeProvisionedProductInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+}
+
+impl DescribeProvisionedProductInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeProvisionedProductInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DescribeProvisionedProductInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProvisionedProductInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeProvisionedProductInput, ::rusoto_core::BuildError> {
+        Ok(DescribeProvisionedProductInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeProvisionedProductOutput {
@@ -997,6 +3137,62 @@ pub struct DescribeProvisionedProductPlanInput {
     pub plan_id: String,
 }
 
+impl DescribeProvisionedProductPlanInput {
+    /// Returns a builder for `DescribeProvisionedProductPlanInput`, checking its required members when built.
+    pub fn builder() -> DescribeProvisionedProductPlanInputBuilder {
+        DescribeProvisionedProductPlanInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProvisionedProductPlanInput`](struct.DescribeProvisionedProductPlanInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeProvisionedProductPlanInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    plan_id: Option<String>,
+}
+
+impl DescribeProvisionedProductPlanInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeProvisionedProductPlanInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.DescribeProvisionedProductPlanInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.DescribeProvisionedProductPlanInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`plan_id`](struct.DescribeProvisionedProductPlanInput.html#structfield.plan_id) member.
+    pub fn plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProvisionedProductPlanInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeProvisionedProductPlanInput, ::rusoto_core::BuildError> {
+        Ok(DescribeProvisionedProductPlanInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            plan_id: self
+                .plan_id
+                .ok_or(::rusoto_core::BuildError::missing("plan_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeProvisionedProductPlanOutput {
@@ -1032,6 +3228,64 @@ pub struct DescribeProvisioningArtifactInput {
     pub verbose: Option<bool>,
 }
 
+impl DescribeProvisioningArtifactInput {
+    /// Returns a builder for `DescribeProvisioningArtifactInput`, checking its required members when built.
+    pub fn builder() -> DescribeProvisioningArtifactInputBuilder {
+        DescribeProvisioningArtifactInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProvisioningArtifactInput`](struct.DescribeProvisioningArtifactInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeProvisioningArtifactInputBuilder {
+    accept_language: Option<String>,
+    product_id: Option<String>,
+    provisioning_artifact_id: Option<String>,
+    verbose: Option<bool>,
+}
+
+impl DescribeProvisioningArtifactInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeProvisioningArtifactInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.DescribeProvisioningArtifactInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_artifact_id`](struct.DescribeProvisioningArtifactInput.html#structfield.provisioning_artifact_id) member.
+    pub fn provisioning_artifact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioning_artifact_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`verbose`](struct.DescribeProvisioningArtifactInput.html#structfield.verbose) member.
+    pub fn verbose<T: Into<bool>>(mut self, value: T) -> Self {
+        self.verbose = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProvisioningArtifactInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeProvisioningArtifactInput, ::rusoto_core::BuildError> {
+        Ok(DescribeProvisioningArtifactInput {
+            accept_language: self.accept_language,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            provisioning_artifact_id: self.provisioning_artifact_id.ok_or(
+                ::rusoto_core::BuildError::missing("provisioning_artifact_id"),
+            )?,
+            verbose: self.verbose,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeProvisioningArtifactOutput {
@@ -1046,7 +3300,7 @@ pub struct DescribeProvisioningArtifactOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1067,6 +3321,64 @@ pub struct DescribeProvisioningParametersInput {
     pub provisioning_artifact_id: String,
 }
 
+impl DescribeProvisioningParametersInput {
+    /// Returns a builder for `DescribeProvisioningParametersInput`, checking its required members when built.
+    pub fn builder() -> DescribeProvisioningParametersInputBuilder {
+        DescribeProvisioningParametersInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProvisioningParametersInput`](struct.DescribeProvisioningParametersInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeProvisioningParametersInputBuilder {
+    accept_language: Option<String>,
+    path_id: Option<String>,
+    product_id: Option<String>,
+    provisioning_artifact_id: Option<String>,
+}
+
+impl DescribeProvisioningParametersInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeProvisioningParametersInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_id`](struct.DescribeProvisioningParametersInput.html#structfield.path_id) member.
+    pub fn path_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.DescribeProvisioningParametersInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_artifact_id`](struct.DescribeProvisioningParametersInput.html#structfield.provisioning_artifact_id) member.
+    pub fn provisioning_artifact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioning_artifact_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProvisioningParametersInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeProvisioningParametersInput, ::rusoto_core::BuildError> {
+        Ok(DescribeProvisioningParametersInput {
+            accept_language: self.accept_language,
+            path_id: self.path_id,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            provisioning_artifact_id: self.provisioning_artifact_id.ok_or(
+                ::rusoto_core::BuildError::missing("provisioning_artifact_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeProvisioningParametersOutput {
@@ -1111,6 +3423,58 @@ pub struct DescribeRecordInput {
     pub page_token: Option<String>,
 }
 
+impl DescribeRecordInput {
+    /// Returns a builder for `DescribeRecordInput`, checking its required members when built.
+    pub fn builder() -> DescribeRecordInputBuilder {
+        DescribeRecordInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRecordInput`](struct.DescribeRecordInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeRecordInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+}
+
+impl DescribeRecordInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeRecordInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DescribeRecordInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.DescribeRecordInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.DescribeRecordInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRecordInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeRecordInput, ::rusoto_core::BuildError> {
+        Ok(DescribeRecordInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            page_size: self.page_size,
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRecordOutput {
@@ -1139,6 +3503,59 @@ pub struct DescribeServiceActionExecutionParametersInput {
     pub service_action_id: String,
 }
 
+impl DescribeServiceActionExecutionParametersInput {
+    /// Returns a builder for `DescribeServiceActionExecutionParametersInput`, checking its required members when built.
+    pub fn builder() -> DescribeServiceActionExecutionParametersInputBuilder {
+        DescribeServiceActionExecutionParametersInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeServiceActionExecutionParametersInput`](struct.DescribeServiceActionExecutionParametersInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeServiceActionExecutionParametersInputBuilder {
+    accept_language: Option<String>,
+    provisioned_product_id: Option<String>,
+    service_action_id: Option<String>,
+}
+
+impl DescribeServiceActionExecutionParametersInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeServiceActionExecutionParametersInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_product_id`](struct.DescribeServiceActionExecutionParametersInput.html#structfield.provisioned_product_id) member.
+    pub fn provisioned_product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioned_product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_action_id`](struct.DescribeServiceActionExecutionParametersInput.html#structfield.service_action_id) member.
+    pub fn service_action_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_action_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeServiceActionExecutionParametersInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeServiceActionExecutionParametersInput,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeServiceActionExecutionParametersInput {
+            accept_language: self.accept_language,
+            provisioned_product_id: self
+                .provisioned_product_id
+                .ok_or(::rusoto_core::BuildError::missing("provisioned_product_id"))?,
+            service_action_id: self
+                .service_action_id
+                .ok_or(::rusoto_core::BuildError::missing("service_action_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeServiceActionExecutionParametersOutput {
@@ -1158,9 +3575,47 @@ pub struct DescribeServiceActionInput {
     pub id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DescribeServiceActionOutput {
+impl DescribeServiceActionInput {
+    /// Returns a builder for `DescribeServiceActionInput`, checking its required members when built.
+    pub fn builder() -> DescribeServiceActionInputBuilder {
+        DescribeServiceActionInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeServiceActionInput`](struct.DescribeServiceActionInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeServiceActionInputBuilder {
+    accept_language: Option<String>,
+    id: Option<String>,
+}
+
+impl DescribeServiceActionInputBuilder {
+    /// Sets the [`accept_language`](struct.DescribeServiceActionInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DescribeServiceActionInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeServiceActionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeServiceActionInput, ::rusoto_core::BuildError> {
+        Ok(DescribeServiceActionInput {
+            accept_language: self.accept_language,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeServiceActionOutput {
     /// <p>Detailed information about the self-service action.</p>
     #[serde(rename = "ServiceActionDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1174,6 +3629,34 @@ pub struct DescribeTagOptionInput {
     pub id: String,
 }
 
+impl DescribeTagOptionInput {
+    /// Returns a builder for `DescribeTagOptionInput`, checking its required members when built.
+    pub fn builder() -> DescribeTagOptionInputBuilder {
+        DescribeTagOptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTagOptionInput`](struct.DescribeTagOptionInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTagOptionInputBuilder {
+    id: Option<String>,
+}
+
+impl DescribeTagOptionInputBuilder {
+    /// Sets the [`id`](struct.DescribeTagOptionInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTagOptionInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTagOptionInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTagOptionInput {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeTagOptionOutput {
@@ -1200,6 +3683,48 @@ pub struct DisassociateBudgetFromResourceInput {
     pub resource_id: String,
 }
 
+impl DisassociateBudgetFromResourceInput {
+    /// Returns a builder for `DisassociateBudgetFromResourceInput`, checking its required members when built.
+    pub fn builder() -> DisassociateBudgetFromResourceInputBuilder {
+        DisassociateBudgetFromResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateBudgetFromResourceInput`](struct.DisassociateBudgetFromResourceInput.html).
+#[derive(Default, Debug)]
+pub struct DisassociateBudgetFromResourceInputBuilder {
+    budget_name: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl DisassociateBudgetFromResourceInputBuilder {
+    /// Sets the [`budget_name`](struct.DisassociateBudgetFromResourceInput.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DisassociateBudgetFromResourceInput.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateBudgetFromResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateBudgetFromResourceInput, ::rusoto_core::BuildError> {
+        Ok(DisassociateBudgetFromResourceInput {
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateBudgetFromResourceOutput {}
@@ -1218,6 +3743,57 @@ pub struct DisassociatePrincipalFromPortfolioInput {
     pub principal_arn: String,
 }
 
+impl DisassociatePrincipalFromPortfolioInput {
+    /// Returns a builder for `DisassociatePrincipalFromPortfolioInput`, checking its required members when built.
+    pub fn builder() -> DisassociatePrincipalFromPortfolioInputBuilder {
+        DisassociatePrincipalFromPortfolioInputBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociatePrincipalFromPortfolioInput`](struct.DisassociatePrincipalFromPortfolioInput.html).
+#[derive(Default, Debug)]
+pub struct DisassociatePrincipalFromPortfolioInputBuilder {
+    accept_language: Option<String>,
+    portfolio_id: Option<String>,
+    principal_arn: Option<String>,
+}
+
+impl DisassociatePrincipalFromPortfolioInputBuilder {
+    /// Sets the [`accept_language`](struct.DisassociatePrincipalFromPortfolioInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.DisassociatePrincipalFromPortfolioInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_arn`](struct.DisassociatePrincipalFromPortfolioInput.html#structfield.principal_arn) member.
+    pub fn principal_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociatePrincipalFromPortfolioInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociatePrincipalFromPortfolioInput, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociatePrincipalFromPortfolioInput {
+            accept_language: self.accept_language,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+            principal_arn: self
+                .principal_arn
+                .ok_or(::rusoto_core::BuildError::missing("principal_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociatePrincipalFromPortfolioOutput {}
@@ -1236,6 +3812,57 @@ pub struct DisassociateProductFromPortfolioInput {
     pub product_id: String,
 }
 
+impl DisassociateProductFromPortfolioInput {
+    /// Returns a builder for `DisassociateProductFromPortfolioInput`, checking its required members when built.
+    pub fn builder() -> DisassociateProductFromPortfolioInputBuilder {
+        DisassociateProductFromPortfolioInputBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateProductFromPortfolioInput`](struct.DisassociateProductFromPortfolioInput.html).
+#[derive(Default, Debug)]
+pub struct DisassociateProductFromPortfolioInputBuilder {
+    accept_language: Option<String>,
+    portfolio_id: Option<String>,
+    product_id: Option<String>,
+}
+
+impl DisassociateProductFromPortfolioInputBuilder {
+    /// Sets the [`accept_language`](struct.DisassociateProductFromPortfolioInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.DisassociateProductFromPortfolioInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.DisassociateProductFromPortfolioInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateProductFromPortfolioInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateProductFromPortfolioInput, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateProductFromPortfolioInput {
+            accept_language: self.accept_language,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateProductFromPortfolioOutput {}
@@ -1257,6 +3884,69 @@ pub struct DisassociateServiceActionFromProvisioningArtifactInput {
     pub service_action_id: String,
 }
 
+impl DisassociateServiceActionFromProvisioningArtifactInput {
+    /// Returns a builder for `DisassociateServiceActionFromProvisioningArtifactInput`, checking its required members when built.
+    pub fn builder() -> DisassociateServiceActionFromProvisioningArtifactInputBuilder {
+        DisassociateServiceActionFromProvisioningArtifactInputBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateServiceActionFromProvisioningArtifactInput`](struct.DisassociateServiceActionFromProvisioningArtifactInput.html).
+#[derive(Default, Debug)]
+pub struct DisassociateServiceActionFromProvisioningArtifactInputBuilder {
+    accept_language: Option<String>,
+    product_id: Option<String>,
+    provisioning_artifact_id: Option<String>,
+    service_action_id: Option<String>,
+}
+
+impl DisassociateServiceActionFromProvisioningArtifactInputBuilder {
+    /// Sets the [`accept_language`](struct.DisassociateServiceActionFromProvisioningArtifactInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.DisassociateServiceActionFromProvisioningArtifactInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_artifact_id`](struct.DisassociateServiceActionFromProvisioningArtifactInput.html#structfield.provisioning_artifact_id) member.
+    pub fn provisioning_artifact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioning_artifact_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_action_id`](struct.DisassociateServiceActionFromProvisioningArtifactInput.html#structfield.service_action_id) member.
+    pub fn service_action_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_action_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateServiceActionFromProvisioningArtifactInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DisassociateServiceActionFromProvisioningArtifactInput,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DisassociateServiceActionFromProvisioningArtifactInput {
+            accept_language: self.accept_language,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            provisioning_artifact_id: self.provisioning_artifact_id.ok_or(
+                ::rusoto_core::BuildError::missing("provisioning_artifact_id"),
+            )?,
+            service_action_id: self
+                .service_action_id
+                .ok_or(::rusoto_core::BuildError::missing("service_action_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateServiceActionFromProvisioningArtifactOutput {}
@@ -1271,6 +3961,49 @@ pub struct DisassociateTagOptionFromResourceInput {
     pub tag_option_id: String,
 }
 
+impl DisassociateTagOptionFromResourceInput {
+    /// Returns a builder for `DisassociateTagOptionFromResourceInput`, checking its required members when built.
+    pub fn builder() -> DisassociateTagOptionFromResourceInputBuilder {
+        DisassociateTagOptionFromResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateTagOptionFromResourceInput`](struct.DisassociateTagOptionFromResourceInput.html).
+#[derive(Default, Debug)]
+pub struct DisassociateTagOptionFromResourceInputBuilder {
+    resource_id: Option<String>,
+    tag_option_id: Option<String>,
+}
+
+impl DisassociateTagOptionFromResourceInputBuilder {
+    /// Sets the [`resource_id`](struct.DisassociateTagOptionFromResourceInput.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_option_id`](struct.DisassociateTagOptionFromResourceInput.html#structfield.tag_option_id) member.
+    pub fn tag_option_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.tag_option_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateTagOptionFromResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateTagOptionFromResourceInput, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateTagOptionFromResourceInput {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tag_option_id: self
+                .tag_option_id
+                .ok_or(::rusoto_core::BuildError::missing("tag_option_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateTagOptionFromResourceOutput {}
@@ -1282,6 +4015,78 @@ pub struct EnableAWSOrganizationsAccessInput {}
 #[cfg_attr(test, derive(Serialize))]
 pub struct EnableAWSOrganizationsAccessOutput {}
 
+/// <p>For static evaluations, the value of the resource attribute will change and the new value is known. For dynamic evaluations, the value might change, and any new value will be determined when the plan is updated.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EvaluationType {
+    /// <p><code>STATIC</code></p>
+    Static,
+    /// <p><code>DYNAMIC</code></p>
+    Dynamic,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EvaluationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EvaluationType::Static => "STATIC",
+            EvaluationType::Dynamic => "DYNAMIC",
+            EvaluationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EvaluationType {
+    fn from(value: &'a str) -> EvaluationType {
+        match value {
+            "STATIC" => EvaluationType::Static,
+            "DYNAMIC" => EvaluationType::Dynamic,
+            _ => EvaluationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EvaluationType {
+    fn from(value: String) -> EvaluationType {
+        EvaluationType::from(value.as_str())
+    }
+}
+
+impl From<EvaluationType> for String {
+    fn from(value: EvaluationType) -> String {
+        match value {
+            EvaluationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EvaluationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EvaluationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EvaluationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EvaluationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EvaluationType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ExecuteProvisionedProductPlanInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
@@ -1296,6 +4101,56 @@ pub struct ExecuteProvisionedProductPlanInput {
     pub plan_id: String,
 }
 
+impl ExecuteProvisionedProductPlanInput {
+    /// Returns a builder for `ExecuteProvisionedProductPlanInput`, checking its required members when built.
+    pub fn builder() -> ExecuteProvisionedProductPlanInputBuilder {
+        ExecuteProvisionedProductPlanInputBuilder::default()
+    }
+}
+
+/// Builds a [`ExecuteProvisionedProductPlanInput`](struct.ExecuteProvisionedProductPlanInput.html).
+#[derive(Default, Debug)]
+pub struct ExecuteProvisionedProductPlanInputBuilder {
+    accept_language: Option<String>,
+    idempotency_token: Option<String>,
+    plan_id: Option<String>,
+}
+
+impl ExecuteProvisionedProductPlanInputBuilder {
+    /// Sets the [`accept_language`](struct.ExecuteProvisionedProductPlanInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.ExecuteProvisionedProductPlanInput.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`plan_id`](struct.ExecuteProvisionedProductPlanInput.html#structfield.plan_id) member.
+    pub fn plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExecuteProvisionedProductPlanInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ExecuteProvisionedProductPlanInput, ::rusoto_core::BuildError> {
+        Ok(ExecuteProvisionedProductPlanInput {
+            accept_language: self.accept_language,
+            idempotency_token: self
+                .idempotency_token
+                .ok_or(::rusoto_core::BuildError::missing("idempotency_token"))?,
+            plan_id: self
+                .plan_id
+                .ok_or(::rusoto_core::BuildError::missing("plan_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ExecuteProvisionedProductPlanOutput {
@@ -1325,6 +4180,78 @@ pub struct ExecuteProvisionedProductServiceActionInput {
     pub service_action_id: String,
 }
 
+impl ExecuteProvisionedProductServiceActionInput {
+    /// Returns a builder for `ExecuteProvisionedProductServiceActionInput`, checking its required members when built.
+    pub fn builder() -> ExecuteProvisionedProductServiceActionInputBuilder {
+        ExecuteProvisionedProductServiceActionInputBuilder::default()
+    }
+}
+
+/// Builds a [`ExecuteProvisionedProductServiceActionInput`](struct.ExecuteProvisionedProductServiceActionInput.html).
+#[derive(Default, Debug)]
+pub struct ExecuteProvisionedProductServiceActionInputBuilder {
+    accept_language: Option<String>,
+    execute_token: Option<String>,
+    parameters: Option<::std::collections::HashMap<String, Vec<String>>>,
+    provisioned_product_id: Option<String>,
+    service_action_id: Option<String>,
+}
+
+impl ExecuteProvisionedProductServiceActionInputBuilder {
+    /// Sets the [`accept_language`](struct.ExecuteProvisionedProductServiceActionInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execute_token`](struct.ExecuteProvisionedProductServiceActionInput.html#structfield.execute_token) member.
+    pub fn execute_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.execute_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ExecuteProvisionedProductServiceActionInput.html#structfield.parameters) member.
+    pub fn parameters<T: Into<::std::collections::HashMap<String, Vec<String>>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_product_id`](struct.ExecuteProvisionedProductServiceActionInput.html#structfield.provisioned_product_id) member.
+    pub fn provisioned_product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioned_product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_action_id`](struct.ExecuteProvisionedProductServiceActionInput.html#structfield.service_action_id) member.
+    pub fn service_action_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_action_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExecuteProvisionedProductServiceActionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ExecuteProvisionedProductServiceActionInput, ::rusoto_core::BuildError>
+    {
+        Ok(ExecuteProvisionedProductServiceActionInput {
+            accept_language: self.accept_language,
+            execute_token: self
+                .execute_token
+                .ok_or(::rusoto_core::BuildError::missing("execute_token"))?,
+            parameters: self.parameters,
+            provisioned_product_id: self
+                .provisioned_product_id
+                .ok_or(::rusoto_core::BuildError::missing("provisioned_product_id"))?,
+            service_action_id: self
+                .service_action_id
+                .ok_or(::rusoto_core::BuildError::missing("service_action_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ExecuteProvisionedProductServiceActionOutput {
@@ -1355,7 +4282,7 @@ pub struct FailedServiceActionAssociation {
     /// <p>The error code. Valid values are listed below.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ServiceActionAssociationErrorCode>,
     /// <p>A text description of the error.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1383,7 +4310,7 @@ pub struct GetAWSOrganizationsAccessStatusOutput {
     /// <p>The status of the portfolio share feature.</p>
     #[serde(rename = "AccessStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_status: Option<String>,
+    pub access_status: Option<AccessStatus>,
 }
 
 /// <p>Summary information about a product path for a user.</p>
@@ -1425,7 +4352,61 @@ pub struct ListAcceptedPortfolioSharesInput {
     /// <p><p>The type of shared portfolios to list. The default is to list imported portfolios.</p> <ul> <li> <p> <code>AWS<em>ORGANIZATIONS</code> - List portfolios shared by the master account of your organization</p> </li> <li> <p> <code>AWS</em>SERVICECATALOG</code> - List default portfolios</p> </li> <li> <p> <code>IMPORTED</code> - List imported portfolios</p> </li> </ul></p>
     #[serde(rename = "PortfolioShareType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub portfolio_share_type: Option<String>,
+    pub portfolio_share_type: Option<PortfolioShareType>,
+}
+
+impl ListAcceptedPortfolioSharesInput {
+    /// Returns a builder for `ListAcceptedPortfolioSharesInput`, checking its required members when built.
+    pub fn builder() -> ListAcceptedPortfolioSharesInputBuilder {
+        ListAcceptedPortfolioSharesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListAcceptedPortfolioSharesInput`](struct.ListAcceptedPortfolioSharesInput.html).
+#[derive(Default, Debug)]
+pub struct ListAcceptedPortfolioSharesInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    portfolio_share_type: Option<PortfolioShareType>,
+}
+
+impl ListAcceptedPortfolioSharesInputBuilder {
+    /// Sets the [`accept_language`](struct.ListAcceptedPortfolioSharesInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListAcceptedPortfolioSharesInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListAcceptedPortfolioSharesInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_share_type`](struct.ListAcceptedPortfolioSharesInput.html#structfield.portfolio_share_type) member.
+    pub fn portfolio_share_type<T: Into<PortfolioShareType>>(mut self, value: T) -> Self {
+        self.portfolio_share_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAcceptedPortfolioSharesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAcceptedPortfolioSharesInput, ::rusoto_core::BuildError> {
+        Ok(ListAcceptedPortfolioSharesInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            portfolio_share_type: self.portfolio_share_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1460,6 +4441,62 @@ pub struct ListBudgetsForResourceInput {
     pub resource_id: String,
 }
 
+impl ListBudgetsForResourceInput {
+    /// Returns a builder for `ListBudgetsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListBudgetsForResourceInputBuilder {
+        ListBudgetsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListBudgetsForResourceInput`](struct.ListBudgetsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListBudgetsForResourceInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl ListBudgetsForResourceInputBuilder {
+    /// Sets the [`accept_language`](struct.ListBudgetsForResourceInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListBudgetsForResourceInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListBudgetsForResourceInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.ListBudgetsForResourceInput.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBudgetsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListBudgetsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListBudgetsForResourceInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListBudgetsForResourceOutput {
@@ -1496,6 +4533,70 @@ pub struct ListConstraintsForPortfolioInput {
     pub product_id: Option<String>,
 }
 
+impl ListConstraintsForPortfolioInput {
+    /// Returns a builder for `ListConstraintsForPortfolioInput`, checking its required members when built.
+    pub fn builder() -> ListConstraintsForPortfolioInputBuilder {
+        ListConstraintsForPortfolioInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListConstraintsForPortfolioInput`](struct.ListConstraintsForPortfolioInput.html).
+#[derive(Default, Debug)]
+pub struct ListConstraintsForPortfolioInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    portfolio_id: Option<String>,
+    product_id: Option<String>,
+}
+
+impl ListConstraintsForPortfolioInputBuilder {
+    /// Sets the [`accept_language`](struct.ListConstraintsForPortfolioInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListConstraintsForPortfolioInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListConstraintsForPortfolioInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.ListConstraintsForPortfolioInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.ListConstraintsForPortfolioInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListConstraintsForPortfolioInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListConstraintsForPortfolioInput, ::rusoto_core::BuildError> {
+        Ok(ListConstraintsForPortfolioInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+            product_id: self.product_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListConstraintsForPortfolioOutput {
@@ -1528,6 +4629,60 @@ pub struct ListLaunchPathsInput {
     pub product_id: String,
 }
 
+impl ListLaunchPathsInput {
+    /// Returns a builder for `ListLaunchPathsInput`, checking its required members when built.
+    pub fn builder() -> ListLaunchPathsInputBuilder {
+        ListLaunchPathsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListLaunchPathsInput`](struct.ListLaunchPathsInput.html).
+#[derive(Default, Debug)]
+pub struct ListLaunchPathsInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    product_id: Option<String>,
+}
+
+impl ListLaunchPathsInputBuilder {
+    /// Sets the [`accept_language`](struct.ListLaunchPathsInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListLaunchPathsInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListLaunchPathsInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.ListLaunchPathsInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLaunchPathsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListLaunchPathsInput, ::rusoto_core::BuildError> {
+        Ok(ListLaunchPathsInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLaunchPathsOutput {
@@ -1541,7 +4696,7 @@ pub struct ListLaunchPathsOutput {
     pub next_page_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListOrganizationPortfolioAccessInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1549,7 +4704,7 @@ pub struct ListOrganizationPortfolioAccessInput {
     pub accept_language: Option<String>,
     /// <p><p>The organization node type that will be returned in the output.</p> <ul> <li> <p> <code>ORGANIZATION</code> - Organization that has access to the portfolio. </p> </li> <li> <p> <code>ORGANIZATIONAL_UNIT</code> - Organizational unit that has access to the portfolio within your organization.</p> </li> <li> <p> <code>ACCOUNT</code> - Account that has access to the portfolio within your organization.</p> </li> </ul></p>
     #[serde(rename = "OrganizationNodeType")]
-    pub organization_node_type: String,
+    pub organization_node_type: OrganizationNodeType,
     /// <p>The maximum number of items to return with this call.</p>
     #[serde(rename = "PageSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1563,21 +4718,88 @@ pub struct ListOrganizationPortfolioAccessInput {
     pub portfolio_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListOrganizationPortfolioAccessOutput {
-    /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
-    #[serde(rename = "NextPageToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_page_token: Option<String>,
-    /// <p>Displays information about the organization nodes.</p>
-    #[serde(rename = "OrganizationNodes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub organization_nodes: Option<Vec<OrganizationNode>>,
+impl ListOrganizationPortfolioAccessInput {
+    /// Returns a builder for `ListOrganizationPortfolioAccessInput`, checking its required members when built.
+    pub fn builder() -> ListOrganizationPortfolioAccessInputBuilder {
+        ListOrganizationPortfolioAccessInputBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListPortfolioAccessInput {
+/// Builds a [`ListOrganizationPortfolioAccessInput`](struct.ListOrganizationPortfolioAccessInput.html).
+#[derive(Default, Debug)]
+pub struct ListOrganizationPortfolioAccessInputBuilder {
+    accept_language: Option<String>,
+    organization_node_type: Option<OrganizationNodeType>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    portfolio_id: Option<String>,
+}
+
+impl ListOrganizationPortfolioAccessInputBuilder {
+    /// Sets the [`accept_language`](struct.ListOrganizationPortfolioAccessInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_node_type`](struct.ListOrganizationPortfolioAccessInput.html#structfield.organization_node_type) member.
+    pub fn organization_node_type<T: Into<OrganizationNodeType>>(mut self, value: T) -> Self {
+        self.organization_node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListOrganizationPortfolioAccessInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListOrganizationPortfolioAccessInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.ListOrganizationPortfolioAccessInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOrganizationPortfolioAccessInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListOrganizationPortfolioAccessInput, ::rusoto_core::BuildError>
+    {
+        Ok(ListOrganizationPortfolioAccessInput {
+            accept_language: self.accept_language,
+            organization_node_type: self
+                .organization_node_type
+                .ok_or(::rusoto_core::BuildError::missing("organization_node_type"))?,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListOrganizationPortfolioAccessOutput {
+    /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
+    #[serde(rename = "NextPageToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+    /// <p>Displays information about the organization nodes.</p>
+    #[serde(rename = "OrganizationNodes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_nodes: Option<Vec<OrganizationNode>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListPortfolioAccessInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1587,6 +4809,46 @@ pub struct ListPortfolioAccessInput {
     pub portfolio_id: String,
 }
 
+impl ListPortfolioAccessInput {
+    /// Returns a builder for `ListPortfolioAccessInput`, checking its required members when built.
+    pub fn builder() -> ListPortfolioAccessInputBuilder {
+        ListPortfolioAccessInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListPortfolioAccessInput`](struct.ListPortfolioAccessInput.html).
+#[derive(Default, Debug)]
+pub struct ListPortfolioAccessInputBuilder {
+    accept_language: Option<String>,
+    portfolio_id: Option<String>,
+}
+
+impl ListPortfolioAccessInputBuilder {
+    /// Sets the [`accept_language`](struct.ListPortfolioAccessInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.ListPortfolioAccessInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPortfolioAccessInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPortfolioAccessInput, ::rusoto_core::BuildError> {
+        Ok(ListPortfolioAccessInput {
+            accept_language: self.accept_language,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPortfolioAccessOutput {
@@ -1619,6 +4881,62 @@ pub struct ListPortfoliosForProductInput {
     pub product_id: String,
 }
 
+impl ListPortfoliosForProductInput {
+    /// Returns a builder for `ListPortfoliosForProductInput`, checking its required members when built.
+    pub fn builder() -> ListPortfoliosForProductInputBuilder {
+        ListPortfoliosForProductInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListPortfoliosForProductInput`](struct.ListPortfoliosForProductInput.html).
+#[derive(Default, Debug)]
+pub struct ListPortfoliosForProductInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    product_id: Option<String>,
+}
+
+impl ListPortfoliosForProductInputBuilder {
+    /// Sets the [`accept_language`](struct.ListPortfoliosForProductInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListPortfoliosForProductInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListPortfoliosForProductInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.ListPortfoliosForProductInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPortfoliosForProductInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPortfoliosForProductInput, ::rusoto_core::BuildError> {
+        Ok(ListPortfoliosForProductInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPortfoliosForProductOutput {
@@ -1648,6 +4966,50 @@ pub struct ListPortfoliosInput {
     pub page_token: Option<String>,
 }
 
+impl ListPortfoliosInput {
+    /// Returns a builder for `ListPortfoliosInput`, checking its required members when built.
+    pub fn builder() -> ListPortfoliosInputBuilder {
+        ListPortfoliosInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListPortfoliosInput`](struct.ListPortfoliosInput.html).
+#[derive(Default, Debug)]
+pub struct ListPortfoliosInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+}
+
+impl ListPortfoliosInputBuilder {
+    /// Sets the [`accept_language`](struct.ListPortfoliosInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListPortfoliosInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListPortfoliosInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPortfoliosInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPortfoliosInput, ::rusoto_core::BuildError> {
+        Ok(ListPortfoliosInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPortfoliosOutput {
@@ -1680,6 +5042,62 @@ pub struct ListPrincipalsForPortfolioInput {
     pub portfolio_id: String,
 }
 
+impl ListPrincipalsForPortfolioInput {
+    /// Returns a builder for `ListPrincipalsForPortfolioInput`, checking its required members when built.
+    pub fn builder() -> ListPrincipalsForPortfolioInputBuilder {
+        ListPrincipalsForPortfolioInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListPrincipalsForPortfolioInput`](struct.ListPrincipalsForPortfolioInput.html).
+#[derive(Default, Debug)]
+pub struct ListPrincipalsForPortfolioInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    portfolio_id: Option<String>,
+}
+
+impl ListPrincipalsForPortfolioInputBuilder {
+    /// Sets the [`accept_language`](struct.ListPrincipalsForPortfolioInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListPrincipalsForPortfolioInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListPrincipalsForPortfolioInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.ListPrincipalsForPortfolioInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPrincipalsForPortfolioInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPrincipalsForPortfolioInput, ::rusoto_core::BuildError> {
+        Ok(ListPrincipalsForPortfolioInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPrincipalsForPortfolioOutput {
@@ -1717,6 +5135,68 @@ pub struct ListProvisionedProductPlansInput {
     pub provision_product_id: Option<String>,
 }
 
+impl ListProvisionedProductPlansInput {
+    /// Returns a builder for `ListProvisionedProductPlansInput`, checking its required members when built.
+    pub fn builder() -> ListProvisionedProductPlansInputBuilder {
+        ListProvisionedProductPlansInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListProvisionedProductPlansInput`](struct.ListProvisionedProductPlansInput.html).
+#[derive(Default, Debug)]
+pub struct ListProvisionedProductPlansInputBuilder {
+    accept_language: Option<String>,
+    access_level_filter: Option<AccessLevelFilter>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    provision_product_id: Option<String>,
+}
+
+impl ListProvisionedProductPlansInputBuilder {
+    /// Sets the [`accept_language`](struct.ListProvisionedProductPlansInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`access_level_filter`](struct.ListProvisionedProductPlansInput.html#structfield.access_level_filter) member.
+    pub fn access_level_filter<T: Into<AccessLevelFilter>>(mut self, value: T) -> Self {
+        self.access_level_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListProvisionedProductPlansInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListProvisionedProductPlansInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provision_product_id`](struct.ListProvisionedProductPlansInput.html#structfield.provision_product_id) member.
+    pub fn provision_product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provision_product_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListProvisionedProductPlansInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListProvisionedProductPlansInput, ::rusoto_core::BuildError> {
+        Ok(ListProvisionedProductPlansInput {
+            accept_language: self.accept_language,
+            access_level_filter: self.access_level_filter,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            provision_product_id: self.provision_product_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListProvisionedProductPlansOutput {
@@ -1749,6 +5229,65 @@ pub struct ListProvisioningArtifactsForServiceActionInput {
     pub service_action_id: String,
 }
 
+impl ListProvisioningArtifactsForServiceActionInput {
+    /// Returns a builder for `ListProvisioningArtifactsForServiceActionInput`, checking its required members when built.
+    pub fn builder() -> ListProvisioningArtifactsForServiceActionInputBuilder {
+        ListProvisioningArtifactsForServiceActionInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListProvisioningArtifactsForServiceActionInput`](struct.ListProvisioningArtifactsForServiceActionInput.html).
+#[derive(Default, Debug)]
+pub struct ListProvisioningArtifactsForServiceActionInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    service_action_id: Option<String>,
+}
+
+impl ListProvisioningArtifactsForServiceActionInputBuilder {
+    /// Sets the [`accept_language`](struct.ListProvisioningArtifactsForServiceActionInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListProvisioningArtifactsForServiceActionInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListProvisioningArtifactsForServiceActionInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_action_id`](struct.ListProvisioningArtifactsForServiceActionInput.html#structfield.service_action_id) member.
+    pub fn service_action_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_action_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListProvisioningArtifactsForServiceActionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ListProvisioningArtifactsForServiceActionInput,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ListProvisioningArtifactsForServiceActionInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            service_action_id: self
+                .service_action_id
+                .ok_or(::rusoto_core::BuildError::missing("service_action_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListProvisioningArtifactsForServiceActionOutput {
@@ -1773,6 +5312,46 @@ pub struct ListProvisioningArtifactsInput {
     pub product_id: String,
 }
 
+impl ListProvisioningArtifactsInput {
+    /// Returns a builder for `ListProvisioningArtifactsInput`, checking its required members when built.
+    pub fn builder() -> ListProvisioningArtifactsInputBuilder {
+        ListProvisioningArtifactsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListProvisioningArtifactsInput`](struct.ListProvisioningArtifactsInput.html).
+#[derive(Default, Debug)]
+pub struct ListProvisioningArtifactsInputBuilder {
+    accept_language: Option<String>,
+    product_id: Option<String>,
+}
+
+impl ListProvisioningArtifactsInputBuilder {
+    /// Sets the [`accept_language`](struct.ListProvisioningArtifactsInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.ListProvisioningArtifactsInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListProvisioningArtifactsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListProvisioningArtifactsInput, ::rusoto_core::BuildError> {
+        Ok(ListProvisioningArtifactsInput {
+            accept_language: self.accept_language,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListProvisioningArtifactsOutput {
@@ -1810,6 +5389,66 @@ pub struct ListRecordHistoryInput {
     pub search_filter: Option<ListRecordHistorySearchFilter>,
 }
 
+impl ListRecordHistoryInput {
+    /// Returns a builder for `ListRecordHistoryInput`, checking its required members when built.
+    pub fn builder() -> ListRecordHistoryInputBuilder {
+        ListRecordHistoryInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListRecordHistoryInput`](struct.ListRecordHistoryInput.html).
+#[derive(Default, Debug)]
+pub struct ListRecordHistoryInputBuilder {
+    accept_language: Option<String>,
+    access_level_filter: Option<AccessLevelFilter>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    search_filter: Option<ListRecordHistorySearchFilter>,
+}
+
+impl ListRecordHistoryInputBuilder {
+    /// Sets the [`accept_language`](struct.ListRecordHistoryInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`access_level_filter`](struct.ListRecordHistoryInput.html#structfield.access_level_filter) member.
+    pub fn access_level_filter<T: Into<AccessLevelFilter>>(mut self, value: T) -> Self {
+        self.access_level_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListRecordHistoryInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListRecordHistoryInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`search_filter`](struct.ListRecordHistoryInput.html#structfield.search_filter) member.
+    pub fn search_filter<T: Into<ListRecordHistorySearchFilter>>(mut self, value: T) -> Self {
+        self.search_filter = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRecordHistoryInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRecordHistoryInput, ::rusoto_core::BuildError> {
+        Ok(ListRecordHistoryInput {
+            accept_language: self.accept_language,
+            access_level_filter: self.access_level_filter,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            search_filter: self.search_filter,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRecordHistoryOutput {
@@ -1855,6 +5494,62 @@ pub struct ListResourcesForTagOptionInput {
     pub tag_option_id: String,
 }
 
+impl ListResourcesForTagOptionInput {
+    /// Returns a builder for `ListResourcesForTagOptionInput`, checking its required members when built.
+    pub fn builder() -> ListResourcesForTagOptionInputBuilder {
+        ListResourcesForTagOptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourcesForTagOptionInput`](struct.ListResourcesForTagOptionInput.html).
+#[derive(Default, Debug)]
+pub struct ListResourcesForTagOptionInputBuilder {
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    resource_type: Option<String>,
+    tag_option_id: Option<String>,
+}
+
+impl ListResourcesForTagOptionInputBuilder {
+    /// Sets the [`page_size`](struct.ListResourcesForTagOptionInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListResourcesForTagOptionInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.ListResourcesForTagOptionInput.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_option_id`](struct.ListResourcesForTagOptionInput.html#structfield.tag_option_id) member.
+    pub fn tag_option_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.tag_option_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourcesForTagOptionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListResourcesForTagOptionInput, ::rusoto_core::BuildError> {
+        Ok(ListResourcesForTagOptionInput {
+            page_size: self.page_size,
+            page_token: self.page_token,
+            resource_type: self.resource_type,
+            tag_option_id: self
+                .tag_option_id
+                .ok_or(::rusoto_core::BuildError::missing("tag_option_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResourcesForTagOptionOutput {
@@ -1890,6 +5585,75 @@ pub struct ListServiceActionsForProvisioningArtifactInput {
     pub provisioning_artifact_id: String,
 }
 
+impl ListServiceActionsForProvisioningArtifactInput {
+    /// Returns a builder for `ListServiceActionsForProvisioningArtifactInput`, checking its required members when built.
+    pub fn builder() -> ListServiceActionsForProvisioningArtifactInputBuilder {
+        ListServiceActionsForProvisioningArtifactInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListServiceActionsForProvisioningArtifactInput`](struct.ListServiceActionsForProvisioningArtifactInput.html).
+#[derive(Default, Debug)]
+pub struct ListServiceActionsForProvisioningArtifactInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    product_id: Option<String>,
+    provisioning_artifact_id: Option<String>,
+}
+
+impl ListServiceActionsForProvisioningArtifactInputBuilder {
+    /// Sets the [`accept_language`](struct.ListServiceActionsForProvisioningArtifactInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListServiceActionsForProvisioningArtifactInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListServiceActionsForProvisioningArtifactInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.ListServiceActionsForProvisioningArtifactInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_artifact_id`](struct.ListServiceActionsForProvisioningArtifactInput.html#structfield.provisioning_artifact_id) member.
+    pub fn provisioning_artifact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioning_artifact_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListServiceActionsForProvisioningArtifactInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ListServiceActionsForProvisioningArtifactInput,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ListServiceActionsForProvisioningArtifactInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            provisioning_artifact_id: self.provisioning_artifact_id.ok_or(
+                ::rusoto_core::BuildError::missing("provisioning_artifact_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListServiceActionsForProvisioningArtifactOutput {
@@ -1919,6 +5683,52 @@ pub struct ListServiceActionsInput {
     pub page_token: Option<String>,
 }
 
+impl ListServiceActionsInput {
+    /// Returns a builder for `ListServiceActionsInput`, checking its required members when built.
+    pub fn builder() -> ListServiceActionsInputBuilder {
+        ListServiceActionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListServiceActionsInput`](struct.ListServiceActionsInput.html).
+#[derive(Default, Debug)]
+pub struct ListServiceActionsInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+}
+
+impl ListServiceActionsInputBuilder {
+    /// Sets the [`accept_language`](struct.ListServiceActionsInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListServiceActionsInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListServiceActionsInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListServiceActionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListServiceActionsInput, ::rusoto_core::BuildError> {
+        Ok(ListServiceActionsInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListServiceActionsOutput {
@@ -1951,6 +5761,65 @@ pub struct ListStackInstancesForProvisionedProductInput {
     pub provisioned_product_id: String,
 }
 
+impl ListStackInstancesForProvisionedProductInput {
+    /// Returns a builder for `ListStackInstancesForProvisionedProductInput`, checking its required members when built.
+    pub fn builder() -> ListStackInstancesForProvisionedProductInputBuilder {
+        ListStackInstancesForProvisionedProductInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStackInstancesForProvisionedProductInput`](struct.ListStackInstancesForProvisionedProductInput.html).
+#[derive(Default, Debug)]
+pub struct ListStackInstancesForProvisionedProductInputBuilder {
+    accept_language: Option<String>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    provisioned_product_id: Option<String>,
+}
+
+impl ListStackInstancesForProvisionedProductInputBuilder {
+    /// Sets the [`accept_language`](struct.ListStackInstancesForProvisionedProductInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListStackInstancesForProvisionedProductInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListStackInstancesForProvisionedProductInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_product_id`](struct.ListStackInstancesForProvisionedProductInput.html#structfield.provisioned_product_id) member.
+    pub fn provisioned_product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioned_product_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStackInstancesForProvisionedProductInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ListStackInstancesForProvisionedProductInput,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ListStackInstancesForProvisionedProductInput {
+            accept_language: self.accept_language,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            provisioned_product_id: self
+                .provisioned_product_id
+                .ok_or(::rusoto_core::BuildError::missing("provisioned_product_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListStackInstancesForProvisionedProductOutput {
@@ -1997,6 +5866,50 @@ pub struct ListTagOptionsInput {
     pub page_token: Option<String>,
 }
 
+impl ListTagOptionsInput {
+    /// Returns a builder for `ListTagOptionsInput`, checking its required members when built.
+    pub fn builder() -> ListTagOptionsInputBuilder {
+        ListTagOptionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagOptionsInput`](struct.ListTagOptionsInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagOptionsInputBuilder {
+    filters: Option<ListTagOptionsFilters>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+}
+
+impl ListTagOptionsInputBuilder {
+    /// Sets the [`filters`](struct.ListTagOptionsInput.html#structfield.filters) member.
+    pub fn filters<T: Into<ListTagOptionsFilters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListTagOptionsInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListTagOptionsInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagOptionsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagOptionsInput, ::rusoto_core::BuildError> {
+        Ok(ListTagOptionsInput {
+            filters: self.filters,
+            page_size: self.page_size,
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagOptionsOutput {
@@ -2016,20 +5929,96 @@ pub struct OrganizationNode {
     /// <p>The organization node type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OrganizationNodeType>,
     /// <p>The identifier of the organization node.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
-/// <p>The constraints that the administrator has put on the parameter.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ParameterConstraints {
-    /// <p>The values that the administrator has allowed for the parameter.</p>
-    #[serde(rename = "AllowedValues")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+/// <p><p>The organization node type that will be returned in the output.</p> <ul> <li> <p> <code>ORGANIZATION</code> - Organization that has access to the portfolio. </p> </li> <li> <p> <code>ORGANIZATIONAL_UNIT</code> - Organizational unit that has access to the portfolio within your organization.</p> </li> <li> <p> <code>ACCOUNT</code> - Account that has access to the portfolio within your organization.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrganizationNodeType {
+    /// <p><code>ORGANIZATION</code> - Organization that has access to the portfolio.</p>
+    Organization,
+    /// <p><code>ORGANIZATIONAL_UNIT</code> - Organizational unit that has access to the portfolio within your organization.</p>
+    OrganizationalUnit,
+    /// <p><code>ACCOUNT</code> - Account that has access to the portfolio within your organization.</p>
+    Account,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrganizationNodeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrganizationNodeType::Organization => "ORGANIZATION",
+            OrganizationNodeType::OrganizationalUnit => "ORGANIZATIONAL_UNIT",
+            OrganizationNodeType::Account => "ACCOUNT",
+            OrganizationNodeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrganizationNodeType {
+    fn from(value: &'a str) -> OrganizationNodeType {
+        match value {
+            "ORGANIZATION" => OrganizationNodeType::Organization,
+            "ORGANIZATIONAL_UNIT" => OrganizationNodeType::OrganizationalUnit,
+            "ACCOUNT" => OrganizationNodeType::Account,
+            _ => OrganizationNodeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrganizationNodeType {
+    fn from(value: String) -> OrganizationNodeType {
+        OrganizationNodeType::from(value.as_str())
+    }
+}
+
+impl From<OrganizationNodeType> for String {
+    fn from(value: OrganizationNodeType) -> String {
+        match value {
+            OrganizationNodeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrganizationNodeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OrganizationNodeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrganizationNodeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrganizationNodeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrganizationNodeType::from(value))
+    }
+}
+
+/// <p>The constraints that the administrator has put on the parameter.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ParameterConstraints {
+    /// <p>The values that the administrator has allowed for the parameter.</p>
+    #[serde(rename = "AllowedValues")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_values: Option<Vec<String>>,
 }
 
@@ -2043,8 +6032,9 @@ pub struct PortfolioDetail {
     pub arn: Option<String>,
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The description of the portfolio.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2063,6 +6053,82 @@ pub struct PortfolioDetail {
     pub provider_name: Option<String>,
 }
 
+/// <p>The type of shared portfolios to accept. The default is to accept imported portfolios.</p> <ul> <li> <p> <code>AWS_ORGANIZATIONS</code> - Accept portfolios shared by the master account of your organization.</p> </li> <li> <p> <code>IMPORTED</code> - Accept imported portfolios.</p> </li> <li> <p> <code>AWS_SERVICECATALOG</code> - Not supported. (Throws ResourceNotFoundException.)</p> </li> </ul> <p>For example, <code>aws servicecatalog accept-portfolio-share --portfolio-id "port-2qwzkwxt3y5fk" --portfolio-share-type AWS_ORGANIZATIONS</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PortfolioShareType {
+    /// <p><code>IMPORTED</code> - Accept imported portfolios.</p>
+    Imported,
+    /// <p><code>AWS_SERVICECATALOG</code> - Not supported. (Throws ResourceNotFoundException.)</p>
+    AwsServicecatalog,
+    /// <p><code>AWS_ORGANIZATIONS</code> - Accept portfolios shared by the master account of your organization.</p>
+    AwsOrganizations,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PortfolioShareType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PortfolioShareType::Imported => "IMPORTED",
+            PortfolioShareType::AwsServicecatalog => "AWS_SERVICECATALOG",
+            PortfolioShareType::AwsOrganizations => "AWS_ORGANIZATIONS",
+            PortfolioShareType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PortfolioShareType {
+    fn from(value: &'a str) -> PortfolioShareType {
+        match value {
+            "IMPORTED" => PortfolioShareType::Imported,
+            "AWS_SERVICECATALOG" => PortfolioShareType::AwsServicecatalog,
+            "AWS_ORGANIZATIONS" => PortfolioShareType::AwsOrganizations,
+            _ => PortfolioShareType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PortfolioShareType {
+    fn from(value: String) -> PortfolioShareType {
+        PortfolioShareType::from(value.as_str())
+    }
+}
+
+impl From<PortfolioShareType> for String {
+    fn from(value: PortfolioShareType) -> String {
+        match value {
+            PortfolioShareType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PortfolioShareType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PortfolioShareType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PortfolioShareType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PortfolioShareType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PortfolioShareType::from(value))
+    }
+}
+
 /// <p>Information about a principal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2074,7 +6140,231 @@ pub struct Principal {
     /// <p>The principal type. The supported value is <code>IAM</code>.</p>
     #[serde(rename = "PrincipalType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub principal_type: Option<String>,
+    pub principal_type: Option<PrincipalType>,
+}
+
+/// <p>The principal type. The supported value is <code>IAM</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PrincipalType {
+    /// <p><code>IAM</code></p>
+    Iam,
+    /// <p><code>IAM_PATTERN</code></p>
+    IamPattern,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PrincipalType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PrincipalType::Iam => "IAM",
+            PrincipalType::IamPattern => "IAM_PATTERN",
+            PrincipalType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PrincipalType {
+    fn from(value: &'a str) -> PrincipalType {
+        match value {
+            "IAM" => PrincipalType::Iam,
+            "IAM_PATTERN" => PrincipalType::IamPattern,
+            _ => PrincipalType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PrincipalType {
+    fn from(value: String) -> PrincipalType {
+        PrincipalType::from(value.as_str())
+    }
+}
+
+impl From<PrincipalType> for String {
+    fn from(value: PrincipalType) -> String {
+        match value {
+            PrincipalType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PrincipalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PrincipalType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PrincipalType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PrincipalType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PrincipalType::from(value))
+    }
+}
+
+/// <p>Access level of the source of the product.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProductSource {
+    /// <p><code>ACCOUNT</code></p>
+    Account,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProductSource {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProductSource::Account => "ACCOUNT",
+            ProductSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProductSource {
+    fn from(value: &'a str) -> ProductSource {
+        match value {
+            "ACCOUNT" => ProductSource::Account,
+            _ => ProductSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProductSource {
+    fn from(value: String) -> ProductSource {
+        ProductSource::from(value.as_str())
+    }
+}
+
+impl From<ProductSource> for String {
+    fn from(value: ProductSource) -> String {
+        match value {
+            ProductSource::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProductSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProductSource {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProductSource {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProductSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProductSource::from(value))
+    }
+}
+
+/// <p>The type of product.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProductType {
+    /// <p><code>CLOUD_FORMATION_TEMPLATE</code></p>
+    CloudFormationTemplate,
+    /// <p><code>MARKETPLACE</code></p>
+    Marketplace,
+    /// <p><code>TERRAFORM_OPEN_SOURCE</code></p>
+    TerraformOpenSource,
+    /// <p><code>TERRAFORM_CLOUD</code></p>
+    TerraformCloud,
+    /// <p><code>EXTERNAL</code></p>
+    External,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProductType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProductType::CloudFormationTemplate => "CLOUD_FORMATION_TEMPLATE",
+            ProductType::Marketplace => "MARKETPLACE",
+            ProductType::TerraformOpenSource => "TERRAFORM_OPEN_SOURCE",
+            ProductType::TerraformCloud => "TERRAFORM_CLOUD",
+            ProductType::External => "EXTERNAL",
+            ProductType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProductType {
+    fn from(value: &'a str) -> ProductType {
+        match value {
+            "CLOUD_FORMATION_TEMPLATE" => ProductType::CloudFormationTemplate,
+            "MARKETPLACE" => ProductType::Marketplace,
+            "TERRAFORM_OPEN_SOURCE" => ProductType::TerraformOpenSource,
+            "TERRAFORM_CLOUD" => ProductType::TerraformCloud,
+            "EXTERNAL" => ProductType::External,
+            _ => ProductType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProductType {
+    fn from(value: String) -> ProductType {
+        ProductType::from(value.as_str())
+    }
+}
+
+impl From<ProductType> for String {
+    fn from(value: ProductType) -> String {
+        match value {
+            ProductType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProductType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProductType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProductType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProductType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProductType::from(value))
+    }
 }
 
 /// <p>A single product view aggregation value/count pair, containing metadata about each product to which the calling user has access.</p>
@@ -2097,8 +6387,9 @@ pub struct ProductViewAggregationValue {
 pub struct ProductViewDetail {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The ARN of the product.</p>
     #[serde(rename = "ProductARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2110,7 +6401,163 @@ pub struct ProductViewDetail {
     /// <p><p>The status of the product.</p> <ul> <li> <p> <code>AVAILABLE</code> - The product is ready for use.</p> </li> <li> <p> <code>CREATING</code> - Product creation has started; the product is not ready for use.</p> </li> <li> <p> <code>FAILED</code> - An action failed.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
+}
+
+/// <p>The search filters. If no search filters are specified, the output includes all products to which the administrator has access.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProductViewFilterBy {
+    /// <p><code>FullTextSearch</code></p>
+    FullTextSearch,
+    /// <p><code>Owner</code></p>
+    Owner,
+    /// <p><code>ProductType</code></p>
+    ProductType,
+    /// <p><code>SourceProductId</code></p>
+    SourceProductId,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProductViewFilterBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProductViewFilterBy::FullTextSearch => "FullTextSearch",
+            ProductViewFilterBy::Owner => "Owner",
+            ProductViewFilterBy::ProductType => "ProductType",
+            ProductViewFilterBy::SourceProductId => "SourceProductId",
+            ProductViewFilterBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProductViewFilterBy {
+    fn from(value: &'a str) -> ProductViewFilterBy {
+        match value {
+            "FullTextSearch" => ProductViewFilterBy::FullTextSearch,
+            "Owner" => ProductViewFilterBy::Owner,
+            "ProductType" => ProductViewFilterBy::ProductType,
+            "SourceProductId" => ProductViewFilterBy::SourceProductId,
+            _ => ProductViewFilterBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProductViewFilterBy {
+    fn from(value: String) -> ProductViewFilterBy {
+        ProductViewFilterBy::from(value.as_str())
+    }
+}
+
+impl From<ProductViewFilterBy> for String {
+    fn from(value: ProductViewFilterBy) -> String {
+        match value {
+            ProductViewFilterBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProductViewFilterBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProductViewFilterBy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProductViewFilterBy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProductViewFilterBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProductViewFilterBy::from(value))
+    }
+}
+
+/// <p>The sort field. If no value is specified, the results are not sorted.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProductViewSortBy {
+    /// <p><code>Title</code></p>
+    Title,
+    /// <p><code>VersionCount</code></p>
+    VersionCount,
+    /// <p><code>CreationDate</code></p>
+    CreationDate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProductViewSortBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProductViewSortBy::Title => "Title",
+            ProductViewSortBy::VersionCount => "VersionCount",
+            ProductViewSortBy::CreationDate => "CreationDate",
+            ProductViewSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProductViewSortBy {
+    fn from(value: &'a str) -> ProductViewSortBy {
+        match value {
+            "Title" => ProductViewSortBy::Title,
+            "VersionCount" => ProductViewSortBy::VersionCount,
+            "CreationDate" => ProductViewSortBy::CreationDate,
+            _ => ProductViewSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProductViewSortBy {
+    fn from(value: String) -> ProductViewSortBy {
+        ProductViewSortBy::from(value.as_str())
+    }
+}
+
+impl From<ProductViewSortBy> for String {
+    fn from(value: ProductViewSortBy) -> String {
+        match value {
+            ProductViewSortBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProductViewSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProductViewSortBy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProductViewSortBy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProductViewSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProductViewSortBy::from(value))
+    }
 }
 
 /// <p>Summary information about a product view.</p>
@@ -2160,38 +6607,110 @@ pub struct ProductViewSummary {
     /// <p>The product type. Contact the product administrator for the significance of this value. If this value is <code>MARKETPLACE</code>, the product was created by AWS Marketplace.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ProductType>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ProvisionProductInput {
-    /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
-    #[serde(rename = "AcceptLanguage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub accept_language: Option<String>,
-    /// <p>Passed to CloudFormation. The SNS topic ARNs to which to publish stack-related events.</p>
-    #[serde(rename = "NotificationArns")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub notification_arns: Option<Vec<String>>,
-    /// <p>The path identifier of the product. This value is optional if the product has a default path, and required if the product has more than one path. To list the paths for a product, use <a>ListLaunchPaths</a>.</p>
-    #[serde(rename = "PathId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub path_id: Option<String>,
-    /// <p>The product identifier.</p>
-    #[serde(rename = "ProductId")]
-    pub product_id: String,
-    /// <p>An idempotency token that uniquely identifies the provisioning request.</p>
-    #[serde(rename = "ProvisionToken")]
-    pub provision_token: String,
-    /// <p>A user-friendly name for the provisioned product. This value must be unique for the AWS account and cannot be updated after the product is provisioned.</p>
-    #[serde(rename = "ProvisionedProductName")]
-    pub provisioned_product_name: String,
-    /// <p>The identifier of the provisioning artifact.</p>
-    #[serde(rename = "ProvisioningArtifactId")]
-    pub provisioning_artifact_id: String,
-    /// <p>Parameters specified by the administrator that are required for provisioning the product.</p>
-    #[serde(rename = "ProvisioningParameters")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+/// <p>A map that contains the provisioned product properties to be updated.</p> <p>The <code>OWNER</code> key only accepts user ARNs. The owner is the user that is allowed to see, update, terminate, and execute service actions in the provisioned product.</p> <p>The administrator can change the owner of a provisioned product to another IAM user within the same account. Both end user owners and administrators can see ownership history of the provisioned product using the <code>ListRecordHistory</code> API. The new owner can describe all past records for the provisioned product using the <code>DescribeRecord</code> API. The previous owner can no longer use <code>DescribeRecord</code>, but can still see the product's history from when he was an owner using <code>ListRecordHistory</code>.</p> <p>If a provisioned product ownership is assigned to an end user, they can see and perform any action through the API or Service Catalog console such as update, terminate, and execute service actions. If an end user provisions a product and the owner is updated to someone else, they will no longer be able to see or perform any actions through API or the Service Catalog console on that provisioned product.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PropertyKey {
+    /// <p><code>OWNER</code></p>
+    Owner,
+    /// <p><code>LAUNCH_ROLE</code></p>
+    LaunchRole,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PropertyKey {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PropertyKey::Owner => "OWNER",
+            PropertyKey::LaunchRole => "LAUNCH_ROLE",
+            PropertyKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PropertyKey {
+    fn from(value: &'a str) -> PropertyKey {
+        match value {
+            "OWNER" => PropertyKey::Owner,
+            "LAUNCH_ROLE" => PropertyKey::LaunchRole,
+            _ => PropertyKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PropertyKey {
+    fn from(value: String) -> PropertyKey {
+        PropertyKey::from(value.as_str())
+    }
+}
+
+impl From<PropertyKey> for String {
+    fn from(value: PropertyKey) -> String {
+        match value {
+            PropertyKey::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PropertyKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PropertyKey {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PropertyKey {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PropertyKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PropertyKey::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ProvisionProductInput {
+    /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
+    #[serde(rename = "AcceptLanguage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+    /// <p>Passed to CloudFormation. The SNS topic ARNs to which to publish stack-related events.</p>
+    #[serde(rename = "NotificationArns")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_arns: Option<Vec<String>>,
+    /// <p>The path identifier of the product. This value is optional if the product has a default path, and required if the product has more than one path. To list the paths for a product, use <a>ListLaunchPaths</a>.</p>
+    #[serde(rename = "PathId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_id: Option<String>,
+    /// <p>The product identifier.</p>
+    #[serde(rename = "ProductId")]
+    pub product_id: String,
+    /// <p>An idempotency token that uniquely identifies the provisioning request.</p>
+    #[serde(rename = "ProvisionToken")]
+    pub provision_token: String,
+    /// <p>A user-friendly name for the provisioned product. This value must be unique for the AWS account and cannot be updated after the product is provisioned.</p>
+    #[serde(rename = "ProvisionedProductName")]
+    pub provisioned_product_name: String,
+    /// <p>The identifier of the provisioning artifact.</p>
+    #[serde(rename = "ProvisioningArtifactId")]
+    pub provisioning_artifact_id: String,
+    /// <p>Parameters specified by the administrator that are required for provisioning the product.</p>
+    #[serde(rename = "ProvisioningParameters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub provisioning_parameters: Option<Vec<ProvisioningParameter>>,
     /// <p>An object that contains information about the provisioning preferences for a stack set.</p>
     #[serde(rename = "ProvisioningPreferences")]
@@ -2203,6 +6722,117 @@ pub struct ProvisionProductInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl ProvisionProductInput {
+    /// Returns a builder for `ProvisionProductInput`, checking its required members when built.
+    pub fn builder() -> ProvisionProductInputBuilder {
+        ProvisionProductInputBuilder::default()
+    }
+}
+
+/// Builds a [`ProvisionProductInput`](struct.ProvisionProductInput.html).
+#[derive(Default, Debug)]
+pub struct ProvisionProductInputBuilder {
+    accept_language: Option<String>,
+    notification_arns: Option<Vec<String>>,
+    path_id: Option<String>,
+    product_id: Option<String>,
+    provision_token: Option<String>,
+    provisioned_product_name: Option<String>,
+    provisioning_artifact_id: Option<String>,
+    provisioning_parameters: Option<Vec<ProvisioningParameter>>,
+    provisioning_preferences: Option<ProvisioningPreferences>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl ProvisionProductInputBuilder {
+    /// Sets the [`accept_language`](struct.ProvisionProductInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_arns`](struct.ProvisionProductInput.html#structfield.notification_arns) member.
+    pub fn notification_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.notification_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_id`](struct.ProvisionProductInput.html#structfield.path_id) member.
+    pub fn path_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.ProvisionProductInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provision_token`](struct.ProvisionProductInput.html#structfield.provision_token) member.
+    pub fn provision_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.provision_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_product_name`](struct.ProvisionProductInput.html#structfield.provisioned_product_name) member.
+    pub fn provisioned_product_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioned_product_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_artifact_id`](struct.ProvisionProductInput.html#structfield.provisioning_artifact_id) member.
+    pub fn provisioning_artifact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioning_artifact_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_parameters`](struct.ProvisionProductInput.html#structfield.provisioning_parameters) member.
+    pub fn provisioning_parameters<T: Into<Vec<ProvisioningParameter>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.provisioning_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_preferences`](struct.ProvisionProductInput.html#structfield.provisioning_preferences) member.
+    pub fn provisioning_preferences<T: Into<ProvisioningPreferences>>(mut self, value: T) -> Self {
+        self.provisioning_preferences = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.ProvisionProductInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `ProvisionProductInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ProvisionProductInput, ::rusoto_core::BuildError> {
+        Ok(ProvisionProductInput {
+            accept_language: self.accept_language,
+            notification_arns: self.notification_arns,
+            path_id: self.path_id,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            provision_token: self
+                .provision_token
+                .ok_or(::rusoto_core::BuildError::missing("provision_token"))?,
+            provisioned_product_name: self.provisioned_product_name.ok_or(
+                ::rusoto_core::BuildError::missing("provisioned_product_name"),
+            )?,
+            provisioning_artifact_id: self.provisioning_artifact_id.ok_or(
+                ::rusoto_core::BuildError::missing("provisioning_artifact_id"),
+            )?,
+            provisioning_parameters: self.provisioning_parameters,
+            provisioning_preferences: self.provisioning_preferences,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ProvisionProductOutput {
@@ -2222,8 +6852,9 @@ pub struct ProvisionedProductAttribute {
     pub arn: Option<String>,
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The identifier of the provisioned product.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2255,7 +6886,7 @@ pub struct ProvisionedProductAttribute {
     /// <p><p>The current status of the provisioned product.</p> <ul> <li> <p> <code>AVAILABLE</code> - Stable state, ready to perform any operation. The most recent operation succeeded and completed.</p> </li> <li> <p> <code>UNDER<em>CHANGE</code> - Transitive state. Operations performed might not have valid results. Wait for an <code>AVAILABLE</code> status before performing operations.</p> </li> <li> <p> <code>TAINTED</code> - Stable state, ready to perform any operation. The stack has completed the requested operation but is not exactly what was requested. For example, a request to update to a new version failed and the stack rolled back to the current version.</p> </li> <li> <p> <code>ERROR</code> - An unexpected error occurred. The provisioned product exists but the stack is not running. For example, CloudFormation received a parameter value that was not valid and could not launch the stack.</p> </li> <li> <p> <code>PLAN</em>IN_PROGRESS</code> - Transitive state. The plan operations were performed to provision a new product, but resources have not yet been created. After reviewing the list of resources to be created, execute the plan. Wait for an <code>AVAILABLE</code> status before performing operations.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProvisionedProductStatus>,
     /// <p>The current status message of the provisioned product.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2288,8 +6919,9 @@ pub struct ProvisionedProductDetail {
     pub arn: Option<String>,
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The identifier of the provisioned product.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2317,7 +6949,7 @@ pub struct ProvisionedProductDetail {
     /// <p><p>The current status of the provisioned product.</p> <ul> <li> <p> <code>AVAILABLE</code> - Stable state, ready to perform any operation. The most recent operation succeeded and completed.</p> </li> <li> <p> <code>UNDER<em>CHANGE</code> - Transitive state. Operations performed might not have valid results. Wait for an <code>AVAILABLE</code> status before performing operations.</p> </li> <li> <p> <code>TAINTED</code> - Stable state, ready to perform any operation. The stack has completed the requested operation but is not exactly what was requested. For example, a request to update to a new version failed and the stack rolled back to the current version.</p> </li> <li> <p> <code>ERROR</code> - An unexpected error occurred. The provisioned product exists but the stack is not running. For example, CloudFormation received a parameter value that was not valid and could not launch the stack.</p> </li> <li> <p> <code>PLAN</em>IN_PROGRESS</code> - Transitive state. The plan operations were performed to provision a new product, but resources have not yet been created. After reviewing the list of resources to be created, execute the plan. Wait for an <code>AVAILABLE</code> status before performing operations.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProvisionedProductStatus>,
     /// <p>The current status message of the provisioned product.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2334,8 +6966,9 @@ pub struct ProvisionedProductDetail {
 pub struct ProvisionedProductPlanDetails {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>Passed to CloudFormation. The SNS topic ARNs to which to publish stack-related events.</p>
     #[serde(rename = "NotificationArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2355,7 +6988,7 @@ pub struct ProvisionedProductPlanDetails {
     /// <p>The plan type.</p>
     #[serde(rename = "PlanType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub plan_type: Option<String>,
+    pub plan_type: Option<ProvisionedProductPlanType>,
     /// <p>The product identifier.</p>
     #[serde(rename = "ProductId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2379,7 +7012,7 @@ pub struct ProvisionedProductPlanDetails {
     /// <p>The status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ProvisionedProductPlanStatus>,
     /// <p>The status message.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2390,8 +7023,99 @@ pub struct ProvisionedProductPlanDetails {
     pub tags: Option<Vec<Tag>>,
     /// <p>The time when the plan was last updated.</p>
     #[serde(rename = "UpdatedTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_time: Option<f64>,
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_time: Option<EpochTimestamp>,
+}
+
+/// <p>The status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProvisionedProductPlanStatus {
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>CREATE_SUCCESS</code></p>
+    CreateSuccess,
+    /// <p><code>CREATE_FAILED</code></p>
+    CreateFailed,
+    /// <p><code>EXECUTE_IN_PROGRESS</code></p>
+    ExecuteInProgress,
+    /// <p><code>EXECUTE_SUCCESS</code></p>
+    ExecuteSuccess,
+    /// <p><code>EXECUTE_FAILED</code></p>
+    ExecuteFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProvisionedProductPlanStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisionedProductPlanStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            ProvisionedProductPlanStatus::CreateSuccess => "CREATE_SUCCESS",
+            ProvisionedProductPlanStatus::CreateFailed => "CREATE_FAILED",
+            ProvisionedProductPlanStatus::ExecuteInProgress => "EXECUTE_IN_PROGRESS",
+            ProvisionedProductPlanStatus::ExecuteSuccess => "EXECUTE_SUCCESS",
+            ProvisionedProductPlanStatus::ExecuteFailed => "EXECUTE_FAILED",
+            ProvisionedProductPlanStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProvisionedProductPlanStatus {
+    fn from(value: &'a str) -> ProvisionedProductPlanStatus {
+        match value {
+            "CREATE_IN_PROGRESS" => ProvisionedProductPlanStatus::CreateInProgress,
+            "CREATE_SUCCESS" => ProvisionedProductPlanStatus::CreateSuccess,
+            "CREATE_FAILED" => ProvisionedProductPlanStatus::CreateFailed,
+            "EXECUTE_IN_PROGRESS" => ProvisionedProductPlanStatus::ExecuteInProgress,
+            "EXECUTE_SUCCESS" => ProvisionedProductPlanStatus::ExecuteSuccess,
+            "EXECUTE_FAILED" => ProvisionedProductPlanStatus::ExecuteFailed,
+            _ => ProvisionedProductPlanStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisionedProductPlanStatus {
+    fn from(value: String) -> ProvisionedProductPlanStatus {
+        ProvisionedProductPlanStatus::from(value.as_str())
+    }
+}
+
+impl From<ProvisionedProductPlanStatus> for String {
+    fn from(value: ProvisionedProductPlanStatus) -> String {
+        match value {
+            ProvisionedProductPlanStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProvisionedProductPlanStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProvisionedProductPlanStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisionedProductPlanStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ProvisionedProductPlanStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProvisionedProductPlanStatus::from(value))
+    }
 }
 
 /// <p>Summary information about a plan.</p>
@@ -2409,7 +7133,7 @@ pub struct ProvisionedProductPlanSummary {
     /// <p>The plan type.</p>
     #[serde(rename = "PlanType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub plan_type: Option<String>,
+    pub plan_type: Option<ProvisionedProductPlanType>,
     /// <p>The product identifier.</p>
     #[serde(rename = "ProvisionProductId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2424,14 +7148,239 @@ pub struct ProvisionedProductPlanSummary {
     pub provisioning_artifact_id: Option<String>,
 }
 
+/// <p>The plan type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProvisionedProductPlanType {
+    /// <p><code>CLOUDFORMATION</code></p>
+    Cloudformation,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProvisionedProductPlanType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisionedProductPlanType::Cloudformation => "CLOUDFORMATION",
+            ProvisionedProductPlanType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProvisionedProductPlanType {
+    fn from(value: &'a str) -> ProvisionedProductPlanType {
+        match value {
+            "CLOUDFORMATION" => ProvisionedProductPlanType::Cloudformation,
+            _ => ProvisionedProductPlanType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisionedProductPlanType {
+    fn from(value: String) -> ProvisionedProductPlanType {
+        ProvisionedProductPlanType::from(value.as_str())
+    }
+}
+
+impl From<ProvisionedProductPlanType> for String {
+    fn from(value: ProvisionedProductPlanType) -> String {
+        match value {
+            ProvisionedProductPlanType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProvisionedProductPlanType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProvisionedProductPlanType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisionedProductPlanType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ProvisionedProductPlanType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProvisionedProductPlanType::from(value))
+    }
+}
+
+/// <p><p>The current status of the provisioned product.</p> <ul> <li> <p> <code>AVAILABLE</code> - Stable state, ready to perform any operation. The most recent operation succeeded and completed.</p> </li> <li> <p> <code>UNDER<em>CHANGE</code> - Transitive state. Operations performed might not have valid results. Wait for an <code>AVAILABLE</code> status before performing operations.</p> </li> <li> <p> <code>TAINTED</code> - Stable state, ready to perform any operation. The stack has completed the requested operation but is not exactly what was requested. For example, a request to update to a new version failed and the stack rolled back to the current version.</p> </li> <li> <p> <code>ERROR</code> - An unexpected error occurred. The provisioned product exists but the stack is not running. For example, CloudFormation received a parameter value that was not valid and could not launch the stack.</p> </li> <li> <p> <code>PLAN</em>IN_PROGRESS</code> - Transitive state. The plan operations were performed to provision a new product, but resources have not yet been created. After reviewing the list of resources to be created, execute the plan. Wait for an <code>AVAILABLE</code> status before performing operations.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProvisionedProductStatus {
+    /// <p><code>AVAILABLE</code> - Stable state, ready to perform any operation. The most recent operation succeeded and completed.</p>
+    Available,
+    /// <p><code>UNDER_CHANGE</code></p>
+    UnderChange,
+    /// <p><code>TAINTED</code> - Stable state, ready to perform any operation. The stack has completed the requested operation but is not exactly what was requested. For example, a request to update to a new version failed and the stack rolled back to the current version.</p>
+    Tainted,
+    /// <p><code>ERROR</code> - An unexpected error occurred. The provisioned product exists but the stack is not running. For example, CloudFormation received a parameter value that was not valid and could not launch the stack.</p>
+    Error,
+    /// <p><code>PLAN_IN_PROGRESS</code></p>
+    PlanInProgress,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProvisionedProductStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisionedProductStatus::Available => "AVAILABLE",
+            ProvisionedProductStatus::UnderChange => "UNDER_CHANGE",
+            ProvisionedProductStatus::Tainted => "TAINTED",
+            ProvisionedProductStatus::Error => "ERROR",
+            ProvisionedProductStatus::PlanInProgress => "PLAN_IN_PROGRESS",
+            ProvisionedProductStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProvisionedProductStatus {
+    fn from(value: &'a str) -> ProvisionedProductStatus {
+        match value {
+            "AVAILABLE" => ProvisionedProductStatus::Available,
+            "UNDER_CHANGE" => ProvisionedProductStatus::UnderChange,
+            "TAINTED" => ProvisionedProductStatus::Tainted,
+            "ERROR" => ProvisionedProductStatus::Error,
+            "PLAN_IN_PROGRESS" => ProvisionedProductStatus::PlanInProgress,
+            _ => ProvisionedProductStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisionedProductStatus {
+    fn from(value: String) -> ProvisionedProductStatus {
+        ProvisionedProductStatus::from(value.as_str())
+    }
+}
+
+impl From<ProvisionedProductStatus> for String {
+    fn from(value: ProvisionedProductStatus) -> String {
+        match value {
+            ProvisionedProductStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProvisionedProductStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProvisionedProductStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisionedProductStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProvisionedProductStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProvisionedProductStatus::from(value))
+    }
+}
+
+/// <p>The search filters.</p> <p>When the key is <code>SearchQuery</code>, the searchable fields are <code>arn</code>, <code>createdTime</code>, <code>id</code>, <code>lastRecordId</code>, <code>idempotencyToken</code>, <code>name</code>, <code>physicalId</code>, <code>productId</code>, <code>provisioningArtifact</code>, <code>type</code>, <code>status</code>, <code>tags</code>, <code>userArn</code>, and <code>userArnSession</code>.</p> <p>Example: <code>"SearchQuery":["status:AVAILABLE"]</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProvisionedProductViewFilterBy {
+    /// <p><code>SearchQuery</code></p>
+    SearchQuery,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProvisionedProductViewFilterBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisionedProductViewFilterBy::SearchQuery => "SearchQuery",
+            ProvisionedProductViewFilterBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProvisionedProductViewFilterBy {
+    fn from(value: &'a str) -> ProvisionedProductViewFilterBy {
+        match value {
+            "SearchQuery" => ProvisionedProductViewFilterBy::SearchQuery,
+            _ => ProvisionedProductViewFilterBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisionedProductViewFilterBy {
+    fn from(value: String) -> ProvisionedProductViewFilterBy {
+        ProvisionedProductViewFilterBy::from(value.as_str())
+    }
+}
+
+impl From<ProvisionedProductViewFilterBy> for String {
+    fn from(value: ProvisionedProductViewFilterBy) -> String {
+        match value {
+            ProvisionedProductViewFilterBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProvisionedProductViewFilterBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProvisionedProductViewFilterBy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisionedProductViewFilterBy {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ProvisionedProductViewFilterBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProvisionedProductViewFilterBy::from(value))
+    }
+}
+
 /// <p>Information about a provisioning artifact. A provisioning artifact is also known as a product version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ProvisioningArtifact {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The description of the provisioning artifact.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2439,7 +7388,7 @@ pub struct ProvisioningArtifact {
     /// <p>Information set by the administrator to provide guidance to end users about which provisioning artifacts to use.</p>
     #[serde(rename = "Guidance")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub guidance: Option<String>,
+    pub guidance: Option<ProvisioningArtifactGuidance>,
     /// <p>The identifier of the provisioning artifact.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2460,8 +7409,9 @@ pub struct ProvisioningArtifactDetail {
     pub active: Option<bool>,
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The description of the provisioning artifact.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2469,7 +7419,7 @@ pub struct ProvisioningArtifactDetail {
     /// <p>Information set by the administrator to provide guidance to end users about which provisioning artifacts to use.</p>
     #[serde(rename = "Guidance")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub guidance: Option<String>,
+    pub guidance: Option<ProvisioningArtifactGuidance>,
     /// <p>The identifier of the provisioning artifact.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2481,7 +7431,81 @@ pub struct ProvisioningArtifactDetail {
     /// <p><p>The type of provisioning artifact.</p> <ul> <li> <p> <code>CLOUD<em>FORMATION</em>TEMPLATE</code> - AWS CloudFormation template</p> </li> <li> <p> <code>MARKETPLACE<em>AMI</code> - AWS Marketplace AMI</p> </li> <li> <p> <code>MARKETPLACE</em>CAR</code> - AWS Marketplace Clusters and AWS Resources</p> </li> </ul></p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ProvisioningArtifactType>,
+}
+
+/// <p>Information set by the administrator to provide guidance to end users about which provisioning artifacts to use.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProvisioningArtifactGuidance {
+    /// <p><code>DEFAULT</code></p>
+    Default,
+    /// <p><code>DEPRECATED</code></p>
+    Deprecated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProvisioningArtifactGuidance {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisioningArtifactGuidance::Default => "DEFAULT",
+            ProvisioningArtifactGuidance::Deprecated => "DEPRECATED",
+            ProvisioningArtifactGuidance::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProvisioningArtifactGuidance {
+    fn from(value: &'a str) -> ProvisioningArtifactGuidance {
+        match value {
+            "DEFAULT" => ProvisioningArtifactGuidance::Default,
+            "DEPRECATED" => ProvisioningArtifactGuidance::Deprecated,
+            _ => ProvisioningArtifactGuidance::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisioningArtifactGuidance {
+    fn from(value: String) -> ProvisioningArtifactGuidance {
+        ProvisioningArtifactGuidance::from(value.as_str())
+    }
+}
+
+impl From<ProvisioningArtifactGuidance> for String {
+    fn from(value: ProvisioningArtifactGuidance) -> String {
+        match value {
+            ProvisioningArtifactGuidance::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProvisioningArtifactGuidance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProvisioningArtifactGuidance {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisioningArtifactGuidance {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ProvisioningArtifactGuidance, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProvisioningArtifactGuidance::from(value))
+    }
 }
 
 /// <p>Information about a parameter used to provision a product.</p>
@@ -2549,22 +7573,92 @@ pub struct ProvisioningArtifactProperties {
     /// <p><p>The type of provisioning artifact.</p> <ul> <li> <p> <code>CLOUD<em>FORMATION</em>TEMPLATE</code> - AWS CloudFormation template</p> </li> <li> <p> <code>MARKETPLACE<em>AMI</code> - AWS Marketplace AMI</p> </li> <li> <p> <code>MARKETPLACE</em>CAR</code> - AWS Marketplace Clusters and AWS Resources</p> </li> </ul></p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ProvisioningArtifactType>,
 }
 
-/// <p>Summary information about a provisioning artifact (also known as a version) for a product.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ProvisioningArtifactSummary {
-    /// <p>The UTC time stamp of the creation time.</p>
-    #[serde(rename = "CreatedTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
-    /// <p>The description of the provisioning artifact.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The identifier of the provisioning artifact.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProvisioningArtifactPropertyName {
+    /// <p><code>Id</code></p>
+    Id,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProvisioningArtifactPropertyName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisioningArtifactPropertyName::Id => "Id",
+            ProvisioningArtifactPropertyName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProvisioningArtifactPropertyName {
+    fn from(value: &'a str) -> ProvisioningArtifactPropertyName {
+        match value {
+            "Id" => ProvisioningArtifactPropertyName::Id,
+            _ => ProvisioningArtifactPropertyName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisioningArtifactPropertyName {
+    fn from(value: String) -> ProvisioningArtifactPropertyName {
+        ProvisioningArtifactPropertyName::from(value.as_str())
+    }
+}
+
+impl From<ProvisioningArtifactPropertyName> for String {
+    fn from(value: ProvisioningArtifactPropertyName) -> String {
+        match value {
+            ProvisioningArtifactPropertyName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProvisioningArtifactPropertyName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProvisioningArtifactPropertyName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisioningArtifactPropertyName {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ProvisioningArtifactPropertyName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProvisioningArtifactPropertyName::from(value))
+    }
+}
+
+/// <p>Summary information about a provisioning artifact (also known as a version) for a product.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ProvisioningArtifactSummary {
+    /// <p>The UTC time stamp of the creation time.</p>
+    #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<EpochTimestamp>,
+    /// <p>The description of the provisioning artifact.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The identifier of the provisioning artifact.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
@@ -2578,6 +7672,94 @@ pub struct ProvisioningArtifactSummary {
     pub provisioning_artifact_metadata: Option<::std::collections::HashMap<String, String>>,
 }
 
+/// <p><p>The type of provisioning artifact.</p> <ul> <li> <p> <code>CLOUD<em>FORMATION</em>TEMPLATE</code> - AWS CloudFormation template</p> </li> <li> <p> <code>MARKETPLACE<em>AMI</code> - AWS Marketplace AMI</p> </li> <li> <p> <code>MARKETPLACE</em>CAR</code> - AWS Marketplace Clusters and AWS Resources</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProvisioningArtifactType {
+    /// <p><code>CLOUD_FORMATION_TEMPLATE</code></p>
+    CloudFormationTemplate,
+    /// <p><code>MARKETPLACE_AMI</code></p>
+    MarketplaceAmi,
+    /// <p><code>MARKETPLACE_CAR</code></p>
+    MarketplaceCar,
+    /// <p><code>TERRAFORM_OPEN_SOURCE</code></p>
+    TerraformOpenSource,
+    /// <p><code>TERRAFORM_CLOUD</code></p>
+    TerraformCloud,
+    /// <p><code>EXTERNAL</code></p>
+    External,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProvisioningArtifactType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisioningArtifactType::CloudFormationTemplate => "CLOUD_FORMATION_TEMPLATE",
+            ProvisioningArtifactType::MarketplaceAmi => "MARKETPLACE_AMI",
+            ProvisioningArtifactType::MarketplaceCar => "MARKETPLACE_CAR",
+            ProvisioningArtifactType::TerraformOpenSource => "TERRAFORM_OPEN_SOURCE",
+            ProvisioningArtifactType::TerraformCloud => "TERRAFORM_CLOUD",
+            ProvisioningArtifactType::External => "EXTERNAL",
+            ProvisioningArtifactType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProvisioningArtifactType {
+    fn from(value: &'a str) -> ProvisioningArtifactType {
+        match value {
+            "CLOUD_FORMATION_TEMPLATE" => ProvisioningArtifactType::CloudFormationTemplate,
+            "MARKETPLACE_AMI" => ProvisioningArtifactType::MarketplaceAmi,
+            "MARKETPLACE_CAR" => ProvisioningArtifactType::MarketplaceCar,
+            "TERRAFORM_OPEN_SOURCE" => ProvisioningArtifactType::TerraformOpenSource,
+            "TERRAFORM_CLOUD" => ProvisioningArtifactType::TerraformCloud,
+            "EXTERNAL" => ProvisioningArtifactType::External,
+            _ => ProvisioningArtifactType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisioningArtifactType {
+    fn from(value: String) -> ProvisioningArtifactType {
+        ProvisioningArtifactType::from(value.as_str())
+    }
+}
+
+impl From<ProvisioningArtifactType> for String {
+    fn from(value: ProvisioningArtifactType) -> String {
+        match value {
+            ProvisioningArtifactType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProvisioningArtifactType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProvisioningArtifactType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisioningArtifactType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProvisioningArtifactType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProvisioningArtifactType::from(value))
+    }
+}
+
 /// <p>An object that contains summary information about a product view and a provisioning artifact.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2640,8 +7822,9 @@ pub struct ProvisioningPreferences {
 pub struct RecordDetail {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The path identifier.</p>
     #[serde(rename = "PathId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2685,11 +7868,12 @@ pub struct RecordDetail {
     /// <p><p>The status of the provisioned product.</p> <ul> <li> <p> <code>CREATED</code> - The request was created but the operation has not started.</p> </li> <li> <p> <code>IN<em>PROGRESS</code> - The requested operation is in progress.</p> </li> <li> <p> <code>IN</em>PROGRESS<em>IN</em>ERROR</code> - The provisioned product is under change but the requested operation failed and some remediation is occurring. For example, a rollback.</p> </li> <li> <p> <code>SUCCEEDED</code> - The requested operation has successfully completed.</p> </li> <li> <p> <code>FAILED</code> - The requested operation has unsuccessfully completed. Investigate using the error messages returned.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RecordStatus>,
     /// <p>The time when the record was last updated.</p>
     #[serde(rename = "UpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_time: Option<f64>,
+    pub updated_time: Option<EpochTimestamp>,
 }
 
 /// <p>The error code and description resulting from an operation.</p>
@@ -2724,6 +7908,90 @@ pub struct RecordOutput {
     pub output_value: Option<String>,
 }
 
+/// <p><p>The status of the provisioned product.</p> <ul> <li> <p> <code>CREATED</code> - The request was created but the operation has not started.</p> </li> <li> <p> <code>IN<em>PROGRESS</code> - The requested operation is in progress.</p> </li> <li> <p> <code>IN</em>PROGRESS<em>IN</em>ERROR</code> - The provisioned product is under change but the requested operation failed and some remediation is occurring. For example, a rollback.</p> </li> <li> <p> <code>SUCCEEDED</code> - The requested operation has successfully completed.</p> </li> <li> <p> <code>FAILED</code> - The requested operation has unsuccessfully completed. Investigate using the error messages returned.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordStatus {
+    /// <p><code>CREATED</code> - The request was created but the operation has not started.</p>
+    Created,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>IN_PROGRESS_IN_ERROR</code></p>
+    InProgressInError,
+    /// <p><code>SUCCEEDED</code> - The requested operation has successfully completed.</p>
+    Succeeded,
+    /// <p><code>FAILED</code> - The requested operation has unsuccessfully completed. Investigate using the error messages returned.</p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RecordStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordStatus::Created => "CREATED",
+            RecordStatus::InProgress => "IN_PROGRESS",
+            RecordStatus::InProgressInError => "IN_PROGRESS_IN_ERROR",
+            RecordStatus::Succeeded => "SUCCEEDED",
+            RecordStatus::Failed => "FAILED",
+            RecordStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RecordStatus {
+    fn from(value: &'a str) -> RecordStatus {
+        match value {
+            "CREATED" => RecordStatus::Created,
+            "IN_PROGRESS" => RecordStatus::InProgress,
+            "IN_PROGRESS_IN_ERROR" => RecordStatus::InProgressInError,
+            "SUCCEEDED" => RecordStatus::Succeeded,
+            "FAILED" => RecordStatus::Failed,
+            _ => RecordStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordStatus {
+    fn from(value: String) -> RecordStatus {
+        RecordStatus::from(value.as_str())
+    }
+}
+
+impl From<RecordStatus> for String {
+    fn from(value: RecordStatus) -> String {
+        match value {
+            RecordStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RecordStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RecordStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RecordStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RecordStatus::from(value))
+    }
+}
+
 /// <p>Information about a tag, which is a key-value pair.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2750,13 +8018,301 @@ pub struct RejectPortfolioShareInput {
     /// <p>The type of shared portfolios to reject. The default is to reject imported portfolios.</p> <ul> <li> <p> <code>AWS_ORGANIZATIONS</code> - Reject portfolios shared by the master account of your organization.</p> </li> <li> <p> <code>IMPORTED</code> - Reject imported portfolios.</p> </li> <li> <p> <code>AWS_SERVICECATALOG</code> - Not supported. (Throws ResourceNotFoundException.)</p> </li> </ul> <p>For example, <code>aws servicecatalog reject-portfolio-share --portfolio-id "port-2qwzkwxt3y5fk" --portfolio-share-type AWS_ORGANIZATIONS</code> </p>
     #[serde(rename = "PortfolioShareType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub portfolio_share_type: Option<String>,
+    pub portfolio_share_type: Option<PortfolioShareType>,
+}
+
+impl RejectPortfolioShareInput {
+    /// Returns a builder for `RejectPortfolioShareInput`, checking its required members when built.
+    pub fn builder() -> RejectPortfolioShareInputBuilder {
+        RejectPortfolioShareInputBuilder::default()
+    }
+}
+
+/// Builds a [`RejectPortfolioShareInput`](struct.RejectPortfolioShareInput.html).
+#[derive(Default, Debug)]
+pub struct RejectPortfolioShareInputBuilder {
+    accept_language: Option<String>,
+    portfolio_id: Option<String>,
+    portfolio_share_type: Option<PortfolioShareType>,
+}
+
+impl RejectPortfolioShareInputBuilder {
+    /// Sets the [`accept_language`](struct.RejectPortfolioShareInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.RejectPortfolioShareInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_share_type`](struct.RejectPortfolioShareInput.html#structfield.portfolio_share_type) member.
+    pub fn portfolio_share_type<T: Into<PortfolioShareType>>(mut self, value: T) -> Self {
+        self.portfolio_share_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `RejectPortfolioShareInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RejectPortfolioShareInput, ::rusoto_core::BuildError> {
+        Ok(RejectPortfolioShareInput {
+            accept_language: self.accept_language,
+            portfolio_id: self
+                .portfolio_id
+                .ok_or(::rusoto_core::BuildError::missing("portfolio_id"))?,
+            portfolio_share_type: self.portfolio_share_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RejectPortfolioShareOutput {}
 
+/// <p>If the change type is <code>Modify</code>, indicates whether the existing resource is deleted and replaced with a new one.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Replacement {
+    /// <p><code>TRUE</code></p>
+    True,
+    /// <p><code>FALSE</code></p>
+    False,
+    /// <p><code>CONDITIONAL</code></p>
+    Conditional,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Replacement {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Replacement::True => "TRUE",
+            Replacement::False => "FALSE",
+            Replacement::Conditional => "CONDITIONAL",
+            Replacement::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Replacement {
+    fn from(value: &'a str) -> Replacement {
+        match value {
+            "TRUE" => Replacement::True,
+            "FALSE" => Replacement::False,
+            "CONDITIONAL" => Replacement::Conditional,
+            _ => Replacement::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Replacement {
+    fn from(value: String) -> Replacement {
+        Replacement::from(value.as_str())
+    }
+}
+
+impl From<Replacement> for String {
+    fn from(value: Replacement) -> String {
+        match value {
+            Replacement::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Replacement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Replacement {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Replacement {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Replacement, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Replacement::from(value))
+    }
+}
+
+/// <p>If the attribute is <code>Properties</code>, indicates whether a change to this property causes the resource to be re-created.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RequiresRecreation {
+    /// <p><code>NEVER</code></p>
+    Never,
+    /// <p><code>CONDITIONALLY</code></p>
+    Conditionally,
+    /// <p><code>ALWAYS</code></p>
+    Always,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RequiresRecreation {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequiresRecreation::Never => "NEVER",
+            RequiresRecreation::Conditionally => "CONDITIONALLY",
+            RequiresRecreation::Always => "ALWAYS",
+            RequiresRecreation::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RequiresRecreation {
+    fn from(value: &'a str) -> RequiresRecreation {
+        match value {
+            "NEVER" => RequiresRecreation::Never,
+            "CONDITIONALLY" => RequiresRecreation::Conditionally,
+            "ALWAYS" => RequiresRecreation::Always,
+            _ => RequiresRecreation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequiresRecreation {
+    fn from(value: String) -> RequiresRecreation {
+        RequiresRecreation::from(value.as_str())
+    }
+}
+
+impl From<RequiresRecreation> for String {
+    fn from(value: RequiresRecreation) -> String {
+        match value {
+            RequiresRecreation::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RequiresRecreation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RequiresRecreation {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RequiresRecreation {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RequiresRecreation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RequiresRecreation::from(value))
+    }
+}
+
+/// <p>The change scope.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceAttribute {
+    /// <p><code>PROPERTIES</code></p>
+    Properties,
+    /// <p><code>METADATA</code></p>
+    Metadata,
+    /// <p><code>CREATIONPOLICY</code></p>
+    Creationpolicy,
+    /// <p><code>UPDATEPOLICY</code></p>
+    Updatepolicy,
+    /// <p><code>DELETIONPOLICY</code></p>
+    Deletionpolicy,
+    /// <p><code>TAGS</code></p>
+    Tags,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceAttribute {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceAttribute::Properties => "PROPERTIES",
+            ResourceAttribute::Metadata => "METADATA",
+            ResourceAttribute::Creationpolicy => "CREATIONPOLICY",
+            ResourceAttribute::Updatepolicy => "UPDATEPOLICY",
+            ResourceAttribute::Deletionpolicy => "DELETIONPOLICY",
+            ResourceAttribute::Tags => "TAGS",
+            ResourceAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceAttribute {
+    fn from(value: &'a str) -> ResourceAttribute {
+        match value {
+            "PROPERTIES" => ResourceAttribute::Properties,
+            "METADATA" => ResourceAttribute::Metadata,
+            "CREATIONPOLICY" => ResourceAttribute::Creationpolicy,
+            "UPDATEPOLICY" => ResourceAttribute::Updatepolicy,
+            "DELETIONPOLICY" => ResourceAttribute::Deletionpolicy,
+            "TAGS" => ResourceAttribute::Tags,
+            _ => ResourceAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceAttribute {
+    fn from(value: String) -> ResourceAttribute {
+        ResourceAttribute::from(value.as_str())
+    }
+}
+
+impl From<ResourceAttribute> for String {
+    fn from(value: ResourceAttribute) -> String {
+        match value {
+            ResourceAttribute::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ResourceAttribute {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceAttribute {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceAttribute::from(value))
+    }
+}
+
 /// <p>Information about a resource change that will occur when a plan is executed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2764,7 +8320,7 @@ pub struct ResourceChange {
     /// <p>The change action.</p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<ChangeAction>,
     /// <p>Information about the resource changes.</p>
     #[serde(rename = "Details")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2780,7 +8336,7 @@ pub struct ResourceChange {
     /// <p>If the change type is <code>Modify</code>, indicates whether the existing resource is deleted and replaced with a new one.</p>
     #[serde(rename = "Replacement")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replacement: Option<String>,
+    pub replacement: Option<Replacement>,
     /// <p>The type of resource.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2788,7 +8344,7 @@ pub struct ResourceChange {
     /// <p>The change scope.</p>
     #[serde(rename = "Scope")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scope: Option<Vec<String>>,
+    pub scope: Option<Vec<ResourceAttribute>>,
 }
 
 /// <p>Information about a change to a resource attribute.</p>
@@ -2802,7 +8358,7 @@ pub struct ResourceChangeDetail {
     /// <p>For static evaluations, the value of the resource attribute will change and the new value is known. For dynamic evaluations, the value might change, and any new value will be determined when the plan is updated.</p>
     #[serde(rename = "Evaluation")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub evaluation: Option<String>,
+    pub evaluation: Option<EvaluationType>,
     /// <p>Information about the resource attribute to be modified.</p>
     #[serde(rename = "Target")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2819,8 +8375,9 @@ pub struct ResourceDetail {
     pub arn: Option<String>,
     /// <p>The creation time of the resource.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The description of the resource.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2842,7 +8399,7 @@ pub struct ResourceTargetDefinition {
     /// <p>The attribute to be changed.</p>
     #[serde(rename = "Attribute")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute: Option<String>,
+    pub attribute: Option<ResourceAttribute>,
     /// <p>If the attribute is <code>Properties</code>, the value is the name of the property. Otherwise, the value is null.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2850,7 +8407,7 @@ pub struct ResourceTargetDefinition {
     /// <p>If the attribute is <code>Properties</code>, indicates whether a change to this property causes the resource to be re-created.</p>
     #[serde(rename = "RequiresRecreation")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requires_recreation: Option<String>,
+    pub requires_recreation: Option<RequiresRecreation>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2873,6 +8430,60 @@ pub struct ScanProvisionedProductsInput {
     pub page_token: Option<String>,
 }
 
+impl ScanProvisionedProductsInput {
+    /// Returns a builder for `ScanProvisionedProductsInput`, checking its required members when built.
+    pub fn builder() -> ScanProvisionedProductsInputBuilder {
+        ScanProvisionedProductsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ScanProvisionedProductsInput`](struct.ScanProvisionedProductsInput.html).
+#[derive(Default, Debug)]
+pub struct ScanProvisionedProductsInputBuilder {
+    accept_language: Option<String>,
+    access_level_filter: Option<AccessLevelFilter>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+}
+
+impl ScanProvisionedProductsInputBuilder {
+    /// Sets the [`accept_language`](struct.ScanProvisionedProductsInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`access_level_filter`](struct.ScanProvisionedProductsInput.html#structfield.access_level_filter) member.
+    pub fn access_level_filter<T: Into<AccessLevelFilter>>(mut self, value: T) -> Self {
+        self.access_level_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ScanProvisionedProductsInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ScanProvisionedProductsInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ScanProvisionedProductsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ScanProvisionedProductsInput, ::rusoto_core::BuildError> {
+        Ok(ScanProvisionedProductsInput {
+            accept_language: self.accept_language,
+            access_level_filter: self.access_level_filter,
+            page_size: self.page_size,
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ScanProvisionedProductsOutput {
@@ -2895,7 +8506,7 @@ pub struct SearchProductsAsAdminInput {
     /// <p>The search filters. If no search filters are specified, the output includes all products to which the administrator has access.</p>
     #[serde(rename = "Filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filters: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub filters: Option<::std::collections::HashMap<ProductViewFilterBy, Vec<String>>>,
     /// <p>The maximum number of items to return with this call.</p>
     #[serde(rename = "PageSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2911,15 +8522,104 @@ pub struct SearchProductsAsAdminInput {
     /// <p>Access level of the source of the product.</p>
     #[serde(rename = "ProductSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_source: Option<String>,
+    pub product_source: Option<ProductSource>,
     /// <p>The sort field. If no value is specified, the results are not sorted.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<ProductViewSortBy>,
     /// <p>The sort order. If no value is specified, the results are not sorted.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
+}
+
+impl SearchProductsAsAdminInput {
+    /// Returns a builder for `SearchProductsAsAdminInput`, checking its required members when built.
+    pub fn builder() -> SearchProductsAsAdminInputBuilder {
+        SearchProductsAsAdminInputBuilder::default()
+    }
+}
+
+/// Builds a [`SearchProductsAsAdminInput`](struct.SearchProductsAsAdminInput.html).
+#[derive(Default, Debug)]
+pub struct SearchProductsAsAdminInputBuilder {
+    accept_language: Option<String>,
+    filters: Option<::std::collections::HashMap<ProductViewFilterBy, Vec<String>>>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    portfolio_id: Option<String>,
+    product_source: Option<ProductSource>,
+    sort_by: Option<ProductViewSortBy>,
+    sort_order: Option<SortOrder>,
+}
+
+impl SearchProductsAsAdminInputBuilder {
+    /// Sets the [`accept_language`](struct.SearchProductsAsAdminInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.SearchProductsAsAdminInput.html#structfield.filters) member.
+    pub fn filters<T: Into<::std::collections::HashMap<ProductViewFilterBy, Vec<String>>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.SearchProductsAsAdminInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.SearchProductsAsAdminInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portfolio_id`](struct.SearchProductsAsAdminInput.html#structfield.portfolio_id) member.
+    pub fn portfolio_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.portfolio_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_source`](struct.SearchProductsAsAdminInput.html#structfield.product_source) member.
+    pub fn product_source<T: Into<ProductSource>>(mut self, value: T) -> Self {
+        self.product_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_by`](struct.SearchProductsAsAdminInput.html#structfield.sort_by) member.
+    pub fn sort_by<T: Into<ProductViewSortBy>>(mut self, value: T) -> Self {
+        self.sort_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_order`](struct.SearchProductsAsAdminInput.html#structfield.sort_order) member.
+    pub fn sort_order<T: Into<SortOrder>>(mut self, value: T) -> Self {
+        self.sort_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchProductsAsAdminInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SearchProductsAsAdminInput, ::rusoto_core::BuildError> {
+        Ok(SearchProductsAsAdminInput {
+            accept_language: self.accept_language,
+            filters: self.filters,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            portfolio_id: self.portfolio_id,
+            product_source: self.product_source,
+            sort_by: self.sort_by,
+            sort_order: self.sort_order,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2944,7 +8644,7 @@ pub struct SearchProductsInput {
     /// <p>The search filters. If no search filters are specified, the output includes all products to which the caller has access.</p>
     #[serde(rename = "Filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filters: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub filters: Option<::std::collections::HashMap<ProductViewFilterBy, Vec<String>>>,
     /// <p>The maximum number of items to return with this call.</p>
     #[serde(rename = "PageSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2956,17 +8656,88 @@ pub struct SearchProductsInput {
     /// <p>The sort field. If no value is specified, the results are not sorted.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<ProductViewSortBy>,
     /// <p>The sort order. If no value is specified, the results are not sorted.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct SearchProductsOutput {
-    /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
+impl SearchProductsInput {
+    /// Returns a builder for `SearchProductsInput`, checking its required members when built.
+    pub fn builder() -> SearchProductsInputBuilder {
+        SearchProductsInputBuilder::default()
+    }
+}
+
+/// Builds a [`SearchProductsInput`](struct.SearchProductsInput.html).
+#[derive(Default, Debug)]
+pub struct SearchProductsInputBuilder {
+    accept_language: Option<String>,
+    filters: Option<::std::collections::HashMap<ProductViewFilterBy, Vec<String>>>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    sort_by: Option<ProductViewSortBy>,
+    sort_order: Option<SortOrder>,
+}
+
+impl SearchProductsInputBuilder {
+    /// Sets the [`accept_language`](struct.SearchProductsInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.SearchProductsInput.html#structfield.filters) member.
+    pub fn filters<T: Into<::std::collections::HashMap<ProductViewFilterBy, Vec<String>>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.SearchProductsInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.SearchProductsInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_by`](struct.SearchProductsInput.html#structfield.sort_by) member.
+    pub fn sort_by<T: Into<ProductViewSortBy>>(mut self, value: T) -> Self {
+        self.sort_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_order`](struct.SearchProductsInput.html#structfield.sort_order) member.
+    pub fn sort_order<T: Into<SortOrder>>(mut self, value: T) -> Self {
+        self.sort_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchProductsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SearchProductsInput, ::rusoto_core::BuildError> {
+        Ok(SearchProductsInput {
+            accept_language: self.accept_language,
+            filters: self.filters,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            sort_by: self.sort_by,
+            sort_order: self.sort_order,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct SearchProductsOutput {
+    /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_page_token: Option<String>,
@@ -2994,7 +8765,7 @@ pub struct SearchProvisionedProductsInput {
     /// <p>The search filters.</p> <p>When the key is <code>SearchQuery</code>, the searchable fields are <code>arn</code>, <code>createdTime</code>, <code>id</code>, <code>lastRecordId</code>, <code>idempotencyToken</code>, <code>name</code>, <code>physicalId</code>, <code>productId</code>, <code>provisioningArtifact</code>, <code>type</code>, <code>status</code>, <code>tags</code>, <code>userArn</code>, and <code>userArnSession</code>.</p> <p>Example: <code>"SearchQuery":["status:AVAILABLE"]</code> </p>
     #[serde(rename = "Filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filters: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub filters: Option<::std::collections::HashMap<ProvisionedProductViewFilterBy, Vec<String>>>,
     /// <p>The maximum number of items to return with this call.</p>
     #[serde(rename = "PageSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3010,7 +8781,90 @@ pub struct SearchProvisionedProductsInput {
     /// <p>The sort order. If no value is specified, the results are not sorted.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
+}
+
+impl SearchProvisionedProductsInput {
+    /// Returns a builder for `SearchProvisionedProductsInput`, checking its required members when built.
+    pub fn builder() -> SearchProvisionedProductsInputBuilder {
+        SearchProvisionedProductsInputBuilder::default()
+    }
+}
+
+/// Builds a [`SearchProvisionedProductsInput`](struct.SearchProvisionedProductsInput.html).
+#[derive(Default, Debug)]
+pub struct SearchProvisionedProductsInputBuilder {
+    accept_language: Option<String>,
+    access_level_filter: Option<AccessLevelFilter>,
+    filters: Option<::std::collections::HashMap<ProvisionedProductViewFilterBy, Vec<String>>>,
+    page_size: Option<i64>,
+    page_token: Option<String>,
+    sort_by: Option<String>,
+    sort_order: Option<SortOrder>,
+}
+
+impl SearchProvisionedProductsInputBuilder {
+    /// Sets the [`accept_language`](struct.SearchProvisionedProductsInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`access_level_filter`](struct.SearchProvisionedProductsInput.html#structfield.access_level_filter) member.
+    pub fn access_level_filter<T: Into<AccessLevelFilter>>(mut self, value: T) -> Self {
+        self.access_level_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.SearchProvisionedProductsInput.html#structfield.filters) member.
+    pub fn filters<
+        T: Into<::std::collections::HashMap<ProvisionedProductViewFilterBy, Vec<String>>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.SearchProvisionedProductsInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.SearchProvisionedProductsInput.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_by`](struct.SearchProvisionedProductsInput.html#structfield.sort_by) member.
+    pub fn sort_by<T: Into<String>>(mut self, value: T) -> Self {
+        self.sort_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_order`](struct.SearchProvisionedProductsInput.html#structfield.sort_order) member.
+    pub fn sort_order<T: Into<SortOrder>>(mut self, value: T) -> Self {
+        self.sort_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchProvisionedProductsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SearchProvisionedProductsInput, ::rusoto_core::BuildError> {
+        Ok(SearchProvisionedProductsInput {
+            accept_language: self.accept_language,
+            access_level_filter: self.access_level_filter,
+            filters: self.filters,
+            page_size: self.page_size,
+            page_token: self.page_token,
+            sort_by: self.sort_by,
+            sort_order: self.sort_order,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3044,6 +8898,248 @@ pub struct ServiceActionAssociation {
     pub service_action_id: String,
 }
 
+/// <p>The error code. Valid values are listed below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceActionAssociationErrorCode {
+    /// <p><code>DUPLICATE_RESOURCE</code></p>
+    DuplicateResource,
+    /// <p><code>INTERNAL_FAILURE</code></p>
+    InternalFailure,
+    /// <p><code>LIMIT_EXCEEDED</code></p>
+    LimitExceeded,
+    /// <p><code>RESOURCE_NOT_FOUND</code></p>
+    ResourceNotFound,
+    /// <p><code>THROTTLING</code></p>
+    Throttling,
+    /// <p><code>INVALID_PARAMETER</code></p>
+    InvalidParameter,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServiceActionAssociationErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceActionAssociationErrorCode::DuplicateResource => "DUPLICATE_RESOURCE",
+            ServiceActionAssociationErrorCode::InternalFailure => "INTERNAL_FAILURE",
+            ServiceActionAssociationErrorCode::LimitExceeded => "LIMIT_EXCEEDED",
+            ServiceActionAssociationErrorCode::ResourceNotFound => "RESOURCE_NOT_FOUND",
+            ServiceActionAssociationErrorCode::Throttling => "THROTTLING",
+            ServiceActionAssociationErrorCode::InvalidParameter => "INVALID_PARAMETER",
+            ServiceActionAssociationErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServiceActionAssociationErrorCode {
+    fn from(value: &'a str) -> ServiceActionAssociationErrorCode {
+        match value {
+            "DUPLICATE_RESOURCE" => ServiceActionAssociationErrorCode::DuplicateResource,
+            "INTERNAL_FAILURE" => ServiceActionAssociationErrorCode::InternalFailure,
+            "LIMIT_EXCEEDED" => ServiceActionAssociationErrorCode::LimitExceeded,
+            "RESOURCE_NOT_FOUND" => ServiceActionAssociationErrorCode::ResourceNotFound,
+            "THROTTLING" => ServiceActionAssociationErrorCode::Throttling,
+            "INVALID_PARAMETER" => ServiceActionAssociationErrorCode::InvalidParameter,
+            _ => ServiceActionAssociationErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceActionAssociationErrorCode {
+    fn from(value: String) -> ServiceActionAssociationErrorCode {
+        ServiceActionAssociationErrorCode::from(value.as_str())
+    }
+}
+
+impl From<ServiceActionAssociationErrorCode> for String {
+    fn from(value: ServiceActionAssociationErrorCode) -> String {
+        match value {
+            ServiceActionAssociationErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceActionAssociationErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServiceActionAssociationErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceActionAssociationErrorCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ServiceActionAssociationErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServiceActionAssociationErrorCode::from(value))
+    }
+}
+
+/// <p><p>The self-service action definition. Can be one of the following:</p> <dl> <dt>Name</dt> <dd> <p>The name of the AWS Systems Manager Document. For example, <code>AWS-RestartEC2Instance</code>.</p> </dd> <dt>Version</dt> <dd> <p>The AWS Systems Manager automation document version. For example, <code>&quot;Version&quot;: &quot;1&quot;</code> </p> </dd> <dt>AssumeRole</dt> <dd> <p>The Amazon Resource Name (ARN) of the role that performs the self-service actions on your behalf. For example, <code>&quot;AssumeRole&quot;: &quot;arn:aws:iam::12345678910:role/ActionRole&quot;</code>.</p> <p>To reuse the provisioned product launch role, set to <code>&quot;AssumeRole&quot;: &quot;LAUNCH_ROLE&quot;</code>.</p> </dd> <dt>Parameters</dt> <dd> <p>The list of parameters in JSON format.</p> <p>For example: <code>[{&quot;Name&quot;:&quot;InstanceId&quot;,&quot;Type&quot;:&quot;TARGET&quot;}]</code>.</p> </dd> </dl></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceActionDefinitionKey {
+    /// <p><code>Name</code></p>
+    Name,
+    /// <p><code>Version</code></p>
+    Version,
+    /// <p><code>AssumeRole</code></p>
+    AssumeRole,
+    /// <p><code>Parameters</code></p>
+    Parameters,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServiceActionDefinitionKey {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceActionDefinitionKey::Name => "Name",
+            ServiceActionDefinitionKey::Version => "Version",
+            ServiceActionDefinitionKey::AssumeRole => "AssumeRole",
+            ServiceActionDefinitionKey::Parameters => "Parameters",
+            ServiceActionDefinitionKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServiceActionDefinitionKey {
+    fn from(value: &'a str) -> ServiceActionDefinitionKey {
+        match value {
+            "Name" => ServiceActionDefinitionKey::Name,
+            "Version" => ServiceActionDefinitionKey::Version,
+            "AssumeRole" => ServiceActionDefinitionKey::AssumeRole,
+            "Parameters" => ServiceActionDefinitionKey::Parameters,
+            _ => ServiceActionDefinitionKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceActionDefinitionKey {
+    fn from(value: String) -> ServiceActionDefinitionKey {
+        ServiceActionDefinitionKey::from(value.as_str())
+    }
+}
+
+impl From<ServiceActionDefinitionKey> for String {
+    fn from(value: ServiceActionDefinitionKey) -> String {
+        match value {
+            ServiceActionDefinitionKey::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceActionDefinitionKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServiceActionDefinitionKey {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceActionDefinitionKey {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ServiceActionDefinitionKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServiceActionDefinitionKey::from(value))
+    }
+}
+
+/// <p>The service action definition type. For example, <code>SSM_AUTOMATION</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceActionDefinitionType {
+    /// <p><code>SSM_AUTOMATION</code></p>
+    SsmAutomation,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServiceActionDefinitionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceActionDefinitionType::SsmAutomation => "SSM_AUTOMATION",
+            ServiceActionDefinitionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServiceActionDefinitionType {
+    fn from(value: &'a str) -> ServiceActionDefinitionType {
+        match value {
+            "SSM_AUTOMATION" => ServiceActionDefinitionType::SsmAutomation,
+            _ => ServiceActionDefinitionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceActionDefinitionType {
+    fn from(value: String) -> ServiceActionDefinitionType {
+        ServiceActionDefinitionType::from(value.as_str())
+    }
+}
+
+impl From<ServiceActionDefinitionType> for String {
+    fn from(value: ServiceActionDefinitionType) -> String {
+        match value {
+            ServiceActionDefinitionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceActionDefinitionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServiceActionDefinitionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceActionDefinitionType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ServiceActionDefinitionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServiceActionDefinitionType::from(value))
+    }
+}
+
 /// <p>An object containing detailed information about the self-service action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3051,7 +9147,7 @@ pub struct ServiceActionDetail {
     /// <p>A map that defines the self-service action.</p>
     #[serde(rename = "Definition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub definition: Option<::std::collections::HashMap<String, String>>,
+    pub definition: Option<::std::collections::HashMap<ServiceActionDefinitionKey, String>>,
     /// <p>Summary information about the self-service action.</p>
     #[serde(rename = "ServiceActionSummary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3065,7 +9161,7 @@ pub struct ServiceActionSummary {
     /// <p>The self-service action definition type. For example, <code>SSM_AUTOMATION</code>.</p>
     #[serde(rename = "DefinitionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub definition_type: Option<String>,
+    pub definition_type: Option<ServiceActionDefinitionType>,
     /// <p>The self-service action description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3112,6 +9208,162 @@ pub struct ShareError {
     pub message: Option<String>,
 }
 
+/// <p>Status of the portfolio share operation.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShareStatus {
+    /// <p><code>NOT_STARTED</code></p>
+    NotStarted,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>COMPLETED_WITH_ERRORS</code></p>
+    CompletedWithErrors,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ShareStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShareStatus::NotStarted => "NOT_STARTED",
+            ShareStatus::InProgress => "IN_PROGRESS",
+            ShareStatus::Completed => "COMPLETED",
+            ShareStatus::CompletedWithErrors => "COMPLETED_WITH_ERRORS",
+            ShareStatus::Error => "ERROR",
+            ShareStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ShareStatus {
+    fn from(value: &'a str) -> ShareStatus {
+        match value {
+            "NOT_STARTED" => ShareStatus::NotStarted,
+            "IN_PROGRESS" => ShareStatus::InProgress,
+            "COMPLETED" => ShareStatus::Completed,
+            "COMPLETED_WITH_ERRORS" => ShareStatus::CompletedWithErrors,
+            "ERROR" => ShareStatus::Error,
+            _ => ShareStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShareStatus {
+    fn from(value: String) -> ShareStatus {
+        ShareStatus::from(value.as_str())
+    }
+}
+
+impl From<ShareStatus> for String {
+    fn from(value: ShareStatus) -> String {
+        match value {
+            ShareStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ShareStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ShareStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShareStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ShareStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ShareStatus::from(value))
+    }
+}
+
+/// <p>The sort order. If no value is specified, the results are not sorted.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// <p><code>ASCENDING</code></p>
+    Ascending,
+    /// <p><code>DESCENDING</code></p>
+    Descending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SortOrder {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Ascending => "ASCENDING",
+            SortOrder::Descending => "DESCENDING",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        SortOrder::from(value.as_str())
+    }
+}
+
+impl From<SortOrder> for String {
+    fn from(value: SortOrder) -> String {
+        match value {
+            SortOrder::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SortOrder::from(value))
+    }
+}
+
 /// <p>An AWS CloudFormation stack, in a specific account and region, that's part of a stack set operation. A stack instance is a reference to an attempted or actual stack in a given account within a given region. A stack instance can exist without a stack—for example, if the stack couldn't be created for some reason. A stack instance is associated with only one stack set. Each stack instance contains the ID of its associated stack set, as well as the ID of the actual stack and the stack status. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3127,62 +9379,290 @@ pub struct StackInstance {
     /// <p><p>The status of the stack instance, in terms of its synchronization with its associated stack set. </p> <ul> <li> <p> <code>INOPERABLE</code>: A <code>DeleteStackInstances</code> operation has failed and left the stack in an unstable state. Stacks in this state are excluded from further <code>UpdateStackSet</code> operations. You might need to perform a <code>DeleteStackInstances</code> operation, with <code>RetainStacks</code> set to true, to delete the stack instance, and then delete the stack manually. </p> </li> <li> <p> <code>OUTDATED</code>: The stack isn&#39;t currently up to date with the stack set because either the associated stack failed during a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation, or the stack was part of a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation that failed or was stopped before the stack was created or updated.</p> </li> <li> <p> <code>CURRENT</code>: The stack is currently up to date with the stack set.</p> </li> </ul></p>
     #[serde(rename = "StackInstanceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stack_instance_status: Option<String>,
+    pub stack_instance_status: Option<StackInstanceStatus>,
 }
 
-/// <p>Information about a tag. A tag is a key-value pair. Tags are propagated to the resources created when provisioning a product.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Tag {
-    /// <p>The tag key.</p>
-    #[serde(rename = "Key")]
-    pub key: String,
-    /// <p>The value for this key.</p>
-    #[serde(rename = "Value")]
-    pub value: String,
+/// <p><p>The status of the stack instance, in terms of its synchronization with its associated stack set. </p> <ul> <li> <p> <code>INOPERABLE</code>: A <code>DeleteStackInstances</code> operation has failed and left the stack in an unstable state. Stacks in this state are excluded from further <code>UpdateStackSet</code> operations. You might need to perform a <code>DeleteStackInstances</code> operation, with <code>RetainStacks</code> set to true, to delete the stack instance, and then delete the stack manually. </p> </li> <li> <p> <code>OUTDATED</code>: The stack isn&#39;t currently up to date with the stack set because either the associated stack failed during a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation, or the stack was part of a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation that failed or was stopped before the stack was created or updated.</p> </li> <li> <p> <code>CURRENT</code>: The stack is currently up to date with the stack set.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackInstanceStatus {
+    /// <p><code>CURRENT</code> - The stack is currently up to date with the stack set.</p>
+    Current,
+    /// <p><code>OUTDATED</code> - The stack isn&#39;t currently up to date with the stack set because either the associated stack failed during a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation, or the stack was part of a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation that failed or was stopped before the stack was created or updated.</p>
+    Outdated,
+    /// <p><code>INOPERABLE</code> - A <code>DeleteStackInstances</code> operation has failed and left the stack in an unstable state. Stacks in this state are excluded from further <code>UpdateStackSet</code> operations. You might need to perform a <code>DeleteStackInstances</code> operation, with <code>RetainStacks</code> set to true, to delete the stack instance, and then delete the stack manually.</p>
+    Inoperable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Information about a TagOption.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct TagOptionDetail {
-    /// <p>The TagOption active state.</p>
-    #[serde(rename = "Active")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub active: Option<bool>,
-    /// <p>The TagOption identifier.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>The TagOption key.</p>
-    #[serde(rename = "Key")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
-    /// <p>The TagOption value.</p>
-    #[serde(rename = "Value")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+impl StackInstanceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackInstanceStatus::Current => "CURRENT",
+            StackInstanceStatus::Outdated => "OUTDATED",
+            StackInstanceStatus::Inoperable => "INOPERABLE",
+            StackInstanceStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Summary information about a TagOption.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct TagOptionSummary {
-    /// <p>The TagOption key.</p>
-    #[serde(rename = "Key")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
-    /// <p>The TagOption value.</p>
-    #[serde(rename = "Values")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub values: Option<Vec<String>>,
+impl<'a> From<&'a str> for StackInstanceStatus {
+    fn from(value: &'a str) -> StackInstanceStatus {
+        match value {
+            "CURRENT" => StackInstanceStatus::Current,
+            "OUTDATED" => StackInstanceStatus::Outdated,
+            "INOPERABLE" => StackInstanceStatus::Inoperable,
+            _ => StackInstanceStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct TerminateProvisionedProductInput {
-    /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
-    #[serde(rename = "AcceptLanguage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub accept_language: Option<String>,
+impl From<String> for StackInstanceStatus {
+    fn from(value: String) -> StackInstanceStatus {
+        StackInstanceStatus::from(value.as_str())
+    }
+}
+
+impl From<StackInstanceStatus> for String {
+    fn from(value: StackInstanceStatus) -> String {
+        match value {
+            StackInstanceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackInstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StackInstanceStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StackInstanceStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StackInstanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StackInstanceStatus::from(value))
+    }
+}
+
+/// <p><p>Determines what action AWS Service Catalog performs to a stack set or a stack instance represented by the provisioned product. The default value is <code>UPDATE</code> if nothing is specified.</p> <p>Applicable only to a <code>CFN_STACKSET</code> provisioned product type.</p> <dl> <dt>CREATE</dt> <dd> <p>Creates a new stack instance in the stack set represented by the provisioned product. In this case, only new stack instances are created based on accounts and regions; if new ProductId or ProvisioningArtifactID are passed, they will be ignored.</p> </dd> <dt>UPDATE</dt> <dd> <p>Updates the stack set represented by the provisioned product and also its stack instances.</p> </dd> <dt>DELETE</dt> <dd> <p>Deletes a stack instance in the stack set represented by the provisioned product.</p> </dd> </dl></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackSetOperationType {
+    /// <p><code>CREATE</code></p>
+    Create,
+    /// <p><code>UPDATE</code></p>
+    Update,
+    /// <p><code>DELETE</code></p>
+    Delete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackSetOperationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetOperationType::Create => "CREATE",
+            StackSetOperationType::Update => "UPDATE",
+            StackSetOperationType::Delete => "DELETE",
+            StackSetOperationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackSetOperationType {
+    fn from(value: &'a str) -> StackSetOperationType {
+        match value {
+            "CREATE" => StackSetOperationType::Create,
+            "UPDATE" => StackSetOperationType::Update,
+            "DELETE" => StackSetOperationType::Delete,
+            _ => StackSetOperationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetOperationType {
+    fn from(value: String) -> StackSetOperationType {
+        StackSetOperationType::from(value.as_str())
+    }
+}
+
+impl From<StackSetOperationType> for String {
+    fn from(value: StackSetOperationType) -> String {
+        match value {
+            StackSetOperationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackSetOperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StackSetOperationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StackSetOperationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StackSetOperationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StackSetOperationType::from(value))
+    }
+}
+
+/// <p>The status of the current request.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// <p><code>AVAILABLE</code> - The product is ready for use.</p>
+    Available,
+    /// <p><code>CREATING</code> - Product creation has started; the product is not ready for use.</p>
+    Creating,
+    /// <p><code>FAILED</code> - An action failed.</p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Status {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::Available => "AVAILABLE",
+            Status::Creating => "CREATING",
+            Status::Failed => "FAILED",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "AVAILABLE" => Status::Available,
+            "CREATING" => Status::Creating,
+            "FAILED" => Status::Failed,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        Status::from(value.as_str())
+    }
+}
+
+impl From<Status> for String {
+    fn from(value: Status) -> String {
+        match value {
+            Status::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Status::from(value))
+    }
+}
+
+/// <p>Information about a tag. A tag is a key-value pair. Tags are propagated to the resources created when provisioning a product.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tag {
+    /// <p>The tag key.</p>
+    #[serde(rename = "Key")]
+    pub key: String,
+    /// <p>The value for this key.</p>
+    #[serde(rename = "Value")]
+    pub value: String,
+}
+
+/// <p>Information about a TagOption.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct TagOptionDetail {
+    /// <p>The TagOption active state.</p>
+    #[serde(rename = "Active")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// <p>The TagOption identifier.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>The TagOption key.</p>
+    #[serde(rename = "Key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// <p>The TagOption value.</p>
+    #[serde(rename = "Value")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// <p>Summary information about a TagOption.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct TagOptionSummary {
+    /// <p>The TagOption key.</p>
+    #[serde(rename = "Key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// <p>The TagOption value.</p>
+    #[serde(rename = "Values")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct TerminateProvisionedProductInput {
+    /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
+    #[serde(rename = "AcceptLanguage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
     /// <p>If set to true, AWS Service Catalog stops managing the specified provisioned product even if it cannot delete the underlying resources.</p>
     #[serde(rename = "IgnoreErrors")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3200,6 +9680,70 @@ pub struct TerminateProvisionedProductInput {
     pub terminate_token: String,
 }
 
+impl TerminateProvisionedProductInput {
+    /// Returns a builder for `TerminateProvisionedProductInput`, checking its required members when built.
+    pub fn builder() -> TerminateProvisionedProductInputBuilder {
+        TerminateProvisionedProductInputBuilder::default()
+    }
+}
+
+/// Builds a [`TerminateProvisionedProductInput`](struct.TerminateProvisionedProductInput.html).
+#[derive(Default, Debug)]
+pub struct TerminateProvisionedProductInputBuilder {
+    accept_language: Option<String>,
+    ignore_errors: Option<bool>,
+    provisioned_product_id: Option<String>,
+    provisioned_product_name: Option<String>,
+    terminate_token: Option<String>,
+}
+
+impl TerminateProvisionedProductInputBuilder {
+    /// Sets the [`accept_language`](struct.TerminateProvisionedProductInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ignore_errors`](struct.TerminateProvisionedProductInput.html#structfield.ignore_errors) member.
+    pub fn ignore_errors<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ignore_errors = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_product_id`](struct.TerminateProvisionedProductInput.html#structfield.provisioned_product_id) member.
+    pub fn provisioned_product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioned_product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_product_name`](struct.TerminateProvisionedProductInput.html#structfield.provisioned_product_name) member.
+    pub fn provisioned_product_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioned_product_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`terminate_token`](struct.TerminateProvisionedProductInput.html#structfield.terminate_token) member.
+    pub fn terminate_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.terminate_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `TerminateProvisionedProductInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TerminateProvisionedProductInput, ::rusoto_core::BuildError> {
+        Ok(TerminateProvisionedProductInput {
+            accept_language: self.accept_language,
+            ignore_errors: self.ignore_errors,
+            provisioned_product_id: self.provisioned_product_id,
+            provisioned_product_name: self.provisioned_product_name,
+            terminate_token: self
+                .terminate_token
+                .ok_or(::rusoto_core::BuildError::missing("terminate_token"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TerminateProvisionedProductOutput {
@@ -3228,6 +9772,58 @@ pub struct UpdateConstraintInput {
     pub parameters: Option<String>,
 }
 
+impl UpdateConstraintInput {
+    /// Returns a builder for `UpdateConstraintInput`, checking its required members when built.
+    pub fn builder() -> UpdateConstraintInputBuilder {
+        UpdateConstraintInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConstraintInput`](struct.UpdateConstraintInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateConstraintInputBuilder {
+    accept_language: Option<String>,
+    description: Option<String>,
+    id: Option<String>,
+    parameters: Option<String>,
+}
+
+impl UpdateConstraintInputBuilder {
+    /// Sets the [`accept_language`](struct.UpdateConstraintInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateConstraintInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateConstraintInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.UpdateConstraintInput.html#structfield.parameters) member.
+    pub fn parameters<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConstraintInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateConstraintInput, ::rusoto_core::BuildError> {
+        Ok(UpdateConstraintInput {
+            accept_language: self.accept_language,
+            description: self.description,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            parameters: self.parameters,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateConstraintOutput {
@@ -3242,7 +9838,7 @@ pub struct UpdateConstraintOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3276,6 +9872,82 @@ pub struct UpdatePortfolioInput {
     pub remove_tags: Option<Vec<String>>,
 }
 
+impl UpdatePortfolioInput {
+    /// Returns a builder for `UpdatePortfolioInput`, checking its required members when built.
+    pub fn builder() -> UpdatePortfolioInputBuilder {
+        UpdatePortfolioInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePortfolioInput`](struct.UpdatePortfolioInput.html).
+#[derive(Default, Debug)]
+pub struct UpdatePortfolioInputBuilder {
+    accept_language: Option<String>,
+    add_tags: Option<Vec<Tag>>,
+    description: Option<String>,
+    display_name: Option<String>,
+    id: Option<String>,
+    provider_name: Option<String>,
+    remove_tags: Option<Vec<String>>,
+}
+
+impl UpdatePortfolioInputBuilder {
+    /// Sets the [`accept_language`](struct.UpdatePortfolioInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`add_tags`](struct.UpdatePortfolioInput.html#structfield.add_tags) member.
+    pub fn add_tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.add_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdatePortfolioInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.UpdatePortfolioInput.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdatePortfolioInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_name`](struct.UpdatePortfolioInput.html#structfield.provider_name) member.
+    pub fn provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remove_tags`](struct.UpdatePortfolioInput.html#structfield.remove_tags) member.
+    pub fn remove_tags<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.remove_tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePortfolioInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdatePortfolioInput, ::rusoto_core::BuildError> {
+        Ok(UpdatePortfolioInput {
+            accept_language: self.accept_language,
+            add_tags: self.add_tags,
+            description: self.description,
+            display_name: self.display_name,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            provider_name: self.provider_name,
+            remove_tags: self.remove_tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdatePortfolioOutput {
@@ -3336,6 +10008,114 @@ pub struct UpdateProductInput {
     pub support_url: Option<String>,
 }
 
+impl UpdateProductInput {
+    /// Returns a builder for `UpdateProductInput`, checking its required members when built.
+    pub fn builder() -> UpdateProductInputBuilder {
+        UpdateProductInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateProductInput`](struct.UpdateProductInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateProductInputBuilder {
+    accept_language: Option<String>,
+    add_tags: Option<Vec<Tag>>,
+    description: Option<String>,
+    distributor: Option<String>,
+    id: Option<String>,
+    name: Option<String>,
+    owner: Option<String>,
+    remove_tags: Option<Vec<String>>,
+    support_description: Option<String>,
+    support_email: Option<String>,
+    support_url: Option<String>,
+}
+
+impl UpdateProductInputBuilder {
+    /// Sets the [`accept_language`](struct.UpdateProductInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`add_tags`](struct.UpdateProductInput.html#structfield.add_tags) member.
+    pub fn add_tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.add_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateProductInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`distributor`](struct.UpdateProductInput.html#structfield.distributor) member.
+    pub fn distributor<T: Into<String>>(mut self, value: T) -> Self {
+        self.distributor = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateProductInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateProductInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner`](struct.UpdateProductInput.html#structfield.owner) member.
+    pub fn owner<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remove_tags`](struct.UpdateProductInput.html#structfield.remove_tags) member.
+    pub fn remove_tags<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.remove_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`support_description`](struct.UpdateProductInput.html#structfield.support_description) member.
+    pub fn support_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.support_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`support_email`](struct.UpdateProductInput.html#structfield.support_email) member.
+    pub fn support_email<T: Into<String>>(mut self, value: T) -> Self {
+        self.support_email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`support_url`](struct.UpdateProductInput.html#structfield.support_url) member.
+    pub fn support_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.support_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateProductInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateProductInput, ::rusoto_core::BuildError> {
+        Ok(UpdateProductInput {
+            accept_language: self.accept_language,
+            add_tags: self.add_tags,
+            description: self.description,
+            distributor: self.distributor,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            name: self.name,
+            owner: self.owner,
+            remove_tags: self.remove_tags,
+            support_description: self.support_description,
+            support_email: self.support_email,
+            support_url: self.support_url,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateProductOutput {
@@ -3392,43 +10172,219 @@ pub struct UpdateProvisionedProductInput {
     pub update_token: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateProvisionedProductOutput {
-    /// <p>Information about the result of the request.</p>
-    #[serde(rename = "RecordDetail")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub record_detail: Option<RecordDetail>,
+impl UpdateProvisionedProductInput {
+    /// Returns a builder for `UpdateProvisionedProductInput`, checking its required members when built.
+    pub fn builder() -> UpdateProvisionedProductInputBuilder {
+        UpdateProvisionedProductInputBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateProvisionedProductPropertiesInput {
-    /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
-    #[serde(rename = "AcceptLanguage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub accept_language: Option<String>,
-    /// <p>The idempotency token that uniquely identifies the provisioning product update request.</p>
-    #[serde(rename = "IdempotencyToken")]
-    pub idempotency_token: String,
-    /// <p>The identifier of the provisioned product.</p>
-    #[serde(rename = "ProvisionedProductId")]
-    pub provisioned_product_id: String,
-    /// <p>A map that contains the provisioned product properties to be updated.</p> <p>The <code>OWNER</code> key only accepts user ARNs. The owner is the user that is allowed to see, update, terminate, and execute service actions in the provisioned product.</p> <p>The administrator can change the owner of a provisioned product to another IAM user within the same account. Both end user owners and administrators can see ownership history of the provisioned product using the <code>ListRecordHistory</code> API. The new owner can describe all past records for the provisioned product using the <code>DescribeRecord</code> API. The previous owner can no longer use <code>DescribeRecord</code>, but can still see the product's history from when he was an owner using <code>ListRecordHistory</code>.</p> <p>If a provisioned product ownership is assigned to an end user, they can see and perform any action through the API or Service Catalog console such as update, terminate, and execute service actions. If an end user provisions a product and the owner is updated to someone else, they will no longer be able to see or perform any actions through API or the Service Catalog console on that provisioned product.</p>
-    #[serde(rename = "ProvisionedProductProperties")]
-    pub provisioned_product_properties: ::std::collections::HashMap<String, String>,
+/// Builds a [`UpdateProvisionedProductInput`](struct.UpdateProvisionedProductInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateProvisionedProductInputBuilder {
+    accept_language: Option<String>,
+    path_id: Option<String>,
+    product_id: Option<String>,
+    provisioned_product_id: Option<String>,
+    provisioned_product_name: Option<String>,
+    provisioning_artifact_id: Option<String>,
+    provisioning_parameters: Option<Vec<UpdateProvisioningParameter>>,
+    provisioning_preferences: Option<UpdateProvisioningPreferences>,
+    tags: Option<Vec<Tag>>,
+    update_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateProvisionedProductPropertiesOutput {
-    /// <p>The provisioned product identifier.</p>
-    #[serde(rename = "ProvisionedProductId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl UpdateProvisionedProductInputBuilder {
+    /// Sets the [`accept_language`](struct.UpdateProvisionedProductInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_id`](struct.UpdateProvisionedProductInput.html#structfield.path_id) member.
+    pub fn path_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.UpdateProvisionedProductInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_product_id`](struct.UpdateProvisionedProductInput.html#structfield.provisioned_product_id) member.
+    pub fn provisioned_product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioned_product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_product_name`](struct.UpdateProvisionedProductInput.html#structfield.provisioned_product_name) member.
+    pub fn provisioned_product_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioned_product_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_artifact_id`](struct.UpdateProvisionedProductInput.html#structfield.provisioning_artifact_id) member.
+    pub fn provisioning_artifact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioning_artifact_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_parameters`](struct.UpdateProvisionedProductInput.html#structfield.provisioning_parameters) member.
+    pub fn provisioning_parameters<T: Into<Vec<UpdateProvisioningParameter>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.provisioning_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_preferences`](struct.UpdateProvisionedProductInput.html#structfield.provisioning_preferences) member.
+    pub fn provisioning_preferences<T: Into<UpdateProvisioningPreferences>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.provisioning_preferences = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.UpdateProvisionedProductInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_token`](struct.UpdateProvisionedProductInput.html#structfield.update_token) member.
+    pub fn update_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.update_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateProvisionedProductInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateProvisionedProductInput, ::rusoto_core::BuildError> {
+        Ok(UpdateProvisionedProductInput {
+            accept_language: self.accept_language,
+            path_id: self.path_id,
+            product_id: self.product_id,
+            provisioned_product_id: self.provisioned_product_id,
+            provisioned_product_name: self.provisioned_product_name,
+            provisioning_artifact_id: self.provisioning_artifact_id,
+            provisioning_parameters: self.provisioning_parameters,
+            provisioning_preferences: self.provisioning_preferences,
+            tags: self.tags,
+            update_token: self
+                .update_token
+                .ok_or(::rusoto_core::BuildError::missing("update_token"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateProvisionedProductOutput {
+    /// <p>Information about the result of the request.</p>
+    #[serde(rename = "RecordDetail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_detail: Option<RecordDetail>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateProvisionedProductPropertiesInput {
+    /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
+    #[serde(rename = "AcceptLanguage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+    /// <p>The idempotency token that uniquely identifies the provisioning product update request.</p>
+    #[serde(rename = "IdempotencyToken")]
+    pub idempotency_token: String,
+    /// <p>The identifier of the provisioned product.</p>
+    #[serde(rename = "ProvisionedProductId")]
+    pub provisioned_product_id: String,
+    /// <p>A map that contains the provisioned product properties to be updated.</p> <p>The <code>OWNER</code> key only accepts user ARNs. The owner is the user that is allowed to see, update, terminate, and execute service actions in the provisioned product.</p> <p>The administrator can change the owner of a provisioned product to another IAM user within the same account. Both end user owners and administrators can see ownership history of the provisioned product using the <code>ListRecordHistory</code> API. The new owner can describe all past records for the provisioned product using the <code>DescribeRecord</code> API. The previous owner can no longer use <code>DescribeRecord</code>, but can still see the product's history from when he was an owner using <code>ListRecordHistory</code>.</p> <p>If a provisioned product ownership is assigned to an end user, they can see and perform any action through the API or Service Catalog console such as update, terminate, and execute service actions. If an end user provisions a product and the owner is updated to someone else, they will no longer be able to see or perform any actions through API or the Service Catalog console on that provisioned product.</p>
+    #[serde(rename = "ProvisionedProductProperties")]
+    pub provisioned_product_properties: ::std::collections::HashMap<PropertyKey, String>,
+}
+
+impl UpdateProvisionedProductPropertiesInput {
+    /// Returns a builder for `UpdateProvisionedProductPropertiesInput`, checking its required members when built.
+    pub fn builder() -> UpdateProvisionedProductPropertiesInputBuilder {
+        UpdateProvisionedProductPropertiesInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateProvisionedProductPropertiesInput`](struct.UpdateProvisionedProductPropertiesInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateProvisionedProductPropertiesInputBuilder {
+    accept_language: Option<String>,
+    idempotency_token: Option<String>,
+    provisioned_product_id: Option<String>,
+    provisioned_product_properties: Option<::std::collections::HashMap<PropertyKey, String>>,
+}
+
+impl UpdateProvisionedProductPropertiesInputBuilder {
+    /// Sets the [`accept_language`](struct.UpdateProvisionedProductPropertiesInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.UpdateProvisionedProductPropertiesInput.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_product_id`](struct.UpdateProvisionedProductPropertiesInput.html#structfield.provisioned_product_id) member.
+    pub fn provisioned_product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioned_product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_product_properties`](struct.UpdateProvisionedProductPropertiesInput.html#structfield.provisioned_product_properties) member.
+    pub fn provisioned_product_properties<
+        T: Into<::std::collections::HashMap<PropertyKey, String>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.provisioned_product_properties = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateProvisionedProductPropertiesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateProvisionedProductPropertiesInput, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateProvisionedProductPropertiesInput {
+            accept_language: self.accept_language,
+            idempotency_token: self
+                .idempotency_token
+                .ok_or(::rusoto_core::BuildError::missing("idempotency_token"))?,
+            provisioned_product_id: self
+                .provisioned_product_id
+                .ok_or(::rusoto_core::BuildError::missing("provisioned_product_id"))?,
+            provisioned_product_properties: self.provisioned_product_properties.ok_or(
+                ::rusoto_core::BuildError::missing("provisioned_product_properties"),
+            )?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateProvisionedProductPropertiesOutput {
+    /// <p>The provisioned product identifier.</p>
+    #[serde(rename = "ProvisionedProductId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub provisioned_product_id: Option<String>,
     /// <p>A map that contains the properties updated.</p>
     #[serde(rename = "ProvisionedProductProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provisioned_product_properties: Option<::std::collections::HashMap<String, String>>,
+    pub provisioned_product_properties: Option<::std::collections::HashMap<PropertyKey, String>>,
     /// <p>The identifier of the record.</p>
     #[serde(rename = "RecordId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3436,7 +10392,7 @@ pub struct UpdateProvisionedProductPropertiesOutput {
     /// <p>The status of the request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RecordStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3456,7 +10412,7 @@ pub struct UpdateProvisioningArtifactInput {
     /// <p>Information set by the administrator to provide guidance to end users about which provisioning artifacts to use.</p> <p>The <code>DEFAULT</code> value indicates that the product version is active.</p> <p>The administrator can set the guidance to <code>DEPRECATED</code> to inform users that the product version is deprecated. Users are able to make updates to a provisioned product of a deprecated version but cannot launch new provisioned products using a deprecated version.</p>
     #[serde(rename = "Guidance")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub guidance: Option<String>,
+    pub guidance: Option<ProvisioningArtifactGuidance>,
     /// <p>The updated name of the provisioning artifact.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3469,6 +10425,88 @@ pub struct UpdateProvisioningArtifactInput {
     pub provisioning_artifact_id: String,
 }
 
+impl UpdateProvisioningArtifactInput {
+    /// Returns a builder for `UpdateProvisioningArtifactInput`, checking its required members when built.
+    pub fn builder() -> UpdateProvisioningArtifactInputBuilder {
+        UpdateProvisioningArtifactInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateProvisioningArtifactInput`](struct.UpdateProvisioningArtifactInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateProvisioningArtifactInputBuilder {
+    accept_language: Option<String>,
+    active: Option<bool>,
+    description: Option<String>,
+    guidance: Option<ProvisioningArtifactGuidance>,
+    name: Option<String>,
+    product_id: Option<String>,
+    provisioning_artifact_id: Option<String>,
+}
+
+impl UpdateProvisioningArtifactInputBuilder {
+    /// Sets the [`accept_language`](struct.UpdateProvisioningArtifactInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`active`](struct.UpdateProvisioningArtifactInput.html#structfield.active) member.
+    pub fn active<T: Into<bool>>(mut self, value: T) -> Self {
+        self.active = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateProvisioningArtifactInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`guidance`](struct.UpdateProvisioningArtifactInput.html#structfield.guidance) member.
+    pub fn guidance<T: Into<ProvisioningArtifactGuidance>>(mut self, value: T) -> Self {
+        self.guidance = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateProvisioningArtifactInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.UpdateProvisioningArtifactInput.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_artifact_id`](struct.UpdateProvisioningArtifactInput.html#structfield.provisioning_artifact_id) member.
+    pub fn provisioning_artifact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provisioning_artifact_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateProvisioningArtifactInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateProvisioningArtifactInput, ::rusoto_core::BuildError> {
+        Ok(UpdateProvisioningArtifactInput {
+            accept_language: self.accept_language,
+            active: self.active,
+            description: self.description,
+            guidance: self.guidance,
+            name: self.name,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            provisioning_artifact_id: self.provisioning_artifact_id.ok_or(
+                ::rusoto_core::BuildError::missing("provisioning_artifact_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateProvisioningArtifactOutput {
@@ -3483,7 +10521,7 @@ pub struct UpdateProvisioningArtifactOutput {
     /// <p>The status of the current request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
 }
 
 /// <p>The parameter key-value pair used to update a provisioned product.</p>
@@ -3529,7 +10567,7 @@ pub struct UpdateProvisioningPreferences {
     /// <p><p>Determines what action AWS Service Catalog performs to a stack set or a stack instance represented by the provisioned product. The default value is <code>UPDATE</code> if nothing is specified.</p> <p>Applicable only to a <code>CFN_STACKSET</code> provisioned product type.</p> <dl> <dt>CREATE</dt> <dd> <p>Creates a new stack instance in the stack set represented by the provisioned product. In this case, only new stack instances are created based on accounts and regions; if new ProductId or ProvisioningArtifactID are passed, they will be ignored.</p> </dd> <dt>UPDATE</dt> <dd> <p>Updates the stack set represented by the provisioned product and also its stack instances.</p> </dd> <dt>DELETE</dt> <dd> <p>Deletes a stack instance in the stack set represented by the provisioned product.</p> </dd> </dl></p>
     #[serde(rename = "StackSetOperationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stack_set_operation_type: Option<String>,
+    pub stack_set_operation_type: Option<StackSetOperationType>,
     /// <p>One or more AWS Regions where the provisioned product will be available.</p> <p>Applicable only to a <code>CFN_STACKSET</code> provisioned product type.</p> <p>The specified regions should be within the list of regions from the <code>STACKSET</code> constraint. To get the list of regions in the <code>STACKSET</code> constraint, use the <code>DescribeProvisioningParameters</code> operation.</p> <p>If no values are specified, the default value is all regions from the <code>STACKSET</code> constraint.</p>
     #[serde(rename = "StackSetRegions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3545,7 +10583,7 @@ pub struct UpdateServiceActionInput {
     /// <p>A map that defines the self-service action.</p>
     #[serde(rename = "Definition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub definition: Option<::std::collections::HashMap<String, String>>,
+    pub definition: Option<::std::collections::HashMap<ServiceActionDefinitionKey, String>>,
     /// <p>The self-service action description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3559,6 +10597,71 @@ pub struct UpdateServiceActionInput {
     pub name: Option<String>,
 }
 
+impl UpdateServiceActionInput {
+    /// Returns a builder for `UpdateServiceActionInput`, checking its required members when built.
+    pub fn builder() -> UpdateServiceActionInputBuilder {
+        UpdateServiceActionInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateServiceActionInput`](struct.UpdateServiceActionInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateServiceActionInputBuilder {
+    accept_language: Option<String>,
+    definition: Option<::std::collections::HashMap<ServiceActionDefinitionKey, String>>,
+    description: Option<String>,
+    id: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateServiceActionInputBuilder {
+    /// Sets the [`accept_language`](struct.UpdateServiceActionInput.html#structfield.accept_language) member.
+    pub fn accept_language<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept_language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`definition`](struct.UpdateServiceActionInput.html#structfield.definition) member.
+    pub fn definition<T: Into<::std::collections::HashMap<ServiceActionDefinitionKey, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.definition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateServiceActionInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateServiceActionInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateServiceActionInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateServiceActionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateServiceActionInput, ::rusoto_core::BuildError> {
+        Ok(UpdateServiceActionInput {
+            accept_language: self.accept_language,
+            definition: self.definition,
+            description: self.description,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateServiceActionOutput {
@@ -3583,6 +10686,50 @@ pub struct UpdateTagOptionInput {
     pub value: Option<String>,
 }
 
+impl UpdateTagOptionInput {
+    /// Returns a builder for `UpdateTagOptionInput`, checking its required members when built.
+    pub fn builder() -> UpdateTagOptionInputBuilder {
+        UpdateTagOptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTagOptionInput`](struct.UpdateTagOptionInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateTagOptionInputBuilder {
+    active: Option<bool>,
+    id: Option<String>,
+    value: Option<String>,
+}
+
+impl UpdateTagOptionInputBuilder {
+    /// Sets the [`active`](struct.UpdateTagOptionInput.html#structfield.active) member.
+    pub fn active<T: Into<bool>>(mut self, value: T) -> Self {
+        self.active = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateTagOptionInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`value`](struct.UpdateTagOptionInput.html#structfield.value) member.
+    pub fn value<T: Into<String>>(mut self, value: T) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTagOptionInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateTagOptionInput, ::rusoto_core::BuildError> {
+        Ok(UpdateTagOptionInput {
+            active: self.active,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            value: self.value,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateTagOptionOutput {
@@ -7291,575 +14438,1121 @@ pub enum UpdateTagOptionError {
     TagOptionNotMigrated(String),
 }
 
-impl UpdateTagOptionError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateTagOptionError> {
-        if let Some(err) = proto::json::Error::parse(&res) {
-            match err.typ.as_str() {
-                "DuplicateResourceException" => {
-                    return RusotoError::Service(UpdateTagOptionError::DuplicateResource(err.msg))
-                }
-                "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateTagOptionError::InvalidParameters(err.msg))
-                }
-                "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTagOptionError::ResourceNotFound(err.msg))
-                }
-                "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(UpdateTagOptionError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
-    }
-}
-impl fmt::Display for UpdateTagOptionError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-impl Error for UpdateTagOptionError {
-    fn description(&self) -> &str {
-        match *self {
-            UpdateTagOptionError::DuplicateResource(ref cause) => cause,
-            UpdateTagOptionError::InvalidParameters(ref cause) => cause,
-            UpdateTagOptionError::ResourceNotFound(ref cause) => cause,
-            UpdateTagOptionError::TagOptionNotMigrated(ref cause) => cause,
-        }
-    }
+impl UpdateTagOptionError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateTagOptionError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "DuplicateResourceException" => {
+                    return RusotoError::Service(UpdateTagOptionError::DuplicateResource(err.msg))
+                }
+                "InvalidParametersException" => {
+                    return RusotoError::Service(UpdateTagOptionError::InvalidParameters(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(UpdateTagOptionError::ResourceNotFound(err.msg))
+                }
+                "TagOptionNotMigratedException" => {
+                    return RusotoError::Service(UpdateTagOptionError::TagOptionNotMigrated(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateTagOptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateTagOptionError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateTagOptionError::DuplicateResource(ref cause) => cause,
+            UpdateTagOptionError::InvalidParameters(ref cause) => cause,
+            UpdateTagOptionError::ResourceNotFound(ref cause) => cause,
+            UpdateTagOptionError::TagOptionNotMigrated(ref cause) => cause,
+        }
+    }
+}
+/// Trait representing the capabilities of the AWS Service Catalog API. AWS Service Catalog clients implement this trait.
+pub trait ServiceCatalog {
+    /// <p>Accepts an offer to share the specified portfolio.</p>
+    fn accept_portfolio_share(
+        &self,
+        input: AcceptPortfolioShareInput,
+    ) -> RusotoFuture<AcceptPortfolioShareOutput, AcceptPortfolioShareError>;
+
+    /// <p>Associates the specified budget with the specified resource.</p>
+    fn associate_budget_with_resource(
+        &self,
+        input: AssociateBudgetWithResourceInput,
+    ) -> RusotoFuture<AssociateBudgetWithResourceOutput, AssociateBudgetWithResourceError>;
+
+    /// <p>Associates the specified principal ARN with the specified portfolio.</p>
+    fn associate_principal_with_portfolio(
+        &self,
+        input: AssociatePrincipalWithPortfolioInput,
+    ) -> RusotoFuture<AssociatePrincipalWithPortfolioOutput, AssociatePrincipalWithPortfolioError>;
+
+    /// <p>Associates the specified product with the specified portfolio.</p>
+    fn associate_product_with_portfolio(
+        &self,
+        input: AssociateProductWithPortfolioInput,
+    ) -> RusotoFuture<AssociateProductWithPortfolioOutput, AssociateProductWithPortfolioError>;
+
+    /// <p>Associates a self-service action with a provisioning artifact.</p>
+    fn associate_service_action_with_provisioning_artifact(
+        &self,
+        input: AssociateServiceActionWithProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        AssociateServiceActionWithProvisioningArtifactOutput,
+        AssociateServiceActionWithProvisioningArtifactError,
+    >;
+
+    /// <p>Associate the specified TagOption with the specified portfolio or product.</p>
+    fn associate_tag_option_with_resource(
+        &self,
+        input: AssociateTagOptionWithResourceInput,
+    ) -> RusotoFuture<AssociateTagOptionWithResourceOutput, AssociateTagOptionWithResourceError>;
+
+    /// <p>Associates multiple self-service actions with provisioning artifacts.</p>
+    fn batch_associate_service_action_with_provisioning_artifact(
+        &self,
+        input: BatchAssociateServiceActionWithProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        BatchAssociateServiceActionWithProvisioningArtifactOutput,
+        BatchAssociateServiceActionWithProvisioningArtifactError,
+    >;
+
+    /// <p>Disassociates a batch of self-service actions from the specified provisioning artifact.</p>
+    fn batch_disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+        BatchDisassociateServiceActionFromProvisioningArtifactError,
+    >;
+
+    /// <p>Copies the specified source product to the specified target product or a new product.</p> <p>You can copy a product to the same account or another account. You can copy a product to the same region or another region.</p> <p>This operation is performed asynchronously. To track the progress of the operation, use <a>DescribeCopyProductStatus</a>.</p>
+    fn copy_product(
+        &self,
+        input: CopyProductInput,
+    ) -> RusotoFuture<CopyProductOutput, CopyProductError>;
+
+    /// <p>Creates a constraint.</p>
+    fn create_constraint(
+        &self,
+        input: CreateConstraintInput,
+    ) -> RusotoFuture<CreateConstraintOutput, CreateConstraintError>;
+
+    /// <p>Creates a portfolio.</p>
+    fn create_portfolio(
+        &self,
+        input: CreatePortfolioInput,
+    ) -> RusotoFuture<CreatePortfolioOutput, CreatePortfolioError>;
+
+    /// <p>Shares the specified portfolio with the specified account or organization node. Shares to an organization node can only be created by the master account of an Organization. AWSOrganizationsAccess must be enabled in order to create a portfolio share to an organization node.</p>
+    fn create_portfolio_share(
+        &self,
+        input: CreatePortfolioShareInput,
+    ) -> RusotoFuture<CreatePortfolioShareOutput, CreatePortfolioShareError>;
+
+    /// <p>Creates a product.</p>
+    fn create_product(
+        &self,
+        input: CreateProductInput,
+    ) -> RusotoFuture<CreateProductOutput, CreateProductError>;
+
+    /// <p>Creates a plan. A plan includes the list of resources to be created (when provisioning a new product) or modified (when updating a provisioned product) when the plan is executed.</p> <p>You can create one plan per provisioned product. To create a plan for an existing provisioned product, the product status must be AVAILBLE or TAINTED.</p> <p>To view the resource changes in the change set, use <a>DescribeProvisionedProductPlan</a>. To create or modify the provisioned product, use <a>ExecuteProvisionedProductPlan</a>.</p>
+    fn create_provisioned_product_plan(
+        &self,
+        input: CreateProvisionedProductPlanInput,
+    ) -> RusotoFuture<CreateProvisionedProductPlanOutput, CreateProvisionedProductPlanError>;
+
+    /// <p>Creates a provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot create a provisioning artifact for a product that was shared with you.</p>
+    fn create_provisioning_artifact(
+        &self,
+        input: CreateProvisioningArtifactInput,
+    ) -> RusotoFuture<CreateProvisioningArtifactOutput, CreateProvisioningArtifactError>;
+
+    /// <p>Creates a self-service action.</p>
+    fn create_service_action(
+        &self,
+        input: CreateServiceActionInput,
+    ) -> RusotoFuture<CreateServiceActionOutput, CreateServiceActionError>;
+
+    /// <p>Creates a TagOption.</p>
+    fn create_tag_option(
+        &self,
+        input: CreateTagOptionInput,
+    ) -> RusotoFuture<CreateTagOptionOutput, CreateTagOptionError>;
+
+    /// <p>Deletes the specified constraint.</p>
+    fn delete_constraint(
+        &self,
+        input: DeleteConstraintInput,
+    ) -> RusotoFuture<DeleteConstraintOutput, DeleteConstraintError>;
+
+    /// <p>Deletes the specified portfolio.</p> <p>You cannot delete a portfolio if it was shared with you or if it has associated products, users, constraints, or shared accounts.</p>
+    fn delete_portfolio(
+        &self,
+        input: DeletePortfolioInput,
+    ) -> RusotoFuture<DeletePortfolioOutput, DeletePortfolioError>;
+
+    /// <p>Stops sharing the specified portfolio with the specified account or organization node. Shares to an organization node can only be deleted by the master account of an Organization.</p>
+    fn delete_portfolio_share(
+        &self,
+        input: DeletePortfolioShareInput,
+    ) -> RusotoFuture<DeletePortfolioShareOutput, DeletePortfolioShareError>;
+
+    /// <p>Deletes the specified product.</p> <p>You cannot delete a product if it was shared with you or is associated with a portfolio.</p>
+    fn delete_product(
+        &self,
+        input: DeleteProductInput,
+    ) -> RusotoFuture<DeleteProductOutput, DeleteProductError>;
+
+    /// <p>Deletes the specified plan.</p>
+    fn delete_provisioned_product_plan(
+        &self,
+        input: DeleteProvisionedProductPlanInput,
+    ) -> RusotoFuture<DeleteProvisionedProductPlanOutput, DeleteProvisionedProductPlanError>;
+
+    /// <p>Deletes the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot delete a provisioning artifact associated with a product that was shared with you. You cannot delete the last provisioning artifact for a product, because a product must have at least one provisioning artifact.</p>
+    fn delete_provisioning_artifact(
+        &self,
+        input: DeleteProvisioningArtifactInput,
+    ) -> RusotoFuture<DeleteProvisioningArtifactOutput, DeleteProvisioningArtifactError>;
+
+    /// <p>Deletes a self-service action.</p>
+    fn delete_service_action(
+        &self,
+        input: DeleteServiceActionInput,
+    ) -> RusotoFuture<DeleteServiceActionOutput, DeleteServiceActionError>;
+
+    /// <p>Deletes the specified TagOption.</p> <p>You cannot delete a TagOption if it is associated with a product or portfolio.</p>
+    fn delete_tag_option(
+        &self,
+        input: DeleteTagOptionInput,
+    ) -> RusotoFuture<DeleteTagOptionOutput, DeleteTagOptionError>;
+
+    /// <p>Gets information about the specified constraint.</p>
+    fn describe_constraint(
+        &self,
+        input: DescribeConstraintInput,
+    ) -> RusotoFuture<DescribeConstraintOutput, DescribeConstraintError>;
+
+    /// <p>Gets the status of the specified copy product operation.</p>
+    fn describe_copy_product_status(
+        &self,
+        input: DescribeCopyProductStatusInput,
+    ) -> RusotoFuture<DescribeCopyProductStatusOutput, DescribeCopyProductStatusError>;
+
+    /// <p>Gets information about the specified portfolio.</p>
+    fn describe_portfolio(
+        &self,
+        input: DescribePortfolioInput,
+    ) -> RusotoFuture<DescribePortfolioOutput, DescribePortfolioError>;
+
+    /// <p>Gets the status of the specified portfolio share operation. This API can only be called by the master account in the organization.</p>
+    fn describe_portfolio_share_status(
+        &self,
+        input: DescribePortfolioShareStatusInput,
+    ) -> RusotoFuture<DescribePortfolioShareStatusOutput, DescribePortfolioShareStatusError>;
+
+    /// <p>Gets information about the specified product.</p>
+    fn describe_product(
+        &self,
+        input: DescribeProductInput,
+    ) -> RusotoFuture<DescribeProductOutput, DescribeProductError>;
+
+    /// <p>Gets information about the specified product. This operation is run with administrator access.</p>
+    fn describe_product_as_admin(
+        &self,
+        input: DescribeProductAsAdminInput,
+    ) -> RusotoFuture<DescribeProductAsAdminOutput, DescribeProductAsAdminError>;
+
+    /// <p>Gets information about the specified product.</p>
+    fn describe_product_view(
+        &self,
+        input: DescribeProductViewInput,
+    ) -> RusotoFuture<DescribeProductViewOutput, DescribeProductViewError>;
+
+    /// <p>Gets information about the specified provisioned product.</p>
+    fn describe_provisioned_product(
+        &self,
+        input: DescribeProvisionedProductInput,
+    ) -> RusotoFuture<DescribeProvisionedProductOutput, DescribeProvisionedProductError>;
+
+    /// <p>Gets information about the resource changes for the specified plan.</p>
+    fn describe_provisioned_product_plan(
+        &self,
+        input: DescribeProvisionedProductPlanInput,
+    ) -> RusotoFuture<DescribeProvisionedProductPlanOutput, DescribeProvisionedProductPlanError>;
+
+    /// <p>Gets information about the specified provisioning artifact (also known as a version) for the specified product.</p>
+    fn describe_provisioning_artifact(
+        &self,
+        input: DescribeProvisioningArtifactInput,
+    ) -> RusotoFuture<DescribeProvisioningArtifactOutput, DescribeProvisioningArtifactError>;
+
+    /// <p>Gets information about the configuration required to provision the specified product using the specified provisioning artifact.</p> <p>If the output contains a TagOption key with an empty list of values, there is a TagOption conflict for that key. The end user cannot take action to fix the conflict, and launch is not blocked. In subsequent calls to <a>ProvisionProduct</a>, do not include conflicted TagOption keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>". Tag the provisioned product with the value <code>sc-tagoption-conflict-portfolioId-productId</code>.</p>
+    fn describe_provisioning_parameters(
+        &self,
+        input: DescribeProvisioningParametersInput,
+    ) -> RusotoFuture<DescribeProvisioningParametersOutput, DescribeProvisioningParametersError>;
+
+    /// <p><p>Gets information about the specified request operation.</p> <p>Use this operation after calling a request operation (for example, <a>ProvisionProduct</a>, <a>TerminateProvisionedProduct</a>, or <a>UpdateProvisionedProduct</a>). </p> <note> <p>If a provisioned product was transferred to a new owner using <a>UpdateProvisionedProductProperties</a>, the new owner will be able to describe all past records for that product. The previous owner will no longer be able to describe the records, but will be able to use <a>ListRecordHistory</a> to see the product&#39;s history from when he was the owner.</p> </note></p>
+    fn describe_record(
+        &self,
+        input: DescribeRecordInput,
+    ) -> RusotoFuture<DescribeRecordOutput, DescribeRecordError>;
+
+    /// <p>Describes a self-service action.</p>
+    fn describe_service_action(
+        &self,
+        input: DescribeServiceActionInput,
+    ) -> RusotoFuture<DescribeServiceActionOutput, DescribeServiceActionError>;
+
+    fn describe_service_action_execution_parameters(
+        &self,
+        input: DescribeServiceActionExecutionParametersInput,
+    ) -> RusotoFuture<
+        DescribeServiceActionExecutionParametersOutput,
+        DescribeServiceActionExecutionParametersError,
+    >;
+
+    /// <p>Gets information about the specified TagOption.</p>
+    fn describe_tag_option(
+        &self,
+        input: DescribeTagOptionInput,
+    ) -> RusotoFuture<DescribeTagOptionOutput, DescribeTagOptionError>;
+
+    /// <p>Disable portfolio sharing through AWS Organizations feature. This feature will not delete your current shares but it will prevent you from creating new shares throughout your organization. Current shares will not be in sync with your organization structure if it changes after calling this API. This API can only be called by the master account in the organization.</p>
+    fn disable_aws_organizations_access(
+        &self,
+    ) -> RusotoFuture<DisableAWSOrganizationsAccessOutput, DisableAWSOrganizationsAccessError>;
+
+    /// <p>Disassociates the specified budget from the specified resource.</p>
+    fn disassociate_budget_from_resource(
+        &self,
+        input: DisassociateBudgetFromResourceInput,
+    ) -> RusotoFuture<DisassociateBudgetFromResourceOutput, DisassociateBudgetFromResourceError>;
+
+    /// <p>Disassociates a previously associated principal ARN from a specified portfolio.</p>
+    fn disassociate_principal_from_portfolio(
+        &self,
+        input: DisassociatePrincipalFromPortfolioInput,
+    ) -> RusotoFuture<
+        DisassociatePrincipalFromPortfolioOutput,
+        DisassociatePrincipalFromPortfolioError,
+    >;
+
+    /// <p>Disassociates the specified product from the specified portfolio. </p>
+    fn disassociate_product_from_portfolio(
+        &self,
+        input: DisassociateProductFromPortfolioInput,
+    ) -> RusotoFuture<DisassociateProductFromPortfolioOutput, DisassociateProductFromPortfolioError>;
+
+    /// <p>Disassociates the specified self-service action association from the specified provisioning artifact.</p>
+    fn disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: DisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        DisassociateServiceActionFromProvisioningArtifactOutput,
+        DisassociateServiceActionFromProvisioningArtifactError,
+    >;
+
+    /// <p>Disassociates the specified TagOption from the specified resource.</p>
+    fn disassociate_tag_option_from_resource(
+        &self,
+        input: DisassociateTagOptionFromResourceInput,
+    ) -> RusotoFuture<DisassociateTagOptionFromResourceOutput, DisassociateTagOptionFromResourceError>;
+
+    /// <p>Enable portfolio sharing feature through AWS Organizations. This API will allow Service Catalog to receive updates on your organization in order to sync your shares with the current structure. This API can only be called by the master account in the organization.</p> <p>By calling this API Service Catalog will make a call to organizations:EnableAWSServiceAccess on your behalf so that your shares can be in sync with any changes in your AWS Organizations structure.</p>
+    fn enable_aws_organizations_access(
+        &self,
+    ) -> RusotoFuture<EnableAWSOrganizationsAccessOutput, EnableAWSOrganizationsAccessError>;
+
+    /// <p>Provisions or modifies a product based on the resource changes for the specified plan.</p>
+    fn execute_provisioned_product_plan(
+        &self,
+        input: ExecuteProvisionedProductPlanInput,
+    ) -> RusotoFuture<ExecuteProvisionedProductPlanOutput, ExecuteProvisionedProductPlanError>;
+
+    /// <p>Executes a self-service action against a provisioned product.</p>
+    fn execute_provisioned_product_service_action(
+        &self,
+        input: ExecuteProvisionedProductServiceActionInput,
+    ) -> RusotoFuture<
+        ExecuteProvisionedProductServiceActionOutput,
+        ExecuteProvisionedProductServiceActionError,
+    >;
+
+    /// <p>Get the Access Status for AWS Organization portfolio share feature. This API can only be called by the master account in the organization.</p>
+    fn get_aws_organizations_access_status(
+        &self,
+    ) -> RusotoFuture<GetAWSOrganizationsAccessStatusOutput, GetAWSOrganizationsAccessStatusError>;
+
+    /// <p>Lists all portfolios for which sharing was accepted by this account.</p>
+    fn list_accepted_portfolio_shares(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> RusotoFuture<ListAcceptedPortfolioSharesOutput, ListAcceptedPortfolioSharesError>;
+
+    /// <p>Lists all the budgets associated to the specified resource.</p>
+    fn list_budgets_for_resource(
+        &self,
+        input: ListBudgetsForResourceInput,
+    ) -> RusotoFuture<ListBudgetsForResourceOutput, ListBudgetsForResourceError>;
+
+    /// <p>Lists the constraints for the specified portfolio and product.</p>
+    fn list_constraints_for_portfolio(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> RusotoFuture<ListConstraintsForPortfolioOutput, ListConstraintsForPortfolioError>;
+
+    /// <p>Lists the paths to the specified product. A path is how the user has access to a specified product, and is necessary when provisioning a product. A path also determines the constraints put on the product.</p>
+    fn list_launch_paths(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> RusotoFuture<ListLaunchPathsOutput, ListLaunchPathsError>;
+
+    /// <p>Lists the organization nodes that have access to the specified portfolio. This API can only be called by the master account in the organization.</p>
+    fn list_organization_portfolio_access(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> RusotoFuture<ListOrganizationPortfolioAccessOutput, ListOrganizationPortfolioAccessError>;
+
+    /// <p>Lists the account IDs that have access to the specified portfolio.</p>
+    fn list_portfolio_access(
+        &self,
+        input: ListPortfolioAccessInput,
+    ) -> RusotoFuture<ListPortfolioAccessOutput, ListPortfolioAccessError>;
+
+    /// <p>Lists all portfolios in the catalog.</p>
+    fn list_portfolios(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> RusotoFuture<ListPortfoliosOutput, ListPortfoliosError>;
+
+    /// <p>Lists all portfolios that the specified product is associated with.</p>
+    fn list_portfolios_for_product(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> RusotoFuture<ListPortfoliosForProductOutput, ListPortfoliosForProductError>;
+
+    /// <p>Lists all principal ARNs associated with the specified portfolio.</p>
+    fn list_principals_for_portfolio(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> RusotoFuture<ListPrincipalsForPortfolioOutput, ListPrincipalsForPortfolioError>;
+
+    /// <p>Lists the plans for the specified provisioned product or all plans to which the user has access.</p>
+    fn list_provisioned_product_plans(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> RusotoFuture<ListProvisionedProductPlansOutput, ListProvisionedProductPlansError>;
+
+    /// <p>Lists all provisioning artifacts (also known as versions) for the specified product.</p>
+    fn list_provisioning_artifacts(
+        &self,
+        input: ListProvisioningArtifactsInput,
+    ) -> RusotoFuture<ListProvisioningArtifactsOutput, ListProvisioningArtifactsError>;
+
+    /// <p>Lists all provisioning artifacts (also known as versions) for the specified self-service action.</p>
+    fn list_provisioning_artifacts_for_service_action(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> RusotoFuture<
+        ListProvisioningArtifactsForServiceActionOutput,
+        ListProvisioningArtifactsForServiceActionError,
+    >;
+
+    /// <p>Lists the specified requests or all performed requests.</p>
+    fn list_record_history(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> RusotoFuture<ListRecordHistoryOutput, ListRecordHistoryError>;
+
+    /// <p>Lists the resources associated with the specified TagOption.</p>
+    fn list_resources_for_tag_option(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> RusotoFuture<ListResourcesForTagOptionOutput, ListResourcesForTagOptionError>;
+
+    /// <p>Lists all self-service actions.</p>
+    fn list_service_actions(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> RusotoFuture<ListServiceActionsOutput, ListServiceActionsError>;
+
+    /// <p>Returns a paginated list of self-service actions associated with the specified Product ID and Provisioning Artifact ID.</p>
+    fn list_service_actions_for_provisioning_artifact(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        ListServiceActionsForProvisioningArtifactOutput,
+        ListServiceActionsForProvisioningArtifactError,
+    >;
+
+    /// <p>Returns summary information about stack instances that are associated with the specified <code>CFN_STACKSET</code> type provisioned product. You can filter for stack instances that are associated with a specific AWS account name or region. </p>
+    fn list_stack_instances_for_provisioned_product(
+        &self,
+        input: ListStackInstancesForProvisionedProductInput,
+    ) -> RusotoFuture<
+        ListStackInstancesForProvisionedProductOutput,
+        ListStackInstancesForProvisionedProductError,
+    >;
+
+    /// <p>Lists the specified TagOptions or all TagOptions.</p>
+    fn list_tag_options(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> RusotoFuture<ListTagOptionsOutput, ListTagOptionsError>;
+
+    /// <p>Provisions the specified product.</p> <p>A provisioned product is a resourced instance of a product. For example, provisioning a product based on a CloudFormation template launches a CloudFormation stack and its underlying resources. You can check the status of this request using <a>DescribeRecord</a>.</p> <p>If the request contains a tag key with an empty list of values, there is a tag conflict for that key. Do not include conflicted keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>".</p>
+    fn provision_product(
+        &self,
+        input: ProvisionProductInput,
+    ) -> RusotoFuture<ProvisionProductOutput, ProvisionProductError>;
+
+    /// <p>Rejects an offer to share the specified portfolio.</p>
+    fn reject_portfolio_share(
+        &self,
+        input: RejectPortfolioShareInput,
+    ) -> RusotoFuture<RejectPortfolioShareOutput, RejectPortfolioShareError>;
+
+    /// <p>Lists the provisioned products that are available (not terminated).</p> <p>To use additional filtering, see <a>SearchProvisionedProducts</a>.</p>
+    fn scan_provisioned_products(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> RusotoFuture<ScanProvisionedProductsOutput, ScanProvisionedProductsError>;
+
+    /// <p>Gets information about the products to which the caller has access.</p>
+    fn search_products(
+        &self,
+        input: SearchProductsInput,
+    ) -> RusotoFuture<SearchProductsOutput, SearchProductsError>;
+
+    /// <p>Gets information about the products for the specified portfolio or all products.</p>
+    fn search_products_as_admin(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> RusotoFuture<SearchProductsAsAdminOutput, SearchProductsAsAdminError>;
+
+    /// <p>Gets information about the provisioned products that meet the specified criteria.</p>
+    fn search_provisioned_products(
+        &self,
+        input: SearchProvisionedProductsInput,
+    ) -> RusotoFuture<SearchProvisionedProductsOutput, SearchProvisionedProductsError>;
+
+    /// <p>Terminates the specified provisioned product.</p> <p>This operation does not delete any records associated with the provisioned product.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
+    fn terminate_provisioned_product(
+        &self,
+        input: TerminateProvisionedProductInput,
+    ) -> RusotoFuture<TerminateProvisionedProductOutput, TerminateProvisionedProductError>;
+
+    /// <p>Updates the specified constraint.</p>
+    fn update_constraint(
+        &self,
+        input: UpdateConstraintInput,
+    ) -> RusotoFuture<UpdateConstraintOutput, UpdateConstraintError>;
+
+    /// <p>Updates the specified portfolio.</p> <p>You cannot update a product that was shared with you.</p>
+    fn update_portfolio(
+        &self,
+        input: UpdatePortfolioInput,
+    ) -> RusotoFuture<UpdatePortfolioOutput, UpdatePortfolioError>;
+
+    /// <p>Updates the specified product.</p>
+    fn update_product(
+        &self,
+        input: UpdateProductInput,
+    ) -> RusotoFuture<UpdateProductOutput, UpdateProductError>;
+
+    /// <p>Requests updates to the configuration of the specified provisioned product.</p> <p>If there are tags associated with the object, they cannot be updated or added. Depending on the specific updates requested, this operation can update with no interruption, with some interruption, or replace the provisioned product entirely.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
+    fn update_provisioned_product(
+        &self,
+        input: UpdateProvisionedProductInput,
+    ) -> RusotoFuture<UpdateProvisionedProductOutput, UpdateProvisionedProductError>;
+
+    /// <p>Requests updates to the properties of the specified provisioned product.</p>
+    fn update_provisioned_product_properties(
+        &self,
+        input: UpdateProvisionedProductPropertiesInput,
+    ) -> RusotoFuture<
+        UpdateProvisionedProductPropertiesOutput,
+        UpdateProvisionedProductPropertiesError,
+    >;
+
+    /// <p>Updates the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot update a provisioning artifact for a product that was shared with you.</p>
+    fn update_provisioning_artifact(
+        &self,
+        input: UpdateProvisioningArtifactInput,
+    ) -> RusotoFuture<UpdateProvisioningArtifactOutput, UpdateProvisioningArtifactError>;
+
+    /// <p>Updates a self-service action.</p>
+    fn update_service_action(
+        &self,
+        input: UpdateServiceActionInput,
+    ) -> RusotoFuture<UpdateServiceActionOutput, UpdateServiceActionError>;
+
+    /// <p>Updates the specified TagOption.</p>
+    fn update_tag_option(
+        &self,
+        input: UpdateTagOptionInput,
+    ) -> RusotoFuture<UpdateTagOptionOutput, UpdateTagOptionError>;
 }
-/// Trait representing the capabilities of the AWS Service Catalog API. AWS Service Catalog clients implement this trait.
-pub trait ServiceCatalog {
-    /// <p>Accepts an offer to share the specified portfolio.</p>
+impl<T: ?Sized + ServiceCatalog> ServiceCatalog for ::std::sync::Arc<T> {
     fn accept_portfolio_share(
         &self,
         input: AcceptPortfolioShareInput,
-    ) -> RusotoFuture<AcceptPortfolioShareOutput, AcceptPortfolioShareError>;
-
-    /// <p>Associates the specified budget with the specified resource.</p>
+    ) -> RusotoFuture<AcceptPortfolioShareOutput, AcceptPortfolioShareError> {
+        (**self).accept_portfolio_share(input)
+    }
     fn associate_budget_with_resource(
         &self,
         input: AssociateBudgetWithResourceInput,
-    ) -> RusotoFuture<AssociateBudgetWithResourceOutput, AssociateBudgetWithResourceError>;
-
-    /// <p>Associates the specified principal ARN with the specified portfolio.</p>
+    ) -> RusotoFuture<AssociateBudgetWithResourceOutput, AssociateBudgetWithResourceError> {
+        (**self).associate_budget_with_resource(input)
+    }
     fn associate_principal_with_portfolio(
         &self,
         input: AssociatePrincipalWithPortfolioInput,
-    ) -> RusotoFuture<AssociatePrincipalWithPortfolioOutput, AssociatePrincipalWithPortfolioError>;
-
-    /// <p>Associates the specified product with the specified portfolio.</p>
+    ) -> RusotoFuture<AssociatePrincipalWithPortfolioOutput, AssociatePrincipalWithPortfolioError>
+    {
+        (**self).associate_principal_with_portfolio(input)
+    }
     fn associate_product_with_portfolio(
         &self,
         input: AssociateProductWithPortfolioInput,
-    ) -> RusotoFuture<AssociateProductWithPortfolioOutput, AssociateProductWithPortfolioError>;
-
-    /// <p>Associates a self-service action with a provisioning artifact.</p>
+    ) -> RusotoFuture<AssociateProductWithPortfolioOutput, AssociateProductWithPortfolioError> {
+        (**self).associate_product_with_portfolio(input)
+    }
     fn associate_service_action_with_provisioning_artifact(
         &self,
         input: AssociateServiceActionWithProvisioningArtifactInput,
     ) -> RusotoFuture<
         AssociateServiceActionWithProvisioningArtifactOutput,
         AssociateServiceActionWithProvisioningArtifactError,
-    >;
-
-    /// <p>Associate the specified TagOption with the specified portfolio or product.</p>
+    > {
+        (**self).associate_service_action_with_provisioning_artifact(input)
+    }
     fn associate_tag_option_with_resource(
         &self,
         input: AssociateTagOptionWithResourceInput,
-    ) -> RusotoFuture<AssociateTagOptionWithResourceOutput, AssociateTagOptionWithResourceError>;
-
-    /// <p>Associates multiple self-service actions with provisioning artifacts.</p>
+    ) -> RusotoFuture<AssociateTagOptionWithResourceOutput, AssociateTagOptionWithResourceError>
+    {
+        (**self).associate_tag_option_with_resource(input)
+    }
     fn batch_associate_service_action_with_provisioning_artifact(
         &self,
         input: BatchAssociateServiceActionWithProvisioningArtifactInput,
     ) -> RusotoFuture<
         BatchAssociateServiceActionWithProvisioningArtifactOutput,
         BatchAssociateServiceActionWithProvisioningArtifactError,
-    >;
-
-    /// <p>Disassociates a batch of self-service actions from the specified provisioning artifact.</p>
+    > {
+        (**self).batch_associate_service_action_with_provisioning_artifact(input)
+    }
     fn batch_disassociate_service_action_from_provisioning_artifact(
         &self,
         input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
     ) -> RusotoFuture<
         BatchDisassociateServiceActionFromProvisioningArtifactOutput,
         BatchDisassociateServiceActionFromProvisioningArtifactError,
-    >;
-
-    /// <p>Copies the specified source product to the specified target product or a new product.</p> <p>You can copy a product to the same account or another account. You can copy a product to the same region or another region.</p> <p>This operation is performed asynchronously. To track the progress of the operation, use <a>DescribeCopyProductStatus</a>.</p>
+    > {
+        (**self).batch_disassociate_service_action_from_provisioning_artifact(input)
+    }
     fn copy_product(
         &self,
         input: CopyProductInput,
-    ) -> RusotoFuture<CopyProductOutput, CopyProductError>;
-
-    /// <p>Creates a constraint.</p>
+    ) -> RusotoFuture<CopyProductOutput, CopyProductError> {
+        (**self).copy_product(input)
+    }
     fn create_constraint(
         &self,
         input: CreateConstraintInput,
-    ) -> RusotoFuture<CreateConstraintOutput, CreateConstraintError>;
-
-    /// <p>Creates a portfolio.</p>
+    ) -> RusotoFuture<CreateConstraintOutput, CreateConstraintError> {
+        (**self).create_constraint(input)
+    }
     fn create_portfolio(
         &self,
         input: CreatePortfolioInput,
-    ) -> RusotoFuture<CreatePortfolioOutput, CreatePortfolioError>;
-
-    /// <p>Shares the specified portfolio with the specified account or organization node. Shares to an organization node can only be created by the master account of an Organization. AWSOrganizationsAccess must be enabled in order to create a portfolio share to an organization node.</p>
+    ) -> RusotoFuture<CreatePortfolioOutput, CreatePortfolioError> {
+        (**self).create_portfolio(input)
+    }
     fn create_portfolio_share(
         &self,
         input: CreatePortfolioShareInput,
-    ) -> RusotoFuture<CreatePortfolioShareOutput, CreatePortfolioShareError>;
-
-    /// <p>Creates a product.</p>
+    ) -> RusotoFuture<CreatePortfolioShareOutput, CreatePortfolioShareError> {
+        (**self).create_portfolio_share(input)
+    }
     fn create_product(
         &self,
         input: CreateProductInput,
-    ) -> RusotoFuture<CreateProductOutput, CreateProductError>;
-
-    /// <p>Creates a plan. A plan includes the list of resources to be created (when provisioning a new product) or modified (when updating a provisioned product) when the plan is executed.</p> <p>You can create one plan per provisioned product. To create a plan for an existing provisioned product, the product status must be AVAILBLE or TAINTED.</p> <p>To view the resource changes in the change set, use <a>DescribeProvisionedProductPlan</a>. To create or modify the provisioned product, use <a>ExecuteProvisionedProductPlan</a>.</p>
+    ) -> RusotoFuture<CreateProductOutput, CreateProductError> {
+        (**self).create_product(input)
+    }
     fn create_provisioned_product_plan(
         &self,
         input: CreateProvisionedProductPlanInput,
-    ) -> RusotoFuture<CreateProvisionedProductPlanOutput, CreateProvisionedProductPlanError>;
-
-    /// <p>Creates a provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot create a provisioning artifact for a product that was shared with you.</p>
+    ) -> RusotoFuture<CreateProvisionedProductPlanOutput, CreateProvisionedProductPlanError> {
+        (**self).create_provisioned_product_plan(input)
+    }
     fn create_provisioning_artifact(
         &self,
         input: CreateProvisioningArtifactInput,
-    ) -> RusotoFuture<CreateProvisioningArtifactOutput, CreateProvisioningArtifactError>;
-
-    /// <p>Creates a self-service action.</p>
+    ) -> RusotoFuture<CreateProvisioningArtifactOutput, CreateProvisioningArtifactError> {
+        (**self).create_provisioning_artifact(input)
+    }
     fn create_service_action(
         &self,
         input: CreateServiceActionInput,
-    ) -> RusotoFuture<CreateServiceActionOutput, CreateServiceActionError>;
-
-    /// <p>Creates a TagOption.</p>
+    ) -> RusotoFuture<CreateServiceActionOutput, CreateServiceActionError> {
+        (**self).create_service_action(input)
+    }
     fn create_tag_option(
         &self,
         input: CreateTagOptionInput,
-    ) -> RusotoFuture<CreateTagOptionOutput, CreateTagOptionError>;
-
-    /// <p>Deletes the specified constraint.</p>
+    ) -> RusotoFuture<CreateTagOptionOutput, CreateTagOptionError> {
+        (**self).create_tag_option(input)
+    }
     fn delete_constraint(
         &self,
         input: DeleteConstraintInput,
-    ) -> RusotoFuture<DeleteConstraintOutput, DeleteConstraintError>;
-
-    /// <p>Deletes the specified portfolio.</p> <p>You cannot delete a portfolio if it was shared with you or if it has associated products, users, constraints, or shared accounts.</p>
+    ) -> RusotoFuture<DeleteConstraintOutput, DeleteConstraintError> {
+        (**self).delete_constraint(input)
+    }
     fn delete_portfolio(
         &self,
         input: DeletePortfolioInput,
-    ) -> RusotoFuture<DeletePortfolioOutput, DeletePortfolioError>;
-
-    /// <p>Stops sharing the specified portfolio with the specified account or organization node. Shares to an organization node can only be deleted by the master account of an Organization.</p>
+    ) -> RusotoFuture<DeletePortfolioOutput, DeletePortfolioError> {
+        (**self).delete_portfolio(input)
+    }
     fn delete_portfolio_share(
         &self,
         input: DeletePortfolioShareInput,
-    ) -> RusotoFuture<DeletePortfolioShareOutput, DeletePortfolioShareError>;
-
-    /// <p>Deletes the specified product.</p> <p>You cannot delete a product if it was shared with you or is associated with a portfolio.</p>
+    ) -> RusotoFuture<DeletePortfolioShareOutput, DeletePortfolioShareError> {
+        (**self).delete_portfolio_share(input)
+    }
     fn delete_product(
         &self,
         input: DeleteProductInput,
-    ) -> RusotoFuture<DeleteProductOutput, DeleteProductError>;
-
-    /// <p>Deletes the specified plan.</p>
+    ) -> RusotoFuture<DeleteProductOutput, DeleteProductError> {
+        (**self).delete_product(input)
+    }
     fn delete_provisioned_product_plan(
         &self,
         input: DeleteProvisionedProductPlanInput,
-    ) -> RusotoFuture<DeleteProvisionedProductPlanOutput, DeleteProvisionedProductPlanError>;
-
-    /// <p>Deletes the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot delete a provisioning artifact associated with a product that was shared with you. You cannot delete the last provisioning artifact for a product, because a product must have at least one provisioning artifact.</p>
+    ) -> RusotoFuture<DeleteProvisionedProductPlanOutput, DeleteProvisionedProductPlanError> {
+        (**self).delete_provisioned_product_plan(input)
+    }
     fn delete_provisioning_artifact(
         &self,
         input: DeleteProvisioningArtifactInput,
-    ) -> RusotoFuture<DeleteProvisioningArtifactOutput, DeleteProvisioningArtifactError>;
-
-    /// <p>Deletes a self-service action.</p>
+    ) -> RusotoFuture<DeleteProvisioningArtifactOutput, DeleteProvisioningArtifactError> {
+        (**self).delete_provisioning_artifact(input)
+    }
     fn delete_service_action(
         &self,
         input: DeleteServiceActionInput,
-    ) -> RusotoFuture<DeleteServiceActionOutput, DeleteServiceActionError>;
-
-    /// <p>Deletes the specified TagOption.</p> <p>You cannot delete a TagOption if it is associated with a product or portfolio.</p>
+    ) -> RusotoFuture<DeleteServiceActionOutput, DeleteServiceActionError> {
+        (**self).delete_service_action(input)
+    }
     fn delete_tag_option(
         &self,
         input: DeleteTagOptionInput,
-    ) -> RusotoFuture<DeleteTagOptionOutput, DeleteTagOptionError>;
-
-    /// <p>Gets information about the specified constraint.</p>
+    ) -> RusotoFuture<DeleteTagOptionOutput, DeleteTagOptionError> {
+        (**self).delete_tag_option(input)
+    }
     fn describe_constraint(
         &self,
         input: DescribeConstraintInput,
-    ) -> RusotoFuture<DescribeConstraintOutput, DescribeConstraintError>;
-
-    /// <p>Gets the status of the specified copy product operation.</p>
+    ) -> RusotoFuture<DescribeConstraintOutput, DescribeConstraintError> {
+        (**self).describe_constraint(input)
+    }
     fn describe_copy_product_status(
         &self,
         input: DescribeCopyProductStatusInput,
-    ) -> RusotoFuture<DescribeCopyProductStatusOutput, DescribeCopyProductStatusError>;
-
-    /// <p>Gets information about the specified portfolio.</p>
+    ) -> RusotoFuture<DescribeCopyProductStatusOutput, DescribeCopyProductStatusError> {
+        (**self).describe_copy_product_status(input)
+    }
     fn describe_portfolio(
         &self,
         input: DescribePortfolioInput,
-    ) -> RusotoFuture<DescribePortfolioOutput, DescribePortfolioError>;
-
-    /// <p>Gets the status of the specified portfolio share operation. This API can only be called by the master account in the organization.</p>
+    ) -> RusotoFuture<DescribePortfolioOutput, DescribePortfolioError> {
+        (**self).describe_portfolio(input)
+    }
     fn describe_portfolio_share_status(
         &self,
         input: DescribePortfolioShareStatusInput,
-    ) -> RusotoFuture<DescribePortfolioShareStatusOutput, DescribePortfolioShareStatusError>;
-
-    /// <p>Gets information about the specified product.</p>
+    ) -> RusotoFuture<DescribePortfolioShareStatusOutput, DescribePortfolioShareStatusError> {
+        (**self).describe_portfolio_share_status(input)
+    }
     fn describe_product(
         &self,
         input: DescribeProductInput,
-    ) -> RusotoFuture<DescribeProductOutput, DescribeProductError>;
-
-    /// <p>Gets information about the specified product. This operation is run with administrator access.</p>
+    ) -> RusotoFuture<DescribeProductOutput, DescribeProductError> {
+        (**self).describe_product(input)
+    }
     fn describe_product_as_admin(
         &self,
         input: DescribeProductAsAdminInput,
-    ) -> RusotoFuture<DescribeProductAsAdminOutput, DescribeProductAsAdminError>;
-
-    /// <p>Gets information about the specified product.</p>
+    ) -> RusotoFuture<DescribeProductAsAdminOutput, DescribeProductAsAdminError> {
+        (**self).describe_product_as_admin(input)
+    }
     fn describe_product_view(
         &self,
         input: DescribeProductViewInput,
-    ) -> RusotoFuture<DescribeProductViewOutput, DescribeProductViewError>;
-
-    /// <p>Gets information about the specified provisioned product.</p>
+    ) -> RusotoFuture<DescribeProductViewOutput, DescribeProductViewError> {
+        (**self).describe_product_view(input)
+    }
     fn describe_provisioned_product(
         &self,
         input: DescribeProvisionedProductInput,
-    ) -> RusotoFuture<DescribeProvisionedProductOutput, DescribeProvisionedProductError>;
-
-    /// <p>Gets information about the resource changes for the specified plan.</p>
+    ) -> RusotoFuture<DescribeProvisionedProductOutput, DescribeProvisionedProductError> {
+        (**self).describe_provisioned_product(input)
+    }
     fn describe_provisioned_product_plan(
         &self,
         input: DescribeProvisionedProductPlanInput,
-    ) -> RusotoFuture<DescribeProvisionedProductPlanOutput, DescribeProvisionedProductPlanError>;
-
-    /// <p>Gets information about the specified provisioning artifact (also known as a version) for the specified product.</p>
+    ) -> RusotoFuture<DescribeProvisionedProductPlanOutput, DescribeProvisionedProductPlanError>
+    {
+        (**self).describe_provisioned_product_plan(input)
+    }
     fn describe_provisioning_artifact(
         &self,
         input: DescribeProvisioningArtifactInput,
-    ) -> RusotoFuture<DescribeProvisioningArtifactOutput, DescribeProvisioningArtifactError>;
-
-    /// <p>Gets information about the configuration required to provision the specified product using the specified provisioning artifact.</p> <p>If the output contains a TagOption key with an empty list of values, there is a TagOption conflict for that key. The end user cannot take action to fix the conflict, and launch is not blocked. In subsequent calls to <a>ProvisionProduct</a>, do not include conflicted TagOption keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>". Tag the provisioned product with the value <code>sc-tagoption-conflict-portfolioId-productId</code>.</p>
+    ) -> RusotoFuture<DescribeProvisioningArtifactOutput, DescribeProvisioningArtifactError> {
+        (**self).describe_provisioning_artifact(input)
+    }
     fn describe_provisioning_parameters(
         &self,
         input: DescribeProvisioningParametersInput,
-    ) -> RusotoFuture<DescribeProvisioningParametersOutput, DescribeProvisioningParametersError>;
-
-    /// <p><p>Gets information about the specified request operation.</p> <p>Use this operation after calling a request operation (for example, <a>ProvisionProduct</a>, <a>TerminateProvisionedProduct</a>, or <a>UpdateProvisionedProduct</a>). </p> <note> <p>If a provisioned product was transferred to a new owner using <a>UpdateProvisionedProductProperties</a>, the new owner will be able to describe all past records for that product. The previous owner will no longer be able to describe the records, but will be able to use <a>ListRecordHistory</a> to see the product&#39;s history from when he was the owner.</p> </note></p>
+    ) -> RusotoFuture<DescribeProvisioningParametersOutput, DescribeProvisioningParametersError>
+    {
+        (**self).describe_provisioning_parameters(input)
+    }
     fn describe_record(
         &self,
         input: DescribeRecordInput,
-    ) -> RusotoFuture<DescribeRecordOutput, DescribeRecordError>;
-
-    /// <p>Describes a self-service action.</p>
+    ) -> RusotoFuture<DescribeRecordOutput, DescribeRecordError> {
+        (**self).describe_record(input)
+    }
     fn describe_service_action(
         &self,
         input: DescribeServiceActionInput,
-    ) -> RusotoFuture<DescribeServiceActionOutput, DescribeServiceActionError>;
-
+    ) -> RusotoFuture<DescribeServiceActionOutput, DescribeServiceActionError> {
+        (**self).describe_service_action(input)
+    }
     fn describe_service_action_execution_parameters(
         &self,
         input: DescribeServiceActionExecutionParametersInput,
     ) -> RusotoFuture<
-        DescribeServiceActionExecutionParametersOutput,
-        DescribeServiceActionExecutionParametersError,
-    >;
-
-    /// <p>Gets information about the specified TagOption.</p>
+        DescribeServiceActionExecutionParametersOutput,
+        DescribeServiceActionExecutionParametersError,
+    > {
+        (**self).describe_service_action_execution_parameters(input)
+    }
     fn describe_tag_option(
         &self,
         input: DescribeTagOptionInput,
-    ) -> RusotoFuture<DescribeTagOptionOutput, DescribeTagOptionError>;
-
-    /// <p>Disable portfolio sharing through AWS Organizations feature. This feature will not delete your current shares but it will prevent you from creating new shares throughout your organization. Current shares will not be in sync with your organization structure if it changes after calling this API. This API can only be called by the master account in the organization.</p>
+    ) -> RusotoFuture<DescribeTagOptionOutput, DescribeTagOptionError> {
+        (**self).describe_tag_option(input)
+    }
     fn disable_aws_organizations_access(
         &self,
-    ) -> RusotoFuture<DisableAWSOrganizationsAccessOutput, DisableAWSOrganizationsAccessError>;
-
-    /// <p>Disassociates the specified budget from the specified resource.</p>
+    ) -> RusotoFuture<DisableAWSOrganizationsAccessOutput, DisableAWSOrganizationsAccessError> {
+        (**self).disable_aws_organizations_access()
+    }
     fn disassociate_budget_from_resource(
         &self,
         input: DisassociateBudgetFromResourceInput,
-    ) -> RusotoFuture<DisassociateBudgetFromResourceOutput, DisassociateBudgetFromResourceError>;
-
-    /// <p>Disassociates a previously associated principal ARN from a specified portfolio.</p>
+    ) -> RusotoFuture<DisassociateBudgetFromResourceOutput, DisassociateBudgetFromResourceError>
+    {
+        (**self).disassociate_budget_from_resource(input)
+    }
     fn disassociate_principal_from_portfolio(
         &self,
         input: DisassociatePrincipalFromPortfolioInput,
     ) -> RusotoFuture<
         DisassociatePrincipalFromPortfolioOutput,
         DisassociatePrincipalFromPortfolioError,
-    >;
-
-    /// <p>Disassociates the specified product from the specified portfolio. </p>
+    > {
+        (**self).disassociate_principal_from_portfolio(input)
+    }
     fn disassociate_product_from_portfolio(
         &self,
         input: DisassociateProductFromPortfolioInput,
-    ) -> RusotoFuture<DisassociateProductFromPortfolioOutput, DisassociateProductFromPortfolioError>;
-
-    /// <p>Disassociates the specified self-service action association from the specified provisioning artifact.</p>
+    ) -> RusotoFuture<DisassociateProductFromPortfolioOutput, DisassociateProductFromPortfolioError>
+    {
+        (**self).disassociate_product_from_portfolio(input)
+    }
     fn disassociate_service_action_from_provisioning_artifact(
         &self,
         input: DisassociateServiceActionFromProvisioningArtifactInput,
     ) -> RusotoFuture<
         DisassociateServiceActionFromProvisioningArtifactOutput,
         DisassociateServiceActionFromProvisioningArtifactError,
-    >;
-
-    /// <p>Disassociates the specified TagOption from the specified resource.</p>
+    > {
+        (**self).disassociate_service_action_from_provisioning_artifact(input)
+    }
     fn disassociate_tag_option_from_resource(
         &self,
         input: DisassociateTagOptionFromResourceInput,
-    ) -> RusotoFuture<DisassociateTagOptionFromResourceOutput, DisassociateTagOptionFromResourceError>;
-
-    /// <p>Enable portfolio sharing feature through AWS Organizations. This API will allow Service Catalog to receive updates on your organization in order to sync your shares with the current structure. This API can only be called by the master account in the organization.</p> <p>By calling this API Service Catalog will make a call to organizations:EnableAWSServiceAccess on your behalf so that your shares can be in sync with any changes in your AWS Organizations structure.</p>
+    ) -> RusotoFuture<DisassociateTagOptionFromResourceOutput, DisassociateTagOptionFromResourceError>
+    {
+        (**self).disassociate_tag_option_from_resource(input)
+    }
     fn enable_aws_organizations_access(
         &self,
-    ) -> RusotoFuture<EnableAWSOrganizationsAccessOutput, EnableAWSOrganizationsAccessError>;
-
-    /// <p>Provisions or modifies a product based on the resource changes for the specified plan.</p>
+    ) -> RusotoFuture<EnableAWSOrganizationsAccessOutput, EnableAWSOrganizationsAccessError> {
+        (**self).enable_aws_organizations_access()
+    }
     fn execute_provisioned_product_plan(
         &self,
         input: ExecuteProvisionedProductPlanInput,
-    ) -> RusotoFuture<ExecuteProvisionedProductPlanOutput, ExecuteProvisionedProductPlanError>;
-
-    /// <p>Executes a self-service action against a provisioned product.</p>
+    ) -> RusotoFuture<ExecuteProvisionedProductPlanOutput, ExecuteProvisionedProductPlanError> {
+        (**self).execute_provisioned_product_plan(input)
+    }
     fn execute_provisioned_product_service_action(
         &self,
         input: ExecuteProvisionedProductServiceActionInput,
     ) -> RusotoFuture<
         ExecuteProvisionedProductServiceActionOutput,
         ExecuteProvisionedProductServiceActionError,
-    >;
-
-    /// <p>Get the Access Status for AWS Organization portfolio share feature. This API can only be called by the master account in the organization.</p>
+    > {
+        (**self).execute_provisioned_product_service_action(input)
+    }
     fn get_aws_organizations_access_status(
         &self,
-    ) -> RusotoFuture<GetAWSOrganizationsAccessStatusOutput, GetAWSOrganizationsAccessStatusError>;
-
-    /// <p>Lists all portfolios for which sharing was accepted by this account.</p>
+    ) -> RusotoFuture<GetAWSOrganizationsAccessStatusOutput, GetAWSOrganizationsAccessStatusError>
+    {
+        (**self).get_aws_organizations_access_status()
+    }
     fn list_accepted_portfolio_shares(
         &self,
         input: ListAcceptedPortfolioSharesInput,
-    ) -> RusotoFuture<ListAcceptedPortfolioSharesOutput, ListAcceptedPortfolioSharesError>;
-
-    /// <p>Lists all the budgets associated to the specified resource.</p>
+    ) -> RusotoFuture<ListAcceptedPortfolioSharesOutput, ListAcceptedPortfolioSharesError> {
+        (**self).list_accepted_portfolio_shares(input)
+    }
     fn list_budgets_for_resource(
         &self,
         input: ListBudgetsForResourceInput,
-    ) -> RusotoFuture<ListBudgetsForResourceOutput, ListBudgetsForResourceError>;
-
-    /// <p>Lists the constraints for the specified portfolio and product.</p>
+    ) -> RusotoFuture<ListBudgetsForResourceOutput, ListBudgetsForResourceError> {
+        (**self).list_budgets_for_resource(input)
+    }
     fn list_constraints_for_portfolio(
         &self,
         input: ListConstraintsForPortfolioInput,
-    ) -> RusotoFuture<ListConstraintsForPortfolioOutput, ListConstraintsForPortfolioError>;
-
-    /// <p>Lists the paths to the specified product. A path is how the user has access to a specified product, and is necessary when provisioning a product. A path also determines the constraints put on the product.</p>
+    ) -> RusotoFuture<ListConstraintsForPortfolioOutput, ListConstraintsForPortfolioError> {
+        (**self).list_constraints_for_portfolio(input)
+    }
     fn list_launch_paths(
         &self,
         input: ListLaunchPathsInput,
-    ) -> RusotoFuture<ListLaunchPathsOutput, ListLaunchPathsError>;
-
-    /// <p>Lists the organization nodes that have access to the specified portfolio. This API can only be called by the master account in the organization.</p>
+    ) -> RusotoFuture<ListLaunchPathsOutput, ListLaunchPathsError> {
+        (**self).list_launch_paths(input)
+    }
     fn list_organization_portfolio_access(
         &self,
         input: ListOrganizationPortfolioAccessInput,
-    ) -> RusotoFuture<ListOrganizationPortfolioAccessOutput, ListOrganizationPortfolioAccessError>;
-
-    /// <p>Lists the account IDs that have access to the specified portfolio.</p>
+    ) -> RusotoFuture<ListOrganizationPortfolioAccessOutput, ListOrganizationPortfolioAccessError>
+    {
+        (**self).list_organization_portfolio_access(input)
+    }
     fn list_portfolio_access(
         &self,
         input: ListPortfolioAccessInput,
-    ) -> RusotoFuture<ListPortfolioAccessOutput, ListPortfolioAccessError>;
-
-    /// <p>Lists all portfolios in the catalog.</p>
+    ) -> RusotoFuture<ListPortfolioAccessOutput, ListPortfolioAccessError> {
+        (**self).list_portfolio_access(input)
+    }
     fn list_portfolios(
         &self,
         input: ListPortfoliosInput,
-    ) -> RusotoFuture<ListPortfoliosOutput, ListPortfoliosError>;
-
-    /// <p>Lists all portfolios that the specified product is associated with.</p>
+    ) -> RusotoFuture<ListPortfoliosOutput, ListPortfoliosError> {
+        (**self).list_portfolios(input)
+    }
     fn list_portfolios_for_product(
         &self,
         input: ListPortfoliosForProductInput,
-    ) -> RusotoFuture<ListPortfoliosForProductOutput, ListPortfoliosForProductError>;
-
-    /// <p>Lists all principal ARNs associated with the specified portfolio.</p>
+    ) -> RusotoFuture<ListPortfoliosForProductOutput, ListPortfoliosForProductError> {
+        (**self).list_portfolios_for_product(input)
+    }
     fn list_principals_for_portfolio(
         &self,
         input: ListPrincipalsForPortfolioInput,
-    ) -> RusotoFuture<ListPrincipalsForPortfolioOutput, ListPrincipalsForPortfolioError>;
-
-    /// <p>Lists the plans for the specified provisioned product or all plans to which the user has access.</p>
+    ) -> RusotoFuture<ListPrincipalsForPortfolioOutput, ListPrincipalsForPortfolioError> {
+        (**self).list_principals_for_portfolio(input)
+    }
     fn list_provisioned_product_plans(
         &self,
         input: ListProvisionedProductPlansInput,
-    ) -> RusotoFuture<ListProvisionedProductPlansOutput, ListProvisionedProductPlansError>;
-
-    /// <p>Lists all provisioning artifacts (also known as versions) for the specified product.</p>
+    ) -> RusotoFuture<ListProvisionedProductPlansOutput, ListProvisionedProductPlansError> {
+        (**self).list_provisioned_product_plans(input)
+    }
     fn list_provisioning_artifacts(
         &self,
         input: ListProvisioningArtifactsInput,
-    ) -> RusotoFuture<ListProvisioningArtifactsOutput, ListProvisioningArtifactsError>;
-
-    /// <p>Lists all provisioning artifacts (also known as versions) for the specified self-service action.</p>
+    ) -> RusotoFuture<ListProvisioningArtifactsOutput, ListProvisioningArtifactsError> {
+        (**self).list_provisioning_artifacts(input)
+    }
     fn list_provisioning_artifacts_for_service_action(
         &self,
         input: ListProvisioningArtifactsForServiceActionInput,
     ) -> RusotoFuture<
         ListProvisioningArtifactsForServiceActionOutput,
         ListProvisioningArtifactsForServiceActionError,
-    >;
-
-    /// <p>Lists the specified requests or all performed requests.</p>
+    > {
+        (**self).list_provisioning_artifacts_for_service_action(input)
+    }
     fn list_record_history(
         &self,
         input: ListRecordHistoryInput,
-    ) -> RusotoFuture<ListRecordHistoryOutput, ListRecordHistoryError>;
-
-    /// <p>Lists the resources associated with the specified TagOption.</p>
+    ) -> RusotoFuture<ListRecordHistoryOutput, ListRecordHistoryError> {
+        (**self).list_record_history(input)
+    }
     fn list_resources_for_tag_option(
         &self,
         input: ListResourcesForTagOptionInput,
-    ) -> RusotoFuture<ListResourcesForTagOptionOutput, ListResourcesForTagOptionError>;
-
-    /// <p>Lists all self-service actions.</p>
+    ) -> RusotoFuture<ListResourcesForTagOptionOutput, ListResourcesForTagOptionError> {
+        (**self).list_resources_for_tag_option(input)
+    }
     fn list_service_actions(
         &self,
         input: ListServiceActionsInput,
-    ) -> RusotoFuture<ListServiceActionsOutput, ListServiceActionsError>;
-
-    /// <p>Returns a paginated list of self-service actions associated with the specified Product ID and Provisioning Artifact ID.</p>
+    ) -> RusotoFuture<ListServiceActionsOutput, ListServiceActionsError> {
+        (**self).list_service_actions(input)
+    }
     fn list_service_actions_for_provisioning_artifact(
         &self,
         input: ListServiceActionsForProvisioningArtifactInput,
     ) -> RusotoFuture<
         ListServiceActionsForProvisioningArtifactOutput,
         ListServiceActionsForProvisioningArtifactError,
-    >;
-
-    /// <p>Returns summary information about stack instances that are associated with the specified <code>CFN_STACKSET</code> type provisioned product. You can filter for stack instances that are associated with a specific AWS account name or region. </p>
+    > {
+        (**self).list_service_actions_for_provisioning_artifact(input)
+    }
     fn list_stack_instances_for_provisioned_product(
         &self,
         input: ListStackInstancesForProvisionedProductInput,
     ) -> RusotoFuture<
         ListStackInstancesForProvisionedProductOutput,
         ListStackInstancesForProvisionedProductError,
-    >;
-
-    /// <p>Lists the specified TagOptions or all TagOptions.</p>
+    > {
+        (**self).list_stack_instances_for_provisioned_product(input)
+    }
     fn list_tag_options(
         &self,
         input: ListTagOptionsInput,
-    ) -> RusotoFuture<ListTagOptionsOutput, ListTagOptionsError>;
-
-    /// <p>Provisions the specified product.</p> <p>A provisioned product is a resourced instance of a product. For example, provisioning a product based on a CloudFormation template launches a CloudFormation stack and its underlying resources. You can check the status of this request using <a>DescribeRecord</a>.</p> <p>If the request contains a tag key with an empty list of values, there is a tag conflict for that key. Do not include conflicted keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>".</p>
+    ) -> RusotoFuture<ListTagOptionsOutput, ListTagOptionsError> {
+        (**self).list_tag_options(input)
+    }
     fn provision_product(
         &self,
         input: ProvisionProductInput,
-    ) -> RusotoFuture<ProvisionProductOutput, ProvisionProductError>;
-
-    /// <p>Rejects an offer to share the specified portfolio.</p>
+    ) -> RusotoFuture<ProvisionProductOutput, ProvisionProductError> {
+        (**self).provision_product(input)
+    }
     fn reject_portfolio_share(
         &self,
         input: RejectPortfolioShareInput,
-    ) -> RusotoFuture<RejectPortfolioShareOutput, RejectPortfolioShareError>;
-
-    /// <p>Lists the provisioned products that are available (not terminated).</p> <p>To use additional filtering, see <a>SearchProvisionedProducts</a>.</p>
+    ) -> RusotoFuture<RejectPortfolioShareOutput, RejectPortfolioShareError> {
+        (**self).reject_portfolio_share(input)
+    }
     fn scan_provisioned_products(
         &self,
         input: ScanProvisionedProductsInput,
-    ) -> RusotoFuture<ScanProvisionedProductsOutput, ScanProvisionedProductsError>;
-
-    /// <p>Gets information about the products to which the caller has access.</p>
+    ) -> RusotoFuture<ScanProvisionedProductsOutput, ScanProvisionedProductsError> {
+        (**self).scan_provisioned_products(input)
+    }
     fn search_products(
         &self,
         input: SearchProductsInput,
-    ) -> RusotoFuture<SearchProductsOutput, SearchProductsError>;
-
-    /// <p>Gets information about the products for the specified portfolio or all products.</p>
+    ) -> RusotoFuture<SearchProductsOutput, SearchProductsError> {
+        (**self).search_products(input)
+    }
     fn search_products_as_admin(
         &self,
         input: SearchProductsAsAdminInput,
-    ) -> RusotoFuture<SearchProductsAsAdminOutput, SearchProductsAsAdminError>;
-
-    /// <p>Gets information about the provisioned products that meet the specified criteria.</p>
+    ) -> RusotoFuture<SearchProductsAsAdminOutput, SearchProductsAsAdminError> {
+        (**self).search_products_as_admin(input)
+    }
     fn search_provisioned_products(
         &self,
         input: SearchProvisionedProductsInput,
-    ) -> RusotoFuture<SearchProvisionedProductsOutput, SearchProvisionedProductsError>;
-
-    /// <p>Terminates the specified provisioned product.</p> <p>This operation does not delete any records associated with the provisioned product.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
+    ) -> RusotoFuture<SearchProvisionedProductsOutput, SearchProvisionedProductsError> {
+        (**self).search_provisioned_products(input)
+    }
     fn terminate_provisioned_product(
         &self,
         input: TerminateProvisionedProductInput,
-    ) -> RusotoFuture<TerminateProvisionedProductOutput, TerminateProvisionedProductError>;
-
-    /// <p>Updates the specified constraint.</p>
+    ) -> RusotoFuture<TerminateProvisionedProductOutput, TerminateProvisionedProductError> {
+        (**self).terminate_provisioned_product(input)
+    }
     fn update_constraint(
         &self,
         input: UpdateConstraintInput,
-    ) -> RusotoFuture<UpdateConstraintOutput, UpdateConstraintError>;
-
-    /// <p>Updates the specified portfolio.</p> <p>You cannot update a product that was shared with you.</p>
+    ) -> RusotoFuture<UpdateConstraintOutput, UpdateConstraintError> {
+        (**self).update_constraint(input)
+    }
     fn update_portfolio(
         &self,
         input: UpdatePortfolioInput,
-    ) -> RusotoFuture<UpdatePortfolioOutput, UpdatePortfolioError>;
-
-    /// <p>Updates the specified product.</p>
+    ) -> RusotoFuture<UpdatePortfolioOutput, UpdatePortfolioError> {
+        (**self).update_portfolio(input)
+    }
     fn update_product(
         &self,
         input: UpdateProductInput,
-    ) -> RusotoFuture<UpdateProductOutput, UpdateProductError>;
-
-    /// <p>Requests updates to the configuration of the specified provisioned product.</p> <p>If there are tags associated with the object, they cannot be updated or added. Depending on the specific updates requested, this operation can update with no interruption, with some interruption, or replace the provisioned product entirely.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
+    ) -> RusotoFuture<UpdateProductOutput, UpdateProductError> {
+        (**self).update_product(input)
+    }
     fn update_provisioned_product(
         &self,
         input: UpdateProvisionedProductInput,
-    ) -> RusotoFuture<UpdateProvisionedProductOutput, UpdateProvisionedProductError>;
-
-    /// <p>Requests updates to the properties of the specified provisioned product.</p>
+    ) -> RusotoFuture<UpdateProvisionedProductOutput, UpdateProvisionedProductError> {
+        (**self).update_provisioned_product(input)
+    }
     fn update_provisioned_product_properties(
         &self,
         input: UpdateProvisionedProductPropertiesInput,
     ) -> RusotoFuture<
         UpdateProvisionedProductPropertiesOutput,
         UpdateProvisionedProductPropertiesError,
-    >;
-
-    /// <p>Updates the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot update a provisioning artifact for a product that was shared with you.</p>
+    > {
+        (**self).update_provisioned_product_properties(input)
+    }
     fn update_provisioning_artifact(
         &self,
         input: UpdateProvisioningArtifactInput,
-    ) -> RusotoFuture<UpdateProvisioningArtifactOutput, UpdateProvisioningArtifactError>;
-
-    /// <p>Updates a self-service action.</p>
+    ) -> RusotoFuture<UpdateProvisioningArtifactOutput, UpdateProvisioningArtifactError> {
+        (**self).update_provisioning_artifact(input)
+    }
     fn update_service_action(
         &self,
         input: UpdateServiceActionInput,
-    ) -> RusotoFuture<UpdateServiceActionOutput, UpdateServiceActionError>;
-
-    /// <p>Updates the specified TagOption.</p>
+    ) -> RusotoFuture<UpdateServiceActionOutput, UpdateServiceActionError> {
+        (**self).update_service_action(input)
+    }
     fn update_tag_option(
         &self,
         input: UpdateTagOptionInput,
-    ) -> RusotoFuture<UpdateTagOptionOutput, UpdateTagOptionError>;
+    ) -> RusotoFuture<UpdateTagOptionOutput, UpdateTagOptionError> {
+        (**self).update_tag_option(input)
+    }
 }
+/// The error codes AWS Service Catalog retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Service Catalog API.
 #[derive(Clone)]
 pub struct ServiceCatalogClient {
@@ -7875,6 +15568,27 @@ impl ServiceCatalogClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SERVICECATALOG`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ServiceCatalogClient {
+        Self::new(region::Region::from_env_for_service("SERVICECATALOG"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `servicecatalog`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ServiceCatalogClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("servicecatalog", "SERVICECATALOG"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -7893,7 +15607,54 @@ impl ServiceCatalogClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ServiceCatalogClient {
-        ServiceCatalogClient { client, region }
+        ServiceCatalogClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "servicecatalog")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "servicecatalog",
+            "servicecatalog",
+            "SERVICECATALOG",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ServiceCatalog` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ServiceCatalog + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ServiceCatalogClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Service Catalog"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "servicecatalog"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ServiceCatalogClient {
+        Self::new_with_client(client, region)
     }
 }
 
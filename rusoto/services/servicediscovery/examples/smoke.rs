@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Cloud Map clients.
+//!
+//! Makes a single read-only `ListNamespaces` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_servicediscovery::{ListNamespacesRequest, ServiceDiscovery, ServiceDiscoveryClient};
+
+fn main() {
+    let client = ServiceDiscoveryClient::from_env();
+    match client
+        .list_namespaces(ListNamespacesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListNamespaces succeeded"),
+        Err(error) => {
+            eprintln!("ListNamespaces failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateHttpNamespaceRequest {
@@ -38,6 +39,54 @@ pub struct CreateHttpNamespaceRequest {
     pub name: String,
 }
 
+impl CreateHttpNamespaceRequest {
+    /// Returns a builder for `CreateHttpNamespaceRequest`, checking its required members when built.
+    pub fn builder() -> CreateHttpNamespaceRequestBuilder {
+        CreateHttpNamespaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateHttpNamespaceRequest`](struct.CreateHttpNamespaceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateHttpNamespaceRequestBuilder {
+    creator_request_id: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateHttpNamespaceRequestBuilder {
+    /// Sets the [`creator_request_id`](struct.CreateHttpNamespaceRequest.html#structfield.creator_request_id) member.
+    pub fn creator_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.creator_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateHttpNamespaceRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateHttpNamespaceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateHttpNamespaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateHttpNamespaceRequest, ::rusoto_core::BuildError> {
+        Ok(CreateHttpNamespaceRequest {
+            creator_request_id: self.creator_request_id,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateHttpNamespaceResponse {
@@ -65,6 +114,62 @@ pub struct CreatePrivateDnsNamespaceRequest {
     pub vpc: String,
 }
 
+impl CreatePrivateDnsNamespaceRequest {
+    /// Returns a builder for `CreatePrivateDnsNamespaceRequest`, checking its required members when built.
+    pub fn builder() -> CreatePrivateDnsNamespaceRequestBuilder {
+        CreatePrivateDnsNamespaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePrivateDnsNamespaceRequest`](struct.CreatePrivateDnsNamespaceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePrivateDnsNamespaceRequestBuilder {
+    creator_request_id: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    vpc: Option<String>,
+}
+
+impl CreatePrivateDnsNamespaceRequestBuilder {
+    /// Sets the [`creator_request_id`](struct.CreatePrivateDnsNamespaceRequest.html#structfield.creator_request_id) member.
+    pub fn creator_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.creator_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreatePrivateDnsNamespaceRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreatePrivateDnsNamespaceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc`](struct.CreatePrivateDnsNamespaceRequest.html#structfield.vpc) member.
+    pub fn vpc<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpc = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePrivateDnsNamespaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePrivateDnsNamespaceRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePrivateDnsNamespaceRequest {
+            creator_request_id: self.creator_request_id,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            vpc: self.vpc.ok_or(::rusoto_core::BuildError::missing("vpc"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreatePrivateDnsNamespaceResponse {
@@ -89,6 +194,54 @@ pub struct CreatePublicDnsNamespaceRequest {
     pub name: String,
 }
 
+impl CreatePublicDnsNamespaceRequest {
+    /// Returns a builder for `CreatePublicDnsNamespaceRequest`, checking its required members when built.
+    pub fn builder() -> CreatePublicDnsNamespaceRequestBuilder {
+        CreatePublicDnsNamespaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePublicDnsNamespaceRequest`](struct.CreatePublicDnsNamespaceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePublicDnsNamespaceRequestBuilder {
+    creator_request_id: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+}
+
+impl CreatePublicDnsNamespaceRequestBuilder {
+    /// Sets the [`creator_request_id`](struct.CreatePublicDnsNamespaceRequest.html#structfield.creator_request_id) member.
+    pub fn creator_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.creator_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreatePublicDnsNamespaceRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreatePublicDnsNamespaceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePublicDnsNamespaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePublicDnsNamespaceRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePublicDnsNamespaceRequest {
+            creator_request_id: self.creator_request_id,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreatePublicDnsNamespaceResponse {
@@ -129,6 +282,87 @@ pub struct CreateServiceRequest {
     pub namespace_id: Option<String>,
 }
 
+impl CreateServiceRequest {
+    /// Returns a builder for `CreateServiceRequest`, checking its required members when built.
+    pub fn builder() -> CreateServiceRequestBuilder {
+        CreateServiceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateServiceRequest`](struct.CreateServiceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateServiceRequestBuilder {
+    creator_request_id: Option<String>,
+    description: Option<String>,
+    dns_config: Option<DnsConfig>,
+    health_check_config: Option<HealthCheckConfig>,
+    health_check_custom_config: Option<HealthCheckCustomConfig>,
+    name: Option<String>,
+    namespace_id: Option<String>,
+}
+
+impl CreateServiceRequestBuilder {
+    /// Sets the [`creator_request_id`](struct.CreateServiceRequest.html#structfield.creator_request_id) member.
+    pub fn creator_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.creator_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateServiceRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dns_config`](struct.CreateServiceRequest.html#structfield.dns_config) member.
+    pub fn dns_config<T: Into<DnsConfig>>(mut self, value: T) -> Self {
+        self.dns_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_config`](struct.CreateServiceRequest.html#structfield.health_check_config) member.
+    pub fn health_check_config<T: Into<HealthCheckConfig>>(mut self, value: T) -> Self {
+        self.health_check_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_custom_config`](struct.CreateServiceRequest.html#structfield.health_check_custom_config) member.
+    pub fn health_check_custom_config<T: Into<HealthCheckCustomConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.health_check_custom_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateServiceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`namespace_id`](struct.CreateServiceRequest.html#structfield.namespace_id) member.
+    pub fn namespace_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.namespace_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateServiceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateServiceRequest, ::rusoto_core::BuildError> {
+        Ok(CreateServiceRequest {
+            creator_request_id: self.creator_request_id,
+            description: self.description,
+            dns_config: self.dns_config,
+            health_check_config: self.health_check_config,
+            health_check_custom_config: self.health_check_custom_config,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            namespace_id: self.namespace_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateServiceResponse {
@@ -138,6 +372,78 @@ pub struct CreateServiceResponse {
     pub service: Option<Service>,
 }
 
+/// <p>The new status of the instance, <code>HEALTHY</code> or <code>UNHEALTHY</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CustomHealthStatus {
+    /// <p><code>HEALTHY</code></p>
+    Healthy,
+    /// <p><code>UNHEALTHY</code></p>
+    Unhealthy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CustomHealthStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CustomHealthStatus::Healthy => "HEALTHY",
+            CustomHealthStatus::Unhealthy => "UNHEALTHY",
+            CustomHealthStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CustomHealthStatus {
+    fn from(value: &'a str) -> CustomHealthStatus {
+        match value {
+            "HEALTHY" => CustomHealthStatus::Healthy,
+            "UNHEALTHY" => CustomHealthStatus::Unhealthy,
+            _ => CustomHealthStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CustomHealthStatus {
+    fn from(value: String) -> CustomHealthStatus {
+        CustomHealthStatus::from(value.as_str())
+    }
+}
+
+impl From<CustomHealthStatus> for String {
+    fn from(value: CustomHealthStatus) -> String {
+        match value {
+            CustomHealthStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CustomHealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CustomHealthStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CustomHealthStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CustomHealthStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CustomHealthStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteNamespaceRequest {
     /// <p>The ID of the namespace that you want to delete.</p>
@@ -145,6 +451,34 @@ pub struct DeleteNamespaceRequest {
     pub id: String,
 }
 
+impl DeleteNamespaceRequest {
+    /// Returns a builder for `DeleteNamespaceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteNamespaceRequestBuilder {
+        DeleteNamespaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteNamespaceRequest`](struct.DeleteNamespaceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteNamespaceRequestBuilder {
+    id: Option<String>,
+}
+
+impl DeleteNamespaceRequestBuilder {
+    /// Sets the [`id`](struct.DeleteNamespaceRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteNamespaceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteNamespaceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteNamespaceRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteNamespaceResponse {
@@ -161,6 +495,34 @@ pub struct DeleteServiceRequest {
     pub id: String,
 }
 
+impl DeleteServiceRequest {
+    /// Returns a builder for `DeleteServiceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteServiceRequestBuilder {
+        DeleteServiceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteServiceRequest`](struct.DeleteServiceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteServiceRequestBuilder {
+    id: Option<String>,
+}
+
+impl DeleteServiceRequestBuilder {
+    /// Sets the [`id`](struct.DeleteServiceRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteServiceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteServiceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteServiceRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteServiceResponse {}
@@ -175,6 +537,48 @@ pub struct DeregisterInstanceRequest {
     pub service_id: String,
 }
 
+impl DeregisterInstanceRequest {
+    /// Returns a builder for `DeregisterInstanceRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterInstanceRequestBuilder {
+        DeregisterInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterInstanceRequest`](struct.DeregisterInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterInstanceRequestBuilder {
+    instance_id: Option<String>,
+    service_id: Option<String>,
+}
+
+impl DeregisterInstanceRequestBuilder {
+    /// Sets the [`instance_id`](struct.DeregisterInstanceRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_id`](struct.DeregisterInstanceRequest.html#structfield.service_id) member.
+    pub fn service_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterInstanceRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            service_id: self
+                .service_id
+                .ok_or(::rusoto_core::BuildError::missing("service_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeregisterInstanceResponse {
@@ -189,7 +593,7 @@ pub struct DiscoverInstancesRequest {
     /// <p>The health status of the instances that you want to discover.</p>
     #[serde(rename = "HealthStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_status: Option<String>,
+    pub health_status: Option<HealthStatusFilter>,
     /// <p>The maximum number of instances that you want Cloud Map to return in the response to a <code>DiscoverInstances</code> request. If you don't specify a value for <code>MaxResults</code>, Cloud Map returns up to 100 instances.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -206,6 +610,75 @@ pub struct DiscoverInstancesRequest {
     pub service_name: String,
 }
 
+impl DiscoverInstancesRequest {
+    /// Returns a builder for `DiscoverInstancesRequest`, checking its required members when built.
+    pub fn builder() -> DiscoverInstancesRequestBuilder {
+        DiscoverInstancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DiscoverInstancesRequest`](struct.DiscoverInstancesRequest.html).
+#[derive(Default, Debug)]
+pub struct DiscoverInstancesRequestBuilder {
+    health_status: Option<HealthStatusFilter>,
+    max_results: Option<i64>,
+    namespace_name: Option<String>,
+    query_parameters: Option<::std::collections::HashMap<String, String>>,
+    service_name: Option<String>,
+}
+
+impl DiscoverInstancesRequestBuilder {
+    /// Sets the [`health_status`](struct.DiscoverInstancesRequest.html#structfield.health_status) member.
+    pub fn health_status<T: Into<HealthStatusFilter>>(mut self, value: T) -> Self {
+        self.health_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DiscoverInstancesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`namespace_name`](struct.DiscoverInstancesRequest.html#structfield.namespace_name) member.
+    pub fn namespace_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.namespace_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_parameters`](struct.DiscoverInstancesRequest.html#structfield.query_parameters) member.
+    pub fn query_parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.query_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_name`](struct.DiscoverInstancesRequest.html#structfield.service_name) member.
+    pub fn service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DiscoverInstancesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DiscoverInstancesRequest, ::rusoto_core::BuildError> {
+        Ok(DiscoverInstancesRequest {
+            health_status: self.health_status,
+            max_results: self.max_results,
+            namespace_name: self
+                .namespace_name
+                .ok_or(::rusoto_core::BuildError::missing("namespace_name"))?,
+            query_parameters: self.query_parameters,
+            service_name: self
+                .service_name
+                .ok_or(::rusoto_core::BuildError::missing("service_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DiscoverInstancesResponse {
@@ -224,7 +697,7 @@ pub struct DnsConfig {
     /// <p>The routing policy that you want to apply to all Route 53 DNS records that AWS Cloud Map creates when you register an instance and specify this service.</p> <note> <p>If you want to use this service to register instances that create alias records, specify <code>WEIGHTED</code> for the routing policy.</p> </note> <p>You can specify the following values:</p> <p> <b>MULTIVALUE</b> </p> <p>If you define a health check for the service and the health check is healthy, Route 53 returns the applicable value for up to eight instances.</p> <p>For example, suppose the service includes configurations for one A record and a health check, and you use the service to register 10 instances. Route 53 responds to DNS queries with IP addresses for up to eight healthy instances. If fewer than eight instances are healthy, Route 53 responds to every DNS query with the IP addresses for all of the healthy instances.</p> <p>If you don't define a health check for the service, Route 53 assumes that all instances are healthy and returns the values for up to eight instances.</p> <p>For more information about the multivalue routing policy, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/routing-policy.html#routing-policy-multivalue">Multivalue Answer Routing</a> in the <i>Route 53 Developer Guide</i>.</p> <p> <b>WEIGHTED</b> </p> <p>Route 53 returns the applicable value from one randomly selected instance from among the instances that you registered using the same service. Currently, all records have the same weight, so you can't route more or less traffic to any instances.</p> <p>For example, suppose the service includes configurations for one A record and a health check, and you use the service to register 10 instances. Route 53 responds to DNS queries with the IP address for one randomly selected instance from among the healthy instances. If no instances are healthy, Route 53 responds to DNS queries as if all of the instances were healthy.</p> <p>If you don't define a health check for the service, Route 53 assumes that all instances are healthy and returns the applicable value for one randomly selected instance.</p> <p>For more information about the weighted routing policy, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/routing-policy.html#routing-policy-weighted">Weighted Routing</a> in the <i>Route 53 Developer Guide</i>.</p>
     #[serde(rename = "RoutingPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub routing_policy: Option<String>,
+    pub routing_policy: Option<RoutingPolicy>,
 }
 
 /// <p>A complex type that contains information about changes to the Route 53 DNS records that AWS Cloud Map creates when you register an instance.</p>
@@ -246,14 +719,94 @@ pub struct DnsProperties {
 }
 
 /// <p>A complex type that contains information about the Route 53 DNS records that you want AWS Cloud Map to create when you register an instance.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DnsRecord {
     /// <p><p>The amount of time, in seconds, that you want DNS resolvers to cache the settings for this record.</p> <note> <p>Alias records don&#39;t include a TTL because Route 53 uses the TTL for the AWS resource that an alias record routes traffic to. If you include the <code>AWS<em>ALIAS</em>DNS_NAME</code> attribute when you submit a <a>RegisterInstance</a> request, the <code>TTL</code> value is ignored. Always specify a TTL for the service; you can use a service to register instances that create either alias or non-alias records.</p> </note></p>
     #[serde(rename = "TTL")]
     pub ttl: i64,
     /// <p>The type of the resource, which indicates the type of value that Route 53 returns in response to DNS queries.</p> <p>Note the following:</p> <ul> <li> <p> <b>A, AAAA, and SRV records:</b> You can specify settings for a maximum of one A, one AAAA, and one SRV record. You can specify them in any combination.</p> </li> <li> <p> <b>CNAME records:</b> If you specify <code>CNAME</code> for <code>Type</code>, you can't define any other records. This is a limitation of DNS: you can't create a CNAME record and any other type of record that has the same name as a CNAME record.</p> </li> <li> <p> <b>Alias records:</b> If you want AWS Cloud Map to create a Route 53 alias record when you register an instance, specify <code>A</code> or <code>AAAA</code> for <code>Type</code>.</p> </li> <li> <p> <b>All records:</b> You specify settings other than <code>TTL</code> and <code>Type</code> when you register an instance.</p> </li> </ul> <p>The following values are supported:</p> <p> <b>A</b> </p> <p>Route 53 returns the IP address of the resource in IPv4 format, such as 192.0.2.44.</p> <p> <b>AAAA</b> </p> <p>Route 53 returns the IP address of the resource in IPv6 format, such as 2001:0db8:85a3:0000:0000:abcd:0001:2345.</p> <p> <b>CNAME</b> </p> <p>Route 53 returns the domain name of the resource, such as www.example.com. Note the following:</p> <ul> <li> <p>You specify the domain name that you want to route traffic to when you register an instance. For more information, see <a>RegisterInstanceRequest$Attributes</a>.</p> </li> <li> <p>You must specify <code>WEIGHTED</code> for the value of <code>RoutingPolicy</code>.</p> </li> <li> <p>You can't specify both <code>CNAME</code> for <code>Type</code> and settings for <code>HealthCheckConfig</code>. If you do, the request will fail with an <code>InvalidInput</code> error.</p> </li> </ul> <p> <b>SRV</b> </p> <p>Route 53 returns the value for an SRV record. The value for an SRV record uses the following values:</p> <p> <code>priority weight port service-hostname</code> </p> <p>Note the following about the values:</p> <ul> <li> <p>The values of <code>priority</code> and <code>weight</code> are both set to <code>1</code> and can't be changed. </p> </li> <li> <p>The value of <code>port</code> comes from the value that you specify for the <code>AWS_INSTANCE_PORT</code> attribute when you submit a <a>RegisterInstance</a> request. </p> </li> <li> <p>The value of <code>service-hostname</code> is a concatenation of the following values:</p> <ul> <li> <p>The value that you specify for <code>InstanceId</code> when you register an instance.</p> </li> <li> <p>The name of the service.</p> </li> <li> <p>The name of the namespace. </p> </li> </ul> <p>For example, if the value of <code>InstanceId</code> is <code>test</code>, the name of the service is <code>backend</code>, and the name of the namespace is <code>example.com</code>, the value of <code>service-hostname</code> is:</p> <p> <code>test.backend.example.com</code> </p> </li> </ul> <p>If you specify settings for an SRV record and if you specify values for <code>AWS_INSTANCE_IPV4</code>, <code>AWS_INSTANCE_IPV6</code>, or both in the <code>RegisterInstance</code> request, AWS Cloud Map automatically creates <code>A</code> and/or <code>AAAA</code> records that have the same name as the value of <code>service-hostname</code> in the SRV record. You can ignore these records.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: RecordType,
+}
+
+/// <p><p>The operator that you want to use to determine whether <code>ListNamespaces</code> returns a namespace. Valid values for <code>condition</code> include:</p> <ul> <li> <p> <code>EQ</code>: When you specify <code>EQ</code> for the condition, you can choose to list only public namespaces or private namespaces, but not both. <code>EQ</code> is the default condition and can be omitted.</p> </li> <li> <p> <code>IN</code>: When you specify <code>IN</code> for the condition, you can choose to list public namespaces, private namespaces, or both. </p> </li> <li> <p> <code>BETWEEN</code>: Not applicable</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FilterCondition {
+    /// <p><code>EQ</code> - When you specify <code>EQ</code> for the condition, you can choose to list only public namespaces or private namespaces, but not both. <code>EQ</code> is the default condition and can be omitted.</p>
+    Eq,
+    /// <p><code>IN</code> - When you specify <code>IN</code> for the condition, you can choose to list public namespaces, private namespaces, or both.</p>
+    In,
+    /// <p><code>BETWEEN</code> - Not applicable</p>
+    Between,
+    /// <p><code>BEGINS_WITH</code></p>
+    BeginsWith,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FilterCondition {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterCondition::Eq => "EQ",
+            FilterCondition::In => "IN",
+            FilterCondition::Between => "BETWEEN",
+            FilterCondition::BeginsWith => "BEGINS_WITH",
+            FilterCondition::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FilterCondition {
+    fn from(value: &'a str) -> FilterCondition {
+        match value {
+            "EQ" => FilterCondition::Eq,
+            "IN" => FilterCondition::In,
+            "BETWEEN" => FilterCondition::Between,
+            "BEGINS_WITH" => FilterCondition::BeginsWith,
+            _ => FilterCondition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterCondition {
+    fn from(value: String) -> FilterCondition {
+        FilterCondition::from(value.as_str())
+    }
+}
+
+impl From<FilterCondition> for String {
+    fn from(value: FilterCondition) -> String {
+        match value {
+            FilterCondition::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FilterCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FilterCondition {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FilterCondition {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FilterCondition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FilterCondition::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -266,6 +819,46 @@ pub struct GetInstanceRequest {
     pub service_id: String,
 }
 
+impl GetInstanceRequest {
+    /// Returns a builder for `GetInstanceRequest`, checking its required members when built.
+    pub fn builder() -> GetInstanceRequestBuilder {
+        GetInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstanceRequest`](struct.GetInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstanceRequestBuilder {
+    instance_id: Option<String>,
+    service_id: Option<String>,
+}
+
+impl GetInstanceRequestBuilder {
+    /// Sets the [`instance_id`](struct.GetInstanceRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_id`](struct.GetInstanceRequest.html#structfield.service_id) member.
+    pub fn service_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstanceRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            service_id: self
+                .service_id
+                .ok_or(::rusoto_core::BuildError::missing("service_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetInstanceResponse {
@@ -294,6 +887,62 @@ pub struct GetInstancesHealthStatusRequest {
     pub service_id: String,
 }
 
+impl GetInstancesHealthStatusRequest {
+    /// Returns a builder for `GetInstancesHealthStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetInstancesHealthStatusRequestBuilder {
+        GetInstancesHealthStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstancesHealthStatusRequest`](struct.GetInstancesHealthStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstancesHealthStatusRequestBuilder {
+    instances: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    service_id: Option<String>,
+}
+
+impl GetInstancesHealthStatusRequestBuilder {
+    /// Sets the [`instances`](struct.GetInstancesHealthStatusRequest.html#structfield.instances) member.
+    pub fn instances<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetInstancesHealthStatusRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetInstancesHealthStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_id`](struct.GetInstancesHealthStatusRequest.html#structfield.service_id) member.
+    pub fn service_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstancesHealthStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetInstancesHealthStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstancesHealthStatusRequest {
+            instances: self.instances,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            service_id: self
+                .service_id
+                .ok_or(::rusoto_core::BuildError::missing("service_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetInstancesHealthStatusResponse {
@@ -304,7 +953,7 @@ pub struct GetInstancesHealthStatusResponse {
     /// <p>A complex type that contains the IDs and the health status of the instances that you specified in the <code>GetInstancesHealthStatus</code> request.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<::std::collections::HashMap<String, String>>,
+    pub status: Option<::std::collections::HashMap<String, HealthStatus>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -314,6 +963,34 @@ pub struct GetNamespaceRequest {
     pub id: String,
 }
 
+impl GetNamespaceRequest {
+    /// Returns a builder for `GetNamespaceRequest`, checking its required members when built.
+    pub fn builder() -> GetNamespaceRequestBuilder {
+        GetNamespaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetNamespaceRequest`](struct.GetNamespaceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetNamespaceRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetNamespaceRequestBuilder {
+    /// Sets the [`id`](struct.GetNamespaceRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetNamespaceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetNamespaceRequest, ::rusoto_core::BuildError> {
+        Ok(GetNamespaceRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetNamespaceResponse {
@@ -330,6 +1007,36 @@ pub struct GetOperationRequest {
     pub operation_id: String,
 }
 
+impl GetOperationRequest {
+    /// Returns a builder for `GetOperationRequest`, checking its required members when built.
+    pub fn builder() -> GetOperationRequestBuilder {
+        GetOperationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetOperationRequest`](struct.GetOperationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetOperationRequestBuilder {
+    operation_id: Option<String>,
+}
+
+impl GetOperationRequestBuilder {
+    /// Sets the [`operation_id`](struct.GetOperationRequest.html#structfield.operation_id) member.
+    pub fn operation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOperationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetOperationRequest, ::rusoto_core::BuildError> {
+        Ok(GetOperationRequest {
+            operation_id: self
+                .operation_id
+                .ok_or(::rusoto_core::BuildError::missing("operation_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetOperationResponse {
@@ -346,6 +1053,34 @@ pub struct GetServiceRequest {
     pub id: String,
 }
 
+impl GetServiceRequest {
+    /// Returns a builder for `GetServiceRequest`, checking its required members when built.
+    pub fn builder() -> GetServiceRequestBuilder {
+        GetServiceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetServiceRequest`](struct.GetServiceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetServiceRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetServiceRequestBuilder {
+    /// Sets the [`id`](struct.GetServiceRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetServiceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetServiceRequest, ::rusoto_core::BuildError> {
+        Ok(GetServiceRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetServiceResponse {
@@ -356,7 +1091,7 @@ pub struct GetServiceResponse {
 }
 
 /// <p> <i>Public DNS namespaces only.</i> A complex type that contains settings for an optional health check. If you specify settings for a health check, AWS Cloud Map associates the health check with the records that you specify in <code>DnsConfig</code>.</p> <important> <p>If you specify a health check configuration, you can specify either <code>HealthCheckCustomConfig</code> or <code>HealthCheckConfig</code> but not both.</p> </important> <p>Health checks are basic Route 53 health checks that monitor an AWS endpoint. For information about pricing for health checks, see <a href="http://aws.amazon.com/route53/pricing/">Amazon Route 53 Pricing</a>.</p> <p>Note the following about configuring health checks.</p> <p> <b>A and AAAA records</b> </p> <p>If <code>DnsConfig</code> includes configurations for both A and AAAA records, AWS Cloud Map creates a health check that uses the IPv4 address to check the health of the resource. If the endpoint that is specified by the IPv4 address is unhealthy, Route 53 considers both the A and AAAA records to be unhealthy. </p> <p> <b>CNAME records</b> </p> <p>You can't specify settings for <code>HealthCheckConfig</code> when the <code>DNSConfig</code> includes <code>CNAME</code> for the value of <code>Type</code>. If you do, the <code>CreateService</code> request will fail with an <code>InvalidInput</code> error.</p> <p> <b>Request interval</b> </p> <p>A Route 53 health checker in each health-checking region sends a health check request to an endpoint every 30 seconds. On average, your endpoint receives a health check request about every two seconds. However, health checkers don't coordinate with one another, so you'll sometimes see several requests per second followed by a few seconds with no health checks at all.</p> <p> <b>Health checking regions</b> </p> <p>Health checkers perform checks from all Route 53 health-checking regions. For a list of the current regions, see <a href="http://docs.aws.amazon.com/Route53/latest/APIReference/API_HealthCheckConfig.html#Route53-Type-HealthCheckConfig-Regions">Regions</a>.</p> <p> <b>Alias records</b> </p> <p>When you register an instance, if you include the <code>AWS_ALIAS_DNS_NAME</code> attribute, AWS Cloud Map creates a Route 53 alias record. Note the following:</p> <ul> <li> <p>Route 53 automatically sets <code>EvaluateTargetHealth</code> to true for alias records. When <code>EvaluateTargetHealth</code> is true, the alias record inherits the health of the referenced AWS resource. such as an ELB load balancer. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/APIReference/API_AliasTarget.html#Route53-Type-AliasTarget-EvaluateTargetHealth">EvaluateTargetHealth</a>.</p> </li> <li> <p>If you include <code>HealthCheckConfig</code> and then use the service to register an instance that creates an alias record, Route 53 doesn't create the health check.</p> </li> </ul> <p> <b>Charges for health checks</b> </p> <p>Health checks are basic Route 53 health checks that monitor an AWS endpoint. For information about pricing for health checks, see <a href="http://aws.amazon.com/route53/pricing/">Amazon Route 53 Pricing</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HealthCheckConfig {
     /// <p>The number of consecutive health checks that an endpoint must pass or fail for Route 53 to change the current status of the endpoint from unhealthy to healthy or vice versa. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-determining-health-of-endpoints.html">How Route 53 Determines Whether an Endpoint Is Healthy</a> in the <i>Route 53 Developer Guide</i>.</p>
     #[serde(rename = "FailureThreshold")]
@@ -368,7 +1103,7 @@ pub struct HealthCheckConfig {
     pub resource_path: Option<String>,
     /// <p>The type of health check that you want to create, which indicates how Route 53 determines whether an endpoint is healthy.</p> <important> <p>You can't change the value of <code>Type</code> after you create a health check.</p> </important> <p>You can create the following types of health checks:</p> <ul> <li> <p> <b>HTTP</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTP request and waits for an HTTP status code of 200 or greater and less than 400.</p> </li> <li> <p> <b>HTTPS</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTPS request and waits for an HTTP status code of 200 or greater and less than 400.</p> <important> <p>If you specify HTTPS for the value of <code>Type</code>, the endpoint must support TLS v1.0 or later.</p> </important> </li> <li> <p> <b>TCP</b>: Route 53 tries to establish a TCP connection.</p> <p>If you specify <code>TCP</code> for <code>Type</code>, don't specify a value for <code>ResourcePath</code>.</p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-determining-health-of-endpoints.html">How Route 53 Determines Whether an Endpoint Is Healthy</a> in the <i>Route 53 Developer Guide</i>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: HealthCheckType,
 }
 
 /// <p>A complex type that contains information about an optional custom health check. A custom health check, which requires that you use a third-party health checker to evaluate the health of your resources, is useful in the following circumstances:</p> <ul> <li> <p>You can't use a health check that is defined by <code>HealthCheckConfig</code> because the resource isn't available over the internet. For example, you can use a custom health check when the instance is in an Amazon VPC. (To check the health of resources in a VPC, the health checker must also be in the VPC.)</p> </li> <li> <p>You want to use a third-party health checker regardless of where your resources are.</p> </li> </ul> <important> <p>If you specify a health check configuration, you can specify either <code>HealthCheckCustomConfig</code> or <code>HealthCheckConfig</code> but not both.</p> </important> <p>To change the status of a custom health check, submit an <code>UpdateInstanceCustomHealthStatus</code> request. Cloud Map doesn't monitor the status of the resource, it just keeps a record of the status specified in the most recent <code>UpdateInstanceCustomHealthStatus</code> request.</p> <p>Here's how custom health checks work:</p> <ol> <li> <p>You create a service and specify a value for <code>FailureThreshold</code>. </p> <p>The failure threshold indicates the number of 30-second intervals you want AWS Cloud Map to wait between the time that your application sends an <a>UpdateInstanceCustomHealthStatus</a> request and the time that AWS Cloud Map stops routing internet traffic to the corresponding resource.</p> </li> <li> <p>You register an instance.</p> </li> <li> <p>You configure a third-party health checker to monitor the resource that is associated with the new instance. </p> <note> <p>AWS Cloud Map doesn't check the health of the resource directly. </p> </note> </li> <li> <p>The third-party health-checker determines that the resource is unhealthy and notifies your application.</p> </li> <li> <p>Your application submits an <code>UpdateInstanceCustomHealthStatus</code> request.</p> </li> <li> <p>AWS Cloud Map waits for (<code>FailureThreshold</code> x 30) seconds.</p> </li> <li> <p>If another <code>UpdateInstanceCustomHealthStatus</code> request doesn't arrive during that time to change the status back to healthy, AWS Cloud Map stops routing traffic to the resource.</p> </li> </ol> <p>Note the following about configuring custom health checks.</p>
@@ -380,18 +1115,250 @@ pub struct HealthCheckCustomConfig {
     pub failure_threshold: Option<i64>,
 }
 
-/// <p>In a response to a <a>DiscoverInstance</a> request, <code>HttpInstanceSummary</code> contains information about one instance that matches the values that you specified in the request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct HttpInstanceSummary {
-    /// <p>If you included any attributes when you registered the instance, the values of those attributes.</p>
-    #[serde(rename = "Attributes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+/// <p>The type of health check that you want to create, which indicates how Route 53 determines whether an endpoint is healthy.</p> <important> <p>You can't change the value of <code>Type</code> after you create a health check.</p> </important> <p>You can create the following types of health checks:</p> <ul> <li> <p> <b>HTTP</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTP request and waits for an HTTP status code of 200 or greater and less than 400.</p> </li> <li> <p> <b>HTTPS</b>: Route 53 tries to establish a TCP connection. If successful, Route 53 submits an HTTPS request and waits for an HTTP status code of 200 or greater and less than 400.</p> <important> <p>If you specify HTTPS for the value of <code>Type</code>, the endpoint must support TLS v1.0 or later.</p> </important> </li> <li> <p> <b>TCP</b>: Route 53 tries to establish a TCP connection.</p> <p>If you specify <code>TCP</code> for <code>Type</code>, don't specify a value for <code>ResourcePath</code>.</p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-determining-health-of-endpoints.html">How Route 53 Determines Whether an Endpoint Is Healthy</a> in the <i>Route 53 Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HealthCheckType {
+    /// <p><code>HTTP</code></p>
+    Http,
+    /// <p><code>HTTPS</code></p>
+    Https,
+    /// <p><code>TCP</code></p>
+    Tcp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HealthCheckType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthCheckType::Http => "HTTP",
+            HealthCheckType::Https => "HTTPS",
+            HealthCheckType::Tcp => "TCP",
+            HealthCheckType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HealthCheckType {
+    fn from(value: &'a str) -> HealthCheckType {
+        match value {
+            "HTTP" => HealthCheckType::Http,
+            "HTTPS" => HealthCheckType::Https,
+            "TCP" => HealthCheckType::Tcp,
+            _ => HealthCheckType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthCheckType {
+    fn from(value: String) -> HealthCheckType {
+        HealthCheckType::from(value.as_str())
+    }
+}
+
+impl From<HealthCheckType> for String {
+    fn from(value: HealthCheckType) -> String {
+        match value {
+            HealthCheckType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HealthCheckType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HealthCheckType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HealthCheckType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HealthCheckType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HealthCheckType::from(value))
+    }
+}
+
+/// <p>A complex type that contains the IDs and the health status of the instances that you specified in the <code>GetInstancesHealthStatus</code> request.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HealthStatus {
+    /// <p><code>HEALTHY</code></p>
+    Healthy,
+    /// <p><code>UNHEALTHY</code></p>
+    Unhealthy,
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HealthStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthStatus::Healthy => "HEALTHY",
+            HealthStatus::Unhealthy => "UNHEALTHY",
+            HealthStatus::UnknownValue => "UNKNOWN",
+            HealthStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HealthStatus {
+    fn from(value: &'a str) -> HealthStatus {
+        match value {
+            "HEALTHY" => HealthStatus::Healthy,
+            "UNHEALTHY" => HealthStatus::Unhealthy,
+            "UNKNOWN" => HealthStatus::UnknownValue,
+            _ => HealthStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthStatus {
+    fn from(value: String) -> HealthStatus {
+        HealthStatus::from(value.as_str())
+    }
+}
+
+impl From<HealthStatus> for String {
+    fn from(value: HealthStatus) -> String {
+        match value {
+            HealthStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HealthStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HealthStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HealthStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HealthStatus::from(value))
+    }
+}
+
+/// <p>The health status of the instances that you want to discover.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HealthStatusFilter {
+    /// <p><code>HEALTHY</code></p>
+    Healthy,
+    /// <p><code>UNHEALTHY</code></p>
+    Unhealthy,
+    /// <p><code>ALL</code></p>
+    All,
+    /// <p><code>HEALTHY_OR_ELSE_ALL</code></p>
+    HealthyOrElseAll,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HealthStatusFilter {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthStatusFilter::Healthy => "HEALTHY",
+            HealthStatusFilter::Unhealthy => "UNHEALTHY",
+            HealthStatusFilter::All => "ALL",
+            HealthStatusFilter::HealthyOrElseAll => "HEALTHY_OR_ELSE_ALL",
+            HealthStatusFilter::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HealthStatusFilter {
+    fn from(value: &'a str) -> HealthStatusFilter {
+        match value {
+            "HEALTHY" => HealthStatusFilter::Healthy,
+            "UNHEALTHY" => HealthStatusFilter::Unhealthy,
+            "ALL" => HealthStatusFilter::All,
+            "HEALTHY_OR_ELSE_ALL" => HealthStatusFilter::HealthyOrElseAll,
+            _ => HealthStatusFilter::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthStatusFilter {
+    fn from(value: String) -> HealthStatusFilter {
+        HealthStatusFilter::from(value.as_str())
+    }
+}
+
+impl From<HealthStatusFilter> for String {
+    fn from(value: HealthStatusFilter) -> String {
+        match value {
+            HealthStatusFilter::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HealthStatusFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HealthStatusFilter {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HealthStatusFilter {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HealthStatusFilter, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HealthStatusFilter::from(value))
+    }
+}
+
+/// <p>In a response to a <a>DiscoverInstance</a> request, <code>HttpInstanceSummary</code> contains information about one instance that matches the values that you specified in the request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct HttpInstanceSummary {
+    /// <p>If you included any attributes when you registered the instance, the values of those attributes.</p>
+    #[serde(rename = "Attributes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<::std::collections::HashMap<String, String>>,
     /// <p>If you configured health checking in the service, the current health status of the service instance.</p>
     #[serde(rename = "HealthStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_status: Option<String>,
+    pub health_status: Option<HealthStatus>,
     /// <p>The ID of an instance that matches the values that you specified in the request.</p>
     #[serde(rename = "InstanceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -462,6 +1429,52 @@ pub struct ListInstancesRequest {
     pub service_id: String,
 }
 
+impl ListInstancesRequest {
+    /// Returns a builder for `ListInstancesRequest`, checking its required members when built.
+    pub fn builder() -> ListInstancesRequestBuilder {
+        ListInstancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListInstancesRequest`](struct.ListInstancesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListInstancesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    service_id: Option<String>,
+}
+
+impl ListInstancesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListInstancesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListInstancesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_id`](struct.ListInstancesRequest.html#structfield.service_id) member.
+    pub fn service_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListInstancesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListInstancesRequest, ::rusoto_core::BuildError> {
+        Ok(ListInstancesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            service_id: self
+                .service_id
+                .ok_or(::rusoto_core::BuildError::missing("service_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListInstancesResponse {
@@ -491,6 +1504,50 @@ pub struct ListNamespacesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListNamespacesRequest {
+    /// Returns a builder for `ListNamespacesRequest`, checking its required members when built.
+    pub fn builder() -> ListNamespacesRequestBuilder {
+        ListNamespacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListNamespacesRequest`](struct.ListNamespacesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListNamespacesRequestBuilder {
+    filters: Option<Vec<NamespaceFilter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListNamespacesRequestBuilder {
+    /// Sets the [`filters`](struct.ListNamespacesRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<NamespaceFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListNamespacesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListNamespacesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListNamespacesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListNamespacesRequest, ::rusoto_core::BuildError> {
+        Ok(ListNamespacesRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListNamespacesResponse {
@@ -520,6 +1577,50 @@ pub struct ListOperationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListOperationsRequest {
+    /// Returns a builder for `ListOperationsRequest`, checking its required members when built.
+    pub fn builder() -> ListOperationsRequestBuilder {
+        ListOperationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOperationsRequest`](struct.ListOperationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOperationsRequestBuilder {
+    filters: Option<Vec<OperationFilter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListOperationsRequestBuilder {
+    /// Sets the [`filters`](struct.ListOperationsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<OperationFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListOperationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListOperationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOperationsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListOperationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListOperationsRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListOperationsResponse {
@@ -549,6 +1650,50 @@ pub struct ListServicesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListServicesRequest {
+    /// Returns a builder for `ListServicesRequest`, checking its required members when built.
+    pub fn builder() -> ListServicesRequestBuilder {
+        ListServicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListServicesRequest`](struct.ListServicesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListServicesRequestBuilder {
+    filters: Option<Vec<ServiceFilter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListServicesRequestBuilder {
+    /// Sets the [`filters`](struct.ListServicesRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<ServiceFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListServicesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListServicesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListServicesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListServicesRequest, ::rusoto_core::BuildError> {
+        Ok(ListServicesRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListServicesResponse {
@@ -572,8 +1717,9 @@ pub struct Namespace {
     pub arn: Option<String>,
     /// <p>The date that the namespace was created, in Unix date/time format and Coordinated Universal Time (UTC). The value of <code>CreateDate</code> is accurate to milliseconds. For example, the value <code>1516925490.087</code> represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<EpochTimestamp>,
     /// <p>A unique string that identifies the request and that allows failed requests to be retried without the risk of executing an operation twice. </p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -601,24 +1747,100 @@ pub struct Namespace {
     /// <p>The type of the namespace. Valid values are <code>DNS_PUBLIC</code> and <code>DNS_PRIVATE</code>.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NamespaceType>,
 }
 
 /// <p>A complex type that identifies the namespaces that you want to list. You can choose to list public or private namespaces.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct NamespaceFilter {
     /// <p><p>The operator that you want to use to determine whether <code>ListNamespaces</code> returns a namespace. Valid values for <code>condition</code> include:</p> <ul> <li> <p> <code>EQ</code>: When you specify <code>EQ</code> for the condition, you can choose to list only public namespaces or private namespaces, but not both. <code>EQ</code> is the default condition and can be omitted.</p> </li> <li> <p> <code>IN</code>: When you specify <code>IN</code> for the condition, you can choose to list public namespaces, private namespaces, or both. </p> </li> <li> <p> <code>BETWEEN</code>: Not applicable</p> </li> </ul></p>
     #[serde(rename = "Condition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub condition: Option<String>,
+    pub condition: Option<FilterCondition>,
     /// <p>Specify <code>TYPE</code>.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: NamespaceFilterName,
     /// <p>If you specify <code>EQ</code> for <code>Condition</code>, specify either <code>DNS_PUBLIC</code> or <code>DNS_PRIVATE</code>.</p> <p>If you specify <code>IN</code> for <code>Condition</code>, you can specify <code>DNS_PUBLIC</code>, <code>DNS_PRIVATE</code>, or both.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+/// <p>Specify <code>TYPE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NamespaceFilterName {
+    /// <p><code>TYPE</code></p>
+    Type,
+    /// <p><code>NAME</code></p>
+    Name,
+    /// <p><code>HTTP_NAME</code></p>
+    HttpName,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NamespaceFilterName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NamespaceFilterName::Type => "TYPE",
+            NamespaceFilterName::Name => "NAME",
+            NamespaceFilterName::HttpName => "HTTP_NAME",
+            NamespaceFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NamespaceFilterName {
+    fn from(value: &'a str) -> NamespaceFilterName {
+        match value {
+            "TYPE" => NamespaceFilterName::Type,
+            "NAME" => NamespaceFilterName::Name,
+            "HTTP_NAME" => NamespaceFilterName::HttpName,
+            _ => NamespaceFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NamespaceFilterName {
+    fn from(value: String) -> NamespaceFilterName {
+        NamespaceFilterName::from(value.as_str())
+    }
+}
+
+impl From<NamespaceFilterName> for String {
+    fn from(value: NamespaceFilterName) -> String {
+        match value {
+            NamespaceFilterName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NamespaceFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NamespaceFilterName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NamespaceFilterName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NamespaceFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NamespaceFilterName::from(value))
+    }
+}
+
 /// <p>A complex type that contains information that is specific to the namespace type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -643,8 +1865,9 @@ pub struct NamespaceSummary {
     pub arn: Option<String>,
     /// <p>The date and time that the namespace was created.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<EpochTimestamp>,
     /// <p>A description for the namespace.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -667,7 +1890,83 @@ pub struct NamespaceSummary {
     /// <p>The type of the namespace, either public or private.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NamespaceType>,
+}
+
+/// <p>The type of the namespace. Valid values are <code>DNS_PUBLIC</code> and <code>DNS_PRIVATE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NamespaceType {
+    /// <p><code>DNS_PUBLIC</code></p>
+    DnsPublic,
+    /// <p><code>DNS_PRIVATE</code></p>
+    DnsPrivate,
+    /// <p><code>HTTP</code></p>
+    Http,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NamespaceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NamespaceType::DnsPublic => "DNS_PUBLIC",
+            NamespaceType::DnsPrivate => "DNS_PRIVATE",
+            NamespaceType::Http => "HTTP",
+            NamespaceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NamespaceType {
+    fn from(value: &'a str) -> NamespaceType {
+        match value {
+            "DNS_PUBLIC" => NamespaceType::DnsPublic,
+            "DNS_PRIVATE" => NamespaceType::DnsPrivate,
+            "HTTP" => NamespaceType::Http,
+            _ => NamespaceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NamespaceType {
+    fn from(value: String) -> NamespaceType {
+        NamespaceType::from(value.as_str())
+    }
+}
+
+impl From<NamespaceType> for String {
+    fn from(value: NamespaceType) -> String {
+        match value {
+            NamespaceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NamespaceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NamespaceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NamespaceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NamespaceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NamespaceType::from(value))
+    }
 }
 
 /// <p>A complex type that contains information about a specified operation.</p>
@@ -676,8 +1975,9 @@ pub struct NamespaceSummary {
 pub struct Operation {
     /// <p>The date and time that the request was submitted, in Unix date/time format and Coordinated Universal Time (UTC). The value of <code>CreateDate</code> is accurate to milliseconds. For example, the value <code>1516925490.087</code> represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<EpochTimestamp>,
     /// <p><p>The code associated with <code>ErrorMessage</code>. Values for <code>ErrorCode</code> include the following:</p> <ul> <li> <p> <code>ACCESS<em>DENIED</code> </p> </li> <li> <p> <code>CANNOT</em>CREATE<em>HOSTED</em>ZONE</code> </p> </li> <li> <p> <code>EXPIRED<em>TOKEN</code> </p> </li> <li> <p> <code>HOSTED</em>ZONE<em>NOT</em>FOUND</code> </p> </li> <li> <p> <code>INTERNAL<em>FAILURE</code> </p> </li> <li> <p> <code>INVALID</em>CHANGE<em>BATCH</code> </p> </li> <li> <p> <code>THROTTLED</em>REQUEST</code> </p> </li> </ul></p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -693,36 +1993,201 @@ pub struct Operation {
     /// <p><p>The status of the operation. Values include the following:</p> <ul> <li> <p> <b>SUBMITTED</b>: This is the initial state immediately after you submit a request.</p> </li> <li> <p> <b>PENDING</b>: AWS Cloud Map is performing the operation.</p> </li> <li> <p> <b>SUCCESS</b>: The operation succeeded.</p> </li> <li> <p> <b>FAIL</b>: The operation failed. For the failure reason, see <code>ErrorMessage</code>.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OperationStatus>,
     /// <p><p>The name of the target entity that is associated with the operation:</p> <ul> <li> <p> <b>NAMESPACE</b>: The namespace ID is returned in the <code>ResourceId</code> property.</p> </li> <li> <p> <b>SERVICE</b>: The service ID is returned in the <code>ResourceId</code> property.</p> </li> <li> <p> <b>INSTANCE</b>: The instance ID is returned in the <code>ResourceId</code> property.</p> </li> </ul></p>
     #[serde(rename = "Targets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub targets: Option<::std::collections::HashMap<String, String>>,
+    pub targets: Option<::std::collections::HashMap<OperationTargetType, String>>,
     /// <p>The name of the operation that is associated with the specified ID.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OperationType>,
     /// <p>The date and time that the value of <code>Status</code> changed to the current value, in Unix date/time format and Coordinated Universal Time (UTC). The value of <code>UpdateDate</code> is accurate to milliseconds. For example, the value <code>1516925490.087</code> represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "UpdateDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_date: Option<f64>,
+    pub update_date: Option<EpochTimestamp>,
 }
 
 /// <p>A complex type that lets you select the operations that you want to list.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct OperationFilter {
     /// <p><p>The operator that you want to use to determine whether an operation matches the specified value. Valid values for condition include:</p> <ul> <li> <p> <code>EQ</code>: When you specify <code>EQ</code> for the condition, you can specify only one value. <code>EQ</code> is supported for <code>NAMESPACE<em>ID</code>, <code>SERVICE</em>ID</code>, <code>STATUS</code>, and <code>TYPE</code>. <code>EQ</code> is the default condition and can be omitted.</p> </li> <li> <p> <code>IN</code>: When you specify <code>IN</code> for the condition, you can specify a list of one or more values. <code>IN</code> is supported for <code>STATUS</code> and <code>TYPE</code>. An operation must match one of the specified values to be returned in the response.</p> </li> <li> <p> <code>BETWEEN</code>: Specify a start date and an end date in Unix date/time format and Coordinated Universal Time (UTC). The start date must be the first value. <code>BETWEEN</code> is supported for <code>UPDATE_DATE</code>. </p> </li> </ul></p>
     #[serde(rename = "Condition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub condition: Option<String>,
+    pub condition: Option<FilterCondition>,
     /// <p><p>Specify the operations that you want to get:</p> <ul> <li> <p> <b>NAMESPACE<em>ID</b>: Gets operations related to specified namespaces.</p> </li> <li> <p> <b>SERVICE</em>ID</b>: Gets operations related to specified services.</p> </li> <li> <p> <b>STATUS</b>: Gets operations based on the status of the operations: <code>SUBMITTED</code>, <code>PENDING</code>, <code>SUCCEED</code>, or <code>FAIL</code>.</p> </li> <li> <p> <b>TYPE</b>: Gets specified types of operation.</p> </li> <li> <p> <b>UPDATE_DATE</b>: Gets operations that changed status during a specified date/time range. </p> </li> </ul></p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: OperationFilterName,
     /// <p><p>Specify values that are applicable to the value that you specify for <code>Name</code>: </p> <ul> <li> <p> <b>NAMESPACE<em>ID</b>: Specify one namespace ID.</p> </li> <li> <p> <b>SERVICE</em>ID</b>: Specify one service ID.</p> </li> <li> <p> <b>STATUS</b>: Specify one or more statuses: <code>SUBMITTED</code>, <code>PENDING</code>, <code>SUCCEED</code>, or <code>FAIL</code>.</p> </li> <li> <p> <b>TYPE</b>: Specify one or more of the following types: <code>CREATE<em>NAMESPACE</code>, <code>DELETE</em>NAMESPACE</code>, <code>UPDATE<em>SERVICE</code>, <code>REGISTER</em>INSTANCE</code>, or <code>DEREGISTER<em>INSTANCE</code>.</p> </li> <li> <p> <b>UPDATE</em>DATE</b>: Specify a start date and an end date in Unix date/time format and Coordinated Universal Time (UTC). The start date must be the first value.</p> </li> </ul></p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+/// <p><p>Specify the operations that you want to get:</p> <ul> <li> <p> <b>NAMESPACE<em>ID</b>: Gets operations related to specified namespaces.</p> </li> <li> <p> <b>SERVICE</em>ID</b>: Gets operations related to specified services.</p> </li> <li> <p> <b>STATUS</b>: Gets operations based on the status of the operations: <code>SUBMITTED</code>, <code>PENDING</code>, <code>SUCCEED</code>, or <code>FAIL</code>.</p> </li> <li> <p> <b>TYPE</b>: Gets specified types of operation.</p> </li> <li> <p> <b>UPDATE_DATE</b>: Gets operations that changed status during a specified date/time range. </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperationFilterName {
+    /// <p><code>NAMESPACE_ID</code></p>
+    NamespaceId,
+    /// <p><code>SERVICE_ID</code></p>
+    ServiceId,
+    /// <p><code>STATUS</code></p>
+    Status,
+    /// <p><code>TYPE</code></p>
+    Type,
+    /// <p><code>UPDATE_DATE</code></p>
+    UpdateDate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OperationFilterName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationFilterName::NamespaceId => "NAMESPACE_ID",
+            OperationFilterName::ServiceId => "SERVICE_ID",
+            OperationFilterName::Status => "STATUS",
+            OperationFilterName::Type => "TYPE",
+            OperationFilterName::UpdateDate => "UPDATE_DATE",
+            OperationFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperationFilterName {
+    fn from(value: &'a str) -> OperationFilterName {
+        match value {
+            "NAMESPACE_ID" => OperationFilterName::NamespaceId,
+            "SERVICE_ID" => OperationFilterName::ServiceId,
+            "STATUS" => OperationFilterName::Status,
+            "TYPE" => OperationFilterName::Type,
+            "UPDATE_DATE" => OperationFilterName::UpdateDate,
+            _ => OperationFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationFilterName {
+    fn from(value: String) -> OperationFilterName {
+        OperationFilterName::from(value.as_str())
+    }
+}
+
+impl From<OperationFilterName> for String {
+    fn from(value: OperationFilterName) -> String {
+        match value {
+            OperationFilterName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OperationFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OperationFilterName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationFilterName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OperationFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OperationFilterName::from(value))
+    }
+}
+
+/// <p><p>The status of the operation. Values include the following:</p> <ul> <li> <p> <b>SUBMITTED</b>: This is the initial state immediately after you submit a request.</p> </li> <li> <p> <b>PENDING</b>: AWS Cloud Map is performing the operation.</p> </li> <li> <p> <b>SUCCESS</b>: The operation succeeded.</p> </li> <li> <p> <b>FAIL</b>: The operation failed. For the failure reason, see <code>ErrorMessage</code>.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperationStatus {
+    /// <p><code>SUBMITTED</code></p>
+    Submitted,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// <p><code>FAIL</code></p>
+    Fail,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OperationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationStatus::Submitted => "SUBMITTED",
+            OperationStatus::Pending => "PENDING",
+            OperationStatus::Success => "SUCCESS",
+            OperationStatus::Fail => "FAIL",
+            OperationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperationStatus {
+    fn from(value: &'a str) -> OperationStatus {
+        match value {
+            "SUBMITTED" => OperationStatus::Submitted,
+            "PENDING" => OperationStatus::Pending,
+            "SUCCESS" => OperationStatus::Success,
+            "FAIL" => OperationStatus::Fail,
+            _ => OperationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationStatus {
+    fn from(value: String) -> OperationStatus {
+        OperationStatus::from(value.as_str())
+    }
+}
+
+impl From<OperationStatus> for String {
+    fn from(value: OperationStatus) -> String {
+        match value {
+            OperationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OperationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OperationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OperationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OperationStatus::from(value))
+    }
+}
+
 /// <p>A complex type that contains information about an operation that matches the criteria that you specified in a <a>ListOperations</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -734,7 +2199,251 @@ pub struct OperationSummary {
     /// <p><p>The status of the operation. Values include the following:</p> <ul> <li> <p> <b>SUBMITTED</b>: This is the initial state immediately after you submit a request.</p> </li> <li> <p> <b>PENDING</b>: AWS Cloud Map is performing the operation.</p> </li> <li> <p> <b>SUCCESS</b>: The operation succeeded.</p> </li> <li> <p> <b>FAIL</b>: The operation failed. For the failure reason, see <code>ErrorMessage</code>.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OperationStatus>,
+}
+
+/// <p><p>The name of the target entity that is associated with the operation:</p> <ul> <li> <p> <b>NAMESPACE</b>: The namespace ID is returned in the <code>ResourceId</code> property.</p> </li> <li> <p> <b>SERVICE</b>: The service ID is returned in the <code>ResourceId</code> property.</p> </li> <li> <p> <b>INSTANCE</b>: The instance ID is returned in the <code>ResourceId</code> property.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperationTargetType {
+    /// <p><code>NAMESPACE</code></p>
+    Namespace,
+    /// <p><code>SERVICE</code></p>
+    Service,
+    /// <p><code>INSTANCE</code></p>
+    Instance,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OperationTargetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationTargetType::Namespace => "NAMESPACE",
+            OperationTargetType::Service => "SERVICE",
+            OperationTargetType::Instance => "INSTANCE",
+            OperationTargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperationTargetType {
+    fn from(value: &'a str) -> OperationTargetType {
+        match value {
+            "NAMESPACE" => OperationTargetType::Namespace,
+            "SERVICE" => OperationTargetType::Service,
+            "INSTANCE" => OperationTargetType::Instance,
+            _ => OperationTargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationTargetType {
+    fn from(value: String) -> OperationTargetType {
+        OperationTargetType::from(value.as_str())
+    }
+}
+
+impl From<OperationTargetType> for String {
+    fn from(value: OperationTargetType) -> String {
+        match value {
+            OperationTargetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OperationTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OperationTargetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationTargetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OperationTargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OperationTargetType::from(value))
+    }
+}
+
+/// <p>The name of the operation that is associated with the specified ID.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperationType {
+    /// <p><code>CREATE_NAMESPACE</code></p>
+    CreateNamespace,
+    /// <p><code>DELETE_NAMESPACE</code></p>
+    DeleteNamespace,
+    /// <p><code>UPDATE_NAMESPACE</code></p>
+    UpdateNamespace,
+    /// <p><code>UPDATE_SERVICE</code></p>
+    UpdateService,
+    /// <p><code>REGISTER_INSTANCE</code></p>
+    RegisterInstance,
+    /// <p><code>DEREGISTER_INSTANCE</code></p>
+    DeregisterInstance,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OperationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationType::CreateNamespace => "CREATE_NAMESPACE",
+            OperationType::DeleteNamespace => "DELETE_NAMESPACE",
+            OperationType::UpdateNamespace => "UPDATE_NAMESPACE",
+            OperationType::UpdateService => "UPDATE_SERVICE",
+            OperationType::RegisterInstance => "REGISTER_INSTANCE",
+            OperationType::DeregisterInstance => "DEREGISTER_INSTANCE",
+            OperationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperationType {
+    fn from(value: &'a str) -> OperationType {
+        match value {
+            "CREATE_NAMESPACE" => OperationType::CreateNamespace,
+            "DELETE_NAMESPACE" => OperationType::DeleteNamespace,
+            "UPDATE_NAMESPACE" => OperationType::UpdateNamespace,
+            "UPDATE_SERVICE" => OperationType::UpdateService,
+            "REGISTER_INSTANCE" => OperationType::RegisterInstance,
+            "DEREGISTER_INSTANCE" => OperationType::DeregisterInstance,
+            _ => OperationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationType {
+    fn from(value: String) -> OperationType {
+        OperationType::from(value.as_str())
+    }
+}
+
+impl From<OperationType> for String {
+    fn from(value: OperationType) -> String {
+        match value {
+            OperationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OperationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OperationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OperationType::from(value))
+    }
+}
+
+/// <p>The type of the resource, which indicates the type of value that Route 53 returns in response to DNS queries.</p> <p>Note the following:</p> <ul> <li> <p> <b>A, AAAA, and SRV records:</b> You can specify settings for a maximum of one A, one AAAA, and one SRV record. You can specify them in any combination.</p> </li> <li> <p> <b>CNAME records:</b> If you specify <code>CNAME</code> for <code>Type</code>, you can't define any other records. This is a limitation of DNS: you can't create a CNAME record and any other type of record that has the same name as a CNAME record.</p> </li> <li> <p> <b>Alias records:</b> If you want AWS Cloud Map to create a Route 53 alias record when you register an instance, specify <code>A</code> or <code>AAAA</code> for <code>Type</code>.</p> </li> <li> <p> <b>All records:</b> You specify settings other than <code>TTL</code> and <code>Type</code> when you register an instance.</p> </li> </ul> <p>The following values are supported:</p> <p> <b>A</b> </p> <p>Route 53 returns the IP address of the resource in IPv4 format, such as 192.0.2.44.</p> <p> <b>AAAA</b> </p> <p>Route 53 returns the IP address of the resource in IPv6 format, such as 2001:0db8:85a3:0000:0000:abcd:0001:2345.</p> <p> <b>CNAME</b> </p> <p>Route 53 returns the domain name of the resource, such as www.example.com. Note the following:</p> <ul> <li> <p>You specify the domain name that you want to route traffic to when you register an instance. For more information, see <a>RegisterInstanceRequest$Attributes</a>.</p> </li> <li> <p>You must specify <code>WEIGHTED</code> for the value of <code>RoutingPolicy</code>.</p> </li> <li> <p>You can't specify both <code>CNAME</code> for <code>Type</code> and settings for <code>HealthCheckConfig</code>. If you do, the request will fail with an <code>InvalidInput</code> error.</p> </li> </ul> <p> <b>SRV</b> </p> <p>Route 53 returns the value for an SRV record. The value for an SRV record uses the following values:</p> <p> <code>priority weight port service-hostname</code> </p> <p>Note the following about the values:</p> <ul> <li> <p>The values of <code>priority</code> and <code>weight</code> are both set to <code>1</code> and can't be changed. </p> </li> <li> <p>The value of <code>port</code> comes from the value that you specify for the <code>AWS_INSTANCE_PORT</code> attribute when you submit a <a>RegisterInstance</a> request. </p> </li> <li> <p>The value of <code>service-hostname</code> is a concatenation of the following values:</p> <ul> <li> <p>The value that you specify for <code>InstanceId</code> when you register an instance.</p> </li> <li> <p>The name of the service.</p> </li> <li> <p>The name of the namespace. </p> </li> </ul> <p>For example, if the value of <code>InstanceId</code> is <code>test</code>, the name of the service is <code>backend</code>, and the name of the namespace is <code>example.com</code>, the value of <code>service-hostname</code> is:</p> <p> <code>test.backend.example.com</code> </p> </li> </ul> <p>If you specify settings for an SRV record and if you specify values for <code>AWS_INSTANCE_IPV4</code>, <code>AWS_INSTANCE_IPV6</code>, or both in the <code>RegisterInstance</code> request, AWS Cloud Map automatically creates <code>A</code> and/or <code>AAAA</code> records that have the same name as the value of <code>service-hostname</code> in the SRV record. You can ignore these records.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordType {
+    /// <p><code>SRV</code></p>
+    Srv,
+    /// <p><code>A</code></p>
+    A,
+    /// <p><code>AAAA</code></p>
+    Aaaa,
+    /// <p><code>CNAME</code></p>
+    Cname,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RecordType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordType::Srv => "SRV",
+            RecordType::A => "A",
+            RecordType::Aaaa => "AAAA",
+            RecordType::Cname => "CNAME",
+            RecordType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RecordType {
+    fn from(value: &'a str) -> RecordType {
+        match value {
+            "SRV" => RecordType::Srv,
+            "A" => RecordType::A,
+            "AAAA" => RecordType::Aaaa,
+            "CNAME" => RecordType::Cname,
+            _ => RecordType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordType {
+    fn from(value: String) -> RecordType {
+        RecordType::from(value.as_str())
+    }
+}
+
+impl From<RecordType> for String {
+    fn from(value: RecordType) -> String {
+        match value {
+            RecordType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RecordType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RecordType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RecordType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RecordType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -754,6 +2463,69 @@ pub struct RegisterInstanceRequest {
     pub service_id: String,
 }
 
+impl RegisterInstanceRequest {
+    /// Returns a builder for `RegisterInstanceRequest`, checking its required members when built.
+    pub fn builder() -> RegisterInstanceRequestBuilder {
+        RegisterInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterInstanceRequest`](struct.RegisterInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterInstanceRequestBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+    creator_request_id: Option<String>,
+    instance_id: Option<String>,
+    service_id: Option<String>,
+}
+
+impl RegisterInstanceRequestBuilder {
+    /// Sets the [`attributes`](struct.RegisterInstanceRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`creator_request_id`](struct.RegisterInstanceRequest.html#structfield.creator_request_id) member.
+    pub fn creator_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.creator_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.RegisterInstanceRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_id`](struct.RegisterInstanceRequest.html#structfield.service_id) member.
+    pub fn service_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterInstanceRequest {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            creator_request_id: self.creator_request_id,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            service_id: self
+                .service_id
+                .ok_or(::rusoto_core::BuildError::missing("service_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RegisterInstanceResponse {
@@ -763,6 +2535,78 @@ pub struct RegisterInstanceResponse {
     pub operation_id: Option<String>,
 }
 
+/// <p>The routing policy that you want to apply to all Route 53 DNS records that AWS Cloud Map creates when you register an instance and specify this service.</p> <note> <p>If you want to use this service to register instances that create alias records, specify <code>WEIGHTED</code> for the routing policy.</p> </note> <p>You can specify the following values:</p> <p> <b>MULTIVALUE</b> </p> <p>If you define a health check for the service and the health check is healthy, Route 53 returns the applicable value for up to eight instances.</p> <p>For example, suppose the service includes configurations for one A record and a health check, and you use the service to register 10 instances. Route 53 responds to DNS queries with IP addresses for up to eight healthy instances. If fewer than eight instances are healthy, Route 53 responds to every DNS query with the IP addresses for all of the healthy instances.</p> <p>If you don't define a health check for the service, Route 53 assumes that all instances are healthy and returns the values for up to eight instances.</p> <p>For more information about the multivalue routing policy, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/routing-policy.html#routing-policy-multivalue">Multivalue Answer Routing</a> in the <i>Route 53 Developer Guide</i>.</p> <p> <b>WEIGHTED</b> </p> <p>Route 53 returns the applicable value from one randomly selected instance from among the instances that you registered using the same service. Currently, all records have the same weight, so you can't route more or less traffic to any instances.</p> <p>For example, suppose the service includes configurations for one A record and a health check, and you use the service to register 10 instances. Route 53 responds to DNS queries with the IP address for one randomly selected instance from among the healthy instances. If no instances are healthy, Route 53 responds to DNS queries as if all of the instances were healthy.</p> <p>If you don't define a health check for the service, Route 53 assumes that all instances are healthy and returns the applicable value for one randomly selected instance.</p> <p>For more information about the weighted routing policy, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/routing-policy.html#routing-policy-weighted">Weighted Routing</a> in the <i>Route 53 Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoutingPolicy {
+    /// <p><code>MULTIVALUE</code></p>
+    Multivalue,
+    /// <p><code>WEIGHTED</code></p>
+    Weighted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RoutingPolicy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RoutingPolicy::Multivalue => "MULTIVALUE",
+            RoutingPolicy::Weighted => "WEIGHTED",
+            RoutingPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RoutingPolicy {
+    fn from(value: &'a str) -> RoutingPolicy {
+        match value {
+            "MULTIVALUE" => RoutingPolicy::Multivalue,
+            "WEIGHTED" => RoutingPolicy::Weighted,
+            _ => RoutingPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RoutingPolicy {
+    fn from(value: String) -> RoutingPolicy {
+        RoutingPolicy::from(value.as_str())
+    }
+}
+
+impl From<RoutingPolicy> for String {
+    fn from(value: RoutingPolicy) -> String {
+        match value {
+            RoutingPolicy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RoutingPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RoutingPolicy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RoutingPolicy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RoutingPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RoutingPolicy::from(value))
+    }
+}
+
 /// <p>A complex type that contains information about the specified service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -773,8 +2617,9 @@ pub struct Service {
     pub arn: Option<String>,
     /// <p>The date and time that the service was created, in Unix format and Coordinated Universal Time (UTC). The value of <code>CreateDate</code> is accurate to milliseconds. For example, the value <code>1516925490.087</code> represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<EpochTimestamp>,
     /// <p>A unique string that identifies the request and that allows failed requests to be retried without the risk of executing the operation twice. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -829,20 +2674,88 @@ pub struct ServiceChange {
 }
 
 /// <p>A complex type that lets you specify the namespaces that you want to list services for.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ServiceFilter {
     /// <p><p>The operator that you want to use to determine whether a service is returned by <code>ListServices</code>. Valid values for <code>Condition</code> include the following:</p> <ul> <li> <p> <code>EQ</code>: When you specify <code>EQ</code>, specify one namespace ID for <code>Values</code>. <code>EQ</code> is the default condition and can be omitted.</p> </li> <li> <p> <code>IN</code>: When you specify <code>IN</code>, specify a list of the IDs for the namespaces that you want <code>ListServices</code> to return a list of services for.</p> </li> <li> <p> <code>BETWEEN</code>: Not applicable.</p> </li> </ul></p>
     #[serde(rename = "Condition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub condition: Option<String>,
+    pub condition: Option<FilterCondition>,
     /// <p>Specify <code>NAMESPACE_ID</code>.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: ServiceFilterName,
     /// <p>The values that are applicable to the value that you specify for <code>Condition</code> to filter the list of services.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+/// <p>Specify <code>NAMESPACE_ID</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceFilterName {
+    /// <p><code>NAMESPACE_ID</code></p>
+    NamespaceId,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServiceFilterName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceFilterName::NamespaceId => "NAMESPACE_ID",
+            ServiceFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServiceFilterName {
+    fn from(value: &'a str) -> ServiceFilterName {
+        match value {
+            "NAMESPACE_ID" => ServiceFilterName::NamespaceId,
+            _ => ServiceFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceFilterName {
+    fn from(value: String) -> ServiceFilterName {
+        ServiceFilterName::from(value.as_str())
+    }
+}
+
+impl From<ServiceFilterName> for String {
+    fn from(value: ServiceFilterName) -> String {
+        match value {
+            ServiceFilterName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServiceFilterName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceFilterName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ServiceFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServiceFilterName::from(value))
+    }
+}
+
 /// <p>A complex type that contains information about a specified service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -853,8 +2766,9 @@ pub struct ServiceSummary {
     pub arn: Option<String>,
     /// <p>The date and time that the service was created.</p>
     #[serde(rename = "CreateDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<EpochTimestamp>,
     /// <p>The description that you specify when you create the service.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -882,7 +2796,7 @@ pub struct ServiceSummary {
     pub name: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateInstanceCustomHealthStatusRequest {
     /// <p>The ID of the instance that you want to change the health status for.</p>
     #[serde(rename = "InstanceId")]
@@ -892,7 +2806,60 @@ pub struct UpdateInstanceCustomHealthStatusRequest {
     pub service_id: String,
     /// <p>The new status of the instance, <code>HEALTHY</code> or <code>UNHEALTHY</code>.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: CustomHealthStatus,
+}
+
+impl UpdateInstanceCustomHealthStatusRequest {
+    /// Returns a builder for `UpdateInstanceCustomHealthStatusRequest`, checking its required members when built.
+    pub fn builder() -> UpdateInstanceCustomHealthStatusRequestBuilder {
+        UpdateInstanceCustomHealthStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateInstanceCustomHealthStatusRequest`](struct.UpdateInstanceCustomHealthStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateInstanceCustomHealthStatusRequestBuilder {
+    instance_id: Option<String>,
+    service_id: Option<String>,
+    status: Option<CustomHealthStatus>,
+}
+
+impl UpdateInstanceCustomHealthStatusRequestBuilder {
+    /// Sets the [`instance_id`](struct.UpdateInstanceCustomHealthStatusRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_id`](struct.UpdateInstanceCustomHealthStatusRequest.html#structfield.service_id) member.
+    pub fn service_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.UpdateInstanceCustomHealthStatusRequest.html#structfield.status) member.
+    pub fn status<T: Into<CustomHealthStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateInstanceCustomHealthStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateInstanceCustomHealthStatusRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateInstanceCustomHealthStatusRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            service_id: self
+                .service_id
+                .ok_or(::rusoto_core::BuildError::missing("service_id"))?,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -905,6 +2872,44 @@ pub struct UpdateServiceRequest {
     pub service: ServiceChange,
 }
 
+impl UpdateServiceRequest {
+    /// Returns a builder for `UpdateServiceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateServiceRequestBuilder {
+        UpdateServiceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateServiceRequest`](struct.UpdateServiceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateServiceRequestBuilder {
+    id: Option<String>,
+    service: Option<ServiceChange>,
+}
+
+impl UpdateServiceRequestBuilder {
+    /// Sets the [`id`](struct.UpdateServiceRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service`](struct.UpdateServiceRequest.html#structfield.service) member.
+    pub fn service<T: Into<ServiceChange>>(mut self, value: T) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateServiceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateServiceRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateServiceRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            service: self
+                .service
+                .ok_or(::rusoto_core::BuildError::missing("service"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateServiceResponse {
@@ -1976,6 +3981,135 @@ pub trait ServiceDiscovery {
         input: UpdateServiceRequest,
     ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError>;
 }
+impl<T: ?Sized + ServiceDiscovery> ServiceDiscovery for ::std::sync::Arc<T> {
+    fn create_http_namespace(
+        &self,
+        input: CreateHttpNamespaceRequest,
+    ) -> RusotoFuture<CreateHttpNamespaceResponse, CreateHttpNamespaceError> {
+        (**self).create_http_namespace(input)
+    }
+    fn create_private_dns_namespace(
+        &self,
+        input: CreatePrivateDnsNamespaceRequest,
+    ) -> RusotoFuture<CreatePrivateDnsNamespaceResponse, CreatePrivateDnsNamespaceError> {
+        (**self).create_private_dns_namespace(input)
+    }
+    fn create_public_dns_namespace(
+        &self,
+        input: CreatePublicDnsNamespaceRequest,
+    ) -> RusotoFuture<CreatePublicDnsNamespaceResponse, CreatePublicDnsNamespaceError> {
+        (**self).create_public_dns_namespace(input)
+    }
+    fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
+        (**self).create_service(input)
+    }
+    fn delete_namespace(
+        &self,
+        input: DeleteNamespaceRequest,
+    ) -> RusotoFuture<DeleteNamespaceResponse, DeleteNamespaceError> {
+        (**self).delete_namespace(input)
+    }
+    fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
+        (**self).delete_service(input)
+    }
+    fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> RusotoFuture<DeregisterInstanceResponse, DeregisterInstanceError> {
+        (**self).deregister_instance(input)
+    }
+    fn discover_instances(
+        &self,
+        input: DiscoverInstancesRequest,
+    ) -> RusotoFuture<DiscoverInstancesResponse, DiscoverInstancesError> {
+        (**self).discover_instances(input)
+    }
+    fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> RusotoFuture<GetInstanceResponse, GetInstanceError> {
+        (**self).get_instance(input)
+    }
+    fn get_instances_health_status(
+        &self,
+        input: GetInstancesHealthStatusRequest,
+    ) -> RusotoFuture<GetInstancesHealthStatusResponse, GetInstancesHealthStatusError> {
+        (**self).get_instances_health_status(input)
+    }
+    fn get_namespace(
+        &self,
+        input: GetNamespaceRequest,
+    ) -> RusotoFuture<GetNamespaceResponse, GetNamespaceError> {
+        (**self).get_namespace(input)
+    }
+    fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> RusotoFuture<GetOperationResponse, GetOperationError> {
+        (**self).get_operation(input)
+    }
+    fn get_service(
+        &self,
+        input: GetServiceRequest,
+    ) -> RusotoFuture<GetServiceResponse, GetServiceError> {
+        (**self).get_service(input)
+    }
+    fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> RusotoFuture<ListInstancesResponse, ListInstancesError> {
+        (**self).list_instances(input)
+    }
+    fn list_namespaces(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> RusotoFuture<ListNamespacesResponse, ListNamespacesError> {
+        (**self).list_namespaces(input)
+    }
+    fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        (**self).list_operations(input)
+    }
+    fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
+        (**self).list_services(input)
+    }
+    fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> RusotoFuture<RegisterInstanceResponse, RegisterInstanceError> {
+        (**self).register_instance(input)
+    }
+    fn update_instance_custom_health_status(
+        &self,
+        input: UpdateInstanceCustomHealthStatusRequest,
+    ) -> RusotoFuture<(), UpdateInstanceCustomHealthStatusError> {
+        (**self).update_instance_custom_health_status(input)
+    }
+    fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
+        (**self).update_service(input)
+    }
+}
+/// The error codes ServiceDiscovery retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the ServiceDiscovery API.
 #[derive(Clone)]
 pub struct ServiceDiscoveryClient {
@@ -1991,6 +4125,27 @@ impl ServiceDiscoveryClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SERVICEDISCOVERY`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ServiceDiscoveryClient {
+        Self::new(region::Region::from_env_for_service("SERVICEDISCOVERY"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `servicediscovery`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ServiceDiscoveryClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("servicediscovery", "SERVICEDISCOVERY"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2009,7 +4164,54 @@ impl ServiceDiscoveryClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ServiceDiscoveryClient {
-        ServiceDiscoveryClient { client, region }
+        ServiceDiscoveryClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "servicediscovery")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "servicediscovery",
+            "servicediscovery",
+            "SERVICEDISCOVERY",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ServiceDiscovery` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ServiceDiscovery + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ServiceDiscoveryClient {
+    fn service_name(&self) -> &'static str {
+        "ServiceDiscovery"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "servicediscovery"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ServiceDiscoveryClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Simple Email Service clients.
+//!
+//! Makes a single read-only `ListConfigurationSets` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_ses::{ListConfigurationSetsRequest, Ses, SesClient};
+
+fn main() {
+    let client = SesClient::from_env();
+    match client
+        .list_configuration_sets(ListConfigurationSetsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListConfigurationSets succeeded"),
+        Err(error) => {
+            eprintln!("ListConfigurationSets failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -1 +1,2 @@
-
+/// Building MIME messages with attachments and sending them with `SendRawEmail`
+pub mod raw_email;
@@ -0,0 +1,610 @@
+//! Building MIME messages with attachments and sending them with `SendRawEmail`.
+//!
+//! `SendEmail` only sends text and HTML bodies. Attachments and inline images need a multipart
+//! MIME message sent with `SendRawEmail`. `RawEmailBuilder` assembles it:
+//!
+//! * the text and HTML bodies are alternatives of each other (`multipart/alternative`),
+//! * inline images are related to the HTML body (`multipart/related`) and referenced from it
+//!   as `cid:<content id>`,
+//! * attachments follow the body (`multipart/mixed`).
+//!
+//! Bodies and attachments are base64-encoded, and header values which aren't ASCII are encoded
+//! as RFC 2047 encoded words. Messages larger than SES accepts are rejected before being sent.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use rusoto_core::Region;
+//! use rusoto_ses::raw_email::{RawEmailBuilder, RawEmailExt};
+//! use rusoto_ses::SesClient;
+//!
+//! let client = SesClient::new(Region::UsEast1);
+//! let email = RawEmailBuilder::new()
+//!     .from("Reports <reports@example.com>")
+//!     .to("alice@example.com")
+//!     .subject("Monthly report")
+//!     .text("The report is attached.")
+//!     .html("<p>The report is attached.</p><img src=\"cid:logo\">")
+//!     .inline_image("logo", "logo.png", "image/png", std::fs::read("logo.png").unwrap())
+//!     .attachment("report.pdf", "application/pdf", std::fs::read("report.pdf").unwrap());
+//! let message_id = client.send_raw_email_message(email).sync().unwrap().message_id;
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use bytes::Bytes;
+use futures::Future;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    RawMessage, SendRawEmailError, SendRawEmailRequest, SendRawEmailResponse, Ses,
+};
+
+/// The largest message SES accepts, once base64-encoded: 10 MB.
+pub const MAX_MESSAGE_SIZE: usize = 10 * 1024 * 1024;
+
+/// The most characters of base64 per line of a MIME part.
+const LINE_LENGTH: usize = 76;
+
+/// The most bytes of text per encoded word, so that each fits on a line.
+const ENCODED_WORD_BYTES: usize = 45;
+
+/// A file attached to a message, or an image shown inline by its HTML body.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attachment {
+    /// The name of the file, e.g. `report.pdf`
+    pub filename: String,
+    /// The MIME type of the file, e.g. `application/pdf`
+    pub content_type: String,
+    /// The contents of the file
+    pub data: Bytes,
+    /// The ID the HTML body refers to an inline image by, as `cid:<content_id>`
+    pub content_id: Option<String>,
+}
+
+/// Errors returned while building or sending a raw email.
+#[derive(Debug, PartialEq)]
+pub enum RawEmailError {
+    /// Sending the message failed.
+    SendRawEmail(SendRawEmailError),
+    /// The message has no `From` address.
+    MissingSender,
+    /// The message has no recipients.
+    MissingRecipients,
+    /// A header, address or file name contains a line break.
+    InvalidHeader(String),
+    /// The message is larger than SES, or the configured limit, accepts.
+    TooLarge {
+        /// The size of the base64-encoded message
+        size: usize,
+        /// The largest size accepted
+        limit: usize,
+    },
+}
+
+impl fmt::Display for RawEmailError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RawEmailError::InvalidHeader(ref header) => {
+                write!(f, "Header contains a line break: {}", header)
+            }
+            RawEmailError::TooLarge { size, limit } => write!(
+                f,
+                "Message of {} bytes is larger than the limit of {} bytes",
+                size, limit
+            ),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for RawEmailError {
+    fn description(&self) -> &str {
+        match *self {
+            RawEmailError::SendRawEmail(ref cause) => cause.description(),
+            RawEmailError::MissingSender => "Message has no From address",
+            RawEmailError::MissingRecipients => "Message has no recipients",
+            RawEmailError::InvalidHeader(_) => "Header contains a line break",
+            RawEmailError::TooLarge { .. } => "Message is too large",
+        }
+    }
+}
+
+/// Builds a multipart MIME message.
+#[derive(Clone, Debug)]
+pub struct RawEmailBuilder {
+    from: Option<String>,
+    to: Vec<String>,
+    cc: Vec<String>,
+    bcc: Vec<String>,
+    reply_to: Vec<String>,
+    subject: Option<String>,
+    headers: Vec<(String, String)>,
+    text: Option<String>,
+    html: Option<String>,
+    inline_images: Vec<Attachment>,
+    attachments: Vec<Attachment>,
+    configuration_set_name: Option<String>,
+    max_size: usize,
+}
+
+impl Default for RawEmailBuilder {
+    fn default() -> RawEmailBuilder {
+        RawEmailBuilder {
+            from: None,
+            to: Vec::new(),
+            cc: Vec::new(),
+            bcc: Vec::new(),
+            reply_to: Vec::new(),
+            subject: None,
+            headers: Vec::new(),
+            text: None,
+            html: None,
+            inline_images: Vec::new(),
+            attachments: Vec::new(),
+            configuration_set_name: None,
+            max_size: MAX_MESSAGE_SIZE,
+        }
+    }
+}
+
+impl RawEmailBuilder {
+    /// An empty message.
+    pub fn new() -> RawEmailBuilder {
+        RawEmailBuilder::default()
+    }
+
+    /// Sets the sender, e.g. `reports@example.com` or `Reports <reports@example.com>`.
+    pub fn from<T: Into<String>>(mut self, address: T) -> Self {
+        self.from = Some(address.into());
+        self
+    }
+
+    /// Adds a `To` recipient.
+    pub fn to<T: Into<String>>(mut self, address: T) -> Self {
+        self.to.push(address.into());
+        self
+    }
+
+    /// Adds a `Cc` recipient.
+    pub fn cc<T: Into<String>>(mut self, address: T) -> Self {
+        self.cc.push(address.into());
+        self
+    }
+
+    /// Adds a `Bcc` recipient, who is sent the message without being named in its headers.
+    pub fn bcc<T: Into<String>>(mut self, address: T) -> Self {
+        self.bcc.push(address.into());
+        self
+    }
+
+    /// Adds a `Reply-To` address.
+    pub fn reply_to<T: Into<String>>(mut self, address: T) -> Self {
+        self.reply_to.push(address.into());
+        self
+    }
+
+    /// Sets the subject.
+    pub fn subject<T: Into<String>>(mut self, subject: T) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    /// Adds a header, e.g. `List-Unsubscribe`.
+    pub fn header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the plain text body.
+    pub fn text<T: Into<String>>(mut self, text: T) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Sets the HTML body.
+    pub fn html<T: Into<String>>(mut self, html: T) -> Self {
+        self.html = Some(html.into());
+        self
+    }
+
+    /// Attaches a file.
+    pub fn attachment<F, C, D>(mut self, filename: F, content_type: C, data: D) -> Self
+    where
+        F: Into<String>,
+        C: Into<String>,
+        D: Into<Bytes>,
+    {
+        self.attachments.push(Attachment {
+            filename: filename.into(),
+            content_type: content_type.into(),
+            data: data.into(),
+            content_id: None,
+        });
+        self
+    }
+
+    /// Adds an image the HTML body shows with `<img src="cid:<content_id>">`.
+    pub fn inline_image<I, F, C, D>(
+        mut self,
+        content_id: I,
+        filename: F,
+        content_type: C,
+        data: D,
+    ) -> Self
+    where
+        I: Into<String>,
+        F: Into<String>,
+        C: Into<String>,
+        D: Into<Bytes>,
+    {
+        self.inline_images.push(Attachment {
+            filename: filename.into(),
+            content_type: content_type.into(),
+            data: data.into(),
+            content_id: Some(content_id.into()),
+        });
+        self
+    }
+
+    /// Sets the configuration set the message is sent with.
+    pub fn configuration_set_name<T: Into<String>>(mut self, name: T) -> Self {
+        self.configuration_set_name = Some(name.into());
+        self
+    }
+
+    /// Sets the largest size of the base64-encoded message, `MAX_MESSAGE_SIZE` by default.
+    pub fn max_size(mut self, bytes: usize) -> Self {
+        self.max_size = bytes;
+        self
+    }
+
+    /// Builds the MIME message.
+    pub fn build(&self) -> Result<Bytes, RawEmailError> {
+        let from = self.from.as_ref().ok_or(RawEmailError::MissingSender)?;
+        if self.to.is_empty() && self.cc.is_empty() && self.bcc.is_empty() {
+            return Err(RawEmailError::MissingRecipients);
+        }
+
+        let mut message = String::new();
+        write_header(&mut message, "From", &encode_addresses(&[from.clone()]))?;
+        if !self.to.is_empty() {
+            write_header(&mut message, "To", &encode_addresses(&self.to))?;
+        }
+        if !self.cc.is_empty() {
+            write_header(&mut message, "Cc", &encode_addresses(&self.cc))?;
+        }
+        if !self.reply_to.is_empty() {
+            write_header(&mut message, "Reply-To", &encode_addresses(&self.reply_to))?;
+        }
+        if let Some(ref subject) = self.subject {
+            write_header(&mut message, "Subject", &encode_words(subject))?;
+        }
+        for &(ref name, ref value) in &self.headers {
+            write_header(&mut message, name, &encode_words(value))?;
+        }
+        write_header(&mut message, "MIME-Version", "1.0")?;
+        self.write_body(&mut message)?;
+
+        let size = (message.len() + 2) / 3 * 4;
+        if size > self.max_size {
+            return Err(RawEmailError::TooLarge {
+                size,
+                limit: self.max_size,
+            });
+        }
+        Ok(Bytes::from(message))
+    }
+
+    /// Builds the `SendRawEmail` request sending the message to all its recipients.
+    pub fn build_request(&self) -> Result<SendRawEmailRequest, RawEmailError> {
+        let message = self.build()?;
+        let destinations = self
+            .to
+            .iter()
+            .chain(&self.cc)
+            .chain(&self.bcc)
+            .cloned()
+            .collect();
+        Ok(SendRawEmailRequest {
+            configuration_set_name: self.configuration_set_name.clone(),
+            destinations: Some(destinations),
+            // the Query protocol sends the raw message base64-encoded
+            raw_message: RawMessage {
+                data: Bytes::from(base64::encode(&message)),
+            },
+            ..Default::default()
+        })
+    }
+
+    fn write_body(&self, message: &mut String) -> Result<(), RawEmailError> {
+        let mut body = self.alternatives();
+        if !self.inline_images.is_empty() {
+            let mut parts = vec![body];
+            for image in &self.inline_images {
+                parts.push(attachment_part(image, "inline")?);
+            }
+            body = multipart("related", &parts);
+        }
+        if !self.attachments.is_empty() {
+            let mut parts = vec![body];
+            for attachment in &self.attachments {
+                parts.push(attachment_part(attachment, "attachment")?);
+            }
+            body = multipart("mixed", &parts);
+        }
+        message.push_str(&body);
+        Ok(())
+    }
+
+    /// The text and HTML bodies, as alternatives when there are both.
+    fn alternatives(&self) -> String {
+        let text = self.text.as_ref().map(|text| text_part("plain", text));
+        let html = self.html.as_ref().map(|html| text_part("html", html));
+        match (text, html) {
+            (Some(text), Some(html)) => multipart("alternative", &[text, html]),
+            (Some(part), None) | (None, Some(part)) => part,
+            (None, None) => text_part("plain", ""),
+        }
+    }
+}
+
+/// Extension methods for sending messages built by `RawEmailBuilder`.
+pub trait RawEmailExt: Ses {
+    /// Builds the message and sends it with `SendRawEmail`.
+    fn send_raw_email_message(
+        &self,
+        email: RawEmailBuilder,
+    ) -> RusotoFuture<SendRawEmailResponse, RawEmailError> {
+        let request = match email.build_request() {
+            Ok(request) => request,
+            Err(e) => return RusotoFuture::from_future(Err(RusotoError::Service(e))),
+        };
+        RusotoFuture::from_future(
+            self.send_raw_email(request)
+                .map_err(|e| e.map_service(RawEmailError::SendRawEmail)),
+        )
+    }
+}
+
+impl<T: Ses> RawEmailExt for T {}
+
+fn write_header(message: &mut String, name: &str, value: &str) -> Result<(), RawEmailError> {
+    if name.contains(|c| c == '\r' || c == '\n') || value.contains(|c| c == '\r' || c == '\n') {
+        return Err(RawEmailError::InvalidHeader(name.to_owned()));
+    }
+    message.push_str(name);
+    message.push_str(": ");
+    message.push_str(value);
+    message.push_str("\r\n");
+    Ok(())
+}
+
+/// Encodes text which isn't ASCII as RFC 2047 encoded words, each short enough for a line.
+fn encode_words(text: &str) -> String {
+    if text.is_ascii() {
+        return text.to_owned();
+    }
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut end = 0;
+    for (index, c) in text.char_indices() {
+        if index + c.len_utf8() - start > ENCODED_WORD_BYTES {
+            words.push(&text[start..end]);
+            start = end;
+        }
+        end = index + c.len_utf8();
+    }
+    words.push(&text[start..end]);
+    words
+        .iter()
+        .map(|word| format!("=?UTF-8?B?{}?=", base64::encode(word)))
+        .collect::<Vec<_>>()
+        // the space between encoded words is dropped when they are decoded
+        .join(" ")
+}
+
+/// Encodes the display names of addresses which aren't ASCII, e.g. `José <jose@example.com>`.
+fn encode_addresses(addresses: &[String]) -> String {
+    addresses
+        .iter()
+        .map(|address| match address.rfind('<') {
+            Some(index) if !address.is_ascii() => format!(
+                "{} {}",
+                encode_words(address[..index].trim()),
+                &address[index..]
+            ),
+            _ => address.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Quotes a parameter of a header, such as a file name.
+fn quote(value: &str) -> String {
+    if value.is_ascii() {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        format!("\"{}\"", encode_words(value))
+    }
+}
+
+fn base64_lines(data: &[u8]) -> String {
+    let encoded = base64::encode(data);
+    let mut lines = String::with_capacity(encoded.len() + encoded.len() / LINE_LENGTH * 2 + 2);
+    for chunk in encoded.as_bytes().chunks(LINE_LENGTH) {
+        // base64 is ASCII
+        lines.push_str(std::str::from_utf8(chunk).unwrap_or_default());
+        lines.push_str("\r\n");
+    }
+    lines
+}
+
+fn text_part(subtype: &str, text: &str) -> String {
+    format!(
+        "Content-Type: text/{}; charset=UTF-8\r\nContent-Transfer-Encoding: base64\r\n\r\n{}",
+        subtype,
+        base64_lines(text.as_bytes())
+    )
+}
+
+fn attachment_part(attachment: &Attachment, disposition: &str) -> Result<String, RawEmailError> {
+    let mut part = String::new();
+    let filename = quote(&attachment.filename);
+    write_header(
+        &mut part,
+        "Content-Type",
+        &format!("{}; name={}", attachment.content_type, filename),
+    )?;
+    write_header(
+        &mut part,
+        "Content-Disposition",
+        &format!("{}; filename={}", disposition, filename),
+    )?;
+    if let Some(ref content_id) = attachment.content_id {
+        write_header(&mut part, "Content-ID", &format!("<{}>", content_id))?;
+    }
+    write_header(&mut part, "Content-Transfer-Encoding", "base64")?;
+    part.push_str("\r\n");
+    part.push_str(&base64_lines(&attachment.data));
+    Ok(part)
+}
+
+/// Joins `parts` into a multipart entity. Parts are base64-encoded, so the boundary, which
+/// contains `=_`, can't appear in them.
+fn multipart(subtype: &str, parts: &[String]) -> String {
+    let boundary = format!("=_rusoto_{}", subtype);
+    let mut entity = format!(
+        "Content-Type: multipart/{}; boundary=\"{}\"\r\n\r\n",
+        subtype, boundary
+    );
+    for part in parts {
+        entity.push_str(&format!("--{}\r\n{}", boundary, part));
+    }
+    entity.push_str(&format!("--{}--\r\n", boundary));
+    entity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(builder: &RawEmailBuilder) -> String {
+        String::from_utf8(builder.build().unwrap().to_vec()).unwrap()
+    }
+
+    #[test]
+    fn text_only_messages_are_single_part() {
+        let email = RawEmailBuilder::new()
+            .from("sender@example.com")
+            .to("a@example.com")
+            .to("b@example.com")
+            .subject("Hello")
+            .text("Hi!");
+        assert_eq!(
+            message(&email),
+            "From: sender@example.com\r\n\
+             To: a@example.com, b@example.com\r\n\
+             Subject: Hello\r\n\
+             MIME-Version: 1.0\r\n\
+             Content-Type: text/plain; charset=UTF-8\r\n\
+             Content-Transfer-Encoding: base64\r\n\
+             \r\n\
+             SGkh\r\n"
+        );
+    }
+
+    #[test]
+    fn nests_alternatives_inline_images_and_attachments() {
+        let email = RawEmailBuilder::new()
+            .from("sender@example.com")
+            .to("a@example.com")
+            .text("Hi")
+            .html("<img src=\"cid:logo\">")
+            .inline_image("logo", "logo.png", "image/png", &b"png"[..])
+            .attachment("report.pdf", "application/pdf", &b"pdf"[..]);
+        let message = message(&email);
+        let position = |needle: &str| message.find(needle).expect(needle);
+
+        assert!(position("multipart/mixed") < position("multipart/related"));
+        assert!(position("multipart/related") < position("multipart/alternative"));
+        assert!(position("text/plain") < position("text/html"));
+        assert!(position("--=_rusoto_alternative--") < position("Content-ID: <logo>"));
+        assert!(message.contains(
+            "Content-Type: image/png; name=\"logo.png\"\r\n\
+             Content-Disposition: inline; filename=\"logo.png\"\r\n"
+        ));
+        assert!(position("--=_rusoto_related--") < position("filename=\"report.pdf\""));
+        assert!(message.ends_with("--=_rusoto_mixed--\r\n"));
+    }
+
+    #[test]
+    fn encodes_headers_which_are_not_ascii() {
+        let email = RawEmailBuilder::new()
+            .from("José <jose@example.com>")
+            .to("a@example.com")
+            .subject("Réunion");
+        let message = message(&email);
+        assert!(message.starts_with("From: =?UTF-8?B?Sm9zw6k=?= <jose@example.com>\r\n"));
+        assert!(message.contains("Subject: =?UTF-8?B?UsOpdW5pb24=?=\r\n"));
+    }
+
+    #[test]
+    fn splits_long_encoded_words() {
+        let words = encode_words(&"é".repeat(40));
+        assert_eq!(words.matches("=?UTF-8?B?").count(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_messages() {
+        let email = RawEmailBuilder::new().to("a@example.com");
+        assert_eq!(email.build(), Err(RawEmailError::MissingSender));
+
+        let email = RawEmailBuilder::new().from("sender@example.com");
+        assert_eq!(email.build(), Err(RawEmailError::MissingRecipients));
+
+        let email = RawEmailBuilder::new()
+            .from("sender@example.com")
+            .to("a@example.com")
+            .subject("Hi\r\nBcc: everyone@example.com");
+        assert_eq!(
+            email.build(),
+            Err(RawEmailError::InvalidHeader("Subject".to_owned()))
+        );
+
+        let email = RawEmailBuilder::new()
+            .from("sender@example.com")
+            .to("a@example.com")
+            .attachment("big.bin", "application/octet-stream", vec![0; 1000])
+            .max_size(1000);
+        match email.build() {
+            Err(RawEmailError::TooLarge { size, limit }) => {
+                assert!(size > 1000);
+                assert_eq!(limit, 1000);
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn bcc_recipients_are_destinations_only() {
+        let email = RawEmailBuilder::new()
+            .from("sender@example.com")
+            .to("a@example.com")
+            .bcc("hidden@example.com");
+        assert!(!message(&email).contains("hidden@example.com"));
+        let request = email.build_request().unwrap();
+        assert_eq!(
+            request.destinations,
+            Some(vec![
+                "a@example.com".to_owned(),
+                "hidden@example.com".to_owned()
+            ])
+        );
+        assert_eq!(
+            base64::decode(&request.raw_message.data).unwrap(),
+            email.build().unwrap().to_vec()
+        );
+    }
+}
@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -42,10 +43,10 @@ pub struct AddHeaderAction {
     pub header_value: String,
 }
 
-struct AddHeaderActionDeserializer;
+pub(crate) struct AddHeaderActionDeserializer;
 impl AddHeaderActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AddHeaderAction, XmlParseError> {
@@ -78,10 +79,13 @@ impl AddHeaderActionSerializer {
     }
 }
 
-struct AddressDeserializer;
+pub(crate) struct AddressDeserializer;
 impl AddressDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -89,10 +93,10 @@ impl AddressDeserializer {
         Ok(obj)
     }
 }
-struct AddressListDeserializer;
+pub(crate) struct AddressListDeserializer;
 impl AddressListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -118,10 +122,13 @@ impl AddressListSerializer {
     }
 }
 
-struct AmazonResourceNameDeserializer;
+pub(crate) struct AmazonResourceNameDeserializer;
 impl AmazonResourceNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -129,17 +136,79 @@ impl AmazonResourceNameDeserializer {
         Ok(obj)
     }
 }
-struct BehaviorOnMXFailureDeserializer;
+pub(crate) struct BehaviorOnMXFailureDeserializer;
 impl BehaviorOnMXFailureDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<BehaviorOnMXFailure, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The action that Amazon SES takes if it cannot successfully read the required MX record when you send an email. A value of <code>UseDefaultValue</code> indicates that if Amazon SES cannot read the required MX record, it uses amazonses.com (or a subdomain of that) as the MAIL FROM domain. A value of <code>RejectMessage</code> indicates that if Amazon SES cannot read the required MX record, Amazon SES returns a <code>MailFromDomainNotVerified</code> error and does not send the email.</p> <p>The custom MAIL FROM setup states that result in this behavior are <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BehaviorOnMXFailure {
+    /// <p><code>UseDefaultValue</code></p>
+    UseDefaultValue,
+    /// <p><code>RejectMessage</code></p>
+    RejectMessage,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BehaviorOnMXFailure {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BehaviorOnMXFailure::UseDefaultValue => "UseDefaultValue",
+            BehaviorOnMXFailure::RejectMessage => "RejectMessage",
+            BehaviorOnMXFailure::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BehaviorOnMXFailure {
+    fn from(value: &'a str) -> BehaviorOnMXFailure {
+        match value {
+            "UseDefaultValue" => BehaviorOnMXFailure::UseDefaultValue,
+            "RejectMessage" => BehaviorOnMXFailure::RejectMessage,
+            _ => BehaviorOnMXFailure::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BehaviorOnMXFailure {
+    fn from(value: String) -> BehaviorOnMXFailure {
+        BehaviorOnMXFailure::from(value.as_str())
+    }
+}
+
+impl From<BehaviorOnMXFailure> for String {
+    fn from(value: BehaviorOnMXFailure) -> String {
+        match value {
+            BehaviorOnMXFailure::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BehaviorOnMXFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BehaviorOnMXFailure {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Represents the body of the message. You can specify text, HTML, or both. If you use both, then the message should display correctly in the widest variety of email clients.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Body {
@@ -182,10 +251,10 @@ pub struct BounceAction {
     pub topic_arn: Option<String>,
 }
 
-struct BounceActionDeserializer;
+pub(crate) struct BounceActionDeserializer;
 impl BounceActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<BounceAction, XmlParseError> {
@@ -243,10 +312,13 @@ impl BounceActionSerializer {
     }
 }
 
-struct BounceMessageDeserializer;
+pub(crate) struct BounceMessageDeserializer;
 impl BounceMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -254,10 +326,13 @@ impl BounceMessageDeserializer {
         Ok(obj)
     }
 }
-struct BounceSmtpReplyCodeDeserializer;
+pub(crate) struct BounceSmtpReplyCodeDeserializer;
 impl BounceSmtpReplyCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -265,10 +340,13 @@ impl BounceSmtpReplyCodeDeserializer {
         Ok(obj)
     }
 }
-struct BounceStatusCodeDeserializer;
+pub(crate) struct BounceStatusCodeDeserializer;
 impl BounceStatusCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -276,11 +354,86 @@ impl BounceStatusCodeDeserializer {
         Ok(obj)
     }
 }
+/// <p>The reason for the bounce. You must provide either this parameter or <code>RecipientDsnFields</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BounceType {
+    /// <p><code>DoesNotExist</code></p>
+    DoesNotExist,
+    /// <p><code>MessageTooLarge</code></p>
+    MessageTooLarge,
+    /// <p><code>ExceededQuota</code></p>
+    ExceededQuota,
+    /// <p><code>ContentRejected</code></p>
+    ContentRejected,
+    /// <p><code>Undefined</code></p>
+    Undefined,
+    /// <p><code>TemporaryFailure</code></p>
+    TemporaryFailure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BounceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BounceType::DoesNotExist => "DoesNotExist",
+            BounceType::MessageTooLarge => "MessageTooLarge",
+            BounceType::ExceededQuota => "ExceededQuota",
+            BounceType::ContentRejected => "ContentRejected",
+            BounceType::Undefined => "Undefined",
+            BounceType::TemporaryFailure => "TemporaryFailure",
+            BounceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BounceType {
+    fn from(value: &'a str) -> BounceType {
+        match value {
+            "DoesNotExist" => BounceType::DoesNotExist,
+            "MessageTooLarge" => BounceType::MessageTooLarge,
+            "ExceededQuota" => BounceType::ExceededQuota,
+            "ContentRejected" => BounceType::ContentRejected,
+            "Undefined" => BounceType::Undefined,
+            "TemporaryFailure" => BounceType::TemporaryFailure,
+            _ => BounceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BounceType {
+    fn from(value: String) -> BounceType {
+        BounceType::from(value.as_str())
+    }
+}
+
+impl From<BounceType> for String {
+    fn from(value: BounceType) -> String {
+        match value {
+            BounceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BounceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BounceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Recipient-related information to include in the Delivery Status Notification (DSN) when an email that Amazon SES receives on your behalf bounces.</p> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct BouncedRecipientInfo {
     /// <p>The reason for the bounce. You must provide either this parameter or <code>RecipientDsnFields</code>.</p>
-    pub bounce_type: Option<String>,
+    pub bounce_type: Option<BounceType>,
     /// <p>The email address of the recipient of the bounced email.</p>
     pub recipient: String,
     /// <p>This parameter is used only for sending authorization. It is the ARN of the identity that is associated with the sending authorization policy that permits you to receive email for the recipient of the bounced email. For more information about sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p>
@@ -385,13 +538,13 @@ pub struct BulkEmailDestinationStatus {
     /// <p>The unique message identifier returned from the <code>SendBulkTemplatedEmail</code> operation.</p>
     pub message_id: Option<String>,
     /// <p><p>The status of a message sent using the <code>SendBulkTemplatedEmail</code> operation.</p> <p>Possible values for this parameter include:</p> <ul> <li> <p> <code>Success</code>: Amazon SES accepted the message, and will attempt to deliver it to the recipients.</p> </li> <li> <p> <code>MessageRejected</code>: The message was rejected because it contained a virus.</p> </li> <li> <p> <code>MailFromDomainNotVerified</code>: The sender&#39;s email address or domain was not verified.</p> </li> <li> <p> <code>ConfigurationSetDoesNotExist</code>: The configuration set you specified does not exist.</p> </li> <li> <p> <code>TemplateDoesNotExist</code>: The template you specified does not exist.</p> </li> <li> <p> <code>AccountSuspended</code>: Your account has been shut down because of issues related to your email sending practices.</p> </li> <li> <p> <code>AccountThrottled</code>: The number of emails you can send has been reduced because your account has exceeded its allocated sending limit.</p> </li> <li> <p> <code>AccountDailyQuotaExceeded</code>: You have reached or exceeded the maximum number of emails you can send from your account in a 24-hour period.</p> </li> <li> <p> <code>InvalidSendingPoolName</code>: The configuration set you specified refers to an IP pool that does not exist.</p> </li> <li> <p> <code>AccountSendingPaused</code>: Email sending for the Amazon SES account was disabled using the <a>UpdateAccountSendingEnabled</a> operation.</p> </li> <li> <p> <code>ConfigurationSetSendingPaused</code>: Email sending for this configuration set was disabled using the <a>UpdateConfigurationSetSendingEnabled</a> operation.</p> </li> <li> <p> <code>InvalidParameterValue</code>: One or more of the parameters you specified when calling this operation was invalid. See the error message for additional information.</p> </li> <li> <p> <code>TransientFailure</code>: Amazon SES was unable to process your request because of a temporary issue.</p> </li> <li> <p> <code>Failed</code>: Amazon SES was unable to process your request. See the error message for additional information.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<BulkEmailStatus>,
 }
 
-struct BulkEmailDestinationStatusDeserializer;
+pub(crate) struct BulkEmailDestinationStatusDeserializer;
 impl BulkEmailDestinationStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<BulkEmailDestinationStatus, XmlParseError> {
@@ -418,10 +571,10 @@ impl BulkEmailDestinationStatusDeserializer {
         )
     }
 }
-struct BulkEmailDestinationStatusListDeserializer;
+pub(crate) struct BulkEmailDestinationStatusListDeserializer;
 impl BulkEmailDestinationStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<BulkEmailDestinationStatus>, XmlParseError> {
@@ -437,21 +590,134 @@ impl BulkEmailDestinationStatusListDeserializer {
         })
     }
 }
-struct BulkEmailStatusDeserializer;
+pub(crate) struct BulkEmailStatusDeserializer;
 impl BulkEmailStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<BulkEmailStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct CidrDeserializer;
+/// <p><p>The status of a message sent using the <code>SendBulkTemplatedEmail</code> operation.</p> <p>Possible values for this parameter include:</p> <ul> <li> <p> <code>Success</code>: Amazon SES accepted the message, and will attempt to deliver it to the recipients.</p> </li> <li> <p> <code>MessageRejected</code>: The message was rejected because it contained a virus.</p> </li> <li> <p> <code>MailFromDomainNotVerified</code>: The sender&#39;s email address or domain was not verified.</p> </li> <li> <p> <code>ConfigurationSetDoesNotExist</code>: The configuration set you specified does not exist.</p> </li> <li> <p> <code>TemplateDoesNotExist</code>: The template you specified does not exist.</p> </li> <li> <p> <code>AccountSuspended</code>: Your account has been shut down because of issues related to your email sending practices.</p> </li> <li> <p> <code>AccountThrottled</code>: The number of emails you can send has been reduced because your account has exceeded its allocated sending limit.</p> </li> <li> <p> <code>AccountDailyQuotaExceeded</code>: You have reached or exceeded the maximum number of emails you can send from your account in a 24-hour period.</p> </li> <li> <p> <code>InvalidSendingPoolName</code>: The configuration set you specified refers to an IP pool that does not exist.</p> </li> <li> <p> <code>AccountSendingPaused</code>: Email sending for the Amazon SES account was disabled using the <a>UpdateAccountSendingEnabled</a> operation.</p> </li> <li> <p> <code>ConfigurationSetSendingPaused</code>: Email sending for this configuration set was disabled using the <a>UpdateConfigurationSetSendingEnabled</a> operation.</p> </li> <li> <p> <code>InvalidParameterValue</code>: One or more of the parameters you specified when calling this operation was invalid. See the error message for additional information.</p> </li> <li> <p> <code>TransientFailure</code>: Amazon SES was unable to process your request because of a temporary issue.</p> </li> <li> <p> <code>Failed</code>: Amazon SES was unable to process your request. See the error message for additional information.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BulkEmailStatus {
+    /// <p><code>Success</code> - Amazon SES accepted the message, and will attempt to deliver it to the recipients.</p>
+    Success,
+    /// <p><code>MessageRejected</code> - The message was rejected because it contained a virus.</p>
+    MessageRejected,
+    /// <p><code>MailFromDomainNotVerified</code> - The sender&#39;s email address or domain was not verified.</p>
+    MailFromDomainNotVerified,
+    /// <p><code>ConfigurationSetDoesNotExist</code> - The configuration set you specified does not exist.</p>
+    ConfigurationSetDoesNotExist,
+    /// <p><code>TemplateDoesNotExist</code> - The template you specified does not exist.</p>
+    TemplateDoesNotExist,
+    /// <p><code>AccountSuspended</code> - Your account has been shut down because of issues related to your email sending practices.</p>
+    AccountSuspended,
+    /// <p><code>AccountThrottled</code> - The number of emails you can send has been reduced because your account has exceeded its allocated sending limit.</p>
+    AccountThrottled,
+    /// <p><code>AccountDailyQuotaExceeded</code> - You have reached or exceeded the maximum number of emails you can send from your account in a 24-hour period.</p>
+    AccountDailyQuotaExceeded,
+    /// <p><code>InvalidSendingPoolName</code> - The configuration set you specified refers to an IP pool that does not exist.</p>
+    InvalidSendingPoolName,
+    /// <p><code>AccountSendingPaused</code> - Email sending for the Amazon SES account was disabled using the <a>UpdateAccountSendingEnabled</a> operation.</p>
+    AccountSendingPaused,
+    /// <p><code>ConfigurationSetSendingPaused</code> - Email sending for this configuration set was disabled using the <a>UpdateConfigurationSetSendingEnabled</a> operation.</p>
+    ConfigurationSetSendingPaused,
+    /// <p><code>InvalidParameterValue</code> - One or more of the parameters you specified when calling this operation was invalid. See the error message for additional information.</p>
+    InvalidParameterValue,
+    /// <p><code>TransientFailure</code> - Amazon SES was unable to process your request because of a temporary issue.</p>
+    TransientFailure,
+    /// <p><code>Failed</code> - Amazon SES was unable to process your request. See the error message for additional information.</p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BulkEmailStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BulkEmailStatus::Success => "Success",
+            BulkEmailStatus::MessageRejected => "MessageRejected",
+            BulkEmailStatus::MailFromDomainNotVerified => "MailFromDomainNotVerified",
+            BulkEmailStatus::ConfigurationSetDoesNotExist => "ConfigurationSetDoesNotExist",
+            BulkEmailStatus::TemplateDoesNotExist => "TemplateDoesNotExist",
+            BulkEmailStatus::AccountSuspended => "AccountSuspended",
+            BulkEmailStatus::AccountThrottled => "AccountThrottled",
+            BulkEmailStatus::AccountDailyQuotaExceeded => "AccountDailyQuotaExceeded",
+            BulkEmailStatus::InvalidSendingPoolName => "InvalidSendingPoolName",
+            BulkEmailStatus::AccountSendingPaused => "AccountSendingPaused",
+            BulkEmailStatus::ConfigurationSetSendingPaused => "ConfigurationSetSendingPaused",
+            BulkEmailStatus::InvalidParameterValue => "InvalidParameterValue",
+            BulkEmailStatus::TransientFailure => "TransientFailure",
+            BulkEmailStatus::Failed => "Failed",
+            BulkEmailStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BulkEmailStatus {
+    fn from(value: &'a str) -> BulkEmailStatus {
+        match value {
+            "Success" => BulkEmailStatus::Success,
+            "MessageRejected" => BulkEmailStatus::MessageRejected,
+            "MailFromDomainNotVerified" => BulkEmailStatus::MailFromDomainNotVerified,
+            "ConfigurationSetDoesNotExist" => BulkEmailStatus::ConfigurationSetDoesNotExist,
+            "TemplateDoesNotExist" => BulkEmailStatus::TemplateDoesNotExist,
+            "AccountSuspended" => BulkEmailStatus::AccountSuspended,
+            "AccountThrottled" => BulkEmailStatus::AccountThrottled,
+            "AccountDailyQuotaExceeded" => BulkEmailStatus::AccountDailyQuotaExceeded,
+            "InvalidSendingPoolName" => BulkEmailStatus::InvalidSendingPoolName,
+            "AccountSendingPaused" => BulkEmailStatus::AccountSendingPaused,
+            "ConfigurationSetSendingPaused" => BulkEmailStatus::ConfigurationSetSendingPaused,
+            "InvalidParameterValue" => BulkEmailStatus::InvalidParameterValue,
+            "TransientFailure" => BulkEmailStatus::TransientFailure,
+            "Failed" => BulkEmailStatus::Failed,
+            _ => BulkEmailStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BulkEmailStatus {
+    fn from(value: String) -> BulkEmailStatus {
+        BulkEmailStatus::from(value.as_str())
+    }
+}
+
+impl From<BulkEmailStatus> for String {
+    fn from(value: BulkEmailStatus) -> String {
+        match value {
+            BulkEmailStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BulkEmailStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BulkEmailStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct CidrDeserializer;
 impl CidrDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -468,6 +734,48 @@ pub struct CloneReceiptRuleSetRequest {
     pub rule_set_name: String,
 }
 
+impl CloneReceiptRuleSetRequest {
+    /// Returns a builder for `CloneReceiptRuleSetRequest`, checking its required members when built.
+    pub fn builder() -> CloneReceiptRuleSetRequestBuilder {
+        CloneReceiptRuleSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CloneReceiptRuleSetRequest`](struct.CloneReceiptRuleSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CloneReceiptRuleSetRequestBuilder {
+    original_rule_set_name: Option<String>,
+    rule_set_name: Option<String>,
+}
+
+impl CloneReceiptRuleSetRequestBuilder {
+    /// Sets the [`original_rule_set_name`](struct.CloneReceiptRuleSetRequest.html#structfield.original_rule_set_name) member.
+    pub fn original_rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.original_rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_set_name`](struct.CloneReceiptRuleSetRequest.html#structfield.rule_set_name) member.
+    pub fn rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CloneReceiptRuleSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CloneReceiptRuleSetRequest, ::rusoto_core::BuildError> {
+        Ok(CloneReceiptRuleSetRequest {
+            original_rule_set_name: self
+                .original_rule_set_name
+                .ok_or(::rusoto_core::BuildError::missing("original_rule_set_name"))?,
+            rule_set_name: self
+                .rule_set_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `CloneReceiptRuleSetRequest` contents to a `SignedRequest`.
 struct CloneReceiptRuleSetRequestSerializer;
 impl CloneReceiptRuleSetRequestSerializer {
@@ -489,10 +797,10 @@ impl CloneReceiptRuleSetRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CloneReceiptRuleSetResponse {}
 
-struct CloneReceiptRuleSetResponseDeserializer;
+pub(crate) struct CloneReceiptRuleSetResponseDeserializer;
 impl CloneReceiptRuleSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CloneReceiptRuleSetResponse, XmlParseError> {
@@ -512,10 +820,10 @@ pub struct CloudWatchDestination {
     pub dimension_configurations: Vec<CloudWatchDimensionConfiguration>,
 }
 
-struct CloudWatchDestinationDeserializer;
+pub(crate) struct CloudWatchDestinationDeserializer;
 impl CloudWatchDestinationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CloudWatchDestination, XmlParseError> {
@@ -554,26 +862,31 @@ impl CloudWatchDestinationSerializer {
 }
 
 /// <p>Contains the dimension configuration to use when you publish email sending events to Amazon CloudWatch.</p> <p>For information about publishing email sending events to Amazon CloudWatch, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CloudWatchDimensionConfiguration {
     /// <p><p>The default value of the dimension that is published to Amazon CloudWatch if you do not provide the value of the dimension when you send an email. The default value must:</p> <ul> <li> <p>This value can only contain ASCII letters (a-z, A-Z), numbers (0-9), underscores (_), or dashes (-).</p> </li> <li> <p>Contain less than 256 characters.</p> </li> </ul></p>
     pub default_dimension_value: String,
     /// <p><p>The name of an Amazon CloudWatch dimension associated with an email sending metric. The name must:</p> <ul> <li> <p>This value can only contain ASCII letters (a-z, A-Z), numbers (0-9), underscores (_), or dashes (-).</p> </li> <li> <p>Contain less than 256 characters.</p> </li> </ul></p>
     pub dimension_name: String,
     /// <p>The place where Amazon SES finds the value of a dimension to publish to Amazon CloudWatch. If you want Amazon SES to use the message tags that you specify using an <code>X-SES-MESSAGE-TAGS</code> header or a parameter to the <code>SendEmail</code>/<code>SendRawEmail</code> API, choose <code>messageTag</code>. If you want Amazon SES to use your own email headers, choose <code>emailHeader</code>.</p>
-    pub dimension_value_source: String,
+    pub dimension_value_source: DimensionValueSource,
 }
 
-struct CloudWatchDimensionConfigurationDeserializer;
+pub(crate) struct CloudWatchDimensionConfigurationDeserializer;
 impl CloudWatchDimensionConfigurationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CloudWatchDimensionConfiguration, XmlParseError> {
-        deserialize_elements::<_, CloudWatchDimensionConfiguration, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            CloudWatchDimensionConfiguration {
+                default_dimension_value: Default::default(),
+                dimension_name: Default::default(),
+                dimension_value_source: DimensionValueSource::Unknown(String::new()),
+            },
             |name, stack, obj| {
                 match name {
                     "DefaultDimensionValue" => {
@@ -625,10 +938,10 @@ impl CloudWatchDimensionConfigurationSerializer {
     }
 }
 
-struct CloudWatchDimensionConfigurationsDeserializer;
+pub(crate) struct CloudWatchDimensionConfigurationsDeserializer;
 impl CloudWatchDimensionConfigurationsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CloudWatchDimensionConfiguration>, XmlParseError> {
@@ -663,10 +976,10 @@ pub struct ConfigurationSet {
     pub name: String,
 }
 
-struct ConfigurationSetDeserializer;
+pub(crate) struct ConfigurationSetDeserializer;
 impl ConfigurationSetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ConfigurationSet, XmlParseError> {
@@ -695,10 +1008,77 @@ impl ConfigurationSetSerializer {
     }
 }
 
+/// <p>A list of configuration set attributes to return.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConfigurationSetAttribute {
+    /// <p><code>eventDestinations</code></p>
+    EventDestinations,
+    /// <p><code>trackingOptions</code></p>
+    TrackingOptions,
+    /// <p><code>deliveryOptions</code></p>
+    DeliveryOptions,
+    /// <p><code>reputationOptions</code></p>
+    ReputationOptions,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConfigurationSetAttribute {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationSetAttribute::EventDestinations => "eventDestinations",
+            ConfigurationSetAttribute::TrackingOptions => "trackingOptions",
+            ConfigurationSetAttribute::DeliveryOptions => "deliveryOptions",
+            ConfigurationSetAttribute::ReputationOptions => "reputationOptions",
+            ConfigurationSetAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationSetAttribute {
+    fn from(value: &'a str) -> ConfigurationSetAttribute {
+        match value {
+            "eventDestinations" => ConfigurationSetAttribute::EventDestinations,
+            "trackingOptions" => ConfigurationSetAttribute::TrackingOptions,
+            "deliveryOptions" => ConfigurationSetAttribute::DeliveryOptions,
+            "reputationOptions" => ConfigurationSetAttribute::ReputationOptions,
+            _ => ConfigurationSetAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationSetAttribute {
+    fn from(value: String) -> ConfigurationSetAttribute {
+        ConfigurationSetAttribute::from(value.as_str())
+    }
+}
+
+impl From<ConfigurationSetAttribute> for String {
+    fn from(value: ConfigurationSetAttribute) -> String {
+        match value {
+            ConfigurationSetAttribute::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigurationSetAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigurationSetAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// Serialize `ConfigurationSetAttributeList` contents to a `SignedRequest`.
 struct ConfigurationSetAttributeListSerializer;
 impl ConfigurationSetAttributeListSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<ConfigurationSetAttribute>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -706,10 +1086,13 @@ impl ConfigurationSetAttributeListSerializer {
     }
 }
 
-struct ConfigurationSetNameDeserializer;
+pub(crate) struct ConfigurationSetNameDeserializer;
 impl ConfigurationSetNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -717,10 +1100,10 @@ impl ConfigurationSetNameDeserializer {
         Ok(obj)
     }
 }
-struct ConfigurationSetsDeserializer;
+pub(crate) struct ConfigurationSetsDeserializer;
 impl ConfigurationSetsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ConfigurationSet>, XmlParseError> {
@@ -759,10 +1142,13 @@ impl ContentSerializer {
     }
 }
 
-struct CounterDeserializer;
+pub(crate) struct CounterDeserializer;
 impl CounterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -779,6 +1165,51 @@ pub struct CreateConfigurationSetEventDestinationRequest {
     pub event_destination: EventDestination,
 }
 
+impl CreateConfigurationSetEventDestinationRequest {
+    /// Returns a builder for `CreateConfigurationSetEventDestinationRequest`, checking its required members when built.
+    pub fn builder() -> CreateConfigurationSetEventDestinationRequestBuilder {
+        CreateConfigurationSetEventDestinationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConfigurationSetEventDestinationRequest`](struct.CreateConfigurationSetEventDestinationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateConfigurationSetEventDestinationRequestBuilder {
+    configuration_set_name: Option<String>,
+    event_destination: Option<EventDestination>,
+}
+
+impl CreateConfigurationSetEventDestinationRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.CreateConfigurationSetEventDestinationRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_destination`](struct.CreateConfigurationSetEventDestinationRequest.html#structfield.event_destination) member.
+    pub fn event_destination<T: Into<EventDestination>>(mut self, value: T) -> Self {
+        self.event_destination = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConfigurationSetEventDestinationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        CreateConfigurationSetEventDestinationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(CreateConfigurationSetEventDestinationRequest {
+            configuration_set_name: self
+                .configuration_set_name
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set_name"))?,
+            event_destination: self
+                .event_destination
+                .ok_or(::rusoto_core::BuildError::missing("event_destination"))?,
+        })
+    }
+}
+
 /// Serialize `CreateConfigurationSetEventDestinationRequest` contents to a `SignedRequest`.
 struct CreateConfigurationSetEventDestinationRequestSerializer;
 impl CreateConfigurationSetEventDestinationRequestSerializer {
@@ -808,10 +1239,10 @@ impl CreateConfigurationSetEventDestinationRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateConfigurationSetEventDestinationResponse {}
 
-struct CreateConfigurationSetEventDestinationResponseDeserializer;
+pub(crate) struct CreateConfigurationSetEventDestinationResponseDeserializer;
 impl CreateConfigurationSetEventDestinationResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateConfigurationSetEventDestinationResponse, XmlParseError> {
@@ -831,6 +1262,38 @@ pub struct CreateConfigurationSetRequest {
     pub configuration_set: ConfigurationSet,
 }
 
+impl CreateConfigurationSetRequest {
+    /// Returns a builder for `CreateConfigurationSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateConfigurationSetRequestBuilder {
+        CreateConfigurationSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConfigurationSetRequest`](struct.CreateConfigurationSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateConfigurationSetRequestBuilder {
+    configuration_set: Option<ConfigurationSet>,
+}
+
+impl CreateConfigurationSetRequestBuilder {
+    /// Sets the [`configuration_set`](struct.CreateConfigurationSetRequest.html#structfield.configuration_set) member.
+    pub fn configuration_set<T: Into<ConfigurationSet>>(mut self, value: T) -> Self {
+        self.configuration_set = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConfigurationSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateConfigurationSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateConfigurationSetRequest {
+            configuration_set: self
+                .configuration_set
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set"))?,
+        })
+    }
+}
+
 /// Serialize `CreateConfigurationSetRequest` contents to a `SignedRequest`.
 struct CreateConfigurationSetRequestSerializer;
 impl CreateConfigurationSetRequestSerializer {
@@ -852,10 +1315,10 @@ impl CreateConfigurationSetRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateConfigurationSetResponse {}
 
-struct CreateConfigurationSetResponseDeserializer;
+pub(crate) struct CreateConfigurationSetResponseDeserializer;
 impl CreateConfigurationSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateConfigurationSetResponse, XmlParseError> {
@@ -876,6 +1339,51 @@ pub struct CreateConfigurationSetTrackingOptionsRequest {
     pub tracking_options: TrackingOptions,
 }
 
+impl CreateConfigurationSetTrackingOptionsRequest {
+    /// Returns a builder for `CreateConfigurationSetTrackingOptionsRequest`, checking its required members when built.
+    pub fn builder() -> CreateConfigurationSetTrackingOptionsRequestBuilder {
+        CreateConfigurationSetTrackingOptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConfigurationSetTrackingOptionsRequest`](struct.CreateConfigurationSetTrackingOptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateConfigurationSetTrackingOptionsRequestBuilder {
+    configuration_set_name: Option<String>,
+    tracking_options: Option<TrackingOptions>,
+}
+
+impl CreateConfigurationSetTrackingOptionsRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.CreateConfigurationSetTrackingOptionsRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tracking_options`](struct.CreateConfigurationSetTrackingOptionsRequest.html#structfield.tracking_options) member.
+    pub fn tracking_options<T: Into<TrackingOptions>>(mut self, value: T) -> Self {
+        self.tracking_options = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConfigurationSetTrackingOptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        CreateConfigurationSetTrackingOptionsRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(CreateConfigurationSetTrackingOptionsRequest {
+            configuration_set_name: self
+                .configuration_set_name
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set_name"))?,
+            tracking_options: self
+                .tracking_options
+                .ok_or(::rusoto_core::BuildError::missing("tracking_options"))?,
+        })
+    }
+}
+
 /// Serialize `CreateConfigurationSetTrackingOptionsRequest` contents to a `SignedRequest`.
 struct CreateConfigurationSetTrackingOptionsRequestSerializer;
 impl CreateConfigurationSetTrackingOptionsRequestSerializer {
@@ -905,10 +1413,10 @@ impl CreateConfigurationSetTrackingOptionsRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateConfigurationSetTrackingOptionsResponse {}
 
-struct CreateConfigurationSetTrackingOptionsResponseDeserializer;
+pub(crate) struct CreateConfigurationSetTrackingOptionsResponseDeserializer;
 impl CreateConfigurationSetTrackingOptionsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateConfigurationSetTrackingOptionsResponse, XmlParseError> {
@@ -938,6 +1446,91 @@ pub struct CreateCustomVerificationEmailTemplateRequest {
     pub template_subject: String,
 }
 
+impl CreateCustomVerificationEmailTemplateRequest {
+    /// Returns a builder for `CreateCustomVerificationEmailTemplateRequest`, checking its required members when built.
+    pub fn builder() -> CreateCustomVerificationEmailTemplateRequestBuilder {
+        CreateCustomVerificationEmailTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCustomVerificationEmailTemplateRequest`](struct.CreateCustomVerificationEmailTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCustomVerificationEmailTemplateRequestBuilder {
+    failure_redirection_url: Option<String>,
+    from_email_address: Option<String>,
+    success_redirection_url: Option<String>,
+    template_content: Option<String>,
+    template_name: Option<String>,
+    template_subject: Option<String>,
+}
+
+impl CreateCustomVerificationEmailTemplateRequestBuilder {
+    /// Sets the [`failure_redirection_url`](struct.CreateCustomVerificationEmailTemplateRequest.html#structfield.failure_redirection_url) member.
+    pub fn failure_redirection_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.failure_redirection_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`from_email_address`](struct.CreateCustomVerificationEmailTemplateRequest.html#structfield.from_email_address) member.
+    pub fn from_email_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.from_email_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`success_redirection_url`](struct.CreateCustomVerificationEmailTemplateRequest.html#structfield.success_redirection_url) member.
+    pub fn success_redirection_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.success_redirection_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_content`](struct.CreateCustomVerificationEmailTemplateRequest.html#structfield.template_content) member.
+    pub fn template_content<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.CreateCustomVerificationEmailTemplateRequest.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_subject`](struct.CreateCustomVerificationEmailTemplateRequest.html#structfield.template_subject) member.
+    pub fn template_subject<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_subject = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCustomVerificationEmailTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        CreateCustomVerificationEmailTemplateRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(CreateCustomVerificationEmailTemplateRequest {
+            failure_redirection_url: self.failure_redirection_url.ok_or(
+                ::rusoto_core::BuildError::missing("failure_redirection_url"),
+            )?,
+            from_email_address: self
+                .from_email_address
+                .ok_or(::rusoto_core::BuildError::missing("from_email_address"))?,
+            success_redirection_url: self.success_redirection_url.ok_or(
+                ::rusoto_core::BuildError::missing("success_redirection_url"),
+            )?,
+            template_content: self
+                .template_content
+                .ok_or(::rusoto_core::BuildError::missing("template_content"))?,
+            template_name: self
+                .template_name
+                .ok_or(::rusoto_core::BuildError::missing("template_name"))?,
+            template_subject: self
+                .template_subject
+                .ok_or(::rusoto_core::BuildError::missing("template_subject"))?,
+        })
+    }
+}
+
 /// Serialize `CreateCustomVerificationEmailTemplateRequest` contents to a `SignedRequest`.
 struct CreateCustomVerificationEmailTemplateRequestSerializer;
 impl CreateCustomVerificationEmailTemplateRequestSerializer {
@@ -976,12 +1569,44 @@ impl CreateCustomVerificationEmailTemplateRequestSerializer {
 }
 
 /// <p>Represents a request to create a new IP address filter. You use IP address filters when you receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateReceiptFilterRequest {
     /// <p>A data structure that describes the IP address filter to create, which consists of a name, an IP address range, and whether to allow or block mail from it.</p>
     pub filter: ReceiptFilter,
 }
 
+impl CreateReceiptFilterRequest {
+    /// Returns a builder for `CreateReceiptFilterRequest`, checking its required members when built.
+    pub fn builder() -> CreateReceiptFilterRequestBuilder {
+        CreateReceiptFilterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateReceiptFilterRequest`](struct.CreateReceiptFilterRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateReceiptFilterRequestBuilder {
+    filter: Option<ReceiptFilter>,
+}
+
+impl CreateReceiptFilterRequestBuilder {
+    /// Sets the [`filter`](struct.CreateReceiptFilterRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<ReceiptFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateReceiptFilterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateReceiptFilterRequest, ::rusoto_core::BuildError> {
+        Ok(CreateReceiptFilterRequest {
+            filter: self
+                .filter
+                .ok_or(::rusoto_core::BuildError::missing("filter"))?,
+        })
+    }
+}
+
 /// Serialize `CreateReceiptFilterRequest` contents to a `SignedRequest`.
 struct CreateReceiptFilterRequestSerializer;
 impl CreateReceiptFilterRequestSerializer {
@@ -999,10 +1624,10 @@ impl CreateReceiptFilterRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateReceiptFilterResponse {}
 
-struct CreateReceiptFilterResponseDeserializer;
+pub(crate) struct CreateReceiptFilterResponseDeserializer;
 impl CreateReceiptFilterResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateReceiptFilterResponse, XmlParseError> {
@@ -1026,6 +1651,56 @@ pub struct CreateReceiptRuleRequest {
     pub rule_set_name: String,
 }
 
+impl CreateReceiptRuleRequest {
+    /// Returns a builder for `CreateReceiptRuleRequest`, checking its required members when built.
+    pub fn builder() -> CreateReceiptRuleRequestBuilder {
+        CreateReceiptRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateReceiptRuleRequest`](struct.CreateReceiptRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateReceiptRuleRequestBuilder {
+    after: Option<String>,
+    rule: Option<ReceiptRule>,
+    rule_set_name: Option<String>,
+}
+
+impl CreateReceiptRuleRequestBuilder {
+    /// Sets the [`after`](struct.CreateReceiptRuleRequest.html#structfield.after) member.
+    pub fn after<T: Into<String>>(mut self, value: T) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule`](struct.CreateReceiptRuleRequest.html#structfield.rule) member.
+    pub fn rule<T: Into<ReceiptRule>>(mut self, value: T) -> Self {
+        self.rule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_set_name`](struct.CreateReceiptRuleRequest.html#structfield.rule_set_name) member.
+    pub fn rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateReceiptRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateReceiptRuleRequest, ::rusoto_core::BuildError> {
+        Ok(CreateReceiptRuleRequest {
+            after: self.after,
+            rule: self
+                .rule
+                .ok_or(::rusoto_core::BuildError::missing("rule"))?,
+            rule_set_name: self
+                .rule_set_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateReceiptRuleRequest` contents to a `SignedRequest`.
 struct CreateReceiptRuleRequestSerializer;
 impl CreateReceiptRuleRequestSerializer {
@@ -1047,10 +1722,10 @@ impl CreateReceiptRuleRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateReceiptRuleResponse {}
 
-struct CreateReceiptRuleResponseDeserializer;
+pub(crate) struct CreateReceiptRuleResponseDeserializer;
 impl CreateReceiptRuleResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateReceiptRuleResponse, XmlParseError> {
@@ -1070,6 +1745,38 @@ pub struct CreateReceiptRuleSetRequest {
     pub rule_set_name: String,
 }
 
+impl CreateReceiptRuleSetRequest {
+    /// Returns a builder for `CreateReceiptRuleSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateReceiptRuleSetRequestBuilder {
+        CreateReceiptRuleSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateReceiptRuleSetRequest`](struct.CreateReceiptRuleSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateReceiptRuleSetRequestBuilder {
+    rule_set_name: Option<String>,
+}
+
+impl CreateReceiptRuleSetRequestBuilder {
+    /// Sets the [`rule_set_name`](struct.CreateReceiptRuleSetRequest.html#structfield.rule_set_name) member.
+    pub fn rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateReceiptRuleSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateReceiptRuleSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateReceiptRuleSetRequest {
+            rule_set_name: self
+                .rule_set_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateReceiptRuleSetRequest` contents to a `SignedRequest`.
 struct CreateReceiptRuleSetRequestSerializer;
 impl CreateReceiptRuleSetRequestSerializer {
@@ -1087,10 +1794,10 @@ impl CreateReceiptRuleSetRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateReceiptRuleSetResponse {}
 
-struct CreateReceiptRuleSetResponseDeserializer;
+pub(crate) struct CreateReceiptRuleSetResponseDeserializer;
 impl CreateReceiptRuleSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateReceiptRuleSetResponse, XmlParseError> {
@@ -1110,6 +1817,36 @@ pub struct CreateTemplateRequest {
     pub template: Template,
 }
 
+impl CreateTemplateRequest {
+    /// Returns a builder for `CreateTemplateRequest`, checking its required members when built.
+    pub fn builder() -> CreateTemplateRequestBuilder {
+        CreateTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTemplateRequest`](struct.CreateTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTemplateRequestBuilder {
+    template: Option<Template>,
+}
+
+impl CreateTemplateRequestBuilder {
+    /// Sets the [`template`](struct.CreateTemplateRequest.html#structfield.template) member.
+    pub fn template<T: Into<Template>>(mut self, value: T) -> Self {
+        self.template = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTemplateRequest {
+            template: self
+                .template
+                .ok_or(::rusoto_core::BuildError::missing("template"))?,
+        })
+    }
+}
+
 /// Serialize `CreateTemplateRequest` contents to a `SignedRequest`.
 struct CreateTemplateRequestSerializer;
 impl CreateTemplateRequestSerializer {
@@ -1126,10 +1863,10 @@ impl CreateTemplateRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateTemplateResponse {}
 
-struct CreateTemplateResponseDeserializer;
+pub(crate) struct CreateTemplateResponseDeserializer;
 impl CreateTemplateResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateTemplateResponse, XmlParseError> {
@@ -1142,26 +1879,99 @@ impl CreateTemplateResponseDeserializer {
         Ok(obj)
     }
 }
-struct CustomMailFromStatusDeserializer;
+pub(crate) struct CustomMailFromStatusDeserializer;
 impl CustomMailFromStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<CustomMailFromStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct CustomRedirectDomainDeserializer;
-impl CustomRedirectDomainDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
-
-        Ok(obj)
+/// <p>The state that indicates whether Amazon SES has successfully read the MX record required for custom MAIL FROM domain setup. If the state is <code>Success</code>, Amazon SES uses the specified custom MAIL FROM domain when the verified identity sends an email. All other states indicate that Amazon SES takes the action described by <code>BehaviorOnMXFailure</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CustomMailFromStatus {
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>Success</code></p>
+    Success,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>TemporaryFailure</code></p>
+    TemporaryFailure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CustomMailFromStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CustomMailFromStatus::Pending => "Pending",
+            CustomMailFromStatus::Success => "Success",
+            CustomMailFromStatus::Failed => "Failed",
+            CustomMailFromStatus::TemporaryFailure => "TemporaryFailure",
+            CustomMailFromStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CustomMailFromStatus {
+    fn from(value: &'a str) -> CustomMailFromStatus {
+        match value {
+            "Pending" => CustomMailFromStatus::Pending,
+            "Success" => CustomMailFromStatus::Success,
+            "Failed" => CustomMailFromStatus::Failed,
+            "TemporaryFailure" => CustomMailFromStatus::TemporaryFailure,
+            _ => CustomMailFromStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CustomMailFromStatus {
+    fn from(value: String) -> CustomMailFromStatus {
+        CustomMailFromStatus::from(value.as_str())
+    }
+}
+
+impl From<CustomMailFromStatus> for String {
+    fn from(value: CustomMailFromStatus) -> String {
+        match value {
+            CustomMailFromStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CustomMailFromStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CustomMailFromStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct CustomRedirectDomainDeserializer;
+impl CustomRedirectDomainDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
     }
 }
 /// <p>Contains information about a custom verification email template.</p>
@@ -1179,10 +1989,10 @@ pub struct CustomVerificationEmailTemplate {
     pub template_subject: Option<String>,
 }
 
-struct CustomVerificationEmailTemplateDeserializer;
+pub(crate) struct CustomVerificationEmailTemplateDeserializer;
 impl CustomVerificationEmailTemplateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CustomVerificationEmailTemplate, XmlParseError> {
@@ -1228,10 +2038,10 @@ impl CustomVerificationEmailTemplateDeserializer {
         )
     }
 }
-struct CustomVerificationEmailTemplatesDeserializer;
+pub(crate) struct CustomVerificationEmailTemplatesDeserializer;
 impl CustomVerificationEmailTemplatesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CustomVerificationEmailTemplate>, XmlParseError> {
@@ -1247,10 +2057,13 @@ impl CustomVerificationEmailTemplatesDeserializer {
         })
     }
 }
-struct DefaultDimensionValueDeserializer;
+pub(crate) struct DefaultDimensionValueDeserializer;
 impl DefaultDimensionValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1267,6 +2080,51 @@ pub struct DeleteConfigurationSetEventDestinationRequest {
     pub event_destination_name: String,
 }
 
+impl DeleteConfigurationSetEventDestinationRequest {
+    /// Returns a builder for `DeleteConfigurationSetEventDestinationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConfigurationSetEventDestinationRequestBuilder {
+        DeleteConfigurationSetEventDestinationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConfigurationSetEventDestinationRequest`](struct.DeleteConfigurationSetEventDestinationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConfigurationSetEventDestinationRequestBuilder {
+    configuration_set_name: Option<String>,
+    event_destination_name: Option<String>,
+}
+
+impl DeleteConfigurationSetEventDestinationRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.DeleteConfigurationSetEventDestinationRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_destination_name`](struct.DeleteConfigurationSetEventDestinationRequest.html#structfield.event_destination_name) member.
+    pub fn event_destination_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_destination_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConfigurationSetEventDestinationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DeleteConfigurationSetEventDestinationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DeleteConfigurationSetEventDestinationRequest {
+            configuration_set_name: self
+                .configuration_set_name
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set_name"))?,
+            event_destination_name: self
+                .event_destination_name
+                .ok_or(::rusoto_core::BuildError::missing("event_destination_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteConfigurationSetEventDestinationRequest` contents to a `SignedRequest`.
 struct DeleteConfigurationSetEventDestinationRequestSerializer;
 impl DeleteConfigurationSetEventDestinationRequestSerializer {
@@ -1295,10 +2153,10 @@ impl DeleteConfigurationSetEventDestinationRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteConfigurationSetEventDestinationResponse {}
 
-struct DeleteConfigurationSetEventDestinationResponseDeserializer;
+pub(crate) struct DeleteConfigurationSetEventDestinationResponseDeserializer;
 impl DeleteConfigurationSetEventDestinationResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteConfigurationSetEventDestinationResponse, XmlParseError> {
@@ -1318,6 +2176,38 @@ pub struct DeleteConfigurationSetRequest {
     pub configuration_set_name: String,
 }
 
+impl DeleteConfigurationSetRequest {
+    /// Returns a builder for `DeleteConfigurationSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConfigurationSetRequestBuilder {
+        DeleteConfigurationSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConfigurationSetRequest`](struct.DeleteConfigurationSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConfigurationSetRequestBuilder {
+    configuration_set_name: Option<String>,
+}
+
+impl DeleteConfigurationSetRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.DeleteConfigurationSetRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConfigurationSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteConfigurationSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteConfigurationSetRequest {
+            configuration_set_name: self
+                .configuration_set_name
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteConfigurationSetRequest` contents to a `SignedRequest`.
 struct DeleteConfigurationSetRequestSerializer;
 impl DeleteConfigurationSetRequestSerializer {
@@ -1338,10 +2228,10 @@ impl DeleteConfigurationSetRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteConfigurationSetResponse {}
 
-struct DeleteConfigurationSetResponseDeserializer;
+pub(crate) struct DeleteConfigurationSetResponseDeserializer;
 impl DeleteConfigurationSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteConfigurationSetResponse, XmlParseError> {
@@ -1361,6 +2251,41 @@ pub struct DeleteConfigurationSetTrackingOptionsRequest {
     pub configuration_set_name: String,
 }
 
+impl DeleteConfigurationSetTrackingOptionsRequest {
+    /// Returns a builder for `DeleteConfigurationSetTrackingOptionsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConfigurationSetTrackingOptionsRequestBuilder {
+        DeleteConfigurationSetTrackingOptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConfigurationSetTrackingOptionsRequest`](struct.DeleteConfigurationSetTrackingOptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConfigurationSetTrackingOptionsRequestBuilder {
+    configuration_set_name: Option<String>,
+}
+
+impl DeleteConfigurationSetTrackingOptionsRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.DeleteConfigurationSetTrackingOptionsRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConfigurationSetTrackingOptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DeleteConfigurationSetTrackingOptionsRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DeleteConfigurationSetTrackingOptionsRequest {
+            configuration_set_name: self
+                .configuration_set_name
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteConfigurationSetTrackingOptionsRequest` contents to a `SignedRequest`.
 struct DeleteConfigurationSetTrackingOptionsRequestSerializer;
 impl DeleteConfigurationSetTrackingOptionsRequestSerializer {
@@ -1385,10 +2310,10 @@ impl DeleteConfigurationSetTrackingOptionsRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteConfigurationSetTrackingOptionsResponse {}
 
-struct DeleteConfigurationSetTrackingOptionsResponseDeserializer;
+pub(crate) struct DeleteConfigurationSetTrackingOptionsResponseDeserializer;
 impl DeleteConfigurationSetTrackingOptionsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteConfigurationSetTrackingOptionsResponse, XmlParseError> {
@@ -1408,6 +2333,41 @@ pub struct DeleteCustomVerificationEmailTemplateRequest {
     pub template_name: String,
 }
 
+impl DeleteCustomVerificationEmailTemplateRequest {
+    /// Returns a builder for `DeleteCustomVerificationEmailTemplateRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCustomVerificationEmailTemplateRequestBuilder {
+        DeleteCustomVerificationEmailTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCustomVerificationEmailTemplateRequest`](struct.DeleteCustomVerificationEmailTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCustomVerificationEmailTemplateRequestBuilder {
+    template_name: Option<String>,
+}
+
+impl DeleteCustomVerificationEmailTemplateRequestBuilder {
+    /// Sets the [`template_name`](struct.DeleteCustomVerificationEmailTemplateRequest.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCustomVerificationEmailTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DeleteCustomVerificationEmailTemplateRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DeleteCustomVerificationEmailTemplateRequest {
+            template_name: self
+                .template_name
+                .ok_or(::rusoto_core::BuildError::missing("template_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteCustomVerificationEmailTemplateRequest` contents to a `SignedRequest`.
 struct DeleteCustomVerificationEmailTemplateRequestSerializer;
 impl DeleteCustomVerificationEmailTemplateRequestSerializer {
@@ -1434,6 +2394,48 @@ pub struct DeleteIdentityPolicyRequest {
     pub policy_name: String,
 }
 
+impl DeleteIdentityPolicyRequest {
+    /// Returns a builder for `DeleteIdentityPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIdentityPolicyRequestBuilder {
+        DeleteIdentityPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIdentityPolicyRequest`](struct.DeleteIdentityPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIdentityPolicyRequestBuilder {
+    identity: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl DeleteIdentityPolicyRequestBuilder {
+    /// Sets the [`identity`](struct.DeleteIdentityPolicyRequest.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.DeleteIdentityPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIdentityPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteIdentityPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIdentityPolicyRequest {
+            identity: self
+                .identity
+                .ok_or(::rusoto_core::BuildError::missing("identity"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteIdentityPolicyRequest` contents to a `SignedRequest`.
 struct DeleteIdentityPolicyRequestSerializer;
 impl DeleteIdentityPolicyRequestSerializer {
@@ -1452,10 +2454,10 @@ impl DeleteIdentityPolicyRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteIdentityPolicyResponse {}
 
-struct DeleteIdentityPolicyResponseDeserializer;
+pub(crate) struct DeleteIdentityPolicyResponseDeserializer;
 impl DeleteIdentityPolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteIdentityPolicyResponse, XmlParseError> {
@@ -1475,6 +2477,36 @@ pub struct DeleteIdentityRequest {
     pub identity: String,
 }
 
+impl DeleteIdentityRequest {
+    /// Returns a builder for `DeleteIdentityRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIdentityRequestBuilder {
+        DeleteIdentityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIdentityRequest`](struct.DeleteIdentityRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIdentityRequestBuilder {
+    identity: Option<String>,
+}
+
+impl DeleteIdentityRequestBuilder {
+    /// Sets the [`identity`](struct.DeleteIdentityRequest.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIdentityRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteIdentityRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIdentityRequest {
+            identity: self
+                .identity
+                .ok_or(::rusoto_core::BuildError::missing("identity"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteIdentityRequest` contents to a `SignedRequest`.
 struct DeleteIdentityRequestSerializer;
 impl DeleteIdentityRequestSerializer {
@@ -1492,10 +2524,10 @@ impl DeleteIdentityRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteIdentityResponse {}
 
-struct DeleteIdentityResponseDeserializer;
+pub(crate) struct DeleteIdentityResponseDeserializer;
 impl DeleteIdentityResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteIdentityResponse, XmlParseError> {
@@ -1515,6 +2547,38 @@ pub struct DeleteReceiptFilterRequest {
     pub filter_name: String,
 }
 
+impl DeleteReceiptFilterRequest {
+    /// Returns a builder for `DeleteReceiptFilterRequest`, checking its required members when built.
+    pub fn builder() -> DeleteReceiptFilterRequestBuilder {
+        DeleteReceiptFilterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteReceiptFilterRequest`](struct.DeleteReceiptFilterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteReceiptFilterRequestBuilder {
+    filter_name: Option<String>,
+}
+
+impl DeleteReceiptFilterRequestBuilder {
+    /// Sets the [`filter_name`](struct.DeleteReceiptFilterRequest.html#structfield.filter_name) member.
+    pub fn filter_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteReceiptFilterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteReceiptFilterRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteReceiptFilterRequest {
+            filter_name: self
+                .filter_name
+                .ok_or(::rusoto_core::BuildError::missing("filter_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteReceiptFilterRequest` contents to a `SignedRequest`.
 struct DeleteReceiptFilterRequestSerializer;
 impl DeleteReceiptFilterRequestSerializer {
@@ -1532,10 +2596,10 @@ impl DeleteReceiptFilterRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteReceiptFilterResponse {}
 
-struct DeleteReceiptFilterResponseDeserializer;
+pub(crate) struct DeleteReceiptFilterResponseDeserializer;
 impl DeleteReceiptFilterResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteReceiptFilterResponse, XmlParseError> {
@@ -1557,6 +2621,48 @@ pub struct DeleteReceiptRuleRequest {
     pub rule_set_name: String,
 }
 
+impl DeleteReceiptRuleRequest {
+    /// Returns a builder for `DeleteReceiptRuleRequest`, checking its required members when built.
+    pub fn builder() -> DeleteReceiptRuleRequestBuilder {
+        DeleteReceiptRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteReceiptRuleRequest`](struct.DeleteReceiptRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteReceiptRuleRequestBuilder {
+    rule_name: Option<String>,
+    rule_set_name: Option<String>,
+}
+
+impl DeleteReceiptRuleRequestBuilder {
+    /// Sets the [`rule_name`](struct.DeleteReceiptRuleRequest.html#structfield.rule_name) member.
+    pub fn rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_set_name`](struct.DeleteReceiptRuleRequest.html#structfield.rule_set_name) member.
+    pub fn rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteReceiptRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteReceiptRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteReceiptRuleRequest {
+            rule_name: self
+                .rule_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_name"))?,
+            rule_set_name: self
+                .rule_set_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteReceiptRuleRequest` contents to a `SignedRequest`.
 struct DeleteReceiptRuleRequestSerializer;
 impl DeleteReceiptRuleRequestSerializer {
@@ -1575,10 +2681,10 @@ impl DeleteReceiptRuleRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteReceiptRuleResponse {}
 
-struct DeleteReceiptRuleResponseDeserializer;
+pub(crate) struct DeleteReceiptRuleResponseDeserializer;
 impl DeleteReceiptRuleResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteReceiptRuleResponse, XmlParseError> {
@@ -1598,6 +2704,38 @@ pub struct DeleteReceiptRuleSetRequest {
     pub rule_set_name: String,
 }
 
+impl DeleteReceiptRuleSetRequest {
+    /// Returns a builder for `DeleteReceiptRuleSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteReceiptRuleSetRequestBuilder {
+        DeleteReceiptRuleSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteReceiptRuleSetRequest`](struct.DeleteReceiptRuleSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteReceiptRuleSetRequestBuilder {
+    rule_set_name: Option<String>,
+}
+
+impl DeleteReceiptRuleSetRequestBuilder {
+    /// Sets the [`rule_set_name`](struct.DeleteReceiptRuleSetRequest.html#structfield.rule_set_name) member.
+    pub fn rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteReceiptRuleSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteReceiptRuleSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteReceiptRuleSetRequest {
+            rule_set_name: self
+                .rule_set_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteReceiptRuleSetRequest` contents to a `SignedRequest`.
 struct DeleteReceiptRuleSetRequestSerializer;
 impl DeleteReceiptRuleSetRequestSerializer {
@@ -1615,10 +2753,10 @@ impl DeleteReceiptRuleSetRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteReceiptRuleSetResponse {}
 
-struct DeleteReceiptRuleSetResponseDeserializer;
+pub(crate) struct DeleteReceiptRuleSetResponseDeserializer;
 impl DeleteReceiptRuleSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteReceiptRuleSetResponse, XmlParseError> {
@@ -1638,6 +2776,36 @@ pub struct DeleteTemplateRequest {
     pub template_name: String,
 }
 
+impl DeleteTemplateRequest {
+    /// Returns a builder for `DeleteTemplateRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTemplateRequestBuilder {
+        DeleteTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTemplateRequest`](struct.DeleteTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTemplateRequestBuilder {
+    template_name: Option<String>,
+}
+
+impl DeleteTemplateRequestBuilder {
+    /// Sets the [`template_name`](struct.DeleteTemplateRequest.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTemplateRequest {
+            template_name: self
+                .template_name
+                .ok_or(::rusoto_core::BuildError::missing("template_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteTemplateRequest` contents to a `SignedRequest`.
 struct DeleteTemplateRequestSerializer;
 impl DeleteTemplateRequestSerializer {
@@ -1654,10 +2822,10 @@ impl DeleteTemplateRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteTemplateResponse {}
 
-struct DeleteTemplateResponseDeserializer;
+pub(crate) struct DeleteTemplateResponseDeserializer;
 impl DeleteTemplateResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteTemplateResponse, XmlParseError> {
@@ -1677,6 +2845,38 @@ pub struct DeleteVerifiedEmailAddressRequest {
     pub email_address: String,
 }
 
+impl DeleteVerifiedEmailAddressRequest {
+    /// Returns a builder for `DeleteVerifiedEmailAddressRequest`, checking its required members when built.
+    pub fn builder() -> DeleteVerifiedEmailAddressRequestBuilder {
+        DeleteVerifiedEmailAddressRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVerifiedEmailAddressRequest`](struct.DeleteVerifiedEmailAddressRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteVerifiedEmailAddressRequestBuilder {
+    email_address: Option<String>,
+}
+
+impl DeleteVerifiedEmailAddressRequestBuilder {
+    /// Sets the [`email_address`](struct.DeleteVerifiedEmailAddressRequest.html#structfield.email_address) member.
+    pub fn email_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_address = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVerifiedEmailAddressRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVerifiedEmailAddressRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteVerifiedEmailAddressRequest {
+            email_address: self
+                .email_address
+                .ok_or(::rusoto_core::BuildError::missing("email_address"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteVerifiedEmailAddressRequest` contents to a `SignedRequest`.
 struct DeleteVerifiedEmailAddressRequestSerializer;
 impl DeleteVerifiedEmailAddressRequestSerializer {
@@ -1694,13 +2894,13 @@ impl DeleteVerifiedEmailAddressRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeliveryOptions {
     /// <p>Specifies whether messages that use the configuration set are required to use Transport Layer Security (TLS). If the value is <code>Require</code>, messages are only delivered if a TLS connection can be established. If the value is <code>Optional</code>, messages can be delivered in plain text if a TLS connection can't be established.</p>
-    pub tls_policy: Option<String>,
+    pub tls_policy: Option<TlsPolicy>,
 }
 
-struct DeliveryOptionsDeserializer;
+pub(crate) struct DeliveryOptionsDeserializer;
 impl DeliveryOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeliveryOptions, XmlParseError> {
@@ -1735,6 +2935,26 @@ impl DeliveryOptionsSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DescribeActiveReceiptRuleSetRequest {}
 
+impl DescribeActiveReceiptRuleSetRequest {
+    /// Returns a builder for `DescribeActiveReceiptRuleSetRequest`, checking its required members when built.
+    pub fn builder() -> DescribeActiveReceiptRuleSetRequestBuilder {
+        DescribeActiveReceiptRuleSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeActiveReceiptRuleSetRequest`](struct.DescribeActiveReceiptRuleSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeActiveReceiptRuleSetRequestBuilder {}
+
+impl DescribeActiveReceiptRuleSetRequestBuilder {
+    /// Builds the `DescribeActiveReceiptRuleSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeActiveReceiptRuleSetRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeActiveReceiptRuleSetRequest {})
+    }
+}
+
 /// Serialize `DescribeActiveReceiptRuleSetRequest` contents to a `SignedRequest`.
 struct DescribeActiveReceiptRuleSetRequestSerializer;
 impl DescribeActiveReceiptRuleSetRequestSerializer {
@@ -1755,10 +2975,10 @@ pub struct DescribeActiveReceiptRuleSetResponse {
     pub rules: Option<Vec<ReceiptRule>>,
 }
 
-struct DescribeActiveReceiptRuleSetResponseDeserializer;
+pub(crate) struct DescribeActiveReceiptRuleSetResponseDeserializer;
 impl DescribeActiveReceiptRuleSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeActiveReceiptRuleSetResponse, XmlParseError> {
@@ -1788,11 +3008,54 @@ impl DescribeActiveReceiptRuleSetResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DescribeConfigurationSetRequest {
     /// <p>A list of configuration set attributes to return.</p>
-    pub configuration_set_attribute_names: Option<Vec<String>>,
+    pub configuration_set_attribute_names: Option<Vec<ConfigurationSetAttribute>>,
     /// <p>The name of the configuration set to describe.</p>
     pub configuration_set_name: String,
 }
 
+impl DescribeConfigurationSetRequest {
+    /// Returns a builder for `DescribeConfigurationSetRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationSetRequestBuilder {
+        DescribeConfigurationSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationSetRequest`](struct.DescribeConfigurationSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationSetRequestBuilder {
+    configuration_set_attribute_names: Option<Vec<ConfigurationSetAttribute>>,
+    configuration_set_name: Option<String>,
+}
+
+impl DescribeConfigurationSetRequestBuilder {
+    /// Sets the [`configuration_set_attribute_names`](struct.DescribeConfigurationSetRequest.html#structfield.configuration_set_attribute_names) member.
+    pub fn configuration_set_attribute_names<T: Into<Vec<ConfigurationSetAttribute>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.configuration_set_attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_set_name`](struct.DescribeConfigurationSetRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigurationSetRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeConfigurationSetRequest {
+            configuration_set_attribute_names: self.configuration_set_attribute_names,
+            configuration_set_name: self
+                .configuration_set_name
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeConfigurationSetRequest` contents to a `SignedRequest`.
 struct DescribeConfigurationSetRequestSerializer;
 impl DescribeConfigurationSetRequestSerializer {
@@ -1830,10 +3093,10 @@ pub struct DescribeConfigurationSetResponse {
     pub tracking_options: Option<TrackingOptions>,
 }
 
-struct DescribeConfigurationSetResponseDeserializer;
+pub(crate) struct DescribeConfigurationSetResponseDeserializer;
 impl DescribeConfigurationSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeConfigurationSetResponse, XmlParseError> {
@@ -1887,6 +3150,48 @@ pub struct DescribeReceiptRuleRequest {
     pub rule_set_name: String,
 }
 
+impl DescribeReceiptRuleRequest {
+    /// Returns a builder for `DescribeReceiptRuleRequest`, checking its required members when built.
+    pub fn builder() -> DescribeReceiptRuleRequestBuilder {
+        DescribeReceiptRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReceiptRuleRequest`](struct.DescribeReceiptRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeReceiptRuleRequestBuilder {
+    rule_name: Option<String>,
+    rule_set_name: Option<String>,
+}
+
+impl DescribeReceiptRuleRequestBuilder {
+    /// Sets the [`rule_name`](struct.DescribeReceiptRuleRequest.html#structfield.rule_name) member.
+    pub fn rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_set_name`](struct.DescribeReceiptRuleRequest.html#structfield.rule_set_name) member.
+    pub fn rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReceiptRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReceiptRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeReceiptRuleRequest {
+            rule_name: self
+                .rule_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_name"))?,
+            rule_set_name: self
+                .rule_set_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeReceiptRuleRequest` contents to a `SignedRequest`.
 struct DescribeReceiptRuleRequestSerializer;
 impl DescribeReceiptRuleRequestSerializer {
@@ -1908,10 +3213,10 @@ pub struct DescribeReceiptRuleResponse {
     pub rule: Option<ReceiptRule>,
 }
 
-struct DescribeReceiptRuleResponseDeserializer;
+pub(crate) struct DescribeReceiptRuleResponseDeserializer;
 impl DescribeReceiptRuleResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeReceiptRuleResponse, XmlParseError> {
@@ -1937,6 +3242,38 @@ pub struct DescribeReceiptRuleSetRequest {
     pub rule_set_name: String,
 }
 
+impl DescribeReceiptRuleSetRequest {
+    /// Returns a builder for `DescribeReceiptRuleSetRequest`, checking its required members when built.
+    pub fn builder() -> DescribeReceiptRuleSetRequestBuilder {
+        DescribeReceiptRuleSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReceiptRuleSetRequest`](struct.DescribeReceiptRuleSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeReceiptRuleSetRequestBuilder {
+    rule_set_name: Option<String>,
+}
+
+impl DescribeReceiptRuleSetRequestBuilder {
+    /// Sets the [`rule_set_name`](struct.DescribeReceiptRuleSetRequest.html#structfield.rule_set_name) member.
+    pub fn rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReceiptRuleSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReceiptRuleSetRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeReceiptRuleSetRequest {
+            rule_set_name: self
+                .rule_set_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeReceiptRuleSetRequest` contents to a `SignedRequest`.
 struct DescribeReceiptRuleSetRequestSerializer;
 impl DescribeReceiptRuleSetRequestSerializer {
@@ -1959,10 +3296,10 @@ pub struct DescribeReceiptRuleSetResponse {
     pub rules: Option<Vec<ReceiptRule>>,
 }
 
-struct DescribeReceiptRuleSetResponseDeserializer;
+pub(crate) struct DescribeReceiptRuleSetResponseDeserializer;
 impl DescribeReceiptRuleSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeReceiptRuleSetResponse, XmlParseError> {
@@ -2032,10 +3369,13 @@ impl DestinationSerializer {
     }
 }
 
-struct DimensionNameDeserializer;
+pub(crate) struct DimensionNameDeserializer;
 impl DimensionNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2043,21 +3383,87 @@ impl DimensionNameDeserializer {
         Ok(obj)
     }
 }
-struct DimensionValueSourceDeserializer;
+pub(crate) struct DimensionValueSourceDeserializer;
 impl DimensionValueSourceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<DimensionValueSource, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct DkimAttributesDeserializer;
+/// <p>The place where Amazon SES finds the value of a dimension to publish to Amazon CloudWatch. If you want Amazon SES to use the message tags that you specify using an <code>X-SES-MESSAGE-TAGS</code> header or a parameter to the <code>SendEmail</code>/<code>SendRawEmail</code> API, choose <code>messageTag</code>. If you want Amazon SES to use your own email headers, choose <code>emailHeader</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DimensionValueSource {
+    /// <p><code>messageTag</code></p>
+    MessageTag,
+    /// <p><code>emailHeader</code></p>
+    EmailHeader,
+    /// <p><code>linkTag</code></p>
+    LinkTag,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DimensionValueSource {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DimensionValueSource::MessageTag => "messageTag",
+            DimensionValueSource::EmailHeader => "emailHeader",
+            DimensionValueSource::LinkTag => "linkTag",
+            DimensionValueSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DimensionValueSource {
+    fn from(value: &'a str) -> DimensionValueSource {
+        match value {
+            "messageTag" => DimensionValueSource::MessageTag,
+            "emailHeader" => DimensionValueSource::EmailHeader,
+            "linkTag" => DimensionValueSource::LinkTag,
+            _ => DimensionValueSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DimensionValueSource {
+    fn from(value: String) -> DimensionValueSource {
+        DimensionValueSource::from(value.as_str())
+    }
+}
+
+impl From<DimensionValueSource> for String {
+    fn from(value: DimensionValueSource) -> String {
+        match value {
+            DimensionValueSource::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DimensionValueSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DimensionValueSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct DkimAttributesDeserializer;
 impl DkimAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, IdentityDkimAttributes>, XmlParseError> {
@@ -2077,31 +3483,490 @@ impl DkimAttributesDeserializer {
         Ok(obj)
     }
 }
-struct EnabledDeserializer;
-impl EnabledDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
-        end_element(tag_name, stack)?;
+/// <p>The action performed by the reporting mail transfer agent (MTA) as a result of its attempt to deliver the message to the recipient address. This is required by <a href="https://tools.ietf.org/html/rfc3464">RFC 3464</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DsnAction {
+    /// <p><code>failed</code></p>
+    Failed,
+    /// <p><code>delayed</code></p>
+    Delayed,
+    /// <p><code>delivered</code></p>
+    Delivered,
+    /// <p><code>relayed</code></p>
+    Relayed,
+    /// <p><code>expanded</code></p>
+    Expanded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DsnAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DsnAction::Failed => "failed",
+            DsnAction::Delayed => "delayed",
+            DsnAction::Delivered => "delivered",
+            DsnAction::Relayed => "relayed",
+            DsnAction::Expanded => "expanded",
+            DsnAction::Unknown(ref value) => value,
+        }
+    }
+}
 
-        Ok(obj)
+impl<'a> From<&'a str> for DsnAction {
+    fn from(value: &'a str) -> DsnAction {
+        match value {
+            "failed" => DsnAction::Failed,
+            "delayed" => DsnAction::Delayed,
+            "delivered" => DsnAction::Delivered,
+            "relayed" => DsnAction::Relayed,
+            "expanded" => DsnAction::Expanded,
+            _ => DsnAction::Unknown(value.to_owned()),
+        }
     }
 }
-struct SesErrorDeserializer;
-impl SesErrorDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl From<String> for DsnAction {
+    fn from(value: String) -> DsnAction {
+        DsnAction::from(value.as_str())
     }
 }
-/// <p>Contains information about the event destination that the specified email sending events will be published to.</p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be Amazon CloudWatch, Amazon Kinesis Firehose or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>Event destinations are associated with configuration sets, which enable you to publish email sending events to Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS). For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct EventDestination {
+
+impl From<DsnAction> for String {
+    fn from(value: DsnAction) -> String {
+        match value {
+            DsnAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DsnAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DsnAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct EnabledDeserializer;
+impl EnabledDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p>The type of email sending events to publish to the event destination.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventType {
+    /// <p><code>send</code></p>
+    Send,
+    /// <p><code>reject</code></p>
+    Reject,
+    /// <p><code>bounce</code></p>
+    Bounce,
+    /// <p><code>complaint</code></p>
+    Complaint,
+    /// <p><code>delivery</code></p>
+    Delivery,
+    /// <p><code>open</code></p>
+    Open,
+    /// <p><code>click</code></p>
+    Click,
+    /// <p><code>renderingFailure</code></p>
+    RenderingFailure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::Send => "send",
+            EventType::Reject => "reject",
+            EventType::Bounce => "bounce",
+            EventType::Complaint => "complaint",
+            EventType::Delivery => "delivery",
+            EventType::Open => "open",
+            EventType::Click => "click",
+            EventType::RenderingFailure => "renderingFailure",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "send" => EventType::Send,
+            "reject" => EventType::Reject,
+            "bounce" => EventType::Bounce,
+            "complaint" => EventType::Complaint,
+            "delivery" => EventType::Delivery,
+            "open" => EventType::Open,
+            "click" => EventType::Click,
+            "renderingFailure" => EventType::RenderingFailure,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        EventType::from(value.as_str())
+    }
+}
+
+impl From<EventType> for String {
+    fn from(value: EventType) -> String {
+        match value {
+            EventType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p>The type of the identities to list. Possible values are "EmailAddress" and "Domain". If this parameter is omitted, then all identities will be listed.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IdentityType {
+    /// <p><code>EmailAddress</code></p>
+    EmailAddress,
+    /// <p><code>Domain</code></p>
+    Domain,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IdentityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IdentityType::EmailAddress => "EmailAddress",
+            IdentityType::Domain => "Domain",
+            IdentityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IdentityType {
+    fn from(value: &'a str) -> IdentityType {
+        match value {
+            "EmailAddress" => IdentityType::EmailAddress,
+            "Domain" => IdentityType::Domain,
+            _ => IdentityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IdentityType {
+    fn from(value: String) -> IdentityType {
+        IdentityType::from(value.as_str())
+    }
+}
+
+impl From<IdentityType> for String {
+    fn from(value: IdentityType) -> String {
+        match value {
+            IdentityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IdentityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IdentityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p><p>The invocation type of the AWS Lambda function. An invocation type of <code>RequestResponse</code> means that the execution of the function will immediately result in a response, and a value of <code>Event</code> means that the function will be invoked asynchronously. The default value is <code>Event</code>. For information about AWS Lambda invocation types, see the <a href="https://docs.aws.amazon.com/lambda/latest/dg/API_Invoke.html">AWS Lambda Developer Guide</a>.</p> <important> <p>There is a 30-second timeout on <code>RequestResponse</code> invocations. You should use <code>Event</code> invocation in most cases. Use <code>RequestResponse</code> only when you want to make a mail flow decision, such as whether to stop the receipt rule or the receipt rule set.</p> </important></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InvocationType {
+    /// <p><code>Event</code></p>
+    Event,
+    /// <p><code>RequestResponse</code></p>
+    RequestResponse,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InvocationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InvocationType::Event => "Event",
+            InvocationType::RequestResponse => "RequestResponse",
+            InvocationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InvocationType {
+    fn from(value: &'a str) -> InvocationType {
+        match value {
+            "Event" => InvocationType::Event,
+            "RequestResponse" => InvocationType::RequestResponse,
+            _ => InvocationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InvocationType {
+    fn from(value: String) -> InvocationType {
+        InvocationType::from(value.as_str())
+    }
+}
+
+impl From<InvocationType> for String {
+    fn from(value: InvocationType) -> String {
+        match value {
+            InvocationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InvocationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InvocationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p>The notification type for which to enable or disable headers in notifications. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NotificationType {
+    /// <p><code>Bounce</code></p>
+    Bounce,
+    /// <p><code>Complaint</code></p>
+    Complaint,
+    /// <p><code>Delivery</code></p>
+    Delivery,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NotificationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotificationType::Bounce => "Bounce",
+            NotificationType::Complaint => "Complaint",
+            NotificationType::Delivery => "Delivery",
+            NotificationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NotificationType {
+    fn from(value: &'a str) -> NotificationType {
+        match value {
+            "Bounce" => NotificationType::Bounce,
+            "Complaint" => NotificationType::Complaint,
+            "Delivery" => NotificationType::Delivery,
+            _ => NotificationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotificationType {
+    fn from(value: String) -> NotificationType {
+        NotificationType::from(value.as_str())
+    }
+}
+
+impl From<NotificationType> for String {
+    fn from(value: NotificationType) -> String {
+        match value {
+            NotificationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NotificationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NotificationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p>Indicates whether to block or allow incoming mail from the specified IP addresses.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReceiptFilterPolicy {
+    /// <p><code>Block</code></p>
+    Block,
+    /// <p><code>Allow</code></p>
+    Allow,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReceiptFilterPolicy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReceiptFilterPolicy::Block => "Block",
+            ReceiptFilterPolicy::Allow => "Allow",
+            ReceiptFilterPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReceiptFilterPolicy {
+    fn from(value: &'a str) -> ReceiptFilterPolicy {
+        match value {
+            "Block" => ReceiptFilterPolicy::Block,
+            "Allow" => ReceiptFilterPolicy::Allow,
+            _ => ReceiptFilterPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReceiptFilterPolicy {
+    fn from(value: String) -> ReceiptFilterPolicy {
+        ReceiptFilterPolicy::from(value.as_str())
+    }
+}
+
+impl From<ReceiptFilterPolicy> for String {
+    fn from(value: ReceiptFilterPolicy) -> String {
+        match value {
+            ReceiptFilterPolicy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReceiptFilterPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReceiptFilterPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p>The encoding to use for the email within the Amazon SNS notification. UTF-8 is easier to use, but may not preserve all special characters when a message was encoded with a different encoding format. Base64 preserves all special characters. The default value is UTF-8.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SNSActionEncoding {
+    /// <p><code>UTF-8</code></p>
+    Utf8,
+    /// <p><code>Base64</code></p>
+    Base64,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SNSActionEncoding {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SNSActionEncoding::Utf8 => "UTF-8",
+            SNSActionEncoding::Base64 => "Base64",
+            SNSActionEncoding::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SNSActionEncoding {
+    fn from(value: &'a str) -> SNSActionEncoding {
+        match value {
+            "UTF-8" => SNSActionEncoding::Utf8,
+            "Base64" => SNSActionEncoding::Base64,
+            _ => SNSActionEncoding::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SNSActionEncoding {
+    fn from(value: String) -> SNSActionEncoding {
+        SNSActionEncoding::from(value.as_str())
+    }
+}
+
+impl From<SNSActionEncoding> for String {
+    fn from(value: SNSActionEncoding) -> String {
+        match value {
+            SNSActionEncoding::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SNSActionEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SNSActionEncoding {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct SesErrorDeserializer;
+impl SesErrorDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p>Contains information about the event destination that the specified email sending events will be published to.</p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be Amazon CloudWatch, Amazon Kinesis Firehose or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>Event destinations are associated with configuration sets, which enable you to publish email sending events to Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS). For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct EventDestination {
     /// <p>An object that contains the names, default values, and sources of the dimensions associated with an Amazon CloudWatch event destination.</p>
     pub cloud_watch_destination: Option<CloudWatchDestination>,
     /// <p>Sets whether Amazon SES publishes events to this destination when you send an email with the associated configuration set. Set to <code>true</code> to enable publishing to this destination; set to <code>false</code> to prevent publishing to this destination. The default value is <code>false</code>.</p>
@@ -2109,17 +3974,17 @@ pub struct EventDestination {
     /// <p>An object that contains the delivery stream ARN and the IAM role ARN associated with an Amazon Kinesis Firehose event destination.</p>
     pub kinesis_firehose_destination: Option<KinesisFirehoseDestination>,
     /// <p>The type of email sending events to publish to the event destination.</p>
-    pub matching_event_types: Vec<String>,
+    pub matching_event_types: Vec<EventType>,
     /// <p><p>The name of the event destination. The name must:</p> <ul> <li> <p>This value can only contain ASCII letters (a-z, A-Z), numbers (0-9), underscores (_), or dashes (-).</p> </li> <li> <p>Contain less than 64 characters.</p> </li> </ul></p>
     pub name: String,
     /// <p>An object that contains the topic ARN associated with an Amazon Simple Notification Service (Amazon SNS) event destination.</p>
     pub sns_destination: Option<SNSDestination>,
 }
 
-struct EventDestinationDeserializer;
+pub(crate) struct EventDestinationDeserializer;
 impl EventDestinationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventDestination, XmlParseError> {
@@ -2207,10 +4072,13 @@ impl EventDestinationSerializer {
     }
 }
 
-struct EventDestinationNameDeserializer;
+pub(crate) struct EventDestinationNameDeserializer;
 impl EventDestinationNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2218,10 +4086,10 @@ impl EventDestinationNameDeserializer {
         Ok(obj)
     }
 }
-struct EventDestinationsDeserializer;
+pub(crate) struct EventDestinationsDeserializer;
 impl EventDestinationsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EventDestination>, XmlParseError> {
@@ -2235,24 +4103,27 @@ impl EventDestinationsDeserializer {
         })
     }
 }
-struct EventTypeDeserializer;
+pub(crate) struct EventTypeDeserializer;
 impl EventTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EventType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct EventTypesDeserializer;
+pub(crate) struct EventTypesDeserializer;
 impl EventTypesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<EventType>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "member" {
                 obj.push(EventTypeDeserializer::deserialize("member", stack)?);
@@ -2267,7 +4138,7 @@ impl EventTypesDeserializer {
 /// Serialize `EventTypes` contents to a `SignedRequest`.
 struct EventTypesSerializer;
 impl EventTypesSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<EventType>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -2309,10 +4180,13 @@ impl ExtensionFieldListSerializer {
     }
 }
 
-struct FailureRedirectionURLDeserializer;
+pub(crate) struct FailureRedirectionURLDeserializer;
 impl FailureRedirectionURLDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2320,10 +4194,13 @@ impl FailureRedirectionURLDeserializer {
         Ok(obj)
     }
 }
-struct FromAddressDeserializer;
+pub(crate) struct FromAddressDeserializer;
 impl FromAddressDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2338,10 +4215,10 @@ pub struct GetAccountSendingEnabledResponse {
     pub enabled: Option<bool>,
 }
 
-struct GetAccountSendingEnabledResponseDeserializer;
+pub(crate) struct GetAccountSendingEnabledResponseDeserializer;
 impl GetAccountSendingEnabledResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetAccountSendingEnabledResponse, XmlParseError> {
@@ -2367,6 +4244,39 @@ pub struct GetCustomVerificationEmailTemplateRequest {
     pub template_name: String,
 }
 
+impl GetCustomVerificationEmailTemplateRequest {
+    /// Returns a builder for `GetCustomVerificationEmailTemplateRequest`, checking its required members when built.
+    pub fn builder() -> GetCustomVerificationEmailTemplateRequestBuilder {
+        GetCustomVerificationEmailTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCustomVerificationEmailTemplateRequest`](struct.GetCustomVerificationEmailTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCustomVerificationEmailTemplateRequestBuilder {
+    template_name: Option<String>,
+}
+
+impl GetCustomVerificationEmailTemplateRequestBuilder {
+    /// Sets the [`template_name`](struct.GetCustomVerificationEmailTemplateRequest.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCustomVerificationEmailTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCustomVerificationEmailTemplateRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetCustomVerificationEmailTemplateRequest {
+            template_name: self
+                .template_name
+                .ok_or(::rusoto_core::BuildError::missing("template_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetCustomVerificationEmailTemplateRequest` contents to a `SignedRequest`.
 struct GetCustomVerificationEmailTemplateRequestSerializer;
 impl GetCustomVerificationEmailTemplateRequestSerializer {
@@ -2397,10 +4307,10 @@ pub struct GetCustomVerificationEmailTemplateResponse {
     pub template_subject: Option<String>,
 }
 
-struct GetCustomVerificationEmailTemplateResponseDeserializer;
+pub(crate) struct GetCustomVerificationEmailTemplateResponseDeserializer;
 impl GetCustomVerificationEmailTemplateResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetCustomVerificationEmailTemplateResponse, XmlParseError> {
@@ -2459,6 +4369,38 @@ pub struct GetIdentityDkimAttributesRequest {
     pub identities: Vec<String>,
 }
 
+impl GetIdentityDkimAttributesRequest {
+    /// Returns a builder for `GetIdentityDkimAttributesRequest`, checking its required members when built.
+    pub fn builder() -> GetIdentityDkimAttributesRequestBuilder {
+        GetIdentityDkimAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIdentityDkimAttributesRequest`](struct.GetIdentityDkimAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIdentityDkimAttributesRequestBuilder {
+    identities: Option<Vec<String>>,
+}
+
+impl GetIdentityDkimAttributesRequestBuilder {
+    /// Sets the [`identities`](struct.GetIdentityDkimAttributesRequest.html#structfield.identities) member.
+    pub fn identities<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.identities = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIdentityDkimAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIdentityDkimAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(GetIdentityDkimAttributesRequest {
+            identities: self
+                .identities
+                .ok_or(::rusoto_core::BuildError::missing("identities"))?,
+        })
+    }
+}
+
 /// Serialize `GetIdentityDkimAttributesRequest` contents to a `SignedRequest`.
 struct GetIdentityDkimAttributesRequestSerializer;
 impl GetIdentityDkimAttributesRequestSerializer {
@@ -2483,10 +4425,10 @@ pub struct GetIdentityDkimAttributesResponse {
     pub dkim_attributes: ::std::collections::HashMap<String, IdentityDkimAttributes>,
 }
 
-struct GetIdentityDkimAttributesResponseDeserializer;
+pub(crate) struct GetIdentityDkimAttributesResponseDeserializer;
 impl GetIdentityDkimAttributesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetIdentityDkimAttributesResponse, XmlParseError> {
@@ -2513,6 +4455,39 @@ pub struct GetIdentityMailFromDomainAttributesRequest {
     pub identities: Vec<String>,
 }
 
+impl GetIdentityMailFromDomainAttributesRequest {
+    /// Returns a builder for `GetIdentityMailFromDomainAttributesRequest`, checking its required members when built.
+    pub fn builder() -> GetIdentityMailFromDomainAttributesRequestBuilder {
+        GetIdentityMailFromDomainAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIdentityMailFromDomainAttributesRequest`](struct.GetIdentityMailFromDomainAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIdentityMailFromDomainAttributesRequestBuilder {
+    identities: Option<Vec<String>>,
+}
+
+impl GetIdentityMailFromDomainAttributesRequestBuilder {
+    /// Sets the [`identities`](struct.GetIdentityMailFromDomainAttributesRequest.html#structfield.identities) member.
+    pub fn identities<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.identities = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIdentityMailFromDomainAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIdentityMailFromDomainAttributesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetIdentityMailFromDomainAttributesRequest {
+            identities: self
+                .identities
+                .ok_or(::rusoto_core::BuildError::missing("identities"))?,
+        })
+    }
+}
+
 /// Serialize `GetIdentityMailFromDomainAttributesRequest` contents to a `SignedRequest`.
 struct GetIdentityMailFromDomainAttributesRequestSerializer;
 impl GetIdentityMailFromDomainAttributesRequestSerializer {
@@ -2542,10 +4517,10 @@ pub struct GetIdentityMailFromDomainAttributesResponse {
         ::std::collections::HashMap<String, IdentityMailFromDomainAttributes>,
 }
 
-struct GetIdentityMailFromDomainAttributesResponseDeserializer;
+pub(crate) struct GetIdentityMailFromDomainAttributesResponseDeserializer;
 impl GetIdentityMailFromDomainAttributesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetIdentityMailFromDomainAttributesResponse, XmlParseError> {
@@ -2575,6 +4550,39 @@ pub struct GetIdentityNotificationAttributesRequest {
     pub identities: Vec<String>,
 }
 
+impl GetIdentityNotificationAttributesRequest {
+    /// Returns a builder for `GetIdentityNotificationAttributesRequest`, checking its required members when built.
+    pub fn builder() -> GetIdentityNotificationAttributesRequestBuilder {
+        GetIdentityNotificationAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIdentityNotificationAttributesRequest`](struct.GetIdentityNotificationAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIdentityNotificationAttributesRequestBuilder {
+    identities: Option<Vec<String>>,
+}
+
+impl GetIdentityNotificationAttributesRequestBuilder {
+    /// Sets the [`identities`](struct.GetIdentityNotificationAttributesRequest.html#structfield.identities) member.
+    pub fn identities<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.identities = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIdentityNotificationAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIdentityNotificationAttributesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetIdentityNotificationAttributesRequest {
+            identities: self
+                .identities
+                .ok_or(::rusoto_core::BuildError::missing("identities"))?,
+        })
+    }
+}
+
 /// Serialize `GetIdentityNotificationAttributesRequest` contents to a `SignedRequest`.
 struct GetIdentityNotificationAttributesRequestSerializer;
 impl GetIdentityNotificationAttributesRequestSerializer {
@@ -2600,10 +4608,10 @@ pub struct GetIdentityNotificationAttributesResponse {
         ::std::collections::HashMap<String, IdentityNotificationAttributes>,
 }
 
-struct GetIdentityNotificationAttributesResponseDeserializer;
+pub(crate) struct GetIdentityNotificationAttributesResponseDeserializer;
 impl GetIdentityNotificationAttributesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetIdentityNotificationAttributesResponse, XmlParseError> {
@@ -2635,6 +4643,48 @@ pub struct GetIdentityPoliciesRequest {
     pub policy_names: Vec<String>,
 }
 
+impl GetIdentityPoliciesRequest {
+    /// Returns a builder for `GetIdentityPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> GetIdentityPoliciesRequestBuilder {
+        GetIdentityPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIdentityPoliciesRequest`](struct.GetIdentityPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIdentityPoliciesRequestBuilder {
+    identity: Option<String>,
+    policy_names: Option<Vec<String>>,
+}
+
+impl GetIdentityPoliciesRequestBuilder {
+    /// Sets the [`identity`](struct.GetIdentityPoliciesRequest.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_names`](struct.GetIdentityPoliciesRequest.html#structfield.policy_names) member.
+    pub fn policy_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIdentityPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIdentityPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(GetIdentityPoliciesRequest {
+            identity: self
+                .identity
+                .ok_or(::rusoto_core::BuildError::missing("identity"))?,
+            policy_names: self
+                .policy_names
+                .ok_or(::rusoto_core::BuildError::missing("policy_names"))?,
+        })
+    }
+}
+
 /// Serialize `GetIdentityPoliciesRequest` contents to a `SignedRequest`.
 struct GetIdentityPoliciesRequestSerializer;
 impl GetIdentityPoliciesRequestSerializer {
@@ -2660,10 +4710,10 @@ pub struct GetIdentityPoliciesResponse {
     pub policies: ::std::collections::HashMap<String, String>,
 }
 
-struct GetIdentityPoliciesResponseDeserializer;
+pub(crate) struct GetIdentityPoliciesResponseDeserializer;
 impl GetIdentityPoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetIdentityPoliciesResponse, XmlParseError> {
@@ -2689,6 +4739,39 @@ pub struct GetIdentityVerificationAttributesRequest {
     pub identities: Vec<String>,
 }
 
+impl GetIdentityVerificationAttributesRequest {
+    /// Returns a builder for `GetIdentityVerificationAttributesRequest`, checking its required members when built.
+    pub fn builder() -> GetIdentityVerificationAttributesRequestBuilder {
+        GetIdentityVerificationAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIdentityVerificationAttributesRequest`](struct.GetIdentityVerificationAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIdentityVerificationAttributesRequestBuilder {
+    identities: Option<Vec<String>>,
+}
+
+impl GetIdentityVerificationAttributesRequestBuilder {
+    /// Sets the [`identities`](struct.GetIdentityVerificationAttributesRequest.html#structfield.identities) member.
+    pub fn identities<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.identities = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIdentityVerificationAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIdentityVerificationAttributesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetIdentityVerificationAttributesRequest {
+            identities: self
+                .identities
+                .ok_or(::rusoto_core::BuildError::missing("identities"))?,
+        })
+    }
+}
+
 /// Serialize `GetIdentityVerificationAttributesRequest` contents to a `SignedRequest`.
 struct GetIdentityVerificationAttributesRequestSerializer;
 impl GetIdentityVerificationAttributesRequestSerializer {
@@ -2714,10 +4797,10 @@ pub struct GetIdentityVerificationAttributesResponse {
         ::std::collections::HashMap<String, IdentityVerificationAttributes>,
 }
 
-struct GetIdentityVerificationAttributesResponseDeserializer;
+pub(crate) struct GetIdentityVerificationAttributesResponseDeserializer;
 impl GetIdentityVerificationAttributesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetIdentityVerificationAttributesResponse, XmlParseError> {
@@ -2751,10 +4834,10 @@ pub struct GetSendQuotaResponse {
     pub sent_last_24_hours: Option<f64>,
 }
 
-struct GetSendQuotaResponseDeserializer;
+pub(crate) struct GetSendQuotaResponseDeserializer;
 impl GetSendQuotaResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetSendQuotaResponse, XmlParseError> {
@@ -2789,10 +4872,10 @@ pub struct GetSendStatisticsResponse {
     pub send_data_points: Option<Vec<SendDataPoint>>,
 }
 
-struct GetSendStatisticsResponseDeserializer;
+pub(crate) struct GetSendStatisticsResponseDeserializer;
 impl GetSendStatisticsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetSendStatisticsResponse, XmlParseError> {
@@ -2819,6 +4902,36 @@ pub struct GetTemplateRequest {
     pub template_name: String,
 }
 
+impl GetTemplateRequest {
+    /// Returns a builder for `GetTemplateRequest`, checking its required members when built.
+    pub fn builder() -> GetTemplateRequestBuilder {
+        GetTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTemplateRequest`](struct.GetTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTemplateRequestBuilder {
+    template_name: Option<String>,
+}
+
+impl GetTemplateRequestBuilder {
+    /// Sets the [`template_name`](struct.GetTemplateRequest.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(GetTemplateRequest {
+            template_name: self
+                .template_name
+                .ok_or(::rusoto_core::BuildError::missing("template_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetTemplateRequest` contents to a `SignedRequest`.
 struct GetTemplateRequestSerializer;
 impl GetTemplateRequestSerializer {
@@ -2837,10 +4950,10 @@ pub struct GetTemplateResponse {
     pub template: Option<Template>,
 }
 
-struct GetTemplateResponseDeserializer;
+pub(crate) struct GetTemplateResponseDeserializer;
 impl GetTemplateResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetTemplateResponse, XmlParseError> {
@@ -2855,10 +4968,13 @@ impl GetTemplateResponseDeserializer {
         })
     }
 }
-struct HeaderNameDeserializer;
+pub(crate) struct HeaderNameDeserializer;
 impl HeaderNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2866,10 +4982,13 @@ impl HeaderNameDeserializer {
         Ok(obj)
     }
 }
-struct HeaderValueDeserializer;
+pub(crate) struct HeaderValueDeserializer;
 impl HeaderValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2877,10 +4996,13 @@ impl HeaderValueDeserializer {
         Ok(obj)
     }
 }
-struct HtmlPartDeserializer;
+pub(crate) struct HtmlPartDeserializer;
 impl HtmlPartDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2888,10 +5010,13 @@ impl HtmlPartDeserializer {
         Ok(obj)
     }
 }
-struct IdentityDeserializer;
+pub(crate) struct IdentityDeserializer;
 impl IdentityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2900,49 +5025,58 @@ impl IdentityDeserializer {
     }
 }
 /// <p>Represents the DKIM attributes of a verified email address or a domain.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct IdentityDkimAttributes {
     /// <p>Is true if DKIM signing is enabled for email sent from the identity. It's false otherwise. The default value is true.</p>
     pub dkim_enabled: bool,
     /// <p>A set of character strings that represent the domain's identity. Using these tokens, you need to create DNS CNAME records that point to DKIM public keys that are hosted by Amazon SES. Amazon Web Services eventually detects that you've updated your DNS records. This detection process might take up to 72 hours. After successful detection, Amazon SES is able to DKIM-sign email originating from that domain. (This only applies to domain identities, not email address identities.)</p> <p>For more information about creating DNS records using DKIM tokens, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Amazon SES Developer Guide</a>.</p>
     pub dkim_tokens: Option<Vec<String>>,
     /// <p>Describes whether Amazon SES has successfully verified the DKIM DNS records (tokens) published in the domain name's DNS. (This only applies to domain identities, not email address identities.)</p>
-    pub dkim_verification_status: String,
+    pub dkim_verification_status: VerificationStatus,
 }
 
-struct IdentityDkimAttributesDeserializer;
+pub(crate) struct IdentityDkimAttributesDeserializer;
 impl IdentityDkimAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IdentityDkimAttributes, XmlParseError> {
-        deserialize_elements::<_, IdentityDkimAttributes, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "DkimEnabled" => {
-                    obj.dkim_enabled = EnabledDeserializer::deserialize("DkimEnabled", stack)?;
-                }
-                "DkimTokens" => {
-                    obj.dkim_tokens.get_or_insert(vec![]).extend(
-                        VerificationTokenListDeserializer::deserialize("DkimTokens", stack)?,
-                    );
-                }
-                "DkimVerificationStatus" => {
-                    obj.dkim_verification_status = VerificationStatusDeserializer::deserialize(
-                        "DkimVerificationStatus",
-                        stack,
-                    )?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            IdentityDkimAttributes {
+                dkim_enabled: Default::default(),
+                dkim_tokens: Default::default(),
+                dkim_verification_status: VerificationStatus::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "DkimEnabled" => {
+                        obj.dkim_enabled = EnabledDeserializer::deserialize("DkimEnabled", stack)?;
+                    }
+                    "DkimTokens" => {
+                        obj.dkim_tokens.get_or_insert(vec![]).extend(
+                            VerificationTokenListDeserializer::deserialize("DkimTokens", stack)?,
+                        );
+                    }
+                    "DkimVerificationStatus" => {
+                        obj.dkim_verification_status = VerificationStatusDeserializer::deserialize(
+                            "DkimVerificationStatus",
+                            stack,
+                        )?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct IdentityListDeserializer;
+pub(crate) struct IdentityListDeserializer;
 impl IdentityListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -2969,26 +5103,31 @@ impl IdentityListSerializer {
 }
 
 /// <p>Represents the custom MAIL FROM domain attributes of a verified identity (email address or domain).</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct IdentityMailFromDomainAttributes {
     /// <p>The action that Amazon SES takes if it cannot successfully read the required MX record when you send an email. A value of <code>UseDefaultValue</code> indicates that if Amazon SES cannot read the required MX record, it uses amazonses.com (or a subdomain of that) as the MAIL FROM domain. A value of <code>RejectMessage</code> indicates that if Amazon SES cannot read the required MX record, Amazon SES returns a <code>MailFromDomainNotVerified</code> error and does not send the email.</p> <p>The custom MAIL FROM setup states that result in this behavior are <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code>.</p>
-    pub behavior_on_mx_failure: String,
+    pub behavior_on_mx_failure: BehaviorOnMXFailure,
     /// <p>The custom MAIL FROM domain that the identity is configured to use.</p>
     pub mail_from_domain: String,
     /// <p>The state that indicates whether Amazon SES has successfully read the MX record required for custom MAIL FROM domain setup. If the state is <code>Success</code>, Amazon SES uses the specified custom MAIL FROM domain when the verified identity sends an email. All other states indicate that Amazon SES takes the action described by <code>BehaviorOnMXFailure</code>.</p>
-    pub mail_from_domain_status: String,
+    pub mail_from_domain_status: CustomMailFromStatus,
 }
 
-struct IdentityMailFromDomainAttributesDeserializer;
+pub(crate) struct IdentityMailFromDomainAttributesDeserializer;
 impl IdentityMailFromDomainAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IdentityMailFromDomainAttributes, XmlParseError> {
-        deserialize_elements::<_, IdentityMailFromDomainAttributes, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            IdentityMailFromDomainAttributes {
+                behavior_on_mx_failure: BehaviorOnMXFailure::Unknown(String::new()),
+                mail_from_domain: Default::default(),
+                mail_from_domain_status: CustomMailFromStatus::Unknown(String::new()),
+            },
             |name, stack, obj| {
                 match name {
                     "BehaviorOnMXFailure" => {
@@ -3034,10 +5173,10 @@ pub struct IdentityNotificationAttributes {
     pub headers_in_delivery_notifications_enabled: Option<bool>,
 }
 
-struct IdentityNotificationAttributesDeserializer;
+pub(crate) struct IdentityNotificationAttributesDeserializer;
 impl IdentityNotificationAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IdentityNotificationAttributes, XmlParseError> {
@@ -3091,24 +5230,28 @@ impl IdentityNotificationAttributesDeserializer {
     }
 }
 /// <p>Represents the verification attributes of a single identity.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct IdentityVerificationAttributes {
     /// <p>The verification status of the identity: "Pending", "Success", "Failed", or "TemporaryFailure".</p>
-    pub verification_status: String,
+    pub verification_status: VerificationStatus,
     /// <p>The verification token for a domain identity. Null for email address identities.</p>
     pub verification_token: Option<String>,
 }
 
-struct IdentityVerificationAttributesDeserializer;
+pub(crate) struct IdentityVerificationAttributesDeserializer;
 impl IdentityVerificationAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IdentityVerificationAttributes, XmlParseError> {
-        deserialize_elements::<_, IdentityVerificationAttributes, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            IdentityVerificationAttributes {
+                verification_status: VerificationStatus::Unknown(String::new()),
+                verification_token: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "VerificationStatus" => {
@@ -3130,12 +5273,15 @@ impl IdentityVerificationAttributesDeserializer {
         )
     }
 }
-struct InvocationTypeDeserializer;
+pub(crate) struct InvocationTypeDeserializer;
 impl InvocationTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<InvocationType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3150,10 +5296,10 @@ pub struct KinesisFirehoseDestination {
     pub iam_role_arn: String,
 }
 
-struct KinesisFirehoseDestinationDeserializer;
+pub(crate) struct KinesisFirehoseDestinationDeserializer;
 impl KinesisFirehoseDestinationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<KinesisFirehoseDestination, XmlParseError> {
@@ -3203,15 +5349,15 @@ pub struct LambdaAction {
     /// <p>The Amazon Resource Name (ARN) of the AWS Lambda function. An example of an AWS Lambda function ARN is <code>arn:aws:lambda:us-west-2:account-id:function:MyFunction</code>. For more information about AWS Lambda, see the <a href="https://docs.aws.amazon.com/lambda/latest/dg/welcome.html">AWS Lambda Developer Guide</a>.</p>
     pub function_arn: String,
     /// <p><p>The invocation type of the AWS Lambda function. An invocation type of <code>RequestResponse</code> means that the execution of the function will immediately result in a response, and a value of <code>Event</code> means that the function will be invoked asynchronously. The default value is <code>Event</code>. For information about AWS Lambda invocation types, see the <a href="https://docs.aws.amazon.com/lambda/latest/dg/API_Invoke.html">AWS Lambda Developer Guide</a>.</p> <important> <p>There is a 30-second timeout on <code>RequestResponse</code> invocations. You should use <code>Event</code> invocation in most cases. Use <code>RequestResponse</code> only when you want to make a mail flow decision, such as whether to stop the receipt rule or the receipt rule set.</p> </important></p>
-    pub invocation_type: Option<String>,
+    pub invocation_type: Option<InvocationType>,
     /// <p>The Amazon Resource Name (ARN) of the Amazon SNS topic to notify when the Lambda action is taken. An example of an Amazon SNS topic ARN is <code>arn:aws:sns:us-west-2:123456789012:MyTopic</code>. For more information about Amazon SNS topics, see the <a href="https://docs.aws.amazon.com/sns/latest/dg/CreateTopic.html">Amazon SNS Developer Guide</a>.</p>
     pub topic_arn: Option<String>,
 }
 
-struct LambdaActionDeserializer;
+pub(crate) struct LambdaActionDeserializer;
 impl LambdaActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LambdaAction, XmlParseError> {
@@ -3258,12 +5404,15 @@ impl LambdaActionSerializer {
     }
 }
 
-struct LastFreshStartDeserializer;
+pub(crate) struct LastFreshStartDeserializer;
 impl LastFreshStartDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3278,6 +5427,44 @@ pub struct ListConfigurationSetsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListConfigurationSetsRequest {
+    /// Returns a builder for `ListConfigurationSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListConfigurationSetsRequestBuilder {
+        ListConfigurationSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListConfigurationSetsRequest`](struct.ListConfigurationSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListConfigurationSetsRequestBuilder {
+    max_items: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListConfigurationSetsRequestBuilder {
+    /// Sets the [`max_items`](struct.ListConfigurationSetsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListConfigurationSetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListConfigurationSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListConfigurationSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListConfigurationSetsRequest {
+            max_items: self.max_items,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListConfigurationSetsRequest` contents to a `SignedRequest`.
 struct ListConfigurationSetsRequestSerializer;
 impl ListConfigurationSetsRequestSerializer {
@@ -3305,10 +5492,10 @@ pub struct ListConfigurationSetsResponse {
     pub next_token: Option<String>,
 }
 
-struct ListConfigurationSetsResponseDeserializer;
+pub(crate) struct ListConfigurationSetsResponseDeserializer;
 impl ListConfigurationSetsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListConfigurationSetsResponse, XmlParseError> {
@@ -3342,6 +5529,45 @@ pub struct ListCustomVerificationEmailTemplatesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListCustomVerificationEmailTemplatesRequest {
+    /// Returns a builder for `ListCustomVerificationEmailTemplatesRequest`, checking its required members when built.
+    pub fn builder() -> ListCustomVerificationEmailTemplatesRequestBuilder {
+        ListCustomVerificationEmailTemplatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCustomVerificationEmailTemplatesRequest`](struct.ListCustomVerificationEmailTemplatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCustomVerificationEmailTemplatesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListCustomVerificationEmailTemplatesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListCustomVerificationEmailTemplatesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListCustomVerificationEmailTemplatesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCustomVerificationEmailTemplatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCustomVerificationEmailTemplatesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListCustomVerificationEmailTemplatesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListCustomVerificationEmailTemplatesRequest` contents to a `SignedRequest`.
 struct ListCustomVerificationEmailTemplatesRequestSerializer;
 impl ListCustomVerificationEmailTemplatesRequestSerializer {
@@ -3373,10 +5599,10 @@ pub struct ListCustomVerificationEmailTemplatesResponse {
     pub next_token: Option<String>,
 }
 
-struct ListCustomVerificationEmailTemplatesResponseDeserializer;
+pub(crate) struct ListCustomVerificationEmailTemplatesResponseDeserializer;
 impl ListCustomVerificationEmailTemplatesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListCustomVerificationEmailTemplatesResponse, XmlParseError> {
@@ -3408,13 +5634,57 @@ impl ListCustomVerificationEmailTemplatesResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListIdentitiesRequest {
     /// <p>The type of the identities to list. Possible values are "EmailAddress" and "Domain". If this parameter is omitted, then all identities will be listed.</p>
-    pub identity_type: Option<String>,
+    pub identity_type: Option<IdentityType>,
     /// <p>The maximum number of identities per page. Possible values are 1-1000 inclusive.</p>
     pub max_items: Option<i64>,
     /// <p>The token to use for pagination.</p>
     pub next_token: Option<String>,
 }
 
+impl ListIdentitiesRequest {
+    /// Returns a builder for `ListIdentitiesRequest`, checking its required members when built.
+    pub fn builder() -> ListIdentitiesRequestBuilder {
+        ListIdentitiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListIdentitiesRequest`](struct.ListIdentitiesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListIdentitiesRequestBuilder {
+    identity_type: Option<IdentityType>,
+    max_items: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListIdentitiesRequestBuilder {
+    /// Sets the [`identity_type`](struct.ListIdentitiesRequest.html#structfield.identity_type) member.
+    pub fn identity_type<T: Into<IdentityType>>(mut self, value: T) -> Self {
+        self.identity_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListIdentitiesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListIdentitiesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIdentitiesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListIdentitiesRequest, ::rusoto_core::BuildError> {
+        Ok(ListIdentitiesRequest {
+            identity_type: self.identity_type,
+            max_items: self.max_items,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListIdentitiesRequest` contents to a `SignedRequest`.
 struct ListIdentitiesRequestSerializer;
 impl ListIdentitiesRequestSerializer {
@@ -3445,10 +5715,10 @@ pub struct ListIdentitiesResponse {
     pub next_token: Option<String>,
 }
 
-struct ListIdentitiesResponseDeserializer;
+pub(crate) struct ListIdentitiesResponseDeserializer;
 impl ListIdentitiesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListIdentitiesResponse, XmlParseError> {
@@ -3474,6 +5744,38 @@ pub struct ListIdentityPoliciesRequest {
     pub identity: String,
 }
 
+impl ListIdentityPoliciesRequest {
+    /// Returns a builder for `ListIdentityPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListIdentityPoliciesRequestBuilder {
+        ListIdentityPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListIdentityPoliciesRequest`](struct.ListIdentityPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListIdentityPoliciesRequestBuilder {
+    identity: Option<String>,
+}
+
+impl ListIdentityPoliciesRequestBuilder {
+    /// Sets the [`identity`](struct.ListIdentityPoliciesRequest.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIdentityPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListIdentityPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListIdentityPoliciesRequest {
+            identity: self
+                .identity
+                .ok_or(::rusoto_core::BuildError::missing("identity"))?,
+        })
+    }
+}
+
 /// Serialize `ListIdentityPoliciesRequest` contents to a `SignedRequest`.
 struct ListIdentityPoliciesRequestSerializer;
 impl ListIdentityPoliciesRequestSerializer {
@@ -3494,10 +5796,10 @@ pub struct ListIdentityPoliciesResponse {
     pub policy_names: Vec<String>,
 }
 
-struct ListIdentityPoliciesResponseDeserializer;
+pub(crate) struct ListIdentityPoliciesResponseDeserializer;
 impl ListIdentityPoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListIdentityPoliciesResponse, XmlParseError> {
@@ -3524,6 +5826,26 @@ impl ListIdentityPoliciesResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListReceiptFiltersRequest {}
 
+impl ListReceiptFiltersRequest {
+    /// Returns a builder for `ListReceiptFiltersRequest`, checking its required members when built.
+    pub fn builder() -> ListReceiptFiltersRequestBuilder {
+        ListReceiptFiltersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListReceiptFiltersRequest`](struct.ListReceiptFiltersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListReceiptFiltersRequestBuilder {}
+
+impl ListReceiptFiltersRequestBuilder {
+    /// Builds the `ListReceiptFiltersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListReceiptFiltersRequest, ::rusoto_core::BuildError> {
+        Ok(ListReceiptFiltersRequest {})
+    }
+}
+
 /// Serialize `ListReceiptFiltersRequest` contents to a `SignedRequest`.
 struct ListReceiptFiltersRequestSerializer;
 impl ListReceiptFiltersRequestSerializer {
@@ -3542,10 +5864,10 @@ pub struct ListReceiptFiltersResponse {
     pub filters: Option<Vec<ReceiptFilter>>,
 }
 
-struct ListReceiptFiltersResponseDeserializer;
+pub(crate) struct ListReceiptFiltersResponseDeserializer;
 impl ListReceiptFiltersResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListReceiptFiltersResponse, XmlParseError> {
@@ -3573,6 +5895,36 @@ pub struct ListReceiptRuleSetsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListReceiptRuleSetsRequest {
+    /// Returns a builder for `ListReceiptRuleSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListReceiptRuleSetsRequestBuilder {
+        ListReceiptRuleSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListReceiptRuleSetsRequest`](struct.ListReceiptRuleSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListReceiptRuleSetsRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl ListReceiptRuleSetsRequestBuilder {
+    /// Sets the [`next_token`](struct.ListReceiptRuleSetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListReceiptRuleSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListReceiptRuleSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListReceiptRuleSetsRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListReceiptRuleSetsRequest` contents to a `SignedRequest`.
 struct ListReceiptRuleSetsRequestSerializer;
 impl ListReceiptRuleSetsRequestSerializer {
@@ -3597,10 +5949,10 @@ pub struct ListReceiptRuleSetsResponse {
     pub rule_sets: Option<Vec<ReceiptRuleSetMetadata>>,
 }
 
-struct ListReceiptRuleSetsResponseDeserializer;
+pub(crate) struct ListReceiptRuleSetsResponseDeserializer;
 impl ListReceiptRuleSetsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListReceiptRuleSetsResponse, XmlParseError> {
@@ -3633,6 +5985,42 @@ pub struct ListTemplatesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListTemplatesRequest {
+    /// Returns a builder for `ListTemplatesRequest`, checking its required members when built.
+    pub fn builder() -> ListTemplatesRequestBuilder {
+        ListTemplatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTemplatesRequest`](struct.ListTemplatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTemplatesRequestBuilder {
+    max_items: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListTemplatesRequestBuilder {
+    /// Sets the [`max_items`](struct.ListTemplatesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTemplatesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTemplatesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTemplatesRequest, ::rusoto_core::BuildError> {
+        Ok(ListTemplatesRequest {
+            max_items: self.max_items,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListTemplatesRequest` contents to a `SignedRequest`.
 struct ListTemplatesRequestSerializer;
 impl ListTemplatesRequestSerializer {
@@ -3659,10 +6047,10 @@ pub struct ListTemplatesResponse {
     pub templates_metadata: Option<Vec<TemplateMetadata>>,
 }
 
-struct ListTemplatesResponseDeserializer;
+pub(crate) struct ListTemplatesResponseDeserializer;
 impl ListTemplatesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListTemplatesResponse, XmlParseError> {
@@ -3689,10 +6077,10 @@ pub struct ListVerifiedEmailAddressesResponse {
     pub verified_email_addresses: Option<Vec<String>>,
 }
 
-struct ListVerifiedEmailAddressesResponseDeserializer;
+pub(crate) struct ListVerifiedEmailAddressesResponseDeserializer;
 impl ListVerifiedEmailAddressesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListVerifiedEmailAddressesResponse, XmlParseError> {
@@ -3713,10 +6101,10 @@ impl ListVerifiedEmailAddressesResponseDeserializer {
         )
     }
 }
-struct MailFromDomainAttributesDeserializer;
+pub(crate) struct MailFromDomainAttributesDeserializer;
 impl MailFromDomainAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, IdentityMailFromDomainAttributes>, XmlParseError>
@@ -3737,10 +6125,13 @@ impl MailFromDomainAttributesDeserializer {
         Ok(obj)
     }
 }
-struct MailFromDomainNameDeserializer;
+pub(crate) struct MailFromDomainNameDeserializer;
 impl MailFromDomainNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3748,10 +6139,13 @@ impl MailFromDomainNameDeserializer {
         Ok(obj)
     }
 }
-struct Max24HourSendDeserializer;
+pub(crate) struct Max24HourSendDeserializer;
 impl Max24HourSendDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3759,10 +6153,13 @@ impl Max24HourSendDeserializer {
         Ok(obj)
     }
 }
-struct MaxSendRateDeserializer;
+pub(crate) struct MaxSendRateDeserializer;
 impl MaxSendRateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3797,7 +6194,7 @@ impl MessageSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct MessageDsn {
     /// <p>When the message was received by the reporting mail transfer agent (MTA), in <a href="https://www.ietf.org/rfc/rfc0822.txt">RFC 822</a> date-time format.</p>
-    pub arrival_date: Option<String>,
+    pub arrival_date: Option<IsoTimestamp>,
     /// <p>Additional X-headers to include in the DSN.</p>
     pub extension_fields: Option<Vec<ExtensionField>>,
     /// <p>The reporting MTA that attempted to deliver the message, formatted as specified in <a href="https://tools.ietf.org/html/rfc3464">RFC 3464</a> (<code>mta-name-type; mta-name</code>). The default value is <code>dns; inbound-smtp.[region].amazonaws.com</code>.</p>
@@ -3827,10 +6224,13 @@ impl MessageDsnSerializer {
     }
 }
 
-struct MessageIdDeserializer;
+pub(crate) struct MessageIdDeserializer;
 impl MessageIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3872,10 +6272,13 @@ impl MessageTagListSerializer {
     }
 }
 
-struct NextTokenDeserializer;
+pub(crate) struct NextTokenDeserializer;
 impl NextTokenDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3883,10 +6286,10 @@ impl NextTokenDeserializer {
         Ok(obj)
     }
 }
-struct NotificationAttributesDeserializer;
+pub(crate) struct NotificationAttributesDeserializer;
 impl NotificationAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, IdentityNotificationAttributes>, XmlParseError>
@@ -3907,10 +6310,13 @@ impl NotificationAttributesDeserializer {
         Ok(obj)
     }
 }
-struct NotificationTopicDeserializer;
+pub(crate) struct NotificationTopicDeserializer;
 impl NotificationTopicDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3918,10 +6324,13 @@ impl NotificationTopicDeserializer {
         Ok(obj)
     }
 }
-struct PolicyDeserializer;
+pub(crate) struct PolicyDeserializer;
 impl PolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3929,10 +6338,10 @@ impl PolicyDeserializer {
         Ok(obj)
     }
 }
-struct PolicyMapDeserializer;
+pub(crate) struct PolicyMapDeserializer;
 impl PolicyMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
@@ -3952,10 +6361,13 @@ impl PolicyMapDeserializer {
         Ok(obj)
     }
 }
-struct PolicyNameDeserializer;
+pub(crate) struct PolicyNameDeserializer;
 impl PolicyNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3963,10 +6375,10 @@ impl PolicyNameDeserializer {
         Ok(obj)
     }
 }
-struct PolicyNameListDeserializer;
+pub(crate) struct PolicyNameListDeserializer;
 impl PolicyNameListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -4001,6 +6413,47 @@ pub struct PutConfigurationSetDeliveryOptionsRequest {
     pub delivery_options: Option<DeliveryOptions>,
 }
 
+impl PutConfigurationSetDeliveryOptionsRequest {
+    /// Returns a builder for `PutConfigurationSetDeliveryOptionsRequest`, checking its required members when built.
+    pub fn builder() -> PutConfigurationSetDeliveryOptionsRequestBuilder {
+        PutConfigurationSetDeliveryOptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutConfigurationSetDeliveryOptionsRequest`](struct.PutConfigurationSetDeliveryOptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutConfigurationSetDeliveryOptionsRequestBuilder {
+    configuration_set_name: Option<String>,
+    delivery_options: Option<DeliveryOptions>,
+}
+
+impl PutConfigurationSetDeliveryOptionsRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.PutConfigurationSetDeliveryOptionsRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delivery_options`](struct.PutConfigurationSetDeliveryOptionsRequest.html#structfield.delivery_options) member.
+    pub fn delivery_options<T: Into<DeliveryOptions>>(mut self, value: T) -> Self {
+        self.delivery_options = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutConfigurationSetDeliveryOptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutConfigurationSetDeliveryOptionsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(PutConfigurationSetDeliveryOptionsRequest {
+            configuration_set_name: self
+                .configuration_set_name
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set_name"))?,
+            delivery_options: self.delivery_options,
+        })
+    }
+}
+
 /// Serialize `PutConfigurationSetDeliveryOptionsRequest` contents to a `SignedRequest`.
 struct PutConfigurationSetDeliveryOptionsRequestSerializer;
 impl PutConfigurationSetDeliveryOptionsRequestSerializer {
@@ -4028,10 +6481,10 @@ impl PutConfigurationSetDeliveryOptionsRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PutConfigurationSetDeliveryOptionsResponse {}
 
-struct PutConfigurationSetDeliveryOptionsResponseDeserializer;
+pub(crate) struct PutConfigurationSetDeliveryOptionsResponseDeserializer;
 impl PutConfigurationSetDeliveryOptionsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PutConfigurationSetDeliveryOptionsResponse, XmlParseError> {
@@ -4055,6 +6508,58 @@ pub struct PutIdentityPolicyRequest {
     pub policy_name: String,
 }
 
+impl PutIdentityPolicyRequest {
+    /// Returns a builder for `PutIdentityPolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutIdentityPolicyRequestBuilder {
+        PutIdentityPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutIdentityPolicyRequest`](struct.PutIdentityPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutIdentityPolicyRequestBuilder {
+    identity: Option<String>,
+    policy: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl PutIdentityPolicyRequestBuilder {
+    /// Sets the [`identity`](struct.PutIdentityPolicyRequest.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.PutIdentityPolicyRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.PutIdentityPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutIdentityPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutIdentityPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutIdentityPolicyRequest {
+            identity: self
+                .identity
+                .ok_or(::rusoto_core::BuildError::missing("identity"))?,
+            policy: self
+                .policy
+                .ok_or(::rusoto_core::BuildError::missing("policy"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// Serialize `PutIdentityPolicyRequest` contents to a `SignedRequest`.
 struct PutIdentityPolicyRequestSerializer;
 impl PutIdentityPolicyRequestSerializer {
@@ -4074,10 +6579,10 @@ impl PutIdentityPolicyRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PutIdentityPolicyResponse {}
 
-struct PutIdentityPolicyResponseDeserializer;
+pub(crate) struct PutIdentityPolicyResponseDeserializer;
 impl PutIdentityPolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PutIdentityPolicyResponse, XmlParseError> {
@@ -4132,10 +6637,10 @@ pub struct ReceiptAction {
     pub workmail_action: Option<WorkmailAction>,
 }
 
-struct ReceiptActionDeserializer;
+pub(crate) struct ReceiptActionDeserializer;
 impl ReceiptActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReceiptAction, XmlParseError> {
@@ -4243,10 +6748,10 @@ impl ReceiptActionSerializer {
     }
 }
 
-struct ReceiptActionsListDeserializer;
+pub(crate) struct ReceiptActionsListDeserializer;
 impl ReceiptActionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ReceiptAction>, XmlParseError> {
@@ -4273,7 +6778,7 @@ impl ReceiptActionsListSerializer {
 }
 
 /// <p>A receipt IP address filter enables you to specify whether to accept or reject mail originating from an IP address or range of IP addresses.</p> <p>For information about setting up IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-ip-filters.html">Amazon SES Developer Guide</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ReceiptFilter {
     /// <p>A structure that provides the IP addresses to block or allow, and whether to block or allow incoming mail from them.</p>
     pub ip_filter: ReceiptIpFilter,
@@ -4281,25 +6786,37 @@ pub struct ReceiptFilter {
     pub name: String,
 }
 
-struct ReceiptFilterDeserializer;
+pub(crate) struct ReceiptFilterDeserializer;
 impl ReceiptFilterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReceiptFilter, XmlParseError> {
-        deserialize_elements::<_, ReceiptFilter, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "IpFilter" => {
-                    obj.ip_filter = ReceiptIpFilterDeserializer::deserialize("IpFilter", stack)?;
-                }
-                "Name" => {
-                    obj.name = ReceiptFilterNameDeserializer::deserialize("Name", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            ReceiptFilter {
+                ip_filter: ReceiptIpFilter {
+                    cidr: Default::default(),
+                    policy: ReceiptFilterPolicy::Unknown(String::new()),
+                },
+                name: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "IpFilter" => {
+                        obj.ip_filter =
+                            ReceiptIpFilterDeserializer::deserialize("IpFilter", stack)?;
+                    }
+                    "Name" => {
+                        obj.name = ReceiptFilterNameDeserializer::deserialize("Name", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -4321,10 +6838,10 @@ impl ReceiptFilterSerializer {
     }
 }
 
-struct ReceiptFilterListDeserializer;
+pub(crate) struct ReceiptFilterListDeserializer;
 impl ReceiptFilterListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ReceiptFilter>, XmlParseError> {
@@ -4338,10 +6855,13 @@ impl ReceiptFilterListDeserializer {
         })
     }
 }
-struct ReceiptFilterNameDeserializer;
+pub(crate) struct ReceiptFilterNameDeserializer;
 impl ReceiptFilterNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4349,45 +6869,56 @@ impl ReceiptFilterNameDeserializer {
         Ok(obj)
     }
 }
-struct ReceiptFilterPolicyDeserializer;
+pub(crate) struct ReceiptFilterPolicyDeserializer;
 impl ReceiptFilterPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReceiptFilterPolicy, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 /// <p>A receipt IP address filter enables you to specify whether to accept or reject mail originating from an IP address or range of IP addresses.</p> <p>For information about setting up IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-ip-filters.html">Amazon SES Developer Guide</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ReceiptIpFilter {
     /// <p>A single IP address or a range of IP addresses that you want to block or allow, specified in Classless Inter-Domain Routing (CIDR) notation. An example of a single email address is 10.0.0.1. An example of a range of IP addresses is 10.0.0.1/24. For more information about CIDR notation, see <a href="https://tools.ietf.org/html/rfc2317">RFC 2317</a>.</p>
     pub cidr: String,
     /// <p>Indicates whether to block or allow incoming mail from the specified IP addresses.</p>
-    pub policy: String,
+    pub policy: ReceiptFilterPolicy,
 }
 
-struct ReceiptIpFilterDeserializer;
+pub(crate) struct ReceiptIpFilterDeserializer;
 impl ReceiptIpFilterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReceiptIpFilter, XmlParseError> {
-        deserialize_elements::<_, ReceiptIpFilter, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Cidr" => {
-                    obj.cidr = CidrDeserializer::deserialize("Cidr", stack)?;
-                }
-                "Policy" => {
-                    obj.policy = ReceiptFilterPolicyDeserializer::deserialize("Policy", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            ReceiptIpFilter {
+                cidr: Default::default(),
+                policy: ReceiptFilterPolicy::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Cidr" => {
+                        obj.cidr = CidrDeserializer::deserialize("Cidr", stack)?;
+                    }
+                    "Policy" => {
+                        obj.policy = ReceiptFilterPolicyDeserializer::deserialize("Policy", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -4419,13 +6950,13 @@ pub struct ReceiptRule {
     /// <p>If <code>true</code>, then messages that this receipt rule applies to are scanned for spam and viruses. The default value is <code>false</code>.</p>
     pub scan_enabled: Option<bool>,
     /// <p>Specifies whether Amazon SES should require that incoming email is delivered over a connection encrypted with Transport Layer Security (TLS). If this parameter is set to <code>Require</code>, Amazon SES will bounce emails that are not received over TLS. The default is <code>Optional</code>.</p>
-    pub tls_policy: Option<String>,
+    pub tls_policy: Option<TlsPolicy>,
 }
 
-struct ReceiptRuleDeserializer;
+pub(crate) struct ReceiptRuleDeserializer;
 impl ReceiptRuleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReceiptRule, XmlParseError> {
@@ -4497,10 +7028,13 @@ impl ReceiptRuleSerializer {
     }
 }
 
-struct ReceiptRuleNameDeserializer;
+pub(crate) struct ReceiptRuleNameDeserializer;
 impl ReceiptRuleNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4524,15 +7058,15 @@ impl ReceiptRuleNamesListSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ReceiptRuleSetMetadata {
     /// <p>The date and time the receipt rule set was created.</p>
-    pub created_timestamp: Option<String>,
+    pub created_timestamp: Option<IsoTimestamp>,
     /// <p><p>The name of the receipt rule set. The name must:</p> <ul> <li> <p>This value can only contain ASCII letters (a-z, A-Z), numbers (0-9), underscores (_), or dashes (-).</p> </li> <li> <p>Start and end with a letter or number.</p> </li> <li> <p>Contain less than 64 characters.</p> </li> </ul></p>
     pub name: Option<String>,
 }
 
-struct ReceiptRuleSetMetadataDeserializer;
+pub(crate) struct ReceiptRuleSetMetadataDeserializer;
 impl ReceiptRuleSetMetadataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReceiptRuleSetMetadata, XmlParseError> {
@@ -4553,10 +7087,13 @@ impl ReceiptRuleSetMetadataDeserializer {
         })
     }
 }
-struct ReceiptRuleSetNameDeserializer;
+pub(crate) struct ReceiptRuleSetNameDeserializer;
 impl ReceiptRuleSetNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4564,10 +7101,10 @@ impl ReceiptRuleSetNameDeserializer {
         Ok(obj)
     }
 }
-struct ReceiptRuleSetsListsDeserializer;
+pub(crate) struct ReceiptRuleSetsListsDeserializer;
 impl ReceiptRuleSetsListsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ReceiptRuleSetMetadata>, XmlParseError> {
@@ -4583,10 +7120,10 @@ impl ReceiptRuleSetsListsDeserializer {
         })
     }
 }
-struct ReceiptRulesListDeserializer;
+pub(crate) struct ReceiptRulesListDeserializer;
 impl ReceiptRulesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ReceiptRule>, XmlParseError> {
@@ -4600,10 +7137,13 @@ impl ReceiptRulesListDeserializer {
         })
     }
 }
-struct RecipientDeserializer;
+pub(crate) struct RecipientDeserializer;
 impl RecipientDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4612,10 +7152,10 @@ impl RecipientDeserializer {
     }
 }
 /// <p>Recipient-related information to include in the Delivery Status Notification (DSN) when an email that Amazon SES receives on your behalf bounces.</p> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RecipientDsnFields {
     /// <p>The action performed by the reporting mail transfer agent (MTA) as a result of its attempt to deliver the message to the recipient address. This is required by <a href="https://tools.ietf.org/html/rfc3464">RFC 3464</a>.</p>
-    pub action: String,
+    pub action: DsnAction,
     /// <p>An extended explanation of what went wrong; this is usually an SMTP response. See <a href="https://tools.ietf.org/html/rfc3463">RFC 3463</a> for the correct formatting of this parameter.</p>
     pub diagnostic_code: Option<String>,
     /// <p>Additional X-headers to include in the DSN.</p>
@@ -4623,7 +7163,7 @@ pub struct RecipientDsnFields {
     /// <p><p>The email address that the message was ultimately delivered to. This corresponds to the <code>Final-Recipient</code> in the DSN. If not specified, <code>FinalRecipient</code> will be set to the <code>Recipient</code> specified in the <code>BouncedRecipientInfo</code> structure. Either <code>FinalRecipient</code> or the recipient in <code>BouncedRecipientInfo</code> must be a recipient of the original bounced message.</p> <note> <p>Do not prepend the <code>FinalRecipient</code> email address with <code>rfc 822;</code>, as described in <a href="https://tools.ietf.org/html/rfc3798">RFC 3798</a>.</p> </note></p>
     pub final_recipient: Option<String>,
     /// <p>The time the final delivery attempt was made, in <a href="https://www.ietf.org/rfc/rfc0822.txt">RFC 822</a> date-time format.</p>
-    pub last_attempt_date: Option<String>,
+    pub last_attempt_date: Option<IsoTimestamp>,
     /// <p>The MTA to which the remote MTA attempted to deliver the message, formatted as specified in <a href="https://tools.ietf.org/html/rfc3464">RFC 3464</a> (<code>mta-name-type; mta-name</code>). This parameter typically applies only to propagating synchronous bounces.</p>
     pub remote_mta: Option<String>,
     /// <p>The status code that indicates what went wrong. This is required by <a href="https://tools.ietf.org/html/rfc3464">RFC 3464</a>.</p>
@@ -4663,10 +7203,10 @@ impl RecipientDsnFieldsSerializer {
     }
 }
 
-struct RecipientsListDeserializer;
+pub(crate) struct RecipientsListDeserializer;
 impl RecipientsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -4692,10 +7232,13 @@ impl RecipientsListSerializer {
     }
 }
 
-struct RenderedTemplateDeserializer;
+pub(crate) struct RenderedTemplateDeserializer;
 impl RenderedTemplateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4712,13 +7255,55 @@ pub struct ReorderReceiptRuleSetRequest {
     pub rule_set_name: String,
 }
 
-/// Serialize `ReorderReceiptRuleSetRequest` contents to a `SignedRequest`.
-struct ReorderReceiptRuleSetRequestSerializer;
-impl ReorderReceiptRuleSetRequestSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &ReorderReceiptRuleSetRequest) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
+impl ReorderReceiptRuleSetRequest {
+    /// Returns a builder for `ReorderReceiptRuleSetRequest`, checking its required members when built.
+    pub fn builder() -> ReorderReceiptRuleSetRequestBuilder {
+        ReorderReceiptRuleSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ReorderReceiptRuleSetRequest`](struct.ReorderReceiptRuleSetRequest.html).
+#[derive(Default, Debug)]
+pub struct ReorderReceiptRuleSetRequestBuilder {
+    rule_names: Option<Vec<String>>,
+    rule_set_name: Option<String>,
+}
+
+impl ReorderReceiptRuleSetRequestBuilder {
+    /// Sets the [`rule_names`](struct.ReorderReceiptRuleSetRequest.html#structfield.rule_names) member.
+    pub fn rule_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.rule_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_set_name`](struct.ReorderReceiptRuleSetRequest.html#structfield.rule_set_name) member.
+    pub fn rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReorderReceiptRuleSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ReorderReceiptRuleSetRequest, ::rusoto_core::BuildError> {
+        Ok(ReorderReceiptRuleSetRequest {
+            rule_names: self
+                .rule_names
+                .ok_or(::rusoto_core::BuildError::missing("rule_names"))?,
+            rule_set_name: self
+                .rule_set_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_set_name"))?,
+        })
+    }
+}
+
+/// Serialize `ReorderReceiptRuleSetRequest` contents to a `SignedRequest`.
+struct ReorderReceiptRuleSetRequestSerializer;
+impl ReorderReceiptRuleSetRequestSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &ReorderReceiptRuleSetRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
         }
 
         ReceiptRuleNamesListSerializer::serialize(
@@ -4734,10 +7319,10 @@ impl ReorderReceiptRuleSetRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ReorderReceiptRuleSetResponse {}
 
-struct ReorderReceiptRuleSetResponseDeserializer;
+pub(crate) struct ReorderReceiptRuleSetResponseDeserializer;
 impl ReorderReceiptRuleSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReorderReceiptRuleSetResponse, XmlParseError> {
@@ -4754,17 +7339,17 @@ impl ReorderReceiptRuleSetResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ReputationOptions {
     /// <p>The date and time at which the reputation metrics for the configuration set were last reset. Resetting these metrics is known as a <i>fresh start</i>.</p> <p>When you disable email sending for a configuration set using <a>UpdateConfigurationSetSendingEnabled</a> and later re-enable it, the reputation metrics for the configuration set (but not for the entire Amazon SES account) are reset.</p> <p>If email sending for the configuration set has never been disabled and later re-enabled, the value of this attribute is <code>null</code>.</p>
-    pub last_fresh_start: Option<String>,
+    pub last_fresh_start: Option<IsoTimestamp>,
     /// <p>Describes whether or not Amazon SES publishes reputation metrics for the configuration set, such as bounce and complaint rates, to Amazon CloudWatch.</p> <p>If the value is <code>true</code>, reputation metrics are published. If the value is <code>false</code>, reputation metrics are not published. The default value is <code>false</code>.</p>
     pub reputation_metrics_enabled: Option<bool>,
     /// <p>Describes whether email sending is enabled or disabled for the configuration set. If the value is <code>true</code>, then Amazon SES will send emails that use the configuration set. If the value is <code>false</code>, Amazon SES will not send emails that use the configuration set. The default value is <code>true</code>. You can change this setting using <a>UpdateConfigurationSetSendingEnabled</a>.</p>
     pub sending_enabled: Option<bool>,
 }
 
-struct ReputationOptionsDeserializer;
+pub(crate) struct ReputationOptionsDeserializer;
 impl ReputationOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReputationOptions, XmlParseError> {
@@ -4805,10 +7390,10 @@ pub struct S3Action {
     pub topic_arn: Option<String>,
 }
 
-struct S3ActionDeserializer;
+pub(crate) struct S3ActionDeserializer;
 impl S3ActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<S3Action, XmlParseError> {
@@ -4863,10 +7448,13 @@ impl S3ActionSerializer {
     }
 }
 
-struct S3BucketNameDeserializer;
+pub(crate) struct S3BucketNameDeserializer;
 impl S3BucketNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4874,10 +7462,13 @@ impl S3BucketNameDeserializer {
         Ok(obj)
     }
 }
-struct S3KeyPrefixDeserializer;
+pub(crate) struct S3KeyPrefixDeserializer;
 impl S3KeyPrefixDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4889,15 +7480,15 @@ impl S3KeyPrefixDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SNSAction {
     /// <p>The encoding to use for the email within the Amazon SNS notification. UTF-8 is easier to use, but may not preserve all special characters when a message was encoded with a different encoding format. Base64 preserves all special characters. The default value is UTF-8.</p>
-    pub encoding: Option<String>,
+    pub encoding: Option<SNSActionEncoding>,
     /// <p>The Amazon Resource Name (ARN) of the Amazon SNS topic to notify. An example of an Amazon SNS topic ARN is <code>arn:aws:sns:us-west-2:123456789012:MyTopic</code>. For more information about Amazon SNS topics, see the <a href="https://docs.aws.amazon.com/sns/latest/dg/CreateTopic.html">Amazon SNS Developer Guide</a>.</p>
     pub topic_arn: String,
 }
 
-struct SNSActionDeserializer;
+pub(crate) struct SNSActionDeserializer;
 impl SNSActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SNSAction, XmlParseError> {
@@ -4934,12 +7525,15 @@ impl SNSActionSerializer {
     }
 }
 
-struct SNSActionEncodingDeserializer;
+pub(crate) struct SNSActionEncodingDeserializer;
 impl SNSActionEncodingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SNSActionEncoding, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4952,10 +7546,10 @@ pub struct SNSDestination {
     pub topic_arn: String,
 }
 
-struct SNSDestinationDeserializer;
+pub(crate) struct SNSDestinationDeserializer;
 impl SNSDestinationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SNSDestination, XmlParseError> {
@@ -5001,6 +7595,83 @@ pub struct SendBounceRequest {
     pub original_message_id: String,
 }
 
+impl SendBounceRequest {
+    /// Returns a builder for `SendBounceRequest`, checking its required members when built.
+    pub fn builder() -> SendBounceRequestBuilder {
+        SendBounceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendBounceRequest`](struct.SendBounceRequest.html).
+#[derive(Default, Debug)]
+pub struct SendBounceRequestBuilder {
+    bounce_sender: Option<String>,
+    bounce_sender_arn: Option<String>,
+    bounced_recipient_info_list: Option<Vec<BouncedRecipientInfo>>,
+    explanation: Option<String>,
+    message_dsn: Option<MessageDsn>,
+    original_message_id: Option<String>,
+}
+
+impl SendBounceRequestBuilder {
+    /// Sets the [`bounce_sender`](struct.SendBounceRequest.html#structfield.bounce_sender) member.
+    pub fn bounce_sender<T: Into<String>>(mut self, value: T) -> Self {
+        self.bounce_sender = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bounce_sender_arn`](struct.SendBounceRequest.html#structfield.bounce_sender_arn) member.
+    pub fn bounce_sender_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.bounce_sender_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bounced_recipient_info_list`](struct.SendBounceRequest.html#structfield.bounced_recipient_info_list) member.
+    pub fn bounced_recipient_info_list<T: Into<Vec<BouncedRecipientInfo>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.bounced_recipient_info_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`explanation`](struct.SendBounceRequest.html#structfield.explanation) member.
+    pub fn explanation<T: Into<String>>(mut self, value: T) -> Self {
+        self.explanation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message_dsn`](struct.SendBounceRequest.html#structfield.message_dsn) member.
+    pub fn message_dsn<T: Into<MessageDsn>>(mut self, value: T) -> Self {
+        self.message_dsn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`original_message_id`](struct.SendBounceRequest.html#structfield.original_message_id) member.
+    pub fn original_message_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.original_message_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendBounceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SendBounceRequest, ::rusoto_core::BuildError> {
+        Ok(SendBounceRequest {
+            bounce_sender: self
+                .bounce_sender
+                .ok_or(::rusoto_core::BuildError::missing("bounce_sender"))?,
+            bounce_sender_arn: self.bounce_sender_arn,
+            bounced_recipient_info_list: self.bounced_recipient_info_list.ok_or(
+                ::rusoto_core::BuildError::missing("bounced_recipient_info_list"),
+            )?,
+            explanation: self.explanation,
+            message_dsn: self.message_dsn,
+            original_message_id: self
+                .original_message_id
+                .ok_or(::rusoto_core::BuildError::missing("original_message_id"))?,
+        })
+    }
+}
+
 /// Serialize `SendBounceRequest` contents to a `SignedRequest`.
 struct SendBounceRequestSerializer;
 impl SendBounceRequestSerializer {
@@ -5043,10 +7714,10 @@ pub struct SendBounceResponse {
     pub message_id: Option<String>,
 }
 
-struct SendBounceResponseDeserializer;
+pub(crate) struct SendBounceResponseDeserializer;
 impl SendBounceResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SendBounceResponse, XmlParseError> {
@@ -5088,6 +7759,122 @@ pub struct SendBulkTemplatedEmailRequest {
     pub template_arn: Option<String>,
 }
 
+impl SendBulkTemplatedEmailRequest {
+    /// Returns a builder for `SendBulkTemplatedEmailRequest`, checking its required members when built.
+    pub fn builder() -> SendBulkTemplatedEmailRequestBuilder {
+        SendBulkTemplatedEmailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendBulkTemplatedEmailRequest`](struct.SendBulkTemplatedEmailRequest.html).
+#[derive(Default, Debug)]
+pub struct SendBulkTemplatedEmailRequestBuilder {
+    configuration_set_name: Option<String>,
+    default_tags: Option<Vec<MessageTag>>,
+    default_template_data: Option<String>,
+    destinations: Option<Vec<BulkEmailDestination>>,
+    reply_to_addresses: Option<Vec<String>>,
+    return_path: Option<String>,
+    return_path_arn: Option<String>,
+    source: Option<String>,
+    source_arn: Option<String>,
+    template: Option<String>,
+    template_arn: Option<String>,
+}
+
+impl SendBulkTemplatedEmailRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.SendBulkTemplatedEmailRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_tags`](struct.SendBulkTemplatedEmailRequest.html#structfield.default_tags) member.
+    pub fn default_tags<T: Into<Vec<MessageTag>>>(mut self, value: T) -> Self {
+        self.default_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_template_data`](struct.SendBulkTemplatedEmailRequest.html#structfield.default_template_data) member.
+    pub fn default_template_data<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_template_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destinations`](struct.SendBulkTemplatedEmailRequest.html#structfield.destinations) member.
+    pub fn destinations<T: Into<Vec<BulkEmailDestination>>>(mut self, value: T) -> Self {
+        self.destinations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reply_to_addresses`](struct.SendBulkTemplatedEmailRequest.html#structfield.reply_to_addresses) member.
+    pub fn reply_to_addresses<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.reply_to_addresses = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_path`](struct.SendBulkTemplatedEmailRequest.html#structfield.return_path) member.
+    pub fn return_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.return_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_path_arn`](struct.SendBulkTemplatedEmailRequest.html#structfield.return_path_arn) member.
+    pub fn return_path_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.return_path_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.SendBulkTemplatedEmailRequest.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_arn`](struct.SendBulkTemplatedEmailRequest.html#structfield.source_arn) member.
+    pub fn source_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template`](struct.SendBulkTemplatedEmailRequest.html#structfield.template) member.
+    pub fn template<T: Into<String>>(mut self, value: T) -> Self {
+        self.template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_arn`](struct.SendBulkTemplatedEmailRequest.html#structfield.template_arn) member.
+    pub fn template_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendBulkTemplatedEmailRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SendBulkTemplatedEmailRequest, ::rusoto_core::BuildError> {
+        Ok(SendBulkTemplatedEmailRequest {
+            configuration_set_name: self.configuration_set_name,
+            default_tags: self.default_tags,
+            default_template_data: self.default_template_data,
+            destinations: self
+                .destinations
+                .ok_or(::rusoto_core::BuildError::missing("destinations"))?,
+            reply_to_addresses: self.reply_to_addresses,
+            return_path: self.return_path,
+            return_path_arn: self.return_path_arn,
+            source: self
+                .source
+                .ok_or(::rusoto_core::BuildError::missing("source"))?,
+            source_arn: self.source_arn,
+            template: self
+                .template
+                .ok_or(::rusoto_core::BuildError::missing("template"))?,
+            template_arn: self.template_arn,
+        })
+    }
+}
+
 /// Serialize `SendBulkTemplatedEmailRequest` contents to a `SignedRequest`.
 struct SendBulkTemplatedEmailRequestSerializer;
 impl SendBulkTemplatedEmailRequestSerializer {
@@ -5151,10 +7938,10 @@ pub struct SendBulkTemplatedEmailResponse {
     pub status: Vec<BulkEmailDestinationStatus>,
 }
 
-struct SendBulkTemplatedEmailResponseDeserializer;
+pub(crate) struct SendBulkTemplatedEmailResponseDeserializer;
 impl SendBulkTemplatedEmailResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SendBulkTemplatedEmailResponse, XmlParseError> {
@@ -5187,6 +7974,56 @@ pub struct SendCustomVerificationEmailRequest {
     pub template_name: String,
 }
 
+impl SendCustomVerificationEmailRequest {
+    /// Returns a builder for `SendCustomVerificationEmailRequest`, checking its required members when built.
+    pub fn builder() -> SendCustomVerificationEmailRequestBuilder {
+        SendCustomVerificationEmailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendCustomVerificationEmailRequest`](struct.SendCustomVerificationEmailRequest.html).
+#[derive(Default, Debug)]
+pub struct SendCustomVerificationEmailRequestBuilder {
+    configuration_set_name: Option<String>,
+    email_address: Option<String>,
+    template_name: Option<String>,
+}
+
+impl SendCustomVerificationEmailRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.SendCustomVerificationEmailRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email_address`](struct.SendCustomVerificationEmailRequest.html#structfield.email_address) member.
+    pub fn email_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.SendCustomVerificationEmailRequest.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendCustomVerificationEmailRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SendCustomVerificationEmailRequest, ::rusoto_core::BuildError> {
+        Ok(SendCustomVerificationEmailRequest {
+            configuration_set_name: self.configuration_set_name,
+            email_address: self
+                .email_address
+                .ok_or(::rusoto_core::BuildError::missing("email_address"))?,
+            template_name: self
+                .template_name
+                .ok_or(::rusoto_core::BuildError::missing("template_name"))?,
+        })
+    }
+}
+
 /// Serialize `SendCustomVerificationEmailRequest` contents to a `SignedRequest`.
 struct SendCustomVerificationEmailRequestSerializer;
 impl SendCustomVerificationEmailRequestSerializer {
@@ -5214,10 +8051,10 @@ pub struct SendCustomVerificationEmailResponse {
     pub message_id: Option<String>,
 }
 
-struct SendCustomVerificationEmailResponseDeserializer;
+pub(crate) struct SendCustomVerificationEmailResponseDeserializer;
 impl SendCustomVerificationEmailResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SendCustomVerificationEmailResponse, XmlParseError> {
@@ -5249,13 +8086,13 @@ pub struct SendDataPoint {
     /// <p>Number of emails rejected by Amazon SES.</p>
     pub rejects: Option<i64>,
     /// <p>Time of the data point.</p>
-    pub timestamp: Option<String>,
+    pub timestamp: Option<IsoTimestamp>,
 }
 
-struct SendDataPointDeserializer;
+pub(crate) struct SendDataPointDeserializer;
 impl SendDataPointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SendDataPoint, XmlParseError> {
@@ -5283,10 +8120,10 @@ impl SendDataPointDeserializer {
         })
     }
 }
-struct SendDataPointListDeserializer;
+pub(crate) struct SendDataPointListDeserializer;
 impl SendDataPointListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SendDataPoint>, XmlParseError> {
@@ -5323,6 +8160,104 @@ pub struct SendEmailRequest {
     pub tags: Option<Vec<MessageTag>>,
 }
 
+impl SendEmailRequest {
+    /// Returns a builder for `SendEmailRequest`, checking its required members when built.
+    pub fn builder() -> SendEmailRequestBuilder {
+        SendEmailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendEmailRequest`](struct.SendEmailRequest.html).
+#[derive(Default, Debug)]
+pub struct SendEmailRequestBuilder {
+    configuration_set_name: Option<String>,
+    destination: Option<Destination>,
+    message: Option<Message>,
+    reply_to_addresses: Option<Vec<String>>,
+    return_path: Option<String>,
+    return_path_arn: Option<String>,
+    source: Option<String>,
+    source_arn: Option<String>,
+    tags: Option<Vec<MessageTag>>,
+}
+
+impl SendEmailRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.SendEmailRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination`](struct.SendEmailRequest.html#structfield.destination) member.
+    pub fn destination<T: Into<Destination>>(mut self, value: T) -> Self {
+        self.destination = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message`](struct.SendEmailRequest.html#structfield.message) member.
+    pub fn message<T: Into<Message>>(mut self, value: T) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reply_to_addresses`](struct.SendEmailRequest.html#structfield.reply_to_addresses) member.
+    pub fn reply_to_addresses<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.reply_to_addresses = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_path`](struct.SendEmailRequest.html#structfield.return_path) member.
+    pub fn return_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.return_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_path_arn`](struct.SendEmailRequest.html#structfield.return_path_arn) member.
+    pub fn return_path_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.return_path_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.SendEmailRequest.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_arn`](struct.SendEmailRequest.html#structfield.source_arn) member.
+    pub fn source_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.SendEmailRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<MessageTag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendEmailRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SendEmailRequest, ::rusoto_core::BuildError> {
+        Ok(SendEmailRequest {
+            configuration_set_name: self.configuration_set_name,
+            destination: self
+                .destination
+                .ok_or(::rusoto_core::BuildError::missing("destination"))?,
+            message: self
+                .message
+                .ok_or(::rusoto_core::BuildError::missing("message"))?,
+            reply_to_addresses: self.reply_to_addresses,
+            return_path: self.return_path,
+            return_path_arn: self.return_path_arn,
+            source: self
+                .source
+                .ok_or(::rusoto_core::BuildError::missing("source"))?,
+            source_arn: self.source_arn,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `SendEmailRequest` contents to a `SignedRequest`.
 struct SendEmailRequestSerializer;
 impl SendEmailRequestSerializer {
@@ -5378,10 +8313,10 @@ pub struct SendEmailResponse {
     pub message_id: String,
 }
 
-struct SendEmailResponseDeserializer;
+pub(crate) struct SendEmailResponseDeserializer;
 impl SendEmailResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SendEmailResponse, XmlParseError> {
@@ -5417,6 +8352,92 @@ pub struct SendRawEmailRequest {
     pub tags: Option<Vec<MessageTag>>,
 }
 
+impl SendRawEmailRequest {
+    /// Returns a builder for `SendRawEmailRequest`, checking its required members when built.
+    pub fn builder() -> SendRawEmailRequestBuilder {
+        SendRawEmailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendRawEmailRequest`](struct.SendRawEmailRequest.html).
+#[derive(Default, Debug)]
+pub struct SendRawEmailRequestBuilder {
+    configuration_set_name: Option<String>,
+    destinations: Option<Vec<String>>,
+    from_arn: Option<String>,
+    raw_message: Option<RawMessage>,
+    return_path_arn: Option<String>,
+    source: Option<String>,
+    source_arn: Option<String>,
+    tags: Option<Vec<MessageTag>>,
+}
+
+impl SendRawEmailRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.SendRawEmailRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destinations`](struct.SendRawEmailRequest.html#structfield.destinations) member.
+    pub fn destinations<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.destinations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`from_arn`](struct.SendRawEmailRequest.html#structfield.from_arn) member.
+    pub fn from_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.from_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`raw_message`](struct.SendRawEmailRequest.html#structfield.raw_message) member.
+    pub fn raw_message<T: Into<RawMessage>>(mut self, value: T) -> Self {
+        self.raw_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_path_arn`](struct.SendRawEmailRequest.html#structfield.return_path_arn) member.
+    pub fn return_path_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.return_path_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.SendRawEmailRequest.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_arn`](struct.SendRawEmailRequest.html#structfield.source_arn) member.
+    pub fn source_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.SendRawEmailRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<MessageTag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendRawEmailRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SendRawEmailRequest, ::rusoto_core::BuildError> {
+        Ok(SendRawEmailRequest {
+            configuration_set_name: self.configuration_set_name,
+            destinations: self.destinations,
+            from_arn: self.from_arn,
+            raw_message: self
+                .raw_message
+                .ok_or(::rusoto_core::BuildError::missing("raw_message"))?,
+            return_path_arn: self.return_path_arn,
+            source: self.source,
+            source_arn: self.source_arn,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `SendRawEmailRequest` contents to a `SignedRequest`.
 struct SendRawEmailRequestSerializer;
 impl SendRawEmailRequestSerializer {
@@ -5473,10 +8494,10 @@ pub struct SendRawEmailResponse {
     pub message_id: String,
 }
 
-struct SendRawEmailResponseDeserializer;
+pub(crate) struct SendRawEmailResponseDeserializer;
 impl SendRawEmailResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SendRawEmailResponse, XmlParseError> {
@@ -5518,6 +8539,124 @@ pub struct SendTemplatedEmailRequest {
     pub template_data: String,
 }
 
+impl SendTemplatedEmailRequest {
+    /// Returns a builder for `SendTemplatedEmailRequest`, checking its required members when built.
+    pub fn builder() -> SendTemplatedEmailRequestBuilder {
+        SendTemplatedEmailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendTemplatedEmailRequest`](struct.SendTemplatedEmailRequest.html).
+#[derive(Default, Debug)]
+pub struct SendTemplatedEmailRequestBuilder {
+    configuration_set_name: Option<String>,
+    destination: Option<Destination>,
+    reply_to_addresses: Option<Vec<String>>,
+    return_path: Option<String>,
+    return_path_arn: Option<String>,
+    source: Option<String>,
+    source_arn: Option<String>,
+    tags: Option<Vec<MessageTag>>,
+    template: Option<String>,
+    template_arn: Option<String>,
+    template_data: Option<String>,
+}
+
+impl SendTemplatedEmailRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.SendTemplatedEmailRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination`](struct.SendTemplatedEmailRequest.html#structfield.destination) member.
+    pub fn destination<T: Into<Destination>>(mut self, value: T) -> Self {
+        self.destination = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reply_to_addresses`](struct.SendTemplatedEmailRequest.html#structfield.reply_to_addresses) member.
+    pub fn reply_to_addresses<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.reply_to_addresses = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_path`](struct.SendTemplatedEmailRequest.html#structfield.return_path) member.
+    pub fn return_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.return_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_path_arn`](struct.SendTemplatedEmailRequest.html#structfield.return_path_arn) member.
+    pub fn return_path_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.return_path_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.SendTemplatedEmailRequest.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_arn`](struct.SendTemplatedEmailRequest.html#structfield.source_arn) member.
+    pub fn source_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.SendTemplatedEmailRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<MessageTag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template`](struct.SendTemplatedEmailRequest.html#structfield.template) member.
+    pub fn template<T: Into<String>>(mut self, value: T) -> Self {
+        self.template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_arn`](struct.SendTemplatedEmailRequest.html#structfield.template_arn) member.
+    pub fn template_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_data`](struct.SendTemplatedEmailRequest.html#structfield.template_data) member.
+    pub fn template_data<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_data = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendTemplatedEmailRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SendTemplatedEmailRequest, ::rusoto_core::BuildError> {
+        Ok(SendTemplatedEmailRequest {
+            configuration_set_name: self.configuration_set_name,
+            destination: self
+                .destination
+                .ok_or(::rusoto_core::BuildError::missing("destination"))?,
+            reply_to_addresses: self.reply_to_addresses,
+            return_path: self.return_path,
+            return_path_arn: self.return_path_arn,
+            source: self
+                .source
+                .ok_or(::rusoto_core::BuildError::missing("source"))?,
+            source_arn: self.source_arn,
+            tags: self.tags,
+            template: self
+                .template
+                .ok_or(::rusoto_core::BuildError::missing("template"))?,
+            template_arn: self.template_arn,
+            template_data: self
+                .template_data
+                .ok_or(::rusoto_core::BuildError::missing("template_data"))?,
+        })
+    }
+}
+
 /// Serialize `SendTemplatedEmailRequest` contents to a `SignedRequest`.
 struct SendTemplatedEmailRequestSerializer;
 impl SendTemplatedEmailRequestSerializer {
@@ -5576,10 +8715,10 @@ pub struct SendTemplatedEmailResponse {
     pub message_id: String,
 }
 
-struct SendTemplatedEmailResponseDeserializer;
+pub(crate) struct SendTemplatedEmailResponseDeserializer;
 impl SendTemplatedEmailResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SendTemplatedEmailResponse, XmlParseError> {
@@ -5598,10 +8737,13 @@ impl SendTemplatedEmailResponseDeserializer {
         )
     }
 }
-struct SentLast24HoursDeserializer;
+pub(crate) struct SentLast24HoursDeserializer;
 impl SentLast24HoursDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5616,6 +8758,36 @@ pub struct SetActiveReceiptRuleSetRequest {
     pub rule_set_name: Option<String>,
 }
 
+impl SetActiveReceiptRuleSetRequest {
+    /// Returns a builder for `SetActiveReceiptRuleSetRequest`, checking its required members when built.
+    pub fn builder() -> SetActiveReceiptRuleSetRequestBuilder {
+        SetActiveReceiptRuleSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetActiveReceiptRuleSetRequest`](struct.SetActiveReceiptRuleSetRequest.html).
+#[derive(Default, Debug)]
+pub struct SetActiveReceiptRuleSetRequestBuilder {
+    rule_set_name: Option<String>,
+}
+
+impl SetActiveReceiptRuleSetRequestBuilder {
+    /// Sets the [`rule_set_name`](struct.SetActiveReceiptRuleSetRequest.html#structfield.rule_set_name) member.
+    pub fn rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetActiveReceiptRuleSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetActiveReceiptRuleSetRequest, ::rusoto_core::BuildError> {
+        Ok(SetActiveReceiptRuleSetRequest {
+            rule_set_name: self.rule_set_name,
+        })
+    }
+}
+
 /// Serialize `SetActiveReceiptRuleSetRequest` contents to a `SignedRequest`.
 struct SetActiveReceiptRuleSetRequestSerializer;
 impl SetActiveReceiptRuleSetRequestSerializer {
@@ -5635,10 +8807,10 @@ impl SetActiveReceiptRuleSetRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetActiveReceiptRuleSetResponse {}
 
-struct SetActiveReceiptRuleSetResponseDeserializer;
+pub(crate) struct SetActiveReceiptRuleSetResponseDeserializer;
 impl SetActiveReceiptRuleSetResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetActiveReceiptRuleSetResponse, XmlParseError> {
@@ -5660,6 +8832,48 @@ pub struct SetIdentityDkimEnabledRequest {
     pub identity: String,
 }
 
+impl SetIdentityDkimEnabledRequest {
+    /// Returns a builder for `SetIdentityDkimEnabledRequest`, checking its required members when built.
+    pub fn builder() -> SetIdentityDkimEnabledRequestBuilder {
+        SetIdentityDkimEnabledRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetIdentityDkimEnabledRequest`](struct.SetIdentityDkimEnabledRequest.html).
+#[derive(Default, Debug)]
+pub struct SetIdentityDkimEnabledRequestBuilder {
+    dkim_enabled: Option<bool>,
+    identity: Option<String>,
+}
+
+impl SetIdentityDkimEnabledRequestBuilder {
+    /// Sets the [`dkim_enabled`](struct.SetIdentityDkimEnabledRequest.html#structfield.dkim_enabled) member.
+    pub fn dkim_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dkim_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity`](struct.SetIdentityDkimEnabledRequest.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetIdentityDkimEnabledRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetIdentityDkimEnabledRequest, ::rusoto_core::BuildError> {
+        Ok(SetIdentityDkimEnabledRequest {
+            dkim_enabled: self
+                .dkim_enabled
+                .ok_or(::rusoto_core::BuildError::missing("dkim_enabled"))?,
+            identity: self
+                .identity
+                .ok_or(::rusoto_core::BuildError::missing("identity"))?,
+        })
+    }
+}
+
 /// Serialize `SetIdentityDkimEnabledRequest` contents to a `SignedRequest`.
 struct SetIdentityDkimEnabledRequestSerializer;
 impl SetIdentityDkimEnabledRequestSerializer {
@@ -5678,10 +8892,10 @@ impl SetIdentityDkimEnabledRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetIdentityDkimEnabledResponse {}
 
-struct SetIdentityDkimEnabledResponseDeserializer;
+pub(crate) struct SetIdentityDkimEnabledResponseDeserializer;
 impl SetIdentityDkimEnabledResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetIdentityDkimEnabledResponse, XmlParseError> {
@@ -5703,6 +8917,49 @@ pub struct SetIdentityFeedbackForwardingEnabledRequest {
     pub identity: String,
 }
 
+impl SetIdentityFeedbackForwardingEnabledRequest {
+    /// Returns a builder for `SetIdentityFeedbackForwardingEnabledRequest`, checking its required members when built.
+    pub fn builder() -> SetIdentityFeedbackForwardingEnabledRequestBuilder {
+        SetIdentityFeedbackForwardingEnabledRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetIdentityFeedbackForwardingEnabledRequest`](struct.SetIdentityFeedbackForwardingEnabledRequest.html).
+#[derive(Default, Debug)]
+pub struct SetIdentityFeedbackForwardingEnabledRequestBuilder {
+    forwarding_enabled: Option<bool>,
+    identity: Option<String>,
+}
+
+impl SetIdentityFeedbackForwardingEnabledRequestBuilder {
+    /// Sets the [`forwarding_enabled`](struct.SetIdentityFeedbackForwardingEnabledRequest.html#structfield.forwarding_enabled) member.
+    pub fn forwarding_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.forwarding_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity`](struct.SetIdentityFeedbackForwardingEnabledRequest.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetIdentityFeedbackForwardingEnabledRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetIdentityFeedbackForwardingEnabledRequest, ::rusoto_core::BuildError>
+    {
+        Ok(SetIdentityFeedbackForwardingEnabledRequest {
+            forwarding_enabled: self
+                .forwarding_enabled
+                .ok_or(::rusoto_core::BuildError::missing("forwarding_enabled"))?,
+            identity: self
+                .identity
+                .ok_or(::rusoto_core::BuildError::missing("identity"))?,
+        })
+    }
+}
+
 /// Serialize `SetIdentityFeedbackForwardingEnabledRequest` contents to a `SignedRequest`.
 struct SetIdentityFeedbackForwardingEnabledRequestSerializer;
 impl SetIdentityFeedbackForwardingEnabledRequestSerializer {
@@ -5728,10 +8985,10 @@ impl SetIdentityFeedbackForwardingEnabledRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetIdentityFeedbackForwardingEnabledResponse {}
 
-struct SetIdentityFeedbackForwardingEnabledResponseDeserializer;
+pub(crate) struct SetIdentityFeedbackForwardingEnabledResponseDeserializer;
 impl SetIdentityFeedbackForwardingEnabledResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetIdentityFeedbackForwardingEnabledResponse, XmlParseError> {
@@ -5745,14 +9002,69 @@ impl SetIdentityFeedbackForwardingEnabledResponseDeserializer {
     }
 }
 /// <p>Represents a request to set whether Amazon SES includes the original email headers in the Amazon SNS notifications of a specified type. For information about notifications, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications-via-sns.html">Amazon SES Developer Guide</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SetIdentityHeadersInNotificationsEnabledRequest {
     /// <p>Sets whether Amazon SES includes the original email headers in Amazon SNS notifications of the specified notification type. A value of <code>true</code> specifies that Amazon SES will include headers in notifications, and a value of <code>false</code> specifies that Amazon SES will not include headers in notifications.</p> <p>This value can only be set when <code>NotificationType</code> is already set to use a particular Amazon SNS topic.</p>
     pub enabled: bool,
     /// <p>The identity for which to enable or disable headers in notifications. Examples: <code>user@example.com</code>, <code>example.com</code>.</p>
     pub identity: String,
     /// <p>The notification type for which to enable or disable headers in notifications. </p>
-    pub notification_type: String,
+    pub notification_type: NotificationType,
+}
+
+impl SetIdentityHeadersInNotificationsEnabledRequest {
+    /// Returns a builder for `SetIdentityHeadersInNotificationsEnabledRequest`, checking its required members when built.
+    pub fn builder() -> SetIdentityHeadersInNotificationsEnabledRequestBuilder {
+        SetIdentityHeadersInNotificationsEnabledRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetIdentityHeadersInNotificationsEnabledRequest`](struct.SetIdentityHeadersInNotificationsEnabledRequest.html).
+#[derive(Default, Debug)]
+pub struct SetIdentityHeadersInNotificationsEnabledRequestBuilder {
+    enabled: Option<bool>,
+    identity: Option<String>,
+    notification_type: Option<NotificationType>,
+}
+
+impl SetIdentityHeadersInNotificationsEnabledRequestBuilder {
+    /// Sets the [`enabled`](struct.SetIdentityHeadersInNotificationsEnabledRequest.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity`](struct.SetIdentityHeadersInNotificationsEnabledRequest.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_type`](struct.SetIdentityHeadersInNotificationsEnabledRequest.html#structfield.notification_type) member.
+    pub fn notification_type<T: Into<NotificationType>>(mut self, value: T) -> Self {
+        self.notification_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetIdentityHeadersInNotificationsEnabledRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        SetIdentityHeadersInNotificationsEnabledRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(SetIdentityHeadersInNotificationsEnabledRequest {
+            enabled: self
+                .enabled
+                .ok_or(::rusoto_core::BuildError::missing("enabled"))?,
+            identity: self
+                .identity
+                .ok_or(::rusoto_core::BuildError::missing("identity"))?,
+            notification_type: self
+                .notification_type
+                .ok_or(::rusoto_core::BuildError::missing("notification_type"))?,
+        })
+    }
 }
 
 /// Serialize `SetIdentityHeadersInNotificationsEnabledRequest` contents to a `SignedRequest`.
@@ -5781,10 +9093,10 @@ impl SetIdentityHeadersInNotificationsEnabledRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetIdentityHeadersInNotificationsEnabledResponse {}
 
-struct SetIdentityHeadersInNotificationsEnabledResponseDeserializer;
+pub(crate) struct SetIdentityHeadersInNotificationsEnabledResponseDeserializer;
 impl SetIdentityHeadersInNotificationsEnabledResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetIdentityHeadersInNotificationsEnabledResponse, XmlParseError> {
@@ -5801,13 +9113,61 @@ impl SetIdentityHeadersInNotificationsEnabledResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetIdentityMailFromDomainRequest {
     /// <p>The action that you want Amazon SES to take if it cannot successfully read the required MX record when you send an email. If you choose <code>UseDefaultValue</code>, Amazon SES will use amazonses.com (or a subdomain of that) as the MAIL FROM domain. If you choose <code>RejectMessage</code>, Amazon SES will return a <code>MailFromDomainNotVerified</code> error and not send the email.</p> <p>The action specified in <code>BehaviorOnMXFailure</code> is taken when the custom MAIL FROM domain setup is in the <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code> states.</p>
-    pub behavior_on_mx_failure: Option<String>,
+    pub behavior_on_mx_failure: Option<BehaviorOnMXFailure>,
     /// <p>The verified identity for which you want to enable or disable the specified custom MAIL FROM domain.</p>
     pub identity: String,
     /// <p>The custom MAIL FROM domain that you want the verified identity to use. The MAIL FROM domain must 1) be a subdomain of the verified identity, 2) not be used in a "From" address if the MAIL FROM domain is the destination of email feedback forwarding (for more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from.html">Amazon SES Developer Guide</a>), and 3) not be used to receive emails. A value of <code>null</code> disables the custom MAIL FROM setting for the identity.</p>
     pub mail_from_domain: Option<String>,
 }
 
+impl SetIdentityMailFromDomainRequest {
+    /// Returns a builder for `SetIdentityMailFromDomainRequest`, checking its required members when built.
+    pub fn builder() -> SetIdentityMailFromDomainRequestBuilder {
+        SetIdentityMailFromDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetIdentityMailFromDomainRequest`](struct.SetIdentityMailFromDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct SetIdentityMailFromDomainRequestBuilder {
+    behavior_on_mx_failure: Option<BehaviorOnMXFailure>,
+    identity: Option<String>,
+    mail_from_domain: Option<String>,
+}
+
+impl SetIdentityMailFromDomainRequestBuilder {
+    /// Sets the [`behavior_on_mx_failure`](struct.SetIdentityMailFromDomainRequest.html#structfield.behavior_on_mx_failure) member.
+    pub fn behavior_on_mx_failure<T: Into<BehaviorOnMXFailure>>(mut self, value: T) -> Self {
+        self.behavior_on_mx_failure = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity`](struct.SetIdentityMailFromDomainRequest.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mail_from_domain`](struct.SetIdentityMailFromDomainRequest.html#structfield.mail_from_domain) member.
+    pub fn mail_from_domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.mail_from_domain = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetIdentityMailFromDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetIdentityMailFromDomainRequest, ::rusoto_core::BuildError> {
+        Ok(SetIdentityMailFromDomainRequest {
+            behavior_on_mx_failure: self.behavior_on_mx_failure,
+            identity: self
+                .identity
+                .ok_or(::rusoto_core::BuildError::missing("identity"))?,
+            mail_from_domain: self.mail_from_domain,
+        })
+    }
+}
+
 /// Serialize `SetIdentityMailFromDomainRequest` contents to a `SignedRequest`.
 struct SetIdentityMailFromDomainRequestSerializer;
 impl SetIdentityMailFromDomainRequestSerializer {
@@ -5834,10 +9194,10 @@ impl SetIdentityMailFromDomainRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetIdentityMailFromDomainResponse {}
 
-struct SetIdentityMailFromDomainResponseDeserializer;
+pub(crate) struct SetIdentityMailFromDomainResponseDeserializer;
 impl SetIdentityMailFromDomainResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetIdentityMailFromDomainResponse, XmlParseError> {
@@ -5851,22 +9211,72 @@ impl SetIdentityMailFromDomainResponseDeserializer {
     }
 }
 /// <p>Represents a request to specify the Amazon SNS topic to which Amazon SES will publish bounce, complaint, or delivery notifications for emails sent with that identity as the Source. For information about Amazon SES notifications, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications-via-sns.html">Amazon SES Developer Guide</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SetIdentityNotificationTopicRequest {
     /// <p>The identity (email address or domain) that you want to set the Amazon SNS topic for.</p> <important> <p>You can only specify a verified identity for this parameter.</p> </important> <p>You can specify an identity by using its name or by using its Amazon Resource Name (ARN). The following examples are all valid identities: <code>sender@example.com</code>, <code>example.com</code>, <code>arn:aws:ses:us-east-1:123456789012:identity/example.com</code>.</p>
     pub identity: String,
     /// <p>The type of notifications that will be published to the specified Amazon SNS topic.</p>
-    pub notification_type: String,
+    pub notification_type: NotificationType,
     /// <p>The Amazon Resource Name (ARN) of the Amazon SNS topic. If the parameter is omitted from the request or a null value is passed, <code>SnsTopic</code> is cleared and publishing is disabled.</p>
     pub sns_topic: Option<String>,
 }
 
-/// Serialize `SetIdentityNotificationTopicRequest` contents to a `SignedRequest`.
-struct SetIdentityNotificationTopicRequestSerializer;
-impl SetIdentityNotificationTopicRequestSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &SetIdentityNotificationTopicRequest) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
+impl SetIdentityNotificationTopicRequest {
+    /// Returns a builder for `SetIdentityNotificationTopicRequest`, checking its required members when built.
+    pub fn builder() -> SetIdentityNotificationTopicRequestBuilder {
+        SetIdentityNotificationTopicRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetIdentityNotificationTopicRequest`](struct.SetIdentityNotificationTopicRequest.html).
+#[derive(Default, Debug)]
+pub struct SetIdentityNotificationTopicRequestBuilder {
+    identity: Option<String>,
+    notification_type: Option<NotificationType>,
+    sns_topic: Option<String>,
+}
+
+impl SetIdentityNotificationTopicRequestBuilder {
+    /// Sets the [`identity`](struct.SetIdentityNotificationTopicRequest.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_type`](struct.SetIdentityNotificationTopicRequest.html#structfield.notification_type) member.
+    pub fn notification_type<T: Into<NotificationType>>(mut self, value: T) -> Self {
+        self.notification_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic`](struct.SetIdentityNotificationTopicRequest.html#structfield.sns_topic) member.
+    pub fn sns_topic<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetIdentityNotificationTopicRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetIdentityNotificationTopicRequest, ::rusoto_core::BuildError> {
+        Ok(SetIdentityNotificationTopicRequest {
+            identity: self
+                .identity
+                .ok_or(::rusoto_core::BuildError::missing("identity"))?,
+            notification_type: self
+                .notification_type
+                .ok_or(::rusoto_core::BuildError::missing("notification_type"))?,
+            sns_topic: self.sns_topic,
+        })
+    }
+}
+
+/// Serialize `SetIdentityNotificationTopicRequest` contents to a `SignedRequest`.
+struct SetIdentityNotificationTopicRequestSerializer;
+impl SetIdentityNotificationTopicRequestSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &SetIdentityNotificationTopicRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
             prefix.push_str(".");
         }
 
@@ -5885,10 +9295,10 @@ impl SetIdentityNotificationTopicRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetIdentityNotificationTopicResponse {}
 
-struct SetIdentityNotificationTopicResponseDeserializer;
+pub(crate) struct SetIdentityNotificationTopicResponseDeserializer;
 impl SetIdentityNotificationTopicResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetIdentityNotificationTopicResponse, XmlParseError> {
@@ -5912,6 +9322,56 @@ pub struct SetReceiptRulePositionRequest {
     pub rule_set_name: String,
 }
 
+impl SetReceiptRulePositionRequest {
+    /// Returns a builder for `SetReceiptRulePositionRequest`, checking its required members when built.
+    pub fn builder() -> SetReceiptRulePositionRequestBuilder {
+        SetReceiptRulePositionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetReceiptRulePositionRequest`](struct.SetReceiptRulePositionRequest.html).
+#[derive(Default, Debug)]
+pub struct SetReceiptRulePositionRequestBuilder {
+    after: Option<String>,
+    rule_name: Option<String>,
+    rule_set_name: Option<String>,
+}
+
+impl SetReceiptRulePositionRequestBuilder {
+    /// Sets the [`after`](struct.SetReceiptRulePositionRequest.html#structfield.after) member.
+    pub fn after<T: Into<String>>(mut self, value: T) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_name`](struct.SetReceiptRulePositionRequest.html#structfield.rule_name) member.
+    pub fn rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_set_name`](struct.SetReceiptRulePositionRequest.html#structfield.rule_set_name) member.
+    pub fn rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetReceiptRulePositionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetReceiptRulePositionRequest, ::rusoto_core::BuildError> {
+        Ok(SetReceiptRulePositionRequest {
+            after: self.after,
+            rule_name: self
+                .rule_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_name"))?,
+            rule_set_name: self
+                .rule_set_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `SetReceiptRulePositionRequest` contents to a `SignedRequest`.
 struct SetReceiptRulePositionRequestSerializer;
 impl SetReceiptRulePositionRequestSerializer {
@@ -5933,10 +9393,10 @@ impl SetReceiptRulePositionRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetReceiptRulePositionResponse {}
 
-struct SetReceiptRulePositionResponseDeserializer;
+pub(crate) struct SetReceiptRulePositionResponseDeserializer;
 impl SetReceiptRulePositionResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetReceiptRulePositionResponse, XmlParseError> {
@@ -5950,35 +9410,43 @@ impl SetReceiptRulePositionResponseDeserializer {
     }
 }
 /// <p>When included in a receipt rule, this action terminates the evaluation of the receipt rule set and, optionally, publishes a notification to Amazon Simple Notification Service (Amazon SNS).</p> <p>For information about setting a stop action in a receipt rule, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-action-stop.html">Amazon SES Developer Guide</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StopAction {
     /// <p>The scope of the StopAction. The only acceptable value is <code>RuleSet</code>.</p>
-    pub scope: String,
+    pub scope: StopScope,
     /// <p>The Amazon Resource Name (ARN) of the Amazon SNS topic to notify when the stop action is taken. An example of an Amazon SNS topic ARN is <code>arn:aws:sns:us-west-2:123456789012:MyTopic</code>. For more information about Amazon SNS topics, see the <a href="https://docs.aws.amazon.com/sns/latest/dg/CreateTopic.html">Amazon SNS Developer Guide</a>.</p>
     pub topic_arn: Option<String>,
 }
 
-struct StopActionDeserializer;
+pub(crate) struct StopActionDeserializer;
 impl StopActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StopAction, XmlParseError> {
-        deserialize_elements::<_, StopAction, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Scope" => {
-                    obj.scope = StopScopeDeserializer::deserialize("Scope", stack)?;
-                }
-                "TopicArn" => {
-                    obj.topic_arn = Some(AmazonResourceNameDeserializer::deserialize(
-                        "TopicArn", stack,
-                    )?);
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            StopAction {
+                scope: StopScope::Unknown(String::new()),
+                topic_arn: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Scope" => {
+                        obj.scope = StopScopeDeserializer::deserialize("Scope", stack)?;
+                    }
+                    "TopicArn" => {
+                        obj.topic_arn = Some(AmazonResourceNameDeserializer::deserialize(
+                            "TopicArn", stack,
+                        )?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -5998,21 +9466,82 @@ impl StopActionSerializer {
     }
 }
 
-struct StopScopeDeserializer;
+pub(crate) struct StopScopeDeserializer;
 impl StopScopeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StopScope, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct SubjectDeserializer;
+/// <p>The scope of the StopAction. The only acceptable value is <code>RuleSet</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StopScope {
+    /// <p><code>RuleSet</code></p>
+    RuleSet,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StopScope {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StopScope::RuleSet => "RuleSet",
+            StopScope::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StopScope {
+    fn from(value: &'a str) -> StopScope {
+        match value {
+            "RuleSet" => StopScope::RuleSet,
+            _ => StopScope::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StopScope {
+    fn from(value: String) -> StopScope {
+        StopScope::from(value.as_str())
+    }
+}
+
+impl From<StopScope> for String {
+    fn from(value: StopScope) -> String {
+        match value {
+            StopScope::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StopScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StopScope {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct SubjectDeserializer;
 impl SubjectDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6020,10 +9549,13 @@ impl SubjectDeserializer {
         Ok(obj)
     }
 }
-struct SubjectPartDeserializer;
+pub(crate) struct SubjectPartDeserializer;
 impl SubjectPartDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6031,10 +9563,13 @@ impl SubjectPartDeserializer {
         Ok(obj)
     }
 }
-struct SuccessRedirectionURLDeserializer;
+pub(crate) struct SuccessRedirectionURLDeserializer;
 impl SuccessRedirectionURLDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6055,10 +9590,10 @@ pub struct Template {
     pub text_part: Option<String>,
 }
 
-struct TemplateDeserializer;
+pub(crate) struct TemplateDeserializer;
 impl TemplateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Template, XmlParseError> {
@@ -6107,10 +9642,13 @@ impl TemplateSerializer {
     }
 }
 
-struct TemplateContentDeserializer;
+pub(crate) struct TemplateContentDeserializer;
 impl TemplateContentDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6122,15 +9660,15 @@ impl TemplateContentDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct TemplateMetadata {
     /// <p>The time and date the template was created.</p>
-    pub created_timestamp: Option<String>,
+    pub created_timestamp: Option<IsoTimestamp>,
     /// <p>The name of the template.</p>
     pub name: Option<String>,
 }
 
-struct TemplateMetadataDeserializer;
+pub(crate) struct TemplateMetadataDeserializer;
 impl TemplateMetadataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TemplateMetadata, XmlParseError> {
@@ -6151,10 +9689,10 @@ impl TemplateMetadataDeserializer {
         })
     }
 }
-struct TemplateMetadataListDeserializer;
+pub(crate) struct TemplateMetadataListDeserializer;
 impl TemplateMetadataListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TemplateMetadata>, XmlParseError> {
@@ -6168,10 +9706,13 @@ impl TemplateMetadataListDeserializer {
         })
     }
 }
-struct TemplateNameDeserializer;
+pub(crate) struct TemplateNameDeserializer;
 impl TemplateNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6187,6 +9728,48 @@ pub struct TestRenderTemplateRequest {
     pub template_name: String,
 }
 
+impl TestRenderTemplateRequest {
+    /// Returns a builder for `TestRenderTemplateRequest`, checking its required members when built.
+    pub fn builder() -> TestRenderTemplateRequestBuilder {
+        TestRenderTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TestRenderTemplateRequest`](struct.TestRenderTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct TestRenderTemplateRequestBuilder {
+    template_data: Option<String>,
+    template_name: Option<String>,
+}
+
+impl TestRenderTemplateRequestBuilder {
+    /// Sets the [`template_data`](struct.TestRenderTemplateRequest.html#structfield.template_data) member.
+    pub fn template_data<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.TestRenderTemplateRequest.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestRenderTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TestRenderTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(TestRenderTemplateRequest {
+            template_data: self
+                .template_data
+                .ok_or(::rusoto_core::BuildError::missing("template_data"))?,
+            template_name: self
+                .template_name
+                .ok_or(::rusoto_core::BuildError::missing("template_name"))?,
+        })
+    }
+}
+
 /// Serialize `TestRenderTemplateRequest` contents to a `SignedRequest`.
 struct TestRenderTemplateRequestSerializer;
 impl TestRenderTemplateRequestSerializer {
@@ -6207,10 +9790,10 @@ pub struct TestRenderTemplateResponse {
     pub rendered_template: Option<String>,
 }
 
-struct TestRenderTemplateResponseDeserializer;
+pub(crate) struct TestRenderTemplateResponseDeserializer;
 impl TestRenderTemplateResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TestRenderTemplateResponse, XmlParseError> {
@@ -6232,10 +9815,13 @@ impl TestRenderTemplateResponseDeserializer {
         )
     }
 }
-struct TextPartDeserializer;
+pub(crate) struct TextPartDeserializer;
 impl TextPartDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6243,28 +9829,93 @@ impl TextPartDeserializer {
         Ok(obj)
     }
 }
-struct TimestampDeserializer;
+pub(crate) struct TimestampDeserializer;
 impl TimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct TlsPolicyDeserializer;
+pub(crate) struct TlsPolicyDeserializer;
 impl TlsPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TlsPolicy, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>Specifies whether messages that use the configuration set are required to use Transport Layer Security (TLS). If the value is <code>Require</code>, messages are only delivered if a TLS connection can be established. If the value is <code>Optional</code>, messages can be delivered in plain text if a TLS connection can't be established.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TlsPolicy {
+    /// <p><code>Require</code></p>
+    Require,
+    /// <p><code>Optional</code></p>
+    Optional,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TlsPolicy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TlsPolicy::Require => "Require",
+            TlsPolicy::Optional => "Optional",
+            TlsPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TlsPolicy {
+    fn from(value: &'a str) -> TlsPolicy {
+        match value {
+            "Require" => TlsPolicy::Require,
+            "Optional" => TlsPolicy::Optional,
+            _ => TlsPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TlsPolicy {
+    fn from(value: String) -> TlsPolicy {
+        TlsPolicy::from(value.as_str())
+    }
+}
+
+impl From<TlsPolicy> for String {
+    fn from(value: TlsPolicy) -> String {
+        match value {
+            TlsPolicy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TlsPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TlsPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A domain that is used to redirect email recipients to an Amazon SES-operated domain. This domain captures open and click events generated by Amazon SES emails.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Configuring Custom Domains to Handle Open and Click Tracking</a> in the <i>Amazon SES Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct TrackingOptions {
@@ -6272,10 +9923,10 @@ pub struct TrackingOptions {
     pub custom_redirect_domain: Option<String>,
 }
 
-struct TrackingOptionsDeserializer;
+pub(crate) struct TrackingOptionsDeserializer;
 impl TrackingOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TrackingOptions, XmlParseError> {
@@ -6320,6 +9971,36 @@ pub struct UpdateAccountSendingEnabledRequest {
     pub enabled: Option<bool>,
 }
 
+impl UpdateAccountSendingEnabledRequest {
+    /// Returns a builder for `UpdateAccountSendingEnabledRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAccountSendingEnabledRequestBuilder {
+        UpdateAccountSendingEnabledRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAccountSendingEnabledRequest`](struct.UpdateAccountSendingEnabledRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAccountSendingEnabledRequestBuilder {
+    enabled: Option<bool>,
+}
+
+impl UpdateAccountSendingEnabledRequestBuilder {
+    /// Sets the [`enabled`](struct.UpdateAccountSendingEnabledRequest.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAccountSendingEnabledRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAccountSendingEnabledRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAccountSendingEnabledRequest {
+            enabled: self.enabled,
+        })
+    }
+}
+
 /// Serialize `UpdateAccountSendingEnabledRequest` contents to a `SignedRequest`.
 struct UpdateAccountSendingEnabledRequestSerializer;
 impl UpdateAccountSendingEnabledRequestSerializer {
@@ -6344,6 +10025,51 @@ pub struct UpdateConfigurationSetEventDestinationRequest {
     pub event_destination: EventDestination,
 }
 
+impl UpdateConfigurationSetEventDestinationRequest {
+    /// Returns a builder for `UpdateConfigurationSetEventDestinationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateConfigurationSetEventDestinationRequestBuilder {
+        UpdateConfigurationSetEventDestinationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConfigurationSetEventDestinationRequest`](struct.UpdateConfigurationSetEventDestinationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateConfigurationSetEventDestinationRequestBuilder {
+    configuration_set_name: Option<String>,
+    event_destination: Option<EventDestination>,
+}
+
+impl UpdateConfigurationSetEventDestinationRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.UpdateConfigurationSetEventDestinationRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_destination`](struct.UpdateConfigurationSetEventDestinationRequest.html#structfield.event_destination) member.
+    pub fn event_destination<T: Into<EventDestination>>(mut self, value: T) -> Self {
+        self.event_destination = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConfigurationSetEventDestinationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        UpdateConfigurationSetEventDestinationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(UpdateConfigurationSetEventDestinationRequest {
+            configuration_set_name: self
+                .configuration_set_name
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set_name"))?,
+            event_destination: self
+                .event_destination
+                .ok_or(::rusoto_core::BuildError::missing("event_destination"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateConfigurationSetEventDestinationRequest` contents to a `SignedRequest`.
 struct UpdateConfigurationSetEventDestinationRequestSerializer;
 impl UpdateConfigurationSetEventDestinationRequestSerializer {
@@ -6373,10 +10099,10 @@ impl UpdateConfigurationSetEventDestinationRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateConfigurationSetEventDestinationResponse {}
 
-struct UpdateConfigurationSetEventDestinationResponseDeserializer;
+pub(crate) struct UpdateConfigurationSetEventDestinationResponseDeserializer;
 impl UpdateConfigurationSetEventDestinationResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateConfigurationSetEventDestinationResponse, XmlParseError> {
@@ -6398,6 +10124,51 @@ pub struct UpdateConfigurationSetReputationMetricsEnabledRequest {
     pub enabled: bool,
 }
 
+impl UpdateConfigurationSetReputationMetricsEnabledRequest {
+    /// Returns a builder for `UpdateConfigurationSetReputationMetricsEnabledRequest`, checking its required members when built.
+    pub fn builder() -> UpdateConfigurationSetReputationMetricsEnabledRequestBuilder {
+        UpdateConfigurationSetReputationMetricsEnabledRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConfigurationSetReputationMetricsEnabledRequest`](struct.UpdateConfigurationSetReputationMetricsEnabledRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateConfigurationSetReputationMetricsEnabledRequestBuilder {
+    configuration_set_name: Option<String>,
+    enabled: Option<bool>,
+}
+
+impl UpdateConfigurationSetReputationMetricsEnabledRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.UpdateConfigurationSetReputationMetricsEnabledRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enabled`](struct.UpdateConfigurationSetReputationMetricsEnabledRequest.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConfigurationSetReputationMetricsEnabledRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        UpdateConfigurationSetReputationMetricsEnabledRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(UpdateConfigurationSetReputationMetricsEnabledRequest {
+            configuration_set_name: self
+                .configuration_set_name
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set_name"))?,
+            enabled: self
+                .enabled
+                .ok_or(::rusoto_core::BuildError::missing("enabled"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateConfigurationSetReputationMetricsEnabledRequest` contents to a `SignedRequest`.
 struct UpdateConfigurationSetReputationMetricsEnabledRequestSerializer;
 impl UpdateConfigurationSetReputationMetricsEnabledRequestSerializer {
@@ -6428,6 +10199,49 @@ pub struct UpdateConfigurationSetSendingEnabledRequest {
     pub enabled: bool,
 }
 
+impl UpdateConfigurationSetSendingEnabledRequest {
+    /// Returns a builder for `UpdateConfigurationSetSendingEnabledRequest`, checking its required members when built.
+    pub fn builder() -> UpdateConfigurationSetSendingEnabledRequestBuilder {
+        UpdateConfigurationSetSendingEnabledRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConfigurationSetSendingEnabledRequest`](struct.UpdateConfigurationSetSendingEnabledRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateConfigurationSetSendingEnabledRequestBuilder {
+    configuration_set_name: Option<String>,
+    enabled: Option<bool>,
+}
+
+impl UpdateConfigurationSetSendingEnabledRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.UpdateConfigurationSetSendingEnabledRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enabled`](struct.UpdateConfigurationSetSendingEnabledRequest.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConfigurationSetSendingEnabledRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateConfigurationSetSendingEnabledRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateConfigurationSetSendingEnabledRequest {
+            configuration_set_name: self
+                .configuration_set_name
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set_name"))?,
+            enabled: self
+                .enabled
+                .ok_or(::rusoto_core::BuildError::missing("enabled"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateConfigurationSetSendingEnabledRequest` contents to a `SignedRequest`.
 struct UpdateConfigurationSetSendingEnabledRequestSerializer;
 impl UpdateConfigurationSetSendingEnabledRequestSerializer {
@@ -6457,6 +10271,51 @@ pub struct UpdateConfigurationSetTrackingOptionsRequest {
     pub tracking_options: TrackingOptions,
 }
 
+impl UpdateConfigurationSetTrackingOptionsRequest {
+    /// Returns a builder for `UpdateConfigurationSetTrackingOptionsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateConfigurationSetTrackingOptionsRequestBuilder {
+        UpdateConfigurationSetTrackingOptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConfigurationSetTrackingOptionsRequest`](struct.UpdateConfigurationSetTrackingOptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateConfigurationSetTrackingOptionsRequestBuilder {
+    configuration_set_name: Option<String>,
+    tracking_options: Option<TrackingOptions>,
+}
+
+impl UpdateConfigurationSetTrackingOptionsRequestBuilder {
+    /// Sets the [`configuration_set_name`](struct.UpdateConfigurationSetTrackingOptionsRequest.html#structfield.configuration_set_name) member.
+    pub fn configuration_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tracking_options`](struct.UpdateConfigurationSetTrackingOptionsRequest.html#structfield.tracking_options) member.
+    pub fn tracking_options<T: Into<TrackingOptions>>(mut self, value: T) -> Self {
+        self.tracking_options = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConfigurationSetTrackingOptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        UpdateConfigurationSetTrackingOptionsRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(UpdateConfigurationSetTrackingOptionsRequest {
+            configuration_set_name: self
+                .configuration_set_name
+                .ok_or(::rusoto_core::BuildError::missing("configuration_set_name"))?,
+            tracking_options: self
+                .tracking_options
+                .ok_or(::rusoto_core::BuildError::missing("tracking_options"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateConfigurationSetTrackingOptionsRequest` contents to a `SignedRequest`.
 struct UpdateConfigurationSetTrackingOptionsRequestSerializer;
 impl UpdateConfigurationSetTrackingOptionsRequestSerializer {
@@ -6486,10 +10345,10 @@ impl UpdateConfigurationSetTrackingOptionsRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateConfigurationSetTrackingOptionsResponse {}
 
-struct UpdateConfigurationSetTrackingOptionsResponseDeserializer;
+pub(crate) struct UpdateConfigurationSetTrackingOptionsResponseDeserializer;
 impl UpdateConfigurationSetTrackingOptionsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateConfigurationSetTrackingOptionsResponse, XmlParseError> {
@@ -6519,6 +10378,81 @@ pub struct UpdateCustomVerificationEmailTemplateRequest {
     pub template_subject: Option<String>,
 }
 
+impl UpdateCustomVerificationEmailTemplateRequest {
+    /// Returns a builder for `UpdateCustomVerificationEmailTemplateRequest`, checking its required members when built.
+    pub fn builder() -> UpdateCustomVerificationEmailTemplateRequestBuilder {
+        UpdateCustomVerificationEmailTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCustomVerificationEmailTemplateRequest`](struct.UpdateCustomVerificationEmailTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateCustomVerificationEmailTemplateRequestBuilder {
+    failure_redirection_url: Option<String>,
+    from_email_address: Option<String>,
+    success_redirection_url: Option<String>,
+    template_content: Option<String>,
+    template_name: Option<String>,
+    template_subject: Option<String>,
+}
+
+impl UpdateCustomVerificationEmailTemplateRequestBuilder {
+    /// Sets the [`failure_redirection_url`](struct.UpdateCustomVerificationEmailTemplateRequest.html#structfield.failure_redirection_url) member.
+    pub fn failure_redirection_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.failure_redirection_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`from_email_address`](struct.UpdateCustomVerificationEmailTemplateRequest.html#structfield.from_email_address) member.
+    pub fn from_email_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.from_email_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`success_redirection_url`](struct.UpdateCustomVerificationEmailTemplateRequest.html#structfield.success_redirection_url) member.
+    pub fn success_redirection_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.success_redirection_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_content`](struct.UpdateCustomVerificationEmailTemplateRequest.html#structfield.template_content) member.
+    pub fn template_content<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.UpdateCustomVerificationEmailTemplateRequest.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_subject`](struct.UpdateCustomVerificationEmailTemplateRequest.html#structfield.template_subject) member.
+    pub fn template_subject<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_subject = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCustomVerificationEmailTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        UpdateCustomVerificationEmailTemplateRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(UpdateCustomVerificationEmailTemplateRequest {
+            failure_redirection_url: self.failure_redirection_url,
+            from_email_address: self.from_email_address,
+            success_redirection_url: self.success_redirection_url,
+            template_content: self.template_content,
+            template_name: self
+                .template_name
+                .ok_or(::rusoto_core::BuildError::missing("template_name"))?,
+            template_subject: self.template_subject,
+        })
+    }
+}
+
 /// Serialize `UpdateCustomVerificationEmailTemplateRequest` contents to a `SignedRequest`.
 struct UpdateCustomVerificationEmailTemplateRequestSerializer;
 impl UpdateCustomVerificationEmailTemplateRequestSerializer {
@@ -6566,6 +10500,48 @@ pub struct UpdateReceiptRuleRequest {
     pub rule_set_name: String,
 }
 
+impl UpdateReceiptRuleRequest {
+    /// Returns a builder for `UpdateReceiptRuleRequest`, checking its required members when built.
+    pub fn builder() -> UpdateReceiptRuleRequestBuilder {
+        UpdateReceiptRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateReceiptRuleRequest`](struct.UpdateReceiptRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateReceiptRuleRequestBuilder {
+    rule: Option<ReceiptRule>,
+    rule_set_name: Option<String>,
+}
+
+impl UpdateReceiptRuleRequestBuilder {
+    /// Sets the [`rule`](struct.UpdateReceiptRuleRequest.html#structfield.rule) member.
+    pub fn rule<T: Into<ReceiptRule>>(mut self, value: T) -> Self {
+        self.rule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_set_name`](struct.UpdateReceiptRuleRequest.html#structfield.rule_set_name) member.
+    pub fn rule_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateReceiptRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateReceiptRuleRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateReceiptRuleRequest {
+            rule: self
+                .rule
+                .ok_or(::rusoto_core::BuildError::missing("rule"))?,
+            rule_set_name: self
+                .rule_set_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateReceiptRuleRequest` contents to a `SignedRequest`.
 struct UpdateReceiptRuleRequestSerializer;
 impl UpdateReceiptRuleRequestSerializer {
@@ -6584,10 +10560,10 @@ impl UpdateReceiptRuleRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateReceiptRuleResponse {}
 
-struct UpdateReceiptRuleResponseDeserializer;
+pub(crate) struct UpdateReceiptRuleResponseDeserializer;
 impl UpdateReceiptRuleResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateReceiptRuleResponse, XmlParseError> {
@@ -6605,6 +10581,36 @@ pub struct UpdateTemplateRequest {
     pub template: Template,
 }
 
+impl UpdateTemplateRequest {
+    /// Returns a builder for `UpdateTemplateRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTemplateRequestBuilder {
+        UpdateTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTemplateRequest`](struct.UpdateTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTemplateRequestBuilder {
+    template: Option<Template>,
+}
+
+impl UpdateTemplateRequestBuilder {
+    /// Sets the [`template`](struct.UpdateTemplateRequest.html#structfield.template) member.
+    pub fn template<T: Into<Template>>(mut self, value: T) -> Self {
+        self.template = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTemplateRequest {
+            template: self
+                .template
+                .ok_or(::rusoto_core::BuildError::missing("template"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateTemplateRequest` contents to a `SignedRequest`.
 struct UpdateTemplateRequestSerializer;
 impl UpdateTemplateRequestSerializer {
@@ -6621,10 +10627,10 @@ impl UpdateTemplateRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateTemplateResponse {}
 
-struct UpdateTemplateResponseDeserializer;
+pub(crate) struct UpdateTemplateResponseDeserializer;
 impl UpdateTemplateResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateTemplateResponse, XmlParseError> {
@@ -6637,10 +10643,10 @@ impl UpdateTemplateResponseDeserializer {
         Ok(obj)
     }
 }
-struct VerificationAttributesDeserializer;
+pub(crate) struct VerificationAttributesDeserializer;
 impl VerificationAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, IdentityVerificationAttributes>, XmlParseError>
@@ -6661,21 +10667,98 @@ impl VerificationAttributesDeserializer {
         Ok(obj)
     }
 }
-struct VerificationStatusDeserializer;
+pub(crate) struct VerificationStatusDeserializer;
 impl VerificationStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<VerificationStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct VerificationTokenDeserializer;
+/// <p>Describes whether Amazon SES has successfully verified the DKIM DNS records (tokens) published in the domain name's DNS. (This only applies to domain identities, not email address identities.)</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VerificationStatus {
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>Success</code></p>
+    Success,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>TemporaryFailure</code></p>
+    TemporaryFailure,
+    /// <p><code>NotStarted</code></p>
+    NotStarted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VerificationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VerificationStatus::Pending => "Pending",
+            VerificationStatus::Success => "Success",
+            VerificationStatus::Failed => "Failed",
+            VerificationStatus::TemporaryFailure => "TemporaryFailure",
+            VerificationStatus::NotStarted => "NotStarted",
+            VerificationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VerificationStatus {
+    fn from(value: &'a str) -> VerificationStatus {
+        match value {
+            "Pending" => VerificationStatus::Pending,
+            "Success" => VerificationStatus::Success,
+            "Failed" => VerificationStatus::Failed,
+            "TemporaryFailure" => VerificationStatus::TemporaryFailure,
+            "NotStarted" => VerificationStatus::NotStarted,
+            _ => VerificationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VerificationStatus {
+    fn from(value: String) -> VerificationStatus {
+        VerificationStatus::from(value.as_str())
+    }
+}
+
+impl From<VerificationStatus> for String {
+    fn from(value: VerificationStatus) -> String {
+        match value {
+            VerificationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VerificationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VerificationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct VerificationTokenDeserializer;
 impl VerificationTokenDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6683,10 +10766,10 @@ impl VerificationTokenDeserializer {
         Ok(obj)
     }
 }
-struct VerificationTokenListDeserializer;
+pub(crate) struct VerificationTokenListDeserializer;
 impl VerificationTokenListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6707,6 +10790,38 @@ pub struct VerifyDomainDkimRequest {
     pub domain: String,
 }
 
+impl VerifyDomainDkimRequest {
+    /// Returns a builder for `VerifyDomainDkimRequest`, checking its required members when built.
+    pub fn builder() -> VerifyDomainDkimRequestBuilder {
+        VerifyDomainDkimRequestBuilder::default()
+    }
+}
+
+/// Builds a [`VerifyDomainDkimRequest`](struct.VerifyDomainDkimRequest.html).
+#[derive(Default, Debug)]
+pub struct VerifyDomainDkimRequestBuilder {
+    domain: Option<String>,
+}
+
+impl VerifyDomainDkimRequestBuilder {
+    /// Sets the [`domain`](struct.VerifyDomainDkimRequest.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Builds the `VerifyDomainDkimRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<VerifyDomainDkimRequest, ::rusoto_core::BuildError> {
+        Ok(VerifyDomainDkimRequest {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+        })
+    }
+}
+
 /// Serialize `VerifyDomainDkimRequest` contents to a `SignedRequest`.
 struct VerifyDomainDkimRequestSerializer;
 impl VerifyDomainDkimRequestSerializer {
@@ -6727,10 +10842,10 @@ pub struct VerifyDomainDkimResponse {
     pub dkim_tokens: Vec<String>,
 }
 
-struct VerifyDomainDkimResponseDeserializer;
+pub(crate) struct VerifyDomainDkimResponseDeserializer;
 impl VerifyDomainDkimResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<VerifyDomainDkimResponse, XmlParseError> {
@@ -6760,6 +10875,38 @@ pub struct VerifyDomainIdentityRequest {
     pub domain: String,
 }
 
+impl VerifyDomainIdentityRequest {
+    /// Returns a builder for `VerifyDomainIdentityRequest`, checking its required members when built.
+    pub fn builder() -> VerifyDomainIdentityRequestBuilder {
+        VerifyDomainIdentityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`VerifyDomainIdentityRequest`](struct.VerifyDomainIdentityRequest.html).
+#[derive(Default, Debug)]
+pub struct VerifyDomainIdentityRequestBuilder {
+    domain: Option<String>,
+}
+
+impl VerifyDomainIdentityRequestBuilder {
+    /// Sets the [`domain`](struct.VerifyDomainIdentityRequest.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Builds the `VerifyDomainIdentityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<VerifyDomainIdentityRequest, ::rusoto_core::BuildError> {
+        Ok(VerifyDomainIdentityRequest {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+        })
+    }
+}
+
 /// Serialize `VerifyDomainIdentityRequest` contents to a `SignedRequest`.
 struct VerifyDomainIdentityRequestSerializer;
 impl VerifyDomainIdentityRequestSerializer {
@@ -6780,10 +10927,10 @@ pub struct VerifyDomainIdentityResponse {
     pub verification_token: String,
 }
 
-struct VerifyDomainIdentityResponseDeserializer;
+pub(crate) struct VerifyDomainIdentityResponseDeserializer;
 impl VerifyDomainIdentityResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<VerifyDomainIdentityResponse, XmlParseError> {
@@ -6810,6 +10957,38 @@ pub struct VerifyEmailAddressRequest {
     pub email_address: String,
 }
 
+impl VerifyEmailAddressRequest {
+    /// Returns a builder for `VerifyEmailAddressRequest`, checking its required members when built.
+    pub fn builder() -> VerifyEmailAddressRequestBuilder {
+        VerifyEmailAddressRequestBuilder::default()
+    }
+}
+
+/// Builds a [`VerifyEmailAddressRequest`](struct.VerifyEmailAddressRequest.html).
+#[derive(Default, Debug)]
+pub struct VerifyEmailAddressRequestBuilder {
+    email_address: Option<String>,
+}
+
+impl VerifyEmailAddressRequestBuilder {
+    /// Sets the [`email_address`](struct.VerifyEmailAddressRequest.html#structfield.email_address) member.
+    pub fn email_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_address = Some(value.into());
+        self
+    }
+
+    /// Builds the `VerifyEmailAddressRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<VerifyEmailAddressRequest, ::rusoto_core::BuildError> {
+        Ok(VerifyEmailAddressRequest {
+            email_address: self
+                .email_address
+                .ok_or(::rusoto_core::BuildError::missing("email_address"))?,
+        })
+    }
+}
+
 /// Serialize `VerifyEmailAddressRequest` contents to a `SignedRequest`.
 struct VerifyEmailAddressRequestSerializer;
 impl VerifyEmailAddressRequestSerializer {
@@ -6830,6 +11009,38 @@ pub struct VerifyEmailIdentityRequest {
     pub email_address: String,
 }
 
+impl VerifyEmailIdentityRequest {
+    /// Returns a builder for `VerifyEmailIdentityRequest`, checking its required members when built.
+    pub fn builder() -> VerifyEmailIdentityRequestBuilder {
+        VerifyEmailIdentityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`VerifyEmailIdentityRequest`](struct.VerifyEmailIdentityRequest.html).
+#[derive(Default, Debug)]
+pub struct VerifyEmailIdentityRequestBuilder {
+    email_address: Option<String>,
+}
+
+impl VerifyEmailIdentityRequestBuilder {
+    /// Sets the [`email_address`](struct.VerifyEmailIdentityRequest.html#structfield.email_address) member.
+    pub fn email_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_address = Some(value.into());
+        self
+    }
+
+    /// Builds the `VerifyEmailIdentityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<VerifyEmailIdentityRequest, ::rusoto_core::BuildError> {
+        Ok(VerifyEmailIdentityRequest {
+            email_address: self
+                .email_address
+                .ok_or(::rusoto_core::BuildError::missing("email_address"))?,
+        })
+    }
+}
+
 /// Serialize `VerifyEmailIdentityRequest` contents to a `SignedRequest`.
 struct VerifyEmailIdentityRequestSerializer;
 impl VerifyEmailIdentityRequestSerializer {
@@ -6847,10 +11058,10 @@ impl VerifyEmailIdentityRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct VerifyEmailIdentityResponse {}
 
-struct VerifyEmailIdentityResponseDeserializer;
+pub(crate) struct VerifyEmailIdentityResponseDeserializer;
 impl VerifyEmailIdentityResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<VerifyEmailIdentityResponse, XmlParseError> {
@@ -6872,10 +11083,10 @@ pub struct WorkmailAction {
     pub topic_arn: Option<String>,
 }
 
-struct WorkmailActionDeserializer;
+pub(crate) struct WorkmailActionDeserializer;
 impl WorkmailActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<WorkmailAction, XmlParseError> {
@@ -10999,6 +15210,477 @@ pub trait Ses {
         input: VerifyEmailIdentityRequest,
     ) -> RusotoFuture<VerifyEmailIdentityResponse, VerifyEmailIdentityError>;
 }
+impl<T: ?Sized + Ses> Ses for ::std::sync::Arc<T> {
+    fn clone_receipt_rule_set(
+        &self,
+        input: CloneReceiptRuleSetRequest,
+    ) -> RusotoFuture<CloneReceiptRuleSetResponse, CloneReceiptRuleSetError> {
+        (**self).clone_receipt_rule_set(input)
+    }
+    fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> RusotoFuture<CreateConfigurationSetResponse, CreateConfigurationSetError> {
+        (**self).create_configuration_set(input)
+    }
+    fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        CreateConfigurationSetEventDestinationResponse,
+        CreateConfigurationSetEventDestinationError,
+    > {
+        (**self).create_configuration_set_event_destination(input)
+    }
+    fn create_configuration_set_tracking_options(
+        &self,
+        input: CreateConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        CreateConfigurationSetTrackingOptionsResponse,
+        CreateConfigurationSetTrackingOptionsError,
+    > {
+        (**self).create_configuration_set_tracking_options(input)
+    }
+    fn create_custom_verification_email_template(
+        &self,
+        input: CreateCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), CreateCustomVerificationEmailTemplateError> {
+        (**self).create_custom_verification_email_template(input)
+    }
+    fn create_receipt_filter(
+        &self,
+        input: CreateReceiptFilterRequest,
+    ) -> RusotoFuture<CreateReceiptFilterResponse, CreateReceiptFilterError> {
+        (**self).create_receipt_filter(input)
+    }
+    fn create_receipt_rule(
+        &self,
+        input: CreateReceiptRuleRequest,
+    ) -> RusotoFuture<CreateReceiptRuleResponse, CreateReceiptRuleError> {
+        (**self).create_receipt_rule(input)
+    }
+    fn create_receipt_rule_set(
+        &self,
+        input: CreateReceiptRuleSetRequest,
+    ) -> RusotoFuture<CreateReceiptRuleSetResponse, CreateReceiptRuleSetError> {
+        (**self).create_receipt_rule_set(input)
+    }
+    fn create_template(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> RusotoFuture<CreateTemplateResponse, CreateTemplateError> {
+        (**self).create_template(input)
+    }
+    fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> RusotoFuture<DeleteConfigurationSetResponse, DeleteConfigurationSetError> {
+        (**self).delete_configuration_set(input)
+    }
+    fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        DeleteConfigurationSetEventDestinationResponse,
+        DeleteConfigurationSetEventDestinationError,
+    > {
+        (**self).delete_configuration_set_event_destination(input)
+    }
+    fn delete_configuration_set_tracking_options(
+        &self,
+        input: DeleteConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        DeleteConfigurationSetTrackingOptionsResponse,
+        DeleteConfigurationSetTrackingOptionsError,
+    > {
+        (**self).delete_configuration_set_tracking_options(input)
+    }
+    fn delete_custom_verification_email_template(
+        &self,
+        input: DeleteCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), DeleteCustomVerificationEmailTemplateError> {
+        (**self).delete_custom_verification_email_template(input)
+    }
+    fn delete_identity(
+        &self,
+        input: DeleteIdentityRequest,
+    ) -> RusotoFuture<DeleteIdentityResponse, DeleteIdentityError> {
+        (**self).delete_identity(input)
+    }
+    fn delete_identity_policy(
+        &self,
+        input: DeleteIdentityPolicyRequest,
+    ) -> RusotoFuture<DeleteIdentityPolicyResponse, DeleteIdentityPolicyError> {
+        (**self).delete_identity_policy(input)
+    }
+    fn delete_receipt_filter(
+        &self,
+        input: DeleteReceiptFilterRequest,
+    ) -> RusotoFuture<DeleteReceiptFilterResponse, DeleteReceiptFilterError> {
+        (**self).delete_receipt_filter(input)
+    }
+    fn delete_receipt_rule(
+        &self,
+        input: DeleteReceiptRuleRequest,
+    ) -> RusotoFuture<DeleteReceiptRuleResponse, DeleteReceiptRuleError> {
+        (**self).delete_receipt_rule(input)
+    }
+    fn delete_receipt_rule_set(
+        &self,
+        input: DeleteReceiptRuleSetRequest,
+    ) -> RusotoFuture<DeleteReceiptRuleSetResponse, DeleteReceiptRuleSetError> {
+        (**self).delete_receipt_rule_set(input)
+    }
+    fn delete_template(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> RusotoFuture<DeleteTemplateResponse, DeleteTemplateError> {
+        (**self).delete_template(input)
+    }
+    fn delete_verified_email_address(
+        &self,
+        input: DeleteVerifiedEmailAddressRequest,
+    ) -> RusotoFuture<(), DeleteVerifiedEmailAddressError> {
+        (**self).delete_verified_email_address(input)
+    }
+    fn describe_active_receipt_rule_set(
+        &self,
+        input: DescribeActiveReceiptRuleSetRequest,
+    ) -> RusotoFuture<DescribeActiveReceiptRuleSetResponse, DescribeActiveReceiptRuleSetError> {
+        (**self).describe_active_receipt_rule_set(input)
+    }
+    fn describe_configuration_set(
+        &self,
+        input: DescribeConfigurationSetRequest,
+    ) -> RusotoFuture<DescribeConfigurationSetResponse, DescribeConfigurationSetError> {
+        (**self).describe_configuration_set(input)
+    }
+    fn describe_receipt_rule(
+        &self,
+        input: DescribeReceiptRuleRequest,
+    ) -> RusotoFuture<DescribeReceiptRuleResponse, DescribeReceiptRuleError> {
+        (**self).describe_receipt_rule(input)
+    }
+    fn describe_receipt_rule_set(
+        &self,
+        input: DescribeReceiptRuleSetRequest,
+    ) -> RusotoFuture<DescribeReceiptRuleSetResponse, DescribeReceiptRuleSetError> {
+        (**self).describe_receipt_rule_set(input)
+    }
+    fn get_account_sending_enabled(
+        &self,
+    ) -> RusotoFuture<GetAccountSendingEnabledResponse, GetAccountSendingEnabledError> {
+        (**self).get_account_sending_enabled()
+    }
+    fn get_custom_verification_email_template(
+        &self,
+        input: GetCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<
+        GetCustomVerificationEmailTemplateResponse,
+        GetCustomVerificationEmailTemplateError,
+    > {
+        (**self).get_custom_verification_email_template(input)
+    }
+    fn get_identity_dkim_attributes(
+        &self,
+        input: GetIdentityDkimAttributesRequest,
+    ) -> RusotoFuture<GetIdentityDkimAttributesResponse, GetIdentityDkimAttributesError> {
+        (**self).get_identity_dkim_attributes(input)
+    }
+    fn get_identity_mail_from_domain_attributes(
+        &self,
+        input: GetIdentityMailFromDomainAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityMailFromDomainAttributesResponse,
+        GetIdentityMailFromDomainAttributesError,
+    > {
+        (**self).get_identity_mail_from_domain_attributes(input)
+    }
+    fn get_identity_notification_attributes(
+        &self,
+        input: GetIdentityNotificationAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityNotificationAttributesResponse,
+        GetIdentityNotificationAttributesError,
+    > {
+        (**self).get_identity_notification_attributes(input)
+    }
+    fn get_identity_policies(
+        &self,
+        input: GetIdentityPoliciesRequest,
+    ) -> RusotoFuture<GetIdentityPoliciesResponse, GetIdentityPoliciesError> {
+        (**self).get_identity_policies(input)
+    }
+    fn get_identity_verification_attributes(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityVerificationAttributesResponse,
+        GetIdentityVerificationAttributesError,
+    > {
+        (**self).get_identity_verification_attributes(input)
+    }
+    fn get_send_quota(&self) -> RusotoFuture<GetSendQuotaResponse, GetSendQuotaError> {
+        (**self).get_send_quota()
+    }
+    fn get_send_statistics(
+        &self,
+    ) -> RusotoFuture<GetSendStatisticsResponse, GetSendStatisticsError> {
+        (**self).get_send_statistics()
+    }
+    fn get_template(
+        &self,
+        input: GetTemplateRequest,
+    ) -> RusotoFuture<GetTemplateResponse, GetTemplateError> {
+        (**self).get_template(input)
+    }
+    fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> RusotoFuture<ListConfigurationSetsResponse, ListConfigurationSetsError> {
+        (**self).list_configuration_sets(input)
+    }
+    fn list_custom_verification_email_templates(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> RusotoFuture<
+        ListCustomVerificationEmailTemplatesResponse,
+        ListCustomVerificationEmailTemplatesError,
+    > {
+        (**self).list_custom_verification_email_templates(input)
+    }
+    fn list_identities(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
+        (**self).list_identities(input)
+    }
+    fn list_identity_policies(
+        &self,
+        input: ListIdentityPoliciesRequest,
+    ) -> RusotoFuture<ListIdentityPoliciesResponse, ListIdentityPoliciesError> {
+        (**self).list_identity_policies(input)
+    }
+    fn list_receipt_filters(
+        &self,
+        input: ListReceiptFiltersRequest,
+    ) -> RusotoFuture<ListReceiptFiltersResponse, ListReceiptFiltersError> {
+        (**self).list_receipt_filters(input)
+    }
+    fn list_receipt_rule_sets(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> RusotoFuture<ListReceiptRuleSetsResponse, ListReceiptRuleSetsError> {
+        (**self).list_receipt_rule_sets(input)
+    }
+    fn list_templates(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> RusotoFuture<ListTemplatesResponse, ListTemplatesError> {
+        (**self).list_templates(input)
+    }
+    fn list_verified_email_addresses(
+        &self,
+    ) -> RusotoFuture<ListVerifiedEmailAddressesResponse, ListVerifiedEmailAddressesError> {
+        (**self).list_verified_email_addresses()
+    }
+    fn put_configuration_set_delivery_options(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> RusotoFuture<
+        PutConfigurationSetDeliveryOptionsResponse,
+        PutConfigurationSetDeliveryOptionsError,
+    > {
+        (**self).put_configuration_set_delivery_options(input)
+    }
+    fn put_identity_policy(
+        &self,
+        input: PutIdentityPolicyRequest,
+    ) -> RusotoFuture<PutIdentityPolicyResponse, PutIdentityPolicyError> {
+        (**self).put_identity_policy(input)
+    }
+    fn reorder_receipt_rule_set(
+        &self,
+        input: ReorderReceiptRuleSetRequest,
+    ) -> RusotoFuture<ReorderReceiptRuleSetResponse, ReorderReceiptRuleSetError> {
+        (**self).reorder_receipt_rule_set(input)
+    }
+    fn send_bounce(
+        &self,
+        input: SendBounceRequest,
+    ) -> RusotoFuture<SendBounceResponse, SendBounceError> {
+        (**self).send_bounce(input)
+    }
+    fn send_bulk_templated_email(
+        &self,
+        input: SendBulkTemplatedEmailRequest,
+    ) -> RusotoFuture<SendBulkTemplatedEmailResponse, SendBulkTemplatedEmailError> {
+        (**self).send_bulk_templated_email(input)
+    }
+    fn send_custom_verification_email(
+        &self,
+        input: SendCustomVerificationEmailRequest,
+    ) -> RusotoFuture<SendCustomVerificationEmailResponse, SendCustomVerificationEmailError> {
+        (**self).send_custom_verification_email(input)
+    }
+    fn send_email(
+        &self,
+        input: SendEmailRequest,
+    ) -> RusotoFuture<SendEmailResponse, SendEmailError> {
+        (**self).send_email(input)
+    }
+    fn send_raw_email(
+        &self,
+        input: SendRawEmailRequest,
+    ) -> RusotoFuture<SendRawEmailResponse, SendRawEmailError> {
+        (**self).send_raw_email(input)
+    }
+    fn send_templated_email(
+        &self,
+        input: SendTemplatedEmailRequest,
+    ) -> RusotoFuture<SendTemplatedEmailResponse, SendTemplatedEmailError> {
+        (**self).send_templated_email(input)
+    }
+    fn set_active_receipt_rule_set(
+        &self,
+        input: SetActiveReceiptRuleSetRequest,
+    ) -> RusotoFuture<SetActiveReceiptRuleSetResponse, SetActiveReceiptRuleSetError> {
+        (**self).set_active_receipt_rule_set(input)
+    }
+    fn set_identity_dkim_enabled(
+        &self,
+        input: SetIdentityDkimEnabledRequest,
+    ) -> RusotoFuture<SetIdentityDkimEnabledResponse, SetIdentityDkimEnabledError> {
+        (**self).set_identity_dkim_enabled(input)
+    }
+    fn set_identity_feedback_forwarding_enabled(
+        &self,
+        input: SetIdentityFeedbackForwardingEnabledRequest,
+    ) -> RusotoFuture<
+        SetIdentityFeedbackForwardingEnabledResponse,
+        SetIdentityFeedbackForwardingEnabledError,
+    > {
+        (**self).set_identity_feedback_forwarding_enabled(input)
+    }
+    fn set_identity_headers_in_notifications_enabled(
+        &self,
+        input: SetIdentityHeadersInNotificationsEnabledRequest,
+    ) -> RusotoFuture<
+        SetIdentityHeadersInNotificationsEnabledResponse,
+        SetIdentityHeadersInNotificationsEnabledError,
+    > {
+        (**self).set_identity_headers_in_notifications_enabled(input)
+    }
+    fn set_identity_mail_from_domain(
+        &self,
+        input: SetIdentityMailFromDomainRequest,
+    ) -> RusotoFuture<SetIdentityMailFromDomainResponse, SetIdentityMailFromDomainError> {
+        (**self).set_identity_mail_from_domain(input)
+    }
+    fn set_identity_notification_topic(
+        &self,
+        input: SetIdentityNotificationTopicRequest,
+    ) -> RusotoFuture<SetIdentityNotificationTopicResponse, SetIdentityNotificationTopicError> {
+        (**self).set_identity_notification_topic(input)
+    }
+    fn set_receipt_rule_position(
+        &self,
+        input: SetReceiptRulePositionRequest,
+    ) -> RusotoFuture<SetReceiptRulePositionResponse, SetReceiptRulePositionError> {
+        (**self).set_receipt_rule_position(input)
+    }
+    fn test_render_template(
+        &self,
+        input: TestRenderTemplateRequest,
+    ) -> RusotoFuture<TestRenderTemplateResponse, TestRenderTemplateError> {
+        (**self).test_render_template(input)
+    }
+    fn update_account_sending_enabled(
+        &self,
+        input: UpdateAccountSendingEnabledRequest,
+    ) -> RusotoFuture<(), UpdateAccountSendingEnabledError> {
+        (**self).update_account_sending_enabled(input)
+    }
+    fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        UpdateConfigurationSetEventDestinationResponse,
+        UpdateConfigurationSetEventDestinationError,
+    > {
+        (**self).update_configuration_set_event_destination(input)
+    }
+    fn update_configuration_set_reputation_metrics_enabled(
+        &self,
+        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
+    ) -> RusotoFuture<(), UpdateConfigurationSetReputationMetricsEnabledError> {
+        (**self).update_configuration_set_reputation_metrics_enabled(input)
+    }
+    fn update_configuration_set_sending_enabled(
+        &self,
+        input: UpdateConfigurationSetSendingEnabledRequest,
+    ) -> RusotoFuture<(), UpdateConfigurationSetSendingEnabledError> {
+        (**self).update_configuration_set_sending_enabled(input)
+    }
+    fn update_configuration_set_tracking_options(
+        &self,
+        input: UpdateConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        UpdateConfigurationSetTrackingOptionsResponse,
+        UpdateConfigurationSetTrackingOptionsError,
+    > {
+        (**self).update_configuration_set_tracking_options(input)
+    }
+    fn update_custom_verification_email_template(
+        &self,
+        input: UpdateCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), UpdateCustomVerificationEmailTemplateError> {
+        (**self).update_custom_verification_email_template(input)
+    }
+    fn update_receipt_rule(
+        &self,
+        input: UpdateReceiptRuleRequest,
+    ) -> RusotoFuture<UpdateReceiptRuleResponse, UpdateReceiptRuleError> {
+        (**self).update_receipt_rule(input)
+    }
+    fn update_template(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> RusotoFuture<UpdateTemplateResponse, UpdateTemplateError> {
+        (**self).update_template(input)
+    }
+    fn verify_domain_dkim(
+        &self,
+        input: VerifyDomainDkimRequest,
+    ) -> RusotoFuture<VerifyDomainDkimResponse, VerifyDomainDkimError> {
+        (**self).verify_domain_dkim(input)
+    }
+    fn verify_domain_identity(
+        &self,
+        input: VerifyDomainIdentityRequest,
+    ) -> RusotoFuture<VerifyDomainIdentityResponse, VerifyDomainIdentityError> {
+        (**self).verify_domain_identity(input)
+    }
+    fn verify_email_address(
+        &self,
+        input: VerifyEmailAddressRequest,
+    ) -> RusotoFuture<(), VerifyEmailAddressError> {
+        (**self).verify_email_address(input)
+    }
+    fn verify_email_identity(
+        &self,
+        input: VerifyEmailIdentityRequest,
+    ) -> RusotoFuture<VerifyEmailIdentityResponse, VerifyEmailIdentityError> {
+        (**self).verify_email_identity(input)
+    }
+}
+/// The error codes Amazon SES retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon SES API.
 #[derive(Clone)]
 pub struct SesClient {
@@ -11014,6 +15696,24 @@ impl SesClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SES`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> SesClient {
+        Self::new(region::Region::from_env_for_service("SES"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `email`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> SesClient {
+        Self::new_with_client(config.client().clone(), config.region_for("email", "SES"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -11032,7 +15732,49 @@ impl SesClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SesClient {
-        SesClient { client, region }
+        SesClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "email")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "email", "email", "SES")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Ses` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Ses + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for SesClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon SES"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "email"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> SesClient {
+        Self::new_with_client(client, region)
     }
 }
 
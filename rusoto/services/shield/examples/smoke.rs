@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Shield clients.
+//!
+//! Makes a single read-only `ListAttacks` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_shield::{ListAttacksRequest, Shield, ShieldClient};
+
+fn main() {
+    let client = ShieldClient::from_env();
+    match client.list_attacks(ListAttacksRequest::default()).sync() {
+        Ok(_) => println!("ListAttacks succeeded"),
+        Err(error) => {
+            eprintln!("ListAttacks failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateDRTLogBucketRequest {
@@ -30,6 +31,38 @@ pub struct AssociateDRTLogBucketRequest {
     pub log_bucket: String,
 }
 
+impl AssociateDRTLogBucketRequest {
+    /// Returns a builder for `AssociateDRTLogBucketRequest`, checking its required members when built.
+    pub fn builder() -> AssociateDRTLogBucketRequestBuilder {
+        AssociateDRTLogBucketRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateDRTLogBucketRequest`](struct.AssociateDRTLogBucketRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateDRTLogBucketRequestBuilder {
+    log_bucket: Option<String>,
+}
+
+impl AssociateDRTLogBucketRequestBuilder {
+    /// Sets the [`log_bucket`](struct.AssociateDRTLogBucketRequest.html#structfield.log_bucket) member.
+    pub fn log_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_bucket = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateDRTLogBucketRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateDRTLogBucketRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateDRTLogBucketRequest {
+            log_bucket: self
+                .log_bucket
+                .ok_or(::rusoto_core::BuildError::missing("log_bucket"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateDRTLogBucketResponse {}
@@ -41,6 +74,38 @@ pub struct AssociateDRTRoleRequest {
     pub role_arn: String,
 }
 
+impl AssociateDRTRoleRequest {
+    /// Returns a builder for `AssociateDRTRoleRequest`, checking its required members when built.
+    pub fn builder() -> AssociateDRTRoleRequestBuilder {
+        AssociateDRTRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateDRTRoleRequest`](struct.AssociateDRTRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateDRTRoleRequestBuilder {
+    role_arn: Option<String>,
+}
+
+impl AssociateDRTRoleRequestBuilder {
+    /// Sets the [`role_arn`](struct.AssociateDRTRoleRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateDRTRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateDRTRoleRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateDRTRoleRequest {
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateDRTRoleResponse {}
@@ -63,8 +128,9 @@ pub struct AttackDetail {
     pub attack_properties: Option<Vec<AttackProperty>>,
     /// <p>The time the attack ended, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>List of mitigation actions taken for the attack.</p>
     #[serde(rename = "Mitigations")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -75,14 +141,87 @@ pub struct AttackDetail {
     pub resource_arn: Option<String>,
     /// <p>The time the attack started, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>If applicable, additional detail about the resource being attacked, for example, IP address or URL.</p>
     #[serde(rename = "SubResources")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sub_resources: Option<Vec<SubResourceSummary>>,
 }
 
+/// <p>The type of distributed denial of service (DDoS) event that was observed. <code>NETWORK</code> indicates layer 3 and layer 4 events and <code>APPLICATION</code> indicates layer 7 events.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AttackLayer {
+    /// <p><code>NETWORK</code></p>
+    Network,
+    /// <p><code>APPLICATION</code></p>
+    Application,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AttackLayer {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttackLayer::Network => "NETWORK",
+            AttackLayer::Application => "APPLICATION",
+            AttackLayer::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AttackLayer {
+    fn from(value: &'a str) -> AttackLayer {
+        match value {
+            "NETWORK" => AttackLayer::Network,
+            "APPLICATION" => AttackLayer::Application,
+            _ => AttackLayer::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttackLayer {
+    fn from(value: String) -> AttackLayer {
+        AttackLayer::from(value.as_str())
+    }
+}
+
+impl From<AttackLayer> for String {
+    fn from(value: AttackLayer) -> String {
+        match value {
+            AttackLayer::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AttackLayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AttackLayer {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AttackLayer {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AttackLayer, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AttackLayer::from(value))
+    }
+}
+
 /// <p>Details of the described attack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -90,11 +229,11 @@ pub struct AttackProperty {
     /// <p>The type of distributed denial of service (DDoS) event that was observed. <code>NETWORK</code> indicates layer 3 and layer 4 events and <code>APPLICATION</code> indicates layer 7 events.</p>
     #[serde(rename = "AttackLayer")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attack_layer: Option<String>,
+    pub attack_layer: Option<AttackLayer>,
     /// <p>Defines the DDoS attack property information that is provided. The <code>WORDPRESS_PINGBACK_REFLECTOR</code> and <code>WORDPRESS_PINGBACK_SOURCE</code> values are valid only for WordPress reflective pingback DDoS attacks.</p>
     #[serde(rename = "AttackPropertyIdentifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attack_property_identifier: Option<String>,
+    pub attack_property_identifier: Option<AttackPropertyIdentifier>,
     /// <p>The array of <a>Contributor</a> objects that includes the top five contributors to an attack. </p>
     #[serde(rename = "TopContributors")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -106,7 +245,103 @@ pub struct AttackProperty {
     /// <p>The unit of the <code>Value</code> of the contributions.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<Unit>,
+}
+
+/// <p>Defines the DDoS attack property information that is provided. The <code>WORDPRESS_PINGBACK_REFLECTOR</code> and <code>WORDPRESS_PINGBACK_SOURCE</code> values are valid only for WordPress reflective pingback DDoS attacks.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AttackPropertyIdentifier {
+    /// <p><code>DESTINATION_URL</code></p>
+    DestinationUrl,
+    /// <p><code>REFERRER</code></p>
+    Referrer,
+    /// <p><code>SOURCE_ASN</code></p>
+    SourceAsn,
+    /// <p><code>SOURCE_COUNTRY</code></p>
+    SourceCountry,
+    /// <p><code>SOURCE_IP_ADDRESS</code></p>
+    SourceIpAddress,
+    /// <p><code>SOURCE_USER_AGENT</code></p>
+    SourceUserAgent,
+    /// <p><code>WORDPRESS_PINGBACK_REFLECTOR</code></p>
+    WordpressPingbackReflector,
+    /// <p><code>WORDPRESS_PINGBACK_SOURCE</code></p>
+    WordpressPingbackSource,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AttackPropertyIdentifier {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttackPropertyIdentifier::DestinationUrl => "DESTINATION_URL",
+            AttackPropertyIdentifier::Referrer => "REFERRER",
+            AttackPropertyIdentifier::SourceAsn => "SOURCE_ASN",
+            AttackPropertyIdentifier::SourceCountry => "SOURCE_COUNTRY",
+            AttackPropertyIdentifier::SourceIpAddress => "SOURCE_IP_ADDRESS",
+            AttackPropertyIdentifier::SourceUserAgent => "SOURCE_USER_AGENT",
+            AttackPropertyIdentifier::WordpressPingbackReflector => "WORDPRESS_PINGBACK_REFLECTOR",
+            AttackPropertyIdentifier::WordpressPingbackSource => "WORDPRESS_PINGBACK_SOURCE",
+            AttackPropertyIdentifier::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AttackPropertyIdentifier {
+    fn from(value: &'a str) -> AttackPropertyIdentifier {
+        match value {
+            "DESTINATION_URL" => AttackPropertyIdentifier::DestinationUrl,
+            "REFERRER" => AttackPropertyIdentifier::Referrer,
+            "SOURCE_ASN" => AttackPropertyIdentifier::SourceAsn,
+            "SOURCE_COUNTRY" => AttackPropertyIdentifier::SourceCountry,
+            "SOURCE_IP_ADDRESS" => AttackPropertyIdentifier::SourceIpAddress,
+            "SOURCE_USER_AGENT" => AttackPropertyIdentifier::SourceUserAgent,
+            "WORDPRESS_PINGBACK_REFLECTOR" => AttackPropertyIdentifier::WordpressPingbackReflector,
+            "WORDPRESS_PINGBACK_SOURCE" => AttackPropertyIdentifier::WordpressPingbackSource,
+            _ => AttackPropertyIdentifier::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttackPropertyIdentifier {
+    fn from(value: String) -> AttackPropertyIdentifier {
+        AttackPropertyIdentifier::from(value.as_str())
+    }
+}
+
+impl From<AttackPropertyIdentifier> for String {
+    fn from(value: AttackPropertyIdentifier) -> String {
+        match value {
+            AttackPropertyIdentifier::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AttackPropertyIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AttackPropertyIdentifier {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AttackPropertyIdentifier {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AttackPropertyIdentifier, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AttackPropertyIdentifier::from(value))
+    }
 }
 
 /// <p>Summarizes all DDoS attacks for a specified time period.</p>
@@ -123,16 +358,18 @@ pub struct AttackSummary {
     pub attack_vectors: Option<Vec<AttackVectorDescription>>,
     /// <p>The end time of the attack, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The ARN (Amazon Resource Name) of the resource that was attacked.</p>
     #[serde(rename = "ResourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_arn: Option<String>,
     /// <p>The start time of the attack, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
 }
 
 /// <p>Describes the attack.</p>
@@ -144,6 +381,78 @@ pub struct AttackVectorDescription {
     pub vector_type: String,
 }
 
+/// <p>If <code>ENABLED</code>, the subscription will be automatically renewed at the end of the existing subscription period.</p> <p>When you initally create a subscription, <code>AutoRenew</code> is set to <code>ENABLED</code>. You can change this by submitting an <code>UpdateSubscription</code> request. If the <code>UpdateSubscription</code> request does not included a value for <code>AutoRenew</code>, the existing value for <code>AutoRenew</code> remains unchanged.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AutoRenew {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AutoRenew {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoRenew::Enabled => "ENABLED",
+            AutoRenew::Disabled => "DISABLED",
+            AutoRenew::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AutoRenew {
+    fn from(value: &'a str) -> AutoRenew {
+        match value {
+            "ENABLED" => AutoRenew::Enabled,
+            "DISABLED" => AutoRenew::Disabled,
+            _ => AutoRenew::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoRenew {
+    fn from(value: String) -> AutoRenew {
+        AutoRenew::from(value.as_str())
+    }
+}
+
+impl From<AutoRenew> for String {
+    fn from(value: AutoRenew) -> String {
+        match value {
+            AutoRenew::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AutoRenew {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AutoRenew {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutoRenew {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AutoRenew, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AutoRenew::from(value))
+    }
+}
+
 /// <p>A contributor to the attack and their contribution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -168,6 +477,48 @@ pub struct CreateProtectionRequest {
     pub resource_arn: String,
 }
 
+impl CreateProtectionRequest {
+    /// Returns a builder for `CreateProtectionRequest`, checking its required members when built.
+    pub fn builder() -> CreateProtectionRequestBuilder {
+        CreateProtectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateProtectionRequest`](struct.CreateProtectionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateProtectionRequestBuilder {
+    name: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl CreateProtectionRequestBuilder {
+    /// Sets the [`name`](struct.CreateProtectionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.CreateProtectionRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateProtectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateProtectionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateProtectionRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateProtectionResponse {
@@ -191,6 +542,38 @@ pub struct DeleteProtectionRequest {
     pub protection_id: String,
 }
 
+impl DeleteProtectionRequest {
+    /// Returns a builder for `DeleteProtectionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteProtectionRequestBuilder {
+        DeleteProtectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteProtectionRequest`](struct.DeleteProtectionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteProtectionRequestBuilder {
+    protection_id: Option<String>,
+}
+
+impl DeleteProtectionRequestBuilder {
+    /// Sets the [`protection_id`](struct.DeleteProtectionRequest.html#structfield.protection_id) member.
+    pub fn protection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.protection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteProtectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteProtectionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteProtectionRequest {
+            protection_id: self
+                .protection_id
+                .ok_or(::rusoto_core::BuildError::missing("protection_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteProtectionResponse {}
@@ -209,6 +592,36 @@ pub struct DescribeAttackRequest {
     pub attack_id: String,
 }
 
+impl DescribeAttackRequest {
+    /// Returns a builder for `DescribeAttackRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAttackRequestBuilder {
+        DescribeAttackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAttackRequest`](struct.DescribeAttackRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAttackRequestBuilder {
+    attack_id: Option<String>,
+}
+
+impl DescribeAttackRequestBuilder {
+    /// Sets the [`attack_id`](struct.DescribeAttackRequest.html#structfield.attack_id) member.
+    pub fn attack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.attack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAttackRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeAttackRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAttackRequest {
+            attack_id: self
+                .attack_id
+                .ok_or(::rusoto_core::BuildError::missing("attack_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAttackResponse {
@@ -258,6 +671,44 @@ pub struct DescribeProtectionRequest {
     pub resource_arn: Option<String>,
 }
 
+impl DescribeProtectionRequest {
+    /// Returns a builder for `DescribeProtectionRequest`, checking its required members when built.
+    pub fn builder() -> DescribeProtectionRequestBuilder {
+        DescribeProtectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProtectionRequest`](struct.DescribeProtectionRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeProtectionRequestBuilder {
+    protection_id: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl DescribeProtectionRequestBuilder {
+    /// Sets the [`protection_id`](struct.DescribeProtectionRequest.html#structfield.protection_id) member.
+    pub fn protection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.protection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.DescribeProtectionRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProtectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeProtectionRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeProtectionRequest {
+            protection_id: self.protection_id,
+            resource_arn: self.resource_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeProtectionResponse {
@@ -286,6 +737,38 @@ pub struct DisassociateDRTLogBucketRequest {
     pub log_bucket: String,
 }
 
+impl DisassociateDRTLogBucketRequest {
+    /// Returns a builder for `DisassociateDRTLogBucketRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateDRTLogBucketRequestBuilder {
+        DisassociateDRTLogBucketRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateDRTLogBucketRequest`](struct.DisassociateDRTLogBucketRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateDRTLogBucketRequestBuilder {
+    log_bucket: Option<String>,
+}
+
+impl DisassociateDRTLogBucketRequestBuilder {
+    /// Sets the [`log_bucket`](struct.DisassociateDRTLogBucketRequest.html#structfield.log_bucket) member.
+    pub fn log_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_bucket = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateDRTLogBucketRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateDRTLogBucketRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateDRTLogBucketRequest {
+            log_bucket: self
+                .log_bucket
+                .ok_or(::rusoto_core::BuildError::missing("log_bucket"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateDRTLogBucketResponse {}
@@ -308,12 +791,12 @@ pub struct EmergencyContact {
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetSubscriptionStateRequest {}
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSubscriptionStateResponse {
     /// <p>The status of the subscription.</p>
     #[serde(rename = "SubscriptionState")]
-    pub subscription_state: String,
+    pub subscription_state: SubscriptionState,
 }
 
 /// <p>Specifies how many protections of a given type you can create.</p>
@@ -354,6 +837,66 @@ pub struct ListAttacksRequest {
     pub start_time: Option<TimeRange>,
 }
 
+impl ListAttacksRequest {
+    /// Returns a builder for `ListAttacksRequest`, checking its required members when built.
+    pub fn builder() -> ListAttacksRequestBuilder {
+        ListAttacksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAttacksRequest`](struct.ListAttacksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAttacksRequestBuilder {
+    end_time: Option<TimeRange>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_arns: Option<Vec<String>>,
+    start_time: Option<TimeRange>,
+}
+
+impl ListAttacksRequestBuilder {
+    /// Sets the [`end_time`](struct.ListAttacksRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<TimeRange>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAttacksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAttacksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arns`](struct.ListAttacksRequest.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.ListAttacksRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<TimeRange>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAttacksRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAttacksRequest, ::rusoto_core::BuildError> {
+        Ok(ListAttacksRequest {
+            end_time: self.end_time,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_arns: self.resource_arns,
+            start_time: self.start_time,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAttacksResponse {
@@ -379,6 +922,42 @@ pub struct ListProtectionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListProtectionsRequest {
+    /// Returns a builder for `ListProtectionsRequest`, checking its required members when built.
+    pub fn builder() -> ListProtectionsRequestBuilder {
+        ListProtectionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListProtectionsRequest`](struct.ListProtectionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListProtectionsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListProtectionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListProtectionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListProtectionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListProtectionsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListProtectionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListProtectionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListProtectionsResponse {
@@ -439,7 +1018,79 @@ pub struct SubResourceSummary {
     /// <p>The <code>SubResource</code> type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<SubResourceType>,
+}
+
+/// <p>The <code>SubResource</code> type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SubResourceType {
+    /// <p><code>IP</code></p>
+    Ip,
+    /// <p><code>URL</code></p>
+    Url,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SubResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubResourceType::Ip => "IP",
+            SubResourceType::Url => "URL",
+            SubResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SubResourceType {
+    fn from(value: &'a str) -> SubResourceType {
+        match value {
+            "IP" => SubResourceType::Ip,
+            "URL" => SubResourceType::Url,
+            _ => SubResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubResourceType {
+    fn from(value: String) -> SubResourceType {
+        SubResourceType::from(value.as_str())
+    }
+}
+
+impl From<SubResourceType> for String {
+    fn from(value: SubResourceType) -> String {
+        match value {
+            SubResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SubResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SubResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SubResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SubResourceType::from(value))
+    }
 }
 
 /// <p>Information about the AWS Shield Advanced subscription for an account.</p>
@@ -449,25 +1100,99 @@ pub struct Subscription {
     /// <p>If <code>ENABLED</code>, the subscription will be automatically renewed at the end of the existing subscription period.</p> <p>When you initally create a subscription, <code>AutoRenew</code> is set to <code>ENABLED</code>. You can change this by submitting an <code>UpdateSubscription</code> request. If the <code>UpdateSubscription</code> request does not included a value for <code>AutoRenew</code>, the existing value for <code>AutoRenew</code> remains unchanged.</p>
     #[serde(rename = "AutoRenew")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_renew: Option<String>,
+    pub auto_renew: Option<AutoRenew>,
     /// <p>The date and time your subscription will end.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>Specifies how many protections of a given type you can create.</p>
     #[serde(rename = "Limits")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<Vec<Limit>>,
     /// <p>The start time of the subscription, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>The length, in seconds, of the AWS Shield Advanced subscription for the account.</p>
     #[serde(rename = "TimeCommitmentInSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_commitment_in_seconds: Option<i64>,
 }
 
+/// <p>The status of the subscription.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SubscriptionState {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SubscriptionState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubscriptionState::Active => "ACTIVE",
+            SubscriptionState::Inactive => "INACTIVE",
+            SubscriptionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SubscriptionState {
+    fn from(value: &'a str) -> SubscriptionState {
+        match value {
+            "ACTIVE" => SubscriptionState::Active,
+            "INACTIVE" => SubscriptionState::Inactive,
+            _ => SubscriptionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubscriptionState {
+    fn from(value: String) -> SubscriptionState {
+        SubscriptionState::from(value.as_str())
+    }
+}
+
+impl From<SubscriptionState> for String {
+    fn from(value: SubscriptionState) -> String {
+        match value {
+            SubscriptionState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SubscriptionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SubscriptionState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubscriptionState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SubscriptionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SubscriptionState::from(value))
+    }
+}
+
 /// <p>A summary of information about the attack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -516,12 +1241,94 @@ pub struct SummarizedCounter {
 pub struct TimeRange {
     /// <p>The start time, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "FromInclusive")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub from_inclusive: Option<f64>,
+    pub from_inclusive: Option<EpochTimestamp>,
     /// <p>The end time, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "ToExclusive")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub to_exclusive: Option<f64>,
+    pub to_exclusive: Option<EpochTimestamp>,
+}
+
+/// <p>The unit of the <code>Value</code> of the contributions.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// <p><code>BITS</code></p>
+    Bits,
+    /// <p><code>BYTES</code></p>
+    Bytes,
+    /// <p><code>PACKETS</code></p>
+    Packets,
+    /// <p><code>REQUESTS</code></p>
+    Requests,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Unit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Unit::Bits => "BITS",
+            Unit::Bytes => "BYTES",
+            Unit::Packets => "PACKETS",
+            Unit::Requests => "REQUESTS",
+            Unit::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Unit {
+    fn from(value: &'a str) -> Unit {
+        match value {
+            "BITS" => Unit::Bits,
+            "BYTES" => Unit::Bytes,
+            "PACKETS" => Unit::Packets,
+            "REQUESTS" => Unit::Requests,
+            _ => Unit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Unit {
+    fn from(value: String) -> Unit {
+        Unit::from(value.as_str())
+    }
+}
+
+impl From<Unit> for String {
+    fn from(value: Unit) -> String {
+        match value {
+            Unit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Unit {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Unit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Unit::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -532,6 +1339,37 @@ pub struct UpdateEmergencyContactSettingsRequest {
     pub emergency_contact_list: Option<Vec<EmergencyContact>>,
 }
 
+impl UpdateEmergencyContactSettingsRequest {
+    /// Returns a builder for `UpdateEmergencyContactSettingsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateEmergencyContactSettingsRequestBuilder {
+        UpdateEmergencyContactSettingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateEmergencyContactSettingsRequest`](struct.UpdateEmergencyContactSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateEmergencyContactSettingsRequestBuilder {
+    emergency_contact_list: Option<Vec<EmergencyContact>>,
+}
+
+impl UpdateEmergencyContactSettingsRequestBuilder {
+    /// Sets the [`emergency_contact_list`](struct.UpdateEmergencyContactSettingsRequest.html#structfield.emergency_contact_list) member.
+    pub fn emergency_contact_list<T: Into<Vec<EmergencyContact>>>(mut self, value: T) -> Self {
+        self.emergency_contact_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateEmergencyContactSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateEmergencyContactSettingsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateEmergencyContactSettingsRequest {
+            emergency_contact_list: self.emergency_contact_list,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateEmergencyContactSettingsResponse {}
@@ -541,7 +1379,37 @@ pub struct UpdateSubscriptionRequest {
     /// <p>When you initally create a subscription, <code>AutoRenew</code> is set to <code>ENABLED</code>. If <code>ENABLED</code>, the subscription will be automatically renewed at the end of the existing subscription period. You can change this by submitting an <code>UpdateSubscription</code> request. If the <code>UpdateSubscription</code> request does not included a value for <code>AutoRenew</code>, the existing value for <code>AutoRenew</code> remains unchanged.</p>
     #[serde(rename = "AutoRenew")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_renew: Option<String>,
+    pub auto_renew: Option<AutoRenew>,
+}
+
+impl UpdateSubscriptionRequest {
+    /// Returns a builder for `UpdateSubscriptionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSubscriptionRequestBuilder {
+        UpdateSubscriptionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSubscriptionRequest`](struct.UpdateSubscriptionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSubscriptionRequestBuilder {
+    auto_renew: Option<AutoRenew>,
+}
+
+impl UpdateSubscriptionRequestBuilder {
+    /// Sets the [`auto_renew`](struct.UpdateSubscriptionRequest.html#structfield.auto_renew) member.
+    pub fn auto_renew<T: Into<AutoRenew>>(mut self, value: T) -> Self {
+        self.auto_renew = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSubscriptionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSubscriptionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSubscriptionRequest {
+            auto_renew: self.auto_renew,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1593,6 +2461,118 @@ pub trait Shield {
         input: UpdateSubscriptionRequest,
     ) -> RusotoFuture<UpdateSubscriptionResponse, UpdateSubscriptionError>;
 }
+impl<T: ?Sized + Shield> Shield for ::std::sync::Arc<T> {
+    fn associate_drt_log_bucket(
+        &self,
+        input: AssociateDRTLogBucketRequest,
+    ) -> RusotoFuture<AssociateDRTLogBucketResponse, AssociateDRTLogBucketError> {
+        (**self).associate_drt_log_bucket(input)
+    }
+    fn associate_drt_role(
+        &self,
+        input: AssociateDRTRoleRequest,
+    ) -> RusotoFuture<AssociateDRTRoleResponse, AssociateDRTRoleError> {
+        (**self).associate_drt_role(input)
+    }
+    fn create_protection(
+        &self,
+        input: CreateProtectionRequest,
+    ) -> RusotoFuture<CreateProtectionResponse, CreateProtectionError> {
+        (**self).create_protection(input)
+    }
+    fn create_subscription(
+        &self,
+    ) -> RusotoFuture<CreateSubscriptionResponse, CreateSubscriptionError> {
+        (**self).create_subscription()
+    }
+    fn delete_protection(
+        &self,
+        input: DeleteProtectionRequest,
+    ) -> RusotoFuture<DeleteProtectionResponse, DeleteProtectionError> {
+        (**self).delete_protection(input)
+    }
+    fn delete_subscription(
+        &self,
+    ) -> RusotoFuture<DeleteSubscriptionResponse, DeleteSubscriptionError> {
+        (**self).delete_subscription()
+    }
+    fn describe_attack(
+        &self,
+        input: DescribeAttackRequest,
+    ) -> RusotoFuture<DescribeAttackResponse, DescribeAttackError> {
+        (**self).describe_attack(input)
+    }
+    fn describe_drt_access(
+        &self,
+    ) -> RusotoFuture<DescribeDRTAccessResponse, DescribeDRTAccessError> {
+        (**self).describe_drt_access()
+    }
+    fn describe_emergency_contact_settings(
+        &self,
+    ) -> RusotoFuture<DescribeEmergencyContactSettingsResponse, DescribeEmergencyContactSettingsError>
+    {
+        (**self).describe_emergency_contact_settings()
+    }
+    fn describe_protection(
+        &self,
+        input: DescribeProtectionRequest,
+    ) -> RusotoFuture<DescribeProtectionResponse, DescribeProtectionError> {
+        (**self).describe_protection(input)
+    }
+    fn describe_subscription(
+        &self,
+    ) -> RusotoFuture<DescribeSubscriptionResponse, DescribeSubscriptionError> {
+        (**self).describe_subscription()
+    }
+    fn disassociate_drt_log_bucket(
+        &self,
+        input: DisassociateDRTLogBucketRequest,
+    ) -> RusotoFuture<DisassociateDRTLogBucketResponse, DisassociateDRTLogBucketError> {
+        (**self).disassociate_drt_log_bucket(input)
+    }
+    fn disassociate_drt_role(
+        &self,
+    ) -> RusotoFuture<DisassociateDRTRoleResponse, DisassociateDRTRoleError> {
+        (**self).disassociate_drt_role()
+    }
+    fn get_subscription_state(
+        &self,
+    ) -> RusotoFuture<GetSubscriptionStateResponse, GetSubscriptionStateError> {
+        (**self).get_subscription_state()
+    }
+    fn list_attacks(
+        &self,
+        input: ListAttacksRequest,
+    ) -> RusotoFuture<ListAttacksResponse, ListAttacksError> {
+        (**self).list_attacks(input)
+    }
+    fn list_protections(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> RusotoFuture<ListProtectionsResponse, ListProtectionsError> {
+        (**self).list_protections(input)
+    }
+    fn update_emergency_contact_settings(
+        &self,
+        input: UpdateEmergencyContactSettingsRequest,
+    ) -> RusotoFuture<UpdateEmergencyContactSettingsResponse, UpdateEmergencyContactSettingsError>
+    {
+        (**self).update_emergency_contact_settings(input)
+    }
+    fn update_subscription(
+        &self,
+        input: UpdateSubscriptionRequest,
+    ) -> RusotoFuture<UpdateSubscriptionResponse, UpdateSubscriptionError> {
+        (**self).update_subscription(input)
+    }
+}
+/// The error codes AWS Shield retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Shield API.
 #[derive(Clone)]
 pub struct ShieldClient {
@@ -1608,6 +2588,27 @@ impl ShieldClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SHIELD`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ShieldClient {
+        Self::new(region::Region::from_env_for_service("SHIELD"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `shield`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ShieldClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("shield", "SHIELD"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1626,7 +2627,49 @@ impl ShieldClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ShieldClient {
-        ShieldClient { client, region }
+        ShieldClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "shield")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "shield", "shield", "SHIELD")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Shield` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Shield + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ShieldClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Shield"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "shield"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ShieldClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Server Migration Service clients.
+//!
+//! Makes a single read-only `ListApps` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_sms::{ListAppsRequest, ServerMigrationService, ServerMigrationServiceClient};
+
+fn main() {
+    let client = ServerMigrationServiceClient::from_env();
+    match client.list_apps(ListAppsRequest::default()).sync() {
+        Ok(_) => println!("ListApps succeeded"),
+        Err(error) => {
+            eprintln!("ListApps failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,7 +22,348 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>Launch status of the application.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AppLaunchStatus {
+    /// <p><code>READY_FOR_CONFIGURATION</code></p>
+    ReadyForConfiguration,
+    /// <p><code>CONFIGURATION_IN_PROGRESS</code></p>
+    ConfigurationInProgress,
+    /// <p><code>CONFIGURATION_INVALID</code></p>
+    ConfigurationInvalid,
+    /// <p><code>READY_FOR_LAUNCH</code></p>
+    ReadyForLaunch,
+    /// <p><code>VALIDATION_IN_PROGRESS</code></p>
+    ValidationInProgress,
+    /// <p><code>LAUNCH_PENDING</code></p>
+    LaunchPending,
+    /// <p><code>LAUNCH_IN_PROGRESS</code></p>
+    LaunchInProgress,
+    /// <p><code>LAUNCHED</code></p>
+    Launched,
+    /// <p><code>PARTIALLY_LAUNCHED</code></p>
+    PartiallyLaunched,
+    /// <p><code>DELTA_LAUNCH_IN_PROGRESS</code></p>
+    DeltaLaunchInProgress,
+    /// <p><code>DELTA_LAUNCH_FAILED</code></p>
+    DeltaLaunchFailed,
+    /// <p><code>LAUNCH_FAILED</code></p>
+    LaunchFailed,
+    /// <p><code>TERMINATE_IN_PROGRESS</code></p>
+    TerminateInProgress,
+    /// <p><code>TERMINATE_FAILED</code></p>
+    TerminateFailed,
+    /// <p><code>TERMINATED</code></p>
+    Terminated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AppLaunchStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AppLaunchStatus::ReadyForConfiguration => "READY_FOR_CONFIGURATION",
+            AppLaunchStatus::ConfigurationInProgress => "CONFIGURATION_IN_PROGRESS",
+            AppLaunchStatus::ConfigurationInvalid => "CONFIGURATION_INVALID",
+            AppLaunchStatus::ReadyForLaunch => "READY_FOR_LAUNCH",
+            AppLaunchStatus::ValidationInProgress => "VALIDATION_IN_PROGRESS",
+            AppLaunchStatus::LaunchPending => "LAUNCH_PENDING",
+            AppLaunchStatus::LaunchInProgress => "LAUNCH_IN_PROGRESS",
+            AppLaunchStatus::Launched => "LAUNCHED",
+            AppLaunchStatus::PartiallyLaunched => "PARTIALLY_LAUNCHED",
+            AppLaunchStatus::DeltaLaunchInProgress => "DELTA_LAUNCH_IN_PROGRESS",
+            AppLaunchStatus::DeltaLaunchFailed => "DELTA_LAUNCH_FAILED",
+            AppLaunchStatus::LaunchFailed => "LAUNCH_FAILED",
+            AppLaunchStatus::TerminateInProgress => "TERMINATE_IN_PROGRESS",
+            AppLaunchStatus::TerminateFailed => "TERMINATE_FAILED",
+            AppLaunchStatus::Terminated => "TERMINATED",
+            AppLaunchStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AppLaunchStatus {
+    fn from(value: &'a str) -> AppLaunchStatus {
+        match value {
+            "READY_FOR_CONFIGURATION" => AppLaunchStatus::ReadyForConfiguration,
+            "CONFIGURATION_IN_PROGRESS" => AppLaunchStatus::ConfigurationInProgress,
+            "CONFIGURATION_INVALID" => AppLaunchStatus::ConfigurationInvalid,
+            "READY_FOR_LAUNCH" => AppLaunchStatus::ReadyForLaunch,
+            "VALIDATION_IN_PROGRESS" => AppLaunchStatus::ValidationInProgress,
+            "LAUNCH_PENDING" => AppLaunchStatus::LaunchPending,
+            "LAUNCH_IN_PROGRESS" => AppLaunchStatus::LaunchInProgress,
+            "LAUNCHED" => AppLaunchStatus::Launched,
+            "PARTIALLY_LAUNCHED" => AppLaunchStatus::PartiallyLaunched,
+            "DELTA_LAUNCH_IN_PROGRESS" => AppLaunchStatus::DeltaLaunchInProgress,
+            "DELTA_LAUNCH_FAILED" => AppLaunchStatus::DeltaLaunchFailed,
+            "LAUNCH_FAILED" => AppLaunchStatus::LaunchFailed,
+            "TERMINATE_IN_PROGRESS" => AppLaunchStatus::TerminateInProgress,
+            "TERMINATE_FAILED" => AppLaunchStatus::TerminateFailed,
+            "TERMINATED" => AppLaunchStatus::Terminated,
+            _ => AppLaunchStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AppLaunchStatus {
+    fn from(value: String) -> AppLaunchStatus {
+        AppLaunchStatus::from(value.as_str())
+    }
+}
+
+impl From<AppLaunchStatus> for String {
+    fn from(value: AppLaunchStatus) -> String {
+        match value {
+            AppLaunchStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AppLaunchStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AppLaunchStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AppLaunchStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AppLaunchStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AppLaunchStatus::from(value))
+    }
+}
+
+/// <p>Replication status of the application.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AppReplicationStatus {
+    /// <p><code>READY_FOR_CONFIGURATION</code></p>
+    ReadyForConfiguration,
+    /// <p><code>CONFIGURATION_IN_PROGRESS</code></p>
+    ConfigurationInProgress,
+    /// <p><code>CONFIGURATION_INVALID</code></p>
+    ConfigurationInvalid,
+    /// <p><code>READY_FOR_REPLICATION</code></p>
+    ReadyForReplication,
+    /// <p><code>VALIDATION_IN_PROGRESS</code></p>
+    ValidationInProgress,
+    /// <p><code>REPLICATION_PENDING</code></p>
+    ReplicationPending,
+    /// <p><code>REPLICATION_IN_PROGRESS</code></p>
+    ReplicationInProgress,
+    /// <p><code>REPLICATED</code></p>
+    Replicated,
+    /// <p><code>PARTIALLY_REPLICATED</code></p>
+    PartiallyReplicated,
+    /// <p><code>DELTA_REPLICATION_IN_PROGRESS</code></p>
+    DeltaReplicationInProgress,
+    /// <p><code>DELTA_REPLICATED</code></p>
+    DeltaReplicated,
+    /// <p><code>DELTA_REPLICATION_FAILED</code></p>
+    DeltaReplicationFailed,
+    /// <p><code>REPLICATION_FAILED</code></p>
+    ReplicationFailed,
+    /// <p><code>REPLICATION_STOPPING</code></p>
+    ReplicationStopping,
+    /// <p><code>REPLICATION_STOP_FAILED</code></p>
+    ReplicationStopFailed,
+    /// <p><code>REPLICATION_STOPPED</code></p>
+    ReplicationStopped,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AppReplicationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AppReplicationStatus::ReadyForConfiguration => "READY_FOR_CONFIGURATION",
+            AppReplicationStatus::ConfigurationInProgress => "CONFIGURATION_IN_PROGRESS",
+            AppReplicationStatus::ConfigurationInvalid => "CONFIGURATION_INVALID",
+            AppReplicationStatus::ReadyForReplication => "READY_FOR_REPLICATION",
+            AppReplicationStatus::ValidationInProgress => "VALIDATION_IN_PROGRESS",
+            AppReplicationStatus::ReplicationPending => "REPLICATION_PENDING",
+            AppReplicationStatus::ReplicationInProgress => "REPLICATION_IN_PROGRESS",
+            AppReplicationStatus::Replicated => "REPLICATED",
+            AppReplicationStatus::PartiallyReplicated => "PARTIALLY_REPLICATED",
+            AppReplicationStatus::DeltaReplicationInProgress => "DELTA_REPLICATION_IN_PROGRESS",
+            AppReplicationStatus::DeltaReplicated => "DELTA_REPLICATED",
+            AppReplicationStatus::DeltaReplicationFailed => "DELTA_REPLICATION_FAILED",
+            AppReplicationStatus::ReplicationFailed => "REPLICATION_FAILED",
+            AppReplicationStatus::ReplicationStopping => "REPLICATION_STOPPING",
+            AppReplicationStatus::ReplicationStopFailed => "REPLICATION_STOP_FAILED",
+            AppReplicationStatus::ReplicationStopped => "REPLICATION_STOPPED",
+            AppReplicationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AppReplicationStatus {
+    fn from(value: &'a str) -> AppReplicationStatus {
+        match value {
+            "READY_FOR_CONFIGURATION" => AppReplicationStatus::ReadyForConfiguration,
+            "CONFIGURATION_IN_PROGRESS" => AppReplicationStatus::ConfigurationInProgress,
+            "CONFIGURATION_INVALID" => AppReplicationStatus::ConfigurationInvalid,
+            "READY_FOR_REPLICATION" => AppReplicationStatus::ReadyForReplication,
+            "VALIDATION_IN_PROGRESS" => AppReplicationStatus::ValidationInProgress,
+            "REPLICATION_PENDING" => AppReplicationStatus::ReplicationPending,
+            "REPLICATION_IN_PROGRESS" => AppReplicationStatus::ReplicationInProgress,
+            "REPLICATED" => AppReplicationStatus::Replicated,
+            "PARTIALLY_REPLICATED" => AppReplicationStatus::PartiallyReplicated,
+            "DELTA_REPLICATION_IN_PROGRESS" => AppReplicationStatus::DeltaReplicationInProgress,
+            "DELTA_REPLICATED" => AppReplicationStatus::DeltaReplicated,
+            "DELTA_REPLICATION_FAILED" => AppReplicationStatus::DeltaReplicationFailed,
+            "REPLICATION_FAILED" => AppReplicationStatus::ReplicationFailed,
+            "REPLICATION_STOPPING" => AppReplicationStatus::ReplicationStopping,
+            "REPLICATION_STOP_FAILED" => AppReplicationStatus::ReplicationStopFailed,
+            "REPLICATION_STOPPED" => AppReplicationStatus::ReplicationStopped,
+            _ => AppReplicationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AppReplicationStatus {
+    fn from(value: String) -> AppReplicationStatus {
+        AppReplicationStatus::from(value.as_str())
+    }
+}
+
+impl From<AppReplicationStatus> for String {
+    fn from(value: AppReplicationStatus) -> String {
+        match value {
+            AppReplicationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AppReplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AppReplicationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AppReplicationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AppReplicationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AppReplicationStatus::from(value))
+    }
+}
+
+/// <p>Status of the application.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AppStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// <p><code>DELETE_FAILED</code></p>
+    DeleteFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AppStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AppStatus::Creating => "CREATING",
+            AppStatus::Active => "ACTIVE",
+            AppStatus::Updating => "UPDATING",
+            AppStatus::Deleting => "DELETING",
+            AppStatus::Deleted => "DELETED",
+            AppStatus::DeleteFailed => "DELETE_FAILED",
+            AppStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AppStatus {
+    fn from(value: &'a str) -> AppStatus {
+        match value {
+            "CREATING" => AppStatus::Creating,
+            "ACTIVE" => AppStatus::Active,
+            "UPDATING" => AppStatus::Updating,
+            "DELETING" => AppStatus::Deleting,
+            "DELETED" => AppStatus::Deleted,
+            "DELETE_FAILED" => AppStatus::DeleteFailed,
+            _ => AppStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AppStatus {
+    fn from(value: String) -> AppStatus {
+        AppStatus::from(value.as_str())
+    }
+}
+
+impl From<AppStatus> for String {
+    fn from(value: AppStatus) -> String {
+        match value {
+            AppStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AppStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AppStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AppStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AppStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AppStatus::from(value))
+    }
+}
+
 /// <p>Information about the application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -33,20 +374,23 @@ pub struct AppSummary {
     pub app_id: Option<String>,
     /// <p>Time of creation of this application.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>Description of the application.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>Timestamp of the application's creation.</p>
     #[serde(rename = "lastModified")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<EpochTimestamp>,
     /// <p>Timestamp of the application's most recent successful replication.</p>
     #[serde(rename = "latestReplicationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_replication_time: Option<f64>,
+    pub latest_replication_time: Option<EpochTimestamp>,
     /// <p>Details about the latest launch of the application.</p>
     #[serde(rename = "launchDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -54,7 +398,7 @@ pub struct AppSummary {
     /// <p>Launch status of the application.</p>
     #[serde(rename = "launchStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_status: Option<String>,
+    pub launch_status: Option<AppLaunchStatus>,
     /// <p>A message related to the launch status of the application.</p>
     #[serde(rename = "launchStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -66,7 +410,7 @@ pub struct AppSummary {
     /// <p>Replication status of the application.</p>
     #[serde(rename = "replicationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replication_status: Option<String>,
+    pub replication_status: Option<AppReplicationStatus>,
     /// <p>A message related to the replication status of the application.</p>
     #[serde(rename = "replicationStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -78,7 +422,7 @@ pub struct AppSummary {
     /// <p>Status of the application.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AppStatus>,
     /// <p>A message related to the status of the application</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -99,12 +443,13 @@ pub struct AppSummary {
 pub struct Connector {
     /// <p>The time the connector was associated.</p>
     #[serde(rename = "associatedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub associated_on: Option<f64>,
+    pub associated_on: Option<EpochTimestamp>,
     /// <p>The capabilities of the connector.</p>
     #[serde(rename = "capabilityList")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub capability_list: Option<Vec<String>>,
+    pub capability_list: Option<Vec<ConnectorCapability>>,
     /// <p>The identifier of the connector.</p>
     #[serde(rename = "connectorId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -120,7 +465,7 @@ pub struct Connector {
     /// <p>The status of the connector.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ConnectorStatus>,
     /// <p>The connector version.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -136,7 +481,163 @@ pub struct Connector {
     /// <p>The VM management product.</p>
     #[serde(rename = "vmManagerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vm_manager_type: Option<String>,
+    pub vm_manager_type: Option<VmManagerType>,
+}
+
+/// <p>The capabilities of the connector.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectorCapability {
+    /// <p><code>VSPHERE</code></p>
+    Vsphere,
+    /// <p><code>SCVMM</code></p>
+    Scvmm,
+    /// <p><code>HYPERV-MANAGER</code></p>
+    HypervManager,
+    /// <p><code>SNAPSHOT_BATCHING</code></p>
+    SnapshotBatching,
+    /// <p><code>SMS_OPTIMIZED</code></p>
+    SmsOptimized,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConnectorCapability {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectorCapability::Vsphere => "VSPHERE",
+            ConnectorCapability::Scvmm => "SCVMM",
+            ConnectorCapability::HypervManager => "HYPERV-MANAGER",
+            ConnectorCapability::SnapshotBatching => "SNAPSHOT_BATCHING",
+            ConnectorCapability::SmsOptimized => "SMS_OPTIMIZED",
+            ConnectorCapability::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConnectorCapability {
+    fn from(value: &'a str) -> ConnectorCapability {
+        match value {
+            "VSPHERE" => ConnectorCapability::Vsphere,
+            "SCVMM" => ConnectorCapability::Scvmm,
+            "HYPERV-MANAGER" => ConnectorCapability::HypervManager,
+            "SNAPSHOT_BATCHING" => ConnectorCapability::SnapshotBatching,
+            "SMS_OPTIMIZED" => ConnectorCapability::SmsOptimized,
+            _ => ConnectorCapability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectorCapability {
+    fn from(value: String) -> ConnectorCapability {
+        ConnectorCapability::from(value.as_str())
+    }
+}
+
+impl From<ConnectorCapability> for String {
+    fn from(value: ConnectorCapability) -> String {
+        match value {
+            ConnectorCapability::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConnectorCapability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConnectorCapability {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectorCapability {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConnectorCapability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConnectorCapability::from(value))
+    }
+}
+
+/// <p>The status of the connector.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectorStatus {
+    /// <p><code>HEALTHY</code></p>
+    Healthy,
+    /// <p><code>UNHEALTHY</code></p>
+    Unhealthy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConnectorStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectorStatus::Healthy => "HEALTHY",
+            ConnectorStatus::Unhealthy => "UNHEALTHY",
+            ConnectorStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConnectorStatus {
+    fn from(value: &'a str) -> ConnectorStatus {
+        match value {
+            "HEALTHY" => ConnectorStatus::Healthy,
+            "UNHEALTHY" => ConnectorStatus::Unhealthy,
+            _ => ConnectorStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectorStatus {
+    fn from(value: String) -> ConnectorStatus {
+        ConnectorStatus::from(value.as_str())
+    }
+}
+
+impl From<ConnectorStatus> for String {
+    fn from(value: ConnectorStatus) -> String {
+        match value {
+            ConnectorStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConnectorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConnectorStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectorStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConnectorStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConnectorStatus::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -167,6 +668,74 @@ pub struct CreateAppRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateAppRequest {
+    /// Returns a builder for `CreateAppRequest`, checking its required members when built.
+    pub fn builder() -> CreateAppRequestBuilder {
+        CreateAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAppRequest`](struct.CreateAppRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAppRequestBuilder {
+    client_token: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    role_name: Option<String>,
+    server_groups: Option<Vec<ServerGroup>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateAppRequestBuilder {
+    /// Sets the [`client_token`](struct.CreateAppRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateAppRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateAppRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.CreateAppRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_groups`](struct.CreateAppRequest.html#structfield.server_groups) member.
+    pub fn server_groups<T: Into<Vec<ServerGroup>>>(mut self, value: T) -> Self {
+        self.server_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateAppRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAppRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAppRequest {
+            client_token: self.client_token,
+            description: self.description,
+            name: self.name,
+            role_name: self.role_name,
+            server_groups: self.server_groups,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateAppResponse {
@@ -205,7 +774,7 @@ pub struct CreateReplicationJobRequest {
     /// <p>The license type to be used for the AMI created by a successful replication run.</p>
     #[serde(rename = "licenseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
+    pub license_type: Option<LicenseType>,
     /// <p>The maximum number of SMS-created AMIs to retain. The oldest will be deleted once the maximum number is reached and a new AMI is created.</p>
     #[serde(rename = "numberOfRecentAmisToKeep")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -220,12 +789,119 @@ pub struct CreateReplicationJobRequest {
     pub run_once: Option<bool>,
     /// <p>The seed replication time.</p>
     #[serde(rename = "seedReplicationTime")]
-    pub seed_replication_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub seed_replication_time: EpochTimestamp,
     /// <p>The identifier of the server.</p>
     #[serde(rename = "serverId")]
     pub server_id: String,
 }
 
+impl CreateReplicationJobRequest {
+    /// Returns a builder for `CreateReplicationJobRequest`, checking its required members when built.
+    pub fn builder() -> CreateReplicationJobRequestBuilder {
+        CreateReplicationJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateReplicationJobRequest`](struct.CreateReplicationJobRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateReplicationJobRequestBuilder {
+    description: Option<String>,
+    encrypted: Option<bool>,
+    frequency: Option<i64>,
+    kms_key_id: Option<String>,
+    license_type: Option<LicenseType>,
+    number_of_recent_amis_to_keep: Option<i64>,
+    role_name: Option<String>,
+    run_once: Option<bool>,
+    seed_replication_time: Option<EpochTimestamp>,
+    server_id: Option<String>,
+}
+
+impl CreateReplicationJobRequestBuilder {
+    /// Sets the [`description`](struct.CreateReplicationJobRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`encrypted`](struct.CreateReplicationJobRequest.html#structfield.encrypted) member.
+    pub fn encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`frequency`](struct.CreateReplicationJobRequest.html#structfield.frequency) member.
+    pub fn frequency<T: Into<i64>>(mut self, value: T) -> Self {
+        self.frequency = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateReplicationJobRequest.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_type`](struct.CreateReplicationJobRequest.html#structfield.license_type) member.
+    pub fn license_type<T: Into<LicenseType>>(mut self, value: T) -> Self {
+        self.license_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_recent_amis_to_keep`](struct.CreateReplicationJobRequest.html#structfield.number_of_recent_amis_to_keep) member.
+    pub fn number_of_recent_amis_to_keep<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_recent_amis_to_keep = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.CreateReplicationJobRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`run_once`](struct.CreateReplicationJobRequest.html#structfield.run_once) member.
+    pub fn run_once<T: Into<bool>>(mut self, value: T) -> Self {
+        self.run_once = Some(value.into());
+        self
+    }
+
+    /// Sets the [`seed_replication_time`](struct.CreateReplicationJobRequest.html#structfield.seed_replication_time) member.
+    pub fn seed_replication_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.seed_replication_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_id`](struct.CreateReplicationJobRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateReplicationJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateReplicationJobRequest, ::rusoto_core::BuildError> {
+        Ok(CreateReplicationJobRequest {
+            description: self.description,
+            encrypted: self.encrypted,
+            frequency: self.frequency,
+            kms_key_id: self.kms_key_id,
+            license_type: self.license_type,
+            number_of_recent_amis_to_keep: self.number_of_recent_amis_to_keep,
+            role_name: self.role_name,
+            run_once: self.run_once,
+            seed_replication_time: self
+                .seed_replication_time
+                .ok_or(::rusoto_core::BuildError::missing("seed_replication_time"))?,
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateReplicationJobResponse {
@@ -243,6 +919,36 @@ pub struct DeleteAppLaunchConfigurationRequest {
     pub app_id: Option<String>,
 }
 
+impl DeleteAppLaunchConfigurationRequest {
+    /// Returns a builder for `DeleteAppLaunchConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAppLaunchConfigurationRequestBuilder {
+        DeleteAppLaunchConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAppLaunchConfigurationRequest`](struct.DeleteAppLaunchConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAppLaunchConfigurationRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl DeleteAppLaunchConfigurationRequestBuilder {
+    /// Sets the [`app_id`](struct.DeleteAppLaunchConfigurationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAppLaunchConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAppLaunchConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAppLaunchConfigurationRequest {
+            app_id: self.app_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteAppLaunchConfigurationResponse {}
@@ -255,6 +961,37 @@ pub struct DeleteAppReplicationConfigurationRequest {
     pub app_id: Option<String>,
 }
 
+impl DeleteAppReplicationConfigurationRequest {
+    /// Returns a builder for `DeleteAppReplicationConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAppReplicationConfigurationRequestBuilder {
+        DeleteAppReplicationConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAppReplicationConfigurationRequest`](struct.DeleteAppReplicationConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAppReplicationConfigurationRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl DeleteAppReplicationConfigurationRequestBuilder {
+    /// Sets the [`app_id`](struct.DeleteAppReplicationConfigurationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAppReplicationConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAppReplicationConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteAppReplicationConfigurationRequest {
+            app_id: self.app_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteAppReplicationConfigurationResponse {}
@@ -275,6 +1012,50 @@ pub struct DeleteAppRequest {
     pub force_terminate_app: Option<bool>,
 }
 
+impl DeleteAppRequest {
+    /// Returns a builder for `DeleteAppRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAppRequestBuilder {
+        DeleteAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAppRequest`](struct.DeleteAppRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAppRequestBuilder {
+    app_id: Option<String>,
+    force_stop_app_replication: Option<bool>,
+    force_terminate_app: Option<bool>,
+}
+
+impl DeleteAppRequestBuilder {
+    /// Sets the [`app_id`](struct.DeleteAppRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_stop_app_replication`](struct.DeleteAppRequest.html#structfield.force_stop_app_replication) member.
+    pub fn force_stop_app_replication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_stop_app_replication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_terminate_app`](struct.DeleteAppRequest.html#structfield.force_terminate_app) member.
+    pub fn force_terminate_app<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_terminate_app = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteAppRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAppRequest {
+            app_id: self.app_id,
+            force_stop_app_replication: self.force_stop_app_replication,
+            force_terminate_app: self.force_terminate_app,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteAppResponse {}
@@ -286,6 +1067,38 @@ pub struct DeleteReplicationJobRequest {
     pub replication_job_id: String,
 }
 
+impl DeleteReplicationJobRequest {
+    /// Returns a builder for `DeleteReplicationJobRequest`, checking its required members when built.
+    pub fn builder() -> DeleteReplicationJobRequestBuilder {
+        DeleteReplicationJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteReplicationJobRequest`](struct.DeleteReplicationJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteReplicationJobRequestBuilder {
+    replication_job_id: Option<String>,
+}
+
+impl DeleteReplicationJobRequestBuilder {
+    /// Sets the [`replication_job_id`](struct.DeleteReplicationJobRequest.html#structfield.replication_job_id) member.
+    pub fn replication_job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteReplicationJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteReplicationJobRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteReplicationJobRequest {
+            replication_job_id: self
+                .replication_job_id
+                .ok_or(::rusoto_core::BuildError::missing("replication_job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteReplicationJobResponse {}
@@ -304,6 +1117,38 @@ pub struct DisassociateConnectorRequest {
     pub connector_id: String,
 }
 
+impl DisassociateConnectorRequest {
+    /// Returns a builder for `DisassociateConnectorRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateConnectorRequestBuilder {
+        DisassociateConnectorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateConnectorRequest`](struct.DisassociateConnectorRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateConnectorRequestBuilder {
+    connector_id: Option<String>,
+}
+
+impl DisassociateConnectorRequestBuilder {
+    /// Sets the [`connector_id`](struct.DisassociateConnectorRequest.html#structfield.connector_id) member.
+    pub fn connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateConnectorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateConnectorRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateConnectorRequest {
+            connector_id: self
+                .connector_id
+                .ok_or(::rusoto_core::BuildError::missing("connector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateConnectorResponse {}
@@ -317,7 +1162,45 @@ pub struct GenerateChangeSetRequest {
     /// <p>Format for the change set.</p>
     #[serde(rename = "changesetFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub changeset_format: Option<String>,
+    pub changeset_format: Option<OutputFormat>,
+}
+
+impl GenerateChangeSetRequest {
+    /// Returns a builder for `GenerateChangeSetRequest`, checking its required members when built.
+    pub fn builder() -> GenerateChangeSetRequestBuilder {
+        GenerateChangeSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GenerateChangeSetRequest`](struct.GenerateChangeSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GenerateChangeSetRequestBuilder {
+    app_id: Option<String>,
+    changeset_format: Option<OutputFormat>,
+}
+
+impl GenerateChangeSetRequestBuilder {
+    /// Sets the [`app_id`](struct.GenerateChangeSetRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`changeset_format`](struct.GenerateChangeSetRequest.html#structfield.changeset_format) member.
+    pub fn changeset_format<T: Into<OutputFormat>>(mut self, value: T) -> Self {
+        self.changeset_format = Some(value.into());
+        self
+    }
+
+    /// Builds the `GenerateChangeSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GenerateChangeSetRequest, ::rusoto_core::BuildError> {
+        Ok(GenerateChangeSetRequest {
+            app_id: self.app_id,
+            changeset_format: self.changeset_format,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -338,7 +1221,45 @@ pub struct GenerateTemplateRequest {
     /// <p>Format for generating the Amazon CloudFormation template.</p>
     #[serde(rename = "templateFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub template_format: Option<String>,
+    pub template_format: Option<OutputFormat>,
+}
+
+impl GenerateTemplateRequest {
+    /// Returns a builder for `GenerateTemplateRequest`, checking its required members when built.
+    pub fn builder() -> GenerateTemplateRequestBuilder {
+        GenerateTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GenerateTemplateRequest`](struct.GenerateTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct GenerateTemplateRequestBuilder {
+    app_id: Option<String>,
+    template_format: Option<OutputFormat>,
+}
+
+impl GenerateTemplateRequestBuilder {
+    /// Sets the [`app_id`](struct.GenerateTemplateRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_format`](struct.GenerateTemplateRequest.html#structfield.template_format) member.
+    pub fn template_format<T: Into<OutputFormat>>(mut self, value: T) -> Self {
+        self.template_format = Some(value.into());
+        self
+    }
+
+    /// Builds the `GenerateTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GenerateTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(GenerateTemplateRequest {
+            app_id: self.app_id,
+            template_format: self.template_format,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -358,6 +1279,36 @@ pub struct GetAppLaunchConfigurationRequest {
     pub app_id: Option<String>,
 }
 
+impl GetAppLaunchConfigurationRequest {
+    /// Returns a builder for `GetAppLaunchConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetAppLaunchConfigurationRequestBuilder {
+        GetAppLaunchConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAppLaunchConfigurationRequest`](struct.GetAppLaunchConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAppLaunchConfigurationRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl GetAppLaunchConfigurationRequestBuilder {
+    /// Sets the [`app_id`](struct.GetAppLaunchConfigurationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAppLaunchConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAppLaunchConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(GetAppLaunchConfigurationRequest {
+            app_id: self.app_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAppLaunchConfigurationResponse {
@@ -383,6 +1334,37 @@ pub struct GetAppReplicationConfigurationRequest {
     pub app_id: Option<String>,
 }
 
+impl GetAppReplicationConfigurationRequest {
+    /// Returns a builder for `GetAppReplicationConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetAppReplicationConfigurationRequestBuilder {
+        GetAppReplicationConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAppReplicationConfigurationRequest`](struct.GetAppReplicationConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAppReplicationConfigurationRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl GetAppReplicationConfigurationRequestBuilder {
+    /// Sets the [`app_id`](struct.GetAppReplicationConfigurationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAppReplicationConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAppReplicationConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetAppReplicationConfigurationRequest {
+            app_id: self.app_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAppReplicationConfigurationResponse {
@@ -400,6 +1382,34 @@ pub struct GetAppRequest {
     pub app_id: Option<String>,
 }
 
+impl GetAppRequest {
+    /// Returns a builder for `GetAppRequest`, checking its required members when built.
+    pub fn builder() -> GetAppRequestBuilder {
+        GetAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAppRequest`](struct.GetAppRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAppRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl GetAppRequestBuilder {
+    /// Sets the [`app_id`](struct.GetAppRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAppRequest, ::rusoto_core::BuildError> {
+        Ok(GetAppRequest {
+            app_id: self.app_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAppResponse {
@@ -429,6 +1439,42 @@ pub struct GetConnectorsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetConnectorsRequest {
+    /// Returns a builder for `GetConnectorsRequest`, checking its required members when built.
+    pub fn builder() -> GetConnectorsRequestBuilder {
+        GetConnectorsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetConnectorsRequest`](struct.GetConnectorsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetConnectorsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetConnectorsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetConnectorsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetConnectorsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetConnectorsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetConnectorsRequest, ::rusoto_core::BuildError> {
+        Ok(GetConnectorsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetConnectorsResponse {
@@ -458,6 +1504,52 @@ pub struct GetReplicationJobsRequest {
     pub replication_job_id: Option<String>,
 }
 
+impl GetReplicationJobsRequest {
+    /// Returns a builder for `GetReplicationJobsRequest`, checking its required members when built.
+    pub fn builder() -> GetReplicationJobsRequestBuilder {
+        GetReplicationJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetReplicationJobsRequest`](struct.GetReplicationJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetReplicationJobsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    replication_job_id: Option<String>,
+}
+
+impl GetReplicationJobsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetReplicationJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetReplicationJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_job_id`](struct.GetReplicationJobsRequest.html#structfield.replication_job_id) member.
+    pub fn replication_job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetReplicationJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetReplicationJobsRequest, ::rusoto_core::BuildError> {
+        Ok(GetReplicationJobsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            replication_job_id: self.replication_job_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetReplicationJobsResponse {
@@ -486,6 +1578,54 @@ pub struct GetReplicationRunsRequest {
     pub replication_job_id: String,
 }
 
+impl GetReplicationRunsRequest {
+    /// Returns a builder for `GetReplicationRunsRequest`, checking its required members when built.
+    pub fn builder() -> GetReplicationRunsRequestBuilder {
+        GetReplicationRunsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetReplicationRunsRequest`](struct.GetReplicationRunsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetReplicationRunsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    replication_job_id: Option<String>,
+}
+
+impl GetReplicationRunsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetReplicationRunsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetReplicationRunsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_job_id`](struct.GetReplicationRunsRequest.html#structfield.replication_job_id) member.
+    pub fn replication_job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetReplicationRunsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetReplicationRunsRequest, ::rusoto_core::BuildError> {
+        Ok(GetReplicationRunsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            replication_job_id: self
+                .replication_job_id
+                .ok_or(::rusoto_core::BuildError::missing("replication_job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetReplicationRunsResponse {
@@ -519,13 +1659,58 @@ pub struct GetServersRequest {
     pub vm_server_address_list: Option<Vec<VmServerAddress>>,
 }
 
+impl GetServersRequest {
+    /// Returns a builder for `GetServersRequest`, checking its required members when built.
+    pub fn builder() -> GetServersRequestBuilder {
+        GetServersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetServersRequest`](struct.GetServersRequest.html).
+#[derive(Default, Debug)]
+pub struct GetServersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    vm_server_address_list: Option<Vec<VmServerAddress>>,
+}
+
+impl GetServersRequestBuilder {
+    /// Sets the [`max_results`](struct.GetServersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetServersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vm_server_address_list`](struct.GetServersRequest.html#structfield.vm_server_address_list) member.
+    pub fn vm_server_address_list<T: Into<Vec<VmServerAddress>>>(mut self, value: T) -> Self {
+        self.vm_server_address_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetServersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetServersRequest, ::rusoto_core::BuildError> {
+        Ok(GetServersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            vm_server_address_list: self.vm_server_address_list,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetServersResponse {
     /// <p>The time when the server was last modified.</p>
     #[serde(rename = "lastModifiedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<EpochTimestamp>,
     /// <p>The token required to retrieve the next set of results. This value is null when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -533,7 +1718,7 @@ pub struct GetServersResponse {
     /// <p>The status of the server catalog.</p>
     #[serde(rename = "serverCatalogStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub server_catalog_status: Option<String>,
+    pub server_catalog_status: Option<ServerCatalogStatus>,
     /// <p>Information about the servers.</p>
     #[serde(rename = "serverList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -555,6 +1740,34 @@ pub struct LaunchAppRequest {
     pub app_id: Option<String>,
 }
 
+impl LaunchAppRequest {
+    /// Returns a builder for `LaunchAppRequest`, checking its required members when built.
+    pub fn builder() -> LaunchAppRequestBuilder {
+        LaunchAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`LaunchAppRequest`](struct.LaunchAppRequest.html).
+#[derive(Default, Debug)]
+pub struct LaunchAppRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl LaunchAppRequestBuilder {
+    /// Sets the [`app_id`](struct.LaunchAppRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `LaunchAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<LaunchAppRequest, ::rusoto_core::BuildError> {
+        Ok(LaunchAppRequest {
+            app_id: self.app_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct LaunchAppResponse {}
@@ -565,8 +1778,9 @@ pub struct LaunchAppResponse {}
 pub struct LaunchDetails {
     /// <p>Latest time this application was launched successfully.</p>
     #[serde(rename = "latestLaunchTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_launch_time: Option<f64>,
+    pub latest_launch_time: Option<EpochTimestamp>,
     /// <p>Identifier of the latest stack launched for this application.</p>
     #[serde(rename = "stackId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -577,6 +1791,78 @@ pub struct LaunchDetails {
     pub stack_name: Option<String>,
 }
 
+/// <p>The license type to be used for the AMI created by a successful replication run.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LicenseType {
+    /// <p><code>AWS</code></p>
+    Aws,
+    /// <p><code>BYOL</code></p>
+    Byol,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LicenseType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LicenseType::Aws => "AWS",
+            LicenseType::Byol => "BYOL",
+            LicenseType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LicenseType {
+    fn from(value: &'a str) -> LicenseType {
+        match value {
+            "AWS" => LicenseType::Aws,
+            "BYOL" => LicenseType::Byol,
+            _ => LicenseType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LicenseType {
+    fn from(value: String) -> LicenseType {
+        LicenseType::from(value.as_str())
+    }
+}
+
+impl From<LicenseType> for String {
+    fn from(value: LicenseType) -> String {
+        match value {
+            LicenseType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LicenseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LicenseType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LicenseType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LicenseType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LicenseType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListAppsRequest {
     /// <p><p/></p>
@@ -593,6 +1879,50 @@ pub struct ListAppsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListAppsRequest {
+    /// Returns a builder for `ListAppsRequest`, checking its required members when built.
+    pub fn builder() -> ListAppsRequestBuilder {
+        ListAppsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAppsRequest`](struct.ListAppsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAppsRequestBuilder {
+    app_ids: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAppsRequestBuilder {
+    /// Sets the [`app_ids`](struct.ListAppsRequest.html#structfield.app_ids) member.
+    pub fn app_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.app_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAppsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAppsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAppsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAppsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAppsRequest {
+            app_ids: self.app_ids,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAppsResponse {
@@ -606,6 +1936,78 @@ pub struct ListAppsResponse {
     pub next_token: Option<String>,
 }
 
+/// <p>Format for the change set.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OutputFormat {
+    /// <p><code>JSON</code></p>
+    Json,
+    /// <p><code>YAML</code></p>
+    Yaml,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OutputFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OutputFormat::Json => "JSON",
+            OutputFormat::Yaml => "YAML",
+            OutputFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OutputFormat {
+    fn from(value: &'a str) -> OutputFormat {
+        match value {
+            "JSON" => OutputFormat::Json,
+            "YAML" => OutputFormat::Yaml,
+            _ => OutputFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OutputFormat {
+    fn from(value: String) -> OutputFormat {
+        OutputFormat::from(value.as_str())
+    }
+}
+
+impl From<OutputFormat> for String {
+    fn from(value: OutputFormat) -> String {
+        match value {
+            OutputFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OutputFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OutputFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OutputFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OutputFormat::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutAppLaunchConfigurationRequest {
     /// <p>ID of the application associated with the launch configuration.</p>
@@ -622,6 +2024,55 @@ pub struct PutAppLaunchConfigurationRequest {
     pub server_group_launch_configurations: Option<Vec<ServerGroupLaunchConfiguration>>,
 }
 
+impl PutAppLaunchConfigurationRequest {
+    /// Returns a builder for `PutAppLaunchConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> PutAppLaunchConfigurationRequestBuilder {
+        PutAppLaunchConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutAppLaunchConfigurationRequest`](struct.PutAppLaunchConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutAppLaunchConfigurationRequestBuilder {
+    app_id: Option<String>,
+    role_name: Option<String>,
+    server_group_launch_configurations: Option<Vec<ServerGroupLaunchConfiguration>>,
+}
+
+impl PutAppLaunchConfigurationRequestBuilder {
+    /// Sets the [`app_id`](struct.PutAppLaunchConfigurationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.PutAppLaunchConfigurationRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_group_launch_configurations`](struct.PutAppLaunchConfigurationRequest.html#structfield.server_group_launch_configurations) member.
+    pub fn server_group_launch_configurations<T: Into<Vec<ServerGroupLaunchConfiguration>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.server_group_launch_configurations = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutAppLaunchConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutAppLaunchConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(PutAppLaunchConfigurationRequest {
+            app_id: self.app_id,
+            role_name: self.role_name,
+            server_group_launch_configurations: self.server_group_launch_configurations,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutAppLaunchConfigurationResponse {}
@@ -638,6 +2089,50 @@ pub struct PutAppReplicationConfigurationRequest {
     pub server_group_replication_configurations: Option<Vec<ServerGroupReplicationConfiguration>>,
 }
 
+impl PutAppReplicationConfigurationRequest {
+    /// Returns a builder for `PutAppReplicationConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> PutAppReplicationConfigurationRequestBuilder {
+        PutAppReplicationConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutAppReplicationConfigurationRequest`](struct.PutAppReplicationConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutAppReplicationConfigurationRequestBuilder {
+    app_id: Option<String>,
+    server_group_replication_configurations: Option<Vec<ServerGroupReplicationConfiguration>>,
+}
+
+impl PutAppReplicationConfigurationRequestBuilder {
+    /// Sets the [`app_id`](struct.PutAppReplicationConfigurationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_group_replication_configurations`](struct.PutAppReplicationConfigurationRequest.html#structfield.server_group_replication_configurations) member.
+    pub fn server_group_replication_configurations<
+        T: Into<Vec<ServerGroupReplicationConfiguration>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.server_group_replication_configurations = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutAppReplicationConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutAppReplicationConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(PutAppReplicationConfigurationRequest {
+            app_id: self.app_id,
+            server_group_replication_configurations: self.server_group_replication_configurations,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutAppReplicationConfigurationResponse {}
@@ -669,11 +2164,12 @@ pub struct ReplicationJob {
     /// <p>The license type to be used for the AMI created by a successful replication run.</p>
     #[serde(rename = "licenseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
+    pub license_type: Option<LicenseType>,
     /// <p>The start time of the next replication run.</p>
     #[serde(rename = "nextReplicationRunStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_replication_run_start_time: Option<f64>,
+    pub next_replication_run_start_time: Option<EpochTimestamp>,
     /// <p>Number of recent AMIs to keep in the customer's account for a replication job. By default the value is set to zero, meaning that all AMIs are kept.</p>
     #[serde(rename = "numberOfRecentAmisToKeep")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -696,8 +2192,9 @@ pub struct ReplicationJob {
     pub run_once: Option<bool>,
     /// <p>The seed replication time.</p>
     #[serde(rename = "seedReplicationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub seed_replication_time: Option<f64>,
+    pub seed_replication_time: Option<EpochTimestamp>,
     /// <p>The identifier of the server.</p>
     #[serde(rename = "serverId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -705,11 +2202,11 @@ pub struct ReplicationJob {
     /// <p>The type of server.</p>
     #[serde(rename = "serverType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub server_type: Option<String>,
+    pub server_type: Option<ServerType>,
     /// <p>The state of the replication job.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ReplicationJobState>,
     /// <p>The description of the current status of the replication job.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -720,6 +2217,102 @@ pub struct ReplicationJob {
     pub vm_server: Option<VmServer>,
 }
 
+/// <p>The state of the replication job.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReplicationJobState {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>PAUSED_ON_FAILURE</code></p>
+    PausedOnFailure,
+    /// <p><code>FAILING</code></p>
+    Failing,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReplicationJobState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationJobState::Pending => "PENDING",
+            ReplicationJobState::Active => "ACTIVE",
+            ReplicationJobState::Failed => "FAILED",
+            ReplicationJobState::Deleting => "DELETING",
+            ReplicationJobState::Deleted => "DELETED",
+            ReplicationJobState::Completed => "COMPLETED",
+            ReplicationJobState::PausedOnFailure => "PAUSED_ON_FAILURE",
+            ReplicationJobState::Failing => "FAILING",
+            ReplicationJobState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationJobState {
+    fn from(value: &'a str) -> ReplicationJobState {
+        match value {
+            "PENDING" => ReplicationJobState::Pending,
+            "ACTIVE" => ReplicationJobState::Active,
+            "FAILED" => ReplicationJobState::Failed,
+            "DELETING" => ReplicationJobState::Deleting,
+            "DELETED" => ReplicationJobState::Deleted,
+            "COMPLETED" => ReplicationJobState::Completed,
+            "PAUSED_ON_FAILURE" => ReplicationJobState::PausedOnFailure,
+            "FAILING" => ReplicationJobState::Failing,
+            _ => ReplicationJobState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationJobState {
+    fn from(value: String) -> ReplicationJobState {
+        ReplicationJobState::from(value.as_str())
+    }
+}
+
+impl From<ReplicationJobState> for String {
+    fn from(value: ReplicationJobState) -> String {
+        match value {
+            ReplicationJobState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReplicationJobState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReplicationJobState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicationJobState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReplicationJobState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReplicationJobState::from(value))
+    }
+}
+
 /// <p>Represents a replication run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -730,8 +2323,9 @@ pub struct ReplicationRun {
     pub ami_id: Option<String>,
     /// <p>The completion time of the last replication run.</p>
     #[serde(rename = "completedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_time: Option<f64>,
+    pub completed_time: Option<EpochTimestamp>,
     /// <p>The description of the replication run.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -750,8 +2344,9 @@ pub struct ReplicationRun {
     pub replication_run_id: Option<String>,
     /// <p>The start time of the next replication run.</p>
     #[serde(rename = "scheduledStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduled_start_time: Option<f64>,
+    pub scheduled_start_time: Option<EpochTimestamp>,
     /// <p>Details of the current stage of the replication run.</p>
     #[serde(rename = "stageDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -759,7 +2354,7 @@ pub struct ReplicationRun {
     /// <p>The state of the replication run.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ReplicationRunState>,
     /// <p>The description of the current status of the replication job.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -767,7 +2362,7 @@ pub struct ReplicationRun {
     /// <p>The type of replication run.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ReplicationRunType>,
 }
 
 /// <p>Details of the current stage of a replication run.</p>
@@ -784,6 +2379,170 @@ pub struct ReplicationRunStageDetails {
     pub stage_progress: Option<String>,
 }
 
+/// <p>The state of the replication run.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReplicationRunState {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>MISSED</code></p>
+    Missed,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReplicationRunState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationRunState::Pending => "PENDING",
+            ReplicationRunState::Missed => "MISSED",
+            ReplicationRunState::Active => "ACTIVE",
+            ReplicationRunState::Failed => "FAILED",
+            ReplicationRunState::Completed => "COMPLETED",
+            ReplicationRunState::Deleting => "DELETING",
+            ReplicationRunState::Deleted => "DELETED",
+            ReplicationRunState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationRunState {
+    fn from(value: &'a str) -> ReplicationRunState {
+        match value {
+            "PENDING" => ReplicationRunState::Pending,
+            "MISSED" => ReplicationRunState::Missed,
+            "ACTIVE" => ReplicationRunState::Active,
+            "FAILED" => ReplicationRunState::Failed,
+            "COMPLETED" => ReplicationRunState::Completed,
+            "DELETING" => ReplicationRunState::Deleting,
+            "DELETED" => ReplicationRunState::Deleted,
+            _ => ReplicationRunState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationRunState {
+    fn from(value: String) -> ReplicationRunState {
+        ReplicationRunState::from(value.as_str())
+    }
+}
+
+impl From<ReplicationRunState> for String {
+    fn from(value: ReplicationRunState) -> String {
+        match value {
+            ReplicationRunState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReplicationRunState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReplicationRunState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicationRunState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReplicationRunState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReplicationRunState::from(value))
+    }
+}
+
+/// <p>The type of replication run.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReplicationRunType {
+    /// <p><code>ON_DEMAND</code></p>
+    OnDemand,
+    /// <p><code>AUTOMATIC</code></p>
+    Automatic,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReplicationRunType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationRunType::OnDemand => "ON_DEMAND",
+            ReplicationRunType::Automatic => "AUTOMATIC",
+            ReplicationRunType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationRunType {
+    fn from(value: &'a str) -> ReplicationRunType {
+        match value {
+            "ON_DEMAND" => ReplicationRunType::OnDemand,
+            "AUTOMATIC" => ReplicationRunType::Automatic,
+            _ => ReplicationRunType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationRunType {
+    fn from(value: String) -> ReplicationRunType {
+        ReplicationRunType::from(value.as_str())
+    }
+}
+
+impl From<ReplicationRunType> for String {
+    fn from(value: ReplicationRunType) -> String {
+        match value {
+            ReplicationRunType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReplicationRunType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReplicationRunType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicationRunType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReplicationRunType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReplicationRunType::from(value))
+    }
+}
+
 /// <p>Location of the Amazon S3 object in the customer's account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3Location {
@@ -815,13 +2574,97 @@ pub struct Server {
     /// <p>The type of server.</p>
     #[serde(rename = "serverType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub server_type: Option<String>,
+    pub server_type: Option<ServerType>,
     /// <p>Information about the VM server.</p>
     #[serde(rename = "vmServer")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vm_server: Option<VmServer>,
 }
 
+/// <p>The status of the server catalog.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServerCatalogStatus {
+    /// <p><code>NOT_IMPORTED</code></p>
+    NotImported,
+    /// <p><code>IMPORTING</code></p>
+    Importing,
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// <p><code>EXPIRED</code></p>
+    Expired,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServerCatalogStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServerCatalogStatus::NotImported => "NOT_IMPORTED",
+            ServerCatalogStatus::Importing => "IMPORTING",
+            ServerCatalogStatus::Available => "AVAILABLE",
+            ServerCatalogStatus::Deleted => "DELETED",
+            ServerCatalogStatus::Expired => "EXPIRED",
+            ServerCatalogStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServerCatalogStatus {
+    fn from(value: &'a str) -> ServerCatalogStatus {
+        match value {
+            "NOT_IMPORTED" => ServerCatalogStatus::NotImported,
+            "IMPORTING" => ServerCatalogStatus::Importing,
+            "AVAILABLE" => ServerCatalogStatus::Available,
+            "DELETED" => ServerCatalogStatus::Deleted,
+            "EXPIRED" => ServerCatalogStatus::Expired,
+            _ => ServerCatalogStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServerCatalogStatus {
+    fn from(value: String) -> ServerCatalogStatus {
+        ServerCatalogStatus::from(value.as_str())
+    }
+}
+
+impl From<ServerCatalogStatus> for String {
+    fn from(value: ServerCatalogStatus) -> String {
+        match value {
+            ServerCatalogStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServerCatalogStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServerCatalogStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServerCatalogStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ServerCatalogStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServerCatalogStatus::from(value))
+    }
+}
+
 /// <p>A logical grouping of servers.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServerGroup {
@@ -941,7 +2784,7 @@ pub struct ServerReplicationParameters {
     /// <p>License type for creating a replication job for the server.</p>
     #[serde(rename = "licenseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
+    pub license_type: Option<LicenseType>,
     /// <p>Number of recent AMIs to keep when creating a replication job for this server.</p>
     #[serde(rename = "numberOfRecentAmisToKeep")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -952,8 +2795,77 @@ pub struct ServerReplicationParameters {
     pub run_once: Option<bool>,
     /// <p>Seed time for creating a replication job for the server.</p>
     #[serde(rename = "seedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub seed_time: Option<f64>,
+    pub seed_time: Option<EpochTimestamp>,
+}
+
+/// <p>The type of server.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServerType {
+    /// <p><code>VIRTUAL_MACHINE</code></p>
+    VirtualMachine,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServerType::VirtualMachine => "VIRTUAL_MACHINE",
+            ServerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServerType {
+    fn from(value: &'a str) -> ServerType {
+        match value {
+            "VIRTUAL_MACHINE" => ServerType::VirtualMachine,
+            _ => ServerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServerType {
+    fn from(value: String) -> ServerType {
+        ServerType::from(value.as_str())
+    }
+}
+
+impl From<ServerType> for String {
+    fn from(value: ServerType) -> String {
+        match value {
+            ServerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ServerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServerType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -964,6 +2876,36 @@ pub struct StartAppReplicationRequest {
     pub app_id: Option<String>,
 }
 
+impl StartAppReplicationRequest {
+    /// Returns a builder for `StartAppReplicationRequest`, checking its required members when built.
+    pub fn builder() -> StartAppReplicationRequestBuilder {
+        StartAppReplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartAppReplicationRequest`](struct.StartAppReplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct StartAppReplicationRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl StartAppReplicationRequestBuilder {
+    /// Sets the [`app_id`](struct.StartAppReplicationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartAppReplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartAppReplicationRequest, ::rusoto_core::BuildError> {
+        Ok(StartAppReplicationRequest {
+            app_id: self.app_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartAppReplicationResponse {}
@@ -979,6 +2921,46 @@ pub struct StartOnDemandReplicationRunRequest {
     pub replication_job_id: String,
 }
 
+impl StartOnDemandReplicationRunRequest {
+    /// Returns a builder for `StartOnDemandReplicationRunRequest`, checking its required members when built.
+    pub fn builder() -> StartOnDemandReplicationRunRequestBuilder {
+        StartOnDemandReplicationRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartOnDemandReplicationRunRequest`](struct.StartOnDemandReplicationRunRequest.html).
+#[derive(Default, Debug)]
+pub struct StartOnDemandReplicationRunRequestBuilder {
+    description: Option<String>,
+    replication_job_id: Option<String>,
+}
+
+impl StartOnDemandReplicationRunRequestBuilder {
+    /// Sets the [`description`](struct.StartOnDemandReplicationRunRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_job_id`](struct.StartOnDemandReplicationRunRequest.html#structfield.replication_job_id) member.
+    pub fn replication_job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartOnDemandReplicationRunRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartOnDemandReplicationRunRequest, ::rusoto_core::BuildError> {
+        Ok(StartOnDemandReplicationRunRequest {
+            description: self.description,
+            replication_job_id: self
+                .replication_job_id
+                .ok_or(::rusoto_core::BuildError::missing("replication_job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartOnDemandReplicationRunResponse {
@@ -996,6 +2978,36 @@ pub struct StopAppReplicationRequest {
     pub app_id: Option<String>,
 }
 
+impl StopAppReplicationRequest {
+    /// Returns a builder for `StopAppReplicationRequest`, checking its required members when built.
+    pub fn builder() -> StopAppReplicationRequestBuilder {
+        StopAppReplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopAppReplicationRequest`](struct.StopAppReplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct StopAppReplicationRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl StopAppReplicationRequestBuilder {
+    /// Sets the [`app_id`](struct.StopAppReplicationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopAppReplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopAppReplicationRequest, ::rusoto_core::BuildError> {
+        Ok(StopAppReplicationRequest {
+            app_id: self.app_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopAppReplicationResponse {}
@@ -1021,6 +3033,34 @@ pub struct TerminateAppRequest {
     pub app_id: Option<String>,
 }
 
+impl TerminateAppRequest {
+    /// Returns a builder for `TerminateAppRequest`, checking its required members when built.
+    pub fn builder() -> TerminateAppRequestBuilder {
+        TerminateAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TerminateAppRequest`](struct.TerminateAppRequest.html).
+#[derive(Default, Debug)]
+pub struct TerminateAppRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl TerminateAppRequestBuilder {
+    /// Sets the [`app_id`](struct.TerminateAppRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `TerminateAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TerminateAppRequest, ::rusoto_core::BuildError> {
+        Ok(TerminateAppRequest {
+            app_id: self.app_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TerminateAppResponse {}
@@ -1053,6 +3093,74 @@ pub struct UpdateAppRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl UpdateAppRequest {
+    /// Returns a builder for `UpdateAppRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAppRequestBuilder {
+        UpdateAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAppRequest`](struct.UpdateAppRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAppRequestBuilder {
+    app_id: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    role_name: Option<String>,
+    server_groups: Option<Vec<ServerGroup>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl UpdateAppRequestBuilder {
+    /// Sets the [`app_id`](struct.UpdateAppRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateAppRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateAppRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.UpdateAppRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_groups`](struct.UpdateAppRequest.html#structfield.server_groups) member.
+    pub fn server_groups<T: Into<Vec<ServerGroup>>>(mut self, value: T) -> Self {
+        self.server_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.UpdateAppRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateAppRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAppRequest {
+            app_id: self.app_id,
+            description: self.description,
+            name: self.name,
+            role_name: self.role_name,
+            server_groups: self.server_groups,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateAppResponse {
@@ -1091,11 +3199,12 @@ pub struct UpdateReplicationJobRequest {
     /// <p>The license type to be used for the AMI created by a successful replication run.</p>
     #[serde(rename = "licenseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
+    pub license_type: Option<LicenseType>,
     /// <p>The start time of the next replication run.</p>
     #[serde(rename = "nextReplicationRunStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_replication_run_start_time: Option<f64>,
+    pub next_replication_run_start_time: Option<EpochTimestamp>,
     /// <p>The maximum number of SMS-created AMIs to retain. The oldest will be deleted once the maximum number is reached and a new AMI is created.</p>
     #[serde(rename = "numberOfRecentAmisToKeep")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1109,6 +3218,102 @@ pub struct UpdateReplicationJobRequest {
     pub role_name: Option<String>,
 }
 
+impl UpdateReplicationJobRequest {
+    /// Returns a builder for `UpdateReplicationJobRequest`, checking its required members when built.
+    pub fn builder() -> UpdateReplicationJobRequestBuilder {
+        UpdateReplicationJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateReplicationJobRequest`](struct.UpdateReplicationJobRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateReplicationJobRequestBuilder {
+    description: Option<String>,
+    encrypted: Option<bool>,
+    frequency: Option<i64>,
+    kms_key_id: Option<String>,
+    license_type: Option<LicenseType>,
+    next_replication_run_start_time: Option<EpochTimestamp>,
+    number_of_recent_amis_to_keep: Option<i64>,
+    replication_job_id: Option<String>,
+    role_name: Option<String>,
+}
+
+impl UpdateReplicationJobRequestBuilder {
+    /// Sets the [`description`](struct.UpdateReplicationJobRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`encrypted`](struct.UpdateReplicationJobRequest.html#structfield.encrypted) member.
+    pub fn encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`frequency`](struct.UpdateReplicationJobRequest.html#structfield.frequency) member.
+    pub fn frequency<T: Into<i64>>(mut self, value: T) -> Self {
+        self.frequency = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.UpdateReplicationJobRequest.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_type`](struct.UpdateReplicationJobRequest.html#structfield.license_type) member.
+    pub fn license_type<T: Into<LicenseType>>(mut self, value: T) -> Self {
+        self.license_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_replication_run_start_time`](struct.UpdateReplicationJobRequest.html#structfield.next_replication_run_start_time) member.
+    pub fn next_replication_run_start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.next_replication_run_start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_recent_amis_to_keep`](struct.UpdateReplicationJobRequest.html#structfield.number_of_recent_amis_to_keep) member.
+    pub fn number_of_recent_amis_to_keep<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_recent_amis_to_keep = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_job_id`](struct.UpdateReplicationJobRequest.html#structfield.replication_job_id) member.
+    pub fn replication_job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.UpdateReplicationJobRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateReplicationJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateReplicationJobRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateReplicationJobRequest {
+            description: self.description,
+            encrypted: self.encrypted,
+            frequency: self.frequency,
+            kms_key_id: self.kms_key_id,
+            license_type: self.license_type,
+            next_replication_run_start_time: self.next_replication_run_start_time,
+            number_of_recent_amis_to_keep: self.number_of_recent_amis_to_keep,
+            replication_job_id: self
+                .replication_job_id
+                .ok_or(::rusoto_core::BuildError::missing("replication_job_id"))?,
+            role_name: self.role_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateReplicationJobResponse {}
@@ -1122,6 +3327,82 @@ pub struct UserData {
     pub s_3_location: Option<S3Location>,
 }
 
+/// <p>The VM management product.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VmManagerType {
+    /// <p><code>VSPHERE</code></p>
+    Vsphere,
+    /// <p><code>SCVMM</code></p>
+    Scvmm,
+    /// <p><code>HYPERV-MANAGER</code></p>
+    HypervManager,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VmManagerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VmManagerType::Vsphere => "VSPHERE",
+            VmManagerType::Scvmm => "SCVMM",
+            VmManagerType::HypervManager => "HYPERV-MANAGER",
+            VmManagerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VmManagerType {
+    fn from(value: &'a str) -> VmManagerType {
+        match value {
+            "VSPHERE" => VmManagerType::Vsphere,
+            "SCVMM" => VmManagerType::Scvmm,
+            "HYPERV-MANAGER" => VmManagerType::HypervManager,
+            _ => VmManagerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VmManagerType {
+    fn from(value: String) -> VmManagerType {
+        VmManagerType::from(value.as_str())
+    }
+}
+
+impl From<VmManagerType> for String {
+    fn from(value: VmManagerType) -> String {
+        match value {
+            VmManagerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VmManagerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for VmManagerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VmManagerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VmManagerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VmManagerType::from(value))
+    }
+}
+
 /// <p>Represents a VM server.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VmServer {
@@ -1132,7 +3413,7 @@ pub struct VmServer {
     /// <p>The type of VM management product.</p>
     #[serde(rename = "vmManagerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vm_manager_type: Option<String>,
+    pub vm_manager_type: Option<VmManagerType>,
     /// <p>The name of the VM.</p>
     #[serde(rename = "vmName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3061,6 +5342,180 @@ pub trait ServerMigrationService {
         input: UpdateReplicationJobRequest,
     ) -> RusotoFuture<UpdateReplicationJobResponse, UpdateReplicationJobError>;
 }
+impl<T: ?Sized + ServerMigrationService> ServerMigrationService for ::std::sync::Arc<T> {
+    fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> RusotoFuture<CreateAppResponse, CreateAppError> {
+        (**self).create_app(input)
+    }
+    fn create_replication_job(
+        &self,
+        input: CreateReplicationJobRequest,
+    ) -> RusotoFuture<CreateReplicationJobResponse, CreateReplicationJobError> {
+        (**self).create_replication_job(input)
+    }
+    fn delete_app(
+        &self,
+        input: DeleteAppRequest,
+    ) -> RusotoFuture<DeleteAppResponse, DeleteAppError> {
+        (**self).delete_app(input)
+    }
+    fn delete_app_launch_configuration(
+        &self,
+        input: DeleteAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<DeleteAppLaunchConfigurationResponse, DeleteAppLaunchConfigurationError> {
+        (**self).delete_app_launch_configuration(input)
+    }
+    fn delete_app_replication_configuration(
+        &self,
+        input: DeleteAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteAppReplicationConfigurationResponse,
+        DeleteAppReplicationConfigurationError,
+    > {
+        (**self).delete_app_replication_configuration(input)
+    }
+    fn delete_replication_job(
+        &self,
+        input: DeleteReplicationJobRequest,
+    ) -> RusotoFuture<DeleteReplicationJobResponse, DeleteReplicationJobError> {
+        (**self).delete_replication_job(input)
+    }
+    fn delete_server_catalog(
+        &self,
+    ) -> RusotoFuture<DeleteServerCatalogResponse, DeleteServerCatalogError> {
+        (**self).delete_server_catalog()
+    }
+    fn disassociate_connector(
+        &self,
+        input: DisassociateConnectorRequest,
+    ) -> RusotoFuture<DisassociateConnectorResponse, DisassociateConnectorError> {
+        (**self).disassociate_connector(input)
+    }
+    fn generate_change_set(
+        &self,
+        input: GenerateChangeSetRequest,
+    ) -> RusotoFuture<GenerateChangeSetResponse, GenerateChangeSetError> {
+        (**self).generate_change_set(input)
+    }
+    fn generate_template(
+        &self,
+        input: GenerateTemplateRequest,
+    ) -> RusotoFuture<GenerateTemplateResponse, GenerateTemplateError> {
+        (**self).generate_template(input)
+    }
+    fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResponse, GetAppError> {
+        (**self).get_app(input)
+    }
+    fn get_app_launch_configuration(
+        &self,
+        input: GetAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<GetAppLaunchConfigurationResponse, GetAppLaunchConfigurationError> {
+        (**self).get_app_launch_configuration(input)
+    }
+    fn get_app_replication_configuration(
+        &self,
+        input: GetAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<GetAppReplicationConfigurationResponse, GetAppReplicationConfigurationError>
+    {
+        (**self).get_app_replication_configuration(input)
+    }
+    fn get_connectors(
+        &self,
+        input: GetConnectorsRequest,
+    ) -> RusotoFuture<GetConnectorsResponse, GetConnectorsError> {
+        (**self).get_connectors(input)
+    }
+    fn get_replication_jobs(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> RusotoFuture<GetReplicationJobsResponse, GetReplicationJobsError> {
+        (**self).get_replication_jobs(input)
+    }
+    fn get_replication_runs(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> RusotoFuture<GetReplicationRunsResponse, GetReplicationRunsError> {
+        (**self).get_replication_runs(input)
+    }
+    fn get_servers(
+        &self,
+        input: GetServersRequest,
+    ) -> RusotoFuture<GetServersResponse, GetServersError> {
+        (**self).get_servers(input)
+    }
+    fn import_server_catalog(
+        &self,
+    ) -> RusotoFuture<ImportServerCatalogResponse, ImportServerCatalogError> {
+        (**self).import_server_catalog()
+    }
+    fn launch_app(
+        &self,
+        input: LaunchAppRequest,
+    ) -> RusotoFuture<LaunchAppResponse, LaunchAppError> {
+        (**self).launch_app(input)
+    }
+    fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResponse, ListAppsError> {
+        (**self).list_apps(input)
+    }
+    fn put_app_launch_configuration(
+        &self,
+        input: PutAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<PutAppLaunchConfigurationResponse, PutAppLaunchConfigurationError> {
+        (**self).put_app_launch_configuration(input)
+    }
+    fn put_app_replication_configuration(
+        &self,
+        input: PutAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<PutAppReplicationConfigurationResponse, PutAppReplicationConfigurationError>
+    {
+        (**self).put_app_replication_configuration(input)
+    }
+    fn start_app_replication(
+        &self,
+        input: StartAppReplicationRequest,
+    ) -> RusotoFuture<StartAppReplicationResponse, StartAppReplicationError> {
+        (**self).start_app_replication(input)
+    }
+    fn start_on_demand_replication_run(
+        &self,
+        input: StartOnDemandReplicationRunRequest,
+    ) -> RusotoFuture<StartOnDemandReplicationRunResponse, StartOnDemandReplicationRunError> {
+        (**self).start_on_demand_replication_run(input)
+    }
+    fn stop_app_replication(
+        &self,
+        input: StopAppReplicationRequest,
+    ) -> RusotoFuture<StopAppReplicationResponse, StopAppReplicationError> {
+        (**self).stop_app_replication(input)
+    }
+    fn terminate_app(
+        &self,
+        input: TerminateAppRequest,
+    ) -> RusotoFuture<TerminateAppResponse, TerminateAppError> {
+        (**self).terminate_app(input)
+    }
+    fn update_app(
+        &self,
+        input: UpdateAppRequest,
+    ) -> RusotoFuture<UpdateAppResponse, UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_replication_job(
+        &self,
+        input: UpdateReplicationJobRequest,
+    ) -> RusotoFuture<UpdateReplicationJobResponse, UpdateReplicationJobError> {
+        (**self).update_replication_job(input)
+    }
+}
+/// The error codes SMS retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the SMS API.
 #[derive(Clone)]
 pub struct ServerMigrationServiceClient {
@@ -3076,6 +5531,24 @@ impl ServerMigrationServiceClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SMS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ServerMigrationServiceClient {
+        Self::new(region::Region::from_env_for_service("SMS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `sms`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ServerMigrationServiceClient {
+        Self::new_with_client(config.client().clone(), config.region_for("sms", "SMS"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3094,7 +5567,49 @@ impl ServerMigrationServiceClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ServerMigrationServiceClient {
-        ServerMigrationServiceClient { client, region }
+        ServerMigrationServiceClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "sms")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "sms", "sms", "SMS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ServerMigrationService` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ServerMigrationService + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ServerMigrationServiceClient {
+    fn service_name(&self) -> &'static str {
+        "SMS"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "sms"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ServerMigrationServiceClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,105 @@
+extern crate rusoto_mock;
+
+use std::time::Duration;
+
+use futures::{Future, Stream};
+
+use crate::custom::jobs::{JobState, JobWatcherExt, TransferProgress};
+use crate::generated::SnowballClient;
+
+use self::rusoto_mock::*;
+use rusoto_core::waiter::WaiterConfig;
+use rusoto_core::Region;
+
+/// A body answering `DescribeJob` and `CancelJob` at once.
+fn job_body(state: &str) -> String {
+    format!(
+        r#"{{
+            "JobMetadata": {{
+                "JobId": "JID123e4567-e89b-12d3-a456-426655440000",
+                "JobState": "{}",
+                "JobType": "IMPORT",
+                "DataTransferProgress": {{
+                    "BytesTransferred": 750,
+                    "ObjectsTransferred": 3,
+                    "TotalBytes": 1000,
+                    "TotalObjects": 4
+                }}
+            }}
+        }}"#,
+        state
+    )
+}
+
+#[test]
+fn job_states_round_trip() {
+    for state in &[
+        "New",
+        "InTransitToAWS",
+        "WithAWSSortingFacility",
+        "Complete",
+    ] {
+        let parsed: JobState = state.parse().unwrap();
+        assert_eq!(parsed.as_str(), *state);
+    }
+    assert_eq!(
+        "Shredded".parse::<JobState>(),
+        Ok(JobState::Unknown("Shredded".to_owned()))
+    );
+    assert!(JobState::Cancelled.is_finished());
+    assert!(!JobState::InProgress.is_finished());
+}
+
+#[test]
+fn should_stop_watching_finished_jobs() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(&job_body("Complete"))
+        .with_request_checker(|request| {
+            assert_eq!(
+                request.headers["x-amz-target"][0],
+                b"AWSIESnowballJobManagementService.DescribeJob".to_vec()
+            );
+        });
+    let client = SnowballClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let updates = client
+        .watch_job(
+            "JID123e4567-e89b-12d3-a456-426655440000",
+            Duration::from_secs(60),
+        )
+        .collect()
+        .wait()
+        .unwrap();
+
+    assert_eq!(updates.len(), 1);
+    let update = &updates[0];
+    assert!(update.state_changed());
+    assert_eq!(update.previous_state, None);
+    assert_eq!(update.state, JobState::Complete);
+    assert_eq!(
+        update.progress,
+        Some(TransferProgress {
+            bytes_transferred: 750,
+            objects_transferred: 3,
+            total_bytes: 1000,
+            total_objects: 4,
+        })
+    );
+    assert_eq!(update.progress.as_ref().unwrap().fraction(), Some(0.75));
+}
+
+#[test]
+fn should_wait_for_jobs_to_be_cancelled() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(&job_body("Cancelled"));
+    let client = SnowballClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let job = client
+        .cancel_job_and_wait(
+            "JID123e4567-e89b-12d3-a456-426655440000",
+            WaiterConfig::new(Duration::from_millis(1), 3),
+        )
+        .sync()
+        .unwrap();
+
+    assert_eq!(job.job_state, Some("Cancelled".into()));
+}
@@ -0,0 +1,390 @@
+//! Watching jobs move through their states and transfer data.
+//!
+//! A Snowball job goes through several states, from being created to the appliance being shipped,
+//! filled by the customer, returned and imported into S3. `watch_job` polls `DescribeJob` and
+//! returns a stream of `JobUpdate`s, one whenever the state of the job or the progress of its
+//! data transfer changed, which ends once the job is complete or cancelled.
+//!
+//! Dropping the stream stops watching the job. Use `cancel_job_and_wait` to cancel the job itself.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use futures::future::{self, Future};
+use futures::stream::{self, Stream};
+use rusoto_core::waiter::{poll_until, sleep, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    CancelJobError, CancelJobRequest, CreateJobError, CreateJobRequest, DataTransfer,
+    DescribeJobError, DescribeJobRequest, JobMetadata, Snowball,
+};
+
+/// The updates of a job, ending once it is complete or cancelled.
+pub type JobUpdates = Box<dyn Stream<Item = JobUpdate, Error = RusotoError<JobWatchError>> + Send>;
+
+/// The state of a job.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JobState {
+    /// The job was created.
+    New,
+    /// The appliance is being prepared.
+    PreparingAppliance,
+    /// The appliance is being prepared for shipping.
+    PreparingShipment,
+    /// The appliance is being shipped to the customer.
+    InTransitToCustomer,
+    /// The appliance was delivered to the customer.
+    WithCustomer,
+    /// The appliance is being shipped back to AWS.
+    InTransitToAws,
+    /// The appliance is at an AWS sorting facility.
+    WithAwsSortingFacility,
+    /// The appliance was delivered to AWS.
+    WithAws,
+    /// The data on the appliance is being imported into S3.
+    InProgress,
+    /// The job is complete.
+    Complete,
+    /// The job was cancelled.
+    Cancelled,
+    /// The job is a cluster job, whose state is that of its cluster.
+    Listing,
+    /// The job is waiting for the other jobs of its cluster.
+    Pending,
+    /// A state this version of Rusoto doesn't know about.
+    Unknown(String),
+}
+
+impl JobState {
+    /// Whether the job won't change state anymore.
+    pub fn is_finished(&self) -> bool {
+        match *self {
+            JobState::Complete | JobState::Cancelled => true,
+            _ => false,
+        }
+    }
+
+    /// The name of the state in the API.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobState::New => "New",
+            JobState::PreparingAppliance => "PreparingAppliance",
+            JobState::PreparingShipment => "PreparingShipment",
+            JobState::InTransitToCustomer => "InTransitToCustomer",
+            JobState::WithCustomer => "WithCustomer",
+            JobState::InTransitToAws => "InTransitToAWS",
+            JobState::WithAwsSortingFacility => "WithAWSSortingFacility",
+            JobState::WithAws => "WithAWS",
+            JobState::InProgress => "InProgress",
+            JobState::Complete => "Complete",
+            JobState::Cancelled => "Cancelled",
+            JobState::Listing => "Listing",
+            JobState::Pending => "Pending",
+            JobState::Unknown(ref state) => state,
+        }
+    }
+}
+
+impl FromStr for JobState {
+    type Err = ();
+
+    fn from_str(state: &str) -> Result<JobState, ()> {
+        Ok(match state {
+            "New" => JobState::New,
+            "PreparingAppliance" => JobState::PreparingAppliance,
+            "PreparingShipment" => JobState::PreparingShipment,
+            "InTransitToCustomer" => JobState::InTransitToCustomer,
+            "WithCustomer" => JobState::WithCustomer,
+            "InTransitToAWS" => JobState::InTransitToAws,
+            "WithAWSSortingFacility" => JobState::WithAwsSortingFacility,
+            "WithAWS" => JobState::WithAws,
+            "InProgress" => JobState::InProgress,
+            "Complete" => JobState::Complete,
+            "Cancelled" => JobState::Cancelled,
+            "Listing" => JobState::Listing,
+            "Pending" => JobState::Pending,
+            state => JobState::Unknown(state.to_owned()),
+        })
+    }
+}
+
+impl fmt::Display for JobState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How much of the data of a job was transferred between the appliance and S3.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransferProgress {
+    /// The number of bytes transferred
+    pub bytes_transferred: i64,
+    /// The number of objects transferred
+    pub objects_transferred: i64,
+    /// The number of bytes to transfer
+    pub total_bytes: i64,
+    /// The number of objects to transfer
+    pub total_objects: i64,
+}
+
+impl TransferProgress {
+    /// The share of the bytes transferred, between 0 and 1, once the total is known.
+    pub fn fraction(&self) -> Option<f64> {
+        if self.total_bytes > 0 {
+            Some(self.bytes_transferred as f64 / self.total_bytes as f64)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<DataTransfer> for TransferProgress {
+    fn from(transfer: DataTransfer) -> TransferProgress {
+        TransferProgress {
+            bytes_transferred: transfer.bytes_transferred.unwrap_or_default(),
+            objects_transferred: transfer.objects_transferred.unwrap_or_default(),
+            total_bytes: transfer.total_bytes.unwrap_or_default(),
+            total_objects: transfer.total_objects.unwrap_or_default(),
+        }
+    }
+}
+
+/// The state and progress of a job, when either changed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobUpdate {
+    /// The ID of the job
+    pub job_id: String,
+    /// The state of the job in the previous update, if there was one
+    pub previous_state: Option<JobState>,
+    /// The state of the job
+    pub state: JobState,
+    /// The progress of the data transfer, once it started
+    pub progress: Option<TransferProgress>,
+    /// The job, as described
+    pub job: JobMetadata,
+}
+
+impl JobUpdate {
+    /// Whether the job changed state since the previous update.
+    pub fn state_changed(&self) -> bool {
+        self.previous_state.as_ref() != Some(&self.state)
+    }
+}
+
+/// Errors returned while watching a job.
+#[derive(Debug, PartialEq)]
+pub enum JobWatchError {
+    /// Creating the job failed.
+    CreateJob(CreateJobError),
+    /// Describing the job failed.
+    DescribeJob(DescribeJobError),
+    /// Cancelling the job failed.
+    CancelJob(CancelJobError),
+    /// The job wasn't described.
+    JobNotFound(String),
+    /// The job was not cancelled within the configured number of attempts.
+    TimedOut(String),
+}
+
+impl fmt::Display for JobWatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for JobWatchError {
+    fn description(&self) -> &str {
+        match *self {
+            JobWatchError::CreateJob(ref cause) => cause.description(),
+            JobWatchError::DescribeJob(ref cause) => cause.description(),
+            JobWatchError::CancelJob(ref cause) => cause.description(),
+            JobWatchError::JobNotFound(ref cause) => cause,
+            JobWatchError::TimedOut(ref cause) => cause,
+        }
+    }
+}
+
+/// Extension methods which watch jobs.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use futures::Stream;
+/// use rusoto_core::Region;
+/// use rusoto_snowball::jobs::JobWatcherExt;
+/// use rusoto_snowball::SnowballClient;
+///
+/// let client = SnowballClient::new(Region::UsEast1);
+/// let updates = client.watch_job("JID123e4567-e89b-12d3-a456-426655440000", Duration::from_secs(60));
+/// for update in updates.wait() {
+///     let update = update.unwrap();
+///     if update.state_changed() {
+///         println!("{}", update.state);
+///     }
+///     if let Some(fraction) = update.progress.as_ref().and_then(|p| p.fraction()) {
+///         println!("{:.0}% transferred", fraction * 100.0);
+///     }
+/// }
+/// ```
+pub trait JobWatcherExt: Snowball + Clone + Send + Sized + 'static {
+    /// Polls `DescribeJob` every `interval`, returning an update whenever the state of the job or
+    /// the progress of its data transfer changed.
+    ///
+    /// The first update is returned right away. The stream ends once the job is complete or
+    /// cancelled; it must be run on a Tokio runtime.
+    fn watch_job(&self, job_id: &str, interval: Duration) -> JobUpdates {
+        watch(self.clone(), job_id.to_owned(), interval)
+    }
+
+    /// Creates a job, then watches it like `watch_job`.
+    fn create_job_and_watch(&self, input: CreateJobRequest, interval: Duration) -> JobUpdates {
+        let client = self.clone();
+        Box::new(
+            self.create_job(input)
+                .map_err(|e| e.map_service(JobWatchError::CreateJob))
+                .and_then(|result| {
+                    result.job_id.ok_or_else(|| {
+                        RusotoError::Service(JobWatchError::JobNotFound(
+                            "CreateJob didn't return a job ID".to_owned(),
+                        ))
+                    })
+                })
+                .map(move |job_id| watch(client, job_id, interval))
+                .flatten_stream(),
+        )
+    }
+
+    /// Cancels a job, then polls `DescribeJob` until it is cancelled, and returns it.
+    fn cancel_job_and_wait(
+        &self,
+        job_id: &str,
+        config: WaiterConfig,
+    ) -> RusotoFuture<JobMetadata, JobWatchError> {
+        let client = self.clone();
+        let job_id = job_id.to_owned();
+        let timeout = RusotoError::Service(JobWatchError::TimedOut(format!(
+            "Job {} was not cancelled",
+            job_id
+        )));
+
+        let future = self
+            .cancel_job(CancelJobRequest {
+                job_id: job_id.clone(),
+            })
+            .map_err(|e| e.map_service(JobWatchError::CancelJob))
+            .and_then(move |_| {
+                poll_until(
+                    config,
+                    move || {
+                        describe_job(&client, job_id.clone()).map(|job| {
+                            match job.job_state.as_ref().map(|state| state.as_str()) {
+                                Some("Cancelled") => Some(job),
+                                _ => None,
+                            }
+                        })
+                    },
+                    timeout,
+                )
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: Snowball + Clone + Send + 'static> JobWatcherExt for T {}
+
+struct WatchState<C> {
+    client: C,
+    job_id: String,
+    interval: Duration,
+    last: Option<(JobState, Option<TransferProgress>)>,
+}
+
+impl<C> WatchState<C> {
+    /// The update for a description of the job, unless nothing changed.
+    fn update(&mut self, job: JobMetadata) -> Option<JobUpdate> {
+        let state = job
+            .job_state
+            .as_ref()
+            .map_or(JobState::New, |state| state.as_str().parse().unwrap());
+        let progress = job
+            .data_transfer_progress
+            .clone()
+            .map(TransferProgress::from);
+        let previous = self.last.replace((state.clone(), progress.clone()));
+        if previous.as_ref() == Some(&(state.clone(), progress.clone())) {
+            return None;
+        }
+        Some(JobUpdate {
+            job_id: self.job_id.clone(),
+            previous_state: previous.map(|(state, _)| state),
+            state,
+            progress,
+            job,
+        })
+    }
+
+    fn is_finished(&self) -> bool {
+        self.last
+            .as_ref()
+            .map_or(false, |&(ref state, _)| state.is_finished())
+    }
+}
+
+fn watch<C: Snowball + Send + 'static>(
+    client: C,
+    job_id: String,
+    interval: Duration,
+) -> JobUpdates {
+    let state = WatchState {
+        client,
+        job_id,
+        interval,
+        last: None,
+    };
+
+    let updates = stream::unfold(state, |mut state| {
+        if state.is_finished() {
+            return None;
+        }
+        let delay: Box<dyn Future<Item = (), Error = RusotoError<JobWatchError>> + Send> =
+            if state.last.is_some() {
+                Box::new(sleep(state.interval))
+            } else {
+                Box::new(future::ok(()))
+            };
+        let job = delay.and_then(move |_| {
+            describe_job(&state.client, state.job_id.clone()).map(move |job| {
+                let update = state.update(job);
+                (update, state)
+            })
+        });
+        Some(job)
+    });
+
+    Box::new(updates.filter_map(|update| update))
+}
+
+fn describe_job<C: Snowball>(
+    client: &C,
+    job_id: String,
+) -> impl Future<Item = JobMetadata, Error = RusotoError<JobWatchError>> {
+    client
+        .describe_job(DescribeJobRequest {
+            job_id: job_id.clone(),
+        })
+        .map_err(|e| e.map_service(JobWatchError::DescribeJob))
+        .and_then(move |result| {
+            result.job_metadata.ok_or_else(|| {
+                RusotoError::Service(JobWatchError::JobNotFound(format!(
+                    "Job {} not found",
+                    job_id
+                )))
+            })
+        })
+}
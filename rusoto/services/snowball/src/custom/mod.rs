@@ -1 +1,5 @@
+/// Watching jobs change state and transfer data
+pub mod jobs;
 
+#[cfg(test)]
+mod custom_tests;
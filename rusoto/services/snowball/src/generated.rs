@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>The address that you want the Snowball or Snowballs associated with a specific job to be shipped to. Addresses are validated at the time of creation. The address you provide must be located within the serviceable area of your region. Although no individual elements of the <code>Address</code> are required, if the address is invalid or unsupported, then an exception is thrown.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -91,6 +92,36 @@ pub struct CancelClusterRequest {
     pub cluster_id: String,
 }
 
+impl CancelClusterRequest {
+    /// Returns a builder for `CancelClusterRequest`, checking its required members when built.
+    pub fn builder() -> CancelClusterRequestBuilder {
+        CancelClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelClusterRequest`](struct.CancelClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelClusterRequestBuilder {
+    cluster_id: Option<String>,
+}
+
+impl CancelClusterRequestBuilder {
+    /// Sets the [`cluster_id`](struct.CancelClusterRequest.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelClusterRequest, ::rusoto_core::BuildError> {
+        Ok(CancelClusterRequest {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelClusterResult {}
@@ -102,6 +133,36 @@ pub struct CancelJobRequest {
     pub job_id: String,
 }
 
+impl CancelJobRequest {
+    /// Returns a builder for `CancelJobRequest`, checking its required members when built.
+    pub fn builder() -> CancelJobRequestBuilder {
+        CancelJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelJobRequest`](struct.CancelJobRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl CancelJobRequestBuilder {
+    /// Sets the [`job_id`](struct.CancelJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelJobRequest, ::rusoto_core::BuildError> {
+        Ok(CancelJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelJobResult {}
@@ -117,11 +178,12 @@ pub struct ClusterListEntry {
     /// <p>The current state of this cluster. For information about the state of a specific node, see <a>JobListEntry$JobState</a>.</p>
     #[serde(rename = "ClusterState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cluster_state: Option<String>,
+    pub cluster_state: Option<ClusterState>,
     /// <p>The creation date for this cluster.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>Defines an optional description of the cluster, for example <code>Environmental Data Cluster-01</code>.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -143,11 +205,12 @@ pub struct ClusterMetadata {
     /// <p>The current status of the cluster.</p>
     #[serde(rename = "ClusterState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cluster_state: Option<String>,
+    pub cluster_state: Option<ClusterState>,
     /// <p>The creation date for this cluster.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The optional description of the cluster.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -159,7 +222,7 @@ pub struct ClusterMetadata {
     /// <p>The type of job for this cluster. Currently, the only job type supported for clusters is <code>LOCAL_USE</code>.</p>
     #[serde(rename = "JobType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     /// <p>The <code>KmsKeyARN</code> Amazon Resource Name (ARN) associated with this cluster. This ARN was created using the <a href="http://docs.aws.amazon.com/kms/latest/APIReference/API_CreateKey.html">CreateKey</a> API action in AWS Key Management Service (AWS KMS).</p>
     #[serde(rename = "KmsKeyARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -179,11 +242,95 @@ pub struct ClusterMetadata {
     /// <p><p>The shipping speed for each node in this cluster. This speed doesn&#39;t dictate how soon you&#39;ll get each device, rather it represents how quickly each device moves to its destination while in transit. Regional shipping speeds are as follows:</p> <ul> <li> <p>In Australia, you have access to express shipping. Typically, devices shipped express are delivered in about a day.</p> </li> <li> <p>In the European Union (EU), you have access to express shipping. Typically, devices shipped express are delivered in about a day. In addition, most countries in the EU have access to standard shipping, which typically takes less than a week, one way.</p> </li> <li> <p>In India, devices are delivered in one to seven days.</p> </li> <li> <p>In the US, you have access to one-day shipping and two-day shipping.</p> </li> </ul></p>
     #[serde(rename = "ShippingOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_option: Option<String>,
+    pub shipping_option: Option<ShippingOption>,
     /// <p>The type of AWS Snowball device to use for this cluster. The only supported device types for cluster jobs are <code>EDGE</code>, <code>EDGE_C</code>, and <code>EDGE_CG</code>.</p>
     #[serde(rename = "SnowballType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_type: Option<String>,
+    pub snowball_type: Option<SnowballType>,
+}
+
+/// <p>The current state of this cluster. For information about the state of a specific node, see <a>JobListEntry$JobState</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClusterState {
+    /// <p><code>AwaitingQuorum</code></p>
+    AwaitingQuorum,
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>InUse</code></p>
+    InUse,
+    /// <p><code>Complete</code></p>
+    Complete,
+    /// <p><code>Cancelled</code></p>
+    Cancelled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ClusterState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterState::AwaitingQuorum => "AwaitingQuorum",
+            ClusterState::Pending => "Pending",
+            ClusterState::InUse => "InUse",
+            ClusterState::Complete => "Complete",
+            ClusterState::Cancelled => "Cancelled",
+            ClusterState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ClusterState {
+    fn from(value: &'a str) -> ClusterState {
+        match value {
+            "AwaitingQuorum" => ClusterState::AwaitingQuorum,
+            "Pending" => ClusterState::Pending,
+            "InUse" => ClusterState::InUse,
+            "Complete" => ClusterState::Complete,
+            "Cancelled" => ClusterState::Cancelled,
+            _ => ClusterState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterState {
+    fn from(value: String) -> ClusterState {
+        ClusterState::from(value.as_str())
+    }
+}
+
+impl From<ClusterState> for String {
+    fn from(value: ClusterState) -> String {
+        match value {
+            ClusterState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ClusterState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ClusterState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ClusterState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ClusterState::from(value))
+    }
 }
 
 /// <p>A JSON-formatted object that describes a compatible Amazon Machine Image (AMI). For more information on compatible AMIs, see <a href="http://docs.aws.amazon.com/snowball/latest/developer-guide/using-ec2.html">Using Amazon EC2 Compute Instances</a> in the <i>AWS Snowball Developer Guide</i>.</p>
@@ -207,6 +354,36 @@ pub struct CreateAddressRequest {
     pub address: Address,
 }
 
+impl CreateAddressRequest {
+    /// Returns a builder for `CreateAddressRequest`, checking its required members when built.
+    pub fn builder() -> CreateAddressRequestBuilder {
+        CreateAddressRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAddressRequest`](struct.CreateAddressRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAddressRequestBuilder {
+    address: Option<Address>,
+}
+
+impl CreateAddressRequestBuilder {
+    /// Sets the [`address`](struct.CreateAddressRequest.html#structfield.address) member.
+    pub fn address<T: Into<Address>>(mut self, value: T) -> Self {
+        self.address = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAddressRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAddressRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAddressRequest {
+            address: self
+                .address
+                .ok_or(::rusoto_core::BuildError::missing("address"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateAddressResult {
@@ -216,7 +393,7 @@ pub struct CreateAddressResult {
     pub address_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateClusterRequest {
     /// <p>The ID for the address that you want the cluster shipped to.</p>
     #[serde(rename = "AddressId")]
@@ -231,7 +408,7 @@ pub struct CreateClusterRequest {
     pub forwarding_address_id: Option<String>,
     /// <p>The type of job for this cluster. Currently, the only job type supported for clusters is <code>LOCAL_USE</code>.</p>
     #[serde(rename = "JobType")]
-    pub job_type: String,
+    pub job_type: JobType,
     /// <p>The <code>KmsKeyARN</code> value that you want to associate with this cluster. <code>KmsKeyARN</code> values are created by using the <a href="http://docs.aws.amazon.com/kms/latest/APIReference/API_CreateKey.html">CreateKey</a> API action in AWS Key Management Service (AWS KMS). </p>
     #[serde(rename = "KmsKeyARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -248,11 +425,121 @@ pub struct CreateClusterRequest {
     pub role_arn: String,
     /// <p><p>The shipping speed for each node in this cluster. This speed doesn&#39;t dictate how soon you&#39;ll get each Snowball Edge device, rather it represents how quickly each device moves to its destination while in transit. Regional shipping speeds are as follows:</p> <ul> <li> <p>In Australia, you have access to express shipping. Typically, devices shipped express are delivered in about a day.</p> </li> <li> <p>In the European Union (EU), you have access to express shipping. Typically, Snowball Edges shipped express are delivered in about a day. In addition, most countries in the EU have access to standard shipping, which typically takes less than a week, one way.</p> </li> <li> <p>In India, devices are delivered in one to seven days.</p> </li> <li> <p>In the US, you have access to one-day shipping and two-day shipping.</p> </li> </ul></p>
     #[serde(rename = "ShippingOption")]
-    pub shipping_option: String,
+    pub shipping_option: ShippingOption,
     /// <p>The type of AWS Snowball device to use for this cluster. The only supported device types for cluster jobs are <code>EDGE</code>, <code>EDGE_C</code>, and <code>EDGE_CG</code>.</p>
     #[serde(rename = "SnowballType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_type: Option<String>,
+    pub snowball_type: Option<SnowballType>,
+}
+
+impl CreateClusterRequest {
+    /// Returns a builder for `CreateClusterRequest`, checking its required members when built.
+    pub fn builder() -> CreateClusterRequestBuilder {
+        CreateClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClusterRequest`](struct.CreateClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateClusterRequestBuilder {
+    address_id: Option<String>,
+    description: Option<String>,
+    forwarding_address_id: Option<String>,
+    job_type: Option<JobType>,
+    kms_key_arn: Option<String>,
+    notification: Option<Notification>,
+    resources: Option<JobResource>,
+    role_arn: Option<String>,
+    shipping_option: Option<ShippingOption>,
+    snowball_type: Option<SnowballType>,
+}
+
+impl CreateClusterRequestBuilder {
+    /// Sets the [`address_id`](struct.CreateClusterRequest.html#structfield.address_id) member.
+    pub fn address_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.address_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateClusterRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`forwarding_address_id`](struct.CreateClusterRequest.html#structfield.forwarding_address_id) member.
+    pub fn forwarding_address_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.forwarding_address_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_type`](struct.CreateClusterRequest.html#structfield.job_type) member.
+    pub fn job_type<T: Into<JobType>>(mut self, value: T) -> Self {
+        self.job_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_arn`](struct.CreateClusterRequest.html#structfield.kms_key_arn) member.
+    pub fn kms_key_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification`](struct.CreateClusterRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resources`](struct.CreateClusterRequest.html#structfield.resources) member.
+    pub fn resources<T: Into<JobResource>>(mut self, value: T) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateClusterRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shipping_option`](struct.CreateClusterRequest.html#structfield.shipping_option) member.
+    pub fn shipping_option<T: Into<ShippingOption>>(mut self, value: T) -> Self {
+        self.shipping_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snowball_type`](struct.CreateClusterRequest.html#structfield.snowball_type) member.
+    pub fn snowball_type<T: Into<SnowballType>>(mut self, value: T) -> Self {
+        self.snowball_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateClusterRequest, ::rusoto_core::BuildError> {
+        Ok(CreateClusterRequest {
+            address_id: self
+                .address_id
+                .ok_or(::rusoto_core::BuildError::missing("address_id"))?,
+            description: self.description,
+            forwarding_address_id: self.forwarding_address_id,
+            job_type: self
+                .job_type
+                .ok_or(::rusoto_core::BuildError::missing("job_type"))?,
+            kms_key_arn: self.kms_key_arn,
+            notification: self.notification,
+            resources: self
+                .resources
+                .ok_or(::rusoto_core::BuildError::missing("resources"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            shipping_option: self
+                .shipping_option
+                .ok_or(::rusoto_core::BuildError::missing("shipping_option"))?,
+            snowball_type: self.snowball_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -285,7 +572,7 @@ pub struct CreateJobRequest {
     /// <p>Defines the type of job that you're creating. </p>
     #[serde(rename = "JobType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     /// <p>The <code>KmsKeyARN</code> that you want to associate with this job. <code>KmsKeyARN</code>s are created using the <a href="http://docs.aws.amazon.com/kms/latest/APIReference/API_CreateKey.html">CreateKey</a> AWS Key Management Service (KMS) API action.</p>
     #[serde(rename = "KmsKeyARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -305,15 +592,131 @@ pub struct CreateJobRequest {
     /// <p><p>The shipping speed for this job. This speed doesn&#39;t dictate how soon you&#39;ll get the Snowball, rather it represents how quickly the Snowball moves to its destination while in transit. Regional shipping speeds are as follows:</p> <ul> <li> <p>In Australia, you have access to express shipping. Typically, Snowballs shipped express are delivered in about a day.</p> </li> <li> <p>In the European Union (EU), you have access to express shipping. Typically, Snowballs shipped express are delivered in about a day. In addition, most countries in the EU have access to standard shipping, which typically takes less than a week, one way.</p> </li> <li> <p>In India, Snowballs are delivered in one to seven days.</p> </li> <li> <p>In the US, you have access to one-day shipping and two-day shipping.</p> </li> </ul></p>
     #[serde(rename = "ShippingOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_option: Option<String>,
+    pub shipping_option: Option<ShippingOption>,
     /// <p>If your job is being created in one of the US regions, you have the option of specifying what size Snowball you'd like for this job. In all other regions, Snowballs come with 80 TB in storage capacity.</p>
     #[serde(rename = "SnowballCapacityPreference")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_capacity_preference: Option<String>,
+    pub snowball_capacity_preference: Option<SnowballCapacity>,
     /// <p>The type of AWS Snowball device to use for this job. The only supported device types for cluster jobs are <code>EDGE</code>, <code>EDGE_C</code>, and <code>EDGE_CG</code>.</p>
     #[serde(rename = "SnowballType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_type: Option<String>,
+    pub snowball_type: Option<SnowballType>,
+}
+
+impl CreateJobRequest {
+    /// Returns a builder for `CreateJobRequest`, checking its required members when built.
+    pub fn builder() -> CreateJobRequestBuilder {
+        CreateJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateJobRequest`](struct.CreateJobRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateJobRequestBuilder {
+    address_id: Option<String>,
+    cluster_id: Option<String>,
+    description: Option<String>,
+    forwarding_address_id: Option<String>,
+    job_type: Option<JobType>,
+    kms_key_arn: Option<String>,
+    notification: Option<Notification>,
+    resources: Option<JobResource>,
+    role_arn: Option<String>,
+    shipping_option: Option<ShippingOption>,
+    snowball_capacity_preference: Option<SnowballCapacity>,
+    snowball_type: Option<SnowballType>,
+}
+
+impl CreateJobRequestBuilder {
+    /// Sets the [`address_id`](struct.CreateJobRequest.html#structfield.address_id) member.
+    pub fn address_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.address_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_id`](struct.CreateJobRequest.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateJobRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`forwarding_address_id`](struct.CreateJobRequest.html#structfield.forwarding_address_id) member.
+    pub fn forwarding_address_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.forwarding_address_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_type`](struct.CreateJobRequest.html#structfield.job_type) member.
+    pub fn job_type<T: Into<JobType>>(mut self, value: T) -> Self {
+        self.job_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_arn`](struct.CreateJobRequest.html#structfield.kms_key_arn) member.
+    pub fn kms_key_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification`](struct.CreateJobRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resources`](struct.CreateJobRequest.html#structfield.resources) member.
+    pub fn resources<T: Into<JobResource>>(mut self, value: T) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateJobRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shipping_option`](struct.CreateJobRequest.html#structfield.shipping_option) member.
+    pub fn shipping_option<T: Into<ShippingOption>>(mut self, value: T) -> Self {
+        self.shipping_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snowball_capacity_preference`](struct.CreateJobRequest.html#structfield.snowball_capacity_preference) member.
+    pub fn snowball_capacity_preference<T: Into<SnowballCapacity>>(mut self, value: T) -> Self {
+        self.snowball_capacity_preference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snowball_type`](struct.CreateJobRequest.html#structfield.snowball_type) member.
+    pub fn snowball_type<T: Into<SnowballType>>(mut self, value: T) -> Self {
+        self.snowball_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateJobRequest, ::rusoto_core::BuildError> {
+        Ok(CreateJobRequest {
+            address_id: self.address_id,
+            cluster_id: self.cluster_id,
+            description: self.description,
+            forwarding_address_id: self.forwarding_address_id,
+            job_type: self.job_type,
+            kms_key_arn: self.kms_key_arn,
+            notification: self.notification,
+            resources: self.resources,
+            role_arn: self.role_arn,
+            shipping_option: self.shipping_option,
+            snowball_capacity_preference: self.snowball_capacity_preference,
+            snowball_type: self.snowball_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -354,6 +757,36 @@ pub struct DescribeAddressRequest {
     pub address_id: String,
 }
 
+impl DescribeAddressRequest {
+    /// Returns a builder for `DescribeAddressRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAddressRequestBuilder {
+        DescribeAddressRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAddressRequest`](struct.DescribeAddressRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAddressRequestBuilder {
+    address_id: Option<String>,
+}
+
+impl DescribeAddressRequestBuilder {
+    /// Sets the [`address_id`](struct.DescribeAddressRequest.html#structfield.address_id) member.
+    pub fn address_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.address_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAddressRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeAddressRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAddressRequest {
+            address_id: self
+                .address_id
+                .ok_or(::rusoto_core::BuildError::missing("address_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAddressResult {
@@ -375,6 +808,44 @@ pub struct DescribeAddressesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeAddressesRequest {
+    /// Returns a builder for `DescribeAddressesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAddressesRequestBuilder {
+        DescribeAddressesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAddressesRequest`](struct.DescribeAddressesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAddressesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeAddressesRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeAddressesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeAddressesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAddressesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAddressesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAddressesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAddressesResult {
@@ -395,6 +866,36 @@ pub struct DescribeClusterRequest {
     pub cluster_id: String,
 }
 
+impl DescribeClusterRequest {
+    /// Returns a builder for `DescribeClusterRequest`, checking its required members when built.
+    pub fn builder() -> DescribeClusterRequestBuilder {
+        DescribeClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterRequest`](struct.DescribeClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterRequestBuilder {
+    cluster_id: Option<String>,
+}
+
+impl DescribeClusterRequestBuilder {
+    /// Sets the [`cluster_id`](struct.DescribeClusterRequest.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeClusterRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeClusterRequest {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeClusterResult {
@@ -411,6 +912,36 @@ pub struct DescribeJobRequest {
     pub job_id: String,
 }
 
+impl DescribeJobRequest {
+    /// Returns a builder for `DescribeJobRequest`, checking its required members when built.
+    pub fn builder() -> DescribeJobRequestBuilder {
+        DescribeJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeJobRequest`](struct.DescribeJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl DescribeJobRequestBuilder {
+    /// Sets the [`job_id`](struct.DescribeJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeJobRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeJobResult {
@@ -452,6 +983,36 @@ pub struct GetJobManifestRequest {
     pub job_id: String,
 }
 
+impl GetJobManifestRequest {
+    /// Returns a builder for `GetJobManifestRequest`, checking its required members when built.
+    pub fn builder() -> GetJobManifestRequestBuilder {
+        GetJobManifestRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobManifestRequest`](struct.GetJobManifestRequest.html).
+#[derive(Default, Debug)]
+pub struct GetJobManifestRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl GetJobManifestRequestBuilder {
+    /// Sets the [`job_id`](struct.GetJobManifestRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobManifestRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetJobManifestRequest, ::rusoto_core::BuildError> {
+        Ok(GetJobManifestRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetJobManifestResult {
@@ -468,6 +1029,38 @@ pub struct GetJobUnlockCodeRequest {
     pub job_id: String,
 }
 
+impl GetJobUnlockCodeRequest {
+    /// Returns a builder for `GetJobUnlockCodeRequest`, checking its required members when built.
+    pub fn builder() -> GetJobUnlockCodeRequestBuilder {
+        GetJobUnlockCodeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobUnlockCodeRequest`](struct.GetJobUnlockCodeRequest.html).
+#[derive(Default, Debug)]
+pub struct GetJobUnlockCodeRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl GetJobUnlockCodeRequestBuilder {
+    /// Sets the [`job_id`](struct.GetJobUnlockCodeRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobUnlockCodeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetJobUnlockCodeRequest, ::rusoto_core::BuildError> {
+        Ok(GetJobUnlockCodeRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetJobUnlockCodeResult {
@@ -499,8 +1092,9 @@ pub struct GetSnowballUsageResult {
 pub struct JobListEntry {
     /// <p>The creation date for this job.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The optional description of this specific job, for example <code>Important Photos 2016-08-11</code>.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -516,15 +1110,15 @@ pub struct JobListEntry {
     /// <p>The current state of this job.</p>
     #[serde(rename = "JobState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_state: Option<String>,
+    pub job_state: Option<JobState>,
     /// <p>The type of job.</p>
     #[serde(rename = "JobType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     /// <p>The type of device used with this job.</p>
     #[serde(rename = "SnowballType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_type: Option<String>,
+    pub snowball_type: Option<SnowballType>,
 }
 
 /// <p>Contains job logs. Whenever Snowball is used to import data into or export data out of Amazon S3, you'll have the option of downloading a PDF job report. Job logs are returned as a part of the response syntax of the <code>DescribeJob</code> action in the <code>JobMetadata</code> data type. The job logs can be accessed for up to 60 minutes after this request has been made. To access any of the job logs after 60 minutes have passed, you'll have to make another call to the <code>DescribeJob</code> action.</p> <p>For import jobs, the PDF job report becomes available at the end of the import process. For export jobs, your job report typically becomes available while the Snowball for your job part is being delivered to you.</p> <p>The job report provides you insight into the state of your Amazon S3 data transfer. The report includes details about your job or job part for your records.</p> <p>For deeper visibility into the status of your transferred objects, you can look at the two associated logs: a success log and a failure log. The logs are saved in comma-separated value (CSV) format, and the name of each log includes the ID of the job or job part that the log describes.</p>
@@ -559,8 +1153,9 @@ pub struct JobMetadata {
     pub cluster_id: Option<String>,
     /// <p>The creation date for this job.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>A value that defines the real-time status of a Snowball's data transfer while the device is at AWS. This data is only available while a job has a <code>JobState</code> value of <code>InProgress</code>, for both import and export jobs.</p>
     #[serde(rename = "DataTransferProgress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -584,11 +1179,11 @@ pub struct JobMetadata {
     /// <p>The current status of the jobs.</p>
     #[serde(rename = "JobState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_state: Option<String>,
+    pub job_state: Option<JobState>,
     /// <p>The type of job.</p>
     #[serde(rename = "JobType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     /// <p>The Amazon Resource Name (ARN) for the AWS Key Management Service (AWS KMS) key associated with this job. This ARN was created using the <a href="http://docs.aws.amazon.com/kms/latest/APIReference/API_CreateKey.html">CreateKey</a> API action in AWS KMS.</p>
     #[serde(rename = "KmsKeyARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -612,11 +1207,11 @@ pub struct JobMetadata {
     /// <p>The Snowball capacity preference for this job, specified at job creation. In US regions, you can choose between 50 TB and 80 TB Snowballs. All other regions use 80 TB capacity Snowballs.</p>
     #[serde(rename = "SnowballCapacityPreference")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_capacity_preference: Option<String>,
+    pub snowball_capacity_preference: Option<SnowballCapacity>,
     /// <p>The type of device used with this job.</p>
     #[serde(rename = "SnowballType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_type: Option<String>,
+    pub snowball_type: Option<SnowballType>,
 }
 
 /// <p>Contains an array of AWS resource objects. Each object represents an Amazon S3 bucket, an AWS Lambda function, or an Amazon Machine Image (AMI) based on Amazon EC2 that is associated with a particular job.</p>
@@ -636,6 +1231,198 @@ pub struct JobResource {
     pub s3_resources: Option<Vec<S3Resource>>,
 }
 
+/// <p>The current state of this job.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobState {
+    /// <p><code>New</code></p>
+    New,
+    /// <p><code>PreparingAppliance</code></p>
+    PreparingAppliance,
+    /// <p><code>PreparingShipment</code></p>
+    PreparingShipment,
+    /// <p><code>InTransitToCustomer</code></p>
+    InTransitToCustomer,
+    /// <p><code>WithCustomer</code></p>
+    WithCustomer,
+    /// <p><code>InTransitToAWS</code></p>
+    InTransitToAWS,
+    /// <p><code>WithAWSSortingFacility</code></p>
+    WithAWSSortingFacility,
+    /// <p><code>WithAWS</code></p>
+    WithAWS,
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Complete</code></p>
+    Complete,
+    /// <p><code>Cancelled</code></p>
+    Cancelled,
+    /// <p><code>Listing</code></p>
+    Listing,
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobState::New => "New",
+            JobState::PreparingAppliance => "PreparingAppliance",
+            JobState::PreparingShipment => "PreparingShipment",
+            JobState::InTransitToCustomer => "InTransitToCustomer",
+            JobState::WithCustomer => "WithCustomer",
+            JobState::InTransitToAWS => "InTransitToAWS",
+            JobState::WithAWSSortingFacility => "WithAWSSortingFacility",
+            JobState::WithAWS => "WithAWS",
+            JobState::InProgress => "InProgress",
+            JobState::Complete => "Complete",
+            JobState::Cancelled => "Cancelled",
+            JobState::Listing => "Listing",
+            JobState::Pending => "Pending",
+            JobState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobState {
+    fn from(value: &'a str) -> JobState {
+        match value {
+            "New" => JobState::New,
+            "PreparingAppliance" => JobState::PreparingAppliance,
+            "PreparingShipment" => JobState::PreparingShipment,
+            "InTransitToCustomer" => JobState::InTransitToCustomer,
+            "WithCustomer" => JobState::WithCustomer,
+            "InTransitToAWS" => JobState::InTransitToAWS,
+            "WithAWSSortingFacility" => JobState::WithAWSSortingFacility,
+            "WithAWS" => JobState::WithAWS,
+            "InProgress" => JobState::InProgress,
+            "Complete" => JobState::Complete,
+            "Cancelled" => JobState::Cancelled,
+            "Listing" => JobState::Listing,
+            "Pending" => JobState::Pending,
+            _ => JobState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobState {
+    fn from(value: String) -> JobState {
+        JobState::from(value.as_str())
+    }
+}
+
+impl From<JobState> for String {
+    fn from(value: JobState) -> String {
+        match value {
+            JobState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for JobState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobState::from(value))
+    }
+}
+
+/// <p>The type of job for this cluster. Currently, the only job type supported for clusters is <code>LOCAL_USE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobType {
+    /// <p><code>IMPORT</code></p>
+    Import,
+    /// <p><code>EXPORT</code></p>
+    Export,
+    /// <p><code>LOCAL_USE</code></p>
+    LocalUse,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobType::Import => "IMPORT",
+            JobType::Export => "EXPORT",
+            JobType::LocalUse => "LOCAL_USE",
+            JobType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobType {
+    fn from(value: &'a str) -> JobType {
+        match value {
+            "IMPORT" => JobType::Import,
+            "EXPORT" => JobType::Export,
+            "LOCAL_USE" => JobType::LocalUse,
+            _ => JobType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobType {
+    fn from(value: String) -> JobType {
+        JobType::from(value.as_str())
+    }
+}
+
+impl From<JobType> for String {
+    fn from(value: JobType) -> String {
+        match value {
+            JobType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for JobType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobType::from(value))
+    }
+}
+
 /// <p>Contains a key range. For export jobs, a <code>S3Resource</code> object can have an optional <code>KeyRange</code> value. The length of the range is defined at job creation, and has either an inclusive <code>BeginMarker</code>, an inclusive <code>EndMarker</code>, or both. Ranges are UTF-8 binary sorted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyRange {
@@ -677,6 +1464,52 @@ pub struct ListClusterJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListClusterJobsRequest {
+    /// Returns a builder for `ListClusterJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListClusterJobsRequestBuilder {
+        ListClusterJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListClusterJobsRequest`](struct.ListClusterJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListClusterJobsRequestBuilder {
+    cluster_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListClusterJobsRequestBuilder {
+    /// Sets the [`cluster_id`](struct.ListClusterJobsRequest.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListClusterJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListClusterJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListClusterJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListClusterJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListClusterJobsRequest {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListClusterJobsResult {
@@ -702,6 +1535,42 @@ pub struct ListClustersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListClustersRequest {
+    /// Returns a builder for `ListClustersRequest`, checking its required members when built.
+    pub fn builder() -> ListClustersRequestBuilder {
+        ListClustersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListClustersRequest`](struct.ListClustersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListClustersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListClustersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListClustersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListClustersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListClustersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListClustersRequest, ::rusoto_core::BuildError> {
+        Ok(ListClustersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListClustersResult {
@@ -727,6 +1596,44 @@ pub struct ListCompatibleImagesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListCompatibleImagesRequest {
+    /// Returns a builder for `ListCompatibleImagesRequest`, checking its required members when built.
+    pub fn builder() -> ListCompatibleImagesRequestBuilder {
+        ListCompatibleImagesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCompatibleImagesRequest`](struct.ListCompatibleImagesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCompatibleImagesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListCompatibleImagesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListCompatibleImagesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListCompatibleImagesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCompatibleImagesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCompatibleImagesRequest, ::rusoto_core::BuildError> {
+        Ok(ListCompatibleImagesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListCompatibleImagesResult {
@@ -752,6 +1659,42 @@ pub struct ListJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListJobsRequest {
+    /// Returns a builder for `ListJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListJobsRequestBuilder {
+        ListJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJobsRequest`](struct.ListJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJobsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListJobsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListJobsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListJobsResult {
@@ -771,7 +1714,7 @@ pub struct Notification {
     /// <p>The list of job states that will trigger a notification for this job.</p>
     #[serde(rename = "JobStatesToNotify")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_states_to_notify: Option<Vec<String>>,
+    pub job_states_to_notify: Option<Vec<JobState>>,
     /// <p>Any change in job state will trigger a notification for this job.</p>
     #[serde(rename = "NotifyAll")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -824,7 +1767,299 @@ pub struct ShippingDetails {
     /// <p><p>The shipping speed for a particular job. This speed doesn&#39;t dictate how soon you&#39;ll get the Snowball from the job&#39;s creation date. This speed represents how quickly it moves to its destination while in transit. Regional shipping speeds are as follows:</p> <ul> <li> <p>In Australia, you have access to express shipping. Typically, Snowballs shipped express are delivered in about a day.</p> </li> <li> <p>In the European Union (EU), you have access to express shipping. Typically, Snowballs shipped express are delivered in about a day. In addition, most countries in the EU have access to standard shipping, which typically takes less than a week, one way.</p> </li> <li> <p>In India, Snowballs are delivered in one to seven days.</p> </li> <li> <p>In the United States of America (US), you have access to one-day shipping and two-day shipping.</p> </li> </ul></p>
     #[serde(rename = "ShippingOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_option: Option<String>,
+    pub shipping_option: Option<ShippingOption>,
+}
+
+/// <p><p>The shipping speed for each node in this cluster. This speed doesn&#39;t dictate how soon you&#39;ll get each device, rather it represents how quickly each device moves to its destination while in transit. Regional shipping speeds are as follows:</p> <ul> <li> <p>In Australia, you have access to express shipping. Typically, devices shipped express are delivered in about a day.</p> </li> <li> <p>In the European Union (EU), you have access to express shipping. Typically, devices shipped express are delivered in about a day. In addition, most countries in the EU have access to standard shipping, which typically takes less than a week, one way.</p> </li> <li> <p>In India, devices are delivered in one to seven days.</p> </li> <li> <p>In the US, you have access to one-day shipping and two-day shipping.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShippingOption {
+    /// <p><code>SECOND_DAY</code></p>
+    SecondDay,
+    /// <p><code>NEXT_DAY</code></p>
+    NextDay,
+    /// <p><code>EXPRESS</code></p>
+    Express,
+    /// <p><code>STANDARD</code></p>
+    Standard,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ShippingOption {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShippingOption::SecondDay => "SECOND_DAY",
+            ShippingOption::NextDay => "NEXT_DAY",
+            ShippingOption::Express => "EXPRESS",
+            ShippingOption::Standard => "STANDARD",
+            ShippingOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ShippingOption {
+    fn from(value: &'a str) -> ShippingOption {
+        match value {
+            "SECOND_DAY" => ShippingOption::SecondDay,
+            "NEXT_DAY" => ShippingOption::NextDay,
+            "EXPRESS" => ShippingOption::Express,
+            "STANDARD" => ShippingOption::Standard,
+            _ => ShippingOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShippingOption {
+    fn from(value: String) -> ShippingOption {
+        ShippingOption::from(value.as_str())
+    }
+}
+
+impl From<ShippingOption> for String {
+    fn from(value: ShippingOption) -> String {
+        match value {
+            ShippingOption::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ShippingOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ShippingOption {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShippingOption {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ShippingOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ShippingOption::from(value))
+    }
+}
+
+/// <p>If your job is being created in one of the US regions, you have the option of specifying what size Snowball you'd like for this job. In all other regions, Snowballs come with 80 TB in storage capacity.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SnowballCapacity {
+    /// <p><code>T50</code></p>
+    T50,
+    /// <p><code>T80</code></p>
+    T80,
+    /// <p><code>T100</code></p>
+    T100,
+    /// <p><code>T42</code></p>
+    T42,
+    /// <p><code>T98</code></p>
+    T98,
+    /// <p><code>T8</code></p>
+    T8,
+    /// <p><code>T14</code></p>
+    T14,
+    /// <p><code>T32</code></p>
+    T32,
+    /// <p><code>NoPreference</code></p>
+    NoPreference,
+    /// <p><code>T240</code></p>
+    T240,
+    /// <p><code>T13</code></p>
+    T13,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SnowballCapacity {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SnowballCapacity::T50 => "T50",
+            SnowballCapacity::T80 => "T80",
+            SnowballCapacity::T100 => "T100",
+            SnowballCapacity::T42 => "T42",
+            SnowballCapacity::T98 => "T98",
+            SnowballCapacity::T8 => "T8",
+            SnowballCapacity::T14 => "T14",
+            SnowballCapacity::T32 => "T32",
+            SnowballCapacity::NoPreference => "NoPreference",
+            SnowballCapacity::T240 => "T240",
+            SnowballCapacity::T13 => "T13",
+            SnowballCapacity::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SnowballCapacity {
+    fn from(value: &'a str) -> SnowballCapacity {
+        match value {
+            "T50" => SnowballCapacity::T50,
+            "T80" => SnowballCapacity::T80,
+            "T100" => SnowballCapacity::T100,
+            "T42" => SnowballCapacity::T42,
+            "T98" => SnowballCapacity::T98,
+            "T8" => SnowballCapacity::T8,
+            "T14" => SnowballCapacity::T14,
+            "T32" => SnowballCapacity::T32,
+            "NoPreference" => SnowballCapacity::NoPreference,
+            "T240" => SnowballCapacity::T240,
+            "T13" => SnowballCapacity::T13,
+            _ => SnowballCapacity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SnowballCapacity {
+    fn from(value: String) -> SnowballCapacity {
+        SnowballCapacity::from(value.as_str())
+    }
+}
+
+impl From<SnowballCapacity> for String {
+    fn from(value: SnowballCapacity) -> String {
+        match value {
+            SnowballCapacity::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SnowballCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SnowballCapacity {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SnowballCapacity {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SnowballCapacity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SnowballCapacity::from(value))
+    }
+}
+
+/// <p>The type of AWS Snowball device to use for this cluster. The only supported device types for cluster jobs are <code>EDGE</code>, <code>EDGE_C</code>, and <code>EDGE_CG</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SnowballType {
+    /// <p><code>STANDARD</code></p>
+    Standard,
+    /// <p><code>EDGE</code></p>
+    Edge,
+    /// <p><code>EDGE_C</code></p>
+    EdgeC,
+    /// <p><code>EDGE_CG</code></p>
+    EdgeCg,
+    /// <p><code>EDGE_S</code></p>
+    EdgeS,
+    /// <p><code>SNC1_HDD</code></p>
+    Snc1Hdd,
+    /// <p><code>SNC1_SSD</code></p>
+    Snc1Ssd,
+    /// <p><code>V3_5C</code></p>
+    V35c,
+    /// <p><code>V3_5S</code></p>
+    V35s,
+    /// <p><code>RACK_5U_C</code></p>
+    Rack5uC,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SnowballType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SnowballType::Standard => "STANDARD",
+            SnowballType::Edge => "EDGE",
+            SnowballType::EdgeC => "EDGE_C",
+            SnowballType::EdgeCg => "EDGE_CG",
+            SnowballType::EdgeS => "EDGE_S",
+            SnowballType::Snc1Hdd => "SNC1_HDD",
+            SnowballType::Snc1Ssd => "SNC1_SSD",
+            SnowballType::V35c => "V3_5C",
+            SnowballType::V35s => "V3_5S",
+            SnowballType::Rack5uC => "RACK_5U_C",
+            SnowballType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SnowballType {
+    fn from(value: &'a str) -> SnowballType {
+        match value {
+            "STANDARD" => SnowballType::Standard,
+            "EDGE" => SnowballType::Edge,
+            "EDGE_C" => SnowballType::EdgeC,
+            "EDGE_CG" => SnowballType::EdgeCg,
+            "EDGE_S" => SnowballType::EdgeS,
+            "SNC1_HDD" => SnowballType::Snc1Hdd,
+            "SNC1_SSD" => SnowballType::Snc1Ssd,
+            "V3_5C" => SnowballType::V35c,
+            "V3_5S" => SnowballType::V35s,
+            "RACK_5U_C" => SnowballType::Rack5uC,
+            _ => SnowballType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SnowballType {
+    fn from(value: String) -> SnowballType {
+        SnowballType::from(value.as_str())
+    }
+}
+
+impl From<SnowballType> for String {
+    fn from(value: SnowballType) -> String {
+        match value {
+            SnowballType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SnowballType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SnowballType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SnowballType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SnowballType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SnowballType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -859,7 +2094,93 @@ pub struct UpdateClusterRequest {
     /// <p>The updated shipping option value of this cluster's <a>ShippingDetails</a> object.</p>
     #[serde(rename = "ShippingOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_option: Option<String>,
+    pub shipping_option: Option<ShippingOption>,
+}
+
+impl UpdateClusterRequest {
+    /// Returns a builder for `UpdateClusterRequest`, checking its required members when built.
+    pub fn builder() -> UpdateClusterRequestBuilder {
+        UpdateClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateClusterRequest`](struct.UpdateClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateClusterRequestBuilder {
+    address_id: Option<String>,
+    cluster_id: Option<String>,
+    description: Option<String>,
+    forwarding_address_id: Option<String>,
+    notification: Option<Notification>,
+    resources: Option<JobResource>,
+    role_arn: Option<String>,
+    shipping_option: Option<ShippingOption>,
+}
+
+impl UpdateClusterRequestBuilder {
+    /// Sets the [`address_id`](struct.UpdateClusterRequest.html#structfield.address_id) member.
+    pub fn address_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.address_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_id`](struct.UpdateClusterRequest.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateClusterRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`forwarding_address_id`](struct.UpdateClusterRequest.html#structfield.forwarding_address_id) member.
+    pub fn forwarding_address_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.forwarding_address_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification`](struct.UpdateClusterRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resources`](struct.UpdateClusterRequest.html#structfield.resources) member.
+    pub fn resources<T: Into<JobResource>>(mut self, value: T) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.UpdateClusterRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shipping_option`](struct.UpdateClusterRequest.html#structfield.shipping_option) member.
+    pub fn shipping_option<T: Into<ShippingOption>>(mut self, value: T) -> Self {
+        self.shipping_option = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateClusterRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateClusterRequest {
+            address_id: self.address_id,
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            description: self.description,
+            forwarding_address_id: self.forwarding_address_id,
+            notification: self.notification,
+            resources: self.resources,
+            role_arn: self.role_arn,
+            shipping_option: self.shipping_option,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -898,11 +2219,105 @@ pub struct UpdateJobRequest {
     /// <p>The updated shipping option value of this job's <a>ShippingDetails</a> object.</p>
     #[serde(rename = "ShippingOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_option: Option<String>,
+    pub shipping_option: Option<ShippingOption>,
     /// <p>The updated <code>SnowballCapacityPreference</code> of this job's <a>JobMetadata</a> object. The 50 TB Snowballs are only available in the US regions.</p>
     #[serde(rename = "SnowballCapacityPreference")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snowball_capacity_preference: Option<String>,
+    pub snowball_capacity_preference: Option<SnowballCapacity>,
+}
+
+impl UpdateJobRequest {
+    /// Returns a builder for `UpdateJobRequest`, checking its required members when built.
+    pub fn builder() -> UpdateJobRequestBuilder {
+        UpdateJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateJobRequest`](struct.UpdateJobRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateJobRequestBuilder {
+    address_id: Option<String>,
+    description: Option<String>,
+    forwarding_address_id: Option<String>,
+    job_id: Option<String>,
+    notification: Option<Notification>,
+    resources: Option<JobResource>,
+    role_arn: Option<String>,
+    shipping_option: Option<ShippingOption>,
+    snowball_capacity_preference: Option<SnowballCapacity>,
+}
+
+impl UpdateJobRequestBuilder {
+    /// Sets the [`address_id`](struct.UpdateJobRequest.html#structfield.address_id) member.
+    pub fn address_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.address_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateJobRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`forwarding_address_id`](struct.UpdateJobRequest.html#structfield.forwarding_address_id) member.
+    pub fn forwarding_address_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.forwarding_address_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.UpdateJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification`](struct.UpdateJobRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resources`](struct.UpdateJobRequest.html#structfield.resources) member.
+    pub fn resources<T: Into<JobResource>>(mut self, value: T) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.UpdateJobRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shipping_option`](struct.UpdateJobRequest.html#structfield.shipping_option) member.
+    pub fn shipping_option<T: Into<ShippingOption>>(mut self, value: T) -> Self {
+        self.shipping_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snowball_capacity_preference`](struct.UpdateJobRequest.html#structfield.snowball_capacity_preference) member.
+    pub fn snowball_capacity_preference<T: Into<SnowballCapacity>>(mut self, value: T) -> Self {
+        self.snowball_capacity_preference = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateJobRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateJobRequest {
+            address_id: self.address_id,
+            description: self.description,
+            forwarding_address_id: self.forwarding_address_id,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            notification: self.notification,
+            resources: self.resources,
+            role_arn: self.role_arn,
+            shipping_option: self.shipping_option,
+            snowball_capacity_preference: self.snowball_capacity_preference,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1754,6 +3169,108 @@ pub trait Snowball {
     /// <p>While a job's <code>JobState</code> value is <code>New</code>, you can update some of the information associated with a job. Once the job changes to a different job state, usually within 60 minutes of the job being created, this action is no longer available.</p>
     fn update_job(&self, input: UpdateJobRequest) -> RusotoFuture<UpdateJobResult, UpdateJobError>;
 }
+impl<T: ?Sized + Snowball> Snowball for ::std::sync::Arc<T> {
+    fn cancel_cluster(
+        &self,
+        input: CancelClusterRequest,
+    ) -> RusotoFuture<CancelClusterResult, CancelClusterError> {
+        (**self).cancel_cluster(input)
+    }
+    fn cancel_job(&self, input: CancelJobRequest) -> RusotoFuture<CancelJobResult, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_address(
+        &self,
+        input: CreateAddressRequest,
+    ) -> RusotoFuture<CreateAddressResult, CreateAddressError> {
+        (**self).create_address(input)
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_job(&self, input: CreateJobRequest) -> RusotoFuture<CreateJobResult, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn describe_address(
+        &self,
+        input: DescribeAddressRequest,
+    ) -> RusotoFuture<DescribeAddressResult, DescribeAddressError> {
+        (**self).describe_address(input)
+    }
+    fn describe_addresses(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> RusotoFuture<DescribeAddressesResult, DescribeAddressesError> {
+        (**self).describe_addresses(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResult, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_job(
+        &self,
+        input: DescribeJobRequest,
+    ) -> RusotoFuture<DescribeJobResult, DescribeJobError> {
+        (**self).describe_job(input)
+    }
+    fn get_job_manifest(
+        &self,
+        input: GetJobManifestRequest,
+    ) -> RusotoFuture<GetJobManifestResult, GetJobManifestError> {
+        (**self).get_job_manifest(input)
+    }
+    fn get_job_unlock_code(
+        &self,
+        input: GetJobUnlockCodeRequest,
+    ) -> RusotoFuture<GetJobUnlockCodeResult, GetJobUnlockCodeError> {
+        (**self).get_job_unlock_code(input)
+    }
+    fn get_snowball_usage(&self) -> RusotoFuture<GetSnowballUsageResult, GetSnowballUsageError> {
+        (**self).get_snowball_usage()
+    }
+    fn list_cluster_jobs(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> RusotoFuture<ListClusterJobsResult, ListClusterJobsError> {
+        (**self).list_cluster_jobs(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResult, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_compatible_images(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> RusotoFuture<ListCompatibleImagesResult, ListCompatibleImagesError> {
+        (**self).list_compatible_images(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> RusotoFuture<UpdateClusterResult, UpdateClusterError> {
+        (**self).update_cluster(input)
+    }
+    fn update_job(&self, input: UpdateJobRequest) -> RusotoFuture<UpdateJobResult, UpdateJobError> {
+        (**self).update_job(input)
+    }
+}
+/// The error codes Amazon Snowball retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Snowball API.
 #[derive(Clone)]
 pub struct SnowballClient {
@@ -1769,6 +3286,27 @@ impl SnowballClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SNOWBALL`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> SnowballClient {
+        Self::new(region::Region::from_env_for_service("SNOWBALL"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `snowball`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> SnowballClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("snowball", "SNOWBALL"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1787,7 +3325,54 @@ impl SnowballClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SnowballClient {
-        SnowballClient { client, region }
+        SnowballClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "snowball")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "snowball",
+            "snowball",
+            "SNOWBALL",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Snowball` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Snowball + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for SnowballClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Snowball"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "snowball"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> SnowballClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Simple Notification Service clients.
+//!
+//! Makes a single read-only `ListPhoneNumbersOptedOut` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_sns::{ListPhoneNumbersOptedOutInput, Sns, SnsClient};
+
+fn main() {
+    let client = SnsClient::from_env();
+    match client
+        .list_phone_numbers_opted_out(ListPhoneNumbersOptedOutInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListPhoneNumbersOptedOut succeeded"),
+        Err(error) => {
+            eprintln!("ListPhoneNumbersOptedOut failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(feature = "verification")]
+extern crate rusoto_mock;
+
+#[cfg(feature = "verification")]
+use crate::verification::{SnsMessageVerifier, VerificationError};
+
+#[cfg(feature = "verification")]
+use self::rusoto_mock::*;
+#[cfg(any(feature = "verification", feature = "fake"))]
+use rusoto_core::RusotoError;
+
+#[cfg(feature = "verification")]
+fn verifier() -> SnsMessageVerifier<MockRequestDispatcher> {
+    let certificate =
+        MockResponseReader::read_response("test_resources/custom", "signing_cert.pem");
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(&certificate)
+        .with_request_checker(|request| {
+            assert_eq!(request.method, "GET");
+            assert_eq!(request.hostname(), "sns.us-east-1.amazonaws.com");
+            assert_eq!(
+                request.path,
+                "/SimpleNotificationService-0000000000000000000000.pem"
+            );
+        });
+    SnsMessageVerifier::new_with(mock)
+}
+
+#[cfg(feature = "verification")]
+#[test]
+fn should_verify_signed_notification() {
+    let body = MockResponseReader::read_response("test_resources/custom", "sns_notification.json");
+    let message = verifier().verify(&body).sync().unwrap();
+    assert_eq!(message.message_type, "Notification");
+    assert_eq!(message.subject, Some("My First Message".to_owned()));
+    assert_eq!(message.message, "Hello world!");
+}
+
+#[cfg(feature = "verification")]
+#[test]
+fn should_reject_tampered_notification() {
+    let body = MockResponseReader::read_response("test_resources/custom", "sns_notification.json")
+        .replace("Hello world!", "Goodbye world!");
+    assert_eq!(
+        verifier().verify(&body).sync().err(),
+        Some(RusotoError::Service(VerificationError::InvalidSignature))
+    );
+}
+
+#[cfg(feature = "verification")]
+#[test]
+fn should_reject_certificate_not_hosted_by_sns() {
+    let body = MockResponseReader::read_response("test_resources/custom", "sns_notification.json")
+        .replace("sns.us-east-1.amazonaws.com/", "attacker.example.com/");
+    match verifier().verify(&body).sync() {
+        Err(RusotoError::Service(VerificationError::UntrustedCertificateUrl(_))) => (),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[cfg(feature = "fake")]
+#[test]
+fn fake_sns_records_published_messages() {
+    use crate::fake::FakeSns;
+    use crate::generated::{
+        CreateTopicInput, ListSubscriptionsByTopicInput, PublishError, PublishInput, Sns,
+        SubscribeInput,
+    };
+
+    let sns = FakeSns::new();
+    let topic_arn = sns
+        .create_topic(CreateTopicInput {
+            name: "alerts".to_owned(),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap()
+        .topic_arn
+        .unwrap();
+    sns.subscribe(SubscribeInput {
+        topic_arn: topic_arn.clone(),
+        protocol: "email".to_owned(),
+        endpoint: Some("oncall@example.com".to_owned()),
+        ..Default::default()
+    })
+    .sync()
+    .unwrap();
+    let subscriptions = sns
+        .list_subscriptions_by_topic(ListSubscriptionsByTopicInput {
+            topic_arn: topic_arn.clone(),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap()
+        .subscriptions
+        .unwrap();
+    assert_eq!(
+        subscriptions[0].endpoint,
+        Some("oncall@example.com".to_owned())
+    );
+
+    let message_id = sns
+        .publish(PublishInput {
+            topic_arn: Some(topic_arn.clone()),
+            subject: Some("Disk full".to_owned()),
+            message: "/var is at 100%".to_owned(),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap()
+        .message_id;
+    let published = sns.backend().published_messages(&topic_arn);
+    assert_eq!(published.len(), 1);
+    assert_eq!(Some(published[0].message_id.clone()), message_id);
+    assert_eq!(published[0].message, "/var is at 100%");
+
+    match sns
+        .publish(PublishInput {
+            topic_arn: Some(format!("{}-missing", topic_arn)),
+            message: "lost".to_owned(),
+            ..Default::default()
+        })
+        .sync()
+    {
+        Err(RusotoError::Service(PublishError::NotFound(_))) => {}
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+}
@@ -0,0 +1,226 @@
+//! An SNS backend keeping topics and subscriptions in memory.
+//!
+//! `FakeSns::new()` creates a fake SNS backed by an empty `InMemorySns`, which can stand in for an
+//! `SnsClient` in unit tests. Topics can be created, listed and deleted, endpoints subscribed to
+//! and unsubscribed from them, and messages published to them. Nothing is delivered: published
+//! messages are recorded, and can be inspected with `InMemorySns::published_messages`. The other
+//! operations fail with `RusotoError::Validation`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+use rusoto_core::RusotoError;
+
+use crate::fake::{FakeSns, SnsBackend};
+use crate::generated::{
+    CreateTopicError, CreateTopicInput, CreateTopicResponse, DeleteTopicError, DeleteTopicInput,
+    ListSubscriptionsByTopicError, ListSubscriptionsByTopicInput, ListSubscriptionsByTopicResponse,
+    ListTopicsError, ListTopicsInput, ListTopicsResponse, MessageAttributeValue, PublishError,
+    PublishInput, PublishResponse, SubscribeError, SubscribeInput, SubscribeResponse, Subscription,
+    Topic, UnsubscribeError, UnsubscribeInput,
+};
+
+/// The account topics are created in.
+const ACCOUNT_ID: &str = "123456789012";
+
+/// The ARN topics are created under.
+const TOPIC_ARN_PREFIX: &str = "arn:aws:sns:us-east-1:123456789012:";
+
+/// A message published to a topic.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PublishedMessage {
+    /// The ID returned from `Publish`.
+    pub message_id: String,
+    /// The message.
+    pub message: String,
+    /// The subject, if any.
+    pub subject: Option<String>,
+    /// The message attributes, if any.
+    pub message_attributes: Option<HashMap<String, MessageAttributeValue>>,
+}
+
+#[derive(Debug, Default)]
+struct TopicState {
+    // subscriptions by their ARN
+    subscriptions: BTreeMap<String, Subscription>,
+    published: Vec<PublishedMessage>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    // topics by their ARN
+    topics: BTreeMap<String, TopicState>,
+    next_id: u64,
+}
+
+impl State {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+/// Topics, their subscriptions and published messages, kept in memory.
+#[derive(Debug, Default)]
+pub struct InMemorySns {
+    state: Mutex<State>,
+}
+
+impl InMemorySns {
+    /// The messages published to a topic, oldest first.
+    pub fn published_messages(&self, topic_arn: &str) -> Vec<PublishedMessage> {
+        self.state()
+            .topics
+            .get(topic_arn)
+            .map(|topic| topic.published.clone())
+            .unwrap_or_default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().expect("InMemorySns lock poisoned")
+    }
+}
+
+impl FakeSns<InMemorySns> {
+    /// Creates a fake SNS without any topics.
+    pub fn new() -> FakeSns<InMemorySns> {
+        FakeSns::with_backend(InMemorySns::default())
+    }
+}
+
+impl Default for FakeSns<InMemorySns> {
+    fn default() -> FakeSns<InMemorySns> {
+        FakeSns::new()
+    }
+}
+
+fn topic_not_found(topic_arn: &str) -> String {
+    format!("Topic does not exist: {}", topic_arn)
+}
+
+impl SnsBackend for InMemorySns {
+    fn create_topic(
+        &self,
+        input: CreateTopicInput,
+    ) -> Result<CreateTopicResponse, RusotoError<CreateTopicError>> {
+        let topic_arn = format!("{}{}", TOPIC_ARN_PREFIX, input.name);
+        // creating an existing topic returns its ARN
+        self.state().topics.entry(topic_arn.clone()).or_default();
+        Ok(CreateTopicResponse {
+            topic_arn: Some(topic_arn),
+        })
+    }
+
+    fn delete_topic(&self, input: DeleteTopicInput) -> Result<(), RusotoError<DeleteTopicError>> {
+        self.state()
+            .topics
+            .remove(&input.topic_arn)
+            .map(|_| ())
+            .ok_or_else(|| {
+                RusotoError::Service(DeleteTopicError::NotFound(topic_not_found(
+                    &input.topic_arn,
+                )))
+            })
+    }
+
+    fn list_subscriptions_by_topic(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> Result<ListSubscriptionsByTopicResponse, RusotoError<ListSubscriptionsByTopicError>> {
+        let state = self.state();
+        let topic = state.topics.get(&input.topic_arn).ok_or_else(|| {
+            RusotoError::Service(ListSubscriptionsByTopicError::NotFound(topic_not_found(
+                &input.topic_arn,
+            )))
+        })?;
+        Ok(ListSubscriptionsByTopicResponse {
+            next_token: None,
+            subscriptions: Some(topic.subscriptions.values().cloned().collect()),
+        })
+    }
+
+    fn list_topics(
+        &self,
+        _input: ListTopicsInput,
+    ) -> Result<ListTopicsResponse, RusotoError<ListTopicsError>> {
+        let topics = self
+            .state()
+            .topics
+            .keys()
+            .map(|topic_arn| Topic {
+                topic_arn: Some(topic_arn.clone()),
+            })
+            .collect();
+        Ok(ListTopicsResponse {
+            next_token: None,
+            topics: Some(topics),
+        })
+    }
+
+    fn publish(&self, input: PublishInput) -> Result<PublishResponse, RusotoError<PublishError>> {
+        let topic_arn = match (input.topic_arn, input.target_arn) {
+            (Some(topic_arn), _) | (None, Some(topic_arn)) => topic_arn,
+            (None, None) => return Err(RusotoError::Service(PublishError::InvalidParameter(
+                "Invalid parameter: TopicArn or TargetArn Reason: no value for required parameter"
+                    .to_owned(),
+            ))),
+        };
+        let mut state = self.state();
+        let message_id = format!("00000000-0000-0000-0000-{:012}", state.next_id());
+        let topic = state.topics.get_mut(&topic_arn).ok_or_else(|| {
+            RusotoError::Service(PublishError::NotFound(topic_not_found(&topic_arn)))
+        })?;
+        topic.published.push(PublishedMessage {
+            message_id: message_id.clone(),
+            message: input.message,
+            subject: input.subject,
+            message_attributes: input.message_attributes,
+        });
+        Ok(PublishResponse {
+            message_id: Some(message_id),
+        })
+    }
+
+    fn subscribe(
+        &self,
+        input: SubscribeInput,
+    ) -> Result<SubscribeResponse, RusotoError<SubscribeError>> {
+        let mut state = self.state();
+        let id = state.next_id();
+        let topic = state.topics.get_mut(&input.topic_arn).ok_or_else(|| {
+            RusotoError::Service(SubscribeError::NotFound(topic_not_found(&input.topic_arn)))
+        })?;
+        // subscriptions are confirmed right away
+        let subscription_arn = format!("{}:{:012}", input.topic_arn, id);
+        topic.subscriptions.insert(
+            subscription_arn.clone(),
+            Subscription {
+                endpoint: input.endpoint,
+                owner: Some(ACCOUNT_ID.to_owned()),
+                protocol: Some(input.protocol),
+                subscription_arn: Some(subscription_arn.clone()),
+                topic_arn: Some(input.topic_arn),
+            },
+        );
+        Ok(SubscribeResponse {
+            subscription_arn: Some(subscription_arn),
+        })
+    }
+
+    fn unsubscribe(&self, input: UnsubscribeInput) -> Result<(), RusotoError<UnsubscribeError>> {
+        let removed = self.state().topics.values_mut().any(|topic| {
+            topic
+                .subscriptions
+                .remove(&input.subscription_arn)
+                .is_some()
+        });
+        if removed {
+            Ok(())
+        } else {
+            Err(RusotoError::Service(UnsubscribeError::NotFound(format!(
+                "Subscription does not exist: {}",
+                input.subscription_arn
+            ))))
+        }
+    }
+}
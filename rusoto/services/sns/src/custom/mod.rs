@@ -1 +1,10 @@
+/// Verifying the signatures of messages SNS delivers to HTTP(S) endpoints
+#[cfg(feature = "verification")]
+pub mod verification;
 
+/// An in-memory backend for `FakeSns`
+#[cfg(feature = "fake")]
+pub mod in_memory;
+
+#[cfg(test)]
+mod custom_tests;
@@ -0,0 +1,505 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! An in-process fake of the Amazon SNS API for unit tests.
+//!
+//! `FakeSns` implements `Sns` by calling a `SnsBackend`, which holds the
+//! state of the fake. Backend methods fail with `RusotoError::Validation` unless they are
+//! implemented, so a backend only needs to implement the operations a test calls.
+
+use std::sync::Arc;
+
+use futures::future;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::*;
+
+/// The error returned by operations a backend doesn't implement.
+pub fn unsupported_operation<E>(operation: &str) -> RusotoError<E> {
+    RusotoError::Validation(format!("{} is not supported by this fake", operation))
+}
+
+/// The state and behaviour of a `FakeSns`.
+pub trait SnsBackend: Send + Sync {
+    fn add_permission(
+        &self,
+        _input: AddPermissionInput,
+    ) -> Result<(), RusotoError<AddPermissionError>> {
+        Err(unsupported_operation("AddPermission"))
+    }
+    fn check_if_phone_number_is_opted_out(
+        &self,
+        _input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> Result<CheckIfPhoneNumberIsOptedOutResponse, RusotoError<CheckIfPhoneNumberIsOptedOutError>>
+    {
+        Err(unsupported_operation("CheckIfPhoneNumberIsOptedOut"))
+    }
+    fn confirm_subscription(
+        &self,
+        _input: ConfirmSubscriptionInput,
+    ) -> Result<ConfirmSubscriptionResponse, RusotoError<ConfirmSubscriptionError>> {
+        Err(unsupported_operation("ConfirmSubscription"))
+    }
+    fn create_platform_application(
+        &self,
+        _input: CreatePlatformApplicationInput,
+    ) -> Result<CreatePlatformApplicationResponse, RusotoError<CreatePlatformApplicationError>>
+    {
+        Err(unsupported_operation("CreatePlatformApplication"))
+    }
+    fn create_platform_endpoint(
+        &self,
+        _input: CreatePlatformEndpointInput,
+    ) -> Result<CreateEndpointResponse, RusotoError<CreatePlatformEndpointError>> {
+        Err(unsupported_operation("CreatePlatformEndpoint"))
+    }
+    fn create_topic(
+        &self,
+        _input: CreateTopicInput,
+    ) -> Result<CreateTopicResponse, RusotoError<CreateTopicError>> {
+        Err(unsupported_operation("CreateTopic"))
+    }
+    fn delete_endpoint(
+        &self,
+        _input: DeleteEndpointInput,
+    ) -> Result<(), RusotoError<DeleteEndpointError>> {
+        Err(unsupported_operation("DeleteEndpoint"))
+    }
+    fn delete_platform_application(
+        &self,
+        _input: DeletePlatformApplicationInput,
+    ) -> Result<(), RusotoError<DeletePlatformApplicationError>> {
+        Err(unsupported_operation("DeletePlatformApplication"))
+    }
+    fn delete_topic(&self, _input: DeleteTopicInput) -> Result<(), RusotoError<DeleteTopicError>> {
+        Err(unsupported_operation("DeleteTopic"))
+    }
+    fn get_endpoint_attributes(
+        &self,
+        _input: GetEndpointAttributesInput,
+    ) -> Result<GetEndpointAttributesResponse, RusotoError<GetEndpointAttributesError>> {
+        Err(unsupported_operation("GetEndpointAttributes"))
+    }
+    fn get_platform_application_attributes(
+        &self,
+        _input: GetPlatformApplicationAttributesInput,
+    ) -> Result<
+        GetPlatformApplicationAttributesResponse,
+        RusotoError<GetPlatformApplicationAttributesError>,
+    > {
+        Err(unsupported_operation("GetPlatformApplicationAttributes"))
+    }
+    fn get_sms_attributes(
+        &self,
+        _input: GetSMSAttributesInput,
+    ) -> Result<GetSMSAttributesResponse, RusotoError<GetSMSAttributesError>> {
+        Err(unsupported_operation("GetSMSAttributes"))
+    }
+    fn get_subscription_attributes(
+        &self,
+        _input: GetSubscriptionAttributesInput,
+    ) -> Result<GetSubscriptionAttributesResponse, RusotoError<GetSubscriptionAttributesError>>
+    {
+        Err(unsupported_operation("GetSubscriptionAttributes"))
+    }
+    fn get_topic_attributes(
+        &self,
+        _input: GetTopicAttributesInput,
+    ) -> Result<GetTopicAttributesResponse, RusotoError<GetTopicAttributesError>> {
+        Err(unsupported_operation("GetTopicAttributes"))
+    }
+    fn list_endpoints_by_platform_application(
+        &self,
+        _input: ListEndpointsByPlatformApplicationInput,
+    ) -> Result<
+        ListEndpointsByPlatformApplicationResponse,
+        RusotoError<ListEndpointsByPlatformApplicationError>,
+    > {
+        Err(unsupported_operation("ListEndpointsByPlatformApplication"))
+    }
+    fn list_phone_numbers_opted_out(
+        &self,
+        _input: ListPhoneNumbersOptedOutInput,
+    ) -> Result<ListPhoneNumbersOptedOutResponse, RusotoError<ListPhoneNumbersOptedOutError>> {
+        Err(unsupported_operation("ListPhoneNumbersOptedOut"))
+    }
+    fn list_platform_applications(
+        &self,
+        _input: ListPlatformApplicationsInput,
+    ) -> Result<ListPlatformApplicationsResponse, RusotoError<ListPlatformApplicationsError>> {
+        Err(unsupported_operation("ListPlatformApplications"))
+    }
+    fn list_subscriptions(
+        &self,
+        _input: ListSubscriptionsInput,
+    ) -> Result<ListSubscriptionsResponse, RusotoError<ListSubscriptionsError>> {
+        Err(unsupported_operation("ListSubscriptions"))
+    }
+    fn list_subscriptions_by_topic(
+        &self,
+        _input: ListSubscriptionsByTopicInput,
+    ) -> Result<ListSubscriptionsByTopicResponse, RusotoError<ListSubscriptionsByTopicError>> {
+        Err(unsupported_operation("ListSubscriptionsByTopic"))
+    }
+    fn list_tags_for_resource(
+        &self,
+        _input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        Err(unsupported_operation("ListTagsForResource"))
+    }
+    fn list_topics(
+        &self,
+        _input: ListTopicsInput,
+    ) -> Result<ListTopicsResponse, RusotoError<ListTopicsError>> {
+        Err(unsupported_operation("ListTopics"))
+    }
+    fn opt_in_phone_number(
+        &self,
+        _input: OptInPhoneNumberInput,
+    ) -> Result<OptInPhoneNumberResponse, RusotoError<OptInPhoneNumberError>> {
+        Err(unsupported_operation("OptInPhoneNumber"))
+    }
+    fn publish(&self, _input: PublishInput) -> Result<PublishResponse, RusotoError<PublishError>> {
+        Err(unsupported_operation("Publish"))
+    }
+    fn remove_permission(
+        &self,
+        _input: RemovePermissionInput,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        Err(unsupported_operation("RemovePermission"))
+    }
+    fn set_endpoint_attributes(
+        &self,
+        _input: SetEndpointAttributesInput,
+    ) -> Result<(), RusotoError<SetEndpointAttributesError>> {
+        Err(unsupported_operation("SetEndpointAttributes"))
+    }
+    fn set_platform_application_attributes(
+        &self,
+        _input: SetPlatformApplicationAttributesInput,
+    ) -> Result<(), RusotoError<SetPlatformApplicationAttributesError>> {
+        Err(unsupported_operation("SetPlatformApplicationAttributes"))
+    }
+    fn set_sms_attributes(
+        &self,
+        _input: SetSMSAttributesInput,
+    ) -> Result<SetSMSAttributesResponse, RusotoError<SetSMSAttributesError>> {
+        Err(unsupported_operation("SetSMSAttributes"))
+    }
+    fn set_subscription_attributes(
+        &self,
+        _input: SetSubscriptionAttributesInput,
+    ) -> Result<(), RusotoError<SetSubscriptionAttributesError>> {
+        Err(unsupported_operation("SetSubscriptionAttributes"))
+    }
+    fn set_topic_attributes(
+        &self,
+        _input: SetTopicAttributesInput,
+    ) -> Result<(), RusotoError<SetTopicAttributesError>> {
+        Err(unsupported_operation("SetTopicAttributes"))
+    }
+    fn subscribe(
+        &self,
+        _input: SubscribeInput,
+    ) -> Result<SubscribeResponse, RusotoError<SubscribeError>> {
+        Err(unsupported_operation("Subscribe"))
+    }
+    fn tag_resource(
+        &self,
+        _input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        Err(unsupported_operation("TagResource"))
+    }
+    fn unsubscribe(&self, _input: UnsubscribeInput) -> Result<(), RusotoError<UnsubscribeError>> {
+        Err(unsupported_operation("Unsubscribe"))
+    }
+    fn untag_resource(
+        &self,
+        _input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        Err(unsupported_operation("UntagResource"))
+    }
+}
+
+/// An implementation of `Sns` calling a `SnsBackend` instead of AWS.
+///
+/// Operations are run by the backend when the returned futures are polled. Clones of the fake
+/// share the same backend.
+pub struct FakeSns<B> {
+    backend: Arc<B>,
+}
+
+impl<B> Clone for FakeSns<B> {
+    fn clone(&self) -> Self {
+        FakeSns {
+            backend: self.backend.clone(),
+        }
+    }
+}
+
+impl<B: SnsBackend> FakeSns<B> {
+    /// Creates a fake calling `backend`.
+    pub fn with_backend(backend: B) -> FakeSns<B> {
+        FakeSns {
+            backend: Arc::new(backend),
+        }
+    }
+
+    /// The backend of the fake, to set up or inspect its state.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+}
+
+impl<B: SnsBackend + 'static> Sns for FakeSns<B> {
+    fn add_permission(&self, input: AddPermissionInput) -> RusotoFuture<(), AddPermissionError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.add_permission(input)))
+    }
+    fn check_if_phone_number_is_opted_out(
+        &self,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> RusotoFuture<CheckIfPhoneNumberIsOptedOutResponse, CheckIfPhoneNumberIsOptedOutError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.check_if_phone_number_is_opted_out(input)
+        }))
+    }
+    fn confirm_subscription(
+        &self,
+        input: ConfirmSubscriptionInput,
+    ) -> RusotoFuture<ConfirmSubscriptionResponse, ConfirmSubscriptionError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.confirm_subscription(input)))
+    }
+    fn create_platform_application(
+        &self,
+        input: CreatePlatformApplicationInput,
+    ) -> RusotoFuture<CreatePlatformApplicationResponse, CreatePlatformApplicationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.create_platform_application(input)
+        }))
+    }
+    fn create_platform_endpoint(
+        &self,
+        input: CreatePlatformEndpointInput,
+    ) -> RusotoFuture<CreateEndpointResponse, CreatePlatformEndpointError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.create_platform_endpoint(input)
+        }))
+    }
+    fn create_topic(
+        &self,
+        input: CreateTopicInput,
+    ) -> RusotoFuture<CreateTopicResponse, CreateTopicError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.create_topic(input)))
+    }
+    fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_endpoint(input)))
+    }
+    fn delete_platform_application(
+        &self,
+        input: DeletePlatformApplicationInput,
+    ) -> RusotoFuture<(), DeletePlatformApplicationError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.delete_platform_application(input)
+        }))
+    }
+    fn delete_topic(&self, input: DeleteTopicInput) -> RusotoFuture<(), DeleteTopicError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_topic(input)))
+    }
+    fn get_endpoint_attributes(
+        &self,
+        input: GetEndpointAttributesInput,
+    ) -> RusotoFuture<GetEndpointAttributesResponse, GetEndpointAttributesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_endpoint_attributes(input)))
+    }
+    fn get_platform_application_attributes(
+        &self,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<GetPlatformApplicationAttributesResponse, GetPlatformApplicationAttributesError>
+    {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.get_platform_application_attributes(input)
+        }))
+    }
+    fn get_sms_attributes(
+        &self,
+        input: GetSMSAttributesInput,
+    ) -> RusotoFuture<GetSMSAttributesResponse, GetSMSAttributesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_sms_attributes(input)))
+    }
+    fn get_subscription_attributes(
+        &self,
+        input: GetSubscriptionAttributesInput,
+    ) -> RusotoFuture<GetSubscriptionAttributesResponse, GetSubscriptionAttributesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.get_subscription_attributes(input)
+        }))
+    }
+    fn get_topic_attributes(
+        &self,
+        input: GetTopicAttributesInput,
+    ) -> RusotoFuture<GetTopicAttributesResponse, GetTopicAttributesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_topic_attributes(input)))
+    }
+    fn list_endpoints_by_platform_application(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> RusotoFuture<
+        ListEndpointsByPlatformApplicationResponse,
+        ListEndpointsByPlatformApplicationError,
+    > {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.list_endpoints_by_platform_application(input)
+        }))
+    }
+    fn list_phone_numbers_opted_out(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> RusotoFuture<ListPhoneNumbersOptedOutResponse, ListPhoneNumbersOptedOutError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.list_phone_numbers_opted_out(input)
+        }))
+    }
+    fn list_platform_applications(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> RusotoFuture<ListPlatformApplicationsResponse, ListPlatformApplicationsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.list_platform_applications(input)
+        }))
+    }
+    fn list_subscriptions(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> RusotoFuture<ListSubscriptionsResponse, ListSubscriptionsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_subscriptions(input)))
+    }
+    fn list_subscriptions_by_topic(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> RusotoFuture<ListSubscriptionsByTopicResponse, ListSubscriptionsByTopicError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.list_subscriptions_by_topic(input)
+        }))
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_tags_for_resource(input)))
+    }
+    fn list_topics(
+        &self,
+        input: ListTopicsInput,
+    ) -> RusotoFuture<ListTopicsResponse, ListTopicsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_topics(input)))
+    }
+    fn opt_in_phone_number(
+        &self,
+        input: OptInPhoneNumberInput,
+    ) -> RusotoFuture<OptInPhoneNumberResponse, OptInPhoneNumberError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.opt_in_phone_number(input)))
+    }
+    fn publish(&self, input: PublishInput) -> RusotoFuture<PublishResponse, PublishError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.publish(input)))
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionInput,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.remove_permission(input)))
+    }
+    fn set_endpoint_attributes(
+        &self,
+        input: SetEndpointAttributesInput,
+    ) -> RusotoFuture<(), SetEndpointAttributesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.set_endpoint_attributes(input)))
+    }
+    fn set_platform_application_attributes(
+        &self,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<(), SetPlatformApplicationAttributesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.set_platform_application_attributes(input)
+        }))
+    }
+    fn set_sms_attributes(
+        &self,
+        input: SetSMSAttributesInput,
+    ) -> RusotoFuture<SetSMSAttributesResponse, SetSMSAttributesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.set_sms_attributes(input)))
+    }
+    fn set_subscription_attributes(
+        &self,
+        input: SetSubscriptionAttributesInput,
+    ) -> RusotoFuture<(), SetSubscriptionAttributesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.set_subscription_attributes(input)
+        }))
+    }
+    fn set_topic_attributes(
+        &self,
+        input: SetTopicAttributesInput,
+    ) -> RusotoFuture<(), SetTopicAttributesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.set_topic_attributes(input)))
+    }
+    fn subscribe(&self, input: SubscribeInput) -> RusotoFuture<SubscribeResponse, SubscribeError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.subscribe(input)))
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.tag_resource(input)))
+    }
+    fn unsubscribe(&self, input: UnsubscribeInput) -> RusotoFuture<(), UnsubscribeError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.unsubscribe(input)))
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.untag_resource(input)))
+    }
+}
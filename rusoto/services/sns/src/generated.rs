@@ -28,15 +28,19 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
 use xml::EventReader;
 
-struct AccountDeserializer;
+pub(crate) struct AccountDeserializer;
 impl AccountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -68,6 +72,66 @@ pub struct AddPermissionInput {
     pub topic_arn: String,
 }
 
+impl AddPermissionInput {
+    /// Returns a builder for `AddPermissionInput`, checking its required members when built.
+    pub fn builder() -> AddPermissionInputBuilder {
+        AddPermissionInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddPermissionInput`](struct.AddPermissionInput.html).
+#[derive(Default, Debug)]
+pub struct AddPermissionInputBuilder {
+    aws_account_id: Option<Vec<String>>,
+    action_name: Option<Vec<String>>,
+    label: Option<String>,
+    topic_arn: Option<String>,
+}
+
+impl AddPermissionInputBuilder {
+    /// Sets the [`aws_account_id`](struct.AddPermissionInput.html#structfield.aws_account_id) member.
+    pub fn aws_account_id<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.aws_account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`action_name`](struct.AddPermissionInput.html#structfield.action_name) member.
+    pub fn action_name<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.action_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`label`](struct.AddPermissionInput.html#structfield.label) member.
+    pub fn label<T: Into<String>>(mut self, value: T) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_arn`](struct.AddPermissionInput.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddPermissionInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddPermissionInput, ::rusoto_core::BuildError> {
+        Ok(AddPermissionInput {
+            aws_account_id: self
+                .aws_account_id
+                .ok_or(::rusoto_core::BuildError::missing("aws_account_id"))?,
+            action_name: self
+                .action_name
+                .ok_or(::rusoto_core::BuildError::missing("action_name"))?,
+            label: self
+                .label
+                .ok_or(::rusoto_core::BuildError::missing("label"))?,
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 /// Serialize `AddPermissionInput` contents to a `SignedRequest`.
 struct AddPermissionInputSerializer;
 impl AddPermissionInputSerializer {
@@ -92,10 +156,13 @@ impl AddPermissionInputSerializer {
     }
 }
 
-struct AttributeNameDeserializer;
+pub(crate) struct AttributeNameDeserializer;
 impl AttributeNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -103,10 +170,13 @@ impl AttributeNameDeserializer {
         Ok(obj)
     }
 }
-struct AttributeValueDeserializer;
+pub(crate) struct AttributeValueDeserializer;
 impl AttributeValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -114,10 +184,13 @@ impl AttributeValueDeserializer {
         Ok(obj)
     }
 }
-struct BooleanDeserializer;
+pub(crate) struct BooleanDeserializer;
 impl BooleanDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -132,6 +205,38 @@ pub struct CheckIfPhoneNumberIsOptedOutInput {
     pub phone_number: String,
 }
 
+impl CheckIfPhoneNumberIsOptedOutInput {
+    /// Returns a builder for `CheckIfPhoneNumberIsOptedOutInput`, checking its required members when built.
+    pub fn builder() -> CheckIfPhoneNumberIsOptedOutInputBuilder {
+        CheckIfPhoneNumberIsOptedOutInputBuilder::default()
+    }
+}
+
+/// Builds a [`CheckIfPhoneNumberIsOptedOutInput`](struct.CheckIfPhoneNumberIsOptedOutInput.html).
+#[derive(Default, Debug)]
+pub struct CheckIfPhoneNumberIsOptedOutInputBuilder {
+    phone_number: Option<String>,
+}
+
+impl CheckIfPhoneNumberIsOptedOutInputBuilder {
+    /// Sets the [`phone_number`](struct.CheckIfPhoneNumberIsOptedOutInput.html#structfield.phone_number) member.
+    pub fn phone_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.phone_number = Some(value.into());
+        self
+    }
+
+    /// Builds the `CheckIfPhoneNumberIsOptedOutInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CheckIfPhoneNumberIsOptedOutInput, ::rusoto_core::BuildError> {
+        Ok(CheckIfPhoneNumberIsOptedOutInput {
+            phone_number: self
+                .phone_number
+                .ok_or(::rusoto_core::BuildError::missing("phone_number"))?,
+        })
+    }
+}
+
 /// Serialize `CheckIfPhoneNumberIsOptedOutInput` contents to a `SignedRequest`.
 struct CheckIfPhoneNumberIsOptedOutInputSerializer;
 impl CheckIfPhoneNumberIsOptedOutInputSerializer {
@@ -152,10 +257,10 @@ pub struct CheckIfPhoneNumberIsOptedOutResponse {
     pub is_opted_out: Option<bool>,
 }
 
-struct CheckIfPhoneNumberIsOptedOutResponseDeserializer;
+pub(crate) struct CheckIfPhoneNumberIsOptedOutResponseDeserializer;
 impl CheckIfPhoneNumberIsOptedOutResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CheckIfPhoneNumberIsOptedOutResponse, XmlParseError> {
@@ -186,6 +291,56 @@ pub struct ConfirmSubscriptionInput {
     pub topic_arn: String,
 }
 
+impl ConfirmSubscriptionInput {
+    /// Returns a builder for `ConfirmSubscriptionInput`, checking its required members when built.
+    pub fn builder() -> ConfirmSubscriptionInputBuilder {
+        ConfirmSubscriptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`ConfirmSubscriptionInput`](struct.ConfirmSubscriptionInput.html).
+#[derive(Default, Debug)]
+pub struct ConfirmSubscriptionInputBuilder {
+    authenticate_on_unsubscribe: Option<String>,
+    token: Option<String>,
+    topic_arn: Option<String>,
+}
+
+impl ConfirmSubscriptionInputBuilder {
+    /// Sets the [`authenticate_on_unsubscribe`](struct.ConfirmSubscriptionInput.html#structfield.authenticate_on_unsubscribe) member.
+    pub fn authenticate_on_unsubscribe<T: Into<String>>(mut self, value: T) -> Self {
+        self.authenticate_on_unsubscribe = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token`](struct.ConfirmSubscriptionInput.html#structfield.token) member.
+    pub fn token<T: Into<String>>(mut self, value: T) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_arn`](struct.ConfirmSubscriptionInput.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfirmSubscriptionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ConfirmSubscriptionInput, ::rusoto_core::BuildError> {
+        Ok(ConfirmSubscriptionInput {
+            authenticate_on_unsubscribe: self.authenticate_on_unsubscribe,
+            token: self
+                .token
+                .ok_or(::rusoto_core::BuildError::missing("token"))?,
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 /// Serialize `ConfirmSubscriptionInput` contents to a `SignedRequest`.
 struct ConfirmSubscriptionInputSerializer;
 impl ConfirmSubscriptionInputSerializer {
@@ -213,10 +368,10 @@ pub struct ConfirmSubscriptionResponse {
     pub subscription_arn: Option<String>,
 }
 
-struct ConfirmSubscriptionResponseDeserializer;
+pub(crate) struct ConfirmSubscriptionResponseDeserializer;
 impl ConfirmSubscriptionResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ConfirmSubscriptionResponse, XmlParseError> {
@@ -245,10 +400,10 @@ pub struct CreateEndpointResponse {
     pub endpoint_arn: Option<String>,
 }
 
-struct CreateEndpointResponseDeserializer;
+pub(crate) struct CreateEndpointResponseDeserializer;
 impl CreateEndpointResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateEndpointResponse, XmlParseError> {
@@ -274,6 +429,61 @@ pub struct CreatePlatformApplicationInput {
     pub platform: String,
 }
 
+impl CreatePlatformApplicationInput {
+    /// Returns a builder for `CreatePlatformApplicationInput`, checking its required members when built.
+    pub fn builder() -> CreatePlatformApplicationInputBuilder {
+        CreatePlatformApplicationInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePlatformApplicationInput`](struct.CreatePlatformApplicationInput.html).
+#[derive(Default, Debug)]
+pub struct CreatePlatformApplicationInputBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+    name: Option<String>,
+    platform: Option<String>,
+}
+
+impl CreatePlatformApplicationInputBuilder {
+    /// Sets the [`attributes`](struct.CreatePlatformApplicationInput.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreatePlatformApplicationInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform`](struct.CreatePlatformApplicationInput.html#structfield.platform) member.
+    pub fn platform<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePlatformApplicationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePlatformApplicationInput, ::rusoto_core::BuildError> {
+        Ok(CreatePlatformApplicationInput {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            platform: self
+                .platform
+                .ok_or(::rusoto_core::BuildError::missing("platform"))?,
+        })
+    }
+}
+
 /// Serialize `CreatePlatformApplicationInput` contents to a `SignedRequest`.
 struct CreatePlatformApplicationInputSerializer;
 impl CreatePlatformApplicationInputSerializer {
@@ -300,10 +510,10 @@ pub struct CreatePlatformApplicationResponse {
     pub platform_application_arn: Option<String>,
 }
 
-struct CreatePlatformApplicationResponseDeserializer;
+pub(crate) struct CreatePlatformApplicationResponseDeserializer;
 impl CreatePlatformApplicationResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreatePlatformApplicationResponse, XmlParseError> {
@@ -338,6 +548,67 @@ pub struct CreatePlatformEndpointInput {
     pub token: String,
 }
 
+impl CreatePlatformEndpointInput {
+    /// Returns a builder for `CreatePlatformEndpointInput`, checking its required members when built.
+    pub fn builder() -> CreatePlatformEndpointInputBuilder {
+        CreatePlatformEndpointInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePlatformEndpointInput`](struct.CreatePlatformEndpointInput.html).
+#[derive(Default, Debug)]
+pub struct CreatePlatformEndpointInputBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+    custom_user_data: Option<String>,
+    platform_application_arn: Option<String>,
+    token: Option<String>,
+}
+
+impl CreatePlatformEndpointInputBuilder {
+    /// Sets the [`attributes`](struct.CreatePlatformEndpointInput.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_user_data`](struct.CreatePlatformEndpointInput.html#structfield.custom_user_data) member.
+    pub fn custom_user_data<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_user_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_application_arn`](struct.CreatePlatformEndpointInput.html#structfield.platform_application_arn) member.
+    pub fn platform_application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token`](struct.CreatePlatformEndpointInput.html#structfield.token) member.
+    pub fn token<T: Into<String>>(mut self, value: T) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePlatformEndpointInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePlatformEndpointInput, ::rusoto_core::BuildError> {
+        Ok(CreatePlatformEndpointInput {
+            attributes: self.attributes,
+            custom_user_data: self.custom_user_data,
+            platform_application_arn: self.platform_application_arn.ok_or(
+                ::rusoto_core::BuildError::missing("platform_application_arn"),
+            )?,
+            token: self
+                .token
+                .ok_or(::rusoto_core::BuildError::missing("token"))?,
+        })
+    }
+}
+
 /// Serialize `CreatePlatformEndpointInput` contents to a `SignedRequest`.
 struct CreatePlatformEndpointInputSerializer;
 impl CreatePlatformEndpointInputSerializer {
@@ -376,6 +647,55 @@ pub struct CreateTopicInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateTopicInput {
+    /// Returns a builder for `CreateTopicInput`, checking its required members when built.
+    pub fn builder() -> CreateTopicInputBuilder {
+        CreateTopicInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTopicInput`](struct.CreateTopicInput.html).
+#[derive(Default, Debug)]
+pub struct CreateTopicInputBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+    name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateTopicInputBuilder {
+    /// Sets the [`attributes`](struct.CreateTopicInput.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateTopicInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTopicInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTopicInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTopicInput, ::rusoto_core::BuildError> {
+        Ok(CreateTopicInput {
+            attributes: self.attributes,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateTopicInput` contents to a `SignedRequest`.
 struct CreateTopicInputSerializer;
 impl CreateTopicInputSerializer {
@@ -406,10 +726,10 @@ pub struct CreateTopicResponse {
     pub topic_arn: Option<String>,
 }
 
-struct CreateTopicResponseDeserializer;
+pub(crate) struct CreateTopicResponseDeserializer;
 impl CreateTopicResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateTopicResponse, XmlParseError> {
@@ -443,6 +763,36 @@ pub struct DeleteEndpointInput {
     pub endpoint_arn: String,
 }
 
+impl DeleteEndpointInput {
+    /// Returns a builder for `DeleteEndpointInput`, checking its required members when built.
+    pub fn builder() -> DeleteEndpointInputBuilder {
+        DeleteEndpointInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEndpointInput`](struct.DeleteEndpointInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteEndpointInputBuilder {
+    endpoint_arn: Option<String>,
+}
+
+impl DeleteEndpointInputBuilder {
+    /// Sets the [`endpoint_arn`](struct.DeleteEndpointInput.html#structfield.endpoint_arn) member.
+    pub fn endpoint_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEndpointInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteEndpointInput, ::rusoto_core::BuildError> {
+        Ok(DeleteEndpointInput {
+            endpoint_arn: self
+                .endpoint_arn
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_arn"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteEndpointInput` contents to a `SignedRequest`.
 struct DeleteEndpointInputSerializer;
 impl DeleteEndpointInputSerializer {
@@ -463,6 +813,38 @@ pub struct DeletePlatformApplicationInput {
     pub platform_application_arn: String,
 }
 
+impl DeletePlatformApplicationInput {
+    /// Returns a builder for `DeletePlatformApplicationInput`, checking its required members when built.
+    pub fn builder() -> DeletePlatformApplicationInputBuilder {
+        DeletePlatformApplicationInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePlatformApplicationInput`](struct.DeletePlatformApplicationInput.html).
+#[derive(Default, Debug)]
+pub struct DeletePlatformApplicationInputBuilder {
+    platform_application_arn: Option<String>,
+}
+
+impl DeletePlatformApplicationInputBuilder {
+    /// Sets the [`platform_application_arn`](struct.DeletePlatformApplicationInput.html#structfield.platform_application_arn) member.
+    pub fn platform_application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_application_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePlatformApplicationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePlatformApplicationInput, ::rusoto_core::BuildError> {
+        Ok(DeletePlatformApplicationInput {
+            platform_application_arn: self.platform_application_arn.ok_or(
+                ::rusoto_core::BuildError::missing("platform_application_arn"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeletePlatformApplicationInput` contents to a `SignedRequest`.
 struct DeletePlatformApplicationInputSerializer;
 impl DeletePlatformApplicationInputSerializer {
@@ -485,6 +867,36 @@ pub struct DeleteTopicInput {
     pub topic_arn: String,
 }
 
+impl DeleteTopicInput {
+    /// Returns a builder for `DeleteTopicInput`, checking its required members when built.
+    pub fn builder() -> DeleteTopicInputBuilder {
+        DeleteTopicInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTopicInput`](struct.DeleteTopicInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteTopicInputBuilder {
+    topic_arn: Option<String>,
+}
+
+impl DeleteTopicInputBuilder {
+    /// Sets the [`topic_arn`](struct.DeleteTopicInput.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTopicInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTopicInput, ::rusoto_core::BuildError> {
+        Ok(DeleteTopicInput {
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteTopicInput` contents to a `SignedRequest`.
 struct DeleteTopicInputSerializer;
 impl DeleteTopicInputSerializer {
@@ -498,10 +910,13 @@ impl DeleteTopicInputSerializer {
     }
 }
 
-struct EndpointDeserializer;
+pub(crate) struct EndpointDeserializer;
 impl EndpointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -516,6 +931,38 @@ pub struct GetEndpointAttributesInput {
     pub endpoint_arn: String,
 }
 
+impl GetEndpointAttributesInput {
+    /// Returns a builder for `GetEndpointAttributesInput`, checking its required members when built.
+    pub fn builder() -> GetEndpointAttributesInputBuilder {
+        GetEndpointAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetEndpointAttributesInput`](struct.GetEndpointAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct GetEndpointAttributesInputBuilder {
+    endpoint_arn: Option<String>,
+}
+
+impl GetEndpointAttributesInputBuilder {
+    /// Sets the [`endpoint_arn`](struct.GetEndpointAttributesInput.html#structfield.endpoint_arn) member.
+    pub fn endpoint_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetEndpointAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetEndpointAttributesInput, ::rusoto_core::BuildError> {
+        Ok(GetEndpointAttributesInput {
+            endpoint_arn: self
+                .endpoint_arn
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_arn"))?,
+        })
+    }
+}
+
 /// Serialize `GetEndpointAttributesInput` contents to a `SignedRequest`.
 struct GetEndpointAttributesInputSerializer;
 impl GetEndpointAttributesInputSerializer {
@@ -536,10 +983,10 @@ pub struct GetEndpointAttributesResponse {
     pub attributes: Option<::std::collections::HashMap<String, String>>,
 }
 
-struct GetEndpointAttributesResponseDeserializer;
+pub(crate) struct GetEndpointAttributesResponseDeserializer;
 impl GetEndpointAttributesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetEndpointAttributesResponse, XmlParseError> {
@@ -568,6 +1015,39 @@ pub struct GetPlatformApplicationAttributesInput {
     pub platform_application_arn: String,
 }
 
+impl GetPlatformApplicationAttributesInput {
+    /// Returns a builder for `GetPlatformApplicationAttributesInput`, checking its required members when built.
+    pub fn builder() -> GetPlatformApplicationAttributesInputBuilder {
+        GetPlatformApplicationAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetPlatformApplicationAttributesInput`](struct.GetPlatformApplicationAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct GetPlatformApplicationAttributesInputBuilder {
+    platform_application_arn: Option<String>,
+}
+
+impl GetPlatformApplicationAttributesInputBuilder {
+    /// Sets the [`platform_application_arn`](struct.GetPlatformApplicationAttributesInput.html#structfield.platform_application_arn) member.
+    pub fn platform_application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_application_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPlatformApplicationAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetPlatformApplicationAttributesInput, ::rusoto_core::BuildError>
+    {
+        Ok(GetPlatformApplicationAttributesInput {
+            platform_application_arn: self.platform_application_arn.ok_or(
+                ::rusoto_core::BuildError::missing("platform_application_arn"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `GetPlatformApplicationAttributesInput` contents to a `SignedRequest`.
 struct GetPlatformApplicationAttributesInputSerializer;
 impl GetPlatformApplicationAttributesInputSerializer {
@@ -591,10 +1071,10 @@ pub struct GetPlatformApplicationAttributesResponse {
     pub attributes: Option<::std::collections::HashMap<String, String>>,
 }
 
-struct GetPlatformApplicationAttributesResponseDeserializer;
+pub(crate) struct GetPlatformApplicationAttributesResponseDeserializer;
 impl GetPlatformApplicationAttributesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetPlatformApplicationAttributesResponse, XmlParseError> {
@@ -623,6 +1103,34 @@ pub struct GetSMSAttributesInput {
     pub attributes: Option<Vec<String>>,
 }
 
+impl GetSMSAttributesInput {
+    /// Returns a builder for `GetSMSAttributesInput`, checking its required members when built.
+    pub fn builder() -> GetSMSAttributesInputBuilder {
+        GetSMSAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetSMSAttributesInput`](struct.GetSMSAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct GetSMSAttributesInputBuilder {
+    attributes: Option<Vec<String>>,
+}
+
+impl GetSMSAttributesInputBuilder {
+    /// Sets the [`attributes`](struct.GetSMSAttributesInput.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSMSAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSMSAttributesInput, ::rusoto_core::BuildError> {
+        Ok(GetSMSAttributesInput {
+            attributes: self.attributes,
+        })
+    }
+}
+
 /// Serialize `GetSMSAttributesInput` contents to a `SignedRequest`.
 struct GetSMSAttributesInputSerializer;
 impl GetSMSAttributesInputSerializer {
@@ -649,10 +1157,10 @@ pub struct GetSMSAttributesResponse {
     pub attributes: Option<::std::collections::HashMap<String, String>>,
 }
 
-struct GetSMSAttributesResponseDeserializer;
+pub(crate) struct GetSMSAttributesResponseDeserializer;
 impl GetSMSAttributesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetSMSAttributesResponse, XmlParseError> {
@@ -681,6 +1189,38 @@ pub struct GetSubscriptionAttributesInput {
     pub subscription_arn: String,
 }
 
+impl GetSubscriptionAttributesInput {
+    /// Returns a builder for `GetSubscriptionAttributesInput`, checking its required members when built.
+    pub fn builder() -> GetSubscriptionAttributesInputBuilder {
+        GetSubscriptionAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetSubscriptionAttributesInput`](struct.GetSubscriptionAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct GetSubscriptionAttributesInputBuilder {
+    subscription_arn: Option<String>,
+}
+
+impl GetSubscriptionAttributesInputBuilder {
+    /// Sets the [`subscription_arn`](struct.GetSubscriptionAttributesInput.html#structfield.subscription_arn) member.
+    pub fn subscription_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSubscriptionAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSubscriptionAttributesInput, ::rusoto_core::BuildError> {
+        Ok(GetSubscriptionAttributesInput {
+            subscription_arn: self
+                .subscription_arn
+                .ok_or(::rusoto_core::BuildError::missing("subscription_arn"))?,
+        })
+    }
+}
+
 /// Serialize `GetSubscriptionAttributesInput` contents to a `SignedRequest`.
 struct GetSubscriptionAttributesInputSerializer;
 impl GetSubscriptionAttributesInputSerializer {
@@ -704,10 +1244,10 @@ pub struct GetSubscriptionAttributesResponse {
     pub attributes: Option<::std::collections::HashMap<String, String>>,
 }
 
-struct GetSubscriptionAttributesResponseDeserializer;
+pub(crate) struct GetSubscriptionAttributesResponseDeserializer;
 impl GetSubscriptionAttributesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetSubscriptionAttributesResponse, XmlParseError> {
@@ -736,6 +1276,38 @@ pub struct GetTopicAttributesInput {
     pub topic_arn: String,
 }
 
+impl GetTopicAttributesInput {
+    /// Returns a builder for `GetTopicAttributesInput`, checking its required members when built.
+    pub fn builder() -> GetTopicAttributesInputBuilder {
+        GetTopicAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetTopicAttributesInput`](struct.GetTopicAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct GetTopicAttributesInputBuilder {
+    topic_arn: Option<String>,
+}
+
+impl GetTopicAttributesInputBuilder {
+    /// Sets the [`topic_arn`](struct.GetTopicAttributesInput.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTopicAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetTopicAttributesInput, ::rusoto_core::BuildError> {
+        Ok(GetTopicAttributesInput {
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 /// Serialize `GetTopicAttributesInput` contents to a `SignedRequest`.
 struct GetTopicAttributesInputSerializer;
 impl GetTopicAttributesInputSerializer {
@@ -756,10 +1328,10 @@ pub struct GetTopicAttributesResponse {
     pub attributes: Option<::std::collections::HashMap<String, String>>,
 }
 
-struct GetTopicAttributesResponseDeserializer;
+pub(crate) struct GetTopicAttributesResponseDeserializer;
 impl GetTopicAttributesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetTopicAttributesResponse, XmlParseError> {
@@ -790,6 +1362,47 @@ pub struct ListEndpointsByPlatformApplicationInput {
     pub platform_application_arn: String,
 }
 
+impl ListEndpointsByPlatformApplicationInput {
+    /// Returns a builder for `ListEndpointsByPlatformApplicationInput`, checking its required members when built.
+    pub fn builder() -> ListEndpointsByPlatformApplicationInputBuilder {
+        ListEndpointsByPlatformApplicationInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListEndpointsByPlatformApplicationInput`](struct.ListEndpointsByPlatformApplicationInput.html).
+#[derive(Default, Debug)]
+pub struct ListEndpointsByPlatformApplicationInputBuilder {
+    next_token: Option<String>,
+    platform_application_arn: Option<String>,
+}
+
+impl ListEndpointsByPlatformApplicationInputBuilder {
+    /// Sets the [`next_token`](struct.ListEndpointsByPlatformApplicationInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_application_arn`](struct.ListEndpointsByPlatformApplicationInput.html#structfield.platform_application_arn) member.
+    pub fn platform_application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_application_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListEndpointsByPlatformApplicationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListEndpointsByPlatformApplicationInput, ::rusoto_core::BuildError>
+    {
+        Ok(ListEndpointsByPlatformApplicationInput {
+            next_token: self.next_token,
+            platform_application_arn: self.platform_application_arn.ok_or(
+                ::rusoto_core::BuildError::missing("platform_application_arn"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `ListEndpointsByPlatformApplicationInput` contents to a `SignedRequest`.
 struct ListEndpointsByPlatformApplicationInputSerializer;
 impl ListEndpointsByPlatformApplicationInputSerializer {
@@ -818,10 +1431,10 @@ pub struct ListEndpointsByPlatformApplicationResponse {
     pub next_token: Option<String>,
 }
 
-struct ListEndpointsByPlatformApplicationResponseDeserializer;
+pub(crate) struct ListEndpointsByPlatformApplicationResponseDeserializer;
 impl ListEndpointsByPlatformApplicationResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListEndpointsByPlatformApplicationResponse, XmlParseError> {
@@ -845,10 +1458,10 @@ impl ListEndpointsByPlatformApplicationResponseDeserializer {
         )
     }
 }
-struct ListOfEndpointsDeserializer;
+pub(crate) struct ListOfEndpointsDeserializer;
 impl ListOfEndpointsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -862,10 +1475,10 @@ impl ListOfEndpointsDeserializer {
         })
     }
 }
-struct ListOfPlatformApplicationsDeserializer;
+pub(crate) struct ListOfPlatformApplicationsDeserializer;
 impl ListOfPlatformApplicationsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PlatformApplication>, XmlParseError> {
@@ -888,6 +1501,36 @@ pub struct ListPhoneNumbersOptedOutInput {
     pub next_token: Option<String>,
 }
 
+impl ListPhoneNumbersOptedOutInput {
+    /// Returns a builder for `ListPhoneNumbersOptedOutInput`, checking its required members when built.
+    pub fn builder() -> ListPhoneNumbersOptedOutInputBuilder {
+        ListPhoneNumbersOptedOutInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListPhoneNumbersOptedOutInput`](struct.ListPhoneNumbersOptedOutInput.html).
+#[derive(Default, Debug)]
+pub struct ListPhoneNumbersOptedOutInputBuilder {
+    next_token: Option<String>,
+}
+
+impl ListPhoneNumbersOptedOutInputBuilder {
+    /// Sets the [`next_token`](struct.ListPhoneNumbersOptedOutInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPhoneNumbersOptedOutInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPhoneNumbersOptedOutInput, ::rusoto_core::BuildError> {
+        Ok(ListPhoneNumbersOptedOutInput {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListPhoneNumbersOptedOutInput` contents to a `SignedRequest`.
 struct ListPhoneNumbersOptedOutInputSerializer;
 impl ListPhoneNumbersOptedOutInputSerializer {
@@ -912,10 +1555,10 @@ pub struct ListPhoneNumbersOptedOutResponse {
     pub phone_numbers: Option<Vec<String>>,
 }
 
-struct ListPhoneNumbersOptedOutResponseDeserializer;
+pub(crate) struct ListPhoneNumbersOptedOutResponseDeserializer;
 impl ListPhoneNumbersOptedOutResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListPhoneNumbersOptedOutResponse, XmlParseError> {
@@ -946,6 +1589,36 @@ pub struct ListPlatformApplicationsInput {
     pub next_token: Option<String>,
 }
 
+impl ListPlatformApplicationsInput {
+    /// Returns a builder for `ListPlatformApplicationsInput`, checking its required members when built.
+    pub fn builder() -> ListPlatformApplicationsInputBuilder {
+        ListPlatformApplicationsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListPlatformApplicationsInput`](struct.ListPlatformApplicationsInput.html).
+#[derive(Default, Debug)]
+pub struct ListPlatformApplicationsInputBuilder {
+    next_token: Option<String>,
+}
+
+impl ListPlatformApplicationsInputBuilder {
+    /// Sets the [`next_token`](struct.ListPlatformApplicationsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPlatformApplicationsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPlatformApplicationsInput, ::rusoto_core::BuildError> {
+        Ok(ListPlatformApplicationsInput {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListPlatformApplicationsInput` contents to a `SignedRequest`.
 struct ListPlatformApplicationsInputSerializer;
 impl ListPlatformApplicationsInputSerializer {
@@ -970,10 +1643,10 @@ pub struct ListPlatformApplicationsResponse {
     pub platform_applications: Option<Vec<PlatformApplication>>,
 }
 
-struct ListPlatformApplicationsResponseDeserializer;
+pub(crate) struct ListPlatformApplicationsResponseDeserializer;
 impl ListPlatformApplicationsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListPlatformApplicationsResponse, XmlParseError> {
@@ -1021,6 +1694,46 @@ pub struct ListSubscriptionsByTopicInput {
     pub topic_arn: String,
 }
 
+impl ListSubscriptionsByTopicInput {
+    /// Returns a builder for `ListSubscriptionsByTopicInput`, checking its required members when built.
+    pub fn builder() -> ListSubscriptionsByTopicInputBuilder {
+        ListSubscriptionsByTopicInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListSubscriptionsByTopicInput`](struct.ListSubscriptionsByTopicInput.html).
+#[derive(Default, Debug)]
+pub struct ListSubscriptionsByTopicInputBuilder {
+    next_token: Option<String>,
+    topic_arn: Option<String>,
+}
+
+impl ListSubscriptionsByTopicInputBuilder {
+    /// Sets the [`next_token`](struct.ListSubscriptionsByTopicInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_arn`](struct.ListSubscriptionsByTopicInput.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSubscriptionsByTopicInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSubscriptionsByTopicInput, ::rusoto_core::BuildError> {
+        Ok(ListSubscriptionsByTopicInput {
+            next_token: self.next_token,
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 /// Serialize `ListSubscriptionsByTopicInput` contents to a `SignedRequest`.
 struct ListSubscriptionsByTopicInputSerializer;
 impl ListSubscriptionsByTopicInputSerializer {
@@ -1046,10 +1759,10 @@ pub struct ListSubscriptionsByTopicResponse {
     pub subscriptions: Option<Vec<Subscription>>,
 }
 
-struct ListSubscriptionsByTopicResponseDeserializer;
+pub(crate) struct ListSubscriptionsByTopicResponseDeserializer;
 impl ListSubscriptionsByTopicResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListSubscriptionsByTopicResponse, XmlParseError> {
@@ -1081,6 +1794,34 @@ pub struct ListSubscriptionsInput {
     pub next_token: Option<String>,
 }
 
+impl ListSubscriptionsInput {
+    /// Returns a builder for `ListSubscriptionsInput`, checking its required members when built.
+    pub fn builder() -> ListSubscriptionsInputBuilder {
+        ListSubscriptionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListSubscriptionsInput`](struct.ListSubscriptionsInput.html).
+#[derive(Default, Debug)]
+pub struct ListSubscriptionsInputBuilder {
+    next_token: Option<String>,
+}
+
+impl ListSubscriptionsInputBuilder {
+    /// Sets the [`next_token`](struct.ListSubscriptionsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSubscriptionsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListSubscriptionsInput, ::rusoto_core::BuildError> {
+        Ok(ListSubscriptionsInput {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListSubscriptionsInput` contents to a `SignedRequest`.
 struct ListSubscriptionsInputSerializer;
 impl ListSubscriptionsInputSerializer {
@@ -1105,10 +1846,10 @@ pub struct ListSubscriptionsResponse {
     pub subscriptions: Option<Vec<Subscription>>,
 }
 
-struct ListSubscriptionsResponseDeserializer;
+pub(crate) struct ListSubscriptionsResponseDeserializer;
 impl ListSubscriptionsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListSubscriptionsResponse, XmlParseError> {
@@ -1139,6 +1880,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 /// Serialize `ListTagsForResourceRequest` contents to a `SignedRequest`.
 struct ListTagsForResourceRequestSerializer;
 impl ListTagsForResourceRequestSerializer {
@@ -1158,10 +1931,10 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct ListTagsForResourceResponseDeserializer;
+pub(crate) struct ListTagsForResourceResponseDeserializer;
 impl ListTagsForResourceResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListTagsForResourceResponse, XmlParseError> {
@@ -1188,6 +1961,34 @@ pub struct ListTopicsInput {
     pub next_token: Option<String>,
 }
 
+impl ListTopicsInput {
+    /// Returns a builder for `ListTopicsInput`, checking its required members when built.
+    pub fn builder() -> ListTopicsInputBuilder {
+        ListTopicsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTopicsInput`](struct.ListTopicsInput.html).
+#[derive(Default, Debug)]
+pub struct ListTopicsInputBuilder {
+    next_token: Option<String>,
+}
+
+impl ListTopicsInputBuilder {
+    /// Sets the [`next_token`](struct.ListTopicsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTopicsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTopicsInput, ::rusoto_core::BuildError> {
+        Ok(ListTopicsInput {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListTopicsInput` contents to a `SignedRequest`.
 struct ListTopicsInputSerializer;
 impl ListTopicsInputSerializer {
@@ -1212,10 +2013,10 @@ pub struct ListTopicsResponse {
     pub topics: Option<Vec<Topic>>,
 }
 
-struct ListTopicsResponseDeserializer;
+pub(crate) struct ListTopicsResponseDeserializer;
 impl ListTopicsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListTopicsResponse, XmlParseError> {
@@ -1235,10 +2036,10 @@ impl ListTopicsResponseDeserializer {
         })
     }
 }
-struct MapStringToStringDeserializer;
+pub(crate) struct MapStringToStringDeserializer;
 impl MapStringToStringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
@@ -1328,10 +2129,13 @@ impl MessageAttributeValueSerializer {
     }
 }
 
-struct MessageIdDeserializer;
+pub(crate) struct MessageIdDeserializer;
 impl MessageIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1339,10 +2143,13 @@ impl MessageIdDeserializer {
         Ok(obj)
     }
 }
-struct NextTokenDeserializer;
+pub(crate) struct NextTokenDeserializer;
 impl NextTokenDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1357,6 +2164,36 @@ pub struct OptInPhoneNumberInput {
     pub phone_number: String,
 }
 
+impl OptInPhoneNumberInput {
+    /// Returns a builder for `OptInPhoneNumberInput`, checking its required members when built.
+    pub fn builder() -> OptInPhoneNumberInputBuilder {
+        OptInPhoneNumberInputBuilder::default()
+    }
+}
+
+/// Builds a [`OptInPhoneNumberInput`](struct.OptInPhoneNumberInput.html).
+#[derive(Default, Debug)]
+pub struct OptInPhoneNumberInputBuilder {
+    phone_number: Option<String>,
+}
+
+impl OptInPhoneNumberInputBuilder {
+    /// Sets the [`phone_number`](struct.OptInPhoneNumberInput.html#structfield.phone_number) member.
+    pub fn phone_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.phone_number = Some(value.into());
+        self
+    }
+
+    /// Builds the `OptInPhoneNumberInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<OptInPhoneNumberInput, ::rusoto_core::BuildError> {
+        Ok(OptInPhoneNumberInput {
+            phone_number: self
+                .phone_number
+                .ok_or(::rusoto_core::BuildError::missing("phone_number"))?,
+        })
+    }
+}
+
 /// Serialize `OptInPhoneNumberInput` contents to a `SignedRequest`.
 struct OptInPhoneNumberInputSerializer;
 impl OptInPhoneNumberInputSerializer {
@@ -1374,10 +2211,10 @@ impl OptInPhoneNumberInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct OptInPhoneNumberResponse {}
 
-struct OptInPhoneNumberResponseDeserializer;
+pub(crate) struct OptInPhoneNumberResponseDeserializer;
 impl OptInPhoneNumberResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptInPhoneNumberResponse, XmlParseError> {
@@ -1390,10 +2227,13 @@ impl OptInPhoneNumberResponseDeserializer {
         Ok(obj)
     }
 }
-struct PhoneNumberDeserializer;
+pub(crate) struct PhoneNumberDeserializer;
 impl PhoneNumberDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1401,10 +2241,10 @@ impl PhoneNumberDeserializer {
         Ok(obj)
     }
 }
-struct PhoneNumberListDeserializer;
+pub(crate) struct PhoneNumberListDeserializer;
 impl PhoneNumberListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -1427,10 +2267,10 @@ pub struct PlatformApplication {
     pub platform_application_arn: Option<String>,
 }
 
-struct PlatformApplicationDeserializer;
+pub(crate) struct PlatformApplicationDeserializer;
 impl PlatformApplicationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PlatformApplication, XmlParseError> {
@@ -1454,10 +2294,13 @@ impl PlatformApplicationDeserializer {
         })
     }
 }
-struct ProtocolDeserializer;
+pub(crate) struct ProtocolDeserializer;
 impl ProtocolDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1484,6 +2327,89 @@ pub struct PublishInput {
     pub topic_arn: Option<String>,
 }
 
+impl PublishInput {
+    /// Returns a builder for `PublishInput`, checking its required members when built.
+    pub fn builder() -> PublishInputBuilder {
+        PublishInputBuilder::default()
+    }
+}
+
+/// Builds a [`PublishInput`](struct.PublishInput.html).
+#[derive(Default, Debug)]
+pub struct PublishInputBuilder {
+    message: Option<String>,
+    message_attributes: Option<::std::collections::HashMap<String, MessageAttributeValue>>,
+    message_structure: Option<String>,
+    phone_number: Option<String>,
+    subject: Option<String>,
+    target_arn: Option<String>,
+    topic_arn: Option<String>,
+}
+
+impl PublishInputBuilder {
+    /// Sets the [`message`](struct.PublishInput.html#structfield.message) member.
+    pub fn message<T: Into<String>>(mut self, value: T) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message_attributes`](struct.PublishInput.html#structfield.message_attributes) member.
+    pub fn message_attributes<
+        T: Into<::std::collections::HashMap<String, MessageAttributeValue>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.message_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message_structure`](struct.PublishInput.html#structfield.message_structure) member.
+    pub fn message_structure<T: Into<String>>(mut self, value: T) -> Self {
+        self.message_structure = Some(value.into());
+        self
+    }
+
+    /// Sets the [`phone_number`](struct.PublishInput.html#structfield.phone_number) member.
+    pub fn phone_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.phone_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subject`](struct.PublishInput.html#structfield.subject) member.
+    pub fn subject<T: Into<String>>(mut self, value: T) -> Self {
+        self.subject = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_arn`](struct.PublishInput.html#structfield.target_arn) member.
+    pub fn target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_arn`](struct.PublishInput.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `PublishInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PublishInput, ::rusoto_core::BuildError> {
+        Ok(PublishInput {
+            message: self
+                .message
+                .ok_or(::rusoto_core::BuildError::missing("message"))?,
+            message_attributes: self.message_attributes,
+            message_structure: self.message_structure,
+            phone_number: self.phone_number,
+            subject: self.subject,
+            target_arn: self.target_arn,
+            topic_arn: self.topic_arn,
+        })
+    }
+}
+
 /// Serialize `PublishInput` contents to a `SignedRequest`.
 struct PublishInputSerializer;
 impl PublishInputSerializer {
@@ -1526,10 +2452,10 @@ pub struct PublishResponse {
     pub message_id: Option<String>,
 }
 
-struct PublishResponseDeserializer;
+pub(crate) struct PublishResponseDeserializer;
 impl PublishResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PublishResponse, XmlParseError> {
@@ -1553,6 +2479,46 @@ pub struct RemovePermissionInput {
     pub topic_arn: String,
 }
 
+impl RemovePermissionInput {
+    /// Returns a builder for `RemovePermissionInput`, checking its required members when built.
+    pub fn builder() -> RemovePermissionInputBuilder {
+        RemovePermissionInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemovePermissionInput`](struct.RemovePermissionInput.html).
+#[derive(Default, Debug)]
+pub struct RemovePermissionInputBuilder {
+    label: Option<String>,
+    topic_arn: Option<String>,
+}
+
+impl RemovePermissionInputBuilder {
+    /// Sets the [`label`](struct.RemovePermissionInput.html#structfield.label) member.
+    pub fn label<T: Into<String>>(mut self, value: T) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_arn`](struct.RemovePermissionInput.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemovePermissionInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RemovePermissionInput, ::rusoto_core::BuildError> {
+        Ok(RemovePermissionInput {
+            label: self
+                .label
+                .ok_or(::rusoto_core::BuildError::missing("label"))?,
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 /// Serialize `RemovePermissionInput` contents to a `SignedRequest`.
 struct RemovePermissionInputSerializer;
 impl RemovePermissionInputSerializer {
@@ -1576,6 +2542,51 @@ pub struct SetEndpointAttributesInput {
     pub endpoint_arn: String,
 }
 
+impl SetEndpointAttributesInput {
+    /// Returns a builder for `SetEndpointAttributesInput`, checking its required members when built.
+    pub fn builder() -> SetEndpointAttributesInputBuilder {
+        SetEndpointAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetEndpointAttributesInput`](struct.SetEndpointAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct SetEndpointAttributesInputBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+    endpoint_arn: Option<String>,
+}
+
+impl SetEndpointAttributesInputBuilder {
+    /// Sets the [`attributes`](struct.SetEndpointAttributesInput.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_arn`](struct.SetEndpointAttributesInput.html#structfield.endpoint_arn) member.
+    pub fn endpoint_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetEndpointAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetEndpointAttributesInput, ::rusoto_core::BuildError> {
+        Ok(SetEndpointAttributesInput {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            endpoint_arn: self
+                .endpoint_arn
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_arn"))?,
+        })
+    }
+}
+
 /// Serialize `SetEndpointAttributesInput` contents to a `SignedRequest`.
 struct SetEndpointAttributesInputSerializer;
 impl SetEndpointAttributesInputSerializer {
@@ -1603,6 +2614,52 @@ pub struct SetPlatformApplicationAttributesInput {
     pub platform_application_arn: String,
 }
 
+impl SetPlatformApplicationAttributesInput {
+    /// Returns a builder for `SetPlatformApplicationAttributesInput`, checking its required members when built.
+    pub fn builder() -> SetPlatformApplicationAttributesInputBuilder {
+        SetPlatformApplicationAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetPlatformApplicationAttributesInput`](struct.SetPlatformApplicationAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct SetPlatformApplicationAttributesInputBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+    platform_application_arn: Option<String>,
+}
+
+impl SetPlatformApplicationAttributesInputBuilder {
+    /// Sets the [`attributes`](struct.SetPlatformApplicationAttributesInput.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_application_arn`](struct.SetPlatformApplicationAttributesInput.html#structfield.platform_application_arn) member.
+    pub fn platform_application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_application_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetPlatformApplicationAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetPlatformApplicationAttributesInput, ::rusoto_core::BuildError>
+    {
+        Ok(SetPlatformApplicationAttributesInput {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            platform_application_arn: self.platform_application_arn.ok_or(
+                ::rusoto_core::BuildError::missing("platform_application_arn"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `SetPlatformApplicationAttributesInput` contents to a `SignedRequest`.
 struct SetPlatformApplicationAttributesInputSerializer;
 impl SetPlatformApplicationAttributesInputSerializer {
@@ -1631,6 +2688,39 @@ pub struct SetSMSAttributesInput {
     pub attributes: ::std::collections::HashMap<String, String>,
 }
 
+impl SetSMSAttributesInput {
+    /// Returns a builder for `SetSMSAttributesInput`, checking its required members when built.
+    pub fn builder() -> SetSMSAttributesInputBuilder {
+        SetSMSAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetSMSAttributesInput`](struct.SetSMSAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct SetSMSAttributesInputBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl SetSMSAttributesInputBuilder {
+    /// Sets the [`attributes`](struct.SetSMSAttributesInput.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetSMSAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetSMSAttributesInput, ::rusoto_core::BuildError> {
+        Ok(SetSMSAttributesInput {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+        })
+    }
+}
+
 /// Serialize `SetSMSAttributesInput` contents to a `SignedRequest`.
 struct SetSMSAttributesInputSerializer;
 impl SetSMSAttributesInputSerializer {
@@ -1652,10 +2742,10 @@ impl SetSMSAttributesInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetSMSAttributesResponse {}
 
-struct SetSMSAttributesResponseDeserializer;
+pub(crate) struct SetSMSAttributesResponseDeserializer;
 impl SetSMSAttributesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetSMSAttributesResponse, XmlParseError> {
@@ -1679,6 +2769,56 @@ pub struct SetSubscriptionAttributesInput {
     pub subscription_arn: String,
 }
 
+impl SetSubscriptionAttributesInput {
+    /// Returns a builder for `SetSubscriptionAttributesInput`, checking its required members when built.
+    pub fn builder() -> SetSubscriptionAttributesInputBuilder {
+        SetSubscriptionAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetSubscriptionAttributesInput`](struct.SetSubscriptionAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct SetSubscriptionAttributesInputBuilder {
+    attribute_name: Option<String>,
+    attribute_value: Option<String>,
+    subscription_arn: Option<String>,
+}
+
+impl SetSubscriptionAttributesInputBuilder {
+    /// Sets the [`attribute_name`](struct.SetSubscriptionAttributesInput.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attribute_value`](struct.SetSubscriptionAttributesInput.html#structfield.attribute_value) member.
+    pub fn attribute_value<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_arn`](struct.SetSubscriptionAttributesInput.html#structfield.subscription_arn) member.
+    pub fn subscription_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetSubscriptionAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetSubscriptionAttributesInput, ::rusoto_core::BuildError> {
+        Ok(SetSubscriptionAttributesInput {
+            attribute_name: self
+                .attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("attribute_name"))?,
+            attribute_value: self.attribute_value,
+            subscription_arn: self
+                .subscription_arn
+                .ok_or(::rusoto_core::BuildError::missing("subscription_arn"))?,
+        })
+    }
+}
+
 /// Serialize `SetSubscriptionAttributesInput` contents to a `SignedRequest`.
 struct SetSubscriptionAttributesInputSerializer;
 impl SetSubscriptionAttributesInputSerializer {
@@ -1713,6 +2853,56 @@ pub struct SetTopicAttributesInput {
     pub topic_arn: String,
 }
 
+impl SetTopicAttributesInput {
+    /// Returns a builder for `SetTopicAttributesInput`, checking its required members when built.
+    pub fn builder() -> SetTopicAttributesInputBuilder {
+        SetTopicAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetTopicAttributesInput`](struct.SetTopicAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct SetTopicAttributesInputBuilder {
+    attribute_name: Option<String>,
+    attribute_value: Option<String>,
+    topic_arn: Option<String>,
+}
+
+impl SetTopicAttributesInputBuilder {
+    /// Sets the [`attribute_name`](struct.SetTopicAttributesInput.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attribute_value`](struct.SetTopicAttributesInput.html#structfield.attribute_value) member.
+    pub fn attribute_value<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_arn`](struct.SetTopicAttributesInput.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetTopicAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetTopicAttributesInput, ::rusoto_core::BuildError> {
+        Ok(SetTopicAttributesInput {
+            attribute_name: self
+                .attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("attribute_name"))?,
+            attribute_value: self.attribute_value,
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 /// Serialize `SetTopicAttributesInput` contents to a `SignedRequest`.
 struct SetTopicAttributesInputSerializer;
 impl SetTopicAttributesInputSerializer {
@@ -1733,10 +2923,13 @@ impl SetTopicAttributesInputSerializer {
     }
 }
 
-struct StringDeserializer;
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1759,6 +2952,73 @@ pub struct SubscribeInput {
     pub topic_arn: String,
 }
 
+impl SubscribeInput {
+    /// Returns a builder for `SubscribeInput`, checking its required members when built.
+    pub fn builder() -> SubscribeInputBuilder {
+        SubscribeInputBuilder::default()
+    }
+}
+
+/// Builds a [`SubscribeInput`](struct.SubscribeInput.html).
+#[derive(Default, Debug)]
+pub struct SubscribeInputBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+    endpoint: Option<String>,
+    protocol: Option<String>,
+    return_subscription_arn: Option<bool>,
+    topic_arn: Option<String>,
+}
+
+impl SubscribeInputBuilder {
+    /// Sets the [`attributes`](struct.SubscribeInput.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint`](struct.SubscribeInput.html#structfield.endpoint) member.
+    pub fn endpoint<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint = Some(value.into());
+        self
+    }
+
+    /// Sets the [`protocol`](struct.SubscribeInput.html#structfield.protocol) member.
+    pub fn protocol<T: Into<String>>(mut self, value: T) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_subscription_arn`](struct.SubscribeInput.html#structfield.return_subscription_arn) member.
+    pub fn return_subscription_arn<T: Into<bool>>(mut self, value: T) -> Self {
+        self.return_subscription_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_arn`](struct.SubscribeInput.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `SubscribeInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SubscribeInput, ::rusoto_core::BuildError> {
+        Ok(SubscribeInput {
+            attributes: self.attributes,
+            endpoint: self.endpoint,
+            protocol: self
+                .protocol
+                .ok_or(::rusoto_core::BuildError::missing("protocol"))?,
+            return_subscription_arn: self.return_subscription_arn,
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 /// Serialize `SubscribeInput` contents to a `SignedRequest`.
 struct SubscribeInputSerializer;
 impl SubscribeInputSerializer {
@@ -1796,10 +3056,10 @@ pub struct SubscribeResponse {
     pub subscription_arn: Option<String>,
 }
 
-struct SubscribeResponseDeserializer;
+pub(crate) struct SubscribeResponseDeserializer;
 impl SubscribeResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SubscribeResponse, XmlParseError> {
@@ -1832,10 +3092,10 @@ pub struct Subscription {
     pub topic_arn: Option<String>,
 }
 
-struct SubscriptionDeserializer;
+pub(crate) struct SubscriptionDeserializer;
 impl SubscriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Subscription, XmlParseError> {
@@ -1865,10 +3125,13 @@ impl SubscriptionDeserializer {
         })
     }
 }
-struct SubscriptionARNDeserializer;
+pub(crate) struct SubscriptionARNDeserializer;
 impl SubscriptionARNDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1876,10 +3139,10 @@ impl SubscriptionARNDeserializer {
         Ok(obj)
     }
 }
-struct SubscriptionAttributesMapDeserializer;
+pub(crate) struct SubscriptionAttributesMapDeserializer;
 impl SubscriptionAttributesMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
@@ -1916,10 +3179,10 @@ impl SubscriptionAttributesMapSerializer {
     }
 }
 
-struct SubscriptionsListDeserializer;
+pub(crate) struct SubscriptionsListDeserializer;
 impl SubscriptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Subscription>, XmlParseError> {
@@ -1942,10 +3205,13 @@ pub struct Tag {
     pub value: String,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -1975,10 +3241,13 @@ impl TagSerializer {
     }
 }
 
-struct TagKeyDeserializer;
+pub(crate) struct TagKeyDeserializer;
 impl TagKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1998,10 +3267,10 @@ impl TagKeyListSerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -2035,6 +3304,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `TagResourceRequest` contents to a `SignedRequest`.
 struct TagResourceRequestSerializer;
 impl TagResourceRequestSerializer {
@@ -2052,10 +3361,10 @@ impl TagResourceRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct TagResourceResponse {}
 
-struct TagResourceResponseDeserializer;
+pub(crate) struct TagResourceResponseDeserializer;
 impl TagResourceResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TagResourceResponse, XmlParseError> {
@@ -2068,10 +3377,13 @@ impl TagResourceResponseDeserializer {
         Ok(obj)
     }
 }
-struct TagValueDeserializer;
+pub(crate) struct TagValueDeserializer;
 impl TagValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2086,10 +3398,13 @@ pub struct Topic {
     pub topic_arn: Option<String>,
 }
 
-struct TopicDeserializer;
+pub(crate) struct TopicDeserializer;
 impl TopicDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Topic, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Topic, XmlParseError> {
         deserialize_elements::<_, Topic, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "TopicArn" => {
@@ -2101,10 +3416,13 @@ impl TopicDeserializer {
         })
     }
 }
-struct TopicARNDeserializer;
+pub(crate) struct TopicARNDeserializer;
 impl TopicARNDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2112,10 +3430,10 @@ impl TopicARNDeserializer {
         Ok(obj)
     }
 }
-struct TopicAttributesMapDeserializer;
+pub(crate) struct TopicAttributesMapDeserializer;
 impl TopicAttributesMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
@@ -2152,10 +3470,10 @@ impl TopicAttributesMapSerializer {
     }
 }
 
-struct TopicsListDeserializer;
+pub(crate) struct TopicsListDeserializer;
 impl TopicsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Topic>, XmlParseError> {
@@ -2176,6 +3494,36 @@ pub struct UnsubscribeInput {
     pub subscription_arn: String,
 }
 
+impl UnsubscribeInput {
+    /// Returns a builder for `UnsubscribeInput`, checking its required members when built.
+    pub fn builder() -> UnsubscribeInputBuilder {
+        UnsubscribeInputBuilder::default()
+    }
+}
+
+/// Builds a [`UnsubscribeInput`](struct.UnsubscribeInput.html).
+#[derive(Default, Debug)]
+pub struct UnsubscribeInputBuilder {
+    subscription_arn: Option<String>,
+}
+
+impl UnsubscribeInputBuilder {
+    /// Sets the [`subscription_arn`](struct.UnsubscribeInput.html#structfield.subscription_arn) member.
+    pub fn subscription_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UnsubscribeInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UnsubscribeInput, ::rusoto_core::BuildError> {
+        Ok(UnsubscribeInput {
+            subscription_arn: self
+                .subscription_arn
+                .ok_or(::rusoto_core::BuildError::missing("subscription_arn"))?,
+        })
+    }
+}
+
 /// Serialize `UnsubscribeInput` contents to a `SignedRequest`.
 struct UnsubscribeInputSerializer;
 impl UnsubscribeInputSerializer {
@@ -2200,6 +3548,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// Serialize `UntagResourceRequest` contents to a `SignedRequest`.
 struct UntagResourceRequestSerializer;
 impl UntagResourceRequestSerializer {
@@ -2217,10 +3605,10 @@ impl UntagResourceRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UntagResourceResponse {}
 
-struct UntagResourceResponseDeserializer;
+pub(crate) struct UntagResourceResponseDeserializer;
 impl UntagResourceResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UntagResourceResponse, XmlParseError> {
@@ -5025,6 +6413,199 @@ pub trait Sns {
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
 }
+impl<T: ?Sized + Sns> Sns for ::std::sync::Arc<T> {
+    fn add_permission(&self, input: AddPermissionInput) -> RusotoFuture<(), AddPermissionError> {
+        (**self).add_permission(input)
+    }
+    fn check_if_phone_number_is_opted_out(
+        &self,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> RusotoFuture<CheckIfPhoneNumberIsOptedOutResponse, CheckIfPhoneNumberIsOptedOutError> {
+        (**self).check_if_phone_number_is_opted_out(input)
+    }
+    fn confirm_subscription(
+        &self,
+        input: ConfirmSubscriptionInput,
+    ) -> RusotoFuture<ConfirmSubscriptionResponse, ConfirmSubscriptionError> {
+        (**self).confirm_subscription(input)
+    }
+    fn create_platform_application(
+        &self,
+        input: CreatePlatformApplicationInput,
+    ) -> RusotoFuture<CreatePlatformApplicationResponse, CreatePlatformApplicationError> {
+        (**self).create_platform_application(input)
+    }
+    fn create_platform_endpoint(
+        &self,
+        input: CreatePlatformEndpointInput,
+    ) -> RusotoFuture<CreateEndpointResponse, CreatePlatformEndpointError> {
+        (**self).create_platform_endpoint(input)
+    }
+    fn create_topic(
+        &self,
+        input: CreateTopicInput,
+    ) -> RusotoFuture<CreateTopicResponse, CreateTopicError> {
+        (**self).create_topic(input)
+    }
+    fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
+        (**self).delete_endpoint(input)
+    }
+    fn delete_platform_application(
+        &self,
+        input: DeletePlatformApplicationInput,
+    ) -> RusotoFuture<(), DeletePlatformApplicationError> {
+        (**self).delete_platform_application(input)
+    }
+    fn delete_topic(&self, input: DeleteTopicInput) -> RusotoFuture<(), DeleteTopicError> {
+        (**self).delete_topic(input)
+    }
+    fn get_endpoint_attributes(
+        &self,
+        input: GetEndpointAttributesInput,
+    ) -> RusotoFuture<GetEndpointAttributesResponse, GetEndpointAttributesError> {
+        (**self).get_endpoint_attributes(input)
+    }
+    fn get_platform_application_attributes(
+        &self,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<GetPlatformApplicationAttributesResponse, GetPlatformApplicationAttributesError>
+    {
+        (**self).get_platform_application_attributes(input)
+    }
+    fn get_sms_attributes(
+        &self,
+        input: GetSMSAttributesInput,
+    ) -> RusotoFuture<GetSMSAttributesResponse, GetSMSAttributesError> {
+        (**self).get_sms_attributes(input)
+    }
+    fn get_subscription_attributes(
+        &self,
+        input: GetSubscriptionAttributesInput,
+    ) -> RusotoFuture<GetSubscriptionAttributesResponse, GetSubscriptionAttributesError> {
+        (**self).get_subscription_attributes(input)
+    }
+    fn get_topic_attributes(
+        &self,
+        input: GetTopicAttributesInput,
+    ) -> RusotoFuture<GetTopicAttributesResponse, GetTopicAttributesError> {
+        (**self).get_topic_attributes(input)
+    }
+    fn list_endpoints_by_platform_application(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> RusotoFuture<
+        ListEndpointsByPlatformApplicationResponse,
+        ListEndpointsByPlatformApplicationError,
+    > {
+        (**self).list_endpoints_by_platform_application(input)
+    }
+    fn list_phone_numbers_opted_out(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> RusotoFuture<ListPhoneNumbersOptedOutResponse, ListPhoneNumbersOptedOutError> {
+        (**self).list_phone_numbers_opted_out(input)
+    }
+    fn list_platform_applications(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> RusotoFuture<ListPlatformApplicationsResponse, ListPlatformApplicationsError> {
+        (**self).list_platform_applications(input)
+    }
+    fn list_subscriptions(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> RusotoFuture<ListSubscriptionsResponse, ListSubscriptionsError> {
+        (**self).list_subscriptions(input)
+    }
+    fn list_subscriptions_by_topic(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> RusotoFuture<ListSubscriptionsByTopicResponse, ListSubscriptionsByTopicError> {
+        (**self).list_subscriptions_by_topic(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_topics(
+        &self,
+        input: ListTopicsInput,
+    ) -> RusotoFuture<ListTopicsResponse, ListTopicsError> {
+        (**self).list_topics(input)
+    }
+    fn opt_in_phone_number(
+        &self,
+        input: OptInPhoneNumberInput,
+    ) -> RusotoFuture<OptInPhoneNumberResponse, OptInPhoneNumberError> {
+        (**self).opt_in_phone_number(input)
+    }
+    fn publish(&self, input: PublishInput) -> RusotoFuture<PublishResponse, PublishError> {
+        (**self).publish(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionInput,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn set_endpoint_attributes(
+        &self,
+        input: SetEndpointAttributesInput,
+    ) -> RusotoFuture<(), SetEndpointAttributesError> {
+        (**self).set_endpoint_attributes(input)
+    }
+    fn set_platform_application_attributes(
+        &self,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<(), SetPlatformApplicationAttributesError> {
+        (**self).set_platform_application_attributes(input)
+    }
+    fn set_sms_attributes(
+        &self,
+        input: SetSMSAttributesInput,
+    ) -> RusotoFuture<SetSMSAttributesResponse, SetSMSAttributesError> {
+        (**self).set_sms_attributes(input)
+    }
+    fn set_subscription_attributes(
+        &self,
+        input: SetSubscriptionAttributesInput,
+    ) -> RusotoFuture<(), SetSubscriptionAttributesError> {
+        (**self).set_subscription_attributes(input)
+    }
+    fn set_topic_attributes(
+        &self,
+        input: SetTopicAttributesInput,
+    ) -> RusotoFuture<(), SetTopicAttributesError> {
+        (**self).set_topic_attributes(input)
+    }
+    fn subscribe(&self, input: SubscribeInput) -> RusotoFuture<SubscribeResponse, SubscribeError> {
+        (**self).subscribe(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unsubscribe(&self, input: UnsubscribeInput) -> RusotoFuture<(), UnsubscribeError> {
+        (**self).unsubscribe(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// The error codes Amazon SNS retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon SNS API.
 #[derive(Clone)]
 pub struct SnsClient {
@@ -5040,6 +6621,24 @@ impl SnsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SNS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> SnsClient {
+        Self::new(region::Region::from_env_for_service("SNS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `sns`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> SnsClient {
+        Self::new_with_client(config.client().clone(), config.region_for("sns", "SNS"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5058,7 +6657,49 @@ impl SnsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SnsClient {
-        SnsClient { client, region }
+        SnsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "sns")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "sns", "sns", "SNS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Sns` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Sns + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for SnsClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon SNS"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "sns"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> SnsClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -24,6 +24,8 @@ extern crate xml;
 
 mod generated;
 mod custom;
+#[cfg(feature = "fake")]
+pub mod fake;
 
 pub use crate::generated::*;
 pub use crate::custom::*;
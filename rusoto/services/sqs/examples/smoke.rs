@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Simple Queue Service clients.
+//!
+//! Makes a single read-only `ListQueues` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_sqs::{ListQueuesRequest, Sqs, SqsClient};
+
+fn main() {
+    let client = SqsClient::from_env();
+    match client.list_queues(ListQueuesRequest::default()).sync() {
+        Ok(_) => println!("ListQueues succeeded"),
+        Err(error) => {
+            eprintln!("ListQueues failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
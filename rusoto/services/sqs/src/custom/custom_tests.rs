@@ -179,3 +179,66 @@ fn test_parse_queue_does_not_exist_error() {
         err
     );
 }
+
+#[cfg(feature = "fake")]
+#[test]
+fn fake_sqs_sends_receives_and_deletes_messages() {
+    use crate::fake::FakeSqs;
+    use crate::generated::{CreateQueueRequest, DeleteMessageError, DeleteMessageRequest};
+
+    let sqs = FakeSqs::new();
+    let queue_url = sqs
+        .create_queue(CreateQueueRequest {
+            queue_name: "jobs".to_owned(),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap()
+        .queue_url
+        .unwrap();
+    for body in &["first", "second"] {
+        sqs.send_message(SendMessageRequest {
+            queue_url: queue_url.clone(),
+            message_body: body.to_string(),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap();
+    }
+
+    let messages = sqs
+        .receive_message(ReceiveMessageRequest {
+            queue_url: queue_url.clone(),
+            max_number_of_messages: Some(10),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap()
+        .messages
+        .unwrap();
+    let bodies: Vec<_> = messages.iter().filter_map(|m| m.body.clone()).collect();
+    assert_eq!(bodies, vec!["first", "second"]);
+    assert!(sqs.backend().visible_messages(&queue_url).is_empty());
+
+    let receipt_handle = messages[0].receipt_handle.clone().unwrap();
+    let delete = DeleteMessageRequest {
+        queue_url: queue_url.clone(),
+        receipt_handle,
+    };
+    sqs.delete_message(delete.clone()).sync().unwrap();
+    match sqs.delete_message(delete).sync() {
+        Err(RusotoError::Service(DeleteMessageError::ReceiptHandleIsInvalid(_))) => {}
+        other => panic!("expected ReceiptHandleIsInvalid, got {:?}", other),
+    }
+
+    match sqs
+        .get_queue_url(GetQueueUrlRequest {
+            queue_name: "missing".to_owned(),
+            ..Default::default()
+        })
+        .sync()
+    {
+        Err(RusotoError::Service(GetQueueUrlError::QueueDoesNotExist(_))) => {}
+        other => panic!("expected QueueDoesNotExist, got {:?}", other),
+    }
+}
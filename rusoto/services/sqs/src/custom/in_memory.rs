@@ -0,0 +1,286 @@
+//! An SQS backend keeping queues and messages in memory.
+//!
+//! `FakeSqs::new()` creates a fake SQS backed by an empty `InMemorySqs`, which can stand in for an
+//! `SqsClient` in unit tests. Queues can be created, looked up, listed, purged and deleted, and
+//! messages sent, received and deleted. The other operations fail with
+//! `RusotoError::Validation`.
+//!
+//! Received messages stay in flight until they are deleted: visibility timeouts, delays and long
+//! polling aren't simulated.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+
+use rusoto_core::signature::ChecksumAlgorithm;
+use rusoto_core::RusotoError;
+
+use crate::fake::{FakeSqs, SqsBackend};
+use crate::generated::{
+    CreateQueueError, CreateQueueRequest, CreateQueueResult, DeleteMessageError,
+    DeleteMessageRequest, DeleteQueueError, DeleteQueueRequest, GetQueueUrlError,
+    GetQueueUrlRequest, GetQueueUrlResult, ListQueuesError, ListQueuesRequest, ListQueuesResult,
+    Message, PurgeQueueError, PurgeQueueRequest, QueueAttributeName, ReceiveMessageError,
+    ReceiveMessageRequest, ReceiveMessageResult, SendMessageError, SendMessageRequest,
+    SendMessageResult,
+};
+
+/// The URL queues are created under.
+const QUEUE_URL_PREFIX: &str = "https://queue.amazonaws.com/123456789012/";
+
+#[derive(Debug, Default)]
+struct Queue {
+    attributes: HashMap<QueueAttributeName, String>,
+    visible: VecDeque<Message>,
+    // received messages by their receipt handle
+    in_flight: HashMap<String, Message>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    // queues by their URL
+    queues: BTreeMap<String, Queue>,
+    next_id: u64,
+}
+
+impl State {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+/// Queues and their messages, kept in memory.
+#[derive(Debug, Default)]
+pub struct InMemorySqs {
+    state: Mutex<State>,
+}
+
+impl InMemorySqs {
+    /// The bodies of the messages in a queue that haven't been received yet.
+    pub fn visible_messages(&self, queue_url: &str) -> Vec<String> {
+        self.state()
+            .queues
+            .get(queue_url)
+            .map(|queue| {
+                queue
+                    .visible
+                    .iter()
+                    .filter_map(|message| message.body.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().expect("InMemorySqs lock poisoned")
+    }
+}
+
+impl FakeSqs<InMemorySqs> {
+    /// Creates a fake SQS without any queues.
+    pub fn new() -> FakeSqs<InMemorySqs> {
+        FakeSqs::with_backend(InMemorySqs::default())
+    }
+}
+
+impl Default for FakeSqs<InMemorySqs> {
+    fn default() -> FakeSqs<InMemorySqs> {
+        FakeSqs::new()
+    }
+}
+
+fn queue_name(queue_url: &str) -> &str {
+    queue_url.rsplit('/').next().unwrap_or(queue_url)
+}
+
+fn non_existent_queue<E>(queue_url: &str) -> RusotoError<E> {
+    RusotoError::Validation(format!(
+        "AWS.SimpleQueueService.NonExistentQueue: The specified queue does not exist: {}",
+        queue_url
+    ))
+}
+
+fn md5_hex(data: &str) -> String {
+    ChecksumAlgorithm::Md5
+        .digest(data.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+impl SqsBackend for InMemorySqs {
+    fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> Result<CreateQueueResult, RusotoError<CreateQueueError>> {
+        let queue_url = format!("{}{}", QUEUE_URL_PREFIX, input.queue_name);
+        let attributes = input.attributes.unwrap_or_default();
+        let mut state = self.state();
+        match state.queues.get(&queue_url) {
+            // creating an existing queue with the same attributes returns its URL
+            Some(queue) if queue.attributes != attributes => {
+                return Err(RusotoError::Service(CreateQueueError::QueueNameExists(
+                    format!(
+                        "A queue already exists with the same name and a different value for attribute(s): {}",
+                        input.queue_name
+                    ),
+                )))
+            }
+            Some(_) => {}
+            None => {
+                state.queues.insert(
+                    queue_url.clone(),
+                    Queue {
+                        attributes,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+        Ok(CreateQueueResult {
+            queue_url: Some(queue_url),
+        })
+    }
+
+    fn delete_message(
+        &self,
+        input: DeleteMessageRequest,
+    ) -> Result<(), RusotoError<DeleteMessageError>> {
+        let mut state = self.state();
+        let queue = state
+            .queues
+            .get_mut(&input.queue_url)
+            .ok_or_else(|| non_existent_queue(&input.queue_url))?;
+        match queue.in_flight.remove(&input.receipt_handle) {
+            Some(_) => Ok(()),
+            None => Err(RusotoError::Service(
+                DeleteMessageError::ReceiptHandleIsInvalid(format!(
+                    "The input receipt handle is invalid: {}",
+                    input.receipt_handle
+                )),
+            )),
+        }
+    }
+
+    fn delete_queue(&self, input: DeleteQueueRequest) -> Result<(), RusotoError<DeleteQueueError>> {
+        self.state()
+            .queues
+            .remove(&input.queue_url)
+            .map(|_| ())
+            .ok_or_else(|| non_existent_queue(&input.queue_url))
+    }
+
+    fn get_queue_url(
+        &self,
+        input: GetQueueUrlRequest,
+    ) -> Result<GetQueueUrlResult, RusotoError<GetQueueUrlError>> {
+        let queue_url = format!("{}{}", QUEUE_URL_PREFIX, input.queue_name);
+        if self.state().queues.contains_key(&queue_url) {
+            Ok(GetQueueUrlResult {
+                queue_url: Some(queue_url),
+            })
+        } else {
+            Err(RusotoError::Service(GetQueueUrlError::QueueDoesNotExist(
+                format!("The specified queue does not exist: {}", input.queue_name),
+            )))
+        }
+    }
+
+    fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<ListQueuesResult, RusotoError<ListQueuesError>> {
+        let prefix = input.queue_name_prefix.unwrap_or_default();
+        let queue_urls = self
+            .state()
+            .queues
+            .keys()
+            .filter(|queue_url| queue_name(queue_url).starts_with(&prefix))
+            .cloned()
+            .collect();
+        Ok(ListQueuesResult {
+            queue_urls: Some(queue_urls),
+        })
+    }
+
+    fn purge_queue(&self, input: PurgeQueueRequest) -> Result<(), RusotoError<PurgeQueueError>> {
+        let mut state = self.state();
+        let queue = state.queues.get_mut(&input.queue_url).ok_or_else(|| {
+            RusotoError::Service(PurgeQueueError::QueueDoesNotExist(format!(
+                "The specified queue does not exist: {}",
+                input.queue_url
+            )))
+        })?;
+        queue.visible.clear();
+        queue.in_flight.clear();
+        Ok(())
+    }
+
+    fn receive_message(
+        &self,
+        input: ReceiveMessageRequest,
+    ) -> Result<ReceiveMessageResult, RusotoError<ReceiveMessageError>> {
+        let max_number_of_messages = match input.max_number_of_messages {
+            None => 1,
+            Some(max) if max >= 1 && max <= 10 => max as usize,
+            Some(max) => return Err(RusotoError::Validation(format!(
+                "Value {} for parameter MaxNumberOfMessages is invalid. Must be between 1 and 10.",
+                max
+            ))),
+        };
+        let mut state = self.state();
+        let State {
+            ref mut queues,
+            ref mut next_id,
+        } = *state;
+        let queue = queues
+            .get_mut(&input.queue_url)
+            .ok_or_else(|| non_existent_queue(&input.queue_url))?;
+        let count = max_number_of_messages.min(queue.visible.len());
+        let received: Vec<Message> = queue.visible.drain(..count).collect();
+        let mut messages = Vec::with_capacity(received.len());
+        for message in received {
+            *next_id += 1;
+            let receipt_handle = format!(
+                "{}#{}",
+                message.message_id.as_ref().map_or("", String::as_str),
+                next_id
+            );
+            queue
+                .in_flight
+                .insert(receipt_handle.clone(), message.clone());
+            messages.push(Message {
+                receipt_handle: Some(receipt_handle),
+                ..message
+            });
+        }
+        Ok(ReceiveMessageResult {
+            messages: Some(messages),
+        })
+    }
+
+    fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> Result<SendMessageResult, RusotoError<SendMessageError>> {
+        let mut state = self.state();
+        let message_id = format!("00000000-0000-0000-0000-{:012}", state.next_id());
+        let queue = state
+            .queues
+            .get_mut(&input.queue_url)
+            .ok_or_else(|| non_existent_queue(&input.queue_url))?;
+        let md5_of_body = md5_hex(&input.message_body);
+        queue.visible.push_back(Message {
+            body: Some(input.message_body),
+            md5_of_body: Some(md5_of_body.clone()),
+            message_attributes: input.message_attributes,
+            message_id: Some(message_id.clone()),
+            ..Default::default()
+        });
+        Ok(SendMessageResult {
+            md5_of_message_body: Some(md5_of_body),
+            message_id: Some(message_id),
+            ..Default::default()
+        })
+    }
+}
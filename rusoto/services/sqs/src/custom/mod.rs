@@ -1,2 +1,6 @@
+/// An in-memory backend for `FakeSqs`
+#[cfg(feature = "fake")]
+pub mod in_memory;
+
 #[cfg(test)]
 mod custom_tests;
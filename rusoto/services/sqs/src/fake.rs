@@ -0,0 +1,307 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! An in-process fake of the Amazon SQS API for unit tests.
+//!
+//! `FakeSqs` implements `Sqs` by calling a `SqsBackend`, which holds the
+//! state of the fake. Backend methods fail with `RusotoError::Validation` unless they are
+//! implemented, so a backend only needs to implement the operations a test calls.
+
+use std::sync::Arc;
+
+use futures::future;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::*;
+
+/// The error returned by operations a backend doesn't implement.
+pub fn unsupported_operation<E>(operation: &str) -> RusotoError<E> {
+    RusotoError::Validation(format!("{} is not supported by this fake", operation))
+}
+
+/// The state and behaviour of a `FakeSqs`.
+pub trait SqsBackend: Send + Sync {
+    fn add_permission(
+        &self,
+        _input: AddPermissionRequest,
+    ) -> Result<(), RusotoError<AddPermissionError>> {
+        Err(unsupported_operation("AddPermission"))
+    }
+    fn change_message_visibility(
+        &self,
+        _input: ChangeMessageVisibilityRequest,
+    ) -> Result<(), RusotoError<ChangeMessageVisibilityError>> {
+        Err(unsupported_operation("ChangeMessageVisibility"))
+    }
+    fn change_message_visibility_batch(
+        &self,
+        _input: ChangeMessageVisibilityBatchRequest,
+    ) -> Result<ChangeMessageVisibilityBatchResult, RusotoError<ChangeMessageVisibilityBatchError>>
+    {
+        Err(unsupported_operation("ChangeMessageVisibilityBatch"))
+    }
+    fn create_queue(
+        &self,
+        _input: CreateQueueRequest,
+    ) -> Result<CreateQueueResult, RusotoError<CreateQueueError>> {
+        Err(unsupported_operation("CreateQueue"))
+    }
+    fn delete_message(
+        &self,
+        _input: DeleteMessageRequest,
+    ) -> Result<(), RusotoError<DeleteMessageError>> {
+        Err(unsupported_operation("DeleteMessage"))
+    }
+    fn delete_message_batch(
+        &self,
+        _input: DeleteMessageBatchRequest,
+    ) -> Result<DeleteMessageBatchResult, RusotoError<DeleteMessageBatchError>> {
+        Err(unsupported_operation("DeleteMessageBatch"))
+    }
+    fn delete_queue(
+        &self,
+        _input: DeleteQueueRequest,
+    ) -> Result<(), RusotoError<DeleteQueueError>> {
+        Err(unsupported_operation("DeleteQueue"))
+    }
+    fn get_queue_attributes(
+        &self,
+        _input: GetQueueAttributesRequest,
+    ) -> Result<GetQueueAttributesResult, RusotoError<GetQueueAttributesError>> {
+        Err(unsupported_operation("GetQueueAttributes"))
+    }
+    fn get_queue_url(
+        &self,
+        _input: GetQueueUrlRequest,
+    ) -> Result<GetQueueUrlResult, RusotoError<GetQueueUrlError>> {
+        Err(unsupported_operation("GetQueueUrl"))
+    }
+    fn list_dead_letter_source_queues(
+        &self,
+        _input: ListDeadLetterSourceQueuesRequest,
+    ) -> Result<ListDeadLetterSourceQueuesResult, RusotoError<ListDeadLetterSourceQueuesError>>
+    {
+        Err(unsupported_operation("ListDeadLetterSourceQueues"))
+    }
+    fn list_queue_tags(
+        &self,
+        _input: ListQueueTagsRequest,
+    ) -> Result<ListQueueTagsResult, RusotoError<ListQueueTagsError>> {
+        Err(unsupported_operation("ListQueueTags"))
+    }
+    fn list_queues(
+        &self,
+        _input: ListQueuesRequest,
+    ) -> Result<ListQueuesResult, RusotoError<ListQueuesError>> {
+        Err(unsupported_operation("ListQueues"))
+    }
+    fn purge_queue(&self, _input: PurgeQueueRequest) -> Result<(), RusotoError<PurgeQueueError>> {
+        Err(unsupported_operation("PurgeQueue"))
+    }
+    fn receive_message(
+        &self,
+        _input: ReceiveMessageRequest,
+    ) -> Result<ReceiveMessageResult, RusotoError<ReceiveMessageError>> {
+        Err(unsupported_operation("ReceiveMessage"))
+    }
+    fn remove_permission(
+        &self,
+        _input: RemovePermissionRequest,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        Err(unsupported_operation("RemovePermission"))
+    }
+    fn send_message(
+        &self,
+        _input: SendMessageRequest,
+    ) -> Result<SendMessageResult, RusotoError<SendMessageError>> {
+        Err(unsupported_operation("SendMessage"))
+    }
+    fn send_message_batch(
+        &self,
+        _input: SendMessageBatchRequest,
+    ) -> Result<SendMessageBatchResult, RusotoError<SendMessageBatchError>> {
+        Err(unsupported_operation("SendMessageBatch"))
+    }
+    fn set_queue_attributes(
+        &self,
+        _input: SetQueueAttributesRequest,
+    ) -> Result<(), RusotoError<SetQueueAttributesError>> {
+        Err(unsupported_operation("SetQueueAttributes"))
+    }
+    fn tag_queue(&self, _input: TagQueueRequest) -> Result<(), RusotoError<TagQueueError>> {
+        Err(unsupported_operation("TagQueue"))
+    }
+    fn untag_queue(&self, _input: UntagQueueRequest) -> Result<(), RusotoError<UntagQueueError>> {
+        Err(unsupported_operation("UntagQueue"))
+    }
+}
+
+/// An implementation of `Sqs` calling a `SqsBackend` instead of AWS.
+///
+/// Operations are run by the backend when the returned futures are polled. Clones of the fake
+/// share the same backend.
+pub struct FakeSqs<B> {
+    backend: Arc<B>,
+}
+
+impl<B> Clone for FakeSqs<B> {
+    fn clone(&self) -> Self {
+        FakeSqs {
+            backend: self.backend.clone(),
+        }
+    }
+}
+
+impl<B: SqsBackend> FakeSqs<B> {
+    /// Creates a fake calling `backend`.
+    pub fn with_backend(backend: B) -> FakeSqs<B> {
+        FakeSqs {
+            backend: Arc::new(backend),
+        }
+    }
+
+    /// The backend of the fake, to set up or inspect its state.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+}
+
+impl<B: SqsBackend + 'static> Sqs for FakeSqs<B> {
+    fn add_permission(&self, input: AddPermissionRequest) -> RusotoFuture<(), AddPermissionError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.add_permission(input)))
+    }
+    fn change_message_visibility(
+        &self,
+        input: ChangeMessageVisibilityRequest,
+    ) -> RusotoFuture<(), ChangeMessageVisibilityError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.change_message_visibility(input)
+        }))
+    }
+    fn change_message_visibility_batch(
+        &self,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> RusotoFuture<ChangeMessageVisibilityBatchResult, ChangeMessageVisibilityBatchError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.change_message_visibility_batch(input)
+        }))
+    }
+    fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> RusotoFuture<CreateQueueResult, CreateQueueError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.create_queue(input)))
+    }
+    fn delete_message(&self, input: DeleteMessageRequest) -> RusotoFuture<(), DeleteMessageError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_message(input)))
+    }
+    fn delete_message_batch(
+        &self,
+        input: DeleteMessageBatchRequest,
+    ) -> RusotoFuture<DeleteMessageBatchResult, DeleteMessageBatchError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_message_batch(input)))
+    }
+    fn delete_queue(&self, input: DeleteQueueRequest) -> RusotoFuture<(), DeleteQueueError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_queue(input)))
+    }
+    fn get_queue_attributes(
+        &self,
+        input: GetQueueAttributesRequest,
+    ) -> RusotoFuture<GetQueueAttributesResult, GetQueueAttributesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_queue_attributes(input)))
+    }
+    fn get_queue_url(
+        &self,
+        input: GetQueueUrlRequest,
+    ) -> RusotoFuture<GetQueueUrlResult, GetQueueUrlError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_queue_url(input)))
+    }
+    fn list_dead_letter_source_queues(
+        &self,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> RusotoFuture<ListDeadLetterSourceQueuesResult, ListDeadLetterSourceQueuesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.list_dead_letter_source_queues(input)
+        }))
+    }
+    fn list_queue_tags(
+        &self,
+        input: ListQueueTagsRequest,
+    ) -> RusotoFuture<ListQueueTagsResult, ListQueueTagsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_queue_tags(input)))
+    }
+    fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> RusotoFuture<ListQueuesResult, ListQueuesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_queues(input)))
+    }
+    fn purge_queue(&self, input: PurgeQueueRequest) -> RusotoFuture<(), PurgeQueueError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.purge_queue(input)))
+    }
+    fn receive_message(
+        &self,
+        input: ReceiveMessageRequest,
+    ) -> RusotoFuture<ReceiveMessageResult, ReceiveMessageError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.receive_message(input)))
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.remove_permission(input)))
+    }
+    fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> RusotoFuture<SendMessageResult, SendMessageError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.send_message(input)))
+    }
+    fn send_message_batch(
+        &self,
+        input: SendMessageBatchRequest,
+    ) -> RusotoFuture<SendMessageBatchResult, SendMessageBatchError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.send_message_batch(input)))
+    }
+    fn set_queue_attributes(
+        &self,
+        input: SetQueueAttributesRequest,
+    ) -> RusotoFuture<(), SetQueueAttributesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.set_queue_attributes(input)))
+    }
+    fn tag_queue(&self, input: TagQueueRequest) -> RusotoFuture<(), TagQueueError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.tag_queue(input)))
+    }
+    fn untag_queue(&self, input: UntagQueueRequest) -> RusotoFuture<(), UntagQueueError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.untag_queue(input)))
+    }
+}
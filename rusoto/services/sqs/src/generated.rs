@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -68,6 +69,66 @@ pub struct AddPermissionRequest {
     pub queue_url: String,
 }
 
+impl AddPermissionRequest {
+    /// Returns a builder for `AddPermissionRequest`, checking its required members when built.
+    pub fn builder() -> AddPermissionRequestBuilder {
+        AddPermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddPermissionRequest`](struct.AddPermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct AddPermissionRequestBuilder {
+    aws_account_ids: Option<Vec<String>>,
+    actions: Option<Vec<String>>,
+    label: Option<String>,
+    queue_url: Option<String>,
+}
+
+impl AddPermissionRequestBuilder {
+    /// Sets the [`aws_account_ids`](struct.AddPermissionRequest.html#structfield.aws_account_ids) member.
+    pub fn aws_account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.aws_account_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`actions`](struct.AddPermissionRequest.html#structfield.actions) member.
+    pub fn actions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`label`](struct.AddPermissionRequest.html#structfield.label) member.
+    pub fn label<T: Into<String>>(mut self, value: T) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_url`](struct.AddPermissionRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddPermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddPermissionRequest, ::rusoto_core::BuildError> {
+        Ok(AddPermissionRequest {
+            aws_account_ids: self
+                .aws_account_ids
+                .ok_or(::rusoto_core::BuildError::missing("aws_account_ids"))?,
+            actions: self
+                .actions
+                .ok_or(::rusoto_core::BuildError::missing("actions"))?,
+            label: self
+                .label
+                .ok_or(::rusoto_core::BuildError::missing("label"))?,
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `AddPermissionRequest` contents to a `SignedRequest`.
 struct AddPermissionRequestSerializer;
 impl AddPermissionRequestSerializer {
@@ -95,7 +156,7 @@ impl AddPermissionRequestSerializer {
 /// Serialize `AttributeNameList` contents to a `SignedRequest`.
 struct AttributeNameListSerializer;
 impl AttributeNameListSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<QueueAttributeName>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.{}", name, index + 1);
             params.put(&key, &obj);
@@ -116,10 +177,10 @@ pub struct BatchResultErrorEntry {
     pub sender_fault: bool,
 }
 
-struct BatchResultErrorEntryDeserializer;
+pub(crate) struct BatchResultErrorEntryDeserializer;
 impl BatchResultErrorEntryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<BatchResultErrorEntry, XmlParseError> {
@@ -143,10 +204,10 @@ impl BatchResultErrorEntryDeserializer {
         })
     }
 }
-struct BatchResultErrorEntryListDeserializer;
+pub(crate) struct BatchResultErrorEntryListDeserializer;
 impl BatchResultErrorEntryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<BatchResultErrorEntry>, XmlParseError> {
@@ -172,10 +233,10 @@ impl BatchResultErrorEntryListDeserializer {
         Ok(obj)
     }
 }
-struct BinaryDeserializer;
+pub(crate) struct BinaryDeserializer;
 impl BinaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<bytes::Bytes, XmlParseError> {
@@ -186,10 +247,10 @@ impl BinaryDeserializer {
         Ok(obj)
     }
 }
-struct BinaryListDeserializer;
+pub(crate) struct BinaryListDeserializer;
 impl BinaryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<bytes::Bytes>, XmlParseError> {
@@ -215,10 +276,13 @@ impl BinaryListSerializer {
     }
 }
 
-struct BooleanDeserializer;
+pub(crate) struct BooleanDeserializer;
 impl BooleanDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -235,6 +299,51 @@ pub struct ChangeMessageVisibilityBatchRequest {
     pub queue_url: String,
 }
 
+impl ChangeMessageVisibilityBatchRequest {
+    /// Returns a builder for `ChangeMessageVisibilityBatchRequest`, checking its required members when built.
+    pub fn builder() -> ChangeMessageVisibilityBatchRequestBuilder {
+        ChangeMessageVisibilityBatchRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ChangeMessageVisibilityBatchRequest`](struct.ChangeMessageVisibilityBatchRequest.html).
+#[derive(Default, Debug)]
+pub struct ChangeMessageVisibilityBatchRequestBuilder {
+    entries: Option<Vec<ChangeMessageVisibilityBatchRequestEntry>>,
+    queue_url: Option<String>,
+}
+
+impl ChangeMessageVisibilityBatchRequestBuilder {
+    /// Sets the [`entries`](struct.ChangeMessageVisibilityBatchRequest.html#structfield.entries) member.
+    pub fn entries<T: Into<Vec<ChangeMessageVisibilityBatchRequestEntry>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.entries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_url`](struct.ChangeMessageVisibilityBatchRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `ChangeMessageVisibilityBatchRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ChangeMessageVisibilityBatchRequest, ::rusoto_core::BuildError> {
+        Ok(ChangeMessageVisibilityBatchRequest {
+            entries: self
+                .entries
+                .ok_or(::rusoto_core::BuildError::missing("entries"))?,
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `ChangeMessageVisibilityBatchRequest` contents to a `SignedRequest`.
 struct ChangeMessageVisibilityBatchRequestSerializer;
 impl ChangeMessageVisibilityBatchRequestSerializer {
@@ -308,10 +417,10 @@ pub struct ChangeMessageVisibilityBatchResult {
     pub successful: Vec<ChangeMessageVisibilityBatchResultEntry>,
 }
 
-struct ChangeMessageVisibilityBatchResultDeserializer;
+pub(crate) struct ChangeMessageVisibilityBatchResultDeserializer;
 impl ChangeMessageVisibilityBatchResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ChangeMessageVisibilityBatchResult, XmlParseError> {
@@ -349,10 +458,10 @@ pub struct ChangeMessageVisibilityBatchResultEntry {
     pub id: String,
 }
 
-struct ChangeMessageVisibilityBatchResultEntryDeserializer;
+pub(crate) struct ChangeMessageVisibilityBatchResultEntryDeserializer;
 impl ChangeMessageVisibilityBatchResultEntryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ChangeMessageVisibilityBatchResultEntry, XmlParseError> {
@@ -371,10 +480,10 @@ impl ChangeMessageVisibilityBatchResultEntryDeserializer {
         )
     }
 }
-struct ChangeMessageVisibilityBatchResultEntryListDeserializer;
+pub(crate) struct ChangeMessageVisibilityBatchResultEntryListDeserializer;
 impl ChangeMessageVisibilityBatchResultEntryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ChangeMessageVisibilityBatchResultEntry>, XmlParseError> {
@@ -412,6 +521,58 @@ pub struct ChangeMessageVisibilityRequest {
     pub visibility_timeout: i64,
 }
 
+impl ChangeMessageVisibilityRequest {
+    /// Returns a builder for `ChangeMessageVisibilityRequest`, checking its required members when built.
+    pub fn builder() -> ChangeMessageVisibilityRequestBuilder {
+        ChangeMessageVisibilityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ChangeMessageVisibilityRequest`](struct.ChangeMessageVisibilityRequest.html).
+#[derive(Default, Debug)]
+pub struct ChangeMessageVisibilityRequestBuilder {
+    queue_url: Option<String>,
+    receipt_handle: Option<String>,
+    visibility_timeout: Option<i64>,
+}
+
+impl ChangeMessageVisibilityRequestBuilder {
+    /// Sets the [`queue_url`](struct.ChangeMessageVisibilityRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`receipt_handle`](struct.ChangeMessageVisibilityRequest.html#structfield.receipt_handle) member.
+    pub fn receipt_handle<T: Into<String>>(mut self, value: T) -> Self {
+        self.receipt_handle = Some(value.into());
+        self
+    }
+
+    /// Sets the [`visibility_timeout`](struct.ChangeMessageVisibilityRequest.html#structfield.visibility_timeout) member.
+    pub fn visibility_timeout<T: Into<i64>>(mut self, value: T) -> Self {
+        self.visibility_timeout = Some(value.into());
+        self
+    }
+
+    /// Builds the `ChangeMessageVisibilityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ChangeMessageVisibilityRequest, ::rusoto_core::BuildError> {
+        Ok(ChangeMessageVisibilityRequest {
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+            receipt_handle: self
+                .receipt_handle
+                .ok_or(::rusoto_core::BuildError::missing("receipt_handle"))?,
+            visibility_timeout: self
+                .visibility_timeout
+                .ok_or(::rusoto_core::BuildError::missing("visibility_timeout"))?,
+        })
+    }
+}
+
 /// Serialize `ChangeMessageVisibilityRequest` contents to a `SignedRequest`.
 struct ChangeMessageVisibilityRequestSerializer;
 impl ChangeMessageVisibilityRequestSerializer {
@@ -437,13 +598,62 @@ impl ChangeMessageVisibilityRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateQueueRequest {
     /// <p><p>A map of attributes with their corresponding values.</p> <p>The following lists the names, descriptions, and values of the special request parameters that the <code>CreateQueue</code> action uses:</p> <ul> <li> <p> <code>DelaySeconds</code> - The length of time, in seconds, for which the delivery of all messages in the queue is delayed. Valid values: An integer from 0 to 900 seconds (15 minutes). Default: 0. </p> </li> <li> <p> <code>MaximumMessageSize</code> - The limit of how many bytes a message can contain before Amazon SQS rejects it. Valid values: An integer from 1,024 bytes (1 KiB) to 262,144 bytes (256 KiB). Default: 262,144 (256 KiB). </p> </li> <li> <p> <code>MessageRetentionPeriod</code> - The length of time, in seconds, for which Amazon SQS retains a message. Valid values: An integer from 60 seconds (1 minute) to 1,209,600 seconds (14 days). Default: 345,600 (4 days). </p> </li> <li> <p> <code>Policy</code> - The queue&#39;s policy. A valid AWS policy. For more information about policy structure, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/PoliciesOverview.html">Overview of AWS IAM Policies</a> in the <i>Amazon IAM User Guide</i>. </p> </li> <li> <p> <code>ReceiveMessageWaitTimeSeconds</code> - The length of time, in seconds, for which a <code> <a>ReceiveMessage</a> </code> action waits for a message to arrive. Valid values: An integer from 0 to 20 (seconds). Default: 0. </p> </li> <li> <p> <code>RedrivePolicy</code> - The string that includes the parameters for the dead-letter queue functionality of the source queue. For more information about the redrive policy and dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p> <code>deadLetterTargetArn</code> - The Amazon Resource Name (ARN) of the dead-letter queue to which Amazon SQS moves messages after the value of <code>maxReceiveCount</code> is exceeded.</p> </li> <li> <p> <code>maxReceiveCount</code> - The number of times a message is delivered to the source queue before being moved to the dead-letter queue. When the <code>ReceiveCount</code> for a message exceeds the <code>maxReceiveCount</code> for a queue, Amazon SQS moves the message to the dead-letter-queue.</p> </li> </ul> <note> <p>The dead-letter queue of a FIFO queue must also be a FIFO queue. Similarly, the dead-letter queue of a standard queue must also be a standard queue.</p> </note> </li> <li> <p> <code>VisibilityTimeout</code> - The visibility timeout for the queue, in seconds. Valid values: An integer from 0 to 43,200 (12 hours). Default: 30. For more information about the visibility timeout, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - The ID of an AWS-managed customer master key (CMK) for Amazon SQS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-sse-key-terms">Key Terms</a>. While the alias of the AWS-managed CMK for Amazon SQS is always <code>alias/aws/sqs</code>, the alias of a custom CMK can, for example, be <code>alias/<i>MyAlias</i> </code>. For more examples, see <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_DescribeKey.html#API_DescribeKey_RequestParameters">KeyId</a> in the <i>AWS Key Management Service API Reference</i>. </p> </li> <li> <p> <code>KmsDataKeyReusePeriodSeconds</code> - The length of time, in seconds, for which Amazon SQS can reuse a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data key</a> to encrypt or decrypt messages before calling AWS KMS again. An integer representing seconds, between 60 seconds (1 minute) and 86,400 seconds (24 hours). Default: 300 (5 minutes). A shorter time period provides better security but results in more calls to KMS which might incur charges after Free Tier. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-how-does-the-data-key-reuse-period-work">How Does the Data Key Reuse Period Work?</a>. </p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO (first-in-first-out) queues</a>:</p> <ul> <li> <p> <code>FifoQueue</code> - Designates a queue as FIFO. Valid values: <code>true</code>, <code>false</code>. If you don&#39;t specify the <code>FifoQueue</code> attribute, Amazon SQS creates a standard queue. You can provide this attribute only during queue creation. You can&#39;t change it for an existing queue. When you set this attribute, you must also provide the <code>MessageGroupId</code> for your messages explicitly.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-understanding-logic">FIFO Queue Logic</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p> <code>ContentBasedDeduplication</code> - Enables content-based deduplication. Valid values: <code>true</code>, <code>false</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-exactly-once-processing">Exactly-Once Processing</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p>Every message must have a unique <code>MessageDeduplicationId</code>,</p> <ul> <li> <p>You may provide a <code>MessageDeduplicationId</code> explicitly.</p> </li> <li> <p>If you aren&#39;t able to provide a <code>MessageDeduplicationId</code> and you enable <code>ContentBasedDeduplication</code> for your queue, Amazon SQS uses a SHA-256 hash to generate the <code>MessageDeduplicationId</code> using the body of the message (but not the attributes of the message). </p> </li> <li> <p>If you don&#39;t provide a <code>MessageDeduplicationId</code> and the queue doesn&#39;t have <code>ContentBasedDeduplication</code> set, the action fails with an error.</p> </li> <li> <p>If the queue has <code>ContentBasedDeduplication</code> set, your <code>MessageDeduplicationId</code> overrides the generated one.</p> </li> </ul> </li> <li> <p>When <code>ContentBasedDeduplication</code> is in effect, messages with identical content sent within the deduplication interval are treated as duplicates and only one copy of the message is delivered.</p> </li> <li> <p>If you send one message with <code>ContentBasedDeduplication</code> enabled and then another message with a <code>MessageDeduplicationId</code> that is the same as the one generated for the first <code>MessageDeduplicationId</code>, the two messages are treated as duplicates and only one copy of the message is delivered. </p> </li> </ul> </li> </ul></p>
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<QueueAttributeName, String>>,
     /// <p>The name of the new queue. The following limits apply to this name:</p> <ul> <li> <p>A queue name can have up to 80 characters.</p> </li> <li> <p>Valid values: alphanumeric characters, hyphens (<code>-</code>), and underscores (<code>_</code>).</p> </li> <li> <p>A FIFO queue name must end with the <code>.fifo</code> suffix.</p> </li> </ul> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_name: String,
     /// <p><p>Add cost allocation tags to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>When you use queue tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a queue isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SQS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> </ul> <p>For a full list of tag restrictions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-limits.html#limits-queues">Limits Related to Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>To be able to tag a queue on creation, you must have the <code>sqs:CreateQueue</code> and <code>sqs:TagQueue</code> permissions.</p> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateQueueRequest {
+    /// Returns a builder for `CreateQueueRequest`, checking its required members when built.
+    pub fn builder() -> CreateQueueRequestBuilder {
+        CreateQueueRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateQueueRequest`](struct.CreateQueueRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateQueueRequestBuilder {
+    attributes: Option<::std::collections::HashMap<QueueAttributeName, String>>,
+    queue_name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateQueueRequestBuilder {
+    /// Sets the [`attributes`](struct.CreateQueueRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<QueueAttributeName, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_name`](struct.CreateQueueRequest.html#structfield.queue_name) member.
+    pub fn queue_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateQueueRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateQueueRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateQueueRequest, ::rusoto_core::BuildError> {
+        Ok(CreateQueueRequest {
+            attributes: self.attributes,
+            queue_name: self
+                .queue_name
+                .ok_or(::rusoto_core::BuildError::missing("queue_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateQueueRequest` contents to a `SignedRequest`.
 struct CreateQueueRequestSerializer;
 impl CreateQueueRequestSerializer {
@@ -474,10 +684,10 @@ pub struct CreateQueueResult {
     pub queue_url: Option<String>,
 }
 
-struct CreateQueueResultDeserializer;
+pub(crate) struct CreateQueueResultDeserializer;
 impl CreateQueueResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateQueueResult, XmlParseError> {
@@ -501,6 +711,48 @@ pub struct DeleteMessageBatchRequest {
     pub queue_url: String,
 }
 
+impl DeleteMessageBatchRequest {
+    /// Returns a builder for `DeleteMessageBatchRequest`, checking its required members when built.
+    pub fn builder() -> DeleteMessageBatchRequestBuilder {
+        DeleteMessageBatchRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMessageBatchRequest`](struct.DeleteMessageBatchRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteMessageBatchRequestBuilder {
+    entries: Option<Vec<DeleteMessageBatchRequestEntry>>,
+    queue_url: Option<String>,
+}
+
+impl DeleteMessageBatchRequestBuilder {
+    /// Sets the [`entries`](struct.DeleteMessageBatchRequest.html#structfield.entries) member.
+    pub fn entries<T: Into<Vec<DeleteMessageBatchRequestEntry>>>(mut self, value: T) -> Self {
+        self.entries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_url`](struct.DeleteMessageBatchRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMessageBatchRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteMessageBatchRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteMessageBatchRequest {
+            entries: self
+                .entries
+                .ok_or(::rusoto_core::BuildError::missing("entries"))?,
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteMessageBatchRequest` contents to a `SignedRequest`.
 struct DeleteMessageBatchRequestSerializer;
 impl DeleteMessageBatchRequestSerializer {
@@ -565,10 +817,10 @@ pub struct DeleteMessageBatchResult {
     pub successful: Vec<DeleteMessageBatchResultEntry>,
 }
 
-struct DeleteMessageBatchResultDeserializer;
+pub(crate) struct DeleteMessageBatchResultDeserializer;
 impl DeleteMessageBatchResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteMessageBatchResult, XmlParseError> {
@@ -606,10 +858,10 @@ pub struct DeleteMessageBatchResultEntry {
     pub id: String,
 }
 
-struct DeleteMessageBatchResultEntryDeserializer;
+pub(crate) struct DeleteMessageBatchResultEntryDeserializer;
 impl DeleteMessageBatchResultEntryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteMessageBatchResultEntry, XmlParseError> {
@@ -628,10 +880,10 @@ impl DeleteMessageBatchResultEntryDeserializer {
         )
     }
 }
-struct DeleteMessageBatchResultEntryListDeserializer;
+pub(crate) struct DeleteMessageBatchResultEntryListDeserializer;
 impl DeleteMessageBatchResultEntryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DeleteMessageBatchResultEntry>, XmlParseError> {
@@ -666,6 +918,46 @@ pub struct DeleteMessageRequest {
     pub receipt_handle: String,
 }
 
+impl DeleteMessageRequest {
+    /// Returns a builder for `DeleteMessageRequest`, checking its required members when built.
+    pub fn builder() -> DeleteMessageRequestBuilder {
+        DeleteMessageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMessageRequest`](struct.DeleteMessageRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteMessageRequestBuilder {
+    queue_url: Option<String>,
+    receipt_handle: Option<String>,
+}
+
+impl DeleteMessageRequestBuilder {
+    /// Sets the [`queue_url`](struct.DeleteMessageRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`receipt_handle`](struct.DeleteMessageRequest.html#structfield.receipt_handle) member.
+    pub fn receipt_handle<T: Into<String>>(mut self, value: T) -> Self {
+        self.receipt_handle = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMessageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteMessageRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteMessageRequest {
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+            receipt_handle: self
+                .receipt_handle
+                .ok_or(::rusoto_core::BuildError::missing("receipt_handle"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteMessageRequest` contents to a `SignedRequest`.
 struct DeleteMessageRequestSerializer;
 impl DeleteMessageRequestSerializer {
@@ -690,6 +982,36 @@ pub struct DeleteQueueRequest {
     pub queue_url: String,
 }
 
+impl DeleteQueueRequest {
+    /// Returns a builder for `DeleteQueueRequest`, checking its required members when built.
+    pub fn builder() -> DeleteQueueRequestBuilder {
+        DeleteQueueRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteQueueRequest`](struct.DeleteQueueRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteQueueRequestBuilder {
+    queue_url: Option<String>,
+}
+
+impl DeleteQueueRequestBuilder {
+    /// Sets the [`queue_url`](struct.DeleteQueueRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteQueueRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteQueueRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteQueueRequest {
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteQueueRequest` contents to a `SignedRequest`.
 struct DeleteQueueRequestSerializer;
 impl DeleteQueueRequestSerializer {
@@ -707,11 +1029,51 @@ impl DeleteQueueRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetQueueAttributesRequest {
     /// <p><p>A list of attributes for which to retrieve information.</p> <note> <p>In the future, new attributes might be added. If you write code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.</p> </note> <p>The following attributes are supported:</p> <ul> <li> <p> <code>All</code> - Returns all values. </p> </li> <li> <p> <code>ApproximateNumberOfMessages</code> - Returns the approximate number of messages available for retrieval from the queue.</p> </li> <li> <p> <code>ApproximateNumberOfMessagesDelayed</code> - Returns the approximate number of messages in the queue that are delayed and not available for reading immediately. This can happen when the queue is configured as a delay queue or when a message has been sent with a delay parameter.</p> </li> <li> <p> <code>ApproximateNumberOfMessagesNotVisible</code> - Returns the approximate number of messages that are in flight. Messages are considered to be <i>in flight</i> if they have been sent to a client but have not yet been deleted or have not yet reached the end of their visibility window. </p> </li> <li> <p> <code>CreatedTimestamp</code> - Returns the time when the queue was created in seconds (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a>).</p> </li> <li> <p> <code>DelaySeconds</code> - Returns the default delay on the queue in seconds.</p> </li> <li> <p> <code>LastModifiedTimestamp</code> - Returns the time when the queue was last changed in seconds (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a>).</p> </li> <li> <p> <code>MaximumMessageSize</code> - Returns the limit of how many bytes a message can contain before Amazon SQS rejects it.</p> </li> <li> <p> <code>MessageRetentionPeriod</code> - Returns the length of time, in seconds, for which Amazon SQS retains a message.</p> </li> <li> <p> <code>Policy</code> - Returns the policy of the queue.</p> </li> <li> <p> <code>QueueArn</code> - Returns the Amazon resource name (ARN) of the queue.</p> </li> <li> <p> <code>ReceiveMessageWaitTimeSeconds</code> - Returns the length of time, in seconds, for which the <code>ReceiveMessage</code> action waits for a message to arrive. </p> </li> <li> <p> <code>RedrivePolicy</code> - Returns the string that includes the parameters for dead-letter queue functionality of the source queue. For more information about the redrive policy and dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p> <code>deadLetterTargetArn</code> - The Amazon Resource Name (ARN) of the dead-letter queue to which Amazon SQS moves messages after the value of <code>maxReceiveCount</code> is exceeded.</p> </li> <li> <p> <code>maxReceiveCount</code> - The number of times a message is delivered to the source queue before being moved to the dead-letter queue. When the <code>ReceiveCount</code> for a message exceeds the <code>maxReceiveCount</code> for a queue, Amazon SQS moves the message to the dead-letter-queue.</p> </li> </ul> </li> <li> <p> <code>VisibilityTimeout</code> - Returns the visibility timeout for the queue. For more information about the visibility timeout, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - Returns the ID of an AWS-managed customer master key (CMK) for Amazon SQS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-sse-key-terms">Key Terms</a>. </p> </li> <li> <p> <code>KmsDataKeyReusePeriodSeconds</code> - Returns the length of time, in seconds, for which Amazon SQS can reuse a data key to encrypt or decrypt messages before calling AWS KMS again. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-how-does-the-data-key-reuse-period-work">How Does the Data Key Reuse Period Work?</a>. </p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO (first-in-first-out) queues</a>:</p> <ul> <li> <p> <code>FifoQueue</code> - Returns whether the queue is FIFO. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-understanding-logic">FIFO Queue Logic</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>To determine whether a queue is <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO</a>, you can check whether <code>QueueName</code> ends with the <code>.fifo</code> suffix.</p> </note> </li> <li> <p> <code>ContentBasedDeduplication</code> - Returns whether content-based deduplication is enabled for the queue. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-exactly-once-processing">Exactly-Once Processing</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> </li> </ul></p>
-    pub attribute_names: Option<Vec<String>>,
+    pub attribute_names: Option<Vec<QueueAttributeName>>,
     /// <p>The URL of the Amazon SQS queue whose attribute information is retrieved.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_url: String,
 }
 
+impl GetQueueAttributesRequest {
+    /// Returns a builder for `GetQueueAttributesRequest`, checking its required members when built.
+    pub fn builder() -> GetQueueAttributesRequestBuilder {
+        GetQueueAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetQueueAttributesRequest`](struct.GetQueueAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetQueueAttributesRequestBuilder {
+    attribute_names: Option<Vec<QueueAttributeName>>,
+    queue_url: Option<String>,
+}
+
+impl GetQueueAttributesRequestBuilder {
+    /// Sets the [`attribute_names`](struct.GetQueueAttributesRequest.html#structfield.attribute_names) member.
+    pub fn attribute_names<T: Into<Vec<QueueAttributeName>>>(mut self, value: T) -> Self {
+        self.attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_url`](struct.GetQueueAttributesRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetQueueAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetQueueAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(GetQueueAttributesRequest {
+            attribute_names: self.attribute_names,
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `GetQueueAttributesRequest` contents to a `SignedRequest`.
 struct GetQueueAttributesRequestSerializer;
 impl GetQueueAttributesRequestSerializer {
@@ -736,13 +1098,13 @@ impl GetQueueAttributesRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetQueueAttributesResult {
     /// <p>A map of attributes to their respective values.</p>
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<QueueAttributeName, String>>,
 }
 
-struct GetQueueAttributesResultDeserializer;
+pub(crate) struct GetQueueAttributesResultDeserializer;
 impl GetQueueAttributesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetQueueAttributesResult, XmlParseError> {
@@ -773,6 +1135,44 @@ pub struct GetQueueUrlRequest {
     pub queue_owner_aws_account_id: Option<String>,
 }
 
+impl GetQueueUrlRequest {
+    /// Returns a builder for `GetQueueUrlRequest`, checking its required members when built.
+    pub fn builder() -> GetQueueUrlRequestBuilder {
+        GetQueueUrlRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetQueueUrlRequest`](struct.GetQueueUrlRequest.html).
+#[derive(Default, Debug)]
+pub struct GetQueueUrlRequestBuilder {
+    queue_name: Option<String>,
+    queue_owner_aws_account_id: Option<String>,
+}
+
+impl GetQueueUrlRequestBuilder {
+    /// Sets the [`queue_name`](struct.GetQueueUrlRequest.html#structfield.queue_name) member.
+    pub fn queue_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_owner_aws_account_id`](struct.GetQueueUrlRequest.html#structfield.queue_owner_aws_account_id) member.
+    pub fn queue_owner_aws_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_owner_aws_account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetQueueUrlRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetQueueUrlRequest, ::rusoto_core::BuildError> {
+        Ok(GetQueueUrlRequest {
+            queue_name: self
+                .queue_name
+                .ok_or(::rusoto_core::BuildError::missing("queue_name"))?,
+            queue_owner_aws_account_id: self.queue_owner_aws_account_id,
+        })
+    }
+}
+
 /// Serialize `GetQueueUrlRequest` contents to a `SignedRequest`.
 struct GetQueueUrlRequestSerializer;
 impl GetQueueUrlRequestSerializer {
@@ -799,10 +1199,10 @@ pub struct GetQueueUrlResult {
     pub queue_url: Option<String>,
 }
 
-struct GetQueueUrlResultDeserializer;
+pub(crate) struct GetQueueUrlResultDeserializer;
 impl GetQueueUrlResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetQueueUrlResult, XmlParseError> {
@@ -824,6 +1224,38 @@ pub struct ListDeadLetterSourceQueuesRequest {
     pub queue_url: String,
 }
 
+impl ListDeadLetterSourceQueuesRequest {
+    /// Returns a builder for `ListDeadLetterSourceQueuesRequest`, checking its required members when built.
+    pub fn builder() -> ListDeadLetterSourceQueuesRequestBuilder {
+        ListDeadLetterSourceQueuesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeadLetterSourceQueuesRequest`](struct.ListDeadLetterSourceQueuesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDeadLetterSourceQueuesRequestBuilder {
+    queue_url: Option<String>,
+}
+
+impl ListDeadLetterSourceQueuesRequestBuilder {
+    /// Sets the [`queue_url`](struct.ListDeadLetterSourceQueuesRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeadLetterSourceQueuesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDeadLetterSourceQueuesRequest, ::rusoto_core::BuildError> {
+        Ok(ListDeadLetterSourceQueuesRequest {
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `ListDeadLetterSourceQueuesRequest` contents to a `SignedRequest`.
 struct ListDeadLetterSourceQueuesRequestSerializer;
 impl ListDeadLetterSourceQueuesRequestSerializer {
@@ -844,10 +1276,10 @@ pub struct ListDeadLetterSourceQueuesResult {
     pub queue_urls: Vec<String>,
 }
 
-struct ListDeadLetterSourceQueuesResultDeserializer;
+pub(crate) struct ListDeadLetterSourceQueuesResultDeserializer;
 impl ListDeadLetterSourceQueuesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListDeadLetterSourceQueuesResult, XmlParseError> {
@@ -873,6 +1305,36 @@ pub struct ListQueueTagsRequest {
     pub queue_url: String,
 }
 
+impl ListQueueTagsRequest {
+    /// Returns a builder for `ListQueueTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListQueueTagsRequestBuilder {
+        ListQueueTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListQueueTagsRequest`](struct.ListQueueTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListQueueTagsRequestBuilder {
+    queue_url: Option<String>,
+}
+
+impl ListQueueTagsRequestBuilder {
+    /// Sets the [`queue_url`](struct.ListQueueTagsRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListQueueTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListQueueTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListQueueTagsRequest {
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `ListQueueTagsRequest` contents to a `SignedRequest`.
 struct ListQueueTagsRequestSerializer;
 impl ListQueueTagsRequestSerializer {
@@ -892,10 +1354,10 @@ pub struct ListQueueTagsResult {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
-struct ListQueueTagsResultDeserializer;
+pub(crate) struct ListQueueTagsResultDeserializer;
 impl ListQueueTagsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListQueueTagsResult, XmlParseError> {
@@ -917,6 +1379,34 @@ pub struct ListQueuesRequest {
     pub queue_name_prefix: Option<String>,
 }
 
+impl ListQueuesRequest {
+    /// Returns a builder for `ListQueuesRequest`, checking its required members when built.
+    pub fn builder() -> ListQueuesRequestBuilder {
+        ListQueuesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListQueuesRequest`](struct.ListQueuesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListQueuesRequestBuilder {
+    queue_name_prefix: Option<String>,
+}
+
+impl ListQueuesRequestBuilder {
+    /// Sets the [`queue_name_prefix`](struct.ListQueuesRequest.html#structfield.queue_name_prefix) member.
+    pub fn queue_name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_name_prefix = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListQueuesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListQueuesRequest, ::rusoto_core::BuildError> {
+        Ok(ListQueuesRequest {
+            queue_name_prefix: self.queue_name_prefix,
+        })
+    }
+}
+
 /// Serialize `ListQueuesRequest` contents to a `SignedRequest`.
 struct ListQueuesRequestSerializer;
 impl ListQueuesRequestSerializer {
@@ -939,10 +1429,10 @@ pub struct ListQueuesResult {
     pub queue_urls: Option<Vec<String>>,
 }
 
-struct ListQueuesResultDeserializer;
+pub(crate) struct ListQueuesResultDeserializer;
 impl ListQueuesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListQueuesResult, XmlParseError> {
@@ -963,7 +1453,7 @@ impl ListQueuesResultDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Message {
     /// <p>A map of the attributes requested in <code> <a>ReceiveMessage</a> </code> to their respective values. Supported attributes:</p> <ul> <li> <p> <code>ApproximateReceiveCount</code> </p> </li> <li> <p> <code>ApproximateFirstReceiveTimestamp</code> </p> </li> <li> <p> <code>MessageDeduplicationId</code> </p> </li> <li> <p> <code>MessageGroupId</code> </p> </li> <li> <p> <code>SenderId</code> </p> </li> <li> <p> <code>SentTimestamp</code> </p> </li> <li> <p> <code>SequenceNumber</code> </p> </li> </ul> <p> <code>ApproximateFirstReceiveTimestamp</code> and <code>SentTimestamp</code> are each returned as an integer representing the <a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a> in milliseconds.</p>
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<MessageSystemAttributeName, String>>,
     /// <p>The message's contents (not URL-encoded).</p>
     pub body: Option<String>,
     /// <p>An MD5 digest of the non-URL-encoded message body string.</p>
@@ -978,10 +1468,10 @@ pub struct Message {
     pub receipt_handle: Option<String>,
 }
 
-struct MessageDeserializer;
+pub(crate) struct MessageDeserializer;
 impl MessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Message, XmlParseError> {
@@ -1052,10 +1542,10 @@ pub struct MessageAttributeValue {
     pub string_value: Option<String>,
 }
 
-struct MessageAttributeValueDeserializer;
+pub(crate) struct MessageAttributeValueDeserializer;
 impl MessageAttributeValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MessageAttributeValue, XmlParseError> {
@@ -1123,10 +1613,10 @@ impl MessageAttributeValueSerializer {
     }
 }
 
-struct MessageBodyAttributeMapDeserializer;
+pub(crate) struct MessageBodyAttributeMapDeserializer;
 impl MessageBodyAttributeMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, MessageAttributeValue>, XmlParseError> {
@@ -1170,7 +1660,10 @@ impl MessageBodySystemAttributeMapSerializer {
     fn serialize(
         params: &mut Params,
         name: &str,
-        obj: &::std::collections::HashMap<String, MessageSystemAttributeValue>,
+        obj: &::std::collections::HashMap<
+            MessageSystemAttributeNameForSends,
+            MessageSystemAttributeValue,
+        >,
     ) {
         for (index, (key, value)) in obj.iter().enumerate() {
             let prefix = format!("{}.{}", name, index + 1);
@@ -1184,10 +1677,10 @@ impl MessageBodySystemAttributeMapSerializer {
     }
 }
 
-struct MessageListDeserializer;
+pub(crate) struct MessageListDeserializer;
 impl MessageListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Message>, XmlParseError> {
@@ -1211,13 +1704,14 @@ impl MessageListDeserializer {
         Ok(obj)
     }
 }
-struct MessageSystemAttributeMapDeserializer;
+pub(crate) struct MessageSystemAttributeMapDeserializer;
 impl MessageSystemAttributeMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
+    ) -> Result<::std::collections::HashMap<MessageSystemAttributeName, String>, XmlParseError>
+    {
         let mut obj = ::std::collections::HashMap::new();
 
         while peek_at_name(stack)? == "Attribute" {
@@ -1231,17 +1725,165 @@ impl MessageSystemAttributeMapDeserializer {
         Ok(obj)
     }
 }
-struct MessageSystemAttributeNameDeserializer;
+pub(crate) struct MessageSystemAttributeNameDeserializer;
 impl MessageSystemAttributeNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<MessageSystemAttributeName, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>A map of the attributes requested in <code> <a>ReceiveMessage</a> </code> to their respective values. Supported attributes:</p> <ul> <li> <p> <code>ApproximateReceiveCount</code> </p> </li> <li> <p> <code>ApproximateFirstReceiveTimestamp</code> </p> </li> <li> <p> <code>MessageDeduplicationId</code> </p> </li> <li> <p> <code>MessageGroupId</code> </p> </li> <li> <p> <code>SenderId</code> </p> </li> <li> <p> <code>SentTimestamp</code> </p> </li> <li> <p> <code>SequenceNumber</code> </p> </li> </ul> <p> <code>ApproximateFirstReceiveTimestamp</code> and <code>SentTimestamp</code> are each returned as an integer representing the <a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a> in milliseconds.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MessageSystemAttributeName {
+    /// <p><code>SenderId</code></p>
+    SenderId,
+    /// <p><code>SentTimestamp</code></p>
+    SentTimestamp,
+    /// <p><code>ApproximateReceiveCount</code></p>
+    ApproximateReceiveCount,
+    /// <p><code>ApproximateFirstReceiveTimestamp</code></p>
+    ApproximateFirstReceiveTimestamp,
+    /// <p><code>SequenceNumber</code></p>
+    SequenceNumber,
+    /// <p><code>MessageDeduplicationId</code></p>
+    MessageDeduplicationId,
+    /// <p><code>MessageGroupId</code></p>
+    MessageGroupId,
+    /// <p><code>AWSTraceHeader</code></p>
+    AWSTraceHeader,
+    /// <p><code>DeadLetterQueueSourceArn</code></p>
+    DeadLetterQueueSourceArn,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MessageSystemAttributeName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageSystemAttributeName::SenderId => "SenderId",
+            MessageSystemAttributeName::SentTimestamp => "SentTimestamp",
+            MessageSystemAttributeName::ApproximateReceiveCount => "ApproximateReceiveCount",
+            MessageSystemAttributeName::ApproximateFirstReceiveTimestamp => {
+                "ApproximateFirstReceiveTimestamp"
+            }
+            MessageSystemAttributeName::SequenceNumber => "SequenceNumber",
+            MessageSystemAttributeName::MessageDeduplicationId => "MessageDeduplicationId",
+            MessageSystemAttributeName::MessageGroupId => "MessageGroupId",
+            MessageSystemAttributeName::AWSTraceHeader => "AWSTraceHeader",
+            MessageSystemAttributeName::DeadLetterQueueSourceArn => "DeadLetterQueueSourceArn",
+            MessageSystemAttributeName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MessageSystemAttributeName {
+    fn from(value: &'a str) -> MessageSystemAttributeName {
+        match value {
+            "SenderId" => MessageSystemAttributeName::SenderId,
+            "SentTimestamp" => MessageSystemAttributeName::SentTimestamp,
+            "ApproximateReceiveCount" => MessageSystemAttributeName::ApproximateReceiveCount,
+            "ApproximateFirstReceiveTimestamp" => {
+                MessageSystemAttributeName::ApproximateFirstReceiveTimestamp
+            }
+            "SequenceNumber" => MessageSystemAttributeName::SequenceNumber,
+            "MessageDeduplicationId" => MessageSystemAttributeName::MessageDeduplicationId,
+            "MessageGroupId" => MessageSystemAttributeName::MessageGroupId,
+            "AWSTraceHeader" => MessageSystemAttributeName::AWSTraceHeader,
+            "DeadLetterQueueSourceArn" => MessageSystemAttributeName::DeadLetterQueueSourceArn,
+            _ => MessageSystemAttributeName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageSystemAttributeName {
+    fn from(value: String) -> MessageSystemAttributeName {
+        MessageSystemAttributeName::from(value.as_str())
+    }
+}
+
+impl From<MessageSystemAttributeName> for String {
+    fn from(value: MessageSystemAttributeName) -> String {
+        match value {
+            MessageSystemAttributeName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MessageSystemAttributeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageSystemAttributeName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MessageSystemAttributeNameForSends {
+    /// <p><code>AWSTraceHeader</code></p>
+    AWSTraceHeader,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MessageSystemAttributeNameForSends {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageSystemAttributeNameForSends::AWSTraceHeader => "AWSTraceHeader",
+            MessageSystemAttributeNameForSends::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MessageSystemAttributeNameForSends {
+    fn from(value: &'a str) -> MessageSystemAttributeNameForSends {
+        match value {
+            "AWSTraceHeader" => MessageSystemAttributeNameForSends::AWSTraceHeader,
+            _ => MessageSystemAttributeNameForSends::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageSystemAttributeNameForSends {
+    fn from(value: String) -> MessageSystemAttributeNameForSends {
+        MessageSystemAttributeNameForSends::from(value.as_str())
+    }
+}
+
+impl From<MessageSystemAttributeNameForSends> for String {
+    fn from(value: MessageSystemAttributeNameForSends) -> String {
+        match value {
+            MessageSystemAttributeNameForSends::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MessageSystemAttributeNameForSends {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageSystemAttributeNameForSends {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>The user-specified message system attribute value. For string data types, the <code>Value</code> attribute has the same restrictions on the content as the message body. For more information, see <code> <a>SendMessage</a>.</code> </p> <p> <code>Name</code>, <code>type</code>, <code>value</code> and the message body must not be empty or null.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct MessageSystemAttributeValue {
@@ -1300,6 +1942,36 @@ pub struct PurgeQueueRequest {
     pub queue_url: String,
 }
 
+impl PurgeQueueRequest {
+    /// Returns a builder for `PurgeQueueRequest`, checking its required members when built.
+    pub fn builder() -> PurgeQueueRequestBuilder {
+        PurgeQueueRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PurgeQueueRequest`](struct.PurgeQueueRequest.html).
+#[derive(Default, Debug)]
+pub struct PurgeQueueRequestBuilder {
+    queue_url: Option<String>,
+}
+
+impl PurgeQueueRequestBuilder {
+    /// Sets the [`queue_url`](struct.PurgeQueueRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `PurgeQueueRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PurgeQueueRequest, ::rusoto_core::BuildError> {
+        Ok(PurgeQueueRequest {
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `PurgeQueueRequest` contents to a `SignedRequest`.
 struct PurgeQueueRequestSerializer;
 impl PurgeQueueRequestSerializer {
@@ -1313,13 +1985,13 @@ impl PurgeQueueRequestSerializer {
     }
 }
 
-struct QueueAttributeMapDeserializer;
+pub(crate) struct QueueAttributeMapDeserializer;
 impl QueueAttributeMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
+    ) -> Result<::std::collections::HashMap<QueueAttributeName, String>, XmlParseError> {
         let mut obj = ::std::collections::HashMap::new();
 
         while peek_at_name(stack)? == "Attribute" {
@@ -1340,7 +2012,7 @@ impl QueueAttributeMapSerializer {
     fn serialize(
         params: &mut Params,
         name: &str,
-        obj: &::std::collections::HashMap<String, String>,
+        obj: &::std::collections::HashMap<QueueAttributeName, String>,
     ) {
         for (index, (key, value)) in obj.iter().enumerate() {
             let prefix = format!("{}.{}", name, index + 1);
@@ -1350,21 +2022,171 @@ impl QueueAttributeMapSerializer {
     }
 }
 
-struct QueueAttributeNameDeserializer;
+pub(crate) struct QueueAttributeNameDeserializer;
 impl QueueAttributeNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<QueueAttributeName, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct QueueUrlListDeserializer;
+/// <p><p>A map of attributes with their corresponding values.</p> <p>The following lists the names, descriptions, and values of the special request parameters that the <code>CreateQueue</code> action uses:</p> <ul> <li> <p> <code>DelaySeconds</code> - The length of time, in seconds, for which the delivery of all messages in the queue is delayed. Valid values: An integer from 0 to 900 seconds (15 minutes). Default: 0. </p> </li> <li> <p> <code>MaximumMessageSize</code> - The limit of how many bytes a message can contain before Amazon SQS rejects it. Valid values: An integer from 1,024 bytes (1 KiB) to 262,144 bytes (256 KiB). Default: 262,144 (256 KiB). </p> </li> <li> <p> <code>MessageRetentionPeriod</code> - The length of time, in seconds, for which Amazon SQS retains a message. Valid values: An integer from 60 seconds (1 minute) to 1,209,600 seconds (14 days). Default: 345,600 (4 days). </p> </li> <li> <p> <code>Policy</code> - The queue&#39;s policy. A valid AWS policy. For more information about policy structure, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/PoliciesOverview.html">Overview of AWS IAM Policies</a> in the <i>Amazon IAM User Guide</i>. </p> </li> <li> <p> <code>ReceiveMessageWaitTimeSeconds</code> - The length of time, in seconds, for which a <code> <a>ReceiveMessage</a> </code> action waits for a message to arrive. Valid values: An integer from 0 to 20 (seconds). Default: 0. </p> </li> <li> <p> <code>RedrivePolicy</code> - The string that includes the parameters for the dead-letter queue functionality of the source queue. For more information about the redrive policy and dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p> <code>deadLetterTargetArn</code> - The Amazon Resource Name (ARN) of the dead-letter queue to which Amazon SQS moves messages after the value of <code>maxReceiveCount</code> is exceeded.</p> </li> <li> <p> <code>maxReceiveCount</code> - The number of times a message is delivered to the source queue before being moved to the dead-letter queue. When the <code>ReceiveCount</code> for a message exceeds the <code>maxReceiveCount</code> for a queue, Amazon SQS moves the message to the dead-letter-queue.</p> </li> </ul> <note> <p>The dead-letter queue of a FIFO queue must also be a FIFO queue. Similarly, the dead-letter queue of a standard queue must also be a standard queue.</p> </note> </li> <li> <p> <code>VisibilityTimeout</code> - The visibility timeout for the queue, in seconds. Valid values: An integer from 0 to 43,200 (12 hours). Default: 30. For more information about the visibility timeout, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - The ID of an AWS-managed customer master key (CMK) for Amazon SQS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-sse-key-terms">Key Terms</a>. While the alias of the AWS-managed CMK for Amazon SQS is always <code>alias/aws/sqs</code>, the alias of a custom CMK can, for example, be <code>alias/<i>MyAlias</i> </code>. For more examples, see <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_DescribeKey.html#API_DescribeKey_RequestParameters">KeyId</a> in the <i>AWS Key Management Service API Reference</i>. </p> </li> <li> <p> <code>KmsDataKeyReusePeriodSeconds</code> - The length of time, in seconds, for which Amazon SQS can reuse a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data key</a> to encrypt or decrypt messages before calling AWS KMS again. An integer representing seconds, between 60 seconds (1 minute) and 86,400 seconds (24 hours). Default: 300 (5 minutes). A shorter time period provides better security but results in more calls to KMS which might incur charges after Free Tier. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-how-does-the-data-key-reuse-period-work">How Does the Data Key Reuse Period Work?</a>. </p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO (first-in-first-out) queues</a>:</p> <ul> <li> <p> <code>FifoQueue</code> - Designates a queue as FIFO. Valid values: <code>true</code>, <code>false</code>. If you don&#39;t specify the <code>FifoQueue</code> attribute, Amazon SQS creates a standard queue. You can provide this attribute only during queue creation. You can&#39;t change it for an existing queue. When you set this attribute, you must also provide the <code>MessageGroupId</code> for your messages explicitly.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-understanding-logic">FIFO Queue Logic</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p> <code>ContentBasedDeduplication</code> - Enables content-based deduplication. Valid values: <code>true</code>, <code>false</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-exactly-once-processing">Exactly-Once Processing</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p>Every message must have a unique <code>MessageDeduplicationId</code>,</p> <ul> <li> <p>You may provide a <code>MessageDeduplicationId</code> explicitly.</p> </li> <li> <p>If you aren&#39;t able to provide a <code>MessageDeduplicationId</code> and you enable <code>ContentBasedDeduplication</code> for your queue, Amazon SQS uses a SHA-256 hash to generate the <code>MessageDeduplicationId</code> using the body of the message (but not the attributes of the message). </p> </li> <li> <p>If you don&#39;t provide a <code>MessageDeduplicationId</code> and the queue doesn&#39;t have <code>ContentBasedDeduplication</code> set, the action fails with an error.</p> </li> <li> <p>If the queue has <code>ContentBasedDeduplication</code> set, your <code>MessageDeduplicationId</code> overrides the generated one.</p> </li> </ul> </li> <li> <p>When <code>ContentBasedDeduplication</code> is in effect, messages with identical content sent within the deduplication interval are treated as duplicates and only one copy of the message is delivered.</p> </li> <li> <p>If you send one message with <code>ContentBasedDeduplication</code> enabled and then another message with a <code>MessageDeduplicationId</code> that is the same as the one generated for the first <code>MessageDeduplicationId</code>, the two messages are treated as duplicates and only one copy of the message is delivered. </p> </li> </ul> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QueueAttributeName {
+    /// <p><code>All</code> - Returns all values.</p>
+    All,
+    /// <p><code>Policy</code> - The queue&#39;s policy. A valid AWS policy. For more information about policy structure, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/PoliciesOverview.html">Overview of AWS IAM Policies</a> in the <i>Amazon IAM User Guide</i>.</p>
+    Policy,
+    /// <p><code>VisibilityTimeout</code> - The visibility timeout for the queue, in seconds. Valid values: An integer from 0 to 43,200 (12 hours). Default: 30. For more information about the visibility timeout, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p>
+    VisibilityTimeout,
+    /// <p><code>MaximumMessageSize</code> - The limit of how many bytes a message can contain before Amazon SQS rejects it. Valid values: An integer from 1,024 bytes (1 KiB) to 262,144 bytes (256 KiB). Default: 262,144 (256 KiB).</p>
+    MaximumMessageSize,
+    /// <p><code>MessageRetentionPeriod</code> - The length of time, in seconds, for which Amazon SQS retains a message. Valid values: An integer from 60 seconds (1 minute) to 1,209,600 seconds (14 days). Default: 345,600 (4 days).</p>
+    MessageRetentionPeriod,
+    /// <p><code>ApproximateNumberOfMessages</code> - Returns the approximate number of messages available for retrieval from the queue.</p>
+    ApproximateNumberOfMessages,
+    /// <p><code>ApproximateNumberOfMessagesNotVisible</code> - Returns the approximate number of messages that are in flight. Messages are considered to be <i>in flight</i> if they have been sent to a client but have not yet been deleted or have not yet reached the end of their visibility window.</p>
+    ApproximateNumberOfMessagesNotVisible,
+    /// <p><code>CreatedTimestamp</code> - Returns the time when the queue was created in seconds (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a>).</p>
+    CreatedTimestamp,
+    /// <p><code>LastModifiedTimestamp</code> - Returns the time when the queue was last changed in seconds (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a>).</p>
+    LastModifiedTimestamp,
+    /// <p><code>QueueArn</code> - Returns the Amazon resource name (ARN) of the queue.</p>
+    QueueArn,
+    /// <p><code>ApproximateNumberOfMessagesDelayed</code> - Returns the approximate number of messages in the queue that are delayed and not available for reading immediately. This can happen when the queue is configured as a delay queue or when a message has been sent with a delay parameter.</p>
+    ApproximateNumberOfMessagesDelayed,
+    /// <p><code>DelaySeconds</code> - The length of time, in seconds, for which the delivery of all messages in the queue is delayed. Valid values: An integer from 0 to 900 seconds (15 minutes). Default: 0.</p>
+    DelaySeconds,
+    /// <p><code>ReceiveMessageWaitTimeSeconds</code> - The length of time, in seconds, for which a <code> <a>ReceiveMessage</a> </code> action waits for a message to arrive. Valid values: An integer from 0 to 20 (seconds). Default: 0.</p>
+    ReceiveMessageWaitTimeSeconds,
+    /// <p><code>RedrivePolicy</code> - The string that includes the parameters for the dead-letter queue functionality of the source queue. For more information about the redrive policy and dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p>
+    RedrivePolicy,
+    /// <p><code>FifoQueue</code> - Designates a queue as FIFO. Valid values: <code>true</code>, <code>false</code>. If you don&#39;t specify the <code>FifoQueue</code> attribute, Amazon SQS creates a standard queue. You can provide this attribute only during queue creation. You can&#39;t change it for an existing queue. When you set this attribute, you must also provide the <code>MessageGroupId</code> for your messages explicitly.</p>
+    FifoQueue,
+    /// <p><code>ContentBasedDeduplication</code> - Enables content-based deduplication. Valid values: <code>true</code>, <code>false</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-exactly-once-processing">Exactly-Once Processing</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p>
+    ContentBasedDeduplication,
+    /// <p><code>KmsMasterKeyId</code> - The ID of an AWS-managed customer master key (CMK) for Amazon SQS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-sse-key-terms">Key Terms</a>. While the alias of the AWS-managed CMK for Amazon SQS is always <code>alias/aws/sqs</code>, the alias of a custom CMK can, for example, be <code>alias/<i>MyAlias</i> </code>. For more examples, see <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_DescribeKey.html#API_DescribeKey_RequestParameters">KeyId</a> in the <i>AWS Key Management Service API Reference</i>.</p>
+    KmsMasterKeyId,
+    /// <p><code>KmsDataKeyReusePeriodSeconds</code> - The length of time, in seconds, for which Amazon SQS can reuse a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data key</a> to encrypt or decrypt messages before calling AWS KMS again. An integer representing seconds, between 60 seconds (1 minute) and 86,400 seconds (24 hours). Default: 300 (5 minutes). A shorter time period provides better security but results in more calls to KMS which might incur charges after Free Tier. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-how-does-the-data-key-reuse-period-work">How Does the Data Key Reuse Period Work?</a>.</p>
+    KmsDataKeyReusePeriodSeconds,
+    /// <p><code>DeduplicationScope</code></p>
+    DeduplicationScope,
+    /// <p><code>FifoThroughputLimit</code></p>
+    FifoThroughputLimit,
+    /// <p><code>RedriveAllowPolicy</code></p>
+    RedriveAllowPolicy,
+    /// <p><code>SqsManagedSseEnabled</code></p>
+    SqsManagedSseEnabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl QueueAttributeName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueueAttributeName::All => "All",
+            QueueAttributeName::Policy => "Policy",
+            QueueAttributeName::VisibilityTimeout => "VisibilityTimeout",
+            QueueAttributeName::MaximumMessageSize => "MaximumMessageSize",
+            QueueAttributeName::MessageRetentionPeriod => "MessageRetentionPeriod",
+            QueueAttributeName::ApproximateNumberOfMessages => "ApproximateNumberOfMessages",
+            QueueAttributeName::ApproximateNumberOfMessagesNotVisible => {
+                "ApproximateNumberOfMessagesNotVisible"
+            }
+            QueueAttributeName::CreatedTimestamp => "CreatedTimestamp",
+            QueueAttributeName::LastModifiedTimestamp => "LastModifiedTimestamp",
+            QueueAttributeName::QueueArn => "QueueArn",
+            QueueAttributeName::ApproximateNumberOfMessagesDelayed => {
+                "ApproximateNumberOfMessagesDelayed"
+            }
+            QueueAttributeName::DelaySeconds => "DelaySeconds",
+            QueueAttributeName::ReceiveMessageWaitTimeSeconds => "ReceiveMessageWaitTimeSeconds",
+            QueueAttributeName::RedrivePolicy => "RedrivePolicy",
+            QueueAttributeName::FifoQueue => "FifoQueue",
+            QueueAttributeName::ContentBasedDeduplication => "ContentBasedDeduplication",
+            QueueAttributeName::KmsMasterKeyId => "KmsMasterKeyId",
+            QueueAttributeName::KmsDataKeyReusePeriodSeconds => "KmsDataKeyReusePeriodSeconds",
+            QueueAttributeName::DeduplicationScope => "DeduplicationScope",
+            QueueAttributeName::FifoThroughputLimit => "FifoThroughputLimit",
+            QueueAttributeName::RedriveAllowPolicy => "RedriveAllowPolicy",
+            QueueAttributeName::SqsManagedSseEnabled => "SqsManagedSseEnabled",
+            QueueAttributeName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for QueueAttributeName {
+    fn from(value: &'a str) -> QueueAttributeName {
+        match value {
+            "All" => QueueAttributeName::All,
+            "Policy" => QueueAttributeName::Policy,
+            "VisibilityTimeout" => QueueAttributeName::VisibilityTimeout,
+            "MaximumMessageSize" => QueueAttributeName::MaximumMessageSize,
+            "MessageRetentionPeriod" => QueueAttributeName::MessageRetentionPeriod,
+            "ApproximateNumberOfMessages" => QueueAttributeName::ApproximateNumberOfMessages,
+            "ApproximateNumberOfMessagesNotVisible" => {
+                QueueAttributeName::ApproximateNumberOfMessagesNotVisible
+            }
+            "CreatedTimestamp" => QueueAttributeName::CreatedTimestamp,
+            "LastModifiedTimestamp" => QueueAttributeName::LastModifiedTimestamp,
+            "QueueArn" => QueueAttributeName::QueueArn,
+            "ApproximateNumberOfMessagesDelayed" => {
+                QueueAttributeName::ApproximateNumberOfMessagesDelayed
+            }
+            "DelaySeconds" => QueueAttributeName::DelaySeconds,
+            "ReceiveMessageWaitTimeSeconds" => QueueAttributeName::ReceiveMessageWaitTimeSeconds,
+            "RedrivePolicy" => QueueAttributeName::RedrivePolicy,
+            "FifoQueue" => QueueAttributeName::FifoQueue,
+            "ContentBasedDeduplication" => QueueAttributeName::ContentBasedDeduplication,
+            "KmsMasterKeyId" => QueueAttributeName::KmsMasterKeyId,
+            "KmsDataKeyReusePeriodSeconds" => QueueAttributeName::KmsDataKeyReusePeriodSeconds,
+            "DeduplicationScope" => QueueAttributeName::DeduplicationScope,
+            "FifoThroughputLimit" => QueueAttributeName::FifoThroughputLimit,
+            "RedriveAllowPolicy" => QueueAttributeName::RedriveAllowPolicy,
+            "SqsManagedSseEnabled" => QueueAttributeName::SqsManagedSseEnabled,
+            _ => QueueAttributeName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueueAttributeName {
+    fn from(value: String) -> QueueAttributeName {
+        QueueAttributeName::from(value.as_str())
+    }
+}
+
+impl From<QueueAttributeName> for String {
+    fn from(value: QueueAttributeName) -> String {
+        match value {
+            QueueAttributeName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QueueAttributeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueueAttributeName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct QueueUrlListDeserializer;
 impl QueueUrlListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -1392,7 +2214,7 @@ impl QueueUrlListDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ReceiveMessageRequest {
     /// <p><p>A list of attributes that need to be returned along with each message. These attributes include:</p> <ul> <li> <p> <code>All</code> - Returns all values.</p> </li> <li> <p> <code>ApproximateFirstReceiveTimestamp</code> - Returns the time the message was first received from the queue (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a> in milliseconds).</p> </li> <li> <p> <code>ApproximateReceiveCount</code> - Returns the number of times a message has been received from the queue but not deleted.</p> </li> <li> <p> <code>AWSTraceHeader</code> - Returns the AWS X-Ray trace header string. </p> </li> <li> <p> <code>SenderId</code> </p> <ul> <li> <p>For an IAM user, returns the IAM user ID, for example <code>ABCDEFGHI1JKLMNOPQ23R</code>.</p> </li> <li> <p>For an IAM role, returns the IAM role ID, for example <code>ABCDE1F2GH3I4JK5LMNOP:i-a123b456</code>.</p> </li> </ul> </li> <li> <p> <code>SentTimestamp</code> - Returns the time the message was sent to the queue (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a> in milliseconds).</p> </li> <li> <p> <code>MessageDeduplicationId</code> - Returns the value provided by the producer that calls the <code> <a>SendMessage</a> </code> action.</p> </li> <li> <p> <code>MessageGroupId</code> - Returns the value provided by the producer that calls the <code> <a>SendMessage</a> </code> action. Messages with the same <code>MessageGroupId</code> are returned in sequence.</p> </li> <li> <p> <code>SequenceNumber</code> - Returns the value provided by Amazon SQS.</p> </li> </ul></p>
-    pub attribute_names: Option<Vec<String>>,
+    pub attribute_names: Option<Vec<QueueAttributeName>>,
     /// <p>The maximum number of messages to return. Amazon SQS never returns more messages than this value (however, fewer messages might be returned). Valid values: 1 to 10. Default: 1.</p>
     pub max_number_of_messages: Option<i64>,
     /// <p>The name of the message attribute, where <i>N</i> is the index.</p> <ul> <li> <p>The name can contain alphanumeric characters and the underscore (<code>_</code>), hyphen (<code>-</code>), and period (<code>.</code>).</p> </li> <li> <p>The name is case-sensitive and must be unique among all attribute names for the message.</p> </li> <li> <p>The name must not start with AWS-reserved prefixes such as <code>AWS.</code> or <code>Amazon.</code> (or any casing variants).</p> </li> <li> <p>The name must not start or end with a period (<code>.</code>), and it should not have periods in succession (<code>..</code>).</p> </li> <li> <p>The name can be up to 256 characters long.</p> </li> </ul> <p>When using <code>ReceiveMessage</code>, you can send a list of attribute names to receive, or you can return all of the attributes by specifying <code>All</code> or <code>.*</code> in your request. You can also use all message attributes starting with a prefix, for example <code>bar.*</code>.</p>
@@ -1407,6 +2229,84 @@ pub struct ReceiveMessageRequest {
     pub wait_time_seconds: Option<i64>,
 }
 
+impl ReceiveMessageRequest {
+    /// Returns a builder for `ReceiveMessageRequest`, checking its required members when built.
+    pub fn builder() -> ReceiveMessageRequestBuilder {
+        ReceiveMessageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ReceiveMessageRequest`](struct.ReceiveMessageRequest.html).
+#[derive(Default, Debug)]
+pub struct ReceiveMessageRequestBuilder {
+    attribute_names: Option<Vec<QueueAttributeName>>,
+    max_number_of_messages: Option<i64>,
+    message_attribute_names: Option<Vec<String>>,
+    queue_url: Option<String>,
+    receive_request_attempt_id: Option<String>,
+    visibility_timeout: Option<i64>,
+    wait_time_seconds: Option<i64>,
+}
+
+impl ReceiveMessageRequestBuilder {
+    /// Sets the [`attribute_names`](struct.ReceiveMessageRequest.html#structfield.attribute_names) member.
+    pub fn attribute_names<T: Into<Vec<QueueAttributeName>>>(mut self, value: T) -> Self {
+        self.attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_number_of_messages`](struct.ReceiveMessageRequest.html#structfield.max_number_of_messages) member.
+    pub fn max_number_of_messages<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_number_of_messages = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message_attribute_names`](struct.ReceiveMessageRequest.html#structfield.message_attribute_names) member.
+    pub fn message_attribute_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.message_attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_url`](struct.ReceiveMessageRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`receive_request_attempt_id`](struct.ReceiveMessageRequest.html#structfield.receive_request_attempt_id) member.
+    pub fn receive_request_attempt_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.receive_request_attempt_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`visibility_timeout`](struct.ReceiveMessageRequest.html#structfield.visibility_timeout) member.
+    pub fn visibility_timeout<T: Into<i64>>(mut self, value: T) -> Self {
+        self.visibility_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`wait_time_seconds`](struct.ReceiveMessageRequest.html#structfield.wait_time_seconds) member.
+    pub fn wait_time_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.wait_time_seconds = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReceiveMessageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ReceiveMessageRequest, ::rusoto_core::BuildError> {
+        Ok(ReceiveMessageRequest {
+            attribute_names: self.attribute_names,
+            max_number_of_messages: self.max_number_of_messages,
+            message_attribute_names: self.message_attribute_names,
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+            receive_request_attempt_id: self.receive_request_attempt_id,
+            visibility_timeout: self.visibility_timeout,
+            wait_time_seconds: self.wait_time_seconds,
+        })
+    }
+}
+
 /// Serialize `ReceiveMessageRequest` contents to a `SignedRequest`.
 struct ReceiveMessageRequestSerializer;
 impl ReceiveMessageRequestSerializer {
@@ -1459,10 +2359,10 @@ pub struct ReceiveMessageResult {
     pub messages: Option<Vec<Message>>,
 }
 
-struct ReceiveMessageResultDeserializer;
+pub(crate) struct ReceiveMessageResultDeserializer;
 impl ReceiveMessageResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReceiveMessageResult, XmlParseError> {
@@ -1488,6 +2388,48 @@ pub struct RemovePermissionRequest {
     pub queue_url: String,
 }
 
+impl RemovePermissionRequest {
+    /// Returns a builder for `RemovePermissionRequest`, checking its required members when built.
+    pub fn builder() -> RemovePermissionRequestBuilder {
+        RemovePermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemovePermissionRequest`](struct.RemovePermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct RemovePermissionRequestBuilder {
+    label: Option<String>,
+    queue_url: Option<String>,
+}
+
+impl RemovePermissionRequestBuilder {
+    /// Sets the [`label`](struct.RemovePermissionRequest.html#structfield.label) member.
+    pub fn label<T: Into<String>>(mut self, value: T) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_url`](struct.RemovePermissionRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemovePermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemovePermissionRequest, ::rusoto_core::BuildError> {
+        Ok(RemovePermissionRequest {
+            label: self
+                .label
+                .ok_or(::rusoto_core::BuildError::missing("label"))?,
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `RemovePermissionRequest` contents to a `SignedRequest`.
 struct RemovePermissionRequestSerializer;
 impl RemovePermissionRequestSerializer {
@@ -1511,6 +2453,48 @@ pub struct SendMessageBatchRequest {
     pub queue_url: String,
 }
 
+impl SendMessageBatchRequest {
+    /// Returns a builder for `SendMessageBatchRequest`, checking its required members when built.
+    pub fn builder() -> SendMessageBatchRequestBuilder {
+        SendMessageBatchRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendMessageBatchRequest`](struct.SendMessageBatchRequest.html).
+#[derive(Default, Debug)]
+pub struct SendMessageBatchRequestBuilder {
+    entries: Option<Vec<SendMessageBatchRequestEntry>>,
+    queue_url: Option<String>,
+}
+
+impl SendMessageBatchRequestBuilder {
+    /// Sets the [`entries`](struct.SendMessageBatchRequest.html#structfield.entries) member.
+    pub fn entries<T: Into<Vec<SendMessageBatchRequestEntry>>>(mut self, value: T) -> Self {
+        self.entries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_url`](struct.SendMessageBatchRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendMessageBatchRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SendMessageBatchRequest, ::rusoto_core::BuildError> {
+        Ok(SendMessageBatchRequest {
+            entries: self
+                .entries
+                .ok_or(::rusoto_core::BuildError::missing("entries"))?,
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `SendMessageBatchRequest` contents to a `SignedRequest`.
 struct SendMessageBatchRequestSerializer;
 impl SendMessageBatchRequestSerializer {
@@ -1545,8 +2529,12 @@ pub struct SendMessageBatchRequestEntry {
     /// <p><p>This parameter applies only to FIFO (first-in-first-out) queues.</p> <p>The tag that specifies that a message belongs to a specific message group. Messages that belong to the same message group are processed in a FIFO manner (however, messages in different message groups might be processed out of order). To interleave multiple ordered streams within a single queue, use <code>MessageGroupId</code> values (for example, session data for multiple users). In this scenario, multiple consumers can process the queue, but the session data of each user is processed in a FIFO fashion.</p> <ul> <li> <p>You must associate a non-empty <code>MessageGroupId</code> with a message. If you don&#39;t provide a <code>MessageGroupId</code>, the action fails.</p> </li> <li> <p> <code>ReceiveMessage</code> might return messages with multiple <code>MessageGroupId</code> values. For each <code>MessageGroupId</code>, the messages are sorted by time sent. The caller can&#39;t specify a <code>MessageGroupId</code>.</p> </li> </ul> <p>The length of <code>MessageGroupId</code> is 128 characters. Valid values: alphanumeric characters and punctuation <code>(!&quot;#$%&amp;&#39;()*+,-./:;&lt;=&gt;?@[]^_`{|}~)</code>.</p> <p>For best practices of using <code>MessageGroupId</code>, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/using-messagegroupid-property.html">Using the MessageGroupId Property</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <important> <p> <code>MessageGroupId</code> is required for FIFO queues. You can&#39;t use it for Standard queues.</p> </important></p>
     pub message_group_id: Option<String>,
     /// <p><p>The message system attribute to send Each message system attribute consists of a <code>Name</code>, <code>Type</code>, and <code>Value</code>.</p> <important> <ul> <li> <p>Currently, the only supported message system attribute is <code>AWSTraceHeader</code>. Its type must be <code>String</code> and its value must be a correctly formatted AWS X-Ray trace string.</p> </li> <li> <p>The size of a message system attribute doesn&#39;t count towards the total size of a message.</p> </li> </ul> </important></p>
-    pub message_system_attributes:
-        Option<::std::collections::HashMap<String, MessageSystemAttributeValue>>,
+    pub message_system_attributes: Option<
+        ::std::collections::HashMap<
+            MessageSystemAttributeNameForSends,
+            MessageSystemAttributeValue,
+        >,
+    >,
 }
 
 /// Serialize `SendMessageBatchRequestEntry` contents to a `SignedRequest`.
@@ -1609,10 +2597,10 @@ pub struct SendMessageBatchResult {
     pub successful: Vec<SendMessageBatchResultEntry>,
 }
 
-struct SendMessageBatchResultDeserializer;
+pub(crate) struct SendMessageBatchResultDeserializer;
 impl SendMessageBatchResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SendMessageBatchResult, XmlParseError> {
@@ -1656,10 +2644,10 @@ pub struct SendMessageBatchResultEntry {
     pub sequence_number: Option<String>,
 }
 
-struct SendMessageBatchResultEntryDeserializer;
+pub(crate) struct SendMessageBatchResultEntryDeserializer;
 impl SendMessageBatchResultEntryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SendMessageBatchResultEntry, XmlParseError> {
@@ -1700,10 +2688,10 @@ impl SendMessageBatchResultEntryDeserializer {
         )
     }
 }
-struct SendMessageBatchResultEntryListDeserializer;
+pub(crate) struct SendMessageBatchResultEntryListDeserializer;
 impl SendMessageBatchResultEntryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SendMessageBatchResultEntry>, XmlParseError> {
@@ -1743,12 +2731,116 @@ pub struct SendMessageRequest {
     /// <p><p>This parameter applies only to FIFO (first-in-first-out) queues.</p> <p>The tag that specifies that a message belongs to a specific message group. Messages that belong to the same message group are processed in a FIFO manner (however, messages in different message groups might be processed out of order). To interleave multiple ordered streams within a single queue, use <code>MessageGroupId</code> values (for example, session data for multiple users). In this scenario, multiple consumers can process the queue, but the session data of each user is processed in a FIFO fashion.</p> <ul> <li> <p>You must associate a non-empty <code>MessageGroupId</code> with a message. If you don&#39;t provide a <code>MessageGroupId</code>, the action fails.</p> </li> <li> <p> <code>ReceiveMessage</code> might return messages with multiple <code>MessageGroupId</code> values. For each <code>MessageGroupId</code>, the messages are sorted by time sent. The caller can&#39;t specify a <code>MessageGroupId</code>.</p> </li> </ul> <p>The length of <code>MessageGroupId</code> is 128 characters. Valid values: alphanumeric characters and punctuation <code>(!&quot;#$%&amp;&#39;()*+,-./:;&lt;=&gt;?@[]^_`{|}~)</code>.</p> <p>For best practices of using <code>MessageGroupId</code>, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/using-messagegroupid-property.html">Using the MessageGroupId Property</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <important> <p> <code>MessageGroupId</code> is required for FIFO queues. You can&#39;t use it for Standard queues.</p> </important></p>
     pub message_group_id: Option<String>,
     /// <p><p>The message system attribute to send. Each message system attribute consists of a <code>Name</code>, <code>Type</code>, and <code>Value</code>.</p> <important> <ul> <li> <p>Currently, the only supported message system attribute is <code>AWSTraceHeader</code>. Its type must be <code>String</code> and its value must be a correctly formatted AWS X-Ray trace string.</p> </li> <li> <p>The size of a message system attribute doesn&#39;t count towards the total size of a message.</p> </li> </ul> </important></p>
-    pub message_system_attributes:
-        Option<::std::collections::HashMap<String, MessageSystemAttributeValue>>,
+    pub message_system_attributes: Option<
+        ::std::collections::HashMap<
+            MessageSystemAttributeNameForSends,
+            MessageSystemAttributeValue,
+        >,
+    >,
     /// <p>The URL of the Amazon SQS queue to which a message is sent.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_url: String,
 }
 
+impl SendMessageRequest {
+    /// Returns a builder for `SendMessageRequest`, checking its required members when built.
+    pub fn builder() -> SendMessageRequestBuilder {
+        SendMessageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendMessageRequest`](struct.SendMessageRequest.html).
+#[derive(Default, Debug)]
+pub struct SendMessageRequestBuilder {
+    delay_seconds: Option<i64>,
+    message_attributes: Option<::std::collections::HashMap<String, MessageAttributeValue>>,
+    message_body: Option<String>,
+    message_deduplication_id: Option<String>,
+    message_group_id: Option<String>,
+    message_system_attributes: Option<
+        ::std::collections::HashMap<
+            MessageSystemAttributeNameForSends,
+            MessageSystemAttributeValue,
+        >,
+    >,
+    queue_url: Option<String>,
+}
+
+impl SendMessageRequestBuilder {
+    /// Sets the [`delay_seconds`](struct.SendMessageRequest.html#structfield.delay_seconds) member.
+    pub fn delay_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.delay_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message_attributes`](struct.SendMessageRequest.html#structfield.message_attributes) member.
+    pub fn message_attributes<
+        T: Into<::std::collections::HashMap<String, MessageAttributeValue>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.message_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message_body`](struct.SendMessageRequest.html#structfield.message_body) member.
+    pub fn message_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.message_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message_deduplication_id`](struct.SendMessageRequest.html#structfield.message_deduplication_id) member.
+    pub fn message_deduplication_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.message_deduplication_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message_group_id`](struct.SendMessageRequest.html#structfield.message_group_id) member.
+    pub fn message_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.message_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message_system_attributes`](struct.SendMessageRequest.html#structfield.message_system_attributes) member.
+    pub fn message_system_attributes<
+        T: Into<
+            ::std::collections::HashMap<
+                MessageSystemAttributeNameForSends,
+                MessageSystemAttributeValue,
+            >,
+        >,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.message_system_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_url`](struct.SendMessageRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendMessageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SendMessageRequest, ::rusoto_core::BuildError> {
+        Ok(SendMessageRequest {
+            delay_seconds: self.delay_seconds,
+            message_attributes: self.message_attributes,
+            message_body: self
+                .message_body
+                .ok_or(::rusoto_core::BuildError::missing("message_body"))?,
+            message_deduplication_id: self.message_deduplication_id,
+            message_group_id: self.message_group_id,
+            message_system_attributes: self.message_system_attributes,
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `SendMessageRequest` contents to a `SignedRequest`.
 struct SendMessageRequestSerializer;
 impl SendMessageRequestSerializer {
@@ -1804,10 +2896,10 @@ pub struct SendMessageResult {
     pub sequence_number: Option<String>,
 }
 
-struct SendMessageResultDeserializer;
+pub(crate) struct SendMessageResultDeserializer;
 impl SendMessageResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SendMessageResult, XmlParseError> {
@@ -1846,11 +2938,56 @@ impl SendMessageResultDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetQueueAttributesRequest {
     /// <p><p>A map of attributes to set.</p> <p>The following lists the names, descriptions, and values of the special request parameters that the <code>SetQueueAttributes</code> action uses:</p> <ul> <li> <p> <code>DelaySeconds</code> - The length of time, in seconds, for which the delivery of all messages in the queue is delayed. Valid values: An integer from 0 to 900 (15 minutes). Default: 0. </p> </li> <li> <p> <code>MaximumMessageSize</code> - The limit of how many bytes a message can contain before Amazon SQS rejects it. Valid values: An integer from 1,024 bytes (1 KiB) up to 262,144 bytes (256 KiB). Default: 262,144 (256 KiB). </p> </li> <li> <p> <code>MessageRetentionPeriod</code> - The length of time, in seconds, for which Amazon SQS retains a message. Valid values: An integer representing seconds, from 60 (1 minute) to 1,209,600 (14 days). Default: 345,600 (4 days). </p> </li> <li> <p> <code>Policy</code> - The queue&#39;s policy. A valid AWS policy. For more information about policy structure, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/PoliciesOverview.html">Overview of AWS IAM Policies</a> in the <i>Amazon IAM User Guide</i>. </p> </li> <li> <p> <code>ReceiveMessageWaitTimeSeconds</code> - The length of time, in seconds, for which a <code> <a>ReceiveMessage</a> </code> action waits for a message to arrive. Valid values: an integer from 0 to 20 (seconds). Default: 0. </p> </li> <li> <p> <code>RedrivePolicy</code> - The string that includes the parameters for the dead-letter queue functionality of the source queue. For more information about the redrive policy and dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p> <code>deadLetterTargetArn</code> - The Amazon Resource Name (ARN) of the dead-letter queue to which Amazon SQS moves messages after the value of <code>maxReceiveCount</code> is exceeded.</p> </li> <li> <p> <code>maxReceiveCount</code> - The number of times a message is delivered to the source queue before being moved to the dead-letter queue. When the <code>ReceiveCount</code> for a message exceeds the <code>maxReceiveCount</code> for a queue, Amazon SQS moves the message to the dead-letter-queue.</p> </li> </ul> <note> <p>The dead-letter queue of a FIFO queue must also be a FIFO queue. Similarly, the dead-letter queue of a standard queue must also be a standard queue.</p> </note> </li> <li> <p> <code>VisibilityTimeout</code> - The visibility timeout for the queue, in seconds. Valid values: an integer from 0 to 43,200 (12 hours). Default: 30. For more information about the visibility timeout, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - The ID of an AWS-managed customer master key (CMK) for Amazon SQS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-sse-key-terms">Key Terms</a>. While the alias of the AWS-managed CMK for Amazon SQS is always <code>alias/aws/sqs</code>, the alias of a custom CMK can, for example, be <code>alias/<i>MyAlias</i> </code>. For more examples, see <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_DescribeKey.html#API_DescribeKey_RequestParameters">KeyId</a> in the <i>AWS Key Management Service API Reference</i>. </p> </li> <li> <p> <code>KmsDataKeyReusePeriodSeconds</code> - The length of time, in seconds, for which Amazon SQS can reuse a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data key</a> to encrypt or decrypt messages before calling AWS KMS again. An integer representing seconds, between 60 seconds (1 minute) and 86,400 seconds (24 hours). Default: 300 (5 minutes). A shorter time period provides better security but results in more calls to KMS which might incur charges after Free Tier. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-how-does-the-data-key-reuse-period-work">How Does the Data Key Reuse Period Work?</a>. </p> </li> </ul> <p>The following attribute applies only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO (first-in-first-out) queues</a>:</p> <ul> <li> <p> <code>ContentBasedDeduplication</code> - Enables content-based deduplication. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-exactly-once-processing">Exactly-Once Processing</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p>Every message must have a unique <code>MessageDeduplicationId</code>,</p> <ul> <li> <p>You may provide a <code>MessageDeduplicationId</code> explicitly.</p> </li> <li> <p>If you aren&#39;t able to provide a <code>MessageDeduplicationId</code> and you enable <code>ContentBasedDeduplication</code> for your queue, Amazon SQS uses a SHA-256 hash to generate the <code>MessageDeduplicationId</code> using the body of the message (but not the attributes of the message). </p> </li> <li> <p>If you don&#39;t provide a <code>MessageDeduplicationId</code> and the queue doesn&#39;t have <code>ContentBasedDeduplication</code> set, the action fails with an error.</p> </li> <li> <p>If the queue has <code>ContentBasedDeduplication</code> set, your <code>MessageDeduplicationId</code> overrides the generated one.</p> </li> </ul> </li> <li> <p>When <code>ContentBasedDeduplication</code> is in effect, messages with identical content sent within the deduplication interval are treated as duplicates and only one copy of the message is delivered.</p> </li> <li> <p>If you send one message with <code>ContentBasedDeduplication</code> enabled and then another message with a <code>MessageDeduplicationId</code> that is the same as the one generated for the first <code>MessageDeduplicationId</code>, the two messages are treated as duplicates and only one copy of the message is delivered. </p> </li> </ul> </li> </ul></p>
-    pub attributes: ::std::collections::HashMap<String, String>,
+    pub attributes: ::std::collections::HashMap<QueueAttributeName, String>,
     /// <p>The URL of the Amazon SQS queue whose attributes are set.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_url: String,
 }
 
+impl SetQueueAttributesRequest {
+    /// Returns a builder for `SetQueueAttributesRequest`, checking its required members when built.
+    pub fn builder() -> SetQueueAttributesRequestBuilder {
+        SetQueueAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetQueueAttributesRequest`](struct.SetQueueAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct SetQueueAttributesRequestBuilder {
+    attributes: Option<::std::collections::HashMap<QueueAttributeName, String>>,
+    queue_url: Option<String>,
+}
+
+impl SetQueueAttributesRequestBuilder {
+    /// Sets the [`attributes`](struct.SetQueueAttributesRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<QueueAttributeName, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_url`](struct.SetQueueAttributesRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetQueueAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetQueueAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(SetQueueAttributesRequest {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+        })
+    }
+}
+
 /// Serialize `SetQueueAttributesRequest` contents to a `SignedRequest`.
 struct SetQueueAttributesRequestSerializer;
 impl SetQueueAttributesRequestSerializer {
@@ -1869,10 +3006,13 @@ impl SetQueueAttributesRequestSerializer {
     }
 }
 
-struct StringDeserializer;
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1880,10 +3020,10 @@ impl StringDeserializer {
         Ok(obj)
     }
 }
-struct StringListDeserializer;
+pub(crate) struct StringListDeserializer;
 impl StringListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -1909,10 +3049,13 @@ impl StringListSerializer {
     }
 }
 
-struct TagKeyDeserializer;
+pub(crate) struct TagKeyDeserializer;
 impl TagKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1932,10 +3075,10 @@ impl TagKeyListSerializer {
     }
 }
 
-struct TagMapDeserializer;
+pub(crate) struct TagMapDeserializer;
 impl TagMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
@@ -1977,6 +3120,46 @@ pub struct TagQueueRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagQueueRequest {
+    /// Returns a builder for `TagQueueRequest`, checking its required members when built.
+    pub fn builder() -> TagQueueRequestBuilder {
+        TagQueueRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagQueueRequest`](struct.TagQueueRequest.html).
+#[derive(Default, Debug)]
+pub struct TagQueueRequestBuilder {
+    queue_url: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagQueueRequestBuilder {
+    /// Sets the [`queue_url`](struct.TagQueueRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagQueueRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagQueueRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagQueueRequest, ::rusoto_core::BuildError> {
+        Ok(TagQueueRequest {
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `TagQueueRequest` contents to a `SignedRequest`.
 struct TagQueueRequestSerializer;
 impl TagQueueRequestSerializer {
@@ -1991,10 +3174,13 @@ impl TagQueueRequestSerializer {
     }
 }
 
-struct TagValueDeserializer;
+pub(crate) struct TagValueDeserializer;
 impl TagValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2010,6 +3196,46 @@ pub struct UntagQueueRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagQueueRequest {
+    /// Returns a builder for `UntagQueueRequest`, checking its required members when built.
+    pub fn builder() -> UntagQueueRequestBuilder {
+        UntagQueueRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagQueueRequest`](struct.UntagQueueRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagQueueRequestBuilder {
+    queue_url: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagQueueRequestBuilder {
+    /// Sets the [`queue_url`](struct.UntagQueueRequest.html#structfield.queue_url) member.
+    pub fn queue_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagQueueRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagQueueRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagQueueRequest, ::rusoto_core::BuildError> {
+        Ok(UntagQueueRequest {
+            queue_url: self
+                .queue_url
+                .ok_or(::rusoto_core::BuildError::missing("queue_url"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// Serialize `UntagQueueRequest` contents to a `SignedRequest`.
 struct UntagQueueRequestSerializer;
 impl UntagQueueRequestSerializer {
@@ -3156,6 +4382,117 @@ pub trait Sqs {
     /// <p><p>Remove cost allocation tags from the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
     fn untag_queue(&self, input: UntagQueueRequest) -> RusotoFuture<(), UntagQueueError>;
 }
+impl<T: ?Sized + Sqs> Sqs for ::std::sync::Arc<T> {
+    fn add_permission(&self, input: AddPermissionRequest) -> RusotoFuture<(), AddPermissionError> {
+        (**self).add_permission(input)
+    }
+    fn change_message_visibility(
+        &self,
+        input: ChangeMessageVisibilityRequest,
+    ) -> RusotoFuture<(), ChangeMessageVisibilityError> {
+        (**self).change_message_visibility(input)
+    }
+    fn change_message_visibility_batch(
+        &self,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> RusotoFuture<ChangeMessageVisibilityBatchResult, ChangeMessageVisibilityBatchError> {
+        (**self).change_message_visibility_batch(input)
+    }
+    fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> RusotoFuture<CreateQueueResult, CreateQueueError> {
+        (**self).create_queue(input)
+    }
+    fn delete_message(&self, input: DeleteMessageRequest) -> RusotoFuture<(), DeleteMessageError> {
+        (**self).delete_message(input)
+    }
+    fn delete_message_batch(
+        &self,
+        input: DeleteMessageBatchRequest,
+    ) -> RusotoFuture<DeleteMessageBatchResult, DeleteMessageBatchError> {
+        (**self).delete_message_batch(input)
+    }
+    fn delete_queue(&self, input: DeleteQueueRequest) -> RusotoFuture<(), DeleteQueueError> {
+        (**self).delete_queue(input)
+    }
+    fn get_queue_attributes(
+        &self,
+        input: GetQueueAttributesRequest,
+    ) -> RusotoFuture<GetQueueAttributesResult, GetQueueAttributesError> {
+        (**self).get_queue_attributes(input)
+    }
+    fn get_queue_url(
+        &self,
+        input: GetQueueUrlRequest,
+    ) -> RusotoFuture<GetQueueUrlResult, GetQueueUrlError> {
+        (**self).get_queue_url(input)
+    }
+    fn list_dead_letter_source_queues(
+        &self,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> RusotoFuture<ListDeadLetterSourceQueuesResult, ListDeadLetterSourceQueuesError> {
+        (**self).list_dead_letter_source_queues(input)
+    }
+    fn list_queue_tags(
+        &self,
+        input: ListQueueTagsRequest,
+    ) -> RusotoFuture<ListQueueTagsResult, ListQueueTagsError> {
+        (**self).list_queue_tags(input)
+    }
+    fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> RusotoFuture<ListQueuesResult, ListQueuesError> {
+        (**self).list_queues(input)
+    }
+    fn purge_queue(&self, input: PurgeQueueRequest) -> RusotoFuture<(), PurgeQueueError> {
+        (**self).purge_queue(input)
+    }
+    fn receive_message(
+        &self,
+        input: ReceiveMessageRequest,
+    ) -> RusotoFuture<ReceiveMessageResult, ReceiveMessageError> {
+        (**self).receive_message(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> RusotoFuture<SendMessageResult, SendMessageError> {
+        (**self).send_message(input)
+    }
+    fn send_message_batch(
+        &self,
+        input: SendMessageBatchRequest,
+    ) -> RusotoFuture<SendMessageBatchResult, SendMessageBatchError> {
+        (**self).send_message_batch(input)
+    }
+    fn set_queue_attributes(
+        &self,
+        input: SetQueueAttributesRequest,
+    ) -> RusotoFuture<(), SetQueueAttributesError> {
+        (**self).set_queue_attributes(input)
+    }
+    fn tag_queue(&self, input: TagQueueRequest) -> RusotoFuture<(), TagQueueError> {
+        (**self).tag_queue(input)
+    }
+    fn untag_queue(&self, input: UntagQueueRequest) -> RusotoFuture<(), UntagQueueError> {
+        (**self).untag_queue(input)
+    }
+}
+/// The error codes Amazon SQS retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["RequestThrottled"],
+        transient: &[],
+    };
+
 /// A client for the Amazon SQS API.
 #[derive(Clone)]
 pub struct SqsClient {
@@ -3171,6 +4508,24 @@ impl SqsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SQS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> SqsClient {
+        Self::new(region::Region::from_env_for_service("SQS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `sqs`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> SqsClient {
+        Self::new_with_client(config.client().clone(), config.region_for("sqs", "SQS"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3189,7 +4544,49 @@ impl SqsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SqsClient {
-        SqsClient { client, region }
+        SqsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "sqs")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "sqs", "sqs", "SQS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Sqs` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Sqs + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for SqsClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon SQS"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "sqs"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> SqsClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Simple Systems Manager (SSM) clients.
+//!
+//! Makes a single read-only `ListAssociations` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_ssm::{ListAssociationsRequest, Ssm, SsmClient};
+
+fn main() {
+    let client = SsmClient::from_env();
+    match client
+        .list_associations(ListAssociationsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListAssociations succeeded"),
+        Err(error) => {
+            eprintln!("ListAssociations failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! Polling configuration stored in parameters.
+//!
+//! Applications often keep their configuration as a JSON document in a Parameter Store
+//! parameter, and pick up changes to it without being restarted. `watch_config` polls
+//! `GetParameter` and returns a stream of `ConfigUpdate`s, starting with the current
+//! configuration and then one whenever the value of the parameter changed, deserialized into
+//! the type of the configuration. A new version of the parameter with the same value isn't a
+//! change.
+//!
+//! `poll_config` does the same, calling a function with each change and keeping the latest
+//! configuration in a `ConfigCache`, which the rest of the application reads.
+//!
+//! Errors, including values which don't deserialize, end the stream. A `ConfigCache` keeps the
+//! last configuration which did.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::future::{self, Future};
+use futures::stream::{self, Stream};
+use rusoto_core::waiter::sleep;
+use rusoto_core::{RusotoError, RusotoFuture};
+use serde::de::DeserializeOwned;
+
+use crate::generated::{GetParameterError, GetParameterRequest, Parameter, Ssm};
+
+/// A stream of changes to a configuration.
+pub type ConfigUpdates<T> =
+    Box<dyn Stream<Item = ConfigUpdate<T>, Error = RusotoError<ConfigWatchError>> + Send>;
+
+/// A new configuration, read from a parameter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigUpdate<T> {
+    /// The name of the parameter.
+    pub name: String,
+    /// The version of the parameter.
+    pub version: Option<i64>,
+    /// The configuration.
+    pub config: T,
+    /// The value of the parameter the configuration was deserialized from.
+    pub payload: String,
+}
+
+/// Errors returned while polling a configuration.
+#[derive(Debug, PartialEq)]
+pub enum ConfigWatchError {
+    /// `GetParameter` failed.
+    GetParameter(GetParameterError),
+    /// The parameter has no value.
+    MissingValue(String),
+    /// The value of the parameter isn't a valid configuration.
+    Deserialize(String),
+}
+
+impl fmt::Display for ConfigWatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for ConfigWatchError {
+    fn description(&self) -> &str {
+        match *self {
+            ConfigWatchError::GetParameter(ref cause) => cause.description(),
+            ConfigWatchError::MissingValue(ref cause) => cause,
+            ConfigWatchError::Deserialize(ref cause) => cause,
+        }
+    }
+}
+
+/// The latest configuration read by `poll_config`.
+///
+/// Clones share the same configuration.
+#[derive(Debug)]
+pub struct ConfigCache<T> {
+    latest: Arc<Mutex<Option<ConfigUpdate<T>>>>,
+}
+
+impl<T> ConfigCache<T> {
+    /// Creates an empty cache.
+    pub fn new() -> ConfigCache<T> {
+        ConfigCache {
+            latest: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    fn set(&self, update: ConfigUpdate<T>) {
+        *self.latest.lock().expect("ConfigCache lock poisoned") = Some(update);
+    }
+}
+
+impl<T: Clone> ConfigCache<T> {
+    /// The latest configuration, once one was read.
+    pub fn get(&self) -> Option<T> {
+        self.latest().map(|update| update.config)
+    }
+
+    /// The latest configuration with the version and value of its parameter.
+    pub fn latest(&self) -> Option<ConfigUpdate<T>> {
+        self.latest
+            .lock()
+            .expect("ConfigCache lock poisoned")
+            .clone()
+    }
+}
+
+impl<T> Clone for ConfigCache<T> {
+    fn clone(&self) -> ConfigCache<T> {
+        ConfigCache {
+            latest: self.latest.clone(),
+        }
+    }
+}
+
+impl<T> Default for ConfigCache<T> {
+    fn default() -> ConfigCache<T> {
+        ConfigCache::new()
+    }
+}
+
+/// Polling configuration stored in parameters, for any `Ssm` client.
+pub trait ConfigWatcherExt: Ssm + Clone + Send + Sized + 'static {
+    /// Polls the parameter `name` every `interval`, returning its JSON value deserialized into
+    /// a `T` first, and then whenever it changed. `SecureString` parameters are decrypted.
+    ///
+    /// The stream doesn't end on its own. Drop it to stop polling.
+    fn watch_config<T>(&self, name: &str, interval: Duration) -> ConfigUpdates<T>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        watch(self.clone(), name.to_owned(), interval)
+    }
+
+    /// Polls the parameter `name` every `interval` like `watch_config`, storing each new
+    /// configuration in `cache` and then calling `on_change` with it.
+    ///
+    /// The future only completes with an error. Drop it to stop polling.
+    fn poll_config<T, F>(
+        &self,
+        name: &str,
+        interval: Duration,
+        cache: ConfigCache<T>,
+        mut on_change: F,
+    ) -> RusotoFuture<(), ConfigWatchError>
+    where
+        T: DeserializeOwned + Clone + Send + 'static,
+        F: FnMut(&ConfigUpdate<T>) + Send + 'static,
+    {
+        let future = self.watch_config(name, interval).for_each(move |update| {
+            cache.set(update.clone());
+            on_change(&update);
+            Ok(())
+        });
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: Ssm + Clone + Send + 'static> ConfigWatcherExt for T {}
+
+struct WatchState<C> {
+    client: C,
+    name: String,
+    interval: Duration,
+    /// The value of the parameter when it was last read.
+    payload: Option<String>,
+}
+
+impl<C> WatchState<C> {
+    /// The new configuration in `parameter`, unless its value didn't change.
+    fn update<T: DeserializeOwned>(
+        &mut self,
+        parameter: Parameter,
+    ) -> Result<Option<ConfigUpdate<T>>, RusotoError<ConfigWatchError>> {
+        let payload = parameter.value.ok_or_else(|| {
+            RusotoError::Service(ConfigWatchError::MissingValue(format!(
+                "Parameter {} has no value",
+                self.name
+            )))
+        })?;
+        if self.payload.as_ref() == Some(&payload) {
+            return Ok(None);
+        }
+        let config = serde_json::from_str(&payload).map_err(|err| {
+            RusotoError::Service(ConfigWatchError::Deserialize(format!(
+                "Parameter {} is not a valid configuration: {}",
+                self.name, err
+            )))
+        })?;
+        self.payload = Some(payload.clone());
+        Ok(Some(ConfigUpdate {
+            name: self.name.clone(),
+            version: parameter.version,
+            config,
+            payload,
+        }))
+    }
+}
+
+fn watch<C, T>(client: C, name: String, interval: Duration) -> ConfigUpdates<T>
+where
+    C: Ssm + Send + 'static,
+    T: DeserializeOwned + Send + 'static,
+{
+    let state = WatchState {
+        client,
+        name,
+        interval,
+        payload: None,
+    };
+
+    let updates = stream::unfold(state, |mut state| {
+        let delay: Box<dyn Future<Item = (), Error = RusotoError<ConfigWatchError>> + Send> =
+            if state.payload.is_some() {
+                Box::new(sleep(state.interval))
+            } else {
+                Box::new(future::ok(()))
+            };
+        let parameter = delay.and_then(move |_| {
+            get_parameter(&state.client, state.name.clone())
+                .and_then(move |parameter| state.update(parameter).map(|update| (update, state)))
+        });
+        Some(parameter)
+    });
+
+    Box::new(updates.filter_map(|update| update))
+}
+
+fn get_parameter<C: Ssm>(
+    client: &C,
+    name: String,
+) -> impl Future<Item = Parameter, Error = RusotoError<ConfigWatchError>> {
+    client
+        .get_parameter(GetParameterRequest {
+            name: name.clone(),
+            with_decryption: Some(true),
+        })
+        .map_err(|e| e.map_service(ConfigWatchError::GetParameter))
+        .and_then(move |result| {
+            result.parameter.ok_or_else(|| {
+                RusotoError::Service(ConfigWatchError::MissingValue(format!(
+                    "Parameter {} not found",
+                    name
+                )))
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameter(value: &str, version: i64) -> Parameter {
+        Parameter {
+            value: Some(value.to_owned()),
+            version: Some(version),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn only_changed_values_are_updates() {
+        let mut state = WatchState {
+            client: (),
+            name: "/app/config".to_owned(),
+            interval: Duration::from_secs(30),
+            payload: None,
+        };
+
+        let update = state
+            .update::<Vec<u32>>(parameter("[1, 2]", 1))
+            .unwrap()
+            .unwrap();
+        assert_eq!(update.config, vec![1, 2]);
+        assert_eq!(update.version, Some(1));
+
+        // a new version with the same value
+        assert_eq!(state.update::<Vec<u32>>(parameter("[1, 2]", 2)), Ok(None));
+
+        let update = state
+            .update::<Vec<u32>>(parameter("[3]", 3))
+            .unwrap()
+            .unwrap();
+        assert_eq!(update.config, vec![3]);
+    }
+
+    #[test]
+    fn invalid_values_are_errors() {
+        let mut state = WatchState {
+            client: (),
+            name: "/app/config".to_owned(),
+            interval: Duration::from_secs(30),
+            payload: Some("[1]".to_owned()),
+        };
+
+        match state.update::<Vec<u32>>(parameter("not json", 2)) {
+            Err(RusotoError::Service(ConfigWatchError::Deserialize(_))) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+        // the last valid value is still the one compared with
+        assert_eq!(state.payload, Some("[1]".to_owned()));
+    }
+}
@@ -0,0 +1,82 @@
+extern crate rusoto_mock;
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use futures::{Future, Stream};
+
+use crate::custom::config::ConfigWatcherExt;
+use crate::custom::patching::{self, PatchingExt};
+use crate::generated::SsmClient;
+
+use self::rusoto_mock::*;
+use rusoto_core::signature::SignedRequestPayload;
+use rusoto_core::Region;
+
+#[test]
+fn should_deserialize_the_current_configuration() {
+    let body = r#"{
+        "Parameter": {
+            "Name": "/app/config",
+            "Type": "SecureString",
+            "Value": "{\"feature\": \"on\"}",
+            "Version": 3
+        }
+    }"#;
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(body)
+        .with_request_checker(|request| {
+            assert_eq!(
+                request.headers["x-amz-target"][0],
+                b"AmazonSSM.GetParameter".to_vec()
+            );
+            if let Some(SignedRequestPayload::Buffer(ref buffer)) = request.payload {
+                let payload = String::from_utf8(buffer.to_vec()).unwrap();
+                assert!(payload.contains(r#""WithDecryption":true"#));
+            } else {
+                panic!("Unexpected request.payload: {:?}", request.payload);
+            }
+        });
+    let client = SsmClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let updates = client
+        .watch_config::<HashMap<String, String>>("/app/config", Duration::from_secs(30))
+        .take(1)
+        .collect()
+        .wait()
+        .unwrap();
+
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].name, "/app/config");
+    assert_eq!(updates[0].version, Some(3));
+    assert_eq!(updates[0].config["feature"], "on");
+}
+
+#[test]
+fn should_scan_targets_with_run_patch_baseline() {
+    let body = r#"{"Command": {"CommandId": "0b9ab5ae-1234-4b61-8ad2-2a1fe1d0e0a9"}}"#;
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(body)
+        .with_request_checker(|request| {
+            assert_eq!(
+                request.headers["x-amz-target"][0],
+                b"AmazonSSM.SendCommand".to_vec()
+            );
+            if let Some(SignedRequestPayload::Buffer(ref buffer)) = request.payload {
+                let payload: serde_json::Value = serde_json::from_slice(buffer).unwrap();
+                assert_eq!(payload["DocumentName"], "AWS-RunPatchBaseline");
+                assert_eq!(payload["Parameters"]["Operation"][0], "Scan");
+                assert_eq!(payload["Targets"][0]["Key"], "tag:PatchGroup");
+                assert_eq!(payload["Targets"][0]["Values"][0], "web");
+            } else {
+                panic!("Unexpected request.payload: {:?}", request.payload);
+            }
+        });
+    let client = SsmClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let command_id = client
+        .scan_for_patches(patching::tag_targets("PatchGroup", &["web"]))
+        .sync()
+        .unwrap();
+    assert_eq!(command_id, "0b9ab5ae-1234-4b61-8ad2-2a1fe1d0e0a9");
+}
@@ -1 +1,7 @@
+/// Polling configuration stored in parameters, with a cache and change callbacks
+pub mod config;
+/// Maintenance window patching, on-demand patch scans and compliance summaries
+pub mod patching;
 
+#[cfg(test)]
+mod custom_tests;
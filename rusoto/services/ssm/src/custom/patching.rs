@@ -0,0 +1,376 @@
+//! Patching instances with maintenance windows and Run Command.
+//!
+//! Patch Manager patches instances by running the `AWS-RunPatchBaseline` document on them,
+//! either scanning them for missing patches or installing them. `PatchingExt` registers the
+//! instances and a patch task with a maintenance window, so they are patched on its schedule,
+//! or runs a scan right away with `SendCommand`.
+//!
+//! Each instance reports the result of its last patch operation. `patch_compliance` reads them
+//! and sums them up in a `PatchComplianceSummary`, listing the instances missing patches and
+//! the ones which never reported.
+
+use std::collections::{HashMap, HashSet};
+
+use futures::stream::{self, Stream};
+use futures::Future;
+use rusoto_core::timestamp::EpochTimestamp;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    DescribeInstancePatchStatesError, DescribeInstancePatchStatesRequest, InstancePatchState,
+    MaintenanceWindowResourceType, MaintenanceWindowRunCommandParameters,
+    MaintenanceWindowTaskInvocationParameters, MaintenanceWindowTaskType, PatchOperationType,
+    RegisterTargetWithMaintenanceWindowError, RegisterTargetWithMaintenanceWindowRequest,
+    RegisterTaskWithMaintenanceWindowError, RegisterTaskWithMaintenanceWindowRequest,
+    SendCommandError, SendCommandRequest, Ssm, Target,
+};
+
+/// The document Patch Manager patches instances with.
+pub const RUN_PATCH_BASELINE: &str = "AWS-RunPatchBaseline";
+
+/// The most instances `DescribeInstancePatchStates` accepts at once.
+const MAX_INSTANCES_PER_REQUEST: usize = 50;
+
+/// What `AWS-RunPatchBaseline` does.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PatchOperation {
+    /// Reports the patches missing from instances, without installing them.
+    Scan,
+    /// Installs the missing patches, rebooting instances if needed.
+    Install,
+}
+
+impl PatchOperation {
+    /// The value of the `Operation` parameter of `AWS-RunPatchBaseline`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PatchOperation::Scan => "Scan",
+            PatchOperation::Install => "Install",
+        }
+    }
+
+    fn parameters(self) -> HashMap<String, Vec<String>> {
+        let mut parameters = HashMap::new();
+        parameters.insert("Operation".to_owned(), vec![self.as_str().to_owned()]);
+        parameters
+    }
+}
+
+/// Targets the instances `instance_ids`.
+pub fn instance_targets(instance_ids: &[&str]) -> Vec<Target> {
+    vec![target("InstanceIds", instance_ids)]
+}
+
+/// Targets the instances tagged with `key` set to one of `values`.
+pub fn tag_targets(key: &str, values: &[&str]) -> Vec<Target> {
+    vec![target(&format!("tag:{}", key), values)]
+}
+
+fn target(key: &str, values: &[&str]) -> Target {
+    Target {
+        key: Some(key.to_owned()),
+        values: Some(values.iter().map(|value| (*value).to_owned()).collect()),
+    }
+}
+
+/// The patch compliance of an instance, as of its last patch operation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstancePatchCompliance {
+    /// The ID of the instance.
+    pub instance_id: String,
+    /// The patch group of the instance.
+    pub patch_group: String,
+    /// The ID of the patch baseline the instance was patched with.
+    pub baseline_id: String,
+    /// The last operation, `Scan` or `Install`.
+    pub operation: PatchOperationType,
+    /// When the last operation ended.
+    pub operation_end_time: EpochTimestamp,
+    /// The number of patches installed, including the ones outside the baseline.
+    pub installed_count: i64,
+    /// The number of patches from the baseline which aren't installed.
+    pub missing_count: i64,
+    /// The number of patches which failed to install.
+    pub failed_count: i64,
+    /// The number of patches which don't apply to the instance.
+    pub not_applicable_count: i64,
+}
+
+impl InstancePatchCompliance {
+    /// Whether no patch is missing or failed to install.
+    pub fn is_compliant(&self) -> bool {
+        self.missing_count == 0 && self.failed_count == 0
+    }
+}
+
+impl From<InstancePatchState> for InstancePatchCompliance {
+    fn from(state: InstancePatchState) -> InstancePatchCompliance {
+        InstancePatchCompliance {
+            instance_id: state.instance_id,
+            patch_group: state.patch_group,
+            baseline_id: state.baseline_id,
+            operation: state.operation,
+            operation_end_time: state.operation_end_time,
+            installed_count: state.installed_count.unwrap_or(0)
+                + state.installed_other_count.unwrap_or(0),
+            missing_count: state.missing_count.unwrap_or(0),
+            failed_count: state.failed_count.unwrap_or(0),
+            not_applicable_count: state.not_applicable_count.unwrap_or(0),
+        }
+    }
+}
+
+/// The patch compliance of a set of instances.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PatchComplianceSummary {
+    /// The instances which reported a patch operation, in the order they were asked for.
+    pub instances: Vec<InstancePatchCompliance>,
+    /// The instances which never reported a patch operation.
+    pub unreported: Vec<String>,
+}
+
+impl PatchComplianceSummary {
+    fn new(instance_ids: &[String], states: Vec<InstancePatchState>) -> PatchComplianceSummary {
+        let mut states = states
+            .into_iter()
+            .map(|state| (state.instance_id.clone(), state))
+            .collect::<HashMap<_, _>>();
+        let mut summary = PatchComplianceSummary::default();
+        let mut seen = HashSet::new();
+        for instance_id in instance_ids {
+            if !seen.insert(instance_id) {
+                continue;
+            }
+            match states.remove(instance_id) {
+                Some(state) => summary.instances.push(state.into()),
+                None => summary.unreported.push(instance_id.clone()),
+            }
+        }
+        summary
+    }
+
+    /// Whether every instance reported and is compliant.
+    pub fn is_compliant(&self) -> bool {
+        self.unreported.is_empty() && self.instances.iter().all(|i| i.is_compliant())
+    }
+
+    /// The instances with no patch missing or failed.
+    pub fn compliant(&self) -> impl Iterator<Item = &InstancePatchCompliance> {
+        self.instances.iter().filter(|i| i.is_compliant())
+    }
+
+    /// The instances with patches missing or failed.
+    pub fn non_compliant(&self) -> impl Iterator<Item = &InstancePatchCompliance> {
+        self.instances.iter().filter(|i| !i.is_compliant())
+    }
+
+    /// The number of patches missing from all instances.
+    pub fn missing_count(&self) -> i64 {
+        self.instances.iter().map(|i| i.missing_count).sum()
+    }
+
+    /// The number of patches which failed to install on all instances.
+    pub fn failed_count(&self) -> i64 {
+        self.instances.iter().map(|i| i.failed_count).sum()
+    }
+}
+
+/// Patching instances, for any `Ssm` client.
+pub trait PatchingExt: Ssm + Clone + Send + Sized + 'static {
+    /// Registers `targets` with the maintenance window `window_id`, returning the ID of the
+    /// window target to register a patch task for.
+    fn register_patch_targets(
+        &self,
+        window_id: &str,
+        targets: Vec<Target>,
+    ) -> RusotoFuture<String, RegisterTargetWithMaintenanceWindowError> {
+        let future = self
+            .register_target_with_maintenance_window(RegisterTargetWithMaintenanceWindowRequest {
+                window_id: window_id.to_owned(),
+                resource_type: MaintenanceWindowResourceType::Instance,
+                targets,
+                client_token: None,
+                description: None,
+                name: None,
+                owner_information: None,
+            })
+            .and_then(|result| {
+                result
+                    .window_target_id
+                    .ok_or_else(|| RusotoError::ParseError("Missing WindowTargetId".to_owned()))
+            });
+        RusotoFuture::from_future(future)
+    }
+
+    /// Registers a task running `AWS-RunPatchBaseline` with the maintenance window `window_id`
+    /// on the instances of its target `window_target_id`, returning the ID of the task.
+    ///
+    /// `max_concurrency` and `max_errors` are a number or a percentage of the instances, e.g.
+    /// `"10%"`.
+    fn register_patch_task(
+        &self,
+        window_id: &str,
+        window_target_id: &str,
+        operation: PatchOperation,
+        max_concurrency: &str,
+        max_errors: &str,
+    ) -> RusotoFuture<String, RegisterTaskWithMaintenanceWindowError> {
+        let future = self
+            .register_task_with_maintenance_window(RegisterTaskWithMaintenanceWindowRequest {
+                window_id: window_id.to_owned(),
+                targets: vec![target("WindowTargetIds", &[window_target_id])],
+                task_arn: RUN_PATCH_BASELINE.to_owned(),
+                task_type: MaintenanceWindowTaskType::RunCommand,
+                task_invocation_parameters: Some(MaintenanceWindowTaskInvocationParameters {
+                    run_command: Some(MaintenanceWindowRunCommandParameters {
+                        parameters: Some(operation.parameters()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                max_concurrency: max_concurrency.to_owned(),
+                max_errors: max_errors.to_owned(),
+                client_token: None,
+                description: None,
+                logging_info: None,
+                name: None,
+                priority: None,
+                service_role_arn: None,
+                task_parameters: None,
+            })
+            .and_then(|result| {
+                result
+                    .window_task_id
+                    .ok_or_else(|| RusotoError::ParseError("Missing WindowTaskId".to_owned()))
+            });
+        RusotoFuture::from_future(future)
+    }
+
+    /// Runs `AWS-RunPatchBaseline` on `targets` now, returning the ID of the command.
+    fn run_patch_baseline(
+        &self,
+        targets: Vec<Target>,
+        operation: PatchOperation,
+    ) -> RusotoFuture<String, SendCommandError> {
+        let future = self
+            .send_command(SendCommandRequest {
+                document_name: RUN_PATCH_BASELINE.to_owned(),
+                targets: Some(targets),
+                parameters: Some(operation.parameters()),
+                comment: Some(format!("Patch {}", operation.as_str())),
+                ..Default::default()
+            })
+            .and_then(|result| {
+                result
+                    .command
+                    .and_then(|command| command.command_id)
+                    .ok_or_else(|| RusotoError::ParseError("Missing CommandId".to_owned()))
+            });
+        RusotoFuture::from_future(future)
+    }
+
+    /// Scans `targets` for missing patches now, returning the ID of the command. Their
+    /// compliance is updated once the command completes.
+    fn scan_for_patches(&self, targets: Vec<Target>) -> RusotoFuture<String, SendCommandError> {
+        self.run_patch_baseline(targets, PatchOperation::Scan)
+    }
+
+    /// Reads the patch compliance of `instance_ids` as of their last patch operation.
+    fn patch_compliance(
+        &self,
+        instance_ids: &[&str],
+    ) -> RusotoFuture<PatchComplianceSummary, DescribeInstancePatchStatesError> {
+        let instance_ids = instance_ids
+            .iter()
+            .map(|id| (*id).to_owned())
+            .collect::<Vec<_>>();
+        let client = self.clone();
+        let chunks = instance_ids
+            .chunks(MAX_INSTANCES_PER_REQUEST)
+            .map(<[String]>::to_vec)
+            .collect::<Vec<_>>();
+        let future = stream::iter_ok::<_, RusotoError<DescribeInstancePatchStatesError>>(chunks)
+            .map(move |chunk| patch_states(client.clone(), chunk))
+            .flatten()
+            .concat2()
+            .map(move |states| PatchComplianceSummary::new(&instance_ids, states));
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: Ssm + Clone + Send + 'static> PatchingExt for T {}
+
+/// The patch states of `instance_ids`, a page at a time.
+fn patch_states<C: Ssm + Send + 'static>(
+    client: C,
+    instance_ids: Vec<String>,
+) -> impl Stream<Item = Vec<InstancePatchState>, Error = RusotoError<DescribeInstancePatchStatesError>>
+{
+    // `None` once the last page was read
+    stream::unfold(Some(None), move |next_token: Option<Option<String>>| {
+        let next_token = next_token?;
+        let page = client
+            .describe_instance_patch_states(DescribeInstancePatchStatesRequest {
+                instance_ids: instance_ids.clone(),
+                max_results: Some(MAX_INSTANCES_PER_REQUEST as i64),
+                next_token,
+            })
+            .map(|result| {
+                (
+                    result.instance_patch_states.unwrap_or_default(),
+                    result.next_token.map(Some),
+                )
+            });
+        Some(page)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(instance_id: &str, missing: i64, failed: i64) -> InstancePatchState {
+        InstancePatchState {
+            instance_id: instance_id.to_owned(),
+            baseline_id: "pb-0123456789abcdef0".to_owned(),
+            patch_group: "web".to_owned(),
+            operation: PatchOperationType::Scan,
+            installed_count: Some(40),
+            installed_other_count: Some(2),
+            missing_count: Some(missing),
+            failed_count: Some(failed),
+            install_override_list: None,
+            installed_rejected_count: None,
+            not_applicable_count: None,
+            operation_end_time: Default::default(),
+            operation_start_time: Default::default(),
+            owner_information: None,
+            snapshot_id: None,
+            unreported_not_applicable_count: None,
+        }
+    }
+
+    #[test]
+    fn summarizes_instance_patch_states() {
+        let instance_ids = ["i-1", "i-2", "i-3", "i-4", "i-1"]
+            .iter()
+            .map(|id| (*id).to_owned())
+            .collect::<Vec<_>>();
+        let states = vec![state("i-3", 0, 1), state("i-1", 0, 0), state("i-2", 5, 0)];
+
+        let summary = PatchComplianceSummary::new(&instance_ids, states);
+        let ids = |instances: Vec<&InstancePatchCompliance>| {
+            instances
+                .iter()
+                .map(|i| i.instance_id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(summary.compliant().collect()), vec!["i-1"]);
+        assert_eq!(ids(summary.non_compliant().collect()), vec!["i-2", "i-3"]);
+        assert_eq!(summary.unreported, vec!["i-4"]);
+        assert_eq!(summary.missing_count(), 5);
+        assert_eq!(summary.failed_count(), 1);
+        assert_eq!(summary.instances[0].installed_count, 42);
+        assert!(!summary.is_compliant());
+    }
+}
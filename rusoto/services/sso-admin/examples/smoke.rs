@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Single Sign-On Admin clients.
+//!
+//! Makes a single read-only `ListApplicationProviders` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_sso_admin::{ListApplicationProvidersRequest, SsoAdmin, SsoAdminClient};
+
+fn main() {
+    let client = SsoAdminClient::from_env();
+    match client
+        .list_application_providers(ListApplicationProvidersRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListApplicationProviders succeeded"),
+        Err(error) => {
+            eprintln!("ListApplicationProviders failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+extern crate rusoto_mock;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::custom::waiters::{PermissionSetWaiterExt, ProvisioningWaitError};
+use crate::generated::{SsoAdminClient, StatusValues};
+
+use self::rusoto_mock::*;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::waiter::WaiterConfig;
+use rusoto_core::{Region, RusotoError};
+
+const INSTANCE_ARN: &str = "arn:aws:sso:::instance/ssoins-1111111111111111";
+const PERMISSION_SET_ARN: &str =
+    "arn:aws:sso:::permissionSet/ssoins-1111111111111111/ps-2222222222222222";
+
+fn target(request: &SignedRequest) -> String {
+    let value = &request.headers["x-amz-target"][0];
+    String::from_utf8(value.clone()).unwrap()
+}
+
+fn input(request: &SignedRequest) -> serde_json::Value {
+    match request.payload {
+        Some(SignedRequestPayload::Buffer(ref body)) => serde_json::from_slice(body).unwrap(),
+        _ => panic!("expected a buffered payload"),
+    }
+}
+
+fn status_body(status: &str) -> String {
+    format!(
+        r#"{{
+            "PermissionSetProvisioningStatus": {{
+                "PermissionSetArn": "{}",
+                "RequestId": "request",
+                "Status": "{}",
+                "FailureReason": "Policy is not attachable"
+            }}
+        }}"#,
+        PERMISSION_SET_ARN, status
+    )
+}
+
+#[test]
+fn attach_managed_policies_and_wait_provisions_all_accounts() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let record = |calls: &Arc<Mutex<Vec<(String, serde_json::Value)>>>| {
+        let calls = calls.clone();
+        move |request: &SignedRequest| {
+            calls
+                .lock()
+                .unwrap()
+                .push((target(request), input(request)));
+        }
+    };
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        MockRequestDispatcher::with_status(200)
+            .with_body("{}")
+            .with_request_checker(record(&calls)),
+        MockRequestDispatcher::with_status(200)
+            .with_body("{}")
+            .with_request_checker(record(&calls)),
+        MockRequestDispatcher::with_status(200)
+            .with_body(&status_body("IN_PROGRESS"))
+            .with_request_checker(record(&calls)),
+        MockRequestDispatcher::with_status(200)
+            .with_body(&status_body("SUCCEEDED"))
+            .with_request_checker(record(&calls)),
+    ]);
+    let client = SsoAdminClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let status = client
+        .attach_managed_policies_and_wait(
+            INSTANCE_ARN,
+            PERMISSION_SET_ARN,
+            vec![
+                "arn:aws:iam::aws:policy/ReadOnlyAccess".to_owned(),
+                "arn:aws:iam::aws:policy/job-function/ViewOnlyAccess".to_owned(),
+            ],
+            WaiterConfig::new(Duration::from_millis(1), 3),
+        )
+        .sync()
+        .unwrap();
+
+    assert_eq!(status.status, Some(StatusValues::Succeeded));
+    let calls = calls.lock().unwrap();
+    let targets: Vec<&str> = calls.iter().map(|(target, _)| target.as_str()).collect();
+    assert_eq!(
+        targets,
+        vec![
+            "SWBExternalService.AttachManagedPolicyToPermissionSet",
+            "SWBExternalService.AttachManagedPolicyToPermissionSet",
+            "SWBExternalService.ProvisionPermissionSet",
+            "SWBExternalService.DescribePermissionSetProvisioningStatus",
+        ]
+    );
+    assert_eq!(
+        calls[1].1["ManagedPolicyArn"],
+        "arn:aws:iam::aws:policy/job-function/ViewOnlyAccess"
+    );
+    assert_eq!(calls[2].1["TargetType"], "ALL_PROVISIONED_ACCOUNTS");
+    assert_eq!(calls[3].1["ProvisionPermissionSetRequestId"], "request");
+}
+
+#[test]
+fn wait_for_permission_set_provisioning_reports_failures() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(&status_body("FAILED"));
+    let client = SsoAdminClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .wait_for_permission_set_provisioning(
+            INSTANCE_ARN,
+            "request",
+            WaiterConfig::new(Duration::from_millis(1), 3),
+        )
+        .sync();
+
+    match result {
+        Err(RusotoError::Service(ProvisioningWaitError::Failed(message))) => {
+            assert!(message.contains("Policy is not attachable"));
+        }
+        other => panic!("expected a failed provisioning, got {:?}", other),
+    }
+}
+
+#[test]
+fn wait_for_permission_set_provisioning_times_out_while_in_progress() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(&status_body("IN_PROGRESS"));
+    let client = SsoAdminClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .wait_for_permission_set_provisioning(
+            INSTANCE_ARN,
+            "request",
+            WaiterConfig::new(Duration::from_millis(1), 2),
+        )
+        .sync();
+
+    match result {
+        Err(RusotoError::Service(ProvisioningWaitError::TimedOut(_))) => {}
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+}
@@ -0,0 +1,5 @@
+/// Waiting for permission set provisioning to finish
+pub mod waiters;
+
+#[cfg(test)]
+mod custom_tests;
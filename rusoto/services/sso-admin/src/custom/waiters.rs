@@ -0,0 +1,248 @@
+//! Helpers for permission set provisioning, which completes asynchronously.
+//!
+//! `ProvisionPermissionSet` only starts copying a permission set to the accounts it is assigned
+//! to, and changes such as attaching a managed policy are not visible in those accounts until the
+//! permission set has been provisioned again. The methods of `PermissionSetWaiterExt` poll
+//! `DescribePermissionSetProvisioningStatus` until a provisioning request has finished.
+
+use std::error::Error;
+use std::fmt;
+
+use futures::future::{self, Loop};
+use futures::Future;
+use rusoto_core::waiter::{poll_until, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    AttachManagedPolicyToPermissionSetError, AttachManagedPolicyToPermissionSetRequest,
+    DescribePermissionSetProvisioningStatusError, DescribePermissionSetProvisioningStatusRequest,
+    PermissionSetProvisioningStatus, ProvisionPermissionSetError, ProvisionPermissionSetRequest,
+    ProvisionTargetType, SsoAdmin, StatusValues,
+};
+
+/// Errors returned while provisioning a permission set and waiting for it.
+#[derive(Debug, PartialEq)]
+pub enum ProvisioningWaitError {
+    /// Attaching a managed policy to the permission set failed.
+    AttachManagedPolicyToPermissionSet(AttachManagedPolicyToPermissionSetError),
+    /// Starting the provisioning failed.
+    ProvisionPermissionSet(ProvisionPermissionSetError),
+    /// Describing the provisioning status while waiting failed.
+    DescribePermissionSetProvisioningStatus(DescribePermissionSetProvisioningStatusError),
+    /// The provisioning ended up `FAILED`.
+    Failed(String),
+    /// The provisioning did not finish within the configured number of attempts.
+    TimedOut(String),
+}
+
+impl fmt::Display for ProvisioningWaitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for ProvisioningWaitError {
+    fn description(&self) -> &str {
+        match *self {
+            ProvisioningWaitError::AttachManagedPolicyToPermissionSet(ref cause) => {
+                cause.description()
+            }
+            ProvisioningWaitError::ProvisionPermissionSet(ref cause) => cause.description(),
+            ProvisioningWaitError::DescribePermissionSetProvisioningStatus(ref cause) => {
+                cause.description()
+            }
+            ProvisioningWaitError::Failed(ref cause) => cause,
+            ProvisioningWaitError::TimedOut(ref cause) => cause,
+        }
+    }
+}
+
+/// Returns true once the provisioning is no longer `IN_PROGRESS`.
+pub fn is_provisioning_finished(status: &PermissionSetProvisioningStatus) -> bool {
+    match status.status {
+        Some(StatusValues::InProgress) | None => false,
+        _ => true,
+    }
+}
+
+fn failure(status: &PermissionSetProvisioningStatus) -> Option<ProvisioningWaitError> {
+    if status.status != Some(StatusValues::Failed) {
+        return None;
+    }
+    let field = |value: &Option<String>| value.clone().unwrap_or_default();
+    Some(ProvisioningWaitError::Failed(format!(
+        "Provisioning of {} failed: {}",
+        field(&status.permission_set_arn),
+        field(&status.failure_reason),
+    )))
+}
+
+/// Extension methods which provision permission sets and wait for the provisioning to finish.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::waiter::WaiterConfig;
+/// use rusoto_core::Region;
+/// use rusoto_sso_admin::waiters::PermissionSetWaiterExt;
+/// use rusoto_sso_admin::SsoAdminClient;
+///
+/// let client = SsoAdminClient::new(Region::UsEast1);
+/// let status = client
+///     .attach_managed_policies_and_wait(
+///         "arn:aws:sso:::instance/ssoins-1111111111111111",
+///         "arn:aws:sso:::permissionSet/ssoins-1111111111111111/ps-2222222222222222",
+///         vec!["arn:aws:iam::aws:policy/ReadOnlyAccess".to_owned()],
+///         WaiterConfig::default(),
+///     )
+///     .sync()
+///     .unwrap();
+/// ```
+pub trait PermissionSetWaiterExt: SsoAdmin + Clone + Send + Sized + 'static {
+    /// Polls `DescribePermissionSetProvisioningStatus` until the provisioning request is no
+    /// longer `IN_PROGRESS`, and returns its final status.
+    ///
+    /// Fails with `ProvisioningWaitError::Failed` if the provisioning is `FAILED`.
+    fn wait_for_permission_set_provisioning(
+        &self,
+        instance_arn: &str,
+        request_id: &str,
+        config: WaiterConfig,
+    ) -> RusotoFuture<PermissionSetProvisioningStatus, ProvisioningWaitError> {
+        let client = self.clone();
+        let request = DescribePermissionSetProvisioningStatusRequest {
+            instance_arn: instance_arn.to_owned(),
+            provision_permission_set_request_id: request_id.to_owned(),
+        };
+        let timeout = RusotoError::Service(ProvisioningWaitError::TimedOut(format!(
+            "Provisioning request {} did not finish",
+            request_id
+        )));
+
+        let future = poll_until(
+            config,
+            move || {
+                client
+                    .describe_permission_set_provisioning_status(request.clone())
+                    .map_err(|e| {
+                        e.map_service(
+                            ProvisioningWaitError::DescribePermissionSetProvisioningStatus,
+                        )
+                    })
+                    .and_then(|output| {
+                        let status = output
+                            .permission_set_provisioning_status
+                            .unwrap_or_default();
+                        if let Some(error) = failure(&status) {
+                            return Err(RusotoError::Service(error));
+                        }
+                        if is_provisioning_finished(&status) {
+                            Ok(Some(status))
+                        } else {
+                            Ok(None)
+                        }
+                    })
+            },
+            timeout,
+        );
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Provisions a permission set, then waits until the provisioning has finished.
+    fn provision_permission_set_and_wait(
+        &self,
+        input: ProvisionPermissionSetRequest,
+        config: WaiterConfig,
+    ) -> RusotoFuture<PermissionSetProvisioningStatus, ProvisioningWaitError> {
+        let client = self.clone();
+        let instance_arn = input.instance_arn.clone();
+
+        let future = self
+            .provision_permission_set(input)
+            .map_err(|e| e.map_service(ProvisioningWaitError::ProvisionPermissionSet))
+            .and_then(move |output| {
+                let status = output
+                    .permission_set_provisioning_status
+                    .unwrap_or_default();
+                if let Some(error) = failure(&status) {
+                    return future::Either::A(future::err(RusotoError::Service(error)));
+                }
+                match status.request_id {
+                    Some(ref request_id) if !is_provisioning_finished(&status) => {
+                        future::Either::B(client.wait_for_permission_set_provisioning(
+                            &instance_arn,
+                            request_id,
+                            config,
+                        ))
+                    }
+                    _ => future::Either::A(future::ok(status)),
+                }
+            });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Attaches AWS managed policies to a permission set one after the other, then provisions it
+    /// to every account it is assigned to and waits until the provisioning has finished.
+    ///
+    /// Once this returns, the policies are in effect in the accounts using the permission set.
+    fn attach_managed_policies_and_wait(
+        &self,
+        instance_arn: &str,
+        permission_set_arn: &str,
+        managed_policy_arns: Vec<String>,
+        config: WaiterConfig,
+    ) -> RusotoFuture<PermissionSetProvisioningStatus, ProvisioningWaitError> {
+        let client = self.clone();
+        let provision = ProvisionPermissionSetRequest {
+            instance_arn: instance_arn.to_owned(),
+            permission_set_arn: permission_set_arn.to_owned(),
+            target_id: None,
+            target_type: ProvisionTargetType::AllProvisionedAccounts,
+        };
+
+        let future =
+            attach_managed_policies(self, instance_arn, permission_set_arn, managed_policy_arns)
+                .and_then(move |_| client.provision_permission_set_and_wait(provision, config));
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: SsoAdmin + Clone + Send + 'static> PermissionSetWaiterExt for T {}
+
+/// Attaches the managed policies in order, stopping at the first failure.
+fn attach_managed_policies<C: SsoAdmin + Clone + Send + 'static>(
+    client: &C,
+    instance_arn: &str,
+    permission_set_arn: &str,
+    managed_policy_arns: Vec<String>,
+) -> impl Future<Item = (), Error = RusotoError<ProvisioningWaitError>> {
+    let client = client.clone();
+    let instance_arn = instance_arn.to_owned();
+    let permission_set_arn = permission_set_arn.to_owned();
+
+    future::loop_fn(
+        managed_policy_arns.into_iter(),
+        move |mut managed_policy_arns| {
+            let managed_policy_arn = match managed_policy_arns.next() {
+                Some(arn) => arn,
+                None => return future::Either::A(future::ok(Loop::Break(()))),
+            };
+            let request = AttachManagedPolicyToPermissionSetRequest {
+                instance_arn: instance_arn.clone(),
+                managed_policy_arn,
+                permission_set_arn: permission_set_arn.clone(),
+            };
+            future::Either::B(
+                client
+                    .attach_managed_policy_to_permission_set(request)
+                    .map_err(|e| {
+                        e.map_service(ProvisioningWaitError::AttachManagedPolicyToPermissionSet)
+                    })
+                    .map(move |_| Loop::Continue(managed_policy_arns)),
+            )
+        },
+    )
+}
@@ -0,0 +1,15175 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+#![allow(warnings)]
+
+use futures::future;
+use futures::Future;
+use rusoto_core::credential::ProvideAwsCredentials;
+use rusoto_core::region;
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
+use rusoto_core::{Client, RusotoError, RusotoFuture};
+use std::error::Error;
+use std::fmt;
+
+use rusoto_core::proto;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
+use serde_json;
+/// <p><p>These are IAM Identity Center identity store attributes that you can configure for use in attributes-based access control (ABAC). You can create permissions policies that determine who can access your Amazon Web Services resources based upon the configured attribute values. When you enable ABAC and specify <code>AccessControlAttributes</code>, IAM Identity Center passes the attribute values of the authenticated user into IAM for use in policy evaluation.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccessControlAttribute {
+    /// <p><p>The name of the attribute associated with your identities in your identity source. This is used to map a specified attribute in your identity source with an attribute in IAM Identity Center.</p></p>
+    #[serde(rename = "Key")]
+    pub key: String,
+    /// <p><p>The value used for mapping a specified attribute to an identity source.</p></p>
+    #[serde(rename = "Value")]
+    pub value: AccessControlAttributeValue,
+}
+
+/// <p><p>The value used for mapping a specified attribute to an identity source. For more information, see <a href="https://docs.aws.amazon.com/singlesignon/latest/userguide/attributemappingsconcept.html">Attribute mappings</a> in the <i>IAM Identity Center User Guide</i>.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccessControlAttributeValue {
+    /// <p><p>The identity source to use when mapping a specified attribute to IAM Identity Center.</p></p>
+    #[serde(rename = "Source")]
+    pub source: Vec<String>,
+}
+
+/// <p><p>The assignment that indicates a principal's limited access to a specified Amazon Web Services account with a specified permission set.</p> <note> <p>The term <i>principal</i> here refers to a user or group that is defined in IAM Identity Center.</p> </note></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AccountAssignment {
+    /// <p><p>The identifier of the Amazon Web Services account.</p></p>
+    #[serde(rename = "AccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// <p><p>The ARN of the permission set. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_set_arn: Option<String>,
+    /// <p><p>An identifier for an object in IAM Identity Center, such as a user or group. PrincipalIds are GUIDs (For example, f81d4fae-7dec-11d0-a765-00a0c91e6bf6). For more information about PrincipalIds in IAM Identity Center, see the <a href="/singlesignon/latest/IdentityStoreAPIReference/welcome.html">IAM Identity Center Identity Store API Reference</a>.</p></p>
+    #[serde(rename = "PrincipalId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_id: Option<String>,
+    /// <p><p>The entity type for which the assignment will be created.</p></p>
+    #[serde(rename = "PrincipalType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_type: Option<PrincipalType>,
+}
+
+/// <p><p>A structure that describes an assignment of an Amazon Web Services account to a principal and the permissions that principal has in the account.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AccountAssignmentForPrincipal {
+    /// <p><p>The account ID number of the Amazon Web Services account.</p></p>
+    #[serde(rename = "AccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// <p><p>The ARN of the IAM Identity Center permission set assigned to this principal for this Amazon Web Services account.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_set_arn: Option<String>,
+    /// <p><p>The ID of the principal.</p></p>
+    #[serde(rename = "PrincipalId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_id: Option<String>,
+    /// <p><p>The type of the principal.</p></p>
+    #[serde(rename = "PrincipalType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_type: Option<PrincipalType>,
+}
+
+/// <p><p>The status of the creation or deletion operation of an assignment that a principal needs to access an account.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AccountAssignmentOperationStatus {
+    /// <p><p>The date that the permission set was created.</p></p>
+    #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<EpochTimestamp>,
+    /// <p><p>The message that contains an error or exception in case of an operation failure.</p></p>
+    #[serde(rename = "FailureReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+    /// <p><p>The ARN of the permission set. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_set_arn: Option<String>,
+    /// <p><p>An identifier for an object in IAM Identity Center, such as a user or group. PrincipalIds are GUIDs (For example, f81d4fae-7dec-11d0-a765-00a0c91e6bf6). For more information about PrincipalIds in IAM Identity Center, see the <a href="/singlesignon/latest/IdentityStoreAPIReference/welcome.html">IAM Identity Center Identity Store API Reference</a>.</p></p>
+    #[serde(rename = "PrincipalId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_id: Option<String>,
+    /// <p><p>The entity type for which the assignment will be created.</p></p>
+    #[serde(rename = "PrincipalType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_type: Option<PrincipalType>,
+    /// <p><p>The identifier for tracking the request operation that is generated by the universally unique identifier (UUID) workflow.</p></p>
+    #[serde(rename = "RequestId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// <p><p>The status of the permission set provisioning process.</p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusValues>,
+    /// <p><p>TargetID is an Amazon Web Services account identifier, (For example, 123456789012).</p></p>
+    #[serde(rename = "TargetId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<String>,
+    /// <p><p>The entity type for which the assignment will be created.</p></p>
+    #[serde(rename = "TargetType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_type: Option<TargetType>,
+}
+
+/// <p><p>Provides information about the <a>AccountAssignment</a> creation request.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AccountAssignmentOperationStatusMetadata {
+    /// <p><p>The date that the permission set was created.</p></p>
+    #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<EpochTimestamp>,
+    /// <p><p>The identifier for tracking the request operation that is generated by the universally unique identifier (UUID) workflow.</p></p>
+    #[serde(rename = "RequestId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// <p><p>The status of the permission set provisioning process.</p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusValues>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActorPolicyDocument {}
+
+/// <p><p>A structure that describes an application that uses IAM Identity Center for access management.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Application {
+    /// <p><p>The Amazon Web Services account ID number of the application.</p></p>
+    #[serde(rename = "ApplicationAccount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_account: Option<String>,
+    /// <p><p>The ARN of the application.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_arn: Option<String>,
+    /// <p><p>The ARN of the application provider for this application.</p></p>
+    #[serde(rename = "ApplicationProviderArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_provider_arn: Option<String>,
+    /// <p><p>The date and time when the application was originally created.</p></p>
+    #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<EpochTimestamp>,
+    /// <p><p>The description of the application.</p></p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p><p>The ARN of the instance of IAM Identity Center that is configured with this application.</p></p>
+    #[serde(rename = "InstanceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_arn: Option<String>,
+    /// <p><p>The name of the application.</p></p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p><p>A structure that describes the options for the access portal associated with this application.</p></p>
+    #[serde(rename = "PortalOptions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub portal_options: Option<PortalOptions>,
+    /// <p><p>The current status of the application in this instance of IAM Identity Center.</p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ApplicationStatus>,
+}
+
+/// <p><p>A structure that describes an assignment of a principal to an application.</p></p>
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ApplicationAssignment {
+    /// <p><p>The ARN of the application that has principals assigned.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>The unique identifier of the principal assigned to the application.</p></p>
+    #[serde(rename = "PrincipalId")]
+    pub principal_id: String,
+    /// <p><p>The type of the principal assigned to the application.</p></p>
+    #[serde(rename = "PrincipalType")]
+    pub principal_type: PrincipalType,
+}
+
+/// <p><p>A structure that describes an application to which a principal is assigned.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ApplicationAssignmentForPrincipal {
+    /// <p><p>The ARN of the application to which the specified principal is assigned.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_arn: Option<String>,
+    /// <p><p>The unique identifier of the principal assigned to the application.</p></p>
+    #[serde(rename = "PrincipalId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_id: Option<String>,
+    /// <p><p>The type of the principal assigned to the application.</p></p>
+    #[serde(rename = "PrincipalType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_type: Option<PrincipalType>,
+}
+
+/// <p><p>A structure that describes a provider that can be used to connect an Amazon Web Services managed application or customer managed application to IAM Identity Center.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ApplicationProvider {
+    /// <p><p>The ARN of the application provider.</p></p>
+    #[serde(rename = "ApplicationProviderArn")]
+    pub application_provider_arn: String,
+    /// <p><p>A structure that describes how IAM Identity Center represents the application provider in the portal.</p></p>
+    #[serde(rename = "DisplayData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_data: Option<DisplayData>,
+    /// <p><p>The protocol that the application provider uses to perform federation.</p></p>
+    #[serde(rename = "FederationProtocol")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub federation_protocol: Option<FederationProtocol>,
+    /// <p><p>A structure that describes the application provider's resource server.</p></p>
+    #[serde(rename = "ResourceServerConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_server_config: Option<ResourceServerConfig>,
+}
+
+/// <p><p>The current status of the application in this instance of IAM Identity Center.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApplicationStatus {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApplicationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationStatus::Enabled => "ENABLED",
+            ApplicationStatus::Disabled => "DISABLED",
+            ApplicationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationStatus {
+    fn from(value: &'a str) -> ApplicationStatus {
+        match value {
+            "ENABLED" => ApplicationStatus::Enabled,
+            "DISABLED" => ApplicationStatus::Disabled,
+            _ => ApplicationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationStatus {
+    fn from(value: String) -> ApplicationStatus {
+        ApplicationStatus::from(value.as_str())
+    }
+}
+
+impl From<ApplicationStatus> for String {
+    fn from(value: ApplicationStatus) -> String {
+        match value {
+            ApplicationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ApplicationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApplicationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ApplicationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ApplicationStatus::from(value))
+    }
+}
+
+/// <p><p>Indicates whether this application is visible in the access portal.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApplicationVisibility {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApplicationVisibility {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationVisibility::Enabled => "ENABLED",
+            ApplicationVisibility::Disabled => "DISABLED",
+            ApplicationVisibility::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationVisibility {
+    fn from(value: &'a str) -> ApplicationVisibility {
+        match value {
+            "ENABLED" => ApplicationVisibility::Enabled,
+            "DISABLED" => ApplicationVisibility::Disabled,
+            _ => ApplicationVisibility::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationVisibility {
+    fn from(value: String) -> ApplicationVisibility {
+        ApplicationVisibility::from(value.as_str())
+    }
+}
+
+impl From<ApplicationVisibility> for String {
+    fn from(value: ApplicationVisibility) -> String {
+        match value {
+            ApplicationVisibility::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApplicationVisibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ApplicationVisibility {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApplicationVisibility {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ApplicationVisibility, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ApplicationVisibility::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct AttachCustomerManagedPolicyReferenceToPermissionSetRequest {
+    /// <p><p>Specifies the name and path of a customer managed policy. You must have an IAM policy that matches the name and path in each Amazon Web Services account where you want to deploy your permission set.</p></p>
+    #[serde(rename = "CustomerManagedPolicyReference")]
+    pub customer_managed_policy_reference: CustomerManagedPolicyReference,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. </p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the <code>PermissionSet</code>.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl AttachCustomerManagedPolicyReferenceToPermissionSetRequest {
+    /// Returns a builder for `AttachCustomerManagedPolicyReferenceToPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> AttachCustomerManagedPolicyReferenceToPermissionSetRequestBuilder {
+        AttachCustomerManagedPolicyReferenceToPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachCustomerManagedPolicyReferenceToPermissionSetRequest`](struct.AttachCustomerManagedPolicyReferenceToPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachCustomerManagedPolicyReferenceToPermissionSetRequestBuilder {
+    customer_managed_policy_reference: Option<CustomerManagedPolicyReference>,
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl AttachCustomerManagedPolicyReferenceToPermissionSetRequestBuilder {
+    /// Sets the [`customer_managed_policy_reference`](struct.AttachCustomerManagedPolicyReferenceToPermissionSetRequest.html#structfield.customer_managed_policy_reference) member.
+    pub fn customer_managed_policy_reference<T: Into<CustomerManagedPolicyReference>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.customer_managed_policy_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.AttachCustomerManagedPolicyReferenceToPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.AttachCustomerManagedPolicyReferenceToPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachCustomerManagedPolicyReferenceToPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        AttachCustomerManagedPolicyReferenceToPermissionSetRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(AttachCustomerManagedPolicyReferenceToPermissionSetRequest {
+            customer_managed_policy_reference: self.customer_managed_policy_reference.ok_or(
+                ::rusoto_core::BuildError::missing("customer_managed_policy_reference"),
+            )?,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AttachCustomerManagedPolicyReferenceToPermissionSetResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct AttachManagedPolicyToPermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The Amazon Web Services managed policy ARN to be attached to a permission set.</p></p>
+    #[serde(rename = "ManagedPolicyArn")]
+    pub managed_policy_arn: String,
+    /// <p><p>The ARN of the <a>PermissionSet</a> that the managed policy should be attached to.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl AttachManagedPolicyToPermissionSetRequest {
+    /// Returns a builder for `AttachManagedPolicyToPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> AttachManagedPolicyToPermissionSetRequestBuilder {
+        AttachManagedPolicyToPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachManagedPolicyToPermissionSetRequest`](struct.AttachManagedPolicyToPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachManagedPolicyToPermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    managed_policy_arn: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl AttachManagedPolicyToPermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.AttachManagedPolicyToPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`managed_policy_arn`](struct.AttachManagedPolicyToPermissionSetRequest.html#structfield.managed_policy_arn) member.
+    pub fn managed_policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.managed_policy_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.AttachManagedPolicyToPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachManagedPolicyToPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachManagedPolicyToPermissionSetRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AttachManagedPolicyToPermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            managed_policy_arn: self
+                .managed_policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("managed_policy_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AttachManagedPolicyToPermissionSetResponse {}
+
+/// <p><p>A structure that stores the details of the Amazon Web Services managed policy.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AttachedManagedPolicy {
+    /// <p><p>The ARN of the Amazon Web Services managed policy. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p><p>The name of the Amazon Web Services managed policy.</p></p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// <p><p>A structure that describes an authentication method that can be used by an application.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthenticationMethod {
+    /// <p><p>A structure that describes details for IAM authentication.</p></p>
+    #[serde(rename = "Iam")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iam: Option<IamAuthenticationMethod>,
+}
+
+/// <p><p>A structure that describes an authentication method and its type.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AuthenticationMethodItem {
+    /// <p><p>A structure that describes an authentication method. The contents of this structure is determined by the <code>AuthenticationMethodType</code>.</p></p>
+    #[serde(rename = "AuthenticationMethod")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_method: Option<AuthenticationMethod>,
+    /// <p><p>The type of authentication that is used by this method.</p></p>
+    #[serde(rename = "AuthenticationMethodType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_method_type: Option<AuthenticationMethodType>,
+}
+
+/// <p><p>The type of authentication that is used by this method.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthenticationMethodType {
+    /// <p><code>IAM</code></p>
+    Iam,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthenticationMethodType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthenticationMethodType::Iam => "IAM",
+            AuthenticationMethodType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthenticationMethodType {
+    fn from(value: &'a str) -> AuthenticationMethodType {
+        match value {
+            "IAM" => AuthenticationMethodType::Iam,
+            _ => AuthenticationMethodType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthenticationMethodType {
+    fn from(value: String) -> AuthenticationMethodType {
+        AuthenticationMethodType::from(value.as_str())
+    }
+}
+
+impl From<AuthenticationMethodType> for String {
+    fn from(value: AuthenticationMethodType) -> String {
+        match value {
+            AuthenticationMethodType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthenticationMethodType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AuthenticationMethodType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthenticationMethodType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthenticationMethodType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthenticationMethodType::from(value))
+    }
+}
+
+/// <p><p>A structure that defines configuration settings for an application that supports the OAuth 2.0 Authorization Code Grant.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthorizationCodeGrant {
+    /// <p><p>A list of URIs that are valid locations to redirect a user's browser after the user is authorized.</p></p>
+    #[serde(rename = "RedirectUris")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_uris: Option<Vec<String>>,
+}
+
+/// <p><p>A structure that describes a trusted token issuer and associates it with a set of authorized audiences.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthorizedTokenIssuer {
+    /// <p><p>An array list of authorized audiences, or applications, that can consume the tokens generated by the associated trusted token issuer.</p></p>
+    #[serde(rename = "AuthorizedAudiences")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_audiences: Option<Vec<String>>,
+    /// <p><p>The ARN of the trusted token issuer.</p></p>
+    #[serde(rename = "TrustedTokenIssuerArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_token_issuer_arn: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateAccountAssignmentRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the permission set that the admin wants to grant the principal access to.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+    /// <p><p>An identifier for an object in IAM Identity Center, such as a user or group. PrincipalIds are GUIDs (For example, f81d4fae-7dec-11d0-a765-00a0c91e6bf6). For more information about PrincipalIds in IAM Identity Center, see the <a href="/singlesignon/latest/IdentityStoreAPIReference/welcome.html">IAM Identity Center Identity Store API Reference</a>.</p></p>
+    #[serde(rename = "PrincipalId")]
+    pub principal_id: String,
+    /// <p><p>The entity type for which the assignment will be created.</p></p>
+    #[serde(rename = "PrincipalType")]
+    pub principal_type: PrincipalType,
+    /// <p><p>TargetID is an Amazon Web Services account identifier, (For example, 123456789012).</p></p>
+    #[serde(rename = "TargetId")]
+    pub target_id: String,
+    /// <p><p>The entity type for which the assignment will be created.</p></p>
+    #[serde(rename = "TargetType")]
+    pub target_type: TargetType,
+}
+
+impl CreateAccountAssignmentRequest {
+    /// Returns a builder for `CreateAccountAssignmentRequest`, checking its required members when built.
+    pub fn builder() -> CreateAccountAssignmentRequestBuilder {
+        CreateAccountAssignmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAccountAssignmentRequest`](struct.CreateAccountAssignmentRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAccountAssignmentRequestBuilder {
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+    principal_id: Option<String>,
+    principal_type: Option<PrincipalType>,
+    target_id: Option<String>,
+    target_type: Option<TargetType>,
+}
+
+impl CreateAccountAssignmentRequestBuilder {
+    /// Sets the [`instance_arn`](struct.CreateAccountAssignmentRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.CreateAccountAssignmentRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_id`](struct.CreateAccountAssignmentRequest.html#structfield.principal_id) member.
+    pub fn principal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_type`](struct.CreateAccountAssignmentRequest.html#structfield.principal_type) member.
+    pub fn principal_type<T: Into<PrincipalType>>(mut self, value: T) -> Self {
+        self.principal_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_id`](struct.CreateAccountAssignmentRequest.html#structfield.target_id) member.
+    pub fn target_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_type`](struct.CreateAccountAssignmentRequest.html#structfield.target_type) member.
+    pub fn target_type<T: Into<TargetType>>(mut self, value: T) -> Self {
+        self.target_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAccountAssignmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateAccountAssignmentRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAccountAssignmentRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+            principal_id: self
+                .principal_id
+                .ok_or(::rusoto_core::BuildError::missing("principal_id"))?,
+            principal_type: self
+                .principal_type
+                .ok_or(::rusoto_core::BuildError::missing("principal_type"))?,
+            target_id: self
+                .target_id
+                .ok_or(::rusoto_core::BuildError::missing("target_id"))?,
+            target_type: self
+                .target_type
+                .ok_or(::rusoto_core::BuildError::missing("target_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateAccountAssignmentResponse {
+    /// <p><p>The status object for the account assignment creation operation.</p></p>
+    #[serde(rename = "AccountAssignmentCreationStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_assignment_creation_status: Option<AccountAssignmentOperationStatus>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateApplicationAssignmentRequest {
+    /// <p><p>The ARN of the application provider under which the operation will run.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>An identifier for an object in IAM Identity Center, such as a user or group. PrincipalIds are GUIDs (For example, f81d4fae-7dec-11d0-a765-00a0c91e6bf6). For more information about PrincipalIds in IAM Identity Center, see the <a href="/singlesignon/latest/IdentityStoreAPIReference/welcome.html">IAM Identity Center Identity Store API Reference</a>.</p></p>
+    #[serde(rename = "PrincipalId")]
+    pub principal_id: String,
+    /// <p><p>The entity type for which the assignment will be created.</p></p>
+    #[serde(rename = "PrincipalType")]
+    pub principal_type: PrincipalType,
+}
+
+impl CreateApplicationAssignmentRequest {
+    /// Returns a builder for `CreateApplicationAssignmentRequest`, checking its required members when built.
+    pub fn builder() -> CreateApplicationAssignmentRequestBuilder {
+        CreateApplicationAssignmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApplicationAssignmentRequest`](struct.CreateApplicationAssignmentRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateApplicationAssignmentRequestBuilder {
+    application_arn: Option<String>,
+    principal_id: Option<String>,
+    principal_type: Option<PrincipalType>,
+}
+
+impl CreateApplicationAssignmentRequestBuilder {
+    /// Sets the [`application_arn`](struct.CreateApplicationAssignmentRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_id`](struct.CreateApplicationAssignmentRequest.html#structfield.principal_id) member.
+    pub fn principal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_type`](struct.CreateApplicationAssignmentRequest.html#structfield.principal_type) member.
+    pub fn principal_type<T: Into<PrincipalType>>(mut self, value: T) -> Self {
+        self.principal_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApplicationAssignmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateApplicationAssignmentRequest, ::rusoto_core::BuildError> {
+        Ok(CreateApplicationAssignmentRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            principal_id: self
+                .principal_id
+                .ok_or(::rusoto_core::BuildError::missing("principal_id"))?,
+            principal_type: self
+                .principal_type
+                .ok_or(::rusoto_core::BuildError::missing("principal_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateApplicationAssignmentResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CreateApplicationRequest {
+    /// <p><p>The ARN of the application provider under which the operation will run.</p></p>
+    #[serde(rename = "ApplicationProviderArn")]
+    pub application_provider_arn: String,
+    /// <p><p>Specifies a unique, case-sensitive ID that you provide to ensure the idempotency of the request. This lets you safely retry the request without accidentally performing the same operation a second time. Passing the same value to a later call to an operation requires that you also pass the same value for all other parameters. We recommend that you use a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID type of value</a>.</p> <p>If you don't provide this value, then Amazon Web Services generates a random one for you.</p> <p>If you retry the operation with the same <code>ClientToken</code>, but with different parameters, the retry fails with an <code>IdempotentParameterMismatch</code> error.</p></p>
+    #[serde(rename = "ClientToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+    /// <p><p>The description of the .</p></p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p><p>The ARN of the instance of IAM Identity Center under which the operation will run. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The name of the .</p></p>
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// <p><p>A structure that describes the options for the portal associated with an application.</p></p>
+    #[serde(rename = "PortalOptions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub portal_options: Option<PortalOptions>,
+    /// <p><p>Specifies whether the application is enabled or disabled.</p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ApplicationStatus>,
+    /// <p><p>Specifies tags to be attached to the application.</p></p>
+    #[serde(rename = "Tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+impl CreateApplicationRequest {
+    /// Returns a builder for `CreateApplicationRequest`, checking its required members when built.
+    pub fn builder() -> CreateApplicationRequestBuilder {
+        CreateApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApplicationRequest`](struct.CreateApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateApplicationRequestBuilder {
+    application_provider_arn: Option<String>,
+    client_token: Option<String>,
+    description: Option<String>,
+    instance_arn: Option<String>,
+    name: Option<String>,
+    portal_options: Option<PortalOptions>,
+    status: Option<ApplicationStatus>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateApplicationRequestBuilder {
+    /// Sets the [`application_provider_arn`](struct.CreateApplicationRequest.html#structfield.application_provider_arn) member.
+    pub fn application_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_token`](struct.CreateApplicationRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateApplicationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.CreateApplicationRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateApplicationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portal_options`](struct.CreateApplicationRequest.html#structfield.portal_options) member.
+    pub fn portal_options<T: Into<PortalOptions>>(mut self, value: T) -> Self {
+        self.portal_options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.CreateApplicationRequest.html#structfield.status) member.
+    pub fn status<T: Into<ApplicationStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateApplicationRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateApplicationRequest {
+            application_provider_arn: self.application_provider_arn.ok_or(
+                ::rusoto_core::BuildError::missing("application_provider_arn"),
+            )?,
+            client_token: self.client_token,
+            description: self.description,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            portal_options: self.portal_options,
+            status: self.status,
+            tags: self.tags,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateApplicationResponse {
+    /// <p><p>Specifies the ARN of the application.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_arn: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CreateInstanceAccessControlAttributeConfigurationRequest {
+    /// <p><p>Specifies the IAM Identity Center identity store attributes to add to your ABAC configuration. When using an external identity provider as an identity source, you can pass attributes through the SAML assertion. Doing so provides an alternative to configuring attributes from the IAM Identity Center identity store. If a SAML assertion passes any of these attributes, IAM Identity Center will replace the attribute value with the value from the IAM Identity Center identity store.</p></p>
+    #[serde(rename = "InstanceAccessControlAttributeConfiguration")]
+    pub instance_access_control_attribute_configuration:
+        InstanceAccessControlAttributeConfiguration,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+}
+
+impl CreateInstanceAccessControlAttributeConfigurationRequest {
+    /// Returns a builder for `CreateInstanceAccessControlAttributeConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> CreateInstanceAccessControlAttributeConfigurationRequestBuilder {
+        CreateInstanceAccessControlAttributeConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateInstanceAccessControlAttributeConfigurationRequest`](struct.CreateInstanceAccessControlAttributeConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateInstanceAccessControlAttributeConfigurationRequestBuilder {
+    instance_access_control_attribute_configuration:
+        Option<InstanceAccessControlAttributeConfiguration>,
+    instance_arn: Option<String>,
+}
+
+impl CreateInstanceAccessControlAttributeConfigurationRequestBuilder {
+    /// Sets the [`instance_access_control_attribute_configuration`](struct.CreateInstanceAccessControlAttributeConfigurationRequest.html#structfield.instance_access_control_attribute_configuration) member.
+    pub fn instance_access_control_attribute_configuration<
+        T: Into<InstanceAccessControlAttributeConfiguration>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.instance_access_control_attribute_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.CreateInstanceAccessControlAttributeConfigurationRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateInstanceAccessControlAttributeConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        CreateInstanceAccessControlAttributeConfigurationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(CreateInstanceAccessControlAttributeConfigurationRequest {
+            instance_access_control_attribute_configuration: self
+                .instance_access_control_attribute_configuration
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "instance_access_control_attribute_configuration",
+                ))?,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateInstanceAccessControlAttributeConfigurationResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CreateInstanceRequest {
+    /// <p><p>Specifies a unique, case-sensitive ID that you provide to ensure the idempotency of the request. This lets you safely retry the request without accidentally performing the same operation a second time. Passing the same value to a later call to an operation requires that you also pass the same value for all other parameters. We recommend that you use a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID type of value</a>.</p> <p>If you don't provide this value, then Amazon Web Services generates a random one for you.</p> <p>If you retry the operation with the same <code>ClientToken</code>, but with different parameters, the retry fails with an <code>IdempotentParameterMismatch</code> error.</p></p>
+    #[serde(rename = "ClientToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+    /// <p><p>The name of the instance of IAM Identity Center.</p></p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p><p>Specifies tags to be attached to the instance of IAM Identity Center.</p></p>
+    #[serde(rename = "Tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+impl CreateInstanceRequest {
+    /// Returns a builder for `CreateInstanceRequest`, checking its required members when built.
+    pub fn builder() -> CreateInstanceRequestBuilder {
+        CreateInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateInstanceRequest`](struct.CreateInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateInstanceRequestBuilder {
+    client_token: Option<String>,
+    name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateInstanceRequestBuilder {
+    /// Sets the [`client_token`](struct.CreateInstanceRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateInstanceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateInstanceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(CreateInstanceRequest {
+            client_token: self.client_token,
+            name: self.name,
+            tags: self.tags,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateInstanceResponse {
+    /// <p><p>The ARN of the instance of IAM Identity Center under which the operation will run. </p> <p>For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_arn: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CreatePermissionSetRequest {
+    /// <p><p>The description of the <a>PermissionSet</a>.</p></p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The name of the <a>PermissionSet</a>.</p></p>
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// <p><p>Used to redirect users within the application during the federation authentication process.</p></p>
+    #[serde(rename = "RelayState")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relay_state: Option<String>,
+    /// <p><p>The length of time that the application user sessions are valid in the ISO-8601 standard.</p></p>
+    #[serde(rename = "SessionDuration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_duration: Option<String>,
+    /// <p><p>The tags to attach to the new <a>PermissionSet</a>.</p></p>
+    #[serde(rename = "Tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+impl CreatePermissionSetRequest {
+    /// Returns a builder for `CreatePermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> CreatePermissionSetRequestBuilder {
+        CreatePermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePermissionSetRequest`](struct.CreatePermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePermissionSetRequestBuilder {
+    description: Option<String>,
+    instance_arn: Option<String>,
+    name: Option<String>,
+    relay_state: Option<String>,
+    session_duration: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreatePermissionSetRequestBuilder {
+    /// Sets the [`description`](struct.CreatePermissionSetRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.CreatePermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreatePermissionSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relay_state`](struct.CreatePermissionSetRequest.html#structfield.relay_state) member.
+    pub fn relay_state<T: Into<String>>(mut self, value: T) -> Self {
+        self.relay_state = Some(value.into());
+        self
+    }
+
+    /// Sets the [`session_duration`](struct.CreatePermissionSetRequest.html#structfield.session_duration) member.
+    pub fn session_duration<T: Into<String>>(mut self, value: T) -> Self {
+        self.session_duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreatePermissionSetRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePermissionSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePermissionSetRequest {
+            description: self.description,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            relay_state: self.relay_state,
+            session_duration: self.session_duration,
+            tags: self.tags,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreatePermissionSetResponse {
+    /// <p><p>Defines the level of access on an Amazon Web Services account.</p></p>
+    #[serde(rename = "PermissionSet")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_set: Option<PermissionSet>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateTrustedTokenIssuerRequest {
+    /// <p><p>Specifies a unique, case-sensitive ID that you provide to ensure the idempotency of the request. This lets you safely retry the request without accidentally performing the same operation a second time. Passing the same value to a later call to an operation requires that you also pass the same value for all other parameters. We recommend that you use a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID type of value.</a>.</p> <p>If you don't provide this value, then Amazon Web Services generates a random one for you.</p> <p>If you retry the operation with the same <code>ClientToken</code>, but with different parameters, the retry fails with an <code>IdempotentParameterMismatch</code> error.</p></p>
+    #[serde(rename = "ClientToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+    /// <p><p>Specifies the ARN of the instance of IAM Identity Center to contain the new trusted token issuer configuration.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>Specifies the name of the new trusted token issuer configuration.</p></p>
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// <p><p>Specifies tags to be attached to the new trusted token issuer configuration.</p></p>
+    #[serde(rename = "Tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+    /// <p><p>Specifies settings that apply to the new trusted token issuer configuration. The settings that are available depend on what <code>TrustedTokenIssuerType</code> you specify.</p></p>
+    #[serde(rename = "TrustedTokenIssuerConfiguration")]
+    pub trusted_token_issuer_configuration: TrustedTokenIssuerConfiguration,
+    /// <p><p>Specifies the type of the new trusted token issuer.</p></p>
+    #[serde(rename = "TrustedTokenIssuerType")]
+    pub trusted_token_issuer_type: TrustedTokenIssuerType,
+}
+
+impl CreateTrustedTokenIssuerRequest {
+    /// Returns a builder for `CreateTrustedTokenIssuerRequest`, checking its required members when built.
+    pub fn builder() -> CreateTrustedTokenIssuerRequestBuilder {
+        CreateTrustedTokenIssuerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTrustedTokenIssuerRequest`](struct.CreateTrustedTokenIssuerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTrustedTokenIssuerRequestBuilder {
+    client_token: Option<String>,
+    instance_arn: Option<String>,
+    name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    trusted_token_issuer_configuration: Option<TrustedTokenIssuerConfiguration>,
+    trusted_token_issuer_type: Option<TrustedTokenIssuerType>,
+}
+
+impl CreateTrustedTokenIssuerRequestBuilder {
+    /// Sets the [`client_token`](struct.CreateTrustedTokenIssuerRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.CreateTrustedTokenIssuerRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateTrustedTokenIssuerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTrustedTokenIssuerRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trusted_token_issuer_configuration`](struct.CreateTrustedTokenIssuerRequest.html#structfield.trusted_token_issuer_configuration) member.
+    pub fn trusted_token_issuer_configuration<T: Into<TrustedTokenIssuerConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.trusted_token_issuer_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trusted_token_issuer_type`](struct.CreateTrustedTokenIssuerRequest.html#structfield.trusted_token_issuer_type) member.
+    pub fn trusted_token_issuer_type<T: Into<TrustedTokenIssuerType>>(mut self, value: T) -> Self {
+        self.trusted_token_issuer_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTrustedTokenIssuerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateTrustedTokenIssuerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTrustedTokenIssuerRequest {
+            client_token: self.client_token,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+            trusted_token_issuer_configuration: self.trusted_token_issuer_configuration.ok_or(
+                ::rusoto_core::BuildError::missing("trusted_token_issuer_configuration"),
+            )?,
+            trusted_token_issuer_type: self.trusted_token_issuer_type.ok_or(
+                ::rusoto_core::BuildError::missing("trusted_token_issuer_type"),
+            )?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateTrustedTokenIssuerResponse {
+    /// <p><p>The ARN of the new trusted token issuer configuration.</p></p>
+    #[serde(rename = "TrustedTokenIssuerArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_token_issuer_arn: Option<String>,
+}
+
+/// <p><p>Specifies the name and path of a customer managed policy. You must have an IAM policy that matches the name and path in each Amazon Web Services account where you want to deploy your permission set.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomerManagedPolicyReference {
+    /// <p><p>The name of the IAM policy that you have configured in each account where you want to deploy your permission set.</p></p>
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// <p><p>The path to the IAM policy that you have configured in each account where you want to deploy your permission set. The default is <code>/</code>. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_identifiers.html#identifiers-friendly-names">Friendly names and paths</a> in the <i>IAM User Guide</i>.</p></p>
+    #[serde(rename = "Path")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteAccountAssignmentRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the permission set that will be used to remove access.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+    /// <p><p>An identifier for an object in IAM Identity Center, such as a user or group. PrincipalIds are GUIDs (For example, f81d4fae-7dec-11d0-a765-00a0c91e6bf6). For more information about PrincipalIds in IAM Identity Center, see the <a href="/singlesignon/latest/IdentityStoreAPIReference/welcome.html">IAM Identity Center Identity Store API Reference</a>.</p></p>
+    #[serde(rename = "PrincipalId")]
+    pub principal_id: String,
+    /// <p><p>The entity type for which the assignment will be deleted.</p></p>
+    #[serde(rename = "PrincipalType")]
+    pub principal_type: PrincipalType,
+    /// <p><p>TargetID is an Amazon Web Services account identifier, (For example, 123456789012).</p></p>
+    #[serde(rename = "TargetId")]
+    pub target_id: String,
+    /// <p><p>The entity type for which the assignment will be deleted.</p></p>
+    #[serde(rename = "TargetType")]
+    pub target_type: TargetType,
+}
+
+impl DeleteAccountAssignmentRequest {
+    /// Returns a builder for `DeleteAccountAssignmentRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAccountAssignmentRequestBuilder {
+        DeleteAccountAssignmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAccountAssignmentRequest`](struct.DeleteAccountAssignmentRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAccountAssignmentRequestBuilder {
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+    principal_id: Option<String>,
+    principal_type: Option<PrincipalType>,
+    target_id: Option<String>,
+    target_type: Option<TargetType>,
+}
+
+impl DeleteAccountAssignmentRequestBuilder {
+    /// Sets the [`instance_arn`](struct.DeleteAccountAssignmentRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.DeleteAccountAssignmentRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_id`](struct.DeleteAccountAssignmentRequest.html#structfield.principal_id) member.
+    pub fn principal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_type`](struct.DeleteAccountAssignmentRequest.html#structfield.principal_type) member.
+    pub fn principal_type<T: Into<PrincipalType>>(mut self, value: T) -> Self {
+        self.principal_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_id`](struct.DeleteAccountAssignmentRequest.html#structfield.target_id) member.
+    pub fn target_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_type`](struct.DeleteAccountAssignmentRequest.html#structfield.target_type) member.
+    pub fn target_type<T: Into<TargetType>>(mut self, value: T) -> Self {
+        self.target_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAccountAssignmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAccountAssignmentRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAccountAssignmentRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+            principal_id: self
+                .principal_id
+                .ok_or(::rusoto_core::BuildError::missing("principal_id"))?,
+            principal_type: self
+                .principal_type
+                .ok_or(::rusoto_core::BuildError::missing("principal_type"))?,
+            target_id: self
+                .target_id
+                .ok_or(::rusoto_core::BuildError::missing("target_id"))?,
+            target_type: self
+                .target_type
+                .ok_or(::rusoto_core::BuildError::missing("target_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteAccountAssignmentResponse {
+    /// <p><p>The status object for the account assignment deletion operation.</p></p>
+    #[serde(rename = "AccountAssignmentDeletionStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_assignment_deletion_status: Option<AccountAssignmentOperationStatus>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteApplicationAccessScopeRequest {
+    /// <p><p>Specifies the ARN of the application with the access scope to delete.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies the name of the access scope to remove from the application.</p></p>
+    #[serde(rename = "Scope")]
+    pub scope: String,
+}
+
+impl DeleteApplicationAccessScopeRequest {
+    /// Returns a builder for `DeleteApplicationAccessScopeRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationAccessScopeRequestBuilder {
+        DeleteApplicationAccessScopeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationAccessScopeRequest`](struct.DeleteApplicationAccessScopeRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationAccessScopeRequestBuilder {
+    application_arn: Option<String>,
+    scope: Option<String>,
+}
+
+impl DeleteApplicationAccessScopeRequestBuilder {
+    /// Sets the [`application_arn`](struct.DeleteApplicationAccessScopeRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scope`](struct.DeleteApplicationAccessScopeRequest.html#structfield.scope) member.
+    pub fn scope<T: Into<String>>(mut self, value: T) -> Self {
+        self.scope = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationAccessScopeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApplicationAccessScopeRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApplicationAccessScopeRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            scope: self
+                .scope
+                .ok_or(::rusoto_core::BuildError::missing("scope"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteApplicationAssignmentRequest {
+    /// <p><p>Specifies the ARN of the application.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>An identifier for an object in IAM Identity Center, such as a user or group. PrincipalIds are GUIDs (For example, f81d4fae-7dec-11d0-a765-00a0c91e6bf6). For more information about PrincipalIds in IAM Identity Center, see the <a href="/singlesignon/latest/IdentityStoreAPIReference/welcome.html">IAM Identity Center Identity Store API Reference</a>.</p></p>
+    #[serde(rename = "PrincipalId")]
+    pub principal_id: String,
+    /// <p><p>The entity type for which the assignment will be deleted.</p></p>
+    #[serde(rename = "PrincipalType")]
+    pub principal_type: PrincipalType,
+}
+
+impl DeleteApplicationAssignmentRequest {
+    /// Returns a builder for `DeleteApplicationAssignmentRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationAssignmentRequestBuilder {
+        DeleteApplicationAssignmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationAssignmentRequest`](struct.DeleteApplicationAssignmentRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationAssignmentRequestBuilder {
+    application_arn: Option<String>,
+    principal_id: Option<String>,
+    principal_type: Option<PrincipalType>,
+}
+
+impl DeleteApplicationAssignmentRequestBuilder {
+    /// Sets the [`application_arn`](struct.DeleteApplicationAssignmentRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_id`](struct.DeleteApplicationAssignmentRequest.html#structfield.principal_id) member.
+    pub fn principal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_type`](struct.DeleteApplicationAssignmentRequest.html#structfield.principal_type) member.
+    pub fn principal_type<T: Into<PrincipalType>>(mut self, value: T) -> Self {
+        self.principal_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationAssignmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApplicationAssignmentRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApplicationAssignmentRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            principal_id: self
+                .principal_id
+                .ok_or(::rusoto_core::BuildError::missing("principal_id"))?,
+            principal_type: self
+                .principal_type
+                .ok_or(::rusoto_core::BuildError::missing("principal_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteApplicationAssignmentResponse {}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteApplicationAuthenticationMethodRequest {
+    /// <p><p>Specifies the ARN of the application with the authentication method to delete.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies the authentication method type to delete from the application.</p></p>
+    #[serde(rename = "AuthenticationMethodType")]
+    pub authentication_method_type: AuthenticationMethodType,
+}
+
+impl DeleteApplicationAuthenticationMethodRequest {
+    /// Returns a builder for `DeleteApplicationAuthenticationMethodRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationAuthenticationMethodRequestBuilder {
+        DeleteApplicationAuthenticationMethodRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationAuthenticationMethodRequest`](struct.DeleteApplicationAuthenticationMethodRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationAuthenticationMethodRequestBuilder {
+    application_arn: Option<String>,
+    authentication_method_type: Option<AuthenticationMethodType>,
+}
+
+impl DeleteApplicationAuthenticationMethodRequestBuilder {
+    /// Sets the [`application_arn`](struct.DeleteApplicationAuthenticationMethodRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authentication_method_type`](struct.DeleteApplicationAuthenticationMethodRequest.html#structfield.authentication_method_type) member.
+    pub fn authentication_method_type<T: Into<AuthenticationMethodType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.authentication_method_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationAuthenticationMethodRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DeleteApplicationAuthenticationMethodRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DeleteApplicationAuthenticationMethodRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            authentication_method_type: self.authentication_method_type.ok_or(
+                ::rusoto_core::BuildError::missing("authentication_method_type"),
+            )?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteApplicationGrantRequest {
+    /// <p><p>Specifies the ARN of the application with the grant to delete.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies the type of grant to delete from the application.</p></p>
+    #[serde(rename = "GrantType")]
+    pub grant_type: GrantType,
+}
+
+impl DeleteApplicationGrantRequest {
+    /// Returns a builder for `DeleteApplicationGrantRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationGrantRequestBuilder {
+        DeleteApplicationGrantRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationGrantRequest`](struct.DeleteApplicationGrantRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationGrantRequestBuilder {
+    application_arn: Option<String>,
+    grant_type: Option<GrantType>,
+}
+
+impl DeleteApplicationGrantRequestBuilder {
+    /// Sets the [`application_arn`](struct.DeleteApplicationGrantRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant_type`](struct.DeleteApplicationGrantRequest.html#structfield.grant_type) member.
+    pub fn grant_type<T: Into<GrantType>>(mut self, value: T) -> Self {
+        self.grant_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationGrantRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApplicationGrantRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApplicationGrantRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            grant_type: self
+                .grant_type
+                .ok_or(::rusoto_core::BuildError::missing("grant_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteApplicationRequest {
+    /// <p><p>Specifies the ARN of the application. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>. </p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+}
+
+impl DeleteApplicationRequest {
+    /// Returns a builder for `DeleteApplicationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationRequestBuilder {
+        DeleteApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationRequest`](struct.DeleteApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationRequestBuilder {
+    application_arn: Option<String>,
+}
+
+impl DeleteApplicationRequestBuilder {
+    /// Sets the [`application_arn`](struct.DeleteApplicationRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApplicationRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteApplicationResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteInlinePolicyFromPermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the permission set that will be used to remove access.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl DeleteInlinePolicyFromPermissionSetRequest {
+    /// Returns a builder for `DeleteInlinePolicyFromPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInlinePolicyFromPermissionSetRequestBuilder {
+        DeleteInlinePolicyFromPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInlinePolicyFromPermissionSetRequest`](struct.DeleteInlinePolicyFromPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInlinePolicyFromPermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl DeleteInlinePolicyFromPermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.DeleteInlinePolicyFromPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.DeleteInlinePolicyFromPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInlinePolicyFromPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteInlinePolicyFromPermissionSetRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteInlinePolicyFromPermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteInlinePolicyFromPermissionSetResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteInstanceAccessControlAttributeConfigurationRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+}
+
+impl DeleteInstanceAccessControlAttributeConfigurationRequest {
+    /// Returns a builder for `DeleteInstanceAccessControlAttributeConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInstanceAccessControlAttributeConfigurationRequestBuilder {
+        DeleteInstanceAccessControlAttributeConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInstanceAccessControlAttributeConfigurationRequest`](struct.DeleteInstanceAccessControlAttributeConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInstanceAccessControlAttributeConfigurationRequestBuilder {
+    instance_arn: Option<String>,
+}
+
+impl DeleteInstanceAccessControlAttributeConfigurationRequestBuilder {
+    /// Sets the [`instance_arn`](struct.DeleteInstanceAccessControlAttributeConfigurationRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInstanceAccessControlAttributeConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DeleteInstanceAccessControlAttributeConfigurationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DeleteInstanceAccessControlAttributeConfigurationRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteInstanceAccessControlAttributeConfigurationResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteInstanceRequest {
+    /// <p><p>The ARN of the instance of IAM Identity Center under which the operation will run.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+}
+
+impl DeleteInstanceRequest {
+    /// Returns a builder for `DeleteInstanceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInstanceRequestBuilder {
+        DeleteInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInstanceRequest`](struct.DeleteInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInstanceRequestBuilder {
+    instance_arn: Option<String>,
+}
+
+impl DeleteInstanceRequestBuilder {
+    /// Sets the [`instance_arn`](struct.DeleteInstanceRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteInstanceRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteInstanceResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeletePermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the permission set that should be deleted.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl DeletePermissionSetRequest {
+    /// Returns a builder for `DeletePermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> DeletePermissionSetRequestBuilder {
+        DeletePermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePermissionSetRequest`](struct.DeletePermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl DeletePermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.DeletePermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.DeletePermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePermissionSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeletePermissionSetResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeletePermissionsBoundaryFromPermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. </p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the <code>PermissionSet</code>.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl DeletePermissionsBoundaryFromPermissionSetRequest {
+    /// Returns a builder for `DeletePermissionsBoundaryFromPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> DeletePermissionsBoundaryFromPermissionSetRequestBuilder {
+        DeletePermissionsBoundaryFromPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePermissionsBoundaryFromPermissionSetRequest`](struct.DeletePermissionsBoundaryFromPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePermissionsBoundaryFromPermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl DeletePermissionsBoundaryFromPermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.DeletePermissionsBoundaryFromPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.DeletePermissionsBoundaryFromPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePermissionsBoundaryFromPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DeletePermissionsBoundaryFromPermissionSetRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DeletePermissionsBoundaryFromPermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeletePermissionsBoundaryFromPermissionSetResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteTrustedTokenIssuerRequest {
+    /// <p><p>Specifies the ARN of the trusted token issuer configuration to delete.</p></p>
+    #[serde(rename = "TrustedTokenIssuerArn")]
+    pub trusted_token_issuer_arn: String,
+}
+
+impl DeleteTrustedTokenIssuerRequest {
+    /// Returns a builder for `DeleteTrustedTokenIssuerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTrustedTokenIssuerRequestBuilder {
+        DeleteTrustedTokenIssuerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTrustedTokenIssuerRequest`](struct.DeleteTrustedTokenIssuerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTrustedTokenIssuerRequestBuilder {
+    trusted_token_issuer_arn: Option<String>,
+}
+
+impl DeleteTrustedTokenIssuerRequestBuilder {
+    /// Sets the [`trusted_token_issuer_arn`](struct.DeleteTrustedTokenIssuerRequest.html#structfield.trusted_token_issuer_arn) member.
+    pub fn trusted_token_issuer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.trusted_token_issuer_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTrustedTokenIssuerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteTrustedTokenIssuerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTrustedTokenIssuerRequest {
+            trusted_token_issuer_arn: self.trusted_token_issuer_arn.ok_or(
+                ::rusoto_core::BuildError::missing("trusted_token_issuer_arn"),
+            )?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteTrustedTokenIssuerResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DescribeAccountAssignmentCreationStatusRequest {
+    /// <p><p>The identifier that is used to track the request operation progress.</p></p>
+    #[serde(rename = "AccountAssignmentCreationRequestId")]
+    pub account_assignment_creation_request_id: String,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+}
+
+impl DescribeAccountAssignmentCreationStatusRequest {
+    /// Returns a builder for `DescribeAccountAssignmentCreationStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAccountAssignmentCreationStatusRequestBuilder {
+        DescribeAccountAssignmentCreationStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAccountAssignmentCreationStatusRequest`](struct.DescribeAccountAssignmentCreationStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAccountAssignmentCreationStatusRequestBuilder {
+    account_assignment_creation_request_id: Option<String>,
+    instance_arn: Option<String>,
+}
+
+impl DescribeAccountAssignmentCreationStatusRequestBuilder {
+    /// Sets the [`account_assignment_creation_request_id`](struct.DescribeAccountAssignmentCreationStatusRequest.html#structfield.account_assignment_creation_request_id) member.
+    pub fn account_assignment_creation_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_assignment_creation_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.DescribeAccountAssignmentCreationStatusRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAccountAssignmentCreationStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeAccountAssignmentCreationStatusRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeAccountAssignmentCreationStatusRequest {
+            account_assignment_creation_request_id: self
+                .account_assignment_creation_request_id
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "account_assignment_creation_request_id",
+                ))?,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeAccountAssignmentCreationStatusResponse {
+    /// <p><p>The status object for the account assignment creation operation.</p></p>
+    #[serde(rename = "AccountAssignmentCreationStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_assignment_creation_status: Option<AccountAssignmentOperationStatus>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DescribeAccountAssignmentDeletionStatusRequest {
+    /// <p><p>The identifier that is used to track the request operation progress.</p></p>
+    #[serde(rename = "AccountAssignmentDeletionRequestId")]
+    pub account_assignment_deletion_request_id: String,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+}
+
+impl DescribeAccountAssignmentDeletionStatusRequest {
+    /// Returns a builder for `DescribeAccountAssignmentDeletionStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAccountAssignmentDeletionStatusRequestBuilder {
+        DescribeAccountAssignmentDeletionStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAccountAssignmentDeletionStatusRequest`](struct.DescribeAccountAssignmentDeletionStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAccountAssignmentDeletionStatusRequestBuilder {
+    account_assignment_deletion_request_id: Option<String>,
+    instance_arn: Option<String>,
+}
+
+impl DescribeAccountAssignmentDeletionStatusRequestBuilder {
+    /// Sets the [`account_assignment_deletion_request_id`](struct.DescribeAccountAssignmentDeletionStatusRequest.html#structfield.account_assignment_deletion_request_id) member.
+    pub fn account_assignment_deletion_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_assignment_deletion_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.DescribeAccountAssignmentDeletionStatusRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAccountAssignmentDeletionStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeAccountAssignmentDeletionStatusRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeAccountAssignmentDeletionStatusRequest {
+            account_assignment_deletion_request_id: self
+                .account_assignment_deletion_request_id
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "account_assignment_deletion_request_id",
+                ))?,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeAccountAssignmentDeletionStatusResponse {
+    /// <p><p>The status object for the account assignment deletion operation.</p></p>
+    #[serde(rename = "AccountAssignmentDeletionStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_assignment_deletion_status: Option<AccountAssignmentOperationStatus>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DescribeApplicationAssignmentRequest {
+    /// <p><p>Specifies the ARN of the application. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>An identifier for an object in IAM Identity Center, such as a user or group. PrincipalIds are GUIDs (For example, f81d4fae-7dec-11d0-a765-00a0c91e6bf6). For more information about PrincipalIds in IAM Identity Center, see the <a href="/singlesignon/latest/IdentityStoreAPIReference/welcome.html">IAM Identity Center Identity Store API Reference</a>.</p></p>
+    #[serde(rename = "PrincipalId")]
+    pub principal_id: String,
+    /// <p><p>The entity type for which the assignment will be created.</p></p>
+    #[serde(rename = "PrincipalType")]
+    pub principal_type: PrincipalType,
+}
+
+impl DescribeApplicationAssignmentRequest {
+    /// Returns a builder for `DescribeApplicationAssignmentRequest`, checking its required members when built.
+    pub fn builder() -> DescribeApplicationAssignmentRequestBuilder {
+        DescribeApplicationAssignmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeApplicationAssignmentRequest`](struct.DescribeApplicationAssignmentRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeApplicationAssignmentRequestBuilder {
+    application_arn: Option<String>,
+    principal_id: Option<String>,
+    principal_type: Option<PrincipalType>,
+}
+
+impl DescribeApplicationAssignmentRequestBuilder {
+    /// Sets the [`application_arn`](struct.DescribeApplicationAssignmentRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_id`](struct.DescribeApplicationAssignmentRequest.html#structfield.principal_id) member.
+    pub fn principal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_type`](struct.DescribeApplicationAssignmentRequest.html#structfield.principal_type) member.
+    pub fn principal_type<T: Into<PrincipalType>>(mut self, value: T) -> Self {
+        self.principal_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeApplicationAssignmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeApplicationAssignmentRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeApplicationAssignmentRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            principal_id: self
+                .principal_id
+                .ok_or(::rusoto_core::BuildError::missing("principal_id"))?,
+            principal_type: self
+                .principal_type
+                .ok_or(::rusoto_core::BuildError::missing("principal_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeApplicationAssignmentResponse {
+    /// <p><p>Specifies the ARN of the application. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_arn: Option<String>,
+    /// <p><p>An identifier for an object in IAM Identity Center, such as a user or group. PrincipalIds are GUIDs (For example, f81d4fae-7dec-11d0-a765-00a0c91e6bf6). For more information about PrincipalIds in IAM Identity Center, see the <a href="/singlesignon/latest/IdentityStoreAPIReference/welcome.html">IAM Identity Center Identity Store API Reference</a>.</p></p>
+    #[serde(rename = "PrincipalId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_id: Option<String>,
+    /// <p><p>The entity type for which the assignment will be created.</p></p>
+    #[serde(rename = "PrincipalType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_type: Option<PrincipalType>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DescribeApplicationProviderRequest {
+    /// <p><p>Specifies the ARN of the application provider for which you want details.</p></p>
+    #[serde(rename = "ApplicationProviderArn")]
+    pub application_provider_arn: String,
+}
+
+impl DescribeApplicationProviderRequest {
+    /// Returns a builder for `DescribeApplicationProviderRequest`, checking its required members when built.
+    pub fn builder() -> DescribeApplicationProviderRequestBuilder {
+        DescribeApplicationProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeApplicationProviderRequest`](struct.DescribeApplicationProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeApplicationProviderRequestBuilder {
+    application_provider_arn: Option<String>,
+}
+
+impl DescribeApplicationProviderRequestBuilder {
+    /// Sets the [`application_provider_arn`](struct.DescribeApplicationProviderRequest.html#structfield.application_provider_arn) member.
+    pub fn application_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeApplicationProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeApplicationProviderRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeApplicationProviderRequest {
+            application_provider_arn: self.application_provider_arn.ok_or(
+                ::rusoto_core::BuildError::missing("application_provider_arn"),
+            )?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeApplicationProviderResponse {
+    /// <p><p>The ARN of the application provider.</p></p>
+    #[serde(rename = "ApplicationProviderArn")]
+    pub application_provider_arn: String,
+    /// <p><p>A structure with details about the display data for the application provider.</p></p>
+    #[serde(rename = "DisplayData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_data: Option<DisplayData>,
+    /// <p><p>The protocol used to federate to the application provider.</p></p>
+    #[serde(rename = "FederationProtocol")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub federation_protocol: Option<FederationProtocol>,
+    /// <p><p>A structure with details about the receiving application.</p></p>
+    #[serde(rename = "ResourceServerConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_server_config: Option<ResourceServerConfig>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DescribeApplicationRequest {
+    /// <p><p>Specifies the ARN of the application. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+}
+
+impl DescribeApplicationRequest {
+    /// Returns a builder for `DescribeApplicationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeApplicationRequestBuilder {
+        DescribeApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeApplicationRequest`](struct.DescribeApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeApplicationRequestBuilder {
+    application_arn: Option<String>,
+}
+
+impl DescribeApplicationRequestBuilder {
+    /// Sets the [`application_arn`](struct.DescribeApplicationRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeApplicationRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeApplicationResponse {
+    /// <p><p>The account ID.</p></p>
+    #[serde(rename = "ApplicationAccount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_account: Option<String>,
+    /// <p><p>Specifies the ARN of the application.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_arn: Option<String>,
+    /// <p><p>The ARN of the application provider under which the operation will run.</p></p>
+    #[serde(rename = "ApplicationProviderArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_provider_arn: Option<String>,
+    /// <p><p>The date the application was created.</p></p>
+    #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<EpochTimestamp>,
+    /// <p><p>The description of the .</p></p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p><p>The ARN of the IAM Identity Center application under which the operation will run. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_arn: Option<String>,
+    /// <p><p>The application name.</p></p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p><p>A structure that describes the options for the portal associated with an application.</p></p>
+    #[serde(rename = "PortalOptions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub portal_options: Option<PortalOptions>,
+    /// <p><p>Specifies whether the application is enabled or disabled.</p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ApplicationStatus>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DescribeInstanceAccessControlAttributeConfigurationRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+}
+
+impl DescribeInstanceAccessControlAttributeConfigurationRequest {
+    /// Returns a builder for `DescribeInstanceAccessControlAttributeConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeInstanceAccessControlAttributeConfigurationRequestBuilder {
+        DescribeInstanceAccessControlAttributeConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeInstanceAccessControlAttributeConfigurationRequest`](struct.DescribeInstanceAccessControlAttributeConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeInstanceAccessControlAttributeConfigurationRequestBuilder {
+    instance_arn: Option<String>,
+}
+
+impl DescribeInstanceAccessControlAttributeConfigurationRequestBuilder {
+    /// Sets the [`instance_arn`](struct.DescribeInstanceAccessControlAttributeConfigurationRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeInstanceAccessControlAttributeConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeInstanceAccessControlAttributeConfigurationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeInstanceAccessControlAttributeConfigurationRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeInstanceAccessControlAttributeConfigurationResponse {
+    /// <p><p>Gets the list of IAM Identity Center identity store attributes that have been added to your ABAC configuration.</p></p>
+    #[serde(rename = "InstanceAccessControlAttributeConfiguration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_access_control_attribute_configuration:
+        Option<InstanceAccessControlAttributeConfiguration>,
+    /// <p><p>The status of the attribute configuration process.</p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InstanceAccessControlAttributeConfigurationStatus>,
+    /// <p><p>Provides more details about the current status of the specified attribute.</p></p>
+    #[serde(rename = "StatusReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_reason: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DescribeInstanceRequest {
+    /// <p><p>The ARN of the instance of IAM Identity Center under which the operation will run.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+}
+
+impl DescribeInstanceRequest {
+    /// Returns a builder for `DescribeInstanceRequest`, checking its required members when built.
+    pub fn builder() -> DescribeInstanceRequestBuilder {
+        DescribeInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeInstanceRequest`](struct.DescribeInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeInstanceRequestBuilder {
+    instance_arn: Option<String>,
+}
+
+impl DescribeInstanceRequestBuilder {
+    /// Sets the [`instance_arn`](struct.DescribeInstanceRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeInstanceRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeInstanceResponse {
+    /// <p><p>The date the instance was created.</p></p>
+    #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<EpochTimestamp>,
+    /// <p><p>The identifier of the identity store that is connected to the instance of IAM Identity Center.</p></p>
+    #[serde(rename = "IdentityStoreId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_store_id: Option<String>,
+    /// <p><p>The ARN of the instance of IAM Identity Center under which the operation will run. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_arn: Option<String>,
+    /// <p><p>Specifies the instance name.</p></p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p><p>The identifier of the Amazon Web Services account for which the instance was created.</p></p>
+    #[serde(rename = "OwnerAccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_account_id: Option<String>,
+    /// <p><p>The status of the instance. </p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InstanceStatus>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DescribePermissionSetProvisioningStatusRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The identifier that is provided by the <a>ProvisionPermissionSet</a> call to retrieve the current status of the provisioning workflow.</p></p>
+    #[serde(rename = "ProvisionPermissionSetRequestId")]
+    pub provision_permission_set_request_id: String,
+}
+
+impl DescribePermissionSetProvisioningStatusRequest {
+    /// Returns a builder for `DescribePermissionSetProvisioningStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribePermissionSetProvisioningStatusRequestBuilder {
+        DescribePermissionSetProvisioningStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePermissionSetProvisioningStatusRequest`](struct.DescribePermissionSetProvisioningStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribePermissionSetProvisioningStatusRequestBuilder {
+    instance_arn: Option<String>,
+    provision_permission_set_request_id: Option<String>,
+}
+
+impl DescribePermissionSetProvisioningStatusRequestBuilder {
+    /// Sets the [`instance_arn`](struct.DescribePermissionSetProvisioningStatusRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provision_permission_set_request_id`](struct.DescribePermissionSetProvisioningStatusRequest.html#structfield.provision_permission_set_request_id) member.
+    pub fn provision_permission_set_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provision_permission_set_request_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePermissionSetProvisioningStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribePermissionSetProvisioningStatusRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribePermissionSetProvisioningStatusRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            provision_permission_set_request_id: self.provision_permission_set_request_id.ok_or(
+                ::rusoto_core::BuildError::missing("provision_permission_set_request_id"),
+            )?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribePermissionSetProvisioningStatusResponse {
+    /// <p><p>The status object for the permission set provisioning operation.</p></p>
+    #[serde(rename = "PermissionSetProvisioningStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_set_provisioning_status: Option<PermissionSetProvisioningStatus>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DescribePermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the permission set. </p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl DescribePermissionSetRequest {
+    /// Returns a builder for `DescribePermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> DescribePermissionSetRequestBuilder {
+        DescribePermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePermissionSetRequest`](struct.DescribePermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribePermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl DescribePermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.DescribePermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.DescribePermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePermissionSetRequest, ::rusoto_core::BuildError> {
+        Ok(DescribePermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribePermissionSetResponse {
+    /// <p><p>Describes the level of access on an Amazon Web Services account.</p></p>
+    #[serde(rename = "PermissionSet")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_set: Option<PermissionSet>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DescribeTrustedTokenIssuerRequest {
+    /// <p><p>Specifies the ARN of the trusted token issuer configuration that you want details about.</p></p>
+    #[serde(rename = "TrustedTokenIssuerArn")]
+    pub trusted_token_issuer_arn: String,
+}
+
+impl DescribeTrustedTokenIssuerRequest {
+    /// Returns a builder for `DescribeTrustedTokenIssuerRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTrustedTokenIssuerRequestBuilder {
+        DescribeTrustedTokenIssuerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTrustedTokenIssuerRequest`](struct.DescribeTrustedTokenIssuerRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTrustedTokenIssuerRequestBuilder {
+    trusted_token_issuer_arn: Option<String>,
+}
+
+impl DescribeTrustedTokenIssuerRequestBuilder {
+    /// Sets the [`trusted_token_issuer_arn`](struct.DescribeTrustedTokenIssuerRequest.html#structfield.trusted_token_issuer_arn) member.
+    pub fn trusted_token_issuer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.trusted_token_issuer_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTrustedTokenIssuerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTrustedTokenIssuerRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTrustedTokenIssuerRequest {
+            trusted_token_issuer_arn: self.trusted_token_issuer_arn.ok_or(
+                ::rusoto_core::BuildError::missing("trusted_token_issuer_arn"),
+            )?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeTrustedTokenIssuerResponse {
+    /// <p><p>The name of the trusted token issuer configuration.</p></p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p><p>The ARN of the trusted token issuer configuration.</p></p>
+    #[serde(rename = "TrustedTokenIssuerArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_token_issuer_arn: Option<String>,
+    /// <p><p>A structure the describes the settings that apply of this trusted token issuer.</p></p>
+    #[serde(rename = "TrustedTokenIssuerConfiguration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_token_issuer_configuration: Option<TrustedTokenIssuerConfiguration>,
+    /// <p><p>The type of the trusted token issuer.</p></p>
+    #[serde(rename = "TrustedTokenIssuerType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_token_issuer_type: Option<TrustedTokenIssuerType>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DetachCustomerManagedPolicyReferenceFromPermissionSetRequest {
+    /// <p><p>Specifies the name and path of a customer managed policy. You must have an IAM policy that matches the name and path in each Amazon Web Services account where you want to deploy your permission set.</p></p>
+    #[serde(rename = "CustomerManagedPolicyReference")]
+    pub customer_managed_policy_reference: CustomerManagedPolicyReference,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. </p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the <code>PermissionSet</code>.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl DetachCustomerManagedPolicyReferenceFromPermissionSetRequest {
+    /// Returns a builder for `DetachCustomerManagedPolicyReferenceFromPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> DetachCustomerManagedPolicyReferenceFromPermissionSetRequestBuilder {
+        DetachCustomerManagedPolicyReferenceFromPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachCustomerManagedPolicyReferenceFromPermissionSetRequest`](struct.DetachCustomerManagedPolicyReferenceFromPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachCustomerManagedPolicyReferenceFromPermissionSetRequestBuilder {
+    customer_managed_policy_reference: Option<CustomerManagedPolicyReference>,
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl DetachCustomerManagedPolicyReferenceFromPermissionSetRequestBuilder {
+    /// Sets the [`customer_managed_policy_reference`](struct.DetachCustomerManagedPolicyReferenceFromPermissionSetRequest.html#structfield.customer_managed_policy_reference) member.
+    pub fn customer_managed_policy_reference<T: Into<CustomerManagedPolicyReference>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.customer_managed_policy_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.DetachCustomerManagedPolicyReferenceFromPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.DetachCustomerManagedPolicyReferenceFromPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachCustomerManagedPolicyReferenceFromPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DetachCustomerManagedPolicyReferenceFromPermissionSetRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(
+            DetachCustomerManagedPolicyReferenceFromPermissionSetRequest {
+                customer_managed_policy_reference: self.customer_managed_policy_reference.ok_or(
+                    ::rusoto_core::BuildError::missing("customer_managed_policy_reference"),
+                )?,
+                instance_arn: self
+                    .instance_arn
+                    .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+                permission_set_arn: self
+                    .permission_set_arn
+                    .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+            },
+        )
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DetachCustomerManagedPolicyReferenceFromPermissionSetResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DetachManagedPolicyFromPermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The Amazon Web Services managed policy ARN to be detached from a permission set.</p></p>
+    #[serde(rename = "ManagedPolicyArn")]
+    pub managed_policy_arn: String,
+    /// <p><p>The ARN of the <a>PermissionSet</a> from which the policy should be detached.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl DetachManagedPolicyFromPermissionSetRequest {
+    /// Returns a builder for `DetachManagedPolicyFromPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> DetachManagedPolicyFromPermissionSetRequestBuilder {
+        DetachManagedPolicyFromPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachManagedPolicyFromPermissionSetRequest`](struct.DetachManagedPolicyFromPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachManagedPolicyFromPermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    managed_policy_arn: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl DetachManagedPolicyFromPermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.DetachManagedPolicyFromPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`managed_policy_arn`](struct.DetachManagedPolicyFromPermissionSetRequest.html#structfield.managed_policy_arn) member.
+    pub fn managed_policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.managed_policy_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.DetachManagedPolicyFromPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachManagedPolicyFromPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachManagedPolicyFromPermissionSetRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DetachManagedPolicyFromPermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            managed_policy_arn: self
+                .managed_policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("managed_policy_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DetachManagedPolicyFromPermissionSetResponse {}
+
+/// <p><p>A structure that describes how the portal represents an application provider.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DisplayData {
+    /// <p><p>The description of the application provider that appears in the portal.</p></p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p><p>The name of the application provider that appears in the portal.</p></p>
+    #[serde(rename = "DisplayName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// <p><p>A URL that points to an icon that represents the application provider.</p></p>
+    #[serde(rename = "IconUrl")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+}
+
+/// <p><p>The protocol that the application provider uses to perform federation.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FederationProtocol {
+    /// <p><code>SAML</code></p>
+    Saml,
+    /// <p><code>OAUTH</code></p>
+    Oauth,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FederationProtocol {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FederationProtocol::Saml => "SAML",
+            FederationProtocol::Oauth => "OAUTH",
+            FederationProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FederationProtocol {
+    fn from(value: &'a str) -> FederationProtocol {
+        match value {
+            "SAML" => FederationProtocol::Saml,
+            "OAUTH" => FederationProtocol::Oauth,
+            _ => FederationProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FederationProtocol {
+    fn from(value: String) -> FederationProtocol {
+        FederationProtocol::from(value.as_str())
+    }
+}
+
+impl From<FederationProtocol> for String {
+    fn from(value: FederationProtocol) -> String {
+        match value {
+            FederationProtocol::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FederationProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FederationProtocol {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FederationProtocol {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FederationProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FederationProtocol::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetApplicationAccessScopeRequest {
+    /// <p><p>Specifies the ARN of the application with the access scope that you want to retrieve.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies the name of the access scope for which you want the authorized targets.</p></p>
+    #[serde(rename = "Scope")]
+    pub scope: String,
+}
+
+impl GetApplicationAccessScopeRequest {
+    /// Returns a builder for `GetApplicationAccessScopeRequest`, checking its required members when built.
+    pub fn builder() -> GetApplicationAccessScopeRequestBuilder {
+        GetApplicationAccessScopeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApplicationAccessScopeRequest`](struct.GetApplicationAccessScopeRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApplicationAccessScopeRequestBuilder {
+    application_arn: Option<String>,
+    scope: Option<String>,
+}
+
+impl GetApplicationAccessScopeRequestBuilder {
+    /// Sets the [`application_arn`](struct.GetApplicationAccessScopeRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scope`](struct.GetApplicationAccessScopeRequest.html#structfield.scope) member.
+    pub fn scope<T: Into<String>>(mut self, value: T) -> Self {
+        self.scope = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApplicationAccessScopeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetApplicationAccessScopeRequest, ::rusoto_core::BuildError> {
+        Ok(GetApplicationAccessScopeRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            scope: self
+                .scope
+                .ok_or(::rusoto_core::BuildError::missing("scope"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetApplicationAccessScopeResponse {
+    /// <p><p>An array of authorized targets associated with this access scope.</p></p>
+    #[serde(rename = "AuthorizedTargets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_targets: Option<Vec<String>>,
+    /// <p><p>The name of the access scope that can be used with the authorized targets.</p></p>
+    #[serde(rename = "Scope")]
+    pub scope: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetApplicationAssignmentConfigurationRequest {
+    /// <p><p>Specifies the ARN of the application. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+}
+
+impl GetApplicationAssignmentConfigurationRequest {
+    /// Returns a builder for `GetApplicationAssignmentConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetApplicationAssignmentConfigurationRequestBuilder {
+        GetApplicationAssignmentConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApplicationAssignmentConfigurationRequest`](struct.GetApplicationAssignmentConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApplicationAssignmentConfigurationRequestBuilder {
+    application_arn: Option<String>,
+}
+
+impl GetApplicationAssignmentConfigurationRequestBuilder {
+    /// Sets the [`application_arn`](struct.GetApplicationAssignmentConfigurationRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApplicationAssignmentConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        GetApplicationAssignmentConfigurationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(GetApplicationAssignmentConfigurationRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetApplicationAssignmentConfigurationResponse {
+    /// <p><p>If <code>AssignmentsRequired</code> is <code>true</code> (default value), users don’t have access to the application unless an assignment is created using the <a href="https://docs.aws.amazon.com/singlesignon/latest/APIReference/API_CreateApplicationAssignment.html">CreateApplicationAssignment API</a>. If <code>false</code>, all users have access to the application. </p></p>
+    #[serde(rename = "AssignmentRequired")]
+    pub assignment_required: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GetApplicationAuthenticationMethodRequest {
+    /// <p><p>Specifies the ARN of the application.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies the type of authentication method for which you want details.</p></p>
+    #[serde(rename = "AuthenticationMethodType")]
+    pub authentication_method_type: AuthenticationMethodType,
+}
+
+impl GetApplicationAuthenticationMethodRequest {
+    /// Returns a builder for `GetApplicationAuthenticationMethodRequest`, checking its required members when built.
+    pub fn builder() -> GetApplicationAuthenticationMethodRequestBuilder {
+        GetApplicationAuthenticationMethodRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApplicationAuthenticationMethodRequest`](struct.GetApplicationAuthenticationMethodRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApplicationAuthenticationMethodRequestBuilder {
+    application_arn: Option<String>,
+    authentication_method_type: Option<AuthenticationMethodType>,
+}
+
+impl GetApplicationAuthenticationMethodRequestBuilder {
+    /// Sets the [`application_arn`](struct.GetApplicationAuthenticationMethodRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authentication_method_type`](struct.GetApplicationAuthenticationMethodRequest.html#structfield.authentication_method_type) member.
+    pub fn authentication_method_type<T: Into<AuthenticationMethodType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.authentication_method_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApplicationAuthenticationMethodRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetApplicationAuthenticationMethodRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetApplicationAuthenticationMethodRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            authentication_method_type: self.authentication_method_type.ok_or(
+                ::rusoto_core::BuildError::missing("authentication_method_type"),
+            )?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetApplicationAuthenticationMethodResponse {
+    /// <p><p>A structure that contains details about the requested authentication method.</p></p>
+    #[serde(rename = "AuthenticationMethod")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_method: Option<AuthenticationMethod>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GetApplicationGrantRequest {
+    /// <p><p>Specifies the ARN of the application that contains the grant.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies the type of grant.</p></p>
+    #[serde(rename = "GrantType")]
+    pub grant_type: GrantType,
+}
+
+impl GetApplicationGrantRequest {
+    /// Returns a builder for `GetApplicationGrantRequest`, checking its required members when built.
+    pub fn builder() -> GetApplicationGrantRequestBuilder {
+        GetApplicationGrantRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApplicationGrantRequest`](struct.GetApplicationGrantRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApplicationGrantRequestBuilder {
+    application_arn: Option<String>,
+    grant_type: Option<GrantType>,
+}
+
+impl GetApplicationGrantRequestBuilder {
+    /// Sets the [`application_arn`](struct.GetApplicationGrantRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant_type`](struct.GetApplicationGrantRequest.html#structfield.grant_type) member.
+    pub fn grant_type<T: Into<GrantType>>(mut self, value: T) -> Self {
+        self.grant_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApplicationGrantRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetApplicationGrantRequest, ::rusoto_core::BuildError> {
+        Ok(GetApplicationGrantRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            grant_type: self
+                .grant_type
+                .ok_or(::rusoto_core::BuildError::missing("grant_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetApplicationGrantResponse {
+    /// <p><p>A structure that describes the requested grant.</p></p>
+    #[serde(rename = "Grant")]
+    pub grant: Grant,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetInlinePolicyForPermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the permission set.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl GetInlinePolicyForPermissionSetRequest {
+    /// Returns a builder for `GetInlinePolicyForPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> GetInlinePolicyForPermissionSetRequestBuilder {
+        GetInlinePolicyForPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInlinePolicyForPermissionSetRequest`](struct.GetInlinePolicyForPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInlinePolicyForPermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl GetInlinePolicyForPermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.GetInlinePolicyForPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.GetInlinePolicyForPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInlinePolicyForPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetInlinePolicyForPermissionSetRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetInlinePolicyForPermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetInlinePolicyForPermissionSetResponse {
+    /// <p><p>The inline policy that is attached to the permission set.</p> <note> <p>For <code>Length Constraints</code>, if a valid ARN is provided for a permission set, it is possible for an empty inline policy to be returned.</p> </note></p>
+    #[serde(rename = "InlinePolicy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_policy: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetPermissionsBoundaryForPermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. </p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the <code>PermissionSet</code>.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl GetPermissionsBoundaryForPermissionSetRequest {
+    /// Returns a builder for `GetPermissionsBoundaryForPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> GetPermissionsBoundaryForPermissionSetRequestBuilder {
+        GetPermissionsBoundaryForPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPermissionsBoundaryForPermissionSetRequest`](struct.GetPermissionsBoundaryForPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPermissionsBoundaryForPermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl GetPermissionsBoundaryForPermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.GetPermissionsBoundaryForPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.GetPermissionsBoundaryForPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPermissionsBoundaryForPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        GetPermissionsBoundaryForPermissionSetRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(GetPermissionsBoundaryForPermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetPermissionsBoundaryForPermissionSetResponse {
+    /// <p><p>The permissions boundary attached to the specified permission set.</p></p>
+    #[serde(rename = "PermissionsBoundary")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions_boundary: Option<PermissionsBoundary>,
+}
+
+/// <p><p>The Grant union represents the set of possible configuration options for the selected grant type. Exactly one member of the union must be specified, and must match the grant type selected.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Grant {
+    /// <p><p>Configuration options for the <code>authorization_code</code> grant type.</p></p>
+    #[serde(rename = "AuthorizationCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_code: Option<AuthorizationCodeGrant>,
+    /// <p><p>Configuration options for the <code>urn:ietf:params:oauth:grant-type:jwt-bearer</code> grant type.</p></p>
+    #[serde(rename = "JwtBearer")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwt_bearer: Option<JwtBearerGrant>,
+    /// <p><p>Configuration options for the <code>refresh_token</code> grant type.</p></p>
+    #[serde(rename = "RefreshToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<RefreshTokenGrant>,
+    /// <p><p>Configuration options for the <code>urn:ietf:params:oauth:grant-type:token-exchange</code> grant type.</p></p>
+    #[serde(rename = "TokenExchange")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_exchange: Option<TokenExchangeGrant>,
+}
+
+/// <p><p>A structure that defines a single grant and its configuration.</p></p>
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GrantItem {
+    /// <p><p>The configuration structure for the selected grant.</p></p>
+    #[serde(rename = "Grant")]
+    pub grant: Grant,
+    /// <p><p>The type of the selected grant.</p></p>
+    #[serde(rename = "GrantType")]
+    pub grant_type: GrantType,
+}
+
+/// <p><p>Specifies the type of grant to delete from the application.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GrantType {
+    /// <p><code>authorization_code</code></p>
+    AuthorizationCode,
+    /// <p><code>refresh_token</code></p>
+    RefreshToken,
+    /// <p><code>urn:ietf:params:oauth:grant-type:jwt-bearer</code></p>
+    UrnIetfParamsOauthGrantTypeJwtBearer,
+    /// <p><code>urn:ietf:params:oauth:grant-type:token-exchange</code></p>
+    UrnIetfParamsOauthGrantTypeTokenExchange,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GrantType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GrantType::AuthorizationCode => "authorization_code",
+            GrantType::RefreshToken => "refresh_token",
+            GrantType::UrnIetfParamsOauthGrantTypeJwtBearer => {
+                "urn:ietf:params:oauth:grant-type:jwt-bearer"
+            }
+            GrantType::UrnIetfParamsOauthGrantTypeTokenExchange => {
+                "urn:ietf:params:oauth:grant-type:token-exchange"
+            }
+            GrantType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GrantType {
+    fn from(value: &'a str) -> GrantType {
+        match value {
+            "authorization_code" => GrantType::AuthorizationCode,
+            "refresh_token" => GrantType::RefreshToken,
+            "urn:ietf:params:oauth:grant-type:jwt-bearer" => {
+                GrantType::UrnIetfParamsOauthGrantTypeJwtBearer
+            }
+            "urn:ietf:params:oauth:grant-type:token-exchange" => {
+                GrantType::UrnIetfParamsOauthGrantTypeTokenExchange
+            }
+            _ => GrantType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GrantType {
+    fn from(value: String) -> GrantType {
+        GrantType::from(value.as_str())
+    }
+}
+
+impl From<GrantType> for String {
+    fn from(value: GrantType) -> String {
+        match value {
+            GrantType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GrantType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for GrantType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GrantType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<GrantType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GrantType::from(value))
+    }
+}
+
+/// <p><p>A structure that describes details for authentication that uses IAM.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IamAuthenticationMethod {
+    /// <p><p>An IAM policy document in JSON.</p></p>
+    #[serde(rename = "ActorPolicy")]
+    pub actor_policy: ActorPolicyDocument,
+}
+
+/// <p><p>Specifies the attributes to add to your attribute-based access control (ABAC) configuration.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstanceAccessControlAttributeConfiguration {
+    /// <p><p>Lists the attributes that are configured for ABAC in the specified IAM Identity Center instance.</p></p>
+    #[serde(rename = "AccessControlAttributes")]
+    pub access_control_attributes: Vec<AccessControlAttribute>,
+}
+
+/// <p><p>The status of the attribute configuration process.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceAccessControlAttributeConfigurationStatus {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>CREATION_IN_PROGRESS</code></p>
+    CreationInProgress,
+    /// <p><code>CREATION_FAILED</code></p>
+    CreationFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceAccessControlAttributeConfigurationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceAccessControlAttributeConfigurationStatus::Enabled => "ENABLED",
+            InstanceAccessControlAttributeConfigurationStatus::CreationInProgress => {
+                "CREATION_IN_PROGRESS"
+            }
+            InstanceAccessControlAttributeConfigurationStatus::CreationFailed => "CREATION_FAILED",
+            InstanceAccessControlAttributeConfigurationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceAccessControlAttributeConfigurationStatus {
+    fn from(value: &'a str) -> InstanceAccessControlAttributeConfigurationStatus {
+        match value {
+            "ENABLED" => InstanceAccessControlAttributeConfigurationStatus::Enabled,
+            "CREATION_IN_PROGRESS" => {
+                InstanceAccessControlAttributeConfigurationStatus::CreationInProgress
+            }
+            "CREATION_FAILED" => InstanceAccessControlAttributeConfigurationStatus::CreationFailed,
+            _ => InstanceAccessControlAttributeConfigurationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceAccessControlAttributeConfigurationStatus {
+    fn from(value: String) -> InstanceAccessControlAttributeConfigurationStatus {
+        InstanceAccessControlAttributeConfigurationStatus::from(value.as_str())
+    }
+}
+
+impl From<InstanceAccessControlAttributeConfigurationStatus> for String {
+    fn from(value: InstanceAccessControlAttributeConfigurationStatus) -> String {
+        match value {
+            InstanceAccessControlAttributeConfigurationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceAccessControlAttributeConfigurationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceAccessControlAttributeConfigurationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceAccessControlAttributeConfigurationStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<InstanceAccessControlAttributeConfigurationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceAccessControlAttributeConfigurationStatus::from(
+            value,
+        ))
+    }
+}
+
+/// <p><p>Provides information about the IAM Identity Center instance.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct InstanceMetadata {
+    /// <p><p>The date and time that the Identity Center instance was created.</p></p>
+    #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<EpochTimestamp>,
+    /// <p><p>The identifier of the identity store that is connected to the Identity Center instance.</p></p>
+    #[serde(rename = "IdentityStoreId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_store_id: Option<String>,
+    /// <p><p>The ARN of the Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_arn: Option<String>,
+    /// <p><p>The name of the Identity Center instance.</p></p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p><p>The Amazon Web Services account ID number of the owner of the Identity Center instance.</p></p>
+    #[serde(rename = "OwnerAccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_account_id: Option<String>,
+    /// <p><p>The current status of this Identity Center instance.</p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InstanceStatus>,
+}
+
+/// <p><p>The status of the instance. </p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceStatus {
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            InstanceStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            InstanceStatus::Active => "ACTIVE",
+            InstanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceStatus {
+    fn from(value: &'a str) -> InstanceStatus {
+        match value {
+            "CREATE_IN_PROGRESS" => InstanceStatus::CreateInProgress,
+            "DELETE_IN_PROGRESS" => InstanceStatus::DeleteInProgress,
+            "ACTIVE" => InstanceStatus::Active,
+            _ => InstanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceStatus {
+    fn from(value: String) -> InstanceStatus {
+        InstanceStatus::from(value.as_str())
+    }
+}
+
+impl From<InstanceStatus> for String {
+    fn from(value: InstanceStatus) -> String {
+        match value {
+            InstanceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceStatus::from(value))
+    }
+}
+
+/// <p><p>The method that the trusted token issuer can use to retrieve the JSON Web Key Set used to verify a JWT.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JwksRetrievalOption {
+    /// <p><code>OPEN_ID_DISCOVERY</code></p>
+    OpenIdDiscovery,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JwksRetrievalOption {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JwksRetrievalOption::OpenIdDiscovery => "OPEN_ID_DISCOVERY",
+            JwksRetrievalOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JwksRetrievalOption {
+    fn from(value: &'a str) -> JwksRetrievalOption {
+        match value {
+            "OPEN_ID_DISCOVERY" => JwksRetrievalOption::OpenIdDiscovery,
+            _ => JwksRetrievalOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JwksRetrievalOption {
+    fn from(value: String) -> JwksRetrievalOption {
+        JwksRetrievalOption::from(value.as_str())
+    }
+}
+
+impl From<JwksRetrievalOption> for String {
+    fn from(value: JwksRetrievalOption) -> String {
+        match value {
+            JwksRetrievalOption::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JwksRetrievalOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for JwksRetrievalOption {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JwksRetrievalOption {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JwksRetrievalOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JwksRetrievalOption::from(value))
+    }
+}
+
+/// <p><p>A structure that defines configuration settings for an application that supports the JWT Bearer Token Authorization Grant.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JwtBearerGrant {
+    /// <p><p>A list of allowed token issuers trusted by the Identity Center instances for this application.</p></p>
+    #[serde(rename = "AuthorizedTokenIssuers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_token_issuers: Option<Vec<AuthorizedTokenIssuer>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListAccountAssignmentCreationStatusRequest {
+    /// <p><p>Filters results based on the passed attribute value.</p></p>
+    #[serde(rename = "Filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<OperationStatusFilter>,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The maximum number of results to display for the assignment.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListAccountAssignmentCreationStatusRequest {
+    /// Returns a builder for `ListAccountAssignmentCreationStatusRequest`, checking its required members when built.
+    pub fn builder() -> ListAccountAssignmentCreationStatusRequestBuilder {
+        ListAccountAssignmentCreationStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAccountAssignmentCreationStatusRequest`](struct.ListAccountAssignmentCreationStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAccountAssignmentCreationStatusRequestBuilder {
+    filter: Option<OperationStatusFilter>,
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAccountAssignmentCreationStatusRequestBuilder {
+    /// Sets the [`filter`](struct.ListAccountAssignmentCreationStatusRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<OperationStatusFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.ListAccountAssignmentCreationStatusRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAccountAssignmentCreationStatusRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAccountAssignmentCreationStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAccountAssignmentCreationStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAccountAssignmentCreationStatusRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListAccountAssignmentCreationStatusRequest {
+            filter: self.filter,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListAccountAssignmentCreationStatusResponse {
+    /// <p><p>The status object for the account assignment creation operation.</p></p>
+    #[serde(rename = "AccountAssignmentsCreationStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_assignments_creation_status: Option<Vec<AccountAssignmentOperationStatusMetadata>>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListAccountAssignmentDeletionStatusRequest {
+    /// <p><p>Filters results based on the passed attribute value.</p></p>
+    #[serde(rename = "Filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<OperationStatusFilter>,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The maximum number of results to display for the assignment.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListAccountAssignmentDeletionStatusRequest {
+    /// Returns a builder for `ListAccountAssignmentDeletionStatusRequest`, checking its required members when built.
+    pub fn builder() -> ListAccountAssignmentDeletionStatusRequestBuilder {
+        ListAccountAssignmentDeletionStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAccountAssignmentDeletionStatusRequest`](struct.ListAccountAssignmentDeletionStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAccountAssignmentDeletionStatusRequestBuilder {
+    filter: Option<OperationStatusFilter>,
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAccountAssignmentDeletionStatusRequestBuilder {
+    /// Sets the [`filter`](struct.ListAccountAssignmentDeletionStatusRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<OperationStatusFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.ListAccountAssignmentDeletionStatusRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAccountAssignmentDeletionStatusRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAccountAssignmentDeletionStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAccountAssignmentDeletionStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAccountAssignmentDeletionStatusRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListAccountAssignmentDeletionStatusRequest {
+            filter: self.filter,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListAccountAssignmentDeletionStatusResponse {
+    /// <p><p>The status object for the account assignment deletion operation.</p></p>
+    #[serde(rename = "AccountAssignmentsDeletionStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_assignments_deletion_status: Option<Vec<AccountAssignmentOperationStatusMetadata>>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+/// <p><p>A structure that describes a filter for account assignments.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListAccountAssignmentsFilter {
+    /// <p><p>The ID number of an Amazon Web Services account that filters the results in the response.</p></p>
+    #[serde(rename = "AccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ListAccountAssignmentsForPrincipalRequest {
+    /// <p><p>Specifies an Amazon Web Services account ID number. Results are filtered to only those that match this ID number.</p></p>
+    #[serde(rename = "Filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ListAccountAssignmentsFilter>,
+    /// <p><p>Specifies the ARN of the instance of IAM Identity Center that contains the principal.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>Specifies the total number of results that you want included in each response. If additional items exist beyond the number you specify, the <code>NextToken</code> response element is returned with a value (not null). Include the specified value as the <code>NextToken</code> request parameter in the next call to the operation to get the next set of results. Note that the service might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>Specifies that you want to receive the next page of results. Valid only if you received a <code>NextToken</code> response in the previous request. If you did, it indicates that more output is available. Set this parameter to the value provided by the previous call's <code>NextToken</code> response to request the next page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>Specifies the principal for which you want to retrieve the list of account assignments.</p></p>
+    #[serde(rename = "PrincipalId")]
+    pub principal_id: String,
+    /// <p><p>Specifies the type of the principal.</p></p>
+    #[serde(rename = "PrincipalType")]
+    pub principal_type: PrincipalType,
+}
+
+impl ListAccountAssignmentsForPrincipalRequest {
+    /// Returns a builder for `ListAccountAssignmentsForPrincipalRequest`, checking its required members when built.
+    pub fn builder() -> ListAccountAssignmentsForPrincipalRequestBuilder {
+        ListAccountAssignmentsForPrincipalRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAccountAssignmentsForPrincipalRequest`](struct.ListAccountAssignmentsForPrincipalRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAccountAssignmentsForPrincipalRequestBuilder {
+    filter: Option<ListAccountAssignmentsFilter>,
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    principal_id: Option<String>,
+    principal_type: Option<PrincipalType>,
+}
+
+impl ListAccountAssignmentsForPrincipalRequestBuilder {
+    /// Sets the [`filter`](struct.ListAccountAssignmentsForPrincipalRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<ListAccountAssignmentsFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.ListAccountAssignmentsForPrincipalRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAccountAssignmentsForPrincipalRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAccountAssignmentsForPrincipalRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_id`](struct.ListAccountAssignmentsForPrincipalRequest.html#structfield.principal_id) member.
+    pub fn principal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_type`](struct.ListAccountAssignmentsForPrincipalRequest.html#structfield.principal_type) member.
+    pub fn principal_type<T: Into<PrincipalType>>(mut self, value: T) -> Self {
+        self.principal_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAccountAssignmentsForPrincipalRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAccountAssignmentsForPrincipalRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListAccountAssignmentsForPrincipalRequest {
+            filter: self.filter,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            principal_id: self
+                .principal_id
+                .ok_or(::rusoto_core::BuildError::missing("principal_id"))?,
+            principal_type: self
+                .principal_type
+                .ok_or(::rusoto_core::BuildError::missing("principal_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListAccountAssignmentsForPrincipalResponse {
+    /// <p><p>An array list of the account assignments for the principal.</p></p>
+    #[serde(rename = "AccountAssignments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_assignments: Option<Vec<AccountAssignmentForPrincipal>>,
+    /// <p><p>If present, this value indicates that more output is available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>. This indicates that this is the last page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListAccountAssignmentsRequest {
+    /// <p><p>The identifier of the Amazon Web Services account from which to list the assignments.</p></p>
+    #[serde(rename = "AccountId")]
+    pub account_id: String,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The maximum number of results to display for the assignment.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>The ARN of the permission set from which to list assignments.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl ListAccountAssignmentsRequest {
+    /// Returns a builder for `ListAccountAssignmentsRequest`, checking its required members when built.
+    pub fn builder() -> ListAccountAssignmentsRequestBuilder {
+        ListAccountAssignmentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAccountAssignmentsRequest`](struct.ListAccountAssignmentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAccountAssignmentsRequestBuilder {
+    account_id: Option<String>,
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl ListAccountAssignmentsRequestBuilder {
+    /// Sets the [`account_id`](struct.ListAccountAssignmentsRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.ListAccountAssignmentsRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAccountAssignmentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAccountAssignmentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.ListAccountAssignmentsRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAccountAssignmentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAccountAssignmentsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAccountAssignmentsRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListAccountAssignmentsResponse {
+    /// <p><p>The list of assignments that match the input Amazon Web Services account and permission set.</p></p>
+    #[serde(rename = "AccountAssignments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_assignments: Option<Vec<AccountAssignment>>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListAccountsForProvisionedPermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The maximum number of results to display for the <a>PermissionSet</a>.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>The ARN of the <a>PermissionSet</a> from which the associated Amazon Web Services accounts will be listed.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+    /// <p><p>The permission set provisioning status for an Amazon Web Services account.</p></p>
+    #[serde(rename = "ProvisioningStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provisioning_status: Option<ProvisioningStatus>,
+}
+
+impl ListAccountsForProvisionedPermissionSetRequest {
+    /// Returns a builder for `ListAccountsForProvisionedPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> ListAccountsForProvisionedPermissionSetRequestBuilder {
+        ListAccountsForProvisionedPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAccountsForProvisionedPermissionSetRequest`](struct.ListAccountsForProvisionedPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAccountsForProvisionedPermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    permission_set_arn: Option<String>,
+    provisioning_status: Option<ProvisioningStatus>,
+}
+
+impl ListAccountsForProvisionedPermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.ListAccountsForProvisionedPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAccountsForProvisionedPermissionSetRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAccountsForProvisionedPermissionSetRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.ListAccountsForProvisionedPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_status`](struct.ListAccountsForProvisionedPermissionSetRequest.html#structfield.provisioning_status) member.
+    pub fn provisioning_status<T: Into<ProvisioningStatus>>(mut self, value: T) -> Self {
+        self.provisioning_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAccountsForProvisionedPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ListAccountsForProvisionedPermissionSetRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ListAccountsForProvisionedPermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+            provisioning_status: self.provisioning_status,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListAccountsForProvisionedPermissionSetResponse {
+    /// <p><p>The list of Amazon Web Services <code>AccountIds</code>.</p></p>
+    #[serde(rename = "AccountIds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_ids: Option<Vec<String>>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListApplicationAccessScopesRequest {
+    /// <p><p>Specifies the ARN of the application.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies the total number of results that you want included in each response. If additional items exist beyond the number you specify, the <code>NextToken</code> response element is returned with a value (not null). Include the specified value as the <code>NextToken</code> request parameter in the next call to the operation to get the next set of results. Note that the service might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>Specifies that you want to receive the next page of results. Valid only if you received a <code>NextToken</code> response in the previous request. If you did, it indicates that more output is available. Set this parameter to the value provided by the previous call's <code>NextToken</code> response to request the next page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListApplicationAccessScopesRequest {
+    /// Returns a builder for `ListApplicationAccessScopesRequest`, checking its required members when built.
+    pub fn builder() -> ListApplicationAccessScopesRequestBuilder {
+        ListApplicationAccessScopesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationAccessScopesRequest`](struct.ListApplicationAccessScopesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationAccessScopesRequestBuilder {
+    application_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListApplicationAccessScopesRequestBuilder {
+    /// Sets the [`application_arn`](struct.ListApplicationAccessScopesRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListApplicationAccessScopesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApplicationAccessScopesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationAccessScopesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListApplicationAccessScopesRequest, ::rusoto_core::BuildError> {
+        Ok(ListApplicationAccessScopesRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListApplicationAccessScopesResponse {
+    /// <p><p>If present, this value indicates that more output is available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>. This indicates that this is the last page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>An array list of access scopes and their authorized targets that are associated with the application.</p></p>
+    #[serde(rename = "Scopes")]
+    pub scopes: Vec<ScopeDetails>,
+}
+
+/// <p><p>A structure that describes a filter for application assignments.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListApplicationAssignmentsFilter {
+    /// <p><p>The ARN of an application.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_arn: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ListApplicationAssignmentsForPrincipalRequest {
+    /// <p><p>Filters the output to include only assignments associated with the application that has the specified ARN.</p></p>
+    #[serde(rename = "Filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ListApplicationAssignmentsFilter>,
+    /// <p><p>Specifies the instance of IAM Identity Center that contains principal and applications.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>Specifies the total number of results that you want included in each response. If additional items exist beyond the number you specify, the <code>NextToken</code> response element is returned with a value (not null). Include the specified value as the <code>NextToken</code> request parameter in the next call to the operation to get the next set of results. Note that the service might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>Specifies that you want to receive the next page of results. Valid only if you received a <code>NextToken</code> response in the previous request. If you did, it indicates that more output is available. Set this parameter to the value provided by the previous call's <code>NextToken</code> response to request the next page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>Specifies the unique identifier of the principal for which you want to retrieve its assignments.</p></p>
+    #[serde(rename = "PrincipalId")]
+    pub principal_id: String,
+    /// <p><p>Specifies the type of the principal for which you want to retrieve its assignments.</p></p>
+    #[serde(rename = "PrincipalType")]
+    pub principal_type: PrincipalType,
+}
+
+impl ListApplicationAssignmentsForPrincipalRequest {
+    /// Returns a builder for `ListApplicationAssignmentsForPrincipalRequest`, checking its required members when built.
+    pub fn builder() -> ListApplicationAssignmentsForPrincipalRequestBuilder {
+        ListApplicationAssignmentsForPrincipalRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationAssignmentsForPrincipalRequest`](struct.ListApplicationAssignmentsForPrincipalRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationAssignmentsForPrincipalRequestBuilder {
+    filter: Option<ListApplicationAssignmentsFilter>,
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    principal_id: Option<String>,
+    principal_type: Option<PrincipalType>,
+}
+
+impl ListApplicationAssignmentsForPrincipalRequestBuilder {
+    /// Sets the [`filter`](struct.ListApplicationAssignmentsForPrincipalRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<ListApplicationAssignmentsFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.ListApplicationAssignmentsForPrincipalRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListApplicationAssignmentsForPrincipalRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApplicationAssignmentsForPrincipalRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_id`](struct.ListApplicationAssignmentsForPrincipalRequest.html#structfield.principal_id) member.
+    pub fn principal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_type`](struct.ListApplicationAssignmentsForPrincipalRequest.html#structfield.principal_type) member.
+    pub fn principal_type<T: Into<PrincipalType>>(mut self, value: T) -> Self {
+        self.principal_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationAssignmentsForPrincipalRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ListApplicationAssignmentsForPrincipalRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ListApplicationAssignmentsForPrincipalRequest {
+            filter: self.filter,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            principal_id: self
+                .principal_id
+                .ok_or(::rusoto_core::BuildError::missing("principal_id"))?,
+            principal_type: self
+                .principal_type
+                .ok_or(::rusoto_core::BuildError::missing("principal_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListApplicationAssignmentsForPrincipalResponse {
+    /// <p><p>An array list of the application assignments for the specified principal.</p></p>
+    #[serde(rename = "ApplicationAssignments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_assignments: Option<Vec<ApplicationAssignmentForPrincipal>>,
+    /// <p><p>If present, this value indicates that more output is available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>. This indicates that this is the last page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListApplicationAssignmentsRequest {
+    /// <p><p>Specifies the ARN of the application.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies the total number of results that you want included in each response. If additional items exist beyond the number you specify, the <code>NextToken</code> response element is returned with a value (not null). Include the specified value as the <code>NextToken</code> request parameter in the next call to the operation to get the next set of results. Note that the service might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>Specifies that you want to receive the next page of results. Valid only if you received a <code>NextToken</code> response in the previous request. If you did, it indicates that more output is available. Set this parameter to the value provided by the previous call's <code>NextToken</code> response to request the next page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListApplicationAssignmentsRequest {
+    /// Returns a builder for `ListApplicationAssignmentsRequest`, checking its required members when built.
+    pub fn builder() -> ListApplicationAssignmentsRequestBuilder {
+        ListApplicationAssignmentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationAssignmentsRequest`](struct.ListApplicationAssignmentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationAssignmentsRequestBuilder {
+    application_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListApplicationAssignmentsRequestBuilder {
+    /// Sets the [`application_arn`](struct.ListApplicationAssignmentsRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListApplicationAssignmentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApplicationAssignmentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationAssignmentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListApplicationAssignmentsRequest, ::rusoto_core::BuildError> {
+        Ok(ListApplicationAssignmentsRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListApplicationAssignmentsResponse {
+    /// <p><p>The list of users assigned to an application.</p></p>
+    #[serde(rename = "ApplicationAssignments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_assignments: Option<Vec<ApplicationAssignment>>,
+    /// <p><p>If present, this value indicates that more output is available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>. This indicates that this is the last page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListApplicationAuthenticationMethodsRequest {
+    /// <p><p>Specifies the ARN of the application with the authentication methods you want to list.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies that you want to receive the next page of results. Valid only if you received a <code>NextToken</code> response in the previous request. If you did, it indicates that more output is available. Set this parameter to the value provided by the previous call's <code>NextToken</code> response to request the next page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListApplicationAuthenticationMethodsRequest {
+    /// Returns a builder for `ListApplicationAuthenticationMethodsRequest`, checking its required members when built.
+    pub fn builder() -> ListApplicationAuthenticationMethodsRequestBuilder {
+        ListApplicationAuthenticationMethodsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationAuthenticationMethodsRequest`](struct.ListApplicationAuthenticationMethodsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationAuthenticationMethodsRequestBuilder {
+    application_arn: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListApplicationAuthenticationMethodsRequestBuilder {
+    /// Sets the [`application_arn`](struct.ListApplicationAuthenticationMethodsRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApplicationAuthenticationMethodsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationAuthenticationMethodsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListApplicationAuthenticationMethodsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListApplicationAuthenticationMethodsRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListApplicationAuthenticationMethodsResponse {
+    /// <p><p>An array list of authentication methods for the specified application.</p></p>
+    #[serde(rename = "AuthenticationMethods")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_methods: Option<Vec<AuthenticationMethodItem>>,
+    /// <p><p>If present, this value indicates that more output is available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>. This indicates that this is the last page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListApplicationGrantsRequest {
+    /// <p><p>Specifies the ARN of the application whose grants you want to list.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies that you want to receive the next page of results. Valid only if you received a <code>NextToken</code> response in the previous request. If you did, it indicates that more output is available. Set this parameter to the value provided by the previous call's <code>NextToken</code> response to request the next page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListApplicationGrantsRequest {
+    /// Returns a builder for `ListApplicationGrantsRequest`, checking its required members when built.
+    pub fn builder() -> ListApplicationGrantsRequestBuilder {
+        ListApplicationGrantsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationGrantsRequest`](struct.ListApplicationGrantsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationGrantsRequestBuilder {
+    application_arn: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListApplicationGrantsRequestBuilder {
+    /// Sets the [`application_arn`](struct.ListApplicationGrantsRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApplicationGrantsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationGrantsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListApplicationGrantsRequest, ::rusoto_core::BuildError> {
+        Ok(ListApplicationGrantsRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListApplicationGrantsResponse {
+    /// <p><p>An array list of structures that describe the requested grants.</p></p>
+    #[serde(rename = "Grants")]
+    pub grants: Vec<GrantItem>,
+    /// <p><p>If present, this value indicates that more output is available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>. This indicates that this is the last page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListApplicationProvidersRequest {
+    /// <p><p>Specifies the total number of results that you want included in each response. If additional items exist beyond the number you specify, the <code>NextToken</code> response element is returned with a value (not null). Include the specified value as the <code>NextToken</code> request parameter in the next call to the operation to get the next set of results. Note that the service might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>Specifies that you want to receive the next page of results. Valid only if you received a <code>NextToken</code> response in the previous request. If you did, it indicates that more output is available. Set this parameter to the value provided by the previous call's <code>NextToken</code> response to request the next page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListApplicationProvidersRequest {
+    /// Returns a builder for `ListApplicationProvidersRequest`, checking its required members when built.
+    pub fn builder() -> ListApplicationProvidersRequestBuilder {
+        ListApplicationProvidersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationProvidersRequest`](struct.ListApplicationProvidersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationProvidersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListApplicationProvidersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListApplicationProvidersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApplicationProvidersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationProvidersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListApplicationProvidersRequest, ::rusoto_core::BuildError> {
+        Ok(ListApplicationProvidersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListApplicationProvidersResponse {
+    /// <p><p>An array list of structures that describe application providers.</p></p>
+    #[serde(rename = "ApplicationProviders")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_providers: Option<Vec<ApplicationProvider>>,
+    /// <p><p>If present, this value indicates that more output is available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>. This indicates that this is the last page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+/// <p><p>A structure that describes a filter for applications.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListApplicationsFilter {
+    /// <p><p>An Amazon Web Services account ID number that filters the results in the response.</p></p>
+    #[serde(rename = "ApplicationAccount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_account: Option<String>,
+    /// <p><p>The ARN of an application provider that can filter the results in the response.</p></p>
+    #[serde(rename = "ApplicationProvider")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_provider: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListApplicationsRequest {
+    /// <p><p>Filters response results. </p></p>
+    #[serde(rename = "Filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ListApplicationsFilter>,
+    /// <p><p>The ARN of the IAM Identity Center application under which the operation will run. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>Specifies the total number of results that you want included in each response. If additional items exist beyond the number you specify, the <code>NextToken</code> response element is returned with a value (not null). Include the specified value as the <code>NextToken</code> request parameter in the next call to the operation to get the next set of results. Note that the service might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>Specifies that you want to receive the next page of results. Valid only if you received a <code>NextToken</code> response in the previous request. If you did, it indicates that more output is available. Set this parameter to the value provided by the previous call's <code>NextToken</code> response to request the next page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListApplicationsRequest {
+    /// Returns a builder for `ListApplicationsRequest`, checking its required members when built.
+    pub fn builder() -> ListApplicationsRequestBuilder {
+        ListApplicationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationsRequest`](struct.ListApplicationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationsRequestBuilder {
+    filter: Option<ListApplicationsFilter>,
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListApplicationsRequestBuilder {
+    /// Sets the [`filter`](struct.ListApplicationsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<ListApplicationsFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.ListApplicationsRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListApplicationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApplicationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListApplicationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListApplicationsRequest {
+            filter: self.filter,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListApplicationsResponse {
+    /// <p><p>Retrieves all applications associated with the instance.</p></p>
+    #[serde(rename = "Applications")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applications: Option<Vec<Application>>,
+    /// <p><p>If present, this value indicates that more output is available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>. This indicates that this is the last page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListCustomerManagedPolicyReferencesInPermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. </p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The maximum number of results to display for the list call.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>The ARN of the <code>PermissionSet</code>. </p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl ListCustomerManagedPolicyReferencesInPermissionSetRequest {
+    /// Returns a builder for `ListCustomerManagedPolicyReferencesInPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> ListCustomerManagedPolicyReferencesInPermissionSetRequestBuilder {
+        ListCustomerManagedPolicyReferencesInPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCustomerManagedPolicyReferencesInPermissionSetRequest`](struct.ListCustomerManagedPolicyReferencesInPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCustomerManagedPolicyReferencesInPermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl ListCustomerManagedPolicyReferencesInPermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.ListCustomerManagedPolicyReferencesInPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListCustomerManagedPolicyReferencesInPermissionSetRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListCustomerManagedPolicyReferencesInPermissionSetRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.ListCustomerManagedPolicyReferencesInPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCustomerManagedPolicyReferencesInPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ListCustomerManagedPolicyReferencesInPermissionSetRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ListCustomerManagedPolicyReferencesInPermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListCustomerManagedPolicyReferencesInPermissionSetResponse {
+    /// <p><p>Specifies the names and paths of the customer managed policies that you have attached to your permission set.</p></p>
+    #[serde(rename = "CustomerManagedPolicyReferences")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_managed_policy_references: Option<Vec<CustomerManagedPolicyReference>>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListInstancesRequest {
+    /// <p><p>The maximum number of results to display for the instance.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListInstancesRequest {
+    /// Returns a builder for `ListInstancesRequest`, checking its required members when built.
+    pub fn builder() -> ListInstancesRequestBuilder {
+        ListInstancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListInstancesRequest`](struct.ListInstancesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListInstancesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListInstancesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListInstancesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListInstancesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListInstancesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListInstancesRequest, ::rusoto_core::BuildError> {
+        Ok(ListInstancesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListInstancesResponse {
+    /// <p><p>Lists the IAM Identity Center instances that the caller has access to.</p></p>
+    #[serde(rename = "Instances")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instances: Option<Vec<InstanceMetadata>>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListManagedPoliciesInPermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The maximum number of results to display for the <a>PermissionSet</a>.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>The ARN of the <a>PermissionSet</a> whose managed policies will be listed.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl ListManagedPoliciesInPermissionSetRequest {
+    /// Returns a builder for `ListManagedPoliciesInPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> ListManagedPoliciesInPermissionSetRequestBuilder {
+        ListManagedPoliciesInPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListManagedPoliciesInPermissionSetRequest`](struct.ListManagedPoliciesInPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct ListManagedPoliciesInPermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl ListManagedPoliciesInPermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.ListManagedPoliciesInPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListManagedPoliciesInPermissionSetRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListManagedPoliciesInPermissionSetRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.ListManagedPoliciesInPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListManagedPoliciesInPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListManagedPoliciesInPermissionSetRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListManagedPoliciesInPermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListManagedPoliciesInPermissionSetResponse {
+    /// <p><p>An array of the <a>AttachedManagedPolicy</a> data type object.</p></p>
+    #[serde(rename = "AttachedManagedPolicies")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attached_managed_policies: Option<Vec<AttachedManagedPolicy>>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListPermissionSetProvisioningStatusRequest {
+    /// <p><p>Filters results based on the passed attribute value.</p></p>
+    #[serde(rename = "Filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<OperationStatusFilter>,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The maximum number of results to display for the assignment.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListPermissionSetProvisioningStatusRequest {
+    /// Returns a builder for `ListPermissionSetProvisioningStatusRequest`, checking its required members when built.
+    pub fn builder() -> ListPermissionSetProvisioningStatusRequestBuilder {
+        ListPermissionSetProvisioningStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPermissionSetProvisioningStatusRequest`](struct.ListPermissionSetProvisioningStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPermissionSetProvisioningStatusRequestBuilder {
+    filter: Option<OperationStatusFilter>,
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListPermissionSetProvisioningStatusRequestBuilder {
+    /// Sets the [`filter`](struct.ListPermissionSetProvisioningStatusRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<OperationStatusFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.ListPermissionSetProvisioningStatusRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListPermissionSetProvisioningStatusRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPermissionSetProvisioningStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPermissionSetProvisioningStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPermissionSetProvisioningStatusRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListPermissionSetProvisioningStatusRequest {
+            filter: self.filter,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListPermissionSetProvisioningStatusResponse {
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>The status object for the permission set provisioning operation.</p></p>
+    #[serde(rename = "PermissionSetsProvisioningStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_sets_provisioning_status: Option<Vec<PermissionSetProvisioningStatusMetadata>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListPermissionSetsProvisionedToAccountRequest {
+    /// <p><p>The identifier of the Amazon Web Services account from which to list the assignments.</p></p>
+    #[serde(rename = "AccountId")]
+    pub account_id: String,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The maximum number of results to display for the assignment.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>The status object for the permission set provisioning operation.</p></p>
+    #[serde(rename = "ProvisioningStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provisioning_status: Option<ProvisioningStatus>,
+}
+
+impl ListPermissionSetsProvisionedToAccountRequest {
+    /// Returns a builder for `ListPermissionSetsProvisionedToAccountRequest`, checking its required members when built.
+    pub fn builder() -> ListPermissionSetsProvisionedToAccountRequestBuilder {
+        ListPermissionSetsProvisionedToAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPermissionSetsProvisionedToAccountRequest`](struct.ListPermissionSetsProvisionedToAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPermissionSetsProvisionedToAccountRequestBuilder {
+    account_id: Option<String>,
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    provisioning_status: Option<ProvisioningStatus>,
+}
+
+impl ListPermissionSetsProvisionedToAccountRequestBuilder {
+    /// Sets the [`account_id`](struct.ListPermissionSetsProvisionedToAccountRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.ListPermissionSetsProvisionedToAccountRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListPermissionSetsProvisionedToAccountRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPermissionSetsProvisionedToAccountRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioning_status`](struct.ListPermissionSetsProvisionedToAccountRequest.html#structfield.provisioning_status) member.
+    pub fn provisioning_status<T: Into<ProvisioningStatus>>(mut self, value: T) -> Self {
+        self.provisioning_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPermissionSetsProvisionedToAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ListPermissionSetsProvisionedToAccountRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ListPermissionSetsProvisionedToAccountRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            provisioning_status: self.provisioning_status,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListPermissionSetsProvisionedToAccountResponse {
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>Defines the level of access that an Amazon Web Services account has.</p></p>
+    #[serde(rename = "PermissionSets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_sets: Option<Vec<String>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListPermissionSetsRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The maximum number of results to display for the assignment.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListPermissionSetsRequest {
+    /// Returns a builder for `ListPermissionSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListPermissionSetsRequestBuilder {
+        ListPermissionSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPermissionSetsRequest`](struct.ListPermissionSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPermissionSetsRequestBuilder {
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListPermissionSetsRequestBuilder {
+    /// Sets the [`instance_arn`](struct.ListPermissionSetsRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListPermissionSetsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPermissionSetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPermissionSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPermissionSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPermissionSetsRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListPermissionSetsResponse {
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>Defines the level of access on an Amazon Web Services account.</p></p>
+    #[serde(rename = "PermissionSets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_sets: Option<Vec<String>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListTagsForResourceRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_arn: Option<String>,
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>The ARN of the resource with the tags to be listed.</p></p>
+    #[serde(rename = "ResourceArn")]
+    pub resource_arn: String,
+}
+
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    instance_arn: Option<String>,
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`instance_arn`](struct.ListTagsForResourceRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsForResourceRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            instance_arn: self.instance_arn,
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListTagsForResourceResponse {
+    /// <p><p>The pagination token for the list API. Initially the value is null. Use the output of previous API calls to make subsequent calls.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>A set of key-value pairs that are used to manage the resource.</p></p>
+    #[serde(rename = "Tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListTrustedTokenIssuersRequest {
+    /// <p><p>Specifies the ARN of the instance of IAM Identity Center with the trusted token issuer configurations that you want to list.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>Specifies the total number of results that you want included in each response. If additional items exist beyond the number you specify, the <code>NextToken</code> response element is returned with a value (not null). Include the specified value as the <code>NextToken</code> request parameter in the next call to the operation to get the next set of results. Note that the service might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p></p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+    /// <p><p>Specifies that you want to receive the next page of results. Valid only if you received a <code>NextToken</code> response in the previous request. If you did, it indicates that more output is available. Set this parameter to the value provided by the previous call's <code>NextToken</code> response to request the next page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListTrustedTokenIssuersRequest {
+    /// Returns a builder for `ListTrustedTokenIssuersRequest`, checking its required members when built.
+    pub fn builder() -> ListTrustedTokenIssuersRequestBuilder {
+        ListTrustedTokenIssuersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTrustedTokenIssuersRequest`](struct.ListTrustedTokenIssuersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTrustedTokenIssuersRequestBuilder {
+    instance_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListTrustedTokenIssuersRequestBuilder {
+    /// Sets the [`instance_arn`](struct.ListTrustedTokenIssuersRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTrustedTokenIssuersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTrustedTokenIssuersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTrustedTokenIssuersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTrustedTokenIssuersRequest, ::rusoto_core::BuildError> {
+        Ok(ListTrustedTokenIssuersRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListTrustedTokenIssuersResponse {
+    /// <p><p>If present, this value indicates that more output is available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>. This indicates that this is the last page of results.</p></p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p><p>An array list of the trusted token issuer configurations.</p></p>
+    #[serde(rename = "TrustedTokenIssuers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_token_issuers: Option<Vec<TrustedTokenIssuerMetadata>>,
+}
+
+/// <p><p>A structure that describes configuration settings for a trusted token issuer that supports OpenID Connect (OIDC) and JSON Web Tokens (JWTs).</p></p>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OidcJwtConfiguration {
+    /// <p><p>The path of the source attribute in the JWT from the trusted token issuer. The attribute mapped by this JMESPath expression is compared against the attribute mapped by <code>IdentityStoreAttributePath</code> when a trusted token issuer token is exchanged for an IAM Identity Center token.</p></p>
+    #[serde(rename = "ClaimAttributePath")]
+    pub claim_attribute_path: String,
+    /// <p><p>The path of the destination attribute in a JWT from IAM Identity Center. The attribute mapped by this JMESPath expression is compared against the attribute mapped by <code>ClaimAttributePath</code> when a trusted token issuer token is exchanged for an IAM Identity Center token. </p></p>
+    #[serde(rename = "IdentityStoreAttributePath")]
+    pub identity_store_attribute_path: String,
+    /// <p><p>The URL that IAM Identity Center uses for OpenID Discovery. OpenID Discovery is used to obtain the information required to verify the tokens that the trusted token issuer generates.</p></p>
+    #[serde(rename = "IssuerUrl")]
+    pub issuer_url: String,
+    /// <p><p>The method that the trusted token issuer can use to retrieve the JSON Web Key Set used to verify a JWT.</p></p>
+    #[serde(rename = "JwksRetrievalOption")]
+    pub jwks_retrieval_option: JwksRetrievalOption,
+}
+
+/// <p><p>A structure that describes updated configuration settings for a trusted token issuer that supports OpenID Connect (OIDC) and JSON Web Tokens (JWTs).</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct OidcJwtUpdateConfiguration {
+    /// <p><p>The path of the source attribute in the JWT from the trusted token issuer. The attribute mapped by this JMESPath expression is compared against the attribute mapped by <code>IdentityStoreAttributePath</code> when a trusted token issuer token is exchanged for an IAM Identity Center token.</p></p>
+    #[serde(rename = "ClaimAttributePath")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_attribute_path: Option<String>,
+    /// <p><p>The path of the destination attribute in a JWT from IAM Identity Center. The attribute mapped by this JMESPath expression is compared against the attribute mapped by <code>ClaimAttributePath</code> when a trusted token issuer token is exchanged for an IAM Identity Center token.</p></p>
+    #[serde(rename = "IdentityStoreAttributePath")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_store_attribute_path: Option<String>,
+    /// <p><p>The method that the trusted token issuer can use to retrieve the JSON Web Key Set used to verify a JWT.</p></p>
+    #[serde(rename = "JwksRetrievalOption")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwks_retrieval_option: Option<JwksRetrievalOption>,
+}
+
+/// <p><p>Filters the operation status list based on the passed attribute value.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct OperationStatusFilter {
+    /// <p><p>Filters the list operations result based on the status attribute.</p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusValues>,
+}
+
+/// <p><p>An entity that contains IAM policies.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct PermissionSet {
+    /// <p><p>The date that the permission set was created.</p></p>
+    #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<EpochTimestamp>,
+    /// <p><p>The description of the <a>PermissionSet</a>.</p></p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p><p>The name of the permission set.</p></p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p><p>The ARN of the permission set. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_set_arn: Option<String>,
+    /// <p><p>Used to redirect users within the application during the federation authentication process.</p></p>
+    #[serde(rename = "RelayState")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relay_state: Option<String>,
+    /// <p><p>The length of time that the application user sessions are valid for in the ISO-8601 standard.</p></p>
+    #[serde(rename = "SessionDuration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_duration: Option<String>,
+}
+
+/// <p><p>A structure that is used to provide the status of the provisioning operation for a specified permission set.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct PermissionSetProvisioningStatus {
+    /// <p><p>The identifier of the Amazon Web Services account from which to list the assignments.</p></p>
+    #[serde(rename = "AccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// <p><p>The date that the permission set was created.</p></p>
+    #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<EpochTimestamp>,
+    /// <p><p>The message that contains an error or exception in case of an operation failure.</p></p>
+    #[serde(rename = "FailureReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+    /// <p><p>The ARN of the permission set that is being provisioned. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_set_arn: Option<String>,
+    /// <p><p>The identifier for tracking the request operation that is generated by the universally unique identifier (UUID) workflow.</p></p>
+    #[serde(rename = "RequestId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// <p><p>The status of the permission set provisioning process.</p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusValues>,
+}
+
+/// <p><p>Provides information about the permission set provisioning status.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct PermissionSetProvisioningStatusMetadata {
+    /// <p><p>The date that the permission set was created.</p></p>
+    #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<EpochTimestamp>,
+    /// <p><p>The identifier for tracking the request operation that is generated by the universally unique identifier (UUID) workflow.</p></p>
+    #[serde(rename = "RequestId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// <p><p>The status of the permission set provisioning process.</p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusValues>,
+}
+
+/// <p><p>Specifies the configuration of the Amazon Web Services managed or customer managed policy that you want to set as a permissions boundary. Specify either <code>CustomerManagedPolicyReference</code> to use the name and path of a customer managed policy, or <code>ManagedPolicyArn</code> to use the ARN of an Amazon Web Services managed policy. A permissions boundary represents the maximum permissions that any policy can grant your role. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_boundaries.html">Permissions boundaries for IAM entities</a> in the <i>IAM User Guide</i>.</p> <important> <p>Policies used as permissions boundaries don't provide permissions. You must also attach an IAM policy to the role. To learn how the effective permissions for a role are evaluated, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_evaluation-logic.html">IAM JSON policy evaluation logic</a> in the <i>IAM User Guide</i>.</p> </important></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PermissionsBoundary {
+    /// <p><p>Specifies the name and path of a customer managed policy. You must have an IAM policy that matches the name and path in each Amazon Web Services account where you want to deploy your permission set.</p></p>
+    #[serde(rename = "CustomerManagedPolicyReference")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_managed_policy_reference: Option<CustomerManagedPolicyReference>,
+    /// <p><p>The Amazon Web Services managed policy ARN that you want to attach to a permission set as a permissions boundary.</p></p>
+    #[serde(rename = "ManagedPolicyArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub managed_policy_arn: Option<String>,
+}
+
+/// <p><p>A structure that describes the options for the access portal associated with an application.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortalOptions {
+    /// <p><p>A structure that describes the sign-in options for the access portal.</p></p>
+    #[serde(rename = "SignInOptions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sign_in_options: Option<SignInOptions>,
+    /// <p><p>Indicates whether this application is visible in the access portal.</p></p>
+    #[serde(rename = "Visibility")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ApplicationVisibility>,
+}
+
+/// <p><p>The entity type for which the assignment will be created.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PrincipalType {
+    /// <p><code>USER</code></p>
+    User,
+    /// <p><code>GROUP</code></p>
+    Group,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PrincipalType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PrincipalType::User => "USER",
+            PrincipalType::Group => "GROUP",
+            PrincipalType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PrincipalType {
+    fn from(value: &'a str) -> PrincipalType {
+        match value {
+            "USER" => PrincipalType::User,
+            "GROUP" => PrincipalType::Group,
+            _ => PrincipalType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PrincipalType {
+    fn from(value: String) -> PrincipalType {
+        PrincipalType::from(value.as_str())
+    }
+}
+
+impl From<PrincipalType> for String {
+    fn from(value: PrincipalType) -> String {
+        match value {
+            PrincipalType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PrincipalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PrincipalType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PrincipalType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PrincipalType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PrincipalType::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProvisionPermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the permission set.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+    /// <p><p>TargetID is an Amazon Web Services account identifier, (For example, 123456789012).</p></p>
+    #[serde(rename = "TargetId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<String>,
+    /// <p><p>The entity type for which the assignment will be created.</p></p>
+    #[serde(rename = "TargetType")]
+    pub target_type: ProvisionTargetType,
+}
+
+impl ProvisionPermissionSetRequest {
+    /// Returns a builder for `ProvisionPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> ProvisionPermissionSetRequestBuilder {
+        ProvisionPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ProvisionPermissionSetRequest`](struct.ProvisionPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct ProvisionPermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+    target_id: Option<String>,
+    target_type: Option<ProvisionTargetType>,
+}
+
+impl ProvisionPermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.ProvisionPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.ProvisionPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_id`](struct.ProvisionPermissionSetRequest.html#structfield.target_id) member.
+    pub fn target_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_type`](struct.ProvisionPermissionSetRequest.html#structfield.target_type) member.
+    pub fn target_type<T: Into<ProvisionTargetType>>(mut self, value: T) -> Self {
+        self.target_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ProvisionPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ProvisionPermissionSetRequest, ::rusoto_core::BuildError> {
+        Ok(ProvisionPermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+            target_id: self.target_id,
+            target_type: self
+                .target_type
+                .ok_or(::rusoto_core::BuildError::missing("target_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ProvisionPermissionSetResponse {
+    /// <p><p>The status object for the permission set provisioning operation.</p></p>
+    #[serde(rename = "PermissionSetProvisioningStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_set_provisioning_status: Option<PermissionSetProvisioningStatus>,
+}
+
+/// <p><p>The entity type for which the assignment will be created.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProvisionTargetType {
+    /// <p><code>AWS_ACCOUNT</code></p>
+    AwsAccount,
+    /// <p><code>ALL_PROVISIONED_ACCOUNTS</code></p>
+    AllProvisionedAccounts,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProvisionTargetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisionTargetType::AwsAccount => "AWS_ACCOUNT",
+            ProvisionTargetType::AllProvisionedAccounts => "ALL_PROVISIONED_ACCOUNTS",
+            ProvisionTargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProvisionTargetType {
+    fn from(value: &'a str) -> ProvisionTargetType {
+        match value {
+            "AWS_ACCOUNT" => ProvisionTargetType::AwsAccount,
+            "ALL_PROVISIONED_ACCOUNTS" => ProvisionTargetType::AllProvisionedAccounts,
+            _ => ProvisionTargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisionTargetType {
+    fn from(value: String) -> ProvisionTargetType {
+        ProvisionTargetType::from(value.as_str())
+    }
+}
+
+impl From<ProvisionTargetType> for String {
+    fn from(value: ProvisionTargetType) -> String {
+        match value {
+            ProvisionTargetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProvisionTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProvisionTargetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisionTargetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProvisionTargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProvisionTargetType::from(value))
+    }
+}
+
+/// <p><p>The permission set provisioning status for an Amazon Web Services account.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProvisioningStatus {
+    /// <p><code>LATEST_PERMISSION_SET_PROVISIONED</code></p>
+    LatestPermissionSetProvisioned,
+    /// <p><code>LATEST_PERMISSION_SET_NOT_PROVISIONED</code></p>
+    LatestPermissionSetNotProvisioned,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProvisioningStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProvisioningStatus::LatestPermissionSetProvisioned => {
+                "LATEST_PERMISSION_SET_PROVISIONED"
+            }
+            ProvisioningStatus::LatestPermissionSetNotProvisioned => {
+                "LATEST_PERMISSION_SET_NOT_PROVISIONED"
+            }
+            ProvisioningStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProvisioningStatus {
+    fn from(value: &'a str) -> ProvisioningStatus {
+        match value {
+            "LATEST_PERMISSION_SET_PROVISIONED" => {
+                ProvisioningStatus::LatestPermissionSetProvisioned
+            }
+            "LATEST_PERMISSION_SET_NOT_PROVISIONED" => {
+                ProvisioningStatus::LatestPermissionSetNotProvisioned
+            }
+            _ => ProvisioningStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProvisioningStatus {
+    fn from(value: String) -> ProvisioningStatus {
+        ProvisioningStatus::from(value.as_str())
+    }
+}
+
+impl From<ProvisioningStatus> for String {
+    fn from(value: ProvisioningStatus) -> String {
+        match value {
+            ProvisioningStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProvisioningStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProvisioningStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProvisioningStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProvisioningStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProvisioningStatus::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct PutApplicationAccessScopeRequest {
+    /// <p><p>Specifies the ARN of the application with the access scope with the targets to add or update.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies an array list of ARNs that represent the authorized targets for this access scope.</p></p>
+    #[serde(rename = "AuthorizedTargets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_targets: Option<Vec<String>>,
+    /// <p><p>Specifies the name of the access scope to be associated with the specified targets.</p></p>
+    #[serde(rename = "Scope")]
+    pub scope: String,
+}
+
+impl PutApplicationAccessScopeRequest {
+    /// Returns a builder for `PutApplicationAccessScopeRequest`, checking its required members when built.
+    pub fn builder() -> PutApplicationAccessScopeRequestBuilder {
+        PutApplicationAccessScopeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutApplicationAccessScopeRequest`](struct.PutApplicationAccessScopeRequest.html).
+#[derive(Default, Debug)]
+pub struct PutApplicationAccessScopeRequestBuilder {
+    application_arn: Option<String>,
+    authorized_targets: Option<Vec<String>>,
+    scope: Option<String>,
+}
+
+impl PutApplicationAccessScopeRequestBuilder {
+    /// Sets the [`application_arn`](struct.PutApplicationAccessScopeRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorized_targets`](struct.PutApplicationAccessScopeRequest.html#structfield.authorized_targets) member.
+    pub fn authorized_targets<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.authorized_targets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scope`](struct.PutApplicationAccessScopeRequest.html#structfield.scope) member.
+    pub fn scope<T: Into<String>>(mut self, value: T) -> Self {
+        self.scope = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutApplicationAccessScopeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutApplicationAccessScopeRequest, ::rusoto_core::BuildError> {
+        Ok(PutApplicationAccessScopeRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            authorized_targets: self.authorized_targets,
+            scope: self
+                .scope
+                .ok_or(::rusoto_core::BuildError::missing("scope"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct PutApplicationAssignmentConfigurationRequest {
+    /// <p><p>Specifies the ARN of the application. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>If <code>AssignmentsRequired</code> is <code>true</code> (default value), users don’t have access to the application unless an assignment is created using the <a href="https://docs.aws.amazon.com/singlesignon/latest/APIReference/API_CreateApplicationAssignment.html">CreateApplicationAssignment API</a>. If <code>false</code>, all users have access to the application. </p></p>
+    #[serde(rename = "AssignmentRequired")]
+    pub assignment_required: bool,
+}
+
+impl PutApplicationAssignmentConfigurationRequest {
+    /// Returns a builder for `PutApplicationAssignmentConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> PutApplicationAssignmentConfigurationRequestBuilder {
+        PutApplicationAssignmentConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutApplicationAssignmentConfigurationRequest`](struct.PutApplicationAssignmentConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutApplicationAssignmentConfigurationRequestBuilder {
+    application_arn: Option<String>,
+    assignment_required: Option<bool>,
+}
+
+impl PutApplicationAssignmentConfigurationRequestBuilder {
+    /// Sets the [`application_arn`](struct.PutApplicationAssignmentConfigurationRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`assignment_required`](struct.PutApplicationAssignmentConfigurationRequest.html#structfield.assignment_required) member.
+    pub fn assignment_required<T: Into<bool>>(mut self, value: T) -> Self {
+        self.assignment_required = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutApplicationAssignmentConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        PutApplicationAssignmentConfigurationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(PutApplicationAssignmentConfigurationRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            assignment_required: self
+                .assignment_required
+                .ok_or(::rusoto_core::BuildError::missing("assignment_required"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct PutApplicationAssignmentConfigurationResponse {}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PutApplicationAuthenticationMethodRequest {
+    /// <p><p>Specifies the ARN of the application with the authentication method to add or update.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies a structure that describes the authentication method to add or update. The structure type you provide is determined by the <code>AuthenticationMethodType</code> parameter.</p></p>
+    #[serde(rename = "AuthenticationMethod")]
+    pub authentication_method: AuthenticationMethod,
+    /// <p><p>Specifies the type of the authentication method that you want to add or update.</p></p>
+    #[serde(rename = "AuthenticationMethodType")]
+    pub authentication_method_type: AuthenticationMethodType,
+}
+
+impl PutApplicationAuthenticationMethodRequest {
+    /// Returns a builder for `PutApplicationAuthenticationMethodRequest`, checking its required members when built.
+    pub fn builder() -> PutApplicationAuthenticationMethodRequestBuilder {
+        PutApplicationAuthenticationMethodRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutApplicationAuthenticationMethodRequest`](struct.PutApplicationAuthenticationMethodRequest.html).
+#[derive(Default, Debug)]
+pub struct PutApplicationAuthenticationMethodRequestBuilder {
+    application_arn: Option<String>,
+    authentication_method: Option<AuthenticationMethod>,
+    authentication_method_type: Option<AuthenticationMethodType>,
+}
+
+impl PutApplicationAuthenticationMethodRequestBuilder {
+    /// Sets the [`application_arn`](struct.PutApplicationAuthenticationMethodRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authentication_method`](struct.PutApplicationAuthenticationMethodRequest.html#structfield.authentication_method) member.
+    pub fn authentication_method<T: Into<AuthenticationMethod>>(mut self, value: T) -> Self {
+        self.authentication_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authentication_method_type`](struct.PutApplicationAuthenticationMethodRequest.html#structfield.authentication_method_type) member.
+    pub fn authentication_method_type<T: Into<AuthenticationMethodType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.authentication_method_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutApplicationAuthenticationMethodRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutApplicationAuthenticationMethodRequest, ::rusoto_core::BuildError>
+    {
+        Ok(PutApplicationAuthenticationMethodRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            authentication_method: self
+                .authentication_method
+                .ok_or(::rusoto_core::BuildError::missing("authentication_method"))?,
+            authentication_method_type: self.authentication_method_type.ok_or(
+                ::rusoto_core::BuildError::missing("authentication_method_type"),
+            )?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PutApplicationGrantRequest {
+    /// <p><p>Specifies the ARN of the application to update.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>Specifies a structure that describes the grant to update.</p></p>
+    #[serde(rename = "Grant")]
+    pub grant: Grant,
+    /// <p><p>Specifies the type of grant to update.</p></p>
+    #[serde(rename = "GrantType")]
+    pub grant_type: GrantType,
+}
+
+impl PutApplicationGrantRequest {
+    /// Returns a builder for `PutApplicationGrantRequest`, checking its required members when built.
+    pub fn builder() -> PutApplicationGrantRequestBuilder {
+        PutApplicationGrantRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutApplicationGrantRequest`](struct.PutApplicationGrantRequest.html).
+#[derive(Default, Debug)]
+pub struct PutApplicationGrantRequestBuilder {
+    application_arn: Option<String>,
+    grant: Option<Grant>,
+    grant_type: Option<GrantType>,
+}
+
+impl PutApplicationGrantRequestBuilder {
+    /// Sets the [`application_arn`](struct.PutApplicationGrantRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant`](struct.PutApplicationGrantRequest.html#structfield.grant) member.
+    pub fn grant<T: Into<Grant>>(mut self, value: T) -> Self {
+        self.grant = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant_type`](struct.PutApplicationGrantRequest.html#structfield.grant_type) member.
+    pub fn grant_type<T: Into<GrantType>>(mut self, value: T) -> Self {
+        self.grant_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutApplicationGrantRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutApplicationGrantRequest, ::rusoto_core::BuildError> {
+        Ok(PutApplicationGrantRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            grant: self
+                .grant
+                .ok_or(::rusoto_core::BuildError::missing("grant"))?,
+            grant_type: self
+                .grant_type
+                .ok_or(::rusoto_core::BuildError::missing("grant_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct PutInlinePolicyToPermissionSetRequest {
+    /// <p><p>The inline policy to attach to a <a>PermissionSet</a>.</p></p>
+    #[serde(rename = "InlinePolicy")]
+    pub inline_policy: String,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the permission set.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+}
+
+impl PutInlinePolicyToPermissionSetRequest {
+    /// Returns a builder for `PutInlinePolicyToPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> PutInlinePolicyToPermissionSetRequestBuilder {
+        PutInlinePolicyToPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutInlinePolicyToPermissionSetRequest`](struct.PutInlinePolicyToPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct PutInlinePolicyToPermissionSetRequestBuilder {
+    inline_policy: Option<String>,
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+}
+
+impl PutInlinePolicyToPermissionSetRequestBuilder {
+    /// Sets the [`inline_policy`](struct.PutInlinePolicyToPermissionSetRequest.html#structfield.inline_policy) member.
+    pub fn inline_policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.inline_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.PutInlinePolicyToPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.PutInlinePolicyToPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutInlinePolicyToPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutInlinePolicyToPermissionSetRequest, ::rusoto_core::BuildError>
+    {
+        Ok(PutInlinePolicyToPermissionSetRequest {
+            inline_policy: self
+                .inline_policy
+                .ok_or(::rusoto_core::BuildError::missing("inline_policy"))?,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct PutInlinePolicyToPermissionSetResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct PutPermissionsBoundaryToPermissionSetRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. </p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the <code>PermissionSet</code>.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+    /// <p><p>The permissions boundary that you want to attach to a <code>PermissionSet</code>.</p></p>
+    #[serde(rename = "PermissionsBoundary")]
+    pub permissions_boundary: PermissionsBoundary,
+}
+
+impl PutPermissionsBoundaryToPermissionSetRequest {
+    /// Returns a builder for `PutPermissionsBoundaryToPermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> PutPermissionsBoundaryToPermissionSetRequestBuilder {
+        PutPermissionsBoundaryToPermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutPermissionsBoundaryToPermissionSetRequest`](struct.PutPermissionsBoundaryToPermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct PutPermissionsBoundaryToPermissionSetRequestBuilder {
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+    permissions_boundary: Option<PermissionsBoundary>,
+}
+
+impl PutPermissionsBoundaryToPermissionSetRequestBuilder {
+    /// Sets the [`instance_arn`](struct.PutPermissionsBoundaryToPermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.PutPermissionsBoundaryToPermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permissions_boundary`](struct.PutPermissionsBoundaryToPermissionSetRequest.html#structfield.permissions_boundary) member.
+    pub fn permissions_boundary<T: Into<PermissionsBoundary>>(mut self, value: T) -> Self {
+        self.permissions_boundary = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutPermissionsBoundaryToPermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        PutPermissionsBoundaryToPermissionSetRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(PutPermissionsBoundaryToPermissionSetRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+            permissions_boundary: self
+                .permissions_boundary
+                .ok_or(::rusoto_core::BuildError::missing("permissions_boundary"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct PutPermissionsBoundaryToPermissionSetResponse {}
+
+/// <p><p>A structure that defines configuration settings for an application that supports the OAuth 2.0 Refresh Token Grant.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RefreshTokenGrant {}
+
+/// <p><p>A structure that describes the configuration of a resource server.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ResourceServerConfig {
+    /// <p><p>A list of the IAM Identity Center access scopes that are associated with this resource server.</p></p>
+    #[serde(rename = "Scopes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<::std::collections::HashMap<String, ResourceServerScopeDetails>>,
+}
+
+/// <p><p>A structure that describes details for an IAM Identity Center access scope that is associated with a resource server.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ResourceServerScopeDetails {
+    /// <p><p>The title of an access scope for a resource server.</p></p>
+    #[serde(rename = "DetailedTitle")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detailed_title: Option<String>,
+    /// <p><p>The description of an access scope for a resource server.</p></p>
+    #[serde(rename = "LongDescription")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub long_description: Option<String>,
+}
+
+/// <p><p>A structure that describes an IAM Identity Center access scope and its authorized targets.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ScopeDetails {
+    /// <p><p>An array list of ARNs of applications.</p></p>
+    #[serde(rename = "AuthorizedTargets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_targets: Option<Vec<String>>,
+    /// <p><p>The name of the access scope.</p></p>
+    #[serde(rename = "Scope")]
+    pub scope: String,
+}
+
+/// <p><p>A structure that describes the sign-in options for an application portal.</p></p>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignInOptions {
+    /// <p><p>The URL that accepts authentication requests for an application. This is a required parameter if the <code>Origin</code> parameter is <code>APPLICATION</code>.</p></p>
+    #[serde(rename = "ApplicationUrl")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_url: Option<String>,
+    /// <p><p>This determines how IAM Identity Center navigates the user to the target application. It can be one of the following values:</p> <ul> <li> <p> <code>APPLICATION</code>: IAM Identity Center redirects the customer to the configured <code>ApplicationUrl</code>.</p> </li> <li> <p> <code>IDENTITY_CENTER</code>: IAM Identity Center uses SAML identity-provider initiated authentication to sign the customer directly into a SAML-based application.</p> </li> </ul></p>
+    #[serde(rename = "Origin")]
+    pub origin: SignInOrigin,
+}
+
+/// <p><p>This determines how IAM Identity Center navigates the user to the target application. It can be one of the following values:</p> <ul> <li> <p> <code>APPLICATION</code>: IAM Identity Center redirects the customer to the configured <code>ApplicationUrl</code>.</p> </li> <li> <p> <code>IDENTITY_CENTER</code>: IAM Identity Center uses SAML identity-provider initiated authentication to sign the customer directly into a SAML-based application.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SignInOrigin {
+    /// <p><code>IDENTITY_CENTER</code> - IAM Identity Center uses SAML identity-provider initiated authentication to sign the customer directly into a SAML-based application.</p>
+    IdentityCenter,
+    /// <p><code>APPLICATION</code> - IAM Identity Center redirects the customer to the configured <code>ApplicationUrl</code>.</p>
+    Application,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SignInOrigin {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SignInOrigin::IdentityCenter => "IDENTITY_CENTER",
+            SignInOrigin::Application => "APPLICATION",
+            SignInOrigin::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SignInOrigin {
+    fn from(value: &'a str) -> SignInOrigin {
+        match value {
+            "IDENTITY_CENTER" => SignInOrigin::IdentityCenter,
+            "APPLICATION" => SignInOrigin::Application,
+            _ => SignInOrigin::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SignInOrigin {
+    fn from(value: String) -> SignInOrigin {
+        SignInOrigin::from(value.as_str())
+    }
+}
+
+impl From<SignInOrigin> for String {
+    fn from(value: SignInOrigin) -> String {
+        match value {
+            SignInOrigin::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SignInOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SignInOrigin {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SignInOrigin {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SignInOrigin, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SignInOrigin::from(value))
+    }
+}
+
+/// <p><p>The status of the permission set provisioning process.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatusValues {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StatusValues {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusValues::InProgress => "IN_PROGRESS",
+            StatusValues::Failed => "FAILED",
+            StatusValues::Succeeded => "SUCCEEDED",
+            StatusValues::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StatusValues {
+    fn from(value: &'a str) -> StatusValues {
+        match value {
+            "IN_PROGRESS" => StatusValues::InProgress,
+            "FAILED" => StatusValues::Failed,
+            "SUCCEEDED" => StatusValues::Succeeded,
+            _ => StatusValues::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusValues {
+    fn from(value: String) -> StatusValues {
+        StatusValues::from(value.as_str())
+    }
+}
+
+impl From<StatusValues> for String {
+    fn from(value: StatusValues) -> String {
+        match value {
+            StatusValues::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StatusValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StatusValues {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StatusValues {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StatusValues, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StatusValues::from(value))
+    }
+}
+
+/// <p><p>A set of key-value pairs that are used to manage the resource. Tags can only be applied to permission sets and cannot be applied to corresponding roles that IAM Identity Center creates in Amazon Web Services accounts.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tag {
+    /// <p><p>The key for the tag.</p></p>
+    #[serde(rename = "Key")]
+    pub key: String,
+    /// <p><p>The value of the tag.</p></p>
+    #[serde(rename = "Value")]
+    pub value: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct TagResourceRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_arn: Option<String>,
+    /// <p><p>The ARN of the resource with the tags to be listed.</p></p>
+    #[serde(rename = "ResourceArn")]
+    pub resource_arn: String,
+    /// <p><p>A set of key-value pairs that are used to manage the resource.</p></p>
+    #[serde(rename = "Tags")]
+    pub tags: Vec<Tag>,
+}
+
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    instance_arn: Option<String>,
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`instance_arn`](struct.TagResourceRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            instance_arn: self.instance_arn,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct TagResourceResponse {}
+
+/// <p><p>The entity type for which the assignment will be created.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetType {
+    /// <p><code>AWS_ACCOUNT</code></p>
+    AwsAccount,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetType::AwsAccount => "AWS_ACCOUNT",
+            TargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetType {
+    fn from(value: &'a str) -> TargetType {
+        match value {
+            "AWS_ACCOUNT" => TargetType::AwsAccount,
+            _ => TargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetType {
+    fn from(value: String) -> TargetType {
+        TargetType::from(value.as_str())
+    }
+}
+
+impl From<TargetType> for String {
+    fn from(value: TargetType) -> String {
+        match value {
+            TargetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TargetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TargetType::from(value))
+    }
+}
+
+/// <p><p>A structure that defines configuration settings for an application that supports the OAuth 2.0 Token Exchange Grant.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenExchangeGrant {}
+
+/// <p><p>A structure that describes the configuration of a trusted token issuer. The structure and available settings are determined by the type of the trusted token issuer.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrustedTokenIssuerConfiguration {
+    /// <p><p>A structure that describes the settings for a trusted token issuer that works with OpenID Connect (OIDC) by using JSON Web Tokens (JWT).</p></p>
+    #[serde(rename = "OidcJwtConfiguration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc_jwt_configuration: Option<OidcJwtConfiguration>,
+}
+
+/// <p><p>A structure that describes a trusted token issuer.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct TrustedTokenIssuerMetadata {
+    /// <p><p>The name of the trusted token issuer configuration in the instance of IAM Identity Center.</p></p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p><p>The ARN of the trusted token issuer configuration in the instance of IAM Identity Center.</p></p>
+    #[serde(rename = "TrustedTokenIssuerArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_token_issuer_arn: Option<String>,
+    /// <p><p>The type of trusted token issuer.</p></p>
+    #[serde(rename = "TrustedTokenIssuerType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_token_issuer_type: Option<TrustedTokenIssuerType>,
+}
+
+/// <p><p>Specifies the type of the new trusted token issuer.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TrustedTokenIssuerType {
+    /// <p><code>OIDC_JWT</code></p>
+    OidcJwt,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TrustedTokenIssuerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TrustedTokenIssuerType::OidcJwt => "OIDC_JWT",
+            TrustedTokenIssuerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TrustedTokenIssuerType {
+    fn from(value: &'a str) -> TrustedTokenIssuerType {
+        match value {
+            "OIDC_JWT" => TrustedTokenIssuerType::OidcJwt,
+            _ => TrustedTokenIssuerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TrustedTokenIssuerType {
+    fn from(value: String) -> TrustedTokenIssuerType {
+        TrustedTokenIssuerType::from(value.as_str())
+    }
+}
+
+impl From<TrustedTokenIssuerType> for String {
+    fn from(value: TrustedTokenIssuerType) -> String {
+        match value {
+            TrustedTokenIssuerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TrustedTokenIssuerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TrustedTokenIssuerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TrustedTokenIssuerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TrustedTokenIssuerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TrustedTokenIssuerType::from(value))
+    }
+}
+
+/// <p><p>A structure that contains details to be updated for a trusted token issuer configuration. The structure and settings that you can include depend on the type of the trusted token issuer being updated.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct TrustedTokenIssuerUpdateConfiguration {
+    /// <p><p>A structure that describes an updated configuration for a trusted token issuer that uses OpenID Connect (OIDC) with JSON web tokens (JWT).</p></p>
+    #[serde(rename = "OidcJwtConfiguration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc_jwt_configuration: Option<OidcJwtUpdateConfiguration>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UntagResourceRequest {
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_arn: Option<String>,
+    /// <p><p>The ARN of the resource with the tags to be listed.</p></p>
+    #[serde(rename = "ResourceArn")]
+    pub resource_arn: String,
+    /// <p><p>The keys of tags that are attached to the resource.</p></p>
+    #[serde(rename = "TagKeys")]
+    pub tag_keys: Vec<String>,
+}
+
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    instance_arn: Option<String>,
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`instance_arn`](struct.UntagResourceRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            instance_arn: self.instance_arn,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UntagResourceResponse {}
+
+/// <p><p>A structure that describes the options for the access portal associated with an application that can be updated.</p></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateApplicationPortalOptions {
+    #[serde(rename = "SignInOptions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sign_in_options: Option<SignInOptions>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateApplicationRequest {
+    /// <p><p>Specifies the ARN of the application. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "ApplicationArn")]
+    pub application_arn: String,
+    /// <p><p>The description of the .</p></p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p><p>Specifies the updated name for the application.</p></p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p><p>A structure that describes the options for the portal associated with an application.</p></p>
+    #[serde(rename = "PortalOptions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub portal_options: Option<UpdateApplicationPortalOptions>,
+    /// <p><p>Specifies whether the application is enabled or disabled.</p></p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ApplicationStatus>,
+}
+
+impl UpdateApplicationRequest {
+    /// Returns a builder for `UpdateApplicationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateApplicationRequestBuilder {
+        UpdateApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApplicationRequest`](struct.UpdateApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateApplicationRequestBuilder {
+    application_arn: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    portal_options: Option<UpdateApplicationPortalOptions>,
+    status: Option<ApplicationStatus>,
+}
+
+impl UpdateApplicationRequestBuilder {
+    /// Sets the [`application_arn`](struct.UpdateApplicationRequest.html#structfield.application_arn) member.
+    pub fn application_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateApplicationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateApplicationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`portal_options`](struct.UpdateApplicationRequest.html#structfield.portal_options) member.
+    pub fn portal_options<T: Into<UpdateApplicationPortalOptions>>(mut self, value: T) -> Self {
+        self.portal_options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.UpdateApplicationRequest.html#structfield.status) member.
+    pub fn status<T: Into<ApplicationStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateApplicationRequest {
+            application_arn: self
+                .application_arn
+                .ok_or(::rusoto_core::BuildError::missing("application_arn"))?,
+            description: self.description,
+            name: self.name,
+            portal_options: self.portal_options,
+            status: self.status,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateApplicationResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateInstanceAccessControlAttributeConfigurationRequest {
+    /// <p><p>Updates the attributes for your ABAC configuration.</p></p>
+    #[serde(rename = "InstanceAccessControlAttributeConfiguration")]
+    pub instance_access_control_attribute_configuration:
+        InstanceAccessControlAttributeConfiguration,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+}
+
+impl UpdateInstanceAccessControlAttributeConfigurationRequest {
+    /// Returns a builder for `UpdateInstanceAccessControlAttributeConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateInstanceAccessControlAttributeConfigurationRequestBuilder {
+        UpdateInstanceAccessControlAttributeConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateInstanceAccessControlAttributeConfigurationRequest`](struct.UpdateInstanceAccessControlAttributeConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateInstanceAccessControlAttributeConfigurationRequestBuilder {
+    instance_access_control_attribute_configuration:
+        Option<InstanceAccessControlAttributeConfiguration>,
+    instance_arn: Option<String>,
+}
+
+impl UpdateInstanceAccessControlAttributeConfigurationRequestBuilder {
+    /// Sets the [`instance_access_control_attribute_configuration`](struct.UpdateInstanceAccessControlAttributeConfigurationRequest.html#structfield.instance_access_control_attribute_configuration) member.
+    pub fn instance_access_control_attribute_configuration<
+        T: Into<InstanceAccessControlAttributeConfiguration>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.instance_access_control_attribute_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.UpdateInstanceAccessControlAttributeConfigurationRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateInstanceAccessControlAttributeConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        UpdateInstanceAccessControlAttributeConfigurationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(UpdateInstanceAccessControlAttributeConfigurationRequest {
+            instance_access_control_attribute_configuration: self
+                .instance_access_control_attribute_configuration
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "instance_access_control_attribute_configuration",
+                ))?,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateInstanceAccessControlAttributeConfigurationResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateInstanceRequest {
+    /// <p><p>The ARN of the instance of IAM Identity Center under which the operation will run. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>Updates the instance name.</p></p>
+    #[serde(rename = "Name")]
+    pub name: String,
+}
+
+impl UpdateInstanceRequest {
+    /// Returns a builder for `UpdateInstanceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateInstanceRequestBuilder {
+        UpdateInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateInstanceRequest`](struct.UpdateInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateInstanceRequestBuilder {
+    instance_arn: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateInstanceRequestBuilder {
+    /// Sets the [`instance_arn`](struct.UpdateInstanceRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateInstanceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateInstanceRequest {
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateInstanceResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdatePermissionSetRequest {
+    /// <p><p>The description of the <a>PermissionSet</a>.</p></p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p><p>The ARN of the IAM Identity Center instance under which the operation will be executed. For more information about ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and Amazon Web Services Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p></p>
+    #[serde(rename = "InstanceArn")]
+    pub instance_arn: String,
+    /// <p><p>The ARN of the permission set.</p></p>
+    #[serde(rename = "PermissionSetArn")]
+    pub permission_set_arn: String,
+    /// <p><p>Used to redirect users within the application during the federation authentication process.</p></p>
+    #[serde(rename = "RelayState")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relay_state: Option<String>,
+    /// <p><p>The length of time that the application user sessions are valid for in the ISO-8601 standard.</p></p>
+    #[serde(rename = "SessionDuration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_duration: Option<String>,
+}
+
+impl UpdatePermissionSetRequest {
+    /// Returns a builder for `UpdatePermissionSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdatePermissionSetRequestBuilder {
+        UpdatePermissionSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePermissionSetRequest`](struct.UpdatePermissionSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdatePermissionSetRequestBuilder {
+    description: Option<String>,
+    instance_arn: Option<String>,
+    permission_set_arn: Option<String>,
+    relay_state: Option<String>,
+    session_duration: Option<String>,
+}
+
+impl UpdatePermissionSetRequestBuilder {
+    /// Sets the [`description`](struct.UpdatePermissionSetRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.UpdatePermissionSetRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_set_arn`](struct.UpdatePermissionSetRequest.html#structfield.permission_set_arn) member.
+    pub fn permission_set_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.permission_set_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relay_state`](struct.UpdatePermissionSetRequest.html#structfield.relay_state) member.
+    pub fn relay_state<T: Into<String>>(mut self, value: T) -> Self {
+        self.relay_state = Some(value.into());
+        self
+    }
+
+    /// Sets the [`session_duration`](struct.UpdatePermissionSetRequest.html#structfield.session_duration) member.
+    pub fn session_duration<T: Into<String>>(mut self, value: T) -> Self {
+        self.session_duration = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePermissionSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdatePermissionSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdatePermissionSetRequest {
+            description: self.description,
+            instance_arn: self
+                .instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_arn"))?,
+            permission_set_arn: self
+                .permission_set_arn
+                .ok_or(::rusoto_core::BuildError::missing("permission_set_arn"))?,
+            relay_state: self.relay_state,
+            session_duration: self.session_duration,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdatePermissionSetResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateTrustedTokenIssuerRequest {
+    /// <p><p>Specifies the updated name to be applied to the trusted token issuer configuration.</p></p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p><p>Specifies the ARN of the trusted token issuer configuration that you want to update.</p></p>
+    #[serde(rename = "TrustedTokenIssuerArn")]
+    pub trusted_token_issuer_arn: String,
+    /// <p><p>Specifies a structure with settings to apply to the specified trusted token issuer. The settings that you can provide are determined by the type of the trusted token issuer that you are updating.</p></p>
+    #[serde(rename = "TrustedTokenIssuerConfiguration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_token_issuer_configuration: Option<TrustedTokenIssuerUpdateConfiguration>,
+}
+
+impl UpdateTrustedTokenIssuerRequest {
+    /// Returns a builder for `UpdateTrustedTokenIssuerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTrustedTokenIssuerRequestBuilder {
+        UpdateTrustedTokenIssuerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTrustedTokenIssuerRequest`](struct.UpdateTrustedTokenIssuerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTrustedTokenIssuerRequestBuilder {
+    name: Option<String>,
+    trusted_token_issuer_arn: Option<String>,
+    trusted_token_issuer_configuration: Option<TrustedTokenIssuerUpdateConfiguration>,
+}
+
+impl UpdateTrustedTokenIssuerRequestBuilder {
+    /// Sets the [`name`](struct.UpdateTrustedTokenIssuerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trusted_token_issuer_arn`](struct.UpdateTrustedTokenIssuerRequest.html#structfield.trusted_token_issuer_arn) member.
+    pub fn trusted_token_issuer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.trusted_token_issuer_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trusted_token_issuer_configuration`](struct.UpdateTrustedTokenIssuerRequest.html#structfield.trusted_token_issuer_configuration) member.
+    pub fn trusted_token_issuer_configuration<T: Into<TrustedTokenIssuerUpdateConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.trusted_token_issuer_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTrustedTokenIssuerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateTrustedTokenIssuerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTrustedTokenIssuerRequest {
+            name: self.name,
+            trusted_token_issuer_arn: self.trusted_token_issuer_arn.ok_or(
+                ::rusoto_core::BuildError::missing("trusted_token_issuer_arn"),
+            )?,
+            trusted_token_issuer_configuration: self.trusted_token_issuer_configuration,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateTrustedTokenIssuerResponse {}
+
+/// Errors returned by AttachCustomerManagedPolicyReferenceToPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum AttachCustomerManagedPolicyReferenceToPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the permitted number of resources that can be created.</p></p>
+    ServiceQuotaExceeded(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl AttachCustomerManagedPolicyReferenceToPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<AttachCustomerManagedPolicyReferenceToPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        AttachCustomerManagedPolicyReferenceToPermissionSetError::AccessDenied(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(
+                        AttachCustomerManagedPolicyReferenceToPermissionSetError::Conflict(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        AttachCustomerManagedPolicyReferenceToPermissionSetError::InternalServer(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        AttachCustomerManagedPolicyReferenceToPermissionSetError::ResourceNotFound(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ServiceQuotaExceededException" => return RusotoError::Service(
+                    AttachCustomerManagedPolicyReferenceToPermissionSetError::ServiceQuotaExceeded(
+                        err.msg,
+                    ),
+                ),
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        AttachCustomerManagedPolicyReferenceToPermissionSetError::Throttling(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for AttachCustomerManagedPolicyReferenceToPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for AttachCustomerManagedPolicyReferenceToPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            AttachCustomerManagedPolicyReferenceToPermissionSetError::AccessDenied(ref cause) => {
+                cause
+            }
+            AttachCustomerManagedPolicyReferenceToPermissionSetError::Conflict(ref cause) => cause,
+            AttachCustomerManagedPolicyReferenceToPermissionSetError::InternalServer(ref cause) => {
+                cause
+            }
+            AttachCustomerManagedPolicyReferenceToPermissionSetError::ResourceNotFound(
+                ref cause,
+            ) => cause,
+            AttachCustomerManagedPolicyReferenceToPermissionSetError::ServiceQuotaExceeded(
+                ref cause,
+            ) => cause,
+            AttachCustomerManagedPolicyReferenceToPermissionSetError::Throttling(ref cause) => {
+                cause
+            }
+        }
+    }
+}
+/// Errors returned by AttachManagedPolicyToPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum AttachManagedPolicyToPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the permitted number of resources that can be created.</p></p>
+    ServiceQuotaExceeded(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl AttachManagedPolicyToPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<AttachManagedPolicyToPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        AttachManagedPolicyToPermissionSetError::AccessDenied(err.msg),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(AttachManagedPolicyToPermissionSetError::Conflict(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        AttachManagedPolicyToPermissionSetError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        AttachManagedPolicyToPermissionSetError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ServiceQuotaExceededException" => {
+                    return RusotoError::Service(
+                        AttachManagedPolicyToPermissionSetError::ServiceQuotaExceeded(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        AttachManagedPolicyToPermissionSetError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for AttachManagedPolicyToPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for AttachManagedPolicyToPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            AttachManagedPolicyToPermissionSetError::AccessDenied(ref cause) => cause,
+            AttachManagedPolicyToPermissionSetError::Conflict(ref cause) => cause,
+            AttachManagedPolicyToPermissionSetError::InternalServer(ref cause) => cause,
+            AttachManagedPolicyToPermissionSetError::ResourceNotFound(ref cause) => cause,
+            AttachManagedPolicyToPermissionSetError::ServiceQuotaExceeded(ref cause) => cause,
+            AttachManagedPolicyToPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by CreateAccountAssignment
+#[derive(Debug, PartialEq)]
+pub enum CreateAccountAssignmentError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the permitted number of resources that can be created.</p></p>
+    ServiceQuotaExceeded(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl CreateAccountAssignmentError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateAccountAssignmentError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(CreateAccountAssignmentError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(CreateAccountAssignmentError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(CreateAccountAssignmentError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(CreateAccountAssignmentError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ServiceQuotaExceededException" => {
+                    return RusotoError::Service(
+                        CreateAccountAssignmentError::ServiceQuotaExceeded(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(CreateAccountAssignmentError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for CreateAccountAssignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for CreateAccountAssignmentError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateAccountAssignmentError::AccessDenied(ref cause) => cause,
+            CreateAccountAssignmentError::Conflict(ref cause) => cause,
+            CreateAccountAssignmentError::InternalServer(ref cause) => cause,
+            CreateAccountAssignmentError::ResourceNotFound(ref cause) => cause,
+            CreateAccountAssignmentError::ServiceQuotaExceeded(ref cause) => cause,
+            CreateAccountAssignmentError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by CreateApplication
+#[derive(Debug, PartialEq)]
+pub enum CreateApplicationError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the permitted number of resources that can be created.</p></p>
+    ServiceQuotaExceeded(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl CreateApplicationError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateApplicationError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(CreateApplicationError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(CreateApplicationError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(CreateApplicationError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(CreateApplicationError::ResourceNotFound(err.msg))
+                }
+                "ServiceQuotaExceededException" => {
+                    return RusotoError::Service(CreateApplicationError::ServiceQuotaExceeded(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(CreateApplicationError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for CreateApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for CreateApplicationError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateApplicationError::AccessDenied(ref cause) => cause,
+            CreateApplicationError::Conflict(ref cause) => cause,
+            CreateApplicationError::InternalServer(ref cause) => cause,
+            CreateApplicationError::ResourceNotFound(ref cause) => cause,
+            CreateApplicationError::ServiceQuotaExceeded(ref cause) => cause,
+            CreateApplicationError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by CreateApplicationAssignment
+#[derive(Debug, PartialEq)]
+pub enum CreateApplicationAssignmentError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the permitted number of resources that can be created.</p></p>
+    ServiceQuotaExceeded(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl CreateApplicationAssignmentError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<CreateApplicationAssignmentError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(CreateApplicationAssignmentError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(CreateApplicationAssignmentError::Conflict(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(CreateApplicationAssignmentError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        CreateApplicationAssignmentError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ServiceQuotaExceededException" => {
+                    return RusotoError::Service(
+                        CreateApplicationAssignmentError::ServiceQuotaExceeded(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(CreateApplicationAssignmentError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for CreateApplicationAssignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for CreateApplicationAssignmentError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateApplicationAssignmentError::AccessDenied(ref cause) => cause,
+            CreateApplicationAssignmentError::Conflict(ref cause) => cause,
+            CreateApplicationAssignmentError::InternalServer(ref cause) => cause,
+            CreateApplicationAssignmentError::ResourceNotFound(ref cause) => cause,
+            CreateApplicationAssignmentError::ServiceQuotaExceeded(ref cause) => cause,
+            CreateApplicationAssignmentError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by CreateInstance
+#[derive(Debug, PartialEq)]
+pub enum CreateInstanceError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that the principal has crossed the permitted number of resources that can be created.</p></p>
+    ServiceQuotaExceeded(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl CreateInstanceError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateInstanceError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(CreateInstanceError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(CreateInstanceError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(CreateInstanceError::InternalServer(err.msg))
+                }
+                "ServiceQuotaExceededException" => {
+                    return RusotoError::Service(CreateInstanceError::ServiceQuotaExceeded(err.msg))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(CreateInstanceError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for CreateInstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for CreateInstanceError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateInstanceError::AccessDenied(ref cause) => cause,
+            CreateInstanceError::Conflict(ref cause) => cause,
+            CreateInstanceError::InternalServer(ref cause) => cause,
+            CreateInstanceError::ServiceQuotaExceeded(ref cause) => cause,
+            CreateInstanceError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by CreateInstanceAccessControlAttributeConfiguration
+#[derive(Debug, PartialEq)]
+pub enum CreateInstanceAccessControlAttributeConfigurationError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl CreateInstanceAccessControlAttributeConfigurationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<CreateInstanceAccessControlAttributeConfigurationError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        CreateInstanceAccessControlAttributeConfigurationError::AccessDenied(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(
+                        CreateInstanceAccessControlAttributeConfigurationError::Conflict(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        CreateInstanceAccessControlAttributeConfigurationError::InternalServer(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        CreateInstanceAccessControlAttributeConfigurationError::ResourceNotFound(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        CreateInstanceAccessControlAttributeConfigurationError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for CreateInstanceAccessControlAttributeConfigurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for CreateInstanceAccessControlAttributeConfigurationError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateInstanceAccessControlAttributeConfigurationError::AccessDenied(ref cause) => {
+                cause
+            }
+            CreateInstanceAccessControlAttributeConfigurationError::Conflict(ref cause) => cause,
+            CreateInstanceAccessControlAttributeConfigurationError::InternalServer(ref cause) => {
+                cause
+            }
+            CreateInstanceAccessControlAttributeConfigurationError::ResourceNotFound(ref cause) => {
+                cause
+            }
+            CreateInstanceAccessControlAttributeConfigurationError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by CreatePermissionSet
+#[derive(Debug, PartialEq)]
+pub enum CreatePermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the permitted number of resources that can be created.</p></p>
+    ServiceQuotaExceeded(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl CreatePermissionSetError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreatePermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(CreatePermissionSetError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(CreatePermissionSetError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(CreatePermissionSetError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(CreatePermissionSetError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ServiceQuotaExceededException" => {
+                    return RusotoError::Service(CreatePermissionSetError::ServiceQuotaExceeded(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(CreatePermissionSetError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for CreatePermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for CreatePermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            CreatePermissionSetError::AccessDenied(ref cause) => cause,
+            CreatePermissionSetError::Conflict(ref cause) => cause,
+            CreatePermissionSetError::InternalServer(ref cause) => cause,
+            CreatePermissionSetError::ResourceNotFound(ref cause) => cause,
+            CreatePermissionSetError::ServiceQuotaExceeded(ref cause) => cause,
+            CreatePermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by CreateTrustedTokenIssuer
+#[derive(Debug, PartialEq)]
+pub enum CreateTrustedTokenIssuerError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that the principal has crossed the permitted number of resources that can be created.</p></p>
+    ServiceQuotaExceeded(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl CreateTrustedTokenIssuerError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateTrustedTokenIssuerError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(CreateTrustedTokenIssuerError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(CreateTrustedTokenIssuerError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(CreateTrustedTokenIssuerError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ServiceQuotaExceededException" => {
+                    return RusotoError::Service(
+                        CreateTrustedTokenIssuerError::ServiceQuotaExceeded(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(CreateTrustedTokenIssuerError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for CreateTrustedTokenIssuerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for CreateTrustedTokenIssuerError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateTrustedTokenIssuerError::AccessDenied(ref cause) => cause,
+            CreateTrustedTokenIssuerError::Conflict(ref cause) => cause,
+            CreateTrustedTokenIssuerError::InternalServer(ref cause) => cause,
+            CreateTrustedTokenIssuerError::ServiceQuotaExceeded(ref cause) => cause,
+            CreateTrustedTokenIssuerError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeleteAccountAssignment
+#[derive(Debug, PartialEq)]
+pub enum DeleteAccountAssignmentError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeleteAccountAssignmentError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteAccountAssignmentError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DeleteAccountAssignmentError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(DeleteAccountAssignmentError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DeleteAccountAssignmentError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(DeleteAccountAssignmentError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DeleteAccountAssignmentError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeleteAccountAssignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeleteAccountAssignmentError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteAccountAssignmentError::AccessDenied(ref cause) => cause,
+            DeleteAccountAssignmentError::Conflict(ref cause) => cause,
+            DeleteAccountAssignmentError::InternalServer(ref cause) => cause,
+            DeleteAccountAssignmentError::ResourceNotFound(ref cause) => cause,
+            DeleteAccountAssignmentError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeleteApplication
+#[derive(Debug, PartialEq)]
+pub enum DeleteApplicationError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeleteApplicationError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteApplicationError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DeleteApplicationError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(DeleteApplicationError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DeleteApplicationError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DeleteApplicationError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeleteApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeleteApplicationError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteApplicationError::AccessDenied(ref cause) => cause,
+            DeleteApplicationError::Conflict(ref cause) => cause,
+            DeleteApplicationError::InternalServer(ref cause) => cause,
+            DeleteApplicationError::ResourceNotFound(ref cause) => cause,
+            DeleteApplicationError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeleteApplicationAccessScope
+#[derive(Debug, PartialEq)]
+pub enum DeleteApplicationAccessScopeError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeleteApplicationAccessScopeError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DeleteApplicationAccessScopeError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DeleteApplicationAccessScopeError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(DeleteApplicationAccessScopeError::Conflict(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DeleteApplicationAccessScopeError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DeleteApplicationAccessScopeError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DeleteApplicationAccessScopeError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeleteApplicationAccessScopeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeleteApplicationAccessScopeError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteApplicationAccessScopeError::AccessDenied(ref cause) => cause,
+            DeleteApplicationAccessScopeError::Conflict(ref cause) => cause,
+            DeleteApplicationAccessScopeError::InternalServer(ref cause) => cause,
+            DeleteApplicationAccessScopeError::ResourceNotFound(ref cause) => cause,
+            DeleteApplicationAccessScopeError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeleteApplicationAssignment
+#[derive(Debug, PartialEq)]
+pub enum DeleteApplicationAssignmentError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeleteApplicationAssignmentError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DeleteApplicationAssignmentError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DeleteApplicationAssignmentError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(DeleteApplicationAssignmentError::Conflict(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DeleteApplicationAssignmentError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DeleteApplicationAssignmentError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DeleteApplicationAssignmentError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeleteApplicationAssignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeleteApplicationAssignmentError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteApplicationAssignmentError::AccessDenied(ref cause) => cause,
+            DeleteApplicationAssignmentError::Conflict(ref cause) => cause,
+            DeleteApplicationAssignmentError::InternalServer(ref cause) => cause,
+            DeleteApplicationAssignmentError::ResourceNotFound(ref cause) => cause,
+            DeleteApplicationAssignmentError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeleteApplicationAuthenticationMethod
+#[derive(Debug, PartialEq)]
+pub enum DeleteApplicationAuthenticationMethodError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeleteApplicationAuthenticationMethodError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DeleteApplicationAuthenticationMethodError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        DeleteApplicationAuthenticationMethodError::AccessDenied(err.msg),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(
+                        DeleteApplicationAuthenticationMethodError::Conflict(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        DeleteApplicationAuthenticationMethodError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DeleteApplicationAuthenticationMethodError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        DeleteApplicationAuthenticationMethodError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeleteApplicationAuthenticationMethodError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeleteApplicationAuthenticationMethodError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteApplicationAuthenticationMethodError::AccessDenied(ref cause) => cause,
+            DeleteApplicationAuthenticationMethodError::Conflict(ref cause) => cause,
+            DeleteApplicationAuthenticationMethodError::InternalServer(ref cause) => cause,
+            DeleteApplicationAuthenticationMethodError::ResourceNotFound(ref cause) => cause,
+            DeleteApplicationAuthenticationMethodError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeleteApplicationGrant
+#[derive(Debug, PartialEq)]
+pub enum DeleteApplicationGrantError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeleteApplicationGrantError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteApplicationGrantError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DeleteApplicationGrantError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(DeleteApplicationGrantError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DeleteApplicationGrantError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(DeleteApplicationGrantError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DeleteApplicationGrantError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeleteApplicationGrantError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeleteApplicationGrantError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteApplicationGrantError::AccessDenied(ref cause) => cause,
+            DeleteApplicationGrantError::Conflict(ref cause) => cause,
+            DeleteApplicationGrantError::InternalServer(ref cause) => cause,
+            DeleteApplicationGrantError::ResourceNotFound(ref cause) => cause,
+            DeleteApplicationGrantError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeleteInlinePolicyFromPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum DeleteInlinePolicyFromPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeleteInlinePolicyFromPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DeleteInlinePolicyFromPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        DeleteInlinePolicyFromPermissionSetError::AccessDenied(err.msg),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(
+                        DeleteInlinePolicyFromPermissionSetError::Conflict(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        DeleteInlinePolicyFromPermissionSetError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DeleteInlinePolicyFromPermissionSetError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        DeleteInlinePolicyFromPermissionSetError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeleteInlinePolicyFromPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeleteInlinePolicyFromPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteInlinePolicyFromPermissionSetError::AccessDenied(ref cause) => cause,
+            DeleteInlinePolicyFromPermissionSetError::Conflict(ref cause) => cause,
+            DeleteInlinePolicyFromPermissionSetError::InternalServer(ref cause) => cause,
+            DeleteInlinePolicyFromPermissionSetError::ResourceNotFound(ref cause) => cause,
+            DeleteInlinePolicyFromPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeleteInstance
+#[derive(Debug, PartialEq)]
+pub enum DeleteInstanceError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeleteInstanceError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteInstanceError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DeleteInstanceError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(DeleteInstanceError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DeleteInstanceError::InternalServer(err.msg))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DeleteInstanceError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeleteInstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeleteInstanceError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteInstanceError::AccessDenied(ref cause) => cause,
+            DeleteInstanceError::Conflict(ref cause) => cause,
+            DeleteInstanceError::InternalServer(ref cause) => cause,
+            DeleteInstanceError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeleteInstanceAccessControlAttributeConfiguration
+#[derive(Debug, PartialEq)]
+pub enum DeleteInstanceAccessControlAttributeConfigurationError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeleteInstanceAccessControlAttributeConfigurationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DeleteInstanceAccessControlAttributeConfigurationError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        DeleteInstanceAccessControlAttributeConfigurationError::AccessDenied(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(
+                        DeleteInstanceAccessControlAttributeConfigurationError::Conflict(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        DeleteInstanceAccessControlAttributeConfigurationError::InternalServer(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DeleteInstanceAccessControlAttributeConfigurationError::ResourceNotFound(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        DeleteInstanceAccessControlAttributeConfigurationError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeleteInstanceAccessControlAttributeConfigurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeleteInstanceAccessControlAttributeConfigurationError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteInstanceAccessControlAttributeConfigurationError::AccessDenied(ref cause) => {
+                cause
+            }
+            DeleteInstanceAccessControlAttributeConfigurationError::Conflict(ref cause) => cause,
+            DeleteInstanceAccessControlAttributeConfigurationError::InternalServer(ref cause) => {
+                cause
+            }
+            DeleteInstanceAccessControlAttributeConfigurationError::ResourceNotFound(ref cause) => {
+                cause
+            }
+            DeleteInstanceAccessControlAttributeConfigurationError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeletePermissionSet
+#[derive(Debug, PartialEq)]
+pub enum DeletePermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeletePermissionSetError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeletePermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DeletePermissionSetError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(DeletePermissionSetError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DeletePermissionSetError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(DeletePermissionSetError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DeletePermissionSetError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeletePermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeletePermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            DeletePermissionSetError::AccessDenied(ref cause) => cause,
+            DeletePermissionSetError::Conflict(ref cause) => cause,
+            DeletePermissionSetError::InternalServer(ref cause) => cause,
+            DeletePermissionSetError::ResourceNotFound(ref cause) => cause,
+            DeletePermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeletePermissionsBoundaryFromPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum DeletePermissionsBoundaryFromPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeletePermissionsBoundaryFromPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DeletePermissionsBoundaryFromPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        DeletePermissionsBoundaryFromPermissionSetError::AccessDenied(err.msg),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(
+                        DeletePermissionsBoundaryFromPermissionSetError::Conflict(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        DeletePermissionsBoundaryFromPermissionSetError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DeletePermissionsBoundaryFromPermissionSetError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        DeletePermissionsBoundaryFromPermissionSetError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeletePermissionsBoundaryFromPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeletePermissionsBoundaryFromPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            DeletePermissionsBoundaryFromPermissionSetError::AccessDenied(ref cause) => cause,
+            DeletePermissionsBoundaryFromPermissionSetError::Conflict(ref cause) => cause,
+            DeletePermissionsBoundaryFromPermissionSetError::InternalServer(ref cause) => cause,
+            DeletePermissionsBoundaryFromPermissionSetError::ResourceNotFound(ref cause) => cause,
+            DeletePermissionsBoundaryFromPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeleteTrustedTokenIssuer
+#[derive(Debug, PartialEq)]
+pub enum DeleteTrustedTokenIssuerError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DeleteTrustedTokenIssuerError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteTrustedTokenIssuerError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DeleteTrustedTokenIssuerError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(DeleteTrustedTokenIssuerError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DeleteTrustedTokenIssuerError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(DeleteTrustedTokenIssuerError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DeleteTrustedTokenIssuerError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DeleteTrustedTokenIssuerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DeleteTrustedTokenIssuerError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteTrustedTokenIssuerError::AccessDenied(ref cause) => cause,
+            DeleteTrustedTokenIssuerError::Conflict(ref cause) => cause,
+            DeleteTrustedTokenIssuerError::InternalServer(ref cause) => cause,
+            DeleteTrustedTokenIssuerError::ResourceNotFound(ref cause) => cause,
+            DeleteTrustedTokenIssuerError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DescribeAccountAssignmentCreationStatus
+#[derive(Debug, PartialEq)]
+pub enum DescribeAccountAssignmentCreationStatusError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DescribeAccountAssignmentCreationStatusError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DescribeAccountAssignmentCreationStatusError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        DescribeAccountAssignmentCreationStatusError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        DescribeAccountAssignmentCreationStatusError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DescribeAccountAssignmentCreationStatusError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        DescribeAccountAssignmentCreationStatusError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DescribeAccountAssignmentCreationStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DescribeAccountAssignmentCreationStatusError {
+    fn description(&self) -> &str {
+        match *self {
+            DescribeAccountAssignmentCreationStatusError::AccessDenied(ref cause) => cause,
+            DescribeAccountAssignmentCreationStatusError::InternalServer(ref cause) => cause,
+            DescribeAccountAssignmentCreationStatusError::ResourceNotFound(ref cause) => cause,
+            DescribeAccountAssignmentCreationStatusError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DescribeAccountAssignmentDeletionStatus
+#[derive(Debug, PartialEq)]
+pub enum DescribeAccountAssignmentDeletionStatusError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DescribeAccountAssignmentDeletionStatusError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DescribeAccountAssignmentDeletionStatusError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        DescribeAccountAssignmentDeletionStatusError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        DescribeAccountAssignmentDeletionStatusError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DescribeAccountAssignmentDeletionStatusError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        DescribeAccountAssignmentDeletionStatusError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DescribeAccountAssignmentDeletionStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DescribeAccountAssignmentDeletionStatusError {
+    fn description(&self) -> &str {
+        match *self {
+            DescribeAccountAssignmentDeletionStatusError::AccessDenied(ref cause) => cause,
+            DescribeAccountAssignmentDeletionStatusError::InternalServer(ref cause) => cause,
+            DescribeAccountAssignmentDeletionStatusError::ResourceNotFound(ref cause) => cause,
+            DescribeAccountAssignmentDeletionStatusError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DescribeApplication
+#[derive(Debug, PartialEq)]
+pub enum DescribeApplicationError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DescribeApplicationError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeApplicationError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DescribeApplicationError::AccessDenied(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DescribeApplicationError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(DescribeApplicationError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DescribeApplicationError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DescribeApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DescribeApplicationError {
+    fn description(&self) -> &str {
+        match *self {
+            DescribeApplicationError::AccessDenied(ref cause) => cause,
+            DescribeApplicationError::InternalServer(ref cause) => cause,
+            DescribeApplicationError::ResourceNotFound(ref cause) => cause,
+            DescribeApplicationError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DescribeApplicationAssignment
+#[derive(Debug, PartialEq)]
+pub enum DescribeApplicationAssignmentError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DescribeApplicationAssignmentError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DescribeApplicationAssignmentError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DescribeApplicationAssignmentError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        DescribeApplicationAssignmentError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DescribeApplicationAssignmentError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DescribeApplicationAssignmentError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DescribeApplicationAssignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DescribeApplicationAssignmentError {
+    fn description(&self) -> &str {
+        match *self {
+            DescribeApplicationAssignmentError::AccessDenied(ref cause) => cause,
+            DescribeApplicationAssignmentError::InternalServer(ref cause) => cause,
+            DescribeApplicationAssignmentError::ResourceNotFound(ref cause) => cause,
+            DescribeApplicationAssignmentError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DescribeApplicationProvider
+#[derive(Debug, PartialEq)]
+pub enum DescribeApplicationProviderError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DescribeApplicationProviderError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DescribeApplicationProviderError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DescribeApplicationProviderError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DescribeApplicationProviderError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DescribeApplicationProviderError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DescribeApplicationProviderError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DescribeApplicationProviderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DescribeApplicationProviderError {
+    fn description(&self) -> &str {
+        match *self {
+            DescribeApplicationProviderError::AccessDenied(ref cause) => cause,
+            DescribeApplicationProviderError::InternalServer(ref cause) => cause,
+            DescribeApplicationProviderError::ResourceNotFound(ref cause) => cause,
+            DescribeApplicationProviderError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DescribeInstance
+#[derive(Debug, PartialEq)]
+pub enum DescribeInstanceError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DescribeInstanceError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeInstanceError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DescribeInstanceError::AccessDenied(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DescribeInstanceError::InternalServer(err.msg))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DescribeInstanceError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DescribeInstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DescribeInstanceError {
+    fn description(&self) -> &str {
+        match *self {
+            DescribeInstanceError::AccessDenied(ref cause) => cause,
+            DescribeInstanceError::InternalServer(ref cause) => cause,
+            DescribeInstanceError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DescribeInstanceAccessControlAttributeConfiguration
+#[derive(Debug, PartialEq)]
+pub enum DescribeInstanceAccessControlAttributeConfigurationError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DescribeInstanceAccessControlAttributeConfigurationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DescribeInstanceAccessControlAttributeConfigurationError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        DescribeInstanceAccessControlAttributeConfigurationError::AccessDenied(
+                            err.msg,
+                        ),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        DescribeInstanceAccessControlAttributeConfigurationError::InternalServer(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DescribeInstanceAccessControlAttributeConfigurationError::ResourceNotFound(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        DescribeInstanceAccessControlAttributeConfigurationError::Throttling(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DescribeInstanceAccessControlAttributeConfigurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DescribeInstanceAccessControlAttributeConfigurationError {
+    fn description(&self) -> &str {
+        match *self {
+            DescribeInstanceAccessControlAttributeConfigurationError::AccessDenied(ref cause) => {
+                cause
+            }
+            DescribeInstanceAccessControlAttributeConfigurationError::InternalServer(ref cause) => {
+                cause
+            }
+            DescribeInstanceAccessControlAttributeConfigurationError::ResourceNotFound(
+                ref cause,
+            ) => cause,
+            DescribeInstanceAccessControlAttributeConfigurationError::Throttling(ref cause) => {
+                cause
+            }
+        }
+    }
+}
+/// Errors returned by DescribePermissionSet
+#[derive(Debug, PartialEq)]
+pub enum DescribePermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DescribePermissionSetError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribePermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DescribePermissionSetError::AccessDenied(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DescribePermissionSetError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(DescribePermissionSetError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DescribePermissionSetError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DescribePermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DescribePermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            DescribePermissionSetError::AccessDenied(ref cause) => cause,
+            DescribePermissionSetError::InternalServer(ref cause) => cause,
+            DescribePermissionSetError::ResourceNotFound(ref cause) => cause,
+            DescribePermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DescribePermissionSetProvisioningStatus
+#[derive(Debug, PartialEq)]
+pub enum DescribePermissionSetProvisioningStatusError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DescribePermissionSetProvisioningStatusError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DescribePermissionSetProvisioningStatusError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        DescribePermissionSetProvisioningStatusError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        DescribePermissionSetProvisioningStatusError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DescribePermissionSetProvisioningStatusError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        DescribePermissionSetProvisioningStatusError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DescribePermissionSetProvisioningStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DescribePermissionSetProvisioningStatusError {
+    fn description(&self) -> &str {
+        match *self {
+            DescribePermissionSetProvisioningStatusError::AccessDenied(ref cause) => cause,
+            DescribePermissionSetProvisioningStatusError::InternalServer(ref cause) => cause,
+            DescribePermissionSetProvisioningStatusError::ResourceNotFound(ref cause) => cause,
+            DescribePermissionSetProvisioningStatusError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DescribeTrustedTokenIssuer
+#[derive(Debug, PartialEq)]
+pub enum DescribeTrustedTokenIssuerError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DescribeTrustedTokenIssuerError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DescribeTrustedTokenIssuerError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(DescribeTrustedTokenIssuerError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(DescribeTrustedTokenIssuerError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(DescribeTrustedTokenIssuerError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(DescribeTrustedTokenIssuerError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DescribeTrustedTokenIssuerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DescribeTrustedTokenIssuerError {
+    fn description(&self) -> &str {
+        match *self {
+            DescribeTrustedTokenIssuerError::AccessDenied(ref cause) => cause,
+            DescribeTrustedTokenIssuerError::InternalServer(ref cause) => cause,
+            DescribeTrustedTokenIssuerError::ResourceNotFound(ref cause) => cause,
+            DescribeTrustedTokenIssuerError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DetachCustomerManagedPolicyReferenceFromPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum DetachCustomerManagedPolicyReferenceFromPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DetachCustomerManagedPolicyReferenceFromPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DetachCustomerManagedPolicyReferenceFromPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        DetachCustomerManagedPolicyReferenceFromPermissionSetError::AccessDenied(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(
+                        DetachCustomerManagedPolicyReferenceFromPermissionSetError::Conflict(
+                            err.msg,
+                        ),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        DetachCustomerManagedPolicyReferenceFromPermissionSetError::InternalServer(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ResourceNotFoundException" => return RusotoError::Service(
+                    DetachCustomerManagedPolicyReferenceFromPermissionSetError::ResourceNotFound(
+                        err.msg,
+                    ),
+                ),
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        DetachCustomerManagedPolicyReferenceFromPermissionSetError::Throttling(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DetachCustomerManagedPolicyReferenceFromPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DetachCustomerManagedPolicyReferenceFromPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            DetachCustomerManagedPolicyReferenceFromPermissionSetError::AccessDenied(ref cause) => {
+                cause
+            }
+            DetachCustomerManagedPolicyReferenceFromPermissionSetError::Conflict(ref cause) => {
+                cause
+            }
+            DetachCustomerManagedPolicyReferenceFromPermissionSetError::InternalServer(
+                ref cause,
+            ) => cause,
+            DetachCustomerManagedPolicyReferenceFromPermissionSetError::ResourceNotFound(
+                ref cause,
+            ) => cause,
+            DetachCustomerManagedPolicyReferenceFromPermissionSetError::Throttling(ref cause) => {
+                cause
+            }
+        }
+    }
+}
+/// Errors returned by DetachManagedPolicyFromPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum DetachManagedPolicyFromPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl DetachManagedPolicyFromPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DetachManagedPolicyFromPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        DetachManagedPolicyFromPermissionSetError::AccessDenied(err.msg),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(
+                        DetachManagedPolicyFromPermissionSetError::Conflict(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        DetachManagedPolicyFromPermissionSetError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        DetachManagedPolicyFromPermissionSetError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        DetachManagedPolicyFromPermissionSetError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for DetachManagedPolicyFromPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for DetachManagedPolicyFromPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            DetachManagedPolicyFromPermissionSetError::AccessDenied(ref cause) => cause,
+            DetachManagedPolicyFromPermissionSetError::Conflict(ref cause) => cause,
+            DetachManagedPolicyFromPermissionSetError::InternalServer(ref cause) => cause,
+            DetachManagedPolicyFromPermissionSetError::ResourceNotFound(ref cause) => cause,
+            DetachManagedPolicyFromPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by GetApplicationAccessScope
+#[derive(Debug, PartialEq)]
+pub enum GetApplicationAccessScopeError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl GetApplicationAccessScopeError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetApplicationAccessScopeError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(GetApplicationAccessScopeError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(GetApplicationAccessScopeError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(GetApplicationAccessScopeError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(GetApplicationAccessScopeError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for GetApplicationAccessScopeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for GetApplicationAccessScopeError {
+    fn description(&self) -> &str {
+        match *self {
+            GetApplicationAccessScopeError::AccessDenied(ref cause) => cause,
+            GetApplicationAccessScopeError::InternalServer(ref cause) => cause,
+            GetApplicationAccessScopeError::ResourceNotFound(ref cause) => cause,
+            GetApplicationAccessScopeError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by GetApplicationAssignmentConfiguration
+#[derive(Debug, PartialEq)]
+pub enum GetApplicationAssignmentConfigurationError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl GetApplicationAssignmentConfigurationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<GetApplicationAssignmentConfigurationError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        GetApplicationAssignmentConfigurationError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        GetApplicationAssignmentConfigurationError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        GetApplicationAssignmentConfigurationError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        GetApplicationAssignmentConfigurationError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for GetApplicationAssignmentConfigurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for GetApplicationAssignmentConfigurationError {
+    fn description(&self) -> &str {
+        match *self {
+            GetApplicationAssignmentConfigurationError::AccessDenied(ref cause) => cause,
+            GetApplicationAssignmentConfigurationError::InternalServer(ref cause) => cause,
+            GetApplicationAssignmentConfigurationError::ResourceNotFound(ref cause) => cause,
+            GetApplicationAssignmentConfigurationError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by GetApplicationAuthenticationMethod
+#[derive(Debug, PartialEq)]
+pub enum GetApplicationAuthenticationMethodError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl GetApplicationAuthenticationMethodError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<GetApplicationAuthenticationMethodError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        GetApplicationAuthenticationMethodError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        GetApplicationAuthenticationMethodError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        GetApplicationAuthenticationMethodError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        GetApplicationAuthenticationMethodError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for GetApplicationAuthenticationMethodError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for GetApplicationAuthenticationMethodError {
+    fn description(&self) -> &str {
+        match *self {
+            GetApplicationAuthenticationMethodError::AccessDenied(ref cause) => cause,
+            GetApplicationAuthenticationMethodError::InternalServer(ref cause) => cause,
+            GetApplicationAuthenticationMethodError::ResourceNotFound(ref cause) => cause,
+            GetApplicationAuthenticationMethodError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by GetApplicationGrant
+#[derive(Debug, PartialEq)]
+pub enum GetApplicationGrantError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl GetApplicationGrantError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetApplicationGrantError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(GetApplicationGrantError::AccessDenied(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(GetApplicationGrantError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(GetApplicationGrantError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(GetApplicationGrantError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for GetApplicationGrantError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for GetApplicationGrantError {
+    fn description(&self) -> &str {
+        match *self {
+            GetApplicationGrantError::AccessDenied(ref cause) => cause,
+            GetApplicationGrantError::InternalServer(ref cause) => cause,
+            GetApplicationGrantError::ResourceNotFound(ref cause) => cause,
+            GetApplicationGrantError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by GetInlinePolicyForPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum GetInlinePolicyForPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl GetInlinePolicyForPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<GetInlinePolicyForPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        GetInlinePolicyForPermissionSetError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        GetInlinePolicyForPermissionSetError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        GetInlinePolicyForPermissionSetError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(GetInlinePolicyForPermissionSetError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for GetInlinePolicyForPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for GetInlinePolicyForPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            GetInlinePolicyForPermissionSetError::AccessDenied(ref cause) => cause,
+            GetInlinePolicyForPermissionSetError::InternalServer(ref cause) => cause,
+            GetInlinePolicyForPermissionSetError::ResourceNotFound(ref cause) => cause,
+            GetInlinePolicyForPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by GetPermissionsBoundaryForPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum GetPermissionsBoundaryForPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl GetPermissionsBoundaryForPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<GetPermissionsBoundaryForPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        GetPermissionsBoundaryForPermissionSetError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        GetPermissionsBoundaryForPermissionSetError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        GetPermissionsBoundaryForPermissionSetError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        GetPermissionsBoundaryForPermissionSetError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for GetPermissionsBoundaryForPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for GetPermissionsBoundaryForPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            GetPermissionsBoundaryForPermissionSetError::AccessDenied(ref cause) => cause,
+            GetPermissionsBoundaryForPermissionSetError::InternalServer(ref cause) => cause,
+            GetPermissionsBoundaryForPermissionSetError::ResourceNotFound(ref cause) => cause,
+            GetPermissionsBoundaryForPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListAccountAssignmentCreationStatus
+#[derive(Debug, PartialEq)]
+pub enum ListAccountAssignmentCreationStatusError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListAccountAssignmentCreationStatusError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListAccountAssignmentCreationStatusError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentCreationStatusError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentCreationStatusError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentCreationStatusError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentCreationStatusError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListAccountAssignmentCreationStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListAccountAssignmentCreationStatusError {
+    fn description(&self) -> &str {
+        match *self {
+            ListAccountAssignmentCreationStatusError::AccessDenied(ref cause) => cause,
+            ListAccountAssignmentCreationStatusError::InternalServer(ref cause) => cause,
+            ListAccountAssignmentCreationStatusError::ResourceNotFound(ref cause) => cause,
+            ListAccountAssignmentCreationStatusError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListAccountAssignmentDeletionStatus
+#[derive(Debug, PartialEq)]
+pub enum ListAccountAssignmentDeletionStatusError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListAccountAssignmentDeletionStatusError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListAccountAssignmentDeletionStatusError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentDeletionStatusError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentDeletionStatusError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentDeletionStatusError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentDeletionStatusError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListAccountAssignmentDeletionStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListAccountAssignmentDeletionStatusError {
+    fn description(&self) -> &str {
+        match *self {
+            ListAccountAssignmentDeletionStatusError::AccessDenied(ref cause) => cause,
+            ListAccountAssignmentDeletionStatusError::InternalServer(ref cause) => cause,
+            ListAccountAssignmentDeletionStatusError::ResourceNotFound(ref cause) => cause,
+            ListAccountAssignmentDeletionStatusError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListAccountAssignments
+#[derive(Debug, PartialEq)]
+pub enum ListAccountAssignmentsError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListAccountAssignmentsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAccountAssignmentsError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(ListAccountAssignmentsError::AccessDenied(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(ListAccountAssignmentsError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(ListAccountAssignmentsError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(ListAccountAssignmentsError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListAccountAssignmentsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListAccountAssignmentsError {
+    fn description(&self) -> &str {
+        match *self {
+            ListAccountAssignmentsError::AccessDenied(ref cause) => cause,
+            ListAccountAssignmentsError::InternalServer(ref cause) => cause,
+            ListAccountAssignmentsError::ResourceNotFound(ref cause) => cause,
+            ListAccountAssignmentsError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListAccountAssignmentsForPrincipal
+#[derive(Debug, PartialEq)]
+pub enum ListAccountAssignmentsForPrincipalError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListAccountAssignmentsForPrincipalError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListAccountAssignmentsForPrincipalError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentsForPrincipalError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentsForPrincipalError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentsForPrincipalError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        ListAccountAssignmentsForPrincipalError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListAccountAssignmentsForPrincipalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListAccountAssignmentsForPrincipalError {
+    fn description(&self) -> &str {
+        match *self {
+            ListAccountAssignmentsForPrincipalError::AccessDenied(ref cause) => cause,
+            ListAccountAssignmentsForPrincipalError::InternalServer(ref cause) => cause,
+            ListAccountAssignmentsForPrincipalError::ResourceNotFound(ref cause) => cause,
+            ListAccountAssignmentsForPrincipalError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListAccountsForProvisionedPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum ListAccountsForProvisionedPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListAccountsForProvisionedPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListAccountsForProvisionedPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        ListAccountsForProvisionedPermissionSetError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        ListAccountsForProvisionedPermissionSetError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        ListAccountsForProvisionedPermissionSetError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        ListAccountsForProvisionedPermissionSetError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListAccountsForProvisionedPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListAccountsForProvisionedPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            ListAccountsForProvisionedPermissionSetError::AccessDenied(ref cause) => cause,
+            ListAccountsForProvisionedPermissionSetError::InternalServer(ref cause) => cause,
+            ListAccountsForProvisionedPermissionSetError::ResourceNotFound(ref cause) => cause,
+            ListAccountsForProvisionedPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListApplicationAccessScopes
+#[derive(Debug, PartialEq)]
+pub enum ListApplicationAccessScopesError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListApplicationAccessScopesError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListApplicationAccessScopesError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(ListApplicationAccessScopesError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(ListApplicationAccessScopesError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        ListApplicationAccessScopesError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(ListApplicationAccessScopesError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListApplicationAccessScopesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListApplicationAccessScopesError {
+    fn description(&self) -> &str {
+        match *self {
+            ListApplicationAccessScopesError::AccessDenied(ref cause) => cause,
+            ListApplicationAccessScopesError::InternalServer(ref cause) => cause,
+            ListApplicationAccessScopesError::ResourceNotFound(ref cause) => cause,
+            ListApplicationAccessScopesError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListApplicationAssignments
+#[derive(Debug, PartialEq)]
+pub enum ListApplicationAssignmentsError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListApplicationAssignmentsError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListApplicationAssignmentsError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(ListApplicationAssignmentsError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(ListApplicationAssignmentsError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(ListApplicationAssignmentsError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(ListApplicationAssignmentsError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListApplicationAssignmentsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListApplicationAssignmentsError {
+    fn description(&self) -> &str {
+        match *self {
+            ListApplicationAssignmentsError::AccessDenied(ref cause) => cause,
+            ListApplicationAssignmentsError::InternalServer(ref cause) => cause,
+            ListApplicationAssignmentsError::ResourceNotFound(ref cause) => cause,
+            ListApplicationAssignmentsError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListApplicationAssignmentsForPrincipal
+#[derive(Debug, PartialEq)]
+pub enum ListApplicationAssignmentsForPrincipalError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListApplicationAssignmentsForPrincipalError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListApplicationAssignmentsForPrincipalError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        ListApplicationAssignmentsForPrincipalError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        ListApplicationAssignmentsForPrincipalError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        ListApplicationAssignmentsForPrincipalError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        ListApplicationAssignmentsForPrincipalError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListApplicationAssignmentsForPrincipalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListApplicationAssignmentsForPrincipalError {
+    fn description(&self) -> &str {
+        match *self {
+            ListApplicationAssignmentsForPrincipalError::AccessDenied(ref cause) => cause,
+            ListApplicationAssignmentsForPrincipalError::InternalServer(ref cause) => cause,
+            ListApplicationAssignmentsForPrincipalError::ResourceNotFound(ref cause) => cause,
+            ListApplicationAssignmentsForPrincipalError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListApplicationAuthenticationMethods
+#[derive(Debug, PartialEq)]
+pub enum ListApplicationAuthenticationMethodsError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListApplicationAuthenticationMethodsError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListApplicationAuthenticationMethodsError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        ListApplicationAuthenticationMethodsError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        ListApplicationAuthenticationMethodsError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        ListApplicationAuthenticationMethodsError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        ListApplicationAuthenticationMethodsError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListApplicationAuthenticationMethodsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListApplicationAuthenticationMethodsError {
+    fn description(&self) -> &str {
+        match *self {
+            ListApplicationAuthenticationMethodsError::AccessDenied(ref cause) => cause,
+            ListApplicationAuthenticationMethodsError::InternalServer(ref cause) => cause,
+            ListApplicationAuthenticationMethodsError::ResourceNotFound(ref cause) => cause,
+            ListApplicationAuthenticationMethodsError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListApplicationGrants
+#[derive(Debug, PartialEq)]
+pub enum ListApplicationGrantsError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListApplicationGrantsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListApplicationGrantsError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(ListApplicationGrantsError::AccessDenied(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(ListApplicationGrantsError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(ListApplicationGrantsError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(ListApplicationGrantsError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListApplicationGrantsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListApplicationGrantsError {
+    fn description(&self) -> &str {
+        match *self {
+            ListApplicationGrantsError::AccessDenied(ref cause) => cause,
+            ListApplicationGrantsError::InternalServer(ref cause) => cause,
+            ListApplicationGrantsError::ResourceNotFound(ref cause) => cause,
+            ListApplicationGrantsError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListApplicationProviders
+#[derive(Debug, PartialEq)]
+pub enum ListApplicationProvidersError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListApplicationProvidersError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListApplicationProvidersError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(ListApplicationProvidersError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(ListApplicationProvidersError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(ListApplicationProvidersError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListApplicationProvidersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListApplicationProvidersError {
+    fn description(&self) -> &str {
+        match *self {
+            ListApplicationProvidersError::AccessDenied(ref cause) => cause,
+            ListApplicationProvidersError::InternalServer(ref cause) => cause,
+            ListApplicationProvidersError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListApplications
+#[derive(Debug, PartialEq)]
+pub enum ListApplicationsError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListApplicationsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListApplicationsError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(ListApplicationsError::AccessDenied(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(ListApplicationsError::InternalServer(err.msg))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(ListApplicationsError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListApplicationsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListApplicationsError {
+    fn description(&self) -> &str {
+        match *self {
+            ListApplicationsError::AccessDenied(ref cause) => cause,
+            ListApplicationsError::InternalServer(ref cause) => cause,
+            ListApplicationsError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListCustomerManagedPolicyReferencesInPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum ListCustomerManagedPolicyReferencesInPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListCustomerManagedPolicyReferencesInPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListCustomerManagedPolicyReferencesInPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        ListCustomerManagedPolicyReferencesInPermissionSetError::AccessDenied(
+                            err.msg,
+                        ),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        ListCustomerManagedPolicyReferencesInPermissionSetError::InternalServer(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        ListCustomerManagedPolicyReferencesInPermissionSetError::ResourceNotFound(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        ListCustomerManagedPolicyReferencesInPermissionSetError::Throttling(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListCustomerManagedPolicyReferencesInPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListCustomerManagedPolicyReferencesInPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            ListCustomerManagedPolicyReferencesInPermissionSetError::AccessDenied(ref cause) => {
+                cause
+            }
+            ListCustomerManagedPolicyReferencesInPermissionSetError::InternalServer(ref cause) => {
+                cause
+            }
+            ListCustomerManagedPolicyReferencesInPermissionSetError::ResourceNotFound(
+                ref cause,
+            ) => cause,
+            ListCustomerManagedPolicyReferencesInPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListInstances
+#[derive(Debug, PartialEq)]
+pub enum ListInstancesError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListInstancesError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListInstancesError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(ListInstancesError::AccessDenied(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(ListInstancesError::InternalServer(err.msg))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(ListInstancesError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListInstancesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListInstancesError {
+    fn description(&self) -> &str {
+        match *self {
+            ListInstancesError::AccessDenied(ref cause) => cause,
+            ListInstancesError::InternalServer(ref cause) => cause,
+            ListInstancesError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListManagedPoliciesInPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum ListManagedPoliciesInPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListManagedPoliciesInPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListManagedPoliciesInPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        ListManagedPoliciesInPermissionSetError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        ListManagedPoliciesInPermissionSetError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        ListManagedPoliciesInPermissionSetError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        ListManagedPoliciesInPermissionSetError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListManagedPoliciesInPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListManagedPoliciesInPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            ListManagedPoliciesInPermissionSetError::AccessDenied(ref cause) => cause,
+            ListManagedPoliciesInPermissionSetError::InternalServer(ref cause) => cause,
+            ListManagedPoliciesInPermissionSetError::ResourceNotFound(ref cause) => cause,
+            ListManagedPoliciesInPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListPermissionSetProvisioningStatus
+#[derive(Debug, PartialEq)]
+pub enum ListPermissionSetProvisioningStatusError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListPermissionSetProvisioningStatusError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListPermissionSetProvisioningStatusError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        ListPermissionSetProvisioningStatusError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        ListPermissionSetProvisioningStatusError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        ListPermissionSetProvisioningStatusError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        ListPermissionSetProvisioningStatusError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListPermissionSetProvisioningStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListPermissionSetProvisioningStatusError {
+    fn description(&self) -> &str {
+        match *self {
+            ListPermissionSetProvisioningStatusError::AccessDenied(ref cause) => cause,
+            ListPermissionSetProvisioningStatusError::InternalServer(ref cause) => cause,
+            ListPermissionSetProvisioningStatusError::ResourceNotFound(ref cause) => cause,
+            ListPermissionSetProvisioningStatusError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListPermissionSets
+#[derive(Debug, PartialEq)]
+pub enum ListPermissionSetsError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListPermissionSetsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListPermissionSetsError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(ListPermissionSetsError::AccessDenied(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(ListPermissionSetsError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(ListPermissionSetsError::ResourceNotFound(err.msg))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(ListPermissionSetsError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListPermissionSetsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListPermissionSetsError {
+    fn description(&self) -> &str {
+        match *self {
+            ListPermissionSetsError::AccessDenied(ref cause) => cause,
+            ListPermissionSetsError::InternalServer(ref cause) => cause,
+            ListPermissionSetsError::ResourceNotFound(ref cause) => cause,
+            ListPermissionSetsError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListPermissionSetsProvisionedToAccount
+#[derive(Debug, PartialEq)]
+pub enum ListPermissionSetsProvisionedToAccountError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListPermissionSetsProvisionedToAccountError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListPermissionSetsProvisionedToAccountError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        ListPermissionSetsProvisionedToAccountError::AccessDenied(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        ListPermissionSetsProvisionedToAccountError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        ListPermissionSetsProvisionedToAccountError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        ListPermissionSetsProvisionedToAccountError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListPermissionSetsProvisionedToAccountError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListPermissionSetsProvisionedToAccountError {
+    fn description(&self) -> &str {
+        match *self {
+            ListPermissionSetsProvisionedToAccountError::AccessDenied(ref cause) => cause,
+            ListPermissionSetsProvisionedToAccountError::InternalServer(ref cause) => cause,
+            ListPermissionSetsProvisionedToAccountError::ResourceNotFound(ref cause) => cause,
+            ListPermissionSetsProvisionedToAccountError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListTagsForResource
+#[derive(Debug, PartialEq)]
+pub enum ListTagsForResourceError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListTagsForResourceError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsForResourceError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListTagsForResourceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListTagsForResourceError {
+    fn description(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(ref cause) => cause,
+            ListTagsForResourceError::InternalServer(ref cause) => cause,
+            ListTagsForResourceError::ResourceNotFound(ref cause) => cause,
+            ListTagsForResourceError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ListTrustedTokenIssuers
+#[derive(Debug, PartialEq)]
+pub enum ListTrustedTokenIssuersError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ListTrustedTokenIssuersError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTrustedTokenIssuersError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(ListTrustedTokenIssuersError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(ListTrustedTokenIssuersError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(ListTrustedTokenIssuersError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ListTrustedTokenIssuersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ListTrustedTokenIssuersError {
+    fn description(&self) -> &str {
+        match *self {
+            ListTrustedTokenIssuersError::AccessDenied(ref cause) => cause,
+            ListTrustedTokenIssuersError::InternalServer(ref cause) => cause,
+            ListTrustedTokenIssuersError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ProvisionPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum ProvisionPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl ProvisionPermissionSetError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ProvisionPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(ProvisionPermissionSetError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(ProvisionPermissionSetError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(ProvisionPermissionSetError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(ProvisionPermissionSetError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(ProvisionPermissionSetError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ProvisionPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ProvisionPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            ProvisionPermissionSetError::AccessDenied(ref cause) => cause,
+            ProvisionPermissionSetError::Conflict(ref cause) => cause,
+            ProvisionPermissionSetError::InternalServer(ref cause) => cause,
+            ProvisionPermissionSetError::ResourceNotFound(ref cause) => cause,
+            ProvisionPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by PutApplicationAccessScope
+#[derive(Debug, PartialEq)]
+pub enum PutApplicationAccessScopeError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl PutApplicationAccessScopeError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutApplicationAccessScopeError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(PutApplicationAccessScopeError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(PutApplicationAccessScopeError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(PutApplicationAccessScopeError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(PutApplicationAccessScopeError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(PutApplicationAccessScopeError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for PutApplicationAccessScopeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for PutApplicationAccessScopeError {
+    fn description(&self) -> &str {
+        match *self {
+            PutApplicationAccessScopeError::AccessDenied(ref cause) => cause,
+            PutApplicationAccessScopeError::Conflict(ref cause) => cause,
+            PutApplicationAccessScopeError::InternalServer(ref cause) => cause,
+            PutApplicationAccessScopeError::ResourceNotFound(ref cause) => cause,
+            PutApplicationAccessScopeError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by PutApplicationAssignmentConfiguration
+#[derive(Debug, PartialEq)]
+pub enum PutApplicationAssignmentConfigurationError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl PutApplicationAssignmentConfigurationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<PutApplicationAssignmentConfigurationError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        PutApplicationAssignmentConfigurationError::AccessDenied(err.msg),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(
+                        PutApplicationAssignmentConfigurationError::Conflict(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        PutApplicationAssignmentConfigurationError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        PutApplicationAssignmentConfigurationError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        PutApplicationAssignmentConfigurationError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for PutApplicationAssignmentConfigurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for PutApplicationAssignmentConfigurationError {
+    fn description(&self) -> &str {
+        match *self {
+            PutApplicationAssignmentConfigurationError::AccessDenied(ref cause) => cause,
+            PutApplicationAssignmentConfigurationError::Conflict(ref cause) => cause,
+            PutApplicationAssignmentConfigurationError::InternalServer(ref cause) => cause,
+            PutApplicationAssignmentConfigurationError::ResourceNotFound(ref cause) => cause,
+            PutApplicationAssignmentConfigurationError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by PutApplicationAuthenticationMethod
+#[derive(Debug, PartialEq)]
+pub enum PutApplicationAuthenticationMethodError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl PutApplicationAuthenticationMethodError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<PutApplicationAuthenticationMethodError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        PutApplicationAuthenticationMethodError::AccessDenied(err.msg),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(PutApplicationAuthenticationMethodError::Conflict(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        PutApplicationAuthenticationMethodError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        PutApplicationAuthenticationMethodError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        PutApplicationAuthenticationMethodError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for PutApplicationAuthenticationMethodError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for PutApplicationAuthenticationMethodError {
+    fn description(&self) -> &str {
+        match *self {
+            PutApplicationAuthenticationMethodError::AccessDenied(ref cause) => cause,
+            PutApplicationAuthenticationMethodError::Conflict(ref cause) => cause,
+            PutApplicationAuthenticationMethodError::InternalServer(ref cause) => cause,
+            PutApplicationAuthenticationMethodError::ResourceNotFound(ref cause) => cause,
+            PutApplicationAuthenticationMethodError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by PutApplicationGrant
+#[derive(Debug, PartialEq)]
+pub enum PutApplicationGrantError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl PutApplicationGrantError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutApplicationGrantError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(PutApplicationGrantError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(PutApplicationGrantError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(PutApplicationGrantError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(PutApplicationGrantError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(PutApplicationGrantError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for PutApplicationGrantError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for PutApplicationGrantError {
+    fn description(&self) -> &str {
+        match *self {
+            PutApplicationGrantError::AccessDenied(ref cause) => cause,
+            PutApplicationGrantError::Conflict(ref cause) => cause,
+            PutApplicationGrantError::InternalServer(ref cause) => cause,
+            PutApplicationGrantError::ResourceNotFound(ref cause) => cause,
+            PutApplicationGrantError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by PutInlinePolicyToPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum PutInlinePolicyToPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the permitted number of resources that can be created.</p></p>
+    ServiceQuotaExceeded(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl PutInlinePolicyToPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<PutInlinePolicyToPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(PutInlinePolicyToPermissionSetError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(PutInlinePolicyToPermissionSetError::Conflict(
+                        err.msg,
+                    ))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        PutInlinePolicyToPermissionSetError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        PutInlinePolicyToPermissionSetError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ServiceQuotaExceededException" => {
+                    return RusotoError::Service(
+                        PutInlinePolicyToPermissionSetError::ServiceQuotaExceeded(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(PutInlinePolicyToPermissionSetError::Throttling(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for PutInlinePolicyToPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for PutInlinePolicyToPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            PutInlinePolicyToPermissionSetError::AccessDenied(ref cause) => cause,
+            PutInlinePolicyToPermissionSetError::Conflict(ref cause) => cause,
+            PutInlinePolicyToPermissionSetError::InternalServer(ref cause) => cause,
+            PutInlinePolicyToPermissionSetError::ResourceNotFound(ref cause) => cause,
+            PutInlinePolicyToPermissionSetError::ServiceQuotaExceeded(ref cause) => cause,
+            PutInlinePolicyToPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by PutPermissionsBoundaryToPermissionSet
+#[derive(Debug, PartialEq)]
+pub enum PutPermissionsBoundaryToPermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl PutPermissionsBoundaryToPermissionSetError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<PutPermissionsBoundaryToPermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        PutPermissionsBoundaryToPermissionSetError::AccessDenied(err.msg),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(
+                        PutPermissionsBoundaryToPermissionSetError::Conflict(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        PutPermissionsBoundaryToPermissionSetError::InternalServer(err.msg),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        PutPermissionsBoundaryToPermissionSetError::ResourceNotFound(err.msg),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        PutPermissionsBoundaryToPermissionSetError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for PutPermissionsBoundaryToPermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for PutPermissionsBoundaryToPermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            PutPermissionsBoundaryToPermissionSetError::AccessDenied(ref cause) => cause,
+            PutPermissionsBoundaryToPermissionSetError::Conflict(ref cause) => cause,
+            PutPermissionsBoundaryToPermissionSetError::InternalServer(ref cause) => cause,
+            PutPermissionsBoundaryToPermissionSetError::ResourceNotFound(ref cause) => cause,
+            PutPermissionsBoundaryToPermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by TagResource
+#[derive(Debug, PartialEq)]
+pub enum TagResourceError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the permitted number of resources that can be created.</p></p>
+    ServiceQuotaExceeded(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl TagResourceError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                }
+                "ServiceQuotaExceededException" => {
+                    return RusotoError::Service(TagResourceError::ServiceQuotaExceeded(err.msg))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for TagResourceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for TagResourceError {
+    fn description(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(ref cause) => cause,
+            TagResourceError::Conflict(ref cause) => cause,
+            TagResourceError::InternalServer(ref cause) => cause,
+            TagResourceError::ResourceNotFound(ref cause) => cause,
+            TagResourceError::ServiceQuotaExceeded(ref cause) => cause,
+            TagResourceError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UntagResource
+#[derive(Debug, PartialEq)]
+pub enum UntagResourceError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl UntagResourceError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UntagResourceError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UntagResourceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UntagResourceError {
+    fn description(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(ref cause) => cause,
+            UntagResourceError::Conflict(ref cause) => cause,
+            UntagResourceError::InternalServer(ref cause) => cause,
+            UntagResourceError::ResourceNotFound(ref cause) => cause,
+            UntagResourceError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdateApplication
+#[derive(Debug, PartialEq)]
+pub enum UpdateApplicationError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl UpdateApplicationError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateApplicationError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(UpdateApplicationError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(UpdateApplicationError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(UpdateApplicationError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(UpdateApplicationError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateApplicationError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateApplicationError::AccessDenied(ref cause) => cause,
+            UpdateApplicationError::Conflict(ref cause) => cause,
+            UpdateApplicationError::InternalServer(ref cause) => cause,
+            UpdateApplicationError::ResourceNotFound(ref cause) => cause,
+            UpdateApplicationError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdateInstance
+#[derive(Debug, PartialEq)]
+pub enum UpdateInstanceError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl UpdateInstanceError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateInstanceError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(UpdateInstanceError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(UpdateInstanceError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(UpdateInstanceError::InternalServer(err.msg))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(UpdateInstanceError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateInstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateInstanceError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateInstanceError::AccessDenied(ref cause) => cause,
+            UpdateInstanceError::Conflict(ref cause) => cause,
+            UpdateInstanceError::InternalServer(ref cause) => cause,
+            UpdateInstanceError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdateInstanceAccessControlAttributeConfiguration
+#[derive(Debug, PartialEq)]
+pub enum UpdateInstanceAccessControlAttributeConfigurationError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl UpdateInstanceAccessControlAttributeConfigurationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<UpdateInstanceAccessControlAttributeConfigurationError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(
+                        UpdateInstanceAccessControlAttributeConfigurationError::AccessDenied(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(
+                        UpdateInstanceAccessControlAttributeConfigurationError::Conflict(err.msg),
+                    )
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(
+                        UpdateInstanceAccessControlAttributeConfigurationError::InternalServer(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(
+                        UpdateInstanceAccessControlAttributeConfigurationError::ResourceNotFound(
+                            err.msg,
+                        ),
+                    )
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(
+                        UpdateInstanceAccessControlAttributeConfigurationError::Throttling(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateInstanceAccessControlAttributeConfigurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateInstanceAccessControlAttributeConfigurationError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateInstanceAccessControlAttributeConfigurationError::AccessDenied(ref cause) => {
+                cause
+            }
+            UpdateInstanceAccessControlAttributeConfigurationError::Conflict(ref cause) => cause,
+            UpdateInstanceAccessControlAttributeConfigurationError::InternalServer(ref cause) => {
+                cause
+            }
+            UpdateInstanceAccessControlAttributeConfigurationError::ResourceNotFound(ref cause) => {
+                cause
+            }
+            UpdateInstanceAccessControlAttributeConfigurationError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdatePermissionSet
+#[derive(Debug, PartialEq)]
+pub enum UpdatePermissionSetError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl UpdatePermissionSetError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdatePermissionSetError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(UpdatePermissionSetError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(UpdatePermissionSetError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(UpdatePermissionSetError::InternalServer(err.msg))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(UpdatePermissionSetError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(UpdatePermissionSetError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdatePermissionSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdatePermissionSetError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdatePermissionSetError::AccessDenied(ref cause) => cause,
+            UpdatePermissionSetError::Conflict(ref cause) => cause,
+            UpdatePermissionSetError::InternalServer(ref cause) => cause,
+            UpdatePermissionSetError::ResourceNotFound(ref cause) => cause,
+            UpdatePermissionSetError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdateTrustedTokenIssuer
+#[derive(Debug, PartialEq)]
+pub enum UpdateTrustedTokenIssuerError {
+    /// <p><p>You do not have sufficient access to perform this action.</p></p>
+    AccessDenied(String),
+    /// <p><p>Occurs when a conflict with a previous successful write is detected. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p></p>
+    Conflict(String),
+    /// <p><p>The request processing has failed because of an unknown error, exception, or failure with an internal server.</p></p>
+    InternalServer(String),
+    /// <p><p>Indicates that a requested resource is not found.</p></p>
+    ResourceNotFound(String),
+    /// <p><p>Indicates that the principal has crossed the throttling limits of the API operations.</p></p>
+    Throttling(String),
+}
+
+impl UpdateTrustedTokenIssuerError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateTrustedTokenIssuerError> {
+        if let Some(err) = proto::json::Error::parse(&res) {
+            match err.typ.as_str() {
+                "AccessDeniedException" => {
+                    return RusotoError::Service(UpdateTrustedTokenIssuerError::AccessDenied(
+                        err.msg,
+                    ))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(UpdateTrustedTokenIssuerError::Conflict(err.msg))
+                }
+                "InternalServerException" => {
+                    return RusotoError::Service(UpdateTrustedTokenIssuerError::InternalServer(
+                        err.msg,
+                    ))
+                }
+                "ResourceNotFoundException" => {
+                    return RusotoError::Service(UpdateTrustedTokenIssuerError::ResourceNotFound(
+                        err.msg,
+                    ))
+                }
+                "ThrottlingException" => {
+                    return RusotoError::Service(UpdateTrustedTokenIssuerError::Throttling(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateTrustedTokenIssuerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateTrustedTokenIssuerError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateTrustedTokenIssuerError::AccessDenied(ref cause) => cause,
+            UpdateTrustedTokenIssuerError::Conflict(ref cause) => cause,
+            UpdateTrustedTokenIssuerError::InternalServer(ref cause) => cause,
+            UpdateTrustedTokenIssuerError::ResourceNotFound(ref cause) => cause,
+            UpdateTrustedTokenIssuerError::Throttling(ref cause) => cause,
+        }
+    }
+}
+/// Trait representing the capabilities of the SSO Admin API. SSO Admin clients implement this trait.
+pub trait SsoAdmin {
+    /// <p><p>Attaches the specified customer managed policy to the specified <a>PermissionSet</a>.</p></p>
+    fn attach_customer_managed_policy_reference_to_permission_set(
+        &self,
+        input: AttachCustomerManagedPolicyReferenceToPermissionSetRequest,
+    ) -> RusotoFuture<
+        AttachCustomerManagedPolicyReferenceToPermissionSetResponse,
+        AttachCustomerManagedPolicyReferenceToPermissionSetError,
+    >;
+
+    /// <p><p>Attaches an Amazon Web Services managed policy ARN to a permission set.</p> <note> <p>If the permission set is already referenced by one or more account assignments, you will need to call <code> <a>ProvisionPermissionSet</a> </code> after this operation. Calling <code>ProvisionPermissionSet</code> applies the corresponding IAM policy updates to all assigned accounts.</p> </note></p>
+    fn attach_managed_policy_to_permission_set(
+        &self,
+        input: AttachManagedPolicyToPermissionSetRequest,
+    ) -> RusotoFuture<
+        AttachManagedPolicyToPermissionSetResponse,
+        AttachManagedPolicyToPermissionSetError,
+    >;
+
+    /// <p><p>Assigns access to a principal for a specified Amazon Web Services account using a specified permission set.</p> <note> <p>The term <i>principal</i> here refers to a user or group that is defined in IAM Identity Center.</p> </note> <note> <p>As part of a successful <code>CreateAccountAssignment</code> call, the specified permission set will automatically be provisioned to the account in the form of an IAM policy. That policy is attached to the IAM role created in IAM Identity Center. If the permission set is subsequently updated, the corresponding IAM policies attached to roles in your accounts will not be updated automatically. In this case, you must call <code> <a>ProvisionPermissionSet</a> </code> to make these updates.</p> </note> <note> <p> After a successful response, call <code>DescribeAccountAssignmentCreationStatus</code> to describe the status of an assignment creation request. </p> </note></p>
+    fn create_account_assignment(
+        &self,
+        input: CreateAccountAssignmentRequest,
+    ) -> RusotoFuture<CreateAccountAssignmentResponse, CreateAccountAssignmentError>;
+
+    /// <p><p>Creates an application in IAM Identity Center for the given application provider.</p></p>
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError>;
+
+    /// <p><p>Grant application access to a user or group.</p></p>
+    fn create_application_assignment(
+        &self,
+        input: CreateApplicationAssignmentRequest,
+    ) -> RusotoFuture<CreateApplicationAssignmentResponse, CreateApplicationAssignmentError>;
+
+    /// <p><p>Creates an instance of IAM Identity Center for a standalone Amazon Web Services account that is not managed by Organizations or a member Amazon Web Services account in an organization. You can create only one instance per account and across all Amazon Web Services Regions.</p> <p>The CreateInstance request is rejected if the following apply: </p> <ul> <li> <p>The instance is created within the organization management account.</p> </li> <li> <p>An instance already exists in the same account.</p> </li> </ul></p>
+    fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> RusotoFuture<CreateInstanceResponse, CreateInstanceError>;
+
+    /// <p><p>Enables the attributes-based access control (ABAC) feature for the specified IAM Identity Center instance. You can also specify new attributes to add to your ABAC configuration during the enabling process. For more information about ABAC, see <a href="/singlesignon/latest/userguide/abac.html">Attribute-Based Access Control</a> in the <i>IAM Identity Center User Guide</i>.</p> <note> <p>After a successful response, call <code>DescribeInstanceAccessControlAttributeConfiguration</code> to validate that <code>InstanceAccessControlAttributeConfiguration</code> was created.</p> </note></p>
+    fn create_instance_access_control_attribute_configuration(
+        &self,
+        input: CreateInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        CreateInstanceAccessControlAttributeConfigurationResponse,
+        CreateInstanceAccessControlAttributeConfigurationError,
+    >;
+
+    /// <p><p>Creates a permission set within a specified IAM Identity Center instance.</p> <note> <p>To grant users and groups access to Amazon Web Services account resources, use <code> <a>CreateAccountAssignment</a> </code>.</p> </note></p>
+    fn create_permission_set(
+        &self,
+        input: CreatePermissionSetRequest,
+    ) -> RusotoFuture<CreatePermissionSetResponse, CreatePermissionSetError>;
+
+    /// <p><p>Creates a connection to a trusted token issuer in an instance of IAM Identity Center. A trusted token issuer enables trusted identity propagation to be used with applications that authenticate outside of Amazon Web Services.</p> <p>This trusted token issuer describes an external identity provider (IdP) that can generate claims or assertions in the form of access tokens for a user. Applications enabled for IAM Identity Center can use these tokens for authentication. </p></p>
+    fn create_trusted_token_issuer(
+        &self,
+        input: CreateTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<CreateTrustedTokenIssuerResponse, CreateTrustedTokenIssuerError>;
+
+    /// <p><p>Deletes a principal's access from a specified Amazon Web Services account using a specified permission set.</p> <note> <p>After a successful response, call <code>DescribeAccountAssignmentDeletionStatus</code> to describe the status of an assignment deletion request.</p> </note></p>
+    fn delete_account_assignment(
+        &self,
+        input: DeleteAccountAssignmentRequest,
+    ) -> RusotoFuture<DeleteAccountAssignmentResponse, DeleteAccountAssignmentError>;
+
+    /// <p><p>Deletes the association with the application. The connected service resource still exists.</p></p>
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<DeleteApplicationResponse, DeleteApplicationError>;
+
+    /// <p><p>Deletes an IAM Identity Center access scope from an application.</p></p>
+    fn delete_application_access_scope(
+        &self,
+        input: DeleteApplicationAccessScopeRequest,
+    ) -> RusotoFuture<(), DeleteApplicationAccessScopeError>;
+
+    /// <p><p>Revoke application access to an application by deleting application assignments for a user or group.</p></p>
+    fn delete_application_assignment(
+        &self,
+        input: DeleteApplicationAssignmentRequest,
+    ) -> RusotoFuture<DeleteApplicationAssignmentResponse, DeleteApplicationAssignmentError>;
+
+    /// <p><p>Deletes an authentication method from an application.</p></p>
+    fn delete_application_authentication_method(
+        &self,
+        input: DeleteApplicationAuthenticationMethodRequest,
+    ) -> RusotoFuture<(), DeleteApplicationAuthenticationMethodError>;
+
+    /// <p><p>Deletes a grant from an application.</p></p>
+    fn delete_application_grant(
+        &self,
+        input: DeleteApplicationGrantRequest,
+    ) -> RusotoFuture<(), DeleteApplicationGrantError>;
+
+    /// <p><p>Deletes the inline policy from a specified permission set.</p></p>
+    fn delete_inline_policy_from_permission_set(
+        &self,
+        input: DeleteInlinePolicyFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DeleteInlinePolicyFromPermissionSetResponse,
+        DeleteInlinePolicyFromPermissionSetError,
+    >;
+
+    /// <p><p>Deletes the instance of IAM Identity Center. Only the account that owns the instance can call this API. Neither the delegated administrator nor member account can delete the organization instance, but those roles can delete their own instance.</p></p>
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<DeleteInstanceResponse, DeleteInstanceError>;
+
+    /// <p><p>Disables the attributes-based access control (ABAC) feature for the specified IAM Identity Center instance and deletes all of the attribute mappings that have been configured. Once deleted, any attributes that are received from an identity source and any custom attributes you have previously configured will not be passed. For more information about ABAC, see <a href="/singlesignon/latest/userguide/abac.html">Attribute-Based Access Control</a> in the <i>IAM Identity Center User Guide</i>.</p></p>
+    fn delete_instance_access_control_attribute_configuration(
+        &self,
+        input: DeleteInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteInstanceAccessControlAttributeConfigurationResponse,
+        DeleteInstanceAccessControlAttributeConfigurationError,
+    >;
+
+    /// <p><p>Deletes the specified permission set.</p></p>
+    fn delete_permission_set(
+        &self,
+        input: DeletePermissionSetRequest,
+    ) -> RusotoFuture<DeletePermissionSetResponse, DeletePermissionSetError>;
+
+    /// <p><p>Deletes the permissions boundary from a specified <a>PermissionSet</a>.</p></p>
+    fn delete_permissions_boundary_from_permission_set(
+        &self,
+        input: DeletePermissionsBoundaryFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DeletePermissionsBoundaryFromPermissionSetResponse,
+        DeletePermissionsBoundaryFromPermissionSetError,
+    >;
+
+    /// <p><p>Deletes a trusted token issuer configuration from an instance of IAM Identity Center.</p> <note> <p>Deleting this trusted token issuer configuration will cause users to lose access to any applications that are configured to use the trusted token issuer.</p> </note></p>
+    fn delete_trusted_token_issuer(
+        &self,
+        input: DeleteTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<DeleteTrustedTokenIssuerResponse, DeleteTrustedTokenIssuerError>;
+
+    /// <p><p>Describes the status of the assignment creation request.</p></p>
+    fn describe_account_assignment_creation_status(
+        &self,
+        input: DescribeAccountAssignmentCreationStatusRequest,
+    ) -> RusotoFuture<
+        DescribeAccountAssignmentCreationStatusResponse,
+        DescribeAccountAssignmentCreationStatusError,
+    >;
+
+    /// <p><p>Describes the status of the assignment deletion request.</p></p>
+    fn describe_account_assignment_deletion_status(
+        &self,
+        input: DescribeAccountAssignmentDeletionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeAccountAssignmentDeletionStatusResponse,
+        DescribeAccountAssignmentDeletionStatusError,
+    >;
+
+    /// <p><p>Retrieves the details of an application associated with an instance of IAM Identity Center.</p></p>
+    fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> RusotoFuture<DescribeApplicationResponse, DescribeApplicationError>;
+
+    /// <p><p>Retrieves a direct assignment of a user or group to an application. If the user doesn’t have a direct assignment to the application, the user may still have access to the application through a group. Therefore, don’t use this API to test access to an application for a user. Instead use <a>ListApplicationAssignmentsForPrincipal</a>.</p></p>
+    fn describe_application_assignment(
+        &self,
+        input: DescribeApplicationAssignmentRequest,
+    ) -> RusotoFuture<DescribeApplicationAssignmentResponse, DescribeApplicationAssignmentError>;
+
+    /// <p><p>Retrieves details about a provider that can be used to connect an Amazon Web Services managed application or customer managed application to IAM Identity Center.</p></p>
+    fn describe_application_provider(
+        &self,
+        input: DescribeApplicationProviderRequest,
+    ) -> RusotoFuture<DescribeApplicationProviderResponse, DescribeApplicationProviderError>;
+
+    /// <p><p>Returns the details of an instance of IAM Identity Center. The status can be one of the following:</p> <ul> <li> <p> <code>CREATE_IN_PROGRESS</code> - The instance is in the process of being created. When the instance is ready for use, DescribeInstance returns the status of <code>ACTIVE</code>. While the instance is in the <code>CREATE_IN_PROGRESS</code> state, you can call only DescribeInstance and DeleteInstance operations.</p> </li> <li> <p> <code>DELETE_IN_PROGRESS</code> - The instance is being deleted. Returns <code>AccessDeniedException</code> after the delete operation completes. </p> </li> <li> <p> <code>ACTIVE</code> - The instance is active.</p> </li> </ul></p>
+    fn describe_instance(
+        &self,
+        input: DescribeInstanceRequest,
+    ) -> RusotoFuture<DescribeInstanceResponse, DescribeInstanceError>;
+
+    /// <p><p>Returns the list of IAM Identity Center identity store attributes that have been configured to work with attributes-based access control (ABAC) for the specified IAM Identity Center instance. This will not return attributes configured and sent by an external identity provider. For more information about ABAC, see <a href="/singlesignon/latest/userguide/abac.html">Attribute-Based Access Control</a> in the <i>IAM Identity Center User Guide</i>.</p></p>
+    fn describe_instance_access_control_attribute_configuration(
+        &self,
+        input: DescribeInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeInstanceAccessControlAttributeConfigurationResponse,
+        DescribeInstanceAccessControlAttributeConfigurationError,
+    >;
+
+    /// <p><p>Gets the details of the permission set.</p></p>
+    fn describe_permission_set(
+        &self,
+        input: DescribePermissionSetRequest,
+    ) -> RusotoFuture<DescribePermissionSetResponse, DescribePermissionSetError>;
+
+    /// <p><p>Describes the status for the given permission set provisioning request.</p></p>
+    fn describe_permission_set_provisioning_status(
+        &self,
+        input: DescribePermissionSetProvisioningStatusRequest,
+    ) -> RusotoFuture<
+        DescribePermissionSetProvisioningStatusResponse,
+        DescribePermissionSetProvisioningStatusError,
+    >;
+
+    /// <p><p>Retrieves details about a trusted token issuer configuration stored in an instance of IAM Identity Center. Details include the name of the trusted token issuer, the issuer URL, and the path of the source attribute and the destination attribute for a trusted token issuer configuration. </p></p>
+    fn describe_trusted_token_issuer(
+        &self,
+        input: DescribeTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<DescribeTrustedTokenIssuerResponse, DescribeTrustedTokenIssuerError>;
+
+    /// <p><p>Detaches the specified customer managed policy from the specified <a>PermissionSet</a>.</p></p>
+    fn detach_customer_managed_policy_reference_from_permission_set(
+        &self,
+        input: DetachCustomerManagedPolicyReferenceFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DetachCustomerManagedPolicyReferenceFromPermissionSetResponse,
+        DetachCustomerManagedPolicyReferenceFromPermissionSetError,
+    >;
+
+    /// <p><p>Detaches the attached Amazon Web Services managed policy ARN from the specified permission set.</p></p>
+    fn detach_managed_policy_from_permission_set(
+        &self,
+        input: DetachManagedPolicyFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DetachManagedPolicyFromPermissionSetResponse,
+        DetachManagedPolicyFromPermissionSetError,
+    >;
+
+    /// <p><p>Retrieves the authorized targets for an IAM Identity Center access scope for an application.</p></p>
+    fn get_application_access_scope(
+        &self,
+        input: GetApplicationAccessScopeRequest,
+    ) -> RusotoFuture<GetApplicationAccessScopeResponse, GetApplicationAccessScopeError>;
+
+    /// <p><p>Retrieves the configuration of <a>PutApplicationAssignmentConfiguration</a>.</p></p>
+    fn get_application_assignment_configuration(
+        &self,
+        input: GetApplicationAssignmentConfigurationRequest,
+    ) -> RusotoFuture<
+        GetApplicationAssignmentConfigurationResponse,
+        GetApplicationAssignmentConfigurationError,
+    >;
+
+    /// <p><p>Retrieves details about an authentication method used by an application.</p></p>
+    fn get_application_authentication_method(
+        &self,
+        input: GetApplicationAuthenticationMethodRequest,
+    ) -> RusotoFuture<
+        GetApplicationAuthenticationMethodResponse,
+        GetApplicationAuthenticationMethodError,
+    >;
+
+    /// <p><p>Retrieves details about an application grant.</p></p>
+    fn get_application_grant(
+        &self,
+        input: GetApplicationGrantRequest,
+    ) -> RusotoFuture<GetApplicationGrantResponse, GetApplicationGrantError>;
+
+    /// <p><p>Obtains the inline policy assigned to the permission set.</p></p>
+    fn get_inline_policy_for_permission_set(
+        &self,
+        input: GetInlinePolicyForPermissionSetRequest,
+    ) -> RusotoFuture<GetInlinePolicyForPermissionSetResponse, GetInlinePolicyForPermissionSetError>;
+
+    /// <p><p>Obtains the permissions boundary for a specified <a>PermissionSet</a>.</p></p>
+    fn get_permissions_boundary_for_permission_set(
+        &self,
+        input: GetPermissionsBoundaryForPermissionSetRequest,
+    ) -> RusotoFuture<
+        GetPermissionsBoundaryForPermissionSetResponse,
+        GetPermissionsBoundaryForPermissionSetError,
+    >;
+
+    /// <p><p>Lists the status of the Amazon Web Services account assignment creation requests for a specified IAM Identity Center instance.</p></p>
+    fn list_account_assignment_creation_status(
+        &self,
+        input: ListAccountAssignmentCreationStatusRequest,
+    ) -> RusotoFuture<
+        ListAccountAssignmentCreationStatusResponse,
+        ListAccountAssignmentCreationStatusError,
+    >;
+
+    /// <p><p>Lists the status of the Amazon Web Services account assignment deletion requests for a specified IAM Identity Center instance.</p></p>
+    fn list_account_assignment_deletion_status(
+        &self,
+        input: ListAccountAssignmentDeletionStatusRequest,
+    ) -> RusotoFuture<
+        ListAccountAssignmentDeletionStatusResponse,
+        ListAccountAssignmentDeletionStatusError,
+    >;
+
+    /// <p><p>Lists the assignee of the specified Amazon Web Services account with the specified permission set.</p></p>
+    fn list_account_assignments(
+        &self,
+        input: ListAccountAssignmentsRequest,
+    ) -> RusotoFuture<ListAccountAssignmentsResponse, ListAccountAssignmentsError>;
+
+    /// <p><p>Retrieves a list of the IAM Identity Center associated Amazon Web Services accounts that the principal has access to.</p></p>
+    fn list_account_assignments_for_principal(
+        &self,
+        input: ListAccountAssignmentsForPrincipalRequest,
+    ) -> RusotoFuture<
+        ListAccountAssignmentsForPrincipalResponse,
+        ListAccountAssignmentsForPrincipalError,
+    >;
+
+    /// <p><p>Lists all the Amazon Web Services accounts where the specified permission set is provisioned.</p></p>
+    fn list_accounts_for_provisioned_permission_set(
+        &self,
+        input: ListAccountsForProvisionedPermissionSetRequest,
+    ) -> RusotoFuture<
+        ListAccountsForProvisionedPermissionSetResponse,
+        ListAccountsForProvisionedPermissionSetError,
+    >;
+
+    /// <p><p>Lists the access scopes and authorized targets associated with an application.</p></p>
+    fn list_application_access_scopes(
+        &self,
+        input: ListApplicationAccessScopesRequest,
+    ) -> RusotoFuture<ListApplicationAccessScopesResponse, ListApplicationAccessScopesError>;
+
+    /// <p><p>Lists Amazon Web Services account users that are assigned to an application.</p></p>
+    fn list_application_assignments(
+        &self,
+        input: ListApplicationAssignmentsRequest,
+    ) -> RusotoFuture<ListApplicationAssignmentsResponse, ListApplicationAssignmentsError>;
+
+    /// <p><p>Lists the applications to which a specified principal is assigned.</p></p>
+    fn list_application_assignments_for_principal(
+        &self,
+        input: ListApplicationAssignmentsForPrincipalRequest,
+    ) -> RusotoFuture<
+        ListApplicationAssignmentsForPrincipalResponse,
+        ListApplicationAssignmentsForPrincipalError,
+    >;
+
+    /// <p><p>Lists all of the authentication methods supported by the specified application.</p></p>
+    fn list_application_authentication_methods(
+        &self,
+        input: ListApplicationAuthenticationMethodsRequest,
+    ) -> RusotoFuture<
+        ListApplicationAuthenticationMethodsResponse,
+        ListApplicationAuthenticationMethodsError,
+    >;
+
+    /// <p><p>List the grants associated with an application.</p></p>
+    fn list_application_grants(
+        &self,
+        input: ListApplicationGrantsRequest,
+    ) -> RusotoFuture<ListApplicationGrantsResponse, ListApplicationGrantsError>;
+
+    /// <p><p>Lists the application providers configured in the IAM Identity Center identity store.</p></p>
+    fn list_application_providers(
+        &self,
+        input: ListApplicationProvidersRequest,
+    ) -> RusotoFuture<ListApplicationProvidersResponse, ListApplicationProvidersError>;
+
+    /// <p><p>Lists all applications associated with the instance of IAM Identity Center. When listing applications for an instance in the management account, member accounts must use the <code>applicationAccount</code> parameter to filter the list to only applications created from that account.</p></p>
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError>;
+
+    /// <p><p>Lists all customer managed policies attached to a specified <a>PermissionSet</a>.</p></p>
+    fn list_customer_managed_policy_references_in_permission_set(
+        &self,
+        input: ListCustomerManagedPolicyReferencesInPermissionSetRequest,
+    ) -> RusotoFuture<
+        ListCustomerManagedPolicyReferencesInPermissionSetResponse,
+        ListCustomerManagedPolicyReferencesInPermissionSetError,
+    >;
+
+    /// <p><p>Lists the details of the organization and account instances of IAM Identity Center that were created in or visible to the account calling this API. </p></p>
+    fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> RusotoFuture<ListInstancesResponse, ListInstancesError>;
+
+    /// <p><p>Lists the Amazon Web Services managed policy that is attached to a specified permission set.</p></p>
+    fn list_managed_policies_in_permission_set(
+        &self,
+        input: ListManagedPoliciesInPermissionSetRequest,
+    ) -> RusotoFuture<
+        ListManagedPoliciesInPermissionSetResponse,
+        ListManagedPoliciesInPermissionSetError,
+    >;
+
+    /// <p><p>Lists the status of the permission set provisioning requests for a specified IAM Identity Center instance.</p></p>
+    fn list_permission_set_provisioning_status(
+        &self,
+        input: ListPermissionSetProvisioningStatusRequest,
+    ) -> RusotoFuture<
+        ListPermissionSetProvisioningStatusResponse,
+        ListPermissionSetProvisioningStatusError,
+    >;
+
+    /// <p><p>Lists the <a>PermissionSet</a>s in an IAM Identity Center instance.</p></p>
+    fn list_permission_sets(
+        &self,
+        input: ListPermissionSetsRequest,
+    ) -> RusotoFuture<ListPermissionSetsResponse, ListPermissionSetsError>;
+
+    /// <p><p>Lists all the permission sets that are provisioned to a specified Amazon Web Services account.</p></p>
+    fn list_permission_sets_provisioned_to_account(
+        &self,
+        input: ListPermissionSetsProvisionedToAccountRequest,
+    ) -> RusotoFuture<
+        ListPermissionSetsProvisionedToAccountResponse,
+        ListPermissionSetsProvisionedToAccountError,
+    >;
+
+    /// <p><p>Lists the tags that are attached to a specified resource.</p></p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
+
+    /// <p><p>Lists all the trusted token issuers configured in an instance of IAM Identity Center.</p></p>
+    fn list_trusted_token_issuers(
+        &self,
+        input: ListTrustedTokenIssuersRequest,
+    ) -> RusotoFuture<ListTrustedTokenIssuersResponse, ListTrustedTokenIssuersError>;
+
+    /// <p><p>The process by which a specified permission set is provisioned to the specified target.</p></p>
+    fn provision_permission_set(
+        &self,
+        input: ProvisionPermissionSetRequest,
+    ) -> RusotoFuture<ProvisionPermissionSetResponse, ProvisionPermissionSetError>;
+
+    /// <p><p>Adds or updates the list of authorized targets for an IAM Identity Center access scope for an application.</p></p>
+    fn put_application_access_scope(
+        &self,
+        input: PutApplicationAccessScopeRequest,
+    ) -> RusotoFuture<(), PutApplicationAccessScopeError>;
+
+    /// <p><p>Configure how users gain access to an application. If <code>AssignmentsRequired</code> is <code>true</code> (default value), users don’t have access to the application unless an assignment is created using the <a href="https://docs.aws.amazon.com/singlesignon/latest/APIReference/API_CreateApplicationAssignment.html">CreateApplicationAssignment API</a>. If <code>false</code>, all users have access to the application. If an assignment is created using <a href="https://docs.aws.amazon.com/singlesignon/latest/APIReference/API_CreateApplicationAssignment.html">CreateApplicationAssignment</a>., the user retains access if <code>AssignmentsRequired</code> is set to <code>true</code>. </p></p>
+    fn put_application_assignment_configuration(
+        &self,
+        input: PutApplicationAssignmentConfigurationRequest,
+    ) -> RusotoFuture<
+        PutApplicationAssignmentConfigurationResponse,
+        PutApplicationAssignmentConfigurationError,
+    >;
+
+    /// <p><p>Adds or updates an authentication method for an application.</p></p>
+    fn put_application_authentication_method(
+        &self,
+        input: PutApplicationAuthenticationMethodRequest,
+    ) -> RusotoFuture<(), PutApplicationAuthenticationMethodError>;
+
+    /// <p><p>Adds a grant to an application.</p></p>
+    fn put_application_grant(
+        &self,
+        input: PutApplicationGrantRequest,
+    ) -> RusotoFuture<(), PutApplicationGrantError>;
+
+    /// <p><p>Attaches an inline policy to a permission set.</p> <note> <p>If the permission set is already referenced by one or more account assignments, you will need to call <code> <a>ProvisionPermissionSet</a> </code> after this action to apply the corresponding IAM policy updates to all assigned accounts.</p> </note></p>
+    fn put_inline_policy_to_permission_set(
+        &self,
+        input: PutInlinePolicyToPermissionSetRequest,
+    ) -> RusotoFuture<PutInlinePolicyToPermissionSetResponse, PutInlinePolicyToPermissionSetError>;
+
+    /// <p><p>Attaches an Amazon Web Services managed or customer managed policy to the specified <a>PermissionSet</a> as a permissions boundary.</p></p>
+    fn put_permissions_boundary_to_permission_set(
+        &self,
+        input: PutPermissionsBoundaryToPermissionSetRequest,
+    ) -> RusotoFuture<
+        PutPermissionsBoundaryToPermissionSetResponse,
+        PutPermissionsBoundaryToPermissionSetError,
+    >;
+
+    /// <p><p>Associates a set of tags with a specified resource.</p></p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError>;
+
+    /// <p><p>Disassociates a set of tags from a specified resource.</p></p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
+
+    /// <p><p>Updates application properties. </p></p>
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError>;
+
+    /// <p><p>Update the details for the instance of IAM Identity Center that is owned by the Amazon Web Services account.</p></p>
+    fn update_instance(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> RusotoFuture<UpdateInstanceResponse, UpdateInstanceError>;
+
+    /// <p><p>Updates the IAM Identity Center identity store attributes that you can use with the IAM Identity Center instance for attributes-based access control (ABAC). When using an external identity provider as an identity source, you can pass attributes through the SAML assertion as an alternative to configuring attributes from the IAM Identity Center identity store. If a SAML assertion passes any of these attributes, IAM Identity Center replaces the attribute value with the value from the IAM Identity Center identity store. For more information about ABAC, see <a href="/singlesignon/latest/userguide/abac.html">Attribute-Based Access Control</a> in the <i>IAM Identity Center User Guide</i>.</p></p>
+    fn update_instance_access_control_attribute_configuration(
+        &self,
+        input: UpdateInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateInstanceAccessControlAttributeConfigurationResponse,
+        UpdateInstanceAccessControlAttributeConfigurationError,
+    >;
+
+    /// <p><p>Updates an existing permission set.</p></p>
+    fn update_permission_set(
+        &self,
+        input: UpdatePermissionSetRequest,
+    ) -> RusotoFuture<UpdatePermissionSetResponse, UpdatePermissionSetError>;
+
+    /// <p><p>Updates the name of the trusted token issuer, or the path of a source attribute or destination attribute for a trusted token issuer configuration.</p> <note> <p>Updating this trusted token issuer configuration might cause users to lose access to any applications that are configured to use the trusted token issuer.</p> </note></p>
+    fn update_trusted_token_issuer(
+        &self,
+        input: UpdateTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<UpdateTrustedTokenIssuerResponse, UpdateTrustedTokenIssuerError>;
+}
+impl<T: ?Sized + SsoAdmin> SsoAdmin for ::std::sync::Arc<T> {
+    fn attach_customer_managed_policy_reference_to_permission_set(
+        &self,
+        input: AttachCustomerManagedPolicyReferenceToPermissionSetRequest,
+    ) -> RusotoFuture<
+        AttachCustomerManagedPolicyReferenceToPermissionSetResponse,
+        AttachCustomerManagedPolicyReferenceToPermissionSetError,
+    > {
+        (**self).attach_customer_managed_policy_reference_to_permission_set(input)
+    }
+    fn attach_managed_policy_to_permission_set(
+        &self,
+        input: AttachManagedPolicyToPermissionSetRequest,
+    ) -> RusotoFuture<
+        AttachManagedPolicyToPermissionSetResponse,
+        AttachManagedPolicyToPermissionSetError,
+    > {
+        (**self).attach_managed_policy_to_permission_set(input)
+    }
+    fn create_account_assignment(
+        &self,
+        input: CreateAccountAssignmentRequest,
+    ) -> RusotoFuture<CreateAccountAssignmentResponse, CreateAccountAssignmentError> {
+        (**self).create_account_assignment(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_application_assignment(
+        &self,
+        input: CreateApplicationAssignmentRequest,
+    ) -> RusotoFuture<CreateApplicationAssignmentResponse, CreateApplicationAssignmentError> {
+        (**self).create_application_assignment(input)
+    }
+    fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> RusotoFuture<CreateInstanceResponse, CreateInstanceError> {
+        (**self).create_instance(input)
+    }
+    fn create_instance_access_control_attribute_configuration(
+        &self,
+        input: CreateInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        CreateInstanceAccessControlAttributeConfigurationResponse,
+        CreateInstanceAccessControlAttributeConfigurationError,
+    > {
+        (**self).create_instance_access_control_attribute_configuration(input)
+    }
+    fn create_permission_set(
+        &self,
+        input: CreatePermissionSetRequest,
+    ) -> RusotoFuture<CreatePermissionSetResponse, CreatePermissionSetError> {
+        (**self).create_permission_set(input)
+    }
+    fn create_trusted_token_issuer(
+        &self,
+        input: CreateTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<CreateTrustedTokenIssuerResponse, CreateTrustedTokenIssuerError> {
+        (**self).create_trusted_token_issuer(input)
+    }
+    fn delete_account_assignment(
+        &self,
+        input: DeleteAccountAssignmentRequest,
+    ) -> RusotoFuture<DeleteAccountAssignmentResponse, DeleteAccountAssignmentError> {
+        (**self).delete_account_assignment(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<DeleteApplicationResponse, DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn delete_application_access_scope(
+        &self,
+        input: DeleteApplicationAccessScopeRequest,
+    ) -> RusotoFuture<(), DeleteApplicationAccessScopeError> {
+        (**self).delete_application_access_scope(input)
+    }
+    fn delete_application_assignment(
+        &self,
+        input: DeleteApplicationAssignmentRequest,
+    ) -> RusotoFuture<DeleteApplicationAssignmentResponse, DeleteApplicationAssignmentError> {
+        (**self).delete_application_assignment(input)
+    }
+    fn delete_application_authentication_method(
+        &self,
+        input: DeleteApplicationAuthenticationMethodRequest,
+    ) -> RusotoFuture<(), DeleteApplicationAuthenticationMethodError> {
+        (**self).delete_application_authentication_method(input)
+    }
+    fn delete_application_grant(
+        &self,
+        input: DeleteApplicationGrantRequest,
+    ) -> RusotoFuture<(), DeleteApplicationGrantError> {
+        (**self).delete_application_grant(input)
+    }
+    fn delete_inline_policy_from_permission_set(
+        &self,
+        input: DeleteInlinePolicyFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DeleteInlinePolicyFromPermissionSetResponse,
+        DeleteInlinePolicyFromPermissionSetError,
+    > {
+        (**self).delete_inline_policy_from_permission_set(input)
+    }
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<DeleteInstanceResponse, DeleteInstanceError> {
+        (**self).delete_instance(input)
+    }
+    fn delete_instance_access_control_attribute_configuration(
+        &self,
+        input: DeleteInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteInstanceAccessControlAttributeConfigurationResponse,
+        DeleteInstanceAccessControlAttributeConfigurationError,
+    > {
+        (**self).delete_instance_access_control_attribute_configuration(input)
+    }
+    fn delete_permission_set(
+        &self,
+        input: DeletePermissionSetRequest,
+    ) -> RusotoFuture<DeletePermissionSetResponse, DeletePermissionSetError> {
+        (**self).delete_permission_set(input)
+    }
+    fn delete_permissions_boundary_from_permission_set(
+        &self,
+        input: DeletePermissionsBoundaryFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DeletePermissionsBoundaryFromPermissionSetResponse,
+        DeletePermissionsBoundaryFromPermissionSetError,
+    > {
+        (**self).delete_permissions_boundary_from_permission_set(input)
+    }
+    fn delete_trusted_token_issuer(
+        &self,
+        input: DeleteTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<DeleteTrustedTokenIssuerResponse, DeleteTrustedTokenIssuerError> {
+        (**self).delete_trusted_token_issuer(input)
+    }
+    fn describe_account_assignment_creation_status(
+        &self,
+        input: DescribeAccountAssignmentCreationStatusRequest,
+    ) -> RusotoFuture<
+        DescribeAccountAssignmentCreationStatusResponse,
+        DescribeAccountAssignmentCreationStatusError,
+    > {
+        (**self).describe_account_assignment_creation_status(input)
+    }
+    fn describe_account_assignment_deletion_status(
+        &self,
+        input: DescribeAccountAssignmentDeletionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeAccountAssignmentDeletionStatusResponse,
+        DescribeAccountAssignmentDeletionStatusError,
+    > {
+        (**self).describe_account_assignment_deletion_status(input)
+    }
+    fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> RusotoFuture<DescribeApplicationResponse, DescribeApplicationError> {
+        (**self).describe_application(input)
+    }
+    fn describe_application_assignment(
+        &self,
+        input: DescribeApplicationAssignmentRequest,
+    ) -> RusotoFuture<DescribeApplicationAssignmentResponse, DescribeApplicationAssignmentError>
+    {
+        (**self).describe_application_assignment(input)
+    }
+    fn describe_application_provider(
+        &self,
+        input: DescribeApplicationProviderRequest,
+    ) -> RusotoFuture<DescribeApplicationProviderResponse, DescribeApplicationProviderError> {
+        (**self).describe_application_provider(input)
+    }
+    fn describe_instance(
+        &self,
+        input: DescribeInstanceRequest,
+    ) -> RusotoFuture<DescribeInstanceResponse, DescribeInstanceError> {
+        (**self).describe_instance(input)
+    }
+    fn describe_instance_access_control_attribute_configuration(
+        &self,
+        input: DescribeInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeInstanceAccessControlAttributeConfigurationResponse,
+        DescribeInstanceAccessControlAttributeConfigurationError,
+    > {
+        (**self).describe_instance_access_control_attribute_configuration(input)
+    }
+    fn describe_permission_set(
+        &self,
+        input: DescribePermissionSetRequest,
+    ) -> RusotoFuture<DescribePermissionSetResponse, DescribePermissionSetError> {
+        (**self).describe_permission_set(input)
+    }
+    fn describe_permission_set_provisioning_status(
+        &self,
+        input: DescribePermissionSetProvisioningStatusRequest,
+    ) -> RusotoFuture<
+        DescribePermissionSetProvisioningStatusResponse,
+        DescribePermissionSetProvisioningStatusError,
+    > {
+        (**self).describe_permission_set_provisioning_status(input)
+    }
+    fn describe_trusted_token_issuer(
+        &self,
+        input: DescribeTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<DescribeTrustedTokenIssuerResponse, DescribeTrustedTokenIssuerError> {
+        (**self).describe_trusted_token_issuer(input)
+    }
+    fn detach_customer_managed_policy_reference_from_permission_set(
+        &self,
+        input: DetachCustomerManagedPolicyReferenceFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DetachCustomerManagedPolicyReferenceFromPermissionSetResponse,
+        DetachCustomerManagedPolicyReferenceFromPermissionSetError,
+    > {
+        (**self).detach_customer_managed_policy_reference_from_permission_set(input)
+    }
+    fn detach_managed_policy_from_permission_set(
+        &self,
+        input: DetachManagedPolicyFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DetachManagedPolicyFromPermissionSetResponse,
+        DetachManagedPolicyFromPermissionSetError,
+    > {
+        (**self).detach_managed_policy_from_permission_set(input)
+    }
+    fn get_application_access_scope(
+        &self,
+        input: GetApplicationAccessScopeRequest,
+    ) -> RusotoFuture<GetApplicationAccessScopeResponse, GetApplicationAccessScopeError> {
+        (**self).get_application_access_scope(input)
+    }
+    fn get_application_assignment_configuration(
+        &self,
+        input: GetApplicationAssignmentConfigurationRequest,
+    ) -> RusotoFuture<
+        GetApplicationAssignmentConfigurationResponse,
+        GetApplicationAssignmentConfigurationError,
+    > {
+        (**self).get_application_assignment_configuration(input)
+    }
+    fn get_application_authentication_method(
+        &self,
+        input: GetApplicationAuthenticationMethodRequest,
+    ) -> RusotoFuture<
+        GetApplicationAuthenticationMethodResponse,
+        GetApplicationAuthenticationMethodError,
+    > {
+        (**self).get_application_authentication_method(input)
+    }
+    fn get_application_grant(
+        &self,
+        input: GetApplicationGrantRequest,
+    ) -> RusotoFuture<GetApplicationGrantResponse, GetApplicationGrantError> {
+        (**self).get_application_grant(input)
+    }
+    fn get_inline_policy_for_permission_set(
+        &self,
+        input: GetInlinePolicyForPermissionSetRequest,
+    ) -> RusotoFuture<GetInlinePolicyForPermissionSetResponse, GetInlinePolicyForPermissionSetError>
+    {
+        (**self).get_inline_policy_for_permission_set(input)
+    }
+    fn get_permissions_boundary_for_permission_set(
+        &self,
+        input: GetPermissionsBoundaryForPermissionSetRequest,
+    ) -> RusotoFuture<
+        GetPermissionsBoundaryForPermissionSetResponse,
+        GetPermissionsBoundaryForPermissionSetError,
+    > {
+        (**self).get_permissions_boundary_for_permission_set(input)
+    }
+    fn list_account_assignment_creation_status(
+        &self,
+        input: ListAccountAssignmentCreationStatusRequest,
+    ) -> RusotoFuture<
+        ListAccountAssignmentCreationStatusResponse,
+        ListAccountAssignmentCreationStatusError,
+    > {
+        (**self).list_account_assignment_creation_status(input)
+    }
+    fn list_account_assignment_deletion_status(
+        &self,
+        input: ListAccountAssignmentDeletionStatusRequest,
+    ) -> RusotoFuture<
+        ListAccountAssignmentDeletionStatusResponse,
+        ListAccountAssignmentDeletionStatusError,
+    > {
+        (**self).list_account_assignment_deletion_status(input)
+    }
+    fn list_account_assignments(
+        &self,
+        input: ListAccountAssignmentsRequest,
+    ) -> RusotoFuture<ListAccountAssignmentsResponse, ListAccountAssignmentsError> {
+        (**self).list_account_assignments(input)
+    }
+    fn list_account_assignments_for_principal(
+        &self,
+        input: ListAccountAssignmentsForPrincipalRequest,
+    ) -> RusotoFuture<
+        ListAccountAssignmentsForPrincipalResponse,
+        ListAccountAssignmentsForPrincipalError,
+    > {
+        (**self).list_account_assignments_for_principal(input)
+    }
+    fn list_accounts_for_provisioned_permission_set(
+        &self,
+        input: ListAccountsForProvisionedPermissionSetRequest,
+    ) -> RusotoFuture<
+        ListAccountsForProvisionedPermissionSetResponse,
+        ListAccountsForProvisionedPermissionSetError,
+    > {
+        (**self).list_accounts_for_provisioned_permission_set(input)
+    }
+    fn list_application_access_scopes(
+        &self,
+        input: ListApplicationAccessScopesRequest,
+    ) -> RusotoFuture<ListApplicationAccessScopesResponse, ListApplicationAccessScopesError> {
+        (**self).list_application_access_scopes(input)
+    }
+    fn list_application_assignments(
+        &self,
+        input: ListApplicationAssignmentsRequest,
+    ) -> RusotoFuture<ListApplicationAssignmentsResponse, ListApplicationAssignmentsError> {
+        (**self).list_application_assignments(input)
+    }
+    fn list_application_assignments_for_principal(
+        &self,
+        input: ListApplicationAssignmentsForPrincipalRequest,
+    ) -> RusotoFuture<
+        ListApplicationAssignmentsForPrincipalResponse,
+        ListApplicationAssignmentsForPrincipalError,
+    > {
+        (**self).list_application_assignments_for_principal(input)
+    }
+    fn list_application_authentication_methods(
+        &self,
+        input: ListApplicationAuthenticationMethodsRequest,
+    ) -> RusotoFuture<
+        ListApplicationAuthenticationMethodsResponse,
+        ListApplicationAuthenticationMethodsError,
+    > {
+        (**self).list_application_authentication_methods(input)
+    }
+    fn list_application_grants(
+        &self,
+        input: ListApplicationGrantsRequest,
+    ) -> RusotoFuture<ListApplicationGrantsResponse, ListApplicationGrantsError> {
+        (**self).list_application_grants(input)
+    }
+    fn list_application_providers(
+        &self,
+        input: ListApplicationProvidersRequest,
+    ) -> RusotoFuture<ListApplicationProvidersResponse, ListApplicationProvidersError> {
+        (**self).list_application_providers(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn list_customer_managed_policy_references_in_permission_set(
+        &self,
+        input: ListCustomerManagedPolicyReferencesInPermissionSetRequest,
+    ) -> RusotoFuture<
+        ListCustomerManagedPolicyReferencesInPermissionSetResponse,
+        ListCustomerManagedPolicyReferencesInPermissionSetError,
+    > {
+        (**self).list_customer_managed_policy_references_in_permission_set(input)
+    }
+    fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> RusotoFuture<ListInstancesResponse, ListInstancesError> {
+        (**self).list_instances(input)
+    }
+    fn list_managed_policies_in_permission_set(
+        &self,
+        input: ListManagedPoliciesInPermissionSetRequest,
+    ) -> RusotoFuture<
+        ListManagedPoliciesInPermissionSetResponse,
+        ListManagedPoliciesInPermissionSetError,
+    > {
+        (**self).list_managed_policies_in_permission_set(input)
+    }
+    fn list_permission_set_provisioning_status(
+        &self,
+        input: ListPermissionSetProvisioningStatusRequest,
+    ) -> RusotoFuture<
+        ListPermissionSetProvisioningStatusResponse,
+        ListPermissionSetProvisioningStatusError,
+    > {
+        (**self).list_permission_set_provisioning_status(input)
+    }
+    fn list_permission_sets(
+        &self,
+        input: ListPermissionSetsRequest,
+    ) -> RusotoFuture<ListPermissionSetsResponse, ListPermissionSetsError> {
+        (**self).list_permission_sets(input)
+    }
+    fn list_permission_sets_provisioned_to_account(
+        &self,
+        input: ListPermissionSetsProvisionedToAccountRequest,
+    ) -> RusotoFuture<
+        ListPermissionSetsProvisionedToAccountResponse,
+        ListPermissionSetsProvisionedToAccountError,
+    > {
+        (**self).list_permission_sets_provisioned_to_account(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_trusted_token_issuers(
+        &self,
+        input: ListTrustedTokenIssuersRequest,
+    ) -> RusotoFuture<ListTrustedTokenIssuersResponse, ListTrustedTokenIssuersError> {
+        (**self).list_trusted_token_issuers(input)
+    }
+    fn provision_permission_set(
+        &self,
+        input: ProvisionPermissionSetRequest,
+    ) -> RusotoFuture<ProvisionPermissionSetResponse, ProvisionPermissionSetError> {
+        (**self).provision_permission_set(input)
+    }
+    fn put_application_access_scope(
+        &self,
+        input: PutApplicationAccessScopeRequest,
+    ) -> RusotoFuture<(), PutApplicationAccessScopeError> {
+        (**self).put_application_access_scope(input)
+    }
+    fn put_application_assignment_configuration(
+        &self,
+        input: PutApplicationAssignmentConfigurationRequest,
+    ) -> RusotoFuture<
+        PutApplicationAssignmentConfigurationResponse,
+        PutApplicationAssignmentConfigurationError,
+    > {
+        (**self).put_application_assignment_configuration(input)
+    }
+    fn put_application_authentication_method(
+        &self,
+        input: PutApplicationAuthenticationMethodRequest,
+    ) -> RusotoFuture<(), PutApplicationAuthenticationMethodError> {
+        (**self).put_application_authentication_method(input)
+    }
+    fn put_application_grant(
+        &self,
+        input: PutApplicationGrantRequest,
+    ) -> RusotoFuture<(), PutApplicationGrantError> {
+        (**self).put_application_grant(input)
+    }
+    fn put_inline_policy_to_permission_set(
+        &self,
+        input: PutInlinePolicyToPermissionSetRequest,
+    ) -> RusotoFuture<PutInlinePolicyToPermissionSetResponse, PutInlinePolicyToPermissionSetError>
+    {
+        (**self).put_inline_policy_to_permission_set(input)
+    }
+    fn put_permissions_boundary_to_permission_set(
+        &self,
+        input: PutPermissionsBoundaryToPermissionSetRequest,
+    ) -> RusotoFuture<
+        PutPermissionsBoundaryToPermissionSetResponse,
+        PutPermissionsBoundaryToPermissionSetError,
+    > {
+        (**self).put_permissions_boundary_to_permission_set(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+    fn update_instance(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> RusotoFuture<UpdateInstanceResponse, UpdateInstanceError> {
+        (**self).update_instance(input)
+    }
+    fn update_instance_access_control_attribute_configuration(
+        &self,
+        input: UpdateInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateInstanceAccessControlAttributeConfigurationResponse,
+        UpdateInstanceAccessControlAttributeConfigurationError,
+    > {
+        (**self).update_instance_access_control_attribute_configuration(input)
+    }
+    fn update_permission_set(
+        &self,
+        input: UpdatePermissionSetRequest,
+    ) -> RusotoFuture<UpdatePermissionSetResponse, UpdatePermissionSetError> {
+        (**self).update_permission_set(input)
+    }
+    fn update_trusted_token_issuer(
+        &self,
+        input: UpdateTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<UpdateTrustedTokenIssuerResponse, UpdateTrustedTokenIssuerError> {
+        (**self).update_trusted_token_issuer(input)
+    }
+}
+/// The error codes SSO Admin retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
+/// A client for the SSO Admin API.
+#[derive(Clone)]
+pub struct SsoAdminClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl SsoAdminClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SsoAdminClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SSO_ADMIN`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> SsoAdminClient {
+        Self::new(region::Region::from_env_for_service("SSO_ADMIN"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `sso`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> SsoAdminClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("sso", "SSO_ADMIN"),
+        )
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SsoAdminClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> SsoAdminClient {
+        SsoAdminClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "sso")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "sso", "sso", "SSO_ADMIN")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `SsoAdmin` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn SsoAdmin + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for SsoAdminClient {
+    fn service_name(&self) -> &'static str {
+        "SSO Admin"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "sso"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> SsoAdminClient {
+        Self::new_with_client(client, region)
+    }
+}
+
+impl SsoAdmin for SsoAdminClient {
+    /// <p><p>Attaches the specified customer managed policy to the specified <a>PermissionSet</a>.</p></p>
+    fn attach_customer_managed_policy_reference_to_permission_set(
+        &self,
+        input: AttachCustomerManagedPolicyReferenceToPermissionSetRequest,
+    ) -> RusotoFuture<
+        AttachCustomerManagedPolicyReferenceToPermissionSetResponse,
+        AttachCustomerManagedPolicyReferenceToPermissionSetError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.AttachCustomerManagedPolicyReferenceToPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+                        if response.status.is_success() {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response).deserialize::<AttachCustomerManagedPolicyReferenceToPermissionSetResponse, _>()
+                }))
+                        } else {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                                Err(AttachCustomerManagedPolicyReferenceToPermissionSetError::from_response(response))
+                            }))
+                        }
+                    })
+    }
+
+    /// <p><p>Attaches an Amazon Web Services managed policy ARN to a permission set.</p> <note> <p>If the permission set is already referenced by one or more account assignments, you will need to call <code> <a>ProvisionPermissionSet</a> </code> after this operation. Calling <code>ProvisionPermissionSet</code> applies the corresponding IAM policy updates to all assigned accounts.</p> </note></p>
+    fn attach_managed_policy_to_permission_set(
+        &self,
+        input: AttachManagedPolicyToPermissionSetRequest,
+    ) -> RusotoFuture<
+        AttachManagedPolicyToPermissionSetResponse,
+        AttachManagedPolicyToPermissionSetError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.AttachManagedPolicyToPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<AttachManagedPolicyToPermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AttachManagedPolicyToPermissionSetError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Assigns access to a principal for a specified Amazon Web Services account using a specified permission set.</p> <note> <p>The term <i>principal</i> here refers to a user or group that is defined in IAM Identity Center.</p> </note> <note> <p>As part of a successful <code>CreateAccountAssignment</code> call, the specified permission set will automatically be provisioned to the account in the form of an IAM policy. That policy is attached to the IAM role created in IAM Identity Center. If the permission set is subsequently updated, the corresponding IAM policies attached to roles in your accounts will not be updated automatically. In this case, you must call <code> <a>ProvisionPermissionSet</a> </code> to make these updates.</p> </note> <note> <p> After a successful response, call <code>DescribeAccountAssignmentCreationStatus</code> to describe the status of an assignment creation request. </p> </note></p>
+    fn create_account_assignment(
+        &self,
+        input: CreateAccountAssignmentRequest,
+    ) -> RusotoFuture<CreateAccountAssignmentResponse, CreateAccountAssignmentError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.CreateAccountAssignment");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<CreateAccountAssignmentResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateAccountAssignmentError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates an application in IAM Identity Center for the given application provider.</p></p>
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.CreateApplication");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<CreateApplicationResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateApplicationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Grant application access to a user or group.</p></p>
+    fn create_application_assignment(
+        &self,
+        input: CreateApplicationAssignmentRequest,
+    ) -> RusotoFuture<CreateApplicationAssignmentResponse, CreateApplicationAssignmentError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.CreateApplicationAssignment",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<CreateApplicationAssignmentResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateApplicationAssignmentError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates an instance of IAM Identity Center for a standalone Amazon Web Services account that is not managed by Organizations or a member Amazon Web Services account in an organization. You can create only one instance per account and across all Amazon Web Services Regions.</p> <p>The CreateInstance request is rejected if the following apply: </p> <ul> <li> <p>The instance is created within the organization management account.</p> </li> <li> <p>An instance already exists in the same account.</p> </li> </ul></p>
+    fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> RusotoFuture<CreateInstanceResponse, CreateInstanceError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.CreateInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<CreateInstanceResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Enables the attributes-based access control (ABAC) feature for the specified IAM Identity Center instance. You can also specify new attributes to add to your ABAC configuration during the enabling process. For more information about ABAC, see <a href="/singlesignon/latest/userguide/abac.html">Attribute-Based Access Control</a> in the <i>IAM Identity Center User Guide</i>.</p> <note> <p>After a successful response, call <code>DescribeInstanceAccessControlAttributeConfiguration</code> to validate that <code>InstanceAccessControlAttributeConfiguration</code> was created.</p> </note></p>
+    fn create_instance_access_control_attribute_configuration(
+        &self,
+        input: CreateInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        CreateInstanceAccessControlAttributeConfigurationResponse,
+        CreateInstanceAccessControlAttributeConfigurationError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.CreateInstanceAccessControlAttributeConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+                        if response.status.is_success() {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response).deserialize::<CreateInstanceAccessControlAttributeConfigurationResponse, _>()
+                }))
+                        } else {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                                Err(CreateInstanceAccessControlAttributeConfigurationError::from_response(response))
+                            }))
+                        }
+                    })
+    }
+
+    /// <p><p>Creates a permission set within a specified IAM Identity Center instance.</p> <note> <p>To grant users and groups access to Amazon Web Services account resources, use <code> <a>CreateAccountAssignment</a> </code>.</p> </note></p>
+    fn create_permission_set(
+        &self,
+        input: CreatePermissionSetRequest,
+    ) -> RusotoFuture<CreatePermissionSetResponse, CreatePermissionSetError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.CreatePermissionSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<CreatePermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreatePermissionSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a connection to a trusted token issuer in an instance of IAM Identity Center. A trusted token issuer enables trusted identity propagation to be used with applications that authenticate outside of Amazon Web Services.</p> <p>This trusted token issuer describes an external identity provider (IdP) that can generate claims or assertions in the form of access tokens for a user. Applications enabled for IAM Identity Center can use these tokens for authentication. </p></p>
+    fn create_trusted_token_issuer(
+        &self,
+        input: CreateTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<CreateTrustedTokenIssuerResponse, CreateTrustedTokenIssuerError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.CreateTrustedTokenIssuer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<CreateTrustedTokenIssuerResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateTrustedTokenIssuerError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes a principal's access from a specified Amazon Web Services account using a specified permission set.</p> <note> <p>After a successful response, call <code>DescribeAccountAssignmentDeletionStatus</code> to describe the status of an assignment deletion request.</p> </note></p>
+    fn delete_account_assignment(
+        &self,
+        input: DeleteAccountAssignmentRequest,
+    ) -> RusotoFuture<DeleteAccountAssignmentResponse, DeleteAccountAssignmentError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.DeleteAccountAssignment");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DeleteAccountAssignmentResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteAccountAssignmentError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes the association with the application. The connected service resource still exists.</p></p>
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<DeleteApplicationResponse, DeleteApplicationError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.DeleteApplication");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DeleteApplicationResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteApplicationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes an IAM Identity Center access scope from an application.</p></p>
+    fn delete_application_access_scope(
+        &self,
+        input: DeleteApplicationAccessScopeRequest,
+    ) -> RusotoFuture<(), DeleteApplicationAccessScopeError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DeleteApplicationAccessScope",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteApplicationAccessScopeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Revoke application access to an application by deleting application assignments for a user or group.</p></p>
+    fn delete_application_assignment(
+        &self,
+        input: DeleteApplicationAssignmentRequest,
+    ) -> RusotoFuture<DeleteApplicationAssignmentResponse, DeleteApplicationAssignmentError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DeleteApplicationAssignment",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DeleteApplicationAssignmentResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteApplicationAssignmentError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes an authentication method from an application.</p></p>
+    fn delete_application_authentication_method(
+        &self,
+        input: DeleteApplicationAuthenticationMethodRequest,
+    ) -> RusotoFuture<(), DeleteApplicationAuthenticationMethodError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DeleteApplicationAuthenticationMethod",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteApplicationAuthenticationMethodError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes a grant from an application.</p></p>
+    fn delete_application_grant(
+        &self,
+        input: DeleteApplicationGrantRequest,
+    ) -> RusotoFuture<(), DeleteApplicationGrantError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.DeleteApplicationGrant");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteApplicationGrantError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes the inline policy from a specified permission set.</p></p>
+    fn delete_inline_policy_from_permission_set(
+        &self,
+        input: DeleteInlinePolicyFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DeleteInlinePolicyFromPermissionSetResponse,
+        DeleteInlinePolicyFromPermissionSetError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DeleteInlinePolicyFromPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DeleteInlinePolicyFromPermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteInlinePolicyFromPermissionSetError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes the instance of IAM Identity Center. Only the account that owns the instance can call this API. Neither the delegated administrator nor member account can delete the organization instance, but those roles can delete their own instance.</p></p>
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<DeleteInstanceResponse, DeleteInstanceError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.DeleteInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DeleteInstanceResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Disables the attributes-based access control (ABAC) feature for the specified IAM Identity Center instance and deletes all of the attribute mappings that have been configured. Once deleted, any attributes that are received from an identity source and any custom attributes you have previously configured will not be passed. For more information about ABAC, see <a href="/singlesignon/latest/userguide/abac.html">Attribute-Based Access Control</a> in the <i>IAM Identity Center User Guide</i>.</p></p>
+    fn delete_instance_access_control_attribute_configuration(
+        &self,
+        input: DeleteInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteInstanceAccessControlAttributeConfigurationResponse,
+        DeleteInstanceAccessControlAttributeConfigurationError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DeleteInstanceAccessControlAttributeConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+                        if response.status.is_success() {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response).deserialize::<DeleteInstanceAccessControlAttributeConfigurationResponse, _>()
+                }))
+                        } else {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                                Err(DeleteInstanceAccessControlAttributeConfigurationError::from_response(response))
+                            }))
+                        }
+                    })
+    }
+
+    /// <p><p>Deletes the specified permission set.</p></p>
+    fn delete_permission_set(
+        &self,
+        input: DeletePermissionSetRequest,
+    ) -> RusotoFuture<DeletePermissionSetResponse, DeletePermissionSetError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.DeletePermissionSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DeletePermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeletePermissionSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes the permissions boundary from a specified <a>PermissionSet</a>.</p></p>
+    fn delete_permissions_boundary_from_permission_set(
+        &self,
+        input: DeletePermissionsBoundaryFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DeletePermissionsBoundaryFromPermissionSetResponse,
+        DeletePermissionsBoundaryFromPermissionSetError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DeletePermissionsBoundaryFromPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DeletePermissionsBoundaryFromPermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeletePermissionsBoundaryFromPermissionSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes a trusted token issuer configuration from an instance of IAM Identity Center.</p> <note> <p>Deleting this trusted token issuer configuration will cause users to lose access to any applications that are configured to use the trusted token issuer.</p> </note></p>
+    fn delete_trusted_token_issuer(
+        &self,
+        input: DeleteTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<DeleteTrustedTokenIssuerResponse, DeleteTrustedTokenIssuerError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DeleteTrustedTokenIssuer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DeleteTrustedTokenIssuerResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteTrustedTokenIssuerError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Describes the status of the assignment creation request.</p></p>
+    fn describe_account_assignment_creation_status(
+        &self,
+        input: DescribeAccountAssignmentCreationStatusRequest,
+    ) -> RusotoFuture<
+        DescribeAccountAssignmentCreationStatusResponse,
+        DescribeAccountAssignmentCreationStatusError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DescribeAccountAssignmentCreationStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DescribeAccountAssignmentCreationStatusResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAccountAssignmentCreationStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Describes the status of the assignment deletion request.</p></p>
+    fn describe_account_assignment_deletion_status(
+        &self,
+        input: DescribeAccountAssignmentDeletionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeAccountAssignmentDeletionStatusResponse,
+        DescribeAccountAssignmentDeletionStatusError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DescribeAccountAssignmentDeletionStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DescribeAccountAssignmentDeletionStatusResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAccountAssignmentDeletionStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the details of an application associated with an instance of IAM Identity Center.</p></p>
+    fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> RusotoFuture<DescribeApplicationResponse, DescribeApplicationError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.DescribeApplication");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DescribeApplicationResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeApplicationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves a direct assignment of a user or group to an application. If the user doesn’t have a direct assignment to the application, the user may still have access to the application through a group. Therefore, don’t use this API to test access to an application for a user. Instead use <a>ListApplicationAssignmentsForPrincipal</a>.</p></p>
+    fn describe_application_assignment(
+        &self,
+        input: DescribeApplicationAssignmentRequest,
+    ) -> RusotoFuture<DescribeApplicationAssignmentResponse, DescribeApplicationAssignmentError>
+    {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DescribeApplicationAssignment",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DescribeApplicationAssignmentResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeApplicationAssignmentError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves details about a provider that can be used to connect an Amazon Web Services managed application or customer managed application to IAM Identity Center.</p></p>
+    fn describe_application_provider(
+        &self,
+        input: DescribeApplicationProviderRequest,
+    ) -> RusotoFuture<DescribeApplicationProviderResponse, DescribeApplicationProviderError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DescribeApplicationProvider",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DescribeApplicationProviderResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeApplicationProviderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns the details of an instance of IAM Identity Center. The status can be one of the following:</p> <ul> <li> <p> <code>CREATE_IN_PROGRESS</code> - The instance is in the process of being created. When the instance is ready for use, DescribeInstance returns the status of <code>ACTIVE</code>. While the instance is in the <code>CREATE_IN_PROGRESS</code> state, you can call only DescribeInstance and DeleteInstance operations.</p> </li> <li> <p> <code>DELETE_IN_PROGRESS</code> - The instance is being deleted. Returns <code>AccessDeniedException</code> after the delete operation completes. </p> </li> <li> <p> <code>ACTIVE</code> - The instance is active.</p> </li> </ul></p>
+    fn describe_instance(
+        &self,
+        input: DescribeInstanceRequest,
+    ) -> RusotoFuture<DescribeInstanceResponse, DescribeInstanceError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.DescribeInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DescribeInstanceResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Returns the list of IAM Identity Center identity store attributes that have been configured to work with attributes-based access control (ABAC) for the specified IAM Identity Center instance. This will not return attributes configured and sent by an external identity provider. For more information about ABAC, see <a href="/singlesignon/latest/userguide/abac.html">Attribute-Based Access Control</a> in the <i>IAM Identity Center User Guide</i>.</p></p>
+    fn describe_instance_access_control_attribute_configuration(
+        &self,
+        input: DescribeInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeInstanceAccessControlAttributeConfigurationResponse,
+        DescribeInstanceAccessControlAttributeConfigurationError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DescribeInstanceAccessControlAttributeConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+                        if response.status.is_success() {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response).deserialize::<DescribeInstanceAccessControlAttributeConfigurationResponse, _>()
+                }))
+                        } else {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                                Err(DescribeInstanceAccessControlAttributeConfigurationError::from_response(response))
+                            }))
+                        }
+                    })
+    }
+
+    /// <p><p>Gets the details of the permission set.</p></p>
+    fn describe_permission_set(
+        &self,
+        input: DescribePermissionSetRequest,
+    ) -> RusotoFuture<DescribePermissionSetResponse, DescribePermissionSetError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.DescribePermissionSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DescribePermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribePermissionSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Describes the status for the given permission set provisioning request.</p></p>
+    fn describe_permission_set_provisioning_status(
+        &self,
+        input: DescribePermissionSetProvisioningStatusRequest,
+    ) -> RusotoFuture<
+        DescribePermissionSetProvisioningStatusResponse,
+        DescribePermissionSetProvisioningStatusError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DescribePermissionSetProvisioningStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DescribePermissionSetProvisioningStatusResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribePermissionSetProvisioningStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves details about a trusted token issuer configuration stored in an instance of IAM Identity Center. Details include the name of the trusted token issuer, the issuer URL, and the path of the source attribute and the destination attribute for a trusted token issuer configuration. </p></p>
+    fn describe_trusted_token_issuer(
+        &self,
+        input: DescribeTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<DescribeTrustedTokenIssuerResponse, DescribeTrustedTokenIssuerError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DescribeTrustedTokenIssuer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DescribeTrustedTokenIssuerResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeTrustedTokenIssuerError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Detaches the specified customer managed policy from the specified <a>PermissionSet</a>.</p></p>
+    fn detach_customer_managed_policy_reference_from_permission_set(
+        &self,
+        input: DetachCustomerManagedPolicyReferenceFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DetachCustomerManagedPolicyReferenceFromPermissionSetResponse,
+        DetachCustomerManagedPolicyReferenceFromPermissionSetError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DetachCustomerManagedPolicyReferenceFromPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+                        if response.status.is_success() {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response).deserialize::<DetachCustomerManagedPolicyReferenceFromPermissionSetResponse, _>()
+                }))
+                        } else {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                                Err(DetachCustomerManagedPolicyReferenceFromPermissionSetError::from_response(response))
+                            }))
+                        }
+                    })
+    }
+
+    /// <p><p>Detaches the attached Amazon Web Services managed policy ARN from the specified permission set.</p></p>
+    fn detach_managed_policy_from_permission_set(
+        &self,
+        input: DetachManagedPolicyFromPermissionSetRequest,
+    ) -> RusotoFuture<
+        DetachManagedPolicyFromPermissionSetResponse,
+        DetachManagedPolicyFromPermissionSetError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.DetachManagedPolicyFromPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<DetachManagedPolicyFromPermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DetachManagedPolicyFromPermissionSetError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the authorized targets for an IAM Identity Center access scope for an application.</p></p>
+    fn get_application_access_scope(
+        &self,
+        input: GetApplicationAccessScopeRequest,
+    ) -> RusotoFuture<GetApplicationAccessScopeResponse, GetApplicationAccessScopeError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.GetApplicationAccessScope",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<GetApplicationAccessScopeResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetApplicationAccessScopeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the configuration of <a>PutApplicationAssignmentConfiguration</a>.</p></p>
+    fn get_application_assignment_configuration(
+        &self,
+        input: GetApplicationAssignmentConfigurationRequest,
+    ) -> RusotoFuture<
+        GetApplicationAssignmentConfigurationResponse,
+        GetApplicationAssignmentConfigurationError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.GetApplicationAssignmentConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<GetApplicationAssignmentConfigurationResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetApplicationAssignmentConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves details about an authentication method used by an application.</p></p>
+    fn get_application_authentication_method(
+        &self,
+        input: GetApplicationAuthenticationMethodRequest,
+    ) -> RusotoFuture<
+        GetApplicationAuthenticationMethodResponse,
+        GetApplicationAuthenticationMethodError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.GetApplicationAuthenticationMethod",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<GetApplicationAuthenticationMethodResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetApplicationAuthenticationMethodError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Retrieves details about an application grant.</p></p>
+    fn get_application_grant(
+        &self,
+        input: GetApplicationGrantRequest,
+    ) -> RusotoFuture<GetApplicationGrantResponse, GetApplicationGrantError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.GetApplicationGrant");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<GetApplicationGrantResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetApplicationGrantError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Obtains the inline policy assigned to the permission set.</p></p>
+    fn get_inline_policy_for_permission_set(
+        &self,
+        input: GetInlinePolicyForPermissionSetRequest,
+    ) -> RusotoFuture<GetInlinePolicyForPermissionSetResponse, GetInlinePolicyForPermissionSetError>
+    {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.GetInlinePolicyForPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<GetInlinePolicyForPermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetInlinePolicyForPermissionSetError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Obtains the permissions boundary for a specified <a>PermissionSet</a>.</p></p>
+    fn get_permissions_boundary_for_permission_set(
+        &self,
+        input: GetPermissionsBoundaryForPermissionSetRequest,
+    ) -> RusotoFuture<
+        GetPermissionsBoundaryForPermissionSetResponse,
+        GetPermissionsBoundaryForPermissionSetError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.GetPermissionsBoundaryForPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<GetPermissionsBoundaryForPermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetPermissionsBoundaryForPermissionSetError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists the status of the Amazon Web Services account assignment creation requests for a specified IAM Identity Center instance.</p></p>
+    fn list_account_assignment_creation_status(
+        &self,
+        input: ListAccountAssignmentCreationStatusRequest,
+    ) -> RusotoFuture<
+        ListAccountAssignmentCreationStatusResponse,
+        ListAccountAssignmentCreationStatusError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListAccountAssignmentCreationStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListAccountAssignmentCreationStatusResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAccountAssignmentCreationStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists the status of the Amazon Web Services account assignment deletion requests for a specified IAM Identity Center instance.</p></p>
+    fn list_account_assignment_deletion_status(
+        &self,
+        input: ListAccountAssignmentDeletionStatusRequest,
+    ) -> RusotoFuture<
+        ListAccountAssignmentDeletionStatusResponse,
+        ListAccountAssignmentDeletionStatusError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListAccountAssignmentDeletionStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListAccountAssignmentDeletionStatusResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAccountAssignmentDeletionStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists the assignee of the specified Amazon Web Services account with the specified permission set.</p></p>
+    fn list_account_assignments(
+        &self,
+        input: ListAccountAssignmentsRequest,
+    ) -> RusotoFuture<ListAccountAssignmentsResponse, ListAccountAssignmentsError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.ListAccountAssignments");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListAccountAssignmentsResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListAccountAssignmentsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves a list of the IAM Identity Center associated Amazon Web Services accounts that the principal has access to.</p></p>
+    fn list_account_assignments_for_principal(
+        &self,
+        input: ListAccountAssignmentsForPrincipalRequest,
+    ) -> RusotoFuture<
+        ListAccountAssignmentsForPrincipalResponse,
+        ListAccountAssignmentsForPrincipalError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListAccountAssignmentsForPrincipal",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListAccountAssignmentsForPrincipalResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAccountAssignmentsForPrincipalError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists all the Amazon Web Services accounts where the specified permission set is provisioned.</p></p>
+    fn list_accounts_for_provisioned_permission_set(
+        &self,
+        input: ListAccountsForProvisionedPermissionSetRequest,
+    ) -> RusotoFuture<
+        ListAccountsForProvisionedPermissionSetResponse,
+        ListAccountsForProvisionedPermissionSetError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListAccountsForProvisionedPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListAccountsForProvisionedPermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAccountsForProvisionedPermissionSetError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists the access scopes and authorized targets associated with an application.</p></p>
+    fn list_application_access_scopes(
+        &self,
+        input: ListApplicationAccessScopesRequest,
+    ) -> RusotoFuture<ListApplicationAccessScopesResponse, ListApplicationAccessScopesError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListApplicationAccessScopes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListApplicationAccessScopesResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListApplicationAccessScopesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists Amazon Web Services account users that are assigned to an application.</p></p>
+    fn list_application_assignments(
+        &self,
+        input: ListApplicationAssignmentsRequest,
+    ) -> RusotoFuture<ListApplicationAssignmentsResponse, ListApplicationAssignmentsError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListApplicationAssignments",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListApplicationAssignmentsResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListApplicationAssignmentsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists the applications to which a specified principal is assigned.</p></p>
+    fn list_application_assignments_for_principal(
+        &self,
+        input: ListApplicationAssignmentsForPrincipalRequest,
+    ) -> RusotoFuture<
+        ListApplicationAssignmentsForPrincipalResponse,
+        ListApplicationAssignmentsForPrincipalError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListApplicationAssignmentsForPrincipal",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListApplicationAssignmentsForPrincipalResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListApplicationAssignmentsForPrincipalError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists all of the authentication methods supported by the specified application.</p></p>
+    fn list_application_authentication_methods(
+        &self,
+        input: ListApplicationAuthenticationMethodsRequest,
+    ) -> RusotoFuture<
+        ListApplicationAuthenticationMethodsResponse,
+        ListApplicationAuthenticationMethodsError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListApplicationAuthenticationMethods",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListApplicationAuthenticationMethodsResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListApplicationAuthenticationMethodsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>List the grants associated with an application.</p></p>
+    fn list_application_grants(
+        &self,
+        input: ListApplicationGrantsRequest,
+    ) -> RusotoFuture<ListApplicationGrantsResponse, ListApplicationGrantsError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.ListApplicationGrants");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListApplicationGrantsResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListApplicationGrantsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Lists the application providers configured in the IAM Identity Center identity store.</p></p>
+    fn list_application_providers(
+        &self,
+        input: ListApplicationProvidersRequest,
+    ) -> RusotoFuture<ListApplicationProvidersResponse, ListApplicationProvidersError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListApplicationProviders",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListApplicationProvidersResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListApplicationProvidersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists all applications associated with the instance of IAM Identity Center. When listing applications for an instance in the management account, member accounts must use the <code>applicationAccount</code> parameter to filter the list to only applications created from that account.</p></p>
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.ListApplications");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListApplicationsResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListApplicationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Lists all customer managed policies attached to a specified <a>PermissionSet</a>.</p></p>
+    fn list_customer_managed_policy_references_in_permission_set(
+        &self,
+        input: ListCustomerManagedPolicyReferencesInPermissionSetRequest,
+    ) -> RusotoFuture<
+        ListCustomerManagedPolicyReferencesInPermissionSetResponse,
+        ListCustomerManagedPolicyReferencesInPermissionSetError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListCustomerManagedPolicyReferencesInPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+                        if response.status.is_success() {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response).deserialize::<ListCustomerManagedPolicyReferencesInPermissionSetResponse, _>()
+                }))
+                        } else {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                                Err(ListCustomerManagedPolicyReferencesInPermissionSetError::from_response(response))
+                            }))
+                        }
+                    })
+    }
+
+    /// <p><p>Lists the details of the organization and account instances of IAM Identity Center that were created in or visible to the account calling this API. </p></p>
+    fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> RusotoFuture<ListInstancesResponse, ListInstancesError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.ListInstances");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListInstancesResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListInstancesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Lists the Amazon Web Services managed policy that is attached to a specified permission set.</p></p>
+    fn list_managed_policies_in_permission_set(
+        &self,
+        input: ListManagedPoliciesInPermissionSetRequest,
+    ) -> RusotoFuture<
+        ListManagedPoliciesInPermissionSetResponse,
+        ListManagedPoliciesInPermissionSetError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListManagedPoliciesInPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListManagedPoliciesInPermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListManagedPoliciesInPermissionSetError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists the status of the permission set provisioning requests for a specified IAM Identity Center instance.</p></p>
+    fn list_permission_set_provisioning_status(
+        &self,
+        input: ListPermissionSetProvisioningStatusRequest,
+    ) -> RusotoFuture<
+        ListPermissionSetProvisioningStatusResponse,
+        ListPermissionSetProvisioningStatusError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListPermissionSetProvisioningStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListPermissionSetProvisioningStatusResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListPermissionSetProvisioningStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists the <a>PermissionSet</a>s in an IAM Identity Center instance.</p></p>
+    fn list_permission_sets(
+        &self,
+        input: ListPermissionSetsRequest,
+    ) -> RusotoFuture<ListPermissionSetsResponse, ListPermissionSetsError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.ListPermissionSets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListPermissionSetsResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListPermissionSetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Lists all the permission sets that are provisioned to a specified Amazon Web Services account.</p></p>
+    fn list_permission_sets_provisioned_to_account(
+        &self,
+        input: ListPermissionSetsProvisionedToAccountRequest,
+    ) -> RusotoFuture<
+        ListPermissionSetsProvisionedToAccountResponse,
+        ListPermissionSetsProvisionedToAccountError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.ListPermissionSetsProvisionedToAccount",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListPermissionSetsProvisionedToAccountResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListPermissionSetsProvisionedToAccountError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists the tags that are attached to a specified resource.</p></p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListTagsForResourceResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Lists all the trusted token issuers configured in an instance of IAM Identity Center.</p></p>
+    fn list_trusted_token_issuers(
+        &self,
+        input: ListTrustedTokenIssuersRequest,
+    ) -> RusotoFuture<ListTrustedTokenIssuersResponse, ListTrustedTokenIssuersError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.ListTrustedTokenIssuers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ListTrustedTokenIssuersResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListTrustedTokenIssuersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>The process by which a specified permission set is provisioned to the specified target.</p></p>
+    fn provision_permission_set(
+        &self,
+        input: ProvisionPermissionSetRequest,
+    ) -> RusotoFuture<ProvisionPermissionSetResponse, ProvisionPermissionSetError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.ProvisionPermissionSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<ProvisionPermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ProvisionPermissionSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Adds or updates the list of authorized targets for an IAM Identity Center access scope for an application.</p></p>
+    fn put_application_access_scope(
+        &self,
+        input: PutApplicationAccessScopeRequest,
+    ) -> RusotoFuture<(), PutApplicationAccessScopeError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.PutApplicationAccessScope",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutApplicationAccessScopeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Configure how users gain access to an application. If <code>AssignmentsRequired</code> is <code>true</code> (default value), users don’t have access to the application unless an assignment is created using the <a href="https://docs.aws.amazon.com/singlesignon/latest/APIReference/API_CreateApplicationAssignment.html">CreateApplicationAssignment API</a>. If <code>false</code>, all users have access to the application. If an assignment is created using <a href="https://docs.aws.amazon.com/singlesignon/latest/APIReference/API_CreateApplicationAssignment.html">CreateApplicationAssignment</a>., the user retains access if <code>AssignmentsRequired</code> is set to <code>true</code>. </p></p>
+    fn put_application_assignment_configuration(
+        &self,
+        input: PutApplicationAssignmentConfigurationRequest,
+    ) -> RusotoFuture<
+        PutApplicationAssignmentConfigurationResponse,
+        PutApplicationAssignmentConfigurationError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.PutApplicationAssignmentConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<PutApplicationAssignmentConfigurationResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutApplicationAssignmentConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Adds or updates an authentication method for an application.</p></p>
+    fn put_application_authentication_method(
+        &self,
+        input: PutApplicationAuthenticationMethodRequest,
+    ) -> RusotoFuture<(), PutApplicationAuthenticationMethodError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.PutApplicationAuthenticationMethod",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutApplicationAuthenticationMethodError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Adds a grant to an application.</p></p>
+    fn put_application_grant(
+        &self,
+        input: PutApplicationGrantRequest,
+    ) -> RusotoFuture<(), PutApplicationGrantError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.PutApplicationGrant");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutApplicationGrantError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Attaches an inline policy to a permission set.</p> <note> <p>If the permission set is already referenced by one or more account assignments, you will need to call <code> <a>ProvisionPermissionSet</a> </code> after this action to apply the corresponding IAM policy updates to all assigned accounts.</p> </note></p>
+    fn put_inline_policy_to_permission_set(
+        &self,
+        input: PutInlinePolicyToPermissionSetRequest,
+    ) -> RusotoFuture<PutInlinePolicyToPermissionSetResponse, PutInlinePolicyToPermissionSetError>
+    {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.PutInlinePolicyToPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<PutInlinePolicyToPermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutInlinePolicyToPermissionSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Attaches an Amazon Web Services managed or customer managed policy to the specified <a>PermissionSet</a> as a permissions boundary.</p></p>
+    fn put_permissions_boundary_to_permission_set(
+        &self,
+        input: PutPermissionsBoundaryToPermissionSetRequest,
+    ) -> RusotoFuture<
+        PutPermissionsBoundaryToPermissionSetResponse,
+        PutPermissionsBoundaryToPermissionSetError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.PutPermissionsBoundaryToPermissionSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<PutPermissionsBoundaryToPermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutPermissionsBoundaryToPermissionSetError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Associates a set of tags with a specified resource.</p></p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<TagResourceResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Disassociates a set of tags from a specified resource.</p></p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<UntagResourceResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates application properties. </p></p>
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.UpdateApplication");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<UpdateApplicationResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateApplicationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Update the details for the instance of IAM Identity Center that is owned by the Amazon Web Services account.</p></p>
+    fn update_instance(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> RusotoFuture<UpdateInstanceResponse, UpdateInstanceError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.UpdateInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<UpdateInstanceResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates the IAM Identity Center identity store attributes that you can use with the IAM Identity Center instance for attributes-based access control (ABAC). When using an external identity provider as an identity source, you can pass attributes through the SAML assertion as an alternative to configuring attributes from the IAM Identity Center identity store. If a SAML assertion passes any of these attributes, IAM Identity Center replaces the attribute value with the value from the IAM Identity Center identity store. For more information about ABAC, see <a href="/singlesignon/latest/userguide/abac.html">Attribute-Based Access Control</a> in the <i>IAM Identity Center User Guide</i>.</p></p>
+    fn update_instance_access_control_attribute_configuration(
+        &self,
+        input: UpdateInstanceAccessControlAttributeConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateInstanceAccessControlAttributeConfigurationResponse,
+        UpdateInstanceAccessControlAttributeConfigurationError,
+    > {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.UpdateInstanceAccessControlAttributeConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+                        if response.status.is_success() {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response).deserialize::<UpdateInstanceAccessControlAttributeConfigurationResponse, _>()
+                }))
+                        } else {
+                            Box::new(response.buffer().from_err().and_then(|response| {
+                                Err(UpdateInstanceAccessControlAttributeConfigurationError::from_response(response))
+                            }))
+                        }
+                    })
+    }
+
+    /// <p><p>Updates an existing permission set.</p></p>
+    fn update_permission_set(
+        &self,
+        input: UpdatePermissionSetRequest,
+    ) -> RusotoFuture<UpdatePermissionSetResponse, UpdatePermissionSetError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SWBExternalService.UpdatePermissionSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<UpdatePermissionSetResponse, _>()
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdatePermissionSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates the name of the trusted token issuer, or the path of a source attribute or destination attribute for a trusted token issuer configuration.</p> <note> <p>Updating this trusted token issuer configuration might cause users to lose access to any applications that are configured to use the trusted token issuer.</p> </note></p>
+    fn update_trusted_token_issuer(
+        &self,
+        input: UpdateTrustedTokenIssuerRequest,
+    ) -> RusotoFuture<UpdateTrustedTokenIssuerResponse, UpdateTrustedTokenIssuerError> {
+        let mut request = SignedRequest::new("POST", "sso", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SWBExternalService.UpdateTrustedTokenIssuer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    proto::json::ResponsePayload::new(&response)
+                        .deserialize::<UpdateTrustedTokenIssuerResponse, _>()
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateTrustedTokenIssuerError::from_response(response))
+                }))
+            }
+        })
+    }
+}
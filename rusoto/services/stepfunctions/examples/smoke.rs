@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Step Functions clients.
+//!
+//! Makes a single read-only `ListActivities` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_stepfunctions::{ListActivitiesInput, StepFunctions, StepFunctionsClient};
+
+fn main() {
+    let client = StepFunctionsClient::from_env();
+    match client
+        .list_activities(ListActivitiesInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListActivities succeeded"),
+        Err(error) => {
+            eprintln!("ListActivities failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Contains details about an activity that failed during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -46,7 +47,8 @@ pub struct ActivityListItem {
     pub activity_arn: String,
     /// <p>The date the activity is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date: EpochTimestamp,
     /// <p><p>The name of the activity.</p> <p>A name must <i>not</i> contain:</p> <ul> <li> <p>white space</p> </li> <li> <p>brackets <code>&lt; &gt; { } [ ]</code> </p> </li> <li> <p>wildcard characters <code>? *</code> </p> </li> <li> <p>special characters <code>&quot; # % \ ^ | ~ ` $ &amp; , ; : /</code> </p> </li> <li> <p>control characters (<code>U+0000-001F</code>, <code>U+007F-009F</code>)</p> </li> </ul></p>
     #[serde(rename = "name")]
     pub name: String,
@@ -132,6 +134,44 @@ pub struct CreateActivityInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateActivityInput {
+    /// Returns a builder for `CreateActivityInput`, checking its required members when built.
+    pub fn builder() -> CreateActivityInputBuilder {
+        CreateActivityInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateActivityInput`](struct.CreateActivityInput.html).
+#[derive(Default, Debug)]
+pub struct CreateActivityInputBuilder {
+    name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateActivityInputBuilder {
+    /// Sets the [`name`](struct.CreateActivityInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateActivityInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateActivityInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateActivityInput, ::rusoto_core::BuildError> {
+        Ok(CreateActivityInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateActivityOutput {
@@ -140,7 +180,8 @@ pub struct CreateActivityOutput {
     pub activity_arn: String,
     /// <p>The date the activity is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date: EpochTimestamp,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -160,12 +201,73 @@ pub struct CreateStateMachineInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateStateMachineInput {
+    /// Returns a builder for `CreateStateMachineInput`, checking its required members when built.
+    pub fn builder() -> CreateStateMachineInputBuilder {
+        CreateStateMachineInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStateMachineInput`](struct.CreateStateMachineInput.html).
+#[derive(Default, Debug)]
+pub struct CreateStateMachineInputBuilder {
+    definition: Option<String>,
+    name: Option<String>,
+    role_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateStateMachineInputBuilder {
+    /// Sets the [`definition`](struct.CreateStateMachineInput.html#structfield.definition) member.
+    pub fn definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.definition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateStateMachineInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateStateMachineInput.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateStateMachineInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStateMachineInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateStateMachineInput, ::rusoto_core::BuildError> {
+        Ok(CreateStateMachineInput {
+            definition: self
+                .definition
+                .ok_or(::rusoto_core::BuildError::missing("definition"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateStateMachineOutput {
     /// <p>The date the state machine is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date: EpochTimestamp,
     /// <p>The Amazon Resource Name (ARN) that identifies the created state machine.</p>
     #[serde(rename = "stateMachineArn")]
     pub state_machine_arn: String,
@@ -178,6 +280,36 @@ pub struct DeleteActivityInput {
     pub activity_arn: String,
 }
 
+impl DeleteActivityInput {
+    /// Returns a builder for `DeleteActivityInput`, checking its required members when built.
+    pub fn builder() -> DeleteActivityInputBuilder {
+        DeleteActivityInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteActivityInput`](struct.DeleteActivityInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteActivityInputBuilder {
+    activity_arn: Option<String>,
+}
+
+impl DeleteActivityInputBuilder {
+    /// Sets the [`activity_arn`](struct.DeleteActivityInput.html#structfield.activity_arn) member.
+    pub fn activity_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.activity_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteActivityInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteActivityInput, ::rusoto_core::BuildError> {
+        Ok(DeleteActivityInput {
+            activity_arn: self
+                .activity_arn
+                .ok_or(::rusoto_core::BuildError::missing("activity_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteActivityOutput {}
@@ -189,6 +321,38 @@ pub struct DeleteStateMachineInput {
     pub state_machine_arn: String,
 }
 
+impl DeleteStateMachineInput {
+    /// Returns a builder for `DeleteStateMachineInput`, checking its required members when built.
+    pub fn builder() -> DeleteStateMachineInputBuilder {
+        DeleteStateMachineInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStateMachineInput`](struct.DeleteStateMachineInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteStateMachineInputBuilder {
+    state_machine_arn: Option<String>,
+}
+
+impl DeleteStateMachineInputBuilder {
+    /// Sets the [`state_machine_arn`](struct.DeleteStateMachineInput.html#structfield.state_machine_arn) member.
+    pub fn state_machine_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.state_machine_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStateMachineInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteStateMachineInput, ::rusoto_core::BuildError> {
+        Ok(DeleteStateMachineInput {
+            state_machine_arn: self
+                .state_machine_arn
+                .ok_or(::rusoto_core::BuildError::missing("state_machine_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteStateMachineOutput {}
@@ -200,6 +364,36 @@ pub struct DescribeActivityInput {
     pub activity_arn: String,
 }
 
+impl DescribeActivityInput {
+    /// Returns a builder for `DescribeActivityInput`, checking its required members when built.
+    pub fn builder() -> DescribeActivityInputBuilder {
+        DescribeActivityInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeActivityInput`](struct.DescribeActivityInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeActivityInputBuilder {
+    activity_arn: Option<String>,
+}
+
+impl DescribeActivityInputBuilder {
+    /// Sets the [`activity_arn`](struct.DescribeActivityInput.html#structfield.activity_arn) member.
+    pub fn activity_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.activity_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeActivityInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeActivityInput, ::rusoto_core::BuildError> {
+        Ok(DescribeActivityInput {
+            activity_arn: self
+                .activity_arn
+                .ok_or(::rusoto_core::BuildError::missing("activity_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeActivityOutput {
@@ -208,7 +402,8 @@ pub struct DescribeActivityOutput {
     pub activity_arn: String,
     /// <p>The date the activity is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date: EpochTimestamp,
     /// <p><p>The name of the activity.</p> <p>A name must <i>not</i> contain:</p> <ul> <li> <p>white space</p> </li> <li> <p>brackets <code>&lt; &gt; { } [ ]</code> </p> </li> <li> <p>wildcard characters <code>? *</code> </p> </li> <li> <p>special characters <code>&quot; # % \ ^ | ~ ` $ &amp; , ; : /</code> </p> </li> <li> <p>control characters (<code>U+0000-001F</code>, <code>U+007F-009F</code>)</p> </li> </ul></p>
     #[serde(rename = "name")]
     pub name: String,
@@ -221,7 +416,37 @@ pub struct DescribeExecutionInput {
     pub execution_arn: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DescribeExecutionInput {
+    /// Returns a builder for `DescribeExecutionInput`, checking its required members when built.
+    pub fn builder() -> DescribeExecutionInputBuilder {
+        DescribeExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeExecutionInput`](struct.DescribeExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeExecutionInputBuilder {
+    execution_arn: Option<String>,
+}
+
+impl DescribeExecutionInputBuilder {
+    /// Sets the [`execution_arn`](struct.DescribeExecutionInput.html#structfield.execution_arn) member.
+    pub fn execution_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.execution_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeExecutionInput, ::rusoto_core::BuildError> {
+        Ok(DescribeExecutionInput {
+            execution_arn: self
+                .execution_arn
+                .ok_or(::rusoto_core::BuildError::missing("execution_arn"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeExecutionOutput {
     /// <p>The Amazon Resource Name (ARN) that identifies the execution.</p>
@@ -240,17 +465,19 @@ pub struct DescribeExecutionOutput {
     pub output: Option<String>,
     /// <p>The date the execution is started.</p>
     #[serde(rename = "startDate")]
-    pub start_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_date: EpochTimestamp,
     /// <p>The Amazon Resource Name (ARN) of the executed stated machine.</p>
     #[serde(rename = "stateMachineArn")]
     pub state_machine_arn: String,
     /// <p>The current status of the execution.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: ExecutionStatus,
     /// <p>If the execution has already ended, the date the execution stopped.</p>
     #[serde(rename = "stopDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_date: Option<f64>,
+    pub stop_date: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -260,6 +487,39 @@ pub struct DescribeStateMachineForExecutionInput {
     pub execution_arn: String,
 }
 
+impl DescribeStateMachineForExecutionInput {
+    /// Returns a builder for `DescribeStateMachineForExecutionInput`, checking its required members when built.
+    pub fn builder() -> DescribeStateMachineForExecutionInputBuilder {
+        DescribeStateMachineForExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStateMachineForExecutionInput`](struct.DescribeStateMachineForExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStateMachineForExecutionInputBuilder {
+    execution_arn: Option<String>,
+}
+
+impl DescribeStateMachineForExecutionInputBuilder {
+    /// Sets the [`execution_arn`](struct.DescribeStateMachineForExecutionInput.html#structfield.execution_arn) member.
+    pub fn execution_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.execution_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStateMachineForExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStateMachineForExecutionInput, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeStateMachineForExecutionInput {
+            execution_arn: self
+                .execution_arn
+                .ok_or(::rusoto_core::BuildError::missing("execution_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeStateMachineForExecutionOutput {
@@ -277,7 +537,8 @@ pub struct DescribeStateMachineForExecutionOutput {
     pub state_machine_arn: String,
     /// <p>The date and time the state machine associated with an execution was updated. For a newly created state machine, this is the creation date.</p>
     #[serde(rename = "updateDate")]
-    pub update_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub update_date: EpochTimestamp,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -287,12 +548,45 @@ pub struct DescribeStateMachineInput {
     pub state_machine_arn: String,
 }
 
+impl DescribeStateMachineInput {
+    /// Returns a builder for `DescribeStateMachineInput`, checking its required members when built.
+    pub fn builder() -> DescribeStateMachineInputBuilder {
+        DescribeStateMachineInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStateMachineInput`](struct.DescribeStateMachineInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStateMachineInputBuilder {
+    state_machine_arn: Option<String>,
+}
+
+impl DescribeStateMachineInputBuilder {
+    /// Sets the [`state_machine_arn`](struct.DescribeStateMachineInput.html#structfield.state_machine_arn) member.
+    pub fn state_machine_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.state_machine_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStateMachineInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStateMachineInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStateMachineInput {
+            state_machine_arn: self
+                .state_machine_arn
+                .ok_or(::rusoto_core::BuildError::missing("state_machine_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeStateMachineOutput {
     /// <p>The date the state machine is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date: EpochTimestamp,
     /// <p>The Amazon States Language definition of the state machine. See <a href="https://docs.aws.amazon.com/step-functions/latest/dg/concepts-amazon-states-language.html">Amazon States Language</a>.</p>
     #[serde(rename = "definition")]
     pub definition: String,
@@ -308,7 +602,7 @@ pub struct DescribeStateMachineOutput {
     /// <p>The current status of the state machine.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StateMachineStatus>,
 }
 
 /// <p>Contains details about an abort of an execution.</p>
@@ -340,7 +634,7 @@ pub struct ExecutionFailedEventDetails {
 }
 
 /// <p>Contains details about an execution.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ExecutionListItem {
     /// <p>The Amazon Resource Name (ARN) that identifies the execution.</p>
@@ -351,17 +645,19 @@ pub struct ExecutionListItem {
     pub name: String,
     /// <p>The date the execution started.</p>
     #[serde(rename = "startDate")]
-    pub start_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_date: EpochTimestamp,
     /// <p>The Amazon Resource Name (ARN) of the executed state machine.</p>
     #[serde(rename = "stateMachineArn")]
     pub state_machine_arn: String,
     /// <p>The current status of the execution.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: ExecutionStatus,
     /// <p>If the execution already ended, the date the execution stopped.</p>
     #[serde(rename = "stopDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_date: Option<f64>,
+    pub stop_date: Option<EpochTimestamp>,
 }
 
 /// <p>Contains details about the start of the execution.</p>
@@ -378,6 +674,94 @@ pub struct ExecutionStartedEventDetails {
     pub role_arn: Option<String>,
 }
 
+/// <p>The current status of the execution.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExecutionStatus {
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>TIMED_OUT</code></p>
+    TimedOut,
+    /// <p><code>ABORTED</code></p>
+    Aborted,
+    /// <p><code>PENDING_REDRIVE</code></p>
+    PendingRedrive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExecutionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionStatus::Running => "RUNNING",
+            ExecutionStatus::Succeeded => "SUCCEEDED",
+            ExecutionStatus::Failed => "FAILED",
+            ExecutionStatus::TimedOut => "TIMED_OUT",
+            ExecutionStatus::Aborted => "ABORTED",
+            ExecutionStatus::PendingRedrive => "PENDING_REDRIVE",
+            ExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExecutionStatus {
+    fn from(value: &'a str) -> ExecutionStatus {
+        match value {
+            "RUNNING" => ExecutionStatus::Running,
+            "SUCCEEDED" => ExecutionStatus::Succeeded,
+            "FAILED" => ExecutionStatus::Failed,
+            "TIMED_OUT" => ExecutionStatus::TimedOut,
+            "ABORTED" => ExecutionStatus::Aborted,
+            "PENDING_REDRIVE" => ExecutionStatus::PendingRedrive,
+            _ => ExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionStatus {
+    fn from(value: String) -> ExecutionStatus {
+        ExecutionStatus::from(value.as_str())
+    }
+}
+
+impl From<ExecutionStatus> for String {
+    fn from(value: ExecutionStatus) -> String {
+        match value {
+            ExecutionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExecutionStatus::from(value))
+    }
+}
+
 /// <p>Contains details about the successful termination of the execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -413,6 +797,44 @@ pub struct GetActivityTaskInput {
     pub worker_name: Option<String>,
 }
 
+impl GetActivityTaskInput {
+    /// Returns a builder for `GetActivityTaskInput`, checking its required members when built.
+    pub fn builder() -> GetActivityTaskInputBuilder {
+        GetActivityTaskInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetActivityTaskInput`](struct.GetActivityTaskInput.html).
+#[derive(Default, Debug)]
+pub struct GetActivityTaskInputBuilder {
+    activity_arn: Option<String>,
+    worker_name: Option<String>,
+}
+
+impl GetActivityTaskInputBuilder {
+    /// Sets the [`activity_arn`](struct.GetActivityTaskInput.html#structfield.activity_arn) member.
+    pub fn activity_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.activity_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_name`](struct.GetActivityTaskInput.html#structfield.worker_name) member.
+    pub fn worker_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.worker_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetActivityTaskInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetActivityTaskInput, ::rusoto_core::BuildError> {
+        Ok(GetActivityTaskInput {
+            activity_arn: self
+                .activity_arn
+                .ok_or(::rusoto_core::BuildError::missing("activity_arn"))?,
+            worker_name: self.worker_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetActivityTaskOutput {
@@ -445,6 +867,62 @@ pub struct GetExecutionHistoryInput {
     pub reverse_order: Option<bool>,
 }
 
+impl GetExecutionHistoryInput {
+    /// Returns a builder for `GetExecutionHistoryInput`, checking its required members when built.
+    pub fn builder() -> GetExecutionHistoryInputBuilder {
+        GetExecutionHistoryInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetExecutionHistoryInput`](struct.GetExecutionHistoryInput.html).
+#[derive(Default, Debug)]
+pub struct GetExecutionHistoryInputBuilder {
+    execution_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    reverse_order: Option<bool>,
+}
+
+impl GetExecutionHistoryInputBuilder {
+    /// Sets the [`execution_arn`](struct.GetExecutionHistoryInput.html#structfield.execution_arn) member.
+    pub fn execution_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.execution_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetExecutionHistoryInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetExecutionHistoryInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reverse_order`](struct.GetExecutionHistoryInput.html#structfield.reverse_order) member.
+    pub fn reverse_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reverse_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetExecutionHistoryInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetExecutionHistoryInput, ::rusoto_core::BuildError> {
+        Ok(GetExecutionHistoryInput {
+            execution_arn: self
+                .execution_arn
+                .ok_or(::rusoto_core::BuildError::missing("execution_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            reverse_order: self.reverse_order,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetExecutionHistoryOutput {
@@ -458,7 +936,7 @@ pub struct GetExecutionHistoryOutput {
 }
 
 /// <p>Contains details about the events of an execution.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct HistoryEvent {
     #[serde(rename = "activityFailedEventDetails")]
@@ -583,10 +1061,319 @@ pub struct HistoryEvent {
     pub task_timed_out_event_details: Option<TaskTimedOutEventDetails>,
     /// <p>The date and time the event occurred.</p>
     #[serde(rename = "timestamp")]
-    pub timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub timestamp: EpochTimestamp,
     /// <p>The type of the event.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: HistoryEventType,
+}
+
+/// <p>The type of the event.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HistoryEventType {
+    /// <p><code>ActivityFailed</code></p>
+    ActivityFailed,
+    /// <p><code>ActivityScheduled</code></p>
+    ActivityScheduled,
+    /// <p><code>ActivityScheduleFailed</code></p>
+    ActivityScheduleFailed,
+    /// <p><code>ActivityStarted</code></p>
+    ActivityStarted,
+    /// <p><code>ActivitySucceeded</code></p>
+    ActivitySucceeded,
+    /// <p><code>ActivityTimedOut</code></p>
+    ActivityTimedOut,
+    /// <p><code>ChoiceStateEntered</code></p>
+    ChoiceStateEntered,
+    /// <p><code>ChoiceStateExited</code></p>
+    ChoiceStateExited,
+    /// <p><code>ExecutionAborted</code></p>
+    ExecutionAborted,
+    /// <p><code>ExecutionFailed</code></p>
+    ExecutionFailed,
+    /// <p><code>ExecutionStarted</code></p>
+    ExecutionStarted,
+    /// <p><code>ExecutionSucceeded</code></p>
+    ExecutionSucceeded,
+    /// <p><code>ExecutionTimedOut</code></p>
+    ExecutionTimedOut,
+    /// <p><code>FailStateEntered</code></p>
+    FailStateEntered,
+    /// <p><code>LambdaFunctionFailed</code></p>
+    LambdaFunctionFailed,
+    /// <p><code>LambdaFunctionScheduled</code></p>
+    LambdaFunctionScheduled,
+    /// <p><code>LambdaFunctionScheduleFailed</code></p>
+    LambdaFunctionScheduleFailed,
+    /// <p><code>LambdaFunctionStarted</code></p>
+    LambdaFunctionStarted,
+    /// <p><code>LambdaFunctionStartFailed</code></p>
+    LambdaFunctionStartFailed,
+    /// <p><code>LambdaFunctionSucceeded</code></p>
+    LambdaFunctionSucceeded,
+    /// <p><code>LambdaFunctionTimedOut</code></p>
+    LambdaFunctionTimedOut,
+    /// <p><code>MapIterationAborted</code></p>
+    MapIterationAborted,
+    /// <p><code>MapIterationFailed</code></p>
+    MapIterationFailed,
+    /// <p><code>MapIterationStarted</code></p>
+    MapIterationStarted,
+    /// <p><code>MapIterationSucceeded</code></p>
+    MapIterationSucceeded,
+    /// <p><code>MapStateAborted</code></p>
+    MapStateAborted,
+    /// <p><code>MapStateEntered</code></p>
+    MapStateEntered,
+    /// <p><code>MapStateExited</code></p>
+    MapStateExited,
+    /// <p><code>MapStateFailed</code></p>
+    MapStateFailed,
+    /// <p><code>MapStateStarted</code></p>
+    MapStateStarted,
+    /// <p><code>MapStateSucceeded</code></p>
+    MapStateSucceeded,
+    /// <p><code>ParallelStateAborted</code></p>
+    ParallelStateAborted,
+    /// <p><code>ParallelStateEntered</code></p>
+    ParallelStateEntered,
+    /// <p><code>ParallelStateExited</code></p>
+    ParallelStateExited,
+    /// <p><code>ParallelStateFailed</code></p>
+    ParallelStateFailed,
+    /// <p><code>ParallelStateStarted</code></p>
+    ParallelStateStarted,
+    /// <p><code>ParallelStateSucceeded</code></p>
+    ParallelStateSucceeded,
+    /// <p><code>PassStateEntered</code></p>
+    PassStateEntered,
+    /// <p><code>PassStateExited</code></p>
+    PassStateExited,
+    /// <p><code>SucceedStateEntered</code></p>
+    SucceedStateEntered,
+    /// <p><code>SucceedStateExited</code></p>
+    SucceedStateExited,
+    /// <p><code>TaskFailed</code></p>
+    TaskFailed,
+    /// <p><code>TaskScheduled</code></p>
+    TaskScheduled,
+    /// <p><code>TaskStarted</code></p>
+    TaskStarted,
+    /// <p><code>TaskStartFailed</code></p>
+    TaskStartFailed,
+    /// <p><code>TaskStateAborted</code></p>
+    TaskStateAborted,
+    /// <p><code>TaskStateEntered</code></p>
+    TaskStateEntered,
+    /// <p><code>TaskStateExited</code></p>
+    TaskStateExited,
+    /// <p><code>TaskSubmitFailed</code></p>
+    TaskSubmitFailed,
+    /// <p><code>TaskSubmitted</code></p>
+    TaskSubmitted,
+    /// <p><code>TaskSucceeded</code></p>
+    TaskSucceeded,
+    /// <p><code>TaskTimedOut</code></p>
+    TaskTimedOut,
+    /// <p><code>WaitStateAborted</code></p>
+    WaitStateAborted,
+    /// <p><code>WaitStateEntered</code></p>
+    WaitStateEntered,
+    /// <p><code>WaitStateExited</code></p>
+    WaitStateExited,
+    /// <p><code>MapRunAborted</code></p>
+    MapRunAborted,
+    /// <p><code>MapRunFailed</code></p>
+    MapRunFailed,
+    /// <p><code>MapRunStarted</code></p>
+    MapRunStarted,
+    /// <p><code>MapRunSucceeded</code></p>
+    MapRunSucceeded,
+    /// <p><code>ExecutionRedriven</code></p>
+    ExecutionRedriven,
+    /// <p><code>MapRunRedriven</code></p>
+    MapRunRedriven,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HistoryEventType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HistoryEventType::ActivityFailed => "ActivityFailed",
+            HistoryEventType::ActivityScheduled => "ActivityScheduled",
+            HistoryEventType::ActivityScheduleFailed => "ActivityScheduleFailed",
+            HistoryEventType::ActivityStarted => "ActivityStarted",
+            HistoryEventType::ActivitySucceeded => "ActivitySucceeded",
+            HistoryEventType::ActivityTimedOut => "ActivityTimedOut",
+            HistoryEventType::ChoiceStateEntered => "ChoiceStateEntered",
+            HistoryEventType::ChoiceStateExited => "ChoiceStateExited",
+            HistoryEventType::ExecutionAborted => "ExecutionAborted",
+            HistoryEventType::ExecutionFailed => "ExecutionFailed",
+            HistoryEventType::ExecutionStarted => "ExecutionStarted",
+            HistoryEventType::ExecutionSucceeded => "ExecutionSucceeded",
+            HistoryEventType::ExecutionTimedOut => "ExecutionTimedOut",
+            HistoryEventType::FailStateEntered => "FailStateEntered",
+            HistoryEventType::LambdaFunctionFailed => "LambdaFunctionFailed",
+            HistoryEventType::LambdaFunctionScheduled => "LambdaFunctionScheduled",
+            HistoryEventType::LambdaFunctionScheduleFailed => "LambdaFunctionScheduleFailed",
+            HistoryEventType::LambdaFunctionStarted => "LambdaFunctionStarted",
+            HistoryEventType::LambdaFunctionStartFailed => "LambdaFunctionStartFailed",
+            HistoryEventType::LambdaFunctionSucceeded => "LambdaFunctionSucceeded",
+            HistoryEventType::LambdaFunctionTimedOut => "LambdaFunctionTimedOut",
+            HistoryEventType::MapIterationAborted => "MapIterationAborted",
+            HistoryEventType::MapIterationFailed => "MapIterationFailed",
+            HistoryEventType::MapIterationStarted => "MapIterationStarted",
+            HistoryEventType::MapIterationSucceeded => "MapIterationSucceeded",
+            HistoryEventType::MapStateAborted => "MapStateAborted",
+            HistoryEventType::MapStateEntered => "MapStateEntered",
+            HistoryEventType::MapStateExited => "MapStateExited",
+            HistoryEventType::MapStateFailed => "MapStateFailed",
+            HistoryEventType::MapStateStarted => "MapStateStarted",
+            HistoryEventType::MapStateSucceeded => "MapStateSucceeded",
+            HistoryEventType::ParallelStateAborted => "ParallelStateAborted",
+            HistoryEventType::ParallelStateEntered => "ParallelStateEntered",
+            HistoryEventType::ParallelStateExited => "ParallelStateExited",
+            HistoryEventType::ParallelStateFailed => "ParallelStateFailed",
+            HistoryEventType::ParallelStateStarted => "ParallelStateStarted",
+            HistoryEventType::ParallelStateSucceeded => "ParallelStateSucceeded",
+            HistoryEventType::PassStateEntered => "PassStateEntered",
+            HistoryEventType::PassStateExited => "PassStateExited",
+            HistoryEventType::SucceedStateEntered => "SucceedStateEntered",
+            HistoryEventType::SucceedStateExited => "SucceedStateExited",
+            HistoryEventType::TaskFailed => "TaskFailed",
+            HistoryEventType::TaskScheduled => "TaskScheduled",
+            HistoryEventType::TaskStarted => "TaskStarted",
+            HistoryEventType::TaskStartFailed => "TaskStartFailed",
+            HistoryEventType::TaskStateAborted => "TaskStateAborted",
+            HistoryEventType::TaskStateEntered => "TaskStateEntered",
+            HistoryEventType::TaskStateExited => "TaskStateExited",
+            HistoryEventType::TaskSubmitFailed => "TaskSubmitFailed",
+            HistoryEventType::TaskSubmitted => "TaskSubmitted",
+            HistoryEventType::TaskSucceeded => "TaskSucceeded",
+            HistoryEventType::TaskTimedOut => "TaskTimedOut",
+            HistoryEventType::WaitStateAborted => "WaitStateAborted",
+            HistoryEventType::WaitStateEntered => "WaitStateEntered",
+            HistoryEventType::WaitStateExited => "WaitStateExited",
+            HistoryEventType::MapRunAborted => "MapRunAborted",
+            HistoryEventType::MapRunFailed => "MapRunFailed",
+            HistoryEventType::MapRunStarted => "MapRunStarted",
+            HistoryEventType::MapRunSucceeded => "MapRunSucceeded",
+            HistoryEventType::ExecutionRedriven => "ExecutionRedriven",
+            HistoryEventType::MapRunRedriven => "MapRunRedriven",
+            HistoryEventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HistoryEventType {
+    fn from(value: &'a str) -> HistoryEventType {
+        match value {
+            "ActivityFailed" => HistoryEventType::ActivityFailed,
+            "ActivityScheduled" => HistoryEventType::ActivityScheduled,
+            "ActivityScheduleFailed" => HistoryEventType::ActivityScheduleFailed,
+            "ActivityStarted" => HistoryEventType::ActivityStarted,
+            "ActivitySucceeded" => HistoryEventType::ActivitySucceeded,
+            "ActivityTimedOut" => HistoryEventType::ActivityTimedOut,
+            "ChoiceStateEntered" => HistoryEventType::ChoiceStateEntered,
+            "ChoiceStateExited" => HistoryEventType::ChoiceStateExited,
+            "ExecutionAborted" => HistoryEventType::ExecutionAborted,
+            "ExecutionFailed" => HistoryEventType::ExecutionFailed,
+            "ExecutionStarted" => HistoryEventType::ExecutionStarted,
+            "ExecutionSucceeded" => HistoryEventType::ExecutionSucceeded,
+            "ExecutionTimedOut" => HistoryEventType::ExecutionTimedOut,
+            "FailStateEntered" => HistoryEventType::FailStateEntered,
+            "LambdaFunctionFailed" => HistoryEventType::LambdaFunctionFailed,
+            "LambdaFunctionScheduled" => HistoryEventType::LambdaFunctionScheduled,
+            "LambdaFunctionScheduleFailed" => HistoryEventType::LambdaFunctionScheduleFailed,
+            "LambdaFunctionStarted" => HistoryEventType::LambdaFunctionStarted,
+            "LambdaFunctionStartFailed" => HistoryEventType::LambdaFunctionStartFailed,
+            "LambdaFunctionSucceeded" => HistoryEventType::LambdaFunctionSucceeded,
+            "LambdaFunctionTimedOut" => HistoryEventType::LambdaFunctionTimedOut,
+            "MapIterationAborted" => HistoryEventType::MapIterationAborted,
+            "MapIterationFailed" => HistoryEventType::MapIterationFailed,
+            "MapIterationStarted" => HistoryEventType::MapIterationStarted,
+            "MapIterationSucceeded" => HistoryEventType::MapIterationSucceeded,
+            "MapStateAborted" => HistoryEventType::MapStateAborted,
+            "MapStateEntered" => HistoryEventType::MapStateEntered,
+            "MapStateExited" => HistoryEventType::MapStateExited,
+            "MapStateFailed" => HistoryEventType::MapStateFailed,
+            "MapStateStarted" => HistoryEventType::MapStateStarted,
+            "MapStateSucceeded" => HistoryEventType::MapStateSucceeded,
+            "ParallelStateAborted" => HistoryEventType::ParallelStateAborted,
+            "ParallelStateEntered" => HistoryEventType::ParallelStateEntered,
+            "ParallelStateExited" => HistoryEventType::ParallelStateExited,
+            "ParallelStateFailed" => HistoryEventType::ParallelStateFailed,
+            "ParallelStateStarted" => HistoryEventType::ParallelStateStarted,
+            "ParallelStateSucceeded" => HistoryEventType::ParallelStateSucceeded,
+            "PassStateEntered" => HistoryEventType::PassStateEntered,
+            "PassStateExited" => HistoryEventType::PassStateExited,
+            "SucceedStateEntered" => HistoryEventType::SucceedStateEntered,
+            "SucceedStateExited" => HistoryEventType::SucceedStateExited,
+            "TaskFailed" => HistoryEventType::TaskFailed,
+            "TaskScheduled" => HistoryEventType::TaskScheduled,
+            "TaskStarted" => HistoryEventType::TaskStarted,
+            "TaskStartFailed" => HistoryEventType::TaskStartFailed,
+            "TaskStateAborted" => HistoryEventType::TaskStateAborted,
+            "TaskStateEntered" => HistoryEventType::TaskStateEntered,
+            "TaskStateExited" => HistoryEventType::TaskStateExited,
+            "TaskSubmitFailed" => HistoryEventType::TaskSubmitFailed,
+            "TaskSubmitted" => HistoryEventType::TaskSubmitted,
+            "TaskSucceeded" => HistoryEventType::TaskSucceeded,
+            "TaskTimedOut" => HistoryEventType::TaskTimedOut,
+            "WaitStateAborted" => HistoryEventType::WaitStateAborted,
+            "WaitStateEntered" => HistoryEventType::WaitStateEntered,
+            "WaitStateExited" => HistoryEventType::WaitStateExited,
+            "MapRunAborted" => HistoryEventType::MapRunAborted,
+            "MapRunFailed" => HistoryEventType::MapRunFailed,
+            "MapRunStarted" => HistoryEventType::MapRunStarted,
+            "MapRunSucceeded" => HistoryEventType::MapRunSucceeded,
+            "ExecutionRedriven" => HistoryEventType::ExecutionRedriven,
+            "MapRunRedriven" => HistoryEventType::MapRunRedriven,
+            _ => HistoryEventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HistoryEventType {
+    fn from(value: String) -> HistoryEventType {
+        HistoryEventType::from(value.as_str())
+    }
+}
+
+impl From<HistoryEventType> for String {
+    fn from(value: HistoryEventType) -> String {
+        match value {
+            HistoryEventType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HistoryEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HistoryEventType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HistoryEventType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HistoryEventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HistoryEventType::from(value))
+    }
 }
 
 /// <p>Contains details about a lambda function that failed during an execution.</p>
@@ -684,6 +1471,42 @@ pub struct ListActivitiesInput {
     pub next_token: Option<String>,
 }
 
+impl ListActivitiesInput {
+    /// Returns a builder for `ListActivitiesInput`, checking its required members when built.
+    pub fn builder() -> ListActivitiesInputBuilder {
+        ListActivitiesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListActivitiesInput`](struct.ListActivitiesInput.html).
+#[derive(Default, Debug)]
+pub struct ListActivitiesInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListActivitiesInputBuilder {
+    /// Sets the [`max_results`](struct.ListActivitiesInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListActivitiesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListActivitiesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListActivitiesInput, ::rusoto_core::BuildError> {
+        Ok(ListActivitiesInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListActivitiesOutput {
@@ -712,7 +1535,61 @@ pub struct ListExecutionsInput {
     /// <p>If specified, only list the executions whose current execution status matches the given filter.</p>
     #[serde(rename = "statusFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_filter: Option<String>,
+    pub status_filter: Option<ExecutionStatus>,
+}
+
+impl ListExecutionsInput {
+    /// Returns a builder for `ListExecutionsInput`, checking its required members when built.
+    pub fn builder() -> ListExecutionsInputBuilder {
+        ListExecutionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListExecutionsInput`](struct.ListExecutionsInput.html).
+#[derive(Default, Debug)]
+pub struct ListExecutionsInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    state_machine_arn: Option<String>,
+    status_filter: Option<ExecutionStatus>,
+}
+
+impl ListExecutionsInputBuilder {
+    /// Sets the [`max_results`](struct.ListExecutionsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListExecutionsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state_machine_arn`](struct.ListExecutionsInput.html#structfield.state_machine_arn) member.
+    pub fn state_machine_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.state_machine_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_filter`](struct.ListExecutionsInput.html#structfield.status_filter) member.
+    pub fn status_filter<T: Into<ExecutionStatus>>(mut self, value: T) -> Self {
+        self.status_filter = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListExecutionsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListExecutionsInput, ::rusoto_core::BuildError> {
+        Ok(ListExecutionsInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            state_machine_arn: self
+                .state_machine_arn
+                .ok_or(::rusoto_core::BuildError::missing("state_machine_arn"))?,
+            status_filter: self.status_filter,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -739,6 +1616,42 @@ pub struct ListStateMachinesInput {
     pub next_token: Option<String>,
 }
 
+impl ListStateMachinesInput {
+    /// Returns a builder for `ListStateMachinesInput`, checking its required members when built.
+    pub fn builder() -> ListStateMachinesInputBuilder {
+        ListStateMachinesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStateMachinesInput`](struct.ListStateMachinesInput.html).
+#[derive(Default, Debug)]
+pub struct ListStateMachinesInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListStateMachinesInputBuilder {
+    /// Sets the [`max_results`](struct.ListStateMachinesInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListStateMachinesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStateMachinesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListStateMachinesInput, ::rusoto_core::BuildError> {
+        Ok(ListStateMachinesInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListStateMachinesOutput {
@@ -757,6 +1670,38 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceInput {
+    /// Returns a builder for `ListTagsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceInputBuilder {
+        ListTagsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceInput`](struct.ListTagsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceInputBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceOutput {
@@ -805,6 +1750,52 @@ pub struct SendTaskFailureInput {
     pub task_token: String,
 }
 
+impl SendTaskFailureInput {
+    /// Returns a builder for `SendTaskFailureInput`, checking its required members when built.
+    pub fn builder() -> SendTaskFailureInputBuilder {
+        SendTaskFailureInputBuilder::default()
+    }
+}
+
+/// Builds a [`SendTaskFailureInput`](struct.SendTaskFailureInput.html).
+#[derive(Default, Debug)]
+pub struct SendTaskFailureInputBuilder {
+    cause: Option<String>,
+    error: Option<String>,
+    task_token: Option<String>,
+}
+
+impl SendTaskFailureInputBuilder {
+    /// Sets the [`cause`](struct.SendTaskFailureInput.html#structfield.cause) member.
+    pub fn cause<T: Into<String>>(mut self, value: T) -> Self {
+        self.cause = Some(value.into());
+        self
+    }
+
+    /// Sets the [`error`](struct.SendTaskFailureInput.html#structfield.error) member.
+    pub fn error<T: Into<String>>(mut self, value: T) -> Self {
+        self.error = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_token`](struct.SendTaskFailureInput.html#structfield.task_token) member.
+    pub fn task_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendTaskFailureInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SendTaskFailureInput, ::rusoto_core::BuildError> {
+        Ok(SendTaskFailureInput {
+            cause: self.cause,
+            error: self.error,
+            task_token: self
+                .task_token
+                .ok_or(::rusoto_core::BuildError::missing("task_token"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SendTaskFailureOutput {}
@@ -816,18 +1807,88 @@ pub struct SendTaskHeartbeatInput {
     pub task_token: String,
 }
 
+impl SendTaskHeartbeatInput {
+    /// Returns a builder for `SendTaskHeartbeatInput`, checking its required members when built.
+    pub fn builder() -> SendTaskHeartbeatInputBuilder {
+        SendTaskHeartbeatInputBuilder::default()
+    }
+}
+
+/// Builds a [`SendTaskHeartbeatInput`](struct.SendTaskHeartbeatInput.html).
+#[derive(Default, Debug)]
+pub struct SendTaskHeartbeatInputBuilder {
+    task_token: Option<String>,
+}
+
+impl SendTaskHeartbeatInputBuilder {
+    /// Sets the [`task_token`](struct.SendTaskHeartbeatInput.html#structfield.task_token) member.
+    pub fn task_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendTaskHeartbeatInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SendTaskHeartbeatInput, ::rusoto_core::BuildError> {
+        Ok(SendTaskHeartbeatInput {
+            task_token: self
+                .task_token
+                .ok_or(::rusoto_core::BuildError::missing("task_token"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SendTaskHeartbeatOutput {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct SendTaskSuccessInput {
-    /// <p>The JSON output of the task.</p>
-    #[serde(rename = "output")]
-    pub output: String,
-    /// <p>The token that represents this task. Task tokens are generated by Step Functions when tasks are assigned to a worker, or in the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/input-output-contextobject.html">context object</a> when a workflow enters a task state. See <a>GetActivityTaskOutput$taskToken</a>.</p>
-    #[serde(rename = "taskToken")]
-    pub task_token: String,
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct SendTaskSuccessInput {
+    /// <p>The JSON output of the task.</p>
+    #[serde(rename = "output")]
+    pub output: String,
+    /// <p>The token that represents this task. Task tokens are generated by Step Functions when tasks are assigned to a worker, or in the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/input-output-contextobject.html">context object</a> when a workflow enters a task state. See <a>GetActivityTaskOutput$taskToken</a>.</p>
+    #[serde(rename = "taskToken")]
+    pub task_token: String,
+}
+
+impl SendTaskSuccessInput {
+    /// Returns a builder for `SendTaskSuccessInput`, checking its required members when built.
+    pub fn builder() -> SendTaskSuccessInputBuilder {
+        SendTaskSuccessInputBuilder::default()
+    }
+}
+
+/// Builds a [`SendTaskSuccessInput`](struct.SendTaskSuccessInput.html).
+#[derive(Default, Debug)]
+pub struct SendTaskSuccessInputBuilder {
+    output: Option<String>,
+    task_token: Option<String>,
+}
+
+impl SendTaskSuccessInputBuilder {
+    /// Sets the [`output`](struct.SendTaskSuccessInput.html#structfield.output) member.
+    pub fn output<T: Into<String>>(mut self, value: T) -> Self {
+        self.output = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_token`](struct.SendTaskSuccessInput.html#structfield.task_token) member.
+    pub fn task_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendTaskSuccessInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SendTaskSuccessInput, ::rusoto_core::BuildError> {
+        Ok(SendTaskSuccessInput {
+            output: self
+                .output
+                .ok_or(::rusoto_core::BuildError::missing("output"))?,
+            task_token: self
+                .task_token
+                .ok_or(::rusoto_core::BuildError::missing("task_token"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -849,6 +1910,52 @@ pub struct StartExecutionInput {
     pub state_machine_arn: String,
 }
 
+impl StartExecutionInput {
+    /// Returns a builder for `StartExecutionInput`, checking its required members when built.
+    pub fn builder() -> StartExecutionInputBuilder {
+        StartExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`StartExecutionInput`](struct.StartExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct StartExecutionInputBuilder {
+    input: Option<String>,
+    name: Option<String>,
+    state_machine_arn: Option<String>,
+}
+
+impl StartExecutionInputBuilder {
+    /// Sets the [`input`](struct.StartExecutionInput.html#structfield.input) member.
+    pub fn input<T: Into<String>>(mut self, value: T) -> Self {
+        self.input = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.StartExecutionInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state_machine_arn`](struct.StartExecutionInput.html#structfield.state_machine_arn) member.
+    pub fn state_machine_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.state_machine_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartExecutionInput, ::rusoto_core::BuildError> {
+        Ok(StartExecutionInput {
+            input: self.input,
+            name: self.name,
+            state_machine_arn: self
+                .state_machine_arn
+                .ok_or(::rusoto_core::BuildError::missing("state_machine_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartExecutionOutput {
@@ -857,7 +1964,8 @@ pub struct StartExecutionOutput {
     pub execution_arn: String,
     /// <p>The date the execution is started.</p>
     #[serde(rename = "startDate")]
-    pub start_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_date: EpochTimestamp,
 }
 
 /// <p>Contains details about a state entered during an execution.</p>
@@ -892,7 +2000,8 @@ pub struct StateExitedEventDetails {
 pub struct StateMachineListItem {
     /// <p>The date the state machine is created.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date: EpochTimestamp,
     /// <p><p>The name of the state machine.</p> <p>A name must <i>not</i> contain:</p> <ul> <li> <p>white space</p> </li> <li> <p>brackets <code>&lt; &gt; { } [ ]</code> </p> </li> <li> <p>wildcard characters <code>? *</code> </p> </li> <li> <p>special characters <code>&quot; # % \ ^ | ~ ` $ &amp; , ; : /</code> </p> </li> <li> <p>control characters (<code>U+0000-001F</code>, <code>U+007F-009F</code>)</p> </li> </ul></p>
     #[serde(rename = "name")]
     pub name: String,
@@ -901,6 +2010,78 @@ pub struct StateMachineListItem {
     pub state_machine_arn: String,
 }
 
+/// <p>The current status of the state machine.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StateMachineStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StateMachineStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StateMachineStatus::Active => "ACTIVE",
+            StateMachineStatus::Deleting => "DELETING",
+            StateMachineStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StateMachineStatus {
+    fn from(value: &'a str) -> StateMachineStatus {
+        match value {
+            "ACTIVE" => StateMachineStatus::Active,
+            "DELETING" => StateMachineStatus::Deleting,
+            _ => StateMachineStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StateMachineStatus {
+    fn from(value: String) -> StateMachineStatus {
+        StateMachineStatus::from(value.as_str())
+    }
+}
+
+impl From<StateMachineStatus> for String {
+    fn from(value: StateMachineStatus) -> String {
+        match value {
+            StateMachineStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StateMachineStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StateMachineStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StateMachineStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StateMachineStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StateMachineStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StopExecutionInput {
     /// <p>A more detailed explanation of the cause of the failure.</p>
@@ -916,12 +2097,59 @@ pub struct StopExecutionInput {
     pub execution_arn: String,
 }
 
+impl StopExecutionInput {
+    /// Returns a builder for `StopExecutionInput`, checking its required members when built.
+    pub fn builder() -> StopExecutionInputBuilder {
+        StopExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`StopExecutionInput`](struct.StopExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct StopExecutionInputBuilder {
+    cause: Option<String>,
+    error: Option<String>,
+    execution_arn: Option<String>,
+}
+
+impl StopExecutionInputBuilder {
+    /// Sets the [`cause`](struct.StopExecutionInput.html#structfield.cause) member.
+    pub fn cause<T: Into<String>>(mut self, value: T) -> Self {
+        self.cause = Some(value.into());
+        self
+    }
+
+    /// Sets the [`error`](struct.StopExecutionInput.html#structfield.error) member.
+    pub fn error<T: Into<String>>(mut self, value: T) -> Self {
+        self.error = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_arn`](struct.StopExecutionInput.html#structfield.execution_arn) member.
+    pub fn execution_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.execution_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopExecutionInput, ::rusoto_core::BuildError> {
+        Ok(StopExecutionInput {
+            cause: self.cause,
+            error: self.error,
+            execution_arn: self
+                .execution_arn
+                .ok_or(::rusoto_core::BuildError::missing("execution_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopExecutionOutput {
     /// <p>The date the execution is stopped.</p>
     #[serde(rename = "stopDate")]
-    pub stop_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub stop_date: EpochTimestamp,
 }
 
 /// <p>Tags are key-value pairs that can be associated with Step Functions state machines and activities.</p> <p>An array of key-value pairs. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>, and <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_iam-tags.html">Controlling Access Using IAM Tags</a>.</p> <p>Tags may only contain Unicode letters, digits, white space, or these symbols: <code>_ . : / = + - @</code>.</p>
@@ -947,6 +2175,46 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceInput {
+    /// Returns a builder for `TagResourceInput`, checking its required members when built.
+    pub fn builder() -> TagResourceInputBuilder {
+        TagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceInput`](struct.TagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceInput, ::rusoto_core::BuildError> {
+        Ok(TagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceOutput {}
@@ -1107,6 +2375,46 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceInput {
+    /// Returns a builder for `UntagResourceInput`, checking its required members when built.
+    pub fn builder() -> UntagResourceInputBuilder {
+        UntagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceInput`](struct.UntagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceOutput {}
@@ -1126,12 +2434,61 @@ pub struct UpdateStateMachineInput {
     pub state_machine_arn: String,
 }
 
+impl UpdateStateMachineInput {
+    /// Returns a builder for `UpdateStateMachineInput`, checking its required members when built.
+    pub fn builder() -> UpdateStateMachineInputBuilder {
+        UpdateStateMachineInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateStateMachineInput`](struct.UpdateStateMachineInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateStateMachineInputBuilder {
+    definition: Option<String>,
+    role_arn: Option<String>,
+    state_machine_arn: Option<String>,
+}
+
+impl UpdateStateMachineInputBuilder {
+    /// Sets the [`definition`](struct.UpdateStateMachineInput.html#structfield.definition) member.
+    pub fn definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.definition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.UpdateStateMachineInput.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state_machine_arn`](struct.UpdateStateMachineInput.html#structfield.state_machine_arn) member.
+    pub fn state_machine_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.state_machine_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateStateMachineInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateStateMachineInput, ::rusoto_core::BuildError> {
+        Ok(UpdateStateMachineInput {
+            definition: self.definition,
+            role_arn: self.role_arn,
+            state_machine_arn: self
+                .state_machine_arn
+                .ok_or(::rusoto_core::BuildError::missing("state_machine_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateStateMachineOutput {
     /// <p>The date and time the state machine was updated.</p>
     #[serde(rename = "updateDate")]
-    pub update_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub update_date: EpochTimestamp,
 }
 
 /// Errors returned by CreateActivity
@@ -2280,6 +3637,148 @@ pub trait StepFunctions {
         input: UpdateStateMachineInput,
     ) -> RusotoFuture<UpdateStateMachineOutput, UpdateStateMachineError>;
 }
+impl<T: ?Sized + StepFunctions> StepFunctions for ::std::sync::Arc<T> {
+    fn create_activity(
+        &self,
+        input: CreateActivityInput,
+    ) -> RusotoFuture<CreateActivityOutput, CreateActivityError> {
+        (**self).create_activity(input)
+    }
+    fn create_state_machine(
+        &self,
+        input: CreateStateMachineInput,
+    ) -> RusotoFuture<CreateStateMachineOutput, CreateStateMachineError> {
+        (**self).create_state_machine(input)
+    }
+    fn delete_activity(
+        &self,
+        input: DeleteActivityInput,
+    ) -> RusotoFuture<DeleteActivityOutput, DeleteActivityError> {
+        (**self).delete_activity(input)
+    }
+    fn delete_state_machine(
+        &self,
+        input: DeleteStateMachineInput,
+    ) -> RusotoFuture<DeleteStateMachineOutput, DeleteStateMachineError> {
+        (**self).delete_state_machine(input)
+    }
+    fn describe_activity(
+        &self,
+        input: DescribeActivityInput,
+    ) -> RusotoFuture<DescribeActivityOutput, DescribeActivityError> {
+        (**self).describe_activity(input)
+    }
+    fn describe_execution(
+        &self,
+        input: DescribeExecutionInput,
+    ) -> RusotoFuture<DescribeExecutionOutput, DescribeExecutionError> {
+        (**self).describe_execution(input)
+    }
+    fn describe_state_machine(
+        &self,
+        input: DescribeStateMachineInput,
+    ) -> RusotoFuture<DescribeStateMachineOutput, DescribeStateMachineError> {
+        (**self).describe_state_machine(input)
+    }
+    fn describe_state_machine_for_execution(
+        &self,
+        input: DescribeStateMachineForExecutionInput,
+    ) -> RusotoFuture<DescribeStateMachineForExecutionOutput, DescribeStateMachineForExecutionError>
+    {
+        (**self).describe_state_machine_for_execution(input)
+    }
+    fn get_activity_task(
+        &self,
+        input: GetActivityTaskInput,
+    ) -> RusotoFuture<GetActivityTaskOutput, GetActivityTaskError> {
+        (**self).get_activity_task(input)
+    }
+    fn get_execution_history(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> RusotoFuture<GetExecutionHistoryOutput, GetExecutionHistoryError> {
+        (**self).get_execution_history(input)
+    }
+    fn list_activities(
+        &self,
+        input: ListActivitiesInput,
+    ) -> RusotoFuture<ListActivitiesOutput, ListActivitiesError> {
+        (**self).list_activities(input)
+    }
+    fn list_executions(
+        &self,
+        input: ListExecutionsInput,
+    ) -> RusotoFuture<ListExecutionsOutput, ListExecutionsError> {
+        (**self).list_executions(input)
+    }
+    fn list_state_machines(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> RusotoFuture<ListStateMachinesOutput, ListStateMachinesError> {
+        (**self).list_state_machines(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn send_task_failure(
+        &self,
+        input: SendTaskFailureInput,
+    ) -> RusotoFuture<SendTaskFailureOutput, SendTaskFailureError> {
+        (**self).send_task_failure(input)
+    }
+    fn send_task_heartbeat(
+        &self,
+        input: SendTaskHeartbeatInput,
+    ) -> RusotoFuture<SendTaskHeartbeatOutput, SendTaskHeartbeatError> {
+        (**self).send_task_heartbeat(input)
+    }
+    fn send_task_success(
+        &self,
+        input: SendTaskSuccessInput,
+    ) -> RusotoFuture<SendTaskSuccessOutput, SendTaskSuccessError> {
+        (**self).send_task_success(input)
+    }
+    fn start_execution(
+        &self,
+        input: StartExecutionInput,
+    ) -> RusotoFuture<StartExecutionOutput, StartExecutionError> {
+        (**self).start_execution(input)
+    }
+    fn stop_execution(
+        &self,
+        input: StopExecutionInput,
+    ) -> RusotoFuture<StopExecutionOutput, StopExecutionError> {
+        (**self).stop_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_state_machine(
+        &self,
+        input: UpdateStateMachineInput,
+    ) -> RusotoFuture<UpdateStateMachineOutput, UpdateStateMachineError> {
+        (**self).update_state_machine(input)
+    }
+}
+/// The error codes AWS SFN retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS SFN API.
 #[derive(Clone)]
 pub struct StepFunctionsClient {
@@ -2295,6 +3794,27 @@ impl StepFunctionsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_STEPFUNCTIONS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> StepFunctionsClient {
+        Self::new(region::Region::from_env_for_service("STEPFUNCTIONS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `states`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> StepFunctionsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("states", "STEPFUNCTIONS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2313,7 +3833,54 @@ impl StepFunctionsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> StepFunctionsClient {
-        StepFunctionsClient { client, region }
+        StepFunctionsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "states")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "states",
+            "states",
+            "STEPFUNCTIONS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `StepFunctions` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn StepFunctions + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for StepFunctionsClient {
+    fn service_name(&self) -> &'static str {
+        "AWS SFN"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "states"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> StepFunctionsClient {
+        Self::new_with_client(client, region)
     }
 }
 
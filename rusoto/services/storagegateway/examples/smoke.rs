@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Storage Gateway clients.
+//!
+//! Makes a single read-only `ListFileShares` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_storagegateway::{ListFileSharesInput, StorageGateway, StorageGatewayClient};
+
+fn main() {
+    let client = StorageGatewayClient::from_env();
+    match client
+        .list_file_shares(ListFileSharesInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListFileShares succeeded"),
+        Err(error) => {
+            eprintln!("ListFileShares failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
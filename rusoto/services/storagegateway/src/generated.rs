@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p><p>A JSON object containing one or more of the following fields:</p> <ul> <li> <p> <a>ActivateGatewayInput$ActivationKey</a> </p> </li> <li> <p> <a>ActivateGatewayInput$GatewayName</a> </p> </li> <li> <p> <a>ActivateGatewayInput$GatewayRegion</a> </p> </li> <li> <p> <a>ActivateGatewayInput$GatewayTimezone</a> </p> </li> <li> <p> <a>ActivateGatewayInput$GatewayType</a> </p> </li> <li> <p> <a>ActivateGatewayInput$TapeDriveType</a> </p> </li> <li> <p> <a>ActivateGatewayInput$MediumChangerType</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -56,6 +57,98 @@ pub struct ActivateGatewayInput {
     pub tape_drive_type: Option<String>,
 }
 
+impl ActivateGatewayInput {
+    /// Returns a builder for `ActivateGatewayInput`, checking its required members when built.
+    pub fn builder() -> ActivateGatewayInputBuilder {
+        ActivateGatewayInputBuilder::default()
+    }
+}
+
+/// Builds a [`ActivateGatewayInput`](struct.ActivateGatewayInput.html).
+#[derive(Default, Debug)]
+pub struct ActivateGatewayInputBuilder {
+    activation_key: Option<String>,
+    gateway_name: Option<String>,
+    gateway_region: Option<String>,
+    gateway_timezone: Option<String>,
+    gateway_type: Option<String>,
+    medium_changer_type: Option<String>,
+    tags: Option<Vec<Tag>>,
+    tape_drive_type: Option<String>,
+}
+
+impl ActivateGatewayInputBuilder {
+    /// Sets the [`activation_key`](struct.ActivateGatewayInput.html#structfield.activation_key) member.
+    pub fn activation_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.activation_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_name`](struct.ActivateGatewayInput.html#structfield.gateway_name) member.
+    pub fn gateway_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_region`](struct.ActivateGatewayInput.html#structfield.gateway_region) member.
+    pub fn gateway_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_timezone`](struct.ActivateGatewayInput.html#structfield.gateway_timezone) member.
+    pub fn gateway_timezone<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_timezone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_type`](struct.ActivateGatewayInput.html#structfield.gateway_type) member.
+    pub fn gateway_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`medium_changer_type`](struct.ActivateGatewayInput.html#structfield.medium_changer_type) member.
+    pub fn medium_changer_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.medium_changer_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.ActivateGatewayInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_drive_type`](struct.ActivateGatewayInput.html#structfield.tape_drive_type) member.
+    pub fn tape_drive_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.tape_drive_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ActivateGatewayInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ActivateGatewayInput, ::rusoto_core::BuildError> {
+        Ok(ActivateGatewayInput {
+            activation_key: self
+                .activation_key
+                .ok_or(::rusoto_core::BuildError::missing("activation_key"))?,
+            gateway_name: self
+                .gateway_name
+                .ok_or(::rusoto_core::BuildError::missing("gateway_name"))?,
+            gateway_region: self
+                .gateway_region
+                .ok_or(::rusoto_core::BuildError::missing("gateway_region"))?,
+            gateway_timezone: self
+                .gateway_timezone
+                .ok_or(::rusoto_core::BuildError::missing("gateway_timezone"))?,
+            gateway_type: self.gateway_type,
+            medium_changer_type: self.medium_changer_type,
+            tags: self.tags,
+            tape_drive_type: self.tape_drive_type,
+        })
+    }
+}
+
 /// <p><p>AWS Storage Gateway returns the Amazon Resource Name (ARN) of the activated gateway. It is a string made of information such as your account, gateway name, and AWS Region. This ARN is used to reference the gateway in other API operations as well as resource-based authorization.</p> <note> <p>For gateways activated prior to September 02, 2015, the gateway ARN contains the gateway name rather than the gateway ID. Changing the name of the gateway has no effect on the gateway ARN.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -74,6 +167,46 @@ pub struct AddCacheInput {
     pub gateway_arn: String,
 }
 
+impl AddCacheInput {
+    /// Returns a builder for `AddCacheInput`, checking its required members when built.
+    pub fn builder() -> AddCacheInputBuilder {
+        AddCacheInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddCacheInput`](struct.AddCacheInput.html).
+#[derive(Default, Debug)]
+pub struct AddCacheInputBuilder {
+    disk_ids: Option<Vec<String>>,
+    gateway_arn: Option<String>,
+}
+
+impl AddCacheInputBuilder {
+    /// Sets the [`disk_ids`](struct.AddCacheInput.html#structfield.disk_ids) member.
+    pub fn disk_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.disk_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.AddCacheInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddCacheInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddCacheInput, ::rusoto_core::BuildError> {
+        Ok(AddCacheInput {
+            disk_ids: self
+                .disk_ids
+                .ok_or(::rusoto_core::BuildError::missing("disk_ids"))?,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddCacheOutput {
@@ -93,6 +226,46 @@ pub struct AddTagsToResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsToResourceInput {
+    /// Returns a builder for `AddTagsToResourceInput`, checking its required members when built.
+    pub fn builder() -> AddTagsToResourceInputBuilder {
+        AddTagsToResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToResourceInput`](struct.AddTagsToResourceInput.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToResourceInputBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsToResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.AddTagsToResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsToResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddTagsToResourceInput, ::rusoto_core::BuildError> {
+        Ok(AddTagsToResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>AddTagsToResourceOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -112,6 +285,46 @@ pub struct AddUploadBufferInput {
     pub gateway_arn: String,
 }
 
+impl AddUploadBufferInput {
+    /// Returns a builder for `AddUploadBufferInput`, checking its required members when built.
+    pub fn builder() -> AddUploadBufferInputBuilder {
+        AddUploadBufferInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddUploadBufferInput`](struct.AddUploadBufferInput.html).
+#[derive(Default, Debug)]
+pub struct AddUploadBufferInputBuilder {
+    disk_ids: Option<Vec<String>>,
+    gateway_arn: Option<String>,
+}
+
+impl AddUploadBufferInputBuilder {
+    /// Sets the [`disk_ids`](struct.AddUploadBufferInput.html#structfield.disk_ids) member.
+    pub fn disk_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.disk_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.AddUploadBufferInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddUploadBufferInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddUploadBufferInput, ::rusoto_core::BuildError> {
+        Ok(AddUploadBufferInput {
+            disk_ids: self
+                .disk_ids
+                .ok_or(::rusoto_core::BuildError::missing("disk_ids"))?,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddUploadBufferOutput {
@@ -130,6 +343,46 @@ pub struct AddWorkingStorageInput {
     pub gateway_arn: String,
 }
 
+impl AddWorkingStorageInput {
+    /// Returns a builder for `AddWorkingStorageInput`, checking its required members when built.
+    pub fn builder() -> AddWorkingStorageInputBuilder {
+        AddWorkingStorageInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddWorkingStorageInput`](struct.AddWorkingStorageInput.html).
+#[derive(Default, Debug)]
+pub struct AddWorkingStorageInputBuilder {
+    disk_ids: Option<Vec<String>>,
+    gateway_arn: Option<String>,
+}
+
+impl AddWorkingStorageInputBuilder {
+    /// Sets the [`disk_ids`](struct.AddWorkingStorageInput.html#structfield.disk_ids) member.
+    pub fn disk_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.disk_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.AddWorkingStorageInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddWorkingStorageInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddWorkingStorageInput, ::rusoto_core::BuildError> {
+        Ok(AddWorkingStorageInput {
+            disk_ids: self
+                .disk_ids
+                .ok_or(::rusoto_core::BuildError::missing("disk_ids"))?,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the of the gateway for which working storage was configured.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -149,6 +402,46 @@ pub struct AssignTapePoolInput {
     pub tape_arn: String,
 }
 
+impl AssignTapePoolInput {
+    /// Returns a builder for `AssignTapePoolInput`, checking its required members when built.
+    pub fn builder() -> AssignTapePoolInputBuilder {
+        AssignTapePoolInputBuilder::default()
+    }
+}
+
+/// Builds a [`AssignTapePoolInput`](struct.AssignTapePoolInput.html).
+#[derive(Default, Debug)]
+pub struct AssignTapePoolInputBuilder {
+    pool_id: Option<String>,
+    tape_arn: Option<String>,
+}
+
+impl AssignTapePoolInputBuilder {
+    /// Sets the [`pool_id`](struct.AssignTapePoolInput.html#structfield.pool_id) member.
+    pub fn pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_arn`](struct.AssignTapePoolInput.html#structfield.tape_arn) member.
+    pub fn tape_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tape_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssignTapePoolInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AssignTapePoolInput, ::rusoto_core::BuildError> {
+        Ok(AssignTapePoolInput {
+            pool_id: self
+                .pool_id
+                .ok_or(::rusoto_core::BuildError::missing("pool_id"))?,
+            tape_arn: self
+                .tape_arn
+                .ok_or(::rusoto_core::BuildError::missing("tape_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssignTapePoolOutput {
@@ -180,6 +473,72 @@ pub struct AttachVolumeInput {
     pub volume_arn: String,
 }
 
+impl AttachVolumeInput {
+    /// Returns a builder for `AttachVolumeInput`, checking its required members when built.
+    pub fn builder() -> AttachVolumeInputBuilder {
+        AttachVolumeInputBuilder::default()
+    }
+}
+
+/// Builds a [`AttachVolumeInput`](struct.AttachVolumeInput.html).
+#[derive(Default, Debug)]
+pub struct AttachVolumeInputBuilder {
+    disk_id: Option<String>,
+    gateway_arn: Option<String>,
+    network_interface_id: Option<String>,
+    target_name: Option<String>,
+    volume_arn: Option<String>,
+}
+
+impl AttachVolumeInputBuilder {
+    /// Sets the [`disk_id`](struct.AttachVolumeInput.html#structfield.disk_id) member.
+    pub fn disk_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.AttachVolumeInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_interface_id`](struct.AttachVolumeInput.html#structfield.network_interface_id) member.
+    pub fn network_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.network_interface_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_name`](struct.AttachVolumeInput.html#structfield.target_name) member.
+    pub fn target_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_arn`](struct.AttachVolumeInput.html#structfield.volume_arn) member.
+    pub fn volume_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachVolumeInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AttachVolumeInput, ::rusoto_core::BuildError> {
+        Ok(AttachVolumeInput {
+            disk_id: self.disk_id,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            network_interface_id: self
+                .network_interface_id
+                .ok_or(::rusoto_core::BuildError::missing("network_interface_id"))?,
+            target_name: self.target_name,
+            volume_arn: self
+                .volume_arn
+                .ok_or(::rusoto_core::BuildError::missing("volume_arn"))?,
+        })
+    }
+}
+
 /// <p>AttachVolumeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -200,8 +559,9 @@ pub struct AttachVolumeOutput {
 pub struct CachediSCSIVolume {
     /// <p>The date the volume was created. Volumes created prior to March 28, 2017 don’t have this time stamp.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     #[serde(rename = "KMSKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key: Option<String>,
@@ -261,6 +621,46 @@ pub struct CancelArchivalInput {
     pub tape_arn: String,
 }
 
+impl CancelArchivalInput {
+    /// Returns a builder for `CancelArchivalInput`, checking its required members when built.
+    pub fn builder() -> CancelArchivalInputBuilder {
+        CancelArchivalInputBuilder::default()
+    }
+}
+
+/// Builds a [`CancelArchivalInput`](struct.CancelArchivalInput.html).
+#[derive(Default, Debug)]
+pub struct CancelArchivalInputBuilder {
+    gateway_arn: Option<String>,
+    tape_arn: Option<String>,
+}
+
+impl CancelArchivalInputBuilder {
+    /// Sets the [`gateway_arn`](struct.CancelArchivalInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_arn`](struct.CancelArchivalInput.html#structfield.tape_arn) member.
+    pub fn tape_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tape_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelArchivalInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelArchivalInput, ::rusoto_core::BuildError> {
+        Ok(CancelArchivalInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            tape_arn: self
+                .tape_arn
+                .ok_or(::rusoto_core::BuildError::missing("tape_arn"))?,
+        })
+    }
+}
+
 /// <p>CancelArchivalOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -281,6 +681,46 @@ pub struct CancelRetrievalInput {
     pub tape_arn: String,
 }
 
+impl CancelRetrievalInput {
+    /// Returns a builder for `CancelRetrievalInput`, checking its required members when built.
+    pub fn builder() -> CancelRetrievalInputBuilder {
+        CancelRetrievalInputBuilder::default()
+    }
+}
+
+/// Builds a [`CancelRetrievalInput`](struct.CancelRetrievalInput.html).
+#[derive(Default, Debug)]
+pub struct CancelRetrievalInputBuilder {
+    gateway_arn: Option<String>,
+    tape_arn: Option<String>,
+}
+
+impl CancelRetrievalInputBuilder {
+    /// Sets the [`gateway_arn`](struct.CancelRetrievalInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_arn`](struct.CancelRetrievalInput.html#structfield.tape_arn) member.
+    pub fn tape_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tape_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelRetrievalInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelRetrievalInput, ::rusoto_core::BuildError> {
+        Ok(CancelRetrievalInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            tape_arn: self
+                .tape_arn
+                .ok_or(::rusoto_core::BuildError::missing("tape_arn"))?,
+        })
+    }
+}
+
 /// <p>CancelRetrievalOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -351,6 +791,118 @@ pub struct CreateCachediSCSIVolumeInput {
     pub volume_size_in_bytes: i64,
 }
 
+impl CreateCachediSCSIVolumeInput {
+    /// Returns a builder for `CreateCachediSCSIVolumeInput`, checking its required members when built.
+    pub fn builder() -> CreateCachediSCSIVolumeInputBuilder {
+        CreateCachediSCSIVolumeInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCachediSCSIVolumeInput`](struct.CreateCachediSCSIVolumeInput.html).
+#[derive(Default, Debug)]
+pub struct CreateCachediSCSIVolumeInputBuilder {
+    client_token: Option<String>,
+    gateway_arn: Option<String>,
+    kms_encrypted: Option<bool>,
+    kms_key: Option<String>,
+    network_interface_id: Option<String>,
+    snapshot_id: Option<String>,
+    source_volume_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_name: Option<String>,
+    volume_size_in_bytes: Option<i64>,
+}
+
+impl CreateCachediSCSIVolumeInputBuilder {
+    /// Sets the [`client_token`](struct.CreateCachediSCSIVolumeInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.CreateCachediSCSIVolumeInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_encrypted`](struct.CreateCachediSCSIVolumeInput.html#structfield.kms_encrypted) member.
+    pub fn kms_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.kms_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key`](struct.CreateCachediSCSIVolumeInput.html#structfield.kms_key) member.
+    pub fn kms_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_interface_id`](struct.CreateCachediSCSIVolumeInput.html#structfield.network_interface_id) member.
+    pub fn network_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.network_interface_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_id`](struct.CreateCachediSCSIVolumeInput.html#structfield.snapshot_id) member.
+    pub fn snapshot_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_volume_arn`](struct.CreateCachediSCSIVolumeInput.html#structfield.source_volume_arn) member.
+    pub fn source_volume_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_volume_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateCachediSCSIVolumeInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_name`](struct.CreateCachediSCSIVolumeInput.html#structfield.target_name) member.
+    pub fn target_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_size_in_bytes`](struct.CreateCachediSCSIVolumeInput.html#structfield.volume_size_in_bytes) member.
+    pub fn volume_size_in_bytes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.volume_size_in_bytes = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCachediSCSIVolumeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCachediSCSIVolumeInput, ::rusoto_core::BuildError> {
+        Ok(CreateCachediSCSIVolumeInput {
+            client_token: self
+                .client_token
+                .ok_or(::rusoto_core::BuildError::missing("client_token"))?,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            kms_encrypted: self.kms_encrypted,
+            kms_key: self.kms_key,
+            network_interface_id: self
+                .network_interface_id
+                .ok_or(::rusoto_core::BuildError::missing("network_interface_id"))?,
+            snapshot_id: self.snapshot_id,
+            source_volume_arn: self.source_volume_arn,
+            tags: self.tags,
+            target_name: self
+                .target_name
+                .ok_or(::rusoto_core::BuildError::missing("target_name"))?,
+            volume_size_in_bytes: self
+                .volume_size_in_bytes
+                .ok_or(::rusoto_core::BuildError::missing("volume_size_in_bytes"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCachediSCSIVolumeOutput {
@@ -403,7 +955,7 @@ pub struct CreateNFSFileShareInput {
     /// <p>A value that sets the access control list permission for objects in the S3 bucket that a file gateway puts objects into. The default value is "private".</p>
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     /// <p>A value that sets the write status of a file share. This value is true if the write status is read-only, and otherwise false.</p>
     #[serde(rename = "ReadOnly")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -425,6 +977,156 @@ pub struct CreateNFSFileShareInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateNFSFileShareInput {
+    /// Returns a builder for `CreateNFSFileShareInput`, checking its required members when built.
+    pub fn builder() -> CreateNFSFileShareInputBuilder {
+        CreateNFSFileShareInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateNFSFileShareInput`](struct.CreateNFSFileShareInput.html).
+#[derive(Default, Debug)]
+pub struct CreateNFSFileShareInputBuilder {
+    client_list: Option<Vec<String>>,
+    client_token: Option<String>,
+    default_storage_class: Option<String>,
+    gateway_arn: Option<String>,
+    guess_mime_type_enabled: Option<bool>,
+    kms_encrypted: Option<bool>,
+    kms_key: Option<String>,
+    location_arn: Option<String>,
+    nfs_file_share_defaults: Option<NFSFileShareDefaults>,
+    object_acl: Option<ObjectACL>,
+    read_only: Option<bool>,
+    requester_pays: Option<bool>,
+    role: Option<String>,
+    squash: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateNFSFileShareInputBuilder {
+    /// Sets the [`client_list`](struct.CreateNFSFileShareInput.html#structfield.client_list) member.
+    pub fn client_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.client_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_token`](struct.CreateNFSFileShareInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_storage_class`](struct.CreateNFSFileShareInput.html#structfield.default_storage_class) member.
+    pub fn default_storage_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_storage_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.CreateNFSFileShareInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`guess_mime_type_enabled`](struct.CreateNFSFileShareInput.html#structfield.guess_mime_type_enabled) member.
+    pub fn guess_mime_type_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.guess_mime_type_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_encrypted`](struct.CreateNFSFileShareInput.html#structfield.kms_encrypted) member.
+    pub fn kms_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.kms_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key`](struct.CreateNFSFileShareInput.html#structfield.kms_key) member.
+    pub fn kms_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location_arn`](struct.CreateNFSFileShareInput.html#structfield.location_arn) member.
+    pub fn location_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.location_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`nfs_file_share_defaults`](struct.CreateNFSFileShareInput.html#structfield.nfs_file_share_defaults) member.
+    pub fn nfs_file_share_defaults<T: Into<NFSFileShareDefaults>>(mut self, value: T) -> Self {
+        self.nfs_file_share_defaults = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_acl`](struct.CreateNFSFileShareInput.html#structfield.object_acl) member.
+    pub fn object_acl<T: Into<ObjectACL>>(mut self, value: T) -> Self {
+        self.object_acl = Some(value.into());
+        self
+    }
+
+    /// Sets the [`read_only`](struct.CreateNFSFileShareInput.html#structfield.read_only) member.
+    pub fn read_only<T: Into<bool>>(mut self, value: T) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets the [`requester_pays`](struct.CreateNFSFileShareInput.html#structfield.requester_pays) member.
+    pub fn requester_pays<T: Into<bool>>(mut self, value: T) -> Self {
+        self.requester_pays = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.CreateNFSFileShareInput.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`squash`](struct.CreateNFSFileShareInput.html#structfield.squash) member.
+    pub fn squash<T: Into<String>>(mut self, value: T) -> Self {
+        self.squash = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateNFSFileShareInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateNFSFileShareInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateNFSFileShareInput, ::rusoto_core::BuildError> {
+        Ok(CreateNFSFileShareInput {
+            client_list: self.client_list,
+            client_token: self
+                .client_token
+                .ok_or(::rusoto_core::BuildError::missing("client_token"))?,
+            default_storage_class: self.default_storage_class,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            guess_mime_type_enabled: self.guess_mime_type_enabled,
+            kms_encrypted: self.kms_encrypted,
+            kms_key: self.kms_key,
+            location_arn: self
+                .location_arn
+                .ok_or(::rusoto_core::BuildError::missing("location_arn"))?,
+            nfs_file_share_defaults: self.nfs_file_share_defaults,
+            object_acl: self.object_acl,
+            read_only: self.read_only,
+            requester_pays: self.requester_pays,
+            role: self
+                .role
+                .ok_or(::rusoto_core::BuildError::missing("role"))?,
+            squash: self.squash,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p>CreateNFSFileShareOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -478,7 +1180,7 @@ pub struct CreateSMBFileShareInput {
     /// <p>A value that sets the access control list permission for objects in the S3 bucket that a file gateway puts objects into. The default value is "private".</p>
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     /// <p>A value that sets the write status of a file share. This value is true if the write status is read-only, and otherwise false.</p>
     #[serde(rename = "ReadOnly")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -504,6 +1206,172 @@ pub struct CreateSMBFileShareInput {
     pub valid_user_list: Option<Vec<String>>,
 }
 
+impl CreateSMBFileShareInput {
+    /// Returns a builder for `CreateSMBFileShareInput`, checking its required members when built.
+    pub fn builder() -> CreateSMBFileShareInputBuilder {
+        CreateSMBFileShareInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSMBFileShareInput`](struct.CreateSMBFileShareInput.html).
+#[derive(Default, Debug)]
+pub struct CreateSMBFileShareInputBuilder {
+    admin_user_list: Option<Vec<String>>,
+    authentication: Option<String>,
+    client_token: Option<String>,
+    default_storage_class: Option<String>,
+    gateway_arn: Option<String>,
+    guess_mime_type_enabled: Option<bool>,
+    invalid_user_list: Option<Vec<String>>,
+    kms_encrypted: Option<bool>,
+    kms_key: Option<String>,
+    location_arn: Option<String>,
+    object_acl: Option<ObjectACL>,
+    read_only: Option<bool>,
+    requester_pays: Option<bool>,
+    role: Option<String>,
+    smbacl_enabled: Option<bool>,
+    tags: Option<Vec<Tag>>,
+    valid_user_list: Option<Vec<String>>,
+}
+
+impl CreateSMBFileShareInputBuilder {
+    /// Sets the [`admin_user_list`](struct.CreateSMBFileShareInput.html#structfield.admin_user_list) member.
+    pub fn admin_user_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.admin_user_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authentication`](struct.CreateSMBFileShareInput.html#structfield.authentication) member.
+    pub fn authentication<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_token`](struct.CreateSMBFileShareInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_storage_class`](struct.CreateSMBFileShareInput.html#structfield.default_storage_class) member.
+    pub fn default_storage_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_storage_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.CreateSMBFileShareInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`guess_mime_type_enabled`](struct.CreateSMBFileShareInput.html#structfield.guess_mime_type_enabled) member.
+    pub fn guess_mime_type_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.guess_mime_type_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`invalid_user_list`](struct.CreateSMBFileShareInput.html#structfield.invalid_user_list) member.
+    pub fn invalid_user_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.invalid_user_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_encrypted`](struct.CreateSMBFileShareInput.html#structfield.kms_encrypted) member.
+    pub fn kms_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.kms_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key`](struct.CreateSMBFileShareInput.html#structfield.kms_key) member.
+    pub fn kms_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location_arn`](struct.CreateSMBFileShareInput.html#structfield.location_arn) member.
+    pub fn location_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.location_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_acl`](struct.CreateSMBFileShareInput.html#structfield.object_acl) member.
+    pub fn object_acl<T: Into<ObjectACL>>(mut self, value: T) -> Self {
+        self.object_acl = Some(value.into());
+        self
+    }
+
+    /// Sets the [`read_only`](struct.CreateSMBFileShareInput.html#structfield.read_only) member.
+    pub fn read_only<T: Into<bool>>(mut self, value: T) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets the [`requester_pays`](struct.CreateSMBFileShareInput.html#structfield.requester_pays) member.
+    pub fn requester_pays<T: Into<bool>>(mut self, value: T) -> Self {
+        self.requester_pays = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.CreateSMBFileShareInput.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`smbacl_enabled`](struct.CreateSMBFileShareInput.html#structfield.smbacl_enabled) member.
+    pub fn smbacl_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.smbacl_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateSMBFileShareInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`valid_user_list`](struct.CreateSMBFileShareInput.html#structfield.valid_user_list) member.
+    pub fn valid_user_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.valid_user_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSMBFileShareInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSMBFileShareInput, ::rusoto_core::BuildError> {
+        Ok(CreateSMBFileShareInput {
+            admin_user_list: self.admin_user_list,
+            authentication: self.authentication,
+            client_token: self
+                .client_token
+                .ok_or(::rusoto_core::BuildError::missing("client_token"))?,
+            default_storage_class: self.default_storage_class,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            guess_mime_type_enabled: self.guess_mime_type_enabled,
+            invalid_user_list: self.invalid_user_list,
+            kms_encrypted: self.kms_encrypted,
+            kms_key: self.kms_key,
+            location_arn: self
+                .location_arn
+                .ok_or(::rusoto_core::BuildError::missing("location_arn"))?,
+            object_acl: self.object_acl,
+            read_only: self.read_only,
+            requester_pays: self.requester_pays,
+            role: self
+                .role
+                .ok_or(::rusoto_core::BuildError::missing("role"))?,
+            smbacl_enabled: self.smbacl_enabled,
+            tags: self.tags,
+            valid_user_list: self.valid_user_list,
+        })
+    }
+}
+
 /// <p>CreateSMBFileShareOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -528,6 +1396,57 @@ pub struct CreateSnapshotFromVolumeRecoveryPointInput {
     pub volume_arn: String,
 }
 
+impl CreateSnapshotFromVolumeRecoveryPointInput {
+    /// Returns a builder for `CreateSnapshotFromVolumeRecoveryPointInput`, checking its required members when built.
+    pub fn builder() -> CreateSnapshotFromVolumeRecoveryPointInputBuilder {
+        CreateSnapshotFromVolumeRecoveryPointInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSnapshotFromVolumeRecoveryPointInput`](struct.CreateSnapshotFromVolumeRecoveryPointInput.html).
+#[derive(Default, Debug)]
+pub struct CreateSnapshotFromVolumeRecoveryPointInputBuilder {
+    snapshot_description: Option<String>,
+    tags: Option<Vec<Tag>>,
+    volume_arn: Option<String>,
+}
+
+impl CreateSnapshotFromVolumeRecoveryPointInputBuilder {
+    /// Sets the [`snapshot_description`](struct.CreateSnapshotFromVolumeRecoveryPointInput.html#structfield.snapshot_description) member.
+    pub fn snapshot_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateSnapshotFromVolumeRecoveryPointInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_arn`](struct.CreateSnapshotFromVolumeRecoveryPointInput.html#structfield.volume_arn) member.
+    pub fn volume_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSnapshotFromVolumeRecoveryPointInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSnapshotFromVolumeRecoveryPointInput, ::rusoto_core::BuildError>
+    {
+        Ok(CreateSnapshotFromVolumeRecoveryPointInput {
+            snapshot_description: self
+                .snapshot_description
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_description"))?,
+            tags: self.tags,
+            volume_arn: self
+                .volume_arn
+                .ok_or(::rusoto_core::BuildError::missing("volume_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSnapshotFromVolumeRecoveryPointOutput {
@@ -560,9 +1479,57 @@ pub struct CreateSnapshotInput {
     pub volume_arn: String,
 }
 
-/// <p>A JSON object containing the following fields:</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl CreateSnapshotInput {
+    /// Returns a builder for `CreateSnapshotInput`, checking its required members when built.
+    pub fn builder() -> CreateSnapshotInputBuilder {
+        CreateSnapshotInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSnapshotInput`](struct.CreateSnapshotInput.html).
+#[derive(Default, Debug)]
+pub struct CreateSnapshotInputBuilder {
+    snapshot_description: Option<String>,
+    tags: Option<Vec<Tag>>,
+    volume_arn: Option<String>,
+}
+
+impl CreateSnapshotInputBuilder {
+    /// Sets the [`snapshot_description`](struct.CreateSnapshotInput.html#structfield.snapshot_description) member.
+    pub fn snapshot_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateSnapshotInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_arn`](struct.CreateSnapshotInput.html#structfield.volume_arn) member.
+    pub fn volume_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSnapshotInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateSnapshotInput, ::rusoto_core::BuildError> {
+        Ok(CreateSnapshotInput {
+            snapshot_description: self
+                .snapshot_description
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_description"))?,
+            tags: self.tags,
+            volume_arn: self
+                .volume_arn
+                .ok_or(::rusoto_core::BuildError::missing("volume_arn"))?,
+        })
+    }
+}
+
+/// <p>A JSON object containing the following fields:</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
 pub struct CreateSnapshotOutput {
     /// <p>The snapshot ID that is used to refer to the snapshot in future operations such as describing snapshots (Amazon Elastic Compute Cloud API <code>DescribeSnapshots</code>) or creating a volume from a snapshot (<a>CreateStorediSCSIVolume</a>).</p>
     #[serde(rename = "SnapshotId")]
@@ -609,6 +1576,110 @@ pub struct CreateStorediSCSIVolumeInput {
     pub target_name: String,
 }
 
+impl CreateStorediSCSIVolumeInput {
+    /// Returns a builder for `CreateStorediSCSIVolumeInput`, checking its required members when built.
+    pub fn builder() -> CreateStorediSCSIVolumeInputBuilder {
+        CreateStorediSCSIVolumeInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStorediSCSIVolumeInput`](struct.CreateStorediSCSIVolumeInput.html).
+#[derive(Default, Debug)]
+pub struct CreateStorediSCSIVolumeInputBuilder {
+    disk_id: Option<String>,
+    gateway_arn: Option<String>,
+    kms_encrypted: Option<bool>,
+    kms_key: Option<String>,
+    network_interface_id: Option<String>,
+    preserve_existing_data: Option<bool>,
+    snapshot_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_name: Option<String>,
+}
+
+impl CreateStorediSCSIVolumeInputBuilder {
+    /// Sets the [`disk_id`](struct.CreateStorediSCSIVolumeInput.html#structfield.disk_id) member.
+    pub fn disk_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.CreateStorediSCSIVolumeInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_encrypted`](struct.CreateStorediSCSIVolumeInput.html#structfield.kms_encrypted) member.
+    pub fn kms_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.kms_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key`](struct.CreateStorediSCSIVolumeInput.html#structfield.kms_key) member.
+    pub fn kms_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_interface_id`](struct.CreateStorediSCSIVolumeInput.html#structfield.network_interface_id) member.
+    pub fn network_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.network_interface_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preserve_existing_data`](struct.CreateStorediSCSIVolumeInput.html#structfield.preserve_existing_data) member.
+    pub fn preserve_existing_data<T: Into<bool>>(mut self, value: T) -> Self {
+        self.preserve_existing_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_id`](struct.CreateStorediSCSIVolumeInput.html#structfield.snapshot_id) member.
+    pub fn snapshot_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateStorediSCSIVolumeInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_name`](struct.CreateStorediSCSIVolumeInput.html#structfield.target_name) member.
+    pub fn target_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStorediSCSIVolumeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateStorediSCSIVolumeInput, ::rusoto_core::BuildError> {
+        Ok(CreateStorediSCSIVolumeInput {
+            disk_id: self
+                .disk_id
+                .ok_or(::rusoto_core::BuildError::missing("disk_id"))?,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            kms_encrypted: self.kms_encrypted,
+            kms_key: self.kms_key,
+            network_interface_id: self
+                .network_interface_id
+                .ok_or(::rusoto_core::BuildError::missing("network_interface_id"))?,
+            preserve_existing_data: self
+                .preserve_existing_data
+                .ok_or(::rusoto_core::BuildError::missing("preserve_existing_data"))?,
+            snapshot_id: self.snapshot_id,
+            tags: self.tags,
+            target_name: self
+                .target_name
+                .ok_or(::rusoto_core::BuildError::missing("target_name"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -657,6 +1728,90 @@ pub struct CreateTapeWithBarcodeInput {
     pub tape_size_in_bytes: i64,
 }
 
+impl CreateTapeWithBarcodeInput {
+    /// Returns a builder for `CreateTapeWithBarcodeInput`, checking its required members when built.
+    pub fn builder() -> CreateTapeWithBarcodeInputBuilder {
+        CreateTapeWithBarcodeInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTapeWithBarcodeInput`](struct.CreateTapeWithBarcodeInput.html).
+#[derive(Default, Debug)]
+pub struct CreateTapeWithBarcodeInputBuilder {
+    gateway_arn: Option<String>,
+    kms_encrypted: Option<bool>,
+    kms_key: Option<String>,
+    pool_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+    tape_barcode: Option<String>,
+    tape_size_in_bytes: Option<i64>,
+}
+
+impl CreateTapeWithBarcodeInputBuilder {
+    /// Sets the [`gateway_arn`](struct.CreateTapeWithBarcodeInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_encrypted`](struct.CreateTapeWithBarcodeInput.html#structfield.kms_encrypted) member.
+    pub fn kms_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.kms_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key`](struct.CreateTapeWithBarcodeInput.html#structfield.kms_key) member.
+    pub fn kms_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pool_id`](struct.CreateTapeWithBarcodeInput.html#structfield.pool_id) member.
+    pub fn pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTapeWithBarcodeInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_barcode`](struct.CreateTapeWithBarcodeInput.html#structfield.tape_barcode) member.
+    pub fn tape_barcode<T: Into<String>>(mut self, value: T) -> Self {
+        self.tape_barcode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_size_in_bytes`](struct.CreateTapeWithBarcodeInput.html#structfield.tape_size_in_bytes) member.
+    pub fn tape_size_in_bytes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.tape_size_in_bytes = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTapeWithBarcodeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateTapeWithBarcodeInput, ::rusoto_core::BuildError> {
+        Ok(CreateTapeWithBarcodeInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            kms_encrypted: self.kms_encrypted,
+            kms_key: self.kms_key,
+            pool_id: self.pool_id,
+            tags: self.tags,
+            tape_barcode: self
+                .tape_barcode
+                .ok_or(::rusoto_core::BuildError::missing("tape_barcode"))?,
+            tape_size_in_bytes: self
+                .tape_size_in_bytes
+                .ok_or(::rusoto_core::BuildError::missing("tape_size_in_bytes"))?,
+        })
+    }
+}
+
 /// <p>CreateTapeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -703,6 +1858,108 @@ pub struct CreateTapesInput {
     pub tape_size_in_bytes: i64,
 }
 
+impl CreateTapesInput {
+    /// Returns a builder for `CreateTapesInput`, checking its required members when built.
+    pub fn builder() -> CreateTapesInputBuilder {
+        CreateTapesInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTapesInput`](struct.CreateTapesInput.html).
+#[derive(Default, Debug)]
+pub struct CreateTapesInputBuilder {
+    client_token: Option<String>,
+    gateway_arn: Option<String>,
+    kms_encrypted: Option<bool>,
+    kms_key: Option<String>,
+    num_tapes_to_create: Option<i64>,
+    pool_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+    tape_barcode_prefix: Option<String>,
+    tape_size_in_bytes: Option<i64>,
+}
+
+impl CreateTapesInputBuilder {
+    /// Sets the [`client_token`](struct.CreateTapesInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.CreateTapesInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_encrypted`](struct.CreateTapesInput.html#structfield.kms_encrypted) member.
+    pub fn kms_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.kms_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key`](struct.CreateTapesInput.html#structfield.kms_key) member.
+    pub fn kms_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`num_tapes_to_create`](struct.CreateTapesInput.html#structfield.num_tapes_to_create) member.
+    pub fn num_tapes_to_create<T: Into<i64>>(mut self, value: T) -> Self {
+        self.num_tapes_to_create = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pool_id`](struct.CreateTapesInput.html#structfield.pool_id) member.
+    pub fn pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTapesInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_barcode_prefix`](struct.CreateTapesInput.html#structfield.tape_barcode_prefix) member.
+    pub fn tape_barcode_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.tape_barcode_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_size_in_bytes`](struct.CreateTapesInput.html#structfield.tape_size_in_bytes) member.
+    pub fn tape_size_in_bytes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.tape_size_in_bytes = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTapesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTapesInput, ::rusoto_core::BuildError> {
+        Ok(CreateTapesInput {
+            client_token: self
+                .client_token
+                .ok_or(::rusoto_core::BuildError::missing("client_token"))?,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            kms_encrypted: self.kms_encrypted,
+            kms_key: self.kms_key,
+            num_tapes_to_create: self
+                .num_tapes_to_create
+                .ok_or(::rusoto_core::BuildError::missing("num_tapes_to_create"))?,
+            pool_id: self.pool_id,
+            tags: self.tags,
+            tape_barcode_prefix: self
+                .tape_barcode_prefix
+                .ok_or(::rusoto_core::BuildError::missing("tape_barcode_prefix"))?,
+            tape_size_in_bytes: self
+                .tape_size_in_bytes
+                .ok_or(::rusoto_core::BuildError::missing("tape_size_in_bytes"))?,
+        })
+    }
+}
+
 /// <p>CreateTapeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -723,6 +1980,48 @@ pub struct DeleteBandwidthRateLimitInput {
     pub gateway_arn: String,
 }
 
+impl DeleteBandwidthRateLimitInput {
+    /// Returns a builder for `DeleteBandwidthRateLimitInput`, checking its required members when built.
+    pub fn builder() -> DeleteBandwidthRateLimitInputBuilder {
+        DeleteBandwidthRateLimitInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBandwidthRateLimitInput`](struct.DeleteBandwidthRateLimitInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteBandwidthRateLimitInputBuilder {
+    bandwidth_type: Option<String>,
+    gateway_arn: Option<String>,
+}
+
+impl DeleteBandwidthRateLimitInputBuilder {
+    /// Sets the [`bandwidth_type`](struct.DeleteBandwidthRateLimitInput.html#structfield.bandwidth_type) member.
+    pub fn bandwidth_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.bandwidth_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.DeleteBandwidthRateLimitInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBandwidthRateLimitInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteBandwidthRateLimitInput, ::rusoto_core::BuildError> {
+        Ok(DeleteBandwidthRateLimitInput {
+            bandwidth_type: self
+                .bandwidth_type
+                .ok_or(::rusoto_core::BuildError::missing("bandwidth_type"))?,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the of the gateway whose bandwidth rate information was deleted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -743,6 +2042,48 @@ pub struct DeleteChapCredentialsInput {
     pub target_arn: String,
 }
 
+impl DeleteChapCredentialsInput {
+    /// Returns a builder for `DeleteChapCredentialsInput`, checking its required members when built.
+    pub fn builder() -> DeleteChapCredentialsInputBuilder {
+        DeleteChapCredentialsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteChapCredentialsInput`](struct.DeleteChapCredentialsInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteChapCredentialsInputBuilder {
+    initiator_name: Option<String>,
+    target_arn: Option<String>,
+}
+
+impl DeleteChapCredentialsInputBuilder {
+    /// Sets the [`initiator_name`](struct.DeleteChapCredentialsInput.html#structfield.initiator_name) member.
+    pub fn initiator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.initiator_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_arn`](struct.DeleteChapCredentialsInput.html#structfield.target_arn) member.
+    pub fn target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteChapCredentialsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteChapCredentialsInput, ::rusoto_core::BuildError> {
+        Ok(DeleteChapCredentialsInput {
+            initiator_name: self
+                .initiator_name
+                .ok_or(::rusoto_core::BuildError::missing("initiator_name"))?,
+            target_arn: self
+                .target_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -769,6 +2110,44 @@ pub struct DeleteFileShareInput {
     pub force_delete: Option<bool>,
 }
 
+impl DeleteFileShareInput {
+    /// Returns a builder for `DeleteFileShareInput`, checking its required members when built.
+    pub fn builder() -> DeleteFileShareInputBuilder {
+        DeleteFileShareInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFileShareInput`](struct.DeleteFileShareInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteFileShareInputBuilder {
+    file_share_arn: Option<String>,
+    force_delete: Option<bool>,
+}
+
+impl DeleteFileShareInputBuilder {
+    /// Sets the [`file_share_arn`](struct.DeleteFileShareInput.html#structfield.file_share_arn) member.
+    pub fn file_share_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_share_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_delete`](struct.DeleteFileShareInput.html#structfield.force_delete) member.
+    pub fn force_delete<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_delete = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFileShareInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteFileShareInput, ::rusoto_core::BuildError> {
+        Ok(DeleteFileShareInput {
+            file_share_arn: self
+                .file_share_arn
+                .ok_or(::rusoto_core::BuildError::missing("file_share_arn"))?,
+            force_delete: self.force_delete,
+        })
+    }
+}
+
 /// <p>DeleteFileShareOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -786,6 +2165,36 @@ pub struct DeleteGatewayInput {
     pub gateway_arn: String,
 }
 
+impl DeleteGatewayInput {
+    /// Returns a builder for `DeleteGatewayInput`, checking its required members when built.
+    pub fn builder() -> DeleteGatewayInputBuilder {
+        DeleteGatewayInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGatewayInput`](struct.DeleteGatewayInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteGatewayInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl DeleteGatewayInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DeleteGatewayInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGatewayInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteGatewayInput, ::rusoto_core::BuildError> {
+        Ok(DeleteGatewayInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the ID of the deleted gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -802,6 +2211,38 @@ pub struct DeleteSnapshotScheduleInput {
     pub volume_arn: String,
 }
 
+impl DeleteSnapshotScheduleInput {
+    /// Returns a builder for `DeleteSnapshotScheduleInput`, checking its required members when built.
+    pub fn builder() -> DeleteSnapshotScheduleInputBuilder {
+        DeleteSnapshotScheduleInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSnapshotScheduleInput`](struct.DeleteSnapshotScheduleInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteSnapshotScheduleInputBuilder {
+    volume_arn: Option<String>,
+}
+
+impl DeleteSnapshotScheduleInputBuilder {
+    /// Sets the [`volume_arn`](struct.DeleteSnapshotScheduleInput.html#structfield.volume_arn) member.
+    pub fn volume_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSnapshotScheduleInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSnapshotScheduleInput, ::rusoto_core::BuildError> {
+        Ok(DeleteSnapshotScheduleInput {
+            volume_arn: self
+                .volume_arn
+                .ok_or(::rusoto_core::BuildError::missing("volume_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSnapshotScheduleOutput {
@@ -819,6 +2260,36 @@ pub struct DeleteTapeArchiveInput {
     pub tape_arn: String,
 }
 
+impl DeleteTapeArchiveInput {
+    /// Returns a builder for `DeleteTapeArchiveInput`, checking its required members when built.
+    pub fn builder() -> DeleteTapeArchiveInputBuilder {
+        DeleteTapeArchiveInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTapeArchiveInput`](struct.DeleteTapeArchiveInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteTapeArchiveInputBuilder {
+    tape_arn: Option<String>,
+}
+
+impl DeleteTapeArchiveInputBuilder {
+    /// Sets the [`tape_arn`](struct.DeleteTapeArchiveInput.html#structfield.tape_arn) member.
+    pub fn tape_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tape_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTapeArchiveInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTapeArchiveInput, ::rusoto_core::BuildError> {
+        Ok(DeleteTapeArchiveInput {
+            tape_arn: self
+                .tape_arn
+                .ok_or(::rusoto_core::BuildError::missing("tape_arn"))?,
+        })
+    }
+}
+
 /// <p>DeleteTapeArchiveOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -840,6 +2311,46 @@ pub struct DeleteTapeInput {
     pub tape_arn: String,
 }
 
+impl DeleteTapeInput {
+    /// Returns a builder for `DeleteTapeInput`, checking its required members when built.
+    pub fn builder() -> DeleteTapeInputBuilder {
+        DeleteTapeInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTapeInput`](struct.DeleteTapeInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteTapeInputBuilder {
+    gateway_arn: Option<String>,
+    tape_arn: Option<String>,
+}
+
+impl DeleteTapeInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DeleteTapeInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_arn`](struct.DeleteTapeInput.html#structfield.tape_arn) member.
+    pub fn tape_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tape_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTapeInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTapeInput, ::rusoto_core::BuildError> {
+        Ok(DeleteTapeInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            tape_arn: self
+                .tape_arn
+                .ok_or(::rusoto_core::BuildError::missing("tape_arn"))?,
+        })
+    }
+}
+
 /// <p>DeleteTapeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -858,6 +2369,36 @@ pub struct DeleteVolumeInput {
     pub volume_arn: String,
 }
 
+impl DeleteVolumeInput {
+    /// Returns a builder for `DeleteVolumeInput`, checking its required members when built.
+    pub fn builder() -> DeleteVolumeInputBuilder {
+        DeleteVolumeInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVolumeInput`](struct.DeleteVolumeInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteVolumeInputBuilder {
+    volume_arn: Option<String>,
+}
+
+impl DeleteVolumeInputBuilder {
+    /// Sets the [`volume_arn`](struct.DeleteVolumeInput.html#structfield.volume_arn) member.
+    pub fn volume_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVolumeInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteVolumeInput, ::rusoto_core::BuildError> {
+        Ok(DeleteVolumeInput {
+            volume_arn: self
+                .volume_arn
+                .ok_or(::rusoto_core::BuildError::missing("volume_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the of the storage volume that was deleted</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -875,6 +2416,38 @@ pub struct DescribeBandwidthRateLimitInput {
     pub gateway_arn: String,
 }
 
+impl DescribeBandwidthRateLimitInput {
+    /// Returns a builder for `DescribeBandwidthRateLimitInput`, checking its required members when built.
+    pub fn builder() -> DescribeBandwidthRateLimitInputBuilder {
+        DescribeBandwidthRateLimitInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBandwidthRateLimitInput`](struct.DescribeBandwidthRateLimitInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeBandwidthRateLimitInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl DescribeBandwidthRateLimitInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DescribeBandwidthRateLimitInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBandwidthRateLimitInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeBandwidthRateLimitInput, ::rusoto_core::BuildError> {
+        Ok(DescribeBandwidthRateLimitInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -898,6 +2471,36 @@ pub struct DescribeCacheInput {
     pub gateway_arn: String,
 }
 
+impl DescribeCacheInput {
+    /// Returns a builder for `DescribeCacheInput`, checking its required members when built.
+    pub fn builder() -> DescribeCacheInputBuilder {
+        DescribeCacheInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCacheInput`](struct.DescribeCacheInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeCacheInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl DescribeCacheInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DescribeCacheInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCacheInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeCacheInput, ::rusoto_core::BuildError> {
+        Ok(DescribeCacheInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeCacheOutput {
@@ -937,6 +2540,38 @@ pub struct DescribeCachediSCSIVolumesInput {
     pub volume_ar_ns: Vec<String>,
 }
 
+impl DescribeCachediSCSIVolumesInput {
+    /// Returns a builder for `DescribeCachediSCSIVolumesInput`, checking its required members when built.
+    pub fn builder() -> DescribeCachediSCSIVolumesInputBuilder {
+        DescribeCachediSCSIVolumesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCachediSCSIVolumesInput`](struct.DescribeCachediSCSIVolumesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeCachediSCSIVolumesInputBuilder {
+    volume_ar_ns: Option<Vec<String>>,
+}
+
+impl DescribeCachediSCSIVolumesInputBuilder {
+    /// Sets the [`volume_ar_ns`](struct.DescribeCachediSCSIVolumesInput.html#structfield.volume_ar_ns) member.
+    pub fn volume_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.volume_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCachediSCSIVolumesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCachediSCSIVolumesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeCachediSCSIVolumesInput {
+            volume_ar_ns: self
+                .volume_ar_ns
+                .ok_or(::rusoto_core::BuildError::missing("volume_ar_ns"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -955,6 +2590,38 @@ pub struct DescribeChapCredentialsInput {
     pub target_arn: String,
 }
 
+impl DescribeChapCredentialsInput {
+    /// Returns a builder for `DescribeChapCredentialsInput`, checking its required members when built.
+    pub fn builder() -> DescribeChapCredentialsInputBuilder {
+        DescribeChapCredentialsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeChapCredentialsInput`](struct.DescribeChapCredentialsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeChapCredentialsInputBuilder {
+    target_arn: Option<String>,
+}
+
+impl DescribeChapCredentialsInputBuilder {
+    /// Sets the [`target_arn`](struct.DescribeChapCredentialsInput.html#structfield.target_arn) member.
+    pub fn target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeChapCredentialsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeChapCredentialsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeChapCredentialsInput {
+            target_arn: self
+                .target_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing a .</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -972,6 +2639,38 @@ pub struct DescribeGatewayInformationInput {
     pub gateway_arn: String,
 }
 
+impl DescribeGatewayInformationInput {
+    /// Returns a builder for `DescribeGatewayInformationInput`, checking its required members when built.
+    pub fn builder() -> DescribeGatewayInformationInputBuilder {
+        DescribeGatewayInformationInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeGatewayInformationInput`](struct.DescribeGatewayInformationInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeGatewayInformationInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl DescribeGatewayInformationInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DescribeGatewayInformationInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeGatewayInformationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeGatewayInformationInput, ::rusoto_core::BuildError> {
+        Ok(DescribeGatewayInformationInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1040,16 +2739,48 @@ pub struct DescribeMaintenanceStartTimeInput {
     pub gateway_arn: String,
 }
 
-/// <p><p>A JSON object containing the following fields:</p> <ul> <li> <p> <a>DescribeMaintenanceStartTimeOutput$DayOfMonth</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$DayOfWeek</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$HourOfDay</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$MinuteOfHour</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$Timezone</a> </p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DescribeMaintenanceStartTimeOutput {
-    /// <p><p>The day of the month component of the maintenance start time represented as an ordinal number from 1 to 28, where 1 represents the first day of the month and 28 represents the last day of the month.</p> <note> <p>This value is only available for tape and volume gateways.</p> </note></p>
-    #[serde(rename = "DayOfMonth")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub day_of_month: Option<i64>,
-    /// <p>An ordinal number between 0 and 6 that represents the day of the week, where 0 represents Sunday and 6 represents Saturday. The day of week is in the time zone of the gateway.</p>
-    #[serde(rename = "DayOfWeek")]
+impl DescribeMaintenanceStartTimeInput {
+    /// Returns a builder for `DescribeMaintenanceStartTimeInput`, checking its required members when built.
+    pub fn builder() -> DescribeMaintenanceStartTimeInputBuilder {
+        DescribeMaintenanceStartTimeInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeMaintenanceStartTimeInput`](struct.DescribeMaintenanceStartTimeInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeMaintenanceStartTimeInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl DescribeMaintenanceStartTimeInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DescribeMaintenanceStartTimeInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeMaintenanceStartTimeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeMaintenanceStartTimeInput, ::rusoto_core::BuildError> {
+        Ok(DescribeMaintenanceStartTimeInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
+/// <p><p>A JSON object containing the following fields:</p> <ul> <li> <p> <a>DescribeMaintenanceStartTimeOutput$DayOfMonth</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$DayOfWeek</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$HourOfDay</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$MinuteOfHour</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$Timezone</a> </p> </li> </ul></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeMaintenanceStartTimeOutput {
+    /// <p><p>The day of the month component of the maintenance start time represented as an ordinal number from 1 to 28, where 1 represents the first day of the month and 28 represents the last day of the month.</p> <note> <p>This value is only available for tape and volume gateways.</p> </note></p>
+    #[serde(rename = "DayOfMonth")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_of_month: Option<i64>,
+    /// <p>An ordinal number between 0 and 6 that represents the day of the week, where 0 represents Sunday and 6 represents Saturday. The day of week is in the time zone of the gateway.</p>
+    #[serde(rename = "DayOfWeek")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub day_of_week: Option<i64>,
     #[serde(rename = "GatewayARN")]
@@ -1077,6 +2808,38 @@ pub struct DescribeNFSFileSharesInput {
     pub file_share_arn_list: Vec<String>,
 }
 
+impl DescribeNFSFileSharesInput {
+    /// Returns a builder for `DescribeNFSFileSharesInput`, checking its required members when built.
+    pub fn builder() -> DescribeNFSFileSharesInputBuilder {
+        DescribeNFSFileSharesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeNFSFileSharesInput`](struct.DescribeNFSFileSharesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeNFSFileSharesInputBuilder {
+    file_share_arn_list: Option<Vec<String>>,
+}
+
+impl DescribeNFSFileSharesInputBuilder {
+    /// Sets the [`file_share_arn_list`](struct.DescribeNFSFileSharesInput.html#structfield.file_share_arn_list) member.
+    pub fn file_share_arn_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.file_share_arn_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeNFSFileSharesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeNFSFileSharesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeNFSFileSharesInput {
+            file_share_arn_list: self
+                .file_share_arn_list
+                .ok_or(::rusoto_core::BuildError::missing("file_share_arn_list"))?,
+        })
+    }
+}
+
 /// <p>DescribeNFSFileSharesOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1095,6 +2858,38 @@ pub struct DescribeSMBFileSharesInput {
     pub file_share_arn_list: Vec<String>,
 }
 
+impl DescribeSMBFileSharesInput {
+    /// Returns a builder for `DescribeSMBFileSharesInput`, checking its required members when built.
+    pub fn builder() -> DescribeSMBFileSharesInputBuilder {
+        DescribeSMBFileSharesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSMBFileSharesInput`](struct.DescribeSMBFileSharesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeSMBFileSharesInputBuilder {
+    file_share_arn_list: Option<Vec<String>>,
+}
+
+impl DescribeSMBFileSharesInputBuilder {
+    /// Sets the [`file_share_arn_list`](struct.DescribeSMBFileSharesInput.html#structfield.file_share_arn_list) member.
+    pub fn file_share_arn_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.file_share_arn_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSMBFileSharesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSMBFileSharesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeSMBFileSharesInput {
+            file_share_arn_list: self
+                .file_share_arn_list
+                .ok_or(::rusoto_core::BuildError::missing("file_share_arn_list"))?,
+        })
+    }
+}
+
 /// <p>DescribeSMBFileSharesOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1111,6 +2906,38 @@ pub struct DescribeSMBSettingsInput {
     pub gateway_arn: String,
 }
 
+impl DescribeSMBSettingsInput {
+    /// Returns a builder for `DescribeSMBSettingsInput`, checking its required members when built.
+    pub fn builder() -> DescribeSMBSettingsInputBuilder {
+        DescribeSMBSettingsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSMBSettingsInput`](struct.DescribeSMBSettingsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeSMBSettingsInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl DescribeSMBSettingsInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DescribeSMBSettingsInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSMBSettingsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSMBSettingsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeSMBSettingsInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeSMBSettingsOutput {
@@ -1128,7 +2955,7 @@ pub struct DescribeSMBSettingsOutput {
     /// <p>The type of security strategy that was specified for file gateway.</p> <p>ClientSpecified: if you use this option, requests are established based on what is negotiated by the client. This option is recommended when you want to maximize compatibility across different clients in your environment. </p> <p>MandatorySigning: if you use this option, file gateway only allows connections from SMBv2 or SMBv3 clients that have signing enabled. This option works with SMB clients on Microsoft Windows Vista, Windows Server 2008 or newer. </p> <p>MandatoryEncryption: if you use this option, file gateway only allows connections from SMBv3 clients that have encryption enabled. This option is highly recommended for environments that handle sensitive data. This option works with SMB clients on Microsoft Windows 8, Windows Server 2012 or newer. </p>
     #[serde(rename = "SMBSecurityStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub smb_security_strategy: Option<String>,
+    pub smb_security_strategy: Option<SMBSecurityStrategy>,
 }
 
 /// <p>A JSON object containing the <a>DescribeSnapshotScheduleInput$VolumeARN</a> of the volume.</p>
@@ -1139,6 +2966,38 @@ pub struct DescribeSnapshotScheduleInput {
     pub volume_arn: String,
 }
 
+impl DescribeSnapshotScheduleInput {
+    /// Returns a builder for `DescribeSnapshotScheduleInput`, checking its required members when built.
+    pub fn builder() -> DescribeSnapshotScheduleInputBuilder {
+        DescribeSnapshotScheduleInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSnapshotScheduleInput`](struct.DescribeSnapshotScheduleInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeSnapshotScheduleInputBuilder {
+    volume_arn: Option<String>,
+}
+
+impl DescribeSnapshotScheduleInputBuilder {
+    /// Sets the [`volume_arn`](struct.DescribeSnapshotScheduleInput.html#structfield.volume_arn) member.
+    pub fn volume_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSnapshotScheduleInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSnapshotScheduleInput, ::rusoto_core::BuildError> {
+        Ok(DescribeSnapshotScheduleInput {
+            volume_arn: self
+                .volume_arn
+                .ok_or(::rusoto_core::BuildError::missing("volume_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeSnapshotScheduleOutput {
@@ -1176,6 +3035,38 @@ pub struct DescribeStorediSCSIVolumesInput {
     pub volume_ar_ns: Vec<String>,
 }
 
+impl DescribeStorediSCSIVolumesInput {
+    /// Returns a builder for `DescribeStorediSCSIVolumesInput`, checking its required members when built.
+    pub fn builder() -> DescribeStorediSCSIVolumesInputBuilder {
+        DescribeStorediSCSIVolumesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStorediSCSIVolumesInput`](struct.DescribeStorediSCSIVolumesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStorediSCSIVolumesInputBuilder {
+    volume_ar_ns: Option<Vec<String>>,
+}
+
+impl DescribeStorediSCSIVolumesInputBuilder {
+    /// Sets the [`volume_ar_ns`](struct.DescribeStorediSCSIVolumesInput.html#structfield.volume_ar_ns) member.
+    pub fn volume_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.volume_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStorediSCSIVolumesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStorediSCSIVolumesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStorediSCSIVolumesInput {
+            volume_ar_ns: self
+                .volume_ar_ns
+                .ok_or(::rusoto_core::BuildError::missing("volume_ar_ns"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeStorediSCSIVolumesOutput {
@@ -1202,6 +3093,52 @@ pub struct DescribeTapeArchivesInput {
     pub tape_ar_ns: Option<Vec<String>>,
 }
 
+impl DescribeTapeArchivesInput {
+    /// Returns a builder for `DescribeTapeArchivesInput`, checking its required members when built.
+    pub fn builder() -> DescribeTapeArchivesInputBuilder {
+        DescribeTapeArchivesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTapeArchivesInput`](struct.DescribeTapeArchivesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTapeArchivesInputBuilder {
+    limit: Option<i64>,
+    marker: Option<String>,
+    tape_ar_ns: Option<Vec<String>>,
+}
+
+impl DescribeTapeArchivesInputBuilder {
+    /// Sets the [`limit`](struct.DescribeTapeArchivesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeTapeArchivesInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_ar_ns`](struct.DescribeTapeArchivesInput.html#structfield.tape_ar_ns) member.
+    pub fn tape_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tape_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTapeArchivesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTapeArchivesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTapeArchivesInput {
+            limit: self.limit,
+            marker: self.marker,
+            tape_ar_ns: self.tape_ar_ns,
+        })
+    }
+}
+
 /// <p>DescribeTapeArchivesOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1231,6 +3168,54 @@ pub struct DescribeTapeRecoveryPointsInput {
     pub marker: Option<String>,
 }
 
+impl DescribeTapeRecoveryPointsInput {
+    /// Returns a builder for `DescribeTapeRecoveryPointsInput`, checking its required members when built.
+    pub fn builder() -> DescribeTapeRecoveryPointsInputBuilder {
+        DescribeTapeRecoveryPointsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTapeRecoveryPointsInput`](struct.DescribeTapeRecoveryPointsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTapeRecoveryPointsInputBuilder {
+    gateway_arn: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl DescribeTapeRecoveryPointsInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DescribeTapeRecoveryPointsInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeTapeRecoveryPointsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeTapeRecoveryPointsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTapeRecoveryPointsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTapeRecoveryPointsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTapeRecoveryPointsInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 /// <p>DescribeTapeRecoveryPointsOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1267,6 +3252,60 @@ pub struct DescribeTapesInput {
     pub tape_ar_ns: Option<Vec<String>>,
 }
 
+impl DescribeTapesInput {
+    /// Returns a builder for `DescribeTapesInput`, checking its required members when built.
+    pub fn builder() -> DescribeTapesInputBuilder {
+        DescribeTapesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTapesInput`](struct.DescribeTapesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTapesInputBuilder {
+    gateway_arn: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+    tape_ar_ns: Option<Vec<String>>,
+}
+
+impl DescribeTapesInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DescribeTapesInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeTapesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeTapesInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_ar_ns`](struct.DescribeTapesInput.html#structfield.tape_ar_ns) member.
+    pub fn tape_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tape_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTapesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTapesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTapesInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            limit: self.limit,
+            marker: self.marker,
+            tape_ar_ns: self.tape_ar_ns,
+        })
+    }
+}
+
 /// <p>DescribeTapesOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1287,6 +3326,38 @@ pub struct DescribeUploadBufferInput {
     pub gateway_arn: String,
 }
 
+impl DescribeUploadBufferInput {
+    /// Returns a builder for `DescribeUploadBufferInput`, checking its required members when built.
+    pub fn builder() -> DescribeUploadBufferInputBuilder {
+        DescribeUploadBufferInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUploadBufferInput`](struct.DescribeUploadBufferInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeUploadBufferInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl DescribeUploadBufferInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DescribeUploadBufferInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUploadBufferInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUploadBufferInput, ::rusoto_core::BuildError> {
+        Ok(DescribeUploadBufferInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUploadBufferOutput {
@@ -1326,6 +3397,62 @@ pub struct DescribeVTLDevicesInput {
     pub vtl_device_ar_ns: Option<Vec<String>>,
 }
 
+impl DescribeVTLDevicesInput {
+    /// Returns a builder for `DescribeVTLDevicesInput`, checking its required members when built.
+    pub fn builder() -> DescribeVTLDevicesInputBuilder {
+        DescribeVTLDevicesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeVTLDevicesInput`](struct.DescribeVTLDevicesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeVTLDevicesInputBuilder {
+    gateway_arn: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+    vtl_device_ar_ns: Option<Vec<String>>,
+}
+
+impl DescribeVTLDevicesInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DescribeVTLDevicesInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeVTLDevicesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeVTLDevicesInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vtl_device_ar_ns`](struct.DescribeVTLDevicesInput.html#structfield.vtl_device_ar_ns) member.
+    pub fn vtl_device_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vtl_device_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeVTLDevicesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeVTLDevicesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeVTLDevicesInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            limit: self.limit,
+            marker: self.marker,
+            vtl_device_ar_ns: self.vtl_device_ar_ns,
+        })
+    }
+}
+
 /// <p>DescribeVTLDevicesOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1350,6 +3477,38 @@ pub struct DescribeWorkingStorageInput {
     pub gateway_arn: String,
 }
 
+impl DescribeWorkingStorageInput {
+    /// Returns a builder for `DescribeWorkingStorageInput`, checking its required members when built.
+    pub fn builder() -> DescribeWorkingStorageInputBuilder {
+        DescribeWorkingStorageInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeWorkingStorageInput`](struct.DescribeWorkingStorageInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeWorkingStorageInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl DescribeWorkingStorageInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DescribeWorkingStorageInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeWorkingStorageInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeWorkingStorageInput, ::rusoto_core::BuildError> {
+        Ok(DescribeWorkingStorageInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1383,6 +3542,44 @@ pub struct DetachVolumeInput {
     pub volume_arn: String,
 }
 
+impl DetachVolumeInput {
+    /// Returns a builder for `DetachVolumeInput`, checking its required members when built.
+    pub fn builder() -> DetachVolumeInputBuilder {
+        DetachVolumeInputBuilder::default()
+    }
+}
+
+/// Builds a [`DetachVolumeInput`](struct.DetachVolumeInput.html).
+#[derive(Default, Debug)]
+pub struct DetachVolumeInputBuilder {
+    force_detach: Option<bool>,
+    volume_arn: Option<String>,
+}
+
+impl DetachVolumeInputBuilder {
+    /// Sets the [`force_detach`](struct.DetachVolumeInput.html#structfield.force_detach) member.
+    pub fn force_detach<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_detach = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_arn`](struct.DetachVolumeInput.html#structfield.volume_arn) member.
+    pub fn volume_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachVolumeInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetachVolumeInput, ::rusoto_core::BuildError> {
+        Ok(DetachVolumeInput {
+            force_detach: self.force_detach,
+            volume_arn: self
+                .volume_arn
+                .ok_or(::rusoto_core::BuildError::missing("volume_arn"))?,
+        })
+    }
+}
+
 /// <p>AttachVolumeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1422,6 +3619,36 @@ pub struct DisableGatewayInput {
     pub gateway_arn: String,
 }
 
+impl DisableGatewayInput {
+    /// Returns a builder for `DisableGatewayInput`, checking its required members when built.
+    pub fn builder() -> DisableGatewayInputBuilder {
+        DisableGatewayInputBuilder::default()
+    }
+}
+
+/// Builds a [`DisableGatewayInput`](struct.DisableGatewayInput.html).
+#[derive(Default, Debug)]
+pub struct DisableGatewayInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl DisableGatewayInputBuilder {
+    /// Sets the [`gateway_arn`](struct.DisableGatewayInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableGatewayInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DisableGatewayInput, ::rusoto_core::BuildError> {
+        Ok(DisableGatewayInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 /// <p>DisableGatewayOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1468,6 +3695,318 @@ pub struct Disk {
     pub disk_status: Option<String>,
 }
 
+/// <p>Additional information about the error.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// <p><code>ActivationKeyExpired</code></p>
+    ActivationKeyExpired,
+    /// <p><code>ActivationKeyInvalid</code></p>
+    ActivationKeyInvalid,
+    /// <p><code>ActivationKeyNotFound</code></p>
+    ActivationKeyNotFound,
+    /// <p><code>GatewayInternalError</code></p>
+    GatewayInternalError,
+    /// <p><code>GatewayNotConnected</code></p>
+    GatewayNotConnected,
+    /// <p><code>GatewayNotFound</code></p>
+    GatewayNotFound,
+    /// <p><code>GatewayProxyNetworkConnectionBusy</code></p>
+    GatewayProxyNetworkConnectionBusy,
+    /// <p><code>AuthenticationFailure</code></p>
+    AuthenticationFailure,
+    /// <p><code>BandwidthThrottleScheduleNotFound</code></p>
+    BandwidthThrottleScheduleNotFound,
+    /// <p><code>Blocked</code></p>
+    Blocked,
+    /// <p><code>CannotExportSnapshot</code></p>
+    CannotExportSnapshot,
+    /// <p><code>ChapCredentialNotFound</code></p>
+    ChapCredentialNotFound,
+    /// <p><code>DiskAlreadyAllocated</code></p>
+    DiskAlreadyAllocated,
+    /// <p><code>DiskDoesNotExist</code></p>
+    DiskDoesNotExist,
+    /// <p><code>DiskSizeGreaterThanVolumeMaxSize</code></p>
+    DiskSizeGreaterThanVolumeMaxSize,
+    /// <p><code>DiskSizeLessThanVolumeSize</code></p>
+    DiskSizeLessThanVolumeSize,
+    /// <p><code>DiskSizeNotGigAligned</code></p>
+    DiskSizeNotGigAligned,
+    /// <p><code>DuplicateCertificateInfo</code></p>
+    DuplicateCertificateInfo,
+    /// <p><code>DuplicateSchedule</code></p>
+    DuplicateSchedule,
+    /// <p><code>EndpointNotFound</code></p>
+    EndpointNotFound,
+    /// <p><code>IAMNotSupported</code></p>
+    IAMNotSupported,
+    /// <p><code>InitiatorInvalid</code></p>
+    InitiatorInvalid,
+    /// <p><code>InitiatorNotFound</code></p>
+    InitiatorNotFound,
+    /// <p><code>InternalError</code></p>
+    InternalError,
+    /// <p><code>InvalidGateway</code></p>
+    InvalidGateway,
+    /// <p><code>InvalidEndpoint</code></p>
+    InvalidEndpoint,
+    /// <p><code>InvalidParameters</code></p>
+    InvalidParameters,
+    /// <p><code>InvalidSchedule</code></p>
+    InvalidSchedule,
+    /// <p><code>LocalStorageLimitExceeded</code></p>
+    LocalStorageLimitExceeded,
+    /// <p><code>LunAlreadyAllocated </code></p>
+    LunAlreadyAllocated,
+    /// <p><code>LunInvalid</code></p>
+    LunInvalid,
+    /// <p><code>JoinDomainInProgress</code></p>
+    JoinDomainInProgress,
+    /// <p><code>MaximumContentLengthExceeded</code></p>
+    MaximumContentLengthExceeded,
+    /// <p><code>MaximumTapeCartridgeCountExceeded</code></p>
+    MaximumTapeCartridgeCountExceeded,
+    /// <p><code>MaximumVolumeCountExceeded</code></p>
+    MaximumVolumeCountExceeded,
+    /// <p><code>NetworkConfigurationChanged</code></p>
+    NetworkConfigurationChanged,
+    /// <p><code>NoDisksAvailable</code></p>
+    NoDisksAvailable,
+    /// <p><code>NotImplemented</code></p>
+    NotImplemented,
+    /// <p><code>NotSupported</code></p>
+    NotSupported,
+    /// <p><code>OperationAborted</code></p>
+    OperationAborted,
+    /// <p><code>OutdatedGateway</code></p>
+    OutdatedGateway,
+    /// <p><code>ParametersNotImplemented</code></p>
+    ParametersNotImplemented,
+    /// <p><code>RegionInvalid</code></p>
+    RegionInvalid,
+    /// <p><code>RequestTimeout</code></p>
+    RequestTimeout,
+    /// <p><code>ServiceUnavailable</code></p>
+    ServiceUnavailable,
+    /// <p><code>SnapshotDeleted</code></p>
+    SnapshotDeleted,
+    /// <p><code>SnapshotIdInvalid</code></p>
+    SnapshotIdInvalid,
+    /// <p><code>SnapshotInProgress</code></p>
+    SnapshotInProgress,
+    /// <p><code>SnapshotNotFound</code></p>
+    SnapshotNotFound,
+    /// <p><code>SnapshotScheduleNotFound</code></p>
+    SnapshotScheduleNotFound,
+    /// <p><code>StagingAreaFull</code></p>
+    StagingAreaFull,
+    /// <p><code>StorageFailure</code></p>
+    StorageFailure,
+    /// <p><code>TapeCartridgeNotFound</code></p>
+    TapeCartridgeNotFound,
+    /// <p><code>TargetAlreadyExists</code></p>
+    TargetAlreadyExists,
+    /// <p><code>TargetInvalid</code></p>
+    TargetInvalid,
+    /// <p><code>TargetNotFound</code></p>
+    TargetNotFound,
+    /// <p><code>UnauthorizedOperation</code></p>
+    UnauthorizedOperation,
+    /// <p><code>VolumeAlreadyExists</code></p>
+    VolumeAlreadyExists,
+    /// <p><code>VolumeIdInvalid</code></p>
+    VolumeIdInvalid,
+    /// <p><code>VolumeInUse</code></p>
+    VolumeInUse,
+    /// <p><code>VolumeNotFound</code></p>
+    VolumeNotFound,
+    /// <p><code>VolumeNotReady</code></p>
+    VolumeNotReady,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::ActivationKeyExpired => "ActivationKeyExpired",
+            ErrorCode::ActivationKeyInvalid => "ActivationKeyInvalid",
+            ErrorCode::ActivationKeyNotFound => "ActivationKeyNotFound",
+            ErrorCode::GatewayInternalError => "GatewayInternalError",
+            ErrorCode::GatewayNotConnected => "GatewayNotConnected",
+            ErrorCode::GatewayNotFound => "GatewayNotFound",
+            ErrorCode::GatewayProxyNetworkConnectionBusy => "GatewayProxyNetworkConnectionBusy",
+            ErrorCode::AuthenticationFailure => "AuthenticationFailure",
+            ErrorCode::BandwidthThrottleScheduleNotFound => "BandwidthThrottleScheduleNotFound",
+            ErrorCode::Blocked => "Blocked",
+            ErrorCode::CannotExportSnapshot => "CannotExportSnapshot",
+            ErrorCode::ChapCredentialNotFound => "ChapCredentialNotFound",
+            ErrorCode::DiskAlreadyAllocated => "DiskAlreadyAllocated",
+            ErrorCode::DiskDoesNotExist => "DiskDoesNotExist",
+            ErrorCode::DiskSizeGreaterThanVolumeMaxSize => "DiskSizeGreaterThanVolumeMaxSize",
+            ErrorCode::DiskSizeLessThanVolumeSize => "DiskSizeLessThanVolumeSize",
+            ErrorCode::DiskSizeNotGigAligned => "DiskSizeNotGigAligned",
+            ErrorCode::DuplicateCertificateInfo => "DuplicateCertificateInfo",
+            ErrorCode::DuplicateSchedule => "DuplicateSchedule",
+            ErrorCode::EndpointNotFound => "EndpointNotFound",
+            ErrorCode::IAMNotSupported => "IAMNotSupported",
+            ErrorCode::InitiatorInvalid => "InitiatorInvalid",
+            ErrorCode::InitiatorNotFound => "InitiatorNotFound",
+            ErrorCode::InternalError => "InternalError",
+            ErrorCode::InvalidGateway => "InvalidGateway",
+            ErrorCode::InvalidEndpoint => "InvalidEndpoint",
+            ErrorCode::InvalidParameters => "InvalidParameters",
+            ErrorCode::InvalidSchedule => "InvalidSchedule",
+            ErrorCode::LocalStorageLimitExceeded => "LocalStorageLimitExceeded",
+            ErrorCode::LunAlreadyAllocated => "LunAlreadyAllocated ",
+            ErrorCode::LunInvalid => "LunInvalid",
+            ErrorCode::JoinDomainInProgress => "JoinDomainInProgress",
+            ErrorCode::MaximumContentLengthExceeded => "MaximumContentLengthExceeded",
+            ErrorCode::MaximumTapeCartridgeCountExceeded => "MaximumTapeCartridgeCountExceeded",
+            ErrorCode::MaximumVolumeCountExceeded => "MaximumVolumeCountExceeded",
+            ErrorCode::NetworkConfigurationChanged => "NetworkConfigurationChanged",
+            ErrorCode::NoDisksAvailable => "NoDisksAvailable",
+            ErrorCode::NotImplemented => "NotImplemented",
+            ErrorCode::NotSupported => "NotSupported",
+            ErrorCode::OperationAborted => "OperationAborted",
+            ErrorCode::OutdatedGateway => "OutdatedGateway",
+            ErrorCode::ParametersNotImplemented => "ParametersNotImplemented",
+            ErrorCode::RegionInvalid => "RegionInvalid",
+            ErrorCode::RequestTimeout => "RequestTimeout",
+            ErrorCode::ServiceUnavailable => "ServiceUnavailable",
+            ErrorCode::SnapshotDeleted => "SnapshotDeleted",
+            ErrorCode::SnapshotIdInvalid => "SnapshotIdInvalid",
+            ErrorCode::SnapshotInProgress => "SnapshotInProgress",
+            ErrorCode::SnapshotNotFound => "SnapshotNotFound",
+            ErrorCode::SnapshotScheduleNotFound => "SnapshotScheduleNotFound",
+            ErrorCode::StagingAreaFull => "StagingAreaFull",
+            ErrorCode::StorageFailure => "StorageFailure",
+            ErrorCode::TapeCartridgeNotFound => "TapeCartridgeNotFound",
+            ErrorCode::TargetAlreadyExists => "TargetAlreadyExists",
+            ErrorCode::TargetInvalid => "TargetInvalid",
+            ErrorCode::TargetNotFound => "TargetNotFound",
+            ErrorCode::UnauthorizedOperation => "UnauthorizedOperation",
+            ErrorCode::VolumeAlreadyExists => "VolumeAlreadyExists",
+            ErrorCode::VolumeIdInvalid => "VolumeIdInvalid",
+            ErrorCode::VolumeInUse => "VolumeInUse",
+            ErrorCode::VolumeNotFound => "VolumeNotFound",
+            ErrorCode::VolumeNotReady => "VolumeNotReady",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "ActivationKeyExpired" => ErrorCode::ActivationKeyExpired,
+            "ActivationKeyInvalid" => ErrorCode::ActivationKeyInvalid,
+            "ActivationKeyNotFound" => ErrorCode::ActivationKeyNotFound,
+            "GatewayInternalError" => ErrorCode::GatewayInternalError,
+            "GatewayNotConnected" => ErrorCode::GatewayNotConnected,
+            "GatewayNotFound" => ErrorCode::GatewayNotFound,
+            "GatewayProxyNetworkConnectionBusy" => ErrorCode::GatewayProxyNetworkConnectionBusy,
+            "AuthenticationFailure" => ErrorCode::AuthenticationFailure,
+            "BandwidthThrottleScheduleNotFound" => ErrorCode::BandwidthThrottleScheduleNotFound,
+            "Blocked" => ErrorCode::Blocked,
+            "CannotExportSnapshot" => ErrorCode::CannotExportSnapshot,
+            "ChapCredentialNotFound" => ErrorCode::ChapCredentialNotFound,
+            "DiskAlreadyAllocated" => ErrorCode::DiskAlreadyAllocated,
+            "DiskDoesNotExist" => ErrorCode::DiskDoesNotExist,
+            "DiskSizeGreaterThanVolumeMaxSize" => ErrorCode::DiskSizeGreaterThanVolumeMaxSize,
+            "DiskSizeLessThanVolumeSize" => ErrorCode::DiskSizeLessThanVolumeSize,
+            "DiskSizeNotGigAligned" => ErrorCode::DiskSizeNotGigAligned,
+            "DuplicateCertificateInfo" => ErrorCode::DuplicateCertificateInfo,
+            "DuplicateSchedule" => ErrorCode::DuplicateSchedule,
+            "EndpointNotFound" => ErrorCode::EndpointNotFound,
+            "IAMNotSupported" => ErrorCode::IAMNotSupported,
+            "InitiatorInvalid" => ErrorCode::InitiatorInvalid,
+            "InitiatorNotFound" => ErrorCode::InitiatorNotFound,
+            "InternalError" => ErrorCode::InternalError,
+            "InvalidGateway" => ErrorCode::InvalidGateway,
+            "InvalidEndpoint" => ErrorCode::InvalidEndpoint,
+            "InvalidParameters" => ErrorCode::InvalidParameters,
+            "InvalidSchedule" => ErrorCode::InvalidSchedule,
+            "LocalStorageLimitExceeded" => ErrorCode::LocalStorageLimitExceeded,
+            "LunAlreadyAllocated " => ErrorCode::LunAlreadyAllocated,
+            "LunInvalid" => ErrorCode::LunInvalid,
+            "JoinDomainInProgress" => ErrorCode::JoinDomainInProgress,
+            "MaximumContentLengthExceeded" => ErrorCode::MaximumContentLengthExceeded,
+            "MaximumTapeCartridgeCountExceeded" => ErrorCode::MaximumTapeCartridgeCountExceeded,
+            "MaximumVolumeCountExceeded" => ErrorCode::MaximumVolumeCountExceeded,
+            "NetworkConfigurationChanged" => ErrorCode::NetworkConfigurationChanged,
+            "NoDisksAvailable" => ErrorCode::NoDisksAvailable,
+            "NotImplemented" => ErrorCode::NotImplemented,
+            "NotSupported" => ErrorCode::NotSupported,
+            "OperationAborted" => ErrorCode::OperationAborted,
+            "OutdatedGateway" => ErrorCode::OutdatedGateway,
+            "ParametersNotImplemented" => ErrorCode::ParametersNotImplemented,
+            "RegionInvalid" => ErrorCode::RegionInvalid,
+            "RequestTimeout" => ErrorCode::RequestTimeout,
+            "ServiceUnavailable" => ErrorCode::ServiceUnavailable,
+            "SnapshotDeleted" => ErrorCode::SnapshotDeleted,
+            "SnapshotIdInvalid" => ErrorCode::SnapshotIdInvalid,
+            "SnapshotInProgress" => ErrorCode::SnapshotInProgress,
+            "SnapshotNotFound" => ErrorCode::SnapshotNotFound,
+            "SnapshotScheduleNotFound" => ErrorCode::SnapshotScheduleNotFound,
+            "StagingAreaFull" => ErrorCode::StagingAreaFull,
+            "StorageFailure" => ErrorCode::StorageFailure,
+            "TapeCartridgeNotFound" => ErrorCode::TapeCartridgeNotFound,
+            "TargetAlreadyExists" => ErrorCode::TargetAlreadyExists,
+            "TargetInvalid" => ErrorCode::TargetInvalid,
+            "TargetNotFound" => ErrorCode::TargetNotFound,
+            "UnauthorizedOperation" => ErrorCode::UnauthorizedOperation,
+            "VolumeAlreadyExists" => ErrorCode::VolumeAlreadyExists,
+            "VolumeIdInvalid" => ErrorCode::VolumeIdInvalid,
+            "VolumeInUse" => ErrorCode::VolumeInUse,
+            "VolumeNotFound" => ErrorCode::VolumeNotFound,
+            "VolumeNotReady" => ErrorCode::VolumeNotReady,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        ErrorCode::from(value.as_str())
+    }
+}
+
+impl From<ErrorCode> for String {
+    fn from(value: ErrorCode) -> String {
+        match value {
+            ErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ErrorCode::from(value))
+    }
+}
+
 /// <p>Describes a file share.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1483,12 +4022,84 @@ pub struct FileShareInfo {
     pub file_share_status: Option<String>,
     #[serde(rename = "FileShareType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_share_type: Option<String>,
+    pub file_share_type: Option<FileShareType>,
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gateway_arn: Option<String>,
 }
 
+/// <p>The type of the file share.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FileShareType {
+    /// <p><code>NFS</code></p>
+    Nfs,
+    /// <p><code>SMB</code></p>
+    Smb,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FileShareType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileShareType::Nfs => "NFS",
+            FileShareType::Smb => "SMB",
+            FileShareType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FileShareType {
+    fn from(value: &'a str) -> FileShareType {
+        match value {
+            "NFS" => FileShareType::Nfs,
+            "SMB" => FileShareType::Smb,
+            _ => FileShareType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileShareType {
+    fn from(value: String) -> FileShareType {
+        FileShareType::from(value.as_str())
+    }
+}
+
+impl From<FileShareType> for String {
+    fn from(value: FileShareType) -> String {
+        match value {
+            FileShareType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FileShareType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FileShareType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileShareType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FileShareType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FileShareType::from(value))
+    }
+}
+
 /// <p>Describes a gateway object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1548,6 +4159,82 @@ pub struct JoinDomainInput {
     pub user_name: String,
 }
 
+impl JoinDomainInput {
+    /// Returns a builder for `JoinDomainInput`, checking its required members when built.
+    pub fn builder() -> JoinDomainInputBuilder {
+        JoinDomainInputBuilder::default()
+    }
+}
+
+/// Builds a [`JoinDomainInput`](struct.JoinDomainInput.html).
+#[derive(Default, Debug)]
+pub struct JoinDomainInputBuilder {
+    domain_controllers: Option<Vec<String>>,
+    domain_name: Option<String>,
+    gateway_arn: Option<String>,
+    organizational_unit: Option<String>,
+    password: Option<String>,
+    user_name: Option<String>,
+}
+
+impl JoinDomainInputBuilder {
+    /// Sets the [`domain_controllers`](struct.JoinDomainInput.html#structfield.domain_controllers) member.
+    pub fn domain_controllers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.domain_controllers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.JoinDomainInput.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.JoinDomainInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organizational_unit`](struct.JoinDomainInput.html#structfield.organizational_unit) member.
+    pub fn organizational_unit<T: Into<String>>(mut self, value: T) -> Self {
+        self.organizational_unit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.JoinDomainInput.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.JoinDomainInput.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `JoinDomainInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<JoinDomainInput, ::rusoto_core::BuildError> {
+        Ok(JoinDomainInput {
+            domain_controllers: self.domain_controllers,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            organizational_unit: self.organizational_unit,
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// <p>JoinDomainOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1575,8 +4262,52 @@ pub struct ListFileSharesInput {
     pub marker: Option<String>,
 }
 
-/// <p>ListFileShareOutput</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl ListFileSharesInput {
+    /// Returns a builder for `ListFileSharesInput`, checking its required members when built.
+    pub fn builder() -> ListFileSharesInputBuilder {
+        ListFileSharesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListFileSharesInput`](struct.ListFileSharesInput.html).
+#[derive(Default, Debug)]
+pub struct ListFileSharesInputBuilder {
+    gateway_arn: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl ListFileSharesInputBuilder {
+    /// Sets the [`gateway_arn`](struct.ListFileSharesInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListFileSharesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListFileSharesInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFileSharesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListFileSharesInput, ::rusoto_core::BuildError> {
+        Ok(ListFileSharesInput {
+            gateway_arn: self.gateway_arn,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
+/// <p>ListFileShareOutput</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFileSharesOutput {
     /// <p>An array of information about the file gateway's file shares. </p>
@@ -1606,6 +4337,42 @@ pub struct ListGatewaysInput {
     pub marker: Option<String>,
 }
 
+impl ListGatewaysInput {
+    /// Returns a builder for `ListGatewaysInput`, checking its required members when built.
+    pub fn builder() -> ListGatewaysInputBuilder {
+        ListGatewaysInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListGatewaysInput`](struct.ListGatewaysInput.html).
+#[derive(Default, Debug)]
+pub struct ListGatewaysInputBuilder {
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl ListGatewaysInputBuilder {
+    /// Sets the [`limit`](struct.ListGatewaysInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListGatewaysInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGatewaysInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListGatewaysInput, ::rusoto_core::BuildError> {
+        Ok(ListGatewaysInput {
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGatewaysOutput {
@@ -1626,6 +4393,36 @@ pub struct ListLocalDisksInput {
     pub gateway_arn: String,
 }
 
+impl ListLocalDisksInput {
+    /// Returns a builder for `ListLocalDisksInput`, checking its required members when built.
+    pub fn builder() -> ListLocalDisksInputBuilder {
+        ListLocalDisksInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListLocalDisksInput`](struct.ListLocalDisksInput.html).
+#[derive(Default, Debug)]
+pub struct ListLocalDisksInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl ListLocalDisksInputBuilder {
+    /// Sets the [`gateway_arn`](struct.ListLocalDisksInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLocalDisksInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListLocalDisksInput, ::rusoto_core::BuildError> {
+        Ok(ListLocalDisksInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLocalDisksOutput {
@@ -1654,6 +4451,54 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceInput {
+    /// Returns a builder for `ListTagsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceInputBuilder {
+        ListTagsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceInput`](struct.ListTagsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceInputBuilder {
+    limit: Option<i64>,
+    marker: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceInputBuilder {
+    /// Sets the [`limit`](struct.ListTagsForResourceInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListTagsForResourceInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceInput {
+            limit: self.limit,
+            marker: self.marker,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 /// <p>ListTagsForResourceOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1688,6 +4533,50 @@ pub struct ListTapesInput {
     pub tape_ar_ns: Option<Vec<String>>,
 }
 
+impl ListTapesInput {
+    /// Returns a builder for `ListTapesInput`, checking its required members when built.
+    pub fn builder() -> ListTapesInputBuilder {
+        ListTapesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTapesInput`](struct.ListTapesInput.html).
+#[derive(Default, Debug)]
+pub struct ListTapesInputBuilder {
+    limit: Option<i64>,
+    marker: Option<String>,
+    tape_ar_ns: Option<Vec<String>>,
+}
+
+impl ListTapesInputBuilder {
+    /// Sets the [`limit`](struct.ListTapesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListTapesInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_ar_ns`](struct.ListTapesInput.html#structfield.tape_ar_ns) member.
+    pub fn tape_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tape_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTapesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTapesInput, ::rusoto_core::BuildError> {
+        Ok(ListTapesInput {
+            limit: self.limit,
+            marker: self.marker,
+            tape_ar_ns: self.tape_ar_ns,
+        })
+    }
+}
+
 /// <p><p>A JSON object containing the following fields:</p> <ul> <li> <p> <a>ListTapesOutput$Marker</a> </p> </li> <li> <p> <a>ListTapesOutput$VolumeInfos</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1709,6 +4598,38 @@ pub struct ListVolumeInitiatorsInput {
     pub volume_arn: String,
 }
 
+impl ListVolumeInitiatorsInput {
+    /// Returns a builder for `ListVolumeInitiatorsInput`, checking its required members when built.
+    pub fn builder() -> ListVolumeInitiatorsInputBuilder {
+        ListVolumeInitiatorsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListVolumeInitiatorsInput`](struct.ListVolumeInitiatorsInput.html).
+#[derive(Default, Debug)]
+pub struct ListVolumeInitiatorsInputBuilder {
+    volume_arn: Option<String>,
+}
+
+impl ListVolumeInitiatorsInputBuilder {
+    /// Sets the [`volume_arn`](struct.ListVolumeInitiatorsInput.html#structfield.volume_arn) member.
+    pub fn volume_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVolumeInitiatorsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListVolumeInitiatorsInput, ::rusoto_core::BuildError> {
+        Ok(ListVolumeInitiatorsInput {
+            volume_arn: self
+                .volume_arn
+                .ok_or(::rusoto_core::BuildError::missing("volume_arn"))?,
+        })
+    }
+}
+
 /// <p>ListVolumeInitiatorsOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1725,6 +4646,38 @@ pub struct ListVolumeRecoveryPointsInput {
     pub gateway_arn: String,
 }
 
+impl ListVolumeRecoveryPointsInput {
+    /// Returns a builder for `ListVolumeRecoveryPointsInput`, checking its required members when built.
+    pub fn builder() -> ListVolumeRecoveryPointsInputBuilder {
+        ListVolumeRecoveryPointsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListVolumeRecoveryPointsInput`](struct.ListVolumeRecoveryPointsInput.html).
+#[derive(Default, Debug)]
+pub struct ListVolumeRecoveryPointsInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl ListVolumeRecoveryPointsInputBuilder {
+    /// Sets the [`gateway_arn`](struct.ListVolumeRecoveryPointsInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVolumeRecoveryPointsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListVolumeRecoveryPointsInput, ::rusoto_core::BuildError> {
+        Ok(ListVolumeRecoveryPointsInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListVolumeRecoveryPointsOutput {
@@ -1753,6 +4706,50 @@ pub struct ListVolumesInput {
     pub marker: Option<String>,
 }
 
+impl ListVolumesInput {
+    /// Returns a builder for `ListVolumesInput`, checking its required members when built.
+    pub fn builder() -> ListVolumesInputBuilder {
+        ListVolumesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListVolumesInput`](struct.ListVolumesInput.html).
+#[derive(Default, Debug)]
+pub struct ListVolumesInputBuilder {
+    gateway_arn: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl ListVolumesInputBuilder {
+    /// Sets the [`gateway_arn`](struct.ListVolumesInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListVolumesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListVolumesInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVolumesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListVolumesInput, ::rusoto_core::BuildError> {
+        Ok(ListVolumesInput {
+            gateway_arn: self.gateway_arn,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 /// <p><p>A JSON object containing the following fields:</p> <ul> <li> <p> <a>ListVolumesOutput$Marker</a> </p> </li> <li> <p> <a>ListVolumesOutput$VolumeInfos</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1833,7 +4830,7 @@ pub struct NFSFileShareInfo {
     pub nfs_file_share_defaults: Option<NFSFileShareDefaults>,
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     #[serde(rename = "Path")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
@@ -1881,6 +4878,38 @@ pub struct NotifyWhenUploadedInput {
     pub file_share_arn: String,
 }
 
+impl NotifyWhenUploadedInput {
+    /// Returns a builder for `NotifyWhenUploadedInput`, checking its required members when built.
+    pub fn builder() -> NotifyWhenUploadedInputBuilder {
+        NotifyWhenUploadedInputBuilder::default()
+    }
+}
+
+/// Builds a [`NotifyWhenUploadedInput`](struct.NotifyWhenUploadedInput.html).
+#[derive(Default, Debug)]
+pub struct NotifyWhenUploadedInputBuilder {
+    file_share_arn: Option<String>,
+}
+
+impl NotifyWhenUploadedInputBuilder {
+    /// Sets the [`file_share_arn`](struct.NotifyWhenUploadedInput.html#structfield.file_share_arn) member.
+    pub fn file_share_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_share_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `NotifyWhenUploadedInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<NotifyWhenUploadedInput, ::rusoto_core::BuildError> {
+        Ok(NotifyWhenUploadedInput {
+            file_share_arn: self
+                .file_share_arn
+                .ok_or(::rusoto_core::BuildError::missing("file_share_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct NotifyWhenUploadedOutput {
@@ -1892,6 +4921,98 @@ pub struct NotifyWhenUploadedOutput {
     pub notification_id: Option<String>,
 }
 
+/// <p>A value that sets the access control list (ACL) permission for objects in the S3 bucket that an S3 File Gateway puts objects into. The default value is <code>private</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ObjectACL {
+    /// <p><code>private</code></p>
+    Private,
+    /// <p><code>public-read</code></p>
+    PublicRead,
+    /// <p><code>public-read-write</code></p>
+    PublicReadWrite,
+    /// <p><code>authenticated-read</code></p>
+    AuthenticatedRead,
+    /// <p><code>bucket-owner-read</code></p>
+    BucketOwnerRead,
+    /// <p><code>bucket-owner-full-control</code></p>
+    BucketOwnerFullControl,
+    /// <p><code>aws-exec-read</code></p>
+    AwsExecRead,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ObjectACL {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectACL::Private => "private",
+            ObjectACL::PublicRead => "public-read",
+            ObjectACL::PublicReadWrite => "public-read-write",
+            ObjectACL::AuthenticatedRead => "authenticated-read",
+            ObjectACL::BucketOwnerRead => "bucket-owner-read",
+            ObjectACL::BucketOwnerFullControl => "bucket-owner-full-control",
+            ObjectACL::AwsExecRead => "aws-exec-read",
+            ObjectACL::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ObjectACL {
+    fn from(value: &'a str) -> ObjectACL {
+        match value {
+            "private" => ObjectACL::Private,
+            "public-read" => ObjectACL::PublicRead,
+            "public-read-write" => ObjectACL::PublicReadWrite,
+            "authenticated-read" => ObjectACL::AuthenticatedRead,
+            "bucket-owner-read" => ObjectACL::BucketOwnerRead,
+            "bucket-owner-full-control" => ObjectACL::BucketOwnerFullControl,
+            "aws-exec-read" => ObjectACL::AwsExecRead,
+            _ => ObjectACL::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectACL {
+    fn from(value: String) -> ObjectACL {
+        ObjectACL::from(value.as_str())
+    }
+}
+
+impl From<ObjectACL> for String {
+    fn from(value: ObjectACL) -> String {
+        match value {
+            ObjectACL::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ObjectACL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ObjectACL {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ObjectACL {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ObjectACL, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ObjectACL::from(value))
+    }
+}
+
 /// <p>RefreshCacheInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RefreshCacheInput {
@@ -1908,6 +5029,52 @@ pub struct RefreshCacheInput {
     pub recursive: Option<bool>,
 }
 
+impl RefreshCacheInput {
+    /// Returns a builder for `RefreshCacheInput`, checking its required members when built.
+    pub fn builder() -> RefreshCacheInputBuilder {
+        RefreshCacheInputBuilder::default()
+    }
+}
+
+/// Builds a [`RefreshCacheInput`](struct.RefreshCacheInput.html).
+#[derive(Default, Debug)]
+pub struct RefreshCacheInputBuilder {
+    file_share_arn: Option<String>,
+    folder_list: Option<Vec<String>>,
+    recursive: Option<bool>,
+}
+
+impl RefreshCacheInputBuilder {
+    /// Sets the [`file_share_arn`](struct.RefreshCacheInput.html#structfield.file_share_arn) member.
+    pub fn file_share_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_share_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`folder_list`](struct.RefreshCacheInput.html#structfield.folder_list) member.
+    pub fn folder_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.folder_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recursive`](struct.RefreshCacheInput.html#structfield.recursive) member.
+    pub fn recursive<T: Into<bool>>(mut self, value: T) -> Self {
+        self.recursive = Some(value.into());
+        self
+    }
+
+    /// Builds the `RefreshCacheInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RefreshCacheInput, ::rusoto_core::BuildError> {
+        Ok(RefreshCacheInput {
+            file_share_arn: self
+                .file_share_arn
+                .ok_or(::rusoto_core::BuildError::missing("file_share_arn"))?,
+            folder_list: self.folder_list,
+            recursive: self.recursive,
+        })
+    }
+}
+
 /// <p>RefreshCacheOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1931,6 +5098,48 @@ pub struct RemoveTagsFromResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl RemoveTagsFromResourceInput {
+    /// Returns a builder for `RemoveTagsFromResourceInput`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromResourceInputBuilder {
+        RemoveTagsFromResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromResourceInput`](struct.RemoveTagsFromResourceInput.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromResourceInputBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl RemoveTagsFromResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.RemoveTagsFromResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.RemoveTagsFromResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromResourceInput, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsFromResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p>RemoveTagsFromResourceOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1947,6 +5156,36 @@ pub struct ResetCacheInput {
     pub gateway_arn: String,
 }
 
+impl ResetCacheInput {
+    /// Returns a builder for `ResetCacheInput`, checking its required members when built.
+    pub fn builder() -> ResetCacheInputBuilder {
+        ResetCacheInputBuilder::default()
+    }
+}
+
+/// Builds a [`ResetCacheInput`](struct.ResetCacheInput.html).
+#[derive(Default, Debug)]
+pub struct ResetCacheInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl ResetCacheInputBuilder {
+    /// Sets the [`gateway_arn`](struct.ResetCacheInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetCacheInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ResetCacheInput, ::rusoto_core::BuildError> {
+        Ok(ResetCacheInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ResetCacheOutput {
@@ -1966,6 +5205,48 @@ pub struct RetrieveTapeArchiveInput {
     pub tape_arn: String,
 }
 
+impl RetrieveTapeArchiveInput {
+    /// Returns a builder for `RetrieveTapeArchiveInput`, checking its required members when built.
+    pub fn builder() -> RetrieveTapeArchiveInputBuilder {
+        RetrieveTapeArchiveInputBuilder::default()
+    }
+}
+
+/// Builds a [`RetrieveTapeArchiveInput`](struct.RetrieveTapeArchiveInput.html).
+#[derive(Default, Debug)]
+pub struct RetrieveTapeArchiveInputBuilder {
+    gateway_arn: Option<String>,
+    tape_arn: Option<String>,
+}
+
+impl RetrieveTapeArchiveInputBuilder {
+    /// Sets the [`gateway_arn`](struct.RetrieveTapeArchiveInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_arn`](struct.RetrieveTapeArchiveInput.html#structfield.tape_arn) member.
+    pub fn tape_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tape_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RetrieveTapeArchiveInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RetrieveTapeArchiveInput, ::rusoto_core::BuildError> {
+        Ok(RetrieveTapeArchiveInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            tape_arn: self
+                .tape_arn
+                .ok_or(::rusoto_core::BuildError::missing("tape_arn"))?,
+        })
+    }
+}
+
 /// <p>RetrieveTapeArchiveOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1986,6 +5267,48 @@ pub struct RetrieveTapeRecoveryPointInput {
     pub tape_arn: String,
 }
 
+impl RetrieveTapeRecoveryPointInput {
+    /// Returns a builder for `RetrieveTapeRecoveryPointInput`, checking its required members when built.
+    pub fn builder() -> RetrieveTapeRecoveryPointInputBuilder {
+        RetrieveTapeRecoveryPointInputBuilder::default()
+    }
+}
+
+/// Builds a [`RetrieveTapeRecoveryPointInput`](struct.RetrieveTapeRecoveryPointInput.html).
+#[derive(Default, Debug)]
+pub struct RetrieveTapeRecoveryPointInputBuilder {
+    gateway_arn: Option<String>,
+    tape_arn: Option<String>,
+}
+
+impl RetrieveTapeRecoveryPointInputBuilder {
+    /// Sets the [`gateway_arn`](struct.RetrieveTapeRecoveryPointInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tape_arn`](struct.RetrieveTapeRecoveryPointInput.html#structfield.tape_arn) member.
+    pub fn tape_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tape_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RetrieveTapeRecoveryPointInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RetrieveTapeRecoveryPointInput, ::rusoto_core::BuildError> {
+        Ok(RetrieveTapeRecoveryPointInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            tape_arn: self
+                .tape_arn
+                .ok_or(::rusoto_core::BuildError::missing("tape_arn"))?,
+        })
+    }
+}
+
 /// <p>RetrieveTapeRecoveryPointOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2043,7 +5366,7 @@ pub struct SMBFileShareInfo {
     pub location_arn: Option<String>,
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     /// <p>The file share path used by the SMB client to identify the mount point.</p>
     #[serde(rename = "Path")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2073,6 +5396,82 @@ pub struct SMBFileShareInfo {
     pub valid_user_list: Option<Vec<String>>,
 }
 
+/// <p>The type of security strategy that was specified for file gateway.</p> <p>ClientSpecified: if you use this option, requests are established based on what is negotiated by the client. This option is recommended when you want to maximize compatibility across different clients in your environment. </p> <p>MandatorySigning: if you use this option, file gateway only allows connections from SMBv2 or SMBv3 clients that have signing enabled. This option works with SMB clients on Microsoft Windows Vista, Windows Server 2008 or newer. </p> <p>MandatoryEncryption: if you use this option, file gateway only allows connections from SMBv3 clients that have encryption enabled. This option is highly recommended for environments that handle sensitive data. This option works with SMB clients on Microsoft Windows 8, Windows Server 2012 or newer. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SMBSecurityStrategy {
+    /// <p><code>ClientSpecified</code></p>
+    ClientSpecified,
+    /// <p><code>MandatorySigning</code></p>
+    MandatorySigning,
+    /// <p><code>MandatoryEncryption</code></p>
+    MandatoryEncryption,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SMBSecurityStrategy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SMBSecurityStrategy::ClientSpecified => "ClientSpecified",
+            SMBSecurityStrategy::MandatorySigning => "MandatorySigning",
+            SMBSecurityStrategy::MandatoryEncryption => "MandatoryEncryption",
+            SMBSecurityStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SMBSecurityStrategy {
+    fn from(value: &'a str) -> SMBSecurityStrategy {
+        match value {
+            "ClientSpecified" => SMBSecurityStrategy::ClientSpecified,
+            "MandatorySigning" => SMBSecurityStrategy::MandatorySigning,
+            "MandatoryEncryption" => SMBSecurityStrategy::MandatoryEncryption,
+            _ => SMBSecurityStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SMBSecurityStrategy {
+    fn from(value: String) -> SMBSecurityStrategy {
+        SMBSecurityStrategy::from(value.as_str())
+    }
+}
+
+impl From<SMBSecurityStrategy> for String {
+    fn from(value: SMBSecurityStrategy) -> String {
+        match value {
+            SMBSecurityStrategy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SMBSecurityStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SMBSecurityStrategy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SMBSecurityStrategy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SMBSecurityStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SMBSecurityStrategy::from(value))
+    }
+}
+
 /// <p>SetLocalConsolePasswordInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SetLocalConsolePasswordInput {
@@ -2083,17 +5482,59 @@ pub struct SetLocalConsolePasswordInput {
     pub local_console_password: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct SetLocalConsolePasswordOutput {
-    #[serde(rename = "GatewayARN")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gateway_arn: Option<String>,
+impl SetLocalConsolePasswordInput {
+    /// Returns a builder for `SetLocalConsolePasswordInput`, checking its required members when built.
+    pub fn builder() -> SetLocalConsolePasswordInputBuilder {
+        SetLocalConsolePasswordInputBuilder::default()
+    }
 }
 
-/// <p>SetSMBGuestPasswordInput</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct SetSMBGuestPasswordInput {
+/// Builds a [`SetLocalConsolePasswordInput`](struct.SetLocalConsolePasswordInput.html).
+#[derive(Default, Debug)]
+pub struct SetLocalConsolePasswordInputBuilder {
+    gateway_arn: Option<String>,
+    local_console_password: Option<String>,
+}
+
+impl SetLocalConsolePasswordInputBuilder {
+    /// Sets the [`gateway_arn`](struct.SetLocalConsolePasswordInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`local_console_password`](struct.SetLocalConsolePasswordInput.html#structfield.local_console_password) member.
+    pub fn local_console_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.local_console_password = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetLocalConsolePasswordInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetLocalConsolePasswordInput, ::rusoto_core::BuildError> {
+        Ok(SetLocalConsolePasswordInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            local_console_password: self
+                .local_console_password
+                .ok_or(::rusoto_core::BuildError::missing("local_console_password"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct SetLocalConsolePasswordOutput {
+    #[serde(rename = "GatewayARN")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gateway_arn: Option<String>,
+}
+
+/// <p>SetSMBGuestPasswordInput</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct SetSMBGuestPasswordInput {
     /// <p>The Amazon Resource Name (ARN) of the file gateway the SMB file share is associated with.</p>
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
@@ -2102,6 +5543,48 @@ pub struct SetSMBGuestPasswordInput {
     pub password: String,
 }
 
+impl SetSMBGuestPasswordInput {
+    /// Returns a builder for `SetSMBGuestPasswordInput`, checking its required members when built.
+    pub fn builder() -> SetSMBGuestPasswordInputBuilder {
+        SetSMBGuestPasswordInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetSMBGuestPasswordInput`](struct.SetSMBGuestPasswordInput.html).
+#[derive(Default, Debug)]
+pub struct SetSMBGuestPasswordInputBuilder {
+    gateway_arn: Option<String>,
+    password: Option<String>,
+}
+
+impl SetSMBGuestPasswordInputBuilder {
+    /// Sets the [`gateway_arn`](struct.SetSMBGuestPasswordInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.SetSMBGuestPasswordInput.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetSMBGuestPasswordInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetSMBGuestPasswordInput, ::rusoto_core::BuildError> {
+        Ok(SetSMBGuestPasswordInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SetSMBGuestPasswordOutput {
@@ -2117,6 +5600,36 @@ pub struct ShutdownGatewayInput {
     pub gateway_arn: String,
 }
 
+impl ShutdownGatewayInput {
+    /// Returns a builder for `ShutdownGatewayInput`, checking its required members when built.
+    pub fn builder() -> ShutdownGatewayInputBuilder {
+        ShutdownGatewayInputBuilder::default()
+    }
+}
+
+/// Builds a [`ShutdownGatewayInput`](struct.ShutdownGatewayInput.html).
+#[derive(Default, Debug)]
+pub struct ShutdownGatewayInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl ShutdownGatewayInputBuilder {
+    /// Sets the [`gateway_arn`](struct.ShutdownGatewayInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ShutdownGatewayInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ShutdownGatewayInput, ::rusoto_core::BuildError> {
+        Ok(ShutdownGatewayInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the of the gateway that was shut down.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2133,6 +5646,36 @@ pub struct StartGatewayInput {
     pub gateway_arn: String,
 }
 
+impl StartGatewayInput {
+    /// Returns a builder for `StartGatewayInput`, checking its required members when built.
+    pub fn builder() -> StartGatewayInputBuilder {
+        StartGatewayInputBuilder::default()
+    }
+}
+
+/// Builds a [`StartGatewayInput`](struct.StartGatewayInput.html).
+#[derive(Default, Debug)]
+pub struct StartGatewayInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl StartGatewayInputBuilder {
+    /// Sets the [`gateway_arn`](struct.StartGatewayInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartGatewayInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartGatewayInput, ::rusoto_core::BuildError> {
+        Ok(StartGatewayInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the of the gateway that was restarted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2146,7 +5689,7 @@ pub struct StartGatewayOutput {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StorageGatewayError {
     /// <p>Additional information about the error.</p>
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>Human-readable text that provides detail about the error that occurred.</p>
     pub error_details: Option<::std::collections::HashMap<String, String>>,
 }
@@ -2157,8 +5700,9 @@ pub struct StorageGatewayError {
 pub struct StorediSCSIVolume {
     /// <p>The date the volume was created. Volumes created prior to March 28, 2017 don’t have this time stamp.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     #[serde(rename = "KMSKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key: Option<String>,
@@ -2252,8 +5796,9 @@ pub struct Tape {
     pub tape_barcode: Option<String>,
     /// <p>The date the virtual tape was created.</p>
     #[serde(rename = "TapeCreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tape_created_date: Option<f64>,
+    pub tape_created_date: Option<EpochTimestamp>,
     /// <p>The size, in bytes, of the virtual tape capacity.</p>
     #[serde(rename = "TapeSizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2278,8 +5823,9 @@ pub struct Tape {
 pub struct TapeArchive {
     /// <p>The time that the archiving of the virtual tape was completed.</p> <p>The default time stamp format is in the ISO8601 extended YYYY-MM-DD'T'HH:MM:SS'Z' format.</p>
     #[serde(rename = "CompletionTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_time: Option<f64>,
+    pub completion_time: Option<EpochTimestamp>,
     #[serde(rename = "KMSKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key: Option<String>,
@@ -2301,8 +5847,9 @@ pub struct TapeArchive {
     pub tape_barcode: Option<String>,
     /// <p>The date the virtual tape was created.</p>
     #[serde(rename = "TapeCreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tape_created_date: Option<f64>,
+    pub tape_created_date: Option<EpochTimestamp>,
     /// <p>The size, in bytes, of the archived virtual tape.</p>
     #[serde(rename = "TapeSizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2357,8 +5904,9 @@ pub struct TapeRecoveryPointInfo {
     pub tape_arn: Option<String>,
     /// <p>The time when the point-in-time view of the virtual tape was replicated for later recovery.</p> <p>The default time stamp format of the tape recovery point time is in the ISO8601 extended YYYY-MM-DD'T'HH:MM:SS'Z' format.</p>
     #[serde(rename = "TapeRecoveryPointTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tape_recovery_point_time: Option<f64>,
+    pub tape_recovery_point_time: Option<EpochTimestamp>,
     /// <p>The size, in bytes, of the virtual tapes to recover.</p>
     #[serde(rename = "TapeSizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2384,6 +5932,56 @@ pub struct UpdateBandwidthRateLimitInput {
     pub gateway_arn: String,
 }
 
+impl UpdateBandwidthRateLimitInput {
+    /// Returns a builder for `UpdateBandwidthRateLimitInput`, checking its required members when built.
+    pub fn builder() -> UpdateBandwidthRateLimitInputBuilder {
+        UpdateBandwidthRateLimitInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateBandwidthRateLimitInput`](struct.UpdateBandwidthRateLimitInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateBandwidthRateLimitInputBuilder {
+    average_download_rate_limit_in_bits_per_sec: Option<i64>,
+    average_upload_rate_limit_in_bits_per_sec: Option<i64>,
+    gateway_arn: Option<String>,
+}
+
+impl UpdateBandwidthRateLimitInputBuilder {
+    /// Sets the [`average_download_rate_limit_in_bits_per_sec`](struct.UpdateBandwidthRateLimitInput.html#structfield.average_download_rate_limit_in_bits_per_sec) member.
+    pub fn average_download_rate_limit_in_bits_per_sec<T: Into<i64>>(mut self, value: T) -> Self {
+        self.average_download_rate_limit_in_bits_per_sec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`average_upload_rate_limit_in_bits_per_sec`](struct.UpdateBandwidthRateLimitInput.html#structfield.average_upload_rate_limit_in_bits_per_sec) member.
+    pub fn average_upload_rate_limit_in_bits_per_sec<T: Into<i64>>(mut self, value: T) -> Self {
+        self.average_upload_rate_limit_in_bits_per_sec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.UpdateBandwidthRateLimitInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateBandwidthRateLimitInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateBandwidthRateLimitInput, ::rusoto_core::BuildError> {
+        Ok(UpdateBandwidthRateLimitInput {
+            average_download_rate_limit_in_bits_per_sec: self
+                .average_download_rate_limit_in_bits_per_sec,
+            average_upload_rate_limit_in_bits_per_sec: self
+                .average_upload_rate_limit_in_bits_per_sec,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the of the gateway whose throttle information was updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2411,6 +6009,66 @@ pub struct UpdateChapCredentialsInput {
     pub target_arn: String,
 }
 
+impl UpdateChapCredentialsInput {
+    /// Returns a builder for `UpdateChapCredentialsInput`, checking its required members when built.
+    pub fn builder() -> UpdateChapCredentialsInputBuilder {
+        UpdateChapCredentialsInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateChapCredentialsInput`](struct.UpdateChapCredentialsInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateChapCredentialsInputBuilder {
+    initiator_name: Option<String>,
+    secret_to_authenticate_initiator: Option<String>,
+    secret_to_authenticate_target: Option<String>,
+    target_arn: Option<String>,
+}
+
+impl UpdateChapCredentialsInputBuilder {
+    /// Sets the [`initiator_name`](struct.UpdateChapCredentialsInput.html#structfield.initiator_name) member.
+    pub fn initiator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.initiator_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_to_authenticate_initiator`](struct.UpdateChapCredentialsInput.html#structfield.secret_to_authenticate_initiator) member.
+    pub fn secret_to_authenticate_initiator<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_to_authenticate_initiator = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_to_authenticate_target`](struct.UpdateChapCredentialsInput.html#structfield.secret_to_authenticate_target) member.
+    pub fn secret_to_authenticate_target<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_to_authenticate_target = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_arn`](struct.UpdateChapCredentialsInput.html#structfield.target_arn) member.
+    pub fn target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateChapCredentialsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateChapCredentialsInput, ::rusoto_core::BuildError> {
+        Ok(UpdateChapCredentialsInput {
+            initiator_name: self
+                .initiator_name
+                .ok_or(::rusoto_core::BuildError::missing("initiator_name"))?,
+            secret_to_authenticate_initiator: self.secret_to_authenticate_initiator.ok_or(
+                ::rusoto_core::BuildError::missing("secret_to_authenticate_initiator"),
+            )?,
+            secret_to_authenticate_target: self.secret_to_authenticate_target,
+            target_arn: self
+                .target_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2442,6 +6100,62 @@ pub struct UpdateGatewayInformationInput {
     pub gateway_timezone: Option<String>,
 }
 
+impl UpdateGatewayInformationInput {
+    /// Returns a builder for `UpdateGatewayInformationInput`, checking its required members when built.
+    pub fn builder() -> UpdateGatewayInformationInputBuilder {
+        UpdateGatewayInformationInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGatewayInformationInput`](struct.UpdateGatewayInformationInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateGatewayInformationInputBuilder {
+    cloud_watch_log_group_arn: Option<String>,
+    gateway_arn: Option<String>,
+    gateway_name: Option<String>,
+    gateway_timezone: Option<String>,
+}
+
+impl UpdateGatewayInformationInputBuilder {
+    /// Sets the [`cloud_watch_log_group_arn`](struct.UpdateGatewayInformationInput.html#structfield.cloud_watch_log_group_arn) member.
+    pub fn cloud_watch_log_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cloud_watch_log_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.UpdateGatewayInformationInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_name`](struct.UpdateGatewayInformationInput.html#structfield.gateway_name) member.
+    pub fn gateway_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_timezone`](struct.UpdateGatewayInformationInput.html#structfield.gateway_timezone) member.
+    pub fn gateway_timezone<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_timezone = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGatewayInformationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateGatewayInformationInput, ::rusoto_core::BuildError> {
+        Ok(UpdateGatewayInformationInput {
+            cloud_watch_log_group_arn: self.cloud_watch_log_group_arn,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            gateway_name: self.gateway_name,
+            gateway_timezone: self.gateway_timezone,
+        })
+    }
+}
+
 /// <p>A JSON object containing the ARN of the gateway that was updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2462,6 +6176,38 @@ pub struct UpdateGatewaySoftwareNowInput {
     pub gateway_arn: String,
 }
 
+impl UpdateGatewaySoftwareNowInput {
+    /// Returns a builder for `UpdateGatewaySoftwareNowInput`, checking its required members when built.
+    pub fn builder() -> UpdateGatewaySoftwareNowInputBuilder {
+        UpdateGatewaySoftwareNowInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGatewaySoftwareNowInput`](struct.UpdateGatewaySoftwareNowInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateGatewaySoftwareNowInputBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl UpdateGatewaySoftwareNowInputBuilder {
+    /// Sets the [`gateway_arn`](struct.UpdateGatewaySoftwareNowInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGatewaySoftwareNowInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateGatewaySoftwareNowInput, ::rusoto_core::BuildError> {
+        Ok(UpdateGatewaySoftwareNowInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the of the gateway that was updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2492,6 +6238,74 @@ pub struct UpdateMaintenanceStartTimeInput {
     pub minute_of_hour: i64,
 }
 
+impl UpdateMaintenanceStartTimeInput {
+    /// Returns a builder for `UpdateMaintenanceStartTimeInput`, checking its required members when built.
+    pub fn builder() -> UpdateMaintenanceStartTimeInputBuilder {
+        UpdateMaintenanceStartTimeInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateMaintenanceStartTimeInput`](struct.UpdateMaintenanceStartTimeInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateMaintenanceStartTimeInputBuilder {
+    day_of_month: Option<i64>,
+    day_of_week: Option<i64>,
+    gateway_arn: Option<String>,
+    hour_of_day: Option<i64>,
+    minute_of_hour: Option<i64>,
+}
+
+impl UpdateMaintenanceStartTimeInputBuilder {
+    /// Sets the [`day_of_month`](struct.UpdateMaintenanceStartTimeInput.html#structfield.day_of_month) member.
+    pub fn day_of_month<T: Into<i64>>(mut self, value: T) -> Self {
+        self.day_of_month = Some(value.into());
+        self
+    }
+
+    /// Sets the [`day_of_week`](struct.UpdateMaintenanceStartTimeInput.html#structfield.day_of_week) member.
+    pub fn day_of_week<T: Into<i64>>(mut self, value: T) -> Self {
+        self.day_of_week = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.UpdateMaintenanceStartTimeInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hour_of_day`](struct.UpdateMaintenanceStartTimeInput.html#structfield.hour_of_day) member.
+    pub fn hour_of_day<T: Into<i64>>(mut self, value: T) -> Self {
+        self.hour_of_day = Some(value.into());
+        self
+    }
+
+    /// Sets the [`minute_of_hour`](struct.UpdateMaintenanceStartTimeInput.html#structfield.minute_of_hour) member.
+    pub fn minute_of_hour<T: Into<i64>>(mut self, value: T) -> Self {
+        self.minute_of_hour = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateMaintenanceStartTimeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateMaintenanceStartTimeInput, ::rusoto_core::BuildError> {
+        Ok(UpdateMaintenanceStartTimeInput {
+            day_of_month: self.day_of_month,
+            day_of_week: self.day_of_week,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            hour_of_day: self
+                .hour_of_day
+                .ok_or(::rusoto_core::BuildError::missing("hour_of_day"))?,
+            minute_of_hour: self
+                .minute_of_hour
+                .ok_or(::rusoto_core::BuildError::missing("minute_of_hour"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the of the gateway whose maintenance start time is updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2534,7 +6348,7 @@ pub struct UpdateNFSFileShareInput {
     /// <p>A value that sets the access control list permission for objects in the S3 bucket that a file gateway puts objects into. The default value is "private".</p>
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     /// <p>A value that sets the write status of a file share. This value is true if the write status is read-only, and otherwise false.</p>
     #[serde(rename = "ReadOnly")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2549,6 +6363,118 @@ pub struct UpdateNFSFileShareInput {
     pub squash: Option<String>,
 }
 
+impl UpdateNFSFileShareInput {
+    /// Returns a builder for `UpdateNFSFileShareInput`, checking its required members when built.
+    pub fn builder() -> UpdateNFSFileShareInputBuilder {
+        UpdateNFSFileShareInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateNFSFileShareInput`](struct.UpdateNFSFileShareInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateNFSFileShareInputBuilder {
+    client_list: Option<Vec<String>>,
+    default_storage_class: Option<String>,
+    file_share_arn: Option<String>,
+    guess_mime_type_enabled: Option<bool>,
+    kms_encrypted: Option<bool>,
+    kms_key: Option<String>,
+    nfs_file_share_defaults: Option<NFSFileShareDefaults>,
+    object_acl: Option<ObjectACL>,
+    read_only: Option<bool>,
+    requester_pays: Option<bool>,
+    squash: Option<String>,
+}
+
+impl UpdateNFSFileShareInputBuilder {
+    /// Sets the [`client_list`](struct.UpdateNFSFileShareInput.html#structfield.client_list) member.
+    pub fn client_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.client_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_storage_class`](struct.UpdateNFSFileShareInput.html#structfield.default_storage_class) member.
+    pub fn default_storage_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_storage_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_share_arn`](struct.UpdateNFSFileShareInput.html#structfield.file_share_arn) member.
+    pub fn file_share_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_share_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`guess_mime_type_enabled`](struct.UpdateNFSFileShareInput.html#structfield.guess_mime_type_enabled) member.
+    pub fn guess_mime_type_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.guess_mime_type_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_encrypted`](struct.UpdateNFSFileShareInput.html#structfield.kms_encrypted) member.
+    pub fn kms_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.kms_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key`](struct.UpdateNFSFileShareInput.html#structfield.kms_key) member.
+    pub fn kms_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`nfs_file_share_defaults`](struct.UpdateNFSFileShareInput.html#structfield.nfs_file_share_defaults) member.
+    pub fn nfs_file_share_defaults<T: Into<NFSFileShareDefaults>>(mut self, value: T) -> Self {
+        self.nfs_file_share_defaults = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_acl`](struct.UpdateNFSFileShareInput.html#structfield.object_acl) member.
+    pub fn object_acl<T: Into<ObjectACL>>(mut self, value: T) -> Self {
+        self.object_acl = Some(value.into());
+        self
+    }
+
+    /// Sets the [`read_only`](struct.UpdateNFSFileShareInput.html#structfield.read_only) member.
+    pub fn read_only<T: Into<bool>>(mut self, value: T) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets the [`requester_pays`](struct.UpdateNFSFileShareInput.html#structfield.requester_pays) member.
+    pub fn requester_pays<T: Into<bool>>(mut self, value: T) -> Self {
+        self.requester_pays = Some(value.into());
+        self
+    }
+
+    /// Sets the [`squash`](struct.UpdateNFSFileShareInput.html#structfield.squash) member.
+    pub fn squash<T: Into<String>>(mut self, value: T) -> Self {
+        self.squash = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateNFSFileShareInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateNFSFileShareInput, ::rusoto_core::BuildError> {
+        Ok(UpdateNFSFileShareInput {
+            client_list: self.client_list,
+            default_storage_class: self.default_storage_class,
+            file_share_arn: self
+                .file_share_arn
+                .ok_or(::rusoto_core::BuildError::missing("file_share_arn"))?,
+            guess_mime_type_enabled: self.guess_mime_type_enabled,
+            kms_encrypted: self.kms_encrypted,
+            kms_key: self.kms_key,
+            nfs_file_share_defaults: self.nfs_file_share_defaults,
+            object_acl: self.object_acl,
+            read_only: self.read_only,
+            requester_pays: self.requester_pays,
+            squash: self.squash,
+        })
+    }
+}
+
 /// <p>UpdateNFSFileShareOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2592,7 +6518,7 @@ pub struct UpdateSMBFileShareInput {
     /// <p>A value that sets the access control list permission for objects in the S3 bucket that a file gateway puts objects into. The default value is "private".</p>
     #[serde(rename = "ObjectACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_acl: Option<String>,
+    pub object_acl: Option<ObjectACL>,
     /// <p>A value that sets the write status of a file share. This value is true if the write status is read-only, and otherwise false.</p>
     #[serde(rename = "ReadOnly")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2611,6 +6537,126 @@ pub struct UpdateSMBFileShareInput {
     pub valid_user_list: Option<Vec<String>>,
 }
 
+impl UpdateSMBFileShareInput {
+    /// Returns a builder for `UpdateSMBFileShareInput`, checking its required members when built.
+    pub fn builder() -> UpdateSMBFileShareInputBuilder {
+        UpdateSMBFileShareInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSMBFileShareInput`](struct.UpdateSMBFileShareInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateSMBFileShareInputBuilder {
+    admin_user_list: Option<Vec<String>>,
+    default_storage_class: Option<String>,
+    file_share_arn: Option<String>,
+    guess_mime_type_enabled: Option<bool>,
+    invalid_user_list: Option<Vec<String>>,
+    kms_encrypted: Option<bool>,
+    kms_key: Option<String>,
+    object_acl: Option<ObjectACL>,
+    read_only: Option<bool>,
+    requester_pays: Option<bool>,
+    smbacl_enabled: Option<bool>,
+    valid_user_list: Option<Vec<String>>,
+}
+
+impl UpdateSMBFileShareInputBuilder {
+    /// Sets the [`admin_user_list`](struct.UpdateSMBFileShareInput.html#structfield.admin_user_list) member.
+    pub fn admin_user_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.admin_user_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_storage_class`](struct.UpdateSMBFileShareInput.html#structfield.default_storage_class) member.
+    pub fn default_storage_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_storage_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_share_arn`](struct.UpdateSMBFileShareInput.html#structfield.file_share_arn) member.
+    pub fn file_share_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_share_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`guess_mime_type_enabled`](struct.UpdateSMBFileShareInput.html#structfield.guess_mime_type_enabled) member.
+    pub fn guess_mime_type_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.guess_mime_type_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`invalid_user_list`](struct.UpdateSMBFileShareInput.html#structfield.invalid_user_list) member.
+    pub fn invalid_user_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.invalid_user_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_encrypted`](struct.UpdateSMBFileShareInput.html#structfield.kms_encrypted) member.
+    pub fn kms_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.kms_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key`](struct.UpdateSMBFileShareInput.html#structfield.kms_key) member.
+    pub fn kms_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_acl`](struct.UpdateSMBFileShareInput.html#structfield.object_acl) member.
+    pub fn object_acl<T: Into<ObjectACL>>(mut self, value: T) -> Self {
+        self.object_acl = Some(value.into());
+        self
+    }
+
+    /// Sets the [`read_only`](struct.UpdateSMBFileShareInput.html#structfield.read_only) member.
+    pub fn read_only<T: Into<bool>>(mut self, value: T) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets the [`requester_pays`](struct.UpdateSMBFileShareInput.html#structfield.requester_pays) member.
+    pub fn requester_pays<T: Into<bool>>(mut self, value: T) -> Self {
+        self.requester_pays = Some(value.into());
+        self
+    }
+
+    /// Sets the [`smbacl_enabled`](struct.UpdateSMBFileShareInput.html#structfield.smbacl_enabled) member.
+    pub fn smbacl_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.smbacl_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`valid_user_list`](struct.UpdateSMBFileShareInput.html#structfield.valid_user_list) member.
+    pub fn valid_user_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.valid_user_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSMBFileShareInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSMBFileShareInput, ::rusoto_core::BuildError> {
+        Ok(UpdateSMBFileShareInput {
+            admin_user_list: self.admin_user_list,
+            default_storage_class: self.default_storage_class,
+            file_share_arn: self
+                .file_share_arn
+                .ok_or(::rusoto_core::BuildError::missing("file_share_arn"))?,
+            guess_mime_type_enabled: self.guess_mime_type_enabled,
+            invalid_user_list: self.invalid_user_list,
+            kms_encrypted: self.kms_encrypted,
+            kms_key: self.kms_key,
+            object_acl: self.object_acl,
+            read_only: self.read_only,
+            requester_pays: self.requester_pays,
+            smbacl_enabled: self.smbacl_enabled,
+            valid_user_list: self.valid_user_list,
+        })
+    }
+}
+
 /// <p>UpdateSMBFileShareOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2621,13 +6667,55 @@ pub struct UpdateSMBFileShareOutput {
     pub file_share_arn: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateSMBSecurityStrategyInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
     /// <p>Specifies the type of security strategy.</p> <p>ClientSpecified: if you use this option, requests are established based on what is negotiated by the client. This option is recommended when you want to maximize compatibility across different clients in your environment. </p> <p>MandatorySigning: if you use this option, file gateway only allows connections from SMBv2 or SMBv3 clients that have signing enabled. This option works with SMB clients on Microsoft Windows Vista, Windows Server 2008 or newer. </p> <p>MandatoryEncryption: if you use this option, file gateway only allows connections from SMBv3 clients that have encryption enabled. This option is highly recommended for environments that handle sensitive data. This option works with SMB clients on Microsoft Windows 8, Windows Server 2012 or newer. </p>
     #[serde(rename = "SMBSecurityStrategy")]
-    pub smb_security_strategy: String,
+    pub smb_security_strategy: SMBSecurityStrategy,
+}
+
+impl UpdateSMBSecurityStrategyInput {
+    /// Returns a builder for `UpdateSMBSecurityStrategyInput`, checking its required members when built.
+    pub fn builder() -> UpdateSMBSecurityStrategyInputBuilder {
+        UpdateSMBSecurityStrategyInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSMBSecurityStrategyInput`](struct.UpdateSMBSecurityStrategyInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateSMBSecurityStrategyInputBuilder {
+    gateway_arn: Option<String>,
+    smb_security_strategy: Option<SMBSecurityStrategy>,
+}
+
+impl UpdateSMBSecurityStrategyInputBuilder {
+    /// Sets the [`gateway_arn`](struct.UpdateSMBSecurityStrategyInput.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`smb_security_strategy`](struct.UpdateSMBSecurityStrategyInput.html#structfield.smb_security_strategy) member.
+    pub fn smb_security_strategy<T: Into<SMBSecurityStrategy>>(mut self, value: T) -> Self {
+        self.smb_security_strategy = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSMBSecurityStrategyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSMBSecurityStrategyInput, ::rusoto_core::BuildError> {
+        Ok(UpdateSMBSecurityStrategyInput {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            smb_security_strategy: self
+                .smb_security_strategy
+                .ok_or(::rusoto_core::BuildError::missing("smb_security_strategy"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2660,6 +6748,74 @@ pub struct UpdateSnapshotScheduleInput {
     pub volume_arn: String,
 }
 
+impl UpdateSnapshotScheduleInput {
+    /// Returns a builder for `UpdateSnapshotScheduleInput`, checking its required members when built.
+    pub fn builder() -> UpdateSnapshotScheduleInputBuilder {
+        UpdateSnapshotScheduleInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSnapshotScheduleInput`](struct.UpdateSnapshotScheduleInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateSnapshotScheduleInputBuilder {
+    description: Option<String>,
+    recurrence_in_hours: Option<i64>,
+    start_at: Option<i64>,
+    tags: Option<Vec<Tag>>,
+    volume_arn: Option<String>,
+}
+
+impl UpdateSnapshotScheduleInputBuilder {
+    /// Sets the [`description`](struct.UpdateSnapshotScheduleInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recurrence_in_hours`](struct.UpdateSnapshotScheduleInput.html#structfield.recurrence_in_hours) member.
+    pub fn recurrence_in_hours<T: Into<i64>>(mut self, value: T) -> Self {
+        self.recurrence_in_hours = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_at`](struct.UpdateSnapshotScheduleInput.html#structfield.start_at) member.
+    pub fn start_at<T: Into<i64>>(mut self, value: T) -> Self {
+        self.start_at = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.UpdateSnapshotScheduleInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_arn`](struct.UpdateSnapshotScheduleInput.html#structfield.volume_arn) member.
+    pub fn volume_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSnapshotScheduleInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSnapshotScheduleInput, ::rusoto_core::BuildError> {
+        Ok(UpdateSnapshotScheduleInput {
+            description: self.description,
+            recurrence_in_hours: self
+                .recurrence_in_hours
+                .ok_or(::rusoto_core::BuildError::missing("recurrence_in_hours"))?,
+            start_at: self
+                .start_at
+                .ok_or(::rusoto_core::BuildError::missing("start_at"))?,
+            tags: self.tags,
+            volume_arn: self
+                .volume_arn
+                .ok_or(::rusoto_core::BuildError::missing("volume_arn"))?,
+        })
+    }
+}
+
 /// <p>A JSON object containing the of the updated storage volume.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2680,6 +6836,48 @@ pub struct UpdateVTLDeviceTypeInput {
     pub vtl_device_arn: String,
 }
 
+impl UpdateVTLDeviceTypeInput {
+    /// Returns a builder for `UpdateVTLDeviceTypeInput`, checking its required members when built.
+    pub fn builder() -> UpdateVTLDeviceTypeInputBuilder {
+        UpdateVTLDeviceTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateVTLDeviceTypeInput`](struct.UpdateVTLDeviceTypeInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateVTLDeviceTypeInputBuilder {
+    device_type: Option<String>,
+    vtl_device_arn: Option<String>,
+}
+
+impl UpdateVTLDeviceTypeInputBuilder {
+    /// Sets the [`device_type`](struct.UpdateVTLDeviceTypeInput.html#structfield.device_type) member.
+    pub fn device_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vtl_device_arn`](struct.UpdateVTLDeviceTypeInput.html#structfield.vtl_device_arn) member.
+    pub fn vtl_device_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.vtl_device_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateVTLDeviceTypeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateVTLDeviceTypeInput, ::rusoto_core::BuildError> {
+        Ok(UpdateVTLDeviceTypeInput {
+            device_type: self
+                .device_type
+                .ok_or(::rusoto_core::BuildError::missing("device_type"))?,
+            vtl_device_arn: self
+                .vtl_device_arn
+                .ok_or(::rusoto_core::BuildError::missing("vtl_device_arn"))?,
+        })
+    }
+}
+
 /// <p>UpdateVTLDeviceTypeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -6321,21 +10519,486 @@ pub trait StorageGateway {
         input: UpdateVTLDeviceTypeInput,
     ) -> RusotoFuture<UpdateVTLDeviceTypeOutput, UpdateVTLDeviceTypeError>;
 }
-/// A client for the AWS Storage Gateway API.
-#[derive(Clone)]
-pub struct StorageGatewayClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl StorageGatewayClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
+impl<T: ?Sized + StorageGateway> StorageGateway for ::std::sync::Arc<T> {
+    fn activate_gateway(
+        &self,
+        input: ActivateGatewayInput,
+    ) -> RusotoFuture<ActivateGatewayOutput, ActivateGatewayError> {
+        (**self).activate_gateway(input)
+    }
+    fn add_cache(&self, input: AddCacheInput) -> RusotoFuture<AddCacheOutput, AddCacheError> {
+        (**self).add_cache(input)
+    }
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceInput,
+    ) -> RusotoFuture<AddTagsToResourceOutput, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn add_upload_buffer(
+        &self,
+        input: AddUploadBufferInput,
+    ) -> RusotoFuture<AddUploadBufferOutput, AddUploadBufferError> {
+        (**self).add_upload_buffer(input)
+    }
+    fn add_working_storage(
+        &self,
+        input: AddWorkingStorageInput,
+    ) -> RusotoFuture<AddWorkingStorageOutput, AddWorkingStorageError> {
+        (**self).add_working_storage(input)
+    }
+    fn assign_tape_pool(
+        &self,
+        input: AssignTapePoolInput,
+    ) -> RusotoFuture<AssignTapePoolOutput, AssignTapePoolError> {
+        (**self).assign_tape_pool(input)
+    }
+    fn attach_volume(
+        &self,
+        input: AttachVolumeInput,
+    ) -> RusotoFuture<AttachVolumeOutput, AttachVolumeError> {
+        (**self).attach_volume(input)
+    }
+    fn cancel_archival(
+        &self,
+        input: CancelArchivalInput,
+    ) -> RusotoFuture<CancelArchivalOutput, CancelArchivalError> {
+        (**self).cancel_archival(input)
+    }
+    fn cancel_retrieval(
+        &self,
+        input: CancelRetrievalInput,
+    ) -> RusotoFuture<CancelRetrievalOutput, CancelRetrievalError> {
+        (**self).cancel_retrieval(input)
+    }
+    fn create_cachedi_scsi_volume(
+        &self,
+        input: CreateCachediSCSIVolumeInput,
+    ) -> RusotoFuture<CreateCachediSCSIVolumeOutput, CreateCachediSCSIVolumeError> {
+        (**self).create_cachedi_scsi_volume(input)
+    }
+    fn create_nfs_file_share(
+        &self,
+        input: CreateNFSFileShareInput,
+    ) -> RusotoFuture<CreateNFSFileShareOutput, CreateNFSFileShareError> {
+        (**self).create_nfs_file_share(input)
+    }
+    fn create_smb_file_share(
+        &self,
+        input: CreateSMBFileShareInput,
+    ) -> RusotoFuture<CreateSMBFileShareOutput, CreateSMBFileShareError> {
+        (**self).create_smb_file_share(input)
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotInput,
+    ) -> RusotoFuture<CreateSnapshotOutput, CreateSnapshotError> {
+        (**self).create_snapshot(input)
+    }
+    fn create_snapshot_from_volume_recovery_point(
+        &self,
+        input: CreateSnapshotFromVolumeRecoveryPointInput,
+    ) -> RusotoFuture<
+        CreateSnapshotFromVolumeRecoveryPointOutput,
+        CreateSnapshotFromVolumeRecoveryPointError,
+    > {
+        (**self).create_snapshot_from_volume_recovery_point(input)
+    }
+    fn create_storedi_scsi_volume(
+        &self,
+        input: CreateStorediSCSIVolumeInput,
+    ) -> RusotoFuture<CreateStorediSCSIVolumeOutput, CreateStorediSCSIVolumeError> {
+        (**self).create_storedi_scsi_volume(input)
+    }
+    fn create_tape_with_barcode(
+        &self,
+        input: CreateTapeWithBarcodeInput,
+    ) -> RusotoFuture<CreateTapeWithBarcodeOutput, CreateTapeWithBarcodeError> {
+        (**self).create_tape_with_barcode(input)
+    }
+    fn create_tapes(
+        &self,
+        input: CreateTapesInput,
+    ) -> RusotoFuture<CreateTapesOutput, CreateTapesError> {
+        (**self).create_tapes(input)
+    }
+    fn delete_bandwidth_rate_limit(
+        &self,
+        input: DeleteBandwidthRateLimitInput,
+    ) -> RusotoFuture<DeleteBandwidthRateLimitOutput, DeleteBandwidthRateLimitError> {
+        (**self).delete_bandwidth_rate_limit(input)
+    }
+    fn delete_chap_credentials(
+        &self,
+        input: DeleteChapCredentialsInput,
+    ) -> RusotoFuture<DeleteChapCredentialsOutput, DeleteChapCredentialsError> {
+        (**self).delete_chap_credentials(input)
+    }
+    fn delete_file_share(
+        &self,
+        input: DeleteFileShareInput,
+    ) -> RusotoFuture<DeleteFileShareOutput, DeleteFileShareError> {
+        (**self).delete_file_share(input)
+    }
+    fn delete_gateway(
+        &self,
+        input: DeleteGatewayInput,
+    ) -> RusotoFuture<DeleteGatewayOutput, DeleteGatewayError> {
+        (**self).delete_gateway(input)
+    }
+    fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleInput,
+    ) -> RusotoFuture<DeleteSnapshotScheduleOutput, DeleteSnapshotScheduleError> {
+        (**self).delete_snapshot_schedule(input)
+    }
+    fn delete_tape(
+        &self,
+        input: DeleteTapeInput,
+    ) -> RusotoFuture<DeleteTapeOutput, DeleteTapeError> {
+        (**self).delete_tape(input)
+    }
+    fn delete_tape_archive(
+        &self,
+        input: DeleteTapeArchiveInput,
+    ) -> RusotoFuture<DeleteTapeArchiveOutput, DeleteTapeArchiveError> {
+        (**self).delete_tape_archive(input)
+    }
+    fn delete_volume(
+        &self,
+        input: DeleteVolumeInput,
+    ) -> RusotoFuture<DeleteVolumeOutput, DeleteVolumeError> {
+        (**self).delete_volume(input)
+    }
+    fn describe_bandwidth_rate_limit(
+        &self,
+        input: DescribeBandwidthRateLimitInput,
+    ) -> RusotoFuture<DescribeBandwidthRateLimitOutput, DescribeBandwidthRateLimitError> {
+        (**self).describe_bandwidth_rate_limit(input)
+    }
+    fn describe_cache(
+        &self,
+        input: DescribeCacheInput,
+    ) -> RusotoFuture<DescribeCacheOutput, DescribeCacheError> {
+        (**self).describe_cache(input)
+    }
+    fn describe_cachedi_scsi_volumes(
+        &self,
+        input: DescribeCachediSCSIVolumesInput,
+    ) -> RusotoFuture<DescribeCachediSCSIVolumesOutput, DescribeCachediSCSIVolumesError> {
+        (**self).describe_cachedi_scsi_volumes(input)
+    }
+    fn describe_chap_credentials(
+        &self,
+        input: DescribeChapCredentialsInput,
+    ) -> RusotoFuture<DescribeChapCredentialsOutput, DescribeChapCredentialsError> {
+        (**self).describe_chap_credentials(input)
+    }
+    fn describe_gateway_information(
+        &self,
+        input: DescribeGatewayInformationInput,
+    ) -> RusotoFuture<DescribeGatewayInformationOutput, DescribeGatewayInformationError> {
+        (**self).describe_gateway_information(input)
+    }
+    fn describe_maintenance_start_time(
+        &self,
+        input: DescribeMaintenanceStartTimeInput,
+    ) -> RusotoFuture<DescribeMaintenanceStartTimeOutput, DescribeMaintenanceStartTimeError> {
+        (**self).describe_maintenance_start_time(input)
+    }
+    fn describe_nfs_file_shares(
+        &self,
+        input: DescribeNFSFileSharesInput,
+    ) -> RusotoFuture<DescribeNFSFileSharesOutput, DescribeNFSFileSharesError> {
+        (**self).describe_nfs_file_shares(input)
+    }
+    fn describe_smb_file_shares(
+        &self,
+        input: DescribeSMBFileSharesInput,
+    ) -> RusotoFuture<DescribeSMBFileSharesOutput, DescribeSMBFileSharesError> {
+        (**self).describe_smb_file_shares(input)
+    }
+    fn describe_smb_settings(
+        &self,
+        input: DescribeSMBSettingsInput,
+    ) -> RusotoFuture<DescribeSMBSettingsOutput, DescribeSMBSettingsError> {
+        (**self).describe_smb_settings(input)
+    }
+    fn describe_snapshot_schedule(
+        &self,
+        input: DescribeSnapshotScheduleInput,
+    ) -> RusotoFuture<DescribeSnapshotScheduleOutput, DescribeSnapshotScheduleError> {
+        (**self).describe_snapshot_schedule(input)
+    }
+    fn describe_storedi_scsi_volumes(
+        &self,
+        input: DescribeStorediSCSIVolumesInput,
+    ) -> RusotoFuture<DescribeStorediSCSIVolumesOutput, DescribeStorediSCSIVolumesError> {
+        (**self).describe_storedi_scsi_volumes(input)
+    }
+    fn describe_tape_archives(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> RusotoFuture<DescribeTapeArchivesOutput, DescribeTapeArchivesError> {
+        (**self).describe_tape_archives(input)
+    }
+    fn describe_tape_recovery_points(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> RusotoFuture<DescribeTapeRecoveryPointsOutput, DescribeTapeRecoveryPointsError> {
+        (**self).describe_tape_recovery_points(input)
+    }
+    fn describe_tapes(
+        &self,
+        input: DescribeTapesInput,
+    ) -> RusotoFuture<DescribeTapesOutput, DescribeTapesError> {
+        (**self).describe_tapes(input)
+    }
+    fn describe_upload_buffer(
+        &self,
+        input: DescribeUploadBufferInput,
+    ) -> RusotoFuture<DescribeUploadBufferOutput, DescribeUploadBufferError> {
+        (**self).describe_upload_buffer(input)
+    }
+    fn describe_vtl_devices(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> RusotoFuture<DescribeVTLDevicesOutput, DescribeVTLDevicesError> {
+        (**self).describe_vtl_devices(input)
+    }
+    fn describe_working_storage(
+        &self,
+        input: DescribeWorkingStorageInput,
+    ) -> RusotoFuture<DescribeWorkingStorageOutput, DescribeWorkingStorageError> {
+        (**self).describe_working_storage(input)
+    }
+    fn detach_volume(
+        &self,
+        input: DetachVolumeInput,
+    ) -> RusotoFuture<DetachVolumeOutput, DetachVolumeError> {
+        (**self).detach_volume(input)
+    }
+    fn disable_gateway(
+        &self,
+        input: DisableGatewayInput,
+    ) -> RusotoFuture<DisableGatewayOutput, DisableGatewayError> {
+        (**self).disable_gateway(input)
+    }
+    fn join_domain(
+        &self,
+        input: JoinDomainInput,
+    ) -> RusotoFuture<JoinDomainOutput, JoinDomainError> {
+        (**self).join_domain(input)
+    }
+    fn list_file_shares(
+        &self,
+        input: ListFileSharesInput,
+    ) -> RusotoFuture<ListFileSharesOutput, ListFileSharesError> {
+        (**self).list_file_shares(input)
+    }
+    fn list_gateways(
+        &self,
+        input: ListGatewaysInput,
+    ) -> RusotoFuture<ListGatewaysOutput, ListGatewaysError> {
+        (**self).list_gateways(input)
+    }
+    fn list_local_disks(
+        &self,
+        input: ListLocalDisksInput,
+    ) -> RusotoFuture<ListLocalDisksOutput, ListLocalDisksError> {
+        (**self).list_local_disks(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_tapes(&self, input: ListTapesInput) -> RusotoFuture<ListTapesOutput, ListTapesError> {
+        (**self).list_tapes(input)
+    }
+    fn list_volume_initiators(
+        &self,
+        input: ListVolumeInitiatorsInput,
+    ) -> RusotoFuture<ListVolumeInitiatorsOutput, ListVolumeInitiatorsError> {
+        (**self).list_volume_initiators(input)
+    }
+    fn list_volume_recovery_points(
+        &self,
+        input: ListVolumeRecoveryPointsInput,
+    ) -> RusotoFuture<ListVolumeRecoveryPointsOutput, ListVolumeRecoveryPointsError> {
+        (**self).list_volume_recovery_points(input)
+    }
+    fn list_volumes(
+        &self,
+        input: ListVolumesInput,
+    ) -> RusotoFuture<ListVolumesOutput, ListVolumesError> {
+        (**self).list_volumes(input)
+    }
+    fn notify_when_uploaded(
+        &self,
+        input: NotifyWhenUploadedInput,
+    ) -> RusotoFuture<NotifyWhenUploadedOutput, NotifyWhenUploadedError> {
+        (**self).notify_when_uploaded(input)
+    }
+    fn refresh_cache(
+        &self,
+        input: RefreshCacheInput,
+    ) -> RusotoFuture<RefreshCacheOutput, RefreshCacheError> {
+        (**self).refresh_cache(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceInput,
+    ) -> RusotoFuture<RemoveTagsFromResourceOutput, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_cache(
+        &self,
+        input: ResetCacheInput,
+    ) -> RusotoFuture<ResetCacheOutput, ResetCacheError> {
+        (**self).reset_cache(input)
+    }
+    fn retrieve_tape_archive(
+        &self,
+        input: RetrieveTapeArchiveInput,
+    ) -> RusotoFuture<RetrieveTapeArchiveOutput, RetrieveTapeArchiveError> {
+        (**self).retrieve_tape_archive(input)
+    }
+    fn retrieve_tape_recovery_point(
+        &self,
+        input: RetrieveTapeRecoveryPointInput,
+    ) -> RusotoFuture<RetrieveTapeRecoveryPointOutput, RetrieveTapeRecoveryPointError> {
+        (**self).retrieve_tape_recovery_point(input)
+    }
+    fn set_local_console_password(
+        &self,
+        input: SetLocalConsolePasswordInput,
+    ) -> RusotoFuture<SetLocalConsolePasswordOutput, SetLocalConsolePasswordError> {
+        (**self).set_local_console_password(input)
+    }
+    fn set_smb_guest_password(
+        &self,
+        input: SetSMBGuestPasswordInput,
+    ) -> RusotoFuture<SetSMBGuestPasswordOutput, SetSMBGuestPasswordError> {
+        (**self).set_smb_guest_password(input)
+    }
+    fn shutdown_gateway(
+        &self,
+        input: ShutdownGatewayInput,
+    ) -> RusotoFuture<ShutdownGatewayOutput, ShutdownGatewayError> {
+        (**self).shutdown_gateway(input)
+    }
+    fn start_gateway(
+        &self,
+        input: StartGatewayInput,
+    ) -> RusotoFuture<StartGatewayOutput, StartGatewayError> {
+        (**self).start_gateway(input)
+    }
+    fn update_bandwidth_rate_limit(
+        &self,
+        input: UpdateBandwidthRateLimitInput,
+    ) -> RusotoFuture<UpdateBandwidthRateLimitOutput, UpdateBandwidthRateLimitError> {
+        (**self).update_bandwidth_rate_limit(input)
+    }
+    fn update_chap_credentials(
+        &self,
+        input: UpdateChapCredentialsInput,
+    ) -> RusotoFuture<UpdateChapCredentialsOutput, UpdateChapCredentialsError> {
+        (**self).update_chap_credentials(input)
+    }
+    fn update_gateway_information(
+        &self,
+        input: UpdateGatewayInformationInput,
+    ) -> RusotoFuture<UpdateGatewayInformationOutput, UpdateGatewayInformationError> {
+        (**self).update_gateway_information(input)
+    }
+    fn update_gateway_software_now(
+        &self,
+        input: UpdateGatewaySoftwareNowInput,
+    ) -> RusotoFuture<UpdateGatewaySoftwareNowOutput, UpdateGatewaySoftwareNowError> {
+        (**self).update_gateway_software_now(input)
+    }
+    fn update_maintenance_start_time(
+        &self,
+        input: UpdateMaintenanceStartTimeInput,
+    ) -> RusotoFuture<UpdateMaintenanceStartTimeOutput, UpdateMaintenanceStartTimeError> {
+        (**self).update_maintenance_start_time(input)
+    }
+    fn update_nfs_file_share(
+        &self,
+        input: UpdateNFSFileShareInput,
+    ) -> RusotoFuture<UpdateNFSFileShareOutput, UpdateNFSFileShareError> {
+        (**self).update_nfs_file_share(input)
+    }
+    fn update_smb_file_share(
+        &self,
+        input: UpdateSMBFileShareInput,
+    ) -> RusotoFuture<UpdateSMBFileShareOutput, UpdateSMBFileShareError> {
+        (**self).update_smb_file_share(input)
+    }
+    fn update_smb_security_strategy(
+        &self,
+        input: UpdateSMBSecurityStrategyInput,
+    ) -> RusotoFuture<UpdateSMBSecurityStrategyOutput, UpdateSMBSecurityStrategyError> {
+        (**self).update_smb_security_strategy(input)
+    }
+    fn update_snapshot_schedule(
+        &self,
+        input: UpdateSnapshotScheduleInput,
+    ) -> RusotoFuture<UpdateSnapshotScheduleOutput, UpdateSnapshotScheduleError> {
+        (**self).update_snapshot_schedule(input)
+    }
+    fn update_vtl_device_type(
+        &self,
+        input: UpdateVTLDeviceTypeInput,
+    ) -> RusotoFuture<UpdateVTLDeviceTypeOutput, UpdateVTLDeviceTypeError> {
+        (**self).update_vtl_device_type(input)
+    }
+}
+/// The error codes AWS Storage Gateway retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
+/// A client for the AWS Storage Gateway API.
+#[derive(Clone)]
+pub struct StorageGatewayClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl StorageGatewayClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
     pub fn new(region: region::Region) -> StorageGatewayClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_STORAGEGATEWAY`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> StorageGatewayClient {
+        Self::new(region::Region::from_env_for_service("STORAGEGATEWAY"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `storagegateway`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> StorageGatewayClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("storagegateway", "STORAGEGATEWAY"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6354,7 +11017,54 @@ impl StorageGatewayClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> StorageGatewayClient {
-        StorageGatewayClient { client, region }
+        StorageGatewayClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "storagegateway")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "storagegateway",
+            "storagegateway",
+            "STORAGEGATEWAY",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `StorageGateway` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn StorageGateway + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for StorageGatewayClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Storage Gateway"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "storagegateway"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> StorageGatewayClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Security Token Service clients.
+//!
+//! Makes a single read-only `GetCallerIdentity` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_sts::{GetCallerIdentityRequest, Sts, StsClient};
+
+fn main() {
+    let client = StsClient::from_env();
+    match client
+        .get_caller_identity(GetCallerIdentityRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("GetCallerIdentity succeeded"),
+        Err(error) => {
+            eprintln!("GetCallerIdentity failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
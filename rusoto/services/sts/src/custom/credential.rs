@@ -15,6 +15,7 @@ use crate::{
     GetSessionTokenResponse, Sts, StsClient,
 };
 use rusoto_core::credential::AwsCredentials;
+use rusoto_core::timestamp;
 use rusoto_core::{CredentialsError, ProvideAwsCredentials, RusotoFuture};
 
 pub const DEFAULT_DURATION_SECONDS: i64 = 3600;
@@ -34,8 +35,7 @@ impl NewAwsCredsForStsCreds for AwsCredentials {
         sts_creds: crate::generated::Credentials,
     ) -> Result<AwsCredentials, CredentialsError> {
         let expires_at = Some(
-            sts_creds
-                .expiration
+            timestamp::to_iso8601(&sts_creds.expiration)
                 .parse::<DateTime<Utc>>()
                 .map_err(CredentialsError::from)?,
         );
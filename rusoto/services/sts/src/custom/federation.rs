@@ -0,0 +1,386 @@
+//! Signing in to the AWS Management Console with temporary credentials.
+//!
+//! The federation endpoint of AWS Sign-In exchanges the credentials returned by `AssumeRole` or
+//! `GetFederationToken` for a sign-in token, which is then embedded in a console login URL.
+//! `ConsoleFederation` calls the regional endpoint of the region it is created for, or the
+//! endpoint of the China and GovCloud partitions.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future;
+use futures::Future;
+use rusoto_core::credential::AwsCredentials;
+use rusoto_core::request::{DispatchSignedRequest, HttpClient};
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{Region, RusotoError, RusotoFuture};
+use serde_json::{json, Value};
+
+/// The shortest session `SessionDuration` accepts.
+const MIN_SESSION_DURATION_SECONDS: u64 = 900;
+/// The longest session `SessionDuration` accepts.
+const MAX_SESSION_DURATION_SECONDS: u64 = 43_200;
+
+/// Why a sign-in token or login URL could not be created.
+#[derive(Debug, PartialEq)]
+pub enum FederationError {
+    /// The credentials have no session token, so they are not temporary credentials
+    MissingSessionToken,
+    /// The session duration is outside of the 15 minutes to 12 hours AWS Sign-In accepts
+    InvalidSessionDuration(String),
+    /// The federation endpoint answered without a sign-in token
+    InvalidResponse(String),
+}
+
+impl fmt::Display for FederationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for FederationError {
+    fn description(&self) -> &str {
+        match *self {
+            FederationError::MissingSessionToken => "credentials have no session token",
+            FederationError::InvalidSessionDuration(ref cause) => cause,
+            FederationError::InvalidResponse(ref cause) => cause,
+        }
+    }
+}
+
+/// Returns the hostname of the AWS Sign-In federation endpoint for `region`, or `None` for
+/// `Region::Custom`, whose endpoint is used as it is.
+fn signin_hostname(region: &Region) -> Option<String> {
+    match *region {
+        Region::Custom { .. } => None,
+        Region::UsEast1 => Some("signin.aws.amazon.com".to_owned()),
+        Region::CnNorth1 | Region::CnNorthwest1 => Some("signin.amazonaws.cn".to_owned()),
+        Region::UsGovEast1 | Region::UsGovWest1 => Some("signin.amazonaws-us-gov.com".to_owned()),
+        _ => Some(format!("{}.signin.aws.amazon.com", region.name())),
+    }
+}
+
+/// Returns the URL of the AWS Management Console home page for `region`, the default
+/// destination of login URLs.
+pub fn console_url(region: &Region) -> String {
+    match *region {
+        Region::Custom { ref endpoint, .. } => endpoint.to_owned(),
+        Region::UsEast1 => "https://console.aws.amazon.com/".to_owned(),
+        Region::CnNorth1 | Region::CnNorthwest1 => format!(
+            "https://{}.console.amazonaws.cn/console/home?region={}",
+            region.name(),
+            region.name()
+        ),
+        Region::UsGovEast1 | Region::UsGovWest1 => format!(
+            "https://console.amazonaws-us-gov.com/console/home?region={}",
+            region.name()
+        ),
+        _ => format!(
+            "https://{}.console.aws.amazon.com/console/home?region={}",
+            region.name(),
+            region.name()
+        ),
+    }
+}
+
+/// Creates AWS Management Console login URLs from temporary credentials.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_core::credential::AwsCredentials;
+/// use rusoto_core::Region;
+/// use rusoto_sts::{console_url, ConsoleFederation};
+///
+/// # let credentials = AwsCredentials::new("key", "secret", Some("token".to_owned()), None);
+/// let federation = ConsoleFederation::new(Region::EuWest1);
+/// let url = federation
+///     .login_url(&credentials, &console_url(&Region::EuWest1), "https://example.com")
+///     .sync()
+///     .unwrap();
+/// println!("{}", url);
+/// ```
+pub struct ConsoleFederation<D = HttpClient> {
+    dispatcher: Arc<D>,
+    region: Region,
+    session_duration: Option<Duration>,
+    timeout: Duration,
+}
+
+impl<D> Clone for ConsoleFederation<D> {
+    fn clone(&self) -> Self {
+        ConsoleFederation {
+            dispatcher: self.dispatcher.clone(),
+            region: self.region.clone(),
+            session_duration: self.session_duration,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl ConsoleFederation {
+    /// Creates a `ConsoleFederation` calling the federation endpoint of `region` with a new
+    /// `HttpClient`.
+    pub fn new(region: Region) -> ConsoleFederation {
+        ConsoleFederation::new_with(
+            HttpClient::new().expect("failed to create request dispatcher"),
+            region,
+        )
+    }
+}
+
+impl<D> ConsoleFederation<D>
+where
+    D: DispatchSignedRequest + Send + Sync + 'static,
+    D::Future: Send,
+{
+    /// Creates a `ConsoleFederation` calling the federation endpoint of `region` with
+    /// `dispatcher`.
+    pub fn new_with(dispatcher: D, region: Region) -> ConsoleFederation<D> {
+        ConsoleFederation {
+            dispatcher: Arc::new(dispatcher),
+            region,
+            session_duration: None,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Sets how long console sessions last, between 15 minutes and 12 hours.
+    ///
+    /// AWS Sign-In only accepts a session duration for credentials of an assumed role. Sessions
+    /// of credentials returned by `GetFederationToken` last as long as the credentials, and by
+    /// default console sessions last one hour.
+    pub fn set_session_duration(&mut self, session_duration: Duration) {
+        self.session_duration = Some(session_duration);
+    }
+
+    /// Sets the timeout for requests to the federation endpoint, 10 seconds by default.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Exchanges temporary credentials for a sign-in token, valid for 15 minutes.
+    pub fn signin_token(
+        &self,
+        credentials: &AwsCredentials,
+    ) -> RusotoFuture<String, FederationError> {
+        let request = match self.signin_token_request(credentials) {
+            Ok(request) => request,
+            Err(e) => return RusotoFuture::from_future(future::err(RusotoError::Service(e))),
+        };
+
+        let future = self
+            .dispatcher
+            .dispatch(request, Some(self.timeout))
+            .map_err(RusotoError::from)
+            .and_then(|response| response.buffer().map_err(RusotoError::from))
+            .and_then(|response| {
+                if !response.status.is_success() {
+                    return Err(RusotoError::Unknown(response));
+                }
+                parse_signin_token(&response.body).map_err(RusotoError::Service)
+            });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Creates a URL which signs in to the console with temporary credentials and opens
+    /// `destination`.
+    ///
+    /// `destination` must be a console URL, such as the one returned by `console_url`. Users
+    /// are sent to `issuer` once their session expires.
+    pub fn login_url(
+        &self,
+        credentials: &AwsCredentials,
+        destination: &str,
+        issuer: &str,
+    ) -> RusotoFuture<String, FederationError> {
+        let mut request = SignedRequest::new("GET", "signin", &self.region, "/federation");
+        request.set_hostname(signin_hostname(&self.region));
+        let endpoint = format!(
+            "{}://{}{}",
+            request.scheme(),
+            request.hostname(),
+            request.path()
+        );
+        let destination = destination.to_owned();
+        let issuer = issuer.to_owned();
+
+        let future = self.signin_token(credentials).map(move |token| {
+            let query = serde_urlencoded::to_string(&[
+                ("Action", "login"),
+                ("Issuer", &issuer),
+                ("Destination", &destination),
+                ("SigninToken", &token),
+            ])
+            .expect("failed to encode login URL");
+            format!("{}?{}", endpoint, query)
+        });
+
+        RusotoFuture::from_future(future)
+    }
+
+    fn signin_token_request(
+        &self,
+        credentials: &AwsCredentials,
+    ) -> Result<SignedRequest, FederationError> {
+        let session_token = credentials
+            .token()
+            .as_ref()
+            .ok_or(FederationError::MissingSessionToken)?;
+        let session = json!({
+            "sessionId": credentials.aws_access_key_id(),
+            "sessionKey": credentials.aws_secret_access_key(),
+            "sessionToken": session_token,
+        });
+
+        let mut request = SignedRequest::new("GET", "signin", &self.region, "/federation");
+        request.set_hostname(signin_hostname(&self.region));
+        request.add_param("Action", "getSigninToken");
+        if let Some(duration) = self.session_duration {
+            let seconds = duration.as_secs();
+            if seconds < MIN_SESSION_DURATION_SECONDS || seconds > MAX_SESSION_DURATION_SECONDS {
+                return Err(FederationError::InvalidSessionDuration(format!(
+                    "Session duration of {} seconds is not between {} and {} seconds",
+                    seconds, MIN_SESSION_DURATION_SECONDS, MAX_SESSION_DURATION_SECONDS
+                )));
+            }
+            request.add_param("SessionDuration", &seconds.to_string());
+        }
+        request.add_param("Session", &session.to_string());
+        request.complement();
+        Ok(request)
+    }
+}
+
+fn parse_signin_token(body: &[u8]) -> Result<String, FederationError> {
+    let invalid = || {
+        FederationError::InvalidResponse(format!(
+            "Federation endpoint returned no sign-in token: {}",
+            String::from_utf8_lossy(body)
+        ))
+    };
+    let response: Value = serde_json::from_slice(body).map_err(|_| invalid())?;
+    response["SigninToken"]
+        .as_str()
+        .map(ToOwned::to_owned)
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate rusoto_mock;
+
+    use self::rusoto_mock::MockRequestDispatcher;
+    use super::*;
+
+    fn credentials() -> AwsCredentials {
+        AwsCredentials::new(
+            "access_key",
+            "secret_key",
+            Some("session_token".to_owned()),
+            None,
+        )
+    }
+
+    #[test]
+    fn uses_regional_signin_endpoints() {
+        assert_eq!(
+            signin_hostname(&Region::UsEast1),
+            Some("signin.aws.amazon.com".to_owned())
+        );
+        assert_eq!(
+            signin_hostname(&Region::EuWest1),
+            Some("eu-west-1.signin.aws.amazon.com".to_owned())
+        );
+        assert_eq!(
+            signin_hostname(&Region::CnNorth1),
+            Some("signin.amazonaws.cn".to_owned())
+        );
+        assert_eq!(
+            signin_hostname(&Region::UsGovWest1),
+            Some("signin.amazonaws-us-gov.com".to_owned())
+        );
+    }
+
+    #[test]
+    fn creates_login_url() {
+        let dispatcher = MockRequestDispatcher::with_status(200)
+            .with_body(r#"{"SigninToken": "sign+in/token"}"#)
+            .with_request_checker(|request: &SignedRequest| {
+                assert_eq!(request.hostname(), "eu-west-1.signin.aws.amazon.com");
+                assert_eq!(request.path(), "/federation");
+                assert_eq!(
+                    request.params.get("Action"),
+                    Some(&Some("getSigninToken".to_owned()))
+                );
+                assert_eq!(
+                    request.params.get("SessionDuration"),
+                    Some(&Some("3600".to_owned()))
+                );
+                let session: Value =
+                    serde_json::from_str(request.params["Session"].as_ref().unwrap()).unwrap();
+                assert_eq!(session["sessionId"], "access_key");
+                assert_eq!(session["sessionKey"], "secret_key");
+                assert_eq!(session["sessionToken"], "session_token");
+            });
+        let mut federation = ConsoleFederation::new_with(dispatcher, Region::EuWest1);
+        federation.set_session_duration(Duration::from_secs(3600));
+
+        let url = federation
+            .login_url(
+                &credentials(),
+                &console_url(&Region::EuWest1),
+                "https://example.com/login",
+            )
+            .sync()
+            .unwrap();
+
+        assert_eq!(
+            url,
+            "https://eu-west-1.signin.aws.amazon.com/federation?Action=login\
+             &Issuer=https%3A%2F%2Fexample.com%2Flogin\
+             &Destination=https%3A%2F%2Feu-west-1.console.aws.amazon.com%2Fconsole%2Fhome%3Fregion%3Deu-west-1\
+             &SigninToken=sign%2Bin%2Ftoken"
+        );
+    }
+
+    #[test]
+    fn requires_session_token() {
+        let federation =
+            ConsoleFederation::new_with(MockRequestDispatcher::with_status(200), Region::UsEast1);
+        let credentials = AwsCredentials::new("access_key", "secret_key", None, None);
+
+        assert_eq!(
+            federation.signin_token(&credentials).sync(),
+            Err(RusotoError::Service(FederationError::MissingSessionToken))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_session_durations() {
+        let mut federation =
+            ConsoleFederation::new_with(MockRequestDispatcher::with_status(200), Region::UsEast1);
+        federation.set_session_duration(Duration::from_secs(60));
+
+        match federation.signin_token(&credentials()).sync() {
+            Err(RusotoError::Service(FederationError::InvalidSessionDuration(_))) => {}
+            other => panic!("expected an invalid session duration, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reports_responses_without_token() {
+        let federation = ConsoleFederation::new_with(
+            MockRequestDispatcher::with_status(200).with_body("{}"),
+            Region::UsEast1,
+        );
+
+        match federation.signin_token(&credentials()).sync() {
+            Err(RusotoError::Service(FederationError::InvalidResponse(_))) => {}
+            other => panic!("expected an invalid response, got {:?}", other),
+        }
+    }
+}
@@ -1,6 +1,10 @@
 mod credential;
+mod federation;
+mod profile;
 
 pub use self::credential::{
     NewAwsCredsForStsCreds, StsAssumeRoleSessionCredentialsProvider, StsSessionCredentialsProvider,
     StsWebIdentityFederationSessionCredentialsProvider,
 };
+pub use self::federation::{console_url, ConsoleFederation, FederationError};
+pub use self::profile::StsProfileCredentialsProvider;
@@ -0,0 +1,512 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use futures::future;
+use futures::{Future, Poll};
+use sha1::{Digest, Sha1};
+
+use rusoto_core::credential::{
+    AwsCredentials, ContainerProvider, CredentialSource, EnvironmentProvider,
+    InstanceMetadataProvider, ProfileProvider, ProfileRole, RoleChainSource, StaticProvider,
+};
+use rusoto_core::timestamp;
+use rusoto_core::{
+    CredentialsError, DispatchSignedRequest, HttpClient, ProvideAwsCredentials, Region,
+};
+
+use crate::{AssumeRoleRequest, Credentials, NewAwsCredsForStsCreds, Sts, StsClient};
+
+/// Cached credentials are only used if they stay valid for at least this long, like in the
+/// AWS CLI.
+const CACHE_EXPIRY_WINDOW_SECONDS: i64 = 15 * 60;
+
+type StsClientFactory = Arc<dyn Fn(AwsCredentials) -> StsClient + Send + Sync>;
+type MfaCodeProvider = Arc<dyn Fn(&str) -> Result<String, CredentialsError> + Send + Sync>;
+type CredentialsFuture = Box<dyn Future<Item = AwsCredentials, Error = CredentialsError> + Send>;
+
+/// [AwsCredentials](../rusoto_credential/struct.AwsCredentials.html) provider for a profile of
+/// the shared config file, which assumes the roles configured with `role_arn`, `source_profile`
+/// and `credential_source`.
+///
+/// Source profiles are followed recursively, so a profile can assume a role with the credentials
+/// of another role. Profiles without a role get their credentials from the wrapped
+/// `ProfileProvider`.
+///
+/// Assumed role credentials are cached in `~/.aws/cli/cache` under the same keys as the AWS CLI,
+/// so sessions are shared with it. This matters most for roles with an `mfa_serial`, which need
+/// an MFA code from `set_mfa_code_provider` whenever no cached session is left.
+pub struct StsProfileCredentialsProvider {
+    profile_provider: ProfileProvider,
+    client_factory: StsClientFactory,
+    mfa_code_provider: Option<MfaCodeProvider>,
+    cache_dir: Option<PathBuf>,
+}
+
+impl StsProfileCredentialsProvider {
+    /// Creates a new `StsProfileCredentialsProvider` for the profile of `profile_provider`,
+    /// calling STS in `region`.
+    pub fn new(profile_provider: ProfileProvider, region: Region) -> StsProfileCredentialsProvider {
+        let dispatcher = HttpClient::new().expect("failed to create request dispatcher");
+        StsProfileCredentialsProvider::new_with(dispatcher, profile_provider, region)
+    }
+
+    /// Creates a new `StsProfileCredentialsProvider` which sends its STS requests with
+    /// `request_dispatcher`.
+    pub fn new_with<D>(
+        request_dispatcher: D,
+        profile_provider: ProfileProvider,
+        region: Region,
+    ) -> StsProfileCredentialsProvider
+    where
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        let dispatcher = Arc::new(request_dispatcher);
+        let cache_dir = profile_provider
+            .environment()
+            .home_dir()
+            .map(|home| home.join(".aws").join("cli").join("cache"));
+        StsProfileCredentialsProvider {
+            profile_provider,
+            client_factory: Arc::new(move |credentials: AwsCredentials| {
+                let credentials_provider = StaticProvider::new(
+                    credentials.aws_access_key_id().to_owned(),
+                    credentials.aws_secret_access_key().to_owned(),
+                    credentials.token().clone(),
+                    None,
+                );
+                StsClient::new_with(dispatcher.clone(), credentials_provider, region.clone())
+            }),
+            mfa_code_provider: None,
+            cache_dir,
+        }
+    }
+
+    /// Set the function asked for the current code of an MFA device, given its serial number,
+    /// when a role with an `mfa_serial` is assumed.
+    pub fn set_mfa_code_provider<F>(&mut self, mfa_code_provider: F)
+    where
+        F: Fn(&str) -> Result<String, CredentialsError> + Send + Sync + 'static,
+    {
+        self.mfa_code_provider = Some(Arc::new(mfa_code_provider));
+    }
+
+    /// Set the directory assumed role credentials are cached in.
+    pub fn set_cache_dir<P>(&mut self, cache_dir: P)
+    where
+        P: Into<PathBuf>,
+    {
+        self.cache_dir = Some(cache_dir.into());
+    }
+
+    /// Disable caching of assumed role credentials.
+    pub fn clear_cache_dir(&mut self) {
+        self.cache_dir = None;
+    }
+
+    fn source_credentials(&self, source: &RoleChainSource) -> CredentialsFuture {
+        match *source {
+            RoleChainSource::Profile(ref profile) => {
+                let mut provider = self.profile_provider.clone();
+                provider.set_profile(profile.as_str());
+                Box::new(provider.credentials())
+            }
+            RoleChainSource::CredentialSource(CredentialSource::Environment) => Box::new(
+                EnvironmentProvider::with_environment(self.profile_provider.environment())
+                    .credentials(),
+            ),
+            RoleChainSource::CredentialSource(CredentialSource::Ec2InstanceMetadata) => {
+                Box::new(InstanceMetadataProvider::new().credentials())
+            }
+            RoleChainSource::CredentialSource(CredentialSource::EcsContainer) => {
+                Box::new(ContainerProvider::new().credentials())
+            }
+        }
+    }
+}
+
+pub struct StsProfileCredentialsProviderFuture {
+    inner: CredentialsFuture,
+}
+
+impl Future for StsProfileCredentialsProviderFuture {
+    type Item = AwsCredentials;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+impl ProvideAwsCredentials for StsProfileCredentialsProvider {
+    type Future = StsProfileCredentialsProviderFuture;
+
+    fn credentials(&self) -> Self::Future {
+        let chain = match self.profile_provider.role_chain() {
+            Ok(Some(chain)) => chain,
+            Ok(None) => {
+                return StsProfileCredentialsProviderFuture {
+                    inner: Box::new(self.profile_provider.credentials()),
+                }
+            }
+            Err(err) => {
+                return StsProfileCredentialsProviderFuture {
+                    inner: Box::new(future::err(err)),
+                }
+            }
+        };
+
+        // continue the chain from the last role with cached credentials
+        let cached = self.cache_dir.as_ref().and_then(|cache_dir| {
+            chain.roles.iter().enumerate().rev().find_map(|(i, role)| {
+                cached_credentials(&cache_file(cache_dir, role)).map(|creds| (i + 1, creds))
+            })
+        });
+        let (start, source): (usize, CredentialsFuture) = match cached {
+            Some((start, creds)) => (start, Box::new(future::ok(creds))),
+            None => (0, self.source_credentials(&chain.source)),
+        };
+
+        let inner = chain.roles[start..].iter().cloned().fold(
+            source,
+            |previous, role| -> CredentialsFuture {
+                let client_factory = self.client_factory.clone();
+                let mfa_code_provider = self.mfa_code_provider.clone();
+                let cache_file = self
+                    .cache_dir
+                    .as_ref()
+                    .map(|cache_dir| cache_file(cache_dir, &role));
+                Box::new(previous.and_then(move |credentials| {
+                    assume_role(
+                        &client_factory,
+                        mfa_code_provider.as_ref(),
+                        cache_file,
+                        role,
+                        credentials,
+                    )
+                }))
+            },
+        );
+
+        StsProfileCredentialsProviderFuture { inner }
+    }
+}
+
+fn assume_role(
+    client_factory: &StsClientFactory,
+    mfa_code_provider: Option<&MfaCodeProvider>,
+    cache_file: Option<PathBuf>,
+    role: ProfileRole,
+    credentials: AwsCredentials,
+) -> CredentialsFuture {
+    let token_code = match (&role.mfa_serial, mfa_code_provider) {
+        (Some(serial), Some(mfa_code_provider)) => match mfa_code_provider(serial) {
+            Ok(code) => Some(code),
+            Err(err) => return Box::new(future::err(err)),
+        },
+        (Some(serial), None) => {
+            return Box::new(future::err(CredentialsError::new(format!(
+                "Profile '{}' requires a code for MFA device {}, but no MFA code provider is set",
+                role.profile, serial
+            ))))
+        }
+        (None, _) => None,
+    };
+
+    let request = AssumeRoleRequest {
+        role_arn: role.role_arn,
+        role_session_name: role
+            .role_session_name
+            .unwrap_or_else(|| format!("rusoto-session-{}", Utc::now().timestamp())),
+        duration_seconds: role.duration_seconds,
+        external_id: role.external_id,
+        serial_number: role.mfa_serial,
+        token_code,
+        ..Default::default()
+    };
+    let future = client_factory(credentials)
+        .assume_role(request)
+        .map_err(|err| CredentialsError::new(format!("Sts AssumeRoleError: {:?}", err)))
+        .and_then(move |resp| {
+            let creds = resp
+                .credentials
+                .ok_or_else(|| CredentialsError::new("no credentials in response"))?;
+            if let Some(cache_file) = cache_file {
+                // a failure to cache only costs another AssumeRole call next time
+                let _ = write_cached_credentials(&cache_file, &creds);
+            }
+            AwsCredentials::new_for_credentials(creds)
+        });
+    Box::new(future)
+}
+
+/// The file the credentials of `role` are cached in.
+///
+/// Like in the AWS CLI, the name is the SHA-1 of the `AssumeRole` parameters other than the
+/// session name, serialized as JSON with sorted keys.
+fn cache_file(cache_dir: &Path, role: &ProfileRole) -> PathBuf {
+    let json_string = |value: &str| serde_json::Value::from(value).to_string();
+    let mut args = Vec::new();
+    if let Some(duration_seconds) = role.duration_seconds {
+        args.push(format!("\"DurationSeconds\": {}", duration_seconds));
+    }
+    if let Some(ref external_id) = role.external_id {
+        args.push(format!("\"ExternalId\": {}", json_string(external_id)));
+    }
+    args.push(format!("\"RoleArn\": {}", json_string(&role.role_arn)));
+    if let Some(ref mfa_serial) = role.mfa_serial {
+        args.push(format!("\"SerialNumber\": {}", json_string(mfa_serial)));
+    }
+    let key = hex::encode(Sha1::digest(format!("{{{}}}", args.join(", ")).as_bytes()));
+    cache_dir.join(format!("{}.json", key))
+}
+
+/// Parses the expiration of cached credentials. Besides RFC 3339, the AWS CLI writes times like
+/// `2019-11-08T10:15:30UTC`.
+fn parse_expiration(expiration: &str) -> Option<DateTime<Utc>> {
+    if let Ok(expiration) = DateTime::parse_from_rfc3339(expiration) {
+        return Some(expiration.with_timezone(&Utc));
+    }
+    let naive = expiration.trim_end_matches("UTC");
+    NaiveDateTime::parse_from_str(naive, "%Y-%m-%dT%H:%M:%S")
+        .ok()
+        .map(|naive| DateTime::from_utc(naive, Utc))
+}
+
+fn cached_credentials(cache_file: &Path) -> Option<AwsCredentials> {
+    let contents = fs::read(cache_file).ok()?;
+    let cached: serde_json::Value = serde_json::from_slice(&contents).ok()?;
+    let creds = &cached["Credentials"];
+    let expiration = parse_expiration(creds["Expiration"].as_str()?)?;
+    if expiration - Utc::now() < Duration::seconds(CACHE_EXPIRY_WINDOW_SECONDS) {
+        return None;
+    }
+    Some(AwsCredentials::new(
+        creds["AccessKeyId"].as_str()?,
+        creds["SecretAccessKey"].as_str()?,
+        creds["SessionToken"].as_str().map(str::to_owned),
+        Some(expiration),
+    ))
+}
+
+fn write_cached_credentials(cache_file: &Path, creds: &Credentials) -> io::Result<()> {
+    let cached = serde_json::json!({
+        "Credentials": {
+            "AccessKeyId": creds.access_key_id,
+            "SecretAccessKey": creds.secret_access_key,
+            "SessionToken": creds.session_token,
+            "Expiration": timestamp::to_iso8601(&creds.expiration),
+        }
+    });
+    if let Some(cache_dir) = cache_file.parent() {
+        fs::create_dir_all(cache_dir)?;
+    }
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        // the file holds secrets, keep it private like the AWS CLI does
+        options.mode(0o600);
+    }
+    options
+        .open(cache_file)?
+        .write_all(cached.to_string().as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate rusoto_mock;
+
+    use std::env;
+    use std::sync::Mutex;
+
+    use chrono::TimeZone;
+
+    use self::rusoto_mock::MockRequestDispatcher;
+    use super::*;
+    use rusoto_core::credential::VirtualEnvironment;
+    use rusoto_core::param::Params;
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+
+    const ASSUME_ROLE_RESPONSE: &str = r#"<AssumeRoleResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleResult>
+    <Credentials>
+      <AccessKeyId>role_access_key</AccessKeyId>
+      <SecretAccessKey>role_secret_key</SecretAccessKey>
+      <SessionToken>role_session_token</SessionToken>
+      <Expiration>2100-01-01T00:00:00Z</Expiration>
+    </Credentials>
+  </AssumeRoleResult>
+  <ResponseMetadata>
+    <RequestId>c6104cbe-af31-11e0-8154-cbc7ccf896c7</RequestId>
+  </ResponseMetadata>
+</AssumeRoleResponse>"#;
+
+    const CONFIG: &str = "[default]
+aws_access_key_id = source_access_key
+aws_secret_access_key = source_secret_key
+
+[profile admin]
+role_arn = arn:aws:iam::123456789012:role/admin
+source_profile = default
+
+[profile audit]
+role_arn = arn:aws:iam::210987654321:role/audit
+source_profile = admin
+external_id = audit-id
+
+[profile mfa]
+role_arn = arn:aws:iam::123456789012:role/mfa
+source_profile = default
+mfa_serial = arn:aws:iam::123456789012:mfa/user
+";
+
+    /// Creates a home directory holding `CONFIG` and returns a provider for `profile` in it.
+    fn profile_provider(home: &str, profile: &str) -> ProfileProvider {
+        let home = env::temp_dir().join(home);
+        let _ = fs::remove_dir_all(&home);
+        fs::create_dir_all(home.join(".aws")).unwrap();
+        fs::write(home.join(".aws").join("config"), CONFIG).unwrap();
+        let environment = VirtualEnvironment::new().with_home_dir(home);
+        let mut provider = ProfileProvider::with_environment(environment).unwrap();
+        provider.set_profile(profile);
+        provider
+    }
+
+    fn request_params(request: &SignedRequest) -> Params {
+        match request.payload {
+            Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                serde_urlencoded::from_bytes(buffer).unwrap()
+            }
+            _ => panic!("Unexpected request.payload: {:?}", request.payload),
+        }
+    }
+
+    #[test]
+    fn cache_keys_match_the_aws_cli() {
+        let role = ProfileRole {
+            profile: "audit".to_owned(),
+            role_arn: "arn:aws:iam::210987654321:role/audit".to_owned(),
+            external_id: Some("audit-id".to_owned()),
+            mfa_serial: None,
+            role_session_name: Some("audit-session".to_owned()),
+            duration_seconds: None,
+        };
+        assert_eq!(
+            cache_file(Path::new("cache"), &role),
+            Path::new("cache").join("ec3f0674460b2feea8926dea4f257fc63792f05a.json")
+        );
+
+        let role = ProfileRole {
+            profile: "admin".to_owned(),
+            role_arn: "arn:aws:iam::123456789012:role/admin".to_owned(),
+            external_id: None,
+            mfa_serial: Some("arn:aws:iam::123456789012:mfa/user".to_owned()),
+            role_session_name: None,
+            duration_seconds: Some(3600),
+        };
+        assert_eq!(
+            cache_file(Path::new("cache"), &role),
+            Path::new("cache").join("862146d6b3fa8a78c2ed78db27bbcad6f60a21b1.json")
+        );
+    }
+
+    #[test]
+    fn parses_cached_expirations() {
+        let expected = Utc.ymd(2019, 11, 8).and_hms(10, 15, 30);
+        assert_eq!(parse_expiration("2019-11-08T10:15:30Z"), Some(expected));
+        assert_eq!(
+            parse_expiration("2019-11-08T10:15:30+00:00"),
+            Some(expected)
+        );
+        assert_eq!(parse_expiration("2019-11-08T10:15:30UTC"), Some(expected));
+        assert_eq!(parse_expiration("yesterday"), None);
+    }
+
+    #[test]
+    fn assumes_chained_roles_and_caches_them() {
+        let provider = profile_provider("rusoto_sts_profile_chain", "audit");
+        let home = provider.environment().home_dir().unwrap();
+
+        let role_arns = Arc::new(Mutex::new(Vec::new()));
+        let requested = role_arns.clone();
+        let mock = MockRequestDispatcher::with_status(200)
+            .with_body(ASSUME_ROLE_RESPONSE)
+            .with_request_checker(move |request: &SignedRequest| {
+                let params = request_params(request);
+                requested
+                    .lock()
+                    .unwrap()
+                    .push(params.get("RoleArn").cloned().unwrap().unwrap());
+            });
+        let sts = StsProfileCredentialsProvider::new_with(mock, provider.clone(), Region::UsEast1);
+        let creds = sts.credentials().wait().unwrap();
+
+        assert_eq!(creds.aws_access_key_id(), "role_access_key");
+        assert_eq!(creds.token(), &Some("role_session_token".to_owned()));
+        assert_eq!(
+            *role_arns.lock().unwrap(),
+            vec![
+                "arn:aws:iam::123456789012:role/admin",
+                "arn:aws:iam::210987654321:role/audit",
+            ]
+        );
+        assert!(home
+            .join(".aws/cli/cache/ec3f0674460b2feea8926dea4f257fc63792f05a.json")
+            .is_file());
+
+        // the cached session is used without calling STS again
+        let mock = MockRequestDispatcher::with_status(500).with_request_checker(|_| {
+            panic!("cached credentials should have been used");
+        });
+        let sts = StsProfileCredentialsProvider::new_with(mock, provider, Region::UsEast1);
+        let creds = sts.credentials().wait().unwrap();
+        assert_eq!(creds.aws_access_key_id(), "role_access_key");
+        assert_eq!(
+            creds.expires_at(),
+            &Some(Utc.ymd(2100, 1, 1).and_hms(0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn profiles_without_roles_use_the_profile_provider() {
+        let provider = profile_provider("rusoto_sts_profile_plain", "default");
+        let mock = MockRequestDispatcher::with_status(500).with_request_checker(|_| {
+            panic!("no role should have been assumed");
+        });
+        let sts = StsProfileCredentialsProvider::new_with(mock, provider, Region::UsEast1);
+        let creds = sts.credentials().wait().unwrap();
+        assert_eq!(creds.aws_access_key_id(), "source_access_key");
+    }
+
+    #[test]
+    fn asks_for_mfa_codes() {
+        let provider = profile_provider("rusoto_sts_profile_mfa", "mfa");
+        let mock = MockRequestDispatcher::with_status(200)
+            .with_body(ASSUME_ROLE_RESPONSE)
+            .with_request_checker(|request: &SignedRequest| {
+                let params = request_params(request);
+                assert_eq!(
+                    params.get("SerialNumber"),
+                    Some(&Some("arn:aws:iam::123456789012:mfa/user".to_owned()))
+                );
+                assert_eq!(params.get("TokenCode"), Some(&Some("123456".to_owned())));
+            });
+        let mut sts = StsProfileCredentialsProvider::new_with(mock, provider, Region::UsEast1);
+        sts.clear_cache_dir();
+
+        let err = sts.credentials().wait().unwrap_err();
+        assert!(err.message.contains("no MFA code provider is set"));
+
+        sts.set_mfa_code_provider(|serial| {
+            assert_eq!(serial, "arn:aws:iam::123456789012:mfa/user");
+            Ok("123456".to_owned())
+        });
+        let creds = sts.credentials().wait().unwrap();
+        assert_eq!(creds.aws_access_key_id(), "role_access_key");
+    }
+}
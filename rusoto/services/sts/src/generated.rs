@@ -28,15 +28,19 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
 use xml::EventReader;
 
-struct AccessKeyIdTypeDeserializer;
+pub(crate) struct AccessKeyIdTypeDeserializer;
 impl AccessKeyIdTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -44,10 +48,13 @@ impl AccessKeyIdTypeDeserializer {
         Ok(obj)
     }
 }
-struct AccessKeySecretTypeDeserializer;
+pub(crate) struct AccessKeySecretTypeDeserializer;
 impl AccessKeySecretTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -55,10 +62,13 @@ impl AccessKeySecretTypeDeserializer {
         Ok(obj)
     }
 }
-struct AccountTypeDeserializer;
+pub(crate) struct AccountTypeDeserializer;
 impl AccountTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -66,10 +76,13 @@ impl AccountTypeDeserializer {
         Ok(obj)
     }
 }
-struct ArnTypeDeserializer;
+pub(crate) struct ArnTypeDeserializer;
 impl ArnTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -97,6 +110,94 @@ pub struct AssumeRoleRequest {
     pub token_code: Option<String>,
 }
 
+impl AssumeRoleRequest {
+    /// Returns a builder for `AssumeRoleRequest`, checking its required members when built.
+    pub fn builder() -> AssumeRoleRequestBuilder {
+        AssumeRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssumeRoleRequest`](struct.AssumeRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct AssumeRoleRequestBuilder {
+    duration_seconds: Option<i64>,
+    external_id: Option<String>,
+    policy: Option<String>,
+    policy_arns: Option<Vec<PolicyDescriptorType>>,
+    role_arn: Option<String>,
+    role_session_name: Option<String>,
+    serial_number: Option<String>,
+    token_code: Option<String>,
+}
+
+impl AssumeRoleRequestBuilder {
+    /// Sets the [`duration_seconds`](struct.AssumeRoleRequest.html#structfield.duration_seconds) member.
+    pub fn duration_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`external_id`](struct.AssumeRoleRequest.html#structfield.external_id) member.
+    pub fn external_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.external_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.AssumeRoleRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_arns`](struct.AssumeRoleRequest.html#structfield.policy_arns) member.
+    pub fn policy_arns<T: Into<Vec<PolicyDescriptorType>>>(mut self, value: T) -> Self {
+        self.policy_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.AssumeRoleRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_session_name`](struct.AssumeRoleRequest.html#structfield.role_session_name) member.
+    pub fn role_session_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_session_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`serial_number`](struct.AssumeRoleRequest.html#structfield.serial_number) member.
+    pub fn serial_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.serial_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token_code`](struct.AssumeRoleRequest.html#structfield.token_code) member.
+    pub fn token_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.token_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssumeRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AssumeRoleRequest, ::rusoto_core::BuildError> {
+        Ok(AssumeRoleRequest {
+            duration_seconds: self.duration_seconds,
+            external_id: self.external_id,
+            policy: self.policy,
+            policy_arns: self.policy_arns,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            role_session_name: self
+                .role_session_name
+                .ok_or(::rusoto_core::BuildError::missing("role_session_name"))?,
+            serial_number: self.serial_number,
+            token_code: self.token_code,
+        })
+    }
+}
+
 /// Serialize `AssumeRoleRequest` contents to a `SignedRequest`.
 struct AssumeRoleRequestSerializer;
 impl AssumeRoleRequestSerializer {
@@ -147,10 +248,10 @@ pub struct AssumeRoleResponse {
     pub packed_policy_size: Option<i64>,
 }
 
-struct AssumeRoleResponseDeserializer;
+pub(crate) struct AssumeRoleResponseDeserializer;
 impl AssumeRoleResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AssumeRoleResponse, XmlParseError> {
@@ -194,6 +295,82 @@ pub struct AssumeRoleWithSAMLRequest {
     pub saml_assertion: String,
 }
 
+impl AssumeRoleWithSAMLRequest {
+    /// Returns a builder for `AssumeRoleWithSAMLRequest`, checking its required members when built.
+    pub fn builder() -> AssumeRoleWithSAMLRequestBuilder {
+        AssumeRoleWithSAMLRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssumeRoleWithSAMLRequest`](struct.AssumeRoleWithSAMLRequest.html).
+#[derive(Default, Debug)]
+pub struct AssumeRoleWithSAMLRequestBuilder {
+    duration_seconds: Option<i64>,
+    policy: Option<String>,
+    policy_arns: Option<Vec<PolicyDescriptorType>>,
+    principal_arn: Option<String>,
+    role_arn: Option<String>,
+    saml_assertion: Option<String>,
+}
+
+impl AssumeRoleWithSAMLRequestBuilder {
+    /// Sets the [`duration_seconds`](struct.AssumeRoleWithSAMLRequest.html#structfield.duration_seconds) member.
+    pub fn duration_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.AssumeRoleWithSAMLRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_arns`](struct.AssumeRoleWithSAMLRequest.html#structfield.policy_arns) member.
+    pub fn policy_arns<T: Into<Vec<PolicyDescriptorType>>>(mut self, value: T) -> Self {
+        self.policy_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_arn`](struct.AssumeRoleWithSAMLRequest.html#structfield.principal_arn) member.
+    pub fn principal_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.AssumeRoleWithSAMLRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`saml_assertion`](struct.AssumeRoleWithSAMLRequest.html#structfield.saml_assertion) member.
+    pub fn saml_assertion<T: Into<String>>(mut self, value: T) -> Self {
+        self.saml_assertion = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssumeRoleWithSAMLRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssumeRoleWithSAMLRequest, ::rusoto_core::BuildError> {
+        Ok(AssumeRoleWithSAMLRequest {
+            duration_seconds: self.duration_seconds,
+            policy: self.policy,
+            policy_arns: self.policy_arns,
+            principal_arn: self
+                .principal_arn
+                .ok_or(::rusoto_core::BuildError::missing("principal_arn"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            saml_assertion: self
+                .saml_assertion
+                .ok_or(::rusoto_core::BuildError::missing("saml_assertion"))?,
+        })
+    }
+}
+
 /// Serialize `AssumeRoleWithSAMLRequest` contents to a `SignedRequest`.
 struct AssumeRoleWithSAMLRequestSerializer;
 impl AssumeRoleWithSAMLRequestSerializer {
@@ -246,10 +423,10 @@ pub struct AssumeRoleWithSAMLResponse {
     pub subject_type: Option<String>,
 }
 
-struct AssumeRoleWithSAMLResponseDeserializer;
+pub(crate) struct AssumeRoleWithSAMLResponseDeserializer;
 impl AssumeRoleWithSAMLResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AssumeRoleWithSAMLResponse, XmlParseError> {
@@ -319,6 +496,90 @@ pub struct AssumeRoleWithWebIdentityRequest {
     pub web_identity_token: String,
 }
 
+impl AssumeRoleWithWebIdentityRequest {
+    /// Returns a builder for `AssumeRoleWithWebIdentityRequest`, checking its required members when built.
+    pub fn builder() -> AssumeRoleWithWebIdentityRequestBuilder {
+        AssumeRoleWithWebIdentityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssumeRoleWithWebIdentityRequest`](struct.AssumeRoleWithWebIdentityRequest.html).
+#[derive(Default, Debug)]
+pub struct AssumeRoleWithWebIdentityRequestBuilder {
+    duration_seconds: Option<i64>,
+    policy: Option<String>,
+    policy_arns: Option<Vec<PolicyDescriptorType>>,
+    provider_id: Option<String>,
+    role_arn: Option<String>,
+    role_session_name: Option<String>,
+    web_identity_token: Option<String>,
+}
+
+impl AssumeRoleWithWebIdentityRequestBuilder {
+    /// Sets the [`duration_seconds`](struct.AssumeRoleWithWebIdentityRequest.html#structfield.duration_seconds) member.
+    pub fn duration_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.AssumeRoleWithWebIdentityRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_arns`](struct.AssumeRoleWithWebIdentityRequest.html#structfield.policy_arns) member.
+    pub fn policy_arns<T: Into<Vec<PolicyDescriptorType>>>(mut self, value: T) -> Self {
+        self.policy_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_id`](struct.AssumeRoleWithWebIdentityRequest.html#structfield.provider_id) member.
+    pub fn provider_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.AssumeRoleWithWebIdentityRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_session_name`](struct.AssumeRoleWithWebIdentityRequest.html#structfield.role_session_name) member.
+    pub fn role_session_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_session_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`web_identity_token`](struct.AssumeRoleWithWebIdentityRequest.html#structfield.web_identity_token) member.
+    pub fn web_identity_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.web_identity_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssumeRoleWithWebIdentityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssumeRoleWithWebIdentityRequest, ::rusoto_core::BuildError> {
+        Ok(AssumeRoleWithWebIdentityRequest {
+            duration_seconds: self.duration_seconds,
+            policy: self.policy,
+            policy_arns: self.policy_arns,
+            provider_id: self.provider_id,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            role_session_name: self
+                .role_session_name
+                .ok_or(::rusoto_core::BuildError::missing("role_session_name"))?,
+            web_identity_token: self
+                .web_identity_token
+                .ok_or(::rusoto_core::BuildError::missing("web_identity_token"))?,
+        })
+    }
+}
+
 /// Serialize `AssumeRoleWithWebIdentityRequest` contents to a `SignedRequest`.
 struct AssumeRoleWithWebIdentityRequestSerializer;
 impl AssumeRoleWithWebIdentityRequestSerializer {
@@ -373,10 +634,10 @@ pub struct AssumeRoleWithWebIdentityResponse {
     pub subject_from_web_identity_token: Option<String>,
 }
 
-struct AssumeRoleWithWebIdentityResponseDeserializer;
+pub(crate) struct AssumeRoleWithWebIdentityResponseDeserializer;
 impl AssumeRoleWithWebIdentityResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AssumeRoleWithWebIdentityResponse, XmlParseError> {
@@ -422,10 +683,13 @@ impl AssumeRoleWithWebIdentityResponseDeserializer {
         )
     }
 }
-struct AssumedRoleIdTypeDeserializer;
+pub(crate) struct AssumedRoleIdTypeDeserializer;
 impl AssumedRoleIdTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -442,10 +706,10 @@ pub struct AssumedRoleUser {
     pub assumed_role_id: String,
 }
 
-struct AssumedRoleUserDeserializer;
+pub(crate) struct AssumedRoleUserDeserializer;
 impl AssumedRoleUserDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AssumedRoleUser, XmlParseError> {
@@ -464,10 +728,13 @@ impl AssumedRoleUserDeserializer {
         })
     }
 }
-struct AudienceDeserializer;
+pub(crate) struct AudienceDeserializer;
 impl AudienceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -481,17 +748,17 @@ pub struct Credentials {
     /// <p>The access key ID that identifies the temporary security credentials.</p>
     pub access_key_id: String,
     /// <p>The date on which the current credentials expire.</p>
-    pub expiration: String,
+    pub expiration: IsoTimestamp,
     /// <p>The secret access key that can be used to sign requests.</p>
     pub secret_access_key: String,
     /// <p>The token that users must pass to the service API to use the temporary credentials.</p>
     pub session_token: String,
 }
 
-struct CredentialsDeserializer;
+pub(crate) struct CredentialsDeserializer;
 impl CredentialsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Credentials, XmlParseError> {
@@ -517,12 +784,15 @@ impl CredentialsDeserializer {
         })
     }
 }
-struct DateTypeDeserializer;
+pub(crate) struct DateTypeDeserializer;
 impl DateTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -534,6 +804,38 @@ pub struct DecodeAuthorizationMessageRequest {
     pub encoded_message: String,
 }
 
+impl DecodeAuthorizationMessageRequest {
+    /// Returns a builder for `DecodeAuthorizationMessageRequest`, checking its required members when built.
+    pub fn builder() -> DecodeAuthorizationMessageRequestBuilder {
+        DecodeAuthorizationMessageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DecodeAuthorizationMessageRequest`](struct.DecodeAuthorizationMessageRequest.html).
+#[derive(Default, Debug)]
+pub struct DecodeAuthorizationMessageRequestBuilder {
+    encoded_message: Option<String>,
+}
+
+impl DecodeAuthorizationMessageRequestBuilder {
+    /// Sets the [`encoded_message`](struct.DecodeAuthorizationMessageRequest.html#structfield.encoded_message) member.
+    pub fn encoded_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.encoded_message = Some(value.into());
+        self
+    }
+
+    /// Builds the `DecodeAuthorizationMessageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DecodeAuthorizationMessageRequest, ::rusoto_core::BuildError> {
+        Ok(DecodeAuthorizationMessageRequest {
+            encoded_message: self
+                .encoded_message
+                .ok_or(::rusoto_core::BuildError::missing("encoded_message"))?,
+        })
+    }
+}
+
 /// Serialize `DecodeAuthorizationMessageRequest` contents to a `SignedRequest`.
 struct DecodeAuthorizationMessageRequestSerializer;
 impl DecodeAuthorizationMessageRequestSerializer {
@@ -557,10 +859,10 @@ pub struct DecodeAuthorizationMessageResponse {
     pub decoded_message: Option<String>,
 }
 
-struct DecodeAuthorizationMessageResponseDeserializer;
+pub(crate) struct DecodeAuthorizationMessageResponseDeserializer;
 impl DecodeAuthorizationMessageResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DecodeAuthorizationMessageResponse, XmlParseError> {
@@ -582,10 +884,13 @@ impl DecodeAuthorizationMessageResponseDeserializer {
         )
     }
 }
-struct DecodedMessageTypeDeserializer;
+pub(crate) struct DecodedMessageTypeDeserializer;
 impl DecodedMessageTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -593,10 +898,13 @@ impl DecodedMessageTypeDeserializer {
         Ok(obj)
     }
 }
-struct FederatedIdTypeDeserializer;
+pub(crate) struct FederatedIdTypeDeserializer;
 impl FederatedIdTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -613,10 +921,10 @@ pub struct FederatedUser {
     pub federated_user_id: String,
 }
 
-struct FederatedUserDeserializer;
+pub(crate) struct FederatedUserDeserializer;
 impl FederatedUserDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FederatedUser, XmlParseError> {
@@ -641,6 +949,38 @@ pub struct GetAccessKeyInfoRequest {
     pub access_key_id: String,
 }
 
+impl GetAccessKeyInfoRequest {
+    /// Returns a builder for `GetAccessKeyInfoRequest`, checking its required members when built.
+    pub fn builder() -> GetAccessKeyInfoRequestBuilder {
+        GetAccessKeyInfoRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAccessKeyInfoRequest`](struct.GetAccessKeyInfoRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAccessKeyInfoRequestBuilder {
+    access_key_id: Option<String>,
+}
+
+impl GetAccessKeyInfoRequestBuilder {
+    /// Sets the [`access_key_id`](struct.GetAccessKeyInfoRequest.html#structfield.access_key_id) member.
+    pub fn access_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAccessKeyInfoRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAccessKeyInfoRequest, ::rusoto_core::BuildError> {
+        Ok(GetAccessKeyInfoRequest {
+            access_key_id: self
+                .access_key_id
+                .ok_or(::rusoto_core::BuildError::missing("access_key_id"))?,
+        })
+    }
+}
+
 /// Serialize `GetAccessKeyInfoRequest` contents to a `SignedRequest`.
 struct GetAccessKeyInfoRequestSerializer;
 impl GetAccessKeyInfoRequestSerializer {
@@ -660,10 +1000,10 @@ pub struct GetAccessKeyInfoResponse {
     pub account: Option<String>,
 }
 
-struct GetAccessKeyInfoResponseDeserializer;
+pub(crate) struct GetAccessKeyInfoResponseDeserializer;
 impl GetAccessKeyInfoResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetAccessKeyInfoResponse, XmlParseError> {
@@ -685,6 +1025,26 @@ impl GetAccessKeyInfoResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetCallerIdentityRequest {}
 
+impl GetCallerIdentityRequest {
+    /// Returns a builder for `GetCallerIdentityRequest`, checking its required members when built.
+    pub fn builder() -> GetCallerIdentityRequestBuilder {
+        GetCallerIdentityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCallerIdentityRequest`](struct.GetCallerIdentityRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCallerIdentityRequestBuilder {}
+
+impl GetCallerIdentityRequestBuilder {
+    /// Builds the `GetCallerIdentityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCallerIdentityRequest, ::rusoto_core::BuildError> {
+        Ok(GetCallerIdentityRequest {})
+    }
+}
+
 /// Serialize `GetCallerIdentityRequest` contents to a `SignedRequest`.
 struct GetCallerIdentityRequestSerializer;
 impl GetCallerIdentityRequestSerializer {
@@ -707,10 +1067,10 @@ pub struct GetCallerIdentityResponse {
     pub user_id: Option<String>,
 }
 
-struct GetCallerIdentityResponseDeserializer;
+pub(crate) struct GetCallerIdentityResponseDeserializer;
 impl GetCallerIdentityResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetCallerIdentityResponse, XmlParseError> {
@@ -747,6 +1107,62 @@ pub struct GetFederationTokenRequest {
     pub policy_arns: Option<Vec<PolicyDescriptorType>>,
 }
 
+impl GetFederationTokenRequest {
+    /// Returns a builder for `GetFederationTokenRequest`, checking its required members when built.
+    pub fn builder() -> GetFederationTokenRequestBuilder {
+        GetFederationTokenRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFederationTokenRequest`](struct.GetFederationTokenRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFederationTokenRequestBuilder {
+    duration_seconds: Option<i64>,
+    name: Option<String>,
+    policy: Option<String>,
+    policy_arns: Option<Vec<PolicyDescriptorType>>,
+}
+
+impl GetFederationTokenRequestBuilder {
+    /// Sets the [`duration_seconds`](struct.GetFederationTokenRequest.html#structfield.duration_seconds) member.
+    pub fn duration_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetFederationTokenRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.GetFederationTokenRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_arns`](struct.GetFederationTokenRequest.html#structfield.policy_arns) member.
+    pub fn policy_arns<T: Into<Vec<PolicyDescriptorType>>>(mut self, value: T) -> Self {
+        self.policy_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFederationTokenRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFederationTokenRequest, ::rusoto_core::BuildError> {
+        Ok(GetFederationTokenRequest {
+            duration_seconds: self.duration_seconds,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            policy: self.policy,
+            policy_arns: self.policy_arns,
+        })
+    }
+}
+
 /// Serialize `GetFederationTokenRequest` contents to a `SignedRequest`.
 struct GetFederationTokenRequestSerializer;
 impl GetFederationTokenRequestSerializer {
@@ -784,10 +1200,10 @@ pub struct GetFederationTokenResponse {
     pub packed_policy_size: Option<i64>,
 }
 
-struct GetFederationTokenResponseDeserializer;
+pub(crate) struct GetFederationTokenResponseDeserializer;
 impl GetFederationTokenResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetFederationTokenResponse, XmlParseError> {
@@ -830,6 +1246,50 @@ pub struct GetSessionTokenRequest {
     pub token_code: Option<String>,
 }
 
+impl GetSessionTokenRequest {
+    /// Returns a builder for `GetSessionTokenRequest`, checking its required members when built.
+    pub fn builder() -> GetSessionTokenRequestBuilder {
+        GetSessionTokenRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSessionTokenRequest`](struct.GetSessionTokenRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSessionTokenRequestBuilder {
+    duration_seconds: Option<i64>,
+    serial_number: Option<String>,
+    token_code: Option<String>,
+}
+
+impl GetSessionTokenRequestBuilder {
+    /// Sets the [`duration_seconds`](struct.GetSessionTokenRequest.html#structfield.duration_seconds) member.
+    pub fn duration_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`serial_number`](struct.GetSessionTokenRequest.html#structfield.serial_number) member.
+    pub fn serial_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.serial_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token_code`](struct.GetSessionTokenRequest.html#structfield.token_code) member.
+    pub fn token_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.token_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSessionTokenRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSessionTokenRequest, ::rusoto_core::BuildError> {
+        Ok(GetSessionTokenRequest {
+            duration_seconds: self.duration_seconds,
+            serial_number: self.serial_number,
+            token_code: self.token_code,
+        })
+    }
+}
+
 /// Serialize `GetSessionTokenRequest` contents to a `SignedRequest`.
 struct GetSessionTokenRequestSerializer;
 impl GetSessionTokenRequestSerializer {
@@ -858,10 +1318,10 @@ pub struct GetSessionTokenResponse {
     pub credentials: Option<Credentials>,
 }
 
-struct GetSessionTokenResponseDeserializer;
+pub(crate) struct GetSessionTokenResponseDeserializer;
 impl GetSessionTokenResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetSessionTokenResponse, XmlParseError> {
@@ -881,10 +1341,13 @@ impl GetSessionTokenResponseDeserializer {
         )
     }
 }
-struct IssuerDeserializer;
+pub(crate) struct IssuerDeserializer;
 impl IssuerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -892,10 +1355,13 @@ impl IssuerDeserializer {
         Ok(obj)
     }
 }
-struct NameQualifierDeserializer;
+pub(crate) struct NameQualifierDeserializer;
 impl NameQualifierDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -903,10 +1369,13 @@ impl NameQualifierDeserializer {
         Ok(obj)
     }
 }
-struct NonNegativeIntegerTypeDeserializer;
+pub(crate) struct NonNegativeIntegerTypeDeserializer;
 impl NonNegativeIntegerTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -948,10 +1417,13 @@ impl PolicyDescriptorTypeSerializer {
     }
 }
 
-struct SubjectDeserializer;
+pub(crate) struct SubjectDeserializer;
 impl SubjectDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -959,10 +1431,13 @@ impl SubjectDeserializer {
         Ok(obj)
     }
 }
-struct SubjectTypeDeserializer;
+pub(crate) struct SubjectTypeDeserializer;
 impl SubjectTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -970,10 +1445,13 @@ impl SubjectTypeDeserializer {
         Ok(obj)
     }
 }
-struct TokenTypeDeserializer;
+pub(crate) struct TokenTypeDeserializer;
 impl TokenTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -981,10 +1459,13 @@ impl TokenTypeDeserializer {
         Ok(obj)
     }
 }
-struct UserIdTypeDeserializer;
+pub(crate) struct UserIdTypeDeserializer;
 impl UserIdTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -992,10 +1473,13 @@ impl UserIdTypeDeserializer {
         Ok(obj)
     }
 }
-struct WebIdentitySubjectTypeDeserializer;
+pub(crate) struct WebIdentitySubjectTypeDeserializer;
 impl WebIdentitySubjectTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1541,6 +2025,63 @@ pub trait Sts {
         input: GetSessionTokenRequest,
     ) -> RusotoFuture<GetSessionTokenResponse, GetSessionTokenError>;
 }
+impl<T: ?Sized + Sts> Sts for ::std::sync::Arc<T> {
+    fn assume_role(
+        &self,
+        input: AssumeRoleRequest,
+    ) -> RusotoFuture<AssumeRoleResponse, AssumeRoleError> {
+        (**self).assume_role(input)
+    }
+    fn assume_role_with_saml(
+        &self,
+        input: AssumeRoleWithSAMLRequest,
+    ) -> RusotoFuture<AssumeRoleWithSAMLResponse, AssumeRoleWithSAMLError> {
+        (**self).assume_role_with_saml(input)
+    }
+    fn assume_role_with_web_identity(
+        &self,
+        input: AssumeRoleWithWebIdentityRequest,
+    ) -> RusotoFuture<AssumeRoleWithWebIdentityResponse, AssumeRoleWithWebIdentityError> {
+        (**self).assume_role_with_web_identity(input)
+    }
+    fn decode_authorization_message(
+        &self,
+        input: DecodeAuthorizationMessageRequest,
+    ) -> RusotoFuture<DecodeAuthorizationMessageResponse, DecodeAuthorizationMessageError> {
+        (**self).decode_authorization_message(input)
+    }
+    fn get_access_key_info(
+        &self,
+        input: GetAccessKeyInfoRequest,
+    ) -> RusotoFuture<GetAccessKeyInfoResponse, GetAccessKeyInfoError> {
+        (**self).get_access_key_info(input)
+    }
+    fn get_caller_identity(
+        &self,
+        input: GetCallerIdentityRequest,
+    ) -> RusotoFuture<GetCallerIdentityResponse, GetCallerIdentityError> {
+        (**self).get_caller_identity(input)
+    }
+    fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        (**self).get_federation_token(input)
+    }
+    fn get_session_token(
+        &self,
+        input: GetSessionTokenRequest,
+    ) -> RusotoFuture<GetSessionTokenResponse, GetSessionTokenError> {
+        (**self).get_session_token(input)
+    }
+}
+/// The error codes AWS STS retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &["IDPCommunicationError"],
+    };
+
 /// A client for the AWS STS API.
 #[derive(Clone)]
 pub struct StsClient {
@@ -1556,6 +2097,24 @@ impl StsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_STS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> StsClient {
+        Self::new(region::Region::from_env_for_service("STS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `sts`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> StsClient {
+        Self::new_with_client(config.client().clone(), config.region_for("sts", "STS"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1574,7 +2133,49 @@ impl StsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> StsClient {
-        StsClient { client, region }
+        StsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "sts")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "sts", "sts", "STS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Sts` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Sts + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for StsClient {
+    fn service_name(&self) -> &'static str {
+        "AWS STS"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "sts"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> StsClient {
+        Self::new_with_client(client, region)
     }
 }
 
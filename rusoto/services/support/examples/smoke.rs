@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Support clients.
+//!
+//! Makes a single read-only `DescribeCases` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_support::{AWSSupport, AWSSupportClient, DescribeCasesRequest};
+
+fn main() {
+    let client = AWSSupportClient::from_env();
+    match client
+        .describe_cases(DescribeCasesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeCases succeeded"),
+        Err(error) => {
+            eprintln!("DescribeCases failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
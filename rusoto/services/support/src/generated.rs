@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -35,6 +36,46 @@ pub struct AddAttachmentsToSetRequest {
     pub attachments: Vec<Attachment>,
 }
 
+impl AddAttachmentsToSetRequest {
+    /// Returns a builder for `AddAttachmentsToSetRequest`, checking its required members when built.
+    pub fn builder() -> AddAttachmentsToSetRequestBuilder {
+        AddAttachmentsToSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddAttachmentsToSetRequest`](struct.AddAttachmentsToSetRequest.html).
+#[derive(Default, Debug)]
+pub struct AddAttachmentsToSetRequestBuilder {
+    attachment_set_id: Option<String>,
+    attachments: Option<Vec<Attachment>>,
+}
+
+impl AddAttachmentsToSetRequestBuilder {
+    /// Sets the [`attachment_set_id`](struct.AddAttachmentsToSetRequest.html#structfield.attachment_set_id) member.
+    pub fn attachment_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.attachment_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attachments`](struct.AddAttachmentsToSetRequest.html#structfield.attachments) member.
+    pub fn attachments<T: Into<Vec<Attachment>>>(mut self, value: T) -> Self {
+        self.attachments = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddAttachmentsToSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddAttachmentsToSetRequest, ::rusoto_core::BuildError> {
+        Ok(AddAttachmentsToSetRequest {
+            attachment_set_id: self.attachment_set_id,
+            attachments: self
+                .attachments
+                .ok_or(::rusoto_core::BuildError::missing("attachments"))?,
+        })
+    }
+}
+
 /// <p>The ID and expiry time of the attachment set returned by the <a>AddAttachmentsToSet</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -69,6 +110,62 @@ pub struct AddCommunicationToCaseRequest {
     pub communication_body: String,
 }
 
+impl AddCommunicationToCaseRequest {
+    /// Returns a builder for `AddCommunicationToCaseRequest`, checking its required members when built.
+    pub fn builder() -> AddCommunicationToCaseRequestBuilder {
+        AddCommunicationToCaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddCommunicationToCaseRequest`](struct.AddCommunicationToCaseRequest.html).
+#[derive(Default, Debug)]
+pub struct AddCommunicationToCaseRequestBuilder {
+    attachment_set_id: Option<String>,
+    case_id: Option<String>,
+    cc_email_addresses: Option<Vec<String>>,
+    communication_body: Option<String>,
+}
+
+impl AddCommunicationToCaseRequestBuilder {
+    /// Sets the [`attachment_set_id`](struct.AddCommunicationToCaseRequest.html#structfield.attachment_set_id) member.
+    pub fn attachment_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.attachment_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`case_id`](struct.AddCommunicationToCaseRequest.html#structfield.case_id) member.
+    pub fn case_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.case_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cc_email_addresses`](struct.AddCommunicationToCaseRequest.html#structfield.cc_email_addresses) member.
+    pub fn cc_email_addresses<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cc_email_addresses = Some(value.into());
+        self
+    }
+
+    /// Sets the [`communication_body`](struct.AddCommunicationToCaseRequest.html#structfield.communication_body) member.
+    pub fn communication_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.communication_body = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddCommunicationToCaseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddCommunicationToCaseRequest, ::rusoto_core::BuildError> {
+        Ok(AddCommunicationToCaseRequest {
+            attachment_set_id: self.attachment_set_id,
+            case_id: self.case_id,
+            cc_email_addresses: self.cc_email_addresses,
+            communication_body: self
+                .communication_body
+                .ok_or(::rusoto_core::BuildError::missing("communication_body"))?,
+        })
+    }
+}
+
 /// <p>The result of the <a>AddCommunicationToCase</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -244,6 +341,102 @@ pub struct CreateCaseRequest {
     pub subject: String,
 }
 
+impl CreateCaseRequest {
+    /// Returns a builder for `CreateCaseRequest`, checking its required members when built.
+    pub fn builder() -> CreateCaseRequestBuilder {
+        CreateCaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCaseRequest`](struct.CreateCaseRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCaseRequestBuilder {
+    attachment_set_id: Option<String>,
+    category_code: Option<String>,
+    cc_email_addresses: Option<Vec<String>>,
+    communication_body: Option<String>,
+    issue_type: Option<String>,
+    language: Option<String>,
+    service_code: Option<String>,
+    severity_code: Option<String>,
+    subject: Option<String>,
+}
+
+impl CreateCaseRequestBuilder {
+    /// Sets the [`attachment_set_id`](struct.CreateCaseRequest.html#structfield.attachment_set_id) member.
+    pub fn attachment_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.attachment_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`category_code`](struct.CreateCaseRequest.html#structfield.category_code) member.
+    pub fn category_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.category_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cc_email_addresses`](struct.CreateCaseRequest.html#structfield.cc_email_addresses) member.
+    pub fn cc_email_addresses<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cc_email_addresses = Some(value.into());
+        self
+    }
+
+    /// Sets the [`communication_body`](struct.CreateCaseRequest.html#structfield.communication_body) member.
+    pub fn communication_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.communication_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`issue_type`](struct.CreateCaseRequest.html#structfield.issue_type) member.
+    pub fn issue_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.issue_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language`](struct.CreateCaseRequest.html#structfield.language) member.
+    pub fn language<T: Into<String>>(mut self, value: T) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_code`](struct.CreateCaseRequest.html#structfield.service_code) member.
+    pub fn service_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`severity_code`](struct.CreateCaseRequest.html#structfield.severity_code) member.
+    pub fn severity_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.severity_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subject`](struct.CreateCaseRequest.html#structfield.subject) member.
+    pub fn subject<T: Into<String>>(mut self, value: T) -> Self {
+        self.subject = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCaseRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateCaseRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCaseRequest {
+            attachment_set_id: self.attachment_set_id,
+            category_code: self.category_code,
+            cc_email_addresses: self.cc_email_addresses,
+            communication_body: self
+                .communication_body
+                .ok_or(::rusoto_core::BuildError::missing("communication_body"))?,
+            issue_type: self.issue_type,
+            language: self.language,
+            service_code: self.service_code,
+            severity_code: self.severity_code,
+            subject: self
+                .subject
+                .ok_or(::rusoto_core::BuildError::missing("subject"))?,
+        })
+    }
+}
+
 /// <p>The AWS Support case ID returned by a successful completion of the <a>CreateCase</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -261,6 +454,38 @@ pub struct DescribeAttachmentRequest {
     pub attachment_id: String,
 }
 
+impl DescribeAttachmentRequest {
+    /// Returns a builder for `DescribeAttachmentRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAttachmentRequestBuilder {
+        DescribeAttachmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAttachmentRequest`](struct.DescribeAttachmentRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAttachmentRequestBuilder {
+    attachment_id: Option<String>,
+}
+
+impl DescribeAttachmentRequestBuilder {
+    /// Sets the [`attachment_id`](struct.DescribeAttachmentRequest.html#structfield.attachment_id) member.
+    pub fn attachment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.attachment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAttachmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAttachmentRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAttachmentRequest {
+            attachment_id: self
+                .attachment_id
+                .ok_or(::rusoto_core::BuildError::missing("attachment_id"))?,
+        })
+    }
+}
+
 /// <p>The content and file name of the attachment returned by the <a>DescribeAttachment</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -312,6 +537,98 @@ pub struct DescribeCasesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeCasesRequest {
+    /// Returns a builder for `DescribeCasesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCasesRequestBuilder {
+        DescribeCasesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCasesRequest`](struct.DescribeCasesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCasesRequestBuilder {
+    after_time: Option<String>,
+    before_time: Option<String>,
+    case_id_list: Option<Vec<String>>,
+    display_id: Option<String>,
+    include_communications: Option<bool>,
+    include_resolved_cases: Option<bool>,
+    language: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeCasesRequestBuilder {
+    /// Sets the [`after_time`](struct.DescribeCasesRequest.html#structfield.after_time) member.
+    pub fn after_time<T: Into<String>>(mut self, value: T) -> Self {
+        self.after_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`before_time`](struct.DescribeCasesRequest.html#structfield.before_time) member.
+    pub fn before_time<T: Into<String>>(mut self, value: T) -> Self {
+        self.before_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`case_id_list`](struct.DescribeCasesRequest.html#structfield.case_id_list) member.
+    pub fn case_id_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.case_id_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_id`](struct.DescribeCasesRequest.html#structfield.display_id) member.
+    pub fn display_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_communications`](struct.DescribeCasesRequest.html#structfield.include_communications) member.
+    pub fn include_communications<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_communications = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_resolved_cases`](struct.DescribeCasesRequest.html#structfield.include_resolved_cases) member.
+    pub fn include_resolved_cases<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_resolved_cases = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language`](struct.DescribeCasesRequest.html#structfield.language) member.
+    pub fn language<T: Into<String>>(mut self, value: T) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeCasesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeCasesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCasesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeCasesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeCasesRequest {
+            after_time: self.after_time,
+            before_time: self.before_time,
+            case_id_list: self.case_id_list,
+            display_id: self.display_id,
+            include_communications: self.include_communications,
+            include_resolved_cases: self.include_resolved_cases,
+            language: self.language,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Returns an array of <a>CaseDetails</a> objects and a <code>nextToken</code> that defines a point for pagination in the result set.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -350,6 +667,70 @@ pub struct DescribeCommunicationsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeCommunicationsRequest {
+    /// Returns a builder for `DescribeCommunicationsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCommunicationsRequestBuilder {
+        DescribeCommunicationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCommunicationsRequest`](struct.DescribeCommunicationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCommunicationsRequestBuilder {
+    after_time: Option<String>,
+    before_time: Option<String>,
+    case_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeCommunicationsRequestBuilder {
+    /// Sets the [`after_time`](struct.DescribeCommunicationsRequest.html#structfield.after_time) member.
+    pub fn after_time<T: Into<String>>(mut self, value: T) -> Self {
+        self.after_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`before_time`](struct.DescribeCommunicationsRequest.html#structfield.before_time) member.
+    pub fn before_time<T: Into<String>>(mut self, value: T) -> Self {
+        self.before_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`case_id`](struct.DescribeCommunicationsRequest.html#structfield.case_id) member.
+    pub fn case_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.case_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeCommunicationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeCommunicationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCommunicationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCommunicationsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeCommunicationsRequest {
+            after_time: self.after_time,
+            before_time: self.before_time,
+            case_id: self
+                .case_id
+                .ok_or(::rusoto_core::BuildError::missing("case_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>The communications returned by the <a>DescribeCommunications</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -377,6 +758,44 @@ pub struct DescribeServicesRequest {
     pub service_code_list: Option<Vec<String>>,
 }
 
+impl DescribeServicesRequest {
+    /// Returns a builder for `DescribeServicesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeServicesRequestBuilder {
+        DescribeServicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeServicesRequest`](struct.DescribeServicesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeServicesRequestBuilder {
+    language: Option<String>,
+    service_code_list: Option<Vec<String>>,
+}
+
+impl DescribeServicesRequestBuilder {
+    /// Sets the [`language`](struct.DescribeServicesRequest.html#structfield.language) member.
+    pub fn language<T: Into<String>>(mut self, value: T) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_code_list`](struct.DescribeServicesRequest.html#structfield.service_code_list) member.
+    pub fn service_code_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.service_code_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeServicesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeServicesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeServicesRequest {
+            language: self.language,
+            service_code_list: self.service_code_list,
+        })
+    }
+}
+
 /// <p>The list of AWS services returned by the <a>DescribeServices</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -396,6 +815,36 @@ pub struct DescribeSeverityLevelsRequest {
     pub language: Option<String>,
 }
 
+impl DescribeSeverityLevelsRequest {
+    /// Returns a builder for `DescribeSeverityLevelsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeSeverityLevelsRequestBuilder {
+        DescribeSeverityLevelsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSeverityLevelsRequest`](struct.DescribeSeverityLevelsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeSeverityLevelsRequestBuilder {
+    language: Option<String>,
+}
+
+impl DescribeSeverityLevelsRequestBuilder {
+    /// Sets the [`language`](struct.DescribeSeverityLevelsRequest.html#structfield.language) member.
+    pub fn language<T: Into<String>>(mut self, value: T) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSeverityLevelsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSeverityLevelsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeSeverityLevelsRequest {
+            language: self.language,
+        })
+    }
+}
+
 /// <p>The list of severity levels returned by the <a>DescribeSeverityLevels</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -414,6 +863,41 @@ pub struct DescribeTrustedAdvisorCheckRefreshStatusesRequest {
     pub check_ids: Vec<String>,
 }
 
+impl DescribeTrustedAdvisorCheckRefreshStatusesRequest {
+    /// Returns a builder for `DescribeTrustedAdvisorCheckRefreshStatusesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTrustedAdvisorCheckRefreshStatusesRequestBuilder {
+        DescribeTrustedAdvisorCheckRefreshStatusesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTrustedAdvisorCheckRefreshStatusesRequest`](struct.DescribeTrustedAdvisorCheckRefreshStatusesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTrustedAdvisorCheckRefreshStatusesRequestBuilder {
+    check_ids: Option<Vec<String>>,
+}
+
+impl DescribeTrustedAdvisorCheckRefreshStatusesRequestBuilder {
+    /// Sets the [`check_ids`](struct.DescribeTrustedAdvisorCheckRefreshStatusesRequest.html#structfield.check_ids) member.
+    pub fn check_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.check_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTrustedAdvisorCheckRefreshStatusesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeTrustedAdvisorCheckRefreshStatusesRequest {
+            check_ids: self
+                .check_ids
+                .ok_or(::rusoto_core::BuildError::missing("check_ids"))?,
+        })
+    }
+}
+
 /// <p>The statuses of the Trusted Advisor checks returned by the <a>DescribeTrustedAdvisorCheckRefreshStatuses</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -435,6 +919,47 @@ pub struct DescribeTrustedAdvisorCheckResultRequest {
     pub language: Option<String>,
 }
 
+impl DescribeTrustedAdvisorCheckResultRequest {
+    /// Returns a builder for `DescribeTrustedAdvisorCheckResultRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTrustedAdvisorCheckResultRequestBuilder {
+        DescribeTrustedAdvisorCheckResultRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTrustedAdvisorCheckResultRequest`](struct.DescribeTrustedAdvisorCheckResultRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTrustedAdvisorCheckResultRequestBuilder {
+    check_id: Option<String>,
+    language: Option<String>,
+}
+
+impl DescribeTrustedAdvisorCheckResultRequestBuilder {
+    /// Sets the [`check_id`](struct.DescribeTrustedAdvisorCheckResultRequest.html#structfield.check_id) member.
+    pub fn check_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.check_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language`](struct.DescribeTrustedAdvisorCheckResultRequest.html#structfield.language) member.
+    pub fn language<T: Into<String>>(mut self, value: T) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTrustedAdvisorCheckResultRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTrustedAdvisorCheckResultRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeTrustedAdvisorCheckResultRequest {
+            check_id: self
+                .check_id
+                .ok_or(::rusoto_core::BuildError::missing("check_id"))?,
+            language: self.language,
+        })
+    }
+}
+
 /// <p>The result of the Trusted Advisor check returned by the <a>DescribeTrustedAdvisorCheckResult</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -453,6 +978,39 @@ pub struct DescribeTrustedAdvisorCheckSummariesRequest {
     pub check_ids: Vec<String>,
 }
 
+impl DescribeTrustedAdvisorCheckSummariesRequest {
+    /// Returns a builder for `DescribeTrustedAdvisorCheckSummariesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTrustedAdvisorCheckSummariesRequestBuilder {
+        DescribeTrustedAdvisorCheckSummariesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTrustedAdvisorCheckSummariesRequest`](struct.DescribeTrustedAdvisorCheckSummariesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTrustedAdvisorCheckSummariesRequestBuilder {
+    check_ids: Option<Vec<String>>,
+}
+
+impl DescribeTrustedAdvisorCheckSummariesRequestBuilder {
+    /// Sets the [`check_ids`](struct.DescribeTrustedAdvisorCheckSummariesRequest.html#structfield.check_ids) member.
+    pub fn check_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.check_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTrustedAdvisorCheckSummariesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTrustedAdvisorCheckSummariesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeTrustedAdvisorCheckSummariesRequest {
+            check_ids: self
+                .check_ids
+                .ok_or(::rusoto_core::BuildError::missing("check_ids"))?,
+        })
+    }
+}
+
 /// <p>The summaries of the Trusted Advisor checks returned by the <a>DescribeTrustedAdvisorCheckSummaries</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -470,6 +1028,38 @@ pub struct DescribeTrustedAdvisorChecksRequest {
     pub language: String,
 }
 
+impl DescribeTrustedAdvisorChecksRequest {
+    /// Returns a builder for `DescribeTrustedAdvisorChecksRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTrustedAdvisorChecksRequestBuilder {
+        DescribeTrustedAdvisorChecksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTrustedAdvisorChecksRequest`](struct.DescribeTrustedAdvisorChecksRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTrustedAdvisorChecksRequestBuilder {
+    language: Option<String>,
+}
+
+impl DescribeTrustedAdvisorChecksRequestBuilder {
+    /// Sets the [`language`](struct.DescribeTrustedAdvisorChecksRequest.html#structfield.language) member.
+    pub fn language<T: Into<String>>(mut self, value: T) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTrustedAdvisorChecksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTrustedAdvisorChecksRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTrustedAdvisorChecksRequest {
+            language: self
+                .language
+                .ok_or(::rusoto_core::BuildError::missing("language"))?,
+        })
+    }
+}
+
 /// <p>Information about the Trusted Advisor checks returned by the <a>DescribeTrustedAdvisorChecks</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -501,6 +1091,38 @@ pub struct RefreshTrustedAdvisorCheckRequest {
     pub check_id: String,
 }
 
+impl RefreshTrustedAdvisorCheckRequest {
+    /// Returns a builder for `RefreshTrustedAdvisorCheckRequest`, checking its required members when built.
+    pub fn builder() -> RefreshTrustedAdvisorCheckRequestBuilder {
+        RefreshTrustedAdvisorCheckRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RefreshTrustedAdvisorCheckRequest`](struct.RefreshTrustedAdvisorCheckRequest.html).
+#[derive(Default, Debug)]
+pub struct RefreshTrustedAdvisorCheckRequestBuilder {
+    check_id: Option<String>,
+}
+
+impl RefreshTrustedAdvisorCheckRequestBuilder {
+    /// Sets the [`check_id`](struct.RefreshTrustedAdvisorCheckRequest.html#structfield.check_id) member.
+    pub fn check_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.check_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RefreshTrustedAdvisorCheckRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RefreshTrustedAdvisorCheckRequest, ::rusoto_core::BuildError> {
+        Ok(RefreshTrustedAdvisorCheckRequest {
+            check_id: self
+                .check_id
+                .ok_or(::rusoto_core::BuildError::missing("check_id"))?,
+        })
+    }
+}
+
 /// <p>The current refresh status of a Trusted Advisor check.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -519,6 +1141,34 @@ pub struct ResolveCaseRequest {
     pub case_id: Option<String>,
 }
 
+impl ResolveCaseRequest {
+    /// Returns a builder for `ResolveCaseRequest`, checking its required members when built.
+    pub fn builder() -> ResolveCaseRequestBuilder {
+        ResolveCaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResolveCaseRequest`](struct.ResolveCaseRequest.html).
+#[derive(Default, Debug)]
+pub struct ResolveCaseRequestBuilder {
+    case_id: Option<String>,
+}
+
+impl ResolveCaseRequestBuilder {
+    /// Sets the [`case_id`](struct.ResolveCaseRequest.html#structfield.case_id) member.
+    pub fn case_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.case_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResolveCaseRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ResolveCaseRequest, ::rusoto_core::BuildError> {
+        Ok(ResolveCaseRequest {
+            case_id: self.case_id,
+        })
+    }
+}
+
 /// <p>The status of the case returned by the <a>ResolveCase</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1416,6 +2066,108 @@ pub trait AWSSupport {
         input: ResolveCaseRequest,
     ) -> RusotoFuture<ResolveCaseResponse, ResolveCaseError>;
 }
+impl<T: ?Sized + AWSSupport> AWSSupport for ::std::sync::Arc<T> {
+    fn add_attachments_to_set(
+        &self,
+        input: AddAttachmentsToSetRequest,
+    ) -> RusotoFuture<AddAttachmentsToSetResponse, AddAttachmentsToSetError> {
+        (**self).add_attachments_to_set(input)
+    }
+    fn add_communication_to_case(
+        &self,
+        input: AddCommunicationToCaseRequest,
+    ) -> RusotoFuture<AddCommunicationToCaseResponse, AddCommunicationToCaseError> {
+        (**self).add_communication_to_case(input)
+    }
+    fn create_case(
+        &self,
+        input: CreateCaseRequest,
+    ) -> RusotoFuture<CreateCaseResponse, CreateCaseError> {
+        (**self).create_case(input)
+    }
+    fn describe_attachment(
+        &self,
+        input: DescribeAttachmentRequest,
+    ) -> RusotoFuture<DescribeAttachmentResponse, DescribeAttachmentError> {
+        (**self).describe_attachment(input)
+    }
+    fn describe_cases(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> RusotoFuture<DescribeCasesResponse, DescribeCasesError> {
+        (**self).describe_cases(input)
+    }
+    fn describe_communications(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> RusotoFuture<DescribeCommunicationsResponse, DescribeCommunicationsError> {
+        (**self).describe_communications(input)
+    }
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        (**self).describe_services(input)
+    }
+    fn describe_severity_levels(
+        &self,
+        input: DescribeSeverityLevelsRequest,
+    ) -> RusotoFuture<DescribeSeverityLevelsResponse, DescribeSeverityLevelsError> {
+        (**self).describe_severity_levels(input)
+    }
+    fn describe_trusted_advisor_check_refresh_statuses(
+        &self,
+        input: DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+        DescribeTrustedAdvisorCheckRefreshStatusesError,
+    > {
+        (**self).describe_trusted_advisor_check_refresh_statuses(input)
+    }
+    fn describe_trusted_advisor_check_result(
+        &self,
+        input: DescribeTrustedAdvisorCheckResultRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckResultResponse,
+        DescribeTrustedAdvisorCheckResultError,
+    > {
+        (**self).describe_trusted_advisor_check_result(input)
+    }
+    fn describe_trusted_advisor_check_summaries(
+        &self,
+        input: DescribeTrustedAdvisorCheckSummariesRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckSummariesResponse,
+        DescribeTrustedAdvisorCheckSummariesError,
+    > {
+        (**self).describe_trusted_advisor_check_summaries(input)
+    }
+    fn describe_trusted_advisor_checks(
+        &self,
+        input: DescribeTrustedAdvisorChecksRequest,
+    ) -> RusotoFuture<DescribeTrustedAdvisorChecksResponse, DescribeTrustedAdvisorChecksError> {
+        (**self).describe_trusted_advisor_checks(input)
+    }
+    fn refresh_trusted_advisor_check(
+        &self,
+        input: RefreshTrustedAdvisorCheckRequest,
+    ) -> RusotoFuture<RefreshTrustedAdvisorCheckResponse, RefreshTrustedAdvisorCheckError> {
+        (**self).refresh_trusted_advisor_check(input)
+    }
+    fn resolve_case(
+        &self,
+        input: ResolveCaseRequest,
+    ) -> RusotoFuture<ResolveCaseResponse, ResolveCaseError> {
+        (**self).resolve_case(input)
+    }
+}
+/// The error codes AWS Support retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Support API.
 #[derive(Clone)]
 pub struct AWSSupportClient {
@@ -1431,6 +2183,27 @@ impl AWSSupportClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SUPPORT`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AWSSupportClient {
+        Self::new(region::Region::from_env_for_service("SUPPORT"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `support`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AWSSupportClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("support", "SUPPORT"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1449,7 +2222,49 @@ impl AWSSupportClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AWSSupportClient {
-        AWSSupportClient { client, region }
+        AWSSupportClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "support")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "support", "support", "SUPPORT")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `AWSSupport` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn AWSSupport + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AWSSupportClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Support"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "support"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AWSSupportClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Unit of work sent to an activity worker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -185,7 +186,7 @@ pub struct ActivityTaskStatus {
 }
 
 /// <p>Provides the details of the <code>ActivityTaskTimedOut</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ActivityTaskTimedOutEventAttributes {
     /// <p>Contains the content of the <code>details</code> parameter for the last call made by the activity to <code>RecordActivityTaskHeartbeat</code>.</p>
@@ -200,7 +201,87 @@ pub struct ActivityTaskTimedOutEventAttributes {
     pub started_event_id: i64,
     /// <p>The type of the timeout that caused this event.</p>
     #[serde(rename = "timeoutType")]
-    pub timeout_type: String,
+    pub timeout_type: ActivityTaskTimeoutType,
+}
+
+/// <p>The type of the timeout that caused this event.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActivityTaskTimeoutType {
+    /// <p><code>START_TO_CLOSE</code></p>
+    StartToClose,
+    /// <p><code>SCHEDULE_TO_START</code></p>
+    ScheduleToStart,
+    /// <p><code>SCHEDULE_TO_CLOSE</code></p>
+    ScheduleToClose,
+    /// <p><code>HEARTBEAT</code></p>
+    Heartbeat,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActivityTaskTimeoutType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActivityTaskTimeoutType::StartToClose => "START_TO_CLOSE",
+            ActivityTaskTimeoutType::ScheduleToStart => "SCHEDULE_TO_START",
+            ActivityTaskTimeoutType::ScheduleToClose => "SCHEDULE_TO_CLOSE",
+            ActivityTaskTimeoutType::Heartbeat => "HEARTBEAT",
+            ActivityTaskTimeoutType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActivityTaskTimeoutType {
+    fn from(value: &'a str) -> ActivityTaskTimeoutType {
+        match value {
+            "START_TO_CLOSE" => ActivityTaskTimeoutType::StartToClose,
+            "SCHEDULE_TO_START" => ActivityTaskTimeoutType::ScheduleToStart,
+            "SCHEDULE_TO_CLOSE" => ActivityTaskTimeoutType::ScheduleToClose,
+            "HEARTBEAT" => ActivityTaskTimeoutType::Heartbeat,
+            _ => ActivityTaskTimeoutType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActivityTaskTimeoutType {
+    fn from(value: String) -> ActivityTaskTimeoutType {
+        ActivityTaskTimeoutType::from(value.as_str())
+    }
+}
+
+impl From<ActivityTaskTimeoutType> for String {
+    fn from(value: ActivityTaskTimeoutType) -> String {
+        match value {
+            ActivityTaskTimeoutType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActivityTaskTimeoutType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ActivityTaskTimeoutType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActivityTaskTimeoutType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ActivityTaskTimeoutType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ActivityTaskTimeoutType::from(value))
+    }
 }
 
 /// <p>Represents an activity type.</p>
@@ -245,7 +326,7 @@ pub struct ActivityTypeConfiguration {
 }
 
 /// <p>Detailed information about an activity type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ActivityTypeDetail {
     /// <p>The configuration settings registered with the activity type.</p>
@@ -257,7 +338,7 @@ pub struct ActivityTypeDetail {
 }
 
 /// <p>Detailed information about an activity type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ActivityTypeInfo {
     /// <p>The <a>ActivityType</a> type structure representing the activity type.</p>
@@ -265,18 +346,20 @@ pub struct ActivityTypeInfo {
     pub activity_type: ActivityType,
     /// <p>The date and time this activity type was created through <a>RegisterActivityType</a>.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date: EpochTimestamp,
     /// <p>If DEPRECATED, the date and time <a>DeprecateActivityType</a> was called.</p>
     #[serde(rename = "deprecationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deprecation_date: Option<f64>,
+    pub deprecation_date: Option<EpochTimestamp>,
     /// <p>The description of the activity type provided in <a>RegisterActivityType</a>.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The current status of the activity type.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: RegistrationStatus,
 }
 
 /// <p>Contains a paginated list of activity type information structures.</p>
@@ -300,13 +383,85 @@ pub struct CancelTimerDecisionAttributes {
     pub timer_id: String,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CancelTimerFailedCause {
+    /// <p><code>TIMER_ID_UNKNOWN</code></p>
+    TimerIdUnknown,
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CancelTimerFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CancelTimerFailedCause::TimerIdUnknown => "TIMER_ID_UNKNOWN",
+            CancelTimerFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            CancelTimerFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CancelTimerFailedCause {
+    fn from(value: &'a str) -> CancelTimerFailedCause {
+        match value {
+            "TIMER_ID_UNKNOWN" => CancelTimerFailedCause::TimerIdUnknown,
+            "OPERATION_NOT_PERMITTED" => CancelTimerFailedCause::OperationNotPermitted,
+            _ => CancelTimerFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CancelTimerFailedCause {
+    fn from(value: String) -> CancelTimerFailedCause {
+        CancelTimerFailedCause::from(value.as_str())
+    }
+}
+
+impl From<CancelTimerFailedCause> for String {
+    fn from(value: CancelTimerFailedCause) -> String {
+        match value {
+            CancelTimerFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CancelTimerFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CancelTimerFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CancelTimerFailedCause {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CancelTimerFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CancelTimerFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>CancelTimerFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelTimerFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: CancelTimerFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>CancelTimer</code> decision to cancel this timer. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -324,18 +479,168 @@ pub struct CancelWorkflowExecutionDecisionAttributes {
     pub details: Option<String>,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CancelWorkflowExecutionFailedCause {
+    /// <p><code>UNHANDLED_DECISION</code></p>
+    UnhandledDecision,
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CancelWorkflowExecutionFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CancelWorkflowExecutionFailedCause::UnhandledDecision => "UNHANDLED_DECISION",
+            CancelWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            CancelWorkflowExecutionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CancelWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> CancelWorkflowExecutionFailedCause {
+        match value {
+            "UNHANDLED_DECISION" => CancelWorkflowExecutionFailedCause::UnhandledDecision,
+            "OPERATION_NOT_PERMITTED" => CancelWorkflowExecutionFailedCause::OperationNotPermitted,
+            _ => CancelWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CancelWorkflowExecutionFailedCause {
+    fn from(value: String) -> CancelWorkflowExecutionFailedCause {
+        CancelWorkflowExecutionFailedCause::from(value.as_str())
+    }
+}
+
+impl From<CancelWorkflowExecutionFailedCause> for String {
+    fn from(value: CancelWorkflowExecutionFailedCause) -> String {
+        match value {
+            CancelWorkflowExecutionFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CancelWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CancelWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CancelWorkflowExecutionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<CancelWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CancelWorkflowExecutionFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>CancelWorkflowExecutionFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: CancelWorkflowExecutionFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>CancelWorkflowExecution</code> decision for this cancellation request. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
 }
 
+/// <p><p>If set, specifies the policy to use for the child workflow executions of the new execution if it is terminated by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This policy overrides the default child policy specified when registering the workflow type using <a>RegisterWorkflowType</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChildPolicy {
+    /// <p><code>TERMINATE</code></p>
+    Terminate,
+    /// <p><code>REQUEST_CANCEL</code></p>
+    RequestCancel,
+    /// <p><code>ABANDON</code></p>
+    Abandon,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChildPolicy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChildPolicy::Terminate => "TERMINATE",
+            ChildPolicy::RequestCancel => "REQUEST_CANCEL",
+            ChildPolicy::Abandon => "ABANDON",
+            ChildPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChildPolicy {
+    fn from(value: &'a str) -> ChildPolicy {
+        match value {
+            "TERMINATE" => ChildPolicy::Terminate,
+            "REQUEST_CANCEL" => ChildPolicy::RequestCancel,
+            "ABANDON" => ChildPolicy::Abandon,
+            _ => ChildPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChildPolicy {
+    fn from(value: String) -> ChildPolicy {
+        ChildPolicy::from(value.as_str())
+    }
+}
+
+impl From<ChildPolicy> for String {
+    fn from(value: ChildPolicy) -> String {
+        match value {
+            ChildPolicy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChildPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ChildPolicy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChildPolicy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChildPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChildPolicy::from(value))
+    }
+}
+
 /// <p>Provide details of the <code>ChildWorkflowExecutionCanceled</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -440,7 +745,7 @@ pub struct ChildWorkflowExecutionTerminatedEventAttributes {
 }
 
 /// <p>Provides the details of the <code>ChildWorkflowExecutionTimedOut</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ChildWorkflowExecutionTimedOutEventAttributes {
     /// <p>The ID of the <code>StartChildWorkflowExecutionInitiated</code> event corresponding to the <code>StartChildWorkflowExecution</code> <a>Decision</a> to start this child workflow execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
@@ -451,7 +756,7 @@ pub struct ChildWorkflowExecutionTimedOutEventAttributes {
     pub started_event_id: i64,
     /// <p>The type of the timeout that caused the child workflow execution to time out.</p>
     #[serde(rename = "timeoutType")]
-    pub timeout_type: String,
+    pub timeout_type: WorkflowExecutionTimeoutType,
     /// <p>The child workflow execution that timed out.</p>
     #[serde(rename = "workflowExecution")]
     pub workflow_execution: WorkflowExecution,
@@ -460,12 +765,100 @@ pub struct ChildWorkflowExecutionTimedOutEventAttributes {
     pub workflow_type: WorkflowType,
 }
 
+/// <p> The close status that must match the close status of an execution for it to meet the criteria of this filter.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CloseStatus {
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>CANCELED</code></p>
+    Canceled,
+    /// <p><code>TERMINATED</code></p>
+    Terminated,
+    /// <p><code>CONTINUED_AS_NEW</code></p>
+    ContinuedAsNew,
+    /// <p><code>TIMED_OUT</code></p>
+    TimedOut,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CloseStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloseStatus::Completed => "COMPLETED",
+            CloseStatus::Failed => "FAILED",
+            CloseStatus::Canceled => "CANCELED",
+            CloseStatus::Terminated => "TERMINATED",
+            CloseStatus::ContinuedAsNew => "CONTINUED_AS_NEW",
+            CloseStatus::TimedOut => "TIMED_OUT",
+            CloseStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CloseStatus {
+    fn from(value: &'a str) -> CloseStatus {
+        match value {
+            "COMPLETED" => CloseStatus::Completed,
+            "FAILED" => CloseStatus::Failed,
+            "CANCELED" => CloseStatus::Canceled,
+            "TERMINATED" => CloseStatus::Terminated,
+            "CONTINUED_AS_NEW" => CloseStatus::ContinuedAsNew,
+            "TIMED_OUT" => CloseStatus::TimedOut,
+            _ => CloseStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloseStatus {
+    fn from(value: String) -> CloseStatus {
+        CloseStatus::from(value.as_str())
+    }
+}
+
+impl From<CloseStatus> for String {
+    fn from(value: CloseStatus) -> String {
+        match value {
+            CloseStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CloseStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CloseStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloseStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CloseStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CloseStatus::from(value))
+    }
+}
+
 /// <p>Used to filter the closed workflow executions in visibility APIs by their close status.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CloseStatusFilter {
     /// <p> The close status that must match the close status of an execution for it to meet the criteria of this filter.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: CloseStatus,
 }
 
 /// <p>Provides the details of the <code>CompleteWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
@@ -477,13 +870,91 @@ pub struct CompleteWorkflowExecutionDecisionAttributes {
     pub result: Option<String>,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CompleteWorkflowExecutionFailedCause {
+    /// <p><code>UNHANDLED_DECISION</code></p>
+    UnhandledDecision,
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CompleteWorkflowExecutionFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CompleteWorkflowExecutionFailedCause::UnhandledDecision => "UNHANDLED_DECISION",
+            CompleteWorkflowExecutionFailedCause::OperationNotPermitted => {
+                "OPERATION_NOT_PERMITTED"
+            }
+            CompleteWorkflowExecutionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CompleteWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> CompleteWorkflowExecutionFailedCause {
+        match value {
+            "UNHANDLED_DECISION" => CompleteWorkflowExecutionFailedCause::UnhandledDecision,
+            "OPERATION_NOT_PERMITTED" => {
+                CompleteWorkflowExecutionFailedCause::OperationNotPermitted
+            }
+            _ => CompleteWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CompleteWorkflowExecutionFailedCause {
+    fn from(value: String) -> CompleteWorkflowExecutionFailedCause {
+        CompleteWorkflowExecutionFailedCause::from(value.as_str())
+    }
+}
+
+impl From<CompleteWorkflowExecutionFailedCause> for String {
+    fn from(value: CompleteWorkflowExecutionFailedCause) -> String {
+        match value {
+            CompleteWorkflowExecutionFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CompleteWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CompleteWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CompleteWorkflowExecutionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<CompleteWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CompleteWorkflowExecutionFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>CompleteWorkflowExecutionFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CompleteWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: CompleteWorkflowExecutionFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>CompleteWorkflowExecution</code> decision to complete this execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -495,7 +966,7 @@ pub struct ContinueAsNewWorkflowExecutionDecisionAttributes {
     /// <p><p>If set, specifies the policy to use for the child workflow executions of the new execution if it is terminated by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This policy overrides the default child policy specified when registering the workflow type using <a>RegisterWorkflowType</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub child_policy: Option<String>,
+    pub child_policy: Option<ChildPolicy>,
     /// <p><p>If set, specifies the total duration for this workflow execution. This overrides the <code>defaultExecutionStartToCloseTimeout</code> specified when registering the workflow type.</p> <p>The duration is specified in seconds, an integer greater than or equal to <code>0</code>. You can use <code>NONE</code> to specify unlimited duration.</p> <note> <p>An execution start-to-close timeout for this workflow execution must be specified either as a default for the workflow type or through this field. If neither this field is set nor a default execution start-to-close timeout was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "executionStartToCloseTimeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -530,13 +1001,115 @@ pub struct ContinueAsNewWorkflowExecutionDecisionAttributes {
     pub workflow_type_version: Option<String>,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContinueAsNewWorkflowExecutionFailedCause {
+    /// <p><code>UNHANDLED_DECISION</code></p>
+    UnhandledDecision,
+    /// <p><code>WORKFLOW_TYPE_DEPRECATED</code></p>
+    WorkflowTypeDeprecated,
+    /// <p><code>WORKFLOW_TYPE_DOES_NOT_EXIST</code></p>
+    WorkflowTypeDoesNotExist,
+    /// <p><code>DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED</code></p>
+    DefaultExecutionStartToCloseTimeoutUndefined,
+    /// <p><code>DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED</code></p>
+    DefaultTaskStartToCloseTimeoutUndefined,
+    /// <p><code>DEFAULT_TASK_LIST_UNDEFINED</code></p>
+    DefaultTaskListUndefined,
+    /// <p><code>DEFAULT_CHILD_POLICY_UNDEFINED</code></p>
+    DefaultChildPolicyUndefined,
+    /// <p><code>CONTINUE_AS_NEW_WORKFLOW_EXECUTION_RATE_EXCEEDED</code></p>
+    ContinueAsNewWorkflowExecutionRateExceeded,
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContinueAsNewWorkflowExecutionFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContinueAsNewWorkflowExecutionFailedCause::UnhandledDecision => "UNHANDLED_DECISION",
+            ContinueAsNewWorkflowExecutionFailedCause::WorkflowTypeDeprecated => "WORKFLOW_TYPE_DEPRECATED",
+            ContinueAsNewWorkflowExecutionFailedCause::WorkflowTypeDoesNotExist => "WORKFLOW_TYPE_DOES_NOT_EXIST",
+            ContinueAsNewWorkflowExecutionFailedCause::DefaultExecutionStartToCloseTimeoutUndefined => "DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED",
+            ContinueAsNewWorkflowExecutionFailedCause::DefaultTaskStartToCloseTimeoutUndefined => "DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED",
+            ContinueAsNewWorkflowExecutionFailedCause::DefaultTaskListUndefined => "DEFAULT_TASK_LIST_UNDEFINED",
+            ContinueAsNewWorkflowExecutionFailedCause::DefaultChildPolicyUndefined => "DEFAULT_CHILD_POLICY_UNDEFINED",
+            ContinueAsNewWorkflowExecutionFailedCause::ContinueAsNewWorkflowExecutionRateExceeded => "CONTINUE_AS_NEW_WORKFLOW_EXECUTION_RATE_EXCEEDED",
+            ContinueAsNewWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            ContinueAsNewWorkflowExecutionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContinueAsNewWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> ContinueAsNewWorkflowExecutionFailedCause {
+        match value {
+            "UNHANDLED_DECISION" => ContinueAsNewWorkflowExecutionFailedCause::UnhandledDecision,
+            "WORKFLOW_TYPE_DEPRECATED" => ContinueAsNewWorkflowExecutionFailedCause::WorkflowTypeDeprecated,
+            "WORKFLOW_TYPE_DOES_NOT_EXIST" => ContinueAsNewWorkflowExecutionFailedCause::WorkflowTypeDoesNotExist,
+            "DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultExecutionStartToCloseTimeoutUndefined,
+            "DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultTaskStartToCloseTimeoutUndefined,
+            "DEFAULT_TASK_LIST_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultTaskListUndefined,
+            "DEFAULT_CHILD_POLICY_UNDEFINED" => ContinueAsNewWorkflowExecutionFailedCause::DefaultChildPolicyUndefined,
+            "CONTINUE_AS_NEW_WORKFLOW_EXECUTION_RATE_EXCEEDED" => ContinueAsNewWorkflowExecutionFailedCause::ContinueAsNewWorkflowExecutionRateExceeded,
+            "OPERATION_NOT_PERMITTED" => ContinueAsNewWorkflowExecutionFailedCause::OperationNotPermitted,
+            _ => ContinueAsNewWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContinueAsNewWorkflowExecutionFailedCause {
+    fn from(value: String) -> ContinueAsNewWorkflowExecutionFailedCause {
+        ContinueAsNewWorkflowExecutionFailedCause::from(value.as_str())
+    }
+}
+
+impl From<ContinueAsNewWorkflowExecutionFailedCause> for String {
+    fn from(value: ContinueAsNewWorkflowExecutionFailedCause) -> String {
+        match value {
+            ContinueAsNewWorkflowExecutionFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContinueAsNewWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ContinueAsNewWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContinueAsNewWorkflowExecutionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ContinueAsNewWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContinueAsNewWorkflowExecutionFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>ContinueAsNewWorkflowExecutionFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ContinueAsNewWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: ContinueAsNewWorkflowExecutionFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>ContinueAsNewWorkflowExecution</code> decision that started this execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -573,6 +1146,86 @@ pub struct CountClosedWorkflowExecutionsInput {
     pub type_filter: Option<WorkflowTypeFilter>,
 }
 
+impl CountClosedWorkflowExecutionsInput {
+    /// Returns a builder for `CountClosedWorkflowExecutionsInput`, checking its required members when built.
+    pub fn builder() -> CountClosedWorkflowExecutionsInputBuilder {
+        CountClosedWorkflowExecutionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`CountClosedWorkflowExecutionsInput`](struct.CountClosedWorkflowExecutionsInput.html).
+#[derive(Default, Debug)]
+pub struct CountClosedWorkflowExecutionsInputBuilder {
+    close_status_filter: Option<CloseStatusFilter>,
+    close_time_filter: Option<ExecutionTimeFilter>,
+    domain: Option<String>,
+    execution_filter: Option<WorkflowExecutionFilter>,
+    start_time_filter: Option<ExecutionTimeFilter>,
+    tag_filter: Option<TagFilter>,
+    type_filter: Option<WorkflowTypeFilter>,
+}
+
+impl CountClosedWorkflowExecutionsInputBuilder {
+    /// Sets the [`close_status_filter`](struct.CountClosedWorkflowExecutionsInput.html#structfield.close_status_filter) member.
+    pub fn close_status_filter<T: Into<CloseStatusFilter>>(mut self, value: T) -> Self {
+        self.close_status_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`close_time_filter`](struct.CountClosedWorkflowExecutionsInput.html#structfield.close_time_filter) member.
+    pub fn close_time_filter<T: Into<ExecutionTimeFilter>>(mut self, value: T) -> Self {
+        self.close_time_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.CountClosedWorkflowExecutionsInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_filter`](struct.CountClosedWorkflowExecutionsInput.html#structfield.execution_filter) member.
+    pub fn execution_filter<T: Into<WorkflowExecutionFilter>>(mut self, value: T) -> Self {
+        self.execution_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time_filter`](struct.CountClosedWorkflowExecutionsInput.html#structfield.start_time_filter) member.
+    pub fn start_time_filter<T: Into<ExecutionTimeFilter>>(mut self, value: T) -> Self {
+        self.start_time_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_filter`](struct.CountClosedWorkflowExecutionsInput.html#structfield.tag_filter) member.
+    pub fn tag_filter<T: Into<TagFilter>>(mut self, value: T) -> Self {
+        self.tag_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_filter`](struct.CountClosedWorkflowExecutionsInput.html#structfield.type_filter) member.
+    pub fn type_filter<T: Into<WorkflowTypeFilter>>(mut self, value: T) -> Self {
+        self.type_filter = Some(value.into());
+        self
+    }
+
+    /// Builds the `CountClosedWorkflowExecutionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CountClosedWorkflowExecutionsInput, ::rusoto_core::BuildError> {
+        Ok(CountClosedWorkflowExecutionsInput {
+            close_status_filter: self.close_status_filter,
+            close_time_filter: self.close_time_filter,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            execution_filter: self.execution_filter,
+            start_time_filter: self.start_time_filter,
+            tag_filter: self.tag_filter,
+            type_filter: self.type_filter,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CountOpenWorkflowExecutionsInput {
     /// <p>The name of the domain containing the workflow executions to count.</p>
@@ -595,16 +1248,124 @@ pub struct CountOpenWorkflowExecutionsInput {
     pub type_filter: Option<WorkflowTypeFilter>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct CountPendingActivityTasksInput {
-    /// <p>The name of the domain that contains the task list.</p>
-    #[serde(rename = "domain")]
-    pub domain: String,
-    /// <p>The name of the task list.</p>
+impl CountOpenWorkflowExecutionsInput {
+    /// Returns a builder for `CountOpenWorkflowExecutionsInput`, checking its required members when built.
+    pub fn builder() -> CountOpenWorkflowExecutionsInputBuilder {
+        CountOpenWorkflowExecutionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`CountOpenWorkflowExecutionsInput`](struct.CountOpenWorkflowExecutionsInput.html).
+#[derive(Default, Debug)]
+pub struct CountOpenWorkflowExecutionsInputBuilder {
+    domain: Option<String>,
+    execution_filter: Option<WorkflowExecutionFilter>,
+    start_time_filter: Option<ExecutionTimeFilter>,
+    tag_filter: Option<TagFilter>,
+    type_filter: Option<WorkflowTypeFilter>,
+}
+
+impl CountOpenWorkflowExecutionsInputBuilder {
+    /// Sets the [`domain`](struct.CountOpenWorkflowExecutionsInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_filter`](struct.CountOpenWorkflowExecutionsInput.html#structfield.execution_filter) member.
+    pub fn execution_filter<T: Into<WorkflowExecutionFilter>>(mut self, value: T) -> Self {
+        self.execution_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time_filter`](struct.CountOpenWorkflowExecutionsInput.html#structfield.start_time_filter) member.
+    pub fn start_time_filter<T: Into<ExecutionTimeFilter>>(mut self, value: T) -> Self {
+        self.start_time_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_filter`](struct.CountOpenWorkflowExecutionsInput.html#structfield.tag_filter) member.
+    pub fn tag_filter<T: Into<TagFilter>>(mut self, value: T) -> Self {
+        self.tag_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_filter`](struct.CountOpenWorkflowExecutionsInput.html#structfield.type_filter) member.
+    pub fn type_filter<T: Into<WorkflowTypeFilter>>(mut self, value: T) -> Self {
+        self.type_filter = Some(value.into());
+        self
+    }
+
+    /// Builds the `CountOpenWorkflowExecutionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CountOpenWorkflowExecutionsInput, ::rusoto_core::BuildError> {
+        Ok(CountOpenWorkflowExecutionsInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            execution_filter: self.execution_filter,
+            start_time_filter: self
+                .start_time_filter
+                .ok_or(::rusoto_core::BuildError::missing("start_time_filter"))?,
+            tag_filter: self.tag_filter,
+            type_filter: self.type_filter,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CountPendingActivityTasksInput {
+    /// <p>The name of the domain that contains the task list.</p>
+    #[serde(rename = "domain")]
+    pub domain: String,
+    /// <p>The name of the task list.</p>
     #[serde(rename = "taskList")]
     pub task_list: TaskList,
 }
 
+impl CountPendingActivityTasksInput {
+    /// Returns a builder for `CountPendingActivityTasksInput`, checking its required members when built.
+    pub fn builder() -> CountPendingActivityTasksInputBuilder {
+        CountPendingActivityTasksInputBuilder::default()
+    }
+}
+
+/// Builds a [`CountPendingActivityTasksInput`](struct.CountPendingActivityTasksInput.html).
+#[derive(Default, Debug)]
+pub struct CountPendingActivityTasksInputBuilder {
+    domain: Option<String>,
+    task_list: Option<TaskList>,
+}
+
+impl CountPendingActivityTasksInputBuilder {
+    /// Sets the [`domain`](struct.CountPendingActivityTasksInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_list`](struct.CountPendingActivityTasksInput.html#structfield.task_list) member.
+    pub fn task_list<T: Into<TaskList>>(mut self, value: T) -> Self {
+        self.task_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `CountPendingActivityTasksInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CountPendingActivityTasksInput, ::rusoto_core::BuildError> {
+        Ok(CountPendingActivityTasksInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            task_list: self
+                .task_list
+                .ok_or(::rusoto_core::BuildError::missing("task_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CountPendingDecisionTasksInput {
     /// <p>The name of the domain that contains the task list.</p>
@@ -615,8 +1376,50 @@ pub struct CountPendingDecisionTasksInput {
     pub task_list: TaskList,
 }
 
+impl CountPendingDecisionTasksInput {
+    /// Returns a builder for `CountPendingDecisionTasksInput`, checking its required members when built.
+    pub fn builder() -> CountPendingDecisionTasksInputBuilder {
+        CountPendingDecisionTasksInputBuilder::default()
+    }
+}
+
+/// Builds a [`CountPendingDecisionTasksInput`](struct.CountPendingDecisionTasksInput.html).
+#[derive(Default, Debug)]
+pub struct CountPendingDecisionTasksInputBuilder {
+    domain: Option<String>,
+    task_list: Option<TaskList>,
+}
+
+impl CountPendingDecisionTasksInputBuilder {
+    /// Sets the [`domain`](struct.CountPendingDecisionTasksInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_list`](struct.CountPendingDecisionTasksInput.html#structfield.task_list) member.
+    pub fn task_list<T: Into<TaskList>>(mut self, value: T) -> Self {
+        self.task_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `CountPendingDecisionTasksInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CountPendingDecisionTasksInput, ::rusoto_core::BuildError> {
+        Ok(CountPendingDecisionTasksInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            task_list: self
+                .task_list
+                .ok_or(::rusoto_core::BuildError::missing("task_list"))?,
+        })
+    }
+}
+
 /// <p><p>Specifies a decision made by the decider. A decision can be one of these types:</p> <ul> <li> <p> <code>CancelTimer</code> – Cancels a previously started timer and records a <code>TimerCanceled</code> event in the history.</p> </li> <li> <p> <code>CancelWorkflowExecution</code> – Closes the workflow execution and records a <code>WorkflowExecutionCanceled</code> event in the history.</p> </li> <li> <p> <code>CompleteWorkflowExecution</code> – Closes the workflow execution and records a <code>WorkflowExecutionCompleted</code> event in the history .</p> </li> <li> <p> <code>ContinueAsNewWorkflowExecution</code> – Closes the workflow execution and starts a new workflow execution of the same type using the same workflow ID and a unique run Id. A <code>WorkflowExecutionContinuedAsNew</code> event is recorded in the history.</p> </li> <li> <p> <code>FailWorkflowExecution</code> – Closes the workflow execution and records a <code>WorkflowExecutionFailed</code> event in the history.</p> </li> <li> <p> <code>RecordMarker</code> – Records a <code>MarkerRecorded</code> event in the history. Markers can be used for adding custom information in the history for instance to let deciders know that they don&#39;t need to look at the history beyond the marker event.</p> </li> <li> <p> <code>RequestCancelActivityTask</code> – Attempts to cancel a previously scheduled activity task. If the activity task was scheduled but has not been assigned to a worker, then it is canceled. If the activity task was already assigned to a worker, then the worker is informed that cancellation has been requested in the response to <a>RecordActivityTaskHeartbeat</a>.</p> </li> <li> <p> <code>RequestCancelExternalWorkflowExecution</code> – Requests that a request be made to cancel the specified external workflow execution and records a <code>RequestCancelExternalWorkflowExecutionInitiated</code> event in the history.</p> </li> <li> <p> <code>ScheduleActivityTask</code> – Schedules an activity task.</p> </li> <li> <p> <code>SignalExternalWorkflowExecution</code> – Requests a signal to be delivered to the specified external workflow execution and records a <code>SignalExternalWorkflowExecutionInitiated</code> event in the history.</p> </li> <li> <p> <code>StartChildWorkflowExecution</code> – Requests that a child workflow execution be started and records a <code>StartChildWorkflowExecutionInitiated</code> event in the history. The child workflow execution is a separate workflow execution with its own history.</p> </li> <li> <p> <code>StartTimer</code> – Starts a timer for this workflow execution and records a <code>TimerStarted</code> event in the history. This timer fires after the specified delay and record a <code>TimerFired</code> event.</p> </li> </ul> <p> <b>Access Control</b> </p> <p>If you grant permission to use <code>RespondDecisionTaskCompleted</code>, you can use IAM policies to express permissions for the list of decisions returned by this action as if they were members of the API. Treating decisions as a pseudo API maintains a uniform conceptual model and helps keep policies readable. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> <p> <b>Decision Failure</b> </p> <p>Decisions can fail for several reasons</p> <ul> <li> <p>The ordering of decisions should follow a logical flow. Some decisions might not make sense in the current context of the workflow execution and therefore fails.</p> </li> <li> <p>A limit on your account was reached.</p> </li> <li> <p>The decision lacks sufficient permissions.</p> </li> </ul> <p>One of the following events might be added to the history to indicate an error. The event attribute&#39;s <code>cause</code> parameter indicates the cause. If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> <ul> <li> <p> <code>ScheduleActivityTaskFailed</code> – A <code>ScheduleActivityTask</code> decision failed. This could happen if the activity type specified in the decision isn&#39;t registered, is in a deprecated state, or the decision isn&#39;t properly configured.</p> </li> <li> <p> <code>RequestCancelActivityTaskFailed</code> – A <code>RequestCancelActivityTask</code> decision failed. This could happen if there is no open activity task with the specified activityId.</p> </li> <li> <p> <code>StartTimerFailed</code> – A <code>StartTimer</code> decision failed. This could happen if there is another open timer with the same timerId.</p> </li> <li> <p> <code>CancelTimerFailed</code> – A <code>CancelTimer</code> decision failed. This could happen if there is no open timer with the specified timerId.</p> </li> <li> <p> <code>StartChildWorkflowExecutionFailed</code> – A <code>StartChildWorkflowExecution</code> decision failed. This could happen if the workflow type specified isn&#39;t registered, is deprecated, or the decision isn&#39;t properly configured.</p> </li> <li> <p> <code>SignalExternalWorkflowExecutionFailed</code> – A <code>SignalExternalWorkflowExecution</code> decision failed. This could happen if the <code>workflowID</code> specified in the decision was incorrect.</p> </li> <li> <p> <code>RequestCancelExternalWorkflowExecutionFailed</code> – A <code>RequestCancelExternalWorkflowExecution</code> decision failed. This could happen if the <code>workflowID</code> specified in the decision was incorrect.</p> </li> <li> <p> <code>CancelWorkflowExecutionFailed</code> – A <code>CancelWorkflowExecution</code> decision failed. This could happen if there is an unhandled decision task pending in the workflow execution.</p> </li> <li> <p> <code>CompleteWorkflowExecutionFailed</code> – A <code>CompleteWorkflowExecution</code> decision failed. This could happen if there is an unhandled decision task pending in the workflow execution.</p> </li> <li> <p> <code>ContinueAsNewWorkflowExecutionFailed</code> – A <code>ContinueAsNewWorkflowExecution</code> decision failed. This could happen if there is an unhandled decision task pending in the workflow execution or the ContinueAsNewWorkflowExecution decision was not configured correctly.</p> </li> <li> <p> <code>FailWorkflowExecutionFailed</code> – A <code>FailWorkflowExecution</code> decision failed. This could happen if there is an unhandled decision task pending in the workflow execution.</p> </li> </ul> <p>The preceding error events might occur due to an error in the decider logic, which might put the workflow execution in an unstable state The cause field in the event structure for the error event indicates the cause of the error.</p> <note> <p>A workflow execution may be closed by the decider by returning one of the following decisions when completing a decision task: <code>CompleteWorkflowExecution</code>, <code>FailWorkflowExecution</code>, <code>CancelWorkflowExecution</code> and <code>ContinueAsNewWorkflowExecution</code>. An <code>UnhandledDecision</code> fault is returned if a workflow closing decision is specified and a signal or activity event had been added to the history while the decision task was being performed by the decider. Unlike the above situations which are logic issues, this fault is always possible because of race conditions in a distributed system. The right action here is to call <a>RespondDecisionTaskCompleted</a> without any decisions. This would result in another decision task with these new events included in the history. The decider should handle the new events and may decide to close the workflow execution.</p> </note> <p> <b>How to Code a Decision</b> </p> <p>You code a decision by first setting the decision type field to one of the above decision values, and then set the corresponding attributes field shown below:</p> <ul> <li> <p> <code> <a>ScheduleActivityTaskDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>RequestCancelActivityTaskDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>CompleteWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>FailWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>CancelWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>ContinueAsNewWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>RecordMarkerDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>StartTimerDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>CancelTimerDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>SignalExternalWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>RequestCancelExternalWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>StartChildWorkflowExecutionDecisionAttributes</a> </code> </p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Decision {
     /// <p>Provides the details of the <code>CancelTimer</code> decision. It isn't set for other decision types.</p>
     #[serde(rename = "cancelTimerDecisionAttributes")]
@@ -639,7 +1442,7 @@ pub struct Decision {
         Option<ContinueAsNewWorkflowExecutionDecisionAttributes>,
     /// <p>Specifies the type of the decision.</p>
     #[serde(rename = "decisionType")]
-    pub decision_type: String,
+    pub decision_type: DecisionType,
     /// <p>Provides the details of the <code>FailWorkflowExecution</code> decision. It isn't set for other decision types.</p>
     #[serde(rename = "failWorkflowExecutionDecisionAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -760,7 +1563,7 @@ pub struct DecisionTaskStartedEventAttributes {
 }
 
 /// <p>Provides the details of the <code>DecisionTaskTimedOut</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DecisionTaskTimedOutEventAttributes {
     /// <p>The ID of the <code>DecisionTaskScheduled</code> event that was recorded when this decision task was scheduled. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
@@ -771,7 +1574,202 @@ pub struct DecisionTaskTimedOutEventAttributes {
     pub started_event_id: i64,
     /// <p>The type of timeout that expired before the decision task could be completed.</p>
     #[serde(rename = "timeoutType")]
-    pub timeout_type: String,
+    pub timeout_type: DecisionTaskTimeoutType,
+}
+
+/// <p>The type of timeout that expired before the decision task could be completed.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DecisionTaskTimeoutType {
+    /// <p><code>START_TO_CLOSE</code></p>
+    StartToClose,
+    /// <p><code>SCHEDULE_TO_START</code></p>
+    ScheduleToStart,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DecisionTaskTimeoutType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DecisionTaskTimeoutType::StartToClose => "START_TO_CLOSE",
+            DecisionTaskTimeoutType::ScheduleToStart => "SCHEDULE_TO_START",
+            DecisionTaskTimeoutType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DecisionTaskTimeoutType {
+    fn from(value: &'a str) -> DecisionTaskTimeoutType {
+        match value {
+            "START_TO_CLOSE" => DecisionTaskTimeoutType::StartToClose,
+            "SCHEDULE_TO_START" => DecisionTaskTimeoutType::ScheduleToStart,
+            _ => DecisionTaskTimeoutType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DecisionTaskTimeoutType {
+    fn from(value: String) -> DecisionTaskTimeoutType {
+        DecisionTaskTimeoutType::from(value.as_str())
+    }
+}
+
+impl From<DecisionTaskTimeoutType> for String {
+    fn from(value: DecisionTaskTimeoutType) -> String {
+        match value {
+            DecisionTaskTimeoutType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DecisionTaskTimeoutType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DecisionTaskTimeoutType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DecisionTaskTimeoutType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DecisionTaskTimeoutType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DecisionTaskTimeoutType::from(value))
+    }
+}
+
+/// <p>Provides the details of the <code>CancelWorkflowExecution</code> decision. It isn't set for other decision types.</p>
+/// <p>Provides the details of the <code>CompleteWorkflowExecution</code> decision. It isn't set for other decision types.</p>
+/// <p>Provides the details of the <code>ContinueAsNewWorkflowExecution</code> decision. It isn't set for other decision types.</p>
+/// <p>Specifies the type of the decision.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DecisionType {
+    /// <p><code>ScheduleActivityTask</code></p>
+    ScheduleActivityTask,
+    /// <p><code>RequestCancelActivityTask</code></p>
+    RequestCancelActivityTask,
+    /// <p><code>CompleteWorkflowExecution</code></p>
+    CompleteWorkflowExecution,
+    /// <p><code>FailWorkflowExecution</code></p>
+    FailWorkflowExecution,
+    /// <p><code>CancelWorkflowExecution</code></p>
+    CancelWorkflowExecution,
+    /// <p><code>ContinueAsNewWorkflowExecution</code></p>
+    ContinueAsNewWorkflowExecution,
+    /// <p><code>RecordMarker</code></p>
+    RecordMarker,
+    /// <p><code>StartTimer</code></p>
+    StartTimer,
+    /// <p><code>CancelTimer</code></p>
+    CancelTimer,
+    /// <p><code>SignalExternalWorkflowExecution</code></p>
+    SignalExternalWorkflowExecution,
+    /// <p><code>RequestCancelExternalWorkflowExecution</code></p>
+    RequestCancelExternalWorkflowExecution,
+    /// <p><code>StartChildWorkflowExecution</code></p>
+    StartChildWorkflowExecution,
+    /// <p><code>ScheduleLambdaFunction</code></p>
+    ScheduleLambdaFunction,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DecisionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DecisionType::ScheduleActivityTask => "ScheduleActivityTask",
+            DecisionType::RequestCancelActivityTask => "RequestCancelActivityTask",
+            DecisionType::CompleteWorkflowExecution => "CompleteWorkflowExecution",
+            DecisionType::FailWorkflowExecution => "FailWorkflowExecution",
+            DecisionType::CancelWorkflowExecution => "CancelWorkflowExecution",
+            DecisionType::ContinueAsNewWorkflowExecution => "ContinueAsNewWorkflowExecution",
+            DecisionType::RecordMarker => "RecordMarker",
+            DecisionType::StartTimer => "StartTimer",
+            DecisionType::CancelTimer => "CancelTimer",
+            DecisionType::SignalExternalWorkflowExecution => "SignalExternalWorkflowExecution",
+            DecisionType::RequestCancelExternalWorkflowExecution => {
+                "RequestCancelExternalWorkflowExecution"
+            }
+            DecisionType::StartChildWorkflowExecution => "StartChildWorkflowExecution",
+            DecisionType::ScheduleLambdaFunction => "ScheduleLambdaFunction",
+            DecisionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DecisionType {
+    fn from(value: &'a str) -> DecisionType {
+        match value {
+            "ScheduleActivityTask" => DecisionType::ScheduleActivityTask,
+            "RequestCancelActivityTask" => DecisionType::RequestCancelActivityTask,
+            "CompleteWorkflowExecution" => DecisionType::CompleteWorkflowExecution,
+            "FailWorkflowExecution" => DecisionType::FailWorkflowExecution,
+            "CancelWorkflowExecution" => DecisionType::CancelWorkflowExecution,
+            "ContinueAsNewWorkflowExecution" => DecisionType::ContinueAsNewWorkflowExecution,
+            "RecordMarker" => DecisionType::RecordMarker,
+            "StartTimer" => DecisionType::StartTimer,
+            "CancelTimer" => DecisionType::CancelTimer,
+            "SignalExternalWorkflowExecution" => DecisionType::SignalExternalWorkflowExecution,
+            "RequestCancelExternalWorkflowExecution" => {
+                DecisionType::RequestCancelExternalWorkflowExecution
+            }
+            "StartChildWorkflowExecution" => DecisionType::StartChildWorkflowExecution,
+            "ScheduleLambdaFunction" => DecisionType::ScheduleLambdaFunction,
+            _ => DecisionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DecisionType {
+    fn from(value: String) -> DecisionType {
+        DecisionType::from(value.as_str())
+    }
+}
+
+impl From<DecisionType> for String {
+    fn from(value: DecisionType) -> String {
+        match value {
+            DecisionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DecisionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DecisionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DecisionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DecisionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DecisionType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -784,6 +1782,48 @@ pub struct DeprecateActivityTypeInput {
     pub domain: String,
 }
 
+impl DeprecateActivityTypeInput {
+    /// Returns a builder for `DeprecateActivityTypeInput`, checking its required members when built.
+    pub fn builder() -> DeprecateActivityTypeInputBuilder {
+        DeprecateActivityTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeprecateActivityTypeInput`](struct.DeprecateActivityTypeInput.html).
+#[derive(Default, Debug)]
+pub struct DeprecateActivityTypeInputBuilder {
+    activity_type: Option<ActivityType>,
+    domain: Option<String>,
+}
+
+impl DeprecateActivityTypeInputBuilder {
+    /// Sets the [`activity_type`](struct.DeprecateActivityTypeInput.html#structfield.activity_type) member.
+    pub fn activity_type<T: Into<ActivityType>>(mut self, value: T) -> Self {
+        self.activity_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.DeprecateActivityTypeInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeprecateActivityTypeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeprecateActivityTypeInput, ::rusoto_core::BuildError> {
+        Ok(DeprecateActivityTypeInput {
+            activity_type: self
+                .activity_type
+                .ok_or(::rusoto_core::BuildError::missing("activity_type"))?,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeprecateDomainInput {
     /// <p>The name of the domain to deprecate.</p>
@@ -791,6 +1831,36 @@ pub struct DeprecateDomainInput {
     pub name: String,
 }
 
+impl DeprecateDomainInput {
+    /// Returns a builder for `DeprecateDomainInput`, checking its required members when built.
+    pub fn builder() -> DeprecateDomainInputBuilder {
+        DeprecateDomainInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeprecateDomainInput`](struct.DeprecateDomainInput.html).
+#[derive(Default, Debug)]
+pub struct DeprecateDomainInputBuilder {
+    name: Option<String>,
+}
+
+impl DeprecateDomainInputBuilder {
+    /// Sets the [`name`](struct.DeprecateDomainInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeprecateDomainInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeprecateDomainInput, ::rusoto_core::BuildError> {
+        Ok(DeprecateDomainInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeprecateWorkflowTypeInput {
     /// <p>The name of the domain in which the workflow type is registered.</p>
@@ -801,6 +1871,48 @@ pub struct DeprecateWorkflowTypeInput {
     pub workflow_type: WorkflowType,
 }
 
+impl DeprecateWorkflowTypeInput {
+    /// Returns a builder for `DeprecateWorkflowTypeInput`, checking its required members when built.
+    pub fn builder() -> DeprecateWorkflowTypeInputBuilder {
+        DeprecateWorkflowTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeprecateWorkflowTypeInput`](struct.DeprecateWorkflowTypeInput.html).
+#[derive(Default, Debug)]
+pub struct DeprecateWorkflowTypeInputBuilder {
+    domain: Option<String>,
+    workflow_type: Option<WorkflowType>,
+}
+
+impl DeprecateWorkflowTypeInputBuilder {
+    /// Sets the [`domain`](struct.DeprecateWorkflowTypeInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workflow_type`](struct.DeprecateWorkflowTypeInput.html#structfield.workflow_type) member.
+    pub fn workflow_type<T: Into<WorkflowType>>(mut self, value: T) -> Self {
+        self.workflow_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeprecateWorkflowTypeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeprecateWorkflowTypeInput, ::rusoto_core::BuildError> {
+        Ok(DeprecateWorkflowTypeInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            workflow_type: self
+                .workflow_type
+                .ok_or(::rusoto_core::BuildError::missing("workflow_type"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeActivityTypeInput {
     /// <p>The activity type to get information about. Activity types are identified by the <code>name</code> and <code>version</code> that were supplied when the activity was registered.</p>
@@ -811,6 +1923,48 @@ pub struct DescribeActivityTypeInput {
     pub domain: String,
 }
 
+impl DescribeActivityTypeInput {
+    /// Returns a builder for `DescribeActivityTypeInput`, checking its required members when built.
+    pub fn builder() -> DescribeActivityTypeInputBuilder {
+        DescribeActivityTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeActivityTypeInput`](struct.DescribeActivityTypeInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeActivityTypeInputBuilder {
+    activity_type: Option<ActivityType>,
+    domain: Option<String>,
+}
+
+impl DescribeActivityTypeInputBuilder {
+    /// Sets the [`activity_type`](struct.DescribeActivityTypeInput.html#structfield.activity_type) member.
+    pub fn activity_type<T: Into<ActivityType>>(mut self, value: T) -> Self {
+        self.activity_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.DescribeActivityTypeInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeActivityTypeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeActivityTypeInput, ::rusoto_core::BuildError> {
+        Ok(DescribeActivityTypeInput {
+            activity_type: self
+                .activity_type
+                .ok_or(::rusoto_core::BuildError::missing("activity_type"))?,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeDomainInput {
     /// <p>The name of the domain to describe.</p>
@@ -818,6 +1972,36 @@ pub struct DescribeDomainInput {
     pub name: String,
 }
 
+impl DescribeDomainInput {
+    /// Returns a builder for `DescribeDomainInput`, checking its required members when built.
+    pub fn builder() -> DescribeDomainInputBuilder {
+        DescribeDomainInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDomainInput`](struct.DescribeDomainInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeDomainInputBuilder {
+    name: Option<String>,
+}
+
+impl DescribeDomainInputBuilder {
+    /// Sets the [`name`](struct.DescribeDomainInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDomainInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeDomainInput, ::rusoto_core::BuildError> {
+        Ok(DescribeDomainInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeWorkflowExecutionInput {
     /// <p>The name of the domain containing the workflow execution.</p>
@@ -828,6 +2012,48 @@ pub struct DescribeWorkflowExecutionInput {
     pub execution: WorkflowExecution,
 }
 
+impl DescribeWorkflowExecutionInput {
+    /// Returns a builder for `DescribeWorkflowExecutionInput`, checking its required members when built.
+    pub fn builder() -> DescribeWorkflowExecutionInputBuilder {
+        DescribeWorkflowExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeWorkflowExecutionInput`](struct.DescribeWorkflowExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeWorkflowExecutionInputBuilder {
+    domain: Option<String>,
+    execution: Option<WorkflowExecution>,
+}
+
+impl DescribeWorkflowExecutionInputBuilder {
+    /// Sets the [`domain`](struct.DescribeWorkflowExecutionInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution`](struct.DescribeWorkflowExecutionInput.html#structfield.execution) member.
+    pub fn execution<T: Into<WorkflowExecution>>(mut self, value: T) -> Self {
+        self.execution = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeWorkflowExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeWorkflowExecutionInput, ::rusoto_core::BuildError> {
+        Ok(DescribeWorkflowExecutionInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            execution: self
+                .execution
+                .ok_or(::rusoto_core::BuildError::missing("execution"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeWorkflowTypeInput {
     /// <p>The name of the domain in which this workflow type is registered.</p>
@@ -838,6 +2064,48 @@ pub struct DescribeWorkflowTypeInput {
     pub workflow_type: WorkflowType,
 }
 
+impl DescribeWorkflowTypeInput {
+    /// Returns a builder for `DescribeWorkflowTypeInput`, checking its required members when built.
+    pub fn builder() -> DescribeWorkflowTypeInputBuilder {
+        DescribeWorkflowTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeWorkflowTypeInput`](struct.DescribeWorkflowTypeInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeWorkflowTypeInputBuilder {
+    domain: Option<String>,
+    workflow_type: Option<WorkflowType>,
+}
+
+impl DescribeWorkflowTypeInputBuilder {
+    /// Sets the [`domain`](struct.DescribeWorkflowTypeInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workflow_type`](struct.DescribeWorkflowTypeInput.html#structfield.workflow_type) member.
+    pub fn workflow_type<T: Into<WorkflowType>>(mut self, value: T) -> Self {
+        self.workflow_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeWorkflowTypeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeWorkflowTypeInput, ::rusoto_core::BuildError> {
+        Ok(DescribeWorkflowTypeInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            workflow_type: self
+                .workflow_type
+                .ok_or(::rusoto_core::BuildError::missing("workflow_type"))?,
+        })
+    }
+}
+
 /// <p>Contains the configuration settings of a domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -848,7 +2116,7 @@ pub struct DomainConfiguration {
 }
 
 /// <p>Contains details of a domain.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DomainDetail {
     /// <p>The domain configuration. Currently, this includes only the domain's retention period.</p>
@@ -860,7 +2128,7 @@ pub struct DomainDetail {
 }
 
 /// <p>Contains general information about a domain.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DomainInfo {
     /// <p>The ARN of the domain.</p>
@@ -876,7 +2144,7 @@ pub struct DomainInfo {
     pub name: String,
     /// <p><p>The status of the domain:</p> <ul> <li> <p> <code>REGISTERED</code> – The domain is properly registered and available. You can use this domain for registering types and creating new workflow executions. </p> </li> <li> <p> <code>DEPRECATED</code> – The domain was deprecated using <a>DeprecateDomain</a>, but is still in use. You should not create new workflow executions in this domain. </p> </li> </ul></p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: RegistrationStatus,
 }
 
 /// <p>Contains a paginated collection of DomainInfo structures.</p>
@@ -892,47 +2160,429 @@ pub struct DomainInfos {
     pub next_page_token: Option<String>,
 }
 
-/// <p>Used to filter the workflow executions in visibility APIs by various time-based rules. Each parameter, if specified, defines a rule that must be satisfied by each returned query result. The parameter values are in the <a href="https://en.wikipedia.org/wiki/Unix_time">Unix Time format</a>. For example: <code>"oldestDate": 1325376070.</code> </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ExecutionTimeFilter {
-    /// <p>Specifies the latest start or close date and time to return.</p>
-    #[serde(rename = "latestDate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_date: Option<f64>,
-    /// <p>Specifies the oldest start or close date and time to return.</p>
-    #[serde(rename = "oldestDate")]
-    pub oldest_date: f64,
+/// <p>The type of the history event.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventType {
+    /// <p><code>WorkflowExecutionStarted</code></p>
+    WorkflowExecutionStarted,
+    /// <p><code>WorkflowExecutionCancelRequested</code></p>
+    WorkflowExecutionCancelRequested,
+    /// <p><code>WorkflowExecutionCompleted</code></p>
+    WorkflowExecutionCompleted,
+    /// <p><code>CompleteWorkflowExecutionFailed</code></p>
+    CompleteWorkflowExecutionFailed,
+    /// <p><code>WorkflowExecutionFailed</code></p>
+    WorkflowExecutionFailed,
+    /// <p><code>FailWorkflowExecutionFailed</code></p>
+    FailWorkflowExecutionFailed,
+    /// <p><code>WorkflowExecutionTimedOut</code></p>
+    WorkflowExecutionTimedOut,
+    /// <p><code>WorkflowExecutionCanceled</code></p>
+    WorkflowExecutionCanceled,
+    /// <p><code>CancelWorkflowExecutionFailed</code></p>
+    CancelWorkflowExecutionFailed,
+    /// <p><code>WorkflowExecutionContinuedAsNew</code></p>
+    WorkflowExecutionContinuedAsNew,
+    /// <p><code>ContinueAsNewWorkflowExecutionFailed</code></p>
+    ContinueAsNewWorkflowExecutionFailed,
+    /// <p><code>WorkflowExecutionTerminated</code></p>
+    WorkflowExecutionTerminated,
+    /// <p><code>DecisionTaskScheduled</code></p>
+    DecisionTaskScheduled,
+    /// <p><code>DecisionTaskStarted</code></p>
+    DecisionTaskStarted,
+    /// <p><code>DecisionTaskCompleted</code></p>
+    DecisionTaskCompleted,
+    /// <p><code>DecisionTaskTimedOut</code></p>
+    DecisionTaskTimedOut,
+    /// <p><code>ActivityTaskScheduled</code></p>
+    ActivityTaskScheduled,
+    /// <p><code>ScheduleActivityTaskFailed</code></p>
+    ScheduleActivityTaskFailed,
+    /// <p><code>ActivityTaskStarted</code></p>
+    ActivityTaskStarted,
+    /// <p><code>ActivityTaskCompleted</code></p>
+    ActivityTaskCompleted,
+    /// <p><code>ActivityTaskFailed</code></p>
+    ActivityTaskFailed,
+    /// <p><code>ActivityTaskTimedOut</code></p>
+    ActivityTaskTimedOut,
+    /// <p><code>ActivityTaskCanceled</code></p>
+    ActivityTaskCanceled,
+    /// <p><code>ActivityTaskCancelRequested</code></p>
+    ActivityTaskCancelRequested,
+    /// <p><code>RequestCancelActivityTaskFailed</code></p>
+    RequestCancelActivityTaskFailed,
+    /// <p><code>WorkflowExecutionSignaled</code></p>
+    WorkflowExecutionSignaled,
+    /// <p><code>MarkerRecorded</code></p>
+    MarkerRecorded,
+    /// <p><code>RecordMarkerFailed</code></p>
+    RecordMarkerFailed,
+    /// <p><code>TimerStarted</code></p>
+    TimerStarted,
+    /// <p><code>StartTimerFailed</code></p>
+    StartTimerFailed,
+    /// <p><code>TimerFired</code></p>
+    TimerFired,
+    /// <p><code>TimerCanceled</code></p>
+    TimerCanceled,
+    /// <p><code>CancelTimerFailed</code></p>
+    CancelTimerFailed,
+    /// <p><code>StartChildWorkflowExecutionInitiated</code></p>
+    StartChildWorkflowExecutionInitiated,
+    /// <p><code>StartChildWorkflowExecutionFailed</code></p>
+    StartChildWorkflowExecutionFailed,
+    /// <p><code>ChildWorkflowExecutionStarted</code></p>
+    ChildWorkflowExecutionStarted,
+    /// <p><code>ChildWorkflowExecutionCompleted</code></p>
+    ChildWorkflowExecutionCompleted,
+    /// <p><code>ChildWorkflowExecutionFailed</code></p>
+    ChildWorkflowExecutionFailed,
+    /// <p><code>ChildWorkflowExecutionTimedOut</code></p>
+    ChildWorkflowExecutionTimedOut,
+    /// <p><code>ChildWorkflowExecutionCanceled</code></p>
+    ChildWorkflowExecutionCanceled,
+    /// <p><code>ChildWorkflowExecutionTerminated</code></p>
+    ChildWorkflowExecutionTerminated,
+    /// <p><code>SignalExternalWorkflowExecutionInitiated</code></p>
+    SignalExternalWorkflowExecutionInitiated,
+    /// <p><code>SignalExternalWorkflowExecutionFailed</code></p>
+    SignalExternalWorkflowExecutionFailed,
+    /// <p><code>ExternalWorkflowExecutionSignaled</code></p>
+    ExternalWorkflowExecutionSignaled,
+    /// <p><code>RequestCancelExternalWorkflowExecutionInitiated</code></p>
+    RequestCancelExternalWorkflowExecutionInitiated,
+    /// <p><code>RequestCancelExternalWorkflowExecutionFailed</code></p>
+    RequestCancelExternalWorkflowExecutionFailed,
+    /// <p><code>ExternalWorkflowExecutionCancelRequested</code></p>
+    ExternalWorkflowExecutionCancelRequested,
+    /// <p><code>LambdaFunctionScheduled</code></p>
+    LambdaFunctionScheduled,
+    /// <p><code>LambdaFunctionStarted</code></p>
+    LambdaFunctionStarted,
+    /// <p><code>LambdaFunctionCompleted</code></p>
+    LambdaFunctionCompleted,
+    /// <p><code>LambdaFunctionFailed</code></p>
+    LambdaFunctionFailed,
+    /// <p><code>LambdaFunctionTimedOut</code></p>
+    LambdaFunctionTimedOut,
+    /// <p><code>ScheduleLambdaFunctionFailed</code></p>
+    ScheduleLambdaFunctionFailed,
+    /// <p><code>StartLambdaFunctionFailed</code></p>
+    StartLambdaFunctionFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::WorkflowExecutionStarted => "WorkflowExecutionStarted",
+            EventType::WorkflowExecutionCancelRequested => "WorkflowExecutionCancelRequested",
+            EventType::WorkflowExecutionCompleted => "WorkflowExecutionCompleted",
+            EventType::CompleteWorkflowExecutionFailed => "CompleteWorkflowExecutionFailed",
+            EventType::WorkflowExecutionFailed => "WorkflowExecutionFailed",
+            EventType::FailWorkflowExecutionFailed => "FailWorkflowExecutionFailed",
+            EventType::WorkflowExecutionTimedOut => "WorkflowExecutionTimedOut",
+            EventType::WorkflowExecutionCanceled => "WorkflowExecutionCanceled",
+            EventType::CancelWorkflowExecutionFailed => "CancelWorkflowExecutionFailed",
+            EventType::WorkflowExecutionContinuedAsNew => "WorkflowExecutionContinuedAsNew",
+            EventType::ContinueAsNewWorkflowExecutionFailed => {
+                "ContinueAsNewWorkflowExecutionFailed"
+            }
+            EventType::WorkflowExecutionTerminated => "WorkflowExecutionTerminated",
+            EventType::DecisionTaskScheduled => "DecisionTaskScheduled",
+            EventType::DecisionTaskStarted => "DecisionTaskStarted",
+            EventType::DecisionTaskCompleted => "DecisionTaskCompleted",
+            EventType::DecisionTaskTimedOut => "DecisionTaskTimedOut",
+            EventType::ActivityTaskScheduled => "ActivityTaskScheduled",
+            EventType::ScheduleActivityTaskFailed => "ScheduleActivityTaskFailed",
+            EventType::ActivityTaskStarted => "ActivityTaskStarted",
+            EventType::ActivityTaskCompleted => "ActivityTaskCompleted",
+            EventType::ActivityTaskFailed => "ActivityTaskFailed",
+            EventType::ActivityTaskTimedOut => "ActivityTaskTimedOut",
+            EventType::ActivityTaskCanceled => "ActivityTaskCanceled",
+            EventType::ActivityTaskCancelRequested => "ActivityTaskCancelRequested",
+            EventType::RequestCancelActivityTaskFailed => "RequestCancelActivityTaskFailed",
+            EventType::WorkflowExecutionSignaled => "WorkflowExecutionSignaled",
+            EventType::MarkerRecorded => "MarkerRecorded",
+            EventType::RecordMarkerFailed => "RecordMarkerFailed",
+            EventType::TimerStarted => "TimerStarted",
+            EventType::StartTimerFailed => "StartTimerFailed",
+            EventType::TimerFired => "TimerFired",
+            EventType::TimerCanceled => "TimerCanceled",
+            EventType::CancelTimerFailed => "CancelTimerFailed",
+            EventType::StartChildWorkflowExecutionInitiated => {
+                "StartChildWorkflowExecutionInitiated"
+            }
+            EventType::StartChildWorkflowExecutionFailed => "StartChildWorkflowExecutionFailed",
+            EventType::ChildWorkflowExecutionStarted => "ChildWorkflowExecutionStarted",
+            EventType::ChildWorkflowExecutionCompleted => "ChildWorkflowExecutionCompleted",
+            EventType::ChildWorkflowExecutionFailed => "ChildWorkflowExecutionFailed",
+            EventType::ChildWorkflowExecutionTimedOut => "ChildWorkflowExecutionTimedOut",
+            EventType::ChildWorkflowExecutionCanceled => "ChildWorkflowExecutionCanceled",
+            EventType::ChildWorkflowExecutionTerminated => "ChildWorkflowExecutionTerminated",
+            EventType::SignalExternalWorkflowExecutionInitiated => {
+                "SignalExternalWorkflowExecutionInitiated"
+            }
+            EventType::SignalExternalWorkflowExecutionFailed => {
+                "SignalExternalWorkflowExecutionFailed"
+            }
+            EventType::ExternalWorkflowExecutionSignaled => "ExternalWorkflowExecutionSignaled",
+            EventType::RequestCancelExternalWorkflowExecutionInitiated => {
+                "RequestCancelExternalWorkflowExecutionInitiated"
+            }
+            EventType::RequestCancelExternalWorkflowExecutionFailed => {
+                "RequestCancelExternalWorkflowExecutionFailed"
+            }
+            EventType::ExternalWorkflowExecutionCancelRequested => {
+                "ExternalWorkflowExecutionCancelRequested"
+            }
+            EventType::LambdaFunctionScheduled => "LambdaFunctionScheduled",
+            EventType::LambdaFunctionStarted => "LambdaFunctionStarted",
+            EventType::LambdaFunctionCompleted => "LambdaFunctionCompleted",
+            EventType::LambdaFunctionFailed => "LambdaFunctionFailed",
+            EventType::LambdaFunctionTimedOut => "LambdaFunctionTimedOut",
+            EventType::ScheduleLambdaFunctionFailed => "ScheduleLambdaFunctionFailed",
+            EventType::StartLambdaFunctionFailed => "StartLambdaFunctionFailed",
+            EventType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Provides the details of the <code>ExternalWorkflowExecutionCancelRequested</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ExternalWorkflowExecutionCancelRequestedEventAttributes {
-    /// <p>The ID of the <code>RequestCancelExternalWorkflowExecutionInitiated</code> event corresponding to the <code>RequestCancelExternalWorkflowExecution</code> decision to cancel this external workflow execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
-    #[serde(rename = "initiatedEventId")]
-    pub initiated_event_id: i64,
-    /// <p>The external workflow execution to which the cancellation request was delivered.</p>
-    #[serde(rename = "workflowExecution")]
-    pub workflow_execution: WorkflowExecution,
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "WorkflowExecutionStarted" => EventType::WorkflowExecutionStarted,
+            "WorkflowExecutionCancelRequested" => EventType::WorkflowExecutionCancelRequested,
+            "WorkflowExecutionCompleted" => EventType::WorkflowExecutionCompleted,
+            "CompleteWorkflowExecutionFailed" => EventType::CompleteWorkflowExecutionFailed,
+            "WorkflowExecutionFailed" => EventType::WorkflowExecutionFailed,
+            "FailWorkflowExecutionFailed" => EventType::FailWorkflowExecutionFailed,
+            "WorkflowExecutionTimedOut" => EventType::WorkflowExecutionTimedOut,
+            "WorkflowExecutionCanceled" => EventType::WorkflowExecutionCanceled,
+            "CancelWorkflowExecutionFailed" => EventType::CancelWorkflowExecutionFailed,
+            "WorkflowExecutionContinuedAsNew" => EventType::WorkflowExecutionContinuedAsNew,
+            "ContinueAsNewWorkflowExecutionFailed" => {
+                EventType::ContinueAsNewWorkflowExecutionFailed
+            }
+            "WorkflowExecutionTerminated" => EventType::WorkflowExecutionTerminated,
+            "DecisionTaskScheduled" => EventType::DecisionTaskScheduled,
+            "DecisionTaskStarted" => EventType::DecisionTaskStarted,
+            "DecisionTaskCompleted" => EventType::DecisionTaskCompleted,
+            "DecisionTaskTimedOut" => EventType::DecisionTaskTimedOut,
+            "ActivityTaskScheduled" => EventType::ActivityTaskScheduled,
+            "ScheduleActivityTaskFailed" => EventType::ScheduleActivityTaskFailed,
+            "ActivityTaskStarted" => EventType::ActivityTaskStarted,
+            "ActivityTaskCompleted" => EventType::ActivityTaskCompleted,
+            "ActivityTaskFailed" => EventType::ActivityTaskFailed,
+            "ActivityTaskTimedOut" => EventType::ActivityTaskTimedOut,
+            "ActivityTaskCanceled" => EventType::ActivityTaskCanceled,
+            "ActivityTaskCancelRequested" => EventType::ActivityTaskCancelRequested,
+            "RequestCancelActivityTaskFailed" => EventType::RequestCancelActivityTaskFailed,
+            "WorkflowExecutionSignaled" => EventType::WorkflowExecutionSignaled,
+            "MarkerRecorded" => EventType::MarkerRecorded,
+            "RecordMarkerFailed" => EventType::RecordMarkerFailed,
+            "TimerStarted" => EventType::TimerStarted,
+            "StartTimerFailed" => EventType::StartTimerFailed,
+            "TimerFired" => EventType::TimerFired,
+            "TimerCanceled" => EventType::TimerCanceled,
+            "CancelTimerFailed" => EventType::CancelTimerFailed,
+            "StartChildWorkflowExecutionInitiated" => {
+                EventType::StartChildWorkflowExecutionInitiated
+            }
+            "StartChildWorkflowExecutionFailed" => EventType::StartChildWorkflowExecutionFailed,
+            "ChildWorkflowExecutionStarted" => EventType::ChildWorkflowExecutionStarted,
+            "ChildWorkflowExecutionCompleted" => EventType::ChildWorkflowExecutionCompleted,
+            "ChildWorkflowExecutionFailed" => EventType::ChildWorkflowExecutionFailed,
+            "ChildWorkflowExecutionTimedOut" => EventType::ChildWorkflowExecutionTimedOut,
+            "ChildWorkflowExecutionCanceled" => EventType::ChildWorkflowExecutionCanceled,
+            "ChildWorkflowExecutionTerminated" => EventType::ChildWorkflowExecutionTerminated,
+            "SignalExternalWorkflowExecutionInitiated" => {
+                EventType::SignalExternalWorkflowExecutionInitiated
+            }
+            "SignalExternalWorkflowExecutionFailed" => {
+                EventType::SignalExternalWorkflowExecutionFailed
+            }
+            "ExternalWorkflowExecutionSignaled" => EventType::ExternalWorkflowExecutionSignaled,
+            "RequestCancelExternalWorkflowExecutionInitiated" => {
+                EventType::RequestCancelExternalWorkflowExecutionInitiated
+            }
+            "RequestCancelExternalWorkflowExecutionFailed" => {
+                EventType::RequestCancelExternalWorkflowExecutionFailed
+            }
+            "ExternalWorkflowExecutionCancelRequested" => {
+                EventType::ExternalWorkflowExecutionCancelRequested
+            }
+            "LambdaFunctionScheduled" => EventType::LambdaFunctionScheduled,
+            "LambdaFunctionStarted" => EventType::LambdaFunctionStarted,
+            "LambdaFunctionCompleted" => EventType::LambdaFunctionCompleted,
+            "LambdaFunctionFailed" => EventType::LambdaFunctionFailed,
+            "LambdaFunctionTimedOut" => EventType::LambdaFunctionTimedOut,
+            "ScheduleLambdaFunctionFailed" => EventType::ScheduleLambdaFunctionFailed,
+            "StartLambdaFunctionFailed" => EventType::StartLambdaFunctionFailed,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Provides the details of the <code>ExternalWorkflowExecutionSignaled</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ExternalWorkflowExecutionSignaledEventAttributes {
-    /// <p>The ID of the <code>SignalExternalWorkflowExecutionInitiated</code> event corresponding to the <code>SignalExternalWorkflowExecution</code> decision to request this signal. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
-    #[serde(rename = "initiatedEventId")]
-    pub initiated_event_id: i64,
-    /// <p>The external workflow execution that the signal was delivered to.</p>
-    #[serde(rename = "workflowExecution")]
-    pub workflow_execution: WorkflowExecution,
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        EventType::from(value.as_str())
+    }
 }
 
-/// <p>Provides the details of the <code>FailWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct FailWorkflowExecutionDecisionAttributes {
-    /// <p> Details of the failure.</p>
-    #[serde(rename = "details")]
+impl From<EventType> for String {
+    fn from(value: EventType) -> String {
+        match value {
+            EventType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventType::from(value))
+    }
+}
+
+/// <p>The current status of the execution.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExecutionStatus {
+    /// <p><code>OPEN</code></p>
+    Open,
+    /// <p><code>CLOSED</code></p>
+    Closed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExecutionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionStatus::Open => "OPEN",
+            ExecutionStatus::Closed => "CLOSED",
+            ExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExecutionStatus {
+    fn from(value: &'a str) -> ExecutionStatus {
+        match value {
+            "OPEN" => ExecutionStatus::Open,
+            "CLOSED" => ExecutionStatus::Closed,
+            _ => ExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionStatus {
+    fn from(value: String) -> ExecutionStatus {
+        ExecutionStatus::from(value.as_str())
+    }
+}
+
+impl From<ExecutionStatus> for String {
+    fn from(value: ExecutionStatus) -> String {
+        match value {
+            ExecutionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExecutionStatus::from(value))
+    }
+}
+
+/// <p>Used to filter the workflow executions in visibility APIs by various time-based rules. Each parameter, if specified, defines a rule that must be satisfied by each returned query result. The parameter values are in the <a href="https://en.wikipedia.org/wiki/Unix_time">Unix Time format</a>. For example: <code>"oldestDate": 1325376070.</code> </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ExecutionTimeFilter {
+    /// <p>Specifies the latest start or close date and time to return.</p>
+    #[serde(rename = "latestDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_date: Option<EpochTimestamp>,
+    /// <p>Specifies the oldest start or close date and time to return.</p>
+    #[serde(rename = "oldestDate")]
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub oldest_date: EpochTimestamp,
+}
+
+/// <p>Provides the details of the <code>ExternalWorkflowExecutionCancelRequested</code> event.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ExternalWorkflowExecutionCancelRequestedEventAttributes {
+    /// <p>The ID of the <code>RequestCancelExternalWorkflowExecutionInitiated</code> event corresponding to the <code>RequestCancelExternalWorkflowExecution</code> decision to cancel this external workflow execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
+    #[serde(rename = "initiatedEventId")]
+    pub initiated_event_id: i64,
+    /// <p>The external workflow execution to which the cancellation request was delivered.</p>
+    #[serde(rename = "workflowExecution")]
+    pub workflow_execution: WorkflowExecution,
+}
+
+/// <p>Provides the details of the <code>ExternalWorkflowExecutionSignaled</code> event.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ExternalWorkflowExecutionSignaledEventAttributes {
+    /// <p>The ID of the <code>SignalExternalWorkflowExecutionInitiated</code> event corresponding to the <code>SignalExternalWorkflowExecution</code> decision to request this signal. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
+    #[serde(rename = "initiatedEventId")]
+    pub initiated_event_id: i64,
+    /// <p>The external workflow execution that the signal was delivered to.</p>
+    #[serde(rename = "workflowExecution")]
+    pub workflow_execution: WorkflowExecution,
+}
+
+/// <p>Provides the details of the <code>FailWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct FailWorkflowExecutionDecisionAttributes {
+    /// <p> Details of the failure.</p>
+    #[serde(rename = "details")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<String>,
     /// <p>A descriptive reason for the failure that may help in diagnostics.</p>
@@ -941,13 +2591,87 @@ pub struct FailWorkflowExecutionDecisionAttributes {
     pub reason: Option<String>,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FailWorkflowExecutionFailedCause {
+    /// <p><code>UNHANDLED_DECISION</code></p>
+    UnhandledDecision,
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FailWorkflowExecutionFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailWorkflowExecutionFailedCause::UnhandledDecision => "UNHANDLED_DECISION",
+            FailWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            FailWorkflowExecutionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FailWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> FailWorkflowExecutionFailedCause {
+        match value {
+            "UNHANDLED_DECISION" => FailWorkflowExecutionFailedCause::UnhandledDecision,
+            "OPERATION_NOT_PERMITTED" => FailWorkflowExecutionFailedCause::OperationNotPermitted,
+            _ => FailWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailWorkflowExecutionFailedCause {
+    fn from(value: String) -> FailWorkflowExecutionFailedCause {
+        FailWorkflowExecutionFailedCause::from(value.as_str())
+    }
+}
+
+impl From<FailWorkflowExecutionFailedCause> for String {
+    fn from(value: FailWorkflowExecutionFailedCause) -> String {
+        match value {
+            FailWorkflowExecutionFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FailWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FailWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FailWorkflowExecutionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<FailWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FailWorkflowExecutionFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>FailWorkflowExecutionFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct FailWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: FailWorkflowExecutionFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>FailWorkflowExecution</code> decision to fail this execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -975,6 +2699,72 @@ pub struct GetWorkflowExecutionHistoryInput {
     pub reverse_order: Option<bool>,
 }
 
+impl GetWorkflowExecutionHistoryInput {
+    /// Returns a builder for `GetWorkflowExecutionHistoryInput`, checking its required members when built.
+    pub fn builder() -> GetWorkflowExecutionHistoryInputBuilder {
+        GetWorkflowExecutionHistoryInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetWorkflowExecutionHistoryInput`](struct.GetWorkflowExecutionHistoryInput.html).
+#[derive(Default, Debug)]
+pub struct GetWorkflowExecutionHistoryInputBuilder {
+    domain: Option<String>,
+    execution: Option<WorkflowExecution>,
+    maximum_page_size: Option<i64>,
+    next_page_token: Option<String>,
+    reverse_order: Option<bool>,
+}
+
+impl GetWorkflowExecutionHistoryInputBuilder {
+    /// Sets the [`domain`](struct.GetWorkflowExecutionHistoryInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution`](struct.GetWorkflowExecutionHistoryInput.html#structfield.execution) member.
+    pub fn execution<T: Into<WorkflowExecution>>(mut self, value: T) -> Self {
+        self.execution = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maximum_page_size`](struct.GetWorkflowExecutionHistoryInput.html#structfield.maximum_page_size) member.
+    pub fn maximum_page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.maximum_page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.GetWorkflowExecutionHistoryInput.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reverse_order`](struct.GetWorkflowExecutionHistoryInput.html#structfield.reverse_order) member.
+    pub fn reverse_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reverse_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetWorkflowExecutionHistoryInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetWorkflowExecutionHistoryInput, ::rusoto_core::BuildError> {
+        Ok(GetWorkflowExecutionHistoryInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            execution: self
+                .execution
+                .ok_or(::rusoto_core::BuildError::missing("execution"))?,
+            maximum_page_size: self.maximum_page_size,
+            next_page_token: self.next_page_token,
+            reverse_order: self.reverse_order,
+        })
+    }
+}
+
 /// <p>Paginated representation of a workflow history for a workflow execution. This is the up to date, complete and authoritative record of the events related to all tasks and events in the life of the workflow execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -989,7 +2779,7 @@ pub struct History {
 }
 
 /// <p><p>Event within a workflow execution. A history event can be one of these types:</p> <ul> <li> <p> <code>ActivityTaskCancelRequested</code> – A <code>RequestCancelActivityTask</code> decision was received by the system.</p> </li> <li> <p> <code>ActivityTaskCanceled</code> – The activity task was successfully canceled.</p> </li> <li> <p> <code>ActivityTaskCompleted</code> – An activity worker successfully completed an activity task by calling <a>RespondActivityTaskCompleted</a>.</p> </li> <li> <p> <code>ActivityTaskFailed</code> – An activity worker failed an activity task by calling <a>RespondActivityTaskFailed</a>.</p> </li> <li> <p> <code>ActivityTaskScheduled</code> – An activity task was scheduled for execution.</p> </li> <li> <p> <code>ActivityTaskStarted</code> – The scheduled activity task was dispatched to a worker.</p> </li> <li> <p> <code>ActivityTaskTimedOut</code> – The activity task timed out.</p> </li> <li> <p> <code>CancelTimerFailed</code> – Failed to process CancelTimer decision. This happens when the decision isn&#39;t configured properly, for example no timer exists with the specified timer Id.</p> </li> <li> <p> <code>CancelWorkflowExecutionFailed</code> – A request to cancel a workflow execution failed.</p> </li> <li> <p> <code>ChildWorkflowExecutionCanceled</code> – A child workflow execution, started by this workflow execution, was canceled and closed.</p> </li> <li> <p> <code>ChildWorkflowExecutionCompleted</code> – A child workflow execution, started by this workflow execution, completed successfully and was closed.</p> </li> <li> <p> <code>ChildWorkflowExecutionFailed</code> – A child workflow execution, started by this workflow execution, failed to complete successfully and was closed.</p> </li> <li> <p> <code>ChildWorkflowExecutionStarted</code> – A child workflow execution was successfully started.</p> </li> <li> <p> <code>ChildWorkflowExecutionTerminated</code> – A child workflow execution, started by this workflow execution, was terminated.</p> </li> <li> <p> <code>ChildWorkflowExecutionTimedOut</code> – A child workflow execution, started by this workflow execution, timed out and was closed.</p> </li> <li> <p> <code>CompleteWorkflowExecutionFailed</code> – The workflow execution failed to complete.</p> </li> <li> <p> <code>ContinueAsNewWorkflowExecutionFailed</code> – The workflow execution failed to complete after being continued as a new workflow execution.</p> </li> <li> <p> <code>DecisionTaskCompleted</code> – The decider successfully completed a decision task by calling <a>RespondDecisionTaskCompleted</a>.</p> </li> <li> <p> <code>DecisionTaskScheduled</code> – A decision task was scheduled for the workflow execution.</p> </li> <li> <p> <code>DecisionTaskStarted</code> – The decision task was dispatched to a decider.</p> </li> <li> <p> <code>DecisionTaskTimedOut</code> – The decision task timed out.</p> </li> <li> <p> <code>ExternalWorkflowExecutionCancelRequested</code> – Request to cancel an external workflow execution was successfully delivered to the target execution.</p> </li> <li> <p> <code>ExternalWorkflowExecutionSignaled</code> – A signal, requested by this workflow execution, was successfully delivered to the target external workflow execution.</p> </li> <li> <p> <code>FailWorkflowExecutionFailed</code> – A request to mark a workflow execution as failed, itself failed.</p> </li> <li> <p> <code>MarkerRecorded</code> – A marker was recorded in the workflow history as the result of a <code>RecordMarker</code> decision.</p> </li> <li> <p> <code>RecordMarkerFailed</code> – A <code>RecordMarker</code> decision was returned as failed.</p> </li> <li> <p> <code>RequestCancelActivityTaskFailed</code> – Failed to process RequestCancelActivityTask decision. This happens when the decision isn&#39;t configured properly.</p> </li> <li> <p> <code>RequestCancelExternalWorkflowExecutionFailed</code> – Request to cancel an external workflow execution failed.</p> </li> <li> <p> <code>RequestCancelExternalWorkflowExecutionInitiated</code> – A request was made to request the cancellation of an external workflow execution.</p> </li> <li> <p> <code>ScheduleActivityTaskFailed</code> – Failed to process ScheduleActivityTask decision. This happens when the decision isn&#39;t configured properly, for example the activity type specified isn&#39;t registered.</p> </li> <li> <p> <code>SignalExternalWorkflowExecutionFailed</code> – The request to signal an external workflow execution failed.</p> </li> <li> <p> <code>SignalExternalWorkflowExecutionInitiated</code> – A request to signal an external workflow was made.</p> </li> <li> <p> <code>StartActivityTaskFailed</code> – A scheduled activity task failed to start.</p> </li> <li> <p> <code>StartChildWorkflowExecutionFailed</code> – Failed to process StartChildWorkflowExecution decision. This happens when the decision isn&#39;t configured properly, for example the workflow type specified isn&#39;t registered.</p> </li> <li> <p> <code>StartChildWorkflowExecutionInitiated</code> – A request was made to start a child workflow execution.</p> </li> <li> <p> <code>StartTimerFailed</code> – Failed to process StartTimer decision. This happens when the decision isn&#39;t configured properly, for example a timer already exists with the specified timer Id.</p> </li> <li> <p> <code>TimerCanceled</code> – A timer, previously started for this workflow execution, was successfully canceled.</p> </li> <li> <p> <code>TimerFired</code> – A timer, previously started for this workflow execution, fired.</p> </li> <li> <p> <code>TimerStarted</code> – A timer was started for the workflow execution due to a <code>StartTimer</code> decision.</p> </li> <li> <p> <code>WorkflowExecutionCancelRequested</code> – A request to cancel this workflow execution was made.</p> </li> <li> <p> <code>WorkflowExecutionCanceled</code> – The workflow execution was successfully canceled and closed.</p> </li> <li> <p> <code>WorkflowExecutionCompleted</code> – The workflow execution was closed due to successful completion.</p> </li> <li> <p> <code>WorkflowExecutionContinuedAsNew</code> – The workflow execution was closed and a new execution of the same type was created with the same workflowId.</p> </li> <li> <p> <code>WorkflowExecutionFailed</code> – The workflow execution closed due to a failure.</p> </li> <li> <p> <code>WorkflowExecutionSignaled</code> – An external signal was received for the workflow execution.</p> </li> <li> <p> <code>WorkflowExecutionStarted</code> – The workflow execution was started.</p> </li> <li> <p> <code>WorkflowExecutionTerminated</code> – The workflow execution was terminated.</p> </li> <li> <p> <code>WorkflowExecutionTimedOut</code> – The workflow execution was closed because a time out was exceeded.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct HistoryEvent {
     /// <p>If the event is of type <code>ActivityTaskcancelRequested</code> then this member is set and provides detailed information about the event. It isn't set for other event types.</p>
@@ -1091,10 +2881,11 @@ pub struct HistoryEvent {
     pub event_id: i64,
     /// <p>The date and time when the event occurred.</p>
     #[serde(rename = "eventTimestamp")]
-    pub event_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub event_timestamp: EpochTimestamp,
     /// <p>The type of the history event.</p>
     #[serde(rename = "eventType")]
-    pub event_type: String,
+    pub event_type: EventType,
     /// <p>If the event is of type <code>ExternalWorkflowExecutionCancelRequested</code> then this member is set and provides detailed information about the event. It isn't set for other event types. </p>
     #[serde(rename = "externalWorkflowExecutionCancelRequestedEventAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1335,10 +3126,78 @@ pub struct LambdaFunctionTimedOutEventAttributes {
     /// <p>The type of the timeout that caused this event.</p>
     #[serde(rename = "timeoutType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timeout_type: Option<String>,
+    pub timeout_type: Option<LambdaFunctionTimeoutType>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p>The type of the timeout that caused this event.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LambdaFunctionTimeoutType {
+    /// <p><code>START_TO_CLOSE</code></p>
+    StartToClose,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LambdaFunctionTimeoutType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LambdaFunctionTimeoutType::StartToClose => "START_TO_CLOSE",
+            LambdaFunctionTimeoutType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LambdaFunctionTimeoutType {
+    fn from(value: &'a str) -> LambdaFunctionTimeoutType {
+        match value {
+            "START_TO_CLOSE" => LambdaFunctionTimeoutType::StartToClose,
+            _ => LambdaFunctionTimeoutType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LambdaFunctionTimeoutType {
+    fn from(value: String) -> LambdaFunctionTimeoutType {
+        LambdaFunctionTimeoutType::from(value.as_str())
+    }
+}
+
+impl From<LambdaFunctionTimeoutType> for String {
+    fn from(value: LambdaFunctionTimeoutType) -> String {
+        match value {
+            LambdaFunctionTimeoutType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LambdaFunctionTimeoutType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LambdaFunctionTimeoutType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LambdaFunctionTimeoutType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LambdaFunctionTimeoutType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LambdaFunctionTimeoutType::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListActivityTypesInput {
     /// <p>The name of the domain in which the activity types have been registered.</p>
     #[serde(rename = "domain")]
@@ -1357,13 +3216,85 @@ pub struct ListActivityTypesInput {
     pub next_page_token: Option<String>,
     /// <p>Specifies the registration status of the activity types to list.</p>
     #[serde(rename = "registrationStatus")]
-    pub registration_status: String,
+    pub registration_status: RegistrationStatus,
     /// <p>When set to <code>true</code>, returns the results in reverse order. By default, the results are returned in ascending alphabetical order by <code>name</code> of the activity types.</p>
     #[serde(rename = "reverseOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reverse_order: Option<bool>,
 }
 
+impl ListActivityTypesInput {
+    /// Returns a builder for `ListActivityTypesInput`, checking its required members when built.
+    pub fn builder() -> ListActivityTypesInputBuilder {
+        ListActivityTypesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListActivityTypesInput`](struct.ListActivityTypesInput.html).
+#[derive(Default, Debug)]
+pub struct ListActivityTypesInputBuilder {
+    domain: Option<String>,
+    maximum_page_size: Option<i64>,
+    name: Option<String>,
+    next_page_token: Option<String>,
+    registration_status: Option<RegistrationStatus>,
+    reverse_order: Option<bool>,
+}
+
+impl ListActivityTypesInputBuilder {
+    /// Sets the [`domain`](struct.ListActivityTypesInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maximum_page_size`](struct.ListActivityTypesInput.html#structfield.maximum_page_size) member.
+    pub fn maximum_page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.maximum_page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ListActivityTypesInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.ListActivityTypesInput.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registration_status`](struct.ListActivityTypesInput.html#structfield.registration_status) member.
+    pub fn registration_status<T: Into<RegistrationStatus>>(mut self, value: T) -> Self {
+        self.registration_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reverse_order`](struct.ListActivityTypesInput.html#structfield.reverse_order) member.
+    pub fn reverse_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reverse_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListActivityTypesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListActivityTypesInput, ::rusoto_core::BuildError> {
+        Ok(ListActivityTypesInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            maximum_page_size: self.maximum_page_size,
+            name: self.name,
+            next_page_token: self.next_page_token,
+            registration_status: self
+                .registration_status
+                .ok_or(::rusoto_core::BuildError::missing("registration_status"))?,
+            reverse_order: self.reverse_order,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListClosedWorkflowExecutionsInput {
     /// <p><p>If specified, only workflow executions that match this <i>close status</i> are listed. For example, if TERMINATED is specified, then only TERMINATED workflow executions are listed.</p> <note> <p> <code>closeStatusFilter</code>, <code>executionFilter</code>, <code>typeFilter</code> and <code>tagFilter</code> are mutually exclusive. You can specify at most one of these in a request.</p> </note></p>
@@ -1407,7 +3338,111 @@ pub struct ListClosedWorkflowExecutionsInput {
     pub type_filter: Option<WorkflowTypeFilter>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl ListClosedWorkflowExecutionsInput {
+    /// Returns a builder for `ListClosedWorkflowExecutionsInput`, checking its required members when built.
+    pub fn builder() -> ListClosedWorkflowExecutionsInputBuilder {
+        ListClosedWorkflowExecutionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListClosedWorkflowExecutionsInput`](struct.ListClosedWorkflowExecutionsInput.html).
+#[derive(Default, Debug)]
+pub struct ListClosedWorkflowExecutionsInputBuilder {
+    close_status_filter: Option<CloseStatusFilter>,
+    close_time_filter: Option<ExecutionTimeFilter>,
+    domain: Option<String>,
+    execution_filter: Option<WorkflowExecutionFilter>,
+    maximum_page_size: Option<i64>,
+    next_page_token: Option<String>,
+    reverse_order: Option<bool>,
+    start_time_filter: Option<ExecutionTimeFilter>,
+    tag_filter: Option<TagFilter>,
+    type_filter: Option<WorkflowTypeFilter>,
+}
+
+impl ListClosedWorkflowExecutionsInputBuilder {
+    /// Sets the [`close_status_filter`](struct.ListClosedWorkflowExecutionsInput.html#structfield.close_status_filter) member.
+    pub fn close_status_filter<T: Into<CloseStatusFilter>>(mut self, value: T) -> Self {
+        self.close_status_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`close_time_filter`](struct.ListClosedWorkflowExecutionsInput.html#structfield.close_time_filter) member.
+    pub fn close_time_filter<T: Into<ExecutionTimeFilter>>(mut self, value: T) -> Self {
+        self.close_time_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.ListClosedWorkflowExecutionsInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_filter`](struct.ListClosedWorkflowExecutionsInput.html#structfield.execution_filter) member.
+    pub fn execution_filter<T: Into<WorkflowExecutionFilter>>(mut self, value: T) -> Self {
+        self.execution_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maximum_page_size`](struct.ListClosedWorkflowExecutionsInput.html#structfield.maximum_page_size) member.
+    pub fn maximum_page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.maximum_page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.ListClosedWorkflowExecutionsInput.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reverse_order`](struct.ListClosedWorkflowExecutionsInput.html#structfield.reverse_order) member.
+    pub fn reverse_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reverse_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time_filter`](struct.ListClosedWorkflowExecutionsInput.html#structfield.start_time_filter) member.
+    pub fn start_time_filter<T: Into<ExecutionTimeFilter>>(mut self, value: T) -> Self {
+        self.start_time_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_filter`](struct.ListClosedWorkflowExecutionsInput.html#structfield.tag_filter) member.
+    pub fn tag_filter<T: Into<TagFilter>>(mut self, value: T) -> Self {
+        self.tag_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_filter`](struct.ListClosedWorkflowExecutionsInput.html#structfield.type_filter) member.
+    pub fn type_filter<T: Into<WorkflowTypeFilter>>(mut self, value: T) -> Self {
+        self.type_filter = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListClosedWorkflowExecutionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListClosedWorkflowExecutionsInput, ::rusoto_core::BuildError> {
+        Ok(ListClosedWorkflowExecutionsInput {
+            close_status_filter: self.close_status_filter,
+            close_time_filter: self.close_time_filter,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            execution_filter: self.execution_filter,
+            maximum_page_size: self.maximum_page_size,
+            next_page_token: self.next_page_token,
+            reverse_order: self.reverse_order,
+            start_time_filter: self.start_time_filter,
+            tag_filter: self.tag_filter,
+            type_filter: self.type_filter,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListDomainsInput {
     /// <p>The maximum number of results that are returned per call. Use <code>nextPageToken</code> to obtain further pages of results. </p>
     #[serde(rename = "maximumPageSize")]
@@ -1419,13 +3454,67 @@ pub struct ListDomainsInput {
     pub next_page_token: Option<String>,
     /// <p>Specifies the registration status of the domains to list.</p>
     #[serde(rename = "registrationStatus")]
-    pub registration_status: String,
+    pub registration_status: RegistrationStatus,
     /// <p>When set to <code>true</code>, returns the results in reverse order. By default, the results are returned in ascending alphabetical order by <code>name</code> of the domains.</p>
     #[serde(rename = "reverseOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reverse_order: Option<bool>,
 }
 
+impl ListDomainsInput {
+    /// Returns a builder for `ListDomainsInput`, checking its required members when built.
+    pub fn builder() -> ListDomainsInputBuilder {
+        ListDomainsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListDomainsInput`](struct.ListDomainsInput.html).
+#[derive(Default, Debug)]
+pub struct ListDomainsInputBuilder {
+    maximum_page_size: Option<i64>,
+    next_page_token: Option<String>,
+    registration_status: Option<RegistrationStatus>,
+    reverse_order: Option<bool>,
+}
+
+impl ListDomainsInputBuilder {
+    /// Sets the [`maximum_page_size`](struct.ListDomainsInput.html#structfield.maximum_page_size) member.
+    pub fn maximum_page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.maximum_page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.ListDomainsInput.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registration_status`](struct.ListDomainsInput.html#structfield.registration_status) member.
+    pub fn registration_status<T: Into<RegistrationStatus>>(mut self, value: T) -> Self {
+        self.registration_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reverse_order`](struct.ListDomainsInput.html#structfield.reverse_order) member.
+    pub fn reverse_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reverse_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDomainsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDomainsInput, ::rusoto_core::BuildError> {
+        Ok(ListDomainsInput {
+            maximum_page_size: self.maximum_page_size,
+            next_page_token: self.next_page_token,
+            registration_status: self
+                .registration_status
+                .ok_or(::rusoto_core::BuildError::missing("registration_status"))?,
+            reverse_order: self.reverse_order,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListOpenWorkflowExecutionsInput {
     /// <p>The name of the domain that contains the workflow executions to list.</p>
@@ -1460,23 +3549,145 @@ pub struct ListOpenWorkflowExecutionsInput {
     pub type_filter: Option<WorkflowTypeFilter>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListTagsForResourceInput {
-    /// <p>The Amazon Resource Name (ARN) for the Amazon SWF domain.</p>
-    #[serde(rename = "resourceArn")]
-    pub resource_arn: String,
+impl ListOpenWorkflowExecutionsInput {
+    /// Returns a builder for `ListOpenWorkflowExecutionsInput`, checking its required members when built.
+    pub fn builder() -> ListOpenWorkflowExecutionsInputBuilder {
+        ListOpenWorkflowExecutionsInputBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListTagsForResourceOutput {
-    /// <p>An array of tags associated with the domain.</p>
-    #[serde(rename = "tags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<ResourceTag>>,
+/// Builds a [`ListOpenWorkflowExecutionsInput`](struct.ListOpenWorkflowExecutionsInput.html).
+#[derive(Default, Debug)]
+pub struct ListOpenWorkflowExecutionsInputBuilder {
+    domain: Option<String>,
+    execution_filter: Option<WorkflowExecutionFilter>,
+    maximum_page_size: Option<i64>,
+    next_page_token: Option<String>,
+    reverse_order: Option<bool>,
+    start_time_filter: Option<ExecutionTimeFilter>,
+    tag_filter: Option<TagFilter>,
+    type_filter: Option<WorkflowTypeFilter>,
+}
+
+impl ListOpenWorkflowExecutionsInputBuilder {
+    /// Sets the [`domain`](struct.ListOpenWorkflowExecutionsInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_filter`](struct.ListOpenWorkflowExecutionsInput.html#structfield.execution_filter) member.
+    pub fn execution_filter<T: Into<WorkflowExecutionFilter>>(mut self, value: T) -> Self {
+        self.execution_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maximum_page_size`](struct.ListOpenWorkflowExecutionsInput.html#structfield.maximum_page_size) member.
+    pub fn maximum_page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.maximum_page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.ListOpenWorkflowExecutionsInput.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reverse_order`](struct.ListOpenWorkflowExecutionsInput.html#structfield.reverse_order) member.
+    pub fn reverse_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reverse_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time_filter`](struct.ListOpenWorkflowExecutionsInput.html#structfield.start_time_filter) member.
+    pub fn start_time_filter<T: Into<ExecutionTimeFilter>>(mut self, value: T) -> Self {
+        self.start_time_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_filter`](struct.ListOpenWorkflowExecutionsInput.html#structfield.tag_filter) member.
+    pub fn tag_filter<T: Into<TagFilter>>(mut self, value: T) -> Self {
+        self.tag_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_filter`](struct.ListOpenWorkflowExecutionsInput.html#structfield.type_filter) member.
+    pub fn type_filter<T: Into<WorkflowTypeFilter>>(mut self, value: T) -> Self {
+        self.type_filter = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOpenWorkflowExecutionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListOpenWorkflowExecutionsInput, ::rusoto_core::BuildError> {
+        Ok(ListOpenWorkflowExecutionsInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            execution_filter: self.execution_filter,
+            maximum_page_size: self.maximum_page_size,
+            next_page_token: self.next_page_token,
+            reverse_order: self.reverse_order,
+            start_time_filter: self
+                .start_time_filter
+                .ok_or(::rusoto_core::BuildError::missing("start_time_filter"))?,
+            tag_filter: self.tag_filter,
+            type_filter: self.type_filter,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListTagsForResourceInput {
+    /// <p>The Amazon Resource Name (ARN) for the Amazon SWF domain.</p>
+    #[serde(rename = "resourceArn")]
+    pub resource_arn: String,
+}
+
+impl ListTagsForResourceInput {
+    /// Returns a builder for `ListTagsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceInputBuilder {
+        ListTagsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceInput`](struct.ListTagsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceInputBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListTagsForResourceOutput {
+    /// <p>An array of tags associated with the domain.</p>
+    #[serde(rename = "tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<ResourceTag>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListWorkflowTypesInput {
     /// <p>The name of the domain in which the workflow types have been registered.</p>
     #[serde(rename = "domain")]
@@ -1495,13 +3706,85 @@ pub struct ListWorkflowTypesInput {
     pub next_page_token: Option<String>,
     /// <p>Specifies the registration status of the workflow types to list.</p>
     #[serde(rename = "registrationStatus")]
-    pub registration_status: String,
+    pub registration_status: RegistrationStatus,
     /// <p>When set to <code>true</code>, returns the results in reverse order. By default the results are returned in ascending alphabetical order of the <code>name</code> of the workflow types.</p>
     #[serde(rename = "reverseOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reverse_order: Option<bool>,
 }
 
+impl ListWorkflowTypesInput {
+    /// Returns a builder for `ListWorkflowTypesInput`, checking its required members when built.
+    pub fn builder() -> ListWorkflowTypesInputBuilder {
+        ListWorkflowTypesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListWorkflowTypesInput`](struct.ListWorkflowTypesInput.html).
+#[derive(Default, Debug)]
+pub struct ListWorkflowTypesInputBuilder {
+    domain: Option<String>,
+    maximum_page_size: Option<i64>,
+    name: Option<String>,
+    next_page_token: Option<String>,
+    registration_status: Option<RegistrationStatus>,
+    reverse_order: Option<bool>,
+}
+
+impl ListWorkflowTypesInputBuilder {
+    /// Sets the [`domain`](struct.ListWorkflowTypesInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maximum_page_size`](struct.ListWorkflowTypesInput.html#structfield.maximum_page_size) member.
+    pub fn maximum_page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.maximum_page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ListWorkflowTypesInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.ListWorkflowTypesInput.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registration_status`](struct.ListWorkflowTypesInput.html#structfield.registration_status) member.
+    pub fn registration_status<T: Into<RegistrationStatus>>(mut self, value: T) -> Self {
+        self.registration_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reverse_order`](struct.ListWorkflowTypesInput.html#structfield.reverse_order) member.
+    pub fn reverse_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reverse_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListWorkflowTypesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListWorkflowTypesInput, ::rusoto_core::BuildError> {
+        Ok(ListWorkflowTypesInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            maximum_page_size: self.maximum_page_size,
+            name: self.name,
+            next_page_token: self.next_page_token,
+            registration_status: self
+                .registration_status
+                .ok_or(::rusoto_core::BuildError::missing("registration_status"))?,
+            reverse_order: self.reverse_order,
+        })
+    }
+}
+
 /// <p>Provides the details of the <code>MarkerRecorded</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1545,6 +3828,56 @@ pub struct PollForActivityTaskInput {
     pub task_list: TaskList,
 }
 
+impl PollForActivityTaskInput {
+    /// Returns a builder for `PollForActivityTaskInput`, checking its required members when built.
+    pub fn builder() -> PollForActivityTaskInputBuilder {
+        PollForActivityTaskInputBuilder::default()
+    }
+}
+
+/// Builds a [`PollForActivityTaskInput`](struct.PollForActivityTaskInput.html).
+#[derive(Default, Debug)]
+pub struct PollForActivityTaskInputBuilder {
+    domain: Option<String>,
+    identity: Option<String>,
+    task_list: Option<TaskList>,
+}
+
+impl PollForActivityTaskInputBuilder {
+    /// Sets the [`domain`](struct.PollForActivityTaskInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity`](struct.PollForActivityTaskInput.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_list`](struct.PollForActivityTaskInput.html#structfield.task_list) member.
+    pub fn task_list<T: Into<TaskList>>(mut self, value: T) -> Self {
+        self.task_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `PollForActivityTaskInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PollForActivityTaskInput, ::rusoto_core::BuildError> {
+        Ok(PollForActivityTaskInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            identity: self.identity,
+            task_list: self
+                .task_list
+                .ok_or(::rusoto_core::BuildError::missing("task_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PollForDecisionTaskInput {
     /// <p>The name of the domain containing the task lists to poll.</p>
@@ -1571,6 +3904,80 @@ pub struct PollForDecisionTaskInput {
     pub task_list: TaskList,
 }
 
+impl PollForDecisionTaskInput {
+    /// Returns a builder for `PollForDecisionTaskInput`, checking its required members when built.
+    pub fn builder() -> PollForDecisionTaskInputBuilder {
+        PollForDecisionTaskInputBuilder::default()
+    }
+}
+
+/// Builds a [`PollForDecisionTaskInput`](struct.PollForDecisionTaskInput.html).
+#[derive(Default, Debug)]
+pub struct PollForDecisionTaskInputBuilder {
+    domain: Option<String>,
+    identity: Option<String>,
+    maximum_page_size: Option<i64>,
+    next_page_token: Option<String>,
+    reverse_order: Option<bool>,
+    task_list: Option<TaskList>,
+}
+
+impl PollForDecisionTaskInputBuilder {
+    /// Sets the [`domain`](struct.PollForDecisionTaskInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity`](struct.PollForDecisionTaskInput.html#structfield.identity) member.
+    pub fn identity<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maximum_page_size`](struct.PollForDecisionTaskInput.html#structfield.maximum_page_size) member.
+    pub fn maximum_page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.maximum_page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.PollForDecisionTaskInput.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reverse_order`](struct.PollForDecisionTaskInput.html#structfield.reverse_order) member.
+    pub fn reverse_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reverse_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_list`](struct.PollForDecisionTaskInput.html#structfield.task_list) member.
+    pub fn task_list<T: Into<TaskList>>(mut self, value: T) -> Self {
+        self.task_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `PollForDecisionTaskInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PollForDecisionTaskInput, ::rusoto_core::BuildError> {
+        Ok(PollForDecisionTaskInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            identity: self.identity,
+            maximum_page_size: self.maximum_page_size,
+            next_page_token: self.next_page_token,
+            reverse_order: self.reverse_order,
+            task_list: self
+                .task_list
+                .ok_or(::rusoto_core::BuildError::missing("task_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RecordActivityTaskHeartbeatInput {
     /// <p>If specified, contains details about the progress of the task.</p>
@@ -1582,6 +3989,46 @@ pub struct RecordActivityTaskHeartbeatInput {
     pub task_token: String,
 }
 
+impl RecordActivityTaskHeartbeatInput {
+    /// Returns a builder for `RecordActivityTaskHeartbeatInput`, checking its required members when built.
+    pub fn builder() -> RecordActivityTaskHeartbeatInputBuilder {
+        RecordActivityTaskHeartbeatInputBuilder::default()
+    }
+}
+
+/// Builds a [`RecordActivityTaskHeartbeatInput`](struct.RecordActivityTaskHeartbeatInput.html).
+#[derive(Default, Debug)]
+pub struct RecordActivityTaskHeartbeatInputBuilder {
+    details: Option<String>,
+    task_token: Option<String>,
+}
+
+impl RecordActivityTaskHeartbeatInputBuilder {
+    /// Sets the [`details`](struct.RecordActivityTaskHeartbeatInput.html#structfield.details) member.
+    pub fn details<T: Into<String>>(mut self, value: T) -> Self {
+        self.details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_token`](struct.RecordActivityTaskHeartbeatInput.html#structfield.task_token) member.
+    pub fn task_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `RecordActivityTaskHeartbeatInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RecordActivityTaskHeartbeatInput, ::rusoto_core::BuildError> {
+        Ok(RecordActivityTaskHeartbeatInput {
+            details: self.details,
+            task_token: self
+                .task_token
+                .ok_or(::rusoto_core::BuildError::missing("task_token"))?,
+        })
+    }
+}
+
 /// <p>Provides the details of the <code>RecordMarker</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RecordMarkerDecisionAttributes {
@@ -1594,13 +4041,81 @@ pub struct RecordMarkerDecisionAttributes {
     pub marker_name: String,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordMarkerFailedCause {
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RecordMarkerFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordMarkerFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            RecordMarkerFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RecordMarkerFailedCause {
+    fn from(value: &'a str) -> RecordMarkerFailedCause {
+        match value {
+            "OPERATION_NOT_PERMITTED" => RecordMarkerFailedCause::OperationNotPermitted,
+            _ => RecordMarkerFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordMarkerFailedCause {
+    fn from(value: String) -> RecordMarkerFailedCause {
+        RecordMarkerFailedCause::from(value.as_str())
+    }
+}
+
+impl From<RecordMarkerFailedCause> for String {
+    fn from(value: RecordMarkerFailedCause) -> String {
+        match value {
+            RecordMarkerFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RecordMarkerFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RecordMarkerFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordMarkerFailedCause {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RecordMarkerFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RecordMarkerFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>RecordMarkerFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RecordMarkerFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: RecordMarkerFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>RecordMarkerFailed</code> decision for this cancellation request. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -1650,6 +4165,114 @@ pub struct RegisterActivityTypeInput {
     pub version: String,
 }
 
+impl RegisterActivityTypeInput {
+    /// Returns a builder for `RegisterActivityTypeInput`, checking its required members when built.
+    pub fn builder() -> RegisterActivityTypeInputBuilder {
+        RegisterActivityTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterActivityTypeInput`](struct.RegisterActivityTypeInput.html).
+#[derive(Default, Debug)]
+pub struct RegisterActivityTypeInputBuilder {
+    default_task_heartbeat_timeout: Option<String>,
+    default_task_list: Option<TaskList>,
+    default_task_priority: Option<String>,
+    default_task_schedule_to_close_timeout: Option<String>,
+    default_task_schedule_to_start_timeout: Option<String>,
+    default_task_start_to_close_timeout: Option<String>,
+    description: Option<String>,
+    domain: Option<String>,
+    name: Option<String>,
+    version: Option<String>,
+}
+
+impl RegisterActivityTypeInputBuilder {
+    /// Sets the [`default_task_heartbeat_timeout`](struct.RegisterActivityTypeInput.html#structfield.default_task_heartbeat_timeout) member.
+    pub fn default_task_heartbeat_timeout<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_task_heartbeat_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_task_list`](struct.RegisterActivityTypeInput.html#structfield.default_task_list) member.
+    pub fn default_task_list<T: Into<TaskList>>(mut self, value: T) -> Self {
+        self.default_task_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_task_priority`](struct.RegisterActivityTypeInput.html#structfield.default_task_priority) member.
+    pub fn default_task_priority<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_task_priority = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_task_schedule_to_close_timeout`](struct.RegisterActivityTypeInput.html#structfield.default_task_schedule_to_close_timeout) member.
+    pub fn default_task_schedule_to_close_timeout<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_task_schedule_to_close_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_task_schedule_to_start_timeout`](struct.RegisterActivityTypeInput.html#structfield.default_task_schedule_to_start_timeout) member.
+    pub fn default_task_schedule_to_start_timeout<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_task_schedule_to_start_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_task_start_to_close_timeout`](struct.RegisterActivityTypeInput.html#structfield.default_task_start_to_close_timeout) member.
+    pub fn default_task_start_to_close_timeout<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_task_start_to_close_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.RegisterActivityTypeInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.RegisterActivityTypeInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.RegisterActivityTypeInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.RegisterActivityTypeInput.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterActivityTypeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterActivityTypeInput, ::rusoto_core::BuildError> {
+        Ok(RegisterActivityTypeInput {
+            default_task_heartbeat_timeout: self.default_task_heartbeat_timeout,
+            default_task_list: self.default_task_list,
+            default_task_priority: self.default_task_priority,
+            default_task_schedule_to_close_timeout: self.default_task_schedule_to_close_timeout,
+            default_task_schedule_to_start_timeout: self.default_task_schedule_to_start_timeout,
+            default_task_start_to_close_timeout: self.default_task_start_to_close_timeout,
+            description: self.description,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RegisterDomainInput {
     /// <p>A text description of the domain.</p>
@@ -1668,12 +4291,73 @@ pub struct RegisterDomainInput {
     pub workflow_execution_retention_period_in_days: String,
 }
 
+impl RegisterDomainInput {
+    /// Returns a builder for `RegisterDomainInput`, checking its required members when built.
+    pub fn builder() -> RegisterDomainInputBuilder {
+        RegisterDomainInputBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterDomainInput`](struct.RegisterDomainInput.html).
+#[derive(Default, Debug)]
+pub struct RegisterDomainInputBuilder {
+    description: Option<String>,
+    name: Option<String>,
+    tags: Option<Vec<ResourceTag>>,
+    workflow_execution_retention_period_in_days: Option<String>,
+}
+
+impl RegisterDomainInputBuilder {
+    /// Sets the [`description`](struct.RegisterDomainInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.RegisterDomainInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RegisterDomainInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<ResourceTag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workflow_execution_retention_period_in_days`](struct.RegisterDomainInput.html#structfield.workflow_execution_retention_period_in_days) member.
+    pub fn workflow_execution_retention_period_in_days<T: Into<String>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.workflow_execution_retention_period_in_days = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterDomainInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RegisterDomainInput, ::rusoto_core::BuildError> {
+        Ok(RegisterDomainInput {
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+            workflow_execution_retention_period_in_days: self
+                .workflow_execution_retention_period_in_days
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "workflow_execution_retention_period_in_days",
+                ))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RegisterWorkflowTypeInput {
     /// <p><p>If set, specifies the default policy to use for the child workflow executions when a workflow execution of this type is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This default can be overridden when starting a workflow execution using the <a>StartWorkflowExecution</a> action or the <code>StartChildWorkflowExecution</code> <a>Decision</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "defaultChildPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_child_policy: Option<String>,
+    pub default_child_policy: Option<ChildPolicy>,
     /// <p>If set, specifies the default maximum duration for executions of this workflow type. You can override this default when starting an execution through the <a>StartWorkflowExecution</a> Action or <code>StartChildWorkflowExecution</code> <a>Decision</a>.</p> <p>The duration is specified in seconds; an integer greater than or equal to 0. Unlike some of the other timeout parameters in Amazon SWF, you cannot specify a value of "NONE" for <code>defaultExecutionStartToCloseTimeout</code>; there is a one-year max limit on the time that a workflow execution can run. Exceeding this limit always causes the workflow execution to time out.</p>
     #[serde(rename = "defaultExecutionStartToCloseTimeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1709,6 +4393,186 @@ pub struct RegisterWorkflowTypeInput {
     pub version: String,
 }
 
+impl RegisterWorkflowTypeInput {
+    /// Returns a builder for `RegisterWorkflowTypeInput`, checking its required members when built.
+    pub fn builder() -> RegisterWorkflowTypeInputBuilder {
+        RegisterWorkflowTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterWorkflowTypeInput`](struct.RegisterWorkflowTypeInput.html).
+#[derive(Default, Debug)]
+pub struct RegisterWorkflowTypeInputBuilder {
+    default_child_policy: Option<ChildPolicy>,
+    default_execution_start_to_close_timeout: Option<String>,
+    default_lambda_role: Option<String>,
+    default_task_list: Option<TaskList>,
+    default_task_priority: Option<String>,
+    default_task_start_to_close_timeout: Option<String>,
+    description: Option<String>,
+    domain: Option<String>,
+    name: Option<String>,
+    version: Option<String>,
+}
+
+impl RegisterWorkflowTypeInputBuilder {
+    /// Sets the [`default_child_policy`](struct.RegisterWorkflowTypeInput.html#structfield.default_child_policy) member.
+    pub fn default_child_policy<T: Into<ChildPolicy>>(mut self, value: T) -> Self {
+        self.default_child_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_execution_start_to_close_timeout`](struct.RegisterWorkflowTypeInput.html#structfield.default_execution_start_to_close_timeout) member.
+    pub fn default_execution_start_to_close_timeout<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_execution_start_to_close_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_lambda_role`](struct.RegisterWorkflowTypeInput.html#structfield.default_lambda_role) member.
+    pub fn default_lambda_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_lambda_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_task_list`](struct.RegisterWorkflowTypeInput.html#structfield.default_task_list) member.
+    pub fn default_task_list<T: Into<TaskList>>(mut self, value: T) -> Self {
+        self.default_task_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_task_priority`](struct.RegisterWorkflowTypeInput.html#structfield.default_task_priority) member.
+    pub fn default_task_priority<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_task_priority = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_task_start_to_close_timeout`](struct.RegisterWorkflowTypeInput.html#structfield.default_task_start_to_close_timeout) member.
+    pub fn default_task_start_to_close_timeout<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_task_start_to_close_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.RegisterWorkflowTypeInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.RegisterWorkflowTypeInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.RegisterWorkflowTypeInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.RegisterWorkflowTypeInput.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterWorkflowTypeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterWorkflowTypeInput, ::rusoto_core::BuildError> {
+        Ok(RegisterWorkflowTypeInput {
+            default_child_policy: self.default_child_policy,
+            default_execution_start_to_close_timeout: self.default_execution_start_to_close_timeout,
+            default_lambda_role: self.default_lambda_role,
+            default_task_list: self.default_task_list,
+            default_task_priority: self.default_task_priority,
+            default_task_start_to_close_timeout: self.default_task_start_to_close_timeout,
+            description: self.description,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
+/// <p>The current status of the activity type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RegistrationStatus {
+    /// <p><code>REGISTERED</code></p>
+    Registered,
+    /// <p><code>DEPRECATED</code></p>
+    Deprecated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RegistrationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RegistrationStatus::Registered => "REGISTERED",
+            RegistrationStatus::Deprecated => "DEPRECATED",
+            RegistrationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RegistrationStatus {
+    fn from(value: &'a str) -> RegistrationStatus {
+        match value {
+            "REGISTERED" => RegistrationStatus::Registered,
+            "DEPRECATED" => RegistrationStatus::Deprecated,
+            _ => RegistrationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RegistrationStatus {
+    fn from(value: String) -> RegistrationStatus {
+        RegistrationStatus::from(value.as_str())
+    }
+}
+
+impl From<RegistrationStatus> for String {
+    fn from(value: RegistrationStatus) -> String {
+        match value {
+            RegistrationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RegistrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RegistrationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RegistrationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RegistrationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RegistrationStatus::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>RequestCancelActivityTask</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RequestCancelActivityTaskDecisionAttributes {
@@ -1717,8 +4581,86 @@ pub struct RequestCancelActivityTaskDecisionAttributes {
     pub activity_id: String,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RequestCancelActivityTaskFailedCause {
+    /// <p><code>ACTIVITY_ID_UNKNOWN</code></p>
+    ActivityIdUnknown,
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RequestCancelActivityTaskFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequestCancelActivityTaskFailedCause::ActivityIdUnknown => "ACTIVITY_ID_UNKNOWN",
+            RequestCancelActivityTaskFailedCause::OperationNotPermitted => {
+                "OPERATION_NOT_PERMITTED"
+            }
+            RequestCancelActivityTaskFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RequestCancelActivityTaskFailedCause {
+    fn from(value: &'a str) -> RequestCancelActivityTaskFailedCause {
+        match value {
+            "ACTIVITY_ID_UNKNOWN" => RequestCancelActivityTaskFailedCause::ActivityIdUnknown,
+            "OPERATION_NOT_PERMITTED" => {
+                RequestCancelActivityTaskFailedCause::OperationNotPermitted
+            }
+            _ => RequestCancelActivityTaskFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequestCancelActivityTaskFailedCause {
+    fn from(value: String) -> RequestCancelActivityTaskFailedCause {
+        RequestCancelActivityTaskFailedCause::from(value.as_str())
+    }
+}
+
+impl From<RequestCancelActivityTaskFailedCause> for String {
+    fn from(value: RequestCancelActivityTaskFailedCause) -> String {
+        match value {
+            RequestCancelActivityTaskFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RequestCancelActivityTaskFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RequestCancelActivityTaskFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RequestCancelActivityTaskFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<RequestCancelActivityTaskFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RequestCancelActivityTaskFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>RequestCancelActivityTaskFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RequestCancelActivityTaskFailedEventAttributes {
     /// <p>The activityId provided in the <code>RequestCancelActivityTask</code> decision that failed.</p>
@@ -1726,7 +4668,7 @@ pub struct RequestCancelActivityTaskFailedEventAttributes {
     pub activity_id: String,
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: RequestCancelActivityTaskFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>RequestCancelActivityTask</code> decision for this cancellation request. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -1748,13 +4690,93 @@ pub struct RequestCancelExternalWorkflowExecutionDecisionAttributes {
     pub workflow_id: String,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RequestCancelExternalWorkflowExecutionFailedCause {
+    /// <p><code>UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION</code></p>
+    UnknownExternalWorkflowExecution,
+    /// <p><code>REQUEST_CANCEL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED</code></p>
+    RequestCancelExternalWorkflowExecutionRateExceeded,
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RequestCancelExternalWorkflowExecutionFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequestCancelExternalWorkflowExecutionFailedCause::UnknownExternalWorkflowExecution => "UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION",
+            RequestCancelExternalWorkflowExecutionFailedCause::RequestCancelExternalWorkflowExecutionRateExceeded => "REQUEST_CANCEL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED",
+            RequestCancelExternalWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            RequestCancelExternalWorkflowExecutionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> RequestCancelExternalWorkflowExecutionFailedCause {
+        match value {
+            "UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION" => RequestCancelExternalWorkflowExecutionFailedCause::UnknownExternalWorkflowExecution,
+            "REQUEST_CANCEL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED" => RequestCancelExternalWorkflowExecutionFailedCause::RequestCancelExternalWorkflowExecutionRateExceeded,
+            "OPERATION_NOT_PERMITTED" => RequestCancelExternalWorkflowExecutionFailedCause::OperationNotPermitted,
+            _ => RequestCancelExternalWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn from(value: String) -> RequestCancelExternalWorkflowExecutionFailedCause {
+        RequestCancelExternalWorkflowExecutionFailedCause::from(value.as_str())
+    }
+}
+
+impl From<RequestCancelExternalWorkflowExecutionFailedCause> for String {
+    fn from(value: RequestCancelExternalWorkflowExecutionFailedCause) -> String {
+        match value {
+            RequestCancelExternalWorkflowExecutionFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RequestCancelExternalWorkflowExecutionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<RequestCancelExternalWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RequestCancelExternalWorkflowExecutionFailedCause::from(
+            value,
+        ))
+    }
+}
+
 /// <p>Provides the details of the <code>RequestCancelExternalWorkflowExecutionFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RequestCancelExternalWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: RequestCancelExternalWorkflowExecutionFailedCause,
     /// <p>The data attached to the event that the decider can use in subsequent workflow tasks. This data isn't sent to the workflow execution.</p>
     #[serde(rename = "control")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1808,6 +4830,56 @@ pub struct RequestCancelWorkflowExecutionInput {
     pub workflow_id: String,
 }
 
+impl RequestCancelWorkflowExecutionInput {
+    /// Returns a builder for `RequestCancelWorkflowExecutionInput`, checking its required members when built.
+    pub fn builder() -> RequestCancelWorkflowExecutionInputBuilder {
+        RequestCancelWorkflowExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`RequestCancelWorkflowExecutionInput`](struct.RequestCancelWorkflowExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct RequestCancelWorkflowExecutionInputBuilder {
+    domain: Option<String>,
+    run_id: Option<String>,
+    workflow_id: Option<String>,
+}
+
+impl RequestCancelWorkflowExecutionInputBuilder {
+    /// Sets the [`domain`](struct.RequestCancelWorkflowExecutionInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`run_id`](struct.RequestCancelWorkflowExecutionInput.html#structfield.run_id) member.
+    pub fn run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.run_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workflow_id`](struct.RequestCancelWorkflowExecutionInput.html#structfield.workflow_id) member.
+    pub fn workflow_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.workflow_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RequestCancelWorkflowExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RequestCancelWorkflowExecutionInput, ::rusoto_core::BuildError> {
+        Ok(RequestCancelWorkflowExecutionInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            run_id: self.run_id,
+            workflow_id: self
+                .workflow_id
+                .ok_or(::rusoto_core::BuildError::missing("workflow_id"))?,
+        })
+    }
+}
+
 /// <p>Tags are key-value pairs that can be associated with Amazon SWF state machines and activities.</p> <p>Tags may only contain unicode letters, digits, whitespace, or these symbols: <code>_ . : / = + - @</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceTag {
@@ -1831,6 +4903,46 @@ pub struct RespondActivityTaskCanceledInput {
     pub task_token: String,
 }
 
+impl RespondActivityTaskCanceledInput {
+    /// Returns a builder for `RespondActivityTaskCanceledInput`, checking its required members when built.
+    pub fn builder() -> RespondActivityTaskCanceledInputBuilder {
+        RespondActivityTaskCanceledInputBuilder::default()
+    }
+}
+
+/// Builds a [`RespondActivityTaskCanceledInput`](struct.RespondActivityTaskCanceledInput.html).
+#[derive(Default, Debug)]
+pub struct RespondActivityTaskCanceledInputBuilder {
+    details: Option<String>,
+    task_token: Option<String>,
+}
+
+impl RespondActivityTaskCanceledInputBuilder {
+    /// Sets the [`details`](struct.RespondActivityTaskCanceledInput.html#structfield.details) member.
+    pub fn details<T: Into<String>>(mut self, value: T) -> Self {
+        self.details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_token`](struct.RespondActivityTaskCanceledInput.html#structfield.task_token) member.
+    pub fn task_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `RespondActivityTaskCanceledInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RespondActivityTaskCanceledInput, ::rusoto_core::BuildError> {
+        Ok(RespondActivityTaskCanceledInput {
+            details: self.details,
+            task_token: self
+                .task_token
+                .ok_or(::rusoto_core::BuildError::missing("task_token"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RespondActivityTaskCompletedInput {
     /// <p>The result of the activity task. It is a free form string that is implementation specific.</p>
@@ -1842,6 +4954,46 @@ pub struct RespondActivityTaskCompletedInput {
     pub task_token: String,
 }
 
+impl RespondActivityTaskCompletedInput {
+    /// Returns a builder for `RespondActivityTaskCompletedInput`, checking its required members when built.
+    pub fn builder() -> RespondActivityTaskCompletedInputBuilder {
+        RespondActivityTaskCompletedInputBuilder::default()
+    }
+}
+
+/// Builds a [`RespondActivityTaskCompletedInput`](struct.RespondActivityTaskCompletedInput.html).
+#[derive(Default, Debug)]
+pub struct RespondActivityTaskCompletedInputBuilder {
+    result: Option<String>,
+    task_token: Option<String>,
+}
+
+impl RespondActivityTaskCompletedInputBuilder {
+    /// Sets the [`result`](struct.RespondActivityTaskCompletedInput.html#structfield.result) member.
+    pub fn result<T: Into<String>>(mut self, value: T) -> Self {
+        self.result = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_token`](struct.RespondActivityTaskCompletedInput.html#structfield.task_token) member.
+    pub fn task_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `RespondActivityTaskCompletedInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RespondActivityTaskCompletedInput, ::rusoto_core::BuildError> {
+        Ok(RespondActivityTaskCompletedInput {
+            result: self.result,
+            task_token: self
+                .task_token
+                .ok_or(::rusoto_core::BuildError::missing("task_token"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RespondActivityTaskFailedInput {
     /// <p> Detailed information about the failure.</p>
@@ -1857,6 +5009,54 @@ pub struct RespondActivityTaskFailedInput {
     pub task_token: String,
 }
 
+impl RespondActivityTaskFailedInput {
+    /// Returns a builder for `RespondActivityTaskFailedInput`, checking its required members when built.
+    pub fn builder() -> RespondActivityTaskFailedInputBuilder {
+        RespondActivityTaskFailedInputBuilder::default()
+    }
+}
+
+/// Builds a [`RespondActivityTaskFailedInput`](struct.RespondActivityTaskFailedInput.html).
+#[derive(Default, Debug)]
+pub struct RespondActivityTaskFailedInputBuilder {
+    details: Option<String>,
+    reason: Option<String>,
+    task_token: Option<String>,
+}
+
+impl RespondActivityTaskFailedInputBuilder {
+    /// Sets the [`details`](struct.RespondActivityTaskFailedInput.html#structfield.details) member.
+    pub fn details<T: Into<String>>(mut self, value: T) -> Self {
+        self.details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason`](struct.RespondActivityTaskFailedInput.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_token`](struct.RespondActivityTaskFailedInput.html#structfield.task_token) member.
+    pub fn task_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `RespondActivityTaskFailedInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RespondActivityTaskFailedInput, ::rusoto_core::BuildError> {
+        Ok(RespondActivityTaskFailedInput {
+            details: self.details,
+            reason: self.reason,
+            task_token: self
+                .task_token
+                .ok_or(::rusoto_core::BuildError::missing("task_token"))?,
+        })
+    }
+}
+
 /// <p>Input data for a TaskCompleted response to a decision task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RespondDecisionTaskCompletedInput {
@@ -1873,6 +5073,54 @@ pub struct RespondDecisionTaskCompletedInput {
     pub task_token: String,
 }
 
+impl RespondDecisionTaskCompletedInput {
+    /// Returns a builder for `RespondDecisionTaskCompletedInput`, checking its required members when built.
+    pub fn builder() -> RespondDecisionTaskCompletedInputBuilder {
+        RespondDecisionTaskCompletedInputBuilder::default()
+    }
+}
+
+/// Builds a [`RespondDecisionTaskCompletedInput`](struct.RespondDecisionTaskCompletedInput.html).
+#[derive(Default, Debug)]
+pub struct RespondDecisionTaskCompletedInputBuilder {
+    decisions: Option<Vec<Decision>>,
+    execution_context: Option<String>,
+    task_token: Option<String>,
+}
+
+impl RespondDecisionTaskCompletedInputBuilder {
+    /// Sets the [`decisions`](struct.RespondDecisionTaskCompletedInput.html#structfield.decisions) member.
+    pub fn decisions<T: Into<Vec<Decision>>>(mut self, value: T) -> Self {
+        self.decisions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_context`](struct.RespondDecisionTaskCompletedInput.html#structfield.execution_context) member.
+    pub fn execution_context<T: Into<String>>(mut self, value: T) -> Self {
+        self.execution_context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_token`](struct.RespondDecisionTaskCompletedInput.html#structfield.task_token) member.
+    pub fn task_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `RespondDecisionTaskCompletedInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RespondDecisionTaskCompletedInput, ::rusoto_core::BuildError> {
+        Ok(RespondDecisionTaskCompletedInput {
+            decisions: self.decisions,
+            execution_context: self.execution_context,
+            task_token: self
+                .task_token
+                .ok_or(::rusoto_core::BuildError::missing("task_token"))?,
+        })
+    }
+}
+
 /// <p>Specifies the <code>runId</code> of a workflow execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1926,8 +5174,150 @@ pub struct ScheduleActivityTaskDecisionAttributes {
     pub task_priority: Option<String>,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScheduleActivityTaskFailedCause {
+    /// <p><code>ACTIVITY_TYPE_DEPRECATED</code></p>
+    ActivityTypeDeprecated,
+    /// <p><code>ACTIVITY_TYPE_DOES_NOT_EXIST</code></p>
+    ActivityTypeDoesNotExist,
+    /// <p><code>ACTIVITY_ID_ALREADY_IN_USE</code></p>
+    ActivityIdAlreadyInUse,
+    /// <p><code>OPEN_ACTIVITIES_LIMIT_EXCEEDED</code></p>
+    OpenActivitiesLimitExceeded,
+    /// <p><code>ACTIVITY_CREATION_RATE_EXCEEDED</code></p>
+    ActivityCreationRateExceeded,
+    /// <p><code>DEFAULT_SCHEDULE_TO_CLOSE_TIMEOUT_UNDEFINED</code></p>
+    DefaultScheduleToCloseTimeoutUndefined,
+    /// <p><code>DEFAULT_TASK_LIST_UNDEFINED</code></p>
+    DefaultTaskListUndefined,
+    /// <p><code>DEFAULT_SCHEDULE_TO_START_TIMEOUT_UNDEFINED</code></p>
+    DefaultScheduleToStartTimeoutUndefined,
+    /// <p><code>DEFAULT_START_TO_CLOSE_TIMEOUT_UNDEFINED</code></p>
+    DefaultStartToCloseTimeoutUndefined,
+    /// <p><code>DEFAULT_HEARTBEAT_TIMEOUT_UNDEFINED</code></p>
+    DefaultHeartbeatTimeoutUndefined,
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScheduleActivityTaskFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScheduleActivityTaskFailedCause::ActivityTypeDeprecated => "ACTIVITY_TYPE_DEPRECATED",
+            ScheduleActivityTaskFailedCause::ActivityTypeDoesNotExist => {
+                "ACTIVITY_TYPE_DOES_NOT_EXIST"
+            }
+            ScheduleActivityTaskFailedCause::ActivityIdAlreadyInUse => "ACTIVITY_ID_ALREADY_IN_USE",
+            ScheduleActivityTaskFailedCause::OpenActivitiesLimitExceeded => {
+                "OPEN_ACTIVITIES_LIMIT_EXCEEDED"
+            }
+            ScheduleActivityTaskFailedCause::ActivityCreationRateExceeded => {
+                "ACTIVITY_CREATION_RATE_EXCEEDED"
+            }
+            ScheduleActivityTaskFailedCause::DefaultScheduleToCloseTimeoutUndefined => {
+                "DEFAULT_SCHEDULE_TO_CLOSE_TIMEOUT_UNDEFINED"
+            }
+            ScheduleActivityTaskFailedCause::DefaultTaskListUndefined => {
+                "DEFAULT_TASK_LIST_UNDEFINED"
+            }
+            ScheduleActivityTaskFailedCause::DefaultScheduleToStartTimeoutUndefined => {
+                "DEFAULT_SCHEDULE_TO_START_TIMEOUT_UNDEFINED"
+            }
+            ScheduleActivityTaskFailedCause::DefaultStartToCloseTimeoutUndefined => {
+                "DEFAULT_START_TO_CLOSE_TIMEOUT_UNDEFINED"
+            }
+            ScheduleActivityTaskFailedCause::DefaultHeartbeatTimeoutUndefined => {
+                "DEFAULT_HEARTBEAT_TIMEOUT_UNDEFINED"
+            }
+            ScheduleActivityTaskFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            ScheduleActivityTaskFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScheduleActivityTaskFailedCause {
+    fn from(value: &'a str) -> ScheduleActivityTaskFailedCause {
+        match value {
+            "ACTIVITY_TYPE_DEPRECATED" => ScheduleActivityTaskFailedCause::ActivityTypeDeprecated,
+            "ACTIVITY_TYPE_DOES_NOT_EXIST" => {
+                ScheduleActivityTaskFailedCause::ActivityTypeDoesNotExist
+            }
+            "ACTIVITY_ID_ALREADY_IN_USE" => ScheduleActivityTaskFailedCause::ActivityIdAlreadyInUse,
+            "OPEN_ACTIVITIES_LIMIT_EXCEEDED" => {
+                ScheduleActivityTaskFailedCause::OpenActivitiesLimitExceeded
+            }
+            "ACTIVITY_CREATION_RATE_EXCEEDED" => {
+                ScheduleActivityTaskFailedCause::ActivityCreationRateExceeded
+            }
+            "DEFAULT_SCHEDULE_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultScheduleToCloseTimeoutUndefined
+            }
+            "DEFAULT_TASK_LIST_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultTaskListUndefined
+            }
+            "DEFAULT_SCHEDULE_TO_START_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultScheduleToStartTimeoutUndefined
+            }
+            "DEFAULT_START_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultStartToCloseTimeoutUndefined
+            }
+            "DEFAULT_HEARTBEAT_TIMEOUT_UNDEFINED" => {
+                ScheduleActivityTaskFailedCause::DefaultHeartbeatTimeoutUndefined
+            }
+            "OPERATION_NOT_PERMITTED" => ScheduleActivityTaskFailedCause::OperationNotPermitted,
+            _ => ScheduleActivityTaskFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScheduleActivityTaskFailedCause {
+    fn from(value: String) -> ScheduleActivityTaskFailedCause {
+        ScheduleActivityTaskFailedCause::from(value.as_str())
+    }
+}
+
+impl From<ScheduleActivityTaskFailedCause> for String {
+    fn from(value: ScheduleActivityTaskFailedCause) -> String {
+        match value {
+            ScheduleActivityTaskFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScheduleActivityTaskFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScheduleActivityTaskFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScheduleActivityTaskFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ScheduleActivityTaskFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScheduleActivityTaskFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>ScheduleActivityTaskFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ScheduleActivityTaskFailedEventAttributes {
     /// <p>The activityId provided in the <code>ScheduleActivityTask</code> decision that failed.</p>
@@ -1938,7 +5328,7 @@ pub struct ScheduleActivityTaskFailedEventAttributes {
     pub activity_type: ActivityType,
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: ScheduleActivityTaskFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision that resulted in the scheduling of this activity task. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -1967,13 +5357,107 @@ pub struct ScheduleLambdaFunctionDecisionAttributes {
     pub start_to_close_timeout: Option<String>,
 }
 
+/// <p><p>The cause of the failure. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScheduleLambdaFunctionFailedCause {
+    /// <p><code>ID_ALREADY_IN_USE</code></p>
+    IdAlreadyInUse,
+    /// <p><code>OPEN_LAMBDA_FUNCTIONS_LIMIT_EXCEEDED</code></p>
+    OpenLambdaFunctionsLimitExceeded,
+    /// <p><code>LAMBDA_FUNCTION_CREATION_RATE_EXCEEDED</code></p>
+    LambdaFunctionCreationRateExceeded,
+    /// <p><code>LAMBDA_SERVICE_NOT_AVAILABLE_IN_REGION</code></p>
+    LambdaServiceNotAvailableInRegion,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScheduleLambdaFunctionFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScheduleLambdaFunctionFailedCause::IdAlreadyInUse => "ID_ALREADY_IN_USE",
+            ScheduleLambdaFunctionFailedCause::OpenLambdaFunctionsLimitExceeded => {
+                "OPEN_LAMBDA_FUNCTIONS_LIMIT_EXCEEDED"
+            }
+            ScheduleLambdaFunctionFailedCause::LambdaFunctionCreationRateExceeded => {
+                "LAMBDA_FUNCTION_CREATION_RATE_EXCEEDED"
+            }
+            ScheduleLambdaFunctionFailedCause::LambdaServiceNotAvailableInRegion => {
+                "LAMBDA_SERVICE_NOT_AVAILABLE_IN_REGION"
+            }
+            ScheduleLambdaFunctionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScheduleLambdaFunctionFailedCause {
+    fn from(value: &'a str) -> ScheduleLambdaFunctionFailedCause {
+        match value {
+            "ID_ALREADY_IN_USE" => ScheduleLambdaFunctionFailedCause::IdAlreadyInUse,
+            "OPEN_LAMBDA_FUNCTIONS_LIMIT_EXCEEDED" => {
+                ScheduleLambdaFunctionFailedCause::OpenLambdaFunctionsLimitExceeded
+            }
+            "LAMBDA_FUNCTION_CREATION_RATE_EXCEEDED" => {
+                ScheduleLambdaFunctionFailedCause::LambdaFunctionCreationRateExceeded
+            }
+            "LAMBDA_SERVICE_NOT_AVAILABLE_IN_REGION" => {
+                ScheduleLambdaFunctionFailedCause::LambdaServiceNotAvailableInRegion
+            }
+            _ => ScheduleLambdaFunctionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScheduleLambdaFunctionFailedCause {
+    fn from(value: String) -> ScheduleLambdaFunctionFailedCause {
+        ScheduleLambdaFunctionFailedCause::from(value.as_str())
+    }
+}
+
+impl From<ScheduleLambdaFunctionFailedCause> for String {
+    fn from(value: ScheduleLambdaFunctionFailedCause) -> String {
+        match value {
+            ScheduleLambdaFunctionFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScheduleLambdaFunctionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScheduleLambdaFunctionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScheduleLambdaFunctionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ScheduleLambdaFunctionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScheduleLambdaFunctionFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>ScheduleLambdaFunctionFailed</code> event. It isn't set for other event types.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ScheduleLambdaFunctionFailedEventAttributes {
     /// <p><p>The cause of the failure. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: ScheduleLambdaFunctionFailedCause,
     /// <p>The ID of the <code>LambdaFunctionCompleted</code> event corresponding to the decision that resulted in scheduling this Lambda task. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -2008,13 +5492,91 @@ pub struct SignalExternalWorkflowExecutionDecisionAttributes {
     pub workflow_id: String,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SignalExternalWorkflowExecutionFailedCause {
+    /// <p><code>UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION</code></p>
+    UnknownExternalWorkflowExecution,
+    /// <p><code>SIGNAL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED</code></p>
+    SignalExternalWorkflowExecutionRateExceeded,
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SignalExternalWorkflowExecutionFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SignalExternalWorkflowExecutionFailedCause::UnknownExternalWorkflowExecution => "UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION",
+            SignalExternalWorkflowExecutionFailedCause::SignalExternalWorkflowExecutionRateExceeded => "SIGNAL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED",
+            SignalExternalWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            SignalExternalWorkflowExecutionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SignalExternalWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> SignalExternalWorkflowExecutionFailedCause {
+        match value {
+            "UNKNOWN_EXTERNAL_WORKFLOW_EXECUTION" => SignalExternalWorkflowExecutionFailedCause::UnknownExternalWorkflowExecution,
+            "SIGNAL_EXTERNAL_WORKFLOW_EXECUTION_RATE_EXCEEDED" => SignalExternalWorkflowExecutionFailedCause::SignalExternalWorkflowExecutionRateExceeded,
+            "OPERATION_NOT_PERMITTED" => SignalExternalWorkflowExecutionFailedCause::OperationNotPermitted,
+            _ => SignalExternalWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SignalExternalWorkflowExecutionFailedCause {
+    fn from(value: String) -> SignalExternalWorkflowExecutionFailedCause {
+        SignalExternalWorkflowExecutionFailedCause::from(value.as_str())
+    }
+}
+
+impl From<SignalExternalWorkflowExecutionFailedCause> for String {
+    fn from(value: SignalExternalWorkflowExecutionFailedCause) -> String {
+        match value {
+            SignalExternalWorkflowExecutionFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SignalExternalWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SignalExternalWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SignalExternalWorkflowExecutionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<SignalExternalWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SignalExternalWorkflowExecutionFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>SignalExternalWorkflowExecutionFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SignalExternalWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: SignalExternalWorkflowExecutionFailedCause,
     /// <p>The data attached to the event that the decider can use in subsequent workflow tasks. This data isn't sent to the workflow execution.</p>
     #[serde(rename = "control")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2082,13 +5644,81 @@ pub struct SignalWorkflowExecutionInput {
     pub workflow_id: String,
 }
 
+impl SignalWorkflowExecutionInput {
+    /// Returns a builder for `SignalWorkflowExecutionInput`, checking its required members when built.
+    pub fn builder() -> SignalWorkflowExecutionInputBuilder {
+        SignalWorkflowExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`SignalWorkflowExecutionInput`](struct.SignalWorkflowExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct SignalWorkflowExecutionInputBuilder {
+    domain: Option<String>,
+    input: Option<String>,
+    run_id: Option<String>,
+    signal_name: Option<String>,
+    workflow_id: Option<String>,
+}
+
+impl SignalWorkflowExecutionInputBuilder {
+    /// Sets the [`domain`](struct.SignalWorkflowExecutionInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input`](struct.SignalWorkflowExecutionInput.html#structfield.input) member.
+    pub fn input<T: Into<String>>(mut self, value: T) -> Self {
+        self.input = Some(value.into());
+        self
+    }
+
+    /// Sets the [`run_id`](struct.SignalWorkflowExecutionInput.html#structfield.run_id) member.
+    pub fn run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.run_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`signal_name`](struct.SignalWorkflowExecutionInput.html#structfield.signal_name) member.
+    pub fn signal_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.signal_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workflow_id`](struct.SignalWorkflowExecutionInput.html#structfield.workflow_id) member.
+    pub fn workflow_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.workflow_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SignalWorkflowExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SignalWorkflowExecutionInput, ::rusoto_core::BuildError> {
+        Ok(SignalWorkflowExecutionInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            input: self.input,
+            run_id: self.run_id,
+            signal_name: self
+                .signal_name
+                .ok_or(::rusoto_core::BuildError::missing("signal_name"))?,
+            workflow_id: self
+                .workflow_id
+                .ok_or(::rusoto_core::BuildError::missing("workflow_id"))?,
+        })
+    }
+}
+
 /// <p>Provides the details of the <code>StartChildWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagList.member.N</code> – The key is "swf:tagList.N" where N is the tag number from 0 to 4, inclusive.</p> </li> <li> <p> <code>taskList</code> – String constraint. The key is <code>swf:taskList.name</code>.</p> </li> <li> <p> <code>workflowType.name</code> – String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code> – String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartChildWorkflowExecutionDecisionAttributes {
     /// <p><p> If set, specifies the policy to use for the child workflow executions if the workflow execution being started is terminated by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This policy overrides the default child policy specified when registering the workflow type using <a>RegisterWorkflowType</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub child_policy: Option<String>,
+    pub child_policy: Option<ChildPolicy>,
     /// <p>The data attached to the event that can be used by the decider in subsequent workflow tasks. This data isn't sent to the child workflow execution.</p>
     #[serde(rename = "control")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2129,13 +5759,145 @@ pub struct StartChildWorkflowExecutionDecisionAttributes {
     pub workflow_type: WorkflowType,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>When <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision fails because it lacks sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html"> Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StartChildWorkflowExecutionFailedCause {
+    /// <p><code>WORKFLOW_TYPE_DOES_NOT_EXIST</code></p>
+    WorkflowTypeDoesNotExist,
+    /// <p><code>WORKFLOW_TYPE_DEPRECATED</code></p>
+    WorkflowTypeDeprecated,
+    /// <p><code>OPEN_CHILDREN_LIMIT_EXCEEDED</code></p>
+    OpenChildrenLimitExceeded,
+    /// <p><code>OPEN_WORKFLOWS_LIMIT_EXCEEDED</code></p>
+    OpenWorkflowsLimitExceeded,
+    /// <p><code>CHILD_CREATION_RATE_EXCEEDED</code></p>
+    ChildCreationRateExceeded,
+    /// <p><code>WORKFLOW_ALREADY_RUNNING</code></p>
+    WorkflowAlreadyRunning,
+    /// <p><code>DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED</code></p>
+    DefaultExecutionStartToCloseTimeoutUndefined,
+    /// <p><code>DEFAULT_TASK_LIST_UNDEFINED</code></p>
+    DefaultTaskListUndefined,
+    /// <p><code>DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED</code></p>
+    DefaultTaskStartToCloseTimeoutUndefined,
+    /// <p><code>DEFAULT_CHILD_POLICY_UNDEFINED</code></p>
+    DefaultChildPolicyUndefined,
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StartChildWorkflowExecutionFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StartChildWorkflowExecutionFailedCause::WorkflowTypeDoesNotExist => "WORKFLOW_TYPE_DOES_NOT_EXIST",
+            StartChildWorkflowExecutionFailedCause::WorkflowTypeDeprecated => "WORKFLOW_TYPE_DEPRECATED",
+            StartChildWorkflowExecutionFailedCause::OpenChildrenLimitExceeded => "OPEN_CHILDREN_LIMIT_EXCEEDED",
+            StartChildWorkflowExecutionFailedCause::OpenWorkflowsLimitExceeded => "OPEN_WORKFLOWS_LIMIT_EXCEEDED",
+            StartChildWorkflowExecutionFailedCause::ChildCreationRateExceeded => "CHILD_CREATION_RATE_EXCEEDED",
+            StartChildWorkflowExecutionFailedCause::WorkflowAlreadyRunning => "WORKFLOW_ALREADY_RUNNING",
+            StartChildWorkflowExecutionFailedCause::DefaultExecutionStartToCloseTimeoutUndefined => "DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED",
+            StartChildWorkflowExecutionFailedCause::DefaultTaskListUndefined => "DEFAULT_TASK_LIST_UNDEFINED",
+            StartChildWorkflowExecutionFailedCause::DefaultTaskStartToCloseTimeoutUndefined => "DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED",
+            StartChildWorkflowExecutionFailedCause::DefaultChildPolicyUndefined => "DEFAULT_CHILD_POLICY_UNDEFINED",
+            StartChildWorkflowExecutionFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            StartChildWorkflowExecutionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StartChildWorkflowExecutionFailedCause {
+    fn from(value: &'a str) -> StartChildWorkflowExecutionFailedCause {
+        match value {
+            "WORKFLOW_TYPE_DOES_NOT_EXIST" => {
+                StartChildWorkflowExecutionFailedCause::WorkflowTypeDoesNotExist
+            }
+            "WORKFLOW_TYPE_DEPRECATED" => {
+                StartChildWorkflowExecutionFailedCause::WorkflowTypeDeprecated
+            }
+            "OPEN_CHILDREN_LIMIT_EXCEEDED" => {
+                StartChildWorkflowExecutionFailedCause::OpenChildrenLimitExceeded
+            }
+            "OPEN_WORKFLOWS_LIMIT_EXCEEDED" => {
+                StartChildWorkflowExecutionFailedCause::OpenWorkflowsLimitExceeded
+            }
+            "CHILD_CREATION_RATE_EXCEEDED" => {
+                StartChildWorkflowExecutionFailedCause::ChildCreationRateExceeded
+            }
+            "WORKFLOW_ALREADY_RUNNING" => {
+                StartChildWorkflowExecutionFailedCause::WorkflowAlreadyRunning
+            }
+            "DEFAULT_EXECUTION_START_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultExecutionStartToCloseTimeoutUndefined
+            }
+            "DEFAULT_TASK_LIST_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultTaskListUndefined
+            }
+            "DEFAULT_TASK_START_TO_CLOSE_TIMEOUT_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultTaskStartToCloseTimeoutUndefined
+            }
+            "DEFAULT_CHILD_POLICY_UNDEFINED" => {
+                StartChildWorkflowExecutionFailedCause::DefaultChildPolicyUndefined
+            }
+            "OPERATION_NOT_PERMITTED" => {
+                StartChildWorkflowExecutionFailedCause::OperationNotPermitted
+            }
+            _ => StartChildWorkflowExecutionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StartChildWorkflowExecutionFailedCause {
+    fn from(value: String) -> StartChildWorkflowExecutionFailedCause {
+        StartChildWorkflowExecutionFailedCause::from(value.as_str())
+    }
+}
+
+impl From<StartChildWorkflowExecutionFailedCause> for String {
+    fn from(value: StartChildWorkflowExecutionFailedCause) -> String {
+        match value {
+            StartChildWorkflowExecutionFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StartChildWorkflowExecutionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StartChildWorkflowExecutionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StartChildWorkflowExecutionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<StartChildWorkflowExecutionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StartChildWorkflowExecutionFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>StartChildWorkflowExecutionFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartChildWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>When <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision fails because it lacks sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html"> Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: StartChildWorkflowExecutionFailedCause,
     /// <p>The data attached to the event that the decider can use in subsequent workflow tasks. This data isn't sent to the child workflow execution.</p>
     #[serde(rename = "control")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2155,12 +5917,12 @@ pub struct StartChildWorkflowExecutionFailedEventAttributes {
 }
 
 /// <p>Provides the details of the <code>StartChildWorkflowExecutionInitiated</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartChildWorkflowExecutionInitiatedEventAttributes {
     /// <p><p>The policy to use for the child workflow executions if this execution gets terminated by explicitly calling the <a>TerminateWorkflowExecution</a> action or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>Data attached to the event that can be used by the decider in subsequent decision tasks. This data isn't sent to the activity.</p>
     #[serde(rename = "control")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2203,6 +5965,76 @@ pub struct StartChildWorkflowExecutionInitiatedEventAttributes {
     pub workflow_type: WorkflowType,
 }
 
+/// <p><p>The cause of the failure. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because the IAM role attached to the execution lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/lambda-task.html">Lambda Tasks</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StartLambdaFunctionFailedCause {
+    /// <p><code>ASSUME_ROLE_FAILED</code></p>
+    AssumeRoleFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StartLambdaFunctionFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StartLambdaFunctionFailedCause::AssumeRoleFailed => "ASSUME_ROLE_FAILED",
+            StartLambdaFunctionFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StartLambdaFunctionFailedCause {
+    fn from(value: &'a str) -> StartLambdaFunctionFailedCause {
+        match value {
+            "ASSUME_ROLE_FAILED" => StartLambdaFunctionFailedCause::AssumeRoleFailed,
+            _ => StartLambdaFunctionFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StartLambdaFunctionFailedCause {
+    fn from(value: String) -> StartLambdaFunctionFailedCause {
+        StartLambdaFunctionFailedCause::from(value.as_str())
+    }
+}
+
+impl From<StartLambdaFunctionFailedCause> for String {
+    fn from(value: StartLambdaFunctionFailedCause) -> String {
+        match value {
+            StartLambdaFunctionFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StartLambdaFunctionFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StartLambdaFunctionFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StartLambdaFunctionFailedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<StartLambdaFunctionFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StartLambdaFunctionFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>StartLambdaFunctionFailed</code> event. It isn't set for other event types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2210,7 +6042,7 @@ pub struct StartLambdaFunctionFailedEventAttributes {
     /// <p><p>The cause of the failure. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because the IAM role attached to the execution lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/lambda-task.html">Lambda Tasks</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cause: Option<String>,
+    pub cause: Option<StartLambdaFunctionFailedCause>,
     /// <p>A description that can help diagnose the cause of the fault.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2236,13 +6068,93 @@ pub struct StartTimerDecisionAttributes {
     pub timer_id: String,
 }
 
+/// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StartTimerFailedCause {
+    /// <p><code>TIMER_ID_ALREADY_IN_USE</code></p>
+    TimerIdAlreadyInUse,
+    /// <p><code>OPEN_TIMERS_LIMIT_EXCEEDED</code></p>
+    OpenTimersLimitExceeded,
+    /// <p><code>TIMER_CREATION_RATE_EXCEEDED</code></p>
+    TimerCreationRateExceeded,
+    /// <p><code>OPERATION_NOT_PERMITTED</code></p>
+    OperationNotPermitted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StartTimerFailedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StartTimerFailedCause::TimerIdAlreadyInUse => "TIMER_ID_ALREADY_IN_USE",
+            StartTimerFailedCause::OpenTimersLimitExceeded => "OPEN_TIMERS_LIMIT_EXCEEDED",
+            StartTimerFailedCause::TimerCreationRateExceeded => "TIMER_CREATION_RATE_EXCEEDED",
+            StartTimerFailedCause::OperationNotPermitted => "OPERATION_NOT_PERMITTED",
+            StartTimerFailedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StartTimerFailedCause {
+    fn from(value: &'a str) -> StartTimerFailedCause {
+        match value {
+            "TIMER_ID_ALREADY_IN_USE" => StartTimerFailedCause::TimerIdAlreadyInUse,
+            "OPEN_TIMERS_LIMIT_EXCEEDED" => StartTimerFailedCause::OpenTimersLimitExceeded,
+            "TIMER_CREATION_RATE_EXCEEDED" => StartTimerFailedCause::TimerCreationRateExceeded,
+            "OPERATION_NOT_PERMITTED" => StartTimerFailedCause::OperationNotPermitted,
+            _ => StartTimerFailedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StartTimerFailedCause {
+    fn from(value: String) -> StartTimerFailedCause {
+        StartTimerFailedCause::from(value.as_str())
+    }
+}
+
+impl From<StartTimerFailedCause> for String {
+    fn from(value: StartTimerFailedCause) -> String {
+        match value {
+            StartTimerFailedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StartTimerFailedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StartTimerFailedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StartTimerFailedCause {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StartTimerFailedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StartTimerFailedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>StartTimerFailed</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartTimerFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
-    pub cause: String,
+    pub cause: StartTimerFailedCause,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>StartTimer</code> decision for this activity task. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -2256,7 +6168,7 @@ pub struct StartWorkflowExecutionInput {
     /// <p><p>If set, specifies the policy to use for the child workflow executions of this workflow execution if it is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This policy overrides the default child policy specified when registering the workflow type using <a>RegisterWorkflowType</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub child_policy: Option<String>,
+    pub child_policy: Option<ChildPolicy>,
     /// <p>The name of the domain in which the workflow execution is created.</p>
     #[serde(rename = "domain")]
     pub domain: String,
@@ -2296,6 +6208,122 @@ pub struct StartWorkflowExecutionInput {
     pub workflow_type: WorkflowType,
 }
 
+impl StartWorkflowExecutionInput {
+    /// Returns a builder for `StartWorkflowExecutionInput`, checking its required members when built.
+    pub fn builder() -> StartWorkflowExecutionInputBuilder {
+        StartWorkflowExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`StartWorkflowExecutionInput`](struct.StartWorkflowExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct StartWorkflowExecutionInputBuilder {
+    child_policy: Option<ChildPolicy>,
+    domain: Option<String>,
+    execution_start_to_close_timeout: Option<String>,
+    input: Option<String>,
+    lambda_role: Option<String>,
+    tag_list: Option<Vec<String>>,
+    task_list: Option<TaskList>,
+    task_priority: Option<String>,
+    task_start_to_close_timeout: Option<String>,
+    workflow_id: Option<String>,
+    workflow_type: Option<WorkflowType>,
+}
+
+impl StartWorkflowExecutionInputBuilder {
+    /// Sets the [`child_policy`](struct.StartWorkflowExecutionInput.html#structfield.child_policy) member.
+    pub fn child_policy<T: Into<ChildPolicy>>(mut self, value: T) -> Self {
+        self.child_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.StartWorkflowExecutionInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_start_to_close_timeout`](struct.StartWorkflowExecutionInput.html#structfield.execution_start_to_close_timeout) member.
+    pub fn execution_start_to_close_timeout<T: Into<String>>(mut self, value: T) -> Self {
+        self.execution_start_to_close_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input`](struct.StartWorkflowExecutionInput.html#structfield.input) member.
+    pub fn input<T: Into<String>>(mut self, value: T) -> Self {
+        self.input = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lambda_role`](struct.StartWorkflowExecutionInput.html#structfield.lambda_role) member.
+    pub fn lambda_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.lambda_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_list`](struct.StartWorkflowExecutionInput.html#structfield.tag_list) member.
+    pub fn tag_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_list`](struct.StartWorkflowExecutionInput.html#structfield.task_list) member.
+    pub fn task_list<T: Into<TaskList>>(mut self, value: T) -> Self {
+        self.task_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_priority`](struct.StartWorkflowExecutionInput.html#structfield.task_priority) member.
+    pub fn task_priority<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_priority = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_start_to_close_timeout`](struct.StartWorkflowExecutionInput.html#structfield.task_start_to_close_timeout) member.
+    pub fn task_start_to_close_timeout<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_start_to_close_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workflow_id`](struct.StartWorkflowExecutionInput.html#structfield.workflow_id) member.
+    pub fn workflow_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.workflow_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workflow_type`](struct.StartWorkflowExecutionInput.html#structfield.workflow_type) member.
+    pub fn workflow_type<T: Into<WorkflowType>>(mut self, value: T) -> Self {
+        self.workflow_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartWorkflowExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartWorkflowExecutionInput, ::rusoto_core::BuildError> {
+        Ok(StartWorkflowExecutionInput {
+            child_policy: self.child_policy,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            execution_start_to_close_timeout: self.execution_start_to_close_timeout,
+            input: self.input,
+            lambda_role: self.lambda_role,
+            tag_list: self.tag_list,
+            task_list: self.task_list,
+            task_priority: self.task_priority,
+            task_start_to_close_timeout: self.task_start_to_close_timeout,
+            workflow_id: self
+                .workflow_id
+                .ok_or(::rusoto_core::BuildError::missing("workflow_id"))?,
+            workflow_type: self
+                .workflow_type
+                .ok_or(::rusoto_core::BuildError::missing("workflow_type"))?,
+        })
+    }
+}
+
 /// <p>Used to filter the workflow executions in visibility APIs based on a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TagFilter {
@@ -2314,6 +6342,46 @@ pub struct TagResourceInput {
     pub tags: Vec<ResourceTag>,
 }
 
+impl TagResourceInput {
+    /// Returns a builder for `TagResourceInput`, checking its required members when built.
+    pub fn builder() -> TagResourceInputBuilder {
+        TagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceInput`](struct.TagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<ResourceTag>>,
+}
+
+impl TagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<ResourceTag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceInput, ::rusoto_core::BuildError> {
+        Ok(TagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>Represents a task list.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskList {
@@ -2327,7 +6395,7 @@ pub struct TerminateWorkflowExecutionInput {
     /// <p><p>If set, specifies the policy to use for the child workflow executions of the workflow execution being terminated. This policy overrides the child policy specified for the workflow execution at registration time or when starting the execution.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub child_policy: Option<String>,
+    pub child_policy: Option<ChildPolicy>,
     /// <p> Details for terminating the workflow execution.</p>
     #[serde(rename = "details")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2348,6 +6416,80 @@ pub struct TerminateWorkflowExecutionInput {
     pub workflow_id: String,
 }
 
+impl TerminateWorkflowExecutionInput {
+    /// Returns a builder for `TerminateWorkflowExecutionInput`, checking its required members when built.
+    pub fn builder() -> TerminateWorkflowExecutionInputBuilder {
+        TerminateWorkflowExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`TerminateWorkflowExecutionInput`](struct.TerminateWorkflowExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct TerminateWorkflowExecutionInputBuilder {
+    child_policy: Option<ChildPolicy>,
+    details: Option<String>,
+    domain: Option<String>,
+    reason: Option<String>,
+    run_id: Option<String>,
+    workflow_id: Option<String>,
+}
+
+impl TerminateWorkflowExecutionInputBuilder {
+    /// Sets the [`child_policy`](struct.TerminateWorkflowExecutionInput.html#structfield.child_policy) member.
+    pub fn child_policy<T: Into<ChildPolicy>>(mut self, value: T) -> Self {
+        self.child_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`details`](struct.TerminateWorkflowExecutionInput.html#structfield.details) member.
+    pub fn details<T: Into<String>>(mut self, value: T) -> Self {
+        self.details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.TerminateWorkflowExecutionInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason`](struct.TerminateWorkflowExecutionInput.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`run_id`](struct.TerminateWorkflowExecutionInput.html#structfield.run_id) member.
+    pub fn run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.run_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workflow_id`](struct.TerminateWorkflowExecutionInput.html#structfield.workflow_id) member.
+    pub fn workflow_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.workflow_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `TerminateWorkflowExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TerminateWorkflowExecutionInput, ::rusoto_core::BuildError> {
+        Ok(TerminateWorkflowExecutionInput {
+            child_policy: self.child_policy,
+            details: self.details,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            reason: self.reason,
+            run_id: self.run_id,
+            workflow_id: self
+                .workflow_id
+                .ok_or(::rusoto_core::BuildError::missing("workflow_id"))?,
+        })
+    }
+}
+
 /// <p> Provides the details of the <code>TimerCanceled</code> event. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2404,6 +6546,48 @@ pub struct UndeprecateActivityTypeInput {
     pub domain: String,
 }
 
+impl UndeprecateActivityTypeInput {
+    /// Returns a builder for `UndeprecateActivityTypeInput`, checking its required members when built.
+    pub fn builder() -> UndeprecateActivityTypeInputBuilder {
+        UndeprecateActivityTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`UndeprecateActivityTypeInput`](struct.UndeprecateActivityTypeInput.html).
+#[derive(Default, Debug)]
+pub struct UndeprecateActivityTypeInputBuilder {
+    activity_type: Option<ActivityType>,
+    domain: Option<String>,
+}
+
+impl UndeprecateActivityTypeInputBuilder {
+    /// Sets the [`activity_type`](struct.UndeprecateActivityTypeInput.html#structfield.activity_type) member.
+    pub fn activity_type<T: Into<ActivityType>>(mut self, value: T) -> Self {
+        self.activity_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.UndeprecateActivityTypeInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Builds the `UndeprecateActivityTypeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UndeprecateActivityTypeInput, ::rusoto_core::BuildError> {
+        Ok(UndeprecateActivityTypeInput {
+            activity_type: self
+                .activity_type
+                .ok_or(::rusoto_core::BuildError::missing("activity_type"))?,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UndeprecateDomainInput {
     /// <p>The name of the domain of the deprecated workflow type.</p>
@@ -2411,6 +6595,36 @@ pub struct UndeprecateDomainInput {
     pub name: String,
 }
 
+impl UndeprecateDomainInput {
+    /// Returns a builder for `UndeprecateDomainInput`, checking its required members when built.
+    pub fn builder() -> UndeprecateDomainInputBuilder {
+        UndeprecateDomainInputBuilder::default()
+    }
+}
+
+/// Builds a [`UndeprecateDomainInput`](struct.UndeprecateDomainInput.html).
+#[derive(Default, Debug)]
+pub struct UndeprecateDomainInputBuilder {
+    name: Option<String>,
+}
+
+impl UndeprecateDomainInputBuilder {
+    /// Sets the [`name`](struct.UndeprecateDomainInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UndeprecateDomainInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UndeprecateDomainInput, ::rusoto_core::BuildError> {
+        Ok(UndeprecateDomainInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UndeprecateWorkflowTypeInput {
     /// <p>The name of the domain of the deprecated workflow type.</p>
@@ -2421,6 +6635,48 @@ pub struct UndeprecateWorkflowTypeInput {
     pub workflow_type: WorkflowType,
 }
 
+impl UndeprecateWorkflowTypeInput {
+    /// Returns a builder for `UndeprecateWorkflowTypeInput`, checking its required members when built.
+    pub fn builder() -> UndeprecateWorkflowTypeInputBuilder {
+        UndeprecateWorkflowTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`UndeprecateWorkflowTypeInput`](struct.UndeprecateWorkflowTypeInput.html).
+#[derive(Default, Debug)]
+pub struct UndeprecateWorkflowTypeInputBuilder {
+    domain: Option<String>,
+    workflow_type: Option<WorkflowType>,
+}
+
+impl UndeprecateWorkflowTypeInputBuilder {
+    /// Sets the [`domain`](struct.UndeprecateWorkflowTypeInput.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workflow_type`](struct.UndeprecateWorkflowTypeInput.html#structfield.workflow_type) member.
+    pub fn workflow_type<T: Into<WorkflowType>>(mut self, value: T) -> Self {
+        self.workflow_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `UndeprecateWorkflowTypeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UndeprecateWorkflowTypeInput, ::rusoto_core::BuildError> {
+        Ok(UndeprecateWorkflowTypeInput {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            workflow_type: self
+                .workflow_type
+                .ok_or(::rusoto_core::BuildError::missing("workflow_type"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceInput {
     /// <p>The Amazon Resource Name (ARN) for the Amazon SWF domain.</p>
@@ -2431,6 +6687,46 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceInput {
+    /// Returns a builder for `UntagResourceInput`, checking its required members when built.
+    pub fn builder() -> UntagResourceInputBuilder {
+        UntagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceInput`](struct.UntagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p>Represents a workflow execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WorkflowExecution {
@@ -2442,6 +6738,76 @@ pub struct WorkflowExecution {
     pub workflow_id: String,
 }
 
+/// <p>If set, indicates that the request to cancel the workflow execution was automatically generated, and specifies the cause. This happens if the parent workflow execution times out or is terminated, and the child policy is set to cancel child executions.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkflowExecutionCancelRequestedCause {
+    /// <p><code>CHILD_POLICY_APPLIED</code></p>
+    ChildPolicyApplied,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkflowExecutionCancelRequestedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkflowExecutionCancelRequestedCause::ChildPolicyApplied => "CHILD_POLICY_APPLIED",
+            WorkflowExecutionCancelRequestedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowExecutionCancelRequestedCause {
+    fn from(value: &'a str) -> WorkflowExecutionCancelRequestedCause {
+        match value {
+            "CHILD_POLICY_APPLIED" => WorkflowExecutionCancelRequestedCause::ChildPolicyApplied,
+            _ => WorkflowExecutionCancelRequestedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkflowExecutionCancelRequestedCause {
+    fn from(value: String) -> WorkflowExecutionCancelRequestedCause {
+        WorkflowExecutionCancelRequestedCause::from(value.as_str())
+    }
+}
+
+impl From<WorkflowExecutionCancelRequestedCause> for String {
+    fn from(value: WorkflowExecutionCancelRequestedCause) -> String {
+        match value {
+            WorkflowExecutionCancelRequestedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkflowExecutionCancelRequestedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkflowExecutionCancelRequestedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkflowExecutionCancelRequestedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<WorkflowExecutionCancelRequestedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkflowExecutionCancelRequestedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>WorkflowExecutionCancelRequested</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2449,7 +6815,7 @@ pub struct WorkflowExecutionCancelRequestedEventAttributes {
     /// <p>If set, indicates that the request to cancel the workflow execution was automatically generated, and specifies the cause. This happens if the parent workflow execution times out or is terminated, and the child policy is set to cancel child executions.</p>
     #[serde(rename = "cause")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cause: Option<String>,
+    pub cause: Option<WorkflowExecutionCancelRequestedCause>,
     /// <p>The ID of the <code>RequestCancelExternalWorkflowExecutionInitiated</code> event corresponding to the <code>RequestCancelExternalWorkflowExecution</code> decision to cancel this workflow execution.The source event with this ID can be found in the history of the source workflow execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "externalInitiatedEventId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2487,12 +6853,12 @@ pub struct WorkflowExecutionCompletedEventAttributes {
 }
 
 /// <p>The configuration settings for a workflow execution including timeout values, tasklist etc. These configuration settings are determined from the defaults specified when registering the workflow type and those specified when starting the workflow execution.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct WorkflowExecutionConfiguration {
     /// <p><p>The policy to use for the child workflow executions if this workflow execution is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>The total duration for this workflow execution.</p> <p>The duration is specified in seconds, an integer greater than or equal to <code>0</code>. You can use <code>NONE</code> to specify unlimited duration.</p>
     #[serde(rename = "executionStartToCloseTimeout")]
     pub execution_start_to_close_timeout: String,
@@ -2513,12 +6879,12 @@ pub struct WorkflowExecutionConfiguration {
 }
 
 /// <p>Provides the details of the <code>WorkflowExecutionContinuedAsNew</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct WorkflowExecutionContinuedAsNewEventAttributes {
     /// <p><p>The policy to use for the child workflow executions of the new execution if it is terminated by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>ContinueAsNewWorkflowExecution</code> decision that started this execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
     pub decision_task_completed_event_id: i64,
@@ -2571,7 +6937,7 @@ pub struct WorkflowExecutionCount {
 }
 
 /// <p>Contains details about a workflow execution.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct WorkflowExecutionDetail {
     /// <p>The configuration settings for this workflow execution including timeout values, tasklist etc.</p>
@@ -2582,8 +6948,9 @@ pub struct WorkflowExecutionDetail {
     pub execution_info: WorkflowExecutionInfo,
     /// <p>The time when the last activity task was scheduled for this workflow execution. You can use this information to determine if the workflow has not made progress for an unusually long period of time and might require a corrective action.</p>
     #[serde(rename = "latestActivityTaskTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_activity_task_timestamp: Option<f64>,
+    pub latest_activity_task_timestamp: Option<EpochTimestamp>,
     /// <p>The latest executionContext provided by the decider for this workflow execution. A decider can provide an executionContext (a free-form string) when closing a decision task using <a>RespondDecisionTaskCompleted</a>.</p>
     #[serde(rename = "latestExecutionContext")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2619,7 +6986,7 @@ pub struct WorkflowExecutionFilter {
 }
 
 /// <p>Contains information about a workflow execution.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct WorkflowExecutionInfo {
     /// <p>Set to true if a cancellation is requested for this workflow execution.</p>
@@ -2629,24 +6996,26 @@ pub struct WorkflowExecutionInfo {
     /// <p><p>If the execution status is closed then this specifies how the execution was closed:</p> <ul> <li> <p> <code>COMPLETED</code> – the execution was successfully completed.</p> </li> <li> <p> <code>CANCELED</code> – the execution was canceled.Cancellation allows the implementation to gracefully clean up before the execution is closed.</p> </li> <li> <p> <code>TERMINATED</code> – the execution was force terminated.</p> </li> <li> <p> <code>FAILED</code> – the execution failed to complete.</p> </li> <li> <p> <code>TIMED<em>OUT</code> – the execution did not complete in the alloted time and was automatically timed out.</p> </li> <li> <p> <code>CONTINUED</em>AS_NEW</code> – the execution is logically continued. This means the current execution was completed and a new execution was started to carry on the workflow.</p> </li> </ul></p>
     #[serde(rename = "closeStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub close_status: Option<String>,
+    pub close_status: Option<CloseStatus>,
     /// <p>The time when the workflow execution was closed. Set only if the execution status is CLOSED.</p>
     #[serde(rename = "closeTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub close_timestamp: Option<f64>,
+    pub close_timestamp: Option<EpochTimestamp>,
     /// <p>The workflow execution this information is about.</p>
     #[serde(rename = "execution")]
     pub execution: WorkflowExecution,
     /// <p>The current status of the execution.</p>
     #[serde(rename = "executionStatus")]
-    pub execution_status: String,
+    pub execution_status: ExecutionStatus,
     /// <p>If this workflow execution is a child of another execution then contains the workflow execution that started this execution.</p>
     #[serde(rename = "parent")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent: Option<WorkflowExecution>,
     /// <p>The time when the execution was started.</p>
     #[serde(rename = "startTimestamp")]
-    pub start_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_timestamp: EpochTimestamp,
     /// <p>The list of tags associated with the workflow execution. Tags can be used to identify and list workflow executions of interest through the visibility APIs. A workflow execution can have a maximum of 5 tags.</p>
     #[serde(rename = "tagList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2713,12 +7082,12 @@ pub struct WorkflowExecutionSignaledEventAttributes {
 }
 
 /// <p>Provides details of <code>WorkflowExecutionStarted</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct WorkflowExecutionStartedEventAttributes {
     /// <p><p>The policy to use for the child workflow executions if this workflow execution is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>If this workflow execution was started due to a <code>ContinueAsNewWorkflowExecution</code> decision, then it contains the <code>runId</code> of the previous workflow execution that was closed and continued as this execution.</p>
     #[serde(rename = "continuedExecutionRunId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2763,17 +7132,95 @@ pub struct WorkflowExecutionStartedEventAttributes {
     pub workflow_type: WorkflowType,
 }
 
+/// <p>If set, indicates that the workflow execution was automatically terminated, and specifies the cause. This happens if the parent workflow execution times out or is terminated and the child policy is set to terminate child executions.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkflowExecutionTerminatedCause {
+    /// <p><code>CHILD_POLICY_APPLIED</code></p>
+    ChildPolicyApplied,
+    /// <p><code>EVENT_LIMIT_EXCEEDED</code></p>
+    EventLimitExceeded,
+    /// <p><code>OPERATOR_INITIATED</code></p>
+    OperatorInitiated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkflowExecutionTerminatedCause {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkflowExecutionTerminatedCause::ChildPolicyApplied => "CHILD_POLICY_APPLIED",
+            WorkflowExecutionTerminatedCause::EventLimitExceeded => "EVENT_LIMIT_EXCEEDED",
+            WorkflowExecutionTerminatedCause::OperatorInitiated => "OPERATOR_INITIATED",
+            WorkflowExecutionTerminatedCause::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowExecutionTerminatedCause {
+    fn from(value: &'a str) -> WorkflowExecutionTerminatedCause {
+        match value {
+            "CHILD_POLICY_APPLIED" => WorkflowExecutionTerminatedCause::ChildPolicyApplied,
+            "EVENT_LIMIT_EXCEEDED" => WorkflowExecutionTerminatedCause::EventLimitExceeded,
+            "OPERATOR_INITIATED" => WorkflowExecutionTerminatedCause::OperatorInitiated,
+            _ => WorkflowExecutionTerminatedCause::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkflowExecutionTerminatedCause {
+    fn from(value: String) -> WorkflowExecutionTerminatedCause {
+        WorkflowExecutionTerminatedCause::from(value.as_str())
+    }
+}
+
+impl From<WorkflowExecutionTerminatedCause> for String {
+    fn from(value: WorkflowExecutionTerminatedCause) -> String {
+        match value {
+            WorkflowExecutionTerminatedCause::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkflowExecutionTerminatedCause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkflowExecutionTerminatedCause {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkflowExecutionTerminatedCause {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<WorkflowExecutionTerminatedCause, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkflowExecutionTerminatedCause::from(value))
+    }
+}
+
 /// <p>Provides the details of the <code>WorkflowExecutionTerminated</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct WorkflowExecutionTerminatedEventAttributes {
     /// <p>If set, indicates that the workflow execution was automatically terminated, and specifies the cause. This happens if the parent workflow execution times out or is terminated and the child policy is set to terminate child executions.</p>
     #[serde(rename = "cause")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cause: Option<String>,
+    pub cause: Option<WorkflowExecutionTerminatedCause>,
     /// <p><p>The policy used for the child workflow executions of this workflow execution.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>The details provided for the termination.</p>
     #[serde(rename = "details")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2785,15 +7232,85 @@ pub struct WorkflowExecutionTerminatedEventAttributes {
 }
 
 /// <p>Provides the details of the <code>WorkflowExecutionTimedOut</code> event.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct WorkflowExecutionTimedOutEventAttributes {
     /// <p><p>The policy used for the child workflow executions of this workflow execution.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
-    pub child_policy: String,
+    pub child_policy: ChildPolicy,
     /// <p>The type of timeout that caused this event.</p>
     #[serde(rename = "timeoutType")]
-    pub timeout_type: String,
+    pub timeout_type: WorkflowExecutionTimeoutType,
+}
+
+/// <p>The type of the timeout that caused the child workflow execution to time out.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkflowExecutionTimeoutType {
+    /// <p><code>START_TO_CLOSE</code></p>
+    StartToClose,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkflowExecutionTimeoutType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkflowExecutionTimeoutType::StartToClose => "START_TO_CLOSE",
+            WorkflowExecutionTimeoutType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowExecutionTimeoutType {
+    fn from(value: &'a str) -> WorkflowExecutionTimeoutType {
+        match value {
+            "START_TO_CLOSE" => WorkflowExecutionTimeoutType::StartToClose,
+            _ => WorkflowExecutionTimeoutType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkflowExecutionTimeoutType {
+    fn from(value: String) -> WorkflowExecutionTimeoutType {
+        WorkflowExecutionTimeoutType::from(value.as_str())
+    }
+}
+
+impl From<WorkflowExecutionTimeoutType> for String {
+    fn from(value: WorkflowExecutionTimeoutType) -> String {
+        match value {
+            WorkflowExecutionTimeoutType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkflowExecutionTimeoutType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkflowExecutionTimeoutType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkflowExecutionTimeoutType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<WorkflowExecutionTimeoutType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkflowExecutionTimeoutType::from(value))
+    }
 }
 
 /// <p>Represents a workflow type.</p>
@@ -2814,7 +7331,7 @@ pub struct WorkflowTypeConfiguration {
     /// <p><p> The default policy to use for the child workflow executions when a workflow execution of this type is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This default can be overridden when starting a workflow execution using the <a>StartWorkflowExecution</a> action or the <code>StartChildWorkflowExecution</code> <a>Decision</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "defaultChildPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_child_policy: Option<String>,
+    pub default_child_policy: Option<ChildPolicy>,
     /// <p> The default maximum duration, specified when registering the workflow type, for executions of this workflow type. This default can be overridden when starting a workflow execution using the <a>StartWorkflowExecution</a> action or the <code>StartChildWorkflowExecution</code> <a>Decision</a>.</p> <p>The duration is specified in seconds, an integer greater than or equal to <code>0</code>. You can use <code>NONE</code> to specify unlimited duration.</p>
     #[serde(rename = "defaultExecutionStartToCloseTimeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2838,7 +7355,7 @@ pub struct WorkflowTypeConfiguration {
 }
 
 /// <p>Contains details about a workflow type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct WorkflowTypeDetail {
     /// <p>Configuration settings of the workflow type registered through <a>RegisterWorkflowType</a> </p>
@@ -2862,23 +7379,25 @@ pub struct WorkflowTypeFilter {
 }
 
 /// <p>Contains information about a workflow type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct WorkflowTypeInfo {
     /// <p>The date when this type was registered.</p>
     #[serde(rename = "creationDate")]
-    pub creation_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date: EpochTimestamp,
     /// <p>If the type is in deprecated state, then it is set to the date when the type was deprecated.</p>
     #[serde(rename = "deprecationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deprecation_date: Option<f64>,
+    pub deprecation_date: Option<EpochTimestamp>,
     /// <p>The description of the type registered through <a>RegisterWorkflowType</a>.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The current status of the workflow type.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: RegistrationStatus,
     /// <p>The workflow type this information is about.</p>
     #[serde(rename = "workflowType")]
     pub workflow_type: WorkflowType,
@@ -4874,6 +9393,225 @@ pub trait Swf {
     /// <p>Remove a tag from a Amazon SWF domain.</p>
     fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError>;
 }
+impl<T: ?Sized + Swf> Swf for ::std::sync::Arc<T> {
+    fn count_closed_workflow_executions(
+        &self,
+        input: CountClosedWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionCount, CountClosedWorkflowExecutionsError> {
+        (**self).count_closed_workflow_executions(input)
+    }
+    fn count_open_workflow_executions(
+        &self,
+        input: CountOpenWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionCount, CountOpenWorkflowExecutionsError> {
+        (**self).count_open_workflow_executions(input)
+    }
+    fn count_pending_activity_tasks(
+        &self,
+        input: CountPendingActivityTasksInput,
+    ) -> RusotoFuture<PendingTaskCount, CountPendingActivityTasksError> {
+        (**self).count_pending_activity_tasks(input)
+    }
+    fn count_pending_decision_tasks(
+        &self,
+        input: CountPendingDecisionTasksInput,
+    ) -> RusotoFuture<PendingTaskCount, CountPendingDecisionTasksError> {
+        (**self).count_pending_decision_tasks(input)
+    }
+    fn deprecate_activity_type(
+        &self,
+        input: DeprecateActivityTypeInput,
+    ) -> RusotoFuture<(), DeprecateActivityTypeError> {
+        (**self).deprecate_activity_type(input)
+    }
+    fn deprecate_domain(
+        &self,
+        input: DeprecateDomainInput,
+    ) -> RusotoFuture<(), DeprecateDomainError> {
+        (**self).deprecate_domain(input)
+    }
+    fn deprecate_workflow_type(
+        &self,
+        input: DeprecateWorkflowTypeInput,
+    ) -> RusotoFuture<(), DeprecateWorkflowTypeError> {
+        (**self).deprecate_workflow_type(input)
+    }
+    fn describe_activity_type(
+        &self,
+        input: DescribeActivityTypeInput,
+    ) -> RusotoFuture<ActivityTypeDetail, DescribeActivityTypeError> {
+        (**self).describe_activity_type(input)
+    }
+    fn describe_domain(
+        &self,
+        input: DescribeDomainInput,
+    ) -> RusotoFuture<DomainDetail, DescribeDomainError> {
+        (**self).describe_domain(input)
+    }
+    fn describe_workflow_execution(
+        &self,
+        input: DescribeWorkflowExecutionInput,
+    ) -> RusotoFuture<WorkflowExecutionDetail, DescribeWorkflowExecutionError> {
+        (**self).describe_workflow_execution(input)
+    }
+    fn describe_workflow_type(
+        &self,
+        input: DescribeWorkflowTypeInput,
+    ) -> RusotoFuture<WorkflowTypeDetail, DescribeWorkflowTypeError> {
+        (**self).describe_workflow_type(input)
+    }
+    fn get_workflow_execution_history(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> RusotoFuture<History, GetWorkflowExecutionHistoryError> {
+        (**self).get_workflow_execution_history(input)
+    }
+    fn list_activity_types(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> RusotoFuture<ActivityTypeInfos, ListActivityTypesError> {
+        (**self).list_activity_types(input)
+    }
+    fn list_closed_workflow_executions(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionInfos, ListClosedWorkflowExecutionsError> {
+        (**self).list_closed_workflow_executions(input)
+    }
+    fn list_domains(&self, input: ListDomainsInput) -> RusotoFuture<DomainInfos, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn list_open_workflow_executions(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionInfos, ListOpenWorkflowExecutionsError> {
+        (**self).list_open_workflow_executions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_workflow_types(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> RusotoFuture<WorkflowTypeInfos, ListWorkflowTypesError> {
+        (**self).list_workflow_types(input)
+    }
+    fn poll_for_activity_task(
+        &self,
+        input: PollForActivityTaskInput,
+    ) -> RusotoFuture<ActivityTask, PollForActivityTaskError> {
+        (**self).poll_for_activity_task(input)
+    }
+    fn poll_for_decision_task(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> RusotoFuture<DecisionTask, PollForDecisionTaskError> {
+        (**self).poll_for_decision_task(input)
+    }
+    fn record_activity_task_heartbeat(
+        &self,
+        input: RecordActivityTaskHeartbeatInput,
+    ) -> RusotoFuture<ActivityTaskStatus, RecordActivityTaskHeartbeatError> {
+        (**self).record_activity_task_heartbeat(input)
+    }
+    fn register_activity_type(
+        &self,
+        input: RegisterActivityTypeInput,
+    ) -> RusotoFuture<(), RegisterActivityTypeError> {
+        (**self).register_activity_type(input)
+    }
+    fn register_domain(&self, input: RegisterDomainInput) -> RusotoFuture<(), RegisterDomainError> {
+        (**self).register_domain(input)
+    }
+    fn register_workflow_type(
+        &self,
+        input: RegisterWorkflowTypeInput,
+    ) -> RusotoFuture<(), RegisterWorkflowTypeError> {
+        (**self).register_workflow_type(input)
+    }
+    fn request_cancel_workflow_execution(
+        &self,
+        input: RequestCancelWorkflowExecutionInput,
+    ) -> RusotoFuture<(), RequestCancelWorkflowExecutionError> {
+        (**self).request_cancel_workflow_execution(input)
+    }
+    fn respond_activity_task_canceled(
+        &self,
+        input: RespondActivityTaskCanceledInput,
+    ) -> RusotoFuture<(), RespondActivityTaskCanceledError> {
+        (**self).respond_activity_task_canceled(input)
+    }
+    fn respond_activity_task_completed(
+        &self,
+        input: RespondActivityTaskCompletedInput,
+    ) -> RusotoFuture<(), RespondActivityTaskCompletedError> {
+        (**self).respond_activity_task_completed(input)
+    }
+    fn respond_activity_task_failed(
+        &self,
+        input: RespondActivityTaskFailedInput,
+    ) -> RusotoFuture<(), RespondActivityTaskFailedError> {
+        (**self).respond_activity_task_failed(input)
+    }
+    fn respond_decision_task_completed(
+        &self,
+        input: RespondDecisionTaskCompletedInput,
+    ) -> RusotoFuture<(), RespondDecisionTaskCompletedError> {
+        (**self).respond_decision_task_completed(input)
+    }
+    fn signal_workflow_execution(
+        &self,
+        input: SignalWorkflowExecutionInput,
+    ) -> RusotoFuture<(), SignalWorkflowExecutionError> {
+        (**self).signal_workflow_execution(input)
+    }
+    fn start_workflow_execution(
+        &self,
+        input: StartWorkflowExecutionInput,
+    ) -> RusotoFuture<Run, StartWorkflowExecutionError> {
+        (**self).start_workflow_execution(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn terminate_workflow_execution(
+        &self,
+        input: TerminateWorkflowExecutionInput,
+    ) -> RusotoFuture<(), TerminateWorkflowExecutionError> {
+        (**self).terminate_workflow_execution(input)
+    }
+    fn undeprecate_activity_type(
+        &self,
+        input: UndeprecateActivityTypeInput,
+    ) -> RusotoFuture<(), UndeprecateActivityTypeError> {
+        (**self).undeprecate_activity_type(input)
+    }
+    fn undeprecate_domain(
+        &self,
+        input: UndeprecateDomainInput,
+    ) -> RusotoFuture<(), UndeprecateDomainError> {
+        (**self).undeprecate_domain(input)
+    }
+    fn undeprecate_workflow_type(
+        &self,
+        input: UndeprecateWorkflowTypeInput,
+    ) -> RusotoFuture<(), UndeprecateWorkflowTypeError> {
+        (**self).undeprecate_workflow_type(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// The error codes Amazon SWF retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon SWF API.
 #[derive(Clone)]
 pub struct SwfClient {
@@ -4889,6 +9627,24 @@ impl SwfClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_SWF`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> SwfClient {
+        Self::new(region::Region::from_env_for_service("SWF"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `swf`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> SwfClient {
+        Self::new_with_client(config.client().clone(), config.region_for("swf", "SWF"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4907,7 +9663,49 @@ impl SwfClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SwfClient {
-        SwfClient { client, region }
+        SwfClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "swf")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "swf", "swf", "SWF")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Swf` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Swf + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for SwfClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon SWF"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "swf"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> SwfClient {
+        Self::new_with_client(client, region)
     }
 }
 
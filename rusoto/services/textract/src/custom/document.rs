@@ -0,0 +1,385 @@
+//! Reassembling the blocks of a document into pages, lines and tables.
+//!
+//! Textract returns what it detects as a flat list of `Block`s which refer to each other by ID:
+//! a `LINE` lists the `WORD`s it is made of as its `CHILD` relationships, a `TABLE` lists its
+//! `CELL`s, and a `CELL` the words and selection elements inside it. `Document::from_blocks`
+//! follows those relationships, grouping lines and tables by the page they are on.
+
+use std::collections::HashMap;
+
+use crate::generated::{Block, BlockType, Geometry, RelationshipType, SelectionStatus};
+
+/// A word of a line or a table cell.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Word {
+    /// The text of the word
+    pub text: String,
+    /// How confident Textract is that the text is right, as a percentage
+    pub confidence: Option<f32>,
+    /// Where the word is on the page
+    pub geometry: Option<Geometry>,
+}
+
+/// A line of text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Line {
+    /// The text of the line
+    pub text: String,
+    /// How confident Textract is that the text is right, as a percentage
+    pub confidence: Option<f32>,
+    /// Where the line is on the page
+    pub geometry: Option<Geometry>,
+    /// The words of the line
+    pub words: Vec<Word>,
+}
+
+/// A cell of a table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cell {
+    /// The row the cell starts in, counting from 1
+    pub row_index: usize,
+    /// The column the cell starts in, counting from 1
+    pub column_index: usize,
+    /// How many rows the cell spans
+    pub row_span: usize,
+    /// How many columns the cell spans
+    pub column_span: usize,
+    /// The words in the cell
+    pub words: Vec<Word>,
+    /// Whether the selection element of the cell, such as a checkbox, is selected, if it holds one
+    pub selection_status: Option<SelectionStatus>,
+    /// How confident Textract is that the cell is right, as a percentage
+    pub confidence: Option<f32>,
+}
+
+impl Cell {
+    /// The words in the cell, separated by spaces.
+    pub fn text(&self) -> String {
+        join_words(&self.words)
+    }
+}
+
+/// A table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Table {
+    /// Where the table is on the page
+    pub geometry: Option<Geometry>,
+    /// The cells of the table, row by row
+    pub cells: Vec<Cell>,
+}
+
+impl Table {
+    /// The number of rows of the table.
+    pub fn row_count(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|cell| cell.row_index + cell.row_span.max(1) - 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The number of columns of the table.
+    pub fn column_count(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|cell| cell.column_index + cell.column_span.max(1) - 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The cell starting at `row_index` and `column_index`, both counting from 1.
+    pub fn cell(&self, row_index: usize, column_index: usize) -> Option<&Cell> {
+        self.cells
+            .iter()
+            .find(|cell| cell.row_index == row_index && cell.column_index == column_index)
+    }
+
+    /// The text of the table's cells as a grid of rows. Positions not covered by the start of a
+    /// cell, e.g. the rest of a merged cell, are empty.
+    pub fn rows(&self) -> Vec<Vec<String>> {
+        let mut rows = vec![vec![String::new(); self.column_count()]; self.row_count()];
+        for cell in &self.cells {
+            if cell.row_index == 0 || cell.column_index == 0 {
+                continue;
+            }
+            rows[cell.row_index - 1][cell.column_index - 1] = cell.text();
+        }
+        rows
+    }
+}
+
+/// A page of a document.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Page {
+    /// The number of the page, counting from 1
+    pub number: i64,
+    /// The lines of text on the page, in reading order
+    pub lines: Vec<Line>,
+    /// The tables on the page
+    pub tables: Vec<Table>,
+}
+
+impl Page {
+    /// The lines of text on the page, separated by newlines.
+    pub fn text(&self) -> String {
+        join_lines(self.lines.iter())
+    }
+}
+
+/// A document reassembled from the blocks Textract returned for it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Document {
+    pages: Vec<Page>,
+    blocks: Vec<Block>,
+    index: HashMap<String, usize>,
+}
+
+impl Document {
+    /// Reassembles a document from its blocks, e.g. every page of the results of a
+    /// `GetDocumentAnalysis` job.
+    pub fn from_blocks(blocks: Vec<Block>) -> Document {
+        let index = blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(position, block)| Some((block.id.clone()?, position)))
+            .collect::<HashMap<_, _>>();
+        let mut document = Document {
+            pages: Vec::new(),
+            blocks,
+            index,
+        };
+        let mut pages: Vec<Page> = Vec::new();
+        for block in &document.blocks {
+            let number = block.page.unwrap_or(1);
+            let page = match pages.iter().position(|page| page.number == number) {
+                Some(position) => &mut pages[position],
+                None => {
+                    pages.push(Page {
+                        number,
+                        ..Default::default()
+                    });
+                    pages.last_mut().unwrap()
+                }
+            };
+            match block.block_type {
+                Some(BlockType::Line) => page.lines.push(document.line(block)),
+                Some(BlockType::Table) => page.tables.push(document.table(block)),
+                _ => {}
+            }
+        }
+        pages.sort_by_key(|page| page.number);
+        document.pages = pages;
+        document
+    }
+
+    /// The pages of the document, in order.
+    pub fn pages(&self) -> &[Page] {
+        &self.pages
+    }
+
+    /// Every block of the document, as Textract returned them.
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
+    /// The block with the ID `id`.
+    pub fn block(&self, id: &str) -> Option<&Block> {
+        self.index.get(id).map(|&position| &self.blocks[position])
+    }
+
+    /// The tables of every page, in order.
+    pub fn tables(&self) -> impl Iterator<Item = &Table> {
+        self.pages.iter().flat_map(|page| page.tables.iter())
+    }
+
+    /// The lines of text of every page, separated by newlines.
+    pub fn text(&self) -> String {
+        join_lines(self.pages.iter().flat_map(|page| page.lines.iter()))
+    }
+
+    /// The blocks `block` lists as its children.
+    fn children<'a>(&'a self, block: &'a Block) -> impl Iterator<Item = &'a Block> + 'a {
+        block
+            .relationships
+            .iter()
+            .flatten()
+            .filter(|relationship| relationship.type_ == Some(RelationshipType::Child))
+            .flat_map(|relationship| relationship.ids.iter().flatten())
+            .filter_map(move |id| self.block(id))
+    }
+
+    fn line(&self, block: &Block) -> Line {
+        Line {
+            text: block.text.clone().unwrap_or_default(),
+            confidence: block.confidence,
+            geometry: block.geometry.clone(),
+            words: self.children(block).filter_map(word).collect(),
+        }
+    }
+
+    fn table(&self, block: &Block) -> Table {
+        Table {
+            geometry: block.geometry.clone(),
+            cells: self
+                .children(block)
+                .filter(|child| is_type(child, BlockType::Cell))
+                .map(|child| self.cell(child))
+                .collect(),
+        }
+    }
+
+    fn cell(&self, block: &Block) -> Cell {
+        let selection_status = self
+            .children(block)
+            .filter(|child| is_type(child, BlockType::SelectionElement))
+            .find_map(|child| child.selection_status.clone());
+        Cell {
+            row_index: index(block.row_index),
+            column_index: index(block.column_index),
+            row_span: index(block.row_span).max(1),
+            column_span: index(block.column_span).max(1),
+            words: self.children(block).filter_map(word).collect(),
+            selection_status,
+            confidence: block.confidence,
+        }
+    }
+}
+
+fn is_type(block: &Block, block_type: BlockType) -> bool {
+    block.block_type == Some(block_type)
+}
+
+fn word(block: &Block) -> Option<Word> {
+    if !is_type(block, BlockType::Word) {
+        return None;
+    }
+    Some(Word {
+        text: block.text.clone().unwrap_or_default(),
+        confidence: block.confidence,
+        geometry: block.geometry.clone(),
+    })
+}
+
+fn index(value: Option<i64>) -> usize {
+    value.filter(|&value| value > 0).unwrap_or(0) as usize
+}
+
+fn join_words(words: &[Word]) -> String {
+    words
+        .iter()
+        .map(|word| word.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn join_lines<'a>(lines: impl Iterator<Item = &'a Line>) -> String {
+    lines
+        .map(|line| line.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::Relationship;
+
+    fn block(
+        id: &str,
+        block_type: BlockType,
+        page: i64,
+        text: Option<&str>,
+        children: &[&str],
+    ) -> Block {
+        Block {
+            id: Some(id.to_owned()),
+            block_type: Some(block_type),
+            page: Some(page),
+            text: text.map(str::to_owned),
+            relationships: if children.is_empty() {
+                None
+            } else {
+                Some(vec![Relationship {
+                    type_: Some(RelationshipType::Child),
+                    ids: Some(children.iter().map(|&id| id.to_owned()).collect()),
+                }])
+            },
+            ..Default::default()
+        }
+    }
+
+    fn cell(id: &str, row: i64, column: i64, children: &[&str]) -> Block {
+        Block {
+            row_index: Some(row),
+            column_index: Some(column),
+            row_span: Some(1),
+            column_span: Some(1),
+            ..block(id, BlockType::Cell, 2, None, children)
+        }
+    }
+
+    #[test]
+    fn reassembles_lines_and_tables_by_page() {
+        let blocks = vec![
+            block("p1", BlockType::Page, 1, None, &["l1"]),
+            block("l1", BlockType::Line, 1, Some("Invoice 42"), &["w1", "w2"]),
+            block("w1", BlockType::Word, 1, Some("Invoice"), &[]),
+            block("w2", BlockType::Word, 1, Some("42"), &[]),
+            block("p2", BlockType::Page, 2, None, &["t1"]),
+            block("t1", BlockType::Table, 2, None, &["c1", "c2", "c3", "c4"]),
+            cell("c1", 1, 1, &["w3"]),
+            cell("c2", 1, 2, &["w4", "w5"]),
+            cell("c3", 2, 1, &["w6"]),
+            cell("c4", 2, 2, &["s1"]),
+            block("w3", BlockType::Word, 2, Some("Item"), &[]),
+            block("w4", BlockType::Word, 2, Some("Paid"), &[]),
+            block("w5", BlockType::Word, 2, Some("in full"), &[]),
+            block("w6", BlockType::Word, 2, Some("Widget"), &[]),
+            Block {
+                selection_status: Some(SelectionStatus::Selected),
+                ..block("s1", BlockType::SelectionElement, 2, None, &[])
+            },
+        ];
+
+        let document = Document::from_blocks(blocks);
+
+        assert_eq!(document.pages().len(), 2);
+        let first = &document.pages()[0];
+        assert_eq!(first.number, 1);
+        assert_eq!(first.text(), "Invoice 42");
+        assert_eq!(first.lines[0].words.len(), 2);
+        assert_eq!(document.text(), "Invoice 42");
+
+        let table = &document.pages()[1].tables[0];
+        assert_eq!((table.row_count(), table.column_count()), (2, 2));
+        assert_eq!(
+            table.rows(),
+            vec![
+                vec!["Item".to_owned(), "Paid in full".to_owned()],
+                vec!["Widget".to_owned(), String::new()],
+            ]
+        );
+        assert_eq!(
+            table.cell(2, 2).unwrap().selection_status,
+            Some(SelectionStatus::Selected)
+        );
+        assert_eq!(document.tables().count(), 1);
+        assert_eq!(
+            document.block("w6").unwrap().text,
+            Some("Widget".to_owned())
+        );
+    }
+
+    #[test]
+    fn ignores_dangling_relationships() {
+        let document = Document::from_blocks(vec![block(
+            "l1",
+            BlockType::Line,
+            1,
+            Some("Total"),
+            &["missing"],
+        )]);
+        assert_eq!(document.pages()[0].lines[0].words, vec![]);
+        assert_eq!(document.text(), "Total");
+    }
+}
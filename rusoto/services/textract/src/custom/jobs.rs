@@ -0,0 +1,528 @@
+//! Running asynchronous document analysis and text detection jobs.
+//!
+//! Multi-page documents have to be processed with `StartDocumentAnalysis` or
+//! `StartDocumentTextDetection`, which only queue a job. Once the job is done its blocks are read
+//! a page at a time with `GetDocumentAnalysis` or `GetDocumentTextDetection`. `DocumentJobExt`
+//! starts jobs, waits for them by polling, and reads every page of their results, reassembled into
+//! a `Document`.
+//!
+//! Instead of polling, jobs can publish to an SNS topic when they finish: set the
+//! `notification_channel` of the request, and with the `notifications` feature enabled wait for
+//! the SQS queue subscribed to the topic to receive the notification with
+//! `notifications::wait_for_job`, then read the results with `analysis_results` or
+//! `text_detection_results`.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use rusoto_core::Region;
+//! use rusoto_textract::jobs::{DocumentJobExt, JobConfig};
+//! use rusoto_textract::{
+//!     DocumentLocation, FeatureType, S3Object, StartDocumentAnalysisRequest, TextractClient,
+//! };
+//!
+//! let client = TextractClient::new(Region::UsEast1);
+//! let request = StartDocumentAnalysisRequest {
+//!     document_location: DocumentLocation {
+//!         s3_object: Some(S3Object {
+//!             bucket: Some("my-invoices".to_owned()),
+//!             name: Some("2019/invoice-42.pdf".to_owned()),
+//!             ..Default::default()
+//!         }),
+//!     },
+//!     feature_types: vec![FeatureType::Tables],
+//!     ..Default::default()
+//! };
+//! let results = client
+//!     .run_document_analysis(request, JobConfig::default())
+//!     .sync()
+//!     .unwrap();
+//! for table in results.document.tables() {
+//!     println!("{:?}", table.rows());
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use futures::{stream, Future, Stream};
+use rusoto_core::waiter::{poll_until, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::custom::document::Document;
+use crate::generated::{
+    Block, GetDocumentAnalysisError, GetDocumentAnalysisRequest, GetDocumentTextDetectionError,
+    GetDocumentTextDetectionRequest, StartDocumentAnalysisError, StartDocumentAnalysisRequest,
+    StartDocumentTextDetectionError, StartDocumentTextDetectionRequest, Textract, Warning,
+};
+
+/// The most blocks `GetDocumentAnalysis` and `GetDocumentTextDetection` return per page.
+const MAX_PAGE_SIZE: i64 = 1000;
+
+/// The status of a job.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JobStatus {
+    /// `IN_PROGRESS`
+    InProgress,
+    /// `SUCCEEDED`
+    Succeeded,
+    /// `PARTIAL_SUCCESS`: some pages couldn't be processed, see the warnings of the results
+    PartialSuccess,
+    /// `FAILED`
+    Failed,
+}
+
+impl JobStatus {
+    /// Parses a status as Textract and its notifications write it.
+    pub fn parse(status: &str) -> Option<JobStatus> {
+        match status {
+            "IN_PROGRESS" => Some(JobStatus::InProgress),
+            "SUCCEEDED" => Some(JobStatus::Succeeded),
+            "PARTIAL_SUCCESS" => Some(JobStatus::PartialSuccess),
+            "FAILED" => Some(JobStatus::Failed),
+            _ => None,
+        }
+    }
+
+    /// Whether the job has finished, successfully or not.
+    pub fn is_finished(self) -> bool {
+        self != JobStatus::InProgress
+    }
+}
+
+/// Errors returned by `DocumentJobExt`.
+#[derive(Debug, PartialEq)]
+pub enum DocumentJobError {
+    /// Starting a document analysis job failed.
+    StartDocumentAnalysis(StartDocumentAnalysisError),
+    /// Starting a text detection job failed.
+    StartDocumentTextDetection(StartDocumentTextDetectionError),
+    /// Reading the status or results of a document analysis job failed.
+    GetDocumentAnalysis(GetDocumentAnalysisError),
+    /// Reading the status or results of a text detection job failed.
+    GetDocumentTextDetection(GetDocumentTextDetectionError),
+    /// The job failed.
+    JobFailed {
+        /// The ID of the job
+        job_id: String,
+        /// Why the job failed
+        status_message: Option<String>,
+    },
+    /// The job was still in progress when the waiter gave up.
+    Timeout(String),
+}
+
+impl fmt::Display for DocumentJobError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DocumentJobError::JobFailed {
+                ref job_id,
+                ref status_message,
+            } => write!(
+                f,
+                "Job {} failed: {}",
+                job_id,
+                status_message
+                    .as_ref()
+                    .map_or("no reason given", String::as_str)
+            ),
+            DocumentJobError::Timeout(ref job_id) => {
+                write!(f, "{}: {}", self.description(), job_id)
+            }
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for DocumentJobError {
+    fn description(&self) -> &str {
+        match *self {
+            DocumentJobError::StartDocumentAnalysis(ref cause) => cause.description(),
+            DocumentJobError::StartDocumentTextDetection(ref cause) => cause.description(),
+            DocumentJobError::GetDocumentAnalysis(ref cause) => cause.description(),
+            DocumentJobError::GetDocumentTextDetection(ref cause) => cause.description(),
+            DocumentJobError::JobFailed { .. } => "Job failed",
+            DocumentJobError::Timeout(_) => "Job still in progress",
+        }
+    }
+}
+
+/// How `DocumentJobExt` waits for jobs and reads their results.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobConfig {
+    /// How often, and for how long, jobs are polled. Defaults to every 5 seconds for up to an
+    /// hour.
+    pub waiter: WaiterConfig,
+    /// How many blocks to read per page of results. Defaults to the maximum, 1000.
+    pub page_size: i64,
+}
+
+impl Default for JobConfig {
+    fn default() -> JobConfig {
+        JobConfig {
+            waiter: WaiterConfig::new(Duration::from_secs(5), 720),
+            page_size: MAX_PAGE_SIZE,
+        }
+    }
+}
+
+/// The results of a finished job.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobResults {
+    /// The ID of the job
+    pub job_id: String,
+    /// `Succeeded` or `PartialSuccess`
+    pub status: JobStatus,
+    /// The number of pages of the document
+    pub pages: Option<i64>,
+    /// The pages which couldn't be processed, if the job only partially succeeded
+    pub warnings: Vec<Warning>,
+    /// The blocks of every page of results, reassembled
+    pub document: Document,
+}
+
+/// The two kinds of jobs, which only differ in the operations that start them and read their
+/// results.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum JobKind {
+    Analysis,
+    TextDetection,
+}
+
+/// A page of results, of either kind of job.
+struct ResultsPage {
+    status: Option<String>,
+    status_message: Option<String>,
+    pages: Option<i64>,
+    warnings: Vec<Warning>,
+    blocks: Vec<Block>,
+    next_token: Option<String>,
+}
+
+type JobFuture<T> = Box<dyn Future<Item = T, Error = RusotoError<DocumentJobError>> + Send>;
+
+/// Starts Textract jobs, waits for them and reads their results.
+pub trait DocumentJobExt: Textract + Clone + Send + Sized + 'static {
+    /// Starts a document analysis job, returning its ID.
+    fn start_analysis(
+        &self,
+        input: StartDocumentAnalysisRequest,
+    ) -> RusotoFuture<String, DocumentJobError> {
+        let future = self
+            .start_document_analysis(input)
+            .map_err(|e| e.map_service(DocumentJobError::StartDocumentAnalysis))
+            .and_then(|output| {
+                output
+                    .job_id
+                    .ok_or_else(|| RusotoError::ParseError("Missing JobId".to_owned()))
+            });
+        RusotoFuture::from_future(future)
+    }
+
+    /// Starts a text detection job, returning its ID.
+    fn start_text_detection(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> RusotoFuture<String, DocumentJobError> {
+        let future = self
+            .start_document_text_detection(input)
+            .map_err(|e| e.map_service(DocumentJobError::StartDocumentTextDetection))
+            .and_then(|output| {
+                output
+                    .job_id
+                    .ok_or_else(|| RusotoError::ParseError("Missing JobId".to_owned()))
+            });
+        RusotoFuture::from_future(future)
+    }
+
+    /// Polls the document analysis job `job_id` until it finishes, then reads every page of
+    /// its results.
+    fn wait_for_analysis(
+        &self,
+        job_id: &str,
+        config: JobConfig,
+    ) -> RusotoFuture<JobResults, DocumentJobError> {
+        RusotoFuture::from_future(wait_for_job(
+            self.clone(),
+            JobKind::Analysis,
+            job_id.to_owned(),
+            config,
+        ))
+    }
+
+    /// Polls the text detection job `job_id` until it finishes, then reads every page of its
+    /// results.
+    fn wait_for_text_detection(
+        &self,
+        job_id: &str,
+        config: JobConfig,
+    ) -> RusotoFuture<JobResults, DocumentJobError> {
+        RusotoFuture::from_future(wait_for_job(
+            self.clone(),
+            JobKind::TextDetection,
+            job_id.to_owned(),
+            config,
+        ))
+    }
+
+    /// Reads every page of the results of the finished document analysis job `job_id`, e.g.
+    /// once its completion has been notified.
+    fn analysis_results(&self, job_id: &str) -> RusotoFuture<JobResults, DocumentJobError> {
+        RusotoFuture::from_future(job_results(
+            self.clone(),
+            JobKind::Analysis,
+            job_id.to_owned(),
+            MAX_PAGE_SIZE,
+        ))
+    }
+
+    /// Reads every page of the results of the finished text detection job `job_id`, e.g. once
+    /// its completion has been notified.
+    fn text_detection_results(&self, job_id: &str) -> RusotoFuture<JobResults, DocumentJobError> {
+        RusotoFuture::from_future(job_results(
+            self.clone(),
+            JobKind::TextDetection,
+            job_id.to_owned(),
+            MAX_PAGE_SIZE,
+        ))
+    }
+
+    /// Starts a document analysis job, waits for it to finish and reads its results.
+    fn run_document_analysis(
+        &self,
+        input: StartDocumentAnalysisRequest,
+        config: JobConfig,
+    ) -> RusotoFuture<JobResults, DocumentJobError> {
+        let client = self.clone();
+        let future = self
+            .start_analysis(input)
+            .and_then(move |job_id| wait_for_job(client, JobKind::Analysis, job_id, config));
+        RusotoFuture::from_future(future)
+    }
+
+    /// Starts a text detection job, waits for it to finish and reads its results.
+    fn run_text_detection(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+        config: JobConfig,
+    ) -> RusotoFuture<JobResults, DocumentJobError> {
+        let client = self.clone();
+        let future = self
+            .start_text_detection(input)
+            .and_then(move |job_id| wait_for_job(client, JobKind::TextDetection, job_id, config));
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: Textract + Clone + Send + Sized + 'static> DocumentJobExt for T {}
+
+fn get_page<C>(
+    client: &C,
+    kind: JobKind,
+    job_id: String,
+    page_size: i64,
+    next_token: Option<String>,
+) -> JobFuture<ResultsPage>
+where
+    C: Textract,
+{
+    match kind {
+        JobKind::Analysis => Box::new(
+            client
+                .get_document_analysis(GetDocumentAnalysisRequest {
+                    job_id,
+                    max_results: Some(page_size),
+                    next_token,
+                })
+                .map_err(|e| e.map_service(DocumentJobError::GetDocumentAnalysis))
+                .map(|output| ResultsPage {
+                    status: output.job_status.map(String::from),
+                    status_message: output.status_message,
+                    pages: output.document_metadata.and_then(|metadata| metadata.pages),
+                    warnings: output.warnings.unwrap_or_default(),
+                    blocks: output.blocks.unwrap_or_default(),
+                    next_token: output.next_token,
+                }),
+        ),
+        JobKind::TextDetection => Box::new(
+            client
+                .get_document_text_detection(GetDocumentTextDetectionRequest {
+                    job_id,
+                    max_results: Some(page_size),
+                    next_token,
+                })
+                .map_err(|e| e.map_service(DocumentJobError::GetDocumentTextDetection))
+                .map(|output| ResultsPage {
+                    status: output.job_status.map(String::from),
+                    status_message: output.status_message,
+                    pages: output.document_metadata.and_then(|metadata| metadata.pages),
+                    warnings: output.warnings.unwrap_or_default(),
+                    blocks: output.blocks.unwrap_or_default(),
+                    next_token: output.next_token,
+                }),
+        ),
+    }
+}
+
+/// The status of the job a page of results is for, failing if the job failed.
+fn finished_status(
+    job_id: &str,
+    page: &ResultsPage,
+) -> Result<JobStatus, RusotoError<DocumentJobError>> {
+    let status = page.status.as_ref().map(String::as_str).unwrap_or_default();
+    match JobStatus::parse(status) {
+        Some(JobStatus::Failed) => Err(RusotoError::Service(DocumentJobError::JobFailed {
+            job_id: job_id.to_owned(),
+            status_message: page.status_message.clone(),
+        })),
+        Some(status) => Ok(status),
+        None => Err(RusotoError::ParseError(format!(
+            "Unknown job status: {:?}",
+            status
+        ))),
+    }
+}
+
+/// Polls the job until it finishes, keeping the first page of results the last poll returned.
+fn wait_for_job<C>(
+    client: C,
+    kind: JobKind,
+    job_id: String,
+    config: JobConfig,
+) -> JobFuture<JobResults>
+where
+    C: Textract + Clone + Send + 'static,
+{
+    let timeout = RusotoError::Service(DocumentJobError::Timeout(job_id.clone()));
+    let page_size = config.page_size;
+    let poll_client = client.clone();
+    let poll_job_id = job_id.clone();
+    let first_page = poll_until(
+        config.waiter,
+        move || {
+            let job_id = poll_job_id.clone();
+            get_page(&poll_client, kind, job_id.clone(), page_size, None).and_then(move |page| {
+                match finished_status(&job_id, &page)? {
+                    JobStatus::InProgress => Ok(None),
+                    status => Ok(Some((status, page))),
+                }
+            })
+        },
+        timeout,
+    );
+    Box::new(first_page.and_then(move |(status, page)| {
+        collect_results(client, kind, job_id, page_size, status, page)
+    }))
+}
+
+/// Reads the results of a job which has finished.
+fn job_results<C>(client: C, kind: JobKind, job_id: String, page_size: i64) -> JobFuture<JobResults>
+where
+    C: Textract + Clone + Send + 'static,
+{
+    let future = get_page(&client, kind, job_id.clone(), page_size, None).and_then(move |page| {
+        match finished_status(&job_id, &page)? {
+            JobStatus::InProgress => Err(RusotoError::Validation(format!(
+                "Job {} is still in progress",
+                job_id
+            ))),
+            status => Ok(collect_results(
+                client, kind, job_id, page_size, status, page,
+            )),
+        }
+    });
+    Box::new(future.flatten())
+}
+
+/// Reads the pages of results following `first_page`, and reassembles their blocks.
+fn collect_results<C>(
+    client: C,
+    kind: JobKind,
+    job_id: String,
+    page_size: i64,
+    status: JobStatus,
+    first_page: ResultsPage,
+) -> JobFuture<JobResults>
+where
+    C: Textract + Clone + Send + 'static,
+{
+    let pages = first_page.pages;
+    let mut warnings = first_page.warnings;
+    let mut blocks = first_page.blocks;
+    let page_job_id = job_id.clone();
+    let rest = stream::unfold(first_page.next_token, move |next_token| {
+        let next_token = next_token?;
+        Some(
+            get_page(
+                &client,
+                kind,
+                page_job_id.clone(),
+                page_size,
+                Some(next_token),
+            )
+            .map(|page| {
+                let next_token = page.next_token.clone();
+                (page, next_token)
+            }),
+        )
+    });
+    let future = rest.collect().map(move |rest| {
+        for page in rest {
+            warnings.extend(page.warnings);
+            blocks.extend(page.blocks);
+        }
+        JobResults {
+            job_id,
+            status,
+            pages,
+            warnings,
+            document: Document::from_blocks(blocks),
+        }
+    });
+    Box::new(future)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(status: &str) -> ResultsPage {
+        ResultsPage {
+            status: Some(status.to_owned()),
+            status_message: Some("Unsupported document".to_owned()),
+            pages: None,
+            warnings: Vec::new(),
+            blocks: Vec::new(),
+            next_token: None,
+        }
+    }
+
+    #[test]
+    fn parses_job_statuses() {
+        assert_eq!(JobStatus::parse("SUCCEEDED"), Some(JobStatus::Succeeded));
+        assert_eq!(
+            JobStatus::parse("PARTIAL_SUCCESS"),
+            Some(JobStatus::PartialSuccess)
+        );
+        assert_eq!(JobStatus::parse("PENDING"), None);
+        assert!(!JobStatus::InProgress.is_finished());
+        assert!(JobStatus::Failed.is_finished());
+    }
+
+    #[test]
+    fn failed_jobs_are_errors() {
+        assert_eq!(
+            finished_status("job", &page("IN_PROGRESS")).unwrap(),
+            JobStatus::InProgress
+        );
+        assert_eq!(
+            finished_status("job", &page("FAILED")).unwrap_err(),
+            RusotoError::Service(DocumentJobError::JobFailed {
+                job_id: "job".to_owned(),
+                status_message: Some("Unsupported document".to_owned()),
+            })
+        );
+        assert!(finished_status("job", &page("")).is_err());
+    }
+}
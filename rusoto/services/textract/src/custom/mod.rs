@@ -0,0 +1,7 @@
+/// Reassembling the blocks Textract returns into pages, lines and tables
+pub mod document;
+/// Running asynchronous jobs and reading every page of their results
+pub mod jobs;
+/// Waiting for jobs to finish with SQS
+#[cfg(feature = "notifications")]
+pub mod notifications;
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AnalyzeDocumentRequest {
@@ -30,7 +31,47 @@ pub struct AnalyzeDocumentRequest {
     pub document: Document,
     /// <p>A list of the types of analysis to perform. Add TABLES to the list to return information about the tables detected in the input document. Add FORMS to return detected fields and the associated text. To perform both types of analysis, add TABLES and FORMS to <code>FeatureTypes</code>.</p>
     #[serde(rename = "FeatureTypes")]
-    pub feature_types: Vec<String>,
+    pub feature_types: Vec<FeatureType>,
+}
+
+impl AnalyzeDocumentRequest {
+    /// Returns a builder for `AnalyzeDocumentRequest`, checking its required members when built.
+    pub fn builder() -> AnalyzeDocumentRequestBuilder {
+        AnalyzeDocumentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AnalyzeDocumentRequest`](struct.AnalyzeDocumentRequest.html).
+#[derive(Default, Debug)]
+pub struct AnalyzeDocumentRequestBuilder {
+    document: Option<Document>,
+    feature_types: Option<Vec<FeatureType>>,
+}
+
+impl AnalyzeDocumentRequestBuilder {
+    /// Sets the [`document`](struct.AnalyzeDocumentRequest.html#structfield.document) member.
+    pub fn document<T: Into<Document>>(mut self, value: T) -> Self {
+        self.document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feature_types`](struct.AnalyzeDocumentRequest.html#structfield.feature_types) member.
+    pub fn feature_types<T: Into<Vec<FeatureType>>>(mut self, value: T) -> Self {
+        self.feature_types = Some(value.into());
+        self
+    }
+
+    /// Builds the `AnalyzeDocumentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AnalyzeDocumentRequest, ::rusoto_core::BuildError> {
+        Ok(AnalyzeDocumentRequest {
+            document: self
+                .document
+                .ok_or(::rusoto_core::BuildError::missing("document"))?,
+            feature_types: self
+                .feature_types
+                .ok_or(::rusoto_core::BuildError::missing("feature_types"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -53,7 +94,7 @@ pub struct Block {
     /// <p><p>The type of text that&#39;s recognized in a block. In text-detection operations, the following types are returned:</p> <ul> <li> <p> <i>PAGE</i> - Contains a list of the LINE Block objects that are detected on a document page.</p> </li> <li> <p> <i>WORD</i> - A word detected on a document page. A word is one or more ISO basic Latin script characters that aren&#39;t separated by spaces.</p> </li> <li> <p> <i>LINE</i> - A string of tab-delimited, contiguous words that&#39;s detected on a document page.</p> </li> </ul> <p>In text analysis operations, the following types are returned:</p> <ul> <li> <p> <i>PAGE</i> - Contains a list of child Block objects that are detected on a document page.</p> </li> <li> <p> <i>KEY<em>VALUE</em>SET</i> - Stores the KEY and VALUE Block objects for a field that&#39;s detected on a document page. Use the <code>EntityType</code> field to determine if a KEY<em>VALUE</em>SET object is a KEY Block object or a VALUE Block object. </p> </li> <li> <p> <i>WORD</i> - A word detected on a document page. A word is one or more ISO basic Latin script characters that aren&#39;t separated by spaces that&#39;s detected on a document page.</p> </li> <li> <p> <i>LINE</i> - A string of tab-delimited, contiguous words that&#39;s detected on a document page.</p> </li> <li> <p> <i>TABLE</i> - A table that&#39;s detected on a document page. A table is any grid-based information with 2 or more rows or columns with a cell span of 1 row and 1 column each. </p> </li> <li> <p> <i>CELL</i> - A cell within a detected table. The cell is the parent of the block that contains the text in the cell.</p> </li> <li> <p> <i>SELECTION_ELEMENT</i> - A selectable element such as a radio button or checkbox that&#39;s detected on a document page. Use the value of <code>SelectionStatus</code> to determine the status of the selection element.</p> </li> </ul></p>
     #[serde(rename = "BlockType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub block_type: Option<String>,
+    pub block_type: Option<BlockType>,
     /// <p>The column in which a table cell appears. The first column position is 1. <code>ColumnIndex</code> isn't returned by <code>DetectDocumentText</code> and <code>GetDocumentTextDetection</code>.</p>
     #[serde(rename = "ColumnIndex")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -69,7 +110,7 @@ pub struct Block {
     /// <p>The type of entity. The following can be returned:</p> <ul> <li> <p> <i>KEY</i> - An identifier for a field on the document.</p> </li> <li> <p> <i>VALUE</i> - The field text.</p> </li> </ul> <p> <code>EntityTypes</code> isn't returned by <code>DetectDocumentText</code> and <code>GetDocumentTextDetection</code>.</p>
     #[serde(rename = "EntityTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub entity_types: Option<Vec<String>>,
+    pub entity_types: Option<Vec<EntityType>>,
     /// <p>The location of the recognized text on the image. It includes an axis-aligned, coarse bounding box that surrounds the text, and a finer-grain polygon for more accurate spatial information. </p>
     #[serde(rename = "Geometry")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -97,13 +138,173 @@ pub struct Block {
     /// <p>The selection status of a selectable element such as a radio button or checkbox. </p>
     #[serde(rename = "SelectionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub selection_status: Option<String>,
+    pub selection_status: Option<SelectionStatus>,
     /// <p>The word or line of text that's recognized by Amazon Textract. </p>
     #[serde(rename = "Text")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 }
 
+/// <p><p>The type of text that&#39;s recognized in a block. In text-detection operations, the following types are returned:</p> <ul> <li> <p> <i>PAGE</i> - Contains a list of the LINE Block objects that are detected on a document page.</p> </li> <li> <p> <i>WORD</i> - A word detected on a document page. A word is one or more ISO basic Latin script characters that aren&#39;t separated by spaces.</p> </li> <li> <p> <i>LINE</i> - A string of tab-delimited, contiguous words that&#39;s detected on a document page.</p> </li> </ul> <p>In text analysis operations, the following types are returned:</p> <ul> <li> <p> <i>PAGE</i> - Contains a list of child Block objects that are detected on a document page.</p> </li> <li> <p> <i>KEY<em>VALUE</em>SET</i> - Stores the KEY and VALUE Block objects for a field that&#39;s detected on a document page. Use the <code>EntityType</code> field to determine if a KEY<em>VALUE</em>SET object is a KEY Block object or a VALUE Block object. </p> </li> <li> <p> <i>WORD</i> - A word detected on a document page. A word is one or more ISO basic Latin script characters that aren&#39;t separated by spaces that&#39;s detected on a document page.</p> </li> <li> <p> <i>LINE</i> - A string of tab-delimited, contiguous words that&#39;s detected on a document page.</p> </li> <li> <p> <i>TABLE</i> - A table that&#39;s detected on a document page. A table is any grid-based information with 2 or more rows or columns with a cell span of 1 row and 1 column each. </p> </li> <li> <p> <i>CELL</i> - A cell within a detected table. The cell is the parent of the block that contains the text in the cell.</p> </li> <li> <p> <i>SELECTION_ELEMENT</i> - A selectable element such as a radio button or checkbox that&#39;s detected on a document page. Use the value of <code>SelectionStatus</code> to determine the status of the selection element.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BlockType {
+    /// <p><code>KEY_VALUE_SET</code></p>
+    KeyValueSet,
+    /// <p><code>PAGE</code></p>
+    Page,
+    /// <p><code>LINE</code></p>
+    Line,
+    /// <p><code>WORD</code></p>
+    Word,
+    /// <p><code>TABLE</code></p>
+    Table,
+    /// <p><code>CELL</code></p>
+    Cell,
+    /// <p><code>SELECTION_ELEMENT</code></p>
+    SelectionElement,
+    /// <p><code>MERGED_CELL</code></p>
+    MergedCell,
+    /// <p><code>TITLE</code></p>
+    Title,
+    /// <p><code>QUERY</code></p>
+    Query,
+    /// <p><code>QUERY_RESULT</code></p>
+    QueryResult,
+    /// <p><code>SIGNATURE</code></p>
+    Signature,
+    /// <p><code>TABLE_TITLE</code></p>
+    TableTitle,
+    /// <p><code>TABLE_FOOTER</code></p>
+    TableFooter,
+    /// <p><code>LAYOUT_TEXT</code></p>
+    LayoutText,
+    /// <p><code>LAYOUT_TITLE</code></p>
+    LayoutTitle,
+    /// <p><code>LAYOUT_HEADER</code></p>
+    LayoutHeader,
+    /// <p><code>LAYOUT_FOOTER</code></p>
+    LayoutFooter,
+    /// <p><code>LAYOUT_SECTION_HEADER</code></p>
+    LayoutSectionHeader,
+    /// <p><code>LAYOUT_PAGE_NUMBER</code></p>
+    LayoutPageNumber,
+    /// <p><code>LAYOUT_LIST</code></p>
+    LayoutList,
+    /// <p><code>LAYOUT_FIGURE</code></p>
+    LayoutFigure,
+    /// <p><code>LAYOUT_TABLE</code></p>
+    LayoutTable,
+    /// <p><code>LAYOUT_KEY_VALUE</code></p>
+    LayoutKeyValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BlockType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BlockType::KeyValueSet => "KEY_VALUE_SET",
+            BlockType::Page => "PAGE",
+            BlockType::Line => "LINE",
+            BlockType::Word => "WORD",
+            BlockType::Table => "TABLE",
+            BlockType::Cell => "CELL",
+            BlockType::SelectionElement => "SELECTION_ELEMENT",
+            BlockType::MergedCell => "MERGED_CELL",
+            BlockType::Title => "TITLE",
+            BlockType::Query => "QUERY",
+            BlockType::QueryResult => "QUERY_RESULT",
+            BlockType::Signature => "SIGNATURE",
+            BlockType::TableTitle => "TABLE_TITLE",
+            BlockType::TableFooter => "TABLE_FOOTER",
+            BlockType::LayoutText => "LAYOUT_TEXT",
+            BlockType::LayoutTitle => "LAYOUT_TITLE",
+            BlockType::LayoutHeader => "LAYOUT_HEADER",
+            BlockType::LayoutFooter => "LAYOUT_FOOTER",
+            BlockType::LayoutSectionHeader => "LAYOUT_SECTION_HEADER",
+            BlockType::LayoutPageNumber => "LAYOUT_PAGE_NUMBER",
+            BlockType::LayoutList => "LAYOUT_LIST",
+            BlockType::LayoutFigure => "LAYOUT_FIGURE",
+            BlockType::LayoutTable => "LAYOUT_TABLE",
+            BlockType::LayoutKeyValue => "LAYOUT_KEY_VALUE",
+            BlockType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BlockType {
+    fn from(value: &'a str) -> BlockType {
+        match value {
+            "KEY_VALUE_SET" => BlockType::KeyValueSet,
+            "PAGE" => BlockType::Page,
+            "LINE" => BlockType::Line,
+            "WORD" => BlockType::Word,
+            "TABLE" => BlockType::Table,
+            "CELL" => BlockType::Cell,
+            "SELECTION_ELEMENT" => BlockType::SelectionElement,
+            "MERGED_CELL" => BlockType::MergedCell,
+            "TITLE" => BlockType::Title,
+            "QUERY" => BlockType::Query,
+            "QUERY_RESULT" => BlockType::QueryResult,
+            "SIGNATURE" => BlockType::Signature,
+            "TABLE_TITLE" => BlockType::TableTitle,
+            "TABLE_FOOTER" => BlockType::TableFooter,
+            "LAYOUT_TEXT" => BlockType::LayoutText,
+            "LAYOUT_TITLE" => BlockType::LayoutTitle,
+            "LAYOUT_HEADER" => BlockType::LayoutHeader,
+            "LAYOUT_FOOTER" => BlockType::LayoutFooter,
+            "LAYOUT_SECTION_HEADER" => BlockType::LayoutSectionHeader,
+            "LAYOUT_PAGE_NUMBER" => BlockType::LayoutPageNumber,
+            "LAYOUT_LIST" => BlockType::LayoutList,
+            "LAYOUT_FIGURE" => BlockType::LayoutFigure,
+            "LAYOUT_TABLE" => BlockType::LayoutTable,
+            "LAYOUT_KEY_VALUE" => BlockType::LayoutKeyValue,
+            _ => BlockType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BlockType {
+    fn from(value: String) -> BlockType {
+        BlockType::from(value.as_str())
+    }
+}
+
+impl From<BlockType> for String {
+    fn from(value: BlockType) -> String {
+        match value {
+            BlockType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BlockType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BlockType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BlockType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BlockType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BlockType::from(value))
+    }
+}
+
 /// <p>The bounding box around the recognized text, key, value, table or table cell on a document page. The <code>left</code> (x-coordinate) and <code>top</code> (y-coordinate) are coordinates that represent the top and left sides of the bounding box. Note that the upper-left corner of the image is the origin (0,0). </p> <p>The <code>top</code> and <code>left</code> values returned are ratios of the overall document page size. For example, if the input image is 700 x 200 pixels, and the top-left coordinate of the bounding box is 350 x 50 pixels, the API returns a <code>left</code> value of 0.5 (350/700) and a <code>top</code> value of 0.25 (50/200).</p> <p>The <code>width</code> and <code>height</code> values represent the dimensions of the bounding box as a ratio of the overall document page dimension. For example, if the document page size is 700 x 200 pixels, and the bounding box width is 70 pixels, the width returned is 0.1. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -133,6 +334,38 @@ pub struct DetectDocumentTextRequest {
     pub document: Document,
 }
 
+impl DetectDocumentTextRequest {
+    /// Returns a builder for `DetectDocumentTextRequest`, checking its required members when built.
+    pub fn builder() -> DetectDocumentTextRequestBuilder {
+        DetectDocumentTextRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectDocumentTextRequest`](struct.DetectDocumentTextRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectDocumentTextRequestBuilder {
+    document: Option<Document>,
+}
+
+impl DetectDocumentTextRequestBuilder {
+    /// Sets the [`document`](struct.DetectDocumentTextRequest.html#structfield.document) member.
+    pub fn document<T: Into<Document>>(mut self, value: T) -> Self {
+        self.document = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectDocumentTextRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetectDocumentTextRequest, ::rusoto_core::BuildError> {
+        Ok(DetectDocumentTextRequest {
+            document: self
+                .document
+                .ok_or(::rusoto_core::BuildError::missing("document"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetectDocumentTextResponse {
@@ -183,6 +416,190 @@ pub struct DocumentMetadata {
     pub pages: Option<i64>,
 }
 
+/// <p>The type of entity. The following can be returned:</p> <ul> <li> <p> <i>KEY</i> - An identifier for a field on the document.</p> </li> <li> <p> <i>VALUE</i> - The field text.</p> </li> </ul> <p> <code>EntityTypes</code> isn't returned by <code>DetectDocumentText</code> and <code>GetDocumentTextDetection</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntityType {
+    /// <p><code>KEY</code></p>
+    Key,
+    /// <p><code>VALUE</code></p>
+    Value,
+    /// <p><code>COLUMN_HEADER</code></p>
+    ColumnHeader,
+    /// <p><code>TABLE_TITLE</code></p>
+    TableTitle,
+    /// <p><code>TABLE_FOOTER</code></p>
+    TableFooter,
+    /// <p><code>TABLE_SECTION_TITLE</code></p>
+    TableSectionTitle,
+    /// <p><code>TABLE_SUMMARY</code></p>
+    TableSummary,
+    /// <p><code>STRUCTURED_TABLE</code></p>
+    StructuredTable,
+    /// <p><code>SEMI_STRUCTURED_TABLE</code></p>
+    SemiStructuredTable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EntityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityType::Key => "KEY",
+            EntityType::Value => "VALUE",
+            EntityType::ColumnHeader => "COLUMN_HEADER",
+            EntityType::TableTitle => "TABLE_TITLE",
+            EntityType::TableFooter => "TABLE_FOOTER",
+            EntityType::TableSectionTitle => "TABLE_SECTION_TITLE",
+            EntityType::TableSummary => "TABLE_SUMMARY",
+            EntityType::StructuredTable => "STRUCTURED_TABLE",
+            EntityType::SemiStructuredTable => "SEMI_STRUCTURED_TABLE",
+            EntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EntityType {
+    fn from(value: &'a str) -> EntityType {
+        match value {
+            "KEY" => EntityType::Key,
+            "VALUE" => EntityType::Value,
+            "COLUMN_HEADER" => EntityType::ColumnHeader,
+            "TABLE_TITLE" => EntityType::TableTitle,
+            "TABLE_FOOTER" => EntityType::TableFooter,
+            "TABLE_SECTION_TITLE" => EntityType::TableSectionTitle,
+            "TABLE_SUMMARY" => EntityType::TableSummary,
+            "STRUCTURED_TABLE" => EntityType::StructuredTable,
+            "SEMI_STRUCTURED_TABLE" => EntityType::SemiStructuredTable,
+            _ => EntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityType {
+    fn from(value: String) -> EntityType {
+        EntityType::from(value.as_str())
+    }
+}
+
+impl From<EntityType> for String {
+    fn from(value: EntityType) -> String {
+        match value {
+            EntityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EntityType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EntityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EntityType::from(value))
+    }
+}
+
+/// <p>A list of the types of analysis to perform. Add TABLES to the list to return information about the tables detected in the input document. Add FORMS to return detected fields and the associated text. To perform both types of analysis, add TABLES and FORMS to <code>FeatureTypes</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FeatureType {
+    /// <p><code>TABLES</code></p>
+    Tables,
+    /// <p><code>FORMS</code></p>
+    Forms,
+    /// <p><code>QUERIES</code></p>
+    Queries,
+    /// <p><code>SIGNATURES</code></p>
+    Signatures,
+    /// <p><code>LAYOUT</code></p>
+    Layout,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FeatureType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FeatureType::Tables => "TABLES",
+            FeatureType::Forms => "FORMS",
+            FeatureType::Queries => "QUERIES",
+            FeatureType::Signatures => "SIGNATURES",
+            FeatureType::Layout => "LAYOUT",
+            FeatureType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FeatureType {
+    fn from(value: &'a str) -> FeatureType {
+        match value {
+            "TABLES" => FeatureType::Tables,
+            "FORMS" => FeatureType::Forms,
+            "QUERIES" => FeatureType::Queries,
+            "SIGNATURES" => FeatureType::Signatures,
+            "LAYOUT" => FeatureType::Layout,
+            _ => FeatureType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FeatureType {
+    fn from(value: String) -> FeatureType {
+        FeatureType::from(value.as_str())
+    }
+}
+
+impl From<FeatureType> for String {
+    fn from(value: FeatureType) -> String {
+        match value {
+            FeatureType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FeatureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FeatureType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FeatureType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FeatureType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FeatureType::from(value))
+    }
+}
+
 /// <p>Information about where a recognized text, key, value, table, or table cell is located on a document page.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -212,6 +629,54 @@ pub struct GetDocumentAnalysisRequest {
     pub next_token: Option<String>,
 }
 
+impl GetDocumentAnalysisRequest {
+    /// Returns a builder for `GetDocumentAnalysisRequest`, checking its required members when built.
+    pub fn builder() -> GetDocumentAnalysisRequestBuilder {
+        GetDocumentAnalysisRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDocumentAnalysisRequest`](struct.GetDocumentAnalysisRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDocumentAnalysisRequestBuilder {
+    job_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetDocumentAnalysisRequestBuilder {
+    /// Sets the [`job_id`](struct.GetDocumentAnalysisRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetDocumentAnalysisRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetDocumentAnalysisRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDocumentAnalysisRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDocumentAnalysisRequest, ::rusoto_core::BuildError> {
+        Ok(GetDocumentAnalysisRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDocumentAnalysisResponse {
@@ -226,7 +691,7 @@ pub struct GetDocumentAnalysisResponse {
     /// <p>The current status of the text detection job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>If the response is truncated, Amazon Textract returns this token. You can use this token in the subsequent request to retrieve the next set of text detection results.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -256,6 +721,54 @@ pub struct GetDocumentTextDetectionRequest {
     pub next_token: Option<String>,
 }
 
+impl GetDocumentTextDetectionRequest {
+    /// Returns a builder for `GetDocumentTextDetectionRequest`, checking its required members when built.
+    pub fn builder() -> GetDocumentTextDetectionRequestBuilder {
+        GetDocumentTextDetectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDocumentTextDetectionRequest`](struct.GetDocumentTextDetectionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDocumentTextDetectionRequestBuilder {
+    job_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetDocumentTextDetectionRequestBuilder {
+    /// Sets the [`job_id`](struct.GetDocumentTextDetectionRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetDocumentTextDetectionRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetDocumentTextDetectionRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDocumentTextDetectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDocumentTextDetectionRequest, ::rusoto_core::BuildError> {
+        Ok(GetDocumentTextDetectionRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDocumentTextDetectionResponse {
@@ -270,7 +783,7 @@ pub struct GetDocumentTextDetectionResponse {
     /// <p>The current status of the text detection job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>If the response is truncated, Amazon Textract returns this token. You can use this token in the subsequent request to retrieve the next set of text-detection results.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -285,6 +798,86 @@ pub struct GetDocumentTextDetectionResponse {
     pub warnings: Option<Vec<Warning>>,
 }
 
+/// <p>The current status of the text detection job.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobStatus {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>PARTIAL_SUCCESS</code></p>
+    PartialSuccess,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::InProgress => "IN_PROGRESS",
+            JobStatus::Succeeded => "SUCCEEDED",
+            JobStatus::Failed => "FAILED",
+            JobStatus::PartialSuccess => "PARTIAL_SUCCESS",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "SUCCEEDED" => JobStatus::Succeeded,
+            "FAILED" => JobStatus::Failed,
+            "PARTIAL_SUCCESS" => JobStatus::PartialSuccess,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        JobStatus::from(value.as_str())
+    }
+}
+
+impl From<JobStatus> for String {
+    fn from(value: JobStatus) -> String {
+        match value {
+            JobStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobStatus::from(value))
+    }
+}
+
 /// <p>The Amazon Simple Notification Service (Amazon SNS) topic to which Amazon Textract publishes the completion status of an asynchronous document operation, such as <a>StartDocumentTextDetection</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct NotificationChannel {
@@ -321,7 +914,107 @@ pub struct Relationship {
     /// <p>The type of relationship that the blocks in the IDs array have with the current block. The relationship can be <code>VALUE</code> or <code>CHILD</code>.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<RelationshipType>,
+}
+
+/// <p>The type of relationship that the blocks in the IDs array have with the current block. The relationship can be <code>VALUE</code> or <code>CHILD</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RelationshipType {
+    /// <p><code>VALUE</code></p>
+    Value,
+    /// <p><code>CHILD</code></p>
+    Child,
+    /// <p><code>COMPLEX_FEATURES</code></p>
+    ComplexFeatures,
+    /// <p><code>MERGED_CELL</code></p>
+    MergedCell,
+    /// <p><code>TITLE</code></p>
+    Title,
+    /// <p><code>ANSWER</code></p>
+    Answer,
+    /// <p><code>TABLE</code></p>
+    Table,
+    /// <p><code>TABLE_TITLE</code></p>
+    TableTitle,
+    /// <p><code>TABLE_FOOTER</code></p>
+    TableFooter,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RelationshipType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelationshipType::Value => "VALUE",
+            RelationshipType::Child => "CHILD",
+            RelationshipType::ComplexFeatures => "COMPLEX_FEATURES",
+            RelationshipType::MergedCell => "MERGED_CELL",
+            RelationshipType::Title => "TITLE",
+            RelationshipType::Answer => "ANSWER",
+            RelationshipType::Table => "TABLE",
+            RelationshipType::TableTitle => "TABLE_TITLE",
+            RelationshipType::TableFooter => "TABLE_FOOTER",
+            RelationshipType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RelationshipType {
+    fn from(value: &'a str) -> RelationshipType {
+        match value {
+            "VALUE" => RelationshipType::Value,
+            "CHILD" => RelationshipType::Child,
+            "COMPLEX_FEATURES" => RelationshipType::ComplexFeatures,
+            "MERGED_CELL" => RelationshipType::MergedCell,
+            "TITLE" => RelationshipType::Title,
+            "ANSWER" => RelationshipType::Answer,
+            "TABLE" => RelationshipType::Table,
+            "TABLE_TITLE" => RelationshipType::TableTitle,
+            "TABLE_FOOTER" => RelationshipType::TableFooter,
+            _ => RelationshipType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelationshipType {
+    fn from(value: String) -> RelationshipType {
+        RelationshipType::from(value.as_str())
+    }
+}
+
+impl From<RelationshipType> for String {
+    fn from(value: RelationshipType) -> String {
+        match value {
+            RelationshipType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RelationshipType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RelationshipType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelationshipType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RelationshipType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RelationshipType::from(value))
+    }
 }
 
 /// <p>The S3 bucket name and file name that identifies the document.</p> <p>The AWS Region for the S3 bucket that contains the document must match the Region that you use for Amazon Textract operations.</p> <p>For Amazon Textract to process a file in an S3 bucket, the user must have permission to access the S3 bucket and file. </p>
@@ -341,6 +1034,78 @@ pub struct S3Object {
     pub version: Option<String>,
 }
 
+/// <p>The selection status of a selectable element such as a radio button or checkbox. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SelectionStatus {
+    /// <p><code>SELECTED</code></p>
+    Selected,
+    /// <p><code>NOT_SELECTED</code></p>
+    NotSelected,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SelectionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SelectionStatus::Selected => "SELECTED",
+            SelectionStatus::NotSelected => "NOT_SELECTED",
+            SelectionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SelectionStatus {
+    fn from(value: &'a str) -> SelectionStatus {
+        match value {
+            "SELECTED" => SelectionStatus::Selected,
+            "NOT_SELECTED" => SelectionStatus::NotSelected,
+            _ => SelectionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SelectionStatus {
+    fn from(value: String) -> SelectionStatus {
+        SelectionStatus::from(value.as_str())
+    }
+}
+
+impl From<SelectionStatus> for String {
+    fn from(value: SelectionStatus) -> String {
+        match value {
+            SelectionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SelectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SelectionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SelectionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SelectionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SelectionStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartDocumentAnalysisRequest {
     /// <p>The idempotent token that you use to identify the start request. If you use the same token with multiple <code>StartDocumentAnalysis</code> requests, the same <code>JobId</code> is returned. Use <code>ClientRequestToken</code> to prevent the same job from being accidentally started more than once. </p>
@@ -352,7 +1117,7 @@ pub struct StartDocumentAnalysisRequest {
     pub document_location: DocumentLocation,
     /// <p>A list of the types of analysis to perform. Add TABLES to the list to return information about the tables that are detected in the input document. Add FORMS to return detected fields and the associated text. To perform both types of analysis, add TABLES and FORMS to <code>FeatureTypes</code>. All selectable elements (<code>SELECTION_ELEMENT</code>) that are detected are returned, whatever the value of <code>FeatureTypes</code>. </p>
     #[serde(rename = "FeatureTypes")]
-    pub feature_types: Vec<String>,
+    pub feature_types: Vec<FeatureType>,
     /// <p>An identifier you specify that's included in the completion notification that's published to the Amazon SNS topic. For example, you can use <code>JobTag</code> to identify the type of document, such as a tax form or a receipt, that the completion notification corresponds to.</p>
     #[serde(rename = "JobTag")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -363,6 +1128,72 @@ pub struct StartDocumentAnalysisRequest {
     pub notification_channel: Option<NotificationChannel>,
 }
 
+impl StartDocumentAnalysisRequest {
+    /// Returns a builder for `StartDocumentAnalysisRequest`, checking its required members when built.
+    pub fn builder() -> StartDocumentAnalysisRequestBuilder {
+        StartDocumentAnalysisRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartDocumentAnalysisRequest`](struct.StartDocumentAnalysisRequest.html).
+#[derive(Default, Debug)]
+pub struct StartDocumentAnalysisRequestBuilder {
+    client_request_token: Option<String>,
+    document_location: Option<DocumentLocation>,
+    feature_types: Option<Vec<FeatureType>>,
+    job_tag: Option<String>,
+    notification_channel: Option<NotificationChannel>,
+}
+
+impl StartDocumentAnalysisRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartDocumentAnalysisRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_location`](struct.StartDocumentAnalysisRequest.html#structfield.document_location) member.
+    pub fn document_location<T: Into<DocumentLocation>>(mut self, value: T) -> Self {
+        self.document_location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feature_types`](struct.StartDocumentAnalysisRequest.html#structfield.feature_types) member.
+    pub fn feature_types<T: Into<Vec<FeatureType>>>(mut self, value: T) -> Self {
+        self.feature_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_tag`](struct.StartDocumentAnalysisRequest.html#structfield.job_tag) member.
+    pub fn job_tag<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_tag = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_channel`](struct.StartDocumentAnalysisRequest.html#structfield.notification_channel) member.
+    pub fn notification_channel<T: Into<NotificationChannel>>(mut self, value: T) -> Self {
+        self.notification_channel = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartDocumentAnalysisRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartDocumentAnalysisRequest, ::rusoto_core::BuildError> {
+        Ok(StartDocumentAnalysisRequest {
+            client_request_token: self.client_request_token,
+            document_location: self
+                .document_location
+                .ok_or(::rusoto_core::BuildError::missing("document_location"))?,
+            feature_types: self
+                .feature_types
+                .ok_or(::rusoto_core::BuildError::missing("feature_types"))?,
+            job_tag: self.job_tag,
+            notification_channel: self.notification_channel,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartDocumentAnalysisResponse {
@@ -391,6 +1222,62 @@ pub struct StartDocumentTextDetectionRequest {
     pub notification_channel: Option<NotificationChannel>,
 }
 
+impl StartDocumentTextDetectionRequest {
+    /// Returns a builder for `StartDocumentTextDetectionRequest`, checking its required members when built.
+    pub fn builder() -> StartDocumentTextDetectionRequestBuilder {
+        StartDocumentTextDetectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartDocumentTextDetectionRequest`](struct.StartDocumentTextDetectionRequest.html).
+#[derive(Default, Debug)]
+pub struct StartDocumentTextDetectionRequestBuilder {
+    client_request_token: Option<String>,
+    document_location: Option<DocumentLocation>,
+    job_tag: Option<String>,
+    notification_channel: Option<NotificationChannel>,
+}
+
+impl StartDocumentTextDetectionRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartDocumentTextDetectionRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_location`](struct.StartDocumentTextDetectionRequest.html#structfield.document_location) member.
+    pub fn document_location<T: Into<DocumentLocation>>(mut self, value: T) -> Self {
+        self.document_location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_tag`](struct.StartDocumentTextDetectionRequest.html#structfield.job_tag) member.
+    pub fn job_tag<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_tag = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_channel`](struct.StartDocumentTextDetectionRequest.html#structfield.notification_channel) member.
+    pub fn notification_channel<T: Into<NotificationChannel>>(mut self, value: T) -> Self {
+        self.notification_channel = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartDocumentTextDetectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartDocumentTextDetectionRequest, ::rusoto_core::BuildError> {
+        Ok(StartDocumentTextDetectionRequest {
+            client_request_token: self.client_request_token,
+            document_location: self
+                .document_location
+                .ok_or(::rusoto_core::BuildError::missing("document_location"))?,
+            job_tag: self.job_tag,
+            notification_channel: self.notification_channel,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartDocumentTextDetectionResponse {
@@ -988,6 +1875,51 @@ pub trait Textract {
         input: StartDocumentTextDetectionRequest,
     ) -> RusotoFuture<StartDocumentTextDetectionResponse, StartDocumentTextDetectionError>;
 }
+impl<T: ?Sized + Textract> Textract for ::std::sync::Arc<T> {
+    fn analyze_document(
+        &self,
+        input: AnalyzeDocumentRequest,
+    ) -> RusotoFuture<AnalyzeDocumentResponse, AnalyzeDocumentError> {
+        (**self).analyze_document(input)
+    }
+    fn detect_document_text(
+        &self,
+        input: DetectDocumentTextRequest,
+    ) -> RusotoFuture<DetectDocumentTextResponse, DetectDocumentTextError> {
+        (**self).detect_document_text(input)
+    }
+    fn get_document_analysis(
+        &self,
+        input: GetDocumentAnalysisRequest,
+    ) -> RusotoFuture<GetDocumentAnalysisResponse, GetDocumentAnalysisError> {
+        (**self).get_document_analysis(input)
+    }
+    fn get_document_text_detection(
+        &self,
+        input: GetDocumentTextDetectionRequest,
+    ) -> RusotoFuture<GetDocumentTextDetectionResponse, GetDocumentTextDetectionError> {
+        (**self).get_document_text_detection(input)
+    }
+    fn start_document_analysis(
+        &self,
+        input: StartDocumentAnalysisRequest,
+    ) -> RusotoFuture<StartDocumentAnalysisResponse, StartDocumentAnalysisError> {
+        (**self).start_document_analysis(input)
+    }
+    fn start_document_text_detection(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> RusotoFuture<StartDocumentTextDetectionResponse, StartDocumentTextDetectionError> {
+        (**self).start_document_text_detection(input)
+    }
+}
+/// The error codes Amazon Textract retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Textract API.
 #[derive(Clone)]
 pub struct TextractClient {
@@ -1003,6 +1935,27 @@ impl TextractClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_TEXTRACT`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> TextractClient {
+        Self::new(region::Region::from_env_for_service("TEXTRACT"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `textract`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> TextractClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("textract", "TEXTRACT"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1021,7 +1974,54 @@ impl TextractClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> TextractClient {
-        TextractClient { client, region }
+        TextractClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "textract")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "textract",
+            "textract",
+            "TEXTRACT",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Textract` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Textract + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for TextractClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Textract"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "textract"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> TextractClient {
+        Self::new_with_client(client, region)
     }
 }
 
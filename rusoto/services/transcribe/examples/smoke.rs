@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Transcribe Service clients.
+//!
+//! Makes a single read-only `ListTranscriptionJobs` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_transcribe::{ListTranscriptionJobsRequest, Transcribe, TranscribeClient};
+
+fn main() {
+    let client = TranscribeClient::from_env();
+    match client
+        .list_transcription_jobs(ListTranscriptionJobsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListTranscriptionJobs succeeded"),
+        Err(error) => {
+            eprintln!("ListTranscriptionJobs failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+extern crate rusoto_mock;
+
+use crate::streaming::{
+    StartStreamTranscriptionError, StartStreamTranscriptionRequest, TranscribeStreamingClient,
+};
+
+use self::rusoto_mock::*;
+use bytes::Bytes;
+use futures::stream;
+use rusoto_core::event_stream::STREAMING_EVENTS_PAYLOAD;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{Region, RusotoError};
+
+fn header(request: &SignedRequest, name: &str) -> String {
+    String::from_utf8(request.headers()[name][0].clone()).unwrap()
+}
+
+#[test]
+fn should_sign_stream_transcription_as_an_event_stream() {
+    let mock = MockRequestDispatcher::with_status(400)
+        .with_header("x-amzn-ErrorType", "BadRequestException")
+        .with_body(r#"{"Message": "Unsupported media encoding"}"#)
+        .with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                request.hostname(),
+                "transcribestreaming.us-east-1.amazonaws.com"
+            );
+            assert_eq!(request.path(), "/stream-transcription");
+            assert_eq!(
+                header(request, "content-type"),
+                "application/vnd.amazon.eventstream"
+            );
+            assert_eq!(
+                header(request, "x-amz-content-sha256"),
+                STREAMING_EVENTS_PAYLOAD
+            );
+            assert_eq!(header(request, "x-amzn-transcribe-sample-rate"), "16000");
+            assert!(header(request, "authorization").contains("/us-east-1/transcribe/"));
+        });
+    let client =
+        TranscribeStreamingClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .start_stream_transcription(
+            StartStreamTranscriptionRequest {
+                language_code: "en-US".to_owned(),
+                media_encoding: "mp3".to_owned(),
+                media_sample_rate_hertz: 16000,
+                ..Default::default()
+            },
+            stream::iter_ok(vec![Bytes::from_static(b"audio")]),
+        )
+        .sync();
+
+    match result {
+        Err(RusotoError::Service(StartStreamTranscriptionError::BadRequest(msg))) => {
+            assert_eq!(msg, "Unsupported media encoding")
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+}
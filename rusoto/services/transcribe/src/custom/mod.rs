@@ -1 +1,5 @@
+/// Real-time transcription of streamed audio
+pub mod streaming;
 
+#[cfg(test)]
+mod custom_tests;
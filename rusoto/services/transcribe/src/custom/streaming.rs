@@ -0,0 +1,479 @@
+//! Real-time transcription with Amazon Transcribe Streaming.
+//!
+//! `StartStreamTranscription` sends audio to Amazon Transcribe while it is being captured and
+//! receives transcripts while the audio is still being sent. Both directions are event streams
+//! carried by a single HTTP/2 request, with every audio message signed on the fly, so the
+//! operation is provided by `TranscribeStreamingClient` rather than the generated
+//! `TranscribeClient`.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::future::{self, Either};
+use futures::{Async, Future, Poll, Stream};
+use http::{HeaderMap, StatusCode};
+use rusoto_core::credential::{DefaultCredentialsProvider, ProvideAwsCredentials};
+use rusoto_core::event_stream::{HeaderValue, Message, MessageStream};
+use rusoto_core::proto;
+use rusoto_core::request::{
+    BufferedHttpResponse, DispatchSignedRequest, HttpClient, HttpConfig, HttpResponse,
+};
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{Region, RusotoError, RusotoFuture};
+
+/// The parameters of a `StartStreamTranscription` call.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StartStreamTranscriptionRequest {
+    /// The language of the audio, e.g. `en-US`.
+    pub language_code: String,
+    /// The encoding of the audio: `pcm` (signed 16-bit little-endian), `ogg-opus` or `flac`.
+    pub media_encoding: String,
+    /// The sample rate of the audio, in Hertz.
+    pub media_sample_rate_hertz: i64,
+    /// Identifies the session, so that a dropped stream can be resumed. Generated by Amazon
+    /// Transcribe when not set.
+    pub session_id: Option<String>,
+    /// The name of a custom vocabulary to use.
+    pub vocabulary_name: Option<String>,
+}
+
+/// The response of a `StartStreamTranscription` call.
+#[derive(Debug)]
+pub struct StartStreamTranscriptionResponse {
+    /// The language of the audio.
+    pub language_code: Option<String>,
+    /// The encoding of the audio.
+    pub media_encoding: Option<String>,
+    /// The sample rate of the audio, in Hertz.
+    pub media_sample_rate_hertz: Option<i64>,
+    /// Identifies the request.
+    pub request_id: Option<String>,
+    /// Identifies the session.
+    pub session_id: Option<String>,
+    /// The transcripts, received as the audio is processed.
+    pub transcript_result_stream: TranscriptResultStream,
+    /// The name of the custom vocabulary used.
+    pub vocabulary_name: Option<String>,
+}
+
+/// A batch of transcription results.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct TranscriptEvent {
+    /// The transcription results.
+    #[serde(rename = "Transcript")]
+    pub transcript: Transcript,
+}
+
+/// The transcription results of a `TranscriptEvent`.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct Transcript {
+    /// Results for consecutive segments of the audio.
+    #[serde(rename = "Results")]
+    pub results: Option<Vec<TranscriptResult>>,
+}
+
+/// The transcription of a segment of the audio.
+///
+/// Partial results are refined by later events carrying the same `result_id`, until a result
+/// with `is_partial` unset completes the segment.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct TranscriptResult {
+    /// Possible transcriptions of the segment, most likely first.
+    #[serde(rename = "Alternatives")]
+    pub alternatives: Option<Vec<Alternative>>,
+    /// The offset of the end of the segment from the start of the stream, in seconds.
+    #[serde(rename = "EndTime")]
+    pub end_time: Option<f64>,
+    /// Whether the segment is not completely transcribed yet.
+    #[serde(rename = "IsPartial")]
+    pub is_partial: Option<bool>,
+    /// Identifies the segment.
+    #[serde(rename = "ResultId")]
+    pub result_id: Option<String>,
+    /// The offset of the start of the segment from the start of the stream, in seconds.
+    #[serde(rename = "StartTime")]
+    pub start_time: Option<f64>,
+}
+
+/// A possible transcription of a segment of the audio.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct Alternative {
+    /// The words and punctuation of the transcription.
+    #[serde(rename = "Items")]
+    pub items: Option<Vec<Item>>,
+    /// The text of the transcription.
+    #[serde(rename = "Transcript")]
+    pub transcript: Option<String>,
+}
+
+/// A word or punctuation mark of a transcription.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct Item {
+    /// The word or punctuation mark.
+    #[serde(rename = "Content")]
+    pub content: Option<String>,
+    /// The offset of the end of the item from the start of the stream, in seconds.
+    #[serde(rename = "EndTime")]
+    pub end_time: Option<f64>,
+    /// The offset of the start of the item from the start of the stream, in seconds.
+    #[serde(rename = "StartTime")]
+    pub start_time: Option<f64>,
+    /// Either `pronunciation` or `punctuation`.
+    #[serde(rename = "Type")]
+    pub type_: Option<String>,
+}
+
+/// Errors returned by StartStreamTranscription, either in the response or in the middle of the
+/// transcript stream.
+#[derive(Debug, PartialEq)]
+pub enum StartStreamTranscriptionError {
+    /// The request or the audio is invalid, e.g. its encoding or sample rate does not match the
+    /// request parameters.
+    BadRequest(String),
+    /// A stream with the same session id is already in progress.
+    Conflict(String),
+    /// There was an internal error.
+    InternalFailure(String),
+    /// Too many streams are open at the same time, or the audio is sent faster than real time.
+    LimitExceeded(String),
+    /// The service is currently unavailable.
+    ServiceUnavailable(String),
+}
+
+impl StartStreamTranscriptionError {
+    /// Converts the error response of the initial request.
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StartStreamTranscriptionError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            if let Some(error) = StartStreamTranscriptionError::from_type(&err.typ, err.msg) {
+                return RusotoError::Service(error);
+            }
+        }
+        RusotoError::Unknown(res)
+    }
+
+    fn from_type(typ: &str, msg: String) -> Option<StartStreamTranscriptionError> {
+        match typ {
+            "BadRequestException" => Some(StartStreamTranscriptionError::BadRequest(msg)),
+            "ConflictException" => Some(StartStreamTranscriptionError::Conflict(msg)),
+            "InternalFailureException" => Some(StartStreamTranscriptionError::InternalFailure(msg)),
+            "LimitExceededException" => Some(StartStreamTranscriptionError::LimitExceeded(msg)),
+            "ServiceUnavailableException" => {
+                Some(StartStreamTranscriptionError::ServiceUnavailable(msg))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for StartStreamTranscriptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for StartStreamTranscriptionError {
+    fn description(&self) -> &str {
+        match *self {
+            StartStreamTranscriptionError::BadRequest(ref cause) => cause,
+            StartStreamTranscriptionError::Conflict(ref cause) => cause,
+            StartStreamTranscriptionError::InternalFailure(ref cause) => cause,
+            StartStreamTranscriptionError::LimitExceeded(ref cause) => cause,
+            StartStreamTranscriptionError::ServiceUnavailable(ref cause) => cause,
+        }
+    }
+}
+
+/// The transcript events of a `StartStreamTranscription` call.
+///
+/// The stream ends once all the audio sent has been transcribed, or with the first error
+/// reported by Amazon Transcribe.
+pub struct TranscriptResultStream {
+    messages: MessageStream,
+}
+
+impl fmt::Debug for TranscriptResultStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<TranscriptResultStream>")
+    }
+}
+
+impl Stream for TranscriptResultStream {
+    type Item = TranscriptEvent;
+    type Error = RusotoError<StartStreamTranscriptionError>;
+
+    fn poll(&mut self) -> Poll<Option<TranscriptEvent>, Self::Error> {
+        loop {
+            let message = match self.messages.poll()? {
+                Async::Ready(Some(message)) => message,
+                Async::Ready(None) => return Ok(Async::Ready(None)),
+                Async::NotReady => return Ok(Async::NotReady),
+            };
+            if let Some(event) = transcript_event(message)? {
+                return Ok(Async::Ready(Some(event)));
+            }
+        }
+    }
+}
+
+fn string_header<'a>(message: &'a Message, name: &str) -> Option<&'a str> {
+    message.header(name).and_then(HeaderValue::as_str)
+}
+
+/// Converts a message of the transcript stream, skipping events of unknown types.
+fn transcript_event(
+    message: Message,
+) -> Result<Option<TranscriptEvent>, RusotoError<StartStreamTranscriptionError>> {
+    match string_header(&message, ":message-type") {
+        Some("event") => match string_header(&message, ":event-type") {
+            Some("TranscriptEvent") => Ok(Some(serde_json::from_slice(&message.payload)?)),
+            _ => Ok(None),
+        },
+        Some("exception") => {
+            let typ = string_header(&message, ":exception-type").unwrap_or_default();
+            let msg = serde_json::from_slice::<serde_json::Value>(&message.payload)
+                .ok()
+                .and_then(|payload| payload["Message"].as_str().map(str::to_owned))
+                .unwrap_or_default();
+            Err(stream_error(typ, msg, &message))
+        }
+        Some("error") => {
+            let code = string_header(&message, ":error-code").unwrap_or_default();
+            let msg = string_header(&message, ":error-message").unwrap_or_default();
+            Err(stream_error(code, msg.to_owned(), &message))
+        }
+        other => Err(RusotoError::ParseError(format!(
+            "unexpected event stream message type {:?}",
+            other
+        ))),
+    }
+}
+
+fn stream_error(
+    typ: &str,
+    msg: String,
+    message: &Message,
+) -> RusotoError<StartStreamTranscriptionError> {
+    match StartStreamTranscriptionError::from_type(typ, msg) {
+        Some(error) => RusotoError::Service(error),
+        None => RusotoError::Unknown(BufferedHttpResponse {
+            status: StatusCode::OK,
+            body: message.payload.clone(),
+            headers: HeaderMap::default(),
+        }),
+    }
+}
+
+fn audio_event(chunk: Bytes) -> Message {
+    Message::new(chunk)
+        .with_header(
+            ":content-type",
+            HeaderValue::String("application/octet-stream".to_owned()),
+        )
+        .with_header(":event-type", HeaderValue::String("AudioEvent".to_owned()))
+        .with_header(":message-type", HeaderValue::String("event".to_owned()))
+}
+
+/// A client for Amazon Transcribe Streaming.
+#[derive(Clone)]
+pub struct TranscribeStreamingClient<P = DefaultCredentialsProvider, D = HttpClient> {
+    credentials_provider: Arc<P>,
+    dispatcher: Arc<D>,
+    region: Region,
+}
+
+impl TranscribeStreamingClient {
+    /// Creates a client for `region` using the default credentials provider and an HTTP/2 only
+    /// request dispatcher.
+    pub fn new(region: Region) -> TranscribeStreamingClient {
+        let mut config = HttpConfig::new();
+        config.http2_only(true);
+        TranscribeStreamingClient::new_with(
+            HttpClient::new_with_config(config).expect("failed to create request dispatcher"),
+            DefaultCredentialsProvider::new().expect("failed to create credentials provider"),
+            region,
+        )
+    }
+}
+
+impl<P, D> TranscribeStreamingClient<P, D>
+where
+    P: ProvideAwsCredentials + Send + Sync + 'static,
+    P::Future: Send,
+    D: DispatchSignedRequest + Send + Sync + 'static,
+    D::Future: Send,
+{
+    /// Creates a client from a request dispatcher and credentials provider.
+    ///
+    /// The dispatcher must speak HTTP/2, e.g. an `HttpClient` created from an `HttpConfig`
+    /// with `http2_only` set.
+    pub fn new_with(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: Region,
+    ) -> TranscribeStreamingClient<P, D> {
+        TranscribeStreamingClient {
+            credentials_provider: Arc::new(credentials_provider),
+            dispatcher: Arc::new(request_dispatcher),
+            region,
+        }
+    }
+
+    /// Starts transcribing `audio`, returning the transcripts as a stream once Amazon
+    /// Transcribe has accepted the request.
+    ///
+    /// Each chunk of `audio` is sent as one signed audio event, so chunks should hold between
+    /// 50 and 200 milliseconds of audio. The transcription ends after the last chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures::{stream, Future, Stream};
+    /// use rusoto_core::Region;
+    /// use rusoto_transcribe::streaming::{
+    ///     StartStreamTranscriptionRequest, TranscribeStreamingClient,
+    /// };
+    ///
+    /// let audio: Vec<bytes::Bytes> = Vec::new();
+    /// let client = TranscribeStreamingClient::new(Region::UsEast1);
+    /// let transcripts = client
+    ///     .start_stream_transcription(
+    ///         StartStreamTranscriptionRequest {
+    ///             language_code: "en-US".to_owned(),
+    ///             media_encoding: "pcm".to_owned(),
+    ///             media_sample_rate_hertz: 16000,
+    ///             ..Default::default()
+    ///         },
+    ///         stream::iter_ok(audio),
+    ///     )
+    ///     .and_then(|response| response.transcript_result_stream.collect())
+    ///     .wait()
+    ///     .unwrap();
+    /// ```
+    pub fn start_stream_transcription<S>(
+        &self,
+        input: StartStreamTranscriptionRequest,
+        audio: S,
+    ) -> RusotoFuture<StartStreamTranscriptionResponse, StartStreamTranscriptionError>
+    where
+        S: Stream<Item = Bytes, Error = io::Error> + Send + 'static,
+    {
+        let mut request =
+            SignedRequest::new("POST", "transcribe", &self.region, "/stream-transcription");
+        request.set_endpoint_prefix("transcribestreaming".to_owned());
+        request.set_content_type("application/vnd.amazon.eventstream".to_owned());
+        request.add_header("x-amzn-transcribe-language-code", &input.language_code);
+        request.add_header("x-amzn-transcribe-media-encoding", &input.media_encoding);
+        request.add_header(
+            "x-amzn-transcribe-sample-rate",
+            &input.media_sample_rate_hertz.to_string(),
+        );
+        if let Some(ref session_id) = input.session_id {
+            request.add_header("x-amzn-transcribe-session-id", session_id);
+        }
+        if let Some(ref vocabulary_name) = input.vocabulary_name {
+            request.add_header("x-amzn-transcribe-vocabulary-name", vocabulary_name);
+        }
+
+        let dispatcher = self.dispatcher.clone();
+        let future = self
+            .credentials_provider
+            .credentials()
+            .map_err(RusotoError::from)
+            .and_then(move |credentials| {
+                request.sign_event_stream(&credentials, audio.map(audio_event));
+                dispatcher
+                    .dispatch(request, None)
+                    .map_err(RusotoError::from)
+            })
+            .and_then(|response| {
+                if response.status.is_success() {
+                    Either::A(future::ok(stream_transcription_response(response)))
+                } else {
+                    Either::B(response.buffer().from_err().and_then(|response| {
+                        Err(StartStreamTranscriptionError::from_response(response))
+                    }))
+                }
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+fn stream_transcription_response(response: HttpResponse) -> StartStreamTranscriptionResponse {
+    let HttpResponse { headers, body, .. } = response;
+    let header = |name: &str| headers.get(name).cloned();
+    StartStreamTranscriptionResponse {
+        language_code: header("x-amzn-transcribe-language-code"),
+        media_encoding: header("x-amzn-transcribe-media-encoding"),
+        media_sample_rate_hertz: header("x-amzn-transcribe-sample-rate")
+            .and_then(|rate| rate.parse().ok()),
+        request_id: header("x-amzn-request-id"),
+        session_id: header("x-amzn-transcribe-session-id"),
+        vocabulary_name: header("x-amzn-transcribe-vocabulary-name"),
+        transcript_result_stream: TranscriptResultStream {
+            messages: MessageStream::new(body),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(event_type: &str, payload: &'static str) -> Message {
+        Message::new(payload)
+            .with_header(":message-type", HeaderValue::String("event".to_owned()))
+            .with_header(":event-type", HeaderValue::String(event_type.to_owned()))
+    }
+
+    #[test]
+    fn parses_transcript_events() {
+        let message = event(
+            "TranscriptEvent",
+            r#"{"Transcript":{"Results":[{"ResultId":"1","StartTime":0.1,"EndTime":0.9,
+                "IsPartial":true,"Alternatives":[{"Transcript":"hello","Items":[
+                {"Content":"hello","StartTime":0.1,"EndTime":0.9,"Type":"pronunciation"}]}]}]}}"#,
+        );
+        let event = transcript_event(message).unwrap().unwrap();
+        let result = &event.transcript.results.unwrap()[0];
+
+        assert_eq!(result.is_partial, Some(true));
+        assert_eq!(
+            result.alternatives.as_ref().unwrap()[0].transcript,
+            Some("hello".to_owned())
+        );
+    }
+
+    #[test]
+    fn skips_unknown_events() {
+        assert_eq!(transcript_event(event("FutureEvent", "{}")), Ok(None));
+    }
+
+    #[test]
+    fn converts_exceptions() {
+        let message = Message::new(r#"{"Message":"Your request timed out"}"#)
+            .with_header(":message-type", HeaderValue::String("exception".to_owned()))
+            .with_header(
+                ":exception-type",
+                HeaderValue::String("BadRequestException".to_owned()),
+            );
+
+        assert_eq!(
+            transcript_event(message),
+            Err(RusotoError::Service(
+                StartStreamTranscriptionError::BadRequest("Your request timed out".to_owned())
+            ))
+        );
+    }
+
+    #[test]
+    fn sends_audio_as_audio_events() {
+        let message = audio_event(Bytes::from_static(b"\x00\x01"));
+
+        assert_eq!(string_header(&message, ":event-type"), Some("AudioEvent"));
+        assert_eq!(&message.payload[..], b"\x00\x01");
+    }
+}
@@ -22,12 +22,13 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateVocabularyRequest {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>An array of strings that contains the vocabulary entries. </p>
     #[serde(rename = "Phrases")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -41,6 +42,64 @@ pub struct CreateVocabularyRequest {
     pub vocabulary_name: String,
 }
 
+impl CreateVocabularyRequest {
+    /// Returns a builder for `CreateVocabularyRequest`, checking its required members when built.
+    pub fn builder() -> CreateVocabularyRequestBuilder {
+        CreateVocabularyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateVocabularyRequest`](struct.CreateVocabularyRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateVocabularyRequestBuilder {
+    language_code: Option<LanguageCode>,
+    phrases: Option<Vec<String>>,
+    vocabulary_file_uri: Option<String>,
+    vocabulary_name: Option<String>,
+}
+
+impl CreateVocabularyRequestBuilder {
+    /// Sets the [`language_code`](struct.CreateVocabularyRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`phrases`](struct.CreateVocabularyRequest.html#structfield.phrases) member.
+    pub fn phrases<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.phrases = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vocabulary_file_uri`](struct.CreateVocabularyRequest.html#structfield.vocabulary_file_uri) member.
+    pub fn vocabulary_file_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.vocabulary_file_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vocabulary_name`](struct.CreateVocabularyRequest.html#structfield.vocabulary_name) member.
+    pub fn vocabulary_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vocabulary_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateVocabularyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateVocabularyRequest, ::rusoto_core::BuildError> {
+        Ok(CreateVocabularyRequest {
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            phrases: self.phrases,
+            vocabulary_file_uri: self.vocabulary_file_uri,
+            vocabulary_name: self
+                .vocabulary_name
+                .ok_or(::rusoto_core::BuildError::missing("vocabulary_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateVocabularyResponse {
@@ -51,11 +110,12 @@ pub struct CreateVocabularyResponse {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the vocabulary was created.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<EpochTimestamp>,
     /// <p>The name of the vocabulary.</p>
     #[serde(rename = "VocabularyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -63,7 +123,7 @@ pub struct CreateVocabularyResponse {
     /// <p>The processing state of the vocabulary. When the <code>VocabularyState</code> field contains <code>READY</code> the vocabulary is ready to be used in a <code>StartTranscriptionJob</code> request.</p>
     #[serde(rename = "VocabularyState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vocabulary_state: Option<String>,
+    pub vocabulary_state: Option<VocabularyState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -73,6 +133,38 @@ pub struct DeleteTranscriptionJobRequest {
     pub transcription_job_name: String,
 }
 
+impl DeleteTranscriptionJobRequest {
+    /// Returns a builder for `DeleteTranscriptionJobRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTranscriptionJobRequestBuilder {
+        DeleteTranscriptionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTranscriptionJobRequest`](struct.DeleteTranscriptionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTranscriptionJobRequestBuilder {
+    transcription_job_name: Option<String>,
+}
+
+impl DeleteTranscriptionJobRequestBuilder {
+    /// Sets the [`transcription_job_name`](struct.DeleteTranscriptionJobRequest.html#structfield.transcription_job_name) member.
+    pub fn transcription_job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.transcription_job_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTranscriptionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteTranscriptionJobRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTranscriptionJobRequest {
+            transcription_job_name: self
+                .transcription_job_name
+                .ok_or(::rusoto_core::BuildError::missing("transcription_job_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteVocabularyRequest {
     /// <p>The name of the vocabulary to delete. </p>
@@ -80,6 +172,38 @@ pub struct DeleteVocabularyRequest {
     pub vocabulary_name: String,
 }
 
+impl DeleteVocabularyRequest {
+    /// Returns a builder for `DeleteVocabularyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteVocabularyRequestBuilder {
+        DeleteVocabularyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVocabularyRequest`](struct.DeleteVocabularyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteVocabularyRequestBuilder {
+    vocabulary_name: Option<String>,
+}
+
+impl DeleteVocabularyRequestBuilder {
+    /// Sets the [`vocabulary_name`](struct.DeleteVocabularyRequest.html#structfield.vocabulary_name) member.
+    pub fn vocabulary_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vocabulary_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVocabularyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVocabularyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteVocabularyRequest {
+            vocabulary_name: self
+                .vocabulary_name
+                .ok_or(::rusoto_core::BuildError::missing("vocabulary_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetTranscriptionJobRequest {
     /// <p>The name of the job.</p>
@@ -87,6 +211,38 @@ pub struct GetTranscriptionJobRequest {
     pub transcription_job_name: String,
 }
 
+impl GetTranscriptionJobRequest {
+    /// Returns a builder for `GetTranscriptionJobRequest`, checking its required members when built.
+    pub fn builder() -> GetTranscriptionJobRequestBuilder {
+        GetTranscriptionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTranscriptionJobRequest`](struct.GetTranscriptionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTranscriptionJobRequestBuilder {
+    transcription_job_name: Option<String>,
+}
+
+impl GetTranscriptionJobRequestBuilder {
+    /// Sets the [`transcription_job_name`](struct.GetTranscriptionJobRequest.html#structfield.transcription_job_name) member.
+    pub fn transcription_job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.transcription_job_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTranscriptionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetTranscriptionJobRequest, ::rusoto_core::BuildError> {
+        Ok(GetTranscriptionJobRequest {
+            transcription_job_name: self
+                .transcription_job_name
+                .ok_or(::rusoto_core::BuildError::missing("transcription_job_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTranscriptionJobResponse {
@@ -103,6 +259,36 @@ pub struct GetVocabularyRequest {
     pub vocabulary_name: String,
 }
 
+impl GetVocabularyRequest {
+    /// Returns a builder for `GetVocabularyRequest`, checking its required members when built.
+    pub fn builder() -> GetVocabularyRequestBuilder {
+        GetVocabularyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetVocabularyRequest`](struct.GetVocabularyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetVocabularyRequestBuilder {
+    vocabulary_name: Option<String>,
+}
+
+impl GetVocabularyRequestBuilder {
+    /// Sets the [`vocabulary_name`](struct.GetVocabularyRequest.html#structfield.vocabulary_name) member.
+    pub fn vocabulary_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vocabulary_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetVocabularyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetVocabularyRequest, ::rusoto_core::BuildError> {
+        Ok(GetVocabularyRequest {
+            vocabulary_name: self
+                .vocabulary_name
+                .ok_or(::rusoto_core::BuildError::missing("vocabulary_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetVocabularyResponse {
@@ -117,11 +303,12 @@ pub struct GetVocabularyResponse {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the vocabulary was last modified.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<EpochTimestamp>,
     /// <p>The name of the vocabulary to return.</p>
     #[serde(rename = "VocabularyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -129,7 +316,483 @@ pub struct GetVocabularyResponse {
     /// <p>The processing state of the vocabulary.</p>
     #[serde(rename = "VocabularyState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vocabulary_state: Option<String>,
+    pub vocabulary_state: Option<VocabularyState>,
+}
+
+/// <p>The language code of the vocabulary entries.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LanguageCode {
+    /// <p><code>af-ZA</code></p>
+    AfZa,
+    /// <p><code>ar-AE</code></p>
+    ArAe,
+    /// <p><code>ar-SA</code></p>
+    ArSa,
+    /// <p><code>da-DK</code></p>
+    DaDk,
+    /// <p><code>de-CH</code></p>
+    DeCh,
+    /// <p><code>de-DE</code></p>
+    DeDe,
+    /// <p><code>en-AB</code></p>
+    EnAb,
+    /// <p><code>en-AU</code></p>
+    EnAu,
+    /// <p><code>en-GB</code></p>
+    EnGb,
+    /// <p><code>en-IE</code></p>
+    EnIe,
+    /// <p><code>en-IN</code></p>
+    EnIn,
+    /// <p><code>en-US</code></p>
+    EnUs,
+    /// <p><code>en-WL</code></p>
+    EnWl,
+    /// <p><code>es-ES</code></p>
+    EsEs,
+    /// <p><code>es-US</code></p>
+    EsUs,
+    /// <p><code>fa-IR</code></p>
+    FaIr,
+    /// <p><code>fr-CA</code></p>
+    FrCa,
+    /// <p><code>fr-FR</code></p>
+    FrFr,
+    /// <p><code>he-IL</code></p>
+    HeIl,
+    /// <p><code>hi-IN</code></p>
+    HiIn,
+    /// <p><code>id-ID</code></p>
+    IdId,
+    /// <p><code>it-IT</code></p>
+    ItIt,
+    /// <p><code>ja-JP</code></p>
+    JaJp,
+    /// <p><code>ko-KR</code></p>
+    KoKr,
+    /// <p><code>ms-MY</code></p>
+    MsMy,
+    /// <p><code>nl-NL</code></p>
+    NlNl,
+    /// <p><code>pt-BR</code></p>
+    PtBr,
+    /// <p><code>pt-PT</code></p>
+    PtPt,
+    /// <p><code>ru-RU</code></p>
+    RuRu,
+    /// <p><code>ta-IN</code></p>
+    TaIn,
+    /// <p><code>te-IN</code></p>
+    TeIn,
+    /// <p><code>tr-TR</code></p>
+    TrTr,
+    /// <p><code>zh-CN</code></p>
+    ZhCn,
+    /// <p><code>zh-TW</code></p>
+    ZhTw,
+    /// <p><code>th-TH</code></p>
+    ThTh,
+    /// <p><code>en-ZA</code></p>
+    EnZa,
+    /// <p><code>en-NZ</code></p>
+    EnNz,
+    /// <p><code>vi-VN</code></p>
+    ViVn,
+    /// <p><code>sv-SE</code></p>
+    SvSe,
+    /// <p><code>ab-GE</code></p>
+    AbGe,
+    /// <p><code>ast-ES</code></p>
+    AstEs,
+    /// <p><code>az-AZ</code></p>
+    AzAz,
+    /// <p><code>ba-RU</code></p>
+    BaRu,
+    /// <p><code>be-BY</code></p>
+    BeBy,
+    /// <p><code>bg-BG</code></p>
+    BgBg,
+    /// <p><code>bn-IN</code></p>
+    BnIn,
+    /// <p><code>bs-BA</code></p>
+    BsBa,
+    /// <p><code>ca-ES</code></p>
+    CaEs,
+    /// <p><code>ckb-IQ</code></p>
+    CkbIq,
+    /// <p><code>ckb-IR</code></p>
+    CkbIr,
+    /// <p><code>cs-CZ</code></p>
+    CsCz,
+    /// <p><code>cy-WL</code></p>
+    CyWl,
+    /// <p><code>el-GR</code></p>
+    ElGr,
+    /// <p><code>et-ET</code></p>
+    EtEt,
+    /// <p><code>eu-ES</code></p>
+    EuEs,
+    /// <p><code>fi-FI</code></p>
+    FiFi,
+    /// <p><code>gl-ES</code></p>
+    GlEs,
+    /// <p><code>gu-IN</code></p>
+    GuIn,
+    /// <p><code>ha-NG</code></p>
+    HaNg,
+    /// <p><code>hr-HR</code></p>
+    HrHr,
+    /// <p><code>hu-HU</code></p>
+    HuHu,
+    /// <p><code>hy-AM</code></p>
+    HyAm,
+    /// <p><code>is-IS</code></p>
+    IsIs,
+    /// <p><code>ka-GE</code></p>
+    KaGe,
+    /// <p><code>kab-DZ</code></p>
+    KabDz,
+    /// <p><code>kk-KZ</code></p>
+    KkKz,
+    /// <p><code>kn-IN</code></p>
+    KnIn,
+    /// <p><code>ky-KG</code></p>
+    KyKg,
+    /// <p><code>lg-IN</code></p>
+    LgIn,
+    /// <p><code>lt-LT</code></p>
+    LtLt,
+    /// <p><code>lv-LV</code></p>
+    LvLv,
+    /// <p><code>mhr-RU</code></p>
+    MhrRu,
+    /// <p><code>mi-NZ</code></p>
+    MiNz,
+    /// <p><code>mk-MK</code></p>
+    MkMk,
+    /// <p><code>ml-IN</code></p>
+    MlIn,
+    /// <p><code>mn-MN</code></p>
+    MnMn,
+    /// <p><code>mr-IN</code></p>
+    MrIn,
+    /// <p><code>mt-MT</code></p>
+    MtMt,
+    /// <p><code>no-NO</code></p>
+    NoNo,
+    /// <p><code>or-IN</code></p>
+    OrIn,
+    /// <p><code>pa-IN</code></p>
+    PaIn,
+    /// <p><code>pl-PL</code></p>
+    PlPl,
+    /// <p><code>ps-AF</code></p>
+    PsAf,
+    /// <p><code>ro-RO</code></p>
+    RoRo,
+    /// <p><code>rw-RW</code></p>
+    RwRw,
+    /// <p><code>si-LK</code></p>
+    SiLk,
+    /// <p><code>sk-SK</code></p>
+    SkSk,
+    /// <p><code>sl-SI</code></p>
+    SlSi,
+    /// <p><code>so-SO</code></p>
+    SoSo,
+    /// <p><code>sr-RS</code></p>
+    SrRs,
+    /// <p><code>su-ID</code></p>
+    SuId,
+    /// <p><code>sw-BI</code></p>
+    SwBi,
+    /// <p><code>sw-KE</code></p>
+    SwKe,
+    /// <p><code>sw-RW</code></p>
+    SwRw,
+    /// <p><code>sw-TZ</code></p>
+    SwTz,
+    /// <p><code>sw-UG</code></p>
+    SwUg,
+    /// <p><code>tl-PH</code></p>
+    TlPh,
+    /// <p><code>tt-RU</code></p>
+    TtRu,
+    /// <p><code>ug-CN</code></p>
+    UgCn,
+    /// <p><code>uk-UA</code></p>
+    UkUa,
+    /// <p><code>uz-UZ</code></p>
+    UzUz,
+    /// <p><code>wo-SN</code></p>
+    WoSn,
+    /// <p><code>zu-ZA</code></p>
+    ZuZa,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LanguageCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LanguageCode::AfZa => "af-ZA",
+            LanguageCode::ArAe => "ar-AE",
+            LanguageCode::ArSa => "ar-SA",
+            LanguageCode::DaDk => "da-DK",
+            LanguageCode::DeCh => "de-CH",
+            LanguageCode::DeDe => "de-DE",
+            LanguageCode::EnAb => "en-AB",
+            LanguageCode::EnAu => "en-AU",
+            LanguageCode::EnGb => "en-GB",
+            LanguageCode::EnIe => "en-IE",
+            LanguageCode::EnIn => "en-IN",
+            LanguageCode::EnUs => "en-US",
+            LanguageCode::EnWl => "en-WL",
+            LanguageCode::EsEs => "es-ES",
+            LanguageCode::EsUs => "es-US",
+            LanguageCode::FaIr => "fa-IR",
+            LanguageCode::FrCa => "fr-CA",
+            LanguageCode::FrFr => "fr-FR",
+            LanguageCode::HeIl => "he-IL",
+            LanguageCode::HiIn => "hi-IN",
+            LanguageCode::IdId => "id-ID",
+            LanguageCode::ItIt => "it-IT",
+            LanguageCode::JaJp => "ja-JP",
+            LanguageCode::KoKr => "ko-KR",
+            LanguageCode::MsMy => "ms-MY",
+            LanguageCode::NlNl => "nl-NL",
+            LanguageCode::PtBr => "pt-BR",
+            LanguageCode::PtPt => "pt-PT",
+            LanguageCode::RuRu => "ru-RU",
+            LanguageCode::TaIn => "ta-IN",
+            LanguageCode::TeIn => "te-IN",
+            LanguageCode::TrTr => "tr-TR",
+            LanguageCode::ZhCn => "zh-CN",
+            LanguageCode::ZhTw => "zh-TW",
+            LanguageCode::ThTh => "th-TH",
+            LanguageCode::EnZa => "en-ZA",
+            LanguageCode::EnNz => "en-NZ",
+            LanguageCode::ViVn => "vi-VN",
+            LanguageCode::SvSe => "sv-SE",
+            LanguageCode::AbGe => "ab-GE",
+            LanguageCode::AstEs => "ast-ES",
+            LanguageCode::AzAz => "az-AZ",
+            LanguageCode::BaRu => "ba-RU",
+            LanguageCode::BeBy => "be-BY",
+            LanguageCode::BgBg => "bg-BG",
+            LanguageCode::BnIn => "bn-IN",
+            LanguageCode::BsBa => "bs-BA",
+            LanguageCode::CaEs => "ca-ES",
+            LanguageCode::CkbIq => "ckb-IQ",
+            LanguageCode::CkbIr => "ckb-IR",
+            LanguageCode::CsCz => "cs-CZ",
+            LanguageCode::CyWl => "cy-WL",
+            LanguageCode::ElGr => "el-GR",
+            LanguageCode::EtEt => "et-ET",
+            LanguageCode::EuEs => "eu-ES",
+            LanguageCode::FiFi => "fi-FI",
+            LanguageCode::GlEs => "gl-ES",
+            LanguageCode::GuIn => "gu-IN",
+            LanguageCode::HaNg => "ha-NG",
+            LanguageCode::HrHr => "hr-HR",
+            LanguageCode::HuHu => "hu-HU",
+            LanguageCode::HyAm => "hy-AM",
+            LanguageCode::IsIs => "is-IS",
+            LanguageCode::KaGe => "ka-GE",
+            LanguageCode::KabDz => "kab-DZ",
+            LanguageCode::KkKz => "kk-KZ",
+            LanguageCode::KnIn => "kn-IN",
+            LanguageCode::KyKg => "ky-KG",
+            LanguageCode::LgIn => "lg-IN",
+            LanguageCode::LtLt => "lt-LT",
+            LanguageCode::LvLv => "lv-LV",
+            LanguageCode::MhrRu => "mhr-RU",
+            LanguageCode::MiNz => "mi-NZ",
+            LanguageCode::MkMk => "mk-MK",
+            LanguageCode::MlIn => "ml-IN",
+            LanguageCode::MnMn => "mn-MN",
+            LanguageCode::MrIn => "mr-IN",
+            LanguageCode::MtMt => "mt-MT",
+            LanguageCode::NoNo => "no-NO",
+            LanguageCode::OrIn => "or-IN",
+            LanguageCode::PaIn => "pa-IN",
+            LanguageCode::PlPl => "pl-PL",
+            LanguageCode::PsAf => "ps-AF",
+            LanguageCode::RoRo => "ro-RO",
+            LanguageCode::RwRw => "rw-RW",
+            LanguageCode::SiLk => "si-LK",
+            LanguageCode::SkSk => "sk-SK",
+            LanguageCode::SlSi => "sl-SI",
+            LanguageCode::SoSo => "so-SO",
+            LanguageCode::SrRs => "sr-RS",
+            LanguageCode::SuId => "su-ID",
+            LanguageCode::SwBi => "sw-BI",
+            LanguageCode::SwKe => "sw-KE",
+            LanguageCode::SwRw => "sw-RW",
+            LanguageCode::SwTz => "sw-TZ",
+            LanguageCode::SwUg => "sw-UG",
+            LanguageCode::TlPh => "tl-PH",
+            LanguageCode::TtRu => "tt-RU",
+            LanguageCode::UgCn => "ug-CN",
+            LanguageCode::UkUa => "uk-UA",
+            LanguageCode::UzUz => "uz-UZ",
+            LanguageCode::WoSn => "wo-SN",
+            LanguageCode::ZuZa => "zu-ZA",
+            LanguageCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LanguageCode {
+    fn from(value: &'a str) -> LanguageCode {
+        match value {
+            "af-ZA" => LanguageCode::AfZa,
+            "ar-AE" => LanguageCode::ArAe,
+            "ar-SA" => LanguageCode::ArSa,
+            "da-DK" => LanguageCode::DaDk,
+            "de-CH" => LanguageCode::DeCh,
+            "de-DE" => LanguageCode::DeDe,
+            "en-AB" => LanguageCode::EnAb,
+            "en-AU" => LanguageCode::EnAu,
+            "en-GB" => LanguageCode::EnGb,
+            "en-IE" => LanguageCode::EnIe,
+            "en-IN" => LanguageCode::EnIn,
+            "en-US" => LanguageCode::EnUs,
+            "en-WL" => LanguageCode::EnWl,
+            "es-ES" => LanguageCode::EsEs,
+            "es-US" => LanguageCode::EsUs,
+            "fa-IR" => LanguageCode::FaIr,
+            "fr-CA" => LanguageCode::FrCa,
+            "fr-FR" => LanguageCode::FrFr,
+            "he-IL" => LanguageCode::HeIl,
+            "hi-IN" => LanguageCode::HiIn,
+            "id-ID" => LanguageCode::IdId,
+            "it-IT" => LanguageCode::ItIt,
+            "ja-JP" => LanguageCode::JaJp,
+            "ko-KR" => LanguageCode::KoKr,
+            "ms-MY" => LanguageCode::MsMy,
+            "nl-NL" => LanguageCode::NlNl,
+            "pt-BR" => LanguageCode::PtBr,
+            "pt-PT" => LanguageCode::PtPt,
+            "ru-RU" => LanguageCode::RuRu,
+            "ta-IN" => LanguageCode::TaIn,
+            "te-IN" => LanguageCode::TeIn,
+            "tr-TR" => LanguageCode::TrTr,
+            "zh-CN" => LanguageCode::ZhCn,
+            "zh-TW" => LanguageCode::ZhTw,
+            "th-TH" => LanguageCode::ThTh,
+            "en-ZA" => LanguageCode::EnZa,
+            "en-NZ" => LanguageCode::EnNz,
+            "vi-VN" => LanguageCode::ViVn,
+            "sv-SE" => LanguageCode::SvSe,
+            "ab-GE" => LanguageCode::AbGe,
+            "ast-ES" => LanguageCode::AstEs,
+            "az-AZ" => LanguageCode::AzAz,
+            "ba-RU" => LanguageCode::BaRu,
+            "be-BY" => LanguageCode::BeBy,
+            "bg-BG" => LanguageCode::BgBg,
+            "bn-IN" => LanguageCode::BnIn,
+            "bs-BA" => LanguageCode::BsBa,
+            "ca-ES" => LanguageCode::CaEs,
+            "ckb-IQ" => LanguageCode::CkbIq,
+            "ckb-IR" => LanguageCode::CkbIr,
+            "cs-CZ" => LanguageCode::CsCz,
+            "cy-WL" => LanguageCode::CyWl,
+            "el-GR" => LanguageCode::ElGr,
+            "et-ET" => LanguageCode::EtEt,
+            "eu-ES" => LanguageCode::EuEs,
+            "fi-FI" => LanguageCode::FiFi,
+            "gl-ES" => LanguageCode::GlEs,
+            "gu-IN" => LanguageCode::GuIn,
+            "ha-NG" => LanguageCode::HaNg,
+            "hr-HR" => LanguageCode::HrHr,
+            "hu-HU" => LanguageCode::HuHu,
+            "hy-AM" => LanguageCode::HyAm,
+            "is-IS" => LanguageCode::IsIs,
+            "ka-GE" => LanguageCode::KaGe,
+            "kab-DZ" => LanguageCode::KabDz,
+            "kk-KZ" => LanguageCode::KkKz,
+            "kn-IN" => LanguageCode::KnIn,
+            "ky-KG" => LanguageCode::KyKg,
+            "lg-IN" => LanguageCode::LgIn,
+            "lt-LT" => LanguageCode::LtLt,
+            "lv-LV" => LanguageCode::LvLv,
+            "mhr-RU" => LanguageCode::MhrRu,
+            "mi-NZ" => LanguageCode::MiNz,
+            "mk-MK" => LanguageCode::MkMk,
+            "ml-IN" => LanguageCode::MlIn,
+            "mn-MN" => LanguageCode::MnMn,
+            "mr-IN" => LanguageCode::MrIn,
+            "mt-MT" => LanguageCode::MtMt,
+            "no-NO" => LanguageCode::NoNo,
+            "or-IN" => LanguageCode::OrIn,
+            "pa-IN" => LanguageCode::PaIn,
+            "pl-PL" => LanguageCode::PlPl,
+            "ps-AF" => LanguageCode::PsAf,
+            "ro-RO" => LanguageCode::RoRo,
+            "rw-RW" => LanguageCode::RwRw,
+            "si-LK" => LanguageCode::SiLk,
+            "sk-SK" => LanguageCode::SkSk,
+            "sl-SI" => LanguageCode::SlSi,
+            "so-SO" => LanguageCode::SoSo,
+            "sr-RS" => LanguageCode::SrRs,
+            "su-ID" => LanguageCode::SuId,
+            "sw-BI" => LanguageCode::SwBi,
+            "sw-KE" => LanguageCode::SwKe,
+            "sw-RW" => LanguageCode::SwRw,
+            "sw-TZ" => LanguageCode::SwTz,
+            "sw-UG" => LanguageCode::SwUg,
+            "tl-PH" => LanguageCode::TlPh,
+            "tt-RU" => LanguageCode::TtRu,
+            "ug-CN" => LanguageCode::UgCn,
+            "uk-UA" => LanguageCode::UkUa,
+            "uz-UZ" => LanguageCode::UzUz,
+            "wo-SN" => LanguageCode::WoSn,
+            "zu-ZA" => LanguageCode::ZuZa,
+            _ => LanguageCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LanguageCode {
+    fn from(value: String) -> LanguageCode {
+        LanguageCode::from(value.as_str())
+    }
+}
+
+impl From<LanguageCode> for String {
+    fn from(value: LanguageCode) -> String {
+        match value {
+            LanguageCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LanguageCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LanguageCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LanguageCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LanguageCode::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -149,7 +812,61 @@ pub struct ListTranscriptionJobsRequest {
     /// <p>When specified, returns only transcription jobs with the specified status. Jobs are ordered by creation date, with the newest jobs returned first. If you don’t specify a status, Amazon Transcribe returns all transcription jobs ordered by creation date. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TranscriptionJobStatus>,
+}
+
+impl ListTranscriptionJobsRequest {
+    /// Returns a builder for `ListTranscriptionJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListTranscriptionJobsRequestBuilder {
+        ListTranscriptionJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTranscriptionJobsRequest`](struct.ListTranscriptionJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTranscriptionJobsRequestBuilder {
+    job_name_contains: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<TranscriptionJobStatus>,
+}
+
+impl ListTranscriptionJobsRequestBuilder {
+    /// Sets the [`job_name_contains`](struct.ListTranscriptionJobsRequest.html#structfield.job_name_contains) member.
+    pub fn job_name_contains<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name_contains = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTranscriptionJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTranscriptionJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListTranscriptionJobsRequest.html#structfield.status) member.
+    pub fn status<T: Into<TranscriptionJobStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTranscriptionJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTranscriptionJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTranscriptionJobsRequest {
+            job_name_contains: self.job_name_contains,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -162,7 +879,7 @@ pub struct ListTranscriptionJobsResponse {
     /// <p>The requested status of the jobs returned.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TranscriptionJobStatus>,
     /// <p>A list of objects containing summary information for a transcription job.</p>
     #[serde(rename = "TranscriptionJobSummaries")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -186,7 +903,61 @@ pub struct ListVocabulariesRequest {
     /// <p>When specified, only returns vocabularies with the <code>VocabularyState</code> field equal to the specified state.</p>
     #[serde(rename = "StateEquals")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state_equals: Option<String>,
+    pub state_equals: Option<VocabularyState>,
+}
+
+impl ListVocabulariesRequest {
+    /// Returns a builder for `ListVocabulariesRequest`, checking its required members when built.
+    pub fn builder() -> ListVocabulariesRequestBuilder {
+        ListVocabulariesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListVocabulariesRequest`](struct.ListVocabulariesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListVocabulariesRequestBuilder {
+    max_results: Option<i64>,
+    name_contains: Option<String>,
+    next_token: Option<String>,
+    state_equals: Option<VocabularyState>,
+}
+
+impl ListVocabulariesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListVocabulariesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_contains`](struct.ListVocabulariesRequest.html#structfield.name_contains) member.
+    pub fn name_contains<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_contains = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListVocabulariesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state_equals`](struct.ListVocabulariesRequest.html#structfield.state_equals) member.
+    pub fn state_equals<T: Into<VocabularyState>>(mut self, value: T) -> Self {
+        self.state_equals = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVocabulariesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListVocabulariesRequest, ::rusoto_core::BuildError> {
+        Ok(ListVocabulariesRequest {
+            max_results: self.max_results,
+            name_contains: self.name_contains,
+            next_token: self.next_token,
+            state_equals: self.state_equals,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -199,7 +970,7 @@ pub struct ListVocabulariesResponse {
     /// <p>The requested vocabulary state.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<VocabularyState>,
     /// <p>A list of objects that describe the vocabularies that match the search criteria in the request.</p>
     #[serde(rename = "Vocabularies")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -215,6 +986,174 @@ pub struct Media {
     pub media_file_uri: Option<String>,
 }
 
+/// <p>The format of the input media file.</p> <p> If you do not specify the format of the media file, Amazon Transcribe determines the format. If the format is not recognized, Amazon Transcribe returns an <code>InternalFailureException</code> exception. If you specify the format, it must match the format detected by Amazon Transcribe, otherwise you get an <code>InternalFailureException</code> exception.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MediaFormat {
+    /// <p><code>mp3</code></p>
+    Mp3,
+    /// <p><code>mp4</code></p>
+    Mp4,
+    /// <p><code>wav</code></p>
+    Wav,
+    /// <p><code>flac</code></p>
+    Flac,
+    /// <p><code>ogg</code></p>
+    Ogg,
+    /// <p><code>amr</code></p>
+    Amr,
+    /// <p><code>webm</code></p>
+    Webm,
+    /// <p><code>m4a</code></p>
+    M4a,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MediaFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MediaFormat::Mp3 => "mp3",
+            MediaFormat::Mp4 => "mp4",
+            MediaFormat::Wav => "wav",
+            MediaFormat::Flac => "flac",
+            MediaFormat::Ogg => "ogg",
+            MediaFormat::Amr => "amr",
+            MediaFormat::Webm => "webm",
+            MediaFormat::M4a => "m4a",
+            MediaFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MediaFormat {
+    fn from(value: &'a str) -> MediaFormat {
+        match value {
+            "mp3" => MediaFormat::Mp3,
+            "mp4" => MediaFormat::Mp4,
+            "wav" => MediaFormat::Wav,
+            "flac" => MediaFormat::Flac,
+            "ogg" => MediaFormat::Ogg,
+            "amr" => MediaFormat::Amr,
+            "webm" => MediaFormat::Webm,
+            "m4a" => MediaFormat::M4a,
+            _ => MediaFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MediaFormat {
+    fn from(value: String) -> MediaFormat {
+        MediaFormat::from(value.as_str())
+    }
+}
+
+impl From<MediaFormat> for String {
+    fn from(value: MediaFormat) -> String {
+        match value {
+            MediaFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MediaFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MediaFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MediaFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MediaFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MediaFormat::from(value))
+    }
+}
+
+/// <p>Indicates the location of the output of the transcription job.</p> <p>If the value is <code>CUSTOMER_BUCKET</code> then the location is the S3 bucket specified in the <code>outputBucketName</code> field when the transcription job was started with the <code>StartTranscriptionJob</code> operation.</p> <p>If the value is <code>SERVICE_BUCKET</code> then the output is stored by Amazon Transcribe and can be retrieved using the URI in the <code>GetTranscriptionJob</code> response's <code>TranscriptFileUri</code> field.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OutputLocationType {
+    /// <p><code>CUSTOMER_BUCKET</code></p>
+    CustomerBucket,
+    /// <p><code>SERVICE_BUCKET</code></p>
+    ServiceBucket,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OutputLocationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OutputLocationType::CustomerBucket => "CUSTOMER_BUCKET",
+            OutputLocationType::ServiceBucket => "SERVICE_BUCKET",
+            OutputLocationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OutputLocationType {
+    fn from(value: &'a str) -> OutputLocationType {
+        match value {
+            "CUSTOMER_BUCKET" => OutputLocationType::CustomerBucket,
+            "SERVICE_BUCKET" => OutputLocationType::ServiceBucket,
+            _ => OutputLocationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OutputLocationType {
+    fn from(value: String) -> OutputLocationType {
+        OutputLocationType::from(value.as_str())
+    }
+}
+
+impl From<OutputLocationType> for String {
+    fn from(value: OutputLocationType) -> String {
+        match value {
+            OutputLocationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OutputLocationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OutputLocationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OutputLocationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OutputLocationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OutputLocationType::from(value))
+    }
+}
+
 /// <p>Provides optional settings for the <code>StartTranscriptionJob</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Settings {
@@ -236,18 +1175,18 @@ pub struct Settings {
     pub vocabulary_name: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StartTranscriptionJobRequest {
     /// <p>The language code for the language used in the input media file.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>An object that describes the input media for a transcription job.</p>
     #[serde(rename = "Media")]
     pub media: Media,
     /// <p>The format of the input media file.</p> <p> If you do not specify the format of the media file, Amazon Transcribe determines the format. If the format is not recognized, Amazon Transcribe returns an <code>InternalFailureException</code> exception. If you specify the format, it must match the format detected by Amazon Transcribe, otherwise you get an <code>InternalFailureException</code> exception.</p>
     #[serde(rename = "MediaFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub media_format: Option<String>,
+    pub media_format: Option<MediaFormat>,
     /// <p>The sample rate of the audio track in the input media file in Hertz. </p> <p>If you do not specify the media sample rate, Amazon Transcribe determines the sample rate. If you specify the sample rate, it must match the sample rate detected by Amazon Transcribe. In most cases, you should leave the <code>MediaSampleRateHertz</code> field blank and let Amazon Transcribe determine the sample rate.</p>
     #[serde(rename = "MediaSampleRateHertz")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -265,6 +1204,90 @@ pub struct StartTranscriptionJobRequest {
     pub transcription_job_name: String,
 }
 
+impl StartTranscriptionJobRequest {
+    /// Returns a builder for `StartTranscriptionJobRequest`, checking its required members when built.
+    pub fn builder() -> StartTranscriptionJobRequestBuilder {
+        StartTranscriptionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartTranscriptionJobRequest`](struct.StartTranscriptionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StartTranscriptionJobRequestBuilder {
+    language_code: Option<LanguageCode>,
+    media: Option<Media>,
+    media_format: Option<MediaFormat>,
+    media_sample_rate_hertz: Option<i64>,
+    output_bucket_name: Option<String>,
+    settings: Option<Settings>,
+    transcription_job_name: Option<String>,
+}
+
+impl StartTranscriptionJobRequestBuilder {
+    /// Sets the [`language_code`](struct.StartTranscriptionJobRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`media`](struct.StartTranscriptionJobRequest.html#structfield.media) member.
+    pub fn media<T: Into<Media>>(mut self, value: T) -> Self {
+        self.media = Some(value.into());
+        self
+    }
+
+    /// Sets the [`media_format`](struct.StartTranscriptionJobRequest.html#structfield.media_format) member.
+    pub fn media_format<T: Into<MediaFormat>>(mut self, value: T) -> Self {
+        self.media_format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`media_sample_rate_hertz`](struct.StartTranscriptionJobRequest.html#structfield.media_sample_rate_hertz) member.
+    pub fn media_sample_rate_hertz<T: Into<i64>>(mut self, value: T) -> Self {
+        self.media_sample_rate_hertz = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_bucket_name`](struct.StartTranscriptionJobRequest.html#structfield.output_bucket_name) member.
+    pub fn output_bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.output_bucket_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`settings`](struct.StartTranscriptionJobRequest.html#structfield.settings) member.
+    pub fn settings<T: Into<Settings>>(mut self, value: T) -> Self {
+        self.settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transcription_job_name`](struct.StartTranscriptionJobRequest.html#structfield.transcription_job_name) member.
+    pub fn transcription_job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.transcription_job_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartTranscriptionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartTranscriptionJobRequest, ::rusoto_core::BuildError> {
+        Ok(StartTranscriptionJobRequest {
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            media: self
+                .media
+                .ok_or(::rusoto_core::BuildError::missing("media"))?,
+            media_format: self.media_format,
+            media_sample_rate_hertz: self.media_sample_rate_hertz,
+            output_bucket_name: self.output_bucket_name,
+            settings: self.settings,
+            transcription_job_name: self
+                .transcription_job_name
+                .ok_or(::rusoto_core::BuildError::missing("transcription_job_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartTranscriptionJobResponse {
@@ -290,12 +1313,14 @@ pub struct Transcript {
 pub struct TranscriptionJob {
     /// <p>A timestamp that shows when the job was completed.</p>
     #[serde(rename = "CompletionTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_time: Option<f64>,
+    pub completion_time: Option<EpochTimestamp>,
     /// <p>A timestamp that shows when the job was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p><p>If the <code>TranscriptionJobStatus</code> field is <code>FAILED</code>, this field contains information about why the job failed.</p> <p>The <code>FailureReason</code> field can contain one of the following values:</p> <ul> <li> <p> <code>Unsupported media format</code> - The media format specified in the <code>MediaFormat</code> field of the request isn&#39;t valid. See the description of the <code>MediaFormat</code> field for a list of valid values.</p> </li> <li> <p> <code>The media format provided does not match the detected media format</code> - The media format of the audio file doesn&#39;t match the format specified in the <code>MediaFormat</code> field in the request. Check the media format of your media file and make sure that the two values match.</p> </li> <li> <p> <code>Invalid sample rate for audio file</code> - The sample rate specified in the <code>MediaSampleRateHertz</code> of the request isn&#39;t valid. The sample rate must be between 8000 and 48000 Hertz.</p> </li> <li> <p> <code>The sample rate provided does not match the detected sample rate</code> - The sample rate in the audio file doesn&#39;t match the sample rate specified in the <code>MediaSampleRateHertz</code> field in the request. Check the sample rate of your media file and make sure that the two values match.</p> </li> <li> <p> <code>Invalid file size: file size too large</code> - The size of your audio file is larger than Amazon Transcribe can process. For more information, see <a href="https://docs.aws.amazon.com/transcribe/latest/dg/limits-guidelines.html#limits">Limits</a> in the <i>Amazon Transcribe Developer Guide</i>.</p> </li> <li> <p> <code>Invalid number of channels: number of channels too large</code> - Your audio contains more channels than Amazon Transcribe is configured to process. To request additional channels, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits-amazon-transcribe">Amazon Transcribe Limits</a> in the <i>Amazon Web Services General Reference</i>.</p> </li> </ul></p>
     #[serde(rename = "FailureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -303,7 +1328,7 @@ pub struct TranscriptionJob {
     /// <p>The language code for the input speech.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>An object that describes the input media for the transcription job.</p>
     #[serde(rename = "Media")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -311,7 +1336,7 @@ pub struct TranscriptionJob {
     /// <p>The format of the input media file.</p>
     #[serde(rename = "MediaFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub media_format: Option<String>,
+    pub media_format: Option<MediaFormat>,
     /// <p>The sample rate, in Hertz, of the audio track in the input media file. </p>
     #[serde(rename = "MediaSampleRateHertz")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -331,7 +1356,87 @@ pub struct TranscriptionJob {
     /// <p>The status of the transcription job.</p>
     #[serde(rename = "TranscriptionJobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transcription_job_status: Option<String>,
+    pub transcription_job_status: Option<TranscriptionJobStatus>,
+}
+
+/// <p>When specified, returns only transcription jobs with the specified status. Jobs are ordered by creation date, with the newest jobs returned first. If you don’t specify a status, Amazon Transcribe returns all transcription jobs ordered by creation date. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TranscriptionJobStatus {
+    /// <p><code>QUEUED</code></p>
+    Queued,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TranscriptionJobStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TranscriptionJobStatus::Queued => "QUEUED",
+            TranscriptionJobStatus::InProgress => "IN_PROGRESS",
+            TranscriptionJobStatus::Failed => "FAILED",
+            TranscriptionJobStatus::Completed => "COMPLETED",
+            TranscriptionJobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TranscriptionJobStatus {
+    fn from(value: &'a str) -> TranscriptionJobStatus {
+        match value {
+            "QUEUED" => TranscriptionJobStatus::Queued,
+            "IN_PROGRESS" => TranscriptionJobStatus::InProgress,
+            "FAILED" => TranscriptionJobStatus::Failed,
+            "COMPLETED" => TranscriptionJobStatus::Completed,
+            _ => TranscriptionJobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TranscriptionJobStatus {
+    fn from(value: String) -> TranscriptionJobStatus {
+        TranscriptionJobStatus::from(value.as_str())
+    }
+}
+
+impl From<TranscriptionJobStatus> for String {
+    fn from(value: TranscriptionJobStatus) -> String {
+        match value {
+            TranscriptionJobStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TranscriptionJobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TranscriptionJobStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TranscriptionJobStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TranscriptionJobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TranscriptionJobStatus::from(value))
+    }
 }
 
 /// <p>Provides a summary of information about a transcription job. .</p>
@@ -340,12 +1445,14 @@ pub struct TranscriptionJob {
 pub struct TranscriptionJobSummary {
     /// <p>A timestamp that shows when the job was completed.</p>
     #[serde(rename = "CompletionTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_time: Option<f64>,
+    pub completion_time: Option<EpochTimestamp>,
     /// <p>A timestamp that shows when the job was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>If the <code>TranscriptionJobStatus</code> field is <code>FAILED</code>, a description of the error.</p>
     #[serde(rename = "FailureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -353,11 +1460,11 @@ pub struct TranscriptionJobSummary {
     /// <p>The language code for the input speech.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>Indicates the location of the output of the transcription job.</p> <p>If the value is <code>CUSTOMER_BUCKET</code> then the location is the S3 bucket specified in the <code>outputBucketName</code> field when the transcription job was started with the <code>StartTranscriptionJob</code> operation.</p> <p>If the value is <code>SERVICE_BUCKET</code> then the output is stored by Amazon Transcribe and can be retrieved using the URI in the <code>GetTranscriptionJob</code> response's <code>TranscriptFileUri</code> field.</p>
     #[serde(rename = "OutputLocationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub output_location_type: Option<String>,
+    pub output_location_type: Option<OutputLocationType>,
     /// <p>The name of the transcription job.</p>
     #[serde(rename = "TranscriptionJobName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -365,14 +1472,14 @@ pub struct TranscriptionJobSummary {
     /// <p>The status of the transcription job. When the status is <code>COMPLETED</code>, use the <code>GetTranscriptionJob</code> operation to get the results of the transcription.</p>
     #[serde(rename = "TranscriptionJobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transcription_job_status: Option<String>,
+    pub transcription_job_status: Option<TranscriptionJobStatus>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateVocabularyRequest {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>An array of strings containing the vocabulary entries.</p>
     #[serde(rename = "Phrases")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -386,17 +1493,76 @@ pub struct UpdateVocabularyRequest {
     pub vocabulary_name: String,
 }
 
+impl UpdateVocabularyRequest {
+    /// Returns a builder for `UpdateVocabularyRequest`, checking its required members when built.
+    pub fn builder() -> UpdateVocabularyRequestBuilder {
+        UpdateVocabularyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateVocabularyRequest`](struct.UpdateVocabularyRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateVocabularyRequestBuilder {
+    language_code: Option<LanguageCode>,
+    phrases: Option<Vec<String>>,
+    vocabulary_file_uri: Option<String>,
+    vocabulary_name: Option<String>,
+}
+
+impl UpdateVocabularyRequestBuilder {
+    /// Sets the [`language_code`](struct.UpdateVocabularyRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`phrases`](struct.UpdateVocabularyRequest.html#structfield.phrases) member.
+    pub fn phrases<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.phrases = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vocabulary_file_uri`](struct.UpdateVocabularyRequest.html#structfield.vocabulary_file_uri) member.
+    pub fn vocabulary_file_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.vocabulary_file_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vocabulary_name`](struct.UpdateVocabularyRequest.html#structfield.vocabulary_name) member.
+    pub fn vocabulary_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vocabulary_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateVocabularyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateVocabularyRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateVocabularyRequest {
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            phrases: self.phrases,
+            vocabulary_file_uri: self.vocabulary_file_uri,
+            vocabulary_name: self
+                .vocabulary_name
+                .ok_or(::rusoto_core::BuildError::missing("vocabulary_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateVocabularyResponse {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the vocabulary was updated.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<EpochTimestamp>,
     /// <p>The name of the vocabulary that was updated.</p>
     #[serde(rename = "VocabularyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -404,7 +1570,7 @@ pub struct UpdateVocabularyResponse {
     /// <p>The processing state of the vocabulary. When the <code>VocabularyState</code> field contains <code>READY</code> the vocabulary is ready to be used in a <code>StartTranscriptionJob</code> request.</p>
     #[serde(rename = "VocabularyState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vocabulary_state: Option<String>,
+    pub vocabulary_state: Option<VocabularyState>,
 }
 
 /// <p>Provides information about a custom vocabulary. </p>
@@ -414,11 +1580,12 @@ pub struct VocabularyInfo {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>The date and time that the vocabulary was last modified.</p>
     #[serde(rename = "LastModifiedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_time: Option<f64>,
+    pub last_modified_time: Option<EpochTimestamp>,
     /// <p>The name of the vocabulary.</p>
     #[serde(rename = "VocabularyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -426,7 +1593,83 @@ pub struct VocabularyInfo {
     /// <p>The processing state of the vocabulary. If the state is <code>READY</code> you can use the vocabulary in a <code>StartTranscriptionJob</code> request.</p>
     #[serde(rename = "VocabularyState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vocabulary_state: Option<String>,
+    pub vocabulary_state: Option<VocabularyState>,
+}
+
+/// <p>The processing state of the vocabulary. When the <code>VocabularyState</code> field contains <code>READY</code> the vocabulary is ready to be used in a <code>StartTranscriptionJob</code> request.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VocabularyState {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>READY</code></p>
+    Ready,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VocabularyState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VocabularyState::Pending => "PENDING",
+            VocabularyState::Ready => "READY",
+            VocabularyState::Failed => "FAILED",
+            VocabularyState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VocabularyState {
+    fn from(value: &'a str) -> VocabularyState {
+        match value {
+            "PENDING" => VocabularyState::Pending,
+            "READY" => VocabularyState::Ready,
+            "FAILED" => VocabularyState::Failed,
+            _ => VocabularyState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VocabularyState {
+    fn from(value: String) -> VocabularyState {
+        VocabularyState::from(value.as_str())
+    }
+}
+
+impl From<VocabularyState> for String {
+    fn from(value: VocabularyState) -> String {
+        match value {
+            VocabularyState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VocabularyState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for VocabularyState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VocabularyState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VocabularyState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VocabularyState::from(value))
+    }
 }
 
 /// Errors returned by CreateVocabulary
@@ -940,6 +2183,69 @@ pub trait Transcribe {
         input: UpdateVocabularyRequest,
     ) -> RusotoFuture<UpdateVocabularyResponse, UpdateVocabularyError>;
 }
+impl<T: ?Sized + Transcribe> Transcribe for ::std::sync::Arc<T> {
+    fn create_vocabulary(
+        &self,
+        input: CreateVocabularyRequest,
+    ) -> RusotoFuture<CreateVocabularyResponse, CreateVocabularyError> {
+        (**self).create_vocabulary(input)
+    }
+    fn delete_transcription_job(
+        &self,
+        input: DeleteTranscriptionJobRequest,
+    ) -> RusotoFuture<(), DeleteTranscriptionJobError> {
+        (**self).delete_transcription_job(input)
+    }
+    fn delete_vocabulary(
+        &self,
+        input: DeleteVocabularyRequest,
+    ) -> RusotoFuture<(), DeleteVocabularyError> {
+        (**self).delete_vocabulary(input)
+    }
+    fn get_transcription_job(
+        &self,
+        input: GetTranscriptionJobRequest,
+    ) -> RusotoFuture<GetTranscriptionJobResponse, GetTranscriptionJobError> {
+        (**self).get_transcription_job(input)
+    }
+    fn get_vocabulary(
+        &self,
+        input: GetVocabularyRequest,
+    ) -> RusotoFuture<GetVocabularyResponse, GetVocabularyError> {
+        (**self).get_vocabulary(input)
+    }
+    fn list_transcription_jobs(
+        &self,
+        input: ListTranscriptionJobsRequest,
+    ) -> RusotoFuture<ListTranscriptionJobsResponse, ListTranscriptionJobsError> {
+        (**self).list_transcription_jobs(input)
+    }
+    fn list_vocabularies(
+        &self,
+        input: ListVocabulariesRequest,
+    ) -> RusotoFuture<ListVocabulariesResponse, ListVocabulariesError> {
+        (**self).list_vocabularies(input)
+    }
+    fn start_transcription_job(
+        &self,
+        input: StartTranscriptionJobRequest,
+    ) -> RusotoFuture<StartTranscriptionJobResponse, StartTranscriptionJobError> {
+        (**self).start_transcription_job(input)
+    }
+    fn update_vocabulary(
+        &self,
+        input: UpdateVocabularyRequest,
+    ) -> RusotoFuture<UpdateVocabularyResponse, UpdateVocabularyError> {
+        (**self).update_vocabulary(input)
+    }
+}
+/// The error codes Amazon Transcribe Service retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Transcribe Service API.
 #[derive(Clone)]
 pub struct TranscribeClient {
@@ -955,6 +2261,27 @@ impl TranscribeClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_TRANSCRIBE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> TranscribeClient {
+        Self::new(region::Region::from_env_for_service("TRANSCRIBE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `transcribe`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> TranscribeClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("transcribe", "TRANSCRIBE"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -973,7 +2300,54 @@ impl TranscribeClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> TranscribeClient {
-        TranscribeClient { client, region }
+        TranscribeClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "transcribe")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "transcribe",
+            "transcribe",
+            "TRANSCRIBE",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Transcribe` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Transcribe + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for TranscribeClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Transcribe Service"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "transcribe"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> TranscribeClient {
+        Self::new_with_client(client, region)
     }
 }
 
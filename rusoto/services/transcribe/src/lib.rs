@@ -18,6 +18,7 @@
 
 extern crate bytes;
 extern crate futures;
+extern crate http;
 extern crate rusoto_core;
 extern crate serde;
 #[macro_use]
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Transfer for SFTP clients.
+//!
+//! Makes a single read-only `ListServers` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_transfer::{ListServersRequest, Transfer, TransferClient};
+
+fn main() {
+    let client = TransferClient::from_env();
+    match client.list_servers(ListServersRequest::default()).sync() {
+        Ok(_) => println!("ListServers succeeded"),
+        Err(error) => {
+            eprintln!("ListServers failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
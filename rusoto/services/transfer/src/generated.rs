@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateServerRequest {
@@ -32,7 +33,7 @@ pub struct CreateServerRequest {
     /// <p>The type of VPC endpoint that you want your SFTP server to connect to. If you connect to a VPC endpoint, your SFTP server isn't accessible over the public internet.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<EndpointType>,
     /// <p>The RSA private key as generated by the <code>ssh-keygen -N "" -f my-new-server-key</code> command.</p> <important> <p>If you aren't planning to migrate existing users from an existing SFTP server to a new AWS SFTP server, don't update the host key. Accidentally changing a server's host key can be disruptive.</p> </important> <p> For more information, see "https://docs.aws.amazon.com/transfer/latest/userguide/change-host-key" in the <i>AWS SFTP User Guide.</i> </p>
     #[serde(rename = "HostKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -44,7 +45,7 @@ pub struct CreateServerRequest {
     /// <p>Specifies the mode of authentication for the SFTP server. The default value is <code>SERVICE_MANAGED</code>, which allows you to store and access SFTP user credentials within the AWS Transfer for SFTP service. Use the <code>API_GATEWAY</code> value to integrate with an identity provider of your choosing. The <code>API_GATEWAY</code> setting requires you to provide an API Gateway endpoint URL to call for authentication using the <code>IdentityProviderDetails</code> parameter.</p>
     #[serde(rename = "IdentityProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_provider_type: Option<String>,
+    pub identity_provider_type: Option<IdentityProviderType>,
     /// <p>A value that allows the service to write your SFTP users' activity to your Amazon CloudWatch logs for monitoring and auditing purposes.</p>
     #[serde(rename = "LoggingRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -55,6 +56,82 @@ pub struct CreateServerRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateServerRequest {
+    /// Returns a builder for `CreateServerRequest`, checking its required members when built.
+    pub fn builder() -> CreateServerRequestBuilder {
+        CreateServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateServerRequest`](struct.CreateServerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateServerRequestBuilder {
+    endpoint_details: Option<EndpointDetails>,
+    endpoint_type: Option<EndpointType>,
+    host_key: Option<String>,
+    identity_provider_details: Option<IdentityProviderDetails>,
+    identity_provider_type: Option<IdentityProviderType>,
+    logging_role: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateServerRequestBuilder {
+    /// Sets the [`endpoint_details`](struct.CreateServerRequest.html#structfield.endpoint_details) member.
+    pub fn endpoint_details<T: Into<EndpointDetails>>(mut self, value: T) -> Self {
+        self.endpoint_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_type`](struct.CreateServerRequest.html#structfield.endpoint_type) member.
+    pub fn endpoint_type<T: Into<EndpointType>>(mut self, value: T) -> Self {
+        self.endpoint_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`host_key`](struct.CreateServerRequest.html#structfield.host_key) member.
+    pub fn host_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.host_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_provider_details`](struct.CreateServerRequest.html#structfield.identity_provider_details) member.
+    pub fn identity_provider_details<T: Into<IdentityProviderDetails>>(mut self, value: T) -> Self {
+        self.identity_provider_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_provider_type`](struct.CreateServerRequest.html#structfield.identity_provider_type) member.
+    pub fn identity_provider_type<T: Into<IdentityProviderType>>(mut self, value: T) -> Self {
+        self.identity_provider_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logging_role`](struct.CreateServerRequest.html#structfield.logging_role) member.
+    pub fn logging_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.logging_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateServerRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateServerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateServerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateServerRequest {
+            endpoint_details: self.endpoint_details,
+            endpoint_type: self.endpoint_type,
+            host_key: self.host_key,
+            identity_provider_details: self.identity_provider_details,
+            identity_provider_type: self.identity_provider_type,
+            logging_role: self.logging_role,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateServerResponse {
@@ -92,6 +169,88 @@ pub struct CreateUserRequest {
     pub user_name: String,
 }
 
+impl CreateUserRequest {
+    /// Returns a builder for `CreateUserRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserRequestBuilder {
+        CreateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserRequest`](struct.CreateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserRequestBuilder {
+    home_directory: Option<String>,
+    policy: Option<String>,
+    role: Option<String>,
+    server_id: Option<String>,
+    ssh_public_key_body: Option<String>,
+    tags: Option<Vec<Tag>>,
+    user_name: Option<String>,
+}
+
+impl CreateUserRequestBuilder {
+    /// Sets the [`home_directory`](struct.CreateUserRequest.html#structfield.home_directory) member.
+    pub fn home_directory<T: Into<String>>(mut self, value: T) -> Self {
+        self.home_directory = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.CreateUserRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.CreateUserRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_id`](struct.CreateUserRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_public_key_body`](struct.CreateUserRequest.html#structfield.ssh_public_key_body) member.
+    pub fn ssh_public_key_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateUserRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.CreateUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateUserRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserRequest {
+            home_directory: self.home_directory,
+            policy: self.policy,
+            role: self
+                .role
+                .ok_or(::rusoto_core::BuildError::missing("role"))?,
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+            ssh_public_key_body: self.ssh_public_key_body,
+            tags: self.tags,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateUserResponse {
@@ -110,6 +269,36 @@ pub struct DeleteServerRequest {
     pub server_id: String,
 }
 
+impl DeleteServerRequest {
+    /// Returns a builder for `DeleteServerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteServerRequestBuilder {
+        DeleteServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteServerRequest`](struct.DeleteServerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteServerRequestBuilder {
+    server_id: Option<String>,
+}
+
+impl DeleteServerRequestBuilder {
+    /// Sets the [`server_id`](struct.DeleteServerRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteServerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteServerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteServerRequest {
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteSshPublicKeyRequest {
     /// <p>A system-assigned unique identifier for a Secure File Transfer Protocol (SFTP) server instance that has the user assigned to it.</p>
@@ -123,6 +312,58 @@ pub struct DeleteSshPublicKeyRequest {
     pub user_name: String,
 }
 
+impl DeleteSshPublicKeyRequest {
+    /// Returns a builder for `DeleteSshPublicKeyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSshPublicKeyRequestBuilder {
+        DeleteSshPublicKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSshPublicKeyRequest`](struct.DeleteSshPublicKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSshPublicKeyRequestBuilder {
+    server_id: Option<String>,
+    ssh_public_key_id: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DeleteSshPublicKeyRequestBuilder {
+    /// Sets the [`server_id`](struct.DeleteSshPublicKeyRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_public_key_id`](struct.DeleteSshPublicKeyRequest.html#structfield.ssh_public_key_id) member.
+    pub fn ssh_public_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DeleteSshPublicKeyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSshPublicKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSshPublicKeyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSshPublicKeyRequest {
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+            ssh_public_key_id: self
+                .ssh_public_key_id
+                .ok_or(::rusoto_core::BuildError::missing("ssh_public_key_id"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteUserRequest {
     /// <p>A system-assigned unique identifier for an SFTP server instance that has the user assigned to it.</p>
@@ -133,6 +374,46 @@ pub struct DeleteUserRequest {
     pub user_name: String,
 }
 
+impl DeleteUserRequest {
+    /// Returns a builder for `DeleteUserRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserRequestBuilder {
+        DeleteUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserRequest`](struct.DeleteUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserRequestBuilder {
+    server_id: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DeleteUserRequestBuilder {
+    /// Sets the [`server_id`](struct.DeleteUserRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DeleteUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUserRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserRequest {
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeServerRequest {
     /// <p>A system-assigned unique identifier for an SFTP server.</p>
@@ -140,6 +421,36 @@ pub struct DescribeServerRequest {
     pub server_id: String,
 }
 
+impl DescribeServerRequest {
+    /// Returns a builder for `DescribeServerRequest`, checking its required members when built.
+    pub fn builder() -> DescribeServerRequestBuilder {
+        DescribeServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeServerRequest`](struct.DescribeServerRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeServerRequestBuilder {
+    server_id: Option<String>,
+}
+
+impl DescribeServerRequestBuilder {
+    /// Sets the [`server_id`](struct.DescribeServerRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeServerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeServerRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeServerRequest {
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeServerResponse {
@@ -158,6 +469,46 @@ pub struct DescribeUserRequest {
     pub user_name: String,
 }
 
+impl DescribeUserRequest {
+    /// Returns a builder for `DescribeUserRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserRequestBuilder {
+        DescribeUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserRequest`](struct.DescribeUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserRequestBuilder {
+    server_id: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DescribeUserRequestBuilder {
+    /// Sets the [`server_id`](struct.DescribeUserRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DescribeUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeUserRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUserRequest {
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUserResponse {
@@ -183,7 +534,7 @@ pub struct DescribedServer {
     /// <p>The type of endpoint that your SFTP server is connected to. If your SFTP server is connected to a VPC endpoint, your server isn't accessible over the public internet.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<EndpointType>,
     /// <p>This value contains the message-digest algorithm (MD5) hash of the server's host key. This value is equivalent to the output of the <code>ssh-keygen -l -E md5 -f my-new-server-key</code> command.</p>
     #[serde(rename = "HostKeyFingerprint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -195,7 +546,7 @@ pub struct DescribedServer {
     /// <p>This property defines the mode of authentication method enabled for this service. A value of <code>SERVICE_MANAGED</code> means that you are using this server to store and access SFTP user credentials within the service. A value of <code>API_GATEWAY</code> indicates that you have integrated an API Gateway endpoint that will be invoked for authenticating your user into the service.</p>
     #[serde(rename = "IdentityProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_provider_type: Option<String>,
+    pub identity_provider_type: Option<IdentityProviderType>,
     /// <p>This property is an AWS Identity and Access Management (IAM) entity that allows the server to turn on Amazon CloudWatch logging for Amazon S3 events. When set, user activity can be viewed in your CloudWatch logs.</p>
     #[serde(rename = "LoggingRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -207,7 +558,7 @@ pub struct DescribedServer {
     /// <p>The condition of the SFTP server for the server that was described. A value of <code>ONLINE</code> indicates that the server can accept jobs and transfer files. A <code>State</code> value of <code>OFFLINE</code> means that the server cannot perform file transfer operations.</p> <p>The states of <code>STARTING</code> and <code>STOPPING</code> indicate that the server is in an intermediate state, either not fully able to respond, or not fully offline. The values of <code>START_FAILED</code> or <code>STOP_FAILED</code> can indicate an error condition.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<State>,
     /// <p>This property contains the key-value pairs that you can use to search for and group servers that were assigned to the server that was described.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -260,6 +611,82 @@ pub struct EndpointDetails {
     pub vpc_endpoint_id: Option<String>,
 }
 
+/// <p>The type of VPC endpoint that you want your SFTP server to connect to. If you connect to a VPC endpoint, your SFTP server isn't accessible over the public internet.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EndpointType {
+    /// <p><code>PUBLIC</code></p>
+    Public,
+    /// <p><code>VPC</code></p>
+    Vpc,
+    /// <p><code>VPC_ENDPOINT</code></p>
+    VpcEndpoint,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EndpointType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EndpointType::Public => "PUBLIC",
+            EndpointType::Vpc => "VPC",
+            EndpointType::VpcEndpoint => "VPC_ENDPOINT",
+            EndpointType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EndpointType {
+    fn from(value: &'a str) -> EndpointType {
+        match value {
+            "PUBLIC" => EndpointType::Public,
+            "VPC" => EndpointType::Vpc,
+            "VPC_ENDPOINT" => EndpointType::VpcEndpoint,
+            _ => EndpointType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EndpointType {
+    fn from(value: String) -> EndpointType {
+        EndpointType::from(value.as_str())
+    }
+}
+
+impl From<EndpointType> for String {
+    fn from(value: EndpointType) -> String {
+        match value {
+            EndpointType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EndpointType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EndpointType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EndpointType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EndpointType::from(value))
+    }
+}
+
 /// <p>Returns information related to the type of user authentication that is in use for a server's users. A server can have only one method of authentication.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IdentityProviderDetails {
@@ -273,6 +700,86 @@ pub struct IdentityProviderDetails {
     pub url: Option<String>,
 }
 
+/// <p>The mode of authentication for a server. The default value is <code>SERVICE_MANAGED</code>, which allows you to store and access user credentials within the Transfer Family service.</p> <p>Use <code>AWS_DIRECTORY_SERVICE</code> to provide access to Active Directory groups in Directory Service for Microsoft Active Directory or Microsoft Active Directory in your on-premises environment or in Amazon Web Services using AD Connector. This option also requires you to provide a Directory ID by using the <code>IdentityProviderDetails</code> parameter.</p> <p>Use the <code>API_GATEWAY</code> value to integrate with an identity provider of your choosing. The <code>API_GATEWAY</code> setting requires you to provide an Amazon API Gateway endpoint URL to call for authentication by using the <code>IdentityProviderDetails</code> parameter.</p> <p>Use the <code>AWS_LAMBDA</code> value to directly use an Lambda function as your identity provider. If you choose this value, you must specify the ARN for the Lambda function in the <code>Function</code> parameter for the <code>IdentityProviderDetails</code> data type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IdentityProviderType {
+    /// <p><code>SERVICE_MANAGED</code></p>
+    ServiceManaged,
+    /// <p><code>API_GATEWAY</code></p>
+    ApiGateway,
+    /// <p><code>AWS_DIRECTORY_SERVICE</code></p>
+    AwsDirectoryService,
+    /// <p><code>AWS_LAMBDA</code></p>
+    AwsLambda,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IdentityProviderType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IdentityProviderType::ServiceManaged => "SERVICE_MANAGED",
+            IdentityProviderType::ApiGateway => "API_GATEWAY",
+            IdentityProviderType::AwsDirectoryService => "AWS_DIRECTORY_SERVICE",
+            IdentityProviderType::AwsLambda => "AWS_LAMBDA",
+            IdentityProviderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IdentityProviderType {
+    fn from(value: &'a str) -> IdentityProviderType {
+        match value {
+            "SERVICE_MANAGED" => IdentityProviderType::ServiceManaged,
+            "API_GATEWAY" => IdentityProviderType::ApiGateway,
+            "AWS_DIRECTORY_SERVICE" => IdentityProviderType::AwsDirectoryService,
+            "AWS_LAMBDA" => IdentityProviderType::AwsLambda,
+            _ => IdentityProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IdentityProviderType {
+    fn from(value: String) -> IdentityProviderType {
+        IdentityProviderType::from(value.as_str())
+    }
+}
+
+impl From<IdentityProviderType> for String {
+    fn from(value: IdentityProviderType) -> String {
+        match value {
+            IdentityProviderType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IdentityProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for IdentityProviderType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IdentityProviderType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IdentityProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IdentityProviderType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ImportSshPublicKeyRequest {
     /// <p>A system-assigned unique identifier for an SFTP server.</p>
@@ -286,6 +793,58 @@ pub struct ImportSshPublicKeyRequest {
     pub user_name: String,
 }
 
+impl ImportSshPublicKeyRequest {
+    /// Returns a builder for `ImportSshPublicKeyRequest`, checking its required members when built.
+    pub fn builder() -> ImportSshPublicKeyRequestBuilder {
+        ImportSshPublicKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportSshPublicKeyRequest`](struct.ImportSshPublicKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportSshPublicKeyRequestBuilder {
+    server_id: Option<String>,
+    ssh_public_key_body: Option<String>,
+    user_name: Option<String>,
+}
+
+impl ImportSshPublicKeyRequestBuilder {
+    /// Sets the [`server_id`](struct.ImportSshPublicKeyRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_public_key_body`](struct.ImportSshPublicKeyRequest.html#structfield.ssh_public_key_body) member.
+    pub fn ssh_public_key_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ImportSshPublicKeyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportSshPublicKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ImportSshPublicKeyRequest, ::rusoto_core::BuildError> {
+        Ok(ImportSshPublicKeyRequest {
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+            ssh_public_key_body: self
+                .ssh_public_key_body
+                .ok_or(::rusoto_core::BuildError::missing("ssh_public_key_body"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// <p>This response identifies the user, the server they belong to, and the identifier of the SSH public key associated with that user. A user can have more than one key on each server that they are associated with.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -313,6 +872,42 @@ pub struct ListServersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListServersRequest {
+    /// Returns a builder for `ListServersRequest`, checking its required members when built.
+    pub fn builder() -> ListServersRequestBuilder {
+        ListServersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListServersRequest`](struct.ListServersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListServersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListServersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListServersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListServersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListServersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListServersRequest, ::rusoto_core::BuildError> {
+        Ok(ListServersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListServersResponse {
@@ -340,6 +935,52 @@ pub struct ListTagsForResourceRequest {
     pub next_token: Option<String>,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`arn`](struct.ListTagsForResourceRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTagsForResourceRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsForResourceRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -372,6 +1013,52 @@ pub struct ListUsersRequest {
     pub server_id: String,
 }
 
+impl ListUsersRequest {
+    /// Returns a builder for `ListUsersRequest`, checking its required members when built.
+    pub fn builder() -> ListUsersRequestBuilder {
+        ListUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUsersRequest`](struct.ListUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUsersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    server_id: Option<String>,
+}
+
+impl ListUsersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListUsersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUsersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_id`](struct.ListUsersRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListUsersRequest, ::rusoto_core::BuildError> {
+        Ok(ListUsersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListUsersResponse {
@@ -397,11 +1084,11 @@ pub struct ListedServer {
     /// <p>The type of VPC endpoint that your SFTP server is connected to. If your SFTP server is connected to a VPC endpoint, your server isn't accessible over the public internet.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<EndpointType>,
     /// <p>The authentication method used to validate a user for the server that was specified. This can include Secure Shell (SSH), user name and password combinations, or your own custom authentication method. Valid values include <code>SERVICE_MANAGED</code> or <code>API_GATEWAY</code>.</p>
     #[serde(rename = "IdentityProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_provider_type: Option<String>,
+    pub identity_provider_type: Option<IdentityProviderType>,
     /// <p>The AWS Identity and Access Management entity that allows the server to turn on Amazon CloudWatch logging.</p>
     #[serde(rename = "LoggingRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -413,7 +1100,7 @@ pub struct ListedServer {
     /// <p>This property describes the condition of the SFTP server for the server that was described. A value of <code>ONLINE</code>&gt; indicates that the server can accept jobs and transfer files. A <code>State</code> value of <code>OFFLINE</code> means that the server cannot perform file transfer operations.</p> <p>The states of <code>STARTING</code> and <code>STOPPING</code> indicate that the server is in an intermediate state, either not fully able to respond, or not fully offline. The values of <code>START_FAILED</code> or <code>STOP_FAILED</code> can indicate an error condition.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<State>,
     /// <p>This property is a numeric value that indicates the number of users that are assigned to the SFTP server you specified with the <code>ServerId</code>.</p>
     #[serde(rename = "UserCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -451,7 +1138,8 @@ pub struct ListedUser {
 pub struct SshPublicKey {
     /// <p>The date that the public key was added to the user account.</p>
     #[serde(rename = "DateImported")]
-    pub date_imported: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub date_imported: EpochTimestamp,
     /// <p>The content of the SSH public key as specified by the <code>PublicKeyId</code>.</p>
     #[serde(rename = "SshPublicKeyBody")]
     pub ssh_public_key_body: String,
@@ -467,6 +1155,124 @@ pub struct StartServerRequest {
     pub server_id: String,
 }
 
+impl StartServerRequest {
+    /// Returns a builder for `StartServerRequest`, checking its required members when built.
+    pub fn builder() -> StartServerRequestBuilder {
+        StartServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartServerRequest`](struct.StartServerRequest.html).
+#[derive(Default, Debug)]
+pub struct StartServerRequestBuilder {
+    server_id: Option<String>,
+}
+
+impl StartServerRequestBuilder {
+    /// Sets the [`server_id`](struct.StartServerRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartServerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartServerRequest, ::rusoto_core::BuildError> {
+        Ok(StartServerRequest {
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+        })
+    }
+}
+
+/// <p>Describes the condition of a file transfer protocol-enabled server with respect to its ability to perform file operations. There are six possible states: <code>OFFLINE</code>, <code>ONLINE</code>, <code>STARTING</code>, <code>STOPPING</code>, <code>START_FAILED</code>, and <code>STOP_FAILED</code>.</p> <p> <code>OFFLINE</code> indicates that the server exists, but that it is not available for file operations. <code>ONLINE</code> indicates that the server is available to perform file operations. <code>STARTING</code> indicates that the server's was instantiated, but the server is not yet available to perform file operations. Under normal conditions, it can take a couple of minutes for the server to be completely operational. Both <code>START_FAILED</code> and <code>STOP_FAILED</code> are error conditions.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum State {
+    /// <p><code>OFFLINE</code></p>
+    Offline,
+    /// <p><code>ONLINE</code></p>
+    Online,
+    /// <p><code>STARTING</code></p>
+    Starting,
+    /// <p><code>STOPPING</code></p>
+    Stopping,
+    /// <p><code>START_FAILED</code></p>
+    StartFailed,
+    /// <p><code>STOP_FAILED</code></p>
+    StopFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl State {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            State::Offline => "OFFLINE",
+            State::Online => "ONLINE",
+            State::Starting => "STARTING",
+            State::Stopping => "STOPPING",
+            State::StartFailed => "START_FAILED",
+            State::StopFailed => "STOP_FAILED",
+            State::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for State {
+    fn from(value: &'a str) -> State {
+        match value {
+            "OFFLINE" => State::Offline,
+            "ONLINE" => State::Online,
+            "STARTING" => State::Starting,
+            "STOPPING" => State::Stopping,
+            "START_FAILED" => State::StartFailed,
+            "STOP_FAILED" => State::StopFailed,
+            _ => State::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for State {
+    fn from(value: String) -> State {
+        State::from(value.as_str())
+    }
+}
+
+impl From<State> for String {
+    fn from(value: State) -> String {
+        match value {
+            State::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for State {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<State, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(State::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StopServerRequest {
     /// <p>A system-assigned unique identifier for an SFTP server that you stopped.</p>
@@ -474,6 +1280,36 @@ pub struct StopServerRequest {
     pub server_id: String,
 }
 
+impl StopServerRequest {
+    /// Returns a builder for `StopServerRequest`, checking its required members when built.
+    pub fn builder() -> StopServerRequestBuilder {
+        StopServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopServerRequest`](struct.StopServerRequest.html).
+#[derive(Default, Debug)]
+pub struct StopServerRequestBuilder {
+    server_id: Option<String>,
+}
+
+impl StopServerRequestBuilder {
+    /// Sets the [`server_id`](struct.StopServerRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopServerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopServerRequest, ::rusoto_core::BuildError> {
+        Ok(StopServerRequest {
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+        })
+    }
+}
+
 /// <p>Creates a key-value pair for a specific resource. Tags are metadata that you can use to search for and group a resource for various purposes. You can apply tags to servers, users, and roles. A tag key can take more than one value. For example, to group servers for accounting purposes, you might create a tag called <code>Group</code> and assign the values <code>Research</code> and <code>Accounting</code> to that group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -495,6 +1331,44 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`arn`](struct.TagResourceRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TestIdentityProviderRequest {
     /// <p>A system-assigned identifier for a specific server. That server's user authentication method is tested with a user name and password.</p>
@@ -509,6 +1383,56 @@ pub struct TestIdentityProviderRequest {
     pub user_password: Option<String>,
 }
 
+impl TestIdentityProviderRequest {
+    /// Returns a builder for `TestIdentityProviderRequest`, checking its required members when built.
+    pub fn builder() -> TestIdentityProviderRequestBuilder {
+        TestIdentityProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TestIdentityProviderRequest`](struct.TestIdentityProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct TestIdentityProviderRequestBuilder {
+    server_id: Option<String>,
+    user_name: Option<String>,
+    user_password: Option<String>,
+}
+
+impl TestIdentityProviderRequestBuilder {
+    /// Sets the [`server_id`](struct.TestIdentityProviderRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.TestIdentityProviderRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_password`](struct.TestIdentityProviderRequest.html#structfield.user_password) member.
+    pub fn user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_password = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestIdentityProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TestIdentityProviderRequest, ::rusoto_core::BuildError> {
+        Ok(TestIdentityProviderRequest {
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+            user_password: self.user_password,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TestIdentityProviderResponse {
@@ -538,6 +1462,44 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`arn`](struct.UntagResourceRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateServerRequest {
     /// <p>The virtual private cloud (VPC) endpoint settings that are configured for your SFTP server. With a VPC endpoint, your SFTP server isn't accessible over the public internet.</p>
@@ -547,7 +1509,7 @@ pub struct UpdateServerRequest {
     /// <p>The type of endpoint that you want your SFTP server to connect to. You can choose to connect to the public internet or a virtual private cloud (VPC) endpoint. With a VPC endpoint, your SFTP server isn't accessible over the public internet. </p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<EndpointType>,
     /// <p>The RSA private key as generated by <code>ssh-keygen -N "" -f my-new-server-key</code>.</p> <important> <p>If you aren't planning to migrate existing users from an existing SFTP server to a new AWS SFTP server, don't update the host key. Accidentally changing a server's host key can be disruptive.</p> </important> <p> For more information, see "https://docs.aws.amazon.com/transfer/latest/userguide/configuring-servers.html#change-host-key" in the <i>AWS SFTP User Guide.</i> </p>
     #[serde(rename = "HostKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -565,6 +1527,76 @@ pub struct UpdateServerRequest {
     pub server_id: String,
 }
 
+impl UpdateServerRequest {
+    /// Returns a builder for `UpdateServerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateServerRequestBuilder {
+        UpdateServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateServerRequest`](struct.UpdateServerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateServerRequestBuilder {
+    endpoint_details: Option<EndpointDetails>,
+    endpoint_type: Option<EndpointType>,
+    host_key: Option<String>,
+    identity_provider_details: Option<IdentityProviderDetails>,
+    logging_role: Option<String>,
+    server_id: Option<String>,
+}
+
+impl UpdateServerRequestBuilder {
+    /// Sets the [`endpoint_details`](struct.UpdateServerRequest.html#structfield.endpoint_details) member.
+    pub fn endpoint_details<T: Into<EndpointDetails>>(mut self, value: T) -> Self {
+        self.endpoint_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_type`](struct.UpdateServerRequest.html#structfield.endpoint_type) member.
+    pub fn endpoint_type<T: Into<EndpointType>>(mut self, value: T) -> Self {
+        self.endpoint_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`host_key`](struct.UpdateServerRequest.html#structfield.host_key) member.
+    pub fn host_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.host_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_provider_details`](struct.UpdateServerRequest.html#structfield.identity_provider_details) member.
+    pub fn identity_provider_details<T: Into<IdentityProviderDetails>>(mut self, value: T) -> Self {
+        self.identity_provider_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logging_role`](struct.UpdateServerRequest.html#structfield.logging_role) member.
+    pub fn logging_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.logging_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_id`](struct.UpdateServerRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateServerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateServerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateServerRequest {
+            endpoint_details: self.endpoint_details,
+            endpoint_type: self.endpoint_type,
+            host_key: self.host_key,
+            identity_provider_details: self.identity_provider_details,
+            logging_role: self.logging_role,
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateServerResponse {
@@ -595,6 +1627,70 @@ pub struct UpdateUserRequest {
     pub user_name: String,
 }
 
+impl UpdateUserRequest {
+    /// Returns a builder for `UpdateUserRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserRequestBuilder {
+        UpdateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserRequest`](struct.UpdateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserRequestBuilder {
+    home_directory: Option<String>,
+    policy: Option<String>,
+    role: Option<String>,
+    server_id: Option<String>,
+    user_name: Option<String>,
+}
+
+impl UpdateUserRequestBuilder {
+    /// Sets the [`home_directory`](struct.UpdateUserRequest.html#structfield.home_directory) member.
+    pub fn home_directory<T: Into<String>>(mut self, value: T) -> Self {
+        self.home_directory = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.UpdateUserRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.UpdateUserRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_id`](struct.UpdateUserRequest.html#structfield.server_id) member.
+    pub fn server_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.UpdateUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateUserRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserRequest {
+            home_directory: self.home_directory,
+            policy: self.policy,
+            role: self.role,
+            server_id: self
+                .server_id
+                .ok_or(::rusoto_core::BuildError::missing("server_id"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// <p> <code>UpdateUserResponse</code> returns the user name and server identifier for the request to update a user's properties.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1643,6 +2739,105 @@ pub trait Transfer {
         input: UpdateUserRequest,
     ) -> RusotoFuture<UpdateUserResponse, UpdateUserError>;
 }
+impl<T: ?Sized + Transfer> Transfer for ::std::sync::Arc<T> {
+    fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
+        (**self).create_server(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_server(&self, input: DeleteServerRequest) -> RusotoFuture<(), DeleteServerError> {
+        (**self).delete_server(input)
+    }
+    fn delete_ssh_public_key(
+        &self,
+        input: DeleteSshPublicKeyRequest,
+    ) -> RusotoFuture<(), DeleteSshPublicKeyError> {
+        (**self).delete_ssh_public_key(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_server(
+        &self,
+        input: DescribeServerRequest,
+    ) -> RusotoFuture<DescribeServerResponse, DescribeServerError> {
+        (**self).describe_server(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn import_ssh_public_key(
+        &self,
+        input: ImportSshPublicKeyRequest,
+    ) -> RusotoFuture<ImportSshPublicKeyResponse, ImportSshPublicKeyError> {
+        (**self).import_ssh_public_key(input)
+    }
+    fn list_servers(
+        &self,
+        input: ListServersRequest,
+    ) -> RusotoFuture<ListServersResponse, ListServersError> {
+        (**self).list_servers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn start_server(&self, input: StartServerRequest) -> RusotoFuture<(), StartServerError> {
+        (**self).start_server(input)
+    }
+    fn stop_server(&self, input: StopServerRequest) -> RusotoFuture<(), StopServerError> {
+        (**self).stop_server(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_identity_provider(
+        &self,
+        input: TestIdentityProviderRequest,
+    ) -> RusotoFuture<TestIdentityProviderResponse, TestIdentityProviderError> {
+        (**self).test_identity_provider(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        (**self).update_server(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+/// The error codes AWS Transfer retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Transfer API.
 #[derive(Clone)]
 pub struct TransferClient {
@@ -1658,6 +2853,27 @@ impl TransferClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_TRANSFER`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> TransferClient {
+        Self::new(region::Region::from_env_for_service("TRANSFER"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `transfer`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> TransferClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("transfer", "TRANSFER"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1676,7 +2892,54 @@ impl TransferClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> TransferClient {
-        TransferClient { client, region }
+        TransferClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "transfer")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "transfer",
+            "transfer",
+            "TRANSFER",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Transfer` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Transfer + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for TransferClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Transfer"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "transfer"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> TransferClient {
+        Self::new_with_client(client, region)
     }
 }
 
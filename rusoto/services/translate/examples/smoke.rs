@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Translate clients.
+//!
+//! Makes a single read-only `ListTerminologies` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_translate::{ListTerminologiesRequest, Translate, TranslateClient};
+
+fn main() {
+    let client = TranslateClient::from_env();
+    match client
+        .list_terminologies(ListTerminologiesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListTerminologies succeeded"),
+        Err(error) => {
+            eprintln!("ListTerminologies failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>The custom terminology applied to the input text by Amazon Translate for the translated text response. This is optional in the response and will only be present if you specified terminology input in the request. Currently, only one terminology can be applied per TranslateText request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -44,25 +45,165 @@ pub struct DeleteTerminologyRequest {
     pub name: String,
 }
 
+impl DeleteTerminologyRequest {
+    /// Returns a builder for `DeleteTerminologyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTerminologyRequestBuilder {
+        DeleteTerminologyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTerminologyRequest`](struct.DeleteTerminologyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTerminologyRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteTerminologyRequestBuilder {
+    /// Sets the [`name`](struct.DeleteTerminologyRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTerminologyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteTerminologyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTerminologyRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>The encryption key used to encrypt the custom terminologies used by Amazon Translate.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptionKey {
     /// <p>The Amazon Resource Name (ARN) of the encryption key being used to encrypt the custom terminology.</p>
     #[serde(rename = "Id")]
     pub id: String,
     /// <p>The type of encryption key used by Amazon Translate to encrypt custom terminologies.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: EncryptionKeyType,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p>The type of encryption key used by Amazon Translate to encrypt custom terminologies.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncryptionKeyType {
+    /// <p><code>KMS</code></p>
+    Kms,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncryptionKeyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionKeyType::Kms => "KMS",
+            EncryptionKeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionKeyType {
+    fn from(value: &'a str) -> EncryptionKeyType {
+        match value {
+            "KMS" => EncryptionKeyType::Kms,
+            _ => EncryptionKeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionKeyType {
+    fn from(value: String) -> EncryptionKeyType {
+        EncryptionKeyType::from(value.as_str())
+    }
+}
+
+impl From<EncryptionKeyType> for String {
+    fn from(value: EncryptionKeyType) -> String {
+        match value {
+            EncryptionKeyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncryptionKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EncryptionKeyType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionKeyType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EncryptionKeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EncryptionKeyType::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetTerminologyRequest {
     /// <p>The name of the custom terminology being retrieved.</p>
     #[serde(rename = "Name")]
     pub name: String,
     /// <p>The data format of the custom terminology being retrieved, either CSV or TMX.</p>
     #[serde(rename = "TerminologyDataFormat")]
-    pub terminology_data_format: String,
+    pub terminology_data_format: TerminologyDataFormat,
+}
+
+impl GetTerminologyRequest {
+    /// Returns a builder for `GetTerminologyRequest`, checking its required members when built.
+    pub fn builder() -> GetTerminologyRequestBuilder {
+        GetTerminologyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTerminologyRequest`](struct.GetTerminologyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTerminologyRequestBuilder {
+    name: Option<String>,
+    terminology_data_format: Option<TerminologyDataFormat>,
+}
+
+impl GetTerminologyRequestBuilder {
+    /// Sets the [`name`](struct.GetTerminologyRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`terminology_data_format`](struct.GetTerminologyRequest.html#structfield.terminology_data_format) member.
+    pub fn terminology_data_format<T: Into<TerminologyDataFormat>>(mut self, value: T) -> Self {
+        self.terminology_data_format = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTerminologyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTerminologyRequest, ::rusoto_core::BuildError> {
+        Ok(GetTerminologyRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            terminology_data_format: self.terminology_data_format.ok_or(
+                ::rusoto_core::BuildError::missing("terminology_data_format"),
+            )?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -78,7 +219,7 @@ pub struct GetTerminologyResponse {
     pub terminology_properties: Option<TerminologyProperties>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ImportTerminologyRequest {
     /// <p>The description of the custom terminology being imported.</p>
     #[serde(rename = "Description")]
@@ -90,7 +231,7 @@ pub struct ImportTerminologyRequest {
     pub encryption_key: Option<EncryptionKey>,
     /// <p>The merge strategy of the custom terminology being imported. Currently, only the OVERWRITE merge strategy is supported. In this case, the imported terminology will overwrite an existing terminology of the same name.</p>
     #[serde(rename = "MergeStrategy")]
-    pub merge_strategy: String,
+    pub merge_strategy: MergeStrategy,
     /// <p>The name of the custom terminology being imported.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -99,6 +240,74 @@ pub struct ImportTerminologyRequest {
     pub terminology_data: TerminologyData,
 }
 
+impl ImportTerminologyRequest {
+    /// Returns a builder for `ImportTerminologyRequest`, checking its required members when built.
+    pub fn builder() -> ImportTerminologyRequestBuilder {
+        ImportTerminologyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportTerminologyRequest`](struct.ImportTerminologyRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportTerminologyRequestBuilder {
+    description: Option<String>,
+    encryption_key: Option<EncryptionKey>,
+    merge_strategy: Option<MergeStrategy>,
+    name: Option<String>,
+    terminology_data: Option<TerminologyData>,
+}
+
+impl ImportTerminologyRequestBuilder {
+    /// Sets the [`description`](struct.ImportTerminologyRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`encryption_key`](struct.ImportTerminologyRequest.html#structfield.encryption_key) member.
+    pub fn encryption_key<T: Into<EncryptionKey>>(mut self, value: T) -> Self {
+        self.encryption_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`merge_strategy`](struct.ImportTerminologyRequest.html#structfield.merge_strategy) member.
+    pub fn merge_strategy<T: Into<MergeStrategy>>(mut self, value: T) -> Self {
+        self.merge_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ImportTerminologyRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`terminology_data`](struct.ImportTerminologyRequest.html#structfield.terminology_data) member.
+    pub fn terminology_data<T: Into<TerminologyData>>(mut self, value: T) -> Self {
+        self.terminology_data = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportTerminologyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ImportTerminologyRequest, ::rusoto_core::BuildError> {
+        Ok(ImportTerminologyRequest {
+            description: self.description,
+            encryption_key: self.encryption_key,
+            merge_strategy: self
+                .merge_strategy
+                .ok_or(::rusoto_core::BuildError::missing("merge_strategy"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            terminology_data: self
+                .terminology_data
+                .ok_or(::rusoto_core::BuildError::missing("terminology_data"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ImportTerminologyResponse {
@@ -120,6 +329,44 @@ pub struct ListTerminologiesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListTerminologiesRequest {
+    /// Returns a builder for `ListTerminologiesRequest`, checking its required members when built.
+    pub fn builder() -> ListTerminologiesRequestBuilder {
+        ListTerminologiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTerminologiesRequest`](struct.ListTerminologiesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTerminologiesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListTerminologiesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListTerminologiesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTerminologiesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTerminologiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTerminologiesRequest, ::rusoto_core::BuildError> {
+        Ok(ListTerminologiesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTerminologiesResponse {
@@ -133,6 +380,74 @@ pub struct ListTerminologiesResponse {
     pub terminology_properties_list: Option<Vec<TerminologyProperties>>,
 }
 
+/// <p>The merge strategy of the custom terminology being imported. Currently, only the OVERWRITE merge strategy is supported. In this case, the imported terminology will overwrite an existing terminology of the same name.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MergeStrategy {
+    /// <p><code>OVERWRITE</code></p>
+    Overwrite,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MergeStrategy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MergeStrategy::Overwrite => "OVERWRITE",
+            MergeStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MergeStrategy {
+    fn from(value: &'a str) -> MergeStrategy {
+        match value {
+            "OVERWRITE" => MergeStrategy::Overwrite,
+            _ => MergeStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MergeStrategy {
+    fn from(value: String) -> MergeStrategy {
+        MergeStrategy::from(value.as_str())
+    }
+}
+
+impl From<MergeStrategy> for String {
+    fn from(value: MergeStrategy) -> String {
+        match value {
+            MergeStrategy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MergeStrategy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MergeStrategy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MergeStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MergeStrategy::from(value))
+    }
+}
+
 /// <p>The term being translated by the custom terminology.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -148,7 +463,7 @@ pub struct Term {
 }
 
 /// <p>The data associated with the custom terminology.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct TerminologyData {
     /// <p>The file containing the custom terminology data.</p>
     #[serde(rename = "File")]
@@ -160,7 +475,83 @@ pub struct TerminologyData {
     pub file: bytes::Bytes,
     /// <p>The data format of the custom terminology. Either CSV or TMX.</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: TerminologyDataFormat,
+}
+
+/// <p>The data format of the custom terminology being retrieved, either CSV or TMX.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TerminologyDataFormat {
+    /// <p><code>CSV</code></p>
+    Csv,
+    /// <p><code>TMX</code></p>
+    Tmx,
+    /// <p><code>TSV</code></p>
+    Tsv,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TerminologyDataFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TerminologyDataFormat::Csv => "CSV",
+            TerminologyDataFormat::Tmx => "TMX",
+            TerminologyDataFormat::Tsv => "TSV",
+            TerminologyDataFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TerminologyDataFormat {
+    fn from(value: &'a str) -> TerminologyDataFormat {
+        match value {
+            "CSV" => TerminologyDataFormat::Csv,
+            "TMX" => TerminologyDataFormat::Tmx,
+            "TSV" => TerminologyDataFormat::Tsv,
+            _ => TerminologyDataFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TerminologyDataFormat {
+    fn from(value: String) -> TerminologyDataFormat {
+        TerminologyDataFormat::from(value.as_str())
+    }
+}
+
+impl From<TerminologyDataFormat> for String {
+    fn from(value: TerminologyDataFormat) -> String {
+        match value {
+            TerminologyDataFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TerminologyDataFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TerminologyDataFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TerminologyDataFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TerminologyDataFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TerminologyDataFormat::from(value))
+    }
 }
 
 /// <p>The location of the custom terminology data.</p>
@@ -185,8 +576,9 @@ pub struct TerminologyProperties {
     pub arn: Option<String>,
     /// <p>The time at which the custom terminology was created, based on the timestamp.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The description of the custom terminology properties.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -197,8 +589,9 @@ pub struct TerminologyProperties {
     pub encryption_key: Option<EncryptionKey>,
     /// <p>The time at which the custom terminology was last update, based on the timestamp.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>The name of the custom terminology.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -238,6 +631,64 @@ pub struct TranslateTextRequest {
     pub text: String,
 }
 
+impl TranslateTextRequest {
+    /// Returns a builder for `TranslateTextRequest`, checking its required members when built.
+    pub fn builder() -> TranslateTextRequestBuilder {
+        TranslateTextRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TranslateTextRequest`](struct.TranslateTextRequest.html).
+#[derive(Default, Debug)]
+pub struct TranslateTextRequestBuilder {
+    source_language_code: Option<String>,
+    target_language_code: Option<String>,
+    terminology_names: Option<Vec<String>>,
+    text: Option<String>,
+}
+
+impl TranslateTextRequestBuilder {
+    /// Sets the [`source_language_code`](struct.TranslateTextRequest.html#structfield.source_language_code) member.
+    pub fn source_language_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_language_code`](struct.TranslateTextRequest.html#structfield.target_language_code) member.
+    pub fn target_language_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`terminology_names`](struct.TranslateTextRequest.html#structfield.terminology_names) member.
+    pub fn terminology_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.terminology_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text`](struct.TranslateTextRequest.html#structfield.text) member.
+    pub fn text<T: Into<String>>(mut self, value: T) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Builds the `TranslateTextRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TranslateTextRequest, ::rusoto_core::BuildError> {
+        Ok(TranslateTextRequest {
+            source_language_code: self
+                .source_language_code
+                .ok_or(::rusoto_core::BuildError::missing("source_language_code"))?,
+            target_language_code: self
+                .target_language_code
+                .ok_or(::rusoto_core::BuildError::missing("target_language_code"))?,
+            terminology_names: self.terminology_names,
+            text: self
+                .text
+                .ok_or(::rusoto_core::BuildError::missing("text"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TranslateTextResponse {
@@ -565,6 +1016,45 @@ pub trait Translate {
         input: TranslateTextRequest,
     ) -> RusotoFuture<TranslateTextResponse, TranslateTextError>;
 }
+impl<T: ?Sized + Translate> Translate for ::std::sync::Arc<T> {
+    fn delete_terminology(
+        &self,
+        input: DeleteTerminologyRequest,
+    ) -> RusotoFuture<(), DeleteTerminologyError> {
+        (**self).delete_terminology(input)
+    }
+    fn get_terminology(
+        &self,
+        input: GetTerminologyRequest,
+    ) -> RusotoFuture<GetTerminologyResponse, GetTerminologyError> {
+        (**self).get_terminology(input)
+    }
+    fn import_terminology(
+        &self,
+        input: ImportTerminologyRequest,
+    ) -> RusotoFuture<ImportTerminologyResponse, ImportTerminologyError> {
+        (**self).import_terminology(input)
+    }
+    fn list_terminologies(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> RusotoFuture<ListTerminologiesResponse, ListTerminologiesError> {
+        (**self).list_terminologies(input)
+    }
+    fn translate_text(
+        &self,
+        input: TranslateTextRequest,
+    ) -> RusotoFuture<TranslateTextResponse, TranslateTextError> {
+        (**self).translate_text(input)
+    }
+}
+/// The error codes Amazon Translate retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the Amazon Translate API.
 #[derive(Clone)]
 pub struct TranslateClient {
@@ -580,6 +1070,27 @@ impl TranslateClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_TRANSLATE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> TranslateClient {
+        Self::new(region::Region::from_env_for_service("TRANSLATE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `translate`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> TranslateClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("translate", "TRANSLATE"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -598,7 +1109,54 @@ impl TranslateClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> TranslateClient {
-        TranslateClient { client, region }
+        TranslateClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "translate")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "translate",
+            "translate",
+            "TRANSLATE",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Translate` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Translate + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for TranslateClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Translate"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "translate"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> TranslateClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,179 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the template in
+//  service_crategen/templates/waf/custom_tests.rs must be updated.
+//
+// =================================================================
+
+extern crate rusoto_mock;
+
+use crate::custom::rules::{
+    geo_match_updates, rate_based_rule, regular_rule, rule_group, validate_rate_limit,
+    validate_rules, web_acl_updates, ByteMatch, Field, RuleError, WebAclRulesExt, WebAclSyncError,
+};
+use crate::generated::{
+    ChangeAction, GeoMatchConstraintType, GeoMatchConstraintValue, MatchFieldType,
+    PositionalConstraint, TextTransformation, WAFRegionalClient, WafAction, WafActionType,
+    WafOverrideActionType, WafRuleType, WebACL,
+};
+
+use self::rusoto_mock::*;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{Region, RusotoError};
+
+#[test]
+fn byte_matches_are_typed_and_validated() {
+    let update = ByteMatch::new(
+        Field::QueryArg("action".to_owned()),
+        PositionalConstraint::Exactly,
+        "delete",
+    )
+    .update(ChangeAction::Insert)
+    .unwrap();
+
+    assert_eq!(update.action, ChangeAction::Insert);
+    assert_eq!(
+        update.byte_match_tuple.field_to_match.type_,
+        MatchFieldType::SingleQueryArg
+    );
+    assert_eq!(
+        update.byte_match_tuple.field_to_match.data,
+        Some("action".to_owned())
+    );
+    assert_eq!(
+        update.byte_match_tuple.positional_constraint,
+        PositionalConstraint::Exactly
+    );
+    assert_eq!(
+        update.byte_match_tuple.text_transformation,
+        TextTransformation::None
+    );
+
+    let too_long = ByteMatch::new(Field::Uri, PositionalConstraint::StartsWith, vec![b'a'; 51]);
+    assert_eq!(too_long.build(), Err(RuleError::InvalidTargetString(51)));
+}
+
+#[test]
+fn geo_matches_and_rate_limits() {
+    let updates = geo_match_updates(
+        ChangeAction::Delete,
+        &[GeoMatchConstraintValue::Gb, GeoMatchConstraintValue::Us],
+    );
+    assert_eq!(updates.len(), 2);
+    assert_eq!(updates[1].action, ChangeAction::Delete);
+    assert_eq!(
+        updates[1].geo_match_constraint.type_,
+        GeoMatchConstraintType::Country
+    );
+    assert_eq!(
+        updates[1].geo_match_constraint.value,
+        GeoMatchConstraintValue::Us
+    );
+
+    assert_eq!(validate_rate_limit(2000), Ok(2000));
+    assert_eq!(
+        validate_rate_limit(99),
+        Err(RuleError::InvalidRateLimit(99))
+    );
+}
+
+#[test]
+fn web_acl_updates_replace_changed_rules() {
+    let mut deployed = WebACL {
+        default_action: WafAction {
+            type_: WafActionType::Allow,
+        },
+        metric_name: None,
+        name: None,
+        rules: vec![
+            regular_rule("keep", 1, WafActionType::Block),
+            regular_rule("remove", 2, WafActionType::Block),
+            rate_based_rule("change", 3, WafActionType::Count),
+        ],
+        web_acl_arn: None,
+        web_acl_id: "acl".to_owned(),
+    };
+    // AWS WAF leaves out the type of regular rules
+    deployed.rules[0].type_ = None;
+
+    let desired = vec![
+        regular_rule("keep", 1, WafActionType::Block),
+        rate_based_rule("change", 3, WafActionType::Block),
+        rule_group("group", 4, WafOverrideActionType::None, &["noisy"]),
+    ];
+    let updates = web_acl_updates(&deployed, &desired);
+
+    let summary: Vec<(&str, &str)> = updates
+        .iter()
+        .map(|update| {
+            (
+                update.action.as_str(),
+                update.activated_rule.rule_id.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("DELETE", "remove"),
+            ("DELETE", "change"),
+            ("INSERT", "change"),
+            ("INSERT", "group"),
+        ]
+    );
+    let group = &updates[3].activated_rule;
+    assert_eq!(group.type_, Some(WafRuleType::Group));
+    assert_eq!(group.excluded_rules.as_ref().unwrap()[0].rule_id, "noisy");
+    assert!(group.action.is_none());
+}
+
+#[test]
+fn validate_rules_rejects_duplicate_priorities() {
+    let rules = vec![
+        regular_rule("a", 1, WafActionType::Block),
+        regular_rule("b", 1, WafActionType::Allow),
+    ];
+    assert_eq!(validate_rules(&rules), Err(RuleError::DuplicatePriority(1)));
+
+    let client = WAFRegionalClient::new_with(
+        MockRequestDispatcher::default(),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    assert_eq!(
+        client.sync_web_acl_rules("acl", rules).sync().err(),
+        Some(RusotoError::Service(WebAclSyncError::InvalidRules(
+            RuleError::DuplicatePriority(1)
+        )))
+    );
+}
+
+#[test]
+fn sync_web_acl_rules_sends_nothing_when_in_sync() {
+    let body = r#"{
+        "WebACL": {
+            "WebACLId": "acl",
+            "DefaultAction": {"Type": "ALLOW"},
+            "Rules": [{"RuleId": "rule", "Priority": 1, "Action": {"Type": "BLOCK"}}]
+        }
+    }"#;
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(body)
+        .with_request_checker(|request: &SignedRequest| {
+            let target = request.headers().get("x-amz-target").unwrap();
+            assert_eq!(target[0], b"AWSWAF_Regional_20161128.GetWebACL".to_vec());
+        });
+    let client = WAFRegionalClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let updates = client
+        .sync_web_acl_rules("acl", vec![regular_rule("rule", 1, WafActionType::Block)])
+        .sync()
+        .unwrap();
+
+    assert!(updates.is_empty());
+}
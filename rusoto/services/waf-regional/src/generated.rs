@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>The <code>ActivatedRule</code> object in an <a>UpdateWebACL</a> request specifies a <code>Rule</code> that you want to insert or delete, the priority of the <code>Rule</code> in the <code>WebACL</code>, and the action that you want AWS WAF to take when a web request matches the <code>Rule</code> (<code>ALLOW</code>, <code>BLOCK</code>, or <code>COUNT</code>).</p> <p>To specify whether to insert or delete a <code>Rule</code>, use the <code>Action</code> parameter in the <a>WebACLUpdate</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -47,7 +48,7 @@ pub struct ActivatedRule {
     /// <p>The rule type, either <code>REGULAR</code>, as defined by <a>Rule</a>, <code>RATE_BASED</code>, as defined by <a>RateBasedRule</a>, or <code>GROUP</code>, as defined by <a>RuleGroup</a>. The default is REGULAR. Although this field is optional, be aware that if you try to add a RATE_BASED rule to a web ACL without setting the type, the <a>UpdateWebACL</a> request will fail because the request tries to add a REGULAR rule with the specified ID, which does not exist. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<WafRuleType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -60,6 +61,46 @@ pub struct AssociateWebACLRequest {
     pub web_acl_id: String,
 }
 
+impl AssociateWebACLRequest {
+    /// Returns a builder for `AssociateWebACLRequest`, checking its required members when built.
+    pub fn builder() -> AssociateWebACLRequestBuilder {
+        AssociateWebACLRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateWebACLRequest`](struct.AssociateWebACLRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateWebACLRequestBuilder {
+    resource_arn: Option<String>,
+    web_acl_id: Option<String>,
+}
+
+impl AssociateWebACLRequestBuilder {
+    /// Sets the [`resource_arn`](struct.AssociateWebACLRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`web_acl_id`](struct.AssociateWebACLRequest.html#structfield.web_acl_id) member.
+    pub fn web_acl_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.web_acl_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateWebACLRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AssociateWebACLRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateWebACLRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            web_acl_id: self
+                .web_acl_id
+                .ok_or(::rusoto_core::BuildError::missing("web_acl_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateWebACLResponse {}
@@ -93,25 +134,25 @@ pub struct ByteMatchSetSummary {
 }
 
 /// <p>In an <a>UpdateByteMatchSet</a> request, <code>ByteMatchSetUpdate</code> specifies whether to insert or delete a <a>ByteMatchTuple</a> and includes the settings for the <code>ByteMatchTuple</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ByteMatchSetUpdate {
     /// <p>Specifies whether to insert or delete a <a>ByteMatchTuple</a>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Information about the part of a web request that you want AWS WAF to inspect and the value that you want AWS WAF to search for. If you specify <code>DELETE</code> for the value of <code>Action</code>, the <code>ByteMatchTuple</code> values must exactly match the values in the <code>ByteMatchTuple</code> that you want to delete from the <code>ByteMatchSet</code>.</p>
     #[serde(rename = "ByteMatchTuple")]
     pub byte_match_tuple: ByteMatchTuple,
 }
 
 /// <p>The bytes (typically a string that corresponds with ASCII characters) that you want AWS WAF to search for in web requests, the location in requests that you want AWS WAF to search, and other settings.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ByteMatchTuple {
     /// <p>The part of a web request that you want AWS WAF to search, such as a specified header or a query string. For more information, see <a>FieldToMatch</a>.</p>
     #[serde(rename = "FieldToMatch")]
     pub field_to_match: FieldToMatch,
     /// <p>Within the portion of a web request that you want to search (for example, in the query string, if any), specify where you want AWS WAF to search. Valid values include the following:</p> <p> <b>CONTAINS</b> </p> <p>The specified part of the web request must include the value of <code>TargetString</code>, but the location doesn't matter.</p> <p> <b>CONTAINS_WORD</b> </p> <p>The specified part of the web request must include the value of <code>TargetString</code>, and <code>TargetString</code> must contain only alphanumeric characters or underscore (A-Z, a-z, 0-9, or _). In addition, <code>TargetString</code> must be a word, which means one of the following:</p> <ul> <li> <p> <code>TargetString</code> exactly matches the value of the specified part of the web request, such as the value of a header.</p> </li> <li> <p> <code>TargetString</code> is at the beginning of the specified part of the web request and is followed by a character other than an alphanumeric character or underscore (_), for example, <code>BadBot;</code>.</p> </li> <li> <p> <code>TargetString</code> is at the end of the specified part of the web request and is preceded by a character other than an alphanumeric character or underscore (_), for example, <code>;BadBot</code>.</p> </li> <li> <p> <code>TargetString</code> is in the middle of the specified part of the web request and is preceded and followed by characters other than alphanumeric characters or underscore (_), for example, <code>-BadBot;</code>.</p> </li> </ul> <p> <b>EXACTLY</b> </p> <p>The value of the specified part of the web request must exactly match the value of <code>TargetString</code>.</p> <p> <b>STARTS_WITH</b> </p> <p>The value of <code>TargetString</code> must appear at the beginning of the specified part of the web request.</p> <p> <b>ENDS_WITH</b> </p> <p>The value of <code>TargetString</code> must appear at the end of the specified part of the web request.</p>
     #[serde(rename = "PositionalConstraint")]
-    pub positional_constraint: String,
+    pub positional_constraint: PositionalConstraint,
     /// <p>The value that you want AWS WAF to search for. AWS WAF searches for the specified string in the part of web requests that you specified in <code>FieldToMatch</code>. The maximum length of the value is 50 bytes.</p> <p>Valid values depend on the values that you specified for <code>FieldToMatch</code>:</p> <ul> <li> <p> <code>HEADER</code>: The value that you want AWS WAF to search for in the request header that you specified in <a>FieldToMatch</a>, for example, the value of the <code>User-Agent</code> or <code>Referer</code> header.</p> </li> <li> <p> <code>METHOD</code>: The HTTP method, which indicates the type of operation specified in the request. CloudFront supports the following methods: <code>DELETE</code>, <code>GET</code>, <code>HEAD</code>, <code>OPTIONS</code>, <code>PATCH</code>, <code>POST</code>, and <code>PUT</code>.</p> </li> <li> <p> <code>QUERY_STRING</code>: The value that you want AWS WAF to search for in the query string, which is the part of a URL that appears after a <code>?</code> character.</p> </li> <li> <p> <code>URI</code>: The value that you want AWS WAF to search for in the part of a URL that identifies a resource, for example, <code>/images/daily-ad.jpg</code>.</p> </li> <li> <p> <code>BODY</code>: The part of a request that contains any additional data that you want to send to your web server as the HTTP request body, such as data from a form. The request body immediately follows the request headers. Note that only the first <code>8192</code> bytes of the request body are forwarded to AWS WAF for inspection. To allow or block requests based on the length of the body, you can create a size constraint set. For more information, see <a>CreateSizeConstraintSet</a>. </p> </li> <li> <p> <code>SINGLE_QUERY_ARG</code>: The parameter in the query string that you will inspect, such as <i>UserName</i> or <i>SalesRegion</i>. The maximum length for <code>SINGLE_QUERY_ARG</code> is 30 characters.</p> </li> <li> <p> <code>ALL_QUERY_ARGS</code>: Similar to <code>SINGLE_QUERY_ARG</code>, but instead of inspecting a single parameter, AWS WAF inspects all parameters within the query string for the value or regex pattern that you specify in <code>TargetString</code>.</p> </li> </ul> <p>If <code>TargetString</code> includes alphabetic characters A-Z and a-z, note that the value is case sensitive.</p> <p> <b>If you're using the AWS WAF API</b> </p> <p>Specify a base64-encoded version of the value. The maximum length of the value before you base64-encode it is 50 bytes.</p> <p>For example, suppose the value of <code>Type</code> is <code>HEADER</code> and the value of <code>Data</code> is <code>User-Agent</code>. If you want to search the <code>User-Agent</code> header for the value <code>BadBot</code>, you base64-encode <code>BadBot</code> using MIME base64-encoding and include the resulting value, <code>QmFkQm90</code>, in the value of <code>TargetString</code>.</p> <p> <b>If you're using the AWS CLI or one of the AWS SDKs</b> </p> <p>The value that you want AWS WAF to search for. The SDK automatically base64 encodes the value.</p>
     #[serde(rename = "TargetString")]
     #[serde(
@@ -122,7 +163,243 @@ pub struct ByteMatchTuple {
     pub target_string: bytes::Bytes,
     /// <p>Text transformations eliminate some of the unusual formatting that attackers use in web requests in an effort to bypass AWS WAF. If you specify a transformation, AWS WAF performs the transformation on <code>TargetString</code> before inspecting a request for a match.</p> <p>You can only specify a single type of TextTransformation.</p> <p> <b>CMD_LINE</b> </p> <p>When you're concerned that attackers are injecting an operating system command line command and using unusual formatting to disguise some or all of the command, use this option to perform the following transformations:</p> <ul> <li> <p>Delete the following characters: \ " ' ^</p> </li> <li> <p>Delete spaces before the following characters: / (</p> </li> <li> <p>Replace the following characters with a space: , ;</p> </li> <li> <p>Replace multiple spaces with one space</p> </li> <li> <p>Convert uppercase letters (A-Z) to lowercase (a-z)</p> </li> </ul> <p> <b>COMPRESS_WHITE_SPACE</b> </p> <p>Use this option to replace the following characters with a space character (decimal 32):</p> <ul> <li> <p>\f, formfeed, decimal 12</p> </li> <li> <p>\t, tab, decimal 9</p> </li> <li> <p>\n, newline, decimal 10</p> </li> <li> <p>\r, carriage return, decimal 13</p> </li> <li> <p>\v, vertical tab, decimal 11</p> </li> <li> <p>non-breaking space, decimal 160</p> </li> </ul> <p> <code>COMPRESS_WHITE_SPACE</code> also replaces multiple spaces with one space.</p> <p> <b>HTML_ENTITY_DECODE</b> </p> <p>Use this option to replace HTML-encoded characters with unencoded characters. <code>HTML_ENTITY_DECODE</code> performs the following operations:</p> <ul> <li> <p>Replaces <code>(ampersand)quot;</code> with <code>"</code> </p> </li> <li> <p>Replaces <code>(ampersand)nbsp;</code> with a non-breaking space, decimal 160</p> </li> <li> <p>Replaces <code>(ampersand)lt;</code> with a "less than" symbol</p> </li> <li> <p>Replaces <code>(ampersand)gt;</code> with <code>&gt;</code> </p> </li> <li> <p>Replaces characters that are represented in hexadecimal format, <code>(ampersand)#xhhhh;</code>, with the corresponding characters</p> </li> <li> <p>Replaces characters that are represented in decimal format, <code>(ampersand)#nnnn;</code>, with the corresponding characters</p> </li> </ul> <p> <b>LOWERCASE</b> </p> <p>Use this option to convert uppercase letters (A-Z) to lowercase (a-z).</p> <p> <b>URL_DECODE</b> </p> <p>Use this option to decode a URL-encoded value.</p> <p> <b>NONE</b> </p> <p>Specify <code>NONE</code> if you don't want to perform any text transformations.</p>
     #[serde(rename = "TextTransformation")]
-    pub text_transformation: String,
+    pub text_transformation: TextTransformation,
+}
+
+/// <p>Specifies whether to insert or delete a <a>ByteMatchTuple</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeAction {
+    /// <p><code>INSERT</code></p>
+    Insert,
+    /// <p><code>DELETE</code></p>
+    Delete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeAction::Insert => "INSERT",
+            ChangeAction::Delete => "DELETE",
+            ChangeAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeAction {
+    fn from(value: &'a str) -> ChangeAction {
+        match value {
+            "INSERT" => ChangeAction::Insert,
+            "DELETE" => ChangeAction::Delete,
+            _ => ChangeAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeAction {
+    fn from(value: String) -> ChangeAction {
+        ChangeAction::from(value.as_str())
+    }
+}
+
+impl From<ChangeAction> for String {
+    fn from(value: ChangeAction) -> String {
+        match value {
+            ChangeAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ChangeAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChangeAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChangeAction::from(value))
+    }
+}
+
+/// <p>The status of the change token.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeTokenStatus {
+    /// <p><code>PROVISIONED</code></p>
+    Provisioned,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>INSYNC</code></p>
+    Insync,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeTokenStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeTokenStatus::Provisioned => "PROVISIONED",
+            ChangeTokenStatus::Pending => "PENDING",
+            ChangeTokenStatus::Insync => "INSYNC",
+            ChangeTokenStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeTokenStatus {
+    fn from(value: &'a str) -> ChangeTokenStatus {
+        match value {
+            "PROVISIONED" => ChangeTokenStatus::Provisioned,
+            "PENDING" => ChangeTokenStatus::Pending,
+            "INSYNC" => ChangeTokenStatus::Insync,
+            _ => ChangeTokenStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeTokenStatus {
+    fn from(value: String) -> ChangeTokenStatus {
+        ChangeTokenStatus::from(value.as_str())
+    }
+}
+
+impl From<ChangeTokenStatus> for String {
+    fn from(value: ChangeTokenStatus) -> String {
+        match value {
+            ChangeTokenStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeTokenStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ChangeTokenStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeTokenStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChangeTokenStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChangeTokenStatus::from(value))
+    }
+}
+
+/// <p>The type of comparison you want AWS WAF to perform. AWS WAF uses this in combination with the provided <code>Size</code> and <code>FieldToMatch</code> to build an expression in the form of "<code>Size</code> <code>ComparisonOperator</code> size in bytes of <code>FieldToMatch</code>". If that expression is true, the <code>SizeConstraint</code> is considered to match.</p> <p> <b>EQ</b>: Used to test if the <code>Size</code> is equal to the size of the <code>FieldToMatch</code> </p> <p> <b>NE</b>: Used to test if the <code>Size</code> is not equal to the size of the <code>FieldToMatch</code> </p> <p> <b>LE</b>: Used to test if the <code>Size</code> is less than or equal to the size of the <code>FieldToMatch</code> </p> <p> <b>LT</b>: Used to test if the <code>Size</code> is strictly less than the size of the <code>FieldToMatch</code> </p> <p> <b>GE</b>: Used to test if the <code>Size</code> is greater than or equal to the size of the <code>FieldToMatch</code> </p> <p> <b>GT</b>: Used to test if the <code>Size</code> is strictly greater than the size of the <code>FieldToMatch</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonOperator {
+    /// <p><code>EQ</code></p>
+    Eq,
+    /// <p><code>NE</code></p>
+    Ne,
+    /// <p><code>LE</code></p>
+    Le,
+    /// <p><code>LT</code></p>
+    Lt,
+    /// <p><code>GE</code></p>
+    Ge,
+    /// <p><code>GT</code></p>
+    Gt,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::Eq => "EQ",
+            ComparisonOperator::Ne => "NE",
+            ComparisonOperator::Le => "LE",
+            ComparisonOperator::Lt => "LT",
+            ComparisonOperator::Ge => "GE",
+            ComparisonOperator::Gt => "GT",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "EQ" => ComparisonOperator::Eq,
+            "NE" => ComparisonOperator::Ne,
+            "LE" => ComparisonOperator::Le,
+            "LT" => ComparisonOperator::Lt,
+            "GE" => ComparisonOperator::Ge,
+            "GT" => ComparisonOperator::Gt,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        ComparisonOperator::from(value.as_str())
+    }
+}
+
+impl From<ComparisonOperator> for String {
+    fn from(value: ComparisonOperator) -> String {
+        match value {
+            ComparisonOperator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ComparisonOperator::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -135,6 +412,48 @@ pub struct CreateByteMatchSetRequest {
     pub name: String,
 }
 
+impl CreateByteMatchSetRequest {
+    /// Returns a builder for `CreateByteMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateByteMatchSetRequestBuilder {
+        CreateByteMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateByteMatchSetRequest`](struct.CreateByteMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateByteMatchSetRequestBuilder {
+    change_token: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateByteMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateByteMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateByteMatchSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateByteMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateByteMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateByteMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateByteMatchSetResponse {
@@ -158,6 +477,48 @@ pub struct CreateGeoMatchSetRequest {
     pub name: String,
 }
 
+impl CreateGeoMatchSetRequest {
+    /// Returns a builder for `CreateGeoMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateGeoMatchSetRequestBuilder {
+        CreateGeoMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGeoMatchSetRequest`](struct.CreateGeoMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGeoMatchSetRequestBuilder {
+    change_token: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateGeoMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateGeoMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateGeoMatchSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGeoMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateGeoMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateGeoMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGeoMatchSetResponse {
@@ -181,6 +542,46 @@ pub struct CreateIPSetRequest {
     pub name: String,
 }
 
+impl CreateIPSetRequest {
+    /// Returns a builder for `CreateIPSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateIPSetRequestBuilder {
+        CreateIPSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateIPSetRequest`](struct.CreateIPSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateIPSetRequestBuilder {
+    change_token: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateIPSetRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateIPSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateIPSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateIPSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateIPSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateIPSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateIPSetResponse {
@@ -194,7 +595,7 @@ pub struct CreateIPSetResponse {
     pub ip_set: Option<IPSet>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateRateBasedRuleRequest {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateRateBasedRule</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -207,7 +608,7 @@ pub struct CreateRateBasedRuleRequest {
     pub name: String,
     /// <p>The field that AWS WAF uses to determine if requests are likely arriving from a single source and thus subject to rate monitoring. The only valid value for <code>RateKey</code> is <code>IP</code>. <code>IP</code> indicates that requests that arrive from the same IP address are subject to the <code>RateLimit</code> that is specified in the <code>RateBasedRule</code>.</p>
     #[serde(rename = "RateKey")]
-    pub rate_key: String,
+    pub rate_key: RateKey,
     /// <p>The maximum number of requests, which have an identical value in the field that is specified by <code>RateKey</code>, allowed in a five-minute period. If the number of requests exceeds the <code>RateLimit</code> and the other predicates specified in the rule are also met, AWS WAF triggers the action that is specified for this rule.</p>
     #[serde(rename = "RateLimit")]
     pub rate_limit: i64,
@@ -216,6 +617,86 @@ pub struct CreateRateBasedRuleRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateRateBasedRuleRequest {
+    /// Returns a builder for `CreateRateBasedRuleRequest`, checking its required members when built.
+    pub fn builder() -> CreateRateBasedRuleRequestBuilder {
+        CreateRateBasedRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRateBasedRuleRequest`](struct.CreateRateBasedRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRateBasedRuleRequestBuilder {
+    change_token: Option<String>,
+    metric_name: Option<String>,
+    name: Option<String>,
+    rate_key: Option<RateKey>,
+    rate_limit: Option<i64>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateRateBasedRuleRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateRateBasedRuleRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.CreateRateBasedRuleRequest.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateRateBasedRuleRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rate_key`](struct.CreateRateBasedRuleRequest.html#structfield.rate_key) member.
+    pub fn rate_key<T: Into<RateKey>>(mut self, value: T) -> Self {
+        self.rate_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rate_limit`](struct.CreateRateBasedRuleRequest.html#structfield.rate_limit) member.
+    pub fn rate_limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.rate_limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRateBasedRuleRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRateBasedRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateRateBasedRuleRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRateBasedRuleRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            metric_name: self
+                .metric_name
+                .ok_or(::rusoto_core::BuildError::missing("metric_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            rate_key: self
+                .rate_key
+                .ok_or(::rusoto_core::BuildError::missing("rate_key"))?,
+            rate_limit: self
+                .rate_limit
+                .ok_or(::rusoto_core::BuildError::missing("rate_limit"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRateBasedRuleResponse {
@@ -239,6 +720,48 @@ pub struct CreateRegexMatchSetRequest {
     pub name: String,
 }
 
+impl CreateRegexMatchSetRequest {
+    /// Returns a builder for `CreateRegexMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateRegexMatchSetRequestBuilder {
+        CreateRegexMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRegexMatchSetRequest`](struct.CreateRegexMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRegexMatchSetRequestBuilder {
+    change_token: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateRegexMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateRegexMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateRegexMatchSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRegexMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateRegexMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRegexMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRegexMatchSetResponse {
@@ -262,6 +785,48 @@ pub struct CreateRegexPatternSetRequest {
     pub name: String,
 }
 
+impl CreateRegexPatternSetRequest {
+    /// Returns a builder for `CreateRegexPatternSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateRegexPatternSetRequestBuilder {
+        CreateRegexPatternSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRegexPatternSetRequest`](struct.CreateRegexPatternSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRegexPatternSetRequestBuilder {
+    change_token: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateRegexPatternSetRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateRegexPatternSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateRegexPatternSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRegexPatternSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateRegexPatternSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRegexPatternSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRegexPatternSetResponse {
@@ -291,6 +856,64 @@ pub struct CreateRuleGroupRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateRuleGroupRequest {
+    /// Returns a builder for `CreateRuleGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateRuleGroupRequestBuilder {
+        CreateRuleGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRuleGroupRequest`](struct.CreateRuleGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRuleGroupRequestBuilder {
+    change_token: Option<String>,
+    metric_name: Option<String>,
+    name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateRuleGroupRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateRuleGroupRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.CreateRuleGroupRequest.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateRuleGroupRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRuleGroupRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRuleGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateRuleGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRuleGroupRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            metric_name: self
+                .metric_name
+                .ok_or(::rusoto_core::BuildError::missing("metric_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRuleGroupResponse {
@@ -320,6 +943,64 @@ pub struct CreateRuleRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateRuleRequest {
+    /// Returns a builder for `CreateRuleRequest`, checking its required members when built.
+    pub fn builder() -> CreateRuleRequestBuilder {
+        CreateRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRuleRequest`](struct.CreateRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRuleRequestBuilder {
+    change_token: Option<String>,
+    metric_name: Option<String>,
+    name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateRuleRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateRuleRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.CreateRuleRequest.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateRuleRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRuleRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateRuleRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRuleRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            metric_name: self
+                .metric_name
+                .ok_or(::rusoto_core::BuildError::missing("metric_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRuleResponse {
@@ -343,6 +1024,48 @@ pub struct CreateSizeConstraintSetRequest {
     pub name: String,
 }
 
+impl CreateSizeConstraintSetRequest {
+    /// Returns a builder for `CreateSizeConstraintSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateSizeConstraintSetRequestBuilder {
+        CreateSizeConstraintSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSizeConstraintSetRequest`](struct.CreateSizeConstraintSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSizeConstraintSetRequestBuilder {
+    change_token: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateSizeConstraintSetRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateSizeConstraintSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateSizeConstraintSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSizeConstraintSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSizeConstraintSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSizeConstraintSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSizeConstraintSetResponse {
@@ -367,6 +1090,48 @@ pub struct CreateSqlInjectionMatchSetRequest {
     pub name: String,
 }
 
+impl CreateSqlInjectionMatchSetRequest {
+    /// Returns a builder for `CreateSqlInjectionMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateSqlInjectionMatchSetRequestBuilder {
+        CreateSqlInjectionMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSqlInjectionMatchSetRequest`](struct.CreateSqlInjectionMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSqlInjectionMatchSetRequestBuilder {
+    change_token: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateSqlInjectionMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateSqlInjectionMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateSqlInjectionMatchSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSqlInjectionMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSqlInjectionMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSqlInjectionMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>The response to a <code>CreateSqlInjectionMatchSet</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -381,7 +1146,7 @@ pub struct CreateSqlInjectionMatchSetResponse {
     pub sql_injection_match_set: Option<SqlInjectionMatchSet>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateWebACLRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -400,8 +1165,76 @@ pub struct CreateWebACLRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl CreateWebACLRequest {
+    /// Returns a builder for `CreateWebACLRequest`, checking its required members when built.
+    pub fn builder() -> CreateWebACLRequestBuilder {
+        CreateWebACLRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateWebACLRequest`](struct.CreateWebACLRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateWebACLRequestBuilder {
+    change_token: Option<String>,
+    default_action: Option<WafAction>,
+    metric_name: Option<String>,
+    name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateWebACLRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateWebACLRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_action`](struct.CreateWebACLRequest.html#structfield.default_action) member.
+    pub fn default_action<T: Into<WafAction>>(mut self, value: T) -> Self {
+        self.default_action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.CreateWebACLRequest.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateWebACLRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateWebACLRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateWebACLRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateWebACLRequest, ::rusoto_core::BuildError> {
+        Ok(CreateWebACLRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            default_action: self
+                .default_action
+                .ok_or(::rusoto_core::BuildError::missing("default_action"))?,
+            metric_name: self
+                .metric_name
+                .ok_or(::rusoto_core::BuildError::missing("metric_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
 pub struct CreateWebACLResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateWebACL</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -424,6 +1257,48 @@ pub struct CreateXssMatchSetRequest {
     pub name: String,
 }
 
+impl CreateXssMatchSetRequest {
+    /// Returns a builder for `CreateXssMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateXssMatchSetRequestBuilder {
+        CreateXssMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateXssMatchSetRequest`](struct.CreateXssMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateXssMatchSetRequestBuilder {
+    change_token: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateXssMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.CreateXssMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateXssMatchSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateXssMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateXssMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateXssMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>The response to a <code>CreateXssMatchSet</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -448,6 +1323,48 @@ pub struct DeleteByteMatchSetRequest {
     pub change_token: String,
 }
 
+impl DeleteByteMatchSetRequest {
+    /// Returns a builder for `DeleteByteMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteByteMatchSetRequestBuilder {
+        DeleteByteMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteByteMatchSetRequest`](struct.DeleteByteMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteByteMatchSetRequestBuilder {
+    byte_match_set_id: Option<String>,
+    change_token: Option<String>,
+}
+
+impl DeleteByteMatchSetRequestBuilder {
+    /// Sets the [`byte_match_set_id`](struct.DeleteByteMatchSetRequest.html#structfield.byte_match_set_id) member.
+    pub fn byte_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.byte_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`change_token`](struct.DeleteByteMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteByteMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteByteMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteByteMatchSetRequest {
+            byte_match_set_id: self
+                .byte_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("byte_match_set_id"))?,
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteByteMatchSetResponse {
@@ -467,6 +1384,48 @@ pub struct DeleteGeoMatchSetRequest {
     pub geo_match_set_id: String,
 }
 
+impl DeleteGeoMatchSetRequest {
+    /// Returns a builder for `DeleteGeoMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteGeoMatchSetRequestBuilder {
+        DeleteGeoMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGeoMatchSetRequest`](struct.DeleteGeoMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteGeoMatchSetRequestBuilder {
+    change_token: Option<String>,
+    geo_match_set_id: Option<String>,
+}
+
+impl DeleteGeoMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.DeleteGeoMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`geo_match_set_id`](struct.DeleteGeoMatchSetRequest.html#structfield.geo_match_set_id) member.
+    pub fn geo_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.geo_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGeoMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteGeoMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteGeoMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            geo_match_set_id: self
+                .geo_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("geo_match_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteGeoMatchSetResponse {
@@ -486,6 +1445,46 @@ pub struct DeleteIPSetRequest {
     pub ip_set_id: String,
 }
 
+impl DeleteIPSetRequest {
+    /// Returns a builder for `DeleteIPSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIPSetRequestBuilder {
+        DeleteIPSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIPSetRequest`](struct.DeleteIPSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIPSetRequestBuilder {
+    change_token: Option<String>,
+    ip_set_id: Option<String>,
+}
+
+impl DeleteIPSetRequestBuilder {
+    /// Sets the [`change_token`](struct.DeleteIPSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ip_set_id`](struct.DeleteIPSetRequest.html#structfield.ip_set_id) member.
+    pub fn ip_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ip_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIPSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteIPSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIPSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            ip_set_id: self
+                .ip_set_id
+                .ok_or(::rusoto_core::BuildError::missing("ip_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteIPSetResponse {
@@ -502,6 +1501,38 @@ pub struct DeleteLoggingConfigurationRequest {
     pub resource_arn: String,
 }
 
+impl DeleteLoggingConfigurationRequest {
+    /// Returns a builder for `DeleteLoggingConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLoggingConfigurationRequestBuilder {
+        DeleteLoggingConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLoggingConfigurationRequest`](struct.DeleteLoggingConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLoggingConfigurationRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl DeleteLoggingConfigurationRequestBuilder {
+    /// Sets the [`resource_arn`](struct.DeleteLoggingConfigurationRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLoggingConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLoggingConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLoggingConfigurationRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteLoggingConfigurationResponse {}
@@ -513,6 +1544,38 @@ pub struct DeletePermissionPolicyRequest {
     pub resource_arn: String,
 }
 
+impl DeletePermissionPolicyRequest {
+    /// Returns a builder for `DeletePermissionPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeletePermissionPolicyRequestBuilder {
+        DeletePermissionPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePermissionPolicyRequest`](struct.DeletePermissionPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePermissionPolicyRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl DeletePermissionPolicyRequestBuilder {
+    /// Sets the [`resource_arn`](struct.DeletePermissionPolicyRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePermissionPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePermissionPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePermissionPolicyRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeletePermissionPolicyResponse {}
@@ -527,6 +1590,48 @@ pub struct DeleteRateBasedRuleRequest {
     pub rule_id: String,
 }
 
+impl DeleteRateBasedRuleRequest {
+    /// Returns a builder for `DeleteRateBasedRuleRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRateBasedRuleRequestBuilder {
+        DeleteRateBasedRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRateBasedRuleRequest`](struct.DeleteRateBasedRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRateBasedRuleRequestBuilder {
+    change_token: Option<String>,
+    rule_id: Option<String>,
+}
+
+impl DeleteRateBasedRuleRequestBuilder {
+    /// Sets the [`change_token`](struct.DeleteRateBasedRuleRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_id`](struct.DeleteRateBasedRuleRequest.html#structfield.rule_id) member.
+    pub fn rule_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRateBasedRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRateBasedRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRateBasedRuleRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            rule_id: self
+                .rule_id
+                .ok_or(::rusoto_core::BuildError::missing("rule_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRateBasedRuleResponse {
@@ -546,6 +1651,48 @@ pub struct DeleteRegexMatchSetRequest {
     pub regex_match_set_id: String,
 }
 
+impl DeleteRegexMatchSetRequest {
+    /// Returns a builder for `DeleteRegexMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRegexMatchSetRequestBuilder {
+        DeleteRegexMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRegexMatchSetRequest`](struct.DeleteRegexMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRegexMatchSetRequestBuilder {
+    change_token: Option<String>,
+    regex_match_set_id: Option<String>,
+}
+
+impl DeleteRegexMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.DeleteRegexMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`regex_match_set_id`](struct.DeleteRegexMatchSetRequest.html#structfield.regex_match_set_id) member.
+    pub fn regex_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.regex_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRegexMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRegexMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRegexMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            regex_match_set_id: self
+                .regex_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("regex_match_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRegexMatchSetResponse {
@@ -565,6 +1712,48 @@ pub struct DeleteRegexPatternSetRequest {
     pub regex_pattern_set_id: String,
 }
 
+impl DeleteRegexPatternSetRequest {
+    /// Returns a builder for `DeleteRegexPatternSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRegexPatternSetRequestBuilder {
+        DeleteRegexPatternSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRegexPatternSetRequest`](struct.DeleteRegexPatternSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRegexPatternSetRequestBuilder {
+    change_token: Option<String>,
+    regex_pattern_set_id: Option<String>,
+}
+
+impl DeleteRegexPatternSetRequestBuilder {
+    /// Sets the [`change_token`](struct.DeleteRegexPatternSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`regex_pattern_set_id`](struct.DeleteRegexPatternSetRequest.html#structfield.regex_pattern_set_id) member.
+    pub fn regex_pattern_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.regex_pattern_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRegexPatternSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRegexPatternSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRegexPatternSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            regex_pattern_set_id: self
+                .regex_pattern_set_id
+                .ok_or(::rusoto_core::BuildError::missing("regex_pattern_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRegexPatternSetResponse {
@@ -584,6 +1773,46 @@ pub struct DeleteRuleGroupRequest {
     pub rule_group_id: String,
 }
 
+impl DeleteRuleGroupRequest {
+    /// Returns a builder for `DeleteRuleGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRuleGroupRequestBuilder {
+        DeleteRuleGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRuleGroupRequest`](struct.DeleteRuleGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRuleGroupRequestBuilder {
+    change_token: Option<String>,
+    rule_group_id: Option<String>,
+}
+
+impl DeleteRuleGroupRequestBuilder {
+    /// Sets the [`change_token`](struct.DeleteRuleGroupRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_group_id`](struct.DeleteRuleGroupRequest.html#structfield.rule_group_id) member.
+    pub fn rule_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRuleGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRuleGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRuleGroupRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            rule_group_id: self
+                .rule_group_id
+                .ok_or(::rusoto_core::BuildError::missing("rule_group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRuleGroupResponse {
@@ -603,6 +1832,46 @@ pub struct DeleteRuleRequest {
     pub rule_id: String,
 }
 
+impl DeleteRuleRequest {
+    /// Returns a builder for `DeleteRuleRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRuleRequestBuilder {
+        DeleteRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRuleRequest`](struct.DeleteRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRuleRequestBuilder {
+    change_token: Option<String>,
+    rule_id: Option<String>,
+}
+
+impl DeleteRuleRequestBuilder {
+    /// Sets the [`change_token`](struct.DeleteRuleRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_id`](struct.DeleteRuleRequest.html#structfield.rule_id) member.
+    pub fn rule_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRuleRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            rule_id: self
+                .rule_id
+                .ok_or(::rusoto_core::BuildError::missing("rule_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRuleResponse {
@@ -622,6 +1891,48 @@ pub struct DeleteSizeConstraintSetRequest {
     pub size_constraint_set_id: String,
 }
 
+impl DeleteSizeConstraintSetRequest {
+    /// Returns a builder for `DeleteSizeConstraintSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSizeConstraintSetRequestBuilder {
+        DeleteSizeConstraintSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSizeConstraintSetRequest`](struct.DeleteSizeConstraintSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSizeConstraintSetRequestBuilder {
+    change_token: Option<String>,
+    size_constraint_set_id: Option<String>,
+}
+
+impl DeleteSizeConstraintSetRequestBuilder {
+    /// Sets the [`change_token`](struct.DeleteSizeConstraintSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`size_constraint_set_id`](struct.DeleteSizeConstraintSetRequest.html#structfield.size_constraint_set_id) member.
+    pub fn size_constraint_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.size_constraint_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSizeConstraintSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSizeConstraintSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSizeConstraintSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            size_constraint_set_id: self
+                .size_constraint_set_id
+                .ok_or(::rusoto_core::BuildError::missing("size_constraint_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSizeConstraintSetResponse {
@@ -642,6 +1953,48 @@ pub struct DeleteSqlInjectionMatchSetRequest {
     pub sql_injection_match_set_id: String,
 }
 
+impl DeleteSqlInjectionMatchSetRequest {
+    /// Returns a builder for `DeleteSqlInjectionMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSqlInjectionMatchSetRequestBuilder {
+        DeleteSqlInjectionMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSqlInjectionMatchSetRequest`](struct.DeleteSqlInjectionMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSqlInjectionMatchSetRequestBuilder {
+    change_token: Option<String>,
+    sql_injection_match_set_id: Option<String>,
+}
+
+impl DeleteSqlInjectionMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.DeleteSqlInjectionMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sql_injection_match_set_id`](struct.DeleteSqlInjectionMatchSetRequest.html#structfield.sql_injection_match_set_id) member.
+    pub fn sql_injection_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.sql_injection_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSqlInjectionMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSqlInjectionMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSqlInjectionMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            sql_injection_match_set_id: self.sql_injection_match_set_id.ok_or(
+                ::rusoto_core::BuildError::missing("sql_injection_match_set_id"),
+            )?,
+        })
+    }
+}
+
 /// <p>The response to a request to delete a <a>SqlInjectionMatchSet</a> from AWS WAF.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -662,6 +2015,46 @@ pub struct DeleteWebACLRequest {
     pub web_acl_id: String,
 }
 
+impl DeleteWebACLRequest {
+    /// Returns a builder for `DeleteWebACLRequest`, checking its required members when built.
+    pub fn builder() -> DeleteWebACLRequestBuilder {
+        DeleteWebACLRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteWebACLRequest`](struct.DeleteWebACLRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteWebACLRequestBuilder {
+    change_token: Option<String>,
+    web_acl_id: Option<String>,
+}
+
+impl DeleteWebACLRequestBuilder {
+    /// Sets the [`change_token`](struct.DeleteWebACLRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`web_acl_id`](struct.DeleteWebACLRequest.html#structfield.web_acl_id) member.
+    pub fn web_acl_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.web_acl_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteWebACLRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteWebACLRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteWebACLRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            web_acl_id: self
+                .web_acl_id
+                .ok_or(::rusoto_core::BuildError::missing("web_acl_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteWebACLResponse {
@@ -682,6 +2075,48 @@ pub struct DeleteXssMatchSetRequest {
     pub xss_match_set_id: String,
 }
 
+impl DeleteXssMatchSetRequest {
+    /// Returns a builder for `DeleteXssMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteXssMatchSetRequestBuilder {
+        DeleteXssMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteXssMatchSetRequest`](struct.DeleteXssMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteXssMatchSetRequestBuilder {
+    change_token: Option<String>,
+    xss_match_set_id: Option<String>,
+}
+
+impl DeleteXssMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.DeleteXssMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`xss_match_set_id`](struct.DeleteXssMatchSetRequest.html#structfield.xss_match_set_id) member.
+    pub fn xss_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.xss_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteXssMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteXssMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteXssMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            xss_match_set_id: self
+                .xss_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("xss_match_set_id"))?,
+        })
+    }
+}
+
 /// <p>The response to a request to delete an <a>XssMatchSet</a> from AWS WAF.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -699,6 +2134,38 @@ pub struct DisassociateWebACLRequest {
     pub resource_arn: String,
 }
 
+impl DisassociateWebACLRequest {
+    /// Returns a builder for `DisassociateWebACLRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateWebACLRequestBuilder {
+        DisassociateWebACLRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateWebACLRequest`](struct.DisassociateWebACLRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateWebACLRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl DisassociateWebACLRequestBuilder {
+    /// Sets the [`resource_arn`](struct.DisassociateWebACLRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateWebACLRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateWebACLRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateWebACLRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateWebACLResponse {}
@@ -712,7 +2179,7 @@ pub struct ExcludedRule {
 }
 
 /// <p>Specifies where in a web request to look for <code>TargetString</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FieldToMatch {
     /// <p>When the value of <code>Type</code> is <code>HEADER</code>, enter the name of the header that you want AWS WAF to search, for example, <code>User-Agent</code> or <code>Referer</code>. The name of the header is not case sensitive.</p> <p>When the value of <code>Type</code> is <code>SINGLE_QUERY_ARG</code>, enter the name of the parameter that you want AWS WAF to search, for example, <code>UserName</code> or <code>SalesRegion</code>. The parameter name is not case sensitive.</p> <p>If the value of <code>Type</code> is any other value, omit <code>Data</code>.</p>
     #[serde(rename = "Data")]
@@ -720,54 +2187,1182 @@ pub struct FieldToMatch {
     pub data: Option<String>,
     /// <p><p>The part of the web request that you want AWS WAF to search for a specified string. Parts of a request that you can search include the following:</p> <ul> <li> <p> <code>HEADER</code>: A specified request header, for example, the value of the <code>User-Agent</code> or <code>Referer</code> header. If you choose <code>HEADER</code> for the type, specify the name of the header in <code>Data</code>.</p> </li> <li> <p> <code>METHOD</code>: The HTTP method, which indicated the type of operation that the request is asking the origin to perform. Amazon CloudFront supports the following methods: <code>DELETE</code>, <code>GET</code>, <code>HEAD</code>, <code>OPTIONS</code>, <code>PATCH</code>, <code>POST</code>, and <code>PUT</code>.</p> </li> <li> <p> <code>QUERY<em>STRING</code>: A query string, which is the part of a URL that appears after a <code>?</code> character, if any.</p> </li> <li> <p> <code>URI</code>: The part of a web request that identifies a resource, for example, <code>/images/daily-ad.jpg</code>.</p> </li> <li> <p> <code>BODY</code>: The part of a request that contains any additional data that you want to send to your web server as the HTTP request body, such as data from a form. The request body immediately follows the request headers. Note that only the first <code>8192</code> bytes of the request body are forwarded to AWS WAF for inspection. To allow or block requests based on the length of the body, you can create a size constraint set. For more information, see <a>CreateSizeConstraintSet</a>. </p> </li> <li> <p> <code>SINGLE</em>QUERY<em>ARG</code>: The parameter in the query string that you will inspect, such as <i>UserName</i> or <i>SalesRegion</i>. The maximum length for <code>SINGLE</em>QUERY<em>ARG</code> is 30 characters.</p> </li> <li> <p> <code>ALL</em>QUERY<em>ARGS</code>: Similar to <code>SINGLE</em>QUERY_ARG</code>, but rather than inspecting a single parameter, AWS WAF will inspect all parameters within the query for the value or regex pattern that you specify in <code>TargetString</code>.</p> </li> </ul></p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: MatchFieldType,
 }
 
 /// <p>The country from which web requests originate that you want AWS WAF to search for.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GeoMatchConstraint {
     /// <p>The type of geographical area you want AWS WAF to search for. Currently <code>Country</code> is the only valid value.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: GeoMatchConstraintType,
     /// <p>The country that you want AWS WAF to search for.</p>
     #[serde(rename = "Value")]
-    pub value: String,
+    pub value: GeoMatchConstraintValue,
 }
 
-/// <p>Contains one or more countries that AWS WAF will search for.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GeoMatchSet {
-    /// <p>An array of <a>GeoMatchConstraint</a> objects, which contain the country that you want AWS WAF to search for.</p>
-    #[serde(rename = "GeoMatchConstraints")]
-    pub geo_match_constraints: Vec<GeoMatchConstraint>,
-    /// <p>The <code>GeoMatchSetId</code> for an <code>GeoMatchSet</code>. You use <code>GeoMatchSetId</code> to get information about a <code>GeoMatchSet</code> (see <a>GeoMatchSet</a>), update a <code>GeoMatchSet</code> (see <a>UpdateGeoMatchSet</a>), insert a <code>GeoMatchSet</code> into a <code>Rule</code> or delete one from a <code>Rule</code> (see <a>UpdateRule</a>), and delete a <code>GeoMatchSet</code> from AWS WAF (see <a>DeleteGeoMatchSet</a>).</p> <p> <code>GeoMatchSetId</code> is returned by <a>CreateGeoMatchSet</a> and by <a>ListGeoMatchSets</a>.</p>
-    #[serde(rename = "GeoMatchSetId")]
-    pub geo_match_set_id: String,
-    /// <p>A friendly name or description of the <a>GeoMatchSet</a>. You can't change the name of an <code>GeoMatchSet</code> after you create it.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+/// <p>The type of geographical area you want AWS WAF to search for. Currently <code>Country</code> is the only valid value.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GeoMatchConstraintType {
+    /// <p><code>Country</code></p>
+    Country,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Contains the identifier and the name of the <code>GeoMatchSet</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GeoMatchSetSummary {
-    /// <p>The <code>GeoMatchSetId</code> for an <a>GeoMatchSet</a>. You can use <code>GeoMatchSetId</code> in a <a>GetGeoMatchSet</a> request to get detailed information about an <a>GeoMatchSet</a>.</p>
-    #[serde(rename = "GeoMatchSetId")]
-    pub geo_match_set_id: String,
-    /// <p>A friendly name or description of the <a>GeoMatchSet</a>. You can't change the name of an <code>GeoMatchSet</code> after you create it.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
+impl GeoMatchConstraintType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GeoMatchConstraintType::Country => "Country",
+            GeoMatchConstraintType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Specifies the type of update to perform to an <a>GeoMatchSet</a> with <a>UpdateGeoMatchSet</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GeoMatchSetUpdate {
+impl<'a> From<&'a str> for GeoMatchConstraintType {
+    fn from(value: &'a str) -> GeoMatchConstraintType {
+        match value {
+            "Country" => GeoMatchConstraintType::Country,
+            _ => GeoMatchConstraintType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GeoMatchConstraintType {
+    fn from(value: String) -> GeoMatchConstraintType {
+        GeoMatchConstraintType::from(value.as_str())
+    }
+}
+
+impl From<GeoMatchConstraintType> for String {
+    fn from(value: GeoMatchConstraintType) -> String {
+        match value {
+            GeoMatchConstraintType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GeoMatchConstraintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for GeoMatchConstraintType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GeoMatchConstraintType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<GeoMatchConstraintType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GeoMatchConstraintType::from(value))
+    }
+}
+
+/// <p>The country that you want AWS WAF to search for.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GeoMatchConstraintValue {
+    /// <p><code>AF</code></p>
+    Af,
+    /// <p><code>AX</code></p>
+    Ax,
+    /// <p><code>AL</code></p>
+    Al,
+    /// <p><code>DZ</code></p>
+    Dz,
+    /// <p><code>AS</code></p>
+    As,
+    /// <p><code>AD</code></p>
+    Ad,
+    /// <p><code>AO</code></p>
+    Ao,
+    /// <p><code>AI</code></p>
+    Ai,
+    /// <p><code>AQ</code></p>
+    Aq,
+    /// <p><code>AG</code></p>
+    Ag,
+    /// <p><code>AR</code></p>
+    Ar,
+    /// <p><code>AM</code></p>
+    Am,
+    /// <p><code>AW</code></p>
+    Aw,
+    /// <p><code>AU</code></p>
+    Au,
+    /// <p><code>AT</code></p>
+    At,
+    /// <p><code>AZ</code></p>
+    Az,
+    /// <p><code>BS</code></p>
+    Bs,
+    /// <p><code>BH</code></p>
+    Bh,
+    /// <p><code>BD</code></p>
+    Bd,
+    /// <p><code>BB</code></p>
+    Bb,
+    /// <p><code>BY</code></p>
+    By,
+    /// <p><code>BE</code></p>
+    Be,
+    /// <p><code>BZ</code></p>
+    Bz,
+    /// <p><code>BJ</code></p>
+    Bj,
+    /// <p><code>BM</code></p>
+    Bm,
+    /// <p><code>BT</code></p>
+    Bt,
+    /// <p><code>BO</code></p>
+    Bo,
+    /// <p><code>BQ</code></p>
+    Bq,
+    /// <p><code>BA</code></p>
+    Ba,
+    /// <p><code>BW</code></p>
+    Bw,
+    /// <p><code>BV</code></p>
+    Bv,
+    /// <p><code>BR</code></p>
+    Br,
+    /// <p><code>IO</code></p>
+    Io,
+    /// <p><code>BN</code></p>
+    Bn,
+    /// <p><code>BG</code></p>
+    Bg,
+    /// <p><code>BF</code></p>
+    Bf,
+    /// <p><code>BI</code></p>
+    Bi,
+    /// <p><code>KH</code></p>
+    Kh,
+    /// <p><code>CM</code></p>
+    Cm,
+    /// <p><code>CA</code></p>
+    Ca,
+    /// <p><code>CV</code></p>
+    Cv,
+    /// <p><code>KY</code></p>
+    Ky,
+    /// <p><code>CF</code></p>
+    Cf,
+    /// <p><code>TD</code></p>
+    Td,
+    /// <p><code>CL</code></p>
+    Cl,
+    /// <p><code>CN</code></p>
+    Cn,
+    /// <p><code>CX</code></p>
+    Cx,
+    /// <p><code>CC</code></p>
+    Cc,
+    /// <p><code>CO</code></p>
+    Co,
+    /// <p><code>KM</code></p>
+    Km,
+    /// <p><code>CG</code></p>
+    Cg,
+    /// <p><code>CD</code></p>
+    Cd,
+    /// <p><code>CK</code></p>
+    Ck,
+    /// <p><code>CR</code></p>
+    Cr,
+    /// <p><code>CI</code></p>
+    Ci,
+    /// <p><code>HR</code></p>
+    Hr,
+    /// <p><code>CU</code></p>
+    Cu,
+    /// <p><code>CW</code></p>
+    Cw,
+    /// <p><code>CY</code></p>
+    Cy,
+    /// <p><code>CZ</code></p>
+    Cz,
+    /// <p><code>DK</code></p>
+    Dk,
+    /// <p><code>DJ</code></p>
+    Dj,
+    /// <p><code>DM</code></p>
+    Dm,
+    /// <p><code>DO</code></p>
+    Do,
+    /// <p><code>EC</code></p>
+    Ec,
+    /// <p><code>EG</code></p>
+    Eg,
+    /// <p><code>SV</code></p>
+    Sv,
+    /// <p><code>GQ</code></p>
+    Gq,
+    /// <p><code>ER</code></p>
+    Er,
+    /// <p><code>EE</code></p>
+    Ee,
+    /// <p><code>ET</code></p>
+    Et,
+    /// <p><code>FK</code></p>
+    Fk,
+    /// <p><code>FO</code></p>
+    Fo,
+    /// <p><code>FJ</code></p>
+    Fj,
+    /// <p><code>FI</code></p>
+    Fi,
+    /// <p><code>FR</code></p>
+    Fr,
+    /// <p><code>GF</code></p>
+    Gf,
+    /// <p><code>PF</code></p>
+    Pf,
+    /// <p><code>TF</code></p>
+    Tf,
+    /// <p><code>GA</code></p>
+    Ga,
+    /// <p><code>GM</code></p>
+    Gm,
+    /// <p><code>GE</code></p>
+    Ge,
+    /// <p><code>DE</code></p>
+    De,
+    /// <p><code>GH</code></p>
+    Gh,
+    /// <p><code>GI</code></p>
+    Gi,
+    /// <p><code>GR</code></p>
+    Gr,
+    /// <p><code>GL</code></p>
+    Gl,
+    /// <p><code>GD</code></p>
+    Gd,
+    /// <p><code>GP</code></p>
+    Gp,
+    /// <p><code>GU</code></p>
+    Gu,
+    /// <p><code>GT</code></p>
+    Gt,
+    /// <p><code>GG</code></p>
+    Gg,
+    /// <p><code>GN</code></p>
+    Gn,
+    /// <p><code>GW</code></p>
+    Gw,
+    /// <p><code>GY</code></p>
+    Gy,
+    /// <p><code>HT</code></p>
+    Ht,
+    /// <p><code>HM</code></p>
+    Hm,
+    /// <p><code>VA</code></p>
+    Va,
+    /// <p><code>HN</code></p>
+    Hn,
+    /// <p><code>HK</code></p>
+    Hk,
+    /// <p><code>HU</code></p>
+    Hu,
+    /// <p><code>IS</code></p>
+    Is,
+    /// <p><code>IN</code></p>
+    In,
+    /// <p><code>ID</code></p>
+    Id,
+    /// <p><code>IR</code></p>
+    Ir,
+    /// <p><code>IQ</code></p>
+    Iq,
+    /// <p><code>IE</code></p>
+    Ie,
+    /// <p><code>IM</code></p>
+    Im,
+    /// <p><code>IL</code></p>
+    Il,
+    /// <p><code>IT</code></p>
+    It,
+    /// <p><code>JM</code></p>
+    Jm,
+    /// <p><code>JP</code></p>
+    Jp,
+    /// <p><code>JE</code></p>
+    Je,
+    /// <p><code>JO</code></p>
+    Jo,
+    /// <p><code>KZ</code></p>
+    Kz,
+    /// <p><code>KE</code></p>
+    Ke,
+    /// <p><code>KI</code></p>
+    Ki,
+    /// <p><code>KP</code></p>
+    Kp,
+    /// <p><code>KR</code></p>
+    Kr,
+    /// <p><code>KW</code></p>
+    Kw,
+    /// <p><code>KG</code></p>
+    Kg,
+    /// <p><code>LA</code></p>
+    La,
+    /// <p><code>LV</code></p>
+    Lv,
+    /// <p><code>LB</code></p>
+    Lb,
+    /// <p><code>LS</code></p>
+    Ls,
+    /// <p><code>LR</code></p>
+    Lr,
+    /// <p><code>LY</code></p>
+    Ly,
+    /// <p><code>LI</code></p>
+    Li,
+    /// <p><code>LT</code></p>
+    Lt,
+    /// <p><code>LU</code></p>
+    Lu,
+    /// <p><code>MO</code></p>
+    Mo,
+    /// <p><code>MK</code></p>
+    Mk,
+    /// <p><code>MG</code></p>
+    Mg,
+    /// <p><code>MW</code></p>
+    Mw,
+    /// <p><code>MY</code></p>
+    My,
+    /// <p><code>MV</code></p>
+    Mv,
+    /// <p><code>ML</code></p>
+    Ml,
+    /// <p><code>MT</code></p>
+    Mt,
+    /// <p><code>MH</code></p>
+    Mh,
+    /// <p><code>MQ</code></p>
+    Mq,
+    /// <p><code>MR</code></p>
+    Mr,
+    /// <p><code>MU</code></p>
+    Mu,
+    /// <p><code>YT</code></p>
+    Yt,
+    /// <p><code>MX</code></p>
+    Mx,
+    /// <p><code>FM</code></p>
+    Fm,
+    /// <p><code>MD</code></p>
+    Md,
+    /// <p><code>MC</code></p>
+    Mc,
+    /// <p><code>MN</code></p>
+    Mn,
+    /// <p><code>ME</code></p>
+    Me,
+    /// <p><code>MS</code></p>
+    Ms,
+    /// <p><code>MA</code></p>
+    Ma,
+    /// <p><code>MZ</code></p>
+    Mz,
+    /// <p><code>MM</code></p>
+    Mm,
+    /// <p><code>NA</code></p>
+    Na,
+    /// <p><code>NR</code></p>
+    Nr,
+    /// <p><code>NP</code></p>
+    Np,
+    /// <p><code>NL</code></p>
+    Nl,
+    /// <p><code>NC</code></p>
+    Nc,
+    /// <p><code>NZ</code></p>
+    Nz,
+    /// <p><code>NI</code></p>
+    Ni,
+    /// <p><code>NE</code></p>
+    Ne,
+    /// <p><code>NG</code></p>
+    Ng,
+    /// <p><code>NU</code></p>
+    Nu,
+    /// <p><code>NF</code></p>
+    Nf,
+    /// <p><code>MP</code></p>
+    Mp,
+    /// <p><code>NO</code></p>
+    No,
+    /// <p><code>OM</code></p>
+    Om,
+    /// <p><code>PK</code></p>
+    Pk,
+    /// <p><code>PW</code></p>
+    Pw,
+    /// <p><code>PS</code></p>
+    Ps,
+    /// <p><code>PA</code></p>
+    Pa,
+    /// <p><code>PG</code></p>
+    Pg,
+    /// <p><code>PY</code></p>
+    Py,
+    /// <p><code>PE</code></p>
+    Pe,
+    /// <p><code>PH</code></p>
+    Ph,
+    /// <p><code>PN</code></p>
+    Pn,
+    /// <p><code>PL</code></p>
+    Pl,
+    /// <p><code>PT</code></p>
+    Pt,
+    /// <p><code>PR</code></p>
+    Pr,
+    /// <p><code>QA</code></p>
+    Qa,
+    /// <p><code>RE</code></p>
+    Re,
+    /// <p><code>RO</code></p>
+    Ro,
+    /// <p><code>RU</code></p>
+    Ru,
+    /// <p><code>RW</code></p>
+    Rw,
+    /// <p><code>BL</code></p>
+    Bl,
+    /// <p><code>SH</code></p>
+    Sh,
+    /// <p><code>KN</code></p>
+    Kn,
+    /// <p><code>LC</code></p>
+    Lc,
+    /// <p><code>MF</code></p>
+    Mf,
+    /// <p><code>PM</code></p>
+    Pm,
+    /// <p><code>VC</code></p>
+    Vc,
+    /// <p><code>WS</code></p>
+    Ws,
+    /// <p><code>SM</code></p>
+    Sm,
+    /// <p><code>ST</code></p>
+    St,
+    /// <p><code>SA</code></p>
+    Sa,
+    /// <p><code>SN</code></p>
+    Sn,
+    /// <p><code>RS</code></p>
+    Rs,
+    /// <p><code>SC</code></p>
+    Sc,
+    /// <p><code>SL</code></p>
+    Sl,
+    /// <p><code>SG</code></p>
+    Sg,
+    /// <p><code>SX</code></p>
+    Sx,
+    /// <p><code>SK</code></p>
+    Sk,
+    /// <p><code>SI</code></p>
+    Si,
+    /// <p><code>SB</code></p>
+    Sb,
+    /// <p><code>SO</code></p>
+    So,
+    /// <p><code>ZA</code></p>
+    Za,
+    /// <p><code>GS</code></p>
+    Gs,
+    /// <p><code>SS</code></p>
+    Ss,
+    /// <p><code>ES</code></p>
+    Es,
+    /// <p><code>LK</code></p>
+    Lk,
+    /// <p><code>SD</code></p>
+    Sd,
+    /// <p><code>SR</code></p>
+    Sr,
+    /// <p><code>SJ</code></p>
+    Sj,
+    /// <p><code>SZ</code></p>
+    Sz,
+    /// <p><code>SE</code></p>
+    Se,
+    /// <p><code>CH</code></p>
+    Ch,
+    /// <p><code>SY</code></p>
+    Sy,
+    /// <p><code>TW</code></p>
+    Tw,
+    /// <p><code>TJ</code></p>
+    Tj,
+    /// <p><code>TZ</code></p>
+    Tz,
+    /// <p><code>TH</code></p>
+    Th,
+    /// <p><code>TL</code></p>
+    Tl,
+    /// <p><code>TG</code></p>
+    Tg,
+    /// <p><code>TK</code></p>
+    Tk,
+    /// <p><code>TO</code></p>
+    To,
+    /// <p><code>TT</code></p>
+    Tt,
+    /// <p><code>TN</code></p>
+    Tn,
+    /// <p><code>TR</code></p>
+    Tr,
+    /// <p><code>TM</code></p>
+    Tm,
+    /// <p><code>TC</code></p>
+    Tc,
+    /// <p><code>TV</code></p>
+    Tv,
+    /// <p><code>UG</code></p>
+    Ug,
+    /// <p><code>UA</code></p>
+    Ua,
+    /// <p><code>AE</code></p>
+    Ae,
+    /// <p><code>GB</code></p>
+    Gb,
+    /// <p><code>US</code></p>
+    Us,
+    /// <p><code>UM</code></p>
+    Um,
+    /// <p><code>UY</code></p>
+    Uy,
+    /// <p><code>UZ</code></p>
+    Uz,
+    /// <p><code>VU</code></p>
+    Vu,
+    /// <p><code>VE</code></p>
+    Ve,
+    /// <p><code>VN</code></p>
+    Vn,
+    /// <p><code>VG</code></p>
+    Vg,
+    /// <p><code>VI</code></p>
+    Vi,
+    /// <p><code>WF</code></p>
+    Wf,
+    /// <p><code>EH</code></p>
+    Eh,
+    /// <p><code>YE</code></p>
+    Ye,
+    /// <p><code>ZM</code></p>
+    Zm,
+    /// <p><code>ZW</code></p>
+    Zw,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GeoMatchConstraintValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GeoMatchConstraintValue::Af => "AF",
+            GeoMatchConstraintValue::Ax => "AX",
+            GeoMatchConstraintValue::Al => "AL",
+            GeoMatchConstraintValue::Dz => "DZ",
+            GeoMatchConstraintValue::As => "AS",
+            GeoMatchConstraintValue::Ad => "AD",
+            GeoMatchConstraintValue::Ao => "AO",
+            GeoMatchConstraintValue::Ai => "AI",
+            GeoMatchConstraintValue::Aq => "AQ",
+            GeoMatchConstraintValue::Ag => "AG",
+            GeoMatchConstraintValue::Ar => "AR",
+            GeoMatchConstraintValue::Am => "AM",
+            GeoMatchConstraintValue::Aw => "AW",
+            GeoMatchConstraintValue::Au => "AU",
+            GeoMatchConstraintValue::At => "AT",
+            GeoMatchConstraintValue::Az => "AZ",
+            GeoMatchConstraintValue::Bs => "BS",
+            GeoMatchConstraintValue::Bh => "BH",
+            GeoMatchConstraintValue::Bd => "BD",
+            GeoMatchConstraintValue::Bb => "BB",
+            GeoMatchConstraintValue::By => "BY",
+            GeoMatchConstraintValue::Be => "BE",
+            GeoMatchConstraintValue::Bz => "BZ",
+            GeoMatchConstraintValue::Bj => "BJ",
+            GeoMatchConstraintValue::Bm => "BM",
+            GeoMatchConstraintValue::Bt => "BT",
+            GeoMatchConstraintValue::Bo => "BO",
+            GeoMatchConstraintValue::Bq => "BQ",
+            GeoMatchConstraintValue::Ba => "BA",
+            GeoMatchConstraintValue::Bw => "BW",
+            GeoMatchConstraintValue::Bv => "BV",
+            GeoMatchConstraintValue::Br => "BR",
+            GeoMatchConstraintValue::Io => "IO",
+            GeoMatchConstraintValue::Bn => "BN",
+            GeoMatchConstraintValue::Bg => "BG",
+            GeoMatchConstraintValue::Bf => "BF",
+            GeoMatchConstraintValue::Bi => "BI",
+            GeoMatchConstraintValue::Kh => "KH",
+            GeoMatchConstraintValue::Cm => "CM",
+            GeoMatchConstraintValue::Ca => "CA",
+            GeoMatchConstraintValue::Cv => "CV",
+            GeoMatchConstraintValue::Ky => "KY",
+            GeoMatchConstraintValue::Cf => "CF",
+            GeoMatchConstraintValue::Td => "TD",
+            GeoMatchConstraintValue::Cl => "CL",
+            GeoMatchConstraintValue::Cn => "CN",
+            GeoMatchConstraintValue::Cx => "CX",
+            GeoMatchConstraintValue::Cc => "CC",
+            GeoMatchConstraintValue::Co => "CO",
+            GeoMatchConstraintValue::Km => "KM",
+            GeoMatchConstraintValue::Cg => "CG",
+            GeoMatchConstraintValue::Cd => "CD",
+            GeoMatchConstraintValue::Ck => "CK",
+            GeoMatchConstraintValue::Cr => "CR",
+            GeoMatchConstraintValue::Ci => "CI",
+            GeoMatchConstraintValue::Hr => "HR",
+            GeoMatchConstraintValue::Cu => "CU",
+            GeoMatchConstraintValue::Cw => "CW",
+            GeoMatchConstraintValue::Cy => "CY",
+            GeoMatchConstraintValue::Cz => "CZ",
+            GeoMatchConstraintValue::Dk => "DK",
+            GeoMatchConstraintValue::Dj => "DJ",
+            GeoMatchConstraintValue::Dm => "DM",
+            GeoMatchConstraintValue::Do => "DO",
+            GeoMatchConstraintValue::Ec => "EC",
+            GeoMatchConstraintValue::Eg => "EG",
+            GeoMatchConstraintValue::Sv => "SV",
+            GeoMatchConstraintValue::Gq => "GQ",
+            GeoMatchConstraintValue::Er => "ER",
+            GeoMatchConstraintValue::Ee => "EE",
+            GeoMatchConstraintValue::Et => "ET",
+            GeoMatchConstraintValue::Fk => "FK",
+            GeoMatchConstraintValue::Fo => "FO",
+            GeoMatchConstraintValue::Fj => "FJ",
+            GeoMatchConstraintValue::Fi => "FI",
+            GeoMatchConstraintValue::Fr => "FR",
+            GeoMatchConstraintValue::Gf => "GF",
+            GeoMatchConstraintValue::Pf => "PF",
+            GeoMatchConstraintValue::Tf => "TF",
+            GeoMatchConstraintValue::Ga => "GA",
+            GeoMatchConstraintValue::Gm => "GM",
+            GeoMatchConstraintValue::Ge => "GE",
+            GeoMatchConstraintValue::De => "DE",
+            GeoMatchConstraintValue::Gh => "GH",
+            GeoMatchConstraintValue::Gi => "GI",
+            GeoMatchConstraintValue::Gr => "GR",
+            GeoMatchConstraintValue::Gl => "GL",
+            GeoMatchConstraintValue::Gd => "GD",
+            GeoMatchConstraintValue::Gp => "GP",
+            GeoMatchConstraintValue::Gu => "GU",
+            GeoMatchConstraintValue::Gt => "GT",
+            GeoMatchConstraintValue::Gg => "GG",
+            GeoMatchConstraintValue::Gn => "GN",
+            GeoMatchConstraintValue::Gw => "GW",
+            GeoMatchConstraintValue::Gy => "GY",
+            GeoMatchConstraintValue::Ht => "HT",
+            GeoMatchConstraintValue::Hm => "HM",
+            GeoMatchConstraintValue::Va => "VA",
+            GeoMatchConstraintValue::Hn => "HN",
+            GeoMatchConstraintValue::Hk => "HK",
+            GeoMatchConstraintValue::Hu => "HU",
+            GeoMatchConstraintValue::Is => "IS",
+            GeoMatchConstraintValue::In => "IN",
+            GeoMatchConstraintValue::Id => "ID",
+            GeoMatchConstraintValue::Ir => "IR",
+            GeoMatchConstraintValue::Iq => "IQ",
+            GeoMatchConstraintValue::Ie => "IE",
+            GeoMatchConstraintValue::Im => "IM",
+            GeoMatchConstraintValue::Il => "IL",
+            GeoMatchConstraintValue::It => "IT",
+            GeoMatchConstraintValue::Jm => "JM",
+            GeoMatchConstraintValue::Jp => "JP",
+            GeoMatchConstraintValue::Je => "JE",
+            GeoMatchConstraintValue::Jo => "JO",
+            GeoMatchConstraintValue::Kz => "KZ",
+            GeoMatchConstraintValue::Ke => "KE",
+            GeoMatchConstraintValue::Ki => "KI",
+            GeoMatchConstraintValue::Kp => "KP",
+            GeoMatchConstraintValue::Kr => "KR",
+            GeoMatchConstraintValue::Kw => "KW",
+            GeoMatchConstraintValue::Kg => "KG",
+            GeoMatchConstraintValue::La => "LA",
+            GeoMatchConstraintValue::Lv => "LV",
+            GeoMatchConstraintValue::Lb => "LB",
+            GeoMatchConstraintValue::Ls => "LS",
+            GeoMatchConstraintValue::Lr => "LR",
+            GeoMatchConstraintValue::Ly => "LY",
+            GeoMatchConstraintValue::Li => "LI",
+            GeoMatchConstraintValue::Lt => "LT",
+            GeoMatchConstraintValue::Lu => "LU",
+            GeoMatchConstraintValue::Mo => "MO",
+            GeoMatchConstraintValue::Mk => "MK",
+            GeoMatchConstraintValue::Mg => "MG",
+            GeoMatchConstraintValue::Mw => "MW",
+            GeoMatchConstraintValue::My => "MY",
+            GeoMatchConstraintValue::Mv => "MV",
+            GeoMatchConstraintValue::Ml => "ML",
+            GeoMatchConstraintValue::Mt => "MT",
+            GeoMatchConstraintValue::Mh => "MH",
+            GeoMatchConstraintValue::Mq => "MQ",
+            GeoMatchConstraintValue::Mr => "MR",
+            GeoMatchConstraintValue::Mu => "MU",
+            GeoMatchConstraintValue::Yt => "YT",
+            GeoMatchConstraintValue::Mx => "MX",
+            GeoMatchConstraintValue::Fm => "FM",
+            GeoMatchConstraintValue::Md => "MD",
+            GeoMatchConstraintValue::Mc => "MC",
+            GeoMatchConstraintValue::Mn => "MN",
+            GeoMatchConstraintValue::Me => "ME",
+            GeoMatchConstraintValue::Ms => "MS",
+            GeoMatchConstraintValue::Ma => "MA",
+            GeoMatchConstraintValue::Mz => "MZ",
+            GeoMatchConstraintValue::Mm => "MM",
+            GeoMatchConstraintValue::Na => "NA",
+            GeoMatchConstraintValue::Nr => "NR",
+            GeoMatchConstraintValue::Np => "NP",
+            GeoMatchConstraintValue::Nl => "NL",
+            GeoMatchConstraintValue::Nc => "NC",
+            GeoMatchConstraintValue::Nz => "NZ",
+            GeoMatchConstraintValue::Ni => "NI",
+            GeoMatchConstraintValue::Ne => "NE",
+            GeoMatchConstraintValue::Ng => "NG",
+            GeoMatchConstraintValue::Nu => "NU",
+            GeoMatchConstraintValue::Nf => "NF",
+            GeoMatchConstraintValue::Mp => "MP",
+            GeoMatchConstraintValue::No => "NO",
+            GeoMatchConstraintValue::Om => "OM",
+            GeoMatchConstraintValue::Pk => "PK",
+            GeoMatchConstraintValue::Pw => "PW",
+            GeoMatchConstraintValue::Ps => "PS",
+            GeoMatchConstraintValue::Pa => "PA",
+            GeoMatchConstraintValue::Pg => "PG",
+            GeoMatchConstraintValue::Py => "PY",
+            GeoMatchConstraintValue::Pe => "PE",
+            GeoMatchConstraintValue::Ph => "PH",
+            GeoMatchConstraintValue::Pn => "PN",
+            GeoMatchConstraintValue::Pl => "PL",
+            GeoMatchConstraintValue::Pt => "PT",
+            GeoMatchConstraintValue::Pr => "PR",
+            GeoMatchConstraintValue::Qa => "QA",
+            GeoMatchConstraintValue::Re => "RE",
+            GeoMatchConstraintValue::Ro => "RO",
+            GeoMatchConstraintValue::Ru => "RU",
+            GeoMatchConstraintValue::Rw => "RW",
+            GeoMatchConstraintValue::Bl => "BL",
+            GeoMatchConstraintValue::Sh => "SH",
+            GeoMatchConstraintValue::Kn => "KN",
+            GeoMatchConstraintValue::Lc => "LC",
+            GeoMatchConstraintValue::Mf => "MF",
+            GeoMatchConstraintValue::Pm => "PM",
+            GeoMatchConstraintValue::Vc => "VC",
+            GeoMatchConstraintValue::Ws => "WS",
+            GeoMatchConstraintValue::Sm => "SM",
+            GeoMatchConstraintValue::St => "ST",
+            GeoMatchConstraintValue::Sa => "SA",
+            GeoMatchConstraintValue::Sn => "SN",
+            GeoMatchConstraintValue::Rs => "RS",
+            GeoMatchConstraintValue::Sc => "SC",
+            GeoMatchConstraintValue::Sl => "SL",
+            GeoMatchConstraintValue::Sg => "SG",
+            GeoMatchConstraintValue::Sx => "SX",
+            GeoMatchConstraintValue::Sk => "SK",
+            GeoMatchConstraintValue::Si => "SI",
+            GeoMatchConstraintValue::Sb => "SB",
+            GeoMatchConstraintValue::So => "SO",
+            GeoMatchConstraintValue::Za => "ZA",
+            GeoMatchConstraintValue::Gs => "GS",
+            GeoMatchConstraintValue::Ss => "SS",
+            GeoMatchConstraintValue::Es => "ES",
+            GeoMatchConstraintValue::Lk => "LK",
+            GeoMatchConstraintValue::Sd => "SD",
+            GeoMatchConstraintValue::Sr => "SR",
+            GeoMatchConstraintValue::Sj => "SJ",
+            GeoMatchConstraintValue::Sz => "SZ",
+            GeoMatchConstraintValue::Se => "SE",
+            GeoMatchConstraintValue::Ch => "CH",
+            GeoMatchConstraintValue::Sy => "SY",
+            GeoMatchConstraintValue::Tw => "TW",
+            GeoMatchConstraintValue::Tj => "TJ",
+            GeoMatchConstraintValue::Tz => "TZ",
+            GeoMatchConstraintValue::Th => "TH",
+            GeoMatchConstraintValue::Tl => "TL",
+            GeoMatchConstraintValue::Tg => "TG",
+            GeoMatchConstraintValue::Tk => "TK",
+            GeoMatchConstraintValue::To => "TO",
+            GeoMatchConstraintValue::Tt => "TT",
+            GeoMatchConstraintValue::Tn => "TN",
+            GeoMatchConstraintValue::Tr => "TR",
+            GeoMatchConstraintValue::Tm => "TM",
+            GeoMatchConstraintValue::Tc => "TC",
+            GeoMatchConstraintValue::Tv => "TV",
+            GeoMatchConstraintValue::Ug => "UG",
+            GeoMatchConstraintValue::Ua => "UA",
+            GeoMatchConstraintValue::Ae => "AE",
+            GeoMatchConstraintValue::Gb => "GB",
+            GeoMatchConstraintValue::Us => "US",
+            GeoMatchConstraintValue::Um => "UM",
+            GeoMatchConstraintValue::Uy => "UY",
+            GeoMatchConstraintValue::Uz => "UZ",
+            GeoMatchConstraintValue::Vu => "VU",
+            GeoMatchConstraintValue::Ve => "VE",
+            GeoMatchConstraintValue::Vn => "VN",
+            GeoMatchConstraintValue::Vg => "VG",
+            GeoMatchConstraintValue::Vi => "VI",
+            GeoMatchConstraintValue::Wf => "WF",
+            GeoMatchConstraintValue::Eh => "EH",
+            GeoMatchConstraintValue::Ye => "YE",
+            GeoMatchConstraintValue::Zm => "ZM",
+            GeoMatchConstraintValue::Zw => "ZW",
+            GeoMatchConstraintValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GeoMatchConstraintValue {
+    fn from(value: &'a str) -> GeoMatchConstraintValue {
+        match value {
+            "AF" => GeoMatchConstraintValue::Af,
+            "AX" => GeoMatchConstraintValue::Ax,
+            "AL" => GeoMatchConstraintValue::Al,
+            "DZ" => GeoMatchConstraintValue::Dz,
+            "AS" => GeoMatchConstraintValue::As,
+            "AD" => GeoMatchConstraintValue::Ad,
+            "AO" => GeoMatchConstraintValue::Ao,
+            "AI" => GeoMatchConstraintValue::Ai,
+            "AQ" => GeoMatchConstraintValue::Aq,
+            "AG" => GeoMatchConstraintValue::Ag,
+            "AR" => GeoMatchConstraintValue::Ar,
+            "AM" => GeoMatchConstraintValue::Am,
+            "AW" => GeoMatchConstraintValue::Aw,
+            "AU" => GeoMatchConstraintValue::Au,
+            "AT" => GeoMatchConstraintValue::At,
+            "AZ" => GeoMatchConstraintValue::Az,
+            "BS" => GeoMatchConstraintValue::Bs,
+            "BH" => GeoMatchConstraintValue::Bh,
+            "BD" => GeoMatchConstraintValue::Bd,
+            "BB" => GeoMatchConstraintValue::Bb,
+            "BY" => GeoMatchConstraintValue::By,
+            "BE" => GeoMatchConstraintValue::Be,
+            "BZ" => GeoMatchConstraintValue::Bz,
+            "BJ" => GeoMatchConstraintValue::Bj,
+            "BM" => GeoMatchConstraintValue::Bm,
+            "BT" => GeoMatchConstraintValue::Bt,
+            "BO" => GeoMatchConstraintValue::Bo,
+            "BQ" => GeoMatchConstraintValue::Bq,
+            "BA" => GeoMatchConstraintValue::Ba,
+            "BW" => GeoMatchConstraintValue::Bw,
+            "BV" => GeoMatchConstraintValue::Bv,
+            "BR" => GeoMatchConstraintValue::Br,
+            "IO" => GeoMatchConstraintValue::Io,
+            "BN" => GeoMatchConstraintValue::Bn,
+            "BG" => GeoMatchConstraintValue::Bg,
+            "BF" => GeoMatchConstraintValue::Bf,
+            "BI" => GeoMatchConstraintValue::Bi,
+            "KH" => GeoMatchConstraintValue::Kh,
+            "CM" => GeoMatchConstraintValue::Cm,
+            "CA" => GeoMatchConstraintValue::Ca,
+            "CV" => GeoMatchConstraintValue::Cv,
+            "KY" => GeoMatchConstraintValue::Ky,
+            "CF" => GeoMatchConstraintValue::Cf,
+            "TD" => GeoMatchConstraintValue::Td,
+            "CL" => GeoMatchConstraintValue::Cl,
+            "CN" => GeoMatchConstraintValue::Cn,
+            "CX" => GeoMatchConstraintValue::Cx,
+            "CC" => GeoMatchConstraintValue::Cc,
+            "CO" => GeoMatchConstraintValue::Co,
+            "KM" => GeoMatchConstraintValue::Km,
+            "CG" => GeoMatchConstraintValue::Cg,
+            "CD" => GeoMatchConstraintValue::Cd,
+            "CK" => GeoMatchConstraintValue::Ck,
+            "CR" => GeoMatchConstraintValue::Cr,
+            "CI" => GeoMatchConstraintValue::Ci,
+            "HR" => GeoMatchConstraintValue::Hr,
+            "CU" => GeoMatchConstraintValue::Cu,
+            "CW" => GeoMatchConstraintValue::Cw,
+            "CY" => GeoMatchConstraintValue::Cy,
+            "CZ" => GeoMatchConstraintValue::Cz,
+            "DK" => GeoMatchConstraintValue::Dk,
+            "DJ" => GeoMatchConstraintValue::Dj,
+            "DM" => GeoMatchConstraintValue::Dm,
+            "DO" => GeoMatchConstraintValue::Do,
+            "EC" => GeoMatchConstraintValue::Ec,
+            "EG" => GeoMatchConstraintValue::Eg,
+            "SV" => GeoMatchConstraintValue::Sv,
+            "GQ" => GeoMatchConstraintValue::Gq,
+            "ER" => GeoMatchConstraintValue::Er,
+            "EE" => GeoMatchConstraintValue::Ee,
+            "ET" => GeoMatchConstraintValue::Et,
+            "FK" => GeoMatchConstraintValue::Fk,
+            "FO" => GeoMatchConstraintValue::Fo,
+            "FJ" => GeoMatchConstraintValue::Fj,
+            "FI" => GeoMatchConstraintValue::Fi,
+            "FR" => GeoMatchConstraintValue::Fr,
+            "GF" => GeoMatchConstraintValue::Gf,
+            "PF" => GeoMatchConstraintValue::Pf,
+            "TF" => GeoMatchConstraintValue::Tf,
+            "GA" => GeoMatchConstraintValue::Ga,
+            "GM" => GeoMatchConstraintValue::Gm,
+            "GE" => GeoMatchConstraintValue::Ge,
+            "DE" => GeoMatchConstraintValue::De,
+            "GH" => GeoMatchConstraintValue::Gh,
+            "GI" => GeoMatchConstraintValue::Gi,
+            "GR" => GeoMatchConstraintValue::Gr,
+            "GL" => GeoMatchConstraintValue::Gl,
+            "GD" => GeoMatchConstraintValue::Gd,
+            "GP" => GeoMatchConstraintValue::Gp,
+            "GU" => GeoMatchConstraintValue::Gu,
+            "GT" => GeoMatchConstraintValue::Gt,
+            "GG" => GeoMatchConstraintValue::Gg,
+            "GN" => GeoMatchConstraintValue::Gn,
+            "GW" => GeoMatchConstraintValue::Gw,
+            "GY" => GeoMatchConstraintValue::Gy,
+            "HT" => GeoMatchConstraintValue::Ht,
+            "HM" => GeoMatchConstraintValue::Hm,
+            "VA" => GeoMatchConstraintValue::Va,
+            "HN" => GeoMatchConstraintValue::Hn,
+            "HK" => GeoMatchConstraintValue::Hk,
+            "HU" => GeoMatchConstraintValue::Hu,
+            "IS" => GeoMatchConstraintValue::Is,
+            "IN" => GeoMatchConstraintValue::In,
+            "ID" => GeoMatchConstraintValue::Id,
+            "IR" => GeoMatchConstraintValue::Ir,
+            "IQ" => GeoMatchConstraintValue::Iq,
+            "IE" => GeoMatchConstraintValue::Ie,
+            "IM" => GeoMatchConstraintValue::Im,
+            "IL" => GeoMatchConstraintValue::Il,
+            "IT" => GeoMatchConstraintValue::It,
+            "JM" => GeoMatchConstraintValue::Jm,
+            "JP" => GeoMatchConstraintValue::Jp,
+            "JE" => GeoMatchConstraintValue::Je,
+            "JO" => GeoMatchConstraintValue::Jo,
+            "KZ" => GeoMatchConstraintValue::Kz,
+            "KE" => GeoMatchConstraintValue::Ke,
+            "KI" => GeoMatchConstraintValue::Ki,
+            "KP" => GeoMatchConstraintValue::Kp,
+            "KR" => GeoMatchConstraintValue::Kr,
+            "KW" => GeoMatchConstraintValue::Kw,
+            "KG" => GeoMatchConstraintValue::Kg,
+            "LA" => GeoMatchConstraintValue::La,
+            "LV" => GeoMatchConstraintValue::Lv,
+            "LB" => GeoMatchConstraintValue::Lb,
+            "LS" => GeoMatchConstraintValue::Ls,
+            "LR" => GeoMatchConstraintValue::Lr,
+            "LY" => GeoMatchConstraintValue::Ly,
+            "LI" => GeoMatchConstraintValue::Li,
+            "LT" => GeoMatchConstraintValue::Lt,
+            "LU" => GeoMatchConstraintValue::Lu,
+            "MO" => GeoMatchConstraintValue::Mo,
+            "MK" => GeoMatchConstraintValue::Mk,
+            "MG" => GeoMatchConstraintValue::Mg,
+            "MW" => GeoMatchConstraintValue::Mw,
+            "MY" => GeoMatchConstraintValue::My,
+            "MV" => GeoMatchConstraintValue::Mv,
+            "ML" => GeoMatchConstraintValue::Ml,
+            "MT" => GeoMatchConstraintValue::Mt,
+            "MH" => GeoMatchConstraintValue::Mh,
+            "MQ" => GeoMatchConstraintValue::Mq,
+            "MR" => GeoMatchConstraintValue::Mr,
+            "MU" => GeoMatchConstraintValue::Mu,
+            "YT" => GeoMatchConstraintValue::Yt,
+            "MX" => GeoMatchConstraintValue::Mx,
+            "FM" => GeoMatchConstraintValue::Fm,
+            "MD" => GeoMatchConstraintValue::Md,
+            "MC" => GeoMatchConstraintValue::Mc,
+            "MN" => GeoMatchConstraintValue::Mn,
+            "ME" => GeoMatchConstraintValue::Me,
+            "MS" => GeoMatchConstraintValue::Ms,
+            "MA" => GeoMatchConstraintValue::Ma,
+            "MZ" => GeoMatchConstraintValue::Mz,
+            "MM" => GeoMatchConstraintValue::Mm,
+            "NA" => GeoMatchConstraintValue::Na,
+            "NR" => GeoMatchConstraintValue::Nr,
+            "NP" => GeoMatchConstraintValue::Np,
+            "NL" => GeoMatchConstraintValue::Nl,
+            "NC" => GeoMatchConstraintValue::Nc,
+            "NZ" => GeoMatchConstraintValue::Nz,
+            "NI" => GeoMatchConstraintValue::Ni,
+            "NE" => GeoMatchConstraintValue::Ne,
+            "NG" => GeoMatchConstraintValue::Ng,
+            "NU" => GeoMatchConstraintValue::Nu,
+            "NF" => GeoMatchConstraintValue::Nf,
+            "MP" => GeoMatchConstraintValue::Mp,
+            "NO" => GeoMatchConstraintValue::No,
+            "OM" => GeoMatchConstraintValue::Om,
+            "PK" => GeoMatchConstraintValue::Pk,
+            "PW" => GeoMatchConstraintValue::Pw,
+            "PS" => GeoMatchConstraintValue::Ps,
+            "PA" => GeoMatchConstraintValue::Pa,
+            "PG" => GeoMatchConstraintValue::Pg,
+            "PY" => GeoMatchConstraintValue::Py,
+            "PE" => GeoMatchConstraintValue::Pe,
+            "PH" => GeoMatchConstraintValue::Ph,
+            "PN" => GeoMatchConstraintValue::Pn,
+            "PL" => GeoMatchConstraintValue::Pl,
+            "PT" => GeoMatchConstraintValue::Pt,
+            "PR" => GeoMatchConstraintValue::Pr,
+            "QA" => GeoMatchConstraintValue::Qa,
+            "RE" => GeoMatchConstraintValue::Re,
+            "RO" => GeoMatchConstraintValue::Ro,
+            "RU" => GeoMatchConstraintValue::Ru,
+            "RW" => GeoMatchConstraintValue::Rw,
+            "BL" => GeoMatchConstraintValue::Bl,
+            "SH" => GeoMatchConstraintValue::Sh,
+            "KN" => GeoMatchConstraintValue::Kn,
+            "LC" => GeoMatchConstraintValue::Lc,
+            "MF" => GeoMatchConstraintValue::Mf,
+            "PM" => GeoMatchConstraintValue::Pm,
+            "VC" => GeoMatchConstraintValue::Vc,
+            "WS" => GeoMatchConstraintValue::Ws,
+            "SM" => GeoMatchConstraintValue::Sm,
+            "ST" => GeoMatchConstraintValue::St,
+            "SA" => GeoMatchConstraintValue::Sa,
+            "SN" => GeoMatchConstraintValue::Sn,
+            "RS" => GeoMatchConstraintValue::Rs,
+            "SC" => GeoMatchConstraintValue::Sc,
+            "SL" => GeoMatchConstraintValue::Sl,
+            "SG" => GeoMatchConstraintValue::Sg,
+            "SX" => GeoMatchConstraintValue::Sx,
+            "SK" => GeoMatchConstraintValue::Sk,
+            "SI" => GeoMatchConstraintValue::Si,
+            "SB" => GeoMatchConstraintValue::Sb,
+            "SO" => GeoMatchConstraintValue::So,
+            "ZA" => GeoMatchConstraintValue::Za,
+            "GS" => GeoMatchConstraintValue::Gs,
+            "SS" => GeoMatchConstraintValue::Ss,
+            "ES" => GeoMatchConstraintValue::Es,
+            "LK" => GeoMatchConstraintValue::Lk,
+            "SD" => GeoMatchConstraintValue::Sd,
+            "SR" => GeoMatchConstraintValue::Sr,
+            "SJ" => GeoMatchConstraintValue::Sj,
+            "SZ" => GeoMatchConstraintValue::Sz,
+            "SE" => GeoMatchConstraintValue::Se,
+            "CH" => GeoMatchConstraintValue::Ch,
+            "SY" => GeoMatchConstraintValue::Sy,
+            "TW" => GeoMatchConstraintValue::Tw,
+            "TJ" => GeoMatchConstraintValue::Tj,
+            "TZ" => GeoMatchConstraintValue::Tz,
+            "TH" => GeoMatchConstraintValue::Th,
+            "TL" => GeoMatchConstraintValue::Tl,
+            "TG" => GeoMatchConstraintValue::Tg,
+            "TK" => GeoMatchConstraintValue::Tk,
+            "TO" => GeoMatchConstraintValue::To,
+            "TT" => GeoMatchConstraintValue::Tt,
+            "TN" => GeoMatchConstraintValue::Tn,
+            "TR" => GeoMatchConstraintValue::Tr,
+            "TM" => GeoMatchConstraintValue::Tm,
+            "TC" => GeoMatchConstraintValue::Tc,
+            "TV" => GeoMatchConstraintValue::Tv,
+            "UG" => GeoMatchConstraintValue::Ug,
+            "UA" => GeoMatchConstraintValue::Ua,
+            "AE" => GeoMatchConstraintValue::Ae,
+            "GB" => GeoMatchConstraintValue::Gb,
+            "US" => GeoMatchConstraintValue::Us,
+            "UM" => GeoMatchConstraintValue::Um,
+            "UY" => GeoMatchConstraintValue::Uy,
+            "UZ" => GeoMatchConstraintValue::Uz,
+            "VU" => GeoMatchConstraintValue::Vu,
+            "VE" => GeoMatchConstraintValue::Ve,
+            "VN" => GeoMatchConstraintValue::Vn,
+            "VG" => GeoMatchConstraintValue::Vg,
+            "VI" => GeoMatchConstraintValue::Vi,
+            "WF" => GeoMatchConstraintValue::Wf,
+            "EH" => GeoMatchConstraintValue::Eh,
+            "YE" => GeoMatchConstraintValue::Ye,
+            "ZM" => GeoMatchConstraintValue::Zm,
+            "ZW" => GeoMatchConstraintValue::Zw,
+            _ => GeoMatchConstraintValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GeoMatchConstraintValue {
+    fn from(value: String) -> GeoMatchConstraintValue {
+        GeoMatchConstraintValue::from(value.as_str())
+    }
+}
+
+impl From<GeoMatchConstraintValue> for String {
+    fn from(value: GeoMatchConstraintValue) -> String {
+        match value {
+            GeoMatchConstraintValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GeoMatchConstraintValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for GeoMatchConstraintValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GeoMatchConstraintValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<GeoMatchConstraintValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GeoMatchConstraintValue::from(value))
+    }
+}
+
+/// <p>Contains one or more countries that AWS WAF will search for.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GeoMatchSet {
+    /// <p>An array of <a>GeoMatchConstraint</a> objects, which contain the country that you want AWS WAF to search for.</p>
+    #[serde(rename = "GeoMatchConstraints")]
+    pub geo_match_constraints: Vec<GeoMatchConstraint>,
+    /// <p>The <code>GeoMatchSetId</code> for an <code>GeoMatchSet</code>. You use <code>GeoMatchSetId</code> to get information about a <code>GeoMatchSet</code> (see <a>GeoMatchSet</a>), update a <code>GeoMatchSet</code> (see <a>UpdateGeoMatchSet</a>), insert a <code>GeoMatchSet</code> into a <code>Rule</code> or delete one from a <code>Rule</code> (see <a>UpdateRule</a>), and delete a <code>GeoMatchSet</code> from AWS WAF (see <a>DeleteGeoMatchSet</a>).</p> <p> <code>GeoMatchSetId</code> is returned by <a>CreateGeoMatchSet</a> and by <a>ListGeoMatchSets</a>.</p>
+    #[serde(rename = "GeoMatchSetId")]
+    pub geo_match_set_id: String,
+    /// <p>A friendly name or description of the <a>GeoMatchSet</a>. You can't change the name of an <code>GeoMatchSet</code> after you create it.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// <p>Contains the identifier and the name of the <code>GeoMatchSet</code>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GeoMatchSetSummary {
+    /// <p>The <code>GeoMatchSetId</code> for an <a>GeoMatchSet</a>. You can use <code>GeoMatchSetId</code> in a <a>GetGeoMatchSet</a> request to get detailed information about an <a>GeoMatchSet</a>.</p>
+    #[serde(rename = "GeoMatchSetId")]
+    pub geo_match_set_id: String,
+    /// <p>A friendly name or description of the <a>GeoMatchSet</a>. You can't change the name of an <code>GeoMatchSet</code> after you create it.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
+}
+
+/// <p>Specifies the type of update to perform to an <a>GeoMatchSet</a> with <a>UpdateGeoMatchSet</a>.</p>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GeoMatchSetUpdate {
     /// <p>Specifies whether to insert or delete a country with <a>UpdateGeoMatchSet</a>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>The country from which web requests originate that you want AWS WAF to search for.</p>
     #[serde(rename = "GeoMatchConstraint")]
     pub geo_match_constraint: GeoMatchConstraint,
@@ -780,6 +3375,36 @@ pub struct GetByteMatchSetRequest {
     pub byte_match_set_id: String,
 }
 
+impl GetByteMatchSetRequest {
+    /// Returns a builder for `GetByteMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> GetByteMatchSetRequestBuilder {
+        GetByteMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetByteMatchSetRequest`](struct.GetByteMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetByteMatchSetRequestBuilder {
+    byte_match_set_id: Option<String>,
+}
+
+impl GetByteMatchSetRequestBuilder {
+    /// Sets the [`byte_match_set_id`](struct.GetByteMatchSetRequest.html#structfield.byte_match_set_id) member.
+    pub fn byte_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.byte_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetByteMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetByteMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(GetByteMatchSetRequest {
+            byte_match_set_id: self
+                .byte_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("byte_match_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetByteMatchSetResponse {
@@ -808,13 +3433,45 @@ pub struct GetChangeTokenStatusRequest {
     pub change_token: String,
 }
 
+impl GetChangeTokenStatusRequest {
+    /// Returns a builder for `GetChangeTokenStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetChangeTokenStatusRequestBuilder {
+        GetChangeTokenStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetChangeTokenStatusRequest`](struct.GetChangeTokenStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetChangeTokenStatusRequestBuilder {
+    change_token: Option<String>,
+}
+
+impl GetChangeTokenStatusRequestBuilder {
+    /// Sets the [`change_token`](struct.GetChangeTokenStatusRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetChangeTokenStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetChangeTokenStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetChangeTokenStatusRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetChangeTokenStatusResponse {
     /// <p>The status of the change token.</p>
     #[serde(rename = "ChangeTokenStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub change_token_status: Option<String>,
+    pub change_token_status: Option<ChangeTokenStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -824,6 +3481,36 @@ pub struct GetGeoMatchSetRequest {
     pub geo_match_set_id: String,
 }
 
+impl GetGeoMatchSetRequest {
+    /// Returns a builder for `GetGeoMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> GetGeoMatchSetRequestBuilder {
+        GetGeoMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGeoMatchSetRequest`](struct.GetGeoMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGeoMatchSetRequestBuilder {
+    geo_match_set_id: Option<String>,
+}
+
+impl GetGeoMatchSetRequestBuilder {
+    /// Sets the [`geo_match_set_id`](struct.GetGeoMatchSetRequest.html#structfield.geo_match_set_id) member.
+    pub fn geo_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.geo_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGeoMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGeoMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(GetGeoMatchSetRequest {
+            geo_match_set_id: self
+                .geo_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("geo_match_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGeoMatchSetResponse {
@@ -840,6 +3527,36 @@ pub struct GetIPSetRequest {
     pub ip_set_id: String,
 }
 
+impl GetIPSetRequest {
+    /// Returns a builder for `GetIPSetRequest`, checking its required members when built.
+    pub fn builder() -> GetIPSetRequestBuilder {
+        GetIPSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIPSetRequest`](struct.GetIPSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIPSetRequestBuilder {
+    ip_set_id: Option<String>,
+}
+
+impl GetIPSetRequestBuilder {
+    /// Sets the [`ip_set_id`](struct.GetIPSetRequest.html#structfield.ip_set_id) member.
+    pub fn ip_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ip_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIPSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetIPSetRequest, ::rusoto_core::BuildError> {
+        Ok(GetIPSetRequest {
+            ip_set_id: self
+                .ip_set_id
+                .ok_or(::rusoto_core::BuildError::missing("ip_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetIPSetResponse {
@@ -856,6 +3573,38 @@ pub struct GetLoggingConfigurationRequest {
     pub resource_arn: String,
 }
 
+impl GetLoggingConfigurationRequest {
+    /// Returns a builder for `GetLoggingConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetLoggingConfigurationRequestBuilder {
+        GetLoggingConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLoggingConfigurationRequest`](struct.GetLoggingConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLoggingConfigurationRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl GetLoggingConfigurationRequestBuilder {
+    /// Sets the [`resource_arn`](struct.GetLoggingConfigurationRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLoggingConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLoggingConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(GetLoggingConfigurationRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLoggingConfigurationResponse {
@@ -872,6 +3621,38 @@ pub struct GetPermissionPolicyRequest {
     pub resource_arn: String,
 }
 
+impl GetPermissionPolicyRequest {
+    /// Returns a builder for `GetPermissionPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetPermissionPolicyRequestBuilder {
+        GetPermissionPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPermissionPolicyRequest`](struct.GetPermissionPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPermissionPolicyRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl GetPermissionPolicyRequestBuilder {
+    /// Sets the [`resource_arn`](struct.GetPermissionPolicyRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPermissionPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetPermissionPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetPermissionPolicyRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPermissionPolicyResponse {
@@ -892,6 +3673,46 @@ pub struct GetRateBasedRuleManagedKeysRequest {
     pub rule_id: String,
 }
 
+impl GetRateBasedRuleManagedKeysRequest {
+    /// Returns a builder for `GetRateBasedRuleManagedKeysRequest`, checking its required members when built.
+    pub fn builder() -> GetRateBasedRuleManagedKeysRequestBuilder {
+        GetRateBasedRuleManagedKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRateBasedRuleManagedKeysRequest`](struct.GetRateBasedRuleManagedKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRateBasedRuleManagedKeysRequestBuilder {
+    next_marker: Option<String>,
+    rule_id: Option<String>,
+}
+
+impl GetRateBasedRuleManagedKeysRequestBuilder {
+    /// Sets the [`next_marker`](struct.GetRateBasedRuleManagedKeysRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_id`](struct.GetRateBasedRuleManagedKeysRequest.html#structfield.rule_id) member.
+    pub fn rule_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRateBasedRuleManagedKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRateBasedRuleManagedKeysRequest, ::rusoto_core::BuildError> {
+        Ok(GetRateBasedRuleManagedKeysRequest {
+            next_marker: self.next_marker,
+            rule_id: self
+                .rule_id
+                .ok_or(::rusoto_core::BuildError::missing("rule_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRateBasedRuleManagedKeysResponse {
@@ -912,6 +3733,38 @@ pub struct GetRateBasedRuleRequest {
     pub rule_id: String,
 }
 
+impl GetRateBasedRuleRequest {
+    /// Returns a builder for `GetRateBasedRuleRequest`, checking its required members when built.
+    pub fn builder() -> GetRateBasedRuleRequestBuilder {
+        GetRateBasedRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRateBasedRuleRequest`](struct.GetRateBasedRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRateBasedRuleRequestBuilder {
+    rule_id: Option<String>,
+}
+
+impl GetRateBasedRuleRequestBuilder {
+    /// Sets the [`rule_id`](struct.GetRateBasedRuleRequest.html#structfield.rule_id) member.
+    pub fn rule_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRateBasedRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRateBasedRuleRequest, ::rusoto_core::BuildError> {
+        Ok(GetRateBasedRuleRequest {
+            rule_id: self
+                .rule_id
+                .ok_or(::rusoto_core::BuildError::missing("rule_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRateBasedRuleResponse {
@@ -928,6 +3781,38 @@ pub struct GetRegexMatchSetRequest {
     pub regex_match_set_id: String,
 }
 
+impl GetRegexMatchSetRequest {
+    /// Returns a builder for `GetRegexMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> GetRegexMatchSetRequestBuilder {
+        GetRegexMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRegexMatchSetRequest`](struct.GetRegexMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRegexMatchSetRequestBuilder {
+    regex_match_set_id: Option<String>,
+}
+
+impl GetRegexMatchSetRequestBuilder {
+    /// Sets the [`regex_match_set_id`](struct.GetRegexMatchSetRequest.html#structfield.regex_match_set_id) member.
+    pub fn regex_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.regex_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRegexMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRegexMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(GetRegexMatchSetRequest {
+            regex_match_set_id: self
+                .regex_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("regex_match_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRegexMatchSetResponse {
@@ -944,6 +3829,38 @@ pub struct GetRegexPatternSetRequest {
     pub regex_pattern_set_id: String,
 }
 
+impl GetRegexPatternSetRequest {
+    /// Returns a builder for `GetRegexPatternSetRequest`, checking its required members when built.
+    pub fn builder() -> GetRegexPatternSetRequestBuilder {
+        GetRegexPatternSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRegexPatternSetRequest`](struct.GetRegexPatternSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRegexPatternSetRequestBuilder {
+    regex_pattern_set_id: Option<String>,
+}
+
+impl GetRegexPatternSetRequestBuilder {
+    /// Sets the [`regex_pattern_set_id`](struct.GetRegexPatternSetRequest.html#structfield.regex_pattern_set_id) member.
+    pub fn regex_pattern_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.regex_pattern_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRegexPatternSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRegexPatternSetRequest, ::rusoto_core::BuildError> {
+        Ok(GetRegexPatternSetRequest {
+            regex_pattern_set_id: self
+                .regex_pattern_set_id
+                .ok_or(::rusoto_core::BuildError::missing("regex_pattern_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRegexPatternSetResponse {
@@ -960,6 +3877,36 @@ pub struct GetRuleGroupRequest {
     pub rule_group_id: String,
 }
 
+impl GetRuleGroupRequest {
+    /// Returns a builder for `GetRuleGroupRequest`, checking its required members when built.
+    pub fn builder() -> GetRuleGroupRequestBuilder {
+        GetRuleGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRuleGroupRequest`](struct.GetRuleGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRuleGroupRequestBuilder {
+    rule_group_id: Option<String>,
+}
+
+impl GetRuleGroupRequestBuilder {
+    /// Sets the [`rule_group_id`](struct.GetRuleGroupRequest.html#structfield.rule_group_id) member.
+    pub fn rule_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRuleGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRuleGroupRequest, ::rusoto_core::BuildError> {
+        Ok(GetRuleGroupRequest {
+            rule_group_id: self
+                .rule_group_id
+                .ok_or(::rusoto_core::BuildError::missing("rule_group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRuleGroupResponse {
@@ -976,6 +3923,36 @@ pub struct GetRuleRequest {
     pub rule_id: String,
 }
 
+impl GetRuleRequest {
+    /// Returns a builder for `GetRuleRequest`, checking its required members when built.
+    pub fn builder() -> GetRuleRequestBuilder {
+        GetRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRuleRequest`](struct.GetRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRuleRequestBuilder {
+    rule_id: Option<String>,
+}
+
+impl GetRuleRequestBuilder {
+    /// Sets the [`rule_id`](struct.GetRuleRequest.html#structfield.rule_id) member.
+    pub fn rule_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRuleRequest, ::rusoto_core::BuildError> {
+        Ok(GetRuleRequest {
+            rule_id: self
+                .rule_id
+                .ok_or(::rusoto_core::BuildError::missing("rule_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRuleResponse {
@@ -1001,6 +3978,68 @@ pub struct GetSampledRequestsRequest {
     pub web_acl_id: String,
 }
 
+impl GetSampledRequestsRequest {
+    /// Returns a builder for `GetSampledRequestsRequest`, checking its required members when built.
+    pub fn builder() -> GetSampledRequestsRequestBuilder {
+        GetSampledRequestsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSampledRequestsRequest`](struct.GetSampledRequestsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSampledRequestsRequestBuilder {
+    max_items: Option<i64>,
+    rule_id: Option<String>,
+    time_window: Option<TimeWindow>,
+    web_acl_id: Option<String>,
+}
+
+impl GetSampledRequestsRequestBuilder {
+    /// Sets the [`max_items`](struct.GetSampledRequestsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_id`](struct.GetSampledRequestsRequest.html#structfield.rule_id) member.
+    pub fn rule_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_window`](struct.GetSampledRequestsRequest.html#structfield.time_window) member.
+    pub fn time_window<T: Into<TimeWindow>>(mut self, value: T) -> Self {
+        self.time_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`web_acl_id`](struct.GetSampledRequestsRequest.html#structfield.web_acl_id) member.
+    pub fn web_acl_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.web_acl_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSampledRequestsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSampledRequestsRequest, ::rusoto_core::BuildError> {
+        Ok(GetSampledRequestsRequest {
+            max_items: self
+                .max_items
+                .ok_or(::rusoto_core::BuildError::missing("max_items"))?,
+            rule_id: self
+                .rule_id
+                .ok_or(::rusoto_core::BuildError::missing("rule_id"))?,
+            time_window: self
+                .time_window
+                .ok_or(::rusoto_core::BuildError::missing("time_window"))?,
+            web_acl_id: self
+                .web_acl_id
+                .ok_or(::rusoto_core::BuildError::missing("web_acl_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSampledRequestsResponse {
@@ -1025,6 +4064,38 @@ pub struct GetSizeConstraintSetRequest {
     pub size_constraint_set_id: String,
 }
 
+impl GetSizeConstraintSetRequest {
+    /// Returns a builder for `GetSizeConstraintSetRequest`, checking its required members when built.
+    pub fn builder() -> GetSizeConstraintSetRequestBuilder {
+        GetSizeConstraintSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSizeConstraintSetRequest`](struct.GetSizeConstraintSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSizeConstraintSetRequestBuilder {
+    size_constraint_set_id: Option<String>,
+}
+
+impl GetSizeConstraintSetRequestBuilder {
+    /// Sets the [`size_constraint_set_id`](struct.GetSizeConstraintSetRequest.html#structfield.size_constraint_set_id) member.
+    pub fn size_constraint_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.size_constraint_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSizeConstraintSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSizeConstraintSetRequest, ::rusoto_core::BuildError> {
+        Ok(GetSizeConstraintSetRequest {
+            size_constraint_set_id: self
+                .size_constraint_set_id
+                .ok_or(::rusoto_core::BuildError::missing("size_constraint_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSizeConstraintSetResponse {
@@ -1042,6 +4113,38 @@ pub struct GetSqlInjectionMatchSetRequest {
     pub sql_injection_match_set_id: String,
 }
 
+impl GetSqlInjectionMatchSetRequest {
+    /// Returns a builder for `GetSqlInjectionMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> GetSqlInjectionMatchSetRequestBuilder {
+        GetSqlInjectionMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSqlInjectionMatchSetRequest`](struct.GetSqlInjectionMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSqlInjectionMatchSetRequestBuilder {
+    sql_injection_match_set_id: Option<String>,
+}
+
+impl GetSqlInjectionMatchSetRequestBuilder {
+    /// Sets the [`sql_injection_match_set_id`](struct.GetSqlInjectionMatchSetRequest.html#structfield.sql_injection_match_set_id) member.
+    pub fn sql_injection_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.sql_injection_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSqlInjectionMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSqlInjectionMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(GetSqlInjectionMatchSetRequest {
+            sql_injection_match_set_id: self.sql_injection_match_set_id.ok_or(
+                ::rusoto_core::BuildError::missing("sql_injection_match_set_id"),
+            )?,
+        })
+    }
+}
+
 /// <p>The response to a <a>GetSqlInjectionMatchSet</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1059,6 +4162,38 @@ pub struct GetWebACLForResourceRequest {
     pub resource_arn: String,
 }
 
+impl GetWebACLForResourceRequest {
+    /// Returns a builder for `GetWebACLForResourceRequest`, checking its required members when built.
+    pub fn builder() -> GetWebACLForResourceRequestBuilder {
+        GetWebACLForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetWebACLForResourceRequest`](struct.GetWebACLForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetWebACLForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl GetWebACLForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.GetWebACLForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetWebACLForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetWebACLForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(GetWebACLForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetWebACLForResourceResponse {
@@ -1075,11 +4210,41 @@ pub struct GetWebACLRequest {
     pub web_acl_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetWebACLResponse {
-    /// <p><p>Information about the <a>WebACL</a> that you specified in the <code>GetWebACL</code> request. For more information, see the following topics:</p> <ul> <li> <p> <a>WebACL</a>: Contains <code>DefaultAction</code>, <code>MetricName</code>, <code>Name</code>, an array of <code>Rule</code> objects, and <code>WebACLId</code> </p> </li> <li> <p> <code>DefaultAction</code> (Data type is <a>WafAction</a>): Contains <code>Type</code> </p> </li> <li> <p> <code>Rules</code>: Contains an array of <code>ActivatedRule</code> objects, which contain <code>Action</code>, <code>Priority</code>, and <code>RuleId</code> </p> </li> <li> <p> <code>Action</code>: Contains <code>Type</code> </p> </li> </ul></p>
-    #[serde(rename = "WebACL")]
+impl GetWebACLRequest {
+    /// Returns a builder for `GetWebACLRequest`, checking its required members when built.
+    pub fn builder() -> GetWebACLRequestBuilder {
+        GetWebACLRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetWebACLRequest`](struct.GetWebACLRequest.html).
+#[derive(Default, Debug)]
+pub struct GetWebACLRequestBuilder {
+    web_acl_id: Option<String>,
+}
+
+impl GetWebACLRequestBuilder {
+    /// Sets the [`web_acl_id`](struct.GetWebACLRequest.html#structfield.web_acl_id) member.
+    pub fn web_acl_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.web_acl_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetWebACLRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetWebACLRequest, ::rusoto_core::BuildError> {
+        Ok(GetWebACLRequest {
+            web_acl_id: self
+                .web_acl_id
+                .ok_or(::rusoto_core::BuildError::missing("web_acl_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetWebACLResponse {
+    /// <p><p>Information about the <a>WebACL</a> that you specified in the <code>GetWebACL</code> request. For more information, see the following topics:</p> <ul> <li> <p> <a>WebACL</a>: Contains <code>DefaultAction</code>, <code>MetricName</code>, <code>Name</code>, an array of <code>Rule</code> objects, and <code>WebACLId</code> </p> </li> <li> <p> <code>DefaultAction</code> (Data type is <a>WafAction</a>): Contains <code>Type</code> </p> </li> <li> <p> <code>Rules</code>: Contains an array of <code>ActivatedRule</code> objects, which contain <code>Action</code>, <code>Priority</code>, and <code>RuleId</code> </p> </li> <li> <p> <code>Action</code>: Contains <code>Type</code> </p> </li> </ul></p>
+    #[serde(rename = "WebACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub web_acl: Option<WebACL>,
 }
@@ -1092,6 +4257,36 @@ pub struct GetXssMatchSetRequest {
     pub xss_match_set_id: String,
 }
 
+impl GetXssMatchSetRequest {
+    /// Returns a builder for `GetXssMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> GetXssMatchSetRequestBuilder {
+        GetXssMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetXssMatchSetRequest`](struct.GetXssMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetXssMatchSetRequestBuilder {
+    xss_match_set_id: Option<String>,
+}
+
+impl GetXssMatchSetRequestBuilder {
+    /// Sets the [`xss_match_set_id`](struct.GetXssMatchSetRequest.html#structfield.xss_match_set_id) member.
+    pub fn xss_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.xss_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetXssMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetXssMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(GetXssMatchSetRequest {
+            xss_match_set_id: self
+                .xss_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("xss_match_set_id"))?,
+        })
+    }
+}
+
 /// <p>The response to a <a>GetXssMatchSet</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1163,16 +4358,88 @@ pub struct IPSet {
 }
 
 /// <p>Specifies the IP address type (<code>IPV4</code> or <code>IPV6</code>) and the IP address range (in CIDR format) that web requests originate from.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IPSetDescriptor {
     /// <p>Specify <code>IPV4</code> or <code>IPV6</code>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: IPSetDescriptorType,
     /// <p><p>Specify an IPv4 address by using CIDR notation. For example:</p> <ul> <li> <p>To configure AWS WAF to allow, block, or count requests that originated from the IP address 192.0.2.44, specify <code>192.0.2.44/32</code>.</p> </li> <li> <p>To configure AWS WAF to allow, block, or count requests that originated from IP addresses from 192.0.2.0 to 192.0.2.255, specify <code>192.0.2.0/24</code>.</p> </li> </ul> <p>For more information about CIDR notation, see the Wikipedia entry <a href="https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>.</p> <p>Specify an IPv6 address by using CIDR notation. For example:</p> <ul> <li> <p>To configure AWS WAF to allow, block, or count requests that originated from the IP address 1111:0000:0000:0000:0000:0000:0000:0111, specify <code>1111:0000:0000:0000:0000:0000:0000:0111/128</code>.</p> </li> <li> <p>To configure AWS WAF to allow, block, or count requests that originated from IP addresses 1111:0000:0000:0000:0000:0000:0000:0000 to 1111:0000:0000:0000:ffff:ffff:ffff:ffff, specify <code>1111:0000:0000:0000:0000:0000:0000:0000/64</code>.</p> </li> </ul></p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+/// <p>Specify <code>IPV4</code> or <code>IPV6</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IPSetDescriptorType {
+    /// <p><code>IPV4</code></p>
+    Ipv4,
+    /// <p><code>IPV6</code></p>
+    Ipv6,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IPSetDescriptorType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IPSetDescriptorType::Ipv4 => "IPV4",
+            IPSetDescriptorType::Ipv6 => "IPV6",
+            IPSetDescriptorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IPSetDescriptorType {
+    fn from(value: &'a str) -> IPSetDescriptorType {
+        match value {
+            "IPV4" => IPSetDescriptorType::Ipv4,
+            "IPV6" => IPSetDescriptorType::Ipv6,
+            _ => IPSetDescriptorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IPSetDescriptorType {
+    fn from(value: String) -> IPSetDescriptorType {
+        IPSetDescriptorType::from(value.as_str())
+    }
+}
+
+impl From<IPSetDescriptorType> for String {
+    fn from(value: IPSetDescriptorType) -> String {
+        match value {
+            IPSetDescriptorType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IPSetDescriptorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for IPSetDescriptorType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IPSetDescriptorType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IPSetDescriptorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IPSetDescriptorType::from(value))
+    }
+}
+
 /// <p>Contains the identifier and the name of the <code>IPSet</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1186,11 +4453,11 @@ pub struct IPSetSummary {
 }
 
 /// <p>Specifies the type of update to perform to an <a>IPSet</a> with <a>UpdateIPSet</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct IPSetUpdate {
     /// <p>Specifies whether to insert or delete an IP address with <a>UpdateIPSet</a>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>The IP address type (<code>IPV4</code> or <code>IPV6</code>) and the IP address range (in CIDR notation) that web requests originate from.</p>
     #[serde(rename = "IPSetDescriptor")]
     pub ip_set_descriptor: IPSetDescriptor,
@@ -1212,6 +4479,53 @@ pub struct ListActivatedRulesInRuleGroupRequest {
     pub rule_group_id: Option<String>,
 }
 
+impl ListActivatedRulesInRuleGroupRequest {
+    /// Returns a builder for `ListActivatedRulesInRuleGroupRequest`, checking its required members when built.
+    pub fn builder() -> ListActivatedRulesInRuleGroupRequestBuilder {
+        ListActivatedRulesInRuleGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListActivatedRulesInRuleGroupRequest`](struct.ListActivatedRulesInRuleGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct ListActivatedRulesInRuleGroupRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+    rule_group_id: Option<String>,
+}
+
+impl ListActivatedRulesInRuleGroupRequestBuilder {
+    /// Sets the [`limit`](struct.ListActivatedRulesInRuleGroupRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListActivatedRulesInRuleGroupRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_group_id`](struct.ListActivatedRulesInRuleGroupRequest.html#structfield.rule_group_id) member.
+    pub fn rule_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListActivatedRulesInRuleGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListActivatedRulesInRuleGroupRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListActivatedRulesInRuleGroupRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+            rule_group_id: self.rule_group_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListActivatedRulesInRuleGroupResponse {
@@ -1237,6 +4551,44 @@ pub struct ListByteMatchSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListByteMatchSetsRequest {
+    /// Returns a builder for `ListByteMatchSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListByteMatchSetsRequestBuilder {
+        ListByteMatchSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListByteMatchSetsRequest`](struct.ListByteMatchSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListByteMatchSetsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListByteMatchSetsRequestBuilder {
+    /// Sets the [`limit`](struct.ListByteMatchSetsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListByteMatchSetsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListByteMatchSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListByteMatchSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListByteMatchSetsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListByteMatchSetsResponse {
@@ -1262,6 +4614,44 @@ pub struct ListGeoMatchSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListGeoMatchSetsRequest {
+    /// Returns a builder for `ListGeoMatchSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListGeoMatchSetsRequestBuilder {
+        ListGeoMatchSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGeoMatchSetsRequest`](struct.ListGeoMatchSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGeoMatchSetsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListGeoMatchSetsRequestBuilder {
+    /// Sets the [`limit`](struct.ListGeoMatchSetsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListGeoMatchSetsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGeoMatchSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListGeoMatchSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListGeoMatchSetsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGeoMatchSetsResponse {
@@ -1287,6 +4677,42 @@ pub struct ListIPSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListIPSetsRequest {
+    /// Returns a builder for `ListIPSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListIPSetsRequestBuilder {
+        ListIPSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListIPSetsRequest`](struct.ListIPSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListIPSetsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListIPSetsRequestBuilder {
+    /// Sets the [`limit`](struct.ListIPSetsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListIPSetsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIPSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListIPSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListIPSetsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListIPSetsResponse {
@@ -1312,6 +4738,44 @@ pub struct ListLoggingConfigurationsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListLoggingConfigurationsRequest {
+    /// Returns a builder for `ListLoggingConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> ListLoggingConfigurationsRequestBuilder {
+        ListLoggingConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListLoggingConfigurationsRequest`](struct.ListLoggingConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListLoggingConfigurationsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListLoggingConfigurationsRequestBuilder {
+    /// Sets the [`limit`](struct.ListLoggingConfigurationsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListLoggingConfigurationsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLoggingConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListLoggingConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListLoggingConfigurationsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLoggingConfigurationsResponse {
@@ -1337,6 +4801,44 @@ pub struct ListRateBasedRulesRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListRateBasedRulesRequest {
+    /// Returns a builder for `ListRateBasedRulesRequest`, checking its required members when built.
+    pub fn builder() -> ListRateBasedRulesRequestBuilder {
+        ListRateBasedRulesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRateBasedRulesRequest`](struct.ListRateBasedRulesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRateBasedRulesRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListRateBasedRulesRequestBuilder {
+    /// Sets the [`limit`](struct.ListRateBasedRulesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListRateBasedRulesRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRateBasedRulesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListRateBasedRulesRequest, ::rusoto_core::BuildError> {
+        Ok(ListRateBasedRulesRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRateBasedRulesResponse {
@@ -1362,6 +4864,44 @@ pub struct ListRegexMatchSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListRegexMatchSetsRequest {
+    /// Returns a builder for `ListRegexMatchSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListRegexMatchSetsRequestBuilder {
+        ListRegexMatchSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRegexMatchSetsRequest`](struct.ListRegexMatchSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRegexMatchSetsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListRegexMatchSetsRequestBuilder {
+    /// Sets the [`limit`](struct.ListRegexMatchSetsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListRegexMatchSetsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRegexMatchSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListRegexMatchSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListRegexMatchSetsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRegexMatchSetsResponse {
@@ -1387,6 +4927,44 @@ pub struct ListRegexPatternSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListRegexPatternSetsRequest {
+    /// Returns a builder for `ListRegexPatternSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListRegexPatternSetsRequestBuilder {
+        ListRegexPatternSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRegexPatternSetsRequest`](struct.ListRegexPatternSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRegexPatternSetsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListRegexPatternSetsRequestBuilder {
+    /// Sets the [`limit`](struct.ListRegexPatternSetsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListRegexPatternSetsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRegexPatternSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListRegexPatternSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListRegexPatternSetsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRegexPatternSetsResponse {
@@ -1405,12 +4983,52 @@ pub struct ListResourcesForWebACLRequest {
     /// <p>The type of resource to list, either an application load balancer or Amazon API Gateway.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The unique identifier (ID) of the web ACL for which to list the associated resources.</p>
     #[serde(rename = "WebACLId")]
     pub web_acl_id: String,
 }
 
+impl ListResourcesForWebACLRequest {
+    /// Returns a builder for `ListResourcesForWebACLRequest`, checking its required members when built.
+    pub fn builder() -> ListResourcesForWebACLRequestBuilder {
+        ListResourcesForWebACLRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourcesForWebACLRequest`](struct.ListResourcesForWebACLRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResourcesForWebACLRequestBuilder {
+    resource_type: Option<ResourceType>,
+    web_acl_id: Option<String>,
+}
+
+impl ListResourcesForWebACLRequestBuilder {
+    /// Sets the [`resource_type`](struct.ListResourcesForWebACLRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<ResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`web_acl_id`](struct.ListResourcesForWebACLRequest.html#structfield.web_acl_id) member.
+    pub fn web_acl_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.web_acl_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourcesForWebACLRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListResourcesForWebACLRequest, ::rusoto_core::BuildError> {
+        Ok(ListResourcesForWebACLRequest {
+            resource_type: self.resource_type,
+            web_acl_id: self
+                .web_acl_id
+                .ok_or(::rusoto_core::BuildError::missing("web_acl_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResourcesForWebACLResponse {
@@ -1432,6 +5050,42 @@ pub struct ListRuleGroupsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListRuleGroupsRequest {
+    /// Returns a builder for `ListRuleGroupsRequest`, checking its required members when built.
+    pub fn builder() -> ListRuleGroupsRequestBuilder {
+        ListRuleGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRuleGroupsRequest`](struct.ListRuleGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRuleGroupsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListRuleGroupsRequestBuilder {
+    /// Sets the [`limit`](struct.ListRuleGroupsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListRuleGroupsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRuleGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRuleGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(ListRuleGroupsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRuleGroupsResponse {
@@ -1457,6 +5111,42 @@ pub struct ListRulesRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListRulesRequest {
+    /// Returns a builder for `ListRulesRequest`, checking its required members when built.
+    pub fn builder() -> ListRulesRequestBuilder {
+        ListRulesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRulesRequest`](struct.ListRulesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRulesRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListRulesRequestBuilder {
+    /// Sets the [`limit`](struct.ListRulesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListRulesRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRulesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRulesRequest, ::rusoto_core::BuildError> {
+        Ok(ListRulesRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRulesResponse {
@@ -1482,6 +5172,44 @@ pub struct ListSizeConstraintSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListSizeConstraintSetsRequest {
+    /// Returns a builder for `ListSizeConstraintSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListSizeConstraintSetsRequestBuilder {
+        ListSizeConstraintSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSizeConstraintSetsRequest`](struct.ListSizeConstraintSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSizeConstraintSetsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListSizeConstraintSetsRequestBuilder {
+    /// Sets the [`limit`](struct.ListSizeConstraintSetsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListSizeConstraintSetsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSizeConstraintSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSizeConstraintSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListSizeConstraintSetsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSizeConstraintSetsResponse {
@@ -1508,6 +5236,44 @@ pub struct ListSqlInjectionMatchSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListSqlInjectionMatchSetsRequest {
+    /// Returns a builder for `ListSqlInjectionMatchSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListSqlInjectionMatchSetsRequestBuilder {
+        ListSqlInjectionMatchSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSqlInjectionMatchSetsRequest`](struct.ListSqlInjectionMatchSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSqlInjectionMatchSetsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListSqlInjectionMatchSetsRequestBuilder {
+    /// Sets the [`limit`](struct.ListSqlInjectionMatchSetsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListSqlInjectionMatchSetsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSqlInjectionMatchSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSqlInjectionMatchSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListSqlInjectionMatchSetsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 /// <p>The response to a <a>ListSqlInjectionMatchSets</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1534,6 +5300,44 @@ pub struct ListSubscribedRuleGroupsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListSubscribedRuleGroupsRequest {
+    /// Returns a builder for `ListSubscribedRuleGroupsRequest`, checking its required members when built.
+    pub fn builder() -> ListSubscribedRuleGroupsRequestBuilder {
+        ListSubscribedRuleGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSubscribedRuleGroupsRequest`](struct.ListSubscribedRuleGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSubscribedRuleGroupsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListSubscribedRuleGroupsRequestBuilder {
+    /// Sets the [`limit`](struct.ListSubscribedRuleGroupsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListSubscribedRuleGroupsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSubscribedRuleGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSubscribedRuleGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(ListSubscribedRuleGroupsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSubscribedRuleGroupsResponse {
@@ -1559,15 +5363,63 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListTagsForResourceResponse {
-    #[serde(rename = "NextMarker")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_marker: Option<String>,
-    #[serde(rename = "TagInfoForResource")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag_info_for_resource: Option<TagInfoForResource>,
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`limit`](struct.ListTagsForResourceRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListTagsForResourceRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListTagsForResourceResponse {
+    #[serde(rename = "NextMarker")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_marker: Option<String>,
+    #[serde(rename = "TagInfoForResource")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_info_for_resource: Option<TagInfoForResource>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1582,6 +5434,42 @@ pub struct ListWebACLsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListWebACLsRequest {
+    /// Returns a builder for `ListWebACLsRequest`, checking its required members when built.
+    pub fn builder() -> ListWebACLsRequestBuilder {
+        ListWebACLsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListWebACLsRequest`](struct.ListWebACLsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListWebACLsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListWebACLsRequestBuilder {
+    /// Sets the [`limit`](struct.ListWebACLsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListWebACLsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListWebACLsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListWebACLsRequest, ::rusoto_core::BuildError> {
+        Ok(ListWebACLsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListWebACLsResponse {
@@ -1608,6 +5496,44 @@ pub struct ListXssMatchSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl ListXssMatchSetsRequest {
+    /// Returns a builder for `ListXssMatchSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListXssMatchSetsRequestBuilder {
+        ListXssMatchSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListXssMatchSetsRequest`](struct.ListXssMatchSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListXssMatchSetsRequestBuilder {
+    limit: Option<i64>,
+    next_marker: Option<String>,
+}
+
+impl ListXssMatchSetsRequestBuilder {
+    /// Sets the [`limit`](struct.ListXssMatchSetsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_marker`](struct.ListXssMatchSetsRequest.html#structfield.next_marker) member.
+    pub fn next_marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListXssMatchSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListXssMatchSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListXssMatchSetsRequest {
+            limit: self.limit,
+            next_marker: self.next_marker,
+        })
+    }
+}
+
 /// <p>The response to a <a>ListXssMatchSets</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1637,8 +5563,184 @@ pub struct LoggingConfiguration {
     pub resource_arn: String,
 }
 
+/// <p><p>The part of the web request that you want AWS WAF to search for a specified string. Parts of a request that you can search include the following:</p> <ul> <li> <p> <code>HEADER</code>: A specified request header, for example, the value of the <code>User-Agent</code> or <code>Referer</code> header. If you choose <code>HEADER</code> for the type, specify the name of the header in <code>Data</code>.</p> </li> <li> <p> <code>METHOD</code>: The HTTP method, which indicated the type of operation that the request is asking the origin to perform. Amazon CloudFront supports the following methods: <code>DELETE</code>, <code>GET</code>, <code>HEAD</code>, <code>OPTIONS</code>, <code>PATCH</code>, <code>POST</code>, and <code>PUT</code>.</p> </li> <li> <p> <code>QUERY<em>STRING</code>: A query string, which is the part of a URL that appears after a <code>?</code> character, if any.</p> </li> <li> <p> <code>URI</code>: The part of a web request that identifies a resource, for example, <code>/images/daily-ad.jpg</code>.</p> </li> <li> <p> <code>BODY</code>: The part of a request that contains any additional data that you want to send to your web server as the HTTP request body, such as data from a form. The request body immediately follows the request headers. Note that only the first <code>8192</code> bytes of the request body are forwarded to AWS WAF for inspection. To allow or block requests based on the length of the body, you can create a size constraint set. For more information, see <a>CreateSizeConstraintSet</a>. </p> </li> <li> <p> <code>SINGLE</em>QUERY<em>ARG</code>: The parameter in the query string that you will inspect, such as <i>UserName</i> or <i>SalesRegion</i>. The maximum length for <code>SINGLE</em>QUERY<em>ARG</code> is 30 characters.</p> </li> <li> <p> <code>ALL</em>QUERY<em>ARGS</code>: Similar to <code>SINGLE</em>QUERY_ARG</code>, but rather than inspecting a single parameter, AWS WAF will inspect all parameters within the query for the value or regex pattern that you specify in <code>TargetString</code>.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MatchFieldType {
+    /// <p><code>URI</code> - The part of a web request that identifies a resource, for example, <code>/images/daily-ad.jpg</code>.</p>
+    Uri,
+    /// <p><code>QUERY_STRING</code></p>
+    QueryString,
+    /// <p><code>HEADER</code> - A specified request header, for example, the value of the <code>User-Agent</code> or <code>Referer</code> header. If you choose <code>HEADER</code> for the type, specify the name of the header in <code>Data</code>.</p>
+    Header,
+    /// <p><code>METHOD</code> - The HTTP method, which indicated the type of operation that the request is asking the origin to perform. Amazon CloudFront supports the following methods: <code>DELETE</code>, <code>GET</code>, <code>HEAD</code>, <code>OPTIONS</code>, <code>PATCH</code>, <code>POST</code>, and <code>PUT</code>.</p>
+    Method,
+    /// <p><code>BODY</code> - The part of a request that contains any additional data that you want to send to your web server as the HTTP request body, such as data from a form. The request body immediately follows the request headers. Note that only the first <code>8192</code> bytes of the request body are forwarded to AWS WAF for inspection. To allow or block requests based on the length of the body, you can create a size constraint set. For more information, see <a>CreateSizeConstraintSet</a>.</p>
+    Body,
+    /// <p><code>SINGLE_QUERY_ARG</code></p>
+    SingleQueryArg,
+    /// <p><code>ALL_QUERY_ARGS</code></p>
+    AllQueryArgs,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MatchFieldType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MatchFieldType::Uri => "URI",
+            MatchFieldType::QueryString => "QUERY_STRING",
+            MatchFieldType::Header => "HEADER",
+            MatchFieldType::Method => "METHOD",
+            MatchFieldType::Body => "BODY",
+            MatchFieldType::SingleQueryArg => "SINGLE_QUERY_ARG",
+            MatchFieldType::AllQueryArgs => "ALL_QUERY_ARGS",
+            MatchFieldType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MatchFieldType {
+    fn from(value: &'a str) -> MatchFieldType {
+        match value {
+            "URI" => MatchFieldType::Uri,
+            "QUERY_STRING" => MatchFieldType::QueryString,
+            "HEADER" => MatchFieldType::Header,
+            "METHOD" => MatchFieldType::Method,
+            "BODY" => MatchFieldType::Body,
+            "SINGLE_QUERY_ARG" => MatchFieldType::SingleQueryArg,
+            "ALL_QUERY_ARGS" => MatchFieldType::AllQueryArgs,
+            _ => MatchFieldType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MatchFieldType {
+    fn from(value: String) -> MatchFieldType {
+        MatchFieldType::from(value.as_str())
+    }
+}
+
+impl From<MatchFieldType> for String {
+    fn from(value: MatchFieldType) -> String {
+        match value {
+            MatchFieldType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MatchFieldType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MatchFieldType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MatchFieldType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MatchFieldType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MatchFieldType::from(value))
+    }
+}
+
+/// <p>Within the portion of a web request that you want to search (for example, in the query string, if any), specify where you want AWS WAF to search. Valid values include the following:</p> <p> <b>CONTAINS</b> </p> <p>The specified part of the web request must include the value of <code>TargetString</code>, but the location doesn't matter.</p> <p> <b>CONTAINS_WORD</b> </p> <p>The specified part of the web request must include the value of <code>TargetString</code>, and <code>TargetString</code> must contain only alphanumeric characters or underscore (A-Z, a-z, 0-9, or _). In addition, <code>TargetString</code> must be a word, which means one of the following:</p> <ul> <li> <p> <code>TargetString</code> exactly matches the value of the specified part of the web request, such as the value of a header.</p> </li> <li> <p> <code>TargetString</code> is at the beginning of the specified part of the web request and is followed by a character other than an alphanumeric character or underscore (_), for example, <code>BadBot;</code>.</p> </li> <li> <p> <code>TargetString</code> is at the end of the specified part of the web request and is preceded by a character other than an alphanumeric character or underscore (_), for example, <code>;BadBot</code>.</p> </li> <li> <p> <code>TargetString</code> is in the middle of the specified part of the web request and is preceded and followed by characters other than alphanumeric characters or underscore (_), for example, <code>-BadBot;</code>.</p> </li> </ul> <p> <b>EXACTLY</b> </p> <p>The value of the specified part of the web request must exactly match the value of <code>TargetString</code>.</p> <p> <b>STARTS_WITH</b> </p> <p>The value of <code>TargetString</code> must appear at the beginning of the specified part of the web request.</p> <p> <b>ENDS_WITH</b> </p> <p>The value of <code>TargetString</code> must appear at the end of the specified part of the web request.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PositionalConstraint {
+    /// <p><code>EXACTLY</code></p>
+    Exactly,
+    /// <p><code>STARTS_WITH</code></p>
+    StartsWith,
+    /// <p><code>ENDS_WITH</code></p>
+    EndsWith,
+    /// <p><code>CONTAINS</code></p>
+    Contains,
+    /// <p><code>CONTAINS_WORD</code></p>
+    ContainsWord,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PositionalConstraint {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PositionalConstraint::Exactly => "EXACTLY",
+            PositionalConstraint::StartsWith => "STARTS_WITH",
+            PositionalConstraint::EndsWith => "ENDS_WITH",
+            PositionalConstraint::Contains => "CONTAINS",
+            PositionalConstraint::ContainsWord => "CONTAINS_WORD",
+            PositionalConstraint::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PositionalConstraint {
+    fn from(value: &'a str) -> PositionalConstraint {
+        match value {
+            "EXACTLY" => PositionalConstraint::Exactly,
+            "STARTS_WITH" => PositionalConstraint::StartsWith,
+            "ENDS_WITH" => PositionalConstraint::EndsWith,
+            "CONTAINS" => PositionalConstraint::Contains,
+            "CONTAINS_WORD" => PositionalConstraint::ContainsWord,
+            _ => PositionalConstraint::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PositionalConstraint {
+    fn from(value: String) -> PositionalConstraint {
+        PositionalConstraint::from(value.as_str())
+    }
+}
+
+impl From<PositionalConstraint> for String {
+    fn from(value: PositionalConstraint) -> String {
+        match value {
+            PositionalConstraint::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PositionalConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PositionalConstraint {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PositionalConstraint {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PositionalConstraint, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PositionalConstraint::from(value))
+    }
+}
+
 /// <p>Specifies the <a>ByteMatchSet</a>, <a>IPSet</a>, <a>SqlInjectionMatchSet</a>, <a>XssMatchSet</a>, <a>RegexMatchSet</a>, <a>GeoMatchSet</a>, and <a>SizeConstraintSet</a> objects that you want to add to a <code>Rule</code> and, for each object, indicates whether you want to negate the settings, for example, requests that do NOT originate from the IP address 192.0.2.44. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Predicate {
     /// <p>A unique identifier for a predicate in a <code>Rule</code>, such as <code>ByteMatchSetId</code> or <code>IPSetId</code>. The ID is returned by the corresponding <code>Create</code> or <code>List</code> command.</p>
     #[serde(rename = "DataId")]
@@ -1648,7 +5750,99 @@ pub struct Predicate {
     pub negated: bool,
     /// <p>The type of predicate in a <code>Rule</code>, such as <code>ByteMatch</code> or <code>IPSet</code>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: PredicateType,
+}
+
+/// <p>The type of predicate in a <code>Rule</code>, such as <code>ByteMatch</code> or <code>IPSet</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PredicateType {
+    /// <p><code>IPMatch</code></p>
+    IPMatch,
+    /// <p><code>ByteMatch</code></p>
+    ByteMatch,
+    /// <p><code>SqlInjectionMatch</code></p>
+    SqlInjectionMatch,
+    /// <p><code>GeoMatch</code></p>
+    GeoMatch,
+    /// <p><code>SizeConstraint</code></p>
+    SizeConstraint,
+    /// <p><code>XssMatch</code></p>
+    XssMatch,
+    /// <p><code>RegexMatch</code></p>
+    RegexMatch,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PredicateType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PredicateType::IPMatch => "IPMatch",
+            PredicateType::ByteMatch => "ByteMatch",
+            PredicateType::SqlInjectionMatch => "SqlInjectionMatch",
+            PredicateType::GeoMatch => "GeoMatch",
+            PredicateType::SizeConstraint => "SizeConstraint",
+            PredicateType::XssMatch => "XssMatch",
+            PredicateType::RegexMatch => "RegexMatch",
+            PredicateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PredicateType {
+    fn from(value: &'a str) -> PredicateType {
+        match value {
+            "IPMatch" => PredicateType::IPMatch,
+            "ByteMatch" => PredicateType::ByteMatch,
+            "SqlInjectionMatch" => PredicateType::SqlInjectionMatch,
+            "GeoMatch" => PredicateType::GeoMatch,
+            "SizeConstraint" => PredicateType::SizeConstraint,
+            "XssMatch" => PredicateType::XssMatch,
+            "RegexMatch" => PredicateType::RegexMatch,
+            _ => PredicateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PredicateType {
+    fn from(value: String) -> PredicateType {
+        PredicateType::from(value.as_str())
+    }
+}
+
+impl From<PredicateType> for String {
+    fn from(value: PredicateType) -> String {
+        match value {
+            PredicateType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PredicateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PredicateType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PredicateType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PredicateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PredicateType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1658,6 +5852,38 @@ pub struct PutLoggingConfigurationRequest {
     pub logging_configuration: LoggingConfiguration,
 }
 
+impl PutLoggingConfigurationRequest {
+    /// Returns a builder for `PutLoggingConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> PutLoggingConfigurationRequestBuilder {
+        PutLoggingConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutLoggingConfigurationRequest`](struct.PutLoggingConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutLoggingConfigurationRequestBuilder {
+    logging_configuration: Option<LoggingConfiguration>,
+}
+
+impl PutLoggingConfigurationRequestBuilder {
+    /// Sets the [`logging_configuration`](struct.PutLoggingConfigurationRequest.html#structfield.logging_configuration) member.
+    pub fn logging_configuration<T: Into<LoggingConfiguration>>(mut self, value: T) -> Self {
+        self.logging_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutLoggingConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutLoggingConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(PutLoggingConfigurationRequest {
+            logging_configuration: self
+                .logging_configuration
+                .ok_or(::rusoto_core::BuildError::missing("logging_configuration"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutLoggingConfigurationResponse {
@@ -1677,12 +5903,54 @@ pub struct PutPermissionPolicyRequest {
     pub resource_arn: String,
 }
 
+impl PutPermissionPolicyRequest {
+    /// Returns a builder for `PutPermissionPolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutPermissionPolicyRequestBuilder {
+        PutPermissionPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutPermissionPolicyRequest`](struct.PutPermissionPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutPermissionPolicyRequestBuilder {
+    policy: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl PutPermissionPolicyRequestBuilder {
+    /// Sets the [`policy`](struct.PutPermissionPolicyRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.PutPermissionPolicyRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutPermissionPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutPermissionPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutPermissionPolicyRequest {
+            policy: self
+                .policy
+                .ok_or(::rusoto_core::BuildError::missing("policy"))?,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutPermissionPolicyResponse {}
 
 /// <p>A <code>RateBasedRule</code> is identical to a regular <a>Rule</a>, with one addition: a <code>RateBasedRule</code> counts the number of requests that arrive from a specified IP address every five minutes. For example, based on recent requests that you've seen from an attacker, you might create a <code>RateBasedRule</code> that includes the following conditions: </p> <ul> <li> <p>The requests come from 192.0.2.44.</p> </li> <li> <p>They contain the value <code>BadBot</code> in the <code>User-Agent</code> header.</p> </li> </ul> <p>In the rule, you also define the rate limit as 15,000.</p> <p>Requests that meet both of these conditions and exceed 15,000 requests every five minutes trigger the rule's action (block or count), which is defined in the web ACL.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RateBasedRule {
     /// <p>The <code>Predicates</code> object contains one <code>Predicate</code> element for each <a>ByteMatchSet</a>, <a>IPSet</a>, or <a>SqlInjectionMatchSet</a> object that you want to include in a <code>RateBasedRule</code>.</p>
@@ -1698,7 +5966,7 @@ pub struct RateBasedRule {
     pub name: Option<String>,
     /// <p>The field that AWS WAF uses to determine if requests are likely arriving from single source and thus subject to rate monitoring. The only valid value for <code>RateKey</code> is <code>IP</code>. <code>IP</code> indicates that requests arriving from the same IP address are subject to the <code>RateLimit</code> that is specified in the <code>RateBasedRule</code>.</p>
     #[serde(rename = "RateKey")]
-    pub rate_key: String,
+    pub rate_key: RateKey,
     /// <p>The maximum number of requests, which have an identical value in the field specified by the <code>RateKey</code>, allowed in a five-minute period. If the number of requests exceeds the <code>RateLimit</code> and the other predicates specified in the rule are also met, AWS WAF triggers the action that is specified for this rule.</p>
     #[serde(rename = "RateLimit")]
     pub rate_limit: i64,
@@ -1707,6 +5975,74 @@ pub struct RateBasedRule {
     pub rule_id: String,
 }
 
+/// <p>The field that AWS WAF uses to determine if requests are likely arriving from a single source and thus subject to rate monitoring. The only valid value for <code>RateKey</code> is <code>IP</code>. <code>IP</code> indicates that requests that arrive from the same IP address are subject to the <code>RateLimit</code> that is specified in the <code>RateBasedRule</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RateKey {
+    /// <p><code>IP</code></p>
+    Ip,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RateKey {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RateKey::Ip => "IP",
+            RateKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RateKey {
+    fn from(value: &'a str) -> RateKey {
+        match value {
+            "IP" => RateKey::Ip,
+            _ => RateKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RateKey {
+    fn from(value: String) -> RateKey {
+        RateKey::from(value.as_str())
+    }
+}
+
+impl From<RateKey> for String {
+    fn from(value: RateKey) -> String {
+        match value {
+            RateKey::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RateKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RateKey {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RateKey {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RateKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RateKey::from(value))
+    }
+}
+
 /// <p>In a <a>GetRegexMatchSet</a> request, <code>RegexMatchSet</code> is a complex type that contains the <code>RegexMatchSetId</code> and <code>Name</code> of a <code>RegexMatchSet</code>, and the values that you specified when you updated the <code>RegexMatchSet</code>.</p> <p> The values are contained in a <code>RegexMatchTuple</code> object, which specify the parts of web requests that you want AWS WAF to inspect and the values that you want AWS WAF to search for. If a <code>RegexMatchSet</code> contains more than one <code>RegexMatchTuple</code> object, a request needs to match the settings in only one <code>ByteMatchTuple</code> to be considered a match.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1738,18 +6074,18 @@ pub struct RegexMatchSetSummary {
 }
 
 /// <p>In an <a>UpdateRegexMatchSet</a> request, <code>RegexMatchSetUpdate</code> specifies whether to insert or delete a <a>RegexMatchTuple</a> and includes the settings for the <code>RegexMatchTuple</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RegexMatchSetUpdate {
     /// <p>Specifies whether to insert or delete a <a>RegexMatchTuple</a>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Information about the part of a web request that you want AWS WAF to inspect and the identifier of the regular expression (regex) pattern that you want AWS WAF to search for. If you specify <code>DELETE</code> for the value of <code>Action</code>, the <code>RegexMatchTuple</code> values must exactly match the values in the <code>RegexMatchTuple</code> that you want to delete from the <code>RegexMatchSet</code>.</p>
     #[serde(rename = "RegexMatchTuple")]
     pub regex_match_tuple: RegexMatchTuple,
 }
 
 /// <p><p>The regular expression pattern that you want AWS WAF to search for in web requests, the location in requests that you want AWS WAF to search, and other settings. Each <code>RegexMatchTuple</code> object contains: </p> <ul> <li> <p>The part of a web request that you want AWS WAF to inspect, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The identifier of the pattern (a regular expression) that you want AWS WAF to look for. For more information, see <a>RegexPatternSet</a>. </p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RegexMatchTuple {
     /// <p>Specifies where in a web request to look for the <code>RegexPatternSet</code>.</p>
     #[serde(rename = "FieldToMatch")]
@@ -1759,7 +6095,7 @@ pub struct RegexMatchTuple {
     pub regex_pattern_set_id: String,
     /// <p>Text transformations eliminate some of the unusual formatting that attackers use in web requests in an effort to bypass AWS WAF. If you specify a transformation, AWS WAF performs the transformation on <code>RegexPatternSet</code> before inspecting a request for a match.</p> <p>You can only specify a single type of TextTransformation.</p> <p> <b>CMD_LINE</b> </p> <p>When you're concerned that attackers are injecting an operating system commandline command and using unusual formatting to disguise some or all of the command, use this option to perform the following transformations:</p> <ul> <li> <p>Delete the following characters: \ " ' ^</p> </li> <li> <p>Delete spaces before the following characters: / (</p> </li> <li> <p>Replace the following characters with a space: , ;</p> </li> <li> <p>Replace multiple spaces with one space</p> </li> <li> <p>Convert uppercase letters (A-Z) to lowercase (a-z)</p> </li> </ul> <p> <b>COMPRESS_WHITE_SPACE</b> </p> <p>Use this option to replace the following characters with a space character (decimal 32):</p> <ul> <li> <p>\f, formfeed, decimal 12</p> </li> <li> <p>\t, tab, decimal 9</p> </li> <li> <p>\n, newline, decimal 10</p> </li> <li> <p>\r, carriage return, decimal 13</p> </li> <li> <p>\v, vertical tab, decimal 11</p> </li> <li> <p>non-breaking space, decimal 160</p> </li> </ul> <p> <code>COMPRESS_WHITE_SPACE</code> also replaces multiple spaces with one space.</p> <p> <b>HTML_ENTITY_DECODE</b> </p> <p>Use this option to replace HTML-encoded characters with unencoded characters. <code>HTML_ENTITY_DECODE</code> performs the following operations:</p> <ul> <li> <p>Replaces <code>(ampersand)quot;</code> with <code>"</code> </p> </li> <li> <p>Replaces <code>(ampersand)nbsp;</code> with a non-breaking space, decimal 160</p> </li> <li> <p>Replaces <code>(ampersand)lt;</code> with a "less than" symbol</p> </li> <li> <p>Replaces <code>(ampersand)gt;</code> with <code>&gt;</code> </p> </li> <li> <p>Replaces characters that are represented in hexadecimal format, <code>(ampersand)#xhhhh;</code>, with the corresponding characters</p> </li> <li> <p>Replaces characters that are represented in decimal format, <code>(ampersand)#nnnn;</code>, with the corresponding characters</p> </li> </ul> <p> <b>LOWERCASE</b> </p> <p>Use this option to convert uppercase letters (A-Z) to lowercase (a-z).</p> <p> <b>URL_DECODE</b> </p> <p>Use this option to decode a URL-encoded value.</p> <p> <b>NONE</b> </p> <p>Specify <code>NONE</code> if you don't want to perform any text transformations.</p>
     #[serde(rename = "TextTransformation")]
-    pub text_transformation: String,
+    pub text_transformation: TextTransformation,
 }
 
 /// <p>The <code>RegexPatternSet</code> specifies the regular expression (regex) pattern that you want AWS WAF to search for, such as <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p>
@@ -1791,16 +6127,88 @@ pub struct RegexPatternSetSummary {
 }
 
 /// <p>In an <a>UpdateRegexPatternSet</a> request, <code>RegexPatternSetUpdate</code> specifies whether to insert or delete a <code>RegexPatternString</code> and includes the settings for the <code>RegexPatternString</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RegexPatternSetUpdate {
     /// <p>Specifies whether to insert or delete a <code>RegexPatternString</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Specifies the regular expression (regex) pattern that you want AWS WAF to search for, such as <code>B[a@]dB[o0]t</code>.</p>
     #[serde(rename = "RegexPatternString")]
     pub regex_pattern_string: String,
 }
 
+/// <p>The type of resource to list, either an application load balancer or Amazon API Gateway.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// <p><code>APPLICATION_LOAD_BALANCER</code></p>
+    ApplicationLoadBalancer,
+    /// <p><code>API_GATEWAY</code></p>
+    ApiGateway,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::ApplicationLoadBalancer => "APPLICATION_LOAD_BALANCER",
+            ResourceType::ApiGateway => "API_GATEWAY",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "APPLICATION_LOAD_BALANCER" => ResourceType::ApplicationLoadBalancer,
+            "API_GATEWAY" => ResourceType::ApiGateway,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        ResourceType::from(value.as_str())
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> String {
+        match value {
+            ResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceType::from(value))
+    }
+}
+
 /// <p>A combination of <a>ByteMatchSet</a>, <a>IPSet</a>, and/or <a>SqlInjectionMatchSet</a> objects that identify the web requests that you want to allow, block, or count. For example, you might create a <code>Rule</code> that includes the following predicates:</p> <ul> <li> <p>An <code>IPSet</code> that causes AWS WAF to search for web requests that originate from the IP address <code>192.0.2.44</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that causes AWS WAF to search for web requests for which the value of the <code>User-Agent</code> header is <code>BadBot</code>.</p> </li> </ul> <p>To match the settings in this <code>Rule</code>, a request must originate from <code>192.0.2.44</code> AND include a <code>User-Agent</code> header for which the value is <code>BadBot</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1851,11 +6259,11 @@ pub struct RuleGroupSummary {
 }
 
 /// <p>Specifies an <code>ActivatedRule</code> and indicates whether you want to add it to a <code>RuleGroup</code> or delete it from a <code>RuleGroup</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RuleGroupUpdate {
     /// <p>Specify <code>INSERT</code> to add an <code>ActivatedRule</code> to a <code>RuleGroup</code>. Use <code>DELETE</code> to remove an <code>ActivatedRule</code> from a <code>RuleGroup</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>The <code>ActivatedRule</code> object specifies a <code>Rule</code> that you want to insert or delete, the priority of the <code>Rule</code> in the <code>WebACL</code>, and the action that you want AWS WAF to take when a web request matches the <code>Rule</code> (<code>ALLOW</code>, <code>BLOCK</code>, or <code>COUNT</code>).</p>
     #[serde(rename = "ActivatedRule")]
     pub activated_rule: ActivatedRule,
@@ -1874,11 +6282,11 @@ pub struct RuleSummary {
 }
 
 /// <p>Specifies a <code>Predicate</code> (such as an <code>IPSet</code>) and indicates whether you want to add it to a <code>Rule</code> or delete it from a <code>Rule</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RuleUpdate {
     /// <p>Specify <code>INSERT</code> to add a <code>Predicate</code> to a <code>Rule</code>. Use <code>DELETE</code> to remove a <code>Predicate</code> from a <code>Rule</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>The ID of the <code>Predicate</code> (such as an <code>IPSet</code>) that you want to add to a <code>Rule</code>.</p>
     #[serde(rename = "Predicate")]
     pub predicate: Predicate,
@@ -1901,19 +6309,20 @@ pub struct SampledHTTPRequest {
     pub rule_within_rule_group: Option<String>,
     /// <p>The time at which AWS WAF received the request from your AWS resource, in Unix time format (in seconds).</p>
     #[serde(rename = "Timestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<EpochTimestamp>,
     /// <p>A value that indicates how one result in the response relates proportionally to other results in the response. A result that has a weight of <code>2</code> represents roughly twice as many CloudFront web requests as a result that has a weight of <code>1</code>.</p>
     #[serde(rename = "Weight")]
     pub weight: i64,
 }
 
 /// <p>Specifies a constraint on the size of a part of the web request. AWS WAF uses the <code>Size</code>, <code>ComparisonOperator</code>, and <code>FieldToMatch</code> to build an expression in the form of "<code>Size</code> <code>ComparisonOperator</code> size in bytes of <code>FieldToMatch</code>". If that expression is true, the <code>SizeConstraint</code> is considered to match.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SizeConstraint {
     /// <p>The type of comparison you want AWS WAF to perform. AWS WAF uses this in combination with the provided <code>Size</code> and <code>FieldToMatch</code> to build an expression in the form of "<code>Size</code> <code>ComparisonOperator</code> size in bytes of <code>FieldToMatch</code>". If that expression is true, the <code>SizeConstraint</code> is considered to match.</p> <p> <b>EQ</b>: Used to test if the <code>Size</code> is equal to the size of the <code>FieldToMatch</code> </p> <p> <b>NE</b>: Used to test if the <code>Size</code> is not equal to the size of the <code>FieldToMatch</code> </p> <p> <b>LE</b>: Used to test if the <code>Size</code> is less than or equal to the size of the <code>FieldToMatch</code> </p> <p> <b>LT</b>: Used to test if the <code>Size</code> is strictly less than the size of the <code>FieldToMatch</code> </p> <p> <b>GE</b>: Used to test if the <code>Size</code> is greater than or equal to the size of the <code>FieldToMatch</code> </p> <p> <b>GT</b>: Used to test if the <code>Size</code> is strictly greater than the size of the <code>FieldToMatch</code> </p>
     #[serde(rename = "ComparisonOperator")]
-    pub comparison_operator: String,
+    pub comparison_operator: ComparisonOperator,
     /// <p>Specifies where in a web request to look for the size constraint.</p>
     #[serde(rename = "FieldToMatch")]
     pub field_to_match: FieldToMatch,
@@ -1922,7 +6331,7 @@ pub struct SizeConstraint {
     pub size: i64,
     /// <p>Text transformations eliminate some of the unusual formatting that attackers use in web requests in an effort to bypass AWS WAF. If you specify a transformation, AWS WAF performs the transformation on <code>FieldToMatch</code> before inspecting a request for a match.</p> <p>You can only specify a single type of TextTransformation.</p> <p>Note that if you choose <code>BODY</code> for the value of <code>Type</code>, you must choose <code>NONE</code> for <code>TextTransformation</code> because CloudFront forwards only the first 8192 bytes for inspection. </p> <p> <b>NONE</b> </p> <p>Specify <code>NONE</code> if you don't want to perform any text transformations.</p> <p> <b>CMD_LINE</b> </p> <p>When you're concerned that attackers are injecting an operating system command line command and using unusual formatting to disguise some or all of the command, use this option to perform the following transformations:</p> <ul> <li> <p>Delete the following characters: \ " ' ^</p> </li> <li> <p>Delete spaces before the following characters: / (</p> </li> <li> <p>Replace the following characters with a space: , ;</p> </li> <li> <p>Replace multiple spaces with one space</p> </li> <li> <p>Convert uppercase letters (A-Z) to lowercase (a-z)</p> </li> </ul> <p> <b>COMPRESS_WHITE_SPACE</b> </p> <p>Use this option to replace the following characters with a space character (decimal 32):</p> <ul> <li> <p>\f, formfeed, decimal 12</p> </li> <li> <p>\t, tab, decimal 9</p> </li> <li> <p>\n, newline, decimal 10</p> </li> <li> <p>\r, carriage return, decimal 13</p> </li> <li> <p>\v, vertical tab, decimal 11</p> </li> <li> <p>non-breaking space, decimal 160</p> </li> </ul> <p> <code>COMPRESS_WHITE_SPACE</code> also replaces multiple spaces with one space.</p> <p> <b>HTML_ENTITY_DECODE</b> </p> <p>Use this option to replace HTML-encoded characters with unencoded characters. <code>HTML_ENTITY_DECODE</code> performs the following operations:</p> <ul> <li> <p>Replaces <code>(ampersand)quot;</code> with <code>"</code> </p> </li> <li> <p>Replaces <code>(ampersand)nbsp;</code> with a non-breaking space, decimal 160</p> </li> <li> <p>Replaces <code>(ampersand)lt;</code> with a "less than" symbol</p> </li> <li> <p>Replaces <code>(ampersand)gt;</code> with <code>&gt;</code> </p> </li> <li> <p>Replaces characters that are represented in hexadecimal format, <code>(ampersand)#xhhhh;</code>, with the corresponding characters</p> </li> <li> <p>Replaces characters that are represented in decimal format, <code>(ampersand)#nnnn;</code>, with the corresponding characters</p> </li> </ul> <p> <b>LOWERCASE</b> </p> <p>Use this option to convert uppercase letters (A-Z) to lowercase (a-z).</p> <p> <b>URL_DECODE</b> </p> <p>Use this option to decode a URL-encoded value.</p>
     #[serde(rename = "TextTransformation")]
-    pub text_transformation: String,
+    pub text_transformation: TextTransformation,
 }
 
 /// <p>A complex type that contains <code>SizeConstraint</code> objects, which specify the parts of web requests that you want AWS WAF to inspect the size of. If a <code>SizeConstraintSet</code> contains more than one <code>SizeConstraint</code> object, a request only needs to match one constraint to be considered a match.</p>
@@ -1954,11 +6363,11 @@ pub struct SizeConstraintSetSummary {
 }
 
 /// <p>Specifies the part of a web request that you want to inspect the size of and indicates whether you want to add the specification to a <a>SizeConstraintSet</a> or delete it from a <code>SizeConstraintSet</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SizeConstraintSetUpdate {
     /// <p>Specify <code>INSERT</code> to add a <a>SizeConstraintSetUpdate</a> to a <a>SizeConstraintSet</a>. Use <code>DELETE</code> to remove a <code>SizeConstraintSetUpdate</code> from a <code>SizeConstraintSet</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Specifies a constraint on the size of a part of the web request. AWS WAF uses the <code>Size</code>, <code>ComparisonOperator</code>, and <code>FieldToMatch</code> to build an expression in the form of "<code>Size</code> <code>ComparisonOperator</code> size in bytes of <code>FieldToMatch</code>". If that expression is true, the <code>SizeConstraint</code> is considered to match.</p>
     #[serde(rename = "SizeConstraint")]
     pub size_constraint: SizeConstraint,
@@ -1993,25 +6402,25 @@ pub struct SqlInjectionMatchSetSummary {
 }
 
 /// <p>Specifies the part of a web request that you want to inspect for snippets of malicious SQL code and indicates whether you want to add the specification to a <a>SqlInjectionMatchSet</a> or delete it from a <code>SqlInjectionMatchSet</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SqlInjectionMatchSetUpdate {
     /// <p>Specify <code>INSERT</code> to add a <a>SqlInjectionMatchSetUpdate</a> to a <a>SqlInjectionMatchSet</a>. Use <code>DELETE</code> to remove a <code>SqlInjectionMatchSetUpdate</code> from a <code>SqlInjectionMatchSet</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Specifies the part of a web request that you want AWS WAF to inspect for snippets of malicious SQL code and, if you want AWS WAF to inspect a header, the name of the header.</p>
     #[serde(rename = "SqlInjectionMatchTuple")]
     pub sql_injection_match_tuple: SqlInjectionMatchTuple,
 }
 
 /// <p>Specifies the part of a web request that you want AWS WAF to inspect for snippets of malicious SQL code and, if you want AWS WAF to inspect a header, the name of the header.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SqlInjectionMatchTuple {
     /// <p>Specifies where in a web request to look for snippets of malicious SQL code.</p>
     #[serde(rename = "FieldToMatch")]
     pub field_to_match: FieldToMatch,
     /// <p>Text transformations eliminate some of the unusual formatting that attackers use in web requests in an effort to bypass AWS WAF. If you specify a transformation, AWS WAF performs the transformation on <code>FieldToMatch</code> before inspecting a request for a match.</p> <p>You can only specify a single type of TextTransformation.</p> <p> <b>CMD_LINE</b> </p> <p>When you're concerned that attackers are injecting an operating system command line command and using unusual formatting to disguise some or all of the command, use this option to perform the following transformations:</p> <ul> <li> <p>Delete the following characters: \ " ' ^</p> </li> <li> <p>Delete spaces before the following characters: / (</p> </li> <li> <p>Replace the following characters with a space: , ;</p> </li> <li> <p>Replace multiple spaces with one space</p> </li> <li> <p>Convert uppercase letters (A-Z) to lowercase (a-z)</p> </li> </ul> <p> <b>COMPRESS_WHITE_SPACE</b> </p> <p>Use this option to replace the following characters with a space character (decimal 32):</p> <ul> <li> <p>\f, formfeed, decimal 12</p> </li> <li> <p>\t, tab, decimal 9</p> </li> <li> <p>\n, newline, decimal 10</p> </li> <li> <p>\r, carriage return, decimal 13</p> </li> <li> <p>\v, vertical tab, decimal 11</p> </li> <li> <p>non-breaking space, decimal 160</p> </li> </ul> <p> <code>COMPRESS_WHITE_SPACE</code> also replaces multiple spaces with one space.</p> <p> <b>HTML_ENTITY_DECODE</b> </p> <p>Use this option to replace HTML-encoded characters with unencoded characters. <code>HTML_ENTITY_DECODE</code> performs the following operations:</p> <ul> <li> <p>Replaces <code>(ampersand)quot;</code> with <code>"</code> </p> </li> <li> <p>Replaces <code>(ampersand)nbsp;</code> with a non-breaking space, decimal 160</p> </li> <li> <p>Replaces <code>(ampersand)lt;</code> with a "less than" symbol</p> </li> <li> <p>Replaces <code>(ampersand)gt;</code> with <code>&gt;</code> </p> </li> <li> <p>Replaces characters that are represented in hexadecimal format, <code>(ampersand)#xhhhh;</code>, with the corresponding characters</p> </li> <li> <p>Replaces characters that are represented in decimal format, <code>(ampersand)#nnnn;</code>, with the corresponding characters</p> </li> </ul> <p> <b>LOWERCASE</b> </p> <p>Use this option to convert uppercase letters (A-Z) to lowercase (a-z).</p> <p> <b>URL_DECODE</b> </p> <p>Use this option to decode a URL-encoded value.</p> <p> <b>NONE</b> </p> <p>Specify <code>NONE</code> if you don't want to perform any text transformations.</p>
     #[serde(rename = "TextTransformation")]
-    pub text_transformation: String,
+    pub text_transformation: TextTransformation,
 }
 
 /// <p>A summary of the rule groups you are subscribed to.</p>
@@ -2058,19 +6467,149 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
 
-/// <p>In a <a>GetSampledRequests</a> request, the <code>StartTime</code> and <code>EndTime</code> objects specify the time range for which you want AWS WAF to return a sample of web requests.</p> <p>In a <a>GetSampledRequests</a> response, the <code>StartTime</code> and <code>EndTime</code> objects specify the time range for which AWS WAF actually returned a sample of web requests. AWS WAF gets the specified number of requests from among the first 5,000 requests that your AWS resource receives during the specified time period. If your resource receives more than 5,000 requests during that period, AWS WAF stops sampling after the 5,000th request. In that case, <code>EndTime</code> is the time that AWS WAF received the 5,000th request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct TimeWindow {
-    /// <p>The end of the time range from which you want <code>GetSampledRequests</code> to return a sample of the requests that your AWS resource received. Specify the date and time in the following format: <code>"2016-09-27T14:50Z"</code>. You can specify any time range in the previous three hours.</p>
-    #[serde(rename = "EndTime")]
-    pub end_time: f64,
-    /// <p>The beginning of the time range from which you want <code>GetSampledRequests</code> to return a sample of the requests that your AWS resource received. Specify the date and time in the following format: <code>"2016-09-27T14:50Z"</code>. You can specify any time range in the previous three hours.</p>
-    #[serde(rename = "StartTime")]
-    pub start_time: f64,
+/// <p>Text transformations eliminate some of the unusual formatting that attackers use in web requests in an effort to bypass AWS WAF. If you specify a transformation, AWS WAF performs the transformation on <code>TargetString</code> before inspecting a request for a match.</p> <p>You can only specify a single type of TextTransformation.</p> <p> <b>CMD_LINE</b> </p> <p>When you're concerned that attackers are injecting an operating system command line command and using unusual formatting to disguise some or all of the command, use this option to perform the following transformations:</p> <ul> <li> <p>Delete the following characters: \ " ' ^</p> </li> <li> <p>Delete spaces before the following characters: / (</p> </li> <li> <p>Replace the following characters with a space: , ;</p> </li> <li> <p>Replace multiple spaces with one space</p> </li> <li> <p>Convert uppercase letters (A-Z) to lowercase (a-z)</p> </li> </ul> <p> <b>COMPRESS_WHITE_SPACE</b> </p> <p>Use this option to replace the following characters with a space character (decimal 32):</p> <ul> <li> <p>\f, formfeed, decimal 12</p> </li> <li> <p>\t, tab, decimal 9</p> </li> <li> <p>\n, newline, decimal 10</p> </li> <li> <p>\r, carriage return, decimal 13</p> </li> <li> <p>\v, vertical tab, decimal 11</p> </li> <li> <p>non-breaking space, decimal 160</p> </li> </ul> <p> <code>COMPRESS_WHITE_SPACE</code> also replaces multiple spaces with one space.</p> <p> <b>HTML_ENTITY_DECODE</b> </p> <p>Use this option to replace HTML-encoded characters with unencoded characters. <code>HTML_ENTITY_DECODE</code> performs the following operations:</p> <ul> <li> <p>Replaces <code>(ampersand)quot;</code> with <code>"</code> </p> </li> <li> <p>Replaces <code>(ampersand)nbsp;</code> with a non-breaking space, decimal 160</p> </li> <li> <p>Replaces <code>(ampersand)lt;</code> with a "less than" symbol</p> </li> <li> <p>Replaces <code>(ampersand)gt;</code> with <code>&gt;</code> </p> </li> <li> <p>Replaces characters that are represented in hexadecimal format, <code>(ampersand)#xhhhh;</code>, with the corresponding characters</p> </li> <li> <p>Replaces characters that are represented in decimal format, <code>(ampersand)#nnnn;</code>, with the corresponding characters</p> </li> </ul> <p> <b>LOWERCASE</b> </p> <p>Use this option to convert uppercase letters (A-Z) to lowercase (a-z).</p> <p> <b>URL_DECODE</b> </p> <p>Use this option to decode a URL-encoded value.</p> <p> <b>NONE</b> </p> <p>Specify <code>NONE</code> if you don't want to perform any text transformations.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TextTransformation {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>COMPRESS_WHITE_SPACE</code></p>
+    CompressWhiteSpace,
+    /// <p><code>HTML_ENTITY_DECODE</code></p>
+    HtmlEntityDecode,
+    /// <p><code>LOWERCASE</code></p>
+    Lowercase,
+    /// <p><code>CMD_LINE</code></p>
+    CmdLine,
+    /// <p><code>URL_DECODE</code></p>
+    UrlDecode,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TextTransformation {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TextTransformation::None => "NONE",
+            TextTransformation::CompressWhiteSpace => "COMPRESS_WHITE_SPACE",
+            TextTransformation::HtmlEntityDecode => "HTML_ENTITY_DECODE",
+            TextTransformation::Lowercase => "LOWERCASE",
+            TextTransformation::CmdLine => "CMD_LINE",
+            TextTransformation::UrlDecode => "URL_DECODE",
+            TextTransformation::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TextTransformation {
+    fn from(value: &'a str) -> TextTransformation {
+        match value {
+            "NONE" => TextTransformation::None,
+            "COMPRESS_WHITE_SPACE" => TextTransformation::CompressWhiteSpace,
+            "HTML_ENTITY_DECODE" => TextTransformation::HtmlEntityDecode,
+            "LOWERCASE" => TextTransformation::Lowercase,
+            "CMD_LINE" => TextTransformation::CmdLine,
+            "URL_DECODE" => TextTransformation::UrlDecode,
+            _ => TextTransformation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TextTransformation {
+    fn from(value: String) -> TextTransformation {
+        TextTransformation::from(value.as_str())
+    }
+}
+
+impl From<TextTransformation> for String {
+    fn from(value: TextTransformation) -> String {
+        match value {
+            TextTransformation::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TextTransformation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TextTransformation {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TextTransformation {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TextTransformation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TextTransformation::from(value))
+    }
+}
+
+/// <p>In a <a>GetSampledRequests</a> request, the <code>StartTime</code> and <code>EndTime</code> objects specify the time range for which you want AWS WAF to return a sample of web requests.</p> <p>In a <a>GetSampledRequests</a> response, the <code>StartTime</code> and <code>EndTime</code> objects specify the time range for which AWS WAF actually returned a sample of web requests. AWS WAF gets the specified number of requests from among the first 5,000 requests that your AWS resource receives during the specified time period. If your resource receives more than 5,000 requests during that period, AWS WAF stops sampling after the 5,000th request. In that case, <code>EndTime</code> is the time that AWS WAF received the 5,000th request. </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeWindow {
+    /// <p>The end of the time range from which you want <code>GetSampledRequests</code> to return a sample of the requests that your AWS resource received. Specify the date and time in the following format: <code>"2016-09-27T14:50Z"</code>. You can specify any time range in the previous three hours.</p>
+    #[serde(rename = "EndTime")]
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
+    /// <p>The beginning of the time range from which you want <code>GetSampledRequests</code> to return a sample of the requests that your AWS resource received. Specify the date and time in the following format: <code>"2016-09-27T14:50Z"</code>. You can specify any time range in the previous three hours.</p>
+    #[serde(rename = "StartTime")]
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2081,6 +6620,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -2098,6 +6677,58 @@ pub struct UpdateByteMatchSetRequest {
     pub updates: Vec<ByteMatchSetUpdate>,
 }
 
+impl UpdateByteMatchSetRequest {
+    /// Returns a builder for `UpdateByteMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateByteMatchSetRequestBuilder {
+        UpdateByteMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateByteMatchSetRequest`](struct.UpdateByteMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateByteMatchSetRequestBuilder {
+    byte_match_set_id: Option<String>,
+    change_token: Option<String>,
+    updates: Option<Vec<ByteMatchSetUpdate>>,
+}
+
+impl UpdateByteMatchSetRequestBuilder {
+    /// Sets the [`byte_match_set_id`](struct.UpdateByteMatchSetRequest.html#structfield.byte_match_set_id) member.
+    pub fn byte_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.byte_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`change_token`](struct.UpdateByteMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateByteMatchSetRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<ByteMatchSetUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateByteMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateByteMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateByteMatchSetRequest {
+            byte_match_set_id: self
+                .byte_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("byte_match_set_id"))?,
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            updates: self
+                .updates
+                .ok_or(::rusoto_core::BuildError::missing("updates"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateByteMatchSetResponse {
@@ -2120,6 +6751,58 @@ pub struct UpdateGeoMatchSetRequest {
     pub updates: Vec<GeoMatchSetUpdate>,
 }
 
+impl UpdateGeoMatchSetRequest {
+    /// Returns a builder for `UpdateGeoMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateGeoMatchSetRequestBuilder {
+        UpdateGeoMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGeoMatchSetRequest`](struct.UpdateGeoMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateGeoMatchSetRequestBuilder {
+    change_token: Option<String>,
+    geo_match_set_id: Option<String>,
+    updates: Option<Vec<GeoMatchSetUpdate>>,
+}
+
+impl UpdateGeoMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.UpdateGeoMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`geo_match_set_id`](struct.UpdateGeoMatchSetRequest.html#structfield.geo_match_set_id) member.
+    pub fn geo_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.geo_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateGeoMatchSetRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<GeoMatchSetUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGeoMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateGeoMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateGeoMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            geo_match_set_id: self
+                .geo_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("geo_match_set_id"))?,
+            updates: self
+                .updates
+                .ok_or(::rusoto_core::BuildError::missing("updates"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGeoMatchSetResponse {
@@ -2142,6 +6825,56 @@ pub struct UpdateIPSetRequest {
     pub updates: Vec<IPSetUpdate>,
 }
 
+impl UpdateIPSetRequest {
+    /// Returns a builder for `UpdateIPSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateIPSetRequestBuilder {
+        UpdateIPSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateIPSetRequest`](struct.UpdateIPSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateIPSetRequestBuilder {
+    change_token: Option<String>,
+    ip_set_id: Option<String>,
+    updates: Option<Vec<IPSetUpdate>>,
+}
+
+impl UpdateIPSetRequestBuilder {
+    /// Sets the [`change_token`](struct.UpdateIPSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ip_set_id`](struct.UpdateIPSetRequest.html#structfield.ip_set_id) member.
+    pub fn ip_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ip_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateIPSetRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<IPSetUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateIPSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateIPSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateIPSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            ip_set_id: self
+                .ip_set_id
+                .ok_or(::rusoto_core::BuildError::missing("ip_set_id"))?,
+            updates: self
+                .updates
+                .ok_or(::rusoto_core::BuildError::missing("updates"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateIPSetResponse {
@@ -2167,6 +6900,68 @@ pub struct UpdateRateBasedRuleRequest {
     pub updates: Vec<RuleUpdate>,
 }
 
+impl UpdateRateBasedRuleRequest {
+    /// Returns a builder for `UpdateRateBasedRuleRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRateBasedRuleRequestBuilder {
+        UpdateRateBasedRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRateBasedRuleRequest`](struct.UpdateRateBasedRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRateBasedRuleRequestBuilder {
+    change_token: Option<String>,
+    rate_limit: Option<i64>,
+    rule_id: Option<String>,
+    updates: Option<Vec<RuleUpdate>>,
+}
+
+impl UpdateRateBasedRuleRequestBuilder {
+    /// Sets the [`change_token`](struct.UpdateRateBasedRuleRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rate_limit`](struct.UpdateRateBasedRuleRequest.html#structfield.rate_limit) member.
+    pub fn rate_limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.rate_limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_id`](struct.UpdateRateBasedRuleRequest.html#structfield.rule_id) member.
+    pub fn rule_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateRateBasedRuleRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<RuleUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRateBasedRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRateBasedRuleRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRateBasedRuleRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            rate_limit: self
+                .rate_limit
+                .ok_or(::rusoto_core::BuildError::missing("rate_limit"))?,
+            rule_id: self
+                .rule_id
+                .ok_or(::rusoto_core::BuildError::missing("rule_id"))?,
+            updates: self
+                .updates
+                .ok_or(::rusoto_core::BuildError::missing("updates"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRateBasedRuleResponse {
@@ -2189,6 +6984,58 @@ pub struct UpdateRegexMatchSetRequest {
     pub updates: Vec<RegexMatchSetUpdate>,
 }
 
+impl UpdateRegexMatchSetRequest {
+    /// Returns a builder for `UpdateRegexMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRegexMatchSetRequestBuilder {
+        UpdateRegexMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRegexMatchSetRequest`](struct.UpdateRegexMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRegexMatchSetRequestBuilder {
+    change_token: Option<String>,
+    regex_match_set_id: Option<String>,
+    updates: Option<Vec<RegexMatchSetUpdate>>,
+}
+
+impl UpdateRegexMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.UpdateRegexMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`regex_match_set_id`](struct.UpdateRegexMatchSetRequest.html#structfield.regex_match_set_id) member.
+    pub fn regex_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.regex_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateRegexMatchSetRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<RegexMatchSetUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRegexMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRegexMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRegexMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            regex_match_set_id: self
+                .regex_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("regex_match_set_id"))?,
+            updates: self
+                .updates
+                .ok_or(::rusoto_core::BuildError::missing("updates"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRegexMatchSetResponse {
@@ -2211,6 +7058,58 @@ pub struct UpdateRegexPatternSetRequest {
     pub updates: Vec<RegexPatternSetUpdate>,
 }
 
+impl UpdateRegexPatternSetRequest {
+    /// Returns a builder for `UpdateRegexPatternSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRegexPatternSetRequestBuilder {
+        UpdateRegexPatternSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRegexPatternSetRequest`](struct.UpdateRegexPatternSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRegexPatternSetRequestBuilder {
+    change_token: Option<String>,
+    regex_pattern_set_id: Option<String>,
+    updates: Option<Vec<RegexPatternSetUpdate>>,
+}
+
+impl UpdateRegexPatternSetRequestBuilder {
+    /// Sets the [`change_token`](struct.UpdateRegexPatternSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`regex_pattern_set_id`](struct.UpdateRegexPatternSetRequest.html#structfield.regex_pattern_set_id) member.
+    pub fn regex_pattern_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.regex_pattern_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateRegexPatternSetRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<RegexPatternSetUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRegexPatternSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRegexPatternSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRegexPatternSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            regex_pattern_set_id: self
+                .regex_pattern_set_id
+                .ok_or(::rusoto_core::BuildError::missing("regex_pattern_set_id"))?,
+            updates: self
+                .updates
+                .ok_or(::rusoto_core::BuildError::missing("updates"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRegexPatternSetResponse {
@@ -2233,6 +7132,56 @@ pub struct UpdateRuleGroupRequest {
     pub updates: Vec<RuleGroupUpdate>,
 }
 
+impl UpdateRuleGroupRequest {
+    /// Returns a builder for `UpdateRuleGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRuleGroupRequestBuilder {
+        UpdateRuleGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRuleGroupRequest`](struct.UpdateRuleGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRuleGroupRequestBuilder {
+    change_token: Option<String>,
+    rule_group_id: Option<String>,
+    updates: Option<Vec<RuleGroupUpdate>>,
+}
+
+impl UpdateRuleGroupRequestBuilder {
+    /// Sets the [`change_token`](struct.UpdateRuleGroupRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_group_id`](struct.UpdateRuleGroupRequest.html#structfield.rule_group_id) member.
+    pub fn rule_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateRuleGroupRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<RuleGroupUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRuleGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateRuleGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRuleGroupRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            rule_group_id: self
+                .rule_group_id
+                .ok_or(::rusoto_core::BuildError::missing("rule_group_id"))?,
+            updates: self
+                .updates
+                .ok_or(::rusoto_core::BuildError::missing("updates"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRuleGroupResponse {
@@ -2255,6 +7204,56 @@ pub struct UpdateRuleRequest {
     pub updates: Vec<RuleUpdate>,
 }
 
+impl UpdateRuleRequest {
+    /// Returns a builder for `UpdateRuleRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRuleRequestBuilder {
+        UpdateRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRuleRequest`](struct.UpdateRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRuleRequestBuilder {
+    change_token: Option<String>,
+    rule_id: Option<String>,
+    updates: Option<Vec<RuleUpdate>>,
+}
+
+impl UpdateRuleRequestBuilder {
+    /// Sets the [`change_token`](struct.UpdateRuleRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_id`](struct.UpdateRuleRequest.html#structfield.rule_id) member.
+    pub fn rule_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateRuleRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<RuleUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateRuleRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRuleRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            rule_id: self
+                .rule_id
+                .ok_or(::rusoto_core::BuildError::missing("rule_id"))?,
+            updates: self
+                .updates
+                .ok_or(::rusoto_core::BuildError::missing("updates"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRuleResponse {
@@ -2277,6 +7276,58 @@ pub struct UpdateSizeConstraintSetRequest {
     pub updates: Vec<SizeConstraintSetUpdate>,
 }
 
+impl UpdateSizeConstraintSetRequest {
+    /// Returns a builder for `UpdateSizeConstraintSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSizeConstraintSetRequestBuilder {
+        UpdateSizeConstraintSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSizeConstraintSetRequest`](struct.UpdateSizeConstraintSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSizeConstraintSetRequestBuilder {
+    change_token: Option<String>,
+    size_constraint_set_id: Option<String>,
+    updates: Option<Vec<SizeConstraintSetUpdate>>,
+}
+
+impl UpdateSizeConstraintSetRequestBuilder {
+    /// Sets the [`change_token`](struct.UpdateSizeConstraintSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`size_constraint_set_id`](struct.UpdateSizeConstraintSetRequest.html#structfield.size_constraint_set_id) member.
+    pub fn size_constraint_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.size_constraint_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateSizeConstraintSetRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<SizeConstraintSetUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSizeConstraintSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSizeConstraintSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSizeConstraintSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            size_constraint_set_id: self
+                .size_constraint_set_id
+                .ok_or(::rusoto_core::BuildError::missing("size_constraint_set_id"))?,
+            updates: self
+                .updates
+                .ok_or(::rusoto_core::BuildError::missing("updates"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateSizeConstraintSetResponse {
@@ -2300,6 +7351,58 @@ pub struct UpdateSqlInjectionMatchSetRequest {
     pub updates: Vec<SqlInjectionMatchSetUpdate>,
 }
 
+impl UpdateSqlInjectionMatchSetRequest {
+    /// Returns a builder for `UpdateSqlInjectionMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSqlInjectionMatchSetRequestBuilder {
+        UpdateSqlInjectionMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSqlInjectionMatchSetRequest`](struct.UpdateSqlInjectionMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSqlInjectionMatchSetRequestBuilder {
+    change_token: Option<String>,
+    sql_injection_match_set_id: Option<String>,
+    updates: Option<Vec<SqlInjectionMatchSetUpdate>>,
+}
+
+impl UpdateSqlInjectionMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.UpdateSqlInjectionMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sql_injection_match_set_id`](struct.UpdateSqlInjectionMatchSetRequest.html#structfield.sql_injection_match_set_id) member.
+    pub fn sql_injection_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.sql_injection_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateSqlInjectionMatchSetRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<SqlInjectionMatchSetUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSqlInjectionMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSqlInjectionMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSqlInjectionMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            sql_injection_match_set_id: self.sql_injection_match_set_id.ok_or(
+                ::rusoto_core::BuildError::missing("sql_injection_match_set_id"),
+            )?,
+            updates: self
+                .updates
+                .ok_or(::rusoto_core::BuildError::missing("updates"))?,
+        })
+    }
+}
+
 /// <p>The response to an <a>UpdateSqlInjectionMatchSets</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2328,6 +7431,62 @@ pub struct UpdateWebACLRequest {
     pub web_acl_id: String,
 }
 
+impl UpdateWebACLRequest {
+    /// Returns a builder for `UpdateWebACLRequest`, checking its required members when built.
+    pub fn builder() -> UpdateWebACLRequestBuilder {
+        UpdateWebACLRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateWebACLRequest`](struct.UpdateWebACLRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateWebACLRequestBuilder {
+    change_token: Option<String>,
+    default_action: Option<WafAction>,
+    updates: Option<Vec<WebACLUpdate>>,
+    web_acl_id: Option<String>,
+}
+
+impl UpdateWebACLRequestBuilder {
+    /// Sets the [`change_token`](struct.UpdateWebACLRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_action`](struct.UpdateWebACLRequest.html#structfield.default_action) member.
+    pub fn default_action<T: Into<WafAction>>(mut self, value: T) -> Self {
+        self.default_action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateWebACLRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<WebACLUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`web_acl_id`](struct.UpdateWebACLRequest.html#structfield.web_acl_id) member.
+    pub fn web_acl_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.web_acl_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateWebACLRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateWebACLRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateWebACLRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            default_action: self.default_action,
+            updates: self.updates,
+            web_acl_id: self
+                .web_acl_id
+                .ok_or(::rusoto_core::BuildError::missing("web_acl_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateWebACLResponse {
@@ -2351,6 +7510,58 @@ pub struct UpdateXssMatchSetRequest {
     pub xss_match_set_id: String,
 }
 
+impl UpdateXssMatchSetRequest {
+    /// Returns a builder for `UpdateXssMatchSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateXssMatchSetRequestBuilder {
+        UpdateXssMatchSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateXssMatchSetRequest`](struct.UpdateXssMatchSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateXssMatchSetRequestBuilder {
+    change_token: Option<String>,
+    updates: Option<Vec<XssMatchSetUpdate>>,
+    xss_match_set_id: Option<String>,
+}
+
+impl UpdateXssMatchSetRequestBuilder {
+    /// Sets the [`change_token`](struct.UpdateXssMatchSetRequest.html#structfield.change_token) member.
+    pub fn change_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`updates`](struct.UpdateXssMatchSetRequest.html#structfield.updates) member.
+    pub fn updates<T: Into<Vec<XssMatchSetUpdate>>>(mut self, value: T) -> Self {
+        self.updates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`xss_match_set_id`](struct.UpdateXssMatchSetRequest.html#structfield.xss_match_set_id) member.
+    pub fn xss_match_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.xss_match_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateXssMatchSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateXssMatchSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateXssMatchSetRequest {
+            change_token: self
+                .change_token
+                .ok_or(::rusoto_core::BuildError::missing("change_token"))?,
+            updates: self
+                .updates
+                .ok_or(::rusoto_core::BuildError::missing("updates"))?,
+            xss_match_set_id: self
+                .xss_match_set_id
+                .ok_or(::rusoto_core::BuildError::missing("xss_match_set_id"))?,
+        })
+    }
+}
+
 /// <p>The response to an <a>UpdateXssMatchSets</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2362,40 +7573,264 @@ pub struct UpdateXssMatchSetResponse {
 }
 
 /// <p>For the action that is associated with a rule in a <code>WebACL</code>, specifies the action that you want AWS WAF to perform when a web request matches all of the conditions in a rule. For the default action in a <code>WebACL</code>, specifies the action that you want AWS WAF to take when a web request doesn't match all of the conditions in any of the rules in a <code>WebACL</code>. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WafAction {
     /// <p><p>Specifies how you want AWS WAF to respond to requests that match the settings in a <code>Rule</code>. Valid settings include the following:</p> <ul> <li> <p> <code>ALLOW</code>: AWS WAF allows requests</p> </li> <li> <p> <code>BLOCK</code>: AWS WAF blocks requests</p> </li> <li> <p> <code>COUNT</code>: AWS WAF increments a counter of the requests that match all of the conditions in the rule. AWS WAF then continues to inspect the web request based on the remaining rules in the web ACL. You can&#39;t specify <code>COUNT</code> for the default action for a <code>WebACL</code>.</p> </li> </ul></p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: WafActionType,
 }
 
-/// <p>The action to take if any rule within the <code>RuleGroup</code> matches a request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct WafOverrideAction {
-    /// <p> <code>COUNT</code> overrides the action specified by the individual rule within a <code>RuleGroup</code> . If set to <code>NONE</code>, the rule's action will take place.</p>
-    #[serde(rename = "Type")]
-    pub type_: String,
+/// <p><p>Specifies how you want AWS WAF to respond to requests that match the settings in a <code>Rule</code>. Valid settings include the following:</p> <ul> <li> <p> <code>ALLOW</code>: AWS WAF allows requests</p> </li> <li> <p> <code>BLOCK</code>: AWS WAF blocks requests</p> </li> <li> <p> <code>COUNT</code>: AWS WAF increments a counter of the requests that match all of the conditions in the rule. AWS WAF then continues to inspect the web request based on the remaining rules in the web ACL. You can&#39;t specify <code>COUNT</code> for the default action for a <code>WebACL</code>.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WafActionType {
+    /// <p><code>BLOCK</code> - AWS WAF blocks requests</p>
+    Block,
+    /// <p><code>ALLOW</code> - AWS WAF allows requests</p>
+    Allow,
+    /// <p><code>COUNT</code> - AWS WAF increments a counter of the requests that match all of the conditions in the rule. AWS WAF then continues to inspect the web request based on the remaining rules in the web ACL. You can&#39;t specify <code>COUNT</code> for the default action for a <code>WebACL</code>.</p>
+    Count,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Contains the <code>Rules</code> that identify the requests that you want to allow, block, or count. In a <code>WebACL</code>, you also specify a default action (<code>ALLOW</code> or <code>BLOCK</code>), and the action for each <code>Rule</code> that you add to a <code>WebACL</code>, for example, block requests from specified IP addresses or block requests from specified referrers. You also associate the <code>WebACL</code> with a CloudFront distribution to identify the requests that you want AWS WAF to filter. If you add more than one <code>Rule</code> to a <code>WebACL</code>, a request needs to match only one of the specifications to be allowed, blocked, or counted. For more information, see <a>UpdateWebACL</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct WebACL {
-    /// <p>The action to perform if none of the <code>Rules</code> contained in the <code>WebACL</code> match. The action is specified by the <a>WafAction</a> object.</p>
-    #[serde(rename = "DefaultAction")]
-    pub default_action: WafAction,
-    /// <p>A friendly name or description for the metrics for this <code>WebACL</code>. The name can contain only alphanumeric characters (A-Z, a-z, 0-9), with maximum length 128 and minimum length one. It can't contain whitespace or metric names reserved for AWS WAF, including "All" and "Default_Action." You can't change <code>MetricName</code> after you create the <code>WebACL</code>.</p>
-    #[serde(rename = "MetricName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metric_name: Option<String>,
-    /// <p>A friendly name or description of the <code>WebACL</code>. You can't change the name of a <code>WebACL</code> after you create it.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>An array that contains the action for each <code>Rule</code> in a <code>WebACL</code>, the priority of the <code>Rule</code>, and the ID of the <code>Rule</code>.</p>
-    #[serde(rename = "Rules")]
-    pub rules: Vec<ActivatedRule>,
-    /// <p>Tha Amazon Resource Name (ARN) of the web ACL.</p>
+impl WafActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WafActionType::Block => "BLOCK",
+            WafActionType::Allow => "ALLOW",
+            WafActionType::Count => "COUNT",
+            WafActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WafActionType {
+    fn from(value: &'a str) -> WafActionType {
+        match value {
+            "BLOCK" => WafActionType::Block,
+            "ALLOW" => WafActionType::Allow,
+            "COUNT" => WafActionType::Count,
+            _ => WafActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WafActionType {
+    fn from(value: String) -> WafActionType {
+        WafActionType::from(value.as_str())
+    }
+}
+
+impl From<WafActionType> for String {
+    fn from(value: WafActionType) -> String {
+        match value {
+            WafActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WafActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WafActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WafActionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WafActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WafActionType::from(value))
+    }
+}
+
+/// <p>The action to take if any rule within the <code>RuleGroup</code> matches a request. </p>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WafOverrideAction {
+    /// <p> <code>COUNT</code> overrides the action specified by the individual rule within a <code>RuleGroup</code> . If set to <code>NONE</code>, the rule's action will take place.</p>
+    #[serde(rename = "Type")]
+    pub type_: WafOverrideActionType,
+}
+
+/// <p> <code>COUNT</code> overrides the action specified by the individual rule within a <code>RuleGroup</code> . If set to <code>NONE</code>, the rule's action will take place.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WafOverrideActionType {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>COUNT</code></p>
+    Count,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WafOverrideActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WafOverrideActionType::None => "NONE",
+            WafOverrideActionType::Count => "COUNT",
+            WafOverrideActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WafOverrideActionType {
+    fn from(value: &'a str) -> WafOverrideActionType {
+        match value {
+            "NONE" => WafOverrideActionType::None,
+            "COUNT" => WafOverrideActionType::Count,
+            _ => WafOverrideActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WafOverrideActionType {
+    fn from(value: String) -> WafOverrideActionType {
+        WafOverrideActionType::from(value.as_str())
+    }
+}
+
+impl From<WafOverrideActionType> for String {
+    fn from(value: WafOverrideActionType) -> String {
+        match value {
+            WafOverrideActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WafOverrideActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WafOverrideActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WafOverrideActionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WafOverrideActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WafOverrideActionType::from(value))
+    }
+}
+
+/// <p>The rule type, either <code>REGULAR</code>, as defined by <a>Rule</a>, <code>RATE_BASED</code>, as defined by <a>RateBasedRule</a>, or <code>GROUP</code>, as defined by <a>RuleGroup</a>. The default is REGULAR. Although this field is optional, be aware that if you try to add a RATE_BASED rule to a web ACL without setting the type, the <a>UpdateWebACL</a> request will fail because the request tries to add a REGULAR rule with the specified ID, which does not exist. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WafRuleType {
+    /// <p><code>REGULAR</code></p>
+    Regular,
+    /// <p><code>RATE_BASED</code></p>
+    RateBased,
+    /// <p><code>GROUP</code></p>
+    Group,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WafRuleType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WafRuleType::Regular => "REGULAR",
+            WafRuleType::RateBased => "RATE_BASED",
+            WafRuleType::Group => "GROUP",
+            WafRuleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WafRuleType {
+    fn from(value: &'a str) -> WafRuleType {
+        match value {
+            "REGULAR" => WafRuleType::Regular,
+            "RATE_BASED" => WafRuleType::RateBased,
+            "GROUP" => WafRuleType::Group,
+            _ => WafRuleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WafRuleType {
+    fn from(value: String) -> WafRuleType {
+        WafRuleType::from(value.as_str())
+    }
+}
+
+impl From<WafRuleType> for String {
+    fn from(value: WafRuleType) -> String {
+        match value {
+            WafRuleType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WafRuleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WafRuleType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WafRuleType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WafRuleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WafRuleType::from(value))
+    }
+}
+
+/// <p>Contains the <code>Rules</code> that identify the requests that you want to allow, block, or count. In a <code>WebACL</code>, you also specify a default action (<code>ALLOW</code> or <code>BLOCK</code>), and the action for each <code>Rule</code> that you add to a <code>WebACL</code>, for example, block requests from specified IP addresses or block requests from specified referrers. You also associate the <code>WebACL</code> with a CloudFront distribution to identify the requests that you want AWS WAF to filter. If you add more than one <code>Rule</code> to a <code>WebACL</code>, a request needs to match only one of the specifications to be allowed, blocked, or counted. For more information, see <a>UpdateWebACL</a>.</p>
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct WebACL {
+    /// <p>The action to perform if none of the <code>Rules</code> contained in the <code>WebACL</code> match. The action is specified by the <a>WafAction</a> object.</p>
+    #[serde(rename = "DefaultAction")]
+    pub default_action: WafAction,
+    /// <p>A friendly name or description for the metrics for this <code>WebACL</code>. The name can contain only alphanumeric characters (A-Z, a-z, 0-9), with maximum length 128 and minimum length one. It can't contain whitespace or metric names reserved for AWS WAF, including "All" and "Default_Action." You can't change <code>MetricName</code> after you create the <code>WebACL</code>.</p>
+    #[serde(rename = "MetricName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric_name: Option<String>,
+    /// <p>A friendly name or description of the <code>WebACL</code>. You can't change the name of a <code>WebACL</code> after you create it.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>An array that contains the action for each <code>Rule</code> in a <code>WebACL</code>, the priority of the <code>Rule</code>, and the ID of the <code>Rule</code>.</p>
+    #[serde(rename = "Rules")]
+    pub rules: Vec<ActivatedRule>,
+    /// <p>Tha Amazon Resource Name (ARN) of the web ACL.</p>
     #[serde(rename = "WebACLArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub web_acl_arn: Option<String>,
@@ -2417,11 +7852,11 @@ pub struct WebACLSummary {
 }
 
 /// <p>Specifies whether to insert a <code>Rule</code> into or delete a <code>Rule</code> from a <code>WebACL</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct WebACLUpdate {
     /// <p>Specifies whether to insert a <code>Rule</code> into or delete a <code>Rule</code> from a <code>WebACL</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>The <code>ActivatedRule</code> object in an <a>UpdateWebACL</a> request specifies a <code>Rule</code> that you want to insert or delete, the priority of the <code>Rule</code> in the <code>WebACL</code>, and the action that you want AWS WAF to take when a web request matches the <code>Rule</code> (<code>ALLOW</code>, <code>BLOCK</code>, or <code>COUNT</code>).</p>
     #[serde(rename = "ActivatedRule")]
     pub activated_rule: ActivatedRule,
@@ -2456,25 +7891,25 @@ pub struct XssMatchSetSummary {
 }
 
 /// <p>Specifies the part of a web request that you want to inspect for cross-site scripting attacks and indicates whether you want to add the specification to an <a>XssMatchSet</a> or delete it from an <code>XssMatchSet</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct XssMatchSetUpdate {
     /// <p>Specify <code>INSERT</code> to add an <a>XssMatchSetUpdate</a> to an <a>XssMatchSet</a>. Use <code>DELETE</code> to remove an <code>XssMatchSetUpdate</code> from an <code>XssMatchSet</code>.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: ChangeAction,
     /// <p>Specifies the part of a web request that you want AWS WAF to inspect for cross-site scripting attacks and, if you want AWS WAF to inspect a header, the name of the header.</p>
     #[serde(rename = "XssMatchTuple")]
     pub xss_match_tuple: XssMatchTuple,
 }
 
 /// <p>Specifies the part of a web request that you want AWS WAF to inspect for cross-site scripting attacks and, if you want AWS WAF to inspect a header, the name of the header.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct XssMatchTuple {
     /// <p>Specifies where in a web request to look for cross-site scripting attacks.</p>
     #[serde(rename = "FieldToMatch")]
     pub field_to_match: FieldToMatch,
     /// <p>Text transformations eliminate some of the unusual formatting that attackers use in web requests in an effort to bypass AWS WAF. If you specify a transformation, AWS WAF performs the transformation on <code>FieldToMatch</code> before inspecting a request for a match.</p> <p>You can only specify a single type of TextTransformation.</p> <p> <b>CMD_LINE</b> </p> <p>When you're concerned that attackers are injecting an operating system command line command and using unusual formatting to disguise some or all of the command, use this option to perform the following transformations:</p> <ul> <li> <p>Delete the following characters: \ " ' ^</p> </li> <li> <p>Delete spaces before the following characters: / (</p> </li> <li> <p>Replace the following characters with a space: , ;</p> </li> <li> <p>Replace multiple spaces with one space</p> </li> <li> <p>Convert uppercase letters (A-Z) to lowercase (a-z)</p> </li> </ul> <p> <b>COMPRESS_WHITE_SPACE</b> </p> <p>Use this option to replace the following characters with a space character (decimal 32):</p> <ul> <li> <p>\f, formfeed, decimal 12</p> </li> <li> <p>\t, tab, decimal 9</p> </li> <li> <p>\n, newline, decimal 10</p> </li> <li> <p>\r, carriage return, decimal 13</p> </li> <li> <p>\v, vertical tab, decimal 11</p> </li> <li> <p>non-breaking space, decimal 160</p> </li> </ul> <p> <code>COMPRESS_WHITE_SPACE</code> also replaces multiple spaces with one space.</p> <p> <b>HTML_ENTITY_DECODE</b> </p> <p>Use this option to replace HTML-encoded characters with unencoded characters. <code>HTML_ENTITY_DECODE</code> performs the following operations:</p> <ul> <li> <p>Replaces <code>(ampersand)quot;</code> with <code>"</code> </p> </li> <li> <p>Replaces <code>(ampersand)nbsp;</code> with a non-breaking space, decimal 160</p> </li> <li> <p>Replaces <code>(ampersand)lt;</code> with a "less than" symbol</p> </li> <li> <p>Replaces <code>(ampersand)gt;</code> with <code>&gt;</code> </p> </li> <li> <p>Replaces characters that are represented in hexadecimal format, <code>(ampersand)#xhhhh;</code>, with the corresponding characters</p> </li> <li> <p>Replaces characters that are represented in decimal format, <code>(ampersand)#nnnn;</code>, with the corresponding characters</p> </li> </ul> <p> <b>LOWERCASE</b> </p> <p>Use this option to convert uppercase letters (A-Z) to lowercase (a-z).</p> <p> <b>URL_DECODE</b> </p> <p>Use this option to decode a URL-encoded value.</p> <p> <b>NONE</b> </p> <p>Specify <code>NONE</code> if you don't want to perform any text transformations.</p>
     #[serde(rename = "TextTransformation")]
-    pub text_transformation: String,
+    pub text_transformation: TextTransformation,
 }
 
 /// Errors returned by AssociateWebACL
@@ -7822,40 +13257,589 @@ pub trait WAFRegional {
         input: UpdateXssMatchSetRequest,
     ) -> RusotoFuture<UpdateXssMatchSetResponse, UpdateXssMatchSetError>;
 }
-/// A client for the WAF Regional API.
-#[derive(Clone)]
-pub struct WAFRegionalClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl WAFRegionalClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> WAFRegionalClient {
-        Self::new_with_client(Client::shared(), region)
+impl<T: ?Sized + WAFRegional> WAFRegional for ::std::sync::Arc<T> {
+    fn associate_web_acl(
+        &self,
+        input: AssociateWebACLRequest,
+    ) -> RusotoFuture<AssociateWebACLResponse, AssociateWebACLError> {
+        (**self).associate_web_acl(input)
     }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> WAFRegionalClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
+    fn create_byte_match_set(
+        &self,
+        input: CreateByteMatchSetRequest,
+    ) -> RusotoFuture<CreateByteMatchSetResponse, CreateByteMatchSetError> {
+        (**self).create_byte_match_set(input)
     }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> WAFRegionalClient {
-        WAFRegionalClient { client, region }
+    fn create_geo_match_set(
+        &self,
+        input: CreateGeoMatchSetRequest,
+    ) -> RusotoFuture<CreateGeoMatchSetResponse, CreateGeoMatchSetError> {
+        (**self).create_geo_match_set(input)
+    }
+    fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
+        (**self).create_ip_set(input)
+    }
+    fn create_rate_based_rule(
+        &self,
+        input: CreateRateBasedRuleRequest,
+    ) -> RusotoFuture<CreateRateBasedRuleResponse, CreateRateBasedRuleError> {
+        (**self).create_rate_based_rule(input)
+    }
+    fn create_regex_match_set(
+        &self,
+        input: CreateRegexMatchSetRequest,
+    ) -> RusotoFuture<CreateRegexMatchSetResponse, CreateRegexMatchSetError> {
+        (**self).create_regex_match_set(input)
+    }
+    fn create_regex_pattern_set(
+        &self,
+        input: CreateRegexPatternSetRequest,
+    ) -> RusotoFuture<CreateRegexPatternSetResponse, CreateRegexPatternSetError> {
+        (**self).create_regex_pattern_set(input)
+    }
+    fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> RusotoFuture<CreateRuleResponse, CreateRuleError> {
+        (**self).create_rule(input)
+    }
+    fn create_rule_group(
+        &self,
+        input: CreateRuleGroupRequest,
+    ) -> RusotoFuture<CreateRuleGroupResponse, CreateRuleGroupError> {
+        (**self).create_rule_group(input)
+    }
+    fn create_size_constraint_set(
+        &self,
+        input: CreateSizeConstraintSetRequest,
+    ) -> RusotoFuture<CreateSizeConstraintSetResponse, CreateSizeConstraintSetError> {
+        (**self).create_size_constraint_set(input)
+    }
+    fn create_sql_injection_match_set(
+        &self,
+        input: CreateSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<CreateSqlInjectionMatchSetResponse, CreateSqlInjectionMatchSetError> {
+        (**self).create_sql_injection_match_set(input)
+    }
+    fn create_web_acl(
+        &self,
+        input: CreateWebACLRequest,
+    ) -> RusotoFuture<CreateWebACLResponse, CreateWebACLError> {
+        (**self).create_web_acl(input)
+    }
+    fn create_xss_match_set(
+        &self,
+        input: CreateXssMatchSetRequest,
+    ) -> RusotoFuture<CreateXssMatchSetResponse, CreateXssMatchSetError> {
+        (**self).create_xss_match_set(input)
+    }
+    fn delete_byte_match_set(
+        &self,
+        input: DeleteByteMatchSetRequest,
+    ) -> RusotoFuture<DeleteByteMatchSetResponse, DeleteByteMatchSetError> {
+        (**self).delete_byte_match_set(input)
+    }
+    fn delete_geo_match_set(
+        &self,
+        input: DeleteGeoMatchSetRequest,
+    ) -> RusotoFuture<DeleteGeoMatchSetResponse, DeleteGeoMatchSetError> {
+        (**self).delete_geo_match_set(input)
+    }
+    fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
+        (**self).delete_ip_set(input)
+    }
+    fn delete_logging_configuration(
+        &self,
+        input: DeleteLoggingConfigurationRequest,
+    ) -> RusotoFuture<DeleteLoggingConfigurationResponse, DeleteLoggingConfigurationError> {
+        (**self).delete_logging_configuration(input)
+    }
+    fn delete_permission_policy(
+        &self,
+        input: DeletePermissionPolicyRequest,
+    ) -> RusotoFuture<DeletePermissionPolicyResponse, DeletePermissionPolicyError> {
+        (**self).delete_permission_policy(input)
+    }
+    fn delete_rate_based_rule(
+        &self,
+        input: DeleteRateBasedRuleRequest,
+    ) -> RusotoFuture<DeleteRateBasedRuleResponse, DeleteRateBasedRuleError> {
+        (**self).delete_rate_based_rule(input)
+    }
+    fn delete_regex_match_set(
+        &self,
+        input: DeleteRegexMatchSetRequest,
+    ) -> RusotoFuture<DeleteRegexMatchSetResponse, DeleteRegexMatchSetError> {
+        (**self).delete_regex_match_set(input)
+    }
+    fn delete_regex_pattern_set(
+        &self,
+        input: DeleteRegexPatternSetRequest,
+    ) -> RusotoFuture<DeleteRegexPatternSetResponse, DeleteRegexPatternSetError> {
+        (**self).delete_regex_pattern_set(input)
+    }
+    fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> RusotoFuture<DeleteRuleResponse, DeleteRuleError> {
+        (**self).delete_rule(input)
+    }
+    fn delete_rule_group(
+        &self,
+        input: DeleteRuleGroupRequest,
+    ) -> RusotoFuture<DeleteRuleGroupResponse, DeleteRuleGroupError> {
+        (**self).delete_rule_group(input)
+    }
+    fn delete_size_constraint_set(
+        &self,
+        input: DeleteSizeConstraintSetRequest,
+    ) -> RusotoFuture<DeleteSizeConstraintSetResponse, DeleteSizeConstraintSetError> {
+        (**self).delete_size_constraint_set(input)
+    }
+    fn delete_sql_injection_match_set(
+        &self,
+        input: DeleteSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<DeleteSqlInjectionMatchSetResponse, DeleteSqlInjectionMatchSetError> {
+        (**self).delete_sql_injection_match_set(input)
+    }
+    fn delete_web_acl(
+        &self,
+        input: DeleteWebACLRequest,
+    ) -> RusotoFuture<DeleteWebACLResponse, DeleteWebACLError> {
+        (**self).delete_web_acl(input)
+    }
+    fn delete_xss_match_set(
+        &self,
+        input: DeleteXssMatchSetRequest,
+    ) -> RusotoFuture<DeleteXssMatchSetResponse, DeleteXssMatchSetError> {
+        (**self).delete_xss_match_set(input)
+    }
+    fn disassociate_web_acl(
+        &self,
+        input: DisassociateWebACLRequest,
+    ) -> RusotoFuture<DisassociateWebACLResponse, DisassociateWebACLError> {
+        (**self).disassociate_web_acl(input)
+    }
+    fn get_byte_match_set(
+        &self,
+        input: GetByteMatchSetRequest,
+    ) -> RusotoFuture<GetByteMatchSetResponse, GetByteMatchSetError> {
+        (**self).get_byte_match_set(input)
+    }
+    fn get_change_token(&self) -> RusotoFuture<GetChangeTokenResponse, GetChangeTokenError> {
+        (**self).get_change_token()
+    }
+    fn get_change_token_status(
+        &self,
+        input: GetChangeTokenStatusRequest,
+    ) -> RusotoFuture<GetChangeTokenStatusResponse, GetChangeTokenStatusError> {
+        (**self).get_change_token_status(input)
+    }
+    fn get_geo_match_set(
+        &self,
+        input: GetGeoMatchSetRequest,
+    ) -> RusotoFuture<GetGeoMatchSetResponse, GetGeoMatchSetError> {
+        (**self).get_geo_match_set(input)
+    }
+    fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
+        (**self).get_ip_set(input)
+    }
+    fn get_logging_configuration(
+        &self,
+        input: GetLoggingConfigurationRequest,
+    ) -> RusotoFuture<GetLoggingConfigurationResponse, GetLoggingConfigurationError> {
+        (**self).get_logging_configuration(input)
+    }
+    fn get_permission_policy(
+        &self,
+        input: GetPermissionPolicyRequest,
+    ) -> RusotoFuture<GetPermissionPolicyResponse, GetPermissionPolicyError> {
+        (**self).get_permission_policy(input)
+    }
+    fn get_rate_based_rule(
+        &self,
+        input: GetRateBasedRuleRequest,
+    ) -> RusotoFuture<GetRateBasedRuleResponse, GetRateBasedRuleError> {
+        (**self).get_rate_based_rule(input)
+    }
+    fn get_rate_based_rule_managed_keys(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> RusotoFuture<GetRateBasedRuleManagedKeysResponse, GetRateBasedRuleManagedKeysError> {
+        (**self).get_rate_based_rule_managed_keys(input)
+    }
+    fn get_regex_match_set(
+        &self,
+        input: GetRegexMatchSetRequest,
+    ) -> RusotoFuture<GetRegexMatchSetResponse, GetRegexMatchSetError> {
+        (**self).get_regex_match_set(input)
+    }
+    fn get_regex_pattern_set(
+        &self,
+        input: GetRegexPatternSetRequest,
+    ) -> RusotoFuture<GetRegexPatternSetResponse, GetRegexPatternSetError> {
+        (**self).get_regex_pattern_set(input)
+    }
+    fn get_rule(&self, input: GetRuleRequest) -> RusotoFuture<GetRuleResponse, GetRuleError> {
+        (**self).get_rule(input)
+    }
+    fn get_rule_group(
+        &self,
+        input: GetRuleGroupRequest,
+    ) -> RusotoFuture<GetRuleGroupResponse, GetRuleGroupError> {
+        (**self).get_rule_group(input)
+    }
+    fn get_sampled_requests(
+        &self,
+        input: GetSampledRequestsRequest,
+    ) -> RusotoFuture<GetSampledRequestsResponse, GetSampledRequestsError> {
+        (**self).get_sampled_requests(input)
+    }
+    fn get_size_constraint_set(
+        &self,
+        input: GetSizeConstraintSetRequest,
+    ) -> RusotoFuture<GetSizeConstraintSetResponse, GetSizeConstraintSetError> {
+        (**self).get_size_constraint_set(input)
+    }
+    fn get_sql_injection_match_set(
+        &self,
+        input: GetSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<GetSqlInjectionMatchSetResponse, GetSqlInjectionMatchSetError> {
+        (**self).get_sql_injection_match_set(input)
+    }
+    fn get_web_acl(
+        &self,
+        input: GetWebACLRequest,
+    ) -> RusotoFuture<GetWebACLResponse, GetWebACLError> {
+        (**self).get_web_acl(input)
+    }
+    fn get_web_acl_for_resource(
+        &self,
+        input: GetWebACLForResourceRequest,
+    ) -> RusotoFuture<GetWebACLForResourceResponse, GetWebACLForResourceError> {
+        (**self).get_web_acl_for_resource(input)
+    }
+    fn get_xss_match_set(
+        &self,
+        input: GetXssMatchSetRequest,
+    ) -> RusotoFuture<GetXssMatchSetResponse, GetXssMatchSetError> {
+        (**self).get_xss_match_set(input)
+    }
+    fn list_activated_rules_in_rule_group(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> RusotoFuture<ListActivatedRulesInRuleGroupResponse, ListActivatedRulesInRuleGroupError>
+    {
+        (**self).list_activated_rules_in_rule_group(input)
+    }
+    fn list_byte_match_sets(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> RusotoFuture<ListByteMatchSetsResponse, ListByteMatchSetsError> {
+        (**self).list_byte_match_sets(input)
+    }
+    fn list_geo_match_sets(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> RusotoFuture<ListGeoMatchSetsResponse, ListGeoMatchSetsError> {
+        (**self).list_geo_match_sets(input)
+    }
+    fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
+        (**self).list_ip_sets(input)
+    }
+    fn list_logging_configurations(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> RusotoFuture<ListLoggingConfigurationsResponse, ListLoggingConfigurationsError> {
+        (**self).list_logging_configurations(input)
+    }
+    fn list_rate_based_rules(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> RusotoFuture<ListRateBasedRulesResponse, ListRateBasedRulesError> {
+        (**self).list_rate_based_rules(input)
+    }
+    fn list_regex_match_sets(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> RusotoFuture<ListRegexMatchSetsResponse, ListRegexMatchSetsError> {
+        (**self).list_regex_match_sets(input)
+    }
+    fn list_regex_pattern_sets(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> RusotoFuture<ListRegexPatternSetsResponse, ListRegexPatternSetsError> {
+        (**self).list_regex_pattern_sets(input)
+    }
+    fn list_resources_for_web_acl(
+        &self,
+        input: ListResourcesForWebACLRequest,
+    ) -> RusotoFuture<ListResourcesForWebACLResponse, ListResourcesForWebACLError> {
+        (**self).list_resources_for_web_acl(input)
+    }
+    fn list_rule_groups(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> RusotoFuture<ListRuleGroupsResponse, ListRuleGroupsError> {
+        (**self).list_rule_groups(input)
+    }
+    fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
+        (**self).list_rules(input)
+    }
+    fn list_size_constraint_sets(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> RusotoFuture<ListSizeConstraintSetsResponse, ListSizeConstraintSetsError> {
+        (**self).list_size_constraint_sets(input)
+    }
+    fn list_sql_injection_match_sets(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> RusotoFuture<ListSqlInjectionMatchSetsResponse, ListSqlInjectionMatchSetsError> {
+        (**self).list_sql_injection_match_sets(input)
+    }
+    fn list_subscribed_rule_groups(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> RusotoFuture<ListSubscribedRuleGroupsResponse, ListSubscribedRuleGroupsError> {
+        (**self).list_subscribed_rule_groups(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_web_ac_ls(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> RusotoFuture<ListWebACLsResponse, ListWebACLsError> {
+        (**self).list_web_ac_ls(input)
+    }
+    fn list_xss_match_sets(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> RusotoFuture<ListXssMatchSetsResponse, ListXssMatchSetsError> {
+        (**self).list_xss_match_sets(input)
+    }
+    fn put_logging_configuration(
+        &self,
+        input: PutLoggingConfigurationRequest,
+    ) -> RusotoFuture<PutLoggingConfigurationResponse, PutLoggingConfigurationError> {
+        (**self).put_logging_configuration(input)
+    }
+    fn put_permission_policy(
+        &self,
+        input: PutPermissionPolicyRequest,
+    ) -> RusotoFuture<PutPermissionPolicyResponse, PutPermissionPolicyError> {
+        (**self).put_permission_policy(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_byte_match_set(
+        &self,
+        input: UpdateByteMatchSetRequest,
+    ) -> RusotoFuture<UpdateByteMatchSetResponse, UpdateByteMatchSetError> {
+        (**self).update_byte_match_set(input)
+    }
+    fn update_geo_match_set(
+        &self,
+        input: UpdateGeoMatchSetRequest,
+    ) -> RusotoFuture<UpdateGeoMatchSetResponse, UpdateGeoMatchSetError> {
+        (**self).update_geo_match_set(input)
+    }
+    fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
+        (**self).update_ip_set(input)
+    }
+    fn update_rate_based_rule(
+        &self,
+        input: UpdateRateBasedRuleRequest,
+    ) -> RusotoFuture<UpdateRateBasedRuleResponse, UpdateRateBasedRuleError> {
+        (**self).update_rate_based_rule(input)
+    }
+    fn update_regex_match_set(
+        &self,
+        input: UpdateRegexMatchSetRequest,
+    ) -> RusotoFuture<UpdateRegexMatchSetResponse, UpdateRegexMatchSetError> {
+        (**self).update_regex_match_set(input)
+    }
+    fn update_regex_pattern_set(
+        &self,
+        input: UpdateRegexPatternSetRequest,
+    ) -> RusotoFuture<UpdateRegexPatternSetResponse, UpdateRegexPatternSetError> {
+        (**self).update_regex_pattern_set(input)
+    }
+    fn update_rule(
+        &self,
+        input: UpdateRuleRequest,
+    ) -> RusotoFuture<UpdateRuleResponse, UpdateRuleError> {
+        (**self).update_rule(input)
+    }
+    fn update_rule_group(
+        &self,
+        input: UpdateRuleGroupRequest,
+    ) -> RusotoFuture<UpdateRuleGroupResponse, UpdateRuleGroupError> {
+        (**self).update_rule_group(input)
+    }
+    fn update_size_constraint_set(
+        &self,
+        input: UpdateSizeConstraintSetRequest,
+    ) -> RusotoFuture<UpdateSizeConstraintSetResponse, UpdateSizeConstraintSetError> {
+        (**self).update_size_constraint_set(input)
+    }
+    fn update_sql_injection_match_set(
+        &self,
+        input: UpdateSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<UpdateSqlInjectionMatchSetResponse, UpdateSqlInjectionMatchSetError> {
+        (**self).update_sql_injection_match_set(input)
+    }
+    fn update_web_acl(
+        &self,
+        input: UpdateWebACLRequest,
+    ) -> RusotoFuture<UpdateWebACLResponse, UpdateWebACLError> {
+        (**self).update_web_acl(input)
+    }
+    fn update_xss_match_set(
+        &self,
+        input: UpdateXssMatchSetRequest,
+    ) -> RusotoFuture<UpdateXssMatchSetResponse, UpdateXssMatchSetError> {
+        (**self).update_xss_match_set(input)
+    }
+}
+/// The error codes WAF Regional retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
+/// A client for the WAF Regional API.
+#[derive(Clone)]
+pub struct WAFRegionalClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl WAFRegionalClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> WAFRegionalClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_WAF_REGIONAL`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> WAFRegionalClient {
+        Self::new(region::Region::from_env_for_service("WAF_REGIONAL"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `waf-regional`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> WAFRegionalClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("waf-regional", "WAF_REGIONAL"),
+        )
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WAFRegionalClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> WAFRegionalClient {
+        WAFRegionalClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "waf-regional")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "waf-regional",
+            "waf-regional",
+            "WAF_REGIONAL",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `WAFRegional` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn WAFRegional + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for WAFRegionalClient {
+    fn service_name(&self) -> &'static str {
+        "WAF Regional"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "waf-regional"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> WAFRegionalClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS WAF clients.
+//!
+//! Makes a single read-only `ListActivatedRulesInRuleGroup` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_waf::{ListActivatedRulesInRuleGroupRequest, Waf, WafClient};
+
+fn main() {
+    let client = WafClient::from_env();
+    match client
+        .list_activated_rules_in_rule_group(ListActivatedRulesInRuleGroupRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListActivatedRulesInRuleGroup succeeded"),
+        Err(error) => {
+            eprintln!("ListActivatedRulesInRuleGroup failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -1 +1,5 @@
+/// Building rule conditions and syncing the rules of web ACLs
+pub mod rules;
 
+#[cfg(test)]
+mod custom_tests;
@@ -0,0 +1,471 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the template in
+//  service_crategen/templates/waf/rules.rs must be updated.
+//
+// =================================================================
+
+//! Building rule conditions and keeping web ACLs in sync with a list of rules.
+//!
+//! The match conditions and activated rules of AWS WAF are loosely typed structs, where most
+//! members are strings which only accept a handful of values. The helpers here build them from
+//! the enums generated for those values and check the limits AWS WAF would otherwise reject
+//! requests for.
+//!
+//! `WebAclRulesExt::sync_web_acl_rules` compares the rules of a deployed web ACL with the rules
+//! it should have, and sends the `UpdateWebACL` request turning one into the other.
+//!
+//! The same helpers are generated for `rusoto_waf`, whose web ACLs protect CloudFront
+//! distributions, and for `rusoto_waf_regional`, whose web ACLs protect regional resources such
+//! as Application Load Balancers.
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+use bytes::Bytes;
+use futures::future::{self, Either};
+use futures::Future;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    ActivatedRule, ByteMatchSetUpdate, ByteMatchTuple, ChangeAction, ExcludedRule, FieldToMatch,
+    GeoMatchConstraint, GeoMatchConstraintType, GeoMatchConstraintValue, GeoMatchSetUpdate,
+    GetChangeTokenError, GetWebACLError, GetWebACLRequest, MatchFieldType, PositionalConstraint,
+    Predicate, PredicateType, RuleUpdate, TextTransformation, UpdateWebACLError,
+    UpdateWebACLRequest, Waf, WafAction, WafActionType, WafOverrideAction, WafOverrideActionType,
+    WafRuleType, WebACL, WebACLUpdate,
+};
+
+/// The longest target string of a byte match, in bytes.
+pub const MAX_TARGET_STRING_LENGTH: usize = 50;
+
+/// The lowest rate limit of a rate-based rule.
+pub const MIN_RATE_LIMIT: i64 = 100;
+
+/// The highest rate limit of a rate-based rule.
+pub const MAX_RATE_LIMIT: i64 = 2_000_000_000;
+
+/// Why a condition or a list of rules is invalid.
+#[derive(Debug, PartialEq)]
+pub enum RuleError {
+    /// The target string of a byte match is empty or longer than `MAX_TARGET_STRING_LENGTH`.
+    InvalidTargetString(usize),
+    /// The rate limit is outside of `MIN_RATE_LIMIT` and `MAX_RATE_LIMIT`.
+    InvalidRateLimit(i64),
+    /// Several rules of a web ACL have the same priority.
+    DuplicatePriority(i64),
+    /// A rule was added to a web ACL more than once.
+    DuplicateRule(String),
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RuleError::InvalidTargetString(length) => write!(
+                f,
+                "Target strings must be 1 to {} bytes long, not {}",
+                MAX_TARGET_STRING_LENGTH, length
+            ),
+            RuleError::InvalidRateLimit(limit) => write!(
+                f,
+                "Rate limits must be between {} and {}, not {}",
+                MIN_RATE_LIMIT, MAX_RATE_LIMIT, limit
+            ),
+            RuleError::DuplicatePriority(priority) => {
+                write!(f, "Several rules have priority {}", priority)
+            }
+            RuleError::DuplicateRule(ref rule_id) => {
+                write!(f, "Rule {} is activated more than once", rule_id)
+            }
+        }
+    }
+}
+
+impl Error for RuleError {
+    fn description(&self) -> &str {
+        match *self {
+            RuleError::InvalidTargetString(_) => "invalid target string",
+            RuleError::InvalidRateLimit(_) => "invalid rate limit",
+            RuleError::DuplicatePriority(_) => "duplicate rule priority",
+            RuleError::DuplicateRule(_) => "duplicate rule",
+        }
+    }
+}
+
+/// The part of a web request a condition inspects.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Field {
+    /// The path of the request
+    Uri,
+    /// The whole query string
+    QueryString,
+    /// The HTTP method
+    Method,
+    /// The first 8192 bytes of the body
+    Body,
+    /// The values of every query parameter
+    AllQueryArgs,
+    /// The header with the given name
+    Header(String),
+    /// The query parameter with the given name
+    QueryArg(String),
+}
+
+impl From<Field> for FieldToMatch {
+    fn from(field: Field) -> FieldToMatch {
+        let (type_, data) = match field {
+            Field::Uri => (MatchFieldType::Uri, None),
+            Field::QueryString => (MatchFieldType::QueryString, None),
+            Field::Method => (MatchFieldType::Method, None),
+            Field::Body => (MatchFieldType::Body, None),
+            Field::AllQueryArgs => (MatchFieldType::AllQueryArgs, None),
+            Field::Header(name) => (MatchFieldType::Header, Some(name)),
+            Field::QueryArg(name) => (MatchFieldType::SingleQueryArg, Some(name)),
+        };
+        FieldToMatch { type_, data }
+    }
+}
+
+/// Builds a `ByteMatchTuple`, matching a field of web requests against a string.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusoto_waf::rules::{ByteMatch, Field};
+/// use rusoto_waf::{ChangeAction, MatchFieldType, PositionalConstraint, TextTransformation};
+///
+/// let update = ByteMatch::new(
+///     Field::Header("User-Agent".to_owned()),
+///     PositionalConstraint::Contains,
+///     "badbot",
+/// )
+/// .transformation(TextTransformation::Lowercase)
+/// .update(ChangeAction::Insert)
+/// .unwrap();
+/// assert_eq!(
+///     update.byte_match_tuple.field_to_match.type_,
+///     MatchFieldType::Header
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ByteMatch {
+    tuple: ByteMatchTuple,
+}
+
+impl ByteMatch {
+    /// Matches `field` against `target` without transforming the field first.
+    pub fn new<T: Into<Bytes>>(
+        field: Field,
+        positional_constraint: PositionalConstraint,
+        target: T,
+    ) -> ByteMatch {
+        ByteMatch {
+            tuple: ByteMatchTuple {
+                field_to_match: field.into(),
+                positional_constraint,
+                target_string: target.into(),
+                text_transformation: TextTransformation::None,
+            },
+        }
+    }
+
+    /// Transforms the field, e.g. by lowercasing it, before matching it.
+    pub fn transformation(mut self, transformation: TextTransformation) -> ByteMatch {
+        self.tuple.text_transformation = transformation;
+        self
+    }
+
+    /// Returns the tuple, failing if the target string is too long or empty.
+    pub fn build(self) -> Result<ByteMatchTuple, RuleError> {
+        let length = self.tuple.target_string.len();
+        if length == 0 || length > MAX_TARGET_STRING_LENGTH {
+            return Err(RuleError::InvalidTargetString(length));
+        }
+        Ok(self.tuple)
+    }
+
+    /// Returns the update inserting the tuple into or deleting it from a byte match set.
+    pub fn update(self, action: ChangeAction) -> Result<ByteMatchSetUpdate, RuleError> {
+        Ok(ByteMatchSetUpdate {
+            action,
+            byte_match_tuple: self.build()?,
+        })
+    }
+}
+
+/// Returns the updates inserting countries into or deleting them from a geo match set.
+pub fn geo_match_updates(
+    action: ChangeAction,
+    countries: &[GeoMatchConstraintValue],
+) -> Vec<GeoMatchSetUpdate> {
+    countries
+        .iter()
+        .map(|country| GeoMatchSetUpdate {
+            action: action.clone(),
+            geo_match_constraint: GeoMatchConstraint {
+                type_: GeoMatchConstraintType::Country,
+                value: country.clone(),
+            },
+        })
+        .collect()
+}
+
+/// Returns the update adding a condition to or removing it from a rule.
+///
+/// With `negated`, the rule matches requests which don't match the condition.
+pub fn predicate_update(
+    action: ChangeAction,
+    predicate_type: PredicateType,
+    data_id: &str,
+    negated: bool,
+) -> RuleUpdate {
+    RuleUpdate {
+        action,
+        predicate: Predicate {
+            data_id: data_id.to_owned(),
+            negated,
+            type_: predicate_type,
+        },
+    }
+}
+
+/// Checks the rate limit of a rate-based rule, before creating or updating it.
+pub fn validate_rate_limit(rate_limit: i64) -> Result<i64, RuleError> {
+    if rate_limit < MIN_RATE_LIMIT || rate_limit > MAX_RATE_LIMIT {
+        return Err(RuleError::InvalidRateLimit(rate_limit));
+    }
+    Ok(rate_limit)
+}
+
+/// Activates a regular rule in a web ACL.
+pub fn regular_rule(rule_id: &str, priority: i64, action: WafActionType) -> ActivatedRule {
+    activated_rule(rule_id, priority, WafRuleType::Regular, action)
+}
+
+/// Activates a rate-based rule in a web ACL.
+pub fn rate_based_rule(rule_id: &str, priority: i64, action: WafActionType) -> ActivatedRule {
+    activated_rule(rule_id, priority, WafRuleType::RateBased, action)
+}
+
+/// Activates a rule group, such as a managed rule group from AWS Marketplace, in a web ACL.
+///
+/// The rules of the group take their own actions, unless `override_action` is
+/// `WafOverrideActionType::Count`. The rules in `excluded_rule_ids` only count matching
+/// requests.
+pub fn rule_group(
+    rule_group_id: &str,
+    priority: i64,
+    override_action: WafOverrideActionType,
+    excluded_rule_ids: &[&str],
+) -> ActivatedRule {
+    ActivatedRule {
+        rule_id: rule_group_id.to_owned(),
+        priority,
+        type_: Some(WafRuleType::Group),
+        override_action: Some(WafOverrideAction {
+            type_: override_action,
+        }),
+        excluded_rules: if excluded_rule_ids.is_empty() {
+            None
+        } else {
+            Some(
+                excluded_rule_ids
+                    .iter()
+                    .map(|rule_id| ExcludedRule {
+                        rule_id: (*rule_id).to_owned(),
+                    })
+                    .collect(),
+            )
+        },
+        action: None,
+    }
+}
+
+fn activated_rule(
+    rule_id: &str,
+    priority: i64,
+    rule_type: WafRuleType,
+    action: WafActionType,
+) -> ActivatedRule {
+    ActivatedRule {
+        rule_id: rule_id.to_owned(),
+        priority,
+        type_: Some(rule_type),
+        action: Some(WafAction { type_: action }),
+        ..Default::default()
+    }
+}
+
+/// Checks that no two rules share a priority or activate the same rule.
+pub fn validate_rules(rules: &[ActivatedRule]) -> Result<(), RuleError> {
+    let mut priorities = HashSet::new();
+    let mut rule_ids = HashSet::new();
+    for rule in rules {
+        if !priorities.insert(rule.priority) {
+            return Err(RuleError::DuplicatePriority(rule.priority));
+        }
+        if !rule_ids.insert(&rule.rule_id) {
+            return Err(RuleError::DuplicateRule(rule.rule_id.clone()));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the updates turning the rules of `web_acl` into `rules`.
+///
+/// Rules are compared as a whole, so changing the priority or action of a rule deletes it and
+/// inserts it again. Deletions come first, which frees the priorities of deleted rules for the
+/// inserted ones.
+pub fn web_acl_updates(web_acl: &WebACL, rules: &[ActivatedRule]) -> Vec<WebACLUpdate> {
+    rule_updates(&web_acl.rules, rules)
+}
+
+fn rule_updates(deployed: &[ActivatedRule], desired: &[ActivatedRule]) -> Vec<WebACLUpdate> {
+    let deployed: Vec<ActivatedRule> = deployed.iter().map(normalize).collect();
+    let desired: Vec<ActivatedRule> = desired.iter().map(normalize).collect();
+
+    let deletions = deployed
+        .iter()
+        .filter(|rule| !desired.contains(rule))
+        .map(|rule| (ChangeAction::Delete, rule));
+    let insertions = desired
+        .iter()
+        .filter(|rule| !deployed.contains(rule))
+        .map(|rule| (ChangeAction::Insert, rule));
+
+    deletions
+        .chain(insertions)
+        .map(|(action, rule)| WebACLUpdate {
+            action,
+            activated_rule: rule.clone(),
+        })
+        .collect()
+}
+
+/// Fills in the defaults AWS WAF leaves out, so equal rules compare equal.
+fn normalize(rule: &ActivatedRule) -> ActivatedRule {
+    let mut rule = rule.clone();
+    if rule.type_.is_none() {
+        rule.type_ = Some(WafRuleType::Regular);
+    }
+    if rule.excluded_rules.as_ref().map_or(false, Vec::is_empty) {
+        rule.excluded_rules = None;
+    }
+    rule
+}
+
+/// Errors returned while syncing the rules of a web ACL.
+#[derive(Debug, PartialEq)]
+pub enum WebAclSyncError {
+    /// The rules are invalid.
+    InvalidRules(RuleError),
+    /// Reading the web ACL failed.
+    GetWebACL(GetWebACLError),
+    /// Getting a change token failed.
+    GetChangeToken(GetChangeTokenError),
+    /// Updating the web ACL failed.
+    UpdateWebACL(UpdateWebACLError),
+}
+
+impl fmt::Display for WebAclSyncError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WebAclSyncError::InvalidRules(ref cause) => write!(f, "{}", cause),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for WebAclSyncError {
+    fn description(&self) -> &str {
+        match *self {
+            WebAclSyncError::InvalidRules(ref cause) => cause.description(),
+            WebAclSyncError::GetWebACL(ref cause) => cause.description(),
+            WebAclSyncError::GetChangeToken(ref cause) => cause.description(),
+            WebAclSyncError::UpdateWebACL(ref cause) => cause.description(),
+        }
+    }
+}
+
+/// Extension methods keeping web ACLs in sync with a list of rules.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_waf::rules::{rate_based_rule, regular_rule, rule_group, WebAclRulesExt};
+/// use rusoto_waf::{WafActionType, WafClient, WafOverrideActionType};
+///
+/// let client = WafClient::new(Region::UsEast1);
+/// let rules = vec![
+///     regular_rule("block-bad-bots", 1, WafActionType::Block),
+///     rate_based_rule("throttle-logins", 2, WafActionType::Block),
+///     rule_group("managed-owasp", 3, WafOverrideActionType::None, &[]),
+/// ];
+/// let updates = client
+///     .sync_web_acl_rules("web-acl-id", rules)
+///     .sync()
+///     .unwrap();
+/// println!("{} rules changed", updates.len());
+/// ```
+pub trait WebAclRulesExt: Waf + Clone + Send + Sized + 'static {
+    /// Updates the web ACL so that it activates exactly `rules`, and returns the updates sent.
+    ///
+    /// Nothing is sent if the web ACL already has these rules.
+    fn sync_web_acl_rules(
+        &self,
+        web_acl_id: &str,
+        rules: Vec<ActivatedRule>,
+    ) -> RusotoFuture<Vec<WebACLUpdate>, WebAclSyncError> {
+        if let Err(e) = validate_rules(&rules) {
+            return RusotoFuture::from_future(future::err(RusotoError::Service(
+                WebAclSyncError::InvalidRules(e),
+            )));
+        }
+
+        let client = self.clone();
+        let web_acl_id = web_acl_id.to_owned();
+        let future = self
+            .get_web_acl(GetWebACLRequest {
+                web_acl_id: web_acl_id.clone(),
+            })
+            .map_err(|e| e.map_service(WebAclSyncError::GetWebACL))
+            .and_then(move |output| {
+                let updates = match output.web_acl {
+                    Some(web_acl) => web_acl_updates(&web_acl, &rules),
+                    None => rule_updates(&[], &rules),
+                };
+                if updates.is_empty() {
+                    return Either::A(future::ok(updates));
+                }
+
+                let update_client = client.clone();
+                Either::B(
+                    client
+                        .get_change_token()
+                        .map_err(|e| e.map_service(WebAclSyncError::GetChangeToken))
+                        .and_then(move |output| {
+                            update_client
+                                .update_web_acl(UpdateWebACLRequest {
+                                    change_token: output.change_token.unwrap_or_default(),
+                                    default_action: None,
+                                    updates: Some(updates.clone()),
+                                    web_acl_id,
+                                })
+                                .map(move |_| updates)
+                                .map_err(|e| e.map_service(WebAclSyncError::UpdateWebACL))
+                        }),
+                )
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: Waf + Clone + Send + 'static> WebAclRulesExt for T {}
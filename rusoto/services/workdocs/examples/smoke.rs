@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon WorkDocs clients.
+//!
+//! Makes a single read-only `DescribeActivities` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_workdocs::{DescribeActivitiesRequest, Workdocs, WorkdocsClient};
+
+fn main() {
+    let client = WorkdocsClient::from_env();
+    match client
+        .describe_activities(DescribeActivitiesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeActivities succeeded"),
+        Err(error) => {
+            eprintln!("DescribeActivities failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AbortDocumentVersionUploadRequest {
@@ -38,6 +39,56 @@ pub struct AbortDocumentVersionUploadRequest {
     pub version_id: String,
 }
 
+impl AbortDocumentVersionUploadRequest {
+    /// Returns a builder for `AbortDocumentVersionUploadRequest`, checking its required members when built.
+    pub fn builder() -> AbortDocumentVersionUploadRequestBuilder {
+        AbortDocumentVersionUploadRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AbortDocumentVersionUploadRequest`](struct.AbortDocumentVersionUploadRequest.html).
+#[derive(Default, Debug)]
+pub struct AbortDocumentVersionUploadRequestBuilder {
+    authentication_token: Option<String>,
+    document_id: Option<String>,
+    version_id: Option<String>,
+}
+
+impl AbortDocumentVersionUploadRequestBuilder {
+    /// Sets the [`authentication_token`](struct.AbortDocumentVersionUploadRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.AbortDocumentVersionUploadRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.AbortDocumentVersionUploadRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AbortDocumentVersionUploadRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AbortDocumentVersionUploadRequest, ::rusoto_core::BuildError> {
+        Ok(AbortDocumentVersionUploadRequest {
+            authentication_token: self.authentication_token,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+            version_id: self
+                .version_id
+                .ok_or(::rusoto_core::BuildError::missing("version_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ActivateUserRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
@@ -49,6 +100,44 @@ pub struct ActivateUserRequest {
     pub user_id: String,
 }
 
+impl ActivateUserRequest {
+    /// Returns a builder for `ActivateUserRequest`, checking its required members when built.
+    pub fn builder() -> ActivateUserRequestBuilder {
+        ActivateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ActivateUserRequest`](struct.ActivateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct ActivateUserRequestBuilder {
+    authentication_token: Option<String>,
+    user_id: Option<String>,
+}
+
+impl ActivateUserRequestBuilder {
+    /// Sets the [`authentication_token`](struct.ActivateUserRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.ActivateUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ActivateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ActivateUserRequest, ::rusoto_core::BuildError> {
+        Ok(ActivateUserRequest {
+            authentication_token: self.authentication_token,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ActivateUserResponse {
@@ -92,12 +181,223 @@ pub struct Activity {
     pub resource_metadata: Option<ResourceMetadata>,
     /// <p>The timestamp when the action was performed.</p>
     #[serde(rename = "TimeStamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_stamp: Option<f64>,
+    pub time_stamp: Option<EpochTimestamp>,
     /// <p>The activity type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ActivityType>,
+}
+
+/// <p>The activity type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActivityType {
+    /// <p><code>DOCUMENT_CHECKED_IN</code></p>
+    DocumentCheckedIn,
+    /// <p><code>DOCUMENT_CHECKED_OUT</code></p>
+    DocumentCheckedOut,
+    /// <p><code>DOCUMENT_RENAMED</code></p>
+    DocumentRenamed,
+    /// <p><code>DOCUMENT_VERSION_UPLOADED</code></p>
+    DocumentVersionUploaded,
+    /// <p><code>DOCUMENT_VERSION_DELETED</code></p>
+    DocumentVersionDeleted,
+    /// <p><code>DOCUMENT_VERSION_VIEWED</code></p>
+    DocumentVersionViewed,
+    /// <p><code>DOCUMENT_VERSION_DOWNLOADED</code></p>
+    DocumentVersionDownloaded,
+    /// <p><code>DOCUMENT_RECYCLED</code></p>
+    DocumentRecycled,
+    /// <p><code>DOCUMENT_RESTORED</code></p>
+    DocumentRestored,
+    /// <p><code>DOCUMENT_REVERTED</code></p>
+    DocumentReverted,
+    /// <p><code>DOCUMENT_SHARED</code></p>
+    DocumentShared,
+    /// <p><code>DOCUMENT_UNSHARED</code></p>
+    DocumentUnshared,
+    /// <p><code>DOCUMENT_SHARE_PERMISSION_CHANGED</code></p>
+    DocumentSharePermissionChanged,
+    /// <p><code>DOCUMENT_SHAREABLE_LINK_CREATED</code></p>
+    DocumentShareableLinkCreated,
+    /// <p><code>DOCUMENT_SHAREABLE_LINK_REMOVED</code></p>
+    DocumentShareableLinkRemoved,
+    /// <p><code>DOCUMENT_SHAREABLE_LINK_PERMISSION_CHANGED</code></p>
+    DocumentShareableLinkPermissionChanged,
+    /// <p><code>DOCUMENT_MOVED</code></p>
+    DocumentMoved,
+    /// <p><code>DOCUMENT_COMMENT_ADDED</code></p>
+    DocumentCommentAdded,
+    /// <p><code>DOCUMENT_COMMENT_DELETED</code></p>
+    DocumentCommentDeleted,
+    /// <p><code>DOCUMENT_ANNOTATION_ADDED</code></p>
+    DocumentAnnotationAdded,
+    /// <p><code>DOCUMENT_ANNOTATION_DELETED</code></p>
+    DocumentAnnotationDeleted,
+    /// <p><code>FOLDER_CREATED</code></p>
+    FolderCreated,
+    /// <p><code>FOLDER_DELETED</code></p>
+    FolderDeleted,
+    /// <p><code>FOLDER_RENAMED</code></p>
+    FolderRenamed,
+    /// <p><code>FOLDER_RECYCLED</code></p>
+    FolderRecycled,
+    /// <p><code>FOLDER_RESTORED</code></p>
+    FolderRestored,
+    /// <p><code>FOLDER_SHARED</code></p>
+    FolderShared,
+    /// <p><code>FOLDER_UNSHARED</code></p>
+    FolderUnshared,
+    /// <p><code>FOLDER_SHARE_PERMISSION_CHANGED</code></p>
+    FolderSharePermissionChanged,
+    /// <p><code>FOLDER_SHAREABLE_LINK_CREATED</code></p>
+    FolderShareableLinkCreated,
+    /// <p><code>FOLDER_SHAREABLE_LINK_REMOVED</code></p>
+    FolderShareableLinkRemoved,
+    /// <p><code>FOLDER_SHAREABLE_LINK_PERMISSION_CHANGED</code></p>
+    FolderShareableLinkPermissionChanged,
+    /// <p><code>FOLDER_MOVED</code></p>
+    FolderMoved,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActivityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActivityType::DocumentCheckedIn => "DOCUMENT_CHECKED_IN",
+            ActivityType::DocumentCheckedOut => "DOCUMENT_CHECKED_OUT",
+            ActivityType::DocumentRenamed => "DOCUMENT_RENAMED",
+            ActivityType::DocumentVersionUploaded => "DOCUMENT_VERSION_UPLOADED",
+            ActivityType::DocumentVersionDeleted => "DOCUMENT_VERSION_DELETED",
+            ActivityType::DocumentVersionViewed => "DOCUMENT_VERSION_VIEWED",
+            ActivityType::DocumentVersionDownloaded => "DOCUMENT_VERSION_DOWNLOADED",
+            ActivityType::DocumentRecycled => "DOCUMENT_RECYCLED",
+            ActivityType::DocumentRestored => "DOCUMENT_RESTORED",
+            ActivityType::DocumentReverted => "DOCUMENT_REVERTED",
+            ActivityType::DocumentShared => "DOCUMENT_SHARED",
+            ActivityType::DocumentUnshared => "DOCUMENT_UNSHARED",
+            ActivityType::DocumentSharePermissionChanged => "DOCUMENT_SHARE_PERMISSION_CHANGED",
+            ActivityType::DocumentShareableLinkCreated => "DOCUMENT_SHAREABLE_LINK_CREATED",
+            ActivityType::DocumentShareableLinkRemoved => "DOCUMENT_SHAREABLE_LINK_REMOVED",
+            ActivityType::DocumentShareableLinkPermissionChanged => {
+                "DOCUMENT_SHAREABLE_LINK_PERMISSION_CHANGED"
+            }
+            ActivityType::DocumentMoved => "DOCUMENT_MOVED",
+            ActivityType::DocumentCommentAdded => "DOCUMENT_COMMENT_ADDED",
+            ActivityType::DocumentCommentDeleted => "DOCUMENT_COMMENT_DELETED",
+            ActivityType::DocumentAnnotationAdded => "DOCUMENT_ANNOTATION_ADDED",
+            ActivityType::DocumentAnnotationDeleted => "DOCUMENT_ANNOTATION_DELETED",
+            ActivityType::FolderCreated => "FOLDER_CREATED",
+            ActivityType::FolderDeleted => "FOLDER_DELETED",
+            ActivityType::FolderRenamed => "FOLDER_RENAMED",
+            ActivityType::FolderRecycled => "FOLDER_RECYCLED",
+            ActivityType::FolderRestored => "FOLDER_RESTORED",
+            ActivityType::FolderShared => "FOLDER_SHARED",
+            ActivityType::FolderUnshared => "FOLDER_UNSHARED",
+            ActivityType::FolderSharePermissionChanged => "FOLDER_SHARE_PERMISSION_CHANGED",
+            ActivityType::FolderShareableLinkCreated => "FOLDER_SHAREABLE_LINK_CREATED",
+            ActivityType::FolderShareableLinkRemoved => "FOLDER_SHAREABLE_LINK_REMOVED",
+            ActivityType::FolderShareableLinkPermissionChanged => {
+                "FOLDER_SHAREABLE_LINK_PERMISSION_CHANGED"
+            }
+            ActivityType::FolderMoved => "FOLDER_MOVED",
+            ActivityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActivityType {
+    fn from(value: &'a str) -> ActivityType {
+        match value {
+            "DOCUMENT_CHECKED_IN" => ActivityType::DocumentCheckedIn,
+            "DOCUMENT_CHECKED_OUT" => ActivityType::DocumentCheckedOut,
+            "DOCUMENT_RENAMED" => ActivityType::DocumentRenamed,
+            "DOCUMENT_VERSION_UPLOADED" => ActivityType::DocumentVersionUploaded,
+            "DOCUMENT_VERSION_DELETED" => ActivityType::DocumentVersionDeleted,
+            "DOCUMENT_VERSION_VIEWED" => ActivityType::DocumentVersionViewed,
+            "DOCUMENT_VERSION_DOWNLOADED" => ActivityType::DocumentVersionDownloaded,
+            "DOCUMENT_RECYCLED" => ActivityType::DocumentRecycled,
+            "DOCUMENT_RESTORED" => ActivityType::DocumentRestored,
+            "DOCUMENT_REVERTED" => ActivityType::DocumentReverted,
+            "DOCUMENT_SHARED" => ActivityType::DocumentShared,
+            "DOCUMENT_UNSHARED" => ActivityType::DocumentUnshared,
+            "DOCUMENT_SHARE_PERMISSION_CHANGED" => ActivityType::DocumentSharePermissionChanged,
+            "DOCUMENT_SHAREABLE_LINK_CREATED" => ActivityType::DocumentShareableLinkCreated,
+            "DOCUMENT_SHAREABLE_LINK_REMOVED" => ActivityType::DocumentShareableLinkRemoved,
+            "DOCUMENT_SHAREABLE_LINK_PERMISSION_CHANGED" => {
+                ActivityType::DocumentShareableLinkPermissionChanged
+            }
+            "DOCUMENT_MOVED" => ActivityType::DocumentMoved,
+            "DOCUMENT_COMMENT_ADDED" => ActivityType::DocumentCommentAdded,
+            "DOCUMENT_COMMENT_DELETED" => ActivityType::DocumentCommentDeleted,
+            "DOCUMENT_ANNOTATION_ADDED" => ActivityType::DocumentAnnotationAdded,
+            "DOCUMENT_ANNOTATION_DELETED" => ActivityType::DocumentAnnotationDeleted,
+            "FOLDER_CREATED" => ActivityType::FolderCreated,
+            "FOLDER_DELETED" => ActivityType::FolderDeleted,
+            "FOLDER_RENAMED" => ActivityType::FolderRenamed,
+            "FOLDER_RECYCLED" => ActivityType::FolderRecycled,
+            "FOLDER_RESTORED" => ActivityType::FolderRestored,
+            "FOLDER_SHARED" => ActivityType::FolderShared,
+            "FOLDER_UNSHARED" => ActivityType::FolderUnshared,
+            "FOLDER_SHARE_PERMISSION_CHANGED" => ActivityType::FolderSharePermissionChanged,
+            "FOLDER_SHAREABLE_LINK_CREATED" => ActivityType::FolderShareableLinkCreated,
+            "FOLDER_SHAREABLE_LINK_REMOVED" => ActivityType::FolderShareableLinkRemoved,
+            "FOLDER_SHAREABLE_LINK_PERMISSION_CHANGED" => {
+                ActivityType::FolderShareableLinkPermissionChanged
+            }
+            "FOLDER_MOVED" => ActivityType::FolderMoved,
+            _ => ActivityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActivityType {
+    fn from(value: String) -> ActivityType {
+        ActivityType::from(value.as_str())
+    }
+}
+
+impl From<ActivityType> for String {
+    fn from(value: ActivityType) -> String {
+        match value {
+            ActivityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActivityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActivityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActivityType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActivityType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ActivityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ActivityType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -118,6 +418,64 @@ pub struct AddResourcePermissionsRequest {
     pub resource_id: String,
 }
 
+impl AddResourcePermissionsRequest {
+    /// Returns a builder for `AddResourcePermissionsRequest`, checking its required members when built.
+    pub fn builder() -> AddResourcePermissionsRequestBuilder {
+        AddResourcePermissionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddResourcePermissionsRequest`](struct.AddResourcePermissionsRequest.html).
+#[derive(Default, Debug)]
+pub struct AddResourcePermissionsRequestBuilder {
+    authentication_token: Option<String>,
+    notification_options: Option<NotificationOptions>,
+    principals: Option<Vec<SharePrincipal>>,
+    resource_id: Option<String>,
+}
+
+impl AddResourcePermissionsRequestBuilder {
+    /// Sets the [`authentication_token`](struct.AddResourcePermissionsRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_options`](struct.AddResourcePermissionsRequest.html#structfield.notification_options) member.
+    pub fn notification_options<T: Into<NotificationOptions>>(mut self, value: T) -> Self {
+        self.notification_options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principals`](struct.AddResourcePermissionsRequest.html#structfield.principals) member.
+    pub fn principals<T: Into<Vec<SharePrincipal>>>(mut self, value: T) -> Self {
+        self.principals = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.AddResourcePermissionsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddResourcePermissionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddResourcePermissionsRequest, ::rusoto_core::BuildError> {
+        Ok(AddResourcePermissionsRequest {
+            authentication_token: self.authentication_token,
+            notification_options: self.notification_options,
+            principals: self
+                .principals
+                .ok_or(::rusoto_core::BuildError::missing("principals"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddResourcePermissionsResponse {
@@ -127,6 +485,84 @@ pub struct AddResourcePermissionsResponse {
     pub share_results: Option<Vec<ShareResult>>,
 }
 
+/// <p>Boolean value to determine whether the user is granted Poweruser privileges.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BooleanEnumType {
+    /// <p><code>TRUE</code></p>
+    True,
+    /// <p><code>FALSE</code></p>
+    False,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BooleanEnumType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BooleanEnumType::True => "TRUE",
+            BooleanEnumType::False => "FALSE",
+            BooleanEnumType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BooleanEnumType {
+    fn from(value: &'a str) -> BooleanEnumType {
+        match value {
+            "TRUE" => BooleanEnumType::True,
+            "FALSE" => BooleanEnumType::False,
+            _ => BooleanEnumType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BooleanEnumType {
+    fn from(value: String) -> BooleanEnumType {
+        BooleanEnumType::from(value.as_str())
+    }
+}
+
+impl From<BooleanEnumType> for String {
+    fn from(value: BooleanEnumType) -> String {
+        match value {
+            BooleanEnumType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BooleanEnumType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BooleanEnumType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BooleanEnumType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BooleanEnumType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BooleanEnumType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BooleanEnumType::from(value))
+    }
+}
+
 /// <p>Describes a comment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -140,8 +576,9 @@ pub struct Comment {
     pub contributor: Option<User>,
     /// <p>The time that the comment was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The ID of the parent comment.</p>
     #[serde(rename = "ParentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -153,7 +590,7 @@ pub struct Comment {
     /// <p>The status of the comment.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CommentStatusType>,
     /// <p>The text of the comment.</p>
     #[serde(rename = "Text")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -165,7 +602,7 @@ pub struct Comment {
     /// <p>The visibility of the comment. Options are either PRIVATE, where the comment is visible only to the comment author and document owner and co-owners, or PUBLIC, where the comment is visible to document owners, co-owners, and contributors.</p>
     #[serde(rename = "Visibility")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub visibility: Option<String>,
+    pub visibility: Option<CommentVisibilityType>,
 }
 
 /// <p>Describes the metadata of a comment.</p>
@@ -179,21 +616,182 @@ pub struct CommentMetadata {
     /// <p>The status of the comment.</p>
     #[serde(rename = "CommentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment_status: Option<String>,
+    pub comment_status: Option<CommentStatusType>,
     /// <p>The user who made the comment.</p>
     #[serde(rename = "Contributor")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub contributor: Option<User>,
     /// <p>The timestamp that the comment was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The ID of the user being replied to.</p>
     #[serde(rename = "RecipientId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recipient_id: Option<String>,
 }
 
+/// <p>The status of the comment.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CommentStatusType {
+    /// <p><code>DRAFT</code></p>
+    Draft,
+    /// <p><code>PUBLISHED</code></p>
+    Published,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CommentStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CommentStatusType::Draft => "DRAFT",
+            CommentStatusType::Published => "PUBLISHED",
+            CommentStatusType::Deleted => "DELETED",
+            CommentStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CommentStatusType {
+    fn from(value: &'a str) -> CommentStatusType {
+        match value {
+            "DRAFT" => CommentStatusType::Draft,
+            "PUBLISHED" => CommentStatusType::Published,
+            "DELETED" => CommentStatusType::Deleted,
+            _ => CommentStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CommentStatusType {
+    fn from(value: String) -> CommentStatusType {
+        CommentStatusType::from(value.as_str())
+    }
+}
+
+impl From<CommentStatusType> for String {
+    fn from(value: CommentStatusType) -> String {
+        match value {
+            CommentStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CommentStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CommentStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CommentStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CommentStatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CommentStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CommentStatusType::from(value))
+    }
+}
+
+/// <p>The visibility of the comment. Options are either PRIVATE, where the comment is visible only to the comment author and document owner and co-owners, or PUBLIC, where the comment is visible to document owners, co-owners, and contributors.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CommentVisibilityType {
+    /// <p><code>PUBLIC</code></p>
+    Public,
+    /// <p><code>PRIVATE</code></p>
+    Private,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CommentVisibilityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CommentVisibilityType::Public => "PUBLIC",
+            CommentVisibilityType::Private => "PRIVATE",
+            CommentVisibilityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CommentVisibilityType {
+    fn from(value: &'a str) -> CommentVisibilityType {
+        match value {
+            "PUBLIC" => CommentVisibilityType::Public,
+            "PRIVATE" => CommentVisibilityType::Private,
+            _ => CommentVisibilityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CommentVisibilityType {
+    fn from(value: String) -> CommentVisibilityType {
+        CommentVisibilityType::from(value.as_str())
+    }
+}
+
+impl From<CommentVisibilityType> for String {
+    fn from(value: CommentVisibilityType) -> String {
+        match value {
+            CommentVisibilityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CommentVisibilityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CommentVisibilityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CommentVisibilityType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CommentVisibilityType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CommentVisibilityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CommentVisibilityType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateCommentRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
@@ -224,7 +822,97 @@ pub struct CreateCommentRequest {
     /// <p>The visibility of the comment. Options are either PRIVATE, where the comment is visible only to the comment author and document owner and co-owners, or PUBLIC, where the comment is visible to document owners, co-owners, and contributors.</p>
     #[serde(rename = "Visibility")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub visibility: Option<String>,
+    pub visibility: Option<CommentVisibilityType>,
+}
+
+impl CreateCommentRequest {
+    /// Returns a builder for `CreateCommentRequest`, checking its required members when built.
+    pub fn builder() -> CreateCommentRequestBuilder {
+        CreateCommentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCommentRequest`](struct.CreateCommentRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCommentRequestBuilder {
+    authentication_token: Option<String>,
+    document_id: Option<String>,
+    notify_collaborators: Option<bool>,
+    parent_id: Option<String>,
+    text: Option<String>,
+    thread_id: Option<String>,
+    version_id: Option<String>,
+    visibility: Option<CommentVisibilityType>,
+}
+
+impl CreateCommentRequestBuilder {
+    /// Sets the [`authentication_token`](struct.CreateCommentRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.CreateCommentRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notify_collaborators`](struct.CreateCommentRequest.html#structfield.notify_collaborators) member.
+    pub fn notify_collaborators<T: Into<bool>>(mut self, value: T) -> Self {
+        self.notify_collaborators = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_id`](struct.CreateCommentRequest.html#structfield.parent_id) member.
+    pub fn parent_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text`](struct.CreateCommentRequest.html#structfield.text) member.
+    pub fn text<T: Into<String>>(mut self, value: T) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thread_id`](struct.CreateCommentRequest.html#structfield.thread_id) member.
+    pub fn thread_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.thread_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.CreateCommentRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`visibility`](struct.CreateCommentRequest.html#structfield.visibility) member.
+    pub fn visibility<T: Into<CommentVisibilityType>>(mut self, value: T) -> Self {
+        self.visibility = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCommentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateCommentRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCommentRequest {
+            authentication_token: self.authentication_token,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+            notify_collaborators: self.notify_collaborators,
+            parent_id: self.parent_id,
+            text: self
+                .text
+                .ok_or(::rusoto_core::BuildError::missing("text"))?,
+            thread_id: self.thread_id,
+            version_id: self
+                .version_id
+                .ok_or(::rusoto_core::BuildError::missing("version_id"))?,
+            visibility: self.visibility,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -254,6 +942,67 @@ pub struct CreateCustomMetadataRequest {
     pub version_id: Option<String>,
 }
 
+impl CreateCustomMetadataRequest {
+    /// Returns a builder for `CreateCustomMetadataRequest`, checking its required members when built.
+    pub fn builder() -> CreateCustomMetadataRequestBuilder {
+        CreateCustomMetadataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCustomMetadataRequest`](struct.CreateCustomMetadataRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCustomMetadataRequestBuilder {
+    authentication_token: Option<String>,
+    custom_metadata: Option<::std::collections::HashMap<String, String>>,
+    resource_id: Option<String>,
+    version_id: Option<String>,
+}
+
+impl CreateCustomMetadataRequestBuilder {
+    /// Sets the [`authentication_token`](struct.CreateCustomMetadataRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_metadata`](struct.CreateCustomMetadataRequest.html#structfield.custom_metadata) member.
+    pub fn custom_metadata<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.custom_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.CreateCustomMetadataRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.CreateCustomMetadataRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCustomMetadataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCustomMetadataRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCustomMetadataRequest {
+            authentication_token: self.authentication_token,
+            custom_metadata: self
+                .custom_metadata
+                .ok_or(::rusoto_core::BuildError::missing("custom_metadata"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            version_id: self.version_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCustomMetadataResponse {}
@@ -273,6 +1022,52 @@ pub struct CreateFolderRequest {
     pub parent_folder_id: String,
 }
 
+impl CreateFolderRequest {
+    /// Returns a builder for `CreateFolderRequest`, checking its required members when built.
+    pub fn builder() -> CreateFolderRequestBuilder {
+        CreateFolderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFolderRequest`](struct.CreateFolderRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFolderRequestBuilder {
+    authentication_token: Option<String>,
+    name: Option<String>,
+    parent_folder_id: Option<String>,
+}
+
+impl CreateFolderRequestBuilder {
+    /// Sets the [`authentication_token`](struct.CreateFolderRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateFolderRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_folder_id`](struct.CreateFolderRequest.html#structfield.parent_folder_id) member.
+    pub fn parent_folder_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_folder_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFolderRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateFolderRequest, ::rusoto_core::BuildError> {
+        Ok(CreateFolderRequest {
+            authentication_token: self.authentication_token,
+            name: self.name,
+            parent_folder_id: self
+                .parent_folder_id
+                .ok_or(::rusoto_core::BuildError::missing("parent_folder_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateFolderResponse {
@@ -296,11 +1091,59 @@ pub struct CreateLabelsRequest {
     pub resource_id: String,
 }
 
+impl CreateLabelsRequest {
+    /// Returns a builder for `CreateLabelsRequest`, checking its required members when built.
+    pub fn builder() -> CreateLabelsRequestBuilder {
+        CreateLabelsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLabelsRequest`](struct.CreateLabelsRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLabelsRequestBuilder {
+    authentication_token: Option<String>,
+    labels: Option<Vec<String>>,
+    resource_id: Option<String>,
+}
+
+impl CreateLabelsRequestBuilder {
+    /// Sets the [`authentication_token`](struct.CreateLabelsRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`labels`](struct.CreateLabelsRequest.html#structfield.labels) member.
+    pub fn labels<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.labels = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.CreateLabelsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLabelsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateLabelsRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLabelsRequest {
+            authentication_token: self.authentication_token,
+            labels: self
+                .labels
+                .ok_or(::rusoto_core::BuildError::missing("labels"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateLabelsResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateNotificationSubscriptionRequest {
     /// <p>The endpoint to receive the notifications. If the protocol is HTTPS, the endpoint is a URL that begins with <code>https</code>.</p>
     #[serde(rename = "Endpoint")]
@@ -310,14 +1153,77 @@ pub struct CreateNotificationSubscriptionRequest {
     pub organization_id: String,
     /// <p>The protocol to use. The supported value is https, which delivers JSON-encoded messages using HTTPS POST.</p>
     #[serde(rename = "Protocol")]
-    pub protocol: String,
+    pub protocol: SubscriptionProtocolType,
     /// <p>The notification type.</p>
     #[serde(rename = "SubscriptionType")]
-    pub subscription_type: String,
+    pub subscription_type: SubscriptionType,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl CreateNotificationSubscriptionRequest {
+    /// Returns a builder for `CreateNotificationSubscriptionRequest`, checking its required members when built.
+    pub fn builder() -> CreateNotificationSubscriptionRequestBuilder {
+        CreateNotificationSubscriptionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateNotificationSubscriptionRequest`](struct.CreateNotificationSubscriptionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateNotificationSubscriptionRequestBuilder {
+    endpoint: Option<String>,
+    organization_id: Option<String>,
+    protocol: Option<SubscriptionProtocolType>,
+    subscription_type: Option<SubscriptionType>,
+}
+
+impl CreateNotificationSubscriptionRequestBuilder {
+    /// Sets the [`endpoint`](struct.CreateNotificationSubscriptionRequest.html#structfield.endpoint) member.
+    pub fn endpoint<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.CreateNotificationSubscriptionRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`protocol`](struct.CreateNotificationSubscriptionRequest.html#structfield.protocol) member.
+    pub fn protocol<T: Into<SubscriptionProtocolType>>(mut self, value: T) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_type`](struct.CreateNotificationSubscriptionRequest.html#structfield.subscription_type) member.
+    pub fn subscription_type<T: Into<SubscriptionType>>(mut self, value: T) -> Self {
+        self.subscription_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateNotificationSubscriptionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateNotificationSubscriptionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateNotificationSubscriptionRequest {
+            endpoint: self
+                .endpoint
+                .ok_or(::rusoto_core::BuildError::missing("endpoint"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            protocol: self
+                .protocol
+                .ok_or(::rusoto_core::BuildError::missing("protocol"))?,
+            subscription_type: self
+                .subscription_type
+                .ok_or(::rusoto_core::BuildError::missing("subscription_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
 pub struct CreateNotificationSubscriptionResponse {
     /// <p>The subscription.</p>
     #[serde(rename = "Subscription")]
@@ -361,6 +1267,106 @@ pub struct CreateUserRequest {
     pub username: String,
 }
 
+impl CreateUserRequest {
+    /// Returns a builder for `CreateUserRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserRequestBuilder {
+        CreateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserRequest`](struct.CreateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserRequestBuilder {
+    authentication_token: Option<String>,
+    email_address: Option<String>,
+    given_name: Option<String>,
+    organization_id: Option<String>,
+    password: Option<String>,
+    storage_rule: Option<StorageRuleType>,
+    surname: Option<String>,
+    time_zone_id: Option<String>,
+    username: Option<String>,
+}
+
+impl CreateUserRequestBuilder {
+    /// Sets the [`authentication_token`](struct.CreateUserRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email_address`](struct.CreateUserRequest.html#structfield.email_address) member.
+    pub fn email_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`given_name`](struct.CreateUserRequest.html#structfield.given_name) member.
+    pub fn given_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.given_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.CreateUserRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.CreateUserRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_rule`](struct.CreateUserRequest.html#structfield.storage_rule) member.
+    pub fn storage_rule<T: Into<StorageRuleType>>(mut self, value: T) -> Self {
+        self.storage_rule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`surname`](struct.CreateUserRequest.html#structfield.surname) member.
+    pub fn surname<T: Into<String>>(mut self, value: T) -> Self {
+        self.surname = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_zone_id`](struct.CreateUserRequest.html#structfield.time_zone_id) member.
+    pub fn time_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.time_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.CreateUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateUserRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserRequest {
+            authentication_token: self.authentication_token,
+            email_address: self.email_address,
+            given_name: self
+                .given_name
+                .ok_or(::rusoto_core::BuildError::missing("given_name"))?,
+            organization_id: self.organization_id,
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+            storage_rule: self.storage_rule,
+            surname: self
+                .surname
+                .ok_or(::rusoto_core::BuildError::missing("surname"))?,
+            time_zone_id: self.time_zone_id,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateUserResponse {
@@ -381,6 +1387,44 @@ pub struct DeactivateUserRequest {
     pub user_id: String,
 }
 
+impl DeactivateUserRequest {
+    /// Returns a builder for `DeactivateUserRequest`, checking its required members when built.
+    pub fn builder() -> DeactivateUserRequestBuilder {
+        DeactivateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeactivateUserRequest`](struct.DeactivateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DeactivateUserRequestBuilder {
+    authentication_token: Option<String>,
+    user_id: Option<String>,
+}
+
+impl DeactivateUserRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DeactivateUserRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.DeactivateUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeactivateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeactivateUserRequest, ::rusoto_core::BuildError> {
+        Ok(DeactivateUserRequest {
+            authentication_token: self.authentication_token,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteCommentRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
@@ -398,6 +1442,64 @@ pub struct DeleteCommentRequest {
     pub version_id: String,
 }
 
+impl DeleteCommentRequest {
+    /// Returns a builder for `DeleteCommentRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCommentRequestBuilder {
+        DeleteCommentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCommentRequest`](struct.DeleteCommentRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCommentRequestBuilder {
+    authentication_token: Option<String>,
+    comment_id: Option<String>,
+    document_id: Option<String>,
+    version_id: Option<String>,
+}
+
+impl DeleteCommentRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DeleteCommentRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`comment_id`](struct.DeleteCommentRequest.html#structfield.comment_id) member.
+    pub fn comment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.DeleteCommentRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.DeleteCommentRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCommentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteCommentRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteCommentRequest {
+            authentication_token: self.authentication_token,
+            comment_id: self
+                .comment_id
+                .ok_or(::rusoto_core::BuildError::missing("comment_id"))?,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+            version_id: self
+                .version_id
+                .ok_or(::rusoto_core::BuildError::missing("version_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteCustomMetadataRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
@@ -421,6 +1523,70 @@ pub struct DeleteCustomMetadataRequest {
     pub version_id: Option<String>,
 }
 
+impl DeleteCustomMetadataRequest {
+    /// Returns a builder for `DeleteCustomMetadataRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCustomMetadataRequestBuilder {
+        DeleteCustomMetadataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCustomMetadataRequest`](struct.DeleteCustomMetadataRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCustomMetadataRequestBuilder {
+    authentication_token: Option<String>,
+    delete_all: Option<bool>,
+    keys: Option<Vec<String>>,
+    resource_id: Option<String>,
+    version_id: Option<String>,
+}
+
+impl DeleteCustomMetadataRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DeleteCustomMetadataRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_all`](struct.DeleteCustomMetadataRequest.html#structfield.delete_all) member.
+    pub fn delete_all<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_all = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keys`](struct.DeleteCustomMetadataRequest.html#structfield.keys) member.
+    pub fn keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DeleteCustomMetadataRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.DeleteCustomMetadataRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCustomMetadataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCustomMetadataRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteCustomMetadataRequest {
+            authentication_token: self.authentication_token,
+            delete_all: self.delete_all,
+            keys: self.keys,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            version_id: self.version_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteCustomMetadataResponse {}
@@ -436,6 +1602,44 @@ pub struct DeleteDocumentRequest {
     pub document_id: String,
 }
 
+impl DeleteDocumentRequest {
+    /// Returns a builder for `DeleteDocumentRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDocumentRequestBuilder {
+        DeleteDocumentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDocumentRequest`](struct.DeleteDocumentRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDocumentRequestBuilder {
+    authentication_token: Option<String>,
+    document_id: Option<String>,
+}
+
+impl DeleteDocumentRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DeleteDocumentRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.DeleteDocumentRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDocumentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDocumentRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDocumentRequest {
+            authentication_token: self.authentication_token,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteFolderContentsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
@@ -447,6 +1651,46 @@ pub struct DeleteFolderContentsRequest {
     pub folder_id: String,
 }
 
+impl DeleteFolderContentsRequest {
+    /// Returns a builder for `DeleteFolderContentsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFolderContentsRequestBuilder {
+        DeleteFolderContentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFolderContentsRequest`](struct.DeleteFolderContentsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFolderContentsRequestBuilder {
+    authentication_token: Option<String>,
+    folder_id: Option<String>,
+}
+
+impl DeleteFolderContentsRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DeleteFolderContentsRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`folder_id`](struct.DeleteFolderContentsRequest.html#structfield.folder_id) member.
+    pub fn folder_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.folder_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFolderContentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteFolderContentsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFolderContentsRequest {
+            authentication_token: self.authentication_token,
+            folder_id: self
+                .folder_id
+                .ok_or(::rusoto_core::BuildError::missing("folder_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteFolderRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
@@ -458,6 +1702,44 @@ pub struct DeleteFolderRequest {
     pub folder_id: String,
 }
 
+impl DeleteFolderRequest {
+    /// Returns a builder for `DeleteFolderRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFolderRequestBuilder {
+        DeleteFolderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFolderRequest`](struct.DeleteFolderRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFolderRequestBuilder {
+    authentication_token: Option<String>,
+    folder_id: Option<String>,
+}
+
+impl DeleteFolderRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DeleteFolderRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`folder_id`](struct.DeleteFolderRequest.html#structfield.folder_id) member.
+    pub fn folder_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.folder_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFolderRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteFolderRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFolderRequest {
+            authentication_token: self.authentication_token,
+            folder_id: self
+                .folder_id
+                .ok_or(::rusoto_core::BuildError::missing("folder_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteLabelsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
@@ -477,6 +1759,60 @@ pub struct DeleteLabelsRequest {
     pub resource_id: String,
 }
 
+impl DeleteLabelsRequest {
+    /// Returns a builder for `DeleteLabelsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLabelsRequestBuilder {
+        DeleteLabelsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLabelsRequest`](struct.DeleteLabelsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLabelsRequestBuilder {
+    authentication_token: Option<String>,
+    delete_all: Option<bool>,
+    labels: Option<Vec<String>>,
+    resource_id: Option<String>,
+}
+
+impl DeleteLabelsRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DeleteLabelsRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_all`](struct.DeleteLabelsRequest.html#structfield.delete_all) member.
+    pub fn delete_all<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_all = Some(value.into());
+        self
+    }
+
+    /// Sets the [`labels`](struct.DeleteLabelsRequest.html#structfield.labels) member.
+    pub fn labels<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.labels = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DeleteLabelsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLabelsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteLabelsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLabelsRequest {
+            authentication_token: self.authentication_token,
+            delete_all: self.delete_all,
+            labels: self.labels,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteLabelsResponse {}
@@ -491,6 +1827,49 @@ pub struct DeleteNotificationSubscriptionRequest {
     pub subscription_id: String,
 }
 
+impl DeleteNotificationSubscriptionRequest {
+    /// Returns a builder for `DeleteNotificationSubscriptionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteNotificationSubscriptionRequestBuilder {
+        DeleteNotificationSubscriptionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteNotificationSubscriptionRequest`](struct.DeleteNotificationSubscriptionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteNotificationSubscriptionRequestBuilder {
+    organization_id: Option<String>,
+    subscription_id: Option<String>,
+}
+
+impl DeleteNotificationSubscriptionRequestBuilder {
+    /// Sets the [`organization_id`](struct.DeleteNotificationSubscriptionRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_id`](struct.DeleteNotificationSubscriptionRequest.html#structfield.subscription_id) member.
+    pub fn subscription_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteNotificationSubscriptionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteNotificationSubscriptionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteNotificationSubscriptionRequest {
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            subscription_id: self
+                .subscription_id
+                .ok_or(::rusoto_core::BuildError::missing("subscription_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteUserRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
@@ -502,6 +1881,44 @@ pub struct DeleteUserRequest {
     pub user_id: String,
 }
 
+impl DeleteUserRequest {
+    /// Returns a builder for `DeleteUserRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserRequestBuilder {
+        DeleteUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserRequest`](struct.DeleteUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserRequestBuilder {
+    authentication_token: Option<String>,
+    user_id: Option<String>,
+}
+
+impl DeleteUserRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DeleteUserRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.DeleteUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUserRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserRequest {
+            authentication_token: self.authentication_token,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeActivitiesRequest {
     /// <p>Specifies which activity types to include in the response. If this field is left empty, all activity types are returned.</p>
@@ -514,8 +1931,9 @@ pub struct DescribeActivitiesRequest {
     pub authentication_token: Option<String>,
     /// <p>The timestamp that determines the end time of the activities. The response includes the activities performed before the specified timestamp.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>Includes indirect activities. An indirect activity results from a direct activity performed on a parent resource. For example, sharing a parent folder (the direct activity) shares all of the subfolders and documents within the parent folder (the indirect activity).</p>
     #[serde(rename = "IncludeIndirectActivities")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -538,14 +1956,117 @@ pub struct DescribeActivitiesRequest {
     pub resource_id: Option<String>,
     /// <p>The timestamp that determines the starting time of the activities. The response includes the activities performed after the specified timestamp.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>The ID of the user who performed the action. The response includes activities pertaining to this user. This is an optional parameter and is only applicable for administrative API (SigV4) requests.</p>
     #[serde(rename = "UserId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
 }
 
+impl DescribeActivitiesRequest {
+    /// Returns a builder for `DescribeActivitiesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeActivitiesRequestBuilder {
+        DescribeActivitiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeActivitiesRequest`](struct.DescribeActivitiesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeActivitiesRequestBuilder {
+    activity_types: Option<String>,
+    authentication_token: Option<String>,
+    end_time: Option<EpochTimestamp>,
+    include_indirect_activities: Option<bool>,
+    limit: Option<i64>,
+    marker: Option<String>,
+    organization_id: Option<String>,
+    resource_id: Option<String>,
+    start_time: Option<EpochTimestamp>,
+    user_id: Option<String>,
+}
+
+impl DescribeActivitiesRequestBuilder {
+    /// Sets the [`activity_types`](struct.DescribeActivitiesRequest.html#structfield.activity_types) member.
+    pub fn activity_types<T: Into<String>>(mut self, value: T) -> Self {
+        self.activity_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authentication_token`](struct.DescribeActivitiesRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.DescribeActivitiesRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_indirect_activities`](struct.DescribeActivitiesRequest.html#structfield.include_indirect_activities) member.
+    pub fn include_indirect_activities<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_indirect_activities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeActivitiesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeActivitiesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.DescribeActivitiesRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DescribeActivitiesRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeActivitiesRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.DescribeActivitiesRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeActivitiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeActivitiesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeActivitiesRequest {
+            activity_types: self.activity_types,
+            authentication_token: self.authentication_token,
+            end_time: self.end_time,
+            include_indirect_activities: self.include_indirect_activities,
+            limit: self.limit,
+            marker: self.marker,
+            organization_id: self.organization_id,
+            resource_id: self.resource_id,
+            start_time: self.start_time,
+            user_id: self.user_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeActivitiesResponse {
@@ -581,6 +2102,72 @@ pub struct DescribeCommentsRequest {
     pub version_id: String,
 }
 
+impl DescribeCommentsRequest {
+    /// Returns a builder for `DescribeCommentsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCommentsRequestBuilder {
+        DescribeCommentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCommentsRequest`](struct.DescribeCommentsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCommentsRequestBuilder {
+    authentication_token: Option<String>,
+    document_id: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+    version_id: Option<String>,
+}
+
+impl DescribeCommentsRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DescribeCommentsRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.DescribeCommentsRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeCommentsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeCommentsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.DescribeCommentsRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCommentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCommentsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeCommentsRequest {
+            authentication_token: self.authentication_token,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+            limit: self.limit,
+            marker: self.marker,
+            version_id: self
+                .version_id
+                .ok_or(::rusoto_core::BuildError::missing("version_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeCommentsResponse {
@@ -621,6 +2208,78 @@ pub struct DescribeDocumentVersionsRequest {
     pub marker: Option<String>,
 }
 
+impl DescribeDocumentVersionsRequest {
+    /// Returns a builder for `DescribeDocumentVersionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDocumentVersionsRequestBuilder {
+        DescribeDocumentVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDocumentVersionsRequest`](struct.DescribeDocumentVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDocumentVersionsRequestBuilder {
+    authentication_token: Option<String>,
+    document_id: Option<String>,
+    fields: Option<String>,
+    include: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl DescribeDocumentVersionsRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DescribeDocumentVersionsRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.DescribeDocumentVersionsRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fields`](struct.DescribeDocumentVersionsRequest.html#structfield.fields) member.
+    pub fn fields<T: Into<String>>(mut self, value: T) -> Self {
+        self.fields = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include`](struct.DescribeDocumentVersionsRequest.html#structfield.include) member.
+    pub fn include<T: Into<String>>(mut self, value: T) -> Self {
+        self.include = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeDocumentVersionsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDocumentVersionsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDocumentVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDocumentVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDocumentVersionsRequest {
+            authentication_token: self.authentication_token,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+            fields: self.fields,
+            include: self.include,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDocumentVersionsResponse {
@@ -658,25 +2317,113 @@ pub struct DescribeFolderContentsRequest {
     /// <p>The order for the contents of the folder.</p>
     #[serde(rename = "Order")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<OrderType>,
     /// <p>The sorting criteria.</p>
     #[serde(rename = "Sort")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<ResourceSortType>,
     /// <p>The type of items.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<FolderContentType>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DescribeFolderContentsResponse {
-    /// <p>The documents in the specified folder.</p>
-    #[serde(rename = "Documents")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub documents: Option<Vec<DocumentMetadata>>,
-    /// <p>The subfolders in the specified folder.</p>
+impl DescribeFolderContentsRequest {
+    /// Returns a builder for `DescribeFolderContentsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeFolderContentsRequestBuilder {
+        DescribeFolderContentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeFolderContentsRequest`](struct.DescribeFolderContentsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeFolderContentsRequestBuilder {
+    authentication_token: Option<String>,
+    folder_id: Option<String>,
+    include: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+    order: Option<OrderType>,
+    sort: Option<ResourceSortType>,
+    type_: Option<FolderContentType>,
+}
+
+impl DescribeFolderContentsRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DescribeFolderContentsRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`folder_id`](struct.DescribeFolderContentsRequest.html#structfield.folder_id) member.
+    pub fn folder_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.folder_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include`](struct.DescribeFolderContentsRequest.html#structfield.include) member.
+    pub fn include<T: Into<String>>(mut self, value: T) -> Self {
+        self.include = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeFolderContentsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeFolderContentsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`order`](struct.DescribeFolderContentsRequest.html#structfield.order) member.
+    pub fn order<T: Into<OrderType>>(mut self, value: T) -> Self {
+        self.order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort`](struct.DescribeFolderContentsRequest.html#structfield.sort) member.
+    pub fn sort<T: Into<ResourceSortType>>(mut self, value: T) -> Self {
+        self.sort = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.DescribeFolderContentsRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<FolderContentType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeFolderContentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeFolderContentsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeFolderContentsRequest {
+            authentication_token: self.authentication_token,
+            folder_id: self
+                .folder_id
+                .ok_or(::rusoto_core::BuildError::missing("folder_id"))?,
+            include: self.include,
+            limit: self.limit,
+            marker: self.marker,
+            order: self.order,
+            sort: self.sort,
+            type_: self.type_,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeFolderContentsResponse {
+    /// <p>The documents in the specified folder.</p>
+    #[serde(rename = "Documents")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documents: Option<Vec<DocumentMetadata>>,
+    /// <p>The subfolders in the specified folder.</p>
     #[serde(rename = "Folders")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub folders: Option<Vec<FolderMetadata>>,
@@ -709,6 +2456,68 @@ pub struct DescribeGroupsRequest {
     pub search_query: String,
 }
 
+impl DescribeGroupsRequest {
+    /// Returns a builder for `DescribeGroupsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeGroupsRequestBuilder {
+        DescribeGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeGroupsRequest`](struct.DescribeGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeGroupsRequestBuilder {
+    authentication_token: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+    organization_id: Option<String>,
+    search_query: Option<String>,
+}
+
+impl DescribeGroupsRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DescribeGroupsRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeGroupsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeGroupsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.DescribeGroupsRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`search_query`](struct.DescribeGroupsRequest.html#structfield.search_query) member.
+    pub fn search_query<T: Into<String>>(mut self, value: T) -> Self {
+        self.search_query = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeGroupsRequest {
+            authentication_token: self.authentication_token,
+            limit: self.limit,
+            marker: self.marker,
+            organization_id: self.organization_id,
+            search_query: self
+                .search_query
+                .ok_or(::rusoto_core::BuildError::missing("search_query"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeGroupsResponse {
@@ -737,6 +2546,55 @@ pub struct DescribeNotificationSubscriptionsRequest {
     pub organization_id: String,
 }
 
+impl DescribeNotificationSubscriptionsRequest {
+    /// Returns a builder for `DescribeNotificationSubscriptionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeNotificationSubscriptionsRequestBuilder {
+        DescribeNotificationSubscriptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeNotificationSubscriptionsRequest`](struct.DescribeNotificationSubscriptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeNotificationSubscriptionsRequestBuilder {
+    limit: Option<i64>,
+    marker: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl DescribeNotificationSubscriptionsRequestBuilder {
+    /// Sets the [`limit`](struct.DescribeNotificationSubscriptionsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeNotificationSubscriptionsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.DescribeNotificationSubscriptionsRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeNotificationSubscriptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeNotificationSubscriptionsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeNotificationSubscriptionsRequest {
+            limit: self.limit,
+            marker: self.marker,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeNotificationSubscriptionsResponse {
@@ -773,6 +2631,70 @@ pub struct DescribeResourcePermissionsRequest {
     pub resource_id: String,
 }
 
+impl DescribeResourcePermissionsRequest {
+    /// Returns a builder for `DescribeResourcePermissionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeResourcePermissionsRequestBuilder {
+        DescribeResourcePermissionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeResourcePermissionsRequest`](struct.DescribeResourcePermissionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeResourcePermissionsRequestBuilder {
+    authentication_token: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+    principal_id: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl DescribeResourcePermissionsRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DescribeResourcePermissionsRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeResourcePermissionsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeResourcePermissionsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_id`](struct.DescribeResourcePermissionsRequest.html#structfield.principal_id) member.
+    pub fn principal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DescribeResourcePermissionsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeResourcePermissionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeResourcePermissionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeResourcePermissionsRequest {
+            authentication_token: self.authentication_token,
+            limit: self.limit,
+            marker: self.marker,
+            principal_id: self.principal_id,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeResourcePermissionsResponse {
@@ -801,6 +2723,54 @@ pub struct DescribeRootFoldersRequest {
     pub marker: Option<String>,
 }
 
+impl DescribeRootFoldersRequest {
+    /// Returns a builder for `DescribeRootFoldersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRootFoldersRequestBuilder {
+        DescribeRootFoldersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRootFoldersRequest`](struct.DescribeRootFoldersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRootFoldersRequestBuilder {
+    authentication_token: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl DescribeRootFoldersRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DescribeRootFoldersRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeRootFoldersRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeRootFoldersRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRootFoldersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRootFoldersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeRootFoldersRequest {
+            authentication_token: self
+                .authentication_token
+                .ok_or(::rusoto_core::BuildError::missing("authentication_token"))?,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRootFoldersResponse {
@@ -827,7 +2797,7 @@ pub struct DescribeUsersRequest {
     /// <p>The state of the users. Specify "ALL" to include inactive users.</p>
     #[serde(rename = "Include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<String>,
+    pub include: Option<UserFilterType>,
     /// <p>The maximum number of items to return.</p>
     #[serde(rename = "Limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -839,7 +2809,7 @@ pub struct DescribeUsersRequest {
     /// <p>The order for the results.</p>
     #[serde(rename = "Order")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<OrderType>,
     /// <p>The ID of the organization.</p>
     #[serde(rename = "OrganizationId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -851,13 +2821,113 @@ pub struct DescribeUsersRequest {
     /// <p>The sorting criteria.</p>
     #[serde(rename = "Sort")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<UserSortType>,
     /// <p>The IDs of the users.</p>
     #[serde(rename = "UserIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_ids: Option<String>,
 }
 
+impl DescribeUsersRequest {
+    /// Returns a builder for `DescribeUsersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUsersRequestBuilder {
+        DescribeUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUsersRequest`](struct.DescribeUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUsersRequestBuilder {
+    authentication_token: Option<String>,
+    fields: Option<String>,
+    include: Option<UserFilterType>,
+    limit: Option<i64>,
+    marker: Option<String>,
+    order: Option<OrderType>,
+    organization_id: Option<String>,
+    query: Option<String>,
+    sort: Option<UserSortType>,
+    user_ids: Option<String>,
+}
+
+impl DescribeUsersRequestBuilder {
+    /// Sets the [`authentication_token`](struct.DescribeUsersRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fields`](struct.DescribeUsersRequest.html#structfield.fields) member.
+    pub fn fields<T: Into<String>>(mut self, value: T) -> Self {
+        self.fields = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include`](struct.DescribeUsersRequest.html#structfield.include) member.
+    pub fn include<T: Into<UserFilterType>>(mut self, value: T) -> Self {
+        self.include = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeUsersRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeUsersRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`order`](struct.DescribeUsersRequest.html#structfield.order) member.
+    pub fn order<T: Into<OrderType>>(mut self, value: T) -> Self {
+        self.order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.DescribeUsersRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query`](struct.DescribeUsersRequest.html#structfield.query) member.
+    pub fn query<T: Into<String>>(mut self, value: T) -> Self {
+        self.query = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort`](struct.DescribeUsersRequest.html#structfield.sort) member.
+    pub fn sort<T: Into<UserSortType>>(mut self, value: T) -> Self {
+        self.sort = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_ids`](struct.DescribeUsersRequest.html#structfield.user_ids) member.
+    pub fn user_ids<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeUsersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUsersRequest {
+            authentication_token: self.authentication_token,
+            fields: self.fields,
+            include: self.include,
+            limit: self.limit,
+            marker: self.marker,
+            order: self.order,
+            organization_id: self.organization_id,
+            query: self.query,
+            sort: self.sort,
+            user_ids: self.user_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUsersResponse {
@@ -877,8 +2947,9 @@ pub struct DescribeUsersResponse {
 pub struct DocumentMetadata {
     /// <p>The time when the document was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The ID of the creator.</p>
     #[serde(rename = "CreatorId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -897,8 +2968,9 @@ pub struct DocumentMetadata {
     pub latest_version_metadata: Option<DocumentVersionMetadata>,
     /// <p>The time when the document was updated.</p>
     #[serde(rename = "ModifiedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_timestamp: Option<f64>,
+    pub modified_timestamp: Option<EpochTimestamp>,
     /// <p>The ID of the parent folder.</p>
     #[serde(rename = "ParentFolderId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -906,7 +2978,245 @@ pub struct DocumentMetadata {
     /// <p>The resource state.</p>
     #[serde(rename = "ResourceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_state: Option<String>,
+    pub resource_state: Option<ResourceStateType>,
+}
+
+/// <p>The source of the document.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DocumentSourceType {
+    /// <p><code>ORIGINAL</code></p>
+    Original,
+    /// <p><code>WITH_COMMENTS</code></p>
+    WithComments,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DocumentSourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentSourceType::Original => "ORIGINAL",
+            DocumentSourceType::WithComments => "WITH_COMMENTS",
+            DocumentSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DocumentSourceType {
+    fn from(value: &'a str) -> DocumentSourceType {
+        match value {
+            "ORIGINAL" => DocumentSourceType::Original,
+            "WITH_COMMENTS" => DocumentSourceType::WithComments,
+            _ => DocumentSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentSourceType {
+    fn from(value: String) -> DocumentSourceType {
+        DocumentSourceType::from(value.as_str())
+    }
+}
+
+impl From<DocumentSourceType> for String {
+    fn from(value: DocumentSourceType) -> String {
+        match value {
+            DocumentSourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DocumentSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentSourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentSourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DocumentSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DocumentSourceType::from(value))
+    }
+}
+
+/// <p>The status of the document.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DocumentStatusType {
+    /// <p><code>INITIALIZED</code></p>
+    Initialized,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DocumentStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentStatusType::Initialized => "INITIALIZED",
+            DocumentStatusType::Active => "ACTIVE",
+            DocumentStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DocumentStatusType {
+    fn from(value: &'a str) -> DocumentStatusType {
+        match value {
+            "INITIALIZED" => DocumentStatusType::Initialized,
+            "ACTIVE" => DocumentStatusType::Active,
+            _ => DocumentStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentStatusType {
+    fn from(value: String) -> DocumentStatusType {
+        DocumentStatusType::from(value.as_str())
+    }
+}
+
+impl From<DocumentStatusType> for String {
+    fn from(value: DocumentStatusType) -> String {
+        match value {
+            DocumentStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DocumentStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentStatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DocumentStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DocumentStatusType::from(value))
+    }
+}
+
+/// <p>The thumbnail of the document.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DocumentThumbnailType {
+    /// <p><code>SMALL</code></p>
+    Small,
+    /// <p><code>SMALL_HQ</code></p>
+    SmallHq,
+    /// <p><code>LARGE</code></p>
+    Large,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DocumentThumbnailType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentThumbnailType::Small => "SMALL",
+            DocumentThumbnailType::SmallHq => "SMALL_HQ",
+            DocumentThumbnailType::Large => "LARGE",
+            DocumentThumbnailType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DocumentThumbnailType {
+    fn from(value: &'a str) -> DocumentThumbnailType {
+        match value {
+            "SMALL" => DocumentThumbnailType::Small,
+            "SMALL_HQ" => DocumentThumbnailType::SmallHq,
+            "LARGE" => DocumentThumbnailType::Large,
+            _ => DocumentThumbnailType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentThumbnailType {
+    fn from(value: String) -> DocumentThumbnailType {
+        DocumentThumbnailType::from(value.as_str())
+    }
+}
+
+impl From<DocumentThumbnailType> for String {
+    fn from(value: DocumentThumbnailType) -> String {
+        match value {
+            DocumentThumbnailType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DocumentThumbnailType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentThumbnailType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentThumbnailType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentThumbnailType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DocumentThumbnailType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DocumentThumbnailType::from(value))
+    }
 }
 
 /// <p>Describes a version of a document.</p>
@@ -915,20 +3225,23 @@ pub struct DocumentMetadata {
 pub struct DocumentVersionMetadata {
     /// <p>The timestamp when the content of the document was originally created.</p>
     #[serde(rename = "ContentCreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_created_timestamp: Option<f64>,
+    pub content_created_timestamp: Option<EpochTimestamp>,
     /// <p>The timestamp when the content of the document was modified.</p>
     #[serde(rename = "ContentModifiedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_modified_timestamp: Option<f64>,
+    pub content_modified_timestamp: Option<EpochTimestamp>,
     /// <p>The content type of the document.</p>
     #[serde(rename = "ContentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
     /// <p>The timestamp when the document was first uploaded.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The ID of the creator.</p>
     #[serde(rename = "CreatorId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -939,8 +3252,9 @@ pub struct DocumentVersionMetadata {
     pub id: Option<String>,
     /// <p>The timestamp when the document was last uploaded.</p>
     #[serde(rename = "ModifiedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_timestamp: Option<f64>,
+    pub modified_timestamp: Option<EpochTimestamp>,
     /// <p>The name of the version.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -956,45 +3270,203 @@ pub struct DocumentVersionMetadata {
     /// <p>The source of the document.</p>
     #[serde(rename = "Source")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<::std::collections::HashMap<String, String>>,
+    pub source: Option<::std::collections::HashMap<DocumentSourceType, String>>,
     /// <p>The status of the document.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DocumentStatusType>,
     /// <p>The thumbnail of the document.</p>
     #[serde(rename = "Thumbnail")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail: Option<::std::collections::HashMap<String, String>>,
+    pub thumbnail: Option<::std::collections::HashMap<DocumentThumbnailType, String>>,
 }
 
-/// <p>Describes a folder.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct FolderMetadata {
-    /// <p>The time when the folder was created.</p>
-    #[serde(rename = "CreatedTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
-    /// <p>The ID of the creator.</p>
-    #[serde(rename = "CreatorId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creator_id: Option<String>,
-    /// <p>The ID of the folder.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>List of labels on the folder.</p>
-    #[serde(rename = "Labels")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<Vec<String>>,
+/// <p>The status of the version.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DocumentVersionStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DocumentVersionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentVersionStatus::Active => "ACTIVE",
+            DocumentVersionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DocumentVersionStatus {
+    fn from(value: &'a str) -> DocumentVersionStatus {
+        match value {
+            "ACTIVE" => DocumentVersionStatus::Active,
+            _ => DocumentVersionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentVersionStatus {
+    fn from(value: String) -> DocumentVersionStatus {
+        DocumentVersionStatus::from(value.as_str())
+    }
+}
+
+impl From<DocumentVersionStatus> for String {
+    fn from(value: DocumentVersionStatus) -> String {
+        match value {
+            DocumentVersionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DocumentVersionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentVersionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentVersionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentVersionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DocumentVersionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DocumentVersionStatus::from(value))
+    }
+}
+
+/// <p>The type of items.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FolderContentType {
+    /// <p><code>ALL</code></p>
+    All,
+    /// <p><code>DOCUMENT</code></p>
+    Document,
+    /// <p><code>FOLDER</code></p>
+    Folder,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FolderContentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FolderContentType::All => "ALL",
+            FolderContentType::Document => "DOCUMENT",
+            FolderContentType::Folder => "FOLDER",
+            FolderContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FolderContentType {
+    fn from(value: &'a str) -> FolderContentType {
+        match value {
+            "ALL" => FolderContentType::All,
+            "DOCUMENT" => FolderContentType::Document,
+            "FOLDER" => FolderContentType::Folder,
+            _ => FolderContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FolderContentType {
+    fn from(value: String) -> FolderContentType {
+        FolderContentType::from(value.as_str())
+    }
+}
+
+impl From<FolderContentType> for String {
+    fn from(value: FolderContentType) -> String {
+        match value {
+            FolderContentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FolderContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FolderContentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FolderContentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FolderContentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FolderContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FolderContentType::from(value))
+    }
+}
+
+/// <p>Describes a folder.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct FolderMetadata {
+    /// <p>The time when the folder was created.</p>
+    #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_timestamp: Option<EpochTimestamp>,
+    /// <p>The ID of the creator.</p>
+    #[serde(rename = "CreatorId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// <p>The ID of the folder.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>List of labels on the folder.</p>
+    #[serde(rename = "Labels")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
     /// <p>The size of the latest version of the folder metadata.</p>
     #[serde(rename = "LatestVersionSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latest_version_size: Option<i64>,
     /// <p>The time when the folder was updated.</p>
     #[serde(rename = "ModifiedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_timestamp: Option<f64>,
+    pub modified_timestamp: Option<EpochTimestamp>,
     /// <p>The name of the folder.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1006,7 +3478,7 @@ pub struct FolderMetadata {
     /// <p>The resource state of the folder.</p>
     #[serde(rename = "ResourceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_state: Option<String>,
+    pub resource_state: Option<ResourceStateType>,
     /// <p>The unique identifier created from the subfolders and documents of the folder.</p>
     #[serde(rename = "Signature")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1024,6 +3496,36 @@ pub struct GetCurrentUserRequest {
     pub authentication_token: String,
 }
 
+impl GetCurrentUserRequest {
+    /// Returns a builder for `GetCurrentUserRequest`, checking its required members when built.
+    pub fn builder() -> GetCurrentUserRequestBuilder {
+        GetCurrentUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCurrentUserRequest`](struct.GetCurrentUserRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCurrentUserRequestBuilder {
+    authentication_token: Option<String>,
+}
+
+impl GetCurrentUserRequestBuilder {
+    /// Sets the [`authentication_token`](struct.GetCurrentUserRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCurrentUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetCurrentUserRequest, ::rusoto_core::BuildError> {
+        Ok(GetCurrentUserRequest {
+            authentication_token: self
+                .authentication_token
+                .ok_or(::rusoto_core::BuildError::missing("authentication_token"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCurrentUserResponse {
@@ -1056,6 +3558,68 @@ pub struct GetDocumentPathRequest {
     pub marker: Option<String>,
 }
 
+impl GetDocumentPathRequest {
+    /// Returns a builder for `GetDocumentPathRequest`, checking its required members when built.
+    pub fn builder() -> GetDocumentPathRequestBuilder {
+        GetDocumentPathRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDocumentPathRequest`](struct.GetDocumentPathRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDocumentPathRequestBuilder {
+    authentication_token: Option<String>,
+    document_id: Option<String>,
+    fields: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl GetDocumentPathRequestBuilder {
+    /// Sets the [`authentication_token`](struct.GetDocumentPathRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.GetDocumentPathRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fields`](struct.GetDocumentPathRequest.html#structfield.fields) member.
+    pub fn fields<T: Into<String>>(mut self, value: T) -> Self {
+        self.fields = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetDocumentPathRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.GetDocumentPathRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDocumentPathRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDocumentPathRequest, ::rusoto_core::BuildError> {
+        Ok(GetDocumentPathRequest {
+            authentication_token: self.authentication_token,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+            fields: self.fields,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDocumentPathResponse {
@@ -1080,6 +3644,52 @@ pub struct GetDocumentRequest {
     pub include_custom_metadata: Option<bool>,
 }
 
+impl GetDocumentRequest {
+    /// Returns a builder for `GetDocumentRequest`, checking its required members when built.
+    pub fn builder() -> GetDocumentRequestBuilder {
+        GetDocumentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDocumentRequest`](struct.GetDocumentRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDocumentRequestBuilder {
+    authentication_token: Option<String>,
+    document_id: Option<String>,
+    include_custom_metadata: Option<bool>,
+}
+
+impl GetDocumentRequestBuilder {
+    /// Sets the [`authentication_token`](struct.GetDocumentRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.GetDocumentRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_custom_metadata`](struct.GetDocumentRequest.html#structfield.include_custom_metadata) member.
+    pub fn include_custom_metadata<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_custom_metadata = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDocumentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDocumentRequest, ::rusoto_core::BuildError> {
+        Ok(GetDocumentRequest {
+            authentication_token: self.authentication_token,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+            include_custom_metadata: self.include_custom_metadata,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDocumentResponse {
@@ -1115,6 +3725,72 @@ pub struct GetDocumentVersionRequest {
     pub version_id: String,
 }
 
+impl GetDocumentVersionRequest {
+    /// Returns a builder for `GetDocumentVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetDocumentVersionRequestBuilder {
+        GetDocumentVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDocumentVersionRequest`](struct.GetDocumentVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDocumentVersionRequestBuilder {
+    authentication_token: Option<String>,
+    document_id: Option<String>,
+    fields: Option<String>,
+    include_custom_metadata: Option<bool>,
+    version_id: Option<String>,
+}
+
+impl GetDocumentVersionRequestBuilder {
+    /// Sets the [`authentication_token`](struct.GetDocumentVersionRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.GetDocumentVersionRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fields`](struct.GetDocumentVersionRequest.html#structfield.fields) member.
+    pub fn fields<T: Into<String>>(mut self, value: T) -> Self {
+        self.fields = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_custom_metadata`](struct.GetDocumentVersionRequest.html#structfield.include_custom_metadata) member.
+    pub fn include_custom_metadata<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_custom_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.GetDocumentVersionRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDocumentVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDocumentVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetDocumentVersionRequest {
+            authentication_token: self.authentication_token,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+            fields: self.fields,
+            include_custom_metadata: self.include_custom_metadata,
+            version_id: self
+                .version_id
+                .ok_or(::rusoto_core::BuildError::missing("version_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDocumentVersionResponse {
@@ -1151,6 +3827,68 @@ pub struct GetFolderPathRequest {
     pub marker: Option<String>,
 }
 
+impl GetFolderPathRequest {
+    /// Returns a builder for `GetFolderPathRequest`, checking its required members when built.
+    pub fn builder() -> GetFolderPathRequestBuilder {
+        GetFolderPathRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFolderPathRequest`](struct.GetFolderPathRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFolderPathRequestBuilder {
+    authentication_token: Option<String>,
+    fields: Option<String>,
+    folder_id: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl GetFolderPathRequestBuilder {
+    /// Sets the [`authentication_token`](struct.GetFolderPathRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fields`](struct.GetFolderPathRequest.html#structfield.fields) member.
+    pub fn fields<T: Into<String>>(mut self, value: T) -> Self {
+        self.fields = Some(value.into());
+        self
+    }
+
+    /// Sets the [`folder_id`](struct.GetFolderPathRequest.html#structfield.folder_id) member.
+    pub fn folder_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.folder_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetFolderPathRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.GetFolderPathRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFolderPathRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetFolderPathRequest, ::rusoto_core::BuildError> {
+        Ok(GetFolderPathRequest {
+            authentication_token: self.authentication_token,
+            fields: self.fields,
+            folder_id: self
+                .folder_id
+                .ok_or(::rusoto_core::BuildError::missing("folder_id"))?,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFolderPathResponse {
@@ -1175,6 +3913,52 @@ pub struct GetFolderRequest {
     pub include_custom_metadata: Option<bool>,
 }
 
+impl GetFolderRequest {
+    /// Returns a builder for `GetFolderRequest`, checking its required members when built.
+    pub fn builder() -> GetFolderRequestBuilder {
+        GetFolderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFolderRequest`](struct.GetFolderRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFolderRequestBuilder {
+    authentication_token: Option<String>,
+    folder_id: Option<String>,
+    include_custom_metadata: Option<bool>,
+}
+
+impl GetFolderRequestBuilder {
+    /// Sets the [`authentication_token`](struct.GetFolderRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`folder_id`](struct.GetFolderRequest.html#structfield.folder_id) member.
+    pub fn folder_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.folder_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_custom_metadata`](struct.GetFolderRequest.html#structfield.include_custom_metadata) member.
+    pub fn include_custom_metadata<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_custom_metadata = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFolderRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetFolderRequest, ::rusoto_core::BuildError> {
+        Ok(GetFolderRequest {
+            authentication_token: self.authentication_token,
+            folder_id: self
+                .folder_id
+                .ok_or(::rusoto_core::BuildError::missing("folder_id"))?,
+            include_custom_metadata: self.include_custom_metadata,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFolderResponse {
@@ -1197,7 +3981,7 @@ pub struct GetResourcesRequest {
     /// <p>The collection type.</p>
     #[serde(rename = "CollectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub collection_type: Option<String>,
+    pub collection_type: Option<ResourceCollectionType>,
     /// <p>The maximum number of resources to return.</p>
     #[serde(rename = "Limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1212,6 +3996,66 @@ pub struct GetResourcesRequest {
     pub user_id: Option<String>,
 }
 
+impl GetResourcesRequest {
+    /// Returns a builder for `GetResourcesRequest`, checking its required members when built.
+    pub fn builder() -> GetResourcesRequestBuilder {
+        GetResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourcesRequest`](struct.GetResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourcesRequestBuilder {
+    authentication_token: Option<String>,
+    collection_type: Option<ResourceCollectionType>,
+    limit: Option<i64>,
+    marker: Option<String>,
+    user_id: Option<String>,
+}
+
+impl GetResourcesRequestBuilder {
+    /// Sets the [`authentication_token`](struct.GetResourcesRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`collection_type`](struct.GetResourcesRequest.html#structfield.collection_type) member.
+    pub fn collection_type<T: Into<ResourceCollectionType>>(mut self, value: T) -> Self {
+        self.collection_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetResourcesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.GetResourcesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.GetResourcesRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourcesRequest {
+            authentication_token: self.authentication_token,
+            collection_type: self.collection_type,
+            limit: self.limit,
+            marker: self.marker,
+            user_id: self.user_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetResourcesResponse {
@@ -1251,12 +4095,14 @@ pub struct InitiateDocumentVersionUploadRequest {
     pub authentication_token: Option<String>,
     /// <p>The timestamp when the content of the document was originally created.</p>
     #[serde(rename = "ContentCreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_created_timestamp: Option<f64>,
+    pub content_created_timestamp: Option<EpochTimestamp>,
     /// <p>The timestamp when the content of the document was modified.</p>
     #[serde(rename = "ContentModifiedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_modified_timestamp: Option<f64>,
+    pub content_modified_timestamp: Option<EpochTimestamp>,
     /// <p>The content type of the document.</p>
     #[serde(rename = "ContentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1278,6 +4124,95 @@ pub struct InitiateDocumentVersionUploadRequest {
     pub parent_folder_id: String,
 }
 
+impl InitiateDocumentVersionUploadRequest {
+    /// Returns a builder for `InitiateDocumentVersionUploadRequest`, checking its required members when built.
+    pub fn builder() -> InitiateDocumentVersionUploadRequestBuilder {
+        InitiateDocumentVersionUploadRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InitiateDocumentVersionUploadRequest`](struct.InitiateDocumentVersionUploadRequest.html).
+#[derive(Default, Debug)]
+pub struct InitiateDocumentVersionUploadRequestBuilder {
+    authentication_token: Option<String>,
+    content_created_timestamp: Option<EpochTimestamp>,
+    content_modified_timestamp: Option<EpochTimestamp>,
+    content_type: Option<String>,
+    document_size_in_bytes: Option<i64>,
+    id: Option<String>,
+    name: Option<String>,
+    parent_folder_id: Option<String>,
+}
+
+impl InitiateDocumentVersionUploadRequestBuilder {
+    /// Sets the [`authentication_token`](struct.InitiateDocumentVersionUploadRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_created_timestamp`](struct.InitiateDocumentVersionUploadRequest.html#structfield.content_created_timestamp) member.
+    pub fn content_created_timestamp<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.content_created_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_modified_timestamp`](struct.InitiateDocumentVersionUploadRequest.html#structfield.content_modified_timestamp) member.
+    pub fn content_modified_timestamp<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.content_modified_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_type`](struct.InitiateDocumentVersionUploadRequest.html#structfield.content_type) member.
+    pub fn content_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_size_in_bytes`](struct.InitiateDocumentVersionUploadRequest.html#structfield.document_size_in_bytes) member.
+    pub fn document_size_in_bytes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.document_size_in_bytes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.InitiateDocumentVersionUploadRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.InitiateDocumentVersionUploadRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_folder_id`](struct.InitiateDocumentVersionUploadRequest.html#structfield.parent_folder_id) member.
+    pub fn parent_folder_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_folder_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `InitiateDocumentVersionUploadRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<InitiateDocumentVersionUploadRequest, ::rusoto_core::BuildError>
+    {
+        Ok(InitiateDocumentVersionUploadRequest {
+            authentication_token: self.authentication_token,
+            content_created_timestamp: self.content_created_timestamp,
+            content_modified_timestamp: self.content_modified_timestamp,
+            content_type: self.content_type,
+            document_size_in_bytes: self.document_size_in_bytes,
+            id: self.id,
+            name: self.name,
+            parent_folder_id: self
+                .parent_folder_id
+                .ok_or(::rusoto_core::BuildError::missing("parent_folder_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InitiateDocumentVersionUploadResponse {
@@ -1291,29 +4226,221 @@ pub struct InitiateDocumentVersionUploadResponse {
     pub upload_metadata: Option<UploadMetadata>,
 }
 
-/// <p>Set of options which defines notification preferences of given action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct NotificationOptions {
-    /// <p>Text value to be included in the email body.</p>
-    #[serde(rename = "EmailMessage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub email_message: Option<String>,
-    /// <p>Boolean value to indicate an email notification should be sent to the receipients.</p>
-    #[serde(rename = "SendEmail")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub send_email: Option<bool>,
+/// <p>The locale of the user.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LocaleType {
+    /// <p><code>en</code></p>
+    En,
+    /// <p><code>fr</code></p>
+    Fr,
+    /// <p><code>ko</code></p>
+    Ko,
+    /// <p><code>de</code></p>
+    De,
+    /// <p><code>es</code></p>
+    Es,
+    /// <p><code>ja</code></p>
+    Ja,
+    /// <p><code>ru</code></p>
+    Ru,
+    /// <p><code>zh_CN</code></p>
+    ZhCn,
+    /// <p><code>zh_TW</code></p>
+    ZhTw,
+    /// <p><code>pt_BR</code></p>
+    PtBr,
+    /// <p><code>default</code></p>
+    Default,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LocaleType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LocaleType::En => "en",
+            LocaleType::Fr => "fr",
+            LocaleType::Ko => "ko",
+            LocaleType::De => "de",
+            LocaleType::Es => "es",
+            LocaleType::Ja => "ja",
+            LocaleType::Ru => "ru",
+            LocaleType::ZhCn => "zh_CN",
+            LocaleType::ZhTw => "zh_TW",
+            LocaleType::PtBr => "pt_BR",
+            LocaleType::Default => "default",
+            LocaleType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Describes the users or user groups.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Participants {
-    /// <p>The list of user groups.</p>
-    #[serde(rename = "Groups")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub groups: Option<Vec<GroupMetadata>>,
-    /// <p>The list of users.</p>
-    #[serde(rename = "Users")]
+impl<'a> From<&'a str> for LocaleType {
+    fn from(value: &'a str) -> LocaleType {
+        match value {
+            "en" => LocaleType::En,
+            "fr" => LocaleType::Fr,
+            "ko" => LocaleType::Ko,
+            "de" => LocaleType::De,
+            "es" => LocaleType::Es,
+            "ja" => LocaleType::Ja,
+            "ru" => LocaleType::Ru,
+            "zh_CN" => LocaleType::ZhCn,
+            "zh_TW" => LocaleType::ZhTw,
+            "pt_BR" => LocaleType::PtBr,
+            "default" => LocaleType::Default,
+            _ => LocaleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LocaleType {
+    fn from(value: String) -> LocaleType {
+        LocaleType::from(value.as_str())
+    }
+}
+
+impl From<LocaleType> for String {
+    fn from(value: LocaleType) -> String {
+        match value {
+            LocaleType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LocaleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LocaleType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LocaleType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LocaleType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LocaleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LocaleType::from(value))
+    }
+}
+
+/// <p>Set of options which defines notification preferences of given action.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct NotificationOptions {
+    /// <p>Text value to be included in the email body.</p>
+    #[serde(rename = "EmailMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_message: Option<String>,
+    /// <p>Boolean value to indicate an email notification should be sent to the receipients.</p>
+    #[serde(rename = "SendEmail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email: Option<bool>,
+}
+
+/// <p>The order for the contents of the folder.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrderType {
+    /// <p><code>ASCENDING</code></p>
+    Ascending,
+    /// <p><code>DESCENDING</code></p>
+    Descending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrderType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderType::Ascending => "ASCENDING",
+            OrderType::Descending => "DESCENDING",
+            OrderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrderType {
+    fn from(value: &'a str) -> OrderType {
+        match value {
+            "ASCENDING" => OrderType::Ascending,
+            "DESCENDING" => OrderType::Descending,
+            _ => OrderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderType {
+    fn from(value: String) -> OrderType {
+        OrderType::from(value.as_str())
+    }
+}
+
+impl From<OrderType> for String {
+    fn from(value: OrderType) -> String {
+        match value {
+            OrderType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrderType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrderType::from(value))
+    }
+}
+
+/// <p>Describes the users or user groups.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Participants {
+    /// <p>The list of user groups.</p>
+    #[serde(rename = "Groups")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<GroupMetadata>>,
+    /// <p>The list of users.</p>
+    #[serde(rename = "Users")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub users: Option<Vec<UserMetadata>>,
 }
@@ -1325,11 +4452,11 @@ pub struct PermissionInfo {
     /// <p>The role of the user.</p>
     #[serde(rename = "Role")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    pub role: Option<RoleType>,
     /// <p>The type of permissions.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<RolePermissionType>,
 }
 
 /// <p>Describes a resource.</p>
@@ -1347,7 +4474,97 @@ pub struct Principal {
     /// <p>The type of resource.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<PrincipalType>,
+}
+
+/// <p>The type of resource.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PrincipalType {
+    /// <p><code>USER</code></p>
+    User,
+    /// <p><code>GROUP</code></p>
+    Group,
+    /// <p><code>INVITE</code></p>
+    Invite,
+    /// <p><code>ANONYMOUS</code></p>
+    Anonymous,
+    /// <p><code>ORGANIZATION</code></p>
+    Organization,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PrincipalType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PrincipalType::User => "USER",
+            PrincipalType::Group => "GROUP",
+            PrincipalType::Invite => "INVITE",
+            PrincipalType::Anonymous => "ANONYMOUS",
+            PrincipalType::Organization => "ORGANIZATION",
+            PrincipalType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PrincipalType {
+    fn from(value: &'a str) -> PrincipalType {
+        match value {
+            "USER" => PrincipalType::User,
+            "GROUP" => PrincipalType::Group,
+            "INVITE" => PrincipalType::Invite,
+            "ANONYMOUS" => PrincipalType::Anonymous,
+            "ORGANIZATION" => PrincipalType::Organization,
+            _ => PrincipalType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PrincipalType {
+    fn from(value: String) -> PrincipalType {
+        PrincipalType::from(value.as_str())
+    }
+}
+
+impl From<PrincipalType> for String {
+    fn from(value: PrincipalType) -> String {
+        match value {
+            PrincipalType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PrincipalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PrincipalType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PrincipalType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PrincipalType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PrincipalType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PrincipalType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1361,6 +4578,46 @@ pub struct RemoveAllResourcePermissionsRequest {
     pub resource_id: String,
 }
 
+impl RemoveAllResourcePermissionsRequest {
+    /// Returns a builder for `RemoveAllResourcePermissionsRequest`, checking its required members when built.
+    pub fn builder() -> RemoveAllResourcePermissionsRequestBuilder {
+        RemoveAllResourcePermissionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveAllResourcePermissionsRequest`](struct.RemoveAllResourcePermissionsRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveAllResourcePermissionsRequestBuilder {
+    authentication_token: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl RemoveAllResourcePermissionsRequestBuilder {
+    /// Sets the [`authentication_token`](struct.RemoveAllResourcePermissionsRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.RemoveAllResourcePermissionsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveAllResourcePermissionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveAllResourcePermissionsRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveAllResourcePermissionsRequest {
+            authentication_token: self.authentication_token,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RemoveResourcePermissionRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
@@ -1373,12 +4630,144 @@ pub struct RemoveResourcePermissionRequest {
     /// <p>The principal type of the resource.</p>
     #[serde(rename = "PrincipalType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub principal_type: Option<String>,
+    pub principal_type: Option<PrincipalType>,
     /// <p>The ID of the resource.</p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
 }
 
+impl RemoveResourcePermissionRequest {
+    /// Returns a builder for `RemoveResourcePermissionRequest`, checking its required members when built.
+    pub fn builder() -> RemoveResourcePermissionRequestBuilder {
+        RemoveResourcePermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveResourcePermissionRequest`](struct.RemoveResourcePermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveResourcePermissionRequestBuilder {
+    authentication_token: Option<String>,
+    principal_id: Option<String>,
+    principal_type: Option<PrincipalType>,
+    resource_id: Option<String>,
+}
+
+impl RemoveResourcePermissionRequestBuilder {
+    /// Sets the [`authentication_token`](struct.RemoveResourcePermissionRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_id`](struct.RemoveResourcePermissionRequest.html#structfield.principal_id) member.
+    pub fn principal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_type`](struct.RemoveResourcePermissionRequest.html#structfield.principal_type) member.
+    pub fn principal_type<T: Into<PrincipalType>>(mut self, value: T) -> Self {
+        self.principal_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.RemoveResourcePermissionRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveResourcePermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveResourcePermissionRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveResourcePermissionRequest {
+            authentication_token: self.authentication_token,
+            principal_id: self
+                .principal_id
+                .ok_or(::rusoto_core::BuildError::missing("principal_id"))?,
+            principal_type: self.principal_type,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
+/// <p>The collection type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceCollectionType {
+    /// <p><code>SHARED_WITH_ME</code></p>
+    SharedWithMe,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceCollectionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceCollectionType::SharedWithMe => "SHARED_WITH_ME",
+            ResourceCollectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceCollectionType {
+    fn from(value: &'a str) -> ResourceCollectionType {
+        match value {
+            "SHARED_WITH_ME" => ResourceCollectionType::SharedWithMe,
+            _ => ResourceCollectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceCollectionType {
+    fn from(value: String) -> ResourceCollectionType {
+        ResourceCollectionType::from(value.as_str())
+    }
+}
+
+impl From<ResourceCollectionType> for String {
+    fn from(value: ResourceCollectionType) -> String {
+        match value {
+            ResourceCollectionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceCollectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceCollectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceCollectionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceCollectionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceCollectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceCollectionType::from(value))
+    }
+}
+
 /// <p>Describes the metadata of a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1406,7 +4795,7 @@ pub struct ResourceMetadata {
     /// <p>The type of resource.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ResourceType>,
     /// <p>The version ID of the resource. This is an optional field and is filled for action on document version.</p>
     #[serde(rename = "VersionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1437,18 +4826,424 @@ pub struct ResourcePathComponent {
     pub name: Option<String>,
 }
 
+/// <p>The sorting criteria.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceSortType {
+    /// <p><code>DATE</code></p>
+    Date,
+    /// <p><code>NAME</code></p>
+    Name,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceSortType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceSortType::Date => "DATE",
+            ResourceSortType::Name => "NAME",
+            ResourceSortType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceSortType {
+    fn from(value: &'a str) -> ResourceSortType {
+        match value {
+            "DATE" => ResourceSortType::Date,
+            "NAME" => ResourceSortType::Name,
+            _ => ResourceSortType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceSortType {
+    fn from(value: String) -> ResourceSortType {
+        ResourceSortType::from(value.as_str())
+    }
+}
+
+impl From<ResourceSortType> for String {
+    fn from(value: ResourceSortType) -> String {
+        match value {
+            ResourceSortType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceSortType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceSortType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceSortType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceSortType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceSortType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceSortType::from(value))
+    }
+}
+
+/// <p>The resource state.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceStateType {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>RESTORING</code></p>
+    Restoring,
+    /// <p><code>RECYCLING</code></p>
+    Recycling,
+    /// <p><code>RECYCLED</code></p>
+    Recycled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceStateType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceStateType::Active => "ACTIVE",
+            ResourceStateType::Restoring => "RESTORING",
+            ResourceStateType::Recycling => "RECYCLING",
+            ResourceStateType::Recycled => "RECYCLED",
+            ResourceStateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceStateType {
+    fn from(value: &'a str) -> ResourceStateType {
+        match value {
+            "ACTIVE" => ResourceStateType::Active,
+            "RESTORING" => ResourceStateType::Restoring,
+            "RECYCLING" => ResourceStateType::Recycling,
+            "RECYCLED" => ResourceStateType::Recycled,
+            _ => ResourceStateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceStateType {
+    fn from(value: String) -> ResourceStateType {
+        ResourceStateType::from(value.as_str())
+    }
+}
+
+impl From<ResourceStateType> for String {
+    fn from(value: ResourceStateType) -> String {
+        match value {
+            ResourceStateType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceStateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceStateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceStateType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceStateType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceStateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceStateType::from(value))
+    }
+}
+
+/// <p>The type of resource.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// <p><code>FOLDER</code></p>
+    Folder,
+    /// <p><code>DOCUMENT</code></p>
+    Document,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Folder => "FOLDER",
+            ResourceType::Document => "DOCUMENT",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "FOLDER" => ResourceType::Folder,
+            "DOCUMENT" => ResourceType::Document,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        ResourceType::from(value.as_str())
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> String {
+        match value {
+            ResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceType::from(value))
+    }
+}
+
+/// <p>The type of permissions.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RolePermissionType {
+    /// <p><code>DIRECT</code></p>
+    Direct,
+    /// <p><code>INHERITED</code></p>
+    Inherited,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RolePermissionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RolePermissionType::Direct => "DIRECT",
+            RolePermissionType::Inherited => "INHERITED",
+            RolePermissionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RolePermissionType {
+    fn from(value: &'a str) -> RolePermissionType {
+        match value {
+            "DIRECT" => RolePermissionType::Direct,
+            "INHERITED" => RolePermissionType::Inherited,
+            _ => RolePermissionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RolePermissionType {
+    fn from(value: String) -> RolePermissionType {
+        RolePermissionType::from(value.as_str())
+    }
+}
+
+impl From<RolePermissionType> for String {
+    fn from(value: RolePermissionType) -> String {
+        match value {
+            RolePermissionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RolePermissionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RolePermissionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RolePermissionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RolePermissionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RolePermissionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RolePermissionType::from(value))
+    }
+}
+
+/// <p>The role of the user.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoleType {
+    /// <p><code>VIEWER</code></p>
+    Viewer,
+    /// <p><code>CONTRIBUTOR</code></p>
+    Contributor,
+    /// <p><code>OWNER</code></p>
+    Owner,
+    /// <p><code>COOWNER</code></p>
+    Coowner,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RoleType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RoleType::Viewer => "VIEWER",
+            RoleType::Contributor => "CONTRIBUTOR",
+            RoleType::Owner => "OWNER",
+            RoleType::Coowner => "COOWNER",
+            RoleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RoleType {
+    fn from(value: &'a str) -> RoleType {
+        match value {
+            "VIEWER" => RoleType::Viewer,
+            "CONTRIBUTOR" => RoleType::Contributor,
+            "OWNER" => RoleType::Owner,
+            "COOWNER" => RoleType::Coowner,
+            _ => RoleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RoleType {
+    fn from(value: String) -> RoleType {
+        RoleType::from(value.as_str())
+    }
+}
+
+impl From<RoleType> for String {
+    fn from(value: RoleType) -> String {
+        match value {
+            RoleType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RoleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RoleType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RoleType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RoleType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RoleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RoleType::from(value))
+    }
+}
+
 /// <p>Describes the recipient type and ID, if available.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SharePrincipal {
     /// <p>The ID of the recipient.</p>
     #[serde(rename = "Id")]
     pub id: String,
     /// <p>The role of the recipient.</p>
     #[serde(rename = "Role")]
-    pub role: String,
+    pub role: RoleType,
     /// <p>The type of the recipient.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: PrincipalType,
 }
 
 /// <p>Describes the share results of a resource.</p>
@@ -1466,7 +5261,7 @@ pub struct ShareResult {
     /// <p>The role.</p>
     #[serde(rename = "Role")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    pub role: Option<RoleType>,
     /// <p>The ID of the resource that was shared.</p>
     #[serde(rename = "ShareId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1474,42 +5269,350 @@ pub struct ShareResult {
     /// <p>The status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ShareStatusType>,
     /// <p>The status message.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
 }
 
-/// <p>Describes the storage for a user.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct StorageRuleType {
-    /// <p>The amount of storage allocated, in bytes.</p>
-    #[serde(rename = "StorageAllocatedInBytes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub storage_allocated_in_bytes: Option<i64>,
-    /// <p>The type of storage.</p>
-    #[serde(rename = "StorageType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub storage_type: Option<String>,
+/// <p>The status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShareStatusType {
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// <p><code>FAILURE</code></p>
+    Failure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ShareStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShareStatusType::Success => "SUCCESS",
+            ShareStatusType::Failure => "FAILURE",
+            ShareStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ShareStatusType {
+    fn from(value: &'a str) -> ShareStatusType {
+        match value {
+            "SUCCESS" => ShareStatusType::Success,
+            "FAILURE" => ShareStatusType::Failure,
+            _ => ShareStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShareStatusType {
+    fn from(value: String) -> ShareStatusType {
+        ShareStatusType::from(value.as_str())
+    }
+}
+
+impl From<ShareStatusType> for String {
+    fn from(value: ShareStatusType) -> String {
+        match value {
+            ShareStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ShareStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ShareStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ShareStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShareStatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ShareStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ShareStatusType::from(value))
+    }
+}
+
+/// <p>Describes the storage for a user.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StorageRuleType {
+    /// <p>The amount of storage allocated, in bytes.</p>
+    #[serde(rename = "StorageAllocatedInBytes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_allocated_in_bytes: Option<i64>,
+    /// <p>The type of storage.</p>
+    #[serde(rename = "StorageType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_type: Option<StorageType>,
+}
+
+/// <p>The type of storage.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StorageType {
+    /// <p><code>UNLIMITED</code></p>
+    Unlimited,
+    /// <p><code>QUOTA</code></p>
+    Quota,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StorageType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StorageType::Unlimited => "UNLIMITED",
+            StorageType::Quota => "QUOTA",
+            StorageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StorageType {
+    fn from(value: &'a str) -> StorageType {
+        match value {
+            "UNLIMITED" => StorageType::Unlimited,
+            "QUOTA" => StorageType::Quota,
+            _ => StorageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StorageType {
+    fn from(value: String) -> StorageType {
+        StorageType::from(value.as_str())
+    }
+}
+
+impl From<StorageType> for String {
+    fn from(value: StorageType) -> String {
+        match value {
+            StorageType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StorageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StorageType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StorageType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StorageType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StorageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StorageType::from(value))
+    }
+}
+
+/// <p>Describes a subscription.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Subscription {
+    /// <p>The endpoint of the subscription.</p>
+    #[serde(rename = "EndPoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_point: Option<String>,
+    /// <p>The protocol of the subscription.</p>
+    #[serde(rename = "Protocol")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<SubscriptionProtocolType>,
+    /// <p>The ID of the subscription.</p>
+    #[serde(rename = "SubscriptionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_id: Option<String>,
+}
+
+/// <p>The protocol to use. The supported value is https, which delivers JSON-encoded messages using HTTPS POST.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SubscriptionProtocolType {
+    /// <p><code>HTTPS</code></p>
+    Https,
+    /// <p><code>SQS</code></p>
+    Sqs,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SubscriptionProtocolType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubscriptionProtocolType::Https => "HTTPS",
+            SubscriptionProtocolType::Sqs => "SQS",
+            SubscriptionProtocolType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SubscriptionProtocolType {
+    fn from(value: &'a str) -> SubscriptionProtocolType {
+        match value {
+            "HTTPS" => SubscriptionProtocolType::Https,
+            "SQS" => SubscriptionProtocolType::Sqs,
+            _ => SubscriptionProtocolType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubscriptionProtocolType {
+    fn from(value: String) -> SubscriptionProtocolType {
+        SubscriptionProtocolType::from(value.as_str())
+    }
+}
+
+impl From<SubscriptionProtocolType> for String {
+    fn from(value: SubscriptionProtocolType) -> String {
+        match value {
+            SubscriptionProtocolType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SubscriptionProtocolType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SubscriptionProtocolType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SubscriptionProtocolType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubscriptionProtocolType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SubscriptionProtocolType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SubscriptionProtocolType::from(value))
+    }
+}
+
+/// <p>The notification type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SubscriptionType {
+    /// <p><code>ALL</code></p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SubscriptionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubscriptionType::All => "ALL",
+            SubscriptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SubscriptionType {
+    fn from(value: &'a str) -> SubscriptionType {
+        match value {
+            "ALL" => SubscriptionType::All,
+            _ => SubscriptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubscriptionType {
+    fn from(value: String) -> SubscriptionType {
+        SubscriptionType::from(value.as_str())
+    }
+}
+
+impl From<SubscriptionType> for String {
+    fn from(value: SubscriptionType) -> String {
+        match value {
+            SubscriptionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-/// <p>Describes a subscription.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Subscription {
-    /// <p>The endpoint of the subscription.</p>
-    #[serde(rename = "EndPoint")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_point: Option<String>,
-    /// <p>The protocol of the subscription.</p>
-    #[serde(rename = "Protocol")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
-    /// <p>The ID of the subscription.</p>
-    #[serde(rename = "SubscriptionId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription_id: Option<String>,
+impl fmt::Display for SubscriptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SubscriptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SubscriptionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubscriptionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SubscriptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SubscriptionType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1532,7 +5635,69 @@ pub struct UpdateDocumentRequest {
     /// <p>The resource state of the document. Only ACTIVE and RECYCLED are supported.</p>
     #[serde(rename = "ResourceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_state: Option<String>,
+    pub resource_state: Option<ResourceStateType>,
+}
+
+impl UpdateDocumentRequest {
+    /// Returns a builder for `UpdateDocumentRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDocumentRequestBuilder {
+        UpdateDocumentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDocumentRequest`](struct.UpdateDocumentRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDocumentRequestBuilder {
+    authentication_token: Option<String>,
+    document_id: Option<String>,
+    name: Option<String>,
+    parent_folder_id: Option<String>,
+    resource_state: Option<ResourceStateType>,
+}
+
+impl UpdateDocumentRequestBuilder {
+    /// Sets the [`authentication_token`](struct.UpdateDocumentRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.UpdateDocumentRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateDocumentRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_folder_id`](struct.UpdateDocumentRequest.html#structfield.parent_folder_id) member.
+    pub fn parent_folder_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_folder_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_state`](struct.UpdateDocumentRequest.html#structfield.resource_state) member.
+    pub fn resource_state<T: Into<ResourceStateType>>(mut self, value: T) -> Self {
+        self.resource_state = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDocumentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateDocumentRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDocumentRequest {
+            authentication_token: self.authentication_token,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+            name: self.name,
+            parent_folder_id: self.parent_folder_id,
+            resource_state: self.resource_state,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1550,7 +5715,65 @@ pub struct UpdateDocumentVersionRequest {
     /// <p>The status of the version.</p>
     #[serde(rename = "VersionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub version_status: Option<String>,
+    pub version_status: Option<DocumentVersionStatus>,
+}
+
+impl UpdateDocumentVersionRequest {
+    /// Returns a builder for `UpdateDocumentVersionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDocumentVersionRequestBuilder {
+        UpdateDocumentVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDocumentVersionRequest`](struct.UpdateDocumentVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDocumentVersionRequestBuilder {
+    authentication_token: Option<String>,
+    document_id: Option<String>,
+    version_id: Option<String>,
+    version_status: Option<DocumentVersionStatus>,
+}
+
+impl UpdateDocumentVersionRequestBuilder {
+    /// Sets the [`authentication_token`](struct.UpdateDocumentVersionRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.UpdateDocumentVersionRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.UpdateDocumentVersionRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_status`](struct.UpdateDocumentVersionRequest.html#structfield.version_status) member.
+    pub fn version_status<T: Into<DocumentVersionStatus>>(mut self, value: T) -> Self {
+        self.version_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDocumentVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDocumentVersionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDocumentVersionRequest {
+            authentication_token: self.authentication_token,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+            version_id: self
+                .version_id
+                .ok_or(::rusoto_core::BuildError::missing("version_id"))?,
+            version_status: self.version_status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1573,7 +5796,69 @@ pub struct UpdateFolderRequest {
     /// <p>The resource state of the folder. Only ACTIVE and RECYCLED are accepted values from the API.</p>
     #[serde(rename = "ResourceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_state: Option<String>,
+    pub resource_state: Option<ResourceStateType>,
+}
+
+impl UpdateFolderRequest {
+    /// Returns a builder for `UpdateFolderRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFolderRequestBuilder {
+        UpdateFolderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFolderRequest`](struct.UpdateFolderRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFolderRequestBuilder {
+    authentication_token: Option<String>,
+    folder_id: Option<String>,
+    name: Option<String>,
+    parent_folder_id: Option<String>,
+    resource_state: Option<ResourceStateType>,
+}
+
+impl UpdateFolderRequestBuilder {
+    /// Sets the [`authentication_token`](struct.UpdateFolderRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`folder_id`](struct.UpdateFolderRequest.html#structfield.folder_id) member.
+    pub fn folder_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.folder_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateFolderRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_folder_id`](struct.UpdateFolderRequest.html#structfield.parent_folder_id) member.
+    pub fn parent_folder_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_folder_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_state`](struct.UpdateFolderRequest.html#structfield.resource_state) member.
+    pub fn resource_state<T: Into<ResourceStateType>>(mut self, value: T) -> Self {
+        self.resource_state = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFolderRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateFolderRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFolderRequest {
+            authentication_token: self.authentication_token,
+            folder_id: self
+                .folder_id
+                .ok_or(::rusoto_core::BuildError::missing("folder_id"))?,
+            name: self.name,
+            parent_folder_id: self.parent_folder_id,
+            resource_state: self.resource_state,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1589,11 +5874,11 @@ pub struct UpdateUserRequest {
     /// <p>Boolean value to determine whether the user is granted Poweruser privileges.</p>
     #[serde(rename = "GrantPoweruserPrivileges")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub grant_poweruser_privileges: Option<String>,
+    pub grant_poweruser_privileges: Option<BooleanEnumType>,
     /// <p>The locale of the user.</p>
     #[serde(rename = "Locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<LocaleType>,
     /// <p>The amount of storage for the user.</p>
     #[serde(rename = "StorageRule")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1609,12 +5894,106 @@ pub struct UpdateUserRequest {
     /// <p>The type of the user.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UserType>,
     /// <p>The ID of the user.</p>
     #[serde(rename = "UserId")]
     pub user_id: String,
 }
 
+impl UpdateUserRequest {
+    /// Returns a builder for `UpdateUserRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserRequestBuilder {
+        UpdateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserRequest`](struct.UpdateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserRequestBuilder {
+    authentication_token: Option<String>,
+    given_name: Option<String>,
+    grant_poweruser_privileges: Option<BooleanEnumType>,
+    locale: Option<LocaleType>,
+    storage_rule: Option<StorageRuleType>,
+    surname: Option<String>,
+    time_zone_id: Option<String>,
+    type_: Option<UserType>,
+    user_id: Option<String>,
+}
+
+impl UpdateUserRequestBuilder {
+    /// Sets the [`authentication_token`](struct.UpdateUserRequest.html#structfield.authentication_token) member.
+    pub fn authentication_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`given_name`](struct.UpdateUserRequest.html#structfield.given_name) member.
+    pub fn given_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.given_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant_poweruser_privileges`](struct.UpdateUserRequest.html#structfield.grant_poweruser_privileges) member.
+    pub fn grant_poweruser_privileges<T: Into<BooleanEnumType>>(mut self, value: T) -> Self {
+        self.grant_poweruser_privileges = Some(value.into());
+        self
+    }
+
+    /// Sets the [`locale`](struct.UpdateUserRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<LocaleType>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_rule`](struct.UpdateUserRequest.html#structfield.storage_rule) member.
+    pub fn storage_rule<T: Into<StorageRuleType>>(mut self, value: T) -> Self {
+        self.storage_rule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`surname`](struct.UpdateUserRequest.html#structfield.surname) member.
+    pub fn surname<T: Into<String>>(mut self, value: T) -> Self {
+        self.surname = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_zone_id`](struct.UpdateUserRequest.html#structfield.time_zone_id) member.
+    pub fn time_zone_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.time_zone_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.UpdateUserRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<UserType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.UpdateUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateUserRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserRequest {
+            authentication_token: self.authentication_token,
+            given_name: self.given_name,
+            grant_poweruser_privileges: self.grant_poweruser_privileges,
+            locale: self.locale,
+            storage_rule: self.storage_rule,
+            surname: self.surname,
+            time_zone_id: self.time_zone_id,
+            type_: self.type_,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateUserResponse {
@@ -1644,8 +6023,9 @@ pub struct UploadMetadata {
 pub struct User {
     /// <p>The time when the user was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The email address of the user.</p>
     #[serde(rename = "EmailAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1661,11 +6041,12 @@ pub struct User {
     /// <p>The locale of the user.</p>
     #[serde(rename = "Locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<LocaleType>,
     /// <p>The time when the user was modified.</p>
     #[serde(rename = "ModifiedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_timestamp: Option<f64>,
+    pub modified_timestamp: Option<EpochTimestamp>,
     /// <p>The ID of the organization.</p>
     #[serde(rename = "OrganizationId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1681,7 +6062,7 @@ pub struct User {
     /// <p>The status of the user.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<UserStatusType>,
     /// <p>The storage for the user.</p>
     #[serde(rename = "Storage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1697,13 +6078,91 @@ pub struct User {
     /// <p>The type of user.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UserType>,
     /// <p>The login name of the user.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
+/// <p>The state of the users. Specify "ALL" to include inactive users.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UserFilterType {
+    /// <p><code>ALL</code></p>
+    All,
+    /// <p><code>ACTIVE_PENDING</code></p>
+    ActivePending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UserFilterType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserFilterType::All => "ALL",
+            UserFilterType::ActivePending => "ACTIVE_PENDING",
+            UserFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UserFilterType {
+    fn from(value: &'a str) -> UserFilterType {
+        match value {
+            "ALL" => UserFilterType::All,
+            "ACTIVE_PENDING" => UserFilterType::ActivePending,
+            _ => UserFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserFilterType {
+    fn from(value: String) -> UserFilterType {
+        UserFilterType::from(value.as_str())
+    }
+}
+
+impl From<UserFilterType> for String {
+    fn from(value: UserFilterType) -> String {
+        match value {
+            UserFilterType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UserFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserFilterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserFilterType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserFilterType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UserFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UserFilterType::from(value))
+    }
+}
+
 /// <p>Describes the metadata of the user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1730,18 +6189,280 @@ pub struct UserMetadata {
     pub username: Option<String>,
 }
 
-/// <p>Describes the storage for a user.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UserStorageMetadata {
-    /// <p>The storage for a user.</p>
-    #[serde(rename = "StorageRule")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub storage_rule: Option<StorageRuleType>,
-    /// <p>The amount of storage used, in bytes.</p>
-    #[serde(rename = "StorageUtilizedInBytes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub storage_utilized_in_bytes: Option<i64>,
+/// <p>The sorting criteria.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UserSortType {
+    /// <p><code>USER_NAME</code></p>
+    UserName,
+    /// <p><code>FULL_NAME</code></p>
+    FullName,
+    /// <p><code>STORAGE_LIMIT</code></p>
+    StorageLimit,
+    /// <p><code>USER_STATUS</code></p>
+    UserStatus,
+    /// <p><code>STORAGE_USED</code></p>
+    StorageUsed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UserSortType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserSortType::UserName => "USER_NAME",
+            UserSortType::FullName => "FULL_NAME",
+            UserSortType::StorageLimit => "STORAGE_LIMIT",
+            UserSortType::UserStatus => "USER_STATUS",
+            UserSortType::StorageUsed => "STORAGE_USED",
+            UserSortType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UserSortType {
+    fn from(value: &'a str) -> UserSortType {
+        match value {
+            "USER_NAME" => UserSortType::UserName,
+            "FULL_NAME" => UserSortType::FullName,
+            "STORAGE_LIMIT" => UserSortType::StorageLimit,
+            "USER_STATUS" => UserSortType::UserStatus,
+            "STORAGE_USED" => UserSortType::StorageUsed,
+            _ => UserSortType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserSortType {
+    fn from(value: String) -> UserSortType {
+        UserSortType::from(value.as_str())
+    }
+}
+
+impl From<UserSortType> for String {
+    fn from(value: UserSortType) -> String {
+        match value {
+            UserSortType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UserSortType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserSortType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserSortType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserSortType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UserSortType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UserSortType::from(value))
+    }
+}
+
+/// <p>The status of the user.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UserStatusType {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UserStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserStatusType::Active => "ACTIVE",
+            UserStatusType::Inactive => "INACTIVE",
+            UserStatusType::Pending => "PENDING",
+            UserStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UserStatusType {
+    fn from(value: &'a str) -> UserStatusType {
+        match value {
+            "ACTIVE" => UserStatusType::Active,
+            "INACTIVE" => UserStatusType::Inactive,
+            "PENDING" => UserStatusType::Pending,
+            _ => UserStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserStatusType {
+    fn from(value: String) -> UserStatusType {
+        UserStatusType::from(value.as_str())
+    }
+}
+
+impl From<UserStatusType> for String {
+    fn from(value: UserStatusType) -> String {
+        match value {
+            UserStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UserStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserStatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UserStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UserStatusType::from(value))
+    }
+}
+
+/// <p>Describes the storage for a user.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UserStorageMetadata {
+    /// <p>The storage for a user.</p>
+    #[serde(rename = "StorageRule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_rule: Option<StorageRuleType>,
+    /// <p>The amount of storage used, in bytes.</p>
+    #[serde(rename = "StorageUtilizedInBytes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_utilized_in_bytes: Option<i64>,
+}
+
+/// <p>The type of the user.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UserType {
+    /// <p><code>USER</code></p>
+    User,
+    /// <p><code>ADMIN</code></p>
+    Admin,
+    /// <p><code>POWERUSER</code></p>
+    Poweruser,
+    /// <p><code>MINIMALUSER</code></p>
+    Minimaluser,
+    /// <p><code>WORKSPACESUSER</code></p>
+    Workspacesuser,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UserType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserType::User => "USER",
+            UserType::Admin => "ADMIN",
+            UserType::Poweruser => "POWERUSER",
+            UserType::Minimaluser => "MINIMALUSER",
+            UserType::Workspacesuser => "WORKSPACESUSER",
+            UserType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UserType {
+    fn from(value: &'a str) -> UserType {
+        match value {
+            "USER" => UserType::User,
+            "ADMIN" => UserType::Admin,
+            "POWERUSER" => UserType::Poweruser,
+            "MINIMALUSER" => UserType::Minimaluser,
+            "WORKSPACESUSER" => UserType::Workspacesuser,
+            _ => UserType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserType {
+    fn from(value: String) -> UserType {
+        UserType::from(value.as_str())
+    }
+}
+
+impl From<UserType> for String {
+    fn from(value: UserType) -> String {
+        match value {
+            UserType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UserType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UserType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UserType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UserType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UserType::from(value))
+    }
 }
 
 /// Errors returned by AbortDocumentVersionUpload
@@ -4872,6 +9593,254 @@ pub trait Workdocs {
         input: UpdateUserRequest,
     ) -> RusotoFuture<UpdateUserResponse, UpdateUserError>;
 }
+impl<T: ?Sized + Workdocs> Workdocs for ::std::sync::Arc<T> {
+    fn abort_document_version_upload(
+        &self,
+        input: AbortDocumentVersionUploadRequest,
+    ) -> RusotoFuture<(), AbortDocumentVersionUploadError> {
+        (**self).abort_document_version_upload(input)
+    }
+    fn activate_user(
+        &self,
+        input: ActivateUserRequest,
+    ) -> RusotoFuture<ActivateUserResponse, ActivateUserError> {
+        (**self).activate_user(input)
+    }
+    fn add_resource_permissions(
+        &self,
+        input: AddResourcePermissionsRequest,
+    ) -> RusotoFuture<AddResourcePermissionsResponse, AddResourcePermissionsError> {
+        (**self).add_resource_permissions(input)
+    }
+    fn create_comment(
+        &self,
+        input: CreateCommentRequest,
+    ) -> RusotoFuture<CreateCommentResponse, CreateCommentError> {
+        (**self).create_comment(input)
+    }
+    fn create_custom_metadata(
+        &self,
+        input: CreateCustomMetadataRequest,
+    ) -> RusotoFuture<CreateCustomMetadataResponse, CreateCustomMetadataError> {
+        (**self).create_custom_metadata(input)
+    }
+    fn create_folder(
+        &self,
+        input: CreateFolderRequest,
+    ) -> RusotoFuture<CreateFolderResponse, CreateFolderError> {
+        (**self).create_folder(input)
+    }
+    fn create_labels(
+        &self,
+        input: CreateLabelsRequest,
+    ) -> RusotoFuture<CreateLabelsResponse, CreateLabelsError> {
+        (**self).create_labels(input)
+    }
+    fn create_notification_subscription(
+        &self,
+        input: CreateNotificationSubscriptionRequest,
+    ) -> RusotoFuture<CreateNotificationSubscriptionResponse, CreateNotificationSubscriptionError>
+    {
+        (**self).create_notification_subscription(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn deactivate_user(
+        &self,
+        input: DeactivateUserRequest,
+    ) -> RusotoFuture<(), DeactivateUserError> {
+        (**self).deactivate_user(input)
+    }
+    fn delete_comment(&self, input: DeleteCommentRequest) -> RusotoFuture<(), DeleteCommentError> {
+        (**self).delete_comment(input)
+    }
+    fn delete_custom_metadata(
+        &self,
+        input: DeleteCustomMetadataRequest,
+    ) -> RusotoFuture<DeleteCustomMetadataResponse, DeleteCustomMetadataError> {
+        (**self).delete_custom_metadata(input)
+    }
+    fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> RusotoFuture<(), DeleteDocumentError> {
+        (**self).delete_document(input)
+    }
+    fn delete_folder(&self, input: DeleteFolderRequest) -> RusotoFuture<(), DeleteFolderError> {
+        (**self).delete_folder(input)
+    }
+    fn delete_folder_contents(
+        &self,
+        input: DeleteFolderContentsRequest,
+    ) -> RusotoFuture<(), DeleteFolderContentsError> {
+        (**self).delete_folder_contents(input)
+    }
+    fn delete_labels(
+        &self,
+        input: DeleteLabelsRequest,
+    ) -> RusotoFuture<DeleteLabelsResponse, DeleteLabelsError> {
+        (**self).delete_labels(input)
+    }
+    fn delete_notification_subscription(
+        &self,
+        input: DeleteNotificationSubscriptionRequest,
+    ) -> RusotoFuture<(), DeleteNotificationSubscriptionError> {
+        (**self).delete_notification_subscription(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_activities(
+        &self,
+        input: DescribeActivitiesRequest,
+    ) -> RusotoFuture<DescribeActivitiesResponse, DescribeActivitiesError> {
+        (**self).describe_activities(input)
+    }
+    fn describe_comments(
+        &self,
+        input: DescribeCommentsRequest,
+    ) -> RusotoFuture<DescribeCommentsResponse, DescribeCommentsError> {
+        (**self).describe_comments(input)
+    }
+    fn describe_document_versions(
+        &self,
+        input: DescribeDocumentVersionsRequest,
+    ) -> RusotoFuture<DescribeDocumentVersionsResponse, DescribeDocumentVersionsError> {
+        (**self).describe_document_versions(input)
+    }
+    fn describe_folder_contents(
+        &self,
+        input: DescribeFolderContentsRequest,
+    ) -> RusotoFuture<DescribeFolderContentsResponse, DescribeFolderContentsError> {
+        (**self).describe_folder_contents(input)
+    }
+    fn describe_groups(
+        &self,
+        input: DescribeGroupsRequest,
+    ) -> RusotoFuture<DescribeGroupsResponse, DescribeGroupsError> {
+        (**self).describe_groups(input)
+    }
+    fn describe_notification_subscriptions(
+        &self,
+        input: DescribeNotificationSubscriptionsRequest,
+    ) -> RusotoFuture<
+        DescribeNotificationSubscriptionsResponse,
+        DescribeNotificationSubscriptionsError,
+    > {
+        (**self).describe_notification_subscriptions(input)
+    }
+    fn describe_resource_permissions(
+        &self,
+        input: DescribeResourcePermissionsRequest,
+    ) -> RusotoFuture<DescribeResourcePermissionsResponse, DescribeResourcePermissionsError> {
+        (**self).describe_resource_permissions(input)
+    }
+    fn describe_root_folders(
+        &self,
+        input: DescribeRootFoldersRequest,
+    ) -> RusotoFuture<DescribeRootFoldersResponse, DescribeRootFoldersError> {
+        (**self).describe_root_folders(input)
+    }
+    fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> RusotoFuture<DescribeUsersResponse, DescribeUsersError> {
+        (**self).describe_users(input)
+    }
+    fn get_current_user(
+        &self,
+        input: GetCurrentUserRequest,
+    ) -> RusotoFuture<GetCurrentUserResponse, GetCurrentUserError> {
+        (**self).get_current_user(input)
+    }
+    fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> RusotoFuture<GetDocumentResponse, GetDocumentError> {
+        (**self).get_document(input)
+    }
+    fn get_document_path(
+        &self,
+        input: GetDocumentPathRequest,
+    ) -> RusotoFuture<GetDocumentPathResponse, GetDocumentPathError> {
+        (**self).get_document_path(input)
+    }
+    fn get_document_version(
+        &self,
+        input: GetDocumentVersionRequest,
+    ) -> RusotoFuture<GetDocumentVersionResponse, GetDocumentVersionError> {
+        (**self).get_document_version(input)
+    }
+    fn get_folder(
+        &self,
+        input: GetFolderRequest,
+    ) -> RusotoFuture<GetFolderResponse, GetFolderError> {
+        (**self).get_folder(input)
+    }
+    fn get_folder_path(
+        &self,
+        input: GetFolderPathRequest,
+    ) -> RusotoFuture<GetFolderPathResponse, GetFolderPathError> {
+        (**self).get_folder_path(input)
+    }
+    fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> RusotoFuture<GetResourcesResponse, GetResourcesError> {
+        (**self).get_resources(input)
+    }
+    fn initiate_document_version_upload(
+        &self,
+        input: InitiateDocumentVersionUploadRequest,
+    ) -> RusotoFuture<InitiateDocumentVersionUploadResponse, InitiateDocumentVersionUploadError>
+    {
+        (**self).initiate_document_version_upload(input)
+    }
+    fn remove_all_resource_permissions(
+        &self,
+        input: RemoveAllResourcePermissionsRequest,
+    ) -> RusotoFuture<(), RemoveAllResourcePermissionsError> {
+        (**self).remove_all_resource_permissions(input)
+    }
+    fn remove_resource_permission(
+        &self,
+        input: RemoveResourcePermissionRequest,
+    ) -> RusotoFuture<(), RemoveResourcePermissionError> {
+        (**self).remove_resource_permission(input)
+    }
+    fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> RusotoFuture<(), UpdateDocumentError> {
+        (**self).update_document(input)
+    }
+    fn update_document_version(
+        &self,
+        input: UpdateDocumentVersionRequest,
+    ) -> RusotoFuture<(), UpdateDocumentVersionError> {
+        (**self).update_document_version(input)
+    }
+    fn update_folder(&self, input: UpdateFolderRequest) -> RusotoFuture<(), UpdateFolderError> {
+        (**self).update_folder(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+/// The error codes Amazon WorkDocs retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon WorkDocs API.
 #[derive(Clone)]
 pub struct WorkdocsClient {
@@ -4887,6 +9856,27 @@ impl WorkdocsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_WORKDOCS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> WorkdocsClient {
+        Self::new(region::Region::from_env_for_service("WORKDOCS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `workdocs`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> WorkdocsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("workdocs", "WORKDOCS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4905,7 +9895,54 @@ impl WorkdocsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> WorkdocsClient {
-        WorkdocsClient { client, region }
+        WorkdocsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "workdocs")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "workdocs",
+            "workdocs",
+            "WORKDOCS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Workdocs` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Workdocs + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for WorkdocsClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon WorkDocs"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "workdocs"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> WorkdocsClient {
+        Self::new_with_client(client, region)
     }
 }
 
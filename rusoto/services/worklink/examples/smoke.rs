@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon WorkLink clients.
+//!
+//! Makes a single read-only `ListFleets` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_worklink::{ListFleetsRequest, Worklink, WorklinkClient};
+
+fn main() {
+    let client = WorklinkClient::from_env();
+    match client.list_fleets(ListFleetsRequest::default()).sync() {
+        Ok(_) => println!("ListFleets succeeded"),
+        Err(error) => {
+            eprintln!("ListFleets failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
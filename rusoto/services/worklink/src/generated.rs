@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateDomainRequest {
@@ -40,15 +41,73 @@ pub struct AssociateDomainRequest {
     pub fleet_arn: String,
 }
 
+impl AssociateDomainRequest {
+    /// Returns a builder for `AssociateDomainRequest`, checking its required members when built.
+    pub fn builder() -> AssociateDomainRequestBuilder {
+        AssociateDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateDomainRequest`](struct.AssociateDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateDomainRequestBuilder {
+    acm_certificate_arn: Option<String>,
+    display_name: Option<String>,
+    domain_name: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl AssociateDomainRequestBuilder {
+    /// Sets the [`acm_certificate_arn`](struct.AssociateDomainRequest.html#structfield.acm_certificate_arn) member.
+    pub fn acm_certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.acm_certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.AssociateDomainRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.AssociateDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.AssociateDomainRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AssociateDomainRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateDomainRequest {
+            acm_certificate_arn: self
+                .acm_certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("acm_certificate_arn"))?,
+            display_name: self.display_name,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateDomainResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateWebsiteAuthorizationProviderRequest {
     /// <p>The authorization provider type.</p>
     #[serde(rename = "AuthorizationProviderType")]
-    pub authorization_provider_type: String,
+    pub authorization_provider_type: AuthorizationProviderType,
     /// <p>The domain name of the authorization provider. This applies only to SAML-based authorization providers.</p>
     #[serde(rename = "DomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -58,6 +117,62 @@ pub struct AssociateWebsiteAuthorizationProviderRequest {
     pub fleet_arn: String,
 }
 
+impl AssociateWebsiteAuthorizationProviderRequest {
+    /// Returns a builder for `AssociateWebsiteAuthorizationProviderRequest`, checking its required members when built.
+    pub fn builder() -> AssociateWebsiteAuthorizationProviderRequestBuilder {
+        AssociateWebsiteAuthorizationProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateWebsiteAuthorizationProviderRequest`](struct.AssociateWebsiteAuthorizationProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateWebsiteAuthorizationProviderRequestBuilder {
+    authorization_provider_type: Option<AuthorizationProviderType>,
+    domain_name: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl AssociateWebsiteAuthorizationProviderRequestBuilder {
+    /// Sets the [`authorization_provider_type`](struct.AssociateWebsiteAuthorizationProviderRequest.html#structfield.authorization_provider_type) member.
+    pub fn authorization_provider_type<T: Into<AuthorizationProviderType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.authorization_provider_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.AssociateWebsiteAuthorizationProviderRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.AssociateWebsiteAuthorizationProviderRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateWebsiteAuthorizationProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        AssociateWebsiteAuthorizationProviderRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(AssociateWebsiteAuthorizationProviderRequest {
+            authorization_provider_type: self.authorization_provider_type.ok_or(
+                ::rusoto_core::BuildError::missing("authorization_provider_type"),
+            )?,
+            domain_name: self.domain_name,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateWebsiteAuthorizationProviderResponse {
@@ -81,6 +196,57 @@ pub struct AssociateWebsiteCertificateAuthorityRequest {
     pub fleet_arn: String,
 }
 
+impl AssociateWebsiteCertificateAuthorityRequest {
+    /// Returns a builder for `AssociateWebsiteCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> AssociateWebsiteCertificateAuthorityRequestBuilder {
+        AssociateWebsiteCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateWebsiteCertificateAuthorityRequest`](struct.AssociateWebsiteCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateWebsiteCertificateAuthorityRequestBuilder {
+    certificate: Option<String>,
+    display_name: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl AssociateWebsiteCertificateAuthorityRequestBuilder {
+    /// Sets the [`certificate`](struct.AssociateWebsiteCertificateAuthorityRequest.html#structfield.certificate) member.
+    pub fn certificate<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.AssociateWebsiteCertificateAuthorityRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.AssociateWebsiteCertificateAuthorityRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateWebsiteCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateWebsiteCertificateAuthorityRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AssociateWebsiteCertificateAuthorityRequest {
+            certificate: self
+                .certificate
+                .ok_or(::rusoto_core::BuildError::missing("certificate"))?,
+            display_name: self.display_name,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateWebsiteCertificateAuthorityResponse {
@@ -90,6 +256,80 @@ pub struct AssociateWebsiteCertificateAuthorityResponse {
     pub website_ca_id: Option<String>,
 }
 
+/// <p>The authorization provider type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthorizationProviderType {
+    /// <p><code>SAML</code></p>
+    Saml,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthorizationProviderType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizationProviderType::Saml => "SAML",
+            AuthorizationProviderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizationProviderType {
+    fn from(value: &'a str) -> AuthorizationProviderType {
+        match value {
+            "SAML" => AuthorizationProviderType::Saml,
+            _ => AuthorizationProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizationProviderType {
+    fn from(value: String) -> AuthorizationProviderType {
+        AuthorizationProviderType::from(value.as_str())
+    }
+}
+
+impl From<AuthorizationProviderType> for String {
+    fn from(value: AuthorizationProviderType) -> String {
+        match value {
+            AuthorizationProviderType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthorizationProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthorizationProviderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizationProviderType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizationProviderType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthorizationProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthorizationProviderType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateFleetRequest {
     /// <p>The fleet name to display.</p>
@@ -105,6 +345,52 @@ pub struct CreateFleetRequest {
     pub optimize_for_end_user_location: Option<bool>,
 }
 
+impl CreateFleetRequest {
+    /// Returns a builder for `CreateFleetRequest`, checking its required members when built.
+    pub fn builder() -> CreateFleetRequestBuilder {
+        CreateFleetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFleetRequest`](struct.CreateFleetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFleetRequestBuilder {
+    display_name: Option<String>,
+    fleet_name: Option<String>,
+    optimize_for_end_user_location: Option<bool>,
+}
+
+impl CreateFleetRequestBuilder {
+    /// Sets the [`display_name`](struct.CreateFleetRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_name`](struct.CreateFleetRequest.html#structfield.fleet_name) member.
+    pub fn fleet_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`optimize_for_end_user_location`](struct.CreateFleetRequest.html#structfield.optimize_for_end_user_location) member.
+    pub fn optimize_for_end_user_location<T: Into<bool>>(mut self, value: T) -> Self {
+        self.optimize_for_end_user_location = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFleetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateFleetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateFleetRequest {
+            display_name: self.display_name,
+            fleet_name: self
+                .fleet_name
+                .ok_or(::rusoto_core::BuildError::missing("fleet_name"))?,
+            optimize_for_end_user_location: self.optimize_for_end_user_location,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateFleetResponse {
@@ -121,6 +407,36 @@ pub struct DeleteFleetRequest {
     pub fleet_arn: String,
 }
 
+impl DeleteFleetRequest {
+    /// Returns a builder for `DeleteFleetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFleetRequestBuilder {
+        DeleteFleetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFleetRequest`](struct.DeleteFleetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFleetRequestBuilder {
+    fleet_arn: Option<String>,
+}
+
+impl DeleteFleetRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.DeleteFleetRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFleetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteFleetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFleetRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteFleetResponse {}
@@ -132,6 +448,39 @@ pub struct DescribeAuditStreamConfigurationRequest {
     pub fleet_arn: String,
 }
 
+impl DescribeAuditStreamConfigurationRequest {
+    /// Returns a builder for `DescribeAuditStreamConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAuditStreamConfigurationRequestBuilder {
+        DescribeAuditStreamConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAuditStreamConfigurationRequest`](struct.DescribeAuditStreamConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAuditStreamConfigurationRequestBuilder {
+    fleet_arn: Option<String>,
+}
+
+impl DescribeAuditStreamConfigurationRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.DescribeAuditStreamConfigurationRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAuditStreamConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAuditStreamConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeAuditStreamConfigurationRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAuditStreamConfigurationResponse {
@@ -148,6 +497,39 @@ pub struct DescribeCompanyNetworkConfigurationRequest {
     pub fleet_arn: String,
 }
 
+impl DescribeCompanyNetworkConfigurationRequest {
+    /// Returns a builder for `DescribeCompanyNetworkConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCompanyNetworkConfigurationRequestBuilder {
+        DescribeCompanyNetworkConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCompanyNetworkConfigurationRequest`](struct.DescribeCompanyNetworkConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCompanyNetworkConfigurationRequestBuilder {
+    fleet_arn: Option<String>,
+}
+
+impl DescribeCompanyNetworkConfigurationRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.DescribeCompanyNetworkConfigurationRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCompanyNetworkConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCompanyNetworkConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeCompanyNetworkConfigurationRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeCompanyNetworkConfigurationResponse {
@@ -172,6 +554,39 @@ pub struct DescribeDevicePolicyConfigurationRequest {
     pub fleet_arn: String,
 }
 
+impl DescribeDevicePolicyConfigurationRequest {
+    /// Returns a builder for `DescribeDevicePolicyConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDevicePolicyConfigurationRequestBuilder {
+        DescribeDevicePolicyConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDevicePolicyConfigurationRequest`](struct.DescribeDevicePolicyConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDevicePolicyConfigurationRequestBuilder {
+    fleet_arn: Option<String>,
+}
+
+impl DescribeDevicePolicyConfigurationRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.DescribeDevicePolicyConfigurationRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDevicePolicyConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDevicePolicyConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDevicePolicyConfigurationRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDevicePolicyConfigurationResponse {
@@ -191,17 +606,59 @@ pub struct DescribeDeviceRequest {
     pub fleet_arn: String,
 }
 
+impl DescribeDeviceRequest {
+    /// Returns a builder for `DescribeDeviceRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDeviceRequestBuilder {
+        DescribeDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDeviceRequest`](struct.DescribeDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDeviceRequestBuilder {
+    device_id: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl DescribeDeviceRequestBuilder {
+    /// Sets the [`device_id`](struct.DescribeDeviceRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.DescribeDeviceRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDeviceRequest {
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDeviceResponse {
     /// <p>The date that the device first signed in to Amazon WorkLink.</p>
     #[serde(rename = "FirstAccessedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_accessed_time: Option<f64>,
+    pub first_accessed_time: Option<EpochTimestamp>,
     /// <p>The date that the device last accessed Amazon WorkLink.</p>
     #[serde(rename = "LastAccessedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_accessed_time: Option<f64>,
+    pub last_accessed_time: Option<EpochTimestamp>,
     /// <p>The manufacturer of the device.</p>
     #[serde(rename = "Manufacturer")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -225,7 +682,7 @@ pub struct DescribeDeviceResponse {
     /// <p>The current state of the device.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeviceStatus>,
     /// <p>The user name associated with the device.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -242,6 +699,46 @@ pub struct DescribeDomainRequest {
     pub fleet_arn: String,
 }
 
+impl DescribeDomainRequest {
+    /// Returns a builder for `DescribeDomainRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDomainRequestBuilder {
+        DescribeDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDomainRequest`](struct.DescribeDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDomainRequestBuilder {
+    domain_name: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl DescribeDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.DescribeDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.DescribeDomainRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeDomainRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDomainResponse {
@@ -251,8 +748,9 @@ pub struct DescribeDomainResponse {
     pub acm_certificate_arn: Option<String>,
     /// <p>The time that the domain was added.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -264,7 +762,7 @@ pub struct DescribeDomainResponse {
     /// <p>The current state for the domain.</p>
     #[serde(rename = "DomainStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_status: Option<String>,
+    pub domain_status: Option<DomainStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -274,6 +772,38 @@ pub struct DescribeFleetMetadataRequest {
     pub fleet_arn: String,
 }
 
+impl DescribeFleetMetadataRequest {
+    /// Returns a builder for `DescribeFleetMetadataRequest`, checking its required members when built.
+    pub fn builder() -> DescribeFleetMetadataRequestBuilder {
+        DescribeFleetMetadataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeFleetMetadataRequest`](struct.DescribeFleetMetadataRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeFleetMetadataRequestBuilder {
+    fleet_arn: Option<String>,
+}
+
+impl DescribeFleetMetadataRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.DescribeFleetMetadataRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeFleetMetadataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeFleetMetadataRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeFleetMetadataRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeFleetMetadataResponse {
@@ -283,8 +813,9 @@ pub struct DescribeFleetMetadataResponse {
     pub company_code: Option<String>,
     /// <p>The time that the fleet was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -296,11 +827,12 @@ pub struct DescribeFleetMetadataResponse {
     /// <p>The current state of the fleet.</p>
     #[serde(rename = "FleetStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fleet_status: Option<String>,
+    pub fleet_status: Option<FleetStatus>,
     /// <p>The time that the fleet was last updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
     /// <p>The option to optimize for better performance by routing traffic through the closest AWS Region to users, which may be outside of your home Region.</p>
     #[serde(rename = "OptimizeForEndUserLocation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -314,6 +846,41 @@ pub struct DescribeIdentityProviderConfigurationRequest {
     pub fleet_arn: String,
 }
 
+impl DescribeIdentityProviderConfigurationRequest {
+    /// Returns a builder for `DescribeIdentityProviderConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeIdentityProviderConfigurationRequestBuilder {
+        DescribeIdentityProviderConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeIdentityProviderConfigurationRequest`](struct.DescribeIdentityProviderConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeIdentityProviderConfigurationRequestBuilder {
+    fleet_arn: Option<String>,
+}
+
+impl DescribeIdentityProviderConfigurationRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.DescribeIdentityProviderConfigurationRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeIdentityProviderConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeIdentityProviderConfigurationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeIdentityProviderConfigurationRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeIdentityProviderConfigurationResponse {
@@ -324,7 +891,7 @@ pub struct DescribeIdentityProviderConfigurationResponse {
     /// <p>The type of identity provider.</p>
     #[serde(rename = "IdentityProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub identity_provider_type: Option<String>,
+    pub identity_provider_type: Option<IdentityProviderType>,
     /// <p>The SAML metadata document uploaded to the user’s identity provider.</p>
     #[serde(rename = "ServiceProviderSamlMetadata")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -341,6 +908,49 @@ pub struct DescribeWebsiteCertificateAuthorityRequest {
     pub website_ca_id: String,
 }
 
+impl DescribeWebsiteCertificateAuthorityRequest {
+    /// Returns a builder for `DescribeWebsiteCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> DescribeWebsiteCertificateAuthorityRequestBuilder {
+        DescribeWebsiteCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeWebsiteCertificateAuthorityRequest`](struct.DescribeWebsiteCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeWebsiteCertificateAuthorityRequestBuilder {
+    fleet_arn: Option<String>,
+    website_ca_id: Option<String>,
+}
+
+impl DescribeWebsiteCertificateAuthorityRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.DescribeWebsiteCertificateAuthorityRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`website_ca_id`](struct.DescribeWebsiteCertificateAuthorityRequest.html#structfield.website_ca_id) member.
+    pub fn website_ca_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.website_ca_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeWebsiteCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeWebsiteCertificateAuthorityRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeWebsiteCertificateAuthorityRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+            website_ca_id: self
+                .website_ca_id
+                .ok_or(::rusoto_core::BuildError::missing("website_ca_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeWebsiteCertificateAuthorityResponse {
@@ -350,14 +960,93 @@ pub struct DescribeWebsiteCertificateAuthorityResponse {
     pub certificate: Option<String>,
     /// <p>The time that the certificate authority was added.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The certificate name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
 }
 
+/// <p>The current state of the device.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>SIGNED_OUT</code></p>
+    SignedOut,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceStatus::Active => "ACTIVE",
+            DeviceStatus::SignedOut => "SIGNED_OUT",
+            DeviceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceStatus {
+    fn from(value: &'a str) -> DeviceStatus {
+        match value {
+            "ACTIVE" => DeviceStatus::Active,
+            "SIGNED_OUT" => DeviceStatus::SignedOut,
+            _ => DeviceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceStatus {
+    fn from(value: String) -> DeviceStatus {
+        DeviceStatus::from(value.as_str())
+    }
+}
+
+impl From<DeviceStatus> for String {
+    fn from(value: DeviceStatus) -> String {
+        match value {
+            DeviceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeviceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceStatus::from(value))
+    }
+}
+
 /// <p>The summary of devices.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -369,7 +1058,7 @@ pub struct DeviceSummary {
     /// <p>The status of the device.</p>
     #[serde(rename = "DeviceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_status: Option<String>,
+    pub device_status: Option<DeviceStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -382,20 +1071,107 @@ pub struct DisassociateDomainRequest {
     pub fleet_arn: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DisassociateDomainResponse {}
+impl DisassociateDomainRequest {
+    /// Returns a builder for `DisassociateDomainRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateDomainRequestBuilder {
+        DisassociateDomainRequestBuilder::default()
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DisassociateWebsiteAuthorizationProviderRequest {
-    /// <p>A unique identifier for the authorization provider.</p>
-    #[serde(rename = "AuthorizationProviderId")]
-    pub authorization_provider_id: String,
-    /// <p>The ARN of the fleet.</p>
+/// Builds a [`DisassociateDomainRequest`](struct.DisassociateDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateDomainRequestBuilder {
+    domain_name: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl DisassociateDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.DisassociateDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.DisassociateDomainRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateDomainRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DisassociateDomainResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DisassociateWebsiteAuthorizationProviderRequest {
+    /// <p>A unique identifier for the authorization provider.</p>
+    #[serde(rename = "AuthorizationProviderId")]
+    pub authorization_provider_id: String,
+    /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
     pub fleet_arn: String,
 }
 
+impl DisassociateWebsiteAuthorizationProviderRequest {
+    /// Returns a builder for `DisassociateWebsiteAuthorizationProviderRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateWebsiteAuthorizationProviderRequestBuilder {
+        DisassociateWebsiteAuthorizationProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateWebsiteAuthorizationProviderRequest`](struct.DisassociateWebsiteAuthorizationProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateWebsiteAuthorizationProviderRequestBuilder {
+    authorization_provider_id: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl DisassociateWebsiteAuthorizationProviderRequestBuilder {
+    /// Sets the [`authorization_provider_id`](struct.DisassociateWebsiteAuthorizationProviderRequest.html#structfield.authorization_provider_id) member.
+    pub fn authorization_provider_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorization_provider_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.DisassociateWebsiteAuthorizationProviderRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateWebsiteAuthorizationProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DisassociateWebsiteAuthorizationProviderRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DisassociateWebsiteAuthorizationProviderRequest {
+            authorization_provider_id: self.authorization_provider_id.ok_or(
+                ::rusoto_core::BuildError::missing("authorization_provider_id"),
+            )?,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateWebsiteAuthorizationProviderResponse {}
@@ -410,17 +1186,165 @@ pub struct DisassociateWebsiteCertificateAuthorityRequest {
     pub website_ca_id: String,
 }
 
+impl DisassociateWebsiteCertificateAuthorityRequest {
+    /// Returns a builder for `DisassociateWebsiteCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateWebsiteCertificateAuthorityRequestBuilder {
+        DisassociateWebsiteCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateWebsiteCertificateAuthorityRequest`](struct.DisassociateWebsiteCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateWebsiteCertificateAuthorityRequestBuilder {
+    fleet_arn: Option<String>,
+    website_ca_id: Option<String>,
+}
+
+impl DisassociateWebsiteCertificateAuthorityRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.DisassociateWebsiteCertificateAuthorityRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`website_ca_id`](struct.DisassociateWebsiteCertificateAuthorityRequest.html#structfield.website_ca_id) member.
+    pub fn website_ca_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.website_ca_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateWebsiteCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DisassociateWebsiteCertificateAuthorityRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DisassociateWebsiteCertificateAuthorityRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+            website_ca_id: self
+                .website_ca_id
+                .ok_or(::rusoto_core::BuildError::missing("website_ca_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateWebsiteCertificateAuthorityResponse {}
 
+/// <p>The current state for the domain.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomainStatus {
+    /// <p><code>PENDING_VALIDATION</code></p>
+    PendingValidation,
+    /// <p><code>ASSOCIATING</code></p>
+    Associating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>DISASSOCIATING</code></p>
+    Disassociating,
+    /// <p><code>DISASSOCIATED</code></p>
+    Disassociated,
+    /// <p><code>FAILED_TO_ASSOCIATE</code></p>
+    FailedToAssociate,
+    /// <p><code>FAILED_TO_DISASSOCIATE</code></p>
+    FailedToDisassociate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DomainStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainStatus::PendingValidation => "PENDING_VALIDATION",
+            DomainStatus::Associating => "ASSOCIATING",
+            DomainStatus::Active => "ACTIVE",
+            DomainStatus::Inactive => "INACTIVE",
+            DomainStatus::Disassociating => "DISASSOCIATING",
+            DomainStatus::Disassociated => "DISASSOCIATED",
+            DomainStatus::FailedToAssociate => "FAILED_TO_ASSOCIATE",
+            DomainStatus::FailedToDisassociate => "FAILED_TO_DISASSOCIATE",
+            DomainStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DomainStatus {
+    fn from(value: &'a str) -> DomainStatus {
+        match value {
+            "PENDING_VALIDATION" => DomainStatus::PendingValidation,
+            "ASSOCIATING" => DomainStatus::Associating,
+            "ACTIVE" => DomainStatus::Active,
+            "INACTIVE" => DomainStatus::Inactive,
+            "DISASSOCIATING" => DomainStatus::Disassociating,
+            "DISASSOCIATED" => DomainStatus::Disassociated,
+            "FAILED_TO_ASSOCIATE" => DomainStatus::FailedToAssociate,
+            "FAILED_TO_DISASSOCIATE" => DomainStatus::FailedToDisassociate,
+            _ => DomainStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainStatus {
+    fn from(value: String) -> DomainStatus {
+        DomainStatus::from(value.as_str())
+    }
+}
+
+impl From<DomainStatus> for String {
+    fn from(value: DomainStatus) -> String {
+        match value {
+            DomainStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DomainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DomainStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DomainStatus::from(value))
+    }
+}
+
 /// <p>The summary of the domain.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DomainSummary {
     /// <p>The time that the domain was created.</p>
     #[serde(rename = "CreatedTime")]
-    pub created_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_time: EpochTimestamp,
     /// <p>The name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -430,7 +1354,101 @@ pub struct DomainSummary {
     pub domain_name: String,
     /// <p>The status of the domain.</p>
     #[serde(rename = "DomainStatus")]
-    pub domain_status: String,
+    pub domain_status: DomainStatus,
+}
+
+/// <p>The current state of the fleet.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FleetStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// <p><code>FAILED_TO_CREATE</code></p>
+    FailedToCreate,
+    /// <p><code>FAILED_TO_DELETE</code></p>
+    FailedToDelete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FleetStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetStatus::Creating => "CREATING",
+            FleetStatus::Active => "ACTIVE",
+            FleetStatus::Deleting => "DELETING",
+            FleetStatus::Deleted => "DELETED",
+            FleetStatus::FailedToCreate => "FAILED_TO_CREATE",
+            FleetStatus::FailedToDelete => "FAILED_TO_DELETE",
+            FleetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FleetStatus {
+    fn from(value: &'a str) -> FleetStatus {
+        match value {
+            "CREATING" => FleetStatus::Creating,
+            "ACTIVE" => FleetStatus::Active,
+            "DELETING" => FleetStatus::Deleting,
+            "DELETED" => FleetStatus::Deleted,
+            "FAILED_TO_CREATE" => FleetStatus::FailedToCreate,
+            "FAILED_TO_DELETE" => FleetStatus::FailedToDelete,
+            _ => FleetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetStatus {
+    fn from(value: String) -> FleetStatus {
+        FleetStatus::from(value.as_str())
+    }
+}
+
+impl From<FleetStatus> for String {
+    fn from(value: FleetStatus) -> String {
+        match value {
+            FleetStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FleetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FleetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FleetStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FleetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FleetStatus::from(value))
+    }
 }
 
 /// <p>The summary of the fleet.</p>
@@ -443,8 +1461,9 @@ pub struct FleetSummary {
     pub company_code: Option<String>,
     /// <p>The time when the fleet was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -460,11 +1479,86 @@ pub struct FleetSummary {
     /// <p>The status of the fleet.</p>
     #[serde(rename = "FleetStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fleet_status: Option<String>,
+    pub fleet_status: Option<FleetStatus>,
     /// <p>The time when the fleet was last updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
+}
+
+/// <p>The type of identity provider.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IdentityProviderType {
+    /// <p><code>SAML</code></p>
+    Saml,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IdentityProviderType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IdentityProviderType::Saml => "SAML",
+            IdentityProviderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IdentityProviderType {
+    fn from(value: &'a str) -> IdentityProviderType {
+        match value {
+            "SAML" => IdentityProviderType::Saml,
+            _ => IdentityProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IdentityProviderType {
+    fn from(value: String) -> IdentityProviderType {
+        IdentityProviderType::from(value.as_str())
+    }
+}
+
+impl From<IdentityProviderType> for String {
+    fn from(value: IdentityProviderType) -> String {
+        match value {
+            IdentityProviderType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IdentityProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IdentityProviderType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IdentityProviderType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IdentityProviderType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IdentityProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IdentityProviderType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -482,6 +1576,52 @@ pub struct ListDevicesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDevicesRequest {
+    /// Returns a builder for `ListDevicesRequest`, checking its required members when built.
+    pub fn builder() -> ListDevicesRequestBuilder {
+        ListDevicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDevicesRequest`](struct.ListDevicesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDevicesRequestBuilder {
+    fleet_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDevicesRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.ListDevicesRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDevicesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDevicesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDevicesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDevicesRequest, ::rusoto_core::BuildError> {
+        Ok(ListDevicesRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDevicesResponse {
@@ -510,6 +1650,52 @@ pub struct ListDomainsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDomainsRequest {
+    /// Returns a builder for `ListDomainsRequest`, checking its required members when built.
+    pub fn builder() -> ListDomainsRequestBuilder {
+        ListDomainsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDomainsRequest`](struct.ListDomainsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDomainsRequestBuilder {
+    fleet_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDomainsRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.ListDomainsRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDomainsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDomainsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDomainsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDomainsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDomainsRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDomainsResponse {
@@ -535,6 +1721,42 @@ pub struct ListFleetsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListFleetsRequest {
+    /// Returns a builder for `ListFleetsRequest`, checking its required members when built.
+    pub fn builder() -> ListFleetsRequestBuilder {
+        ListFleetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFleetsRequest`](struct.ListFleetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFleetsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListFleetsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListFleetsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListFleetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFleetsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListFleetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListFleetsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFleetsResponse {
@@ -563,6 +1785,55 @@ pub struct ListWebsiteAuthorizationProvidersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListWebsiteAuthorizationProvidersRequest {
+    /// Returns a builder for `ListWebsiteAuthorizationProvidersRequest`, checking its required members when built.
+    pub fn builder() -> ListWebsiteAuthorizationProvidersRequestBuilder {
+        ListWebsiteAuthorizationProvidersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListWebsiteAuthorizationProvidersRequest`](struct.ListWebsiteAuthorizationProvidersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListWebsiteAuthorizationProvidersRequestBuilder {
+    fleet_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListWebsiteAuthorizationProvidersRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.ListWebsiteAuthorizationProvidersRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListWebsiteAuthorizationProvidersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListWebsiteAuthorizationProvidersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListWebsiteAuthorizationProvidersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListWebsiteAuthorizationProvidersRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListWebsiteAuthorizationProvidersRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListWebsiteAuthorizationProvidersResponse {
@@ -591,6 +1862,55 @@ pub struct ListWebsiteCertificateAuthoritiesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListWebsiteCertificateAuthoritiesRequest {
+    /// Returns a builder for `ListWebsiteCertificateAuthoritiesRequest`, checking its required members when built.
+    pub fn builder() -> ListWebsiteCertificateAuthoritiesRequestBuilder {
+        ListWebsiteCertificateAuthoritiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListWebsiteCertificateAuthoritiesRequest`](struct.ListWebsiteCertificateAuthoritiesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListWebsiteCertificateAuthoritiesRequestBuilder {
+    fleet_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListWebsiteCertificateAuthoritiesRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.ListWebsiteCertificateAuthoritiesRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListWebsiteCertificateAuthoritiesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListWebsiteCertificateAuthoritiesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListWebsiteCertificateAuthoritiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListWebsiteCertificateAuthoritiesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListWebsiteCertificateAuthoritiesRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListWebsiteCertificateAuthoritiesResponse {
@@ -614,6 +1934,48 @@ pub struct RestoreDomainAccessRequest {
     pub fleet_arn: String,
 }
 
+impl RestoreDomainAccessRequest {
+    /// Returns a builder for `RestoreDomainAccessRequest`, checking its required members when built.
+    pub fn builder() -> RestoreDomainAccessRequestBuilder {
+        RestoreDomainAccessRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreDomainAccessRequest`](struct.RestoreDomainAccessRequest.html).
+#[derive(Default, Debug)]
+pub struct RestoreDomainAccessRequestBuilder {
+    domain_name: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl RestoreDomainAccessRequestBuilder {
+    /// Sets the [`domain_name`](struct.RestoreDomainAccessRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.RestoreDomainAccessRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreDomainAccessRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreDomainAccessRequest, ::rusoto_core::BuildError> {
+        Ok(RestoreDomainAccessRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RestoreDomainAccessResponse {}
@@ -628,6 +1990,48 @@ pub struct RevokeDomainAccessRequest {
     pub fleet_arn: String,
 }
 
+impl RevokeDomainAccessRequest {
+    /// Returns a builder for `RevokeDomainAccessRequest`, checking its required members when built.
+    pub fn builder() -> RevokeDomainAccessRequestBuilder {
+        RevokeDomainAccessRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RevokeDomainAccessRequest`](struct.RevokeDomainAccessRequest.html).
+#[derive(Default, Debug)]
+pub struct RevokeDomainAccessRequestBuilder {
+    domain_name: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl RevokeDomainAccessRequestBuilder {
+    /// Sets the [`domain_name`](struct.RevokeDomainAccessRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.RevokeDomainAccessRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RevokeDomainAccessRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RevokeDomainAccessRequest, ::rusoto_core::BuildError> {
+        Ok(RevokeDomainAccessRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RevokeDomainAccessResponse {}
@@ -642,6 +2046,46 @@ pub struct SignOutUserRequest {
     pub username: String,
 }
 
+impl SignOutUserRequest {
+    /// Returns a builder for `SignOutUserRequest`, checking its required members when built.
+    pub fn builder() -> SignOutUserRequestBuilder {
+        SignOutUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SignOutUserRequest`](struct.SignOutUserRequest.html).
+#[derive(Default, Debug)]
+pub struct SignOutUserRequestBuilder {
+    fleet_arn: Option<String>,
+    username: Option<String>,
+}
+
+impl SignOutUserRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.SignOutUserRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.SignOutUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `SignOutUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SignOutUserRequest, ::rusoto_core::BuildError> {
+        Ok(SignOutUserRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SignOutUserResponse {}
@@ -657,6 +2101,47 @@ pub struct UpdateAuditStreamConfigurationRequest {
     pub fleet_arn: String,
 }
 
+impl UpdateAuditStreamConfigurationRequest {
+    /// Returns a builder for `UpdateAuditStreamConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAuditStreamConfigurationRequestBuilder {
+        UpdateAuditStreamConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAuditStreamConfigurationRequest`](struct.UpdateAuditStreamConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAuditStreamConfigurationRequestBuilder {
+    audit_stream_arn: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl UpdateAuditStreamConfigurationRequestBuilder {
+    /// Sets the [`audit_stream_arn`](struct.UpdateAuditStreamConfigurationRequest.html#structfield.audit_stream_arn) member.
+    pub fn audit_stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.audit_stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.UpdateAuditStreamConfigurationRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAuditStreamConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAuditStreamConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateAuditStreamConfigurationRequest {
+            audit_stream_arn: self.audit_stream_arn,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateAuditStreamConfigurationResponse {}
@@ -677,6 +2162,69 @@ pub struct UpdateCompanyNetworkConfigurationRequest {
     pub vpc_id: String,
 }
 
+impl UpdateCompanyNetworkConfigurationRequest {
+    /// Returns a builder for `UpdateCompanyNetworkConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateCompanyNetworkConfigurationRequestBuilder {
+        UpdateCompanyNetworkConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCompanyNetworkConfigurationRequest`](struct.UpdateCompanyNetworkConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateCompanyNetworkConfigurationRequestBuilder {
+    fleet_arn: Option<String>,
+    security_group_ids: Option<Vec<String>>,
+    subnet_ids: Option<Vec<String>>,
+    vpc_id: Option<String>,
+}
+
+impl UpdateCompanyNetworkConfigurationRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.UpdateCompanyNetworkConfigurationRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_group_ids`](struct.UpdateCompanyNetworkConfigurationRequest.html#structfield.security_group_ids) member.
+    pub fn security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.UpdateCompanyNetworkConfigurationRequest.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_id`](struct.UpdateCompanyNetworkConfigurationRequest.html#structfield.vpc_id) member.
+    pub fn vpc_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpc_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCompanyNetworkConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateCompanyNetworkConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateCompanyNetworkConfigurationRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+            security_group_ids: self
+                .security_group_ids
+                .ok_or(::rusoto_core::BuildError::missing("security_group_ids"))?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+            vpc_id: self
+                .vpc_id
+                .ok_or(::rusoto_core::BuildError::missing("vpc_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateCompanyNetworkConfigurationResponse {}
@@ -692,6 +2240,47 @@ pub struct UpdateDevicePolicyConfigurationRequest {
     pub fleet_arn: String,
 }
 
+impl UpdateDevicePolicyConfigurationRequest {
+    /// Returns a builder for `UpdateDevicePolicyConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDevicePolicyConfigurationRequestBuilder {
+        UpdateDevicePolicyConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDevicePolicyConfigurationRequest`](struct.UpdateDevicePolicyConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDevicePolicyConfigurationRequestBuilder {
+    device_ca_certificate: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl UpdateDevicePolicyConfigurationRequestBuilder {
+    /// Sets the [`device_ca_certificate`](struct.UpdateDevicePolicyConfigurationRequest.html#structfield.device_ca_certificate) member.
+    pub fn device_ca_certificate<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_ca_certificate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.UpdateDevicePolicyConfigurationRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDevicePolicyConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDevicePolicyConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateDevicePolicyConfigurationRequest {
+            device_ca_certificate: self.device_ca_certificate,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDevicePolicyConfigurationResponse {}
@@ -710,6 +2299,56 @@ pub struct UpdateDomainMetadataRequest {
     pub fleet_arn: String,
 }
 
+impl UpdateDomainMetadataRequest {
+    /// Returns a builder for `UpdateDomainMetadataRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDomainMetadataRequestBuilder {
+        UpdateDomainMetadataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDomainMetadataRequest`](struct.UpdateDomainMetadataRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDomainMetadataRequestBuilder {
+    display_name: Option<String>,
+    domain_name: Option<String>,
+    fleet_arn: Option<String>,
+}
+
+impl UpdateDomainMetadataRequestBuilder {
+    /// Sets the [`display_name`](struct.UpdateDomainMetadataRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.UpdateDomainMetadataRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.UpdateDomainMetadataRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDomainMetadataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDomainMetadataRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDomainMetadataRequest {
+            display_name: self.display_name,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDomainMetadataResponse {}
@@ -729,11 +2368,59 @@ pub struct UpdateFleetMetadataRequest {
     pub optimize_for_end_user_location: Option<bool>,
 }
 
+impl UpdateFleetMetadataRequest {
+    /// Returns a builder for `UpdateFleetMetadataRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFleetMetadataRequestBuilder {
+        UpdateFleetMetadataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFleetMetadataRequest`](struct.UpdateFleetMetadataRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFleetMetadataRequestBuilder {
+    display_name: Option<String>,
+    fleet_arn: Option<String>,
+    optimize_for_end_user_location: Option<bool>,
+}
+
+impl UpdateFleetMetadataRequestBuilder {
+    /// Sets the [`display_name`](struct.UpdateFleetMetadataRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_arn`](struct.UpdateFleetMetadataRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`optimize_for_end_user_location`](struct.UpdateFleetMetadataRequest.html#structfield.optimize_for_end_user_location) member.
+    pub fn optimize_for_end_user_location<T: Into<bool>>(mut self, value: T) -> Self {
+        self.optimize_for_end_user_location = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFleetMetadataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateFleetMetadataRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFleetMetadataRequest {
+            display_name: self.display_name,
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+            optimize_for_end_user_location: self.optimize_for_end_user_location,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateFleetMetadataResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateIdentityProviderConfigurationRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
@@ -744,7 +2431,58 @@ pub struct UpdateIdentityProviderConfigurationRequest {
     pub identity_provider_saml_metadata: Option<String>,
     /// <p>The type of identity provider.</p>
     #[serde(rename = "IdentityProviderType")]
-    pub identity_provider_type: String,
+    pub identity_provider_type: IdentityProviderType,
+}
+
+impl UpdateIdentityProviderConfigurationRequest {
+    /// Returns a builder for `UpdateIdentityProviderConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateIdentityProviderConfigurationRequestBuilder {
+        UpdateIdentityProviderConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateIdentityProviderConfigurationRequest`](struct.UpdateIdentityProviderConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateIdentityProviderConfigurationRequestBuilder {
+    fleet_arn: Option<String>,
+    identity_provider_saml_metadata: Option<String>,
+    identity_provider_type: Option<IdentityProviderType>,
+}
+
+impl UpdateIdentityProviderConfigurationRequestBuilder {
+    /// Sets the [`fleet_arn`](struct.UpdateIdentityProviderConfigurationRequest.html#structfield.fleet_arn) member.
+    pub fn fleet_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_provider_saml_metadata`](struct.UpdateIdentityProviderConfigurationRequest.html#structfield.identity_provider_saml_metadata) member.
+    pub fn identity_provider_saml_metadata<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_provider_saml_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_provider_type`](struct.UpdateIdentityProviderConfigurationRequest.html#structfield.identity_provider_type) member.
+    pub fn identity_provider_type<T: Into<IdentityProviderType>>(mut self, value: T) -> Self {
+        self.identity_provider_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateIdentityProviderConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateIdentityProviderConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateIdentityProviderConfigurationRequest {
+            fleet_arn: self
+                .fleet_arn
+                .ok_or(::rusoto_core::BuildError::missing("fleet_arn"))?,
+            identity_provider_saml_metadata: self.identity_provider_saml_metadata,
+            identity_provider_type: self
+                .identity_provider_type
+                .ok_or(::rusoto_core::BuildError::missing("identity_provider_type"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -752,7 +2490,7 @@ pub struct UpdateIdentityProviderConfigurationRequest {
 pub struct UpdateIdentityProviderConfigurationResponse {}
 
 /// <p>The summary of the website authorization provider.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct WebsiteAuthorizationProviderSummary {
     /// <p>A unique identifier for the authorization provider.</p>
@@ -761,11 +2499,12 @@ pub struct WebsiteAuthorizationProviderSummary {
     pub authorization_provider_id: Option<String>,
     /// <p>The authorization provider type.</p>
     #[serde(rename = "AuthorizationProviderType")]
-    pub authorization_provider_type: String,
+    pub authorization_provider_type: AuthorizationProviderType,
     /// <p>The time of creation.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The domain name of the authorization provider. This applies only to SAML-based authorization providers.</p>
     #[serde(rename = "DomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -778,8 +2517,9 @@ pub struct WebsiteAuthorizationProviderSummary {
 pub struct WebsiteCaSummary {
     /// <p>The time when the CA was added.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The name to display.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2946,6 +4686,234 @@ pub trait Worklink {
         UpdateIdentityProviderConfigurationError,
     >;
 }
+impl<T: ?Sized + Worklink> Worklink for ::std::sync::Arc<T> {
+    fn associate_domain(
+        &self,
+        input: AssociateDomainRequest,
+    ) -> RusotoFuture<AssociateDomainResponse, AssociateDomainError> {
+        (**self).associate_domain(input)
+    }
+    fn associate_website_authorization_provider(
+        &self,
+        input: AssociateWebsiteAuthorizationProviderRequest,
+    ) -> RusotoFuture<
+        AssociateWebsiteAuthorizationProviderResponse,
+        AssociateWebsiteAuthorizationProviderError,
+    > {
+        (**self).associate_website_authorization_provider(input)
+    }
+    fn associate_website_certificate_authority(
+        &self,
+        input: AssociateWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        AssociateWebsiteCertificateAuthorityResponse,
+        AssociateWebsiteCertificateAuthorityError,
+    > {
+        (**self).associate_website_certificate_authority(input)
+    }
+    fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> RusotoFuture<CreateFleetResponse, CreateFleetError> {
+        (**self).create_fleet(input)
+    }
+    fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> RusotoFuture<DeleteFleetResponse, DeleteFleetError> {
+        (**self).delete_fleet(input)
+    }
+    fn describe_audit_stream_configuration(
+        &self,
+        input: DescribeAuditStreamConfigurationRequest,
+    ) -> RusotoFuture<DescribeAuditStreamConfigurationResponse, DescribeAuditStreamConfigurationError>
+    {
+        (**self).describe_audit_stream_configuration(input)
+    }
+    fn describe_company_network_configuration(
+        &self,
+        input: DescribeCompanyNetworkConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeCompanyNetworkConfigurationResponse,
+        DescribeCompanyNetworkConfigurationError,
+    > {
+        (**self).describe_company_network_configuration(input)
+    }
+    fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> RusotoFuture<DescribeDeviceResponse, DescribeDeviceError> {
+        (**self).describe_device(input)
+    }
+    fn describe_device_policy_configuration(
+        &self,
+        input: DescribeDevicePolicyConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeDevicePolicyConfigurationResponse,
+        DescribeDevicePolicyConfigurationError,
+    > {
+        (**self).describe_device_policy_configuration(input)
+    }
+    fn describe_domain(
+        &self,
+        input: DescribeDomainRequest,
+    ) -> RusotoFuture<DescribeDomainResponse, DescribeDomainError> {
+        (**self).describe_domain(input)
+    }
+    fn describe_fleet_metadata(
+        &self,
+        input: DescribeFleetMetadataRequest,
+    ) -> RusotoFuture<DescribeFleetMetadataResponse, DescribeFleetMetadataError> {
+        (**self).describe_fleet_metadata(input)
+    }
+    fn describe_identity_provider_configuration(
+        &self,
+        input: DescribeIdentityProviderConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeIdentityProviderConfigurationResponse,
+        DescribeIdentityProviderConfigurationError,
+    > {
+        (**self).describe_identity_provider_configuration(input)
+    }
+    fn describe_website_certificate_authority(
+        &self,
+        input: DescribeWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        DescribeWebsiteCertificateAuthorityResponse,
+        DescribeWebsiteCertificateAuthorityError,
+    > {
+        (**self).describe_website_certificate_authority(input)
+    }
+    fn disassociate_domain(
+        &self,
+        input: DisassociateDomainRequest,
+    ) -> RusotoFuture<DisassociateDomainResponse, DisassociateDomainError> {
+        (**self).disassociate_domain(input)
+    }
+    fn disassociate_website_authorization_provider(
+        &self,
+        input: DisassociateWebsiteAuthorizationProviderRequest,
+    ) -> RusotoFuture<
+        DisassociateWebsiteAuthorizationProviderResponse,
+        DisassociateWebsiteAuthorizationProviderError,
+    > {
+        (**self).disassociate_website_authorization_provider(input)
+    }
+    fn disassociate_website_certificate_authority(
+        &self,
+        input: DisassociateWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        DisassociateWebsiteCertificateAuthorityResponse,
+        DisassociateWebsiteCertificateAuthorityError,
+    > {
+        (**self).disassociate_website_certificate_authority(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn list_fleets(
+        &self,
+        input: ListFleetsRequest,
+    ) -> RusotoFuture<ListFleetsResponse, ListFleetsError> {
+        (**self).list_fleets(input)
+    }
+    fn list_website_authorization_providers(
+        &self,
+        input: ListWebsiteAuthorizationProvidersRequest,
+    ) -> RusotoFuture<
+        ListWebsiteAuthorizationProvidersResponse,
+        ListWebsiteAuthorizationProvidersError,
+    > {
+        (**self).list_website_authorization_providers(input)
+    }
+    fn list_website_certificate_authorities(
+        &self,
+        input: ListWebsiteCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<
+        ListWebsiteCertificateAuthoritiesResponse,
+        ListWebsiteCertificateAuthoritiesError,
+    > {
+        (**self).list_website_certificate_authorities(input)
+    }
+    fn restore_domain_access(
+        &self,
+        input: RestoreDomainAccessRequest,
+    ) -> RusotoFuture<RestoreDomainAccessResponse, RestoreDomainAccessError> {
+        (**self).restore_domain_access(input)
+    }
+    fn revoke_domain_access(
+        &self,
+        input: RevokeDomainAccessRequest,
+    ) -> RusotoFuture<RevokeDomainAccessResponse, RevokeDomainAccessError> {
+        (**self).revoke_domain_access(input)
+    }
+    fn sign_out_user(
+        &self,
+        input: SignOutUserRequest,
+    ) -> RusotoFuture<SignOutUserResponse, SignOutUserError> {
+        (**self).sign_out_user(input)
+    }
+    fn update_audit_stream_configuration(
+        &self,
+        input: UpdateAuditStreamConfigurationRequest,
+    ) -> RusotoFuture<UpdateAuditStreamConfigurationResponse, UpdateAuditStreamConfigurationError>
+    {
+        (**self).update_audit_stream_configuration(input)
+    }
+    fn update_company_network_configuration(
+        &self,
+        input: UpdateCompanyNetworkConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateCompanyNetworkConfigurationResponse,
+        UpdateCompanyNetworkConfigurationError,
+    > {
+        (**self).update_company_network_configuration(input)
+    }
+    fn update_device_policy_configuration(
+        &self,
+        input: UpdateDevicePolicyConfigurationRequest,
+    ) -> RusotoFuture<UpdateDevicePolicyConfigurationResponse, UpdateDevicePolicyConfigurationError>
+    {
+        (**self).update_device_policy_configuration(input)
+    }
+    fn update_domain_metadata(
+        &self,
+        input: UpdateDomainMetadataRequest,
+    ) -> RusotoFuture<UpdateDomainMetadataResponse, UpdateDomainMetadataError> {
+        (**self).update_domain_metadata(input)
+    }
+    fn update_fleet_metadata(
+        &self,
+        input: UpdateFleetMetadataRequest,
+    ) -> RusotoFuture<UpdateFleetMetadataResponse, UpdateFleetMetadataError> {
+        (**self).update_fleet_metadata(input)
+    }
+    fn update_identity_provider_configuration(
+        &self,
+        input: UpdateIdentityProviderConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateIdentityProviderConfigurationResponse,
+        UpdateIdentityProviderConfigurationError,
+    > {
+        (**self).update_identity_provider_configuration(input)
+    }
+}
+/// The error codes WorkLink retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the WorkLink API.
 #[derive(Clone)]
 pub struct WorklinkClient {
@@ -2961,6 +4929,27 @@ impl WorklinkClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_WORKLINK`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> WorklinkClient {
+        Self::new(region::Region::from_env_for_service("WORKLINK"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `worklink`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> WorklinkClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("worklink", "WORKLINK"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2979,7 +4968,54 @@ impl WorklinkClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> WorklinkClient {
-        WorklinkClient { client, region }
+        WorklinkClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "worklink")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "worklink",
+            "worklink",
+            "WORKLINK",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Worklink` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Worklink + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for WorklinkClient {
+    fn service_name(&self) -> &'static str {
+        "WorkLink"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "worklink"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> WorklinkClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon WorkMail clients.
+//!
+//! Makes a single read-only `ListOrganizations` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_workmail::{ListOrganizationsRequest, Workmail, WorkmailClient};
+
+fn main() {
+    let client = WorkmailClient::from_env();
+    match client
+        .list_organizations(ListOrganizationsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListOrganizations succeeded"),
+        Err(error) => {
+            eprintln!("ListOrganizations failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
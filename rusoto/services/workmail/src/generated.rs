@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateDelegateToResourceRequest {
@@ -36,6 +37,58 @@ pub struct AssociateDelegateToResourceRequest {
     pub resource_id: String,
 }
 
+impl AssociateDelegateToResourceRequest {
+    /// Returns a builder for `AssociateDelegateToResourceRequest`, checking its required members when built.
+    pub fn builder() -> AssociateDelegateToResourceRequestBuilder {
+        AssociateDelegateToResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateDelegateToResourceRequest`](struct.AssociateDelegateToResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateDelegateToResourceRequestBuilder {
+    entity_id: Option<String>,
+    organization_id: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl AssociateDelegateToResourceRequestBuilder {
+    /// Sets the [`entity_id`](struct.AssociateDelegateToResourceRequest.html#structfield.entity_id) member.
+    pub fn entity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.AssociateDelegateToResourceRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.AssociateDelegateToResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateDelegateToResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateDelegateToResourceRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateDelegateToResourceRequest {
+            entity_id: self
+                .entity_id
+                .ok_or(::rusoto_core::BuildError::missing("entity_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateDelegateToResourceResponse {}
@@ -53,6 +106,58 @@ pub struct AssociateMemberToGroupRequest {
     pub organization_id: String,
 }
 
+impl AssociateMemberToGroupRequest {
+    /// Returns a builder for `AssociateMemberToGroupRequest`, checking its required members when built.
+    pub fn builder() -> AssociateMemberToGroupRequestBuilder {
+        AssociateMemberToGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateMemberToGroupRequest`](struct.AssociateMemberToGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateMemberToGroupRequestBuilder {
+    group_id: Option<String>,
+    member_id: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl AssociateMemberToGroupRequestBuilder {
+    /// Sets the [`group_id`](struct.AssociateMemberToGroupRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`member_id`](struct.AssociateMemberToGroupRequest.html#structfield.member_id) member.
+    pub fn member_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.member_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.AssociateMemberToGroupRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateMemberToGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateMemberToGroupRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateMemberToGroupRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            member_id: self
+                .member_id
+                .ok_or(::rusoto_core::BuildError::missing("member_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateMemberToGroupResponse {}
@@ -87,6 +192,56 @@ pub struct CreateAliasRequest {
     pub organization_id: String,
 }
 
+impl CreateAliasRequest {
+    /// Returns a builder for `CreateAliasRequest`, checking its required members when built.
+    pub fn builder() -> CreateAliasRequestBuilder {
+        CreateAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAliasRequest`](struct.CreateAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAliasRequestBuilder {
+    alias: Option<String>,
+    entity_id: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl CreateAliasRequestBuilder {
+    /// Sets the [`alias`](struct.CreateAliasRequest.html#structfield.alias) member.
+    pub fn alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`entity_id`](struct.CreateAliasRequest.html#structfield.entity_id) member.
+    pub fn entity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.CreateAliasRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAliasRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAliasRequest {
+            alias: self
+                .alias
+                .ok_or(::rusoto_core::BuildError::missing("alias"))?,
+            entity_id: self
+                .entity_id
+                .ok_or(::rusoto_core::BuildError::missing("entity_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateAliasResponse {}
@@ -101,6 +256,46 @@ pub struct CreateGroupRequest {
     pub organization_id: String,
 }
 
+impl CreateGroupRequest {
+    /// Returns a builder for `CreateGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateGroupRequestBuilder {
+        CreateGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGroupRequest`](struct.CreateGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGroupRequestBuilder {
+    name: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl CreateGroupRequestBuilder {
+    /// Sets the [`name`](struct.CreateGroupRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.CreateGroupRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateGroupRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGroupResponse {
@@ -110,7 +305,7 @@ pub struct CreateGroupResponse {
     pub group_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateResourceRequest {
     /// <p>The name of the new resource.</p>
     #[serde(rename = "Name")]
@@ -120,7 +315,57 @@ pub struct CreateResourceRequest {
     pub organization_id: String,
     /// <p>The type of the new resource. The available types are <code>equipment</code> and <code>room</code>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ResourceType,
+}
+
+impl CreateResourceRequest {
+    /// Returns a builder for `CreateResourceRequest`, checking its required members when built.
+    pub fn builder() -> CreateResourceRequestBuilder {
+        CreateResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateResourceRequest`](struct.CreateResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateResourceRequestBuilder {
+    name: Option<String>,
+    organization_id: Option<String>,
+    type_: Option<ResourceType>,
+}
+
+impl CreateResourceRequestBuilder {
+    /// Sets the [`name`](struct.CreateResourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.CreateResourceRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreateResourceRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<ResourceType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateResourceRequest, ::rusoto_core::BuildError> {
+        Ok(CreateResourceRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -148,6 +393,66 @@ pub struct CreateUserRequest {
     pub password: String,
 }
 
+impl CreateUserRequest {
+    /// Returns a builder for `CreateUserRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserRequestBuilder {
+        CreateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserRequest`](struct.CreateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserRequestBuilder {
+    display_name: Option<String>,
+    name: Option<String>,
+    organization_id: Option<String>,
+    password: Option<String>,
+}
+
+impl CreateUserRequestBuilder {
+    /// Sets the [`display_name`](struct.CreateUserRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateUserRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.CreateUserRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.CreateUserRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateUserRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserRequest {
+            display_name: self
+                .display_name
+                .ok_or(::rusoto_core::BuildError::missing("display_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateUserResponse {
@@ -158,7 +463,7 @@ pub struct CreateUserResponse {
 }
 
 /// <p>The name of the attribute, which is one of the values defined in the UserAttribute enumeration.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Delegate {
     /// <p>The identifier for the user or group associated as the resource's delegate.</p>
@@ -166,7 +471,7 @@ pub struct Delegate {
     pub id: String,
     /// <p>The type of the delegate: user or group.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: MemberType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -182,6 +487,56 @@ pub struct DeleteAliasRequest {
     pub organization_id: String,
 }
 
+impl DeleteAliasRequest {
+    /// Returns a builder for `DeleteAliasRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAliasRequestBuilder {
+        DeleteAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAliasRequest`](struct.DeleteAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAliasRequestBuilder {
+    alias: Option<String>,
+    entity_id: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl DeleteAliasRequestBuilder {
+    /// Sets the [`alias`](struct.DeleteAliasRequest.html#structfield.alias) member.
+    pub fn alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`entity_id`](struct.DeleteAliasRequest.html#structfield.entity_id) member.
+    pub fn entity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.DeleteAliasRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteAliasRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAliasRequest {
+            alias: self
+                .alias
+                .ok_or(::rusoto_core::BuildError::missing("alias"))?,
+            entity_id: self
+                .entity_id
+                .ok_or(::rusoto_core::BuildError::missing("entity_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteAliasResponse {}
@@ -196,6 +551,46 @@ pub struct DeleteGroupRequest {
     pub organization_id: String,
 }
 
+impl DeleteGroupRequest {
+    /// Returns a builder for `DeleteGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteGroupRequestBuilder {
+        DeleteGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGroupRequest`](struct.DeleteGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteGroupRequestBuilder {
+    group_id: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl DeleteGroupRequestBuilder {
+    /// Sets the [`group_id`](struct.DeleteGroupRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.DeleteGroupRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteGroupRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteGroupResponse {}
@@ -213,6 +608,58 @@ pub struct DeleteMailboxPermissionsRequest {
     pub organization_id: String,
 }
 
+impl DeleteMailboxPermissionsRequest {
+    /// Returns a builder for `DeleteMailboxPermissionsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteMailboxPermissionsRequestBuilder {
+        DeleteMailboxPermissionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMailboxPermissionsRequest`](struct.DeleteMailboxPermissionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteMailboxPermissionsRequestBuilder {
+    entity_id: Option<String>,
+    grantee_id: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl DeleteMailboxPermissionsRequestBuilder {
+    /// Sets the [`entity_id`](struct.DeleteMailboxPermissionsRequest.html#structfield.entity_id) member.
+    pub fn entity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grantee_id`](struct.DeleteMailboxPermissionsRequest.html#structfield.grantee_id) member.
+    pub fn grantee_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.grantee_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.DeleteMailboxPermissionsRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMailboxPermissionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteMailboxPermissionsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteMailboxPermissionsRequest {
+            entity_id: self
+                .entity_id
+                .ok_or(::rusoto_core::BuildError::missing("entity_id"))?,
+            grantee_id: self
+                .grantee_id
+                .ok_or(::rusoto_core::BuildError::missing("grantee_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteMailboxPermissionsResponse {}
@@ -227,6 +674,46 @@ pub struct DeleteResourceRequest {
     pub resource_id: String,
 }
 
+impl DeleteResourceRequest {
+    /// Returns a builder for `DeleteResourceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteResourceRequestBuilder {
+        DeleteResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteResourceRequest`](struct.DeleteResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteResourceRequestBuilder {
+    organization_id: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl DeleteResourceRequestBuilder {
+    /// Sets the [`organization_id`](struct.DeleteResourceRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DeleteResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteResourceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteResourceRequest {
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteResourceResponse {}
@@ -241,6 +728,46 @@ pub struct DeleteUserRequest {
     pub user_id: String,
 }
 
+impl DeleteUserRequest {
+    /// Returns a builder for `DeleteUserRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserRequestBuilder {
+        DeleteUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserRequest`](struct.DeleteUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserRequestBuilder {
+    organization_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl DeleteUserRequestBuilder {
+    /// Sets the [`organization_id`](struct.DeleteUserRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.DeleteUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUserRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserRequest {
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteUserResponse {}
@@ -255,6 +782,48 @@ pub struct DeregisterFromWorkMailRequest {
     pub organization_id: String,
 }
 
+impl DeregisterFromWorkMailRequest {
+    /// Returns a builder for `DeregisterFromWorkMailRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterFromWorkMailRequestBuilder {
+        DeregisterFromWorkMailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterFromWorkMailRequest`](struct.DeregisterFromWorkMailRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterFromWorkMailRequestBuilder {
+    entity_id: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl DeregisterFromWorkMailRequestBuilder {
+    /// Sets the [`entity_id`](struct.DeregisterFromWorkMailRequest.html#structfield.entity_id) member.
+    pub fn entity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.DeregisterFromWorkMailRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterFromWorkMailRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterFromWorkMailRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterFromWorkMailRequest {
+            entity_id: self
+                .entity_id
+                .ok_or(::rusoto_core::BuildError::missing("entity_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeregisterFromWorkMailResponse {}
@@ -269,21 +838,63 @@ pub struct DescribeGroupRequest {
     pub organization_id: String,
 }
 
+impl DescribeGroupRequest {
+    /// Returns a builder for `DescribeGroupRequest`, checking its required members when built.
+    pub fn builder() -> DescribeGroupRequestBuilder {
+        DescribeGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeGroupRequest`](struct.DescribeGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeGroupRequestBuilder {
+    group_id: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl DescribeGroupRequestBuilder {
+    /// Sets the [`group_id`](struct.DescribeGroupRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.DescribeGroupRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeGroupRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeGroupResponse {
     /// <p>The date and time when a user was deregistered from WorkMail, in UNIX epoch time format.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<EpochTimestamp>,
     /// <p>The email of the described group.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     /// <p>The date and time when a user was registered to WorkMail, in UNIX epoch time format.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<EpochTimestamp>,
     /// <p>The identifier of the described group.</p>
     #[serde(rename = "GroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -295,7 +906,7 @@ pub struct DescribeGroupResponse {
     /// <p>The state of the user: enabled (registered to Amazon WorkMail) or disabled (deregistered or never registered to WorkMail).</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -305,6 +916,38 @@ pub struct DescribeOrganizationRequest {
     pub organization_id: String,
 }
 
+impl DescribeOrganizationRequest {
+    /// Returns a builder for `DescribeOrganizationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeOrganizationRequestBuilder {
+        DescribeOrganizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOrganizationRequest`](struct.DescribeOrganizationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeOrganizationRequestBuilder {
+    organization_id: Option<String>,
+}
+
+impl DescribeOrganizationRequestBuilder {
+    /// Sets the [`organization_id`](struct.DescribeOrganizationRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOrganizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeOrganizationRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeOrganizationRequest {
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeOrganizationResponse {
@@ -314,8 +957,9 @@ pub struct DescribeOrganizationResponse {
     pub alias: Option<String>,
     /// <p>The date at which the organization became usable in the WorkMail context, in UNIX epoch time format.</p>
     #[serde(rename = "CompletedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_date: Option<f64>,
+    pub completed_date: Option<EpochTimestamp>,
     /// <p>The default mail domain associated with the organization.</p>
     #[serde(rename = "DefaultMailDomain")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -352,6 +996,48 @@ pub struct DescribeResourceRequest {
     pub resource_id: String,
 }
 
+impl DescribeResourceRequest {
+    /// Returns a builder for `DescribeResourceRequest`, checking its required members when built.
+    pub fn builder() -> DescribeResourceRequestBuilder {
+        DescribeResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeResourceRequest`](struct.DescribeResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeResourceRequestBuilder {
+    organization_id: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl DescribeResourceRequestBuilder {
+    /// Sets the [`organization_id`](struct.DescribeResourceRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DescribeResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeResourceRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeResourceRequest {
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeResourceResponse {
@@ -361,16 +1047,18 @@ pub struct DescribeResourceResponse {
     pub booking_options: Option<BookingOptions>,
     /// <p>The date and time when a resource was disabled from WorkMail, in UNIX epoch time format.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<EpochTimestamp>,
     /// <p>The email of the described resource.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     /// <p>The date and time when a resource was enabled for WorkMail, in UNIX epoch time format.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<EpochTimestamp>,
     /// <p>The name of the described resource.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -382,11 +1070,11 @@ pub struct DescribeResourceResponse {
     /// <p>The state of the resource: enabled (registered to Amazon WorkMail) or disabled (deregistered or never registered to WorkMail).</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
     /// <p>The type of the described resource.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ResourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -399,13 +1087,54 @@ pub struct DescribeUserRequest {
     pub user_id: String,
 }
 
+impl DescribeUserRequest {
+    /// Returns a builder for `DescribeUserRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserRequestBuilder {
+        DescribeUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserRequest`](struct.DescribeUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserRequestBuilder {
+    organization_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl DescribeUserRequestBuilder {
+    /// Sets the [`organization_id`](struct.DescribeUserRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.DescribeUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeUserRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUserRequest {
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUserResponse {
     /// <p>The date and time at which the user was disabled for Amazon WorkMail usage, in UNIX epoch time format.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<EpochTimestamp>,
     /// <p>The display name of the user.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -416,8 +1145,9 @@ pub struct DescribeUserResponse {
     pub email: Option<String>,
     /// <p>The date and time at which the user was enabled for Amazon WorkMail usage, in UNIX epoch time format.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<EpochTimestamp>,
     /// <p>The name for the user.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -425,7 +1155,7 @@ pub struct DescribeUserResponse {
     /// <p>The state of a user: enabled (registered to Amazon WorkMail) or disabled (deregistered or never registered to WorkMail).</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
     /// <p>The identifier for the described user.</p>
     #[serde(rename = "UserId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -433,7 +1163,7 @@ pub struct DescribeUserResponse {
     /// <p>In certain cases, other entities are modeled as users. If interoperability is enabled, resources are imported into Amazon WorkMail as users. Because different WorkMail organizations rely on different directory types, administrators can distinguish between an unregistered user (account is disabled and has a user role) and the directory administrators. The values are USER, RESOURCE, and SYSTEM_USER.</p>
     #[serde(rename = "UserRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_role: Option<String>,
+    pub user_role: Option<UserRole>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -449,6 +1179,59 @@ pub struct DisassociateDelegateFromResourceRequest {
     pub resource_id: String,
 }
 
+impl DisassociateDelegateFromResourceRequest {
+    /// Returns a builder for `DisassociateDelegateFromResourceRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateDelegateFromResourceRequestBuilder {
+        DisassociateDelegateFromResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateDelegateFromResourceRequest`](struct.DisassociateDelegateFromResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateDelegateFromResourceRequestBuilder {
+    entity_id: Option<String>,
+    organization_id: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl DisassociateDelegateFromResourceRequestBuilder {
+    /// Sets the [`entity_id`](struct.DisassociateDelegateFromResourceRequest.html#structfield.entity_id) member.
+    pub fn entity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.DisassociateDelegateFromResourceRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DisassociateDelegateFromResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateDelegateFromResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateDelegateFromResourceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateDelegateFromResourceRequest {
+            entity_id: self
+                .entity_id
+                .ok_or(::rusoto_core::BuildError::missing("entity_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateDelegateFromResourceResponse {}
@@ -466,18 +1249,188 @@ pub struct DisassociateMemberFromGroupRequest {
     pub organization_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DisassociateMemberFromGroupResponse {}
+impl DisassociateMemberFromGroupRequest {
+    /// Returns a builder for `DisassociateMemberFromGroupRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateMemberFromGroupRequestBuilder {
+        DisassociateMemberFromGroupRequestBuilder::default()
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetMailboxDetailsRequest {
-    /// <p>The identifier for the organization that contains the user whose mailbox details are being requested.</p>
-    #[serde(rename = "OrganizationId")]
-    pub organization_id: String,
-    /// <p>The identifier for the user whose mailbox details are being requested.</p>
-    #[serde(rename = "UserId")]
-    pub user_id: String,
+/// Builds a [`DisassociateMemberFromGroupRequest`](struct.DisassociateMemberFromGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateMemberFromGroupRequestBuilder {
+    group_id: Option<String>,
+    member_id: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl DisassociateMemberFromGroupRequestBuilder {
+    /// Sets the [`group_id`](struct.DisassociateMemberFromGroupRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`member_id`](struct.DisassociateMemberFromGroupRequest.html#structfield.member_id) member.
+    pub fn member_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.member_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.DisassociateMemberFromGroupRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateMemberFromGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateMemberFromGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateMemberFromGroupRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            member_id: self
+                .member_id
+                .ok_or(::rusoto_core::BuildError::missing("member_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DisassociateMemberFromGroupResponse {}
+
+/// <p>The state of the user: enabled (registered to Amazon WorkMail) or disabled (deregistered or never registered to WorkMail).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntityState {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EntityState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityState::Enabled => "ENABLED",
+            EntityState::Disabled => "DISABLED",
+            EntityState::Deleted => "DELETED",
+            EntityState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EntityState {
+    fn from(value: &'a str) -> EntityState {
+        match value {
+            "ENABLED" => EntityState::Enabled,
+            "DISABLED" => EntityState::Disabled,
+            "DELETED" => EntityState::Deleted,
+            _ => EntityState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityState {
+    fn from(value: String) -> EntityState {
+        EntityState::from(value.as_str())
+    }
+}
+
+impl From<EntityState> for String {
+    fn from(value: EntityState) -> String {
+        match value {
+            EntityState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EntityState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EntityState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EntityState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EntityState::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetMailboxDetailsRequest {
+    /// <p>The identifier for the organization that contains the user whose mailbox details are being requested.</p>
+    #[serde(rename = "OrganizationId")]
+    pub organization_id: String,
+    /// <p>The identifier for the user whose mailbox details are being requested.</p>
+    #[serde(rename = "UserId")]
+    pub user_id: String,
+}
+
+impl GetMailboxDetailsRequest {
+    /// Returns a builder for `GetMailboxDetailsRequest`, checking its required members when built.
+    pub fn builder() -> GetMailboxDetailsRequestBuilder {
+        GetMailboxDetailsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMailboxDetailsRequest`](struct.GetMailboxDetailsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMailboxDetailsRequestBuilder {
+    organization_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl GetMailboxDetailsRequestBuilder {
+    /// Sets the [`organization_id`](struct.GetMailboxDetailsRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.GetMailboxDetailsRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMailboxDetailsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetMailboxDetailsRequest, ::rusoto_core::BuildError> {
+        Ok(GetMailboxDetailsRequest {
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -499,16 +1452,18 @@ pub struct GetMailboxDetailsResponse {
 pub struct Group {
     /// <p>The date indicating when the group was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<EpochTimestamp>,
     /// <p>The email of the group.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     /// <p>The date indicating when the group was enabled for Amazon WorkMail use.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<EpochTimestamp>,
     /// <p>The identifier of the group.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -520,7 +1475,7 @@ pub struct Group {
     /// <p>The state of the group, which can be ENABLED, DISABLED, or DELETED.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -541,6 +1496,62 @@ pub struct ListAliasesRequest {
     pub organization_id: String,
 }
 
+impl ListAliasesRequest {
+    /// Returns a builder for `ListAliasesRequest`, checking its required members when built.
+    pub fn builder() -> ListAliasesRequestBuilder {
+        ListAliasesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAliasesRequest`](struct.ListAliasesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAliasesRequestBuilder {
+    entity_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl ListAliasesRequestBuilder {
+    /// Sets the [`entity_id`](struct.ListAliasesRequest.html#structfield.entity_id) member.
+    pub fn entity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAliasesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAliasesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.ListAliasesRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAliasesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAliasesRequest, ::rusoto_core::BuildError> {
+        Ok(ListAliasesRequest {
+            entity_id: self
+                .entity_id
+                .ok_or(::rusoto_core::BuildError::missing("entity_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAliasesResponse {
@@ -572,6 +1583,64 @@ pub struct ListGroupMembersRequest {
     pub organization_id: String,
 }
 
+impl ListGroupMembersRequest {
+    /// Returns a builder for `ListGroupMembersRequest`, checking its required members when built.
+    pub fn builder() -> ListGroupMembersRequestBuilder {
+        ListGroupMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGroupMembersRequest`](struct.ListGroupMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGroupMembersRequestBuilder {
+    group_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl ListGroupMembersRequestBuilder {
+    /// Sets the [`group_id`](struct.ListGroupMembersRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListGroupMembersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListGroupMembersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.ListGroupMembersRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGroupMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListGroupMembersRequest, ::rusoto_core::BuildError> {
+        Ok(ListGroupMembersRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGroupMembersResponse {
@@ -600,6 +1669,52 @@ pub struct ListGroupsRequest {
     pub organization_id: String,
 }
 
+impl ListGroupsRequest {
+    /// Returns a builder for `ListGroupsRequest`, checking its required members when built.
+    pub fn builder() -> ListGroupsRequestBuilder {
+        ListGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGroupsRequest`](struct.ListGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGroupsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl ListGroupsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListGroupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.ListGroupsRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(ListGroupsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGroupsResponse {
@@ -631,6 +1746,64 @@ pub struct ListMailboxPermissionsRequest {
     pub organization_id: String,
 }
 
+impl ListMailboxPermissionsRequest {
+    /// Returns a builder for `ListMailboxPermissionsRequest`, checking its required members when built.
+    pub fn builder() -> ListMailboxPermissionsRequestBuilder {
+        ListMailboxPermissionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListMailboxPermissionsRequest`](struct.ListMailboxPermissionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListMailboxPermissionsRequestBuilder {
+    entity_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl ListMailboxPermissionsRequestBuilder {
+    /// Sets the [`entity_id`](struct.ListMailboxPermissionsRequest.html#structfield.entity_id) member.
+    pub fn entity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListMailboxPermissionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListMailboxPermissionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.ListMailboxPermissionsRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMailboxPermissionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListMailboxPermissionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListMailboxPermissionsRequest {
+            entity_id: self
+                .entity_id
+                .ok_or(::rusoto_core::BuildError::missing("entity_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListMailboxPermissionsResponse {
@@ -656,6 +1829,44 @@ pub struct ListOrganizationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListOrganizationsRequest {
+    /// Returns a builder for `ListOrganizationsRequest`, checking its required members when built.
+    pub fn builder() -> ListOrganizationsRequestBuilder {
+        ListOrganizationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOrganizationsRequest`](struct.ListOrganizationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOrganizationsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListOrganizationsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListOrganizationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListOrganizationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOrganizationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListOrganizationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListOrganizationsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListOrganizationsResponse {
@@ -687,6 +1898,64 @@ pub struct ListResourceDelegatesRequest {
     pub resource_id: String,
 }
 
+impl ListResourceDelegatesRequest {
+    /// Returns a builder for `ListResourceDelegatesRequest`, checking its required members when built.
+    pub fn builder() -> ListResourceDelegatesRequestBuilder {
+        ListResourceDelegatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourceDelegatesRequest`](struct.ListResourceDelegatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResourceDelegatesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    organization_id: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl ListResourceDelegatesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListResourceDelegatesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListResourceDelegatesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.ListResourceDelegatesRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.ListResourceDelegatesRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourceDelegatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListResourceDelegatesRequest, ::rusoto_core::BuildError> {
+        Ok(ListResourceDelegatesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResourceDelegatesResponse {
@@ -715,6 +1984,52 @@ pub struct ListResourcesRequest {
     pub organization_id: String,
 }
 
+impl ListResourcesRequest {
+    /// Returns a builder for `ListResourcesRequest`, checking its required members when built.
+    pub fn builder() -> ListResourcesRequestBuilder {
+        ListResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourcesRequest`](struct.ListResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResourcesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl ListResourcesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListResourcesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListResourcesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.ListResourcesRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(ListResourcesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResourcesResponse {
@@ -743,6 +2058,52 @@ pub struct ListUsersRequest {
     pub organization_id: String,
 }
 
+impl ListUsersRequest {
+    /// Returns a builder for `ListUsersRequest`, checking its required members when built.
+    pub fn builder() -> ListUsersRequestBuilder {
+        ListUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUsersRequest`](struct.ListUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUsersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl ListUsersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListUsersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUsersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.ListUsersRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListUsersRequest, ::rusoto_core::BuildError> {
+        Ok(ListUsersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListUsersResponse {
@@ -762,12 +2123,14 @@ pub struct ListUsersResponse {
 pub struct Member {
     /// <p>The date indicating when the member was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<EpochTimestamp>,
     /// <p>The date indicating when the member was enabled for Amazon WorkMail use.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<EpochTimestamp>,
     /// <p>The identifier of the member.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -779,11 +2142,83 @@ pub struct Member {
     /// <p>The state of the member, which can be ENABLED, DISABLED, or DELETED.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
     /// <p>A member can be a user or group.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<MemberType>,
+}
+
+/// <p>The type of the delegate: user or group.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MemberType {
+    /// <p><code>GROUP</code></p>
+    Group,
+    /// <p><code>USER</code></p>
+    User,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MemberType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MemberType::Group => "GROUP",
+            MemberType::User => "USER",
+            MemberType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MemberType {
+    fn from(value: &'a str) -> MemberType {
+        match value {
+            "GROUP" => MemberType::Group,
+            "USER" => MemberType::User,
+            _ => MemberType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MemberType {
+    fn from(value: String) -> MemberType {
+        MemberType::from(value.as_str())
+    }
+}
+
+impl From<MemberType> for String {
+    fn from(value: MemberType) -> String {
+        match value {
+            MemberType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MemberType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MemberType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MemberType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MemberType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MemberType::from(value))
+    }
 }
 
 /// <p>The representation of an organization.</p>
@@ -809,7 +2244,7 @@ pub struct OrganizationSummary {
 }
 
 /// <p>Permission granted to a user, group, or resource to access a certain aspect of another user, group, or resource mailbox.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Permission {
     /// <p>The identifier of the user, group, or resource to which the permissions are granted.</p>
@@ -817,10 +2252,86 @@ pub struct Permission {
     pub grantee_id: String,
     /// <p>The type of user, group, or resource referred to in GranteeId.</p>
     #[serde(rename = "GranteeType")]
-    pub grantee_type: String,
+    pub grantee_type: MemberType,
     /// <p>The permissions granted to the grantee. SEND_AS allows the grantee to send email as the owner of the mailbox (the grantee is not mentioned on these emails). SEND_ON_BEHALF allows the grantee to send email on behalf of the owner of the mailbox (the grantee is not mentioned as the physical sender of these emails). FULL_ACCESS allows the grantee full access to the mailbox, irrespective of other folder-level permissions set on the mailbox.</p>
     #[serde(rename = "PermissionValues")]
-    pub permission_values: Vec<String>,
+    pub permission_values: Vec<PermissionType>,
+}
+
+/// <p>The permissions granted to the grantee. SEND_AS allows the grantee to send email as the owner of the mailbox (the grantee is not mentioned on these emails). SEND_ON_BEHALF allows the grantee to send email on behalf of the owner of the mailbox (the grantee is not mentioned as the physical sender of these emails). FULL_ACCESS allows the grantee full access to the mailbox, irrespective of other folder-level permissions set on the mailbox.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PermissionType {
+    /// <p><code>FULL_ACCESS</code></p>
+    FullAccess,
+    /// <p><code>SEND_AS</code></p>
+    SendAs,
+    /// <p><code>SEND_ON_BEHALF</code></p>
+    SendOnBehalf,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PermissionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PermissionType::FullAccess => "FULL_ACCESS",
+            PermissionType::SendAs => "SEND_AS",
+            PermissionType::SendOnBehalf => "SEND_ON_BEHALF",
+            PermissionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PermissionType {
+    fn from(value: &'a str) -> PermissionType {
+        match value {
+            "FULL_ACCESS" => PermissionType::FullAccess,
+            "SEND_AS" => PermissionType::SendAs,
+            "SEND_ON_BEHALF" => PermissionType::SendOnBehalf,
+            _ => PermissionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PermissionType {
+    fn from(value: String) -> PermissionType {
+        PermissionType::from(value.as_str())
+    }
+}
+
+impl From<PermissionType> for String {
+    fn from(value: PermissionType) -> String {
+        match value {
+            PermissionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PermissionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PermissionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PermissionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PermissionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PermissionType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -836,7 +2347,69 @@ pub struct PutMailboxPermissionsRequest {
     pub organization_id: String,
     /// <p>The permissions granted to the grantee. SEND_AS allows the grantee to send email as the owner of the mailbox (the grantee is not mentioned on these emails). SEND_ON_BEHALF allows the grantee to send email on behalf of the owner of the mailbox (the grantee is not mentioned as the physical sender of these emails). FULL_ACCESS allows the grantee full access to the mailbox, irrespective of other folder-level permissions set on the mailbox.</p>
     #[serde(rename = "PermissionValues")]
-    pub permission_values: Vec<String>,
+    pub permission_values: Vec<PermissionType>,
+}
+
+impl PutMailboxPermissionsRequest {
+    /// Returns a builder for `PutMailboxPermissionsRequest`, checking its required members when built.
+    pub fn builder() -> PutMailboxPermissionsRequestBuilder {
+        PutMailboxPermissionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutMailboxPermissionsRequest`](struct.PutMailboxPermissionsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutMailboxPermissionsRequestBuilder {
+    entity_id: Option<String>,
+    grantee_id: Option<String>,
+    organization_id: Option<String>,
+    permission_values: Option<Vec<PermissionType>>,
+}
+
+impl PutMailboxPermissionsRequestBuilder {
+    /// Sets the [`entity_id`](struct.PutMailboxPermissionsRequest.html#structfield.entity_id) member.
+    pub fn entity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grantee_id`](struct.PutMailboxPermissionsRequest.html#structfield.grantee_id) member.
+    pub fn grantee_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.grantee_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.PutMailboxPermissionsRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permission_values`](struct.PutMailboxPermissionsRequest.html#structfield.permission_values) member.
+    pub fn permission_values<T: Into<Vec<PermissionType>>>(mut self, value: T) -> Self {
+        self.permission_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutMailboxPermissionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutMailboxPermissionsRequest, ::rusoto_core::BuildError> {
+        Ok(PutMailboxPermissionsRequest {
+            entity_id: self
+                .entity_id
+                .ok_or(::rusoto_core::BuildError::missing("entity_id"))?,
+            grantee_id: self
+                .grantee_id
+                .ok_or(::rusoto_core::BuildError::missing("grantee_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            permission_values: self
+                .permission_values
+                .ok_or(::rusoto_core::BuildError::missing("permission_values"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -856,6 +2429,58 @@ pub struct RegisterToWorkMailRequest {
     pub organization_id: String,
 }
 
+impl RegisterToWorkMailRequest {
+    /// Returns a builder for `RegisterToWorkMailRequest`, checking its required members when built.
+    pub fn builder() -> RegisterToWorkMailRequestBuilder {
+        RegisterToWorkMailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterToWorkMailRequest`](struct.RegisterToWorkMailRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterToWorkMailRequestBuilder {
+    email: Option<String>,
+    entity_id: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl RegisterToWorkMailRequestBuilder {
+    /// Sets the [`email`](struct.RegisterToWorkMailRequest.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`entity_id`](struct.RegisterToWorkMailRequest.html#structfield.entity_id) member.
+    pub fn entity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.RegisterToWorkMailRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterToWorkMailRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterToWorkMailRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterToWorkMailRequest {
+            email: self
+                .email
+                .ok_or(::rusoto_core::BuildError::missing("email"))?,
+            entity_id: self
+                .entity_id
+                .ok_or(::rusoto_core::BuildError::missing("entity_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RegisterToWorkMailResponse {}
@@ -873,6 +2498,56 @@ pub struct ResetPasswordRequest {
     pub user_id: String,
 }
 
+impl ResetPasswordRequest {
+    /// Returns a builder for `ResetPasswordRequest`, checking its required members when built.
+    pub fn builder() -> ResetPasswordRequestBuilder {
+        ResetPasswordRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResetPasswordRequest`](struct.ResetPasswordRequest.html).
+#[derive(Default, Debug)]
+pub struct ResetPasswordRequestBuilder {
+    organization_id: Option<String>,
+    password: Option<String>,
+    user_id: Option<String>,
+}
+
+impl ResetPasswordRequestBuilder {
+    /// Sets the [`organization_id`](struct.ResetPasswordRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.ResetPasswordRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.ResetPasswordRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetPasswordRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ResetPasswordRequest, ::rusoto_core::BuildError> {
+        Ok(ResetPasswordRequest {
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ResetPasswordResponse {}
@@ -883,16 +2558,18 @@ pub struct ResetPasswordResponse {}
 pub struct Resource {
     /// <p>The date indicating when the resource was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<EpochTimestamp>,
     /// <p>The email of the resource.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     /// <p>The date indicating when the resource was enabled for Amazon WorkMail use.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<EpochTimestamp>,
     /// <p>The identifier of the resource.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -904,11 +2581,83 @@ pub struct Resource {
     /// <p>The state of the resource, which can be ENABLED, DISABLED, or DELETED.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
     /// <p>The type of the resource: equipment or room.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ResourceType>,
+}
+
+/// <p>The type of the new resource. The available types are <code>equipment</code> and <code>room</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// <p><code>ROOM</code></p>
+    Room,
+    /// <p><code>EQUIPMENT</code></p>
+    Equipment,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Room => "ROOM",
+            ResourceType::Equipment => "EQUIPMENT",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "ROOM" => ResourceType::Room,
+            "EQUIPMENT" => ResourceType::Equipment,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        ResourceType::from(value.as_str())
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> String {
+        match value {
+            ResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -924,21 +2673,125 @@ pub struct UpdateMailboxQuotaRequest {
     pub user_id: String,
 }
 
+impl UpdateMailboxQuotaRequest {
+    /// Returns a builder for `UpdateMailboxQuotaRequest`, checking its required members when built.
+    pub fn builder() -> UpdateMailboxQuotaRequestBuilder {
+        UpdateMailboxQuotaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateMailboxQuotaRequest`](struct.UpdateMailboxQuotaRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateMailboxQuotaRequestBuilder {
+    mailbox_quota: Option<i64>,
+    organization_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl UpdateMailboxQuotaRequestBuilder {
+    /// Sets the [`mailbox_quota`](struct.UpdateMailboxQuotaRequest.html#structfield.mailbox_quota) member.
+    pub fn mailbox_quota<T: Into<i64>>(mut self, value: T) -> Self {
+        self.mailbox_quota = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.UpdateMailboxQuotaRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.UpdateMailboxQuotaRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateMailboxQuotaRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateMailboxQuotaRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateMailboxQuotaRequest {
+            mailbox_quota: self
+                .mailbox_quota
+                .ok_or(::rusoto_core::BuildError::missing("mailbox_quota"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateMailboxQuotaResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdatePrimaryEmailAddressRequest {
-    /// <p>The value of the email to be updated as primary.</p>
-    #[serde(rename = "Email")]
-    pub email: String,
-    /// <p>The user, group, or resource to update.</p>
-    #[serde(rename = "EntityId")]
-    pub entity_id: String,
-    /// <p>The organization that contains the user, group, or resource to update.</p>
-    #[serde(rename = "OrganizationId")]
-    pub organization_id: String,
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdatePrimaryEmailAddressRequest {
+    /// <p>The value of the email to be updated as primary.</p>
+    #[serde(rename = "Email")]
+    pub email: String,
+    /// <p>The user, group, or resource to update.</p>
+    #[serde(rename = "EntityId")]
+    pub entity_id: String,
+    /// <p>The organization that contains the user, group, or resource to update.</p>
+    #[serde(rename = "OrganizationId")]
+    pub organization_id: String,
+}
+
+impl UpdatePrimaryEmailAddressRequest {
+    /// Returns a builder for `UpdatePrimaryEmailAddressRequest`, checking its required members when built.
+    pub fn builder() -> UpdatePrimaryEmailAddressRequestBuilder {
+        UpdatePrimaryEmailAddressRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePrimaryEmailAddressRequest`](struct.UpdatePrimaryEmailAddressRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdatePrimaryEmailAddressRequestBuilder {
+    email: Option<String>,
+    entity_id: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl UpdatePrimaryEmailAddressRequestBuilder {
+    /// Sets the [`email`](struct.UpdatePrimaryEmailAddressRequest.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`entity_id`](struct.UpdatePrimaryEmailAddressRequest.html#structfield.entity_id) member.
+    pub fn entity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.UpdatePrimaryEmailAddressRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePrimaryEmailAddressRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdatePrimaryEmailAddressRequest, ::rusoto_core::BuildError> {
+        Ok(UpdatePrimaryEmailAddressRequest {
+            email: self
+                .email
+                .ok_or(::rusoto_core::BuildError::missing("email"))?,
+            entity_id: self
+                .entity_id
+                .ok_or(::rusoto_core::BuildError::missing("entity_id"))?,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -963,6 +2816,62 @@ pub struct UpdateResourceRequest {
     pub resource_id: String,
 }
 
+impl UpdateResourceRequest {
+    /// Returns a builder for `UpdateResourceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateResourceRequestBuilder {
+        UpdateResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateResourceRequest`](struct.UpdateResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateResourceRequestBuilder {
+    booking_options: Option<BookingOptions>,
+    name: Option<String>,
+    organization_id: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl UpdateResourceRequestBuilder {
+    /// Sets the [`booking_options`](struct.UpdateResourceRequest.html#structfield.booking_options) member.
+    pub fn booking_options<T: Into<BookingOptions>>(mut self, value: T) -> Self {
+        self.booking_options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateResourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.UpdateResourceRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.UpdateResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateResourceRequest {
+            booking_options: self.booking_options,
+            name: self.name,
+            organization_id: self
+                .organization_id
+                .ok_or(::rusoto_core::BuildError::missing("organization_id"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateResourceResponse {}
@@ -973,8 +2882,9 @@ pub struct UpdateResourceResponse {}
 pub struct User {
     /// <p>The date indicating when the user was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled_date: Option<f64>,
+    pub disabled_date: Option<EpochTimestamp>,
     /// <p>The display name of the user.</p>
     #[serde(rename = "DisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -985,8 +2895,9 @@ pub struct User {
     pub email: Option<String>,
     /// <p>The date indicating when the user was enabled for Amazon WorkMail use.</p>
     #[serde(rename = "EnabledDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_date: Option<f64>,
+    pub enabled_date: Option<EpochTimestamp>,
     /// <p>The identifier of the user.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -998,11 +2909,91 @@ pub struct User {
     /// <p>The state of the user, which can be ENABLED, DISABLED, or DELETED.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EntityState>,
     /// <p>The role of the user.</p>
     #[serde(rename = "UserRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_role: Option<String>,
+    pub user_role: Option<UserRole>,
+}
+
+/// <p>In certain cases, other entities are modeled as users. If interoperability is enabled, resources are imported into Amazon WorkMail as users. Because different WorkMail organizations rely on different directory types, administrators can distinguish between an unregistered user (account is disabled and has a user role) and the directory administrators. The values are USER, RESOURCE, and SYSTEM_USER.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UserRole {
+    /// <p><code>USER</code></p>
+    User,
+    /// <p><code>RESOURCE</code></p>
+    Resource,
+    /// <p><code>SYSTEM_USER</code></p>
+    SystemUser,
+    /// <p><code>REMOTE_USER</code></p>
+    RemoteUser,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UserRole {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserRole::User => "USER",
+            UserRole::Resource => "RESOURCE",
+            UserRole::SystemUser => "SYSTEM_USER",
+            UserRole::RemoteUser => "REMOTE_USER",
+            UserRole::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UserRole {
+    fn from(value: &'a str) -> UserRole {
+        match value {
+            "USER" => UserRole::User,
+            "RESOURCE" => UserRole::Resource,
+            "SYSTEM_USER" => UserRole::SystemUser,
+            "REMOTE_USER" => UserRole::RemoteUser,
+            _ => UserRole::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserRole {
+    fn from(value: String) -> UserRole {
+        UserRole::from(value.as_str())
+    }
+}
+
+impl From<UserRole> for String {
+    fn from(value: UserRole) -> String {
+        match value {
+            UserRole::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UserRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UserRole {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserRole {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UserRole, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UserRole::from(value))
+    }
 }
 
 /// Errors returned by AssociateDelegateToResource
@@ -3398,6 +5389,214 @@ pub trait Workmail {
         input: UpdateResourceRequest,
     ) -> RusotoFuture<UpdateResourceResponse, UpdateResourceError>;
 }
+impl<T: ?Sized + Workmail> Workmail for ::std::sync::Arc<T> {
+    fn associate_delegate_to_resource(
+        &self,
+        input: AssociateDelegateToResourceRequest,
+    ) -> RusotoFuture<AssociateDelegateToResourceResponse, AssociateDelegateToResourceError> {
+        (**self).associate_delegate_to_resource(input)
+    }
+    fn associate_member_to_group(
+        &self,
+        input: AssociateMemberToGroupRequest,
+    ) -> RusotoFuture<AssociateMemberToGroupResponse, AssociateMemberToGroupError> {
+        (**self).associate_member_to_group(input)
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<CreateAliasResponse, CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> RusotoFuture<CreateResourceResponse, CreateResourceError> {
+        (**self).create_resource(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> RusotoFuture<DeleteAliasResponse, DeleteAliasError> {
+        (**self).delete_alias(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_mailbox_permissions(
+        &self,
+        input: DeleteMailboxPermissionsRequest,
+    ) -> RusotoFuture<DeleteMailboxPermissionsResponse, DeleteMailboxPermissionsError> {
+        (**self).delete_mailbox_permissions(input)
+    }
+    fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> RusotoFuture<DeleteResourceResponse, DeleteResourceError> {
+        (**self).delete_resource(input)
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn deregister_from_work_mail(
+        &self,
+        input: DeregisterFromWorkMailRequest,
+    ) -> RusotoFuture<DeregisterFromWorkMailResponse, DeregisterFromWorkMailError> {
+        (**self).deregister_from_work_mail(input)
+    }
+    fn describe_group(
+        &self,
+        input: DescribeGroupRequest,
+    ) -> RusotoFuture<DescribeGroupResponse, DescribeGroupError> {
+        (**self).describe_group(input)
+    }
+    fn describe_organization(
+        &self,
+        input: DescribeOrganizationRequest,
+    ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
+        (**self).describe_organization(input)
+    }
+    fn describe_resource(
+        &self,
+        input: DescribeResourceRequest,
+    ) -> RusotoFuture<DescribeResourceResponse, DescribeResourceError> {
+        (**self).describe_resource(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn disassociate_delegate_from_resource(
+        &self,
+        input: DisassociateDelegateFromResourceRequest,
+    ) -> RusotoFuture<DisassociateDelegateFromResourceResponse, DisassociateDelegateFromResourceError>
+    {
+        (**self).disassociate_delegate_from_resource(input)
+    }
+    fn disassociate_member_from_group(
+        &self,
+        input: DisassociateMemberFromGroupRequest,
+    ) -> RusotoFuture<DisassociateMemberFromGroupResponse, DisassociateMemberFromGroupError> {
+        (**self).disassociate_member_from_group(input)
+    }
+    fn get_mailbox_details(
+        &self,
+        input: GetMailboxDetailsRequest,
+    ) -> RusotoFuture<GetMailboxDetailsResponse, GetMailboxDetailsError> {
+        (**self).get_mailbox_details(input)
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        (**self).list_aliases(input)
+    }
+    fn list_group_members(
+        &self,
+        input: ListGroupMembersRequest,
+    ) -> RusotoFuture<ListGroupMembersResponse, ListGroupMembersError> {
+        (**self).list_group_members(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn list_mailbox_permissions(
+        &self,
+        input: ListMailboxPermissionsRequest,
+    ) -> RusotoFuture<ListMailboxPermissionsResponse, ListMailboxPermissionsError> {
+        (**self).list_mailbox_permissions(input)
+    }
+    fn list_organizations(
+        &self,
+        input: ListOrganizationsRequest,
+    ) -> RusotoFuture<ListOrganizationsResponse, ListOrganizationsError> {
+        (**self).list_organizations(input)
+    }
+    fn list_resource_delegates(
+        &self,
+        input: ListResourceDelegatesRequest,
+    ) -> RusotoFuture<ListResourceDelegatesResponse, ListResourceDelegatesError> {
+        (**self).list_resource_delegates(input)
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
+        (**self).list_resources(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn put_mailbox_permissions(
+        &self,
+        input: PutMailboxPermissionsRequest,
+    ) -> RusotoFuture<PutMailboxPermissionsResponse, PutMailboxPermissionsError> {
+        (**self).put_mailbox_permissions(input)
+    }
+    fn register_to_work_mail(
+        &self,
+        input: RegisterToWorkMailRequest,
+    ) -> RusotoFuture<RegisterToWorkMailResponse, RegisterToWorkMailError> {
+        (**self).register_to_work_mail(input)
+    }
+    fn reset_password(
+        &self,
+        input: ResetPasswordRequest,
+    ) -> RusotoFuture<ResetPasswordResponse, ResetPasswordError> {
+        (**self).reset_password(input)
+    }
+    fn update_mailbox_quota(
+        &self,
+        input: UpdateMailboxQuotaRequest,
+    ) -> RusotoFuture<UpdateMailboxQuotaResponse, UpdateMailboxQuotaError> {
+        (**self).update_mailbox_quota(input)
+    }
+    fn update_primary_email_address(
+        &self,
+        input: UpdatePrimaryEmailAddressRequest,
+    ) -> RusotoFuture<UpdatePrimaryEmailAddressResponse, UpdatePrimaryEmailAddressError> {
+        (**self).update_primary_email_address(input)
+    }
+    fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> RusotoFuture<UpdateResourceResponse, UpdateResourceError> {
+        (**self).update_resource(input)
+    }
+}
+/// The error codes Amazon WorkMail retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon WorkMail API.
 #[derive(Clone)]
 pub struct WorkmailClient {
@@ -3413,6 +5612,27 @@ impl WorkmailClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_WORKMAIL`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> WorkmailClient {
+        Self::new(region::Region::from_env_for_service("WORKMAIL"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `workmail`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> WorkmailClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("workmail", "WORKMAIL"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3431,7 +5651,54 @@ impl WorkmailClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> WorkmailClient {
-        WorkmailClient { client, region }
+        WorkmailClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "workmail")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "workmail",
+            "workmail",
+            "WORKMAIL",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Workmail` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Workmail + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for WorkmailClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon WorkMail"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "workmail"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> WorkmailClient {
+        Self::new_with_client(client, region)
     }
 }
 
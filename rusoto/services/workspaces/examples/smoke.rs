@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon WorkSpaces clients.
+//!
+//! Makes a single read-only `DescribeAccount` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_workspaces::{Workspaces, WorkspacesClient};
+
+fn main() {
+    let client = WorkspacesClient::from_env();
+    match client.describe_account().sync() {
+        Ok(_) => println!("DescribeAccount succeeded"),
+        Err(error) => {
+            eprintln!("DescribeAccount failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Describes a modification to the configuration of bring your own license (BYOL) for the specified account. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -34,7 +35,7 @@ pub struct AccountModification {
     /// <p>The status of BYOL (whether BYOL is being enabled or disabled).</p>
     #[serde(rename = "DedicatedTenancySupport")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dedicated_tenancy_support: Option<String>,
+    pub dedicated_tenancy_support: Option<DedicatedTenancySupportResultEnum>,
     /// <p>The error code that is returned if the configuration of BYOL cannot be modified.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -46,11 +47,12 @@ pub struct AccountModification {
     /// <p>The state of the modification to the configuration of BYOL.</p>
     #[serde(rename = "ModificationState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modification_state: Option<String>,
+    pub modification_state: Option<DedicatedTenancyModificationStateEnum>,
     /// <p>The timestamp when the modification of the BYOL configuration was started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -63,6 +65,48 @@ pub struct AssociateIpGroupsRequest {
     pub group_ids: Vec<String>,
 }
 
+impl AssociateIpGroupsRequest {
+    /// Returns a builder for `AssociateIpGroupsRequest`, checking its required members when built.
+    pub fn builder() -> AssociateIpGroupsRequestBuilder {
+        AssociateIpGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateIpGroupsRequest`](struct.AssociateIpGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateIpGroupsRequestBuilder {
+    directory_id: Option<String>,
+    group_ids: Option<Vec<String>>,
+}
+
+impl AssociateIpGroupsRequestBuilder {
+    /// Sets the [`directory_id`](struct.AssociateIpGroupsRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_ids`](struct.AssociateIpGroupsRequest.html#structfield.group_ids) member.
+    pub fn group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateIpGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateIpGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateIpGroupsRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            group_ids: self
+                .group_ids
+                .ok_or(::rusoto_core::BuildError::missing("group_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateIpGroupsResult {}
@@ -77,6 +121,48 @@ pub struct AuthorizeIpRulesRequest {
     pub user_rules: Vec<IpRuleItem>,
 }
 
+impl AuthorizeIpRulesRequest {
+    /// Returns a builder for `AuthorizeIpRulesRequest`, checking its required members when built.
+    pub fn builder() -> AuthorizeIpRulesRequestBuilder {
+        AuthorizeIpRulesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AuthorizeIpRulesRequest`](struct.AuthorizeIpRulesRequest.html).
+#[derive(Default, Debug)]
+pub struct AuthorizeIpRulesRequestBuilder {
+    group_id: Option<String>,
+    user_rules: Option<Vec<IpRuleItem>>,
+}
+
+impl AuthorizeIpRulesRequestBuilder {
+    /// Sets the [`group_id`](struct.AuthorizeIpRulesRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_rules`](struct.AuthorizeIpRulesRequest.html#structfield.user_rules) member.
+    pub fn user_rules<T: Into<Vec<IpRuleItem>>>(mut self, value: T) -> Self {
+        self.user_rules = Some(value.into());
+        self
+    }
+
+    /// Builds the `AuthorizeIpRulesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AuthorizeIpRulesRequest, ::rusoto_core::BuildError> {
+        Ok(AuthorizeIpRulesRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            user_rules: self
+                .user_rules
+                .ok_or(::rusoto_core::BuildError::missing("user_rules"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AuthorizeIpRulesResult {}
@@ -87,7 +173,7 @@ pub struct ClientProperties {
     /// <p>Specifies whether users can cache their credentials on the Amazon WorkSpaces client. When enabled, users can choose to reconnect to their WorkSpaces without re-entering their credentials. </p>
     #[serde(rename = "ReconnectEnabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reconnect_enabled: Option<String>,
+    pub reconnect_enabled: Option<ReconnectEnum>,
 }
 
 /// <p>Information about the Amazon WorkSpaces client.</p>
@@ -104,6 +190,106 @@ pub struct ClientPropertiesResult {
     pub resource_id: Option<String>,
 }
 
+/// <p>The compute type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Compute {
+    /// <p><code>VALUE</code></p>
+    Value,
+    /// <p><code>STANDARD</code></p>
+    Standard,
+    /// <p><code>PERFORMANCE</code></p>
+    Performance,
+    /// <p><code>POWER</code></p>
+    Power,
+    /// <p><code>GRAPHICS</code></p>
+    Graphics,
+    /// <p><code>POWERPRO</code></p>
+    Powerpro,
+    /// <p><code>GRAPHICSPRO</code></p>
+    Graphicspro,
+    /// <p><code>GRAPHICS_G4DN</code></p>
+    GraphicsG4dn,
+    /// <p><code>GRAPHICSPRO_G4DN</code></p>
+    GraphicsproG4dn,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Compute {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Compute::Value => "VALUE",
+            Compute::Standard => "STANDARD",
+            Compute::Performance => "PERFORMANCE",
+            Compute::Power => "POWER",
+            Compute::Graphics => "GRAPHICS",
+            Compute::Powerpro => "POWERPRO",
+            Compute::Graphicspro => "GRAPHICSPRO",
+            Compute::GraphicsG4dn => "GRAPHICS_G4DN",
+            Compute::GraphicsproG4dn => "GRAPHICSPRO_G4DN",
+            Compute::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Compute {
+    fn from(value: &'a str) -> Compute {
+        match value {
+            "VALUE" => Compute::Value,
+            "STANDARD" => Compute::Standard,
+            "PERFORMANCE" => Compute::Performance,
+            "POWER" => Compute::Power,
+            "GRAPHICS" => Compute::Graphics,
+            "POWERPRO" => Compute::Powerpro,
+            "GRAPHICSPRO" => Compute::Graphicspro,
+            "GRAPHICS_G4DN" => Compute::GraphicsG4dn,
+            "GRAPHICSPRO_G4DN" => Compute::GraphicsproG4dn,
+            _ => Compute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Compute {
+    fn from(value: String) -> Compute {
+        Compute::from(value.as_str())
+    }
+}
+
+impl From<Compute> for String {
+    fn from(value: Compute) -> String {
+        match value {
+            Compute::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Compute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Compute {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Compute {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Compute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Compute::from(value))
+    }
+}
+
 /// <p>Describes the compute type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -111,7 +297,83 @@ pub struct ComputeType {
     /// <p>The compute type.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<Compute>,
+}
+
+/// <p>The connection state of the WorkSpace. The connection state is unknown if the WorkSpace is stopped.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    /// <p><code>CONNECTED</code></p>
+    Connected,
+    /// <p><code>DISCONNECTED</code></p>
+    Disconnected,
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConnectionState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionState::Connected => "CONNECTED",
+            ConnectionState::Disconnected => "DISCONNECTED",
+            ConnectionState::UnknownValue => "UNKNOWN",
+            ConnectionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionState {
+    fn from(value: &'a str) -> ConnectionState {
+        match value {
+            "CONNECTED" => ConnectionState::Connected,
+            "DISCONNECTED" => ConnectionState::Disconnected,
+            "UNKNOWN" => ConnectionState::UnknownValue,
+            _ => ConnectionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionState {
+    fn from(value: String) -> ConnectionState {
+        ConnectionState::from(value.as_str())
+    }
+}
+
+impl From<ConnectionState> for String {
+    fn from(value: ConnectionState) -> String {
+        match value {
+            ConnectionState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConnectionState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConnectionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConnectionState::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -135,6 +397,74 @@ pub struct CopyWorkspaceImageRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CopyWorkspaceImageRequest {
+    /// Returns a builder for `CopyWorkspaceImageRequest`, checking its required members when built.
+    pub fn builder() -> CopyWorkspaceImageRequestBuilder {
+        CopyWorkspaceImageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CopyWorkspaceImageRequest`](struct.CopyWorkspaceImageRequest.html).
+#[derive(Default, Debug)]
+pub struct CopyWorkspaceImageRequestBuilder {
+    description: Option<String>,
+    name: Option<String>,
+    source_image_id: Option<String>,
+    source_region: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CopyWorkspaceImageRequestBuilder {
+    /// Sets the [`description`](struct.CopyWorkspaceImageRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CopyWorkspaceImageRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_image_id`](struct.CopyWorkspaceImageRequest.html#structfield.source_image_id) member.
+    pub fn source_image_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_image_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_region`](struct.CopyWorkspaceImageRequest.html#structfield.source_region) member.
+    pub fn source_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CopyWorkspaceImageRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyWorkspaceImageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CopyWorkspaceImageRequest, ::rusoto_core::BuildError> {
+        Ok(CopyWorkspaceImageRequest {
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            source_image_id: self
+                .source_image_id
+                .ok_or(::rusoto_core::BuildError::missing("source_image_id"))?,
+            source_region: self
+                .source_region
+                .ok_or(::rusoto_core::BuildError::missing("source_region"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CopyWorkspaceImageResult {
@@ -163,6 +493,60 @@ pub struct CreateIpGroupRequest {
     pub user_rules: Option<Vec<IpRuleItem>>,
 }
 
+impl CreateIpGroupRequest {
+    /// Returns a builder for `CreateIpGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateIpGroupRequestBuilder {
+        CreateIpGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateIpGroupRequest`](struct.CreateIpGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateIpGroupRequestBuilder {
+    group_desc: Option<String>,
+    group_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    user_rules: Option<Vec<IpRuleItem>>,
+}
+
+impl CreateIpGroupRequestBuilder {
+    /// Sets the [`group_desc`](struct.CreateIpGroupRequest.html#structfield.group_desc) member.
+    pub fn group_desc<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_desc = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.CreateIpGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateIpGroupRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_rules`](struct.CreateIpGroupRequest.html#structfield.user_rules) member.
+    pub fn user_rules<T: Into<Vec<IpRuleItem>>>(mut self, value: T) -> Self {
+        self.user_rules = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateIpGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateIpGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateIpGroupRequest {
+            group_desc: self.group_desc,
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            tags: self.tags,
+            user_rules: self.user_rules,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateIpGroupResult {
@@ -182,6 +566,46 @@ pub struct CreateTagsRequest {
     pub tags: Vec<Tag>,
 }
 
+impl CreateTagsRequest {
+    /// Returns a builder for `CreateTagsRequest`, checking its required members when built.
+    pub fn builder() -> CreateTagsRequestBuilder {
+        CreateTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTagsRequest`](struct.CreateTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTagsRequestBuilder {
+    resource_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateTagsRequestBuilder {
+    /// Sets the [`resource_id`](struct.CreateTagsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTagsRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTagsRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTagsRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateTagsResult {}
@@ -193,6 +617,38 @@ pub struct CreateWorkspacesRequest {
     pub workspaces: Vec<WorkspaceRequest>,
 }
 
+impl CreateWorkspacesRequest {
+    /// Returns a builder for `CreateWorkspacesRequest`, checking its required members when built.
+    pub fn builder() -> CreateWorkspacesRequestBuilder {
+        CreateWorkspacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateWorkspacesRequest`](struct.CreateWorkspacesRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateWorkspacesRequestBuilder {
+    workspaces: Option<Vec<WorkspaceRequest>>,
+}
+
+impl CreateWorkspacesRequestBuilder {
+    /// Sets the [`workspaces`](struct.CreateWorkspacesRequest.html#structfield.workspaces) member.
+    pub fn workspaces<T: Into<Vec<WorkspaceRequest>>>(mut self, value: T) -> Self {
+        self.workspaces = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateWorkspacesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateWorkspacesRequest, ::rusoto_core::BuildError> {
+        Ok(CreateWorkspacesRequest {
+            workspaces: self
+                .workspaces
+                .ok_or(::rusoto_core::BuildError::missing("workspaces"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateWorkspacesResult {
@@ -206,6 +662,228 @@ pub struct CreateWorkspacesResult {
     pub pending_requests: Option<Vec<Workspace>>,
 }
 
+/// <p>The state of the modification to the configuration of BYOL.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DedicatedTenancyModificationStateEnum {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DedicatedTenancyModificationStateEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DedicatedTenancyModificationStateEnum::Pending => "PENDING",
+            DedicatedTenancyModificationStateEnum::Completed => "COMPLETED",
+            DedicatedTenancyModificationStateEnum::Failed => "FAILED",
+            DedicatedTenancyModificationStateEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DedicatedTenancyModificationStateEnum {
+    fn from(value: &'a str) -> DedicatedTenancyModificationStateEnum {
+        match value {
+            "PENDING" => DedicatedTenancyModificationStateEnum::Pending,
+            "COMPLETED" => DedicatedTenancyModificationStateEnum::Completed,
+            "FAILED" => DedicatedTenancyModificationStateEnum::Failed,
+            _ => DedicatedTenancyModificationStateEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DedicatedTenancyModificationStateEnum {
+    fn from(value: String) -> DedicatedTenancyModificationStateEnum {
+        DedicatedTenancyModificationStateEnum::from(value.as_str())
+    }
+}
+
+impl From<DedicatedTenancyModificationStateEnum> for String {
+    fn from(value: DedicatedTenancyModificationStateEnum) -> String {
+        match value {
+            DedicatedTenancyModificationStateEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DedicatedTenancyModificationStateEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DedicatedTenancyModificationStateEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DedicatedTenancyModificationStateEnum {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<DedicatedTenancyModificationStateEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DedicatedTenancyModificationStateEnum::from(value))
+    }
+}
+
+/// <p>The status of BYOL.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DedicatedTenancySupportEnum {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DedicatedTenancySupportEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DedicatedTenancySupportEnum::Enabled => "ENABLED",
+            DedicatedTenancySupportEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DedicatedTenancySupportEnum {
+    fn from(value: &'a str) -> DedicatedTenancySupportEnum {
+        match value {
+            "ENABLED" => DedicatedTenancySupportEnum::Enabled,
+            _ => DedicatedTenancySupportEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DedicatedTenancySupportEnum {
+    fn from(value: String) -> DedicatedTenancySupportEnum {
+        DedicatedTenancySupportEnum::from(value.as_str())
+    }
+}
+
+impl From<DedicatedTenancySupportEnum> for String {
+    fn from(value: DedicatedTenancySupportEnum) -> String {
+        match value {
+            DedicatedTenancySupportEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DedicatedTenancySupportEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DedicatedTenancySupportEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DedicatedTenancySupportEnum {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<DedicatedTenancySupportEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DedicatedTenancySupportEnum::from(value))
+    }
+}
+
+/// <p>The status of BYOL (whether BYOL is being enabled or disabled).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DedicatedTenancySupportResultEnum {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DedicatedTenancySupportResultEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DedicatedTenancySupportResultEnum::Enabled => "ENABLED",
+            DedicatedTenancySupportResultEnum::Disabled => "DISABLED",
+            DedicatedTenancySupportResultEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DedicatedTenancySupportResultEnum {
+    fn from(value: &'a str) -> DedicatedTenancySupportResultEnum {
+        match value {
+            "ENABLED" => DedicatedTenancySupportResultEnum::Enabled,
+            "DISABLED" => DedicatedTenancySupportResultEnum::Disabled,
+            _ => DedicatedTenancySupportResultEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DedicatedTenancySupportResultEnum {
+    fn from(value: String) -> DedicatedTenancySupportResultEnum {
+        DedicatedTenancySupportResultEnum::from(value.as_str())
+    }
+}
+
+impl From<DedicatedTenancySupportResultEnum> for String {
+    fn from(value: DedicatedTenancySupportResultEnum) -> String {
+        match value {
+            DedicatedTenancySupportResultEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DedicatedTenancySupportResultEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DedicatedTenancySupportResultEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DedicatedTenancySupportResultEnum {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<DedicatedTenancySupportResultEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DedicatedTenancySupportResultEnum::from(value))
+    }
+}
+
 /// <p>Describes the default values used to create a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -239,6 +917,36 @@ pub struct DeleteIpGroupRequest {
     pub group_id: String,
 }
 
+impl DeleteIpGroupRequest {
+    /// Returns a builder for `DeleteIpGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIpGroupRequestBuilder {
+        DeleteIpGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIpGroupRequest`](struct.DeleteIpGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIpGroupRequestBuilder {
+    group_id: Option<String>,
+}
+
+impl DeleteIpGroupRequestBuilder {
+    /// Sets the [`group_id`](struct.DeleteIpGroupRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIpGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteIpGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIpGroupRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteIpGroupResult {}
@@ -253,6 +961,46 @@ pub struct DeleteTagsRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl DeleteTagsRequest {
+    /// Returns a builder for `DeleteTagsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTagsRequestBuilder {
+        DeleteTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTagsRequest`](struct.DeleteTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTagsRequestBuilder {
+    resource_id: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl DeleteTagsRequestBuilder {
+    /// Sets the [`resource_id`](struct.DeleteTagsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DeleteTagsRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTagsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTagsRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteTagsResult {}
@@ -264,6 +1012,38 @@ pub struct DeleteWorkspaceImageRequest {
     pub image_id: String,
 }
 
+impl DeleteWorkspaceImageRequest {
+    /// Returns a builder for `DeleteWorkspaceImageRequest`, checking its required members when built.
+    pub fn builder() -> DeleteWorkspaceImageRequestBuilder {
+        DeleteWorkspaceImageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteWorkspaceImageRequest`](struct.DeleteWorkspaceImageRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteWorkspaceImageRequestBuilder {
+    image_id: Option<String>,
+}
+
+impl DeleteWorkspaceImageRequestBuilder {
+    /// Sets the [`image_id`](struct.DeleteWorkspaceImageRequest.html#structfield.image_id) member.
+    pub fn image_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteWorkspaceImageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteWorkspaceImageRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteWorkspaceImageRequest {
+            image_id: self
+                .image_id
+                .ok_or(::rusoto_core::BuildError::missing("image_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteWorkspaceImageResult {}
@@ -276,6 +1056,36 @@ pub struct DescribeAccountModificationsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeAccountModificationsRequest {
+    /// Returns a builder for `DescribeAccountModificationsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAccountModificationsRequestBuilder {
+        DescribeAccountModificationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAccountModificationsRequest`](struct.DescribeAccountModificationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAccountModificationsRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl DescribeAccountModificationsRequestBuilder {
+    /// Sets the [`next_token`](struct.DescribeAccountModificationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAccountModificationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAccountModificationsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAccountModificationsRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAccountModificationsResult {
@@ -302,7 +1112,7 @@ pub struct DescribeAccountResult {
     /// <p>The status of BYOL (whether BYOL is enabled or disabled).</p>
     #[serde(rename = "DedicatedTenancySupport")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dedicated_tenancy_support: Option<String>,
+    pub dedicated_tenancy_support: Option<DedicatedTenancySupportResultEnum>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -312,6 +1122,38 @@ pub struct DescribeClientPropertiesRequest {
     pub resource_ids: Vec<String>,
 }
 
+impl DescribeClientPropertiesRequest {
+    /// Returns a builder for `DescribeClientPropertiesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeClientPropertiesRequestBuilder {
+        DescribeClientPropertiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClientPropertiesRequest`](struct.DescribeClientPropertiesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeClientPropertiesRequestBuilder {
+    resource_ids: Option<Vec<String>>,
+}
+
+impl DescribeClientPropertiesRequestBuilder {
+    /// Sets the [`resource_ids`](struct.DescribeClientPropertiesRequest.html#structfield.resource_ids) member.
+    pub fn resource_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClientPropertiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClientPropertiesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeClientPropertiesRequest {
+            resource_ids: self
+                .resource_ids
+                .ok_or(::rusoto_core::BuildError::missing("resource_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeClientPropertiesResult {
@@ -337,6 +1179,52 @@ pub struct DescribeIpGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeIpGroupsRequest {
+    /// Returns a builder for `DescribeIpGroupsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeIpGroupsRequestBuilder {
+        DescribeIpGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeIpGroupsRequest`](struct.DescribeIpGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeIpGroupsRequestBuilder {
+    group_ids: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeIpGroupsRequestBuilder {
+    /// Sets the [`group_ids`](struct.DescribeIpGroupsRequest.html#structfield.group_ids) member.
+    pub fn group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeIpGroupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeIpGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeIpGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeIpGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeIpGroupsRequest {
+            group_ids: self.group_ids,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeIpGroupsResult {
@@ -357,6 +1245,36 @@ pub struct DescribeTagsRequest {
     pub resource_id: String,
 }
 
+impl DescribeTagsRequest {
+    /// Returns a builder for `DescribeTagsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTagsRequestBuilder {
+        DescribeTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTagsRequest`](struct.DescribeTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTagsRequestBuilder {
+    resource_id: Option<String>,
+}
+
+impl DescribeTagsRequestBuilder {
+    /// Sets the [`resource_id`](struct.DescribeTagsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTagsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTagsRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeTagsResult {
@@ -382,6 +1300,52 @@ pub struct DescribeWorkspaceBundlesRequest {
     pub owner: Option<String>,
 }
 
+impl DescribeWorkspaceBundlesRequest {
+    /// Returns a builder for `DescribeWorkspaceBundlesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeWorkspaceBundlesRequestBuilder {
+        DescribeWorkspaceBundlesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeWorkspaceBundlesRequest`](struct.DescribeWorkspaceBundlesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeWorkspaceBundlesRequestBuilder {
+    bundle_ids: Option<Vec<String>>,
+    next_token: Option<String>,
+    owner: Option<String>,
+}
+
+impl DescribeWorkspaceBundlesRequestBuilder {
+    /// Sets the [`bundle_ids`](struct.DescribeWorkspaceBundlesRequest.html#structfield.bundle_ids) member.
+    pub fn bundle_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.bundle_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeWorkspaceBundlesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner`](struct.DescribeWorkspaceBundlesRequest.html#structfield.owner) member.
+    pub fn owner<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeWorkspaceBundlesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeWorkspaceBundlesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeWorkspaceBundlesRequest {
+            bundle_ids: self.bundle_ids,
+            next_token: self.next_token,
+            owner: self.owner,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeWorkspaceBundlesResult {
@@ -407,6 +1371,44 @@ pub struct DescribeWorkspaceDirectoriesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeWorkspaceDirectoriesRequest {
+    /// Returns a builder for `DescribeWorkspaceDirectoriesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeWorkspaceDirectoriesRequestBuilder {
+        DescribeWorkspaceDirectoriesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeWorkspaceDirectoriesRequest`](struct.DescribeWorkspaceDirectoriesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeWorkspaceDirectoriesRequestBuilder {
+    directory_ids: Option<Vec<String>>,
+    next_token: Option<String>,
+}
+
+impl DescribeWorkspaceDirectoriesRequestBuilder {
+    /// Sets the [`directory_ids`](struct.DescribeWorkspaceDirectoriesRequest.html#structfield.directory_ids) member.
+    pub fn directory_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.directory_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeWorkspaceDirectoriesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeWorkspaceDirectoriesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeWorkspaceDirectoriesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeWorkspaceDirectoriesRequest {
+            directory_ids: self.directory_ids,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeWorkspaceDirectoriesResult {
@@ -436,6 +1438,52 @@ pub struct DescribeWorkspaceImagesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeWorkspaceImagesRequest {
+    /// Returns a builder for `DescribeWorkspaceImagesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeWorkspaceImagesRequestBuilder {
+        DescribeWorkspaceImagesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeWorkspaceImagesRequest`](struct.DescribeWorkspaceImagesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeWorkspaceImagesRequestBuilder {
+    image_ids: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeWorkspaceImagesRequestBuilder {
+    /// Sets the [`image_ids`](struct.DescribeWorkspaceImagesRequest.html#structfield.image_ids) member.
+    pub fn image_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.image_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeWorkspaceImagesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeWorkspaceImagesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeWorkspaceImagesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeWorkspaceImagesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeWorkspaceImagesRequest {
+            image_ids: self.image_ids,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeWorkspaceImagesResult {
@@ -461,6 +1509,45 @@ pub struct DescribeWorkspacesConnectionStatusRequest {
     pub workspace_ids: Option<Vec<String>>,
 }
 
+impl DescribeWorkspacesConnectionStatusRequest {
+    /// Returns a builder for `DescribeWorkspacesConnectionStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeWorkspacesConnectionStatusRequestBuilder {
+        DescribeWorkspacesConnectionStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeWorkspacesConnectionStatusRequest`](struct.DescribeWorkspacesConnectionStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeWorkspacesConnectionStatusRequestBuilder {
+    next_token: Option<String>,
+    workspace_ids: Option<Vec<String>>,
+}
+
+impl DescribeWorkspacesConnectionStatusRequestBuilder {
+    /// Sets the [`next_token`](struct.DescribeWorkspacesConnectionStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workspace_ids`](struct.DescribeWorkspacesConnectionStatusRequest.html#structfield.workspace_ids) member.
+    pub fn workspace_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.workspace_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeWorkspacesConnectionStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeWorkspacesConnectionStatusRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeWorkspacesConnectionStatusRequest {
+            next_token: self.next_token,
+            workspace_ids: self.workspace_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeWorkspacesConnectionStatusResult {
@@ -502,6 +1589,76 @@ pub struct DescribeWorkspacesRequest {
     pub workspace_ids: Option<Vec<String>>,
 }
 
+impl DescribeWorkspacesRequest {
+    /// Returns a builder for `DescribeWorkspacesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeWorkspacesRequestBuilder {
+        DescribeWorkspacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeWorkspacesRequest`](struct.DescribeWorkspacesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeWorkspacesRequestBuilder {
+    bundle_id: Option<String>,
+    directory_id: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    user_name: Option<String>,
+    workspace_ids: Option<Vec<String>>,
+}
+
+impl DescribeWorkspacesRequestBuilder {
+    /// Sets the [`bundle_id`](struct.DescribeWorkspacesRequest.html#structfield.bundle_id) member.
+    pub fn bundle_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bundle_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_id`](struct.DescribeWorkspacesRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeWorkspacesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeWorkspacesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DescribeWorkspacesRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workspace_ids`](struct.DescribeWorkspacesRequest.html#structfield.workspace_ids) member.
+    pub fn workspace_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.workspace_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeWorkspacesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeWorkspacesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeWorkspacesRequest {
+            bundle_id: self.bundle_id,
+            directory_id: self.directory_id,
+            limit: self.limit,
+            next_token: self.next_token,
+            user_name: self.user_name,
+            workspace_ids: self.workspace_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeWorkspacesResult {
@@ -525,6 +1682,48 @@ pub struct DisassociateIpGroupsRequest {
     pub group_ids: Vec<String>,
 }
 
+impl DisassociateIpGroupsRequest {
+    /// Returns a builder for `DisassociateIpGroupsRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateIpGroupsRequestBuilder {
+        DisassociateIpGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateIpGroupsRequest`](struct.DisassociateIpGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateIpGroupsRequestBuilder {
+    directory_id: Option<String>,
+    group_ids: Option<Vec<String>>,
+}
+
+impl DisassociateIpGroupsRequestBuilder {
+    /// Sets the [`directory_id`](struct.DisassociateIpGroupsRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_ids`](struct.DisassociateIpGroupsRequest.html#structfield.group_ids) member.
+    pub fn group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateIpGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateIpGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateIpGroupsRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            group_ids: self
+                .group_ids
+                .ok_or(::rusoto_core::BuildError::missing("group_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateIpGroupsResult {}
@@ -565,7 +1764,7 @@ pub struct FailedWorkspaceChangeRequest {
     pub workspace_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ImportWorkspaceImageRequest {
     /// <p>The identifier of the EC2 image.</p>
     #[serde(rename = "Ec2ImageId")]
@@ -578,13 +1777,83 @@ pub struct ImportWorkspaceImageRequest {
     pub image_name: String,
     /// <p>The ingestion process to be used when importing the image.</p>
     #[serde(rename = "IngestionProcess")]
-    pub ingestion_process: String,
+    pub ingestion_process: WorkspaceImageIngestionProcess,
     /// <p>The tags. Each WorkSpaces resource can have a maximum of 50 tags.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
 }
 
+impl ImportWorkspaceImageRequest {
+    /// Returns a builder for `ImportWorkspaceImageRequest`, checking its required members when built.
+    pub fn builder() -> ImportWorkspaceImageRequestBuilder {
+        ImportWorkspaceImageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportWorkspaceImageRequest`](struct.ImportWorkspaceImageRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportWorkspaceImageRequestBuilder {
+    ec_2_image_id: Option<String>,
+    image_description: Option<String>,
+    image_name: Option<String>,
+    ingestion_process: Option<WorkspaceImageIngestionProcess>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl ImportWorkspaceImageRequestBuilder {
+    /// Sets the [`ec_2_image_id`](struct.ImportWorkspaceImageRequest.html#structfield.ec_2_image_id) member.
+    pub fn ec_2_image_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec_2_image_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_description`](struct.ImportWorkspaceImageRequest.html#structfield.image_description) member.
+    pub fn image_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_name`](struct.ImportWorkspaceImageRequest.html#structfield.image_name) member.
+    pub fn image_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ingestion_process`](struct.ImportWorkspaceImageRequest.html#structfield.ingestion_process) member.
+    pub fn ingestion_process<T: Into<WorkspaceImageIngestionProcess>>(mut self, value: T) -> Self {
+        self.ingestion_process = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.ImportWorkspaceImageRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportWorkspaceImageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ImportWorkspaceImageRequest, ::rusoto_core::BuildError> {
+        Ok(ImportWorkspaceImageRequest {
+            ec_2_image_id: self
+                .ec_2_image_id
+                .ok_or(::rusoto_core::BuildError::missing("ec_2_image_id"))?,
+            image_description: self
+                .image_description
+                .ok_or(::rusoto_core::BuildError::missing("image_description"))?,
+            image_name: self
+                .image_name
+                .ok_or(::rusoto_core::BuildError::missing("image_name"))?,
+            ingestion_process: self
+                .ingestion_process
+                .ok_or(::rusoto_core::BuildError::missing("ingestion_process"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ImportWorkspaceImageResult {
@@ -622,6 +1891,55 @@ pub struct ListAvailableManagementCidrRangesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListAvailableManagementCidrRangesRequest {
+    /// Returns a builder for `ListAvailableManagementCidrRangesRequest`, checking its required members when built.
+    pub fn builder() -> ListAvailableManagementCidrRangesRequestBuilder {
+        ListAvailableManagementCidrRangesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAvailableManagementCidrRangesRequest`](struct.ListAvailableManagementCidrRangesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAvailableManagementCidrRangesRequestBuilder {
+    management_cidr_range_constraint: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAvailableManagementCidrRangesRequestBuilder {
+    /// Sets the [`management_cidr_range_constraint`](struct.ListAvailableManagementCidrRangesRequest.html#structfield.management_cidr_range_constraint) member.
+    pub fn management_cidr_range_constraint<T: Into<String>>(mut self, value: T) -> Self {
+        self.management_cidr_range_constraint = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAvailableManagementCidrRangesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAvailableManagementCidrRangesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAvailableManagementCidrRangesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAvailableManagementCidrRangesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListAvailableManagementCidrRangesRequest {
+            management_cidr_range_constraint: self.management_cidr_range_constraint.ok_or(
+                ::rusoto_core::BuildError::missing("management_cidr_range_constraint"),
+            )?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAvailableManagementCidrRangesResult {
@@ -635,6 +1953,82 @@ pub struct ListAvailableManagementCidrRangesResult {
     pub next_token: Option<String>,
 }
 
+/// <p>The resource.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ModificationResourceEnum {
+    /// <p><code>ROOT_VOLUME</code></p>
+    RootVolume,
+    /// <p><code>USER_VOLUME</code></p>
+    UserVolume,
+    /// <p><code>COMPUTE_TYPE</code></p>
+    ComputeType,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ModificationResourceEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModificationResourceEnum::RootVolume => "ROOT_VOLUME",
+            ModificationResourceEnum::UserVolume => "USER_VOLUME",
+            ModificationResourceEnum::ComputeType => "COMPUTE_TYPE",
+            ModificationResourceEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ModificationResourceEnum {
+    fn from(value: &'a str) -> ModificationResourceEnum {
+        match value {
+            "ROOT_VOLUME" => ModificationResourceEnum::RootVolume,
+            "USER_VOLUME" => ModificationResourceEnum::UserVolume,
+            "COMPUTE_TYPE" => ModificationResourceEnum::ComputeType,
+            _ => ModificationResourceEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ModificationResourceEnum {
+    fn from(value: String) -> ModificationResourceEnum {
+        ModificationResourceEnum::from(value.as_str())
+    }
+}
+
+impl From<ModificationResourceEnum> for String {
+    fn from(value: ModificationResourceEnum) -> String {
+        match value {
+            ModificationResourceEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ModificationResourceEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ModificationResourceEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ModificationResourceEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ModificationResourceEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ModificationResourceEnum::from(value))
+    }
+}
+
 /// <p>Describes a WorkSpace modification.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -642,11 +2036,83 @@ pub struct ModificationState {
     /// <p>The resource.</p>
     #[serde(rename = "Resource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource: Option<String>,
+    pub resource: Option<ModificationResourceEnum>,
     /// <p>The modification state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ModificationStateEnum>,
+}
+
+/// <p>The modification state.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ModificationStateEnum {
+    /// <p><code>UPDATE_INITIATED</code></p>
+    UpdateInitiated,
+    /// <p><code>UPDATE_IN_PROGRESS</code></p>
+    UpdateInProgress,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ModificationStateEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModificationStateEnum::UpdateInitiated => "UPDATE_INITIATED",
+            ModificationStateEnum::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            ModificationStateEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ModificationStateEnum {
+    fn from(value: &'a str) -> ModificationStateEnum {
+        match value {
+            "UPDATE_INITIATED" => ModificationStateEnum::UpdateInitiated,
+            "UPDATE_IN_PROGRESS" => ModificationStateEnum::UpdateInProgress,
+            _ => ModificationStateEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ModificationStateEnum {
+    fn from(value: String) -> ModificationStateEnum {
+        ModificationStateEnum::from(value.as_str())
+    }
+}
+
+impl From<ModificationStateEnum> for String {
+    fn from(value: ModificationStateEnum) -> String {
+        match value {
+            ModificationStateEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ModificationStateEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ModificationStateEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ModificationStateEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ModificationStateEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ModificationStateEnum::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -658,7 +2124,46 @@ pub struct ModifyAccountRequest {
     /// <p>The status of BYOL.</p>
     #[serde(rename = "DedicatedTenancySupport")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dedicated_tenancy_support: Option<String>,
+    pub dedicated_tenancy_support: Option<DedicatedTenancySupportEnum>,
+}
+
+impl ModifyAccountRequest {
+    /// Returns a builder for `ModifyAccountRequest`, checking its required members when built.
+    pub fn builder() -> ModifyAccountRequestBuilder {
+        ModifyAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyAccountRequest`](struct.ModifyAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct ModifyAccountRequestBuilder {
+    dedicated_tenancy_management_cidr_range: Option<String>,
+    dedicated_tenancy_support: Option<DedicatedTenancySupportEnum>,
+}
+
+impl ModifyAccountRequestBuilder {
+    /// Sets the [`dedicated_tenancy_management_cidr_range`](struct.ModifyAccountRequest.html#structfield.dedicated_tenancy_management_cidr_range) member.
+    pub fn dedicated_tenancy_management_cidr_range<T: Into<String>>(mut self, value: T) -> Self {
+        self.dedicated_tenancy_management_cidr_range = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dedicated_tenancy_support`](struct.ModifyAccountRequest.html#structfield.dedicated_tenancy_support) member.
+    pub fn dedicated_tenancy_support<T: Into<DedicatedTenancySupportEnum>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.dedicated_tenancy_support = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ModifyAccountRequest, ::rusoto_core::BuildError> {
+        Ok(ModifyAccountRequest {
+            dedicated_tenancy_management_cidr_range: self.dedicated_tenancy_management_cidr_range,
+            dedicated_tenancy_support: self.dedicated_tenancy_support,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -675,6 +2180,48 @@ pub struct ModifyClientPropertiesRequest {
     pub resource_id: String,
 }
 
+impl ModifyClientPropertiesRequest {
+    /// Returns a builder for `ModifyClientPropertiesRequest`, checking its required members when built.
+    pub fn builder() -> ModifyClientPropertiesRequestBuilder {
+        ModifyClientPropertiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyClientPropertiesRequest`](struct.ModifyClientPropertiesRequest.html).
+#[derive(Default, Debug)]
+pub struct ModifyClientPropertiesRequestBuilder {
+    client_properties: Option<ClientProperties>,
+    resource_id: Option<String>,
+}
+
+impl ModifyClientPropertiesRequestBuilder {
+    /// Sets the [`client_properties`](struct.ModifyClientPropertiesRequest.html#structfield.client_properties) member.
+    pub fn client_properties<T: Into<ClientProperties>>(mut self, value: T) -> Self {
+        self.client_properties = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.ModifyClientPropertiesRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyClientPropertiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyClientPropertiesRequest, ::rusoto_core::BuildError> {
+        Ok(ModifyClientPropertiesRequest {
+            client_properties: self
+                .client_properties
+                .ok_or(::rusoto_core::BuildError::missing("client_properties"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ModifyClientPropertiesResult {}
@@ -689,18 +2236,102 @@ pub struct ModifyWorkspacePropertiesRequest {
     pub workspace_properties: WorkspaceProperties,
 }
 
+impl ModifyWorkspacePropertiesRequest {
+    /// Returns a builder for `ModifyWorkspacePropertiesRequest`, checking its required members when built.
+    pub fn builder() -> ModifyWorkspacePropertiesRequestBuilder {
+        ModifyWorkspacePropertiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyWorkspacePropertiesRequest`](struct.ModifyWorkspacePropertiesRequest.html).
+#[derive(Default, Debug)]
+pub struct ModifyWorkspacePropertiesRequestBuilder {
+    workspace_id: Option<String>,
+    workspace_properties: Option<WorkspaceProperties>,
+}
+
+impl ModifyWorkspacePropertiesRequestBuilder {
+    /// Sets the [`workspace_id`](struct.ModifyWorkspacePropertiesRequest.html#structfield.workspace_id) member.
+    pub fn workspace_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.workspace_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workspace_properties`](struct.ModifyWorkspacePropertiesRequest.html#structfield.workspace_properties) member.
+    pub fn workspace_properties<T: Into<WorkspaceProperties>>(mut self, value: T) -> Self {
+        self.workspace_properties = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyWorkspacePropertiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyWorkspacePropertiesRequest, ::rusoto_core::BuildError> {
+        Ok(ModifyWorkspacePropertiesRequest {
+            workspace_id: self
+                .workspace_id
+                .ok_or(::rusoto_core::BuildError::missing("workspace_id"))?,
+            workspace_properties: self
+                .workspace_properties
+                .ok_or(::rusoto_core::BuildError::missing("workspace_properties"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ModifyWorkspacePropertiesResult {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ModifyWorkspaceStateRequest {
     /// <p>The identifier of the WorkSpace.</p>
     #[serde(rename = "WorkspaceId")]
     pub workspace_id: String,
     /// <p>The WorkSpace state.</p>
     #[serde(rename = "WorkspaceState")]
-    pub workspace_state: String,
+    pub workspace_state: TargetWorkspaceState,
+}
+
+impl ModifyWorkspaceStateRequest {
+    /// Returns a builder for `ModifyWorkspaceStateRequest`, checking its required members when built.
+    pub fn builder() -> ModifyWorkspaceStateRequestBuilder {
+        ModifyWorkspaceStateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyWorkspaceStateRequest`](struct.ModifyWorkspaceStateRequest.html).
+#[derive(Default, Debug)]
+pub struct ModifyWorkspaceStateRequestBuilder {
+    workspace_id: Option<String>,
+    workspace_state: Option<TargetWorkspaceState>,
+}
+
+impl ModifyWorkspaceStateRequestBuilder {
+    /// Sets the [`workspace_id`](struct.ModifyWorkspaceStateRequest.html#structfield.workspace_id) member.
+    pub fn workspace_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.workspace_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workspace_state`](struct.ModifyWorkspaceStateRequest.html#structfield.workspace_state) member.
+    pub fn workspace_state<T: Into<TargetWorkspaceState>>(mut self, value: T) -> Self {
+        self.workspace_state = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyWorkspaceStateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyWorkspaceStateRequest, ::rusoto_core::BuildError> {
+        Ok(ModifyWorkspaceStateRequest {
+            workspace_id: self
+                .workspace_id
+                .ok_or(::rusoto_core::BuildError::missing("workspace_id"))?,
+            workspace_state: self
+                .workspace_state
+                .ok_or(::rusoto_core::BuildError::missing("workspace_state"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -714,7 +2345,79 @@ pub struct OperatingSystem {
     /// <p>The operating system.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OperatingSystemType>,
+}
+
+/// <p>The operating system.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperatingSystemType {
+    /// <p><code>WINDOWS</code></p>
+    Windows,
+    /// <p><code>LINUX</code></p>
+    Linux,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OperatingSystemType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperatingSystemType::Windows => "WINDOWS",
+            OperatingSystemType::Linux => "LINUX",
+            OperatingSystemType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperatingSystemType {
+    fn from(value: &'a str) -> OperatingSystemType {
+        match value {
+            "WINDOWS" => OperatingSystemType::Windows,
+            "LINUX" => OperatingSystemType::Linux,
+            _ => OperatingSystemType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperatingSystemType {
+    fn from(value: String) -> OperatingSystemType {
+        OperatingSystemType::from(value.as_str())
+    }
+}
+
+impl From<OperatingSystemType> for String {
+    fn from(value: OperatingSystemType) -> String {
+        match value {
+            OperatingSystemType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OperatingSystemType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OperatingSystemType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperatingSystemType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OperatingSystemType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OperatingSystemType::from(value))
+    }
 }
 
 /// <p>Describes the information used to reboot a WorkSpace.</p>
@@ -732,6 +2435,38 @@ pub struct RebootWorkspacesRequest {
     pub reboot_workspace_requests: Vec<RebootRequest>,
 }
 
+impl RebootWorkspacesRequest {
+    /// Returns a builder for `RebootWorkspacesRequest`, checking its required members when built.
+    pub fn builder() -> RebootWorkspacesRequestBuilder {
+        RebootWorkspacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RebootWorkspacesRequest`](struct.RebootWorkspacesRequest.html).
+#[derive(Default, Debug)]
+pub struct RebootWorkspacesRequestBuilder {
+    reboot_workspace_requests: Option<Vec<RebootRequest>>,
+}
+
+impl RebootWorkspacesRequestBuilder {
+    /// Sets the [`reboot_workspace_requests`](struct.RebootWorkspacesRequest.html#structfield.reboot_workspace_requests) member.
+    pub fn reboot_workspace_requests<T: Into<Vec<RebootRequest>>>(mut self, value: T) -> Self {
+        self.reboot_workspace_requests = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootWorkspacesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RebootWorkspacesRequest, ::rusoto_core::BuildError> {
+        Ok(RebootWorkspacesRequest {
+            reboot_workspace_requests: self.reboot_workspace_requests.ok_or(
+                ::rusoto_core::BuildError::missing("reboot_workspace_requests"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RebootWorkspacesResult {
@@ -756,6 +2491,38 @@ pub struct RebuildWorkspacesRequest {
     pub rebuild_workspace_requests: Vec<RebuildRequest>,
 }
 
+impl RebuildWorkspacesRequest {
+    /// Returns a builder for `RebuildWorkspacesRequest`, checking its required members when built.
+    pub fn builder() -> RebuildWorkspacesRequestBuilder {
+        RebuildWorkspacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RebuildWorkspacesRequest`](struct.RebuildWorkspacesRequest.html).
+#[derive(Default, Debug)]
+pub struct RebuildWorkspacesRequestBuilder {
+    rebuild_workspace_requests: Option<Vec<RebuildRequest>>,
+}
+
+impl RebuildWorkspacesRequestBuilder {
+    /// Sets the [`rebuild_workspace_requests`](struct.RebuildWorkspacesRequest.html#structfield.rebuild_workspace_requests) member.
+    pub fn rebuild_workspace_requests<T: Into<Vec<RebuildRequest>>>(mut self, value: T) -> Self {
+        self.rebuild_workspace_requests = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebuildWorkspacesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RebuildWorkspacesRequest, ::rusoto_core::BuildError> {
+        Ok(RebuildWorkspacesRequest {
+            rebuild_workspace_requests: self.rebuild_workspace_requests.ok_or(
+                ::rusoto_core::BuildError::missing("rebuild_workspace_requests"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RebuildWorkspacesResult {
@@ -765,6 +2532,78 @@ pub struct RebuildWorkspacesResult {
     pub failed_requests: Option<Vec<FailedWorkspaceChangeRequest>>,
 }
 
+/// <p>Specifies whether users can cache their credentials on the Amazon WorkSpaces client. When enabled, users can choose to reconnect to their WorkSpaces without re-entering their credentials. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReconnectEnum {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReconnectEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReconnectEnum::Enabled => "ENABLED",
+            ReconnectEnum::Disabled => "DISABLED",
+            ReconnectEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReconnectEnum {
+    fn from(value: &'a str) -> ReconnectEnum {
+        match value {
+            "ENABLED" => ReconnectEnum::Enabled,
+            "DISABLED" => ReconnectEnum::Disabled,
+            _ => ReconnectEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReconnectEnum {
+    fn from(value: String) -> ReconnectEnum {
+        ReconnectEnum::from(value.as_str())
+    }
+}
+
+impl From<ReconnectEnum> for String {
+    fn from(value: ReconnectEnum) -> String {
+        match value {
+            ReconnectEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReconnectEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReconnectEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReconnectEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReconnectEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReconnectEnum::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RevokeIpRulesRequest {
     /// <p>The identifier of the group.</p>
@@ -775,6 +2614,46 @@ pub struct RevokeIpRulesRequest {
     pub user_rules: Vec<String>,
 }
 
+impl RevokeIpRulesRequest {
+    /// Returns a builder for `RevokeIpRulesRequest`, checking its required members when built.
+    pub fn builder() -> RevokeIpRulesRequestBuilder {
+        RevokeIpRulesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RevokeIpRulesRequest`](struct.RevokeIpRulesRequest.html).
+#[derive(Default, Debug)]
+pub struct RevokeIpRulesRequestBuilder {
+    group_id: Option<String>,
+    user_rules: Option<Vec<String>>,
+}
+
+impl RevokeIpRulesRequestBuilder {
+    /// Sets the [`group_id`](struct.RevokeIpRulesRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_rules`](struct.RevokeIpRulesRequest.html#structfield.user_rules) member.
+    pub fn user_rules<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.user_rules = Some(value.into());
+        self
+    }
+
+    /// Builds the `RevokeIpRulesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RevokeIpRulesRequest, ::rusoto_core::BuildError> {
+        Ok(RevokeIpRulesRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            user_rules: self
+                .user_rules
+                .ok_or(::rusoto_core::BuildError::missing("user_rules"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RevokeIpRulesResult {}
@@ -789,6 +2668,82 @@ pub struct RootStorage {
     pub capacity: Option<String>,
 }
 
+/// <p>The running mode. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/running-mode.html">Manage the WorkSpace Running Mode</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RunningMode {
+    /// <p><code>AUTO_STOP</code></p>
+    AutoStop,
+    /// <p><code>ALWAYS_ON</code></p>
+    AlwaysOn,
+    /// <p><code>MANUAL</code></p>
+    Manual,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RunningMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RunningMode::AutoStop => "AUTO_STOP",
+            RunningMode::AlwaysOn => "ALWAYS_ON",
+            RunningMode::Manual => "MANUAL",
+            RunningMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RunningMode {
+    fn from(value: &'a str) -> RunningMode {
+        match value {
+            "AUTO_STOP" => RunningMode::AutoStop,
+            "ALWAYS_ON" => RunningMode::AlwaysOn,
+            "MANUAL" => RunningMode::Manual,
+            _ => RunningMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RunningMode {
+    fn from(value: String) -> RunningMode {
+        RunningMode::from(value.as_str())
+    }
+}
+
+impl From<RunningMode> for String {
+    fn from(value: RunningMode) -> String {
+        match value {
+            RunningMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RunningMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RunningMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RunningMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RunningMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RunningMode::from(value))
+    }
+}
+
 /// <p>Information used to start a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartRequest {
@@ -805,6 +2760,36 @@ pub struct StartWorkspacesRequest {
     pub start_workspace_requests: Vec<StartRequest>,
 }
 
+impl StartWorkspacesRequest {
+    /// Returns a builder for `StartWorkspacesRequest`, checking its required members when built.
+    pub fn builder() -> StartWorkspacesRequestBuilder {
+        StartWorkspacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartWorkspacesRequest`](struct.StartWorkspacesRequest.html).
+#[derive(Default, Debug)]
+pub struct StartWorkspacesRequestBuilder {
+    start_workspace_requests: Option<Vec<StartRequest>>,
+}
+
+impl StartWorkspacesRequestBuilder {
+    /// Sets the [`start_workspace_requests`](struct.StartWorkspacesRequest.html#structfield.start_workspace_requests) member.
+    pub fn start_workspace_requests<T: Into<Vec<StartRequest>>>(mut self, value: T) -> Self {
+        self.start_workspace_requests = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartWorkspacesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartWorkspacesRequest, ::rusoto_core::BuildError> {
+        Ok(StartWorkspacesRequest {
+            start_workspace_requests: self.start_workspace_requests.ok_or(
+                ::rusoto_core::BuildError::missing("start_workspace_requests"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartWorkspacesResult {
@@ -830,6 +2815,36 @@ pub struct StopWorkspacesRequest {
     pub stop_workspace_requests: Vec<StopRequest>,
 }
 
+impl StopWorkspacesRequest {
+    /// Returns a builder for `StopWorkspacesRequest`, checking its required members when built.
+    pub fn builder() -> StopWorkspacesRequestBuilder {
+        StopWorkspacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopWorkspacesRequest`](struct.StopWorkspacesRequest.html).
+#[derive(Default, Debug)]
+pub struct StopWorkspacesRequestBuilder {
+    stop_workspace_requests: Option<Vec<StopRequest>>,
+}
+
+impl StopWorkspacesRequestBuilder {
+    /// Sets the [`stop_workspace_requests`](struct.StopWorkspacesRequest.html#structfield.stop_workspace_requests) member.
+    pub fn stop_workspace_requests<T: Into<Vec<StopRequest>>>(mut self, value: T) -> Self {
+        self.stop_workspace_requests = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopWorkspacesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopWorkspacesRequest, ::rusoto_core::BuildError> {
+        Ok(StopWorkspacesRequest {
+            stop_workspace_requests: self.stop_workspace_requests.ok_or(
+                ::rusoto_core::BuildError::missing("stop_workspace_requests"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopWorkspacesResult {
@@ -851,6 +2866,78 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+/// <p>The WorkSpace state.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetWorkspaceState {
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>ADMIN_MAINTENANCE</code></p>
+    AdminMaintenance,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetWorkspaceState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetWorkspaceState::Available => "AVAILABLE",
+            TargetWorkspaceState::AdminMaintenance => "ADMIN_MAINTENANCE",
+            TargetWorkspaceState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetWorkspaceState {
+    fn from(value: &'a str) -> TargetWorkspaceState {
+        match value {
+            "AVAILABLE" => TargetWorkspaceState::Available,
+            "ADMIN_MAINTENANCE" => TargetWorkspaceState::AdminMaintenance,
+            _ => TargetWorkspaceState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetWorkspaceState {
+    fn from(value: String) -> TargetWorkspaceState {
+        TargetWorkspaceState::from(value.as_str())
+    }
+}
+
+impl From<TargetWorkspaceState> for String {
+    fn from(value: TargetWorkspaceState) -> String {
+        match value {
+            TargetWorkspaceState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetWorkspaceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TargetWorkspaceState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetWorkspaceState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TargetWorkspaceState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TargetWorkspaceState::from(value))
+    }
+}
+
 /// <p>Describes the information used to terminate a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TerminateRequest {
@@ -866,6 +2953,41 @@ pub struct TerminateWorkspacesRequest {
     pub terminate_workspace_requests: Vec<TerminateRequest>,
 }
 
+impl TerminateWorkspacesRequest {
+    /// Returns a builder for `TerminateWorkspacesRequest`, checking its required members when built.
+    pub fn builder() -> TerminateWorkspacesRequestBuilder {
+        TerminateWorkspacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TerminateWorkspacesRequest`](struct.TerminateWorkspacesRequest.html).
+#[derive(Default, Debug)]
+pub struct TerminateWorkspacesRequestBuilder {
+    terminate_workspace_requests: Option<Vec<TerminateRequest>>,
+}
+
+impl TerminateWorkspacesRequestBuilder {
+    /// Sets the [`terminate_workspace_requests`](struct.TerminateWorkspacesRequest.html#structfield.terminate_workspace_requests) member.
+    pub fn terminate_workspace_requests<T: Into<Vec<TerminateRequest>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.terminate_workspace_requests = Some(value.into());
+        self
+    }
+
+    /// Builds the `TerminateWorkspacesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TerminateWorkspacesRequest, ::rusoto_core::BuildError> {
+        Ok(TerminateWorkspacesRequest {
+            terminate_workspace_requests: self.terminate_workspace_requests.ok_or(
+                ::rusoto_core::BuildError::missing("terminate_workspace_requests"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TerminateWorkspacesResult {
@@ -885,6 +3007,48 @@ pub struct UpdateRulesOfIpGroupRequest {
     pub user_rules: Vec<IpRuleItem>,
 }
 
+impl UpdateRulesOfIpGroupRequest {
+    /// Returns a builder for `UpdateRulesOfIpGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRulesOfIpGroupRequestBuilder {
+        UpdateRulesOfIpGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRulesOfIpGroupRequest`](struct.UpdateRulesOfIpGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRulesOfIpGroupRequestBuilder {
+    group_id: Option<String>,
+    user_rules: Option<Vec<IpRuleItem>>,
+}
+
+impl UpdateRulesOfIpGroupRequestBuilder {
+    /// Sets the [`group_id`](struct.UpdateRulesOfIpGroupRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_rules`](struct.UpdateRulesOfIpGroupRequest.html#structfield.user_rules) member.
+    pub fn user_rules<T: Into<Vec<IpRuleItem>>>(mut self, value: T) -> Self {
+        self.user_rules = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRulesOfIpGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRulesOfIpGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRulesOfIpGroupRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            user_rules: self
+                .user_rules
+                .ok_or(::rusoto_core::BuildError::missing("user_rules"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRulesOfIpGroupResult {}
@@ -938,7 +3102,7 @@ pub struct Workspace {
     /// <p>The operational state of the WorkSpace.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkspaceState>,
     /// <p>The identifier of the subnet for the WorkSpace.</p>
     #[serde(rename = "SubnetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1006,15 +3170,17 @@ pub struct WorkspaceConnectionStatus {
     /// <p>The connection state of the WorkSpace. The connection state is unknown if the WorkSpace is stopped.</p>
     #[serde(rename = "ConnectionState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state: Option<String>,
+    pub connection_state: Option<ConnectionState>,
     /// <p>The timestamp of the connection status check.</p>
     #[serde(rename = "ConnectionStateCheckTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state_check_timestamp: Option<f64>,
+    pub connection_state_check_timestamp: Option<EpochTimestamp>,
     /// <p>The timestamp of the last known user connection.</p>
     #[serde(rename = "LastKnownUserConnectionTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_known_user_connection_timestamp: Option<f64>,
+    pub last_known_user_connection_timestamp: Option<EpochTimestamp>,
     /// <p>The identifier of the WorkSpace.</p>
     #[serde(rename = "WorkspaceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1044,7 +3210,7 @@ pub struct WorkspaceDirectory {
     /// <p>The directory type.</p>
     #[serde(rename = "DirectoryType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub directory_type: Option<String>,
+    pub directory_type: Option<WorkspaceDirectoryType>,
     /// <p>The IP addresses of the DNS servers for the directory.</p>
     #[serde(rename = "DnsIpAddresses")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1060,7 +3226,7 @@ pub struct WorkspaceDirectory {
     /// <p>The state of the directory's registration with Amazon WorkSpaces</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkspaceDirectoryState>,
     /// <p>The identifiers of the subnets used with the directory.</p>
     #[serde(rename = "SubnetIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1079,6 +3245,162 @@ pub struct WorkspaceDirectory {
     pub ip_group_ids: Option<Vec<String>>,
 }
 
+/// <p>The state of the directory's registration with Amazon WorkSpaces</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkspaceDirectoryState {
+    /// <p><code>REGISTERING</code></p>
+    Registering,
+    /// <p><code>REGISTERED</code></p>
+    Registered,
+    /// <p><code>DEREGISTERING</code></p>
+    Deregistering,
+    /// <p><code>DEREGISTERED</code></p>
+    Deregistered,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkspaceDirectoryState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceDirectoryState::Registering => "REGISTERING",
+            WorkspaceDirectoryState::Registered => "REGISTERED",
+            WorkspaceDirectoryState::Deregistering => "DEREGISTERING",
+            WorkspaceDirectoryState::Deregistered => "DEREGISTERED",
+            WorkspaceDirectoryState::Error => "ERROR",
+            WorkspaceDirectoryState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceDirectoryState {
+    fn from(value: &'a str) -> WorkspaceDirectoryState {
+        match value {
+            "REGISTERING" => WorkspaceDirectoryState::Registering,
+            "REGISTERED" => WorkspaceDirectoryState::Registered,
+            "DEREGISTERING" => WorkspaceDirectoryState::Deregistering,
+            "DEREGISTERED" => WorkspaceDirectoryState::Deregistered,
+            "ERROR" => WorkspaceDirectoryState::Error,
+            _ => WorkspaceDirectoryState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceDirectoryState {
+    fn from(value: String) -> WorkspaceDirectoryState {
+        WorkspaceDirectoryState::from(value.as_str())
+    }
+}
+
+impl From<WorkspaceDirectoryState> for String {
+    fn from(value: WorkspaceDirectoryState) -> String {
+        match value {
+            WorkspaceDirectoryState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkspaceDirectoryState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkspaceDirectoryState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkspaceDirectoryState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WorkspaceDirectoryState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkspaceDirectoryState::from(value))
+    }
+}
+
+/// <p>The directory type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkspaceDirectoryType {
+    /// <p><code>SIMPLE_AD</code></p>
+    SimpleAd,
+    /// <p><code>AD_CONNECTOR</code></p>
+    AdConnector,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkspaceDirectoryType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceDirectoryType::SimpleAd => "SIMPLE_AD",
+            WorkspaceDirectoryType::AdConnector => "AD_CONNECTOR",
+            WorkspaceDirectoryType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceDirectoryType {
+    fn from(value: &'a str) -> WorkspaceDirectoryType {
+        match value {
+            "SIMPLE_AD" => WorkspaceDirectoryType::SimpleAd,
+            "AD_CONNECTOR" => WorkspaceDirectoryType::AdConnector,
+            _ => WorkspaceDirectoryType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceDirectoryType {
+    fn from(value: String) -> WorkspaceDirectoryType {
+        WorkspaceDirectoryType::from(value.as_str())
+    }
+}
+
+impl From<WorkspaceDirectoryType> for String {
+    fn from(value: WorkspaceDirectoryType) -> String {
+        match value {
+            WorkspaceDirectoryType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkspaceDirectoryType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkspaceDirectoryType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkspaceDirectoryType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WorkspaceDirectoryType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkspaceDirectoryType::from(value))
+    }
+}
+
 /// <p>Describes a WorkSpace image.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1110,11 +3432,255 @@ pub struct WorkspaceImage {
     /// <p>Specifies whether the image is running on dedicated hardware. When bring your own license (BYOL) is enabled, this value is set to DEDICATED. </p>
     #[serde(rename = "RequiredTenancy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub required_tenancy: Option<String>,
+    pub required_tenancy: Option<WorkspaceImageRequiredTenancy>,
     /// <p>The status of the image.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkspaceImageState>,
+}
+
+/// <p>The ingestion process to be used when importing the image.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkspaceImageIngestionProcess {
+    /// <p><code>BYOL_REGULAR</code></p>
+    ByolRegular,
+    /// <p><code>BYOL_GRAPHICS</code></p>
+    ByolGraphics,
+    /// <p><code>BYOL_GRAPHICSPRO</code></p>
+    ByolGraphicspro,
+    /// <p><code>BYOL_GRAPHICS_G4DN</code></p>
+    ByolGraphicsG4dn,
+    /// <p><code>BYOL_REGULAR_WSP</code></p>
+    ByolRegularWsp,
+    /// <p><code>BYOL_REGULAR_BYOP</code></p>
+    ByolRegularByop,
+    /// <p><code>BYOL_GRAPHICS_G4DN_BYOP</code></p>
+    ByolGraphicsG4dnByop,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkspaceImageIngestionProcess {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceImageIngestionProcess::ByolRegular => "BYOL_REGULAR",
+            WorkspaceImageIngestionProcess::ByolGraphics => "BYOL_GRAPHICS",
+            WorkspaceImageIngestionProcess::ByolGraphicspro => "BYOL_GRAPHICSPRO",
+            WorkspaceImageIngestionProcess::ByolGraphicsG4dn => "BYOL_GRAPHICS_G4DN",
+            WorkspaceImageIngestionProcess::ByolRegularWsp => "BYOL_REGULAR_WSP",
+            WorkspaceImageIngestionProcess::ByolRegularByop => "BYOL_REGULAR_BYOP",
+            WorkspaceImageIngestionProcess::ByolGraphicsG4dnByop => "BYOL_GRAPHICS_G4DN_BYOP",
+            WorkspaceImageIngestionProcess::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceImageIngestionProcess {
+    fn from(value: &'a str) -> WorkspaceImageIngestionProcess {
+        match value {
+            "BYOL_REGULAR" => WorkspaceImageIngestionProcess::ByolRegular,
+            "BYOL_GRAPHICS" => WorkspaceImageIngestionProcess::ByolGraphics,
+            "BYOL_GRAPHICSPRO" => WorkspaceImageIngestionProcess::ByolGraphicspro,
+            "BYOL_GRAPHICS_G4DN" => WorkspaceImageIngestionProcess::ByolGraphicsG4dn,
+            "BYOL_REGULAR_WSP" => WorkspaceImageIngestionProcess::ByolRegularWsp,
+            "BYOL_REGULAR_BYOP" => WorkspaceImageIngestionProcess::ByolRegularByop,
+            "BYOL_GRAPHICS_G4DN_BYOP" => WorkspaceImageIngestionProcess::ByolGraphicsG4dnByop,
+            _ => WorkspaceImageIngestionProcess::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceImageIngestionProcess {
+    fn from(value: String) -> WorkspaceImageIngestionProcess {
+        WorkspaceImageIngestionProcess::from(value.as_str())
+    }
+}
+
+impl From<WorkspaceImageIngestionProcess> for String {
+    fn from(value: WorkspaceImageIngestionProcess) -> String {
+        match value {
+            WorkspaceImageIngestionProcess::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkspaceImageIngestionProcess {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkspaceImageIngestionProcess {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkspaceImageIngestionProcess {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<WorkspaceImageIngestionProcess, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkspaceImageIngestionProcess::from(value))
+    }
+}
+
+/// <p>Specifies whether the image is running on dedicated hardware. When bring your own license (BYOL) is enabled, this value is set to DEDICATED. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkspaceImageRequiredTenancy {
+    /// <p><code>DEFAULT</code></p>
+    Default,
+    /// <p><code>DEDICATED</code></p>
+    Dedicated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkspaceImageRequiredTenancy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceImageRequiredTenancy::Default => "DEFAULT",
+            WorkspaceImageRequiredTenancy::Dedicated => "DEDICATED",
+            WorkspaceImageRequiredTenancy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceImageRequiredTenancy {
+    fn from(value: &'a str) -> WorkspaceImageRequiredTenancy {
+        match value {
+            "DEFAULT" => WorkspaceImageRequiredTenancy::Default,
+            "DEDICATED" => WorkspaceImageRequiredTenancy::Dedicated,
+            _ => WorkspaceImageRequiredTenancy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceImageRequiredTenancy {
+    fn from(value: String) -> WorkspaceImageRequiredTenancy {
+        WorkspaceImageRequiredTenancy::from(value.as_str())
+    }
+}
+
+impl From<WorkspaceImageRequiredTenancy> for String {
+    fn from(value: WorkspaceImageRequiredTenancy) -> String {
+        match value {
+            WorkspaceImageRequiredTenancy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkspaceImageRequiredTenancy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkspaceImageRequiredTenancy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkspaceImageRequiredTenancy {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<WorkspaceImageRequiredTenancy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkspaceImageRequiredTenancy::from(value))
+    }
+}
+
+/// <p>The status of the image.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkspaceImageState {
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkspaceImageState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceImageState::Available => "AVAILABLE",
+            WorkspaceImageState::Pending => "PENDING",
+            WorkspaceImageState::Error => "ERROR",
+            WorkspaceImageState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceImageState {
+    fn from(value: &'a str) -> WorkspaceImageState {
+        match value {
+            "AVAILABLE" => WorkspaceImageState::Available,
+            "PENDING" => WorkspaceImageState::Pending,
+            "ERROR" => WorkspaceImageState::Error,
+            _ => WorkspaceImageState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceImageState {
+    fn from(value: String) -> WorkspaceImageState {
+        WorkspaceImageState::from(value.as_str())
+    }
+}
+
+impl From<WorkspaceImageState> for String {
+    fn from(value: WorkspaceImageState) -> String {
+        match value {
+            WorkspaceImageState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkspaceImageState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkspaceImageState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkspaceImageState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WorkspaceImageState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkspaceImageState::from(value))
+    }
 }
 
 /// <p>Describes a WorkSpace.</p>
@@ -1123,7 +3689,7 @@ pub struct WorkspaceProperties {
     /// <p>The compute type. For more information, see <a href="http://aws.amazon.com/workspaces/details/#Amazon_WorkSpaces_Bundles">Amazon WorkSpaces Bundles</a>.</p>
     #[serde(rename = "ComputeTypeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_type_name: Option<String>,
+    pub compute_type_name: Option<Compute>,
     /// <p>The size of the root volume.</p>
     #[serde(rename = "RootVolumeSizeGib")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1131,7 +3697,7 @@ pub struct WorkspaceProperties {
     /// <p>The running mode. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/running-mode.html">Manage the WorkSpace Running Mode</a>.</p>
     #[serde(rename = "RunningMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub running_mode: Option<String>,
+    pub running_mode: Option<RunningMode>,
     /// <p>The time after a user logs off when WorkSpaces are automatically stopped. Configured in 60 minute intervals.</p>
     #[serde(rename = "RunningModeAutoStopTimeoutInMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1176,6 +3742,138 @@ pub struct WorkspaceRequest {
     pub workspace_properties: Option<WorkspaceProperties>,
 }
 
+/// <p>The operational state of the WorkSpace.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkspaceState {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>IMPAIRED</code></p>
+    Impaired,
+    /// <p><code>UNHEALTHY</code></p>
+    Unhealthy,
+    /// <p><code>REBOOTING</code></p>
+    Rebooting,
+    /// <p><code>STARTING</code></p>
+    Starting,
+    /// <p><code>REBUILDING</code></p>
+    Rebuilding,
+    /// <p><code>RESTORING</code></p>
+    Restoring,
+    /// <p><code>MAINTENANCE</code></p>
+    Maintenance,
+    /// <p><code>ADMIN_MAINTENANCE</code></p>
+    AdminMaintenance,
+    /// <p><code>TERMINATING</code></p>
+    Terminating,
+    /// <p><code>TERMINATED</code></p>
+    Terminated,
+    /// <p><code>SUSPENDED</code></p>
+    Suspended,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>STOPPING</code></p>
+    Stopping,
+    /// <p><code>STOPPED</code></p>
+    Stopped,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkspaceState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkspaceState::Pending => "PENDING",
+            WorkspaceState::Available => "AVAILABLE",
+            WorkspaceState::Impaired => "IMPAIRED",
+            WorkspaceState::Unhealthy => "UNHEALTHY",
+            WorkspaceState::Rebooting => "REBOOTING",
+            WorkspaceState::Starting => "STARTING",
+            WorkspaceState::Rebuilding => "REBUILDING",
+            WorkspaceState::Restoring => "RESTORING",
+            WorkspaceState::Maintenance => "MAINTENANCE",
+            WorkspaceState::AdminMaintenance => "ADMIN_MAINTENANCE",
+            WorkspaceState::Terminating => "TERMINATING",
+            WorkspaceState::Terminated => "TERMINATED",
+            WorkspaceState::Suspended => "SUSPENDED",
+            WorkspaceState::Updating => "UPDATING",
+            WorkspaceState::Stopping => "STOPPING",
+            WorkspaceState::Stopped => "STOPPED",
+            WorkspaceState::Error => "ERROR",
+            WorkspaceState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkspaceState {
+    fn from(value: &'a str) -> WorkspaceState {
+        match value {
+            "PENDING" => WorkspaceState::Pending,
+            "AVAILABLE" => WorkspaceState::Available,
+            "IMPAIRED" => WorkspaceState::Impaired,
+            "UNHEALTHY" => WorkspaceState::Unhealthy,
+            "REBOOTING" => WorkspaceState::Rebooting,
+            "STARTING" => WorkspaceState::Starting,
+            "REBUILDING" => WorkspaceState::Rebuilding,
+            "RESTORING" => WorkspaceState::Restoring,
+            "MAINTENANCE" => WorkspaceState::Maintenance,
+            "ADMIN_MAINTENANCE" => WorkspaceState::AdminMaintenance,
+            "TERMINATING" => WorkspaceState::Terminating,
+            "TERMINATED" => WorkspaceState::Terminated,
+            "SUSPENDED" => WorkspaceState::Suspended,
+            "UPDATING" => WorkspaceState::Updating,
+            "STOPPING" => WorkspaceState::Stopping,
+            "STOPPED" => WorkspaceState::Stopped,
+            "ERROR" => WorkspaceState::Error,
+            _ => WorkspaceState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkspaceState {
+    fn from(value: String) -> WorkspaceState {
+        WorkspaceState::from(value.as_str())
+    }
+}
+
+impl From<WorkspaceState> for String {
+    fn from(value: WorkspaceState) -> String {
+        match value {
+            WorkspaceState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkspaceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkspaceState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkspaceState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WorkspaceState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkspaceState::from(value))
+    }
+}
+
 /// <p>Describes an IP access control group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2943,6 +5641,214 @@ pub trait Workspaces {
         input: UpdateRulesOfIpGroupRequest,
     ) -> RusotoFuture<UpdateRulesOfIpGroupResult, UpdateRulesOfIpGroupError>;
 }
+impl<T: ?Sized + Workspaces> Workspaces for ::std::sync::Arc<T> {
+    fn associate_ip_groups(
+        &self,
+        input: AssociateIpGroupsRequest,
+    ) -> RusotoFuture<AssociateIpGroupsResult, AssociateIpGroupsError> {
+        (**self).associate_ip_groups(input)
+    }
+    fn authorize_ip_rules(
+        &self,
+        input: AuthorizeIpRulesRequest,
+    ) -> RusotoFuture<AuthorizeIpRulesResult, AuthorizeIpRulesError> {
+        (**self).authorize_ip_rules(input)
+    }
+    fn copy_workspace_image(
+        &self,
+        input: CopyWorkspaceImageRequest,
+    ) -> RusotoFuture<CopyWorkspaceImageResult, CopyWorkspaceImageError> {
+        (**self).copy_workspace_image(input)
+    }
+    fn create_ip_group(
+        &self,
+        input: CreateIpGroupRequest,
+    ) -> RusotoFuture<CreateIpGroupResult, CreateIpGroupError> {
+        (**self).create_ip_group(input)
+    }
+    fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> RusotoFuture<CreateTagsResult, CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn create_workspaces(
+        &self,
+        input: CreateWorkspacesRequest,
+    ) -> RusotoFuture<CreateWorkspacesResult, CreateWorkspacesError> {
+        (**self).create_workspaces(input)
+    }
+    fn delete_ip_group(
+        &self,
+        input: DeleteIpGroupRequest,
+    ) -> RusotoFuture<DeleteIpGroupResult, DeleteIpGroupError> {
+        (**self).delete_ip_group(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> RusotoFuture<DeleteTagsResult, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn delete_workspace_image(
+        &self,
+        input: DeleteWorkspaceImageRequest,
+    ) -> RusotoFuture<DeleteWorkspaceImageResult, DeleteWorkspaceImageError> {
+        (**self).delete_workspace_image(input)
+    }
+    fn describe_account(&self) -> RusotoFuture<DescribeAccountResult, DescribeAccountError> {
+        (**self).describe_account()
+    }
+    fn describe_account_modifications(
+        &self,
+        input: DescribeAccountModificationsRequest,
+    ) -> RusotoFuture<DescribeAccountModificationsResult, DescribeAccountModificationsError> {
+        (**self).describe_account_modifications(input)
+    }
+    fn describe_client_properties(
+        &self,
+        input: DescribeClientPropertiesRequest,
+    ) -> RusotoFuture<DescribeClientPropertiesResult, DescribeClientPropertiesError> {
+        (**self).describe_client_properties(input)
+    }
+    fn describe_ip_groups(
+        &self,
+        input: DescribeIpGroupsRequest,
+    ) -> RusotoFuture<DescribeIpGroupsResult, DescribeIpGroupsError> {
+        (**self).describe_ip_groups(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResult, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_workspace_bundles(
+        &self,
+        input: DescribeWorkspaceBundlesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceBundlesResult, DescribeWorkspaceBundlesError> {
+        (**self).describe_workspace_bundles(input)
+    }
+    fn describe_workspace_directories(
+        &self,
+        input: DescribeWorkspaceDirectoriesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceDirectoriesResult, DescribeWorkspaceDirectoriesError> {
+        (**self).describe_workspace_directories(input)
+    }
+    fn describe_workspace_images(
+        &self,
+        input: DescribeWorkspaceImagesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceImagesResult, DescribeWorkspaceImagesError> {
+        (**self).describe_workspace_images(input)
+    }
+    fn describe_workspaces(
+        &self,
+        input: DescribeWorkspacesRequest,
+    ) -> RusotoFuture<DescribeWorkspacesResult, DescribeWorkspacesError> {
+        (**self).describe_workspaces(input)
+    }
+    fn describe_workspaces_connection_status(
+        &self,
+        input: DescribeWorkspacesConnectionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeWorkspacesConnectionStatusResult,
+        DescribeWorkspacesConnectionStatusError,
+    > {
+        (**self).describe_workspaces_connection_status(input)
+    }
+    fn disassociate_ip_groups(
+        &self,
+        input: DisassociateIpGroupsRequest,
+    ) -> RusotoFuture<DisassociateIpGroupsResult, DisassociateIpGroupsError> {
+        (**self).disassociate_ip_groups(input)
+    }
+    fn import_workspace_image(
+        &self,
+        input: ImportWorkspaceImageRequest,
+    ) -> RusotoFuture<ImportWorkspaceImageResult, ImportWorkspaceImageError> {
+        (**self).import_workspace_image(input)
+    }
+    fn list_available_management_cidr_ranges(
+        &self,
+        input: ListAvailableManagementCidrRangesRequest,
+    ) -> RusotoFuture<ListAvailableManagementCidrRangesResult, ListAvailableManagementCidrRangesError>
+    {
+        (**self).list_available_management_cidr_ranges(input)
+    }
+    fn modify_account(
+        &self,
+        input: ModifyAccountRequest,
+    ) -> RusotoFuture<ModifyAccountResult, ModifyAccountError> {
+        (**self).modify_account(input)
+    }
+    fn modify_client_properties(
+        &self,
+        input: ModifyClientPropertiesRequest,
+    ) -> RusotoFuture<ModifyClientPropertiesResult, ModifyClientPropertiesError> {
+        (**self).modify_client_properties(input)
+    }
+    fn modify_workspace_properties(
+        &self,
+        input: ModifyWorkspacePropertiesRequest,
+    ) -> RusotoFuture<ModifyWorkspacePropertiesResult, ModifyWorkspacePropertiesError> {
+        (**self).modify_workspace_properties(input)
+    }
+    fn modify_workspace_state(
+        &self,
+        input: ModifyWorkspaceStateRequest,
+    ) -> RusotoFuture<ModifyWorkspaceStateResult, ModifyWorkspaceStateError> {
+        (**self).modify_workspace_state(input)
+    }
+    fn reboot_workspaces(
+        &self,
+        input: RebootWorkspacesRequest,
+    ) -> RusotoFuture<RebootWorkspacesResult, RebootWorkspacesError> {
+        (**self).reboot_workspaces(input)
+    }
+    fn rebuild_workspaces(
+        &self,
+        input: RebuildWorkspacesRequest,
+    ) -> RusotoFuture<RebuildWorkspacesResult, RebuildWorkspacesError> {
+        (**self).rebuild_workspaces(input)
+    }
+    fn revoke_ip_rules(
+        &self,
+        input: RevokeIpRulesRequest,
+    ) -> RusotoFuture<RevokeIpRulesResult, RevokeIpRulesError> {
+        (**self).revoke_ip_rules(input)
+    }
+    fn start_workspaces(
+        &self,
+        input: StartWorkspacesRequest,
+    ) -> RusotoFuture<StartWorkspacesResult, StartWorkspacesError> {
+        (**self).start_workspaces(input)
+    }
+    fn stop_workspaces(
+        &self,
+        input: StopWorkspacesRequest,
+    ) -> RusotoFuture<StopWorkspacesResult, StopWorkspacesError> {
+        (**self).stop_workspaces(input)
+    }
+    fn terminate_workspaces(
+        &self,
+        input: TerminateWorkspacesRequest,
+    ) -> RusotoFuture<TerminateWorkspacesResult, TerminateWorkspacesError> {
+        (**self).terminate_workspaces(input)
+    }
+    fn update_rules_of_ip_group(
+        &self,
+        input: UpdateRulesOfIpGroupRequest,
+    ) -> RusotoFuture<UpdateRulesOfIpGroupResult, UpdateRulesOfIpGroupError> {
+        (**self).update_rules_of_ip_group(input)
+    }
+}
+/// The error codes Amazon WorkSpaces retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon WorkSpaces API.
 #[derive(Clone)]
 pub struct WorkspacesClient {
@@ -2958,6 +5864,27 @@ impl WorkspacesClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_WORKSPACES`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> WorkspacesClient {
+        Self::new(region::Region::from_env_for_service("WORKSPACES"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `workspaces`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> WorkspacesClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("workspaces", "WORKSPACES"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2976,7 +5903,54 @@ impl WorkspacesClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> WorkspacesClient {
-        WorkspacesClient { client, region }
+        WorkspacesClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "workspaces")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "workspaces",
+            "workspaces",
+            "WORKSPACES",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Workspaces` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Workspaces + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for WorkspacesClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon WorkSpaces"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "workspaces"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> WorkspacesClient {
+        Self::new_with_client(client, region)
     }
 }
 
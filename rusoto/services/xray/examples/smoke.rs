@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS X-Ray clients.
+//!
+//! Makes a single read-only `GetEncryptionConfig` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_xray::{XRay, XRayClient};
+
+fn main() {
+    let client = XRayClient::from_env();
+    match client.get_encryption_config().sync() {
+        Ok(_) => println!("GetEncryptionConfig succeeded"),
+        Err(error) => {
+            eprintln!("GetEncryptionConfig failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
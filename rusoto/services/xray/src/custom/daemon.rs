@@ -0,0 +1,338 @@
+//! Sending segments to the X-Ray daemon.
+//!
+//! The X-Ray daemon receives segment documents over UDP, at `AWS_XRAY_DAEMON_ADDRESS` or
+//! `127.0.0.1:2000` by default, and uploads them in batches. `DaemonEmitter` sends it the
+//! segments of an application, and as a `SubsegmentEmitter` the subsegments of the calls made
+//! by Rusoto clients, so they show up in the service map. Sending is best effort: documents
+//! which can't be sent are dropped.
+//!
+//! ```rust,no_run
+//! use rusoto_core::xray::{set_subsegment_emitter, with_trace_context};
+//! use rusoto_xray::daemon::{DaemonEmitter, Segment};
+//!
+//! let emitter = DaemonEmitter::from_env().expect("failed to bind a UDP socket");
+//! set_subsegment_emitter(DaemonEmitter::from_env().expect("failed to bind a UDP socket"));
+//!
+//! let mut segment = Segment::begin("checkout", None);
+//! with_trace_context(segment.context(), || {
+//!     // the calls made here are subsegments of the segment
+//! });
+//! segment.end();
+//! emitter
+//!     .emit_segment(&segment)
+//!     .expect("failed to send the segment");
+//! ```
+
+use std::env;
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+
+use rusoto_core::xray::{self, Subsegment, SubsegmentEmitter, TraceContext};
+use serde_json::{json, Map, Value};
+
+/// The address the X-Ray daemon listens on by default.
+pub const DEFAULT_DAEMON_ADDRESS: &str = "127.0.0.1:2000";
+
+/// The line every datagram sent to the daemon starts with.
+const DATAGRAM_HEADER: &[u8] = b"{\"format\": \"json\", \"version\": 1}\n";
+
+/// Sends segment documents to the X-Ray daemon.
+#[derive(Debug)]
+pub struct DaemonEmitter {
+    socket: UdpSocket,
+    daemon: SocketAddr,
+}
+
+impl DaemonEmitter {
+    /// Creates an emitter sending to the daemon at `AWS_XRAY_DAEMON_ADDRESS`, or at
+    /// `DEFAULT_DAEMON_ADDRESS`.
+    pub fn from_env() -> io::Result<DaemonEmitter> {
+        let address = env::var("AWS_XRAY_DAEMON_ADDRESS")
+            .unwrap_or_else(|_| DEFAULT_DAEMON_ADDRESS.to_owned());
+        DaemonEmitter::new(udp_address(&address))
+    }
+
+    /// Creates an emitter sending to the daemon at `address`.
+    pub fn new<A>(address: A) -> io::Result<DaemonEmitter>
+    where
+        A: ToSocketAddrs,
+    {
+        let daemon = address
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no daemon address"))?;
+        let bind = if daemon.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(bind)?;
+        socket.set_nonblocking(true)?;
+        Ok(DaemonEmitter { socket, daemon })
+    }
+
+    /// Sends a segment document.
+    pub fn send(&self, document: &Value) -> io::Result<()> {
+        let mut datagram = DATAGRAM_HEADER.to_vec();
+        serde_json::to_writer(&mut datagram, document)?;
+        self.socket.send_to(&datagram, self.daemon).map(|_| ())
+    }
+
+    /// Sends the document of a segment.
+    pub fn emit_segment(&self, segment: &Segment) -> io::Result<()> {
+        self.send(&segment.document())
+    }
+}
+
+impl SubsegmentEmitter for DaemonEmitter {
+    fn emit(&self, subsegment: &Subsegment) {
+        let _ = self.send(&subsegment_document(subsegment));
+    }
+}
+
+/// Picks the UDP address out of an `AWS_XRAY_DAEMON_ADDRESS` value, which either is a single
+/// address or names the TCP and UDP addresses, as in `tcp:127.0.0.1:2000 udp:127.0.0.1:2001`.
+fn udp_address(value: &str) -> &str {
+    let value = value.trim();
+    value
+        .split_whitespace()
+        .find(|address| address.starts_with("udp:"))
+        .map_or(value, |address| &address["udp:".len()..])
+}
+
+/// Returns the document of the subsegment of a call, in the X-Ray segment document format.
+pub fn subsegment_document(subsegment: &Subsegment) -> Value {
+    let mut aws = Map::new();
+    if let Some(ref operation) = subsegment.operation {
+        aws.insert("operation".to_owned(), json!(operation));
+    }
+    aws.insert("region".to_owned(), json!(subsegment.region));
+    if let Some(ref request_id) = subsegment.request_id {
+        aws.insert("request_id".to_owned(), json!(request_id));
+    }
+    aws.insert("retries".to_owned(), json!(subsegment.retries));
+
+    let mut document = json!({
+        "name": subsegment.service,
+        "id": subsegment.id,
+        "trace_id": subsegment.trace_id,
+        "parent_id": subsegment.parent_id,
+        "type": "subsegment",
+        "namespace": "aws",
+        "start_time": subsegment.start_time,
+        "end_time": subsegment.end_time,
+        "aws": aws,
+    });
+    match subsegment.http_status {
+        Some(status) => {
+            document["http"] = json!({ "response": { "status": status } });
+            if status == 429 {
+                document["throttle"] = json!(true);
+            }
+            if status >= 500 {
+                document["fault"] = json!(true);
+            } else if status >= 400 {
+                document["error"] = json!(true);
+            }
+        }
+        None => {
+            document["fault"] = json!(true);
+            if let Some(ref error) = subsegment.error {
+                document["cause"] = json!({ "exceptions": [{ "message": error }] });
+            }
+        }
+    }
+    document
+}
+
+/// A segment of an application, such as the handling of a request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Segment {
+    /// The name of the application, shown in the service map
+    pub name: String,
+    /// The id of the segment
+    pub id: String,
+    /// The id of the trace
+    pub trace_id: String,
+    /// The id of the segment of the caller, if the trace was started upstream
+    pub parent_id: Option<String>,
+    /// Seconds since the epoch when the segment began
+    pub start_time: f64,
+    /// Seconds since the epoch when the segment ended, if it has
+    pub end_time: Option<f64>,
+    /// Whether the work failed because of its input
+    pub error: bool,
+    /// Whether the work failed by itself
+    pub fault: bool,
+}
+
+impl Segment {
+    /// Begins a segment continuing the trace of `upstream`, such as the context in the trace
+    /// header of a request being handled, or a new trace.
+    pub fn begin<S>(name: S, upstream: Option<&TraceContext>) -> Segment
+    where
+        S: Into<String>,
+    {
+        let (trace_id, parent_id) = match upstream {
+            Some(context) => (context.trace_id.clone(), context.parent_id.clone()),
+            None => (xray::generate_trace_id(), None),
+        };
+        Segment {
+            name: name.into(),
+            id: xray::generate_id(),
+            trace_id,
+            parent_id,
+            start_time: xray::now(),
+            end_time: None,
+            error: false,
+            fault: false,
+        }
+    }
+
+    /// The trace context of the work done in the segment, to pass to `with_trace_context`.
+    pub fn context(&self) -> TraceContext {
+        TraceContext {
+            trace_id: self.trace_id.clone(),
+            parent_id: Some(self.id.clone()),
+            sampled: Some(true),
+        }
+    }
+
+    /// Ends the segment now.
+    pub fn end(&mut self) {
+        self.end_time = Some(xray::now());
+    }
+
+    /// Returns the document of the segment, which is in progress until it ends.
+    pub fn document(&self) -> Value {
+        let mut document = json!({
+            "name": self.name,
+            "id": self.id,
+            "trace_id": self.trace_id,
+            "start_time": self.start_time,
+        });
+        if let Some(ref parent_id) = self.parent_id {
+            document["parent_id"] = json!(parent_id);
+        }
+        match self.end_time {
+            Some(end_time) => document["end_time"] = json!(end_time),
+            None => document["in_progress"] = json!(true),
+        }
+        if self.error {
+            document["error"] = json!(true);
+        }
+        if self.fault {
+            document["fault"] = json!(true);
+        }
+        document
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn subsegment() -> Subsegment {
+        Subsegment {
+            id: "70de5b6f19ff9a0a".to_owned(),
+            trace_id: "1-5759e988-bd862e3fe1be46a994272793".to_owned(),
+            parent_id: "53995c3f42cd8ad8".to_owned(),
+            service: "dynamodb".to_owned(),
+            operation: Some("ListTables".to_owned()),
+            region: "us-west-2".to_owned(),
+            start_time: 1_478_293_361.271,
+            end_time: 1_478_293_361.449,
+            http_status: Some(429),
+            request_id: Some("REQUESTID".to_owned()),
+            retries: 2,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn picks_the_udp_address() {
+        assert_eq!(udp_address("127.0.0.1:2000"), "127.0.0.1:2000");
+        assert_eq!(
+            udp_address("tcp:127.0.0.1:2000 udp:127.0.0.2:2001"),
+            "127.0.0.2:2001"
+        );
+    }
+
+    #[test]
+    fn describes_calls_as_aws_subsegments() {
+        assert_eq!(
+            subsegment_document(&subsegment()),
+            json!({
+                "name": "dynamodb",
+                "id": "70de5b6f19ff9a0a",
+                "trace_id": "1-5759e988-bd862e3fe1be46a994272793",
+                "parent_id": "53995c3f42cd8ad8",
+                "type": "subsegment",
+                "namespace": "aws",
+                "start_time": 1_478_293_361.271,
+                "end_time": 1_478_293_361.449,
+                "aws": {
+                    "operation": "ListTables",
+                    "region": "us-west-2",
+                    "request_id": "REQUESTID",
+                    "retries": 2
+                },
+                "http": { "response": { "status": 429 } },
+                "throttle": true,
+                "error": true
+            })
+        );
+
+        let failed = Subsegment {
+            http_status: None,
+            request_id: None,
+            error: Some("connection refused".to_owned()),
+            ..subsegment()
+        };
+        let document = subsegment_document(&failed);
+        assert_eq!(document["fault"], true);
+        assert_eq!(
+            document["cause"]["exceptions"][0]["message"],
+            "connection refused"
+        );
+    }
+
+    #[test]
+    fn continues_upstream_traces() {
+        let upstream = TraceContext::parse(
+            "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1",
+        )
+        .unwrap();
+        let mut segment = Segment::begin("checkout", Some(&upstream));
+        assert_eq!(segment.trace_id, upstream.trace_id);
+        assert_eq!(segment.document()["in_progress"], true);
+        assert_eq!(segment.context().parent_id, Some(segment.id.clone()));
+
+        segment.end();
+        let document = segment.document();
+        assert_eq!(document["parent_id"], "53995c3f42cd8ad8");
+        assert!(document.get("in_progress").is_none());
+        assert!(document["end_time"].as_f64().unwrap() >= segment.start_time);
+    }
+
+    #[test]
+    fn sends_documents_to_the_daemon() {
+        let daemon = UdpSocket::bind("127.0.0.1:0").unwrap();
+        daemon
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let emitter = DaemonEmitter::new(daemon.local_addr().unwrap()).unwrap();
+        emitter.emit(&subsegment());
+
+        let mut buffer = [0; 4096];
+        let len = daemon.recv(&mut buffer).unwrap();
+        let mut lines = buffer[..len].splitn(2, |&byte| byte == b'\n');
+        assert_eq!(
+            lines.next().unwrap(),
+            &DATAGRAM_HEADER[..DATAGRAM_HEADER.len() - 1]
+        );
+        let document: Value = serde_json::from_slice(lines.next().unwrap()).unwrap();
+        assert_eq!(document, subsegment_document(&subsegment()));
+    }
+}
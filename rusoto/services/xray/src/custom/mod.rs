@@ -1 +1,2 @@
-
+/// Sending segments, and the subsegments of Rusoto calls, to the X-Ray daemon
+pub mod daemon;
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>An alias for an edge.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -109,6 +110,44 @@ pub struct BatchGetTracesRequest {
     pub trace_ids: Vec<String>,
 }
 
+impl BatchGetTracesRequest {
+    /// Returns a builder for `BatchGetTracesRequest`, checking its required members when built.
+    pub fn builder() -> BatchGetTracesRequestBuilder {
+        BatchGetTracesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetTracesRequest`](struct.BatchGetTracesRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchGetTracesRequestBuilder {
+    next_token: Option<String>,
+    trace_ids: Option<Vec<String>>,
+}
+
+impl BatchGetTracesRequestBuilder {
+    /// Sets the [`next_token`](struct.BatchGetTracesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trace_ids`](struct.BatchGetTracesRequest.html#structfield.trace_ids) member.
+    pub fn trace_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.trace_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetTracesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchGetTracesRequest, ::rusoto_core::BuildError> {
+        Ok(BatchGetTracesRequest {
+            next_token: self.next_token,
+            trace_ids: self
+                .trace_ids
+                .ok_or(::rusoto_core::BuildError::missing("trace_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetTracesResult {
@@ -137,6 +176,44 @@ pub struct CreateGroupRequest {
     pub group_name: String,
 }
 
+impl CreateGroupRequest {
+    /// Returns a builder for `CreateGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateGroupRequestBuilder {
+        CreateGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGroupRequest`](struct.CreateGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGroupRequestBuilder {
+    filter_expression: Option<String>,
+    group_name: Option<String>,
+}
+
+impl CreateGroupRequestBuilder {
+    /// Sets the [`filter_expression`](struct.CreateGroupRequest.html#structfield.filter_expression) member.
+    pub fn filter_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.CreateGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateGroupRequest {
+            filter_expression: self.filter_expression,
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGroupResult {
@@ -153,6 +230,38 @@ pub struct CreateSamplingRuleRequest {
     pub sampling_rule: SamplingRule,
 }
 
+impl CreateSamplingRuleRequest {
+    /// Returns a builder for `CreateSamplingRuleRequest`, checking its required members when built.
+    pub fn builder() -> CreateSamplingRuleRequestBuilder {
+        CreateSamplingRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSamplingRuleRequest`](struct.CreateSamplingRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSamplingRuleRequestBuilder {
+    sampling_rule: Option<SamplingRule>,
+}
+
+impl CreateSamplingRuleRequestBuilder {
+    /// Sets the [`sampling_rule`](struct.CreateSamplingRuleRequest.html#structfield.sampling_rule) member.
+    pub fn sampling_rule<T: Into<SamplingRule>>(mut self, value: T) -> Self {
+        self.sampling_rule = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSamplingRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSamplingRuleRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSamplingRuleRequest {
+            sampling_rule: self
+                .sampling_rule
+                .ok_or(::rusoto_core::BuildError::missing("sampling_rule"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSamplingRuleResult {
@@ -174,6 +283,42 @@ pub struct DeleteGroupRequest {
     pub group_name: Option<String>,
 }
 
+impl DeleteGroupRequest {
+    /// Returns a builder for `DeleteGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteGroupRequestBuilder {
+        DeleteGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGroupRequest`](struct.DeleteGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteGroupRequestBuilder {
+    group_arn: Option<String>,
+    group_name: Option<String>,
+}
+
+impl DeleteGroupRequestBuilder {
+    /// Sets the [`group_arn`](struct.DeleteGroupRequest.html#structfield.group_arn) member.
+    pub fn group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.DeleteGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteGroupRequest {
+            group_arn: self.group_arn,
+            group_name: self.group_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteGroupResult {}
@@ -190,6 +335,44 @@ pub struct DeleteSamplingRuleRequest {
     pub rule_name: Option<String>,
 }
 
+impl DeleteSamplingRuleRequest {
+    /// Returns a builder for `DeleteSamplingRuleRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSamplingRuleRequestBuilder {
+        DeleteSamplingRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSamplingRuleRequest`](struct.DeleteSamplingRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSamplingRuleRequestBuilder {
+    rule_arn: Option<String>,
+    rule_name: Option<String>,
+}
+
+impl DeleteSamplingRuleRequestBuilder {
+    /// Sets the [`rule_arn`](struct.DeleteSamplingRuleRequest.html#structfield.rule_arn) member.
+    pub fn rule_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_name`](struct.DeleteSamplingRuleRequest.html#structfield.rule_name) member.
+    pub fn rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSamplingRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSamplingRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSamplingRuleRequest {
+            rule_arn: self.rule_arn,
+            rule_name: self.rule_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSamplingRuleResult {
@@ -209,8 +392,9 @@ pub struct Edge {
     pub aliases: Option<Vec<Alias>>,
     /// <p>The end time of the last segment on the edge.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>Identifier of the edge. Unique within a service map.</p>
     #[serde(rename = "ReferenceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -221,8 +405,9 @@ pub struct Edge {
     pub response_time_histogram: Option<Vec<HistogramEntry>>,
     /// <p>The start time of the first segment on the edge.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>Response statistics for segments on the edge.</p>
     #[serde(rename = "SummaryStatistics")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -266,11 +451,167 @@ pub struct EncryptionConfig {
     /// <p>The encryption status. While the status is <code>UPDATING</code>, X-Ray may encrypt data with a combination of the new and old settings.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EncryptionStatus>,
     /// <p>The type of encryption. Set to <code>KMS</code> for encryption with CMKs. Set to <code>NONE</code> for default encryption.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EncryptionType>,
+}
+
+/// <p>The encryption status. While the status is <code>UPDATING</code>, X-Ray may encrypt data with a combination of the new and old settings.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncryptionStatus {
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncryptionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionStatus::Updating => "UPDATING",
+            EncryptionStatus::Active => "ACTIVE",
+            EncryptionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionStatus {
+    fn from(value: &'a str) -> EncryptionStatus {
+        match value {
+            "UPDATING" => EncryptionStatus::Updating,
+            "ACTIVE" => EncryptionStatus::Active,
+            _ => EncryptionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionStatus {
+    fn from(value: String) -> EncryptionStatus {
+        EncryptionStatus::from(value.as_str())
+    }
+}
+
+impl From<EncryptionStatus> for String {
+    fn from(value: EncryptionStatus) -> String {
+        match value {
+            EncryptionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncryptionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EncryptionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EncryptionStatus::from(value))
+    }
+}
+
+/// <p>The type of encryption. Set to <code>KMS</code> for encryption with CMKs. Set to <code>NONE</code> for default encryption.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncryptionType {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>KMS</code></p>
+    Kms,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncryptionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionType::None => "NONE",
+            EncryptionType::Kms => "KMS",
+            EncryptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionType {
+    fn from(value: &'a str) -> EncryptionType {
+        match value {
+            "NONE" => EncryptionType::None,
+            "KMS" => EncryptionType::Kms,
+            _ => EncryptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionType {
+    fn from(value: String) -> EncryptionType {
+        EncryptionType::from(value.as_str())
+    }
+}
+
+impl From<EncryptionType> for String {
+    fn from(value: EncryptionType) -> String {
+        match value {
+            EncryptionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncryptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EncryptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EncryptionType::from(value))
+    }
 }
 
 /// <p>The root cause of a trace summary error.</p>
@@ -445,6 +786,42 @@ pub struct GetGroupRequest {
     pub group_name: Option<String>,
 }
 
+impl GetGroupRequest {
+    /// Returns a builder for `GetGroupRequest`, checking its required members when built.
+    pub fn builder() -> GetGroupRequestBuilder {
+        GetGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGroupRequest`](struct.GetGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGroupRequestBuilder {
+    group_arn: Option<String>,
+    group_name: Option<String>,
+}
+
+impl GetGroupRequestBuilder {
+    /// Sets the [`group_arn`](struct.GetGroupRequest.html#structfield.group_arn) member.
+    pub fn group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.GetGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGroupRequest, ::rusoto_core::BuildError> {
+        Ok(GetGroupRequest {
+            group_arn: self.group_arn,
+            group_name: self.group_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGroupResult {
@@ -462,6 +839,34 @@ pub struct GetGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetGroupsRequest {
+    /// Returns a builder for `GetGroupsRequest`, checking its required members when built.
+    pub fn builder() -> GetGroupsRequestBuilder {
+        GetGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGroupsRequest`](struct.GetGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGroupsRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl GetGroupsRequestBuilder {
+    /// Sets the [`next_token`](struct.GetGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(GetGroupsRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGroupsResult {
@@ -483,6 +888,36 @@ pub struct GetSamplingRulesRequest {
     pub next_token: Option<String>,
 }
 
+impl GetSamplingRulesRequest {
+    /// Returns a builder for `GetSamplingRulesRequest`, checking its required members when built.
+    pub fn builder() -> GetSamplingRulesRequestBuilder {
+        GetSamplingRulesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSamplingRulesRequest`](struct.GetSamplingRulesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSamplingRulesRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl GetSamplingRulesRequestBuilder {
+    /// Sets the [`next_token`](struct.GetSamplingRulesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSamplingRulesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSamplingRulesRequest, ::rusoto_core::BuildError> {
+        Ok(GetSamplingRulesRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSamplingRulesResult {
@@ -504,6 +939,37 @@ pub struct GetSamplingStatisticSummariesRequest {
     pub next_token: Option<String>,
 }
 
+impl GetSamplingStatisticSummariesRequest {
+    /// Returns a builder for `GetSamplingStatisticSummariesRequest`, checking its required members when built.
+    pub fn builder() -> GetSamplingStatisticSummariesRequestBuilder {
+        GetSamplingStatisticSummariesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSamplingStatisticSummariesRequest`](struct.GetSamplingStatisticSummariesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSamplingStatisticSummariesRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl GetSamplingStatisticSummariesRequestBuilder {
+    /// Sets the [`next_token`](struct.GetSamplingStatisticSummariesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSamplingStatisticSummariesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSamplingStatisticSummariesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetSamplingStatisticSummariesRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSamplingStatisticSummariesResult {
@@ -524,13 +990,49 @@ pub struct GetSamplingTargetsRequest {
     pub sampling_statistics_documents: Vec<SamplingStatisticsDocument>,
 }
 
+impl GetSamplingTargetsRequest {
+    /// Returns a builder for `GetSamplingTargetsRequest`, checking its required members when built.
+    pub fn builder() -> GetSamplingTargetsRequestBuilder {
+        GetSamplingTargetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSamplingTargetsRequest`](struct.GetSamplingTargetsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSamplingTargetsRequestBuilder {
+    sampling_statistics_documents: Option<Vec<SamplingStatisticsDocument>>,
+}
+
+impl GetSamplingTargetsRequestBuilder {
+    /// Sets the [`sampling_statistics_documents`](struct.GetSamplingTargetsRequest.html#structfield.sampling_statistics_documents) member.
+    pub fn sampling_statistics_documents<T: Into<Vec<SamplingStatisticsDocument>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.sampling_statistics_documents = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSamplingTargetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSamplingTargetsRequest, ::rusoto_core::BuildError> {
+        Ok(GetSamplingTargetsRequest {
+            sampling_statistics_documents: self.sampling_statistics_documents.ok_or(
+                ::rusoto_core::BuildError::missing("sampling_statistics_documents"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSamplingTargetsResult {
     /// <p>The last time a user changed the sampling rule configuration. If the sampling rule configuration changed since the service last retrieved it, the service should call <a>GetSamplingRules</a> to get the latest version.</p>
     #[serde(rename = "LastRuleModification")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_rule_modification: Option<f64>,
+    pub last_rule_modification: Option<EpochTimestamp>,
     /// <p>Updated rules that the service should use to sample requests.</p>
     #[serde(rename = "SamplingTargetDocuments")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -545,7 +1047,8 @@ pub struct GetSamplingTargetsResult {
 pub struct GetServiceGraphRequest {
     /// <p>The end of the timeframe for which to generate a graph.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p>The ARN of a group to generate a graph based on.</p>
     #[serde(rename = "GroupARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -560,7 +1063,72 @@ pub struct GetServiceGraphRequest {
     pub next_token: Option<String>,
     /// <p>The start of the time frame for which to generate a graph.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
+}
+
+impl GetServiceGraphRequest {
+    /// Returns a builder for `GetServiceGraphRequest`, checking its required members when built.
+    pub fn builder() -> GetServiceGraphRequestBuilder {
+        GetServiceGraphRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetServiceGraphRequest`](struct.GetServiceGraphRequest.html).
+#[derive(Default, Debug)]
+pub struct GetServiceGraphRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    group_arn: Option<String>,
+    group_name: Option<String>,
+    next_token: Option<String>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl GetServiceGraphRequestBuilder {
+    /// Sets the [`end_time`](struct.GetServiceGraphRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_arn`](struct.GetServiceGraphRequest.html#structfield.group_arn) member.
+    pub fn group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.GetServiceGraphRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetServiceGraphRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetServiceGraphRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetServiceGraphRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetServiceGraphRequest, ::rusoto_core::BuildError> {
+        Ok(GetServiceGraphRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            group_arn: self.group_arn,
+            group_name: self.group_name,
+            next_token: self.next_token,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -572,8 +1140,9 @@ pub struct GetServiceGraphResult {
     pub contains_old_group_versions: Option<bool>,
     /// <p>The end of the time frame for which the graph was generated.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>Pagination token. Not used.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -584,15 +1153,17 @@ pub struct GetServiceGraphResult {
     pub services: Option<Vec<Service>>,
     /// <p>The start of the time frame for which the graph was generated.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetTimeSeriesServiceStatisticsRequest {
     /// <p>The end of the time frame for which to aggregate statistics.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p>A filter expression defining entities that will be aggregated for statistics. Supports ID, service, and edge functions. If no selector expression is specified, edge statistics are returned. </p>
     #[serde(rename = "EntitySelectorExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -615,7 +1186,91 @@ pub struct GetTimeSeriesServiceStatisticsRequest {
     pub period: Option<i64>,
     /// <p>The start of the time frame for which to aggregate statistics.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
+}
+
+impl GetTimeSeriesServiceStatisticsRequest {
+    /// Returns a builder for `GetTimeSeriesServiceStatisticsRequest`, checking its required members when built.
+    pub fn builder() -> GetTimeSeriesServiceStatisticsRequestBuilder {
+        GetTimeSeriesServiceStatisticsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTimeSeriesServiceStatisticsRequest`](struct.GetTimeSeriesServiceStatisticsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTimeSeriesServiceStatisticsRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    entity_selector_expression: Option<String>,
+    group_arn: Option<String>,
+    group_name: Option<String>,
+    next_token: Option<String>,
+    period: Option<i64>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl GetTimeSeriesServiceStatisticsRequestBuilder {
+    /// Sets the [`end_time`](struct.GetTimeSeriesServiceStatisticsRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`entity_selector_expression`](struct.GetTimeSeriesServiceStatisticsRequest.html#structfield.entity_selector_expression) member.
+    pub fn entity_selector_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_selector_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_arn`](struct.GetTimeSeriesServiceStatisticsRequest.html#structfield.group_arn) member.
+    pub fn group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.GetTimeSeriesServiceStatisticsRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetTimeSeriesServiceStatisticsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`period`](struct.GetTimeSeriesServiceStatisticsRequest.html#structfield.period) member.
+    pub fn period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetTimeSeriesServiceStatisticsRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTimeSeriesServiceStatisticsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetTimeSeriesServiceStatisticsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetTimeSeriesServiceStatisticsRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            entity_selector_expression: self.entity_selector_expression,
+            group_arn: self.group_arn,
+            group_name: self.group_name,
+            next_token: self.next_token,
+            period: self.period,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -646,6 +1301,44 @@ pub struct GetTraceGraphRequest {
     pub trace_ids: Vec<String>,
 }
 
+impl GetTraceGraphRequest {
+    /// Returns a builder for `GetTraceGraphRequest`, checking its required members when built.
+    pub fn builder() -> GetTraceGraphRequestBuilder {
+        GetTraceGraphRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTraceGraphRequest`](struct.GetTraceGraphRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTraceGraphRequestBuilder {
+    next_token: Option<String>,
+    trace_ids: Option<Vec<String>>,
+}
+
+impl GetTraceGraphRequestBuilder {
+    /// Sets the [`next_token`](struct.GetTraceGraphRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trace_ids`](struct.GetTraceGraphRequest.html#structfield.trace_ids) member.
+    pub fn trace_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.trace_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTraceGraphRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTraceGraphRequest, ::rusoto_core::BuildError> {
+        Ok(GetTraceGraphRequest {
+            next_token: self.next_token,
+            trace_ids: self
+                .trace_ids
+                .ok_or(::rusoto_core::BuildError::missing("trace_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTraceGraphResult {
@@ -663,7 +1356,8 @@ pub struct GetTraceGraphResult {
 pub struct GetTraceSummariesRequest {
     /// <p>The end of the time frame for which to retrieve traces.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p>Specify a filter expression to retrieve trace summaries for services or requests that meet certain requirements.</p>
     #[serde(rename = "FilterExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -682,11 +1376,94 @@ pub struct GetTraceSummariesRequest {
     pub sampling_strategy: Option<SamplingStrategy>,
     /// <p>The start of the time frame for which to retrieve traces.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
     /// <p>A parameter to indicate whether to query trace summaries by TraceId or Event time.</p>
     #[serde(rename = "TimeRangeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_range_type: Option<String>,
+    pub time_range_type: Option<TimeRangeType>,
+}
+
+impl GetTraceSummariesRequest {
+    /// Returns a builder for `GetTraceSummariesRequest`, checking its required members when built.
+    pub fn builder() -> GetTraceSummariesRequestBuilder {
+        GetTraceSummariesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTraceSummariesRequest`](struct.GetTraceSummariesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTraceSummariesRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    filter_expression: Option<String>,
+    next_token: Option<String>,
+    sampling: Option<bool>,
+    sampling_strategy: Option<SamplingStrategy>,
+    start_time: Option<EpochTimestamp>,
+    time_range_type: Option<TimeRangeType>,
+}
+
+impl GetTraceSummariesRequestBuilder {
+    /// Sets the [`end_time`](struct.GetTraceSummariesRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_expression`](struct.GetTraceSummariesRequest.html#structfield.filter_expression) member.
+    pub fn filter_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetTraceSummariesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sampling`](struct.GetTraceSummariesRequest.html#structfield.sampling) member.
+    pub fn sampling<T: Into<bool>>(mut self, value: T) -> Self {
+        self.sampling = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sampling_strategy`](struct.GetTraceSummariesRequest.html#structfield.sampling_strategy) member.
+    pub fn sampling_strategy<T: Into<SamplingStrategy>>(mut self, value: T) -> Self {
+        self.sampling_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetTraceSummariesRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_range_type`](struct.GetTraceSummariesRequest.html#structfield.time_range_type) member.
+    pub fn time_range_type<T: Into<TimeRangeType>>(mut self, value: T) -> Self {
+        self.time_range_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTraceSummariesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetTraceSummariesRequest, ::rusoto_core::BuildError> {
+        Ok(GetTraceSummariesRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            filter_expression: self.filter_expression,
+            next_token: self.next_token,
+            sampling: self.sampling,
+            sampling_strategy: self.sampling_strategy,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+            time_range_type: self.time_range_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -694,8 +1471,9 @@ pub struct GetTraceSummariesRequest {
 pub struct GetTraceSummariesResult {
     /// <p>The start time of this page of results.</p>
     #[serde(rename = "ApproximateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approximate_time: Option<f64>,
+    pub approximate_time: Option<EpochTimestamp>,
     /// <p>If the requested time frame contained more than one page of results, you can use this token to retrieve the next page. The first page contains the most most recent results, closest to the end of the time frame.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -796,7 +1574,7 @@ pub struct InstanceIdDetail {
     pub id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutEncryptionConfigRequest {
     /// <p>An AWS KMS customer master key (CMK) in one of the following formats:</p> <ul> <li> <p> <b>Alias</b> - The name of the key. For example, <code>alias/MyKey</code>.</p> </li> <li> <p> <b>Key ID</b> - The KMS key ID of the key. For example, <code>ae4aa6d49-a4d8-9df9-a475-4ff6d7898456</code>.</p> </li> <li> <p> <b>ARN</b> - The full Amazon Resource Name of the key ID or alias. For example, <code>arn:aws:kms:us-east-2:123456789012:key/ae4aa6d49-a4d8-9df9-a475-4ff6d7898456</code>. Use this format to specify a key in a different account.</p> </li> </ul> <p>Omit this key if you set <code>Type</code> to <code>NONE</code>.</p>
     #[serde(rename = "KeyId")]
@@ -804,7 +1582,47 @@ pub struct PutEncryptionConfigRequest {
     pub key_id: Option<String>,
     /// <p>The type of encryption. Set to <code>KMS</code> to use your own key for encryption. Set to <code>NONE</code> for default encryption.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: EncryptionType,
+}
+
+impl PutEncryptionConfigRequest {
+    /// Returns a builder for `PutEncryptionConfigRequest`, checking its required members when built.
+    pub fn builder() -> PutEncryptionConfigRequestBuilder {
+        PutEncryptionConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutEncryptionConfigRequest`](struct.PutEncryptionConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct PutEncryptionConfigRequestBuilder {
+    key_id: Option<String>,
+    type_: Option<EncryptionType>,
+}
+
+impl PutEncryptionConfigRequestBuilder {
+    /// Sets the [`key_id`](struct.PutEncryptionConfigRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.PutEncryptionConfigRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<EncryptionType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutEncryptionConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutEncryptionConfigRequest, ::rusoto_core::BuildError> {
+        Ok(PutEncryptionConfigRequest {
+            key_id: self.key_id,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -835,6 +1653,62 @@ pub struct PutTelemetryRecordsRequest {
     pub telemetry_records: Vec<TelemetryRecord>,
 }
 
+impl PutTelemetryRecordsRequest {
+    /// Returns a builder for `PutTelemetryRecordsRequest`, checking its required members when built.
+    pub fn builder() -> PutTelemetryRecordsRequestBuilder {
+        PutTelemetryRecordsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutTelemetryRecordsRequest`](struct.PutTelemetryRecordsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutTelemetryRecordsRequestBuilder {
+    ec2_instance_id: Option<String>,
+    hostname: Option<String>,
+    resource_arn: Option<String>,
+    telemetry_records: Option<Vec<TelemetryRecord>>,
+}
+
+impl PutTelemetryRecordsRequestBuilder {
+    /// Sets the [`ec2_instance_id`](struct.PutTelemetryRecordsRequest.html#structfield.ec2_instance_id) member.
+    pub fn ec2_instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hostname`](struct.PutTelemetryRecordsRequest.html#structfield.hostname) member.
+    pub fn hostname<T: Into<String>>(mut self, value: T) -> Self {
+        self.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.PutTelemetryRecordsRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`telemetry_records`](struct.PutTelemetryRecordsRequest.html#structfield.telemetry_records) member.
+    pub fn telemetry_records<T: Into<Vec<TelemetryRecord>>>(mut self, value: T) -> Self {
+        self.telemetry_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutTelemetryRecordsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutTelemetryRecordsRequest, ::rusoto_core::BuildError> {
+        Ok(PutTelemetryRecordsRequest {
+            ec2_instance_id: self.ec2_instance_id,
+            hostname: self.hostname,
+            resource_arn: self.resource_arn,
+            telemetry_records: self
+                .telemetry_records
+                .ok_or(::rusoto_core::BuildError::missing("telemetry_records"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutTelemetryRecordsResult {}
@@ -846,6 +1720,38 @@ pub struct PutTraceSegmentsRequest {
     pub trace_segment_documents: Vec<String>,
 }
 
+impl PutTraceSegmentsRequest {
+    /// Returns a builder for `PutTraceSegmentsRequest`, checking its required members when built.
+    pub fn builder() -> PutTraceSegmentsRequestBuilder {
+        PutTraceSegmentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutTraceSegmentsRequest`](struct.PutTraceSegmentsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutTraceSegmentsRequestBuilder {
+    trace_segment_documents: Option<Vec<String>>,
+}
+
+impl PutTraceSegmentsRequestBuilder {
+    /// Sets the [`trace_segment_documents`](struct.PutTraceSegmentsRequest.html#structfield.trace_segment_documents) member.
+    pub fn trace_segment_documents<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.trace_segment_documents = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutTraceSegmentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutTraceSegmentsRequest, ::rusoto_core::BuildError> {
+        Ok(PutTraceSegmentsRequest {
+            trace_segment_documents: self.trace_segment_documents.ok_or(
+                ::rusoto_core::BuildError::missing("trace_segment_documents"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutTraceSegmentsResult {
@@ -990,12 +1896,14 @@ pub struct SamplingRule {
 pub struct SamplingRuleRecord {
     /// <p>When the rule was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>When the rule was last modified.</p>
     #[serde(rename = "ModifiedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified_at: Option<f64>,
+    pub modified_at: Option<EpochTimestamp>,
     /// <p>The sampling rule.</p>
     #[serde(rename = "SamplingRule")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1077,8 +1985,9 @@ pub struct SamplingStatisticSummary {
     pub sampled_count: Option<i64>,
     /// <p>The start time of the reporting window.</p>
     #[serde(rename = "Timestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<EpochTimestamp>,
 }
 
 /// <p>Request sampling results for a single rule from a service. Results are for the last 10 seconds unless the service has been assigned a longer reporting interval after a previous call to <a>GetSamplingTargets</a>.</p>
@@ -1102,7 +2011,8 @@ pub struct SamplingStatisticsDocument {
     pub sampled_count: i64,
     /// <p>The current time.</p>
     #[serde(rename = "Timestamp")]
-    pub timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub timestamp: EpochTimestamp,
 }
 
 /// <p>The name and value of a sampling rule to apply to a trace summary.</p>
@@ -1111,13 +2021,91 @@ pub struct SamplingStrategy {
     /// <p>The name of a sampling rule.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SamplingStrategyName>,
     /// <p>The value of a sampling rule.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<f64>,
 }
 
+/// <p>The name of a sampling rule.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SamplingStrategyName {
+    /// <p><code>PartialScan</code></p>
+    PartialScan,
+    /// <p><code>FixedRate</code></p>
+    FixedRate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SamplingStrategyName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SamplingStrategyName::PartialScan => "PartialScan",
+            SamplingStrategyName::FixedRate => "FixedRate",
+            SamplingStrategyName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SamplingStrategyName {
+    fn from(value: &'a str) -> SamplingStrategyName {
+        match value {
+            "PartialScan" => SamplingStrategyName::PartialScan,
+            "FixedRate" => SamplingStrategyName::FixedRate,
+            _ => SamplingStrategyName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SamplingStrategyName {
+    fn from(value: String) -> SamplingStrategyName {
+        SamplingStrategyName::from(value.as_str())
+    }
+}
+
+impl From<SamplingStrategyName> for String {
+    fn from(value: SamplingStrategyName) -> String {
+        match value {
+            SamplingStrategyName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SamplingStrategyName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SamplingStrategyName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SamplingStrategyName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SamplingStrategyName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SamplingStrategyName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SamplingStrategyName::from(value))
+    }
+}
+
 /// <p>Temporary changes to a sampling rule configuration. To meet the global sampling target for a rule, X-Ray calculates a new reservoir for each service based on the recent sampling results of all services that called <a>GetSamplingTargets</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1136,8 +2124,9 @@ pub struct SamplingTargetDocument {
     pub reservoir_quota: Option<i64>,
     /// <p>When the reservoir quota expires.</p>
     #[serde(rename = "ReservoirQuotaTTL")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reservoir_quota_ttl: Option<f64>,
+    pub reservoir_quota_ttl: Option<EpochTimestamp>,
     /// <p>The name of the sampling rule.</p>
     #[serde(rename = "RuleName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1176,8 +2165,9 @@ pub struct Service {
     pub edges: Option<Vec<Edge>>,
     /// <p>The end time of the last segment that the service generated.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The canonical name of the service.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1200,8 +2190,9 @@ pub struct Service {
     pub root: Option<bool>,
     /// <p>The start time of the first segment that the service generated.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>The service's state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1289,7 +2280,90 @@ pub struct TelemetryRecord {
     pub segments_spillover_count: Option<i64>,
     /// <p><p/></p>
     #[serde(rename = "Timestamp")]
-    pub timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub timestamp: EpochTimestamp,
+}
+
+/// <p>A parameter to indicate whether to query trace summaries by TraceId or Event time.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TimeRangeType {
+    /// <p><code>TraceId</code></p>
+    TraceId,
+    /// <p><code>Event</code></p>
+    Event,
+    /// <p><code>Service</code></p>
+    Service,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TimeRangeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TimeRangeType::TraceId => "TraceId",
+            TimeRangeType::Event => "Event",
+            TimeRangeType::Service => "Service",
+            TimeRangeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TimeRangeType {
+    fn from(value: &'a str) -> TimeRangeType {
+        match value {
+            "TraceId" => TimeRangeType::TraceId,
+            "Event" => TimeRangeType::Event,
+            "Service" => TimeRangeType::Service,
+            _ => TimeRangeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TimeRangeType {
+    fn from(value: String) -> TimeRangeType {
+        TimeRangeType::from(value.as_str())
+    }
+}
+
+impl From<TimeRangeType> for String {
+    fn from(value: TimeRangeType) -> String {
+        match value {
+            TimeRangeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TimeRangeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TimeRangeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TimeRangeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TimeRangeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TimeRangeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TimeRangeType::from(value))
+    }
 }
 
 /// <p>A list of TimeSeriesStatistic structures.</p>
@@ -1308,8 +2382,9 @@ pub struct TimeSeriesServiceStatistics {
     pub service_summary_statistics: Option<ServiceStatistics>,
     /// <p>Timestamp of the window for which statistics are aggregated.</p>
     #[serde(rename = "Timestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<EpochTimestamp>,
 }
 
 /// <p>A collection of segment documents with matching trace IDs.</p>
@@ -1388,8 +2463,9 @@ pub struct TraceSummary {
     pub is_partial: Option<bool>,
     /// <p>The matched time stamp of a defined event.</p>
     #[serde(rename = "MatchedEventTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub matched_event_time: Option<f64>,
+    pub matched_event_time: Option<EpochTimestamp>,
     /// <p>A list of resource ARNs for any resource corresponding to the trace segments.</p>
     #[serde(rename = "ResourceARNs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1482,6 +2558,50 @@ pub struct UpdateGroupRequest {
     pub group_name: Option<String>,
 }
 
+impl UpdateGroupRequest {
+    /// Returns a builder for `UpdateGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateGroupRequestBuilder {
+        UpdateGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGroupRequest`](struct.UpdateGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateGroupRequestBuilder {
+    filter_expression: Option<String>,
+    group_arn: Option<String>,
+    group_name: Option<String>,
+}
+
+impl UpdateGroupRequestBuilder {
+    /// Sets the [`filter_expression`](struct.UpdateGroupRequest.html#structfield.filter_expression) member.
+    pub fn filter_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_arn`](struct.UpdateGroupRequest.html#structfield.group_arn) member.
+    pub fn group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.UpdateGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateGroupRequest {
+            filter_expression: self.filter_expression,
+            group_arn: self.group_arn,
+            group_name: self.group_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGroupResult {
@@ -1498,6 +2618,38 @@ pub struct UpdateSamplingRuleRequest {
     pub sampling_rule_update: SamplingRuleUpdate,
 }
 
+impl UpdateSamplingRuleRequest {
+    /// Returns a builder for `UpdateSamplingRuleRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSamplingRuleRequestBuilder {
+        UpdateSamplingRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSamplingRuleRequest`](struct.UpdateSamplingRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSamplingRuleRequestBuilder {
+    sampling_rule_update: Option<SamplingRuleUpdate>,
+}
+
+impl UpdateSamplingRuleRequestBuilder {
+    /// Sets the [`sampling_rule_update`](struct.UpdateSamplingRuleRequest.html#structfield.sampling_rule_update) member.
+    pub fn sampling_rule_update<T: Into<SamplingRuleUpdate>>(mut self, value: T) -> Self {
+        self.sampling_rule_update = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSamplingRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSamplingRuleRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSamplingRuleRequest {
+            sampling_rule_update: self
+                .sampling_rule_update
+                .ok_or(::rusoto_core::BuildError::missing("sampling_rule_update"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateSamplingRuleResult {
@@ -2436,6 +3588,129 @@ pub trait XRay {
         input: UpdateSamplingRuleRequest,
     ) -> RusotoFuture<UpdateSamplingRuleResult, UpdateSamplingRuleError>;
 }
+impl<T: ?Sized + XRay> XRay for ::std::sync::Arc<T> {
+    fn batch_get_traces(
+        &self,
+        input: BatchGetTracesRequest,
+    ) -> RusotoFuture<BatchGetTracesResult, BatchGetTracesError> {
+        (**self).batch_get_traces(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResult, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_sampling_rule(
+        &self,
+        input: CreateSamplingRuleRequest,
+    ) -> RusotoFuture<CreateSamplingRuleResult, CreateSamplingRuleError> {
+        (**self).create_sampling_rule(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResult, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_sampling_rule(
+        &self,
+        input: DeleteSamplingRuleRequest,
+    ) -> RusotoFuture<DeleteSamplingRuleResult, DeleteSamplingRuleError> {
+        (**self).delete_sampling_rule(input)
+    }
+    fn get_encryption_config(
+        &self,
+    ) -> RusotoFuture<GetEncryptionConfigResult, GetEncryptionConfigError> {
+        (**self).get_encryption_config()
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResult, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_groups(&self, input: GetGroupsRequest) -> RusotoFuture<GetGroupsResult, GetGroupsError> {
+        (**self).get_groups(input)
+    }
+    fn get_sampling_rules(
+        &self,
+        input: GetSamplingRulesRequest,
+    ) -> RusotoFuture<GetSamplingRulesResult, GetSamplingRulesError> {
+        (**self).get_sampling_rules(input)
+    }
+    fn get_sampling_statistic_summaries(
+        &self,
+        input: GetSamplingStatisticSummariesRequest,
+    ) -> RusotoFuture<GetSamplingStatisticSummariesResult, GetSamplingStatisticSummariesError> {
+        (**self).get_sampling_statistic_summaries(input)
+    }
+    fn get_sampling_targets(
+        &self,
+        input: GetSamplingTargetsRequest,
+    ) -> RusotoFuture<GetSamplingTargetsResult, GetSamplingTargetsError> {
+        (**self).get_sampling_targets(input)
+    }
+    fn get_service_graph(
+        &self,
+        input: GetServiceGraphRequest,
+    ) -> RusotoFuture<GetServiceGraphResult, GetServiceGraphError> {
+        (**self).get_service_graph(input)
+    }
+    fn get_time_series_service_statistics(
+        &self,
+        input: GetTimeSeriesServiceStatisticsRequest,
+    ) -> RusotoFuture<GetTimeSeriesServiceStatisticsResult, GetTimeSeriesServiceStatisticsError>
+    {
+        (**self).get_time_series_service_statistics(input)
+    }
+    fn get_trace_graph(
+        &self,
+        input: GetTraceGraphRequest,
+    ) -> RusotoFuture<GetTraceGraphResult, GetTraceGraphError> {
+        (**self).get_trace_graph(input)
+    }
+    fn get_trace_summaries(
+        &self,
+        input: GetTraceSummariesRequest,
+    ) -> RusotoFuture<GetTraceSummariesResult, GetTraceSummariesError> {
+        (**self).get_trace_summaries(input)
+    }
+    fn put_encryption_config(
+        &self,
+        input: PutEncryptionConfigRequest,
+    ) -> RusotoFuture<PutEncryptionConfigResult, PutEncryptionConfigError> {
+        (**self).put_encryption_config(input)
+    }
+    fn put_telemetry_records(
+        &self,
+        input: PutTelemetryRecordsRequest,
+    ) -> RusotoFuture<PutTelemetryRecordsResult, PutTelemetryRecordsError> {
+        (**self).put_telemetry_records(input)
+    }
+    fn put_trace_segments(
+        &self,
+        input: PutTraceSegmentsRequest,
+    ) -> RusotoFuture<PutTraceSegmentsResult, PutTraceSegmentsError> {
+        (**self).put_trace_segments(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResult, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_sampling_rule(
+        &self,
+        input: UpdateSamplingRuleRequest,
+    ) -> RusotoFuture<UpdateSamplingRuleResult, UpdateSamplingRuleError> {
+        (**self).update_sampling_rule(input)
+    }
+}
+/// The error codes AWS X-Ray retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS X-Ray API.
 #[derive(Clone)]
 pub struct XRayClient {
@@ -2451,6 +3726,24 @@ impl XRayClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_XRAY`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> XRayClient {
+        Self::new(region::Region::from_env_for_service("XRAY"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `xray`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> XRayClient {
+        Self::new_with_client(config.client().clone(), config.region_for("xray", "XRAY"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2469,7 +3762,49 @@ impl XRayClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> XRayClient {
-        XRayClient { client, region }
+        XRayClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "xray")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "xray", "xray", "XRAY")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `XRay` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn XRay + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for XRayClient {
+    fn service_name(&self) -> &'static str {
+        "AWS X-Ray"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "xray"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> XRayClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,74 @@
+//! Benchmarks of the Signature Version 4 hot path.
+//!
+//! Run with `cargo bench -p rusoto_signature`.
+
+use std::sync::Arc;
+use std::thread;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rusoto_signature::credential::AwsCredentials;
+use rusoto_signature::{Region, SignedRequest};
+
+const GET_ITEM: &[u8] =
+    br#"{"TableName":"table","Key":{"pk":{"S":"USER#42"},"sk":{"S":"PROFILE"}}}"#;
+
+fn credentials() -> AwsCredentials {
+    AwsCredentials::new(
+        "AKIDEXAMPLE",
+        "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+        None,
+        None,
+    )
+}
+
+fn get_item_request() -> SignedRequest {
+    let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+    request.set_content_type("application/x-amz-json-1.0".to_owned());
+    request.add_header("x-amz-target", "DynamoDB_20120810.GetItem");
+    request.set_payload(Some(GET_ITEM.to_vec()));
+    request
+}
+
+fn sign(c: &mut Criterion) {
+    let credentials = credentials();
+    c.bench_function("sign dynamodb request", |b| {
+        b.iter(|| get_item_request().sign(&credentials))
+    });
+
+    let credentials = Arc::new(credentials);
+    c.bench_function("sign 1000 dynamodb requests on 4 threads", |b| {
+        b.iter(|| {
+            let threads: Vec<_> = (0..4)
+                .map(|_| {
+                    let credentials = credentials.clone();
+                    thread::spawn(move || {
+                        for _ in 0..250 {
+                            get_item_request().sign(&credentials);
+                        }
+                    })
+                })
+                .collect();
+            for thread in threads {
+                thread.join().unwrap();
+            }
+        })
+    });
+}
+
+fn complement(c: &mut Criterion) {
+    let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket");
+    for (key, value) in &[
+        ("list-type", "2"),
+        ("prefix", "photos/2019/January & February/"),
+        ("start-after", "photos/2019/January & February/IMG_0042.jpg"),
+        ("max-keys", "1000"),
+    ] {
+        request.add_param(*key, *value);
+    }
+    c.bench_function("canonicalize s3 request", |b| {
+        b.iter(|| request.complement())
+    });
+}
+
+criterion_group!(benches, sign, complement);
+criterion_main!(benches);
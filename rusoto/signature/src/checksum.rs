@@ -0,0 +1,238 @@
+//! Payload checksums for integrity protected requests.
+//!
+//! S3 historically required a `Content-MD5` header for a number of operations. It now also
+//! accepts the `x-amz-checksum-*` family of headers, which allows cheaper algorithms to be used.
+//! CRC32 and CRC32C are computed with SIMD or dedicated CPU instructions (SSE 4.2, ARMv8 CRC)
+//! when the running processor supports them, falling back to a table driven implementation
+//! otherwise.
+
+use std::fmt;
+use std::str::FromStr;
+
+use base64;
+use crc32c;
+use crc32fast;
+use md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// The algorithm used to compute a payload checksum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChecksumAlgorithm {
+    /// MD5, sent in the `Content-MD5` header
+    Md5,
+    /// CRC32, sent in the `x-amz-checksum-crc32` header
+    Crc32,
+    /// CRC32C (Castagnoli), sent in the `x-amz-checksum-crc32c` header
+    Crc32c,
+    /// SHA-1, sent in the `x-amz-checksum-sha1` header
+    Sha1,
+    /// SHA-256, sent in the `x-amz-checksum-sha256` header
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// The name of the header carrying a checksum computed with this algorithm.
+    pub fn header_name(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Md5 => "Content-MD5",
+            ChecksumAlgorithm::Crc32 => "x-amz-checksum-crc32",
+            ChecksumAlgorithm::Crc32c => "x-amz-checksum-crc32c",
+            ChecksumAlgorithm::Sha1 => "x-amz-checksum-sha1",
+            ChecksumAlgorithm::Sha256 => "x-amz-checksum-sha256",
+        }
+    }
+
+    /// The name AWS uses for this algorithm, e.g. in the `x-amz-sdk-checksum-algorithm` header.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Md5 => "MD5",
+            ChecksumAlgorithm::Crc32 => "CRC32",
+            ChecksumAlgorithm::Crc32c => "CRC32C",
+            ChecksumAlgorithm::Sha1 => "SHA1",
+            ChecksumAlgorithm::Sha256 => "SHA256",
+        }
+    }
+
+    /// Computes the checksum of `payload`, returning the raw digest bytes.
+    ///
+    /// CRC digests are returned big-endian, as expected by AWS.
+    pub fn digest(self, payload: &[u8]) -> Vec<u8> {
+        match self {
+            ChecksumAlgorithm::Md5 => md5::compute(payload).to_vec(),
+            ChecksumAlgorithm::Crc32 => crc32fast::hash(payload).to_be_bytes().to_vec(),
+            ChecksumAlgorithm::Crc32c => crc32c::crc32c(payload).to_be_bytes().to_vec(),
+            ChecksumAlgorithm::Sha1 => Sha1::digest(payload).to_vec(),
+            ChecksumAlgorithm::Sha256 => Sha256::digest(payload).to_vec(),
+        }
+    }
+
+    /// Computes the checksum of `payload`, base64 encoded as expected in the checksum header.
+    pub fn compute(self, payload: &[u8]) -> String {
+        base64::encode(&self.digest(payload))
+    }
+
+    /// Starts computing the checksum of a payload received in pieces, e.g. a streaming body.
+    pub fn checksum(self) -> Checksum {
+        let state = match self {
+            ChecksumAlgorithm::Md5 => ChecksumState::Md5(md5::Context::new()),
+            ChecksumAlgorithm::Crc32 => ChecksumState::Crc32(crc32fast::Hasher::new()),
+            ChecksumAlgorithm::Crc32c => ChecksumState::Crc32c(0),
+            ChecksumAlgorithm::Sha1 => ChecksumState::Sha1(Sha1::new()),
+            ChecksumAlgorithm::Sha256 => ChecksumState::Sha256(Sha256::new()),
+        };
+        Checksum { state }
+    }
+}
+
+/// The checksum of a payload received in pieces, started with `ChecksumAlgorithm::checksum`.
+pub struct Checksum {
+    state: ChecksumState,
+}
+
+enum ChecksumState {
+    Md5(md5::Context),
+    Crc32(crc32fast::Hasher),
+    Crc32c(u32),
+    Sha1(Sha1),
+    Sha256(Sha256),
+}
+
+impl Checksum {
+    /// The algorithm the checksum is computed with.
+    pub fn algorithm(&self) -> ChecksumAlgorithm {
+        match self.state {
+            ChecksumState::Md5(_) => ChecksumAlgorithm::Md5,
+            ChecksumState::Crc32(_) => ChecksumAlgorithm::Crc32,
+            ChecksumState::Crc32c(_) => ChecksumAlgorithm::Crc32c,
+            ChecksumState::Sha1(_) => ChecksumAlgorithm::Sha1,
+            ChecksumState::Sha256(_) => ChecksumAlgorithm::Sha256,
+        }
+    }
+
+    /// Adds the next piece of the payload.
+    pub fn update(&mut self, data: &[u8]) {
+        match self.state {
+            ChecksumState::Md5(ref mut context) => context.consume(data),
+            ChecksumState::Crc32(ref mut hasher) => hasher.update(data),
+            ChecksumState::Crc32c(ref mut crc) => *crc = crc32c::crc32c_append(*crc, data),
+            ChecksumState::Sha1(ref mut hasher) => hasher.input(data),
+            ChecksumState::Sha256(ref mut hasher) => hasher.input(data),
+        }
+    }
+
+    /// Returns the raw digest bytes of the payload, like `ChecksumAlgorithm::digest`.
+    pub fn finish(self) -> Vec<u8> {
+        match self.state {
+            ChecksumState::Md5(context) => context.compute().to_vec(),
+            ChecksumState::Crc32(hasher) => hasher.finalize().to_be_bytes().to_vec(),
+            ChecksumState::Crc32c(crc) => crc.to_be_bytes().to_vec(),
+            ChecksumState::Sha1(hasher) => hasher.result().to_vec(),
+            ChecksumState::Sha256(hasher) => hasher.result().to_vec(),
+        }
+    }
+}
+
+impl fmt::Debug for Checksum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Checksum")
+            .field("algorithm", &self.algorithm())
+            .finish()
+    }
+}
+
+impl Default for ChecksumAlgorithm {
+    fn default() -> ChecksumAlgorithm {
+        ChecksumAlgorithm::Md5
+    }
+}
+
+impl fmt::Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// An error produced when parsing an unknown checksum algorithm name.
+#[derive(Debug, PartialEq)]
+pub struct ParseChecksumAlgorithmError {
+    message: String,
+}
+
+impl std::error::Error for ParseChecksumAlgorithmError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseChecksumAlgorithmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl FromStr for ChecksumAlgorithm {
+    type Err = ParseChecksumAlgorithmError;
+
+    fn from_str(s: &str) -> Result<ChecksumAlgorithm, ParseChecksumAlgorithmError> {
+        match s.to_ascii_uppercase().as_ref() {
+            "MD5" => Ok(ChecksumAlgorithm::Md5),
+            "CRC32" => Ok(ChecksumAlgorithm::Crc32),
+            "CRC32C" => Ok(ChecksumAlgorithm::Crc32c),
+            "SHA1" => Ok(ChecksumAlgorithm::Sha1),
+            "SHA256" => Ok(ChecksumAlgorithm::Sha256),
+            _ => Err(ParseChecksumAlgorithmError {
+                message: format!("Not a valid checksum algorithm: {}", s),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_known_checksums() {
+        let payload = b"hello world";
+        assert_eq!(
+            ChecksumAlgorithm::Md5.compute(payload),
+            "XrY7u+Ae7tCTyyK7j1rNww=="
+        );
+        assert_eq!(ChecksumAlgorithm::Crc32.compute(payload), "DUoRhQ==");
+        assert_eq!(ChecksumAlgorithm::Crc32c.compute(payload), "yZRlqg==");
+        assert_eq!(
+            ChecksumAlgorithm::Sha1.compute(payload),
+            "Kq5sNclPz7QV2+lfQIuc6R7oRu0="
+        );
+        assert_eq!(
+            ChecksumAlgorithm::Sha256.compute(payload),
+            "uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek="
+        );
+    }
+
+    #[test]
+    fn computes_checksums_in_pieces() {
+        for &algorithm in &[
+            ChecksumAlgorithm::Md5,
+            ChecksumAlgorithm::Crc32,
+            ChecksumAlgorithm::Crc32c,
+            ChecksumAlgorithm::Sha1,
+            ChecksumAlgorithm::Sha256,
+        ] {
+            let mut checksum = algorithm.checksum();
+            checksum.update(b"hello");
+            checksum.update(b"");
+            checksum.update(b" world");
+            assert_eq!(checksum.algorithm(), algorithm);
+            assert_eq!(checksum.finish(), algorithm.digest(b"hello world"));
+        }
+    }
+
+    #[test]
+    fn parses_algorithm_names() {
+        assert_eq!("crc32c".parse(), Ok(ChecksumAlgorithm::Crc32c));
+        assert_eq!("SHA1".parse(), Ok(ChecksumAlgorithm::Sha1));
+        assert!("adler32".parse::<ChecksumAlgorithm>().is_err());
+    }
+}
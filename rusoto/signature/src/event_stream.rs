@@ -0,0 +1,609 @@
+//! The `application/vnd.amazon.eventstream` message framing and its signing.
+//!
+//! Services with bidirectional streaming operations, such as Amazon Transcribe Streaming,
+//! exchange a sequence of binary messages over a single HTTP/2 request. Each message carries a
+//! set of typed headers and an opaque payload, and is protected by two CRC32 checksums:
+//!
+//! ```text
+//! [total length: u32][headers length: u32][prelude crc: u32][headers][payload][message crc: u32]
+//! ```
+//!
+//! Messages sent by the client are signed one by one: every message is wrapped in an envelope
+//! whose `:chunk-signature` header chains its signature to the signature of the previous
+//! message, starting with the signature of the request itself. See
+//! `SignedRequest::sign_event_stream`.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::str;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut, IntoBuf};
+use crc32fast;
+use futures::{stream, try_ready, Async, Poll, Stream};
+use hex;
+use hmac::Mac;
+use time::{now_utc, Tm};
+
+use crate::credential::AwsCredentials;
+use crate::signature::{hmac, signing_key, to_hexdigest};
+use crate::stream::ByteStream;
+
+/// Payload hash sent in the `x-amz-content-sha256` header of a request whose body is a stream
+/// of signed event stream messages.
+pub static STREAMING_EVENTS_PAYLOAD: &str = "STREAMING-AWS4-HMAC-SHA256-EVENTS";
+
+/// Length of the total length, headers length and prelude crc fields.
+const PRELUDE_LENGTH: usize = 12;
+/// Length of the trailing message crc.
+const MESSAGE_CRC_LENGTH: usize = 4;
+/// Messages larger than this are rejected by AWS, so decoding one is treated as an error.
+const MAX_MESSAGE_LENGTH: usize = 16 * 1024 * 1024;
+
+/// The value of an event stream message header.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HeaderValue {
+    /// A boolean, encoded in the header type itself
+    Bool(bool),
+    /// A signed byte
+    Byte(i8),
+    /// A 16 bit signed integer
+    Int16(i16),
+    /// A 32 bit signed integer
+    Int32(i32),
+    /// A 64 bit signed integer
+    Int64(i64),
+    /// An array of at most 65535 bytes
+    ByteArray(Bytes),
+    /// A UTF-8 string of at most 65535 bytes
+    String(String),
+    /// Milliseconds since the Unix epoch
+    Timestamp(i64),
+    /// A UUID, as its 16 raw bytes
+    Uuid([u8; 16]),
+}
+
+impl HeaderValue {
+    /// Returns the value as a string slice, if it is a `String` header.
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            HeaderValue::String(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        match *self {
+            HeaderValue::Bool(true) => buf.put_u8(0),
+            HeaderValue::Bool(false) => buf.put_u8(1),
+            HeaderValue::Byte(value) => {
+                buf.put_u8(2);
+                buf.put_i8(value);
+            }
+            HeaderValue::Int16(value) => {
+                buf.put_u8(3);
+                buf.put_i16_be(value);
+            }
+            HeaderValue::Int32(value) => {
+                buf.put_u8(4);
+                buf.put_i32_be(value);
+            }
+            HeaderValue::Int64(value) => {
+                buf.put_u8(5);
+                buf.put_i64_be(value);
+            }
+            HeaderValue::ByteArray(ref value) => {
+                buf.put_u8(6);
+                buf.put_u16_be(value.len() as u16);
+                buf.put_slice(value);
+            }
+            HeaderValue::String(ref value) => {
+                buf.put_u8(7);
+                buf.put_u16_be(value.len() as u16);
+                buf.put_slice(value.as_bytes());
+            }
+            HeaderValue::Timestamp(value) => {
+                buf.put_u8(8);
+                buf.put_i64_be(value);
+            }
+            HeaderValue::Uuid(ref value) => {
+                buf.put_u8(9);
+                buf.put_slice(value);
+            }
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        1 + match *self {
+            HeaderValue::Bool(_) => 0,
+            HeaderValue::Byte(_) => 1,
+            HeaderValue::Int16(_) => 2,
+            HeaderValue::Int32(_) => 4,
+            HeaderValue::Int64(_) | HeaderValue::Timestamp(_) => 8,
+            HeaderValue::ByteArray(ref value) => 2 + value.len(),
+            HeaderValue::String(ref value) => 2 + value.len(),
+            HeaderValue::Uuid(_) => 16,
+        }
+    }
+}
+
+/// A header of an event stream message.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Header {
+    /// The header name, at most 255 bytes long
+    pub name: String,
+    /// The header value
+    pub value: HeaderValue,
+}
+
+/// A single message of an event stream.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Message {
+    /// The message headers, in the order they are encoded
+    pub headers: Vec<Header>,
+    /// The message payload
+    pub payload: Bytes,
+}
+
+impl Message {
+    /// Creates a message without any headers.
+    pub fn new<B: Into<Bytes>>(payload: B) -> Message {
+        Message {
+            headers: Vec::new(),
+            payload: payload.into(),
+        }
+    }
+
+    /// Adds a header to the message.
+    pub fn with_header<N: Into<String>>(mut self, name: N, value: HeaderValue) -> Message {
+        self.headers.push(Header {
+            name: name.into(),
+            value,
+        });
+        self
+    }
+
+    /// Returns the value of the first header named `name`.
+    pub fn header(&self, name: &str) -> Option<&HeaderValue> {
+        self.headers
+            .iter()
+            .find(|header| header.name == name)
+            .map(|header| &header.value)
+    }
+
+    /// Encodes the message, including its prelude and checksums.
+    pub fn encode(&self) -> Bytes {
+        let headers = encode_headers(&self.headers);
+        let total_length = PRELUDE_LENGTH + headers.len() + self.payload.len() + MESSAGE_CRC_LENGTH;
+
+        let mut buf = BytesMut::with_capacity(total_length);
+        buf.put_u32_be(total_length as u32);
+        buf.put_u32_be(headers.len() as u32);
+        let prelude_crc = crc32fast::hash(&buf[..8]);
+        buf.put_u32_be(prelude_crc);
+        buf.put_slice(&headers);
+        buf.put_slice(&self.payload);
+        let message_crc = crc32fast::hash(&buf);
+        buf.put_u32_be(message_crc);
+        buf.freeze()
+    }
+
+    /// Decodes the first message in `buf`, removing it from the buffer.
+    ///
+    /// Returns `Ok(None)` and leaves the buffer untouched if it does not yet hold a complete
+    /// message.
+    pub fn decode(buf: &mut BytesMut) -> Result<Option<Message>, EventStreamError> {
+        if buf.len() < PRELUDE_LENGTH {
+            return Ok(None);
+        }
+        let mut prelude = (&buf[..PRELUDE_LENGTH]).into_buf();
+        let total_length = prelude.get_u32_be() as usize;
+        let headers_length = prelude.get_u32_be() as usize;
+        let prelude_crc = prelude.get_u32_be();
+
+        if crc32fast::hash(&buf[..8]) != prelude_crc {
+            return Err(EventStreamError::new("prelude checksum mismatch"));
+        }
+        if total_length > MAX_MESSAGE_LENGTH
+            || total_length < PRELUDE_LENGTH + headers_length + MESSAGE_CRC_LENGTH
+        {
+            return Err(EventStreamError::new(format!(
+                "invalid message length {} with {} bytes of headers",
+                total_length, headers_length
+            )));
+        }
+        if buf.len() < total_length {
+            return Ok(None);
+        }
+
+        let message = buf.split_to(total_length).freeze();
+        let crc_offset = total_length - MESSAGE_CRC_LENGTH;
+        let message_crc = (&message[crc_offset..]).into_buf().get_u32_be();
+        if crc32fast::hash(&message[..crc_offset]) != message_crc {
+            return Err(EventStreamError::new("message checksum mismatch"));
+        }
+
+        let headers_end = PRELUDE_LENGTH + headers_length;
+        Ok(Some(Message {
+            headers: decode_headers(&message[PRELUDE_LENGTH..headers_end])?,
+            payload: message.slice(headers_end, crc_offset),
+        }))
+    }
+}
+
+fn encode_headers(headers: &[Header]) -> BytesMut {
+    let length = headers
+        .iter()
+        .map(|header| 1 + header.name.len() + header.value.encoded_len())
+        .sum();
+    let mut buf = BytesMut::with_capacity(length);
+    for header in headers {
+        buf.put_u8(header.name.len() as u8);
+        buf.put_slice(header.name.as_bytes());
+        header.value.encode(&mut buf);
+    }
+    buf
+}
+
+fn decode_headers(mut buf: &[u8]) -> Result<Vec<Header>, EventStreamError> {
+    fn take<'a>(buf: &mut &'a [u8], n: usize) -> Result<&'a [u8], EventStreamError> {
+        if buf.len() < n {
+            return Err(EventStreamError::new("truncated message headers"));
+        }
+        let (taken, rest) = buf.split_at(n);
+        *buf = rest;
+        Ok(taken)
+    }
+    fn utf8(bytes: &[u8]) -> Result<String, EventStreamError> {
+        str::from_utf8(bytes)
+            .map(str::to_owned)
+            .map_err(|_| EventStreamError::new("header is not valid UTF-8"))
+    }
+
+    let mut headers = Vec::new();
+    while !buf.is_empty() {
+        let name_length = take(&mut buf, 1)?[0] as usize;
+        let name = utf8(take(&mut buf, name_length)?)?;
+        let value = match take(&mut buf, 1)?[0] {
+            0 => HeaderValue::Bool(true),
+            1 => HeaderValue::Bool(false),
+            2 => HeaderValue::Byte(take(&mut buf, 1)?[0] as i8),
+            3 => HeaderValue::Int16(take(&mut buf, 2)?.into_buf().get_i16_be()),
+            4 => HeaderValue::Int32(take(&mut buf, 4)?.into_buf().get_i32_be()),
+            5 => HeaderValue::Int64(take(&mut buf, 8)?.into_buf().get_i64_be()),
+            6 => {
+                let length = take(&mut buf, 2)?.into_buf().get_u16_be() as usize;
+                HeaderValue::ByteArray(Bytes::from(take(&mut buf, length)?))
+            }
+            7 => {
+                let length = take(&mut buf, 2)?.into_buf().get_u16_be() as usize;
+                HeaderValue::String(utf8(take(&mut buf, length)?)?)
+            }
+            8 => HeaderValue::Timestamp(take(&mut buf, 8)?.into_buf().get_i64_be()),
+            9 => {
+                let mut uuid = [0; 16];
+                uuid.copy_from_slice(take(&mut buf, 16)?);
+                HeaderValue::Uuid(uuid)
+            }
+            other => {
+                return Err(EventStreamError::new(format!(
+                    "unknown header value type {}",
+                    other
+                )))
+            }
+        };
+        headers.push(Header { name, value });
+    }
+    Ok(headers)
+}
+
+/// An error produced when an event stream message is malformed.
+#[derive(Debug, PartialEq)]
+pub struct EventStreamError {
+    message: String,
+}
+
+impl EventStreamError {
+    fn new<M: Into<String>>(message: M) -> EventStreamError {
+        EventStreamError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for EventStreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for EventStreamError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<EventStreamError> for io::Error {
+    fn from(err: EventStreamError) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Decodes the messages of an event stream response body.
+pub struct MessageStream {
+    body: ByteStream,
+    buffer: BytesMut,
+    finished: bool,
+}
+
+impl MessageStream {
+    /// Decodes the messages read from `body`.
+    pub fn new(body: ByteStream) -> MessageStream {
+        MessageStream {
+            body,
+            buffer: BytesMut::new(),
+            finished: false,
+        }
+    }
+}
+
+impl fmt::Debug for MessageStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<MessageStream buffered={}>", self.buffer.len())
+    }
+}
+
+impl Stream for MessageStream {
+    type Item = Message;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Message>, io::Error> {
+        loop {
+            if let Some(message) = Message::decode(&mut self.buffer)? {
+                return Ok(Async::Ready(Some(message)));
+            }
+            if self.finished {
+                if self.buffer.is_empty() {
+                    return Ok(Async::Ready(None));
+                }
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "event stream ended in the middle of a message",
+                ));
+            }
+            match try_ready!(self.body.poll()) {
+                Some(chunk) => self.buffer.extend_from_slice(&chunk),
+                None => self.finished = true,
+            }
+        }
+    }
+}
+
+/// Signs the messages sent on an event stream.
+///
+/// Created by `SignedRequest::sign_event_stream`, seeded with the signature of the request.
+#[derive(Clone, Debug)]
+pub struct EventStreamSigner {
+    credentials: AwsCredentials,
+    region: String,
+    service: String,
+    prior_signature: String,
+}
+
+impl EventStreamSigner {
+    /// Creates a signer whose first message signature is chained to `seed_signature`.
+    pub fn new(
+        credentials: AwsCredentials,
+        region: &str,
+        service: &str,
+        seed_signature: &str,
+    ) -> EventStreamSigner {
+        EventStreamSigner {
+            credentials,
+            region: region.to_owned(),
+            service: service.to_owned(),
+            prior_signature: seed_signature.to_owned(),
+        }
+    }
+
+    /// Wraps `message` in a signed envelope, dated now.
+    pub fn sign(&mut self, message: &Message) -> Message {
+        self.sign_at(message.encode(), now_utc())
+    }
+
+    /// Returns the signed empty message which marks the end of the stream.
+    pub fn sign_end_of_stream(&mut self) -> Message {
+        self.sign_at(Bytes::new(), now_utc())
+    }
+
+    /// Wraps the encoded message `payload` in an envelope carrying its `:date` and
+    /// `:chunk-signature` headers.
+    fn sign_at(&mut self, payload: Bytes, date: Tm) -> Message {
+        let timestamp = date.to_timespec();
+        let date_header = Header {
+            name: ":date".to_owned(),
+            value: HeaderValue::Timestamp(
+                timestamp.sec * 1000 + i64::from(timestamp.nsec) / 1_000_000,
+            ),
+        };
+
+        let scope = format!(
+            "{}/{}/{}/aws4_request",
+            date.strftime("%Y%m%d").unwrap(),
+            self.region,
+            self.service
+        );
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256-PAYLOAD\n{}\n{}\n{}\n{}\n{}",
+            date.strftime("%Y%m%dT%H%M%SZ").unwrap(),
+            scope,
+            self.prior_signature,
+            to_hexdigest(encode_headers(&[date_header.clone()])),
+            to_hexdigest(&payload)
+        );
+        let key = signing_key(
+            self.credentials.aws_secret_access_key(),
+            date,
+            &self.region,
+            &self.service,
+        );
+        let signature = hmac(&key, string_to_sign.as_bytes()).result().code();
+        self.prior_signature = hex::encode(signature.as_ref());
+
+        Message {
+            headers: vec![
+                date_header,
+                Header {
+                    name: ":chunk-signature".to_owned(),
+                    value: HeaderValue::ByteArray(Bytes::from(signature.as_ref())),
+                },
+            ],
+            payload,
+        }
+    }
+
+    /// Signs every message of `messages`, followed by the end of stream message, and returns
+    /// the encoded envelopes as a request body.
+    pub fn sign_stream<S>(self, messages: S) -> ByteStream
+    where
+        S: Stream<Item = Message, Error = io::Error> + Send + 'static,
+    {
+        let mut signer = self;
+        let signed = messages
+            .map(Some)
+            .chain(stream::once(Ok(None)))
+            .map(move |message| {
+                let envelope = match message {
+                    Some(message) => signer.sign(&message),
+                    None => signer.sign_end_of_stream(),
+                };
+                envelope.encode()
+            });
+        ByteStream::new(signed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::Future;
+    use time::at_utc;
+    use time::Timespec;
+
+    fn credentials() -> AwsCredentials {
+        AwsCredentials::new(
+            "AKIDEXAMPLE",
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            None,
+            None,
+        )
+    }
+
+    fn audio_event(payload: &'static [u8]) -> Message {
+        Message::new(payload)
+            .with_header(":message-type", HeaderValue::String("event".to_owned()))
+            .with_header(":event-type", HeaderValue::String("AudioEvent".to_owned()))
+    }
+
+    #[test]
+    fn encodes_a_message_without_headers() {
+        // the smallest valid message, as documented for the event stream format
+        let encoded = Message::new(Bytes::new()).encode();
+        assert_eq!(
+            &encoded[..],
+            &[
+                0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x05, 0xc2, 0x48, 0xeb, 0x7d, 0x98,
+                0xc8, 0xff
+            ][..]
+        );
+    }
+
+    #[test]
+    fn round_trips_every_header_type() {
+        let message = Message::new(&b"{\"foo\":\"bar\"}"[..])
+            .with_header("true", HeaderValue::Bool(true))
+            .with_header("false", HeaderValue::Bool(false))
+            .with_header("byte", HeaderValue::Byte(-2))
+            .with_header("short", HeaderValue::Int16(-300))
+            .with_header("int", HeaderValue::Int32(70_000))
+            .with_header("long", HeaderValue::Int64(-5_000_000_000))
+            .with_header(
+                "bytes",
+                HeaderValue::ByteArray(Bytes::from_static(&[0xde, 0xad])),
+            )
+            .with_header("string", HeaderValue::String("event".to_owned()))
+            .with_header("timestamp", HeaderValue::Timestamp(1_369_353_600_000))
+            .with_header("uuid", HeaderValue::Uuid([7; 16]));
+
+        let mut buf = BytesMut::from(&message.encode()[..]);
+        assert_eq!(Message::decode(&mut buf), Ok(Some(message)));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn waits_for_a_complete_message() {
+        let encoded = audio_event(b"audio").encode();
+        let mut buf = BytesMut::from(&encoded[..encoded.len() - 1]);
+        assert_eq!(Message::decode(&mut buf), Ok(None));
+        assert_eq!(buf.len(), encoded.len() - 1);
+    }
+
+    #[test]
+    fn rejects_corrupted_messages() {
+        let mut encoded = BytesMut::from(&audio_event(b"audio").encode()[..]);
+        let last = encoded.len() - 5;
+        encoded[last] ^= 0xff;
+        assert_eq!(
+            Message::decode(&mut encoded),
+            Err(EventStreamError::new("message checksum mismatch"))
+        );
+    }
+
+    #[test]
+    fn decodes_messages_split_across_chunks() {
+        let mut body = BytesMut::new();
+        body.extend_from_slice(&audio_event(b"first").encode());
+        body.extend_from_slice(&audio_event(b"second").encode());
+        let chunks = body.chunks(7).map(Bytes::from).collect::<Vec<Bytes>>();
+
+        let messages = MessageStream::new(ByteStream::new(stream::iter_ok(chunks)))
+            .collect()
+            .wait()
+            .unwrap();
+        assert_eq!(
+            messages,
+            vec![audio_event(b"first"), audio_event(b"second")]
+        );
+    }
+
+    #[test]
+    fn chains_message_signatures() {
+        let date = at_utc(Timespec::new(1_369_353_600, 0));
+        let mut signer = EventStreamSigner::new(credentials(), "us-east-1", "transcribe", "seed");
+
+        let first = signer.sign_at(audio_event(b"audio").encode(), date);
+        let first_signature = signer.prior_signature.clone();
+        let second = signer.sign_at(Bytes::new(), date);
+
+        assert_eq!(
+            first.header(":date"),
+            Some(&HeaderValue::Timestamp(1_369_353_600_000))
+        );
+        match first.header(":chunk-signature") {
+            Some(HeaderValue::ByteArray(signature)) => {
+                assert_eq!(hex::encode(signature), first_signature)
+            }
+            other => panic!("unexpected signature header {:?}", other),
+        }
+        assert_eq!(
+            Message::decode(&mut BytesMut::from(&first.payload[..])),
+            Ok(Some(audio_event(b"audio")))
+        );
+        // every signature covers the one before it
+        assert_ne!(
+            first.header(":chunk-signature"),
+            second.header(":chunk-signature")
+        );
+        assert_ne!(signer.prior_signature, first_signature);
+    }
+}
@@ -10,9 +10,19 @@
 //!
 #![cfg_attr(not(feature = "unstable"), deny(warnings))]
 pub extern crate rusoto_credential as credential;
+#[cfg(feature = "region-detection")]
+#[macro_use]
+extern crate lazy_static;
+pub mod checksum;
+pub mod event_stream;
+pub mod partition;
 pub mod region;
+#[cfg(feature = "region-detection")]
+mod region_detection;
 pub mod signature;
 pub mod stream;
+pub use checksum::ChecksumAlgorithm;
+pub use partition::Partition;
 pub use region::Region;
-pub use signature::{SignedRequest, SignedRequestPayload};
-pub use stream::ByteStream;
+pub use signature::{SessionTokenPlacement, SignedRequest, SignedRequestPayload, SigningKey};
+pub use stream::{ByteStream, TransferProgress};
@@ -0,0 +1,186 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+use super::{GlobalEndpoint, Partition};
+
+/// The partitions in botocore's `endpoints.json`.
+pub(super) const PARTITIONS: &[Partition] = &[
+    Partition {
+        id: "aws",
+        name: "AWS Standard",
+        dns_suffix: "amazonaws.com",
+        region_prefixes: &["us-", "eu-", "ap-", "sa-", "ca-", "me-"],
+        regions: &[
+            "ap-east-1",
+            "ap-northeast-1",
+            "ap-northeast-2",
+            "ap-south-1",
+            "ap-southeast-1",
+            "ap-southeast-2",
+            "ca-central-1",
+            "eu-central-1",
+            "eu-north-1",
+            "eu-west-1",
+            "eu-west-2",
+            "eu-west-3",
+            "me-south-1",
+            "sa-east-1",
+            "us-east-1",
+            "us-east-2",
+            "us-west-1",
+            "us-west-2",
+        ],
+        global_endpoints: &[
+            GlobalEndpoint {
+                service: "budgets",
+                hostname: "budgets.amazonaws.com",
+                signing_region: "us-east-1",
+            },
+            GlobalEndpoint {
+                service: "ce",
+                hostname: "ce.us-east-1.amazonaws.com",
+                signing_region: "us-east-1",
+            },
+            GlobalEndpoint {
+                service: "chime",
+                hostname: "service.chime.aws.amazon.com",
+                signing_region: "us-east-1",
+            },
+            GlobalEndpoint {
+                service: "cloudfront",
+                hostname: "cloudfront.amazonaws.com",
+                signing_region: "us-east-1",
+            },
+            GlobalEndpoint {
+                service: "iam",
+                hostname: "iam.amazonaws.com",
+                signing_region: "us-east-1",
+            },
+            GlobalEndpoint {
+                service: "importexport",
+                hostname: "importexport.amazonaws.com",
+                signing_region: "us-east-1",
+            },
+            GlobalEndpoint {
+                service: "organizations",
+                hostname: "organizations.us-east-1.amazonaws.com",
+                signing_region: "us-east-1",
+            },
+            GlobalEndpoint {
+                service: "route53",
+                hostname: "route53.amazonaws.com",
+                signing_region: "us-east-1",
+            },
+            GlobalEndpoint {
+                service: "shield",
+                hostname: "shield.us-east-1.amazonaws.com",
+                signing_region: "us-east-1",
+            },
+            GlobalEndpoint {
+                service: "waf",
+                hostname: "waf.amazonaws.com",
+                signing_region: "us-east-1",
+            },
+        ],
+    },
+    Partition {
+        id: "aws-cn",
+        name: "AWS China",
+        dns_suffix: "amazonaws.com.cn",
+        region_prefixes: &["cn-"],
+        regions: &["cn-north-1", "cn-northwest-1"],
+        global_endpoints: &[
+            GlobalEndpoint {
+                service: "ce",
+                hostname: "ce.cn-northwest-1.amazonaws.com.cn",
+                signing_region: "cn-northwest-1",
+            },
+            GlobalEndpoint {
+                service: "iam",
+                hostname: "iam.cn-north-1.amazonaws.com.cn",
+                signing_region: "cn-north-1",
+            },
+            GlobalEndpoint {
+                service: "organizations",
+                hostname: "organizations.cn-northwest-1.amazonaws.com.cn",
+                signing_region: "cn-northwest-1",
+            },
+            GlobalEndpoint {
+                service: "route53",
+                hostname: "route53.amazonaws.com.cn",
+                signing_region: "cn-northwest-1",
+            },
+        ],
+    },
+    Partition {
+        id: "aws-us-gov",
+        name: "AWS GovCloud (US)",
+        dns_suffix: "amazonaws.com",
+        region_prefixes: &["us-gov-"],
+        regions: &["us-gov-east-1", "us-gov-west-1"],
+        global_endpoints: &[
+            GlobalEndpoint {
+                service: "iam",
+                hostname: "iam.us-gov.amazonaws.com",
+                signing_region: "us-gov-west-1",
+            },
+            GlobalEndpoint {
+                service: "organizations",
+                hostname: "organizations.us-gov-west-1.amazonaws.com",
+                signing_region: "us-gov-west-1",
+            },
+            GlobalEndpoint {
+                service: "route53",
+                hostname: "route53.us-gov.amazonaws.com",
+                signing_region: "us-gov-west-1",
+            },
+        ],
+    },
+    Partition {
+        id: "aws-iso",
+        name: "AWS ISO (US)",
+        dns_suffix: "c2s.ic.gov",
+        region_prefixes: &["us-iso-"],
+        regions: &["us-iso-east-1"],
+        global_endpoints: &[
+            GlobalEndpoint {
+                service: "iam",
+                hostname: "iam.us-iso-east-1.c2s.ic.gov",
+                signing_region: "us-iso-east-1",
+            },
+            GlobalEndpoint {
+                service: "route53",
+                hostname: "route53.c2s.ic.gov",
+                signing_region: "us-iso-east-1",
+            },
+        ],
+    },
+    Partition {
+        id: "aws-iso-b",
+        name: "AWS ISOB (US)",
+        dns_suffix: "sc2s.sgov.gov",
+        region_prefixes: &["us-isob-"],
+        regions: &["us-isob-east-1"],
+        global_endpoints: &[
+            GlobalEndpoint {
+                service: "iam",
+                hostname: "iam.us-isob-east-1.sc2s.sgov.gov",
+                signing_region: "us-isob-east-1",
+            },
+            GlobalEndpoint {
+                service: "route53",
+                hostname: "route53.sc2s.sgov.gov",
+                signing_region: "us-isob-east-1",
+            },
+        ],
+    },
+];
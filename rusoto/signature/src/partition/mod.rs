@@ -0,0 +1,157 @@
+//! AWS partitions.
+//!
+//! Regions are grouped in partitions, each with its own DNS suffix and credentials. Most regions
+//! are in the `aws` partition, whose endpoints end with `amazonaws.com`. The China regions are
+//! in `aws-cn` (`amazonaws.com.cn`), the GovCloud regions in `aws-us-gov`, and the isolated
+//! regions in `aws-iso` (`c2s.ic.gov`) and `aws-iso-b` (`sc2s.sgov.gov`).
+//!
+//! Services which aren't regionalized, such as IAM or Route 53, have a single endpoint in each
+//! partition, and requests to it are signed for one of the partition's regions whichever region
+//! the client was created for.
+//!
+//! The partitions are generated from botocore's `endpoints.json` by `service_crategen`.
+//!
+//! ```
+//! # use rusoto_signature::Region;
+//! let partition = Region::CnNorth1.partition();
+//! assert_eq!(partition.id(), "aws-cn");
+//! assert_eq!(partition.dns_suffix(), "amazonaws.com.cn");
+//! assert_eq!(partition.hostname("sqs", "cn-north-1"), "sqs.cn-north-1.amazonaws.com.cn");
+//! ```
+
+mod generated;
+
+/// A partition of AWS.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Partition {
+    id: &'static str,
+    name: &'static str,
+    dns_suffix: &'static str,
+    /// Prefixes of the names of regions in the partition which aren't listed in `regions` yet
+    region_prefixes: &'static [&'static str],
+    regions: &'static [&'static str],
+    global_endpoints: &'static [GlobalEndpoint],
+}
+
+/// The endpoint of a service which isn't regionalized in a partition.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GlobalEndpoint {
+    /// The endpoint prefix of the service, e.g. `iam`.
+    pub service: &'static str,
+    /// The host requests are sent to, e.g. `iam.amazonaws.com`.
+    pub hostname: &'static str,
+    /// The region requests are signed for, e.g. `us-east-1`.
+    pub signing_region: &'static str,
+}
+
+impl Partition {
+    /// All the partitions.
+    pub fn all() -> &'static [Partition] {
+        generated::PARTITIONS
+    }
+
+    /// The partition with the ID `id`, such as `aws-us-gov`.
+    pub fn from_id(id: &str) -> Option<&'static Partition> {
+        Partition::all().iter().find(|partition| partition.id == id)
+    }
+
+    /// The partition of the region named `region`: the one listing it, or else the one whose
+    /// regions are named like it, e.g. `cn-*` regions are in `aws-cn`.
+    pub fn from_region_name(region: &str) -> Option<&'static Partition> {
+        let partitions = Partition::all();
+        partitions
+            .iter()
+            .find(|partition| partition.regions.iter().any(|&name| name == region))
+            .or_else(|| {
+                // the longest prefix wins, e.g. `us-gov-` over `us-`
+                partitions
+                    .iter()
+                    .flat_map(|partition| {
+                        partition
+                            .region_prefixes
+                            .iter()
+                            .map(move |prefix| (prefix, partition))
+                    })
+                    .filter(|&(prefix, _)| region.starts_with(prefix))
+                    .max_by_key(|&(prefix, _)| prefix.len())
+                    .map(|(_, partition)| partition)
+            })
+    }
+
+    /// The standard `aws` partition.
+    pub fn aws() -> &'static Partition {
+        Partition::from_id("aws").expect("the aws partition is missing")
+    }
+
+    /// The ID of the partition, e.g. `aws-cn`.
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// The name of the partition, e.g. `AWS China`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The suffix of the hostnames of the partition, e.g. `amazonaws.com.cn`.
+    pub fn dns_suffix(&self) -> &'static str {
+        self.dns_suffix
+    }
+
+    /// The names of the regions in the partition.
+    pub fn regions(&self) -> &'static [&'static str] {
+        self.regions
+    }
+
+    /// The single endpoint of `service` in the partition, if it isn't regionalized.
+    pub fn global_endpoint(&self, service: &str) -> Option<&'static GlobalEndpoint> {
+        self.global_endpoints
+            .iter()
+            .find(|endpoint| endpoint.service == service)
+    }
+
+    /// The hostname of the endpoint of a regionalized service in `region`, e.g.
+    /// `sqs.cn-north-1.amazonaws.com.cn`.
+    pub fn hostname(&self, service: &str, region: &str) -> String {
+        format!("{}.{}.{}", service, region, self.dns_suffix)
+    }
+
+    /// The hostname of the FIPS 140-2 endpoint of a service in `region`, e.g.
+    /// `kms-fips.us-gov-west-1.amazonaws.com`.
+    ///
+    /// This is how most services name their FIPS endpoints, but not all services have one in
+    /// every region. Check the service's documentation.
+    pub fn fips_hostname(&self, service: &str, region: &str) -> String {
+        format!("{}-fips.{}.{}", service, region, self.dns_suffix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regions_are_in_their_partition() {
+        let partition = |region| Partition::from_region_name(region).map(Partition::id);
+        assert_eq!(partition("eu-west-1"), Some("aws"));
+        assert_eq!(partition("cn-northwest-1"), Some("aws-cn"));
+        assert_eq!(partition("us-gov-west-1"), Some("aws-us-gov"));
+        assert_eq!(partition("us-iso-east-1"), Some("aws-iso"));
+        assert_eq!(partition("us-isob-east-1"), Some("aws-iso-b"));
+        // regions which aren't listed yet
+        assert_eq!(partition("ap-northeast-3"), Some("aws"));
+        assert_eq!(partition("us-gov-central-1"), Some("aws-us-gov"));
+        assert_eq!(partition("moon-base-1"), None);
+    }
+
+    #[test]
+    fn global_endpoints_are_per_partition() {
+        let iam = Partition::from_id("aws-us-gov")
+            .unwrap()
+            .global_endpoint("iam")
+            .unwrap();
+        assert_eq!(iam.hostname, "iam.us-gov.amazonaws.com");
+        assert_eq!(iam.signing_region, "us-gov-west-1");
+        assert!(Partition::aws().global_endpoint("sqs").is_none());
+    }
+}
@@ -4,7 +4,12 @@
 //!
 //! For example: `UsEast1` to "us-east-1"
 
-use crate::credential::ProfileProvider;
+use crate::credential::{
+    CredentialsError, Environment, InstanceMetadataClient, ProfileProvider, SystemEnvironment,
+};
+use crate::partition::Partition;
+use futures::future::{self, Either};
+use futures::Future;
 use serde::ser::SerializeTuple;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std;
@@ -21,6 +26,13 @@ use std::str::FromStr;
 /// If it is not present it will fallback on the value associated with the current profile in `~/.aws/config` or the file
 /// specified by the `AWS_CONFIG_FILE` environment variable. If that is malformed of absent it will fall back on `Region::UsEast1`
 ///
+/// With the `region-detection` feature, the region of the ECS task or EC2 instance the process
+/// runs in is used instead of `Region::UsEast1`, if it can be read from the task metadata
+/// endpoint or the instance metadata service. This is done once, the first time it is needed;
+/// each request times out after `AWS_METADATA_SERVICE_TIMEOUT` seconds, 1 by default, and
+/// setting `AWS_EC2_METADATA_DISABLED` to `true` skips the instance metadata service. Without
+/// the feature, `Region::default_or_instance_metadata` asks the instance metadata service.
+///
 /// # AWS-compatible services
 ///
 /// `Region::Custom` can be used to connect to AWS-compatible services such as DynamoDB Local or Ceph.
@@ -33,6 +45,12 @@ use std::str::FromStr;
 ///     };
 /// ```
 ///
+/// # Partitions
+///
+/// Each region is in a partition, such as `aws-cn` for the China regions, which determines the
+/// DNS suffix of its endpoints and the region requests to services which aren't regionalized,
+/// like IAM, are signed for. See `Region::partition` and the `partition` module.
+///
 /// # Caveats
 ///
 /// `CnNorth1` is currently untested due to Rusoto maintainers not having access to AWS China.
@@ -107,6 +125,12 @@ pub enum Region {
     /// Region that covers North-Western  part of China
     CnNorthwest1,
 
+    /// Region that covers the Eastern part of the United States for the US intelligence community
+    UsIsoEast1,
+
+    /// Region that covers the Eastern part of the United States for the US intelligence community
+    UsIsobEast1,
+
     /// Specifies a custom region, such as a local Ceph target
     Custom {
         /// Name of the endpoint (e.g. `"eu-east-2"`).
@@ -154,9 +178,137 @@ impl Region {
             Region::UsGovWest1 => "us-gov-west-1",
             Region::CnNorth1 => "cn-north-1",
             Region::CnNorthwest1 => "cn-northwest-1",
+            Region::UsIsoEast1 => "us-iso-east-1",
+            Region::UsIsobEast1 => "us-isob-east-1",
             Region::Custom { ref name, .. } => name,
         }
     }
+
+    /// The partition of the region. A `Region::Custom` is in the partition its name belongs to,
+    /// or else in the standard `aws` partition.
+    ///
+    /// ```
+    ///     # use rusoto_signature::Region;
+    ///     assert_eq!(Region::UsGovWest1.partition().id(), "aws-us-gov");
+    ///     assert_eq!(Region::UsIsoEast1.partition().dns_suffix(), "c2s.ic.gov");
+    /// ```
+    pub fn partition(&self) -> &'static Partition {
+        Partition::from_region_name(self.name()).unwrap_or_else(Partition::aws)
+    }
+
+    /// The region with requests to `service` sent to its FIPS 140-2 endpoint, e.g.
+    /// `kms-fips.us-gov-west-1.amazonaws.com` for `kms` in `Region::UsGovWest1`.
+    ///
+    /// `service` is the endpoint prefix of the service. A `Region::Custom` is returned as is.
+    ///
+    /// ```
+    ///     # use rusoto_signature::Region;
+    ///     assert_eq!(
+    ///         Region::UsEast1.fips("kms"),
+    ///         Region::Custom {
+    ///             name: "us-east-1".to_owned(),
+    ///             endpoint: "https://kms-fips.us-east-1.amazonaws.com".to_owned(),
+    ///         }
+    ///     );
+    /// ```
+    pub fn fips(&self, service: &str) -> Region {
+        if let Region::Custom { .. } = *self {
+            return self.clone();
+        }
+        Region::Custom {
+            name: self.name().to_owned(),
+            endpoint: format!(
+                "https://{}",
+                self.partition().fips_hostname(service, self.name())
+            ),
+        }
+    }
+
+    /// Resolves the region for a service entirely from the environment.
+    ///
+    /// The region is determined as for `Region::default()`. If the `AWS_ENDPOINT_URL_<SERVICE>`
+    /// or `AWS_ENDPOINT_URL` environment variable is set, a `Region::Custom` with that endpoint
+    /// is returned instead, keeping the resolved region name for signing.
+    ///
+    /// `service` is the upper case service name used in the environment variable, e.g. `S3`
+    /// or `ACM_PCA`.
+    pub fn from_env_for_service(service: &str) -> Region {
+        Region::from_environment_for_service(service, &SystemEnvironment)
+    }
+
+    /// Resolves the region like `Region::default`, but reads the environment variables and the
+    /// location of the config file from `environment` instead of the environment of the process.
+    ///
+    /// ```
+    /// # use rusoto_signature::Region;
+    /// use rusoto_credential::VirtualEnvironment;
+    ///
+    /// let environment = VirtualEnvironment::new().with_var("AWS_REGION", "eu-west-1");
+    /// assert_eq!(Region::from_environment(&environment), Region::EuWest1);
+    /// ```
+    pub fn from_environment(environment: &dyn Environment) -> Region {
+        Region::configured(environment).unwrap_or(Region::UsEast1)
+    }
+
+    /// Resolves the region like `Region::default`, but asks the instance metadata service for
+    /// the region of the EC2 instance when neither the environment nor the config file name one,
+    /// rather than falling back to `Region::UsEast1`.
+    ///
+    /// This fails when the instance metadata service can't be reached, e.g. off EC2.
+    ///
+    /// ```rust,no_run
+    /// # extern crate futures;
+    /// # extern crate rusoto_signature;
+    /// use futures::Future;
+    /// use rusoto_signature::credential::InstanceMetadataClient;
+    /// use rusoto_signature::Region;
+    ///
+    /// # fn main() {
+    /// let region = Region::default_or_instance_metadata(&InstanceMetadataClient::new())
+    ///     .wait()
+    ///     .unwrap_or_default();
+    /// # }
+    /// ```
+    pub fn default_or_instance_metadata(
+        client: &InstanceMetadataClient,
+    ) -> impl Future<Item = Region, Error = CredentialsError> + Send {
+        match Region::configured(&SystemEnvironment) {
+            Some(region) => Either::A(future::ok(region)),
+            None => Either::B(client.region().and_then(|name| {
+                Region::from_str(&name).map_err(|e| CredentialsError::new(e.message))
+            })),
+        }
+    }
+
+    /// The region named by the environment variables or the config file, if it is valid.
+    fn configured(environment: &dyn Environment) -> Option<Region> {
+        match environment
+            .var("AWS_DEFAULT_REGION")
+            .or_else(|| environment.var("AWS_REGION"))
+        {
+            Some(ref v) => Region::from_str(v).ok(),
+            None => match ProfileProvider::region_from_environment(environment) {
+                Ok(Some(region)) => Region::from_str(&region).ok(),
+                _ => None,
+            },
+        }
+    }
+
+    /// Like `Region::from_env_for_service`, but reads the environment variables and the location
+    /// of the config file from `environment` instead of the environment of the process.
+    pub fn from_environment_for_service(service: &str, environment: &dyn Environment) -> Region {
+        let region = Region::from_environment(environment);
+        let endpoint = environment
+            .var(&format!("AWS_ENDPOINT_URL_{}", service))
+            .or_else(|| environment.var("AWS_ENDPOINT_URL"));
+        match endpoint {
+            Some(ref endpoint) if !endpoint.is_empty() => Region::Custom {
+                name: region.name().to_owned(),
+                endpoint: endpoint.to_owned(),
+            },
+            _ => region,
+        }
+    }
 }
 
 /// An error produced when attempting to convert a `str` into a `Region` fails.
@@ -252,6 +404,8 @@ impl FromStr for Region {
             "us-gov-west-1" | "usgovwest1" => Ok(Region::UsGovWest1),
             "cn-north-1" | "cnnorth1" => Ok(Region::CnNorth1),
             "cn-northwest-1" | "cnnorthwest1" => Ok(Region::CnNorthwest1),
+            "us-iso-east-1" | "usisoeast1" => Ok(Region::UsIsoEast1),
+            "us-isob-east-1" | "usisobeast1" => Ok(Region::UsIsobEast1),
             s => Err(ParseRegionError::new(s)),
         }
     }
@@ -280,13 +434,10 @@ impl Display for ParseRegionError {
 
 impl Default for Region {
     fn default() -> Region {
-        match std::env::var("AWS_DEFAULT_REGION").or_else(|_| std::env::var("AWS_REGION")) {
-            Ok(ref v) => Region::from_str(v).unwrap_or(Region::UsEast1),
-            Err(_) => match ProfileProvider::region() {
-                Ok(Some(region)) => Region::from_str(&region).unwrap_or(Region::UsEast1),
-                _ => Region::UsEast1,
-            },
-        }
+        let region = Region::configured(&SystemEnvironment);
+        #[cfg(feature = "region-detection")]
+        let region = region.or_else(crate::region_detection::detected_region);
+        region.unwrap_or(Region::UsEast1)
     }
 }
 
@@ -295,6 +446,7 @@ mod tests {
     extern crate serde_test;
     use self::serde_test::{assert_tokens, Token};
     use super::*;
+    use crate::credential::VirtualEnvironment;
 
     #[test]
     fn from_str() {
@@ -329,6 +481,8 @@ mod tests {
         assert_eq!("us-gov-west-1".parse(), Ok(Region::UsGovWest1));
         assert_eq!("cn-north-1".parse(), Ok(Region::CnNorth1));
         assert_eq!("cn-northwest-1".parse(), Ok(Region::CnNorthwest1));
+        assert_eq!("us-iso-east-1".parse(), Ok(Region::UsIsoEast1));
+        assert_eq!("us-isob-east-1".parse(), Ok(Region::UsIsobEast1));
     }
 
     #[test]
@@ -355,7 +509,20 @@ mod tests {
         assert_tokens(&Region::UsGovEast1, &tokens_for_region("us-gov-east-1"));
         assert_tokens(&Region::UsGovWest1, &tokens_for_region("us-gov-west-1"));
         assert_tokens(&Region::CnNorth1, &tokens_for_region("cn-north-1"));
-        assert_tokens(&Region::CnNorthwest1, &tokens_for_region("cn-northwest-1"))
+        assert_tokens(&Region::CnNorthwest1, &tokens_for_region("cn-northwest-1"));
+        assert_tokens(&Region::UsIsoEast1, &tokens_for_region("us-iso-east-1"));
+        assert_tokens(&Region::UsIsobEast1, &tokens_for_region("us-isob-east-1"))
+    }
+
+    #[test]
+    fn custom_regions_are_in_the_partition_of_their_name() {
+        let region = |name: &str| Region::Custom {
+            name: name.to_owned(),
+            endpoint: "http://localhost:8000".to_owned(),
+        };
+        assert_eq!(region("cn-north-1").partition().id(), "aws-cn");
+        assert_eq!(region("local").partition().id(), "aws");
+        assert_eq!(region("local").fips("kms"), region("local"));
     }
 
     fn tokens_for_region(name: &'static str) -> [Token; 4] {
@@ -403,6 +570,55 @@ mod tests {
         assert_eq!(r, from_json);
     }
 
+    #[test]
+    fn from_environment_for_service_applies_endpoint_override() {
+        let environment = VirtualEnvironment::new()
+            .with_var("AWS_REGION", "eu-west-1")
+            .with_var(
+                "AWS_ENDPOINT_URL_RUSOTO_REGION_TEST",
+                "http://localhost:4566",
+            );
+        let region = Region::from_environment_for_service("RUSOTO_REGION_TEST", &environment);
+
+        assert_eq!(
+            region,
+            Region::Custom {
+                name: "eu-west-1".to_owned(),
+                endpoint: "http://localhost:4566".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn from_environment_prefers_default_region_variable() {
+        let environment = VirtualEnvironment::new()
+            .with_var("AWS_DEFAULT_REGION", "ap-south-1")
+            .with_var("AWS_REGION", "eu-west-1");
+        assert_eq!(Region::from_environment(&environment), Region::ApSouth1);
+    }
+
+    #[test]
+    fn from_environment_falls_back_to_us_east_1() {
+        let environment = VirtualEnvironment::new().with_var("AWS_REGION", "not-a-region");
+        assert_eq!(Region::from_environment(&environment), Region::UsEast1);
+        assert_eq!(
+            Region::from_environment(&VirtualEnvironment::new()),
+            Region::UsEast1
+        );
+    }
+
+    #[test]
+    fn configured_region_ignores_the_fallback() {
+        let environment = VirtualEnvironment::new()
+            .with_var("AWS_REGION", "eu-west-1")
+            .with_var("AWS_CONFIG_FILE", "/nonexistent/rusoto/config");
+        assert_eq!(Region::configured(&environment), Some(Region::EuWest1));
+        let environment = VirtualEnvironment::new()
+            .with_var("AWS_REGION", "not-a-region")
+            .with_var("AWS_CONFIG_FILE", "/nonexistent/rusoto/config");
+        assert_eq!(Region::configured(&environment), None);
+    }
+
     #[test]
     fn region_serialize_deserialize_standard_only_region_name() {
         let r = Region::UsWest2;
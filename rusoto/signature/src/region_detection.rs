@@ -0,0 +1,97 @@
+//! Detecting the region from the metadata of the ECS task or EC2 instance the process runs in,
+//! for `Region::default()` with the `region-detection` feature.
+
+use std::str::FromStr;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use futures::future::{self, Either};
+use futures::Future;
+use tokio::runtime::current_thread::Runtime;
+
+use crate::credential::{
+    CredentialsError, Environment, InstanceMetadataClient, SystemEnvironment, TaskMetadataClient,
+};
+use crate::region::Region;
+
+/// How long a metadata request may take by default, in seconds, as in the other SDKs.
+const DEFAULT_TIMEOUT_SECS: u64 = 1;
+
+lazy_static! {
+    static ref DETECTED_REGION: Option<Region> = detect_region(&SystemEnvironment);
+}
+
+/// The region of the ECS task or EC2 instance the process runs in, detected on the first call.
+pub(crate) fn detected_region() -> Option<Region> {
+    DETECTED_REGION.clone()
+}
+
+/// Asks the task metadata endpoint if the process runs in an ECS task, and then the instance
+/// metadata service unless `AWS_EC2_METADATA_DISABLED` is `true`.
+///
+/// Each request times out after `AWS_METADATA_SERVICE_TIMEOUT` seconds, 1 by default.
+fn detect_region(environment: &dyn Environment) -> Option<Region> {
+    let timeout = environment
+        .var("AWS_METADATA_SERVICE_TIMEOUT")
+        .and_then(|secs| secs.parse().ok())
+        .map_or(
+            Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            Duration::from_secs,
+        );
+    let mut task = TaskMetadataClient::from_environment(environment);
+    task.set_timeout(timeout);
+    let instance = match environment.var("AWS_EC2_METADATA_DISABLED") {
+        Some(ref disabled) if disabled.eq_ignore_ascii_case("true") => None,
+        _ => {
+            let mut instance = InstanceMetadataClient::new();
+            instance.set_timeout(timeout);
+            Some(instance)
+        }
+    };
+    if !task.is_available() && instance.is_none() {
+        return None;
+    }
+
+    // the requests run on a runtime of their own, so that `Region::default()` can be called
+    // from anywhere, including from a task of another runtime
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let name = Runtime::new()
+            .ok()
+            .and_then(|mut runtime| runtime.block_on(region_name(task, instance)).ok());
+        let _ = sender.send(name);
+    });
+    let name = receiver
+        .recv_timeout(timeout * 3)
+        .ok()
+        .and_then(|name| name)?;
+    Region::from_str(&name).ok()
+}
+
+fn region_name(
+    task: TaskMetadataClient,
+    instance: Option<InstanceMetadataClient>,
+) -> impl Future<Item = String, Error = CredentialsError> {
+    let from_task = if task.is_available() {
+        Either::A(task.region())
+    } else {
+        Either::B(future::err(CredentialsError::new("Not in an ECS task")))
+    };
+    from_task.or_else(move |e| match instance {
+        Some(ref instance) => Either::A(instance.region()),
+        None => Either::B(future::err(e)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::VirtualEnvironment;
+
+    #[test]
+    fn detection_can_be_disabled() {
+        let environment = VirtualEnvironment::new().with_var("AWS_EC2_METADATA_DISABLED", "TRUE");
+        assert_eq!(detect_region(&environment), None);
+    }
+}
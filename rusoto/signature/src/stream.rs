@@ -5,9 +5,19 @@ use bytes::Bytes;
 use futures::{future, stream, Async, Future, Poll, Stream};
 use tokio::io::AsyncRead;
 
+/// Progress of the transfer of a `ByteStream`, as reported to `ByteStream::with_progress`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferProgress {
+    /// Number of bytes transferred so far
+    pub transferred: u64,
+    /// Total number of bytes to transfer, when known
+    pub total: Option<u64>,
+}
+
 /// Stream of bytes.
 pub struct ByteStream {
     size_hint: Option<usize>,
+    buffer: Option<Bytes>,
     inner: Box<dyn Stream<Item = Bytes, Error = io::Error> + Send + 'static>,
 }
 
@@ -19,6 +29,7 @@ impl ByteStream {
     {
         ByteStream {
             size_hint: None,
+            buffer: None,
             inner: Box::new(stream),
         }
     }
@@ -27,6 +38,38 @@ impl ByteStream {
         self.size_hint
     }
 
+    /// Returns the contents of a stream created from a buffer, e.g. to compute a checksum of
+    /// a request body before it is sent.
+    ///
+    /// Returns `None` for streams created with `new`, whose contents are only known once read.
+    pub fn buffered(&self) -> Option<&Bytes> {
+        self.buffer.as_ref()
+    }
+
+    /// Calls `progress` with the number of bytes transferred so far after each chunk of the
+    /// stream is read, e.g. as a request body is sent or a response body is received.
+    ///
+    /// `progress` is called on the event loop driving the transfer, so it should only record the
+    /// progress or hand it off rather than block. The total is the length of streams created from
+    /// a buffer, and unknown for streams created with `new`.
+    pub fn with_progress<F>(self, progress: F) -> ByteStream
+    where
+        F: FnMut(TransferProgress) + Send + 'static,
+    {
+        ByteStream {
+            size_hint: self.size_hint,
+            buffer: self.buffer,
+            inner: Box::new(ProgressStream {
+                inner: self.inner,
+                progress: TransferProgress {
+                    transferred: 0,
+                    total: self.size_hint.map(|size| size as u64),
+                },
+                callback: progress,
+            }),
+        }
+    }
+
     /// Return an implementation of `AsyncRead` that uses async i/o to consume the stream.
     pub fn into_async_read(self) -> impl AsyncRead + Send {
         ImplAsyncRead::new(self.inner)
@@ -40,9 +83,16 @@ impl ByteStream {
 
 impl From<Vec<u8>> for ByteStream {
     fn from(buf: Vec<u8>) -> ByteStream {
+        ByteStream::from(Bytes::from(buf))
+    }
+}
+
+impl From<Bytes> for ByteStream {
+    fn from(buf: Bytes) -> ByteStream {
         ByteStream {
             size_hint: Some(buf.len()),
-            inner: Box::new(stream::once(Ok(Bytes::from(buf)))),
+            buffer: Some(buf.clone()),
+            inner: Box::new(stream::once(Ok(buf))),
         }
     }
 }
@@ -62,6 +112,29 @@ impl Stream for ByteStream {
     }
 }
 
+struct ProgressStream<F> {
+    inner: Box<dyn Stream<Item = Bytes, Error = io::Error> + Send>,
+    progress: TransferProgress,
+    callback: F,
+}
+
+impl<F: FnMut(TransferProgress)> Stream for ProgressStream<F> {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        let chunk = match self.inner.poll()? {
+            Async::Ready(chunk) => chunk,
+            Async::NotReady => return Ok(Async::NotReady),
+        };
+        if let Some(ref chunk) = chunk {
+            self.progress.transferred += chunk.len() as u64;
+            (self.callback)(self.progress);
+        }
+        Ok(Async::Ready(chunk))
+    }
+}
+
 struct ImplAsyncRead {
     buffer: io::Cursor<Bytes>,
     stream: stream::Fuse<Box<dyn Stream<Item = Bytes, Error = io::Error> + Send>>,
@@ -163,3 +236,45 @@ fn test_blocking_read() {
     assert_eq!(&buf[..1], b"8");
     assert_eq!(async_read.read(&mut buf).unwrap(), 0);
 }
+
+#[test]
+fn test_progress() {
+    use std::sync::{Arc, Mutex};
+
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let chunks = vec![Bytes::from_static(b"1234"), Bytes::from_static(b"56")];
+    let sink = reported.clone();
+    let stream = ByteStream::new(stream::iter_ok(chunks))
+        .with_progress(move |progress| sink.lock().unwrap().push(progress));
+    assert_eq!(
+        stream.concat2().wait().unwrap(),
+        Bytes::from_static(b"123456")
+    );
+    assert_eq!(
+        *reported.lock().unwrap(),
+        vec![
+            TransferProgress {
+                transferred: 4,
+                total: None
+            },
+            TransferProgress {
+                transferred: 6,
+                total: None
+            },
+        ]
+    );
+
+    let sink = reported.clone();
+    reported.lock().unwrap().clear();
+    let stream = ByteStream::from(b"123456".to_vec())
+        .with_progress(move |progress| sink.lock().unwrap().push(progress));
+    assert_eq!(ByteStream::buffered(&stream), Some(&Bytes::from_static(b"123456")));
+    stream.concat2().wait().unwrap();
+    assert_eq!(
+        *reported.lock().unwrap(),
+        vec![TransferProgress {
+            transferred: 6,
+            total: Some(6)
+        }]
+    );
+}
@@ -15,6 +15,128 @@ use crate::util;
 
 const BOTOCORE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/botocore/botocore/data/");
 
+/// Botocore's `_retry.json`, listing the errors retried for each service.
+#[derive(Debug, Deserialize)]
+pub struct RetryDefinition {
+    retry: BTreeMap<String, serde_json::Value>,
+}
+
+impl RetryDefinition {
+    pub fn load() -> Result<Self, Box<dyn error::Error>> {
+        let input_file = BufReader::new(File::open(Path::new(BOTOCORE_DIR).join("_retry.json"))?);
+        Ok(serde_json::from_reader(input_file)?)
+    }
+
+    /// The error codes retried for every operation of `service`, with the name of the policy
+    /// retrying them.
+    ///
+    /// Policies referring to the definitions shared by all services, and policies which don't
+    /// apply to an error code, such as CRC32 checks, are left out.
+    pub fn service_error_codes(&self, service: &str) -> Vec<(String, String)> {
+        let policies = self
+            .retry
+            .get(service)
+            .and_then(|service| service.pointer("/__default__/policies"))
+            .and_then(serde_json::Value::as_object);
+        let policies = match policies {
+            Some(policies) => policies,
+            None => return Vec::new(),
+        };
+        policies
+            .iter()
+            .filter_map(|(name, policy)| {
+                policy
+                    .pointer("/applies_when/response/service_error_code")
+                    .and_then(serde_json::Value::as_str)
+                    .map(|code| (name.clone(), code.to_owned()))
+            })
+            .collect()
+    }
+}
+
+/// Botocore's `endpoints.json`, describing the partitions of AWS and the endpoints of each
+/// service in them.
+#[derive(Debug, Deserialize)]
+pub struct EndpointsDefinition {
+    pub partitions: Vec<PartitionDefinition>,
+}
+
+impl EndpointsDefinition {
+    pub fn load() -> Result<Self, Box<dyn error::Error>> {
+        let input_file =
+            BufReader::new(File::open(Path::new(BOTOCORE_DIR).join("endpoints.json"))?);
+        Ok(serde_json::from_reader(input_file)?)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartitionDefinition {
+    pub partition: String,
+    pub partition_name: String,
+    pub dns_suffix: String,
+    pub region_regex: String,
+    #[serde(default)]
+    pub defaults: EndpointDefinition,
+    #[serde(default)]
+    pub regions: BTreeMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub services: BTreeMap<String, PartitionService>,
+}
+
+impl PartitionDefinition {
+    /// The endpoint of `service` for `region` in this partition, with its hostname and signing
+    /// region filled in from the defaults of the service and of the partition.
+    pub fn endpoint(&self, service: &str, region: &str) -> EndpointDefinition {
+        let service_definition = &self.services[service];
+        let endpoint = service_definition.endpoints.get(region);
+        let hostname = endpoint
+            .and_then(|endpoint| endpoint.hostname.as_ref())
+            .or_else(|| service_definition.defaults.hostname.as_ref())
+            .or_else(|| self.defaults.hostname.as_ref())
+            .map(|hostname| {
+                hostname
+                    .replace("{service}", service)
+                    .replace("{region}", region)
+                    .replace("{dnsSuffix}", &self.dns_suffix)
+            });
+        let signing_region = endpoint
+            .and_then(|endpoint| endpoint.credential_scope.as_ref())
+            .or_else(|| service_definition.defaults.credential_scope.as_ref())
+            .and_then(|scope| scope.region.clone())
+            .unwrap_or_else(|| region.to_owned());
+        EndpointDefinition {
+            hostname,
+            credential_scope: Some(CredentialScope {
+                region: Some(signing_region),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartitionService {
+    #[serde(default)]
+    pub defaults: EndpointDefinition,
+    pub is_regionalized: Option<bool>,
+    pub partition_endpoint: Option<String>,
+    #[serde(default)]
+    pub endpoints: BTreeMap<String, EndpointDefinition>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointDefinition {
+    pub hostname: Option<String>,
+    pub credential_scope: Option<CredentialScope>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CredentialScope {
+    pub region: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ServiceDefinition {
     pub documentation: Option<String>,
@@ -38,6 +160,17 @@ impl ServiceDefinition {
         Ok(service)
     }
 
+    /// The API versions of a service with a model in botocore, oldest first.
+    pub fn api_versions(name: &str) -> Result<Vec<String>, Box<dyn error::Error>> {
+        let mut versions = fs::read_dir(Path::new(BOTOCORE_DIR).join(name))?
+            .filter_map(std::result::Result::ok)
+            .filter(|e| e.path().join("service-2.json").is_file())
+            .map(|e| e.file_name().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        versions.sort();
+        Ok(versions)
+    }
+
     pub fn load_all() -> Result<BTreeMap<String, Self>, Box<dyn error::Error>> {
         fs::read_dir(BOTOCORE_DIR)?
             .filter_map(std::result::Result::ok)
@@ -125,17 +258,28 @@ pub struct HttpError {
     pub sender_fault: Option<bool>,
 }
 
+/// Marks an error shape as retryable, and whether it is a throttling error.
+#[derive(Debug, Deserialize)]
+pub struct Retryable {
+    #[serde(default)]
+    pub throttling: bool,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Member {
     pub deprecated: Option<bool>,
     pub documentation: Option<String>,
     pub flattened: Option<bool>,
+    #[serde(rename = "idempotencyToken")]
+    pub idempotency_token: Option<bool>,
     pub location: Option<String>,
     #[serde(rename = "locationName")]
     pub location_name: Option<String>,
     #[serde(deserialize_with = "ShapeName::deserialize_shape_name")]
     pub shape: String,
     pub streaming: Option<bool>,
+    #[serde(rename = "timestampFormat")]
+    pub timestamp_format: Option<String>,
     #[serde(rename = "xmlAttribute")]
     pub xml_attribute: Option<bool>,
     #[serde(rename = "xmlNamespace")]
@@ -150,6 +294,10 @@ impl Member {
     pub fn streaming(&self) -> bool {
         self.streaming.unwrap_or(false)
     }
+
+    pub fn idempotency_token(&self) -> bool {
+        self.idempotency_token.unwrap_or(false)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -216,6 +364,7 @@ pub struct Shape {
     pub pattern: Option<String>,
     pub payload: Option<String>,
     pub required: Option<Vec<String>>,
+    pub retryable: Option<Retryable>,
     #[serde(rename = "enum")]
     pub shape_enum: Option<Vec<String>>,
     #[serde(rename = "type")]
@@ -236,6 +385,11 @@ impl Shape {
         }
     }
 
+    /// Whether the shape is a string with enumerated values, generated as an enum.
+    pub fn is_enum(&self) -> bool {
+        self.shape_type == ShapeType::String && self.shape_enum.is_some()
+    }
+
     pub fn has_query_parameters(&self) -> bool {
         self.members.as_ref().unwrap().iter().any(|(_, member)| {
             if let Some(ref loc) = member.location {
@@ -308,6 +462,10 @@ pub struct Operation {
     pub documentation: Option<String>,
     #[serde(rename = "documentationUrl")]
     pub documentation_url: Option<String>,
+    #[serde(rename = "endpointdiscovery")]
+    pub endpoint_discovery: Option<EndpointDiscovery>,
+    #[serde(rename = "endpointoperation")]
+    pub endpoint_operation: Option<bool>,
     pub errors: Option<BTreeSet<Error>>,
     pub http: HttpRequest,
     pub input: Option<Input>,
@@ -315,6 +473,11 @@ pub struct Operation {
     pub output: Option<Output>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct EndpointDiscovery {
+    pub required: Option<bool>,
+}
+
 impl<'a> Operation {
     pub fn input_shape(&'a self) -> &'a str {
         &self
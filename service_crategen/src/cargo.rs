@@ -16,6 +16,8 @@ pub struct Manifest {
     #[serde(serialize_with = "toml::ser::tables_last")]
     pub dev_dependencies: BTreeMap<String, Dependency>,
     pub features: Option<BTreeMap<String, Vec<String>>>,
+    #[serde(rename = "example")]
+    pub examples: Option<Vec<Example>>,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -34,6 +36,13 @@ pub struct Metadata {
     pub exclude: Option<Vec<String>>,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Example {
+    pub name: String,
+    #[serde(rename = "required-features")]
+    pub required_features: Vec<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Badge {
     pub repository: String,
@@ -53,3 +62,12 @@ pub enum Dependency {
         features: Option<Vec<String>>,
     },
 }
+
+impl Dependency {
+    pub fn is_optional(&self) -> bool {
+        match *self {
+            Dependency::Extended { optional, .. } => optional.unwrap_or(false),
+            Dependency::Simple(_) => false,
+        }
+    }
+}
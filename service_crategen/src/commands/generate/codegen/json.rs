@@ -1,24 +1,25 @@
 use inflector::Inflector;
 use std::io::Write;
 
-use super::{error_type_name, FileWriter, GenerateProtocol, IoResult};
+use super::{error_type_name, generate_operation_example, FileWriter, GenerateProtocol, IoResult};
 use crate::botocore::Operation;
 use crate::Service;
 
 pub struct JsonGenerator;
 
 impl GenerateProtocol for JsonGenerator {
-    fn generate_method_signatures(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+    fn generate_method_signatures(&self, writer: &mut dyn Write, service: &Service<'_>) -> IoResult {
         for (operation_name, operation) in service.operations().iter() {
             let output_type = operation.output_shape_or("()");
 
             writeln!(
                 writer,
                 "
-                {documentation}
+                {documentation}{example}
                 {method_signature} -> RusotoFuture<{output_type}, {error_type}>;
                 ",
                 documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
+                example = generate_operation_example(service, operation, self),
                 method_signature = generate_method_signature(service, operation),
                 error_type = error_type_name(service, operation_name),
                 output_type = output_type
@@ -41,7 +42,7 @@ impl GenerateProtocol for JsonGenerator {
                     request.add_header(\"x-amz-target\", \"{target_prefix}.{name}\");
                     {payload}
 
-                    self.client.sign_and_dispatch(request, |response| {{
+                    {dispatch}|response| {{
                         if response.status.is_success() {{
                             {ok_response}
                         }} else {{
@@ -55,6 +56,7 @@ impl GenerateProtocol for JsonGenerator {
                      documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
                      method_signature = generate_method_signature(service, operation),
                      payload = generate_payload(service, operation),
+                     dispatch = generate_dispatch(service, operation),
                      signing_name = service.signing_name(),
                      modify_endpoint_prefix = generate_endpoint_modification(service)
                          .unwrap_or_else(|| "".to_owned()),
@@ -75,7 +77,8 @@ impl GenerateProtocol for JsonGenerator {
             writer,
             "
         use rusoto_core::proto;
-        use rusoto_core::signature::SignedRequest;"
+        use rusoto_core::signature::SignedRequest;
+        use rusoto_core::timestamp::EpochTimestamp;"
         );
         if service.needs_serde_json_crate() {
             return writeln!(writer, "use serde_json;");
@@ -92,7 +95,7 @@ impl GenerateProtocol for JsonGenerator {
     }
 
     fn timestamp_type(&self) -> &'static str {
-        "f64"
+        "EpochTimestamp"
     }
 }
 
@@ -107,15 +110,51 @@ fn generate_endpoint_modification(service: &Service<'_>) -> Option<String> {
     }
 }
 
-fn generate_method_signature(service: &Service<'_>, operation: &Operation) -> String {
-    if operation.input.is_some()
+/// Whether the operation's method takes an input struct.
+fn takes_input(service: &Service<'_>, operation: &Operation) -> bool {
+    operation.input.is_some()
         && service
             .get_shape(operation.input_shape())
             .as_ref()
             .and_then(|s| s.members.as_ref())
             .map(|m| !m.is_empty())
             .unwrap_or(false)
-    {
+}
+
+/// Starts the call dispatching the request, to a discovered endpoint if the operation uses
+/// endpoint discovery.
+fn generate_dispatch(service: &Service<'_>, operation: &Operation) -> String {
+    let (discovery, endpoint_operation) = match (
+        operation.endpoint_discovery.as_ref(),
+        service.endpoint_operation(),
+    ) {
+        (Some(discovery), Some(endpoint_operation)) => (discovery, endpoint_operation),
+        _ => return "self.client.sign_and_dispatch(request, ".to_owned(),
+    };
+    format!(
+        "let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new({required}, move || {{
+            Box::new(discovery_client.{method_name}({input})
+                .map(|response| {{
+                    response.endpoints.into_iter().map(|endpoint| {{
+                        rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(endpoint.address, endpoint.cache_period_in_minutes)
+                    }}).collect()
+                }})
+                .map_err(|err| err.to_string()))
+        }});
+        self.client.sign_and_dispatch_with_discovery(request, discovery, ",
+        required = discovery.required.unwrap_or(false),
+        method_name = endpoint_operation.name.to_snake_case(),
+        input = if takes_input(service, endpoint_operation) {
+            "Default::default()"
+        } else {
+            ""
+        }
+    )
+}
+
+fn generate_method_signature(service: &Service<'_>, operation: &Operation) -> String {
+    if takes_input(service, operation) {
         format!(
             "fn {method_name}(&self, input: {input_type}) ",
             input_type = operation.input_shape(),
@@ -130,14 +169,7 @@ fn generate_method_signature(service: &Service<'_>, operation: &Operation) -> St
 }
 
 fn generate_payload(service: &Service<'_>, operation: &Operation) -> String {
-    if operation.input.is_some()
-        && service
-            .get_shape(operation.input_shape())
-            .as_ref()
-            .and_then(|s| s.members.as_ref())
-            .map(|m| !m.is_empty())
-            .unwrap_or(false)
-    {
+    if takes_input(service, operation) {
         "let encoded = serde_json::to_string(&input).unwrap();
          request.set_payload(Some(encoded));
          "
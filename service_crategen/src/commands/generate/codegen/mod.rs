@@ -3,6 +3,7 @@ use std::fs::File;
 use std::io::{BufWriter, Write};
 
 use inflector::Inflector;
+use regex::Regex;
 
 use self::error_types::{GenerateErrorTypes, JsonErrorTypes, RestJsonErrorTypes, XmlErrorTypes};
 use self::json::JsonGenerator;
@@ -11,7 +12,7 @@ use self::rest_json::RestJsonGenerator;
 use self::rest_xml::RestXmlGenerator;
 use self::tests::generate_tests;
 use self::type_filter::filter_types;
-use crate::botocore::{Member, Shape, ShapeType};
+use crate::botocore::{Member, Operation, RetryDefinition, Shape, ShapeType};
 use crate::util;
 use crate::Service;
 
@@ -34,11 +35,9 @@ pub trait GenerateProtocol {
     /// Generate the various `use` statements required by the module generatedfor this service
     fn generate_prelude(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult;
 
-    fn generate_method_signatures(
-        &self,
-        writer: &mut FileWriter,
-        service: &Service<'_>,
-    ) -> IoResult;
+    /// Generate the signature of each operation's method in the service trait
+    fn generate_method_signatures(&self, writer: &mut dyn Write, service: &Service<'_>)
+        -> IoResult;
 
     /// Generate a method for each `Operation` in the `Service` to execute that method remotely
     ///
@@ -94,6 +93,369 @@ pub fn generate_source(service: &Service<'_>, writer: &mut FileWriter) -> IoResu
     }
 }
 
+/// Generates `src/fake.rs`, an in-process fake of the service for unit tests.
+///
+/// The fake implements the service trait by calling a `{Trait}Backend`, whose methods return the
+/// output of each operation directly. Every backend method fails by default, so hand-written
+/// backends in the crate's `custom` module only implement the operations they support.
+pub fn generate_fake_source(service: &Service<'_>, writer: &mut FileWriter) -> IoResult {
+    let mut signatures = Vec::new();
+    match service.protocol() {
+        "json" => JsonGenerator.generate_method_signatures(&mut signatures, service)?,
+        "query" | "ec2" => QueryGenerator.generate_method_signatures(&mut signatures, service)?,
+        "rest-json" => RestJsonGenerator.generate_method_signatures(&mut signatures, service)?,
+        "rest-xml" => RestXmlGenerator.generate_method_signatures(&mut signatures, service)?,
+        protocol => panic!("Unknown protocol {}", protocol),
+    }
+    let code: String = String::from_utf8_lossy(&signatures)
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("///"))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let trait_name = service.service_type_name();
+    writeln!(
+        writer,
+        "// =================================================================
+        //
+        //                           * WARNING *
+        //
+        //                    This file is generated!
+        //
+        //  Changes made to this file will be overwritten. If changes are
+        //  required to the generated code, the service_crategen project
+        //  must be updated to generate the changes.
+        //
+        // =================================================================
+
+        //! An in-process fake of the {service_name} API for unit tests.
+        //!
+        //! `Fake{trait_name}` implements `{trait_name}` by calling a `{trait_name}Backend`, which holds the
+        //! state of the fake. Backend methods fail with `RusotoError::Validation` unless they are
+        //! implemented, so a backend only needs to implement the operations a test calls.
+
+        use std::sync::Arc;
+
+        use futures::future;
+        use rusoto_core::{{RusotoError, RusotoFuture}};
+
+        use crate::generated::*;
+
+        /// The error returned by operations a backend doesn't implement.
+        pub fn unsupported_operation<E>(operation: &str) -> RusotoError<E> {{
+            RusotoError::Validation(format!(\"{{}} is not supported by this fake\", operation))
+        }}
+
+        /// The state and behaviour of a `Fake{trait_name}`.
+        pub trait {trait_name}Backend: Send + Sync {{",
+        service_name = service.name(),
+        trait_name = trait_name,
+    )?;
+
+    let mut methods = Vec::new();
+    for signature in code.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+        let method_name = signature["fn ".len()..]
+            .split('(')
+            .next()
+            .expect("method signature without arguments")
+            .trim()
+            .to_owned();
+        let operation = service
+            .operations()
+            .values()
+            .find(|operation| operation.name.to_snake_case() == method_name)
+            .expect("method without operation");
+        let return_start = signature
+            .rfind("-> RusotoFuture<")
+            .expect("method without RusotoFuture");
+        let return_types =
+            &signature[return_start + "-> RusotoFuture<".len()..signature.len() - ">".len()];
+        let (output_type, error_type) =
+            return_types.split_at(return_types.rfind(", ").expect("RusotoFuture without error type"));
+        let takes_input = signature.contains("input:");
+        let backend_signature = format!(
+            "{}-> Result<{}, RusotoError<{}>>",
+            &signature[..return_start],
+            output_type,
+            &error_type[", ".len()..]
+        );
+
+        writeln!(
+            writer,
+            "{signature} {{
+                Err(unsupported_operation(\"{operation}\"))
+            }}",
+            signature = backend_signature.replace("input:", "_input:"),
+            operation = operation.name,
+        )?;
+        methods.push((signature.to_owned(), method_name, takes_input));
+    }
+
+    writeln!(
+        writer,
+        "}}
+
+        /// An implementation of `{trait_name}` calling a `{trait_name}Backend` instead of AWS.
+        ///
+        /// Operations are run by the backend when the returned futures are polled. Clones of the fake
+        /// share the same backend.
+        pub struct Fake{trait_name}<B> {{
+            backend: Arc<B>,
+        }}
+
+        impl<B> Clone for Fake{trait_name}<B> {{
+            fn clone(&self) -> Self {{
+                Fake{trait_name} {{
+                    backend: self.backend.clone(),
+                }}
+            }}
+        }}
+
+        impl<B: {trait_name}Backend> Fake{trait_name}<B> {{
+            /// Creates a fake calling `backend`.
+            pub fn with_backend(backend: B) -> Fake{trait_name}<B> {{
+                Fake{trait_name} {{
+                    backend: Arc::new(backend),
+                }}
+            }}
+
+            /// The backend of the fake, to set up or inspect its state.
+            pub fn backend(&self) -> &B {{
+                &self.backend
+            }}
+        }}
+
+        impl<B: {trait_name}Backend + 'static> {trait_name} for Fake{trait_name}<B> {{",
+        trait_name = trait_name,
+    )?;
+
+    for (signature, method_name, takes_input) in methods {
+        writeln!(
+            writer,
+            "{signature} {{
+                let backend = self.backend.clone();
+                RusotoFuture::from_future(future::lazy(move || backend.{method_name}({argument})))
+            }}",
+            signature = signature,
+            method_name = method_name,
+            argument = if takes_input { "input" } else { "" },
+        )?;
+    }
+
+    writeln!(writer, "}}")
+}
+
+/// Generates `examples/smoke.rs`, which makes a harmless read-only call so that users can check
+/// the credentials, region and endpoint they have configured for the service.
+///
+/// The call is the first `List`, `Describe` or `Get` operation, in that order of preference,
+/// without required parameters. Services without such an operation get no example.
+pub fn generate_smoke_example(service: &Service<'_>, crate_name: &str) -> Option<String> {
+    let takes_no_parameters = |operation: &Operation| match operation.input {
+        None => true,
+        Some(ref input) => service
+            .get_shape(&input.shape)
+            .map_or(false, |shape| shape.required.as_ref().map_or(true, Vec::is_empty)),
+    };
+    let operation = ["list_", "describe_", "get_"].iter().find_map(|prefix| {
+        service.operations().values().find(|operation| {
+            operation.name.to_snake_case().starts_with(prefix) && takes_no_parameters(operation)
+        })
+    })?;
+
+    let method_name = operation.name.to_snake_case();
+    let (imports, call) = match operation.input {
+        Some(ref input) => {
+            let input_type = mutate_type_name(service, &input.shape);
+            (
+                format!("{}, {}, {}", service.service_type_name(), service.client_type_name(), input_type),
+                format!("client.{}({}::default())", method_name, input_type),
+            )
+        }
+        None => (
+            format!("{}, {}", service.service_type_name(), service.client_type_name()),
+            format!("client.{}()", method_name),
+        ),
+    };
+
+    Some(format!(
+        "// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of {service_name} clients.
+//!
+//! Makes a single read-only `{operation}` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use {crate_name}::{{{imports}}};
+
+fn main() {{
+    let client = {client}::from_env();
+    match {call}.sync() {{
+        Ok(_) => println!(\"{operation} succeeded\"),
+        Err(error) => {{
+            eprintln!(\"{operation} failed: {{}}\", error);
+            process::exit(1);
+        }}
+    }}
+}}
+",
+        service_name = service.full_name(),
+        operation = operation.name,
+        crate_name = crate_name,
+        imports = imports,
+        client = service.client_type_name(),
+        call = call,
+    ))
+}
+
+/// Generates the `# Example` section of the documentation of an operation in the service trait,
+/// for the services configured with `operationExamples`.
+///
+/// The example creates a client, builds the input of the operation with each of its required
+/// members set, and makes the call. It's compiled by the crate's doc tests but isn't run.
+/// Operations with a required member which has no simple value, such as a timestamp or a
+/// streaming body, aren't given an example.
+pub fn generate_operation_example<P: GenerateProtocol>(
+    service: &Service<'_>,
+    operation: &Operation,
+    protocol_generator: &P,
+) -> String {
+    if !service.operation_examples() {
+        return String::new();
+    }
+
+    let method_name = operation.name.to_snake_case();
+    let mut imports = vec![
+        service.service_type_name().to_owned(),
+        service.client_type_name(),
+    ];
+    let mut lines = vec![format!(
+        "let client = {}::new(Region::UsEast1);",
+        service.client_type_name()
+    )];
+
+    let call = match operation.input {
+        Some(ref input) => {
+            let input_type = mutate_type_name(service, &input.shape);
+            let shape = service.get_shape(&input.shape).expect("input shape missing");
+            let mut setters = Vec::new();
+            let shape_members = shape.members.iter().flat_map(|members| members.iter());
+            for (member_name, member) in shape_members {
+                if member.deprecated == Some(true) || !shape.required(member_name) {
+                    continue;
+                }
+                let (field, field_type) = struct_field(
+                    service,
+                    shape,
+                    &input_type,
+                    member_name,
+                    member,
+                    protocol_generator,
+                );
+                let member_shape = service.shape_for_member(member).unwrap();
+                let value = match example_value(&field, &field_type, member, member_shape) {
+                    Some(value) => value,
+                    None => return String::new(),
+                };
+                if member_shape.is_enum() || member_shape.shape_type == ShapeType::Structure {
+                    imports.push(field_type);
+                }
+                setters.push(format!("    .{}({})", field, value));
+            }
+
+            if setters.is_empty() {
+                lines.push(format!("let request = {}::default();", input_type));
+            } else {
+                lines.push(format!("let request = {}::builder()", input_type));
+                lines.extend(setters);
+                lines.push("    .build()".to_owned());
+                lines.push("    .unwrap();".to_owned());
+            }
+            imports.push(input_type);
+            format!("client.{}(request)", method_name)
+        }
+        None => format!("client.{}()", method_name),
+    };
+
+    lines.push(format!("match {}.sync() {{", call));
+    lines.push("    Ok(output) => println!(\"{:?}\", output),".to_owned());
+    lines.push(format!(
+        "    Err(error) => eprintln!(\"{} failed: {{}}\", error),",
+        operation.name
+    ));
+    lines.push("}".to_owned());
+
+    imports.sort();
+    imports.dedup();
+    format!(
+        "
+        ///
+        /// # Example
+        ///
+        /// ```rust,no_run
+        /// use rusoto_core::Region;
+        /// use {crate_name}::{{{imports}}};
+        ///
+        {lines}
+        /// ```",
+        crate_name = service.crate_name(),
+        imports = imports.join(", "),
+        lines = lines
+            .iter()
+            .map(|line| format!("/// {}", line))
+            .collect::<Vec<String>>()
+            .join("\n"),
+    )
+}
+
+/// A value for a required member in an operation example, passed to the member's setter on the
+/// input's builder.
+fn example_value(
+    field: &str,
+    field_type: &str,
+    member: &Member,
+    member_shape: &Shape,
+) -> Option<String> {
+    if member.streaming() {
+        return None;
+    }
+    match member_shape.shape_type {
+        ShapeType::String if member_shape.is_enum() => {
+            let value = &member_shape.shape_enum.as_ref().unwrap()[0];
+            Some(format!("{}::{}", field_type, enum_variant_name(value)))
+        }
+        ShapeType::String => Some(format!("{:?}", field)),
+        ShapeType::Boolean => Some("false".to_owned()),
+        ShapeType::Integer | ShapeType::Long => Some("0".to_owned()),
+        ShapeType::Double => Some("0.0".to_owned()),
+        ShapeType::Float => Some("0.0f32".to_owned()),
+        ShapeType::Blob => Some(format!("b{:?}.to_vec()", field)),
+        ShapeType::List => Some("Vec::new()".to_owned()),
+        ShapeType::Map => Some("::std::collections::HashMap::new()".to_owned()),
+        // self-referencing members are boxed
+        ShapeType::Structure if !field_type.starts_with("Box<") => {
+            Some(format!("{}::default()", field_type))
+        }
+        ShapeType::Structure | ShapeType::Timestamp => None,
+    }
+}
+
 /// Translate a botocore field name to something rust-idiomatic and
 /// escape reserved words with an underscore
 pub fn generate_field_name(member_name: &str) -> String {
@@ -170,12 +532,22 @@ where
              trait_name = service.service_type_name(),
              service_name = service.name())?;
 
-    protocol_generator.generate_method_signatures(writer, service)?;
+    let mut signatures = Vec::new();
+    protocol_generator.generate_method_signatures(&mut signatures, service)?;
+    writer.write_all(&signatures)?;
 
     writeln!(writer, "}}")?;
 
+    generate_shared_impl(
+        writer,
+        &service.service_type_name(),
+        &String::from_utf8_lossy(&signatures),
+    )?;
+
+    generate_retry_codes(writer, service)?;
+
     writeln!(writer,
-        "/// A client for the {service_name} API.
+        r#"/// A client for the {service_name} API.
         #[derive(Clone)]
         pub struct {type_name} {{
             client: Client,
@@ -190,6 +562,24 @@ where
                 Self::new_with_client(Client::shared(), region)
             }}
 
+            /// Creates a client with its region, credentials and endpoint resolved from the environment.
+            ///
+            /// The region and credentials are resolved as for `Region::default()` and the default
+            /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_{env_name}`
+            /// or `AWS_ENDPOINT_URL` environment variables.
+            pub fn from_env() -> {type_name} {{
+                Self::new(region::Region::from_env_for_service("{env_name}"))
+            }}
+
+            /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+            /// `config`.
+            ///
+            /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+            /// sets none, at the endpoint its endpoint resolver returns for `{endpoint_prefix}`, if any.
+            pub fn from_config(config: &rusoto_core::config::Config) -> {type_name} {{
+                Self::new_with_client(config.client().clone(), config.region_for("{endpoint_prefix}", "{env_name}"))
+            }}
+
             pub fn new_with<P, D>(request_dispatcher: D, credentials_provider: P, region: region::Region) -> {type_name}
                 where P: ProvideAwsCredentials + Send + Sync + 'static,
                       P::Future: Send,
@@ -202,22 +592,179 @@ where
             pub fn new_with_client(client: Client, region: region::Region) -> {type_name}
             {{
                 {type_name} {{
-                    client,
+                    client: client.with_retry_codes(RETRY_CODES),
                     region
                 }}
             }}
+
+            /// Returns the scheme and host this client sends its requests to.
+            pub fn regional_endpoint(&self) -> String {{
+                rusoto_core::config::regional_endpoint(&self.region, "{endpoint_prefix}")
+            }}
+
+            /// Returns the configuration this client sends requests with, and where each value came from.
+            pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {{
+                rusoto_core::config::EffectiveConfig::resolve(&self.region, "{endpoint_prefix}", "{signing_name}", "{env_name}")
+                    .with_client(&self.client)
+            }}
+
+            /// Moves the client behind a shared `{trait_name}` trait object, so it can be
+            /// passed around interchangeably with other implementations such as mocks.
+            pub fn into_shared(self) -> ::std::sync::Arc<dyn {trait_name} + Send + Sync> {{
+                ::std::sync::Arc::new(self)
+            }}
+        }}
+
+        impl rusoto_core::AwsService for {type_name} {{
+            fn service_name(&self) -> &'static str {{
+                {service_name:?}
+            }}
+
+            fn endpoint_prefix(&self) -> &'static str {{
+                "{endpoint_prefix}"
+            }}
+
+            fn region(&self) -> &region::Region {{
+                &self.region
+            }}
+
+            fn client(&self) -> &Client {{
+                &self.client
+            }}
+
+            fn from_client(client: Client, region: region::Region) -> {type_name} {{
+                Self::new_with_client(client, region)
+            }}
         }}
 
         impl {trait_name} for {type_name} {{
-        ",
+        "#,
         service_name = service.name(),
         type_name = service.client_type_name(),
         trait_name = service.service_type_name(),
+        env_name = service.env_name(),
+        endpoint_prefix = service.endpoint_prefix(),
+        // query and rest-xml requests are signed with the endpoint prefix
+        signing_name = match service.protocol() {
+            "json" | "rest-json" => service.signing_name(),
+            _ => service.endpoint_prefix().to_owned(),
+        },
     )?;
     protocol_generator.generate_method_impls(writer, service)?;
     writeln!(writer, "}}")
 }
 
+/// Generate the error codes the service retries beyond the ones every service retries: the
+/// errors its model marks as retryable or returns with a `429` status, and the ones its
+/// policies in botocore's `retry.json` retry.
+fn generate_retry_codes(writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+    let mut throttling = BTreeSet::new();
+    let mut transient = BTreeSet::new();
+
+    for (name, shape) in service.shapes() {
+        if !shape.exception() {
+            continue;
+        }
+        let code = shape
+            .error
+            .as_ref()
+            .and_then(|error| error.code.clone())
+            .unwrap_or_else(|| name.clone());
+        let too_many_requests = shape
+            .error
+            .as_ref()
+            .map_or(false, |error| error.http_status_code == 429);
+        match shape.retryable {
+            Some(ref retryable) if retryable.throttling => throttling.insert(code),
+            Some(_) => transient.insert(code),
+            None if too_many_requests => throttling.insert(code),
+            None => false,
+        };
+    }
+
+    let retry = RetryDefinition::load().expect(
+        "Failed to load _retry.json. Make sure the botocore submodule has been initialized!",
+    );
+    for (policy, code) in retry.service_error_codes(service.key()) {
+        if is_throttling_policy(&policy) {
+            throttling.insert(code);
+        } else {
+            transient.insert(code);
+        }
+    }
+
+    let codes = |codes: BTreeSet<String>| {
+        codes
+            .iter()
+            .map(|code| format!("{:?}", code))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    writeln!(
+        writer,
+        "/// The error codes {service_name} retries, in addition to the ones retried for every service.
+        pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes = rusoto_core::retry::ServiceRetryCodes {{
+            throttling: &[{throttling}],
+            transient: &[{transient}],
+        }};
+        ",
+        service_name = service.name(),
+        throttling = codes(throttling),
+        transient = codes(transient),
+    )
+}
+
+/// Whether a `retry.json` policy retries throttling errors, judging by its name, e.g.
+/// `request_limit_exceeded` or `throughput_exceeded`.
+fn is_throttling_policy(name: &str) -> bool {
+    const MARKERS: &[&str] = &[
+        "throttl",
+        "limit_exceeded",
+        "throughput_exceeded",
+        "too_many_requests",
+    ];
+    MARKERS.iter().any(|marker| name.contains(marker))
+}
+
+/// Implement the service trait for `Arc<T>` by forwarding each method, so that an
+/// `Arc<dyn Trait + Send + Sync>` can be used wherever a client is expected.
+fn generate_shared_impl(writer: &mut FileWriter, trait_name: &str, signatures: &str) -> IoResult {
+    writeln!(
+        writer,
+        "impl<T: ?Sized + {trait_name}> {trait_name} for ::std::sync::Arc<T> {{",
+        trait_name = trait_name
+    )?;
+
+    let code: String = signatures
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("///"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    for signature in code.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+        let method_name = signature["fn ".len()..]
+            .split('(')
+            .next()
+            .expect("method signature without arguments")
+            .trim();
+        let argument = if signature.contains("input:") {
+            "input"
+        } else {
+            ""
+        };
+        writeln!(
+            writer,
+            "{signature} {{
+                (**self).{method_name}({argument})
+            }}",
+            signature = signature,
+            method_name = method_name,
+            argument = argument
+        )?;
+    }
+
+    writeln!(writer, "}}")
+}
+
 pub fn get_rust_type(
     service: &Service<'_>,
     shape_name: &str,
@@ -232,6 +779,7 @@ pub fn get_rust_type(
             ShapeType::Double => "f64".into(),
             ShapeType::Float => "f32".into(),
             ShapeType::Integer | ShapeType::Long => "i64".into(),
+            ShapeType::String if shape.is_enum() => mutate_type_name(service, shape_name),
             ShapeType::String => "String".into(),
             ShapeType::Timestamp => for_timestamps.into(),
             ShapeType::List => format!(
@@ -394,9 +942,19 @@ where
                     protocol_generator,
                 );
                 writeln!(writer, "{}", generated)?;
+
+                if is_input_shape(service, name) {
+                    let builder = generate_builder(service, &type_name, shape, protocol_generator);
+                    writeln!(writer, "{}", builder)?;
+                }
             }
         }
 
+        if shape.is_enum() {
+            let generated = generate_enum(service, name, &type_name, shape, protocol_generator);
+            writeln!(writer, "{}", generated)?;
+        }
+
         if streaming {
             // Add a second type for streaming blobs, which are the only streaming type we can have
             writeln!(
@@ -427,6 +985,209 @@ where
     Ok(())
 }
 
+/// Turns an enumerated value such as `in-progress`, `IN_PROGRESS` or `t2.micro` into a variant
+/// name such as `InProgress` or `T2Micro`.
+fn enum_variant_name(value: &str) -> String {
+    let mut name = String::new();
+    for word in value.split(|c: char| !c.is_ascii_alphanumeric()) {
+        if word.chars().any(|c| c.is_ascii_lowercase()) {
+            name.push_str(&util::capitalize_first(word));
+        } else {
+            name.push_str(&util::capitalize_first(word.to_ascii_lowercase()));
+        }
+    }
+    match &name[..] {
+        "" => "Empty".to_owned(),
+        // these would collide with the variant for unknown values, and with the `Self` keyword
+        "Unknown" | "Self" => format!("{}Value", name),
+        _ if name.starts_with(|c: char| c.is_ascii_digit()) => format!("V{}", name),
+        _ => name,
+    }
+}
+
+/// The documentation of the structure members whose values are of the string shape
+/// `shape_name`, directly or in a list or a map.
+fn enum_member_docs<'a>(service: &'a Service<'_>, shape_name: &str) -> Vec<&'a String> {
+    service
+        .shapes()
+        .values()
+        .filter_map(|shape| shape.members.as_ref())
+        .flat_map(|members| members.values())
+        .filter(|member| {
+            let member_shape = service.shape_for_member(member).unwrap();
+            member.shape == shape_name
+                || member_shape.member.as_ref().map(|m| &m.shape[..]) == Some(shape_name)
+                || member_shape.key.as_ref().map(|k| &k.shape[..]) == Some(shape_name)
+                || member_shape.value.as_ref().map(|v| &v.shape[..]) == Some(shape_name)
+        })
+        .filter_map(|member| member.documentation.as_ref())
+        .collect()
+}
+
+/// Finds the description of an enumerated value in documentation listing the values, such as
+/// `<li><p><code>CREATING</code> - The table is being created.</p></li>`.
+fn enum_value_description(value: &str, docs: &[&String]) -> Option<String> {
+    let pattern = Regex::new(&format!(
+        r"<code>\s*{}\s*</code>\s*(?:-|:)\s*(.+?)\s*</p>",
+        regex::escape(value)
+    ))
+    .unwrap();
+    docs.iter()
+        .filter_map(|docs| pattern.captures(docs))
+        .map(|captures| captures[1].to_owned())
+        .next()
+}
+
+/// Generates an enum for a string shape with enumerated values.
+///
+/// Values added to the API after the crate was generated are kept as `Unknown`. The enum is
+/// documented with the shape's documentation, or else with the documentation of the first member
+/// using it, and the variants with the descriptions of their values found there. There's no
+/// default value, so that required members of the shape are always set explicitly.
+fn generate_enum<P: GenerateProtocol>(
+    service: &Service<'_>,
+    shape_name: &str,
+    name: &str,
+    shape: &Shape,
+    protocol_generator: &P,
+) -> String {
+    let member_docs = enum_member_docs(service, shape_name);
+    let mut docs: Vec<&String> = shape.documentation.iter().collect();
+    docs.extend(member_docs);
+
+    let mut variants: Vec<(String, &str)> = Vec::new();
+    for value in shape.shape_enum.as_ref().unwrap() {
+        let mut variant = enum_variant_name(value);
+        // values only differing in case or punctuation map to the same variant name
+        let mut suffix = 2;
+        while variants.iter().any(|&(ref existing, _)| *existing == variant) {
+            variant = format!("{}{}", enum_variant_name(value), suffix);
+            suffix += 1;
+        }
+        variants.push((variant, value));
+    }
+
+    let declarations = variants
+        .iter()
+        .map(|&(ref variant, value)| {
+            let variant_docs = match enum_value_description(value, &docs) {
+                Some(description) => format!("<code>{}</code> - {}", value, description),
+                None => format!("<code>{}</code>", value),
+            };
+            format!("{}\n{},", crate::doco::Item(variant_docs), variant)
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let as_str = variants
+        .iter()
+        .map(|&(ref variant, value)| format!("{}::{} => {:?},", name, variant, value))
+        .collect::<Vec<String>>()
+        .join("\n");
+    let from_str = variants
+        .iter()
+        .map(|&(ref variant, value)| format!("{:?} => {}::{},", value, name, variant))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let mut conversions = Vec::new();
+    // query parameters, and every member of query requests, are sent with `ToParam`
+    if service.protocol() != "json" {
+        conversions.push(format!(
+            "impl ::rusoto_core::param::ToParam for {name} {{
+                fn to_param(&self) -> String {{
+                    self.as_str().to_owned()
+                }}
+            }}",
+            name = name,
+        ));
+    }
+    if protocol_generator.serialize_trait().is_some() {
+        conversions.push(format!(
+            "impl ::serde::Serialize for {name} {{
+                fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                where
+                    S: ::serde::Serializer,
+                {{
+                    serializer.serialize_str(self.as_str())
+                }}
+            }}
+
+            impl<'de> ::serde::Deserialize<'de> for {name} {{
+                fn deserialize<D>(deserializer: D) -> ::std::result::Result<{name}, D::Error>
+                where
+                    D: ::serde::Deserializer<'de>,
+                {{
+                    let value = String::deserialize(deserializer)?;
+                    Ok({name}::from(value))
+                }}
+            }}",
+            name = name,
+        ));
+    }
+
+    format!(
+        "{docs}
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum {name} {{
+            {declarations}
+            /// A value which wasn't known when this crate was generated
+            Unknown(String),
+        }}
+
+        impl {name} {{
+            /// The value as sent to and received from AWS.
+            pub fn as_str(&self) -> &str {{
+                match *self {{
+                    {as_str}
+                    {name}::Unknown(ref value) => value,
+                }}
+            }}
+        }}
+
+        impl<'a> From<&'a str> for {name} {{
+            fn from(value: &'a str) -> {name} {{
+                match value {{
+                    {from_str}
+                    _ => {name}::Unknown(value.to_owned()),
+                }}
+            }}
+        }}
+
+        impl From<String> for {name} {{
+            fn from(value: String) -> {name} {{
+                {name}::from(value.as_str())
+            }}
+        }}
+
+        impl From<{name}> for String {{
+            fn from(value: {name}) -> String {{
+                match value {{
+                    {name}::Unknown(value) => value,
+                    value => value.as_str().to_owned(),
+                }}
+            }}
+        }}
+
+        impl fmt::Display for {name} {{
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{
+                write!(f, \"{{}}\", self.as_str())
+            }}
+        }}
+
+        {conversions}
+        ",
+        docs = docs
+            .first()
+            .map(|docs| crate::doco::Item(docs).to_string())
+            .unwrap_or_default(),
+        name = name,
+        declarations = declarations,
+        as_str = as_str,
+        from_str = from_str,
+        conversions = conversions.join("\n\n"),
+    )
+}
+
 fn generate_struct<P>(
     service: &Service<'_>,
     name: &str,
@@ -439,7 +1200,10 @@ fn generate_struct<P>(
 where
     P: GenerateProtocol,
 {
-    let mut derived = vec!["Default", "Debug"];
+    let mut derived = vec!["Debug"];
+    if derives_default(service, name, shape) {
+        derived.insert(0, "Default");
+    }
 
     // Streaming is implemented with Box<Stream<...>>, so we can't derive Clone nor PartialEq.
     // This affects both the streaming struct itself, and structs which contain it.
@@ -516,6 +1280,8 @@ fn generate_struct_fields<P: GenerateProtocol>(
             lines.push(crate::doco::Item(docs).to_string());
         }
 
+        let (name, field_type) = struct_field(service, shape, shape_name, member_name, member, protocol_generator);
+
         if serde_attrs {
             lines.push(format!("#[serde(rename=\"{}\")]", member_name));
 
@@ -528,6 +1294,17 @@ fn generate_struct_fields<P: GenerateProtocol>(
                             default,
                         )]".to_owned()
                     );
+                } else if member_shape.shape_type == ShapeType::Timestamp {
+                    // timestamps are sent as seconds since the epoch unless the model says otherwise
+                    let format = match service.timestamp_format_for_member(member) {
+                        Some("iso8601") => "iso8601",
+                        _ => "epoch_seconds",
+                    };
+                    if field_type.starts_with("Option<") {
+                        lines.push(format!("#[serde(default, with=\"::rusoto_core::timestamp::{}::option\")]", format));
+                    } else {
+                        lines.push(format!("#[serde(with=\"::rusoto_core::timestamp::{}\")]", format));
+                    }
                 } else if member_shape.shape_type == ShapeType::List {
                     if let Some(ref list_element_member) = member_shape.member {
                         if let Some(list_element_shape_type) = service.shape_type_for_member(list_element_member) {
@@ -550,44 +1327,255 @@ fn generate_struct_fields<P: GenerateProtocol>(
             }
         }
 
+        lines.push(format!("pub {}: {},", name, field_type));
+
+        Some(lines.join("\n"))
+    }).chain(if has_checksum_algorithm(service, shape_name) {
+        Some(format!(
+            "/// <p>The algorithm of the checksum sent with the request body, <code>Content-MD5</code> when not set. CRC32 and CRC32C are considerably cheaper to compute for large bodies.</p>
+            pub checksum_algorithm: Option<{}>,",
+            CHECKSUM_ALGORITHM_TYPE
+        ))
+    } else {
+        None
+    }).collect::<Vec<String>>().join("\n")
+}
+
+/// The type of the `checksum_algorithm` member of the requests whose payload is checksummed.
+const CHECKSUM_ALGORITHM_TYPE: &str = "::rusoto_core::signature::ChecksumAlgorithm";
+
+/// Whether a shape is the input of an operation whose payload is sent with a checksum, whose
+/// algorithm is selected with a `checksum_algorithm` member which isn't part of the model.
+fn has_checksum_algorithm(service: &Service<'_>, shape_name: &str) -> bool {
+    service.operations().values().any(|operation| {
+        operation.input_shape_or("") == shape_name
+            && rest_xml::requires_payload_checksum(service, &operation.name)
+    })
+}
+
+/// The name and type of the field generated for a member of a structure.
+fn struct_field<P: GenerateProtocol>(
+    service: &Service<'_>,
+    shape: &Shape,
+    shape_name: &str,
+    member_name: &str,
+    member: &Member,
+    protocol_generator: &P,
+) -> (String, String) {
+    let member_shape = service.shape_for_member(member).unwrap();
+    let rs_type = get_rust_type(
+        service,
+        &member.shape,
+        member_shape,
+        member.streaming(),
+        protocol_generator.timestamp_type(),
+    );
+    let name = generate_field_name(member_name);
+
+    // For structs that can contain another of themselves, we need to box them.
+    if shape_name == rs_type {
+        if shape.required(member_name) {
+            (name, format!("Box<{}>", rs_type))
+        } else if name == "type" {
+            (format!("aws_{}", name), format!("Box<Option<{}>>", rs_type))
+        } else {
+            (name, format!("Box<Option<{}>>", rs_type))
+        }
+    } else {
+        // In pratice, Lex can return null values for slots that are not filled. The documentation
+        // does not mention that the slot values themselves can be null.
+        if service.name() == "Amazon Lex Runtime Service" && shape_name == "PostTextResponse" && name == "slots" {
+            (name, "Option<::std::collections::HashMap<String, Option<String>>>".to_owned())
+        } else if required_field(service, shape, shape_name, member_name) {
+            (name, rs_type)
+        } else if name == "type" {
+            (format!("aws_{}", name), format!("Option<{}>", rs_type))
+        } else {
+            (name, format!("Option<{}>", rs_type))
+        }
+    }
+}
+
+/// Whether the field generated for a member of a structure which doesn't contain another of
+/// itself is required, rather than an `Option`.
+fn required_field(
+    service: &Service<'_>,
+    shape: &Shape,
+    shape_name: &str,
+    member_name: &str,
+) -> bool {
+    let name = generate_field_name(member_name);
+    // In the official documentation the fields revision_change_id and created are required
+    // but when looking at the responses from aws those are not always set.
+    // See https://github.com/rusoto/rusoto/issues/1419 for more information
+    if service.name() == "CodePipeline" && shape_name == "ActionRevision" && name == "revision_change_id" || name == "created" {
+        return false;
+    }
+    if service.name() == "Amazon Lex Runtime Service" && shape_name == "PostTextResponse" && name == "slots" {
+        return false;
+    }
+    shape.required(member_name)
+}
+
+/// Whether the structure generated for a shape derives `Default`.
+///
+/// Enums have no default: an empty value would be sent as it is. So structures with a required
+/// member of an enum shape don't derive it, nor do the structures requiring those in turn, and
+/// have to be created with each of their required members set.
+pub fn derives_default(service: &Service<'_>, name: &str, shape: &Shape) -> bool {
+    let shape_members = shape.members.iter().flat_map(|members| members.iter());
+    for (member_name, member) in shape_members {
+        if member.deprecated == Some(true) || !required_field(service, shape, name, member_name) {
+            continue;
+        }
         let member_shape = service.shape_for_member(member).unwrap();
-        let rs_type = get_rust_type(service,
-                                    &member.shape,
-                                    member_shape,
-                                    member.streaming(),
-                                    protocol_generator.timestamp_type());
-        let name = generate_field_name(member_name);
-
-        // For structs that can contain another of themselves, we need to box them.
-        if shape_name == rs_type {
-            if shape.required(member_name) {
-                lines.push(format!("pub {}: Box<{}>,", name, rs_type))
-            } else if name == "type" {
-                lines.push(format!("pub aws_{}: Box<Option<{}>>,", name,rs_type))
-            } else {
-                lines.push(format!("pub {}: Box<Option<{}>>,", name, rs_type))
-            }
+        let member_type = mutate_type_name(service, &member.shape);
+        if member_shape.is_enum() {
+            return false;
+        }
+        if member_shape.shape_type == ShapeType::Structure
+            && member_type != name
+            && !derives_default(service, &member_type, member_shape)
+        {
+            return false;
+        }
+    }
+    true
+}
+
+/// An expression for a value of a structure which is filled in afterwards, such as the value a
+/// deserializer starts from.
+///
+/// That's the default value of the structure, or for the structures which don't derive `Default`,
+/// a value whose required members of enum shapes are empty `Unknown` values.
+pub fn initial_value(service: &Service<'_>, name: &str, shape: &Shape) -> String {
+    if derives_default(service, name, shape) {
+        return format!("{}::default()", name);
+    }
+
+    let shape_members = shape.members.iter().flat_map(|members| members.iter());
+    let mut fields = Vec::new();
+    for (member_name, member) in shape_members {
+        if member.deprecated == Some(true) {
+            continue;
+        }
+        let member_shape = service.shape_for_member(member).unwrap();
+        let member_type = mutate_type_name(service, &member.shape);
+        let value = if !required_field(service, shape, name, member_name) {
+            "Default::default()".to_owned()
+        } else if member_shape.is_enum() {
+            format!("{}::Unknown(String::new())", member_type)
+        } else if member_shape.shape_type == ShapeType::Structure && member_type != name {
+            initial_value(service, &member_type, member_shape)
         } else {
-            // In the official documentation the fields revision_change_id and created are required
-            // but when looking at the responses from aws those are not always set.
-            // See https://github.com/rusoto/rusoto/issues/1419 for more information
-            if service.name() == "CodePipeline" && shape_name == "ActionRevision" && name == "revision_change_id" || name == "created" {
-                lines.push(format!("pub {}: Option<{}>,", name, rs_type))
-            // In pratice, Lex can return null values for slots that are not filled. The documentation
-            // does not mention that the slot values themselves can be null.
-            } else if service.name() == "Amazon Lex Runtime Service"  && shape_name == "PostTextResponse" && name == "slots"{
-                lines.push(format!("pub {}: Option<::std::collections::HashMap<String, Option<String>>>,", name))
-            } else if shape.required(member_name) {
-                lines.push(format!("pub {}: {},", name, rs_type))
-            } else if name == "type" {
-                lines.push(format!("pub aws_{}: Option<{}>,", name,rs_type))
-            } else {
-                lines.push(format!("pub {}: Option<{}>,", name, rs_type))
-            }
+            "Default::default()".to_owned()
+        };
+        fields.push(format!("{}: {},", generate_field_name(member_name), value));
+    }
+    if has_checksum_algorithm(service, name) {
+        fields.push("checksum_algorithm: Default::default(),".to_owned());
+    }
+
+    format!("{} {{ {} }}", name, fields.join("\n"))
+}
+
+/// Whether a shape is the input of an operation.
+fn is_input_shape(service: &Service<'_>, shape_name: &str) -> bool {
+    service
+        .operations()
+        .values()
+        .any(|operation| operation.input_shape_or("") == shape_name)
+}
+
+/// Generates a builder for an input shape, so that requests can be created without relying on
+/// `..Default::default()` and required members are checked when the request is built.
+fn generate_builder<P: GenerateProtocol>(
+    service: &Service<'_>,
+    name: &str,
+    shape: &Shape,
+    protocol_generator: &P,
+) -> String {
+    let mut fields = Vec::new();
+    let mut setters = Vec::new();
+    let mut members = Vec::new();
+
+    let shape_members = shape.members.iter().flat_map(|members| members.iter());
+    for (member_name, member) in shape_members {
+        if member.deprecated == Some(true) {
+            continue;
         }
+        let (field, field_type) =
+            struct_field(service, shape, name, member_name, member, protocol_generator);
+        // optional members are stored as they are, required ones are wrapped until built
+        let (value_type, optional) = if field_type.starts_with("Option<") {
+            (field_type["Option<".len()..field_type.len() - 1].to_owned(), true)
+        } else {
+            (field_type.clone(), false)
+        };
+        fields.push(format!("{}: Option<{}>,", field, value_type));
+        setters.push(format!(
+            "/// Sets the [`{field}`](struct.{name}.html#structfield.{field}) member.
+            pub fn {field}<T: Into<{value_type}>>(mut self, value: T) -> Self {{
+                self.{field} = Some(value.into());
+                self
+            }}",
+            field = field,
+            name = name,
+            value_type = value_type,
+        ));
+        if optional {
+            members.push(format!("{field}: self.{field},", field = field));
+        } else {
+            members.push(format!(
+                "{field}: self.{field}.ok_or(::rusoto_core::BuildError::missing(\"{field}\"))?,",
+                field = field,
+            ));
+        }
+    }
+    if has_checksum_algorithm(service, name) {
+        fields.push(format!("checksum_algorithm: Option<{}>,", CHECKSUM_ALGORITHM_TYPE));
+        setters.push(format!(
+            "/// Sets the [`checksum_algorithm`](struct.{name}.html#structfield.checksum_algorithm) member.
+            pub fn checksum_algorithm<T: Into<{value_type}>>(mut self, value: T) -> Self {{
+                self.checksum_algorithm = Some(value.into());
+                self
+            }}",
+            name = name,
+            value_type = CHECKSUM_ALGORITHM_TYPE,
+        ));
+        members.push("checksum_algorithm: self.checksum_algorithm,".to_owned());
+    }
 
-        Some(lines.join("\n"))
-    }).collect::<Vec<String>>().join("\n")
+    format!(
+        "impl {name} {{
+            /// Returns a builder for `{name}`, checking its required members when built.
+            pub fn builder() -> {name}Builder {{
+                {name}Builder::default()
+            }}
+        }}
+
+        /// Builds a [`{name}`](struct.{name}.html).
+        #[derive(Default, Debug)]
+        pub struct {name}Builder {{
+            {fields}
+        }}
+
+        impl {name}Builder {{
+            {setters}
+
+            /// Builds the `{name}`, failing if a required member hasn't been set.
+            pub fn build(self) -> ::std::result::Result<{name}, ::rusoto_core::BuildError> {{
+                Ok({name} {{
+                    {members}
+                }})
+            }}
+        }}
+        ",
+        name = name,
+        fields = fields.join("\n"),
+        setters = setters.join("\n\n"),
+        members = members.join("\n"),
+    )
 }
 
 fn error_type_name(service: &Service<'_>, name: &str) -> String {
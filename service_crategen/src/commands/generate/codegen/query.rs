@@ -7,21 +7,23 @@ use crate::Service;
 
 use super::xml_payload_parser;
 use super::{
-    error_type_name, generate_field_name, get_rust_type, FileWriter, GenerateProtocol, IoResult,
+    error_type_name, generate_field_name, generate_operation_example, get_rust_type, FileWriter,
+    GenerateProtocol, IoResult,
 };
 
 pub struct QueryGenerator;
 
 impl GenerateProtocol for QueryGenerator {
-    fn generate_method_signatures(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+    fn generate_method_signatures(&self, writer: &mut dyn Write, service: &Service<'_>) -> IoResult {
         for (operation_name, operation) in service.operations().iter() {
             writeln!(
                 writer,
                 "
-                {documentation}
+                {documentation}{example}
                 {method_signature};
                 ",
                 documentation = generate_documentation(operation),
+                example = generate_operation_example(service, operation, self),
                 method_signature = generate_method_signature(operation_name, operation, service),
             )?
         }
@@ -79,6 +81,7 @@ impl GenerateProtocol for QueryGenerator {
             use rusoto_core::proto::xml::util::{{Next, Peek, XmlParseError, XmlResponse}};
             use rusoto_core::proto::xml::util::{{characters, end_element, find_start_element, start_element, skip_tree, peek_at_name, deserialize_elements}};
             use rusoto_core::proto::xml::error::*;
+            use rusoto_core::timestamp::IsoTimestamp;
             use serde_urlencoded;
             ")
     }
@@ -118,7 +121,7 @@ impl GenerateProtocol for QueryGenerator {
     }
 
     fn timestamp_type(&self) -> &'static str {
-        "String"
+        "IsoTimestamp"
     }
 }
 
@@ -357,6 +360,9 @@ fn optional_primitive_field_serializer(
         return "".to_owned();
     }
     let member_shape = service.shape_for_member(member).unwrap();
+    if member.idempotency_token() && member_shape.shape_type == ShapeType::String {
+        return idempotency_token_field_serializer(service, member_name, member);
+    }
     let expression = serialize_primitive_expression(member_shape.shape_type, "field_value");
 
     format!(
@@ -369,6 +375,24 @@ fn optional_primitive_field_serializer(
     )
 }
 
+/// Serializes an optional idempotency token, generating one when it is left `None` so that
+/// retried requests are only carried out once.
+fn idempotency_token_field_serializer(
+    service: &Service<'_>,
+    member_name: &str,
+    member: &Member,
+) -> String {
+    format!(
+        "let field_value = obj
+                .{field_name}
+                .clone()
+                .unwrap_or_else(rusoto_core::idempotency::generate_token);
+            params.put(&format!(\"{{}}{{}}\", prefix, \"{tag_name}\"), &field_value);",
+        field_name = generate_field_name(member_name),
+        tag_name = member_location(service, member, member_name)
+    )
+}
+
 fn required_primitive_field_serializer(
     service: &Service<'_>,
     member_name: &str,
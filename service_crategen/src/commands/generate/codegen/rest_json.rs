@@ -3,8 +3,8 @@ use std::io::Write;
 use inflector::Inflector;
 
 use super::{
-    error_type_name, generate_field_name, rest_request_generator, rest_response_parser, FileWriter,
-    GenerateProtocol, IoResult,
+    error_type_name, generate_field_name, generate_operation_example, initial_value,
+    rest_request_generator, rest_response_parser, FileWriter, GenerateProtocol, IoResult,
 };
 use crate::botocore::{Operation, Shape, ShapeType};
 use crate::Service;
@@ -12,7 +12,7 @@ use crate::Service;
 pub struct RestJsonGenerator;
 
 impl GenerateProtocol for RestJsonGenerator {
-    fn generate_method_signatures(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+    fn generate_method_signatures(&self, writer: &mut dyn Write, service: &Service<'_>) -> IoResult {
         for (operation_name, operation) in service.operations().iter() {
             let input_type = match &operation.input {
                 Some(_) => operation.input_shape(),
@@ -26,11 +26,12 @@ impl GenerateProtocol for RestJsonGenerator {
             writeln!(
                 writer,
                 "
-                {documentation}
+                {documentation}{example}
                 {method_signature} -> \
                       RusotoFuture<{output_type}, {error_type}>;
                 ",
                 documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
+                example = generate_operation_example(service, operation, self),
                 method_signature = generate_method_signature(operation, *input_shape),
                 error_type = error_type_name(service, operation_name),
                 output_type = output_type
@@ -115,7 +116,8 @@ impl GenerateProtocol for RestJsonGenerator {
         let res = writeln!(
             writer,
             "use rusoto_core::signature::SignedRequest;
-                  use rusoto_core::proto;"
+                  use rusoto_core::proto;
+                  use rusoto_core::timestamp::EpochTimestamp;"
         );
         if service.needs_serde_json_crate() {
             return writeln!(writer, "use serde_json;");
@@ -132,7 +134,7 @@ impl GenerateProtocol for RestJsonGenerator {
     }
 
     fn timestamp_type(&self) -> &'static str {
-        "f64"
+        "EpochTimestamp"
     }
 }
 
@@ -367,7 +369,7 @@ fn generate_body_parser(operation: &Operation, service: &Service<'_>) -> String
                 {
                     payload_body_parser(
                         payload_type,
-                        shape_name,
+                        &initial_value(service, shape_name, output_shape),
                         payload_member_name,
                         mutable_result,
                         payload_shape_required,
@@ -383,7 +385,7 @@ fn generate_body_parser(operation: &Operation, service: &Service<'_>) -> String
 /// on the result object
 fn payload_body_parser(
     payload_type: ShapeType,
-    output_shape: &str,
+    initial: &str,
     payload_member: &str,
     mutable_result: bool,
     payload_required: bool,
@@ -401,10 +403,10 @@ fn payload_body_parser(
 
     format!(
         "
-        let {mutable} result = {output_shape}::default();
+        let {mutable} result = {initial};
         result.{payload_member} = {response_body};
         ",
-        output_shape = output_shape,
+        initial = initial,
         payload_member = payload_member.to_snake_case(),
         response_body = response_body,
         mutable = if mutable_result { "mut" } else { "" }
@@ -21,20 +21,39 @@ pub fn generate_headers(service: &Service<'_>, operation: &Operation) -> Option<
             member.location.as_ref()?;
             match &member.location.as_ref().unwrap()[..] {
                 "header" => {
+                    // timestamps are sent as HTTP dates unless the model says otherwise
+                    let to_header = match service.shape_type_for_member(member) {
+                        Some(ShapeType::Timestamp) => match service.timestamp_format_for_member(member) {
+                            Some("iso8601") => Some("rusoto_core::timestamp::to_iso8601"),
+                            _ => Some("rusoto_core::timestamp::to_http_date"),
+                        },
+                        _ => None,
+                    };
                     if shape.required(member_name) {
+                        let member_shape = service.shape_for_member(member).unwrap();
+                        let value = match to_header {
+                            Some(to_header) => format!("&{}(&input.{})", to_header, generate_field_name(member_name)),
+                            None if member_shape.is_enum() => format!("input.{}.as_str()", generate_field_name(member_name)),
+                            None => format!("&input.{}", generate_field_name(member_name)),
+                        };
                         Some(format!("request.add_header(\"{location_name}\", 
-                                      &input.{field_name});",
+                                      {value});",
                                      location_name = member.location_name.as_ref().unwrap(),
-                                     field_name = generate_field_name(member_name)))
+                                     value = value))
                     } else {
+                        let value = match to_header {
+                            Some(to_header) => format!("&{}({})", to_header, generate_field_name(member_name)),
+                            None => format!("&{}.to_string()", generate_field_name(member_name)),
+                        };
                         Some(format!("
                         if let Some(ref {field_name}) = 
                                       input.{field_name} {{
                                       request.add_header(\"{location_name}\", 
-                                      &{field_name}.to_string());
+                                      {value});
                         }}",
                                      location_name = member.location_name.as_ref().unwrap(),
-                                     field_name = generate_field_name(member_name)))
+                                     field_name = generate_field_name(member_name),
+                                     value = value))
                     }
                 },
                 "headers" => {
@@ -281,6 +300,23 @@ fn generate_param_load_string(
             param_name = param_name,
             field_name = field_name
         ),
+        // timestamps are sent in ISO 8601 format by `ToParam`, unless the model says otherwise
+        (ShapeType::Timestamp, false)
+            if member
+                .timestamp_format
+                .as_ref()
+                .or_else(|| member_shape.timestamp_format.as_ref())
+                .map(String::as_str)
+                == Some("rfc822") =>
+        {
+            format!(
+                "if let Some(ref x) = input.{field_name} {{
+                    params.put(\"{param_name}\", &rusoto_core::timestamp::to_http_date(x));
+                }}",
+                param_name = param_name,
+                field_name = field_name,
+            )
+        }
         (_, false) => format!(
             "if let Some(ref x) = input.{field_name} {{
                     params.put(\"{param_name}\", x);
@@ -288,6 +324,7 @@ fn generate_param_load_string(
             param_name = param_name,
             field_name = field_name,
         ),
+
     }
 }
 
@@ -110,7 +110,18 @@ fn parse_single_header(
                  result.{field_name} = {primitive_parser};",
             location_name = member.location_name.as_ref().unwrap(),
             field_name = member_name.to_snake_case(),
-            primitive_parser = generate_header_primitive_parser(member_shape)
+            primitive_parser = generate_header_primitive_parser(service, member, member_shape)
+        )
+    } else if member_shape.shape_type == ShapeType::Timestamp {
+        // an invalid optional date, such as `Expires: 0`, is treated as unset
+        format!(
+            "if let Some({field_name}) = response.headers.get(\"{location_name}\") {{
+                    let value = {field_name}.to_owned();
+                    result.{field_name} = {timestamp_parser}.ok()
+                  }};",
+            location_name = member.location_name.as_ref().unwrap(),
+            field_name = member_name.to_snake_case(),
+            timestamp_parser = generate_header_timestamp_parser(service, member)
         )
     } else {
         format!(
@@ -120,14 +131,18 @@ fn parse_single_header(
                   }};",
             location_name = member.location_name.as_ref().unwrap(),
             field_name = member_name.to_snake_case(),
-            primitive_parser = generate_header_primitive_parser(member_shape)
+            primitive_parser = generate_header_primitive_parser(service, member, member_shape)
         )
     }
 }
 
-fn generate_header_primitive_parser(shape: &Shape) -> String {
+fn generate_header_primitive_parser(service: &Service<'_>, member: &Member, shape: &Shape) -> String {
     let statement = match shape.shape_type {
-        ShapeType::String | ShapeType::Timestamp => "value",
+        ShapeType::String if shape.is_enum() => "value.into()",
+        ShapeType::String => "value",
+        ShapeType::Timestamp => {
+            return format!("{}.unwrap()", generate_header_timestamp_parser(service, member))
+        }
         ShapeType::Double => "value.parse::<f64>().unwrap()",
         ShapeType::Integer | ShapeType::Long => "value.parse::<i64>().unwrap()",
         ShapeType::Float => "value.parse::<f32>().unwrap()",
@@ -137,3 +152,11 @@ fn generate_header_primitive_parser(shape: &Shape) -> String {
 
     statement.to_string()
 }
+
+// timestamps are sent as HTTP dates unless the model says otherwise
+fn generate_header_timestamp_parser(service: &Service<'_>, member: &Member) -> &'static str {
+    match service.timestamp_format_for_member(member) {
+        Some("iso8601") => "rusoto_core::timestamp::from_iso8601(value)",
+        _ => "rusoto_core::timestamp::from_http_date(&value)",
+    }
+}
@@ -1,7 +1,7 @@
 use inflector::Inflector;
 use std::io::Write;
 
-use super::{error_type_name, generate_field_name, GenerateProtocol};
+use super::{error_type_name, generate_field_name, generate_operation_example, GenerateProtocol};
 use super::{
     get_rust_type, mutate_type_name, rest_request_generator, rest_response_parser,
     xml_payload_parser,
@@ -13,15 +13,16 @@ use crate::Service;
 pub struct RestXmlGenerator;
 
 impl GenerateProtocol for RestXmlGenerator {
-    fn generate_method_signatures(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+    fn generate_method_signatures(&self, writer: &mut dyn Write, service: &Service<'_>) -> IoResult {
         for (operation_name, operation) in service.operations().iter() {
             writeln!(
                 writer,
                 "
-                {documentation}
+                {documentation}{example}
                 {method_signature};
                 ",
                 documentation = generate_documentation(operation, service),
+                example = generate_operation_example(service, operation, self),
                 method_signature = generate_method_signature(operation_name, operation, service),
             )?
         }
@@ -94,6 +95,7 @@ impl GenerateProtocol for RestXmlGenerator {
             use rusoto_core::proto::xml::error::*;
             use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
             use rusoto_core::proto::xml::util::{peek_at_name, characters, end_element, find_start_element, start_element, skip_tree, deserialize_elements};
+            use rusoto_core::timestamp::IsoTimestamp;
             "
             .to_owned();
 
@@ -144,7 +146,7 @@ impl GenerateProtocol for RestXmlGenerator {
     }
 
     fn timestamp_type(&self) -> &'static str {
-        "String"
+        "IsoTimestamp"
     }
 }
 
@@ -202,20 +204,37 @@ fn generate_payload_serialization(service: &Service<'_>, operation: &Operation)
     Some(parts.join("\n"))
 }
 
-fn generate_service_specific_code(service: &Service<'_>, operation: &Operation) -> Option<String> {
+/// Whether the payload of an operation must be sent with a checksum, `Content-MD5` unless the
+/// request selects another algorithm with its `checksum_algorithm` member.
+pub fn requires_payload_checksum(service: &Service<'_>, operation_name: &str) -> bool {
     // S3 needs some special handholding.  Others may later.
     // See `handlers.py` in botocore for more details
     match service.service_type_name() {
-        "S3" => match &operation.name[..] {
+        "S3" => match operation_name {
             "PutBucketTagging"
             | "PutBucketLifecycle"
             | "PutBucketLifecycleConfiguration"
             | "PutBucketCors"
             | "DeleteObjects"
-            | "PutBucketReplication" => Some("request.set_content_md5_header();".to_owned()),
-            _ => None,
+            | "PutBucketReplication" => true,
+            _ => false,
         },
-        _ => None,
+        _ => false,
+    }
+}
+
+fn generate_service_specific_code(service: &Service<'_>, operation: &Operation) -> Option<String> {
+    if requires_payload_checksum(service, &operation.name) {
+        Some(
+            "request.set_checksum_header(
+                input
+                    .checksum_algorithm
+                    .unwrap_or(::rusoto_core::signature::ChecksumAlgorithm::Md5),
+            );"
+            .to_owned(),
+        )
+    } else {
+        None
     }
 }
 
@@ -302,6 +321,7 @@ fn generate_serializer_signature(name: &str) -> String {
 fn generate_primitive_serializer(shape: &Shape) -> String {
     let value_str = match shape.shape_type {
         ShapeType::Blob => "String::from_utf8(obj.to_vec()).expect(\"Not a UTF-8 string\")",
+        ShapeType::Timestamp => "rusoto_core::timestamp::to_iso8601(obj)",
         _ => "obj.to_string()",
     };
     format!("
@@ -4,7 +4,7 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use self::util::case_insensitive_btreemap_get;
-use super::{FileWriter, IoResult};
+use super::{initial_value, FileWriter, IoResult};
 use crate::util;
 use crate::Service;
 
@@ -105,9 +105,12 @@ fn generate_response_parse_test(
     let request_params;
     let request_constructor;
     if operation.input.is_some() {
+        let input_shape = service
+            .get_shape(operation.input_shape())
+            .expect("input shape missing");
         request_constructor = format!(
-            "let request = {request_type}::default();",
-            request_type = operation.input_shape()
+            "let request = {initial};",
+            initial = initial_value(service, operation.input_shape(), input_shape)
         );
         request_params = "request".to_string();
     } else {
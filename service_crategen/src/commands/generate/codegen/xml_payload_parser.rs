@@ -1,15 +1,15 @@
 use inflector::Inflector;
 
-use super::{generate_field_name, mutate_type_name};
+use super::{derives_default, generate_field_name, initial_value, mutate_type_name};
 use crate::botocore::{Member, Operation, Shape, ShapeType};
 use crate::Service;
 
 pub fn generate_deserializer(name: &str, ty: &str, shape: &Shape, service: &Service<'_>) -> String {
     format!(
-        "struct {name}Deserializer;
+        "pub(crate) struct {name}Deserializer;
             impl {name}Deserializer {{
                 #[allow(unused_variables)]
-                fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T)
+                pub(crate) fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T)
                 -> Result<{ty}, XmlParseError> {{
                     {deserializer_body}
                 }}
@@ -60,12 +60,14 @@ pub fn generate_response_parser(
         .get_shape(shape_name)
         .expect("failed to get output shape");
     let mutated_shape_name = mutate_type_name(service, shape_name);
+    let initial = initial_value(service, &mutated_shape_name, output_shape);
 
     // if the 'payload' field on the output shape is a blob or string, it indicates that
     // the entire payload is set as one of the struct members, and not parsed
     match output_shape.payload {
         None => xml_body_parser(
             &mutated_shape_name,
+            &initial,
             result_wrapper,
             mutable_result,
             parse_non_payload,
@@ -86,7 +88,7 @@ pub fn generate_response_parser(
                 {
                     payload_body_parser(
                         payload_type,
-                        &mutated_shape_name,
+                        &initial,
                         payload_member_name,
                         has_streaming_payload(output_shape),
                         parse_non_payload,
@@ -94,6 +96,7 @@ pub fn generate_response_parser(
                 }
                 _ => xml_body_parser(
                     &mutated_shape_name,
+                    &initial,
                     result_wrapper,
                     mutable_result,
                     parse_non_payload,
@@ -105,7 +108,7 @@ pub fn generate_response_parser(
 
 fn payload_body_parser(
     payload_type: ShapeType,
-    output_shape: &str,
+    initial: &str,
     payload_member: &str,
     streaming: bool,
     parse_non_payload: &str,
@@ -114,37 +117,37 @@ fn payload_body_parser(
         ShapeType::Blob if !streaming => {
             format!("
                 Box::new(response.buffer().from_err().map(move |response| {{
-                    let mut result = {output_shape}::default();
+                    let mut result = {initial};
                     result.{payload_member} = Some(response.body);
                     {parse_non_payload}
                     result
                 }}))
                 ",
-                    output_shape = output_shape,
+                    initial = initial,
                     payload_member = payload_member.to_snake_case(),
                     parse_non_payload = parse_non_payload)
         },
         ShapeType::Blob if streaming => {
             format!("
-                let mut result = {output_shape}::default();
+                let mut result = {initial};
                 result.{payload_member} = Some(response.body);
                 {parse_non_payload}
                 Box::new(future::ok(result))
                 ",
-                    output_shape = output_shape,
+                    initial = initial,
                     payload_member = payload_member.to_snake_case(),
                     parse_non_payload = parse_non_payload)
         },
         _ => {
             format!("
                 Box::new(response.buffer().from_err().map(move |response| {{
-                    let mut result = {output_shape}::default();
+                    let mut result = {initial};
                     result.{payload_member} = Some(String::from_utf8_lossy(response.body.as_ref()).into());
                     {parse_non_payload}
                     result
                 }}))
                 ",
-                    output_shape = output_shape,
+                    initial = initial,
                     payload_member = payload_member.to_snake_case(),
                     parse_non_payload = parse_non_payload)
         },
@@ -153,6 +156,7 @@ fn payload_body_parser(
 
 fn xml_body_parser(
     output_shape: &str,
+    initial: &str,
     result_wrapper: &Option<String>,
     mutable_result: bool,
     parse_non_payload: &str,
@@ -183,7 +187,7 @@ fn xml_body_parser(
             {let_result}
 
             if response.body.is_empty() {{
-                result = {output_shape}::default();
+                result = {initial};
             }} else {{
                 let reader = EventReader::new_with_config(
                     response.body.as_ref(),
@@ -198,7 +202,7 @@ fn xml_body_parser(
             Ok(result)
         }}))",
         let_result = let_result,
-        output_shape = output_shape,
+        initial = initial,
         deserialize = deserialize,
         parse_non_payload = parse_non_payload
     )
@@ -230,10 +234,10 @@ fn generate_deserializer_body(name: &str, shape: &Shape, service: &Service<'_>)
                 .collect::<Vec<String>>()
                 .join("\n");
             return format!(
-                "let mut obj = {name}::default();
+                "let mut obj = {initial};
                             {struct_field_deserializers}
                             Ok(obj)",
-                name = name,
+                initial = initial_value(service, name, shape),
                 struct_field_deserializers = struct_field_deserializers
             );
         }
@@ -361,7 +365,9 @@ fn generate_primitive_deserializer(shape: &Shape, percent_decode: bool) -> Strin
         ShapeType::String if percent_decode => {
             "rusoto_core::signature::decode_uri(&characters(stack)?)"
         }
-        ShapeType::String | ShapeType::Timestamp => "characters(stack)?",
+        ShapeType::String if shape.is_enum() => "characters(stack)?.into()",
+        ShapeType::String => "characters(stack)?",
+        ShapeType::Timestamp => "rusoto_core::timestamp::from_iso8601(characters(stack)?)?",
         ShapeType::Integer | ShapeType::Long => {
             "i64::from_str(characters(stack)?.as_ref()).unwrap()"
         }
@@ -410,10 +416,11 @@ fn generate_struct_deserializer(name: &str, service: &Service<'_>, shape: &Shape
             "
             Ok({name} {{
                 {member}: {deserialize},
-                ..{name}::default()
+                ..{initial}
             }})
             ",
             name = name,
+            initial = initial_value(service, name, shape),
             member = payload_member_name.to_snake_case(),
             deserialize = deserialize
         );
@@ -433,13 +440,35 @@ fn generate_struct_deserializer(name: &str, service: &Service<'_>, shape: &Shape
         return format!(
             "start_element(tag_name, stack)?;
 
-            let obj = {name}::default();
+            let obj = {initial};
 
             end_element(tag_name, stack)?;
 
             Ok(obj)
             ",
-            name = name,
+            initial = initial_value(service, name, shape),
+        );
+    }
+
+    let struct_field_deserializers = generate_struct_field_deserializers(service, shape);
+    if !derives_default(service, name, shape) {
+        // the required members of enum shapes are set when their elements are found
+        return format!(
+            "::rusoto_core::proto::xml::util::deserialize_elements_into(
+                tag_name,
+                stack,
+                {initial},
+                |name, stack, obj| {{
+                    match name {{
+                        {struct_field_deserializers}
+                        _ => skip_tree(stack),
+                    }}
+                    Ok(())
+                }},
+            )
+            ",
+            initial = initial_value(service, name, shape),
+            struct_field_deserializers = struct_field_deserializers,
         );
     }
 
@@ -453,7 +482,7 @@ fn generate_struct_deserializer(name: &str, service: &Service<'_>, shape: &Shape
         }})
         ",
         name = name,
-        struct_field_deserializers = generate_struct_field_deserializers(service, shape),
+        struct_field_deserializers = struct_field_deserializers,
     )
 }
 
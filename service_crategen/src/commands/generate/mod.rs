@@ -8,10 +8,30 @@ use rayon::prelude::*;
 use toml;
 
 mod codegen;
+mod partitions;
+mod templates;
 
 use crate::cargo;
 use crate::{Service, ServiceConfig, ServiceDefinition};
 
+/// The declarations in `lib.rs` of the modules of the additional API versions, each behind its
+/// feature.
+fn version_modules(service: &Service<'_>, service_config: &ServiceConfig) -> String {
+    service_config
+        .additional_versions()
+        .iter()
+        .map(|(version, feature, module)| {
+            format!(
+                "\n/// The {} API as of `{}`.\n#[cfg(feature = \"{}\")]\npub mod {};",
+                service.full_name(),
+                version,
+                feature,
+                module
+            )
+        })
+        .collect()
+}
+
 fn generate_examples(crate_dir_path: &Path) -> Option<String> {
     let examples_dir_path = crate_dir_path.join("examples");
 
@@ -23,6 +43,10 @@ fn generate_examples(crate_dir_path: &Path) -> Option<String> {
 
     for dir_entry_result in fs::read_dir(&examples_dir_path).expect("failed to read examples dir") {
         let dir_entry = dir_entry_result.expect("failed to read examples dir");
+        // the smoke test example is generated, and only meant to be run
+        if dir_entry.file_name() == "smoke.rs" {
+            continue;
+        }
         let mut contents = Vec::new();
         let mut file = fs::File::open(dir_entry.path()).expect("failed to open example");
         file.read_to_end(&mut contents)
@@ -63,18 +87,31 @@ pub fn generate_services(
         fs::create_dir(out_dir).expect("Unable to create output directory");
     }
 
+    // the partitions shared by every service, next to the regions in rusoto_signature
+    let partitions_path = out_dir.join("../signature/src/partition/generated.rs");
+    partitions::generate_partitions(&partitions_path);
+    let status = Command::new("rustfmt")
+        .args(&["--emit", "files"])
+        .args(&["--config-path", "rustfmt.toml"])
+        .arg(&partitions_path)
+        .status()
+        .expect("rustfmt command failed to start");
+    if !status.success() {
+        panic!("rustfmt failed");
+    }
+
     services.par_iter().for_each(|(name, service_config)| {
         if !service_to_generate.map(|s| s.contains(&name.as_str())).unwrap_or(true) {
             return;
         }
 
         let service = match ServiceDefinition::load(name, &service_config.protocol_version) {
-            Ok(sd) => Service::new(service_config, sd),
+            Ok(sd) => Service::new(name, service_config, sd),
             Err(_) => panic!("Failed to load service {}. Make sure the botocore submodule has been initialized!", name),
         };
 
         let crate_dir = out_dir.join(&name);
-        let crate_name = format!("rusoto_{}", &name.replace('-', "_"));
+        let crate_name = service.crate_name();
 
         println!("Generating crate for {} @ {}...", service.full_name(), service.api_version());
 
@@ -83,14 +120,36 @@ pub fn generate_services(
         }
 
         let mut features = BTreeMap::new();
+        features.insert("chrono".into(), vec!["rusoto_core/chrono".into()]);
         features.insert("default".into(), vec!["native-tls".into()]);
         features.insert("native-tls".into(), vec!["rusoto_core/native-tls".into()]);
         features.insert("rustls".into(), vec!["rusoto_core/rustls".into()]);
+        if service_config.generate_fake {
+            features.insert("fake".into(), vec![]);
+        }
+        for (_, feature, _) in service_config.additional_versions() {
+            features.insert(feature, vec![]);
+        }
+        if let Some(ref custom_features) = service_config.custom_features {
+            features.extend(custom_features.clone());
+        }
+
+        // the smoke test example makes real calls, so it's only built with its feature
+        let smoke_example = codegen::generate_smoke_example(&service, &crate_name);
+        let mut examples = Vec::new();
+        if smoke_example.is_some() {
+            features.insert("smoke".into(), vec![]);
+            examples.push(cargo::Example {
+                name: "smoke".into(),
+                required_features: vec!["smoke".into()],
+            });
+        }
 
         let service_dependencies = service.get_dependencies();
         let service_dev_dependencies = service.get_dev_dependencies();
 
-        let mut extern_crates = service_dependencies.iter().map(|(k, _)| {
+        // optional dependencies are only pulled in by custom code behind a feature flag
+        let mut extern_crates = service_dependencies.iter().filter(|(_, v)| !v.is_optional()).map(|(k, _)| {
             if k == "xml-rs" {
                 return "extern crate xml;".into();
             }
@@ -102,7 +161,9 @@ pub fn generate_services(
             format!("extern crate {};", safe_name)
         }).collect::<Vec<String>>().join("\n");
         // S3 needs the external test crate for benchmark tests
+        let mut crate_attributes = String::new();
         if service.full_name() == "Amazon Simple Storage Service" {
+            crate_attributes.push_str("\n#![cfg_attr(nightly, feature(test))]");
             extern_crates.push_str("\n#[cfg(nightly)]\nextern crate test;");
         }
 
@@ -143,6 +204,7 @@ pub fn generate_services(
             features: Some(features),
             dependencies: service_dependencies,
             dev_dependencies: service_dev_dependencies,
+            examples: Some(examples).filter(|examples| !examples.is_empty()),
             ..cargo::Manifest::default()
         };
 
@@ -239,7 +301,7 @@ See [LICENSE][license] for details.
 //
 // =================================================================
 
-#![doc(html_logo_url = "https://raw.githubusercontent.com/rusoto/rusoto/master/assets/logo-square.png")]
+#![doc(html_logo_url = "https://raw.githubusercontent.com/rusoto/rusoto/master/assets/logo-square.png")]{crate_attributes}
 {service_docs}
 //!
 //! If you're using the service, you're probably looking for [{client_name}](struct.{client_name}.html) and [{trait_name}](trait.{trait_name}.html).
@@ -247,16 +309,23 @@ See [LICENSE][license] for details.
 {extern_crates}
 
 mod generated;
-mod custom;
+mod custom;{fake_module}{version_modules}
 
 pub use crate::generated::*;
 pub use crate::custom::*;
             "#,
+            crate_attributes = crate_attributes,
             service_docs = crate::doco::Module(service.documentation().unwrap_or(&service.full_name().to_owned())),
             client_name = service.client_type_name(),
             trait_name = service.service_type_name(),
             examples = generate_examples(&crate_dir).unwrap_or_else(|| "".to_string()),
-            extern_crates = extern_crates
+            extern_crates = extern_crates,
+            fake_module = if service_config.generate_fake {
+                "\n#[cfg(feature = \"fake\")]\npub mod fake;"
+            } else {
+                ""
+            },
+            version_modules = version_modules(&service, service_config)
             ).expect("Couldn't write library file");
 
             let gen_file_path = src_dir.join("generated.rs");
@@ -272,6 +341,43 @@ pub use crate::custom::*;
 
             codegen::generate_source(&service, &mut gen_writer).unwrap();
 
+            if service_config.generate_fake {
+                let mut fake_writer = BufWriter::new(
+                    OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .truncate(true)
+                    .open(src_dir.join("fake.rs"))
+                    .expect("Unable to write fake.rs")
+                );
+
+                codegen::generate_fake_source(&service, &mut fake_writer).unwrap();
+            }
+
+            for (version, _, module) in service_config.additional_versions() {
+                let definition = match ServiceDefinition::load(name, version) {
+                    Ok(sd) => sd,
+                    Err(_) => panic!(
+                        "Failed to load version {} of service {}. The versions in botocore are: {:?}",
+                        version,
+                        name,
+                        ServiceDefinition::api_versions(name).unwrap_or_default()
+                    ),
+                };
+                let versioned_service = Service::new(name, service_config, definition);
+
+                let mut version_writer = BufWriter::new(
+                    OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .truncate(true)
+                    .open(src_dir.join(format!("{}.rs", module)))
+                    .unwrap_or_else(|_| panic!("Unable to write {}.rs", module))
+                );
+
+                codegen::generate_source(&versioned_service, &mut version_writer).unwrap();
+            }
+
             let custom_dir_path = src_dir.join("custom");
 
             if !custom_dir_path.exists() {
@@ -289,14 +395,37 @@ pub use crate::custom::*;
             }
         }
 
+        let mut template_files = Vec::new();
+        for template in &service_config.custom_templates {
+            let custom_dir_path = crate_dir.join("src").join("custom");
+            template_files.extend(templates::render_template(&service, template, &custom_dir_path));
+        }
+
+        if let Some(example) = smoke_example {
+            let examples_dir = crate_dir.join("examples");
+
+            if !examples_dir.exists() {
+                fs::create_dir(&examples_dir).unwrap_or_else(|_| panic!("Unable to create directory at {}", examples_dir.display()));
+            }
+
+            fs::write(examples_dir.join("smoke.rs"), example).expect("Unable to write smoke.rs");
+        }
+
         {
             let src_dir = crate_dir.join("src");
-            let gen_file_path = src_dir.join("generated.rs");
+            let mut generated_files = vec![src_dir.join("generated.rs")];
+            if service_config.generate_fake {
+                generated_files.push(src_dir.join("fake.rs"));
+            }
+            for (_, _, module) in service_config.additional_versions() {
+                generated_files.push(src_dir.join(format!("{}.rs", module)));
+            }
+            generated_files.extend(template_files);
 
             let status = Command::new("rustfmt")
                 .args(&["--emit", "files"])
                 .args(&["--config-path", "rustfmt.toml"])
-                .arg(gen_file_path)
+                .args(&generated_files)
                 .status()
                 .expect("rustfmt command failed to start");
             if !status.success() {
@@ -345,3 +474,99 @@ pub use crate::custom::*;
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::BufWriter;
+
+    use super::{codegen, version_modules};
+    use crate::{Service, ServiceConfig, ServiceDefinition};
+
+    const CONFIG: &str = r#"{
+        "version": "0.41.0",
+        "coreVersion": "0.41.0",
+        "protocolVersion": "2012-08-10",
+        "baseTypeName": "Widgets",
+        "additionalProtocolVersions": ["2011-12-05"]
+    }"#;
+
+    /// The older API version of a small JSON service.
+    const MODEL_2011_12_05: &str = r#"{
+        "version": "2.0",
+        "metadata": {
+            "apiVersion": "2011-12-05",
+            "endpointPrefix": "widgets",
+            "jsonVersion": "1.0",
+            "protocol": "json",
+            "serviceFullName": "Amazon Widgets",
+            "signatureVersion": "v4",
+            "targetPrefix": "Widgets_20111205"
+        },
+        "operations": {
+            "ListWidgets": {
+                "name": "ListWidgets",
+                "http": {"method": "POST", "requestUri": "/"},
+                "input": {"shape": "ListWidgetsInput"},
+                "output": {"shape": "ListWidgetsOutput"}
+            }
+        },
+        "shapes": {
+            "ListWidgetsInput": {
+                "type": "structure",
+                "members": {"Limit": {"shape": "Limit"}}
+            },
+            "ListWidgetsOutput": {
+                "type": "structure",
+                "members": {"WidgetNames": {"shape": "WidgetNames"}}
+            },
+            "Limit": {"type": "integer"},
+            "WidgetName": {"type": "string"},
+            "WidgetNames": {"type": "list", "member": {"shape": "WidgetName"}}
+        }
+    }"#;
+
+    #[test]
+    fn additional_versions_have_a_feature_and_a_module() {
+        let config: ServiceConfig = serde_json::from_str(CONFIG).unwrap();
+        assert_eq!(
+            config.additional_versions(),
+            vec![(
+                "2011-12-05",
+                "api-2011-12-05".to_owned(),
+                "v2011_12_05".to_owned()
+            )]
+        );
+
+        let config: ServiceConfig =
+            serde_json::from_str(&CONFIG.replace(r#""additionalProtocolVersions""#, r#""unused""#))
+                .unwrap();
+        assert!(config.additional_versions().is_empty());
+    }
+
+    // generating the client needs the botocore submodule, as generating the crates does
+    #[test]
+    fn generates_a_module_for_an_additional_version() {
+        let config: ServiceConfig = serde_json::from_str(CONFIG).unwrap();
+        let definition: ServiceDefinition = serde_json::from_str(MODEL_2011_12_05).unwrap();
+        let service = Service::new("widgets", &config, definition);
+
+        assert_eq!(
+            version_modules(&service, &config),
+            "\n/// The Amazon Widgets API as of `2011-12-05`.\n#[cfg(feature = \"api-2011-12-05\")]\npub mod v2011_12_05;"
+        );
+
+        let path = env::temp_dir().join("rusoto_widgets_v2011_12_05.rs");
+        {
+            let mut writer = BufWriter::new(File::create(&path).unwrap());
+            codegen::generate_source(&service, &mut writer).unwrap();
+        }
+        let source = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(source.contains("pub struct ListWidgetsInput"));
+        assert!(source.contains("fn list_widgets("));
+        assert!(source.contains("\"Widgets_20111205.ListWidgets\""));
+    }
+}
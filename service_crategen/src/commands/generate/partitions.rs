@@ -0,0 +1,126 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use crate::botocore::{EndpointsDefinition, PartitionDefinition};
+
+/// Generates the partitions of `rusoto_signature::partition` from botocore's `endpoints.json`.
+pub fn generate_partitions(out_path: &Path) {
+    let endpoints = EndpointsDefinition::load().expect(
+        "Failed to load endpoints.json. Make sure the botocore submodule has been initialized!",
+    );
+
+    let mut output = String::from(
+        "// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+use super::{GlobalEndpoint, Partition};
+
+/// The partitions in botocore's `endpoints.json`.
+pub(super) const PARTITIONS: &[Partition] = &[
+",
+    );
+    for partition in &endpoints.partitions {
+        generate_partition(&mut output, partition);
+    }
+    output.push_str("];\n");
+
+    fs::write(out_path, output)
+        .unwrap_or_else(|_| panic!("Unable to write {}", out_path.display()));
+}
+
+fn generate_partition(output: &mut String, partition: &PartitionDefinition) {
+    let regions = partition
+        .regions
+        .keys()
+        .map(|region| format!("{:?}", region))
+        .collect::<Vec<_>>();
+    let prefixes = region_prefixes(&partition.region_regex)
+        .iter()
+        .map(|prefix| format!("{:?}", prefix))
+        .collect::<Vec<_>>();
+
+    writeln!(
+        output,
+        "Partition {{
+            id: {id:?},
+            name: {name:?},
+            dns_suffix: {dns_suffix:?},
+            region_prefixes: &[{prefixes}],
+            regions: &[{regions}],
+            global_endpoints: &[",
+        id = partition.partition,
+        name = partition.partition_name,
+        dns_suffix = partition.dns_suffix,
+        prefixes = prefixes.join(", "),
+        regions = regions.join(", "),
+    )
+    .unwrap();
+
+    // services which aren't regionalized have a single endpoint in the partition
+    for (name, service) in &partition.services {
+        let partition_endpoint = match (service.is_regionalized, &service.partition_endpoint) {
+            (Some(false), Some(partition_endpoint)) => partition_endpoint,
+            _ => continue,
+        };
+        let endpoint = partition.endpoint(name, partition_endpoint);
+        let hostname = endpoint.hostname.expect("global endpoint without a hostname");
+        let signing_region = endpoint
+            .credential_scope
+            .and_then(|scope| scope.region)
+            .expect("global endpoint without a signing region");
+        writeln!(
+            output,
+            "GlobalEndpoint {{
+                service: {service:?},
+                hostname: {hostname:?},
+                signing_region: {signing_region:?},
+            }},",
+            service = name,
+            hostname = hostname,
+            signing_region = signing_region,
+        )
+        .unwrap();
+    }
+
+    output.push_str("],\n},\n");
+}
+
+/// The prefixes of the region names matched by the `regionRegex` of a partition, such as
+/// `^(us|eu|ap|sa|ca|me)\-\w+\-\d+$` or `^us\-gov\-\w+\-\d+$`.
+fn region_prefixes(regex: &str) -> Vec<String> {
+    let regex = regex.trim_start_matches('^');
+    let prefix = regex[..regex.find("\\w").unwrap_or_else(|| regex.len())].replace("\\-", "-");
+    if !prefix.starts_with('(') {
+        return vec![prefix];
+    }
+    let end = prefix.find(')').expect("unbalanced regionRegex");
+    prefix[1..end]
+        .split('|')
+        .map(|name| format!("{}{}", name, &prefix[end + 1..]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::region_prefixes;
+
+    #[test]
+    fn region_prefixes_of_partitions() {
+        assert_eq!(
+            region_prefixes(r"^(us|eu|ap|sa|ca|me)\-\w+\-\d+$"),
+            vec!["us-", "eu-", "ap-", "sa-", "ca-", "me-"]
+        );
+        assert_eq!(region_prefixes(r"^us\-gov\-\w+\-\d+$"), vec!["us-gov-"]);
+        assert_eq!(region_prefixes(r"^cn\-\w+\-\d+$"), vec!["cn-"]);
+    }
+}
@@ -0,0 +1,63 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Service;
+
+const TEMPLATES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/templates/");
+
+/// Renders the files of the custom code template `name` into `custom_dir`, and returns the
+/// paths of the rendered files.
+///
+/// Templates hold custom code shared by services with the same shapes, such as WAF and WAF
+/// Regional. `{{crate_name}}`, `{{trait_name}}`, `{{client_name}}` and `{{target_prefix}}` are
+/// replaced with the names of `service`.
+pub fn render_template(service: &Service<'_>, name: &str, custom_dir: &Path) -> Vec<PathBuf> {
+    let template_dir = Path::new(TEMPLATES_DIR).join(name);
+    let mut template_paths = fs::read_dir(&template_dir)
+        .unwrap_or_else(|_| panic!("Unable to read template {}", template_dir.display()))
+        .map(|entry| entry.expect("failed to read template dir").path())
+        .collect::<Vec<_>>();
+    template_paths.sort();
+
+    template_paths
+        .into_iter()
+        .map(|template_path| {
+            let template = fs::read_to_string(&template_path)
+                .unwrap_or_else(|_| panic!("Unable to read {}", template_path.display()));
+            let file_name = template_path
+                .file_name()
+                .expect("template without a file name");
+            let rendered = template
+                .replace("{{crate_name}}", &service.crate_name())
+                .replace("{{trait_name}}", service.service_type_name())
+                .replace("{{client_name}}", &service.client_type_name())
+                .replace(
+                    "{{target_prefix}}",
+                    service.target_prefix().map_or("", String::as_str),
+                );
+
+            let out_path = custom_dir.join(file_name);
+            let output = format!(
+                "// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the template in
+//  service_crategen/templates/{name}/{file_name} must be updated.
+//
+// =================================================================
+
+{rendered}",
+                name = name,
+                file_name = file_name.to_string_lossy(),
+                rendered = rendered
+            );
+            fs::write(&out_path, output)
+                .unwrap_or_else(|_| panic!("Unable to write {}", out_path.display()));
+            out_path
+        })
+        .collect()
+}
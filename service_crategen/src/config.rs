@@ -21,8 +21,24 @@ pub struct ServiceConfig {
     pub custom_dependencies: Option<BTreeMap<String, cargo::Dependency>>,
     #[serde(rename = "customDevDependencies")]
     pub custom_dev_dependencies: Option<BTreeMap<String, cargo::Dependency>>,
+    #[serde(rename = "customFeatures")]
+    pub custom_features: Option<BTreeMap<String, Vec<String>>>,
     #[serde(rename = "baseTypeName")]
     pub base_type_name: String,
+    /// Whether to generate `src/fake.rs`, an in-process fake of the service behind a `fake` feature
+    #[serde(rename = "generateFake", default)]
+    pub generate_fake: bool,
+    /// Whether to document each operation of the service trait with an example
+    #[serde(rename = "operationExamples", default)]
+    pub operation_examples: bool,
+    /// Older API versions from botocore to generate alongside `protocolVersion`, each in a
+    /// `v<version>` module behind an `api-<version>` feature
+    #[serde(rename = "additionalProtocolVersions", default)]
+    pub additional_protocol_versions: Vec<String>,
+    /// Templates in `service_crategen/templates` rendered into `src/custom`, for custom code
+    /// shared with other services
+    #[serde(rename = "customTemplates", default)]
+    pub custom_templates: Vec<String>,
 }
 
 impl ServiceConfig {
@@ -36,6 +52,21 @@ impl ServiceConfig {
             serde_json::from_str(&contents).expect("Unable to parse services configuration file.");
         Ok(parsed)
     }
+
+    /// The features and modules of the additional API versions, e.g. `api-2011-12-05` and
+    /// `v2011_12_05`.
+    pub fn additional_versions(&self) -> Vec<(&str, String, String)> {
+        self.additional_protocol_versions
+            .iter()
+            .map(|version| {
+                (
+                    version.as_str(),
+                    format!("api-{}", version),
+                    format!("v{}", version.replace('-', "_")),
+                )
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug)]
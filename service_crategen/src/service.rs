@@ -6,13 +6,33 @@ use crate::config::ServiceConfig;
 
 #[derive(Debug)]
 pub struct Service<'a> {
+    key: &'a str,
     config: &'a crate::ServiceConfig,
     definition: ServiceDefinition,
 }
 
 impl<'b> Service<'b> {
-    pub fn new(config: &'b ServiceConfig, definition: ServiceDefinition) -> Self {
-        Service { config, definition }
+    pub fn new(key: &'b str, config: &'b ServiceConfig, definition: ServiceDefinition) -> Self {
+        Service {
+            key,
+            config,
+            definition,
+        }
+    }
+
+    /// The name of this service in botocore and in `services.json`, e.g. `acm-pca`
+    pub fn key(&self) -> &str {
+        self.key
+    }
+
+    /// The name used for this service in environment variables, e.g. `AWS_ENDPOINT_URL_ACM_PCA`
+    pub fn env_name(&self) -> String {
+        self.key.to_uppercase().replace('-', "_")
+    }
+
+    /// The name of the generated crate, e.g. `rusoto_acm_pca`
+    pub fn crate_name(&self) -> String {
+        format!("rusoto_{}", self.key.replace('-', "_"))
     }
 
     pub fn name(&self) -> &str {
@@ -42,6 +62,11 @@ impl<'b> Service<'b> {
         &self.definition.metadata.protocol
     }
 
+    /// Whether the operations of the service trait are documented with examples
+    pub fn operation_examples(&self) -> bool {
+        self.config.operation_examples
+    }
+
     pub fn client_type_name(&self) -> String {
         format!("{}Client", self.service_type_name())
     }
@@ -78,6 +103,14 @@ impl<'b> Service<'b> {
         &self.definition.operations
     }
 
+    /// The operation returning the endpoints of operations using endpoint discovery, if any.
+    pub fn endpoint_operation(&self) -> Option<&Operation> {
+        self.definition
+            .operations
+            .values()
+            .find(|operation| operation.endpoint_operation.unwrap_or(false))
+    }
+
     pub fn shape_for_value<'a>(&'a self, value: &Value) -> Option<&'a Shape> {
         self.definition.shapes.get(&value.shape)
     }
@@ -93,6 +126,19 @@ impl<'b> Service<'b> {
             .map(|shape| shape.shape_type)
     }
 
+    /// The format of a timestamp member given by the model, `iso8601`, `rfc822` or
+    /// `unixTimestamp`. Without one, the default format of the member's location applies.
+    pub fn timestamp_format_for_member<'a>(&'a self, member: &'a Member) -> Option<&'a str> {
+        member
+            .timestamp_format
+            .as_ref()
+            .or_else(|| {
+                self.shape_for_member(member)
+                    .and_then(|shape| shape.timestamp_format.as_ref())
+            })
+            .map(String::as_str)
+    }
+
     pub fn signing_name(&self) -> String {
         match self.definition.metadata.signing_name {
             Some(ref signing_name) => signing_name.to_string(),